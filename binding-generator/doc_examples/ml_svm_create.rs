@@ -0,0 +1,15 @@
+fn example() -> opencv::Result<()> {
+	use opencv::{core::{self, Mat}, ml, prelude::*};
+
+	let samples = Mat::from_slice_2d(&[[0f32, 0.], [1., 1.], [10., 10.], [11., 11.]])?;
+	let responses = Mat::from_slice(&[0i32, 0, 1, 1])?;
+
+	let mut svm = <dyn ml::SVM>::create()?;
+	svm.set_type(ml::SVM_Types::C_SVC as i32)?;
+	svm.set_kernel(ml::SVM_KernelTypes::LINEAR as i32)?;
+	svm.train_with_layout(&samples, ml::SampleTypes::ROW_SAMPLE, &responses)?;
+
+	let sample = Mat::from_slice_2d(&[[10.5f32, 10.5]])?;
+	assert_eq!(1., svm.predict(&sample, &mut core::no_array(), 0)?);
+	Ok(())
+}
@@ -0,0 +1,11 @@
+fn example() -> opencv::Result<()> {
+	use opencv::{core::Mat, ml, prelude::*};
+
+	let samples = Mat::from_slice_2d(&[[0f32, 0., 0.], [1., 1., 1.], [10., 10., 10.], [11., 11., 11.]])?;
+	let responses = Mat::from_slice(&[0i32, 0, 1, 1])?;
+
+	let data = ml::TrainData_CreateBuilder::new(&samples, ml::SampleTypes::ROW_SAMPLE as i32, &responses).call()?;
+	assert_eq!(4, data.get_n_samples()?);
+	assert_eq!(3, data.get_n_vars()?);
+	Ok(())
+}
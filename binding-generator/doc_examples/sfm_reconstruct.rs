@@ -0,0 +1,19 @@
+fn example() -> opencv::Result<()> {
+	use opencv::{core::{self, Mat}, prelude::*, sfm};
+
+	// one 2xN matrix of the tracked points per image, the columns must correspond between images
+	let mut points2d = core::Vector::<Mat>::new();
+	for image in 0..3 {
+		let shift = image as f64 * 5.;
+		points2d.push(Mat::from_slice_2d(&[
+			[10. + shift, 120. + shift, 30. + shift, 200. + shift, 75. + shift, 160. + shift, 40. + shift, 90. + shift],
+			[20., 40., 150., 90., 60., 170., 110., 30.],
+		])?);
+	}
+	let mut k = Mat::from_slice_2d(&[[350f64, 0., 240.], [0., 350., 360.], [0., 0., 1.]])?;
+
+	let mut ps = core::Vector::<Mat>::new();
+	let mut points3d = core::Vector::<Mat>::new();
+	sfm::reconstruct(&points2d, &mut ps, &mut points3d, &mut k, true)?;
+	Ok(())
+}
@@ -1,6 +1,8 @@
+use std::borrow::Cow;
+
 use once_cell::sync::Lazy;
 use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
-use regex::Regex;
+use regex::{Captures, Regex};
 
 use crate::{StrExt, string_ext::Indent, StringExt};
 
@@ -184,11 +186,192 @@ fn preprocess_formula(formula: &str) -> String {
 	out
 }
 
+/// Renders the body of the `example()` function from the `doc_examples` source as a doctest in the code block with the
+/// `fence` info string, the final `Ok(())` becomes a hidden line
+pub fn render_doc_example(fence: &str, src: &str) -> String {
+	let mut out = format!("```{}\n", fence);
+	let lines = src.trim_end().lines().collect::<Vec<_>>();
+	// skip the function signature and the closing brace
+	let body = lines.get(1..lines.len().saturating_sub(1)).unwrap_or_default();
+	for line in body {
+		let line = line.strip_prefix('\t').unwrap_or(line);
+		if line == "Ok(())" {
+			out.push_str("# Ok::<(), opencv::Error>(())");
+		} else {
+			out.push_str(line);
+		}
+		out.push('\n');
+	}
+	out.push_str("```");
+	out
+}
+
+/// Converts the C++ entity reference (e.g. `cv::sepFilter2D`, `#BORDER_DEFAULT` or `SVM::getDefaultGrid()`) to the
+/// rustdoc intra-doc link, returns `None` for the references that can't be mapped like doxygen pages, groups or
+/// namespaced entities
+///
+/// `doc_link` resolves the C++ name of the function or class to its full Rust path, the references it can't resolve
+/// (e.g. the entities of the modules that are not included in the current one) are rendered as the inline code.
+fn rust_doc_link(cpp_ref: &str, doc_link: &impl Fn(&str) -> Option<String>) -> Option<String> {
+	static REF: Lazy<Regex> = Lazy::new(|| Regex::new(r#"^(?:([A-Z]\w*)::)?([A-Za-z_]\w*)$"#).unwrap());
+	let cpp_ref = cpp_ref.strip_suffix("()").unwrap_or(cpp_ref);
+	let cpp_ref = cpp_ref.strip_prefix('#').unwrap_or(cpp_ref);
+	let cpp_ref = cpp_ref.strip_prefix("cv::").unwrap_or(cpp_ref);
+	let caps = REF.captures(cpp_ref)?;
+	let name = caps.get(2)?.as_str();
+	let is_func = name.starts_with(|c: char| c.is_ascii_lowercase());
+	if let Some(class) = caps.get(1) {
+		if is_func {
+			// the methods are linked to their class
+			let text = format!("{}::{}", class.as_str(), name.to_snake_case());
+			Some(render_doc_link(&text, doc_link(class.as_str())))
+		} else {
+			None
+		}
+	} else if is_func && name.contains('_') {
+		// OpenCV functions are camelCase, lowercase names with underscores are doxygen pages and groups
+		None
+	} else {
+		let path = doc_link(name);
+		let text = match &path {
+			Some(path) => path.rsplit("::").next().unwrap_or(path).to_string(),
+			None if is_func => name.to_snake_case(),
+			None => name.to_string(),
+		};
+		Some(render_doc_link(&text, path))
+	}
+}
+
+fn render_doc_link(text: &str, path: Option<String>) -> String {
+	if let Some(path) = path {
+		format!("[{}]({})", text, path)
+	} else {
+		format!("`{}`", text)
+	}
+}
+
+/// Converts `@ref` references and the entities listed in "See also" blocks to rustdoc intra-doc links, code blocks
+/// are left intact
+fn render_intra_doc_links(out: &mut String, doc_link: impl Fn(&str) -> Option<String>) {
+	static SEE_ALSO_LIST: Lazy<Regex> = Lazy::new(|| Regex::new(r#"^\s*[\w:#~]+(?:\(\))?(?:\s*,\s*[\w:#~]+(?:\(\))?)*\s*[.,]?\s*$"#).unwrap());
+	static SEE_ALSO_ITEM: Lazy<Regex> = Lazy::new(|| Regex::new(r#"[\w:#~]+(?:\(\))?"#).unwrap());
+	static REF: Lazy<Regex> = Lazy::new(|| Regex::new(r#"@ref[ \t]+([\w:#~]+(?:\(\))?)(?:[ \t]+"([^"]*)")?"#).unwrap());
+	static MARKDOWN_REF: Lazy<Regex> = Lazy::new(|| Regex::new(r#"\[([^\]]+)]\(@ref[ \t]+([\w:#~]+(?:\(\))?)\)"#).unwrap());
+	if !out.contains("@ref") && !out.contains("## See also") {
+		return;
+	}
+	let mut in_code = false;
+	let mut in_see_also = false;
+	let rendered = out.split('\n')
+		.map(|line| {
+			let line_trimmed = line.trim();
+			if line_trimmed.starts_with("```") {
+				in_code = !in_code;
+				in_see_also = false;
+				return Cow::Borrowed(line);
+			}
+			if in_code {
+				return Cow::Borrowed(line);
+			}
+			if line_trimmed == "## See also" {
+				in_see_also = true;
+				return Cow::Borrowed(line);
+			}
+			let mut line = Cow::Borrowed(line);
+			if in_see_also {
+				if line_trimmed.is_empty() || line_trimmed.starts_with("# ") || line_trimmed.starts_with("##") {
+					in_see_also = false;
+				} else if SEE_ALSO_LIST.is_match(&line) {
+					line = SEE_ALSO_ITEM.replace_all(&line, |caps: &Captures| rust_doc_link(&caps[0], &doc_link).unwrap_or_else(|| caps[0].to_string()))
+						.into_owned()
+						.into();
+				}
+			}
+			if line.contains("](@ref") {
+				// markdown link with the reference as the target keeps its text
+				line = MARKDOWN_REF.replace_all(&line, |caps: &Captures| {
+					match rust_doc_link(&caps[2], &doc_link) {
+						Some(link) if link.starts_with('[') => {
+							let target = link.rsplit_once("](").map_or("", |(_, target)| target);
+							format!("[{}]({}", &caps[1], target)
+						}
+						_ => caps[1].to_string(),
+					}
+				})
+					.into_owned()
+					.into();
+			}
+			if line.contains("@ref") {
+				line = REF.replace_all(&line, |caps: &Captures| {
+					if let Some(text) = caps.get(2) {
+						text.as_str().to_string()
+					} else {
+						rust_doc_link(&caps[1], &doc_link).unwrap_or_else(|| caps[1].to_string())
+					}
+				})
+					.into_owned()
+					.into();
+			}
+			line
+		})
+		.collect::<Vec<_>>()
+		.join("\n");
+	*out = rendered;
+}
+
+/// Escapes the square brackets that are not a part of a markdown link or inline code, rustdoc would treat them as the
+/// intra-doc links, e.g. `src[i]` or `[0, 255]`
+fn escape_brackets(out: &mut String) {
+	static LINK_OR_CODE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"!?\[[^\[\]]*]\([^()\s]*\)|`[^`]*`"#).unwrap());
+	fn escape(src: &str, out: &mut String) {
+		for c in src.chars() {
+			if c == '[' || c == ']' {
+				out.push('\\');
+			}
+			out.push(c);
+		}
+	}
+
+	if !out.contains('[') {
+		return;
+	}
+	let mut in_code = false;
+	let rendered = out.split('\n')
+		.map(|line| {
+			if line.trim_start().starts_with("```") {
+				in_code = !in_code;
+				return Cow::Borrowed(line);
+			}
+			if in_code || !line.contains('[') {
+				return Cow::Borrowed(line);
+			}
+			let mut escaped = String::with_capacity(line.len() + 8);
+			let mut last = 0;
+			for m in LINK_OR_CODE.find_iter(line) {
+				escape(&line[last..m.start()], &mut escaped);
+				escaped.push_str(m.as_str());
+				last = m.end();
+			}
+			escape(&line[last..], &mut escaped);
+			Cow::Owned(escaped)
+		})
+		.collect::<Vec<_>>()
+		.join("\n");
+	*out = rendered;
+}
+
 pub fn render_doc_comment(doc_comment: &str, prefix: &str, opencv_version: &str) -> String {
-	render_doc_comment_with_processor(doc_comment, prefix, opencv_version, |_| {})
+	render_doc_comment_with_processor(doc_comment, prefix, opencv_version, |_| None, |_| {})
 }
 
-pub fn render_doc_comment_with_processor(doc_comment: &str, prefix: &str, opencv_version: &str, mut post_processor: impl FnMut(&mut String)) -> String {
+/// Renders the doc comment, `doc_link` resolves the C++ names of the referenced entities to their Rust paths
+pub fn render_doc_comment_with_processor(
+	doc_comment: &str,
+	prefix: &str,
+	opencv_version: &str,
+	doc_link: impl Fn(&str) -> Option<String>,
+	mut post_processor: impl FnMut(&mut String),
+) -> String {
 	let mut out = strip_comment_markers(doc_comment);
 	out.replace_in_place("\r\n", "\n");
 	// module titles
@@ -304,6 +487,9 @@ pub fn render_doc_comment_with_processor(doc_comment: &str, prefix: &str, opencv
 	static INDENTS: Lazy<Regex> = Lazy::new(|| Regex::new(r#"(?m)^(\s{3}|\s{7}|\s{11}|\s{15}|\s{19})\s(\S)"#).unwrap());
 	out.replace_in_place_regex(&INDENTS, "$1$2");
 
+	render_intra_doc_links(&mut out, doc_link);
+	escape_brackets(&mut out);
+
 	post_processor(&mut out);
 
 	let mut out = if out.is_empty() || prefix.is_empty() {
@@ -13,6 +13,7 @@ use clang::{
 
 use crate::{
 	comment,
+	GeneratorEnv,
 	IteratorExt,
 	reserved_rename,
 	settings,
@@ -53,6 +54,17 @@ impl DefaultElement {
 		comment::render_doc_comment(&this.entity().get_comment().unwrap_or_default(), prefix, opencv_version)
 	}
 
+	/// Renders the doc comment with the references to the other entities resolved through the `gen_env`
+	pub fn rendered_doc_comment_with_links<'tu>(this: &impl EntityElement<'tu>, prefix: &str, opencv_version: &str, gen_env: &GeneratorEnv<'tu>) -> String {
+		comment::render_doc_comment_with_processor(
+			&this.entity().get_comment().unwrap_or_default(),
+			prefix,
+			opencv_version,
+			|name| gen_env.doc_link(name),
+			|_| {},
+		)
+	}
+
 	pub fn rendered_doc_comment(this: &(impl Element + ?Sized), opencv_version: &str) -> String {
 		this.rendered_doc_comment_with_prefix("///", opencv_version)
 	}
@@ -349,11 +349,24 @@ impl<'tu, 'ge> Func<'tu, 'ge> {
 	}
 
 	pub fn is_infallible(&self) -> bool {
-		self.as_field_accessor().is_some()
+		self.as_field_accessor().is_some() && !self.is_field_accessor_by_ptr()
 			|| matches!(self.entity.get_exception_specification(), Some(ExceptionSpecification::BasicNoexcept) | Some(ExceptionSpecification::Unevaluated))
 			|| settings::FORCE_INFALLIBLE.contains(&self.func_id())
 	}
 
+	/// Getter of the boxed class field that returns a pointer into the instance (e.g. for the fixed array fields), it's
+	/// fallible because the instance pointer can be null
+	pub fn is_field_accessor_by_ptr(&self) -> bool {
+		if let Kind::FieldAccessor(cls) = self.kind() {
+			self.type_hint != FunctionTypeHint::FieldSetter && cls.is_by_ptr() && {
+				let ret_type = self.return_type();
+				ret_type.as_pointer().map_or(false, |i| !i.is_void()) && !ret_type.is_pass_by_ptr() || ret_type.as_fixed_array().is_some()
+			}
+		} else {
+			false
+		}
+	}
+
 	pub fn is_unsafe(&self) -> bool {
 		settings::FUNC_UNSAFE.contains(&self.func_id())
 			|| self.arguments().into_iter().any(|a| a.type_ref().is_pass_by_ptr() && !a.is_user_data())
@@ -631,7 +644,7 @@ impl Element for Func<'_, '_> {
 				Some("".into())
 			}
 		});
-		comment::render_doc_comment_with_processor(&comment, prefix, opencv_version, |out| {
+		comment::render_doc_comment_with_processor(&comment, prefix, opencv_version, |name| self.gen_env.doc_link(name), |out| {
 			let mut default_args_comment = String::with_capacity(1024);
 			for arg in self.arguments() {
 				if let Some(def_val) = arg.default_value() {
@@ -647,6 +660,13 @@ impl Element for Func<'_, '_> {
 				}
 				out.push_str(&default_args_comment);
 			}
+			if let Some((fence, example)) = settings::FUNC_DOC_EXAMPLE.get(self.identifier().as_ref()) {
+				if !out.is_empty() {
+					out.push_str("\n\n");
+				}
+				out.push_str("## Example\n");
+				out.push_str(&comment::render_doc_example(fence, example));
+			}
 		})
 	}
 
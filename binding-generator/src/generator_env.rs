@@ -24,6 +24,7 @@ use crate::{
 	NamePool,
 	opencv_module_from_path,
 	settings,
+	type_ref::FishStyle,
 	TypeRef,
 };
 
@@ -82,6 +83,22 @@ impl<'tu> DbPopulator<'tu, '_> {
 		self.gen_env.class_constants.insert(full_name, cnst);
 	}
 
+	/// Functions and classes directly in the `cv` namespace can be referenced by their name in the doc comments
+	fn add_doc_link_entity(&mut self, entity: Entity<'tu>) {
+		let in_cv_namespace = entity.get_semantic_parent()
+			.map_or(false, |parent| parent.get_kind() == EntityKind::Namespace && parent.get_name().map_or(false, |name| name == "cv"));
+		if in_cv_namespace {
+			if let Some(name) = entity.get_name() {
+				if entity.get_kind() == EntityKind::FunctionDecl {
+					// the first overload gets the name without suffix
+					self.gen_env.doc_link_entities.entry(name).or_insert(entity);
+				} else if entity.is_definition() {
+					self.gen_env.doc_link_entities.insert(name, entity);
+				}
+			}
+		}
+	}
+
 	fn add_used_in_smart_ptr(&mut self, func: Entity<'tu>) {
 		let args = Func::new(func, self.gen_env).arguments().into_iter()
 			.filter_map(|arg| arg.type_ref().as_smart_ptr())
@@ -110,6 +127,7 @@ impl<'tu> EntityWalkerVisitor<'tu> for DbPopulator<'tu, '_> {
 	fn visit_entity(&mut self, entity: Entity<'tu>) -> bool {
 		match entity.get_kind() {
 			EntityKind::ClassDecl | EntityKind::StructDecl => {
+				self.add_doc_link_entity(entity);
 				entity.visit_children(|c, _| {
 					match c.get_kind() {
 						EntityKind::BaseSpecifier => {
@@ -136,6 +154,7 @@ impl<'tu> EntityWalkerVisitor<'tu> for DbPopulator<'tu, '_> {
 				});
 			}
 			EntityKind::FunctionDecl => {
+				self.add_doc_link_entity(entity);
 				self.add_func_comment(entity);
 				self.add_used_in_smart_ptr(entity);
 			}
@@ -156,6 +175,7 @@ pub struct GeneratorEnv<'tu> {
 	type_resolve_cache: HashMap<String, Type<'tu>>,
 	used_in_smart_ptr: HashSet<Entity<'tu>>,
 	pub descendants: HashMap<String, HashSet<Entity<'tu>>>,
+	doc_link_entities: HashMap<String, Entity<'tu>>,
 }
 
 impl<'tu> GeneratorEnv<'tu> {
@@ -171,6 +191,7 @@ impl<'tu> GeneratorEnv<'tu> {
 			type_resolve_cache: HashMap::with_capacity(32),
 			used_in_smart_ptr: HashSet::with_capacity(32),
 			descendants: HashMap::with_capacity(16),
+			doc_link_entities: HashMap::with_capacity(1024),
 		};
 		let walker = EntityWalker::new(root_entity);
 		walker.walk_opencv_entities(DbPopulator { gen_env: &mut out });
@@ -262,6 +283,24 @@ impl<'tu> GeneratorEnv<'tu> {
 		self.func_comments.get(cpp_fullname).map(|x| x.as_str())
 	}
 
+	/// Full Rust path of the function or class `cpp_name` of the `cv` namespace for the intra-doc links, `None` if it's
+	/// unknown or not exported
+	pub fn doc_link(&self, cpp_name: &str) -> Option<String> {
+		let entity = *self.doc_link_entities.get(cpp_name)?;
+		let (is_excluded, module, name) = if entity.get_kind() == EntityKind::FunctionDecl {
+			let func = Func::new(entity, self);
+			(func.is_excluded(), func.rust_module().into_owned(), func.rust_leafname(FishStyle::No).into_owned())
+		} else {
+			let cls = Class::new(entity, self);
+			(cls.is_excluded(), cls.rust_module().into_owned(), cls.rust_leafname(FishStyle::No).into_owned())
+		};
+		if is_excluded {
+			None
+		} else {
+			Some(format!("crate::{}::{}", module, name))
+		}
+	}
+
 	pub fn get_class_kind(&self, entity: Entity<'tu>) -> Option<ClassKind> {
 		let id = entity.usr();
 		memo_map(&self.class_kind_cache, id.as_ref(), || {
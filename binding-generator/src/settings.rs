@@ -584,6 +584,14 @@ pub static FUNC_CFG_ATTR: Lazy<HashMap<&str, (&str, &str)>> = Lazy::new(|| hashm
 	"cv_TrackerStateEstimatorMILBoosting_TrackerMILTargetState_setTargetFg_bool" => ("not(target_os = \"windows\")", "!defined(OCVRS_TARGET_OS_WINDOWS)"),
 });
 
+/// Examples appended to the doc comments of the functions: the code fence info string and the source, the source files
+/// contain the `example()` function that is compiled by `tests/doc_examples.rs`, its body is rendered into the docs
+pub static FUNC_DOC_EXAMPLE: Lazy<HashMap<&str, (&str, &str)>> = Lazy::new(|| hashmap! {
+	"cv_ml_SVM_create" => ("", include_str!("../doc_examples/ml_svm_create.rs")),
+	"cv_ml_TrainData_create_const__InputArrayR_int_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR" => ("", include_str!("../doc_examples/ml_traindata_create.rs")),
+	"cv_sfm_reconstruct_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__InputOutputArrayR_bool" => ("no_run", include_str!("../doc_examples/sfm_reconstruct.rs")),
+});
+
 /// cpp_fullname
 pub static ELEMENT_EXCLUDE: Lazy<HashSet<&str>> = Lazy::new(|| hashset! {
 	"cv::String",
@@ -729,11 +737,35 @@ pub static IMPLEMENTED_FUNCTION_LIKE_MACROS: Lazy<HashSet<&str>> = Lazy::new(||
 	"CV_MAKETYPE",
 });
 
+/// simple core types (without `core::` prefix) whose C++ default argument values are translated to Rust in the
+/// generated function builders, e.g. `Size()` or `Point(-1,-1)`
+pub static BUILDER_CORE_TYPES: Lazy<HashSet<&str>> = Lazy::new(|| hashset! {
+	"Point",
+	"Point2d",
+	"Point2f",
+	"Point2i",
+	"Rect",
+	"Rect2d",
+	"Rect2f",
+	"Rect2i",
+	"Scalar",
+	"Size",
+	"Size2d",
+	"Size2f",
+	"Size2i",
+});
+
 // fixme, generalize, make it use constant::ValueKind
 pub static CONST_TYPE_USIZE: Lazy<HashSet<&str>> = Lazy::new(|| hashset! {
 	"Mat_AUTO_STEP",
 });
 
+/// Modules where the module-level constants duplicating the variants of named enums are marked as deprecated in favor
+/// of the enum variants
+pub static CONST_DEPRECATE_FOR_ENUM: Lazy<HashSet<&str>> = Lazy::new(|| hashset! {
+	"ml",
+});
+
 /// map of reserved Rust keywords and their replacement to be used in var, function and class names
 /// key: reserved keyword
 /// value: replacement
@@ -4,7 +4,7 @@ use maplit::hashmap;
 use regex::Regex;
 
 use crate::{
-	comment::render_doc_comment,
+	comment::{render_doc_comment, render_doc_comment_with_processor},
 	StrExt,
 	string_ext::Indent,
 	StringExt,
@@ -625,4 +625,39 @@ line2
 #[deprecated = \"test\"]";
 		assert_eq!(res, &render_doc_comment(comment, "///", "master"));
 	}
+
+	{
+		let comment = "\
+/** Blurs an image using @ref filter2D \"the 2D filter\" and @ref cv::sepFilter2D.
+@sa boxFilter, #BORDER_DEFAULT, SVM::getDefaultGrid
+*/
+";
+		let res = "\
+/// Blurs an image using the 2D filter and `sep_filter_2d`.
+/// ## See also
+/// `box_filter`, `BORDER_DEFAULT`, `SVM::get_default_grid`";
+		assert_eq!(res, &render_doc_comment(comment, "///", "master"));
+		let doc_link = |name: &str| match name {
+			"sepFilter2D" => Some("crate::imgproc::sep_filter_2d".to_string()),
+			"Sobel" => Some("crate::imgproc::sobel".to_string()),
+			"SVM" => Some("crate::ml::SVM".to_string()),
+			_ => None,
+		};
+		let comment = "\
+/** Blurs an image using @ref cv::sepFilter2D and @ref Sobel, see [the filter](@ref Sobel) and [the box](@ref boxFilter).
+@sa boxFilter, SVM::getDefaultGrid
+*/
+";
+		let res = "\
+/// Blurs an image using [sep_filter_2d](crate::imgproc::sep_filter_2d) and [sobel](crate::imgproc::sobel), see [the filter](crate::imgproc::sobel) and the box.
+/// ## See also
+/// `box_filter`, [SVM::get_default_grid](crate::ml::SVM)";
+		assert_eq!(res, &render_doc_comment_with_processor(comment, "///", "master", doc_link, |_| {}));
+	}
+
+	{
+		let comment = "/** Scales dst[i] to the [0, 255] range, see `src[i]` and @cite Arthur2007 */";
+		let res = "/// Scales dst\\[i\\] to the \\[0, 255\\] range, see `src[i]` and [Arthur2007](https://docs.opencv.org/master/d0/de3/citelist.html#CITEREF_Arthur2007)";
+		assert_eq!(res, &render_doc_comment(comment, "///", "master"));
+	}
 }
@@ -75,7 +75,7 @@ impl Element for Typedef<'_, '_> {
 	}
 
 	fn rendered_doc_comment_with_prefix(&self, prefix: &str, opencv_version: &str) -> String {
-		DefaultElement::rendered_doc_comment_with_prefix(self, prefix, opencv_version)
+		DefaultElement::rendered_doc_comment_with_links(self, prefix, opencv_version, self.gen_env)
 	}
 
 	fn cpp_namespace(&self) -> Cow<str> {
@@ -18,7 +18,7 @@ use crate::{
 	type_ref::{FishStyle, NameStyle},
 };
 
-use super::RustNativeGeneratedElement;
+use super::{func, RustNativeGeneratedElement};
 
 fn gen_rust_class(c: &Class, opencv_version: &str) -> String {
 	static BOXED_TPL: Lazy<CompiledInterpolation> = Lazy::new(
@@ -110,12 +110,12 @@ fn gen_rust_class(c: &Class, opencv_version: &str) -> String {
 			trait_bases_mut.insert_str(0, ": ");
 		};
 		let mut trait_methods_pool = NamePool::with_capacity(method_count);
-		let trait_const_methods = rust_generate_funcs(
+		let (trait_const_methods, trait_const_builders) = rust_generate_funcs(
 			const_methods.iter().filter(|m| m.as_instance_method().is_some()),
 			&mut trait_methods_pool,
 			opencv_version,
 		);
-		let trait_mut_methods = rust_generate_funcs(
+		let (trait_mut_methods, trait_mut_builders) = rust_generate_funcs(
 			mut_methods.iter().filter(|m| m.as_instance_method().is_some()),
 			&mut trait_methods_pool,
 			opencv_version,
@@ -126,12 +126,12 @@ fn gen_rust_class(c: &Class, opencv_version: &str) -> String {
 				.join("");
 
 			let mut methods_pool = NamePool::with_capacity(method_count);
-			let const_methods = rust_generate_funcs(
+			let (const_methods, const_builders) = rust_generate_funcs(
 				const_methods.iter().filter(|m| m.as_static_method().is_some()),
 				&mut methods_pool,
 				opencv_version,
 			);
-			let mut_methods = rust_generate_funcs(
+			let (mut_methods, mut_builders) = rust_generate_funcs(
 				mut_methods.iter().filter(|m| m.as_static_method().is_some()),
 				&mut methods_pool,
 				opencv_version,
@@ -142,6 +142,7 @@ fn gen_rust_class(c: &Class, opencv_version: &str) -> String {
 					"consts" => consts.into(),
 					"const_methods" => const_methods.into(),
 					"mut_methods" => mut_methods.into(),
+					"builders" => (const_builders + &mut_builders).into(),
 				})
 			} else {
 				String::new()
@@ -161,6 +162,8 @@ fn gen_rust_class(c: &Class, opencv_version: &str) -> String {
 			"trait_bases_mut" => trait_bases_mut.into(),
 			"trait_const_methods" => trait_const_methods.into(),
 			"trait_mut_methods" => trait_mut_methods.into(),
+			"trait_const_builders" => trait_const_builders.into(),
+			"trait_mut_builders" => trait_mut_builders.into(),
 			"dyn_impl" => dyn_impl.into(),
 		});
 	}
@@ -264,8 +267,6 @@ fn gen_rust_class(c: &Class, opencv_version: &str) -> String {
 			vec![]
 		};
 
-		let mut inherent_const_methods = String::with_capacity(512 * const_methods.len());
-		let mut inherent_mut_methods = String::with_capacity(512 * mut_methods.len());
 		let mut inherent_methods_pool = NamePool::with_capacity(method_count);
 		let is_trait = c.is_trait();
 
@@ -277,7 +278,7 @@ fn gen_rust_class(c: &Class, opencv_version: &str) -> String {
 			}
 		}
 
-		inherent_const_methods.push_str(&if is_trait {
+		let (inherent_const_methods, inherent_const_builders) = if is_trait {
 			rust_generate_funcs(
 				const_methods.iter()
 					.filter(|m| m.as_static_method().is_some() || m.as_constructor().is_some()),
@@ -286,8 +287,8 @@ fn gen_rust_class(c: &Class, opencv_version: &str) -> String {
 			)
 		} else {
 			rust_generate_funcs(const_methods.iter(), &mut inherent_methods_pool, opencv_version)
-		});
-		inherent_mut_methods.push_str(&if is_trait {
+		};
+		let (inherent_mut_methods, inherent_mut_builders) = if is_trait {
 			rust_generate_funcs(
 				mut_methods.iter()
 					.filter(|m| m.as_static_method().is_some() || m.as_constructor().is_some()),
@@ -296,7 +297,7 @@ fn gen_rust_class(c: &Class, opencv_version: &str) -> String {
 			)
 		} else {
 			rust_generate_funcs(mut_methods.iter(), &mut inherent_methods_pool, opencv_version)
-		});
+		};
 
 		let tpl = if is_simple {
 			&SIMPLE_TPL
@@ -322,6 +323,7 @@ fn gen_rust_class(c: &Class, opencv_version: &str) -> String {
 				"consts" => consts.into(),
 				"const_methods" => inherent_const_methods.into(),
 				"mut_methods" => inherent_mut_methods.into(),
+				"builders" => (inherent_const_builders + &inherent_mut_builders).into(),
 			}).into(),
 			"impls" => impls.into(),
 		});
@@ -409,18 +411,22 @@ fn gen_cpp_boxed(c: &Class) -> String {
 	out
 }
 
-fn rust_generate_funcs<'f, 'tu, 'ge>(fns: impl Iterator<Item=&'f Func<'tu, 'ge>>, name_pool: &mut NamePool, opencv_version: &str) -> String where 'tu: 'ge, 'ge: 'f {
-	let fns = fns.filter(|f| !f.is_excluded());
-	fns.map(move |func| {
+/// Returns the generated methods and their builders, the latter are placed outside of the enclosing trait or impl block
+fn rust_generate_funcs<'f, 'tu, 'ge>(fns: impl Iterator<Item=&'f Func<'tu, 'ge>>, name_pool: &mut NamePool, opencv_version: &str) -> (String, String) where 'tu: 'ge, 'ge: 'f {
+	let mut out = String::new();
+	let mut builders = String::new();
+	for func in fns.filter(|f| !f.is_excluded()) {
 		let mut func = Cow::Borrowed(func);
 		let mut name = func.rust_leafname(FishStyle::No);
 		name_pool.make_unique_name(&mut name);
 		if let Cow::Owned(name) = name {
 			func.to_mut().set_name_hint(Some(name));
 		}
-		func.gen_rust(opencv_version) // fixme
-	})
-		.join("")
+		out += &func.gen_rust(opencv_version); // fixme
+		out += &func::gen_rust_try_accessor(&func);
+		builders += &func::gen_rust_builder(&func);
+	}
+	(out, builders)
 }
 
 impl RustNativeGeneratedElement for Class<'_, '_> {
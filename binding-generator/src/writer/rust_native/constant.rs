@@ -10,6 +10,7 @@ use crate::{
 	constant::ValueKind,
 	Element,
 	EntityElement,
+	Enum,
 	get_debug,
 	settings,
 	StrExt,
@@ -66,6 +67,7 @@ impl RustNativeGeneratedElement for Const<'_> {
 			tpl.interpolate(&hashmap! {
 				"doc_comment" => Cow::Owned(self.rendered_doc_comment(opencv_version)),
 				"debug" => get_debug(self).into(),
+				"deprecated" => deprecated_for_enum(self).into(),
 				"name" => name,
 				"value" => value.to_string().into(),
 			})
@@ -74,3 +76,27 @@ impl RustNativeGeneratedElement for Const<'_> {
 		}
 	}
 }
+
+/// Returns `#[deprecated]` attribute pointing to the enum variant if the constant comes from the named enum in one of the
+/// modules listed in `CONST_DEPRECATE_FOR_ENUM`, duplicate values point to the first variant because only that one is
+/// generated in the enum
+fn deprecated_for_enum(cnst: &Const) -> String {
+	if cnst.entity().get_kind() != EntityKind::EnumConstantDecl
+		|| !settings::CONST_DEPRECATE_FOR_ENUM.contains(cnst.rust_module().as_ref()) {
+		return "".to_string();
+	}
+	let value = cnst.value().map(|v| v.to_string());
+	cnst.entity().get_semantic_parent()
+		.map(Enum::new)
+		.filter(|enm| enm.rust_leafname(FishStyle::No) != "unnamed")
+		.and_then(|enm| {
+			enm.consts().into_iter()
+				.find(|c| c.value().map(|v| v.to_string()) == value)
+				.map(|variant| format!(
+					"#[deprecated = \"Use {enm}::{variant} instead\"]",
+					enm=enm.rust_localname(FishStyle::No),
+					variant=variant.rust_leafname(FishStyle::No),
+				))
+		})
+		.unwrap_or_default()
+}
@@ -2,6 +2,7 @@ use std::borrow::Cow;
 
 use maplit::hashmap;
 use once_cell::sync::Lazy;
+use regex::Regex;
 
 use crate::{
 	Class,
@@ -19,7 +20,7 @@ use crate::{
 	settings,
 	StrExt,
 	StringExt,
-	type_ref::{Dir, FishStyle, StrEnc, StrType},
+	type_ref::{Dir, FishStyle, NameStyle, StrEnc, StrType},
 	TypeRef,
 };
 
@@ -32,6 +33,21 @@ fn pre_post_arg_handle(mut arg: String, args: &mut Vec<String>) {
 	}
 }
 
+fn rust_return_func_decl(f: &Func) -> String {
+	let is_static_func = matches!(f.kind(), Kind::StaticMethod(..) | Kind::Function);
+	let return_type = f.return_type();
+	let return_type_func_decl = if f.is_infallible() {
+		return_type.rust_return_func_decl(FishStyle::No, is_static_func)
+	} else {
+		format!("Result<{}>", return_type.rust_return_func_decl(FishStyle::No, is_static_func)).into()
+	};
+	if return_type_func_decl == "()" {
+		"".to_string()
+	} else {
+		format!(" -> {}", return_type_func_decl)
+	}
+}
+
 fn gen_rust_with_name(f: &Func, name: &str, opencv_version: &str) -> String {
 	static TPL: Lazy<CompiledInterpolation> = Lazy::new(
 		|| include_str!("tpl/func/rust.tpl.rs").compile_interpolation()
@@ -47,8 +63,18 @@ fn gen_rust_with_name(f: &Func, name: &str, opencv_version: &str) -> String {
 	let mut pre_call_args = Vec::with_capacity(args.len());
 	let mut post_call_args = Vec::with_capacity(args.len());
 	if let Some(cls) = &as_instance_method {
-		decl_args.push(cls.type_ref().rust_self_func_decl(method_constness));
-		call_args.push(cls.type_ref().rust_self_func_call(method_constness));
+		let type_ref = cls.type_ref();
+		decl_args.push(type_ref.rust_self_func_decl(method_constness));
+		call_args.push(type_ref.rust_self_func_call(method_constness));
+		if cls.is_boxed() {
+			// the instance can be released through a stale wrapper, fallible methods report it, infallible only assert
+			let check = if is_infallible {
+				format!("crate::core::validity::debug_check(\"{}\", {})", type_ref.rust_local(), type_ref.rust_self_func_call(method_constness))
+			} else {
+				format!("crate::core::validity::check(\"{}\", {})?", type_ref.rust_local(), type_ref.rust_self_func_call(method_constness))
+			};
+			pre_post_arg_handle(check, &mut pre_call_args);
+		}
 	}
 	let mut callback_arg_name: Option<String> = None;
 	for (name, arg) in args {
@@ -101,16 +127,7 @@ fn gen_rust_with_name(f: &Func, name: &str, opencv_version: &str) -> String {
 	let is_safe = !f.is_unsafe();
 	let is_static_func = matches!(f.kind(), Kind::StaticMethod(..) | Kind::Function);
 	let return_type = f.return_type();
-	let return_type_func_decl = if is_infallible {
-		return_type.rust_return_func_decl(FishStyle::No, is_static_func)
-	} else {
-		format!("Result<{}>", return_type.rust_return_func_decl(FishStyle::No, is_static_func)).into()
-	};
-	let return_type_func_decl = if return_type_func_decl == "()" {
-		Cow::Borrowed("")
-	} else {
-		format!(" -> {}", return_type_func_decl).into()
-	};
+	let return_type_func_decl = rust_return_func_decl(f);
 	if is_infallible {
 		post_call_args.push("ret".to_string());
 	} else {
@@ -165,7 +182,7 @@ fn gen_rust_with_name(f: &Func, name: &str, opencv_version: &str) -> String {
 		"name" => name,
 		"generic_decl" => "",
 		"decl_args" => &decl_args,
-		"rv_rust_full" => return_type_func_decl.as_ref(),
+		"rv_rust_full" => return_type_func_decl.as_str(),
 		"pre_call_args" => &pre_call_args,
 		"unsafety_call" => if is_safe { "unsafe " } else { "" },
 		"identifier" => identifier.as_ref(),
@@ -177,6 +194,401 @@ fn gen_rust_with_name(f: &Func, name: &str, opencv_version: &str) -> String {
 	})
 }
 
+/// Generates the `try_` variant of the field getter of the boxed class, it checks the object pointer with
+/// `core::validity::check()` before calling the infallible getter, the getter is called through the trait because the
+/// base traits can have the field of the same name
+pub(super) fn gen_rust_try_accessor(f: &Func) -> String {
+	static TPL: Lazy<CompiledInterpolation> = Lazy::new(
+		|| include_str!("tpl/func/rust_try_accessor.tpl.rs").compile_interpolation()
+	);
+
+	let cls = match f.kind() {
+		Kind::FieldAccessor(cls) if f.type_hint() != FunctionTypeHint::FieldSetter && cls.is_boxed() => cls,
+		_ => return String::new(),
+	};
+	if f.is_excluded() || f.is_unsafe() || !f.is_infallible() {
+		return String::new();
+	}
+	let return_type_func_decl = rust_return_func_decl(f);
+	let rv_rust = if let Some(rv_rust) = return_type_func_decl.strip_prefix(" -> ") {
+		rv_rust
+	} else {
+		return String::new();
+	};
+	let constness = f.constness();
+	let type_ref = cls.type_ref();
+	TPL.interpolate(&hashmap! {
+		"name" => rust_func_name(f).into_owned(),
+		"self_decl" => type_ref.rust_self_func_decl(constness),
+		"self_call" => type_ref.rust_self_func_call(constness),
+		"rust_local" => type_ref.rust_local().into_owned(),
+		"rv_rust" => rv_rust.to_string(),
+		"rust_trait" => cls.rust_trait_name(NameStyle::Declaration, constness).into_owned(),
+	})
+}
+
+/// Functions with more arguments than this get a builder struct
+const BUILDER_MIN_ARGS: usize = 5;
+/// Functions with more default arguments than this get a builder struct
+const BUILDER_MIN_DEFAULTS: usize = 2;
+
+/// Default value of the builder field
+enum BuilderDefault {
+	/// Rust expression of the C++ default value
+	Value(String),
+	/// `noArray()`, the field is stored as `Option` and the missing array is created in `call()`
+	NoArray,
+}
+
+struct BuilderArg {
+	name: String,
+	typ: String,
+	cpp_default: Option<String>,
+	default: Option<BuilderDefault>,
+}
+
+impl BuilderArg {
+	fn field_type(&self) -> String {
+		let typ = rust_builder_lifetime(&self.typ);
+		if let Some(BuilderDefault::NoArray) = self.default {
+			format!("Option<{}>", typ)
+		} else {
+			typ
+		}
+	}
+}
+
+fn rust_func_name<'f>(f: &'f Func) -> Cow<'f, str> {
+	if f.is_clone() {
+		"try_clone".into()
+	} else if let Some(name_hint) = f.name_hint() {
+		name_hint.into()
+	} else {
+		f.rust_leafname(FishStyle::No)
+	}
+}
+
+/// Adds `'a` lifetime to every reference in the Rust type
+fn rust_builder_lifetime(typ: &str) -> String {
+	let mut out = String::with_capacity(typ.len() + 8);
+	let mut chars = typ.chars().peekable();
+	while let Some(c) = chars.next() {
+		out.push(c);
+		if c == '&' && chars.peek() != Some(&'\'') {
+			out.push_str("'a ");
+		}
+	}
+	out
+}
+
+fn rust_builder_int(val: &str, signed: bool) -> Option<String> {
+	static INT: Lazy<Regex> = Lazy::new(|| Regex::new(r"^-?\d+$").expect("Can't compile regex"));
+	if INT.is_match(val) && (signed || !val.starts_with('-')) {
+		Some(val.to_string())
+	} else {
+		None
+	}
+}
+
+fn rust_builder_float(val: &str) -> Option<String> {
+	static FLOAT: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(-?)(\d*)(\.\d*)?([eE][-+]?\d+)?[fF]?$").expect("Can't compile regex"));
+	let caps = FLOAT.captures(val)?;
+	let int = caps.get(2).map_or("", |m| m.as_str());
+	let frac = caps.get(3).map_or("", |m| m.as_str());
+	let exp = caps.get(4).map_or("", |m| m.as_str());
+	if int.is_empty() && frac.len() <= 1 {
+		return None;
+	}
+	Some(format!(
+		"{sign}{int}{frac}{exp}",
+		sign=caps.get(1).map_or("", |m| m.as_str()),
+		int=if int.is_empty() { "0" } else { int },
+		frac=if frac.is_empty() && exp.is_empty() { "." } else { frac },
+		exp=exp,
+	))
+}
+
+/// Translates the default value of the simple core type like `Size(8,8)` or `Scalar::all(0)`
+fn rust_builder_core_default(typ: &str, leaf: &str, val: &str) -> Option<String> {
+	static CTOR: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(\w+)(::all)?\((.*)\)$").expect("Can't compile regex"));
+	let caps = CTOR.captures(val)?;
+	let name = caps.get(1)?.as_str();
+	let is_all = caps.get(2).is_some();
+	let args = caps.get(3)?.as_str().trim();
+	let is_float = leaf == "Scalar" || leaf.ends_with('f') || leaf.ends_with('d');
+	let args = if args.is_empty() {
+		vec![]
+	} else {
+		args.split(',')
+			.map(|arg| if is_float { rust_builder_float(arg.trim()) } else { rust_builder_int(arg.trim(), true) })
+			.collect::<Option<Vec<_>>>()?
+	};
+	if args.is_empty() && !is_all && settings::BUILDER_CORE_TYPES.contains(&name) {
+		Some(format!("{}::default()", typ))
+	} else if name != leaf {
+		None
+	} else if is_all {
+		if leaf == "Scalar" && args.len() == 1 {
+			Some(format!("{}::all({})", typ, args[0]))
+		} else {
+			None
+		}
+	} else if leaf == "Scalar" && args.len() <= 4 {
+		let mut args = args;
+		args.resize(4, "0.".to_string());
+		Some(format!("{}::new({})", typ, args.join(", ")))
+	} else if (leaf.starts_with("Size") || leaf.starts_with("Point")) && args.len() == 2 {
+		Some(format!("{}::new({})", typ, args.join(", ")))
+	} else {
+		None
+	}
+}
+
+/// Translates C++ default value `val` of the argument with Rust type `typ`, returns `None` if it's not supported
+fn rust_builder_default(typ: &str, val: &str) -> Option<BuilderDefault> {
+	static CHAR: Lazy<Regex> = Lazy::new(|| Regex::new(r"^'([ -&(-\[\]-~])'$").expect("Can't compile regex"));
+	let val = val.trim();
+	let val_unqual = val.strip_prefix("cv::").unwrap_or(val);
+	let out = match typ {
+		"bool" => if val == "true" || val == "false" {
+			Some(val.to_string())
+		} else {
+			None
+		},
+		"i8" => CHAR.captures(val)
+			.map(|caps| format!("b'{}' as i8", &caps[1]))
+			.or_else(|| rust_builder_int(val, true)),
+		"i16" | "i32" | "i64" | "isize" => rust_builder_int(val, true),
+		"u8" | "u16" | "u32" | "u64" | "usize" | "size_t" => rust_builder_int(val, false),
+		"f32" | "f64" => rust_builder_float(val),
+		"&str" => match val_unqual {
+			"String()" | "std::string()" | "\"\"" => Some("\"\"".to_string()),
+			_ if val.len() >= 2 && val.starts_with('"') && val.ends_with('"') && !val.contains('\\') => Some(val.to_string()),
+			_ => None,
+		},
+		"&dyn core::ToInputArray" => return if val_unqual == "noArray()" || val_unqual == "Mat()" {
+			Some(BuilderDefault::NoArray)
+		} else {
+			None
+		},
+		"&mut dyn core::ToOutputArray" | "&mut dyn core::ToInputOutputArray" => return if val_unqual == "noArray()" {
+			Some(BuilderDefault::NoArray)
+		} else {
+			None
+		},
+		_ => typ.strip_prefix("core::")
+			.filter(|leaf| settings::BUILDER_CORE_TYPES.contains(leaf))
+			.and_then(|leaf| rust_builder_core_default(typ, leaf, val_unqual)),
+	};
+	out.map(BuilderDefault::Value)
+}
+
+/// Generates the builder struct for the function with a lot of arguments or default arguments, the builder takes the
+/// arguments without a default value in `new()` and fills in the rest with the translated C++ defaults
+pub(super) fn gen_rust_builder(f: &Func) -> String {
+	static TPL: Lazy<CompiledInterpolation> = Lazy::new(
+		|| include_str!("tpl/func/rust_builder.tpl.rs").compile_interpolation()
+	);
+
+	static DEFAULT_TPL: Lazy<CompiledInterpolation> = Lazy::new(
+		|| include_str!("tpl/func/rust_builder_default.tpl.rs").compile_interpolation()
+	);
+
+	if f.is_unsafe() || f.is_generic() || f.is_excluded() {
+		return String::new();
+	}
+	let identifier = f.identifier();
+	if settings::FUNC_MANUAL.contains_key(identifier.as_ref()) {
+		return String::new();
+	}
+	let name = rust_func_name(f);
+	let (instance, bound, call_path, cls) = match f.kind() {
+		Kind::Function => (None, None, name.clone().into_owned(), None),
+		Kind::StaticMethod(cls) | Kind::Constructor(cls) => {
+			let call_path = if cls.is_abstract() {
+				format!("<dyn {}>::{}", cls.rust_trait_name(NameStyle::Declaration, Constness::Mut), name)
+			} else {
+				format!("{}::{}", cls.rust_localname(FishStyle::No), name)
+			};
+			(None, None, call_path, Some(cls))
+		}
+		Kind::InstanceMethod(cls) => {
+			let constness = f.constness();
+			let mutability = if constness.is_const() { "" } else { "mut " };
+			if cls.is_trait() {
+				let trait_name = cls.rust_trait_name(NameStyle::Declaration, constness).into_owned();
+				(
+					Some(format!("&'a {}T", mutability)),
+					Some(format!("T: ?Sized + {}", trait_name)),
+					format!("{}::{}", trait_name, name),
+					Some(cls),
+				)
+			} else {
+				let rust_local = cls.rust_localname(FishStyle::No).into_owned();
+				let instance = if cls.type_ref().rust_self_func_decl(constness) == "self" {
+					rust_local.clone()
+				} else {
+					format!("&'a {}{}", mutability, rust_local)
+				};
+				(Some(instance), None, format!("{}::{}", rust_local, name), Some(cls))
+			}
+		}
+		_ => return String::new(),
+	};
+
+	let mut args = Vec::with_capacity(f.arguments().len());
+	for (name, arg) in Field::rust_disambiguate_names(f.arguments()) {
+		if arg.is_user_data() || arg.as_slice_len().is_some() {
+			continue;
+		}
+		let decl = arg.type_ref().rust_arg_func_decl(&name);
+		let decl = decl.strip_prefix("mut ").unwrap_or(&decl);
+		let typ = if let Some((_, typ)) = decl.split_once(": ") {
+			typ.to_string()
+		} else {
+			return String::new();
+		};
+		if typ.contains("Fn(") || name == "instance" {
+			return String::new();
+		}
+		let cpp_default = arg.default_value();
+		let default = cpp_default.as_deref().and_then(|val| rust_builder_default(&typ, val));
+		args.push(BuilderArg { name, typ, cpp_default, default });
+	}
+	let default_count = args.iter().filter(|arg| arg.cpp_default.is_some()).count();
+	if (args.len() <= BUILDER_MIN_ARGS && default_count <= BUILDER_MIN_DEFAULTS) || !args.iter().any(|arg| arg.default.is_some()) {
+		return String::new();
+	}
+	let return_type_func_decl = rust_return_func_decl(f);
+	if return_type_func_decl.contains('&') || return_type_func_decl.contains('\'') {
+		return String::new();
+	}
+
+	let pascal_name = name.split('_')
+		.filter(|part| !part.is_empty())
+		.map(|part| {
+			let mut chars = part.chars();
+			chars.next()
+				.map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+				.unwrap_or_default()
+		})
+		.join("");
+	let builder_name = if let Some(cls) = cls {
+		format!("{}_{}Builder", cls.rust_localname(FishStyle::No), pascal_name)
+	} else {
+		format!("{}Builder", pascal_name)
+	};
+
+	let mut fields = Vec::with_capacity(args.len() + 1);
+	let mut new_args = Vec::with_capacity(args.len() + 1);
+	let mut field_inits = Vec::with_capacity(args.len() + 1);
+	let mut setters = String::new();
+	let mut pre_call = Vec::new();
+	let mut call_args = Vec::with_capacity(args.len() + 1);
+	if let Some(instance) = &instance {
+		fields.push(format!("instance: {},", instance));
+		new_args.push(format!("instance: {}", instance));
+		field_inits.push("instance".to_string());
+		call_args.push("self.instance".to_string());
+	}
+	for arg in &args {
+		let field_type = arg.field_type();
+		fields.push(format!("{}: {},", arg.name, field_type));
+		match &arg.default {
+			None => {
+				new_args.push(format!("{}: {}", arg.name, field_type));
+				field_inits.push(arg.name.clone());
+				call_args.push(format!("self.{}", arg.name));
+			}
+			Some(default) => {
+				let (init, setter_type, setter_value) = match default {
+					BuilderDefault::Value(val) => (val.as_str(), field_type.clone(), arg.name.clone()),
+					BuilderDefault::NoArray => ("None", rust_builder_lifetime(&arg.typ), format!("Some({})", arg.name)),
+				};
+				field_inits.push(format!("{}: {}", arg.name, init));
+				setters += &format!(
+					"/// C++ default: `{cpp_default}`\n#[inline]\npub fn with_{name}(mut self, {name}: {typ}) -> Self {{\n\tself.{name} = {value};\n\tself\n}}\n\n",
+					cpp_default=arg.cpp_default.as_deref().unwrap_or_default(),
+					name=arg.name,
+					typ=setter_type,
+					value=setter_value,
+				);
+				if let BuilderDefault::NoArray = default {
+					let mutability = if arg.typ.starts_with("&mut ") { "mut " } else { "" };
+					pre_call.push(format!("let {mutability}{name}_no_array = core::no_array();", mutability=mutability, name=arg.name));
+					pre_call.push(format!(
+						"let {name}: {typ} = match self.{name} {{ Some({name}) => {name}, None => &{mutability}{name}_no_array }};",
+						name=arg.name,
+						typ=arg.typ,
+						mutability=mutability,
+					));
+					call_args.push(arg.name.clone());
+				} else {
+					call_args.push(format!("self.{}", arg.name));
+				}
+			}
+		}
+	}
+
+	let mut generics = Vec::with_capacity(2);
+	if fields.iter().any(|field| field.contains("'a")) {
+		generics.push("'a".to_string());
+	}
+	let generic_use = if bound.is_some() {
+		let mut generic_use = generics.clone();
+		generic_use.push("T".to_string());
+		format!("<{}>", generic_use.join(", "))
+	} else if generics.is_empty() {
+		String::new()
+	} else {
+		format!("<{}>", generics.join(", "))
+	};
+	if let Some(bound) = bound {
+		generics.push(bound);
+	}
+	let generic_decl = if generics.is_empty() {
+		String::new()
+	} else {
+		format!("<{}>", generics.join(", "))
+	};
+	let attributes = if let Some(attrs) = settings::FUNC_CFG_ATTR.get(identifier.as_ref()) {
+		format!("#[cfg({})]", attrs.0)
+	} else {
+		String::new()
+	};
+
+	let default_impl = if new_args.is_empty() {
+		DEFAULT_TPL.interpolate(&hashmap! {
+			"name" => builder_name.as_str(),
+			"attributes" => &attributes,
+			"generic_decl" => &generic_decl,
+			"generic_use" => &generic_use,
+		})
+	} else {
+		String::new()
+	};
+	let fields = fields.join("\n");
+	let new_args = new_args.join(", ");
+	let field_inits = field_inits.join(", ");
+	let pre_call = pre_call.join("\n");
+	let call_args = call_args.join(", ");
+	TPL.interpolate(&hashmap! {
+		"name" => builder_name.as_str(),
+		"attributes" => &attributes,
+		"generic_decl" => &generic_decl,
+		"generic_use" => &generic_use,
+		"fields" => &fields,
+		"new_args" => &new_args,
+		"field_inits" => &field_inits,
+		"setters" => &setters,
+		"rv_rust_full" => &return_type_func_decl,
+		"pre_call" => &pre_call,
+		"call_path" => &call_path,
+		"call_args" => &call_args,
+		"default_impl" => &default_impl,
+	})
+}
+
 fn cpp_method_call_name(c: &Class, method_name: &str) -> String {
 	if c.is_by_ptr() {
 		format!("instance->{name}", name = method_name)
@@ -324,14 +736,7 @@ impl RustNativeGeneratedElement for Func<'_, '_> {
 	}
 
 	fn gen_rust(&self, opencv_version: &str) -> String {
-		let name = if self.is_clone() {
-			"try_clone".into()
-		} else if let Some(name_hint) = self.name_hint() {
-			name_hint.into()
-		} else {
-			self.rust_leafname(FishStyle::No)
-		};
-		gen_rust_with_name(self, name.as_ref(), opencv_version)
+		gen_rust_with_name(self, rust_func_name(self).as_ref(), opencv_version)
 	}
 
 	fn gen_rust_exports(&self) -> String {
@@ -403,15 +808,22 @@ impl RustNativeGeneratedElement for Func<'_, '_> {
 		let mut pre_call_args = Vec::with_capacity(args.len());
 		let mut post_call_args = Vec::with_capacity(args.len());
 		let mut cleanup_args = Vec::with_capacity(args.len());
+		let mut context_args = Vec::with_capacity(args.len());
 		if let Some(cls) = self.as_instance_method() {
 			decl_args.push(cls.type_ref().cpp_self_func_decl(self.constness()));
 		}
+		if self.is_field_accessor_by_ptr() {
+			pre_post_arg_handle("if (!instance) CV_Error(cv::Error::StsNullPtr, \"Instance is null\")".to_string(), &mut pre_call_args);
+		}
 		for (name, arg) in args {
 			let type_ref = arg.type_ref();
 			decl_args.push(type_ref.cpp_arg_func_decl(&name));
 			pre_post_arg_handle(type_ref.cpp_arg_pre_call(&name), &mut pre_call_args);
 			pre_post_arg_handle(type_ref.cpp_arg_post_call(&name), &mut post_call_args);
 			pre_post_arg_handle(type_ref.cpp_arg_cleanup(&name), &mut cleanup_args);
+			if type_ref.is_input_array() || type_ref.is_input_output_array() {
+				context_args.push(format!("{{\"{name}\", ocvrs_describe_array({call})}}", name=name, call=type_ref.cpp_arg_func_call(name.as_str())));
+			}
 		}
 
 		let is_infallible = self.is_infallible();
@@ -478,7 +890,12 @@ impl RustNativeGeneratedElement for Func<'_, '_> {
 		let func_catch = if is_infallible {
 			Cow::Borrowed("")
 		} else {
-			format!("}} OCVRS_CATCH(OCVRS_TYPE({return_wrapper_full}))", return_wrapper_full= mut_ret_wrapper_full).into()
+			format!(
+				"}} OCVRS_CATCH_CTX(OCVRS_TYPE({return_wrapper_full}), \"{func}\", {context_args})",
+				return_wrapper_full=mut_ret_wrapper_full,
+				func=self.cpp_fullname(),
+				context_args=context_args.join(", "),
+			).into()
 		};
 
 		TPL.interpolate(&hashmap! {
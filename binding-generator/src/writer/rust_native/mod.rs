@@ -135,7 +135,9 @@ impl GeneratorVisitor for RustNativeBindingWriter<'_> {
 	fn visit_func(&mut self, func: Func) {
 		self.emit_debug_log(&func);
 		let name: String = func.identifier().into_owned();
-		self.rust_funcs.push((name.clone(), func.gen_rust(self.opencv_version)));
+		let mut rust = func.gen_rust(self.opencv_version);
+		rust.push_str(&func::gen_rust_builder(&func));
+		self.rust_funcs.push((name.clone(), rust));
 		self.export_funcs.push((name.clone(), func.gen_rust_exports()));
 		self.cpp_funcs.push((name, func.gen_cpp()));
 	}
@@ -9,7 +9,10 @@ opencv_type_boxed! { {{rust_local}} }
 impl Drop for {{rust_local}} {
 	fn drop(&mut self) {
 		extern "C" { fn cv_{{rust_local}}_delete(instance: {{rust_extern_mut}}); }
-		unsafe { cv_{{rust_local}}_delete(self.as_raw_mut_{{rust_local}}()) };
+		let ptr = self.as_raw_mut_{{rust_local}}();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_{{rust_local}}_delete(ptr) };
 	}
 }
 
@@ -4,4 +4,4 @@ impl {{rust_local}} {
 	{{mut_methods}}
 }
 
-
+{{builders}}
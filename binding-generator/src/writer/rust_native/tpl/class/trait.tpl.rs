@@ -6,11 +6,12 @@ pub trait {{rust_trait_local_const}}{{trait_bases_const}} {
 	{{trait_const_methods}}
 }
 
+{{trait_const_builders}}
 pub trait {{rust_trait_local}}{{trait_bases_mut}} {
 	fn as_raw_mut_{{rust_local}}(&mut self) -> {{rust_extern_mut}};
 
 	{{trait_mut_methods}}
 }
 
+{{trait_mut_builders}}
 {{dyn_impl}}
-
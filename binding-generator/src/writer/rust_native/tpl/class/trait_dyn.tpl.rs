@@ -4,3 +4,4 @@ impl dyn {{rust_local}} + '_ {
 	{{mut_methods}}
 }
 
+{{builders}}
@@ -1,4 +1,5 @@
 {{doc_comment}}
 {{debug}}
+{{deprecated}}
 pub const {{name}}: usize = {{value}};
 
@@ -0,0 +1,25 @@
+/// Builder for `{{call_path}}()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+{{attributes}}
+pub struct {{name}}{{generic_decl}} {
+	{{fields}}
+}
+
+{{attributes}}
+impl{{generic_decl}} {{name}}{{generic_use}} {
+	#[inline]
+	pub fn new({{new_args}}) -> Self {
+		Self { {{field_inits}} }
+	}
+
+	{{setters}}
+	#[inline]
+	pub fn call(self){{rv_rust_full}} {
+		{{pre_call}}
+		{{call_path}}({{call_args}})
+	}
+}
+
+{{default_impl}}
@@ -0,0 +1,8 @@
+{{attributes}}
+impl{{generic_decl}} Default for {{name}}{{generic_use}} {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
@@ -0,0 +1,8 @@
+/// Non-panicking version of [{{name}}]({{rust_trait}}::{{name}}), fails with `StsNullPtr` if the object pointer is null or
+/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+#[inline]
+fn try_{{name}}({{self_decl}}) -> Result<{{rv_rust}}> {
+	crate::core::validity::check("{{rust_local}}", {{self_call}})?;
+	Ok({{rust_trait}}::{{name}}(self))
+}
+
@@ -0,0 +1,6 @@
+#!/bin/bash
+
+set -vex
+
+echo "=== Checking with rustc: $(rustc --version), features: $FEATURES"
+cargo check -vv --features "$FEATURES"
@@ -54,6 +54,7 @@ cargo test -vv -p opencv-binding-generator
 FEATURES=rgb
 
 cargo test -vv --features "$FEATURES"
+RUSTDOCFLAGS="-D rustdoc::broken_intra_doc_links" cargo doc -vv --no-deps --features "$FEATURES"
 cargo test --release -vv --features "$FEATURES"
 cargo test --release -vv --features "$FEATURES,clang-runtime"
 
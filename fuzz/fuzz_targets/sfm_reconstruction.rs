@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+	// the first byte selects the format
+	if let Some((&format, data)) = data.split_first() {
+		let format = i32::from(format % 5);
+		let _ = opencv::fuzz::sfm_reconstruction(data, format);
+	}
+});
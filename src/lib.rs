@@ -1,8 +1,19 @@
-#![allow(broken_intra_doc_links)]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
 
 pub use error::{Error, Result};
 
 pub use crate::opencv::hub::*;
+#[cfg(ocvrs_has_module_core)]
+pub use crate::manual::fuzz;
+#[cfg(ocvrs_has_module_core)]
+pub use crate::manual::interop;
+#[cfg(all(unix, ocvrs_has_module_core))]
+pub use crate::manual::risky;
+#[cfg(all(feature = "show", ocvrs_has_module_imgproc))]
+pub use crate::manual::show;
+pub use crate::manual::stable;
+#[cfg(all(feature = "test-util", ocvrs_has_module_core))]
+pub use crate::manual::test_support;
 
 #[macro_use]
 mod templ;
@@ -0,0 +1,3 @@
+pub mod hand_eye;
+pub mod stereo;
+pub mod targets;
@@ -0,0 +1,269 @@
+//! Hand-eye and robot-world/hand-eye calibration over the typed poses
+//!
+//! The poses are passed as the pairs of the robot and the camera measurements taken at the same robot station. With the
+//! `nalgebra` feature `nalgebra::Isometry3<f64>` can be used in place of [Pose].
+//! ```no_run
+//! # use opencv::calib3d::{HandEyeCalibrationMethod, hand_eye::{self, Pose}};
+//! # fn run(stations: &[(Pose, Pose)]) -> opencv::Result<()> {
+//! let out = hand_eye::calibrate(stations, HandEyeCalibrationMethod::CALIB_HAND_EYE_PARK)?;
+//! println!("{:?} rms: {}°", out.cam2gripper.t, out.residuals.rotation_rms);
+//! # Ok(()) }
+//! ```
+
+use std::ops::Mul;
+
+use crate::{
+	calib3d::{self, HandEyeCalibrationMethod, RobotWorldHandEyeCalibrationMethod},
+	core::{self, Mat, Matx33d, Point3d, Vec3d, Vector},
+	Error,
+	prelude::*,
+	Result,
+};
+
+/// Rigid transform `x' = r * x + t`, e.g. `gripper2base` maps the gripper coordinates to the robot base coordinates
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Pose {
+	/// Rotation matrix
+	pub r: Matx33d,
+	pub t: Vec3d,
+}
+
+impl Pose {
+	#[inline]
+	pub fn new(r: Matx33d, t: Vec3d) -> Self {
+		Self { r, t }
+	}
+
+	#[inline]
+	pub fn identity() -> Self {
+		Self::new(Matx33d::eye(), Vec3d::all(0.))
+	}
+
+	/// Creates the pose from the Rodrigues rotation vector and the translation, e.g. the output of `solve_pnp()`
+	pub fn from_rvec_tvec(rvec: Vec3d, tvec: Vec3d) -> Result<Self> {
+		let mut r = Mat::default();
+		calib3d::rodrigues(&rvec, &mut r, &mut core::no_array())?;
+		Ok(Self::new(matx33d(&r)?, tvec))
+	}
+
+	/// Returns the Rodrigues rotation vector of the rotation
+	pub fn rvec(&self) -> Result<Vec3d> {
+		let mut out = Mat::default();
+		calib3d::rodrigues(&self.r, &mut out, &mut core::no_array())?;
+		vec3d(&out)
+	}
+
+	pub fn inverse(&self) -> Self {
+		let r = transpose(&self.r);
+		let t = mul_vec(&r, &self.t);
+		Self::new(r, Vec3d::from([-t[0], -t[1], -t[2]]))
+	}
+
+	/// Returns the rotation angle in radians
+	pub fn rotation_angle(&self) -> f64 {
+		let trace = self.r[(0, 0)] + self.r[(1, 1)] + self.r[(2, 2)];
+		((trace - 1.) / 2.).clamp(-1., 1.).acos()
+	}
+
+	#[inline]
+	pub fn translation_norm(&self) -> f64 {
+		self.t.iter().map(|v| v * v).sum::<f64>().sqrt()
+	}
+
+	#[inline]
+	pub fn transform_point(&self, p: Point3d) -> Point3d {
+		let out = mul_vec(&self.r, &Vec3d::from([p.x, p.y, p.z]));
+		Point3d::new(out[0] + self.t[0], out[1] + self.t[1], out[2] + self.t[2])
+	}
+}
+
+impl Default for Pose {
+	#[inline]
+	fn default() -> Self {
+		Self::identity()
+	}
+}
+
+/// Composition, `(a * b)` applies `b` first
+impl Mul for Pose {
+	type Output = Pose;
+
+	fn mul(self, rhs: Pose) -> Pose {
+		let mut r = Matx33d::zeros();
+		for i in 0..3 {
+			for j in 0..3 {
+				r[(i, j)] = (0..3).map(|k| self.r[(i, k)] * rhs.r[(k, j)]).sum();
+			}
+		}
+		let t = mul_vec(&self.r, &rhs.t);
+		Pose::new(r, Vec3d::from([t[0] + self.t[0], t[1] + self.t[1], t[2] + self.t[2]]))
+	}
+}
+
+fn transpose(m: &Matx33d) -> Matx33d {
+	let mut out = *m;
+	for i in 0..3 {
+		for j in 0..3 {
+			out[(i, j)] = m[(j, i)];
+		}
+	}
+	out
+}
+
+fn mul_vec(m: &Matx33d, v: &Vec3d) -> Vec3d {
+	let row = |i: usize| m[(i, 0)] * v[0] + m[(i, 1)] * v[1] + m[(i, 2)] * v[2];
+	Vec3d::from([row(0), row(1), row(2)])
+}
+
+fn matx33d(m: &Mat) -> Result<Matx33d> {
+	let mut out = Matx33d::zeros();
+	for i in 0..3 {
+		for j in 0..3 {
+			out[(i, j)] = *m.at_2d::<f64>(i as i32, j as i32)?;
+		}
+	}
+	Ok(out)
+}
+
+fn vec3d(m: &Mat) -> Result<Vec3d> {
+	Ok(Vec3d::from([*m.at::<f64>(0)?, *m.at::<f64>(1)?, *m.at::<f64>(2)?]))
+}
+
+/// Converts the poses to the rotation and translation vectors accepted by OpenCV
+fn pose_arrays(poses: impl Iterator<Item=Pose>) -> Result<(Vector<Mat>, Vector<Mat>)> {
+	let mut rs = Vector::new();
+	let mut ts = Vector::new();
+	for pose in poses {
+		rs.push(Mat::from_slice_2d(&[&pose.r.val[0..3], &pose.r.val[3..6], &pose.r.val[6..9]])?);
+		ts.push(Mat::from_slice_2d(&[[pose.t[0]], [pose.t[1]], [pose.t[2]]])?);
+	}
+	Ok((rs, ts))
+}
+
+/// Statistics of the deviations from the identity transform, the angles are in degrees
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Residuals {
+	pub rotation_rms: f64,
+	pub rotation_max: f64,
+	/// In the units of the input translations
+	pub translation_rms: f64,
+	pub translation_max: f64,
+}
+
+impl Residuals {
+	fn from_errors(errors: impl Iterator<Item=Pose>) -> Self {
+		let mut out = Self::default();
+		let mut count = 0;
+		for error in errors {
+			let angle = error.rotation_angle().to_degrees();
+			let distance = error.translation_norm();
+			out.rotation_rms += angle * angle;
+			out.rotation_max = out.rotation_max.max(angle);
+			out.translation_rms += distance * distance;
+			out.translation_max = out.translation_max.max(distance);
+			count += 1;
+		}
+		if count > 0 {
+			out.rotation_rms = (out.rotation_rms / f64::from(count)).sqrt();
+			out.translation_rms = (out.translation_rms / f64::from(count)).sqrt();
+		}
+		out
+	}
+}
+
+/// Result of the [calibrate]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HandEyeCalibration {
+	/// Camera pose in the gripper coordinates
+	pub cam2gripper: Pose,
+	/// Disagreement of the target poses in the robot base coordinates between the consecutive stations
+	pub residuals: Residuals,
+}
+
+/// Solves `AX = XB` for the camera mounted on the gripper from at least 3 `(gripper2base, target2cam)` pairs
+///
+/// The target is static relative to the robot base. The stations should have the rotations around the different axes,
+/// the pure translations don't constrain the result.
+pub fn calibrate<P: Clone + Into<Pose>>(stations: &[(P, P)], method: HandEyeCalibrationMethod) -> Result<HandEyeCalibration> {
+	if stations.len() < 3 {
+		return Err(Error::new(core::StsBadArg, format!("Hand-eye calibration requires at least 3 stations, got: {}", stations.len())));
+	}
+	let stations = stations.iter()
+		.map(|(gripper2base, target2cam)| (gripper2base.clone().into(), target2cam.clone().into()))
+		.collect::<Vec<(Pose, Pose)>>();
+	let (r_gripper2base, t_gripper2base) = pose_arrays(stations.iter().map(|s| s.0))?;
+	let (r_target2cam, t_target2cam) = pose_arrays(stations.iter().map(|s| s.1))?;
+	let mut r = Mat::default();
+	let mut t = Mat::default();
+	calib3d::calibrate_hand_eye(&r_gripper2base, &t_gripper2base, &r_target2cam, &t_target2cam, &mut r, &mut t, method)?;
+	let cam2gripper = Pose::new(matx33d(&r)?, vec3d(&t)?);
+	let target2base = stations.iter()
+		.map(|&(gripper2base, target2cam)| gripper2base * cam2gripper * target2cam)
+		.collect::<Vec<_>>();
+	let residuals = Residuals::from_errors(target2base.windows(2).map(|w| w[0].inverse() * w[1]));
+	Ok(HandEyeCalibration { cam2gripper, residuals })
+}
+
+/// Result of the [calibrate_robot_world]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RobotWorldHandEyeCalibration {
+	/// Robot base pose in the world (calibration target) coordinates
+	pub base2world: Pose,
+	/// Gripper pose in the camera coordinates
+	pub gripper2cam: Pose,
+	/// Deviations of `world2cam * base2world` from `gripper2cam * base2gripper` over the stations
+	pub residuals: Residuals,
+}
+
+/// Solves `AX = ZB` for the robot base in the world and the gripper in the camera from at least 3
+/// `(world2cam, base2gripper)` pairs
+pub fn calibrate_robot_world<P: Clone + Into<Pose>>(stations: &[(P, P)], method: RobotWorldHandEyeCalibrationMethod) -> Result<RobotWorldHandEyeCalibration> {
+	if stations.len() < 3 {
+		return Err(Error::new(core::StsBadArg, format!("Robot-world/hand-eye calibration requires at least 3 stations, got: {}", stations.len())));
+	}
+	let stations = stations.iter()
+		.map(|(world2cam, base2gripper)| (world2cam.clone().into(), base2gripper.clone().into()))
+		.collect::<Vec<(Pose, Pose)>>();
+	let (r_world2cam, t_world2cam) = pose_arrays(stations.iter().map(|s| s.0))?;
+	let (r_base2gripper, t_base2gripper) = pose_arrays(stations.iter().map(|s| s.1))?;
+	let (mut r_base2world, mut t_base2world) = (Mat::default(), Mat::default());
+	let (mut r_gripper2cam, mut t_gripper2cam) = (Mat::default(), Mat::default());
+	calib3d::calibrate_robot_world_hand_eye(
+		&r_world2cam,
+		&t_world2cam,
+		&r_base2gripper,
+		&t_base2gripper,
+		&mut r_base2world,
+		&mut t_base2world,
+		&mut r_gripper2cam,
+		&mut t_gripper2cam,
+		method,
+	)?;
+	let base2world = Pose::new(matx33d(&r_base2world)?, vec3d(&t_base2world)?);
+	let gripper2cam = Pose::new(matx33d(&r_gripper2cam)?, vec3d(&t_gripper2cam)?);
+	let residuals = Residuals::from_errors(stations.iter().map(|&(world2cam, base2gripper)| (world2cam * base2world).inverse() * gripper2cam * base2gripper));
+	Ok(RobotWorldHandEyeCalibration { base2world, gripper2cam, residuals })
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Isometry3<f64>> for Pose {
+	fn from(iso: nalgebra::Isometry3<f64>) -> Self {
+		let r = iso.rotation.to_rotation_matrix();
+		let m = r.matrix();
+		Self::new(
+			Matx33d::from([m[(0, 0)], m[(0, 1)], m[(0, 2)], m[(1, 0)], m[(1, 1)], m[(1, 2)], m[(2, 0)], m[(2, 1)], m[(2, 2)]]),
+			Vec3d::from([iso.translation.x, iso.translation.y, iso.translation.z]),
+		)
+	}
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<Pose> for nalgebra::Isometry3<f64> {
+	fn from(pose: Pose) -> Self {
+		let r = nalgebra::Rotation3::from_matrix_unchecked(nalgebra::Matrix3::from_row_slice(&pose.r.val));
+		nalgebra::Isometry3::from_parts(
+			nalgebra::Translation3::new(pose.t[0], pose.t[1], pose.t[2]),
+			nalgebra::UnitQuaternion::from_rotation_matrix(&r),
+		)
+	}
+}
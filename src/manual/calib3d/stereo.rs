@@ -0,0 +1,284 @@
+//! Semi-global block matching with the tuned presets and the disparity in pixels
+//!
+//! `StereoSGBM::compute()` returns `CV_16S` disparity scaled by 16 with `(min_disparity - 1) * 16` marking the pixels
+//! without a match. [Matcher] converts it to the `CV_32F` [Disparity] in pixels with `NaN` for the missing values, which
+//! reprojects to the [DepthMap].
+//! ```no_run
+//! # use opencv::{calib3d::stereo::{Matcher, Preset}, core::Mat};
+//! # fn run(left: &Mat, right: &Mat, q: &Mat) -> opencv::Result<()> {
+//! let mut matcher = Matcher::new(Preset::Balanced, 64)?;
+//! let depth = matcher.compute(left, right)?.to_depth(q)?;
+//! println!("{:?}", depth.at(240, 320)?);
+//! # Ok(()) }
+//! ```
+
+use crate::{
+	calib3d::{self, StereoSGBM},
+	core::{self, Mat, Point3f, Ptr, Rect, Scalar, Vec3f},
+	Error,
+	prelude::*,
+	Result,
+};
+
+/// Speed/quality trade-off of the [Matcher]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Preset {
+	/// Large blocks with 3-way SGBM, no speckle filtering
+	Fast,
+	/// 5 path SGBM with the speckle filtering
+	Balanced,
+	/// Small blocks with the full 8 path SGBM, slow and memory hungry for the large images
+	Accurate,
+}
+
+struct PresetParams {
+	block_size: i32,
+	mode: i32,
+	uniqueness_ratio: i32,
+	speckle_window_size: i32,
+	speckle_range: i32,
+	disp12_max_diff: i32,
+}
+
+impl Preset {
+	fn params(self) -> PresetParams {
+		match self {
+			Preset::Fast => PresetParams {
+				block_size: 7,
+				mode: calib3d::StereoSGBM_MODE_SGBM_3WAY,
+				uniqueness_ratio: 15,
+				speckle_window_size: 0,
+				speckle_range: 0,
+				disp12_max_diff: -1,
+			},
+			Preset::Balanced => PresetParams {
+				block_size: 5,
+				mode: calib3d::StereoSGBM_MODE_SGBM,
+				uniqueness_ratio: 10,
+				speckle_window_size: 100,
+				speckle_range: 2,
+				disp12_max_diff: 1,
+			},
+			Preset::Accurate => PresetParams {
+				block_size: 3,
+				mode: calib3d::StereoSGBM_MODE_HH,
+				uniqueness_ratio: 5,
+				speckle_window_size: 200,
+				speckle_range: 1,
+				disp12_max_diff: 1,
+			},
+		}
+	}
+}
+
+/// `StereoSGBM` configured from a [Preset] with the optional WLS post-filtering
+///
+/// The smoothness penalties `P1` and `P2` are set from the number of channels of the images on every
+/// [compute](Matcher::compute) using the `8 * cn * block_size²` and `32 * cn * block_size²` rule of thumb.
+pub struct Matcher {
+	sgbm: Ptr<dyn StereoSGBM>,
+	#[cfg(ocvrs_has_module_ximgproc)]
+	wls: Option<Ptr<dyn crate::ximgproc::DisparityWLSFilter>>,
+}
+
+impl Matcher {
+	/// `num_disparities` is rounded up to the multiple of 16 required by SGBM
+	pub fn new(preset: Preset, num_disparities: i32) -> Result<Self> {
+		if num_disparities <= 0 {
+			return Err(Error::new(core::StsBadArg, format!("Number of disparities must be positive, got: {}", num_disparities)));
+		}
+		let params = preset.params();
+		let sgbm = <dyn StereoSGBM>::create(
+			0,
+			(num_disparities + 15) / 16 * 16,
+			params.block_size,
+			0,
+			0,
+			params.disp12_max_diff,
+			63,
+			params.uniqueness_ratio,
+			params.speckle_window_size,
+			params.speckle_range,
+			params.mode,
+		)?;
+		Ok(Self {
+			sgbm,
+			#[cfg(ocvrs_has_module_ximgproc)]
+			wls: None,
+		})
+	}
+
+	/// Sets the minimum disparity, `0` by default, negative values allow the matches "behind" the infinity
+	pub fn with_min_disparity(mut self, min_disparity: i32) -> Result<Self> {
+		self.sgbm.set_min_disparity(min_disparity)?;
+		Ok(self)
+	}
+
+	/// Enables the edge-aware WLS filtering of the disparity guided by the left view
+	///
+	/// `lambda` is the regularization strength (`8000` is a good start) and `sigma_color` is the sensitivity to the left
+	/// view edges (`0.8`–`2.0`). The disparity of the right view used for the confidence is computed by matching the
+	/// mirrored pair, so the filtering roughly doubles the matching time. The images must be `CV_8UC1` or `CV_8UC3`.
+	#[cfg(ocvrs_has_module_ximgproc)]
+	pub fn with_wls(mut self, lambda: f64, sigma_color: f64) -> Result<Self> {
+		let mut wls = crate::ximgproc::create_disparity_wls_filter_generic(true)?;
+		wls.set_lambda(lambda)?;
+		wls.set_sigma_color(sigma_color)?;
+		self.wls = Some(wls);
+		Ok(self)
+	}
+
+	/// Computes the disparity of the rectified pair in the left view coordinates
+	pub fn compute(&mut self, left: &Mat, right: &Mat) -> Result<Disparity> {
+		if left.size()? != right.size()? || left.typ() != right.typ() {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("Stereo pair must have the same size and type, got: {:?} {} and {:?} {}", left.size()?, left.typ(), right.size()?, right.typ())));
+		}
+		if left.empty() {
+			return Err(Error::new(core::StsBadArg, "Stereo pair images are empty"));
+		}
+		let block_size = self.sgbm.get_block_size()?;
+		let area = left.channels() * block_size * block_size;
+		self.sgbm.set_p1(8 * area)?;
+		self.sgbm.set_p2(32 * area)?;
+		let mut raw = Mat::default();
+		self.sgbm.compute(left, right, &mut raw)?;
+		let raw = self.post_filter(raw, left, right)?;
+		Disparity::from_fixed_point(&raw, self.sgbm.get_min_disparity()?)
+	}
+
+	#[cfg(not(ocvrs_has_module_ximgproc))]
+	#[inline]
+	fn post_filter(&mut self, raw: Mat, _left: &Mat, _right: &Mat) -> Result<Mat> {
+		Ok(raw)
+	}
+
+	#[cfg(ocvrs_has_module_ximgproc)]
+	fn post_filter(&mut self, raw: Mat, left: &Mat, right: &Mat) -> Result<Mat> {
+		let wls = if let Some(wls) = &mut self.wls {
+			wls
+		} else {
+			return Ok(raw);
+		};
+		let min_disparity = self.sgbm.get_min_disparity()?;
+		let num_disparities = self.sgbm.get_num_disparities()?;
+		let block_size = self.sgbm.get_block_size()?;
+		// matching the mirrored pair gives the right view disparity without a separate right matcher
+		let mut left_mirrored = Mat::default();
+		core::flip(left, &mut left_mirrored, 1)?;
+		let mut right_mirrored = Mat::default();
+		core::flip(right, &mut right_mirrored, 1)?;
+		let mut raw_mirrored = Mat::default();
+		self.sgbm.compute(&right_mirrored, &left_mirrored, &mut raw_mirrored)?;
+		let mut raw_right = Mat::default();
+		core::flip(&raw_mirrored, &mut raw_right, 1)?;
+		let mut missing = Mat::default();
+		core::compare(&raw_right, &Scalar::all(f64::from(missing_value(min_disparity))), &mut missing, core::CMP_LE)?;
+		// the filter expects the negative right view disparity with its own missing value like `createRightMatcher()` produces
+		let mut disparity_right = Mat::default();
+		raw_right.convert_to(&mut disparity_right, core::CV_16S, -1., 0.)?;
+		disparity_right.set_to(&Scalar::all(f64::from(missing_value(-(min_disparity + num_disparities) + 1))), &missing)?;
+
+		let size = left.size()?;
+		let roi = Rect::new(
+			min_disparity + num_disparities + block_size / 2,
+			block_size / 2,
+			size.width - num_disparities - block_size / 2 * 2,
+			size.height - block_size / 2 * 2,
+		);
+		if roi.width <= 0 || roi.height <= 0 {
+			return Err(Error::new(core::StsBadSize, format!("Images: {:?} are too small for {} disparities", size, num_disparities)));
+		}
+		let mut out = Mat::default();
+		wls.filter(&raw, left, &mut out, &disparity_right, roi, right)?;
+		Ok(out)
+	}
+}
+
+#[inline]
+fn missing_value(min_disparity: i32) -> i32 {
+	(min_disparity - 1) * 16
+}
+
+/// `CV_32F` disparity in pixels with `NaN` for the pixels without a match
+#[derive(Debug)]
+pub struct Disparity {
+	mat: Mat,
+}
+
+impl Disparity {
+	/// Converts the `CV_16S` output of `StereoSGBM` or `StereoBM` scaled by 16
+	pub fn from_fixed_point(raw: &Mat, min_disparity: i32) -> Result<Self> {
+		if raw.typ() != core::CV_16SC1 {
+			return Err(Error::new(core::StsUnsupportedFormat, format!("Fixed point disparity must have CV_16SC1 type, but it has: {}", raw.typ())));
+		}
+		let mut mat = Mat::default();
+		raw.convert_to(&mut mat, core::CV_32F, 1. / 16., 0.)?;
+		let mut missing = Mat::default();
+		core::compare(raw, &Scalar::all(f64::from(missing_value(min_disparity))), &mut missing, core::CMP_LE)?;
+		mat.set_to(&Scalar::all(f64::NAN), &missing)?;
+		Ok(Self { mat })
+	}
+
+	#[inline]
+	pub fn as_mat(&self) -> &Mat {
+		&self.mat
+	}
+
+	#[inline]
+	pub fn into_mat(self) -> Mat {
+		self.mat
+	}
+
+	/// Returns the disparity in pixels, `None` for the pixel without a match
+	#[inline]
+	pub fn at(&self, row: i32, col: i32) -> Result<Option<f32>> {
+		self.mat.at_2d::<f32>(row, col).map(|&d| if d.is_nan() { None } else { Some(d) })
+	}
+
+	/// Reprojects the disparity to 3D with the `4x4` `Q` matrix returned by `calib3d::stereo_rectify()`
+	pub fn to_depth(&self, q: &Mat) -> Result<DepthMap> {
+		if q.rows() != 4 || q.cols() != 4 || q.channels() != 1 {
+			return Err(Error::new(core::StsBadArg, format!("Q must be a 4x4 single channel matrix, got: {}x{}", q.rows(), q.cols())));
+		}
+		let mut points = Mat::default();
+		calib3d::reproject_image_to_3d(&self.mat, &mut points, q, false, core::CV_32F)?;
+		// zero disparity lands at infinity
+		for point in points.data_typed_mut::<Vec3f>()? {
+			if !point.iter().all(|v| v.is_finite()) {
+				*point = Vec3f::all(f32::NAN);
+			}
+		}
+		Ok(DepthMap { points })
+	}
+}
+
+/// `CV_32FC3` 3D points in the left rectified camera coordinates with `NaN` for the pixels without depth
+#[derive(Debug)]
+pub struct DepthMap {
+	points: Mat,
+}
+
+impl DepthMap {
+	#[inline]
+	pub fn points(&self) -> &Mat {
+		&self.points
+	}
+
+	#[inline]
+	pub fn into_points(self) -> Mat {
+		self.points
+	}
+
+	/// Returns the `CV_32F` Z coordinates, the `NaN` values are skipped by `sfm::depth_to_cloud()`
+	pub fn depth(&self) -> Result<Mat> {
+		let mut out = Mat::default();
+		core::extract_channel(&self.points, &mut out, 2)?;
+		Ok(out)
+	}
+
+	/// Returns the 3D point of the pixel, `None` if it has no depth
+	#[inline]
+	pub fn at(&self, row: i32, col: i32) -> Result<Option<Point3f>> {
+		self.points.at_2d::<Vec3f>(row, col).map(|p| if p[2].is_nan() { None } else { Some(Point3f::new(p[0], p[1], p[2])) })
+	}
+}
@@ -0,0 +1,174 @@
+//! Printable calibration targets with their object points
+//!
+//! The target is rendered at the requested DPI with the square size rounded to whole pixels, the object points use the
+//! rounded [square_size](Target::square_size) so they match the print made at 100% scale. A quiet zone of one square is
+//! left around the pattern.
+//! ```no_run
+//! # use opencv::{calib3d::targets::{self, TargetSpec}, core::Size};
+//! let target = targets::render(TargetSpec::Chessboard { inner_corners: Size::new(9, 6), square_size: 25. }, 300.)?;
+//! std::fs::write("chessboard.pdf", target.to_pdf()?).unwrap();
+//! # Ok::<_, opencv::Error>(())
+//! ```
+
+use std::{borrow::Cow, io::Write};
+
+use crate::{
+	core::{self, Mat, Point3f, Scalar, Size, Vector},
+	Error,
+	prelude::*,
+	Result,
+};
+
+const MM_PER_INCH: f64 = 25.4;
+const PT_PER_INCH: f64 = 72.;
+
+/// Calibration pattern, the sizes are in millimeters
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TargetSpec {
+	/// `inner_corners` is the pattern size passed to `find_chessboard_corners()`, the board has one square more in
+	/// each direction
+	Chessboard { inner_corners: Size, square_size: f32 },
+	/// Board for `aruco::interpolate_corners_charuco()` with `squares` squares in each direction
+	#[cfg(ocvrs_has_module_aruco)]
+	Charuco { squares: Size, square_size: f32, marker_size: f32, dictionary: crate::aruco::PREDEFINED_DICTIONARY_NAME },
+}
+
+/// Rendered calibration target
+#[derive(Debug)]
+pub struct Target {
+	/// `CV_8UC1` black and white image
+	pub image: Mat,
+	/// Corner coordinates in millimeters on the `Z = 0` plane, in the order of the detected corners
+	pub object_points: Vector<Point3f>,
+	/// Printed square size in millimeters after the rounding to the whole pixels
+	pub square_size: f32,
+	pub dpi: f64,
+}
+
+/// Renders the target at `dpi` pixels per inch
+pub fn render(spec: TargetSpec, dpi: f64) -> Result<Target> {
+	if !(dpi.is_finite() && dpi > 0.) {
+		return Err(Error::new(core::StsBadArg, format!("DPI must be positive, got: {}", dpi)));
+	}
+	match spec {
+		TargetSpec::Chessboard { inner_corners, square_size } => {
+			if inner_corners.width < 2 || inner_corners.height < 2 {
+				return Err(Error::new(core::StsBadArg, format!("Chessboard must have at least 2x2 inner corners, got: {:?}", inner_corners)));
+			}
+			let square_px = square_pixels(square_size, dpi, 4)?;
+			let square_size = physical_size(square_px, dpi);
+			let squares = Size::new(inner_corners.width + 1, inner_corners.height + 1);
+			let mut image = Mat::new_rows_cols_with_default((squares.height + 2) * square_px, (squares.width + 2) * square_px, core::CV_8UC1, Scalar::all(255.))?;
+			let cols = image.cols() as usize;
+			for (y, row) in image.data_bytes_mut()?.chunks_exact_mut(cols).enumerate() {
+				let square_y = y as i32 / square_px - 1;
+				if square_y < 0 || square_y >= squares.height {
+					continue;
+				}
+				for (x, px) in row.iter_mut().enumerate() {
+					let square_x = x as i32 / square_px - 1;
+					if square_x >= 0 && square_x < squares.width && (square_x + square_y) % 2 == 0 {
+						*px = 0;
+					}
+				}
+			}
+			let object_points = (0..inner_corners.height)
+				.flat_map(|i| (0..inner_corners.width).map(move |j| Point3f::new(j as f32 * square_size, i as f32 * square_size, 0.)))
+				.collect();
+			Ok(Target { image, object_points, square_size, dpi })
+		}
+		#[cfg(ocvrs_has_module_aruco)]
+		TargetSpec::Charuco { squares, square_size, marker_size, dictionary } => {
+			use crate::aruco::{CharucoBoard, CharucoBoardTrait, CharucoBoardTraitConst};
+
+			if squares.width < 2 || squares.height < 2 {
+				return Err(Error::new(core::StsBadArg, format!("ChArUco board must have at least 2x2 squares, got: {:?}", squares)));
+			}
+			if !(marker_size > 0. && marker_size < square_size) {
+				return Err(Error::new(core::StsBadArg, format!("Marker size: {} must be positive and less than the square size: {}", marker_size, square_size)));
+			}
+			let square_px = square_pixels(square_size, dpi, 16)?;
+			let physical_square_size = physical_size(square_px, dpi);
+			let marker_size = marker_size * physical_square_size / square_size;
+			let dictionary = crate::aruco::get_predefined_dictionary(dictionary)?;
+			let mut board = CharucoBoard::create(squares.width, squares.height, physical_square_size, marker_size, &dictionary)?;
+			let mut image = Mat::default();
+			board.draw(Size::new((squares.width + 2) * square_px, (squares.height + 2) * square_px), &mut image, square_px, 1)?;
+			Ok(Target { image, object_points: board.chessboard_corners(), square_size: physical_square_size, dpi })
+		}
+	}
+}
+
+/// Returns the square size in whole pixels, at least `min_px`
+fn square_pixels(square_size: f32, dpi: f64, min_px: i32) -> Result<i32> {
+	let square_px = (f64::from(square_size) / MM_PER_INCH * dpi).round();
+	if square_px.is_nan() || square_px < f64::from(min_px) {
+		return Err(Error::new(core::StsBadArg, format!("Square size: {}mm is less than {} pixels at {} DPI", square_size, min_px, dpi)));
+	}
+	Ok(square_px as i32)
+}
+
+#[inline]
+fn physical_size(px: i32, dpi: f64) -> f32 {
+	(f64::from(px) / dpi * MM_PER_INCH) as f32
+}
+
+impl Target {
+	/// Returns the single page PDF with the page size of the target printed at its DPI
+	///
+	/// The image is embedded as an uncompressed 1 bit per pixel bitmap.
+	pub fn to_pdf(&self) -> Result<Vec<u8>> {
+		let (rows, cols) = (self.image.rows() as usize, self.image.cols() as usize);
+		if self.image.typ() != core::CV_8UC1 || rows == 0 {
+			return Err(Error::new(core::StsUnsupportedFormat, format!("Target image must be non-empty CV_8UC1, but it has type: {}", self.image.typ())));
+		}
+		let image = if self.image.is_continuous() { Cow::Borrowed(&self.image) } else { Cow::Owned(self.image.try_clone()?) };
+		// DeviceGray 1 bit is white for 1, the rows are padded to the whole bytes
+		let row_len = (cols - 1) / 8 + 1;
+		let mut bits = vec![0; row_len * rows];
+		for (src, dst) in image.data_bytes()?.chunks_exact(cols).zip(bits.chunks_exact_mut(row_len)) {
+			for (x, &px) in src.iter().enumerate() {
+				if px >= 128 {
+					dst[x / 8] |= 0x80 >> (x % 8);
+				}
+			}
+		}
+		let width = cols as f64 / self.dpi * PT_PER_INCH;
+		let height = rows as f64 / self.dpi * PT_PER_INCH;
+		let content = format!("q {:.3} 0 0 {:.3} 0 0 cm /Im0 Do Q\n", width, height);
+
+		let mut out = b"%PDF-1.4\n".to_vec();
+		let mut offsets = Vec::with_capacity(5);
+		let mut object = |out: &mut Vec<u8>, dict: String, stream: Option<&[u8]>| {
+			offsets.push(out.len());
+			let _ = write!(out, "{} 0 obj\n{}\n", offsets.len(), dict);
+			if let Some(stream) = stream {
+				out.extend_from_slice(b"stream\n");
+				out.extend_from_slice(stream);
+				out.extend_from_slice(b"\nendstream\n");
+			}
+			out.extend_from_slice(b"endobj\n");
+		};
+		object(&mut out, "<< /Type /Catalog /Pages 2 0 R >>".to_string(), None);
+		object(&mut out, "<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_string(), None);
+		object(
+			&mut out,
+			format!("<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {:.3} {:.3}] /Resources << /XObject << /Im0 4 0 R >> >> /Contents 5 0 R >>", width, height),
+			None,
+		);
+		object(
+			&mut out,
+			format!("<< /Type /XObject /Subtype /Image /Width {} /Height {} /ColorSpace /DeviceGray /BitsPerComponent 1 /Length {} >>", cols, rows, bits.len()),
+			Some(&bits),
+		);
+		object(&mut out, format!("<< /Length {} >>", content.len()), Some(content.as_bytes()));
+
+		let xref = out.len();
+		let _ = write!(out, "xref\n0 {}\n0000000000 65535 f \n", offsets.len() + 1);
+		for offset in &offsets {
+			let _ = writeln!(out, "{:010} 00000 n ", offset);
+		}
+		let _ = write!(out, "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n", offsets.len() + 1, xref);
+		Ok(out)
+	}
+}
@@ -0,0 +1,253 @@
+use std::{
+	env,
+	sync::atomic::{AtomicUsize, Ordering},
+};
+
+use crate::{
+	ccalib::{self, MultiCameraCalibration, RandomPatternGenerator},
+	core::{self, FileNode, FileStorage, FileStorage_Mode, Mat, Range, Size, Size2f, TermCriteria},
+	Error,
+	features2d::{AKAZE, AKAZE_DescriptorType, DescriptorMatcher, KAZE_DiffusivityType},
+	prelude::*,
+	Result,
+};
+
+/// Projection model of the rig cameras
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RigCameraModel {
+	Pinhole,
+	/// Mei's unified model with the `xi` parameter, see `ccalib::calibrate()`
+	Omnidirectional,
+}
+
+impl RigCameraModel {
+	fn camera_type(self) -> i32 {
+		match self {
+			RigCameraModel::Pinhole => ccalib::MultiCameraCalibration_PINHOLE,
+			RigCameraModel::Omnidirectional => ccalib::MultiCameraCalibration_OMNIDIRECTIONAL,
+		}
+	}
+}
+
+/// Calibrates the intrinsics of every camera of the rig and their relative poses with `MultiCameraCalibration`
+///
+/// All cameras observe the random pattern (see [generate_random_pattern]). The `image_list` is the OpenCV XML or YAML
+/// file with the list of image paths named `cameraIdx-timestamp.*`, the images with the same timestamp are the pattern
+/// photographed at once by several cameras. Features are detected with AKAZE as in the C++ defaults.
+/// ```no_run
+/// # use opencv::{ccalib::{RigCalibrator, RigCameraModel}, core::Size2f};
+/// let rig = RigCalibrator::new(RigCameraModel::Pinhole, 3, "images.xml", Size2f::new(0.8, 0.6)).run()?;
+/// println!("{} {:?}", rig.reprojection_error, rig.cameras[1].t);
+/// # Ok::<_, opencv::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct RigCalibrator {
+	model: RigCameraModel,
+	n_cameras: usize,
+	image_list: String,
+	pattern_size: Size2f,
+	min_matches: i32,
+	flags: i32,
+	criteria: TermCriteria,
+	verbose: bool,
+}
+
+impl RigCalibrator {
+	/// `pattern_size` is the physical size of the printed pattern, the translations are in the same units
+	pub fn new(model: RigCameraModel, n_cameras: usize, image_list: impl Into<String>, pattern_size: Size2f) -> Self {
+		Self {
+			model,
+			n_cameras,
+			image_list: image_list.into(),
+			pattern_size,
+			min_matches: 20,
+			flags: 0,
+			criteria: TermCriteria::both(200, 1e-7),
+			verbose: false,
+		}
+	}
+
+	/// Sets the minimum number of the pattern feature matches for the image to be used, 20 by default
+	pub fn with_min_matches(mut self, min_matches: i32) -> Self {
+		self.min_matches = min_matches;
+		self
+	}
+
+	/// Sets the `ccalib::CALIB_*` flags of the single camera calibration
+	pub fn with_flags(mut self, flags: i32) -> Self {
+		self.flags = flags;
+		self
+	}
+
+	pub fn with_criteria(mut self, criteria: TermCriteria) -> Self {
+		self.criteria = criteria;
+		self
+	}
+
+	/// Prints the progress of the calibration to stdout
+	pub fn with_verbose(mut self, verbose: bool) -> Self {
+		self.verbose = verbose;
+		self
+	}
+
+	pub fn run(&self) -> Result<RigCalibration> {
+		if self.n_cameras == 0 {
+			return Err(Error::new(core::StsBadArg, "Rig must have at least one camera"));
+		}
+		let akaze = || -> Result<core::Ptr<crate::features2d::Feature2D>> {
+			Ok(<dyn AKAZE>::create(AKAZE_DescriptorType::DESCRIPTOR_MLDB, 0, 3, 0.006, 4, 4, KAZE_DiffusivityType::DIFF_PM_G2)?.into())
+		};
+		let mut calibration = MultiCameraCalibration::new(
+			self.model.camera_type(),
+			self.n_cameras as i32,
+			&self.image_list,
+			self.pattern_size.width,
+			self.pattern_size.height,
+			i32::from(self.verbose),
+			0,
+			self.min_matches,
+			self.flags,
+			self.criteria,
+			akaze()?,
+			akaze()?,
+			<dyn DescriptorMatcher>::create("BruteForce-L1")?,
+		)?;
+		calibration.run()?;
+		// the results are only accessible through the written parameters
+		static COUNTER: AtomicUsize = AtomicUsize::new(0);
+		let path = env::temp_dir().join(format!("opencv-rig-{}-{}.yml", std::process::id(), COUNTER.fetch_add(1, Ordering::Relaxed)));
+		let path = path.to_str().ok_or_else(|| Error::new(core::StsError, "Temporary directory path is not valid UTF-8"))?;
+		calibration.write_parameters(path)?;
+		let out = RigCalibration::load(path);
+		let _ = std::fs::remove_file(path);
+		out
+	}
+}
+
+/// Intrinsics and pose of a single rig camera
+#[derive(Debug)]
+pub struct RigCamera {
+	/// `3x3` camera matrix
+	pub camera_matrix: Mat,
+	/// Distortion coefficients `k1, k2, p1, p2`
+	pub dist_coeffs: Mat,
+	/// Parameter of the omnidirectional model, `None` for pinhole cameras
+	pub xi: Option<f64>,
+	/// `3x3` rotation from the first camera coordinates to this camera coordinates
+	pub r: Mat,
+	/// `3x1` translation from the first camera coordinates to this camera coordinates
+	pub t: Mat,
+}
+
+impl RigCamera {
+	/// Returns the pose in the form used by the sfm module, e.g. to seed the reconstruction with the known rig geometry
+	#[cfg(ocvrs_has_module_sfm)]
+	pub fn camera_pose(&self) -> Result<crate::sfm::CameraPose> {
+		Ok(crate::sfm::CameraPose { r: self.r.try_clone()?, t: self.t.try_clone()?, inliers: vec![] })
+	}
+
+	/// Returns the intrinsics for the sfm module, only pinhole cameras are supported
+	#[cfg(ocvrs_has_module_sfm)]
+	pub fn intrinsics(&self, image_size: Size) -> Result<crate::sfm::libmv_CameraIntrinsicsOptions> {
+		if self.xi.is_some() {
+			return Err(Error::new(core::StsNotImplemented, "Omnidirectional cameras can't be represented by libmv intrinsics"));
+		}
+		let k = |row, col| -> Result<f64> { Ok(*self.camera_matrix.at_2d::<f64>(row, col)?) };
+		let d = to_f64_values(&self.dist_coeffs)?;
+		let d = |i: usize| d.get(i).copied().unwrap_or(0.);
+		Ok(crate::sfm::libmv_CameraIntrinsicsOptions {
+			distortion_model: crate::sfm::SFM_DISTORTION_MODEL_POLYNOMIAL,
+			image_width: image_size.width,
+			image_height: image_size.height,
+			focal_length_x: k(0, 0)?,
+			focal_length_y: k(1, 1)?,
+			principal_point_x: k(0, 2)?,
+			principal_point_y: k(1, 2)?,
+			polynomial_k1: d(0),
+			polynomial_k2: d(1),
+			polynomial_k3: d(4),
+			polynomial_p1: d(2),
+			polynomial_p2: d(3),
+			division_k1: 0.,
+			division_k2: 0.,
+		})
+	}
+}
+
+fn to_f64_values(mat: &Mat) -> Result<Vec<f64>> {
+	let mut converted = Mat::default();
+	mat.convert_to(&mut converted, f64::typ(), 1., 0.)?;
+	let converted = if converted.is_continuous() { converted } else { converted.try_clone()? };
+	Ok(converted.data_typed::<f64>()?.to_vec())
+}
+
+/// Result of the [RigCalibrator]
+#[derive(Debug)]
+pub struct RigCalibration {
+	/// Cameras in the order of their indices, the first camera is the rig origin
+	pub cameras: Vec<RigCamera>,
+	pub reprojection_error: f64,
+}
+
+impl RigCalibration {
+	/// Reads the parameters written by `MultiCameraCalibration::write_parameters()`
+	pub fn load(filename: &str) -> Result<Self> {
+		let fs = FileStorage::new(filename, FileStorage_Mode::READ as i32, "")?;
+		if !fs.is_opened()? {
+			return Err(Error::new(core::StsError, format!("Can't open rig parameters: {}", filename)));
+		}
+		let n_cameras = required(&fs, "nCameras")?.to_i32()?;
+		let cameras = (0..n_cameras)
+			.map(|i| {
+				let xi = fs.get(&format!("xi_{}", i))?;
+				let pose = required(&fs, &format!("camera_pose_{}", i))?.mat()?;
+				if pose.rows() != 4 || pose.cols() != 4 {
+					return Err(Error::new(core::StsParseError, format!("Camera pose: {} must be 4x4", i)));
+				}
+				let mut r = Mat::default();
+				let mut t = Mat::default();
+				Mat::rowscols(&pose, &Range::new(0, 3)?, &Range::new(0, 3)?)?.convert_to(&mut r, f64::typ(), 1., 0.)?;
+				Mat::rowscols(&pose, &Range::new(0, 3)?, &Range::new(3, 4)?)?.convert_to(&mut t, f64::typ(), 1., 0.)?;
+				Ok(RigCamera {
+					camera_matrix: required(&fs, &format!("camera_matrix_{}", i))?.mat()?,
+					dist_coeffs: required(&fs, &format!("camera_distortion_{}", i))?.mat()?,
+					xi: if xi.is_none()? { None } else { Some(xi.real()?) },
+					r,
+					t,
+				})
+			})
+			.collect::<Result<Vec<_>>>()?;
+		let error = fs.get("meanReprojectError")?;
+		Ok(Self { cameras, reprojection_error: if error.is_none()? { 0. } else { error.real()? } })
+	}
+
+	/// Returns the pose of the camera `to` relative to the camera `from` as the `3x3` rotation and `3x1` translation
+	pub fn relative_pose(&self, from: usize, to: usize) -> Result<(Mat, Mat)> {
+		let camera = |i: usize| self.cameras.get(i)
+			.ok_or_else(|| Error::new(core::StsOutOfRange, format!("Camera index: {} is out of range, rig has {} cameras", i, self.cameras.len())));
+		let (from, to) = (camera(from)?, camera(to)?);
+		// x_to = r_to * r_from^T * (x_from - t_from) + t_to
+		let mut r = Mat::default();
+		core::gemm(&to.r, &from.r, 1., &core::no_array(), 0., &mut r, core::GEMM_2_T)?;
+		let mut t = Mat::default();
+		core::gemm(&r, &from.t, -1., &to.t, 1., &mut t, 0)?;
+		Ok((r, t))
+	}
+}
+
+fn required(fs: &FileStorage, name: &str) -> Result<FileNode> {
+	let out = fs.get(name)?;
+	if out.is_none()? {
+		Err(Error::new(core::StsParseError, format!("Rig parameters are missing required field: {}", name)))
+	} else {
+		Ok(out)
+	}
+}
+
+/// Generates the random pattern image for the [RigCalibrator] to print
+pub fn generate_random_pattern(size: Size) -> Result<Mat> {
+	let mut generator = RandomPatternGenerator::new(size.width, size.height)?;
+	generator.generate_pattern()?;
+	generator.get_pattern()
+}
+
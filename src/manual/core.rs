@@ -1,34 +1,60 @@
 pub use affine3::*;
+pub use batch::*;
 pub use CV_MAKETYPE as CV_MAKE_TYPE;
+pub use coord_map::*;
 pub use data_type::*;
 pub use gpumat::*;
 pub use input_output_array::*;
 pub use mat::*;
 pub use mat_ops::*;
 pub use matx::*;
+#[cfg(feature = "mmap")]
+pub use mmap_mat::*;
+pub use output_mat::*;
 pub use point::*;
 pub use point3::*;
 pub use ptr::*;
 pub use rect::*;
 pub use scalar::*;
+pub use serial_context::{Serial, SerialContext};
+pub use similarity::*;
 pub use size::*;
 pub use sized::*;
+pub use typed_results::*;
 pub use vec::*;
 pub use vector::*;
 
 mod affine3;
+pub mod alloc_tracking;
+mod batch;
+mod coord_map;
 mod data_type;
+pub mod decomposition;
 mod gpumat;
 mod input_output_array;
+pub mod linalg;
 mod mat;
 mod mat_ops;
 mod matx;
+#[cfg(feature = "mmap")]
+mod mmap_mat;
+pub mod npy;
+mod output_mat;
 mod point3;
 mod point;
 pub(crate) mod ptr;
+mod range;
 mod rect;
 mod scalar;
+pub mod serial_context;
+mod similarity;
+#[cfg(feature = "simd")]
+pub mod simd;
 mod size;
 mod sized;
+pub mod stats;
+mod term_criteria;
+mod typed_results;
+pub mod validity;
 mod vec;
 mod vector;
@@ -7,7 +7,7 @@ use crate::{
 
 /// [docs.opencv.org](https://docs.opencv.org/master/dd/d99/classcv_1_1Affine3.html)
 #[repr(C)]
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug)]
 pub struct Affine3<T: Copy> {
 	pub matrix: Matx44<T>,
 }
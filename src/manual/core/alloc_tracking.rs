@@ -0,0 +1,132 @@
+//! Live object accounting for hunting leaks in long-running processes
+//!
+//! When the `alloc-tracking` feature is enabled every boxed OpenCV object (`Mat`, `UMat`, algorithm classes, etc.) is
+//! registered on creation and unregistered on drop, so [live_objects] and [dump_live_objects] report what is currently
+//! alive on the Rust side. Byte counts come from the OpenCV allocator statistics and are available regardless of the
+//! feature (OpenCV 3.4.4+ built with `OPENCV_ENABLE_ALLOCATOR_STATS`, which is the default).
+
+use std::ffi::c_void;
+#[cfg(feature = "alloc-tracking")]
+use std::{
+	collections::{BTreeMap, HashMap},
+	sync::Mutex,
+};
+
+#[cfg(feature = "alloc-tracking")]
+use once_cell::sync::Lazy;
+
+/// Statistics of the OpenCV allocator, must match the layout of `ocvrs_AllocatorStats` in `manual-core.cpp`
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocatorStats {
+	/// Bytes currently allocated by OpenCV (e.g. `Mat` data)
+	pub current_bytes: u64,
+	/// Maximum of `current_bytes` since the start or the last [reset_peak_bytes]
+	pub peak_bytes: u64,
+	/// Total bytes allocated since the start
+	pub total_bytes: u64,
+	/// Total number of allocations since the start
+	pub allocations: u64,
+}
+
+/// Number of live objects of a single type
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LiveObjects {
+	/// Full path of the Rust type as returned by `std::any::type_name()`, e.g. `opencv::core::Mat`
+	pub type_name: &'static str,
+	pub count: usize,
+}
+
+#[cfg(feature = "alloc-tracking")]
+static LIVE: Lazy<Mutex<HashMap<usize, &'static str>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Returns the current OpenCV allocator statistics, all values are 0 if OpenCV doesn't support them
+pub fn allocator_stats() -> AllocatorStats {
+	extern "C" { fn cv_manual_getAllocatorStatistics(ocvrs_return: *mut AllocatorStats); }
+	let mut out = AllocatorStats::default();
+	unsafe { ffi_call!(local cv_manual_getAllocatorStatistics(&mut out)) };
+	out
+}
+
+/// Resets `peak_bytes` of [AllocatorStats] to the current usage
+pub fn reset_peak_bytes() {
+	extern "C" { fn cv_manual_resetAllocatorPeakUsage(); }
+	unsafe { ffi_call!(local cv_manual_resetAllocatorPeakUsage()) }
+}
+
+/// Returns the number of live objects grouped by type, sorted by type name
+///
+/// Always empty without the `alloc-tracking` feature.
+pub fn live_objects() -> Vec<LiveObjects> {
+	#[cfg(feature = "alloc-tracking")]
+	{
+		let mut counts = BTreeMap::new();
+		for &type_name in LIVE.lock().expect("Can't lock live objects").values() {
+			*counts.entry(type_name).or_insert(0) += 1;
+		}
+		counts.into_iter()
+			.map(|(type_name, count)| LiveObjects { type_name, count })
+			.collect()
+	}
+	#[cfg(not(feature = "alloc-tracking"))]
+	{
+		vec![]
+	}
+}
+
+/// Returns the total number of live objects, always 0 without the `alloc-tracking` feature
+pub fn live_object_count() -> usize {
+	#[cfg(feature = "alloc-tracking")]
+	{
+		LIVE.lock().expect("Can't lock live objects").len()
+	}
+	#[cfg(not(feature = "alloc-tracking"))]
+	{
+		0
+	}
+}
+
+/// Returns a human readable report of the live objects and the allocator statistics
+pub fn dump_live_objects() -> String {
+	let stats = allocator_stats();
+	let mut out = format!(
+		"OpenCV allocator: current: {} bytes, peak: {} bytes, total: {} bytes in {} allocations\n",
+		stats.current_bytes,
+		stats.peak_bytes,
+		stats.total_bytes,
+		stats.allocations,
+	);
+	if cfg!(feature = "alloc-tracking") {
+		out += &format!("Live objects: {}\n", live_object_count());
+		for LiveObjects { type_name, count } in live_objects() {
+			out += &format!("  {}: {}\n", type_name, count);
+		}
+	} else {
+		out += "Live object tracking is disabled, enable `alloc-tracking` feature\n";
+	}
+	out
+}
+
+/// Registers the newly wrapped object, called from `Boxed::from_raw()`
+#[doc(hidden)]
+#[inline]
+pub fn track_alloc(type_name: &'static str, ptr: *const c_void) {
+	#[cfg(feature = "alloc-tracking")]
+	if !ptr.is_null() {
+		LIVE.lock().expect("Can't lock live objects").insert(ptr as usize, type_name);
+	}
+	#[cfg(not(feature = "alloc-tracking"))]
+	let _ = (type_name, ptr);
+}
+
+/// Unregisters the object that is dropped or released with `Boxed::into_raw()`
+#[doc(hidden)]
+#[inline]
+pub fn track_release(ptr: *const c_void) {
+	#[cfg(feature = "alloc-tracking")]
+	if !ptr.is_null() {
+		LIVE.lock().expect("Can't lock live objects").remove(&(ptr as usize));
+	}
+	#[cfg(not(feature = "alloc-tracking"))]
+	let _ = ptr;
+}
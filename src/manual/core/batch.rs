@@ -0,0 +1,114 @@
+use std::ffi::c_void;
+
+use crate::{
+	core::{Mat, Size},
+	platform_types::size_t,
+	prelude::*,
+	Result,
+	sys,
+};
+
+/// Kind of the operation recorded in the [Batch], must match `OCVRS_BATCH_*` constants in `manual-core.cpp`
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum BatchOpKind {
+	CvtColor = 0,
+	Resize = 1,
+	Normalize = 2,
+	ConvertTo = 3,
+}
+
+/// Single recorded operation, must match the layout of `ocvrs_BatchOp` in `manual-core.cpp`
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct BatchOp {
+	kind: BatchOpKind,
+	/// `code` for `cvt_color`, `interpolation` for `resize`, `norm_type` for `normalize`
+	flag: i32,
+	/// `dst_cn` for `cvt_color`, `dtype` for `normalize`, `rtype` for `convert_to`
+	typ: i32,
+	size: Size,
+	/// `fx` for `resize`, `alpha` for `normalize` and `convert_to`
+	a: f64,
+	/// `fy` for `resize`, `beta` for `normalize` and `convert_to`
+	b: f64,
+}
+
+/// Sequence of simple operations that is executed within a single FFI call
+///
+/// Each call into OpenCV has a fixed overhead that becomes noticeable when processing a lot of small images, recording
+/// the pipeline once and then running it for every frame avoids that overhead and the intermediate `Mat` allocations on
+/// the Rust side:
+/// ```ignore
+/// let mut batch = core::Batch::new();
+/// batch.cvt_color(imgproc::COLOR_BGR2RGB, 0)
+///     .resize(Size::new(224, 224), 0., 0., imgproc::INTER_LINEAR)
+///     .convert_to(f32::typ(), 1. / 255., 0.);
+/// batch.run(&frame, &mut blob)?;
+/// ```
+/// `cvt_color` and `resize` require `imgproc` module, running a batch containing them without it produces an error.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Batch {
+	ops: Vec<BatchOp>,
+}
+
+impl Batch {
+	#[inline]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Records `imgproc::cvt_color(src, dst, code, dst_cn)`
+	pub fn cvt_color(&mut self, code: i32, dst_cn: i32) -> &mut Self {
+		self.push(BatchOpKind::CvtColor, code, dst_cn, Size::default(), 0., 0.)
+	}
+
+	/// Records `imgproc::resize(src, dst, dsize, fx, fy, interpolation)`
+	pub fn resize(&mut self, dsize: impl Into<Size>, fx: f64, fy: f64, interpolation: i32) -> &mut Self {
+		self.push(BatchOpKind::Resize, interpolation, 0, dsize.into(), fx, fy)
+	}
+
+	/// Records `core::normalize(src, dst, alpha, beta, norm_type, dtype, &no_array())`
+	pub fn normalize(&mut self, alpha: f64, beta: f64, norm_type: i32, dtype: i32) -> &mut Self {
+		self.push(BatchOpKind::Normalize, norm_type, dtype, Size::default(), alpha, beta)
+	}
+
+	/// Records `src.convert_to(dst, rtype, alpha, beta)`
+	pub fn convert_to(&mut self, rtype: i32, alpha: f64, beta: f64) -> &mut Self {
+		self.push(BatchOpKind::ConvertTo, 0, rtype, Size::default(), alpha, beta)
+	}
+
+	/// Number of the recorded operations
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.ops.len()
+	}
+
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.ops.is_empty()
+	}
+
+	/// Removes all recorded operations
+	#[inline]
+	pub fn clear(&mut self) {
+		self.ops.clear()
+	}
+
+	/// Executes the recorded operations on `src` storing the final result into `dst`
+	///
+	/// Empty batch copies `src` into `dst`. Intermediate results are kept on the C++ side and `dst` is reused if it
+	/// already has the matching size and type.
+	pub fn run(&self, src: &Mat, dst: &mut Mat) -> Result<()> {
+		extern "C" { fn cv_manual_Batch_run(src: *const c_void, dst: *mut c_void, ops: *const BatchOp, ops_count: size_t, ocvrs_return: *mut sys::Result_void); }
+		return_send!(via ocvrs_return);
+		unsafe { ffi_call!(local cv_manual_Batch_run(src.as_raw_Mat(), dst.as_raw_mut_Mat(), self.ops.as_ptr(), self.ops.len(), ocvrs_return.as_mut_ptr())) };
+		return_receive!(unsafe ocvrs_return => ret);
+		ret.into_result()
+	}
+
+	fn push(&mut self, kind: BatchOpKind, flag: i32, typ: i32, size: Size, a: f64, b: f64) -> &mut Self {
+		self.ops.push(BatchOp { kind, flag, typ, size, a, b });
+		self
+	}
+}
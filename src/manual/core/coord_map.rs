@@ -0,0 +1,107 @@
+use crate::core::{Point2f, Rect, Rect2f};
+
+/// Axis-aligned scale and offset mapping the original image coordinates into the coordinates of a processed
+/// (resized, letterboxed, cropped) frame: `processed = original * scale + offset`
+///
+/// Maps are composable, so the detection results on the frame that went through several steps can be mapped back with
+/// a single call:
+/// ```ignore
+/// let to_input = crop.transform.then(&letterbox.transform);
+/// let rect_in_original = to_input.invert_rect(detection);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CoordMap {
+	pub scale_x: f64,
+	pub scale_y: f64,
+	pub offset_x: f64,
+	pub offset_y: f64,
+}
+
+impl Default for CoordMap {
+	#[inline]
+	fn default() -> Self {
+		Self::identity()
+	}
+}
+
+impl CoordMap {
+	#[inline]
+	pub const fn new(scale_x: f64, scale_y: f64, offset_x: f64, offset_y: f64) -> Self {
+		Self { scale_x, scale_y, offset_x, offset_y }
+	}
+
+	#[inline]
+	pub const fn identity() -> Self {
+		Self::new(1., 1., 0., 0.)
+	}
+
+	#[inline]
+	pub const fn scale(scale_x: f64, scale_y: f64) -> Self {
+		Self::new(scale_x, scale_y, 0., 0.)
+	}
+
+	#[inline]
+	pub const fn translate(offset_x: f64, offset_y: f64) -> Self {
+		Self::new(1., 1., offset_x, offset_y)
+	}
+
+	/// Map into the region of interest `roi` of the original image
+	#[inline]
+	pub fn crop(roi: impl Into<Rect>) -> Self {
+		let roi = roi.into();
+		Self::translate(-f64::from(roi.x), -f64::from(roi.y))
+	}
+
+	/// Returns the map that applies `self` first and then `next`
+	#[inline]
+	pub fn then(&self, next: &CoordMap) -> Self {
+		Self::new(
+			self.scale_x * next.scale_x,
+			self.scale_y * next.scale_y,
+			self.offset_x * next.scale_x + next.offset_x,
+			self.offset_y * next.scale_y + next.offset_y,
+		)
+	}
+
+	/// Returns the map from the processed coordinates back to the original ones
+	#[inline]
+	pub fn inverse(&self) -> Self {
+		Self::new(1. / self.scale_x, 1. / self.scale_y, -self.offset_x / self.scale_x, -self.offset_y / self.scale_y)
+	}
+
+	/// Maps the point from the original image into the processed one
+	#[inline]
+	pub fn apply(&self, pt: Point2f) -> Point2f {
+		Point2f::new(
+			(f64::from(pt.x) * self.scale_x + self.offset_x) as f32,
+			(f64::from(pt.y) * self.scale_y + self.offset_y) as f32,
+		)
+	}
+
+	/// Maps the point from the processed image back into the original one
+	#[inline]
+	pub fn invert(&self, pt: Point2f) -> Point2f {
+		self.inverse().apply(pt)
+	}
+
+	/// Maps the rectangle from the original image into the processed one
+	pub fn apply_rect(&self, rect: Rect2f) -> Rect2f {
+		let tl = self.apply(Point2f::new(rect.x, rect.y));
+		Rect2f::new(tl.x, tl.y, (f64::from(rect.width) * self.scale_x) as f32, (f64::from(rect.height) * self.scale_y) as f32)
+	}
+
+	/// Maps the rectangle from the processed image back into the original one
+	#[inline]
+	pub fn invert_rect(&self, rect: Rect2f) -> Rect2f {
+		self.inverse().apply_rect(rect)
+	}
+
+	/// Maps the integer rectangle from the processed image back into the original one, the result is the smallest
+	/// integer rectangle containing the mapped one
+	pub fn invert_rect_i(&self, rect: Rect) -> Rect {
+		let mapped = self.invert_rect(Rect2f::new(rect.x as f32, rect.y as f32, rect.width as f32, rect.height as f32));
+		let (x1, y1) = (mapped.x.floor() as i32, mapped.y.floor() as i32);
+		let (x2, y2) = ((mapped.x + mapped.width).ceil() as i32, (mapped.y + mapped.height).ceil() as i32);
+		Rect::new(x1, y1, x2 - x1, y2 - y1)
+	}
+}
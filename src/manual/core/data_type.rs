@@ -6,6 +6,12 @@ pub const fn CV_MAT_DEPTH(flags: i32) -> i32 {
 	flags & crate::core::Mat_DEPTH_MASK
 }
 
+#[inline]
+pub const fn CV_MAT_CN(flags: i32) -> i32 {
+	#![allow(non_snake_case)]
+	((flags & crate::core::CV_MAT_CN_MASK) >> crate::core::CV_CN_SHIFT) + 1
+}
+
 #[inline]
 pub const fn CV_MAKETYPE(depth: i32, cn: i32) -> i32 {
 	#![allow(non_snake_case)]
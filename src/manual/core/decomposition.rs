@@ -0,0 +1,379 @@
+//! Fitted PCA, LDA and SVD decompositions with projection, explained variance and serialization
+//!
+//! The samples are always expected one per row:
+//! ```no_run
+//! # use opencv::{core::{Mat, decomposition::Pca}, Result};
+//! # fn faces(samples: &Mat, face: &Mat) -> Result<()> {
+//! // keep the components explaining 95% of the variance, e.g. for eigenfaces
+//! let pca = Pca::fit_variance(samples, 0.95)?;
+//! println!("{} components: {:?}", pca.component_count(), pca.explained_variance_ratio()?);
+//! let weights = pca.project(face)?;
+//! let reconstructed = pca.back_project(&weights)?;
+//! pca.save("eigenfaces.yml")?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+	core::{self, FileNode, FileStorage, FileStorage_Mode, LDA, Mat, PCA, PCA_Flags, SVD},
+	Error,
+	prelude::*,
+	Result,
+};
+
+fn open_write(filename: &str) -> Result<FileStorage> {
+	FileStorage::new(filename, FileStorage_Mode::WRITE as i32, "")
+}
+
+fn open_write_memory() -> Result<FileStorage> {
+	FileStorage::new(".yml", FileStorage_Mode::WRITE as i32 | FileStorage_Mode::MEMORY as i32, "")
+}
+
+/// Opens the storage for reading and returns its `name` top-level node
+fn open_read(source: &str, memory: bool, name: &str) -> Result<(FileStorage, FileNode)> {
+	let mode = if memory { FileStorage_Mode::READ as i32 | FileStorage_Mode::MEMORY as i32 } else { FileStorage_Mode::READ as i32 };
+	let fs = FileStorage::new(source, mode, "")?;
+	if !fs.is_opened()? {
+		return Err(Error::new(core::StsError, format!("Can't open {} storage", name)));
+	}
+	let node = fs.get_node(name)?;
+	if node.is_none()? {
+		return Err(Error::new(core::StsParseError, format!("Input doesn't contain {} decomposition", name)));
+	}
+	Ok((fs, node))
+}
+
+fn required_mat(node: &FileNode, name: &str) -> Result<Mat> {
+	let out = node.get(name)?;
+	if out.is_none()? {
+		Err(Error::new(core::StsParseError, format!("Decomposition is missing required field: {}", name)))
+	} else {
+		out.mat()
+	}
+}
+
+fn to_f64_values(mat: &Mat) -> Result<Vec<f64>> {
+	let mut converted = Mat::default();
+	mat.convert_to(&mut converted, f64::typ(), 1., 0.)?;
+	Ok(converted.data_typed::<f64>()?.to_vec())
+}
+
+fn ratios(values: &[f64], total: f64) -> Vec<f64> {
+	values.iter().map(|v| v / total).collect()
+}
+
+fn check_samples(samples: &Mat) -> Result<()> {
+	if samples.empty() || samples.dims() != 2 || samples.channels() != 1 {
+		return Err(Error::new(core::StsBadArg, "Samples must be a non-empty single channel 2D matrix with one sample per row"));
+	}
+	Ok(())
+}
+
+/// Principal component analysis fitted on the samples
+pub struct Pca {
+	pca: PCA,
+	/// Sum of the variances of all the input dimensions, needed for the ratio of the truncated components
+	total_variance: f64,
+}
+
+impl Pca {
+	/// Fits the PCA keeping at most `max_components` components, `0` keeps all of them
+	pub fn fit(samples: &Mat, max_components: i32) -> Result<Self> {
+		check_samples(samples)?;
+		let pca = PCA::new(samples, &core::no_array(), PCA_Flags::DATA_AS_ROW as i32, max_components)?;
+		Self::from_pca(pca, samples)
+	}
+
+	/// Fits the PCA keeping the smallest number of components that retains `retained_variance` (0..1) of the variance
+	pub fn fit_variance(samples: &Mat, retained_variance: f64) -> Result<Self> {
+		check_samples(samples)?;
+		let pca = PCA::new_with_variance(samples, &core::no_array(), PCA_Flags::DATA_AS_ROW as i32, retained_variance)?;
+		Self::from_pca(pca, samples)
+	}
+
+	fn from_pca(pca: PCA, samples: &Mat) -> Result<Self> {
+		let mut samples_f64 = Mat::default();
+		samples.convert_to(&mut samples_f64, f64::typ(), 1., 0.)?;
+		let mut mean = Mat::default();
+		pca.mean().convert_to(&mut mean, f64::typ(), 1., 0.)?;
+		let mut sum = 0.;
+		for row in 0..samples_f64.rows() {
+			sum += core::norm2(&samples_f64.row(row)?, &mean, core::NORM_L2SQR, &core::no_array())?;
+		}
+		Ok(Self { pca, total_variance: sum / f64::from(samples_f64.rows()) })
+	}
+
+	/// Projects the samples (one per row) into the principal component subspace
+	#[inline]
+	pub fn project(&self, samples: &Mat) -> Result<Mat> {
+		self.pca.project(samples)
+	}
+
+	/// Reconstructs the samples from their projections
+	#[inline]
+	pub fn back_project(&self, projections: &Mat) -> Result<Mat> {
+		self.pca.back_project(projections)
+	}
+
+	#[inline]
+	pub fn component_count(&self) -> i32 {
+		self.pca.eigenvectors().rows()
+	}
+
+	/// Principal components, one per row
+	#[inline]
+	pub fn components(&self) -> Mat {
+		self.pca.eigenvectors()
+	}
+
+	#[inline]
+	pub fn mean(&self) -> Mat {
+		self.pca.mean()
+	}
+
+	/// Variance along every kept component (eigenvalues of the covariance matrix) in decreasing order
+	#[inline]
+	pub fn explained_variance(&self) -> Result<Vec<f64>> {
+		to_f64_values(&self.pca.eigenvalues())
+	}
+
+	/// Fraction of the total variance explained by every kept component
+	pub fn explained_variance_ratio(&self) -> Result<Vec<f64>> {
+		Ok(ratios(&self.explained_variance()?, self.total_variance))
+	}
+
+	/// Underlying OpenCV object
+	#[inline]
+	pub fn as_pca(&self) -> &PCA {
+		&self.pca
+	}
+
+	fn write(&self, fs: &mut FileStorage) -> Result<()> {
+		fs.start_write_struct("pca", core::FileNode_MAP, "")?;
+		self.pca.write(fs)?;
+		fs.write_f64("total_variance", self.total_variance)?;
+		fs.end_write_struct()
+	}
+
+	fn read(node: &FileNode) -> Result<Self> {
+		let mut pca = PCA::default()?;
+		pca.read(node)?;
+		if pca.eigenvectors().empty() {
+			return Err(Error::new(core::StsParseError, "Decomposition is missing required field: vectors"));
+		}
+		Ok(Self { pca, total_variance: node.get("total_variance")?.real()? })
+	}
+
+	pub fn save(&self, filename: &str) -> Result<()> {
+		let mut fs = open_write(filename)?;
+		self.write(&mut fs)?;
+		fs.release()
+	}
+
+	pub fn save_to_string(&self) -> Result<String> {
+		let mut fs = open_write_memory()?;
+		self.write(&mut fs)?;
+		fs.release_and_get_string()
+	}
+
+	pub fn load(filename: &str) -> Result<Self> {
+		Self::read(&open_read(filename, false, "pca")?.1)
+	}
+
+	pub fn load_from_str(s: &str) -> Result<Self> {
+		Self::read(&open_read(s, true, "pca")?.1)
+	}
+}
+
+/// Linear discriminant analysis fitted on the labeled samples
+pub struct Lda {
+	eigenvalues: Mat,
+	eigenvectors: Mat,
+}
+
+impl Lda {
+	/// Fits the LDA on the `samples` (one per row) with one integer label per sample, `num_components` of `0` keeps
+	/// `class count - 1` components
+	pub fn fit(samples: &Mat, labels: &Mat, num_components: i32) -> Result<Self> {
+		check_samples(samples)?;
+		if labels.total() != samples.rows() as usize {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("Number of labels: {} doesn't match the number of samples: {}", labels.total(), samples.rows())));
+		}
+		let lda = LDA::new_with_data(samples, labels, num_components)?;
+		Ok(Self { eigenvalues: lda.eigenvalues()?, eigenvectors: lda.eigenvectors()? })
+	}
+
+	/// Projects the samples (one per row) into the discriminant subspace
+	#[inline]
+	pub fn project(&self, samples: &Mat) -> Result<Mat> {
+		LDA::subspace_project(&self.eigenvectors, &core::no_array(), samples)
+	}
+
+	/// Maps the projections back into the input space
+	#[inline]
+	pub fn back_project(&self, projections: &Mat) -> Result<Mat> {
+		LDA::subspace_reconstruct(&self.eigenvectors, &core::no_array(), projections)
+	}
+
+	#[inline]
+	pub fn component_count(&self) -> i32 {
+		self.eigenvectors.cols()
+	}
+
+	/// Discriminant directions, one per column
+	#[inline]
+	pub fn components(&self) -> &Mat {
+		&self.eigenvectors
+	}
+
+	/// Eigenvalues of the discriminant directions, i.e. the ratio of the between-class to within-class variance
+	#[inline]
+	pub fn explained_variance(&self) -> Result<Vec<f64>> {
+		to_f64_values(&self.eigenvalues)
+	}
+
+	/// Share of the discriminative power of every kept component
+	pub fn explained_variance_ratio(&self) -> Result<Vec<f64>> {
+		let values = self.explained_variance()?;
+		let total = values.iter().sum();
+		Ok(ratios(&values, total))
+	}
+
+	fn write(&self, fs: &mut FileStorage) -> Result<()> {
+		fs.start_write_struct("lda", core::FileNode_MAP, "")?;
+		fs.write_mat("eigenvalues", &self.eigenvalues)?;
+		fs.write_mat("eigenvectors", &self.eigenvectors)?;
+		fs.end_write_struct()
+	}
+
+	fn read(node: &FileNode) -> Result<Self> {
+		Ok(Self { eigenvalues: required_mat(node, "eigenvalues")?, eigenvectors: required_mat(node, "eigenvectors")? })
+	}
+
+	pub fn save(&self, filename: &str) -> Result<()> {
+		let mut fs = open_write(filename)?;
+		self.write(&mut fs)?;
+		fs.release()
+	}
+
+	pub fn save_to_string(&self) -> Result<String> {
+		let mut fs = open_write_memory()?;
+		self.write(&mut fs)?;
+		fs.release_and_get_string()
+	}
+
+	pub fn load(filename: &str) -> Result<Self> {
+		Self::read(&open_read(filename, false, "lda")?.1)
+	}
+
+	pub fn load_from_str(s: &str) -> Result<Self> {
+		Self::read(&open_read(s, true, "lda")?.1)
+	}
+}
+
+/// Singular value decomposition `A = U * diag(W) * Vt` of the sample matrix
+///
+/// The data is not centered, use [Pca] for the variance around the mean.
+pub struct Svd {
+	u: Mat,
+	w: Mat,
+	vt: Mat,
+}
+
+impl Svd {
+	/// Decomposes the `samples` matrix (one sample per row)
+	pub fn fit(samples: &Mat) -> Result<Self> {
+		check_samples(samples)?;
+		let svd = SVD::new(samples, 0)?;
+		Ok(Self { u: svd.u(), w: svd.w(), vt: svd.vt() })
+	}
+
+	/// Keeps only the first `components` singular values and vectors
+	pub fn truncated(self, components: i32) -> Result<Self> {
+		let components = components.max(1).min(self.component_count());
+		Ok(Self {
+			u: self.u.col_range(&core::Range::new(0, components)?)?.try_clone()?,
+			w: self.w.row_range(&core::Range::new(0, components)?)?.try_clone()?,
+			vt: self.vt.row_range(&core::Range::new(0, components)?)?.try_clone()?,
+		})
+	}
+
+	/// Projects the samples (one per row) onto the right singular vectors: `samples * V`
+	pub fn project(&self, samples: &Mat) -> Result<Mat> {
+		let mut out = Mat::default();
+		core::gemm(samples, &self.vt, 1., &core::no_array(), 0., &mut out, core::GEMM_2_T)?;
+		Ok(out)
+	}
+
+	/// Maps the projections back: `projections * Vt`
+	pub fn back_project(&self, projections: &Mat) -> Result<Mat> {
+		let mut out = Mat::default();
+		core::gemm(projections, &self.vt, 1., &core::no_array(), 0., &mut out, 0)?;
+		Ok(out)
+	}
+
+	#[inline]
+	pub fn component_count(&self) -> i32 {
+		self.vt.rows()
+	}
+
+	#[inline]
+	pub fn u(&self) -> &Mat {
+		&self.u
+	}
+
+	/// Singular values in decreasing order as a column
+	#[inline]
+	pub fn w(&self) -> &Mat {
+		&self.w
+	}
+
+	#[inline]
+	pub fn vt(&self) -> &Mat {
+		&self.vt
+	}
+
+	/// Squared singular values, i.e. the energy captured by every component
+	pub fn explained_variance(&self) -> Result<Vec<f64>> {
+		Ok(to_f64_values(&self.w)?.into_iter().map(|w| w * w).collect())
+	}
+
+	/// Fraction of the energy captured by every kept component
+	pub fn explained_variance_ratio(&self) -> Result<Vec<f64>> {
+		let values = self.explained_variance()?;
+		let total = values.iter().sum();
+		Ok(ratios(&values, total))
+	}
+
+	fn write(&self, fs: &mut FileStorage) -> Result<()> {
+		fs.start_write_struct("svd", core::FileNode_MAP, "")?;
+		fs.write_mat("u", &self.u)?;
+		fs.write_mat("w", &self.w)?;
+		fs.write_mat("vt", &self.vt)?;
+		fs.end_write_struct()
+	}
+
+	fn read(node: &FileNode) -> Result<Self> {
+		Ok(Self { u: required_mat(node, "u")?, w: required_mat(node, "w")?, vt: required_mat(node, "vt")? })
+	}
+
+	pub fn save(&self, filename: &str) -> Result<()> {
+		let mut fs = open_write(filename)?;
+		self.write(&mut fs)?;
+		fs.release()
+	}
+
+	pub fn save_to_string(&self) -> Result<String> {
+		let mut fs = open_write_memory()?;
+		self.write(&mut fs)?;
+		fs.release_and_get_string()
+	}
+
+	pub fn load(filename: &str) -> Result<Self> {
+		Self::read(&open_read(filename, false, "svd")?.1)
+	}
+
+	pub fn load_from_str(s: &str) -> Result<Self> {
+		Self::read(&open_read(s, true, "svd")?.1)
+	}
+}
@@ -0,0 +1,199 @@
+//! Linear system solving, inversion, eigen decomposition and determinant with a typed decomposition method
+//!
+//! The results carry the numerical rank and the condition number of the input matrix so that the degenerate inputs
+//! (e.g. coplanar points in a pose estimation) can be detected instead of silently producing garbage:
+//! ```no_run
+//! # use opencv::{core::{Mat, linalg::{self, DecompMethod}}, Result};
+//! # fn fit(a: &Mat, b: &Mat) -> Result<()> {
+//! let solution = linalg::solve(a, b, DecompMethod::Qr)?;
+//! if solution.info.condition_number > 1e8 {
+//!     println!("Ill-conditioned system, rank: {}", solution.info.rank);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+	core::{self, DecompTypes, Mat, SVD},
+	Error,
+	prelude::*,
+	Result,
+};
+
+/// Matrix decomposition used to solve the system or invert the matrix
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DecompMethod {
+	/// Gaussian elimination with the optimal pivot element, matrix must be square and non-singular
+	Lu,
+	/// `L*L^T` factorization, matrix must be symmetric and positive definite
+	Cholesky,
+	/// Singular value decomposition, the system can be over-determined and the matrix can be singular (least squares)
+	Svd,
+	/// Eigenvalue decomposition, matrix must be symmetric
+	Eig,
+	/// QR factorization, the system can be over-determined (least squares)
+	Qr,
+}
+
+impl DecompMethod {
+	/// Returns `true` if the method handles non-square and singular matrices
+	#[inline]
+	pub fn is_least_squares(self) -> bool {
+		matches!(self, Self::Svd | Self::Qr)
+	}
+}
+
+impl From<DecompMethod> for DecompTypes {
+	#[inline]
+	fn from(s: DecompMethod) -> Self {
+		match s {
+			DecompMethod::Lu => Self::DECOMP_LU,
+			DecompMethod::Cholesky => Self::DECOMP_CHOLESKY,
+			DecompMethod::Svd => Self::DECOMP_SVD,
+			DecompMethod::Eig => Self::DECOMP_EIG,
+			DecompMethod::Qr => Self::DECOMP_QR,
+		}
+	}
+}
+
+/// Numerical properties of the matrix calculated from its singular values
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MatrixInfo {
+	/// Number of the singular values above the tolerance of `max(rows, cols) * max_singular_value * epsilon`
+	pub rank: i32,
+	/// Ratio of the largest to the smallest singular value, `inf` for the singular matrices
+	pub condition_number: f64,
+}
+
+/// Calculates the rank and the condition number of the single channel floating point matrix
+pub fn matrix_info(a: &Mat) -> Result<MatrixInfo> {
+	check_float(a, "Matrix")?;
+	let mut w = Mat::default();
+	SVD::compute(a, &mut w, 0)?;
+	let mut w_f64 = Mat::default();
+	w.convert_to(&mut w_f64, f64::typ(), 1., 0.)?;
+	let w = w_f64.data_typed::<f64>()?;
+	let max = w.first().copied().unwrap_or(0.);
+	let min = w.last().copied().unwrap_or(0.);
+	let epsilon = if a.depth() == core::CV_32F { f64::from(f32::EPSILON) } else { f64::EPSILON };
+	let tolerance = f64::from(a.rows().max(a.cols())) * max * epsilon;
+	Ok(MatrixInfo {
+		rank: w.iter().filter(|&&v| v > tolerance).count() as i32,
+		condition_number: if min > 0. { max / min } else { f64::INFINITY },
+	})
+}
+
+/// Solution of the linear system `a * x = b`
+#[derive(Debug)]
+pub struct Solution {
+	pub x: Mat,
+	/// Properties of `a`
+	pub info: MatrixInfo,
+}
+
+/// Solves the linear system `a * x = b`, `b` can have several columns to solve for several right-hand sides at once
+///
+/// Fails if `a` is singular and `method` is not [DecompMethod::is_least_squares]. For over-determined systems the
+/// least squares solution is returned.
+pub fn solve(a: &Mat, b: &Mat, method: DecompMethod) -> Result<Solution> {
+	check_float(a, "Matrix")?;
+	check_float(b, "Right-hand side")?;
+	if a.rows() != b.rows() {
+		return Err(Error::new(core::StsUnmatchedSizes, format!("Right-hand side rows: {} don't match the matrix rows: {}", b.rows(), a.rows())));
+	}
+	check_square_for(a, method)?;
+	let mut x = Mat::default();
+	if !core::solve(a, b, &mut x, DecompTypes::from(method) as i32)? {
+		return Err(Error::new(core::StsError, format!("Matrix is singular, can't solve the system using: {:?}", method)));
+	}
+	Ok(Solution { x, info: matrix_info(a)? })
+}
+
+/// Inverse (or pseudo-inverse) of the matrix
+#[derive(Debug)]
+pub struct Inverse {
+	pub inverse: Mat,
+	/// Properties of the inverted matrix
+	pub info: MatrixInfo,
+}
+
+/// Inverts the matrix, [DecompMethod::Svd] computes the pseudo-inverse of the non-square or singular matrix
+///
+/// Fails if the matrix is singular for the other methods.
+pub fn invert(a: &Mat, method: DecompMethod) -> Result<Inverse> {
+	check_float(a, "Matrix")?;
+	if method == DecompMethod::Qr {
+		return Err(Error::new(core::StsBadArg, "QR decomposition is not supported for the matrix inversion, use Svd"));
+	}
+	check_square_for(a, method)?;
+	let mut inverse = Mat::default();
+	let res = core::invert(a, &mut inverse, DecompTypes::from(method) as i32)?;
+	if method != DecompMethod::Svd && res == 0. {
+		return Err(Error::new(core::StsError, format!("Matrix is singular, can't invert using: {:?}", method)));
+	}
+	Ok(Inverse { inverse, info: matrix_info(a)? })
+}
+
+/// Eigenvalues (as a column in descending order) and the corresponding eigenvectors (as rows)
+#[derive(Debug)]
+pub struct Eigen {
+	pub values: Mat,
+	pub vectors: Mat,
+}
+
+/// Calculates the eigenvalues and eigenvectors of the symmetric matrix
+///
+/// Fails if the matrix is not symmetric, use [eigen_non_symmetric] for those.
+pub fn eigen(a: &Mat) -> Result<Eigen> {
+	check_float(a, "Matrix")?;
+	check_square(a)?;
+	let mut transposed = Mat::default();
+	core::transpose(a, &mut transposed)?;
+	let asymmetry = core::norm2(a, &transposed, core::NORM_INF, &core::no_array())?;
+	if asymmetry > 1e-6 * core::norm(a, core::NORM_INF, &core::no_array())?.max(1.) {
+		return Err(Error::new(core::StsBadArg, "Matrix is not symmetric, use eigen_non_symmetric()"));
+	}
+	let mut out = Eigen { values: Mat::default(), vectors: Mat::default() };
+	if !core::eigen(a, &mut out.values, &mut out.vectors)? {
+		return Err(Error::new(core::StsError, "Eigen decomposition failed"));
+	}
+	Ok(out)
+}
+
+/// Calculates the eigenvalues and eigenvectors of the non-symmetric matrix, only the real eigenvalues are supported
+pub fn eigen_non_symmetric(a: &Mat) -> Result<Eigen> {
+	check_float(a, "Matrix")?;
+	check_square(a)?;
+	let mut out = Eigen { values: Mat::default(), vectors: Mat::default() };
+	core::eigen_non_symmetric(a, &mut out.values, &mut out.vectors)?;
+	Ok(out)
+}
+
+/// Calculates the determinant of the square floating point matrix
+pub fn determinant(a: &Mat) -> Result<f64> {
+	check_float(a, "Matrix")?;
+	check_square(a)?;
+	core::determinant(a)
+}
+
+fn check_float(m: &Mat, name: &str) -> Result<()> {
+	if m.empty() || m.dims() != 2 || m.channels() != 1 || (m.depth() != core::CV_32F && m.depth() != core::CV_64F) {
+		return Err(Error::new(core::StsUnsupportedFormat, format!("{} must be a non-empty single channel 2D matrix of CV_32F or CV_64F type", name)));
+	}
+	Ok(())
+}
+
+fn check_square(m: &Mat) -> Result<()> {
+	if m.rows() != m.cols() {
+		return Err(Error::new(core::StsBadSize, format!("Matrix must be square, but it's: {}x{}", m.rows(), m.cols())));
+	}
+	Ok(())
+}
+
+fn check_square_for(m: &Mat, method: DecompMethod) -> Result<()> {
+	if method.is_least_squares() || m.rows() == m.cols() {
+		Ok(())
+	} else {
+		Err(Error::new(core::StsBadSize, format!("Matrix must be square for: {:?}, but it's: {}x{}, use Svd or Qr", method, m.rows(), m.cols())))
+	}
+}
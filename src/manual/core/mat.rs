@@ -323,7 +323,7 @@ pub trait MatTraitConstManual: MatTraitConst {
 	fn size(&self) -> Result<core::Size> {
 		extern "C" { fn cv_manual_Mat_size(instance: *const c_void, ocvrs_return: *mut sys::Result<core::Size>); }
 		return_send!(via ocvrs_return);
-		unsafe { cv_manual_Mat_size(self.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(local cv_manual_Mat_size(self.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret.into_result()
 	}
@@ -337,7 +337,7 @@ pub trait MatTraitConstManual: MatTraitConst {
 	#[inline]
 	fn data(&self) -> *const u8 {
 		extern "C" { fn cv_manual_Mat_data(instance: *const c_void) -> *const u8; }
-		unsafe { cv_manual_Mat_data(self.as_raw_Mat()) }
+		unsafe { ffi_call!(local cv_manual_Mat_data(self.as_raw_Mat())) }
 	}
 
 	/// Returns underlying data array as byte slice, Mat must be continuous.
@@ -478,7 +478,7 @@ pub trait MatTraitManual: MatTraitConstManual + MatTrait {
 	fn set(&mut self, s: Scalar) -> Result<()> {
 		extern "C" { fn cv_manual_Mat_set(instance: *mut c_void, s: *const Scalar, ocvrs_returl: *mut sys::Result_void); }
 		return_send!(via ocvrs_return);
-		unsafe { cv_manual_Mat_set(self.as_raw_mut_Mat(), &s, ocvrs_return.as_mut_ptr()) }
+		unsafe { ffi_call!(local cv_manual_Mat_set(self.as_raw_mut_Mat(), &s, ocvrs_return.as_mut_ptr())) }
 		return_receive!(unsafe ocvrs_return => ret);
 		ret.into_result()
 	}
@@ -488,7 +488,12 @@ pub trait MatTraitManual: MatTraitConstManual + MatTrait {
 	fn data_bytes_mut(&mut self) -> Result<&mut [u8]> {
 		match_is_continuous(self)
 			.and_then(|_| {
-				Ok(unsafe { slice::from_raw_parts_mut(self.data_mut(), self.total() * self.elem_size()?) })
+				let data = self.data_mut();
+				if data.is_null() {
+					Err(Error::new(core::StsNullPtr, "Function returned null pointer"))
+				} else {
+					Ok(unsafe { slice::from_raw_parts_mut(data, self.total() * self.elem_size()?) })
+				}
 			})
 	}
 
@@ -504,7 +509,12 @@ pub trait MatTraitManual: MatTraitConstManual + MatTrait {
 	#[inline]
 	unsafe fn data_typed_unchecked_mut<T: DataType>(&mut self) -> Result<&mut [T]> {
 		let total = self.total();
-		Ok(slice::from_raw_parts_mut(self.data_mut() as *mut u8 as *mut T, total))
+		let data = self.data_mut();
+		if data.is_null() {
+			Err(Error::new(core::StsNullPtr, "Function returned null pointer"))
+		} else {
+			Ok(slice::from_raw_parts_mut(data as *mut T, total))
+		}
 	}
 }
 
@@ -545,7 +555,7 @@ pub trait UMatTraitConstManual: UMatTraitConst {
 	fn size(&self) -> Result<core::Size> {
 		extern "C" { fn cv_manual_UMat_size(instance: *const c_void, ocvrs_return: *mut sys::Result<core::Size>); }
 		return_send!(via ocvrs_return);
-		unsafe { cv_manual_UMat_size(self.as_raw_UMat(), ocvrs_return.as_mut_ptr()) }
+		unsafe { ffi_call!(local cv_manual_UMat_size(self.as_raw_UMat(), ocvrs_return.as_mut_ptr())) }
 		return_receive!(unsafe ocvrs_return => ret);
 		ret.into_result()
 	}
@@ -560,7 +570,7 @@ pub trait MatSizeTraitConstManual: MatSizeTraitConst {
 	#[inline]
 	fn dims(&self) -> i32 {
 		extern "C" { fn cv_manual_MatSize_dims(instance: *const c_void) -> i32; }
-		unsafe { cv_manual_MatSize_dims(self.as_raw_MatSize()) }
+		unsafe { ffi_call!(local cv_manual_MatSize_dims(self.as_raw_MatSize())) }
 	}
 }
 
@@ -588,7 +598,7 @@ impl Deref for MatStep {
 	#[inline]
 	fn deref(&self) -> &Self::Target {
 		extern "C" { fn cv_manual_MatStep_deref(instance: *const c_void) -> *const size_t; }
-		let ptr = unsafe { cv_manual_MatStep_deref(self.as_raw_MatStep()) };
+		let ptr = unsafe { ffi_call!(local cv_manual_MatStep_deref(self.as_raw_MatStep())) };
 		unsafe { slice::from_raw_parts(ptr, 2) }
 	}
 }
@@ -603,13 +613,13 @@ pub trait MatConstIteratorTraitManual: MatConstIteratorTrait {
 	#[inline]
 	fn typ(&self) -> i32 {
 		extern "C" { fn cv_manual_MatConstIterator_type(instance: *const c_void) -> i32; }
-		unsafe { cv_manual_MatConstIterator_type(self.as_raw_MatConstIterator()) }
+		unsafe { ffi_call!(local cv_manual_MatConstIterator_type(self.as_raw_MatConstIterator())) }
 	}
 
 	#[inline]
 	fn has_elements(&self) -> bool {
 		extern "C" { fn cv_manual_MatConstIterator_has_elements(instance: *const c_void) -> bool; }
-		unsafe { cv_manual_MatConstIterator_has_elements(self.as_raw_MatConstIterator()) }
+		unsafe { ffi_call!(local cv_manual_MatConstIterator_has_elements(self.as_raw_MatConstIterator())) }
 	}
 
 	#[inline]
@@ -270,7 +270,7 @@ macro_rules! matx_extern {
 			unsafe fn extern_input_array(&self) -> $crate::sys::Result<*mut ::std::ffi::c_void> {
 				extern "C" { fn $extern_input_array(instance: *const $crate::manual::core::Matx<$type, $array>, ocvrs_return: *mut $crate::sys::Result<*mut ::std::ffi::c_void>); }
 				return_send!(via ocvrs_return);
-				$extern_input_array(self, ocvrs_return.as_mut_ptr());
+				ffi_call!(local $extern_input_array(self, ocvrs_return.as_mut_ptr()));
 				return_receive!(ocvrs_return => ret);
 				ret
 			}
@@ -279,7 +279,7 @@ macro_rules! matx_extern {
 			unsafe fn extern_output_array(&mut self) -> $crate::sys::Result<*mut ::std::ffi::c_void> {
 				extern "C" { fn $extern_ouput_array(instance: *mut $crate::manual::core::Matx<$type, $array>, ocvrs_return: *mut $crate::sys::Result<*mut ::std::ffi::c_void>); }
 				return_send!(via ocvrs_return);
-				$extern_ouput_array(self, ocvrs_return.as_mut_ptr());
+				ffi_call!(local $extern_ouput_array(self, ocvrs_return.as_mut_ptr()));
 				return_receive!(ocvrs_return => ret);
 				ret
 			}
@@ -288,7 +288,7 @@ macro_rules! matx_extern {
 			unsafe fn extern_input_output_array(&mut self) -> $crate::sys::Result<*mut ::std::ffi::c_void> {
 				extern "C" { fn $extern_input_array_output(instance: *mut $crate::manual::core::Matx<$type, $array>, ocvrs_return: *mut $crate::sys::Result<*mut ::std::ffi::c_void>); }
 				return_send!(via ocvrs_return);
-				$extern_input_array_output(self, ocvrs_return.as_mut_ptr());
+				ffi_call!(local $extern_input_array_output(self, ocvrs_return.as_mut_ptr()));
 				return_receive!(ocvrs_return => ret);
 				ret
 			}
@@ -0,0 +1,92 @@
+use std::{
+	ffi::c_void,
+	fs::File,
+	ops::Deref,
+	path::Path,
+};
+
+use memmap2::{MmapMut, MmapOptions};
+
+use crate::{
+	core::{self, Mat},
+	Error,
+	Result,
+};
+
+/// `Mat` backed by a memory-mapped file with the raw row-major data
+///
+/// The pages are loaded by the OS on access and can be evicted under memory pressure, so the matrix can be larger than
+/// the available RAM. The file is mapped copy-on-write: it's never modified, writes through the `Mat` only change the
+/// private copy of the touched pages. The `Mat` (and any shallow copies or submatrices of it) must not outlive the
+/// `MmapMat` and the file must not be truncated while it's mapped.
+pub struct MmapMat {
+	// drops before the mapping
+	mat: Mat,
+	_map: MmapMut,
+}
+
+impl MmapMat {
+	/// Maps `rows * cols` elements of type `typ` starting at `offset` bytes into the file
+	pub fn open(path: impl AsRef<Path>, offset: u64, rows: i32, cols: i32, typ: i32) -> Result<Self> {
+		let path = path.as_ref();
+		if rows <= 0 || cols <= 0 {
+			return Err(Error::new(core::StsBadSize, format!("Invalid memory-mapped Mat size: {}x{}", rows, cols)));
+		}
+		let elem_size1 = depth_size(core::CV_MAT_DEPTH(typ))?;
+		let misalignment = offset % elem_size1 as u64;
+		if misalignment != 0 {
+			return Err(Error::new(core::StsBadArg, format!("Offset: {} is not aligned to the element size: {}", offset, elem_size1)));
+		}
+		let len = rows as usize * cols as usize * core::CV_MAT_CN(typ) as usize * elem_size1;
+		let file = File::open(path)
+			.map_err(|e| Error::new(core::StsError, format!("Can't open file: {}: {}", path.display(), e)))?;
+		let file_len = file.metadata()
+			.map_err(|e| Error::new(core::StsError, format!("Can't read metadata of: {}: {}", path.display(), e)))?
+			.len();
+		if file_len < offset + len as u64 {
+			return Err(Error::new(core::StsOutOfRange, format!("File: {} has {} bytes, but {} bytes are required for {}x{} Mat at offset: {}", path.display(), file_len, offset + len as u64, rows, cols, offset)));
+		}
+		// SAFETY: the mapping is private and the length is checked above, truncation of the file by another process is
+		// documented as the caller's responsibility
+		let mut map = unsafe { MmapOptions::new().offset(offset).len(len).map_copy(&file) }
+			.map_err(|e| Error::new(core::StsError, format!("Can't map file: {}: {}", path.display(), e)))?;
+		let mat = unsafe { Mat::new_rows_cols_with_data(rows, cols, typ, map.as_mut_ptr().cast::<c_void>(), core::Mat_AUTO_STEP) }?;
+		Ok(Self { mat, _map: map })
+	}
+
+	#[inline]
+	pub fn mat(&self) -> &Mat {
+		&self.mat
+	}
+}
+
+impl Deref for MmapMat {
+	type Target = Mat;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		&self.mat
+	}
+}
+
+impl Mat {
+	/// Maps the file containing `rows * cols` elements of type `typ` in row-major order without reading it into memory
+	///
+	/// See [MmapMat] for the details, use [MmapMat::open] for the data starting at an offset (e.g. after a header).
+	#[inline]
+	pub fn from_memmap(path: impl AsRef<Path>, rows: i32, cols: i32, typ: i32) -> Result<MmapMat> {
+		MmapMat::open(path, 0, rows, cols, typ)
+	}
+}
+
+fn depth_size(depth: i32) -> Result<usize> {
+	match depth {
+		core::CV_8U | core::CV_8S => Ok(1),
+		core::CV_16U | core::CV_16S => Ok(2),
+		#[cfg(not(ocvrs_opencv_branch_32))]
+		core::CV_16F => Ok(2),
+		core::CV_32S | core::CV_32F => Ok(4),
+		core::CV_64F => Ok(8),
+		_ => Err(Error::new(core::StsUnsupportedFormat, format!("Unsupported depth: {}", depth))),
+	}
+}
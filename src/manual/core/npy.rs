@@ -0,0 +1,433 @@
+//! NumPy `.npy` and `.npz` interchange
+//!
+//! Arrays keep their element type across the boundary, so the feature matrices and labels exported from a NumPy
+//! pipeline can be used as `TrainData` samples and responses directly:
+//! ```no_run
+//! # use opencv::{core::{Mat, npy}, ml::{self, SampleTypes}, prelude::*, Result};
+//! # fn load() -> Result<()> {
+//! let archive = npy::read_npz(&std::fs::read("dataset.npz").unwrap())?;
+//! let samples = &archive.iter().find(|(name, _)| name == "x").unwrap().1;
+//! let labels = &archive.iter().find(|(name, _)| name == "y").unwrap().1;
+//! let data = <dyn ml::TrainData>::create_with_layout(samples, SampleTypes::ROW_SAMPLE, labels)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The dimensions follow the conventions of the OpenCV Python bindings: 1D arrays of length `n` become `n x 1` columns,
+//! 2D arrays are single-channel matrices and the last axis of 3D arrays becomes the channels. OpenCV has no 64-bit
+//! integer type, so `int64`, `uint32` and `uint64` arrays (NumPy default for the integer labels) are converted to
+//! `CV_32S`, failing if any value doesn't fit.
+
+use std::convert::TryFrom;
+
+use once_cell::sync::Lazy;
+
+use crate::{
+	core::{self, Mat},
+	Error,
+	prelude::*,
+	Result,
+};
+
+const NPY_MAGIC: &[u8] = b"\x93NUMPY";
+const ZIP_LOCAL_HEADER: u32 = 0x0403_4b50;
+const ZIP_CENTRAL_HEADER: u32 = 0x0201_4b50;
+const ZIP_END_RECORD: u32 = 0x0605_4b50;
+const ZIP64_END_RECORD: u32 = 0x0606_4b50;
+const ZIP64_END_LOCATOR: u32 = 0x0706_4b50;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Conversion {
+	/// Data is copied as is
+	Direct,
+	/// Data is converted to `CV_32S` with the range check
+	ToI32,
+}
+
+struct Dtype {
+	depth: i32,
+	size: usize,
+	little_endian: bool,
+	kind: u8,
+	conversion: Conversion,
+}
+
+impl Dtype {
+	fn parse(descr: &str) -> Result<Self> {
+		let bytes = descr.as_bytes();
+		if bytes.len() < 3 || !bytes[1].is_ascii() {
+			return Err(Error::new(core::StsParseError, format!("Invalid npy dtype: {}", descr)));
+		}
+		let little_endian = match bytes[0] {
+			b'<' => true,
+			b'>' => false,
+			b'|' | b'=' => cfg!(target_endian = "little"),
+			_ => return Err(Error::new(core::StsParseError, format!("Invalid npy dtype byte order: {}", descr))),
+		};
+		let kind = bytes[1];
+		let size = descr[2..].parse::<usize>()
+			.map_err(|_| Error::new(core::StsParseError, format!("Invalid npy dtype size: {}", descr)))?;
+		let (depth, conversion) = match (kind, size) {
+			(b'b', 1) | (b'u', 1) => (core::CV_8U, Conversion::Direct),
+			(b'i', 1) => (core::CV_8S, Conversion::Direct),
+			(b'u', 2) => (core::CV_16U, Conversion::Direct),
+			(b'i', 2) => (core::CV_16S, Conversion::Direct),
+			(b'i', 4) => (core::CV_32S, Conversion::Direct),
+			#[cfg(not(ocvrs_opencv_branch_32))]
+			(b'f', 2) => (core::CV_16F, Conversion::Direct),
+			(b'f', 4) => (core::CV_32F, Conversion::Direct),
+			(b'f', 8) => (core::CV_64F, Conversion::Direct),
+			(b'u', 4) | (b'i', 8) | (b'u', 8) => (core::CV_32S, Conversion::ToI32),
+			_ => return Err(Error::new(core::StsUnsupportedFormat, format!("Unsupported npy dtype: {}", descr))),
+		};
+		Ok(Self { depth, size, little_endian, kind, conversion })
+	}
+
+	fn for_depth(depth: i32) -> Result<&'static str> {
+		let (little, big) = match depth {
+			core::CV_8U => ("|u1", "|u1"),
+			core::CV_8S => ("|i1", "|i1"),
+			core::CV_16U => ("<u2", ">u2"),
+			core::CV_16S => ("<i2", ">i2"),
+			core::CV_32S => ("<i4", ">i4"),
+			#[cfg(not(ocvrs_opencv_branch_32))]
+			core::CV_16F => ("<f2", ">f2"),
+			core::CV_32F => ("<f4", ">f4"),
+			core::CV_64F => ("<f8", ">f8"),
+			_ => return Err(Error::new(core::StsUnsupportedFormat, format!("Unsupported Mat depth for npy: {}", depth))),
+		};
+		Ok(if cfg!(target_endian = "little") { little } else { big })
+	}
+
+	/// Reads the element at the start of `bytes` as `i32` for the `ToI32` conversion
+	fn read_i32(&self, bytes: &[u8]) -> Option<i32> {
+		let mut buf = [0; 8];
+		buf[..self.size].copy_from_slice(&bytes[..self.size]);
+		if !self.little_endian {
+			buf[..self.size].reverse();
+		}
+		let value = u64::from_le_bytes(buf);
+		match (self.kind, self.size) {
+			(b'i', 8) => i32::try_from(value as i64).ok(),
+			_ => i32::try_from(value).ok(),
+		}
+	}
+}
+
+struct Header {
+	dtype: Dtype,
+	fortran_order: bool,
+	shape: Vec<usize>,
+}
+
+/// Returns the value following `'key':` in the header dict
+fn header_value<'h>(header: &'h str, key: &str) -> Result<&'h str> {
+	let pos = header.find(&format!("'{}'", key))
+		.or_else(|| header.find(&format!("\"{}\"", key)))
+		.ok_or_else(|| Error::new(core::StsParseError, format!("npy header is missing: {}", key)))?;
+	let rest = &header[pos + key.len() + 2..];
+	let rest = rest.trim_start();
+	rest.strip_prefix(':')
+		.map(|rest| rest.trim_start())
+		.ok_or_else(|| Error::new(core::StsParseError, format!("Invalid npy header: {}", header)))
+}
+
+impl Header {
+	fn parse(header: &str) -> Result<Self> {
+		let descr = header_value(header, "descr")?;
+		let quote = descr.chars().next().filter(|&c| c == '\'' || c == '"')
+			.ok_or_else(|| Error::new(core::StsParseError, format!("Invalid npy dtype in header: {}", header)))?;
+		let descr = descr[1..].split(quote).next().unwrap_or("");
+		let fortran_order = header_value(header, "fortran_order")?.starts_with("True");
+		let shape = header_value(header, "shape")?;
+		let shape = shape.strip_prefix('(')
+			.and_then(|shape| shape.split(')').next())
+			.ok_or_else(|| Error::new(core::StsParseError, format!("Invalid npy shape in header: {}", header)))?
+			.split(',')
+			.map(|dim| dim.trim())
+			.filter(|dim| !dim.is_empty())
+			.map(|dim| dim.trim_end_matches('L').parse::<usize>()
+				.map_err(|_| Error::new(core::StsParseError, format!("Invalid npy dimension: {}", dim)))
+			)
+			.collect::<Result<Vec<_>>>()?;
+		Ok(Self { dtype: Dtype::parse(descr)?, fortran_order, shape })
+	}
+}
+
+fn end_of_data() -> Error {
+	Error::new(core::StsParseError, "Unexpected end of data")
+}
+
+/// `pos + len`, the offsets come from the untrusted data, so the overflow is reported as the end of data
+fn offset(pos: usize, len: usize) -> Result<usize> {
+	pos.checked_add(len).ok_or_else(end_of_data)
+}
+
+/// Returns `len` bytes at `pos`
+fn read_bytes(data: &[u8], pos: usize, len: usize) -> Result<&[u8]> {
+	data.get(pos..offset(pos, len)?).ok_or_else(end_of_data)
+}
+
+fn read_u16(data: &[u8], pos: usize) -> Result<u16> {
+	read_bytes(data, pos, 2).map(|b| u16::from_le_bytes([b[0], b[1]]))
+}
+
+fn read_u32(data: &[u8], pos: usize) -> Result<u32> {
+	read_bytes(data, pos, 4).map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+fn read_u64(data: &[u8], pos: usize) -> Result<u64> {
+	Ok(u64::from(read_u32(data, pos)?) | u64::from(read_u32(data, offset(pos, 4)?)?) << 32)
+}
+
+/// Converts the 64-bit offset or size read from the data, fails if it doesn't fit into the address space
+fn read_usize(value: u64) -> Result<usize> {
+	usize::try_from(value).map_err(|_| end_of_data())
+}
+
+fn to_i32(value: usize, name: &str) -> Result<i32> {
+	i32::try_from(value).map_err(|_| Error::new(core::StsOutOfRange, format!("{}: {} is too large for Mat", name, value)))
+}
+
+impl Mat {
+	/// Creates a `Mat` from the contents of the NumPy `.npy` file
+	///
+	/// See the [npy](crate::core::npy) module for the supported types and shapes.
+	pub fn from_npy(data: &[u8]) -> Result<Mat> {
+		if !data.starts_with(NPY_MAGIC) || data.len() < 10 {
+			return Err(Error::new(core::StsParseError, "Data is not in npy format"));
+		}
+		let (header_len, header_start) = match data[6] {
+			1 => (usize::from(read_u16(data, 8)?), 10),
+			2 | 3 => (read_usize(u64::from(read_u32(data, 8)?))?, 12),
+			version => return Err(Error::new(core::StsUnsupportedFormat, format!("Unsupported npy version: {}", version))),
+		};
+		let header = read_bytes(data, header_start, header_len)
+			.map_err(|_| Error::new(core::StsParseError, "npy header is truncated"))?;
+		let header = Header::parse(&String::from_utf8_lossy(header))?;
+		let body = &data[header_start + header_len..];
+
+		let (rows, cols, channels) = match header.shape.as_slice() {
+			&[] => (1, 1, 1),
+			&[n] => (n, 1, 1),
+			&[rows, cols] => (rows, cols, 1),
+			&[rows, cols, channels] if !header.fortran_order && (1..=core::CV_CN_MAX as usize).contains(&channels) => (rows, cols, channels),
+			shape => return Err(Error::new(core::StsUnsupportedFormat, format!("Unsupported npy shape: {:?}, fortran order: {}", shape, header.fortran_order))),
+		};
+		let dtype = &header.dtype;
+		let body_len = rows.checked_mul(cols)
+			.and_then(|count| count.checked_mul(channels))
+			.and_then(|count| count.checked_mul(dtype.size))
+			.ok_or_else(|| Error::new(core::StsOutOfRange, format!("npy shape is too large: {:?}", header.shape)))?;
+		let count = body_len / dtype.size;
+		if body.len() < body_len {
+			return Err(Error::new(core::StsParseError, format!("npy data is truncated, expected: {} bytes, got: {}", body_len, body.len())));
+		}
+		let body = &body[..body_len];
+		// column-major data is read into the transposed Mat, the order is the same for a single row or column
+		let transpose = header.fortran_order && rows > 1 && cols > 1;
+		let (mat_rows, mat_cols) = if transpose { (cols, rows) } else { (rows, cols) };
+		let typ = core::CV_MAKETYPE(dtype.depth, to_i32(channels, "Channel count")?);
+		let mut out = unsafe { Mat::new_rows_cols(to_i32(mat_rows, "Row count")?, to_i32(mat_cols, "Column count")?, typ) }?;
+		if count > 0 {
+			let dst = out.data_bytes_mut()?;
+			match dtype.conversion {
+				Conversion::Direct => {
+					dst.copy_from_slice(body);
+					if dtype.size > 1 && dtype.little_endian != cfg!(target_endian = "little") {
+						dst.chunks_exact_mut(dtype.size).for_each(|elem| elem.reverse());
+					}
+				}
+				Conversion::ToI32 => {
+					for (dst, src) in dst.chunks_exact_mut(4).zip(body.chunks_exact(dtype.size)) {
+						let value = dtype.read_i32(src)
+							.ok_or_else(|| Error::new(core::StsOutOfRange, "npy integer value doesn't fit into CV_32S"))?;
+						dst.copy_from_slice(&value.to_ne_bytes());
+					}
+				}
+			}
+		}
+		if transpose {
+			let mut transposed = Mat::default();
+			core::transpose(&out, &mut transposed)?;
+			out = transposed;
+		}
+		Ok(out)
+	}
+
+	/// Returns the contents of the `.npy` file with the `Mat` data, shape is `(rows, cols)` or `(rows, cols, channels)`
+	pub fn to_npy(&self) -> Result<Vec<u8>> {
+		if self.dims() > 2 {
+			return Err(Error::new(core::StsUnsupportedFormat, format!("Only 2D Mats can be written to npy, Mat has: {} dims", self.dims())));
+		}
+		let descr = Dtype::for_depth(self.depth())?;
+		let shape = if self.channels() == 1 {
+			format!("({}, {})", self.rows(), self.cols())
+		} else {
+			format!("({}, {}, {})", self.rows(), self.cols(), self.channels())
+		};
+		let mut header = format!("{{'descr': '{}', 'fortran_order': False, 'shape': {}, }}", descr, shape);
+		// magic, version and header length take 10 bytes, the header ends with '\n' and the total is aligned to 64 bytes
+		let padding = 63 - (10 + header.len() + 1 + 63) % 64;
+		header.push_str(&" ".repeat(padding));
+		header.push('\n');
+		let header_len = u16::try_from(header.len())
+			.map_err(|_| Error::new(core::StsOutOfRange, "npy header is too long"))?;
+		let continuous;
+		let mat = if self.is_continuous() {
+			self
+		} else {
+			continuous = self.try_clone()?;
+			&continuous
+		};
+		let data = if mat.empty() { &[][..] } else { mat.data_bytes()? };
+		let mut out = Vec::with_capacity(10 + header.len() + data.len());
+		out.extend_from_slice(NPY_MAGIC);
+		out.extend_from_slice(&[1, 0]);
+		out.extend_from_slice(&header_len.to_le_bytes());
+		out.extend_from_slice(header.as_bytes());
+		out.extend_from_slice(data);
+		Ok(out)
+	}
+}
+
+static CRC32_TABLE: Lazy<[u32; 256]> = Lazy::new(|| {
+	let mut table = [0; 256];
+	for (i, entry) in table.iter_mut().enumerate() {
+		let mut crc = i as u32;
+		for _ in 0..8 {
+			crc = if crc & 1 == 1 { 0xedb8_8320 ^ (crc >> 1) } else { crc >> 1 };
+		}
+		*entry = crc;
+	}
+	table
+});
+
+fn crc32(data: &[u8]) -> u32 {
+	let table = &*CRC32_TABLE;
+	!data.iter().fold(!0, |crc, &b| table[((crc ^ u32::from(b)) & 0xff) as usize] ^ (crc >> 8))
+}
+
+/// Reads all arrays from the `.npz` archive produced by `numpy.savez()`, the names are without the `.npy` suffix
+///
+/// Only the uncompressed archives are supported, `numpy.savez_compressed()` output is rejected.
+pub fn read_npz(data: &[u8]) -> Result<Vec<(String, Mat)>> {
+	let end_pos = (0..=data.len().saturating_sub(22)).rev()
+		.take(0x10000 + 22)
+		.find(|&pos| matches!(read_u32(data, pos), Ok(ZIP_END_RECORD)))
+		.ok_or_else(|| Error::new(core::StsParseError, "Data is not in npz (zip) format"))?;
+	let mut entry_count = u64::from(read_u16(data, end_pos + 10)?);
+	let mut dir_pos = u64::from(read_u32(data, end_pos + 16)?);
+	if (entry_count == 0xffff || dir_pos == 0xffff_ffff) && end_pos >= 20 && read_u32(data, end_pos - 20)? == ZIP64_END_LOCATOR {
+		let record_pos = read_usize(read_u64(data, end_pos - 12)?)?;
+		if read_u32(data, record_pos)? != ZIP64_END_RECORD {
+			return Err(Error::new(core::StsParseError, "Invalid zip64 end record"));
+		}
+		entry_count = read_u64(data, offset(record_pos, 32)?)?;
+		dir_pos = read_u64(data, offset(record_pos, 48)?)?;
+	}
+	let mut pos = read_usize(dir_pos)?;
+	// the count comes from the data, every central directory header takes at least 46 bytes
+	let mut out = Vec::with_capacity(read_usize(entry_count).unwrap_or(usize::MAX).min(data.len() / 46));
+	for _ in 0..entry_count {
+		if read_u32(data, pos)? != ZIP_CENTRAL_HEADER {
+			return Err(Error::new(core::StsParseError, "Invalid zip central directory"));
+		}
+		// the header was read successfully, so the offsets within it can't overflow
+		let method = read_u16(data, pos + 10)?;
+		let crc = read_u32(data, pos + 16)?;
+		let mut size = u64::from(read_u32(data, pos + 24)?);
+		let name_len = usize::from(read_u16(data, pos + 28)?);
+		let extra_len = usize::from(read_u16(data, pos + 30)?);
+		let comment_len = usize::from(read_u16(data, pos + 32)?);
+		let mut local_pos = u64::from(read_u32(data, pos + 42)?);
+		let name = read_bytes(data, pos + 46, name_len)?;
+		let name = String::from_utf8_lossy(name).into_owned();
+		// zip64 extended information, the fields are present only for the values that don't fit into 32 bits
+		let mut extra_pos = pos + 46 + name_len;
+		let extra_end = offset(extra_pos, extra_len)?;
+		while offset(extra_pos, 4)? <= extra_end {
+			let id = read_u16(data, extra_pos)?;
+			let len = usize::from(read_u16(data, extra_pos + 2)?);
+			if id == 0x0001 {
+				let mut field = extra_pos + 4;
+				let compressed_size = read_u32(data, pos + 20)?;
+				if size == 0xffff_ffff {
+					size = read_u64(data, field)?;
+					field = offset(field, 8)?;
+				}
+				if compressed_size == 0xffff_ffff {
+					field = offset(field, 8)?;
+				}
+				if local_pos == 0xffff_ffff {
+					local_pos = read_u64(data, field)?;
+				}
+			}
+			extra_pos = offset(extra_pos, 4 + len)?;
+		}
+		pos = offset(extra_end, comment_len)?;
+
+		if method != 0 {
+			return Err(Error::new(core::StsUnsupportedFormat, format!("npz entry: {} is compressed, only numpy.savez() archives are supported", name)));
+		}
+		let local_pos = read_usize(local_pos)?;
+		if read_u32(data, local_pos)? != ZIP_LOCAL_HEADER {
+			return Err(Error::new(core::StsParseError, format!("Invalid zip local header for: {}", name)));
+		}
+		let data_pos = offset(local_pos + 30, usize::from(read_u16(data, local_pos + 26)?) + usize::from(read_u16(data, local_pos + 28)?))?;
+		let entry = read_usize(size)
+			.and_then(|size| read_bytes(data, data_pos, size))
+			.map_err(|_| Error::new(core::StsParseError, format!("npz entry: {} is truncated", name)))?;
+		if crc32(entry) != crc {
+			return Err(Error::new(core::StsParseError, format!("CRC mismatch for npz entry: {}", name)));
+		}
+		let mat = Mat::from_npy(entry)?;
+		let name = name.strip_suffix(".npy").map_or(name.clone(), |name| name.to_string());
+		out.push((name, mat));
+	}
+	Ok(out)
+}
+
+/// Writes the arrays into an uncompressed `.npz` archive readable by `numpy.load()`
+pub fn write_npz(arrays: &[(&str, &Mat)]) -> Result<Vec<u8>> {
+	let mut out = Vec::new();
+	let mut directory = Vec::new();
+	for (name, mat) in arrays {
+		let entry = mat.to_npy()?;
+		let name = format!("{}.npy", name);
+		let too_large = || Error::new(core::StsOutOfRange, "npz archives larger than 4 GB are not supported");
+		let size = u32::try_from(entry.len()).map_err(|_| too_large())?;
+		let offset = u32::try_from(out.len()).map_err(|_| too_large())?;
+		let name_len = u16::try_from(name.len())
+			.map_err(|_| Error::new(core::StsOutOfRange, format!("npz entry name is too long: {}", name)))?;
+		let crc = crc32(&entry);
+		// version, flags, method, modification time and date (1980-01-01 00:00)
+		let common = [&20u16.to_le_bytes()[..], &0u16.to_le_bytes(), &0u16.to_le_bytes(), &0u16.to_le_bytes(), &0x21u16.to_le_bytes(), &crc.to_le_bytes(), &size.to_le_bytes(), &size.to_le_bytes(), &name_len.to_le_bytes(), &0u16.to_le_bytes()].concat();
+
+		out.extend_from_slice(&ZIP_LOCAL_HEADER.to_le_bytes());
+		out.extend_from_slice(&common);
+		out.extend_from_slice(name.as_bytes());
+		out.extend_from_slice(&entry);
+
+		directory.extend_from_slice(&ZIP_CENTRAL_HEADER.to_le_bytes());
+		directory.extend_from_slice(&20u16.to_le_bytes());
+		directory.extend_from_slice(&common);
+		// comment length, disk number, internal and external attributes
+		directory.extend_from_slice(&[0; 10]);
+		directory.extend_from_slice(&offset.to_le_bytes());
+		directory.extend_from_slice(name.as_bytes());
+	}
+	let entry_count = u16::try_from(arrays.len())
+		.map_err(|_| Error::new(core::StsOutOfRange, "Too many npz entries"))?;
+	let dir_size = u32::try_from(directory.len()).map_err(|_| Error::new(core::StsOutOfRange, "npz directory is too large"))?;
+	let dir_pos = u32::try_from(out.len()).map_err(|_| Error::new(core::StsOutOfRange, "npz archives larger than 4 GB are not supported"))?;
+	out.extend_from_slice(&directory);
+	out.extend_from_slice(&ZIP_END_RECORD.to_le_bytes());
+	out.extend_from_slice(&[0; 4]);
+	out.extend_from_slice(&entry_count.to_le_bytes());
+	out.extend_from_slice(&entry_count.to_le_bytes());
+	out.extend_from_slice(&dir_size.to_le_bytes());
+	out.extend_from_slice(&dir_pos.to_le_bytes());
+	out.extend_from_slice(&[0; 2]);
+	Ok(out)
+}
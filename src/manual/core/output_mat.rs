@@ -0,0 +1,120 @@
+use std::{
+	ffi::c_void,
+	marker::PhantomData,
+	slice,
+};
+
+use crate::{
+	core::{self, _InputArray, _InputOutputArray, _OutputArray, Mat, ToInputArray, ToInputOutputArray, ToOutputArray},
+	Error,
+	input_array_ref_forward,
+	output_array_ref_forward,
+	prelude::*,
+	Result,
+	sys,
+	traits::Boxed,
+};
+
+/// Output destination that is guaranteed to be written in place
+///
+/// The wrapper is passed to OpenCV as a fixed-size and fixed-type output array, so a function that would need to
+/// reallocate the destination (because of the different size or type of the result) fails instead. This allows
+/// reusing the same buffer for every frame without the hidden allocations:
+/// ```ignore
+/// let mut buf = vec![0u8; 224 * 224 * 3];
+/// let mut dst = OutputMat::from_slice(224, 224, &mut buf)?;
+/// imgproc::resize(&frame, &mut dst, Size::new(224, 224), 0., 0., imgproc::INTER_LINEAR)?;
+/// dst.check()?;
+/// ```
+/// The buffer stays mutably borrowed for the lifetime of the wrapper.
+pub struct OutputMat<'a> {
+	/// Non-owning header over the borrowed buffer
+	header: Mat,
+	data: *const u8,
+	_buf: PhantomData<&'a mut [u8]>,
+}
+
+impl<'a> OutputMat<'a> {
+	/// Wraps the data of the already allocated 2D `mat`
+	pub fn new(mat: &'a mut Mat) -> Result<Self> {
+		if mat.empty() || mat.dims() > 2 {
+			return Err(Error::new(core::StsBadArg, "OutputMat requires allocated 2D Mat"));
+		}
+		let step = mat.step1(0)? * mat.elem_size1();
+		let header = unsafe { Mat::new_rows_cols_with_data(mat.rows(), mat.cols(), mat.typ(), mat.data_mut().cast::<c_void>(), step) }?;
+		Ok(Self::from_header(header))
+	}
+
+	/// Wraps the continuous `rows x cols` buffer of `T` elements, `buf` length must be exactly `rows * cols`
+	pub fn from_slice<T: DataType>(rows: i32, cols: i32, buf: &'a mut [T]) -> Result<Self> {
+		if rows <= 0 || cols <= 0 || buf.len() != rows as usize * cols as usize {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("Buffer of: {} elements doesn't match: {}x{} size", buf.len(), rows, cols)));
+		}
+		let header = unsafe { Mat::new_rows_cols_with_data(rows, cols, T::typ(), buf.as_mut_ptr().cast::<c_void>(), core::Mat_AUTO_STEP) }?;
+		Ok(Self::from_header(header))
+	}
+
+	/// Checks that the destination still points to the wrapped buffer
+	///
+	/// Fixed output arrays cover the vast majority of OpenCV functions, but some of them replace the destination header
+	/// directly, call this after such operation to be sure.
+	pub fn check(&self) -> Result<()> {
+		if self.header.data() == self.data {
+			Ok(())
+		} else {
+			Err(Error::new(core::StsError, "OpenCV function reallocated the OutputMat destination"))
+		}
+	}
+
+	/// Returns the `Mat` header pointing to the wrapped buffer
+	#[inline]
+	pub fn as_mat(&self) -> &Mat {
+		&self.header
+	}
+
+	/// Returns the wrapped buffer as bytes
+	#[inline]
+	pub fn data_bytes(&self) -> Result<&[u8]> {
+		self.check()?;
+		Ok(unsafe { slice::from_raw_parts(self.data, self.header.total() * self.header.elem_size()?) })
+	}
+
+	fn from_header(header: Mat) -> Self {
+		let data = header.data();
+		Self { header, data, _buf: PhantomData }
+	}
+}
+
+impl ToInputArray for OutputMat<'_> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		self.header.input_array()
+	}
+}
+
+impl ToOutputArray for OutputMat<'_> {
+	#[inline]
+	fn output_array(&mut self) -> Result<_OutputArray> {
+		extern "C" { fn cv_manual_OutputMat_output_array(instance: *mut c_void, ocvrs_return: *mut sys::Result<*mut c_void>); }
+		return_send!(via ocvrs_return);
+		unsafe { ffi_call!(local cv_manual_OutputMat_output_array(self.header.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) }
+		return_receive!(unsafe ocvrs_return => ret);
+		ret.into_result()
+			.map(|ptr| unsafe { _OutputArray::from_raw(ptr) })
+	}
+}
+
+impl ToInputOutputArray for OutputMat<'_> {
+	#[inline]
+	fn input_output_array(&mut self) -> Result<_InputOutputArray> {
+		extern "C" { fn cv_manual_OutputMat_input_output_array(instance: *mut c_void, ocvrs_return: *mut sys::Result<*mut c_void>); }
+		return_send!(via ocvrs_return);
+		unsafe { ffi_call!(local cv_manual_OutputMat_input_output_array(self.header.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) }
+		return_receive!(unsafe ocvrs_return => ret);
+		ret.into_result()
+			.map(|ptr| unsafe { _InputOutputArray::from_raw(ptr) })
+	}
+}
+
+input_array_ref_forward! { OutputMat<'_> }
+output_array_ref_forward! { OutputMat<'_> }
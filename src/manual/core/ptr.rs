@@ -6,7 +6,12 @@ use std::{
 
 pub use ptr_extern::{PtrExtern, PtrExternCtor};
 
-use crate::traits::{Boxed, OpenCVType, OpenCVTypeArg, OpenCVTypeExternContainer};
+use crate::{
+	core,
+	Error,
+	Result,
+	traits::{Boxed, OpenCVType, OpenCVTypeArg, OpenCVTypeExternContainer},
+};
 
 mod ptr_f32;
 mod ptr_extern;
@@ -25,25 +30,90 @@ impl<T: ?Sized> Ptr<T> where Self: PtrExtern {
 	}
 
 	/// Get raw pointer to the inner object
+	///
+	/// Returns null if this `Ptr` was already released (see `core::validity`), so the generated methods of the inner
+	/// object fail with `StsNullPtr` instead of reading the released `Ptr`.
 	pub fn inner_as_raw(&self) -> *const c_void {
+		if core::validity::is_poisoned(self.ptr) {
+			return std::ptr::null();
+		}
 		unsafe { self.extern_inner_as_ptr() }
 	}
 
 	/// Get mutable raw pointer to the inner object
+	///
+	/// Returns null if this `Ptr` was already released, see [inner_as_raw](Self::inner_as_raw).
 	pub fn inner_as_raw_mut(&mut self) -> *mut c_void {
+		if core::validity::is_poisoned(self.ptr) {
+			return std::ptr::null_mut();
+		}
 		unsafe { self.extern_inner_as_ptr_mut() }
 	}
+
+	/// Returns `true` if the inner object is null, e.g. the `Ptr` returned by a factory function that failed to load
+	/// the object
+	pub fn is_null(&self) -> bool {
+		self.inner_as_raw().is_null()
+	}
+
+	/// Get raw pointer to the inner object, fails with `StsNullPtr` if it's null
+	pub fn try_inner_as_raw(&self) -> Result<*const c_void> {
+		let out = self.inner_as_raw();
+		if out.is_null() {
+			Err(null_inner_error())
+		} else {
+			Ok(out)
+		}
+	}
+
+	/// Get mutable raw pointer to the inner object, fails with `StsNullPtr` if it's null
+	pub fn try_inner_as_raw_mut(&mut self) -> Result<*mut c_void> {
+		let out = self.inner_as_raw_mut();
+		if out.is_null() {
+			Err(null_inner_error())
+		} else {
+			Ok(out)
+		}
+	}
+
+	/// Returns `self` if the inner object is not null, fails with `StsNullPtr` otherwise
+	///
+	/// The property accessors of the inner object that return plain values don't check the pointer, use this to guard
+	/// them: `ptr.try_non_null()?.value()`.
+	pub fn try_non_null(&self) -> Result<&Self> {
+		self.try_inner_as_raw().map(|_| self)
+	}
+
+	/// Mutable version of [try_non_null](Self::try_non_null)
+	pub fn try_non_null_mut(&mut self) -> Result<&mut Self> {
+		self.try_inner_as_raw_mut()?;
+		Ok(self)
+	}
+}
+
+fn null_inner_error() -> Error {
+	Error::new(core::StsNullPtr, "Ptr holds a null pointer")
 }
 
 impl<T: ?Sized> Boxed for Ptr<T> where Self: PtrExtern {
 	#[inline]
 	unsafe fn from_raw(ptr: *mut c_void) -> Self {
-		Self { ptr, _d: PhantomData }
+		core::alloc_tracking::track_alloc(std::any::type_name::<Self>(), ptr);
+		core::validity::track_wrap(ptr);
+		let out = Self { ptr, _d: PhantomData };
+		// the generated methods of the inner object check its pointer too, it can reuse the address of a released object
+		#[cfg(debug_assertions)]
+		if !ptr.is_null() {
+			core::validity::track_wrap(out.extern_inner_as_ptr());
+		}
+		out
 	}
 
 	#[inline]
 	fn into_raw(self) -> *mut c_void {
-		ManuallyDrop::new(self).ptr
+		let ptr = ManuallyDrop::new(self).ptr;
+		core::alloc_tracking::track_release(ptr);
+		ptr
 	}
 
 	#[inline]
@@ -55,6 +125,11 @@ impl<T: ?Sized> Boxed for Ptr<T> where Self: PtrExtern {
 	fn as_raw_mut(&mut self) -> *mut c_void {
 		self.ptr
 	}
+
+	#[inline]
+	fn validate(&self) -> Result<()> {
+		core::validity::check(std::any::type_name::<Self>(), self.ptr)
+	}
 }
 
 impl<T: ?Sized> OpenCVType<'_> for Ptr<T> where Self: PtrExtern {
@@ -103,6 +178,8 @@ impl<T: ?Sized> OpenCVTypeExternContainer for Ptr<T> where Self: PtrExtern {
 
 impl<T: ?Sized> Drop for Ptr<T> where Self: PtrExtern {
 	fn drop(&mut self) {
-		unsafe { self.extern_delete() }
+		core::alloc_tracking::track_release(self.ptr);
+		core::validity::track_drop(self.ptr);
+		unsafe { Self::extern_delete(self.ptr) };
 	}
 }
@@ -4,7 +4,7 @@ use crate::traits::{OpenCVType, OpenCVTypeExternContainer};
 
 #[doc(hidden)]
 pub trait PtrExtern {
-	#[doc(hidden)]	unsafe fn extern_delete(&mut self);
+	#[doc(hidden)]	unsafe fn extern_delete(ptr: *mut c_void);
 	#[doc(hidden)]	unsafe fn extern_inner_as_ptr(&self) -> *const c_void;
 	#[doc(hidden)]	unsafe fn extern_inner_as_ptr_mut(&mut self) -> *mut c_void;
 }
@@ -25,18 +25,18 @@ macro_rules! ptr_extern {
 
 		impl $crate::manual::core::PtrExtern for $crate::manual::core::Ptr<$type> {
 			#[inline]
-			unsafe fn extern_delete(&mut self) {
-				$extern_delete(self.as_raw_mut())
+			unsafe fn extern_delete(ptr: *mut std::ffi::c_void) {
+				ffi_call!(local $extern_delete(ptr))
 			}
 
 			#[inline]
 			unsafe fn extern_inner_as_ptr(&self) -> *const std::ffi::c_void {
-				$extern_inner_as_ptr(self.as_raw())
+				ffi_call!(local $extern_inner_as_ptr(self.as_raw()))
 			}
 
 			#[inline]
 			unsafe fn extern_inner_as_ptr_mut(&mut self) -> *mut std::ffi::c_void {
-				$extern_inner_as_ptr_mut(self.as_raw_mut())
+				ffi_call!(local $extern_inner_as_ptr_mut(self.as_raw_mut()))
 			}
 		}
 	};
@@ -50,7 +50,7 @@ macro_rules! ptr_extern_ctor {
 		impl $crate::manual::core::PtrExternCtor<$type> for $crate::manual::core::Ptr<$type> {
 			#[inline]
 			unsafe fn extern_new(val: <<$type as $crate::traits::OpenCVType>::ExternContainer as $crate::traits::OpenCVTypeExternContainer>::ExternSendMut) -> *mut std::ffi::c_void {
-				$extern_new(val)
+				ffi_call!(local $extern_new(val))
 			}
 		}
 	};
@@ -64,7 +64,7 @@ macro_rules! ptr_cast_base {
 		impl ::std::convert::From<$type> for $base {
 			#[inline]
 			fn from(s: $type) -> Self {
-				unsafe { Self::from_raw($extern_convert(s.into_raw())) }
+				unsafe { Self::from_raw(ffi_call!(local $extern_convert(s.into_raw()))) }
 			}
 		}
 	};
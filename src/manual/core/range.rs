@@ -0,0 +1,42 @@
+//! Conversions between `core::Range` and the `std::ops` ranges
+//!
+//! `Range` is a boxed class, so its construction can fail and there is only `TryFrom`. The generated functions keep
+//! the concrete argument types: `impl Into<...>` arguments would make the traits of the OpenCV classes unusable as
+//! trait objects and break the calls that pass `(x, y).into()`, so convert at the call site with `try_into()`. The
+//! manual wrappers in `core` and `imgproc` accept `impl Into<Size>`, `impl Into<Rect>` and `impl Into<Scalar>`.
+
+use std::{
+	convert::TryFrom,
+	ops,
+};
+
+use crate::{
+	core::{Range, RangeTraitConst},
+	Error,
+	Result,
+};
+
+impl TryFrom<ops::Range<i32>> for Range {
+	type Error = Error;
+
+	#[inline]
+	fn try_from(r: ops::Range<i32>) -> Result<Self> {
+		Self::new(r.start, r.end)
+	}
+}
+
+impl TryFrom<ops::RangeFull> for Range {
+	type Error = Error;
+
+	#[inline]
+	fn try_from(_: ops::RangeFull) -> Result<Self> {
+		Self::all()
+	}
+}
+
+impl From<&Range> for ops::Range<i32> {
+	#[inline]
+	fn from(r: &Range) -> Self {
+		r.start()..r.end()
+	}
+}
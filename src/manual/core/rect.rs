@@ -6,8 +6,9 @@ use std::{
 use num_traits::{NumCast, NumOps, ToPrimitive, Zero};
 
 use crate::{
-	core::{Point_, prelude::*, RotatedRect, Size_},
+	core::{Point2f, Point_, prelude::*, RotatedRect, Size_},
 	opencv_type_simple_generic,
+	Result,
 };
 
 #[inline(always)]
@@ -216,6 +217,21 @@ impl<T: PartialOrd + NumOps + Zero + Copy> BitAndAssign for Rect_<T> where Self:
 	}
 }
 
+impl RotatedRect {
+	/// Returns the corners of the rectangle in the same order as [RotatedRectTraitConst::points]
+	pub fn corners(&self) -> Result<[Point2f; 4]> {
+		let mut out = [Point2f::default(); 4];
+		self.points(&mut out)?;
+		Ok(out)
+	}
+
+	#[inline]
+	pub fn area(&self) -> f32 {
+		let size = self.size();
+		size.width * size.height
+	}
+}
+
 impl fmt::Debug for RotatedRect {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		f.debug_struct("RotatedRect")
@@ -0,0 +1,144 @@
+//! Single-threaded executors for the OpenCV components that are not reentrant or are bound to a single thread
+//!
+//! Some `VideoCapture` backends (e.g. MSMF, AVFoundation, some V4L drivers) and the highgui windowing expect all calls
+//! to come from the same thread. [SerialContext] owns a dedicated thread and runs the submitted closures there one by
+//! one. [Serial] wraps an object that is created, used and dropped only on the context thread, so it can be shared
+//! between any threads. The serialization is opt-in, the regular bindings always call OpenCV directly on the calling
+//! thread, so e.g. a blocking `VideoCapture::read()` doesn't stall the other captures. See `SerialVideoCapture` and
+//! `highgui::serial` for the ready-made wrappers.
+
+use std::{
+	mem,
+	panic::{self, AssertUnwindSafe},
+	sync::{
+		Arc,
+		mpsc::{self, Sender},
+		Mutex,
+		TryLockError,
+	},
+	thread::{self, ThreadId},
+};
+
+use once_cell::sync::Lazy;
+
+use crate::{
+	core,
+	Error,
+	Result,
+};
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// Dedicated thread running the submitted closures in order
+///
+/// The thread finishes after the context is dropped and all queued closures are done.
+pub struct SerialContext {
+	sender: Mutex<Sender<Job>>,
+	thread_id: ThreadId,
+}
+
+impl SerialContext {
+	/// Starts the context thread with the specified `name`
+	pub fn new(name: &str) -> Result<Self> {
+		let (sender, receiver) = mpsc::channel::<Job>();
+		let handle = thread::Builder::new()
+			.name(name.to_string())
+			.spawn(move || {
+				for job in receiver {
+					job();
+				}
+			})
+			.map_err(|e| Error::new(core::StsError, format!("Can't start serial context thread: {}", e)))?;
+		Ok(Self { sender: Mutex::new(sender), thread_id: handle.thread().id() })
+	}
+
+	/// Shared context used for highgui and `VideoCapture`, started on the first use
+	pub fn global() -> Arc<SerialContext> {
+		static GLOBAL: Lazy<Arc<SerialContext>> = Lazy::new(|| Arc::new(SerialContext::new("opencv-serial").expect("Can't start global serial context")));
+		Arc::clone(&GLOBAL)
+	}
+
+	/// Returns `true` when called from the context thread
+	#[inline]
+	pub fn is_current(&self) -> bool {
+		thread::current().id() == self.thread_id
+	}
+
+	/// Runs `f` on the context thread and waits for its result, a panic inside of `f` is propagated to the caller
+	///
+	/// Calls from the context thread itself (i.e. nested `run()`) execute `f` directly.
+	pub fn run<'f, R: Send + 'f>(&self, f: impl FnOnce() -> R + Send + 'f) -> R {
+		if self.is_current() {
+			return f();
+		}
+		let (res_sender, res_receiver) = mpsc::sync_channel(1);
+		let job: Box<dyn FnOnce() + Send + 'f> = Box::new(move || {
+			let _ = res_sender.send(panic::catch_unwind(AssertUnwindSafe(f)));
+		});
+		// SAFETY: the job can borrow from the caller only until it's finished or dropped, this function doesn't return
+		// before receiving the result that is sent at the end of the job or the error when the job is dropped unfinished
+		let job = unsafe { mem::transmute::<Box<dyn FnOnce() + Send + 'f>, Job>(job) };
+		self.sender.lock().expect("Can't lock serial context")
+			.send(job)
+			.expect("Serial context thread has stopped");
+		match res_receiver.recv().expect("Serial context thread has stopped") {
+			Ok(res) => res,
+			Err(payload) => panic::resume_unwind(payload),
+		}
+	}
+}
+
+/// Object that lives on the [SerialContext] thread
+///
+/// The object is created, accessed and dropped only on the context thread, `Serial` itself can be used from any thread.
+pub struct Serial<T: Send> {
+	context: Arc<SerialContext>,
+	inner: Mutex<Option<T>>,
+}
+
+impl<T: Send> Serial<T> {
+	/// Creates the object by running `create` on the `context` thread
+	pub fn new(context: Arc<SerialContext>, create: impl FnOnce() -> Result<T> + Send) -> Result<Self> {
+		let inner = context.run(create)?;
+		Ok(Self { context, inner: Mutex::new(Some(inner)) })
+	}
+
+	/// Creates the object on the [SerialContext::global] context
+	#[inline]
+	pub fn new_global(create: impl FnOnce() -> Result<T> + Send) -> Result<Self> {
+		Self::new(SerialContext::global(), create)
+	}
+
+	/// Runs `f` with the object on the context thread
+	///
+	/// Fails with `StsError` when called from inside of `f` of another `with()` call on the same object, the object is
+	/// already borrowed mutably there.
+	pub fn with<R: Send>(&self, f: impl FnOnce(&mut T) -> Result<R> + Send) -> Result<R> {
+		let inner = &self.inner;
+		self.context.run(move || {
+			// the object is only locked on the context thread, so it can only be locked already by the enclosing call
+			let mut inner = match inner.try_lock() {
+				Ok(inner) => inner,
+				Err(TryLockError::Poisoned(e)) => e.into_inner(),
+				Err(TryLockError::WouldBlock) => {
+					return Err(Error::new(core::StsError, "Serial object is already borrowed by the enclosing with() call"));
+				}
+			};
+			f(inner.as_mut().expect("Serial object is already dropped"))
+		})
+	}
+
+	#[inline]
+	pub fn context(&self) -> &Arc<SerialContext> {
+		&self.context
+	}
+}
+
+impl<T: Send> Drop for Serial<T> {
+	fn drop(&mut self) {
+		let inner = self.inner.get_mut().ok().and_then(|inner| inner.take());
+		if inner.is_some() {
+			self.context.run(move || drop(inner));
+		}
+	}
+}
@@ -0,0 +1,142 @@
+//! Vectorized implementations of small per-frame operations commonly done on the `Mat` data
+//!
+//! These functions work directly on the pixel slices (e.g. from `Mat::data_bytes()` or `Mat::data_typed()`) so they don't
+//! require an FFI call or an intermediate `Mat`. The module is available with the `simd` feature which requires nightly
+//! compiler because of `std::simd`.
+
+use std::simd::{num::SimdUint, Simd, simd_swizzle};
+
+use crate::{
+	core,
+	Error,
+	Result,
+};
+
+/// Number of pixels processed per iteration in the 3-channel `u8` swizzle
+const SWAP_RB_PIXELS: usize = 16;
+/// Number of values processed per iteration in the `u8` to `f32` conversion
+const NORMALIZE_LANES: usize = 16;
+/// Number of pixels processed per iteration in the 3-channel transpose
+const NCHW_PIXELS: usize = 8;
+
+/// Swaps the 1st and the 3rd byte of every 3 consecutive bytes, the rest of lanes are left in place
+const SWAP_RB_INDEX: [usize; 64] = {
+	let mut out = [0; 64];
+	let mut i = 0;
+	while i < out.len() {
+		out[i] = if i < SWAP_RB_PIXELS * 3 { i - i % 3 + 2 - i % 3 } else { i };
+		i += 1;
+	}
+	out
+};
+
+const fn nchw_index(channel: usize) -> [usize; NCHW_PIXELS] {
+	let mut out = [0; NCHW_PIXELS];
+	let mut i = 0;
+	while i < out.len() {
+		out[i] = i * 3 + channel;
+		i += 1;
+	}
+	out
+}
+
+/// Converts packed 3-channel BGR pixels into RGB (or vice versa), `src` and `dst` must be of the same length
+pub fn bgr_to_rgb(src: &[u8], dst: &mut [u8]) -> Result<()> {
+	check_len(src.len(), dst.len())?;
+	check_channels(src.len(), 3)?;
+	let mut i = 0;
+	// each iteration loads 64 bytes, but stores only 48 of them
+	while i + 64 <= src.len() {
+		let v = simd_swizzle!(Simd::<u8, 64>::from_slice(&src[i..]), SWAP_RB_INDEX);
+		dst[i..i + SWAP_RB_PIXELS * 3].copy_from_slice(&v.as_array()[..SWAP_RB_PIXELS * 3]);
+		i += SWAP_RB_PIXELS * 3;
+	}
+	for (src, dst) in src[i..].chunks_exact(3).zip(dst[i..].chunks_exact_mut(3)) {
+		dst[0] = src[2];
+		dst[1] = src[1];
+		dst[2] = src[0];
+	}
+	Ok(())
+}
+
+/// In-place version of [bgr_to_rgb]
+pub fn bgr_to_rgb_inplace(data: &mut [u8]) -> Result<()> {
+	check_channels(data.len(), 3)?;
+	let mut i = 0;
+	while i + 64 <= data.len() {
+		let v = simd_swizzle!(Simd::<u8, 64>::from_slice(&data[i..]), SWAP_RB_INDEX);
+		data[i..i + SWAP_RB_PIXELS * 3].copy_from_slice(&v.as_array()[..SWAP_RB_PIXELS * 3]);
+		i += SWAP_RB_PIXELS * 3;
+	}
+	data[i..].chunks_exact_mut(3).for_each(|px| px.swap(0, 2));
+	Ok(())
+}
+
+/// Converts `u8` values to `f32` as `dst = src * scale + offset`, `src` and `dst` must be of the same length
+///
+/// E.g. use `scale = 1. / 255.` and `offset = 0.` to get the values in `0..=1` range.
+pub fn normalize_u8_to_f32(src: &[u8], dst: &mut [f32], scale: f32, offset: f32) -> Result<()> {
+	check_len(src.len(), dst.len())?;
+	let scale_v = Simd::<f32, NORMALIZE_LANES>::splat(scale);
+	let offset_v = Simd::<f32, NORMALIZE_LANES>::splat(offset);
+	let mut src_chunks = src.chunks_exact(NORMALIZE_LANES);
+	let mut dst_chunks = dst.chunks_exact_mut(NORMALIZE_LANES);
+	for (src, dst) in (&mut src_chunks).zip(&mut dst_chunks) {
+		let v = Simd::<u8, NORMALIZE_LANES>::from_slice(src).cast::<f32>();
+		(v * scale_v + offset_v).copy_to_slice(dst);
+	}
+	for (&src, dst) in src_chunks.remainder().iter().zip(dst_chunks.into_remainder()) {
+		*dst = f32::from(src) * scale + offset;
+	}
+	Ok(())
+}
+
+/// Transposes interleaved `height x width x channels` (NHWC) image into planar `channels x height x width` (NCHW)
+/// layout suitable as a `dnn` network input
+///
+/// `src` and `dst` must both contain `height * width * channels` elements. 3-channel images use the vectorized path,
+/// other channel counts are transposed element by element.
+pub fn nhwc_to_nchw(src: &[f32], dst: &mut [f32], height: usize, width: usize, channels: usize) -> Result<()> {
+	check_len(src.len(), dst.len())?;
+	let plane = height * width;
+	if src.len() != plane * channels {
+		return Err(Error::new(core::StsUnmatchedSizes, format!("Expected: {}x{}x{} elements, but got: {}", height, width, channels, src.len())));
+	}
+	let mut px = 0;
+	if channels == 3 {
+		let (dst_0, dst_rest) = dst.split_at_mut(plane);
+		let (dst_1, dst_2) = dst_rest.split_at_mut(plane);
+		// each iteration loads 32 values, but uses only 24 of them
+		while px * 3 + 32 <= src.len() {
+			let v = Simd::<f32, 32>::from_slice(&src[px * 3..]);
+			simd_swizzle!(v, nchw_index(0)).copy_to_slice(&mut dst_0[px..]);
+			simd_swizzle!(v, nchw_index(1)).copy_to_slice(&mut dst_1[px..]);
+			simd_swizzle!(v, nchw_index(2)).copy_to_slice(&mut dst_2[px..]);
+			px += NCHW_PIXELS;
+		}
+	}
+	for (px, src) in src.chunks_exact(channels.max(1)).enumerate().skip(px) {
+		for (c, &v) in src.iter().enumerate() {
+			dst[c * plane + px] = v;
+		}
+	}
+	Ok(())
+}
+
+#[inline]
+fn check_len(src: usize, dst: usize) -> Result<()> {
+	if src == dst {
+		Ok(())
+	} else {
+		Err(Error::new(core::StsUnmatchedSizes, format!("Source length: {} doesn't match destination length: {}", src, dst)))
+	}
+}
+
+#[inline]
+fn check_channels(len: usize, channels: usize) -> Result<()> {
+	if len.is_multiple_of(channels) {
+		Ok(())
+	} else {
+		Err(Error::new(core::StsBadArg, format!("Length: {} is not a multiple of: {} channels", len, channels)))
+	}
+}
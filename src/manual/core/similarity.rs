@@ -0,0 +1,135 @@
+use crate::{
+	core::{self, Mat, Matx33d, Point3d, Vec3d},
+	Error,
+	prelude::*,
+	Result,
+};
+
+/// Similarity transform `dst = s * R * src + t`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Similarity {
+	/// Rotation
+	pub r: Matx33d,
+	/// Translation
+	pub t: Vec3d,
+	/// Scale, `1` when estimated without the scale
+	pub s: f64,
+}
+
+impl Similarity {
+	pub fn identity() -> Self {
+		Self { r: Matx33d::from([1., 0., 0., 0., 1., 0., 0., 0., 1.]), t: Vec3d::default(), s: 1. }
+	}
+
+	/// Transforms the point
+	#[inline]
+	pub fn apply(&self, p: Point3d) -> Point3d {
+		let r = &self.r;
+		Point3d::new(
+			self.s * (r[(0, 0)] * p.x + r[(0, 1)] * p.y + r[(0, 2)] * p.z) + self.t[0],
+			self.s * (r[(1, 0)] * p.x + r[(1, 1)] * p.y + r[(1, 2)] * p.z) + self.t[1],
+			self.s * (r[(2, 0)] * p.x + r[(2, 1)] * p.y + r[(2, 2)] * p.z) + self.t[2],
+		)
+	}
+
+	/// Returns the transform from `dst` to `src`
+	pub fn inverse(&self) -> Self {
+		let r = &self.r;
+		let rt = Matx33d::from([
+			r[(0, 0)], r[(1, 0)], r[(2, 0)],
+			r[(0, 1)], r[(1, 1)], r[(2, 1)],
+			r[(0, 2)], r[(1, 2)], r[(2, 2)],
+		]);
+		let s = 1. / self.s;
+		let mut out = Self { r: rt, t: Vec3d::default(), s };
+		let t = out.apply(Point3d::new(self.t[0], self.t[1], self.t[2]));
+		out.t = Vec3d::from([-t.x, -t.y, -t.z]);
+		out
+	}
+
+	/// Returns the `3x4` `CV_64F` matrix `[s*R|t]`
+	pub fn to_mat(&self) -> Result<Mat> {
+		let row = |i: usize| [self.s * self.r[(i, 0)], self.s * self.r[(i, 1)], self.s * self.r[(i, 2)], self.t[i]];
+		Mat::from_slice_2d(&[row(0), row(1), row(2)])
+	}
+}
+
+impl Default for Similarity {
+	#[inline]
+	fn default() -> Self {
+		Self::identity()
+	}
+}
+
+/// Estimates the least squares similarity transform mapping `src` points onto `dst` points (Umeyama)
+///
+/// Unlike `sfm::compute_orientation()` the rotation is guaranteed to be proper (no reflection) and the scale is
+/// optional, set `with_scale` to `false` for the rigid transform. At least 3 non-collinear correspondences are
+/// required, use `sfm::cloud::points_from_mat()` to convert the `3xN` Mats.
+pub fn estimate_similarity(src: &[Point3d], dst: &[Point3d], with_scale: bool) -> Result<Similarity> {
+	if src.len() != dst.len() {
+		return Err(Error::new(core::StsUnmatchedSizes, format!("Got {} source points but {} destination points", src.len(), dst.len())));
+	}
+	if src.len() < 3 {
+		return Err(Error::new(core::StsBadArg, format!("Similarity estimation requires at least 3 points, got: {}", src.len())));
+	}
+	let n = src.len() as f64;
+	let mean = |points: &[Point3d]| points.iter().fold(Point3d::default(), |acc, &p| acc + p) / n;
+	let (mean_src, mean_dst) = (mean(src), mean(dst));
+
+	let mut cov = [[0.; 3]; 3];
+	let mut var_src = 0.;
+	for (&s, &d) in src.iter().zip(dst) {
+		let (s, d) = (s - mean_src, d - mean_dst);
+		let (s, d) = ([s.x, s.y, s.z], [d.x, d.y, d.z]);
+		for (r, row) in cov.iter_mut().enumerate() {
+			for (c, v) in row.iter_mut().enumerate() {
+				*v += d[r] * s[c] / n;
+			}
+		}
+		var_src += (s[0] * s[0] + s[1] * s[1] + s[2] * s[2]) / n;
+	}
+	let mut w = Mat::default();
+	let mut u = Mat::default();
+	let mut vt = Mat::default();
+	core::sv_decomp(&Mat::from_slice_2d(&cov)?, &mut w, &mut u, &mut vt, 0)?;
+	let w = w.data_typed::<f64>()?;
+	// the second singular value vanishes for the collinear points, the rotation around their line is undetermined
+	if w[1] <= 1e-12 * w[0] {
+		return Err(Error::new(core::StsError, "Points are degenerate (coincident or collinear)"));
+	}
+	let u = read_3x3(&u)?;
+	let vt = read_3x3(&vt)?;
+	let reflection = if det(&u) * det(&vt) < 0. { -1. } else { 1. };
+	let mut r = Matx33d::default();
+	for i in 0..3 {
+		for j in 0..3 {
+			r[(i, j)] = u[i][0] * vt[0][j] + u[i][1] * vt[1][j] + reflection * u[i][2] * vt[2][j];
+		}
+	}
+	let s = if with_scale {
+		(w[0] + w[1] + reflection * w[2]) / var_src
+	} else {
+		1.
+	};
+	let mut out = Similarity { r, t: Vec3d::default(), s };
+	let rotated = out.apply(mean_src);
+	out.t = Vec3d::from([mean_dst.x - rotated.x, mean_dst.y - rotated.y, mean_dst.z - rotated.z]);
+	Ok(out)
+}
+
+fn read_3x3(m: &Mat) -> Result<[[f64; 3]; 3]> {
+	let mut out = [[0.; 3]; 3];
+	for (r, row) in out.iter_mut().enumerate() {
+		for (c, v) in row.iter_mut().enumerate() {
+			*v = *m.at_2d::<f64>(r as i32, c as i32)?;
+		}
+	}
+	Ok(out)
+}
+
+fn det(m: &[[f64; 3]; 3]) -> f64 {
+	m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+		- m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+		+ m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+}
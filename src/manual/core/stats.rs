@@ -0,0 +1,107 @@
+//! Descriptive statistics of the `Mat` contents for quick data sanity checks
+//!
+//! ```ignore
+//! for (channel, summary) in core::stats::describe(&img)?.iter().enumerate() {
+//!     println!("{}: {}", channel, summary);
+//! }
+//! ```
+
+use std::{cmp::Ordering, fmt};
+
+use crate::{
+	core::{self, Mat},
+	Error,
+	prelude::*,
+	Result,
+};
+
+/// Summary statistics of a set of values, `NaN` values are excluded
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Summary {
+	/// Number of the non-`NaN` values
+	pub count: usize,
+	pub min: f64,
+	pub max: f64,
+	pub mean: f64,
+	/// Population standard deviation
+	pub std_dev: f64,
+	/// 25th percentile
+	pub q25: f64,
+	pub median: f64,
+	/// 75th percentile
+	pub q75: f64,
+}
+
+impl Summary {
+	/// Calculates the summary of the `values`, all fields except `count` are `NaN` if there are no values
+	pub fn from_values(values: impl IntoIterator<Item=f64>) -> Self {
+		let mut values = values.into_iter().filter(|v| !v.is_nan()).collect::<Vec<_>>();
+		if values.is_empty() {
+			return Self { count: 0, min: f64::NAN, max: f64::NAN, mean: f64::NAN, std_dev: f64::NAN, q25: f64::NAN, median: f64::NAN, q75: f64::NAN };
+		}
+		values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+		let count = values.len();
+		let mean = values.iter().sum::<f64>() / count as f64;
+		let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / count as f64;
+		Self {
+			count,
+			min: values[0],
+			max: values[count - 1],
+			mean,
+			std_dev: variance.sqrt(),
+			q25: quantile(&values, 0.25),
+			median: quantile(&values, 0.5),
+			q75: quantile(&values, 0.75),
+		}
+	}
+}
+
+impl fmt::Display for Summary {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"count: {}, min: {}, q25: {}, median: {}, q75: {}, max: {}, mean: {}, std: {}",
+			self.count, self.min, self.q25, self.median, self.q75, self.max, self.mean, self.std_dev,
+		)
+	}
+}
+
+/// Returns the summary of every channel of `mat`
+pub fn describe(mat: &Mat) -> Result<Vec<Summary>> {
+	let values = to_f64_values(mat)?;
+	let channels = mat.channels() as usize;
+	Ok((0..channels)
+		.map(|c| Summary::from_values(values.iter().skip(c).step_by(channels).copied()))
+		.collect())
+}
+
+/// Returns the summary of every column of the single channel 2D `mat` (e.g. one summary per feature of a sample matrix)
+pub fn describe_columns(mat: &Mat) -> Result<Vec<Summary>> {
+	if mat.channels() != 1 || mat.dims() > 2 {
+		return Err(Error::new(core::StsBadArg, format!("Expected single channel 2D Mat, but got: {} channels and {} dims", mat.channels(), mat.dims())));
+	}
+	let values = to_f64_values(mat)?;
+	let cols = mat.cols() as usize;
+	Ok((0..cols)
+		.map(|c| Summary::from_values(values.iter().skip(c).step_by(cols.max(1)).copied()))
+		.collect())
+}
+
+/// Returns all values of `mat` converted to `f64` in the continuous row-major order
+fn to_f64_values(mat: &Mat) -> Result<Vec<f64>> {
+	if mat.empty() {
+		return Ok(vec![]);
+	}
+	let mut mat_f64 = Mat::default();
+	mat.convert_to(&mut mat_f64, core::CV_64F, 1., 0.)?;
+	let mat_f64 = if mat_f64.is_continuous() { mat_f64 } else { mat_f64.try_clone()? };
+	Ok(unsafe { std::slice::from_raw_parts(mat_f64.data().cast::<f64>(), mat_f64.total() * mat_f64.channels() as usize) }.to_vec())
+}
+
+/// Linear interpolation between the closest ranks of the sorted `values`
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+	let pos = q * (sorted.len() - 1) as f64;
+	let lower = pos.floor() as usize;
+	let upper = pos.ceil() as usize;
+	sorted[lower] + (sorted[upper] - sorted[lower]) * (pos - lower as f64)
+}
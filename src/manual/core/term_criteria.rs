@@ -0,0 +1,34 @@
+use crate::core::{TermCriteria, TermCriteria_Type};
+
+impl TermCriteria {
+	/// Creates criteria that stops after `max_count` iterations
+	#[inline]
+	pub fn max_iter(max_count: i32) -> Self {
+		Self { typ: TermCriteria_Type::COUNT as i32, max_count, epsilon: 0. }
+	}
+
+	/// Creates criteria that stops when the desired accuracy `epsilon` is reached
+	#[inline]
+	pub fn eps(epsilon: f64) -> Self {
+		Self { typ: TermCriteria_Type::EPS as i32, max_count: 0, epsilon }
+	}
+
+	/// Creates criteria that stops after `max_count` iterations or when the desired accuracy `epsilon` is reached,
+	/// whichever comes first
+	#[inline]
+	pub fn both(max_count: i32, epsilon: f64) -> Self {
+		Self { typ: TermCriteria_Type::COUNT as i32 | TermCriteria_Type::EPS as i32, max_count, epsilon }
+	}
+
+	/// Returns true if the criteria includes the iteration limit
+	#[inline]
+	pub fn has_max_iter(&self) -> bool {
+		self.typ & TermCriteria_Type::COUNT as i32 != 0
+	}
+
+	/// Returns true if the criteria includes the accuracy limit
+	#[inline]
+	pub fn has_eps(&self) -> bool {
+		self.typ & TermCriteria_Type::EPS as i32 != 0
+	}
+}
@@ -0,0 +1,65 @@
+use crate::{
+	core::{self, Mat, Point, Scalar, ToInputArray},
+	Error,
+	prelude::*,
+	Result,
+};
+
+/// Result of [min_max]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MinMax {
+	pub min: f64,
+	pub max: f64,
+	pub min_loc: Point,
+	pub max_loc: Point,
+}
+
+/// Result of [mean_std]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MeanStdDev {
+	/// Per-channel mean
+	pub mean: Scalar,
+	/// Per-channel standard deviation
+	pub std_dev: Scalar,
+}
+
+/// Same as `min_max_loc()`, but returns all values at once instead of filling the output arguments
+///
+/// `src` must be a single channel array, pass `&no_array()` as `mask` to consider all elements.
+#[inline]
+pub fn min_max(src: &dyn ToInputArray, mask: &dyn ToInputArray) -> Result<MinMax> {
+	let mut out = MinMax::default();
+	core::min_max_loc(src, Some(&mut out.min), Some(&mut out.max), Some(&mut out.min_loc), Some(&mut out.max_loc), mask)?;
+	Ok(out)
+}
+
+/// Same as `mean_std_dev()`, but returns the values as `Scalar`s instead of filling the output arrays
+pub fn mean_std(src: &dyn ToInputArray, mask: &dyn ToInputArray) -> Result<MeanStdDev> {
+	let mut mean = Mat::default();
+	let mut std_dev = Mat::default();
+	core::mean_std_dev(src, &mut mean, &mut std_dev, mask)?;
+	Ok(MeanStdDev { mean: mat_to_scalar(&mean)?, std_dev: mat_to_scalar(&std_dev)? })
+}
+
+/// Same as `reduce()`, but returns the reduced vector directly
+///
+/// `dim` is `0` to reduce to a single row (one value per column) and `1` to reduce to a single column (one value per
+/// row), `rtype` is one of `REDUCE_*` constants. The result is calculated with the type of `T`, which must match the
+/// channel count of `src`.
+pub fn reduce_to_vec<T: DataType>(src: &dyn ToInputArray, dim: i32, rtype: i32) -> Result<Vec<T>> {
+	let mut dst = Mat::default();
+	core::reduce(src, &mut dst, dim, rtype, T::depth())?;
+	if dst.channels() != T::channels() {
+		return Err(Error::new(core::StsUnmatchedFormats, format!("Reduced Mat has: {} channels, but requested type has: {}", dst.channels(), T::channels())));
+	}
+	Ok(dst.data_typed::<T>()?.to_vec())
+}
+
+/// Converts the `Nx1` `CV_64F` output of the statistical functions into `Scalar`
+fn mat_to_scalar(mat: &Mat) -> Result<Scalar> {
+	let mut out = Scalar::default();
+	for (dst, src) in out.iter_mut().zip(mat.data_typed::<f64>()?) {
+		*dst = *src;
+	}
+	Ok(out)
+}
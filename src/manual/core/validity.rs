@@ -0,0 +1,111 @@
+//! Validity tracking of the boxed objects for catching the use of the released objects
+//!
+//! This is a diagnostic mode enabled in the builds with `debug_assertions`, in the release builds nothing is poisoned
+//! and [check] only rejects the null pointers. The address of every dropped boxed object (`Mat`, `Ptr`, algorithm
+//! classes, etc.) is poisoned. The generated methods of the boxed classes check the object pointer before passing it to OpenCV, so a call through a stale wrapper (e.g.
+//! the one created by `Boxed::from_raw()` from a pointer that was already owned and dropped by another wrapper) fails
+//! with `StsObjectNotFound` instead of passing the dangling pointer to OpenCV. The infallible methods can't return an
+//! error, they only check the pointer with a debug assertion. The poison is cleared when a new object is wrapped at
+//! the same address. Use `Boxed::validate()` to check the object explicitly.
+//!
+//! The poison flag of an object is a single atomic slot selected by its address, so poisoning, clearing and checking
+//! never lock. The slots are shared between the addresses, when another object is released into the same slot the
+//! earlier poison is forgotten, so a use long after the release can go undetected. Every pointer that the generated
+//! methods check is cleared when it's wrapped: the object pointer in `Boxed::from_raw()` and, for `Ptr`, also the
+//! pointer to the inner object, so a live object allocated at the address of a released one is never reported as
+//! released.
+
+use std::ffi::c_void;
+#[cfg(debug_assertions)]
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::{
+	core,
+	Error,
+	Result,
+};
+
+#[cfg(debug_assertions)]
+const SLOT_COUNT: usize = 4096;
+
+#[cfg(debug_assertions)]
+#[allow(clippy::declare_interior_mutable_const)]
+const EMPTY_SLOT: AtomicUsize = AtomicUsize::new(0);
+
+/// Address of the last released object for each slot, 0 if it's empty
+#[cfg(debug_assertions)]
+static POISONED: [AtomicUsize; SLOT_COUNT] = [EMPTY_SLOT; SLOT_COUNT];
+
+#[cfg(debug_assertions)]
+#[inline]
+fn slot(ptr: *const c_void) -> &'static AtomicUsize {
+	// the allocations are at least 16 bytes aligned, so the lowest bits don't help with the distribution
+	&POISONED[(ptr as usize >> 4) % SLOT_COUNT]
+}
+
+/// Returns `true` if the object at `ptr` was dropped and no new object was wrapped at the same address since
+///
+/// Always `false` without `debug_assertions`.
+#[inline]
+pub fn is_poisoned(ptr: *const c_void) -> bool {
+	#[cfg(debug_assertions)]
+	{
+		!ptr.is_null() && slot(ptr).load(Ordering::Acquire) == ptr as usize
+	}
+	#[cfg(not(debug_assertions))]
+	{
+		let _ = ptr;
+		false
+	}
+}
+
+/// Checks the raw pointer of the boxed object of `type_name`, fails with `StsNullPtr` if it's null or with
+/// `StsObjectNotFound` if the object was released
+#[inline]
+pub fn check(type_name: &str, ptr: *const c_void) -> Result<()> {
+	if ptr.is_null() {
+		Err(Error::new(core::StsNullPtr, format!("{} holds a null pointer", type_name)))
+	} else if is_poisoned(ptr) {
+		Err(Error::new(core::StsObjectNotFound, format!("{} at {:p} was already released", type_name, ptr)))
+	} else {
+		Ok(())
+	}
+}
+
+/// Clears the poison from the newly wrapped object, called from `Boxed::from_raw()`
+#[doc(hidden)]
+#[inline]
+pub fn track_wrap(ptr: *const c_void) {
+	#[cfg(debug_assertions)]
+	if is_poisoned(ptr) {
+		// another object could have been released into the same slot in the meantime, keep its poison then
+		let _ = slot(ptr).compare_exchange(ptr as usize, 0, Ordering::AcqRel, Ordering::Relaxed);
+	}
+	#[cfg(not(debug_assertions))]
+	let _ = ptr;
+}
+
+/// Poisons the address of the object deleted by drop
+///
+/// Must be called before the object is deleted, as soon as the memory is freed another thread can get the same address
+/// for a new object and clear the poison in [track_wrap], poisoning afterwards would mark that live object as released.
+#[doc(hidden)]
+#[inline]
+pub fn track_drop(ptr: *const c_void) {
+	#[cfg(debug_assertions)]
+	if !ptr.is_null() {
+		slot(ptr).store(ptr as usize, Ordering::Release);
+	}
+	#[cfg(not(debug_assertions))]
+	let _ = ptr;
+}
+
+/// Asserts that the object is not released before passing its pointer to OpenCV, called from the generated infallible
+/// methods of the boxed classes
+#[doc(hidden)]
+#[inline]
+pub fn debug_check(type_name: &str, ptr: *const c_void) {
+	debug_assert!(!is_poisoned(ptr), "Use of {} at {:p} after it was released", type_name, ptr);
+	#[cfg(not(debug_assertions))]
+	let _ = (type_name, ptr);
+}
@@ -12,6 +12,7 @@ pub use iter::{VectorIterator, VectorRefIterator};
 pub use vector_extern::{VectorElement, VectorExtern, VectorExternCopyNonBool};
 
 use crate::{
+	core,
 	platform_types::size_t,
 	Result,
 	traits::{Boxed, OpenCVType, OpenCVTypeArg, OpenCVTypeExternContainer},
@@ -252,6 +253,7 @@ impl<T: VectorElement + fmt::Debug> fmt::Debug for Vector<T> where Self: VectorE
 
 impl<T: VectorElement> Drop for Vector<T> where Self: VectorExtern<T> {
 	fn drop(&mut self) {
+		core::alloc_tracking::track_release(self.ptr);
 		unsafe { self.extern_delete() }
 	}
 }
@@ -270,12 +272,15 @@ impl<'a, T: VectorElement> Extend<<T as OpenCVType<'a>>::Arg> for Vector<T> wher
 impl<T: VectorElement> Boxed for Vector<T> where Self: VectorExtern<T> {
 	#[inline]
 	unsafe fn from_raw(ptr: *mut c_void) -> Self {
+		core::alloc_tracking::track_alloc(std::any::type_name::<Self>(), ptr);
 		Self { ptr, _d: PhantomData }
 	}
 
 	#[inline]
 	fn into_raw(self) -> *mut c_void {
-		ManuallyDrop::new(self).ptr
+		let ptr = ManuallyDrop::new(self).ptr;
+		core::alloc_tracking::track_release(ptr);
+		ptr
 	}
 
 	#[inline]
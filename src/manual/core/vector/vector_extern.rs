@@ -74,52 +74,52 @@ macro_rules! vector_extern {
 		impl $crate::manual::core::VectorExtern<$type> for $crate::manual::core::Vector<$type> {
 			#[inline]
 			unsafe fn extern_new() -> $vector_extern_mut {
-				$extern_new()
+				ffi_call!(local $extern_new())
 			}
 
 			#[inline]
 			unsafe fn extern_delete(&mut self) {
-				$extern_delete(self.as_raw_mut())
+				ffi_call!(local $extern_delete(self.as_raw_mut()))
 			}
 
 			#[inline]
 			unsafe fn extern_len(&self) -> $crate::platform_types::size_t {
-				$extern_len(self.as_raw())
+				ffi_call!(local $extern_len(self.as_raw()))
 			}
 
 			#[inline]
 			unsafe fn extern_is_empty(&self) -> bool {
-				$extern_is_empty(self.as_raw())
+				ffi_call!(local $extern_is_empty(self.as_raw()))
 			}
 
 			#[inline]
 			unsafe fn extern_capacity(&self) -> $crate::platform_types::size_t {
-				$extern_capacity(self.as_raw())
+				ffi_call!(local $extern_capacity(self.as_raw()))
 			}
 
 			#[inline]
 			unsafe fn extern_shrink_to_fit(&mut self) {
-				$extern_shrink_to_fit(self.as_raw_mut())
+				ffi_call!(local $extern_shrink_to_fit(self.as_raw_mut()))
 			}
 
 			#[inline]
 			unsafe fn extern_reserve(&mut self, additional: $crate::platform_types::size_t) {
-				$extern_reserve(self.as_raw_mut(), additional)
+				ffi_call!(local $extern_reserve(self.as_raw_mut(), additional))
 			}
 
 			#[inline]
 			unsafe fn extern_remove(&mut self, index: $crate::platform_types::size_t) {
-				$extern_remove(self.as_raw_mut(), index)
+				ffi_call!(local $extern_remove(self.as_raw_mut(), index))
 			}
 
 			#[inline]
 			unsafe fn extern_swap(&mut self, index1: $crate::platform_types::size_t, index2: $crate::platform_types::size_t) {
-				$extern_swap(self.as_raw_mut(), index1, index2)
+				ffi_call!(local $extern_swap(self.as_raw_mut(), index1, index2))
 			}
 
 			#[inline]
 			unsafe fn extern_clear(&mut self) {
-				$extern_clear(self.as_raw_mut())
+				ffi_call!(local $extern_clear(self.as_raw_mut()))
 			}
 
 			#[inline]
@@ -175,7 +175,7 @@ macro_rules! vector_copy_non_bool {
 			#[inline]
 			unsafe fn extern_clone(&self) -> $vector_extern_mut {
 				extern "C" { fn $extern_clone(instance: $vector_extern_const) -> $vector_extern_mut; }
-				$extern_clone(self.as_raw())
+				ffi_call!(local $extern_clone(self.as_raw()))
 			}
 		}
 
@@ -197,19 +197,19 @@ macro_rules! vector_copy_non_bool {
 			#[inline]
 			unsafe fn extern_data(&self) -> *const $type {
 				extern "C" { fn $extern_data_const(instance: $vector_extern_const) -> *const $type; }
-				$extern_data_const(self.as_raw())
+				ffi_call!(local $extern_data_const(self.as_raw()))
 			}
 
 			#[inline]
 			unsafe fn extern_data_mut(&mut self) -> *mut $type {
 				extern "C" { fn $extern_data_mut(instance: $vector_extern_mut) -> *mut $type; }
-				$extern_data_mut(self.as_raw_mut())
+				ffi_call!(local $extern_data_mut(self.as_raw_mut()))
 			}
 
 			#[inline]
 			unsafe fn extern_from_slice(data: *const $type, len: $crate::platform_types::size_t) -> $vector_extern_mut {
 				extern "C" { fn $extern_from_slice(data: *const $type, len: $crate::platform_types::size_t) -> $vector_extern_mut; }
-				$extern_from_slice(data, len)
+				ffi_call!(local $extern_from_slice(data, len))
 			}
 		}
 	};
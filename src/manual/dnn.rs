@@ -31,9 +31,22 @@ impl LayerParams {
 	pub fn default() -> Result<Self> {
 		extern "C" { fn cv_dnn_LayerParams_LayerParams(ocvrs_return: *mut sys::Result<*mut c_void>); }
 		return_send!(via ocvrs_return);
-		unsafe { cv_dnn_LayerParams_LayerParams(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(local cv_dnn_LayerParams_LayerParams(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret.into_result()
 			.map(|ptr| unsafe { LayerParams::from_raw(ptr) })
 	}
 }
+
+pub use preprocess::*;
+
+#[cfg(not(ocvrs_opencv_branch_32))]
+pub mod custom_layer;
+pub mod decode;
+pub mod masks;
+pub mod pose;
+mod preprocess;
+pub mod profile;
+#[cfg(ocvrs_opencv_branch_4)]
+pub mod quantization;
+pub mod zoo;
@@ -0,0 +1,198 @@
+//! Layers implemented in Rust
+//!
+//! The network importers create the layers through the `LayerFactory` by their type name, registering a custom layer
+//! type allows running the models with the operations that OpenCV doesn't implement:
+//! ```ignore
+//! // ONNX "HardTanh" node, the attributes of the node are available in `params`
+//! dnn::custom_layer::register("HardTanh", |params, inputs, outputs| {
+//!     let min = params.get("min_val")?.get_f64(-1)?;
+//!     let max = params.get("max_val")?.get_f64(-1)?;
+//!     let mut out = outputs.get(0)?;
+//!     core::max(&inputs.get(0)?, &Scalar::all(min), &mut out)?;
+//!     core::min(&out.clone(), &Scalar::all(max), &mut out)
+//! })?;
+//! let mut net = dnn::read_net_from_onnx("model.onnx")?;
+//! ```
+//! The outputs passed into the forward closure are preallocated with the shapes from the shape closure (the shapes of
+//! the inputs by default), the closure can either write into them or replace them with the `Mat`s of the same shape and
+//! type. The custom layers run on `DNN_BACKEND_OPENCV`, the OpenCL and FP16 blobs are converted to `CV_32F` `Mat`s.
+
+use std::{
+	ffi::{c_void, CString},
+	mem::ManuallyDrop,
+	ops::{Deref, DerefMut},
+	os::raw::c_char,
+	panic::{self, AssertUnwindSafe},
+};
+
+use crate::{
+	core::{self, Mat, Vector},
+	dnn::{LayerParams, MatShape},
+	Error,
+	prelude::*,
+	Result,
+	sys,
+};
+
+/// Computes the output shapes of the layer from the shapes of its inputs
+pub type ShapesFn = dyn Fn(&LayerParams, &Vector<MatShape>) -> Result<Vector<MatShape>> + Send + Sync;
+
+/// Computes the outputs of the layer from its inputs
+pub type ForwardFn = dyn Fn(&LayerParams, &Vector<Mat>, &mut Vector<Mat>) -> Result<()> + Send + Sync;
+
+/// Closures of a registered layer type, owned by the C++ side
+struct Callbacks {
+	shapes: Option<Box<ShapesFn>>,
+	forward: Box<ForwardFn>,
+}
+
+/// Registers the layer type `typ` with the outputs of the same shape as the first input, see [register_with_shapes]
+#[inline]
+pub fn register(typ: &str, forward: impl Fn(&LayerParams, &Vector<Mat>, &mut Vector<Mat>) -> Result<()> + Send + Sync + 'static) -> Result<()> {
+	register_callbacks(typ, Callbacks { shapes: None, forward: Box::new(forward) })
+}
+
+/// Registers the layer type `typ` implemented by the `forward` closure with the output shapes computed by `shapes`
+///
+/// Registering the type again replaces the previous closures for the layers created afterwards. The types registered
+/// by OpenCV itself can be overridden too, [unregister] restores the OpenCV implementation.
+#[inline]
+pub fn register_with_shapes(
+	typ: &str,
+	shapes: impl Fn(&LayerParams, &Vector<MatShape>) -> Result<Vector<MatShape>> + Send + Sync + 'static,
+	forward: impl Fn(&LayerParams, &Vector<Mat>, &mut Vector<Mat>) -> Result<()> + Send + Sync + 'static,
+) -> Result<()> {
+	register_callbacks(typ, Callbacks { shapes: Some(Box::new(shapes)), forward: Box::new(forward) })
+}
+
+/// Unregisters the layer type `typ`, the already created layers keep working
+///
+/// Does nothing if `typ` is not registered by [register] or [register_with_shapes].
+pub fn unregister(typ: &str) -> Result<()> {
+	extern "C" { fn cv_manual_dnn_CustomLayer_unregister(typ: *const c_char, ocvrs_return: *mut sys::Result_void); }
+	let typ = type_name(typ)?;
+	return_send!(via ocvrs_return);
+	unsafe { ffi_call!(local cv_manual_dnn_CustomLayer_unregister(typ.as_ptr(), ocvrs_return.as_mut_ptr())) };
+	return_receive!(unsafe ocvrs_return => ret);
+	ret.into_result()
+}
+
+type ShapesTrampoline = unsafe extern "C" fn(user: *mut c_void, params: *const c_void, inputs: *const c_void, outputs: *mut c_void, error: *mut c_void) -> bool;
+type ForwardTrampoline = unsafe extern "C" fn(user: *mut c_void, params: *const c_void, inputs: *const c_void, outputs: *mut c_void, error: *mut c_void) -> bool;
+type DropTrampoline = unsafe extern "C" fn(user: *mut c_void);
+
+fn register_callbacks(typ: &str, callbacks: Callbacks) -> Result<()> {
+	extern "C" {
+		fn cv_manual_dnn_CustomLayer_register(
+			typ: *const c_char,
+			user: *mut c_void,
+			shapes: Option<ShapesTrampoline>,
+			forward: ForwardTrampoline,
+			drop: DropTrampoline,
+			ocvrs_return: *mut sys::Result_void,
+		);
+	}
+	let typ = type_name(typ)?;
+	let shapes = if callbacks.shapes.is_some() {
+		Some(shapes_trampoline as ShapesTrampoline)
+	} else {
+		None
+	};
+	let user = Box::into_raw(Box::new(callbacks)) as *mut c_void;
+	return_send!(via ocvrs_return);
+	// the C++ side takes the ownership of `user` and releases it with `drop_trampoline`
+	unsafe { ffi_call!(local cv_manual_dnn_CustomLayer_register(typ.as_ptr(), user, shapes, forward_trampoline as ForwardTrampoline, drop_trampoline as DropTrampoline, ocvrs_return.as_mut_ptr())) };
+	return_receive!(unsafe ocvrs_return => ret);
+	ret.into_result()
+}
+
+fn type_name(typ: &str) -> Result<CString> {
+	if typ.is_empty() {
+		return Err(Error::new(core::StsBadArg, "Layer type must not be empty"));
+	}
+	CString::new(typ).map_err(|_| Error::new(core::StsBadArg, format!("Layer type must not contain NUL: {}", typ)))
+}
+
+unsafe extern "C" fn shapes_trampoline(user: *mut c_void, params: *const c_void, inputs: *const c_void, outputs: *mut c_void, error: *mut c_void) -> bool {
+	let callbacks = &*(user as *const Callbacks);
+	let params = Borrowed::<LayerParams>::new(params as _);
+	let inputs = Borrowed::<Vector<MatShape>>::new(inputs as _);
+	let mut outputs = Borrowed::<Vector<MatShape>>::new(outputs);
+	let res = call(|| {
+		let shapes = callbacks.shapes.as_ref().expect("Shapes trampoline is only registered together with the closure");
+		let shapes = shapes(&params, &inputs)?;
+		outputs.clear();
+		for shape in shapes {
+			outputs.push(shape);
+		}
+		Ok(())
+	});
+	report(res, error)
+}
+
+unsafe extern "C" fn forward_trampoline(user: *mut c_void, params: *const c_void, inputs: *const c_void, outputs: *mut c_void, error: *mut c_void) -> bool {
+	let callbacks = &*(user as *const Callbacks);
+	let params = Borrowed::<LayerParams>::new(params as _);
+	let inputs = Borrowed::<Vector<Mat>>::new(inputs as _);
+	let mut outputs = Borrowed::<Vector<Mat>>::new(outputs);
+	let res = call(|| (callbacks.forward)(&params, &inputs, &mut outputs));
+	report(res, error)
+}
+
+unsafe extern "C" fn drop_trampoline(user: *mut c_void) {
+	drop(Box::from_raw(user as *mut Callbacks));
+}
+
+/// Runs the closure, the panics must not unwind into the C++ code
+fn call(f: impl FnOnce() -> Result<()>) -> Result<()> {
+	panic::catch_unwind(AssertUnwindSafe(f))
+		.unwrap_or_else(|e| {
+			let msg = e.downcast_ref::<String>().map(String::as_str)
+				.or_else(|| e.downcast_ref::<&str>().copied())
+				.unwrap_or("unknown error");
+			Err(Error::new(core::StsError, format!("Custom layer panicked: {}", msg)))
+		})
+}
+
+/// Stores the error for the C++ side, returns `false` on error
+unsafe fn report(res: Result<()>, error: *mut c_void) -> bool {
+	extern "C" { fn cv_manual_dnn_CustomLayer_setError(error: *mut c_void, code: i32, message: *const c_char); }
+	match res {
+		Ok(()) => true,
+		Err(e) => {
+			let message = CString::new(e.message.replace('\0', "")).expect("NUL bytes are removed");
+			ffi_call!(local cv_manual_dnn_CustomLayer_setError(error, e.code, message.as_ptr()));
+			false
+		}
+	}
+}
+
+/// Object owned by the C++ side, it's not destroyed when the wrapper is dropped
+struct Borrowed<T: Boxed>(ManuallyDrop<T>);
+
+impl<T: Boxed> Borrowed<T> {
+	unsafe fn new(ptr: *mut c_void) -> Self {
+		Self(ManuallyDrop::new(T::from_raw(ptr)))
+	}
+}
+
+impl<T: Boxed> Drop for Borrowed<T> {
+	fn drop(&mut self) {
+		// balances the allocation tracking of `from_raw`
+		unsafe { ManuallyDrop::take(&mut self.0) }.into_raw();
+	}
+}
+
+impl<T: Boxed> Deref for Borrowed<T> {
+	type Target = T;
+
+	fn deref(&self) -> &T {
+		&self.0
+	}
+}
+
+impl<T: Boxed> DerefMut for Borrowed<T> {
+	fn deref_mut(&mut self) -> &mut T {
+		&mut self.0
+	}
+}
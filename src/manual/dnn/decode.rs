@@ -0,0 +1,402 @@
+//! Decoding of the YOLO-family detector outputs
+//!
+//! The decoder is configured by a small `FileStorage` document (JSON, YAML or XML), so a new model variant only needs
+//! a new config file:
+//! ```json
+//! {
+//!     "version": "yolov5",
+//!     "input_width": 640,
+//!     "input_height": 640,
+//!     "class_names": ["person", "bicycle", "car"],
+//!     "strides": [8, 16, 32],
+//!     "anchors": [[10, 13, 16, 30, 33, 23], [30, 61, 62, 45, 59, 119], [116, 90, 156, 198, 373, 326]],
+//!     "score_threshold": 0.25,
+//!     "nms_threshold": 0.45
+//! }
+//! ```
+//! ```ignore
+//! let decoder = dnn::decode::YoloDecoder::load("yolov5s.json")?;
+//! let input = frame.resized(decoder.input_size, Interp::Linear, Fit::Letterbox(Scalar::all(114.)))?;
+//! // ... run the network on the blob of `input.mat`
+//! for mut detection in decoder.decode(&outputs)? {
+//!     detection.rect = input.transform.invert_rect(detection.rect);
+//! }
+//! ```
+
+use std::convert::TryFrom;
+
+use crate::{
+	core::{self, FileNode, FileStorage, FileStorage_Mode, Mat, Rect2d, Rect2f, Size, Vector},
+	dnn,
+	Error,
+	interop::messages::Detection,
+	prelude::*,
+	Result,
+};
+
+/// Output layout of the detector
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum YoloVersion {
+	/// Anchor-based `[cx, cy, w, h, objectness, classes...]` rows, either decoded by the exported model (a single
+	/// `1xNx(5+C)` output) or raw (an output per stride of the `1xAxHxWx(5+C)` logits, requires the anchors)
+	V5,
+	/// Anchor-free decoded `1x(4+C)xN` output without the objectness (YOLOv8 and later), `1xNx(4+C)` is also accepted
+	V8,
+	/// Anchor-free `1xNx(5+C)` output of YOLOX with the box offsets relative to the grid cells of all strides
+	X,
+}
+
+impl YoloVersion {
+	fn name(self) -> &'static str {
+		match self {
+			YoloVersion::V5 => "yolov5",
+			YoloVersion::V8 => "yolov8",
+			YoloVersion::X => "yolox",
+		}
+	}
+}
+
+/// Configurable decoder of the YOLO-family detector outputs into the detections in the network input coordinates
+#[derive(Clone, Debug, PartialEq)]
+pub struct YoloDecoder {
+	pub version: YoloVersion,
+	/// Size of the network input the box coordinates are relative to
+	pub input_size: Size,
+	pub class_count: usize,
+	/// Labels of the detections, the detections of the classes without a name get an empty label
+	pub class_names: Vec<String>,
+	/// Strides of the output grids, `[8, 16, 32]` by default
+	pub strides: Vec<i32>,
+	/// Anchor `(width, height)` in the input pixels for each of the `strides`, only used by the raw [YoloVersion::V5]
+	/// outputs
+	pub anchors: Vec<Vec<(f32, f32)>>,
+	/// Minimum detection score, `0.25` by default
+	pub score_threshold: f32,
+	/// IoU threshold of the per-class non-maximum suppression, `0.45` by default
+	pub nms_threshold: f32,
+}
+
+impl YoloDecoder {
+	pub fn new(version: YoloVersion, input_size: Size, class_count: usize) -> Self {
+		Self {
+			version,
+			input_size,
+			class_count,
+			class_names: vec![],
+			strides: vec![8, 16, 32],
+			anchors: vec![],
+			score_threshold: 0.25,
+			nms_threshold: 0.45,
+		}
+	}
+
+	/// Sets the class names, also sets the `class_count` to their number
+	pub fn with_class_names(mut self, names: impl IntoIterator<Item=impl Into<String>>) -> Self {
+		self.class_names = names.into_iter().map(|n| n.into()).collect();
+		self.class_count = self.class_names.len();
+		self
+	}
+
+	pub fn with_strides(mut self, strides: Vec<i32>) -> Self {
+		self.strides = strides;
+		self
+	}
+
+	pub fn with_anchors(mut self, anchors: Vec<Vec<(f32, f32)>>) -> Self {
+		self.anchors = anchors;
+		self
+	}
+
+	pub fn with_score_threshold(mut self, score_threshold: f32) -> Self {
+		self.score_threshold = score_threshold;
+		self
+	}
+
+	pub fn with_nms_threshold(mut self, nms_threshold: f32) -> Self {
+		self.nms_threshold = nms_threshold;
+		self
+	}
+
+	/// Loads the config from the specified file
+	pub fn load(filename: &str) -> Result<Self> {
+		let fs = FileStorage::new(filename, FileStorage_Mode::READ as i32, "")?;
+		Self::read(&fs)
+	}
+
+	/// Loads the config from the string in any of the `FileStorage` formats
+	pub fn load_from_str(s: &str) -> Result<Self> {
+		let fs = FileStorage::new(s, FileStorage_Mode::READ as i32 | FileStorage_Mode::MEMORY as i32, "")?;
+		Self::read(&fs)
+	}
+
+	/// Saves the config to the specified file, the format is selected by the extension
+	pub fn save(&self, filename: &str) -> Result<()> {
+		let mut fs = FileStorage::new(filename, FileStorage_Mode::WRITE as i32, "")?;
+		fs.write_str("version", self.version.name())?;
+		fs.write_i32("input_width", self.input_size.width)?;
+		fs.write_i32("input_height", self.input_size.height)?;
+		fs.write_i32("class_count", self.class_count as i32)?;
+		fs.write_str_vec("class_names", &Vector::from_iter(self.class_names.iter().map(|n| n.as_str())))?;
+		fs.start_write_struct("strides", core::FileNode_SEQ | core::FileNode_FLOW, "")?;
+		for &stride in &self.strides {
+			fs.write_i32("", stride)?;
+		}
+		fs.end_write_struct()?;
+		fs.start_write_struct("anchors", core::FileNode_SEQ, "")?;
+		for anchors in &self.anchors {
+			fs.start_write_struct("", core::FileNode_SEQ | core::FileNode_FLOW, "")?;
+			for &(width, height) in anchors {
+				fs.write_f64("", f64::from(width))?;
+				fs.write_f64("", f64::from(height))?;
+			}
+			fs.end_write_struct()?;
+		}
+		fs.end_write_struct()?;
+		fs.write_f64("score_threshold", f64::from(self.score_threshold))?;
+		fs.write_f64("nms_threshold", f64::from(self.nms_threshold))?;
+		fs.release()
+	}
+
+	fn read(fs: &FileStorage) -> Result<Self> {
+		if !fs.is_opened()? {
+			return Err(Error::new(core::StsError, "Can't open decoder config"));
+		}
+		let root = fs.root(0)?;
+		let version = match required_node(&root, "version")?.string()?.to_lowercase().as_str() {
+			"yolov5" | "v5" => YoloVersion::V5,
+			"yolov8" | "v8" => YoloVersion::V8,
+			"yolox" | "x" => YoloVersion::X,
+			version => return Err(Error::new(core::StsParseError, format!("Unknown YOLO version: {}", version))),
+		};
+		let input_size = Size::new(required_node(&root, "input_width")?.to_i32()?, required_node(&root, "input_height")?.to_i32()?);
+		let class_names = seq_items(&root.get("class_names")?)?
+			.into_iter()
+			.map(|n| n.string())
+			.collect::<Result<Vec<_>>>()?;
+		let class_count = root.get("class_count")?;
+		let class_count = if class_count.is_none()? {
+			class_names.len()
+		} else {
+			usize::try_from(class_count.to_i32()?).map_err(|_| Error::new(core::StsParseError, "Class count must not be negative"))?
+		};
+		let mut out = Self::new(version, input_size, class_count);
+		out.class_names = class_names;
+		let strides = seq_items(&root.get("strides")?)?;
+		if !strides.is_empty() {
+			out.strides = strides.into_iter().map(|n| n.to_i32()).collect::<Result<_>>()?;
+		}
+		out.anchors = seq_items(&root.get("anchors")?)?
+			.into_iter()
+			.map(|stride_anchors| {
+				let values = seq_items(&stride_anchors)?
+					.into_iter()
+					.map(|n| n.real().map(|v| v as f32))
+					.collect::<Result<Vec<_>>>()?;
+				if values.len() % 2 != 0 {
+					return Err(Error::new(core::StsParseError, "Anchors must be the pairs of width and height"));
+				}
+				Ok(values.chunks_exact(2).map(|wh| (wh[0], wh[1])).collect())
+			})
+			.collect::<Result<_>>()?;
+		if let Some(score_threshold) = optional_real(&root, "score_threshold")? {
+			out.score_threshold = score_threshold as f32;
+		}
+		if let Some(nms_threshold) = optional_real(&root, "nms_threshold")? {
+			out.nms_threshold = nms_threshold as f32;
+		}
+		Ok(out)
+	}
+
+	/// Decodes the network `outputs` (in the order of `Net::get_unconnected_out_layers_names()`) into the detections with
+	/// the rects in the network input coordinates, use the `CoordMap` of the preprocessing to map them to the source
+	/// image
+	pub fn decode(&self, outputs: &[Mat]) -> Result<Vec<Detection>> {
+		let mut candidates = vec![];
+		match self.version {
+			YoloVersion::V5 if !self.anchors.is_empty() => self.decode_v5_raw(outputs, &mut candidates)?,
+			YoloVersion::V5 => {
+				let output = single_output(outputs)?;
+				for row in rows(output, 5 + self.class_count)? {
+					self.push_candidate(&mut candidates, [row[0], row[1], row[2], row[3]], row[4], &row[5..]);
+				}
+			}
+			YoloVersion::V8 => {
+				let output = single_output(outputs)?;
+				let attributes = 4 + self.class_count;
+				let shape = output.mat_size();
+				let rows_output = if matches!(*shape, [.., n, a] if a as usize == attributes && n as usize != attributes) {
+					output.try_clone()?
+				} else {
+					// 1x(4+C)xN -> Nx(4+C)
+					let mut transposed = Mat::default();
+					core::transpose(&output.reshape(1, attributes as i32)?, &mut transposed)?;
+					transposed
+				};
+				for row in rows(&rows_output, attributes)? {
+					self.push_candidate(&mut candidates, [row[0], row[1], row[2], row[3]], 1., &row[4..]);
+				}
+			}
+			YoloVersion::X => {
+				let output = single_output(outputs)?;
+				let mut cells = self.strides.iter()
+					.flat_map(|&stride| {
+						let (grid_w, grid_h) = (self.input_size.width / stride, self.input_size.height / stride);
+						(0..grid_h).flat_map(move |y| (0..grid_w).map(move |x| (x as f32, y as f32, stride as f32)))
+					});
+				for row in rows(output, 5 + self.class_count)? {
+					let (x, y, stride) = cells.next()
+						.ok_or_else(|| Error::new(core::StsBadSize, format!("Output has more rows than the grid cells of the strides: {:?}", self.strides)))?;
+					let rect = [(row[0] + x) * stride, (row[1] + y) * stride, row[2].exp() * stride, row[3].exp() * stride];
+					self.push_candidate(&mut candidates, rect, row[4], &row[5..]);
+				}
+				if cells.next().is_some() {
+					return Err(Error::new(core::StsBadSize, format!("Output has less rows than the grid cells of the strides: {:?}", self.strides)));
+				}
+			}
+		}
+		self.suppress(candidates)
+	}
+
+	fn decode_v5_raw(&self, outputs: &[Mat], candidates: &mut Vec<([f32; 4], f32, usize)>) -> Result<()> {
+		if outputs.len() != self.strides.len() || self.anchors.len() != self.strides.len() {
+			return Err(Error::new(core::StsBadArg, format!(
+				"Raw YOLOv5 decoding requires an output and the anchors for each of: {} strides, got: {} outputs and: {} anchor sets",
+				self.strides.len(),
+				outputs.len(),
+				self.anchors.len(),
+			)));
+		}
+		let attributes = 5 + self.class_count;
+		for ((output, &stride), anchors) in outputs.iter().zip(&self.strides).zip(&self.anchors) {
+			let (grid_w, grid_h) = ((self.input_size.width / stride) as usize, (self.input_size.height / stride) as usize);
+			let data = output_data(output)?;
+			if data.len() != anchors.len() * grid_h * grid_w * attributes {
+				return Err(Error::new(core::StsBadSize, format!(
+					"Output of stride: {} has: {} values, expected {}x{}x{}x{}",
+					stride,
+					data.len(),
+					anchors.len(),
+					grid_h,
+					grid_w,
+					attributes,
+				)));
+			}
+			let stride = stride as f32;
+			for (i, row) in data.chunks_exact(attributes).enumerate() {
+				let (x, y) = ((i % grid_w) as f32, (i / grid_w % grid_h) as f32);
+				let (anchor_w, anchor_h) = anchors[i / (grid_w * grid_h)];
+				let rect = [
+					(sigmoid(row[0]) * 2. - 0.5 + x) * stride,
+					(sigmoid(row[1]) * 2. - 0.5 + y) * stride,
+					(sigmoid(row[2]) * 2.).powi(2) * anchor_w,
+					(sigmoid(row[3]) * 2.).powi(2) * anchor_h,
+				];
+				let scores = row[5..].iter().map(|&v| sigmoid(v)).collect::<Vec<_>>();
+				self.push_candidate(candidates, rect, sigmoid(row[4]), &scores);
+			}
+		}
+		Ok(())
+	}
+
+	/// Adds the box with center, width and height `rect` if its best class score passes the threshold
+	fn push_candidate(&self, candidates: &mut Vec<([f32; 4], f32, usize)>, rect: [f32; 4], objectness: f32, class_scores: &[f32]) {
+		if objectness < self.score_threshold {
+			return;
+		}
+		let best = class_scores.iter()
+			.enumerate()
+			.fold(None, |best: Option<(usize, f32)>, (class, &score)| match best {
+				Some((_, best_score)) if best_score >= score => best,
+				_ => Some((class, score)),
+			});
+		if let Some((class, score)) = best {
+			let score = objectness * score;
+			if score >= self.score_threshold {
+				candidates.push((rect, score, class));
+			}
+		}
+	}
+
+	/// Per-class non-maximum suppression
+	fn suppress(&self, candidates: Vec<([f32; 4], f32, usize)>) -> Result<Vec<Detection>> {
+		let mut out = vec![];
+		for class in 0..self.class_count {
+			let class_candidates = candidates.iter().filter(|c| c.2 == class).collect::<Vec<_>>();
+			if class_candidates.is_empty() {
+				continue;
+			}
+			let rects = class_candidates.iter()
+				.map(|([cx, cy, w, h], _, _)| Rect2d::new(f64::from(cx - w / 2.), f64::from(cy - h / 2.), f64::from(*w), f64::from(*h)))
+				.collect::<Vector<_>>();
+			let scores = class_candidates.iter().map(|c| c.1).collect::<Vector<f32>>();
+			let mut indices = Vector::<i32>::new();
+			dnn::nms_boxes_f64(&rects, &scores, self.score_threshold, self.nms_threshold, &mut indices, 1., 0)?;
+			for i in indices {
+				let rect = rects.get(i as usize)?;
+				out.push(Detection {
+					rect: Rect2f::new(rect.x as f32, rect.y as f32, rect.width as f32, rect.height as f32),
+					score: scores.get(i as usize)?,
+					class_id: class as i32,
+					label: self.class_names.get(class).cloned().unwrap_or_default(),
+				});
+			}
+		}
+		out.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+		Ok(out)
+	}
+}
+
+fn sigmoid(x: f32) -> f32 {
+	1. / (1. + (-x).exp())
+}
+
+fn single_output(outputs: &[Mat]) -> Result<&Mat> {
+	match outputs {
+		[output] => Ok(output),
+		_ => Err(Error::new(core::StsBadArg, format!("Expected a single decoded output, got: {}", outputs.len()))),
+	}
+}
+
+fn output_data(output: &Mat) -> Result<&[f32]> {
+	if output.depth() != core::CV_32F {
+		return Err(Error::new(core::StsUnsupportedFormat, format!("Network output must be CV_32F, got depth: {}", output.depth())));
+	}
+	output.data_typed::<f32>()
+}
+
+/// Splits the output into the rows of `attributes` values
+fn rows(output: &Mat, attributes: usize) -> Result<std::slice::ChunksExact<'_, f32>> {
+	let data = output_data(output)?;
+	if data.len() % attributes != 0 {
+		return Err(Error::new(core::StsBadSize, format!("Output of: {} values is not a multiple of: {} attributes", data.len(), attributes)));
+	}
+	Ok(data.chunks_exact(attributes))
+}
+
+fn required_node(node: &FileNode, name: &str) -> Result<FileNode> {
+	let out = node.get(name)?;
+	if out.is_none()? {
+		Err(Error::new(core::StsParseError, format!("Decoder config is missing required field: {}", name)))
+	} else {
+		Ok(out)
+	}
+}
+
+fn optional_real(node: &FileNode, name: &str) -> Result<Option<f64>> {
+	let out = node.get(name)?;
+	if out.is_none()? {
+		Ok(None)
+	} else {
+		out.real().map(Some)
+	}
+}
+
+fn seq_items(node: &FileNode) -> Result<Vec<FileNode>> {
+	if node.is_none()? {
+		Ok(vec![])
+	} else {
+		(0..node.size()? as i32)
+			.map(|i| node.at(i))
+			.collect()
+	}
+}
@@ -0,0 +1,204 @@
+//! Export of the segmentation masks to the annotation formats
+//!
+//! ```ignore
+//! // binary mask of an instance from the segmentation model output
+//! let polygons = dnn::masks::to_polygons(&instance_mask, 1.)?;
+//! let rle = dnn::masks::encode(&instance_mask)?;
+//! println!(r#"{{"size": [{}, {}], "counts": "{}"}}"#, rle.size.height, rle.size.width, rle.to_compressed_string());
+//! ```
+//! The run-length encoding follows the COCO dataset format, so it can be read by `pycocotools`.
+
+use std::convert::TryFrom;
+
+use crate::{
+	core::{self, Mat, Point, Rect, Scalar, Size, Vector},
+	Error,
+	imgproc,
+	prelude::*,
+	Result,
+};
+
+/// Finds the outlines of the non-zero regions of the single channel `mask` and simplifies them with the Douglas-Peucker
+/// algorithm with the maximum distance of `epsilon` pixels, `0` keeps all vertices
+///
+/// Only the outer outlines are returned as the COCO polygons can't represent the holes, use [encode] for the masks with
+/// holes. The degenerate outlines with less than 3 vertices are skipped.
+pub fn to_polygons(mask: &Mat, epsilon: f64) -> Result<Vec<Vec<Point>>> {
+	let binary = binarize(mask)?;
+	let mut contours = Vector::<Vector<Point>>::new();
+	imgproc::find_contours(&binary, &mut contours, imgproc::RETR_EXTERNAL, imgproc::CHAIN_APPROX_SIMPLE, Point::default())?;
+	let mut out = Vec::with_capacity(contours.len());
+	for contour in contours {
+		let polygon = if epsilon > 0. {
+			let mut approx = Vector::<Point>::new();
+			imgproc::approx_poly_dp(&contour, &mut approx, epsilon, true)?;
+			approx
+		} else {
+			contour
+		};
+		if polygon.len() >= 3 {
+			out.push(polygon.to_vec());
+		}
+	}
+	Ok(out)
+}
+
+/// Run-length encoded binary mask in the COCO format
+///
+/// The mask is scanned in the column-major order, the `counts` are the lengths of the alternating runs starting with
+/// the zero pixels, so the first count is `0` if the top-left pixel is set.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Rle {
+	pub size: Size,
+	pub counts: Vec<u32>,
+}
+
+impl Rle {
+	/// Number of the set pixels
+	pub fn area(&self) -> u64 {
+		self.counts.iter().skip(1).step_by(2).map(|&count| u64::from(count)).sum()
+	}
+
+	/// Bounding box of the set pixels, an empty `Rect` for an empty mask
+	pub fn bbox(&self) -> Rect {
+		let height = self.size.height.max(1) as u64;
+		let (mut x_min, mut y_min, mut x_max, mut y_max) = (u64::MAX, u64::MAX, 0, 0);
+		let mut pos = 0;
+		for (i, &count) in self.counts.iter().enumerate() {
+			let (start, end) = (pos, pos + u64::from(count));
+			pos = end;
+			if i % 2 == 0 || count == 0 {
+				continue;
+			}
+			let (x_start, x_end) = (start / height, (end - 1) / height);
+			x_min = x_min.min(x_start);
+			x_max = x_max.max(x_end);
+			if x_start == x_end {
+				y_min = y_min.min(start % height);
+				y_max = y_max.max((end - 1) % height);
+			} else {
+				// the run wraps over to the next column, so it covers the whole column height
+				y_min = 0;
+				y_max = height - 1;
+			}
+		}
+		if x_min > x_max {
+			return Rect::default();
+		}
+		Rect::new(x_min as i32, y_min as i32, (x_max - x_min + 1) as i32, (y_max - y_min + 1) as i32)
+	}
+
+	/// Compressed string form of the `counts` used in the COCO JSON annotations
+	pub fn to_compressed_string(&self) -> String {
+		let mut out = String::new();
+		for (i, &count) in self.counts.iter().enumerate() {
+			let mut x = i64::from(count);
+			if i > 2 {
+				x -= i64::from(self.counts[i - 2]);
+			}
+			loop {
+				let mut c = (x & 0x1f) as u8;
+				x >>= 5;
+				let more = if c & 0x10 != 0 { x != -1 } else { x != 0 };
+				if more {
+					c |= 0x20;
+				}
+				out.push(char::from(c + 48));
+				if !more {
+					break;
+				}
+			}
+		}
+		out
+	}
+
+	/// Parses the compressed `counts` string of the mask of `size`, see [to_compressed_string](Self::to_compressed_string)
+	pub fn from_compressed_string(size: Size, s: &str) -> Result<Self> {
+		let mut counts = Vec::<u32>::new();
+		let mut bytes = s.bytes();
+		while let Some(first) = bytes.next() {
+			let mut x = 0i64;
+			let mut k = 0;
+			let mut byte = Some(first);
+			loop {
+				let c = match byte {
+					Some(c) if (48..48 + 64).contains(&c) => i64::from(c - 48),
+					_ => return Err(Error::new(core::StsParseError, format!("Invalid or truncated compressed RLE string: {}", s))),
+				};
+				if k > 6 {
+					return Err(Error::new(core::StsParseError, format!("Compressed RLE count is too large in: {}", s)));
+				}
+				x |= (c & 0x1f) << (5 * k);
+				k += 1;
+				if c & 0x20 == 0 {
+					if c & 0x10 != 0 {
+						x |= -1 << (5 * k);
+					}
+					break;
+				}
+				byte = bytes.next();
+			}
+			if counts.len() > 2 {
+				x += i64::from(counts[counts.len() - 2]);
+			}
+			let count = u32::try_from(x)
+				.map_err(|_| Error::new(core::StsParseError, format!("Compressed RLE count is out of range in: {}", s)))?;
+			counts.push(count);
+		}
+		Ok(Self { size, counts })
+	}
+}
+
+/// Run-length encodes the non-zero pixels of the single channel `mask`
+pub fn encode(mask: &Mat) -> Result<Rle> {
+	let binary = binarize(mask)?;
+	// the rows of the transposed mask are the columns of the source
+	let mut transposed = Mat::default();
+	core::transpose(&binary, &mut transposed)?;
+	let mut counts = vec![];
+	let mut current = 0;
+	let mut run = 0;
+	for &pixel in transposed.data_bytes()? {
+		let value = u8::from(pixel != 0);
+		if value != current {
+			counts.push(run);
+			run = 0;
+			current = value;
+		}
+		run += 1;
+	}
+	counts.push(run);
+	Ok(Rle { size: binary.size()?, counts })
+}
+
+/// Decodes the [Rle] into the `CV_8UC1` mask with the set pixels of `255`
+pub fn decode(rle: &Rle) -> Result<Mat> {
+	let Size { width, height } = rle.size;
+	let total = rle.counts.iter().map(|&count| u64::from(count)).sum::<u64>();
+	if width < 0 || height < 0 || total != width as u64 * height as u64 {
+		return Err(Error::new(core::StsBadArg, format!("RLE counts sum: {} doesn't match the mask size: {:?}", total, rle.size)));
+	}
+	let mut transposed = Mat::new_rows_cols_with_default(width, height, core::CV_8UC1, Scalar::all(0.))?;
+	let data = transposed.data_bytes_mut()?;
+	let mut pos = 0;
+	for (i, &count) in rle.counts.iter().enumerate() {
+		let end = pos + count as usize;
+		if i % 2 == 1 {
+			data[pos..end].fill(255);
+		}
+		pos = end;
+	}
+	let mut out = Mat::default();
+	core::transpose(&transposed, &mut out)?;
+	Ok(out)
+}
+
+/// `CV_8UC1` mask with `255` for the non-zero pixels
+fn binarize(mask: &Mat) -> Result<Mat> {
+	if mask.channels() != 1 {
+		return Err(Error::new(core::StsBadArg, format!("Mask must have a single channel, got: {} channels", mask.channels())));
+	}
+	let mut out = Mat::default();
+	core::compare(mask, &Scalar::all(0.), &mut out, core::CMP_NE)?;
+	Ok(out)
+}
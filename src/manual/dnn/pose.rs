@@ -0,0 +1,369 @@
+//! Decoding of the body pose network outputs into the COCO skeletons
+//!
+//! ```ignore
+//! let blob = dnn::blob_from_image(&frame, 1. / 255., Size::new(368, 368), Scalar::default(), false, false, core::CV_32F)?;
+//! net.set_input(&blob, "", 1., Scalar::default())?;
+//! let output = net.forward_single("")?;
+//! let people = dnn::pose::decode_openpose(&output, frame.size()?, 0.1)?;
+//! dnn::pose::draw(&mut frame, &people, Scalar::new(0., 255., 0., 0.), 2)?;
+//! ```
+//! All decoders return the keypoints in the [KEYPOINT_NAMES] order in the coordinates of the image of `image_size` the
+//! network input was resized from.
+
+use crate::{
+	core::{self, Mat, Point, Point2f, Rect2f, Scalar, Size},
+	Error,
+	imgproc,
+	prelude::*,
+	Result,
+};
+
+/// Number of the COCO body keypoints
+pub const KEYPOINT_COUNT: usize = 17;
+
+/// Names of the COCO body keypoints, the order of [Person::keypoints]
+pub const KEYPOINT_NAMES: [&str; KEYPOINT_COUNT] = [
+	"nose",
+	"left_eye",
+	"right_eye",
+	"left_ear",
+	"right_ear",
+	"left_shoulder",
+	"right_shoulder",
+	"left_elbow",
+	"right_elbow",
+	"left_wrist",
+	"right_wrist",
+	"left_hip",
+	"right_hip",
+	"left_knee",
+	"right_knee",
+	"left_ankle",
+	"right_ankle",
+];
+
+/// Pairs of the connected keypoints of the COCO skeleton
+pub const SKELETON: [(usize, usize); 19] = [
+	(15, 13), (13, 11), (16, 14), (14, 12), (11, 12), (5, 11), (6, 12), (5, 6), (5, 7), (6, 8),
+	(7, 9), (8, 10), (1, 2), (0, 1), (0, 2), (1, 3), (2, 4), (3, 5), (4, 6),
+];
+
+/// Detected body pose
+#[derive(Clone, Debug, PartialEq)]
+pub struct Person {
+	/// Keypoints in the [KEYPOINT_NAMES] order, `None` for the keypoints that weren't found
+	pub keypoints: [Option<Point2f>; KEYPOINT_COUNT],
+	/// Mean confidence of the found keypoints
+	pub score: f32,
+}
+
+impl Person {
+	/// Number of the found keypoints
+	pub fn keypoint_count(&self) -> usize {
+		self.keypoints.iter().flatten().count()
+	}
+
+	/// Bounding rectangle of the found keypoints, `None` if there are none
+	pub fn bounding_rect(&self) -> Option<Rect2f> {
+		let mut points = self.keypoints.iter().flatten();
+		let first = *points.next()?;
+		let (min, max) = points.fold((first, first), |(min, max), p| {
+			(Point2f::new(min.x.min(p.x), min.y.min(p.y)), Point2f::new(max.x.max(p.x), max.y.max(p.y)))
+		});
+		Some(Rect2f::new(min.x, min.y, max.x - min.x, max.y - min.y))
+	}
+}
+
+/// Decodes the top-down (SimpleBaseline, HRNet) model output with one person per input
+///
+/// `heatmaps` is the `Nx17xHxW` `CV_32F` blob, the maximum of each heatmap above the `threshold` is the keypoint. One
+/// [Person] is returned for each of `N` inputs.
+pub fn decode_heatmaps(heatmaps: &Mat, image_size: Size, threshold: f32) -> Result<Vec<Person>> {
+	let (batch, channels, height, width) = nchw(heatmaps)?;
+	if channels != KEYPOINT_COUNT {
+		return Err(Error::new(core::StsBadSize, format!("Expected {} heatmaps, got: {}", KEYPOINT_COUNT, channels)));
+	}
+	let data = heatmaps.data_typed::<f32>()?;
+	let scale = Scale::new(image_size, width, height);
+	Ok(data.chunks_exact(channels * height * width)
+		.take(batch)
+		.map(|person| {
+			let mut keypoints = [None; KEYPOINT_COUNT];
+			let mut scores = [0.; KEYPOINT_COUNT];
+			for (k, heatmap) in person.chunks_exact(height * width).enumerate() {
+				let (i, &score) = heatmap.iter()
+					.enumerate()
+					.fold((0, &f32::NEG_INFINITY), |max, v| if v.1 > max.1 { v } else { max });
+				if score >= threshold {
+					let (x, y) = (i % width, i / width);
+					// quarter pixel shift towards the higher neighbour
+					let at = |x: usize, y: usize| heatmap[y * width + x];
+					let dx = if x > 0 && x + 1 < width { quarter_shift(at(x + 1, y) - at(x - 1, y)) } else { 0. };
+					let dy = if y > 0 && y + 1 < height { quarter_shift(at(x, y + 1) - at(x, y - 1)) } else { 0. };
+					keypoints[k] = Some(scale.apply(x as f32 + dx, y as f32 + dy));
+					scores[k] = score;
+				}
+			}
+			person_from(keypoints, &scores)
+		})
+		.collect())
+}
+
+/// Decodes the output of the models regressing the keypoint coordinates (MoveNet)
+///
+/// `output` is either the `1x1x17x3` single pose blob or the `1xNx56` multi pose blob, each keypoint is the
+/// `[y, x, score]` triplet normalized to `0..1`. The multi pose rows end with `[y_min, x_min, y_max, x_max, score]` of
+/// the person, the rows with the person score below `threshold` are skipped. Only the keypoints with the score of at
+/// least `threshold` are set.
+pub fn decode_regression(output: &Mat, image_size: Size, threshold: f32) -> Result<Vec<Person>> {
+	let shape = output.mat_size();
+	let stride = match *shape {
+		[.., 17, 3] => KEYPOINT_COUNT * 3,
+		[.., 56] => KEYPOINT_COUNT * 3 + 5,
+		_ => return Err(Error::new(core::StsBadSize, format!("Unsupported keypoint regression output shape: {:?}", &*shape))),
+	};
+	if output.depth() != core::CV_32F {
+		return Err(Error::new(core::StsUnsupportedFormat, format!("Keypoint regression output must be CV_32F, got depth: {}", output.depth())));
+	}
+	let (width, height) = (image_size.width as f32, image_size.height as f32);
+	let mut out = vec![];
+	for row in output.data_typed::<f32>()?.chunks_exact(stride) {
+		if stride > KEYPOINT_COUNT * 3 && row[stride - 1] < threshold {
+			continue;
+		}
+		let mut keypoints = [None; KEYPOINT_COUNT];
+		let mut scores = [0.; KEYPOINT_COUNT];
+		for (k, keypoint) in row.chunks_exact(3).take(KEYPOINT_COUNT).enumerate() {
+			if keypoint[2] >= threshold {
+				keypoints[k] = Some(Point2f::new(keypoint[1] * width, keypoint[0] * height));
+				scores[k] = keypoint[2];
+			}
+		}
+		let person = person_from(keypoints, &scores);
+		if person.keypoint_count() > 0 {
+			out.push(person);
+		}
+	}
+	Ok(out)
+}
+
+/// Part pairs connected by the part affinity fields of the COCO OpenPose model
+const OPENPOSE_PAIRS: [(usize, usize); 19] = [
+	(1, 2), (1, 5), (2, 3), (3, 4), (5, 6), (6, 7), (1, 8), (8, 9), (9, 10), (1, 11),
+	(11, 12), (12, 13), (1, 0), (0, 14), (14, 16), (0, 15), (15, 17), (2, 17), (5, 16),
+];
+
+/// Output channels of the x and y components of the part affinity field of each of [OPENPOSE_PAIRS]
+const OPENPOSE_PAFS: [(usize, usize); 19] = [
+	(31, 32), (39, 40), (33, 34), (35, 36), (41, 42), (43, 44), (19, 20), (21, 22), (23, 24), (25, 26),
+	(27, 28), (29, 30), (47, 48), (49, 50), (53, 54), (51, 52), (55, 56), (37, 38), (45, 46),
+];
+
+/// COCO keypoint of each OpenPose part, the neck has none
+const OPENPOSE_TO_COCO: [Option<usize>; 18] = [
+	Some(0), None, Some(6), Some(8), Some(10), Some(5), Some(7), Some(9), Some(12),
+	Some(14), Some(16), Some(11), Some(13), Some(15), Some(2), Some(1), Some(4), Some(3),
+];
+
+const OPENPOSE_PARTS: usize = 18;
+
+/// Decodes the multi-person output of the COCO OpenPose model grouping the keypoints with the part affinity fields
+///
+/// `output` is the `1x57xHxW` `CV_32F` blob with the 18 part heatmaps, the background heatmap and the 38 affinity field
+/// channels. The local maxima of the heatmaps above `threshold` are the keypoint candidates. The people with less than
+/// 3 found parts are dropped.
+pub fn decode_openpose(output: &Mat, image_size: Size, threshold: f32) -> Result<Vec<Person>> {
+	let (batch, channels, height, width) = nchw(output)?;
+	if channels < 57 {
+		return Err(Error::new(core::StsBadSize, format!("Expected 57 channels of the COCO OpenPose model, got: {}", channels)));
+	}
+	let data = output.data_typed::<f32>()?;
+	let channel = |n: usize, c: usize| &data[(n * channels + c) * height * width..][..height * width];
+	let scale = Scale::new(image_size, width, height);
+	let mut out = vec![];
+	for n in 0..batch {
+		// keypoint candidates: (x, y, score)
+		let mut peaks = vec![];
+		let mut part_peaks = vec![vec![]; OPENPOSE_PARTS];
+		for (part, indices) in part_peaks.iter_mut().enumerate() {
+			for (x, y, score) in local_maxima(channel(n, part), width, height, threshold) {
+				indices.push(peaks.len());
+				peaks.push((x, y, score));
+			}
+		}
+
+		// parts of each person and the sum of the part scores
+		let mut people: Vec<([Option<usize>; OPENPOSE_PARTS], f32)> = vec![];
+		for (&(part_a, part_b), &(paf_x, paf_y)) in OPENPOSE_PAIRS.iter().zip(OPENPOSE_PAFS.iter()) {
+			let (paf_x, paf_y) = (channel(n, paf_x), channel(n, paf_y));
+			let mut connections = vec![];
+			for &a in &part_peaks[part_a] {
+				for &b in &part_peaks[part_b] {
+					let (xa, ya, _) = peaks[a];
+					let (xb, yb, _) = peaks[b];
+					if let Some(score) = limb_score((xa, ya), (xb, yb), paf_x, paf_y, width, height) {
+						connections.push((score, a, b));
+					}
+				}
+			}
+			connections.sort_by(|l, r| r.0.partial_cmp(&l.0).unwrap_or(std::cmp::Ordering::Equal));
+			let (mut used_a, mut used_b) = (vec![], vec![]);
+			for (_, a, b) in connections {
+				if used_a.contains(&a) || used_b.contains(&b) {
+					continue;
+				}
+				used_a.push(a);
+				used_b.push(b);
+				if let Some((parts, score)) = people.iter_mut().find(|(parts, _)| parts[part_a] == Some(a)) {
+					if parts[part_b].is_none() {
+						parts[part_b] = Some(b);
+						*score += peaks[b].2;
+					}
+				} else if let Some((parts, score)) = people.iter_mut().find(|(parts, _)| parts[part_b] == Some(b)) {
+					if parts[part_a].is_none() {
+						parts[part_a] = Some(a);
+						*score += peaks[a].2;
+					}
+				} else {
+					let mut parts = [None; OPENPOSE_PARTS];
+					parts[part_a] = Some(a);
+					parts[part_b] = Some(b);
+					people.push((parts, peaks[a].2 + peaks[b].2));
+				}
+			}
+		}
+
+		for (parts, _) in people {
+			if parts.iter().flatten().count() < 3 {
+				continue;
+			}
+			let mut keypoints = [None; KEYPOINT_COUNT];
+			let mut scores = [0.; KEYPOINT_COUNT];
+			for (part, peak) in parts.iter().enumerate() {
+				if let (Some(k), Some(peak)) = (OPENPOSE_TO_COCO[part], peak) {
+					let (x, y, score) = peaks[*peak];
+					keypoints[k] = Some(scale.apply(x as f32, y as f32));
+					scores[k] = score;
+				}
+			}
+			out.push(person_from(keypoints, &scores));
+		}
+	}
+	Ok(out)
+}
+
+/// Draws the skeleton lines and the keypoints of the `people` onto the `image`
+pub fn draw(image: &mut Mat, people: &[Person], color: Scalar, thickness: i32) -> Result<()> {
+	let to_point = |p: Point2f| Point::new(p.x.round() as i32, p.y.round() as i32);
+	for person in people {
+		for &(a, b) in SKELETON.iter() {
+			if let (Some(a), Some(b)) = (person.keypoints[a], person.keypoints[b]) {
+				imgproc::line(image, to_point(a), to_point(b), color, thickness, imgproc::LINE_AA, 0)?;
+			}
+		}
+		for keypoint in person.keypoints.iter().flatten() {
+			imgproc::circle(image, to_point(*keypoint), thickness + 1, color, imgproc::FILLED, imgproc::LINE_AA, 0)?;
+		}
+	}
+	Ok(())
+}
+
+fn person_from(keypoints: [Option<Point2f>; KEYPOINT_COUNT], scores: &[f32; KEYPOINT_COUNT]) -> Person {
+	let count = keypoints.iter().flatten().count();
+	let score = if count > 0 { scores.iter().sum::<f32>() / count as f32 } else { 0. };
+	Person { keypoints, score }
+}
+
+fn quarter_shift(diff: f32) -> f32 {
+	if diff > 0. {
+		0.25
+	} else if diff < 0. {
+		-0.25
+	} else {
+		0.
+	}
+}
+
+/// Shape of the `CV_32F` `NCHW` blob
+fn nchw(blob: &Mat) -> Result<(usize, usize, usize, usize)> {
+	if blob.depth() != core::CV_32F {
+		return Err(Error::new(core::StsUnsupportedFormat, format!("Network output must be CV_32F, got depth: {}", blob.depth())));
+	}
+	match *blob.mat_size() {
+		[n, c, h, w] => Ok((n as usize, c as usize, h as usize, w as usize)),
+		ref shape => Err(Error::new(core::StsBadSize, format!("Expected the NCHW network output, got shape: {:?}", shape))),
+	}
+}
+
+/// Mapping of the output pixel centers to the image pixels
+struct Scale {
+	x: f32,
+	y: f32,
+}
+
+impl Scale {
+	fn new(image_size: Size, width: usize, height: usize) -> Self {
+		Self { x: image_size.width as f32 / width as f32, y: image_size.height as f32 / height as f32 }
+	}
+
+	fn apply(&self, x: f32, y: f32) -> Point2f {
+		Point2f::new((x + 0.5) * self.x - 0.5, (y + 0.5) * self.y - 0.5)
+	}
+}
+
+/// Pixels of the `heatmap` with the value of at least `threshold` not smaller than their 8 neighbours, of the plateaus
+/// only the first pixel is returned
+fn local_maxima(heatmap: &[f32], width: usize, height: usize, threshold: f32) -> Vec<(usize, usize, f32)> {
+	let mut out = vec![];
+	for y in 0..height {
+		for x in 0..width {
+			let v = heatmap[y * width + x];
+			if v < threshold {
+				continue;
+			}
+			let is_max = (y.saturating_sub(1)..(y + 2).min(height))
+				.flat_map(|ny| (x.saturating_sub(1)..(x + 2).min(width)).map(move |nx| (nx, ny)))
+				.filter(|&(nx, ny)| (nx, ny) != (x, y))
+				.all(|(nx, ny)| {
+					let neighbour = heatmap[ny * width + nx];
+					// the neighbours preceding in the raster order must be strictly smaller to break the ties
+					if (ny, nx) < (y, x) { neighbour < v } else { neighbour <= v }
+				});
+			if is_max {
+				out.push((x, y, v));
+			}
+		}
+	}
+	out
+}
+
+/// Score of the limb between the candidates `a` and `b`: the mean projection of the affinity field onto the limb
+/// direction with a penalty for the limbs longer than half of the output height, `None` if the field doesn't support
+/// the limb
+fn limb_score(a: (usize, usize), b: (usize, usize), paf_x: &[f32], paf_y: &[f32], width: usize, height: usize) -> Option<f32> {
+	const SAMPLES: usize = 10;
+	let (dx, dy) = (b.0 as f32 - a.0 as f32, b.1 as f32 - a.1 as f32);
+	let norm = dx.hypot(dy);
+	if norm < 1e-6 {
+		return None;
+	}
+	let (ux, uy) = (dx / norm, dy / norm);
+	let mut sum = 0.;
+	let mut supported = 0;
+	for i in 0..SAMPLES {
+		let t = i as f32 / (SAMPLES - 1) as f32;
+		let x = ((a.0 as f32 + dx * t).round() as usize).min(width - 1);
+		let y = ((a.1 as f32 + dy * t).round() as usize).min(height - 1);
+		let projection = paf_x[y * width + x] * ux + paf_y[y * width + x] * uy;
+		sum += projection;
+		if projection > 0.05 {
+			supported += 1;
+		}
+	}
+	let score = sum / SAMPLES as f32 + (0.5 * height as f32 / norm - 1.).min(0.);
+	if supported * 10 >= SAMPLES * 8 && score > 0. {
+		Some(score)
+	} else {
+		None
+	}
+}
@@ -0,0 +1,227 @@
+use crate::{
+	core::{self, CoordMap, Mat, Scalar, Size, UMat, UMatUsageFlags, Vector},
+	dnn,
+	Error,
+	imgproc::{self, Interp},
+	prelude::*,
+	Result,
+};
+#[cfg(all(ocvrs_has_module_cudaarithm, ocvrs_has_module_cudaimgproc, ocvrs_has_module_cudawarping))]
+use crate::{
+	core::{GpuMat, Stream},
+	cudaarithm,
+	cudaimgproc,
+	cudawarping,
+};
+
+/// Fitting of the frames into the network input size of [preprocess_batch]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PadMode {
+	/// Stretches the frame to exactly the input size
+	Stretch,
+	/// Keeps the aspect ratio and pads the centered frame with the `pad_color` of [PreprocessParams]
+	Letterbox,
+}
+
+/// Device the resizing, padding and normalization of [preprocess_batch] are executed on
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PreprocessTarget {
+	Cpu,
+	/// `UMat` transparent API, runs on the CPU when OpenCL is not available
+	OpenCl,
+	/// `GpuMat`, runs on the CPU when OpenCV is built without the CUDA modules or there is no CUDA device
+	Cuda,
+}
+
+/// Parameters of [preprocess_batch_with_params]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PreprocessParams {
+	/// Network input size
+	pub size: Size,
+	pub pad: PadMode,
+	pub target: PreprocessTarget,
+	/// Color of the letterbox padding in the source channel order, `114` gray by default
+	pub pad_color: Scalar,
+	pub interp: Interp,
+	/// Whether to swap the first and the third channel (BGR to RGB), `true` by default
+	pub swap_rb: bool,
+	/// Subtracted from the channels in the output channel order, zero by default
+	pub mean: Scalar,
+	/// Multiplies the values after the mean subtraction, `1 / 255` by default
+	pub scale: f64,
+}
+
+impl PreprocessParams {
+	pub fn new(size: Size, pad: PadMode, target: PreprocessTarget) -> Self {
+		Self {
+			size,
+			pad,
+			target,
+			pad_color: Scalar::all(114.),
+			interp: Interp::Linear,
+			swap_rb: true,
+			mean: Scalar::default(),
+			scale: 1. / 255.,
+		}
+	}
+}
+
+/// Result of [preprocess_batch]
+#[derive(Debug)]
+pub struct PreprocessedBatch {
+	/// `NxCxHxW` `CV_32F` network input
+	pub blob: Mat,
+	/// Map from the coordinates of each frame into the network input, use `invert_rect` to map the detections back
+	pub transforms: Vec<CoordMap>,
+}
+
+/// Resizes the `frames` to the network input `size` and packs them into a single blob normalized to `0..1` with the
+/// channels swapped to RGB, see [preprocess_batch_with_params]
+///
+/// ```ignore
+/// let batch = dnn::preprocess_batch(&frames, Size::new(640, 640), PadMode::Letterbox, PreprocessTarget::OpenCl)?;
+/// net.set_input(&batch.blob, "", 1., Scalar::default())?;
+/// // ... decode the detections of the frame `i`
+/// let rect = batch.transforms[i].invert_rect(detection.rect);
+/// ```
+#[inline]
+pub fn preprocess_batch(frames: &[Mat], size: Size, pad: PadMode, target: PreprocessTarget) -> Result<PreprocessedBatch> {
+	preprocess_batch_with_params(frames, &PreprocessParams::new(size, pad, target))
+}
+
+/// Resizes, pads and normalizes the `frames` on the `target` device and packs them into a single `NCHW` blob
+///
+/// The frames can have different sizes, but they must have the same number of channels.
+pub fn preprocess_batch_with_params(frames: &[Mat], params: &PreprocessParams) -> Result<PreprocessedBatch> {
+	if params.size.width <= 0 || params.size.height <= 0 {
+		return Err(Error::new(core::StsBadArg, format!("Invalid network input size: {:?}", params.size)));
+	}
+	let first = frames.first().ok_or_else(|| Error::new(core::StsBadArg, "No frames to preprocess"))?;
+	if let Some(frame) = frames.iter().find(|frame| frame.channels() != first.channels()) {
+		return Err(Error::new(core::StsUnmatchedFormats, format!("All frames must have the same number of channels, got: {} and {}", first.channels(), frame.channels())));
+	}
+	let swap_rb = params.swap_rb && first.channels() == 3;
+	let target = effective_target(params.target)?;
+	let mut inputs = Vector::<Mat>::with_capacity(frames.len());
+	let mut transforms = Vec::with_capacity(frames.len());
+	for frame in frames {
+		let placement = Placement::new(frame.size()?, params)?;
+		let input = match target {
+			PreprocessTarget::Cpu => prepare(frame, &placement, params, swap_rb, Mat::default)?,
+			PreprocessTarget::OpenCl => {
+				let mut src = UMat::new(UMatUsageFlags::USAGE_DEFAULT);
+				frame.copy_to(&mut src)?;
+				let prepared = prepare(&src, &placement, params, swap_rb, || UMat::new(UMatUsageFlags::USAGE_DEFAULT))?;
+				let mut out = Mat::default();
+				prepared.copy_to(&mut out)?;
+				out
+			}
+			#[cfg(all(ocvrs_has_module_cudaarithm, ocvrs_has_module_cudaimgproc, ocvrs_has_module_cudawarping))]
+			PreprocessTarget::Cuda => prepare_cuda(frame, &placement, params, swap_rb)?,
+			#[cfg(not(all(ocvrs_has_module_cudaarithm, ocvrs_has_module_cudaimgproc, ocvrs_has_module_cudawarping)))]
+			PreprocessTarget::Cuda => unreachable!("CUDA target is replaced by the CPU one when the CUDA modules are missing"),
+		};
+		inputs.push(input);
+		transforms.push(placement.transform);
+	}
+	// the inputs are already resized and normalized, only the NHWC to NCHW packing is left
+	let blob = dnn::blob_from_images(&inputs, 1., Size::default(), Scalar::default(), false, false, core::CV_32F)?;
+	Ok(PreprocessedBatch { blob, transforms })
+}
+
+fn effective_target(target: PreprocessTarget) -> Result<PreprocessTarget> {
+	match target {
+		#[cfg(all(ocvrs_has_module_cudaarithm, ocvrs_has_module_cudaimgproc, ocvrs_has_module_cudawarping))]
+		PreprocessTarget::Cuda if core::get_cuda_enabled_device_count()? > 0 => Ok(PreprocessTarget::Cuda),
+		PreprocessTarget::Cuda => Ok(PreprocessTarget::Cpu),
+		target => Ok(target),
+	}
+}
+
+/// Placement of the resized frame inside of the network input
+struct Placement {
+	resize_size: Size,
+	top: i32,
+	bottom: i32,
+	left: i32,
+	right: i32,
+	transform: CoordMap,
+}
+
+impl Placement {
+	fn new(src_size: Size, params: &PreprocessParams) -> Result<Self> {
+		if src_size.width <= 0 || src_size.height <= 0 {
+			return Err(Error::new(core::StsBadArg, "Can't preprocess empty frame"));
+		}
+		let (src_w, src_h) = (f64::from(src_size.width), f64::from(src_size.height));
+		let size = params.size;
+		let resize_size = match params.pad {
+			PadMode::Stretch => size,
+			PadMode::Letterbox => {
+				let scale = (f64::from(size.width) / src_w).min(f64::from(size.height) / src_h);
+				Size::new(((src_w * scale).round() as i32).clamp(1, size.width), ((src_h * scale).round() as i32).clamp(1, size.height))
+			}
+		};
+		let left = (size.width - resize_size.width) / 2;
+		let top = (size.height - resize_size.height) / 2;
+		let transform = CoordMap::new(
+			f64::from(resize_size.width) / src_w,
+			f64::from(resize_size.height) / src_h,
+			f64::from(left),
+			f64::from(top),
+		);
+		Ok(Self {
+			resize_size,
+			top,
+			bottom: size.height - resize_size.height - top,
+			left,
+			right: size.width - resize_size.width - left,
+			transform,
+		})
+	}
+}
+
+/// Resizes, pads and normalizes the frame with the transparent API functions, `new` creates the empty `Mat` or `UMat`
+fn prepare<T: core::ToInputArray + core::ToOutputArray>(src: &T, placement: &Placement, params: &PreprocessParams, swap_rb: bool, new: impl Fn() -> T) -> Result<T> {
+	let mut resized = new();
+	imgproc::resize(src, &mut resized, placement.resize_size, 0., 0., params.interp.flags())?;
+	let mut padded = new();
+	core::copy_make_border(&resized, &mut padded, placement.top, placement.bottom, placement.left, placement.right, core::BORDER_CONSTANT, params.pad_color)?;
+	let swapped = if swap_rb {
+		let mut swapped = new();
+		imgproc::cvt_color(&padded, &mut swapped, imgproc::COLOR_BGR2RGB, 0)?;
+		swapped
+	} else {
+		padded
+	};
+	let mut centered = new();
+	core::subtract(&swapped, &params.mean, &mut centered, &core::no_array(), core::CV_32F)?;
+	let mut out = new();
+	core::multiply(&centered, &Scalar::all(params.scale), &mut out, 1., core::CV_32F)?;
+	Ok(out)
+}
+
+#[cfg(all(ocvrs_has_module_cudaarithm, ocvrs_has_module_cudaimgproc, ocvrs_has_module_cudawarping))]
+fn prepare_cuda(frame: &Mat, placement: &Placement, params: &PreprocessParams, swap_rb: bool) -> Result<Mat> {
+	let mut stream = Stream::null()?;
+	let mut src = GpuMat::default()?;
+	src.upload(frame)?;
+	let mut resized = GpuMat::default()?;
+	cudawarping::resize(&src, &mut resized, placement.resize_size, 0., 0., params.interp.flags(), &mut stream)?;
+	let mut padded = GpuMat::default()?;
+	cudaarithm::copy_make_border(&resized, &mut padded, placement.top, placement.bottom, placement.left, placement.right, core::BORDER_CONSTANT, params.pad_color, &mut stream)?;
+	let swapped = if swap_rb {
+		let mut swapped = GpuMat::default()?;
+		cudaimgproc::cvt_color(&padded, &mut swapped, imgproc::COLOR_BGR2RGB, 0, &mut stream)?;
+		swapped
+	} else {
+		padded
+	};
+	let mut centered = GpuMat::default()?;
+	cudaarithm::subtract(&swapped, &params.mean, &mut centered, &core::no_array(), core::CV_32F, &mut stream)?;
+	let mut normalized = GpuMat::default()?;
+	centered.convert_to_3(&mut normalized, core::CV_32F, params.scale, &mut stream)?;
+	let mut out = Mat::default();
+	GpuMatTraitConst::download(&normalized, &mut out)?;
+	Ok(out)
+}
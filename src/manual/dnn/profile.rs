@@ -0,0 +1,173 @@
+//! Per-layer timing of the network inference
+//!
+//! ```ignore
+//! let profile = dnn::profile::run(&mut net, &blob)?;
+//! println!("{}", profile);
+//! std::fs::write("profile.folded", profile.to_folded())?; // inferno-flamegraph profile.folded > profile.svg
+//! std::fs::write("profile.json", profile.to_json())?;
+//! ```
+//! The timings are only collected by the `DNN_BACKEND_OPENCV` backend on the `DNN_TARGET_CPU` target, the layers fused
+//! into the previous ones report zero time.
+
+use std::fmt::{self, Write};
+
+use crate::{
+	core::{self, Mat, Scalar, Vector},
+	dnn::{DictValue, MatShape, Net},
+	prelude::*,
+	Result,
+};
+
+/// Timing of a single layer of the last forward pass
+#[derive(Clone, Debug, PartialEq)]
+pub struct LayerTiming {
+	pub id: i32,
+	pub name: String,
+	/// Layer type, e.g. `Convolution`
+	pub typ: String,
+	pub time_ms: f64,
+	/// Floating point operations of the layer, `None` when the input shapes are not known
+	pub flops: Option<i64>,
+}
+
+/// Per-layer timings of the last forward pass of the network, see [collect]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Profile {
+	/// Overall inference time including the overhead between the layers
+	pub total_ms: f64,
+	/// Layers in the order of the network
+	pub layers: Vec<LayerTiming>,
+}
+
+impl Profile {
+	/// Sum of the layer timings
+	pub fn layers_ms(&self) -> f64 {
+		self.layers.iter().map(|layer| layer.time_ms).sum()
+	}
+
+	/// Overall floating point operations, `None` when the input shapes are not known
+	pub fn flops(&self) -> Option<i64> {
+		self.layers.iter().map(|layer| layer.flops).sum()
+	}
+
+	/// Up to `count` layers sorted from the slowest one
+	pub fn slowest(&self, count: usize) -> Vec<&LayerTiming> {
+		let mut out = self.layers.iter().collect::<Vec<_>>();
+		out.sort_by(|a, b| b.time_ms.partial_cmp(&a.time_ms).unwrap_or(std::cmp::Ordering::Equal));
+		out.truncate(count);
+		out
+	}
+
+	/// Folded stacks (`net;type;name microseconds` lines) for `flamegraph.pl` or `inferno-flamegraph`, the layers with
+	/// zero time are skipped
+	pub fn to_folded(&self) -> String {
+		let mut out = String::new();
+		for layer in &self.layers {
+			let micros = (layer.time_ms * 1000.).round() as u64;
+			if micros > 0 {
+				// `;` separates the frames and the count follows the last space
+				writeln!(out, "net;{};{} {}", layer.typ.replace(';', ":"), layer.name.replace(';', ":"), micros).expect("Writing to String can't fail");
+			}
+		}
+		out
+	}
+
+	/// JSON document with the overall and the per-layer timings
+	pub fn to_json(&self) -> String {
+		let mut out = String::new();
+		write!(out, "{{\"total_ms\": {}, \"layers\": [", json_number(self.total_ms)).expect("Writing to String can't fail");
+		for (i, layer) in self.layers.iter().enumerate() {
+			if i > 0 {
+				out.push_str(", ");
+			}
+			write!(
+				out,
+				"{{\"id\": {}, \"name\": {}, \"type\": {}, \"time_ms\": {}, \"flops\": {}}}",
+				layer.id,
+				json_string(&layer.name),
+				json_string(&layer.typ),
+				json_number(layer.time_ms),
+				layer.flops.map_or_else(|| "null".to_string(), |flops| flops.to_string()),
+			).expect("Writing to String can't fail");
+		}
+		out.push_str("]}");
+		out
+	}
+}
+
+impl fmt::Display for Profile {
+	/// Table of the layers with their share of the overall time
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		writeln!(f, "{:>10} {:>6} {:>12}  {:<20} name", "time ms", "%", "MFLOPs", "type")?;
+		for layer in &self.layers {
+			let share = if self.total_ms > 0. {
+				layer.time_ms / self.total_ms * 100.
+			} else {
+				0.
+			};
+			let mflops = layer.flops.map_or_else(|| "-".to_string(), |flops| format!("{:.1}", flops as f64 / 1e6));
+			writeln!(f, "{:>10.3} {:>6.1} {:>12}  {:<20} {}", layer.time_ms, share, mflops, layer.typ, layer.name)?;
+		}
+		write!(f, "{:>10.3} {:>6.1} {:>12}  total", self.total_ms, 100., self.flops().map_or_else(|| "-".to_string(), |flops| format!("{:.1}", flops as f64 / 1e6)))
+	}
+}
+
+/// Runs the forward pass of the `net` on the `input` blob and collects its profile
+pub fn run(net: &mut Net, input: &Mat) -> Result<Profile> {
+	net.set_input(input, "", 1., Scalar::default())?;
+	net.forward_single("")?;
+	let shape = input.mat_size().to_vec();
+	collect(net, &[&shape])
+}
+
+/// Collects the profile of the last forward pass of the `net`
+///
+/// The `input_shapes` of all network inputs are needed for the FLOPs computation, pass an empty slice to skip it.
+pub fn collect(net: &mut Net, input_shapes: &[&[i32]]) -> Result<Profile> {
+	let mut timings = Vector::<f64>::new();
+	let total_ticks = net.get_perf_profile(&mut timings)?;
+	let ms_per_tick = 1000. / core::get_tick_frequency()?;
+	let shapes = input_shapes.iter()
+		.map(|&shape| MatShape::from_slice(shape))
+		.collect::<Vector<MatShape>>();
+	let names = net.get_layer_names()?;
+	let mut layers = Vec::with_capacity(names.len());
+	// the timings skip the input layer with id 0 like the layer names do
+	for (name, ticks) in names.into_iter().zip(timings) {
+		let id = net.get_layer_id(&name)?;
+		let typ = net.get_layer(DictValue::from_i32(id)?)?.typ();
+		let flops = if shapes.is_empty() {
+			None
+		} else {
+			Some(net.get_flops_2(id, &shapes)?)
+		};
+		layers.push(LayerTiming { id, name, typ, time_ms: ticks * ms_per_tick, flops });
+	}
+	Ok(Profile { total_ms: total_ticks as f64 * ms_per_tick, layers })
+}
+
+fn json_number(value: f64) -> String {
+	if value.is_finite() {
+		value.to_string()
+	} else {
+		"null".to_string()
+	}
+}
+
+fn json_string(s: &str) -> String {
+	let mut out = String::with_capacity(s.len() + 2);
+	out.push('"');
+	for c in s.chars() {
+		match c {
+			'"' => out.push_str("\\\""),
+			'\\' => out.push_str("\\\\"),
+			'\n' => out.push_str("\\n"),
+			'\r' => out.push_str("\\r"),
+			'\t' => out.push_str("\\t"),
+			c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).expect("Writing to String can't fail"),
+			c => out.push(c),
+		}
+	}
+	out.push('"');
+	out
+}
@@ -0,0 +1,231 @@
+//! Post-training int8 quantization of the floating point networks
+//!
+//! ```ignore
+//! // representative preprocessed inputs, a few hundred frames are usually enough
+//! let calibration = frames.iter().filter_map(|frame| dnn::blob_from_image(frame, 1. / 255., size, mean, true, false, core::CV_32F).ok());
+//! let quantized = dnn::quantization::quantize(&mut net, calibration)?;
+//! for layer in &quantized.layers {
+//!     println!("{}: {:.1} dB", layer.name, layer.sqnr_db);
+//! }
+//! if let Some(worst) = quantized.worst_layer() {
+//!     println!("worst: {} with {:.1} dB", worst.name, worst.sqnr_db);
+//! }
+//! ```
+//! `Net::quantize` is available since OpenCV 4.5.4, only the networks with a single input are supported.
+
+use std::fmt;
+
+use crate::{
+	core::{self, Mat, Scalar, Vector},
+	dnn::Net,
+	Error,
+	prelude::*,
+	Result,
+};
+
+/// Source of the representative network inputs used to compute the quantization ranges
+///
+/// Implemented for all iterators over `Mat`.
+pub trait CalibrationSource {
+	/// Next `CV_32F` `NxCxHxW` input blob, `None` when the data is exhausted
+	fn next_blob(&mut self) -> Result<Option<Mat>>;
+}
+
+impl<I: Iterator<Item = Mat>> CalibrationSource for I {
+	#[inline]
+	fn next_blob(&mut self) -> Result<Option<Mat>> {
+		Ok(self.next())
+	}
+}
+
+/// Parameters of [quantize_with_params]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct QuantizeParams {
+	/// Input data type of the quantized network, `CV_32F` (default) or `CV_8S`
+	pub inputs_dtype: i32,
+	/// Output data type of the quantized network, `CV_32F` (default) or `CV_8S`
+	pub outputs_dtype: i32,
+}
+
+impl Default for QuantizeParams {
+	fn default() -> Self {
+		Self {
+			inputs_dtype: core::CV_32F,
+			outputs_dtype: core::CV_32F,
+		}
+	}
+}
+
+/// Difference between the output of a layer in the floating point and in the quantized network
+#[derive(Clone, Debug, PartialEq)]
+pub struct LayerError {
+	pub name: String,
+	/// Maximum absolute difference
+	pub max_abs: f64,
+	/// Root mean square difference
+	pub rmse: f64,
+	/// Signal to quantization noise ratio in decibels, infinite for the exact outputs, values below `20` usually
+	/// indicate a layer that doesn't quantize well
+	pub sqnr_db: f64,
+}
+
+/// Result of [quantize]
+pub struct QuantizedNet {
+	pub net: Net,
+	/// Errors of the layers present in both networks in the order of the source network, measured on the calibration
+	/// data
+	pub layers: Vec<LayerError>,
+}
+
+impl fmt::Debug for QuantizedNet {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("QuantizedNet")
+			.field("layers", &self.layers)
+			.finish()
+	}
+}
+
+impl QuantizedNet {
+	/// Layer with the lowest signal to noise ratio
+	pub fn worst_layer(&self) -> Option<&LayerError> {
+		self.layers.iter().min_by(|a, b| a.sqnr_db.partial_cmp(&b.sqnr_db).unwrap_or(std::cmp::Ordering::Equal))
+	}
+}
+
+/// Quantizes the `net` to int8 with the `CV_32F` inputs and outputs, see [quantize_with_params]
+#[inline]
+pub fn quantize(net: &mut Net, source: impl CalibrationSource) -> Result<QuantizedNet> {
+	quantize_with_params(net, source, &QuantizeParams::default())
+}
+
+/// Quantizes the `net` to int8 with the quantization ranges computed from all blobs of the `source` and reports the
+/// layer-wise errors of the quantized network
+///
+/// The blobs are concatenated into a single batch, so they must have the same shape apart from the batch size. The
+/// errors are measured by running the batch through both networks, the `int8` layer outputs are dequantized with the
+/// same range computation as used by OpenCV. The `net` is kept floating point.
+pub fn quantize_with_params(net: &mut Net, mut source: impl CalibrationSource, params: &QuantizeParams) -> Result<QuantizedNet> {
+	for &dtype in &[params.inputs_dtype, params.outputs_dtype] {
+		if dtype != core::CV_32F && dtype != core::CV_8S {
+			return Err(Error::new(core::StsBadArg, format!("Quantized network data type must be CV_32F or CV_8S, got: {}", dtype)));
+		}
+	}
+	let mut blobs = vec![];
+	while let Some(blob) = source.next_blob()? {
+		blobs.push(blob);
+	}
+	let batch = concat_batch(&blobs)?;
+	let mut quantized = net.quantize(&batch, params.inputs_dtype, params.outputs_dtype)?;
+	let layers = layer_errors(net, &mut quantized, &batch, params)?;
+	Ok(QuantizedNet { net: quantized, layers })
+}
+
+/// Concatenates the blobs along the first (batch) dimension
+fn concat_batch(blobs: &[Mat]) -> Result<Mat> {
+	let first = blobs.first().ok_or_else(|| Error::new(core::StsBadArg, "No calibration data"))?;
+	let shape = first.mat_size();
+	if shape.len() < 2 || first.typ() != core::CV_32F {
+		return Err(Error::new(core::StsBadArg, format!("Calibration blob must be a CV_32F blob with at least 2 dimensions, got: {:?} of type {}", &*shape, first.typ())));
+	}
+	if blobs.len() == 1 {
+		return Ok(first.clone());
+	}
+	let mut rows = Vector::<Mat>::with_capacity(blobs.len());
+	let mut batch_size = 0;
+	for blob in blobs {
+		let blob_shape = blob.mat_size();
+		if blob.typ() != core::CV_32F || blob_shape.len() != shape.len() || blob_shape[1..] != shape[1..] {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("Calibration blobs must have the same type and shape apart from the batch size, got: {:?} and {:?}", &*shape, &*blob_shape)));
+		}
+		batch_size += blob_shape[0];
+		rows.push(blob.reshape(1, blob_shape[0])?);
+	}
+	let mut concatenated = Mat::default();
+	core::vconcat(&rows, &mut concatenated)?;
+	let mut batch_shape = shape.to_vec();
+	batch_shape[0] = batch_size;
+	concatenated.reshape_nd(1, &batch_shape)
+}
+
+fn layer_errors(net: &mut Net, quantized: &mut Net, batch: &Mat, params: &QuantizeParams) -> Result<Vec<LayerError>> {
+	let quantized_names = quantized.get_layer_names()?;
+	let names = net.get_layer_names()?
+		.into_iter()
+		.filter(|name| quantized_names.iter().any(|quantized_name| quantized_name == *name))
+		.collect::<Vec<_>>();
+	if names.is_empty() {
+		return Ok(vec![]);
+	}
+	let out_names = names.iter().map(|name| name.as_str()).collect::<Vector<String>>();
+	net.set_input(batch, "", 1., Scalar::default())?;
+	let mut reference = Vector::<Mat>::new();
+	net.forward(&mut reference, &out_names)?;
+	let quantized_input = if params.inputs_dtype == core::CV_8S {
+		let mut scales = Vector::<f32>::new();
+		let mut zero_points = Vector::<i32>::new();
+		quantized.get_input_details(&mut scales, &mut zero_points)?;
+		let (scale, zero_point) = (f64::from(scales.get(0)?), f64::from(zero_points.get(0)?));
+		let mut input = Mat::default();
+		batch.convert_to(&mut input, core::CV_8S, 1. / scale, zero_point)?;
+		input
+	} else {
+		batch.clone()
+	};
+	quantized.set_input(&quantized_input, "", 1., Scalar::default())?;
+	let mut outputs = Vector::<Mat>::new();
+	quantized.forward(&mut outputs, &out_names)?;
+	let mut out = Vec::with_capacity(names.len());
+	for ((name, expected), actual) in names.into_iter().zip(reference).zip(outputs) {
+		if let Some(error) = layer_error(name, &expected, &actual)? {
+			out.push(error);
+		}
+	}
+	Ok(out)
+}
+
+/// Compares the outputs of a single layer, `None` if they are not comparable
+fn layer_error(name: String, expected: &Mat, actual: &Mat) -> Result<Option<LayerError>> {
+	if expected.typ() != core::CV_32F || expected.total() != actual.total() || expected.total() == 0 || !expected.is_continuous() || !actual.is_continuous() {
+		return Ok(None);
+	}
+	let expected = expected.reshape(1, 1)?;
+	let actual = actual.reshape(1, 1)?;
+	let dequantized = match actual.typ() {
+		core::CV_32F => actual,
+		core::CV_8S => {
+			let (scale, zero_point) = quantization_params(&expected)?;
+			let mut dequantized = Mat::default();
+			actual.convert_to(&mut dequantized, core::CV_32F, scale, -zero_point * scale)?;
+			dequantized
+		}
+		_ => return Ok(None),
+	};
+	let max_abs = core::norm2(&expected, &dequantized, core::NORM_INF, &core::no_array())?;
+	let noise = core::norm2(&expected, &dequantized, core::NORM_L2SQR, &core::no_array())?;
+	let signal = core::norm(&expected, core::NORM_L2SQR, &core::no_array())?;
+	let sqnr_db = if noise > 0. {
+		10. * (signal / noise).log10()
+	} else {
+		f64::INFINITY
+	};
+	Ok(Some(LayerError {
+		name,
+		max_abs,
+		rmse: (noise / expected.total() as f64).sqrt(),
+		sqnr_db,
+	}))
+}
+
+/// Asymmetric int8 scale and zero point of the value range of `src`, matches the computation of `Net::quantize`
+fn quantization_params(src: &Mat) -> Result<(f64, f64)> {
+	let (mut min, mut max) = (0., 0.);
+	core::min_max_loc(src, Some(&mut min), Some(&mut max), None, None, &core::no_array())?;
+	// zero must be exactly representable
+	let (min, max) = (min.min(0.), max.max(0.));
+	let scale = if max == min {
+		1.
+	} else {
+		(max - min) / 255.
+	};
+	Ok((scale, (-128. - min / scale).round()))
+}
@@ -0,0 +1,311 @@
+//! Downloading of the pretrained models into a local cache
+//!
+//! ```ignore
+//! let zoo = dnn::zoo::Zoo::new(dnn::zoo::Zoo::default_cache_dir()).with_model(dnn::zoo::ModelSpec::new(
+//! 	"face_detection_yunet",
+//! 	dnn::zoo::ModelFile::new(YUNET_URL, YUNET_SHA256),
+//! ));
+//! let mut net = zoo.load("face_detection_yunet")?;
+//! ```
+//! Every file is pinned by its SHA-256 checksum, the download and the cached copy are rejected when they don't match it,
+//! nothing is trusted on the first use. The URL should point to an exact revision (a commit or a release tag, not a
+//! branch) so that the pinned checksum stays valid. The zoo comes without the predefined models, register the ones
+//! the application uses with [Zoo::with_model].
+
+use std::{
+	env,
+	fmt,
+	fs::{self, File},
+	io::Read,
+	path::{Path, PathBuf},
+	process::Command,
+};
+
+use crate::{
+	core,
+	dnn::{self, Net},
+	Error,
+	Result,
+};
+
+/// Single file of a model
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ModelFile {
+	pub url: String,
+	/// Name of the file in the cache, the last segment of the `url` by default
+	pub file_name: String,
+	/// Expected lowercase hex SHA-256 checksum
+	pub sha256: String,
+}
+
+impl ModelFile {
+	/// Creates the file downloaded from `url` and pinned by its `sha256` checksum
+	pub fn new(url: impl Into<String>, sha256: impl Into<String>) -> Self {
+		let url = url.into();
+		let file_name = url.rsplit('/').next().unwrap_or_default().to_string();
+		Self { url, file_name, sha256: sha256.into().to_lowercase() }
+	}
+}
+
+/// Named model of the [Zoo]: the weights and the optional network description (e.g. the Caffe `.prototxt`)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ModelSpec {
+	pub name: String,
+	pub model: ModelFile,
+	pub config: Option<ModelFile>,
+}
+
+impl ModelSpec {
+	pub fn new(name: impl Into<String>, model: ModelFile) -> Self {
+		Self { name: name.into(), model, config: None }
+	}
+
+	pub fn with_config(mut self, config: ModelFile) -> Self {
+		self.config = Some(config);
+		self
+	}
+}
+
+/// Transport used by the [Zoo] to download the files
+pub trait Downloader {
+	/// Downloads the `url` into the `dest` file
+	fn download(&self, url: &str, dest: &Path) -> Result<()>;
+}
+
+/// [Downloader] running the `curl` command line tool
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CurlDownloader;
+
+impl Downloader for CurlDownloader {
+	fn download(&self, url: &str, dest: &Path) -> Result<()> {
+		let status = Command::new("curl")
+			.args(["--fail", "--silent", "--show-error", "--location", "--output"])
+			.arg(dest)
+			.arg(url)
+			.status()
+			.map_err(|e| Error::new(core::StsError, format!("Can't run curl to download: {}: {}", url, e)))?;
+		if status.success() {
+			Ok(())
+		} else {
+			Err(Error::new(core::StsError, format!("Download of: {} failed with: {}", url, status)))
+		}
+	}
+}
+
+/// Local files of a fetched model
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FetchedModel {
+	pub model: PathBuf,
+	pub config: Option<PathBuf>,
+}
+
+/// Cache of the downloaded models
+pub struct Zoo {
+	cache_dir: PathBuf,
+	models: Vec<ModelSpec>,
+	downloader: Box<dyn Downloader>,
+}
+
+impl fmt::Debug for Zoo {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Zoo")
+			.field("cache_dir", &self.cache_dir)
+			.field("models", &self.models)
+			.finish()
+	}
+}
+
+impl Zoo {
+	/// Creates the empty zoo stored in the `cache_dir`
+	pub fn new(cache_dir: impl Into<PathBuf>) -> Self {
+		Self { cache_dir: cache_dir.into(), models: vec![], downloader: Box::new(CurlDownloader) }
+	}
+
+	/// `OPENCV_RUST_ZOO_DIR` environment variable if set, otherwise the `opencv-rust/zoo` directory in the user cache
+	/// directory (`XDG_CACHE_HOME` or `~/.cache`), falls back to the system temporary directory
+	pub fn default_cache_dir() -> PathBuf {
+		if let Some(dir) = env::var_os("OPENCV_RUST_ZOO_DIR") {
+			return PathBuf::from(dir);
+		}
+		env::var_os("XDG_CACHE_HOME")
+			.map(PathBuf::from)
+			.or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))
+			.unwrap_or_else(env::temp_dir)
+			.join("opencv-rust")
+			.join("zoo")
+	}
+
+	pub fn with_downloader(mut self, downloader: impl Downloader + 'static) -> Self {
+		self.downloader = Box::new(downloader);
+		self
+	}
+
+	/// Adds the model, replacing the registered model with the same name
+	pub fn with_model(mut self, spec: ModelSpec) -> Self {
+		self.models.retain(|m| m.name != spec.name);
+		self.models.push(spec);
+		self
+	}
+
+	#[inline]
+	pub fn cache_dir(&self) -> &Path {
+		&self.cache_dir
+	}
+
+	#[inline]
+	pub fn models(&self) -> &[ModelSpec] {
+		&self.models
+	}
+
+	/// Returns the verified local files of the model `name`, downloading the ones missing from the cache
+	pub fn fetch(&self, name: &str) -> Result<FetchedModel> {
+		let spec = self.models.iter()
+			.find(|m| m.name == name)
+			.ok_or_else(|| Error::new(core::StsObjectNotFound, format!("Unknown model: {}", name)))?;
+		let dir = self.cache_dir.join(&spec.name);
+		fs::create_dir_all(&dir)
+			.map_err(|e| Error::new(core::StsError, format!("Can't create directory: {}: {}", dir.display(), e)))?;
+		Ok(FetchedModel {
+			model: self.fetch_file(&dir, &spec.model)?,
+			config: spec.config.as_ref().map(|config| self.fetch_file(&dir, config)).transpose()?,
+		})
+	}
+
+	/// Fetches the model `name` and reads it into a `Net`
+	pub fn load(&self, name: &str) -> Result<Net> {
+		let fetched = self.fetch(name)?;
+		let config = fetched.config.as_deref().map(path_str).transpose()?.unwrap_or_default();
+		dnn::read_net(path_str(&fetched.model)?, config, "")
+	}
+
+	fn fetch_file(&self, dir: &Path, file: &ModelFile) -> Result<PathBuf> {
+		if file.file_name.is_empty() || file.file_name.contains(&['/', '\\'][..]) || file.file_name == ".." {
+			return Err(Error::new(core::StsBadArg, format!("Invalid model file name: {}", file.file_name)));
+		}
+		if file.sha256.len() != 64 || !file.sha256.bytes().all(|c| c.is_ascii_hexdigit()) {
+			return Err(Error::new(core::StsBadArg, format!("Invalid SHA-256 checksum of: {}: {}", file.url, file.sha256)));
+		}
+		let path = dir.join(&file.file_name);
+		// the corrupted or outdated cached file is downloaded again
+		if path.is_file() && sha256_file(&path)? == file.sha256 {
+			return Ok(path);
+		}
+
+		let partial = dir.join(format!("{}.part", file.file_name));
+		self.downloader.download(&file.url, &partial)?;
+		let actual = sha256_file(&partial)?;
+		if actual != file.sha256 {
+			fs::remove_file(&partial).ok();
+			return Err(Error::new(core::StsError, format!("SHA-256 checksum mismatch of: {}, expected: {}, got: {}", file.url, file.sha256, actual)));
+		}
+		fs::rename(&partial, &path).map_err(|e| Error::new(core::StsError, format!("Can't store: {}: {}", path.display(), e)))?;
+		Ok(path)
+	}
+}
+
+/// Lowercase hex SHA-256 checksum of the file contents
+pub fn sha256_file(path: &Path) -> Result<String> {
+	let io_err = |e: std::io::Error| Error::new(core::StsError, format!("Can't read: {}: {}", path.display(), e));
+	let mut file = File::open(path).map_err(io_err)?;
+	let mut hash = Sha256::new();
+	let mut buf = vec![0; 64 * 1024];
+	loop {
+		let read = file.read(&mut buf).map_err(io_err)?;
+		if read == 0 {
+			break;
+		}
+		hash.update(&buf[..read]);
+	}
+	Ok(hash.finish_hex())
+}
+
+fn path_str(path: &Path) -> Result<&str> {
+	path.to_str().ok_or_else(|| Error::new(core::StsBadArg, format!("Path is not valid UTF-8: {}", path.display())))
+}
+
+/// Streaming SHA-256 (FIPS 180-4)
+struct Sha256 {
+	state: [u32; 8],
+	block: [u8; 64],
+	block_len: usize,
+	total_len: u64,
+}
+
+impl Sha256 {
+	const K: [u32; 64] = [
+		0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+		0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+		0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+		0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+		0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+		0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+		0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+		0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+	];
+
+	fn new() -> Self {
+		Self {
+			state: [0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19],
+			block: [0; 64],
+			block_len: 0,
+			total_len: 0,
+		}
+	}
+
+	fn update(&mut self, mut data: &[u8]) {
+		self.total_len += data.len() as u64;
+		while !data.is_empty() {
+			let take = (64 - self.block_len).min(data.len());
+			self.block[self.block_len..self.block_len + take].copy_from_slice(&data[..take]);
+			self.block_len += take;
+			data = &data[take..];
+			if self.block_len == 64 {
+				let block = self.block;
+				self.compress(&block);
+				self.block_len = 0;
+			}
+		}
+	}
+
+	fn finish_hex(mut self) -> String {
+		let bit_len = self.total_len.wrapping_mul(8);
+		self.update(&[0x80]);
+		while self.block_len != 56 {
+			self.update(&[0]);
+		}
+		self.update(&bit_len.to_be_bytes());
+		self.state.iter().map(|word| format!("{:08x}", word)).collect()
+	}
+
+	fn compress(&mut self, block: &[u8; 64]) {
+		let mut w = [0u32; 64];
+		for (i, chunk) in block.chunks_exact(4).enumerate() {
+			w[i] = u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+		}
+		for i in 16..64 {
+			let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+			let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+			w[i] = w[i - 16].wrapping_add(s0).wrapping_add(w[i - 7]).wrapping_add(s1);
+		}
+		let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = self.state;
+		for (&k, &w) in Self::K.iter().zip(&w) {
+			let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+			let ch = (e & f) ^ (!e & g);
+			let t1 = h.wrapping_add(s1).wrapping_add(ch).wrapping_add(k).wrapping_add(w);
+			let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+			let maj = (a & b) ^ (a & c) ^ (b & c);
+			let t2 = s0.wrapping_add(maj);
+			h = g;
+			g = f;
+			f = e;
+			e = d.wrapping_add(t1);
+			d = c;
+			c = b;
+			b = a;
+			a = t1.wrapping_add(t2);
+		}
+		for (state, v) in self.state.iter_mut().zip(&[a, b, c, d, e, f, g, h]) {
+			*state = state.wrapping_add(*v);
+		}
+	}
+}
@@ -12,7 +12,7 @@ impl dyn ORB + '_ {
 	pub fn default() -> Result<types::PtrOfORB> {
 		extern "C" { fn cv_ORB_create(ocvrs_return: *mut sys::Result<*mut c_void>); }
 		return_send!(via ocvrs_return);
-		unsafe { cv_ORB_create(ocvrs_return.as_mut_ptr()) }
+		unsafe { ffi_call!(local cv_ORB_create(ocvrs_return.as_mut_ptr())) }
 		return_receive!(unsafe ocvrs_return => ret);
 		ret.into_result()
 			.map(|ptr| unsafe { types::PtrOfORB::from_raw(ptr) })
@@ -0,0 +1,155 @@
+//! Entry points for fuzzing the OpenCV parsers with untrusted input
+//!
+//! Every function takes the raw input bytes, runs the parser and converts any panic into an `Error`, so the fuzzer
+//! only reports the real crashes (segfaults, aborts, sanitizer findings) of the native code. They are also usable as
+//! the hardened wrappers for parsing the untrusted input in services. See `fuzz/` directory in the crate repository for
+//! the `cargo fuzz` targets.
+
+use std::{
+	any::Any,
+	panic::{self, AssertUnwindSafe},
+};
+
+use crate::{
+	core,
+	Error,
+	prelude::*,
+	Result,
+};
+
+/// Maximum nesting depth of the FileStorage nodes visited by [file_storage]
+const MAX_NODE_DEPTH: usize = 32;
+/// Maximum number of the FileStorage nodes visited by [file_storage]
+const MAX_NODES: usize = 100_000;
+
+/// Runs `f` converting a panic into `Error` with `StsError` code
+pub fn catch_panic<T>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+	panic::catch_unwind(AssertUnwindSafe(f))
+		.unwrap_or_else(|payload| Err(Error::new(core::StsError, format!("Parser panicked: {}", panic_message(&payload)))))
+}
+
+fn panic_message(payload: &Box<dyn Any + Send>) -> &str {
+	if let Some(s) = payload.downcast_ref::<&str>() {
+		s
+	} else if let Some(s) = payload.downcast_ref::<String>() {
+		s.as_str()
+	} else {
+		"unknown payload"
+	}
+}
+
+/// Decodes the image from the encoded bytes with `imgcodecs::imdecode()`, returns an empty Mat if the format is not
+/// recognized
+#[cfg(ocvrs_has_module_imgcodecs)]
+pub fn imdecode(data: &[u8]) -> Result<core::Mat> {
+	use crate::imgcodecs;
+
+	catch_panic(|| {
+		if data.is_empty() {
+			return Ok(core::Mat::default());
+		}
+		let buf = core::Vector::<u8>::from_slice(data);
+		imgcodecs::imdecode(&buf, imgcodecs::IMREAD_UNCHANGED)
+	})
+}
+
+/// Parses the XML, YAML or JSON document with `FileStorage` and visits all of its nodes, returns the number of nodes
+pub fn file_storage(data: &[u8]) -> Result<usize> {
+	catch_panic(|| {
+		let text = std::str::from_utf8(data)
+			.map_err(|e| Error::new(core::StsParseError, format!("FileStorage input is not valid UTF-8: {}", e)))?;
+		let flags = core::FileStorage_Mode::READ as i32 | core::FileStorage_Mode::MEMORY as i32;
+		let storage = core::FileStorage::new(text, flags, "")?;
+		if !storage.is_opened()? {
+			return Err(Error::new(core::StsParseError, "Can't parse FileStorage input"));
+		}
+		let mut count = 0;
+		visit_node(&storage.root(0)?, 0, &mut count)?;
+		Ok(count)
+	})
+}
+
+fn visit_node(node: &core::FileNode, depth: usize, count: &mut usize) -> Result<()> {
+	*count += 1;
+	if depth >= MAX_NODE_DEPTH || *count >= MAX_NODES {
+		return Ok(());
+	}
+	if node.is_map()? {
+		for key in node.keys()? {
+			visit_node(&node.get(&key)?, depth + 1, count)?;
+		}
+	} else if node.is_seq()? {
+		for i in 0..node.size()? {
+			visit_node(&node.at(i as i32)?, depth + 1, count)?;
+		}
+	} else if node.is_string()? {
+		node.to_string()?;
+	} else if node.is_int()? || node.is_real()? {
+		node.to_f64()?;
+	}
+	Ok(())
+}
+
+/// Temporary file that's removed on drop, for the parsers that only accept a file name
+#[cfg(any(ocvrs_has_module_ml, ocvrs_has_module_sfm))]
+struct TempFile {
+	path: std::path::PathBuf,
+}
+
+#[cfg(any(ocvrs_has_module_ml, ocvrs_has_module_sfm))]
+impl TempFile {
+	fn new(extension: &str, data: &[u8]) -> Result<Self> {
+		use std::sync::atomic::{AtomicUsize, Ordering};
+
+		static COUNTER: AtomicUsize = AtomicUsize::new(0);
+		let name = format!("ocvrs-fuzz-{}-{}.{}", std::process::id(), COUNTER.fetch_add(1, Ordering::Relaxed), extension);
+		let path = std::env::temp_dir().join(name);
+		std::fs::write(&path, data)
+			.map_err(|e| Error::new(core::StsError, format!("Can't write temporary file: {}: {}", path.display(), e)))?;
+		Ok(Self { path })
+	}
+
+	fn path_str(&self) -> Result<&str> {
+		self.path.to_str()
+			.ok_or_else(|| Error::new(core::StsBadArg, format!("Temporary path is not valid UTF-8: {}", self.path.display())))
+	}
+}
+
+#[cfg(any(ocvrs_has_module_ml, ocvrs_has_module_sfm))]
+impl Drop for TempFile {
+	fn drop(&mut self) {
+		let _ = std::fs::remove_file(&self.path);
+	}
+}
+
+/// Parses the CSV with `ml::TrainData::load_from_csv()`, the last column is the response, returns the number of samples
+#[cfg(ocvrs_has_module_ml)]
+pub fn train_data_csv(data: &[u8]) -> Result<i32> {
+	use crate::ml::TrainData;
+
+	catch_panic(|| {
+		let file = TempFile::new("csv", data)?;
+		let train_data = <dyn TrainData>::load_from_csv(file.path_str()?, 0, -1, -1, "", b',' as i8, b'?' as i8)?;
+		if train_data.is_null() {
+			return Err(Error::new(core::StsParseError, "Can't parse CSV train data"));
+		}
+		train_data.get_n_samples()
+	})
+}
+
+/// Parses the reconstruction with `sfm::import_reconstruction()` in the `sfm::SFM_IO_*` `file_format`, returns the
+/// number of cameras
+#[cfg(ocvrs_has_module_sfm)]
+pub fn sfm_reconstruction(data: &[u8], file_format: i32) -> Result<usize> {
+	use crate::sfm;
+
+	catch_panic(|| {
+		let file = TempFile::new("out", data)?;
+		let mut rs = core::Vector::<core::Mat>::new();
+		let mut ts = core::Vector::<core::Mat>::new();
+		let mut ks = core::Vector::<core::Mat>::new();
+		let mut points3d = core::Vector::<core::Mat>::new();
+		sfm::import_reconstruction(file.path_str()?, &mut rs, &mut ts, &mut ks, &mut points3d, file_format)?;
+		Ok(rs.len())
+	})
+}
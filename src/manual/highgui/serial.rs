@@ -0,0 +1,38 @@
+//! highgui functions that are always executed on the [SerialContext::global] thread
+//!
+//! Most GUI backends require the windows to be created, updated and polled from the same thread, these wrappers allow
+//! calling highgui from any thread (e.g. from the workers of a thread pool) while keeping that guarantee.
+
+use crate::{
+	core::{Mat, SerialContext},
+	highgui,
+	Result,
+};
+
+/// Same as [highgui::imshow], but runs on the global serial context
+///
+/// The `Mat` is passed without copying the data.
+pub fn imshow(winname: &str, mat: &Mat) -> Result<()> {
+	let mat = Mat::copy(mat)?;
+	SerialContext::global().run(move || highgui::imshow(winname, &mat))
+}
+
+/// Same as [highgui::wait_key], but runs on the global serial context
+pub fn wait_key(delay: i32) -> Result<i32> {
+	SerialContext::global().run(move || highgui::wait_key(delay))
+}
+
+/// Same as [highgui::named_window], but runs on the global serial context
+pub fn named_window(winname: &str, flags: i32) -> Result<()> {
+	SerialContext::global().run(move || highgui::named_window(winname, flags))
+}
+
+/// Same as [highgui::destroy_window], but runs on the global serial context
+pub fn destroy_window(winname: &str) -> Result<()> {
+	SerialContext::global().run(move || highgui::destroy_window(winname))
+}
+
+/// Same as [highgui::destroy_all_windows], but runs on the global serial context
+pub fn destroy_all_windows() -> Result<()> {
+	SerialContext::global().run(highgui::destroy_all_windows)
+}
@@ -0,0 +1,171 @@
+use std::{fmt, str::FromStr};
+
+use crate::{
+	core::{self, Mat},
+	Error,
+	img_hash::{self as hash, ImgHashBaseTrait},
+	prelude::*,
+	Result,
+};
+
+/// Bit hash of an image computed by one of the `img_hash` algorithms
+///
+/// The hashes of the similar images have the small [hamming_distance](Hash::hamming_distance), it's the distance used by
+/// `compare()` of average, block mean, Marr-Hildreth and p-hash algorithms. Radial variance hash bytes are the
+/// coefficients compared with the cross-correlation by `RadialVarianceHash::compare()`, the Hamming distance between them is
+/// a rough estimate only. The hash formats as a lowercase hex string and serializes as one with the `serde` feature.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Hash {
+	bytes: Vec<u8>,
+}
+
+impl Hash {
+	#[inline]
+	pub fn from_bytes(bytes: Vec<u8>) -> Self {
+		Self { bytes }
+	}
+
+	/// Creates the hash from the `CV_8U` single channel output of `ImgHashBase::compute()`
+	pub fn from_mat(mat: &Mat) -> Result<Self> {
+		if mat.depth() != core::CV_8U || mat.channels() != 1 {
+			return Err(Error::new(core::StsUnsupportedFormat, format!("Hash Mat must have CV_8UC1 type, but it has: {}", mat.typ())));
+		}
+		let bytes = if mat.empty() {
+			vec![]
+		} else if mat.is_continuous() {
+			mat.data_bytes()?.to_vec()
+		} else {
+			mat.try_clone()?.data_bytes()?.to_vec()
+		};
+		Ok(Self { bytes })
+	}
+
+	/// Computes the hash of the `image` with the `hasher`, e.g. `BlockMeanHash::create()`
+	pub fn compute(hasher: &mut impl ImgHashBaseTrait, image: &Mat) -> Result<Self> {
+		let mut out = Mat::default();
+		hasher.compute(image, &mut out)?;
+		Self::from_mat(&out)
+	}
+
+	/// 64 bit average hash
+	pub fn average(image: &Mat) -> Result<Self> {
+		let mut out = Mat::default();
+		hash::average_hash(image, &mut out)?;
+		Self::from_mat(&out)
+	}
+
+	/// 64 bit DCT based p-hash
+	pub fn phash(image: &Mat) -> Result<Self> {
+		let mut out = Mat::default();
+		hash::p_hash(image, &mut out)?;
+		Self::from_mat(&out)
+	}
+
+	/// 40 byte radial variance hash with the default `sigma` of 1 and 180 angle lines
+	pub fn radial_variance(image: &Mat) -> Result<Self> {
+		let mut out = Mat::default();
+		hash::radial_variance_hash(image, &mut out, 1., 180)?;
+		Self::from_mat(&out)
+	}
+
+	#[inline]
+	pub fn as_bytes(&self) -> &[u8] {
+		&self.bytes
+	}
+
+	#[inline]
+	pub fn into_bytes(self) -> Vec<u8> {
+		self.bytes
+	}
+
+	/// Returns the `1xN` `CV_8U` Mat accepted by `ImgHashBase::compare()`
+	#[inline]
+	pub fn to_mat(&self) -> Result<Mat> {
+		Mat::from_slice(&self.bytes)
+	}
+
+	#[inline]
+	pub fn bit_count(&self) -> usize {
+		self.bytes.len() * 8
+	}
+
+	/// Returns the number of the differing bits, fails if the hashes have different lengths
+	pub fn hamming_distance(&self, other: &Hash) -> Result<u32> {
+		if self.bytes.len() != other.bytes.len() {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("Hash lengths don't match: {} and {}", self.bytes.len(), other.bytes.len())));
+		}
+		Ok(self.bytes.iter().zip(&other.bytes).map(|(a, b)| (a ^ b).count_ones()).sum())
+	}
+}
+
+/// Returns the index pairs `(i, j)`, `i < j`, of the hashes within `max_distance` of each other
+///
+/// Compares every pair, so it's meant for the batches of up to a few thousand images.
+pub fn near_duplicates(hashes: &[Hash], max_distance: u32) -> Result<Vec<(usize, usize)>> {
+	let mut out = vec![];
+	for (i, a) in hashes.iter().enumerate() {
+		for (j, b) in hashes.iter().enumerate().skip(i + 1) {
+			if a.hamming_distance(b)? <= max_distance {
+				out.push((i, j));
+			}
+		}
+	}
+	Ok(out)
+}
+
+impl fmt::Display for Hash {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.bytes.iter().try_for_each(|b| write!(f, "{:02x}", b))
+	}
+}
+
+impl FromStr for Hash {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self> {
+		let invalid = || Error::new(core::StsParseError, format!("Invalid hex hash: {}", s));
+		let bytes = s.as_bytes()
+			.chunks(2)
+			.map(|pair| {
+				let pair = std::str::from_utf8(pair).ok().filter(|pair| pair.len() == 2).ok_or_else(invalid)?;
+				u8::from_str_radix(pair, 16).map_err(|_| invalid())
+			})
+			.collect::<Result<Vec<_>>>()?;
+		Ok(Self { bytes })
+	}
+}
+
+impl From<Vec<u8>> for Hash {
+	#[inline]
+	fn from(bytes: Vec<u8>) -> Self {
+		Self::from_bytes(bytes)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Hash {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+		serializer.collect_str(self)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Hash {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+		struct HexVisitor;
+
+		impl<'de> serde::de::Visitor<'de> for HexVisitor {
+			type Value = Hash;
+
+			fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				f.write_str("hex string")
+			}
+
+			fn visit_str<E: serde::de::Error>(self, v: &str) -> std::result::Result<Hash, E> {
+				v.parse().map_err(|e: Error| E::custom(e.message))
+			}
+		}
+
+		deserializer.deserialize_str(HexVisitor)
+	}
+}
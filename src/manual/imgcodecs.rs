@@ -0,0 +1,5 @@
+#[cfg(feature = "fast-jpeg")]
+pub use fast_jpeg::*;
+
+#[cfg(feature = "fast-jpeg")]
+mod fast_jpeg;
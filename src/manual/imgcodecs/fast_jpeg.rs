@@ -0,0 +1,132 @@
+use std::panic::{self, AssertUnwindSafe};
+
+use mozjpeg::{ColorSpace, Decompress, Marker};
+
+use crate::{
+	core::{self, Mat},
+	Error,
+	imgcodecs,
+	prelude::*,
+	Result,
+};
+
+const EXIF_ORIENTATION_TAG: u16 = 0x0112;
+
+/// Decodes the image like `imdecode()`, JPEGs are decoded with libjpeg-turbo (through `mozjpeg`), see [imdecode_jpeg_to]
+#[inline]
+pub fn imdecode_jpeg(buf: &[u8], flags: i32) -> Result<Mat> {
+	let mut out = Mat::default();
+	imdecode_jpeg_to(buf, flags, &mut out)?;
+	Ok(out)
+}
+
+/// Decodes the image like `imdecode()` into `dst`, reusing its buffer when it already has the resulting size and type
+///
+/// The JPEG scanlines are decoded straight into the `Mat` rows skipping the intermediate buffers of the imgcodecs
+/// path. `IMREAD_UNCHANGED`, `IMREAD_GRAYSCALE`, `IMREAD_COLOR`, `IMREAD_REDUCED_*` and `IMREAD_IGNORE_ORIENTATION` are
+/// supported. Other flags, non-JPEG data, CMYK JPEGs and the images with EXIF orientation to apply go through
+/// `imgcodecs::imdecode()`.
+pub fn imdecode_jpeg_to(buf: &[u8], flags: i32, dst: &mut Mat) -> Result<()> {
+	if let Some(mode) = FastMode::from_flags(flags) {
+		if buf.starts_with(&[0xFF, 0xD8, 0xFF]) && decode(buf, mode, dst)? {
+			return Ok(());
+		}
+	}
+	*dst = imgcodecs::imdecode(&Mat::from_slice(buf)?, flags)?;
+	if dst.empty() {
+		return Err(Error::new(core::StsError, "Can't decode the image"));
+	}
+	Ok(())
+}
+
+#[derive(Clone, Copy, Debug)]
+struct FastMode {
+	/// `None` keeps the number of channels of the image
+	color: Option<bool>,
+	scale_numerator: u8,
+	ignore_orientation: bool,
+}
+
+impl FastMode {
+	fn from_flags(flags: i32) -> Option<Self> {
+		if flags == imgcodecs::IMREAD_UNCHANGED {
+			return Some(Self { color: None, scale_numerator: 8, ignore_orientation: true });
+		}
+		let ignore_orientation = flags & imgcodecs::IMREAD_IGNORE_ORIENTATION != 0;
+		let (color, scale_numerator) = match flags & !imgcodecs::IMREAD_IGNORE_ORIENTATION {
+			imgcodecs::IMREAD_GRAYSCALE => (false, 8),
+			imgcodecs::IMREAD_COLOR => (true, 8),
+			imgcodecs::IMREAD_REDUCED_GRAYSCALE_2 => (false, 4),
+			imgcodecs::IMREAD_REDUCED_COLOR_2 => (true, 4),
+			imgcodecs::IMREAD_REDUCED_GRAYSCALE_4 => (false, 2),
+			imgcodecs::IMREAD_REDUCED_COLOR_4 => (true, 2),
+			imgcodecs::IMREAD_REDUCED_GRAYSCALE_8 => (false, 1),
+			imgcodecs::IMREAD_REDUCED_COLOR_8 => (true, 1),
+			_ => return None,
+		};
+		Some(Self { color: Some(color), scale_numerator, ignore_orientation })
+	}
+}
+
+/// Returns `false` when the image must go through imgcodecs
+fn decode(buf: &[u8], mode: FastMode, dst: &mut Mat) -> Result<bool> {
+	// libjpeg reports the errors by unwinding
+	panic::catch_unwind(AssertUnwindSafe(|| decode_unwinding(buf, mode, dst)))
+		.unwrap_or_else(|e| {
+			let msg = e.downcast_ref::<String>().map(String::as_str)
+				.or_else(|| e.downcast_ref::<&str>().copied())
+				.unwrap_or("unknown error");
+			Err(Error::new(core::StsError, format!("Can't decode JPEG: {}", msg)))
+		})
+}
+
+fn decode_unwinding(buf: &[u8], mode: FastMode, dst: &mut Mat) -> Result<bool> {
+	let io_err = |e: std::io::Error| Error::new(core::StsError, format!("Can't decode JPEG: {}", e));
+	let mut dec = Decompress::with_markers(&[Marker::APP(1)]).from_mem(buf).map_err(io_err)?;
+	if !mode.ignore_orientation && dec.markers().any(|m| matches!(exif_orientation(m.data), Some(o) if o > 1)) {
+		return Ok(false);
+	}
+	let is_gray = match dec.color_space() {
+		ColorSpace::JCS_GRAYSCALE => true,
+		ColorSpace::JCS_YCbCr | ColorSpace::JCS_RGB => false,
+		_ => return Ok(false),
+	};
+	dec.scale(mode.scale_numerator);
+	let (out_color_space, typ) = if mode.color.unwrap_or(!is_gray) {
+		(ColorSpace::JCS_EXT_BGR, core::CV_8UC3)
+	} else {
+		(ColorSpace::JCS_GRAYSCALE, core::CV_8UC1)
+	};
+	let mut started = dec.to_colorspace(out_color_space).map_err(io_err)?;
+	let (rows, cols) = (started.height() as i32, started.width() as i32);
+	if dst.rows() != rows || dst.cols() != cols || dst.typ() != typ || !dst.is_continuous() {
+		*dst = unsafe { Mat::new_rows_cols(rows, cols, typ) }?;
+	}
+	started.read_scanlines_into::<u8>(dst.data_bytes_mut()?).map_err(io_err)?;
+	started.finish().map_err(io_err)?;
+	Ok(true)
+}
+
+/// Returns the orientation from the APP1 marker data if it's EXIF
+fn exif_orientation(data: &[u8]) -> Option<u16> {
+	let tiff = data.strip_prefix(b"Exif\0\0")?;
+	let big_endian = match tiff.get(..4)? {
+		b"MM\0*" => true,
+		b"II*\0" => false,
+		_ => return None,
+	};
+	let read_u16 = |pos: usize| {
+		let bytes = [*tiff.get(pos)?, *tiff.get(pos + 1)?];
+		Some(if big_endian { u16::from_be_bytes(bytes) } else { u16::from_le_bytes(bytes) })
+	};
+	let read_u32 = |pos: usize| {
+		let bytes = [*tiff.get(pos)?, *tiff.get(pos + 1)?, *tiff.get(pos + 2)?, *tiff.get(pos + 3)?];
+		Some(if big_endian { u32::from_be_bytes(bytes) } else { u32::from_le_bytes(bytes) })
+	};
+	let ifd = read_u32(4)? as usize;
+	let entries = read_u16(ifd)? as usize;
+	(0..entries)
+		.map(|i| ifd + 2 + i * 12)
+		.find(|&entry| read_u16(entry) == Some(EXIF_ORIENTATION_TAG))
+		.and_then(|entry| read_u16(entry + 8))
+}
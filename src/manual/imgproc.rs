@@ -0,0 +1,17 @@
+pub use morphology::*;
+pub use resize::*;
+pub use template_search::*;
+
+#[cfg(all(ocvrs_has_module_calib3d, ocvrs_has_module_features2d, ocvrs_has_module_video))]
+pub mod align;
+pub mod detect;
+pub mod document;
+pub mod geometry;
+pub mod grab_cut;
+mod morphology;
+pub mod photometric;
+pub mod plot;
+mod resize;
+pub mod rolling_shutter;
+pub mod segment;
+mod template_search;
@@ -0,0 +1,144 @@
+//! Registration of an image to a template with a single call
+//!
+//! ```ignore
+//! let aligned = imgproc::align::estimate_and_warp(&photo, &form_template, AlignMethod::orb())?;
+//! imgcodecs::imwrite("aligned.png", &aligned.warped, &Vector::new())?;
+//! ```
+
+use crate::{
+	calib3d,
+	core::{self, DMatch, KeyPoint, Mat, Point2f, Scalar, TermCriteria, Vector},
+	Error,
+	features2d::{BFMatcher, ORB},
+	imgproc,
+	prelude::*,
+	Result,
+	video,
+};
+
+/// Method used to estimate the homography in [estimate_and_warp]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AlignMethod {
+	/// ORB feature matching followed by the RANSAC homography estimation, works for large displacements
+	Orb {
+		max_features: i32,
+		/// Lowe's ratio test threshold for the descriptor matches
+		ratio: f32,
+		/// Maximum reprojection error in pixels for a match to be counted as an inlier
+		ransac_threshold: f64,
+	},
+	/// Enhanced correlation coefficient maximization (`findTransformECC()`), precise but requires the images to be
+	/// roughly aligned already
+	Ecc {
+		criteria: TermCriteria,
+		gauss_filt_size: i32,
+	},
+}
+
+impl AlignMethod {
+	/// ORB with the commonly used parameters
+	#[inline]
+	pub fn orb() -> Self {
+		AlignMethod::Orb { max_features: 5000, ratio: 0.75, ransac_threshold: 5. }
+	}
+
+	/// ECC with the commonly used parameters
+	#[inline]
+	pub fn ecc() -> Self {
+		AlignMethod::Ecc { criteria: TermCriteria::both(100, 1e-6), gauss_filt_size: 5 }
+	}
+}
+
+/// Result of [estimate_and_warp]
+#[derive(Debug)]
+pub struct Alignment {
+	/// `3x3` `CV_64F` homography mapping the source image coordinates into the template coordinates
+	pub homography: Mat,
+	/// Number of RANSAC inlier matches for `Orb`, number of template pixels covered by the warped source for `Ecc`
+	pub inliers: usize,
+	/// Source image warped into the template frame, it has the size of the template
+	pub warped: Mat,
+}
+
+/// Estimates the homography between `src` and `template` and warps `src` to overlay the `template`
+///
+/// Both images can be either grayscale or BGR. Fails with `StsNoConv` if the alignment can't be found.
+pub fn estimate_and_warp(src: &Mat, template: &Mat, method: AlignMethod) -> Result<Alignment> {
+	let src_gray = to_gray(src)?;
+	let template_gray = to_gray(template)?;
+	let (homography, inliers) = match method {
+		AlignMethod::Orb { max_features, ratio, ransac_threshold } => estimate_orb(&src_gray, &template_gray, max_features, ratio, ransac_threshold)?,
+		AlignMethod::Ecc { criteria, gauss_filt_size } => estimate_ecc(&src_gray, &template_gray, criteria, gauss_filt_size)?,
+	};
+	let mut warped = Mat::default();
+	imgproc::warp_perspective(src, &mut warped, &homography, template.size()?, imgproc::INTER_LINEAR, core::BORDER_CONSTANT, Scalar::default())?;
+	Ok(Alignment { homography, inliers, warped })
+}
+
+fn estimate_orb(src: &Mat, template: &Mat, max_features: i32, ratio: f32, ransac_threshold: f64) -> Result<(Mat, usize)> {
+	let mut orb = <dyn ORB>::default()?;
+	orb.set_max_features(max_features)?;
+	let mut src_keypoints = Vector::<KeyPoint>::new();
+	let mut src_descriptors = Mat::default();
+	orb.detect_and_compute(src, &core::no_array(), &mut src_keypoints, &mut src_descriptors, false)?;
+	let mut template_keypoints = Vector::<KeyPoint>::new();
+	let mut template_descriptors = Mat::default();
+	orb.detect_and_compute(template, &core::no_array(), &mut template_keypoints, &mut template_descriptors, false)?;
+	if src_descriptors.empty() || template_descriptors.empty() {
+		return Err(Error::new(core::StsNoConv, "No features found for the alignment"));
+	}
+
+	let matcher = BFMatcher::new(core::NORM_HAMMING, false)?;
+	let mut matches = Vector::<Vector<DMatch>>::new();
+	matcher.knn_train_match(&src_descriptors, &template_descriptors, &mut matches, 2, &core::no_array(), false)?;
+	let mut src_points = Vector::<Point2f>::new();
+	let mut template_points = Vector::<Point2f>::new();
+	for m in &matches {
+		let good = match (m.get(0), m.get(1)) {
+			(Ok(best), Ok(second)) => best.distance < ratio * second.distance,
+			(Ok(_), Err(_)) => true,
+			_ => false,
+		};
+		if good {
+			let best = m.get(0)?;
+			src_points.push(src_keypoints.get(best.query_idx as usize)?.pt);
+			template_points.push(template_keypoints.get(best.train_idx as usize)?.pt);
+		}
+	}
+	if src_points.len() < 4 {
+		return Err(Error::new(core::StsNoConv, format!("Not enough matches for the homography: {}", src_points.len())));
+	}
+
+	let mut mask = Mat::default();
+	let homography = calib3d::find_homography(&src_points, &template_points, &mut mask, calib3d::RANSAC, ransac_threshold)?;
+	if homography.empty() {
+		return Err(Error::new(core::StsNoConv, "Homography estimation failed"));
+	}
+	Ok((homography, core::count_non_zero(&mask)? as usize))
+}
+
+fn estimate_ecc(src: &Mat, template: &Mat, criteria: TermCriteria, gauss_filt_size: i32) -> Result<(Mat, usize)> {
+	// ECC estimates the warp from the template into the source coordinates
+	let mut warp = Mat::eye(3, 3, core::CV_32F)?.to_mat()?;
+	video::find_transform_ecc(template, src, &mut warp, video::MOTION_HOMOGRAPHY, criteria, &core::no_array(), gauss_filt_size)?;
+	let mut homography = Mat::default();
+	warp.inv(core::DECOMP_LU)?.to_mat()?.convert_to(&mut homography, core::CV_64F, 1., 0.)?;
+
+	let coverage = Mat::new_size_with_default(src.size()?, core::CV_8UC1, Scalar::all(255.))?;
+	let mut warped_coverage = Mat::default();
+	imgproc::warp_perspective(&coverage, &mut warped_coverage, &homography, template.size()?, imgproc::INTER_NEAREST, core::BORDER_CONSTANT, Scalar::default())?;
+	Ok((homography, core::count_non_zero(&warped_coverage)? as usize))
+}
+
+fn to_gray(image: &Mat) -> Result<Mat> {
+	match image.channels() {
+		1 => image.try_clone(),
+		3 | 4 => {
+			let mut gray = Mat::default();
+			let code = if image.channels() == 3 { imgproc::COLOR_BGR2GRAY } else { imgproc::COLOR_BGRA2GRAY };
+			imgproc::cvt_color(image, &mut gray, code, 0)?;
+			Ok(gray)
+		}
+		channels => Err(Error::new(core::StsUnsupportedFormat, format!("Unsupported number of channels: {}", channels))),
+	}
+}
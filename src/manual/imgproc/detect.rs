@@ -0,0 +1,287 @@
+//! Line segment and circle detection returning typed results
+//!
+//! ```ignore
+//! let segments = imgproc::detect::lines(&img, imgproc::detect::HoughLinesParams::default())?;
+//! let coins = imgproc::detect::circles(&img, &imgproc::detect::HoughCirclesParams::default().with_radius_range(10, 50))?;
+//! ```
+
+use std::f64::consts::PI;
+
+use crate::{
+	core::{self, Mat, Point2f, Vec3f, Vec4f, Vec4i, Vector},
+	Error,
+	imgproc,
+	prelude::*,
+	Result,
+};
+
+/// Line segment found by [lines]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct LineSegment {
+	pub start: Point2f,
+	pub end: Point2f,
+}
+
+impl LineSegment {
+	#[inline]
+	pub fn new(start: Point2f, end: Point2f) -> Self {
+		Self { start, end }
+	}
+
+	#[inline]
+	pub fn length(&self) -> f32 {
+		(self.end - self.start).norm() as f32
+	}
+
+	/// Angle of the segment direction in degrees in the `(-180, 180]` range, the y axis points down like in the image
+	#[inline]
+	pub fn angle(&self) -> f32 {
+		(self.end.y - self.start.y).atan2(self.end.x - self.start.x).to_degrees()
+	}
+}
+
+/// Circle found by [circles]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Circle {
+	pub center: Point2f,
+	pub radius: f32,
+}
+
+/// Parameters of the probabilistic Hough transform (`HoughLinesP()`)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HoughLinesParams {
+	/// Distance resolution of the accumulator in pixels
+	pub rho: f64,
+	/// Angle resolution of the accumulator in radians
+	pub theta: f64,
+	/// Minimum number of accumulator votes for a line
+	pub threshold: i32,
+	pub min_line_length: f64,
+	/// Maximum allowed gap between the points on the same line to link them
+	pub max_line_gap: f64,
+	/// Lower and upper thresholds of the `Canny()` edge detection run on the image before the transform, `None` if the
+	/// input is already a binary edge map
+	pub canny: Option<(f64, f64)>,
+}
+
+impl Default for HoughLinesParams {
+	fn default() -> Self {
+		Self {
+			rho: 1.,
+			theta: PI / 180.,
+			threshold: 50,
+			min_line_length: 30.,
+			max_line_gap: 10.,
+			canny: Some((50., 150.)),
+		}
+	}
+}
+
+impl HoughLinesParams {
+	#[inline]
+	pub fn with_threshold(mut self, threshold: i32) -> Self {
+		self.threshold = threshold;
+		self
+	}
+
+	#[inline]
+	pub fn with_min_line_length(mut self, min_line_length: f64) -> Self {
+		self.min_line_length = min_line_length;
+		self
+	}
+
+	#[inline]
+	pub fn with_max_line_gap(mut self, max_line_gap: f64) -> Self {
+		self.max_line_gap = max_line_gap;
+		self
+	}
+
+	#[inline]
+	pub fn with_canny(mut self, canny: Option<(f64, f64)>) -> Self {
+		self.canny = canny;
+		self
+	}
+}
+
+/// Parameters of the line segment detector (`createLineSegmentDetector()`)
+///
+/// LSD is not available in OpenCV 4.1.0 - 4.5.0 due to the license issues, [lines] fails with `StsNotImplemented` there.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LsdParams {
+	/// One of `imgproc::LSD_REFINE_*`
+	pub refine: i32,
+	pub scale: f64,
+	pub sigma_scale: f64,
+	pub quant: f64,
+	pub ang_th: f64,
+	pub log_eps: f64,
+	pub density_th: f64,
+	pub n_bins: i32,
+}
+
+impl Default for LsdParams {
+	fn default() -> Self {
+		Self {
+			refine: imgproc::LSD_REFINE_STD,
+			scale: 0.8,
+			sigma_scale: 0.6,
+			quant: 2.,
+			ang_th: 22.5,
+			log_eps: 0.,
+			density_th: 0.7,
+			n_bins: 1024,
+		}
+	}
+}
+
+/// Algorithm used by [lines]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LineParams {
+	Hough(HoughLinesParams),
+	Lsd(LsdParams),
+}
+
+impl Default for LineParams {
+	#[inline]
+	fn default() -> Self {
+		LineParams::Hough(HoughLinesParams::default())
+	}
+}
+
+impl From<HoughLinesParams> for LineParams {
+	#[inline]
+	fn from(s: HoughLinesParams) -> Self {
+		LineParams::Hough(s)
+	}
+}
+
+impl From<LsdParams> for LineParams {
+	#[inline]
+	fn from(s: LsdParams) -> Self {
+		LineParams::Lsd(s)
+	}
+}
+
+/// Parameters of the Hough circle transform (`HoughCircles()`)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HoughCirclesParams {
+	/// `imgproc::HOUGH_GRADIENT` or `imgproc::HOUGH_GRADIENT_ALT` (OpenCV 4.3+)
+	pub method: i32,
+	/// Inverse ratio of the accumulator resolution to the image resolution
+	pub dp: f64,
+	/// Minimum distance between the centers of the detected circles
+	pub min_dist: f64,
+	/// Upper threshold of the internal `Canny()` edge detection
+	pub param1: f64,
+	/// Accumulator threshold for `HOUGH_GRADIENT`, circle "perfectness" for `HOUGH_GRADIENT_ALT`
+	pub param2: f64,
+	pub min_radius: i32,
+	/// Maximum radius, `0` to use the maximum image dimension
+	pub max_radius: i32,
+	/// Kernel size of the median blur applied to the image before the transform to suppress the false circles, `0` to
+	/// disable
+	pub blur_ksize: i32,
+}
+
+impl Default for HoughCirclesParams {
+	fn default() -> Self {
+		Self {
+			method: imgproc::HOUGH_GRADIENT,
+			dp: 1.,
+			min_dist: 20.,
+			param1: 100.,
+			param2: 30.,
+			min_radius: 0,
+			max_radius: 0,
+			blur_ksize: 5,
+		}
+	}
+}
+
+impl HoughCirclesParams {
+	#[inline]
+	pub fn with_min_dist(mut self, min_dist: f64) -> Self {
+		self.min_dist = min_dist;
+		self
+	}
+
+	#[inline]
+	pub fn with_radius_range(mut self, min_radius: i32, max_radius: i32) -> Self {
+		self.min_radius = min_radius;
+		self.max_radius = max_radius;
+		self
+	}
+
+	#[inline]
+	pub fn with_thresholds(mut self, param1: f64, param2: f64) -> Self {
+		self.param1 = param1;
+		self.param2 = param2;
+		self
+	}
+
+	#[inline]
+	pub fn with_blur_ksize(mut self, blur_ksize: i32) -> Self {
+		self.blur_ksize = blur_ksize;
+		self
+	}
+}
+
+/// Detects the line segments in the grayscale or BGR `CV_8U` `image`
+pub fn lines(image: &Mat, params: impl Into<LineParams>) -> Result<Vec<LineSegment>> {
+	let gray = to_gray(image)?;
+	match params.into() {
+		LineParams::Hough(params) => {
+			let edges = match params.canny {
+				Some((threshold1, threshold2)) => {
+					let mut edges = Mat::default();
+					imgproc::canny(&gray, &mut edges, threshold1, threshold2, 3, false)?;
+					edges
+				}
+				None => gray,
+			};
+			let mut lines = Vector::<Vec4i>::new();
+			imgproc::hough_lines_p(&edges, &mut lines, params.rho, params.theta, params.threshold, params.min_line_length, params.max_line_gap)?;
+			Ok(lines.iter()
+				.map(|l| LineSegment::new(Point2f::new(l[0] as f32, l[1] as f32), Point2f::new(l[2] as f32, l[3] as f32)))
+				.collect())
+		}
+		LineParams::Lsd(params) => {
+			let mut lsd = imgproc::create_line_segment_detector(params.refine, params.scale, params.sigma_scale, params.quant, params.ang_th, params.log_eps, params.density_th, params.n_bins)?;
+			let mut lines = Vector::<Vec4f>::new();
+			lsd.detect(&gray, &mut lines, &mut core::no_array(), &mut core::no_array(), &mut core::no_array())?;
+			Ok(lines.iter()
+				.map(|l| LineSegment::new(Point2f::new(l[0], l[1]), Point2f::new(l[2], l[3])))
+				.collect())
+		}
+	}
+}
+
+/// Detects the circles in the grayscale or BGR `CV_8U` `image`, the circles are sorted by the accumulator votes
+pub fn circles(image: &Mat, params: &HoughCirclesParams) -> Result<Vec<Circle>> {
+	let mut gray = to_gray(image)?;
+	if params.blur_ksize > 1 {
+		let mut blurred = Mat::default();
+		imgproc::median_blur(&gray, &mut blurred, params.blur_ksize)?;
+		gray = blurred;
+	}
+	let mut circles = Vector::<Vec3f>::new();
+	imgproc::hough_circles(&gray, &mut circles, params.method, params.dp, params.min_dist, params.param1, params.param2, params.min_radius, params.max_radius)?;
+	Ok(circles.iter()
+		.map(|c| Circle { center: Point2f::new(c[0], c[1]), radius: c[2] })
+		.collect())
+}
+
+fn to_gray(image: &Mat) -> Result<Mat> {
+	if image.depth() != core::CV_8U {
+		return Err(Error::new(core::StsUnsupportedFormat, format!("Expected CV_8U image, but got depth: {}", image.depth())));
+	}
+	match image.channels() {
+		1 => image.try_clone(),
+		3 => {
+			let mut gray = Mat::default();
+			imgproc::cvt_color(image, &mut gray, imgproc::COLOR_BGR2GRAY, 0)?;
+			Ok(gray)
+		}
+		channels => Err(Error::new(core::StsUnsupportedFormat, format!("Expected 1 or 3 channel image, but got: {} channels", channels))),
+	}
+}
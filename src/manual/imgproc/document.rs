@@ -0,0 +1,181 @@
+//! Document scanner: page detection, perspective rectification and binarization
+//!
+//! ```ignore
+//! let scanned = imgproc::document::scan(&photo)?;
+//! imgcodecs::imwrite("page.png", &scanned.image, &Vector::new())?;
+//! ```
+//! The [rectify] step alone works for any quadrilateral, e.g. a barcode region found by the detector.
+
+use crate::{
+	core::{self, Mat, Point, Point2f, Scalar, Size, Vector},
+	Error,
+	imgproc,
+	prelude::*,
+	Result,
+};
+
+/// Adaptive binarization of the rectified document
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Binarization {
+	/// Size of the neighbourhood the threshold is computed in, odd, it should be larger than the text strokes
+	pub block_size: i32,
+	/// Constant subtracted from the Gaussian-weighted neighbourhood mean, higher values clean more of the background
+	pub c: f64,
+}
+
+impl Default for Binarization {
+	fn default() -> Self {
+		Self { block_size: 21, c: 10. }
+	}
+}
+
+/// Parameters of [scan_with_params]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScanParams {
+	/// Output resolution in dots per inch for the page of `page_size`, `None` keeps the pixel size of the document in
+	/// the photo
+	pub dpi: Option<f64>,
+	/// Width and height of the page in inches for the `dpi`, the orientation follows the detected page, A4 by default
+	pub page_size: (f64, f64),
+	/// Binarization of the output, `None` keeps the source colors
+	pub binarization: Option<Binarization>,
+	/// Minimum area of the page as a fraction of the image area
+	pub min_area_ratio: f64,
+}
+
+impl Default for ScanParams {
+	fn default() -> Self {
+		Self {
+			dpi: None,
+			page_size: (8.27, 11.69),
+			binarization: Some(Binarization::default()),
+			min_area_ratio: 0.2,
+		}
+	}
+}
+
+/// Result of [scan]
+#[derive(Debug)]
+pub struct ScannedDocument {
+	/// Page corners in the source image: top-left, top-right, bottom-right and bottom-left
+	pub corners: [Point2f; 4],
+	/// `3x3` `CV_64F` homography from the source image to the output image
+	pub homography: Mat,
+	/// Rectified page, `CV_8UC1` if binarized, otherwise of the source type
+	pub image: Mat,
+}
+
+/// Scans the document with the default [ScanParams]
+#[inline]
+pub fn scan(image: &Mat) -> Result<ScannedDocument> {
+	scan_with_params(image, &ScanParams::default())
+}
+
+/// Finds the page in the photo, rectifies its perspective and binarizes it
+///
+/// Fails with `StsObjectNotFound` if there is no quadrilateral with the area of at least `min_area_ratio`.
+pub fn scan_with_params(image: &Mat, params: &ScanParams) -> Result<ScannedDocument> {
+	let corners = find_quadrilateral(image, params.min_area_ratio)?
+		.ok_or_else(|| Error::new(core::StsObjectNotFound, "No document found in the image"))?;
+	let [tl, tr, br, bl] = corners;
+	let width = distance(tl, tr).max(distance(bl, br));
+	let height = distance(tl, bl).max(distance(tr, br));
+	let size = match params.dpi {
+		Some(dpi) => {
+			let (short, long) = (params.page_size.0.min(params.page_size.1), params.page_size.0.max(params.page_size.1));
+			let (page_width, page_height) = if width > height { (long, short) } else { (short, long) };
+			Size::new((page_width * dpi).round() as i32, (page_height * dpi).round() as i32)
+		}
+		None => Size::new(width.round() as i32, height.round() as i32),
+	};
+	let (rectified, homography) = rectify(image, &corners, size)?;
+	let image = match params.binarization {
+		Some(binarization) => binarize(&rectified, binarization)?,
+		None => rectified,
+	};
+	Ok(ScannedDocument { corners, homography, image })
+}
+
+/// Finds the largest convex quadrilateral outline in the image with the area of at least `min_area_ratio` of the image
+/// area, the corners are ordered top-left, top-right, bottom-right, bottom-left
+pub fn find_quadrilateral(image: &Mat, min_area_ratio: f64) -> Result<Option<[Point2f; 4]>> {
+	let gray = to_gray(image)?;
+	let mut blurred = Mat::default();
+	imgproc::gaussian_blur(&gray, &mut blurred, Size::new(5, 5), 0., 0., core::BORDER_DEFAULT)?;
+	let mut edges = Mat::default();
+	imgproc::canny(&blurred, &mut edges, 75., 200., 3, false)?;
+	// closes the small gaps in the page outline
+	let mut closed = Mat::default();
+	let kernel = imgproc::get_structuring_element(imgproc::MORPH_RECT, Size::new(3, 3), Point::new(-1, -1))?;
+	imgproc::dilate(&edges, &mut closed, &kernel, Point::new(-1, -1), 1, core::BORDER_CONSTANT, imgproc::morphology_default_border_value()?)?;
+	let mut contours = Vector::<Vector<Point>>::new();
+	imgproc::find_contours(&closed, &mut contours, imgproc::RETR_LIST, imgproc::CHAIN_APPROX_SIMPLE, Point::default())?;
+
+	let min_area = min_area_ratio * f64::from(image.rows()) * f64::from(image.cols());
+	let mut candidates = contours.iter()
+		.map(|contour| imgproc::contour_area(&contour, false).map(|area| (area, contour)))
+		.collect::<Result<Vec<_>>>()?;
+	candidates.retain(|(area, _)| *area >= min_area);
+	candidates.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+	for (_, contour) in candidates {
+		let mut approx = Vector::<Point>::new();
+		imgproc::approx_poly_dp(&contour, &mut approx, 0.02 * imgproc::arc_length(&contour, true)?, true)?;
+		if approx.len() == 4 && imgproc::is_contour_convex(&approx)? && imgproc::contour_area(&approx, false)? >= min_area {
+			let points = approx.iter().map(|p| Point2f::new(p.x as f32, p.y as f32)).collect::<Vec<_>>();
+			return Ok(Some(order_corners(&points)));
+		}
+	}
+	Ok(None)
+}
+
+/// Warps the quadrilateral with `corners` (top-left, top-right, bottom-right, bottom-left) to the rectangle of `size`
+///
+/// Returns the rectified image and the `3x3` `CV_64F` homography from the source image.
+pub fn rectify(image: &Mat, corners: &[Point2f; 4], size: impl Into<Size>) -> Result<(Mat, Mat)> {
+	let size = size.into();
+	if size.width <= 0 || size.height <= 0 {
+		return Err(Error::new(core::StsBadArg, format!("Output size must be positive, got: {:?}", size)));
+	}
+	let (w, h) = ((size.width - 1) as f32, (size.height - 1) as f32);
+	let src = Mat::from_slice(corners)?;
+	let dst = Mat::from_slice(&[Point2f::new(0., 0.), Point2f::new(w, 0.), Point2f::new(w, h), Point2f::new(0., h)])?;
+	let homography = imgproc::get_perspective_transform(&src, &dst, core::DECOMP_LU)?;
+	let mut out = Mat::default();
+	imgproc::warp_perspective(image, &mut out, &homography, size, imgproc::INTER_LINEAR, core::BORDER_REPLICATE, Scalar::default())?;
+	Ok((out, homography))
+}
+
+fn binarize(image: &Mat, binarization: Binarization) -> Result<Mat> {
+	let mut out = Mat::default();
+	imgproc::adaptive_threshold(&to_gray(image)?, &mut out, 255., imgproc::ADAPTIVE_THRESH_GAUSSIAN_C, imgproc::THRESH_BINARY, binarization.block_size, binarization.c)?;
+	Ok(out)
+}
+
+/// Orders the 4 points as top-left, top-right, bottom-right and bottom-left
+fn order_corners(points: &[Point2f]) -> [Point2f; 4] {
+	let by = |key: fn(&Point2f) -> f32, max: bool| {
+		let cmp = |a: &&Point2f, b: &&Point2f| key(a).partial_cmp(&key(b)).unwrap_or(std::cmp::Ordering::Equal);
+		*if max { points.iter().max_by(cmp) } else { points.iter().min_by(cmp) }.expect("Points are not empty")
+	};
+	[
+		by(|p| p.x + p.y, false),
+		by(|p| p.y - p.x, false),
+		by(|p| p.x + p.y, true),
+		by(|p| p.y - p.x, true),
+	]
+}
+
+fn distance(a: Point2f, b: Point2f) -> f64 {
+	f64::from(a.x - b.x).hypot(f64::from(a.y - b.y))
+}
+
+fn to_gray(image: &Mat) -> Result<Mat> {
+	match image.channels() {
+		1 => image.try_clone(),
+		channels => {
+			let mut gray = Mat::default();
+			imgproc::cvt_color(image, &mut gray, if channels == 4 { imgproc::COLOR_BGRA2GRAY } else { imgproc::COLOR_BGR2GRAY }, 0)?;
+			Ok(gray)
+		}
+	}
+}
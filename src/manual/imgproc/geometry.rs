@@ -0,0 +1,99 @@
+//! Rotated rectangle and polygon geometry for the oriented bounding box post-processing
+//!
+//! ```ignore
+//! let iou = imgproc::geometry::rotated_rect_iou(&detection, &ground_truth)?;
+//! ```
+
+use crate::{
+	core::{Point2f, RotatedRect, Vector},
+	imgproc,
+	Result,
+};
+
+/// Returns the intersection region of 2 rotated rectangles as a convex polygon, the polygon is empty if the rectangles
+/// don't intersect
+///
+/// The vertices are ordered counter-clockwise in the coordinate system with the y axis pointing up.
+pub fn rotated_rect_intersection(a: &RotatedRect, b: &RotatedRect) -> Result<Vec<Point2f>> {
+	let mut region = Vector::<Point2f>::new();
+	if imgproc::rotated_rectangle_intersection(a, b, &mut region)? == imgproc::INTERSECT_NONE || region.len() < 3 {
+		return Ok(vec![]);
+	}
+	// older OpenCV versions don't order the vertices of the region
+	let mut hull = Vector::<Point2f>::new();
+	imgproc::convex_hull(&region, &mut hull, false, true)?;
+	Ok(hull.to_vec())
+}
+
+/// Returns the area of the intersection of 2 rotated rectangles
+#[inline]
+pub fn rotated_rect_intersection_area(a: &RotatedRect, b: &RotatedRect) -> Result<f64> {
+	rotated_rect_intersection(a, b).map(|region| polygon_area(&region))
+}
+
+/// Returns the intersection over union of 2 rotated rectangles, `0` if both rectangles are degenerate
+pub fn rotated_rect_iou(a: &RotatedRect, b: &RotatedRect) -> Result<f64> {
+	let intersection = rotated_rect_intersection_area(a, b)?;
+	let union = f64::from(a.area()) + f64::from(b.area()) - intersection;
+	Ok(if union > 0. { intersection / union } else { 0. })
+}
+
+/// Returns the signed area of the simple polygon, it's positive for the counter-clockwise vertex order in the
+/// coordinate system with the y axis pointing up (clockwise when displayed as an image)
+pub fn polygon_signed_area(polygon: &[Point2f]) -> f64 {
+	let n = polygon.len();
+	if n < 3 {
+		return 0.;
+	}
+	let twice_area = (0..n)
+		.map(|i| {
+			let (p, q) = (polygon[i], polygon[(i + 1) % n]);
+			f64::from(p.x) * f64::from(q.y) - f64::from(q.x) * f64::from(p.y)
+		})
+		.sum::<f64>();
+	twice_area / 2.
+}
+
+/// Returns the area of the simple polygon regardless of its vertex order
+#[inline]
+pub fn polygon_area(polygon: &[Point2f]) -> f64 {
+	polygon_signed_area(polygon).abs()
+}
+
+/// Clips the `subject` polygon with the convex `clip` polygon (Sutherland-Hodgman algorithm)
+///
+/// `subject` can be concave, `clip` must be convex with either vertex order. The result is empty if the polygons don't
+/// overlap.
+pub fn clip_polygon(subject: &[Point2f], clip: &[Point2f]) -> Vec<Point2f> {
+	if clip.len() < 3 {
+		return vec![];
+	}
+	let orientation = polygon_signed_area(clip).signum() as f32;
+	let mut out = subject.to_vec();
+	for i in 0..clip.len() {
+		if out.is_empty() {
+			break;
+		}
+		let (edge_start, edge_end) = (clip[i], clip[(i + 1) % clip.len()]);
+		let side = |p: Point2f| orientation * ((edge_end.x - edge_start.x) * (p.y - edge_start.y) - (edge_end.y - edge_start.y) * (p.x - edge_start.x));
+		let input = std::mem::take(&mut out);
+		for (j, &current) in input.iter().enumerate() {
+			let prev = input[(j + input.len() - 1) % input.len()];
+			let (side_current, side_prev) = (side(current), side(prev));
+			if side_current >= 0. {
+				if side_prev < 0. {
+					out.push(lerp(prev, current, side_prev / (side_prev - side_current)));
+				}
+				out.push(current);
+			} else if side_prev >= 0. {
+				out.push(lerp(prev, current, side_prev / (side_prev - side_current)));
+			}
+		}
+	}
+	out
+}
+
+#[inline]
+fn lerp(a: Point2f, b: Point2f, t: f32) -> Point2f {
+	Point2f::new(a.x + (b.x - a.x) * t, a.y + (b.y - a.y) * t)
+}
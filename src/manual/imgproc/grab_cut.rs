@@ -0,0 +1,138 @@
+//! Interactive foreground extraction with GrabCut
+//!
+//! ```ignore
+//! let mut segmentation = imgproc::grab_cut::Segmentation::from_rect(&photo, object_rect)?;
+//! segmentation.refine()?;
+//! // the user marks the missed parts of the object and the leaked background
+//! segmentation.add_foreground_stroke(&stroke)?;
+//! segmentation.add_background_stroke(&other_stroke)?;
+//! segmentation.refine()?;
+//! let mask = segmentation.foreground_mask()?;
+//! ```
+
+use crate::{
+	core::{self, Mat, Point, Rect, Scalar, Vector},
+	Error,
+	imgproc,
+	prelude::*,
+	Result,
+};
+
+/// GrabCut session keeping the label mask and the color models between the refinements
+#[derive(Debug)]
+pub struct Segmentation {
+	image: Mat,
+	mask: Mat,
+	bgd_model: Mat,
+	fgd_model: Mat,
+	initialized: bool,
+	iterations: i32,
+	brush_radius: i32,
+}
+
+impl Segmentation {
+	/// Starts the segmentation with everything labelled as the probable background, add the foreground strokes before
+	/// the first [refine](Self::refine)
+	pub fn new(image: &Mat) -> Result<Self> {
+		if image.typ() != core::CV_8UC3 {
+			return Err(Error::new(core::StsUnsupportedFormat, format!("GrabCut requires a CV_8UC3 image, got type: {}", image.typ())));
+		}
+		Ok(Self {
+			image: image.try_clone()?,
+			mask: Mat::new_size_with_default(image.size()?, core::CV_8UC1, Scalar::all(f64::from(imgproc::GC_PR_BGD)))?,
+			bgd_model: Mat::default(),
+			fgd_model: Mat::default(),
+			initialized: false,
+			iterations: 5,
+			brush_radius: 3,
+		})
+	}
+
+	/// Starts the segmentation with the object inside of `rect`: the pixels outside are the background and the pixels
+	/// inside are the probable foreground
+	pub fn from_rect(image: &Mat, rect: impl Into<Rect>) -> Result<Self> {
+		let rect: Rect = rect.into();
+		let mut out = Self::new(image)?;
+		let rect = rect & Rect::new(0, 0, image.cols(), image.rows());
+		if rect.empty() {
+			return Err(Error::new(core::StsBadArg, "Rectangle doesn't overlap the image"));
+		}
+		out.mask.set_to(&Scalar::all(f64::from(imgproc::GC_BGD)), &core::no_array())?;
+		Mat::roi(&out.mask, rect)?.set_to(&Scalar::all(f64::from(imgproc::GC_PR_FGD)), &core::no_array())?;
+		Ok(out)
+	}
+
+	/// Sets the number of the GrabCut iterations of each [refine](Self::refine), `5` by default
+	pub fn with_iterations(mut self, iterations: i32) -> Self {
+		self.iterations = iterations;
+		self
+	}
+
+	/// Sets the radius of the stroke brush in pixels, `3` by default
+	pub fn with_brush_radius(mut self, brush_radius: i32) -> Self {
+		self.brush_radius = brush_radius;
+		self
+	}
+
+	/// Marks the pixels along the stroke as the sure foreground
+	#[inline]
+	pub fn add_foreground_stroke(&mut self, points: &[Point]) -> Result<()> {
+		self.add_stroke(points, imgproc::GC_FGD)
+	}
+
+	/// Marks the pixels along the stroke as the sure background
+	#[inline]
+	pub fn add_background_stroke(&mut self, points: &[Point]) -> Result<()> {
+		self.add_stroke(points, imgproc::GC_BGD)
+	}
+
+	fn add_stroke(&mut self, points: &[Point], label: i32) -> Result<()> {
+		let color = Scalar::all(f64::from(label));
+		match points {
+			[] => Ok(()),
+			[point] => imgproc::circle(&mut self.mask, *point, self.brush_radius, color, imgproc::FILLED, imgproc::LINE_8, 0),
+			points => imgproc::polylines(&mut self.mask, &Vector::from_slice(points), false, color, 2 * self.brush_radius + 1, imgproc::LINE_8, 0),
+		}
+	}
+
+	/// Runs the GrabCut iterations updating the mask and the color models from the current labels
+	///
+	/// The mask must contain both foreground and background labels, the probable ones included.
+	pub fn refine(&mut self) -> Result<()> {
+		let mode = if self.initialized { imgproc::GC_EVAL } else { imgproc::GC_INIT_WITH_MASK };
+		imgproc::grab_cut(&self.image, &mut self.mask, Rect::default(), &mut self.bgd_model, &mut self.fgd_model, self.iterations, mode)?;
+		self.initialized = true;
+		Ok(())
+	}
+
+	/// Whether the color models were estimated by the first [refine](Self::refine)
+	#[inline]
+	pub fn is_initialized(&self) -> bool {
+		self.initialized
+	}
+
+	/// `CV_8UC1` mask of the `imgproc::GC_*` labels
+	#[inline]
+	pub fn mask(&self) -> &Mat {
+		&self.mask
+	}
+
+	/// Replaces the label mask, e.g. to restore the state for the undo, the color models are kept
+	pub fn set_mask(&mut self, mask: Mat) -> Result<()> {
+		if mask.typ() != core::CV_8UC1 || mask.size()? != self.image.size()? {
+			return Err(Error::new(core::StsBadArg, format!("Mask must be CV_8UC1 of size: {:?}, got type: {} of size: {:?}", self.image.size()?, mask.typ(), mask.size()?)));
+		}
+		self.mask = mask;
+		Ok(())
+	}
+
+	/// Returns the `CV_8UC1` mask with `255` for the sure and probable foreground pixels
+	pub fn foreground_mask(&self) -> Result<Mat> {
+		// GC_FGD and GC_PR_FGD are the odd labels
+		let mut odd = Mat::default();
+		core::bitwise_and(&self.mask, &Scalar::all(1.), &mut odd, &core::no_array())?;
+		let mut out = Mat::default();
+		imgproc::threshold(&odd, &mut out, 0., 255., imgproc::THRESH_BINARY)?;
+		Ok(out)
+	}
+}
@@ -0,0 +1,199 @@
+use std::mem;
+
+use crate::{
+	core::{self, Mat, Point, Scalar, Size},
+	imgproc,
+	prelude::*,
+	Result,
+};
+
+/// Structuring element of a [Morphology] step
+#[derive(Debug)]
+pub enum Kernel {
+	/// One of `imgproc::MORPH_RECT`, `MORPH_CROSS` or `MORPH_ELLIPSE` shapes of the given size
+	Shape { shape: i32, size: Size },
+	/// Custom `CV_8U` structuring element, non-zero pixels are part of the element
+	Custom(Mat),
+}
+
+impl Kernel {
+	/// Square of `size x size`
+	#[inline]
+	pub fn rect(size: i32) -> Self {
+		Self::rect_size(Size::new(size, size))
+	}
+
+	#[inline]
+	pub fn rect_size(size: impl Into<Size>) -> Self {
+		Kernel::Shape { shape: imgproc::MORPH_RECT, size: size.into() }
+	}
+
+	/// Circle inscribed into `size x size` square
+	#[inline]
+	pub fn ellipse(size: i32) -> Self {
+		Self::ellipse_size(Size::new(size, size))
+	}
+
+	#[inline]
+	pub fn ellipse_size(size: impl Into<Size>) -> Self {
+		Kernel::Shape { shape: imgproc::MORPH_ELLIPSE, size: size.into() }
+	}
+
+	/// Cross of `size x size`
+	#[inline]
+	pub fn cross(size: i32) -> Self {
+		Self::cross_size(Size::new(size, size))
+	}
+
+	#[inline]
+	pub fn cross_size(size: impl Into<Size>) -> Self {
+		Kernel::Shape { shape: imgproc::MORPH_CROSS, size: size.into() }
+	}
+
+	/// Returns the structuring element as `Mat`
+	pub fn to_mat(&self) -> Result<Mat> {
+		match self {
+			Kernel::Shape { shape, size } => imgproc::get_structuring_element(*shape, *size, Point::new(-1, -1)),
+			Kernel::Custom(element) => element.try_clone(),
+		}
+	}
+}
+
+impl From<Mat> for Kernel {
+	#[inline]
+	fn from(s: Mat) -> Self {
+		Kernel::Custom(s)
+	}
+}
+
+#[derive(Debug)]
+struct MorphologyStep {
+	op: i32,
+	kernel: Kernel,
+	iterations: i32,
+}
+
+/// Sequence of morphological operations applied one after another
+///
+/// ```ignore
+/// let cleaned = imgproc::Morphology::new()
+///     .open(Kernel::ellipse(5))
+///     .close(Kernel::rect(3))
+///     .apply(&mask)?;
+/// ```
+/// The intermediate results are stored in 2 buffers that are swapped between the steps, so the whole sequence doesn't
+/// allocate more than 2 images regardless of the number of steps.
+#[derive(Debug)]
+pub struct Morphology {
+	steps: Vec<MorphologyStep>,
+	border_type: i32,
+	border_value: Option<Scalar>,
+}
+
+impl Default for Morphology {
+	fn default() -> Self {
+		Self {
+			steps: vec![],
+			border_type: core::BORDER_CONSTANT,
+			border_value: None,
+		}
+	}
+}
+
+impl Morphology {
+	#[inline]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Adds the step with any `imgproc::MORPH_*` operation applied `iterations` times
+	pub fn op(mut self, op: i32, kernel: impl Into<Kernel>, iterations: i32) -> Self {
+		self.steps.push(MorphologyStep { op, kernel: kernel.into(), iterations });
+		self
+	}
+
+	#[inline]
+	pub fn erode(self, kernel: impl Into<Kernel>) -> Self {
+		self.op(imgproc::MORPH_ERODE, kernel, 1)
+	}
+
+	#[inline]
+	pub fn dilate(self, kernel: impl Into<Kernel>) -> Self {
+		self.op(imgproc::MORPH_DILATE, kernel, 1)
+	}
+
+	/// Erosion followed by dilation, removes the small foreground specks
+	#[inline]
+	pub fn open(self, kernel: impl Into<Kernel>) -> Self {
+		self.op(imgproc::MORPH_OPEN, kernel, 1)
+	}
+
+	/// Dilation followed by erosion, fills the small holes in the foreground
+	#[inline]
+	pub fn close(self, kernel: impl Into<Kernel>) -> Self {
+		self.op(imgproc::MORPH_CLOSE, kernel, 1)
+	}
+
+	#[inline]
+	pub fn gradient(self, kernel: impl Into<Kernel>) -> Self {
+		self.op(imgproc::MORPH_GRADIENT, kernel, 1)
+	}
+
+	#[inline]
+	pub fn top_hat(self, kernel: impl Into<Kernel>) -> Self {
+		self.op(imgproc::MORPH_TOPHAT, kernel, 1)
+	}
+
+	#[inline]
+	pub fn black_hat(self, kernel: impl Into<Kernel>) -> Self {
+		self.op(imgproc::MORPH_BLACKHAT, kernel, 1)
+	}
+
+	/// Sets the border handling of all steps, by default it's `BORDER_CONSTANT` with
+	/// `morphology_default_border_value()` which doesn't affect the result
+	#[inline]
+	pub fn with_border(mut self, border_type: i32, border_value: impl Into<Scalar>) -> Self {
+		self.border_type = border_type;
+		self.border_value = Some(border_value.into());
+		self
+	}
+
+	/// Number of the added steps
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.steps.len()
+	}
+
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.steps.is_empty()
+	}
+
+	/// Runs all steps on `src` and returns the result, a copy of `src` is returned if there are no steps
+	pub fn apply(&self, src: &Mat) -> Result<Mat> {
+		let mut out = Mat::default();
+		self.apply_to(src, &mut out)?;
+		Ok(out)
+	}
+
+	/// Runs all steps on `src` and stores the result into `dst`, `dst` is reused as one of the intermediate buffers
+	pub fn apply_to(&self, src: &Mat, dst: &mut Mat) -> Result<()> {
+		let border_value = match self.border_value {
+			Some(border_value) => border_value,
+			None => imgproc::morphology_default_border_value()?,
+		};
+		let mut steps = self.steps.iter();
+		let first = match steps.next() {
+			Some(first) => first,
+			None => return src.copy_to(dst),
+		};
+		let anchor = Point::new(-1, -1);
+		imgproc::morphology_ex(src, dst, first.op, &first.kernel.to_mat()?, anchor, first.iterations, self.border_type, border_value)?;
+		let mut buf = Mat::default();
+		for step in steps {
+			imgproc::morphology_ex(dst, &mut buf, step.op, &step.kernel.to_mat()?, anchor, step.iterations, self.border_type, border_value)?;
+			mem::swap(dst, &mut buf);
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,192 @@
+//! Photometric consistency of the multi-camera captures
+//!
+//! The cameras of a rig usually run their own auto exposure and white balance, the differences hurt the feature
+//! matching and show as seams in the stitched panoramas. [match_exposure] brings the frames to the common brightness
+//! and color statistics:
+//! ```ignore
+//! let options = ExposureOptions::new(ExposureModel::GainOffset).with_masks(overlap_masks);
+//! let matched = imgproc::photometric::match_exposure(&frames, &options)?;
+//! ```
+
+use crate::{
+	core::{self, Mat, Scalar},
+	Error,
+	imgproc,
+	prelude::*,
+	Result,
+};
+
+/// Model of the photometric difference between the cameras
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExposureModel {
+	/// Single gain for all channels, compensates the exposure and keeps the white balance
+	Gain,
+	/// Gain and offset for each channel, matches the mean and the standard deviation of every channel
+	GainOffset,
+	/// Gain and offset for each channel in the Lab color space (Reinhard color transfer), transfers both the exposure
+	/// and the color cast, requires 3 channel BGR frames
+	ColorTransfer,
+}
+
+/// Options of the [match_exposure]
+#[derive(Debug)]
+pub struct ExposureOptions {
+	pub model: ExposureModel,
+	/// Camera the others are matched to, `None` to match all cameras to their average statistics
+	pub reference: Option<usize>,
+	/// `CV_8UC1` mask of the pixels used for the statistics of each frame, e.g. the overlap with the other cameras, an
+	/// empty Mat uses the whole frame, no masks by default
+	pub masks: Vec<Mat>,
+}
+
+impl ExposureOptions {
+	pub fn new(model: ExposureModel) -> Self {
+		Self { model, reference: None, masks: vec![] }
+	}
+
+	pub fn with_reference(mut self, reference: Option<usize>) -> Self {
+		self.reference = reference;
+		self
+	}
+
+	pub fn with_masks(mut self, masks: Vec<Mat>) -> Self {
+		self.masks = masks;
+		self
+	}
+}
+
+impl Default for ExposureOptions {
+	#[inline]
+	fn default() -> Self {
+		Self::new(ExposureModel::GainOffset)
+	}
+}
+
+/// Photometric correction of a camera `out = gain * in + offset` for each channel
+#[derive(Clone, Debug, PartialEq)]
+pub struct Compensation {
+	pub gain: Vec<f64>,
+	pub offset: Vec<f64>,
+	/// Whether the correction is applied in the Lab color space
+	pub lab: bool,
+}
+
+impl Compensation {
+	/// Correction that doesn't change the frame
+	pub fn identity(channels: usize) -> Self {
+		Self { gain: vec![1.; channels], offset: vec![0.; channels], lab: false }
+	}
+
+	/// Applies the correction to the frame, the values are saturated to the range of the frame depth
+	pub fn apply(&self, frame: &Mat) -> Result<Mat> {
+		let channels = frame.channels() as usize;
+		if channels != self.gain.len() {
+			return Err(Error::new(core::StsUnmatchedFormats, format!("Compensation has: {} channels, but the frame has: {}", self.gain.len(), channels)));
+		}
+		// channels x (channels + 1) affine matrix
+		let mut m = Mat::new_rows_cols_with_default(channels as i32, channels as i32 + 1, core::CV_64FC1, Scalar::default())?;
+		for (c, (&gain, &offset)) in self.gain.iter().zip(&self.offset).enumerate() {
+			*m.at_2d_mut::<f64>(c as i32, c as i32)? = gain;
+			*m.at_2d_mut::<f64>(c as i32, channels as i32)? = offset;
+		}
+		let mut out = Mat::default();
+		if self.lab {
+			let lab = convert(frame, imgproc::COLOR_BGR2Lab)?;
+			let mut transformed = Mat::default();
+			core::transform(&lab, &mut transformed, &m)?;
+			imgproc::cvt_color(&transformed, &mut out, imgproc::COLOR_Lab2BGR, 0)?;
+		} else {
+			core::transform(frame, &mut out, &m)?;
+		}
+		Ok(out)
+	}
+}
+
+/// Estimates the correction of each frame bringing them to the common photometry
+///
+/// All frames must have the same type, the frames of the different sizes are fine.
+pub fn estimate_exposure(frames: &[Mat], options: &ExposureOptions) -> Result<Vec<Compensation>> {
+	let first = match frames.first() {
+		Some(first) => first,
+		None => return Ok(vec![]),
+	};
+	if let Some(frame) = frames.iter().find(|frame| frame.typ() != first.typ()) {
+		return Err(Error::new(core::StsUnmatchedFormats, format!("All frames must have the same type, got: {} and {}", first.typ(), frame.typ())));
+	}
+	if !options.masks.is_empty() && options.masks.len() != frames.len() {
+		return Err(Error::new(core::StsUnmatchedSizes, format!("Got: {} masks for: {} frames", options.masks.len(), frames.len())));
+	}
+	if matches!(options.reference, Some(reference) if reference >= frames.len()) {
+		return Err(Error::new(core::StsOutOfRange, format!("Reference camera is out of bounds of: {} frames", frames.len())));
+	}
+	let lab = options.model == ExposureModel::ColorTransfer;
+	if lab && first.channels() != 3 {
+		return Err(Error::new(core::StsBadArg, format!("Color transfer requires 3 channel frames, got: {}", first.channels())));
+	}
+
+	let stats = frames.iter()
+		.enumerate()
+		.map(|(i, frame)| {
+			let mask = options.masks.get(i).filter(|mask| !mask.empty());
+			if lab {
+				statistics(&convert(frame, imgproc::COLOR_BGR2Lab)?, mask)
+			} else {
+				statistics(frame, mask)
+			}
+		})
+		.collect::<Result<Vec<_>>>()?;
+	let channels = first.channels() as usize;
+	let (ref_mean, ref_std) = match options.reference {
+		Some(reference) => stats[reference].clone(),
+		None => {
+			let n = stats.len() as f64;
+			let mut average = (vec![0.; channels], vec![0.; channels]);
+			for (mean, std) in &stats {
+				average.0.iter_mut().zip(mean).for_each(|(acc, v)| *acc += v / n);
+				average.1.iter_mut().zip(std).for_each(|(acc, v)| *acc += v / n);
+			}
+			average
+		}
+	};
+
+	Ok(stats.iter()
+		.map(|(mean, std)| match options.model {
+			ExposureModel::Gain => {
+				let brightness = mean.iter().sum::<f64>();
+				let gain = if brightness > f64::EPSILON { ref_mean.iter().sum::<f64>() / brightness } else { 1. };
+				Compensation { gain: vec![gain; channels], offset: vec![0.; channels], lab: false }
+			}
+			ExposureModel::GainOffset | ExposureModel::ColorTransfer => {
+				let gain = std.iter().zip(&ref_std).map(|(&std, &ref_std)| if std > f64::EPSILON { ref_std / std } else { 1. }).collect::<Vec<_>>();
+				let offset = mean.iter().zip(&ref_mean).zip(&gain).map(|((&mean, &ref_mean), &gain)| ref_mean - gain * mean).collect();
+				Compensation { gain, offset, lab }
+			}
+		})
+		.collect())
+}
+
+/// Brings the frames to the common photometry, see [estimate_exposure]
+pub fn match_exposure(frames: &[Mat], options: &ExposureOptions) -> Result<Vec<Mat>> {
+	estimate_exposure(frames, options)?
+		.iter()
+		.zip(frames)
+		.map(|(compensation, frame)| compensation.apply(frame))
+		.collect()
+}
+
+/// Per channel mean and standard deviation
+fn statistics(frame: &Mat, mask: Option<&Mat>) -> Result<(Vec<f64>, Vec<f64>)> {
+	let mut mean = Mat::default();
+	let mut std = Mat::default();
+	match mask {
+		Some(mask) => core::mean_std_dev(frame, &mut mean, &mut std, mask)?,
+		None => core::mean_std_dev(frame, &mut mean, &mut std, &core::no_array())?,
+	}
+	Ok((mean.data_typed::<f64>()?.to_vec(), std.data_typed::<f64>()?.to_vec()))
+}
+
+fn convert(frame: &Mat, code: i32) -> Result<Mat> {
+	let mut out = Mat::default();
+	imgproc::cvt_color(frame, &mut out, code, 0)?;
+	Ok(out)
+}
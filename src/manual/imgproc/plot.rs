@@ -0,0 +1,579 @@
+//! Headless rendering of line, bar and scatter charts into Mats or SVG
+//!
+//! Doesn't require highgui or the contrib `plot` module, intended for saving the training diagnostics to disk:
+//! ```ignore
+//! let chart = Plot::new(Size::new(640, 480))
+//!     .with_title("training")
+//!     .with_labels("epoch", "loss")
+//!     .series(Series::line("train", train_loss))
+//!     .series(Series::line("validation", val_loss));
+//! chart.save("loss.png")?;
+//! chart.save("loss.svg")?;
+//! ```
+
+use std::{fmt::Write, fs, path::Path};
+
+use crate::{
+	core::{self, Mat, Point, Rect, Scalar, Size},
+	Error,
+	imgproc,
+	prelude::*,
+	Result,
+};
+
+/// Colors assigned to the series without an explicit color, BGR
+const PALETTE: [(f64, f64, f64); 6] = [
+	(180., 119., 31.),
+	(14., 127., 255.),
+	(44., 160., 44.),
+	(40., 39., 214.),
+	(189., 103., 148.),
+	(75., 86., 140.),
+];
+
+const MARGIN_LEFT: f64 = 64.;
+const MARGIN_RIGHT: f64 = 20.;
+const MARGIN_TOP: f64 = 36.;
+const MARGIN_BOTTOM: f64 = 44.;
+const FONT_PX: f64 = 12.;
+
+/// How the [Series] points are drawn
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Style {
+	/// Points connected with the line, non-finite values break the line
+	Line,
+	/// Vertical bars from 0, e.g. for histograms
+	Bars,
+	/// Unconnected dots
+	Points,
+	/// Dashed line, e.g. for the reference lines
+	Dashed,
+}
+
+/// Named set of points in the [Plot]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Series {
+	pub name: String,
+	pub points: Vec<(f64, f64)>,
+	pub style: Style,
+	/// `None` to pick the next color from the palette
+	pub color: Option<Scalar>,
+}
+
+impl Series {
+	/// Creates the line from the values, the x coordinates are the value indices
+	pub fn line(name: impl Into<String>, values: impl IntoIterator<Item=impl Into<f64>>) -> Self {
+		let points = values.into_iter().enumerate().map(|(i, y)| (i as f64, y.into())).collect();
+		Self::xy(name, points)
+	}
+
+	/// Creates the line from the `(x, y)` points
+	pub fn xy(name: impl Into<String>, points: Vec<(f64, f64)>) -> Self {
+		Self { name: name.into(), points, style: Style::Line, color: None }
+	}
+
+	/// Creates the line from the single-channel Mat with a single row or column
+	pub fn from_mat(name: impl Into<String>, mat: &impl MatTraitConst) -> Result<Self> {
+		if mat.channels() != 1 || (mat.rows() != 1 && mat.cols() != 1) {
+			return Err(Error::new(core::StsBadArg, format!("Can only plot single-channel 1-D Mat, got {}x{} with {} channels", mat.rows(), mat.cols(), mat.channels())));
+		}
+		let mut values = Mat::default();
+		mat.convert_to(&mut values, core::CV_64F, 1., 0.)?;
+		let values = values.reshape(1, 1)?;
+		Ok(Self::line(name, values.at_row::<f64>(0)?.iter().copied()))
+	}
+
+	/// Creates the histogram of the values with `bins` equal bins between the minimum and the maximum value
+	pub fn histogram(name: impl Into<String>, values: impl IntoIterator<Item=impl Into<f64>>, bins: usize) -> Self {
+		let values = values.into_iter().map(Into::into).filter(|v: &f64| v.is_finite()).collect::<Vec<_>>();
+		let bins = bins.max(1);
+		let (min, max) = values.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &v| (min.min(v), max.max(v)));
+		let mut counts = vec![0usize; bins];
+		if !values.is_empty() {
+			let width = (max - min) / bins as f64;
+			for &v in &values {
+				let bin = if width > 0. { ((v - min) / width) as usize } else { 0 };
+				counts[bin.min(bins - 1)] += 1;
+			}
+		}
+		let width = if max > min { (max - min) / bins as f64 } else { 1. };
+		let start = if min.is_finite() { min } else { 0. };
+		let points = counts.iter().enumerate().map(|(i, &count)| (start + width * (i as f64 + 0.5), count as f64)).collect();
+		Self::xy(name, points).with_style(Style::Bars)
+	}
+
+	#[inline]
+	pub fn with_style(mut self, style: Style) -> Self {
+		self.style = style;
+		self
+	}
+
+	#[inline]
+	pub fn with_color(mut self, color: impl Into<Scalar>) -> Self {
+		self.color = Some(color.into());
+		self
+	}
+
+	fn finite_points(&self) -> impl Iterator<Item=(f64, f64)> + '_ {
+		self.points.iter().copied().filter(|(x, y)| x.is_finite() && y.is_finite())
+	}
+
+	/// Width of a bar in the data units, 80% of the smallest distance between the neighboring points
+	fn bar_width(&self) -> f64 {
+		let mut xs = self.finite_points().map(|(x, _)| x).collect::<Vec<_>>();
+		xs.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+		let min_step = xs.windows(2).map(|w| w[1] - w[0]).filter(|&d| d > 0.).fold(f64::INFINITY, f64::min);
+		if min_step.is_finite() { min_step * 0.8 } else { 0.8 }
+	}
+}
+
+/// Chart with one or more [Series], see the [module documentation](self)
+#[derive(Clone, Debug, PartialEq)]
+pub struct Plot {
+	pub size: Size,
+	pub title: String,
+	pub x_label: String,
+	pub y_label: String,
+	/// `None` to fit the data
+	pub x_range: Option<(f64, f64)>,
+	/// `None` to fit the data
+	pub y_range: Option<(f64, f64)>,
+	pub grid: bool,
+	pub background: Scalar,
+	pub series: Vec<Series>,
+}
+
+impl Plot {
+	pub fn new(size: impl Into<Size>) -> Self {
+		Self {
+			size: size.into(),
+			title: String::new(),
+			x_label: String::new(),
+			y_label: String::new(),
+			x_range: None,
+			y_range: None,
+			grid: true,
+			background: Scalar::all(255.),
+			series: vec![],
+		}
+	}
+
+	/// Creates the ROC curve plot from the `(false positive rate, true positive rate)` points with the chance diagonal
+	pub fn roc(size: impl Into<Size>, name: impl Into<String>, points: Vec<(f64, f64)>) -> Self {
+		Self::new(size)
+			.with_title("ROC")
+			.with_labels("false positive rate", "true positive rate")
+			.with_x_range(0., 1.)
+			.with_y_range(0., 1.)
+			.series(Series::xy(name, points))
+			.series(Series::xy("", vec![(0., 0.), (1., 1.)]).with_style(Style::Dashed).with_color(Scalar::all(160.)))
+	}
+
+	#[inline]
+	pub fn with_title(mut self, title: impl Into<String>) -> Self {
+		self.title = title.into();
+		self
+	}
+
+	#[inline]
+	pub fn with_labels(mut self, x_label: impl Into<String>, y_label: impl Into<String>) -> Self {
+		self.x_label = x_label.into();
+		self.y_label = y_label.into();
+		self
+	}
+
+	#[inline]
+	pub fn with_x_range(mut self, min: f64, max: f64) -> Self {
+		self.x_range = Some((min, max));
+		self
+	}
+
+	#[inline]
+	pub fn with_y_range(mut self, min: f64, max: f64) -> Self {
+		self.y_range = Some((min, max));
+		self
+	}
+
+	#[inline]
+	pub fn with_grid(mut self, grid: bool) -> Self {
+		self.grid = grid;
+		self
+	}
+
+	/// Adds the series
+	#[inline]
+	pub fn series(mut self, series: Series) -> Self {
+		self.series.push(series);
+		self
+	}
+
+	/// Renders the plot into the `CV_8UC3` Mat
+	pub fn render(&self) -> Result<Mat> {
+		let layout = self.layout()?;
+		let mut canvas = MatCanvas { mat: Mat::new_size_with_default(self.size, core::CV_8UC3, self.background)? };
+		self.draw(&layout, &mut canvas)?;
+		Ok(canvas.mat)
+	}
+
+	/// Renders the plot as the SVG document
+	pub fn to_svg(&self) -> Result<String> {
+		let layout = self.layout()?;
+		let mut canvas = SvgCanvas { out: String::new() };
+		let _ = write!(
+			canvas.out,
+			r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" font-family="sans-serif">"#,
+			w = self.size.width,
+			h = self.size.height,
+		);
+		let _ = write!(canvas.out, r#"<rect width="100%" height="100%" fill="{}"/>"#, svg_color(self.background));
+		self.draw(&layout, &mut canvas)?;
+		canvas.out.push_str("</svg>\n");
+		Ok(canvas.out)
+	}
+
+	/// Saves the plot to the file, `.svg` extension writes SVG, other extensions are encoded with `imgcodecs::imwrite()`
+	pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+		let path = path.as_ref();
+		if matches!(path.extension().and_then(|ext| ext.to_str()), Some(ext) if ext.eq_ignore_ascii_case("svg")) {
+			return fs::write(path, self.to_svg()?)
+				.map_err(|e| Error::new(core::StsError, format!("Can't write plot to: {}: {}", path.display(), e)));
+		}
+		self.save_image(path)
+	}
+
+	#[cfg(ocvrs_has_module_imgcodecs)]
+	fn save_image(&self, path: &Path) -> Result<()> {
+		let path_str = path.to_str()
+			.ok_or_else(|| Error::new(core::StsBadArg, format!("Path is not valid UTF-8: {}", path.display())))?;
+		if crate::imgcodecs::imwrite(path_str, &self.render()?, &core::Vector::new())? {
+			Ok(())
+		} else {
+			Err(Error::new(core::StsError, format!("Can't write plot to: {}", path.display())))
+		}
+	}
+
+	#[cfg(not(ocvrs_has_module_imgcodecs))]
+	fn save_image(&self, path: &Path) -> Result<()> {
+		Err(Error::new(core::StsNotImplemented, format!("Saving raster images requires imgcodecs module, use .svg extension: {}", path.display())))
+	}
+
+	fn layout(&self) -> Result<Layout> {
+		let (width, height) = (f64::from(self.size.width), f64::from(self.size.height));
+		if width <= MARGIN_LEFT + MARGIN_RIGHT || height <= MARGIN_TOP + MARGIN_BOTTOM {
+			return Err(Error::new(core::StsBadArg, format!("Plot size is too small: {:?}", self.size)));
+		}
+		let mut x_data = (f64::INFINITY, f64::NEG_INFINITY);
+		let mut y_data = (f64::INFINITY, f64::NEG_INFINITY);
+		for series in &self.series {
+			let half_bar = if series.style == Style::Bars { series.bar_width() / 2. } else { 0. };
+			for (x, y) in series.finite_points() {
+				x_data = (x_data.0.min(x - half_bar), x_data.1.max(x + half_bar));
+				y_data = (y_data.0.min(y), y_data.1.max(y));
+				if series.style == Style::Bars {
+					y_data = (y_data.0.min(0.), y_data.1.max(0.));
+				}
+			}
+		}
+		let x = self.x_range.unwrap_or_else(|| expand(x_data, 0.));
+		let y = self.y_range.unwrap_or_else(|| expand(y_data, 0.05));
+		let valid = |(min, max): (f64, f64)| min.is_finite() && max.is_finite() && max > min;
+		if !valid(x) || !valid(y) {
+			return Err(Error::new(core::StsBadArg, format!("Invalid plot range: x {:?}, y {:?}", x, y)));
+		}
+		Ok(Layout {
+			x,
+			y,
+			left: MARGIN_LEFT,
+			top: MARGIN_TOP,
+			right: width - MARGIN_RIGHT,
+			bottom: height - MARGIN_BOTTOM,
+		})
+	}
+
+	fn draw(&self, layout: &Layout, canvas: &mut dyn Canvas) -> Result<()> {
+		let axis_color = Scalar::all(60.);
+		let grid_color = Scalar::all(225.);
+		let text_color = Scalar::all(30.);
+		let (left, top, right, bottom) = (layout.left, layout.top, layout.right, layout.bottom);
+
+		for x in ticks(layout.x) {
+			let px = layout.px(x, layout.y.0).0;
+			if self.grid {
+				canvas.line((px, top), (px, bottom), grid_color, false)?;
+			}
+			canvas.line((px, bottom), (px, bottom + 4.), axis_color, false)?;
+			canvas.text((px, bottom + 6. + FONT_PX), &format_tick(x, layout.x), Anchor::Middle, text_color)?;
+		}
+		for y in ticks(layout.y) {
+			let py = layout.px(layout.x.0, y).1;
+			if self.grid {
+				canvas.line((left, py), (right, py), grid_color, false)?;
+			}
+			canvas.line((left - 4., py), (left, py), axis_color, false)?;
+			canvas.text((left - 6., py + FONT_PX / 2. - 1.), &format_tick(y, layout.y), Anchor::End, text_color)?;
+		}
+		canvas.line((left, bottom), (right, bottom), axis_color, false)?;
+		canvas.line((left, top), (left, bottom), axis_color, false)?;
+
+		canvas.text(((left + right) / 2., top - 14.), &self.title, Anchor::Middle, text_color)?;
+		canvas.text(((left + right) / 2., bottom + 14. + FONT_PX * 2.), &self.x_label, Anchor::Middle, text_color)?;
+		canvas.text((left - 6., top - 14.), &self.y_label, Anchor::Start, text_color)?;
+
+		let mut palette = PALETTE.iter().cycle();
+		let mut legend_y = top + 8.;
+		for series in &self.series {
+			let color = series.color.unwrap_or_else(|| {
+				let &(b, g, r) = palette.next().unwrap_or(&PALETTE[0]);
+				Scalar::new(b, g, r, 0.)
+			});
+			match series.style {
+				Style::Line | Style::Dashed => {
+					let mut prev = None;
+					for &(x, y) in &series.points {
+						let cur = if x.is_finite() && y.is_finite() { Some(layout.px(x, y)) } else { None };
+						if let (Some(from), Some(to)) = (prev, cur) {
+							let (from, to) = layout.clip(from, to);
+							canvas.line(from, to, color, series.style == Style::Dashed)?;
+						}
+						prev = cur;
+					}
+				}
+				Style::Bars => {
+					let half = series.bar_width() / 2.;
+					let base = 0f64.max(layout.y.0).min(layout.y.1);
+					for (x, y) in series.finite_points() {
+						let (x1, y1) = layout.clip_point(layout.px(x - half, y));
+						let (x2, y2) = layout.clip_point(layout.px(x + half, base));
+						canvas.fill_rect((x1.min(x2), y1.min(y2)), ((x2 - x1).abs(), (y2 - y1).abs()), color)?;
+					}
+				}
+				Style::Points => {
+					for (x, y) in series.finite_points() {
+						let p = layout.px(x, y);
+						if layout.contains(p) {
+							canvas.dot(p, color)?;
+						}
+					}
+				}
+			}
+			if !series.name.is_empty() {
+				canvas.line((right - 110., legend_y), (right - 90., legend_y), color, series.style == Style::Dashed)?;
+				canvas.text((right - 84., legend_y + FONT_PX / 2. - 1.), &series.name, Anchor::Start, text_color)?;
+				legend_y += FONT_PX + 4.;
+			}
+		}
+		Ok(())
+	}
+}
+
+/// Mapping from the data coordinates into the image coordinates
+struct Layout {
+	x: (f64, f64),
+	y: (f64, f64),
+	left: f64,
+	top: f64,
+	right: f64,
+	bottom: f64,
+}
+
+impl Layout {
+	fn px(&self, x: f64, y: f64) -> (f64, f64) {
+		(
+			self.left + (x - self.x.0) / (self.x.1 - self.x.0) * (self.right - self.left),
+			self.bottom - (y - self.y.0) / (self.y.1 - self.y.0) * (self.bottom - self.top),
+		)
+	}
+
+	fn contains(&self, (x, y): (f64, f64)) -> bool {
+		x >= self.left && x <= self.right && y >= self.top && y <= self.bottom
+	}
+
+	fn clip_point(&self, (x, y): (f64, f64)) -> (f64, f64) {
+		(x.max(self.left).min(self.right), y.max(self.top).min(self.bottom))
+	}
+
+	/// Clips the segment to the plot area (Liang-Barsky), degenerates into a point if it's completely outside
+	fn clip(&self, from: (f64, f64), to: (f64, f64)) -> ((f64, f64), (f64, f64)) {
+		let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+		let (mut t0, mut t1) = (0f64, 1f64);
+		for &(p, q) in &[(-dx, from.0 - self.left), (dx, self.right - from.0), (-dy, from.1 - self.top), (dy, self.bottom - from.1)] {
+			if p == 0. {
+				if q < 0. {
+					return (self.clip_point(from), self.clip_point(from));
+				}
+			} else {
+				let t = q / p;
+				if p < 0. {
+					t0 = t0.max(t);
+				} else {
+					t1 = t1.min(t);
+				}
+			}
+		}
+		if t0 > t1 {
+			return (self.clip_point(from), self.clip_point(from));
+		}
+		((from.0 + t0 * dx, from.1 + t0 * dy), (from.0 + t1 * dx, from.1 + t1 * dy))
+	}
+}
+
+/// Pads the data range by `padding` of its span, empty or zero-width ranges are expanded around the value
+fn expand((min, max): (f64, f64), padding: f64) -> (f64, f64) {
+	if !min.is_finite() || !max.is_finite() {
+		(0., 1.)
+	} else if max > min {
+		let pad = (max - min) * padding;
+		(min - pad, max + pad)
+	} else {
+		(min - 0.5, max + 0.5)
+	}
+}
+
+/// Returns the step of about 5 ticks that's 1, 2 or 5 times a power of 10
+fn tick_step((min, max): (f64, f64)) -> f64 {
+	let raw = (max - min) / 5.;
+	let magnitude = 10f64.powf(raw.log10().floor());
+	let normalized = raw / magnitude;
+	let nice = if normalized < 1.5 {
+		1.
+	} else if normalized < 3.5 {
+		2.
+	} else if normalized < 7.5 {
+		5.
+	} else {
+		10.
+	};
+	nice * magnitude
+}
+
+fn ticks(range: (f64, f64)) -> Vec<f64> {
+	let step = tick_step(range);
+	let first = (range.0 / step).ceil() as i64;
+	let last = (range.1 / step).floor() as i64;
+	(first..=last).map(|i| i as f64 * step).collect()
+}
+
+fn format_tick(value: f64, range: (f64, f64)) -> String {
+	let decimals = (-tick_step(range).log10().floor()).max(0.) as usize;
+	// avoid "-0"
+	let value = if value.abs() < tick_step(range) * 1e-9 { 0. } else { value };
+	format!("{:.*}", decimals, value)
+}
+
+fn svg_color(color: Scalar) -> String {
+	format!("rgb({},{},{})", color[2] as u8, color[1] as u8, color[0] as u8)
+}
+
+fn svg_escape(text: &str) -> String {
+	text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Anchor {
+	Start,
+	Middle,
+	End,
+}
+
+/// Drawing backend of [Plot], coordinates are in pixels
+trait Canvas {
+	fn line(&mut self, from: (f64, f64), to: (f64, f64), color: Scalar, dashed: bool) -> Result<()>;
+	fn fill_rect(&mut self, origin: (f64, f64), size: (f64, f64), color: Scalar) -> Result<()>;
+	fn dot(&mut self, center: (f64, f64), color: Scalar) -> Result<()>;
+	/// `pos` is the baseline point of the text
+	fn text(&mut self, pos: (f64, f64), text: &str, anchor: Anchor, color: Scalar) -> Result<()>;
+}
+
+struct MatCanvas {
+	mat: Mat,
+}
+
+fn to_point((x, y): (f64, f64)) -> Point {
+	Point::new(x.round() as i32, y.round() as i32)
+}
+
+impl Canvas for MatCanvas {
+	fn line(&mut self, from: (f64, f64), to: (f64, f64), color: Scalar, dashed: bool) -> Result<()> {
+		if !dashed {
+			return imgproc::line(&mut self.mat, to_point(from), to_point(to), color, 1, imgproc::LINE_AA, 0);
+		}
+		let len = (to.0 - from.0).hypot(to.1 - from.1);
+		let dashes = (len / 8.).ceil() as usize;
+		for i in 0..dashes {
+			let t0 = i as f64 * 8. / len;
+			let t1 = ((i as f64 * 8. + 4.) / len).min(1.);
+			let a = (from.0 + (to.0 - from.0) * t0, from.1 + (to.1 - from.1) * t0);
+			let b = (from.0 + (to.0 - from.0) * t1, from.1 + (to.1 - from.1) * t1);
+			imgproc::line(&mut self.mat, to_point(a), to_point(b), color, 1, imgproc::LINE_AA, 0)?;
+		}
+		Ok(())
+	}
+
+	fn fill_rect(&mut self, origin: (f64, f64), size: (f64, f64), color: Scalar) -> Result<()> {
+		let tl = to_point(origin);
+		let br = to_point((origin.0 + size.0, origin.1 + size.1));
+		imgproc::rectangle(&mut self.mat, Rect::from_points(tl, br), color, -1, imgproc::LINE_8, 0)
+	}
+
+	fn dot(&mut self, center: (f64, f64), color: Scalar) -> Result<()> {
+		imgproc::circle(&mut self.mat, to_point(center), 2, color, -1, imgproc::LINE_AA, 0)
+	}
+
+	fn text(&mut self, pos: (f64, f64), text: &str, anchor: Anchor, color: Scalar) -> Result<()> {
+		if text.is_empty() {
+			return Ok(());
+		}
+		// Hershey simplex glyphs are ~22px high at scale 1
+		let scale = FONT_PX / 22.;
+		let mut baseline = 0;
+		let size = imgproc::get_text_size(text, imgproc::FONT_HERSHEY_SIMPLEX, scale, 1, &mut baseline)?;
+		let x = match anchor {
+			Anchor::Start => pos.0,
+			Anchor::Middle => pos.0 - f64::from(size.width) / 2.,
+			Anchor::End => pos.0 - f64::from(size.width),
+		};
+		imgproc::put_text(&mut self.mat, text, to_point((x, pos.1)), imgproc::FONT_HERSHEY_SIMPLEX, scale, color, 1, imgproc::LINE_AA, false)
+	}
+}
+
+struct SvgCanvas {
+	out: String,
+}
+
+impl Canvas for SvgCanvas {
+	fn line(&mut self, from: (f64, f64), to: (f64, f64), color: Scalar, dashed: bool) -> Result<()> {
+		let _ = write!(
+			self.out,
+			r#"<line x1="{:.1}" y1="{:.1}" x2="{:.1}" y2="{:.1}" stroke="{}"{}/>"#,
+			from.0, from.1, to.0, to.1, svg_color(color), if dashed { r#" stroke-dasharray="4 4""# } else { "" },
+		);
+		Ok(())
+	}
+
+	fn fill_rect(&mut self, origin: (f64, f64), size: (f64, f64), color: Scalar) -> Result<()> {
+		let _ = write!(self.out, r#"<rect x="{:.1}" y="{:.1}" width="{:.1}" height="{:.1}" fill="{}"/>"#, origin.0, origin.1, size.0, size.1, svg_color(color));
+		Ok(())
+	}
+
+	fn dot(&mut self, center: (f64, f64), color: Scalar) -> Result<()> {
+		let _ = write!(self.out, r#"<circle cx="{:.1}" cy="{:.1}" r="2" fill="{}"/>"#, center.0, center.1, svg_color(color));
+		Ok(())
+	}
+
+	fn text(&mut self, pos: (f64, f64), text: &str, anchor: Anchor, color: Scalar) -> Result<()> {
+		if text.is_empty() {
+			return Ok(());
+		}
+		let anchor = match anchor {
+			Anchor::Start => "start",
+			Anchor::Middle => "middle",
+			Anchor::End => "end",
+		};
+		let _ = write!(
+			self.out,
+			r#"<text x="{:.1}" y="{:.1}" font-size="{}" text-anchor="{}" fill="{}">{}</text>"#,
+			pos.0, pos.1, FONT_PX, anchor, svg_color(color), svg_escape(text),
+		);
+		Ok(())
+	}
+}
@@ -0,0 +1,146 @@
+use crate::{
+	core::{self, CoordMap, Mat, Rect, Scalar, Size},
+	Error,
+	imgproc,
+	prelude::*,
+	Result,
+};
+
+/// Interpolation method of [MatResize::resized]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Interp {
+	Nearest,
+	Linear,
+	Cubic,
+	/// Pixel area relation, the preferred method for the downscaling
+	Area,
+	Lanczos4,
+	/// Bit-exact bilinear interpolation
+	#[cfg(not(ocvrs_opencv_branch_32))]
+	LinearExact,
+}
+
+impl Interp {
+	/// Returns the matching `imgproc::INTER_*` flag
+	pub fn flags(self) -> i32 {
+		match self {
+			Interp::Nearest => imgproc::INTER_NEAREST,
+			Interp::Linear => imgproc::INTER_LINEAR,
+			Interp::Cubic => imgproc::INTER_CUBIC,
+			Interp::Area => imgproc::INTER_AREA,
+			Interp::Lanczos4 => imgproc::INTER_LANCZOS4,
+			#[cfg(not(ocvrs_opencv_branch_32))]
+			Interp::LinearExact => imgproc::INTER_LINEAR_EXACT,
+		}
+	}
+}
+
+impl Default for Interp {
+	#[inline]
+	fn default() -> Self {
+		Interp::Linear
+	}
+}
+
+/// Requested output size of [MatResize::resized]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ResizeTarget {
+	Size(Size),
+	/// Scale factors along the x and y axes
+	Scale(f64, f64),
+}
+
+impl From<Size> for ResizeTarget {
+	#[inline]
+	fn from(s: Size) -> Self {
+		ResizeTarget::Size(s)
+	}
+}
+
+impl From<f64> for ResizeTarget {
+	/// Same scale factor for both axes
+	#[inline]
+	fn from(s: f64) -> Self {
+		ResizeTarget::Scale(s, s)
+	}
+}
+
+/// Fitting of the source image into the target size of [MatResize::resized]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Fit {
+	/// Stretches the image to exactly the target size
+	Exact,
+	/// Keeps the aspect ratio, the output is as large as possible but doesn't exceed the target size
+	Preserve,
+	/// Keeps the aspect ratio and pads the image with the color to the target size, the image is centered
+	Letterbox(Scalar),
+}
+
+/// Result of [MatResize::resized] and [MatResize::cropped]
+#[derive(Debug)]
+pub struct Resized {
+	pub mat: Mat,
+	/// Map from the source Mat coordinates into the `mat` coordinates
+	pub transform: CoordMap,
+}
+
+/// Resizing and cropping that keeps track of the applied coordinate mapping
+pub trait MatResize: MatTraitConst {
+	/// Resizes the Mat to the `target` size or scale fitting it according to `fit`
+	///
+	/// ```ignore
+	/// let input = frame.resized(Size::new(640, 640), Interp::Linear, Fit::Letterbox(Scalar::all(114.)))?;
+	/// // ... run the detector on input.mat
+	/// let rect = input.transform.invert_rect(detection);
+	/// ```
+	fn resized(&self, target: impl Into<ResizeTarget>, interp: Interp, fit: Fit) -> Result<Resized>;
+
+	/// Copies the `roi` region of the Mat
+	fn cropped(&self, roi: Rect) -> Result<Resized>;
+}
+
+impl MatResize for Mat {
+	fn resized(&self, target: impl Into<ResizeTarget>, interp: Interp, fit: Fit) -> Result<Resized> {
+		let src_size = self.size()?;
+		if src_size.width <= 0 || src_size.height <= 0 {
+			return Err(Error::new(core::StsBadArg, "Can't resize empty Mat"));
+		}
+		let (src_w, src_h) = (f64::from(src_size.width), f64::from(src_size.height));
+		let target_size = match target.into() {
+			ResizeTarget::Size(size) => size,
+			ResizeTarget::Scale(fx, fy) => Size::new((src_w * fx).round() as i32, (src_h * fy).round() as i32),
+		};
+		if target_size.width <= 0 || target_size.height <= 0 {
+			return Err(Error::new(core::StsBadArg, format!("Invalid target size: {:?}", target_size)));
+		}
+		let (target_w, target_h) = (f64::from(target_size.width), f64::from(target_size.height));
+		let resize_size = match fit {
+			Fit::Exact => target_size,
+			Fit::Preserve | Fit::Letterbox(_) => {
+				let scale = (target_w / src_w).min(target_h / src_h);
+				Size::new(((src_w * scale).round() as i32).max(1), ((src_h * scale).round() as i32).max(1))
+			}
+		};
+		let mut resized = Mat::default();
+		imgproc::resize(self, &mut resized, resize_size, 0., 0., interp.flags())?;
+		let mut transform = CoordMap::scale(f64::from(resize_size.width) / src_w, f64::from(resize_size.height) / src_h);
+		let mat = if let Fit::Letterbox(color) = fit {
+			let left = (target_size.width - resize_size.width) / 2;
+			let top = (target_size.height - resize_size.height) / 2;
+			let right = target_size.width - resize_size.width - left;
+			let bottom = target_size.height - resize_size.height - top;
+			transform.offset_x = f64::from(left);
+			transform.offset_y = f64::from(top);
+			let mut padded = Mat::default();
+			core::copy_make_border(&resized, &mut padded, top, bottom, left, right, core::BORDER_CONSTANT, color)?;
+			padded
+		} else {
+			resized
+		};
+		Ok(Resized { mat, transform })
+	}
+
+	fn cropped(&self, roi: Rect) -> Result<Resized> {
+		Ok(Resized { mat: Mat::roi(self, roi)?.try_clone()?, transform: CoordMap::crop(roi) })
+	}
+}
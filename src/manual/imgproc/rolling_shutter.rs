@@ -0,0 +1,183 @@
+//! Rolling shutter correction
+//!
+//! The rows of a rolling shutter sensor are read one after the other, the camera motion during the readout skews and
+//! wobbles the frame. The [Estimator] measures the image velocity in the horizontal bands of the frame with the sparse
+//! optical flow from the previous frame and [correct] moves every row to its position at the time the reference row was
+//! read:
+//! ```ignore
+//! let motion = imgproc::rolling_shutter::Estimator::new(0.8).estimate(&previous, &frame)?;
+//! let corrected = imgproc::rolling_shutter::correct(&frame, &motion)?;
+//! ```
+
+#[cfg(ocvrs_has_module_video)]
+use std::cmp::Ordering;
+
+use crate::{
+	core::{self, Mat, Point2f, Scalar},
+	Error,
+	imgproc,
+	prelude::*,
+	Result,
+};
+#[cfg(ocvrs_has_module_video)]
+use crate::{
+	core::{Size, TermCriteria, Vector},
+	video,
+};
+
+/// Removes the rolling shutter distortion of the frame
+///
+/// `per_row_motion` has an entry for every row of the `frame`, it's the displacement of the row content relative to
+/// its undistorted position: the pixel at `(x, y)` of the output is sampled at `(x, y) + per_row_motion[y]` of the
+/// `frame`. The uncovered areas replicate the border pixels.
+pub fn correct(frame: &Mat, per_row_motion: &[Point2f]) -> Result<Mat> {
+	let size = frame.size()?;
+	if per_row_motion.len() != size.height as usize {
+		return Err(Error::new(core::StsUnmatchedSizes, format!("Got motion for: {} rows, but the frame has: {} rows", per_row_motion.len(), size.height)));
+	}
+	let mut map_x = Mat::new_size_with_default(size, core::CV_32FC1, Scalar::default())?;
+	let mut map_y = Mat::new_size_with_default(size, core::CV_32FC1, Scalar::default())?;
+	for (y, motion) in per_row_motion.iter().enumerate() {
+		let row_x = map_x.at_row_mut::<f32>(y as i32)?;
+		let row_y = map_y.at_row_mut::<f32>(y as i32)?;
+		for (x, (map_x, map_y)) in row_x.iter_mut().zip(row_y).enumerate() {
+			*map_x = x as f32 + motion.x;
+			*map_y = y as f32 + motion.y;
+		}
+	}
+	let mut out = Mat::default();
+	imgproc::remap(frame, &mut out, &map_x, &map_y, imgproc::INTER_LINEAR, core::BORDER_REPLICATE, Scalar::default())?;
+	Ok(out)
+}
+
+/// Estimator of the per-row motion for the [correct]
+///
+/// The image velocity is assumed to be constant during the frame period inside of each band, it's measured as the
+/// median optical flow of the features tracked from the previous frame and interpolated between the band centers.
+#[cfg(ocvrs_has_module_video)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Estimator {
+	/// Readout time of the whole frame as a fraction of the frame period, in `(0; 1]`
+	pub readout: f64,
+	/// Number of horizontal bands with the independent velocity, `8` by default
+	pub bands: usize,
+	/// Maximum number of the tracked features, `1000` by default
+	pub max_corners: i32,
+	/// Row the frame is corrected to, `None` for the middle row
+	pub reference_row: Option<i32>,
+}
+
+#[cfg(ocvrs_has_module_video)]
+impl Estimator {
+	/// Creates the estimator for the sensor with the `readout` time as a fraction of the frame period, it's usually
+	/// between `0.5` and `0.9` for the phone cameras
+	pub fn new(readout: f64) -> Self {
+		Self { readout, bands: 8, max_corners: 1000, reference_row: None }
+	}
+
+	pub fn with_bands(mut self, bands: usize) -> Self {
+		self.bands = bands;
+		self
+	}
+
+	pub fn with_max_corners(mut self, max_corners: i32) -> Self {
+		self.max_corners = max_corners;
+		self
+	}
+
+	pub fn with_reference_row(mut self, reference_row: Option<i32>) -> Self {
+		self.reference_row = reference_row;
+		self
+	}
+
+	/// Estimates the per-row motion of the `frame` from its motion relative to the `previous` frame
+	///
+	/// Returns the zero motion when no features can be tracked.
+	pub fn estimate(&self, previous: &Mat, frame: &Mat) -> Result<Vec<Point2f>> {
+		if !(self.readout > 0. && self.readout <= 1.) {
+			return Err(Error::new(core::StsOutOfRange, format!("Readout must be in (0; 1], got: {}", self.readout)));
+		}
+		if self.bands == 0 {
+			return Err(Error::new(core::StsOutOfRange, "Number of bands must be positive"));
+		}
+		let size = frame.size()?;
+		if previous.size()? != size {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("Frames must have the same size, got: {:?} and {:?}", previous.size()?, size)));
+		}
+		let rows = size.height as usize;
+		let reference_row = self.reference_row.unwrap_or(size.height / 2);
+		if reference_row < 0 || reference_row >= size.height {
+			return Err(Error::new(core::StsOutOfRange, format!("Reference row: {} is outside of the frame with: {} rows", reference_row, size.height)));
+		}
+		let velocities = self.band_velocities(&to_gray(previous)?, &to_gray(frame)?)?;
+
+		let band_height = rows as f64 / self.bands as f64;
+		let velocity = |row: usize| {
+			let position = ((row as f64 + 0.5) / band_height - 0.5).max(0.).min((self.bands - 1) as f64);
+			let (i, t) = (position.floor() as usize, position.fract() as f32);
+			let next = velocities[(i + 1).min(self.bands - 1)];
+			velocities[i] * (1. - t) + next * t
+		};
+		// the time between the reads of the consecutive rows in frame periods
+		let dt = (self.readout / rows as f64) as f32;
+		let reference_row = reference_row as usize;
+		let mut out = vec![Point2f::default(); rows];
+		for row in reference_row + 1..rows {
+			out[row] = out[row - 1] + velocity(row - 1) * dt;
+		}
+		for row in (0..reference_row).rev() {
+			out[row] = out[row + 1] - velocity(row) * dt;
+		}
+		Ok(out)
+	}
+
+	/// Median optical flow in each band in pixels per frame, the bands without features get the median of all features
+	fn band_velocities(&self, previous: &Mat, frame: &Mat) -> Result<Vec<Point2f>> {
+		let mut corners = Vector::<Point2f>::new();
+		imgproc::good_features_to_track(previous, &mut corners, self.max_corners, 0.01, 8., &core::no_array(), 3, false, 0.04)?;
+		if corners.is_empty() {
+			return Ok(vec![Point2f::default(); self.bands]);
+		}
+		let mut tracked = Vector::<Point2f>::new();
+		let mut status = Vector::<u8>::new();
+		video::calc_optical_flow_pyr_lk(previous, frame, &corners, &mut tracked, &mut status, &mut core::no_array(), Size::new(21, 21), 3, TermCriteria::both(30, 0.01), 0, 1e-4)?;
+
+		let rows = frame.rows() as f32;
+		let mut samples = vec![vec![]; self.bands];
+		for ((corner, tracked), status) in corners.iter().zip(tracked.iter()).zip(status.iter()) {
+			if status == 0 || !(tracked.y >= 0. && tracked.y < rows) {
+				continue;
+			}
+			let band = ((tracked.y / rows * self.bands as f32) as usize).min(self.bands - 1);
+			samples[band].push(tracked - corner);
+		}
+		let mut all = samples.concat();
+		let fallback = median(&mut all).unwrap_or_default();
+		Ok(samples.iter_mut().map(|band| median(band).unwrap_or(fallback)).collect())
+	}
+}
+
+/// Component-wise median of the flow vectors
+#[cfg(ocvrs_has_module_video)]
+fn median(flow: &mut [Point2f]) -> Option<Point2f> {
+	if flow.is_empty() {
+		return None;
+	}
+	let middle = flow.len() / 2;
+	flow.sort_by(|a, b| a.x.partial_cmp(&b.x).unwrap_or(Ordering::Equal));
+	let x = flow[middle].x;
+	flow.sort_by(|a, b| a.y.partial_cmp(&b.y).unwrap_or(Ordering::Equal));
+	Some(Point2f::new(x, flow[middle].y))
+}
+
+#[cfg(ocvrs_has_module_video)]
+fn to_gray(image: &Mat) -> Result<Mat> {
+	match image.channels() {
+		1 => image.try_clone(),
+		channels => {
+			let mut gray = Mat::default();
+			imgproc::cvt_color(image, &mut gray, if channels == 4 { imgproc::COLOR_BGRA2GRAY } else { imgproc::COLOR_BGR2GRAY }, 0)?;
+			Ok(gray)
+		}
+	}
+}
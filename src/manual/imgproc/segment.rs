@@ -0,0 +1,149 @@
+//! Separation of the touching objects in a binary mask
+//!
+//! ```ignore
+//! let segmentation = imgproc::segment::watershed_markers(&mask)?;
+//! for region in &segmentation.regions {
+//!     println!("{}: {} px around {:?}", region.label, region.area, region.centroid);
+//! }
+//! ```
+
+use crate::{
+	core::{self, Mat, Point, Point2d, Rect, Scalar},
+	Error,
+	imgproc,
+	prelude::*,
+	Result,
+};
+
+/// Parameters of [watershed_markers_with_params]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WatershedParams {
+	/// Pixels with the distance to the background larger than this fraction of the maximum distance are the sure
+	/// foreground seeds, lower values merge more objects together
+	pub peak_ratio: f64,
+	/// Number of the `3x3` dilations of the mask that make up the sure background
+	pub background_dilations: i32,
+	/// Connectivity of the seed labelling, 4 or 8
+	pub connectivity: i32,
+}
+
+impl Default for WatershedParams {
+	fn default() -> Self {
+		Self {
+			peak_ratio: 0.7,
+			background_dilations: 3,
+			connectivity: 8,
+		}
+	}
+}
+
+/// Single object found by the segmentation
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Region {
+	/// Label of the region pixels in [Segmentation::labels], starting from `1`
+	pub label: i32,
+	/// Number of pixels
+	pub area: usize,
+	pub bounding_box: Rect,
+	pub centroid: Point2d,
+}
+
+/// Result of [watershed_markers]
+#[derive(Debug)]
+pub struct Segmentation {
+	/// `CV_32S` label image of the mask size, `0` is the background, `-1` marks the boundaries between the regions and
+	/// positive values are the region labels
+	pub labels: Mat,
+	/// Regions sorted by label
+	pub regions: Vec<Region>,
+}
+
+/// Splits the touching objects of the binary `CV_8UC1` `mask` using the default [WatershedParams]
+#[inline]
+pub fn watershed_markers(mask: &Mat) -> Result<Segmentation> {
+	watershed_markers_with_params(mask, &WatershedParams::default())
+}
+
+/// Splits the touching objects of the binary `CV_8UC1` `mask`
+///
+/// The seeds are the peaks of the distance transform of the mask, they are labelled with `connected_components()` and
+/// grown with `watershed()` into the unknown area between the seeds and the sure background.
+pub fn watershed_markers_with_params(mask: &Mat, params: &WatershedParams) -> Result<Segmentation> {
+	if mask.typ() != core::CV_8UC1 {
+		return Err(Error::new(core::StsUnsupportedFormat, format!("Mask must be CV_8UC1, but got type: {}", mask.typ())));
+	}
+	let mut binary = Mat::default();
+	imgproc::threshold(mask, &mut binary, 0., 255., imgproc::THRESH_BINARY)?;
+
+	let mut sure_bg = Mat::default();
+	let kernel = imgproc::get_structuring_element(imgproc::MORPH_RECT, core::Size::new(3, 3), Point::new(-1, -1))?;
+	imgproc::dilate(&binary, &mut sure_bg, &kernel, Point::new(-1, -1), params.background_dilations, core::BORDER_CONSTANT, imgproc::morphology_default_border_value()?)?;
+
+	let mut dist = Mat::default();
+	imgproc::distance_transform(&binary, &mut dist, imgproc::DIST_L2, imgproc::DIST_MASK_PRECISE, core::CV_32F)?;
+	let max_dist = core::min_max(&dist, &core::no_array())?.max;
+	let mut sure_fg = Mat::default();
+	imgproc::threshold(&dist, &mut sure_fg, params.peak_ratio * max_dist, 255., imgproc::THRESH_BINARY)?;
+	let mut sure_fg_u8 = Mat::default();
+	sure_fg.convert_to(&mut sure_fg_u8, core::CV_8U, 1., 0.)?;
+
+	let mut markers = Mat::default();
+	imgproc::connected_components(&sure_fg_u8, &mut markers, params.connectivity, core::CV_32S)?;
+	// shift the labels so the sure background is 1 and the unknown area is 0
+	for row in 0..markers.rows() {
+		let (bg_row, fg_row) = (sure_bg.at_row::<u8>(row)?.to_vec(), sure_fg_u8.at_row::<u8>(row)?.to_vec());
+		for ((marker, bg), fg) in markers.at_row_mut::<i32>(row)?.iter_mut().zip(bg_row).zip(fg_row) {
+			*marker = if fg != 0 {
+				*marker + 1
+			} else if bg == 0 {
+				1
+			} else {
+				0
+			};
+		}
+	}
+
+	// watershed marks the outermost pixels as boundaries, so run it on the image padded with the background
+	let mut image = Mat::default();
+	imgproc::cvt_color(&binary, &mut image, imgproc::COLOR_GRAY2BGR, 0)?;
+	let mut padded_image = Mat::default();
+	core::copy_make_border(&image, &mut padded_image, 1, 1, 1, 1, core::BORDER_CONSTANT, Scalar::all(0.))?;
+	let mut padded_markers = Mat::default();
+	core::copy_make_border(&markers, &mut padded_markers, 1, 1, 1, 1, core::BORDER_CONSTANT, Scalar::all(1.))?;
+	imgproc::watershed(&padded_image, &mut padded_markers)?;
+	let mut markers = Mat::roi(&padded_markers, Rect::new(1, 1, mask.cols(), mask.rows()))?.try_clone()?;
+
+	let mut regions = Vec::<Region>::new();
+	let mut sums = Vec::<(f64, f64)>::new();
+	for row in 0..markers.rows() {
+		for (col, label) in markers.at_row_mut::<i32>(row)?.iter_mut().enumerate() {
+			if *label > 0 {
+				*label -= 1;
+			}
+			if *label > 0 {
+				let idx = *label as usize - 1;
+				if idx >= regions.len() {
+					regions.resize_with(idx + 1, || Region { label: 0, area: 0, bounding_box: Rect::default(), centroid: Point2d::default() });
+					sums.resize(idx + 1, (0., 0.));
+				}
+				let pixel = Rect::new(col as i32, row, 1, 1);
+				let region = &mut regions[idx];
+				region.bounding_box = if region.area == 0 { pixel } else { region.bounding_box | pixel };
+				region.area += 1;
+				sums[idx].0 += col as f64;
+				sums[idx].1 += f64::from(row);
+			}
+		}
+	}
+	let regions = regions.into_iter()
+		.zip(sums)
+		.enumerate()
+		.filter(|(_, (region, _))| region.area > 0)
+		.map(|(idx, (region, (sum_x, sum_y)))| Region {
+			label: idx as i32 + 1,
+			centroid: Point2d::new(sum_x / region.area as f64, sum_y / region.area as f64),
+			..region
+		})
+		.collect();
+	Ok(Segmentation { labels: markers, regions })
+}
@@ -0,0 +1,238 @@
+use std::cmp::Ordering;
+
+use crate::{
+	core::{self, Mat, Point2f, Rect, Scalar, Size},
+	Error,
+	imgproc,
+	prelude::*,
+	Result,
+};
+
+/// Parameters of the [match_template_multi] search
+#[derive(Debug)]
+pub struct TemplateSearch {
+	/// One of `imgproc::TM_*` methods, for `TM_SQDIFF` and `TM_SQDIFF_NORMED` lower values are better
+	pub method: i32,
+	/// Template scale factors to try
+	pub scales: Vec<f64>,
+	/// Template rotation angles in degrees (counter-clockwise) to try
+	pub angles: Vec<f64>,
+	/// Matches with the score worse than this value are dropped
+	pub threshold: f64,
+	/// Maximum intersection over union of two matches, the worse one of the more overlapping matches is suppressed
+	pub nms_threshold: f64,
+	/// Optional mask of the template pixels, must have the same size as the template
+	pub mask: Option<Mat>,
+}
+
+impl Default for TemplateSearch {
+	fn default() -> Self {
+		Self {
+			method: imgproc::TM_CCOEFF_NORMED,
+			scales: vec![1.],
+			angles: vec![0.],
+			threshold: 0.8,
+			nms_threshold: 0.3,
+			mask: None,
+		}
+	}
+}
+
+impl TemplateSearch {
+	#[inline]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	#[inline]
+	pub fn with_method(mut self, method: i32) -> Self {
+		self.method = method;
+		self
+	}
+
+	/// Searches the scales from `min` to `max` inclusive with the specified `step`
+	pub fn with_scale_range(mut self, min: f64, max: f64, step: f64) -> Self {
+		self.scales = float_range(min, max, step);
+		self
+	}
+
+	/// Searches the angles (in degrees) from `min` to `max` inclusive with the specified `step`
+	pub fn with_angle_range(mut self, min: f64, max: f64, step: f64) -> Self {
+		self.angles = float_range(min, max, step);
+		self
+	}
+
+	#[inline]
+	pub fn with_threshold(mut self, threshold: f64) -> Self {
+		self.threshold = threshold;
+		self
+	}
+
+	#[inline]
+	pub fn with_nms_threshold(mut self, nms_threshold: f64) -> Self {
+		self.nms_threshold = nms_threshold;
+		self
+	}
+
+	#[inline]
+	pub fn with_mask(mut self, mask: Mat) -> Self {
+		self.mask = Some(mask);
+		self
+	}
+
+	fn lower_is_better(&self) -> bool {
+		self.method == imgproc::TM_SQDIFF || self.method == imgproc::TM_SQDIFF_NORMED
+	}
+}
+
+/// Single match found by [match_template_multi]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TemplateMatch {
+	/// Bounding box of the scaled and rotated template in the image
+	pub rect: Rect,
+	/// Center of the matched template in the image
+	pub center: Point2f,
+	pub score: f64,
+	pub scale: f64,
+	/// Rotation angle in degrees
+	pub angle: f64,
+}
+
+/// Finds all occurrences of `templ` in `image` over the ranges of scales and rotations
+///
+/// Runs `match_template()` for every combination of `search.scales` and `search.angles`, collects the local extrema of
+/// the result that pass `search.threshold` and applies the non-maximum suppression to all of them. The matches are
+/// returned sorted from the best to the worst score. Rotated templates are matched with a mask covering only the
+/// template pixels, so the method must support masks (`TM_SQDIFF` and `TM_CCORR_NORMED` on OpenCV 3.x, all methods
+/// starting from 4.x).
+pub fn match_template_multi(image: &Mat, templ: &Mat, search: &TemplateSearch) -> Result<Vec<TemplateMatch>> {
+	if let Some(mask) = &search.mask {
+		if mask.size()? != templ.size()? {
+			return Err(Error::new(core::StsUnmatchedSizes, "Template mask must have the same size as the template"));
+		}
+	}
+	let image_size = image.size()?;
+	let lower_is_better = search.lower_is_better();
+	let mut candidates = vec![];
+	for &scale in &search.scales {
+		let scaled_size = Size::new((f64::from(templ.cols()) * scale).round() as i32, (f64::from(templ.rows()) * scale).round() as i32);
+		if scaled_size.width < 1 || scaled_size.height < 1 {
+			continue;
+		}
+		let mut scaled = Mat::default();
+		imgproc::resize(templ, &mut scaled, scaled_size, 0., 0., imgproc::INTER_LINEAR)?;
+		let scaled_mask = match &search.mask {
+			Some(mask) => {
+				let mut scaled_mask = Mat::default();
+				imgproc::resize(mask, &mut scaled_mask, scaled_size, 0., 0., imgproc::INTER_NEAREST)?;
+				Some(scaled_mask)
+			}
+			None => None,
+		};
+		for &angle in &search.angles {
+			let (rotated, rotated_mask) = if angle == 0. {
+				(scaled.try_clone()?, scaled_mask.as_ref().map(|m| m.try_clone()).transpose()?)
+			} else {
+				let full_mask = match &scaled_mask {
+					Some(mask) => mask.try_clone()?,
+					None => Mat::new_size_with_default(scaled_size, core::CV_8UC1, Scalar::all(255.))?,
+				};
+				(rotate(&scaled, angle, imgproc::INTER_LINEAR)?, Some(rotate(&full_mask, angle, imgproc::INTER_NEAREST)?))
+			};
+			let rotated_size = rotated.size()?;
+			if rotated_size.width > image_size.width || rotated_size.height > image_size.height {
+				continue;
+			}
+			let mut result = Mat::default();
+			match &rotated_mask {
+				Some(mask) => imgproc::match_template(image, &rotated, &mut result, search.method, mask)?,
+				None => imgproc::match_template(image, &rotated, &mut result, search.method, &core::no_array())?,
+			}
+			for (loc, score) in local_extrema(&result, search.threshold, lower_is_better)? {
+				candidates.push(TemplateMatch {
+					rect: Rect::new(loc.0, loc.1, rotated_size.width, rotated_size.height),
+					center: Point2f::new(loc.0 as f32 + rotated_size.width as f32 / 2., loc.1 as f32 + rotated_size.height as f32 / 2.),
+					score,
+					scale,
+					angle,
+				});
+			}
+		}
+	}
+	// scores are finite, non-finite values are skipped by local_extrema()
+	if lower_is_better {
+		candidates.sort_by(|a, b| a.score.partial_cmp(&b.score).unwrap_or(Ordering::Equal));
+	} else {
+		candidates.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(Ordering::Equal));
+	}
+	let mut out: Vec<TemplateMatch> = Vec::new();
+	for candidate in candidates {
+		if out.iter().all(|m| iou(&m.rect, &candidate.rect) <= search.nms_threshold) {
+			out.push(candidate);
+		}
+	}
+	Ok(out)
+}
+
+/// Rotates the image around its center expanding the canvas to fit the whole rotated image
+fn rotate(src: &Mat, angle: f64, interpolation: i32) -> Result<Mat> {
+	let (w, h) = (f64::from(src.cols()), f64::from(src.rows()));
+	let (sin, cos) = angle.to_radians().sin_cos();
+	let (rw, rh) = ((w * cos.abs() + h * sin.abs()).ceil(), (w * sin.abs() + h * cos.abs()).ceil());
+	let mut m = imgproc::get_rotation_matrix_2d(Point2f::new((w / 2.) as f32, (h / 2.) as f32), angle, 1.)?;
+	*m.at_2d_mut::<f64>(0, 2)? += (rw - w) / 2.;
+	*m.at_2d_mut::<f64>(1, 2)? += (rh - h) / 2.;
+	let mut out = Mat::default();
+	imgproc::warp_affine(src, &mut out, &m, Size::new(rw as i32, rh as i32), interpolation, core::BORDER_CONSTANT, Scalar::default())?;
+	Ok(out)
+}
+
+/// Returns the locations and values of the `3x3` local extrema of the `CV_32F` `result` that pass the `threshold`
+fn local_extrema(result: &Mat, threshold: f64, lower_is_better: bool) -> Result<Vec<((i32, i32), f64)>> {
+	let (rows, cols) = (result.rows(), result.cols());
+	let better = |a: f32, b: f32| if lower_is_better { a < b } else { a > b };
+	let mut out = vec![];
+	for y in 0..rows {
+		let row = result.at_row::<f32>(y)?;
+		for (x, &v) in row.iter().enumerate() {
+			let x = x as i32;
+			if !v.is_finite() || better(threshold as f32, v) {
+				continue;
+			}
+			let mut is_extremum = true;
+			'neighbors: for ny in (y - 1).max(0)..=(y + 1).min(rows - 1) {
+				let neighbor_row = result.at_row::<f32>(ny)?;
+				for nx in (x - 1).max(0)..=(x + 1).min(cols - 1) {
+					let n = neighbor_row[nx as usize];
+					// ties are resolved in favor of the first pixel in the row-major order
+					if n.is_finite() && (better(n, v) || (n == v && (ny, nx) < (y, x))) {
+						is_extremum = false;
+						break 'neighbors;
+					}
+				}
+			}
+			if is_extremum {
+				out.push(((x, y), f64::from(v)));
+			}
+		}
+	}
+	Ok(out)
+}
+
+fn iou(a: &Rect, b: &Rect) -> f64 {
+	let intersection = f64::from((*a & *b).area());
+	let union = f64::from(a.area()) + f64::from(b.area()) - intersection;
+	if union > 0. {
+		intersection / union
+	} else {
+		0.
+	}
+}
+
+fn float_range(min: f64, max: f64, step: f64) -> Vec<f64> {
+	if step <= 0. || max < min {
+		return vec![min];
+	}
+	let count = ((max - min) / step + 1e-9).floor() as usize + 1;
+	(0..count).map(|i| min + i as f64 * step).collect()
+}
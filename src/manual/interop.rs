@@ -0,0 +1,4 @@
+pub mod messages;
+pub mod ros;
+#[cfg(feature = "wgpu-interop")]
+pub mod texture;
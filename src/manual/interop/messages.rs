@@ -0,0 +1,271 @@
+//! Protocol Buffers encoding of the common results (detections, keypoints, poses, point clouds)
+//!
+//! The messages are encoded in the protobuf wire format according to [PROTO_SCHEMA] without any code generation on this
+//! side, the receiving service generates its bindings from the schema (e.g. to send the results over gRPC):
+//! ```no_run
+//! # use opencv::{core::{Rect2f, Size}, interop::messages::{Detection, Detections, Message}};
+//! let detections = Detections {
+//!     detections: vec![Detection { rect: Rect2f::new(10., 20., 50., 80.), score: 0.9, class_id: 0, label: "person".to_string() }],
+//!     image_size: Size::new(640, 480),
+//!     timestamp_us: 0,
+//! };
+//! let bytes = detections.encode_to_vec();
+//! ```
+
+use crate::{
+	core::{self, KeyPoint, Mat, Point3d, Rect2f, Size},
+	Error,
+	prelude::*,
+	Result,
+};
+
+/// Contents of the `.proto` file describing all messages of this module
+pub const PROTO_SCHEMA: &str = include_str!("messages.proto");
+
+const WIRE_VARINT: u32 = 0;
+const WIRE_LEN: u32 = 2;
+const WIRE_FIXED32: u32 = 5;
+
+/// Value that can be encoded as a protobuf message
+pub trait Message {
+	/// Appends the encoded fields of the message to `buf`
+	fn encode(&self, buf: &mut Vec<u8>);
+
+	fn encode_to_vec(&self) -> Vec<u8> {
+		let mut out = Vec::new();
+		self.encode(&mut out);
+		out
+	}
+}
+
+fn write_varint(buf: &mut Vec<u8>, mut value: u64) {
+	while value >= 0x80 {
+		buf.push((value as u8) | 0x80);
+		value >>= 7;
+	}
+	buf.push(value as u8);
+}
+
+fn write_tag(buf: &mut Vec<u8>, field: u32, wire_type: u32) {
+	write_varint(buf, u64::from(field << 3 | wire_type));
+}
+
+// scalar fields with the default value are omitted as in proto3
+
+fn write_float(buf: &mut Vec<u8>, field: u32, value: f32) {
+	if value.to_bits() != 0 {
+		write_tag(buf, field, WIRE_FIXED32);
+		buf.extend_from_slice(&value.to_le_bytes());
+	}
+}
+
+fn write_int(buf: &mut Vec<u8>, field: u32, value: i64) {
+	if value != 0 {
+		write_tag(buf, field, WIRE_VARINT);
+		// negative values are sign-extended to 10 bytes
+		write_varint(buf, value as u64);
+	}
+}
+
+fn write_bytes(buf: &mut Vec<u8>, field: u32, value: &[u8]) {
+	write_tag(buf, field, WIRE_LEN);
+	write_varint(buf, value.len() as u64);
+	buf.extend_from_slice(value);
+}
+
+fn write_string(buf: &mut Vec<u8>, field: u32, value: &str) {
+	if !value.is_empty() {
+		write_bytes(buf, field, value.as_bytes());
+	}
+}
+
+fn write_message(buf: &mut Vec<u8>, field: u32, value: &impl Message) {
+	write_bytes(buf, field, &value.encode_to_vec());
+}
+
+fn write_packed_doubles(buf: &mut Vec<u8>, field: u32, values: impl ExactSizeIterator<Item=f64>) {
+	if values.len() > 0 {
+		write_tag(buf, field, WIRE_LEN);
+		write_varint(buf, values.len() as u64 * 8);
+		values.for_each(|v| buf.extend_from_slice(&v.to_le_bytes()));
+	}
+}
+
+fn write_packed_varints(buf: &mut Vec<u8>, field: u32, values: &[u32]) {
+	if !values.is_empty() {
+		let mut packed = Vec::with_capacity(values.len() * 4);
+		values.iter().for_each(|&v| write_varint(&mut packed, u64::from(v)));
+		write_bytes(buf, field, &packed);
+	}
+}
+
+impl Message for Rect2f {
+	fn encode(&self, buf: &mut Vec<u8>) {
+		write_float(buf, 1, self.x);
+		write_float(buf, 2, self.y);
+		write_float(buf, 3, self.width);
+		write_float(buf, 4, self.height);
+	}
+}
+
+/// Single detected object
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Detection {
+	pub rect: Rect2f,
+	pub score: f32,
+	pub class_id: i32,
+	pub label: String,
+}
+
+impl Message for Detection {
+	fn encode(&self, buf: &mut Vec<u8>) {
+		write_message(buf, 1, &self.rect);
+		write_float(buf, 2, self.score);
+		write_int(buf, 3, i64::from(self.class_id));
+		write_string(buf, 4, &self.label);
+	}
+}
+
+/// Detections in a single frame
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Detections {
+	pub detections: Vec<Detection>,
+	pub image_size: Size,
+	/// Capture time in microseconds since Unix epoch
+	pub timestamp_us: i64,
+}
+
+impl Message for Detections {
+	fn encode(&self, buf: &mut Vec<u8>) {
+		for detection in &self.detections {
+			write_message(buf, 1, detection);
+		}
+		write_int(buf, 2, i64::from(self.image_size.width));
+		write_int(buf, 3, i64::from(self.image_size.height));
+		write_int(buf, 4, self.timestamp_us);
+	}
+}
+
+impl Message for KeyPoint {
+	fn encode(&self, buf: &mut Vec<u8>) {
+		write_float(buf, 1, self.pt.x);
+		write_float(buf, 2, self.pt.y);
+		write_float(buf, 3, self.size);
+		write_float(buf, 4, self.angle);
+		write_float(buf, 5, self.response);
+		write_int(buf, 6, i64::from(self.octave));
+		write_int(buf, 7, i64::from(self.class_id));
+	}
+}
+
+/// Keypoints of a single image
+#[derive(Clone, Debug, Default)]
+pub struct KeyPoints {
+	pub keypoints: Vec<KeyPoint>,
+}
+
+impl Message for KeyPoints {
+	fn encode(&self, buf: &mut Vec<u8>) {
+		for keypoint in &self.keypoints {
+			write_message(buf, 1, keypoint);
+		}
+	}
+}
+
+impl From<core::Vector<KeyPoint>> for KeyPoints {
+	#[inline]
+	fn from(s: core::Vector<KeyPoint>) -> Self {
+		Self { keypoints: s.to_vec() }
+	}
+}
+
+/// Rigid transform `x_camera = rotation * x_world + translation`
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Pose {
+	/// `3x3` rotation matrix in row-major order
+	pub rotation: [f64; 9],
+	pub translation: [f64; 3],
+}
+
+impl Pose {
+	/// Creates the pose from the `3x3` rotation matrix and `3x1` or `1x3` translation vector of any floating point type
+	pub fn from_mats(r: &Mat, t: &Mat) -> Result<Self> {
+		let mut out = Self::default();
+		copy_f64(r, &mut out.rotation, "Rotation matrix")?;
+		copy_f64(t, &mut out.translation, "Translation vector")?;
+		Ok(out)
+	}
+}
+
+fn copy_f64(src: &Mat, dst: &mut [f64], name: &str) -> Result<()> {
+	if src.total() != dst.len() || src.channels() != 1 {
+		return Err(Error::new(core::StsBadSize, format!("{} must have {} single channel elements, but it has: {}", name, dst.len(), src.total())));
+	}
+	let mut converted = Mat::default();
+	src.convert_to(&mut converted, f64::typ(), 1., 0.)?;
+	dst.copy_from_slice(converted.data_typed::<f64>()?);
+	Ok(())
+}
+
+#[cfg(ocvrs_has_module_sfm)]
+impl std::convert::TryFrom<&crate::sfm::CameraPose> for Pose {
+	type Error = Error;
+
+	#[inline]
+	fn try_from(s: &crate::sfm::CameraPose) -> Result<Self> {
+		Self::from_mats(&s.r, &s.t)
+	}
+}
+
+impl Message for Pose {
+	fn encode(&self, buf: &mut Vec<u8>) {
+		write_packed_doubles(buf, 1, self.rotation.iter().copied());
+		write_packed_doubles(buf, 2, self.translation.iter().copied());
+	}
+}
+
+/// 3D points with the optional colors, e.g. the `sfm::reconstruct()` output
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PointCloud {
+	pub points: Vec<Point3d>,
+	/// Empty or `0xRRGGBB` color for every point
+	pub colors: Vec<u32>,
+}
+
+impl PointCloud {
+	/// Sets the colors, fails if their number doesn't match the number of points
+	pub fn with_colors(mut self, colors: Vec<u32>) -> Result<Self> {
+		if colors.len() != self.points.len() {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("Number of colors: {} doesn't match the number of points: {}", colors.len(), self.points.len())));
+		}
+		self.colors = colors;
+		Ok(self)
+	}
+}
+
+impl From<Vec<Point3d>> for PointCloud {
+	#[inline]
+	fn from(points: Vec<Point3d>) -> Self {
+		Self { points, colors: vec![] }
+	}
+}
+
+#[cfg(ocvrs_has_module_sfm)]
+impl std::convert::TryFrom<&Mat> for PointCloud {
+	type Error = Error;
+
+	/// Reads the points in any layout supported by `sfm::cloud::points_from_mat()`
+	#[inline]
+	fn try_from(s: &Mat) -> Result<Self> {
+		crate::sfm::cloud::points_from_mat(s).map(Self::from)
+	}
+}
+
+impl Message for PointCloud {
+	fn encode(&self, buf: &mut Vec<u8>) {
+		let mut coords = Vec::with_capacity(self.points.len() * 3);
+		self.points.iter().for_each(|p| coords.extend_from_slice(&[p.x, p.y, p.z]));
+		write_packed_doubles(buf, 1, coords.into_iter());
+		write_packed_varints(buf, 2, &self.colors);
+	}
+}
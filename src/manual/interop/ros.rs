@@ -0,0 +1,393 @@
+//! Conversions between `Mat` and the ROS 2 `sensor_msgs/Image` and `sensor_msgs/CameraInfo` messages
+//!
+//! The message types are generated by the ROS client library of choice (`r2r`, `rclrs`), so this module works with the
+//! mirror structs [RosImage] and [RosCameraInfo] having the same fields. [ros_conversions](crate::ros_conversions)
+//! generates the functions converting the actual message types:
+//! ```ignore
+//! opencv::ros_conversions! {
+//!     pub mod ros_cv {
+//!         image: r2r::sensor_msgs::msg::Image,
+//!         camera_info: r2r::sensor_msgs::msg::CameraInfo,
+//!     }
+//! }
+//!
+//! let frame = ros_cv::image_to_mat(&msg)?;
+//! let out = ros_cv::mat_to_image(&frame)?;
+//! ```
+
+use std::convert::TryFrom;
+
+use crate::{
+	core::{self, Mat, Size},
+	Error,
+	prelude::*,
+	Result,
+};
+
+/// Mirror of `sensor_msgs/Image` without the header
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RosImage {
+	pub height: u32,
+	pub width: u32,
+	pub encoding: String,
+	pub is_bigendian: u8,
+	/// Row length in bytes
+	pub step: u32,
+	pub data: Vec<u8>,
+}
+
+impl RosImage {
+	/// Copies the `Mat` into the image with the encoding derived from its type, see [mat_type_to_encoding]
+	pub fn from_mat(mat: &Mat) -> Result<Self> {
+		Self::from_mat_with_encoding(mat, &mat_type_to_encoding(mat.typ())?)
+	}
+
+	/// Copies the `Mat` into the image with the `encoding`, that must match the `Mat` type (e.g. `rgb8` for `CV_8UC3`)
+	pub fn from_mat_with_encoding(mat: &Mat, encoding: &str) -> Result<Self> {
+		let typ = encoding_to_mat_type(encoding)?;
+		if typ != mat.typ() || mat.dims() > 2 {
+			return Err(Error::new(core::StsUnmatchedFormats, format!("Encoding: {} doesn't match the Mat type: {} with {} dims", encoding, mat.typ(), mat.dims())));
+		}
+		let continuous;
+		let mat = if mat.is_continuous() {
+			mat
+		} else {
+			continuous = mat.try_clone()?;
+			&continuous
+		};
+		let step = mat.cols() as usize * mat.elem_size()?;
+		Ok(Self {
+			height: mat.rows() as u32,
+			width: mat.cols() as u32,
+			encoding: encoding.to_string(),
+			is_bigendian: u8::from(cfg!(target_endian = "big")),
+			step: u32::try_from(step).map_err(|_| Error::new(core::StsOutOfRange, "Image row is too long"))?,
+			data: if mat.empty() { vec![] } else { mat.data_bytes()?.to_vec() },
+		})
+	}
+
+	/// Copies the image into a new `Mat`, the rows padding is removed and the byte order is converted to the native one
+	pub fn to_mat(&self) -> Result<Mat> {
+		image_to_mat(self.height, self.width, &self.encoding, self.is_bigendian != 0, self.step, &self.data)
+	}
+}
+
+/// Copies the `sensor_msgs/Image` fields into a new `Mat`, see [RosImage::to_mat]
+pub fn image_to_mat(height: u32, width: u32, encoding: &str, is_bigendian: bool, step: u32, data: &[u8]) -> Result<Mat> {
+	let typ = encoding_to_mat_type(encoding)?;
+	let rows = i32::try_from(height).map_err(|_| Error::new(core::StsOutOfRange, format!("Image height is too large: {}", height)))?;
+	let cols = i32::try_from(width).map_err(|_| Error::new(core::StsOutOfRange, format!("Image width is too large: {}", width)))?;
+	let mut out = unsafe { Mat::new_rows_cols(rows, cols, typ) }?;
+	if out.empty() {
+		return Ok(out);
+	}
+	let row_len = cols as usize * out.elem_size()?;
+	let step = step as usize;
+	if step < row_len || data.len() < step * (rows as usize - 1) + row_len {
+		return Err(Error::new(core::StsBadSize, format!("Image data of {} bytes with step: {} is too small for {}x{} {}", data.len(), step, width, height, encoding)));
+	}
+	let swap = is_bigendian != cfg!(target_endian = "big");
+	let elem_size1 = out.elem_size1();
+	let dst = out.data_bytes_mut()?;
+	for (dst_row, src_row) in dst.chunks_exact_mut(row_len).zip(data.chunks(step)) {
+		dst_row.copy_from_slice(&src_row[..row_len]);
+		if swap && elem_size1 > 1 {
+			dst_row.chunks_exact_mut(elem_size1).for_each(|elem| elem.reverse());
+		}
+	}
+	Ok(out)
+}
+
+/// Returns the `Mat` type for the `sensor_msgs/image_encodings` value
+///
+/// Color encodings keep their channel order (`rgb8` is `CV_8UC3` with red first), Bayer encodings are single channel.
+pub fn encoding_to_mat_type(encoding: &str) -> Result<i32> {
+	let typ = match encoding {
+		"mono8" => core::CV_8UC1,
+		"mono16" => core::CV_16UC1,
+		"rgb8" | "bgr8" => core::CV_8UC3,
+		"rgba8" | "bgra8" => core::CV_8UC4,
+		"rgb16" | "bgr16" => core::CV_16UC3,
+		"rgba16" | "bgra16" => core::CV_16UC4,
+		"yuv422" | "uyvy" | "yuyv" | "yuv422_yuy2" => core::CV_8UC2,
+		_ if encoding.starts_with("bayer_") && encoding.ends_with('8') => core::CV_8UC1,
+		_ if encoding.starts_with("bayer_") && encoding.ends_with("16") => core::CV_16UC1,
+		_ => {
+			let invalid = || Error::new(core::StsUnsupportedFormat, format!("Unsupported image encoding: {}", encoding));
+			// generic encodings like 8UC3 or 32FC1
+			let c_pos = encoding.find('C').ok_or_else(invalid)?;
+			let depth = match &encoding[..c_pos] {
+				"8U" => core::CV_8U,
+				"8S" => core::CV_8S,
+				"16U" => core::CV_16U,
+				"16S" => core::CV_16S,
+				"32S" => core::CV_32S,
+				"32F" => core::CV_32F,
+				"64F" => core::CV_64F,
+				_ => return Err(invalid()),
+			};
+			let channels = encoding[c_pos + 1..].parse::<i32>().map_err(|_| invalid())?;
+			if !(1..=4).contains(&channels) {
+				return Err(invalid());
+			}
+			core::CV_MAKETYPE(depth, channels)
+		}
+	};
+	Ok(typ)
+}
+
+/// Returns the encoding for the `Mat` type: `mono8`, `mono16`, `bgr8` and `bgra8` (OpenCV channel order) for the
+/// corresponding types and the generic encoding like `32FC1` for the rest
+pub fn mat_type_to_encoding(typ: i32) -> Result<String> {
+	Ok(match typ {
+		core::CV_8UC1 => "mono8".to_string(),
+		core::CV_16UC1 => "mono16".to_string(),
+		core::CV_8UC3 => "bgr8".to_string(),
+		core::CV_8UC4 => "bgra8".to_string(),
+		_ => {
+			let depth = match core::CV_MAT_DEPTH(typ) {
+				core::CV_8U => "8U",
+				core::CV_8S => "8S",
+				core::CV_16U => "16U",
+				core::CV_16S => "16S",
+				core::CV_32S => "32S",
+				core::CV_32F => "32F",
+				core::CV_64F => "64F",
+				depth => return Err(Error::new(core::StsUnsupportedFormat, format!("Unsupported Mat depth for ROS image: {}", depth))),
+			};
+			format!("{}C{}", depth, core::CV_MAT_CN(typ))
+		}
+	})
+}
+
+/// Mirror of `sensor_msgs/CameraInfo` without the header and region of interest
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RosCameraInfo {
+	pub height: u32,
+	pub width: u32,
+	/// `plumb_bob` (`k1, k2, p1, p2, k3`), `rational_polynomial` (8 coefficients) or `equidistant`
+	pub distortion_model: String,
+	pub d: Vec<f64>,
+	/// `3x3` camera matrix in row-major order
+	pub k: [f64; 9],
+	/// `3x3` rectification matrix in row-major order
+	pub r: [f64; 9],
+	/// `3x4` projection matrix in row-major order
+	pub p: [f64; 12],
+	pub binning_x: u32,
+	pub binning_y: u32,
+}
+
+impl RosCameraInfo {
+	/// Creates the info from the OpenCV calibration (e.g. `calib3d::calibrate_camera()` output) with the identity
+	/// rectification and `p = [k | 0]`
+	pub fn from_calibration(size: Size, camera_matrix: &Mat, dist_coeffs: &Mat) -> Result<Self> {
+		let k = mat_values(camera_matrix, "Camera matrix")?;
+		let k = <[f64; 9]>::try_from(k.as_slice())
+			.map_err(|_| Error::new(core::StsBadSize, "Camera matrix must be 3x3"))?;
+		let d = mat_values(dist_coeffs, "Distortion coefficients")?;
+		let distortion_model = match d.len() {
+			0..=5 => "plumb_bob",
+			8 => "rational_polynomial",
+			len => return Err(Error::new(core::StsBadSize, format!("Unsupported number of distortion coefficients: {}", len))),
+		};
+		let mut p = [0.; 12];
+		for row in 0..3 {
+			p[row * 4..row * 4 + 3].copy_from_slice(&k[row * 3..row * 3 + 3]);
+		}
+		Ok(Self {
+			height: size.height as u32,
+			width: size.width as u32,
+			distortion_model: distortion_model.to_string(),
+			d,
+			k,
+			r: [1., 0., 0., 0., 1., 0., 0., 0., 1.],
+			p,
+			binning_x: 0,
+			binning_y: 0,
+		})
+	}
+
+	/// Returns the `3x3` `CV_64F` camera matrix
+	pub fn camera_matrix(&self) -> Result<Mat> {
+		Mat::from_slice(&self.k)?.reshape(1, 3)?.try_clone()
+	}
+
+	/// Returns the `1xN` `CV_64F` distortion coefficients in OpenCV order
+	///
+	/// `plumb_bob` and `rational_polynomial` coefficients are already in OpenCV order, `equidistant` ones are for the
+	/// `fisheye` camera model.
+	#[inline]
+	pub fn dist_coeffs(&self) -> Result<Mat> {
+		Mat::from_slice(&self.d)
+	}
+
+	#[inline]
+	pub fn image_size(&self) -> Size {
+		Size::new(self.width as i32, self.height as i32)
+	}
+}
+
+fn mat_values(mat: &Mat, name: &str) -> Result<Vec<f64>> {
+	if mat.empty() {
+		return Ok(vec![]);
+	}
+	if mat.channels() != 1 {
+		return Err(Error::new(core::StsBadArg, format!("{} must be single channel", name)));
+	}
+	let mut converted = Mat::default();
+	mat.convert_to(&mut converted, f64::typ(), 1., 0.)?;
+	let converted = if converted.is_continuous() { converted } else { converted.try_clone()? };
+	Ok(converted.data_typed::<f64>()?.to_vec())
+}
+
+#[cfg(ocvrs_has_module_sfm)]
+impl TryFrom<&RosCameraInfo> for crate::sfm::libmv_CameraIntrinsicsOptions {
+	type Error = Error;
+
+	/// Supports the `plumb_bob` distortion model mapped to `SFM_DISTORTION_MODEL_POLYNOMIAL`
+	fn try_from(s: &RosCameraInfo) -> Result<Self> {
+		if s.distortion_model != "plumb_bob" && !s.d.is_empty() {
+			return Err(Error::new(core::StsNotImplemented, format!("Distortion model: {} can't be converted to libmv intrinsics, only plumb_bob is supported", s.distortion_model)));
+		}
+		let d = |i: usize| s.d.get(i).copied().unwrap_or(0.);
+		Ok(Self {
+			distortion_model: crate::sfm::SFM_DISTORTION_MODEL_POLYNOMIAL,
+			image_width: s.width as i32,
+			image_height: s.height as i32,
+			focal_length_x: s.k[0],
+			focal_length_y: s.k[4],
+			principal_point_x: s.k[2],
+			principal_point_y: s.k[5],
+			polynomial_k1: d(0),
+			polynomial_k2: d(1),
+			polynomial_k3: d(4),
+			polynomial_p1: d(2),
+			polynomial_p2: d(3),
+			division_k1: 0.,
+			division_k2: 0.,
+		})
+	}
+}
+
+#[cfg(ocvrs_has_module_sfm)]
+impl TryFrom<&crate::sfm::libmv_CameraIntrinsicsOptions> for RosCameraInfo {
+	type Error = Error;
+
+	/// Supports `SFM_DISTORTION_MODEL_POLYNOMIAL` mapped to the `plumb_bob` distortion model
+	fn try_from(s: &crate::sfm::libmv_CameraIntrinsicsOptions) -> Result<Self> {
+		if s.distortion_model != crate::sfm::SFM_DISTORTION_MODEL_POLYNOMIAL {
+			return Err(Error::new(core::StsNotImplemented, "Only the polynomial distortion model can be converted to CameraInfo"));
+		}
+		let camera_matrix = Mat::from_slice_2d(&[
+			[s.focal_length_x, 0., s.principal_point_x],
+			[0., s.focal_length_y, s.principal_point_y],
+			[0., 0., 1.],
+		])?;
+		let dist_coeffs = Mat::from_slice(&[s.polynomial_k1, s.polynomial_k2, s.polynomial_p1, s.polynomial_p2, s.polynomial_k3])?;
+		Self::from_calibration(Size::new(s.image_width, s.image_height), &camera_matrix, &dist_coeffs)
+	}
+}
+
+/// Fixed-size array field of the generated message, `Vec<f64>` in `r2r` and `[f64; N]` in `rclrs`
+#[doc(hidden)]
+pub trait RosArray: Sized {
+	fn from_values(values: &[f64]) -> Self;
+}
+
+impl RosArray for Vec<f64> {
+	#[inline]
+	fn from_values(values: &[f64]) -> Self {
+		values.to_vec()
+	}
+}
+
+impl<const N: usize> RosArray for [f64; N] {
+	#[inline]
+	fn from_values(values: &[f64]) -> Self {
+		let mut out = [0.; N];
+		out.copy_from_slice(values);
+		out
+	}
+}
+
+#[doc(hidden)]
+pub fn copy_array<const N: usize>(values: &[f64], name: &str) -> Result<[f64; N]> {
+	<[f64; N]>::try_from(values)
+		.map_err(|_| Error::new(core::StsBadSize, format!("CameraInfo field: {} must have {} values, but it has: {}", name, N, values.len())))
+}
+
+/// Generates the module with the conversions between `Mat`/[RosCameraInfo](crate::interop::ros::RosCameraInfo) and the
+/// `sensor_msgs` types of a ROS 2 client library
+///
+/// The generated module contains `image_to_mat()`, `mat_to_image()`, `mat_to_image_with_encoding()`,
+/// `camera_info_from_msg()` and `camera_info_to_msg()`. The header of the created messages is left default.
+/// ```ignore
+/// opencv::ros_conversions! {
+///     pub mod ros_cv {
+///         image: rclrs_example_msgs::sensor_msgs::msg::Image,
+///         camera_info: rclrs_example_msgs::sensor_msgs::msg::CameraInfo,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! ros_conversions {
+	($vis: vis mod $name: ident { image: $image: ty, camera_info: $info: ty $(,)? }) => {
+		$vis mod $name {
+			#[allow(unused_imports)]
+			use super::*;
+			use $crate::{core::Mat, interop::ros, Result};
+
+			pub fn image_to_mat(msg: &$image) -> Result<Mat> {
+				ros::image_to_mat(msg.height, msg.width, &msg.encoding, msg.is_bigendian != 0, msg.step, &msg.data)
+			}
+
+			pub fn mat_to_image(mat: &Mat) -> Result<$image> {
+				ros::RosImage::from_mat(mat).map(from_ros_image)
+			}
+
+			pub fn mat_to_image_with_encoding(mat: &Mat, encoding: &str) -> Result<$image> {
+				ros::RosImage::from_mat_with_encoding(mat, encoding).map(from_ros_image)
+			}
+
+			fn from_ros_image(image: ros::RosImage) -> $image {
+				let mut out = <$image>::default();
+				out.height = image.height;
+				out.width = image.width;
+				out.encoding = image.encoding;
+				out.is_bigendian = image.is_bigendian;
+				out.step = image.step;
+				out.data = image.data;
+				out
+			}
+
+			pub fn camera_info_from_msg(msg: &$info) -> Result<ros::RosCameraInfo> {
+				Ok(ros::RosCameraInfo {
+					height: msg.height,
+					width: msg.width,
+					distortion_model: msg.distortion_model.clone(),
+					d: msg.d.to_vec(),
+					k: ros::copy_array(AsRef::<[f64]>::as_ref(&msg.k), "k")?,
+					r: ros::copy_array(AsRef::<[f64]>::as_ref(&msg.r), "r")?,
+					p: ros::copy_array(AsRef::<[f64]>::as_ref(&msg.p), "p")?,
+					binning_x: msg.binning_x,
+					binning_y: msg.binning_y,
+				})
+			}
+
+			pub fn camera_info_to_msg(info: &ros::RosCameraInfo) -> $info {
+				let mut out = <$info>::default();
+				out.height = info.height;
+				out.width = info.width;
+				out.distortion_model = info.distortion_model.clone();
+				out.d = info.d.clone();
+				out.k = ros::RosArray::from_values(&info.k);
+				out.r = ros::RosArray::from_values(&info.r);
+				out.p = ros::RosArray::from_values(&info.p);
+				out.binning_x = info.binning_x;
+				out.binning_y = info.binning_y;
+				out
+			}
+		}
+	};
+}
@@ -0,0 +1,225 @@
+//! Transfer of the frames between `Mat`/`UMat` and `wgpu` textures
+//!
+//! The data goes through the `wgpu` staging buffers: [upload_texture] writes the `Mat` rows with `Queue::write_texture()`
+//! and [download_texture] copies the texture into a mappable buffer and waits for it. OpenCV and `wgpu` don't share the
+//! device memory, so a `UMat` is read to the host before the upload.
+//!
+//! 3 channel `Mat`s are expanded to 4 channels because there are no 3 channel texture formats: `CV_8UC3` BGR becomes
+//! `Bgra8Unorm` and the other depths become RGBA. The alpha is opaque.
+//! ```no_run
+//! # fn run(device: &wgpu::Device, queue: &wgpu::Queue, frame: &opencv::core::Mat) -> opencv::Result<()> {
+//! use opencv::interop::texture;
+//! let texture = texture::upload_texture(device, queue, frame, wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_SRC)?;
+//! // render or post-process with the shaders
+//! let processed = texture::download_texture(device, queue, &texture)?;
+//! # Ok(()) }
+//! ```
+
+use std::{borrow::Cow, sync::mpsc};
+
+use wgpu::TextureFormat;
+
+use crate::{
+	core::{self, Mat, Scalar, UMat},
+	Error,
+	prelude::*,
+	Result,
+};
+
+/// Returns the texture format storing the elements of the `Mat` type as is
+///
+/// 4 channel `CV_8U` maps to `Bgra8Unorm` following OpenCV channel order. 3 channel types have no matching format, see
+/// [upload_texture]. The half-float formats are not available with OpenCV 3.2 that has no `CV_16F`.
+pub fn texture_format(typ: i32) -> Result<TextureFormat> {
+	use TextureFormat::*;
+	let format = match (core::CV_MAT_DEPTH(typ), core::CV_MAT_CN(typ)) {
+		(core::CV_8U, 1) => R8Unorm,
+		(core::CV_8U, 2) => Rg8Unorm,
+		(core::CV_8U, 4) => Bgra8Unorm,
+		(core::CV_8S, 1) => R8Snorm,
+		(core::CV_8S, 2) => Rg8Snorm,
+		(core::CV_8S, 4) => Rgba8Snorm,
+		(core::CV_16U, 1) => R16Uint,
+		(core::CV_16U, 2) => Rg16Uint,
+		(core::CV_16U, 4) => Rgba16Uint,
+		(core::CV_16S, 1) => R16Sint,
+		(core::CV_16S, 2) => Rg16Sint,
+		(core::CV_16S, 4) => Rgba16Sint,
+		#[cfg(not(ocvrs_opencv_branch_32))]
+		(core::CV_16F, 1) => R16Float,
+		#[cfg(not(ocvrs_opencv_branch_32))]
+		(core::CV_16F, 2) => Rg16Float,
+		#[cfg(not(ocvrs_opencv_branch_32))]
+		(core::CV_16F, 4) => Rgba16Float,
+		(core::CV_32S, 1) => R32Sint,
+		(core::CV_32S, 2) => Rg32Sint,
+		(core::CV_32S, 4) => Rgba32Sint,
+		(core::CV_32F, 1) => R32Float,
+		(core::CV_32F, 2) => Rg32Float,
+		(core::CV_32F, 4) => Rgba32Float,
+		_ => return Err(Error::new(core::StsUnsupportedFormat, format!("Mat type: {} has no matching texture format", typ))),
+	};
+	Ok(format)
+}
+
+/// Returns the `Mat` type for the texels of the texture `format`, the inverse of [texture_format]
+///
+/// Also accepts `Rgba8Unorm`, the sRGB variants and the normalized 16 bit formats, the values are kept as is.
+pub fn mat_type(format: TextureFormat) -> Result<i32> {
+	use TextureFormat::*;
+	let typ = match format {
+		R8Unorm | R8Uint => core::CV_8UC1,
+		Rg8Unorm | Rg8Uint => core::CV_8UC2,
+		Rgba8Unorm | Rgba8UnormSrgb | Bgra8Unorm | Bgra8UnormSrgb | Rgba8Uint => core::CV_8UC4,
+		R8Snorm | R8Sint => core::CV_8SC1,
+		Rg8Snorm | Rg8Sint => core::CV_8SC2,
+		Rgba8Snorm | Rgba8Sint => core::CV_8SC4,
+		R16Uint | R16Unorm => core::CV_16UC1,
+		Rg16Uint | Rg16Unorm => core::CV_16UC2,
+		Rgba16Uint | Rgba16Unorm => core::CV_16UC4,
+		R16Sint | R16Snorm => core::CV_16SC1,
+		Rg16Sint | Rg16Snorm => core::CV_16SC2,
+		Rgba16Sint | Rgba16Snorm => core::CV_16SC4,
+		#[cfg(not(ocvrs_opencv_branch_32))]
+		R16Float => core::CV_16FC1,
+		#[cfg(not(ocvrs_opencv_branch_32))]
+		Rg16Float => core::CV_16FC2,
+		#[cfg(not(ocvrs_opencv_branch_32))]
+		Rgba16Float => core::CV_16FC4,
+		R32Sint => core::CV_32SC1,
+		Rg32Sint => core::CV_32SC2,
+		Rgba32Sint => core::CV_32SC4,
+		R32Float => core::CV_32FC1,
+		Rg32Float => core::CV_32FC2,
+		Rgba32Float => core::CV_32FC4,
+		_ => return Err(Error::new(core::StsUnsupportedFormat, format!("Texture format: {:?} has no matching Mat type", format))),
+	};
+	Ok(typ)
+}
+
+/// Returns the continuous `Mat` with the elements in the texture layout, 3 channels are expanded to 4
+fn texture_layout(mat: &Mat) -> Result<Cow<'_, Mat>> {
+	if mat.dims() > 2 || mat.empty() {
+		return Err(Error::new(core::StsBadArg, "Only non-empty 2D Mats can be uploaded to a texture"));
+	}
+	let depth = mat.depth();
+	if mat.channels() == 3 {
+		let alpha = match depth {
+			core::CV_8U => 255.,
+			core::CV_16U => 65535.,
+			_ => 1.,
+		};
+		let mut out = Mat::new_rows_cols_with_default(mat.rows(), mat.cols(), core::CV_MAKETYPE(depth, 4), Scalar::all(alpha))?;
+		// CV_8U is stored as BGRA, the rest as RGBA
+		let from_to = if depth == core::CV_8U { [0, 0, 1, 1, 2, 2] } else { [0, 2, 1, 1, 2, 0] };
+		core::mix_channels(mat, &mut out, &from_to)?;
+		Ok(Cow::Owned(out))
+	} else if mat.is_continuous() {
+		Ok(Cow::Borrowed(mat))
+	} else {
+		mat.try_clone().map(Cow::Owned)
+	}
+}
+
+/// Creates the texture of the `Mat` size and format (see [texture_format]) and uploads the `Mat` to it
+///
+/// `COPY_DST` is always added to the `usage`.
+pub fn upload_texture(device: &wgpu::Device, queue: &wgpu::Queue, mat: &Mat, usage: wgpu::TextureUsages) -> Result<wgpu::Texture> {
+	let data = texture_layout(mat)?;
+	let texture = device.create_texture(&wgpu::TextureDescriptor {
+		label: Some("opencv::Mat"),
+		size: wgpu::Extent3d { width: data.cols() as u32, height: data.rows() as u32, depth_or_array_layers: 1 },
+		mip_level_count: 1,
+		sample_count: 1,
+		dimension: wgpu::TextureDimension::D2,
+		format: texture_format(data.typ())?,
+		usage: usage | wgpu::TextureUsages::COPY_DST,
+		view_formats: &[],
+	});
+	write_data(queue, &texture, &data)?;
+	Ok(texture)
+}
+
+/// Uploads the `Mat` to the existing texture, its size and format must match the `Mat` (after the 3 channel expansion)
+pub fn write_texture(queue: &wgpu::Queue, texture: &wgpu::Texture, mat: &Mat) -> Result<()> {
+	let data = texture_layout(mat)?;
+	if texture.width() != data.cols() as u32 || texture.height() != data.rows() as u32 {
+		return Err(Error::new(core::StsUnmatchedSizes, format!("Mat size: {}x{} doesn't match the texture size: {}x{}", data.cols(), data.rows(), texture.width(), texture.height())));
+	}
+	if mat_type(texture.format())? != data.typ() {
+		return Err(Error::new(core::StsUnmatchedFormats, format!("Mat type: {} doesn't match the texture format: {:?}", data.typ(), texture.format())));
+	}
+	write_data(queue, texture, &data)
+}
+
+fn write_data(queue: &wgpu::Queue, texture: &wgpu::Texture, data: &Mat) -> Result<()> {
+	let bytes_per_row = data.cols() as u32 * data.elem_size()? as u32;
+	queue.write_texture(
+		texture.as_image_copy(),
+		data.data_bytes()?,
+		wgpu::TexelCopyBufferLayout { offset: 0, bytes_per_row: Some(bytes_per_row), rows_per_image: None },
+		wgpu::Extent3d { width: texture.width(), height: texture.height(), depth_or_array_layers: 1 },
+	);
+	Ok(())
+}
+
+/// Reads the `UMat` to the host and uploads it, see [upload_texture]
+pub fn upload_umat(device: &wgpu::Device, queue: &wgpu::Queue, umat: &UMat, usage: wgpu::TextureUsages) -> Result<wgpu::Texture> {
+	let mat = umat.get_mat(core::AccessFlag::ACCESS_READ)?;
+	upload_texture(device, queue, &mat, usage)
+}
+
+/// Copies the first mip level of the 2D texture into a new `Mat` of the type returned by [mat_type]
+///
+/// The texture must have `COPY_SRC` usage. Blocks until the GPU finishes the submitted work.
+pub fn download_texture(device: &wgpu::Device, queue: &wgpu::Queue, texture: &wgpu::Texture) -> Result<Mat> {
+	let typ = mat_type(texture.format())?;
+	let mut out = unsafe { Mat::new_rows_cols(texture.height() as i32, texture.width() as i32, typ) }?;
+	let row_len = out.cols() as usize * out.elem_size()?;
+	// buffer copies require the aligned rows
+	let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT as usize;
+	let padded_row_len = row_len.div_ceil(align) * align;
+	let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+		label: Some("opencv::Mat download"),
+		size: (padded_row_len * out.rows() as usize) as u64,
+		usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+		mapped_at_creation: false,
+	});
+	let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: Some("opencv::Mat download") });
+	encoder.copy_texture_to_buffer(
+		texture.as_image_copy(),
+		wgpu::TexelCopyBufferInfo {
+			buffer: &buffer,
+			layout: wgpu::TexelCopyBufferLayout { offset: 0, bytes_per_row: Some(padded_row_len as u32), rows_per_image: None },
+		},
+		wgpu::Extent3d { width: texture.width(), height: texture.height(), depth_or_array_layers: 1 },
+	);
+	let submission = queue.submit(Some(encoder.finish()));
+
+	let slice = buffer.slice(..);
+	let (tx, rx) = mpsc::channel();
+	slice.map_async(wgpu::MapMode::Read, move |res| {
+		let _ = tx.send(res);
+	});
+	device.poll(wgpu::Maintain::WaitForSubmissionIndex(submission));
+	rx.recv()
+		.map_err(|_| Error::new(core::StsError, "Texture download buffer was dropped before mapping"))?
+		.map_err(|e| Error::new(core::StsError, format!("Can't map the texture download buffer: {}", e)))?;
+	{
+		let mapped = slice.get_mapped_range();
+		let dst = out.data_bytes_mut()?;
+		for (dst_row, src_row) in dst.chunks_exact_mut(row_len).zip(mapped.chunks(padded_row_len)) {
+			dst_row.copy_from_slice(&src_row[..row_len]);
+		}
+	}
+	buffer.unmap();
+	Ok(out)
+}
+
+/// Downloads the texture into a new `UMat`, see [download_texture]
+pub fn download_umat(device: &wgpu::Device, queue: &wgpu::Queue, texture: &wgpu::Texture) -> Result<UMat> {
+	let mat = download_texture(device, queue, texture)?;
+	let mut out = UMat::new(core::UMatUsageFlags::USAGE_DEFAULT);
+	mat.copy_to(&mut out)?;
+	Ok(out)
+}
@@ -0,0 +1,102 @@
+//! Color calibration with a ColorChecker chart
+//!
+//! ```ignore
+//! let checker = mcc::detect_color_checker(&photo, MCC_TYPECHART::MCC24)?.expect("Chart is visible");
+//! let mut model = ColorCorrectionModel::from_checker(&checker, CONST_COLOR::COLORCHECKER_Macbeth)?;
+//! model.run()?;
+//! let calibrated = model.apply_ccm(&photo)?;
+//! ```
+
+use crate::{
+	core::{self, Mat, Point2f},
+	Error,
+	imgproc,
+	mcc::{ColorCorrectionModel, CONST_COLOR, MCC_CChecker, MCC_CCheckerDetector, MCC_DetectorParameters, MCC_TYPECHART},
+	prelude::*,
+	Result,
+};
+
+/// Color chart found by the [detect_color_checker]
+#[derive(Debug)]
+pub struct ColorChecker {
+	pub chart_type: MCC_TYPECHART,
+	/// Corners of the chart in the image
+	pub corners: Vec<Point2f>,
+	pub center: Point2f,
+	/// Detection cost, lower is better
+	pub cost: f32,
+	/// `Nx1` `CV_64FC3` mean RGB colors of the patches in `0..1`, the source colors of the [ColorCorrectionModel]
+	pub colors: Mat,
+}
+
+/// Detects the best color chart of the `chart_type` in the 8-bit BGR `image`, `None` if there is no chart
+pub fn detect_color_checker(image: &Mat, chart_type: MCC_TYPECHART) -> Result<Option<ColorChecker>> {
+	let mut detector = <dyn MCC_CCheckerDetector>::create()?;
+	if !detector.process(image, chart_type, 1, false, &MCC_DetectorParameters::create()?)? {
+		return Ok(None);
+	}
+	let mut checker = detector.get_best_color_checker()?;
+	// a row per patch channel, the second column is the mean value
+	let charts_rgb = checker.get_charts_rgb()?;
+	let mut colors = Mat::default();
+	charts_rgb.col(1)?.try_clone()?.reshape(3, charts_rgb.rows() / 3)?.convert_to(&mut colors, core::CV_64F, 1. / 255., 0.)?;
+	Ok(Some(ColorChecker {
+		chart_type,
+		corners: checker.get_box()?.to_vec(),
+		center: checker.get_center()?,
+		cost: checker.get_cost()?,
+		colors,
+	}))
+}
+
+impl ColorCorrectionModel {
+	/// Creates the model mapping the detected patch colors to the known colors of the `reference` chart
+	///
+	/// Configure the model with the `set_*` methods and fit it with `run()` before calling [apply_ccm](Self::apply_ccm).
+	#[inline]
+	pub fn from_checker(checker: &ColorChecker, reference: CONST_COLOR) -> Result<Self> {
+		Self::new(&checker.colors, reference)
+	}
+
+	/// Corrects the BGR `image` of `CV_8U`, `CV_16U` or `CV_32F` (in `0..1`) depth with the fitted model, the result
+	/// has the type of the `image`
+	pub fn apply_ccm(&mut self, image: &Mat) -> Result<Mat> {
+		let scale = match image.depth() {
+			core::CV_8U => 255.,
+			core::CV_16U => 65535.,
+			core::CV_32F => 1.,
+			depth => return Err(Error::new(core::StsUnsupportedFormat, format!("Unsupported image depth for the color correction: {}", depth))),
+		};
+		if image.channels() != 3 {
+			return Err(Error::new(core::StsBadArg, format!("Color correction requires a 3 channel BGR image, got: {} channels", image.channels())));
+		}
+		let mut rgb = Mat::default();
+		imgproc::cvt_color(image, &mut rgb, imgproc::COLOR_BGR2RGB, 0)?;
+		let mut normalized = Mat::default();
+		rgb.convert_to(&mut normalized, core::CV_64F, 1. / scale, 0.)?;
+		let calibrated = self.infer(&normalized, false)?;
+		calibrated.convert_to(&mut rgb, image.depth(), scale, 0.)?;
+		let mut out = Mat::default();
+		imgproc::cvt_color(&rgb, &mut out, imgproc::COLOR_RGB2BGR, 0)?;
+		Ok(out)
+	}
+}
+
+/// Applies the `3x3` or `4x3` color correction matrix returned by `ColorCorrectionModel::get_ccm()` to the linear RGB
+/// image of the floating point type, the affine `4x3` matrix multiplies `[r, g, b, 1]`
+///
+/// Unlike [ColorCorrectionModel::apply_ccm] there is no linearization, use it for the images already in the linear
+/// color space of the model.
+pub fn apply_ccm(linear_rgb: &Mat, ccm: &Mat) -> Result<Mat> {
+	if linear_rgb.channels() != 3 {
+		return Err(Error::new(core::StsBadArg, format!("Color correction requires a 3 channel image, got: {} channels", linear_rgb.channels())));
+	}
+	if ccm.cols() != 3 || !(ccm.rows() == 3 || ccm.rows() == 4) || ccm.channels() != 1 {
+		return Err(Error::new(core::StsBadSize, format!("CCM must be a 3x3 or 4x3 single channel matrix, got: {}x{}", ccm.rows(), ccm.cols())));
+	}
+	let mut m = Mat::default();
+	ccm.t()?.to_mat()?.convert_to(&mut m, core::CV_64F, 1., 0.)?;
+	let mut out = Mat::default();
+	core::transform(linear_rgb, &mut out, &m)?;
+	Ok(out)
+}
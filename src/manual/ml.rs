@@ -0,0 +1,23 @@
+pub use batch_source::*;
+pub use labels::*;
+pub use model_bundle::*;
+pub use named_model::*;
+pub use sample_layout::*;
+pub use svm_gpu::*;
+pub use train_control::*;
+pub use train_data_stats::*;
+pub use validation::*;
+
+mod batch_source;
+mod labels;
+pub mod logging;
+mod model_bundle;
+pub mod monitor;
+mod named_model;
+#[cfg(all(ocvrs_has_module_features2d, ocvrs_has_module_imgcodecs, ocvrs_has_module_imgproc, ocvrs_has_module_objdetect))]
+pub mod quick;
+mod sample_layout;
+mod svm_gpu;
+mod train_control;
+mod train_data_stats;
+mod validation;
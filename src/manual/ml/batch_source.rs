@@ -0,0 +1,175 @@
+use std::{
+	fs::File,
+	io::{BufRead, BufReader},
+	path::Path,
+	sync::mpsc::Receiver,
+};
+
+use crate::{
+	core::{self, Mat},
+	Error,
+	ml::{IncrementalTrain, SampleTypes, TrainData},
+	prelude::*,
+	Result,
+};
+
+/// Source of the training data in mini-batches, so the whole dataset never has to be in memory at once
+pub trait BatchSource {
+	/// Returns the next `(samples, responses)` batch with one sample per row, `None` when the source is exhausted
+	fn next_batch(&mut self) -> Result<Option<(Mat, Mat)>>;
+}
+
+impl<T: BatchSource + ?Sized> BatchSource for &mut T {
+	#[inline]
+	fn next_batch(&mut self) -> Result<Option<(Mat, Mat)>> {
+		(**self).next_batch()
+	}
+}
+
+/// Receives the batches produced by another thread, the source is exhausted when all senders are dropped
+impl BatchSource for Receiver<(Mat, Mat)> {
+	#[inline]
+	fn next_batch(&mut self) -> Result<Option<(Mat, Mat)>> {
+		Ok(self.recv().ok())
+	}
+}
+
+/// [BatchSource] reading the numeric CSV lines, `batch_size` lines at a time
+///
+/// By default the last column is the response, the values are separated by `,` and there is no header line. Empty
+/// lines are skipped. Samples and responses are returned as `CV_32F`.
+#[derive(Debug)]
+pub struct CsvBatches<R> {
+	reader: R,
+	batch_size: usize,
+	delimiter: char,
+	response_col: i32,
+	skip_header: bool,
+	line_num: usize,
+}
+
+impl CsvBatches<BufReader<File>> {
+	pub fn open(path: impl AsRef<Path>, batch_size: usize) -> Result<Self> {
+		let path = path.as_ref();
+		let file = File::open(path)
+			.map_err(|e| Error::new(core::StsError, format!("Can't open CSV file: {}: {}", path.display(), e)))?;
+		Self::new(BufReader::new(file), batch_size)
+	}
+}
+
+impl<R: BufRead> CsvBatches<R> {
+	pub fn new(reader: R, batch_size: usize) -> Result<Self> {
+		if batch_size == 0 {
+			return Err(Error::new(core::StsBadArg, "Batch size must be positive"));
+		}
+		Ok(Self { reader, batch_size, delimiter: ',', response_col: -1, skip_header: false, line_num: 0 })
+	}
+
+	pub fn with_delimiter(mut self, delimiter: char) -> Self {
+		self.delimiter = delimiter;
+		self
+	}
+
+	/// Sets the index of the response column, negative values count from the end (`-1` is the last column)
+	pub fn with_response_col(mut self, response_col: i32) -> Self {
+		self.response_col = response_col;
+		self
+	}
+
+	/// Skips the first line
+	pub fn with_header(mut self, skip_header: bool) -> Self {
+		self.skip_header = skip_header;
+		self
+	}
+
+	fn read_line(&mut self, line: &mut String) -> Result<bool> {
+		line.clear();
+		self.line_num += 1;
+		let read = self.reader.read_line(line)
+			.map_err(|e| Error::new(core::StsError, format!("Can't read CSV line: {}: {}", self.line_num, e)))?;
+		Ok(read > 0)
+	}
+
+	fn parse_line(&self, line: &str) -> Result<(Vec<f32>, f32)> {
+		let mut values = line.split(self.delimiter)
+			.map(|v| v.trim().parse::<f32>()
+				.map_err(|e| Error::new(core::StsParseError, format!("Invalid value: {} in CSV line: {}: {}", v.trim(), self.line_num, e)))
+			)
+			.collect::<Result<Vec<_>>>()?;
+		let len = values.len() as i32;
+		let response_col = if self.response_col < 0 { len + self.response_col } else { self.response_col };
+		if len < 2 || response_col < 0 || response_col >= len {
+			return Err(Error::new(core::StsParseError, format!("CSV line: {} has {} values, response column: {} is out of range", self.line_num, len, self.response_col)));
+		}
+		let response = values.remove(response_col as usize);
+		Ok((values, response))
+	}
+}
+
+impl<R: BufRead> BatchSource for CsvBatches<R> {
+	fn next_batch(&mut self) -> Result<Option<(Mat, Mat)>> {
+		let mut line = String::new();
+		if self.skip_header && self.line_num == 0 {
+			self.read_line(&mut line)?;
+		}
+		let mut samples: Vec<Vec<f32>> = Vec::with_capacity(self.batch_size);
+		let mut responses = Vec::with_capacity(self.batch_size);
+		while samples.len() < self.batch_size && self.read_line(&mut line)? {
+			let trimmed = line.trim();
+			if trimmed.is_empty() {
+				continue;
+			}
+			let (sample, response) = self.parse_line(trimmed)?;
+			if let Some(first) = samples.first() {
+				if first.len() != sample.len() {
+					return Err(Error::new(core::StsParseError, format!("CSV line: {} has {} features, expected: {}", self.line_num, sample.len(), first.len())));
+				}
+			}
+			samples.push(sample);
+			responses.push(response);
+		}
+		if samples.is_empty() {
+			Ok(None)
+		} else {
+			Ok(Some((Mat::from_slice_2d(&samples)?, Mat::from_exact_iter(responses.into_iter())?)))
+		}
+	}
+}
+
+/// Result of [fit_stream]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StreamSummary {
+	pub batches: usize,
+	pub samples: usize,
+}
+
+/// Trains the `model` over all batches of the `source`, the first batch starts the training and the rest continue it
+///
+/// Every batch is trained with the model termination criteria, so set it to a few iterations. Works with the models
+/// implementing [IncrementalTrain]: `ANN_MLP` continues with `UPDATE_WEIGHTS` and `EM` starts every batch from the
+/// parameters of the previous one. Empty batches are skipped, the batch failing to train stops the training with an
+/// error.
+///
+/// `SVMSGD` and `LogisticRegression` are deliberately not supported and don't implement [IncrementalTrain]. Their
+/// `train()` clears the model before the optimization and ignores `UPDATE_MODEL`, and OpenCV has no setter for the
+/// learnt weights, so there is no way to continue from the previous batch, each batch would train a new model from
+/// scratch. For a streamed linear classifier use `ANN_MLP` without the hidden layers and with the `SIGMOID_SYM`
+/// activation.
+pub fn fit_stream(model: &mut impl IncrementalTrain, mut source: impl BatchSource, flags: i32) -> Result<StreamSummary> {
+	let mut summary = StreamSummary::default();
+	while let Some((samples, responses)) = source.next_batch()? {
+		if samples.rows() == 0 {
+			continue;
+		}
+		if responses.rows() != samples.rows() {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("Batch: {} has {} responses for {} samples", summary.batches, responses.rows(), samples.rows())));
+		}
+		let data = <dyn TrainData>::create_with_layout(&samples, SampleTypes::ROW_SAMPLE, &responses)?;
+		if !model.train_chunk(&data, flags, summary.batches > 0)? {
+			return Err(Error::new(core::StsError, format!("Training failed on batch: {}", summary.batches)));
+		}
+		summary.batches += 1;
+		summary.samples += samples.rows() as usize;
+	}
+	Ok(summary)
+}
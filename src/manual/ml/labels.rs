@@ -0,0 +1,140 @@
+use std::{
+	collections::HashMap,
+	convert::TryFrom,
+};
+
+use crate::{
+	core::{self, Mat, Ptr},
+	Error,
+	ml::TrainData,
+	prelude::*,
+	Result,
+};
+
+/// Mapping between the class names and the contiguous integer codes used as responses
+///
+/// Codes are assigned in the order the names are first seen starting from `0`, so the classifier responses can be
+/// decoded back into the names. Any type implementing `AsRef<str>` (e.g. an enum with a name for every variant) can be
+/// used as a label. Store the mapping together with the model using [ModelMetadata::with_labels](super::ModelMetadata::with_labels).
+/// ```no_run
+/// # use opencv::{core::Mat, ml, prelude::*, Result};
+/// # fn train(samples: &Mat, predicted: &Mat) -> Result<()> {
+/// let mut labels = ml::Labels::new();
+/// let responses = labels.encode_all(vec!["cat", "dog", "cat"])?;
+/// // train the model with `responses`, then after the prediction:
+/// let names = labels.decode_all(predicted)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Labels {
+	names: Vec<String>,
+	codes: HashMap<String, i32>,
+}
+
+impl Labels {
+	#[inline]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Creates a mapping with the codes assigned to `names` in order, fails on duplicate names
+	pub fn from_names(names: impl IntoIterator<Item=impl AsRef<str>>) -> Result<Self> {
+		let mut out = Self::new();
+		for name in names {
+			let name = name.as_ref();
+			if out.code(name).is_some() {
+				return Err(Error::new(core::StsBadArg, format!("Duplicate label: {}", name)));
+			}
+			out.encode(name);
+		}
+		Ok(out)
+	}
+
+	/// Checks that every class label of `data` (`TrainData::get_class_labels()`) is a code known to this mapping
+	///
+	/// Catches the responses that were not produced by [encode](Self::encode) or the mapping that doesn't belong to
+	/// the training data.
+	pub fn check_train_data(&self, data: &Ptr<dyn TrainData>) -> Result<()> {
+		let mut class_labels = Mat::default();
+		data.get_class_labels()?.convert_to(&mut class_labels, f32::typ(), 1., 0.)?;
+		if class_labels.empty() {
+			return Err(Error::new(core::StsBadArg, "TrainData has no class labels, responses must be categorical"));
+		}
+		class_labels.data_typed::<f32>()?
+			.iter()
+			.try_for_each(|&label| self.decode(label).map(|_| ()))
+	}
+
+	/// Returns the code of the `label` assigning a new one if it's not yet known
+	pub fn encode(&mut self, label: impl AsRef<str>) -> i32 {
+		let label = label.as_ref();
+		if let Some(code) = self.code(label) {
+			code
+		} else {
+			let code = self.names.len() as i32;
+			self.names.push(label.to_string());
+			self.codes.insert(label.to_string(), code);
+			code
+		}
+	}
+
+	/// Encodes all `labels` into a single column `CV_32SC1` matrix suitable as the classifier responses
+	pub fn encode_all(&mut self, labels: impl IntoIterator<Item=impl AsRef<str>>) -> Result<Mat> {
+		let codes = labels.into_iter()
+			.map(|label| self.encode(label))
+			.collect::<Vec<_>>();
+		Mat::from_exact_iter(codes.into_iter())
+	}
+
+	/// Returns the code of the already known `label`
+	#[inline]
+	pub fn code(&self, label: impl AsRef<str>) -> Option<i32> {
+		self.codes.get(label.as_ref()).copied()
+	}
+
+	/// Returns the name for the `code`
+	#[inline]
+	pub fn name(&self, code: i32) -> Option<&str> {
+		usize::try_from(code).ok()
+			.and_then(|code| self.names.get(code))
+			.map(|name| name.as_str())
+	}
+
+	/// Returns the name for the prediction result, fails for the codes that are not in the mapping
+	pub fn decode(&self, response: f32) -> Result<&str> {
+		let code = response.round();
+		if (code - response).abs() > 1e-3 {
+			return Err(Error::new(core::StsBadArg, format!("Response is not a class code: {}", response)));
+		}
+		self.name(code as i32)
+			.ok_or_else(|| Error::new(core::StsOutOfRange, format!("Unknown class code: {}, labels have: {} classes", code, self.len())))
+	}
+
+	/// Decodes every element of the single channel prediction results (`CV_32F` or `CV_32S`)
+	pub fn decode_all(&self, results: &Mat) -> Result<Vec<&str>> {
+		let mut converted = Mat::default();
+		results.convert_to(&mut converted, f32::typ(), 1., 0.)?;
+		converted.data_typed::<f32>()?
+			.iter()
+			.map(|&r| self.decode(r))
+			.collect()
+	}
+
+	/// Returns the names in the order of their codes
+	#[inline]
+	pub fn names(&self) -> &[String] {
+		&self.names
+	}
+
+	/// Returns the number of classes
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.names.len()
+	}
+
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.names.is_empty()
+	}
+}
@@ -0,0 +1,279 @@
+//! Experiment tracking for the model training in the style of MLflow or Weights & Biases
+//!
+//! A [Tracker] receives the parameters, metrics and artifacts of a training run. It's invoked by [train_tracked],
+//! [TrainControl::train_tracked](crate::ml::TrainControl::train_tracked) and
+//! [ModelBundle::save_tracked](crate::ml::ModelBundle::save_tracked). [JsonlTracker] appends the records to a JSON Lines
+//! file that can be imported into the tracking server of choice:
+//! ```no_run
+//! # use opencv::{core::Ptr, ml::{self, logging::{self, JsonlTracker}}, prelude::*, Result};
+//! # fn train(data: &Ptr<dyn ml::TrainData>) -> Result<()> {
+//! let mut tracker = JsonlTracker::create("experiments.jsonl", "svm-rbf")?;
+//! let mut svm = <dyn ml::SVM>::create()?;
+//! logging::train_tracked(&mut svm, data, &mut tracker, |svm| svm.train_with_data(data, 0))?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+	fmt::Write as _,
+	fs::{File, OpenOptions},
+	io::{BufWriter, Write},
+	path::Path,
+	time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+	core::{self, Ptr, TermCriteria},
+	Error,
+	ml::{ANN_MLP, DTrees, RTrees, StatModel, SVM, TrainData},
+	prelude::*,
+	Result,
+};
+
+/// Receiver of the experiment records
+pub trait Tracker {
+	/// Records the hyperparameter or any other input of the run
+	fn log_param(&mut self, key: &str, value: &str) -> Result<()>;
+
+	/// Records the value of the metric, `step` is usually the iteration number for the metrics tracked during training
+	fn log_metric(&mut self, key: &str, value: f64, step: Option<i64>) -> Result<()>;
+
+	/// Records the file produced by the run
+	fn log_artifact(&mut self, key: &str, path: &Path) -> Result<()>;
+
+	fn log_params(&mut self, params: &[(&str, String)]) -> Result<()> {
+		params.iter().try_for_each(|(key, value)| self.log_param(key, value))
+	}
+}
+
+impl<T: Tracker + ?Sized> Tracker for &mut T {
+	#[inline]
+	fn log_param(&mut self, key: &str, value: &str) -> Result<()> {
+		(**self).log_param(key, value)
+	}
+
+	#[inline]
+	fn log_metric(&mut self, key: &str, value: f64, step: Option<i64>) -> Result<()> {
+		(**self).log_metric(key, value, step)
+	}
+
+	#[inline]
+	fn log_artifact(&mut self, key: &str, path: &Path) -> Result<()> {
+		(**self).log_artifact(key, path)
+	}
+}
+
+/// [Tracker] writing one JSON object per record
+///
+/// Every record contains `run`, `timestamp` (seconds since Unix epoch), `kind` (`param`, `metric` or `artifact`) and
+/// `key` fields, plus `value` for params and metrics, `step` for metrics and `path` for artifacts. Non-finite metric
+/// values are written as `null`.
+#[derive(Debug)]
+pub struct JsonlTracker<W: Write = BufWriter<File>> {
+	out: W,
+	run: String,
+}
+
+impl JsonlTracker {
+	/// Opens the file for appending, the records are tagged with the `run` name
+	pub fn create(path: impl AsRef<Path>, run: impl Into<String>) -> Result<Self> {
+		let path = path.as_ref();
+		let file = OpenOptions::new()
+			.create(true)
+			.append(true)
+			.open(path)
+			.map_err(|e| Error::new(core::StsError, format!("Can't open tracking file: {}: {}", path.display(), e)))?;
+		Ok(Self::new(BufWriter::new(file), run))
+	}
+}
+
+impl<W: Write> JsonlTracker<W> {
+	#[inline]
+	pub fn new(out: W, run: impl Into<String>) -> Self {
+		Self { out, run: run.into() }
+	}
+
+	#[inline]
+	pub fn run(&self) -> &str {
+		&self.run
+	}
+
+	#[inline]
+	pub fn into_inner(self) -> W {
+		self.out
+	}
+
+	fn write_record(&mut self, kind: &str, key: &str, fields: &str) -> Result<()> {
+		let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0., |d| d.as_secs_f64());
+		let mut line = String::from("{\"run\":");
+		write_json_str(&mut line, &self.run);
+		let _ = write!(line, ",\"timestamp\":{:.3},\"kind\":\"{}\",\"key\":", timestamp, kind);
+		write_json_str(&mut line, key);
+		line.push_str(fields);
+		line.push_str("}\n");
+		self.out.write_all(line.as_bytes())
+			.and_then(|_| self.out.flush())
+			.map_err(|e| Error::new(core::StsError, format!("Can't write tracking record: {}", e)))
+	}
+}
+
+impl<W: Write> Tracker for JsonlTracker<W> {
+	fn log_param(&mut self, key: &str, value: &str) -> Result<()> {
+		let mut fields = String::from(",\"value\":");
+		write_json_str(&mut fields, value);
+		self.write_record("param", key, &fields)
+	}
+
+	fn log_metric(&mut self, key: &str, value: f64, step: Option<i64>) -> Result<()> {
+		let mut fields = String::from(",\"value\":");
+		if value.is_finite() {
+			let _ = write!(fields, "{}", value);
+		} else {
+			fields.push_str("null");
+		}
+		if let Some(step) = step {
+			let _ = write!(fields, ",\"step\":{}", step);
+		}
+		self.write_record("metric", key, &fields)
+	}
+
+	fn log_artifact(&mut self, key: &str, path: &Path) -> Result<()> {
+		let mut fields = String::from(",\"path\":");
+		write_json_str(&mut fields, &path.to_string_lossy());
+		self.write_record("artifact", key, &fields)
+	}
+}
+
+fn write_json_str(out: &mut String, s: &str) {
+	out.push('"');
+	for c in s.chars() {
+		match c {
+			'"' => out.push_str("\\\""),
+			'\\' => out.push_str("\\\\"),
+			'\n' => out.push_str("\\n"),
+			'\r' => out.push_str("\\r"),
+			'\t' => out.push_str("\\t"),
+			c if (c as u32) < 0x20 => {
+				let _ = write!(out, "\\u{:04x}", c as u32);
+			}
+			c => out.push(c),
+		}
+	}
+	out.push('"');
+}
+
+pub(crate) fn criteria_params(prefix: &str, criteria: TermCriteria) -> Vec<(String, String)> {
+	let mut out = vec![];
+	if criteria.has_max_iter() {
+		out.push((format!("{}max_iter", prefix), criteria.max_count.to_string()));
+	}
+	if criteria.has_eps() {
+		out.push((format!("{}epsilon", prefix), criteria.epsilon.to_string()));
+	}
+	out
+}
+
+/// Model that can report its hyperparameters to a [Tracker]
+///
+/// Implemented for `SVM`, `DTrees`, `RTrees` and `ANN_MLP`.
+pub trait TrackedParams {
+	/// Returns the current hyperparameters as `(name, value)` pairs
+	fn tracked_params(&self) -> Result<Vec<(String, String)>>;
+}
+
+impl TrackedParams for Ptr<dyn SVM> {
+	fn tracked_params(&self) -> Result<Vec<(String, String)>> {
+		let mut out = vec![
+			("svm_type".to_string(), self.get_type()?.to_string()),
+			("kernel_type".to_string(), self.get_kernel_type()?.to_string()),
+			("c".to_string(), self.get_c()?.to_string()),
+			("gamma".to_string(), self.get_gamma()?.to_string()),
+			("nu".to_string(), self.get_nu()?.to_string()),
+			("p".to_string(), self.get_p()?.to_string()),
+			("degree".to_string(), self.get_degree()?.to_string()),
+			("coef0".to_string(), self.get_coef0()?.to_string()),
+		];
+		out.extend(criteria_params("", self.get_term_criteria()?));
+		Ok(out)
+	}
+}
+
+fn dtrees_params(model: &(impl DTreesConst + ?Sized)) -> Result<Vec<(String, String)>> {
+	Ok(vec![
+		("max_depth".to_string(), model.get_max_depth()?.to_string()),
+		("min_sample_count".to_string(), model.get_min_sample_count()?.to_string()),
+		("max_categories".to_string(), model.get_max_categories()?.to_string()),
+		("cv_folds".to_string(), model.get_cv_folds()?.to_string()),
+		("use_surrogates".to_string(), model.get_use_surrogates()?.to_string()),
+		("regression_accuracy".to_string(), model.get_regression_accuracy()?.to_string()),
+	])
+}
+
+impl TrackedParams for Ptr<dyn DTrees> {
+	#[inline]
+	fn tracked_params(&self) -> Result<Vec<(String, String)>> {
+		dtrees_params(self)
+	}
+}
+
+impl TrackedParams for Ptr<dyn RTrees> {
+	fn tracked_params(&self) -> Result<Vec<(String, String)>> {
+		let mut out = dtrees_params(self)?;
+		out.push(("active_var_count".to_string(), self.get_active_var_count()?.to_string()));
+		out.push(("calculate_var_importance".to_string(), self.get_calculate_var_importance()?.to_string()));
+		out.extend(criteria_params("", self.get_term_criteria()?));
+		Ok(out)
+	}
+}
+
+impl TrackedParams for Ptr<dyn ANN_MLP> {
+	fn tracked_params(&self) -> Result<Vec<(String, String)>> {
+		let layer_sizes = self.get_layer_sizes()?;
+		let layer_sizes = (0..layer_sizes.total())
+			.map(|i| layer_sizes.at::<i32>(i as i32).map(|v| v.to_string()))
+			.collect::<Result<Vec<_>>>()?;
+		let mut out = vec![
+			("layer_sizes".to_string(), layer_sizes.join(",")),
+			("train_method".to_string(), self.get_train_method()?.to_string()),
+			("backprop_weight_scale".to_string(), self.get_backprop_weight_scale()?.to_string()),
+			("backprop_momentum_scale".to_string(), self.get_backprop_momentum_scale()?.to_string()),
+			("rprop_dw0".to_string(), self.get_rprop_dw0()?.to_string()),
+		];
+		out.extend(criteria_params("", self.get_term_criteria()?));
+		Ok(out)
+	}
+}
+
+/// Runs the `train` closure for the `model` recording the run to `tracker`
+///
+/// `train` can be any training call including the builders and the parameter tuning like `SVM::train_auto()`, the model
+/// hyperparameters are recorded after it finishes, so the tuned values end up in the log. Records the model name and
+/// the dataset size as params and `train_duration_s`, `train_error` and `test_error` (if `data` has a test split) as
+/// metrics.
+pub fn train_tracked<M: StatModel + TrackedParams>(
+	model: &mut M,
+	data: &Ptr<dyn TrainData>,
+	tracker: &mut dyn Tracker,
+	train: impl FnOnce(&mut M) -> Result<bool>,
+) -> Result<bool> {
+	let test_samples = data.get_n_test_samples()?;
+	tracker.log_params(&[
+		("model", model.get_default_name()?),
+		("train_samples", data.get_n_train_samples()?.to_string()),
+		("test_samples", test_samples.to_string()),
+		("var_count", data.get_n_vars()?.to_string()),
+	])?;
+	let start = Instant::now();
+	let res = train(model)?;
+	tracker.log_metric("train_duration_s", start.elapsed().as_secs_f64(), None)?;
+	for (key, value) in model.tracked_params()? {
+		tracker.log_param(&key, &value)?;
+	}
+	if res && model.is_trained()? {
+		tracker.log_metric("train_error", f64::from(model.calc_error(data, false, &mut core::no_array())?), None)?;
+		if test_samples > 0 {
+			tracker.log_metric("test_error", f64::from(model.calc_error(data, true, &mut core::no_array())?), None)?;
+		}
+	}
+	Ok(res)
+}
@@ -0,0 +1,324 @@
+use std::path::Path;
+
+use crate::{
+	core::{self, FileNode, FileStorage, FileStorage_Mode, Mat, Ptr, Vector},
+	Error,
+	ml::{csv_feature_names, Labels, logging::Tracker, StatModel, TrainData},
+	prelude::*,
+	Result,
+};
+
+/// Version of the bundle layout, bumped on incompatible changes
+pub const MODEL_BUNDLE_FORMAT_VERSION: i32 = 1;
+
+const BUNDLE_NODE: &str = "model_bundle";
+const MODEL_NODE: &str = "model";
+
+/// Provenance information stored alongside the model in a [ModelBundle]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ModelMetadata {
+	/// Hash of the training samples and responses, see [ModelMetadata::hash_train_data]
+	pub train_data_hash: Option<String>,
+	/// Names of the features in the order the model expects them
+	pub feature_names: Vec<String>,
+	/// Class names in the order of their codes, see [Labels]
+	pub class_labels: Vec<String>,
+	/// Arbitrary named evaluation metrics, e.g. `("test_error", 0.12)`
+	pub metrics: Vec<(String, f64)>,
+	/// Version of this crate that produced the bundle
+	pub crate_version: String,
+	/// Version of OpenCV library that produced the bundle
+	pub opencv_version: String,
+}
+
+impl ModelMetadata {
+	/// Creates metadata with crate and OpenCV versions filled in from the running environment
+	pub fn new() -> Result<Self> {
+		Ok(Self {
+			train_data_hash: None,
+			feature_names: vec![],
+			class_labels: vec![],
+			metrics: vec![],
+			crate_version: env!("CARGO_PKG_VERSION").to_string(),
+			opencv_version: core::get_version_string()?,
+		})
+	}
+
+	/// Fills `train_data_hash` from the samples and responses of `data`
+	pub fn with_train_data(mut self, data: &Ptr<dyn TrainData>) -> Result<Self> {
+		self.train_data_hash = Some(Self::hash_train_data(data)?);
+		Ok(self)
+	}
+
+	/// Fills `feature_names` from the header of the training CSV file, see [csv_feature_names]
+	pub fn with_csv_feature_names(mut self, filename: &str, response_start_idx: i32, response_end_idx: i32, delimiter: char) -> Result<Self> {
+		self.feature_names = csv_feature_names(filename, response_start_idx, response_end_idx, delimiter)?;
+		Ok(self)
+	}
+
+	/// Sets `feature_names`, in the order of the sample columns
+	pub fn with_feature_names(mut self, names: impl IntoIterator<Item=impl Into<String>>) -> Self {
+		self.feature_names = names.into_iter().map(|n| n.into()).collect();
+		self
+	}
+
+	/// Fills `class_labels` from the names of `labels`
+	pub fn with_labels(mut self, labels: &Labels) -> Self {
+		self.class_labels = labels.names().to_vec();
+		self
+	}
+
+	/// Returns the stored class labels mapping
+	pub fn labels(&self) -> Result<Labels> {
+		Labels::from_names(&self.class_labels)
+	}
+
+	/// Adds a named evaluation metric to `metrics`
+	pub fn with_metric(mut self, name: impl Into<String>, value: f64) -> Self {
+		self.metrics.push((name.into(), value));
+		self
+	}
+
+	/// Returns the value of the metric with the specified name
+	pub fn metric(&self, name: &str) -> Option<f64> {
+		self.metrics.iter()
+			.find(|(n, _)| n == name)
+			.map(|&(_, v)| v)
+	}
+
+	/// Calculates the stable hash (64-bit FNV-1a in hex) of the samples and responses of the training data
+	pub fn hash_train_data(data: &Ptr<dyn TrainData>) -> Result<String> {
+		let mut hash = Fnv1a::new();
+		for mat in &[data.get_samples()?, data.get_responses()?] {
+			hash.write_mat(mat)?;
+		}
+		Ok(format!("{:016x}", hash.finish()))
+	}
+
+	fn write(&self, fs: &mut FileStorage) -> Result<()> {
+		fs.write_i32("format_version", MODEL_BUNDLE_FORMAT_VERSION)?;
+		fs.write_str("crate_version", &self.crate_version)?;
+		fs.write_str("opencv_version", &self.opencv_version)?;
+		if let Some(train_data_hash) = &self.train_data_hash {
+			fs.write_str("train_data_hash", train_data_hash)?;
+		}
+		fs.write_str_vec("feature_names", &Vector::from_iter(self.feature_names.iter().map(|n| n.as_str())))?;
+		fs.write_str_vec("class_labels", &Vector::from_iter(self.class_labels.iter().map(|n| n.as_str())))?;
+		fs.start_write_struct("metrics", core::FileNode_SEQ, "")?;
+		for (name, value) in &self.metrics {
+			fs.start_write_struct("", core::FileNode_MAP, "")?;
+			fs.write_str("name", name)?;
+			fs.write_f64("value", *value)?;
+			fs.end_write_struct()?;
+		}
+		fs.end_write_struct()
+	}
+
+	fn read(node: &FileNode) -> Result<Self> {
+		let format_version = required_node(node, "format_version")?.to_i32()?;
+		if format_version != MODEL_BUNDLE_FORMAT_VERSION {
+			return Err(Error::new(core::StsNotImplemented, format!("Unsupported model bundle format version: {}, expected: {}", format_version, MODEL_BUNDLE_FORMAT_VERSION)));
+		}
+		let train_data_hash = node.get("train_data_hash")?;
+		let train_data_hash = if train_data_hash.is_none()? {
+			None
+		} else {
+			Some(train_data_hash.string()?)
+		};
+		let feature_names = seq_items(&node.get("feature_names")?)?
+			.into_iter()
+			.map(|n| n.string())
+			.collect::<Result<_>>()?;
+		let class_labels = seq_items(&node.get("class_labels")?)?
+			.into_iter()
+			.map(|n| n.string())
+			.collect::<Result<_>>()?;
+		let metrics = seq_items(&node.get("metrics")?)?
+			.into_iter()
+			.map(|m| Ok((required_node(&m, "name")?.string()?, required_node(&m, "value")?.real()?)))
+			.collect::<Result<_>>()?;
+		Ok(Self {
+			train_data_hash,
+			feature_names,
+			class_labels,
+			metrics,
+			crate_version: required_node(node, "crate_version")?.string()?,
+			opencv_version: required_node(node, "opencv_version")?.string()?,
+		})
+	}
+}
+
+/// Trained model together with its [ModelMetadata]
+///
+/// The bundle is stored as a single JSON document using `FileStorage`, the model payload is exactly what
+/// `Algorithm::write()` produces, so it can also be extracted and loaded by the plain OpenCV tools.
+#[derive(Debug)]
+pub struct ModelBundle<M> {
+	pub model: M,
+	pub metadata: ModelMetadata,
+}
+
+impl<M: StatModel> ModelBundle<M> {
+	#[inline]
+	pub fn new(model: M, metadata: ModelMetadata) -> Self {
+		Self { model, metadata }
+	}
+
+	/// Saves the bundle to the specified file
+	pub fn save(&self, filename: &str) -> Result<()> {
+		let mut fs = FileStorage::new(filename, FileStorage_Mode::WRITE as i32 | FileStorage_Mode::FORMAT_JSON as i32, "")?;
+		self.write(&mut fs)?;
+		fs.release()
+	}
+
+	/// Same as [save](Self::save), but also records the metadata metrics and the saved file as `model_bundle` artifact
+	/// to `tracker`
+	pub fn save_tracked(&self, filename: &str, tracker: &mut dyn Tracker) -> Result<()> {
+		self.save(filename)?;
+		if let Some(train_data_hash) = &self.metadata.train_data_hash {
+			tracker.log_param("train_data_hash", train_data_hash)?;
+		}
+		for (key, value) in &self.metadata.metrics {
+			tracker.log_metric(key, *value, None)?;
+		}
+		tracker.log_artifact("model_bundle", Path::new(filename))
+	}
+
+	/// Serializes the bundle into a JSON string
+	pub fn save_to_string(&self) -> Result<String> {
+		let mut fs = FileStorage::new(
+			".json",
+			FileStorage_Mode::WRITE as i32 | FileStorage_Mode::MEMORY as i32 | FileStorage_Mode::FORMAT_JSON as i32,
+			"",
+		)?;
+		self.write(&mut fs)?;
+		fs.release_and_get_string()
+	}
+
+	/// Loads the bundle from the specified file
+	///
+	/// `model` must be an empty model of the matching type (e.g. `<dyn SVM>::create()?`), it's populated from the bundle
+	/// and then [validated](ModelBundle::validate).
+	pub fn load(filename: &str, model: M) -> Result<Self> {
+		let fs = FileStorage::new(filename, FileStorage_Mode::READ as i32, "")?;
+		Self::read(&fs, model)
+	}
+
+	/// Loads the bundle from the string produced by [ModelBundle::save_to_string]
+	pub fn load_from_str(s: &str, model: M) -> Result<Self> {
+		let fs = FileStorage::new(s, FileStorage_Mode::READ as i32 | FileStorage_Mode::MEMORY as i32, "")?;
+		Self::read(&fs, model)
+	}
+
+	/// Checks that the loaded model is consistent with the stored metadata
+	pub fn validate(&self) -> Result<()> {
+		if !self.model.is_trained()? {
+			return Err(Error::new(core::StsError, "Model in the bundle is not trained"));
+		}
+		let var_count = self.model.get_var_count()?;
+		if !self.metadata.feature_names.is_empty() && self.metadata.feature_names.len() != var_count as usize {
+			return Err(Error::new(core::StsUnmatchedSizes, format!(
+				"Model expects: {} features, but bundle metadata lists: {} feature names",
+				var_count,
+				self.metadata.feature_names.len(),
+			)));
+		}
+		let current_opencv_major = core::CV_VERSION_MAJOR.to_string();
+		let bundle_opencv_major = self.metadata.opencv_version.split('.').next().unwrap_or_default();
+		if bundle_opencv_major != current_opencv_major {
+			return Err(Error::new(core::StsError, format!(
+				"Model bundle was created with OpenCV: {}, which is incompatible with the current OpenCV: {}",
+				self.metadata.opencv_version,
+				core::CV_VERSION,
+			)));
+		}
+		Ok(())
+	}
+
+	/// Writes the bundle as `model_bundle` node of the currently open struct of `fs`
+	pub(crate) fn write(&self, fs: &mut FileStorage) -> Result<()> {
+		fs.start_write_struct(BUNDLE_NODE, core::FileNode_MAP, "")?;
+		self.metadata.write(fs)?;
+		fs.start_write_struct(MODEL_NODE, core::FileNode_MAP, "")?;
+		self.model.write(fs)?;
+		fs.end_write_struct()?;
+		fs.end_write_struct()
+	}
+
+	fn read(fs: &FileStorage, model: M) -> Result<Self> {
+		if !fs.is_opened()? {
+			return Err(Error::new(core::StsError, "Can't open model bundle"));
+		}
+		Self::read_node(&fs.root(0)?, model)
+	}
+
+	/// Reads the bundle from the `model_bundle` child of `parent` node
+	pub(crate) fn read_node(parent: &FileNode, mut model: M) -> Result<Self> {
+		let bundle = parent.get(BUNDLE_NODE)?;
+		if bundle.is_none()? {
+			return Err(Error::new(core::StsParseError, "Input is not a model bundle"));
+		}
+		let metadata = ModelMetadata::read(&bundle)?;
+		model.read(&required_node(&bundle, MODEL_NODE)?)?;
+		let out = Self { model, metadata };
+		out.validate()?;
+		Ok(out)
+	}
+}
+
+fn required_node(node: &FileNode, name: &str) -> Result<FileNode> {
+	let out = node.get(name)?;
+	if out.is_none()? {
+		Err(Error::new(core::StsParseError, format!("Model bundle is missing required field: {}", name)))
+	} else {
+		Ok(out)
+	}
+}
+
+fn seq_items(node: &FileNode) -> Result<Vec<FileNode>> {
+	if node.is_none()? {
+		Ok(vec![])
+	} else {
+		(0..node.size()? as i32)
+			.map(|i| node.at(i))
+			.collect()
+	}
+}
+
+struct Fnv1a(u64);
+
+impl Fnv1a {
+	const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+	const PRIME: u64 = 0x100000001b3;
+
+	fn new() -> Self {
+		Self(Self::OFFSET_BASIS)
+	}
+
+	fn write(&mut self, bytes: &[u8]) {
+		for &b in bytes {
+			self.0 ^= u64::from(b);
+			self.0 = self.0.wrapping_mul(Self::PRIME);
+		}
+	}
+
+	fn write_mat(&mut self, mat: &Mat) -> Result<()> {
+		self.write(&mat.typ().to_le_bytes());
+		self.write(&mat.rows().to_le_bytes());
+		self.write(&mat.cols().to_le_bytes());
+		// e.g. the missing responses, there is no data to hash
+		if mat.empty() {
+			return Ok(());
+		}
+		if mat.is_continuous() {
+			self.write(mat.data_bytes()?);
+		} else {
+			self.write(mat.try_clone()?.data_bytes()?);
+		}
+		Ok(())
+	}
+
+	fn finish(&self) -> u64 {
+		self.0
+	}
+}
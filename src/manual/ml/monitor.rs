@@ -0,0 +1,185 @@
+//! Data drift detection between the reference training data and the incoming samples
+
+use crate::{
+	core::{self, Mat, Ptr},
+	Error,
+	ml::{SampleTypes, TrainData},
+	prelude::*,
+	Result,
+};
+
+/// Default number of quantile bins used for the population stability index
+pub const DRIFT_DEFAULT_BINS: usize = 10;
+
+/// Probability floor for empty bins to keep the population stability index finite
+const PSI_EPSILON: f64 = 1e-4;
+
+/// Drift scores of a single feature
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FeatureDrift {
+	/// Index of the feature (sample column)
+	pub feature: usize,
+	/// Population stability index, values above 0.25 are usually considered a significant shift
+	pub psi: f64,
+	/// Two-sample Kolmogorov-Smirnov statistic (maximum distance between empirical CDFs)
+	pub ks_statistic: f64,
+	/// Asymptotic p-value of the Kolmogorov-Smirnov test, low values indicate that the distributions differ
+	pub ks_p_value: f64,
+}
+
+/// Per-feature drift detector holding the reference distribution
+#[derive(Clone, Debug)]
+pub struct DriftMonitor {
+	/// Sorted finite reference values for every feature
+	reference: Vec<Vec<f32>>,
+	bins: usize,
+}
+
+impl DriftMonitor {
+	/// Creates a monitor from the training samples of `data`
+	pub fn from_train_data(data: &Ptr<dyn TrainData>) -> Result<Self> {
+		Self::from_samples(&data.train_samples_with_layout(SampleTypes::ROW_SAMPLE, true, true)?)
+	}
+
+	/// Creates a monitor from the reference samples, one sample per row
+	pub fn from_samples(samples: &Mat) -> Result<Self> {
+		let mut reference = columns(samples)?;
+		reference.iter_mut().for_each(|col| col.sort_unstable_by(|a, b| a.partial_cmp(b).expect("Values are finite")));
+		Ok(Self { reference, bins: DRIFT_DEFAULT_BINS })
+	}
+
+	/// Sets the number of quantile bins used for the population stability index
+	pub fn with_bins(mut self, bins: usize) -> Self {
+		self.bins = bins.max(1);
+		self
+	}
+
+	#[inline]
+	pub fn feature_count(&self) -> usize {
+		self.reference.len()
+	}
+
+	/// Calculates drift scores for every feature of the `batch` (one sample per row) against the reference
+	pub fn drift(&self, batch: &Mat) -> Result<Vec<FeatureDrift>> {
+		if batch.cols() as usize != self.feature_count() {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("Batch has: {} features, but reference has: {}", batch.cols(), self.feature_count())));
+		}
+		Ok(columns(batch)?.into_iter()
+			.zip(&self.reference)
+			.enumerate()
+			.map(|(feature, (mut current, reference))| {
+				current.sort_unstable_by(|a, b| a.partial_cmp(b).expect("Values are finite"));
+				let ks_statistic = ks_statistic(reference, &current);
+				FeatureDrift {
+					feature,
+					psi: population_stability_index(reference, &current, self.bins),
+					ks_statistic,
+					ks_p_value: ks_p_value(ks_statistic, reference.len(), current.len()),
+				}
+			})
+			.collect()
+		)
+	}
+}
+
+/// Calculates the population stability index of `current` relative to `reference`
+///
+/// Bins are the quantiles of the `reference` distribution, both slices must be sorted.
+pub fn population_stability_index(reference: &[f32], current: &[f32], bins: usize) -> f64 {
+	if reference.is_empty() || current.is_empty() {
+		return 0.;
+	}
+	let bins = bins.max(1);
+	let edges = (1..bins)
+		.map(|i| reference[(i * reference.len() / bins).min(reference.len() - 1)])
+		.collect::<Vec<_>>();
+	let reference_hist = histogram(reference, &edges);
+	let current_hist = histogram(current, &edges);
+	reference_hist.into_iter()
+		.zip(current_hist)
+		.map(|(r, c)| {
+			let r = (r as f64 / reference.len() as f64).max(PSI_EPSILON);
+			let c = (c as f64 / current.len() as f64).max(PSI_EPSILON);
+			(c - r) * (c / r).ln()
+		})
+		.sum()
+}
+
+/// Calculates the two-sample Kolmogorov-Smirnov statistic, both slices must be sorted
+pub fn ks_statistic(a: &[f32], b: &[f32]) -> f64 {
+	if a.is_empty() || b.is_empty() {
+		return 0.;
+	}
+	let (n_a, n_b) = (a.len() as f64, b.len() as f64);
+	let (mut i, mut j) = (0, 0);
+	let mut out = 0f64;
+	while i < a.len() && j < b.len() {
+		let x = a[i].min(b[j]);
+		while i < a.len() && a[i] <= x {
+			i += 1;
+		}
+		while j < b.len() && b[j] <= x {
+			j += 1;
+		}
+		out = out.max((i as f64 / n_a - j as f64 / n_b).abs());
+	}
+	out
+}
+
+/// Calculates the asymptotic p-value for the two-sample Kolmogorov-Smirnov statistic `d` with sample sizes `n` and `m`
+pub fn ks_p_value(d: f64, n: usize, m: usize) -> f64 {
+	if n == 0 || m == 0 {
+		return 1.;
+	}
+	let en = (n as f64 * m as f64 / (n + m) as f64).sqrt();
+	let lambda = (en + 0.12 + 0.11 / en) * d;
+	if lambda < 1e-3 {
+		return 1.;
+	}
+	let mut sum = 0.;
+	let mut sign = 1.;
+	for j in 1..=100 {
+		let term = sign * (-2. * (j as f64 * lambda).powi(2)).exp();
+		sum += term;
+		if term.abs() < 1e-10 {
+			break;
+		}
+		sign = -sign;
+	}
+	(2. * sum).clamp(0., 1.)
+}
+
+fn histogram(sorted: &[f32], edges: &[f32]) -> Vec<usize> {
+	let mut out = vec![0; edges.len() + 1];
+	let mut bin = 0;
+	for &v in sorted {
+		while bin < edges.len() && v >= edges[bin] {
+			bin += 1;
+		}
+		out[bin] += 1;
+	}
+	out
+}
+
+/// Extracts finite values of every column of the sample matrix as `f32`
+fn columns(samples: &Mat) -> Result<Vec<Vec<f32>>> {
+	if samples.channels() != 1 {
+		return Err(Error::new(core::StsBadArg, format!("Samples must be single channel, got: {} channels", samples.channels())));
+	}
+	let mut converted = Mat::default();
+	let samples = if samples.typ() == f32::typ() {
+		samples
+	} else {
+		samples.convert_to(&mut converted, f32::typ(), 1., 0.)?;
+		&converted
+	};
+	let mut out = vec![Vec::with_capacity(samples.rows() as usize); samples.cols() as usize];
+	for row_n in 0..samples.rows() {
+		for (col, &v) in out.iter_mut().zip(samples.at_row::<f32>(row_n)?) {
+			if v.is_finite() {
+				col.push(v);
+			}
+		}
+	}
+	Ok(out)
+}
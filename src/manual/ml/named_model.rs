@@ -0,0 +1,205 @@
+use std::{
+	collections::{BTreeMap, HashMap},
+	fs::File,
+	io::{BufRead, BufReader},
+};
+
+use crate::{
+	core::{self, Mat},
+	Error,
+	ml::{ModelBundle, StatModel},
+	prelude::*,
+	Result,
+};
+
+/// Source of named feature values that can be fed to [NamedModel]
+///
+/// It's implemented for maps from feature name to value, for a custom struct implement it by reporting every field:
+/// ```ignore
+/// impl NamedFeatures for Flower {
+///     fn for_each_feature(&self, f: &mut dyn FnMut(&str, f32)) {
+///         f("petal_length", self.petal_length);
+///         f("petal_width", self.petal_width);
+///     }
+/// }
+/// ```
+pub trait NamedFeatures {
+	/// Calls `f` for every feature with its name and value
+	fn for_each_feature(&self, f: &mut dyn FnMut(&str, f32));
+}
+
+impl<K: AsRef<str>, S> NamedFeatures for HashMap<K, f32, S> {
+	fn for_each_feature(&self, f: &mut dyn FnMut(&str, f32)) {
+		self.iter().for_each(|(name, &value)| f(name.as_ref(), value))
+	}
+}
+
+impl<K: AsRef<str>> NamedFeatures for BTreeMap<K, f32> {
+	fn for_each_feature(&self, f: &mut dyn FnMut(&str, f32)) {
+		self.iter().for_each(|(name, &value)| f(name.as_ref(), value))
+	}
+}
+
+impl<K: AsRef<str>> NamedFeatures for [(K, f32)] {
+	fn for_each_feature(&self, f: &mut dyn FnMut(&str, f32)) {
+		self.iter().for_each(|(name, value)| f(name.as_ref(), *value))
+	}
+}
+
+impl<T: NamedFeatures + ?Sized> NamedFeatures for &T {
+	#[inline]
+	fn for_each_feature(&self, f: &mut dyn FnMut(&str, f32)) {
+		(*self).for_each_feature(f)
+	}
+}
+
+/// Reads the input variable names from the header line of the CSV file loaded with `TrainData::load_from_csv()`
+///
+/// `response_start_idx` and `response_end_idx` have the same meaning as in `load_from_csv()`, the response columns are
+/// skipped. `TrainData::get_names()` can't be used for this, it returns the values of the categorical variables.
+pub fn csv_feature_names(filename: &str, response_start_idx: i32, response_end_idx: i32, delimiter: char) -> Result<Vec<String>> {
+	let mut header = String::new();
+	File::open(filename)
+		.and_then(|file| BufReader::new(file).read_line(&mut header))
+		.map_err(|e| Error::new(core::StsError, format!("Can't read CSV header: {}: {}", filename, e)))?;
+	let columns = header.trim_end_matches(&['\r', '\n'][..])
+		.split(delimiter)
+		.map(|name| name.trim().to_string())
+		.collect::<Vec<_>>();
+	if columns.iter().all(String::is_empty) {
+		return Err(Error::new(core::StsParseError, format!("CSV file has no header: {}", filename)));
+	}
+	let col_count = columns.len() as i32;
+	let responses = match response_start_idx {
+		-2 => 0..0,
+		start => {
+			let start = if start < 0 { col_count - 1 } else { start };
+			let end = if response_end_idx < 0 { start + 1 } else { response_end_idx };
+			if start >= end || end > col_count {
+				return Err(Error::new(core::StsOutOfRange, format!("Response columns: {}..{} are out of the: {} CSV columns", start, end, col_count)));
+			}
+			start..end
+		}
+	};
+	Ok(columns.into_iter()
+		.enumerate()
+		.filter(|(i, _)| !responses.contains(&(*i as i32)))
+		.map(|(_, name)| name)
+		.collect())
+}
+
+/// Model wrapper that binds the sample columns to feature names
+///
+/// Samples are assembled from [NamedFeatures] in the order the model was trained with, missing or unknown feature
+/// names produce an error instead of silently shifting the columns.
+#[derive(Debug)]
+pub struct NamedModel<M> {
+	model: M,
+	feature_names: Vec<String>,
+	feature_index: HashMap<String, usize>,
+}
+
+impl<M: StatModel> NamedModel<M> {
+	/// Creates a new wrapper, `feature_names` must be unique and for a trained model match its variable count
+	pub fn new(model: M, feature_names: impl IntoIterator<Item=impl Into<String>>) -> Result<Self> {
+		let feature_names = feature_names.into_iter().map(|n| n.into()).collect::<Vec<String>>();
+		let mut feature_index = HashMap::with_capacity(feature_names.len());
+		for (i, name) in feature_names.iter().enumerate() {
+			if feature_index.insert(name.clone(), i).is_some() {
+				return Err(Error::new(core::StsBadArg, format!("Duplicate feature name: {}", name)));
+			}
+		}
+		if model.is_trained()? {
+			let var_count = model.get_var_count()?;
+			if var_count as usize != feature_names.len() {
+				return Err(Error::new(core::StsUnmatchedSizes, format!("Model expects: {} features, but: {} names were provided", var_count, feature_names.len())));
+			}
+		}
+		Ok(Self { model, feature_names, feature_index })
+	}
+
+	/// Creates a new wrapper taking the feature names from the header of the training CSV file, see [csv_feature_names]
+	#[inline]
+	pub fn from_csv_header(model: M, filename: &str, response_start_idx: i32, response_end_idx: i32, delimiter: char) -> Result<Self> {
+		Self::new(model, csv_feature_names(filename, response_start_idx, response_end_idx, delimiter)?)
+	}
+
+	/// Creates a new wrapper taking the model and the feature names from the [ModelBundle]
+	#[inline]
+	pub fn from_bundle(bundle: ModelBundle<M>) -> Result<Self> {
+		Self::new(bundle.model, bundle.metadata.feature_names)
+	}
+
+	#[inline]
+	pub fn feature_names(&self) -> &[String] {
+		&self.feature_names
+	}
+
+	#[inline]
+	pub fn model(&self) -> &M {
+		&self.model
+	}
+
+	#[inline]
+	pub fn model_mut(&mut self) -> &mut M {
+		&mut self.model
+	}
+
+	#[inline]
+	pub fn into_inner(self) -> M {
+		self.model
+	}
+
+	/// Assembles a single `1xN` `CV_32F` sample row from the named features
+	pub fn sample(&self, input: &(impl NamedFeatures + ?Sized)) -> Result<Mat> {
+		self.samples(&[input])
+	}
+
+	/// Assembles `CV_32F` sample matrix with one row per input
+	pub fn samples(&self, inputs: &[impl NamedFeatures]) -> Result<Mat> {
+		let mut out = Mat::new_rows_cols_with_default(inputs.len() as i32, self.feature_names.len() as i32, f32::typ(), core::Scalar::all(0.))?;
+		for (row_n, input) in inputs.iter().enumerate() {
+			self.fill_row(input, out.at_row_mut(row_n as i32)?)?;
+		}
+		Ok(out)
+	}
+
+	/// Predicts the response for a single sample
+	pub fn predict(&self, input: &(impl NamedFeatures + ?Sized)) -> Result<f32> {
+		self.model.predict(&self.sample(input)?, &mut core::no_array(), 0)
+	}
+
+	/// Predicts the responses for multiple samples, returns a column of results
+	pub fn predict_batch(&self, inputs: &[impl NamedFeatures], flags: i32) -> Result<Mat> {
+		let mut results = Mat::default();
+		self.model.predict(&self.samples(inputs)?, &mut results, flags)?;
+		Ok(results)
+	}
+
+	fn fill_row(&self, input: &(impl NamedFeatures + ?Sized), row: &mut [f32]) -> Result<()> {
+		let mut seen = vec![false; row.len()];
+		let mut unknown = vec![];
+		input.for_each_feature(&mut |name, value| {
+			match self.feature_index.get(name) {
+				Some(&i) => {
+					row[i] = value;
+					seen[i] = true;
+				}
+				None => unknown.push(name.to_string()),
+			}
+		});
+		if !unknown.is_empty() {
+			return Err(Error::new(core::StsBadArg, format!("Unknown features: {}", unknown.join(", "))));
+		}
+		let missing = seen.iter()
+			.zip(&self.feature_names)
+			.filter(|(&seen, _)| !seen)
+			.map(|(_, name)| name.as_str())
+			.collect::<Vec<_>>();
+		if missing.is_empty() {
+			Ok(())
+		} else {
+			Err(Error::new(core::StsBadArg, format!("Missing features: {}", missing.join(", "))))
+		}
+	}
+}
@@ -0,0 +1,418 @@
+//! End-to-end image classification training from a folder structure
+//!
+//! Images are expected to be sorted into subfolders named after the classes:
+//! ```text
+//! dataset/
+//!   cat/001.jpg, 002.jpg, ...
+//!   dog/001.jpg, 002.jpg, ...
+//! ```
+//! [train_image_classifier] extracts the features of every image, holds out a part of every class for the evaluation,
+//! trains the model and returns an [ImageClassifier] that can be saved and later loaded to classify new images:
+//! ```no_run
+//! # use opencv::{imgcodecs, ml::quick::{self, FeatureExtractor, ModelKind}, Result};
+//! # fn main() -> Result<()> {
+//! let classifier = quick::train_image_classifier("dataset", FeatureExtractor::Hog, ModelKind::Svm)?;
+//! println!("test accuracy: {}", classifier.test_accuracy());
+//! classifier.save("classifier.json")?;
+//! let classifier = quick::ImageClassifier::load("classifier.json")?;
+//! let class = classifier.classify(&imgcodecs::imread("cat.jpg", imgcodecs::IMREAD_COLOR)?)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+	fs,
+	path::{Path, PathBuf},
+};
+
+use crate::{
+	core::{self, FileStorage, FileStorage_Mode, Mat, Ptr, Size, TermCriteria, Vector},
+	Error,
+	features2d::{self, BFMatcher, BOWKMeansTrainer, ORB_CreateBuilder, ORB_ScoreType},
+	imgcodecs,
+	imgproc,
+	ml::{Labels, ModelBundle, ModelMetadata, SampleTypes, SVM, SVM_KernelTypes, SVM_Types},
+	objdetect::HOGDescriptor,
+	prelude::*,
+	Result,
+};
+
+/// Version of the saved classifier layout, bumped on incompatible changes
+pub const IMAGE_CLASSIFIER_FORMAT_VERSION: i32 = 1;
+
+/// Default fraction of the images of every class held out for the evaluation
+pub const QUICK_DEFAULT_TEST_FRACTION: f64 = 0.2;
+
+/// Default number of the visual words for [FeatureExtractor::OrbBoVW]
+pub const QUICK_DEFAULT_VOCABULARY_SIZE: i32 = 100;
+
+const IMAGE_EXTENSIONS: &[&str] = &["bmp", "jpeg", "jpg", "pbm", "pgm", "png", "ppm", "tif", "tiff", "webp"];
+
+/// Size the images are resized to for the HOG features, it's the default `HOGDescriptor` window
+const HOG_WINDOW: Size = Size { width: 64, height: 128 };
+
+/// Image features used for the classification
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeatureExtractor {
+	/// Histogram of oriented gradients of the whole image resized to 64x128, suits the objects with a stable shape
+	Hog,
+	/// Bag of visual words built from the ORB descriptors, tolerates the position and scale changes of the object
+	OrbBoVW,
+}
+
+impl FeatureExtractor {
+	fn name(self) -> &'static str {
+		match self {
+			FeatureExtractor::Hog => "hog",
+			FeatureExtractor::OrbBoVW => "orb_bovw",
+		}
+	}
+
+	fn from_name(name: &str) -> Result<Self> {
+		match name {
+			"hog" => Ok(FeatureExtractor::Hog),
+			"orb_bovw" => Ok(FeatureExtractor::OrbBoVW),
+			_ => Err(Error::new(core::StsParseError, format!("Unknown feature extractor: {}", name))),
+		}
+	}
+}
+
+/// Model trained on the extracted features
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ModelKind {
+	/// C-SVM, linear kernel for [FeatureExtractor::Hog] and histogram intersection kernel for
+	/// [FeatureExtractor::OrbBoVW]
+	Svm,
+}
+
+/// Feature extraction state shared between the training and the classification
+struct Features {
+	extractor: FeatureExtractor,
+	/// Visual words for [FeatureExtractor::OrbBoVW], one per row
+	vocabulary: Mat,
+}
+
+impl Features {
+	fn extract(&self, image: &Mat) -> Result<Mat> {
+		let gray = grayscale(image)?;
+		match self.extractor {
+			FeatureExtractor::Hog => {
+				let mut resized = Mat::default();
+				imgproc::resize(&gray, &mut resized, HOG_WINDOW, 0., 0., imgproc::INTER_AREA)?;
+				let hog = HOGDescriptor::default()?;
+				let mut descriptors = Vector::<f32>::new();
+				hog.compute(&resized, &mut descriptors, Size::default(), Size::default(), &Vector::new())?;
+				Mat::from_slice(descriptors.as_slice())
+			}
+			FeatureExtractor::OrbBoVW => {
+				let descriptors = orb_descriptors(&gray)?;
+				let words = self.vocabulary.rows();
+				let mut out = Mat::new_rows_cols_with_default(1, words, f32::typ(), core::Scalar::all(0.))?;
+				if descriptors.empty() {
+					return Ok(out);
+				}
+				let mut matches = Vector::new();
+				BFMatcher::new(core::NORM_L2, false)?.train_match(&descriptors, &self.vocabulary, &mut matches, &core::no_array())?;
+				let hist = out.data_typed_mut::<f32>()?;
+				let weight = 1. / matches.len() as f32;
+				for m in matches {
+					hist[m.train_idx as usize] += weight;
+				}
+				Ok(out)
+			}
+		}
+	}
+}
+
+fn grayscale(image: &Mat) -> Result<Mat> {
+	let code = match image.channels() {
+		1 => return Mat::copy(image),
+		3 => imgproc::COLOR_BGR2GRAY,
+		4 => imgproc::COLOR_BGRA2GRAY,
+		channels => return Err(Error::new(core::StsBadArg, format!("Unsupported number of image channels: {}", channels))),
+	};
+	let mut out = Mat::default();
+	imgproc::cvt_color(image, &mut out, code, 0)?;
+	Ok(out)
+}
+
+/// ORB descriptors of the image converted to `CV_32F` for the clustering
+fn orb_descriptors(gray: &Mat) -> Result<Mat> {
+	let mut orb = ORB_CreateBuilder::new(ORB_ScoreType::HARRIS_SCORE).call()?;
+	let mut keypoints = Vector::new();
+	let mut descriptors = Mat::default();
+	orb.detect_and_compute(gray, &core::no_array(), &mut keypoints, &mut descriptors, false)?;
+	let mut out = Mat::default();
+	if !descriptors.empty() {
+		descriptors.convert_to(&mut out, f32::typ(), 1., 0.)?;
+	}
+	Ok(out)
+}
+
+/// Image files of every class subfolder of `dir`, classes and files are sorted by name
+fn dataset_images(dir: &Path) -> Result<Vec<(String, Vec<PathBuf>)>> {
+	let io_err = |path: &Path, e: std::io::Error| Error::new(core::StsError, format!("Can't read dataset folder: {}: {}", path.display(), e));
+	let mut out = vec![];
+	for entry in fs::read_dir(dir).map_err(|e| io_err(dir, e))? {
+		let class_dir = entry.map_err(|e| io_err(dir, e))?.path();
+		if !class_dir.is_dir() {
+			continue;
+		}
+		let class = class_dir.file_name()
+			.and_then(|n| n.to_str())
+			.ok_or_else(|| Error::new(core::StsBadArg, format!("Class folder name is not valid UTF-8: {}", class_dir.display())))?
+			.to_string();
+		let mut images = vec![];
+		for entry in fs::read_dir(&class_dir).map_err(|e| io_err(&class_dir, e))? {
+			let path = entry.map_err(|e| io_err(&class_dir, e))?.path();
+			let is_image = matches!(
+				path.extension().and_then(|e| e.to_str()),
+				Some(ext) if IMAGE_EXTENSIONS.contains(&ext.to_ascii_lowercase().as_str())
+			);
+			if is_image && path.is_file() {
+				images.push(path);
+			}
+		}
+		if !images.is_empty() {
+			images.sort();
+			out.push((class, images));
+		}
+	}
+	out.sort_by(|(a, _), (b, _)| a.cmp(b));
+	if out.len() < 2 {
+		return Err(Error::new(core::StsBadArg, format!(
+			"Dataset folder: {} must contain at least 2 class subfolders with images, found: {}",
+			dir.display(),
+			out.len(),
+		)));
+	}
+	Ok(out)
+}
+
+fn read_image(path: &Path) -> Result<Mat> {
+	let out = imgcodecs::imread(&path.to_string_lossy(), imgcodecs::IMREAD_GRAYSCALE)?;
+	if out.empty() {
+		return Err(Error::new(core::StsError, format!("Can't read image: {}", path.display())));
+	}
+	Ok(out)
+}
+
+/// Fraction of the `samples` predicted as `expected` by the model
+fn accuracy(svm: &Ptr<dyn SVM>, samples: &Mat, expected: &[i32]) -> Result<f64> {
+	if expected.is_empty() {
+		return Ok(f64::NAN);
+	}
+	let mut results = Mat::default();
+	svm.predict(samples, &mut results, 0)?;
+	let correct = results.data_typed::<f32>()?
+		.iter()
+		.zip(expected)
+		.filter(|(&r, &e)| r.round() as i32 == e)
+		.count();
+	Ok(correct as f64 / expected.len() as f64)
+}
+
+/// Configurable version of [train_image_classifier]
+#[derive(Clone, Debug)]
+pub struct ImageClassifierTrainer {
+	extractor: FeatureExtractor,
+	model: ModelKind,
+	test_fraction: f64,
+	vocabulary_size: i32,
+	c: f64,
+}
+
+impl ImageClassifierTrainer {
+	pub fn new(extractor: FeatureExtractor, model: ModelKind) -> Self {
+		Self { extractor, model, test_fraction: QUICK_DEFAULT_TEST_FRACTION, vocabulary_size: QUICK_DEFAULT_VOCABULARY_SIZE, c: 1. }
+	}
+
+	/// Sets the fraction of the images of every class held out for the evaluation, `0` trains on all images
+	pub fn with_test_fraction(mut self, test_fraction: f64) -> Self {
+		self.test_fraction = test_fraction.clamp(0., 0.9);
+		self
+	}
+
+	/// Sets the number of the visual words for [FeatureExtractor::OrbBoVW]
+	pub fn with_vocabulary_size(mut self, vocabulary_size: i32) -> Self {
+		self.vocabulary_size = vocabulary_size.max(2);
+		self
+	}
+
+	/// Sets the SVM `C` parameter
+	pub fn with_c(mut self, c: f64) -> Self {
+		self.c = c;
+		self
+	}
+
+	/// Trains the classifier on the images in the class subfolders of `dir`
+	pub fn train(&self, dir: impl AsRef<Path>) -> Result<ImageClassifier> {
+		let dataset = dataset_images(dir.as_ref())?;
+		let mut labels = Labels::new();
+		// (image, code, is_test)
+		let mut images = vec![];
+		for (class, paths) in &dataset {
+			let code = labels.encode(class);
+			let count = paths.len();
+			let test_count = (count as f64 * self.test_fraction).round() as usize;
+			for (i, path) in paths.iter().enumerate() {
+				// spreads exactly `test_count` test images evenly over the sorted files
+				let is_test = (i + 1) * test_count / count > i * test_count / count;
+				images.push((read_image(path)?, code, is_test));
+			}
+		}
+
+		let vocabulary = match self.extractor {
+			FeatureExtractor::Hog => Mat::default(),
+			FeatureExtractor::OrbBoVW => {
+				let mut trainer = BOWKMeansTrainer::new(self.vocabulary_size, TermCriteria::both(100, 1e-3), 3, core::KMEANS_PP_CENTERS)?;
+				let mut descriptor_count = 0;
+				for (image, _, is_test) in &images {
+					let descriptors = orb_descriptors(image)?;
+					if !is_test && !descriptors.empty() {
+						descriptor_count += descriptors.rows();
+						trainer.add(&descriptors)?;
+					}
+				}
+				if descriptor_count < self.vocabulary_size {
+					return Err(Error::new(core::StsBadArg, format!(
+						"Training images have: {} ORB descriptors in total, that's not enough for the vocabulary of: {} words",
+						descriptor_count,
+						self.vocabulary_size,
+					)));
+				}
+				features2d::BOWKMeansTrainerTraitConst::cluster(&trainer)?
+			}
+		};
+		let features = Features { extractor: self.extractor, vocabulary };
+
+		let mut train_samples = Mat::default();
+		let mut train_codes = vec![];
+		let mut test_samples = Mat::default();
+		let mut test_codes = vec![];
+		for (image, code, is_test) in &images {
+			let sample = features.extract(image)?;
+			let (samples, codes) = if *is_test { (&mut test_samples, &mut test_codes) } else { (&mut train_samples, &mut train_codes) };
+			samples.push_back(&sample)?;
+			codes.push(*code);
+		}
+
+		let mut svm = match self.model {
+			ModelKind::Svm => {
+				let mut svm = <dyn SVM>::create()?;
+				svm.set_type(SVM_Types::C_SVC as i32)?;
+				svm.set_kernel(match self.extractor {
+					FeatureExtractor::Hog => SVM_KernelTypes::LINEAR,
+					FeatureExtractor::OrbBoVW => SVM_KernelTypes::INTER,
+				} as i32)?;
+				svm.set_c(self.c)?;
+				svm
+			}
+		};
+		svm.train(&train_samples, SampleTypes::ROW_SAMPLE as i32, &Mat::from_exact_iter(train_codes.iter().copied())?)?;
+
+		let train_accuracy = accuracy(&svm, &train_samples, &train_codes)?;
+		let test_accuracy = accuracy(&svm, &test_samples, &test_codes)?;
+		let mut metadata = ModelMetadata::new()?
+			.with_labels(&labels)
+			.with_metric("train_accuracy", train_accuracy);
+		if !test_codes.is_empty() {
+			metadata = metadata.with_metric("test_accuracy", test_accuracy);
+		}
+		Ok(ImageClassifier { features, labels, bundle: ModelBundle::new(svm, metadata) })
+	}
+}
+
+/// Trains an image classifier on the images in the class subfolders of `dir` with the default settings
+///
+/// See [ImageClassifierTrainer] to change the held-out split, vocabulary size or model parameters.
+#[inline]
+pub fn train_image_classifier(dir: impl AsRef<Path>, extractor: FeatureExtractor, model: ModelKind) -> Result<ImageClassifier> {
+	ImageClassifierTrainer::new(extractor, model).train(dir)
+}
+
+/// Trained image classifier together with its feature extraction settings
+pub struct ImageClassifier {
+	features: Features,
+	labels: Labels,
+	bundle: ModelBundle<Ptr<dyn SVM>>,
+}
+
+impl ImageClassifier {
+	/// Returns the class name of the `image` (grayscale, BGR or BGRA)
+	pub fn classify(&self, image: &Mat) -> Result<&str> {
+		let sample = self.features.extract(image)?;
+		let response = self.bundle.model.predict(&sample, &mut core::no_array(), 0)?;
+		self.labels.decode(response)
+	}
+
+	#[inline]
+	pub fn extractor(&self) -> FeatureExtractor {
+		self.features.extractor
+	}
+
+	#[inline]
+	pub fn labels(&self) -> &Labels {
+		&self.labels
+	}
+
+	/// Fraction of the held-out images classified correctly, NaN if no images were held out
+	#[inline]
+	pub fn test_accuracy(&self) -> f64 {
+		self.bundle.metadata.metric("test_accuracy").unwrap_or(f64::NAN)
+	}
+
+	/// Fraction of the training images classified correctly
+	#[inline]
+	pub fn train_accuracy(&self) -> f64 {
+		self.bundle.metadata.metric("train_accuracy").unwrap_or(f64::NAN)
+	}
+
+	/// Returns the model with its metadata
+	#[inline]
+	pub fn bundle(&self) -> &ModelBundle<Ptr<dyn SVM>> {
+		&self.bundle
+	}
+
+	/// Saves the classifier to the specified file, the format is selected by the extension (`.json`, `.yml` or `.xml`)
+	pub fn save(&self, filename: &str) -> Result<()> {
+		let mut fs = FileStorage::new(filename, FileStorage_Mode::WRITE as i32, "")?;
+		fs.write_i32("image_classifier_format_version", IMAGE_CLASSIFIER_FORMAT_VERSION)?;
+		fs.write_str("extractor", self.features.extractor.name())?;
+		if !self.features.vocabulary.empty() {
+			fs.write_mat("vocabulary", &self.features.vocabulary)?;
+		}
+		self.bundle.write(&mut fs)?;
+		fs.release()
+	}
+
+	/// Loads the classifier saved with [save](Self::save)
+	pub fn load(filename: &str) -> Result<Self> {
+		let fs = FileStorage::new(filename, FileStorage_Mode::READ as i32, "")?;
+		if !fs.is_opened()? {
+			return Err(Error::new(core::StsError, format!("Can't open image classifier: {}", filename)));
+		}
+		let root = fs.root(0)?;
+		let format_version = root.get("image_classifier_format_version")?;
+		if format_version.is_none()? {
+			return Err(Error::new(core::StsParseError, "Input is not an image classifier"));
+		}
+		let format_version = format_version.to_i32()?;
+		if format_version != IMAGE_CLASSIFIER_FORMAT_VERSION {
+			return Err(Error::new(core::StsNotImplemented, format!(
+				"Unsupported image classifier format version: {}, expected: {}",
+				format_version,
+				IMAGE_CLASSIFIER_FORMAT_VERSION,
+			)));
+		}
+		let extractor = FeatureExtractor::from_name(&root.get("extractor")?.string()?)?;
+		let vocabulary = root.get("vocabulary")?;
+		let vocabulary = if vocabulary.is_none()? { Mat::default() } else { vocabulary.mat()? };
+		if extractor == FeatureExtractor::OrbBoVW && vocabulary.empty() {
+			return Err(Error::new(core::StsParseError, "Image classifier is missing the vocabulary"));
+		}
+		let bundle = ModelBundle::read_node(&root, <dyn SVM>::create()?)?;
+		let labels = bundle.metadata.labels()?;
+		Ok(Self { features: Features { extractor, vocabulary }, labels, bundle })
+	}
+}
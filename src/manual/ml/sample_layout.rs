@@ -0,0 +1,206 @@
+use std::borrow::Cow;
+
+use crate::{
+	core::{self, Mat, Ptr, ToInputArray, ToOutputArray},
+	Error,
+	ml::{self, SampleTypes, StatModel, TrainData, TrainDataConst, VariableTypes},
+	prelude::*,
+	Result,
+};
+
+fn sample_layout(layout: i32) -> Result<SampleTypes> {
+	match layout {
+		0 => Ok(SampleTypes::ROW_SAMPLE),
+		1 => Ok(SampleTypes::COL_SAMPLE),
+		_ => Err(Error::new(core::StsOutOfRange, format!("Unknown sample layout: {}", layout))),
+	}
+}
+
+fn variable_type(typ: i32) -> Result<VariableTypes> {
+	match typ {
+		0 => Ok(VariableTypes::VAR_NUMERICAL),
+		1 => Ok(VariableTypes::VAR_CATEGORICAL),
+		_ => Err(Error::new(core::StsOutOfRange, format!("Unknown variable type: {}", typ))),
+	}
+}
+
+/// Arrangement of the samples in a [Samples] matrix
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SamplesLayout {
+	/// One sample per row, one feature per column
+	RowMajor,
+	/// One sample per column, one feature per row
+	ColMajor,
+}
+
+impl From<SamplesLayout> for SampleTypes {
+	#[inline]
+	fn from(s: SamplesLayout) -> Self {
+		match s {
+			SamplesLayout::RowMajor => SampleTypes::ROW_SAMPLE,
+			SamplesLayout::ColMajor => SampleTypes::COL_SAMPLE,
+		}
+	}
+}
+
+impl From<SampleTypes> for SamplesLayout {
+	#[inline]
+	fn from(s: SampleTypes) -> Self {
+		match s {
+			SampleTypes::ROW_SAMPLE => SamplesLayout::RowMajor,
+			SampleTypes::COL_SAMPLE => SamplesLayout::ColMajor,
+		}
+	}
+}
+
+/// Sample matrix that carries its layout
+///
+/// Passing a column-major matrix together with the `ROW_SAMPLE` layout (or the other way around) doesn't fail in
+/// OpenCV, the model is just trained on the wrong data. `Samples` keeps the matrix and the layout together, it's
+/// accepted by [StatModelLayout::train_samples], [StatModelLayout::predict_samples] and
+/// `<dyn TrainData>::create_from_samples()` which transpose it or pass the matching layout as needed.
+#[derive(Clone, Copy, Debug)]
+pub struct Samples<'m> {
+	mat: &'m Mat,
+	layout: SamplesLayout,
+}
+
+impl<'m> Samples<'m> {
+	#[inline]
+	pub fn new(mat: &'m Mat, layout: SamplesLayout) -> Self {
+		Self { mat, layout }
+	}
+
+	/// Samples stored one per row
+	#[inline]
+	pub fn row_major(mat: &'m Mat) -> Self {
+		Self::new(mat, SamplesLayout::RowMajor)
+	}
+
+	/// Samples stored one per column
+	#[inline]
+	pub fn col_major(mat: &'m Mat) -> Self {
+		Self::new(mat, SamplesLayout::ColMajor)
+	}
+
+	#[inline]
+	pub fn mat(&self) -> &'m Mat {
+		self.mat
+	}
+
+	#[inline]
+	pub fn layout(&self) -> SamplesLayout {
+		self.layout
+	}
+
+	/// Returns the number of samples
+	#[inline]
+	pub fn sample_count(&self) -> i32 {
+		match self.layout {
+			SamplesLayout::RowMajor => self.mat.rows(),
+			SamplesLayout::ColMajor => self.mat.cols(),
+		}
+	}
+
+	/// Returns the number of features in every sample
+	#[inline]
+	pub fn feature_count(&self) -> i32 {
+		match self.layout {
+			SamplesLayout::RowMajor => self.mat.cols(),
+			SamplesLayout::ColMajor => self.mat.rows(),
+		}
+	}
+
+	/// Returns the samples with one sample per row, the matrix is transposed only if it's column-major
+	pub fn to_row_major(&self) -> Result<Cow<'m, Mat>> {
+		match self.layout {
+			SamplesLayout::RowMajor => Ok(Cow::Borrowed(self.mat)),
+			SamplesLayout::ColMajor => {
+				let mut out = Mat::default();
+				core::transpose(self.mat, &mut out)?;
+				Ok(Cow::Owned(out))
+			}
+		}
+	}
+
+	/// Checks that there is one response per sample, empty `responses` are allowed for the unsupervised models
+	fn check_responses(&self, responses: &dyn ToInputArray) -> Result<()> {
+		let responses = responses.input_array()?;
+		if responses.empty()? {
+			return Ok(());
+		}
+		let size = responses.size(-1)?;
+		let sample_count = self.sample_count();
+		if size.width != sample_count && size.height != sample_count {
+			return Err(Error::new(core::StsUnmatchedSizes, format!(
+				"Number of responses doesn't match the number of {:?} samples: {}, responses are {}x{}",
+				self.layout,
+				sample_count,
+				size.height,
+				size.width,
+			)));
+		}
+		Ok(())
+	}
+}
+
+/// `StatModel` methods taking `SampleTypes` instead of the deprecated `ROW_SAMPLE`/`COL_SAMPLE` constants
+pub trait StatModelLayout: StatModel {
+	/// `StatModel::train()` with the typed sample layout
+	#[inline]
+	fn train_with_layout(&mut self, samples: &dyn ToInputArray, layout: SampleTypes, responses: &dyn ToInputArray) -> Result<bool> {
+		self.train(samples, layout as i32, responses)
+	}
+
+	/// `StatModel::train()` with the layout taken from `samples`, fails if the number of `responses` doesn't match the
+	/// number of samples
+	fn train_samples(&mut self, samples: &Samples, responses: &dyn ToInputArray) -> Result<bool> {
+		samples.check_responses(responses)?;
+		self.train_with_layout(samples.mat(), samples.layout().into(), responses)
+	}
+
+	/// `StatModel::predict()` accepting samples in any layout, column-major samples are transposed before the call
+	fn predict_samples(&self, samples: &Samples, results: &mut dyn ToOutputArray, flags: i32) -> Result<f32> {
+		self.predict(&*samples.to_row_major()?, results, flags)
+	}
+}
+
+impl<T: StatModel + ?Sized> StatModelLayout for T {}
+
+/// `TrainData` methods taking and returning `SampleTypes` and `VariableTypes` instead of the deprecated integer constants
+pub trait TrainDataLayout: TrainDataConst {
+	/// `TrainData::get_layout()` as `SampleTypes`
+	#[inline]
+	fn sample_layout(&self) -> Result<SampleTypes> {
+		sample_layout(self.get_layout()?)
+	}
+
+	/// `TrainData::get_train_samples()` with the typed sample layout
+	#[inline]
+	fn train_samples_with_layout(&self, layout: SampleTypes, compress_samples: bool, compress_vars: bool) -> Result<Mat> {
+		self.get_train_samples(layout as i32, compress_samples, compress_vars)
+	}
+
+	/// `TrainData::get_response_type()` as `VariableTypes`
+	#[inline]
+	fn response_variable_type(&self) -> Result<VariableTypes> {
+		variable_type(self.get_response_type()?)
+	}
+}
+
+impl<T: TrainDataConst + ?Sized> TrainDataLayout for T {}
+
+impl dyn TrainData + '_ {
+	/// `TrainData::create()` with the typed sample layout and without the optional index and type arrays
+	#[inline]
+	pub fn create_with_layout(samples: &dyn ToInputArray, layout: SampleTypes, responses: &dyn ToInputArray) -> Result<Ptr<dyn TrainData>> {
+		ml::TrainData_CreateBuilder::new(samples, layout as i32, responses).call()
+	}
+
+	/// `TrainData::create()` with the layout taken from `samples`, fails if the number of `responses` doesn't match the
+	/// number of samples
+	pub fn create_from_samples(samples: &Samples, responses: &dyn ToInputArray) -> Result<Ptr<dyn TrainData>> {
+		samples.check_responses(responses)?;
+		Self::create_with_layout(samples.mat(), samples.layout().into(), responses)
+	}
+}
@@ -0,0 +1,319 @@
+use std::convert::TryFrom;
+
+use crate::{
+	core::{self, FileStorage, FileStorage_Mode, Mat, Scalar, UMat, UMatUsageFlags},
+	Error,
+	ml::{SVM_KernelTypes, SVM_Types, SVMConst},
+	prelude::*,
+	Result,
+};
+#[cfg(ocvrs_has_module_cudaarithm)]
+use crate::{
+	core::{GpuMat, Stream},
+	cudaarithm,
+};
+
+/// Matrix type that [SvmGpuPredictor] can evaluate the decision functions on
+///
+/// Implemented for `UMat` (OpenCL through the transparent API, falls back to CPU when OpenCL is not available) and for
+/// `GpuMat` when `cudaarithm` module is available.
+pub trait SvmDeviceMat: Sized {
+	fn upload(m: &Mat) -> Result<Self>;
+
+	fn download(&self) -> Result<Mat>;
+
+	fn rows(&self) -> i32;
+
+	fn cols(&self) -> i32;
+
+	fn ones(rows: i32, cols: i32) -> Result<Self>;
+
+	/// `dst = alpha * src1 * src2 + beta * src3`
+	fn gemm(src1: &Self, src2: &Self, alpha: f64, src3: Option<&Self>, beta: f64, flags: i32) -> Result<Self>;
+
+	/// Column with the sums of squared elements of every row
+	fn row_sqr_sum(&self) -> Result<Self>;
+
+	fn exp(&self) -> Result<Self>;
+}
+
+impl SvmDeviceMat for UMat {
+	#[inline]
+	fn upload(m: &Mat) -> Result<Self> {
+		let mut out = UMat::new(UMatUsageFlags::USAGE_DEFAULT);
+		m.copy_to(&mut out)?;
+		Ok(out)
+	}
+
+	#[inline]
+	fn download(&self) -> Result<Mat> {
+		let mut out = Mat::default();
+		self.copy_to(&mut out)?;
+		Ok(out)
+	}
+
+	#[inline]
+	fn rows(&self) -> i32 {
+		UMatTraitConst::rows(self)
+	}
+
+	#[inline]
+	fn cols(&self) -> i32 {
+		UMatTraitConst::cols(self)
+	}
+
+	#[inline]
+	fn ones(rows: i32, cols: i32) -> Result<Self> {
+		UMat::ones(rows, cols, f32::typ(), UMatUsageFlags::USAGE_DEFAULT)
+	}
+
+	fn gemm(src1: &Self, src2: &Self, alpha: f64, src3: Option<&Self>, beta: f64, flags: i32) -> Result<Self> {
+		let mut out = UMat::new(UMatUsageFlags::USAGE_DEFAULT);
+		match src3 {
+			Some(src3) => core::gemm(src1, src2, alpha, src3, beta, &mut out, flags)?,
+			None => core::gemm(src1, src2, alpha, &core::no_array(), 0., &mut out, flags)?,
+		}
+		Ok(out)
+	}
+
+	fn row_sqr_sum(&self) -> Result<Self> {
+		let mut sqr = UMat::new(UMatUsageFlags::USAGE_DEFAULT);
+		core::multiply(self, self, &mut sqr, 1., -1)?;
+		let mut out = UMat::new(UMatUsageFlags::USAGE_DEFAULT);
+		core::reduce(&sqr, &mut out, 1, core::REDUCE_SUM, -1)?;
+		Ok(out)
+	}
+
+	fn exp(&self) -> Result<Self> {
+		let mut out = UMat::new(UMatUsageFlags::USAGE_DEFAULT);
+		core::exp(self, &mut out)?;
+		Ok(out)
+	}
+}
+
+#[cfg(ocvrs_has_module_cudaarithm)]
+impl SvmDeviceMat for GpuMat {
+	#[inline]
+	fn upload(m: &Mat) -> Result<Self> {
+		let mut out = GpuMat::default()?;
+		out.upload(m)?;
+		Ok(out)
+	}
+
+	#[inline]
+	fn download(&self) -> Result<Mat> {
+		let mut out = Mat::default();
+		GpuMatTraitConst::download(self, &mut out)?;
+		Ok(out)
+	}
+
+	#[inline]
+	fn rows(&self) -> i32 {
+		GpuMatTraitConst::rows(self)
+	}
+
+	#[inline]
+	fn cols(&self) -> i32 {
+		GpuMatTraitConst::cols(self)
+	}
+
+	#[inline]
+	fn ones(rows: i32, cols: i32) -> Result<Self> {
+		<Self as SvmDeviceMat>::upload(&Mat::ones(rows, cols, f32::typ())?.to_mat()?)
+	}
+
+	fn gemm(src1: &Self, src2: &Self, alpha: f64, src3: Option<&Self>, beta: f64, flags: i32) -> Result<Self> {
+		let mut out = GpuMat::default()?;
+		match src3 {
+			Some(src3) => cudaarithm::gemm(src1, src2, alpha, src3, beta, &mut out, flags, &mut Stream::null()?)?,
+			None => cudaarithm::gemm(src1, src2, alpha, &core::no_array(), 0., &mut out, flags, &mut Stream::null()?)?,
+		}
+		Ok(out)
+	}
+
+	fn row_sqr_sum(&self) -> Result<Self> {
+		let mut sqr = GpuMat::default()?;
+		cudaarithm::multiply(self, self, &mut sqr, 1., -1, &mut Stream::null()?)?;
+		let mut out = GpuMat::default()?;
+		cudaarithm::reduce(&sqr, &mut out, 1, core::REDUCE_SUM, -1, &mut Stream::null()?)?;
+		Ok(out)
+	}
+
+	fn exp(&self) -> Result<Self> {
+		let mut out = GpuMat::default()?;
+		cudaarithm::exp(self, &mut out, &mut Stream::null()?)?;
+		Ok(out)
+	}
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum SvmKernel {
+	Linear,
+	Rbf { gamma: f64 },
+}
+
+/// Batch SVM prediction that evaluates decision functions as matrix products on the device
+///
+/// Only `LINEAR` and `RBF` kernels are supported. Support vectors and coefficients are uploaded once on creation, the
+/// kernel matrix is then computed with a few `gemm` calls for the whole batch. Vote counting for the multi-class
+/// classification is done on the host.
+#[derive(Debug)]
+pub struct SvmGpuPredictor<M = UMat> {
+	svm_type: SVM_Types,
+	kernel: SvmKernel,
+	class_count: usize,
+	class_labels: Option<Vec<f32>>,
+	/// Support vectors, `sv_count x var_count`
+	support_vectors: M,
+	/// Squared norms of the support vectors, `1 x sv_count`
+	support_vector_sqr: M,
+	/// Coefficients of every decision function, `df_count x sv_count`
+	alphas: M,
+	/// Decision function offsets, `1 x df_count`
+	rho: M,
+}
+
+impl<M: SvmDeviceMat> SvmGpuPredictor<M> {
+	/// Prepares the trained `svm` for prediction on the device
+	pub fn new(svm: &(impl SVMConst + ?Sized)) -> Result<Self> {
+		let svm_type = match svm.get_type()? {
+			x if x == SVM_Types::C_SVC as i32 => SVM_Types::C_SVC,
+			x if x == SVM_Types::NU_SVC as i32 => SVM_Types::NU_SVC,
+			x if x == SVM_Types::ONE_CLASS as i32 => SVM_Types::ONE_CLASS,
+			x if x == SVM_Types::EPS_SVR as i32 => SVM_Types::EPS_SVR,
+			x if x == SVM_Types::NU_SVR as i32 => SVM_Types::NU_SVR,
+			x => return Err(Error::new(core::StsBadArg, format!("Unknown SVM type: {}", x))),
+		};
+		let kernel = match svm.get_kernel_type()? {
+			x if x == SVM_KernelTypes::LINEAR as i32 => SvmKernel::Linear,
+			x if x == SVM_KernelTypes::RBF as i32 => SvmKernel::Rbf { gamma: svm.get_gamma()? },
+			x => return Err(Error::new(core::StsNotImplemented, format!("Only LINEAR and RBF kernels are supported for device prediction, got kernel type: {}", x))),
+		};
+		let support_vectors = convert(&svm.get_support_vectors()?, f32::typ())?;
+		if support_vectors.empty() {
+			return Err(Error::new(core::StsBadArg, "SVM is not trained"));
+		}
+		let sv_count = support_vectors.rows();
+		let (df_count, class_count) = if matches!(svm_type, SVM_Types::C_SVC | SVM_Types::NU_SVC) {
+			// `n` classes produce `n * (n - 1) / 2` one-vs-one decision functions
+			let class_count = svm_class_count(svm)?;
+			(class_count * (class_count - 1) / 2, class_count)
+		} else {
+			(1, 1)
+		};
+
+		let mut alphas = Mat::new_rows_cols_with_default(df_count as i32, sv_count, f32::typ(), Scalar::all(0.))?;
+		let mut rho = Mat::new_rows_cols_with_default(1, df_count as i32, f32::typ(), Scalar::all(0.))?;
+		for df in 0..df_count as i32 {
+			let mut alpha = Mat::default();
+			let mut sv_idx = Mat::default();
+			*rho.at_2d_mut::<f32>(0, df)? = svm.get_decision_function(df, &mut alpha, &mut sv_idx)? as f32;
+			let alpha = convert(&alpha, f64::typ())?;
+			let alpha_row = alphas.at_row_mut::<f32>(df)?;
+			for (&a, &i) in alpha.data_typed::<f64>()?.iter().zip(sv_idx.data_typed::<i32>()?) {
+				alpha_row[i as usize] = a as f32;
+			}
+		}
+		let mut support_vector_sqr = Mat::default();
+		core::multiply(&support_vectors, &support_vectors, &mut support_vector_sqr, 1., -1)?;
+		let mut support_vector_sqr_row = Mat::default();
+		core::reduce(&support_vector_sqr, &mut support_vector_sqr_row, 1, core::REDUCE_SUM, -1)?;
+		Ok(Self {
+			svm_type,
+			kernel,
+			class_count,
+			class_labels: None,
+			support_vectors: M::upload(&support_vectors)?,
+			support_vector_sqr: M::upload(&support_vector_sqr_row.t()?.to_mat()?)?,
+			alphas: M::upload(&alphas)?,
+			rho: M::upload(&rho)?,
+		})
+	}
+
+	/// Sets the class labels (e.g. from `TrainData::get_class_labels()`) to return from [predict](Self::predict)
+	///
+	/// Without the labels the classification returns the class indices.
+	pub fn with_class_labels(mut self, class_labels: &Mat) -> Result<Self> {
+		let labels = convert(class_labels, f64::typ())?.data_typed::<f64>()?.iter().map(|&l| l as f32).collect::<Vec<_>>();
+		if labels.len() != self.class_count {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("SVM has: {} classes, but: {} labels were provided", self.class_count, labels.len())));
+		}
+		self.class_labels = Some(labels);
+		Ok(self)
+	}
+
+	/// Computes the values of all decision functions for the `samples` (`CV_32F`, one sample per row)
+	///
+	/// The result is `samples.rows x df_count` matrix, the values are the same as returned by `StatModel::predict()` with
+	/// `RAW_OUTPUT` flag.
+	pub fn decision_values(&self, samples: &M) -> Result<M> {
+		let kernel = match self.kernel {
+			SvmKernel::Linear => M::gemm(samples, &self.support_vectors, 1., None, 0., core::GEMM_2_T)?,
+			SvmKernel::Rbf { gamma } => {
+				// exp(-gamma * (|x|^2 - 2 * x * sv + |sv|^2))
+				let ones = M::ones(samples.rows(), 1)?;
+				let sample_sqr_ones = M::ones(1, self.support_vectors.rows())?;
+				let dist = M::gemm(&samples.row_sqr_sum()?, &sample_sqr_ones, -gamma, None, 0., 0)?;
+				let dist = M::gemm(samples, &self.support_vectors, 2. * gamma, Some(&dist), 1., core::GEMM_2_T)?;
+				let dist = M::gemm(&ones, &self.support_vector_sqr, -gamma, Some(&dist), 1., 0)?;
+				dist.exp()?
+			}
+		};
+		let weighted = M::gemm(&kernel, &self.alphas, 1., None, 0., core::GEMM_2_T)?;
+		M::gemm(&M::ones(samples.rows(), 1)?, &self.rho, -1., Some(&weighted), 1., 0)
+	}
+
+	/// Predicts the responses for the `samples` (`CV_32F`, one sample per row), returns `CV_32F` column of results
+	pub fn predict(&self, samples: &M) -> Result<Mat> {
+		let values = self.decision_values(samples)?.download()?;
+		let mut out = Mat::new_rows_cols_with_default(values.rows(), 1, f32::typ(), Scalar::all(0.))?;
+		let mut votes = vec![0; self.class_count];
+		for row_n in 0..values.rows() {
+			let row = values.at_row::<f32>(row_n)?;
+			let res = match self.svm_type {
+				SVM_Types::C_SVC | SVM_Types::NU_SVC => {
+					votes.iter_mut().for_each(|v| *v = 0);
+					let mut df = 0;
+					for i in 0..self.class_count {
+						for j in i + 1..self.class_count {
+							votes[if row[df] > 0. { i } else { j }] += 1;
+							df += 1;
+						}
+					}
+					let class = votes.iter()
+						.enumerate()
+						.fold(0, |best, (i, &v)| if v > votes[best] { i } else { best });
+					self.class_labels.as_ref().map_or(class as f32, |labels| labels[class])
+				}
+				SVM_Types::ONE_CLASS => if row[0] > 0. { 1. } else { 0. },
+				SVM_Types::EPS_SVR | SVM_Types::NU_SVR => row[0],
+			};
+			*out.at_2d_mut::<f32>(row_n, 0)? = res;
+		}
+		Ok(out)
+	}
+}
+
+/// Returns the number of classes of the trained classification `svm`
+///
+/// OpenCV doesn't expose the class labels of the model, so the count is read from the serialized model.
+fn svm_class_count(svm: &(impl SVMConst + ?Sized)) -> Result<usize> {
+	let mut fs = FileStorage::new(".yml", FileStorage_Mode::WRITE as i32 | FileStorage_Mode::MEMORY as i32, "")?;
+	svm.write(&mut fs)?;
+	let fs = FileStorage::new(&fs.release_and_get_string()?, FileStorage_Mode::READ as i32 | FileStorage_Mode::MEMORY as i32, "")?;
+	let class_count = fs.get("class_count")?;
+	if class_count.is_none()? {
+		return Err(Error::new(core::StsBadArg, "SVM model doesn't have the class count"));
+	}
+	let class_count = class_count.to_i32()?;
+	match usize::try_from(class_count) {
+		Ok(class_count) if class_count >= 2 => Ok(class_count),
+		_ => Err(Error::new(core::StsBadArg, format!("SVM must have at least 2 classes, got: {}", class_count))),
+	}
+}
+
+fn convert(m: &Mat, typ: i32) -> Result<Mat> {
+	let mut out = Mat::default();
+	m.convert_to(&mut out, typ, 1., 0.)?;
+	Ok(out)
+}
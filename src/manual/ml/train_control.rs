@@ -0,0 +1,321 @@
+use std::{
+	sync::{
+		Arc,
+		atomic::{AtomicBool, Ordering},
+	},
+	time::{Duration, Instant},
+};
+
+use crate::{
+	core::{self, Mat, Ptr, TermCriteria, Vector},
+	Error,
+	ml::{ANN_MLP, ANN_MLP_TrainFlags, EM, logging::{self, Tracker}, SampleTypes, StatModel, TrainData},
+	prelude::*,
+	Result,
+};
+
+/// Default number of iterations per training chunk in [TrainControl]
+pub const TRAIN_DEFAULT_CHUNK_ITERATIONS: i32 = 10;
+
+/// Model that can continue training from its current state
+///
+/// Implemented for `ANN_MLP` (continues with `UPDATE_WEIGHTS`) and `EM` (continues with `train_e()` from the current
+/// means, covariances and weights). `LogisticRegression` and `SVMSGD` always restart from the initial weights in
+/// OpenCV, so they can't be trained in chunks.
+pub trait IncrementalTrain: StatModel {
+	/// Returns the termination criteria currently set for the model
+	fn train_criteria(&self) -> Result<TermCriteria>;
+
+	fn set_train_criteria(&mut self, criteria: TermCriteria) -> Result<()>;
+
+	/// Runs the training with the currently set criteria, `update` is false for the first chunk
+	fn train_chunk(&mut self, data: &Ptr<dyn TrainData>, flags: i32, update: bool) -> Result<bool>;
+
+	/// Returns the value that is compared between the chunks to detect convergence
+	fn train_error(&self, data: &Ptr<dyn TrainData>) -> Result<f32> {
+		self.calc_error(data, false, &mut core::no_array())
+	}
+
+	/// Trains the model using its own termination criteria, checking `token` between the chunks
+	///
+	/// Returns `false` if the training was cancelled or a chunk failed. After the cancellation the model contains the state
+	/// after the last finished chunk, after the failure its state is unspecified.
+	fn train_with_cancel(&mut self, data: &Ptr<dyn TrainData>, flags: i32, token: &CancellationToken) -> Result<bool> where Self: Sized {
+		let summary = TrainControl::new(self.train_criteria()?)?
+			.with_flags(flags)
+			.with_cancellation(token.clone())
+			.train(self, data)?;
+		Ok(!matches!(summary.stop_reason, TrainStopReason::Cancelled | TrainStopReason::Failed))
+	}
+}
+
+impl IncrementalTrain for Ptr<dyn ANN_MLP> {
+	#[inline]
+	fn train_criteria(&self) -> Result<TermCriteria> {
+		self.get_term_criteria()
+	}
+
+	#[inline]
+	fn set_train_criteria(&mut self, criteria: TermCriteria) -> Result<()> {
+		self.set_term_criteria(criteria)
+	}
+
+	fn train_chunk(&mut self, data: &Ptr<dyn TrainData>, flags: i32, update: bool) -> Result<bool> {
+		let flags = if update { flags | ANN_MLP_TrainFlags::UPDATE_WEIGHTS as i32 } else { flags };
+		self.train_with_data(data, flags)
+	}
+}
+
+impl IncrementalTrain for Ptr<dyn EM> {
+	#[inline]
+	fn train_criteria(&self) -> Result<TermCriteria> {
+		self.get_term_criteria()
+	}
+
+	#[inline]
+	fn set_train_criteria(&mut self, criteria: TermCriteria) -> Result<()> {
+		self.set_term_criteria(criteria)
+	}
+
+	fn train_chunk(&mut self, data: &Ptr<dyn TrainData>, flags: i32, update: bool) -> Result<bool> {
+		if update {
+			let means = self.get_means()?;
+			let weights = self.get_weights()?;
+			let mut covs = Vector::<Mat>::new();
+			self.get_covs(&mut covs)?;
+			let samples = data.train_samples_with_layout(SampleTypes::ROW_SAMPLE, true, true)?;
+			self.train_e(&samples, &means, &covs, &weights, &mut core::no_array(), &mut core::no_array(), &mut core::no_array())
+		} else {
+			self.train_with_data(data, flags)
+		}
+	}
+
+	/// Negative mean log-likelihood of the training samples
+	fn train_error(&self, data: &Ptr<dyn TrainData>) -> Result<f32> {
+		let samples = data.train_samples_with_layout(SampleTypes::ROW_SAMPLE, true, true)?;
+		let mut sum = 0.;
+		for row in 0..samples.rows() {
+			sum += self.predict2(&samples.row(row)?, &mut core::no_array())?[0];
+		}
+		Ok((-sum / f64::from(samples.rows().max(1))) as f32)
+	}
+}
+
+/// Trains the `model` on the consecutive ranges of `chunk_rows` rows of `samples` and `responses`, `epochs` times
+///
+/// Only the current chunk is converted and copied by the training, so `samples` can be a memory-mapped matrix (see
+/// `Mat::from_memmap()` with the `mmap` feature) larger than the available RAM. Every chunk is trained with the model
+/// termination criteria continuing from the state after the previous one, so set it to a few iterations. Returns
+/// `false` if the training of any chunk failed.
+pub fn train_row_chunks(model: &mut impl IncrementalTrain, samples: &Mat, responses: &Mat, chunk_rows: i32, epochs: i32, flags: i32) -> Result<bool> {
+	if responses.rows() != samples.rows() {
+		return Err(Error::new(core::StsUnmatchedSizes, format!("Number of responses: {} doesn't match the number of samples: {}", responses.rows(), samples.rows())));
+	}
+	if chunk_rows <= 0 || epochs <= 0 {
+		return Err(Error::new(core::StsBadArg, format!("Chunk rows: {} and epochs: {} must be positive", chunk_rows, epochs)));
+	}
+	let mut update = false;
+	for _ in 0..epochs {
+		let mut start = 0;
+		while start < samples.rows() {
+			let end = (start + chunk_rows).min(samples.rows());
+			let range = core::Range::new(start, end)?;
+			let data = <dyn TrainData>::create_with_layout(&samples.row_range(&range)?, SampleTypes::ROW_SAMPLE, &responses.row_range(&range)?)?;
+			if !model.train_chunk(&data, flags, update)? {
+				return Ok(false);
+			}
+			update = true;
+			start = end;
+		}
+	}
+	Ok(true)
+}
+
+/// Cooperative cancellation flag that can be shared between threads
+///
+/// Clones share the same flag, so the token can be passed to the training thread and cancelled from the UI thread.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+	#[inline]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	#[inline]
+	pub fn cancel(&self) {
+		self.0.store(true, Ordering::SeqCst)
+	}
+
+	#[inline]
+	pub fn is_cancelled(&self) -> bool {
+		self.0.load(Ordering::SeqCst)
+	}
+}
+
+/// Reason for [TrainControl] to stop the training
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TrainStopReason {
+	/// Iteration limit was reached
+	MaxIterations,
+	/// Training error changed by at most the tolerance set by [TrainControl::with_error_tolerance] between chunks
+	Converged,
+	/// Wall-clock time limit was reached
+	Timeout,
+	/// [CancellationToken] was cancelled
+	Cancelled,
+	/// Training of a chunk returned `false`, the model can be left partially updated by that chunk
+	Failed,
+}
+
+/// Result of the training run by [TrainControl]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TrainSummary {
+	pub iterations: i32,
+	pub elapsed: Duration,
+	/// Value of [IncrementalTrain::train_error] after the last successful chunk, NaN if no chunk succeeded
+	pub train_error: f32,
+	pub stop_reason: TrainStopReason,
+}
+
+/// Training loop with iteration, convergence and wall-clock limits
+///
+/// The training is split into chunks of a few iterations, limits are checked between the chunks, so the timeout can be
+/// overshot by the duration of a single chunk. The COUNT of the criteria limits the total number of iterations, the EPS
+/// is passed to the model together with the iteration limit of every chunk and keeps its model specific meaning, use
+/// [with_error_tolerance](Self::with_error_tolerance) to stop when the training error stops changing.
+#[derive(Clone, Debug)]
+pub struct TrainControl {
+	criteria: TermCriteria,
+	timeout: Option<Duration>,
+	chunk_iterations: i32,
+	error_tolerance: Option<f64>,
+	flags: i32,
+	cancellation: Option<CancellationToken>,
+}
+
+impl TrainControl {
+	/// Creates a new control from the `criteria`, it's checked for the limits that are requested, but not set
+	pub fn new(criteria: TermCriteria) -> Result<Self> {
+		if criteria.has_max_iter() && criteria.max_count <= 0 {
+			return Err(Error::new(core::StsBadArg, format!("TermCriteria has COUNT type, but max_count is: {}", criteria.max_count)));
+		}
+		if criteria.has_eps() && criteria.epsilon <= 0. {
+			return Err(Error::new(core::StsBadArg, format!("TermCriteria has EPS type, but epsilon is: {}", criteria.epsilon)));
+		}
+		Ok(Self { criteria, timeout: None, chunk_iterations: TRAIN_DEFAULT_CHUNK_ITERATIONS, error_tolerance: None, flags: 0, cancellation: None })
+	}
+
+	pub fn with_timeout(mut self, timeout: Duration) -> Self {
+		self.timeout = Some(timeout);
+		self
+	}
+
+	/// Sets the number of iterations between the limit checks
+	pub fn with_chunk_iterations(mut self, chunk_iterations: i32) -> Self {
+		self.chunk_iterations = chunk_iterations.max(1);
+		self
+	}
+
+	/// Stops the training when [IncrementalTrain::train_error] changes by at most `tolerance` between the chunks
+	///
+	/// The tolerance is in the units of the model error, e.g. the percentage of the misclassified samples for the
+	/// classifiers.
+	pub fn with_error_tolerance(mut self, tolerance: f64) -> Self {
+		self.error_tolerance = Some(tolerance.max(0.));
+		self
+	}
+
+	/// Sets the model specific training flags
+	pub fn with_flags(mut self, flags: i32) -> Self {
+		self.flags = flags;
+		self
+	}
+
+	/// Stops the training when the `token` is cancelled
+	pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+		self.cancellation = Some(token);
+		self
+	}
+
+	/// Trains the `model` on `data` until one of the limits is reached, the model criteria is restored afterwards
+	pub fn train(&self, model: &mut impl IncrementalTrain, data: &Ptr<dyn TrainData>) -> Result<TrainSummary> {
+		if !self.criteria.has_max_iter() && self.error_tolerance.is_none() && self.timeout.is_none() && self.cancellation.is_none() {
+			return Err(Error::new(core::StsBadArg, "TrainControl needs at least one of: COUNT criteria, error tolerance, timeout or cancellation"));
+		}
+		let original_criteria = model.train_criteria()?;
+		let res = self.train_chunks(model, data, None);
+		model.set_train_criteria(original_criteria)?;
+		res
+	}
+
+	/// Same as [train](Self::train), but records the limits as params, `train_error` after every chunk (with the number
+	/// of iterations as step) and the summary to `tracker`
+	pub fn train_tracked(&self, model: &mut impl IncrementalTrain, data: &Ptr<dyn TrainData>, tracker: &mut dyn Tracker) -> Result<TrainSummary> {
+		tracker.log_param("model", &model.get_default_name()?)?;
+		for (key, value) in logging::criteria_params("", self.criteria) {
+			tracker.log_param(&key, &value)?;
+		}
+		if let Some(timeout) = self.timeout {
+			tracker.log_param("timeout_s", &timeout.as_secs_f64().to_string())?;
+		}
+		tracker.log_param("chunk_iterations", &self.chunk_iterations.to_string())?;
+		if let Some(error_tolerance) = self.error_tolerance {
+			tracker.log_param("error_tolerance", &error_tolerance.to_string())?;
+		}
+		tracker.log_param("flags", &self.flags.to_string())?;
+		let original_criteria = model.train_criteria()?;
+		let res = self.train_chunks(model, data, Some(&mut *tracker));
+		model.set_train_criteria(original_criteria)?;
+		let summary = res?;
+		tracker.log_param("stop_reason", &format!("{:?}", summary.stop_reason))?;
+		tracker.log_metric("iterations", f64::from(summary.iterations), None)?;
+		tracker.log_metric("train_duration_s", summary.elapsed.as_secs_f64(), None)?;
+		Ok(summary)
+	}
+
+	fn train_chunks(&self, model: &mut impl IncrementalTrain, data: &Ptr<dyn TrainData>, mut tracker: Option<&mut dyn Tracker>) -> Result<TrainSummary> {
+		let start = Instant::now();
+		let mut iterations = 0;
+		let mut train_error = f32::NAN;
+		let mut prev_error: Option<f32> = None;
+		loop {
+			if matches!(&self.cancellation, Some(token) if token.is_cancelled()) {
+				return Ok(TrainSummary { iterations, elapsed: start.elapsed(), train_error, stop_reason: TrainStopReason::Cancelled });
+			}
+			let chunk = if self.criteria.has_max_iter() {
+				self.chunk_iterations.min(self.criteria.max_count - iterations)
+			} else {
+				self.chunk_iterations
+			};
+			let chunk_criteria = if self.criteria.has_eps() {
+				TermCriteria::both(chunk, self.criteria.epsilon)
+			} else {
+				TermCriteria::max_iter(chunk)
+			};
+			model.set_train_criteria(chunk_criteria)?;
+			if !model.train_chunk(data, self.flags, iterations > 0)? {
+				return Ok(TrainSummary { iterations, elapsed: start.elapsed(), train_error, stop_reason: TrainStopReason::Failed });
+			}
+			iterations += chunk;
+			train_error = model.train_error(data)?;
+			if let Some(tracker) = tracker.as_mut() {
+				tracker.log_metric("train_error", f64::from(train_error), Some(i64::from(iterations)))?;
+			}
+			let stop_reason = if self.criteria.has_max_iter() && iterations >= self.criteria.max_count {
+				Some(TrainStopReason::MaxIterations)
+			} else if matches!((prev_error, self.error_tolerance), (Some(prev), Some(tolerance)) if f64::from((prev - train_error).abs()) <= tolerance) {
+				Some(TrainStopReason::Converged)
+			} else if matches!(self.timeout, Some(timeout) if start.elapsed() >= timeout) {
+				Some(TrainStopReason::Timeout)
+			} else {
+				None
+			};
+			if let Some(stop_reason) = stop_reason {
+				return Ok(TrainSummary { iterations, elapsed: start.elapsed(), train_error, stop_reason });
+			}
+			prev_error = Some(train_error);
+		}
+	}
+}
@@ -0,0 +1,55 @@
+use std::collections::BTreeMap;
+
+use crate::{
+	core::{self, stats::{self, Summary}},
+	ml::{SampleTypes, TrainDataConst, VariableTypes},
+	prelude::*,
+	Result,
+};
+
+/// Summary of the training part of `TrainData` returned by [TrainDataDescribe::describe]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TrainDataSummary {
+	/// Number of the training samples
+	pub samples: usize,
+	/// Summary of every active feature (variable)
+	pub features: Vec<Summary>,
+	/// Summary of the responses, one per response column for the multi-output responses
+	pub responses: Vec<Summary>,
+	/// Number of samples of every class as `(label, count)` sorted by label, `None` if the responses are not
+	/// categorical
+	pub class_counts: Option<Vec<(i32, usize)>>,
+}
+
+/// Dataset sanity checks before the training
+pub trait TrainDataDescribe: TrainDataConst {
+	/// Calculates the per-feature summary and the class distribution of the training samples
+	fn describe(&self) -> Result<TrainDataSummary> {
+		let samples = self.train_samples_with_layout(SampleTypes::ROW_SAMPLE, true, true)?;
+		let responses = self.get_train_responses()?;
+		let class_counts = if self.response_variable_type()? == VariableTypes::VAR_CATEGORICAL {
+			let mut responses_i32 = core::Mat::default();
+			responses.convert_to(&mut responses_i32, core::CV_32S, 1., 0.)?;
+			let mut counts = BTreeMap::new();
+			for &label in responses_i32.data_typed::<i32>()? {
+				*counts.entry(label).or_insert(0) += 1;
+			}
+			Some(counts.into_iter().collect())
+		} else {
+			None
+		};
+		let responses = if responses.rows() == 1 || responses.cols() == 1 {
+			stats::describe(&responses)?
+		} else {
+			stats::describe_columns(&responses)?
+		};
+		Ok(TrainDataSummary {
+			samples: samples.rows() as usize,
+			features: stats::describe_columns(&samples)?,
+			responses,
+			class_counts,
+		})
+	}
+}
+
+impl<T: TrainDataConst + ?Sized> TrainDataDescribe for T {}
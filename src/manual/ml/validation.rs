@@ -0,0 +1,126 @@
+use crate::{
+	core::{self, Mat, ToInputArray, ToOutputArray},
+	Error,
+	ml::{SampleTypes, StatModel, StatModelConst},
+	prelude::*,
+	Result,
+};
+
+/// Response depth required by the model (identified by its default name) and the reason
+fn required_response_depth(model_name: &str, is_classifier: bool) -> Option<(i32, &'static str)> {
+	match model_name {
+		"opencv_ml_svm" if is_classifier => Some((core::CV_32S, "SVM classification requires integer class labels")),
+		"opencv_ml_nbayes" => Some((core::CV_32S, "NormalBayesClassifier requires integer class labels")),
+		"opencv_ml_lr" => Some((core::CV_32F, "LogisticRegression requires floating point labels")),
+		"opencv_ml_ann_mlp" => Some((core::CV_32F, "ANN_MLP requires floating point output vectors")),
+		_ => None,
+	}
+}
+
+fn type_name(typ: i32) -> Result<String> {
+	#[cfg(not(ocvrs_opencv_branch_32))]
+	{
+		core::type_to_string(typ)
+	}
+	#[cfg(ocvrs_opencv_branch_32)]
+	{
+		Ok(typ.to_string())
+	}
+}
+
+/// Checks that `samples` is a non-empty 2D `CV_32FC1` matrix, returns it
+fn check_samples(samples: &dyn ToInputArray) -> Result<Mat> {
+	let samples = samples.input_array()?.get_mat(-1)?;
+	if samples.empty() {
+		return Err(Error::new(core::StsBadArg, "Samples are empty"));
+	}
+	if samples.dims() != 2 {
+		return Err(Error::new(core::StsBadSize, format!("Samples must be a 2D matrix, but it has {} dimensions", samples.dims())));
+	}
+	if samples.typ() != core::CV_32FC1 {
+		return Err(Error::new(core::StsUnsupportedFormat, format!("Samples must be CV_32FC1, but got: {}", type_name(samples.typ())?)));
+	}
+	Ok(samples)
+}
+
+/// Pre-validation of the `StatModel` training and prediction inputs
+///
+/// OpenCV checks the shapes and types deep inside the training code and reports them with errors like
+/// `Assertion failed (samples.cols == var_count)`, these checks run before the call and describe the problem in terms
+/// of the passed arrays.
+pub trait StatModelValidate: StatModelConst {
+	/// Checks the inputs of `StatModel::train()`: `samples` must be `CV_32FC1`, the number of `responses` must match
+	/// the number of samples and their type must suit the model (e.g. integer labels for SVM classification)
+	fn validate_train(&self, samples: &dyn ToInputArray, layout: i32, responses: &dyn ToInputArray) -> Result<()> {
+		let samples = check_samples(samples)?;
+		let sample_count = if layout == SampleTypes::COL_SAMPLE as i32 { samples.cols() } else { samples.rows() };
+		let model_name = self.get_default_name()?;
+		if model_name == "opencv_ml_em" {
+			// unsupervised, responses are ignored
+			return Ok(());
+		}
+		let responses = responses.input_array()?.get_mat(-1)?;
+		if responses.empty() {
+			return Err(Error::new(core::StsBadArg, format!("Responses are empty, {} needs one per sample", model_name)));
+		}
+		if responses.channels() != 1 {
+			return Err(Error::new(core::StsUnsupportedFormat, format!("Responses must be single channel, but got: {}", type_name(responses.typ())?)));
+		}
+		if responses.rows() != sample_count && responses.cols() != sample_count {
+			return Err(Error::new(core::StsUnmatchedSizes, format!(
+				"Number of responses doesn't match the number of samples: {}, responses are {}x{}",
+				sample_count,
+				responses.rows(),
+				responses.cols(),
+			)));
+		}
+		let depth = responses.depth();
+		if depth != core::CV_32F && depth != core::CV_32S {
+			return Err(Error::new(core::StsUnsupportedFormat, format!("Responses must be CV_32F or CV_32S, but got: {}", type_name(responses.typ())?)));
+		}
+		if let Some((required_depth, reason)) = required_response_depth(&model_name, self.is_classifier()?) {
+			if depth != required_depth {
+				return Err(Error::new(core::StsUnsupportedFormat, format!(
+					"{}, responses must be {}, but got: {}",
+					reason,
+					type_name(required_depth)?,
+					type_name(responses.typ())?,
+				)));
+			}
+		}
+		Ok(())
+	}
+
+	/// Checks the inputs of `StatModel::predict()`: the model must be trained and `samples` must be `CV_32FC1` with one
+	/// sample per row and `get_var_count()` columns
+	fn validate_predict(&self, samples: &dyn ToInputArray) -> Result<()> {
+		if !self.is_trained()? {
+			return Err(Error::new(core::StsError, format!("{} is not trained", self.get_default_name()?)));
+		}
+		let samples = check_samples(samples)?;
+		let var_count = self.get_var_count()?;
+		if samples.cols() != var_count {
+			return Err(Error::new(core::StsBadSize, format!(
+				"Samples must have {} columns (variables), one sample per row, but got {}x{}",
+				var_count,
+				samples.rows(),
+				samples.cols(),
+			)));
+		}
+		Ok(())
+	}
+
+	/// `StatModel::train()` with [validate_train](Self::validate_train) before it
+	fn train_validated(&mut self, samples: &dyn ToInputArray, layout: i32, responses: &dyn ToInputArray) -> Result<bool> where Self: StatModel {
+		self.validate_train(samples, layout, responses)?;
+		self.train(samples, layout, responses)
+	}
+
+	/// `StatModel::predict()` with [validate_predict](Self::validate_predict) before it
+	fn predict_validated(&self, samples: &dyn ToInputArray, results: &mut dyn ToOutputArray, flags: i32) -> Result<f32> {
+		self.validate_predict(samples)?;
+		self.predict(samples, results, flags)
+	}
+}
+
+impl<T: StatModelConst + ?Sized> StatModelValidate for T {}
@@ -1,15 +1,70 @@
+#[cfg(ocvrs_has_module_calib3d)]
+pub mod calib3d;
+#[cfg(ocvrs_has_module_ccalib)]
+pub mod ccalib;
 #[cfg(ocvrs_has_module_core)]
 pub mod core;
 #[cfg(ocvrs_has_module_dnn)]
 pub mod dnn;
 #[cfg(ocvrs_has_module_features2d)]
 pub mod features2d;
+#[cfg(ocvrs_has_module_core)]
+pub mod fuzz;
+#[cfg(ocvrs_has_module_highgui)]
+pub mod highgui;
+#[cfg(ocvrs_has_module_img_hash)]
+pub mod img_hash;
+#[cfg(ocvrs_has_module_imgcodecs)]
+pub mod imgcodecs;
+#[cfg(ocvrs_has_module_imgproc)]
+pub mod imgproc;
+#[cfg(ocvrs_has_module_core)]
+pub mod interop;
+#[cfg(ocvrs_has_module_mcc)]
+pub mod mcc;
+#[cfg(ocvrs_has_module_ml)]
+pub mod ml;
+#[cfg(ocvrs_has_module_optflow)]
+pub mod optflow;
+#[cfg(ocvrs_has_module_quality)]
+pub mod quality;
+#[cfg(ocvrs_has_module_rgbd)]
+pub mod rgbd;
+#[cfg(all(unix, ocvrs_has_module_core))]
+pub mod risky;
+#[cfg(ocvrs_has_module_sfm)]
+pub mod sfm;
+#[cfg(all(feature = "show", ocvrs_has_module_imgproc))]
+pub mod show;
+pub mod stable;
+#[cfg(ocvrs_has_module_stitching)]
+pub mod stitching;
 pub mod sys;
+#[cfg(all(feature = "test-util", ocvrs_has_module_core))]
+pub mod test_support;
 pub mod types;
+#[cfg(ocvrs_has_module_videoio)]
+pub mod videoio;
+#[cfg(ocvrs_has_module_videostab)]
+pub mod videostab;
+#[cfg(ocvrs_has_module_viz)]
+pub mod viz;
+#[cfg(ocvrs_has_module_ximgproc)]
+pub mod ximgproc;
+#[cfg(ocvrs_has_module_xphoto)]
+pub mod xphoto;
 
 pub mod prelude {
 	#[cfg(ocvrs_has_module_core)]
 	pub use super::core::{MatConstIteratorTraitManual, MatTraitConstManual, MatTraitManual, MatxTrait, UMatTraitConstManual};
 	#[cfg(all(ocvrs_has_module_core, ocvrs_opencv_branch_32))]
 	pub use super::core::MatSizeTraitConstManual;
+	#[cfg(ocvrs_has_module_imgproc)]
+	pub use super::imgproc::MatResize;
+	#[cfg(ocvrs_has_module_ml)]
+	pub use super::ml::{IncrementalTrain, StatModelLayout, StatModelValidate, TrainDataDescribe, TrainDataLayout};
+	#[cfg(ocvrs_has_module_sfm)]
+	pub use super::sfm::BaseSFMErrorReport;
+	#[cfg(ocvrs_has_module_videoio)]
+	pub use super::videoio::VideoCaptureProps;
 }
@@ -0,0 +1,2 @@
+#[cfg(ocvrs_has_module_calib3d)]
+pub mod scene_flow;
@@ -0,0 +1,194 @@
+//! Per-pixel 3D motion from two consecutive rectified stereo pairs
+//!
+//! The disparity of both pairs is computed with the [Matcher](crate::calib3d::stereo::Matcher) and the left views are
+//! linked by the dense optical flow, the 3D motion of a pixel is the difference of its 3D points in both frames:
+//! ```no_run
+//! # use opencv::{calib3d::stereo::{Matcher, Preset}, core::Mat, optflow::scene_flow::{FlowMethod, SceneFlowEstimator}};
+//! # fn run(left0: &Mat, right0: &Mat, left1: &Mat, right1: &Mat, q: Mat) -> opencv::Result<()> {
+//! let mut estimator = SceneFlowEstimator::new(Matcher::new(Preset::Balanced, 64)?, q)?
+//!     .with_flow_method(FlowMethod::DeepFlow)?;
+//! let flow = estimator.compute(left0, right0, left1, right1)?;
+//! println!("{:?}", flow.at(240, 320)?);
+//! # Ok(()) }
+//! ```
+//!
+//! The Global Patch Collider forest matching is a C++ template that is not exposed by the bindings, the trained
+//! [GPCTree](crate::optflow::GPCTree)s can't be used as the flow backend.
+
+use std::borrow::Cow;
+
+use crate::{
+	calib3d::stereo::{DepthMap, Matcher},
+	core::{self, Mat, Vec2f, Vec3f},
+	Error,
+	imgproc,
+	optflow,
+	prelude::*,
+	Result,
+	video::{self, DenseOpticalFlow},
+};
+
+/// Dense optical flow algorithm linking the left views of the consecutive frames
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FlowMethod {
+	/// Dense Inverse Search with the medium preset, fast with a good quality
+	Dis,
+	/// Gunnar Farneback's polynomial expansion
+	Farneback,
+	/// DeepFlow, slow but handles the large displacements
+	DeepFlow,
+	/// PCA-Flow, fast and smooth
+	PcaFlow,
+}
+
+impl Default for FlowMethod {
+	#[inline]
+	fn default() -> Self {
+		Self::Dis
+	}
+}
+
+impl FlowMethod {
+	fn create(self) -> Result<Box<dyn DenseOpticalFlow>> {
+		Ok(match self {
+			FlowMethod::Dis => Box::new(<dyn video::DISOpticalFlow>::create(video::DISOpticalFlow_PRESET_MEDIUM)?),
+			FlowMethod::Farneback => Box::new(optflow::create_opt_flow_farneback()?),
+			FlowMethod::DeepFlow => Box::new(optflow::create_opt_flow_deep_flow()?),
+			FlowMethod::PcaFlow => Box::new(optflow::create_opt_flow_pca_flow()?),
+		})
+	}
+}
+
+/// Estimator of the scene flow from the consecutive rectified stereo pairs
+pub struct SceneFlowEstimator {
+	matcher: Matcher,
+	q: Mat,
+	flow: Box<dyn DenseOpticalFlow>,
+}
+
+impl SceneFlowEstimator {
+	/// Creates the estimator with the [FlowMethod::Dis] flow, `q` is the `4x4` matrix returned by
+	/// `calib3d::stereo_rectify()`
+	pub fn new(matcher: Matcher, q: Mat) -> Result<Self> {
+		if q.rows() != 4 || q.cols() != 4 || q.channels() != 1 {
+			return Err(Error::new(core::StsBadArg, format!("Q must be a 4x4 single channel matrix, got: {}x{}", q.rows(), q.cols())));
+		}
+		Ok(Self { matcher, q, flow: FlowMethod::default().create()? })
+	}
+
+	pub fn with_flow_method(mut self, method: FlowMethod) -> Result<Self> {
+		self.flow = method.create()?;
+		Ok(self)
+	}
+
+	/// Computes the motion of the points seen in `left0` to their positions at the time of `left1`
+	///
+	/// All images must have the same size and type, the pairs must be rectified with the same calibration.
+	pub fn compute(&mut self, left0: &Mat, right0: &Mat, left1: &Mat, right1: &Mat) -> Result<SceneFlow> {
+		if left0.size()? != left1.size()? || left0.typ() != left1.typ() {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("Frames must have the same size and type, got: {:?} {} and {:?} {}", left0.size()?, left0.typ(), left1.size()?, left1.typ())));
+		}
+		let depth0 = self.matcher.compute(left0, right0)?.to_depth(&self.q)?;
+		let depth1 = self.matcher.compute(left1, right1)?.to_depth(&self.q)?;
+		let mut flow = Mat::default();
+		self.flow.calc(&gray(left0)?, &gray(left1)?, &mut flow)?;
+		SceneFlow::from_parts(depth0, &depth1, flow)
+	}
+}
+
+/// Converts the image to `CV_8UC1` as required by the flow algorithms
+fn gray(image: &Mat) -> Result<Mat> {
+	match image.channels() {
+		1 => image.try_clone(),
+		channels => {
+			let mut out = Mat::default();
+			imgproc::cvt_color(image, &mut out, if channels == 4 { imgproc::COLOR_BGRA2GRAY } else { imgproc::COLOR_BGR2GRAY }, 0)?;
+			Ok(out)
+		}
+	}
+}
+
+/// Scene flow in the left rectified camera coordinates of the first frame
+#[derive(Debug)]
+pub struct SceneFlow {
+	points: Mat,
+	motion: Mat,
+	flow: Mat,
+}
+
+impl SceneFlow {
+	/// Combines the 3D points of both frames with the `CV_32FC2` optical flow from the first left view to the second one
+	///
+	/// The motion of a pixel is `NaN` when it has no depth in the first frame, its flow leaves the image or the pixel
+	/// it lands on has no depth in the second frame.
+	pub fn from_parts(depth0: DepthMap, depth1: &DepthMap, flow: Mat) -> Result<Self> {
+		let points = depth0.into_points();
+		let size = points.size()?;
+		if depth1.points().size()? != size || flow.size()? != size {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("Depth maps and flow must have the same size, got: {:?}, {:?} and {:?}", size, depth1.points().size()?, flow.size()?)));
+		}
+		if flow.typ() != core::CV_32FC2 {
+			return Err(Error::new(core::StsUnsupportedFormat, format!("Flow must have CV_32FC2 type, but it has: {}", flow.typ())));
+		}
+		let points0 = continuous(&points)?;
+		let points1 = continuous(depth1.points())?;
+		let flow_values = continuous(&flow)?;
+		let (points0, points1, flow_values) = (points0.data_typed::<Vec3f>()?, points1.data_typed::<Vec3f>()?, flow_values.data_typed::<Vec2f>()?);
+		let (width, height) = (size.width as usize, size.height as usize);
+		let mut motion = Mat::new_size_with_default(size, core::CV_32FC3, core::Scalar::all(f64::NAN))?;
+		for (i, m) in motion.data_typed_mut::<Vec3f>()?.iter_mut().enumerate() {
+			let (x, y) = ((i % width) as f32 + flow_values[i][0], (i / width) as f32 + flow_values[i][1]);
+			let (x, y) = (x.round(), y.round());
+			if !(x >= 0. && y >= 0. && (x as usize) < width && (y as usize) < height) {
+				continue;
+			}
+			let (p0, p1) = (points0[i], points1[y as usize * width + x as usize]);
+			if !p0[2].is_nan() && !p1[2].is_nan() {
+				*m = Vec3f::from([p1[0] - p0[0], p1[1] - p0[1], p1[2] - p0[2]]);
+			}
+		}
+		Ok(Self { points, motion, flow })
+	}
+
+	/// `CV_32FC3` 3D points of the first frame, `NaN` for the pixels without depth
+	#[inline]
+	pub fn points(&self) -> &Mat {
+		&self.points
+	}
+
+	/// `CV_32FC3` 3D displacement of the points, `NaN` where it's unknown
+	#[inline]
+	pub fn motion(&self) -> &Mat {
+		&self.motion
+	}
+
+	/// `CV_32FC2` optical flow of the left view in pixels
+	#[inline]
+	pub fn flow(&self) -> &Mat {
+		&self.flow
+	}
+
+	/// Returns the 3D displacement of the pixel, `None` if it's unknown
+	#[inline]
+	pub fn at(&self, row: i32, col: i32) -> Result<Option<Vec3f>> {
+		self.motion.at_2d::<Vec3f>(row, col).map(|m| if m[2].is_nan() { None } else { Some(*m) })
+	}
+
+	/// Returns the `CV_8UC1` mask of the pixels with the known motion
+	pub fn valid_mask(&self) -> Result<Mat> {
+		let mut z = Mat::default();
+		core::extract_channel(&self.motion, &mut z, 2)?;
+		let mut out = Mat::default();
+		// NaN is not equal to itself
+		core::compare(&z, &z, &mut out, core::CMP_EQ)?;
+		Ok(out)
+	}
+}
+
+fn continuous(mat: &Mat) -> Result<Cow<'_, Mat>> {
+	if mat.is_continuous() {
+		Ok(Cow::Borrowed(mat))
+	} else {
+		mat.try_clone().map(Cow::Owned)
+	}
+}
@@ -0,0 +1,86 @@
+use crate::{
+	core::{self, Mat, Ptr, Scalar},
+	Error,
+	prelude::*,
+	quality::{QualityBRISQUE, QualityBase, QualityGMSD, QualityMSE, QualityPSNR, QualitySSIM},
+	Result,
+};
+
+/// Returns the mean structural similarity of the images over the channels, `1` for the identical images
+#[inline]
+pub fn ssim(a: &Mat, b: &Mat) -> Result<f64> {
+	check_pair(a, b)?;
+	QualitySSIM::compute(a, b, &mut core::no_array()).map(|s| channels_mean(s, a))
+}
+
+/// Returns the peak signal-to-noise ratio in dB averaged over the channels, infinite for the identical images
+///
+/// The maximum pixel value is derived from the depth: 255 for `CV_8U`, 65535 for `CV_16U` and 1 for the floating
+/// point images.
+pub fn psnr(a: &Mat, b: &Mat) -> Result<f64> {
+	check_pair(a, b)?;
+	let max_pixel_value = match a.depth() {
+		core::CV_8U => 255.,
+		core::CV_8S => 127.,
+		core::CV_16U => 65535.,
+		core::CV_16S => 32767.,
+		core::CV_32F | core::CV_64F | core::CV_16F => 1.,
+		depth => return Err(Error::new(core::StsUnsupportedFormat, format!("Unsupported image depth for PSNR: {}", depth))),
+	};
+	QualityPSNR::compute(a, b, &mut core::no_array(), max_pixel_value).map(|s| channels_mean(s, a))
+}
+
+/// Returns the mean squared error averaged over the channels
+#[inline]
+pub fn mse(a: &Mat, b: &Mat) -> Result<f64> {
+	check_pair(a, b)?;
+	QualityMSE::compute(a, b, &mut core::no_array()).map(|s| channels_mean(s, a))
+}
+
+/// Returns the gradient magnitude similarity deviation averaged over the channels, `0` for the identical images
+#[inline]
+pub fn gmsd(a: &Mat, b: &Mat) -> Result<f64> {
+	check_pair(a, b)?;
+	QualityGMSD::compute(a, b, &mut core::no_array()).map(|s| channels_mean(s, a))
+}
+
+fn check_pair(a: &Mat, b: &Mat) -> Result<()> {
+	if a.size()? != b.size()? || a.typ() != b.typ() {
+		return Err(Error::new(core::StsUnmatchedSizes, format!("Images must have the same size and type, got: {:?} {} and {:?} {}", a.size()?, a.typ(), b.size()?, b.typ())));
+	}
+	if a.empty() {
+		return Err(Error::new(core::StsBadArg, "Images are empty"));
+	}
+	Ok(())
+}
+
+fn channels_mean(s: Scalar, image: &Mat) -> f64 {
+	let channels = (image.channels() as usize).min(4);
+	s.0[..channels].iter().sum::<f64>() / channels as f64
+}
+
+/// No-reference BRISQUE quality scorer with the model loaded once
+///
+/// The score is in `[0, 100]` range, lower is better. The model trained on the LIVE-R2 database is distributed with
+/// opencv_contrib in `modules/quality/samples/` as `brisque_model_live.yml` and `brisque_range_live.yml`.
+pub struct Brisque {
+	inner: Ptr<QualityBRISQUE>,
+}
+
+impl Brisque {
+	pub fn new(model_file_path: &str, range_file_path: &str) -> Result<Self> {
+		Ok(Self { inner: QualityBRISQUE::create(model_file_path, range_file_path)? })
+	}
+
+	/// Returns the score of the image of any number of channels
+	#[inline]
+	pub fn score(&mut self, image: &Mat) -> Result<f64> {
+		QualityBase::compute(&mut self.inner, image).map(|s| s[0])
+	}
+}
+
+/// Returns the BRISQUE score of the image loading the model for this call only, see [Brisque]
+#[inline]
+pub fn brisque(image: &Mat, model_file_path: &str, range_file_path: &str) -> Result<f64> {
+	QualityBRISQUE::compute(image, model_file_path, range_file_path).map(|s| s[0])
+}
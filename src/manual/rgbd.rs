@@ -0,0 +1,5 @@
+pub use fusion::*;
+pub use odometry_tracker::*;
+
+mod fusion;
+mod odometry_tracker;
@@ -0,0 +1,85 @@
+use crate::{
+	core::{self, Affine3f, Mat, Ptr, Size},
+	Error,
+	prelude::*,
+	Result,
+	rgbd::{Dynafu_DynaFu, Kinfu_KinFu, LargeKinfu},
+};
+
+/// Common frame-feeding interface of the dense depth fusion algorithms (`KinFu`, `LargeKinfu` and `DynaFu`)
+///
+/// Unlike the raw `update()` the depth frame is validated on the Rust side, so a frame of the wrong size or type
+/// produces an error instead of the OpenCV assertion deep inside the pipeline:
+/// ```ignore
+/// let mut kinfu = <dyn rgbd::Kinfu_KinFu>::create(&rgbd::Kinfu_Params::default_params()?)?;
+/// for depth in frames {
+///     if !kinfu.feed(&depth)? {
+///         kinfu.reset()?;
+///     }
+/// }
+/// let (points, normals) = kinfu.cloud()?;
+/// ```
+pub trait DepthFusion {
+	/// Size of the depth frames expected by the algorithm
+	fn frame_size(&self) -> Result<Size>;
+
+	/// Integrates the depth frame into the volume, returns `false` if the camera tracking was lost for this frame
+	///
+	/// `depth` must be a single channel `CV_16U` or `CV_32F` Mat of [frame_size](DepthFusion::frame_size), values are
+	/// interpreted according to the `depth_factor` of the algorithm parameters.
+	fn feed(&mut self, depth: &Mat) -> Result<bool>;
+
+	/// Current camera pose in the volume coordinates
+	fn pose(&self) -> Result<Affine3f>;
+
+	/// Extracts the fused surface as the point cloud, returns the points and the corresponding normals
+	fn cloud(&self) -> Result<(Mat, Mat)>;
+}
+
+/// Checks that `depth` is usable as the input frame of the fusion algorithm with the specified `frame_size`
+pub fn check_depth_frame(depth: &Mat, frame_size: Size) -> Result<()> {
+	if depth.empty() {
+		return Err(Error::new(core::StsBadArg, "Depth frame is empty"));
+	}
+	let typ = depth.typ();
+	if typ != core::CV_16UC1 && typ != core::CV_32FC1 {
+		return Err(Error::new(core::StsUnsupportedFormat, format!("Depth frame must be CV_16UC1 or CV_32FC1, but got type: {}", core::type_to_string(typ)?)));
+	}
+	let size = depth.size()?;
+	if size != frame_size {
+		return Err(Error::new(core::StsUnmatchedSizes, format!("Depth frame size: {:?} doesn't match the expected size: {:?}", size, frame_size)));
+	}
+	Ok(())
+}
+
+macro_rules! depth_fusion_impl {
+	($typ: ty) => {
+		impl DepthFusion for $typ {
+			#[inline]
+			fn frame_size(&self) -> Result<Size> {
+				Ok(self.get_params()?.frame_size())
+			}
+
+			fn feed(&mut self, depth: &Mat) -> Result<bool> {
+				check_depth_frame(depth, DepthFusion::frame_size(self)?)?;
+				self.update(depth)
+			}
+
+			#[inline]
+			fn pose(&self) -> Result<Affine3f> {
+				self.get_pose()
+			}
+
+			fn cloud(&self) -> Result<(Mat, Mat)> {
+				let mut points = Mat::default();
+				let mut normals = Mat::default();
+				self.get_cloud(&mut points, &mut normals)?;
+				Ok((points, normals))
+			}
+		}
+	};
+}
+
+depth_fusion_impl!(Ptr<dyn Kinfu_KinFu>);
+depth_fusion_impl!(Ptr<dyn LargeKinfu>);
+depth_fusion_impl!(Ptr<dyn Dynafu_DynaFu>);
@@ -0,0 +1,101 @@
+use crate::{
+	core::{self, Mat, no_array, Ptr},
+	Error,
+	imgproc,
+	prelude::*,
+	Result,
+	rgbd::Odometry,
+};
+
+/// Frame-to-frame visual odometry that accumulates the camera trajectory
+///
+/// Wraps any `rgbd::Odometry` implementation (`RgbdOdometry`, `ICPOdometry`, `RgbdICPOdometry`, `FastICPOdometry`) and
+/// takes care of keeping the previous frame and converting the inputs into the format expected by the algorithm:
+/// ```ignore
+/// let odometry = <dyn rgbd::Odometry>::create("RgbdOdometry")?;
+/// let mut tracker = rgbd::OdometryTracker::new(odometry)?.with_depth_scale(0.001);
+/// for (image, depth) in frames {
+///     tracker.feed(&image, &depth)?;
+///     println!("{:?}", tracker.pose().data_typed::<f64>()?);
+/// }
+/// ```
+pub struct OdometryTracker {
+	odometry: Ptr<dyn Odometry>,
+	depth_scale: f64,
+	/// Grayscale image and `CV_32F` depth in meters of the previous frame
+	prev: Option<(Mat, Mat)>,
+	/// `4x4` `CV_64F` pose of the current camera in the coordinates of the first frame
+	pose: Mat,
+}
+
+impl OdometryTracker {
+	pub fn new(odometry: Ptr<dyn Odometry>) -> Result<Self> {
+		Ok(Self { odometry, depth_scale: 1., prev: None, pose: Mat::eye(4, 4, core::CV_64F)?.to_mat()? })
+	}
+
+	/// Sets the multiplier that converts depth values into meters, e.g. `0.001` for the `CV_16U` depth in millimeters
+	#[inline]
+	pub fn with_depth_scale(mut self, depth_scale: f64) -> Self {
+		self.depth_scale = depth_scale;
+		self
+	}
+
+	/// Processes the next frame, returns the `4x4` motion from the previous frame or `None` for the first frame and
+	/// if the odometry failed
+	///
+	/// `image` is either grayscale or BGR `CV_8U` image and `depth` is a single channel depth map of the same size. When
+	/// the odometry fails the pose is left unchanged and the tracking continues from the current frame.
+	pub fn feed(&mut self, image: &Mat, depth: &Mat) -> Result<Option<Mat>> {
+		let image_size = image.size()?;
+		if image_size != depth.size()? || depth.channels() != 1 {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("Expected single channel depth of size: {:?}", image_size)));
+		}
+		let gray = match image.channels() {
+			1 => image.try_clone()?,
+			3 => {
+				let mut gray = Mat::default();
+				imgproc::cvt_color(image, &mut gray, imgproc::COLOR_BGR2GRAY, 0)?;
+				gray
+			}
+			channels => return Err(Error::new(core::StsUnsupportedFormat, format!("Expected 1 or 3 channel image, but got: {} channels", channels))),
+		};
+		let mut depth_m = Mat::default();
+		depth.convert_to(&mut depth_m, core::CV_32F, self.depth_scale, 0.)?;
+		let motion = if let Some((prev_gray, prev_depth)) = &self.prev {
+			let mut rt = Mat::default();
+			let empty = Mat::default();
+			if self.odometry.compute(prev_gray, prev_depth, &empty, &gray, &depth_m, &empty, &mut rt, &empty)? {
+				// rt maps the points of the previous frame into the current one
+				let mut pose = Mat::default();
+				core::gemm(&self.pose, &rt.inv(core::DECOMP_SVD)?.to_mat()?, 1., &no_array(), 0., &mut pose, 0)?;
+				self.pose = pose;
+				Some(rt)
+			} else {
+				None
+			}
+		} else {
+			None
+		};
+		self.prev = Some((gray, depth_m));
+		Ok(motion)
+	}
+
+	/// `4x4` `CV_64F` pose of the latest camera in the coordinates of the first frame
+	#[inline]
+	pub fn pose(&self) -> &Mat {
+		&self.pose
+	}
+
+	/// Forgets the previous frame and resets the pose to identity
+	pub fn reset(&mut self) -> Result<()> {
+		self.prev = None;
+		self.pose = Mat::eye(4, 4, core::CV_64F)?.to_mat()?;
+		Ok(())
+	}
+
+	/// Returns the wrapped odometry algorithm
+	#[inline]
+	pub fn odometry(&self) -> &Ptr<dyn Odometry> {
+		&self.odometry
+	}
+}
@@ -0,0 +1,302 @@
+//! Crash isolation for the calls that are known to bring down the whole process on malformed input
+//!
+//! Some OpenCV code paths (decoding corrupted video files, loading malformed model files, etc.) can segfault or abort
+//! instead of reporting an error. [run] executes such a closure in a forked child process and passes the result back
+//! through a pipe, so a crash of the native code becomes an `Error` with `StsInternal` code in the calling process.
+//!
+//! Catching `SIGSEGV` in-process is not an option: unwinding or jumping out of the signal handler through the Rust and
+//! C++ frames is undefined behavior and leaves OpenCV in an unknown state.
+//!
+//! The child is a copy of the calling process with only the calling thread, so the closure must not rely on the other
+//! threads (e.g. locks held by them at the time of the fork). Consider `core::set_num_threads(0)` inside of the closure
+//! to avoid the OpenCV thread pool. Only the returned value crosses the process boundary, any other side effects of the
+//! closure (like modifying the captured variables) are lost.
+
+use std::{
+	convert::TryInto,
+	fs::File,
+	io::{self, Read, Write},
+	os::unix::io::FromRawFd,
+	panic::{self, AssertUnwindSafe},
+};
+
+use crate::{
+	core::{self, Mat},
+	Error,
+	prelude::*,
+	Result,
+};
+
+/// Value that can be passed from the child process back to the caller of [run]
+pub trait Transfer: Sized {
+	fn write_to(&self, out: &mut Vec<u8>) -> Result<()>;
+
+	/// Reads the value from the start of `data` advancing it past the read bytes
+	fn read_from(data: &mut &[u8]) -> Result<Self>;
+}
+
+fn truncated() -> Error {
+	Error::new(core::StsInternal, "Truncated result received from the child process")
+}
+
+fn take<'d>(data: &mut &'d [u8], len: usize) -> Result<&'d [u8]> {
+	if data.len() < len {
+		return Err(truncated());
+	}
+	let (out, rest) = data.split_at(len);
+	*data = rest;
+	Ok(out)
+}
+
+impl Transfer for () {
+	#[inline]
+	fn write_to(&self, _out: &mut Vec<u8>) -> Result<()> {
+		Ok(())
+	}
+
+	#[inline]
+	fn read_from(_data: &mut &[u8]) -> Result<Self> {
+		Ok(())
+	}
+}
+
+impl Transfer for bool {
+	#[inline]
+	fn write_to(&self, out: &mut Vec<u8>) -> Result<()> {
+		out.push(u8::from(*self));
+		Ok(())
+	}
+
+	#[inline]
+	fn read_from(data: &mut &[u8]) -> Result<Self> {
+		Ok(take(data, 1)?[0] != 0)
+	}
+}
+
+macro_rules! transfer_num {
+	($($typ: ty),+) => {
+		$(
+			impl Transfer for $typ {
+				#[inline]
+				fn write_to(&self, out: &mut Vec<u8>) -> Result<()> {
+					out.extend_from_slice(&self.to_le_bytes());
+					Ok(())
+				}
+
+				#[inline]
+				fn read_from(data: &mut &[u8]) -> Result<Self> {
+					let bytes = take(data, std::mem::size_of::<$typ>())?;
+					Ok(<$typ>::from_le_bytes(bytes.try_into().map_err(|_| truncated())?))
+				}
+			}
+		)+
+	};
+}
+
+transfer_num!(i32, u32, i64, u64, f32, f64, usize);
+
+impl Transfer for Vec<u8> {
+	#[inline]
+	fn write_to(&self, out: &mut Vec<u8>) -> Result<()> {
+		self.len().write_to(out)?;
+		out.extend_from_slice(self);
+		Ok(())
+	}
+
+	#[inline]
+	fn read_from(data: &mut &[u8]) -> Result<Self> {
+		let len = usize::read_from(data)?;
+		Ok(take(data, len)?.to_vec())
+	}
+}
+
+impl Transfer for String {
+	#[inline]
+	fn write_to(&self, out: &mut Vec<u8>) -> Result<()> {
+		self.len().write_to(out)?;
+		out.extend_from_slice(self.as_bytes());
+		Ok(())
+	}
+
+	#[inline]
+	fn read_from(data: &mut &[u8]) -> Result<Self> {
+		String::from_utf8(Vec::<u8>::read_from(data)?)
+			.map_err(|e| Error::new(core::StsInternal, format!("Invalid string received from the child process: {}", e)))
+	}
+}
+
+impl<T: Transfer> Transfer for Option<T> {
+	#[inline]
+	fn write_to(&self, out: &mut Vec<u8>) -> Result<()> {
+		self.is_some().write_to(out)?;
+		if let Some(val) = self {
+			val.write_to(out)?;
+		}
+		Ok(())
+	}
+
+	#[inline]
+	fn read_from(data: &mut &[u8]) -> Result<Self> {
+		if bool::read_from(data)? {
+			T::read_from(data).map(Some)
+		} else {
+			Ok(None)
+		}
+	}
+}
+
+/// Only 2D matrices are supported, the data is copied
+impl Transfer for Mat {
+	fn write_to(&self, out: &mut Vec<u8>) -> Result<()> {
+		if self.dims() > 2 {
+			return Err(Error::new(core::StsNotImplemented, format!("Only 2D Mat can be transferred, but it has {} dimensions", self.dims())));
+		}
+		self.rows().write_to(out)?;
+		self.cols().write_to(out)?;
+		self.typ().write_to(out)?;
+		// e.g. imdecode() of the corrupted input, there is no data to pass
+		if self.empty() {
+			return Ok(());
+		}
+		if self.is_continuous() {
+			out.extend_from_slice(self.data_bytes()?);
+		} else {
+			out.extend_from_slice(self.try_clone()?.data_bytes()?);
+		}
+		Ok(())
+	}
+
+	fn read_from(data: &mut &[u8]) -> Result<Self> {
+		let rows = i32::read_from(data)?;
+		let cols = i32::read_from(data)?;
+		let typ = i32::read_from(data)?;
+		let mut out = Mat::new_rows_cols_with_default(rows, cols, typ, core::Scalar::all(0.))?;
+		if out.empty() {
+			return Ok(out);
+		}
+		let len = out.data_bytes()?.len();
+		out.data_bytes_mut()?.copy_from_slice(take(data, len)?);
+		Ok(out)
+	}
+}
+
+fn os_error(operation: &str) -> Error {
+	Error::new(core::StsInternal, format!("Can't {} for the risky call: {}", operation, io::Error::last_os_error()))
+}
+
+/// Creates the status pipe with both ends closed on exec, so they don't leak into the processes spawned concurrently
+#[cfg(not(any(target_os = "macos", target_os = "ios")))]
+fn status_pipe() -> Result<[libc::c_int; 2]> {
+	let mut fds = [0; 2];
+	if unsafe { libc::pipe2(fds.as_mut_ptr(), libc::O_CLOEXEC) } != 0 {
+		return Err(os_error("create pipe"));
+	}
+	Ok(fds)
+}
+
+/// Creates the status pipe with both ends closed on exec, so they don't leak into the processes spawned concurrently
+///
+/// There is no `pipe2` on Apple platforms, the flag is set right after the creation, which leaves a small window for
+/// a concurrent `fork` + `exec` to inherit the descriptors.
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+fn status_pipe() -> Result<[libc::c_int; 2]> {
+	let mut fds = [0; 2];
+	if unsafe { libc::pipe(fds.as_mut_ptr()) } != 0 {
+		return Err(os_error("create pipe"));
+	}
+	for &fd in &fds {
+		if unsafe { libc::fcntl(fd, libc::F_SETFD, libc::FD_CLOEXEC) } == -1 {
+			let err = os_error("set close-on-exec on the pipe");
+			unsafe {
+				libc::close(fds[0]);
+				libc::close(fds[1]);
+			}
+			return Err(err);
+		}
+	}
+	Ok(fds)
+}
+
+fn encode_result<T: Transfer>(res: Result<T>) -> Vec<u8> {
+	let mut out = vec![];
+	let res = res.and_then(|val| {
+		out.push(0);
+		val.write_to(&mut out)
+	});
+	if let Err(e) = res {
+		out.clear();
+		out.push(1);
+		let _ = e.code.write_to(&mut out);
+		let _ = e.message.write_to(&mut out);
+	}
+	out
+}
+
+fn decode_result<T: Transfer>(mut data: &[u8]) -> Result<T> {
+	let data = &mut data;
+	if take(data, 1)?[0] == 0 {
+		T::read_from(data)
+	} else {
+		let code = i32::read_from(data)?;
+		let message = String::read_from(data)?;
+		Err(Error::new(code, message))
+	}
+}
+
+fn signal_name(signal: i32) -> &'static str {
+	match signal {
+		libc::SIGSEGV => "SIGSEGV",
+		libc::SIGABRT => "SIGABRT",
+		libc::SIGBUS => "SIGBUS",
+		libc::SIGFPE => "SIGFPE",
+		libc::SIGILL => "SIGILL",
+		libc::SIGKILL => "SIGKILL",
+		_ => "signal",
+	}
+}
+
+/// Runs `f` in a forked child process and returns its result, a crash of the child is reported as `Error` with
+/// `StsInternal` code
+///
+/// Panics inside of `f` are also converted to errors. See the [module docs](self) for the limitations.
+pub fn run<T: Transfer>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+	let [read_fd, write_fd] = status_pipe()?;
+	let pid = unsafe { libc::fork() };
+	if pid == -1 {
+		let err = os_error("fork");
+		unsafe {
+			libc::close(read_fd);
+			libc::close(write_fd);
+		}
+		return Err(err);
+	}
+	if pid == 0 {
+		// child, never returns
+		unsafe { libc::close(read_fd) };
+		let res = panic::catch_unwind(AssertUnwindSafe(f))
+			.unwrap_or_else(|_| Err(Error::new(core::StsError, "Risky call panicked")));
+		let mut pipe = unsafe { File::from_raw_fd(write_fd) };
+		let status = if pipe.write_all(&encode_result(res)).is_ok() { 0 } else { 1 };
+		drop(pipe);
+		// skip the destructors and atexit handlers, they belong to the parent
+		unsafe { libc::_exit(status) }
+	}
+	unsafe { libc::close(write_fd) };
+	let mut data = vec![];
+	let read_res = unsafe { File::from_raw_fd(read_fd) }.read_to_end(&mut data);
+	let mut status = 0;
+	while unsafe { libc::waitpid(pid, &mut status, 0) } == -1 {
+		if io::Error::last_os_error().kind() != io::ErrorKind::Interrupted {
+			return Err(os_error("wait for the child process"));
+		}
+	}
+	if libc::WIFSIGNALED(status) {
+		let signal = libc::WTERMSIG(status);
+		return Err(Error::new(core::StsInternal, format!("Risky call crashed with {} ({})", signal_name(signal), signal)));
+	}
+	if !libc::WIFEXITED(status) || libc::WEXITSTATUS(status) != 0 {
+		return Err(Error::new(core::StsInternal, "Risky call failed to send back its result"));
+	}
+	read_res.map_err(|e| Error::new(core::StsInternal, format!("Can't read the result of the risky call: {}", e)))?;
+	decode_result(&data)
+}
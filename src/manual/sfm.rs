@@ -0,0 +1,40 @@
+pub use background::*;
+pub use bundle_adjust::*;
+pub use depth::*;
+pub use exif::*;
+#[cfg(ocvrs_has_module_imgcodecs)]
+pub use images::*;
+#[cfg(ocvrs_has_module_calib3d)]
+pub use merge::*;
+#[cfg(ocvrs_has_module_calib3d)]
+pub use pipeline::*;
+pub use refine::*;
+pub use register::*;
+pub use report::*;
+pub use tracks::*;
+
+pub mod cloud;
+pub mod fit;
+#[cfg(all(ocvrs_has_module_imgcodecs, ocvrs_has_module_imgproc))]
+pub mod intrinsics;
+#[cfg(all(ocvrs_has_module_calib3d, ocvrs_has_module_features2d))]
+pub mod loop_closure;
+pub mod scale;
+#[cfg(ocvrs_has_module_calib3d)]
+pub mod two_view;
+#[cfg(ocvrs_has_module_imgproc)]
+pub mod viz;
+mod background;
+mod bundle_adjust;
+mod depth;
+mod exif;
+#[cfg(ocvrs_has_module_imgcodecs)]
+mod images;
+#[cfg(ocvrs_has_module_calib3d)]
+mod merge;
+#[cfg(ocvrs_has_module_calib3d)]
+mod pipeline;
+mod refine;
+mod register;
+mod report;
+mod tracks;
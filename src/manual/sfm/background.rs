@@ -0,0 +1,180 @@
+use std::{
+	sync::{
+		Arc,
+		atomic::{AtomicU8, Ordering},
+	},
+	thread::{self, JoinHandle},
+};
+
+use crate::{
+	core::{self, Mat, Vector},
+	Error,
+	Result,
+	sfm,
+};
+
+const STATUS_RUNNING: u8 = 0;
+const STATUS_FINISHED: u8 = 1;
+const STATUS_CANCELLED: u8 = 2;
+
+/// Source of the 2D observations for the reconstruction
+#[derive(Debug)]
+pub enum ReconstructionSource {
+	/// Image paths, features are detected and matched by libmv
+	Images(Vec<String>),
+	/// Tracks, one `2xN` `CV_64F` Mat per frame, missing observations are marked by `-1`
+	Tracks(Vec<Mat>),
+}
+
+/// Input of the [spawn_reconstruction]
+#[derive(Debug)]
+pub struct ReconstructionInput {
+	pub source: ReconstructionSource,
+	/// Initial guess of the `3x3` camera matrix, it's refined during the reconstruction
+	pub k: Mat,
+	/// Whether the cameras are projective
+	pub is_projective: bool,
+	/// `CV_8UC1` masks, one per frame, the observations in their zero pixels are ignored, see [with_masks](Self::with_masks)
+	pub masks: Vec<Mat>,
+}
+
+impl ReconstructionInput {
+	#[inline]
+	pub fn from_images(images: impl IntoIterator<Item=impl Into<String>>, k: Mat) -> Self {
+		Self { source: ReconstructionSource::Images(images.into_iter().map(|i| i.into()).collect()), k, is_projective: false, masks: vec![] }
+	}
+
+	#[inline]
+	pub fn from_tracks(tracks: Vec<Mat>, k: Mat) -> Self {
+		Self { source: ReconstructionSource::Tracks(tracks), k, is_projective: false, masks: vec![] }
+	}
+
+	#[inline]
+	pub fn with_projective(mut self, is_projective: bool) -> Self {
+		self.is_projective = is_projective;
+		self
+	}
+
+	/// Sets the masks of the dynamic regions, e.g. the moving objects, one per frame, empty Mat doesn't mask its frame
+	///
+	/// The masked observations are removed from the tracks with [mask_tracks](sfm::mask_tracks). libmv can't mask the
+	/// images, so the masked image reconstruction builds the tracks with [TrackBuilder](sfm::TrackBuilder) instead,
+	/// this requires the `features2d`, `calib3d` and `imgcodecs` modules.
+	#[inline]
+	pub fn with_masks(mut self, masks: Vec<Mat>) -> Self {
+		self.masks = masks;
+		self
+	}
+}
+
+/// Result of the reconstruction
+#[derive(Debug)]
+pub struct Reconstruction {
+	/// `3x3` rotations of the cameras
+	pub rs: Vec<Mat>,
+	/// `3x1` translations of the cameras
+	pub ts: Vec<Mat>,
+	/// Refined camera matrix
+	pub k: Mat,
+	/// Reconstructed 3D points, `3x1` each
+	pub points3d: Vec<Mat>,
+}
+
+impl Reconstruction {
+	/// Runs the reconstruction on the current thread
+	pub fn run(input: ReconstructionInput) -> Result<Self> {
+		let ReconstructionInput { source, mut k, is_projective, masks } = input;
+		let mut rs = Vector::<Mat>::new();
+		let mut ts = Vector::<Mat>::new();
+		let mut points3d = Vector::<Mat>::new();
+		match source {
+			ReconstructionSource::Images(images) if masks.is_empty() => {
+				sfm::reconstruct_3(Vector::from_iter(images.iter().map(String::as_str)), &mut rs, &mut ts, &mut k, &mut points3d, is_projective)?;
+			}
+			ReconstructionSource::Images(images) => {
+				let tracks = masked_image_tracks(&images, masks)?;
+				sfm::reconstruct_1(&Vector::<Mat>::from_iter(tracks), &mut rs, &mut ts, &mut k, &mut points3d, is_projective)?;
+			}
+			ReconstructionSource::Tracks(mut tracks) => {
+				if !masks.is_empty() {
+					sfm::mask_tracks(&mut tracks, &masks)?;
+				}
+				sfm::reconstruct_1(&Vector::<Mat>::from_iter(tracks), &mut rs, &mut ts, &mut k, &mut points3d, is_projective)?;
+			}
+		}
+		Ok(Self { rs: rs.to_vec(), ts: ts.to_vec(), k, points3d: points3d.to_vec() })
+	}
+}
+
+#[cfg(all(ocvrs_has_module_calib3d, ocvrs_has_module_features2d, ocvrs_has_module_imgcodecs))]
+fn masked_image_tracks(images: &[String], masks: Vec<Mat>) -> Result<Vec<Mat>> {
+	let images = images.iter()
+		.map(|path| crate::imgcodecs::imread(path, crate::imgcodecs::IMREAD_GRAYSCALE))
+		.collect::<Result<Vec<_>>>()?;
+	sfm::TrackBuilder::new()?.with_masks(masks).build(&images)
+}
+
+#[cfg(not(all(ocvrs_has_module_calib3d, ocvrs_has_module_features2d, ocvrs_has_module_imgcodecs)))]
+fn masked_image_tracks(_images: &[String], _masks: Vec<Mat>) -> Result<Vec<Mat>> {
+	Err(Error::new(core::StsNotImplemented, "Masked reconstruction from the images requires the features2d, calib3d and imgcodecs modules"))
+}
+
+/// Status of the reconstruction started by [spawn_reconstruction]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReconstructionStatus {
+	Running,
+	Finished,
+	Cancelled,
+}
+
+/// Handle to the reconstruction running on a worker thread
+///
+/// Dropping the handle detaches the worker thread.
+#[derive(Debug)]
+pub struct ReconstructionHandle {
+	status: Arc<AtomicU8>,
+	thread: JoinHandle<Result<Reconstruction>>,
+}
+
+impl ReconstructionHandle {
+	pub fn poll_status(&self) -> ReconstructionStatus {
+		match self.status.load(Ordering::SeqCst) {
+			STATUS_RUNNING => ReconstructionStatus::Running,
+			STATUS_FINISHED => ReconstructionStatus::Finished,
+			_ => ReconstructionStatus::Cancelled,
+		}
+	}
+
+	/// Requests the cancellation
+	///
+	/// libmv pipeline can't be interrupted, so the worker thread keeps running until the current reconstruction
+	/// finishes, but its result is discarded and [join](Self::join) returns an error.
+	pub fn cancel(&self) {
+		let _ = self.status.compare_exchange(STATUS_RUNNING, STATUS_CANCELLED, Ordering::SeqCst, Ordering::SeqCst);
+	}
+
+	/// Waits for the reconstruction to finish and returns its result
+	pub fn join(self) -> Result<Reconstruction> {
+		let res = self.thread.join()
+			.map_err(|_| Error::new(core::StsError, "Reconstruction thread panicked"))?;
+		if self.status.load(Ordering::SeqCst) == STATUS_CANCELLED {
+			Err(Error::new(core::StsError, "Reconstruction was cancelled"))
+		} else {
+			res
+		}
+	}
+}
+
+/// Starts [Reconstruction::run] on a new worker thread
+pub fn spawn_reconstruction(input: ReconstructionInput) -> ReconstructionHandle {
+	let status = Arc::new(AtomicU8::new(STATUS_RUNNING));
+	let thread = thread::spawn({
+		let status = Arc::clone(&status);
+		move || {
+			let res = Reconstruction::run(input);
+			let _ = status.compare_exchange(STATUS_RUNNING, STATUS_FINISHED, Ordering::SeqCst, Ordering::SeqCst);
+			res
+		}
+	});
+	ReconstructionHandle { status, thread }
+}
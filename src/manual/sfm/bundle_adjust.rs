@@ -0,0 +1,608 @@
+use crate::{
+	core::{self, Matx33d, Matx66d, Point2d, Point3d, Vec3d},
+	Error,
+	Result,
+};
+
+/// Robust loss applied to the squared reprojection error of every observation
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BaLoss {
+	/// Plain least squares
+	Squared,
+	/// Quadratic up to the specified pixel error, linear after that
+	Huber(f64),
+	/// Logarithmic growth after the specified pixel error, more aggressive outlier suppression than Huber
+	Cauchy(f64),
+}
+
+impl BaLoss {
+	/// Returns the loss value `rho(s)` and its derivative `rho'(s)` for the squared error `s`
+	fn eval(self, s: f64) -> (f64, f64) {
+		match self {
+			BaLoss::Squared => (s, 1.),
+			BaLoss::Huber(delta) => {
+				let delta_sqr = delta * delta;
+				if s <= delta_sqr {
+					(s, 1.)
+				} else {
+					let norm = s.sqrt();
+					(2. * delta * norm - delta_sqr, delta / norm)
+				}
+			}
+			BaLoss::Cauchy(delta) => {
+				let delta_sqr = delta * delta;
+				(delta_sqr * (s / delta_sqr).ln_1p(), 1. / (1. + s / delta_sqr))
+			}
+		}
+	}
+}
+
+/// Camera of the bundle adjustment problem, intrinsics are kept fixed
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BaCamera {
+	/// `3x3` camera matrix
+	pub k: Matx33d,
+	/// Rotation as a Rodrigues vector
+	pub rvec: Vec3d,
+	pub tvec: Vec3d,
+}
+
+/// Observation of the `point` by the `camera` at the `pixel` position
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BaObservation {
+	pub camera: usize,
+	pub point: usize,
+	pub pixel: Point2d,
+}
+
+/// Options of the [bundle_adjust]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BaOptions {
+	pub loss: BaLoss,
+	pub max_iterations: usize,
+	/// Optimization stops when the relative cost decrease of the accepted step falls below this value
+	pub function_tolerance: f64,
+	/// Initial Levenberg-Marquardt damping
+	pub initial_lambda: f64,
+	/// Cameras with `true` at the corresponding index are not optimized, missing values are `false`
+	///
+	/// Fixing at least one camera removes the gauge freedom of the problem.
+	pub fixed_cameras: Vec<bool>,
+	/// Points with `true` at the corresponding index are not optimized, missing values are `false`
+	pub fixed_points: Vec<bool>,
+	/// Whether the [BaReport::camera_covariances] are calculated
+	pub compute_covariance: bool,
+}
+
+impl Default for BaOptions {
+	fn default() -> Self {
+		Self {
+			loss: BaLoss::Squared,
+			max_iterations: 50,
+			function_tolerance: 1e-6,
+			initial_lambda: 1e-3,
+			fixed_cameras: vec![],
+			fixed_points: vec![],
+			compute_covariance: false,
+		}
+	}
+}
+
+impl BaOptions {
+	pub fn with_loss(mut self, loss: BaLoss) -> Self {
+		self.loss = loss;
+		self
+	}
+
+	pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+		self.max_iterations = max_iterations;
+		self
+	}
+
+	pub fn with_fixed_cameras(mut self, fixed_cameras: Vec<bool>) -> Self {
+		self.fixed_cameras = fixed_cameras;
+		self
+	}
+
+	pub fn with_fixed_points(mut self, fixed_points: Vec<bool>) -> Self {
+		self.fixed_points = fixed_points;
+		self
+	}
+
+	pub fn with_covariance(mut self, compute_covariance: bool) -> Self {
+		self.compute_covariance = compute_covariance;
+		self
+	}
+}
+
+/// State after a single Levenberg-Marquardt iteration
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BaIteration {
+	pub iteration: usize,
+	/// Cost after the iteration (half the sum of the robustified squared reprojection errors)
+	pub cost: f64,
+	/// Damping used for the step
+	pub lambda: f64,
+	/// Whether the step decreased the cost and was applied
+	pub step_accepted: bool,
+}
+
+/// Summary of the [bundle_adjust] run
+#[derive(Clone, Debug, PartialEq)]
+pub struct BaReport {
+	pub initial_cost: f64,
+	pub final_cost: f64,
+	pub iterations: Vec<BaIteration>,
+	/// `false` if the optimization stopped because of `max_iterations`
+	pub converged: bool,
+	/// `6x6` covariance of each camera pose in the `[rvec, tvec]` order, zero for the fixed cameras
+	///
+	/// It's the inverse of the Gauss-Newton Hessian with the points marginalized, scaled by the residual variance.
+	/// `None` if not requested with [BaOptions::compute_covariance] or if the Hessian is singular, the gauge freedom
+	/// must be removed by fixing the cameras or the points for the covariance to be meaningful.
+	pub camera_covariances: Option<Vec<Matx66d>>,
+}
+
+/// Refines camera poses and 3D points by minimizing the reprojection error
+///
+/// Runs sparse Levenberg-Marquardt (points are eliminated with the Schur complement) on the poses of `cameras` and
+/// `points` in place.
+///
+/// This is a standalone solver written in Rust, not a binding of the Ceres based bundle adjustment of libmv: the `sfm`
+/// module only runs that one inside of its reconstruction pipeline and doesn't expose it for the external data. The
+/// solver is approximate compared to Ceres: the Jacobians are computed numerically with the central differences, the
+/// reduced camera system is solved with the dense Cholesky decomposition and the robust loss is applied by reweighting
+/// the residuals. The cost of an iteration grows with the cube of the number of the optimized cameras, so it's meant for
+/// the problems of up to a few hundred cameras.
+#[inline]
+pub fn bundle_adjust(cameras: &mut [BaCamera], points: &mut [Point3d], observations: &[BaObservation], options: &BaOptions) -> Result<BaReport> {
+	bundle_adjust_with_callback(cameras, points, observations, options, |_| {})
+}
+
+/// Same as [bundle_adjust], but calls `on_iteration` after every iteration
+pub fn bundle_adjust_with_callback(
+	cameras: &mut [BaCamera],
+	points: &mut [Point3d],
+	observations: &[BaObservation],
+	options: &BaOptions,
+	mut on_iteration: impl FnMut(&BaIteration),
+) -> Result<BaReport> {
+	for obs in observations {
+		if obs.camera >= cameras.len() || obs.point >= points.len() {
+			return Err(Error::new(core::StsOutOfRange, format!("Observation references camera: {} and point: {}, but there are only: {} cameras and: {} points", obs.camera, obs.point, cameras.len(), points.len())));
+		}
+	}
+	let mut problem = Problem::new(cameras, points, observations, options);
+	let initial_cost = problem.cost(&problem.cameras, &problem.points);
+	let mut cost = initial_cost;
+	let mut lambda = options.initial_lambda;
+	let mut iterations = Vec::with_capacity(options.max_iterations);
+	let mut converged = false;
+	for iteration in 0..options.max_iterations {
+		let step = problem.step(lambda);
+		let (new_cameras, new_points) = match step {
+			Some(step) => step,
+			None => {
+				lambda *= 10.;
+				let it = BaIteration { iteration, cost, lambda, step_accepted: false };
+				on_iteration(&it);
+				iterations.push(it);
+				continue;
+			}
+		};
+		let new_cost = problem.cost(&new_cameras, &new_points);
+		let step_accepted = new_cost < cost;
+		if step_accepted {
+			let decrease = (cost - new_cost) / cost.max(f64::MIN_POSITIVE);
+			problem.cameras = new_cameras;
+			problem.points = new_points;
+			cost = new_cost;
+			lambda = (lambda / 3.).max(1e-12);
+			converged = decrease < options.function_tolerance;
+		} else {
+			lambda *= 10.;
+		}
+		let it = BaIteration { iteration, cost, lambda, step_accepted };
+		on_iteration(&it);
+		iterations.push(it);
+		if converged || cost == 0. {
+			converged = true;
+			break;
+		}
+	}
+	for (dst, src) in cameras.iter_mut().zip(&problem.cameras) {
+		dst.rvec = Vec3d::from([src[0], src[1], src[2]]);
+		dst.tvec = Vec3d::from([src[3], src[4], src[5]]);
+	}
+	for (dst, src) in points.iter_mut().zip(&problem.points) {
+		*dst = Point3d::new(src[0], src[1], src[2]);
+	}
+	let camera_covariances = if options.compute_covariance {
+		problem.camera_covariances(cost)
+	} else {
+		None
+	};
+	Ok(BaReport { initial_cost, final_cost: cost, iterations, converged, camera_covariances })
+}
+
+/// Covariance of the single camera pose observing the fixed points, `None` if it's not constrained by the points
+pub(super) fn pose_covariance(camera: BaCamera, points: &[Point3d], pixels: &[Point2d]) -> Option<Matx66d> {
+	let observations = pixels.iter()
+		.enumerate()
+		.map(|(point, &pixel)| BaObservation { camera: 0, point, pixel })
+		.collect::<Vec<_>>();
+	let options = BaOptions::default().with_fixed_points(vec![true; points.len()]);
+	let problem = Problem::new(&[camera], points, &observations, &options);
+	let cost = problem.cost(&problem.cameras, &problem.points);
+	problem.camera_covariances(cost)?.pop()
+}
+
+type Mat6x3 = [[f64; 3]; 6];
+type Mat3x3 = [[f64; 3]; 3];
+/// Camera poses and points
+type Params = (Vec<[f64; 6]>, Vec<[f64; 3]>);
+
+struct Problem<'o> {
+	k: Vec<[f64; 9]>,
+	/// Rodrigues vector followed by translation
+	cameras: Vec<[f64; 6]>,
+	points: Vec<[f64; 3]>,
+	observations: &'o [BaObservation],
+	loss: BaLoss,
+	/// Index of the camera in the reduced system, `None` for fixed cameras
+	camera_var: Vec<Option<usize>>,
+	point_fixed: Vec<bool>,
+	/// Observation indices grouped by point
+	point_observations: Vec<Vec<usize>>,
+}
+
+impl<'o> Problem<'o> {
+	fn new(cameras: &[BaCamera], points: &[Point3d], observations: &'o [BaObservation], options: &BaOptions) -> Self {
+		let mut var_count = 0;
+		let camera_var = (0..cameras.len())
+			.map(|i| if options.fixed_cameras.get(i).copied().unwrap_or(false) {
+				None
+			} else {
+				var_count += 1;
+				Some(var_count - 1)
+			})
+			.collect();
+		let mut point_observations = vec![vec![]; points.len()];
+		for (i, obs) in observations.iter().enumerate() {
+			point_observations[obs.point].push(i);
+		}
+		Self {
+			k: cameras.iter().map(|c| {
+				let mut k = [0.; 9];
+				for (i, v) in k.iter_mut().enumerate() {
+					*v = c.k[(i / 3, i % 3)];
+				}
+				k
+			}).collect(),
+			cameras: cameras.iter().map(|c| [c.rvec[0], c.rvec[1], c.rvec[2], c.tvec[0], c.tvec[1], c.tvec[2]]).collect(),
+			points: points.iter().map(|p| [p.x, p.y, p.z]).collect(),
+			observations,
+			loss: options.loss,
+			camera_var,
+			point_fixed: (0..points.len()).map(|i| options.fixed_points.get(i).copied().unwrap_or(false)).collect(),
+			point_observations,
+		}
+	}
+
+	fn residual(&self, obs: &BaObservation, camera: &[f64; 6], point: &[f64; 3]) -> [f64; 2] {
+		let p = project(&self.k[obs.camera], camera, point);
+		[p[0] - obs.pixel.x, p[1] - obs.pixel.y]
+	}
+
+	fn cost(&self, cameras: &[[f64; 6]], points: &[[f64; 3]]) -> f64 {
+		self.observations.iter()
+			.map(|obs| {
+				let r = self.residual(obs, &cameras[obs.camera], &points[obs.point]);
+				self.loss.eval(r[0] * r[0] + r[1] * r[1]).0
+			})
+			.sum::<f64>() / 2.
+	}
+
+	/// Builds the damped normal equations with the points eliminated by the Schur complement
+	fn reduce(&self, lambda: f64) -> Reduced {
+		let var_count = self.camera_var.iter().flatten().count();
+		let mut u = vec![[[0.; 6]; 6]; var_count];
+		let mut ga = vec![[0.; 6]; var_count];
+		let mut v = vec![[[0.; 3]; 3]; self.points.len()];
+		let mut gb = vec![[0.; 3]; self.points.len()];
+		let mut w = vec![[[0.; 3]; 6]; self.observations.len()];
+		for (obs_idx, obs) in self.observations.iter().enumerate() {
+			let camera = &self.cameras[obs.camera];
+			let point = &self.points[obs.point];
+			let r = self.residual(obs, camera, point);
+			let weight = self.loss.eval(r[0] * r[0] + r[1] * r[1]).1;
+			let jc = self.camera_var[obs.camera].map(|_| numeric_jacobian(camera, |c| self.residual(obs, c, point)));
+			let jp = if self.point_fixed[obs.point] {
+				None
+			} else {
+				Some(numeric_jacobian(point, |p| self.residual(obs, camera, p)))
+			};
+			if let (Some(var), Some(jc)) = (self.camera_var[obs.camera], &jc) {
+				accumulate(&mut u[var], &mut ga[var], jc, jc, &r, weight);
+			}
+			if let Some(jp) = &jp {
+				accumulate(&mut v[obs.point], &mut gb[obs.point], jp, jp, &r, weight);
+			}
+			if let (Some(jc), Some(jp)) = (&jc, &jp) {
+				for (a, w_row) in w[obs_idx].iter_mut().enumerate() {
+					for (b, w_val) in w_row.iter_mut().enumerate() {
+						*w_val = weight * (jc[0][a] * jp[0][b] + jc[1][a] * jp[1][b]);
+					}
+				}
+			}
+		}
+
+		for u in &mut u {
+			damp(u, lambda);
+		}
+		let v_inv = v.iter_mut()
+			.zip(&self.point_fixed)
+			.map(|(v, &fixed)| if fixed {
+				[[0.; 3]; 3]
+			} else {
+				damp(v, lambda);
+				invert3(v).unwrap_or([[0.; 3]; 3])
+			})
+			.collect::<Vec<_>>();
+
+		// reduced camera system: (U - W V^-1 W^T) da = -ga + W V^-1 gb
+		let n = var_count * 6;
+		let mut s = vec![0.; n * n];
+		let mut rhs = vec![0.; n];
+		for (var, u) in u.iter().enumerate() {
+			for a in 0..6 {
+				rhs[var * 6 + a] = -ga[var][a];
+				for b in 0..6 {
+					s[(var * 6 + a) * n + var * 6 + b] = u[a][b];
+				}
+			}
+		}
+		for (point, obs_ids) in self.point_observations.iter().enumerate() {
+			if self.point_fixed[point] {
+				continue;
+			}
+			let v_inv = &v_inv[point];
+			for &i in obs_ids {
+				let var_i = match self.camera_var[self.observations[i].camera] {
+					Some(var) => var,
+					None => continue,
+				};
+				let wv = mul_6x3_3x3(&w[i], v_inv);
+				for a in 0..6 {
+					rhs[var_i * 6 + a] += (0..3).map(|b| wv[a][b] * gb[point][b]).sum::<f64>();
+				}
+				for &j in obs_ids {
+					let var_j = match self.camera_var[self.observations[j].camera] {
+						Some(var) => var,
+						None => continue,
+					};
+					for a in 0..6 {
+						for b in 0..6 {
+							s[(var_i * 6 + a) * n + var_j * 6 + b] -= (0..3).map(|c| wv[a][c] * w[j][b][c]).sum::<f64>();
+						}
+					}
+				}
+			}
+		}
+		Reduced { s, rhs, n, v_inv, gb, w }
+	}
+
+	/// Computes damped Gauss-Newton step, returns updated parameters or `None` if the system can't be solved
+	fn step(&self, lambda: f64) -> Option<Params> {
+		let Reduced { mut s, mut rhs, n, v_inv, gb, w } = self.reduce(lambda);
+		let da = cholesky_solve(&mut s, &mut rhs, n)?;
+
+		let mut cameras = self.cameras.clone();
+		for (camera, var) in cameras.iter_mut().zip(&self.camera_var) {
+			if let Some(var) = var {
+				camera.iter_mut().enumerate().for_each(|(a, x)| *x += da[var * 6 + a]);
+			}
+		}
+		let mut points = self.points.clone();
+		for (point, obs_ids) in self.point_observations.iter().enumerate() {
+			if self.point_fixed[point] {
+				continue;
+			}
+			// db = V^-1 (-gb - W^T da)
+			let mut tmp = gb[point].map(|g| -g);
+			for &i in obs_ids {
+				if let Some(var) = self.camera_var[self.observations[i].camera] {
+					for (b, tmp) in tmp.iter_mut().enumerate() {
+						*tmp -= (0..6).map(|a| w[i][a][b] * da[var * 6 + a]).sum::<f64>();
+					}
+				}
+			}
+			for (b, x) in points[point].iter_mut().enumerate() {
+				*x += (0..3).map(|c| v_inv[point][b][c] * tmp[c]).sum::<f64>();
+			}
+		}
+		if da.iter().chain(points.iter().flatten()).all(|x| x.is_finite()) {
+			Some((cameras, points))
+		} else {
+			None
+		}
+	}
+
+	/// Returns the marginal covariances of the cameras at the current parameters with the final `cost`
+	fn camera_covariances(&self, cost: f64) -> Option<Vec<Matx66d>> {
+		let Reduced { mut s, n, .. } = self.reduce(0.);
+		let free_points = self.point_fixed.iter().filter(|&&fixed| !fixed).count();
+		let dof = (2 * self.observations.len()).saturating_sub(n + 3 * free_points).max(1);
+		let sigma_sqr = 2. * cost / dof as f64;
+		if !cholesky_factor(&mut s, n) {
+			return None;
+		}
+		// only the diagonal blocks of the inverse are needed
+		let mut blocks = vec![Matx66d::default(); n / 6];
+		for col in 0..n {
+			let mut e = vec![0.; n];
+			e[col] = 1.;
+			cholesky_substitute(&s, &mut e, n);
+			let (var, b) = (col / 6, col % 6);
+			for a in 0..6 {
+				blocks[var][(a, b)] = sigma_sqr * e[var * 6 + a];
+			}
+		}
+		Some(self.camera_var.iter()
+			.map(|var| var.map(|var| blocks[var]).unwrap_or_default())
+			.collect())
+	}
+}
+
+/// Reduced camera system `s * da = rhs` with the data for the point back-substitution
+struct Reduced {
+	s: Vec<f64>,
+	rhs: Vec<f64>,
+	n: usize,
+	v_inv: Vec<Mat3x3>,
+	gb: Vec<[f64; 3]>,
+	w: Vec<Mat6x3>,
+}
+
+/// Projects `point` with camera matrix `k` and pose `camera` (Rodrigues vector and translation)
+fn project(k: &[f64; 9], camera: &[f64; 6], point: &[f64; 3]) -> [f64; 2] {
+	let r = rodrigues(&[camera[0], camera[1], camera[2]]);
+	let mut pc = [camera[3], camera[4], camera[5]];
+	for (i, pc) in pc.iter_mut().enumerate() {
+		*pc += r[i][0] * point[0] + r[i][1] * point[1] + r[i][2] * point[2];
+	}
+	let x = k[0] * pc[0] + k[1] * pc[1] + k[2] * pc[2];
+	let y = k[3] * pc[0] + k[4] * pc[1] + k[5] * pc[2];
+	let z = k[6] * pc[0] + k[7] * pc[1] + k[8] * pc[2];
+	[x / z, y / z]
+}
+
+/// Converts a rotation vector to a rotation matrix
+fn rodrigues(rvec: &[f64; 3]) -> Mat3x3 {
+	let theta = (rvec[0] * rvec[0] + rvec[1] * rvec[1] + rvec[2] * rvec[2]).sqrt();
+	if theta < f64::EPSILON {
+		return [
+			[1., -rvec[2], rvec[1]],
+			[rvec[2], 1., -rvec[0]],
+			[-rvec[1], rvec[0], 1.],
+		];
+	}
+	let [x, y, z] = rvec.map(|v| v / theta);
+	let (s, c) = theta.sin_cos();
+	let c1 = 1. - c;
+	[
+		[c + x * x * c1, x * y * c1 - z * s, x * z * c1 + y * s],
+		[y * x * c1 + z * s, c + y * y * c1, y * z * c1 - x * s],
+		[z * x * c1 - y * s, z * y * c1 + x * s, c + z * z * c1],
+	]
+}
+
+/// Central difference Jacobian of the 2D residual `f` with respect to `x`
+fn numeric_jacobian<const N: usize>(x: &[f64; N], f: impl Fn(&[f64; N]) -> [f64; 2]) -> [[f64; N]; 2] {
+	let mut out = [[0.; N]; 2];
+	let mut x = *x;
+	for i in 0..N {
+		let orig = x[i];
+		let h = 1e-6 * (1. + orig.abs());
+		x[i] = orig + h;
+		let plus = f(&x);
+		x[i] = orig - h;
+		let minus = f(&x);
+		x[i] = orig;
+		out[0][i] = (plus[0] - minus[0]) / (2. * h);
+		out[1][i] = (plus[1] - minus[1]) / (2. * h);
+	}
+	out
+}
+
+/// `h += weight * a^T b`, `g += weight * a^T r`
+fn accumulate<const N: usize>(h: &mut [[f64; N]; N], g: &mut [f64; N], a: &[[f64; N]; 2], b: &[[f64; N]; 2], r: &[f64; 2], weight: f64) {
+	for i in 0..N {
+		g[i] += weight * (a[0][i] * r[0] + a[1][i] * r[1]);
+		for j in 0..N {
+			h[i][j] += weight * (a[0][i] * b[0][j] + a[1][i] * b[1][j]);
+		}
+	}
+}
+
+/// Marquardt damping of the diagonal
+fn damp<const N: usize>(h: &mut [[f64; N]; N], lambda: f64) {
+	for (i, row) in h.iter_mut().enumerate() {
+		row[i] += lambda * row[i] + 1e-12;
+	}
+}
+
+fn invert3(m: &Mat3x3) -> Option<Mat3x3> {
+	let cof = |r0: usize, r1: usize, c0: usize, c1: usize| m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0];
+	let det = m[0][0] * cof(1, 2, 1, 2) - m[0][1] * cof(1, 2, 0, 2) + m[0][2] * cof(1, 2, 0, 1);
+	if det.abs() < 1e-300 {
+		return None;
+	}
+	let inv_det = 1. / det;
+	Some([
+		[cof(1, 2, 1, 2) * inv_det, -cof(0, 2, 1, 2) * inv_det, cof(0, 1, 1, 2) * inv_det],
+		[-cof(1, 2, 0, 2) * inv_det, cof(0, 2, 0, 2) * inv_det, -cof(0, 1, 0, 2) * inv_det],
+		[cof(1, 2, 0, 1) * inv_det, -cof(0, 2, 0, 1) * inv_det, cof(0, 1, 0, 1) * inv_det],
+	])
+}
+
+fn mul_6x3_3x3(a: &Mat6x3, b: &Mat3x3) -> Mat6x3 {
+	let mut out = [[0.; 3]; 6];
+	for (out_row, a_row) in out.iter_mut().zip(a) {
+		for (j, out) in out_row.iter_mut().enumerate() {
+			*out = (0..3).map(|k| a_row[k] * b[k][j]).sum();
+		}
+	}
+	out
+}
+
+/// Solves `a x = b` for symmetric positive definite `n x n` matrix `a` in place, returns `None` if `a` is not positive
+/// definite
+fn cholesky_solve(a: &mut [f64], b: &mut [f64], n: usize) -> Option<Vec<f64>> {
+	if !cholesky_factor(a, n) {
+		return None;
+	}
+	cholesky_substitute(a, b, n);
+	Some(b.to_vec())
+}
+
+/// Replaces the lower triangle of `a` with its Cholesky factor `L`, returns `false` if `a` is not positive definite
+fn cholesky_factor(a: &mut [f64], n: usize) -> bool {
+	for j in 0..n {
+		let mut d = a[j * n + j];
+		for k in 0..j {
+			d -= a[j * n + k] * a[j * n + k];
+		}
+		if d <= 0. || !d.is_finite() {
+			return false;
+		}
+		let d = d.sqrt();
+		a[j * n + j] = d;
+		for i in j + 1..n {
+			let mut s = a[i * n + j];
+			for k in 0..j {
+				s -= a[i * n + k] * a[j * n + k];
+			}
+			a[i * n + j] = s / d;
+		}
+	}
+	true
+}
+
+/// Solves `L L^T x = b` in place with the factor from the [cholesky_factor]
+fn cholesky_substitute(l: &[f64], b: &mut [f64], n: usize) {
+	for i in 0..n {
+		let mut s = b[i];
+		for k in 0..i {
+			s -= l[i * n + k] * b[k];
+		}
+		b[i] = s / l[i * n + i];
+	}
+	for i in (0..n).rev() {
+		let mut s = b[i];
+		for k in i + 1..n {
+			s -= l[k * n + i] * b[k];
+		}
+		b[i] = s / l[i * n + i];
+	}
+}
@@ -0,0 +1,211 @@
+//! Post-processing of the reconstructed point clouds
+//!
+//! Functions work on `&[Point3d]`, use [points_from_mat] and [points_to_mat] to convert from and to the `3xN` Mats
+//! produced by `sfm::reconstruct()`. Neighbor search is done with the `flann` KD-tree.
+
+use std::{cmp::Ordering, collections::HashMap};
+
+use crate::{
+	core::{self, Mat, Point3d, Vec3d},
+	Error,
+	flann,
+	prelude::*,
+	Result,
+};
+
+/// Reads the points from `3xN` or `Nx3` 1-channel Mat or from `Nx1`/`1xN` 3-channel Mat of `CV_32F` or `CV_64F` depth
+pub fn points_from_mat(mat: &Mat) -> Result<Vec<Point3d>> {
+	let mut mat_f64 = Mat::default();
+	mat.convert_to(&mut mat_f64, core::CV_64F, 1., 0.)?;
+	let (rows, cols) = (mat_f64.rows(), mat_f64.cols());
+	match mat_f64.channels() {
+		3 if rows == 1 || cols == 1 => {
+			(0..rows * cols).map(|i| mat_f64.at::<Vec3d>(i).map(|v| Point3d::new(v[0], v[1], v[2]))).collect()
+		}
+		1 if rows == 3 => {
+			(0..cols).map(|c| Ok(Point3d::new(*mat_f64.at_2d::<f64>(0, c)?, *mat_f64.at_2d::<f64>(1, c)?, *mat_f64.at_2d::<f64>(2, c)?))).collect()
+		}
+		1 if cols == 3 => {
+			(0..rows).map(|r| Ok(Point3d::new(*mat_f64.at_2d::<f64>(r, 0)?, *mat_f64.at_2d::<f64>(r, 1)?, *mat_f64.at_2d::<f64>(r, 2)?))).collect()
+		}
+		_ if mat_f64.empty() => Ok(vec![]),
+		channels => Err(Error::new(core::StsBadSize, format!("Expected 3xN, Nx3 or 3-channel vector Mat, but got: {}x{} with: {} channels", rows, cols, channels))),
+	}
+}
+
+/// Converts the points into `3xN` `CV_64F` Mat, the same layout as the `sfm::reconstruct()` output
+pub fn points_to_mat(points: &[Point3d]) -> Result<Mat> {
+	Mat::from_slice_2d(&[
+		points.iter().map(|p| p.x).collect::<Vec<_>>(),
+		points.iter().map(|p| p.y).collect(),
+		points.iter().map(|p| p.z).collect(),
+	])
+}
+
+/// Returns the mask of the points that are not statistical outliers
+///
+/// For every point the mean distance to its `k` nearest neighbors is calculated, a point is an outlier if that distance
+/// exceeds the global mean by more than `std_ratio` standard deviations.
+pub fn statistical_outlier_mask(points: &[Point3d], k: usize, std_ratio: f64) -> Result<Vec<bool>> {
+	if points.len() <= k {
+		return Ok(vec![true; points.len()]);
+	}
+	let mean_dists = Neighbors::search(points, k)?.mean_distances();
+	let count = mean_dists.len() as f64;
+	let mean = mean_dists.iter().sum::<f64>() / count;
+	let std_dev = (mean_dists.iter().map(|d| (d - mean).powi(2)).sum::<f64>() / count).sqrt();
+	let threshold = mean + std_ratio * std_dev;
+	Ok(mean_dists.into_iter().map(|d| d <= threshold).collect())
+}
+
+/// Returns the points without the statistical outliers, see [statistical_outlier_mask]
+pub fn remove_statistical_outliers(points: &[Point3d], k: usize, std_ratio: f64) -> Result<Vec<Point3d>> {
+	let mask = statistical_outlier_mask(points, k, std_ratio)?;
+	Ok(points.iter().zip(mask).filter(|(_, keep)| *keep).map(|(p, _)| *p).collect())
+}
+
+/// Replaces the points within every `voxel_size` cube by their centroid
+///
+/// Output is ordered by the first occurrence of the voxel in the input.
+pub fn voxel_downsample(points: &[Point3d], voxel_size: f64) -> Result<Vec<Point3d>> {
+	if voxel_size <= 0. || !voxel_size.is_finite() {
+		return Err(Error::new(core::StsOutOfRange, format!("Voxel size must be positive, but got: {}", voxel_size)));
+	}
+	let mut voxels = HashMap::<(i64, i64, i64), usize>::with_capacity(points.len());
+	let mut sums = Vec::<(Point3d, usize)>::new();
+	for p in points {
+		let key = ((p.x / voxel_size).floor() as i64, (p.y / voxel_size).floor() as i64, (p.z / voxel_size).floor() as i64);
+		let idx = *voxels.entry(key).or_insert_with(|| {
+			sums.push((Point3d::default(), 0));
+			sums.len() - 1
+		});
+		let (sum, count) = &mut sums[idx];
+		*sum += *p;
+		*count += 1;
+	}
+	Ok(sums.into_iter().map(|(sum, count)| sum / count as f64).collect())
+}
+
+/// Estimates unit normals from the `k` nearest neighbors of every point
+///
+/// Normal is the direction of the smallest variance of the neighborhood, it's oriented towards the `viewpoint` (e.g.
+/// the camera center). Normals of the points with less than 2 neighbors are zero.
+pub fn estimate_normals(points: &[Point3d], k: usize, viewpoint: Point3d) -> Result<Vec<Vec3d>> {
+	if points.len() < 3 || k < 2 {
+		return Ok(vec![Vec3d::default(); points.len()]);
+	}
+	let neighbors = Neighbors::search(points, k.min(points.len() - 1))?;
+	Ok(points.iter()
+		.enumerate()
+		.map(|(i, p)| {
+			let hood = neighbors.of(i).map(|n| points[n]).chain(Some(*p)).collect::<Vec<_>>();
+			let centroid = hood.iter().fold(Point3d::default(), |acc, &p| acc + p) / hood.len() as f64;
+			let mut cov = [[0.; 3]; 3];
+			for p in &hood {
+				let d = [p.x - centroid.x, p.y - centroid.y, p.z - centroid.z];
+				for (r, row) in cov.iter_mut().enumerate() {
+					for (c, v) in row.iter_mut().enumerate() {
+						*v += d[r] * d[c];
+					}
+				}
+			}
+			let mut n = smallest_eigenvector(cov);
+			let to_view = viewpoint - *p;
+			if n[0] * to_view.x + n[1] * to_view.y + n[2] * to_view.z < 0. {
+				n = n.map(|v| -v);
+			}
+			Vec3d::from(n)
+		})
+		.collect())
+}
+
+/// Result of the k nearest neighbors search for every point of the cloud, the point itself is excluded
+struct Neighbors {
+	k: usize,
+	indices: Vec<i32>,
+	sqr_dists: Vec<f32>,
+}
+
+impl Neighbors {
+	fn search(points: &[Point3d], k: usize) -> Result<Self> {
+		let features = Mat::from_slice_2d(&points.iter().map(|p| [p.x as f32, p.y as f32, p.z as f32]).collect::<Vec<_>>())?;
+		let mut index = flann::Index::new(&features, &flann::KDTreeIndexParams::new(4)?.into(), flann::flann_distance_t::FLANN_DIST_EUCLIDEAN)?;
+		let mut indices = Mat::default();
+		let mut dists = Mat::default();
+		let knn = k + 1;
+		index.knn_search(&features, &mut indices, &mut dists, knn as i32, &flann::SearchParams::new_1(flann::FLANN_CHECKS_UNLIMITED, 0., true)?)?;
+		let indices = indices.data_typed::<i32>()?;
+		let dists = dists.data_typed::<f32>()?;
+		let mut out = Self { k, indices: Vec::with_capacity(points.len() * k), sqr_dists: Vec::with_capacity(points.len() * k) };
+		for (i, (indices, dists)) in indices.chunks_exact(knn).zip(dists.chunks_exact(knn)).enumerate() {
+			// the point itself is normally the first result, but it's not guaranteed with duplicate points
+			let self_pos = indices.iter().position(|&n| n == i as i32).unwrap_or(k);
+			for (j, (&n, &d)) in indices.iter().zip(dists).enumerate() {
+				if j != self_pos {
+					out.indices.push(n);
+					out.sqr_dists.push(d);
+				}
+			}
+		}
+		Ok(out)
+	}
+
+	fn of(&self, point: usize) -> impl Iterator<Item=usize> + '_ {
+		self.indices[point * self.k..(point + 1) * self.k].iter().map(|&n| n as usize)
+	}
+
+	fn mean_distances(&self) -> Vec<f64> {
+		self.sqr_dists.chunks_exact(self.k)
+			.map(|d| d.iter().map(|&d| f64::from(d).sqrt()).sum::<f64>() / self.k as f64)
+			.collect()
+	}
+}
+
+/// Returns the unit eigenvector of the symmetric `3x3` matrix corresponding to its smallest eigenvalue
+fn smallest_eigenvector(a: [[f64; 3]; 3]) -> [f64; 3] {
+	symmetric_eigen(a)[0].1
+}
+
+/// Returns the eigenvalues with the unit eigenvectors of the symmetric `3x3` matrix in the ascending order of the
+/// eigenvalues (Jacobi method)
+pub(super) fn symmetric_eigen(mut a: [[f64; 3]; 3]) -> [(f64, [f64; 3]); 3] {
+	let mut v = [[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]];
+	for _ in 0..32 {
+		let off_diag = a[0][1].abs() + a[0][2].abs() + a[1][2].abs();
+		if off_diag < 1e-15 {
+			break;
+		}
+		for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+			if a[p][q].abs() < 1e-300 {
+				continue;
+			}
+			let theta = (a[q][q] - a[p][p]) / (2. * a[p][q]);
+			let t = theta.signum() / (theta.abs() + (theta * theta + 1.).sqrt());
+			let c = 1. / (t * t + 1.).sqrt();
+			let s = t * c;
+			for row in &mut a {
+				let (akp, akq) = (row[p], row[q]);
+				row[p] = c * akp - s * akq;
+				row[q] = s * akp + c * akq;
+			}
+			let (row_p, row_q) = (a[p], a[q]);
+			for k in 0..3 {
+				a[p][k] = c * row_p[k] - s * row_q[k];
+				a[q][k] = s * row_p[k] + c * row_q[k];
+			}
+			for row in &mut v {
+				let (vp, vq) = (row[p], row[q]);
+				row[p] = c * vp - s * vq;
+				row[q] = s * vp + c * vq;
+			}
+		}
+	}
+	let mut out = [(0., [0.; 3]); 3];
+	for (i, pair) in out.iter_mut().enumerate() {
+		let n = [v[0][i], v[1][i], v[2][i]];
+		let norm = (n[0] * n[0] + n[1] * n[1] + n[2] * n[2]).sqrt();
+		*pair = (a[i][i], [n[0] / norm, n[1] / norm, n[2] / norm]);
+	}
+	out.sort_unstable_by(|x, y| x.0.partial_cmp(&y.0).unwrap_or(Ordering::Equal));
+	out
+}
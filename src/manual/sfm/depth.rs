@@ -0,0 +1,91 @@
+use crate::{
+	core::{self, Mat, Matx33d, Point3d},
+	Error,
+	prelude::*,
+	Result,
+};
+
+/// Triangle mesh as the plain vertex and face buffers
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TriangleMesh {
+	pub vertices: Vec<Point3d>,
+	/// Vertex indices of every triangle, counter-clockwise when looking from the camera
+	pub faces: Vec<[u32; 3]>,
+}
+
+/// Back-projects every valid pixel of the `depth` map into the camera coordinates
+///
+/// `depth` is a single channel Mat of any depth (e.g. `CV_16U` from a depth sensor, `CV_32F` computed from the
+/// `StereoSGBM` disparity or predicted by a `dnn` model), the values are used as is, so scale them to the desired units
+/// beforehand. Zero and non-finite values mark missing depth. `k` is the `3x3` camera matrix.
+pub fn depth_to_cloud(depth: &Mat, k: Matx33d) -> Result<Vec<Point3d>> {
+	let mut out = Vec::new();
+	for_each_depth_point(depth, k, |p| {
+		if let Some(p) = p {
+			out.push(p);
+		}
+	})?;
+	Ok(out)
+}
+
+/// Builds the organized mesh connecting the neighboring pixels of the `depth` map, see [depth_to_cloud]
+///
+/// Every `2x2` block of valid pixels produces 2 triangles, a triangle is skipped if any of its edges is longer than
+/// `max_edge_length` which removes the surfaces spanning the depth discontinuities (use `f64::INFINITY` to keep all of
+/// them). Only the vertices that are referenced by the faces are kept.
+pub fn depth_to_mesh(depth: &Mat, k: Matx33d, max_edge_length: f64) -> Result<TriangleMesh> {
+	let (rows, cols) = (depth.rows() as usize, depth.cols() as usize);
+	let mut grid = Vec::with_capacity(depth.total());
+	for_each_depth_point(depth, k, |p| grid.push(p))?;
+	let mut vertex_ids = vec![None; grid.len()];
+	let mut out = TriangleMesh::default();
+	let mut vertex_id = |out: &mut TriangleMesh, idx: usize, p: Point3d| {
+		*vertex_ids[idx].get_or_insert_with(|| {
+			out.vertices.push(p);
+			out.vertices.len() as u32 - 1
+		})
+	};
+	let max_sqr = max_edge_length * max_edge_length;
+	let short_edges = |a: Point3d, b: Point3d, c: Point3d| [a - b, b - c, c - a].iter().all(|d| d.dot(*d) <= max_sqr);
+	for row in 0..rows.saturating_sub(1) {
+		for col in 0..cols.saturating_sub(1) {
+			let tl = row * cols + col;
+			let (tr, bl, br) = (tl + 1, tl + cols, tl + cols + 1);
+			for [a, b, c] in [[tl, bl, tr], [tr, bl, br]] {
+				if let (Some(pa), Some(pb), Some(pc)) = (grid[a], grid[b], grid[c]) {
+					if short_edges(pa, pb, pc) {
+						let face = [vertex_id(&mut out, a, pa), vertex_id(&mut out, b, pb), vertex_id(&mut out, c, pc)];
+						out.faces.push(face);
+					}
+				}
+			}
+		}
+	}
+	Ok(out)
+}
+
+/// Calls `f(point)` for every pixel in the row-major order, `point` is `None` for missing depth
+fn for_each_depth_point(depth: &Mat, k: Matx33d, mut f: impl FnMut(Option<Point3d>)) -> Result<()> {
+	if depth.channels() != 1 || depth.dims() > 2 {
+		return Err(Error::new(core::StsBadArg, format!("Depth must be a single channel 2D Mat, but it has: {} channels", depth.channels())));
+	}
+	let (fx, fy, skew, cx, cy) = (k[(0, 0)], k[(1, 1)], k[(0, 1)], k[(0, 2)], k[(1, 2)]);
+	if fx == 0. || fy == 0. {
+		return Err(Error::new(core::StsBadArg, "Camera matrix has zero focal length"));
+	}
+	let mut depth_f64 = Mat::default();
+	depth.convert_to(&mut depth_f64, core::CV_64F, 1., 0.)?;
+	for row in 0..depth_f64.rows() {
+		let y_norm = (f64::from(row) - cy) / fy;
+		for (col, &z) in depth_f64.at_row::<f64>(row)?.iter().enumerate() {
+			let point = if z != 0. && z.is_finite() {
+				let x_norm = (col as f64 - cx - skew * y_norm) / fx;
+				Some(Point3d::new(x_norm * z, y_norm * z, z))
+			} else {
+				None
+			};
+			f(point);
+		}
+	}
+	Ok(())
+}
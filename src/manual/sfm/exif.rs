@@ -0,0 +1,195 @@
+use std::{
+	convert::TryInto,
+	fs::File,
+	io::Read,
+	path::Path,
+};
+
+use crate::{
+	core,
+	Error,
+	Result,
+};
+
+/// EXIF headers are in the first segments of the file, the rest of it is not read
+const MAX_HEADER_SIZE: u64 = 1 << 20;
+/// Diagonal of the 35 mm film frame in millimeters
+const FILM_35MM_DIAGONAL: f64 = 43.266_615;
+
+/// Camera information from the EXIF header of a JPEG file
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Exif {
+	pub make: Option<String>,
+	pub model: Option<String>,
+	/// Physical focal length in millimeters
+	pub focal_length_mm: Option<f64>,
+	/// Focal length equivalent for the 35 mm film in millimeters
+	pub focal_length_35mm: Option<f64>,
+	/// Sensor pixels per millimeter along X
+	pub focal_plane_x_resolution: Option<f64>,
+	/// Width of the image the EXIF was written for, the file can be downscaled since
+	pub pixel_width: Option<u32>,
+	pub pixel_height: Option<u32>,
+}
+
+impl Exif {
+	/// Reads the EXIF header of the JPEG file, returns `None` if the file has none
+	pub fn from_file(path: impl AsRef<Path>) -> Result<Option<Self>> {
+		let path = path.as_ref();
+		let mut data = vec![];
+		File::open(path)
+			.and_then(|file| file.take(MAX_HEADER_SIZE).read_to_end(&mut data))
+			.map_err(|e| Error::new(core::StsError, format!("Can't read: {}: {}", path.display(), e)))?;
+		Ok(Self::from_jpeg(&data))
+	}
+
+	/// Parses the EXIF header of the JPEG data, returns `None` if it's not a JPEG or it has no EXIF
+	pub fn from_jpeg(data: &[u8]) -> Option<Self> {
+		if data.get(..2)? != [0xFF, 0xD8] {
+			return None;
+		}
+		let mut pos = 2;
+		while pos + 4 <= data.len() && data[pos] == 0xFF {
+			let marker = data[pos + 1];
+			let len = usize::from(u16::from_be_bytes([data[pos + 2], data[pos + 3]]));
+			// start of the scan, the headers are over
+			if marker == 0xDA {
+				break;
+			}
+			let segment = data.get(pos + 4..pos + 2 + len)?;
+			if marker == 0xE1 && segment.starts_with(b"Exif\0\0") {
+				return Self::from_tiff(&segment[6..]);
+			}
+			pos += 2 + len;
+		}
+		None
+	}
+
+	fn from_tiff(tiff: &[u8]) -> Option<Self> {
+		let reader = TiffReader { data: tiff, little_endian: tiff.get(..2)? == b"II" };
+		if reader.u16(2)? != 42 {
+			return None;
+		}
+		let mut out = Self::default();
+		let mut exif_ifd = None;
+		for entry in reader.ifd(reader.u32(4)? as usize)? {
+			match entry.tag {
+				0x010F => out.make = reader.ascii(&entry),
+				0x0110 => out.model = reader.ascii(&entry),
+				0x8769 => exif_ifd = reader.unsigned(&entry),
+				_ => {}
+			}
+		}
+		let mut resolution_unit_mm = 25.4;
+		if let Some(exif_ifd) = exif_ifd.and_then(|offset| reader.ifd(offset as usize)) {
+			for entry in exif_ifd {
+				match entry.tag {
+					0x920A => out.focal_length_mm = reader.rational(&entry),
+					0xA405 => out.focal_length_35mm = reader.unsigned(&entry).map(f64::from).filter(|&f| f > 0.),
+					0xA20E => out.focal_plane_x_resolution = reader.rational(&entry),
+					0xA210 => {
+						resolution_unit_mm = match reader.unsigned(&entry) {
+							Some(3) => 10.,
+							Some(4) => 1.,
+							Some(5) => 0.001,
+							_ => 25.4,
+						}
+					}
+					0xA002 => out.pixel_width = reader.unsigned(&entry),
+					0xA003 => out.pixel_height = reader.unsigned(&entry),
+					_ => {}
+				}
+			}
+		}
+		out.focal_plane_x_resolution = out.focal_plane_x_resolution
+			.map(|res| res / resolution_unit_mm)
+			.filter(|&res| res > 0.);
+		out.focal_length_mm = out.focal_length_mm.filter(|&f| f > 0.);
+		Some(out)
+	}
+
+	/// Returns the focal length in pixels for the image of the `width`x`height` size
+	///
+	/// The physical focal length and the sensor resolution are preferred, the 35 mm equivalent is the fallback.
+	pub fn focal_length_px(&self, width: u32, height: u32) -> Option<f64> {
+		let physical = self.focal_length_mm
+			.and_then(|f| self.focal_plane_x_resolution.map(|res| f * res))
+			.map(|f| match self.pixel_width {
+				// the image was resized after the EXIF was written
+				Some(pixel_width) if pixel_width > 0 => f * f64::from(width) / f64::from(pixel_width),
+				_ => f,
+			});
+		physical.or_else(|| {
+			self.focal_length_35mm
+				.map(|f| f * f64::from(width).hypot(f64::from(height)) / FILM_35MM_DIAGONAL)
+		})
+	}
+}
+
+struct IfdEntry {
+	tag: u16,
+	typ: u16,
+	count: u32,
+	/// Position of the 4-byte value or offset field
+	value_pos: usize,
+}
+
+struct TiffReader<'d> {
+	data: &'d [u8],
+	little_endian: bool,
+}
+
+impl TiffReader<'_> {
+	fn u16(&self, pos: usize) -> Option<u16> {
+		let bytes = self.data.get(pos..pos + 2)?.try_into().ok()?;
+		Some(if self.little_endian { u16::from_le_bytes(bytes) } else { u16::from_be_bytes(bytes) })
+	}
+
+	fn u32(&self, pos: usize) -> Option<u32> {
+		let bytes = self.data.get(pos..pos + 4)?.try_into().ok()?;
+		Some(if self.little_endian { u32::from_le_bytes(bytes) } else { u32::from_be_bytes(bytes) })
+	}
+
+	fn ifd(&self, pos: usize) -> Option<Vec<IfdEntry>> {
+		let count = usize::from(self.u16(pos)?);
+		(0..count)
+			.map(|i| {
+				let entry = pos + 2 + i * 12;
+				Some(IfdEntry { tag: self.u16(entry)?, typ: self.u16(entry + 2)?, count: self.u32(entry + 4)?, value_pos: entry + 8 })
+			})
+			.collect()
+	}
+
+	/// Reads the SHORT or LONG value
+	fn unsigned(&self, entry: &IfdEntry) -> Option<u32> {
+		match entry.typ {
+			3 => self.u16(entry.value_pos).map(u32::from),
+			4 => self.u32(entry.value_pos),
+			_ => None,
+		}
+	}
+
+	fn rational(&self, entry: &IfdEntry) -> Option<f64> {
+		if entry.typ != 5 {
+			return None;
+		}
+		let pos = self.u32(entry.value_pos)? as usize;
+		let (numerator, denominator) = (self.u32(pos)?, self.u32(pos + 4)?);
+		if denominator == 0 {
+			None
+		} else {
+			Some(f64::from(numerator) / f64::from(denominator))
+		}
+	}
+
+	fn ascii(&self, entry: &IfdEntry) -> Option<String> {
+		if entry.typ != 2 {
+			return None;
+		}
+		let count = entry.count as usize;
+		let pos = if count <= 4 { entry.value_pos } else { self.u32(entry.value_pos)? as usize };
+		let bytes = self.data.get(pos..pos + count)?;
+		let text = String::from_utf8_lossy(bytes.split(|&b| b == 0).next().unwrap_or(bytes)).trim().to_string();
+		if text.is_empty() { None } else { Some(text) }
+	}
+}
@@ -0,0 +1,361 @@
+//! Geometric primitive fitting over the point clouds
+//!
+//! Planes, spheres and 3D lines are fitted with RANSAC followed by the least squares refinement on the inliers, e.g. to
+//! extract the ground plane from the `sfm::reconstruct()` output:
+//! ```no_run
+//! # use opencv::{core::Mat, sfm::{cloud, fit}};
+//! # fn run(points3d: &Mat) -> opencv::Result<()> {
+//! let points = cloud::points_from_mat(points3d)?;
+//! let ground = fit::fit_plane(&points, 0.05)?;
+//! println!("{:?} with {} inliers, rms: {}", ground.model, ground.inlier_count(), ground.rms);
+//! # Ok(()) }
+//! ```
+
+use std::cmp::Ordering;
+
+use crate::{
+	core::{self, Mat, Point3d, Vec3d},
+	Error,
+	Result,
+};
+
+use super::cloud::symmetric_eigen;
+
+/// Geometric model that can be fitted to the points
+pub trait Model: Sized {
+	/// Minimal number of the points that define the model
+	const SAMPLE_SIZE: usize;
+
+	/// Least squares fit, `None` if the points are degenerate for the model (e.g. collinear points for a plane)
+	fn fit(points: &[Point3d]) -> Option<Self>;
+
+	/// Euclidean distance from the point to the model
+	fn distance(&self, point: Point3d) -> f64;
+}
+
+/// Plane `normal · p + d = 0`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Plane {
+	/// Unit normal
+	pub normal: Vec3d,
+	pub d: f64,
+}
+
+impl Plane {
+	/// Signed distance, positive on the side the normal points to
+	#[inline]
+	pub fn signed_distance(&self, point: Point3d) -> f64 {
+		self.normal[0] * point.x + self.normal[1] * point.y + self.normal[2] * point.z + self.d
+	}
+
+	/// Orthogonal projection of the point onto the plane
+	#[inline]
+	pub fn project(&self, point: Point3d) -> Point3d {
+		let dist = self.signed_distance(point);
+		Point3d::new(point.x - dist * self.normal[0], point.y - dist * self.normal[1], point.z - dist * self.normal[2])
+	}
+}
+
+impl Model for Plane {
+	const SAMPLE_SIZE: usize = 3;
+
+	/// Total least squares, the normal is the direction of the smallest variance
+	fn fit(points: &[Point3d]) -> Option<Self> {
+		let (centroid, eigen) = principal_axes(points, Self::SAMPLE_SIZE)?;
+		// the second variance vanishes for the collinear points
+		if eigen[1].0 <= 1e-12 * eigen[2].0 {
+			return None;
+		}
+		let n = eigen[0].1;
+		Some(Self { normal: Vec3d::from(n), d: -(n[0] * centroid.x + n[1] * centroid.y + n[2] * centroid.z) })
+	}
+
+	#[inline]
+	fn distance(&self, point: Point3d) -> f64 {
+		self.signed_distance(point).abs()
+	}
+}
+
+/// Sphere surface
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sphere {
+	pub center: Point3d,
+	pub radius: f64,
+}
+
+impl Model for Sphere {
+	const SAMPLE_SIZE: usize = 4;
+
+	/// Algebraic least squares of `|p|² + a · p + b = 0`, the points are centered and scaled for the conditioning
+	fn fit(points: &[Point3d]) -> Option<Self> {
+		if points.len() < Self::SAMPLE_SIZE {
+			return None;
+		}
+		let centroid = centroid(points);
+		let scale = points.iter().map(|&p| (p - centroid).norm()).sum::<f64>() / points.len() as f64;
+		if scale <= 0. {
+			return None;
+		}
+		// normal equations of the rows [x y z 1] = -(x² + y² + z²)
+		let mut ata = [[0.; 4]; 4];
+		let mut atb = [0.; 4];
+		for &p in points {
+			let q = (p - centroid) / scale;
+			let row = [q.x, q.y, q.z, 1.];
+			let rhs = -(q.x * q.x + q.y * q.y + q.z * q.z);
+			for (r, ata_row) in ata.iter_mut().enumerate() {
+				for (c, v) in ata_row.iter_mut().enumerate() {
+					*v += row[r] * row[c];
+				}
+				atb[r] += row[r] * rhs;
+			}
+		}
+		let x = solve4(ata, atb)?;
+		let center = Point3d::new(-x[0] / 2., -x[1] / 2., -x[2] / 2.);
+		let radius_sq = center.dot(center) - x[3];
+		if radius_sq <= 0. {
+			return None;
+		}
+		Some(Self { center: centroid + center * scale, radius: radius_sq.sqrt() * scale })
+	}
+
+	#[inline]
+	fn distance(&self, point: Point3d) -> f64 {
+		((point - self.center).norm() - self.radius).abs()
+	}
+}
+
+/// Infinite 3D line
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Line3 {
+	/// Point on the line, the centroid of the fitted points
+	pub point: Point3d,
+	/// Unit direction
+	pub direction: Vec3d,
+}
+
+impl Model for Line3 {
+	const SAMPLE_SIZE: usize = 2;
+
+	/// Total least squares, the direction is the direction of the largest variance
+	fn fit(points: &[Point3d]) -> Option<Self> {
+		let (centroid, eigen) = principal_axes(points, Self::SAMPLE_SIZE)?;
+		if eigen[2].0 <= 0. {
+			return None;
+		}
+		Some(Self { point: centroid, direction: Vec3d::from(eigen[2].1) })
+	}
+
+	#[inline]
+	fn distance(&self, point: Point3d) -> f64 {
+		let v = point - self.point;
+		let d = Point3d::new(self.direction[0], self.direction[1], self.direction[2]);
+		v.cross(d).norm()
+	}
+}
+
+/// Fitted model with its inliers
+#[derive(Clone, Debug)]
+pub struct Fit<M> {
+	pub model: M,
+	/// Whether the point is within the threshold from the model, aligned with the input points
+	pub inliers: Vec<bool>,
+	/// Root mean square distance of the inliers to the model
+	pub rms: f64,
+}
+
+impl<M> Fit<M> {
+	#[inline]
+	pub fn inlier_count(&self) -> usize {
+		self.inliers.iter().filter(|&&inlier| inlier).count()
+	}
+
+	/// Returns the inliers as `Nx1` `CV_8U` mask with 255 for the inliers, the convention of the `calib3d` functions
+	pub fn inlier_mask(&self) -> Result<Mat> {
+		Mat::from_exact_iter(self.inliers.iter().map(|&inlier| if inlier { 255u8 } else { 0 }))
+	}
+}
+
+/// Parameters of the [ransac] fitting
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RansacParams {
+	/// Maximum distance of the inlier to the model
+	pub threshold: f64,
+	/// Probability of drawing at least one outlier-free sample, sets the adaptive number of the iterations
+	pub confidence: f64,
+	pub max_iterations: usize,
+}
+
+impl RansacParams {
+	/// Creates the parameters with 0.99 confidence and 1000 max iterations
+	pub fn new(threshold: f64) -> Self {
+		Self { threshold, confidence: 0.99, max_iterations: 1000 }
+	}
+
+	pub fn with_confidence(mut self, confidence: f64) -> Self {
+		self.confidence = confidence;
+		self
+	}
+
+	pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+		self.max_iterations = max_iterations;
+		self
+	}
+}
+
+/// Fits the model to all points with least squares, all points are the inliers
+pub fn least_squares<M: Model>(points: &[Point3d]) -> Result<Fit<M>> {
+	check_len::<M>(points)?;
+	let model = M::fit(points).ok_or_else(|| Error::new(core::StsError, "Points are degenerate for the model"))?;
+	let rms = (points.iter().map(|&p| model.distance(p).powi(2)).sum::<f64>() / points.len() as f64).sqrt();
+	Ok(Fit { model, inliers: vec![true; points.len()], rms })
+}
+
+/// Fits the model robustly with RANSAC, the best model is refined with least squares on its inliers
+///
+/// The sampling is deterministic, so that the results are reproducible.
+pub fn ransac<M: Model>(points: &[Point3d], params: RansacParams) -> Result<Fit<M>> {
+	check_len::<M>(points)?;
+	if params.threshold <= 0. || !params.threshold.is_finite() {
+		return Err(Error::new(core::StsOutOfRange, format!("RANSAC threshold must be positive, but got: {}", params.threshold)));
+	}
+	if !(0. ..1.).contains(&params.confidence) {
+		return Err(Error::new(core::StsOutOfRange, format!("RANSAC confidence must be in [0, 1), but got: {}", params.confidence)));
+	}
+	let mut state = 0x2545_f491_4f6c_dd1d_u64;
+	let mut random = |n: usize| {
+		state ^= state << 13;
+		state ^= state >> 7;
+		state ^= state << 17;
+		(state % n as u64) as usize
+	};
+	let mut best: Option<(M, usize)> = None;
+	let mut iterations = params.max_iterations;
+	let mut indices = Vec::with_capacity(M::SAMPLE_SIZE);
+	let mut sample = Vec::with_capacity(M::SAMPLE_SIZE);
+	let mut i = 0;
+	while i < iterations {
+		i += 1;
+		indices.clear();
+		while indices.len() < M::SAMPLE_SIZE {
+			let index = random(points.len());
+			if !indices.contains(&index) {
+				indices.push(index);
+			}
+		}
+		sample.clear();
+		sample.extend(indices.iter().map(|&index| points[index]));
+		let model = match M::fit(&sample) {
+			Some(model) => model,
+			None => continue,
+		};
+		let count = points.iter().filter(|&&p| model.distance(p) <= params.threshold).count();
+		if best.as_ref().map(|b| count > b.1).unwrap_or(true) {
+			best = Some((model, count));
+			let ratio = count as f64 / points.len() as f64;
+			let no_outliers = 1. - ratio.powi(M::SAMPLE_SIZE as i32);
+			if no_outliers <= 0. {
+				break;
+			}
+			let needed = ((1. - params.confidence).ln() / no_outliers.ln()).ceil();
+			if needed.is_finite() && needed >= 0. {
+				iterations = iterations.min(needed as usize);
+			}
+		}
+	}
+	let (mut model, _) = best.ok_or_else(|| Error::new(core::StsError, "All samples are degenerate for the model"))?;
+	let inliers_of = |model: &M| points.iter().map(|&p| model.distance(p) <= params.threshold).collect::<Vec<_>>();
+	let mut inliers = inliers_of(&model);
+	let inlier_points = points.iter().zip(&inliers).filter(|(_, &inlier)| inlier).map(|(&p, _)| p).collect::<Vec<_>>();
+	if let Some(refined) = M::fit(&inlier_points) {
+		let refined_inliers = inliers_of(&refined);
+		if refined_inliers.iter().filter(|&&inlier| inlier).count() >= inlier_points.len() {
+			model = refined;
+			inliers = refined_inliers;
+		}
+	}
+	let (sum_sq, count) = points.iter()
+		.zip(&inliers)
+		.filter(|(_, &inlier)| inlier)
+		.fold((0., 0), |(sum, count), (&p, _)| (sum + model.distance(p).powi(2), count + 1));
+	let rms = if count > 0 { (sum_sq / f64::from(count)).sqrt() } else { 0. };
+	Ok(Fit { model, inliers, rms })
+}
+
+/// Fits the plane with [ransac] and the default parameters
+#[inline]
+pub fn fit_plane(points: &[Point3d], threshold: f64) -> Result<Fit<Plane>> {
+	ransac(points, RansacParams::new(threshold))
+}
+
+/// Fits the sphere with [ransac] and the default parameters
+#[inline]
+pub fn fit_sphere(points: &[Point3d], threshold: f64) -> Result<Fit<Sphere>> {
+	ransac(points, RansacParams::new(threshold))
+}
+
+/// Fits the line with [ransac] and the default parameters
+#[inline]
+pub fn fit_line(points: &[Point3d], threshold: f64) -> Result<Fit<Line3>> {
+	ransac(points, RansacParams::new(threshold))
+}
+
+fn check_len<M: Model>(points: &[Point3d]) -> Result<()> {
+	if points.len() < M::SAMPLE_SIZE {
+		Err(Error::new(core::StsBadArg, format!("Fitting requires at least {} points, got: {}", M::SAMPLE_SIZE, points.len())))
+	} else {
+		Ok(())
+	}
+}
+
+fn centroid(points: &[Point3d]) -> Point3d {
+	points.iter().fold(Point3d::default(), |acc, &p| acc + p) / points.len() as f64
+}
+
+/// Variances with their unit axes in the ascending order
+type Axes = [(f64, [f64; 3]); 3];
+
+/// Returns the centroid and the eigen decomposition of the covariance
+fn principal_axes(points: &[Point3d], min_len: usize) -> Option<(Point3d, Axes)> {
+	if points.len() < min_len {
+		return None;
+	}
+	let centroid = centroid(points);
+	let mut cov = [[0.; 3]; 3];
+	for p in points {
+		let d = [p.x - centroid.x, p.y - centroid.y, p.z - centroid.z];
+		for (r, row) in cov.iter_mut().enumerate() {
+			for (c, v) in row.iter_mut().enumerate() {
+				*v += d[r] * d[c];
+			}
+		}
+	}
+	Some((centroid, symmetric_eigen(cov)))
+}
+
+/// Solves the `4x4` linear system with the partial pivoting, `None` if it's singular
+fn solve4(mut a: [[f64; 4]; 4], mut b: [f64; 4]) -> Option<[f64; 4]> {
+	let scale = a.iter().flat_map(|row| row.iter()).fold(0f64, |acc, v| acc.max(v.abs()));
+	for col in 0..4 {
+		let pivot = (col..4).max_by(|&i, &j| a[i][col].abs().partial_cmp(&a[j][col].abs()).unwrap_or(Ordering::Equal))?;
+		if a[pivot][col].abs() <= 1e-12 * scale {
+			return None;
+		}
+		a.swap(col, pivot);
+		b.swap(col, pivot);
+		for row in col + 1..4 {
+			let factor = a[row][col] / a[col][col];
+			let pivot_row = a[col];
+			for (v, pivot_v) in a[row].iter_mut().zip(&pivot_row).skip(col) {
+				*v -= factor * pivot_v;
+			}
+			b[row] -= factor * b[col];
+		}
+	}
+	let mut x = [0.; 4];
+	for row in (0..4).rev() {
+		let sum = (row + 1..4).map(|k| a[row][k] * x[k]).sum::<f64>();
+		x[row] = (b[row] - sum) / a[row][row];
+	}
+	Some(x)
+}
@@ -0,0 +1,193 @@
+use std::{
+	collections::VecDeque,
+	fs,
+	path::{Path, PathBuf},
+};
+
+use crate::{
+	core::{self, Mat, Vector},
+	Error,
+	imgcodecs,
+	prelude::*,
+	Result,
+	sfm::{Exif, ReconstructionInput},
+};
+
+/// Sorted image sequence with the lazy loading
+///
+/// The loaded images are kept in a LRU cache, so that the sequences that don't fit into the memory can be processed
+/// in the order of the views.
+/// ```no_run
+/// # use opencv::sfm::ImageSet;
+/// # fn run() -> opencv::Result<()> {
+/// let mut images = ImageSet::from_glob("imgs/*.jpg")?;
+/// let k = images.k_from_exif(0)?;
+/// let first = images.get(0)?;
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+pub struct ImageSet {
+	paths: Vec<PathBuf>,
+	flags: i32,
+	cache_size: usize,
+	/// `(index, image)` from the least to the most recently used
+	cache: VecDeque<(usize, Mat)>,
+}
+
+impl ImageSet {
+	/// Creates the set from the paths in the given order
+	pub fn from_paths(paths: impl IntoIterator<Item=impl Into<PathBuf>>) -> Self {
+		Self {
+			paths: paths.into_iter().map(|p| p.into()).collect(),
+			flags: imgcodecs::IMREAD_COLOR,
+			cache_size: 16,
+			cache: VecDeque::new(),
+		}
+	}
+
+	/// Creates the set from the files matching the pattern sorted by the path
+	///
+	/// The wildcards `*` and `?` are only supported in the file name, e.g. `imgs/*.jpg`.
+	pub fn from_glob(pattern: &str) -> Result<Self> {
+		let pattern = Path::new(pattern);
+		let dir = pattern.parent()
+			.filter(|dir| !dir.as_os_str().is_empty())
+			.unwrap_or_else(|| Path::new("."));
+		let name_pattern = pattern.file_name()
+			.and_then(|name| name.to_str())
+			.ok_or_else(|| Error::new(core::StsBadArg, format!("Glob pattern must end with a file name: {}", pattern.display())))?;
+		let mut paths = fs::read_dir(dir)
+			.map_err(|e| Error::new(core::StsError, format!("Can't read directory: {}: {}", dir.display(), e)))?
+			.filter_map(|entry| entry.ok())
+			.filter(|entry| matches!(entry.file_type(), Ok(t) if t.is_file()))
+			.filter(|entry| matches!(entry.file_name().to_str(), Some(name) if wildcard_match(name_pattern.as_bytes(), name.as_bytes())))
+			.map(|entry| entry.path())
+			.collect::<Vec<_>>();
+		paths.sort();
+		if paths.is_empty() {
+			return Err(Error::new(core::StsObjectNotFound, format!("No files match: {}", pattern.display())));
+		}
+		Ok(Self::from_paths(paths))
+	}
+
+	/// Sets the `IMREAD_*` flags of the loading, `IMREAD_COLOR` by default
+	pub fn with_flags(mut self, flags: i32) -> Self {
+		self.flags = flags;
+		self.cache.clear();
+		self
+	}
+
+	/// Sets the maximum number of the images kept in the memory, 16 by default
+	pub fn with_cache_size(mut self, cache_size: usize) -> Self {
+		self.cache_size = cache_size.max(1);
+		self.cache.truncate(self.cache_size);
+		self
+	}
+
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.paths.len()
+	}
+
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.paths.is_empty()
+	}
+
+	#[inline]
+	pub fn paths(&self) -> &[PathBuf] {
+		&self.paths
+	}
+
+	/// Returns the image, it's loaded only if it's not in the cache
+	pub fn get(&mut self, index: usize) -> Result<&Mat> {
+		let path = self.paths.get(index)
+			.ok_or_else(|| Error::new(core::StsOutOfRange, format!("Index: {} is out of bounds of the image set of: {} images", index, self.paths.len())))?;
+		if let Some(pos) = self.cache.iter().position(|(i, _)| *i == index) {
+			let entry = self.cache.remove(pos).expect("Position is in the cache");
+			self.cache.push_back(entry);
+		} else {
+			let image = imgcodecs::imread(&path.to_string_lossy(), self.flags)?;
+			if image.empty() {
+				return Err(Error::new(core::StsError, format!("Can't load image: {}", path.display())));
+			}
+			if self.cache.len() >= self.cache_size {
+				self.cache.pop_front();
+			}
+			self.cache.push_back((index, image));
+		}
+		Ok(&self.cache.back().expect("Image was just added to the cache").1)
+	}
+
+	/// Reads the EXIF header of the image, `None` if it has none
+	pub fn exif(&self, index: usize) -> Result<Option<Exif>> {
+		let path = self.paths.get(index)
+			.ok_or_else(|| Error::new(core::StsOutOfRange, format!("Index: {} is out of bounds of the image set of: {} images", index, self.paths.len())))?;
+		Exif::from_file(path)
+	}
+
+	/// Returns the `3x3` `CV_64F` camera matrix with the focal length from the EXIF and the principal point in the
+	/// image center, `None` if the EXIF doesn't have enough information
+	///
+	/// The image is loaded for its size.
+	pub fn k_from_exif(&mut self, index: usize) -> Result<Option<Mat>> {
+		let exif = self.exif(index)?;
+		let size = self.get(index)?.size()?;
+		let focal = exif.and_then(|exif| exif.focal_length_px(size.width as u32, size.height as u32));
+		focal
+			.map(|f| {
+				let (cx, cy) = (f64::from(size.width) / 2., f64::from(size.height) / 2.);
+				Mat::from_slice_2d(&[[f, 0., cx], [0., f, cy], [0., 0., 1.]])
+			})
+			.transpose()
+	}
+
+	/// Returns the paths as accepted by `sfm::reconstruct_2()` and `sfm::reconstruct_3()`
+	pub fn to_vector(&self) -> Vector<String> {
+		let paths = self.paths.iter().map(|p| p.to_string_lossy().into_owned()).collect::<Vec<_>>();
+		Vector::from_iter(paths.iter().map(String::as_str))
+	}
+}
+
+impl From<&ImageSet> for Vector<String> {
+	#[inline]
+	fn from(s: &ImageSet) -> Self {
+		s.to_vector()
+	}
+}
+
+impl ReconstructionInput {
+	/// Creates the input for the reconstruction from the paths of the image set
+	#[inline]
+	pub fn from_image_set(images: &ImageSet, k: Mat) -> Self {
+		Self::from_images(images.paths.iter().map(|p| p.to_string_lossy().into_owned()), k)
+	}
+}
+
+/// Matches the name against the pattern with the `*` and `?` wildcards
+fn wildcard_match(pattern: &[u8], name: &[u8]) -> bool {
+	let (mut p, mut n) = (0, 0);
+	// position of the last `*` in the pattern and the name position it was matched at
+	let mut backtrack = None;
+	while n < name.len() {
+		match pattern.get(p) {
+			Some(b'*') => {
+				backtrack = Some((p, n));
+				p += 1;
+			}
+			Some(&c) if c == b'?' || c == name[n] => {
+				p += 1;
+				n += 1;
+			}
+			_ => match backtrack {
+				Some((star_p, star_n)) => {
+					p = star_p + 1;
+					n = star_n + 1;
+					backtrack = Some((star_p, star_n + 1));
+				}
+				None => return false,
+			},
+		}
+	}
+	pattern[p..].iter().all(|&c| c == b'*')
+}
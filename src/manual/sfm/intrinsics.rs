@@ -0,0 +1,284 @@
+//! Initial camera matrix for the reconstruction
+//!
+//! Bad initial intrinsics are the most common cause of the failed reconstructions. [estimate_focal] takes the focal
+//! length from the EXIF headers, looking the sensor size up in the [SensorDatabase] when it's not recorded, and falls
+//! back to the vanishing points of the man-made scenes:
+//! ```no_run
+//! # use opencv::sfm::{ImageSet, ReconstructionInput, intrinsics};
+//! # fn run() -> opencv::Result<()> {
+//! let mut images = ImageSet::from_glob("imgs/*.jpg")?;
+//! let estimate = intrinsics::estimate_focal(&mut images)?;
+//! println!("{:?} focal: {} confidence: {}", estimate.source, estimate.focal, estimate.confidence);
+//! let input = ReconstructionInput::from_image_set(&images, estimate.k);
+//! # Ok(()) }
+//! ```
+
+use std::{cmp::Ordering, collections::HashMap, f64::consts::PI};
+
+use crate::{
+	core::{self, Mat, Vec4i, Vector},
+	Error,
+	imgproc,
+	prelude::*,
+	Result,
+	sfm::{Exif, ImageSet},
+};
+
+/// Sensor widths in millimeters by `"make model"`
+const SENSOR_WIDTHS: &[(&str, f64)] = &[
+	("canon canon eos 5d mark ii", 36.),
+	("canon canon eos 5d mark iii", 36.),
+	("canon canon eos 6d", 35.8),
+	("canon canon eos 70d", 22.5),
+	("canon canon eos 80d", 22.5),
+	("canon canon eos rebel t6i", 22.3),
+	("canon canon powershot g12", 7.44),
+	("fujifilm x-t2", 23.6),
+	("gopro hero4 black", 6.17),
+	("nikon corporation nikon d3200", 23.2),
+	("nikon corporation nikon d5300", 23.5),
+	("nikon corporation nikon d7000", 23.6),
+	("nikon corporation nikon d800", 35.9),
+	("nikon corporation nikon d810", 35.9),
+	("olympus imaging corp. e-m10", 17.3),
+	("panasonic dmc-gh4", 17.3),
+	("sony dsc-rx100", 13.2),
+	("sony ilce-6000", 23.5),
+	("sony ilce-7m2", 35.8),
+	("sony ilce-7m3", 35.6),
+];
+
+/// Sensor widths of the cameras missing the sensor size in their EXIF headers
+#[derive(Clone, Debug)]
+pub struct SensorDatabase {
+	widths: HashMap<String, f64>,
+}
+
+impl SensorDatabase {
+	/// Creates the database without any cameras
+	pub fn empty() -> Self {
+		Self { widths: HashMap::new() }
+	}
+
+	/// Adds or replaces the sensor width in millimeters, the make and the model are matched case-insensitively
+	pub fn insert(&mut self, make: &str, model: &str, sensor_width_mm: f64) {
+		self.widths.insert(key(make, model), sensor_width_mm);
+	}
+
+	/// Returns the sensor width in millimeters
+	pub fn sensor_width(&self, make: &str, model: &str) -> Option<f64> {
+		self.widths.get(&key(make, model)).copied()
+	}
+}
+
+/// Creates the database with the built-in common cameras
+impl Default for SensorDatabase {
+	fn default() -> Self {
+		Self { widths: SENSOR_WIDTHS.iter().map(|&(name, width)| (name.to_string(), width)).collect() }
+	}
+}
+
+fn key(make: &str, model: &str) -> String {
+	format!("{} {}", make.trim(), model.trim()).to_lowercase()
+}
+
+/// Origin of the [FocalEstimate]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FocalSource {
+	/// EXIF focal length with the sensor width from the [SensorDatabase]
+	SensorDatabase,
+	/// EXIF focal length with the sensor resolution or the 35 mm equivalent
+	Exif,
+	/// Orthogonal vanishing points of the lines in the images
+	VanishingPoints,
+	/// `1.2 * max(width, height)`, common for the consumer cameras
+	Default,
+}
+
+/// Focal length estimate to seed the reconstruction
+#[derive(Debug)]
+pub struct FocalEstimate {
+	/// Focal length in pixels
+	pub focal: f64,
+	/// `3x3` `CV_64F` camera matrix with the principal point in the center of the first image
+	pub k: Mat,
+	/// Rough reliability in `[0, 1]`
+	pub confidence: f64,
+	pub source: FocalSource,
+}
+
+/// Estimates the focal length shared by all images with the built-in [SensorDatabase], see [estimate_focal_with]
+#[inline]
+pub fn estimate_focal(images: &mut ImageSet) -> Result<FocalEstimate> {
+	estimate_focal_with(images, &SensorDatabase::default())
+}
+
+/// Estimates the focal length shared by all images
+///
+/// The median of the per image EXIF estimates is used if any image has one, then the median of the vanishing point
+/// estimates and finally the default guess. The confidence is lowered by the disagreement between the images.
+pub fn estimate_focal_with(images: &mut ImageSet, sensors: &SensorDatabase) -> Result<FocalEstimate> {
+	if images.is_empty() {
+		return Err(Error::new(core::StsBadArg, "Image set is empty"));
+	}
+	let size = images.get(0)?.size()?;
+	let (width, height) = (f64::from(size.width), f64::from(size.height));
+
+	let mut exif_focals = vec![];
+	let mut source = FocalSource::Exif;
+	for i in 0..images.len() {
+		if let Some(exif) = images.exif(i)? {
+			if let Some(f) = exif_focal_from_database(&exif, width, height, sensors) {
+				source = FocalSource::SensorDatabase;
+				exif_focals.push(f);
+			} else if let Some(f) = exif.focal_length_px(size.width as u32, size.height as u32) {
+				exif_focals.push(f);
+			}
+		}
+	}
+	let (focal, confidence, source) = if !exif_focals.is_empty() {
+		let base = if source == FocalSource::SensorDatabase { 0.9 } else { 0.8 };
+		let (focal, agreement) = median_agreement(exif_focals);
+		(focal, base * agreement, source)
+	} else {
+		let mut vp_focals = vec![];
+		for i in 0..images.len() {
+			if let Some(f) = focal_from_vanishing_points(images.get(i)?)? {
+				vp_focals.push(f);
+			}
+		}
+		if vp_focals.is_empty() {
+			(1.2 * width.max(height), 0.1, FocalSource::Default)
+		} else {
+			let coverage = vp_focals.len() as f64 / images.len() as f64;
+			let (focal, agreement) = median_agreement(vp_focals);
+			(focal, 0.6 * agreement * coverage.sqrt(), FocalSource::VanishingPoints)
+		}
+	};
+	let k = Mat::from_slice_2d(&[[focal, 0., width / 2.], [0., focal, height / 2.], [0., 0., 1.]])?;
+	Ok(FocalEstimate { focal, k, confidence, source })
+}
+
+fn exif_focal_from_database(exif: &Exif, width: f64, height: f64, sensors: &SensorDatabase) -> Option<f64> {
+	let focal_mm = exif.focal_length_mm?;
+	let sensor_width = sensors.sensor_width(exif.make.as_deref()?, exif.model.as_deref()?)?;
+	// the sensor width is along the longer side of the image
+	Some(focal_mm * width.max(height) / sensor_width)
+}
+
+/// Returns the median and the agreement `1 - median absolute deviation / median` clamped to `[0, 1]`
+fn median_agreement(mut values: Vec<f64>) -> (f64, f64) {
+	let median = |values: &mut Vec<f64>| {
+		values.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+		values[values.len() / 2]
+	};
+	let m = median(&mut values);
+	let mut deviations = values.iter().map(|v| (v - m).abs()).collect::<Vec<_>>();
+	let mad = median(&mut deviations);
+	(m, (1. - mad / m).clamp(0., 1.))
+}
+
+/// Line segment with its homogeneous line `a * x + b * y + c = 0`, `a² + b² = 1`
+struct Segment {
+	line: [f64; 3],
+	mid: (f64, f64),
+	direction: (f64, f64),
+}
+
+/// Estimates the focal length from two orthogonal vanishing points of the straight lines in the image
+///
+/// Square pixels and the principal point in the image center are assumed. Returns `None` when two finite vanishing
+/// points with a consistent geometry are not found, e.g. for the natural scenes.
+pub fn focal_from_vanishing_points(image: &Mat) -> Result<Option<f64>> {
+	let gray = if image.channels() == 1 {
+		image.try_clone()?
+	} else {
+		let mut gray = Mat::default();
+		imgproc::cvt_color(image, &mut gray, if image.channels() == 4 { imgproc::COLOR_BGRA2GRAY } else { imgproc::COLOR_BGR2GRAY }, 0)?;
+		gray
+	};
+	let mut edges = Mat::default();
+	imgproc::canny(&gray, &mut edges, 50., 150., 3, false)?;
+	let (width, height) = (f64::from(image.cols()), f64::from(image.rows()));
+	let mut lines = Vector::<Vec4i>::new();
+	imgproc::hough_lines_p(&edges, &mut lines, 1., PI / 180., 50, 0.05 * width.max(height), 5.)?;
+	let mut segments = lines.iter()
+		.map(|l| {
+			let (x1, y1, x2, y2) = (f64::from(l[0]), f64::from(l[1]), f64::from(l[2]), f64::from(l[3]));
+			let (a, b) = (y1 - y2, x2 - x1);
+			let norm = a.hypot(b);
+			Segment {
+				line: [a / norm, b / norm, (x1 * y2 - x2 * y1) / norm],
+				mid: ((x1 + x2) / 2., (y1 + y2) / 2.),
+				direction: ((x2 - x1) / norm, (y2 - y1) / norm),
+			}
+		})
+		.collect::<Vec<_>>();
+
+	let (cx, cy) = (width / 2., height / 2.);
+	let first = match vanishing_point(&mut segments, width.max(height)) {
+		Some(vp) => vp,
+		None => return Ok(None),
+	};
+	let second = match vanishing_point(&mut segments, width.max(height)) {
+		Some(vp) => vp,
+		None => return Ok(None),
+	};
+	let focal_sq = -((first.0 - cx) * (second.0 - cx) + (first.1 - cy) * (second.1 - cy));
+	if focal_sq <= 0. {
+		return Ok(None);
+	}
+	let focal = focal_sq.sqrt();
+	// the vanishing points of the nearly parallel lines give the unreliable extremes
+	Ok(Some(focal).filter(|f| (0.3..=5.).contains(&(f / width.max(height)))))
+}
+
+/// Finds the finite vanishing point supported by the most segments with RANSAC and removes its segments
+fn vanishing_point(segments: &mut Vec<Segment>, image_size: f64) -> Option<(f64, f64)> {
+	const ITERATIONS: usize = 500;
+	const MIN_SUPPORT: usize = 5;
+	let max_angle = 2f64.to_radians();
+	if segments.len() < MIN_SUPPORT {
+		return None;
+	}
+	let supports = |vp: (f64, f64), s: &Segment| {
+		let (dx, dy) = (vp.0 - s.mid.0, vp.1 - s.mid.1);
+		let norm = dx.hypot(dy);
+		norm > 0. && (dx * s.direction.1 - dy * s.direction.0).abs() / norm < max_angle.sin()
+	};
+	// deterministic xorshift, so that the estimates are reproducible
+	let mut state = 0x2545_f491_4f6c_dd1d_u64;
+	let mut random = |n: usize| {
+		state ^= state << 13;
+		state ^= state >> 7;
+		state ^= state << 17;
+		(state % n as u64) as usize
+	};
+	let mut best: Option<((f64, f64), usize)> = None;
+	for _ in 0..ITERATIONS {
+		let (i, j) = (random(segments.len()), random(segments.len()));
+		if i == j {
+			continue;
+		}
+		let (l1, l2) = (segments[i].line, segments[j].line);
+		let w = l1[0] * l2[1] - l1[1] * l2[0];
+		// the vanishing points far outside of the image are too unstable for the focal length
+		if w.abs() < 1e-9 {
+			continue;
+		}
+		let vp = ((l1[1] * l2[2] - l1[2] * l2[1]) / w, (l1[2] * l2[0] - l1[0] * l2[2]) / w);
+		if vp.0.abs().max(vp.1.abs()) > 20. * image_size {
+			continue;
+		}
+		let support = segments.iter().filter(|s| supports(vp, s)).count();
+		if best.map(|(_, best_support)| support > best_support).unwrap_or(true) {
+			best = Some((vp, support));
+		}
+	}
+	let (vp, support) = best?;
+	if support < MIN_SUPPORT {
+		return None;
+	}
+	segments.retain(|s| !supports(vp, s));
+	Some(vp)
+}
@@ -0,0 +1,286 @@
+//! Detection of the revisited views in the long image sequences with a bag of visual words
+//!
+//! Every view added to the [LoopDetector] is described by the tf-idf weighted histogram of its visual words. The
+//! best scoring older view is verified by matching the descriptors and fitting the fundamental matrix, the verified
+//! [LoopClosure] links the observations for the bundle adjustment to pull the drifted part of the trajectory back:
+//! ```no_run
+//! # use opencv::{core::{Mat, Point2d}, sfm::{BaObservation, loop_closure::{LoopDetector, Vocabulary}}};
+//! # fn run(training: &[Mat], views: &[(Vec<Point2d>, Mat)], observations: &mut [BaObservation], point_of: impl Fn(usize, usize) -> Option<usize>) -> opencv::Result<()> {
+//! let mut detector = LoopDetector::new(Vocabulary::train(training, 500)?);
+//! for (keypoints, descriptors) in views {
+//!     if let Some(closure) = detector.add_view(keypoints, descriptors)? {
+//!         closure.link_observations(observations, &point_of);
+//!     }
+//! }
+//! # Ok(()) }
+//! ```
+
+use crate::{
+	calib3d,
+	core::{self, DMatch, Mat, Point2d, TermCriteria, Vector},
+	Error,
+	features2d::{self, BFMatcher, BOWKMeansTrainer},
+	prelude::*,
+	Result,
+	sfm::BaObservation,
+};
+
+/// Visual words, one `CV_32F` cluster center per row
+#[derive(Debug)]
+pub struct Vocabulary {
+	words: Mat,
+}
+
+impl Vocabulary {
+	/// Clusters the descriptors of the training views into `size` words with k-means
+	///
+	/// Binary descriptors (e.g. ORB) are clustered as the vectors of their byte values.
+	pub fn train(descriptors: &[Mat], size: i32) -> Result<Self> {
+		let mut trainer = BOWKMeansTrainer::new(size, TermCriteria::both(100, 1e-3), 3, core::KMEANS_PP_CENTERS)?;
+		let mut count = 0;
+		for descriptors in descriptors.iter().filter(|d| !d.empty()) {
+			count += descriptors.rows();
+			trainer.add(&to_f32(descriptors)?)?;
+		}
+		if count < size {
+			return Err(Error::new(core::StsBadArg, format!("Training views have: {} descriptors in total, that's not enough for the vocabulary of: {} words", count, size)));
+		}
+		Ok(Self { words: features2d::BOWKMeansTrainerTraitConst::cluster(&trainer)? })
+	}
+
+	/// Creates the vocabulary from the words trained earlier, e.g. loaded with `FileStorage`
+	pub fn from_mat(words: Mat) -> Result<Self> {
+		if words.empty() {
+			return Err(Error::new(core::StsBadArg, "Vocabulary is empty"));
+		}
+		Ok(Self { words: to_f32(&words)? })
+	}
+
+	#[inline]
+	pub fn as_mat(&self) -> &Mat {
+		&self.words
+	}
+
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.words.rows() as usize
+	}
+
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.words.empty()
+	}
+
+	/// Returns the number of occurrences of each word in the descriptors
+	fn word_counts(&self, descriptors: &Mat) -> Result<Vec<u32>> {
+		let mut out = vec![0; self.len()];
+		if descriptors.empty() {
+			return Ok(out);
+		}
+		let mut matches = Vector::<DMatch>::new();
+		BFMatcher::new(core::NORM_L2, false)?.train_match(&to_f32(descriptors)?, &self.words, &mut matches, &core::no_array())?;
+		for m in matches {
+			out[m.train_idx as usize] += 1;
+		}
+		Ok(out)
+	}
+}
+
+fn to_f32(descriptors: &Mat) -> Result<Mat> {
+	let mut out = Mat::default();
+	descriptors.convert_to(&mut out, core::CV_32F, 1., 0.)?;
+	Ok(out)
+}
+
+/// Verified loop between the newly added view and an older one
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoopClosure {
+	/// Index of the newly added view
+	pub view: usize,
+	/// Index of the revisited older view
+	pub matched_view: usize,
+	/// Similarity of the word histograms in `[0, 1]`
+	pub score: f64,
+	/// Keypoint index pairs `(view keypoint, matched_view keypoint)` consistent with the epipolar geometry
+	pub matches: Vec<(usize, usize)>,
+}
+
+impl LoopClosure {
+	/// Merges the 3D points of the matched keypoints in the bundle adjustment observations
+	///
+	/// `point_of(view, keypoint)` returns the index of the 3D point observed by the keypoint. The observations of the
+	/// `view` points are moved to the points of their matches in the `matched_view`, so that `sfm::bundle_adjust()`
+	/// treats them as the same scene points. The replaced points are left without observations. Returns the number of
+	/// the merged points.
+	pub fn link_observations(&self, observations: &mut [BaObservation], point_of: impl Fn(usize, usize) -> Option<usize>) -> usize {
+		let mut merged = 0;
+		for &(keypoint, matched_keypoint) in &self.matches {
+			if let (Some(from), Some(to)) = (point_of(self.view, keypoint), point_of(self.matched_view, matched_keypoint)) {
+				if from != to {
+					observations.iter_mut()
+						.filter(|o| o.point == from)
+						.for_each(|o| o.point = to);
+					merged += 1;
+				}
+			}
+		}
+		merged
+	}
+}
+
+struct View {
+	keypoints: Vec<Point2d>,
+	descriptors: Mat,
+	word_counts: Vec<u32>,
+}
+
+/// Incremental loop closure detector
+pub struct LoopDetector {
+	vocabulary: Vocabulary,
+	views: Vec<View>,
+	/// Number of the views containing each word
+	document_frequency: Vec<u32>,
+	min_gap: usize,
+	min_score: f64,
+	min_inliers: usize,
+	ratio: f32,
+}
+
+impl LoopDetector {
+	pub fn new(vocabulary: Vocabulary) -> Self {
+		let words = vocabulary.len();
+		Self {
+			vocabulary,
+			views: vec![],
+			document_frequency: vec![0; words],
+			min_gap: 30,
+			min_score: 0.3,
+			min_inliers: 30,
+			ratio: 0.8,
+		}
+	}
+
+	/// Sets the number of the most recent views excluded from the search, 30 by default
+	///
+	/// The neighboring views of a sequence are always similar, they are handled by the regular matching.
+	pub fn with_min_gap(mut self, min_gap: usize) -> Self {
+		self.min_gap = min_gap;
+		self
+	}
+
+	/// Sets the minimum histogram similarity of a loop candidate, 0.3 by default
+	pub fn with_min_score(mut self, min_score: f64) -> Self {
+		self.min_score = min_score;
+		self
+	}
+
+	/// Sets the minimum number of the epipolar inliers of a verified loop, 30 by default
+	pub fn with_min_inliers(mut self, min_inliers: usize) -> Self {
+		self.min_inliers = min_inliers;
+		self
+	}
+
+	/// Sets the ratio test threshold of the descriptor matching, 0.8 by default
+	pub fn with_ratio(mut self, ratio: f32) -> Self {
+		self.ratio = ratio;
+		self
+	}
+
+	#[inline]
+	pub fn view_count(&self) -> usize {
+		self.views.len()
+	}
+
+	/// Adds the next view of the sequence and returns the loop it closes, if any
+	///
+	/// `descriptors` has a row per keypoint. Binary descriptors are matched with the Hamming distance, the others with L2.
+	pub fn add_view(&mut self, keypoints: &[Point2d], descriptors: &Mat) -> Result<Option<LoopClosure>> {
+		if descriptors.rows() as usize != keypoints.len() {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("Got {} keypoints but {} descriptors", keypoints.len(), descriptors.rows())));
+		}
+		let word_counts = self.vocabulary.word_counts(descriptors)?;
+		for (df, &count) in self.document_frequency.iter_mut().zip(&word_counts) {
+			if count > 0 {
+				*df += 1;
+			}
+		}
+		self.views.push(View { keypoints: keypoints.to_vec(), descriptors: descriptors.try_clone()?, word_counts });
+		let view = self.views.len() - 1;
+		if view < self.min_gap {
+			return Ok(None);
+		}
+
+		let query = if let Some(query) = self.weighted_histogram(&self.views[view].word_counts) {
+			query
+		} else {
+			return Ok(None);
+		};
+		let mut candidates = self.views[..=view - self.min_gap].iter()
+			.enumerate()
+			.filter_map(|(i, v)| {
+				let hist = self.weighted_histogram(&v.word_counts)?;
+				// L1 score of the normalized histograms
+				let distance = query.iter().zip(&hist).map(|(a, b)| (a - b).abs()).sum::<f64>();
+				Some((i, 1. - 0.5 * distance))
+			})
+			.filter(|&(_, score)| score >= self.min_score)
+			.collect::<Vec<_>>();
+		candidates.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+		// the geometric verification is expensive, only the best few are tried
+		for &(matched_view, score) in candidates.iter().take(3) {
+			let matches = self.verify(view, matched_view)?;
+			if matches.len() >= self.min_inliers {
+				return Ok(Some(LoopClosure { view, matched_view, score, matches }));
+			}
+		}
+		Ok(None)
+	}
+
+	/// Returns the L1 normalized tf-idf histogram, `None` if the view has no distinctive words
+	fn weighted_histogram(&self, word_counts: &[u32]) -> Option<Vec<f64>> {
+		let view_count = self.views.len() as f64;
+		let mut out = word_counts.iter()
+			.zip(&self.document_frequency)
+			.map(|(&count, &df)| if count > 0 { f64::from(count) * (view_count / f64::from(df)).ln() } else { 0. })
+			.collect::<Vec<_>>();
+		let sum = out.iter().sum::<f64>();
+		if sum > 0. {
+			out.iter_mut().for_each(|v| *v /= sum);
+			Some(out)
+		} else {
+			None
+		}
+	}
+
+	/// Returns the matched keypoint pairs that are the inliers of the fundamental matrix
+	fn verify(&self, view: usize, matched_view: usize) -> Result<Vec<(usize, usize)>> {
+		let (query, train) = (&self.views[view], &self.views[matched_view]);
+		if query.descriptors.rows() < 8 || train.descriptors.rows() < 8 {
+			return Ok(vec![]);
+		}
+		let norm = if query.descriptors.depth() == core::CV_8U { core::NORM_HAMMING } else { core::NORM_L2 };
+		let mut knn = Vector::<Vector<DMatch>>::new();
+		BFMatcher::new(norm, false)?.knn_train_match(&query.descriptors, &train.descriptors, &mut knn, 2, &core::no_array(), false)?;
+		let matches = knn.iter()
+			.filter_map(|m| {
+				let best = m.get(0).ok()?;
+				match m.get(1) {
+					Ok(second) if best.distance >= self.ratio * second.distance => None,
+					_ => Some((best.query_idx as usize, best.train_idx as usize)),
+				}
+			})
+			.collect::<Vec<_>>();
+		if matches.len() < self.min_inliers.max(8) {
+			return Ok(vec![]);
+		}
+		let points1 = Mat::from_exact_iter(matches.iter().map(|&(q, _)| query.keypoints[q]))?;
+		let points2 = Mat::from_exact_iter(matches.iter().map(|&(_, t)| train.keypoints[t]))?;
+		let mut mask = Mat::default();
+		let f = calib3d::find_fundamental_mat(&points1, &points2, calib3d::FM_RANSAC, 3., 0.99, 1000, &mut mask)?;
+		if f.empty() {
+			return Ok(vec![]);
+		}
+		let mask = mask.data_typed::<u8>()?;
+		Ok(matches.into_iter().zip(mask).filter(|(_, &inlier)| inlier != 0).map(|(m, _)| m).collect())
+	}
+}
@@ -0,0 +1,218 @@
+use crate::{
+	calib3d,
+	core::{self, Mat, Matx33d, no_array, Point3d, Similarity, Vec3d},
+	Error,
+	prelude::*,
+	Result,
+	sfm::{BaCamera, BaObservation, BaOptions, BaReport, bundle_adjust, Reconstruction},
+};
+
+use super::pipeline::{read_tracks, read_values};
+
+/// Options of the [merge]
+#[derive(Clone, Debug)]
+pub struct MergeOptions {
+	/// Whether the scale is estimated, the monocular reconstructions have arbitrary scales, `true` by default
+	pub with_scale: bool,
+	/// Tracks of the first reconstruction, one `2xN` Mat per camera with a column per point, `-1` for the missing
+	/// observations
+	pub tracks_a: Vec<Mat>,
+	/// Tracks of the second reconstruction
+	pub tracks_b: Vec<Mat>,
+	/// Options of the joint bundle adjustment, it's skipped if `None` or if the tracks are not set
+	pub bundle_adjustment: Option<BaOptions>,
+}
+
+impl Default for MergeOptions {
+	fn default() -> Self {
+		Self { with_scale: true, tracks_a: vec![], tracks_b: vec![], bundle_adjustment: Some(BaOptions::default()) }
+	}
+}
+
+impl MergeOptions {
+	pub fn with_scale(mut self, with_scale: bool) -> Self {
+		self.with_scale = with_scale;
+		self
+	}
+
+	/// Sets the tracks the reconstructions were computed from, they provide the observations of the joint bundle
+	/// adjustment, e.g. [PipelineOutput::tracks](crate::sfm::PipelineOutput::tracks)
+	pub fn with_tracks(mut self, tracks_a: Vec<Mat>, tracks_b: Vec<Mat>) -> Self {
+		self.tracks_a = tracks_a;
+		self.tracks_b = tracks_b;
+		self
+	}
+
+	pub fn with_bundle_adjustment(mut self, bundle_adjustment: Option<BaOptions>) -> Self {
+		self.bundle_adjustment = bundle_adjustment;
+		self
+	}
+}
+
+/// Result of the [merge]
+#[derive(Debug)]
+pub struct MergedReconstruction {
+	/// Joint reconstruction in the coordinates of the first one, the cameras of the first reconstruction are followed by
+	/// the cameras of the second one, the camera matrix is the one of the first reconstruction
+	pub reconstruction: Reconstruction,
+	/// Transform from the coordinates of the second reconstruction to the coordinates of the first one
+	pub alignment: Similarity,
+	/// Root mean square distance of the corresponding points after the alignment
+	pub alignment_rms: f64,
+	/// Index of each point of the second reconstruction in the merged points, the points of the first reconstruction
+	/// keep their indices
+	pub point_map_b: Vec<usize>,
+	/// `None` if the joint bundle adjustment was skipped
+	pub bundle_adjustment: Option<BaReport>,
+}
+
+/// Merges two partial reconstructions sharing some points, e.g. the overlapping chunks of a long sequence
+///
+/// `correspondences` are the `(index in a, index in b)` pairs of the same 3D points, at least 3 are required. The
+/// second reconstruction is aligned to the first one with [estimate_similarity](core::estimate_similarity), the
+/// corresponding points are fused into their mean and the cameras and points are refined by the joint
+/// [bundle_adjust] with the first camera fixed.
+pub fn merge(a: &Reconstruction, b: &Reconstruction, correspondences: &[(usize, usize)], options: &MergeOptions) -> Result<MergedReconstruction> {
+	let points_a = read_points(&a.points3d)?;
+	let points_b = read_points(&b.points3d)?;
+	for &(i, j) in correspondences {
+		if i >= points_a.len() || j >= points_b.len() {
+			return Err(Error::new(core::StsOutOfRange, format!("Correspondence ({}, {}) is out of bounds of: {} and: {} points", i, j, points_a.len(), points_b.len())));
+		}
+	}
+	let src = correspondences.iter().map(|&(_, j)| points_b[j]).collect::<Vec<_>>();
+	let dst = correspondences.iter().map(|&(i, _)| points_a[i]).collect::<Vec<_>>();
+	let alignment = core::estimate_similarity(&src, &dst, options.with_scale)?;
+	let alignment_rms = (src.iter().zip(&dst).map(|(&s, &d)| (alignment.apply(s) - d).norm().powi(2)).sum::<f64>() / src.len() as f64).sqrt();
+
+	let mut points = points_a;
+	let mut point_map_b = vec![usize::MAX; points_b.len()];
+	for &(i, j) in correspondences {
+		points[i] = (points[i] + alignment.apply(points_b[j])) / 2.;
+		point_map_b[j] = i;
+	}
+	for (j, index) in point_map_b.iter_mut().enumerate() {
+		if *index == usize::MAX {
+			*index = points.len();
+			points.push(alignment.apply(points_b[j]));
+		}
+	}
+
+	let mut cameras = Vec::with_capacity(a.rs.len() + b.rs.len());
+	let k_a = read_matx33(&a.k, "Camera matrix")?;
+	for (r, t) in a.rs.iter().zip(&a.ts) {
+		cameras.push(camera(k_a, read_matx33(r, "Rotation")?, read_vec3(t)?)?);
+	}
+	// x = R_b * X_b + t_b and X_b = R^T * (X_a - t) / s, the camera coordinates are scaled by s
+	let k_b = read_matx33(&b.k, "Camera matrix")?;
+	let inverse = alignment.inverse();
+	for (r, t) in b.rs.iter().zip(&b.ts) {
+		let (r_b, t_b) = (read_matx33(r, "Rotation")?, read_vec3(t)?);
+		let r = mul(&r_b, &inverse.r);
+		let rt = rotate(&r, alignment.t);
+		let t = Vec3d::from([alignment.s * t_b[0] - rt[0], alignment.s * t_b[1] - rt[1], alignment.s * t_b[2] - rt[2]]);
+		cameras.push(camera(k_b, r, t)?);
+	}
+
+	let bundle_adjustment = match &options.bundle_adjustment {
+		Some(ba_options) if !options.tracks_a.is_empty() && !options.tracks_b.is_empty() => {
+			let mut observations = track_observations(&options.tracks_a, 0, a, |j| j)?;
+			observations.extend(track_observations(&options.tracks_b, a.rs.len(), b, |j| point_map_b[j])?);
+			let mut ba_options = ba_options.clone();
+			if ba_options.fixed_cameras.is_empty() {
+				ba_options.fixed_cameras = vec![true];
+			}
+			Some(bundle_adjust(&mut cameras, &mut points, &observations, &ba_options)?)
+		}
+		_ => None,
+	};
+
+	let mut rs = Vec::with_capacity(cameras.len());
+	let mut ts = Vec::with_capacity(cameras.len());
+	for camera in &cameras {
+		let mut r = Mat::default();
+		calib3d::rodrigues(&Mat::from_slice(&[camera.rvec[0], camera.rvec[1], camera.rvec[2]])?, &mut r, &mut no_array())?;
+		rs.push(r);
+		ts.push(Mat::from_slice_2d(&[[camera.tvec[0]], [camera.tvec[1]], [camera.tvec[2]]])?);
+	}
+	let points3d = points.iter()
+		.map(|p| Mat::from_slice_2d(&[[p.x], [p.y], [p.z]]))
+		.collect::<Result<Vec<_>>>()?;
+	Ok(MergedReconstruction {
+		reconstruction: Reconstruction { rs, ts, k: a.k.try_clone()?, points3d },
+		alignment,
+		alignment_rms,
+		point_map_b,
+		bundle_adjustment,
+	})
+}
+
+fn read_points(points3d: &[Mat]) -> Result<Vec<Point3d>> {
+	points3d.iter()
+		.map(|p| read_values(p, 3, "3D point").map(|v| Point3d::new(v[0], v[1], v[2])))
+		.collect()
+}
+
+fn read_matx33(mat: &Mat, name: &str) -> Result<Matx33d> {
+	let values = read_values(mat, 9, name)?;
+	let mut out = Matx33d::default();
+	for (i, v) in values.into_iter().enumerate() {
+		out[(i / 3, i % 3)] = v;
+	}
+	Ok(out)
+}
+
+fn read_vec3(mat: &Mat) -> Result<Vec3d> {
+	let values = read_values(mat, 3, "Translation")?;
+	Ok(Vec3d::from([values[0], values[1], values[2]]))
+}
+
+fn camera(k: Matx33d, r: Matx33d, tvec: Vec3d) -> Result<BaCamera> {
+	let mut rvec = Mat::default();
+	calib3d::rodrigues(&Mat::from_slice_2d(&[
+		[r[(0, 0)], r[(0, 1)], r[(0, 2)]],
+		[r[(1, 0)], r[(1, 1)], r[(1, 2)]],
+		[r[(2, 0)], r[(2, 1)], r[(2, 2)]],
+	])?, &mut rvec, &mut no_array())?;
+	let rvec = read_values(&rvec, 3, "Rotation vector")?;
+	Ok(BaCamera { k, rvec: Vec3d::from([rvec[0], rvec[1], rvec[2]]), tvec })
+}
+
+fn mul(a: &Matx33d, b: &Matx33d) -> Matx33d {
+	let mut out = Matx33d::default();
+	for i in 0..3 {
+		for j in 0..3 {
+			out[(i, j)] = (0..3).map(|k| a[(i, k)] * b[(k, j)]).sum();
+		}
+	}
+	out
+}
+
+fn rotate(r: &Matx33d, v: Vec3d) -> [f64; 3] {
+	let mut out = [0.; 3];
+	for (i, out) in out.iter_mut().enumerate() {
+		*out = r[(i, 0)] * v[0] + r[(i, 1)] * v[1] + r[(i, 2)] * v[2];
+	}
+	out
+}
+
+/// Reads the observations of the reconstruction tracks, the cameras are offset by `camera_offset` and the points are
+/// mapped by `point`
+fn track_observations(tracks: &[Mat], camera_offset: usize, reconstruction: &Reconstruction, point: impl Fn(usize) -> usize) -> Result<Vec<BaObservation>> {
+	let frames = read_tracks(tracks)?;
+	if frames.len() != reconstruction.rs.len() {
+		return Err(Error::new(core::StsUnmatchedSizes, format!("Got: {} tracks, but the reconstruction has: {} cameras", frames.len(), reconstruction.rs.len())));
+	}
+	if matches!(frames.first(), Some(frame) if frame.len() != reconstruction.points3d.len()) {
+		return Err(Error::new(core::StsUnmatchedSizes, format!("Tracks have: {} columns, but the reconstruction has: {} points", frames[0].len(), reconstruction.points3d.len())));
+	}
+	Ok(frames.iter()
+		.enumerate()
+		.flat_map(|(camera, frame)| {
+			let point = &point;
+			frame.iter()
+				.enumerate()
+				.filter_map(move |(j, pixel)| pixel.map(|pixel| BaObservation { camera: camera_offset + camera, point: point(j), pixel }))
+		})
+		.collect())
+}
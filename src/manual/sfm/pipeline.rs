@@ -0,0 +1,450 @@
+//! Reconstruction pipeline over the libmv reconstruction with the Rust side keyframe selection
+//!
+//! `libmv_ReconstructionOptions` only accepts a fixed pair of the initial keyframes or the libmv autoselection that
+//! doesn't tell which pair was used. [ReconstructionBuilder] picks the pair from the tracks with one of the
+//! [KeyframeSelection] strategies, passes it to libmv and reports it alongside the reconstruction:
+//! ```no_run
+//! # use opencv::{core::Mat, sfm::{KeyframeSelection, ReconstructionBuilder}};
+//! # fn run(tracks: Vec<Mat>, k: Mat) -> opencv::Result<()> {
+//! let out = ReconstructionBuilder::new(tracks, k)
+//!     .keyframe_selection(KeyframeSelection::Gric)
+//!     .run()?;
+//! if let Some(keyframes) = out.keyframes {
+//!     println!("initialized from frames {} and {}", keyframes.first, keyframes.second);
+//! }
+//! # Ok(()) }
+//! ```
+//!
+//! libmv is sensitive to the outliers in the tracks. With an [OutlierPolicy] the builder repeats the reconstruction
+//! with the badly reprojecting observations and the short tracks removed until nothing is left to remove.
+
+use std::cmp::Ordering;
+
+use crate::{
+	calib3d,
+	core::{self, Mat, Point2d, Vector},
+	Error,
+	prelude::*,
+	Result,
+	sfm::{self, libmv_CameraIntrinsicsOptions, libmv_ReconstructionOptions, Reconstruction, RefineIntrinsics, SFMLibmvEuclideanReconstruction},
+};
+
+/// Minimum number of the common tracks of a keyframe pair, the fundamental matrix needs 8 correspondences
+const MIN_COMMON_TRACKS: usize = 8;
+/// Maximum number of the reconstructions repeated after removing the outliers
+const MAX_OUTLIER_ROUNDS: usize = 5;
+
+/// Strategy for choosing the initial pair of the frames the reconstruction starts from
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum KeyframeSelection {
+	/// Fixed pair of the 0-based frame indices
+	Manual(usize, usize),
+	/// libmv autoselection, the chosen pair is not reported
+	Libmv,
+	/// Pair where the fundamental matrix explains the motion best compared to the homography by the Geometric Robust
+	/// Information Criterion (Torr), i.e. the pair with the strongest evidence of the general 3D motion as opposed to
+	/// the pure rotation or the planar scene
+	Gric,
+	/// Pair with the most common tracks among the pairs with the median track displacement of at least `min_px` pixels
+	Parallax { min_px: f64 },
+	/// Pair with the largest median track displacement among the pairs sharing at least `min_ratio` of the tracks of
+	/// the frame with fewer observations
+	FeatureOverlap { min_ratio: f64 },
+}
+
+impl Default for KeyframeSelection {
+	#[inline]
+	fn default() -> Self {
+		Self::Libmv
+	}
+}
+
+/// Initial keyframes chosen by the [KeyframeSelection]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct KeyframePair {
+	/// 0-based index of the first frame
+	pub first: usize,
+	/// 0-based index of the second frame, always greater than `first`
+	pub second: usize,
+	/// Number of the tracks observed in both frames
+	pub common_tracks: usize,
+	/// `common_tracks` relative to the number of the observations in the frame with fewer of them
+	pub overlap: f64,
+	/// Median displacement of the common tracks in pixels
+	pub median_parallax: f64,
+	/// Relative GRIC improvement of the fundamental matrix over the homography `(gric_h - gric_f) / gric_h`, only
+	/// computed for [KeyframeSelection::Gric]
+	pub gric_score: Option<f64>,
+}
+
+/// Removal of the outliers from the tracks between the reconstructions, see [filter_tracks]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OutlierPolicy {
+	/// Observations with the reprojection error above this number of pixels are removed
+	pub max_reproj_px: f64,
+	/// Tracks left with fewer observations are removed
+	pub min_track_len: usize,
+}
+
+/// Observations and tracks removed after one reconstruction
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OutlierRound {
+	pub removed_observations: usize,
+	pub removed_tracks: usize,
+}
+
+/// Result of the [ReconstructionBuilder::run]
+#[derive(Debug)]
+pub struct PipelineOutput {
+	pub reconstruction: Reconstruction,
+	/// Keyframes the reconstruction was initialized from, `None` for [KeyframeSelection::Libmv]
+	pub keyframes: Option<KeyframePair>,
+	/// Tracks of the final reconstruction, the masked input tracks if there is no [OutlierPolicy]
+	pub tracks: Vec<Mat>,
+	/// Index of each of the `tracks` in the input tracks
+	pub track_indices: Vec<usize>,
+	/// Filtering rounds that removed anything, at most 5
+	pub outlier_rounds: Vec<OutlierRound>,
+}
+
+/// Builder of the Euclidean reconstruction from the 2D tracks
+#[derive(Debug)]
+pub struct ReconstructionBuilder {
+	tracks: Vec<Mat>,
+	k: Mat,
+	keyframe_selection: KeyframeSelection,
+	keyframe_window: usize,
+	refine_intrinsics: RefineIntrinsics,
+	outlier_policy: Option<OutlierPolicy>,
+	masks: Vec<Mat>,
+}
+
+impl ReconstructionBuilder {
+	/// Creates the builder from the tracks, one `2xN` Mat per frame with the negative coordinates for the missing
+	/// observations, and the initial guess of the `3x3` camera matrix
+	pub fn new(tracks: Vec<Mat>, k: Mat) -> Self {
+		Self {
+			tracks,
+			k,
+			keyframe_selection: KeyframeSelection::default(),
+			keyframe_window: 20,
+			refine_intrinsics: RefineIntrinsics::default(),
+			outlier_policy: None,
+			masks: vec![],
+		}
+	}
+
+	/// Sets the keyframe selection strategy, [KeyframeSelection::Libmv] by default
+	pub fn keyframe_selection(mut self, keyframe_selection: KeyframeSelection) -> Self {
+		self.keyframe_selection = keyframe_selection;
+		self
+	}
+
+	/// Sets the maximum distance between the frames of the evaluated keyframe pairs, 20 by default
+	pub fn keyframe_window(mut self, keyframe_window: usize) -> Self {
+		self.keyframe_window = keyframe_window;
+		self
+	}
+
+	/// Sets the intrinsics refined by the bundle adjustment, [RefineIntrinsics::FOCAL_LENGTH] by default
+	///
+	/// The unsupported combinations make [run](Self::run) fail.
+	pub fn refine_intrinsics(mut self, refine_intrinsics: RefineIntrinsics) -> Self {
+		self.refine_intrinsics = refine_intrinsics;
+		self
+	}
+
+	/// Enables the removal of the outliers and the repeated reconstruction without them, disabled by default
+	///
+	/// The reconstruction is repeated up to 5 times, starting from the camera matrix refined by the previous one.
+	pub fn outlier_policy(mut self, outlier_policy: OutlierPolicy) -> Self {
+		self.outlier_policy = Some(outlier_policy);
+		self
+	}
+
+	/// Sets the `CV_8UC1` masks of the dynamic regions, one per frame, the observations in their zero pixels are
+	/// removed before the keyframe selection, see [mask_tracks](sfm::mask_tracks)
+	pub fn masks(mut self, masks: Vec<Mat>) -> Self {
+		self.masks = masks;
+		self
+	}
+
+	fn masked_tracks(&self) -> Result<Vec<Mat>> {
+		let mut tracks = self.tracks.clone();
+		if !self.masks.is_empty() {
+			sfm::mask_tracks(&mut tracks, &self.masks)?;
+		}
+		Ok(tracks)
+	}
+
+	/// Runs the keyframe selection alone, see [select_keyframes]
+	#[inline]
+	pub fn select_keyframes(&self) -> Result<Option<KeyframePair>> {
+		select_keyframes(&self.masked_tracks()?, self.keyframe_selection, self.keyframe_window)
+	}
+
+	/// Selects the keyframes and runs the reconstruction on the current thread
+	pub fn run(&self) -> Result<PipelineOutput> {
+		self.refine_intrinsics.validate()?;
+		let mut tracks = self.masked_tracks()?;
+		let keyframes = select_keyframes(&tracks, self.keyframe_selection, self.keyframe_window)?;
+		let mut track_indices = (0..self.tracks.first().map_or(0, |t| t.cols() as usize)).collect::<Vec<_>>();
+		let mut outlier_rounds = vec![];
+		let mut k = self.k.clone();
+		loop {
+			let reconstruction = reconstruct(&tracks, &k, keyframes, self.refine_intrinsics)?;
+			let policy = match self.outlier_policy {
+				Some(policy) if outlier_rounds.len() < MAX_OUTLIER_ROUNDS => policy,
+				_ => return Ok(PipelineOutput { reconstruction, keyframes, tracks, track_indices, outlier_rounds }),
+			};
+			let filtered = filter_tracks(&reconstruction, &tracks, policy)?;
+			if filtered.removed_observations == 0 && filtered.removed_tracks == 0 {
+				return Ok(PipelineOutput { reconstruction, keyframes, tracks, track_indices, outlier_rounds });
+			}
+			outlier_rounds.push(OutlierRound { removed_observations: filtered.removed_observations, removed_tracks: filtered.removed_tracks });
+			track_indices = filtered.kept.iter().map(|&i| track_indices[i]).collect();
+			tracks = filtered.tracks;
+			k = reconstruction.k;
+		}
+	}
+}
+
+fn reconstruct(tracks: &[Mat], k: &Mat, keyframes: Option<KeyframePair>, refine_intrinsics: RefineIntrinsics) -> Result<Reconstruction> {
+	let (keyframe1, keyframe2, select_keyframes) = match keyframes {
+		Some(pair) => (pair.first as i32, pair.second as i32, 0),
+		None => (1, 2, 1),
+	};
+	let reconstruction_options = libmv_ReconstructionOptions::new(keyframe1, keyframe2, refine_intrinsics.bits(), select_keyframes, -1)?;
+	let k_values = read_values(k, 9, "Camera matrix")?;
+	let intrinsics_options = libmv_CameraIntrinsicsOptions::new(sfm::SFM_DISTORTION_MODEL_POLYNOMIAL, k_values[0], k_values[4], k_values[2], k_values[5], 0., 0., 0., 0., 0.)?;
+	let mut sfm = <dyn SFMLibmvEuclideanReconstruction>::create(intrinsics_options, reconstruction_options)?;
+	let mut k = k.clone();
+	let mut rs = Vector::<Mat>::new();
+	let mut ts = Vector::<Mat>::new();
+	let mut points3d = Vector::<Mat>::new();
+	SFMLibmvEuclideanReconstruction::run_1(&mut sfm, &Vector::<Mat>::from_iter(tracks.iter().cloned()), &mut k, &mut rs, &mut ts, &mut points3d)?;
+	Ok(Reconstruction { rs: rs.to_vec(), ts: ts.to_vec(), k, points3d: points3d.to_vec() })
+}
+
+/// Tracks left by the [filter_tracks]
+#[derive(Debug)]
+pub struct FilteredTracks {
+	/// Remaining tracks, one `2xM` `CV_64F` Mat per frame
+	pub tracks: Vec<Mat>,
+	/// Index of each remaining track in the input tracks
+	pub kept: Vec<usize>,
+	/// Number of the observations with too large reprojection error
+	pub removed_observations: usize,
+	/// Number of the tracks removed for being too short
+	pub removed_tracks: usize,
+}
+
+/// Removes the observations with the reprojection error above `policy.max_reproj_px` and then the tracks shorter than
+/// `policy.min_track_len` from the tracks the `reconstruction` was made from
+///
+/// The reconstruction must have a 3D point per track. The observations of the points behind the camera count as
+/// outliers, lens distortion is not modeled. Returns an error if no track is left.
+pub fn filter_tracks(reconstruction: &Reconstruction, tracks: &[Mat], policy: OutlierPolicy) -> Result<FilteredTracks> {
+	let mut frames = read_tracks(tracks)?;
+	let count = frames.first().map_or(0, |f| f.len());
+	if reconstruction.rs.len() != frames.len() || reconstruction.ts.len() != frames.len() {
+		return Err(Error::new(core::StsUnmatchedSizes, format!("Reconstruction has {} cameras, but there are {} frames", reconstruction.rs.len(), frames.len())));
+	}
+	if reconstruction.points3d.len() != count {
+		return Err(Error::new(core::StsUnmatchedSizes, format!("Reconstruction has {} points, but there are {} tracks", reconstruction.points3d.len(), count)));
+	}
+	let k = read_values(&reconstruction.k, 9, "Camera matrix")?;
+	let points = reconstruction.points3d.iter()
+		.map(|p| read_values(p, 3, "3D point"))
+		.collect::<Result<Vec<_>>>()?;
+	let mut removed_observations = 0;
+	for ((frame, r), t) in frames.iter_mut().zip(&reconstruction.rs).zip(&reconstruction.ts) {
+		let r = read_values(r, 9, "Rotation")?;
+		let t = read_values(t, 3, "Translation")?;
+		for (observation, point) in frame.iter_mut().zip(&points) {
+			if let Some(p) = *observation {
+				let cam = |row: usize| r[row * 3] * point[0] + r[row * 3 + 1] * point[1] + r[row * 3 + 2] * point[2] + t[row];
+				let (cx, cy, cz) = (cam(0), cam(1), cam(2));
+				let error = if cz > 0. {
+					let u = k[0] * cx / cz + k[1] * cy / cz + k[2];
+					let v = k[4] * cy / cz + k[5];
+					(u - p.x).hypot(v - p.y)
+				} else {
+					f64::INFINITY
+				};
+				if error > policy.max_reproj_px {
+					*observation = None;
+					removed_observations += 1;
+				}
+			}
+		}
+	}
+	let kept = (0..count)
+		.filter(|&i| frames.iter().filter(|f| f[i].is_some()).count() >= policy.min_track_len)
+		.collect::<Vec<_>>();
+	if kept.is_empty() {
+		return Err(Error::new(core::StsError, "All tracks were removed as outliers"));
+	}
+	let tracks = frames.iter()
+		.map(|frame| {
+			let (xs, ys): (Vec<_>, Vec<_>) = kept.iter()
+				.map(|&i| frame[i].map_or((-1., -1.), |p| (p.x, p.y)))
+				.unzip();
+			Mat::from_slice_2d(&[xs, ys])
+		})
+		.collect::<Result<Vec<_>>>()?;
+	Ok(FilteredTracks { tracks, removed_tracks: count - kept.len(), kept, removed_observations })
+}
+
+pub(super) fn read_values(mat: &Mat, len: usize, name: &str) -> Result<Vec<f64>> {
+	if mat.total() != len || mat.channels() != 1 {
+		return Err(Error::new(core::StsBadSize, format!("{} must have {} single channel elements, got: {}x{}", name, len, mat.rows(), mat.cols())));
+	}
+	let mut converted = Mat::default();
+	mat.convert_to(&mut converted, core::CV_64F, 1., 0.)?;
+	let converted = if converted.is_continuous() { converted } else { converted.try_clone()? };
+	Ok(converted.data_typed::<f64>()?.to_vec())
+}
+
+/// Reads the `2xN` tracks into the per frame observations, `None` for the missing ones
+pub(super) fn read_tracks(tracks: &[Mat]) -> Result<Vec<Vec<Option<Point2d>>>> {
+	let count = tracks.first().map_or(0, |t| t.cols() as usize);
+	tracks.iter()
+		.map(|track| {
+			if track.rows() != 2 || track.cols() as usize != count {
+				return Err(Error::new(core::StsUnmatchedSizes, format!("Tracks must all be 2x{}, got: {}x{}", count, track.rows(), track.cols())));
+			}
+			let values = read_values(track, 2 * count, "Track")?;
+			Ok((0..count)
+				.map(|i| Some(Point2d::new(values[i], values[count + i])).filter(|p| p.x >= 0. && p.y >= 0.))
+				.collect())
+		})
+		.collect()
+}
+
+fn median(mut values: Vec<f64>) -> f64 {
+	if values.is_empty() {
+		return 0.;
+	}
+	values.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+	values[values.len() / 2]
+}
+
+/// Chooses the initial keyframes from the `2xN` tracks with the `selection` strategy
+///
+/// The pairs with at most `window` frames between them and at least 8 common tracks are evaluated. Returns `None` for
+/// [KeyframeSelection::Libmv] and an error when no pair satisfies the strategy.
+pub fn select_keyframes(tracks: &[Mat], selection: KeyframeSelection, window: usize) -> Result<Option<KeyframePair>> {
+	let frames = &read_tracks(tracks)?;
+	let pair = |first: usize, second: usize| {
+		let common = frames[first].iter()
+			.zip(&frames[second])
+			.filter_map(|(a, b)| a.and_then(|a| b.map(|b| (a, b))))
+			.collect::<Vec<_>>();
+		let observed = |frame: &[Option<Point2d>]| frame.iter().filter(|p| p.is_some()).count();
+		let fewer = observed(&frames[first]).min(observed(&frames[second]));
+		let keyframes = KeyframePair {
+			first,
+			second,
+			common_tracks: common.len(),
+			overlap: if fewer > 0 { common.len() as f64 / fewer as f64 } else { 0. },
+			median_parallax: median(common.iter().map(|(a, b)| (b.x - a.x).hypot(b.y - a.y)).collect()),
+			gric_score: None,
+		};
+		(keyframes, common)
+	};
+	let candidates = || (0..frames.len())
+		.flat_map(move |first| (first + 1..frames.len().min(first + window + 1)).map(move |second| (first, second)))
+		.map(|(first, second)| pair(first, second))
+		.filter(|(keyframes, _)| keyframes.common_tracks >= MIN_COMMON_TRACKS);
+	let best_by = |candidates: Vec<KeyframePair>, key: &dyn Fn(&KeyframePair) -> f64| {
+		candidates.into_iter()
+			.max_by(|a, b| key(a).partial_cmp(&key(b)).unwrap_or(Ordering::Equal))
+	};
+
+	let out = match selection {
+		KeyframeSelection::Libmv => return Ok(None),
+		KeyframeSelection::Manual(first, second) => {
+			if first >= second || second >= frames.len() {
+				return Err(Error::new(core::StsOutOfRange, format!("Keyframes must be two increasing indices below the frame count: {}, got: {} and {}", frames.len(), first, second)));
+			}
+			Some(pair(first, second).0)
+		}
+		KeyframeSelection::Gric => {
+			let mut scored = vec![];
+			for (mut keyframes, common) in candidates() {
+				// non-positive score means the homography is at least as good, the pair is degenerate
+				match gric_score(&common)? {
+					Some(score) if score > 0. => {
+						keyframes.gric_score = Some(score);
+						scored.push(keyframes);
+					}
+					_ => {}
+				}
+			}
+			best_by(scored, &|k| k.gric_score.unwrap_or(0.))
+		}
+		KeyframeSelection::Parallax { min_px } => {
+			best_by(candidates().map(|(k, _)| k).filter(|k| k.median_parallax >= min_px).collect(), &|k| k.common_tracks as f64)
+		}
+		KeyframeSelection::FeatureOverlap { min_ratio } => {
+			best_by(candidates().map(|(k, _)| k).filter(|k| k.overlap >= min_ratio).collect(), &|k| k.median_parallax)
+		}
+	};
+	out.map(Some)
+		.ok_or_else(|| Error::new(core::StsError, format!("No pair of the {} frames satisfies the keyframe selection: {:?}", frames.len(), selection)))
+}
+
+/// Returns `(gric_h - gric_f) / gric_h` for the correspondences, `None` if either model can't be fitted
+fn gric_score(common: &[(Point2d, Point2d)]) -> Result<Option<f64>> {
+	// expected noise of the track positions in pixels
+	const SIGMA: f64 = 1.;
+	let points1 = Mat::from_exact_iter(common.iter().map(|c| c.0))?;
+	let points2 = Mat::from_exact_iter(common.iter().map(|c| c.1))?;
+	let f = calib3d::find_fundamental_mat(&points1, &points2, calib3d::FM_RANSAC, 3. * SIGMA, 0.99, 1000, &mut core::no_array())?;
+	let h = calib3d::find_homography(&points1, &points2, &mut core::no_array(), calib3d::RANSAC, 3. * SIGMA)?;
+	// several solutions are stacked for the 7-point algorithm, the first one is used
+	if f.rows() < 3 || h.rows() != 3 {
+		return Ok(None);
+	}
+	let f = read_values(&Mat::roi(&f, core::Rect::new(0, 0, 3, 3))?.try_clone()?, 9, "Fundamental matrix")?;
+	let h = read_values(&h, 9, "Homography")?;
+	let mul = |m: &[f64], p: Point2d| [
+		m[0] * p.x + m[1] * p.y + m[2],
+		m[3] * p.x + m[4] * p.y + m[5],
+		m[6] * p.x + m[7] * p.y + m[8],
+	];
+	let f_errors = common.iter().map(|&(a, b)| {
+		// Sampson distance
+		let fa = mul(&f, a);
+		let ftb = [
+			f[0] * b.x + f[3] * b.y + f[6],
+			f[1] * b.x + f[4] * b.y + f[7],
+		];
+		let epipolar = b.x * fa[0] + b.y * fa[1] + fa[2];
+		let denominator = fa[0] * fa[0] + fa[1] * fa[1] + ftb[0] * ftb[0] + ftb[1] * ftb[1];
+		if denominator > 0. { epipolar * epipolar / denominator } else { 0. }
+	});
+	let h_errors = common.iter().map(|&(a, b)| {
+		let ha = mul(&h, a);
+		if ha[2] == 0. {
+			f64::INFINITY
+		} else {
+			(ha[0] / ha[2] - b.x).powi(2) + (ha[1] / ha[2] - b.y).powi(2)
+		}
+	});
+	let gric_f = gric(f_errors, common.len(), 3, 7, SIGMA);
+	let gric_h = gric(h_errors, common.len(), 2, 8, SIGMA);
+	Ok(if gric_h > 0. { Some((gric_h - gric_f) / gric_h) } else { None })
+}
+
+/// Torr's GRIC of a model with the structure dimension `d` and `k` parameters for the 4-dimensional correspondences
+fn gric(squared_errors: impl Iterator<Item=f64>, n: usize, d: usize, k: usize, sigma: f64) -> f64 {
+	const R: f64 = 4.;
+	const LAMBDA3: f64 = 2.;
+	let (n, d, k) = (n as f64, d as f64, k as f64);
+	let lambda1 = R.ln();
+	let lambda2 = (R * n).ln();
+	let cap = LAMBDA3 * (R - d);
+	squared_errors.map(|e| (e / (sigma * sigma)).min(cap)).sum::<f64>() + lambda1 * d * n + lambda2 * k
+}
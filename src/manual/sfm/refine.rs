@@ -0,0 +1,138 @@
+use std::{
+	fmt,
+	ops::{BitAnd, BitOr, BitOrAssign},
+};
+
+use crate::{
+	core,
+	Error,
+	Result,
+	sfm::{self, libmv_ReconstructionOptions},
+};
+
+/// Intrinsics refined by the bundle adjustment of the libmv reconstruction, typed `SFM_REFINE_*` flags
+///
+/// libmv only supports the combinations that include the focal length and refine K2 together with K1, or no
+/// refinement at all, see [validate](Self::validate).
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RefineIntrinsics(i32);
+
+impl RefineIntrinsics {
+	pub const NONE: Self = Self(0);
+	pub const FOCAL_LENGTH: Self = Self(sfm::SFM_REFINE_FOCAL_LENGTH);
+	pub const PRINCIPAL_POINT: Self = Self(sfm::SFM_REFINE_PRINCIPAL_POINT);
+	pub const RADIAL_K1: Self = Self(sfm::SFM_REFINE_RADIAL_DISTORTION_K1);
+	pub const RADIAL_K2: Self = Self(sfm::SFM_REFINE_RADIAL_DISTORTION_K2);
+
+	const ALL: i32 = sfm::SFM_REFINE_FOCAL_LENGTH | sfm::SFM_REFINE_PRINCIPAL_POINT | sfm::SFM_REFINE_RADIAL_DISTORTION_K1 | sfm::SFM_REFINE_RADIAL_DISTORTION_K2;
+
+	/// Creates the flags from the raw `SFM_REFINE_*` combination, fails on the unknown bits and the unsupported
+	/// combinations
+	pub fn from_bits(bits: i32) -> Result<Self> {
+		if bits & !Self::ALL != 0 {
+			return Err(Error::new(core::StsBadFlag, format!("Unknown intrinsics refinement flags: {:#x}", bits & !Self::ALL)));
+		}
+		let out = Self(bits);
+		out.validate()?;
+		Ok(out)
+	}
+
+	#[inline]
+	pub fn bits(self) -> i32 {
+		self.0
+	}
+
+	#[inline]
+	pub fn is_empty(self) -> bool {
+		self.0 == 0
+	}
+
+	#[inline]
+	pub fn contains(self, other: Self) -> bool {
+		self.0 & other.0 == other.0
+	}
+
+	/// Checks that libmv supports the combination
+	pub fn validate(self) -> Result<()> {
+		if !self.is_empty() && !self.contains(Self::FOCAL_LENGTH) {
+			return Err(Error::new(core::StsBadFlag, format!("Refining {:?} requires refining the focal length too", self)));
+		}
+		if self.contains(Self::RADIAL_K2) && !self.contains(Self::RADIAL_K1) {
+			return Err(Error::new(core::StsBadFlag, "Refining the radial distortion K2 requires refining K1 too"));
+		}
+		Ok(())
+	}
+}
+
+impl Default for RefineIntrinsics {
+	/// Focal length only, the libmv default
+	#[inline]
+	fn default() -> Self {
+		Self::FOCAL_LENGTH
+	}
+}
+
+impl fmt::Debug for RefineIntrinsics {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if self.is_empty() {
+			return f.write_str("NONE");
+		}
+		let names = [
+			(Self::FOCAL_LENGTH, "FOCAL_LENGTH"),
+			(Self::PRINCIPAL_POINT, "PRINCIPAL_POINT"),
+			(Self::RADIAL_K1, "RADIAL_K1"),
+			(Self::RADIAL_K2, "RADIAL_K2"),
+		];
+		let mut first = true;
+		for (flag, name) in names.iter() {
+			if self.contains(*flag) {
+				if !first {
+					f.write_str(" | ")?;
+				}
+				f.write_str(name)?;
+				first = false;
+			}
+		}
+		Ok(())
+	}
+}
+
+impl BitOr for RefineIntrinsics {
+	type Output = Self;
+
+	#[inline]
+	fn bitor(self, rhs: Self) -> Self {
+		Self(self.0 | rhs.0)
+	}
+}
+
+impl BitOrAssign for RefineIntrinsics {
+	#[inline]
+	fn bitor_assign(&mut self, rhs: Self) {
+		self.0 |= rhs.0;
+	}
+}
+
+impl BitAnd for RefineIntrinsics {
+	type Output = Self;
+
+	#[inline]
+	fn bitand(self, rhs: Self) -> Self {
+		Self(self.0 & rhs.0)
+	}
+}
+
+impl libmv_ReconstructionOptions {
+	/// Returns the typed `refine_intrinsics`, fails if it has the unknown bits or an unsupported combination
+	#[inline]
+	pub fn refine_intrinsics_flags(&self) -> Result<RefineIntrinsics> {
+		RefineIntrinsics::from_bits(self.refine_intrinsics)
+	}
+
+	/// Validates and sets the `refine_intrinsics`
+	pub fn set_refine_intrinsics_flags(&mut self, refine_intrinsics: RefineIntrinsics) -> Result<()> {
+		refine_intrinsics.validate()?;
+		self.refine_intrinsics = refine_intrinsics.bits();
+		Ok(())
+	}
+}
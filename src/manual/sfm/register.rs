@@ -0,0 +1,168 @@
+use crate::{
+	calib3d,
+	core::{self, Mat, Matx33d, no_array, Point2d, ToInputArray, Vec3d, Vector},
+	Error,
+	prelude::*,
+	Result,
+	sfm::BaCamera,
+};
+
+use super::{bundle_adjust::pose_covariance, cloud::points_from_mat};
+
+/// Minimal PnP solver used for the RANSAC hypotheses
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PnpSolver {
+	/// `SOLVEPNP_ITERATIVE`
+	Iterative,
+	/// `SOLVEPNP_EPNP`
+	Epnp,
+	/// `SOLVEPNP_P3P`
+	P3p,
+	/// `SOLVEPNP_AP3P`
+	Ap3p,
+	/// `SOLVEPNP_SQPNP`
+	Sqpnp,
+}
+
+impl PnpSolver {
+	#[inline]
+	pub fn flags(self) -> i32 {
+		match self {
+			PnpSolver::Iterative => calib3d::SOLVEPNP_ITERATIVE,
+			PnpSolver::Epnp => calib3d::SOLVEPNP_EPNP,
+			PnpSolver::P3p => calib3d::SOLVEPNP_P3P,
+			PnpSolver::Ap3p => calib3d::SOLVEPNP_AP3P,
+			PnpSolver::Sqpnp => calib3d::SOLVEPNP_SQPNP,
+		}
+	}
+}
+
+/// Robust estimation method for the [register_camera]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PnpMethod {
+	/// Classic `solvePnPRansac()`
+	Ransac {
+		solver: PnpSolver,
+		iterations_count: i32,
+		/// Maximum reprojection error in pixels for the point to be considered an inlier
+		reprojection_error: f32,
+		confidence: f64,
+	},
+	/// USAC framework variant of `solvePnPRansac()`
+	#[cfg(ocvrs_opencv_branch_4)]
+	Usac(calib3d::UsacParams),
+}
+
+impl PnpMethod {
+	/// RANSAC with the specified `solver` and OpenCV default parameters
+	#[inline]
+	pub fn ransac(solver: PnpSolver) -> Self {
+		PnpMethod::Ransac { solver, iterations_count: 100, reprojection_error: 8., confidence: 0.99 }
+	}
+}
+
+impl Default for PnpMethod {
+	#[inline]
+	fn default() -> Self {
+		Self::ransac(PnpSolver::Iterative)
+	}
+}
+
+/// Camera pose estimated by the [register_camera]
+#[derive(Debug)]
+pub struct CameraPose {
+	/// `3x3` rotation matrix
+	pub r: Mat,
+	/// `3x1` translation vector
+	pub t: Mat,
+	/// Indices of the correspondences consistent with the pose
+	pub inliers: Vec<i32>,
+}
+
+impl CameraPose {
+	/// Returns the `6x6` `CV_64F` covariance of the pose in the `[rvec, t]` order for fusing with the other sensors
+	///
+	/// The arguments are the ones passed to the [register_camera], only the inliers are used. The 3D points are treated
+	/// as exact, the covariance is the inverse of the Gauss-Newton Hessian scaled by the reprojection error variance.
+	/// Returns `None` if the points don't constrain the pose.
+	pub fn covariance(&self, points3d: &dyn ToInputArray, points2d: &dyn ToInputArray, k: &dyn ToInputArray) -> Result<Option<Mat>> {
+		let points3d = points_from_mat(&points3d.input_array()?.get_mat(-1)?)?;
+		let points2d = points2_from_mat(&points2d.input_array()?.get_mat(-1)?)?;
+		if points3d.len() != points2d.len() {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("Got {} 3D points but {} 2D points", points3d.len(), points2d.len())));
+		}
+		let (points3d, points2d) = if self.inliers.is_empty() {
+			(points3d, points2d)
+		} else {
+			self.inliers.iter()
+				.map(|&i| {
+					let i = i as usize;
+					match (points3d.get(i), points2d.get(i)) {
+						(Some(&p3), Some(&p2)) => Ok((p3, p2)),
+						_ => Err(Error::new(core::StsOutOfRange, format!("Inlier: {} is out of bounds of: {} points", i, points3d.len()))),
+					}
+				})
+				.collect::<Result<Vec<_>>>()?
+				.into_iter()
+				.unzip()
+		};
+		let k = f64_values(&k.input_array()?.get_mat(-1)?)?;
+		if k.len() != 9 {
+			return Err(Error::new(core::StsBadSize, format!("Camera matrix must have 9 elements, got: {}", k.len())));
+		}
+		let mut rvec = Mat::default();
+		calib3d::rodrigues(&self.r, &mut rvec, &mut no_array())?;
+		let (rvec, t) = (f64_values(&rvec)?, f64_values(&self.t)?);
+		let camera = BaCamera {
+			k: Matx33d::from([k[0], k[1], k[2], k[3], k[4], k[5], k[6], k[7], k[8]]),
+			rvec: Vec3d::from([rvec[0], rvec[1], rvec[2]]),
+			tvec: Vec3d::from([t[0], t[1], t[2]]),
+		};
+		pose_covariance(camera, &points3d, &points2d)
+			.map(|cov| Mat::from_slice_2d(&(0..6).map(|r| (0..6).map(|c| cov[(r, c)]).collect::<Vec<_>>()).collect::<Vec<_>>()))
+			.transpose()
+	}
+}
+
+/// Localizes a new camera against the existing point cloud using the 3D-2D correspondences
+///
+/// `points3d` and `points2d` are the matching points (e.g. `Vector<Point3d>` and `Vector<Point2d>` or `Nx3`/`Nx2` Mats)
+/// and `k` is the `3x3` camera matrix, image points are expected to be undistorted. Returns `None` if no pose was found.
+pub fn register_camera(points3d: &dyn ToInputArray, points2d: &dyn ToInputArray, k: &dyn ToInputArray, method: PnpMethod) -> Result<Option<CameraPose>> {
+	let mut rvec = Mat::default();
+	let mut t = Mat::default();
+	let mut inliers = Vector::<i32>::new();
+	let found = match method {
+		PnpMethod::Ransac { solver, iterations_count, reprojection_error, confidence } => {
+			calib3d::solve_pnp_ransac(points3d, points2d, k, &no_array(), &mut rvec, &mut t, false, iterations_count, reprojection_error, confidence, &mut inliers, solver.flags())?
+		}
+		#[cfg(ocvrs_opencv_branch_4)]
+		PnpMethod::Usac(params) => {
+			// USAC variant takes the camera matrix as input-output
+			let mut k = k.input_array()?.get_mat(-1)?.try_clone()?;
+			calib3d::solve_pnp_ransac_1(points3d, points2d, &mut k, &no_array(), &mut rvec, &mut t, &mut inliers, params)?
+		}
+	};
+	if !found {
+		return Ok(None);
+	}
+	let mut r = Mat::default();
+	calib3d::rodrigues(&rvec, &mut r, &mut no_array())?;
+	Ok(Some(CameraPose { r, t, inliers: inliers.to_vec() }))
+}
+
+/// Reads the points from `Nx2` 1-channel or `Nx1`/`1xN` 2-channel Mat
+fn points2_from_mat(mat: &Mat) -> Result<Vec<Point2d>> {
+	let values = f64_values(mat)?;
+	if !(mat.channels() == 2 || mat.channels() == 1 && mat.cols() == 2) {
+		return Err(Error::new(core::StsBadSize, format!("Expected Nx2 or 2-channel vector Mat, but got: {}x{} with: {} channels", mat.rows(), mat.cols(), mat.channels())));
+	}
+	Ok(values.chunks_exact(2).map(|p| Point2d::new(p[0], p[1])).collect())
+}
+
+fn f64_values(mat: &Mat) -> Result<Vec<f64>> {
+	let mut out = Mat::default();
+	mat.convert_to(&mut out, core::CV_64F, 1., 0.)?;
+	let out = if out.is_continuous() { out } else { out.try_clone()? };
+	Ok(out.data_typed::<f64>()?.to_vec())
+}
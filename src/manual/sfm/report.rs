@@ -0,0 +1,145 @@
+use std::cmp::Ordering;
+
+use crate::{
+	core::{self, Mat, Vector},
+	Error,
+	prelude::*,
+	Result,
+	sfm::{BaseSFM, Reconstruction},
+};
+
+/// Distribution of the reprojection errors in pixels
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ErrorStats {
+	/// Number of the observations, the other fields are `0` if it's `0`
+	pub count: usize,
+	pub mean: f64,
+	pub median: f64,
+	/// 95th percentile
+	pub p95: f64,
+	pub max: f64,
+}
+
+impl ErrorStats {
+	fn from_errors(mut errors: Vec<f64>) -> Self {
+		if errors.is_empty() {
+			return Self::default();
+		}
+		errors.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+		let percentile = |p: f64| errors[((errors.len() - 1) as f64 * p).round() as usize];
+		Self {
+			count: errors.len(),
+			mean: errors.iter().sum::<f64>() / errors.len() as f64,
+			median: percentile(0.5),
+			p95: percentile(0.95),
+			max: errors[errors.len() - 1],
+		}
+	}
+}
+
+/// Reprojection errors of a reconstruction, see [reprojection_error_report]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ErrorReport {
+	pub overall: ErrorStats,
+	/// Errors of the observations in each camera
+	pub per_view: Vec<ErrorStats>,
+	/// Errors of the observations of each 3D point
+	pub per_point: Vec<ErrorStats>,
+}
+
+impl ErrorReport {
+	/// Returns the indices of the views with the median error above `threshold` pixels
+	pub fn views_above(&self, threshold: f64) -> Vec<usize> {
+		self.per_view.iter().enumerate().filter(|(_, s)| s.median > threshold).map(|(i, _)| i).collect()
+	}
+
+	/// Returns the indices of the points with the median error above `threshold` pixels
+	pub fn points_above(&self, threshold: f64) -> Vec<usize> {
+		self.per_point.iter().enumerate().filter(|(_, s)| s.median > threshold).map(|(i, _)| i).collect()
+	}
+}
+
+fn to_f64_values(mat: &Mat, len: usize, name: &str) -> Result<Vec<f64>> {
+	if mat.total() != len || mat.channels() != 1 {
+		return Err(Error::new(core::StsBadSize, format!("{} must have {} single channel elements, got: {}x{}", name, len, mat.rows(), mat.cols())));
+	}
+	let mut converted = Mat::default();
+	mat.convert_to(&mut converted, core::CV_64F, 1., 0.)?;
+	let converted = if converted.is_continuous() { converted } else { converted.try_clone()? };
+	Ok(converted.data_typed::<f64>()?.to_vec())
+}
+
+/// Computes the reprojection errors of the reconstructed points in the tracks they were reconstructed from
+///
+/// `rs`, `ts`, `k` and `points3d` are the outputs of `sfm::reconstruct()` and `tracks` is its input: one `2xN` Mat per
+/// view with `N` equal to the number of points and the negative coordinates for the missing observations. Lens
+/// distortion is not modeled and the points behind a camera are skipped.
+pub fn reprojection_error_report(rs: &[Mat], ts: &[Mat], k: &Mat, points3d: &[Mat], tracks: &[Mat]) -> Result<ErrorReport> {
+	if rs.len() != ts.len() || rs.len() != tracks.len() {
+		return Err(Error::new(core::StsUnmatchedSizes, format!("Numbers of rotations: {}, translations: {} and tracks: {} must match", rs.len(), ts.len(), tracks.len())));
+	}
+	let k = to_f64_values(k, 9, "Camera matrix")?;
+	let points = points3d.iter()
+		.map(|p| to_f64_values(p, 3, "3D point"))
+		.collect::<Result<Vec<_>>>()?;
+	let mut per_view = Vec::with_capacity(rs.len());
+	let mut per_point = vec![vec![]; points.len()];
+	let mut overall = vec![];
+	for ((r, t), track) in rs.iter().zip(ts).zip(tracks) {
+		let r = to_f64_values(r, 9, "Rotation")?;
+		let t = to_f64_values(t, 3, "Translation")?;
+		if track.rows() != 2 || track.cols() as usize != points.len() {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("Track must be 2x{} to match the number of points, got: {}x{}", points.len(), track.rows(), track.cols())));
+		}
+		let track = to_f64_values(track, 2 * points.len(), "Track")?;
+		let mut view = vec![];
+		for (i, point) in points.iter().enumerate() {
+			let (x, y) = (track[i], track[points.len() + i]);
+			if x < 0. || y < 0. {
+				continue;
+			}
+			let cam = |row: usize| r[row * 3] * point[0] + r[row * 3 + 1] * point[1] + r[row * 3 + 2] * point[2] + t[row];
+			let (cx, cy, cz) = (cam(0), cam(1), cam(2));
+			if cz <= 0. {
+				continue;
+			}
+			let u = k[0] * cx / cz + k[1] * cy / cz + k[2];
+			let v = k[4] * cy / cz + k[5];
+			let error = (u - x).hypot(v - y);
+			view.push(error);
+			per_point[i].push(error);
+			overall.push(error);
+		}
+		per_view.push(ErrorStats::from_errors(view));
+	}
+	Ok(ErrorReport {
+		overall: ErrorStats::from_errors(overall),
+		per_view,
+		per_point: per_point.into_iter().map(ErrorStats::from_errors).collect(),
+	})
+}
+
+impl Reconstruction {
+	/// Computes the reprojection errors in the `tracks` the reconstruction was run on, see [reprojection_error_report]
+	#[inline]
+	pub fn error_report(&self, tracks: &[Mat]) -> Result<ErrorReport> {
+		reprojection_error_report(&self.rs, &self.ts, &self.k, &self.points3d, tracks)
+	}
+}
+
+/// Reprojection error distributions of the `BaseSFM` reconstructions
+///
+/// `get_error()` returns a single scalar that hides the systematic problems like a single badly registered view.
+pub trait BaseSFMErrorReport: BaseSFM {
+	/// Computes the reprojection errors in the `tracks` passed to `run()`, see [reprojection_error_report]
+	fn error_report(&mut self, tracks: &[Mat]) -> Result<ErrorReport> {
+		let mut rs = Vector::<Mat>::new();
+		let mut ts = Vector::<Mat>::new();
+		self.get_cameras(&mut rs, &mut ts)?;
+		let mut points3d = Vector::<Mat>::new();
+		self.get_points(&mut points3d)?;
+		reprojection_error_report(&rs.to_vec(), &ts.to_vec(), &self.get_intrinsics()?, &points3d.to_vec(), tracks)
+	}
+}
+
+impl<T: BaseSFM + ?Sized> BaseSFMErrorReport for T {}
@@ -0,0 +1,210 @@
+//! Recovery of the metric scale of the Euclidean reconstructions
+//!
+//! The reconstructions are only defined up to a similarity transform. [apply_known_distance] fixes the scale from a
+//! measured distance between two reconstructed points and [align_to_gps] fits the similarity to the camera positions
+//! known in the geodetic coordinates, placing the reconstruction in the local East-North-Up frame.
+
+use crate::{
+	core::{self, Mat, Matx33d, Point3d, Similarity, Vec3d},
+	Error,
+	prelude::*,
+	Result,
+	sfm::Reconstruction,
+};
+
+/// WGS84 semi-major axis in meters
+const WGS84_A: f64 = 6_378_137.;
+/// WGS84 first eccentricity squared
+const WGS84_E2: f64 = 6.694_379_990_14e-3;
+
+/// WGS84 position, the angles are in degrees and the altitude in meters above the ellipsoid
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GeoPoint {
+	pub latitude: f64,
+	pub longitude: f64,
+	pub altitude: f64,
+}
+
+impl GeoPoint {
+	#[inline]
+	pub fn new(latitude: f64, longitude: f64, altitude: f64) -> Self {
+		Self { latitude, longitude, altitude }
+	}
+
+	/// Earth-centered, Earth-fixed coordinates in meters
+	pub fn to_ecef(&self) -> [f64; 3] {
+		let (lat, lon) = (self.latitude.to_radians(), self.longitude.to_radians());
+		let n = WGS84_A / (1. - WGS84_E2 * lat.sin().powi(2)).sqrt();
+		[
+			(n + self.altitude) * lat.cos() * lon.cos(),
+			(n + self.altitude) * lat.cos() * lon.sin(),
+			(n * (1. - WGS84_E2) + self.altitude) * lat.sin(),
+		]
+	}
+
+	/// East-North-Up coordinates in meters of `self` in the tangent plane at the `origin`
+	pub fn to_enu(&self, origin: &GeoPoint) -> Vec3d {
+		let p = self.to_ecef();
+		let o = origin.to_ecef();
+		let d = [p[0] - o[0], p[1] - o[1], p[2] - o[2]];
+		let (lat, lon) = (origin.latitude.to_radians(), origin.longitude.to_radians());
+		Vec3d::from([
+			-lon.sin() * d[0] + lon.cos() * d[1],
+			-lat.sin() * lon.cos() * d[0] - lat.sin() * lon.sin() * d[1] + lat.cos() * d[2],
+			lat.cos() * lon.cos() * d[0] + lat.cos() * lon.sin() * d[1] + lat.sin() * d[2],
+		])
+	}
+}
+
+fn read_vec3(mat: &Mat) -> Result<Vec3d> {
+	if mat.total() != 3 || mat.channels() != 1 {
+		return Err(Error::new(core::StsBadSize, format!("Expected 3 element vector, got: {}x{}", mat.rows(), mat.cols())));
+	}
+	let mut converted = Mat::default();
+	mat.reshape(1, 3)?.convert_to(&mut converted, core::CV_64F, 1., 0.)?;
+	Ok(Vec3d::from([*converted.at::<f64>(0)?, *converted.at::<f64>(1)?, *converted.at::<f64>(2)?]))
+}
+
+fn read_matx33(mat: &Mat) -> Result<Matx33d> {
+	if mat.rows() != 3 || mat.cols() != 3 || mat.channels() != 1 {
+		return Err(Error::new(core::StsBadSize, format!("Expected 3x3 rotation, got: {}x{}", mat.rows(), mat.cols())));
+	}
+	let mut converted = Mat::default();
+	mat.convert_to(&mut converted, core::CV_64F, 1., 0.)?;
+	let mut out = Matx33d::zeros();
+	for i in 0..3 {
+		for j in 0..3 {
+			out[(i, j)] = *converted.at_2d::<f64>(i as i32, j as i32)?;
+		}
+	}
+	Ok(out)
+}
+
+#[inline]
+fn vec3_mat(v: &Vec3d) -> Result<Mat> {
+	Mat::from_slice_2d(&[[v[0]], [v[1]], [v[2]]])
+}
+
+#[inline]
+fn vec3_point(v: &Vec3d) -> Point3d {
+	Point3d::new(v[0], v[1], v[2])
+}
+
+#[inline]
+fn point3_vec(p: Point3d) -> Vec3d {
+	Vec3d::from([p.x, p.y, p.z])
+}
+
+#[inline]
+fn matx33_mat(m: &Matx33d) -> Result<Mat> {
+	Mat::from_slice_2d(&[&m.val[0..3], &m.val[3..6], &m.val[6..9]])
+}
+
+/// Returns `a * b`, transposing `b` if `b_t`
+fn mul33(a: &Matx33d, b: &Matx33d, b_t: bool) -> Matx33d {
+	let mut out = Matx33d::zeros();
+	for i in 0..3 {
+		for j in 0..3 {
+			out[(i, j)] = (0..3).map(|k| a[(i, k)] * if b_t { b[(j, k)] } else { b[(k, j)] }).sum();
+		}
+	}
+	out
+}
+
+/// Returns `m * v`, transposing `m` if `m_t`
+fn mul3(m: &Matx33d, v: &Vec3d, m_t: bool) -> Vec3d {
+	let row = |i: usize| (0..3).map(|k| if m_t { m[(k, i)] } else { m[(i, k)] } * v[k]).sum();
+	Vec3d::from([row(0), row(1), row(2)])
+}
+
+/// Returns the camera centers `-Rᵀ * t` of the reconstruction
+pub fn camera_centers(recon: &Reconstruction) -> Result<Vec<Vec3d>> {
+	recon.rs.iter()
+		.zip(&recon.ts)
+		.map(|(r, t)| {
+			let c = mul3(&read_matx33(r)?, &read_vec3(t)?, true);
+			Ok(Vec3d::from([-c[0], -c[1], -c[2]]))
+		})
+		.collect()
+}
+
+/// Transforms the points and the cameras of the reconstruction, the camera frames keep their orientation relative to
+/// the scene and are scaled along with it
+pub fn apply_similarity(recon: &mut Reconstruction, similarity: &Similarity) -> Result<()> {
+	if !(similarity.s.is_finite() && similarity.s > 0.) {
+		return Err(Error::new(core::StsBadArg, format!("Scale must be positive, got: {}", similarity.s)));
+	}
+	for point in &mut recon.points3d {
+		*point = vec3_mat(&point3_vec(similarity.apply(vec3_point(&read_vec3(point)?))))?;
+	}
+	// x_cam' = scale * (R * X + t) with X = Rsᵀ * (X' - T) / scale
+	for (r, t) in recon.rs.iter_mut().zip(&mut recon.ts) {
+		let new_r = mul33(&read_matx33(r)?, &similarity.r, true);
+		let rt = mul3(&new_r, &similarity.t, false);
+		let old_t = read_vec3(t)?;
+		*t = vec3_mat(&Vec3d::from([
+			similarity.s * old_t[0] - rt[0],
+			similarity.s * old_t[1] - rt[1],
+			similarity.s * old_t[2] - rt[2],
+		]))?;
+		*r = matx33_mat(&new_r)?;
+	}
+	Ok(())
+}
+
+/// Scales the reconstruction so that the distance between the points with indices `point_a` and `point_b` is
+/// `meters`, returns the applied scale factor
+pub fn apply_known_distance(recon: &mut Reconstruction, point_a: usize, point_b: usize, meters: f64) -> Result<f64> {
+	if !(meters.is_finite() && meters > 0.) {
+		return Err(Error::new(core::StsBadArg, format!("Distance must be positive, got: {}", meters)));
+	}
+	let point = |i: usize| recon.points3d.get(i)
+		.ok_or_else(|| Error::new(core::StsOutOfRange, format!("Point index: {} is out of range, reconstruction has {} points", i, recon.points3d.len())))
+		.and_then(read_vec3);
+	let (a, b) = (point(point_a)?, point(point_b)?);
+	let distance = ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)).sqrt();
+	if distance <= f64::EPSILON {
+		return Err(Error::new(core::StsBadArg, format!("Points: {} and {} coincide in the reconstruction", point_a, point_b)));
+	}
+	let scale = meters / distance;
+	apply_similarity(recon, &Similarity { s: scale, ..Similarity::identity() })?;
+	Ok(scale)
+}
+
+/// Result of the [align_to_gps]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GpsAlignment {
+	/// Origin of the East-North-Up frame of the aligned reconstruction, the first prior
+	pub origin: GeoPoint,
+	pub similarity: Similarity,
+	/// RMS distance in meters between the aligned camera centers and their priors
+	pub rms_error: f64,
+}
+
+/// Aligns the reconstruction to the GPS positions of its cameras with the least squares similarity estimated by
+/// [estimate_similarity](core::estimate_similarity)
+///
+/// `priors` are the pairs of the view index and its position, at least 3 non-collinear ones are required. After the
+/// alignment the reconstruction is in meters in the East-North-Up frame at the [origin](GpsAlignment::origin).
+pub fn align_to_gps(recon: &mut Reconstruction, priors: &[(usize, GeoPoint)]) -> Result<GpsAlignment> {
+	if priors.len() < 3 {
+		return Err(Error::new(core::StsBadArg, format!("GPS alignment requires at least 3 priors, got: {}", priors.len())));
+	}
+	let centers = camera_centers(recon)?;
+	let origin = priors[0].1;
+	let pairs = priors.iter()
+		.map(|(view, geo)| {
+			let center = centers.get(*view)
+				.ok_or_else(|| Error::new(core::StsOutOfRange, format!("View index: {} is out of range, reconstruction has {} views", view, centers.len())))?;
+			Ok((vec3_point(center), vec3_point(&geo.to_enu(&origin))))
+		})
+		.collect::<Result<Vec<_>>>()?;
+	let (src, dst): (Vec<_>, Vec<_>) = pairs.into_iter().unzip();
+	let similarity = core::estimate_similarity(&src, &dst, true)?;
+	let rms_error = (src.iter().zip(&dst)
+		.map(|(&src, &dst)| (similarity.apply(src) - dst).norm().powi(2))
+		.sum::<f64>() / src.len() as f64)
+		.sqrt();
+	apply_similarity(recon, &similarity)?;
+	Ok(GpsAlignment { origin, similarity, rms_error })
+}
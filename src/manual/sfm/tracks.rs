@@ -0,0 +1,220 @@
+#[cfg(all(ocvrs_has_module_calib3d, ocvrs_has_module_features2d))]
+use std::collections::HashMap;
+
+#[cfg(all(ocvrs_has_module_calib3d, ocvrs_has_module_features2d))]
+use crate::{
+	calib3d,
+	core::{DMatch, KeyPoint, Ptr, Vector},
+	features2d::{BFMatcher, Feature2D, ORB},
+};
+use crate::{
+	core::{self, Mat},
+	Error,
+	prelude::*,
+	Result,
+};
+
+/// Removes the observations that fall into the zero pixels of the per frame masks, e.g. the moving objects found by
+/// the background subtraction or the segmentation
+///
+/// `tracks` are `2xN` Mats, one per frame, with the negative coordinates for the missing observations, they are
+/// converted to `CV_64F` in place. `masks` are `CV_8UC1`, one per frame, an empty Mat keeps all observations of its
+/// frame. Observations outside of the mask are removed too. Returns the number of the removed observations.
+pub fn mask_tracks(tracks: &mut [Mat], masks: &[Mat]) -> Result<usize> {
+	if tracks.len() != masks.len() {
+		return Err(Error::new(core::StsUnmatchedSizes, format!("Got {} tracks but {} masks", tracks.len(), masks.len())));
+	}
+	let mut removed = 0;
+	for (track, mask) in tracks.iter_mut().zip(masks) {
+		if mask.empty() {
+			continue;
+		}
+		if mask.typ() != core::CV_8UC1 {
+			return Err(Error::new(core::StsUnsupportedFormat, format!("Masks must be CV_8UC1, got type: {}", mask.typ())));
+		}
+		if track.rows() != 2 {
+			return Err(Error::new(core::StsBadSize, format!("Tracks must have 2 rows, got: {}", track.rows())));
+		}
+		let mut track_f64 = Mat::default();
+		track.convert_to(&mut track_f64, core::CV_64F, 1., 0.)?;
+		for i in 0..track_f64.cols() {
+			let (x, y) = (*track_f64.at_2d::<f64>(0, i)?, *track_f64.at_2d::<f64>(1, i)?);
+			if x < 0. || y < 0. {
+				continue;
+			}
+			let (col, row) = (x.round() as i32, y.round() as i32);
+			let keep = row < mask.rows() && col < mask.cols() && *mask.at_2d::<u8>(row, col)? != 0;
+			if !keep {
+				*track_f64.at_2d_mut::<f64>(0, i)? = -1.;
+				*track_f64.at_2d_mut::<f64>(1, i)? = -1.;
+				removed += 1;
+			}
+		}
+		*track = track_f64;
+	}
+	Ok(removed)
+}
+
+/// Builds the tracks for `sfm::reconstruct()` from the images by detecting and matching the features
+///
+/// The features are matched between the images up to `match_window` apart, the matches that pass the ratio test and
+/// the fundamental matrix check are chained into tracks. The features under the zero pixels of the masks are ignored.
+#[cfg(all(ocvrs_has_module_calib3d, ocvrs_has_module_features2d))]
+pub struct TrackBuilder {
+	detector: Ptr<Feature2D>,
+	masks: Vec<Mat>,
+	match_window: usize,
+	ratio: f32,
+	min_track_len: usize,
+}
+
+#[cfg(all(ocvrs_has_module_calib3d, ocvrs_has_module_features2d))]
+impl TrackBuilder {
+	/// Creates the builder with the default ORB detector
+	pub fn new() -> Result<Self> {
+		Ok(Self::with_detector(<dyn ORB>::default()?.into()))
+	}
+
+	pub fn with_detector(detector: Ptr<Feature2D>) -> Self {
+		Self {
+			detector,
+			masks: vec![],
+			match_window: 2,
+			ratio: 0.8,
+			min_track_len: 2,
+		}
+	}
+
+	/// Sets the `CV_8UC1` masks, one per image, features are only detected in their non-zero pixels
+	///
+	/// An empty Mat disables the masking for its image.
+	pub fn with_masks(mut self, masks: Vec<Mat>) -> Self {
+		self.masks = masks;
+		self
+	}
+
+	/// Sets the maximum distance between the matched images, 2 by default
+	pub fn with_match_window(mut self, match_window: usize) -> Self {
+		self.match_window = match_window;
+		self
+	}
+
+	/// Sets the ratio test threshold of the descriptor matching, 0.8 by default
+	pub fn with_ratio(mut self, ratio: f32) -> Self {
+		self.ratio = ratio;
+		self
+	}
+
+	/// Sets the minimum number of the observations of a track, 2 by default
+	pub fn with_min_track_len(mut self, min_track_len: usize) -> Self {
+		self.min_track_len = min_track_len;
+		self
+	}
+
+	/// Returns the tracks, one `2xN` `CV_64F` Mat per image with `-1` for the missing observations
+	///
+	/// Tracks that reach the same image twice are inconsistent and dropped.
+	pub fn build(&mut self, images: &[Mat]) -> Result<Vec<Mat>> {
+		if !self.masks.is_empty() && self.masks.len() != images.len() {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("Got {} images but {} masks", images.len(), self.masks.len())));
+		}
+		let no_mask = Mat::default();
+		let mut features = Vec::with_capacity(images.len());
+		for (i, image) in images.iter().enumerate() {
+			let mut keypoints = Vector::<KeyPoint>::new();
+			let mut descriptors = Mat::default();
+			self.detector.detect_and_compute(image, self.masks.get(i).unwrap_or(&no_mask), &mut keypoints, &mut descriptors, false)?;
+			features.push((keypoints.to_vec(), descriptors));
+		}
+
+		// union-find over all keypoints of all images
+		let offsets = features.iter()
+			.scan(0, |offset, (keypoints, _)| {
+				let out = *offset;
+				*offset += keypoints.len();
+				Some(out)
+			})
+			.collect::<Vec<_>>();
+		let mut parent = (0..features.iter().map(|(k, _)| k.len()).sum()).collect::<Vec<usize>>();
+		fn find(parent: &mut [usize], mut node: usize) -> usize {
+			while parent[node] != node {
+				parent[node] = parent[parent[node]];
+				node = parent[node];
+			}
+			node
+		}
+		for first in 0..features.len() {
+			for second in first + 1..features.len().min(first + self.match_window + 1) {
+				for (a, b) in self.match_pair(&features[first], &features[second])? {
+					let (a, b) = (find(&mut parent, offsets[first] + a), find(&mut parent, offsets[second] + b));
+					parent[a.max(b)] = a.min(b);
+				}
+			}
+		}
+
+		let mut components = HashMap::<usize, Vec<(usize, usize)>>::new();
+		let mut order = vec![];
+		for (image, (keypoints, _)) in features.iter().enumerate() {
+			for keypoint in 0..keypoints.len() {
+				let root = find(&mut parent, offsets[image] + keypoint);
+				components.entry(root)
+					.or_insert_with(|| {
+						order.push(root);
+						vec![]
+					})
+					.push((image, keypoint));
+			}
+		}
+		let tracks = order.iter()
+			.map(|root| &components[root])
+			.filter(|observations| observations.len() >= self.min_track_len.max(2) && observations.windows(2).all(|w| w[0].0 != w[1].0))
+			.collect::<Vec<_>>();
+		if tracks.is_empty() {
+			return Err(Error::new(core::StsError, "No tracks could be built from the images"));
+		}
+		features.iter()
+			.enumerate()
+			.map(|(image, (keypoints, _))| {
+				let (xs, ys): (Vec<_>, Vec<_>) = tracks.iter()
+					.map(|observations| {
+						observations.iter()
+							.find(|o| o.0 == image)
+							.map_or((-1., -1.), |&(_, k)| (f64::from(keypoints[k].pt.x), f64::from(keypoints[k].pt.y)))
+					})
+					.unzip();
+				Mat::from_slice_2d(&[xs, ys])
+			})
+			.collect()
+	}
+
+	/// Returns the keypoint index pairs that pass the ratio test and are the inliers of the fundamental matrix
+	fn match_pair(&self, (keypoints1, descriptors1): &(Vec<KeyPoint>, Mat), (keypoints2, descriptors2): &(Vec<KeyPoint>, Mat)) -> Result<Vec<(usize, usize)>> {
+		if descriptors1.rows() < 8 || descriptors2.rows() < 8 {
+			return Ok(vec![]);
+		}
+		let norm = if descriptors1.depth() == core::CV_8U { core::NORM_HAMMING } else { core::NORM_L2 };
+		let mut knn = Vector::<Vector<DMatch>>::new();
+		BFMatcher::new(norm, false)?.knn_train_match(descriptors1, descriptors2, &mut knn, 2, &core::no_array(), false)?;
+		let matches = knn.iter()
+			.filter_map(|m| {
+				let best = m.get(0).ok()?;
+				match m.get(1) {
+					Ok(second) if best.distance >= self.ratio * second.distance => None,
+					_ => Some((best.query_idx as usize, best.train_idx as usize)),
+				}
+			})
+			.collect::<Vec<_>>();
+		if matches.len() < 8 {
+			return Ok(vec![]);
+		}
+		let points1 = Mat::from_exact_iter(matches.iter().map(|&(a, _)| keypoints1[a].pt))?;
+		let points2 = Mat::from_exact_iter(matches.iter().map(|&(_, b)| keypoints2[b].pt))?;
+		let mut mask = Mat::default();
+		let f = calib3d::find_fundamental_mat(&points1, &points2, calib3d::FM_RANSAC, 3., 0.99, 1000, &mut mask)?;
+		if f.empty() {
+			return Ok(vec![]);
+		}
+		let mask = mask.data_typed::<u8>()?;
+		Ok(matches.into_iter().zip(mask).filter(|(_, &inlier)| inlier != 0).map(|(m, _)| m).collect())
+	}
+}
@@ -0,0 +1,109 @@
+//! Relative pose and structure from two calibrated views in a single call
+//!
+//! ```no_run
+//! # use opencv::{core::{Mat, Point2d}, sfm::two_view};
+//! # fn run(x1: &[Point2d], x2: &[Point2d], k: &Mat) -> opencv::Result<()> {
+//! let out = two_view::reconstruct(x1, x2, k)?;
+//! let inliers = out.points3d.iter().zip(&out.inliers).filter(|(_, &inlier)| inlier).count();
+//! println!("{} of {} points reconstructed", inliers, x1.len());
+//! # Ok(()) }
+//! ```
+
+use crate::{
+	calib3d,
+	core::{self, Mat, Point2d, Point3d, Vector},
+	Error,
+	prelude::*,
+	Result,
+	sfm,
+};
+
+/// Result of the [reconstruct]
+#[derive(Debug)]
+pub struct TwoViewResult {
+	/// `3x3` essential matrix
+	pub e: Mat,
+	/// `3x3` rotation of the second camera, the first one is `[I|0]`
+	pub r: Mat,
+	/// `3x1` unit translation of the second camera
+	pub t: Mat,
+	/// Triangulated point of each correspondence in the first camera coordinates, the scale is set by `|t| = 1`
+	pub points3d: Vec<Point3d>,
+	/// Whether the correspondence is consistent with `e` and its point is in front of both cameras
+	pub inliers: Vec<bool>,
+}
+
+/// Estimates the relative pose of the second camera and triangulates the correspondences
+///
+/// Chains the RANSAC essential matrix estimation, the decomposition into the 4 candidate motions and the triangulation.
+/// The motion is chosen by the cheirality of all essential matrix inliers, which is more robust than deciding by a
+/// single point. Both views share the camera matrix `k`, at least 5 correspondences are required.
+pub fn reconstruct(x1: &[Point2d], x2: &[Point2d], k: &Mat) -> Result<TwoViewResult> {
+	if x1.len() != x2.len() {
+		return Err(Error::new(core::StsUnmatchedSizes, format!("Got {} points in the first view but {} in the second", x1.len(), x2.len())));
+	}
+	if x1.len() < 5 {
+		return Err(Error::new(core::StsBadArg, format!("Two-view reconstruction requires at least 5 correspondences, got: {}", x1.len())));
+	}
+	let mut k_f64 = Mat::default();
+	k.convert_to(&mut k_f64, core::CV_64F, 1., 0.)?;
+	let points1 = Mat::from_exact_iter(x1.iter().copied())?;
+	let points2 = Mat::from_exact_iter(x2.iter().copied())?;
+	let mut mask = Mat::default();
+	let e = calib3d::find_essential_mat(&points1, &points2, &k_f64, calib3d::RANSAC, 0.999, 1., 1000, &mut mask)?;
+	if e.rows() < 3 {
+		return Err(Error::new(core::StsError, "Essential matrix can't be estimated from the correspondences"));
+	}
+	// several solutions can be stacked, the first one is used
+	let e = Mat::roi(&e, core::Rect::new(0, 0, 3, 3))?.try_clone()?;
+	let essential_inliers = mask.data_typed::<u8>()?.iter().map(|&m| m != 0).collect::<Vec<_>>();
+
+	let mut rs = Vector::<Mat>::new();
+	let mut ts = Vector::<Mat>::new();
+	sfm::motion_from_essential(&e, &mut rs, &mut ts)?;
+	let tracks = Vector::<Mat>::from_iter(vec![to_track(x1)?, to_track(x2)?]);
+	let mut p1 = Mat::default();
+	sfm::projection_from_k_rt(&k_f64, &Mat::eye(3, 3, core::CV_64F)?.to_mat()?, &Mat::zeros(3, 1, core::CV_64F)?.to_mat()?, &mut p1)?;
+
+	let mut best: Option<(usize, TwoViewResult)> = None;
+	for (r, t) in rs.iter().zip(ts.iter()) {
+		let mut p2 = Mat::default();
+		sfm::projection_from_k_rt(&k_f64, &r, &t, &mut p2)?;
+		let mut points3d = Mat::default();
+		sfm::triangulate_points(&tracks, &Vector::<Mat>::from_iter(vec![p1.clone(), p2]), &mut points3d)?;
+		let (r_values, t_values) = (values(&r)?, values(&t)?);
+		let points = (0..points3d.cols())
+			.map(|i| -> Result<Point3d> {
+				Ok(Point3d::new(*points3d.at_2d::<f64>(0, i)?, *points3d.at_2d::<f64>(1, i)?, *points3d.at_2d::<f64>(2, i)?))
+			})
+			.collect::<Result<Vec<_>>>()?;
+		let inliers = points.iter()
+			.zip(&essential_inliers)
+			.map(|(p, &inlier)| {
+				let depth2 = r_values[6] * p.x + r_values[7] * p.y + r_values[8] * p.z + t_values[2];
+				inlier && p.z > 0. && depth2 > 0.
+			})
+			.collect::<Vec<_>>();
+		let count = inliers.iter().filter(|&&inlier| inlier).count();
+		if best.as_ref().map(|b| count > b.0).unwrap_or(true) {
+			best = Some((count, TwoViewResult { e: e.clone(), r, t, points3d: points, inliers }));
+		}
+	}
+	match best {
+		Some((count, out)) if count > 0 => Ok(out),
+		_ => Err(Error::new(core::StsError, "No motion puts the points in front of both cameras")),
+	}
+}
+
+/// Converts the points to the `2xN` `CV_64F` track
+fn to_track(points: &[Point2d]) -> Result<Mat> {
+	let (xs, ys): (Vec<_>, Vec<_>) = points.iter().map(|p| (p.x, p.y)).unzip();
+	Mat::from_slice_2d(&[xs, ys])
+}
+
+fn values(mat: &Mat) -> Result<Vec<f64>> {
+	let mut out = Mat::default();
+	mat.convert_to(&mut out, core::CV_64F, 1., 0.)?;
+	let out = if out.is_continuous() { out } else { out.try_clone()? };
+	Ok(out.data_typed::<f64>()?.to_vec())
+}
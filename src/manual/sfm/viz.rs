@@ -0,0 +1,179 @@
+//! Drawing of the two-view geometry and the camera poses for debugging the reconstructions
+//!
+//! The matrices are accepted as `CV_32F` or `CV_64F` Mats in the layouts returned by `calib3d::find_fundamental_mat()`
+//! and `BaseSFM::get_cameras()`.
+
+use crate::{
+	core::{self, Mat, Point, Point2d, Scalar, Size},
+	Error,
+	imgproc,
+	prelude::*,
+	Result,
+};
+
+/// Colors of the consecutive matches and cameras, BGR
+const PALETTE: [(f64, f64, f64); 8] = [
+	(48., 48., 255.),
+	(48., 200., 48.),
+	(255., 128., 32.),
+	(0., 200., 255.),
+	(255., 64., 255.),
+	(255., 255., 0.),
+	(128., 128., 255.),
+	(0., 128., 255.),
+];
+
+fn palette_color(i: usize) -> Scalar {
+	let (b, g, r) = PALETTE[i % PALETTE.len()];
+	Scalar::new(b, g, r, 0.)
+}
+
+/// Reads the `rows`x`cols` matrix of any floating point depth into the row-major `f64` values
+fn read_matrix(mat: &Mat, rows: i32, cols: i32, name: &str) -> Result<Vec<f64>> {
+	if mat.rows() != rows || mat.cols() != cols || mat.channels() != 1 {
+		return Err(Error::new(core::StsBadSize, format!("{} must be a {}x{} single channel matrix, got: {}x{}", name, rows, cols, mat.rows(), mat.cols())));
+	}
+	let mut mat_f64 = Mat::default();
+	mat.convert_to(&mut mat_f64, core::CV_64F, 1., 0.)?;
+	(0..rows)
+		.flat_map(|r| (0..cols).map(move |c| (r, c)))
+		.map(|(r, c)| -> Result<f64> { Ok(*mat_f64.at_2d::<f64>(r, c)?) })
+		.collect()
+}
+
+fn to_bgr(image: &Mat) -> Result<Mat> {
+	if image.depth() != core::CV_8U {
+		return Err(Error::new(core::StsUnsupportedFormat, format!("Only CV_8U images can be annotated, got type: {}", image.typ())));
+	}
+	let code = match image.channels() {
+		1 => imgproc::COLOR_GRAY2BGR,
+		3 => return image.try_clone(),
+		4 => imgproc::COLOR_BGRA2BGR,
+		channels => return Err(Error::new(core::StsUnsupportedFormat, format!("Images with {} channels can't be annotated", channels))),
+	};
+	let mut out = Mat::default();
+	imgproc::cvt_color(image, &mut out, code, 0)?;
+	Ok(out)
+}
+
+#[inline]
+fn to_point(x: f64, y: f64) -> Point {
+	Point::new(x.round() as i32, y.round() as i32)
+}
+
+/// Draws the line `a * x + b * y + c = 0` across the whole image
+fn draw_line(image: &mut Mat, line: [f64; 3], color: Scalar) -> Result<()> {
+	let [a, b, c] = line;
+	let (width, height) = (f64::from(image.cols()), f64::from(image.rows()));
+	let (from, to) = if b.abs() >= a.abs() {
+		if b == 0. {
+			return Ok(());
+		}
+		(to_point(0., -c / b), to_point(width, -(c + a * width) / b))
+	} else {
+		(to_point(-c / a, 0.), to_point(-(c + b * height) / a, height))
+	};
+	imgproc::line(image, from, to, color, 1, imgproc::LINE_AA, 0)
+}
+
+/// Returns `img1` and `img2` side by side with the matched points and their epipolar lines
+///
+/// `f` is the fundamental matrix with `x2ᵀ * F * x1 = 0` for the matched points. The epipolar line of each point is
+/// drawn in the other image in the color of the match, the points of a correct `F` lie on the lines of their matches.
+pub fn draw_epipolar_lines(img1: &Mat, img2: &Mat, f: &Mat, matches: &[(Point2d, Point2d)]) -> Result<Mat> {
+	let f = read_matrix(f, 3, 3, "Fundamental matrix")?;
+	let mut left = to_bgr(img1)?;
+	let mut right = to_bgr(img2)?;
+	for (i, &(p1, p2)) in matches.iter().enumerate() {
+		let color = palette_color(i);
+		// l2 = F * x1, l1 = Fᵀ * x2
+		let l2 = |r: usize| f[r * 3] * p1.x + f[r * 3 + 1] * p1.y + f[r * 3 + 2];
+		let l1 = |c: usize| f[c] * p2.x + f[3 + c] * p2.y + f[6 + c];
+		draw_line(&mut left, [l1(0), l1(1), l1(2)], color)?;
+		draw_line(&mut right, [l2(0), l2(1), l2(2)], color)?;
+		imgproc::circle(&mut left, to_point(p1.x, p1.y), 4, color, -1, imgproc::LINE_AA, 0)?;
+		imgproc::circle(&mut right, to_point(p2.x, p2.y), 4, color, -1, imgproc::LINE_AA, 0)?;
+	}
+	let height = left.rows().max(right.rows());
+	let mut left_padded = Mat::default();
+	core::copy_make_border(&left, &mut left_padded, 0, height - left.rows(), 0, 0, core::BORDER_CONSTANT, Scalar::all(0.))?;
+	let mut right_padded = Mat::default();
+	core::copy_make_border(&right, &mut right_padded, 0, height - right.rows(), 0, 0, core::BORDER_CONSTANT, Scalar::all(0.))?;
+	let mut out = Mat::default();
+	core::hconcat2(&left_padded, &right_padded, &mut out)?;
+	Ok(out)
+}
+
+/// Draws the top-down view (X to the right, Z up) of the camera frustums fitted into the `canvas`
+///
+/// `rs` and `ts` are the world to camera rotations and translations, `ks` has either one camera matrix per camera or
+/// a single shared one. The image size is assumed to be twice the principal point and the frustum depth is a fifth of
+/// the spread of the camera centers. Each camera is labeled with its index.
+pub fn draw_camera_frustums(canvas: &mut Mat, ks: &[Mat], rs: &[Mat], ts: &[Mat]) -> Result<()> {
+	if rs.len() != ts.len() || (ks.len() != 1 && ks.len() != rs.len()) {
+		return Err(Error::new(core::StsUnmatchedSizes, format!("Expected the same number of rotations and translations and one or the same number of camera matrices, got: {} {} {}", rs.len(), ts.len(), ks.len())));
+	}
+	if canvas.empty() || canvas.typ() != core::CV_8UC3 {
+		return Err(Error::new(core::StsUnsupportedFormat, format!("Canvas must be non-empty CV_8UC3, got type: {}", canvas.typ())));
+	}
+	if rs.is_empty() {
+		return Ok(());
+	}
+	let cameras = rs.iter()
+		.zip(ts)
+		.map(|(r, t)| {
+			let r = read_matrix(r, 3, 3, "Rotation")?;
+			let t = read_matrix(t, 3, 1, "Translation")?;
+			// camera center -Rᵀ * t and the camera axes as the rows of R
+			let center = |c: usize| -(r[c] * t[0] + r[3 + c] * t[1] + r[6 + c] * t[2]);
+			let center = [center(0), center(1), center(2)];
+			Ok((center, r))
+		})
+		.collect::<Result<Vec<_>>>()?;
+
+	let (min, max) = cameras.iter().fold(([f64::INFINITY; 2], [f64::NEG_INFINITY; 2]), |(min, max), (c, _)| {
+		([min[0].min(c[0]), min[1].min(c[2])], [max[0].max(c[0]), max[1].max(c[2])])
+	});
+	let spread = (max[0] - min[0]).hypot(max[1] - min[1]);
+	let depth = if spread > 0. { spread / 5. } else { 1. };
+
+	// frustum corners on the image plane at the depth in the world coordinates
+	let frustums = cameras.iter()
+		.enumerate()
+		.map(|(i, (center, r))| {
+			let k = read_matrix(&ks[if ks.len() == 1 { 0 } else { i }], 3, 3, "Camera matrix")?;
+			let (fx, fy, cx, cy) = (k[0], k[4], k[2], k[5]);
+			let corners = [(0., 0.), (2. * cx, 0.), (2. * cx, 2. * cy), (0., 2. * cy)].iter()
+				.map(|&(u, v)| {
+					let ray = [(u - cx) / fx * depth, (v - cy) / fy * depth, depth];
+					let corner = |c: usize| center[c] + r[c] * ray[0] + r[3 + c] * ray[1] + r[6 + c] * ray[2];
+					[corner(0), corner(1), corner(2)]
+				})
+				.collect::<Vec<_>>();
+			Ok((*center, corners))
+		})
+		.collect::<Result<Vec<_>>>()?;
+
+	let (min, max) = frustums.iter()
+		.flat_map(|(center, corners)| Some(center).into_iter().chain(corners))
+		.fold(([f64::INFINITY; 2], [f64::NEG_INFINITY; 2]), |(min, max), p| {
+			([min[0].min(p[0]), min[1].min(p[2])], [max[0].max(p[0]), max[1].max(p[2])])
+		});
+	let Size { width, height } = canvas.size()?;
+	let margin = 20.;
+	let scale = ((f64::from(width) - 2. * margin) / (max[0] - min[0]).max(f64::EPSILON))
+		.min((f64::from(height) - 2. * margin) / (max[1] - min[1]).max(f64::EPSILON));
+	let project = |p: &[f64; 3]| to_point(margin + (p[0] - min[0]) * scale, f64::from(height) - margin - (p[2] - min[1]) * scale);
+
+	for (i, (center, corners)) in frustums.iter().enumerate() {
+		let color = palette_color(i);
+		let apex = project(center);
+		for (j, corner) in corners.iter().enumerate() {
+			imgproc::line(canvas, apex, project(corner), color, 1, imgproc::LINE_AA, 0)?;
+			imgproc::line(canvas, project(corner), project(&corners[(j + 1) % corners.len()]), color, 1, imgproc::LINE_AA, 0)?;
+		}
+		imgproc::circle(canvas, apex, 3, color, -1, imgproc::LINE_AA, 0)?;
+		imgproc::put_text(canvas, &i.to_string(), apex + Point::new(5, -5), imgproc::FONT_HERSHEY_SIMPLEX, 0.4, color, 1, imgproc::LINE_AA, false)?;
+	}
+	Ok(())
+}
@@ -0,0 +1,384 @@
+//! Pure Rust replacement for `highgui::imshow()` for the OpenCV builds without highgui
+//!
+//! Enabled with the `show` feature, the windows are created with `winit` and drawn with `softbuffer`:
+//! ```ignore
+//! let window = show::Window::new("preview")?;
+//! while window.is_open() {
+//!     cap.read(&mut frame)?;
+//!     window.set_overlay(&[format!("frame {}", index)]);
+//!     window.show(&frame)?;
+//!     if let Some(show::Key::Escape) = show::wait_key(Some(Duration::from_millis(1)))? {
+//!         break;
+//!     }
+//! }
+//! ```
+//! Like with highgui the windows are only updated while [wait_key] or [poll_events] is being called. All calls must be
+//! made from the main thread on every platform, `winit` refuses to create the event loop on any other thread on Linux,
+//! Windows and macOS alike. The calls from the other threads fail with `StsError` instead of creating the windows.
+
+use std::{
+	cell::RefCell,
+	collections::{HashMap, VecDeque},
+	num::NonZeroU32,
+	panic,
+	rc::Rc,
+	time::{Duration, Instant},
+};
+
+use softbuffer::{Context, Surface};
+use winit::{
+	application::ApplicationHandler,
+	dpi::PhysicalSize,
+	event::{ElementState, WindowEvent},
+	event_loop::{ActiveEventLoop, EventLoop},
+	keyboard::{Key as WinitKey, NamedKey},
+	platform::pump_events::{EventLoopExtPumpEvents, PumpStatus},
+	window::{Window as WinitWindow, WindowId},
+};
+
+use crate::{
+	core::{self, Mat, Point, Scalar, Vec4b},
+	Error,
+	imgproc,
+	prelude::*,
+	Result,
+};
+
+/// Key pressed in one of the windows
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Key {
+	Char(char),
+	Escape,
+	Enter,
+	Space,
+	Backspace,
+	Tab,
+	Left,
+	Right,
+	Up,
+	Down,
+	/// Any other non-character key
+	Other,
+}
+
+/// Input event received from a window
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Event {
+	Key { window: String, key: Key },
+	/// Window was closed by the user, [Window::is_open] returns `false` from now on
+	Closed { window: String },
+}
+
+/// Top-level window displaying a Mat, see the [module documentation](self)
+///
+/// The window is closed when the value is dropped.
+#[derive(Debug)]
+pub struct Window {
+	title: String,
+}
+
+impl Window {
+	/// Opens the window with the `title`, the title must be unique among the open windows
+	pub fn new(title: impl Into<String>) -> Result<Self> {
+		let title = title.into();
+		with_display(|display| {
+			if display.app.windows.contains_key(&title) {
+				return Err(Error::new(core::StsBadArg, format!("Window already exists: {}", title)));
+			}
+			display.app.windows.insert(title.clone(), WindowState::default());
+			Ok(())
+		})?;
+		poll_events()?;
+		Ok(Self { title })
+	}
+
+	#[inline]
+	pub fn title(&self) -> &str {
+		&self.title
+	}
+
+	/// Returns `false` after the user has closed the window
+	pub fn is_open(&self) -> bool {
+		with_display(|display| Ok(display.app.windows.get(&self.title).is_some_and(|state| !state.closed))).unwrap_or(false)
+	}
+
+	/// Displays the Mat, the window is resized to the Mat size on the first call
+	///
+	/// Same as with `imshow()` the 16-bit images are divided by 256 and the floating point images are multiplied by
+	/// 255. 1, 3 (BGR) and 4 (BGRA) channel images are supported.
+	pub fn show(&self, mat: &impl MatTraitConst) -> Result<()> {
+		let overlay = with_display(|display| Ok(display.app.windows.get(&self.title).map(|state| state.overlay.clone()).unwrap_or_default()))?;
+		let frame = to_frame(mat, &overlay)?;
+		with_display(|display| {
+			if let Some(state) = display.app.windows.get_mut(&self.title) {
+				if let Some(view) = &state.view {
+					if state.frame.is_none() {
+						let _ = view.window.request_inner_size(PhysicalSize::new(frame.width, frame.height));
+					}
+					view.window.request_redraw();
+				}
+				state.frame = Some(frame);
+			}
+			Ok(())
+		})?;
+		poll_events()?;
+		Ok(())
+	}
+
+	/// Sets the text lines drawn over the top-left corner of every subsequently shown Mat, empty to remove
+	pub fn set_overlay(&self, lines: &[impl AsRef<str>]) {
+		let lines = lines.iter().map(|line| line.as_ref().to_string()).collect();
+		let _ = with_display(|display| {
+			if let Some(state) = display.app.windows.get_mut(&self.title) {
+				state.overlay = lines;
+			}
+			Ok(())
+		});
+	}
+}
+
+impl Drop for Window {
+	fn drop(&mut self) {
+		let _ = with_display(|display| {
+			if let Some(state) = display.app.windows.remove(&self.title) {
+				if let Some(view) = state.view {
+					display.app.ids.remove(&view.window.id());
+				}
+			}
+			Ok(())
+		});
+	}
+}
+
+/// Processes the window events and returns the ones received since the last call
+pub fn poll_events() -> Result<Vec<Event>> {
+	with_display(|display| {
+		display.pump(Some(Duration::from_secs(0)));
+		Ok(display.app.events.drain(..).collect())
+	})
+}
+
+/// Processes the window events until a key is pressed or the `timeout` expires, `None` waits indefinitely
+///
+/// Returns `None` on timeout or when there are no open windows left. Non-key events are discarded, use [poll_events] if
+/// they are needed.
+pub fn wait_key(timeout: Option<Duration>) -> Result<Option<Key>> {
+	let deadline = timeout.map(|timeout| Instant::now() + timeout);
+	with_display(|display| loop {
+		let remaining = deadline.map(|deadline| deadline.saturating_duration_since(Instant::now()));
+		let exited = display.pump(remaining);
+		while let Some(event) = display.app.events.pop_front() {
+			if let Event::Key { key, .. } = event {
+				return Ok(Some(key));
+			}
+		}
+		let any_open = display.app.windows.values().any(|state| !state.closed);
+		if exited || !any_open || remaining == Some(Duration::from_secs(0)) {
+			return Ok(None);
+		}
+	})
+}
+
+thread_local! {
+	static DISPLAY: RefCell<Option<Display>> = const { RefCell::new(None) };
+}
+
+fn with_display<T>(f: impl FnOnce(&mut Display) -> Result<T>) -> Result<T> {
+	DISPLAY.with(|display| {
+		let mut display = display.try_borrow_mut()
+			.map_err(|_| Error::new(core::StsError, "Window functions can't be called recursively"))?;
+		if display.is_none() {
+			// winit panics when the event loop is created outside the main thread
+			let event_loop = panic::catch_unwind(EventLoop::new)
+				.map_err(|_| Error::new(core::StsError, "Window functions must be called from the main thread"))?
+				.map_err(|e| Error::new(core::StsError, format!("Can't create the window event loop: {}", e)))?;
+			*display = Some(Display { event_loop, app: App::default() });
+		}
+		match display.as_mut() {
+			Some(display) => f(display),
+			None => unreachable!(),
+		}
+	})
+}
+
+struct Display {
+	event_loop: EventLoop<()>,
+	app: App,
+}
+
+impl Display {
+	/// Returns `true` if the event loop has exited
+	fn pump(&mut self, timeout: Option<Duration>) -> bool {
+		matches!(self.event_loop.pump_app_events(timeout, &mut self.app), PumpStatus::Exit(_))
+	}
+}
+
+/// Converted frame in the `0RGB` format expected by `softbuffer`
+struct Frame {
+	width: u32,
+	height: u32,
+	pixels: Vec<u32>,
+}
+
+struct View {
+	window: Rc<WinitWindow>,
+	surface: Surface<Rc<WinitWindow>, Rc<WinitWindow>>,
+}
+
+#[derive(Default)]
+struct WindowState {
+	/// `None` until the window is created by the event loop
+	view: Option<View>,
+	frame: Option<Frame>,
+	overlay: Vec<String>,
+	closed: bool,
+}
+
+#[derive(Default)]
+struct App {
+	windows: HashMap<String, WindowState>,
+	ids: HashMap<WindowId, String>,
+	events: VecDeque<Event>,
+}
+
+impl App {
+	fn create_pending(&mut self, event_loop: &ActiveEventLoop) {
+		for (title, state) in &mut self.windows {
+			if state.view.is_some() || state.closed {
+				continue;
+			}
+			let mut attributes = WinitWindow::default_attributes().with_title(title.as_str());
+			if let Some(frame) = &state.frame {
+				attributes = attributes.with_inner_size(PhysicalSize::new(frame.width, frame.height));
+			}
+			let window = match event_loop.create_window(attributes) {
+				Ok(window) => Rc::new(window),
+				Err(_) => continue,
+			};
+			let surface = match Context::new(Rc::clone(&window)).and_then(|context| Surface::new(&context, Rc::clone(&window))) {
+				Ok(surface) => surface,
+				Err(_) => continue,
+			};
+			self.ids.insert(window.id(), title.clone());
+			window.request_redraw();
+			state.view = Some(View { window, surface });
+		}
+	}
+
+	fn redraw(state: &mut WindowState) {
+		let (view, frame) = match (&mut state.view, &state.frame) {
+			(Some(view), Some(frame)) => (view, frame),
+			_ => return,
+		};
+		let size = view.window.inner_size();
+		let (width, height) = match (NonZeroU32::new(size.width), NonZeroU32::new(size.height)) {
+			(Some(width), Some(height)) => (width, height),
+			_ => return,
+		};
+		if view.surface.resize(width, height).is_err() {
+			return;
+		}
+		let mut buffer = match view.surface.buffer_mut() {
+			Ok(buffer) => buffer,
+			Err(_) => return,
+		};
+		// nearest neighbor scaling to the current window size
+		let (dst_w, dst_h) = (size.width as usize, size.height as usize);
+		let (src_w, src_h) = (frame.width as usize, frame.height as usize);
+		for (y, dst_row) in buffer.chunks_exact_mut(dst_w).enumerate().take(dst_h) {
+			let src_row = &frame.pixels[y * src_h / dst_h * src_w..][..src_w];
+			for (x, pixel) in dst_row.iter_mut().enumerate() {
+				*pixel = src_row[x * src_w / dst_w];
+			}
+		}
+		let _ = buffer.present();
+	}
+}
+
+impl ApplicationHandler for App {
+	fn resumed(&mut self, event_loop: &ActiveEventLoop) {
+		self.create_pending(event_loop);
+	}
+
+	fn window_event(&mut self, _event_loop: &ActiveEventLoop, window_id: WindowId, event: WindowEvent) {
+		let title = match self.ids.get(&window_id) {
+			Some(title) => title.clone(),
+			None => return,
+		};
+		match event {
+			WindowEvent::CloseRequested => {
+				self.ids.remove(&window_id);
+				if let Some(state) = self.windows.get_mut(&title) {
+					state.view = None;
+					state.closed = true;
+				}
+				self.events.push_back(Event::Closed { window: title });
+			}
+			WindowEvent::RedrawRequested => {
+				if let Some(state) = self.windows.get_mut(&title) {
+					Self::redraw(state);
+				}
+			}
+			WindowEvent::KeyboardInput { event, .. } if event.state == ElementState::Pressed => {
+				let key = match event.logical_key {
+					WinitKey::Named(NamedKey::Escape) => Key::Escape,
+					WinitKey::Named(NamedKey::Enter) => Key::Enter,
+					WinitKey::Named(NamedKey::Space) => Key::Space,
+					WinitKey::Named(NamedKey::Backspace) => Key::Backspace,
+					WinitKey::Named(NamedKey::Tab) => Key::Tab,
+					WinitKey::Named(NamedKey::ArrowLeft) => Key::Left,
+					WinitKey::Named(NamedKey::ArrowRight) => Key::Right,
+					WinitKey::Named(NamedKey::ArrowUp) => Key::Up,
+					WinitKey::Named(NamedKey::ArrowDown) => Key::Down,
+					WinitKey::Character(chars) => chars.chars().next().map_or(Key::Other, Key::Char),
+					_ => Key::Other,
+				};
+				self.events.push_back(Event::Key { window: title, key });
+			}
+			_ => {}
+		}
+	}
+
+	fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
+		self.create_pending(event_loop);
+	}
+}
+
+/// Converts the Mat into the `0RGB` pixels drawing the `overlay` text lines over it
+fn to_frame(mat: &impl MatTraitConst, overlay: &[String]) -> Result<Frame> {
+	let size = mat.size()?;
+	if size.width <= 0 || size.height <= 0 {
+		return Err(Error::new(core::StsBadArg, "Can't show empty Mat"));
+	}
+	let scale = match mat.depth() {
+		core::CV_8U => 1.,
+		core::CV_16U => 1. / 256.,
+		core::CV_32F | core::CV_64F => 255.,
+		_ => 1.,
+	};
+	let mut mat_8u = Mat::default();
+	mat.convert_to(&mut mat_8u, core::CV_8U, scale, 0.)?;
+	let code = match mat_8u.channels() {
+		1 => imgproc::COLOR_GRAY2BGRA,
+		3 => imgproc::COLOR_BGR2BGRA,
+		4 => -1,
+		channels => return Err(Error::new(core::StsBadArg, format!("Can't show Mat with {} channels", channels))),
+	};
+	let mut bgra = if code == -1 {
+		mat_8u
+	} else {
+		let mut bgra = Mat::default();
+		imgproc::cvt_color(&mat_8u, &mut bgra, code, 0)?;
+		bgra
+	};
+	for (i, line) in overlay.iter().enumerate() {
+		let org = Point::new(8, 22 + 24 * i as i32);
+		imgproc::put_text(&mut bgra, line, org, imgproc::FONT_HERSHEY_SIMPLEX, 0.6, Scalar::new(0., 0., 0., 255.), 3, imgproc::LINE_AA, false)?;
+		imgproc::put_text(&mut bgra, line, org, imgproc::FONT_HERSHEY_SIMPLEX, 0.6, Scalar::all(255.), 1, imgproc::LINE_AA, false)?;
+	}
+	let mut pixels = Vec::with_capacity(size.area() as usize);
+	for row in 0..size.height {
+		pixels.extend(bgra.at_row::<Vec4b>(row)?.iter().map(|p| u32::from(p[2]) << 16 | u32::from(p[1]) << 8 | u32::from(p[0])));
+	}
+	Ok(Frame { width: size.width as u32, height: size.height as u32, pixels })
+}
@@ -0,0 +1,22 @@
+//! Version-stable facade over the generated bindings
+//!
+//! The shape of the generated modules follows the OpenCV headers, so the same call can take an `i32` constant with
+//! OpenCV 3.2/3.4 and a typed enum with OpenCV 4.x, or live in a different module (e.g. `LINE_8` is in `core` in 3.x and
+//! in `imgproc` in 4.x). The items in this module keep the same signatures for every supported OpenCV branch and
+//! delegate to the versioned bindings internally, use them in the code that must compile against several OpenCV
+//! versions without `cfg` switches.
+//!
+//! The facade only covers the entry points that change shape between the branches: the enums that replaced the `i32`
+//! constants (access flags, feature detector types, line types, fonts) and the constructors with the different
+//! arguments (`VideoCapture`). The calls that have the same signature in every branch, like `imgproc::cvt_color()`,
+//! `imgproc::resize()` or `VideoCapture::read()`, are used from the versioned modules directly, they are not duplicated
+//! here.
+
+#[cfg(ocvrs_has_module_core)]
+pub mod core;
+#[cfg(ocvrs_has_module_features2d)]
+pub mod features2d;
+#[cfg(ocvrs_has_module_imgproc)]
+pub mod imgproc;
+#[cfg(ocvrs_has_module_videoio)]
+pub mod videoio;
@@ -0,0 +1,48 @@
+use crate::{
+	core::{self, Mat, UMat, UMatUsageFlags},
+	prelude::*,
+	Result,
+};
+
+/// Access flags for the data of `UMat`, `core::AccessFlag` in OpenCV 4.x and `ACCESS_*` constants before that
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AccessFlag {
+	Read,
+	Write,
+	ReadWrite,
+	Fast,
+}
+
+impl AccessFlag {
+	#[cfg(ocvrs_opencv_branch_4)]
+	fn versioned(self) -> core::AccessFlag {
+		match self {
+			AccessFlag::Read => core::AccessFlag::ACCESS_READ,
+			AccessFlag::Write => core::AccessFlag::ACCESS_WRITE,
+			AccessFlag::ReadWrite => core::AccessFlag::ACCESS_RW,
+			AccessFlag::Fast => core::AccessFlag::ACCESS_FAST,
+		}
+	}
+
+	#[cfg(not(ocvrs_opencv_branch_4))]
+	fn versioned(self) -> i32 {
+		match self {
+			AccessFlag::Read => core::ACCESS_READ,
+			AccessFlag::Write => core::ACCESS_WRITE,
+			AccessFlag::ReadWrite => core::ACCESS_RW,
+			AccessFlag::Fast => core::ACCESS_FAST,
+		}
+	}
+}
+
+/// `UMat::get_mat()`
+#[inline]
+pub fn umat_get_mat(umat: &UMat, access: AccessFlag) -> Result<Mat> {
+	umat.get_mat(access.versioned())
+}
+
+/// `Mat::get_umat()`
+#[inline]
+pub fn mat_get_umat(mat: &Mat, access: AccessFlag, usage: UMatUsageFlags) -> Result<UMat> {
+	mat.get_umat(access.versioned(), usage)
+}
@@ -0,0 +1,200 @@
+use crate::{
+	core::Ptr,
+	features2d,
+	Result,
+};
+
+/// Neighborhoods of the FAST detector, `FastFeatureDetector_DetectorType` in OpenCV 4.x and `i32` before that
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FastType {
+	Type5_8,
+	Type7_12,
+	Type9_16,
+}
+
+impl FastType {
+	#[cfg(ocvrs_opencv_branch_4)]
+	fn versioned(self) -> features2d::FastFeatureDetector_DetectorType {
+		match self {
+			FastType::Type5_8 => features2d::FastFeatureDetector_DetectorType::TYPE_5_8,
+			FastType::Type7_12 => features2d::FastFeatureDetector_DetectorType::TYPE_7_12,
+			FastType::Type9_16 => features2d::FastFeatureDetector_DetectorType::TYPE_9_16,
+		}
+	}
+
+	#[cfg(not(ocvrs_opencv_branch_4))]
+	fn versioned(self) -> i32 {
+		match self {
+			FastType::Type5_8 => features2d::FastFeatureDetector_TYPE_5_8,
+			FastType::Type7_12 => features2d::FastFeatureDetector_TYPE_7_12,
+			FastType::Type9_16 => features2d::FastFeatureDetector_TYPE_9_16,
+		}
+	}
+}
+
+/// `FastFeatureDetector::create()`
+#[inline]
+pub fn fast_feature_detector(threshold: i32, nonmax_suppression: bool, typ: FastType) -> Result<Ptr<dyn features2d::FastFeatureDetector>> {
+	<dyn features2d::FastFeatureDetector>::create(threshold, nonmax_suppression, typ.versioned())
+}
+
+/// Neighborhoods of the AGAST detector, `AgastFeatureDetector_DetectorType` in OpenCV 4.x and `i32` before that
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AgastType {
+	Agast5_8,
+	Agast7_12d,
+	Agast7_12s,
+	Oast9_16,
+}
+
+impl AgastType {
+	#[cfg(ocvrs_opencv_branch_4)]
+	fn versioned(self) -> features2d::AgastFeatureDetector_DetectorType {
+		match self {
+			AgastType::Agast5_8 => features2d::AgastFeatureDetector_DetectorType::AGAST_5_8,
+			AgastType::Agast7_12d => features2d::AgastFeatureDetector_DetectorType::AGAST_7_12d,
+			AgastType::Agast7_12s => features2d::AgastFeatureDetector_DetectorType::AGAST_7_12s,
+			AgastType::Oast9_16 => features2d::AgastFeatureDetector_DetectorType::OAST_9_16,
+		}
+	}
+
+	#[cfg(not(ocvrs_opencv_branch_4))]
+	fn versioned(self) -> i32 {
+		match self {
+			AgastType::Agast5_8 => features2d::AgastFeatureDetector_AGAST_5_8,
+			AgastType::Agast7_12d => features2d::AgastFeatureDetector_AGAST_7_12d,
+			AgastType::Agast7_12s => features2d::AgastFeatureDetector_AGAST_7_12s,
+			AgastType::Oast9_16 => features2d::AgastFeatureDetector_OAST_9_16,
+		}
+	}
+}
+
+/// `AgastFeatureDetector::create()`
+#[inline]
+pub fn agast_feature_detector(threshold: i32, nonmax_suppression: bool, typ: AgastType) -> Result<Ptr<dyn features2d::AgastFeatureDetector>> {
+	<dyn features2d::AgastFeatureDetector>::create(threshold, nonmax_suppression, typ.versioned())
+}
+
+/// Keypoint ranking of ORB, `ORB_ScoreType` in OpenCV 4.x and `i32` before that
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OrbScoreType {
+	Harris,
+	Fast,
+}
+
+impl OrbScoreType {
+	#[cfg(ocvrs_opencv_branch_4)]
+	fn versioned(self) -> features2d::ORB_ScoreType {
+		match self {
+			OrbScoreType::Harris => features2d::ORB_ScoreType::HARRIS_SCORE,
+			OrbScoreType::Fast => features2d::ORB_ScoreType::FAST_SCORE,
+		}
+	}
+
+	#[cfg(not(ocvrs_opencv_branch_4))]
+	fn versioned(self) -> i32 {
+		match self {
+			OrbScoreType::Harris => features2d::ORB_HARRIS_SCORE,
+			OrbScoreType::Fast => features2d::ORB_FAST_SCORE,
+		}
+	}
+}
+
+/// `ORB::create()`
+#[allow(clippy::too_many_arguments)]
+#[inline]
+pub fn orb(
+	nfeatures: i32,
+	scale_factor: f32,
+	nlevels: i32,
+	edge_threshold: i32,
+	first_level: i32,
+	wta_k: i32,
+	score_type: OrbScoreType,
+	patch_size: i32,
+	fast_threshold: i32,
+) -> Result<Ptr<dyn features2d::ORB>> {
+	<dyn features2d::ORB>::create(nfeatures, scale_factor, nlevels, edge_threshold, first_level, wta_k, score_type.versioned(), patch_size, fast_threshold)
+}
+
+/// Descriptor of AKAZE, `AKAZE_DescriptorType` in OpenCV 4.x and `i32` before that
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AkazeDescriptorType {
+	KazeUpright,
+	Kaze,
+	MldbUpright,
+	Mldb,
+}
+
+impl AkazeDescriptorType {
+	#[cfg(ocvrs_opencv_branch_4)]
+	fn versioned(self) -> features2d::AKAZE_DescriptorType {
+		match self {
+			AkazeDescriptorType::KazeUpright => features2d::AKAZE_DescriptorType::DESCRIPTOR_KAZE_UPRIGHT,
+			AkazeDescriptorType::Kaze => features2d::AKAZE_DescriptorType::DESCRIPTOR_KAZE,
+			AkazeDescriptorType::MldbUpright => features2d::AKAZE_DescriptorType::DESCRIPTOR_MLDB_UPRIGHT,
+			AkazeDescriptorType::Mldb => features2d::AKAZE_DescriptorType::DESCRIPTOR_MLDB,
+		}
+	}
+
+	#[cfg(not(ocvrs_opencv_branch_4))]
+	fn versioned(self) -> i32 {
+		match self {
+			AkazeDescriptorType::KazeUpright => features2d::AKAZE_DESCRIPTOR_KAZE_UPRIGHT,
+			AkazeDescriptorType::Kaze => features2d::AKAZE_DESCRIPTOR_KAZE,
+			AkazeDescriptorType::MldbUpright => features2d::AKAZE_DESCRIPTOR_MLDB_UPRIGHT,
+			AkazeDescriptorType::Mldb => features2d::AKAZE_DESCRIPTOR_MLDB,
+		}
+	}
+}
+
+/// Diffusivity of KAZE and AKAZE, `KAZE_DiffusivityType` in OpenCV 4.x and `i32` before that
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum KazeDiffusivity {
+	PmG1,
+	PmG2,
+	Weickert,
+	Charbonnier,
+}
+
+impl KazeDiffusivity {
+	#[cfg(ocvrs_opencv_branch_4)]
+	fn versioned(self) -> features2d::KAZE_DiffusivityType {
+		match self {
+			KazeDiffusivity::PmG1 => features2d::KAZE_DiffusivityType::DIFF_PM_G1,
+			KazeDiffusivity::PmG2 => features2d::KAZE_DiffusivityType::DIFF_PM_G2,
+			KazeDiffusivity::Weickert => features2d::KAZE_DiffusivityType::DIFF_WEICKERT,
+			KazeDiffusivity::Charbonnier => features2d::KAZE_DiffusivityType::DIFF_CHARBONNIER,
+		}
+	}
+
+	#[cfg(not(ocvrs_opencv_branch_4))]
+	fn versioned(self) -> i32 {
+		match self {
+			KazeDiffusivity::PmG1 => features2d::KAZE_DIFF_PM_G1,
+			KazeDiffusivity::PmG2 => features2d::KAZE_DIFF_PM_G2,
+			KazeDiffusivity::Weickert => features2d::KAZE_DIFF_WEICKERT,
+			KazeDiffusivity::Charbonnier => features2d::KAZE_DIFF_CHARBONNIER,
+		}
+	}
+}
+
+/// `AKAZE::create()`
+#[inline]
+pub fn akaze(
+	descriptor_type: AkazeDescriptorType,
+	descriptor_size: i32,
+	descriptor_channels: i32,
+	threshold: f32,
+	n_octaves: i32,
+	n_octave_layers: i32,
+	diffusivity: KazeDiffusivity,
+) -> Result<Ptr<dyn features2d::AKAZE>> {
+	<dyn features2d::AKAZE>::create(descriptor_type.versioned(), descriptor_size, descriptor_channels, threshold, n_octaves, n_octave_layers, diffusivity.versioned())
+}
+
+/// `KAZE::create()`
+#[inline]
+pub fn kaze(extended: bool, upright: bool, threshold: f32, n_octaves: i32, n_octave_layers: i32, diffusivity: KazeDiffusivity) -> Result<Ptr<dyn features2d::KAZE>> {
+	<dyn features2d::KAZE>::create(extended, upright, threshold, n_octaves, n_octave_layers, diffusivity.versioned())
+}
@@ -0,0 +1,89 @@
+/// Line types for the drawing functions, `LINE_*` constants in `imgproc` in OpenCV 4.x and in `core` before that
+///
+/// The drawing functions take the line type as `i32` in all versions, use `LineType::Line8.into()` to pass it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LineType {
+	Filled,
+	Line4,
+	Line8,
+	LineAA,
+}
+
+impl From<LineType> for i32 {
+	fn from(s: LineType) -> Self {
+		#[cfg(ocvrs_opencv_branch_4)]
+		use crate::imgproc::{FILLED, LINE_4, LINE_8, LINE_AA};
+		#[cfg(not(ocvrs_opencv_branch_4))]
+		use crate::core::{FILLED, LINE_4, LINE_8, LINE_AA};
+		match s {
+			LineType::Filled => FILLED,
+			LineType::Line4 => LINE_4,
+			LineType::Line8 => LINE_8,
+			LineType::LineAA => LINE_AA,
+		}
+	}
+}
+
+/// Fonts for `put_text()` and `get_text_size()`, `FONT_*` constants in `imgproc` in OpenCV 4.x and in `core` before that
+///
+/// The text functions take the font face as `i32` in all versions, use `HersheyFont::Simplex.into()` to pass it or
+/// [HersheyFont::italic] for the slanted variant.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HersheyFont {
+	Simplex,
+	Plain,
+	Duplex,
+	Complex,
+	Triplex,
+	ComplexSmall,
+	ScriptSimplex,
+	ScriptComplex,
+}
+
+impl HersheyFont {
+	/// Font face of the italic variant of the font
+	pub fn italic(self) -> i32 {
+		#[cfg(ocvrs_opencv_branch_4)]
+		use crate::imgproc::FONT_ITALIC;
+		#[cfg(not(ocvrs_opencv_branch_4))]
+		use crate::core::FONT_ITALIC;
+		i32::from(self) | FONT_ITALIC
+	}
+}
+
+impl From<HersheyFont> for i32 {
+	fn from(s: HersheyFont) -> Self {
+		#[cfg(ocvrs_opencv_branch_4)]
+		use crate::imgproc::{
+			FONT_HERSHEY_COMPLEX,
+			FONT_HERSHEY_COMPLEX_SMALL,
+			FONT_HERSHEY_DUPLEX,
+			FONT_HERSHEY_PLAIN,
+			FONT_HERSHEY_SCRIPT_COMPLEX,
+			FONT_HERSHEY_SCRIPT_SIMPLEX,
+			FONT_HERSHEY_SIMPLEX,
+			FONT_HERSHEY_TRIPLEX,
+		};
+		#[cfg(not(ocvrs_opencv_branch_4))]
+		use crate::core::{
+			FONT_HERSHEY_COMPLEX,
+			FONT_HERSHEY_COMPLEX_SMALL,
+			FONT_HERSHEY_DUPLEX,
+			FONT_HERSHEY_PLAIN,
+			FONT_HERSHEY_SCRIPT_COMPLEX,
+			FONT_HERSHEY_SCRIPT_SIMPLEX,
+			FONT_HERSHEY_SIMPLEX,
+			FONT_HERSHEY_TRIPLEX,
+		};
+		match s {
+			HersheyFont::Simplex => FONT_HERSHEY_SIMPLEX,
+			HersheyFont::Plain => FONT_HERSHEY_PLAIN,
+			HersheyFont::Duplex => FONT_HERSHEY_DUPLEX,
+			HersheyFont::Complex => FONT_HERSHEY_COMPLEX,
+			HersheyFont::Triplex => FONT_HERSHEY_TRIPLEX,
+			HersheyFont::ComplexSmall => FONT_HERSHEY_COMPLEX_SMALL,
+			HersheyFont::ScriptSimplex => FONT_HERSHEY_SCRIPT_SIMPLEX,
+			HersheyFont::ScriptComplex => FONT_HERSHEY_SCRIPT_COMPLEX,
+		}
+	}
+}
@@ -0,0 +1,40 @@
+use crate::{
+	core::Size,
+	Result,
+	videoio::{VideoCapture, VideoWriter},
+};
+
+/// Opens the camera with the specified `index` using any available backend
+///
+/// OpenCV 3.2 only has the single argument constructor, later versions take the API preference too.
+#[inline]
+pub fn open_camera(index: i32) -> Result<VideoCapture> {
+	#[cfg(ocvrs_opencv_branch_32)]
+	{
+		VideoCapture::new_default(index)
+	}
+	#[cfg(not(ocvrs_opencv_branch_32))]
+	{
+		VideoCapture::new(index, crate::videoio::CAP_ANY)
+	}
+}
+
+/// Opens the video file or the stream URL using any available backend
+#[inline]
+pub fn open_file(filename: &str) -> Result<VideoCapture> {
+	VideoCapture::from_file(filename, crate::videoio::CAP_ANY)
+}
+
+/// Opens the video file for writing the frames encoded with the `fourcc` codec, see [fourcc]
+#[inline]
+pub fn open_writer(filename: &str, fourcc: i32, fps: f64, frame_size: Size, is_color: bool) -> Result<VideoWriter> {
+	VideoWriter::new(filename, fourcc, fps, frame_size, is_color)
+}
+
+/// `VideoWriter::fourcc()` taking the code as bytes, e.g. `fourcc(b"MJPG")`
+///
+/// The generated binding takes 4 separate `c_char` values that are signed or unsigned depending on the platform.
+#[inline]
+pub fn fourcc(code: &[u8; 4]) -> Result<i32> {
+	VideoWriter::fourcc(code[0] as _, code[1] as _, code[2] as _, code[3] as _)
+}
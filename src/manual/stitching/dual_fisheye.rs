@@ -0,0 +1,213 @@
+//! Equirectangular panorama from the two back-to-back fisheye lenses of a 360° camera
+//!
+//! The lenses use the `calib3d::fisheye_*` (Kannala-Brandt) model, their images are unwarped into the
+//! equirectangular projection and feather-blended in the overlap of the fields of view:
+//! ```ignore
+//! let front = FisheyeLens::from_mats(&k_front, &d_front)?;
+//! let back = FisheyeLens::from_mats(&k_back, &d_back)?.facing_back();
+//! let stitcher = DualFisheye::new(&front, &back, front_image.size()?, 3840)?;
+//! let panorama = stitcher.stitch(&front_image, &back_image)?;
+//! ```
+//!
+//! Unlike `calib3d::fisheye_project_points()` the projection handles the rays more than 90° off the optical axis, the
+//! usual fields of view of such lenses are 190° to 210°.
+
+use std::f64::consts::PI;
+
+use crate::{
+	core::{self, Mat, Matx33d, Scalar, Size, Vec4d},
+	Error,
+	imgproc,
+	prelude::*,
+	Result,
+};
+
+/// Calibration and orientation of a fisheye lens
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FisheyeLens {
+	/// Camera matrix
+	pub k: Matx33d,
+	/// Distortion coefficients `k1..k4`
+	pub d: Vec4d,
+	/// Rotation from the panorama coordinates (`x` right, `y` down, `z` to the panorama center) to the camera
+	/// coordinates
+	pub r: Matx33d,
+	/// Field of view in radians, `195°` by default
+	pub fov: f64,
+}
+
+impl FisheyeLens {
+	/// Creates the lens looking at the panorama center
+	pub fn new(k: Matx33d, d: Vec4d) -> Self {
+		Self { k, d, r: Matx33d::from([1., 0., 0., 0., 1., 0., 0., 0., 1.]), fov: 195f64.to_radians() }
+	}
+
+	/// Creates the lens from the camera matrix and distortion coefficients returned by `calib3d::fisheye_calibrate()`
+	pub fn from_mats(k: &Mat, d: &Mat) -> Result<Self> {
+		let k = f64_values(k, 9, "Camera matrix")?;
+		let d = f64_values(d, 4, "Distortion coefficients")?;
+		Ok(Self::new(
+			Matx33d::from([k[0], k[1], k[2], k[3], k[4], k[5], k[6], k[7], k[8]]),
+			Vec4d::from([d[0], d[1], d[2], d[3]]),
+		))
+	}
+
+	pub fn with_rotation(mut self, r: Matx33d) -> Self {
+		self.r = r;
+		self
+	}
+
+	/// Turns the lens by 180° around the vertical axis, the usual orientation of the second lens
+	pub fn facing_back(self) -> Self {
+		self.with_rotation(Matx33d::from([-1., 0., 0., 0., 1., 0., 0., 0., -1.]))
+	}
+
+	pub fn with_fov(mut self, fov: f64) -> Self {
+		self.fov = fov;
+		self
+	}
+
+	/// Projects the ray in the panorama coordinates to the image, returns the pixel and the angle between the ray and
+	/// the edge of the field of view, `None` if the ray is outside of the field of view
+	fn project(&self, ray: [f64; 3]) -> Option<(f64, f64, f64)> {
+		let r = &self.r;
+		let c = [
+			r[(0, 0)] * ray[0] + r[(0, 1)] * ray[1] + r[(0, 2)] * ray[2],
+			r[(1, 0)] * ray[0] + r[(1, 1)] * ray[1] + r[(1, 2)] * ray[2],
+			r[(2, 0)] * ray[0] + r[(2, 1)] * ray[1] + r[(2, 2)] * ray[2],
+		];
+		let theta = c[2].clamp(-1., 1.).acos();
+		let margin = self.fov / 2. - theta;
+		if margin < 0. {
+			return None;
+		}
+		let theta2 = theta * theta;
+		let theta_d = theta * (1. + theta2 * (self.d[0] + theta2 * (self.d[1] + theta2 * (self.d[2] + theta2 * self.d[3]))));
+		let radius = c[0].hypot(c[1]);
+		let (a, b) = if radius > 1e-12 {
+			(c[0] / radius * theta_d, c[1] / radius * theta_d)
+		} else {
+			(0., 0.)
+		};
+		let k = &self.k;
+		Some((k[(0, 0)] * a + k[(0, 1)] * b + k[(0, 2)], k[(1, 1)] * b + k[(1, 2)], margin))
+	}
+}
+
+/// Stitcher of the dual-fisheye images into the equirectangular panorama
+///
+/// The remap tables are computed once in [DualFisheye::new], so the stitcher can be reused for the video frames.
+#[derive(Debug)]
+pub struct DualFisheye {
+	image_size: Size,
+	/// `(map_x, map_y, margin)` of the front and back lenses
+	maps: [(Mat, Mat, Mat); 2],
+	weights: [Mat; 2],
+	blend_angle: f64,
+}
+
+impl DualFisheye {
+	/// Creates the stitcher of the images with `image_size` into the panorama `panorama_width` pixels wide and
+	/// `panorama_width / 2` pixels high, the blending band is 5° wide
+	///
+	/// The panorama center (longitude `0`) is the `z` axis of the panorama coordinates, its top is the `-y` axis.
+	pub fn new(front: &FisheyeLens, back: &FisheyeLens, image_size: Size, panorama_width: i32) -> Result<Self> {
+		if panorama_width < 2 {
+			return Err(Error::new(core::StsBadArg, format!("Panorama width must be at least 2, got: {}", panorama_width)));
+		}
+		let size = Size::new(panorama_width, panorama_width / 2);
+		let maps = [lens_maps(front, image_size, size)?, lens_maps(back, image_size, size)?];
+		let mut out = Self { image_size, maps, weights: [Mat::default(), Mat::default()], blend_angle: 0. };
+		out.set_blend_angle(5f64.to_radians())?;
+		Ok(out)
+	}
+
+	/// Sets the width in radians of the band along the edge of each field of view where the lens fades out
+	pub fn with_blend_angle(mut self, blend_angle: f64) -> Result<Self> {
+		self.set_blend_angle(blend_angle)?;
+		Ok(self)
+	}
+
+	fn set_blend_angle(&mut self, blend_angle: f64) -> Result<()> {
+		if blend_angle.is_nan() || blend_angle <= 0. {
+			return Err(Error::new(core::StsOutOfRange, format!("Blend angle must be positive, got: {}", blend_angle)));
+		}
+		for ((_, _, margin), weight) in self.maps.iter().zip(self.weights.iter_mut()) {
+			let mut scaled = Mat::default();
+			margin.convert_to(&mut scaled, core::CV_32F, 1. / blend_angle, 0.)?;
+			imgproc::threshold(&scaled, weight, 1., 1., imgproc::THRESH_TRUNC)?;
+		}
+		self.blend_angle = blend_angle;
+		Ok(())
+	}
+
+	#[inline]
+	pub fn blend_angle(&self) -> f64 {
+		self.blend_angle
+	}
+
+	/// Size of the panorama
+	#[inline]
+	pub fn panorama_size(&self) -> Result<Size> {
+		self.weights[0].size()
+	}
+
+	/// Stitches the images of the front and back lenses, they must have the same type, `CV_8U` or `CV_32F` with any
+	/// number of channels
+	///
+	/// The pixels seen by neither lens are black.
+	pub fn stitch(&self, front: &Mat, back: &Mat) -> Result<Mat> {
+		for image in &[front, back] {
+			if image.size()? != self.image_size {
+				return Err(Error::new(core::StsUnmatchedSizes, format!("Image size must be: {:?}, got: {:?}", self.image_size, image.size()?)));
+			}
+		}
+		if front.typ() != back.typ() {
+			return Err(Error::new(core::StsUnmatchedFormats, format!("Images must have the same type, got: {} and {}", front.typ(), back.typ())));
+		}
+		let mut unwarped = [Mat::default(), Mat::default()];
+		for ((image, (map_x, map_y, _)), out) in [front, back].iter().zip(&self.maps).zip(unwarped.iter_mut()) {
+			imgproc::remap(*image, out, map_x, map_y, imgproc::INTER_LINEAR, core::BORDER_CONSTANT, Scalar::default())?;
+		}
+		let mut out = Mat::default();
+		imgproc::blend_linear(&unwarped[0], &unwarped[1], &self.weights[0], &self.weights[1], &mut out)?;
+		Ok(out)
+	}
+}
+
+/// Computes the remap tables of the lens and the angular distance of each panorama pixel to the edge of the field of
+/// view, `0` outside of it
+fn lens_maps(lens: &FisheyeLens, image_size: Size, size: Size) -> Result<(Mat, Mat, Mat)> {
+	let mut map_x = Mat::new_size_with_default(size, core::CV_32FC1, Scalar::all(-1.))?;
+	let mut map_y = Mat::new_size_with_default(size, core::CV_32FC1, Scalar::all(-1.))?;
+	let mut margin = Mat::new_size_with_default(size, core::CV_32FC1, Scalar::default())?;
+	let (width, height) = (f64::from(image_size.width), f64::from(image_size.height));
+	for v in 0..size.height {
+		let latitude = PI / 2. - (f64::from(v) + 0.5) * PI / f64::from(size.height);
+		let (row_x, row_y, row_margin) = (map_x.at_row_mut::<f32>(v)?, map_y.at_row_mut::<f32>(v)?, margin.at_row_mut::<f32>(v)?);
+		for (u, ((map_x, map_y), margin)) in row_x.iter_mut().zip(row_y).zip(row_margin).enumerate() {
+			let longitude = (u as f64 + 0.5) * 2. * PI / f64::from(size.width) - PI;
+			let ray = [latitude.cos() * longitude.sin(), -latitude.sin(), latitude.cos() * longitude.cos()];
+			if let Some((x, y, m)) = lens.project(ray) {
+				if x >= -0.5 && y >= -0.5 && x < width - 0.5 && y < height - 0.5 {
+					*map_x = x as f32;
+					*map_y = y as f32;
+					*margin = m as f32;
+				}
+			}
+		}
+	}
+	Ok((map_x, map_y, margin))
+}
+
+fn f64_values(mat: &Mat, len: usize, name: &str) -> Result<Vec<f64>> {
+	if mat.total() != len || mat.channels() != 1 {
+		return Err(Error::new(core::StsBadSize, format!("{} must have {} single channel elements, but it has: {}", name, len, mat.total())));
+	}
+	let mut converted = Mat::default();
+	mat.convert_to(&mut converted, core::CV_64F, 1., 0.)?;
+	if !converted.is_continuous() {
+		converted = converted.try_clone()?;
+	}
+	Ok(converted.data_typed::<f64>()?.to_vec())
+}
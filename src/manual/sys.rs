@@ -9,6 +9,9 @@ use std::{
 	mem::MaybeUninit,
 };
 
+#[cfg(feature = "trace-ffi")]
+use std::{fmt, time::Instant};
+
 use crate::{Error, Result as CrateResult, types::Unit};
 
 #[repr(C)]
@@ -31,3 +34,51 @@ impl<S: Into<O>, O> Result<S, O> {
 }
 
 pub type Result_void = Result<Unit, ()>;
+
+/// Trace of a single call to the extern function, created by `ffi_call!` with the `trace-ffi` feature
+///
+/// Every call is logged twice at the `TRACE` level of the `opencv::ffi` target: before entering the C++ code with the
+/// arguments and after returning with the duration. Pointers are logged as addresses so the same object can be followed
+/// between the calls, and a crash inside OpenCV leaves the last "enter" event without the matching "exit".
+#[cfg(feature = "trace-ffi")]
+pub struct FfiCallTrace {
+	func: &'static str,
+	arg_count: usize,
+	arg_seen: usize,
+	args: Vec<String>,
+	start: Option<Instant>,
+}
+
+#[cfg(feature = "trace-ffi")]
+impl FfiCallTrace {
+	#[inline]
+	pub fn new(func: &'static str, arg_count: usize) -> Self {
+		let mut out = Self { func, arg_count, arg_seen: 0, args: Vec::with_capacity(arg_count), start: None };
+		if arg_count == 0 {
+			out.enter();
+		}
+		out
+	}
+
+	/// Records the evaluated argument, the "enter" event is logged after the last one
+	#[inline]
+	pub fn arg(&mut self, arg: &dyn fmt::Debug) {
+		if tracing::enabled!(target: "opencv::ffi", tracing::Level::TRACE) {
+			self.args.push(format!("{:?}", arg));
+		}
+		self.arg_seen += 1;
+		if self.arg_seen == self.arg_count {
+			self.enter();
+		}
+	}
+
+	fn enter(&mut self) {
+		tracing::trace!(target: "opencv::ffi", func = self.func, args = ?self.args, "enter");
+		self.start = Some(Instant::now());
+	}
+
+	#[inline]
+	pub fn finish(self) {
+		tracing::trace!(target: "opencv::ffi", func = self.func, elapsed = ?self.start.map(|start| start.elapsed()), "exit");
+	}
+}
@@ -0,0 +1,215 @@
+//! Helpers for testing the code built on top of the bindings, enabled with the `test-util` feature
+//!
+//! Contains the [proptest](https://docs.rs/proptest) strategies generating Mats and the golden image comparison:
+//! ```ignore
+//! use opencv::test_support::{self, proptest::prelude::*};
+//!
+//! proptest! {
+//!     #[test]
+//!     fn flip_twice_is_identity(mat in test_support::mats(1..32, 1..32, test_support::mat_types())) {
+//!         let twice = flip(&flip(&mat)?)?;
+//!         prop_assert!(test_support::compare(&twice, &mat, &Tolerance::exact())?.is_within());
+//!     }
+//! }
+//!
+//! #[test]
+//! fn render() -> opencv::Result<()> {
+//!     test_support::assert_golden(&render_scene()?, "tests/golden/scene.png", &Tolerance::new(2.))
+//! }
+//! ```
+
+use std::ops::Range;
+
+pub use proptest;
+use proptest::{collection, prelude::*, sample};
+
+use crate::{
+	core::{self, Mat, Scalar},
+	Error,
+	prelude::*,
+	Result,
+};
+
+/// All Mat depths
+pub const DEPTHS: [i32; 7] = [core::CV_8U, core::CV_8S, core::CV_16U, core::CV_16S, core::CV_32S, core::CV_32F, core::CV_64F];
+
+/// Generates the Mat types of any depth with 1 to 4 channels
+pub fn mat_types() -> impl Strategy<Value=i32> {
+	mat_types_of(&DEPTHS, 1..5)
+}
+
+/// Generates the Mat types of the selected `depths` and `channels` count
+pub fn mat_types_of(depths: &[i32], channels: Range<i32>) -> impl Strategy<Value=i32> {
+	(sample::select(depths.to_vec()), channels).prop_map(|(depth, channels)| core::CV_MAKETYPE(depth, channels))
+}
+
+/// Generates the Mats of the size within `rows` and `cols` ranges and of the type generated by `typ`
+///
+/// The element values cover the whole range of the integer depths and `-1e6..1e6` for the floating point depths,
+/// shrinking reduces the size of the Mat and the values towards 0.
+pub fn mats(rows: Range<i32>, cols: Range<i32>, typ: impl Strategy<Value=i32>) -> impl Strategy<Value=Mat> {
+	(rows, cols, typ).prop_flat_map(|(rows, cols, typ)| {
+		let len = (rows * cols * core::CV_MAT_CN(typ)) as usize;
+		collection::vec(element_values(core::CV_MAT_DEPTH(typ)), len)
+			.prop_map(move |values| mat_from_values(rows, cols, typ, &values).expect("Can't create Mat"))
+	})
+}
+
+/// Generates the Mats up to 16x16 of any type
+pub fn small_mats() -> impl Strategy<Value=Mat> {
+	mats(1..17, 1..17, mat_types())
+}
+
+/// Generates the `CV_8UC3` images of the size within `rows` and `cols` ranges
+pub fn images(rows: Range<i32>, cols: Range<i32>) -> impl Strategy<Value=Mat> {
+	mats(rows, cols, Just(core::CV_8UC3))
+}
+
+fn element_values(depth: i32) -> BoxedStrategy<f64> {
+	match depth {
+		core::CV_8U => (0..=u8::MAX).prop_map(f64::from).boxed(),
+		core::CV_8S => (i8::MIN..=i8::MAX).prop_map(f64::from).boxed(),
+		core::CV_16U => (0..=u16::MAX).prop_map(f64::from).boxed(),
+		core::CV_16S => (i16::MIN..=i16::MAX).prop_map(f64::from).boxed(),
+		core::CV_32S => (i32::MIN..=i32::MAX).prop_map(f64::from).boxed(),
+		_ => (-1e6..1e6).boxed(),
+	}
+}
+
+fn mat_from_values(rows: i32, cols: i32, typ: i32, values: &[f64]) -> Result<Mat> {
+	let channels = core::CV_MAT_CN(typ);
+	let mut mat = Mat::new_rows_cols_with_default(rows, cols, core::CV_MAKETYPE(core::CV_64F, channels), Scalar::all(0.))?;
+	mat.data_typed_mut::<f64>()?.copy_from_slice(values);
+	let mut out = Mat::default();
+	mat.convert_to(&mut out, typ, 1., 0.)?;
+	Ok(out)
+}
+
+/// Allowed difference between the compared Mats
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Tolerance {
+	/// Maximum absolute difference of an element that still counts as a match
+	pub max_abs_diff: f64,
+	/// Fraction of the elements that are allowed to exceed `max_abs_diff`, e.g. for the anti-aliased edges
+	pub max_mismatched_ratio: f64,
+}
+
+impl Tolerance {
+	pub fn new(max_abs_diff: f64) -> Self {
+		Self { max_abs_diff, max_mismatched_ratio: 0. }
+	}
+
+	/// No difference is allowed
+	pub fn exact() -> Self {
+		Self::new(0.)
+	}
+
+	#[inline]
+	pub fn with_mismatched_ratio(mut self, max_mismatched_ratio: f64) -> Self {
+		self.max_mismatched_ratio = max_mismatched_ratio;
+		self
+	}
+}
+
+/// Result of [compare]
+#[derive(Debug)]
+pub struct Comparison {
+	/// Tolerance used for the comparison
+	pub tolerance: Tolerance,
+	/// Largest absolute difference of an element
+	pub max_abs_diff: f64,
+	/// Number of the elements (pixel channels) that differ by more than `tolerance.max_abs_diff`
+	pub mismatched: usize,
+	/// Total number of the elements (pixel channels)
+	pub total: usize,
+	/// Absolute difference normalized to `0..255`, `CV_8U` with the same number of channels as the compared Mats
+	pub diff: Mat,
+}
+
+impl Comparison {
+	/// Returns `true` if the compared Mats match within the tolerance
+	pub fn is_within(&self) -> bool {
+		self.mismatched == 0 || (self.total > 0 && self.mismatched as f64 / self.total as f64 <= self.tolerance.max_mismatched_ratio)
+	}
+}
+
+/// Compares the Mats element-wise, fails if their sizes or types differ
+pub fn compare(actual: &impl MatTraitConst, expected: &impl MatTraitConst, tolerance: &Tolerance) -> Result<Comparison> {
+	if actual.size()? != expected.size()? {
+		return Err(Error::new(core::StsUnmatchedSizes, format!("Mat sizes differ, actual: {:?}, expected: {:?}", actual.size()?, expected.size()?)));
+	}
+	if actual.typ() != expected.typ() {
+		return Err(Error::new(core::StsUnmatchedFormats, format!("Mat types differ, actual: {}, expected: {}", actual.typ(), expected.typ())));
+	}
+	let (mut actual_f, mut expected_f) = (Mat::default(), Mat::default());
+	actual.convert_to(&mut actual_f, core::CV_64F, 1., 0.)?;
+	expected.convert_to(&mut expected_f, core::CV_64F, 1., 0.)?;
+	let mut abs_diff = Mat::default();
+	core::absdiff(&actual_f, &expected_f, &mut abs_diff)?;
+	let flat = abs_diff.reshape(1, 0)?;
+	let total = flat.total();
+	let (max_abs_diff, mismatched) = if total > 0 {
+		let mut over = Mat::default();
+		core::compare(&flat, &Scalar::all(tolerance.max_abs_diff), &mut over, core::CMP_GT)?;
+		(core::min_max(&flat, &core::no_array())?.max, core::count_non_zero(&over)? as usize)
+	} else {
+		(0., 0)
+	};
+	let mut diff = Mat::default();
+	core::normalize(&abs_diff, &mut diff, 0., 255., core::NORM_MINMAX, core::CV_8U, &core::no_array())?;
+	Ok(Comparison { tolerance: *tolerance, max_abs_diff, mismatched, total, diff })
+}
+
+/// Compares `actual` with the golden image stored at `path`
+///
+/// If the golden image doesn't exist or the `OPENCV_UPDATE_GOLDEN` environment variable is set to `1` it's (re)written
+/// from `actual`. On mismatch `<name>.actual.png` and `<name>.diff.png` are written next to the golden image and the
+/// error describing the difference is returned. Use a lossless format (e.g. PNG) for the golden images.
+#[cfg(ocvrs_has_module_imgcodecs)]
+pub fn assert_golden(actual: &Mat, path: impl AsRef<std::path::Path>, tolerance: &Tolerance) -> Result<()> {
+	use crate::imgcodecs;
+
+	let path = path.as_ref();
+	let path_str = |path: &std::path::Path| path.to_str()
+		.map(str::to_string)
+		.ok_or_else(|| Error::new(core::StsBadArg, format!("Path is not valid UTF-8: {}", path.display())));
+	let write = |path: &std::path::Path, mat: &Mat| -> Result<()> {
+		if let Some(dir) = path.parent() {
+			std::fs::create_dir_all(dir)
+				.map_err(|e| Error::new(core::StsError, format!("Can't create directory: {}: {}", dir.display(), e)))?;
+		}
+		if imgcodecs::imwrite(&path_str(path)?, mat, &core::Vector::new())? {
+			Ok(())
+		} else {
+			Err(Error::new(core::StsError, format!("Can't write image: {}", path.display())))
+		}
+	};
+
+	let update = matches!(std::env::var("OPENCV_UPDATE_GOLDEN").as_deref(), Ok("1"));
+	if update || !path.exists() {
+		return write(path, actual);
+	}
+	let expected = imgcodecs::imread(&path_str(path)?, imgcodecs::IMREAD_UNCHANGED)?;
+	if expected.empty() {
+		return Err(Error::new(core::StsError, format!("Can't read golden image: {}", path.display())));
+	}
+	let comparison = compare(actual, &expected, tolerance)?;
+	if comparison.is_within() {
+		return Ok(());
+	}
+	let stem = path.file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default();
+	let actual_path = path.with_file_name(format!("{}.actual.png", stem));
+	let diff_path = path.with_file_name(format!("{}.diff.png", stem));
+	write(&actual_path, actual)?;
+	write(&diff_path, &comparison.diff)?;
+	Err(Error::new(core::StsError, format!(
+		"Image differs from golden {}: {} of {} elements differ by more than {}, max difference: {}, see {} and {}",
+		path.display(),
+		comparison.mismatched,
+		comparison.total,
+		tolerance.max_abs_diff,
+		comparison.max_abs_diff,
+		actual_path.display(),
+		diff_path.display(),
+	)))
+}
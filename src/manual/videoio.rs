@@ -0,0 +1,11 @@
+pub use capture_property::*;
+pub use resilient_capture::*;
+pub use serial_capture::*;
+
+mod capture_property;
+pub mod pipeline;
+mod resilient_capture;
+mod serial_capture;
+pub mod sources;
+#[cfg(target_os = "linux")]
+pub mod v4l2;
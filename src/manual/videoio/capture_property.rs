@@ -0,0 +1,198 @@
+use std::time::{Duration, Instant};
+
+use crate::{
+	core::{self, Mat},
+	Error,
+	prelude::*,
+	Result,
+	videoio,
+};
+
+/// Typed replacement for the generic `videoio::CAP_PROP_*` constants
+///
+/// Backend-specific properties (`CAP_PROP_XI_*`, `CAP_PROP_OPENNI_*`, etc.) can be passed with [CaptureProperty::Other].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CaptureProperty {
+	/// Current position in the stream in milliseconds or the frame timestamp
+	PosMsec,
+	/// 0-based index of the frame to be decoded next
+	PosFrames,
+	/// Relative position in the file, `0` is the start and `1` is the end
+	PosAviRatio,
+	FrameWidth,
+	FrameHeight,
+	Fps,
+	/// 4-character code of the codec
+	Fourcc,
+	FrameCount,
+	/// Format of the Mat returned by `retrieve()`
+	Format,
+	Mode,
+	Brightness,
+	Contrast,
+	Saturation,
+	Hue,
+	Gain,
+	Exposure,
+	/// Whether the images should be converted to RGB
+	ConvertRgb,
+	WhiteBalanceBlueU,
+	WhiteBalanceRedV,
+	Rectification,
+	Monochrome,
+	Sharpness,
+	AutoExposure,
+	Gamma,
+	Temperature,
+	Trigger,
+	TriggerDelay,
+	Zoom,
+	Focus,
+	Guid,
+	IsoSpeed,
+	Backlight,
+	Pan,
+	Tilt,
+	Roll,
+	Iris,
+	/// Pops up the video/camera filter dialog
+	Settings,
+	BufferSize,
+	Autofocus,
+	SarNum,
+	SarDen,
+	#[cfg(not(ocvrs_opencv_branch_32))]
+	Backend,
+	#[cfg(not(ocvrs_opencv_branch_32))]
+	Channel,
+	#[cfg(not(ocvrs_opencv_branch_32))]
+	AutoWb,
+	#[cfg(not(ocvrs_opencv_branch_32))]
+	WbTemperature,
+	/// Any other property by its `CAP_PROP_*` id
+	Other(i32),
+}
+
+impl CaptureProperty {
+	/// Returns the `CAP_PROP_*` id of the property
+	pub fn id(self) -> i32 {
+		match self {
+			CaptureProperty::PosMsec => videoio::CAP_PROP_POS_MSEC,
+			CaptureProperty::PosFrames => videoio::CAP_PROP_POS_FRAMES,
+			CaptureProperty::PosAviRatio => videoio::CAP_PROP_POS_AVI_RATIO,
+			CaptureProperty::FrameWidth => videoio::CAP_PROP_FRAME_WIDTH,
+			CaptureProperty::FrameHeight => videoio::CAP_PROP_FRAME_HEIGHT,
+			CaptureProperty::Fps => videoio::CAP_PROP_FPS,
+			CaptureProperty::Fourcc => videoio::CAP_PROP_FOURCC,
+			CaptureProperty::FrameCount => videoio::CAP_PROP_FRAME_COUNT,
+			CaptureProperty::Format => videoio::CAP_PROP_FORMAT,
+			CaptureProperty::Mode => videoio::CAP_PROP_MODE,
+			CaptureProperty::Brightness => videoio::CAP_PROP_BRIGHTNESS,
+			CaptureProperty::Contrast => videoio::CAP_PROP_CONTRAST,
+			CaptureProperty::Saturation => videoio::CAP_PROP_SATURATION,
+			CaptureProperty::Hue => videoio::CAP_PROP_HUE,
+			CaptureProperty::Gain => videoio::CAP_PROP_GAIN,
+			CaptureProperty::Exposure => videoio::CAP_PROP_EXPOSURE,
+			CaptureProperty::ConvertRgb => videoio::CAP_PROP_CONVERT_RGB,
+			CaptureProperty::WhiteBalanceBlueU => videoio::CAP_PROP_WHITE_BALANCE_BLUE_U,
+			CaptureProperty::WhiteBalanceRedV => videoio::CAP_PROP_WHITE_BALANCE_RED_V,
+			CaptureProperty::Rectification => videoio::CAP_PROP_RECTIFICATION,
+			CaptureProperty::Monochrome => videoio::CAP_PROP_MONOCHROME,
+			CaptureProperty::Sharpness => videoio::CAP_PROP_SHARPNESS,
+			CaptureProperty::AutoExposure => videoio::CAP_PROP_AUTO_EXPOSURE,
+			CaptureProperty::Gamma => videoio::CAP_PROP_GAMMA,
+			CaptureProperty::Temperature => videoio::CAP_PROP_TEMPERATURE,
+			CaptureProperty::Trigger => videoio::CAP_PROP_TRIGGER,
+			CaptureProperty::TriggerDelay => videoio::CAP_PROP_TRIGGER_DELAY,
+			CaptureProperty::Zoom => videoio::CAP_PROP_ZOOM,
+			CaptureProperty::Focus => videoio::CAP_PROP_FOCUS,
+			CaptureProperty::Guid => videoio::CAP_PROP_GUID,
+			CaptureProperty::IsoSpeed => videoio::CAP_PROP_ISO_SPEED,
+			CaptureProperty::Backlight => videoio::CAP_PROP_BACKLIGHT,
+			CaptureProperty::Pan => videoio::CAP_PROP_PAN,
+			CaptureProperty::Tilt => videoio::CAP_PROP_TILT,
+			CaptureProperty::Roll => videoio::CAP_PROP_ROLL,
+			CaptureProperty::Iris => videoio::CAP_PROP_IRIS,
+			CaptureProperty::Settings => videoio::CAP_PROP_SETTINGS,
+			CaptureProperty::BufferSize => videoio::CAP_PROP_BUFFERSIZE,
+			CaptureProperty::Autofocus => videoio::CAP_PROP_AUTOFOCUS,
+			CaptureProperty::SarNum => videoio::CAP_PROP_SAR_NUM,
+			CaptureProperty::SarDen => videoio::CAP_PROP_SAR_DEN,
+			#[cfg(not(ocvrs_opencv_branch_32))]
+			CaptureProperty::Backend => videoio::CAP_PROP_BACKEND,
+			#[cfg(not(ocvrs_opencv_branch_32))]
+			CaptureProperty::Channel => videoio::CAP_PROP_CHANNEL,
+			#[cfg(not(ocvrs_opencv_branch_32))]
+			CaptureProperty::AutoWb => videoio::CAP_PROP_AUTO_WB,
+			#[cfg(not(ocvrs_opencv_branch_32))]
+			CaptureProperty::WbTemperature => videoio::CAP_PROP_WB_TEMPERATURE,
+			CaptureProperty::Other(id) => id,
+		}
+	}
+}
+
+impl From<CaptureProperty> for i32 {
+	#[inline]
+	fn from(s: CaptureProperty) -> Self {
+		s.id()
+	}
+}
+
+/// Time of a captured frame returned by [VideoCaptureProps::read_timestamped]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Timestamp {
+	/// Monotonic clock reading taken right after the frame was read, always available
+	pub captured_at: Instant,
+	/// Frame position reported by the backend (`CAP_PROP_POS_MSEC`), it's the position in the file for the video files
+	/// and the driver timestamp for some cameras, `None` if the backend doesn't report it
+	pub stream_time: Option<Duration>,
+}
+
+impl Timestamp {
+	/// Returns the stream time if it's available, otherwise the monotonic time elapsed since `origin`
+	///
+	/// Use the same `origin` (e.g. the capture start time) for all frames of a stream.
+	pub fn time_since(&self, origin: Instant) -> Duration {
+		self.stream_time.unwrap_or_else(|| self.captured_at.saturating_duration_since(origin))
+	}
+}
+
+/// Typed access to the `VideoCapture` properties
+pub trait VideoCaptureProps: VideoCaptureTrait {
+	/// Returns the property value, `0` usually means that the property is not supported by the backend
+	#[inline]
+	fn property(&self, prop: CaptureProperty) -> Result<f64> {
+		self.get(prop.id())
+	}
+
+	/// Sets the property value, fails with `StsNotImplemented` if the backend rejects it
+	///
+	/// Note that some backends accept the value without applying it, read it back with [VideoCaptureProps::property]
+	/// if it's important.
+	fn set_property(&mut self, prop: CaptureProperty, value: f64) -> Result<()> {
+		if self.set(prop.id(), value)? {
+			Ok(())
+		} else {
+			Err(Error::new(core::StsNotImplemented, format!("Backend rejected setting {:?} to: {}", prop, value)))
+		}
+	}
+
+	/// Reads the next frame together with its [Timestamp], returns `None` at the end of the stream
+	fn read_timestamped(&mut self) -> Result<Option<(Mat, Timestamp)>> {
+		let mut frame = Mat::default();
+		if !self.read(&mut frame)? || frame.empty() {
+			return Ok(None);
+		}
+		let captured_at = Instant::now();
+		let pos_msec = self.get(videoio::CAP_PROP_POS_MSEC)?;
+		// 0 is a valid position only for the first frame of a file, otherwise it means that the position is not supported
+		let stream_time = if pos_msec.is_finite() && (pos_msec > 0. || (pos_msec == 0. && self.get(videoio::CAP_PROP_POS_FRAMES)? == 1.)) {
+			Some(Duration::from_secs_f64(pos_msec / 1000.))
+		} else {
+			None
+		};
+		Ok(Some((frame, Timestamp { captured_at, stream_time })))
+	}
+}
+
+impl<T: VideoCaptureTrait + ?Sized> VideoCaptureProps for T {}
@@ -0,0 +1,373 @@
+//! Threaded `Source -> [Stage] -> Sink` frame processing
+//!
+//! Every stage runs in its own thread (or several threads for the parallel stages), the stages are connected with the
+//! bounded channels, so a slow stage applies the backpressure up to the source instead of buffering the whole video:
+//! ```ignore
+//! let capture = videoio::VideoCapture::from_file("input.mp4", videoio::CAP_ANY)?;
+//! let report = Pipeline::new(capture)
+//!     .parallel_stage("blur", 4, |mut frame| {
+//!         let mut blurred = Mat::default();
+//!         imgproc::gaussian_blur(&frame.mat, &mut blurred, Size::new(5, 5), 0., 0., core::BORDER_DEFAULT)?;
+//!         frame.mat = blurred;
+//!         Ok(frame)
+//!     })
+//!     .run(writer)?;
+//! for stage in &report.stages {
+//!     println!("{}: {:?} per frame", stage.name, stage.mean());
+//! }
+//! ```
+//! The frames are delivered to the sequential stages and to the sink in the source order even after the parallel stages.
+
+use std::{
+	collections::BTreeMap,
+	sync::{
+		Arc,
+		atomic::{AtomicBool, Ordering},
+		mpsc::{Receiver, sync_channel, SyncSender},
+		Mutex,
+	},
+	thread::{self, JoinHandle},
+	time::{Duration, Instant},
+};
+
+use crate::{
+	core::{self, Mat},
+	Error,
+	prelude::*,
+	Result,
+	videoio::{VideoCapture, VideoWriter},
+};
+
+/// Single frame travelling through the [Pipeline]
+#[derive(Debug)]
+pub struct Frame {
+	/// Sequential number of the frame from the source starting from `0`
+	pub index: u64,
+	pub mat: Mat,
+	/// Time when the frame was received from the source
+	pub captured_at: Instant,
+}
+
+/// Producer of the [Pipeline] frames, `Ok(None)` ends the stream
+pub trait Source: Send {
+	fn next_frame(&mut self) -> Result<Option<Mat>>;
+}
+
+impl Source for VideoCapture {
+	fn next_frame(&mut self) -> Result<Option<Mat>> {
+		let mut mat = Mat::default();
+		if self.read(&mut mat)? && !mat.empty() {
+			Ok(Some(mat))
+		} else {
+			Ok(None)
+		}
+	}
+}
+
+impl<F: FnMut() -> Result<Option<Mat>> + Send> Source for F {
+	#[inline]
+	fn next_frame(&mut self) -> Result<Option<Mat>> {
+		self()
+	}
+}
+
+/// Consumer of the [Pipeline] frames, it runs in the thread calling [Pipeline::run]
+pub trait Sink {
+	fn consume(&mut self, frame: Frame) -> Result<()>;
+}
+
+impl Sink for VideoWriter {
+	#[inline]
+	fn consume(&mut self, frame: Frame) -> Result<()> {
+		self.write(&frame.mat)
+	}
+}
+
+impl<F: FnMut(Frame) -> Result<()>> Sink for F {
+	#[inline]
+	fn consume(&mut self, frame: Frame) -> Result<()> {
+		self(frame)
+	}
+}
+
+/// Processing time statistics of a single stage
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StageStats {
+	pub name: String,
+	/// Number of the processed frames
+	pub frames: u64,
+	/// Total time spent in the stage function, summed over all workers
+	pub busy: Duration,
+	/// Longest processing time of a single frame
+	pub max: Duration,
+}
+
+impl StageStats {
+	/// Mean processing time of a frame
+	pub fn mean(&self) -> Duration {
+		if self.frames == 0 {
+			Duration::default()
+		} else {
+			Duration::from_secs_f64(self.busy.as_secs_f64() / self.frames as f64)
+		}
+	}
+
+	fn record(&mut self, took: Duration) {
+		self.frames += 1;
+		self.busy += took;
+		self.max = self.max.max(took);
+	}
+}
+
+/// Result of the successful [Pipeline::run]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PipelineReport {
+	/// Number of frames consumed by the sink
+	pub frames: u64,
+	pub elapsed: Duration,
+	/// Statistics of the stages in the order they were added
+	pub stages: Vec<StageStats>,
+}
+
+impl PipelineReport {
+	/// Overall throughput in frames per second
+	pub fn fps(&self) -> f64 {
+		let secs = self.elapsed.as_secs_f64();
+		if secs > 0. {
+			self.frames as f64 / secs
+		} else {
+			0.
+		}
+	}
+}
+
+type SequentialFn = Box<dyn FnMut(Frame) -> Result<Frame> + Send>;
+type ParallelFn = Arc<dyn Fn(Frame) -> Result<Frame> + Send + Sync>;
+
+enum StageFn {
+	Sequential(SequentialFn),
+	Parallel(ParallelFn, usize),
+}
+
+struct Stage {
+	name: String,
+	f: StageFn,
+}
+
+/// Frame processing pipeline, see the [module documentation](self)
+pub struct Pipeline<S> {
+	source: S,
+	stages: Vec<Stage>,
+	capacity: usize,
+}
+
+impl<S: Source + 'static> Pipeline<S> {
+	pub fn new(source: S) -> Self {
+		Self { source, stages: vec![], capacity: 4 }
+	}
+
+	/// Sets the number of frames that can wait between 2 neighboring stages, `4` by default
+	#[inline]
+	pub fn with_capacity(mut self, capacity: usize) -> Self {
+		self.capacity = capacity.max(1);
+		self
+	}
+
+	/// Adds the stage that processes the frames one by one in the source order, it can keep the state between the frames
+	pub fn stage(mut self, name: impl Into<String>, f: impl FnMut(Frame) -> Result<Frame> + Send + 'static) -> Self {
+		self.stages.push(Stage { name: name.into(), f: StageFn::Sequential(Box::new(f)) });
+		self
+	}
+
+	/// Adds the stage that processes up to `workers` frames simultaneously
+	pub fn parallel_stage(mut self, name: impl Into<String>, workers: usize, f: impl Fn(Frame) -> Result<Frame> + Send + Sync + 'static) -> Self {
+		self.stages.push(Stage { name: name.into(), f: StageFn::Parallel(Arc::new(f), workers.max(1)) });
+		self
+	}
+
+	/// Runs the pipeline until the source is exhausted and feeds the results into `sink`
+	///
+	/// The first error returned by the source, any of the stages or the sink stops the whole pipeline and is returned.
+	pub fn run(self, mut sink: impl Sink) -> Result<PipelineReport> {
+		let start = Instant::now();
+		let state = Arc::new(SharedState { stop: AtomicBool::new(false), error: Mutex::new(None) });
+		let mut handles = Vec::<JoinHandle<()>>::new();
+
+		let (tx, mut rx) = sync_channel::<Frame>(self.capacity);
+		handles.push(spawn_source(self.source, tx, Arc::clone(&state)));
+
+		let mut stats = Vec::with_capacity(self.stages.len());
+		for stage in self.stages {
+			let stage_stats = Arc::new(Mutex::new(StageStats { name: stage.name, ..StageStats::default() }));
+			let (tx, next_rx) = sync_channel::<Frame>(self.capacity);
+			match stage.f {
+				StageFn::Sequential(mut f) => {
+					let (state, stage_stats) = (Arc::clone(&state), Arc::clone(&stage_stats));
+					handles.push(thread::spawn(move || {
+						for frame in InOrder::new(rx) {
+							if !run_stage(&mut f, frame, &tx, &state, &stage_stats) {
+								break;
+							}
+						}
+					}));
+				}
+				StageFn::Parallel(f, workers) => {
+					let rx = Arc::new(Mutex::new(rx));
+					for _ in 0..workers {
+						let (f, rx, tx, state, stage_stats) = (Arc::clone(&f), Arc::clone(&rx), tx.clone(), Arc::clone(&state), Arc::clone(&stage_stats));
+						handles.push(thread::spawn(move || loop {
+							let frame = match rx.lock() {
+								Ok(rx) => rx.recv(),
+								Err(_) => break,
+							};
+							match frame {
+								Ok(frame) => {
+									if !run_stage(&mut |frame| f(frame), frame, &tx, &state, &stage_stats) {
+										break;
+									}
+								}
+								Err(_) => break,
+							}
+						}));
+					}
+				}
+			}
+			stats.push(stage_stats);
+			rx = next_rx;
+		}
+
+		let mut frames = 0;
+		for frame in InOrder::new(rx) {
+			if state.stopped() {
+				break;
+			}
+			if let Err(e) = sink.consume(frame) {
+				state.fail(e);
+				break;
+			}
+			frames += 1;
+		}
+		state.stop.store(true, Ordering::SeqCst);
+		for handle in handles {
+			if handle.join().is_err() {
+				state.fail(Error::new(core::StsError, "Pipeline thread panicked"));
+			}
+		}
+		if let Some(e) = state.error.lock().ok().and_then(|mut e| e.take()) {
+			return Err(e);
+		}
+		Ok(PipelineReport {
+			frames,
+			elapsed: start.elapsed(),
+			stages: stats.into_iter()
+				.map(|s| s.lock().map(|s| s.clone()).unwrap_or_default())
+				.collect(),
+		})
+	}
+}
+
+struct SharedState {
+	stop: AtomicBool,
+	/// First error that stopped the pipeline
+	error: Mutex<Option<Error>>,
+}
+
+impl SharedState {
+	fn stopped(&self) -> bool {
+		self.stop.load(Ordering::SeqCst)
+	}
+
+	fn fail(&self, e: Error) {
+		self.stop.store(true, Ordering::SeqCst);
+		if let Ok(mut error) = self.error.lock() {
+			error.get_or_insert(e);
+		}
+	}
+}
+
+fn spawn_source(mut source: impl Source + 'static, tx: SyncSender<Frame>, state: Arc<SharedState>) -> JoinHandle<()> {
+	thread::spawn(move || {
+		let mut index = 0;
+		while !state.stopped() {
+			match source.next_frame() {
+				Ok(Some(mat)) => {
+					if tx.send(Frame { index, mat, captured_at: Instant::now() }).is_err() {
+						break;
+					}
+					index += 1;
+				}
+				Ok(None) => break,
+				Err(e) => {
+					state.fail(e);
+					break;
+				}
+			}
+		}
+	})
+}
+
+/// Runs the stage function on `frame` and passes the result on, returns `false` if the stage must stop
+fn run_stage(f: &mut dyn FnMut(Frame) -> Result<Frame>, frame: Frame, tx: &SyncSender<Frame>, state: &SharedState, stats: &Mutex<StageStats>) -> bool {
+	if state.stopped() {
+		return false;
+	}
+	let index = frame.index;
+	let start = Instant::now();
+	let res = f(frame);
+	let took = start.elapsed();
+	if let Ok(mut stats) = stats.lock() {
+		stats.record(took);
+	}
+	match res {
+		Ok(mut frame) => {
+			// the stage must not renumber the frames, the ordering of the downstream stages relies on it
+			frame.index = index;
+			tx.send(frame).is_ok()
+		}
+		Err(e) => {
+			state.fail(e);
+			false
+		}
+	}
+}
+
+/// Restores the source order of the frames arriving from the parallel stages
+struct InOrder {
+	rx: Receiver<Frame>,
+	next: u64,
+	pending: BTreeMap<u64, Frame>,
+}
+
+impl InOrder {
+	fn new(rx: Receiver<Frame>) -> Self {
+		Self { rx, next: 0, pending: BTreeMap::new() }
+	}
+}
+
+impl Iterator for InOrder {
+	type Item = Frame;
+
+	fn next(&mut self) -> Option<Frame> {
+		loop {
+			if let Some(frame) = self.pending.remove(&self.next) {
+				self.next += 1;
+				return Some(frame);
+			}
+			match self.rx.recv() {
+				Ok(frame) if frame.index == self.next => {
+					self.next += 1;
+					return Some(frame);
+				}
+				Ok(frame) => {
+					self.pending.insert(frame.index, frame);
+				}
+				Err(_) => {
+					// upstream has stopped, flush whatever is left in order
+					let index = *self.pending.keys().next()?;
+					self.next = index + 1;
+					return self.pending.remove(&index);
+				}
+			}
+		}
+	}
+}
@@ -0,0 +1,264 @@
+use std::{
+	fmt,
+	thread,
+	time::{Duration, Instant},
+};
+
+use crate::{
+	core::{self, Mat},
+	Error,
+	prelude::*,
+	Result,
+	videoio::{pipeline::Source, VideoCapture},
+};
+
+/// Connection state change reported by [ResilientCapture]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CaptureEvent {
+	/// Stream was opened, `attempt` is `0` if it succeeded on the first try
+	Connected { attempt: u32 },
+	/// No frame was received for longer than the stale timeout, the stream is going to be reopened
+	Stale { since_last_frame: Duration },
+	/// Waiting for `delay` before the reconnection `attempt`
+	Reconnecting { attempt: u32, delay: Duration },
+	/// Reconnection `attempt` failed
+	ConnectFailed { attempt: u32 },
+	/// Maximum number of the reconnection attempts was reached, reading fails from now on
+	GaveUp { attempts: u32 },
+}
+
+/// Reconnection and health check settings of [ResilientCapture]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReconnectPolicy {
+	/// Delay before the first reconnection attempt
+	pub initial_backoff: Duration,
+	/// Upper bound of the delay between the attempts
+	pub max_backoff: Duration,
+	/// Multiplier of the delay after every failed attempt
+	pub backoff_factor: f64,
+	/// Number of the reconnection attempts in a row after which reading fails, `None` to retry forever
+	pub max_attempts: Option<u32>,
+	/// Stream is considered stale if it doesn't produce a frame within this time
+	pub stale_timeout: Duration,
+}
+
+impl Default for ReconnectPolicy {
+	fn default() -> Self {
+		Self {
+			initial_backoff: Duration::from_millis(500),
+			max_backoff: Duration::from_secs(30),
+			backoff_factor: 2.,
+			max_attempts: None,
+			stale_timeout: Duration::from_secs(5),
+		}
+	}
+}
+
+/// `VideoCapture` for the network streams (RTSP, HTTP) that survives the connection drops
+///
+/// Failed reads are retried on the same connection until the stale timeout, after that the stream is reopened with the
+/// exponential backoff between the attempts:
+/// ```ignore
+/// let mut cap = videoio::ResilientCapture::new("rtsp://camera/stream", videoio::CAP_FFMPEG)
+///     .with_stale_timeout(Duration::from_secs(3))
+///     .on_event(|e| eprintln!("camera: {:?}", e));
+/// let mut frame = Mat::default();
+/// while cap.read(&mut frame)? {
+///     // ...
+/// }
+/// ```
+/// Note that a single `read()` can block inside the backend, the blocking time is controlled by the backend timeouts
+/// (e.g. `CAP_PROP_READ_TIMEOUT_MSEC` or `OPENCV_FFMPEG_CAPTURE_OPTIONS` for FFmpeg).
+pub struct ResilientCapture {
+	url: String,
+	api_preference: i32,
+	policy: ReconnectPolicy,
+	capture: Option<VideoCapture>,
+	on_event: Option<Box<dyn FnMut(CaptureEvent) + Send>>,
+	last_frame: Instant,
+	connected_once: bool,
+	gave_up: bool,
+	reconnects: u32,
+}
+
+impl ResilientCapture {
+	/// Creates the capture for `url`, the stream is opened on the first read
+	pub fn new(url: impl Into<String>, api_preference: i32) -> Self {
+		Self {
+			url: url.into(),
+			api_preference,
+			policy: ReconnectPolicy::default(),
+			capture: None,
+			on_event: None,
+			last_frame: Instant::now(),
+			connected_once: false,
+			gave_up: false,
+			reconnects: 0,
+		}
+	}
+
+	#[inline]
+	pub fn with_policy(mut self, policy: ReconnectPolicy) -> Self {
+		self.policy = policy;
+		self
+	}
+
+	/// Sets the initial and the maximum delay between the reconnection attempts
+	#[inline]
+	pub fn with_backoff(mut self, initial: Duration, max: Duration) -> Self {
+		self.policy.initial_backoff = initial;
+		self.policy.max_backoff = max;
+		self
+	}
+
+	#[inline]
+	pub fn with_max_attempts(mut self, max_attempts: Option<u32>) -> Self {
+		self.policy.max_attempts = max_attempts;
+		self
+	}
+
+	#[inline]
+	pub fn with_stale_timeout(mut self, stale_timeout: Duration) -> Self {
+		self.policy.stale_timeout = stale_timeout;
+		self
+	}
+
+	/// Sets the callback that receives the connection state changes
+	pub fn on_event(mut self, callback: impl FnMut(CaptureEvent) + Send + 'static) -> Self {
+		self.on_event = Some(Box::new(callback));
+		self
+	}
+
+	#[inline]
+	pub fn url(&self) -> &str {
+		&self.url
+	}
+
+	#[inline]
+	pub fn policy(&self) -> &ReconnectPolicy {
+		&self.policy
+	}
+
+	/// Returns `true` if the stream is currently open
+	#[inline]
+	pub fn is_connected(&self) -> bool {
+		self.capture.is_some()
+	}
+
+	/// Number of the successful reconnections after the initial connection
+	#[inline]
+	pub fn reconnects(&self) -> u32 {
+		self.reconnects
+	}
+
+	/// Time since the last successfully read frame or since the stream was opened
+	#[inline]
+	pub fn since_last_frame(&self) -> Duration {
+		self.last_frame.elapsed()
+	}
+
+	/// Returns the underlying capture if it's connected, e.g. to query the stream properties
+	#[inline]
+	pub fn capture(&self) -> Option<&VideoCapture> {
+		self.capture.as_ref()
+	}
+
+	/// Reads the next frame reconnecting as necessary
+	///
+	/// Returns `Ok(true)` with the frame in `frame`, fails when `max_attempts` reconnection attempts in a row failed and
+	/// on every subsequent call. It never returns `Ok(false)`, the signature follows `VideoCapture::read()`.
+	pub fn read(&mut self, frame: &mut Mat) -> Result<bool> {
+		loop {
+			if self.capture.is_none() {
+				self.connect()?;
+			}
+			if let Some(capture) = &mut self.capture {
+				if capture.read(frame)? && !frame.empty() {
+					self.last_frame = Instant::now();
+					return Ok(true);
+				}
+			}
+			let since_last_frame = self.last_frame.elapsed();
+			if since_last_frame >= self.policy.stale_timeout {
+				self.emit(CaptureEvent::Stale { since_last_frame });
+				self.disconnect()?;
+			} else {
+				thread::sleep(Duration::from_millis(10).min(self.policy.stale_timeout - since_last_frame));
+			}
+		}
+	}
+
+	/// Closes the stream, it's reopened on the next read
+	pub fn disconnect(&mut self) -> Result<()> {
+		if let Some(mut capture) = self.capture.take() {
+			capture.release()?;
+		}
+		Ok(())
+	}
+
+	/// Opens the stream, retrying with the backoff according to the policy
+	fn connect(&mut self) -> Result<()> {
+		if self.gave_up {
+			return Err(Error::new(core::StsError, format!("Gave up reconnecting to: {}", self.url)));
+		}
+		// the initial connection is tried once right away, the reconnection starts with the backoff
+		let mut attempt = if self.connected_once { 1 } else { 0 };
+		let mut delay = self.policy.initial_backoff;
+		loop {
+			if attempt > 0 {
+				if let Some(max_attempts) = self.policy.max_attempts {
+					if attempt > max_attempts {
+						self.gave_up = true;
+						self.emit(CaptureEvent::GaveUp { attempts: max_attempts });
+						return Err(Error::new(core::StsError, format!("Failed to connect to: {} after {} attempts", self.url, max_attempts)));
+					}
+				}
+				self.emit(CaptureEvent::Reconnecting { attempt, delay });
+				thread::sleep(delay);
+				delay = delay.mul_f64(self.policy.backoff_factor).min(self.policy.max_backoff);
+			}
+			// failing to open is just another failed attempt, not an error on its own
+			match VideoCapture::from_file(&self.url, self.api_preference) {
+				Ok(capture) if capture.is_opened().unwrap_or(false) => {
+					if self.connected_once {
+						self.reconnects += 1;
+					}
+					self.connected_once = true;
+					self.capture = Some(capture);
+					self.last_frame = Instant::now();
+					self.emit(CaptureEvent::Connected { attempt });
+					return Ok(());
+				}
+				_ => {
+					self.emit(CaptureEvent::ConnectFailed { attempt });
+					attempt += 1;
+				}
+			}
+		}
+	}
+
+	fn emit(&mut self, event: CaptureEvent) {
+		if let Some(on_event) = &mut self.on_event {
+			on_event(event);
+		}
+	}
+}
+
+impl fmt::Debug for ResilientCapture {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("ResilientCapture")
+			.field("url", &self.url)
+			.field("api_preference", &self.api_preference)
+			.field("policy", &self.policy)
+			.field("connected", &self.is_connected())
+			.field("reconnects", &self.reconnects)
+			.finish()
+	}
+}
+
+impl Source for ResilientCapture {
+	fn next_frame(&mut self) -> Result<Option<Mat>> {
+		let mut mat = Mat::default();
+		Ok(if self.read(&mut mat)? { Some(mat) } else { None })
+	}
+}
@@ -0,0 +1,35 @@
+use crate::{
+	core::{Mat, Serial, SerialContext},
+	prelude::*,
+	Result,
+	videoio::VideoCapture,
+};
+
+/// `VideoCapture` that is opened, read and released on the [SerialContext::global] thread
+///
+/// Some capture backends (e.g. MSMF, AVFoundation) misbehave when the same capture is used from different threads, this
+/// wrapper can be shared between threads (e.g. in an `Arc`) and keeps all the calls on the same one. Use
+/// [Serial::with] to access the rest of the `VideoCapture` API.
+pub type SerialVideoCapture = Serial<VideoCapture>;
+
+impl Serial<VideoCapture> {
+	/// Opens the camera with the specified `index`, see [VideoCapture::new]
+	pub fn open_camera(index: i32, api_preference: i32) -> Result<Self> {
+		Self::new(SerialContext::global(), move || VideoCapture::new(index, api_preference))
+	}
+
+	/// Opens the video file or stream, see [VideoCapture::from_file]
+	pub fn open_file(filename: &str, api_preference: i32) -> Result<Self> {
+		Self::new(SerialContext::global(), move || VideoCapture::from_file(filename, api_preference))
+	}
+
+	/// Same as [VideoCaptureTrait::read]
+	pub fn read(&self, image: &mut Mat) -> Result<bool> {
+		self.with(move |cap| cap.read(image))
+	}
+
+	/// Same as [VideoCaptureTraitConst::is_opened]
+	pub fn is_opened(&self) -> Result<bool> {
+		self.with(|cap| cap.is_opened())
+	}
+}
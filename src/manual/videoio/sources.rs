@@ -0,0 +1,287 @@
+//! Frame sources that can stand in for `VideoCapture`
+//!
+//! All sources provide `read()` with the same semantics as `VideoCapture::read()` and implement
+//! [pipeline::Source](super::pipeline::Source), so the pipelines can be tested headlessly with [SyntheticPattern] or
+//! [ImageSequence] and switched to a camera or to [ScreenCapture] without changes.
+
+use std::path::{Path, PathBuf};
+
+use crate::{
+	core::{self, Mat, Rect, Scalar, Size},
+	Error,
+	prelude::*,
+	Result,
+	videoio::{self, pipeline::Source, VideoCapture},
+};
+
+/// Captures the desktop using the GStreamer backend of `VideoCapture`
+///
+/// Requires OpenCV built with GStreamer and the platform screen capture plugin: `ximagesrc` on Linux (X11),
+/// `d3d11screencapturesrc` on Windows, `avfvideosrc` on macOS.
+pub struct ScreenCapture {
+	capture: VideoCapture,
+	region: Option<Rect>,
+}
+
+impl ScreenCapture {
+	/// Starts capturing the whole primary screen
+	pub fn new() -> Result<Self> {
+		Self::from_gstreamer_pipeline(&format!("{} ! videoconvert ! video/x-raw,format=BGR ! appsink drop=true max-buffers=1", Self::platform_source()))
+	}
+
+	/// Starts capturing with the custom GStreamer pipeline, it must end with `appsink`
+	pub fn from_gstreamer_pipeline(pipeline: &str) -> Result<Self> {
+		let capture = VideoCapture::from_file(pipeline, videoio::CAP_GSTREAMER)?;
+		if !capture.is_opened()? {
+			return Err(Error::new(core::StsNotImplemented, format!("Can't open the screen capture pipeline, OpenCV must be built with GStreamer: {}", pipeline)));
+		}
+		Ok(Self { capture, region: None })
+	}
+
+	/// Crops the captured frames to `region` of the screen
+	#[inline]
+	pub fn with_region(mut self, region: Rect) -> Self {
+		self.region = Some(region);
+		self
+	}
+
+	pub fn read(&mut self, frame: &mut Mat) -> Result<bool> {
+		if !self.capture.read(frame)? || frame.empty() {
+			return Ok(false);
+		}
+		if let Some(region) = self.region {
+			*frame = Mat::roi(frame, region)?.try_clone()?;
+		}
+		Ok(true)
+	}
+
+	fn platform_source() -> &'static str {
+		if cfg!(target_os = "windows") {
+			"d3d11screencapturesrc"
+		} else if cfg!(target_os = "macos") {
+			"avfvideosrc capture-screen=true"
+		} else {
+			"ximagesrc use-damage=false"
+		}
+	}
+}
+
+/// Reads the images from the list of files as the consecutive frames
+#[cfg(ocvrs_has_module_imgcodecs)]
+#[derive(Clone, Debug)]
+pub struct ImageSequence {
+	paths: Vec<PathBuf>,
+	position: usize,
+	looped: bool,
+	flags: i32,
+}
+
+#[cfg(ocvrs_has_module_imgcodecs)]
+impl ImageSequence {
+	pub fn new(paths: impl IntoIterator<Item=impl Into<PathBuf>>) -> Self {
+		Self { paths: paths.into_iter().map(Into::into).collect(), position: 0, looped: false, flags: crate::imgcodecs::IMREAD_COLOR }
+	}
+
+	/// Lists the files in `dir` with one of the `extensions` (case-insensitive, without the dot) sorted by name
+	pub fn from_dir(dir: impl AsRef<Path>, extensions: &[&str]) -> Result<Self> {
+		let dir = dir.as_ref();
+		let entries = dir.read_dir()
+			.map_err(|e| Error::new(core::StsError, format!("Can't list directory: {}: {}", dir.display(), e)))?;
+		let mut paths = entries
+			.filter_map(|entry| entry.ok().map(|entry| entry.path()))
+			.filter(|path| {
+				path.extension()
+					.and_then(|ext| ext.to_str())
+					.map(|ext| extensions.iter().any(|e| e.eq_ignore_ascii_case(ext)))
+					.unwrap_or(false)
+			})
+			.collect::<Vec<_>>();
+		paths.sort();
+		Ok(Self::new(paths))
+	}
+
+	/// Restarts from the first image after the last one
+	#[inline]
+	pub fn looped(mut self, looped: bool) -> Self {
+		self.looped = looped;
+		self
+	}
+
+	/// Sets the `imgcodecs::IMREAD_*` flags, `IMREAD_COLOR` by default
+	#[inline]
+	pub fn with_flags(mut self, flags: i32) -> Self {
+		self.flags = flags;
+		self
+	}
+
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.paths.len()
+	}
+
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.paths.is_empty()
+	}
+
+	/// Fails if the image can't be read
+	pub fn read(&mut self, frame: &mut Mat) -> Result<bool> {
+		if self.position >= self.paths.len() {
+			if !self.looped || self.paths.is_empty() {
+				return Ok(false);
+			}
+			self.position = 0;
+		}
+		let path = &self.paths[self.position];
+		let path_str = path.to_str()
+			.ok_or_else(|| Error::new(core::StsBadArg, format!("Path is not valid UTF-8: {}", path.display())))?;
+		let image = crate::imgcodecs::imread(path_str, self.flags)?;
+		if image.empty() {
+			return Err(Error::new(core::StsError, format!("Can't read image: {}", path.display())));
+		}
+		*frame = image;
+		self.position += 1;
+		Ok(true)
+	}
+}
+
+/// Kind of the generated [SyntheticPattern] frames
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Pattern {
+	Solid(Scalar),
+	/// 8 vertical bars of white, yellow, cyan, green, magenta, red, blue and black
+	ColorBars,
+	/// Checkerboard with squares of the given size
+	Checkerboard(i32),
+	/// White square of the given size moving diagonally over black background, useful for testing the trackers
+	MovingBox(i32),
+	/// Uniform noise, deterministic for the given seed
+	Noise(u64),
+}
+
+/// Generates the `CV_8UC3` test frames
+#[cfg(ocvrs_has_module_imgproc)]
+#[derive(Clone, Debug)]
+pub struct SyntheticPattern {
+	size: Size,
+	pattern: Pattern,
+	limit: Option<u64>,
+	index: u64,
+	rng: u64,
+}
+
+#[cfg(ocvrs_has_module_imgproc)]
+impl SyntheticPattern {
+	/// Creates the endless source of frames of `size`
+	pub fn new(size: Size, pattern: Pattern) -> Self {
+		let rng = match pattern {
+			Pattern::Noise(seed) => seed | 1,
+			_ => 1,
+		};
+		Self { size, pattern, limit: None, index: 0, rng }
+	}
+
+	/// Ends the stream after `frames` frames
+	#[inline]
+	pub fn with_limit(mut self, frames: u64) -> Self {
+		self.limit = Some(frames);
+		self
+	}
+
+	/// Index of the next frame
+	#[inline]
+	pub fn position(&self) -> u64 {
+		self.index
+	}
+
+	pub fn read(&mut self, frame: &mut Mat) -> Result<bool> {
+		if matches!(self.limit, Some(limit) if self.index >= limit) {
+			return Ok(false);
+		}
+		*frame = self.generate()?;
+		self.index += 1;
+		Ok(true)
+	}
+
+	fn generate(&mut self) -> Result<Mat> {
+		use crate::imgproc;
+
+		let Size { width, height } = self.size;
+		let mut out = Mat::new_size_with_default(self.size, core::CV_8UC3, Scalar::all(0.))?;
+		match self.pattern {
+			Pattern::Solid(color) => {
+				out.set_to(&color, &core::no_array())?;
+			}
+			Pattern::ColorBars => {
+				let colors = [
+					(255., 255., 255.), (0., 255., 255.), (255., 255., 0.), (0., 255., 0.),
+					(255., 0., 255.), (0., 0., 255.), (255., 0., 0.), (0., 0., 0.),
+				];
+				for (i, &(b, g, r)) in colors.iter().enumerate() {
+					let x1 = width * i as i32 / colors.len() as i32;
+					let x2 = width * (i as i32 + 1) / colors.len() as i32;
+					imgproc::rectangle(&mut out, Rect::new(x1, 0, x2 - x1, height), Scalar::new(b, g, r, 0.), -1, imgproc::LINE_8, 0)?;
+				}
+			}
+			Pattern::Checkerboard(square) => {
+				let square = square.max(1);
+				for y in (0..height).step_by(square as usize) {
+					for x in (0..width).step_by(square as usize) {
+						if (x / square + y / square) % 2 == 0 {
+							imgproc::rectangle(&mut out, Rect::new(x, y, square, square), Scalar::all(255.), -1, imgproc::LINE_8, 0)?;
+						}
+					}
+				}
+			}
+			Pattern::MovingBox(box_size) => {
+				let (range_x, range_y) = ((width - box_size).max(1), (height - box_size).max(1));
+				let x = bounce(self.index as i64, i64::from(range_x)) as i32;
+				let y = bounce(self.index as i64, i64::from(range_y)) as i32;
+				imgproc::rectangle(&mut out, Rect::new(x, y, box_size, box_size), Scalar::all(255.), -1, imgproc::LINE_8, 0)?;
+			}
+			Pattern::Noise(_) => {
+				for row in 0..height {
+					for pixel in out.at_row_mut::<core::Vec3b>(row)? {
+						self.rng ^= self.rng << 13;
+						self.rng ^= self.rng >> 7;
+						self.rng ^= self.rng << 17;
+						let bytes = self.rng.to_le_bytes();
+						*pixel = core::Vec3b::from([bytes[0], bytes[1], bytes[2]]);
+					}
+				}
+			}
+		}
+		Ok(out)
+	}
+}
+
+/// Position moving back and forth over `0..=range`
+#[cfg(ocvrs_has_module_imgproc)]
+fn bounce(step: i64, range: i64) -> i64 {
+	let period = range * 2;
+	let phase = step % period;
+	if phase <= range { phase } else { period - phase }
+}
+
+impl Source for ScreenCapture {
+	fn next_frame(&mut self) -> Result<Option<Mat>> {
+		let mut frame = Mat::default();
+		Ok(if self.read(&mut frame)? { Some(frame) } else { None })
+	}
+}
+
+#[cfg(ocvrs_has_module_imgcodecs)]
+impl Source for ImageSequence {
+	fn next_frame(&mut self) -> Result<Option<Mat>> {
+		let mut frame = Mat::default();
+		Ok(if self.read(&mut frame)? { Some(frame) } else { None })
+	}
+}
+
+#[cfg(ocvrs_has_module_imgproc)]
+impl Source for SyntheticPattern {
+	fn next_frame(&mut self) -> Result<Option<Mat>> {
+		let mut frame = Mat::default();
+		Ok(if self.read(&mut frame)? { Some(frame) } else { None })
+	}
+}
@@ -0,0 +1,485 @@
+//! Direct access to the Video4Linux2 devices for the formats and controls that `VideoCapture` can't report
+//!
+//! `VideoCapture::set()` with `CAP_V4L2` often returns `true` for the values that the driver then silently clamps or
+//! ignores. [V4l2Device] queries the real ranges from the driver and validates the values before setting them:
+//! ```ignore
+//! let mut dev = v4l2::V4l2Device::open_index(0)?;
+//! for format in dev.formats()? {
+//!     println!("{} {:?}", format.fourcc_str(), format.sizes);
+//! }
+//! dev.set(v4l2::Control::AutoExposure, v4l2::AUTO_EXPOSURE_MANUAL)?;
+//! dev.set(v4l2::Control::ExposureAbsolute, 150)?;
+//! ```
+//! The device can stay open alongside the `VideoCapture` that's reading from it.
+
+use std::{
+	ffi::CString,
+	fmt,
+	io,
+	mem,
+	os::raw::{c_int, c_ulong, c_void},
+	path::{Path, PathBuf},
+};
+
+use crate::{
+	core,
+	Error,
+	Result,
+};
+
+/// Value of [Control::AutoExposure] for the manual exposure
+pub const AUTO_EXPOSURE_MANUAL: i32 = 1;
+/// Value of [Control::AutoExposure] for the fully automatic exposure
+pub const AUTO_EXPOSURE_AUTO: i32 = 0;
+/// Value of [Control::AutoExposure] for the automatic exposure with the manual iris
+pub const AUTO_EXPOSURE_APERTURE_PRIORITY: i32 = 3;
+
+const CID_BASE: u32 = 0x0098_0900;
+const CID_CAMERA_CLASS_BASE: u32 = 0x009a_0900;
+
+/// Camera controls that are commonly supported by the UVC webcams
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Control {
+	Brightness,
+	Contrast,
+	Saturation,
+	Hue,
+	Gamma,
+	Gain,
+	Sharpness,
+	BacklightCompensation,
+	/// Boolean, enables the automatic white balance
+	AutoWhiteBalance,
+	/// In Kelvin, usually requires `AutoWhiteBalance` to be off
+	WhiteBalanceTemperature,
+	/// Menu, see `AUTO_EXPOSURE_*` constants
+	AutoExposure,
+	/// In units of 100 µs, usually requires `AutoExposure` to be `AUTO_EXPOSURE_MANUAL`
+	ExposureAbsolute,
+	/// Boolean, enables the continuous autofocus
+	AutoFocus,
+	FocusAbsolute,
+	/// Any other control by its `V4L2_CID_*` id
+	Other(u32),
+}
+
+impl Control {
+	/// Returns the `V4L2_CID_*` id of the control
+	pub fn id(self) -> u32 {
+		match self {
+			Control::Brightness => CID_BASE,
+			Control::Contrast => CID_BASE + 1,
+			Control::Saturation => CID_BASE + 2,
+			Control::Hue => CID_BASE + 3,
+			Control::AutoWhiteBalance => CID_BASE + 12,
+			Control::Gamma => CID_BASE + 16,
+			Control::Gain => CID_BASE + 19,
+			Control::WhiteBalanceTemperature => CID_BASE + 26,
+			Control::Sharpness => CID_BASE + 27,
+			Control::BacklightCompensation => CID_BASE + 28,
+			Control::AutoExposure => CID_CAMERA_CLASS_BASE + 1,
+			Control::ExposureAbsolute => CID_CAMERA_CLASS_BASE + 2,
+			Control::FocusAbsolute => CID_CAMERA_CLASS_BASE + 10,
+			Control::AutoFocus => CID_CAMERA_CLASS_BASE + 12,
+			Control::Other(id) => id,
+		}
+	}
+}
+
+/// Kind of the control value
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ControlKind {
+	Integer,
+	Boolean,
+	Menu,
+	IntegerMenu,
+	Button,
+	/// Types that can't be set with a single `i32` value
+	Other(u32),
+}
+
+impl ControlKind {
+	fn from_raw(typ: u32) -> Self {
+		match typ {
+			1 => ControlKind::Integer,
+			2 => ControlKind::Boolean,
+			3 => ControlKind::Menu,
+			4 => ControlKind::Button,
+			9 => ControlKind::IntegerMenu,
+			other => ControlKind::Other(other),
+		}
+	}
+}
+
+/// Range of the valid control values as reported by the driver
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ControlRange {
+	pub control: Control,
+	/// Driver provided name of the control
+	pub name: String,
+	pub kind: ControlKind,
+	pub min: i32,
+	pub max: i32,
+	/// Valid values are `min + n * step`, it's `1` if the driver reports `0`
+	pub step: i32,
+	pub default: i32,
+	pub read_only: bool,
+	/// Control can't be changed in the current state, e.g. the exposure when the auto exposure is on
+	pub inactive: bool,
+}
+
+impl ControlRange {
+	/// Checks that `value` can be set, returns the `StsOutOfRange` error with the valid range otherwise
+	pub fn validate(&self, value: i32) -> Result<()> {
+		if self.read_only {
+			return Err(Error::new(core::StsError, format!("Control {} is read-only", self.name)));
+		}
+		if value < self.min || value > self.max {
+			return Err(Error::new(core::StsOutOfRange, format!("Value {} of control {} is out of range: [{}, {}]", value, self.name, self.min, self.max)));
+		}
+		if (i64::from(value) - i64::from(self.min)) % i64::from(self.step) != 0 {
+			return Err(Error::new(core::StsOutOfRange, format!("Value {} of control {} is not a multiple of step {} from {}", value, self.name, self.step, self.min)));
+		}
+		Ok(())
+	}
+
+	/// Returns the closest valid value to `value`
+	pub fn clamp(&self, value: i32) -> i32 {
+		let value = i64::from(value.max(self.min).min(self.max));
+		let (min, step) = (i64::from(self.min), i64::from(self.step));
+		let snapped = min + (value - min + step / 2) / step * step;
+		snapped.min(i64::from(self.max)) as i32
+	}
+}
+
+/// Frame rate as a fraction, e.g. `30/1` or `30000/1001`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FrameRate {
+	pub numerator: u32,
+	pub denominator: u32,
+}
+
+impl FrameRate {
+	/// Frames per second
+	pub fn fps(self) -> f64 {
+		if self.denominator == 0 {
+			0.
+		} else {
+			f64::from(self.numerator) / f64::from(self.denominator)
+		}
+	}
+}
+
+impl fmt::Display for FrameRate {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if self.denominator == 1 {
+			write!(f, "{}", self.numerator)
+		} else {
+			write!(f, "{}/{}", self.numerator, self.denominator)
+		}
+	}
+}
+
+/// Frame rates supported for a single resolution
+#[derive(Clone, Debug, PartialEq)]
+pub enum FrameRates {
+	Discrete(Vec<FrameRate>),
+	/// Any rate between `min` and `max`
+	Stepwise { min: FrameRate, max: FrameRate },
+}
+
+/// Resolution supported for a pixel format
+#[derive(Clone, Debug, PartialEq)]
+pub struct FrameSize {
+	pub width: u32,
+	pub height: u32,
+	pub rates: FrameRates,
+}
+
+/// Pixel format supported by the device
+#[derive(Clone, Debug, PartialEq)]
+pub struct PixelFormat {
+	/// FourCC code, same as used by `CAP_PROP_FOURCC`
+	pub fourcc: u32,
+	pub description: String,
+	pub compressed: bool,
+	/// Discrete resolutions, for the devices with stepwise sizes it contains the min and the max sizes
+	pub sizes: Vec<FrameSize>,
+}
+
+impl PixelFormat {
+	/// Returns the FourCC code as a string, e.g. `"MJPG"` or `"YUYV"`
+	pub fn fourcc_str(&self) -> String {
+		self.fourcc.to_le_bytes().iter().map(|&b| char::from(b)).collect()
+	}
+}
+
+/// Open V4L2 device, see the [module documentation](self)
+#[derive(Debug)]
+pub struct V4l2Device {
+	path: PathBuf,
+	fd: c_int,
+}
+
+impl V4l2Device {
+	/// Opens the device, e.g. `/dev/video0`
+	pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+		let path = path.as_ref();
+		let c_path = CString::new(path.to_string_lossy().as_bytes())
+			.map_err(|_| Error::new(core::StsBadArg, format!("Invalid device path: {}", path.display())))?;
+		let fd = unsafe { libc::open(c_path.as_ptr(), libc::O_RDWR | libc::O_NONBLOCK | libc::O_CLOEXEC) };
+		if fd < 0 {
+			return Err(io_error(&format!("Can't open V4L2 device: {}", path.display())));
+		}
+		Ok(Self { path: path.to_path_buf(), fd })
+	}
+
+	/// Opens `/dev/video{index}`, same index as used by `VideoCapture::new(index, CAP_V4L2)`
+	#[inline]
+	pub fn open_index(index: u32) -> Result<Self> {
+		Self::open(format!("/dev/video{}", index))
+	}
+
+	#[inline]
+	pub fn path(&self) -> &Path {
+		&self.path
+	}
+
+	/// Lists the pixel formats with their resolutions and frame rates
+	pub fn formats(&self) -> Result<Vec<PixelFormat>> {
+		let mut out = vec![];
+		for index in 0.. {
+			let mut desc = FmtDesc { index, typ: BUF_TYPE_VIDEO_CAPTURE, ..FmtDesc::default() };
+			if !self.ioctl_enum(VIDIOC_ENUM_FMT, &mut desc)? {
+				break;
+			}
+			out.push(PixelFormat {
+				fourcc: desc.pixelformat,
+				description: c_str(&desc.description),
+				compressed: desc.flags & FMT_FLAG_COMPRESSED != 0,
+				sizes: self.frame_sizes(desc.pixelformat)?,
+			});
+		}
+		Ok(out)
+	}
+
+	/// Returns the range of the control, `StsNotImplemented` error if the device doesn't have it
+	pub fn range(&self, control: Control) -> Result<ControlRange> {
+		let mut query = QueryCtrl { id: control.id(), ..QueryCtrl::default() };
+		if !self.ioctl_enum(VIDIOC_QUERYCTRL, &mut query)? || query.flags & CTRL_FLAG_DISABLED != 0 {
+			return Err(Error::new(core::StsNotImplemented, format!("Device {} doesn't support control: {:?}", self.path.display(), control)));
+		}
+		Ok(ControlRange {
+			control,
+			name: c_str(&query.name),
+			kind: ControlKind::from_raw(query.typ),
+			min: query.minimum,
+			max: query.maximum,
+			step: query.step.max(1),
+			default: query.default_value,
+			read_only: query.flags & CTRL_FLAG_READ_ONLY != 0,
+			inactive: query.flags & CTRL_FLAG_INACTIVE != 0,
+		})
+	}
+
+	/// Lists the ranges of all [Control] variants supported by the device
+	pub fn controls(&self) -> Result<Vec<ControlRange>> {
+		let all = [
+			Control::Brightness, Control::Contrast, Control::Saturation, Control::Hue, Control::Gamma, Control::Gain,
+			Control::Sharpness, Control::BacklightCompensation, Control::AutoWhiteBalance, Control::WhiteBalanceTemperature,
+			Control::AutoExposure, Control::ExposureAbsolute, Control::AutoFocus, Control::FocusAbsolute,
+		];
+		let mut out = Vec::with_capacity(all.len());
+		for &control in &all {
+			match self.range(control) {
+				Ok(range) => out.push(range),
+				Err(e) if e.code == core::StsNotImplemented => {}
+				Err(e) => return Err(e),
+			}
+		}
+		Ok(out)
+	}
+
+	/// Returns the current value of the control
+	pub fn get(&self, control: Control) -> Result<i32> {
+		let mut ctrl = Ctrl { id: control.id(), value: 0 };
+		self.ioctl(VIDIOC_G_CTRL, &mut ctrl)
+			.map_err(|e| Error::new(core::StsError, format!("Can't get control {:?} of {}: {}", control, self.path.display(), e)))?;
+		Ok(ctrl.value)
+	}
+
+	/// Validates the value against the driver reported range and sets it
+	///
+	/// Fails with `StsOutOfRange` for the invalid values and with `StsError` if the control is currently inactive, e.g.
+	/// when setting `ExposureAbsolute` while `AutoExposure` is on.
+	pub fn set(&mut self, control: Control, value: i32) -> Result<()> {
+		let range = self.range(control)?;
+		range.validate(value)?;
+		if range.inactive {
+			return Err(Error::new(core::StsError, format!("Control {} is inactive in the current mode of {}", range.name, self.path.display())));
+		}
+		let mut ctrl = Ctrl { id: control.id(), value };
+		self.ioctl(VIDIOC_S_CTRL, &mut ctrl)
+			.map_err(|e| Error::new(core::StsError, format!("Can't set control {} of {} to {}: {}", range.name, self.path.display(), value, e)))
+	}
+
+	/// Sets the control back to the driver default value
+	pub fn reset(&mut self, control: Control) -> Result<()> {
+		let default = self.range(control)?.default;
+		self.set(control, default)
+	}
+
+	fn frame_sizes(&self, pixel_format: u32) -> Result<Vec<FrameSize>> {
+		let mut out = vec![];
+		for index in 0.. {
+			let mut size = FrmSizeEnum { index, pixel_format, ..FrmSizeEnum::default() };
+			if !self.ioctl_enum(VIDIOC_ENUM_FRAMESIZES, &mut size)? {
+				break;
+			}
+			if size.typ == FRMSIZE_TYPE_DISCRETE {
+				let (width, height) = (size.data[0], size.data[1]);
+				out.push(FrameSize { width, height, rates: self.frame_rates(pixel_format, width, height)? });
+			} else {
+				// stepwise: min_width, max_width, step_width, min_height, max_height, step_height
+				for &(width, height) in &[(size.data[0], size.data[3]), (size.data[1], size.data[4])] {
+					out.push(FrameSize { width, height, rates: self.frame_rates(pixel_format, width, height)? });
+				}
+				break;
+			}
+		}
+		Ok(out)
+	}
+
+	fn frame_rates(&self, pixel_format: u32, width: u32, height: u32) -> Result<FrameRates> {
+		let mut rates = vec![];
+		for index in 0.. {
+			let mut interval = FrmIvalEnum { index, pixel_format, width, height, ..FrmIvalEnum::default() };
+			if !self.ioctl_enum(VIDIOC_ENUM_FRAMEINTERVALS, &mut interval)? {
+				break;
+			}
+			// intervals are in seconds per frame, the rate is the inverse
+			let rate = |i: usize| FrameRate { numerator: interval.data[i + 1], denominator: interval.data[i] };
+			if interval.typ == FRMIVAL_TYPE_DISCRETE {
+				rates.push(rate(0));
+			} else {
+				// stepwise: min interval, max interval, step
+				return Ok(FrameRates::Stepwise { min: rate(2), max: rate(0) });
+			}
+		}
+		Ok(FrameRates::Discrete(rates))
+	}
+
+	fn ioctl<T>(&self, request: c_ulong, arg: &mut T) -> io::Result<()> {
+		loop {
+			let res = unsafe { libc::ioctl(self.fd, request as _, arg as *mut T as *mut c_void) };
+			if res != -1 {
+				return Ok(());
+			}
+			let err = io::Error::last_os_error();
+			if err.kind() != io::ErrorKind::Interrupted {
+				return Err(err);
+			}
+		}
+	}
+
+	/// Runs the enumeration ioctl, returns `false` when the index is past the end of the list
+	fn ioctl_enum<T>(&self, request: c_ulong, arg: &mut T) -> Result<bool> {
+		match self.ioctl(request, arg) {
+			Ok(()) => Ok(true),
+			Err(e) if e.raw_os_error() == Some(libc::EINVAL) => Ok(false),
+			Err(e) => Err(Error::new(core::StsError, format!("V4L2 ioctl failed on {}: {}", self.path.display(), e))),
+		}
+	}
+}
+
+impl Drop for V4l2Device {
+	fn drop(&mut self) {
+		unsafe { libc::close(self.fd) };
+	}
+}
+
+fn io_error(msg: &str) -> Error {
+	Error::new(core::StsError, format!("{}: {}", msg, io::Error::last_os_error()))
+}
+
+fn c_str(bytes: &[u8]) -> String {
+	let len = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+	String::from_utf8_lossy(&bytes[..len]).into_owned()
+}
+
+// mirrors of the linux/videodev2.h structs
+
+const BUF_TYPE_VIDEO_CAPTURE: u32 = 1;
+const FMT_FLAG_COMPRESSED: u32 = 0x0001;
+const FRMSIZE_TYPE_DISCRETE: u32 = 1;
+const FRMIVAL_TYPE_DISCRETE: u32 = 1;
+const CTRL_FLAG_DISABLED: u32 = 0x0001;
+const CTRL_FLAG_READ_ONLY: u32 = 0x0004;
+const CTRL_FLAG_INACTIVE: u32 = 0x0010;
+
+#[repr(C)]
+#[derive(Default)]
+struct FmtDesc {
+	index: u32,
+	typ: u32,
+	flags: u32,
+	description: [u8; 32],
+	pixelformat: u32,
+	mbus_code: u32,
+	reserved: [u32; 3],
+}
+
+#[repr(C)]
+#[derive(Default)]
+struct FrmSizeEnum {
+	index: u32,
+	pixel_format: u32,
+	typ: u32,
+	data: [u32; 6],
+	reserved: [u32; 2],
+}
+
+#[repr(C)]
+#[derive(Default)]
+struct FrmIvalEnum {
+	index: u32,
+	pixel_format: u32,
+	width: u32,
+	height: u32,
+	typ: u32,
+	data: [u32; 6],
+	reserved: [u32; 2],
+}
+
+#[repr(C)]
+#[derive(Default)]
+struct QueryCtrl {
+	id: u32,
+	typ: u32,
+	name: [u8; 32],
+	minimum: i32,
+	maximum: i32,
+	step: i32,
+	default_value: i32,
+	flags: u32,
+	reserved: [u32; 2],
+}
+
+#[repr(C)]
+#[derive(Default)]
+struct Ctrl {
+	id: u32,
+	value: i32,
+}
+
+/// `_IOWR('V', nr, T)`
+const fn iowr<T>(nr: c_ulong) -> c_ulong {
+	(3 << 30) | ((mem::size_of::<T>() as c_ulong) << 16) | ((b'V' as c_ulong) << 8) | nr
+}
+
+const VIDIOC_ENUM_FMT: c_ulong = iowr::<FmtDesc>(2);
+const VIDIOC_G_CTRL: c_ulong = iowr::<Ctrl>(27);
+const VIDIOC_S_CTRL: c_ulong = iowr::<Ctrl>(28);
+const VIDIOC_QUERYCTRL: c_ulong = iowr::<QueryCtrl>(36);
+const VIDIOC_ENUM_FRAMESIZES: c_ulong = iowr::<FrmSizeEnum>(74);
+const VIDIOC_ENUM_FRAMEINTERVALS: c_ulong = iowr::<FrmIvalEnum>(75);
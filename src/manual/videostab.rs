@@ -0,0 +1,409 @@
+//! One-shot video stabilization
+//!
+//! [stabilize] drives the `videostab` building blocks the way `OnePassStabilizer` and `TwoPassStabilizer` do: the
+//! motion between the consecutive frames is estimated with the `KeypointBasedMotionEstimator`, smoothed with the
+//! `GaussianMotionFilter` and the frames are warped by the stabilizing motion:
+//! ```no_run
+//! # use opencv::videostab::{stabilize, StabMode, StabOptions};
+//! # fn run() -> opencv::Result<()> {
+//! for frame in stabilize("shaky.mp4", StabOptions::default().with_mode(StabMode::TwoPass).with_trim_ratio(0.1))? {
+//!     let frame = frame?;
+//! }
+//! # Ok(()) }
+//! ```
+//!
+//! The stabilizer classes themselves are not used, `IFrameSource` can't be implemented in Rust to feed them the frames
+//! from memory and they report the progress to stdout through the log that can't be replaced.
+
+use std::collections::VecDeque;
+#[cfg(ocvrs_has_module_videoio)]
+use std::path::{Path, PathBuf};
+use std::vec;
+
+use crate::{
+	core::{self, Mat, Ptr, Range, Rect, Scalar, Size, Vector},
+	Error,
+	imgproc,
+	prelude::*,
+	Result,
+	videostab::{self, GaussianMotionFilter, ImageMotionEstimatorBase, KeypointBasedMotionEstimator, MotionEstimatorRansacL2, MotionFilterBase, MotionModel},
+};
+#[cfg(ocvrs_has_module_videoio)]
+use crate::videoio::{self, VideoCapture};
+
+/// Input of the [stabilize]
+#[derive(Debug)]
+pub enum StabInput {
+	/// Video file or any other source accepted by `VideoCapture::from_file()`
+	#[cfg(ocvrs_has_module_videoio)]
+	Path(PathBuf),
+	/// Frames in memory, all of the same size
+	Frames(Vec<Mat>),
+}
+
+#[cfg(ocvrs_has_module_videoio)]
+impl From<&str> for StabInput {
+	#[inline]
+	fn from(s: &str) -> Self {
+		Self::Path(s.into())
+	}
+}
+
+#[cfg(ocvrs_has_module_videoio)]
+impl From<String> for StabInput {
+	#[inline]
+	fn from(s: String) -> Self {
+		Self::Path(s.into())
+	}
+}
+
+#[cfg(ocvrs_has_module_videoio)]
+impl From<&Path> for StabInput {
+	#[inline]
+	fn from(s: &Path) -> Self {
+		Self::Path(s.to_path_buf())
+	}
+}
+
+#[cfg(ocvrs_has_module_videoio)]
+impl From<PathBuf> for StabInput {
+	#[inline]
+	fn from(s: PathBuf) -> Self {
+		Self::Path(s)
+	}
+}
+
+impl From<Vec<Mat>> for StabInput {
+	#[inline]
+	fn from(s: Vec<Mat>) -> Self {
+		Self::Frames(s)
+	}
+}
+
+/// Stabilization strategy
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StabMode {
+	/// Streams the frames with the lookahead of `radius` frames, like `OnePassStabilizer`
+	OnePass,
+	/// Estimates the motion of the whole input before producing the first frame, like `TwoPassStabilizer`, the files
+	/// are read twice and only the in-memory frames are kept in memory
+	TwoPass,
+}
+
+impl Default for StabMode {
+	#[inline]
+	fn default() -> Self {
+		Self::OnePass
+	}
+}
+
+/// Options of the [stabilize], the defaults match the ones of the OpenCV stabilizers
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StabOptions {
+	pub mode: StabMode,
+	/// Number of frames on each side taken into account by the motion smoothing, `15` by default
+	pub radius: i32,
+	/// Standard deviation of the Gaussian smoothing, `sqrt(radius)` when negative, `-1` by default
+	pub stdev: f32,
+	/// Model of the motion between the frames, `MM_AFFINE` by default
+	pub motion_model: MotionModel,
+	/// Part of the frame cropped on each side to hide the borders, `0` by default
+	pub trim_ratio: f32,
+	/// Whether the trim ratio is estimated to exclude all borders, overrides `trim_ratio`, only in the
+	/// [StabMode::TwoPass], `false` by default
+	pub estimate_trim_ratio: bool,
+	/// Whether the stabilizing motion is constrained to keep the trimmed frame inside of the source frame, `false` by
+	/// default
+	pub correction_for_inclusion: bool,
+	/// Extrapolation of the pixels outside of the source frame, `BORDER_REPLICATE` by default
+	pub border_mode: i32,
+}
+
+impl Default for StabOptions {
+	fn default() -> Self {
+		Self {
+			mode: StabMode::default(),
+			radius: 15,
+			stdev: -1.,
+			motion_model: MotionModel::MM_AFFINE,
+			trim_ratio: 0.,
+			estimate_trim_ratio: false,
+			correction_for_inclusion: false,
+			border_mode: core::BORDER_REPLICATE,
+		}
+	}
+}
+
+impl StabOptions {
+	pub fn with_mode(mut self, mode: StabMode) -> Self {
+		self.mode = mode;
+		self
+	}
+
+	pub fn with_radius(mut self, radius: i32) -> Self {
+		self.radius = radius;
+		self
+	}
+
+	pub fn with_stdev(mut self, stdev: f32) -> Self {
+		self.stdev = stdev;
+		self
+	}
+
+	pub fn with_motion_model(mut self, motion_model: MotionModel) -> Self {
+		self.motion_model = motion_model;
+		self
+	}
+
+	pub fn with_trim_ratio(mut self, trim_ratio: f32) -> Self {
+		self.trim_ratio = trim_ratio;
+		self
+	}
+
+	pub fn with_estimate_trim_ratio(mut self, estimate_trim_ratio: bool) -> Self {
+		self.estimate_trim_ratio = estimate_trim_ratio;
+		self
+	}
+
+	pub fn with_correction_for_inclusion(mut self, correction_for_inclusion: bool) -> Self {
+		self.correction_for_inclusion = correction_for_inclusion;
+		self
+	}
+
+	pub fn with_border_mode(mut self, border_mode: i32) -> Self {
+		self.border_mode = border_mode;
+		self
+	}
+}
+
+/// Stabilizes the shaky video, e.g. the handheld footage before `sfm` or the tracking
+///
+/// Returns the iterator over the stabilized frames, they are cropped by the trim ratio on each side. The errors are
+/// reported by the iterator, it ends after the first one.
+pub fn stabilize(input: impl Into<StabInput>, options: StabOptions) -> Result<Stabilized> {
+	if options.radius < 1 {
+		return Err(Error::new(core::StsOutOfRange, format!("Radius must be positive, got: {}", options.radius)));
+	}
+	if !(0. ..0.5).contains(&options.trim_ratio) {
+		return Err(Error::new(core::StsOutOfRange, format!("Trim ratio must be in [0; 0.5), got: {}", options.trim_ratio)));
+	}
+	let estimator = KeypointBasedMotionEstimator::new(Ptr::new(MotionEstimatorRansacL2::new(options.motion_model)?).into())?;
+	let mut out = Stabilized {
+		source: Source::Frames(vec![].into_iter()),
+		estimator,
+		filter: GaussianMotionFilter::new(options.radius, options.stdev)?,
+		options,
+		trim_ratio: options.trim_ratio,
+		size: None,
+		motions: VecDeque::new(),
+		motion_base: 0,
+		frame_count: None,
+		pending: VecDeque::new(),
+		read: 0,
+		next: 0,
+		done: false,
+	};
+	let input = input.into();
+	if options.mode == StabMode::TwoPass {
+		let frame_count = match &input {
+			#[cfg(ocvrs_has_module_videoio)]
+			StabInput::Path(path) => {
+				let mut source = Source::open(path)?;
+				let mut previous = None;
+				while let Some(frame) = source.read()? {
+					out.push_motion(previous.as_ref(), &frame)?;
+					previous = Some(frame);
+				}
+				out.motions.len() + previous.is_some() as usize
+			}
+			StabInput::Frames(frames) => {
+				for (i, frame) in frames.iter().enumerate() {
+					out.push_motion(i.checked_sub(1).map(|i| &frames[i]), frame)?;
+				}
+				frames.len()
+			}
+		};
+		out.frame_count = Some(frame_count);
+		if options.estimate_trim_ratio {
+			if let Some(size) = out.size {
+				out.trim_ratio = 0.;
+				for i in 0..frame_count {
+					let motion = out.stabilization_motion(i)?;
+					out.trim_ratio = out.trim_ratio.max(videostab::estimate_optimal_trim_ratio(&motion, size)?);
+				}
+			}
+		}
+	}
+	out.source = match input {
+		#[cfg(ocvrs_has_module_videoio)]
+		StabInput::Path(path) => Source::open(&path)?,
+		StabInput::Frames(frames) => Source::Frames(frames.into_iter()),
+	};
+	Ok(out)
+}
+
+enum Source {
+	#[cfg(ocvrs_has_module_videoio)]
+	Capture(VideoCapture),
+	Frames(vec::IntoIter<Mat>),
+}
+
+impl Source {
+	#[cfg(ocvrs_has_module_videoio)]
+	fn open(path: &Path) -> Result<Self> {
+		let path_str = path.to_str()
+			.ok_or_else(|| Error::new(core::StsBadArg, format!("Path is not valid UTF-8: {}", path.display())))?;
+		let capture = VideoCapture::from_file(path_str, videoio::CAP_ANY)?;
+		if !capture.is_opened()? {
+			return Err(Error::new(core::StsError, format!("Can't open video: {}", path.display())));
+		}
+		Ok(Self::Capture(capture))
+	}
+
+	fn read(&mut self) -> Result<Option<Mat>> {
+		match self {
+			#[cfg(ocvrs_has_module_videoio)]
+			Self::Capture(capture) => {
+				let mut frame = Mat::default();
+				Ok(if capture.read(&mut frame)? && !frame.empty() { Some(frame) } else { None })
+			}
+			Self::Frames(frames) => Ok(frames.next()),
+		}
+	}
+}
+
+/// Iterator over the stabilized frames returned by the [stabilize]
+pub struct Stabilized {
+	source: Source,
+	estimator: KeypointBasedMotionEstimator,
+	filter: GaussianMotionFilter,
+	options: StabOptions,
+	trim_ratio: f32,
+	size: Option<Size>,
+	/// Motions between the consecutive frames, the first one is from the frame `motion_base` to the next one
+	motions: VecDeque<Mat>,
+	motion_base: usize,
+	/// Number of the input frames, `None` until the end of the input in the [StabMode::OnePass]
+	frame_count: Option<usize>,
+	/// Frames waiting for the stabilization, the first one is the frame `next`
+	pending: VecDeque<Mat>,
+	read: usize,
+	next: usize,
+	done: bool,
+}
+
+impl Stabilized {
+	/// Trim ratio of the output frames, it's estimated before the first frame when requested by
+	/// [StabOptions::estimate_trim_ratio]
+	#[inline]
+	pub fn trim_ratio(&self) -> f32 {
+		self.trim_ratio
+	}
+
+	/// Estimates the motion from the `previous` frame to the `frame`
+	fn push_motion(&mut self, previous: Option<&Mat>, frame: &Mat) -> Result<()> {
+		let size = frame.size()?;
+		match self.size {
+			Some(expected) if expected != size => {
+				return Err(Error::new(core::StsUnmatchedSizes, format!("All frames must have the same size, expected: {:?}, got: {:?}", expected, size)));
+			}
+			_ => self.size = Some(size),
+		}
+		if let Some(previous) = previous {
+			let mut ok = true;
+			let motion = ImageMotionEstimatorBase::estimate(&mut self.estimator, previous, frame, &mut ok)?;
+			// the frames without enough features are not moved relative to each other
+			self.motions.push_back(if ok && !motion.empty() { motion } else { Mat::eye(3, 3, core::CV_32F)?.to_mat()? });
+		}
+		Ok(())
+	}
+
+	fn read_frame(&mut self) -> Result<bool> {
+		match self.source.read()? {
+			Some(frame) => {
+				if self.frame_count.is_none() {
+					// the motions are not estimated yet only in the one-pass mode
+					let previous = self.pending.pop_back();
+					self.push_motion(previous.as_ref(), &frame)?;
+					self.pending.extend(previous);
+				}
+				self.pending.push_back(frame);
+				self.read += 1;
+				Ok(true)
+			}
+			None => {
+				self.frame_count.get_or_insert(self.read);
+				Ok(false)
+			}
+		}
+	}
+
+	/// Smooths the motions around the frame `idx`, all of them must be known
+	fn stabilization_motion(&mut self, idx: usize) -> Result<Mat> {
+		let radius = self.options.radius as usize;
+		let start = idx.saturating_sub(radius).max(self.motion_base);
+		let end = (idx + radius).min(self.motion_base + self.motions.len());
+		let motions = self.motions.iter()
+			.skip(start - self.motion_base)
+			.take(end - start)
+			.map(|m| m.try_clone())
+			.collect::<Result<Vector<Mat>>>()?;
+		MotionFilterBase::stabilize(&mut self.filter, (idx - start) as i32, &motions, &Range::new(0, (end - start) as i32)?)
+	}
+
+	fn warp(&self, frame: &Mat, motion: &Mat) -> Result<Mat> {
+		let size = frame.size()?;
+		let constrained;
+		let motion = if self.options.correction_for_inclusion {
+			constrained = videostab::ensure_inclusion_constraint(motion, size, self.trim_ratio)?;
+			&constrained
+		} else {
+			motion
+		};
+		let mut out = Mat::default();
+		if self.options.motion_model == MotionModel::MM_HOMOGRAPHY {
+			imgproc::warp_perspective(frame, &mut out, motion, size, imgproc::INTER_LINEAR, self.options.border_mode, Scalar::default())?;
+		} else {
+			imgproc::warp_affine(frame, &mut out, &Mat::roi(motion, Rect::new(0, 0, 3, 2))?, size, imgproc::INTER_LINEAR, self.options.border_mode, Scalar::default())?;
+		}
+		let dx = (self.trim_ratio * size.width as f32).floor() as i32;
+		let dy = (self.trim_ratio * size.height as f32).floor() as i32;
+		if dx == 0 && dy == 0 {
+			return Ok(out);
+		}
+		Mat::roi(&out, Rect::new(dx, dy, size.width - 2 * dx, size.height - 2 * dy))?.try_clone()
+	}
+
+	fn next_frame(&mut self) -> Result<Option<Mat>> {
+		let radius = self.options.radius as usize;
+		while self.pending.is_empty() || (self.frame_count.is_none() && self.read <= self.next + radius) {
+			if !self.read_frame()? {
+				break;
+			}
+		}
+		let frame = match self.pending.pop_front() {
+			Some(frame) => frame,
+			None => return Ok(None),
+		};
+		let motion = self.stabilization_motion(self.next)?;
+		self.next += 1;
+		while self.motion_base + radius < self.next && !self.motions.is_empty() {
+			self.motions.pop_front();
+			self.motion_base += 1;
+		}
+		self.warp(&frame, &motion).map(Some)
+	}
+}
+
+impl Iterator for Stabilized {
+	type Item = Result<Mat>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.done {
+			return None;
+		}
+		let out = self.next_frame().transpose();
+		self.done = !matches!(out, Some(Ok(_)));
+		out
+	}
+}
@@ -0,0 +1,68 @@
+use std::slice;
+
+use crate::{
+	core::{self, CV_MAKETYPE, Mat, Matx33d, Scalar, ToInputArray},
+	Error,
+	prelude::*,
+	Result,
+	viz::{self, Color, Viz3d, WCloud, WCoordinateSystem, Widget, WTrajectory, WTrajectoryFrustums},
+};
+
+/// Builds the trajectory path suitable for `WTrajectory` and `WTrajectoryFrustums` from the sfm camera motions
+///
+/// `rs` are `3x3` rotations and `ts` are `3x1` translations mapping world points into the camera coordinates (as
+/// returned by `sfm::reconstruct()`), they are inverted to get the camera poses in the world. The result is `Nx1` Mat
+/// of `CV_64FC(16)` elements, each element is a row-major `4x4` pose matrix.
+pub fn trajectory_from_motions(rs: &[Mat], ts: &[Mat]) -> Result<Mat> {
+	if rs.len() != ts.len() {
+		return Err(Error::new(core::StsUnmatchedSizes, format!("Number of rotations: {} doesn't match number of translations: {}", rs.len(), ts.len())));
+	}
+	let mut out = Mat::new_rows_cols_with_default(rs.len() as i32, 1, CV_MAKETYPE(core::CV_64F, 16), Scalar::all(0.))?;
+	let poses = unsafe { slice::from_raw_parts_mut(out.data_mut().cast::<f64>(), rs.len() * 16) };
+	for ((r, t), pose) in rs.iter().zip(ts).zip(poses.chunks_exact_mut(16)) {
+		let mut r_f64 = Mat::default();
+		r.convert_to(&mut r_f64, core::CV_64F, 1., 0.)?;
+		let mut t_f64 = Mat::default();
+		t.convert_to(&mut t_f64, core::CV_64F, 1., 0.)?;
+		if r_f64.total() != 9 || t_f64.total() != 3 {
+			return Err(Error::new(core::StsBadSize, "Expected 3x3 rotation and 3x1 translation"));
+		}
+		// pose = [R^T | -R^T t]
+		for row in 0..3 {
+			let mut tr = 0.;
+			for col in 0..3 {
+				let r_val = *r_f64.at_2d::<f64>(col as i32, row as i32)?;
+				pose[row * 4 + col] = r_val;
+				tr -= r_val * *t_f64.at::<f64>(col as i32)?;
+			}
+			pose[row * 4 + 3] = tr;
+		}
+		pose[15] = 1.;
+	}
+	Ok(out)
+}
+
+/// Opens a window displaying the point cloud together with the camera frustums and the trajectory
+///
+/// Intended for a quick sanity check of the `sfm::reconstruct()` output: `points3d` is the cloud (`3xN` Mat, vector of
+/// `Point3d` or 3-channel Mat), `rs` and `ts` are the camera motions (see [trajectory_from_motions]) and `k` is the
+/// camera matrix used to draw the frustums. The call blocks until the window is closed, use mouse to rotate and zoom
+/// the scene and keyboard shortcuts of `Viz3d` (`h` prints the help) to navigate.
+pub fn show_reconstruction(window_name: &str, points3d: &dyn ToInputArray, rs: &[Mat], ts: &[Mat], k: Matx33d) -> Result<()> {
+	let mut window = Viz3d::new(window_name)?;
+	let mut cloud = Mat::default();
+	points3d.input_array()?.get_mat(-1)?.copy_to(&mut cloud)?;
+	// WCloud expects points in a 3-channel Mat
+	if cloud.channels() == 1 && cloud.rows() == 3 {
+		cloud = cloud.t()?.to_mat()?;
+	}
+	let cloud = cloud.reshape(3, 1)?.try_clone()?;
+	window.show_widget("cloud", &Widget::from(WCloud::new_1(&cloud, &Color::white()?)?), core::Affine3d::default())?;
+	window.show_widget("origin", &Widget::from(WCoordinateSystem::new(1.)?), core::Affine3d::default())?;
+	if !rs.is_empty() {
+		let path = trajectory_from_motions(rs, ts)?;
+		window.show_widget("trajectory", &Widget::from(WTrajectory::new(&path, viz::WTrajectory_PATH, 0.5, &Color::green()?)?), core::Affine3d::default())?;
+		window.show_widget("frustums", &Widget::from(WTrajectoryFrustums::new(&path, k, 0.3, &Color::yellow()?)?), core::Affine3d::default())?;
+	}
+	window.spin()
+}
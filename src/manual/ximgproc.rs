@@ -0,0 +1,85 @@
+use crate::{
+	core::{self, Mat, Scalar},
+	Error,
+	imgproc,
+	prelude::*,
+	Result,
+	ximgproc,
+};
+
+/// Parameters of [slic_superpixels]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SlicParams {
+	/// One of `ximgproc::SLIC`, `SLICO` or `MSLIC`
+	pub algorithm: i32,
+	/// Average superpixel size in pixels
+	pub region_size: i32,
+	/// Smoothness of the superpixel boundaries, ignored by `SLICO`
+	pub ruler: f32,
+	pub iterations: i32,
+	/// Superpixels smaller than this percentage of the average superpixel size are merged into the neighbors, `0` to
+	/// skip the connectivity enforcement
+	pub min_element_size: i32,
+	/// Converts the BGR input into CIELAB before the segmentation, as recommended for color images
+	pub to_lab: bool,
+}
+
+impl Default for SlicParams {
+	fn default() -> Self {
+		Self {
+			algorithm: ximgproc::SLICO,
+			region_size: 10,
+			ruler: 10.,
+			iterations: 10,
+			min_element_size: 25,
+			to_lab: true,
+		}
+	}
+}
+
+/// Superpixel segmentation result returned by [slic_superpixels]
+#[derive(Debug)]
+pub struct Superpixels {
+	/// `CV_32S` label image of the input size, labels are in `0..count` range
+	pub labels: Mat,
+	pub count: i32,
+	/// `CV_8U` mask of the superpixel boundaries, boundary pixels are `255`
+	pub contours: Mat,
+}
+
+impl Superpixels {
+	/// Returns the copy of `image` with the superpixel boundaries drawn in `color`
+	pub fn overlay(&self, image: &Mat, color: Scalar) -> Result<Mat> {
+		if image.size()? != self.contours.size()? {
+			return Err(Error::new(core::StsUnmatchedSizes, "Image size doesn't match the superpixel labels size"));
+		}
+		let mut out = image.try_clone()?;
+		out.set_to(&color, &self.contours)?;
+		Ok(out)
+	}
+}
+
+/// Segments the `image` into SLIC superpixels
+///
+/// Takes care of creating and iterating the `SuperpixelSLIC` algorithm and collecting its outputs in one call.
+pub fn slic_superpixels(image: &Mat, params: &SlicParams) -> Result<Superpixels> {
+	let input = if params.to_lab && image.channels() == 3 {
+		let mut blurred = Mat::default();
+		imgproc::gaussian_blur(image, &mut blurred, core::Size::new(3, 3), 0., 0., core::BORDER_DEFAULT)?;
+		let mut lab = Mat::default();
+		imgproc::cvt_color(&blurred, &mut lab, imgproc::COLOR_BGR2Lab, 0)?;
+		lab
+	} else {
+		image.try_clone()?
+	};
+	let mut slic = ximgproc::create_superpixel_slic(&input, params.algorithm, params.region_size, params.ruler)?;
+	slic.iterate(params.iterations)?;
+	if params.min_element_size > 0 {
+		slic.enforce_label_connectivity(params.min_element_size)?;
+	}
+	let mut labels = Mat::default();
+	slic.get_labels(&mut labels)?;
+	let mut contours = Mat::default();
+	slic.get_label_contour_mask(&mut contours, false)?;
+	Ok(Superpixels { labels, count: slic.get_number_of_superpixels()?, contours })
+}
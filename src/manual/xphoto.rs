@@ -0,0 +1,177 @@
+use std::{fmt, mem};
+
+use crate::{
+	core::{self, Mat},
+	Error,
+	photo,
+	prelude::*,
+	Result,
+	xphoto::{self, WhiteBalancer},
+};
+
+enum ColorStep {
+	WhiteBalance(&'static str, Box<dyn WhiteBalancer>),
+	ChannelGains([f32; 3]),
+	NlMeansDenoise { h: f32, h_color: f32 },
+	DctDenoise { sigma: f64, psize: i32 },
+	Bm3dDenoise { h: f32 },
+	Inpaint { mask: Mat, algorithm: i32 },
+}
+
+impl fmt::Debug for ColorStep {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			ColorStep::WhiteBalance(name, _) => f.debug_tuple("WhiteBalance").field(name).finish(),
+			ColorStep::ChannelGains(gains) => f.debug_tuple("ChannelGains").field(gains).finish(),
+			ColorStep::NlMeansDenoise { h, h_color } => f.debug_struct("NlMeansDenoise").field("h", h).field("h_color", h_color).finish(),
+			ColorStep::DctDenoise { sigma, psize } => f.debug_struct("DctDenoise").field("sigma", sigma).field("psize", psize).finish(),
+			ColorStep::Bm3dDenoise { h } => f.debug_struct("Bm3dDenoise").field("h", h).finish(),
+			ColorStep::Inpaint { mask, algorithm } => f.debug_struct("Inpaint").field("mask", mask).field("algorithm", algorithm).finish(),
+		}
+	}
+}
+
+/// Sequence of the camera ISP-style color processing steps applied one after another
+///
+/// ```ignore
+/// let mut correction = xphoto::ColorCorrection::new()
+///     .grayworld_wb(0.95)?
+///     .nl_means_denoise(3., 3.);
+/// let corrected = correction.apply(&raw_frame)?;
+/// ```
+/// The white balancers are created once, so the same correction can be applied to all frames of a video. Like in
+/// [Morphology](crate::imgproc::Morphology) the intermediate results are stored in 2 swapped buffers.
+#[derive(Debug, Default)]
+pub struct ColorCorrection {
+	steps: Vec<ColorStep>,
+}
+
+impl ColorCorrection {
+	#[inline]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Adds the white balance step with the custom configured balancer, e.g. `xphoto::create_simple_wb()` with the
+	/// changed input range
+	pub fn white_balancer(mut self, balancer: impl WhiteBalancer + 'static) -> Self {
+		self.steps.push(ColorStep::WhiteBalance("custom", Box::new(balancer)));
+		self
+	}
+
+	/// Stretches each channel to the full range discarding the `p` percent of the darkest and brightest pixels, `2` by
+	/// default
+	pub fn simple_wb(mut self, p: f32) -> Result<Self> {
+		let mut balancer = xphoto::create_simple_wb()?;
+		balancer.set_p(p)?;
+		self.steps.push(ColorStep::WhiteBalance("simple", Box::new(balancer)));
+		Ok(self)
+	}
+
+	/// Scales the channels to make the average color gray ignoring the pixels with the saturation above
+	/// `saturation_threshold` (`0..1`), `0.9` by default
+	pub fn grayworld_wb(mut self, saturation_threshold: f32) -> Result<Self> {
+		let mut balancer = xphoto::create_grayworld_wb()?;
+		balancer.set_saturation_threshold(saturation_threshold)?;
+		self.steps.push(ColorStep::WhiteBalance("grayworld", Box::new(balancer)));
+		Ok(self)
+	}
+
+	/// Estimates the illuminant with the learned model, an empty `model_path` uses the model built into OpenCV
+	pub fn learning_based_wb(mut self, model_path: &str) -> Result<Self> {
+		let balancer = xphoto::create_learning_based_wb(model_path)?;
+		self.steps.push(ColorStep::WhiteBalance("learning_based", Box::new(balancer)));
+		Ok(self)
+	}
+
+	/// Multiplies the BGR channels by the fixed gains, e.g. the ones reported by the camera
+	pub fn channel_gains(mut self, gain_b: f32, gain_g: f32, gain_r: f32) -> Self {
+		self.steps.push(ColorStep::ChannelGains([gain_b, gain_g, gain_r]));
+		self
+	}
+
+	/// Non-local means denoising, `h_color` is only used for the color images
+	pub fn nl_means_denoise(mut self, h: f32, h_color: f32) -> Self {
+		self.steps.push(ColorStep::NlMeansDenoise { h, h_color });
+		self
+	}
+
+	/// DCT denoising with the expected noise standard deviation `sigma` and `16x16` blocks
+	pub fn dct_denoise(mut self, sigma: f64) -> Self {
+		self.steps.push(ColorStep::DctDenoise { sigma, psize: 16 });
+		self
+	}
+
+	/// BM3D denoising of the `CV_8UC1` images, requires OpenCV built with `OPENCV_ENABLE_NONFREE`
+	pub fn bm3d_denoise(mut self, h: f32) -> Self {
+		self.steps.push(ColorStep::Bm3dDenoise { h });
+		self
+	}
+
+	/// Fills the zero pixels of the `CV_8UC1` `mask` (e.g. the dead pixels or the sensor dust) with one of the
+	/// `xphoto::INPAINT_*` algorithms, the mask must have the size of the processed images
+	pub fn inpaint(mut self, mask: Mat, algorithm: i32) -> Self {
+		self.steps.push(ColorStep::Inpaint { mask, algorithm });
+		self
+	}
+
+	/// Number of the added steps
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.steps.len()
+	}
+
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.steps.is_empty()
+	}
+
+	/// Runs all steps on `src` and returns the result, a copy of `src` is returned if there are no steps
+	pub fn apply(&mut self, src: &Mat) -> Result<Mat> {
+		let mut out = Mat::default();
+		self.apply_to(src, &mut out)?;
+		Ok(out)
+	}
+
+	/// Runs all steps on `src` and stores the result into `dst`, `dst` is reused as one of the intermediate buffers
+	pub fn apply_to(&mut self, src: &Mat, dst: &mut Mat) -> Result<()> {
+		let mut steps = self.steps.iter_mut();
+		match steps.next() {
+			Some(first) => apply_step(first, src, dst)?,
+			None => return src.copy_to(dst),
+		}
+		let mut buf = Mat::default();
+		for step in steps {
+			apply_step(step, dst, &mut buf)?;
+			mem::swap(dst, &mut buf);
+		}
+		Ok(())
+	}
+}
+
+fn apply_step(step: &mut ColorStep, src: &Mat, dst: &mut Mat) -> Result<()> {
+	match step {
+		ColorStep::WhiteBalance(_, balancer) => balancer.balance_white(src, dst),
+		ColorStep::ChannelGains([b, g, r]) => {
+			if src.channels() != 3 {
+				return Err(Error::new(core::StsBadArg, format!("Channel gains require a 3 channel BGR image, got: {} channels", src.channels())));
+			}
+			xphoto::apply_channel_gains(src, dst, *b, *g, *r)
+		}
+		ColorStep::NlMeansDenoise { h, h_color } => {
+			if src.channels() == 1 {
+				photo::fast_nl_means_denoising(src, dst, *h, 7, 21)
+			} else {
+				photo::fast_nl_means_denoising_colored(src, dst, *h, *h_color, 7, 21)
+			}
+		}
+		ColorStep::DctDenoise { sigma, psize } => xphoto::dct_denoising(src, dst, *sigma, *psize),
+		ColorStep::Bm3dDenoise { h } => xphoto::bm3d_denoising_1(src, dst, *h, 4, 16, 2500, 400, 8, 1, 2., core::NORM_L2, xphoto::BM3D_STEPALL, xphoto::HAAR),
+		ColorStep::Inpaint { mask, algorithm } => {
+			if mask.size()? != src.size()? {
+				return Err(Error::new(core::StsUnmatchedSizes, format!("Inpainting mask size: {:?} doesn't match the image size: {:?}", mask.size()?, src.size()?)));
+			}
+			xphoto::inpaint(src, mask, dst, *algorithm)
+		}
+	}
+}
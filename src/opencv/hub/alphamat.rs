@@ -34,7 +34,7 @@ pub fn info_flow(image: &dyn core::ToInputArray, tmap: &dyn core::ToInputArray,
 	input_array_arg!(tmap);
 	output_array_arg!(result);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_alphamat_infoFlow_const__InputArrayR_const__InputArrayR_const__OutputArrayR(image.as_raw__InputArray(), tmap.as_raw__InputArray(), result.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_alphamat_infoFlow_const__InputArrayR_const__InputArrayR_const__OutputArrayR(image.as_raw__InputArray(), tmap.as_raw__InputArray(), result.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
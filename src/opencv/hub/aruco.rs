@@ -171,12 +171,52 @@ pub fn calibrate_camera_aruco_extended(corners: &dyn core::ToInputArray, ids: &d
 	output_array_arg!(std_deviations_extrinsics);
 	output_array_arg!(per_view_errors);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_aruco_calibrateCameraAruco_const__InputArrayR_const__InputArrayR_const__InputArrayR_const_Ptr_Board_R_Size_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int_TermCriteria(corners.as_raw__InputArray(), ids.as_raw__InputArray(), counter.as_raw__InputArray(), board.as_raw_PtrOfBoard(), image_size.opencv_as_extern(), camera_matrix.as_raw__InputOutputArray(), dist_coeffs.as_raw__InputOutputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), std_deviations_intrinsics.as_raw__OutputArray(), std_deviations_extrinsics.as_raw__OutputArray(), per_view_errors.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_aruco_calibrateCameraAruco_const__InputArrayR_const__InputArrayR_const__InputArrayR_const_Ptr_Board_R_Size_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int_TermCriteria(corners.as_raw__InputArray(), ids.as_raw__InputArray(), counter.as_raw__InputArray(), board.as_raw_PtrOfBoard(), image_size.opencv_as_extern(), camera_matrix.as_raw__InputOutputArray(), dist_coeffs.as_raw__InputOutputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), std_deviations_intrinsics.as_raw__OutputArray(), std_deviations_extrinsics.as_raw__OutputArray(), per_view_errors.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `calibrate_camera_aruco_extended()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CalibrateCameraArucoExtendedBuilder<'a> {
+	corners: &'a dyn core::ToInputArray,
+	ids: &'a dyn core::ToInputArray,
+	counter: &'a dyn core::ToInputArray,
+	board: &'a core::Ptr<crate::aruco::Board>,
+	image_size: core::Size,
+	camera_matrix: &'a mut dyn core::ToInputOutputArray,
+	dist_coeffs: &'a mut dyn core::ToInputOutputArray,
+	rvecs: &'a mut dyn core::ToOutputArray,
+	tvecs: &'a mut dyn core::ToOutputArray,
+	std_deviations_intrinsics: &'a mut dyn core::ToOutputArray,
+	std_deviations_extrinsics: &'a mut dyn core::ToOutputArray,
+	per_view_errors: &'a mut dyn core::ToOutputArray,
+	flags: i32,
+	criteria: core::TermCriteria,
+}
+
+impl<'a> CalibrateCameraArucoExtendedBuilder<'a> {
+	#[inline]
+	pub fn new(corners: &'a dyn core::ToInputArray, ids: &'a dyn core::ToInputArray, counter: &'a dyn core::ToInputArray, board: &'a core::Ptr<crate::aruco::Board>, image_size: core::Size, camera_matrix: &'a mut dyn core::ToInputOutputArray, dist_coeffs: &'a mut dyn core::ToInputOutputArray, rvecs: &'a mut dyn core::ToOutputArray, tvecs: &'a mut dyn core::ToOutputArray, std_deviations_intrinsics: &'a mut dyn core::ToOutputArray, std_deviations_extrinsics: &'a mut dyn core::ToOutputArray, per_view_errors: &'a mut dyn core::ToOutputArray, criteria: core::TermCriteria) -> Self {
+		Self { corners, ids, counter, board, image_size, camera_matrix, dist_coeffs, rvecs, tvecs, std_deviations_intrinsics, std_deviations_extrinsics, per_view_errors, flags: 0, criteria }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_flags(mut self, flags: i32) -> Self {
+		self.flags = flags;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<f64> {
+		calibrate_camera_aruco_extended(self.corners, self.ids, self.counter, self.board, self.image_size, self.camera_matrix, self.dist_coeffs, self.rvecs, self.tvecs, self.std_deviations_intrinsics, self.std_deviations_extrinsics, self.per_view_errors, self.flags, self.criteria)
+	}
+}
+
 /// It's the same function as #calibrateCameraAruco but without calibration error estimation.
 /// 
 /// ## C++ default parameters
@@ -194,12 +234,67 @@ pub fn calibrate_camera_aruco(corners: &dyn core::ToInputArray, ids: &dyn core::
 	output_array_arg!(rvecs);
 	output_array_arg!(tvecs);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_aruco_calibrateCameraAruco_const__InputArrayR_const__InputArrayR_const__InputArrayR_const_Ptr_Board_R_Size_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_int_TermCriteria(corners.as_raw__InputArray(), ids.as_raw__InputArray(), counter.as_raw__InputArray(), board.as_raw_PtrOfBoard(), image_size.opencv_as_extern(), camera_matrix.as_raw__InputOutputArray(), dist_coeffs.as_raw__InputOutputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_aruco_calibrateCameraAruco_const__InputArrayR_const__InputArrayR_const__InputArrayR_const_Ptr_Board_R_Size_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_int_TermCriteria(corners.as_raw__InputArray(), ids.as_raw__InputArray(), counter.as_raw__InputArray(), board.as_raw_PtrOfBoard(), image_size.opencv_as_extern(), camera_matrix.as_raw__InputOutputArray(), dist_coeffs.as_raw__InputOutputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `calibrate_camera_aruco()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CalibrateCameraArucoBuilder<'a> {
+	corners: &'a dyn core::ToInputArray,
+	ids: &'a dyn core::ToInputArray,
+	counter: &'a dyn core::ToInputArray,
+	board: &'a core::Ptr<crate::aruco::Board>,
+	image_size: core::Size,
+	camera_matrix: &'a mut dyn core::ToInputOutputArray,
+	dist_coeffs: &'a mut dyn core::ToInputOutputArray,
+	rvecs: Option<&'a mut dyn core::ToOutputArray>,
+	tvecs: Option<&'a mut dyn core::ToOutputArray>,
+	flags: i32,
+	criteria: core::TermCriteria,
+}
+
+impl<'a> CalibrateCameraArucoBuilder<'a> {
+	#[inline]
+	pub fn new(corners: &'a dyn core::ToInputArray, ids: &'a dyn core::ToInputArray, counter: &'a dyn core::ToInputArray, board: &'a core::Ptr<crate::aruco::Board>, image_size: core::Size, camera_matrix: &'a mut dyn core::ToInputOutputArray, dist_coeffs: &'a mut dyn core::ToInputOutputArray, criteria: core::TermCriteria) -> Self {
+		Self { corners, ids, counter, board, image_size, camera_matrix, dist_coeffs, rvecs: None, tvecs: None, flags: 0, criteria }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_rvecs(mut self, rvecs: &'a mut dyn core::ToOutputArray) -> Self {
+		self.rvecs = Some(rvecs);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_tvecs(mut self, tvecs: &'a mut dyn core::ToOutputArray) -> Self {
+		self.tvecs = Some(tvecs);
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_flags(mut self, flags: i32) -> Self {
+		self.flags = flags;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<f64> {
+		let mut rvecs_no_array = core::no_array();
+		let rvecs: &mut dyn core::ToOutputArray = match self.rvecs { Some(rvecs) => rvecs, None => &mut rvecs_no_array };
+		let mut tvecs_no_array = core::no_array();
+		let tvecs: &mut dyn core::ToOutputArray = match self.tvecs { Some(tvecs) => tvecs, None => &mut tvecs_no_array };
+		calibrate_camera_aruco(self.corners, self.ids, self.counter, self.board, self.image_size, self.camera_matrix, self.dist_coeffs, rvecs, tvecs, self.flags, self.criteria)
+	}
+}
+
 /// Calibrate a camera using Charuco corners
 /// 
 /// ## Parameters
@@ -248,12 +343,51 @@ pub fn calibrate_camera_charuco_extended(charuco_corners: &dyn core::ToInputArra
 	output_array_arg!(std_deviations_extrinsics);
 	output_array_arg!(per_view_errors);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_aruco_calibrateCameraCharuco_const__InputArrayR_const__InputArrayR_const_Ptr_CharucoBoard_R_Size_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int_TermCriteria(charuco_corners.as_raw__InputArray(), charuco_ids.as_raw__InputArray(), board.as_raw_PtrOfCharucoBoard(), image_size.opencv_as_extern(), camera_matrix.as_raw__InputOutputArray(), dist_coeffs.as_raw__InputOutputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), std_deviations_intrinsics.as_raw__OutputArray(), std_deviations_extrinsics.as_raw__OutputArray(), per_view_errors.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_aruco_calibrateCameraCharuco_const__InputArrayR_const__InputArrayR_const_Ptr_CharucoBoard_R_Size_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int_TermCriteria(charuco_corners.as_raw__InputArray(), charuco_ids.as_raw__InputArray(), board.as_raw_PtrOfCharucoBoard(), image_size.opencv_as_extern(), camera_matrix.as_raw__InputOutputArray(), dist_coeffs.as_raw__InputOutputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), std_deviations_intrinsics.as_raw__OutputArray(), std_deviations_extrinsics.as_raw__OutputArray(), per_view_errors.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `calibrate_camera_charuco_extended()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CalibrateCameraCharucoExtendedBuilder<'a> {
+	charuco_corners: &'a dyn core::ToInputArray,
+	charuco_ids: &'a dyn core::ToInputArray,
+	board: &'a core::Ptr<crate::aruco::CharucoBoard>,
+	image_size: core::Size,
+	camera_matrix: &'a mut dyn core::ToInputOutputArray,
+	dist_coeffs: &'a mut dyn core::ToInputOutputArray,
+	rvecs: &'a mut dyn core::ToOutputArray,
+	tvecs: &'a mut dyn core::ToOutputArray,
+	std_deviations_intrinsics: &'a mut dyn core::ToOutputArray,
+	std_deviations_extrinsics: &'a mut dyn core::ToOutputArray,
+	per_view_errors: &'a mut dyn core::ToOutputArray,
+	flags: i32,
+	criteria: core::TermCriteria,
+}
+
+impl<'a> CalibrateCameraCharucoExtendedBuilder<'a> {
+	#[inline]
+	pub fn new(charuco_corners: &'a dyn core::ToInputArray, charuco_ids: &'a dyn core::ToInputArray, board: &'a core::Ptr<crate::aruco::CharucoBoard>, image_size: core::Size, camera_matrix: &'a mut dyn core::ToInputOutputArray, dist_coeffs: &'a mut dyn core::ToInputOutputArray, rvecs: &'a mut dyn core::ToOutputArray, tvecs: &'a mut dyn core::ToOutputArray, std_deviations_intrinsics: &'a mut dyn core::ToOutputArray, std_deviations_extrinsics: &'a mut dyn core::ToOutputArray, per_view_errors: &'a mut dyn core::ToOutputArray, criteria: core::TermCriteria) -> Self {
+		Self { charuco_corners, charuco_ids, board, image_size, camera_matrix, dist_coeffs, rvecs, tvecs, std_deviations_intrinsics, std_deviations_extrinsics, per_view_errors, flags: 0, criteria }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_flags(mut self, flags: i32) -> Self {
+		self.flags = flags;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<f64> {
+		calibrate_camera_charuco_extended(self.charuco_corners, self.charuco_ids, self.board, self.image_size, self.camera_matrix, self.dist_coeffs, self.rvecs, self.tvecs, self.std_deviations_intrinsics, self.std_deviations_extrinsics, self.per_view_errors, self.flags, self.criteria)
+	}
+}
+
 /// It's the same function as #calibrateCameraCharuco but without calibration error estimation.
 /// 
 /// ## C++ default parameters
@@ -270,12 +404,66 @@ pub fn calibrate_camera_charuco(charuco_corners: &dyn core::ToInputArray, charuc
 	output_array_arg!(rvecs);
 	output_array_arg!(tvecs);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_aruco_calibrateCameraCharuco_const__InputArrayR_const__InputArrayR_const_Ptr_CharucoBoard_R_Size_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_int_TermCriteria(charuco_corners.as_raw__InputArray(), charuco_ids.as_raw__InputArray(), board.as_raw_PtrOfCharucoBoard(), image_size.opencv_as_extern(), camera_matrix.as_raw__InputOutputArray(), dist_coeffs.as_raw__InputOutputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_aruco_calibrateCameraCharuco_const__InputArrayR_const__InputArrayR_const_Ptr_CharucoBoard_R_Size_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_int_TermCriteria(charuco_corners.as_raw__InputArray(), charuco_ids.as_raw__InputArray(), board.as_raw_PtrOfCharucoBoard(), image_size.opencv_as_extern(), camera_matrix.as_raw__InputOutputArray(), dist_coeffs.as_raw__InputOutputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `calibrate_camera_charuco()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CalibrateCameraCharucoBuilder<'a> {
+	charuco_corners: &'a dyn core::ToInputArray,
+	charuco_ids: &'a dyn core::ToInputArray,
+	board: &'a core::Ptr<crate::aruco::CharucoBoard>,
+	image_size: core::Size,
+	camera_matrix: &'a mut dyn core::ToInputOutputArray,
+	dist_coeffs: &'a mut dyn core::ToInputOutputArray,
+	rvecs: Option<&'a mut dyn core::ToOutputArray>,
+	tvecs: Option<&'a mut dyn core::ToOutputArray>,
+	flags: i32,
+	criteria: core::TermCriteria,
+}
+
+impl<'a> CalibrateCameraCharucoBuilder<'a> {
+	#[inline]
+	pub fn new(charuco_corners: &'a dyn core::ToInputArray, charuco_ids: &'a dyn core::ToInputArray, board: &'a core::Ptr<crate::aruco::CharucoBoard>, image_size: core::Size, camera_matrix: &'a mut dyn core::ToInputOutputArray, dist_coeffs: &'a mut dyn core::ToInputOutputArray, criteria: core::TermCriteria) -> Self {
+		Self { charuco_corners, charuco_ids, board, image_size, camera_matrix, dist_coeffs, rvecs: None, tvecs: None, flags: 0, criteria }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_rvecs(mut self, rvecs: &'a mut dyn core::ToOutputArray) -> Self {
+		self.rvecs = Some(rvecs);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_tvecs(mut self, tvecs: &'a mut dyn core::ToOutputArray) -> Self {
+		self.tvecs = Some(tvecs);
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_flags(mut self, flags: i32) -> Self {
+		self.flags = flags;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<f64> {
+		let mut rvecs_no_array = core::no_array();
+		let rvecs: &mut dyn core::ToOutputArray = match self.rvecs { Some(rvecs) => rvecs, None => &mut rvecs_no_array };
+		let mut tvecs_no_array = core::no_array();
+		let tvecs: &mut dyn core::ToOutputArray = match self.tvecs { Some(tvecs) => tvecs, None => &mut tvecs_no_array };
+		calibrate_camera_charuco(self.charuco_corners, self.charuco_ids, self.board, self.image_size, self.camera_matrix, self.dist_coeffs, rvecs, tvecs, self.flags, self.criteria)
+	}
+}
+
 /// Detect ChArUco Diamond markers
 /// 
 /// ## Parameters
@@ -311,12 +499,57 @@ pub fn detect_charuco_diamond(image: &dyn core::ToInputArray, marker_corners: &d
 	input_array_arg!(camera_matrix);
 	input_array_arg!(dist_coeffs);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_aruco_detectCharucoDiamond_const__InputArrayR_const__InputArrayR_const__InputArrayR_float_const__OutputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR(image.as_raw__InputArray(), marker_corners.as_raw__InputArray(), marker_ids.as_raw__InputArray(), square_marker_length_rate, diamond_corners.as_raw__OutputArray(), diamond_ids.as_raw__OutputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_aruco_detectCharucoDiamond_const__InputArrayR_const__InputArrayR_const__InputArrayR_float_const__OutputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR(image.as_raw__InputArray(), marker_corners.as_raw__InputArray(), marker_ids.as_raw__InputArray(), square_marker_length_rate, diamond_corners.as_raw__OutputArray(), diamond_ids.as_raw__OutputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `detect_charuco_diamond()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct DetectCharucoDiamondBuilder<'a> {
+	image: &'a dyn core::ToInputArray,
+	marker_corners: &'a dyn core::ToInputArray,
+	marker_ids: &'a dyn core::ToInputArray,
+	square_marker_length_rate: f32,
+	diamond_corners: &'a mut dyn core::ToOutputArray,
+	diamond_ids: &'a mut dyn core::ToOutputArray,
+	camera_matrix: Option<&'a dyn core::ToInputArray>,
+	dist_coeffs: Option<&'a dyn core::ToInputArray>,
+}
+
+impl<'a> DetectCharucoDiamondBuilder<'a> {
+	#[inline]
+	pub fn new(image: &'a dyn core::ToInputArray, marker_corners: &'a dyn core::ToInputArray, marker_ids: &'a dyn core::ToInputArray, square_marker_length_rate: f32, diamond_corners: &'a mut dyn core::ToOutputArray, diamond_ids: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { image, marker_corners, marker_ids, square_marker_length_rate, diamond_corners, diamond_ids, camera_matrix: None, dist_coeffs: None }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_camera_matrix(mut self, camera_matrix: &'a dyn core::ToInputArray) -> Self {
+		self.camera_matrix = Some(camera_matrix);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_dist_coeffs(mut self, dist_coeffs: &'a dyn core::ToInputArray) -> Self {
+		self.dist_coeffs = Some(dist_coeffs);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let camera_matrix_no_array = core::no_array();
+		let camera_matrix: &dyn core::ToInputArray = match self.camera_matrix { Some(camera_matrix) => camera_matrix, None => &camera_matrix_no_array };
+		let dist_coeffs_no_array = core::no_array();
+		let dist_coeffs: &dyn core::ToInputArray = match self.dist_coeffs { Some(dist_coeffs) => dist_coeffs, None => &dist_coeffs_no_array };
+		detect_charuco_diamond(self.image, self.marker_corners, self.marker_ids, self.square_marker_length_rate, self.diamond_corners, self.diamond_ids, camera_matrix, dist_coeffs)
+	}
+}
+
 /// Basic marker detection
 /// 
 /// ## Parameters
@@ -341,7 +574,7 @@ pub fn detect_charuco_diamond(image: &dyn core::ToInputArray, marker_corners: &d
 /// and its corresponding identifier.
 /// Note that this function does not perform pose estimation.
 /// ## See also
-/// estimatePoseSingleMarkers,  estimatePoseBoard
+/// [estimate_pose_single_markers](crate::aruco::estimate_pose_single_markers),  [estimate_pose_board](crate::aruco::estimate_pose_board)
 /// 
 /// ## C++ default parameters
 /// * parameters: DetectorParameters::create()
@@ -357,12 +590,66 @@ pub fn detect_markers(image: &dyn core::ToInputArray, dictionary: &core::Ptr<cra
 	input_array_arg!(camera_matrix);
 	input_array_arg!(dist_coeff);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_aruco_detectMarkers_const__InputArrayR_const_Ptr_Dictionary_R_const__OutputArrayR_const__OutputArrayR_const_Ptr_DetectorParameters_R_const__OutputArrayR_const__InputArrayR_const__InputArrayR(image.as_raw__InputArray(), dictionary.as_raw_PtrOfDictionary(), corners.as_raw__OutputArray(), ids.as_raw__OutputArray(), parameters.as_raw_PtrOfDetectorParameters(), rejected_img_points.as_raw__OutputArray(), camera_matrix.as_raw__InputArray(), dist_coeff.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_aruco_detectMarkers_const__InputArrayR_const_Ptr_Dictionary_R_const__OutputArrayR_const__OutputArrayR_const_Ptr_DetectorParameters_R_const__OutputArrayR_const__InputArrayR_const__InputArrayR(image.as_raw__InputArray(), dictionary.as_raw_PtrOfDictionary(), corners.as_raw__OutputArray(), ids.as_raw__OutputArray(), parameters.as_raw_PtrOfDetectorParameters(), rejected_img_points.as_raw__OutputArray(), camera_matrix.as_raw__InputArray(), dist_coeff.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `detect_markers()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct DetectMarkersBuilder<'a> {
+	image: &'a dyn core::ToInputArray,
+	dictionary: &'a core::Ptr<crate::aruco::Dictionary>,
+	corners: &'a mut dyn core::ToOutputArray,
+	ids: &'a mut dyn core::ToOutputArray,
+	parameters: &'a core::Ptr<crate::aruco::DetectorParameters>,
+	rejected_img_points: Option<&'a mut dyn core::ToOutputArray>,
+	camera_matrix: Option<&'a dyn core::ToInputArray>,
+	dist_coeff: Option<&'a dyn core::ToInputArray>,
+}
+
+impl<'a> DetectMarkersBuilder<'a> {
+	#[inline]
+	pub fn new(image: &'a dyn core::ToInputArray, dictionary: &'a core::Ptr<crate::aruco::Dictionary>, corners: &'a mut dyn core::ToOutputArray, ids: &'a mut dyn core::ToOutputArray, parameters: &'a core::Ptr<crate::aruco::DetectorParameters>) -> Self {
+		Self { image, dictionary, corners, ids, parameters, rejected_img_points: None, camera_matrix: None, dist_coeff: None }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_rejected_img_points(mut self, rejected_img_points: &'a mut dyn core::ToOutputArray) -> Self {
+		self.rejected_img_points = Some(rejected_img_points);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_camera_matrix(mut self, camera_matrix: &'a dyn core::ToInputArray) -> Self {
+		self.camera_matrix = Some(camera_matrix);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_dist_coeff(mut self, dist_coeff: &'a dyn core::ToInputArray) -> Self {
+		self.dist_coeff = Some(dist_coeff);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let mut rejected_img_points_no_array = core::no_array();
+		let rejected_img_points: &mut dyn core::ToOutputArray = match self.rejected_img_points { Some(rejected_img_points) => rejected_img_points, None => &mut rejected_img_points_no_array };
+		let camera_matrix_no_array = core::no_array();
+		let camera_matrix: &dyn core::ToInputArray = match self.camera_matrix { Some(camera_matrix) => camera_matrix, None => &camera_matrix_no_array };
+		let dist_coeff_no_array = core::no_array();
+		let dist_coeff: &dyn core::ToInputArray = match self.dist_coeff { Some(dist_coeff) => dist_coeff, None => &dist_coeff_no_array };
+		detect_markers(self.image, self.dictionary, self.corners, self.ids, self.parameters, rejected_img_points, camera_matrix, dist_coeff)
+	}
+}
+
 /// Draw coordinate system axis from pose estimation
 /// 
 /// ## Parameters
@@ -390,7 +677,7 @@ pub fn draw_axis(image: &mut dyn core::ToInputOutputArray, camera_matrix: &dyn c
 	input_array_arg!(rvec);
 	input_array_arg!(tvec);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_aruco_drawAxis_const__InputOutputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_float(image.as_raw__InputOutputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvec.as_raw__InputArray(), tvec.as_raw__InputArray(), length, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_aruco_drawAxis_const__InputOutputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_float(image.as_raw__InputOutputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvec.as_raw__InputArray(), tvec.as_raw__InputArray(), length, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -417,12 +704,52 @@ pub fn draw_axis(image: &mut dyn core::ToInputOutputArray, camera_matrix: &dyn c
 pub fn draw_charuco_diamond(dictionary: &core::Ptr<crate::aruco::Dictionary>, ids: core::Vec4i, square_length: i32, marker_length: i32, img: &mut dyn core::ToOutputArray, margin_size: i32, border_bits: i32) -> Result<()> {
 	output_array_arg!(img);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_aruco_drawCharucoDiamond_const_Ptr_Dictionary_R_Vec4i_int_int_const__OutputArrayR_int_int(dictionary.as_raw_PtrOfDictionary(), ids.opencv_as_extern(), square_length, marker_length, img.as_raw__OutputArray(), margin_size, border_bits, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_aruco_drawCharucoDiamond_const_Ptr_Dictionary_R_Vec4i_int_int_const__OutputArrayR_int_int(dictionary.as_raw_PtrOfDictionary(), ids.opencv_as_extern(), square_length, marker_length, img.as_raw__OutputArray(), margin_size, border_bits, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `draw_charuco_diamond()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct DrawCharucoDiamondBuilder<'a> {
+	dictionary: &'a core::Ptr<crate::aruco::Dictionary>,
+	ids: core::Vec4i,
+	square_length: i32,
+	marker_length: i32,
+	img: &'a mut dyn core::ToOutputArray,
+	margin_size: i32,
+	border_bits: i32,
+}
+
+impl<'a> DrawCharucoDiamondBuilder<'a> {
+	#[inline]
+	pub fn new(dictionary: &'a core::Ptr<crate::aruco::Dictionary>, ids: core::Vec4i, square_length: i32, marker_length: i32, img: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { dictionary, ids, square_length, marker_length, img, margin_size: 0, border_bits: 1 }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_margin_size(mut self, margin_size: i32) -> Self {
+		self.margin_size = margin_size;
+		self
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_border_bits(mut self, border_bits: i32) -> Self {
+		self.border_bits = border_bits;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		draw_charuco_diamond(self.dictionary, self.ids, self.square_length, self.marker_length, self.img, self.margin_size, self.border_bits)
+	}
+}
+
 /// Draws a set of Charuco corners
 /// ## Parameters
 /// * image: input/output image. It must have 1 or 3 channels. The number of channels is not
@@ -443,7 +770,7 @@ pub fn draw_detected_corners_charuco(image: &mut dyn core::ToInputOutputArray, c
 	input_array_arg!(charuco_corners);
 	input_array_arg!(charuco_ids);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_aruco_drawDetectedCornersCharuco_const__InputOutputArrayR_const__InputArrayR_const__InputArrayR_Scalar(image.as_raw__InputOutputArray(), charuco_corners.as_raw__InputArray(), charuco_ids.as_raw__InputArray(), corner_color.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_aruco_drawDetectedCornersCharuco_const__InputOutputArrayR_const__InputArrayR_const__InputArrayR_Scalar(image.as_raw__InputOutputArray(), charuco_corners.as_raw__InputArray(), charuco_ids.as_raw__InputArray(), corner_color.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -476,7 +803,7 @@ pub fn draw_detected_diamonds(image: &mut dyn core::ToInputOutputArray, diamond_
 	input_array_arg!(diamond_corners);
 	input_array_arg!(diamond_ids);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_aruco_drawDetectedDiamonds_const__InputOutputArrayR_const__InputArrayR_const__InputArrayR_Scalar(image.as_raw__InputOutputArray(), diamond_corners.as_raw__InputArray(), diamond_ids.as_raw__InputArray(), border_color.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_aruco_drawDetectedDiamonds_const__InputOutputArrayR_const__InputArrayR_const__InputArrayR_Scalar(image.as_raw__InputOutputArray(), diamond_corners.as_raw__InputArray(), diamond_ids.as_raw__InputArray(), border_color.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -508,7 +835,7 @@ pub fn draw_detected_markers(image: &mut dyn core::ToInputOutputArray, corners:
 	input_array_arg!(corners);
 	input_array_arg!(ids);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_aruco_drawDetectedMarkers_const__InputOutputArrayR_const__InputArrayR_const__InputArrayR_Scalar(image.as_raw__InputOutputArray(), corners.as_raw__InputArray(), ids.as_raw__InputArray(), border_color.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_aruco_drawDetectedMarkers_const__InputOutputArrayR_const__InputArrayR_const__InputArrayR_Scalar(image.as_raw__InputOutputArray(), corners.as_raw__InputArray(), ids.as_raw__InputArray(), border_color.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -532,7 +859,7 @@ pub fn draw_detected_markers(image: &mut dyn core::ToInputOutputArray, corners:
 pub fn draw_marker(dictionary: &core::Ptr<crate::aruco::Dictionary>, id: i32, side_pixels: i32, img: &mut dyn core::ToOutputArray, border_bits: i32) -> Result<()> {
 	output_array_arg!(img);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_aruco_drawMarker_const_Ptr_Dictionary_R_int_int_const__OutputArrayR_int(dictionary.as_raw_PtrOfDictionary(), id, side_pixels, img.as_raw__OutputArray(), border_bits, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_aruco_drawMarker_const_Ptr_Dictionary_R_int_int_const__OutputArrayR_int(dictionary.as_raw_PtrOfDictionary(), id, side_pixels, img.as_raw__OutputArray(), border_bits, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -540,7 +867,7 @@ pub fn draw_marker(dictionary: &core::Ptr<crate::aruco::Dictionary>, id: i32, si
 
 /// Draw a planar board
 /// ## See also
-/// _drawPlanarBoardImpl
+/// `_drawPlanarBoardImpl`
 /// 
 /// ## Parameters
 /// * board: layout of the board that will be drawn. The board should be planar,
@@ -561,7 +888,7 @@ pub fn draw_marker(dictionary: &core::Ptr<crate::aruco::Dictionary>, id: i32, si
 pub fn draw_planar_board(board: &core::Ptr<crate::aruco::Board>, out_size: core::Size, img: &mut dyn core::ToOutputArray, margin_size: i32, border_bits: i32) -> Result<()> {
 	output_array_arg!(img);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_aruco_drawPlanarBoard_const_Ptr_Board_R_Size_const__OutputArrayR_int_int(board.as_raw_PtrOfBoard(), out_size.opencv_as_extern(), img.as_raw__OutputArray(), margin_size, border_bits, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_aruco_drawPlanarBoard_const_Ptr_Board_R_Size_const__OutputArrayR_int_int(board.as_raw_PtrOfBoard(), out_size.opencv_as_extern(), img.as_raw__OutputArray(), margin_size, border_bits, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -606,12 +933,46 @@ pub fn estimate_pose_board(corners: &dyn core::ToInputArray, ids: &dyn core::ToI
 	input_output_array_arg!(rvec);
 	input_output_array_arg!(tvec);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_aruco_estimatePoseBoard_const__InputArrayR_const__InputArrayR_const_Ptr_Board_R_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_bool(corners.as_raw__InputArray(), ids.as_raw__InputArray(), board.as_raw_PtrOfBoard(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvec.as_raw__InputOutputArray(), tvec.as_raw__InputOutputArray(), use_extrinsic_guess, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_aruco_estimatePoseBoard_const__InputArrayR_const__InputArrayR_const_Ptr_Board_R_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_bool(corners.as_raw__InputArray(), ids.as_raw__InputArray(), board.as_raw_PtrOfBoard(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvec.as_raw__InputOutputArray(), tvec.as_raw__InputOutputArray(), use_extrinsic_guess, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `estimate_pose_board()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct EstimatePoseBoardBuilder<'a> {
+	corners: &'a dyn core::ToInputArray,
+	ids: &'a dyn core::ToInputArray,
+	board: &'a core::Ptr<crate::aruco::Board>,
+	camera_matrix: &'a dyn core::ToInputArray,
+	dist_coeffs: &'a dyn core::ToInputArray,
+	rvec: &'a mut dyn core::ToInputOutputArray,
+	tvec: &'a mut dyn core::ToInputOutputArray,
+	use_extrinsic_guess: bool,
+}
+
+impl<'a> EstimatePoseBoardBuilder<'a> {
+	#[inline]
+	pub fn new(corners: &'a dyn core::ToInputArray, ids: &'a dyn core::ToInputArray, board: &'a core::Ptr<crate::aruco::Board>, camera_matrix: &'a dyn core::ToInputArray, dist_coeffs: &'a dyn core::ToInputArray, rvec: &'a mut dyn core::ToInputOutputArray, tvec: &'a mut dyn core::ToInputOutputArray) -> Self {
+		Self { corners, ids, board, camera_matrix, dist_coeffs, rvec, tvec, use_extrinsic_guess: false }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_use_extrinsic_guess(mut self, use_extrinsic_guess: bool) -> Self {
+		self.use_extrinsic_guess = use_extrinsic_guess;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<i32> {
+		estimate_pose_board(self.corners, self.ids, self.board, self.camera_matrix, self.dist_coeffs, self.rvec, self.tvec, self.use_extrinsic_guess)
+	}
+}
+
 /// Pose estimation for a ChArUco board given some of their corners
 /// ## Parameters
 /// * charucoCorners: vector of detected charuco corners
@@ -641,12 +1002,46 @@ pub fn estimate_pose_charuco_board(charuco_corners: &dyn core::ToInputArray, cha
 	input_output_array_arg!(rvec);
 	input_output_array_arg!(tvec);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_aruco_estimatePoseCharucoBoard_const__InputArrayR_const__InputArrayR_const_Ptr_CharucoBoard_R_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_bool(charuco_corners.as_raw__InputArray(), charuco_ids.as_raw__InputArray(), board.as_raw_PtrOfCharucoBoard(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvec.as_raw__InputOutputArray(), tvec.as_raw__InputOutputArray(), use_extrinsic_guess, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_aruco_estimatePoseCharucoBoard_const__InputArrayR_const__InputArrayR_const_Ptr_CharucoBoard_R_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_bool(charuco_corners.as_raw__InputArray(), charuco_ids.as_raw__InputArray(), board.as_raw_PtrOfCharucoBoard(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvec.as_raw__InputOutputArray(), tvec.as_raw__InputOutputArray(), use_extrinsic_guess, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `estimate_pose_charuco_board()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct EstimatePoseCharucoBoardBuilder<'a> {
+	charuco_corners: &'a dyn core::ToInputArray,
+	charuco_ids: &'a dyn core::ToInputArray,
+	board: &'a core::Ptr<crate::aruco::CharucoBoard>,
+	camera_matrix: &'a dyn core::ToInputArray,
+	dist_coeffs: &'a dyn core::ToInputArray,
+	rvec: &'a mut dyn core::ToInputOutputArray,
+	tvec: &'a mut dyn core::ToInputOutputArray,
+	use_extrinsic_guess: bool,
+}
+
+impl<'a> EstimatePoseCharucoBoardBuilder<'a> {
+	#[inline]
+	pub fn new(charuco_corners: &'a dyn core::ToInputArray, charuco_ids: &'a dyn core::ToInputArray, board: &'a core::Ptr<crate::aruco::CharucoBoard>, camera_matrix: &'a dyn core::ToInputArray, dist_coeffs: &'a dyn core::ToInputArray, rvec: &'a mut dyn core::ToInputOutputArray, tvec: &'a mut dyn core::ToInputOutputArray) -> Self {
+		Self { charuco_corners, charuco_ids, board, camera_matrix, dist_coeffs, rvec, tvec, use_extrinsic_guess: false }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_use_extrinsic_guess(mut self, use_extrinsic_guess: bool) -> Self {
+		self.use_extrinsic_guess = use_extrinsic_guess;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<bool> {
+		estimate_pose_charuco_board(self.charuco_corners, self.charuco_ids, self.board, self.camera_matrix, self.dist_coeffs, self.rvec, self.tvec, self.use_extrinsic_guess)
+	}
+}
+
 /// Pose estimation for single markers
 /// 
 /// ## Parameters
@@ -654,7 +1049,7 @@ pub fn estimate_pose_charuco_board(charuco_corners: &dyn core::ToInputArray, cha
 /// are provided, (e.g std::vector<std::vector<cv::Point2f> > ). For N detected markers,
 /// the dimensions of this array should be Nx4. The order of the corners should be clockwise.
 /// ## See also
-/// detectMarkers
+/// [detect_markers](crate::aruco::detect_markers)
 /// * markerLength: the length of the markers' side. The returning translation vectors will
 /// be in the same unit. Normally, unit is meters.
 /// * cameraMatrix: input 3x3 floating-point camera matrix
@@ -688,12 +1083,47 @@ pub fn estimate_pose_single_markers(corners: &dyn core::ToInputArray, marker_len
 	output_array_arg!(tvecs);
 	output_array_arg!(_obj_points);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_aruco_estimatePoseSingleMarkers_const__InputArrayR_float_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(corners.as_raw__InputArray(), marker_length, camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), _obj_points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_aruco_estimatePoseSingleMarkers_const__InputArrayR_float_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(corners.as_raw__InputArray(), marker_length, camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), _obj_points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `estimate_pose_single_markers()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct EstimatePoseSingleMarkersBuilder<'a> {
+	corners: &'a dyn core::ToInputArray,
+	marker_length: f32,
+	camera_matrix: &'a dyn core::ToInputArray,
+	dist_coeffs: &'a dyn core::ToInputArray,
+	rvecs: &'a mut dyn core::ToOutputArray,
+	tvecs: &'a mut dyn core::ToOutputArray,
+	_obj_points: Option<&'a mut dyn core::ToOutputArray>,
+}
+
+impl<'a> EstimatePoseSingleMarkersBuilder<'a> {
+	#[inline]
+	pub fn new(corners: &'a dyn core::ToInputArray, marker_length: f32, camera_matrix: &'a dyn core::ToInputArray, dist_coeffs: &'a dyn core::ToInputArray, rvecs: &'a mut dyn core::ToOutputArray, tvecs: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { corners, marker_length, camera_matrix, dist_coeffs, rvecs, tvecs, _obj_points: None }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with__obj_points(mut self, _obj_points: &'a mut dyn core::ToOutputArray) -> Self {
+		self._obj_points = Some(_obj_points);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let mut _obj_points_no_array = core::no_array();
+		let _obj_points: &mut dyn core::ToOutputArray = match self._obj_points { Some(_obj_points) => _obj_points, None => &mut _obj_points_no_array };
+		estimate_pose_single_markers(self.corners, self.marker_length, self.camera_matrix, self.dist_coeffs, self.rvecs, self.tvecs, _obj_points)
+	}
+}
+
 /// Generates a new customizable marker dictionary
 /// 
 /// ## Parameters
@@ -712,7 +1142,7 @@ pub fn estimate_pose_single_markers(corners: &dyn core::ToInputArray, marker_len
 #[inline]
 pub fn custom_dictionary_from(n_markers: i32, marker_size: i32, base_dictionary: &core::Ptr<crate::aruco::Dictionary>, random_seed: i32) -> Result<core::Ptr<crate::aruco::Dictionary>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_aruco_generateCustomDictionary_int_int_const_Ptr_Dictionary_R_int(n_markers, marker_size, base_dictionary.as_raw_PtrOfDictionary(), random_seed, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_aruco_generateCustomDictionary_int_int_const_Ptr_Dictionary_R_int(n_markers, marker_size, base_dictionary.as_raw_PtrOfDictionary(), random_seed, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<crate::aruco::Dictionary>::opencv_from_extern(ret) };
@@ -720,14 +1150,14 @@ pub fn custom_dictionary_from(n_markers: i32, marker_size: i32, base_dictionary:
 }
 
 /// ## See also
-/// generateCustomDictionary
+/// `generate_custom_dictionary`
 /// 
 /// ## C++ default parameters
 /// * random_seed: 0
 #[inline]
 pub fn custom_dictionary(n_markers: i32, marker_size: i32, random_seed: i32) -> Result<core::Ptr<crate::aruco::Dictionary>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_aruco_generateCustomDictionary_int_int_int(n_markers, marker_size, random_seed, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_aruco_generateCustomDictionary_int_int_int(n_markers, marker_size, random_seed, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<crate::aruco::Dictionary>::opencv_from_extern(ret) };
@@ -750,7 +1180,7 @@ pub fn get_board_object_and_image_points(board: &core::Ptr<crate::aruco::Board>,
 	output_array_arg!(obj_points);
 	output_array_arg!(img_points);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_aruco_getBoardObjectAndImagePoints_const_Ptr_Board_R_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(board.as_raw_PtrOfBoard(), detected_corners.as_raw__InputArray(), detected_ids.as_raw__InputArray(), obj_points.as_raw__OutputArray(), img_points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_aruco_getBoardObjectAndImagePoints_const_Ptr_Board_R_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(board.as_raw_PtrOfBoard(), detected_corners.as_raw__InputArray(), detected_ids.as_raw__InputArray(), obj_points.as_raw__OutputArray(), img_points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -760,7 +1190,7 @@ pub fn get_board_object_and_image_points(board: &core::Ptr<crate::aruco::Board>,
 #[inline]
 pub fn get_predefined_dictionary(name: crate::aruco::PREDEFINED_DICTIONARY_NAME) -> Result<core::Ptr<crate::aruco::Dictionary>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_aruco_getPredefinedDictionary_PREDEFINED_DICTIONARY_NAME(name, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_aruco_getPredefinedDictionary_PREDEFINED_DICTIONARY_NAME(name, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<crate::aruco::Dictionary>::opencv_from_extern(ret) };
@@ -771,7 +1201,7 @@ pub fn get_predefined_dictionary(name: crate::aruco::PREDEFINED_DICTIONARY_NAME)
 #[inline]
 pub fn get_predefined_dictionary_i32(dict: i32) -> Result<core::Ptr<crate::aruco::Dictionary>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_aruco_getPredefinedDictionary_int(dict, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_aruco_getPredefinedDictionary_int(dict, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<crate::aruco::Dictionary>::opencv_from_extern(ret) };
@@ -816,12 +1246,65 @@ pub fn interpolate_corners_charuco(marker_corners: &dyn core::ToInputArray, mark
 	input_array_arg!(camera_matrix);
 	input_array_arg!(dist_coeffs);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_aruco_interpolateCornersCharuco_const__InputArrayR_const__InputArrayR_const__InputArrayR_const_Ptr_CharucoBoard_R_const__OutputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_int(marker_corners.as_raw__InputArray(), marker_ids.as_raw__InputArray(), image.as_raw__InputArray(), board.as_raw_PtrOfCharucoBoard(), charuco_corners.as_raw__OutputArray(), charuco_ids.as_raw__OutputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), min_markers, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_aruco_interpolateCornersCharuco_const__InputArrayR_const__InputArrayR_const__InputArrayR_const_Ptr_CharucoBoard_R_const__OutputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_int(marker_corners.as_raw__InputArray(), marker_ids.as_raw__InputArray(), image.as_raw__InputArray(), board.as_raw_PtrOfCharucoBoard(), charuco_corners.as_raw__OutputArray(), charuco_ids.as_raw__OutputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), min_markers, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `interpolate_corners_charuco()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct InterpolateCornersCharucoBuilder<'a> {
+	marker_corners: &'a dyn core::ToInputArray,
+	marker_ids: &'a dyn core::ToInputArray,
+	image: &'a dyn core::ToInputArray,
+	board: &'a core::Ptr<crate::aruco::CharucoBoard>,
+	charuco_corners: &'a mut dyn core::ToOutputArray,
+	charuco_ids: &'a mut dyn core::ToOutputArray,
+	camera_matrix: Option<&'a dyn core::ToInputArray>,
+	dist_coeffs: Option<&'a dyn core::ToInputArray>,
+	min_markers: i32,
+}
+
+impl<'a> InterpolateCornersCharucoBuilder<'a> {
+	#[inline]
+	pub fn new(marker_corners: &'a dyn core::ToInputArray, marker_ids: &'a dyn core::ToInputArray, image: &'a dyn core::ToInputArray, board: &'a core::Ptr<crate::aruco::CharucoBoard>, charuco_corners: &'a mut dyn core::ToOutputArray, charuco_ids: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { marker_corners, marker_ids, image, board, charuco_corners, charuco_ids, camera_matrix: None, dist_coeffs: None, min_markers: 2 }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_camera_matrix(mut self, camera_matrix: &'a dyn core::ToInputArray) -> Self {
+		self.camera_matrix = Some(camera_matrix);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_dist_coeffs(mut self, dist_coeffs: &'a dyn core::ToInputArray) -> Self {
+		self.dist_coeffs = Some(dist_coeffs);
+		self
+	}
+	
+	/// C++ default: `2`
+	#[inline]
+	pub fn with_min_markers(mut self, min_markers: i32) -> Self {
+		self.min_markers = min_markers;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<i32> {
+		let camera_matrix_no_array = core::no_array();
+		let camera_matrix: &dyn core::ToInputArray = match self.camera_matrix { Some(camera_matrix) => camera_matrix, None => &camera_matrix_no_array };
+		let dist_coeffs_no_array = core::no_array();
+		let dist_coeffs: &dyn core::ToInputArray = match self.dist_coeffs { Some(dist_coeffs) => dist_coeffs, None => &dist_coeffs_no_array };
+		interpolate_corners_charuco(self.marker_corners, self.marker_ids, self.image, self.board, self.charuco_corners, self.charuco_ids, camera_matrix, dist_coeffs, self.min_markers)
+	}
+}
+
 /// Refind not detected markers based on the already detected and the board layout
 /// 
 /// ## Parameters
@@ -871,12 +1354,91 @@ pub fn refine_detected_markers(image: &dyn core::ToInputArray, board: &core::Ptr
 	input_array_arg!(dist_coeffs);
 	output_array_arg!(recovered_idxs);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_aruco_refineDetectedMarkers_const__InputArrayR_const_Ptr_Board_R_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__InputArrayR_const__InputArrayR_float_float_bool_const__OutputArrayR_const_Ptr_DetectorParameters_R(image.as_raw__InputArray(), board.as_raw_PtrOfBoard(), detected_corners.as_raw__InputOutputArray(), detected_ids.as_raw__InputOutputArray(), rejected_corners.as_raw__InputOutputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), min_rep_distance, error_correction_rate, check_all_orders, recovered_idxs.as_raw__OutputArray(), parameters.as_raw_PtrOfDetectorParameters(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_aruco_refineDetectedMarkers_const__InputArrayR_const_Ptr_Board_R_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__InputArrayR_const__InputArrayR_float_float_bool_const__OutputArrayR_const_Ptr_DetectorParameters_R(image.as_raw__InputArray(), board.as_raw_PtrOfBoard(), detected_corners.as_raw__InputOutputArray(), detected_ids.as_raw__InputOutputArray(), rejected_corners.as_raw__InputOutputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), min_rep_distance, error_correction_rate, check_all_orders, recovered_idxs.as_raw__OutputArray(), parameters.as_raw_PtrOfDetectorParameters(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `refine_detected_markers()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct RefineDetectedMarkersBuilder<'a> {
+	image: &'a dyn core::ToInputArray,
+	board: &'a core::Ptr<crate::aruco::Board>,
+	detected_corners: &'a mut dyn core::ToInputOutputArray,
+	detected_ids: &'a mut dyn core::ToInputOutputArray,
+	rejected_corners: &'a mut dyn core::ToInputOutputArray,
+	camera_matrix: Option<&'a dyn core::ToInputArray>,
+	dist_coeffs: Option<&'a dyn core::ToInputArray>,
+	min_rep_distance: f32,
+	error_correction_rate: f32,
+	check_all_orders: bool,
+	recovered_idxs: Option<&'a mut dyn core::ToOutputArray>,
+	parameters: &'a core::Ptr<crate::aruco::DetectorParameters>,
+}
+
+impl<'a> RefineDetectedMarkersBuilder<'a> {
+	#[inline]
+	pub fn new(image: &'a dyn core::ToInputArray, board: &'a core::Ptr<crate::aruco::Board>, detected_corners: &'a mut dyn core::ToInputOutputArray, detected_ids: &'a mut dyn core::ToInputOutputArray, rejected_corners: &'a mut dyn core::ToInputOutputArray, parameters: &'a core::Ptr<crate::aruco::DetectorParameters>) -> Self {
+		Self { image, board, detected_corners, detected_ids, rejected_corners, camera_matrix: None, dist_coeffs: None, min_rep_distance: 10., error_correction_rate: 3., check_all_orders: true, recovered_idxs: None, parameters }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_camera_matrix(mut self, camera_matrix: &'a dyn core::ToInputArray) -> Self {
+		self.camera_matrix = Some(camera_matrix);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_dist_coeffs(mut self, dist_coeffs: &'a dyn core::ToInputArray) -> Self {
+		self.dist_coeffs = Some(dist_coeffs);
+		self
+	}
+	
+	/// C++ default: `10.f`
+	#[inline]
+	pub fn with_min_rep_distance(mut self, min_rep_distance: f32) -> Self {
+		self.min_rep_distance = min_rep_distance;
+		self
+	}
+	
+	/// C++ default: `3.f`
+	#[inline]
+	pub fn with_error_correction_rate(mut self, error_correction_rate: f32) -> Self {
+		self.error_correction_rate = error_correction_rate;
+		self
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_check_all_orders(mut self, check_all_orders: bool) -> Self {
+		self.check_all_orders = check_all_orders;
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_recovered_idxs(mut self, recovered_idxs: &'a mut dyn core::ToOutputArray) -> Self {
+		self.recovered_idxs = Some(recovered_idxs);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let camera_matrix_no_array = core::no_array();
+		let camera_matrix: &dyn core::ToInputArray = match self.camera_matrix { Some(camera_matrix) => camera_matrix, None => &camera_matrix_no_array };
+		let dist_coeffs_no_array = core::no_array();
+		let dist_coeffs: &dyn core::ToInputArray = match self.dist_coeffs { Some(dist_coeffs) => dist_coeffs, None => &dist_coeffs_no_array };
+		let mut recovered_idxs_no_array = core::no_array();
+		let recovered_idxs: &mut dyn core::ToOutputArray = match self.recovered_idxs { Some(recovered_idxs) => recovered_idxs, None => &mut recovered_idxs_no_array };
+		refine_detected_markers(self.image, self.board, self.detected_corners, self.detected_ids, self.rejected_corners, camera_matrix, dist_coeffs, self.min_rep_distance, self.error_correction_rate, self.check_all_orders, recovered_idxs, self.parameters)
+	}
+}
+
 /// test whether the ChArUco markers are collinear
 /// 
 /// ## Parameters
@@ -891,7 +1453,7 @@ pub fn refine_detected_markers(image: &dyn core::ToInputArray, board: &core::Ptr
 pub fn test_charuco_corners_collinear(_board: &core::Ptr<crate::aruco::CharucoBoard>, _charuco_ids: &dyn core::ToInputArray) -> Result<bool> {
 	input_array_arg!(_charuco_ids);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_aruco_testCharucoCornersCollinear_const_Ptr_CharucoBoard_R_const__InputArrayR(_board.as_raw_PtrOfCharucoBoard(), _charuco_ids.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_aruco_testCharucoCornersCollinear_const_Ptr_CharucoBoard_R_const__InputArrayR(_board.as_raw_PtrOfCharucoBoard(), _charuco_ids.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -912,20 +1474,38 @@ pub trait BoardTraitConst {
 	/// each marker include its 4 corners in CCW order. For M markers, the size is Mx4.
 	#[inline]
 	fn obj_points(&self) -> core::Vector<core::Vector<core::Point3f>> {
-		let ret = unsafe { sys::cv_aruco_Board_getPropObjPoints_const(self.as_raw_Board()) };
+		crate::core::validity::debug_check("Board", self.as_raw_Board());
+		let ret = unsafe { ffi_call!(cv_aruco_Board_getPropObjPoints_const(self.as_raw_Board())) };
 		let ret = unsafe { core::Vector::<core::Vector<core::Point3f>>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [obj_points](BoardTraitConst::obj_points), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_obj_points(&self) -> Result<core::Vector<core::Vector<core::Point3f>>> {
+		crate::core::validity::check("Board", self.as_raw_Board())?;
+		Ok(BoardTraitConst::obj_points(self))
+	}
+	
 	/// vector of the identifiers of the markers in the board (same size than objPoints)
 	/// The identifiers refers to the board dictionary
 	#[inline]
 	fn ids(&self) -> core::Vector<i32> {
-		let ret = unsafe { sys::cv_aruco_Board_getPropIds_const(self.as_raw_Board()) };
+		crate::core::validity::debug_check("Board", self.as_raw_Board());
+		let ret = unsafe { ffi_call!(cv_aruco_Board_getPropIds_const(self.as_raw_Board())) };
 		let ret = unsafe { core::Vector::<i32>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [ids](BoardTraitConst::ids), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_ids(&self) -> Result<core::Vector<i32>> {
+		crate::core::validity::check("Board", self.as_raw_Board())?;
+		Ok(BoardTraitConst::ids(self))
+	}
+	
 }
 
 pub trait BoardTrait: crate::aruco::BoardTraitConst {
@@ -935,22 +1515,33 @@ pub trait BoardTrait: crate::aruco::BoardTraitConst {
 	/// each marker include its 4 corners in CCW order. For M markers, the size is Mx4.
 	#[inline]
 	fn set_obj_points(&mut self, mut val: core::Vector<core::Vector<core::Point3f>>) {
-		let ret = unsafe { sys::cv_aruco_Board_setPropObjPoints_vector_vector_Point3f__(self.as_raw_mut_Board(), val.as_raw_mut_VectorOfVectorOfPoint3f()) };
+		crate::core::validity::debug_check("Board", self.as_raw_mut_Board());
+		let ret = unsafe { ffi_call!(cv_aruco_Board_setPropObjPoints_vector_vector_Point3f__(self.as_raw_mut_Board(), val.as_raw_mut_VectorOfVectorOfPoint3f())) };
 		ret
 	}
 	
 	/// the dictionary of markers employed for this board
 	#[inline]
 	fn dictionary(&mut self) -> core::Ptr<crate::aruco::Dictionary> {
-		let ret = unsafe { sys::cv_aruco_Board_getPropDictionary(self.as_raw_mut_Board()) };
+		crate::core::validity::debug_check("Board", self.as_raw_mut_Board());
+		let ret = unsafe { ffi_call!(cv_aruco_Board_getPropDictionary(self.as_raw_mut_Board())) };
 		let ret = unsafe { core::Ptr::<crate::aruco::Dictionary>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [dictionary](BoardTrait::dictionary), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_dictionary(&mut self) -> Result<core::Ptr<crate::aruco::Dictionary>> {
+		crate::core::validity::check("Board", self.as_raw_mut_Board())?;
+		Ok(BoardTrait::dictionary(self))
+	}
+	
 	/// the dictionary of markers employed for this board
 	#[inline]
 	fn set_dictionary(&mut self, mut val: core::Ptr<crate::aruco::Dictionary>) {
-		let ret = unsafe { sys::cv_aruco_Board_setPropDictionary_Ptr_Dictionary_(self.as_raw_mut_Board(), val.as_raw_mut_PtrOfDictionary()) };
+		crate::core::validity::debug_check("Board", self.as_raw_mut_Board());
+		let ret = unsafe { ffi_call!(cv_aruco_Board_setPropDictionary_Ptr_Dictionary_(self.as_raw_mut_Board(), val.as_raw_mut_PtrOfDictionary())) };
 		ret
 	}
 	
@@ -958,7 +1549,8 @@ pub trait BoardTrait: crate::aruco::BoardTraitConst {
 	/// The identifiers refers to the board dictionary
 	#[inline]
 	fn set_ids(&mut self, mut val: core::Vector<i32>) {
-		let ret = unsafe { sys::cv_aruco_Board_setPropIds_vector_int_(self.as_raw_mut_Board(), val.as_raw_mut_VectorOfi32()) };
+		crate::core::validity::debug_check("Board", self.as_raw_mut_Board());
+		let ret = unsafe { ffi_call!(cv_aruco_Board_setPropIds_vector_int_(self.as_raw_mut_Board(), val.as_raw_mut_VectorOfi32())) };
 		ret
 	}
 	
@@ -972,9 +1564,10 @@ pub trait BoardTrait: crate::aruco::BoardTraitConst {
 	/// of objPoints.
 	#[inline]
 	fn set_ids_1(&mut self, ids: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("Board", self.as_raw_mut_Board())?;
 		input_array_arg!(ids);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_aruco_Board_setIds_const__InputArrayR(self.as_raw_mut_Board(), ids.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_aruco_Board_setIds_const__InputArrayR(self.as_raw_mut_Board(), ids.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -999,7 +1592,10 @@ opencv_type_boxed! { Board }
 impl Drop for Board {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Board_delete(instance: *mut c_void); }
-		unsafe { cv_Board_delete(self.as_raw_mut_Board()) };
+		let ptr = self.as_raw_mut_Board();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Board_delete(ptr) };
 	}
 }
 
@@ -1025,7 +1621,7 @@ impl Board {
 		input_array_arg!(obj_points);
 		input_array_arg!(ids);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_aruco_Board_create_const__InputArrayR_const_Ptr_Dictionary_R_const__InputArrayR(obj_points.as_raw__InputArray(), dictionary.as_raw_PtrOfDictionary(), ids.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_aruco_Board_create_const__InputArrayR_const_Ptr_Dictionary_R_const__InputArrayR(obj_points.as_raw__InputArray(), dictionary.as_raw_PtrOfDictionary(), ids.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::aruco::Board>::opencv_from_extern(ret) };
@@ -1045,29 +1641,57 @@ pub trait CharucoBoardTraitConst: crate::aruco::BoardTraitConst {
 
 	#[inline]
 	fn chessboard_corners(&self) -> core::Vector<core::Point3f> {
-		let ret = unsafe { sys::cv_aruco_CharucoBoard_getPropChessboardCorners_const(self.as_raw_CharucoBoard()) };
+		crate::core::validity::debug_check("CharucoBoard", self.as_raw_CharucoBoard());
+		let ret = unsafe { ffi_call!(cv_aruco_CharucoBoard_getPropChessboardCorners_const(self.as_raw_CharucoBoard())) };
 		let ret = unsafe { core::Vector::<core::Point3f>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [chessboard_corners](CharucoBoardTraitConst::chessboard_corners), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_chessboard_corners(&self) -> Result<core::Vector<core::Point3f>> {
+		crate::core::validity::check("CharucoBoard", self.as_raw_CharucoBoard())?;
+		Ok(CharucoBoardTraitConst::chessboard_corners(self))
+	}
+	
 	#[inline]
 	fn nearest_marker_idx(&self) -> core::Vector<core::Vector<i32>> {
-		let ret = unsafe { sys::cv_aruco_CharucoBoard_getPropNearestMarkerIdx_const(self.as_raw_CharucoBoard()) };
+		crate::core::validity::debug_check("CharucoBoard", self.as_raw_CharucoBoard());
+		let ret = unsafe { ffi_call!(cv_aruco_CharucoBoard_getPropNearestMarkerIdx_const(self.as_raw_CharucoBoard())) };
 		let ret = unsafe { core::Vector::<core::Vector<i32>>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [nearest_marker_idx](CharucoBoardTraitConst::nearest_marker_idx), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_nearest_marker_idx(&self) -> Result<core::Vector<core::Vector<i32>>> {
+		crate::core::validity::check("CharucoBoard", self.as_raw_CharucoBoard())?;
+		Ok(CharucoBoardTraitConst::nearest_marker_idx(self))
+	}
+	
 	#[inline]
 	fn nearest_marker_corners(&self) -> core::Vector<core::Vector<i32>> {
-		let ret = unsafe { sys::cv_aruco_CharucoBoard_getPropNearestMarkerCorners_const(self.as_raw_CharucoBoard()) };
+		crate::core::validity::debug_check("CharucoBoard", self.as_raw_CharucoBoard());
+		let ret = unsafe { ffi_call!(cv_aruco_CharucoBoard_getPropNearestMarkerCorners_const(self.as_raw_CharucoBoard())) };
 		let ret = unsafe { core::Vector::<core::Vector<i32>>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [nearest_marker_corners](CharucoBoardTraitConst::nearest_marker_corners), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_nearest_marker_corners(&self) -> Result<core::Vector<core::Vector<i32>>> {
+		crate::core::validity::check("CharucoBoard", self.as_raw_CharucoBoard())?;
+		Ok(CharucoBoardTraitConst::nearest_marker_corners(self))
+	}
+	
 	#[inline]
 	fn get_chessboard_size(&self) -> Result<core::Size> {
+		crate::core::validity::check("CharucoBoard", self.as_raw_CharucoBoard())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_aruco_CharucoBoard_getChessboardSize_const(self.as_raw_CharucoBoard(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_aruco_CharucoBoard_getChessboardSize_const(self.as_raw_CharucoBoard(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1075,8 +1699,9 @@ pub trait CharucoBoardTraitConst: crate::aruco::BoardTraitConst {
 	
 	#[inline]
 	fn get_square_length(&self) -> Result<f32> {
+		crate::core::validity::check("CharucoBoard", self.as_raw_CharucoBoard())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_aruco_CharucoBoard_getSquareLength_const(self.as_raw_CharucoBoard(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_aruco_CharucoBoard_getSquareLength_const(self.as_raw_CharucoBoard(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1084,8 +1709,9 @@ pub trait CharucoBoardTraitConst: crate::aruco::BoardTraitConst {
 	
 	#[inline]
 	fn get_marker_length(&self) -> Result<f32> {
+		crate::core::validity::check("CharucoBoard", self.as_raw_CharucoBoard())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_aruco_CharucoBoard_getMarkerLength_const(self.as_raw_CharucoBoard(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_aruco_CharucoBoard_getMarkerLength_const(self.as_raw_CharucoBoard(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1098,19 +1724,22 @@ pub trait CharucoBoardTrait: crate::aruco::BoardTrait + crate::aruco::CharucoBoa
 
 	#[inline]
 	fn set_chessboard_corners(&mut self, mut val: core::Vector<core::Point3f>) {
-		let ret = unsafe { sys::cv_aruco_CharucoBoard_setPropChessboardCorners_vector_Point3f_(self.as_raw_mut_CharucoBoard(), val.as_raw_mut_VectorOfPoint3f()) };
+		crate::core::validity::debug_check("CharucoBoard", self.as_raw_mut_CharucoBoard());
+		let ret = unsafe { ffi_call!(cv_aruco_CharucoBoard_setPropChessboardCorners_vector_Point3f_(self.as_raw_mut_CharucoBoard(), val.as_raw_mut_VectorOfPoint3f())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_nearest_marker_idx(&mut self, mut val: core::Vector<core::Vector<i32>>) {
-		let ret = unsafe { sys::cv_aruco_CharucoBoard_setPropNearestMarkerIdx_vector_vector_int__(self.as_raw_mut_CharucoBoard(), val.as_raw_mut_VectorOfVectorOfi32()) };
+		crate::core::validity::debug_check("CharucoBoard", self.as_raw_mut_CharucoBoard());
+		let ret = unsafe { ffi_call!(cv_aruco_CharucoBoard_setPropNearestMarkerIdx_vector_vector_int__(self.as_raw_mut_CharucoBoard(), val.as_raw_mut_VectorOfVectorOfi32())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_nearest_marker_corners(&mut self, mut val: core::Vector<core::Vector<i32>>) {
-		let ret = unsafe { sys::cv_aruco_CharucoBoard_setPropNearestMarkerCorners_vector_vector_int__(self.as_raw_mut_CharucoBoard(), val.as_raw_mut_VectorOfVectorOfi32()) };
+		crate::core::validity::debug_check("CharucoBoard", self.as_raw_mut_CharucoBoard());
+		let ret = unsafe { ffi_call!(cv_aruco_CharucoBoard_setPropNearestMarkerCorners_vector_vector_int__(self.as_raw_mut_CharucoBoard(), val.as_raw_mut_VectorOfVectorOfi32())) };
 		ret
 	}
 	
@@ -1130,9 +1759,10 @@ pub trait CharucoBoardTrait: crate::aruco::BoardTrait + crate::aruco::CharucoBoa
 	/// * border_bits: 1
 	#[inline]
 	fn draw(&mut self, out_size: core::Size, img: &mut dyn core::ToOutputArray, margin_size: i32, border_bits: i32) -> Result<()> {
+		crate::core::validity::check("CharucoBoard", self.as_raw_mut_CharucoBoard())?;
 		output_array_arg!(img);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_aruco_CharucoBoard_draw_Size_const__OutputArrayR_int_int(self.as_raw_mut_CharucoBoard(), out_size.opencv_as_extern(), img.as_raw__OutputArray(), margin_size, border_bits, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_aruco_CharucoBoard_draw_Size_const__OutputArrayR_int_int(self.as_raw_mut_CharucoBoard(), out_size.opencv_as_extern(), img.as_raw__OutputArray(), margin_size, border_bits, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1155,7 +1785,10 @@ opencv_type_boxed! { CharucoBoard }
 impl Drop for CharucoBoard {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CharucoBoard_delete(instance: *mut c_void); }
-		unsafe { cv_CharucoBoard_delete(self.as_raw_mut_CharucoBoard()) };
+		let ptr = self.as_raw_mut_CharucoBoard();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_CharucoBoard_delete(ptr) };
 	}
 }
 
@@ -1195,7 +1828,7 @@ impl CharucoBoard {
 	#[inline]
 	pub fn create(squares_x: i32, squares_y: i32, square_length: f32, marker_length: f32, dictionary: &core::Ptr<crate::aruco::Dictionary>) -> Result<core::Ptr<crate::aruco::CharucoBoard>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_aruco_CharucoBoard_create_int_int_float_float_const_Ptr_Dictionary_R(squares_x, squares_y, square_length, marker_length, dictionary.as_raw_PtrOfDictionary(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_aruco_CharucoBoard_create_int_int_float_float_const_Ptr_Dictionary_R(squares_x, squares_y, square_length, marker_length, dictionary.as_raw_PtrOfDictionary(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::aruco::CharucoBoard>::opencv_from_extern(ret) };
@@ -1257,7 +1890,7 @@ boxed_cast_base! { CharucoBoard, crate::aruco::Board, cv_CharucoBoard_to_Board }
 ///   allowed?  This is useful in rejecting contours that are far from being quad shaped; rejecting
 ///   these quads "early" saves expensive decoding processing. (default 10.0)
 /// - aprilTagMinWhiteBlackDiff: When we build our model of black & white pixels, we add an extra check that
-///   the white model must be (overall) brighter than the black model.  How much brighter? (in pixel values, [0,255]). (default 5)
+///   the white model must be (overall) brighter than the black model.  How much brighter? (in pixel values, \[0,255\]). (default 5)
 /// - aprilTagDeglitch:  should the thresholded image be deglitched? Only useful for very noisy images. (default 0)
 /// - aprilTagQuadDecimate: Detection of quads can be done on a lower-resolution image, improving speed at a
 ///   cost of pose accuracy and a slight decrease in detection rate. Decoding the binary payload is still
@@ -1271,178 +1904,439 @@ pub trait DetectorParametersTraitConst {
 
 	#[inline]
 	fn adaptive_thresh_win_size_min(&self) -> i32 {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_getPropAdaptiveThreshWinSizeMin_const(self.as_raw_DetectorParameters()) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_getPropAdaptiveThreshWinSizeMin_const(self.as_raw_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [adaptive_thresh_win_size_min](DetectorParametersTraitConst::adaptive_thresh_win_size_min), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_adaptive_thresh_win_size_min(&self) -> Result<i32> {
+		crate::core::validity::check("DetectorParameters", self.as_raw_DetectorParameters())?;
+		Ok(DetectorParametersTraitConst::adaptive_thresh_win_size_min(self))
+	}
+	
 	#[inline]
 	fn adaptive_thresh_win_size_max(&self) -> i32 {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_getPropAdaptiveThreshWinSizeMax_const(self.as_raw_DetectorParameters()) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_getPropAdaptiveThreshWinSizeMax_const(self.as_raw_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [adaptive_thresh_win_size_max](DetectorParametersTraitConst::adaptive_thresh_win_size_max), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_adaptive_thresh_win_size_max(&self) -> Result<i32> {
+		crate::core::validity::check("DetectorParameters", self.as_raw_DetectorParameters())?;
+		Ok(DetectorParametersTraitConst::adaptive_thresh_win_size_max(self))
+	}
+	
 	#[inline]
 	fn adaptive_thresh_win_size_step(&self) -> i32 {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_getPropAdaptiveThreshWinSizeStep_const(self.as_raw_DetectorParameters()) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_getPropAdaptiveThreshWinSizeStep_const(self.as_raw_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [adaptive_thresh_win_size_step](DetectorParametersTraitConst::adaptive_thresh_win_size_step), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_adaptive_thresh_win_size_step(&self) -> Result<i32> {
+		crate::core::validity::check("DetectorParameters", self.as_raw_DetectorParameters())?;
+		Ok(DetectorParametersTraitConst::adaptive_thresh_win_size_step(self))
+	}
+	
 	#[inline]
 	fn adaptive_thresh_constant(&self) -> f64 {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_getPropAdaptiveThreshConstant_const(self.as_raw_DetectorParameters()) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_getPropAdaptiveThreshConstant_const(self.as_raw_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [adaptive_thresh_constant](DetectorParametersTraitConst::adaptive_thresh_constant), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_adaptive_thresh_constant(&self) -> Result<f64> {
+		crate::core::validity::check("DetectorParameters", self.as_raw_DetectorParameters())?;
+		Ok(DetectorParametersTraitConst::adaptive_thresh_constant(self))
+	}
+	
 	#[inline]
 	fn min_marker_perimeter_rate(&self) -> f64 {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_getPropMinMarkerPerimeterRate_const(self.as_raw_DetectorParameters()) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_getPropMinMarkerPerimeterRate_const(self.as_raw_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [min_marker_perimeter_rate](DetectorParametersTraitConst::min_marker_perimeter_rate), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_min_marker_perimeter_rate(&self) -> Result<f64> {
+		crate::core::validity::check("DetectorParameters", self.as_raw_DetectorParameters())?;
+		Ok(DetectorParametersTraitConst::min_marker_perimeter_rate(self))
+	}
+	
 	#[inline]
 	fn max_marker_perimeter_rate(&self) -> f64 {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_getPropMaxMarkerPerimeterRate_const(self.as_raw_DetectorParameters()) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_getPropMaxMarkerPerimeterRate_const(self.as_raw_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [max_marker_perimeter_rate](DetectorParametersTraitConst::max_marker_perimeter_rate), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_max_marker_perimeter_rate(&self) -> Result<f64> {
+		crate::core::validity::check("DetectorParameters", self.as_raw_DetectorParameters())?;
+		Ok(DetectorParametersTraitConst::max_marker_perimeter_rate(self))
+	}
+	
 	#[inline]
 	fn polygonal_approx_accuracy_rate(&self) -> f64 {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_getPropPolygonalApproxAccuracyRate_const(self.as_raw_DetectorParameters()) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_getPropPolygonalApproxAccuracyRate_const(self.as_raw_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [polygonal_approx_accuracy_rate](DetectorParametersTraitConst::polygonal_approx_accuracy_rate), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_polygonal_approx_accuracy_rate(&self) -> Result<f64> {
+		crate::core::validity::check("DetectorParameters", self.as_raw_DetectorParameters())?;
+		Ok(DetectorParametersTraitConst::polygonal_approx_accuracy_rate(self))
+	}
+	
 	#[inline]
 	fn min_corner_distance_rate(&self) -> f64 {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_getPropMinCornerDistanceRate_const(self.as_raw_DetectorParameters()) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_getPropMinCornerDistanceRate_const(self.as_raw_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [min_corner_distance_rate](DetectorParametersTraitConst::min_corner_distance_rate), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_min_corner_distance_rate(&self) -> Result<f64> {
+		crate::core::validity::check("DetectorParameters", self.as_raw_DetectorParameters())?;
+		Ok(DetectorParametersTraitConst::min_corner_distance_rate(self))
+	}
+	
 	#[inline]
 	fn min_distance_to_border(&self) -> i32 {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_getPropMinDistanceToBorder_const(self.as_raw_DetectorParameters()) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_getPropMinDistanceToBorder_const(self.as_raw_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [min_distance_to_border](DetectorParametersTraitConst::min_distance_to_border), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_min_distance_to_border(&self) -> Result<i32> {
+		crate::core::validity::check("DetectorParameters", self.as_raw_DetectorParameters())?;
+		Ok(DetectorParametersTraitConst::min_distance_to_border(self))
+	}
+	
 	#[inline]
 	fn min_marker_distance_rate(&self) -> f64 {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_getPropMinMarkerDistanceRate_const(self.as_raw_DetectorParameters()) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_getPropMinMarkerDistanceRate_const(self.as_raw_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [min_marker_distance_rate](DetectorParametersTraitConst::min_marker_distance_rate), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_min_marker_distance_rate(&self) -> Result<f64> {
+		crate::core::validity::check("DetectorParameters", self.as_raw_DetectorParameters())?;
+		Ok(DetectorParametersTraitConst::min_marker_distance_rate(self))
+	}
+	
 	#[inline]
 	fn corner_refinement_method(&self) -> i32 {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_getPropCornerRefinementMethod_const(self.as_raw_DetectorParameters()) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_getPropCornerRefinementMethod_const(self.as_raw_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [corner_refinement_method](DetectorParametersTraitConst::corner_refinement_method), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_corner_refinement_method(&self) -> Result<i32> {
+		crate::core::validity::check("DetectorParameters", self.as_raw_DetectorParameters())?;
+		Ok(DetectorParametersTraitConst::corner_refinement_method(self))
+	}
+	
 	#[inline]
 	fn corner_refinement_win_size(&self) -> i32 {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_getPropCornerRefinementWinSize_const(self.as_raw_DetectorParameters()) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_getPropCornerRefinementWinSize_const(self.as_raw_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [corner_refinement_win_size](DetectorParametersTraitConst::corner_refinement_win_size), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_corner_refinement_win_size(&self) -> Result<i32> {
+		crate::core::validity::check("DetectorParameters", self.as_raw_DetectorParameters())?;
+		Ok(DetectorParametersTraitConst::corner_refinement_win_size(self))
+	}
+	
 	#[inline]
 	fn corner_refinement_max_iterations(&self) -> i32 {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_getPropCornerRefinementMaxIterations_const(self.as_raw_DetectorParameters()) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_getPropCornerRefinementMaxIterations_const(self.as_raw_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [corner_refinement_max_iterations](DetectorParametersTraitConst::corner_refinement_max_iterations), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_corner_refinement_max_iterations(&self) -> Result<i32> {
+		crate::core::validity::check("DetectorParameters", self.as_raw_DetectorParameters())?;
+		Ok(DetectorParametersTraitConst::corner_refinement_max_iterations(self))
+	}
+	
 	#[inline]
 	fn corner_refinement_min_accuracy(&self) -> f64 {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_getPropCornerRefinementMinAccuracy_const(self.as_raw_DetectorParameters()) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_getPropCornerRefinementMinAccuracy_const(self.as_raw_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [corner_refinement_min_accuracy](DetectorParametersTraitConst::corner_refinement_min_accuracy), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_corner_refinement_min_accuracy(&self) -> Result<f64> {
+		crate::core::validity::check("DetectorParameters", self.as_raw_DetectorParameters())?;
+		Ok(DetectorParametersTraitConst::corner_refinement_min_accuracy(self))
+	}
+	
 	#[inline]
 	fn marker_border_bits(&self) -> i32 {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_getPropMarkerBorderBits_const(self.as_raw_DetectorParameters()) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_getPropMarkerBorderBits_const(self.as_raw_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [marker_border_bits](DetectorParametersTraitConst::marker_border_bits), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_marker_border_bits(&self) -> Result<i32> {
+		crate::core::validity::check("DetectorParameters", self.as_raw_DetectorParameters())?;
+		Ok(DetectorParametersTraitConst::marker_border_bits(self))
+	}
+	
 	#[inline]
 	fn perspective_remove_pixel_per_cell(&self) -> i32 {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_getPropPerspectiveRemovePixelPerCell_const(self.as_raw_DetectorParameters()) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_getPropPerspectiveRemovePixelPerCell_const(self.as_raw_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [perspective_remove_pixel_per_cell](DetectorParametersTraitConst::perspective_remove_pixel_per_cell), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_perspective_remove_pixel_per_cell(&self) -> Result<i32> {
+		crate::core::validity::check("DetectorParameters", self.as_raw_DetectorParameters())?;
+		Ok(DetectorParametersTraitConst::perspective_remove_pixel_per_cell(self))
+	}
+	
 	#[inline]
 	fn perspective_remove_ignored_margin_per_cell(&self) -> f64 {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_getPropPerspectiveRemoveIgnoredMarginPerCell_const(self.as_raw_DetectorParameters()) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_getPropPerspectiveRemoveIgnoredMarginPerCell_const(self.as_raw_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [perspective_remove_ignored_margin_per_cell](DetectorParametersTraitConst::perspective_remove_ignored_margin_per_cell), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_perspective_remove_ignored_margin_per_cell(&self) -> Result<f64> {
+		crate::core::validity::check("DetectorParameters", self.as_raw_DetectorParameters())?;
+		Ok(DetectorParametersTraitConst::perspective_remove_ignored_margin_per_cell(self))
+	}
+	
 	#[inline]
 	fn max_erroneous_bits_in_border_rate(&self) -> f64 {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_getPropMaxErroneousBitsInBorderRate_const(self.as_raw_DetectorParameters()) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_getPropMaxErroneousBitsInBorderRate_const(self.as_raw_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [max_erroneous_bits_in_border_rate](DetectorParametersTraitConst::max_erroneous_bits_in_border_rate), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_max_erroneous_bits_in_border_rate(&self) -> Result<f64> {
+		crate::core::validity::check("DetectorParameters", self.as_raw_DetectorParameters())?;
+		Ok(DetectorParametersTraitConst::max_erroneous_bits_in_border_rate(self))
+	}
+	
 	#[inline]
 	fn min_otsu_std_dev(&self) -> f64 {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_getPropMinOtsuStdDev_const(self.as_raw_DetectorParameters()) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_getPropMinOtsuStdDev_const(self.as_raw_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [min_otsu_std_dev](DetectorParametersTraitConst::min_otsu_std_dev), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_min_otsu_std_dev(&self) -> Result<f64> {
+		crate::core::validity::check("DetectorParameters", self.as_raw_DetectorParameters())?;
+		Ok(DetectorParametersTraitConst::min_otsu_std_dev(self))
+	}
+	
 	#[inline]
 	fn error_correction_rate(&self) -> f64 {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_getPropErrorCorrectionRate_const(self.as_raw_DetectorParameters()) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_getPropErrorCorrectionRate_const(self.as_raw_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [error_correction_rate](DetectorParametersTraitConst::error_correction_rate), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_error_correction_rate(&self) -> Result<f64> {
+		crate::core::validity::check("DetectorParameters", self.as_raw_DetectorParameters())?;
+		Ok(DetectorParametersTraitConst::error_correction_rate(self))
+	}
+	
 	#[inline]
 	fn april_tag_quad_decimate(&self) -> f32 {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_getPropAprilTagQuadDecimate_const(self.as_raw_DetectorParameters()) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_getPropAprilTagQuadDecimate_const(self.as_raw_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [april_tag_quad_decimate](DetectorParametersTraitConst::april_tag_quad_decimate), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_april_tag_quad_decimate(&self) -> Result<f32> {
+		crate::core::validity::check("DetectorParameters", self.as_raw_DetectorParameters())?;
+		Ok(DetectorParametersTraitConst::april_tag_quad_decimate(self))
+	}
+	
 	#[inline]
 	fn april_tag_quad_sigma(&self) -> f32 {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_getPropAprilTagQuadSigma_const(self.as_raw_DetectorParameters()) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_getPropAprilTagQuadSigma_const(self.as_raw_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [april_tag_quad_sigma](DetectorParametersTraitConst::april_tag_quad_sigma), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_april_tag_quad_sigma(&self) -> Result<f32> {
+		crate::core::validity::check("DetectorParameters", self.as_raw_DetectorParameters())?;
+		Ok(DetectorParametersTraitConst::april_tag_quad_sigma(self))
+	}
+	
 	#[inline]
 	fn april_tag_min_cluster_pixels(&self) -> i32 {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_getPropAprilTagMinClusterPixels_const(self.as_raw_DetectorParameters()) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_getPropAprilTagMinClusterPixels_const(self.as_raw_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [april_tag_min_cluster_pixels](DetectorParametersTraitConst::april_tag_min_cluster_pixels), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_april_tag_min_cluster_pixels(&self) -> Result<i32> {
+		crate::core::validity::check("DetectorParameters", self.as_raw_DetectorParameters())?;
+		Ok(DetectorParametersTraitConst::april_tag_min_cluster_pixels(self))
+	}
+	
 	#[inline]
 	fn april_tag_max_nmaxima(&self) -> i32 {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_getPropAprilTagMaxNmaxima_const(self.as_raw_DetectorParameters()) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_getPropAprilTagMaxNmaxima_const(self.as_raw_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [april_tag_max_nmaxima](DetectorParametersTraitConst::april_tag_max_nmaxima), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_april_tag_max_nmaxima(&self) -> Result<i32> {
+		crate::core::validity::check("DetectorParameters", self.as_raw_DetectorParameters())?;
+		Ok(DetectorParametersTraitConst::april_tag_max_nmaxima(self))
+	}
+	
 	#[inline]
 	fn april_tag_critical_rad(&self) -> f32 {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_getPropAprilTagCriticalRad_const(self.as_raw_DetectorParameters()) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_getPropAprilTagCriticalRad_const(self.as_raw_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [april_tag_critical_rad](DetectorParametersTraitConst::april_tag_critical_rad), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_april_tag_critical_rad(&self) -> Result<f32> {
+		crate::core::validity::check("DetectorParameters", self.as_raw_DetectorParameters())?;
+		Ok(DetectorParametersTraitConst::april_tag_critical_rad(self))
+	}
+	
 	#[inline]
 	fn april_tag_max_line_fit_mse(&self) -> f32 {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_getPropAprilTagMaxLineFitMse_const(self.as_raw_DetectorParameters()) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_getPropAprilTagMaxLineFitMse_const(self.as_raw_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [april_tag_max_line_fit_mse](DetectorParametersTraitConst::april_tag_max_line_fit_mse), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_april_tag_max_line_fit_mse(&self) -> Result<f32> {
+		crate::core::validity::check("DetectorParameters", self.as_raw_DetectorParameters())?;
+		Ok(DetectorParametersTraitConst::april_tag_max_line_fit_mse(self))
+	}
+	
 	#[inline]
 	fn april_tag_min_white_black_diff(&self) -> i32 {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_getPropAprilTagMinWhiteBlackDiff_const(self.as_raw_DetectorParameters()) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_getPropAprilTagMinWhiteBlackDiff_const(self.as_raw_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [april_tag_min_white_black_diff](DetectorParametersTraitConst::april_tag_min_white_black_diff), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_april_tag_min_white_black_diff(&self) -> Result<i32> {
+		crate::core::validity::check("DetectorParameters", self.as_raw_DetectorParameters())?;
+		Ok(DetectorParametersTraitConst::april_tag_min_white_black_diff(self))
+	}
+	
 	#[inline]
 	fn april_tag_deglitch(&self) -> i32 {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_getPropAprilTagDeglitch_const(self.as_raw_DetectorParameters()) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_getPropAprilTagDeglitch_const(self.as_raw_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [april_tag_deglitch](DetectorParametersTraitConst::april_tag_deglitch), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_april_tag_deglitch(&self) -> Result<i32> {
+		crate::core::validity::check("DetectorParameters", self.as_raw_DetectorParameters())?;
+		Ok(DetectorParametersTraitConst::april_tag_deglitch(self))
+	}
+	
 	#[inline]
 	fn detect_inverted_marker(&self) -> bool {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_getPropDetectInvertedMarker_const(self.as_raw_DetectorParameters()) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_getPropDetectInvertedMarker_const(self.as_raw_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [detect_inverted_marker](DetectorParametersTraitConst::detect_inverted_marker), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_detect_inverted_marker(&self) -> Result<bool> {
+		crate::core::validity::check("DetectorParameters", self.as_raw_DetectorParameters())?;
+		Ok(DetectorParametersTraitConst::detect_inverted_marker(self))
+	}
+	
 }
 
 pub trait DetectorParametersTrait: crate::aruco::DetectorParametersTraitConst {
@@ -1450,175 +2344,204 @@ pub trait DetectorParametersTrait: crate::aruco::DetectorParametersTraitConst {
 
 	#[inline]
 	fn set_adaptive_thresh_win_size_min(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_setPropAdaptiveThreshWinSizeMin_int(self.as_raw_mut_DetectorParameters(), val) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_mut_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_setPropAdaptiveThreshWinSizeMin_int(self.as_raw_mut_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_adaptive_thresh_win_size_max(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_setPropAdaptiveThreshWinSizeMax_int(self.as_raw_mut_DetectorParameters(), val) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_mut_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_setPropAdaptiveThreshWinSizeMax_int(self.as_raw_mut_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_adaptive_thresh_win_size_step(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_setPropAdaptiveThreshWinSizeStep_int(self.as_raw_mut_DetectorParameters(), val) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_mut_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_setPropAdaptiveThreshWinSizeStep_int(self.as_raw_mut_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_adaptive_thresh_constant(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_setPropAdaptiveThreshConstant_double(self.as_raw_mut_DetectorParameters(), val) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_mut_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_setPropAdaptiveThreshConstant_double(self.as_raw_mut_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_min_marker_perimeter_rate(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_setPropMinMarkerPerimeterRate_double(self.as_raw_mut_DetectorParameters(), val) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_mut_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_setPropMinMarkerPerimeterRate_double(self.as_raw_mut_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_max_marker_perimeter_rate(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_setPropMaxMarkerPerimeterRate_double(self.as_raw_mut_DetectorParameters(), val) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_mut_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_setPropMaxMarkerPerimeterRate_double(self.as_raw_mut_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_polygonal_approx_accuracy_rate(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_setPropPolygonalApproxAccuracyRate_double(self.as_raw_mut_DetectorParameters(), val) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_mut_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_setPropPolygonalApproxAccuracyRate_double(self.as_raw_mut_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_min_corner_distance_rate(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_setPropMinCornerDistanceRate_double(self.as_raw_mut_DetectorParameters(), val) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_mut_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_setPropMinCornerDistanceRate_double(self.as_raw_mut_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_min_distance_to_border(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_setPropMinDistanceToBorder_int(self.as_raw_mut_DetectorParameters(), val) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_mut_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_setPropMinDistanceToBorder_int(self.as_raw_mut_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_min_marker_distance_rate(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_setPropMinMarkerDistanceRate_double(self.as_raw_mut_DetectorParameters(), val) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_mut_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_setPropMinMarkerDistanceRate_double(self.as_raw_mut_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_corner_refinement_method(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_setPropCornerRefinementMethod_int(self.as_raw_mut_DetectorParameters(), val) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_mut_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_setPropCornerRefinementMethod_int(self.as_raw_mut_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_corner_refinement_win_size(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_setPropCornerRefinementWinSize_int(self.as_raw_mut_DetectorParameters(), val) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_mut_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_setPropCornerRefinementWinSize_int(self.as_raw_mut_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_corner_refinement_max_iterations(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_setPropCornerRefinementMaxIterations_int(self.as_raw_mut_DetectorParameters(), val) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_mut_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_setPropCornerRefinementMaxIterations_int(self.as_raw_mut_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_corner_refinement_min_accuracy(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_setPropCornerRefinementMinAccuracy_double(self.as_raw_mut_DetectorParameters(), val) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_mut_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_setPropCornerRefinementMinAccuracy_double(self.as_raw_mut_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_marker_border_bits(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_setPropMarkerBorderBits_int(self.as_raw_mut_DetectorParameters(), val) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_mut_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_setPropMarkerBorderBits_int(self.as_raw_mut_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_perspective_remove_pixel_per_cell(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_setPropPerspectiveRemovePixelPerCell_int(self.as_raw_mut_DetectorParameters(), val) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_mut_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_setPropPerspectiveRemovePixelPerCell_int(self.as_raw_mut_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_perspective_remove_ignored_margin_per_cell(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_setPropPerspectiveRemoveIgnoredMarginPerCell_double(self.as_raw_mut_DetectorParameters(), val) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_mut_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_setPropPerspectiveRemoveIgnoredMarginPerCell_double(self.as_raw_mut_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_max_erroneous_bits_in_border_rate(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_setPropMaxErroneousBitsInBorderRate_double(self.as_raw_mut_DetectorParameters(), val) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_mut_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_setPropMaxErroneousBitsInBorderRate_double(self.as_raw_mut_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_min_otsu_std_dev(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_setPropMinOtsuStdDev_double(self.as_raw_mut_DetectorParameters(), val) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_mut_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_setPropMinOtsuStdDev_double(self.as_raw_mut_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_error_correction_rate(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_setPropErrorCorrectionRate_double(self.as_raw_mut_DetectorParameters(), val) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_mut_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_setPropErrorCorrectionRate_double(self.as_raw_mut_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_april_tag_quad_decimate(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_setPropAprilTagQuadDecimate_float(self.as_raw_mut_DetectorParameters(), val) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_mut_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_setPropAprilTagQuadDecimate_float(self.as_raw_mut_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_april_tag_quad_sigma(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_setPropAprilTagQuadSigma_float(self.as_raw_mut_DetectorParameters(), val) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_mut_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_setPropAprilTagQuadSigma_float(self.as_raw_mut_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_april_tag_min_cluster_pixels(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_setPropAprilTagMinClusterPixels_int(self.as_raw_mut_DetectorParameters(), val) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_mut_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_setPropAprilTagMinClusterPixels_int(self.as_raw_mut_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_april_tag_max_nmaxima(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_setPropAprilTagMaxNmaxima_int(self.as_raw_mut_DetectorParameters(), val) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_mut_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_setPropAprilTagMaxNmaxima_int(self.as_raw_mut_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_april_tag_critical_rad(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_setPropAprilTagCriticalRad_float(self.as_raw_mut_DetectorParameters(), val) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_mut_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_setPropAprilTagCriticalRad_float(self.as_raw_mut_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_april_tag_max_line_fit_mse(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_setPropAprilTagMaxLineFitMse_float(self.as_raw_mut_DetectorParameters(), val) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_mut_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_setPropAprilTagMaxLineFitMse_float(self.as_raw_mut_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_april_tag_min_white_black_diff(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_setPropAprilTagMinWhiteBlackDiff_int(self.as_raw_mut_DetectorParameters(), val) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_mut_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_setPropAprilTagMinWhiteBlackDiff_int(self.as_raw_mut_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_april_tag_deglitch(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_setPropAprilTagDeglitch_int(self.as_raw_mut_DetectorParameters(), val) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_mut_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_setPropAprilTagDeglitch_int(self.as_raw_mut_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_detect_inverted_marker(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_aruco_DetectorParameters_setPropDetectInvertedMarker_bool(self.as_raw_mut_DetectorParameters(), val) };
+		crate::core::validity::debug_check("DetectorParameters", self.as_raw_mut_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_aruco_DetectorParameters_setPropDetectInvertedMarker_bool(self.as_raw_mut_DetectorParameters(), val)) };
 		ret
 	}
 	
@@ -1675,7 +2598,7 @@ pub trait DetectorParametersTrait: crate::aruco::DetectorParametersTraitConst {
 ///   allowed?  This is useful in rejecting contours that are far from being quad shaped; rejecting
 ///   these quads "early" saves expensive decoding processing. (default 10.0)
 /// - aprilTagMinWhiteBlackDiff: When we build our model of black & white pixels, we add an extra check that
-///   the white model must be (overall) brighter than the black model.  How much brighter? (in pixel values, [0,255]). (default 5)
+///   the white model must be (overall) brighter than the black model.  How much brighter? (in pixel values, \[0,255\]). (default 5)
 /// - aprilTagDeglitch:  should the thresholded image be deglitched? Only useful for very noisy images. (default 0)
 /// - aprilTagQuadDecimate: Detection of quads can be done on a lower-resolution image, improving speed at a
 ///   cost of pose accuracy and a slight decrease in detection rate. Decoding the binary payload is still
@@ -1693,7 +2616,10 @@ opencv_type_boxed! { DetectorParameters }
 impl Drop for DetectorParameters {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DetectorParameters_delete(instance: *mut c_void); }
-		unsafe { cv_DetectorParameters_delete(self.as_raw_mut_DetectorParameters()) };
+		let ptr = self.as_raw_mut_DetectorParameters();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_DetectorParameters_delete(ptr) };
 	}
 }
 
@@ -1711,7 +2637,7 @@ impl DetectorParameters {
 	#[inline]
 	pub fn default() -> Result<crate::aruco::DetectorParameters> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_aruco_DetectorParameters_DetectorParameters(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_aruco_DetectorParameters_DetectorParameters(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::aruco::DetectorParameters::opencv_from_extern(ret) };
@@ -1721,7 +2647,7 @@ impl DetectorParameters {
 	#[inline]
 	pub fn create() -> Result<core::Ptr<crate::aruco::DetectorParameters>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_aruco_DetectorParameters_create(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_aruco_DetectorParameters_create(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::aruco::DetectorParameters>::opencv_from_extern(ret) };
@@ -1731,7 +2657,7 @@ impl DetectorParameters {
 	#[inline]
 	pub fn read_detector_parameters(fn_: &core::FileNode, params: &mut core::Ptr<crate::aruco::DetectorParameters>) -> Result<bool> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_aruco_DetectorParameters_readDetectorParameters_const_FileNodeR_Ptr_DetectorParameters_R(fn_.as_raw_FileNode(), params.as_raw_mut_PtrOfDetectorParameters(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_aruco_DetectorParameters_readDetectorParameters_const_FileNodeR_Ptr_DetectorParameters_R(fn_.as_raw_FileNode(), params.as_raw_mut_PtrOfDetectorParameters(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1752,29 +2678,57 @@ pub trait DictionaryTraitConst {
 
 	#[inline]
 	fn bytes_list(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_aruco_Dictionary_getPropBytesList_const(self.as_raw_Dictionary()) };
+		crate::core::validity::debug_check("Dictionary", self.as_raw_Dictionary());
+		let ret = unsafe { ffi_call!(cv_aruco_Dictionary_getPropBytesList_const(self.as_raw_Dictionary())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [bytes_list](DictionaryTraitConst::bytes_list), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_bytes_list(&self) -> Result<core::Mat> {
+		crate::core::validity::check("Dictionary", self.as_raw_Dictionary())?;
+		Ok(DictionaryTraitConst::bytes_list(self))
+	}
+	
 	#[inline]
 	fn marker_size(&self) -> i32 {
-		let ret = unsafe { sys::cv_aruco_Dictionary_getPropMarkerSize_const(self.as_raw_Dictionary()) };
+		crate::core::validity::debug_check("Dictionary", self.as_raw_Dictionary());
+		let ret = unsafe { ffi_call!(cv_aruco_Dictionary_getPropMarkerSize_const(self.as_raw_Dictionary())) };
 		ret
 	}
 	
+	/// Non-panicking version of [marker_size](DictionaryTraitConst::marker_size), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_marker_size(&self) -> Result<i32> {
+		crate::core::validity::check("Dictionary", self.as_raw_Dictionary())?;
+		Ok(DictionaryTraitConst::marker_size(self))
+	}
+	
 	#[inline]
 	fn max_correction_bits(&self) -> i32 {
-		let ret = unsafe { sys::cv_aruco_Dictionary_getPropMaxCorrectionBits_const(self.as_raw_Dictionary()) };
+		crate::core::validity::debug_check("Dictionary", self.as_raw_Dictionary());
+		let ret = unsafe { ffi_call!(cv_aruco_Dictionary_getPropMaxCorrectionBits_const(self.as_raw_Dictionary())) };
 		ret
 	}
 	
+	/// Non-panicking version of [max_correction_bits](DictionaryTraitConst::max_correction_bits), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_max_correction_bits(&self) -> Result<i32> {
+		crate::core::validity::check("Dictionary", self.as_raw_Dictionary())?;
+		Ok(DictionaryTraitConst::max_correction_bits(self))
+	}
+	
 	/// Given a matrix of bits. Returns whether if marker is identified or not.
 	/// It returns by reference the correct id (if any) and the correct rotation
 	#[inline]
 	fn identify(&self, only_bits: &core::Mat, idx: &mut i32, rotation: &mut i32, max_correction_rate: f64) -> Result<bool> {
+		crate::core::validity::check("Dictionary", self.as_raw_Dictionary())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_aruco_Dictionary_identify_const_const_MatR_intR_intR_double(self.as_raw_Dictionary(), only_bits.as_raw_Mat(), idx, rotation, max_correction_rate, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_aruco_Dictionary_identify_const_const_MatR_intR_intR_double(self.as_raw_Dictionary(), only_bits.as_raw_Mat(), idx, rotation, max_correction_rate, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1787,9 +2741,10 @@ pub trait DictionaryTraitConst {
 	/// * all_rotations: true
 	#[inline]
 	fn get_distance_to_id(&self, bits: &dyn core::ToInputArray, id: i32, all_rotations: bool) -> Result<i32> {
+		crate::core::validity::check("Dictionary", self.as_raw_Dictionary())?;
 		input_array_arg!(bits);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_aruco_Dictionary_getDistanceToId_const_const__InputArrayR_int_bool(self.as_raw_Dictionary(), bits.as_raw__InputArray(), id, all_rotations, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_aruco_Dictionary_getDistanceToId_const_const__InputArrayR_int_bool(self.as_raw_Dictionary(), bits.as_raw__InputArray(), id, all_rotations, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1801,9 +2756,10 @@ pub trait DictionaryTraitConst {
 	/// * border_bits: 1
 	#[inline]
 	fn draw_marker(&self, id: i32, side_pixels: i32, _img: &mut dyn core::ToOutputArray, border_bits: i32) -> Result<()> {
+		crate::core::validity::check("Dictionary", self.as_raw_Dictionary())?;
 		output_array_arg!(_img);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_aruco_Dictionary_drawMarker_const_int_int_const__OutputArrayR_int(self.as_raw_Dictionary(), id, side_pixels, _img.as_raw__OutputArray(), border_bits, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_aruco_Dictionary_drawMarker_const_int_int_const__OutputArrayR_int(self.as_raw_Dictionary(), id, side_pixels, _img.as_raw__OutputArray(), border_bits, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1816,19 +2772,22 @@ pub trait DictionaryTrait: crate::aruco::DictionaryTraitConst {
 
 	#[inline]
 	fn set_bytes_list(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_aruco_Dictionary_setPropBytesList_Mat(self.as_raw_mut_Dictionary(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("Dictionary", self.as_raw_mut_Dictionary());
+		let ret = unsafe { ffi_call!(cv_aruco_Dictionary_setPropBytesList_Mat(self.as_raw_mut_Dictionary(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_marker_size(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_aruco_Dictionary_setPropMarkerSize_int(self.as_raw_mut_Dictionary(), val) };
+		crate::core::validity::debug_check("Dictionary", self.as_raw_mut_Dictionary());
+		let ret = unsafe { ffi_call!(cv_aruco_Dictionary_setPropMarkerSize_int(self.as_raw_mut_Dictionary(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_max_correction_bits(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_aruco_Dictionary_setPropMaxCorrectionBits_int(self.as_raw_mut_Dictionary(), val) };
+		crate::core::validity::debug_check("Dictionary", self.as_raw_mut_Dictionary());
+		let ret = unsafe { ffi_call!(cv_aruco_Dictionary_setPropMaxCorrectionBits_int(self.as_raw_mut_Dictionary(), val)) };
 		ret
 	}
 	
@@ -1851,7 +2810,10 @@ opencv_type_boxed! { Dictionary }
 impl Drop for Dictionary {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Dictionary_delete(instance: *mut c_void); }
-		unsafe { cv_Dictionary_delete(self.as_raw_mut_Dictionary()) };
+		let ptr = self.as_raw_mut_Dictionary();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Dictionary_delete(ptr) };
 	}
 }
 
@@ -1873,7 +2835,7 @@ impl Dictionary {
 	#[inline]
 	pub fn new(_bytes_list: &core::Mat, _marker_size: i32, _maxcorr: i32) -> Result<crate::aruco::Dictionary> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_aruco_Dictionary_Dictionary_const_MatR_int_int(_bytes_list.as_raw_Mat(), _marker_size, _maxcorr, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_aruco_Dictionary_Dictionary_const_MatR_int_int(_bytes_list.as_raw_Mat(), _marker_size, _maxcorr, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::aruco::Dictionary::opencv_from_extern(ret) };
@@ -1883,7 +2845,7 @@ impl Dictionary {
 	#[inline]
 	pub fn copy(_dictionary: &core::Ptr<crate::aruco::Dictionary>) -> Result<crate::aruco::Dictionary> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_aruco_Dictionary_Dictionary_const_Ptr_Dictionary_R(_dictionary.as_raw_PtrOfDictionary(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_aruco_Dictionary_Dictionary_const_Ptr_Dictionary_R(_dictionary.as_raw_PtrOfDictionary(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::aruco::Dictionary::opencv_from_extern(ret) };
@@ -1891,14 +2853,14 @@ impl Dictionary {
 	}
 	
 	/// ## See also
-	/// generateCustomDictionary
+	/// `generate_custom_dictionary`
 	/// 
 	/// ## C++ default parameters
 	/// * random_seed: 0
 	#[inline]
 	pub fn create(n_markers: i32, marker_size: i32, random_seed: i32) -> Result<core::Ptr<crate::aruco::Dictionary>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_aruco_Dictionary_create_int_int_int(n_markers, marker_size, random_seed, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_aruco_Dictionary_create_int_int_int(n_markers, marker_size, random_seed, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::aruco::Dictionary>::opencv_from_extern(ret) };
@@ -1906,14 +2868,14 @@ impl Dictionary {
 	}
 	
 	/// ## See also
-	/// generateCustomDictionary
+	/// `generate_custom_dictionary`
 	/// 
 	/// ## C++ default parameters
 	/// * random_seed: 0
 	#[inline]
 	pub fn create_from(n_markers: i32, marker_size: i32, base_dictionary: &core::Ptr<crate::aruco::Dictionary>, random_seed: i32) -> Result<core::Ptr<crate::aruco::Dictionary>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_aruco_Dictionary_create_int_int_const_Ptr_Dictionary_R_int(n_markers, marker_size, base_dictionary.as_raw_PtrOfDictionary(), random_seed, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_aruco_Dictionary_create_int_int_const_Ptr_Dictionary_R_int(n_markers, marker_size, base_dictionary.as_raw_PtrOfDictionary(), random_seed, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::aruco::Dictionary>::opencv_from_extern(ret) };
@@ -1929,18 +2891,18 @@ impl Dictionary {
 	#[inline]
 	pub fn read_dictionary(fn_: &core::FileNode, dictionary: &mut core::Ptr<crate::aruco::Dictionary>) -> Result<bool> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_aruco_Dictionary_readDictionary_const_FileNodeR_Ptr_Dictionary_R(fn_.as_raw_FileNode(), dictionary.as_raw_mut_PtrOfDictionary(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_aruco_Dictionary_readDictionary_const_FileNodeR_Ptr_Dictionary_R(fn_.as_raw_FileNode(), dictionary.as_raw_mut_PtrOfDictionary(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// getPredefinedDictionary
+	/// [get_predefined_dictionary](crate::aruco::get_predefined_dictionary)
 	#[inline]
 	pub fn get(dict: i32) -> Result<core::Ptr<crate::aruco::Dictionary>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_aruco_Dictionary_get_int(dict, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_aruco_Dictionary_get_int(dict, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::aruco::Dictionary>::opencv_from_extern(ret) };
@@ -1951,7 +2913,7 @@ impl Dictionary {
 	#[inline]
 	pub fn get_byte_list_from_bits(bits: &core::Mat) -> Result<core::Mat> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_aruco_Dictionary_getByteListFromBits_const_MatR(bits.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_aruco_Dictionary_getByteListFromBits_const_MatR(bits.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -1962,7 +2924,7 @@ impl Dictionary {
 	#[inline]
 	pub fn get_bits_from_byte_list(byte_list: &core::Mat, marker_size: i32) -> Result<core::Mat> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_aruco_Dictionary_getBitsFromByteList_const_MatR_int(byte_list.as_raw_Mat(), marker_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_aruco_Dictionary_getBitsFromByteList_const_MatR_int(byte_list.as_raw_Mat(), marker_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -1971,6 +2933,42 @@ impl Dictionary {
 	
 }
 
+/// Builder for `Dictionary::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct Dictionary_NewBuilder<'a> {
+	_bytes_list: &'a core::Mat,
+	_marker_size: i32,
+	_maxcorr: i32,
+}
+
+impl<'a> Dictionary_NewBuilder<'a> {
+	#[inline]
+	pub fn new(_bytes_list: &'a core::Mat) -> Self {
+		Self { _bytes_list, _marker_size: 0, _maxcorr: 0 }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with__marker_size(mut self, _marker_size: i32) -> Self {
+		self._marker_size = _marker_size;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with__maxcorr(mut self, _maxcorr: i32) -> Self {
+		self._maxcorr = _maxcorr;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::aruco::Dictionary> {
+		Dictionary::new(self._bytes_list, self._marker_size, self._maxcorr)
+	}
+}
+
 /// Planar board with grid arrangement of markers
 /// More common type of board. All markers are placed in the same plane in a grid arrangement.
 /// The board can be drawn using drawPlanarBoard() function (see also: drawPlanarBoard)
@@ -1979,8 +2977,9 @@ pub trait GridBoardTraitConst: crate::aruco::BoardTraitConst {
 
 	#[inline]
 	fn get_grid_size(&self) -> Result<core::Size> {
+		crate::core::validity::check("GridBoard", self.as_raw_GridBoard())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_aruco_GridBoard_getGridSize_const(self.as_raw_GridBoard(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_aruco_GridBoard_getGridSize_const(self.as_raw_GridBoard(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1988,8 +2987,9 @@ pub trait GridBoardTraitConst: crate::aruco::BoardTraitConst {
 	
 	#[inline]
 	fn get_marker_length(&self) -> Result<f32> {
+		crate::core::validity::check("GridBoard", self.as_raw_GridBoard())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_aruco_GridBoard_getMarkerLength_const(self.as_raw_GridBoard(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_aruco_GridBoard_getMarkerLength_const(self.as_raw_GridBoard(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1997,8 +2997,9 @@ pub trait GridBoardTraitConst: crate::aruco::BoardTraitConst {
 	
 	#[inline]
 	fn get_marker_separation(&self) -> Result<f32> {
+		crate::core::validity::check("GridBoard", self.as_raw_GridBoard())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_aruco_GridBoard_getMarkerSeparation_const(self.as_raw_GridBoard(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_aruco_GridBoard_getMarkerSeparation_const(self.as_raw_GridBoard(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2025,9 +3026,10 @@ pub trait GridBoardTrait: crate::aruco::BoardTrait + crate::aruco::GridBoardTrai
 	/// * border_bits: 1
 	#[inline]
 	fn draw(&mut self, out_size: core::Size, img: &mut dyn core::ToOutputArray, margin_size: i32, border_bits: i32) -> Result<()> {
+		crate::core::validity::check("GridBoard", self.as_raw_mut_GridBoard())?;
 		output_array_arg!(img);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_aruco_GridBoard_draw_Size_const__OutputArrayR_int_int(self.as_raw_mut_GridBoard(), out_size.opencv_as_extern(), img.as_raw__OutputArray(), margin_size, border_bits, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_aruco_GridBoard_draw_Size_const__OutputArrayR_int_int(self.as_raw_mut_GridBoard(), out_size.opencv_as_extern(), img.as_raw__OutputArray(), margin_size, border_bits, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2047,7 +3049,10 @@ opencv_type_boxed! { GridBoard }
 impl Drop for GridBoard {
 	fn drop(&mut self) {
 		extern "C" { fn cv_GridBoard_delete(instance: *mut c_void); }
-		unsafe { cv_GridBoard_delete(self.as_raw_mut_GridBoard()) };
+		let ptr = self.as_raw_mut_GridBoard();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_GridBoard_delete(ptr) };
 	}
 }
 
@@ -2090,7 +3095,7 @@ impl GridBoard {
 	#[inline]
 	pub fn create(markers_x: i32, markers_y: i32, marker_length: f32, marker_separation: f32, dictionary: &core::Ptr<crate::aruco::Dictionary>, first_marker: i32) -> Result<core::Ptr<crate::aruco::GridBoard>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_aruco_GridBoard_create_int_int_float_float_const_Ptr_Dictionary_R_int(markers_x, markers_y, marker_length, marker_separation, dictionary.as_raw_PtrOfDictionary(), first_marker, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_aruco_GridBoard_create_int_int_float_float_const_Ptr_Dictionary_R_int(markers_x, markers_y, marker_length, marker_separation, dictionary.as_raw_PtrOfDictionary(), first_marker, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::aruco::GridBoard>::opencv_from_extern(ret) };
@@ -2099,4 +3104,36 @@ impl GridBoard {
 	
 }
 
+/// Builder for `GridBoard::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct GridBoard_CreateBuilder<'a> {
+	markers_x: i32,
+	markers_y: i32,
+	marker_length: f32,
+	marker_separation: f32,
+	dictionary: &'a core::Ptr<crate::aruco::Dictionary>,
+	first_marker: i32,
+}
+
+impl<'a> GridBoard_CreateBuilder<'a> {
+	#[inline]
+	pub fn new(markers_x: i32, markers_y: i32, marker_length: f32, marker_separation: f32, dictionary: &'a core::Ptr<crate::aruco::Dictionary>) -> Self {
+		Self { markers_x, markers_y, marker_length, marker_separation, dictionary, first_marker: 0 }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_first_marker(mut self, first_marker: i32) -> Self {
+		self.first_marker = first_marker;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<crate::aruco::GridBoard>> {
+		GridBoard::create(self.markers_x, self.markers_y, self.marker_length, self.marker_separation, self.dictionary, self.first_marker)
+	}
+}
+
 boxed_cast_base! { GridBoard, crate::aruco::Board, cv_GridBoard_to_Board }
@@ -40,14 +40,15 @@ pub trait BarcodeDetectorTraitConst {
 	/// ## Parameters
 	/// * img: grayscale or color (BGR) image containing (or not) Barcode.
 	/// * points: Output vector of vector of vertices of the minimum-area rotated rectangle containing the codes.
-	/// For N detected barcodes, the dimensions of this array should be [N][4].
+	/// For N detected barcodes, the dimensions of this array should be \[N\]\[4\].
 	/// Order of four points in vector< Point2f> is bottomLeft, topLeft, topRight, bottomRight.
 	#[inline]
 	fn detect(&self, img: &dyn core::ToInputArray, points: &mut dyn core::ToOutputArray) -> Result<bool> {
+		crate::core::validity::check("BarcodeDetector", self.as_raw_BarcodeDetector())?;
 		input_array_arg!(img);
 		output_array_arg!(points);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_barcode_BarcodeDetector_detect_const_const__InputArrayR_const__OutputArrayR(self.as_raw_BarcodeDetector(), img.as_raw__InputArray(), points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_barcode_BarcodeDetector_detect_const_const__InputArrayR_const__OutputArrayR(self.as_raw_BarcodeDetector(), img.as_raw__InputArray(), points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -58,16 +59,17 @@ pub trait BarcodeDetectorTraitConst {
 	/// ## Parameters
 	/// * img: grayscale or color (BGR) image containing bar code.
 	/// * points: vector of rotated rectangle vertices found by detect() method (or some other algorithm).
-	/// For N detected barcodes, the dimensions of this array should be [N][4].
+	/// For N detected barcodes, the dimensions of this array should be \[N\]\[4\].
 	/// Order of four points in vector<Point2f> is bottomLeft, topLeft, topRight, bottomRight.
 	/// * decoded_info: UTF8-encoded output vector of string or empty vector of string if the codes cannot be decoded.
 	/// * decoded_type: vector of BarcodeType, specifies the type of these barcodes
 	#[inline]
 	fn decode(&self, img: &dyn core::ToInputArray, points: &dyn core::ToInputArray, decoded_info: &mut core::Vector<String>, decoded_type: &mut core::Vector<crate::barcode::BarcodeType>) -> Result<bool> {
+		crate::core::validity::check("BarcodeDetector", self.as_raw_BarcodeDetector())?;
 		input_array_arg!(img);
 		input_array_arg!(points);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_barcode_BarcodeDetector_decode_const_const__InputArrayR_const__InputArrayR_vector_string_R_vector_BarcodeType_R(self.as_raw_BarcodeDetector(), img.as_raw__InputArray(), points.as_raw__InputArray(), decoded_info.as_raw_mut_VectorOfString(), decoded_type.as_raw_mut_VectorOfBarcodeType(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_barcode_BarcodeDetector_decode_const_const__InputArrayR_const__InputArrayR_vector_string_R_vector_BarcodeType_R(self.as_raw_BarcodeDetector(), img.as_raw__InputArray(), points.as_raw__InputArray(), decoded_info.as_raw_mut_VectorOfString(), decoded_type.as_raw_mut_VectorOfBarcodeType(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -85,10 +87,11 @@ pub trait BarcodeDetectorTraitConst {
 	/// * points: noArray()
 	#[inline]
 	fn detect_and_decode(&self, img: &dyn core::ToInputArray, decoded_info: &mut core::Vector<String>, decoded_type: &mut core::Vector<crate::barcode::BarcodeType>, points: &mut dyn core::ToOutputArray) -> Result<bool> {
+		crate::core::validity::check("BarcodeDetector", self.as_raw_BarcodeDetector())?;
 		input_array_arg!(img);
 		output_array_arg!(points);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_barcode_BarcodeDetector_detectAndDecode_const_const__InputArrayR_vector_string_R_vector_BarcodeType_R_const__OutputArrayR(self.as_raw_BarcodeDetector(), img.as_raw__InputArray(), decoded_info.as_raw_mut_VectorOfString(), decoded_type.as_raw_mut_VectorOfBarcodeType(), points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_barcode_BarcodeDetector_detectAndDecode_const_const__InputArrayR_vector_string_R_vector_BarcodeType_R_const__OutputArrayR(self.as_raw_BarcodeDetector(), img.as_raw__InputArray(), decoded_info.as_raw_mut_VectorOfString(), decoded_type.as_raw_mut_VectorOfBarcodeType(), points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -110,7 +113,10 @@ opencv_type_boxed! { BarcodeDetector }
 impl Drop for BarcodeDetector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BarcodeDetector_delete(instance: *mut c_void); }
-		unsafe { cv_BarcodeDetector_delete(self.as_raw_mut_BarcodeDetector()) };
+		let ptr = self.as_raw_mut_BarcodeDetector();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_BarcodeDetector_delete(ptr) };
 	}
 }
 
@@ -138,7 +144,7 @@ impl BarcodeDetector {
 		extern_container_arg!(prototxt_path);
 		extern_container_arg!(model_path);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_barcode_BarcodeDetector_BarcodeDetector_const_stringR_const_stringR(prototxt_path.opencv_as_extern(), model_path.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_barcode_BarcodeDetector_BarcodeDetector_const_stringR_const_stringR(prototxt_path.opencv_as_extern(), model_path.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::barcode::BarcodeDetector::opencv_from_extern(ret) };
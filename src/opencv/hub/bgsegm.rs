@@ -40,13 +40,57 @@ opencv_type_enum! { crate::bgsegm::LSBPCameraMotionCompensation }
 #[inline]
 pub fn create_background_subtractor_cnt(min_pixel_stability: i32, use_history: bool, max_pixel_stability: i32, is_parallel: bool) -> Result<core::Ptr<dyn crate::bgsegm::BackgroundSubtractorCNT>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_bgsegm_createBackgroundSubtractorCNT_int_bool_int_bool(min_pixel_stability, use_history, max_pixel_stability, is_parallel, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_bgsegm_createBackgroundSubtractorCNT_int_bool_int_bool(min_pixel_stability, use_history, max_pixel_stability, is_parallel, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::bgsegm::BackgroundSubtractorCNT>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_background_subtractor_cnt()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateBackgroundSubtractorCntBuilder {
+	min_pixel_stability: i32,
+	use_history: bool,
+	max_pixel_stability: i32,
+	is_parallel: bool,
+}
+
+impl CreateBackgroundSubtractorCntBuilder {
+	#[inline]
+	pub fn new(max_pixel_stability: i32) -> Self {
+		Self { min_pixel_stability: 15, use_history: true, max_pixel_stability, is_parallel: true }
+	}
+	
+	/// C++ default: `15`
+	#[inline]
+	pub fn with_min_pixel_stability(mut self, min_pixel_stability: i32) -> Self {
+		self.min_pixel_stability = min_pixel_stability;
+		self
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_use_history(mut self, use_history: bool) -> Self {
+		self.use_history = use_history;
+		self
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_is_parallel(mut self, is_parallel: bool) -> Self {
+		self.is_parallel = is_parallel;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::bgsegm::BackgroundSubtractorCNT>> {
+		create_background_subtractor_cnt(self.min_pixel_stability, self.use_history, self.max_pixel_stability, self.is_parallel)
+	}
+}
+
 /// Creates a GMG Background Subtractor
 /// 
 /// ## Parameters
@@ -59,7 +103,7 @@ pub fn create_background_subtractor_cnt(min_pixel_stability: i32, use_history: b
 #[inline]
 pub fn create_background_subtractor_gmg(initialization_frames: i32, decision_threshold: f64) -> Result<core::Ptr<dyn crate::bgsegm::BackgroundSubtractorGMG>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_bgsegm_createBackgroundSubtractorGMG_int_double(initialization_frames, decision_threshold, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_bgsegm_createBackgroundSubtractorGMG_int_double(initialization_frames, decision_threshold, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::bgsegm::BackgroundSubtractorGMG>::opencv_from_extern(ret) };
@@ -98,13 +142,113 @@ pub fn create_background_subtractor_gmg(initialization_frames: i32, decision_thr
 #[inline]
 pub fn create_background_subtractor_gsoc(mc: i32, n_samples: i32, replace_rate: f32, propagation_rate: f32, hits_threshold: i32, alpha: f32, beta: f32, blinking_supression_decay: f32, blinking_supression_multiplier: f32, noise_removal_threshold_fac_bg: f32, noise_removal_threshold_fac_fg: f32) -> Result<core::Ptr<dyn crate::bgsegm::BackgroundSubtractorGSOC>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_bgsegm_createBackgroundSubtractorGSOC_int_int_float_float_int_float_float_float_float_float_float(mc, n_samples, replace_rate, propagation_rate, hits_threshold, alpha, beta, blinking_supression_decay, blinking_supression_multiplier, noise_removal_threshold_fac_bg, noise_removal_threshold_fac_fg, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_bgsegm_createBackgroundSubtractorGSOC_int_int_float_float_int_float_float_float_float_float_float(mc, n_samples, replace_rate, propagation_rate, hits_threshold, alpha, beta, blinking_supression_decay, blinking_supression_multiplier, noise_removal_threshold_fac_bg, noise_removal_threshold_fac_fg, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::bgsegm::BackgroundSubtractorGSOC>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_background_subtractor_gsoc()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateBackgroundSubtractorGsocBuilder {
+	mc: i32,
+	n_samples: i32,
+	replace_rate: f32,
+	propagation_rate: f32,
+	hits_threshold: i32,
+	alpha: f32,
+	beta: f32,
+	blinking_supression_decay: f32,
+	blinking_supression_multiplier: f32,
+	noise_removal_threshold_fac_bg: f32,
+	noise_removal_threshold_fac_fg: f32,
+}
+
+impl CreateBackgroundSubtractorGsocBuilder {
+	#[inline]
+	pub fn new(mc: i32) -> Self {
+		Self { mc, n_samples: 20, replace_rate: 0.003, propagation_rate: 0.01, hits_threshold: 32, alpha: 0.01, beta: 0.0022, blinking_supression_decay: 0.1, blinking_supression_multiplier: 0.1, noise_removal_threshold_fac_bg: 0.0004, noise_removal_threshold_fac_fg: 0.0008 }
+	}
+	
+	/// C++ default: `20`
+	#[inline]
+	pub fn with_n_samples(mut self, n_samples: i32) -> Self {
+		self.n_samples = n_samples;
+		self
+	}
+	
+	/// C++ default: `0.003f`
+	#[inline]
+	pub fn with_replace_rate(mut self, replace_rate: f32) -> Self {
+		self.replace_rate = replace_rate;
+		self
+	}
+	
+	/// C++ default: `0.01f`
+	#[inline]
+	pub fn with_propagation_rate(mut self, propagation_rate: f32) -> Self {
+		self.propagation_rate = propagation_rate;
+		self
+	}
+	
+	/// C++ default: `32`
+	#[inline]
+	pub fn with_hits_threshold(mut self, hits_threshold: i32) -> Self {
+		self.hits_threshold = hits_threshold;
+		self
+	}
+	
+	/// C++ default: `0.01f`
+	#[inline]
+	pub fn with_alpha(mut self, alpha: f32) -> Self {
+		self.alpha = alpha;
+		self
+	}
+	
+	/// C++ default: `0.0022f`
+	#[inline]
+	pub fn with_beta(mut self, beta: f32) -> Self {
+		self.beta = beta;
+		self
+	}
+	
+	/// C++ default: `0.1f`
+	#[inline]
+	pub fn with_blinking_supression_decay(mut self, blinking_supression_decay: f32) -> Self {
+		self.blinking_supression_decay = blinking_supression_decay;
+		self
+	}
+	
+	/// C++ default: `0.1f`
+	#[inline]
+	pub fn with_blinking_supression_multiplier(mut self, blinking_supression_multiplier: f32) -> Self {
+		self.blinking_supression_multiplier = blinking_supression_multiplier;
+		self
+	}
+	
+	/// C++ default: `0.0004f`
+	#[inline]
+	pub fn with_noise_removal_threshold_fac_bg(mut self, noise_removal_threshold_fac_bg: f32) -> Self {
+		self.noise_removal_threshold_fac_bg = noise_removal_threshold_fac_bg;
+		self
+	}
+	
+	/// C++ default: `0.0008f`
+	#[inline]
+	pub fn with_noise_removal_threshold_fac_fg(mut self, noise_removal_threshold_fac_fg: f32) -> Self {
+		self.noise_removal_threshold_fac_fg = noise_removal_threshold_fac_fg;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::bgsegm::BackgroundSubtractorGSOC>> {
+		create_background_subtractor_gsoc(self.mc, self.n_samples, self.replace_rate, self.propagation_rate, self.hits_threshold, self.alpha, self.beta, self.blinking_supression_decay, self.blinking_supression_multiplier, self.noise_removal_threshold_fac_bg, self.noise_removal_threshold_fac_fg)
+	}
+}
+
 /// Creates an instance of BackgroundSubtractorLSBP algorithm.
 /// 
 /// Background Subtraction using Local SVD Binary Pattern. More details about the algorithm can be found at [LGuo2016](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_LGuo2016)
@@ -141,13 +285,129 @@ pub fn create_background_subtractor_gsoc(mc: i32, n_samples: i32, replace_rate:
 #[inline]
 pub fn create_background_subtractor_lsbp(mc: i32, n_samples: i32, lsbp_radius: i32, tlower: f32, tupper: f32, tinc: f32, tdec: f32, rscale: f32, rincdec: f32, noise_removal_threshold_fac_bg: f32, noise_removal_threshold_fac_fg: f32, lsb_pthreshold: i32, min_count: i32) -> Result<core::Ptr<dyn crate::bgsegm::BackgroundSubtractorLSBP>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_bgsegm_createBackgroundSubtractorLSBP_int_int_int_float_float_float_float_float_float_float_float_int_int(mc, n_samples, lsbp_radius, tlower, tupper, tinc, tdec, rscale, rincdec, noise_removal_threshold_fac_bg, noise_removal_threshold_fac_fg, lsb_pthreshold, min_count, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_bgsegm_createBackgroundSubtractorLSBP_int_int_int_float_float_float_float_float_float_float_float_int_int(mc, n_samples, lsbp_radius, tlower, tupper, tinc, tdec, rscale, rincdec, noise_removal_threshold_fac_bg, noise_removal_threshold_fac_fg, lsb_pthreshold, min_count, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::bgsegm::BackgroundSubtractorLSBP>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_background_subtractor_lsbp()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateBackgroundSubtractorLsbpBuilder {
+	mc: i32,
+	n_samples: i32,
+	lsbp_radius: i32,
+	tlower: f32,
+	tupper: f32,
+	tinc: f32,
+	tdec: f32,
+	rscale: f32,
+	rincdec: f32,
+	noise_removal_threshold_fac_bg: f32,
+	noise_removal_threshold_fac_fg: f32,
+	lsb_pthreshold: i32,
+	min_count: i32,
+}
+
+impl CreateBackgroundSubtractorLsbpBuilder {
+	#[inline]
+	pub fn new(mc: i32) -> Self {
+		Self { mc, n_samples: 20, lsbp_radius: 16, tlower: 2.0, tupper: 32.0, tinc: 1.0, tdec: 0.05, rscale: 10.0, rincdec: 0.005, noise_removal_threshold_fac_bg: 0.0004, noise_removal_threshold_fac_fg: 0.0008, lsb_pthreshold: 8, min_count: 2 }
+	}
+	
+	/// C++ default: `20`
+	#[inline]
+	pub fn with_n_samples(mut self, n_samples: i32) -> Self {
+		self.n_samples = n_samples;
+		self
+	}
+	
+	/// C++ default: `16`
+	#[inline]
+	pub fn with_lsbp_radius(mut self, lsbp_radius: i32) -> Self {
+		self.lsbp_radius = lsbp_radius;
+		self
+	}
+	
+	/// C++ default: `2.0f`
+	#[inline]
+	pub fn with_tlower(mut self, tlower: f32) -> Self {
+		self.tlower = tlower;
+		self
+	}
+	
+	/// C++ default: `32.0f`
+	#[inline]
+	pub fn with_tupper(mut self, tupper: f32) -> Self {
+		self.tupper = tupper;
+		self
+	}
+	
+	/// C++ default: `1.0f`
+	#[inline]
+	pub fn with_tinc(mut self, tinc: f32) -> Self {
+		self.tinc = tinc;
+		self
+	}
+	
+	/// C++ default: `0.05f`
+	#[inline]
+	pub fn with_tdec(mut self, tdec: f32) -> Self {
+		self.tdec = tdec;
+		self
+	}
+	
+	/// C++ default: `10.0f`
+	#[inline]
+	pub fn with_rscale(mut self, rscale: f32) -> Self {
+		self.rscale = rscale;
+		self
+	}
+	
+	/// C++ default: `0.005f`
+	#[inline]
+	pub fn with_rincdec(mut self, rincdec: f32) -> Self {
+		self.rincdec = rincdec;
+		self
+	}
+	
+	/// C++ default: `0.0004f`
+	#[inline]
+	pub fn with_noise_removal_threshold_fac_bg(mut self, noise_removal_threshold_fac_bg: f32) -> Self {
+		self.noise_removal_threshold_fac_bg = noise_removal_threshold_fac_bg;
+		self
+	}
+	
+	/// C++ default: `0.0008f`
+	#[inline]
+	pub fn with_noise_removal_threshold_fac_fg(mut self, noise_removal_threshold_fac_fg: f32) -> Self {
+		self.noise_removal_threshold_fac_fg = noise_removal_threshold_fac_fg;
+		self
+	}
+	
+	/// C++ default: `8`
+	#[inline]
+	pub fn with_lsb_pthreshold(mut self, lsb_pthreshold: i32) -> Self {
+		self.lsb_pthreshold = lsb_pthreshold;
+		self
+	}
+	
+	/// C++ default: `2`
+	#[inline]
+	pub fn with_min_count(mut self, min_count: i32) -> Self {
+		self.min_count = min_count;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::bgsegm::BackgroundSubtractorLSBP>> {
+		create_background_subtractor_lsbp(self.mc, self.n_samples, self.lsbp_radius, self.tlower, self.tupper, self.tinc, self.tdec, self.rscale, self.rincdec, self.noise_removal_threshold_fac_bg, self.noise_removal_threshold_fac_fg, self.lsb_pthreshold, self.min_count)
+	}
+}
+
 /// Creates mixture-of-gaussian background subtractor
 /// 
 /// ## Parameters
@@ -165,13 +425,71 @@ pub fn create_background_subtractor_lsbp(mc: i32, n_samples: i32, lsbp_radius: i
 #[inline]
 pub fn create_background_subtractor_mog(history: i32, nmixtures: i32, background_ratio: f64, noise_sigma: f64) -> Result<core::Ptr<dyn crate::bgsegm::BackgroundSubtractorMOG>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_bgsegm_createBackgroundSubtractorMOG_int_int_double_double(history, nmixtures, background_ratio, noise_sigma, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_bgsegm_createBackgroundSubtractorMOG_int_int_double_double(history, nmixtures, background_ratio, noise_sigma, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::bgsegm::BackgroundSubtractorMOG>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_background_subtractor_mog()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateBackgroundSubtractorMogBuilder {
+	history: i32,
+	nmixtures: i32,
+	background_ratio: f64,
+	noise_sigma: f64,
+}
+
+impl CreateBackgroundSubtractorMogBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { history: 200, nmixtures: 5, background_ratio: 0.7, noise_sigma: 0. }
+	}
+	
+	/// C++ default: `200`
+	#[inline]
+	pub fn with_history(mut self, history: i32) -> Self {
+		self.history = history;
+		self
+	}
+	
+	/// C++ default: `5`
+	#[inline]
+	pub fn with_nmixtures(mut self, nmixtures: i32) -> Self {
+		self.nmixtures = nmixtures;
+		self
+	}
+	
+	/// C++ default: `0.7`
+	#[inline]
+	pub fn with_background_ratio(mut self, background_ratio: f64) -> Self {
+		self.background_ratio = background_ratio;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_noise_sigma(mut self, noise_sigma: f64) -> Self {
+		self.noise_sigma = noise_sigma;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::bgsegm::BackgroundSubtractorMOG>> {
+		create_background_subtractor_mog(self.history, self.nmixtures, self.background_ratio, self.noise_sigma)
+	}
+}
+
+impl Default for CreateBackgroundSubtractorMogBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 /// Creates an instance of SyntheticSequenceGenerator.
 /// 
 /// ## Parameters
@@ -192,13 +510,66 @@ pub fn create_synthetic_sequence_generator(background: &dyn core::ToInputArray,
 	input_array_arg!(background);
 	input_array_arg!(object);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_bgsegm_createSyntheticSequenceGenerator_const__InputArrayR_const__InputArrayR_double_double_double_double(background.as_raw__InputArray(), object.as_raw__InputArray(), amplitude, wavelength, wavespeed, objspeed, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_bgsegm_createSyntheticSequenceGenerator_const__InputArrayR_const__InputArrayR_double_double_double_double(background.as_raw__InputArray(), object.as_raw__InputArray(), amplitude, wavelength, wavespeed, objspeed, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<crate::bgsegm::SyntheticSequenceGenerator>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_synthetic_sequence_generator()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateSyntheticSequenceGeneratorBuilder<'a> {
+	background: &'a dyn core::ToInputArray,
+	object: &'a dyn core::ToInputArray,
+	amplitude: f64,
+	wavelength: f64,
+	wavespeed: f64,
+	objspeed: f64,
+}
+
+impl<'a> CreateSyntheticSequenceGeneratorBuilder<'a> {
+	#[inline]
+	pub fn new(background: &'a dyn core::ToInputArray, object: &'a dyn core::ToInputArray) -> Self {
+		Self { background, object, amplitude: 2.0, wavelength: 20.0, wavespeed: 0.2, objspeed: 6.0 }
+	}
+	
+	/// C++ default: `2.0`
+	#[inline]
+	pub fn with_amplitude(mut self, amplitude: f64) -> Self {
+		self.amplitude = amplitude;
+		self
+	}
+	
+	/// C++ default: `20.0`
+	#[inline]
+	pub fn with_wavelength(mut self, wavelength: f64) -> Self {
+		self.wavelength = wavelength;
+		self
+	}
+	
+	/// C++ default: `0.2`
+	#[inline]
+	pub fn with_wavespeed(mut self, wavespeed: f64) -> Self {
+		self.wavespeed = wavespeed;
+		self
+	}
+	
+	/// C++ default: `6.0`
+	#[inline]
+	pub fn with_objspeed(mut self, objspeed: f64) -> Self {
+		self.objspeed = objspeed;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<crate::bgsegm::SyntheticSequenceGenerator>> {
+		create_synthetic_sequence_generator(self.background, self.object, self.amplitude, self.wavelength, self.wavespeed, self.objspeed)
+	}
+}
+
 /// Background subtraction based on counting.
 /// 
 /// About as fast as MOG2 on a high end system.
@@ -210,9 +581,10 @@ pub trait BackgroundSubtractorCNTConst: crate::video::BackgroundSubtractorConst
 
 	#[inline]
 	fn get_background_image(&self, background_image: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorCNT", self.as_raw_BackgroundSubtractorCNT())?;
 		output_array_arg!(background_image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorCNT_getBackgroundImage_const_const__OutputArrayR(self.as_raw_BackgroundSubtractorCNT(), background_image.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorCNT_getBackgroundImage_const_const__OutputArrayR(self.as_raw_BackgroundSubtractorCNT(), background_image.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -221,8 +593,9 @@ pub trait BackgroundSubtractorCNTConst: crate::video::BackgroundSubtractorConst
 	/// Returns number of frames with same pixel color to consider stable.
 	#[inline]
 	fn get_min_pixel_stability(&self) -> Result<i32> {
+		crate::core::validity::check("BackgroundSubtractorCNT", self.as_raw_BackgroundSubtractorCNT())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorCNT_getMinPixelStability_const(self.as_raw_BackgroundSubtractorCNT(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorCNT_getMinPixelStability_const(self.as_raw_BackgroundSubtractorCNT(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -231,8 +604,9 @@ pub trait BackgroundSubtractorCNTConst: crate::video::BackgroundSubtractorConst
 	/// Returns maximum allowed credit for a pixel in history.
 	#[inline]
 	fn get_max_pixel_stability(&self) -> Result<i32> {
+		crate::core::validity::check("BackgroundSubtractorCNT", self.as_raw_BackgroundSubtractorCNT())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorCNT_getMaxPixelStability_const(self.as_raw_BackgroundSubtractorCNT(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorCNT_getMaxPixelStability_const(self.as_raw_BackgroundSubtractorCNT(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -241,8 +615,9 @@ pub trait BackgroundSubtractorCNTConst: crate::video::BackgroundSubtractorConst
 	/// Returns if we're giving a pixel credit for being stable for a long time.
 	#[inline]
 	fn get_use_history(&self) -> Result<bool> {
+		crate::core::validity::check("BackgroundSubtractorCNT", self.as_raw_BackgroundSubtractorCNT())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorCNT_getUseHistory_const(self.as_raw_BackgroundSubtractorCNT(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorCNT_getUseHistory_const(self.as_raw_BackgroundSubtractorCNT(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -251,8 +626,9 @@ pub trait BackgroundSubtractorCNTConst: crate::video::BackgroundSubtractorConst
 	/// Returns if we're parallelizing the algorithm.
 	#[inline]
 	fn get_is_parallel(&self) -> Result<bool> {
+		crate::core::validity::check("BackgroundSubtractorCNT", self.as_raw_BackgroundSubtractorCNT())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorCNT_getIsParallel_const(self.as_raw_BackgroundSubtractorCNT(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorCNT_getIsParallel_const(self.as_raw_BackgroundSubtractorCNT(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -267,10 +643,11 @@ pub trait BackgroundSubtractorCNT: crate::bgsegm::BackgroundSubtractorCNTConst +
 	/// * learning_rate: -1
 	#[inline]
 	fn apply(&mut self, image: &dyn core::ToInputArray, fgmask: &mut dyn core::ToOutputArray, learning_rate: f64) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorCNT", self.as_raw_mut_BackgroundSubtractorCNT())?;
 		input_array_arg!(image);
 		output_array_arg!(fgmask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorCNT_apply_const__InputArrayR_const__OutputArrayR_double(self.as_raw_mut_BackgroundSubtractorCNT(), image.as_raw__InputArray(), fgmask.as_raw__OutputArray(), learning_rate, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorCNT_apply_const__InputArrayR_const__OutputArrayR_double(self.as_raw_mut_BackgroundSubtractorCNT(), image.as_raw__InputArray(), fgmask.as_raw__OutputArray(), learning_rate, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -279,8 +656,9 @@ pub trait BackgroundSubtractorCNT: crate::bgsegm::BackgroundSubtractorCNTConst +
 	/// Sets the number of frames with same pixel color to consider stable.
 	#[inline]
 	fn set_min_pixel_stability(&mut self, value: i32) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorCNT", self.as_raw_mut_BackgroundSubtractorCNT())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorCNT_setMinPixelStability_int(self.as_raw_mut_BackgroundSubtractorCNT(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorCNT_setMinPixelStability_int(self.as_raw_mut_BackgroundSubtractorCNT(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -289,8 +667,9 @@ pub trait BackgroundSubtractorCNT: crate::bgsegm::BackgroundSubtractorCNTConst +
 	/// Sets the maximum allowed credit for a pixel in history.
 	#[inline]
 	fn set_max_pixel_stability(&mut self, value: i32) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorCNT", self.as_raw_mut_BackgroundSubtractorCNT())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorCNT_setMaxPixelStability_int(self.as_raw_mut_BackgroundSubtractorCNT(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorCNT_setMaxPixelStability_int(self.as_raw_mut_BackgroundSubtractorCNT(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -299,8 +678,9 @@ pub trait BackgroundSubtractorCNT: crate::bgsegm::BackgroundSubtractorCNTConst +
 	/// Sets if we're giving a pixel credit for being stable for a long time.
 	#[inline]
 	fn set_use_history(&mut self, value: bool) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorCNT", self.as_raw_mut_BackgroundSubtractorCNT())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorCNT_setUseHistory_bool(self.as_raw_mut_BackgroundSubtractorCNT(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorCNT_setUseHistory_bool(self.as_raw_mut_BackgroundSubtractorCNT(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -309,8 +689,9 @@ pub trait BackgroundSubtractorCNT: crate::bgsegm::BackgroundSubtractorCNTConst +
 	/// Sets if we're parallelizing the algorithm.
 	#[inline]
 	fn set_is_parallel(&mut self, value: bool) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorCNT", self.as_raw_mut_BackgroundSubtractorCNT())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorCNT_setIsParallel_bool(self.as_raw_mut_BackgroundSubtractorCNT(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorCNT_setIsParallel_bool(self.as_raw_mut_BackgroundSubtractorCNT(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -331,8 +712,9 @@ pub trait BackgroundSubtractorGMGConst: crate::video::BackgroundSubtractorConst
 	/// Returns total number of distinct colors to maintain in histogram.
 	#[inline]
 	fn get_max_features(&self) -> Result<i32> {
+		crate::core::validity::check("BackgroundSubtractorGMG", self.as_raw_BackgroundSubtractorGMG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorGMG_getMaxFeatures_const(self.as_raw_BackgroundSubtractorGMG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorGMG_getMaxFeatures_const(self.as_raw_BackgroundSubtractorGMG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -344,8 +726,9 @@ pub trait BackgroundSubtractorGMGConst: crate::video::BackgroundSubtractorConst
 	/// histograms.
 	#[inline]
 	fn get_default_learning_rate(&self) -> Result<f64> {
+		crate::core::validity::check("BackgroundSubtractorGMG", self.as_raw_BackgroundSubtractorGMG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorGMG_getDefaultLearningRate_const(self.as_raw_BackgroundSubtractorGMG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorGMG_getDefaultLearningRate_const(self.as_raw_BackgroundSubtractorGMG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -354,8 +737,9 @@ pub trait BackgroundSubtractorGMGConst: crate::video::BackgroundSubtractorConst
 	/// Returns the number of frames used to initialize background model.
 	#[inline]
 	fn get_num_frames(&self) -> Result<i32> {
+		crate::core::validity::check("BackgroundSubtractorGMG", self.as_raw_BackgroundSubtractorGMG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorGMG_getNumFrames_const(self.as_raw_BackgroundSubtractorGMG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorGMG_getNumFrames_const(self.as_raw_BackgroundSubtractorGMG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -366,8 +750,9 @@ pub trait BackgroundSubtractorGMGConst: crate::video::BackgroundSubtractorConst
 	/// It is the number of discrete levels in each channel to be used in histograms.
 	#[inline]
 	fn get_quantization_levels(&self) -> Result<i32> {
+		crate::core::validity::check("BackgroundSubtractorGMG", self.as_raw_BackgroundSubtractorGMG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorGMG_getQuantizationLevels_const(self.as_raw_BackgroundSubtractorGMG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorGMG_getQuantizationLevels_const(self.as_raw_BackgroundSubtractorGMG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -376,8 +761,9 @@ pub trait BackgroundSubtractorGMGConst: crate::video::BackgroundSubtractorConst
 	/// Returns the prior probability that each individual pixel is a background pixel.
 	#[inline]
 	fn get_background_prior(&self) -> Result<f64> {
+		crate::core::validity::check("BackgroundSubtractorGMG", self.as_raw_BackgroundSubtractorGMG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorGMG_getBackgroundPrior_const(self.as_raw_BackgroundSubtractorGMG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorGMG_getBackgroundPrior_const(self.as_raw_BackgroundSubtractorGMG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -386,8 +772,9 @@ pub trait BackgroundSubtractorGMGConst: crate::video::BackgroundSubtractorConst
 	/// Returns the kernel radius used for morphological operations
 	#[inline]
 	fn get_smoothing_radius(&self) -> Result<i32> {
+		crate::core::validity::check("BackgroundSubtractorGMG", self.as_raw_BackgroundSubtractorGMG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorGMG_getSmoothingRadius_const(self.as_raw_BackgroundSubtractorGMG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorGMG_getSmoothingRadius_const(self.as_raw_BackgroundSubtractorGMG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -398,8 +785,9 @@ pub trait BackgroundSubtractorGMGConst: crate::video::BackgroundSubtractorConst
 	/// Decision value is the value above which pixel is determined to be FG.
 	#[inline]
 	fn get_decision_threshold(&self) -> Result<f64> {
+		crate::core::validity::check("BackgroundSubtractorGMG", self.as_raw_BackgroundSubtractorGMG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorGMG_getDecisionThreshold_const(self.as_raw_BackgroundSubtractorGMG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorGMG_getDecisionThreshold_const(self.as_raw_BackgroundSubtractorGMG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -408,8 +796,9 @@ pub trait BackgroundSubtractorGMGConst: crate::video::BackgroundSubtractorConst
 	/// Returns the status of background model update
 	#[inline]
 	fn get_update_background_model(&self) -> Result<bool> {
+		crate::core::validity::check("BackgroundSubtractorGMG", self.as_raw_BackgroundSubtractorGMG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorGMG_getUpdateBackgroundModel_const(self.as_raw_BackgroundSubtractorGMG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorGMG_getUpdateBackgroundModel_const(self.as_raw_BackgroundSubtractorGMG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -418,8 +807,9 @@ pub trait BackgroundSubtractorGMGConst: crate::video::BackgroundSubtractorConst
 	/// Returns the minimum value taken on by pixels in image sequence. Usually 0.
 	#[inline]
 	fn get_min_val(&self) -> Result<f64> {
+		crate::core::validity::check("BackgroundSubtractorGMG", self.as_raw_BackgroundSubtractorGMG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorGMG_getMinVal_const(self.as_raw_BackgroundSubtractorGMG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorGMG_getMinVal_const(self.as_raw_BackgroundSubtractorGMG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -428,8 +818,9 @@ pub trait BackgroundSubtractorGMGConst: crate::video::BackgroundSubtractorConst
 	/// Returns the maximum value taken on by pixels in image sequence. e.g. 1.0 or 255.
 	#[inline]
 	fn get_max_val(&self) -> Result<f64> {
+		crate::core::validity::check("BackgroundSubtractorGMG", self.as_raw_BackgroundSubtractorGMG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorGMG_getMaxVal_const(self.as_raw_BackgroundSubtractorGMG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorGMG_getMaxVal_const(self.as_raw_BackgroundSubtractorGMG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -443,8 +834,9 @@ pub trait BackgroundSubtractorGMG: crate::bgsegm::BackgroundSubtractorGMGConst +
 	/// Sets total number of distinct colors to maintain in histogram.
 	#[inline]
 	fn set_max_features(&mut self, max_features: i32) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorGMG", self.as_raw_mut_BackgroundSubtractorGMG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorGMG_setMaxFeatures_int(self.as_raw_mut_BackgroundSubtractorGMG(), max_features, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorGMG_setMaxFeatures_int(self.as_raw_mut_BackgroundSubtractorGMG(), max_features, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -453,8 +845,9 @@ pub trait BackgroundSubtractorGMG: crate::bgsegm::BackgroundSubtractorGMGConst +
 	/// Sets the learning rate of the algorithm.
 	#[inline]
 	fn set_default_learning_rate(&mut self, lr: f64) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorGMG", self.as_raw_mut_BackgroundSubtractorGMG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorGMG_setDefaultLearningRate_double(self.as_raw_mut_BackgroundSubtractorGMG(), lr, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorGMG_setDefaultLearningRate_double(self.as_raw_mut_BackgroundSubtractorGMG(), lr, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -463,8 +856,9 @@ pub trait BackgroundSubtractorGMG: crate::bgsegm::BackgroundSubtractorGMGConst +
 	/// Sets the number of frames used to initialize background model.
 	#[inline]
 	fn set_num_frames(&mut self, nframes: i32) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorGMG", self.as_raw_mut_BackgroundSubtractorGMG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorGMG_setNumFrames_int(self.as_raw_mut_BackgroundSubtractorGMG(), nframes, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorGMG_setNumFrames_int(self.as_raw_mut_BackgroundSubtractorGMG(), nframes, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -473,8 +867,9 @@ pub trait BackgroundSubtractorGMG: crate::bgsegm::BackgroundSubtractorGMGConst +
 	/// Sets the parameter used for quantization of color-space
 	#[inline]
 	fn set_quantization_levels(&mut self, nlevels: i32) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorGMG", self.as_raw_mut_BackgroundSubtractorGMG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorGMG_setQuantizationLevels_int(self.as_raw_mut_BackgroundSubtractorGMG(), nlevels, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorGMG_setQuantizationLevels_int(self.as_raw_mut_BackgroundSubtractorGMG(), nlevels, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -483,8 +878,9 @@ pub trait BackgroundSubtractorGMG: crate::bgsegm::BackgroundSubtractorGMGConst +
 	/// Sets the prior probability that each individual pixel is a background pixel.
 	#[inline]
 	fn set_background_prior(&mut self, bgprior: f64) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorGMG", self.as_raw_mut_BackgroundSubtractorGMG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorGMG_setBackgroundPrior_double(self.as_raw_mut_BackgroundSubtractorGMG(), bgprior, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorGMG_setBackgroundPrior_double(self.as_raw_mut_BackgroundSubtractorGMG(), bgprior, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -493,8 +889,9 @@ pub trait BackgroundSubtractorGMG: crate::bgsegm::BackgroundSubtractorGMGConst +
 	/// Sets the kernel radius used for morphological operations
 	#[inline]
 	fn set_smoothing_radius(&mut self, radius: i32) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorGMG", self.as_raw_mut_BackgroundSubtractorGMG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorGMG_setSmoothingRadius_int(self.as_raw_mut_BackgroundSubtractorGMG(), radius, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorGMG_setSmoothingRadius_int(self.as_raw_mut_BackgroundSubtractorGMG(), radius, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -503,8 +900,9 @@ pub trait BackgroundSubtractorGMG: crate::bgsegm::BackgroundSubtractorGMGConst +
 	/// Sets the value of decision threshold.
 	#[inline]
 	fn set_decision_threshold(&mut self, thresh: f64) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorGMG", self.as_raw_mut_BackgroundSubtractorGMG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorGMG_setDecisionThreshold_double(self.as_raw_mut_BackgroundSubtractorGMG(), thresh, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorGMG_setDecisionThreshold_double(self.as_raw_mut_BackgroundSubtractorGMG(), thresh, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -513,8 +911,9 @@ pub trait BackgroundSubtractorGMG: crate::bgsegm::BackgroundSubtractorGMGConst +
 	/// Sets the status of background model update
 	#[inline]
 	fn set_update_background_model(&mut self, update: bool) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorGMG", self.as_raw_mut_BackgroundSubtractorGMG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorGMG_setUpdateBackgroundModel_bool(self.as_raw_mut_BackgroundSubtractorGMG(), update, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorGMG_setUpdateBackgroundModel_bool(self.as_raw_mut_BackgroundSubtractorGMG(), update, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -523,8 +922,9 @@ pub trait BackgroundSubtractorGMG: crate::bgsegm::BackgroundSubtractorGMGConst +
 	/// Sets the minimum value taken on by pixels in image sequence.
 	#[inline]
 	fn set_min_val(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorGMG", self.as_raw_mut_BackgroundSubtractorGMG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorGMG_setMinVal_double(self.as_raw_mut_BackgroundSubtractorGMG(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorGMG_setMinVal_double(self.as_raw_mut_BackgroundSubtractorGMG(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -533,8 +933,9 @@ pub trait BackgroundSubtractorGMG: crate::bgsegm::BackgroundSubtractorGMGConst +
 	/// Sets the maximum value taken on by pixels in image sequence.
 	#[inline]
 	fn set_max_val(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorGMG", self.as_raw_mut_BackgroundSubtractorGMG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorGMG_setMaxVal_double(self.as_raw_mut_BackgroundSubtractorGMG(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorGMG_setMaxVal_double(self.as_raw_mut_BackgroundSubtractorGMG(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -550,9 +951,10 @@ pub trait BackgroundSubtractorGSOCConst: crate::video::BackgroundSubtractorConst
 
 	#[inline]
 	fn get_background_image(&self, background_image: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorGSOC", self.as_raw_BackgroundSubtractorGSOC())?;
 		output_array_arg!(background_image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorGSOC_getBackgroundImage_const_const__OutputArrayR(self.as_raw_BackgroundSubtractorGSOC(), background_image.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorGSOC_getBackgroundImage_const_const__OutputArrayR(self.as_raw_BackgroundSubtractorGSOC(), background_image.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -567,10 +969,11 @@ pub trait BackgroundSubtractorGSOC: crate::bgsegm::BackgroundSubtractorGSOCConst
 	/// * learning_rate: -1
 	#[inline]
 	fn apply(&mut self, image: &dyn core::ToInputArray, fgmask: &mut dyn core::ToOutputArray, learning_rate: f64) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorGSOC", self.as_raw_mut_BackgroundSubtractorGSOC())?;
 		input_array_arg!(image);
 		output_array_arg!(fgmask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorGSOC_apply_const__InputArrayR_const__OutputArrayR_double(self.as_raw_mut_BackgroundSubtractorGSOC(), image.as_raw__InputArray(), fgmask.as_raw__OutputArray(), learning_rate, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorGSOC_apply_const__InputArrayR_const__OutputArrayR_double(self.as_raw_mut_BackgroundSubtractorGSOC(), image.as_raw__InputArray(), fgmask.as_raw__OutputArray(), learning_rate, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -584,9 +987,10 @@ pub trait BackgroundSubtractorLSBPConst: crate::video::BackgroundSubtractorConst
 
 	#[inline]
 	fn get_background_image(&self, background_image: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorLSBP", self.as_raw_BackgroundSubtractorLSBP())?;
 		output_array_arg!(background_image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorLSBP_getBackgroundImage_const_const__OutputArrayR(self.as_raw_BackgroundSubtractorLSBP(), background_image.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorLSBP_getBackgroundImage_const_const__OutputArrayR(self.as_raw_BackgroundSubtractorLSBP(), background_image.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -601,10 +1005,11 @@ pub trait BackgroundSubtractorLSBP: crate::bgsegm::BackgroundSubtractorLSBPConst
 	/// * learning_rate: -1
 	#[inline]
 	fn apply(&mut self, image: &dyn core::ToInputArray, fgmask: &mut dyn core::ToOutputArray, learning_rate: f64) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorLSBP", self.as_raw_mut_BackgroundSubtractorLSBP())?;
 		input_array_arg!(image);
 		output_array_arg!(fgmask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorLSBP_apply_const__InputArrayR_const__OutputArrayR_double(self.as_raw_mut_BackgroundSubtractorLSBP(), image.as_raw__InputArray(), fgmask.as_raw__OutputArray(), learning_rate, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorLSBP_apply_const__InputArrayR_const__OutputArrayR_double(self.as_raw_mut_BackgroundSubtractorLSBP(), image.as_raw__InputArray(), fgmask.as_raw__OutputArray(), learning_rate, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -633,7 +1038,10 @@ opencv_type_boxed! { BackgroundSubtractorLSBPDesc }
 impl Drop for BackgroundSubtractorLSBPDesc {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BackgroundSubtractorLSBPDesc_delete(instance: *mut c_void); }
-		unsafe { cv_BackgroundSubtractorLSBPDesc_delete(self.as_raw_mut_BackgroundSubtractorLSBPDesc()) };
+		let ptr = self.as_raw_mut_BackgroundSubtractorLSBPDesc();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_BackgroundSubtractorLSBPDesc_delete(ptr) };
 	}
 }
 
@@ -652,7 +1060,7 @@ impl BackgroundSubtractorLSBPDesc {
 	pub fn calc_local_svd_values(local_svd_values: &mut dyn core::ToOutputArray, frame: &core::Mat) -> Result<()> {
 		output_array_arg!(local_svd_values);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorLSBPDesc_calcLocalSVDValues_const__OutputArrayR_const_MatR(local_svd_values.as_raw__OutputArray(), frame.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorLSBPDesc_calcLocalSVDValues_const__OutputArrayR_const_MatR(local_svd_values.as_raw__OutputArray(), frame.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -662,7 +1070,7 @@ impl BackgroundSubtractorLSBPDesc {
 	pub fn compute_from_local_svd_values(desc: &mut dyn core::ToOutputArray, local_svd_values: &core::Mat, lsbp_sample_points: &core::Point2i) -> Result<()> {
 		output_array_arg!(desc);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorLSBPDesc_computeFromLocalSVDValues_const__OutputArrayR_const_MatR_const_Point2iX(desc.as_raw__OutputArray(), local_svd_values.as_raw_Mat(), lsbp_sample_points, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorLSBPDesc_computeFromLocalSVDValues_const__OutputArrayR_const_MatR_const_Point2iX(desc.as_raw__OutputArray(), local_svd_values.as_raw_Mat(), lsbp_sample_points, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -672,7 +1080,7 @@ impl BackgroundSubtractorLSBPDesc {
 	pub fn compute(desc: &mut dyn core::ToOutputArray, frame: &core::Mat, lsbp_sample_points: &core::Point2i) -> Result<()> {
 		output_array_arg!(desc);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorLSBPDesc_compute_const__OutputArrayR_const_MatR_const_Point2iX(desc.as_raw__OutputArray(), frame.as_raw_Mat(), lsbp_sample_points, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorLSBPDesc_compute_const__OutputArrayR_const_MatR_const_Point2iX(desc.as_raw__OutputArray(), frame.as_raw_Mat(), lsbp_sample_points, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -688,8 +1096,9 @@ pub trait BackgroundSubtractorMOGConst: crate::video::BackgroundSubtractorConst
 
 	#[inline]
 	fn get_history(&self) -> Result<i32> {
+		crate::core::validity::check("BackgroundSubtractorMOG", self.as_raw_BackgroundSubtractorMOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorMOG_getHistory_const(self.as_raw_BackgroundSubtractorMOG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorMOG_getHistory_const(self.as_raw_BackgroundSubtractorMOG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -697,8 +1106,9 @@ pub trait BackgroundSubtractorMOGConst: crate::video::BackgroundSubtractorConst
 	
 	#[inline]
 	fn get_n_mixtures(&self) -> Result<i32> {
+		crate::core::validity::check("BackgroundSubtractorMOG", self.as_raw_BackgroundSubtractorMOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorMOG_getNMixtures_const(self.as_raw_BackgroundSubtractorMOG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorMOG_getNMixtures_const(self.as_raw_BackgroundSubtractorMOG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -706,8 +1116,9 @@ pub trait BackgroundSubtractorMOGConst: crate::video::BackgroundSubtractorConst
 	
 	#[inline]
 	fn get_background_ratio(&self) -> Result<f64> {
+		crate::core::validity::check("BackgroundSubtractorMOG", self.as_raw_BackgroundSubtractorMOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorMOG_getBackgroundRatio_const(self.as_raw_BackgroundSubtractorMOG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorMOG_getBackgroundRatio_const(self.as_raw_BackgroundSubtractorMOG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -715,8 +1126,9 @@ pub trait BackgroundSubtractorMOGConst: crate::video::BackgroundSubtractorConst
 	
 	#[inline]
 	fn get_noise_sigma(&self) -> Result<f64> {
+		crate::core::validity::check("BackgroundSubtractorMOG", self.as_raw_BackgroundSubtractorMOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorMOG_getNoiseSigma_const(self.as_raw_BackgroundSubtractorMOG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorMOG_getNoiseSigma_const(self.as_raw_BackgroundSubtractorMOG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -729,8 +1141,9 @@ pub trait BackgroundSubtractorMOG: crate::bgsegm::BackgroundSubtractorMOGConst +
 
 	#[inline]
 	fn set_history(&mut self, nframes: i32) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorMOG", self.as_raw_mut_BackgroundSubtractorMOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorMOG_setHistory_int(self.as_raw_mut_BackgroundSubtractorMOG(), nframes, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorMOG_setHistory_int(self.as_raw_mut_BackgroundSubtractorMOG(), nframes, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -738,8 +1151,9 @@ pub trait BackgroundSubtractorMOG: crate::bgsegm::BackgroundSubtractorMOGConst +
 	
 	#[inline]
 	fn set_n_mixtures(&mut self, nmix: i32) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorMOG", self.as_raw_mut_BackgroundSubtractorMOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorMOG_setNMixtures_int(self.as_raw_mut_BackgroundSubtractorMOG(), nmix, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorMOG_setNMixtures_int(self.as_raw_mut_BackgroundSubtractorMOG(), nmix, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -747,8 +1161,9 @@ pub trait BackgroundSubtractorMOG: crate::bgsegm::BackgroundSubtractorMOGConst +
 	
 	#[inline]
 	fn set_background_ratio(&mut self, background_ratio: f64) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorMOG", self.as_raw_mut_BackgroundSubtractorMOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorMOG_setBackgroundRatio_double(self.as_raw_mut_BackgroundSubtractorMOG(), background_ratio, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorMOG_setBackgroundRatio_double(self.as_raw_mut_BackgroundSubtractorMOG(), background_ratio, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -756,8 +1171,9 @@ pub trait BackgroundSubtractorMOG: crate::bgsegm::BackgroundSubtractorMOGConst +
 	
 	#[inline]
 	fn set_noise_sigma(&mut self, noise_sigma: f64) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorMOG", self.as_raw_mut_BackgroundSubtractorMOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_BackgroundSubtractorMOG_setNoiseSigma_double(self.as_raw_mut_BackgroundSubtractorMOG(), noise_sigma, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_BackgroundSubtractorMOG_setNoiseSigma_double(self.as_raw_mut_BackgroundSubtractorMOG(), noise_sigma, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -784,10 +1200,11 @@ pub trait SyntheticSequenceGeneratorTrait: core::AlgorithmTrait + crate::bgsegm:
 	/// * gtMask: Output ground-truth (reference) segmentation mask object/background.
 	#[inline]
 	fn get_next_frame(&mut self, frame: &mut dyn core::ToOutputArray, gt_mask: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("SyntheticSequenceGenerator", self.as_raw_mut_SyntheticSequenceGenerator())?;
 		output_array_arg!(frame);
 		output_array_arg!(gt_mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_SyntheticSequenceGenerator_getNextFrame_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_SyntheticSequenceGenerator(), frame.as_raw__OutputArray(), gt_mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_SyntheticSequenceGenerator_getNextFrame_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_SyntheticSequenceGenerator(), frame.as_raw__OutputArray(), gt_mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -808,7 +1225,10 @@ opencv_type_boxed! { SyntheticSequenceGenerator }
 impl Drop for SyntheticSequenceGenerator {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SyntheticSequenceGenerator_delete(instance: *mut c_void); }
-		unsafe { cv_SyntheticSequenceGenerator_delete(self.as_raw_mut_SyntheticSequenceGenerator()) };
+		let ptr = self.as_raw_mut_SyntheticSequenceGenerator();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_SyntheticSequenceGenerator_delete(ptr) };
 	}
 }
 
@@ -845,7 +1265,7 @@ impl SyntheticSequenceGenerator {
 		input_array_arg!(background);
 		input_array_arg!(object);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bgsegm_SyntheticSequenceGenerator_SyntheticSequenceGenerator_const__InputArrayR_const__InputArrayR_double_double_double_double(background.as_raw__InputArray(), object.as_raw__InputArray(), amplitude, wavelength, wavespeed, objspeed, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bgsegm_SyntheticSequenceGenerator_SyntheticSequenceGenerator_const__InputArrayR_const__InputArrayR_double_double_double_double(background.as_raw__InputArray(), object.as_raw__InputArray(), amplitude, wavelength, wavespeed, objspeed, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::bgsegm::SyntheticSequenceGenerator::opencv_from_extern(ret) };
@@ -12,7 +12,7 @@
 //! The module provides biological visual systems models (human visual system and others). It also
 //! provides derivated objects that take advantage of those bio-inspired models.
 //! 
-//! @ref bioinspired_retina
+//! bioinspired_retina
 use crate::{mod_prelude::*, core, sys, types};
 pub mod prelude {
 	pub use { super::RetinaParametersTraitConst, super::RetinaParametersTrait, super::RetinaConst, super::Retina, super::RetinaFastToneMappingConst, super::RetinaFastToneMapping, super::TransientAreasSegmentationModuleConst, super::TransientAreasSegmentationModule };
@@ -55,9 +55,10 @@ pub trait RetinaConst: core::AlgorithmTraitConst {
 	/// information
 	#[inline]
 	fn write(&self, fs: &str) -> Result<()> {
+		crate::core::validity::check("Retina", self.as_raw_Retina())?;
 		extern_container_arg!(mut fs);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_Retina_write_const_String(self.as_raw_Retina(), fs.opencv_as_extern_mut(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_Retina_write_const_String(self.as_raw_Retina(), fs.opencv_as_extern_mut(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -71,8 +72,9 @@ pub trait RetinaConst: core::AlgorithmTraitConst {
 	/// ## Overloaded parameters
 	#[inline]
 	fn write_to_storage(&self, fs: &mut core::FileStorage) -> Result<()> {
+		crate::core::validity::check("Retina", self.as_raw_Retina())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_Retina_write_const_FileStorageR(self.as_raw_Retina(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_Retina_write_const_FileStorageR(self.as_raw_Retina(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -80,13 +82,14 @@ pub trait RetinaConst: core::AlgorithmTraitConst {
 	
 	/// Accessor of the motion channel of the retina (models peripheral vision).
 	/// ## See also
-	/// getMagno
+	/// `get_magno`
 	/// 
 	/// ## Overloaded parameters
 	#[inline]
 	fn get_magno_raw(&self) -> Result<core::Mat> {
+		crate::core::validity::check("Retina", self.as_raw_Retina())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_Retina_getMagnoRAW_const(self.as_raw_Retina(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_Retina_getMagnoRAW_const(self.as_raw_Retina(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -95,13 +98,14 @@ pub trait RetinaConst: core::AlgorithmTraitConst {
 	
 	/// Accessor of the details channel of the retina (models foveal vision).
 	/// ## See also
-	/// getParvo
+	/// `get_parvo`
 	/// 
 	/// ## Overloaded parameters
 	#[inline]
 	fn get_parvo_raw(&self) -> Result<core::Mat> {
+		crate::core::validity::check("Retina", self.as_raw_Retina())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_Retina_getParvoRAW_const(self.as_raw_Retina(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_Retina_getParvoRAW_const(self.as_raw_Retina(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -118,8 +122,9 @@ pub trait Retina: core::AlgorithmTrait + crate::bioinspired::RetinaConst {
 	/// the retina input buffer size
 	#[inline]
 	fn get_input_size(&mut self) -> Result<core::Size> {
+		crate::core::validity::check("Retina", self.as_raw_mut_Retina())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_Retina_getInputSize(self.as_raw_mut_Retina(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_Retina_getInputSize(self.as_raw_mut_Retina(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -131,8 +136,9 @@ pub trait Retina: core::AlgorithmTrait + crate::bioinspired::RetinaConst {
 	/// the retina output buffer size
 	#[inline]
 	fn get_output_size(&mut self) -> Result<core::Size> {
+		crate::core::validity::check("Retina", self.as_raw_mut_Retina())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_Retina_getOutputSize(self.as_raw_mut_Retina(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_Retina_getOutputSize(self.as_raw_mut_Retina(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -154,9 +160,10 @@ pub trait Retina: core::AlgorithmTrait + crate::bioinspired::RetinaConst {
 	/// * apply_default_setup_on_failure: true
 	#[inline]
 	fn setup_from_file(&mut self, retina_parameter_file: &str, apply_default_setup_on_failure: bool) -> Result<()> {
+		crate::core::validity::check("Retina", self.as_raw_mut_Retina())?;
 		extern_container_arg!(mut retina_parameter_file);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_Retina_setup_String_const_bool(self.as_raw_mut_Retina(), retina_parameter_file.opencv_as_extern_mut(), apply_default_setup_on_failure, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_Retina_setup_String_const_bool(self.as_raw_mut_Retina(), retina_parameter_file.opencv_as_extern_mut(), apply_default_setup_on_failure, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -182,8 +189,9 @@ pub trait Retina: core::AlgorithmTrait + crate::bioinspired::RetinaConst {
 	/// * apply_default_setup_on_failure: true
 	#[inline]
 	fn setup_from_storage(&mut self, fs: &mut core::FileStorage, apply_default_setup_on_failure: bool) -> Result<()> {
+		crate::core::validity::check("Retina", self.as_raw_mut_Retina())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_Retina_setup_FileStorageR_const_bool(self.as_raw_mut_Retina(), fs.as_raw_mut_FileStorage(), apply_default_setup_on_failure, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_Retina_setup_FileStorageR_const_bool(self.as_raw_mut_Retina(), fs.as_raw_mut_FileStorage(), apply_default_setup_on_failure, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -205,8 +213,9 @@ pub trait Retina: core::AlgorithmTrait + crate::bioinspired::RetinaConst {
 	/// * newParameters: a parameters structures updated with the new target configuration.
 	#[inline]
 	fn setup(&mut self, mut new_parameters: crate::bioinspired::RetinaParameters) -> Result<()> {
+		crate::core::validity::check("Retina", self.as_raw_mut_Retina())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_Retina_setup_RetinaParameters(self.as_raw_mut_Retina(), new_parameters.as_raw_mut_RetinaParameters(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_Retina_setup_RetinaParameters(self.as_raw_mut_Retina(), new_parameters.as_raw_mut_RetinaParameters(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -216,8 +225,9 @@ pub trait Retina: core::AlgorithmTrait + crate::bioinspired::RetinaConst {
 	/// the current parameters setup
 	#[inline]
 	fn get_parameters(&mut self) -> Result<crate::bioinspired::RetinaParameters> {
+		crate::core::validity::check("Retina", self.as_raw_mut_Retina())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_Retina_getParameters(self.as_raw_mut_Retina(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_Retina_getParameters(self.as_raw_mut_Retina(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::bioinspired::RetinaParameters::opencv_from_extern(ret) };
@@ -229,8 +239,9 @@ pub trait Retina: core::AlgorithmTrait + crate::bioinspired::RetinaConst {
 	/// a string which contains formated parameters information
 	#[inline]
 	fn print_setup(&mut self) -> Result<String> {
+		crate::core::validity::check("Retina", self.as_raw_mut_Retina())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_Retina_printSetup(self.as_raw_mut_Retina(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_Retina_printSetup(self.as_raw_mut_Retina(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -284,8 +295,9 @@ pub trait Retina: core::AlgorithmTrait + crate::bioinspired::RetinaConst {
 	/// * ganglion_cells_sensitivity: 0.7f
 	#[inline]
 	fn setup_op_land_ipl_parvo_channel(&mut self, color_mode: bool, normalise_output: bool, photoreceptors_local_adaptation_sensitivity: f32, photoreceptors_temporal_constant: f32, photoreceptors_spatial_constant: f32, horizontal_cells_gain: f32, hcells_temporal_constant: f32, hcells_spatial_constant: f32, ganglion_cells_sensitivity: f32) -> Result<()> {
+		crate::core::validity::check("Retina", self.as_raw_mut_Retina())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_Retina_setupOPLandIPLParvoChannel_const_bool_const_bool_const_float_const_float_const_float_const_float_const_float_const_float_const_float(self.as_raw_mut_Retina(), color_mode, normalise_output, photoreceptors_local_adaptation_sensitivity, photoreceptors_temporal_constant, photoreceptors_spatial_constant, horizontal_cells_gain, hcells_temporal_constant, hcells_spatial_constant, ganglion_cells_sensitivity, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_Retina_setupOPLandIPLParvoChannel_const_bool_const_bool_const_float_const_float_const_float_const_float_const_float_const_float_const_float(self.as_raw_mut_Retina(), color_mode, normalise_output, photoreceptors_local_adaptation_sensitivity, photoreceptors_temporal_constant, photoreceptors_spatial_constant, horizontal_cells_gain, hcells_temporal_constant, hcells_spatial_constant, ganglion_cells_sensitivity, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -328,8 +340,9 @@ pub trait Retina: core::AlgorithmTrait + crate::bioinspired::RetinaConst {
 	/// * local_adaptintegration_k: 7.f
 	#[inline]
 	fn setup_ipl_magno_channel(&mut self, normalise_output: bool, parasol_cells_beta: f32, parasol_cells_tau: f32, parasol_cells_k: f32, amacrin_cells_temporal_cut_frequency: f32, v0_compression_parameter: f32, local_adaptintegration_tau: f32, local_adaptintegration_k: f32) -> Result<()> {
+		crate::core::validity::check("Retina", self.as_raw_mut_Retina())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_Retina_setupIPLMagnoChannel_const_bool_const_float_const_float_const_float_const_float_const_float_const_float_const_float(self.as_raw_mut_Retina(), normalise_output, parasol_cells_beta, parasol_cells_tau, parasol_cells_k, amacrin_cells_temporal_cut_frequency, v0_compression_parameter, local_adaptintegration_tau, local_adaptintegration_k, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_Retina_setupIPLMagnoChannel_const_bool_const_float_const_float_const_float_const_float_const_float_const_float_const_float(self.as_raw_mut_Retina(), normalise_output, parasol_cells_beta, parasol_cells_tau, parasol_cells_k, amacrin_cells_temporal_cut_frequency, v0_compression_parameter, local_adaptintegration_tau, local_adaptintegration_k, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -344,9 +357,10 @@ pub trait Retina: core::AlgorithmTrait + crate::bioinspired::RetinaConst {
 	/// format (from 8bit to 16bits)
 	#[inline]
 	fn run(&mut self, input_image: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("Retina", self.as_raw_mut_Retina())?;
 		input_array_arg!(input_image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_Retina_run_const__InputArrayR(self.as_raw_mut_Retina(), input_image.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_Retina_run_const__InputArrayR(self.as_raw_mut_Retina(), input_image.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -370,10 +384,11 @@ pub trait Retina: core::AlgorithmTrait + crate::bioinspired::RetinaConst {
 	/// * outputToneMappedImage: the output 8bit/channel tone mapped image (CV_8U or CV_8UC3 format).
 	#[inline]
 	fn apply_fast_tone_mapping(&mut self, input_image: &dyn core::ToInputArray, output_tone_mapped_image: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("Retina", self.as_raw_mut_Retina())?;
 		input_array_arg!(input_image);
 		output_array_arg!(output_tone_mapped_image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_Retina_applyFastToneMapping_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_Retina(), input_image.as_raw__InputArray(), output_tone_mapped_image.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_Retina_applyFastToneMapping_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_Retina(), input_image.as_raw__InputArray(), output_tone_mapped_image.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -381,7 +396,7 @@ pub trait Retina: core::AlgorithmTrait + crate::bioinspired::RetinaConst {
 	
 	/// Accessor of the details channel of the retina (models foveal vision).
 	/// 
-	/// Warning, getParvoRAW methods return buffers that are not rescaled within range [0;255] while
+	/// Warning, getParvoRAW methods return buffers that are not rescaled within range \[0;255\] while
 	/// the non RAW method allows a normalized matrix to be retrieved.
 	/// 
 	/// ## Parameters
@@ -391,12 +406,13 @@ pub trait Retina: core::AlgorithmTrait + crate::bioinspired::RetinaConst {
 	/// B2, ...Bn), this output is the original retina filter model output, without any
 	/// quantification or rescaling.
 	/// ## See also
-	/// getParvoRAW
+	/// `get_parvo_raw`
 	#[inline]
 	fn get_parvo(&mut self, retina_output_parvo: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("Retina", self.as_raw_mut_Retina())?;
 		output_array_arg!(retina_output_parvo);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_Retina_getParvo_const__OutputArrayR(self.as_raw_mut_Retina(), retina_output_parvo.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_Retina_getParvo_const__OutputArrayR(self.as_raw_mut_Retina(), retina_output_parvo.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -404,12 +420,13 @@ pub trait Retina: core::AlgorithmTrait + crate::bioinspired::RetinaConst {
 	
 	/// Accessor of the details channel of the retina (models foveal vision).
 	/// ## See also
-	/// getParvo
+	/// `get_parvo`
 	#[inline]
 	fn get_parvo_raw_to(&mut self, retina_output_parvo: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("Retina", self.as_raw_mut_Retina())?;
 		output_array_arg!(retina_output_parvo);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_Retina_getParvoRAW_const__OutputArrayR(self.as_raw_mut_Retina(), retina_output_parvo.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_Retina_getParvoRAW_const__OutputArrayR(self.as_raw_mut_Retina(), retina_output_parvo.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -417,7 +434,7 @@ pub trait Retina: core::AlgorithmTrait + crate::bioinspired::RetinaConst {
 	
 	/// Accessor of the motion channel of the retina (models peripheral vision).
 	/// 
-	/// Warning, getMagnoRAW methods return buffers that are not rescaled within range [0;255] while
+	/// Warning, getMagnoRAW methods return buffers that are not rescaled within range \[0;255\] while
 	/// the non RAW method allows a normalized matrix to be retrieved.
 	/// ## Parameters
 	/// * retinaOutput_magno: the output buffer (reallocated if necessary), format can be :
@@ -425,12 +442,13 @@ pub trait Retina: core::AlgorithmTrait + crate::bioinspired::RetinaConst {
 	/// *   RAW methods actually return a 1D matrix (encoding is M1, M2,... Mn), this output is the
 	/// original retina filter model output, without any quantification or rescaling.
 	/// ## See also
-	/// getMagnoRAW
+	/// `get_magno_raw`
 	#[inline]
 	fn get_magno(&mut self, retina_output_magno: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("Retina", self.as_raw_mut_Retina())?;
 		output_array_arg!(retina_output_magno);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_Retina_getMagno_const__OutputArrayR(self.as_raw_mut_Retina(), retina_output_magno.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_Retina_getMagno_const__OutputArrayR(self.as_raw_mut_Retina(), retina_output_magno.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -438,12 +456,13 @@ pub trait Retina: core::AlgorithmTrait + crate::bioinspired::RetinaConst {
 	
 	/// Accessor of the motion channel of the retina (models peripheral vision).
 	/// ## See also
-	/// getMagno
+	/// `get_magno`
 	#[inline]
 	fn get_magno_raw_to(&mut self, retina_output_magno: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("Retina", self.as_raw_mut_Retina())?;
 		output_array_arg!(retina_output_magno);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_Retina_getMagnoRAW_const__OutputArrayR(self.as_raw_mut_Retina(), retina_output_magno.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_Retina_getMagnoRAW_const__OutputArrayR(self.as_raw_mut_Retina(), retina_output_magno.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -461,8 +480,9 @@ pub trait Retina: core::AlgorithmTrait + crate::bioinspired::RetinaConst {
 	/// * color_saturation_value: 4.0f
 	#[inline]
 	fn set_color_saturation(&mut self, saturate_colors: bool, color_saturation_value: f32) -> Result<()> {
+		crate::core::validity::check("Retina", self.as_raw_mut_Retina())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_Retina_setColorSaturation_const_bool_const_float(self.as_raw_mut_Retina(), saturate_colors, color_saturation_value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_Retina_setColorSaturation_const_bool_const_float(self.as_raw_mut_Retina(), saturate_colors, color_saturation_value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -474,8 +494,9 @@ pub trait Retina: core::AlgorithmTrait + crate::bioinspired::RetinaConst {
 	/// transition occuring just after this method call.
 	#[inline]
 	fn clear_buffers(&mut self) -> Result<()> {
+		crate::core::validity::check("Retina", self.as_raw_mut_Retina())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_Retina_clearBuffers(self.as_raw_mut_Retina(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_Retina_clearBuffers(self.as_raw_mut_Retina(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -488,8 +509,9 @@ pub trait Retina: core::AlgorithmTrait + crate::bioinspired::RetinaConst {
 	/// the Magnocellular output can be retrieved using the **getMagno** methods
 	#[inline]
 	fn activate_moving_contours_processing(&mut self, activate: bool) -> Result<()> {
+		crate::core::validity::check("Retina", self.as_raw_mut_Retina())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_Retina_activateMovingContoursProcessing_const_bool(self.as_raw_mut_Retina(), activate, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_Retina_activateMovingContoursProcessing_const_bool(self.as_raw_mut_Retina(), activate, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -503,8 +525,9 @@ pub trait Retina: core::AlgorithmTrait + crate::bioinspired::RetinaConst {
 	/// Retina::getParvo methods
 	#[inline]
 	fn activate_contours_processing(&mut self, activate: bool) -> Result<()> {
+		crate::core::validity::check("Retina", self.as_raw_mut_Retina())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_Retina_activateContoursProcessing_const_bool(self.as_raw_mut_Retina(), activate, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_Retina_activateContoursProcessing_const_bool(self.as_raw_mut_Retina(), activate, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -512,6 +535,182 @@ pub trait Retina: core::AlgorithmTrait + crate::bioinspired::RetinaConst {
 	
 }
 
+/// Builder for `Retina::setup_op_land_ipl_parvo_channel()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct Retina_SetupOpLandIplParvoChannelBuilder<'a, T: ?Sized + Retina> {
+	instance: &'a mut T,
+	color_mode: bool,
+	normalise_output: bool,
+	photoreceptors_local_adaptation_sensitivity: f32,
+	photoreceptors_temporal_constant: f32,
+	photoreceptors_spatial_constant: f32,
+	horizontal_cells_gain: f32,
+	hcells_temporal_constant: f32,
+	hcells_spatial_constant: f32,
+	ganglion_cells_sensitivity: f32,
+}
+
+impl<'a, T: ?Sized + Retina> Retina_SetupOpLandIplParvoChannelBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T) -> Self {
+		Self { instance, color_mode: true, normalise_output: true, photoreceptors_local_adaptation_sensitivity: 0.7, photoreceptors_temporal_constant: 0.5, photoreceptors_spatial_constant: 0.53, horizontal_cells_gain: 0., hcells_temporal_constant: 1., hcells_spatial_constant: 7., ganglion_cells_sensitivity: 0.7 }
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_color_mode(mut self, color_mode: bool) -> Self {
+		self.color_mode = color_mode;
+		self
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_normalise_output(mut self, normalise_output: bool) -> Self {
+		self.normalise_output = normalise_output;
+		self
+	}
+	
+	/// C++ default: `0.7f`
+	#[inline]
+	pub fn with_photoreceptors_local_adaptation_sensitivity(mut self, photoreceptors_local_adaptation_sensitivity: f32) -> Self {
+		self.photoreceptors_local_adaptation_sensitivity = photoreceptors_local_adaptation_sensitivity;
+		self
+	}
+	
+	/// C++ default: `0.5f`
+	#[inline]
+	pub fn with_photoreceptors_temporal_constant(mut self, photoreceptors_temporal_constant: f32) -> Self {
+		self.photoreceptors_temporal_constant = photoreceptors_temporal_constant;
+		self
+	}
+	
+	/// C++ default: `0.53f`
+	#[inline]
+	pub fn with_photoreceptors_spatial_constant(mut self, photoreceptors_spatial_constant: f32) -> Self {
+		self.photoreceptors_spatial_constant = photoreceptors_spatial_constant;
+		self
+	}
+	
+	/// C++ default: `0.f`
+	#[inline]
+	pub fn with_horizontal_cells_gain(mut self, horizontal_cells_gain: f32) -> Self {
+		self.horizontal_cells_gain = horizontal_cells_gain;
+		self
+	}
+	
+	/// C++ default: `1.f`
+	#[inline]
+	pub fn with_hcells_temporal_constant(mut self, hcells_temporal_constant: f32) -> Self {
+		self.hcells_temporal_constant = hcells_temporal_constant;
+		self
+	}
+	
+	/// C++ default: `7.f`
+	#[inline]
+	pub fn with_hcells_spatial_constant(mut self, hcells_spatial_constant: f32) -> Self {
+		self.hcells_spatial_constant = hcells_spatial_constant;
+		self
+	}
+	
+	/// C++ default: `0.7f`
+	#[inline]
+	pub fn with_ganglion_cells_sensitivity(mut self, ganglion_cells_sensitivity: f32) -> Self {
+		self.ganglion_cells_sensitivity = ganglion_cells_sensitivity;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		Retina::setup_op_land_ipl_parvo_channel(self.instance, self.color_mode, self.normalise_output, self.photoreceptors_local_adaptation_sensitivity, self.photoreceptors_temporal_constant, self.photoreceptors_spatial_constant, self.horizontal_cells_gain, self.hcells_temporal_constant, self.hcells_spatial_constant, self.ganglion_cells_sensitivity)
+	}
+}
+
+/// Builder for `Retina::setup_ipl_magno_channel()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct Retina_SetupIplMagnoChannelBuilder<'a, T: ?Sized + Retina> {
+	instance: &'a mut T,
+	normalise_output: bool,
+	parasol_cells_beta: f32,
+	parasol_cells_tau: f32,
+	parasol_cells_k: f32,
+	amacrin_cells_temporal_cut_frequency: f32,
+	v0_compression_parameter: f32,
+	local_adaptintegration_tau: f32,
+	local_adaptintegration_k: f32,
+}
+
+impl<'a, T: ?Sized + Retina> Retina_SetupIplMagnoChannelBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T) -> Self {
+		Self { instance, normalise_output: true, parasol_cells_beta: 0., parasol_cells_tau: 0., parasol_cells_k: 7., amacrin_cells_temporal_cut_frequency: 1.2, v0_compression_parameter: 0.95, local_adaptintegration_tau: 0., local_adaptintegration_k: 7. }
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_normalise_output(mut self, normalise_output: bool) -> Self {
+		self.normalise_output = normalise_output;
+		self
+	}
+	
+	/// C++ default: `0.f`
+	#[inline]
+	pub fn with_parasol_cells_beta(mut self, parasol_cells_beta: f32) -> Self {
+		self.parasol_cells_beta = parasol_cells_beta;
+		self
+	}
+	
+	/// C++ default: `0.f`
+	#[inline]
+	pub fn with_parasol_cells_tau(mut self, parasol_cells_tau: f32) -> Self {
+		self.parasol_cells_tau = parasol_cells_tau;
+		self
+	}
+	
+	/// C++ default: `7.f`
+	#[inline]
+	pub fn with_parasol_cells_k(mut self, parasol_cells_k: f32) -> Self {
+		self.parasol_cells_k = parasol_cells_k;
+		self
+	}
+	
+	/// C++ default: `1.2f`
+	#[inline]
+	pub fn with_amacrin_cells_temporal_cut_frequency(mut self, amacrin_cells_temporal_cut_frequency: f32) -> Self {
+		self.amacrin_cells_temporal_cut_frequency = amacrin_cells_temporal_cut_frequency;
+		self
+	}
+	
+	/// C++ default: `0.95f`
+	#[inline]
+	pub fn with_v0_compression_parameter(mut self, v0_compression_parameter: f32) -> Self {
+		self.v0_compression_parameter = v0_compression_parameter;
+		self
+	}
+	
+	/// C++ default: `0.f`
+	#[inline]
+	pub fn with_local_adaptintegration_tau(mut self, local_adaptintegration_tau: f32) -> Self {
+		self.local_adaptintegration_tau = local_adaptintegration_tau;
+		self
+	}
+	
+	/// C++ default: `7.f`
+	#[inline]
+	pub fn with_local_adaptintegration_k(mut self, local_adaptintegration_k: f32) -> Self {
+		self.local_adaptintegration_k = local_adaptintegration_k;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		Retina::setup_ipl_magno_channel(self.instance, self.normalise_output, self.parasol_cells_beta, self.parasol_cells_tau, self.parasol_cells_k, self.amacrin_cells_temporal_cut_frequency, self.v0_compression_parameter, self.local_adaptintegration_tau, self.local_adaptintegration_k)
+	}
+}
+
 impl dyn Retina + '_ {
 	/// Constructors from standardized interfaces : retreive a smart pointer to a Retina instance
 	/// 
@@ -534,7 +733,7 @@ impl dyn Retina + '_ {
 	#[inline]
 	pub fn create(input_size: core::Size) -> Result<core::Ptr<dyn crate::bioinspired::Retina>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_Retina_create_Size(input_size.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_Retina_create_Size(input_size.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::bioinspired::Retina>::opencv_from_extern(ret) };
@@ -566,7 +765,7 @@ impl dyn Retina + '_ {
 	#[inline]
 	pub fn create_ext(input_size: core::Size, color_mode: bool, color_sampling_method: i32, use_retina_log_sampling: bool, reduction_factor: f32, sampling_strength: f32) -> Result<core::Ptr<dyn crate::bioinspired::Retina>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_Retina_create_Size_const_bool_int_const_bool_const_float_const_float(input_size.opencv_as_extern(), color_mode, color_sampling_method, use_retina_log_sampling, reduction_factor, sampling_strength, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_Retina_create_Size_const_bool_int_const_bool_const_float_const_float(input_size.opencv_as_extern(), color_mode, color_sampling_method, use_retina_log_sampling, reduction_factor, sampling_strength, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::bioinspired::Retina>::opencv_from_extern(ret) };
@@ -574,6 +773,52 @@ impl dyn Retina + '_ {
 	}
 	
 }
+
+/// Builder for `<dyn Retina>::create_ext()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct Retina_CreateExtBuilder {
+	input_size: core::Size,
+	color_mode: bool,
+	color_sampling_method: i32,
+	use_retina_log_sampling: bool,
+	reduction_factor: f32,
+	sampling_strength: f32,
+}
+
+impl Retina_CreateExtBuilder {
+	#[inline]
+	pub fn new(input_size: core::Size, color_mode: bool, color_sampling_method: i32) -> Self {
+		Self { input_size, color_mode, color_sampling_method, use_retina_log_sampling: false, reduction_factor: 1.0, sampling_strength: 10.0 }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_use_retina_log_sampling(mut self, use_retina_log_sampling: bool) -> Self {
+		self.use_retina_log_sampling = use_retina_log_sampling;
+		self
+	}
+	
+	/// C++ default: `1.0f`
+	#[inline]
+	pub fn with_reduction_factor(mut self, reduction_factor: f32) -> Self {
+		self.reduction_factor = reduction_factor;
+		self
+	}
+	
+	/// C++ default: `10.0f`
+	#[inline]
+	pub fn with_sampling_strength(mut self, sampling_strength: f32) -> Self {
+		self.sampling_strength = sampling_strength;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::bioinspired::Retina>> {
+		<dyn Retina>::create_ext(self.input_size, self.color_mode, self.color_sampling_method, self.use_retina_log_sampling, self.reduction_factor, self.sampling_strength)
+	}
+}
 ///  a wrapper class which allows the tone mapping algorithm of Meylan&al(2007) to be used with OpenCV.
 /// 
 /// This algorithm is already implemented in thre Retina class (retina::applyFastToneMapping) but used it does not require all the retina model to be allocated. This allows a light memory use for low memory devices (smartphones, etc.
@@ -614,10 +859,11 @@ pub trait RetinaFastToneMapping: core::AlgorithmTrait + crate::bioinspired::Reti
 	/// * outputToneMappedImage: the output tone mapped image
 	#[inline]
 	fn apply_fast_tone_mapping(&mut self, input_image: &dyn core::ToInputArray, output_tone_mapped_image: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("RetinaFastToneMapping", self.as_raw_mut_RetinaFastToneMapping())?;
 		input_array_arg!(input_image);
 		output_array_arg!(output_tone_mapped_image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_RetinaFastToneMapping_applyFastToneMapping_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_RetinaFastToneMapping(), input_image.as_raw__InputArray(), output_tone_mapped_image.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_RetinaFastToneMapping_applyFastToneMapping_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_RetinaFastToneMapping(), input_image.as_raw__InputArray(), output_tone_mapped_image.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -637,8 +883,9 @@ pub trait RetinaFastToneMapping: core::AlgorithmTrait + crate::bioinspired::Reti
 	/// * mean_luminance_modulator_k: 1.f
 	#[inline]
 	fn setup(&mut self, photoreceptors_neighborhood_radius: f32, ganglioncells_neighborhood_radius: f32, mean_luminance_modulator_k: f32) -> Result<()> {
+		crate::core::validity::check("RetinaFastToneMapping", self.as_raw_mut_RetinaFastToneMapping())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_RetinaFastToneMapping_setup_const_float_const_float_const_float(self.as_raw_mut_RetinaFastToneMapping(), photoreceptors_neighborhood_radius, ganglioncells_neighborhood_radius, mean_luminance_modulator_k, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_RetinaFastToneMapping_setup_const_float_const_float_const_float(self.as_raw_mut_RetinaFastToneMapping(), photoreceptors_neighborhood_radius, ganglioncells_neighborhood_radius, mean_luminance_modulator_k, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -646,11 +893,55 @@ pub trait RetinaFastToneMapping: core::AlgorithmTrait + crate::bioinspired::Reti
 	
 }
 
+/// Builder for `RetinaFastToneMapping::setup()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct RetinaFastToneMapping_SetupBuilder<'a, T: ?Sized + RetinaFastToneMapping> {
+	instance: &'a mut T,
+	photoreceptors_neighborhood_radius: f32,
+	ganglioncells_neighborhood_radius: f32,
+	mean_luminance_modulator_k: f32,
+}
+
+impl<'a, T: ?Sized + RetinaFastToneMapping> RetinaFastToneMapping_SetupBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T) -> Self {
+		Self { instance, photoreceptors_neighborhood_radius: 3., ganglioncells_neighborhood_radius: 1., mean_luminance_modulator_k: 1. }
+	}
+	
+	/// C++ default: `3.f`
+	#[inline]
+	pub fn with_photoreceptors_neighborhood_radius(mut self, photoreceptors_neighborhood_radius: f32) -> Self {
+		self.photoreceptors_neighborhood_radius = photoreceptors_neighborhood_radius;
+		self
+	}
+	
+	/// C++ default: `1.f`
+	#[inline]
+	pub fn with_ganglioncells_neighborhood_radius(mut self, ganglioncells_neighborhood_radius: f32) -> Self {
+		self.ganglioncells_neighborhood_radius = ganglioncells_neighborhood_radius;
+		self
+	}
+	
+	/// C++ default: `1.f`
+	#[inline]
+	pub fn with_mean_luminance_modulator_k(mut self, mean_luminance_modulator_k: f32) -> Self {
+		self.mean_luminance_modulator_k = mean_luminance_modulator_k;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		RetinaFastToneMapping::setup(self.instance, self.photoreceptors_neighborhood_radius, self.ganglioncells_neighborhood_radius, self.mean_luminance_modulator_k)
+	}
+}
+
 impl dyn RetinaFastToneMapping + '_ {
 	#[inline]
 	pub fn create(input_size: core::Size) -> Result<core::Ptr<dyn crate::bioinspired::RetinaFastToneMapping>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_RetinaFastToneMapping_create_Size(input_size.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_RetinaFastToneMapping_create_Size(input_size.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::bioinspired::RetinaFastToneMapping>::opencv_from_extern(ret) };
@@ -723,20 +1014,38 @@ pub trait RetinaParametersTraitConst {
 
 	#[inline]
 	fn op_land_ipl_parvo(&self) -> crate::bioinspired::RetinaParameters_OPLandIplParvoParameters {
+		crate::core::validity::debug_check("RetinaParameters", self.as_raw_RetinaParameters());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_RetinaParameters_getPropOPLandIplParvo_const(self.as_raw_RetinaParameters(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_RetinaParameters_getPropOPLandIplParvo_const(self.as_raw_RetinaParameters(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [op_land_ipl_parvo](RetinaParametersTraitConst::op_land_ipl_parvo), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_op_land_ipl_parvo(&self) -> Result<crate::bioinspired::RetinaParameters_OPLandIplParvoParameters> {
+		crate::core::validity::check("RetinaParameters", self.as_raw_RetinaParameters())?;
+		Ok(RetinaParametersTraitConst::op_land_ipl_parvo(self))
+	}
+	
 	#[inline]
 	fn ipl_magno(&self) -> crate::bioinspired::RetinaParameters_IplMagnoParameters {
+		crate::core::validity::debug_check("RetinaParameters", self.as_raw_RetinaParameters());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_RetinaParameters_getPropIplMagno_const(self.as_raw_RetinaParameters(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_RetinaParameters_getPropIplMagno_const(self.as_raw_RetinaParameters(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [ipl_magno](RetinaParametersTraitConst::ipl_magno), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_ipl_magno(&self) -> Result<crate::bioinspired::RetinaParameters_IplMagnoParameters> {
+		crate::core::validity::check("RetinaParameters", self.as_raw_RetinaParameters())?;
+		Ok(RetinaParametersTraitConst::ipl_magno(self))
+	}
+	
 }
 
 pub trait RetinaParametersTrait: crate::bioinspired::RetinaParametersTraitConst {
@@ -744,13 +1053,15 @@ pub trait RetinaParametersTrait: crate::bioinspired::RetinaParametersTraitConst
 
 	#[inline]
 	fn set_op_land_ipl_parvo(&mut self, val: crate::bioinspired::RetinaParameters_OPLandIplParvoParameters) {
-		let ret = unsafe { sys::cv_bioinspired_RetinaParameters_setPropOPLandIplParvo_OPLandIplParvoParameters(self.as_raw_mut_RetinaParameters(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("RetinaParameters", self.as_raw_mut_RetinaParameters());
+		let ret = unsafe { ffi_call!(cv_bioinspired_RetinaParameters_setPropOPLandIplParvo_OPLandIplParvoParameters(self.as_raw_mut_RetinaParameters(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_ipl_magno(&mut self, val: crate::bioinspired::RetinaParameters_IplMagnoParameters) {
-		let ret = unsafe { sys::cv_bioinspired_RetinaParameters_setPropIplMagno_IplMagnoParameters(self.as_raw_mut_RetinaParameters(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("RetinaParameters", self.as_raw_mut_RetinaParameters());
+		let ret = unsafe { ffi_call!(cv_bioinspired_RetinaParameters_setPropIplMagno_IplMagnoParameters(self.as_raw_mut_RetinaParameters(), val.opencv_as_extern())) };
 		ret
 	}
 	
@@ -825,7 +1136,10 @@ opencv_type_boxed! { RetinaParameters }
 impl Drop for RetinaParameters {
 	fn drop(&mut self) {
 		extern "C" { fn cv_RetinaParameters_delete(instance: *mut c_void); }
-		unsafe { cv_RetinaParameters_delete(self.as_raw_mut_RetinaParameters()) };
+		let ptr = self.as_raw_mut_RetinaParameters();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_RetinaParameters_delete(ptr) };
 	}
 }
 
@@ -862,7 +1176,7 @@ impl RetinaParameters_IplMagnoParameters {
 	#[inline]
 	pub fn default() -> Result<crate::bioinspired::RetinaParameters_IplMagnoParameters> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_RetinaParameters_IplMagnoParameters_IplMagnoParameters(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_RetinaParameters_IplMagnoParameters_IplMagnoParameters(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -891,7 +1205,7 @@ impl RetinaParameters_OPLandIplParvoParameters {
 	#[inline]
 	pub fn default() -> Result<crate::bioinspired::RetinaParameters_OPLandIplParvoParameters> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_RetinaParameters_OPLandIplParvoParameters_OPLandIplParvoParameters(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_RetinaParameters_OPLandIplParvoParameters_OPLandIplParvoParameters(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -923,7 +1237,7 @@ impl SegmentationParameters {
 	#[inline]
 	pub fn default() -> Result<crate::bioinspired::SegmentationParameters> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_SegmentationParameters_SegmentationParameters(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_SegmentationParameters_SegmentationParameters(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -951,9 +1265,10 @@ pub trait TransientAreasSegmentationModuleConst: core::AlgorithmTraitConst {
 	/// * fs: : the filename of the xml file that will be open and writen with formatted parameters information
 	#[inline]
 	fn write(&self, fs: &str) -> Result<()> {
+		crate::core::validity::check("TransientAreasSegmentationModule", self.as_raw_TransientAreasSegmentationModule())?;
 		extern_container_arg!(mut fs);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_TransientAreasSegmentationModule_write_const_String(self.as_raw_TransientAreasSegmentationModule(), fs.opencv_as_extern_mut(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_TransientAreasSegmentationModule_write_const_String(self.as_raw_TransientAreasSegmentationModule(), fs.opencv_as_extern_mut(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -964,8 +1279,9 @@ pub trait TransientAreasSegmentationModuleConst: core::AlgorithmTraitConst {
 	/// * fs: : a cv::Filestorage object ready to be filled
 	#[inline]
 	fn write_to_storage(&self, fs: &mut core::FileStorage) -> Result<()> {
+		crate::core::validity::check("TransientAreasSegmentationModule", self.as_raw_TransientAreasSegmentationModule())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_TransientAreasSegmentationModule_write_const_FileStorageR(self.as_raw_TransientAreasSegmentationModule(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_TransientAreasSegmentationModule_write_const_FileStorageR(self.as_raw_TransientAreasSegmentationModule(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -979,8 +1295,9 @@ pub trait TransientAreasSegmentationModule: core::AlgorithmTrait + crate::bioins
 	/// return the sze of the manage input and output images
 	#[inline]
 	fn get_size(&mut self) -> Result<core::Size> {
+		crate::core::validity::check("TransientAreasSegmentationModule", self.as_raw_mut_TransientAreasSegmentationModule())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_TransientAreasSegmentationModule_getSize(self.as_raw_mut_TransientAreasSegmentationModule(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_TransientAreasSegmentationModule_getSize(self.as_raw_mut_TransientAreasSegmentationModule(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -999,9 +1316,10 @@ pub trait TransientAreasSegmentationModule: core::AlgorithmTrait + crate::bioins
 	/// * apply_default_setup_on_failure: true
 	#[inline]
 	fn setup_from_file(&mut self, segmentation_parameter_file: &str, apply_default_setup_on_failure: bool) -> Result<()> {
+		crate::core::validity::check("TransientAreasSegmentationModule", self.as_raw_mut_TransientAreasSegmentationModule())?;
 		extern_container_arg!(mut segmentation_parameter_file);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_TransientAreasSegmentationModule_setup_String_const_bool(self.as_raw_mut_TransientAreasSegmentationModule(), segmentation_parameter_file.opencv_as_extern_mut(), apply_default_setup_on_failure, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_TransientAreasSegmentationModule_setup_String_const_bool(self.as_raw_mut_TransientAreasSegmentationModule(), segmentation_parameter_file.opencv_as_extern_mut(), apply_default_setup_on_failure, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1019,8 +1337,9 @@ pub trait TransientAreasSegmentationModule: core::AlgorithmTrait + crate::bioins
 	/// * apply_default_setup_on_failure: true
 	#[inline]
 	fn setup_from_storage(&mut self, fs: &mut core::FileStorage, apply_default_setup_on_failure: bool) -> Result<()> {
+		crate::core::validity::check("TransientAreasSegmentationModule", self.as_raw_mut_TransientAreasSegmentationModule())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_TransientAreasSegmentationModule_setup_FileStorageR_const_bool(self.as_raw_mut_TransientAreasSegmentationModule(), fs.as_raw_mut_FileStorage(), apply_default_setup_on_failure, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_TransientAreasSegmentationModule_setup_FileStorageR_const_bool(self.as_raw_mut_TransientAreasSegmentationModule(), fs.as_raw_mut_FileStorage(), apply_default_setup_on_failure, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1034,8 +1353,9 @@ pub trait TransientAreasSegmentationModule: core::AlgorithmTrait + crate::bioins
 	/// * newParameters: : a parameters structures updated with the new target configuration
 	#[inline]
 	fn setup(&mut self, new_parameters: crate::bioinspired::SegmentationParameters) -> Result<()> {
+		crate::core::validity::check("TransientAreasSegmentationModule", self.as_raw_mut_TransientAreasSegmentationModule())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_TransientAreasSegmentationModule_setup_SegmentationParameters(self.as_raw_mut_TransientAreasSegmentationModule(), new_parameters.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_TransientAreasSegmentationModule_setup_SegmentationParameters(self.as_raw_mut_TransientAreasSegmentationModule(), new_parameters.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1044,8 +1364,9 @@ pub trait TransientAreasSegmentationModule: core::AlgorithmTrait + crate::bioins
 	/// return the current parameters setup
 	#[inline]
 	fn get_parameters(&mut self) -> Result<crate::bioinspired::SegmentationParameters> {
+		crate::core::validity::check("TransientAreasSegmentationModule", self.as_raw_mut_TransientAreasSegmentationModule())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_TransientAreasSegmentationModule_getParameters(self.as_raw_mut_TransientAreasSegmentationModule(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_TransientAreasSegmentationModule_getParameters(self.as_raw_mut_TransientAreasSegmentationModule(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1056,8 +1377,9 @@ pub trait TransientAreasSegmentationModule: core::AlgorithmTrait + crate::bioins
 	/// a string which contains formatted parameters information
 	#[inline]
 	fn print_setup(&mut self) -> Result<String> {
+		crate::core::validity::check("TransientAreasSegmentationModule", self.as_raw_mut_TransientAreasSegmentationModule())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_TransientAreasSegmentationModule_printSetup(self.as_raw_mut_TransientAreasSegmentationModule(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_TransientAreasSegmentationModule_printSetup(self.as_raw_mut_TransientAreasSegmentationModule(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -1073,9 +1395,10 @@ pub trait TransientAreasSegmentationModule: core::AlgorithmTrait + crate::bioins
 	/// * channel_index: 0
 	#[inline]
 	fn run(&mut self, input_to_segment: &dyn core::ToInputArray, channel_index: i32) -> Result<()> {
+		crate::core::validity::check("TransientAreasSegmentationModule", self.as_raw_mut_TransientAreasSegmentationModule())?;
 		input_array_arg!(input_to_segment);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_TransientAreasSegmentationModule_run_const__InputArrayR_const_int(self.as_raw_mut_TransientAreasSegmentationModule(), input_to_segment.as_raw__InputArray(), channel_index, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_TransientAreasSegmentationModule_run_const__InputArrayR_const_int(self.as_raw_mut_TransientAreasSegmentationModule(), input_to_segment.as_raw__InputArray(), channel_index, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1085,9 +1408,10 @@ pub trait TransientAreasSegmentationModule: core::AlgorithmTrait + crate::bioins
 	/// return the last segmentation result: a boolean picture which is resampled between 0 and 255 for a display purpose
 	#[inline]
 	fn get_segmentation_picture(&mut self, transient_areas: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("TransientAreasSegmentationModule", self.as_raw_mut_TransientAreasSegmentationModule())?;
 		output_array_arg!(transient_areas);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_TransientAreasSegmentationModule_getSegmentationPicture_const__OutputArrayR(self.as_raw_mut_TransientAreasSegmentationModule(), transient_areas.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_TransientAreasSegmentationModule_getSegmentationPicture_const__OutputArrayR(self.as_raw_mut_TransientAreasSegmentationModule(), transient_areas.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1096,8 +1420,9 @@ pub trait TransientAreasSegmentationModule: core::AlgorithmTrait + crate::bioins
 	/// cleans all the buffers of the instance
 	#[inline]
 	fn clear_all_buffers(&mut self) -> Result<()> {
+		crate::core::validity::check("TransientAreasSegmentationModule", self.as_raw_mut_TransientAreasSegmentationModule())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_TransientAreasSegmentationModule_clearAllBuffers(self.as_raw_mut_TransientAreasSegmentationModule(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_TransientAreasSegmentationModule_clearAllBuffers(self.as_raw_mut_TransientAreasSegmentationModule(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1112,7 +1437,7 @@ impl dyn TransientAreasSegmentationModule + '_ {
 	#[inline]
 	pub fn create(input_size: core::Size) -> Result<core::Ptr<dyn crate::bioinspired::TransientAreasSegmentationModule>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_bioinspired_TransientAreasSegmentationModule_create_Size(input_size.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_bioinspired_TransientAreasSegmentationModule_create_Size(input_size.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::bioinspired::TransientAreasSegmentationModule>::opencv_from_extern(ret) };
@@ -231,7 +231,7 @@
 //! 
 //!    ![block formula](https://latex.codecogs.com/png.latex?x%20%3D%20Xc%5F1%20%5C%5C%20y%20%3D%20Xc%5F2%20%5C%5C%20z%20%3D%20Xc%5F3)
 //! 
-//!    The pinhole projection coordinates of P is [a; b] where
+//!    The pinhole projection coordinates of P is \[a; b\] where
 //! 
 //!    ![block formula](https://latex.codecogs.com/png.latex?a%20%3D%20x%20%2F%20z%20%5C%20and%20%5C%20b%20%3D%20y%20%2F%20z%20%5C%5C%20r%5E2%20%3D%20a%5E2%20%2B%20b%5E2%20%5C%5C%20%5Ctheta%20%3D%20atan%28r%29)
 //! 
@@ -239,11 +239,11 @@
 //! 
 //!    ![block formula](https://latex.codecogs.com/png.latex?%5Ctheta%5Fd%20%3D%20%5Ctheta%20%281%20%2B%20k%5F1%20%5Ctheta%5E2%20%2B%20k%5F2%20%5Ctheta%5E4%20%2B%20k%5F3%20%5Ctheta%5E6%20%2B%20k%5F4%20%5Ctheta%5E8%29)
 //! 
-//!    The distorted point coordinates are [x'; y'] where
+//!    The distorted point coordinates are \[x'; y'\] where
 //! 
 //!    ![block formula](https://latex.codecogs.com/png.latex?x%27%20%3D%20%28%5Ctheta%5Fd%20%2F%20r%29%20a%20%5C%5C%20y%27%20%3D%20%28%5Ctheta%5Fd%20%2F%20r%29%20b%20)
 //! 
-//!    Finally, conversion into pixel coordinates: The final pixel coordinates vector [u; v] where:
+//!    Finally, conversion into pixel coordinates: The final pixel coordinates vector \[u; v\] where:
 //! 
 //!    ![block formula](https://latex.codecogs.com/png.latex?u%20%3D%20f%5Fx%20%28x%27%20%2B%20%5Calpha%20y%27%29%20%2B%20c%5Fx%20%5C%5C%0A%20%20%20%20v%20%3D%20f%5Fy%20y%27%20%2B%20c%5Fy)
 //! 
@@ -366,10 +366,10 @@ pub const SOLVEPNP_IPPE: i32 = 6;
 /// This is a special case suitable for marker pose estimation.
 /// 
 /// 4 coplanar object points must be defined in the following order:
-///   - point 0: [-squareLength / 2,  squareLength / 2, 0]
-///   - point 1: [ squareLength / 2,  squareLength / 2, 0]
-///   - point 2: [ squareLength / 2, -squareLength / 2, 0]
-///   - point 3: [-squareLength / 2, -squareLength / 2, 0]
+///   - point 0: \[-squareLength / 2,  squareLength / 2, 0\]
+///   - point 1: \[ squareLength / 2,  squareLength / 2, 0\]
+///   - point 2: \[ squareLength / 2, -squareLength / 2, 0\]
+///   - point 3: \[-squareLength / 2, -squareLength / 2, 0\]
 pub const SOLVEPNP_IPPE_SQUARE: i32 = 7;
 /// Pose refinement using non-linear Levenberg-Marquardt minimization scheme [Madsen04](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_Madsen04) [Eade13](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_Eade13) 
 /// 
@@ -522,10 +522,10 @@ pub enum SolvePnPMethod {
 	/// This is a special case suitable for marker pose estimation.
 	/// 
 	/// 4 coplanar object points must be defined in the following order:
-	///   - point 0: [-squareLength / 2,  squareLength / 2, 0]
-	///   - point 1: [ squareLength / 2,  squareLength / 2, 0]
-	///   - point 2: [ squareLength / 2, -squareLength / 2, 0]
-	///   - point 3: [-squareLength / 2, -squareLength / 2, 0]
+	///   - point 0: \[-squareLength / 2,  squareLength / 2, 0\]
+	///   - point 1: \[ squareLength / 2,  squareLength / 2, 0\]
+	///   - point 2: \[ squareLength / 2, -squareLength / 2, 0\]
+	///   - point 3: \[-squareLength / 2, -squareLength / 2, 0\]
 	SOLVEPNP_IPPE_SQUARE = 7,
 	/// SQPnP: A Consistently Fast and Globally OptimalSolution to the Perspective-n-Point Problem [Terzakis2020SQPnP](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_Terzakis2020SQPnP)
 	SOLVEPNP_SQPNP = 8,
@@ -579,12 +579,64 @@ pub fn rq_decomp3x3(src: &dyn core::ToInputArray, mtx_r: &mut dyn core::ToOutput
 	output_array_arg!(qy);
 	output_array_arg!(qz);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_RQDecomp3x3_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(src.as_raw__InputArray(), mtx_r.as_raw__OutputArray(), mtx_q.as_raw__OutputArray(), qx.as_raw__OutputArray(), qy.as_raw__OutputArray(), qz.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_RQDecomp3x3_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(src.as_raw__InputArray(), mtx_r.as_raw__OutputArray(), mtx_q.as_raw__OutputArray(), qx.as_raw__OutputArray(), qy.as_raw__OutputArray(), qz.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `rq_decomp3x3()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct RqDecomp3x3Builder<'a> {
+	src: &'a dyn core::ToInputArray,
+	mtx_r: &'a mut dyn core::ToOutputArray,
+	mtx_q: &'a mut dyn core::ToOutputArray,
+	qx: Option<&'a mut dyn core::ToOutputArray>,
+	qy: Option<&'a mut dyn core::ToOutputArray>,
+	qz: Option<&'a mut dyn core::ToOutputArray>,
+}
+
+impl<'a> RqDecomp3x3Builder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, mtx_r: &'a mut dyn core::ToOutputArray, mtx_q: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { src, mtx_r, mtx_q, qx: None, qy: None, qz: None }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_qx(mut self, qx: &'a mut dyn core::ToOutputArray) -> Self {
+		self.qx = Some(qx);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_qy(mut self, qy: &'a mut dyn core::ToOutputArray) -> Self {
+		self.qy = Some(qy);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_qz(mut self, qz: &'a mut dyn core::ToOutputArray) -> Self {
+		self.qz = Some(qz);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Vec3d> {
+		let mut qx_no_array = core::no_array();
+		let qx: &mut dyn core::ToOutputArray = match self.qx { Some(qx) => qx, None => &mut qx_no_array };
+		let mut qy_no_array = core::no_array();
+		let qy: &mut dyn core::ToOutputArray = match self.qy { Some(qy) => qy, None => &mut qy_no_array };
+		let mut qz_no_array = core::no_array();
+		let qz: &mut dyn core::ToOutputArray = match self.qz { Some(qz) => qz, None => &mut qz_no_array };
+		rq_decomp3x3(self.src, self.mtx_r, self.mtx_q, qx, qy, qz)
+	}
+}
+
 /// Converts a rotation matrix to a rotation vector or vice versa.
 /// 
 /// ## Parameters
@@ -601,7 +653,7 @@ pub fn rq_decomp3x3(src: &dyn core::ToInputArray, mtx_r: &mut dyn core::ToOutput
 /// 
 /// A rotation vector is a convenient and most compact representation of a rotation matrix (since any
 /// rotation matrix has just 3 degrees of freedom). The representation is used in the global 3D geometry
-/// optimization procedures like @ref calibrateCamera, @ref stereoCalibrate, or @ref solvePnP .
+/// optimization procedures like [calibrate_camera](crate::calib3d::calibrate_camera), [stereo_calibrate](crate::calib3d::stereo_calibrate), or [solve_pnp](crate::calib3d::solve_pnp) .
 /// 
 /// 
 /// Note: More information about the computation of the derivative of a 3D rotation matrix with respect to its exponential coordinate
@@ -622,7 +674,7 @@ pub fn rodrigues(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 	output_array_arg!(dst);
 	output_array_arg!(jacobian);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_Rodrigues_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), jacobian.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_Rodrigues_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), jacobian.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -641,19 +693,19 @@ pub fn rodrigues(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 /// * objectPoints: Vector of vectors of calibration pattern points in the calibration pattern
 /// coordinate space. See #calibrateCamera for details. If the method of releasing object to be used,
 /// the identical calibration board must be used in each view and it must be fully visible, and all
-/// objectPoints[i] must be the same and all points should be roughly close to a plane. **The calibration
+/// objectPoints\[i\] must be the same and all points should be roughly close to a plane. **The calibration
 /// target has to be rigid, or at least static if the camera (rather than the calibration target) is
 /// shifted for grabbing images.**
 /// * imagePoints: Vector of vectors of the projections of calibration pattern points. See
 /// #calibrateCamera for details.
 /// * imageSize: Size of the image used only to initialize the intrinsic camera matrix.
-/// * iFixedPoint: The index of the 3D object point in objectPoints[0] to be fixed. It also acts as
+/// * iFixedPoint: The index of the 3D object point in objectPoints\[0\] to be fixed. It also acts as
 /// a switch for calibration method selection. If object-releasing method to be used, pass in the
-/// parameter in the range of [1, objectPoints[0].size()-2], otherwise a value out of this range will
+/// parameter in the range of \[1, objectPoints\[0\].size()-2\], otherwise a value out of this range will
 /// make standard calibration method selected. Usually the top-right corner point of the calibration
 /// board grid is recommended to be fixed when object-releasing method being utilized. According to
-/// \cite strobl2011iccv, two other points are also fixed. In this implementation, objectPoints[0].front
-/// and objectPoints[0].back.z are used. With object-releasing method, accurate rvecs, tvecs and
+/// \cite strobl2011iccv, two other points are also fixed. In this implementation, objectPoints\[0\].front
+/// and objectPoints\[0\].back.z are used. With object-releasing method, accurate rvecs, tvecs and
 /// newObjPoints are only possible if coordinates of these three fixed points are accurate enough.
 /// * cameraMatrix: Output 3x3 floating-point camera matrix. See #calibrateCamera for details.
 /// * distCoeffs: Output vector of distortion coefficients. See #calibrateCamera for details.
@@ -669,7 +721,7 @@ pub fn rodrigues(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 /// * stdDeviationsExtrinsics: Output vector of standard deviations estimated for extrinsic parameters.
 /// See #calibrateCamera for details.
 /// * stdDeviationsObjPoints: Output vector of standard deviations estimated for refined coordinates
-/// of calibration pattern points. It has the same size and order as objectPoints[0] vector. This
+/// of calibration pattern points. It has the same size and order as objectPoints\[0\] vector. This
 /// parameter is ignored with standard calibration method.
 /// * perViewErrors: Output vector of the RMS re-projection error estimated for each pattern view.
 /// * flags: Different flags that may be zero or a combination of some predefined values. See
@@ -685,7 +737,7 @@ pub fn rodrigues(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 /// views. The algorithm is based on [Zhang2000](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_Zhang2000), [BouguetMCT](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_BouguetMCT) and [strobl2011iccv](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_strobl2011iccv). See
 /// #calibrateCamera for other detailed explanations.
 /// ## See also
-/// calibrateCamera, findChessboardCorners, solvePnP, initCameraMatrix2D, stereoCalibrate, undistort
+/// [calibrate_camera](crate::calib3d::calibrate_camera), [find_chessboard_corners](crate::calib3d::find_chessboard_corners), [solve_pnp](crate::calib3d::solve_pnp), [init_camera_matrix_2d](crate::calib3d::init_camera_matrix_2d), [stereo_calibrate](crate::calib3d::stereo_calibrate), [undistort](crate::calib3d::undistort)
 /// 
 /// ## C++ default parameters
 /// * flags: 0
@@ -704,12 +756,53 @@ pub fn calibrate_camera_ro_extended(object_points: &dyn core::ToInputArray, imag
 	output_array_arg!(std_deviations_obj_points);
 	output_array_arg!(per_view_errors);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_calibrateCameraRO_const__InputArrayR_const__InputArrayR_Size_int_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int_TermCriteria(object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), image_size.opencv_as_extern(), i_fixed_point, camera_matrix.as_raw__InputOutputArray(), dist_coeffs.as_raw__InputOutputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), new_obj_points.as_raw__OutputArray(), std_deviations_intrinsics.as_raw__OutputArray(), std_deviations_extrinsics.as_raw__OutputArray(), std_deviations_obj_points.as_raw__OutputArray(), per_view_errors.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_calibrateCameraRO_const__InputArrayR_const__InputArrayR_Size_int_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int_TermCriteria(object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), image_size.opencv_as_extern(), i_fixed_point, camera_matrix.as_raw__InputOutputArray(), dist_coeffs.as_raw__InputOutputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), new_obj_points.as_raw__OutputArray(), std_deviations_intrinsics.as_raw__OutputArray(), std_deviations_extrinsics.as_raw__OutputArray(), std_deviations_obj_points.as_raw__OutputArray(), per_view_errors.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `calibrate_camera_ro_extended()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CalibrateCameraRoExtendedBuilder<'a> {
+	object_points: &'a dyn core::ToInputArray,
+	image_points: &'a dyn core::ToInputArray,
+	image_size: core::Size,
+	i_fixed_point: i32,
+	camera_matrix: &'a mut dyn core::ToInputOutputArray,
+	dist_coeffs: &'a mut dyn core::ToInputOutputArray,
+	rvecs: &'a mut dyn core::ToOutputArray,
+	tvecs: &'a mut dyn core::ToOutputArray,
+	new_obj_points: &'a mut dyn core::ToOutputArray,
+	std_deviations_intrinsics: &'a mut dyn core::ToOutputArray,
+	std_deviations_extrinsics: &'a mut dyn core::ToOutputArray,
+	std_deviations_obj_points: &'a mut dyn core::ToOutputArray,
+	per_view_errors: &'a mut dyn core::ToOutputArray,
+	flags: i32,
+	criteria: core::TermCriteria,
+}
+
+impl<'a> CalibrateCameraRoExtendedBuilder<'a> {
+	#[inline]
+	pub fn new(object_points: &'a dyn core::ToInputArray, image_points: &'a dyn core::ToInputArray, image_size: core::Size, i_fixed_point: i32, camera_matrix: &'a mut dyn core::ToInputOutputArray, dist_coeffs: &'a mut dyn core::ToInputOutputArray, rvecs: &'a mut dyn core::ToOutputArray, tvecs: &'a mut dyn core::ToOutputArray, new_obj_points: &'a mut dyn core::ToOutputArray, std_deviations_intrinsics: &'a mut dyn core::ToOutputArray, std_deviations_extrinsics: &'a mut dyn core::ToOutputArray, std_deviations_obj_points: &'a mut dyn core::ToOutputArray, per_view_errors: &'a mut dyn core::ToOutputArray, criteria: core::TermCriteria) -> Self {
+		Self { object_points, image_points, image_size, i_fixed_point, camera_matrix, dist_coeffs, rvecs, tvecs, new_obj_points, std_deviations_intrinsics, std_deviations_extrinsics, std_deviations_obj_points, per_view_errors, flags: 0, criteria }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_flags(mut self, flags: i32) -> Self {
+		self.flags = flags;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<f64> {
+		calibrate_camera_ro_extended(self.object_points, self.image_points, self.image_size, self.i_fixed_point, self.camera_matrix, self.dist_coeffs, self.rvecs, self.tvecs, self.new_obj_points, self.std_deviations_intrinsics, self.std_deviations_extrinsics, self.std_deviations_obj_points, self.per_view_errors, self.flags, self.criteria)
+	}
+}
+
 /// Finds the camera intrinsic and extrinsic parameters from several views of a calibration pattern.
 /// 
 /// This function is an extension of #calibrateCamera with the method of releasing object which was
@@ -723,19 +816,19 @@ pub fn calibrate_camera_ro_extended(object_points: &dyn core::ToInputArray, imag
 /// * objectPoints: Vector of vectors of calibration pattern points in the calibration pattern
 /// coordinate space. See #calibrateCamera for details. If the method of releasing object to be used,
 /// the identical calibration board must be used in each view and it must be fully visible, and all
-/// objectPoints[i] must be the same and all points should be roughly close to a plane. **The calibration
+/// objectPoints\[i\] must be the same and all points should be roughly close to a plane. **The calibration
 /// target has to be rigid, or at least static if the camera (rather than the calibration target) is
 /// shifted for grabbing images.**
 /// * imagePoints: Vector of vectors of the projections of calibration pattern points. See
 /// #calibrateCamera for details.
 /// * imageSize: Size of the image used only to initialize the intrinsic camera matrix.
-/// * iFixedPoint: The index of the 3D object point in objectPoints[0] to be fixed. It also acts as
+/// * iFixedPoint: The index of the 3D object point in objectPoints\[0\] to be fixed. It also acts as
 /// a switch for calibration method selection. If object-releasing method to be used, pass in the
-/// parameter in the range of [1, objectPoints[0].size()-2], otherwise a value out of this range will
+/// parameter in the range of \[1, objectPoints\[0\].size()-2\], otherwise a value out of this range will
 /// make standard calibration method selected. Usually the top-right corner point of the calibration
 /// board grid is recommended to be fixed when object-releasing method being utilized. According to
-/// \cite strobl2011iccv, two other points are also fixed. In this implementation, objectPoints[0].front
-/// and objectPoints[0].back.z are used. With object-releasing method, accurate rvecs, tvecs and
+/// \cite strobl2011iccv, two other points are also fixed. In this implementation, objectPoints\[0\].front
+/// and objectPoints\[0\].back.z are used. With object-releasing method, accurate rvecs, tvecs and
 /// newObjPoints are only possible if coordinates of these three fixed points are accurate enough.
 /// * cameraMatrix: Output 3x3 floating-point camera matrix. See #calibrateCamera for details.
 /// * distCoeffs: Output vector of distortion coefficients. See #calibrateCamera for details.
@@ -751,7 +844,7 @@ pub fn calibrate_camera_ro_extended(object_points: &dyn core::ToInputArray, imag
 /// * stdDeviationsExtrinsics: Output vector of standard deviations estimated for extrinsic parameters.
 /// See #calibrateCamera for details.
 /// * stdDeviationsObjPoints: Output vector of standard deviations estimated for refined coordinates
-/// of calibration pattern points. It has the same size and order as objectPoints[0] vector. This
+/// of calibration pattern points. It has the same size and order as objectPoints\[0\] vector. This
 /// parameter is ignored with standard calibration method.
 /// * perViewErrors: Output vector of the RMS re-projection error estimated for each pattern view.
 /// * flags: Different flags that may be zero or a combination of some predefined values. See
@@ -767,7 +860,7 @@ pub fn calibrate_camera_ro_extended(object_points: &dyn core::ToInputArray, imag
 /// views. The algorithm is based on [Zhang2000](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_Zhang2000), [BouguetMCT](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_BouguetMCT) and [strobl2011iccv](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_strobl2011iccv). See
 /// #calibrateCamera for other detailed explanations.
 /// ## See also
-/// calibrateCamera, findChessboardCorners, solvePnP, initCameraMatrix2D, stereoCalibrate, undistort
+/// [calibrate_camera](crate::calib3d::calibrate_camera), [find_chessboard_corners](crate::calib3d::find_chessboard_corners), [solve_pnp](crate::calib3d::solve_pnp), [init_camera_matrix_2d](crate::calib3d::init_camera_matrix_2d), [stereo_calibrate](crate::calib3d::stereo_calibrate), [undistort](crate::calib3d::undistort)
 /// 
 /// ## Overloaded parameters
 /// 
@@ -784,12 +877,49 @@ pub fn calibrate_camera_ro(object_points: &dyn core::ToInputArray, image_points:
 	output_array_arg!(tvecs);
 	output_array_arg!(new_obj_points);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_calibrateCameraRO_const__InputArrayR_const__InputArrayR_Size_int_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int_TermCriteria(object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), image_size.opencv_as_extern(), i_fixed_point, camera_matrix.as_raw__InputOutputArray(), dist_coeffs.as_raw__InputOutputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), new_obj_points.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_calibrateCameraRO_const__InputArrayR_const__InputArrayR_Size_int_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int_TermCriteria(object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), image_size.opencv_as_extern(), i_fixed_point, camera_matrix.as_raw__InputOutputArray(), dist_coeffs.as_raw__InputOutputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), new_obj_points.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `calibrate_camera_ro()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CalibrateCameraRoBuilder<'a> {
+	object_points: &'a dyn core::ToInputArray,
+	image_points: &'a dyn core::ToInputArray,
+	image_size: core::Size,
+	i_fixed_point: i32,
+	camera_matrix: &'a mut dyn core::ToInputOutputArray,
+	dist_coeffs: &'a mut dyn core::ToInputOutputArray,
+	rvecs: &'a mut dyn core::ToOutputArray,
+	tvecs: &'a mut dyn core::ToOutputArray,
+	new_obj_points: &'a mut dyn core::ToOutputArray,
+	flags: i32,
+	criteria: core::TermCriteria,
+}
+
+impl<'a> CalibrateCameraRoBuilder<'a> {
+	#[inline]
+	pub fn new(object_points: &'a dyn core::ToInputArray, image_points: &'a dyn core::ToInputArray, image_size: core::Size, i_fixed_point: i32, camera_matrix: &'a mut dyn core::ToInputOutputArray, dist_coeffs: &'a mut dyn core::ToInputOutputArray, rvecs: &'a mut dyn core::ToOutputArray, tvecs: &'a mut dyn core::ToOutputArray, new_obj_points: &'a mut dyn core::ToOutputArray, criteria: core::TermCriteria) -> Self {
+		Self { object_points, image_points, image_size, i_fixed_point, camera_matrix, dist_coeffs, rvecs, tvecs, new_obj_points, flags: 0, criteria }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_flags(mut self, flags: i32) -> Self {
+		self.flags = flags;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<f64> {
+		calibrate_camera_ro(self.object_points, self.image_points, self.image_size, self.i_fixed_point, self.camera_matrix, self.dist_coeffs, self.rvecs, self.tvecs, self.new_obj_points, self.flags, self.criteria)
+	}
+}
+
 /// Finds the camera intrinsic and extrinsic parameters from several views of a calibration
 /// pattern.
 /// 
@@ -805,17 +935,17 @@ pub fn calibrate_camera_ro(object_points: &dyn core::ToInputArray, image_points:
 /// together.
 /// * imagePoints: In the new interface it is a vector of vectors of the projections of calibration
 /// pattern points (e.g. std::vector<std::vector<cv::Vec2f>>). imagePoints.size() and
-/// objectPoints.size(), and imagePoints[i].size() and objectPoints[i].size() for each i, must be equal,
+/// objectPoints.size(), and imagePoints\[i\].size() and objectPoints\[i\].size() for each i, must be equal,
 /// respectively. In the old interface all the vectors of object points from different views are
 /// concatenated together.
 /// * imageSize: Size of the image used only to initialize the camera intrinsic matrix.
 /// * cameraMatrix: Input/output 3x3 floating-point camera intrinsic matrix
-/// ![inline formula](https://latex.codecogs.com/png.latex?%5Ccameramatrix%7BA%7D) . If @ref CALIB_USE_INTRINSIC_GUESS
-/// and/or @ref CALIB_FIX_ASPECT_RATIO, @ref CALIB_FIX_PRINCIPAL_POINT or @ref CALIB_FIX_FOCAL_LENGTH
+/// ![inline formula](https://latex.codecogs.com/png.latex?%5Ccameramatrix%7BA%7D) . If `CALIB_USE_INTRINSIC_GUESS`
+/// and/or `CALIB_FIX_ASPECT_RATIO`, `CALIB_FIX_PRINCIPAL_POINT` or `CALIB_FIX_FOCAL_LENGTH`
 /// are specified, some or all of fx, fy, cx, cy must be initialized before calling the function.
 /// * distCoeffs: Input/output vector of distortion coefficients
 /// ![inline formula](https://latex.codecogs.com/png.latex?%5Cdistcoeffs).
-/// * rvecs: Output vector of rotation vectors (@ref Rodrigues ) estimated for each pattern view
+/// * rvecs: Output vector of rotation vectors ([rodrigues](crate::calib3d::rodrigues) ) estimated for each pattern view
 /// (e.g. std::vector<cv::Mat>>). That is, each i-th rotation vector together with the corresponding
 /// i-th translation vector (see the next output parameter description) brings the calibration pattern
 /// from the object coordinate space (in which object points are specified) to the camera coordinate
@@ -833,39 +963,39 @@ pub fn calibrate_camera_ro(object_points: &dyn core::ToInputArray, image_points:
 /// the number of pattern views. ![inline formula](https://latex.codecogs.com/png.latex?R%5Fi%2C%20T%5Fi) are concatenated 1x3 vectors.
 /// * perViewErrors: Output vector of the RMS re-projection error estimated for each pattern view.
 /// * flags: Different flags that may be zero or a combination of the following values:
-/// *   @ref CALIB_USE_INTRINSIC_GUESS cameraMatrix contains valid initial values of
+/// *   `CALIB_USE_INTRINSIC_GUESS` cameraMatrix contains valid initial values of
 /// fx, fy, cx, cy that are optimized further. Otherwise, (cx, cy) is initially set to the image
 /// center ( imageSize is used), and focal distances are computed in a least-squares fashion.
 /// Note, that if intrinsic parameters are known, there is no need to use this function just to
-/// estimate extrinsic parameters. Use @ref solvePnP instead.
-/// *   @ref CALIB_FIX_PRINCIPAL_POINT The principal point is not changed during the global
+/// estimate extrinsic parameters. Use [solve_pnp](crate::calib3d::solve_pnp) instead.
+/// *   `CALIB_FIX_PRINCIPAL_POINT` The principal point is not changed during the global
 /// optimization. It stays at the center or at a different location specified when
-///  @ref CALIB_USE_INTRINSIC_GUESS is set too.
-/// *   @ref CALIB_FIX_ASPECT_RATIO The functions consider only fy as a free parameter. The
+///  `CALIB_USE_INTRINSIC_GUESS` is set too.
+/// *   `CALIB_FIX_ASPECT_RATIO` The functions consider only fy as a free parameter. The
 /// ratio fx/fy stays the same as in the input cameraMatrix . When
-///  @ref CALIB_USE_INTRINSIC_GUESS is not set, the actual input values of fx and fy are
+///  `CALIB_USE_INTRINSIC_GUESS` is not set, the actual input values of fx and fy are
 /// ignored, only their ratio is computed and used further.
-/// *   @ref CALIB_ZERO_TANGENT_DIST Tangential distortion coefficients ![inline formula](https://latex.codecogs.com/png.latex?%28p%5F1%2C%20p%5F2%29) are set
+/// *   `CALIB_ZERO_TANGENT_DIST` Tangential distortion coefficients ![inline formula](https://latex.codecogs.com/png.latex?%28p%5F1%2C%20p%5F2%29) are set
 /// to zeros and stay zero.
-/// *   @ref CALIB_FIX_FOCAL_LENGTH The focal length is not changed during the global optimization if
-///  @ref CALIB_USE_INTRINSIC_GUESS is set.
-/// *   @ref CALIB_FIX_K1,..., @ref CALIB_FIX_K6 The corresponding radial distortion
-/// coefficient is not changed during the optimization. If @ref CALIB_USE_INTRINSIC_GUESS is
+/// *   `CALIB_FIX_FOCAL_LENGTH` The focal length is not changed during the global optimization if
+///  `CALIB_USE_INTRINSIC_GUESS` is set.
+/// *   `CALIB_FIX_K1`,..., `CALIB_FIX_K6` The corresponding radial distortion
+/// coefficient is not changed during the optimization. If `CALIB_USE_INTRINSIC_GUESS` is
 /// set, the coefficient from the supplied distCoeffs matrix is used. Otherwise, it is set to 0.
-/// *   @ref CALIB_RATIONAL_MODEL Coefficients k4, k5, and k6 are enabled. To provide the
+/// *   `CALIB_RATIONAL_MODEL` Coefficients k4, k5, and k6 are enabled. To provide the
 /// backward compatibility, this extra flag should be explicitly specified to make the
 /// calibration function use the rational model and return 8 coefficients or more.
-/// *   @ref CALIB_THIN_PRISM_MODEL Coefficients s1, s2, s3 and s4 are enabled. To provide the
+/// *   `CALIB_THIN_PRISM_MODEL` Coefficients s1, s2, s3 and s4 are enabled. To provide the
 /// backward compatibility, this extra flag should be explicitly specified to make the
 /// calibration function use the thin prism model and return 12 coefficients or more.
-/// *   @ref CALIB_FIX_S1_S2_S3_S4 The thin prism distortion coefficients are not changed during
-/// the optimization. If @ref CALIB_USE_INTRINSIC_GUESS is set, the coefficient from the
+/// *   `CALIB_FIX_S1_S2_S3_S4` The thin prism distortion coefficients are not changed during
+/// the optimization. If `CALIB_USE_INTRINSIC_GUESS` is set, the coefficient from the
 /// supplied distCoeffs matrix is used. Otherwise, it is set to 0.
-/// *   @ref CALIB_TILTED_MODEL Coefficients tauX and tauY are enabled. To provide the
+/// *   `CALIB_TILTED_MODEL` Coefficients tauX and tauY are enabled. To provide the
 /// backward compatibility, this extra flag should be explicitly specified to make the
 /// calibration function use the tilted sensor model and return 14 coefficients.
-/// *   @ref CALIB_FIX_TAUX_TAUY The coefficients of the tilted sensor model are not changed during
-/// the optimization. If @ref CALIB_USE_INTRINSIC_GUESS is set, the coefficient from the
+/// *   `CALIB_FIX_TAUX_TAUY` The coefficients of the tilted sensor model are not changed during
+/// the optimization. If `CALIB_USE_INTRINSIC_GUESS` is set, the coefficient from the
 /// supplied distCoeffs matrix is used. Otherwise, it is set to 0.
 /// * criteria: Termination criteria for the iterative optimization algorithm.
 /// 
@@ -877,8 +1007,8 @@ pub fn calibrate_camera_ro(object_points: &dyn core::ToInputArray, image_points:
 /// points and their corresponding 2D projections in each view must be specified. That may be achieved
 /// by using an object with known geometry and easily detectable feature points. Such an object is
 /// called a calibration rig or calibration pattern, and OpenCV has built-in support for a chessboard as
-/// a calibration rig (see @ref findChessboardCorners). Currently, initialization of intrinsic
-/// parameters (when @ref CALIB_USE_INTRINSIC_GUESS is not set) is only implemented for planar calibration
+/// a calibration rig (see [find_chessboard_corners](crate::calib3d::find_chessboard_corners)). Currently, initialization of intrinsic
+/// parameters (when `CALIB_USE_INTRINSIC_GUESS` is not set) is only implemented for planar calibration
 /// patterns (where Z-coordinates of the object points must be all zeros). 3D calibration rigs can also
 /// be used as long as initial cameraMatrix is provided.
 /// 
@@ -889,23 +1019,23 @@ pub fn calibrate_camera_ro(object_points: &dyn core::ToInputArray, image_points:
 ///    zeros initially unless some of CALIB_FIX_K? are specified.
 /// 
 /// *   Estimate the initial camera pose as if the intrinsic parameters have been already known. This is
-///    done using @ref solvePnP .
+///    done using [solve_pnp](crate::calib3d::solve_pnp) .
 /// 
 /// *   Run the global Levenberg-Marquardt optimization algorithm to minimize the reprojection error,
 ///    that is, the total sum of squared distances between the observed feature points imagePoints and
 ///    the projected (using the current estimates for camera parameters and the poses) object points
-///    objectPoints. See @ref projectPoints for details.
+///    objectPoints. See [project_points](crate::calib3d::project_points) for details.
 /// 
 /// 
 /// Note:
-///    If you use a non-square (i.e. non-N-by-N) grid and @ref findChessboardCorners for calibration,
-///    and @ref calibrateCamera returns bad values (zero distortion coefficients, ![inline formula](https://latex.codecogs.com/png.latex?c%5Fx) and
+///    If you use a non-square (i.e. non-N-by-N) grid and [find_chessboard_corners](crate::calib3d::find_chessboard_corners) for calibration,
+///    and [calibrate_camera](crate::calib3d::calibrate_camera) returns bad values (zero distortion coefficients, ![inline formula](https://latex.codecogs.com/png.latex?c%5Fx) and
 ///    ![inline formula](https://latex.codecogs.com/png.latex?c%5Fy) very far from the image center, and/or large differences between ![inline formula](https://latex.codecogs.com/png.latex?f%5Fx) and
 ///    ![inline formula](https://latex.codecogs.com/png.latex?f%5Fy) (ratios of 10:1 or more)), then you are probably using patternSize=cvSize(rows,cols)
-///    instead of using patternSize=cvSize(cols,rows) in @ref findChessboardCorners.
+///    instead of using patternSize=cvSize(cols,rows) in [find_chessboard_corners](crate::calib3d::find_chessboard_corners).
 /// ## See also
-/// calibrateCameraRO, findChessboardCorners, solvePnP, initCameraMatrix2D, stereoCalibrate,
-///    undistort
+/// [calibrate_camera_ro](crate::calib3d::calibrate_camera_ro), [find_chessboard_corners](crate::calib3d::find_chessboard_corners), [solve_pnp](crate::calib3d::solve_pnp), [init_camera_matrix_2d](crate::calib3d::init_camera_matrix_2d), [stereo_calibrate](crate::calib3d::stereo_calibrate),
+///    [undistort](crate::calib3d::undistort)
 /// 
 /// ## C++ default parameters
 /// * flags: 0
@@ -922,12 +1052,50 @@ pub fn calibrate_camera_extended(object_points: &dyn core::ToInputArray, image_p
 	output_array_arg!(std_deviations_extrinsics);
 	output_array_arg!(per_view_errors);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_calibrateCamera_const__InputArrayR_const__InputArrayR_Size_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int_TermCriteria(object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), image_size.opencv_as_extern(), camera_matrix.as_raw__InputOutputArray(), dist_coeffs.as_raw__InputOutputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), std_deviations_intrinsics.as_raw__OutputArray(), std_deviations_extrinsics.as_raw__OutputArray(), per_view_errors.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_calibrateCamera_const__InputArrayR_const__InputArrayR_Size_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int_TermCriteria(object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), image_size.opencv_as_extern(), camera_matrix.as_raw__InputOutputArray(), dist_coeffs.as_raw__InputOutputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), std_deviations_intrinsics.as_raw__OutputArray(), std_deviations_extrinsics.as_raw__OutputArray(), per_view_errors.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `calibrate_camera_extended()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CalibrateCameraExtendedBuilder<'a> {
+	object_points: &'a dyn core::ToInputArray,
+	image_points: &'a dyn core::ToInputArray,
+	image_size: core::Size,
+	camera_matrix: &'a mut dyn core::ToInputOutputArray,
+	dist_coeffs: &'a mut dyn core::ToInputOutputArray,
+	rvecs: &'a mut dyn core::ToOutputArray,
+	tvecs: &'a mut dyn core::ToOutputArray,
+	std_deviations_intrinsics: &'a mut dyn core::ToOutputArray,
+	std_deviations_extrinsics: &'a mut dyn core::ToOutputArray,
+	per_view_errors: &'a mut dyn core::ToOutputArray,
+	flags: i32,
+	criteria: core::TermCriteria,
+}
+
+impl<'a> CalibrateCameraExtendedBuilder<'a> {
+	#[inline]
+	pub fn new(object_points: &'a dyn core::ToInputArray, image_points: &'a dyn core::ToInputArray, image_size: core::Size, camera_matrix: &'a mut dyn core::ToInputOutputArray, dist_coeffs: &'a mut dyn core::ToInputOutputArray, rvecs: &'a mut dyn core::ToOutputArray, tvecs: &'a mut dyn core::ToOutputArray, std_deviations_intrinsics: &'a mut dyn core::ToOutputArray, std_deviations_extrinsics: &'a mut dyn core::ToOutputArray, per_view_errors: &'a mut dyn core::ToOutputArray, criteria: core::TermCriteria) -> Self {
+		Self { object_points, image_points, image_size, camera_matrix, dist_coeffs, rvecs, tvecs, std_deviations_intrinsics, std_deviations_extrinsics, per_view_errors, flags: 0, criteria }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_flags(mut self, flags: i32) -> Self {
+		self.flags = flags;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<f64> {
+		calibrate_camera_extended(self.object_points, self.image_points, self.image_size, self.camera_matrix, self.dist_coeffs, self.rvecs, self.tvecs, self.std_deviations_intrinsics, self.std_deviations_extrinsics, self.per_view_errors, self.flags, self.criteria)
+	}
+}
+
 /// Finds the camera intrinsic and extrinsic parameters from several views of a calibration
 /// pattern.
 /// 
@@ -943,17 +1111,17 @@ pub fn calibrate_camera_extended(object_points: &dyn core::ToInputArray, image_p
 /// together.
 /// * imagePoints: In the new interface it is a vector of vectors of the projections of calibration
 /// pattern points (e.g. std::vector<std::vector<cv::Vec2f>>). imagePoints.size() and
-/// objectPoints.size(), and imagePoints[i].size() and objectPoints[i].size() for each i, must be equal,
+/// objectPoints.size(), and imagePoints\[i\].size() and objectPoints\[i\].size() for each i, must be equal,
 /// respectively. In the old interface all the vectors of object points from different views are
 /// concatenated together.
 /// * imageSize: Size of the image used only to initialize the camera intrinsic matrix.
 /// * cameraMatrix: Input/output 3x3 floating-point camera intrinsic matrix
-/// ![inline formula](https://latex.codecogs.com/png.latex?%5Ccameramatrix%7BA%7D) . If @ref CALIB_USE_INTRINSIC_GUESS
-/// and/or @ref CALIB_FIX_ASPECT_RATIO, @ref CALIB_FIX_PRINCIPAL_POINT or @ref CALIB_FIX_FOCAL_LENGTH
+/// ![inline formula](https://latex.codecogs.com/png.latex?%5Ccameramatrix%7BA%7D) . If `CALIB_USE_INTRINSIC_GUESS`
+/// and/or `CALIB_FIX_ASPECT_RATIO`, `CALIB_FIX_PRINCIPAL_POINT` or `CALIB_FIX_FOCAL_LENGTH`
 /// are specified, some or all of fx, fy, cx, cy must be initialized before calling the function.
 /// * distCoeffs: Input/output vector of distortion coefficients
 /// ![inline formula](https://latex.codecogs.com/png.latex?%5Cdistcoeffs).
-/// * rvecs: Output vector of rotation vectors (@ref Rodrigues ) estimated for each pattern view
+/// * rvecs: Output vector of rotation vectors ([rodrigues](crate::calib3d::rodrigues) ) estimated for each pattern view
 /// (e.g. std::vector<cv::Mat>>). That is, each i-th rotation vector together with the corresponding
 /// i-th translation vector (see the next output parameter description) brings the calibration pattern
 /// from the object coordinate space (in which object points are specified) to the camera coordinate
@@ -971,39 +1139,39 @@ pub fn calibrate_camera_extended(object_points: &dyn core::ToInputArray, image_p
 /// the number of pattern views. ![inline formula](https://latex.codecogs.com/png.latex?R%5Fi%2C%20T%5Fi) are concatenated 1x3 vectors.
 /// * perViewErrors: Output vector of the RMS re-projection error estimated for each pattern view.
 /// * flags: Different flags that may be zero or a combination of the following values:
-/// *   @ref CALIB_USE_INTRINSIC_GUESS cameraMatrix contains valid initial values of
+/// *   `CALIB_USE_INTRINSIC_GUESS` cameraMatrix contains valid initial values of
 /// fx, fy, cx, cy that are optimized further. Otherwise, (cx, cy) is initially set to the image
 /// center ( imageSize is used), and focal distances are computed in a least-squares fashion.
 /// Note, that if intrinsic parameters are known, there is no need to use this function just to
-/// estimate extrinsic parameters. Use @ref solvePnP instead.
-/// *   @ref CALIB_FIX_PRINCIPAL_POINT The principal point is not changed during the global
+/// estimate extrinsic parameters. Use [solve_pnp](crate::calib3d::solve_pnp) instead.
+/// *   `CALIB_FIX_PRINCIPAL_POINT` The principal point is not changed during the global
 /// optimization. It stays at the center or at a different location specified when
-///  @ref CALIB_USE_INTRINSIC_GUESS is set too.
-/// *   @ref CALIB_FIX_ASPECT_RATIO The functions consider only fy as a free parameter. The
+///  `CALIB_USE_INTRINSIC_GUESS` is set too.
+/// *   `CALIB_FIX_ASPECT_RATIO` The functions consider only fy as a free parameter. The
 /// ratio fx/fy stays the same as in the input cameraMatrix . When
-///  @ref CALIB_USE_INTRINSIC_GUESS is not set, the actual input values of fx and fy are
+///  `CALIB_USE_INTRINSIC_GUESS` is not set, the actual input values of fx and fy are
 /// ignored, only their ratio is computed and used further.
-/// *   @ref CALIB_ZERO_TANGENT_DIST Tangential distortion coefficients ![inline formula](https://latex.codecogs.com/png.latex?%28p%5F1%2C%20p%5F2%29) are set
+/// *   `CALIB_ZERO_TANGENT_DIST` Tangential distortion coefficients ![inline formula](https://latex.codecogs.com/png.latex?%28p%5F1%2C%20p%5F2%29) are set
 /// to zeros and stay zero.
-/// *   @ref CALIB_FIX_FOCAL_LENGTH The focal length is not changed during the global optimization if
-///  @ref CALIB_USE_INTRINSIC_GUESS is set.
-/// *   @ref CALIB_FIX_K1,..., @ref CALIB_FIX_K6 The corresponding radial distortion
-/// coefficient is not changed during the optimization. If @ref CALIB_USE_INTRINSIC_GUESS is
+/// *   `CALIB_FIX_FOCAL_LENGTH` The focal length is not changed during the global optimization if
+///  `CALIB_USE_INTRINSIC_GUESS` is set.
+/// *   `CALIB_FIX_K1`,..., `CALIB_FIX_K6` The corresponding radial distortion
+/// coefficient is not changed during the optimization. If `CALIB_USE_INTRINSIC_GUESS` is
 /// set, the coefficient from the supplied distCoeffs matrix is used. Otherwise, it is set to 0.
-/// *   @ref CALIB_RATIONAL_MODEL Coefficients k4, k5, and k6 are enabled. To provide the
+/// *   `CALIB_RATIONAL_MODEL` Coefficients k4, k5, and k6 are enabled. To provide the
 /// backward compatibility, this extra flag should be explicitly specified to make the
 /// calibration function use the rational model and return 8 coefficients or more.
-/// *   @ref CALIB_THIN_PRISM_MODEL Coefficients s1, s2, s3 and s4 are enabled. To provide the
+/// *   `CALIB_THIN_PRISM_MODEL` Coefficients s1, s2, s3 and s4 are enabled. To provide the
 /// backward compatibility, this extra flag should be explicitly specified to make the
 /// calibration function use the thin prism model and return 12 coefficients or more.
-/// *   @ref CALIB_FIX_S1_S2_S3_S4 The thin prism distortion coefficients are not changed during
-/// the optimization. If @ref CALIB_USE_INTRINSIC_GUESS is set, the coefficient from the
+/// *   `CALIB_FIX_S1_S2_S3_S4` The thin prism distortion coefficients are not changed during
+/// the optimization. If `CALIB_USE_INTRINSIC_GUESS` is set, the coefficient from the
 /// supplied distCoeffs matrix is used. Otherwise, it is set to 0.
-/// *   @ref CALIB_TILTED_MODEL Coefficients tauX and tauY are enabled. To provide the
+/// *   `CALIB_TILTED_MODEL` Coefficients tauX and tauY are enabled. To provide the
 /// backward compatibility, this extra flag should be explicitly specified to make the
 /// calibration function use the tilted sensor model and return 14 coefficients.
-/// *   @ref CALIB_FIX_TAUX_TAUY The coefficients of the tilted sensor model are not changed during
-/// the optimization. If @ref CALIB_USE_INTRINSIC_GUESS is set, the coefficient from the
+/// *   `CALIB_FIX_TAUX_TAUY` The coefficients of the tilted sensor model are not changed during
+/// the optimization. If `CALIB_USE_INTRINSIC_GUESS` is set, the coefficient from the
 /// supplied distCoeffs matrix is used. Otherwise, it is set to 0.
 /// * criteria: Termination criteria for the iterative optimization algorithm.
 /// 
@@ -1015,8 +1183,8 @@ pub fn calibrate_camera_extended(object_points: &dyn core::ToInputArray, image_p
 /// points and their corresponding 2D projections in each view must be specified. That may be achieved
 /// by using an object with known geometry and easily detectable feature points. Such an object is
 /// called a calibration rig or calibration pattern, and OpenCV has built-in support for a chessboard as
-/// a calibration rig (see @ref findChessboardCorners). Currently, initialization of intrinsic
-/// parameters (when @ref CALIB_USE_INTRINSIC_GUESS is not set) is only implemented for planar calibration
+/// a calibration rig (see [find_chessboard_corners](crate::calib3d::find_chessboard_corners)). Currently, initialization of intrinsic
+/// parameters (when `CALIB_USE_INTRINSIC_GUESS` is not set) is only implemented for planar calibration
 /// patterns (where Z-coordinates of the object points must be all zeros). 3D calibration rigs can also
 /// be used as long as initial cameraMatrix is provided.
 /// 
@@ -1027,23 +1195,23 @@ pub fn calibrate_camera_extended(object_points: &dyn core::ToInputArray, image_p
 ///    zeros initially unless some of CALIB_FIX_K? are specified.
 /// 
 /// *   Estimate the initial camera pose as if the intrinsic parameters have been already known. This is
-///    done using @ref solvePnP .
+///    done using [solve_pnp](crate::calib3d::solve_pnp) .
 /// 
 /// *   Run the global Levenberg-Marquardt optimization algorithm to minimize the reprojection error,
 ///    that is, the total sum of squared distances between the observed feature points imagePoints and
 ///    the projected (using the current estimates for camera parameters and the poses) object points
-///    objectPoints. See @ref projectPoints for details.
+///    objectPoints. See [project_points](crate::calib3d::project_points) for details.
 /// 
 /// 
 /// Note:
-///    If you use a non-square (i.e. non-N-by-N) grid and @ref findChessboardCorners for calibration,
-///    and @ref calibrateCamera returns bad values (zero distortion coefficients, ![inline formula](https://latex.codecogs.com/png.latex?c%5Fx) and
+///    If you use a non-square (i.e. non-N-by-N) grid and [find_chessboard_corners](crate::calib3d::find_chessboard_corners) for calibration,
+///    and [calibrate_camera](crate::calib3d::calibrate_camera) returns bad values (zero distortion coefficients, ![inline formula](https://latex.codecogs.com/png.latex?c%5Fx) and
 ///    ![inline formula](https://latex.codecogs.com/png.latex?c%5Fy) very far from the image center, and/or large differences between ![inline formula](https://latex.codecogs.com/png.latex?f%5Fx) and
 ///    ![inline formula](https://latex.codecogs.com/png.latex?f%5Fy) (ratios of 10:1 or more)), then you are probably using patternSize=cvSize(rows,cols)
-///    instead of using patternSize=cvSize(cols,rows) in @ref findChessboardCorners.
+///    instead of using patternSize=cvSize(cols,rows) in [find_chessboard_corners](crate::calib3d::find_chessboard_corners).
 /// ## See also
-/// calibrateCameraRO, findChessboardCorners, solvePnP, initCameraMatrix2D, stereoCalibrate,
-///    undistort
+/// [calibrate_camera_ro](crate::calib3d::calibrate_camera_ro), [find_chessboard_corners](crate::calib3d::find_chessboard_corners), [solve_pnp](crate::calib3d::solve_pnp), [init_camera_matrix_2d](crate::calib3d::init_camera_matrix_2d), [stereo_calibrate](crate::calib3d::stereo_calibrate),
+///    [undistort](crate::calib3d::undistort)
 /// 
 /// ## Overloaded parameters
 /// 
@@ -1059,12 +1227,47 @@ pub fn calibrate_camera(object_points: &dyn core::ToInputArray, image_points: &d
 	output_array_arg!(rvecs);
 	output_array_arg!(tvecs);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_calibrateCamera_const__InputArrayR_const__InputArrayR_Size_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_int_TermCriteria(object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), image_size.opencv_as_extern(), camera_matrix.as_raw__InputOutputArray(), dist_coeffs.as_raw__InputOutputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_calibrateCamera_const__InputArrayR_const__InputArrayR_Size_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_int_TermCriteria(object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), image_size.opencv_as_extern(), camera_matrix.as_raw__InputOutputArray(), dist_coeffs.as_raw__InputOutputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `calibrate_camera()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CalibrateCameraBuilder<'a> {
+	object_points: &'a dyn core::ToInputArray,
+	image_points: &'a dyn core::ToInputArray,
+	image_size: core::Size,
+	camera_matrix: &'a mut dyn core::ToInputOutputArray,
+	dist_coeffs: &'a mut dyn core::ToInputOutputArray,
+	rvecs: &'a mut dyn core::ToOutputArray,
+	tvecs: &'a mut dyn core::ToOutputArray,
+	flags: i32,
+	criteria: core::TermCriteria,
+}
+
+impl<'a> CalibrateCameraBuilder<'a> {
+	#[inline]
+	pub fn new(object_points: &'a dyn core::ToInputArray, image_points: &'a dyn core::ToInputArray, image_size: core::Size, camera_matrix: &'a mut dyn core::ToInputOutputArray, dist_coeffs: &'a mut dyn core::ToInputOutputArray, rvecs: &'a mut dyn core::ToOutputArray, tvecs: &'a mut dyn core::ToOutputArray, criteria: core::TermCriteria) -> Self {
+		Self { object_points, image_points, image_size, camera_matrix, dist_coeffs, rvecs, tvecs, flags: 0, criteria }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_flags(mut self, flags: i32) -> Self {
+		self.flags = flags;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<f64> {
+		calibrate_camera(self.object_points, self.image_points, self.image_size, self.camera_matrix, self.dist_coeffs, self.rvecs, self.tvecs, self.flags, self.criteria)
+	}
+}
+
 /// Computes Hand-Eye calibration: ![inline formula](https://latex.codecogs.com/png.latex?%5F%7B%7D%5E%7Bg%7D%5Ctextrm%7BT%7D%5Fc)
 /// 
 /// ## Parameters
@@ -1084,9 +1287,9 @@ pub fn calibrate_camera(object_points: &dyn core::ToInputArray, image_points: &d
 /// expressed in the target frame to the camera frame (![inline formula](https://latex.codecogs.com/png.latex?%5F%7B%7D%5E%7Bc%7D%5Ctextrm%7BT%7D%5Ft)).
 /// This is a vector (`vector<Mat>`) that contains the `(3x1)` translation vectors for all the transformations
 /// from calibration target frame to camera frame.
-/// * R_cam2gripper:[out] Estimated `(3x3)` rotation part extracted from the homogeneous matrix that transforms a point
+/// * R_cam2gripper:\[out\] Estimated `(3x3)` rotation part extracted from the homogeneous matrix that transforms a point
 /// expressed in the camera frame to the gripper frame (![inline formula](https://latex.codecogs.com/png.latex?%5F%7B%7D%5E%7Bg%7D%5Ctextrm%7BT%7D%5Fc)).
-/// * t_cam2gripper:[out] Estimated `(3x1)` translation part extracted from the homogeneous matrix that transforms a point
+/// * t_cam2gripper:\[out\] Estimated `(3x1)` translation part extracted from the homogeneous matrix that transforms a point
 /// expressed in the camera frame to the gripper frame (![inline formula](https://latex.codecogs.com/png.latex?%5F%7B%7D%5E%7Bg%7D%5Ctextrm%7BT%7D%5Fc)).
 /// * method: One of the implemented Hand-Eye calibration method, see cv::HandEyeCalibrationMethod
 /// 
@@ -1148,7 +1351,7 @@ pub fn calibrate_hand_eye(r_gripper2base: &dyn core::ToInputArray, t_gripper2bas
 	output_array_arg!(r_cam2gripper);
 	output_array_arg!(t_cam2gripper);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_calibrateHandEye_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_HandEyeCalibrationMethod(r_gripper2base.as_raw__InputArray(), t_gripper2base.as_raw__InputArray(), r_target2cam.as_raw__InputArray(), t_target2cam.as_raw__InputArray(), r_cam2gripper.as_raw__OutputArray(), t_cam2gripper.as_raw__OutputArray(), method, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_calibrateHandEye_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_HandEyeCalibrationMethod(r_gripper2base.as_raw__InputArray(), t_gripper2base.as_raw__InputArray(), r_target2cam.as_raw__InputArray(), t_target2cam.as_raw__InputArray(), r_cam2gripper.as_raw__OutputArray(), t_cam2gripper.as_raw__OutputArray(), method, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1173,13 +1376,13 @@ pub fn calibrate_hand_eye(r_gripper2base: &dyn core::ToInputArray, t_gripper2bas
 /// expressed in the robot base frame to the gripper frame (![inline formula](https://latex.codecogs.com/png.latex?%5F%7B%7D%5E%7Bg%7D%5Ctextrm%7BT%7D%5Fb)).
 /// This is a vector (`vector<Mat>`) that contains the `(3x1)` translation vectors for all the transformations
 /// from robot base frame to the gripper frame.
-/// * R_base2world:[out] Estimated `(3x3)` rotation part extracted from the homogeneous matrix that transforms a point
+/// * R_base2world:\[out\] Estimated `(3x3)` rotation part extracted from the homogeneous matrix that transforms a point
 /// expressed in the robot base frame to the world frame (![inline formula](https://latex.codecogs.com/png.latex?%5F%7B%7D%5E%7Bw%7D%5Ctextrm%7BT%7D%5Fb)).
-/// * t_base2world:[out] Estimated `(3x1)` translation part extracted from the homogeneous matrix that transforms a point
+/// * t_base2world:\[out\] Estimated `(3x1)` translation part extracted from the homogeneous matrix that transforms a point
 /// expressed in the robot base frame to the world frame (![inline formula](https://latex.codecogs.com/png.latex?%5F%7B%7D%5E%7Bw%7D%5Ctextrm%7BT%7D%5Fb)).
-/// * R_gripper2cam:[out] Estimated `(3x3)` rotation part extracted from the homogeneous matrix that transforms a point
+/// * R_gripper2cam:\[out\] Estimated `(3x3)` rotation part extracted from the homogeneous matrix that transforms a point
 /// expressed in the gripper frame to the camera frame (![inline formula](https://latex.codecogs.com/png.latex?%5F%7B%7D%5E%7Bc%7D%5Ctextrm%7BT%7D%5Fg)).
-/// * t_gripper2cam:[out] Estimated `(3x1)` translation part extracted from the homogeneous matrix that transforms a point
+/// * t_gripper2cam:\[out\] Estimated `(3x1)` translation part extracted from the homogeneous matrix that transforms a point
 /// expressed in the gripper frame to the camera frame (![inline formula](https://latex.codecogs.com/png.latex?%5F%7B%7D%5E%7Bc%7D%5Ctextrm%7BT%7D%5Fg)).
 /// * method: One of the implemented Robot-World/Hand-Eye calibration method, see cv::RobotWorldHandEyeCalibrationMethod
 /// 
@@ -1233,7 +1436,7 @@ pub fn calibrate_robot_world_hand_eye(r_world2cam: &dyn core::ToInputArray, t_wo
 	output_array_arg!(r_gripper2cam);
 	output_array_arg!(t_gripper2cam);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_calibrateRobotWorldHandEye_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_RobotWorldHandEyeCalibrationMethod(r_world2cam.as_raw__InputArray(), t_world2cam.as_raw__InputArray(), r_base2gripper.as_raw__InputArray(), t_base2gripper.as_raw__InputArray(), r_base2world.as_raw__OutputArray(), t_base2world.as_raw__OutputArray(), r_gripper2cam.as_raw__OutputArray(), t_gripper2cam.as_raw__OutputArray(), method, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_calibrateRobotWorldHandEye_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_RobotWorldHandEyeCalibrationMethod(r_world2cam.as_raw__InputArray(), t_world2cam.as_raw__InputArray(), r_base2gripper.as_raw__InputArray(), t_base2gripper.as_raw__InputArray(), r_base2world.as_raw__OutputArray(), t_base2world.as_raw__OutputArray(), r_gripper2cam.as_raw__OutputArray(), t_gripper2cam.as_raw__OutputArray(), method, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1264,7 +1467,7 @@ pub fn calibrate_robot_world_hand_eye(r_world2cam: &dyn core::ToInputArray, t_wo
 pub fn calibration_matrix_values(camera_matrix: &dyn core::ToInputArray, image_size: core::Size, aperture_width: f64, aperture_height: f64, fovx: &mut f64, fovy: &mut f64, focal_length: &mut f64, principal_point: &mut core::Point2d, aspect_ratio: &mut f64) -> Result<()> {
 	input_array_arg!(camera_matrix);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_calibrationMatrixValues_const__InputArrayR_Size_double_double_doubleR_doubleR_doubleR_Point2dR_doubleR(camera_matrix.as_raw__InputArray(), image_size.opencv_as_extern(), aperture_width, aperture_height, fovx, fovy, focal_length, principal_point, aspect_ratio, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_calibrationMatrixValues_const__InputArrayR_Size_double_double_doubleR_doubleR_doubleR_Point2dR_doubleR(camera_matrix.as_raw__InputArray(), image_size.opencv_as_extern(), aperture_width, aperture_height, fovx, fovy, focal_length, principal_point, aspect_ratio, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1274,7 +1477,7 @@ pub fn calibration_matrix_values(camera_matrix: &dyn core::ToInputArray, image_s
 pub fn check_chessboard(img: &dyn core::ToInputArray, size: core::Size) -> Result<bool> {
 	input_array_arg!(img);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_checkChessboard_const__InputArrayR_Size(img.as_raw__InputArray(), size.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_checkChessboard_const__InputArrayR_Size(img.as_raw__InputArray(), size.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1336,12 +1539,117 @@ pub fn compose_rt(rvec1: &dyn core::ToInputArray, tvec1: &dyn core::ToInputArray
 	output_array_arg!(dt3dr2);
 	output_array_arg!(dt3dt2);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_composeRT_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(rvec1.as_raw__InputArray(), tvec1.as_raw__InputArray(), rvec2.as_raw__InputArray(), tvec2.as_raw__InputArray(), rvec3.as_raw__OutputArray(), tvec3.as_raw__OutputArray(), dr3dr1.as_raw__OutputArray(), dr3dt1.as_raw__OutputArray(), dr3dr2.as_raw__OutputArray(), dr3dt2.as_raw__OutputArray(), dt3dr1.as_raw__OutputArray(), dt3dt1.as_raw__OutputArray(), dt3dr2.as_raw__OutputArray(), dt3dt2.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_composeRT_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(rvec1.as_raw__InputArray(), tvec1.as_raw__InputArray(), rvec2.as_raw__InputArray(), tvec2.as_raw__InputArray(), rvec3.as_raw__OutputArray(), tvec3.as_raw__OutputArray(), dr3dr1.as_raw__OutputArray(), dr3dt1.as_raw__OutputArray(), dr3dr2.as_raw__OutputArray(), dr3dt2.as_raw__OutputArray(), dt3dr1.as_raw__OutputArray(), dt3dt1.as_raw__OutputArray(), dt3dr2.as_raw__OutputArray(), dt3dt2.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `compose_rt()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct ComposeRtBuilder<'a> {
+	rvec1: &'a dyn core::ToInputArray,
+	tvec1: &'a dyn core::ToInputArray,
+	rvec2: &'a dyn core::ToInputArray,
+	tvec2: &'a dyn core::ToInputArray,
+	rvec3: &'a mut dyn core::ToOutputArray,
+	tvec3: &'a mut dyn core::ToOutputArray,
+	dr3dr1: Option<&'a mut dyn core::ToOutputArray>,
+	dr3dt1: Option<&'a mut dyn core::ToOutputArray>,
+	dr3dr2: Option<&'a mut dyn core::ToOutputArray>,
+	dr3dt2: Option<&'a mut dyn core::ToOutputArray>,
+	dt3dr1: Option<&'a mut dyn core::ToOutputArray>,
+	dt3dt1: Option<&'a mut dyn core::ToOutputArray>,
+	dt3dr2: Option<&'a mut dyn core::ToOutputArray>,
+	dt3dt2: Option<&'a mut dyn core::ToOutputArray>,
+}
+
+impl<'a> ComposeRtBuilder<'a> {
+	#[inline]
+	pub fn new(rvec1: &'a dyn core::ToInputArray, tvec1: &'a dyn core::ToInputArray, rvec2: &'a dyn core::ToInputArray, tvec2: &'a dyn core::ToInputArray, rvec3: &'a mut dyn core::ToOutputArray, tvec3: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { rvec1, tvec1, rvec2, tvec2, rvec3, tvec3, dr3dr1: None, dr3dt1: None, dr3dr2: None, dr3dt2: None, dt3dr1: None, dt3dt1: None, dt3dr2: None, dt3dt2: None }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_dr3dr1(mut self, dr3dr1: &'a mut dyn core::ToOutputArray) -> Self {
+		self.dr3dr1 = Some(dr3dr1);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_dr3dt1(mut self, dr3dt1: &'a mut dyn core::ToOutputArray) -> Self {
+		self.dr3dt1 = Some(dr3dt1);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_dr3dr2(mut self, dr3dr2: &'a mut dyn core::ToOutputArray) -> Self {
+		self.dr3dr2 = Some(dr3dr2);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_dr3dt2(mut self, dr3dt2: &'a mut dyn core::ToOutputArray) -> Self {
+		self.dr3dt2 = Some(dr3dt2);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_dt3dr1(mut self, dt3dr1: &'a mut dyn core::ToOutputArray) -> Self {
+		self.dt3dr1 = Some(dt3dr1);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_dt3dt1(mut self, dt3dt1: &'a mut dyn core::ToOutputArray) -> Self {
+		self.dt3dt1 = Some(dt3dt1);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_dt3dr2(mut self, dt3dr2: &'a mut dyn core::ToOutputArray) -> Self {
+		self.dt3dr2 = Some(dt3dr2);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_dt3dt2(mut self, dt3dt2: &'a mut dyn core::ToOutputArray) -> Self {
+		self.dt3dt2 = Some(dt3dt2);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let mut dr3dr1_no_array = core::no_array();
+		let dr3dr1: &mut dyn core::ToOutputArray = match self.dr3dr1 { Some(dr3dr1) => dr3dr1, None => &mut dr3dr1_no_array };
+		let mut dr3dt1_no_array = core::no_array();
+		let dr3dt1: &mut dyn core::ToOutputArray = match self.dr3dt1 { Some(dr3dt1) => dr3dt1, None => &mut dr3dt1_no_array };
+		let mut dr3dr2_no_array = core::no_array();
+		let dr3dr2: &mut dyn core::ToOutputArray = match self.dr3dr2 { Some(dr3dr2) => dr3dr2, None => &mut dr3dr2_no_array };
+		let mut dr3dt2_no_array = core::no_array();
+		let dr3dt2: &mut dyn core::ToOutputArray = match self.dr3dt2 { Some(dr3dt2) => dr3dt2, None => &mut dr3dt2_no_array };
+		let mut dt3dr1_no_array = core::no_array();
+		let dt3dr1: &mut dyn core::ToOutputArray = match self.dt3dr1 { Some(dt3dr1) => dt3dr1, None => &mut dt3dr1_no_array };
+		let mut dt3dt1_no_array = core::no_array();
+		let dt3dt1: &mut dyn core::ToOutputArray = match self.dt3dt1 { Some(dt3dt1) => dt3dt1, None => &mut dt3dt1_no_array };
+		let mut dt3dr2_no_array = core::no_array();
+		let dt3dr2: &mut dyn core::ToOutputArray = match self.dt3dr2 { Some(dt3dr2) => dt3dr2, None => &mut dt3dr2_no_array };
+		let mut dt3dt2_no_array = core::no_array();
+		let dt3dt2: &mut dyn core::ToOutputArray = match self.dt3dt2 { Some(dt3dt2) => dt3dt2, None => &mut dt3dt2_no_array };
+		compose_rt(self.rvec1, self.tvec1, self.rvec2, self.tvec2, self.rvec3, self.tvec3, dr3dr1, dr3dt1, dr3dr2, dr3dt2, dt3dr1, dt3dt1, dt3dr2, dt3dt2)
+	}
+}
+
 /// For points in an image of a stereo pair, computes the corresponding epilines in the other image.
 /// 
 /// ## Parameters
@@ -1371,7 +1679,7 @@ pub fn compute_correspond_epilines(points: &dyn core::ToInputArray, which_image:
 	input_array_arg!(f);
 	output_array_arg!(lines);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_computeCorrespondEpilines_const__InputArrayR_int_const__InputArrayR_const__OutputArrayR(points.as_raw__InputArray(), which_image, f.as_raw__InputArray(), lines.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_computeCorrespondEpilines_const__InputArrayR_int_const__InputArrayR_const__OutputArrayR(points.as_raw__InputArray(), which_image, f.as_raw__InputArray(), lines.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1391,7 +1699,7 @@ pub fn convert_points_from_homogeneous(src: &dyn core::ToInputArray, dst: &mut d
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_convertPointsFromHomogeneous_const__InputArrayR_const__OutputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_convertPointsFromHomogeneous_const__InputArrayR_const__OutputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1413,7 +1721,7 @@ pub fn convert_points_homogeneous(src: &dyn core::ToInputArray, dst: &mut dyn co
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_convertPointsHomogeneous_const__InputArrayR_const__OutputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_convertPointsHomogeneous_const__InputArrayR_const__OutputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1432,7 +1740,7 @@ pub fn convert_points_to_homogeneous(src: &dyn core::ToInputArray, dst: &mut dyn
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_convertPointsToHomogeneous_const__InputArrayR_const__OutputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_convertPointsToHomogeneous_const__InputArrayR_const__OutputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1448,8 +1756,8 @@ pub fn convert_points_to_homogeneous(src: &dyn core::ToInputArray, dst: &mut dyn
 /// * newPoints2: The optimized points2.
 /// 
 /// The function implements the Optimal Triangulation Method (see Multiple View Geometry for details).
-/// For each given point correspondence points1[i] \<-\> points2[i], and a fundamental matrix F, it
-/// computes the corrected correspondences newPoints1[i] \<-\> newPoints2[i] that minimize the geometric
+/// For each given point correspondence points1\[i\] \<-\> points2\[i\], and a fundamental matrix F, it
+/// computes the corrected correspondences newPoints1\[i\] \<-\> newPoints2\[i\] that minimize the geometric
 /// error ![inline formula](https://latex.codecogs.com/png.latex?d%28points1%5Bi%5D%2C%20newPoints1%5Bi%5D%29%5E2%20%2B%20d%28points2%5Bi%5D%2CnewPoints2%5Bi%5D%29%5E2) (where ![inline formula](https://latex.codecogs.com/png.latex?d%28a%2Cb%29) is the
 /// geometric distance between points ![inline formula](https://latex.codecogs.com/png.latex?a) and ![inline formula](https://latex.codecogs.com/png.latex?b) ) subject to the epipolar constraint
 /// ![inline formula](https://latex.codecogs.com/png.latex?newPoints2%5ET%20%2A%20F%20%2A%20newPoints1%20%3D%200) .
@@ -1461,7 +1769,7 @@ pub fn correct_matches(f: &dyn core::ToInputArray, points1: &dyn core::ToInputAr
 	output_array_arg!(new_points1);
 	output_array_arg!(new_points2);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_correctMatches_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(f.as_raw__InputArray(), points1.as_raw__InputArray(), points2.as_raw__InputArray(), new_points1.as_raw__OutputArray(), new_points2.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_correctMatches_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(f.as_raw__InputArray(), points1.as_raw__InputArray(), points2.as_raw__InputArray(), new_points1.as_raw__OutputArray(), new_points2.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1492,7 +1800,7 @@ pub fn decompose_essential_mat(e: &dyn core::ToInputArray, r1: &mut dyn core::To
 	output_array_arg!(r2);
 	output_array_arg!(t);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_decomposeEssentialMat_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(e.as_raw__InputArray(), r1.as_raw__OutputArray(), r2.as_raw__OutputArray(), t.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_decomposeEssentialMat_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(e.as_raw__InputArray(), r1.as_raw__OutputArray(), r2.as_raw__OutputArray(), t.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1513,8 +1821,8 @@ pub fn decompose_essential_mat(e: &dyn core::ToInputArray, r1: &mut dyn core::To
 /// 
 /// If the homography H, induced by the plane, gives the constraint
 /// ![block formula](https://latex.codecogs.com/png.latex?s%5Fi%20%5Cbegin%7Bbmatrix%7D%20x%27%5Fi%5C%5C%20y%27%5Fi%5C%5C%201%20%5Cend%7Bbmatrix%7D%20%5Csim%20H%20%5Cbegin%7Bbmatrix%7D%20x%5Fi%5C%5C%20y%5Fi%5C%5C%201%20%5Cend%7Bbmatrix%7D) on the source image points
-/// ![inline formula](https://latex.codecogs.com/png.latex?p%5Fi) and the destination image points ![inline formula](https://latex.codecogs.com/png.latex?p%27%5Fi), then the tuple of rotations[k] and
-/// translations[k] is a change of basis from the source camera's coordinate system to the destination
+/// ![inline formula](https://latex.codecogs.com/png.latex?p%5Fi) and the destination image points ![inline formula](https://latex.codecogs.com/png.latex?p%27%5Fi), then the tuple of rotations\[k\] and
+/// translations\[k\] is a change of basis from the source camera's coordinate system to the destination
 /// camera's coordinate system. However, by decomposing H, one can only get the translation normalized
 /// by the (typically unknown) depth of the scene, i.e. its direction but with normalized length.
 /// 
@@ -1528,7 +1836,7 @@ pub fn decompose_homography_mat(h: &dyn core::ToInputArray, k: &dyn core::ToInpu
 	output_array_arg!(translations);
 	output_array_arg!(normals);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_decomposeHomographyMat_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(h.as_raw__InputArray(), k.as_raw__InputArray(), rotations.as_raw__OutputArray(), translations.as_raw__OutputArray(), normals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_decomposeHomographyMat_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(h.as_raw__InputArray(), k.as_raw__InputArray(), rotations.as_raw__OutputArray(), translations.as_raw__OutputArray(), normals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1573,12 +1881,75 @@ pub fn decompose_projection_matrix(proj_matrix: &dyn core::ToInputArray, camera_
 	output_array_arg!(rot_matrix_z);
 	output_array_arg!(euler_angles);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_decomposeProjectionMatrix_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(proj_matrix.as_raw__InputArray(), camera_matrix.as_raw__OutputArray(), rot_matrix.as_raw__OutputArray(), trans_vect.as_raw__OutputArray(), rot_matrix_x.as_raw__OutputArray(), rot_matrix_y.as_raw__OutputArray(), rot_matrix_z.as_raw__OutputArray(), euler_angles.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_decomposeProjectionMatrix_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(proj_matrix.as_raw__InputArray(), camera_matrix.as_raw__OutputArray(), rot_matrix.as_raw__OutputArray(), trans_vect.as_raw__OutputArray(), rot_matrix_x.as_raw__OutputArray(), rot_matrix_y.as_raw__OutputArray(), rot_matrix_z.as_raw__OutputArray(), euler_angles.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `decompose_projection_matrix()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct DecomposeProjectionMatrixBuilder<'a> {
+	proj_matrix: &'a dyn core::ToInputArray,
+	camera_matrix: &'a mut dyn core::ToOutputArray,
+	rot_matrix: &'a mut dyn core::ToOutputArray,
+	trans_vect: &'a mut dyn core::ToOutputArray,
+	rot_matrix_x: Option<&'a mut dyn core::ToOutputArray>,
+	rot_matrix_y: Option<&'a mut dyn core::ToOutputArray>,
+	rot_matrix_z: Option<&'a mut dyn core::ToOutputArray>,
+	euler_angles: Option<&'a mut dyn core::ToOutputArray>,
+}
+
+impl<'a> DecomposeProjectionMatrixBuilder<'a> {
+	#[inline]
+	pub fn new(proj_matrix: &'a dyn core::ToInputArray, camera_matrix: &'a mut dyn core::ToOutputArray, rot_matrix: &'a mut dyn core::ToOutputArray, trans_vect: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { proj_matrix, camera_matrix, rot_matrix, trans_vect, rot_matrix_x: None, rot_matrix_y: None, rot_matrix_z: None, euler_angles: None }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_rot_matrix_x(mut self, rot_matrix_x: &'a mut dyn core::ToOutputArray) -> Self {
+		self.rot_matrix_x = Some(rot_matrix_x);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_rot_matrix_y(mut self, rot_matrix_y: &'a mut dyn core::ToOutputArray) -> Self {
+		self.rot_matrix_y = Some(rot_matrix_y);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_rot_matrix_z(mut self, rot_matrix_z: &'a mut dyn core::ToOutputArray) -> Self {
+		self.rot_matrix_z = Some(rot_matrix_z);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_euler_angles(mut self, euler_angles: &'a mut dyn core::ToOutputArray) -> Self {
+		self.euler_angles = Some(euler_angles);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let mut rot_matrix_x_no_array = core::no_array();
+		let rot_matrix_x: &mut dyn core::ToOutputArray = match self.rot_matrix_x { Some(rot_matrix_x) => rot_matrix_x, None => &mut rot_matrix_x_no_array };
+		let mut rot_matrix_y_no_array = core::no_array();
+		let rot_matrix_y: &mut dyn core::ToOutputArray = match self.rot_matrix_y { Some(rot_matrix_y) => rot_matrix_y, None => &mut rot_matrix_y_no_array };
+		let mut rot_matrix_z_no_array = core::no_array();
+		let rot_matrix_z: &mut dyn core::ToOutputArray = match self.rot_matrix_z { Some(rot_matrix_z) => rot_matrix_z, None => &mut rot_matrix_z_no_array };
+		let mut euler_angles_no_array = core::no_array();
+		let euler_angles: &mut dyn core::ToOutputArray = match self.euler_angles { Some(euler_angles) => euler_angles, None => &mut euler_angles_no_array };
+		decompose_projection_matrix(self.proj_matrix, self.camera_matrix, self.rot_matrix, self.trans_vect, rot_matrix_x, rot_matrix_y, rot_matrix_z, euler_angles)
+	}
+}
+
 /// Renders the detected chessboard corners.
 /// 
 /// ## Parameters
@@ -1596,7 +1967,7 @@ pub fn draw_chessboard_corners(image: &mut dyn core::ToInputOutputArray, pattern
 	input_output_array_arg!(image);
 	input_array_arg!(corners);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_drawChessboardCorners_const__InputOutputArrayR_Size_const__InputArrayR_bool(image.as_raw__InputOutputArray(), pattern_size.opencv_as_extern(), corners.as_raw__InputArray(), pattern_was_found, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_drawChessboardCorners_const__InputOutputArrayR_Size_const__InputArrayR_bool(image.as_raw__InputOutputArray(), pattern_size.opencv_as_extern(), corners.as_raw__InputArray(), pattern_was_found, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1610,7 +1981,7 @@ pub fn draw_chessboard_corners(image: &mut dyn core::ToInputOutputArray, pattern
 /// ![inline formula](https://latex.codecogs.com/png.latex?%5Ccameramatrix%7BA%7D)
 /// * distCoeffs: Input vector of distortion coefficients
 /// ![inline formula](https://latex.codecogs.com/png.latex?%5Cdistcoeffs). If the vector is empty, the zero distortion coefficients are assumed.
-/// * rvec: Rotation vector (see @ref Rodrigues ) that, together with tvec, brings points from
+/// * rvec: Rotation vector (see [rodrigues](crate::calib3d::rodrigues) ) that, together with tvec, brings points from
 /// the model coordinate system to the camera coordinate system.
 /// * tvec: Translation vector.
 /// * length: Length of the painted axes in the same unit than tvec (usually in meters).
@@ -1629,19 +2000,52 @@ pub fn draw_frame_axes(image: &mut dyn core::ToInputOutputArray, camera_matrix:
 	input_array_arg!(rvec);
 	input_array_arg!(tvec);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_drawFrameAxes_const__InputOutputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_float_int(image.as_raw__InputOutputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvec.as_raw__InputArray(), tvec.as_raw__InputArray(), length, thickness, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_drawFrameAxes_const__InputOutputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_float_int(image.as_raw__InputOutputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvec.as_raw__InputArray(), tvec.as_raw__InputArray(), length, thickness, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `draw_frame_axes()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct DrawFrameAxesBuilder<'a> {
+	image: &'a mut dyn core::ToInputOutputArray,
+	camera_matrix: &'a dyn core::ToInputArray,
+	dist_coeffs: &'a dyn core::ToInputArray,
+	rvec: &'a dyn core::ToInputArray,
+	tvec: &'a dyn core::ToInputArray,
+	length: f32,
+	thickness: i32,
+}
+
+impl<'a> DrawFrameAxesBuilder<'a> {
+	#[inline]
+	pub fn new(image: &'a mut dyn core::ToInputOutputArray, camera_matrix: &'a dyn core::ToInputArray, dist_coeffs: &'a dyn core::ToInputArray, rvec: &'a dyn core::ToInputArray, tvec: &'a dyn core::ToInputArray, length: f32) -> Self {
+		Self { image, camera_matrix, dist_coeffs, rvec, tvec, length, thickness: 3 }
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_thickness(mut self, thickness: i32) -> Self {
+		self.thickness = thickness;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		draw_frame_axes(self.image, self.camera_matrix, self.dist_coeffs, self.rvec, self.tvec, self.length, self.thickness)
+	}
+}
+
 #[inline]
 pub fn estimate_affine_2d_1(pts1: &dyn core::ToInputArray, pts2: &dyn core::ToInputArray, inliers: &mut dyn core::ToOutputArray, params: crate::calib3d::UsacParams) -> Result<core::Mat> {
 	input_array_arg!(pts1);
 	input_array_arg!(pts2);
 	output_array_arg!(inliers);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_estimateAffine2D_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const_UsacParamsR(pts1.as_raw__InputArray(), pts2.as_raw__InputArray(), inliers.as_raw__OutputArray(), &params, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_estimateAffine2D_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const_UsacParamsR(pts1.as_raw__InputArray(), pts2.as_raw__InputArray(), inliers.as_raw__OutputArray(), &params, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -1658,8 +2062,8 @@ pub fn estimate_affine_2d_1(pts1: &dyn core::ToInputArray, pts2: &dyn core::ToIn
 /// * to: Second input 2D point set containing ![inline formula](https://latex.codecogs.com/png.latex?%28x%2Cy%29).
 /// * inliers: Output vector indicating which points are inliers (1-inlier, 0-outlier).
 /// * method: Robust method used to compute transformation. The following methods are possible:
-/// *   @ref RANSAC - RANSAC-based robust method
-/// *   @ref LMEDS - Least-Median robust method
+/// *   `RANSAC` - RANSAC-based robust method
+/// *   `LMEDS` - Least-Median robust method
 /// RANSAC is the default method.
 /// * ransacReprojThreshold: Maximum reprojection error in the RANSAC algorithm to consider
 /// a point as an inlier. Applies only to RANSAC.
@@ -1687,7 +2091,7 @@ pub fn estimate_affine_2d_1(pts1: &dyn core::ToInputArray, pts2: &dyn core::ToIn
 /// distinguish inliers from outliers. The method LMeDS does not need any threshold but it works
 /// correctly only when there are more than 50% of inliers.
 /// ## See also
-/// estimateAffinePartial2D, getAffineTransform
+/// [estimate_affine_partial_2d](crate::calib3d::estimate_affine_partial_2d), `get_affine_transform`
 /// 
 /// ## C++ default parameters
 /// * inliers: noArray()
@@ -1702,13 +2106,77 @@ pub fn estimate_affine_2d(from: &dyn core::ToInputArray, to: &dyn core::ToInputA
 	input_array_arg!(to);
 	output_array_arg!(inliers);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_estimateAffine2D_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_double_size_t_double_size_t(from.as_raw__InputArray(), to.as_raw__InputArray(), inliers.as_raw__OutputArray(), method, ransac_reproj_threshold, max_iters, confidence, refine_iters, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_estimateAffine2D_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_double_size_t_double_size_t(from.as_raw__InputArray(), to.as_raw__InputArray(), inliers.as_raw__OutputArray(), method, ransac_reproj_threshold, max_iters, confidence, refine_iters, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `estimate_affine_2d()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct EstimateAffine2dBuilder<'a> {
+	from: &'a dyn core::ToInputArray,
+	to: &'a dyn core::ToInputArray,
+	inliers: Option<&'a mut dyn core::ToOutputArray>,
+	method: i32,
+	ransac_reproj_threshold: f64,
+	max_iters: size_t,
+	confidence: f64,
+	refine_iters: size_t,
+}
+
+impl<'a> EstimateAffine2dBuilder<'a> {
+	#[inline]
+	pub fn new(from: &'a dyn core::ToInputArray, to: &'a dyn core::ToInputArray, method: i32) -> Self {
+		Self { from, to, inliers: None, method, ransac_reproj_threshold: 3., max_iters: 2000, confidence: 0.99, refine_iters: 10 }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_inliers(mut self, inliers: &'a mut dyn core::ToOutputArray) -> Self {
+		self.inliers = Some(inliers);
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_ransac_reproj_threshold(mut self, ransac_reproj_threshold: f64) -> Self {
+		self.ransac_reproj_threshold = ransac_reproj_threshold;
+		self
+	}
+	
+	/// C++ default: `2000`
+	#[inline]
+	pub fn with_max_iters(mut self, max_iters: size_t) -> Self {
+		self.max_iters = max_iters;
+		self
+	}
+	
+	/// C++ default: `0.99`
+	#[inline]
+	pub fn with_confidence(mut self, confidence: f64) -> Self {
+		self.confidence = confidence;
+		self
+	}
+	
+	/// C++ default: `10`
+	#[inline]
+	pub fn with_refine_iters(mut self, refine_iters: size_t) -> Self {
+		self.refine_iters = refine_iters;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Mat> {
+		let mut inliers_no_array = core::no_array();
+		let inliers: &mut dyn core::ToOutputArray = match self.inliers { Some(inliers) => inliers, None => &mut inliers_no_array };
+		estimate_affine_2d(self.from, self.to, inliers, self.method, self.ransac_reproj_threshold, self.max_iters, self.confidence, self.refine_iters)
+	}
+}
+
 /// Computes an optimal affine transformation between two 3D point sets.
 /// 
 /// It computes
@@ -1739,12 +2207,51 @@ pub fn estimate_affine_3d(src: &dyn core::ToInputArray, dst: &dyn core::ToInputA
 	output_array_arg!(out);
 	output_array_arg!(inliers);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_estimateAffine3D_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_double_double(src.as_raw__InputArray(), dst.as_raw__InputArray(), out.as_raw__OutputArray(), inliers.as_raw__OutputArray(), ransac_threshold, confidence, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_estimateAffine3D_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_double_double(src.as_raw__InputArray(), dst.as_raw__InputArray(), out.as_raw__OutputArray(), inliers.as_raw__OutputArray(), ransac_threshold, confidence, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `estimate_affine_3d()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct EstimateAffine3dBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a dyn core::ToInputArray,
+	out: &'a mut dyn core::ToOutputArray,
+	inliers: &'a mut dyn core::ToOutputArray,
+	ransac_threshold: f64,
+	confidence: f64,
+}
+
+impl<'a> EstimateAffine3dBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a dyn core::ToInputArray, out: &'a mut dyn core::ToOutputArray, inliers: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { src, dst, out, inliers, ransac_threshold: 3., confidence: 0.99 }
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_ransac_threshold(mut self, ransac_threshold: f64) -> Self {
+		self.ransac_threshold = ransac_threshold;
+		self
+	}
+	
+	/// C++ default: `0.99`
+	#[inline]
+	pub fn with_confidence(mut self, confidence: f64) -> Self {
+		self.confidence = confidence;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<i32> {
+		estimate_affine_3d(self.src, self.dst, self.out, self.inliers, self.ransac_threshold, self.confidence)
+	}
+}
+
 /// Computes an optimal affine transformation between two 3D point sets.
 /// 
 /// It computes ![inline formula](https://latex.codecogs.com/png.latex?R%2Cs%2Ct) minimizing ![inline formula](https://latex.codecogs.com/png.latex?%5Csum%7Bi%7D%20dst%5Fi%20%2D%20c%20%5Ccdot%20R%20%5Ccdot%20src%5Fi%20)
@@ -1774,7 +2281,7 @@ pub fn estimate_affine_3d_1(src: &dyn core::ToInputArray, dst: &dyn core::ToInpu
 	input_array_arg!(src);
 	input_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_estimateAffine3D_const__InputArrayR_const__InputArrayR_doubleX_bool(src.as_raw__InputArray(), dst.as_raw__InputArray(), scale, force_rotation, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_estimateAffine3D_const__InputArrayR_const__InputArrayR_doubleX_bool(src.as_raw__InputArray(), dst.as_raw__InputArray(), scale, force_rotation, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -1789,8 +2296,8 @@ pub fn estimate_affine_3d_1(src: &dyn core::ToInputArray, dst: &dyn core::ToInpu
 /// * to: Second input 2D point set.
 /// * inliers: Output vector indicating which points are inliers.
 /// * method: Robust method used to compute transformation. The following methods are possible:
-/// *   @ref RANSAC - RANSAC-based robust method
-/// *   @ref LMEDS - Least-Median robust method
+/// *   `RANSAC` - RANSAC-based robust method
+/// *   `LMEDS` - Least-Median robust method
 /// RANSAC is the default method.
 /// * ransacReprojThreshold: Maximum reprojection error in the RANSAC algorithm to consider
 /// a point as an inlier. Applies only to RANSAC.
@@ -1823,7 +2330,7 @@ pub fn estimate_affine_3d_1(src: &dyn core::ToInputArray, dst: &dyn core::ToInpu
 /// distinguish inliers from outliers. The method LMeDS does not need any threshold but it works
 /// correctly only when there are more than 50% of inliers.
 /// ## See also
-/// estimateAffine2D, getAffineTransform
+/// [estimate_affine_2d](crate::calib3d::estimate_affine_2d), `get_affine_transform`
 /// 
 /// ## C++ default parameters
 /// * inliers: noArray()
@@ -1838,13 +2345,77 @@ pub fn estimate_affine_partial_2d(from: &dyn core::ToInputArray, to: &dyn core::
 	input_array_arg!(to);
 	output_array_arg!(inliers);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_estimateAffinePartial2D_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_double_size_t_double_size_t(from.as_raw__InputArray(), to.as_raw__InputArray(), inliers.as_raw__OutputArray(), method, ransac_reproj_threshold, max_iters, confidence, refine_iters, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_estimateAffinePartial2D_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_double_size_t_double_size_t(from.as_raw__InputArray(), to.as_raw__InputArray(), inliers.as_raw__OutputArray(), method, ransac_reproj_threshold, max_iters, confidence, refine_iters, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `estimate_affine_partial_2d()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct EstimateAffinePartial2dBuilder<'a> {
+	from: &'a dyn core::ToInputArray,
+	to: &'a dyn core::ToInputArray,
+	inliers: Option<&'a mut dyn core::ToOutputArray>,
+	method: i32,
+	ransac_reproj_threshold: f64,
+	max_iters: size_t,
+	confidence: f64,
+	refine_iters: size_t,
+}
+
+impl<'a> EstimateAffinePartial2dBuilder<'a> {
+	#[inline]
+	pub fn new(from: &'a dyn core::ToInputArray, to: &'a dyn core::ToInputArray, method: i32) -> Self {
+		Self { from, to, inliers: None, method, ransac_reproj_threshold: 3., max_iters: 2000, confidence: 0.99, refine_iters: 10 }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_inliers(mut self, inliers: &'a mut dyn core::ToOutputArray) -> Self {
+		self.inliers = Some(inliers);
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_ransac_reproj_threshold(mut self, ransac_reproj_threshold: f64) -> Self {
+		self.ransac_reproj_threshold = ransac_reproj_threshold;
+		self
+	}
+	
+	/// C++ default: `2000`
+	#[inline]
+	pub fn with_max_iters(mut self, max_iters: size_t) -> Self {
+		self.max_iters = max_iters;
+		self
+	}
+	
+	/// C++ default: `0.99`
+	#[inline]
+	pub fn with_confidence(mut self, confidence: f64) -> Self {
+		self.confidence = confidence;
+		self
+	}
+	
+	/// C++ default: `10`
+	#[inline]
+	pub fn with_refine_iters(mut self, refine_iters: size_t) -> Self {
+		self.refine_iters = refine_iters;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Mat> {
+		let mut inliers_no_array = core::no_array();
+		let inliers: &mut dyn core::ToOutputArray = match self.inliers { Some(inliers) => inliers, None => &mut inliers_no_array };
+		estimate_affine_partial_2d(self.from, self.to, inliers, self.method, self.ransac_reproj_threshold, self.max_iters, self.confidence, self.refine_iters)
+	}
+}
+
 /// Estimates the sharpness of a detected chessboard.
 /// 
 /// Image sharpness, as well as brightness, are a critical parameter for accuracte
@@ -1884,12 +2455,60 @@ pub fn estimate_chessboard_sharpness(image: &dyn core::ToInputArray, pattern_siz
 	input_array_arg!(corners);
 	output_array_arg!(sharpness);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_estimateChessboardSharpness_const__InputArrayR_Size_const__InputArrayR_float_bool_const__OutputArrayR(image.as_raw__InputArray(), pattern_size.opencv_as_extern(), corners.as_raw__InputArray(), rise_distance, vertical, sharpness.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_estimateChessboardSharpness_const__InputArrayR_Size_const__InputArrayR_float_bool_const__OutputArrayR(image.as_raw__InputArray(), pattern_size.opencv_as_extern(), corners.as_raw__InputArray(), rise_distance, vertical, sharpness.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `estimate_chessboard_sharpness()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct EstimateChessboardSharpnessBuilder<'a> {
+	image: &'a dyn core::ToInputArray,
+	pattern_size: core::Size,
+	corners: &'a dyn core::ToInputArray,
+	rise_distance: f32,
+	vertical: bool,
+	sharpness: Option<&'a mut dyn core::ToOutputArray>,
+}
+
+impl<'a> EstimateChessboardSharpnessBuilder<'a> {
+	#[inline]
+	pub fn new(image: &'a dyn core::ToInputArray, pattern_size: core::Size, corners: &'a dyn core::ToInputArray) -> Self {
+		Self { image, pattern_size, corners, rise_distance: 0.8, vertical: false, sharpness: None }
+	}
+	
+	/// C++ default: `0.8F`
+	#[inline]
+	pub fn with_rise_distance(mut self, rise_distance: f32) -> Self {
+		self.rise_distance = rise_distance;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_vertical(mut self, vertical: bool) -> Self {
+		self.vertical = vertical;
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_sharpness(mut self, sharpness: &'a mut dyn core::ToOutputArray) -> Self {
+		self.sharpness = Some(sharpness);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Scalar> {
+		let mut sharpness_no_array = core::no_array();
+		let sharpness: &mut dyn core::ToOutputArray = match self.sharpness { Some(sharpness) => sharpness, None => &mut sharpness_no_array };
+		estimate_chessboard_sharpness(self.image, self.pattern_size, self.corners, self.rise_distance, self.vertical, sharpness)
+	}
+}
+
 /// Computes an optimal translation between two 3D point sets.
 /// 
 /// It computes
@@ -1920,12 +2539,51 @@ pub fn estimate_translation_3d(src: &dyn core::ToInputArray, dst: &dyn core::ToI
 	output_array_arg!(out);
 	output_array_arg!(inliers);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_estimateTranslation3D_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_double_double(src.as_raw__InputArray(), dst.as_raw__InputArray(), out.as_raw__OutputArray(), inliers.as_raw__OutputArray(), ransac_threshold, confidence, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_estimateTranslation3D_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_double_double(src.as_raw__InputArray(), dst.as_raw__InputArray(), out.as_raw__OutputArray(), inliers.as_raw__OutputArray(), ransac_threshold, confidence, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `estimate_translation_3d()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct EstimateTranslation3dBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a dyn core::ToInputArray,
+	out: &'a mut dyn core::ToOutputArray,
+	inliers: &'a mut dyn core::ToOutputArray,
+	ransac_threshold: f64,
+	confidence: f64,
+}
+
+impl<'a> EstimateTranslation3dBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a dyn core::ToInputArray, out: &'a mut dyn core::ToOutputArray, inliers: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { src, dst, out, inliers, ransac_threshold: 3., confidence: 0.99 }
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_ransac_threshold(mut self, ransac_threshold: f64) -> Self {
+		self.ransac_threshold = ransac_threshold;
+		self
+	}
+	
+	/// C++ default: `0.99`
+	#[inline]
+	pub fn with_confidence(mut self, confidence: f64) -> Self {
+		self.confidence = confidence;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<i32> {
+		estimate_translation_3d(self.src, self.dst, self.out, self.inliers, self.ransac_threshold, self.confidence)
+	}
+}
+
 /// Filters homography decompositions based on additional information.
 /// 
 /// ## Parameters
@@ -1955,12 +2613,46 @@ pub fn filter_homography_decomp_by_visible_refpoints(rotations: &dyn core::ToInp
 	output_array_arg!(possible_solutions);
 	input_array_arg!(points_mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_filterHomographyDecompByVisibleRefpoints_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR(rotations.as_raw__InputArray(), normals.as_raw__InputArray(), before_points.as_raw__InputArray(), after_points.as_raw__InputArray(), possible_solutions.as_raw__OutputArray(), points_mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_filterHomographyDecompByVisibleRefpoints_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR(rotations.as_raw__InputArray(), normals.as_raw__InputArray(), before_points.as_raw__InputArray(), after_points.as_raw__InputArray(), possible_solutions.as_raw__OutputArray(), points_mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `filter_homography_decomp_by_visible_refpoints()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FilterHomographyDecompByVisibleRefpointsBuilder<'a> {
+	rotations: &'a dyn core::ToInputArray,
+	normals: &'a dyn core::ToInputArray,
+	before_points: &'a dyn core::ToInputArray,
+	after_points: &'a dyn core::ToInputArray,
+	possible_solutions: &'a mut dyn core::ToOutputArray,
+	points_mask: Option<&'a dyn core::ToInputArray>,
+}
+
+impl<'a> FilterHomographyDecompByVisibleRefpointsBuilder<'a> {
+	#[inline]
+	pub fn new(rotations: &'a dyn core::ToInputArray, normals: &'a dyn core::ToInputArray, before_points: &'a dyn core::ToInputArray, after_points: &'a dyn core::ToInputArray, possible_solutions: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { rotations, normals, before_points, after_points, possible_solutions, points_mask: None }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_points_mask(mut self, points_mask: &'a dyn core::ToInputArray) -> Self {
+		self.points_mask = Some(points_mask);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let points_mask_no_array = core::no_array();
+		let points_mask: &dyn core::ToInputArray = match self.points_mask { Some(points_mask) => points_mask, None => &points_mask_no_array };
+		filter_homography_decomp_by_visible_refpoints(self.rotations, self.normals, self.before_points, self.after_points, self.possible_solutions, points_mask)
+	}
+}
+
 /// Filters off small noise blobs (speckles) in the disparity map
 /// 
 /// ## Parameters
@@ -1981,7 +2673,7 @@ pub fn filter_speckles(img: &mut dyn core::ToInputOutputArray, new_val: f64, max
 	input_output_array_arg!(img);
 	input_output_array_arg!(buf);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_filterSpeckles_const__InputOutputArrayR_double_int_double_const__InputOutputArrayR(img.as_raw__InputOutputArray(), new_val, max_speckle_size, max_diff, buf.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_filterSpeckles_const__InputOutputArrayR_double_int_double_const__InputOutputArrayR(img.as_raw__InputOutputArray(), new_val, max_speckle_size, max_diff, buf.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1993,7 +2685,7 @@ pub fn find4_quad_corner_subpix(img: &dyn core::ToInputArray, corners: &mut dyn
 	input_array_arg!(img);
 	input_output_array_arg!(corners);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_find4QuadCornerSubpix_const__InputArrayR_const__InputOutputArrayR_Size(img.as_raw__InputArray(), corners.as_raw__InputOutputArray(), region_size.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_find4QuadCornerSubpix_const__InputArrayR_const__InputOutputArrayR_Size(img.as_raw__InputArray(), corners.as_raw__InputOutputArray(), region_size.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -2007,11 +2699,11 @@ pub fn find4_quad_corner_subpix(img: &dyn core::ToInputArray, corners: &mut dyn
 /// ( patternSize = cv::Size(points_per_row,points_per_colum) = cv::Size(columns,rows) ).
 /// * corners: Output array of detected corners.
 /// * flags: Various operation flags that can be zero or a combination of the following values:
-/// *   @ref CALIB_CB_NORMALIZE_IMAGE Normalize the image gamma with equalizeHist before detection.
-/// *   @ref CALIB_CB_EXHAUSTIVE Run an exhaustive search to improve detection rate.
-/// *   @ref CALIB_CB_ACCURACY Up sample input image to improve sub-pixel accuracy due to aliasing effects.
-/// *   @ref CALIB_CB_LARGER The detected pattern is allowed to be larger than patternSize (see description).
-/// *   @ref CALIB_CB_MARKER The detected pattern must have a marker (see description).
+/// *   `CALIB_CB_NORMALIZE_IMAGE` Normalize the image gamma with equalizeHist before detection.
+/// *   `CALIB_CB_EXHAUSTIVE` Run an exhaustive search to improve detection rate.
+/// *   `CALIB_CB_ACCURACY` Up sample input image to improve sub-pixel accuracy due to aliasing effects.
+/// *   `CALIB_CB_LARGER` The detected pattern is allowed to be larger than patternSize (see description).
+/// *   `CALIB_CB_MARKER` The detected pattern must have a marker (see description).
 /// This should be used if an accurate camera calibration is required.
 /// * meta: Optional output arrray of detected corners (CV_8UC1 and size = cv::Size(columns,rows)).
 /// Each entry stands for one corner of the pattern and can have one of the following values:
@@ -2030,7 +2722,7 @@ pub fn find4_quad_corner_subpix(img: &dyn core::ToInputArray, corners: &mut dyn
 /// accurate than the one returned by cornerSubPix allowing a precise camera
 /// calibration for demanding applications.
 /// 
-/// In the case, the flags @ref CALIB_CB_LARGER or @ref CALIB_CB_MARKER are given,
+/// In the case, the flags `CALIB_CB_LARGER` or `CALIB_CB_MARKER` are given,
 /// the result can be recovered from the optional meta array. Both flags are
 /// helpful to use calibration patterns exceeding the field of view of the camera.
 /// These oversized patterns allow more accurate calibrations as corners can be
@@ -2048,7 +2740,7 @@ pub fn find4_quad_corner_subpix(img: &dyn core::ToInputArray, corners: &mut dyn
 /// a sample checkerboard optimized for the detection. However, any other checkerboard
 /// can be used as well.
 /// 
-/// Use gen_pattern.py (@ref tutorial_camera_calibration_pattern) to create checkerboard.
+/// Use gen_pattern.py (tutorial_camera_calibration_pattern) to create checkerboard.
 /// ![Checkerboard](https://docs.opencv.org/4.5.5/checkerboard_radon.png)
 /// 
 /// ## Overloaded parameters
@@ -2060,7 +2752,7 @@ pub fn find_chessboard_corners_sb(image: &dyn core::ToInputArray, pattern_size:
 	input_array_arg!(image);
 	output_array_arg!(corners);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_findChessboardCornersSB_const__InputArrayR_Size_const__OutputArrayR_int(image.as_raw__InputArray(), pattern_size.opencv_as_extern(), corners.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_findChessboardCornersSB_const__InputArrayR_Size_const__OutputArrayR_int(image.as_raw__InputArray(), pattern_size.opencv_as_extern(), corners.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -2074,11 +2766,11 @@ pub fn find_chessboard_corners_sb(image: &dyn core::ToInputArray, pattern_size:
 /// ( patternSize = cv::Size(points_per_row,points_per_colum) = cv::Size(columns,rows) ).
 /// * corners: Output array of detected corners.
 /// * flags: Various operation flags that can be zero or a combination of the following values:
-/// *   @ref CALIB_CB_NORMALIZE_IMAGE Normalize the image gamma with equalizeHist before detection.
-/// *   @ref CALIB_CB_EXHAUSTIVE Run an exhaustive search to improve detection rate.
-/// *   @ref CALIB_CB_ACCURACY Up sample input image to improve sub-pixel accuracy due to aliasing effects.
-/// *   @ref CALIB_CB_LARGER The detected pattern is allowed to be larger than patternSize (see description).
-/// *   @ref CALIB_CB_MARKER The detected pattern must have a marker (see description).
+/// *   `CALIB_CB_NORMALIZE_IMAGE` Normalize the image gamma with equalizeHist before detection.
+/// *   `CALIB_CB_EXHAUSTIVE` Run an exhaustive search to improve detection rate.
+/// *   `CALIB_CB_ACCURACY` Up sample input image to improve sub-pixel accuracy due to aliasing effects.
+/// *   `CALIB_CB_LARGER` The detected pattern is allowed to be larger than patternSize (see description).
+/// *   `CALIB_CB_MARKER` The detected pattern must have a marker (see description).
 /// This should be used if an accurate camera calibration is required.
 /// * meta: Optional output arrray of detected corners (CV_8UC1 and size = cv::Size(columns,rows)).
 /// Each entry stands for one corner of the pattern and can have one of the following values:
@@ -2097,7 +2789,7 @@ pub fn find_chessboard_corners_sb(image: &dyn core::ToInputArray, pattern_size:
 /// accurate than the one returned by cornerSubPix allowing a precise camera
 /// calibration for demanding applications.
 /// 
-/// In the case, the flags @ref CALIB_CB_LARGER or @ref CALIB_CB_MARKER are given,
+/// In the case, the flags `CALIB_CB_LARGER` or `CALIB_CB_MARKER` are given,
 /// the result can be recovered from the optional meta array. Both flags are
 /// helpful to use calibration patterns exceeding the field of view of the camera.
 /// These oversized patterns allow more accurate calibrations as corners can be
@@ -2115,7 +2807,7 @@ pub fn find_chessboard_corners_sb(image: &dyn core::ToInputArray, pattern_size:
 /// a sample checkerboard optimized for the detection. However, any other checkerboard
 /// can be used as well.
 /// 
-/// Use gen_pattern.py (@ref tutorial_camera_calibration_pattern) to create checkerboard.
+/// Use gen_pattern.py (tutorial_camera_calibration_pattern) to create checkerboard.
 /// ![Checkerboard](https://docs.opencv.org/4.5.5/checkerboard_radon.png)
 #[inline]
 pub fn find_chessboard_corners_sb_with_meta(image: &dyn core::ToInputArray, pattern_size: core::Size, corners: &mut dyn core::ToOutputArray, flags: i32, meta: &mut dyn core::ToOutputArray) -> Result<bool> {
@@ -2123,7 +2815,7 @@ pub fn find_chessboard_corners_sb_with_meta(image: &dyn core::ToInputArray, patt
 	output_array_arg!(corners);
 	output_array_arg!(meta);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_findChessboardCornersSB_const__InputArrayR_Size_const__OutputArrayR_int_const__OutputArrayR(image.as_raw__InputArray(), pattern_size.opencv_as_extern(), corners.as_raw__OutputArray(), flags, meta.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_findChessboardCornersSB_const__InputArrayR_Size_const__OutputArrayR_int_const__OutputArrayR(image.as_raw__InputArray(), pattern_size.opencv_as_extern(), corners.as_raw__OutputArray(), flags, meta.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -2137,13 +2829,13 @@ pub fn find_chessboard_corners_sb_with_meta(image: &dyn core::ToInputArray, patt
 /// ( patternSize = cv::Size(points_per_row,points_per_colum) = cv::Size(columns,rows) ).
 /// * corners: Output array of detected corners.
 /// * flags: Various operation flags that can be zero or a combination of the following values:
-/// *   @ref CALIB_CB_ADAPTIVE_THRESH Use adaptive thresholding to convert the image to black
+/// *   `CALIB_CB_ADAPTIVE_THRESH` Use adaptive thresholding to convert the image to black
 /// and white, rather than a fixed threshold level (computed from the average image brightness).
-/// *   @ref CALIB_CB_NORMALIZE_IMAGE Normalize the image gamma with equalizeHist before
+/// *   `CALIB_CB_NORMALIZE_IMAGE` Normalize the image gamma with equalizeHist before
 /// applying fixed or adaptive thresholding.
-/// *   @ref CALIB_CB_FILTER_QUADS Use additional criteria (like contour area, perimeter,
+/// *   `CALIB_CB_FILTER_QUADS` Use additional criteria (like contour area, perimeter,
 /// square-like shape) to filter out false quads extracted at the contour retrieval stage.
-/// *   @ref CALIB_CB_FAST_CHECK Run a fast check on the image that looks for chessboard corners,
+/// *   `CALIB_CB_FAST_CHECK` Run a fast check on the image that looks for chessboard corners,
 /// and shortcut the call if none is found. This can drastically speed up the call in the
 /// degenerate condition when no chessboard is observed.
 /// 
@@ -2181,7 +2873,7 @@ pub fn find_chessboard_corners_sb_with_meta(image: &dyn core::ToInputArray, patt
 /// border and the background is dark, the outer black squares cannot be segmented properly and so the
 /// square grouping and ordering algorithm fails.
 /// 
-/// Use gen_pattern.py (@ref tutorial_camera_calibration_pattern) to create checkerboard.
+/// Use gen_pattern.py (tutorial_camera_calibration_pattern) to create checkerboard.
 /// 
 /// ## C++ default parameters
 /// * flags: CALIB_CB_ADAPTIVE_THRESH+CALIB_CB_NORMALIZE_IMAGE
@@ -2190,7 +2882,7 @@ pub fn find_chessboard_corners(image: &dyn core::ToInputArray, pattern_size: cor
 	input_array_arg!(image);
 	output_array_arg!(corners);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_findChessboardCorners_const__InputArrayR_Size_const__OutputArrayR_int(image.as_raw__InputArray(), pattern_size.opencv_as_extern(), corners.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_findChessboardCorners_const__InputArrayR_Size_const__OutputArrayR_int(image.as_raw__InputArray(), pattern_size.opencv_as_extern(), corners.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -2204,9 +2896,9 @@ pub fn find_chessboard_corners(image: &dyn core::ToInputArray, pattern_size: cor
 /// ( patternSize = Size(points_per_row, points_per_colum) ).
 /// * centers: output array of detected centers.
 /// * flags: various operation flags that can be one of the following values:
-/// *   @ref CALIB_CB_SYMMETRIC_GRID uses symmetric pattern of circles.
-/// *   @ref CALIB_CB_ASYMMETRIC_GRID uses asymmetric pattern of circles.
-/// *   @ref CALIB_CB_CLUSTERING uses a special algorithm for grid detection. It is more robust to
+/// *   `CALIB_CB_SYMMETRIC_GRID` uses symmetric pattern of circles.
+/// *   `CALIB_CB_ASYMMETRIC_GRID` uses asymmetric pattern of circles.
+/// *   `CALIB_CB_CLUSTERING` uses a special algorithm for grid detection. It is more robust to
 /// perspective distortions but much more sensitive to background clutter.
 /// * blobDetector: feature detector that finds blobs like dark circles on light background.
 ///                    If `blobDetector` is NULL then `image` represents Point2f array of candidates.
@@ -2242,7 +2934,7 @@ pub fn find_circles_grid_1(image: &dyn core::ToInputArray, pattern_size: core::S
 	input_array_arg!(image);
 	output_array_arg!(centers);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_findCirclesGrid_const__InputArrayR_Size_const__OutputArrayR_int_const_Ptr_Feature2D_R(image.as_raw__InputArray(), pattern_size.opencv_as_extern(), centers.as_raw__OutputArray(), flags, blob_detector.as_raw_PtrOfFeature2D(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_findCirclesGrid_const__InputArrayR_Size_const__OutputArrayR_int_const_Ptr_Feature2D_R(image.as_raw__InputArray(), pattern_size.opencv_as_extern(), centers.as_raw__OutputArray(), flags, blob_detector.as_raw_PtrOfFeature2D(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -2256,9 +2948,9 @@ pub fn find_circles_grid_1(image: &dyn core::ToInputArray, pattern_size: core::S
 /// ( patternSize = Size(points_per_row, points_per_colum) ).
 /// * centers: output array of detected centers.
 /// * flags: various operation flags that can be one of the following values:
-/// *   @ref CALIB_CB_SYMMETRIC_GRID uses symmetric pattern of circles.
-/// *   @ref CALIB_CB_ASYMMETRIC_GRID uses asymmetric pattern of circles.
-/// *   @ref CALIB_CB_CLUSTERING uses a special algorithm for grid detection. It is more robust to
+/// *   `CALIB_CB_SYMMETRIC_GRID` uses symmetric pattern of circles.
+/// *   `CALIB_CB_ASYMMETRIC_GRID` uses asymmetric pattern of circles.
+/// *   `CALIB_CB_CLUSTERING` uses a special algorithm for grid detection. It is more robust to
 /// perspective distortions but much more sensitive to background clutter.
 /// * blobDetector: feature detector that finds blobs like dark circles on light background.
 ///                    If `blobDetector` is NULL then `image` represents Point2f array of candidates.
@@ -2288,7 +2980,7 @@ pub fn find_circles_grid(image: &dyn core::ToInputArray, pattern_size: core::Siz
 	input_array_arg!(image);
 	output_array_arg!(centers);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_findCirclesGrid_const__InputArrayR_Size_const__OutputArrayR_int_const_Ptr_Feature2D_R_const_CirclesGridFinderParametersR(image.as_raw__InputArray(), pattern_size.opencv_as_extern(), centers.as_raw__OutputArray(), flags, blob_detector.as_raw_PtrOfFeature2D(), &parameters, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_findCirclesGrid_const__InputArrayR_Size_const__OutputArrayR_int_const_Ptr_Feature2D_R_const_CirclesGridFinderParametersR(image.as_raw__InputArray(), pattern_size.opencv_as_extern(), centers.as_raw__OutputArray(), flags, blob_detector.as_raw_PtrOfFeature2D(), &parameters, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -2304,7 +2996,7 @@ pub fn find_essential_mat_4(points1: &dyn core::ToInputArray, points2: &dyn core
 	input_array_arg!(dist_coeff2);
 	output_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_findEssentialMat_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const_UsacParamsR(points1.as_raw__InputArray(), points2.as_raw__InputArray(), camera_matrix1.as_raw__InputArray(), camera_matrix2.as_raw__InputArray(), dist_coeff1.as_raw__InputArray(), dist_coeff2.as_raw__InputArray(), mask.as_raw__OutputArray(), &params, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_findEssentialMat_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const_UsacParamsR(points1.as_raw__InputArray(), points2.as_raw__InputArray(), camera_matrix1.as_raw__InputArray(), camera_matrix2.as_raw__InputArray(), dist_coeff1.as_raw__InputArray(), dist_coeff2.as_raw__InputArray(), mask.as_raw__OutputArray(), &params, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -2336,8 +3028,8 @@ pub fn find_essential_mat_4(points1: &dyn core::ToInputArray, points2: &dyn core
 /// ![inline formula](https://latex.codecogs.com/png.latex?%28k%5F1%2C%20k%5F2%2C%20p%5F1%2C%20p%5F2%5B%2C%20k%5F3%5B%2C%20k%5F4%2C%20k%5F5%2C%20k%5F6%5B%2C%20s%5F1%2C%20s%5F2%2C%20s%5F3%2C%20s%5F4%5B%2C%20%5Ctau%5Fx%2C%20%5Ctau%5Fy%5D%5D%5D%5D%29)
 /// of 4, 5, 8, 12 or 14 elements. If the vector is NULL/empty, the zero distortion coefficients are assumed.
 /// * method: Method for computing an essential matrix.
-/// *   @ref RANSAC for the RANSAC algorithm.
-/// *   @ref LMEDS for the LMedS algorithm.
+/// *   `RANSAC` for the RANSAC algorithm.
+/// *   `LMEDS` for the LMedS algorithm.
 /// * prob: Parameter used for the RANSAC or LMedS methods only. It specifies a desirable level of
 /// confidence (probability) that the estimated matrix is correct.
 /// * threshold: Parameter used for RANSAC. It is the maximum distance from a point to an epipolar
@@ -2371,13 +3063,65 @@ pub fn find_essential_mat_3(points1: &dyn core::ToInputArray, points2: &dyn core
 	input_array_arg!(dist_coeffs2);
 	output_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_findEssentialMat_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_double_double_const__OutputArrayR(points1.as_raw__InputArray(), points2.as_raw__InputArray(), camera_matrix1.as_raw__InputArray(), dist_coeffs1.as_raw__InputArray(), camera_matrix2.as_raw__InputArray(), dist_coeffs2.as_raw__InputArray(), method, prob, threshold, mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_findEssentialMat_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_double_double_const__OutputArrayR(points1.as_raw__InputArray(), points2.as_raw__InputArray(), camera_matrix1.as_raw__InputArray(), dist_coeffs1.as_raw__InputArray(), camera_matrix2.as_raw__InputArray(), dist_coeffs2.as_raw__InputArray(), method, prob, threshold, mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `find_essential_mat_3()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FindEssentialMat3Builder<'a> {
+	points1: &'a dyn core::ToInputArray,
+	points2: &'a dyn core::ToInputArray,
+	camera_matrix1: &'a dyn core::ToInputArray,
+	dist_coeffs1: &'a dyn core::ToInputArray,
+	camera_matrix2: &'a dyn core::ToInputArray,
+	dist_coeffs2: &'a dyn core::ToInputArray,
+	method: i32,
+	prob: f64,
+	threshold: f64,
+	mask: Option<&'a mut dyn core::ToOutputArray>,
+}
+
+impl<'a> FindEssentialMat3Builder<'a> {
+	#[inline]
+	pub fn new(points1: &'a dyn core::ToInputArray, points2: &'a dyn core::ToInputArray, camera_matrix1: &'a dyn core::ToInputArray, dist_coeffs1: &'a dyn core::ToInputArray, camera_matrix2: &'a dyn core::ToInputArray, dist_coeffs2: &'a dyn core::ToInputArray, method: i32) -> Self {
+		Self { points1, points2, camera_matrix1, dist_coeffs1, camera_matrix2, dist_coeffs2, method, prob: 0.999, threshold: 1.0, mask: None }
+	}
+	
+	/// C++ default: `0.999`
+	#[inline]
+	pub fn with_prob(mut self, prob: f64) -> Self {
+		self.prob = prob;
+		self
+	}
+	
+	/// C++ default: `1.0`
+	#[inline]
+	pub fn with_threshold(mut self, threshold: f64) -> Self {
+		self.threshold = threshold;
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_mask(mut self, mask: &'a mut dyn core::ToOutputArray) -> Self {
+		self.mask = Some(mask);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Mat> {
+		let mut mask_no_array = core::no_array();
+		let mask: &mut dyn core::ToOutputArray = match self.mask { Some(mask) => mask, None => &mut mask_no_array };
+		find_essential_mat_3(self.points1, self.points2, self.camera_matrix1, self.dist_coeffs1, self.camera_matrix2, self.dist_coeffs2, self.method, self.prob, self.threshold, mask)
+	}
+}
+
 /// Calculates an essential matrix from the corresponding points in two images from potentially two different cameras.
 /// 
 /// ## Parameters
@@ -2403,8 +3147,8 @@ pub fn find_essential_mat_3(points1: &dyn core::ToInputArray, points2: &dyn core
 /// ![inline formula](https://latex.codecogs.com/png.latex?%28k%5F1%2C%20k%5F2%2C%20p%5F1%2C%20p%5F2%5B%2C%20k%5F3%5B%2C%20k%5F4%2C%20k%5F5%2C%20k%5F6%5B%2C%20s%5F1%2C%20s%5F2%2C%20s%5F3%2C%20s%5F4%5B%2C%20%5Ctau%5Fx%2C%20%5Ctau%5Fy%5D%5D%5D%5D%29)
 /// of 4, 5, 8, 12 or 14 elements. If the vector is NULL/empty, the zero distortion coefficients are assumed.
 /// * method: Method for computing an essential matrix.
-/// *   @ref RANSAC for the RANSAC algorithm.
-/// *   @ref LMEDS for the LMedS algorithm.
+/// *   `RANSAC` for the RANSAC algorithm.
+/// *   `LMEDS` for the LMedS algorithm.
 /// * prob: Parameter used for the RANSAC or LMedS methods only. It specifies a desirable level of
 /// confidence (probability) that the estimated matrix is correct.
 /// * threshold: Parameter used for RANSAC. It is the maximum distance from a point to an epipolar
@@ -2431,7 +3175,7 @@ pub fn find_essential_mat_matrix(points1: &dyn core::ToInputArray, points2: &dyn
 	input_array_arg!(camera_matrix);
 	output_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_findEssentialMat_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_double_double_const__OutputArrayR(points1.as_raw__InputArray(), points2.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), method, prob, threshold, mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_findEssentialMat_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_double_double_const__OutputArrayR(points1.as_raw__InputArray(), points2.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), method, prob, threshold, mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -2451,8 +3195,8 @@ pub fn find_essential_mat_matrix(points1: &dyn core::ToInputArray, points2: &dyn
 /// to normalized image coordinates, which are valid for the identity camera intrinsic matrix. When
 /// passing these coordinates, pass the identity matrix for this parameter.
 /// * method: Method for computing an essential matrix.
-/// *   @ref RANSAC for the RANSAC algorithm.
-/// *   @ref LMEDS for the LMedS algorithm.
+/// *   `RANSAC` for the RANSAC algorithm.
+/// *   `LMEDS` for the LMedS algorithm.
 /// * prob: Parameter used for the RANSAC or LMedS methods only. It specifies a desirable level of
 /// confidence (probability) that the estimated matrix is correct.
 /// * threshold: Parameter used for RANSAC. It is the maximum distance from a point to an epipolar
@@ -2485,13 +3229,70 @@ pub fn find_essential_mat(points1: &dyn core::ToInputArray, points2: &dyn core::
 	input_array_arg!(camera_matrix);
 	output_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_findEssentialMat_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_double_double_int_const__OutputArrayR(points1.as_raw__InputArray(), points2.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), method, prob, threshold, max_iters, mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_findEssentialMat_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_double_double_int_const__OutputArrayR(points1.as_raw__InputArray(), points2.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), method, prob, threshold, max_iters, mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `find_essential_mat()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FindEssentialMatBuilder<'a> {
+	points1: &'a dyn core::ToInputArray,
+	points2: &'a dyn core::ToInputArray,
+	camera_matrix: &'a dyn core::ToInputArray,
+	method: i32,
+	prob: f64,
+	threshold: f64,
+	max_iters: i32,
+	mask: Option<&'a mut dyn core::ToOutputArray>,
+}
+
+impl<'a> FindEssentialMatBuilder<'a> {
+	#[inline]
+	pub fn new(points1: &'a dyn core::ToInputArray, points2: &'a dyn core::ToInputArray, camera_matrix: &'a dyn core::ToInputArray, method: i32) -> Self {
+		Self { points1, points2, camera_matrix, method, prob: 0.999, threshold: 1.0, max_iters: 1000, mask: None }
+	}
+	
+	/// C++ default: `0.999`
+	#[inline]
+	pub fn with_prob(mut self, prob: f64) -> Self {
+		self.prob = prob;
+		self
+	}
+	
+	/// C++ default: `1.0`
+	#[inline]
+	pub fn with_threshold(mut self, threshold: f64) -> Self {
+		self.threshold = threshold;
+		self
+	}
+	
+	/// C++ default: `1000`
+	#[inline]
+	pub fn with_max_iters(mut self, max_iters: i32) -> Self {
+		self.max_iters = max_iters;
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_mask(mut self, mask: &'a mut dyn core::ToOutputArray) -> Self {
+		self.mask = Some(mask);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Mat> {
+		let mut mask_no_array = core::no_array();
+		let mask: &mut dyn core::ToOutputArray = match self.mask { Some(mask) => mask, None => &mut mask_no_array };
+		find_essential_mat(self.points1, self.points2, self.camera_matrix, self.method, self.prob, self.threshold, self.max_iters, mask)
+	}
+}
+
 /// Calculates an essential matrix from the corresponding points in two images from potentially two different cameras.
 /// 
 /// ## Parameters
@@ -2517,8 +3318,8 @@ pub fn find_essential_mat(points1: &dyn core::ToInputArray, points2: &dyn core::
 /// ![inline formula](https://latex.codecogs.com/png.latex?%28k%5F1%2C%20k%5F2%2C%20p%5F1%2C%20p%5F2%5B%2C%20k%5F3%5B%2C%20k%5F4%2C%20k%5F5%2C%20k%5F6%5B%2C%20s%5F1%2C%20s%5F2%2C%20s%5F3%2C%20s%5F4%5B%2C%20%5Ctau%5Fx%2C%20%5Ctau%5Fy%5D%5D%5D%5D%29)
 /// of 4, 5, 8, 12 or 14 elements. If the vector is NULL/empty, the zero distortion coefficients are assumed.
 /// * method: Method for computing an essential matrix.
-/// *   @ref RANSAC for the RANSAC algorithm.
-/// *   @ref LMEDS for the LMedS algorithm.
+/// *   `RANSAC` for the RANSAC algorithm.
+/// *   `LMEDS` for the LMedS algorithm.
 /// * prob: Parameter used for the RANSAC or LMedS methods only. It specifies a desirable level of
 /// confidence (probability) that the estimated matrix is correct.
 /// * threshold: Parameter used for RANSAC. It is the maximum distance from a point to an epipolar
@@ -2544,7 +3345,7 @@ pub fn find_essential_mat_2(points1: &dyn core::ToInputArray, points2: &dyn core
 	input_array_arg!(points2);
 	output_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_findEssentialMat_const__InputArrayR_const__InputArrayR_double_Point2d_int_double_double_const__OutputArrayR(points1.as_raw__InputArray(), points2.as_raw__InputArray(), focal, pp.opencv_as_extern(), method, prob, threshold, mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_findEssentialMat_const__InputArrayR_const__InputArrayR_double_Point2d_int_double_double_const__OutputArrayR(points1.as_raw__InputArray(), points2.as_raw__InputArray(), focal, pp.opencv_as_extern(), method, prob, threshold, mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -2576,8 +3377,8 @@ pub fn find_essential_mat_2(points1: &dyn core::ToInputArray, points2: &dyn core
 /// ![inline formula](https://latex.codecogs.com/png.latex?%28k%5F1%2C%20k%5F2%2C%20p%5F1%2C%20p%5F2%5B%2C%20k%5F3%5B%2C%20k%5F4%2C%20k%5F5%2C%20k%5F6%5B%2C%20s%5F1%2C%20s%5F2%2C%20s%5F3%2C%20s%5F4%5B%2C%20%5Ctau%5Fx%2C%20%5Ctau%5Fy%5D%5D%5D%5D%29)
 /// of 4, 5, 8, 12 or 14 elements. If the vector is NULL/empty, the zero distortion coefficients are assumed.
 /// * method: Method for computing an essential matrix.
-/// *   @ref RANSAC for the RANSAC algorithm.
-/// *   @ref LMEDS for the LMedS algorithm.
+/// *   `RANSAC` for the RANSAC algorithm.
+/// *   `LMEDS` for the LMedS algorithm.
 /// * prob: Parameter used for the RANSAC or LMedS methods only. It specifies a desirable level of
 /// confidence (probability) that the estimated matrix is correct.
 /// * threshold: Parameter used for RANSAC. It is the maximum distance from a point to an epipolar
@@ -2605,8 +3406,8 @@ pub fn find_essential_mat_2(points1: &dyn core::ToInputArray, points2: &dyn core
 /// are feature points from cameras with same focal length and principal point.
 /// * pp: principal point of the camera.
 /// * method: Method for computing a fundamental matrix.
-/// *   @ref RANSAC for the RANSAC algorithm.
-/// *   @ref LMEDS for the LMedS algorithm.
+/// *   `RANSAC` for the RANSAC algorithm.
+/// *   `LMEDS` for the LMedS algorithm.
 /// * threshold: Parameter used for RANSAC. It is the maximum distance from a point to an epipolar
 /// line in pixels, beyond which the point is considered an outlier and is not used for computing the
 /// final fundamental matrix. It can be set to something like 1-3, depending on the accuracy of the
@@ -2636,20 +3437,92 @@ pub fn find_essential_mat_1(points1: &dyn core::ToInputArray, points2: &dyn core
 	input_array_arg!(points2);
 	output_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_findEssentialMat_const__InputArrayR_const__InputArrayR_double_Point2d_int_double_double_int_const__OutputArrayR(points1.as_raw__InputArray(), points2.as_raw__InputArray(), focal, pp.opencv_as_extern(), method, prob, threshold, max_iters, mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_findEssentialMat_const__InputArrayR_const__InputArrayR_double_Point2d_int_double_double_int_const__OutputArrayR(points1.as_raw__InputArray(), points2.as_raw__InputArray(), focal, pp.opencv_as_extern(), method, prob, threshold, max_iters, mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `find_essential_mat_1()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FindEssentialMat1Builder<'a> {
+	points1: &'a dyn core::ToInputArray,
+	points2: &'a dyn core::ToInputArray,
+	focal: f64,
+	pp: core::Point2d,
+	method: i32,
+	prob: f64,
+	threshold: f64,
+	max_iters: i32,
+	mask: Option<&'a mut dyn core::ToOutputArray>,
+}
+
+impl<'a> FindEssentialMat1Builder<'a> {
+	#[inline]
+	pub fn new(points1: &'a dyn core::ToInputArray, points2: &'a dyn core::ToInputArray, method: i32) -> Self {
+		Self { points1, points2, focal: 1.0, pp: core::Point2d::new(0., 0.), method, prob: 0.999, threshold: 1.0, max_iters: 1000, mask: None }
+	}
+	
+	/// C++ default: `1.0`
+	#[inline]
+	pub fn with_focal(mut self, focal: f64) -> Self {
+		self.focal = focal;
+		self
+	}
+	
+	/// C++ default: `Point2d(0,0)`
+	#[inline]
+	pub fn with_pp(mut self, pp: core::Point2d) -> Self {
+		self.pp = pp;
+		self
+	}
+	
+	/// C++ default: `0.999`
+	#[inline]
+	pub fn with_prob(mut self, prob: f64) -> Self {
+		self.prob = prob;
+		self
+	}
+	
+	/// C++ default: `1.0`
+	#[inline]
+	pub fn with_threshold(mut self, threshold: f64) -> Self {
+		self.threshold = threshold;
+		self
+	}
+	
+	/// C++ default: `1000`
+	#[inline]
+	pub fn with_max_iters(mut self, max_iters: i32) -> Self {
+		self.max_iters = max_iters;
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_mask(mut self, mask: &'a mut dyn core::ToOutputArray) -> Self {
+		self.mask = Some(mask);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Mat> {
+		let mut mask_no_array = core::no_array();
+		let mask: &mut dyn core::ToOutputArray = match self.mask { Some(mask) => mask, None => &mut mask_no_array };
+		find_essential_mat_1(self.points1, self.points2, self.focal, self.pp, self.method, self.prob, self.threshold, self.max_iters, mask)
+	}
+}
+
 #[inline]
 pub fn find_fundamental_mat_2(points1: &dyn core::ToInputArray, points2: &dyn core::ToInputArray, mask: &mut dyn core::ToOutputArray, params: crate::calib3d::UsacParams) -> Result<core::Mat> {
 	input_array_arg!(points1);
 	input_array_arg!(points2);
 	output_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_findFundamentalMat_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const_UsacParamsR(points1.as_raw__InputArray(), points2.as_raw__InputArray(), mask.as_raw__OutputArray(), &params, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_findFundamentalMat_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const_UsacParamsR(points1.as_raw__InputArray(), points2.as_raw__InputArray(), mask.as_raw__OutputArray(), &params, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -2663,17 +3536,17 @@ pub fn find_fundamental_mat_2(points1: &dyn core::ToInputArray, points2: &dyn co
 /// floating-point (single or double precision).
 /// * points2: Array of the second image points of the same size and format as points1 .
 /// * method: Method for computing a fundamental matrix.
-/// *   @ref FM_7POINT for a 7-point algorithm. ![inline formula](https://latex.codecogs.com/png.latex?N%20%3D%207)
-/// *   @ref FM_8POINT for an 8-point algorithm. ![inline formula](https://latex.codecogs.com/png.latex?N%20%5Cge%208)
-/// *   @ref FM_RANSAC for the RANSAC algorithm. ![inline formula](https://latex.codecogs.com/png.latex?N%20%5Cge%208)
-/// *   @ref FM_LMEDS for the LMedS algorithm. ![inline formula](https://latex.codecogs.com/png.latex?N%20%5Cge%208)
+/// *   `FM_7POINT` for a 7-point algorithm. ![inline formula](https://latex.codecogs.com/png.latex?N%20%3D%207)
+/// *   `FM_8POINT` for an 8-point algorithm. ![inline formula](https://latex.codecogs.com/png.latex?N%20%5Cge%208)
+/// *   `FM_RANSAC` for the RANSAC algorithm. ![inline formula](https://latex.codecogs.com/png.latex?N%20%5Cge%208)
+/// *   `FM_LMEDS` for the LMedS algorithm. ![inline formula](https://latex.codecogs.com/png.latex?N%20%5Cge%208)
 /// * ransacReprojThreshold: Parameter used only for RANSAC. It is the maximum distance from a point to an epipolar
 /// line in pixels, beyond which the point is considered an outlier and is not used for computing the
 /// final fundamental matrix. It can be set to something like 1-3, depending on the accuracy of the
 /// point localization, image resolution, and the image noise.
 /// * confidence: Parameter used for the RANSAC and LMedS methods only. It specifies a desirable level
 /// of confidence (probability) that the estimated matrix is correct.
-/// * mask:[out] optional output mask
+/// * mask:\[out\] optional output mask
 /// * maxIters: The maximum number of robust method iterations.
 /// 
 /// The epipolar geometry is described by the following equation:
@@ -2721,13 +3594,52 @@ pub fn find_fundamental_mat_mask(points1: &dyn core::ToInputArray, points2: &dyn
 	input_array_arg!(points2);
 	output_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_findFundamentalMat_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_double_double(points1.as_raw__InputArray(), points2.as_raw__InputArray(), mask.as_raw__OutputArray(), method, ransac_reproj_threshold, confidence, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_findFundamentalMat_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_double_double(points1.as_raw__InputArray(), points2.as_raw__InputArray(), mask.as_raw__OutputArray(), method, ransac_reproj_threshold, confidence, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `find_fundamental_mat_mask()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FindFundamentalMatMaskBuilder<'a> {
+	points1: &'a dyn core::ToInputArray,
+	points2: &'a dyn core::ToInputArray,
+	mask: &'a mut dyn core::ToOutputArray,
+	method: i32,
+	ransac_reproj_threshold: f64,
+	confidence: f64,
+}
+
+impl<'a> FindFundamentalMatMaskBuilder<'a> {
+	#[inline]
+	pub fn new(points1: &'a dyn core::ToInputArray, points2: &'a dyn core::ToInputArray, mask: &'a mut dyn core::ToOutputArray, method: i32) -> Self {
+		Self { points1, points2, mask, method, ransac_reproj_threshold: 3., confidence: 0.99 }
+	}
+	
+	/// C++ default: `3.`
+	#[inline]
+	pub fn with_ransac_reproj_threshold(mut self, ransac_reproj_threshold: f64) -> Self {
+		self.ransac_reproj_threshold = ransac_reproj_threshold;
+		self
+	}
+	
+	/// C++ default: `0.99`
+	#[inline]
+	pub fn with_confidence(mut self, confidence: f64) -> Self {
+		self.confidence = confidence;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Mat> {
+		find_fundamental_mat_mask(self.points1, self.points2, self.mask, self.method, self.ransac_reproj_threshold, self.confidence)
+	}
+}
+
 /// Calculates a fundamental matrix from the corresponding points in two images.
 /// 
 /// ## Parameters
@@ -2735,17 +3647,17 @@ pub fn find_fundamental_mat_mask(points1: &dyn core::ToInputArray, points2: &dyn
 /// floating-point (single or double precision).
 /// * points2: Array of the second image points of the same size and format as points1 .
 /// * method: Method for computing a fundamental matrix.
-/// *   @ref FM_7POINT for a 7-point algorithm. ![inline formula](https://latex.codecogs.com/png.latex?N%20%3D%207)
-/// *   @ref FM_8POINT for an 8-point algorithm. ![inline formula](https://latex.codecogs.com/png.latex?N%20%5Cge%208)
-/// *   @ref FM_RANSAC for the RANSAC algorithm. ![inline formula](https://latex.codecogs.com/png.latex?N%20%5Cge%208)
-/// *   @ref FM_LMEDS for the LMedS algorithm. ![inline formula](https://latex.codecogs.com/png.latex?N%20%5Cge%208)
+/// *   `FM_7POINT` for a 7-point algorithm. ![inline formula](https://latex.codecogs.com/png.latex?N%20%3D%207)
+/// *   `FM_8POINT` for an 8-point algorithm. ![inline formula](https://latex.codecogs.com/png.latex?N%20%5Cge%208)
+/// *   `FM_RANSAC` for the RANSAC algorithm. ![inline formula](https://latex.codecogs.com/png.latex?N%20%5Cge%208)
+/// *   `FM_LMEDS` for the LMedS algorithm. ![inline formula](https://latex.codecogs.com/png.latex?N%20%5Cge%208)
 /// * ransacReprojThreshold: Parameter used only for RANSAC. It is the maximum distance from a point to an epipolar
 /// line in pixels, beyond which the point is considered an outlier and is not used for computing the
 /// final fundamental matrix. It can be set to something like 1-3, depending on the accuracy of the
 /// point localization, image resolution, and the image noise.
 /// * confidence: Parameter used for the RANSAC and LMedS methods only. It specifies a desirable level
 /// of confidence (probability) that the estimated matrix is correct.
-/// * mask:[out] optional output mask
+/// * mask:\[out\] optional output mask
 /// * maxIters: The maximum number of robust method iterations.
 /// 
 /// The epipolar geometry is described by the following equation:
@@ -2794,13 +3706,61 @@ pub fn find_fundamental_mat_1(points1: &dyn core::ToInputArray, points2: &dyn co
 	input_array_arg!(points2);
 	output_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_findFundamentalMat_const__InputArrayR_const__InputArrayR_int_double_double_const__OutputArrayR(points1.as_raw__InputArray(), points2.as_raw__InputArray(), method, ransac_reproj_threshold, confidence, mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_findFundamentalMat_const__InputArrayR_const__InputArrayR_int_double_double_const__OutputArrayR(points1.as_raw__InputArray(), points2.as_raw__InputArray(), method, ransac_reproj_threshold, confidence, mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `find_fundamental_mat_1()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FindFundamentalMat1Builder<'a> {
+	points1: &'a dyn core::ToInputArray,
+	points2: &'a dyn core::ToInputArray,
+	method: i32,
+	ransac_reproj_threshold: f64,
+	confidence: f64,
+	mask: Option<&'a mut dyn core::ToOutputArray>,
+}
+
+impl<'a> FindFundamentalMat1Builder<'a> {
+	#[inline]
+	pub fn new(points1: &'a dyn core::ToInputArray, points2: &'a dyn core::ToInputArray, method: i32) -> Self {
+		Self { points1, points2, method, ransac_reproj_threshold: 3., confidence: 0.99, mask: None }
+	}
+	
+	/// C++ default: `3.`
+	#[inline]
+	pub fn with_ransac_reproj_threshold(mut self, ransac_reproj_threshold: f64) -> Self {
+		self.ransac_reproj_threshold = ransac_reproj_threshold;
+		self
+	}
+	
+	/// C++ default: `0.99`
+	#[inline]
+	pub fn with_confidence(mut self, confidence: f64) -> Self {
+		self.confidence = confidence;
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_mask(mut self, mask: &'a mut dyn core::ToOutputArray) -> Self {
+		self.mask = Some(mask);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Mat> {
+		let mut mask_no_array = core::no_array();
+		let mask: &mut dyn core::ToOutputArray = match self.mask { Some(mask) => mask, None => &mut mask_no_array };
+		find_fundamental_mat_1(self.points1, self.points2, self.method, self.ransac_reproj_threshold, self.confidence, mask)
+	}
+}
+
 /// Calculates a fundamental matrix from the corresponding points in two images.
 /// 
 /// ## Parameters
@@ -2808,17 +3768,17 @@ pub fn find_fundamental_mat_1(points1: &dyn core::ToInputArray, points2: &dyn co
 /// floating-point (single or double precision).
 /// * points2: Array of the second image points of the same size and format as points1 .
 /// * method: Method for computing a fundamental matrix.
-/// *   @ref FM_7POINT for a 7-point algorithm. ![inline formula](https://latex.codecogs.com/png.latex?N%20%3D%207)
-/// *   @ref FM_8POINT for an 8-point algorithm. ![inline formula](https://latex.codecogs.com/png.latex?N%20%5Cge%208)
-/// *   @ref FM_RANSAC for the RANSAC algorithm. ![inline formula](https://latex.codecogs.com/png.latex?N%20%5Cge%208)
-/// *   @ref FM_LMEDS for the LMedS algorithm. ![inline formula](https://latex.codecogs.com/png.latex?N%20%5Cge%208)
+/// *   `FM_7POINT` for a 7-point algorithm. ![inline formula](https://latex.codecogs.com/png.latex?N%20%3D%207)
+/// *   `FM_8POINT` for an 8-point algorithm. ![inline formula](https://latex.codecogs.com/png.latex?N%20%5Cge%208)
+/// *   `FM_RANSAC` for the RANSAC algorithm. ![inline formula](https://latex.codecogs.com/png.latex?N%20%5Cge%208)
+/// *   `FM_LMEDS` for the LMedS algorithm. ![inline formula](https://latex.codecogs.com/png.latex?N%20%5Cge%208)
 /// * ransacReprojThreshold: Parameter used only for RANSAC. It is the maximum distance from a point to an epipolar
 /// line in pixels, beyond which the point is considered an outlier and is not used for computing the
 /// final fundamental matrix. It can be set to something like 1-3, depending on the accuracy of the
 /// point localization, image resolution, and the image noise.
 /// * confidence: Parameter used for the RANSAC and LMedS methods only. It specifies a desirable level
 /// of confidence (probability) that the estimated matrix is correct.
-/// * mask:[out] optional output mask
+/// * mask:\[out\] optional output mask
 /// * maxIters: The maximum number of robust method iterations.
 /// 
 /// The epipolar geometry is described by the following equation:
@@ -2862,20 +3822,55 @@ pub fn find_fundamental_mat(points1: &dyn core::ToInputArray, points2: &dyn core
 	input_array_arg!(points2);
 	output_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_findFundamentalMat_const__InputArrayR_const__InputArrayR_int_double_double_int_const__OutputArrayR(points1.as_raw__InputArray(), points2.as_raw__InputArray(), method, ransac_reproj_threshold, confidence, max_iters, mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_findFundamentalMat_const__InputArrayR_const__InputArrayR_int_double_double_int_const__OutputArrayR(points1.as_raw__InputArray(), points2.as_raw__InputArray(), method, ransac_reproj_threshold, confidence, max_iters, mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `find_fundamental_mat()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FindFundamentalMatBuilder<'a> {
+	points1: &'a dyn core::ToInputArray,
+	points2: &'a dyn core::ToInputArray,
+	method: i32,
+	ransac_reproj_threshold: f64,
+	confidence: f64,
+	max_iters: i32,
+	mask: Option<&'a mut dyn core::ToOutputArray>,
+}
+
+impl<'a> FindFundamentalMatBuilder<'a> {
+	#[inline]
+	pub fn new(points1: &'a dyn core::ToInputArray, points2: &'a dyn core::ToInputArray, method: i32, ransac_reproj_threshold: f64, confidence: f64, max_iters: i32) -> Self {
+		Self { points1, points2, method, ransac_reproj_threshold, confidence, max_iters, mask: None }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_mask(mut self, mask: &'a mut dyn core::ToOutputArray) -> Self {
+		self.mask = Some(mask);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Mat> {
+		let mut mask_no_array = core::no_array();
+		let mask: &mut dyn core::ToOutputArray = match self.mask { Some(mask) => mask, None => &mut mask_no_array };
+		find_fundamental_mat(self.points1, self.points2, self.method, self.ransac_reproj_threshold, self.confidence, self.max_iters, mask)
+	}
+}
+
 #[inline]
 pub fn find_homography_1(src_points: &dyn core::ToInputArray, dst_points: &dyn core::ToInputArray, mask: &mut dyn core::ToOutputArray, params: crate::calib3d::UsacParams) -> Result<core::Mat> {
 	input_array_arg!(src_points);
 	input_array_arg!(dst_points);
 	output_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_findHomography_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const_UsacParamsR(src_points.as_raw__InputArray(), dst_points.as_raw__InputArray(), mask.as_raw__OutputArray(), &params, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_findHomography_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const_UsacParamsR(src_points.as_raw__InputArray(), dst_points.as_raw__InputArray(), mask.as_raw__OutputArray(), &params, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -2891,9 +3886,9 @@ pub fn find_homography_1(src_points: &dyn core::ToInputArray, dst_points: &dyn c
 /// a vector\<Point2f\> .
 /// * method: Method used to compute a homography matrix. The following methods are possible:
 /// *   **0** - a regular method using all the points, i.e., the least squares method
-/// *   @ref RANSAC - RANSAC-based robust method
-/// *   @ref LMEDS - Least-Median robust method
-/// *   @ref RHO - PROSAC-based robust method
+/// *   `RANSAC` - RANSAC-based robust method
+/// *   `LMEDS` - Least-Median robust method
+/// *   `RHO` - PROSAC-based robust method
 /// * ransacReprojThreshold: Maximum allowed reprojection error to treat a point pair as an inlier
 /// (used in the RANSAC and RHO methods only). That is, if
 /// ![block formula](https://latex.codecogs.com/png.latex?%5C%7C%20%5Ctexttt%7BdstPoints%7D%20%5Fi%20%2D%20%20%5Ctexttt%7BconvertPointsHomogeneous%7D%20%28%20%5Ctexttt%7BH%7D%20%2A%20%5Ctexttt%7BsrcPoints%7D%20%5Fi%29%20%5C%7C%5F2%20%20%3E%20%20%5Ctexttt%7BransacReprojThreshold%7D)
@@ -2938,8 +3933,8 @@ pub fn find_homography_1(src_points: &dyn core::ToInputArray, dst_points: &dyn c
 /// determined up to a scale. Thus, it is normalized so that ![inline formula](https://latex.codecogs.com/png.latex?h%5F%7B33%7D%3D1). Note that whenever an ![inline formula](https://latex.codecogs.com/png.latex?H) matrix
 /// cannot be estimated, an empty one will be returned.
 /// ## See also
-/// getAffineTransform, estimateAffine2D, estimateAffinePartial2D, getPerspectiveTransform, warpPerspective,
-/// perspectiveTransform
+/// `get_affine_transform`, [estimate_affine_2d](crate::calib3d::estimate_affine_2d), [estimate_affine_partial_2d](crate::calib3d::estimate_affine_partial_2d), `get_perspective_transform`, `warp_perspective`,
+/// [perspective_transform](crate::core::perspective_transform)
 /// 
 /// ## Overloaded parameters
 /// 
@@ -2952,7 +3947,7 @@ pub fn find_homography(src_points: &dyn core::ToInputArray, dst_points: &dyn cor
 	input_array_arg!(dst_points);
 	output_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_findHomography_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_double(src_points.as_raw__InputArray(), dst_points.as_raw__InputArray(), mask.as_raw__OutputArray(), method, ransac_reproj_threshold, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_findHomography_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_double(src_points.as_raw__InputArray(), dst_points.as_raw__InputArray(), mask.as_raw__OutputArray(), method, ransac_reproj_threshold, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -2968,9 +3963,9 @@ pub fn find_homography(src_points: &dyn core::ToInputArray, dst_points: &dyn cor
 /// a vector\<Point2f\> .
 /// * method: Method used to compute a homography matrix. The following methods are possible:
 /// *   **0** - a regular method using all the points, i.e., the least squares method
-/// *   @ref RANSAC - RANSAC-based robust method
-/// *   @ref LMEDS - Least-Median robust method
-/// *   @ref RHO - PROSAC-based robust method
+/// *   `RANSAC` - RANSAC-based robust method
+/// *   `LMEDS` - Least-Median robust method
+/// *   `RHO` - PROSAC-based robust method
 /// * ransacReprojThreshold: Maximum allowed reprojection error to treat a point pair as an inlier
 /// (used in the RANSAC and RHO methods only). That is, if
 /// ![block formula](https://latex.codecogs.com/png.latex?%5C%7C%20%5Ctexttt%7BdstPoints%7D%20%5Fi%20%2D%20%20%5Ctexttt%7BconvertPointsHomogeneous%7D%20%28%20%5Ctexttt%7BH%7D%20%2A%20%5Ctexttt%7BsrcPoints%7D%20%5Fi%29%20%5C%7C%5F2%20%20%3E%20%20%5Ctexttt%7BransacReprojThreshold%7D)
@@ -3015,8 +4010,8 @@ pub fn find_homography(src_points: &dyn core::ToInputArray, dst_points: &dyn cor
 /// determined up to a scale. Thus, it is normalized so that ![inline formula](https://latex.codecogs.com/png.latex?h%5F%7B33%7D%3D1). Note that whenever an ![inline formula](https://latex.codecogs.com/png.latex?H) matrix
 /// cannot be estimated, an empty one will be returned.
 /// ## See also
-/// getAffineTransform, estimateAffine2D, estimateAffinePartial2D, getPerspectiveTransform, warpPerspective,
-/// perspectiveTransform
+/// `get_affine_transform`, [estimate_affine_2d](crate::calib3d::estimate_affine_2d), [estimate_affine_partial_2d](crate::calib3d::estimate_affine_partial_2d), `get_perspective_transform`, `warp_perspective`,
+/// [perspective_transform](crate::core::perspective_transform)
 /// 
 /// ## C++ default parameters
 /// * method: 0
@@ -3030,25 +4025,88 @@ pub fn find_homography_ext(src_points: &dyn core::ToInputArray, dst_points: &dyn
 	input_array_arg!(dst_points);
 	output_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_findHomography_const__InputArrayR_const__InputArrayR_int_double_const__OutputArrayR_const_int_const_double(src_points.as_raw__InputArray(), dst_points.as_raw__InputArray(), method, ransac_reproj_threshold, mask.as_raw__OutputArray(), max_iters, confidence, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_findHomography_const__InputArrayR_const__InputArrayR_int_double_const__OutputArrayR_const_int_const_double(src_points.as_raw__InputArray(), dst_points.as_raw__InputArray(), method, ransac_reproj_threshold, mask.as_raw__OutputArray(), max_iters, confidence, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `find_homography_ext()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FindHomographyExtBuilder<'a> {
+	src_points: &'a dyn core::ToInputArray,
+	dst_points: &'a dyn core::ToInputArray,
+	method: i32,
+	ransac_reproj_threshold: f64,
+	mask: Option<&'a mut dyn core::ToOutputArray>,
+	max_iters: i32,
+	confidence: f64,
+}
+
+impl<'a> FindHomographyExtBuilder<'a> {
+	#[inline]
+	pub fn new(src_points: &'a dyn core::ToInputArray, dst_points: &'a dyn core::ToInputArray) -> Self {
+		Self { src_points, dst_points, method: 0, ransac_reproj_threshold: 3., mask: None, max_iters: 2000, confidence: 0.995 }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_method(mut self, method: i32) -> Self {
+		self.method = method;
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_ransac_reproj_threshold(mut self, ransac_reproj_threshold: f64) -> Self {
+		self.ransac_reproj_threshold = ransac_reproj_threshold;
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_mask(mut self, mask: &'a mut dyn core::ToOutputArray) -> Self {
+		self.mask = Some(mask);
+		self
+	}
+	
+	/// C++ default: `2000`
+	#[inline]
+	pub fn with_max_iters(mut self, max_iters: i32) -> Self {
+		self.max_iters = max_iters;
+		self
+	}
+	
+	/// C++ default: `0.995`
+	#[inline]
+	pub fn with_confidence(mut self, confidence: f64) -> Self {
+		self.confidence = confidence;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Mat> {
+		let mut mask_no_array = core::no_array();
+		let mask: &mut dyn core::ToOutputArray = match self.mask { Some(mask) => mask, None => &mut mask_no_array };
+		find_homography_ext(self.src_points, self.dst_points, self.method, self.ransac_reproj_threshold, mask, self.max_iters, self.confidence)
+	}
+}
+
 /// Performs camera calibaration
 /// 
 /// ## Parameters
 /// * objectPoints: vector of vectors of calibration pattern points in the calibration pattern
 ///    coordinate space.
 /// * imagePoints: vector of vectors of the projections of calibration pattern points.
-///    imagePoints.size() and objectPoints.size() and imagePoints[i].size() must be equal to
-///    objectPoints[i].size() for each i.
+///    imagePoints.size() and objectPoints.size() and imagePoints\[i\].size() must be equal to
+///    objectPoints\[i\].size() for each i.
 /// * image_size: Size of the image used only to initialize the camera intrinsic matrix.
 /// * K: Output 3x3 floating-point camera intrinsic matrix
 ///    ![inline formula](https://latex.codecogs.com/png.latex?%5Ccameramatrix%7BA%7D) . If
-///    @ref fisheye::CALIB_USE_INTRINSIC_GUESS is specified, some or all of fx, fy, cx, cy must be
+///    fisheye::CALIB_USE_INTRINSIC_GUESS is specified, some or all of fx, fy, cx, cy must be
 ///    initialized before calling the function.
 /// * D: Output vector of distortion coefficients ![inline formula](https://latex.codecogs.com/png.latex?%5Cdistcoeffsfisheye).
 /// * rvecs: Output vector of rotation vectors (see Rodrigues ) estimated for each pattern view.
@@ -3058,19 +4116,19 @@ pub fn find_homography_ext(src_points: &dyn core::ToInputArray, dst_points: &dyn
 ///    position of the calibration pattern in the k-th pattern view (k=0.. *M* -1).
 /// * tvecs: Output vector of translation vectors estimated for each pattern view.
 /// * flags: Different flags that may be zero or a combination of the following values:
-///    *    @ref fisheye::CALIB_USE_INTRINSIC_GUESS  cameraMatrix contains valid initial values of
+///    *    fisheye::CALIB_USE_INTRINSIC_GUESS  cameraMatrix contains valid initial values of
 ///    fx, fy, cx, cy that are optimized further. Otherwise, (cx, cy) is initially set to the image
 ///    center ( imageSize is used), and focal distances are computed in a least-squares fashion.
-///    *    @ref fisheye::CALIB_RECOMPUTE_EXTRINSIC  Extrinsic will be recomputed after each iteration
+///    *    fisheye::CALIB_RECOMPUTE_EXTRINSIC  Extrinsic will be recomputed after each iteration
 ///    of intrinsic optimization.
-///    *    @ref fisheye::CALIB_CHECK_COND  The functions will check validity of condition number.
-///    *    @ref fisheye::CALIB_FIX_SKEW  Skew coefficient (alpha) is set to zero and stay zero.
-///    *    @ref fisheye::CALIB_FIX_K1,..., @ref fisheye::CALIB_FIX_K4 Selected distortion coefficients
+///    *    fisheye::CALIB_CHECK_COND  The functions will check validity of condition number.
+///    *    fisheye::CALIB_FIX_SKEW  Skew coefficient (alpha) is set to zero and stay zero.
+///    *    fisheye::CALIB_FIX_K1,..., fisheye::CALIB_FIX_K4 Selected distortion coefficients
 ///    are set to zeros and stay zero.
-///    *    @ref fisheye::CALIB_FIX_PRINCIPAL_POINT  The principal point is not changed during the global
-/// optimization. It stays at the center or at a different location specified when @ref fisheye::CALIB_USE_INTRINSIC_GUESS is set too.
-///    *    @ref fisheye::CALIB_FIX_FOCAL_LENGTH The focal length is not changed during the global
-/// optimization. It is the ![inline formula](https://latex.codecogs.com/png.latex?max%28width%2Cheight%29%2F%5Cpi) or the provided ![inline formula](https://latex.codecogs.com/png.latex?f%5Fx), ![inline formula](https://latex.codecogs.com/png.latex?f%5Fy) when @ref fisheye::CALIB_USE_INTRINSIC_GUESS is set too.
+///    *    fisheye::CALIB_FIX_PRINCIPAL_POINT  The principal point is not changed during the global
+/// optimization. It stays at the center or at a different location specified when fisheye::CALIB_USE_INTRINSIC_GUESS is set too.
+///    *    fisheye::CALIB_FIX_FOCAL_LENGTH The focal length is not changed during the global
+/// optimization. It is the ![inline formula](https://latex.codecogs.com/png.latex?max%28width%2Cheight%29%2F%5Cpi) or the provided ![inline formula](https://latex.codecogs.com/png.latex?f%5Fx), ![inline formula](https://latex.codecogs.com/png.latex?f%5Fy) when fisheye::CALIB_USE_INTRINSIC_GUESS is set too.
 /// * criteria: Termination criteria for the iterative optimization algorithm.
 /// 
 /// ## C++ default parameters
@@ -3085,12 +4143,47 @@ pub fn calibrate(object_points: &dyn core::ToInputArray, image_points: &dyn core
 	output_array_arg!(rvecs);
 	output_array_arg!(tvecs);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_fisheye_calibrate_const__InputArrayR_const__InputArrayR_const_SizeR_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_int_TermCriteria(object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), &image_size, k.as_raw__InputOutputArray(), d.as_raw__InputOutputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_fisheye_calibrate_const__InputArrayR_const__InputArrayR_const_SizeR_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_int_TermCriteria(object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), &image_size, k.as_raw__InputOutputArray(), d.as_raw__InputOutputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `calibrate()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CalibrateBuilder<'a> {
+	object_points: &'a dyn core::ToInputArray,
+	image_points: &'a dyn core::ToInputArray,
+	image_size: core::Size,
+	k: &'a mut dyn core::ToInputOutputArray,
+	d: &'a mut dyn core::ToInputOutputArray,
+	rvecs: &'a mut dyn core::ToOutputArray,
+	tvecs: &'a mut dyn core::ToOutputArray,
+	flags: i32,
+	criteria: core::TermCriteria,
+}
+
+impl<'a> CalibrateBuilder<'a> {
+	#[inline]
+	pub fn new(object_points: &'a dyn core::ToInputArray, image_points: &'a dyn core::ToInputArray, image_size: core::Size, k: &'a mut dyn core::ToInputOutputArray, d: &'a mut dyn core::ToInputOutputArray, rvecs: &'a mut dyn core::ToOutputArray, tvecs: &'a mut dyn core::ToOutputArray, criteria: core::TermCriteria) -> Self {
+		Self { object_points, image_points, image_size, k, d, rvecs, tvecs, flags: 0, criteria }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_flags(mut self, flags: i32) -> Self {
+		self.flags = flags;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<f64> {
+		calibrate(self.object_points, self.image_points, self.image_size, self.k, self.d, self.rvecs, self.tvecs, self.flags, self.criteria)
+	}
+}
+
 /// Distorts 2D points using fisheye model.
 /// 
 /// ## Parameters
@@ -3114,7 +4207,7 @@ pub fn distort_points(undistorted: &dyn core::ToInputArray, distorted: &mut dyn
 	input_array_arg!(k);
 	input_array_arg!(d);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_fisheye_distortPoints_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_double(undistorted.as_raw__InputArray(), distorted.as_raw__OutputArray(), k.as_raw__InputArray(), d.as_raw__InputArray(), alpha, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_fisheye_distortPoints_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_double(undistorted.as_raw__InputArray(), distorted.as_raw__OutputArray(), k.as_raw__InputArray(), d.as_raw__InputArray(), alpha, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -3130,7 +4223,7 @@ pub fn distort_points(undistorted: &dyn core::ToInputArray, distorted: &mut dyn
 /// 1-channel or 1x1 3-channel
 /// * P: New camera intrinsic matrix (3x3) or new projection matrix (3x4)
 /// * balance: Sets the new focal length in range between the min focal length and the max focal
-/// length. Balance is in range of [0, 1].
+/// length. Balance is in range of \[0, 1\].
 /// * new_size: the new size
 /// * fov_scale: Divisor for new focal length.
 /// 
@@ -3145,12 +4238,60 @@ pub fn estimate_new_camera_matrix_for_undistort_rectify(k: &dyn core::ToInputArr
 	input_array_arg!(r);
 	output_array_arg!(p);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_fisheye_estimateNewCameraMatrixForUndistortRectify_const__InputArrayR_const__InputArrayR_const_SizeR_const__InputArrayR_const__OutputArrayR_double_const_SizeR_double(k.as_raw__InputArray(), d.as_raw__InputArray(), &image_size, r.as_raw__InputArray(), p.as_raw__OutputArray(), balance, &new_size, fov_scale, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_fisheye_estimateNewCameraMatrixForUndistortRectify_const__InputArrayR_const__InputArrayR_const_SizeR_const__InputArrayR_const__OutputArrayR_double_const_SizeR_double(k.as_raw__InputArray(), d.as_raw__InputArray(), &image_size, r.as_raw__InputArray(), p.as_raw__OutputArray(), balance, &new_size, fov_scale, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `estimate_new_camera_matrix_for_undistort_rectify()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct EstimateNewCameraMatrixForUndistortRectifyBuilder<'a> {
+	k: &'a dyn core::ToInputArray,
+	d: &'a dyn core::ToInputArray,
+	image_size: core::Size,
+	r: &'a dyn core::ToInputArray,
+	p: &'a mut dyn core::ToOutputArray,
+	balance: f64,
+	new_size: core::Size,
+	fov_scale: f64,
+}
+
+impl<'a> EstimateNewCameraMatrixForUndistortRectifyBuilder<'a> {
+	#[inline]
+	pub fn new(k: &'a dyn core::ToInputArray, d: &'a dyn core::ToInputArray, image_size: core::Size, r: &'a dyn core::ToInputArray, p: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { k, d, image_size, r, p, balance: 0.0, new_size: core::Size::default(), fov_scale: 1.0 }
+	}
+	
+	/// C++ default: `0.0`
+	#[inline]
+	pub fn with_balance(mut self, balance: f64) -> Self {
+		self.balance = balance;
+		self
+	}
+	
+	/// C++ default: `Size()`
+	#[inline]
+	pub fn with_new_size(mut self, new_size: core::Size) -> Self {
+		self.new_size = new_size;
+		self
+	}
+	
+	/// C++ default: `1.0`
+	#[inline]
+	pub fn with_fov_scale(mut self, fov_scale: f64) -> Self {
+		self.fov_scale = fov_scale;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		estimate_new_camera_matrix_for_undistort_rectify(self.k, self.d, self.image_size, self.r, self.p, self.balance, self.new_size, self.fov_scale)
+	}
+}
+
 /// Computes undistortion and rectification maps for image transform by #remap. If D is empty zero
 /// distortion is used, if R or P is empty identity matrixes are used.
 /// 
@@ -3174,7 +4315,7 @@ pub fn fisheye_init_undistort_rectify_map(k: &dyn core::ToInputArray, d: &dyn co
 	output_array_arg!(map1);
 	output_array_arg!(map2);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_fisheye_initUndistortRectifyMap_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const_SizeR_int_const__OutputArrayR_const__OutputArrayR(k.as_raw__InputArray(), d.as_raw__InputArray(), r.as_raw__InputArray(), p.as_raw__InputArray(), &size, m1type, map1.as_raw__OutputArray(), map2.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_fisheye_initUndistortRectifyMap_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const_SizeR_int_const__OutputArrayR_const__OutputArrayR(k.as_raw__InputArray(), d.as_raw__InputArray(), r.as_raw__InputArray(), p.as_raw__InputArray(), &size, m1type, map1.as_raw__OutputArray(), map2.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -3212,12 +4353,54 @@ pub fn fisheye_project_points(object_points: &dyn core::ToInputArray, image_poin
 	input_array_arg!(d);
 	output_array_arg!(jacobian);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_fisheye_projectPoints_const__InputArrayR_const__OutputArrayR_const_Affine3dR_const__InputArrayR_const__InputArrayR_double_const__OutputArrayR(object_points.as_raw__InputArray(), image_points.as_raw__OutputArray(), &affine, k.as_raw__InputArray(), d.as_raw__InputArray(), alpha, jacobian.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_fisheye_projectPoints_const__InputArrayR_const__OutputArrayR_const_Affine3dR_const__InputArrayR_const__InputArrayR_double_const__OutputArrayR(object_points.as_raw__InputArray(), image_points.as_raw__OutputArray(), &affine, k.as_raw__InputArray(), d.as_raw__InputArray(), alpha, jacobian.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `fisheye_project_points()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FisheyeProjectPointsBuilder<'a> {
+	object_points: &'a dyn core::ToInputArray,
+	image_points: &'a mut dyn core::ToOutputArray,
+	affine: core::Affine3d,
+	k: &'a dyn core::ToInputArray,
+	d: &'a dyn core::ToInputArray,
+	alpha: f64,
+	jacobian: Option<&'a mut dyn core::ToOutputArray>,
+}
+
+impl<'a> FisheyeProjectPointsBuilder<'a> {
+	#[inline]
+	pub fn new(object_points: &'a dyn core::ToInputArray, image_points: &'a mut dyn core::ToOutputArray, affine: core::Affine3d, k: &'a dyn core::ToInputArray, d: &'a dyn core::ToInputArray) -> Self {
+		Self { object_points, image_points, affine, k, d, alpha: 0., jacobian: None }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_alpha(mut self, alpha: f64) -> Self {
+		self.alpha = alpha;
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_jacobian(mut self, jacobian: &'a mut dyn core::ToOutputArray) -> Self {
+		self.jacobian = Some(jacobian);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let mut jacobian_no_array = core::no_array();
+		let jacobian: &mut dyn core::ToOutputArray = match self.jacobian { Some(jacobian) => jacobian, None => &mut jacobian_no_array };
+		fisheye_project_points(self.object_points, self.image_points, self.affine, self.k, self.d, self.alpha, jacobian)
+	}
+}
+
 /// Projects points using fisheye model
 /// 
 /// ## Parameters
@@ -3254,12 +4437,55 @@ pub fn fisheye_project_points_vec(object_points: &dyn core::ToInputArray, image_
 	input_array_arg!(d);
 	output_array_arg!(jacobian);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_fisheye_projectPoints_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_double_const__OutputArrayR(object_points.as_raw__InputArray(), image_points.as_raw__OutputArray(), rvec.as_raw__InputArray(), tvec.as_raw__InputArray(), k.as_raw__InputArray(), d.as_raw__InputArray(), alpha, jacobian.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_fisheye_projectPoints_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_double_const__OutputArrayR(object_points.as_raw__InputArray(), image_points.as_raw__OutputArray(), rvec.as_raw__InputArray(), tvec.as_raw__InputArray(), k.as_raw__InputArray(), d.as_raw__InputArray(), alpha, jacobian.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `fisheye_project_points_vec()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FisheyeProjectPointsVecBuilder<'a> {
+	object_points: &'a dyn core::ToInputArray,
+	image_points: &'a mut dyn core::ToOutputArray,
+	rvec: &'a dyn core::ToInputArray,
+	tvec: &'a dyn core::ToInputArray,
+	k: &'a dyn core::ToInputArray,
+	d: &'a dyn core::ToInputArray,
+	alpha: f64,
+	jacobian: Option<&'a mut dyn core::ToOutputArray>,
+}
+
+impl<'a> FisheyeProjectPointsVecBuilder<'a> {
+	#[inline]
+	pub fn new(object_points: &'a dyn core::ToInputArray, image_points: &'a mut dyn core::ToOutputArray, rvec: &'a dyn core::ToInputArray, tvec: &'a dyn core::ToInputArray, k: &'a dyn core::ToInputArray, d: &'a dyn core::ToInputArray) -> Self {
+		Self { object_points, image_points, rvec, tvec, k, d, alpha: 0., jacobian: None }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_alpha(mut self, alpha: f64) -> Self {
+		self.alpha = alpha;
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_jacobian(mut self, jacobian: &'a mut dyn core::ToOutputArray) -> Self {
+		self.jacobian = Some(jacobian);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let mut jacobian_no_array = core::no_array();
+		let jacobian: &mut dyn core::ToOutputArray = match self.jacobian { Some(jacobian) => jacobian, None => &mut jacobian_no_array };
+		fisheye_project_points_vec(self.object_points, self.image_points, self.rvec, self.tvec, self.k, self.d, self.alpha, jacobian)
+	}
+}
+
 /// Performs stereo calibration
 /// 
 /// ## Parameters
@@ -3270,7 +4496,7 @@ pub fn fisheye_project_points_vec(object_points: &dyn core::ToInputArray, image_
 /// observed by the second camera.
 /// * K1: Input/output first camera intrinsic matrix:
 /// ![inline formula](https://latex.codecogs.com/png.latex?%5Cvecthreethree%7Bf%5Fx%5E%7B%28j%29%7D%7D%7B0%7D%7Bc%5Fx%5E%7B%28j%29%7D%7D%7B0%7D%7Bf%5Fy%5E%7B%28j%29%7D%7D%7Bc%5Fy%5E%7B%28j%29%7D%7D%7B0%7D%7B0%7D%7B1%7D) , ![inline formula](https://latex.codecogs.com/png.latex?j%20%3D%200%2C%5C%2C%201) . If
-/// any of @ref fisheye::CALIB_USE_INTRINSIC_GUESS , @ref fisheye::CALIB_FIX_INTRINSIC are specified,
+/// any of fisheye::CALIB_USE_INTRINSIC_GUESS , fisheye::CALIB_FIX_INTRINSIC are specified,
 /// some or all of the matrix components must be initialized.
 /// * D1: Input/output vector of distortion coefficients ![inline formula](https://latex.codecogs.com/png.latex?%5Cdistcoeffsfisheye) of 4 elements.
 /// * K2: Input/output second camera intrinsic matrix. The parameter is similar to K1 .
@@ -3280,16 +4506,16 @@ pub fn fisheye_project_points_vec(object_points: &dyn core::ToInputArray, image_
 /// * R: Output rotation matrix between the 1st and the 2nd camera coordinate systems.
 /// * T: Output translation vector between the coordinate systems of the cameras.
 /// * flags: Different flags that may be zero or a combination of the following values:
-/// *    @ref fisheye::CALIB_FIX_INTRINSIC  Fix K1, K2? and D1, D2? so that only R, T matrices
+/// *    fisheye::CALIB_FIX_INTRINSIC  Fix K1, K2? and D1, D2? so that only R, T matrices
 /// are estimated.
-/// *    @ref fisheye::CALIB_USE_INTRINSIC_GUESS  K1, K2 contains valid initial values of
+/// *    fisheye::CALIB_USE_INTRINSIC_GUESS  K1, K2 contains valid initial values of
 /// fx, fy, cx, cy that are optimized further. Otherwise, (cx, cy) is initially set to the image
 /// center (imageSize is used), and focal distances are computed in a least-squares fashion.
-/// *    @ref fisheye::CALIB_RECOMPUTE_EXTRINSIC  Extrinsic will be recomputed after each iteration
+/// *    fisheye::CALIB_RECOMPUTE_EXTRINSIC  Extrinsic will be recomputed after each iteration
 /// of intrinsic optimization.
-/// *    @ref fisheye::CALIB_CHECK_COND  The functions will check validity of condition number.
-/// *    @ref fisheye::CALIB_FIX_SKEW  Skew coefficient (alpha) is set to zero and stay zero.
-/// *   @ref fisheye::CALIB_FIX_K1,..., @ref fisheye::CALIB_FIX_K4 Selected distortion coefficients are set to zeros and stay
+/// *    fisheye::CALIB_CHECK_COND  The functions will check validity of condition number.
+/// *    fisheye::CALIB_FIX_SKEW  Skew coefficient (alpha) is set to zero and stay zero.
+/// *   fisheye::CALIB_FIX_K1,..., fisheye::CALIB_FIX_K4 Selected distortion coefficients are set to zeros and stay
 /// zero.
 /// * criteria: Termination criteria for the iterative optimization algorithm.
 /// 
@@ -3308,7 +4534,7 @@ pub fn fisheye_stereo_calibrate(object_points: &dyn core::ToInputArray, image_po
 	output_array_arg!(r);
 	output_array_arg!(t);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_fisheye_stereoCalibrate_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_Size_const__OutputArrayR_const__OutputArrayR_int_TermCriteria(object_points.as_raw__InputArray(), image_points1.as_raw__InputArray(), image_points2.as_raw__InputArray(), k1.as_raw__InputOutputArray(), d1.as_raw__InputOutputArray(), k2.as_raw__InputOutputArray(), d2.as_raw__InputOutputArray(), image_size.opencv_as_extern(), r.as_raw__OutputArray(), t.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_fisheye_stereoCalibrate_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_Size_const__OutputArrayR_const__OutputArrayR_int_TermCriteria(object_points.as_raw__InputArray(), image_points1.as_raw__InputArray(), image_points2.as_raw__InputArray(), k1.as_raw__InputOutputArray(), d1.as_raw__InputOutputArray(), k2.as_raw__InputOutputArray(), d2.as_raw__InputOutputArray(), image_size.opencv_as_extern(), r.as_raw__OutputArray(), t.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -3332,7 +4558,7 @@ pub fn fisheye_stereo_calibrate(object_points: &dyn core::ToInputArray, image_po
 /// * P2: Output 3x4 projection matrix in the new (rectified) coordinate systems for the second
 /// camera.
 /// * Q: Output ![inline formula](https://latex.codecogs.com/png.latex?4%20%5Ctimes%204) disparity-to-depth mapping matrix (see reprojectImageTo3D ).
-/// * flags: Operation flags that may be zero or @ref fisheye::CALIB_ZERO_DISPARITY . If the flag is set,
+/// * flags: Operation flags that may be zero or fisheye::CALIB_ZERO_DISPARITY . If the flag is set,
 /// the function makes the principal points of each camera have the same pixel coordinates in the
 /// rectified views. And if the flag is not set, the function may still shift the images in the
 /// horizontal or vertical direction (depending on the orientation of epipolar lines) to maximize the
@@ -3342,7 +4568,7 @@ pub fn fisheye_stereo_calibrate(object_points: &dyn core::ToInputArray, image_po
 /// is passed (default), it is set to the original imageSize . Setting it to larger value can help you
 /// preserve details in the original image, especially when there is a big radial distortion.
 /// * balance: Sets the new focal length in range between the min focal length and the max focal
-/// length. Balance is in range of [0, 1].
+/// length. Balance is in range of \[0, 1\].
 /// * fov_scale: Divisor for new focal length.
 /// 
 /// ## C++ default parameters
@@ -3363,12 +4589,68 @@ pub fn fisheye_stereo_rectify(k1: &dyn core::ToInputArray, d1: &dyn core::ToInpu
 	output_array_arg!(p2);
 	output_array_arg!(q);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_fisheye_stereoRectify_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const_SizeR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int_const_SizeR_double_double(k1.as_raw__InputArray(), d1.as_raw__InputArray(), k2.as_raw__InputArray(), d2.as_raw__InputArray(), &image_size, r.as_raw__InputArray(), tvec.as_raw__InputArray(), r1.as_raw__OutputArray(), r2.as_raw__OutputArray(), p1.as_raw__OutputArray(), p2.as_raw__OutputArray(), q.as_raw__OutputArray(), flags, &new_image_size, balance, fov_scale, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_fisheye_stereoRectify_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const_SizeR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int_const_SizeR_double_double(k1.as_raw__InputArray(), d1.as_raw__InputArray(), k2.as_raw__InputArray(), d2.as_raw__InputArray(), &image_size, r.as_raw__InputArray(), tvec.as_raw__InputArray(), r1.as_raw__OutputArray(), r2.as_raw__OutputArray(), p1.as_raw__OutputArray(), p2.as_raw__OutputArray(), q.as_raw__OutputArray(), flags, &new_image_size, balance, fov_scale, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `fisheye_stereo_rectify()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FisheyeStereoRectifyBuilder<'a> {
+	k1: &'a dyn core::ToInputArray,
+	d1: &'a dyn core::ToInputArray,
+	k2: &'a dyn core::ToInputArray,
+	d2: &'a dyn core::ToInputArray,
+	image_size: core::Size,
+	r: &'a dyn core::ToInputArray,
+	tvec: &'a dyn core::ToInputArray,
+	r1: &'a mut dyn core::ToOutputArray,
+	r2: &'a mut dyn core::ToOutputArray,
+	p1: &'a mut dyn core::ToOutputArray,
+	p2: &'a mut dyn core::ToOutputArray,
+	q: &'a mut dyn core::ToOutputArray,
+	flags: i32,
+	new_image_size: core::Size,
+	balance: f64,
+	fov_scale: f64,
+}
+
+impl<'a> FisheyeStereoRectifyBuilder<'a> {
+	#[inline]
+	pub fn new(k1: &'a dyn core::ToInputArray, d1: &'a dyn core::ToInputArray, k2: &'a dyn core::ToInputArray, d2: &'a dyn core::ToInputArray, image_size: core::Size, r: &'a dyn core::ToInputArray, tvec: &'a dyn core::ToInputArray, r1: &'a mut dyn core::ToOutputArray, r2: &'a mut dyn core::ToOutputArray, p1: &'a mut dyn core::ToOutputArray, p2: &'a mut dyn core::ToOutputArray, q: &'a mut dyn core::ToOutputArray, flags: i32) -> Self {
+		Self { k1, d1, k2, d2, image_size, r, tvec, r1, r2, p1, p2, q, flags, new_image_size: core::Size::default(), balance: 0.0, fov_scale: 1.0 }
+	}
+	
+	/// C++ default: `Size()`
+	#[inline]
+	pub fn with_new_image_size(mut self, new_image_size: core::Size) -> Self {
+		self.new_image_size = new_image_size;
+		self
+	}
+	
+	/// C++ default: `0.0`
+	#[inline]
+	pub fn with_balance(mut self, balance: f64) -> Self {
+		self.balance = balance;
+		self
+	}
+	
+	/// C++ default: `1.0`
+	#[inline]
+	pub fn with_fov_scale(mut self, fov_scale: f64) -> Self {
+		self.fov_scale = fov_scale;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		fisheye_stereo_rectify(self.k1, self.d1, self.k2, self.d2, self.image_size, self.r, self.tvec, self.r1, self.r2, self.p1, self.p2, self.q, self.flags, self.new_image_size, self.balance, self.fov_scale)
+	}
+}
+
 /// Transforms an image to compensate for fisheye lens distortion.
 /// 
 /// ## Parameters
@@ -3409,12 +4691,53 @@ pub fn fisheye_undistort_image(distorted: &dyn core::ToInputArray, undistorted:
 	input_array_arg!(d);
 	input_array_arg!(knew);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_fisheye_undistortImage_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const_SizeR(distorted.as_raw__InputArray(), undistorted.as_raw__OutputArray(), k.as_raw__InputArray(), d.as_raw__InputArray(), knew.as_raw__InputArray(), &new_size, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_fisheye_undistortImage_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const_SizeR(distorted.as_raw__InputArray(), undistorted.as_raw__OutputArray(), k.as_raw__InputArray(), d.as_raw__InputArray(), knew.as_raw__InputArray(), &new_size, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `fisheye_undistort_image()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FisheyeUndistortImageBuilder<'a> {
+	distorted: &'a dyn core::ToInputArray,
+	undistorted: &'a mut dyn core::ToOutputArray,
+	k: &'a dyn core::ToInputArray,
+	d: &'a dyn core::ToInputArray,
+	knew: Option<&'a dyn core::ToInputArray>,
+	new_size: core::Size,
+}
+
+impl<'a> FisheyeUndistortImageBuilder<'a> {
+	#[inline]
+	pub fn new(distorted: &'a dyn core::ToInputArray, undistorted: &'a mut dyn core::ToOutputArray, k: &'a dyn core::ToInputArray, d: &'a dyn core::ToInputArray) -> Self {
+		Self { distorted, undistorted, k, d, knew: None, new_size: core::Size::default() }
+	}
+	
+	/// C++ default: `cv::noArray()`
+	#[inline]
+	pub fn with_knew(mut self, knew: &'a dyn core::ToInputArray) -> Self {
+		self.knew = Some(knew);
+		self
+	}
+	
+	/// C++ default: `Size()`
+	#[inline]
+	pub fn with_new_size(mut self, new_size: core::Size) -> Self {
+		self.new_size = new_size;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let knew_no_array = core::no_array();
+		let knew: &dyn core::ToInputArray = match self.knew { Some(knew) => knew, None => &knew_no_array };
+		fisheye_undistort_image(self.distorted, self.undistorted, self.k, self.d, knew, self.new_size)
+	}
+}
+
 /// Undistorts 2D points using fisheye model
 /// 
 /// ## Parameters
@@ -3439,12 +4762,55 @@ pub fn fisheye_undistort_points(distorted: &dyn core::ToInputArray, undistorted:
 	input_array_arg!(r);
 	input_array_arg!(p);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_fisheye_undistortPoints_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR(distorted.as_raw__InputArray(), undistorted.as_raw__OutputArray(), k.as_raw__InputArray(), d.as_raw__InputArray(), r.as_raw__InputArray(), p.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_fisheye_undistortPoints_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR(distorted.as_raw__InputArray(), undistorted.as_raw__OutputArray(), k.as_raw__InputArray(), d.as_raw__InputArray(), r.as_raw__InputArray(), p.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `fisheye_undistort_points()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FisheyeUndistortPointsBuilder<'a> {
+	distorted: &'a dyn core::ToInputArray,
+	undistorted: &'a mut dyn core::ToOutputArray,
+	k: &'a dyn core::ToInputArray,
+	d: &'a dyn core::ToInputArray,
+	r: Option<&'a dyn core::ToInputArray>,
+	p: Option<&'a dyn core::ToInputArray>,
+}
+
+impl<'a> FisheyeUndistortPointsBuilder<'a> {
+	#[inline]
+	pub fn new(distorted: &'a dyn core::ToInputArray, undistorted: &'a mut dyn core::ToOutputArray, k: &'a dyn core::ToInputArray, d: &'a dyn core::ToInputArray) -> Self {
+		Self { distorted, undistorted, k, d, r: None, p: None }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_r(mut self, r: &'a dyn core::ToInputArray) -> Self {
+		self.r = Some(r);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_p(mut self, p: &'a dyn core::ToInputArray) -> Self {
+		self.p = Some(p);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let r_no_array = core::no_array();
+		let r: &dyn core::ToInputArray = match self.r { Some(r) => r, None => &r_no_array };
+		let p_no_array = core::no_array();
+		let p: &dyn core::ToInputArray = match self.p { Some(p) => p, None => &p_no_array };
+		fisheye_undistort_points(self.distorted, self.undistorted, self.k, self.d, r, p)
+	}
+}
+
 /// Returns the default new camera matrix.
 /// 
 /// The function returns the camera matrix that is either an exact copy of the input cameraMatrix (when
@@ -3475,7 +4841,7 @@ pub fn fisheye_undistort_points(distorted: &dyn core::ToInputArray, undistorted:
 pub fn get_default_new_camera_matrix(camera_matrix: &dyn core::ToInputArray, imgsize: core::Size, center_principal_point: bool) -> Result<core::Mat> {
 	input_array_arg!(camera_matrix);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_getDefaultNewCameraMatrix_const__InputArrayR_Size_bool(camera_matrix.as_raw__InputArray(), imgsize.opencv_as_extern(), center_principal_point, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_getDefaultNewCameraMatrix_const__InputArrayR_Size_bool(camera_matrix.as_raw__InputArray(), imgsize.opencv_as_extern(), center_principal_point, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -3519,18 +4885,58 @@ pub fn get_optimal_new_camera_matrix(camera_matrix: &dyn core::ToInputArray, dis
 	input_array_arg!(camera_matrix);
 	input_array_arg!(dist_coeffs);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_getOptimalNewCameraMatrix_const__InputArrayR_const__InputArrayR_Size_double_Size_RectX_bool(camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), image_size.opencv_as_extern(), alpha, new_img_size.opencv_as_extern(), valid_pix_roi, center_principal_point, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_getOptimalNewCameraMatrix_const__InputArrayR_const__InputArrayR_Size_double_Size_RectX_bool(camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), image_size.opencv_as_extern(), alpha, new_img_size.opencv_as_extern(), valid_pix_roi, center_principal_point, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `get_optimal_new_camera_matrix()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct GetOptimalNewCameraMatrixBuilder<'a> {
+	camera_matrix: &'a dyn core::ToInputArray,
+	dist_coeffs: &'a dyn core::ToInputArray,
+	image_size: core::Size,
+	alpha: f64,
+	new_img_size: core::Size,
+	valid_pix_roi: &'a mut core::Rect,
+	center_principal_point: bool,
+}
+
+impl<'a> GetOptimalNewCameraMatrixBuilder<'a> {
+	#[inline]
+	pub fn new(camera_matrix: &'a dyn core::ToInputArray, dist_coeffs: &'a dyn core::ToInputArray, image_size: core::Size, alpha: f64, valid_pix_roi: &'a mut core::Rect) -> Self {
+		Self { camera_matrix, dist_coeffs, image_size, alpha, new_img_size: core::Size::default(), valid_pix_roi, center_principal_point: false }
+	}
+	
+	/// C++ default: `Size()`
+	#[inline]
+	pub fn with_new_img_size(mut self, new_img_size: core::Size) -> Self {
+		self.new_img_size = new_img_size;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_center_principal_point(mut self, center_principal_point: bool) -> Self {
+		self.center_principal_point = center_principal_point;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Mat> {
+		get_optimal_new_camera_matrix(self.camera_matrix, self.dist_coeffs, self.image_size, self.alpha, self.new_img_size, self.valid_pix_roi, self.center_principal_point)
+	}
+}
+
 /// computes valid disparity ROI from the valid ROIs of the rectified images (that are returned by #stereoRectify)
 #[inline]
 pub fn get_valid_disparity_roi(roi1: core::Rect, roi2: core::Rect, min_disparity: i32, number_of_disparities: i32, block_size: i32) -> Result<core::Rect> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_getValidDisparityROI_Rect_Rect_int_int_int(roi1.opencv_as_extern(), roi2.opencv_as_extern(), min_disparity, number_of_disparities, block_size, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_getValidDisparityROI_Rect_Rect_int_int_int(roi1.opencv_as_extern(), roi2.opencv_as_extern(), min_disparity, number_of_disparities, block_size, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -3559,7 +4965,7 @@ pub fn init_camera_matrix_2d(object_points: &dyn core::ToInputArray, image_point
 	input_array_arg!(object_points);
 	input_array_arg!(image_points);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_initCameraMatrix2D_const__InputArrayR_const__InputArrayR_Size_double(object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), image_size.opencv_as_extern(), aspect_ratio, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_initCameraMatrix2D_const__InputArrayR_const__InputArrayR_Size_double(object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), image_size.opencv_as_extern(), aspect_ratio, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -3619,7 +5025,7 @@ pub fn init_inverse_rectification_map(camera_matrix: &dyn core::ToInputArray, di
 	output_array_arg!(map1);
 	output_array_arg!(map2);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_initInverseRectificationMap_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const_SizeR_int_const__OutputArrayR_const__OutputArrayR(camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), r.as_raw__InputArray(), new_camera_matrix.as_raw__InputArray(), &size, m1type, map1.as_raw__OutputArray(), map2.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_initInverseRectificationMap_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const_SizeR_int_const__OutputArrayR_const__OutputArrayR(camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), r.as_raw__InputArray(), new_camera_matrix.as_raw__InputArray(), &size, m1type, map1.as_raw__OutputArray(), map2.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -3677,7 +5083,7 @@ pub fn init_undistort_rectify_map(camera_matrix: &dyn core::ToInputArray, dist_c
 	output_array_arg!(map1);
 	output_array_arg!(map2);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_initUndistortRectifyMap_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_Size_int_const__OutputArrayR_const__OutputArrayR(camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), r.as_raw__InputArray(), new_camera_matrix.as_raw__InputArray(), size.opencv_as_extern(), m1type, map1.as_raw__OutputArray(), map2.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_initUndistortRectifyMap_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_Size_int_const__OutputArrayR_const__OutputArrayR(camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), r.as_raw__InputArray(), new_camera_matrix.as_raw__InputArray(), size.opencv_as_extern(), m1type, map1.as_raw__OutputArray(), map2.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -3695,12 +5101,47 @@ pub fn init_wide_angle_proj_map(camera_matrix: &dyn core::ToInputArray, dist_coe
 	output_array_arg!(map1);
 	output_array_arg!(map2);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_initWideAngleProjMap_const__InputArrayR_const__InputArrayR_Size_int_int_const__OutputArrayR_const__OutputArrayR_UndistortTypes_double(camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), image_size.opencv_as_extern(), dest_image_width, m1type, map1.as_raw__OutputArray(), map2.as_raw__OutputArray(), proj_type, alpha, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_initWideAngleProjMap_const__InputArrayR_const__InputArrayR_Size_int_int_const__OutputArrayR_const__OutputArrayR_UndistortTypes_double(camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), image_size.opencv_as_extern(), dest_image_width, m1type, map1.as_raw__OutputArray(), map2.as_raw__OutputArray(), proj_type, alpha, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `init_wide_angle_proj_map()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct InitWideAngleProjMapBuilder<'a> {
+	camera_matrix: &'a dyn core::ToInputArray,
+	dist_coeffs: &'a dyn core::ToInputArray,
+	image_size: core::Size,
+	dest_image_width: i32,
+	m1type: i32,
+	map1: &'a mut dyn core::ToOutputArray,
+	map2: &'a mut dyn core::ToOutputArray,
+	proj_type: crate::calib3d::UndistortTypes,
+	alpha: f64,
+}
+
+impl<'a> InitWideAngleProjMapBuilder<'a> {
+	#[inline]
+	pub fn new(camera_matrix: &'a dyn core::ToInputArray, dist_coeffs: &'a dyn core::ToInputArray, image_size: core::Size, dest_image_width: i32, m1type: i32, map1: &'a mut dyn core::ToOutputArray, map2: &'a mut dyn core::ToOutputArray, proj_type: crate::calib3d::UndistortTypes) -> Self {
+		Self { camera_matrix, dist_coeffs, image_size, dest_image_width, m1type, map1, map2, proj_type, alpha: 0. }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_alpha(mut self, alpha: f64) -> Self {
+		self.alpha = alpha;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<f32> {
+		init_wide_angle_proj_map(self.camera_matrix, self.dist_coeffs, self.image_size, self.dest_image_width, self.m1type, self.map1, self.map2, self.proj_type, self.alpha)
+	}
+}
+
 /// Computes partial derivatives of the matrix product for each multiplied matrix.
 /// 
 /// ## Parameters
@@ -3721,7 +5162,7 @@ pub fn mat_mul_deriv(a: &dyn core::ToInputArray, b: &dyn core::ToInputArray, d_a
 	output_array_arg!(d_a_bd_a);
 	output_array_arg!(d_a_bd_b);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_matMulDeriv_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(a.as_raw__InputArray(), b.as_raw__InputArray(), d_a_bd_a.as_raw__OutputArray(), d_a_bd_b.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_matMulDeriv_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(a.as_raw__InputArray(), b.as_raw__InputArray(), d_a_bd_a.as_raw__OutputArray(), d_a_bd_b.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -3732,8 +5173,8 @@ pub fn mat_mul_deriv(a: &dyn core::ToInputArray, b: &dyn core::ToInputArray, d_a
 /// ## Parameters
 /// * objectPoints: Array of object points expressed wrt. the world coordinate frame. A 3xN/Nx3
 /// 1-channel or 1xN/Nx1 3-channel (or vector\<Point3f\> ), where N is the number of points in the view.
-/// * rvec: The rotation vector (@ref Rodrigues) that, together with tvec, performs a change of
-/// basis from world to camera coordinate system, see @ref calibrateCamera for details.
+/// * rvec: The rotation vector ([rodrigues](crate::calib3d::rodrigues)) that, together with tvec, performs a change of
+/// basis from world to camera coordinate system, see [calibrate_camera](crate::calib3d::calibrate_camera) for details.
 /// * tvec: The translation vector, see parameter description above.
 /// * cameraMatrix: Camera intrinsic matrix ![inline formula](https://latex.codecogs.com/png.latex?%5Ccameramatrix%7BA%7D) .
 /// * distCoeffs: Input vector of distortion coefficients
@@ -3752,7 +5193,7 @@ pub fn mat_mul_deriv(a: &dyn core::ToInputArray, b: &dyn core::ToInputArray, d_a
 /// extrinsic camera parameters. Optionally, the function computes Jacobians -matrices of partial
 /// derivatives of image points coordinates (as functions of all the input parameters) with respect to
 /// the particular parameters, intrinsic and/or extrinsic. The Jacobians are used during the global
-/// optimization in @ref calibrateCamera, @ref solvePnP, and @ref stereoCalibrate. The function itself
+/// optimization in [calibrate_camera](crate::calib3d::calibrate_camera), [solve_pnp](crate::calib3d::solve_pnp), and [stereo_calibrate](crate::calib3d::stereo_calibrate). The function itself
 /// can also be used to compute a re-projection error, given the current intrinsic and extrinsic
 /// parameters.
 /// 
@@ -3775,12 +5216,55 @@ pub fn project_points(object_points: &dyn core::ToInputArray, rvec: &dyn core::T
 	output_array_arg!(image_points);
 	output_array_arg!(jacobian);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_projectPoints_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_double(object_points.as_raw__InputArray(), rvec.as_raw__InputArray(), tvec.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), image_points.as_raw__OutputArray(), jacobian.as_raw__OutputArray(), aspect_ratio, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_projectPoints_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_double(object_points.as_raw__InputArray(), rvec.as_raw__InputArray(), tvec.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), image_points.as_raw__OutputArray(), jacobian.as_raw__OutputArray(), aspect_ratio, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `project_points()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct ProjectPointsBuilder<'a> {
+	object_points: &'a dyn core::ToInputArray,
+	rvec: &'a dyn core::ToInputArray,
+	tvec: &'a dyn core::ToInputArray,
+	camera_matrix: &'a dyn core::ToInputArray,
+	dist_coeffs: &'a dyn core::ToInputArray,
+	image_points: &'a mut dyn core::ToOutputArray,
+	jacobian: Option<&'a mut dyn core::ToOutputArray>,
+	aspect_ratio: f64,
+}
+
+impl<'a> ProjectPointsBuilder<'a> {
+	#[inline]
+	pub fn new(object_points: &'a dyn core::ToInputArray, rvec: &'a dyn core::ToInputArray, tvec: &'a dyn core::ToInputArray, camera_matrix: &'a dyn core::ToInputArray, dist_coeffs: &'a dyn core::ToInputArray, image_points: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { object_points, rvec, tvec, camera_matrix, dist_coeffs, image_points, jacobian: None, aspect_ratio: 0. }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_jacobian(mut self, jacobian: &'a mut dyn core::ToOutputArray) -> Self {
+		self.jacobian = Some(jacobian);
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_aspect_ratio(mut self, aspect_ratio: f64) -> Self {
+		self.aspect_ratio = aspect_ratio;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let mut jacobian_no_array = core::no_array();
+		let jacobian: &mut dyn core::ToOutputArray = match self.jacobian { Some(jacobian) => jacobian, None => &mut jacobian_no_array };
+		project_points(self.object_points, self.rvec, self.tvec, self.camera_matrix, self.dist_coeffs, self.image_points, jacobian, self.aspect_ratio)
+	}
+}
+
 /// Recovers the relative camera rotation and the translation from corresponding points in two images from two different cameras, using cheirality check. Returns the number of
 /// inliers that pass the check.
 /// 
@@ -3789,24 +5273,24 @@ pub fn project_points(object_points: &dyn core::ToInputArray, rvec: &dyn core::T
 /// floating-point (single or double precision).
 /// * points2: Array of the second image points of the same size and format as points1 .
 /// * cameraMatrix1: Input/output camera matrix for the first camera, the same as in
-/// @ref calibrateCamera. Furthermore, for the stereo case, additional flags may be used, see below.
+/// [calibrate_camera](crate::calib3d::calibrate_camera). Furthermore, for the stereo case, additional flags may be used, see below.
 /// * distCoeffs1: Input/output vector of distortion coefficients, the same as in
-/// @ref calibrateCamera.
+/// [calibrate_camera](crate::calib3d::calibrate_camera).
 /// * cameraMatrix2: Input/output camera matrix for the first camera, the same as in
-/// @ref calibrateCamera. Furthermore, for the stereo case, additional flags may be used, see below.
+/// [calibrate_camera](crate::calib3d::calibrate_camera). Furthermore, for the stereo case, additional flags may be used, see below.
 /// * distCoeffs2: Input/output vector of distortion coefficients, the same as in
-/// @ref calibrateCamera.
+/// [calibrate_camera](crate::calib3d::calibrate_camera).
 /// * E: The output essential matrix.
 /// * R: Output rotation matrix. Together with the translation vector, this matrix makes up a tuple
 /// that performs a change of basis from the first camera's coordinate system to the second camera's
 /// coordinate system. Note that, in general, t can not be used for this tuple, see the parameter
 /// described below.
-/// * t: Output translation vector. This vector is obtained by @ref decomposeEssentialMat and
+/// * t: Output translation vector. This vector is obtained by [decompose_essential_mat](crate::calib3d::decompose_essential_mat) and
 /// therefore is only known up to scale, i.e. t is the direction of the translation vector and has unit
 /// length.
 /// * method: Method for computing an essential matrix.
-/// *   @ref RANSAC for the RANSAC algorithm.
-/// *   @ref LMEDS for the LMedS algorithm.
+/// *   `RANSAC` for the RANSAC algorithm.
+/// *   `LMEDS` for the LMedS algorithm.
 /// * prob: Parameter used for the RANSAC or LMedS methods only. It specifies a desirable level of
 /// confidence (probability) that the estimated matrix is correct.
 /// * threshold: Parameter used for RANSAC. It is the maximum distance from a point to an epipolar
@@ -3817,11 +5301,11 @@ pub fn project_points(object_points: &dyn core::ToInputArray, rvec: &dyn core::T
 /// inliers in points1 and points2 for then given essential matrix E. Only these inliers will be used to
 /// recover pose. In the output mask only inliers which pass the cheirality check.
 /// 
-/// This function decomposes an essential matrix using @ref decomposeEssentialMat and then verifies
+/// This function decomposes an essential matrix using [decompose_essential_mat](crate::calib3d::decompose_essential_mat) and then verifies
 /// possible pose hypotheses by doing cheirality check. The cheirality check means that the
 /// triangulated 3D points should have positive depth. Some details can be found in [Nister03](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_Nister03).
 /// 
-/// This function can be used to process the output E and mask from @ref findEssentialMat. In this
+/// This function can be used to process the output E and mask from [find_essential_mat](crate::calib3d::find_essential_mat). In this
 /// scenario, points1 and points2 are the same input for findEssentialMat.:
 /// ```ignore
 ///    // Example. Estimation of fundamental matrix using the RANSAC algorithm
@@ -3864,12 +5348,67 @@ pub fn recover_pose_2_cameras(points1: &dyn core::ToInputArray, points2: &dyn co
 	output_array_arg!(t);
 	input_output_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_recoverPose_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int_double_double_const__InputOutputArrayR(points1.as_raw__InputArray(), points2.as_raw__InputArray(), camera_matrix1.as_raw__InputArray(), dist_coeffs1.as_raw__InputArray(), camera_matrix2.as_raw__InputArray(), dist_coeffs2.as_raw__InputArray(), e.as_raw__OutputArray(), r.as_raw__OutputArray(), t.as_raw__OutputArray(), method, prob, threshold, mask.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_recoverPose_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int_double_double_const__InputOutputArrayR(points1.as_raw__InputArray(), points2.as_raw__InputArray(), camera_matrix1.as_raw__InputArray(), dist_coeffs1.as_raw__InputArray(), camera_matrix2.as_raw__InputArray(), dist_coeffs2.as_raw__InputArray(), e.as_raw__OutputArray(), r.as_raw__OutputArray(), t.as_raw__OutputArray(), method, prob, threshold, mask.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `recover_pose_2_cameras()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct RecoverPose2CamerasBuilder<'a> {
+	points1: &'a dyn core::ToInputArray,
+	points2: &'a dyn core::ToInputArray,
+	camera_matrix1: &'a dyn core::ToInputArray,
+	dist_coeffs1: &'a dyn core::ToInputArray,
+	camera_matrix2: &'a dyn core::ToInputArray,
+	dist_coeffs2: &'a dyn core::ToInputArray,
+	e: &'a mut dyn core::ToOutputArray,
+	r: &'a mut dyn core::ToOutputArray,
+	t: &'a mut dyn core::ToOutputArray,
+	method: i32,
+	prob: f64,
+	threshold: f64,
+	mask: Option<&'a mut dyn core::ToInputOutputArray>,
+}
+
+impl<'a> RecoverPose2CamerasBuilder<'a> {
+	#[inline]
+	pub fn new(points1: &'a dyn core::ToInputArray, points2: &'a dyn core::ToInputArray, camera_matrix1: &'a dyn core::ToInputArray, dist_coeffs1: &'a dyn core::ToInputArray, camera_matrix2: &'a dyn core::ToInputArray, dist_coeffs2: &'a dyn core::ToInputArray, e: &'a mut dyn core::ToOutputArray, r: &'a mut dyn core::ToOutputArray, t: &'a mut dyn core::ToOutputArray, method: i32) -> Self {
+		Self { points1, points2, camera_matrix1, dist_coeffs1, camera_matrix2, dist_coeffs2, e, r, t, method, prob: 0.999, threshold: 1.0, mask: None }
+	}
+	
+	/// C++ default: `0.999`
+	#[inline]
+	pub fn with_prob(mut self, prob: f64) -> Self {
+		self.prob = prob;
+		self
+	}
+	
+	/// C++ default: `1.0`
+	#[inline]
+	pub fn with_threshold(mut self, threshold: f64) -> Self {
+		self.threshold = threshold;
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_mask(mut self, mask: &'a mut dyn core::ToInputOutputArray) -> Self {
+		self.mask = Some(mask);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<i32> {
+		let mut mask_no_array = core::no_array();
+		let mask: &mut dyn core::ToInputOutputArray = match self.mask { Some(mask) => mask, None => &mut mask_no_array };
+		recover_pose_2_cameras(self.points1, self.points2, self.camera_matrix1, self.dist_coeffs1, self.camera_matrix2, self.dist_coeffs2, self.e, self.r, self.t, self.method, self.prob, self.threshold, mask)
+	}
+}
+
 /// Recovers the relative camera rotation and the translation from an estimated essential
 /// matrix and the corresponding points in two images, using cheirality check. Returns the number of
 /// inliers that pass the check.
@@ -3886,18 +5425,18 @@ pub fn recover_pose_2_cameras(points1: &dyn core::ToInputArray, points2: &dyn co
 /// that performs a change of basis from the first camera's coordinate system to the second camera's
 /// coordinate system. Note that, in general, t can not be used for this tuple, see the parameter
 /// described below.
-/// * t: Output translation vector. This vector is obtained by @ref decomposeEssentialMat and
+/// * t: Output translation vector. This vector is obtained by [decompose_essential_mat](crate::calib3d::decompose_essential_mat) and
 /// therefore is only known up to scale, i.e. t is the direction of the translation vector and has unit
 /// length.
 /// * mask: Input/output mask for inliers in points1 and points2. If it is not empty, then it marks
 /// inliers in points1 and points2 for then given essential matrix E. Only these inliers will be used to
 /// recover pose. In the output mask only inliers which pass the cheirality check.
 /// 
-/// This function decomposes an essential matrix using @ref decomposeEssentialMat and then verifies
+/// This function decomposes an essential matrix using [decompose_essential_mat](crate::calib3d::decompose_essential_mat) and then verifies
 /// possible pose hypotheses by doing cheirality check. The cheirality check means that the
 /// triangulated 3D points should have positive depth. Some details can be found in [Nister03](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_Nister03).
 /// 
-/// This function can be used to process the output E and mask from @ref findEssentialMat. In this
+/// This function can be used to process the output E and mask from [find_essential_mat](crate::calib3d::find_essential_mat). In this
 /// scenario, points1 and points2 are the same input for #findEssentialMat :
 /// ```ignore
 ///    // Example. Estimation of fundamental matrix using the RANSAC algorithm
@@ -3934,12 +5473,47 @@ pub fn recover_pose_estimated(e: &dyn core::ToInputArray, points1: &dyn core::To
 	output_array_arg!(t);
 	input_output_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_recoverPose_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__InputOutputArrayR(e.as_raw__InputArray(), points1.as_raw__InputArray(), points2.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), r.as_raw__OutputArray(), t.as_raw__OutputArray(), mask.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_recoverPose_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__InputOutputArrayR(e.as_raw__InputArray(), points1.as_raw__InputArray(), points2.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), r.as_raw__OutputArray(), t.as_raw__OutputArray(), mask.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `recover_pose_estimated()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct RecoverPoseEstimatedBuilder<'a> {
+	e: &'a dyn core::ToInputArray,
+	points1: &'a dyn core::ToInputArray,
+	points2: &'a dyn core::ToInputArray,
+	camera_matrix: &'a dyn core::ToInputArray,
+	r: &'a mut dyn core::ToOutputArray,
+	t: &'a mut dyn core::ToOutputArray,
+	mask: Option<&'a mut dyn core::ToInputOutputArray>,
+}
+
+impl<'a> RecoverPoseEstimatedBuilder<'a> {
+	#[inline]
+	pub fn new(e: &'a dyn core::ToInputArray, points1: &'a dyn core::ToInputArray, points2: &'a dyn core::ToInputArray, camera_matrix: &'a dyn core::ToInputArray, r: &'a mut dyn core::ToOutputArray, t: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { e, points1, points2, camera_matrix, r, t, mask: None }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_mask(mut self, mask: &'a mut dyn core::ToInputOutputArray) -> Self {
+		self.mask = Some(mask);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<i32> {
+		let mut mask_no_array = core::no_array();
+		let mask: &mut dyn core::ToInputOutputArray = match self.mask { Some(mask) => mask, None => &mut mask_no_array };
+		recover_pose_estimated(self.e, self.points1, self.points2, self.camera_matrix, self.r, self.t, mask)
+	}
+}
+
 /// Recovers the relative camera rotation and the translation from an estimated essential
 /// matrix and the corresponding points in two images, using cheirality check. Returns the number of
 /// inliers that pass the check.
@@ -3956,18 +5530,18 @@ pub fn recover_pose_estimated(e: &dyn core::ToInputArray, points1: &dyn core::To
 /// that performs a change of basis from the first camera's coordinate system to the second camera's
 /// coordinate system. Note that, in general, t can not be used for this tuple, see the parameter
 /// described below.
-/// * t: Output translation vector. This vector is obtained by @ref decomposeEssentialMat and
+/// * t: Output translation vector. This vector is obtained by [decompose_essential_mat](crate::calib3d::decompose_essential_mat) and
 /// therefore is only known up to scale, i.e. t is the direction of the translation vector and has unit
 /// length.
 /// * mask: Input/output mask for inliers in points1 and points2. If it is not empty, then it marks
 /// inliers in points1 and points2 for then given essential matrix E. Only these inliers will be used to
 /// recover pose. In the output mask only inliers which pass the cheirality check.
 /// 
-/// This function decomposes an essential matrix using @ref decomposeEssentialMat and then verifies
+/// This function decomposes an essential matrix using [decompose_essential_mat](crate::calib3d::decompose_essential_mat) and then verifies
 /// possible pose hypotheses by doing cheirality check. The cheirality check means that the
 /// triangulated 3D points should have positive depth. Some details can be found in [Nister03](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_Nister03).
 /// 
-/// This function can be used to process the output E and mask from @ref findEssentialMat. In this
+/// This function can be used to process the output E and mask from [find_essential_mat](crate::calib3d::find_essential_mat). In this
 /// scenario, points1 and points2 are the same input for #findEssentialMat :
 /// ```ignore
 ///    // Example. Estimation of fundamental matrix using the RANSAC algorithm
@@ -4005,7 +5579,7 @@ pub fn recover_pose_estimated(e: &dyn core::ToInputArray, points1: &dyn core::To
 /// that performs a change of basis from the first camera's coordinate system to the second camera's
 /// coordinate system. Note that, in general, t can not be used for this tuple, see the parameter
 /// description below.
-/// * t: Output translation vector. This vector is obtained by @ref decomposeEssentialMat and
+/// * t: Output translation vector. This vector is obtained by [decompose_essential_mat](crate::calib3d::decompose_essential_mat) and
 /// therefore is only known up to scale, i.e. t is the direction of the translation vector and has unit
 /// length.
 /// * distanceThresh: threshold distance which is used to filter out far away points (i.e. infinite
@@ -4032,12 +5606,58 @@ pub fn recover_pose_triangulated(e: &dyn core::ToInputArray, points1: &dyn core:
 	input_output_array_arg!(mask);
 	output_array_arg!(triangulated_points);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_recoverPose_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_double_const__InputOutputArrayR_const__OutputArrayR(e.as_raw__InputArray(), points1.as_raw__InputArray(), points2.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), r.as_raw__OutputArray(), t.as_raw__OutputArray(), distance_thresh, mask.as_raw__InputOutputArray(), triangulated_points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_recoverPose_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_double_const__InputOutputArrayR_const__OutputArrayR(e.as_raw__InputArray(), points1.as_raw__InputArray(), points2.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), r.as_raw__OutputArray(), t.as_raw__OutputArray(), distance_thresh, mask.as_raw__InputOutputArray(), triangulated_points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `recover_pose_triangulated()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct RecoverPoseTriangulatedBuilder<'a> {
+	e: &'a dyn core::ToInputArray,
+	points1: &'a dyn core::ToInputArray,
+	points2: &'a dyn core::ToInputArray,
+	camera_matrix: &'a dyn core::ToInputArray,
+	r: &'a mut dyn core::ToOutputArray,
+	t: &'a mut dyn core::ToOutputArray,
+	distance_thresh: f64,
+	mask: Option<&'a mut dyn core::ToInputOutputArray>,
+	triangulated_points: Option<&'a mut dyn core::ToOutputArray>,
+}
+
+impl<'a> RecoverPoseTriangulatedBuilder<'a> {
+	#[inline]
+	pub fn new(e: &'a dyn core::ToInputArray, points1: &'a dyn core::ToInputArray, points2: &'a dyn core::ToInputArray, camera_matrix: &'a dyn core::ToInputArray, r: &'a mut dyn core::ToOutputArray, t: &'a mut dyn core::ToOutputArray, distance_thresh: f64) -> Self {
+		Self { e, points1, points2, camera_matrix, r, t, distance_thresh, mask: None, triangulated_points: None }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_mask(mut self, mask: &'a mut dyn core::ToInputOutputArray) -> Self {
+		self.mask = Some(mask);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_triangulated_points(mut self, triangulated_points: &'a mut dyn core::ToOutputArray) -> Self {
+		self.triangulated_points = Some(triangulated_points);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<i32> {
+		let mut mask_no_array = core::no_array();
+		let mask: &mut dyn core::ToInputOutputArray = match self.mask { Some(mask) => mask, None => &mut mask_no_array };
+		let mut triangulated_points_no_array = core::no_array();
+		let triangulated_points: &mut dyn core::ToOutputArray = match self.triangulated_points { Some(triangulated_points) => triangulated_points, None => &mut triangulated_points_no_array };
+		recover_pose_triangulated(self.e, self.points1, self.points2, self.camera_matrix, self.r, self.t, self.distance_thresh, mask, triangulated_points)
+	}
+}
+
 /// Recovers the relative camera rotation and the translation from an estimated essential
 /// matrix and the corresponding points in two images, using cheirality check. Returns the number of
 /// inliers that pass the check.
@@ -4054,18 +5674,18 @@ pub fn recover_pose_triangulated(e: &dyn core::ToInputArray, points1: &dyn core:
 /// that performs a change of basis from the first camera's coordinate system to the second camera's
 /// coordinate system. Note that, in general, t can not be used for this tuple, see the parameter
 /// described below.
-/// * t: Output translation vector. This vector is obtained by @ref decomposeEssentialMat and
+/// * t: Output translation vector. This vector is obtained by [decompose_essential_mat](crate::calib3d::decompose_essential_mat) and
 /// therefore is only known up to scale, i.e. t is the direction of the translation vector and has unit
 /// length.
 /// * mask: Input/output mask for inliers in points1 and points2. If it is not empty, then it marks
 /// inliers in points1 and points2 for then given essential matrix E. Only these inliers will be used to
 /// recover pose. In the output mask only inliers which pass the cheirality check.
 /// 
-/// This function decomposes an essential matrix using @ref decomposeEssentialMat and then verifies
+/// This function decomposes an essential matrix using [decompose_essential_mat](crate::calib3d::decompose_essential_mat) and then verifies
 /// possible pose hypotheses by doing cheirality check. The cheirality check means that the
 /// triangulated 3D points should have positive depth. Some details can be found in [Nister03](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_Nister03).
 /// 
-/// This function can be used to process the output E and mask from @ref findEssentialMat. In this
+/// This function can be used to process the output E and mask from [find_essential_mat](crate::calib3d::find_essential_mat). In this
 /// scenario, points1 and points2 are the same input for #findEssentialMat :
 /// ```ignore
 ///    // Example. Estimation of fundamental matrix using the RANSAC algorithm
@@ -4100,7 +5720,7 @@ pub fn recover_pose_triangulated(e: &dyn core::ToInputArray, points1: &dyn core:
 /// that performs a change of basis from the first camera's coordinate system to the second camera's
 /// coordinate system. Note that, in general, t can not be used for this tuple, see the parameter
 /// description below.
-/// * t: Output translation vector. This vector is obtained by @ref decomposeEssentialMat and
+/// * t: Output translation vector. This vector is obtained by [decompose_essential_mat](crate::calib3d::decompose_essential_mat) and
 /// therefore is only known up to scale, i.e. t is the direction of the translation vector and has unit
 /// length.
 /// * focal: Focal length of the camera. Note that this function assumes that points1 and points2
@@ -4128,12 +5748,62 @@ pub fn recover_pose(e: &dyn core::ToInputArray, points1: &dyn core::ToInputArray
 	output_array_arg!(t);
 	input_output_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_recoverPose_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_double_Point2d_const__InputOutputArrayR(e.as_raw__InputArray(), points1.as_raw__InputArray(), points2.as_raw__InputArray(), r.as_raw__OutputArray(), t.as_raw__OutputArray(), focal, pp.opencv_as_extern(), mask.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_recoverPose_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_double_Point2d_const__InputOutputArrayR(e.as_raw__InputArray(), points1.as_raw__InputArray(), points2.as_raw__InputArray(), r.as_raw__OutputArray(), t.as_raw__OutputArray(), focal, pp.opencv_as_extern(), mask.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `recover_pose()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct RecoverPoseBuilder<'a> {
+	e: &'a dyn core::ToInputArray,
+	points1: &'a dyn core::ToInputArray,
+	points2: &'a dyn core::ToInputArray,
+	r: &'a mut dyn core::ToOutputArray,
+	t: &'a mut dyn core::ToOutputArray,
+	focal: f64,
+	pp: core::Point2d,
+	mask: Option<&'a mut dyn core::ToInputOutputArray>,
+}
+
+impl<'a> RecoverPoseBuilder<'a> {
+	#[inline]
+	pub fn new(e: &'a dyn core::ToInputArray, points1: &'a dyn core::ToInputArray, points2: &'a dyn core::ToInputArray, r: &'a mut dyn core::ToOutputArray, t: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { e, points1, points2, r, t, focal: 1.0, pp: core::Point2d::new(0., 0.), mask: None }
+	}
+	
+	/// C++ default: `1.0`
+	#[inline]
+	pub fn with_focal(mut self, focal: f64) -> Self {
+		self.focal = focal;
+		self
+	}
+	
+	/// C++ default: `Point2d(0,0)`
+	#[inline]
+	pub fn with_pp(mut self, pp: core::Point2d) -> Self {
+		self.pp = pp;
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_mask(mut self, mask: &'a mut dyn core::ToInputOutputArray) -> Self {
+		self.mask = Some(mask);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<i32> {
+		let mut mask_no_array = core::no_array();
+		let mask: &mut dyn core::ToInputOutputArray = match self.mask { Some(mask) => mask, None => &mut mask_no_array };
+		recover_pose(self.e, self.points1, self.points2, self.r, self.t, self.focal, self.pp, mask)
+	}
+}
+
 /// computes the rectification transformations for 3-head camera, where all the heads are on the same line.
 #[inline]
 pub fn rectify3_collinear(camera_matrix1: &dyn core::ToInputArray, dist_coeffs1: &dyn core::ToInputArray, camera_matrix2: &dyn core::ToInputArray, dist_coeffs2: &dyn core::ToInputArray, camera_matrix3: &dyn core::ToInputArray, dist_coeffs3: &dyn core::ToInputArray, imgpt1: &dyn core::ToInputArray, imgpt3: &dyn core::ToInputArray, image_size: core::Size, r12: &dyn core::ToInputArray, t12: &dyn core::ToInputArray, r13: &dyn core::ToInputArray, t13: &dyn core::ToInputArray, r1: &mut dyn core::ToOutputArray, r2: &mut dyn core::ToOutputArray, r3: &mut dyn core::ToOutputArray, p1: &mut dyn core::ToOutputArray, p2: &mut dyn core::ToOutputArray, p3: &mut dyn core::ToOutputArray, q: &mut dyn core::ToOutputArray, alpha: f64, new_img_size: core::Size, roi1: &mut core::Rect, roi2: &mut core::Rect, flags: i32) -> Result<f32> {
@@ -4157,7 +5827,7 @@ pub fn rectify3_collinear(camera_matrix1: &dyn core::ToInputArray, dist_coeffs1:
 	output_array_arg!(p3);
 	output_array_arg!(q);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_rectify3Collinear_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_Size_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_double_Size_RectX_RectX_int(camera_matrix1.as_raw__InputArray(), dist_coeffs1.as_raw__InputArray(), camera_matrix2.as_raw__InputArray(), dist_coeffs2.as_raw__InputArray(), camera_matrix3.as_raw__InputArray(), dist_coeffs3.as_raw__InputArray(), imgpt1.as_raw__InputArray(), imgpt3.as_raw__InputArray(), image_size.opencv_as_extern(), r12.as_raw__InputArray(), t12.as_raw__InputArray(), r13.as_raw__InputArray(), t13.as_raw__InputArray(), r1.as_raw__OutputArray(), r2.as_raw__OutputArray(), r3.as_raw__OutputArray(), p1.as_raw__OutputArray(), p2.as_raw__OutputArray(), p3.as_raw__OutputArray(), q.as_raw__OutputArray(), alpha, new_img_size.opencv_as_extern(), roi1, roi2, flags, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_rectify3Collinear_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_Size_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_double_Size_RectX_RectX_int(camera_matrix1.as_raw__InputArray(), dist_coeffs1.as_raw__InputArray(), camera_matrix2.as_raw__InputArray(), dist_coeffs2.as_raw__InputArray(), camera_matrix3.as_raw__InputArray(), dist_coeffs3.as_raw__InputArray(), imgpt1.as_raw__InputArray(), imgpt3.as_raw__InputArray(), image_size.opencv_as_extern(), r12.as_raw__InputArray(), t12.as_raw__InputArray(), r13.as_raw__InputArray(), t13.as_raw__InputArray(), r1.as_raw__OutputArray(), r2.as_raw__OutputArray(), r3.as_raw__OutputArray(), p1.as_raw__OutputArray(), p2.as_raw__OutputArray(), p3.as_raw__OutputArray(), q.as_raw__OutputArray(), alpha, new_img_size.opencv_as_extern(), roi1, roi2, flags, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -4168,15 +5838,15 @@ pub fn rectify3_collinear(camera_matrix1: &dyn core::ToInputArray, dist_coeffs1:
 /// ## Parameters
 /// * disparity: Input single-channel 8-bit unsigned, 16-bit signed, 32-bit signed or 32-bit
 /// floating-point disparity image. The values of 8-bit / 16-bit signed formats are assumed to have no
-/// fractional bits. If the disparity is 16-bit signed format, as computed by @ref StereoBM or
-/// @ref StereoSGBM and maybe other algorithms, it should be divided by 16 (and scaled to float) before
+/// fractional bits. If the disparity is 16-bit signed format, as computed by [StereoBM](crate::calib3d::StereoBM) or
+/// [StereoSGBM](crate::calib3d::StereoSGBM) and maybe other algorithms, it should be divided by 16 (and scaled to float) before
 /// being used here.
 /// * _3dImage: Output 3-channel floating-point image of the same size as disparity. Each element of
 /// _3dImage(x,y) contains 3D coordinates of the point (x,y) computed from the disparity map. If one
-/// uses Q obtained by @ref stereoRectify, then the returned points are represented in the first
+/// uses Q obtained by [stereo_rectify](crate::calib3d::stereo_rectify), then the returned points are represented in the first
 /// camera's rectified coordinate system.
 /// * Q: ![inline formula](https://latex.codecogs.com/png.latex?4%20%5Ctimes%204) perspective transformation matrix that can be obtained with
-/// @ref stereoRectify.
+/// [stereo_rectify](crate::calib3d::stereo_rectify).
 /// * handleMissingValues: Indicates, whether the function should handle missing values (i.e.
 /// points where the disparity was not computed). If handleMissingValues=true, then pixels with the
 /// minimal disparity that corresponds to the outliers (see StereoMatcher::compute ) are transformed
@@ -4201,7 +5871,7 @@ pub fn reproject_image_to_3d(disparity: &dyn core::ToInputArray, _3d_image: &mut
 	output_array_arg!(_3d_image);
 	input_array_arg!(q);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_reprojectImageTo3D_const__InputArrayR_const__OutputArrayR_const__InputArrayR_bool_int(disparity.as_raw__InputArray(), _3d_image.as_raw__OutputArray(), q.as_raw__InputArray(), handle_missing_values, ddepth, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_reprojectImageTo3D_const__InputArrayR_const__OutputArrayR_const__InputArrayR_bool_int(disparity.as_raw__InputArray(), _3d_image.as_raw__OutputArray(), q.as_raw__InputArray(), handle_missing_values, ddepth, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -4224,7 +5894,7 @@ pub fn sampson_distance(pt1: &dyn core::ToInputArray, pt2: &dyn core::ToInputArr
 	input_array_arg!(pt2);
 	input_array_arg!(f);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sampsonDistance_const__InputArrayR_const__InputArrayR_const__InputArrayR(pt1.as_raw__InputArray(), pt2.as_raw__InputArray(), f.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sampsonDistance_const__InputArrayR_const__InputArrayR_const__InputArrayR(pt1.as_raw__InputArray(), pt2.as_raw__InputArray(), f.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -4232,7 +5902,7 @@ pub fn sampson_distance(pt1: &dyn core::ToInputArray, pt2: &dyn core::ToInputArr
 
 /// Finds an object pose from 3 3D-2D point correspondences.
 /// ## See also
-/// @ref calib3d_solvePnP
+/// calib3d_solvePnP
 /// 
 /// ## Parameters
 /// * objectPoints: Array of object points in the object coordinate space, 3x3 1-channel or
@@ -4243,13 +5913,13 @@ pub fn sampson_distance(pt1: &dyn core::ToInputArray, pt2: &dyn core::ToInputArr
 /// * distCoeffs: Input vector of distortion coefficients
 /// ![inline formula](https://latex.codecogs.com/png.latex?%5Cdistcoeffs). If the vector is NULL/empty, the zero distortion coefficients are
 /// assumed.
-/// * rvecs: Output rotation vectors (see @ref Rodrigues ) that, together with tvecs, brings points from
+/// * rvecs: Output rotation vectors (see [rodrigues](crate::calib3d::rodrigues) ) that, together with tvecs, brings points from
 /// the model coordinate system to the camera coordinate system. A P3P problem has up to 4 solutions.
 /// * tvecs: Output translation vectors.
 /// * flags: Method for solving a P3P problem:
-/// *   @ref SOLVEPNP_P3P Method is based on the paper of X.S. Gao, X.-R. Hou, J. Tang, H.-F. Chang
+/// *   `SOLVEPNP_P3P` Method is based on the paper of X.S. Gao, X.-R. Hou, J. Tang, H.-F. Chang
 /// "Complete Solution Classification for the Perspective-Three-Point Problem" ([gao2003complete](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_gao2003complete)).
-/// *   @ref SOLVEPNP_AP3P Method is based on the paper of T. Ke and S. Roumeliotis.
+/// *   `SOLVEPNP_AP3P` Method is based on the paper of T. Ke and S. Roumeliotis.
 /// "An Efficient Algebraic Solution to the Perspective-Three-Point Problem" ([Ke17](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_Ke17)).
 /// 
 /// The function estimates the object pose given 3 object points, their corresponding image
@@ -4267,7 +5937,7 @@ pub fn solve_p3p(object_points: &dyn core::ToInputArray, image_points: &dyn core
 	output_array_arg!(rvecs);
 	output_array_arg!(tvecs);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_solveP3P_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_int(object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_solveP3P_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_int(object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -4275,18 +5945,18 @@ pub fn solve_p3p(object_points: &dyn core::ToInputArray, image_points: &dyn core
 
 /// Finds an object pose from 3D-2D point correspondences.
 /// ## See also
-/// @ref calib3d_solvePnP
+/// calib3d_solvePnP
 /// 
 /// This function returns a list of all the possible solutions (a solution is a <rotation vector, translation vector>
 /// couple), depending on the number of input points and the chosen method:
-/// - P3P methods (@ref SOLVEPNP_P3P, @ref SOLVEPNP_AP3P): 3 or 4 input points. Number of returned solutions can be between 0 and 4 with 3 input points.
-/// - @ref SOLVEPNP_IPPE Input points must be >= 4 and object points must be coplanar. Returns 2 solutions.
-/// - @ref SOLVEPNP_IPPE_SQUARE Special case suitable for marker pose estimation.
+/// - P3P methods (`SOLVEPNP_P3P`, `SOLVEPNP_AP3P`): 3 or 4 input points. Number of returned solutions can be between 0 and 4 with 3 input points.
+/// - `SOLVEPNP_IPPE` Input points must be >= 4 and object points must be coplanar. Returns 2 solutions.
+/// - `SOLVEPNP_IPPE_SQUARE` Special case suitable for marker pose estimation.
 /// Number of input points must be 4 and 2 solutions are returned. Object points must be defined in the following order:
-///   - point 0: [-squareLength / 2,  squareLength / 2, 0]
-///   - point 1: [ squareLength / 2,  squareLength / 2, 0]
-///   - point 2: [ squareLength / 2, -squareLength / 2, 0]
-///   - point 3: [-squareLength / 2, -squareLength / 2, 0]
+///   - point 0: \[-squareLength / 2,  squareLength / 2, 0\]
+///   - point 1: \[ squareLength / 2,  squareLength / 2, 0\]
+///   - point 2: \[ squareLength / 2, -squareLength / 2, 0\]
+///   - point 3: \[-squareLength / 2, -squareLength / 2, 0\]
 /// - for all the other flags, number of input points must be >= 4 and object points can be in any configuration.
 /// Only 1 solution is returned.
 /// 
@@ -4299,22 +5969,22 @@ pub fn solve_p3p(object_points: &dyn core::ToInputArray, image_points: &dyn core
 /// * distCoeffs: Input vector of distortion coefficients
 /// ![inline formula](https://latex.codecogs.com/png.latex?%5Cdistcoeffs). If the vector is NULL/empty, the zero distortion coefficients are
 /// assumed.
-/// * rvecs: Vector of output rotation vectors (see @ref Rodrigues ) that, together with tvecs, brings points from
+/// * rvecs: Vector of output rotation vectors (see [rodrigues](crate::calib3d::rodrigues) ) that, together with tvecs, brings points from
 /// the model coordinate system to the camera coordinate system.
 /// * tvecs: Vector of output translation vectors.
 /// * useExtrinsicGuess: Parameter used for #SOLVEPNP_ITERATIVE. If true (1), the function uses
 /// the provided rvec and tvec values as initial approximations of the rotation and translation
 /// vectors, respectively, and further optimizes them.
-/// * flags: Method for solving a PnP problem: see @ref calib3d_solvePnP_flags
-/// * rvec: Rotation vector used to initialize an iterative PnP refinement algorithm, when flag is @ref SOLVEPNP_ITERATIVE
+/// * flags: Method for solving a PnP problem: see calib3d_solvePnP_flags
+/// * rvec: Rotation vector used to initialize an iterative PnP refinement algorithm, when flag is `SOLVEPNP_ITERATIVE`
 /// and useExtrinsicGuess is set to true.
-/// * tvec: Translation vector used to initialize an iterative PnP refinement algorithm, when flag is @ref SOLVEPNP_ITERATIVE
+/// * tvec: Translation vector used to initialize an iterative PnP refinement algorithm, when flag is `SOLVEPNP_ITERATIVE`
 /// and useExtrinsicGuess is set to true.
 /// * reprojectionError: Optional vector of reprojection error, that is the RMS error
 /// (![inline formula](https://latex.codecogs.com/png.latex?%20%5Ctext%7BRMSE%7D%20%3D%20%5Csqrt%7B%5Cfrac%7B%5Csum%5F%7Bi%7D%5E%7BN%7D%20%5Cleft%20%28%20%5Chat%7By%5Fi%7D%20%2D%20y%5Fi%20%5Cright%20%29%5E2%7D%7BN%7D%7D%20)) between the input image points
 /// and the 3D object points projected with the estimated pose.
 /// 
-/// More information is described in @ref calib3d_solvePnP
+/// More information is described in calib3d_solvePnP
 /// 
 /// 
 /// Note:
@@ -4329,23 +5999,23 @@ pub fn solve_p3p(object_points: &dyn core::ToInputArray, image_points: &dyn core
 ///        which requires 2-channel information.
 ///        - Thus, given some data D = np.array(...) where D.shape = (N,M), in order to use a subset of
 ///        it as, e.g., imagePoints, one must effectively copy it into a new array: imagePoints =
-///        np.ascontiguousarray(D[:,:2]).reshape((N,1,2))
-///    *   The methods @ref SOLVEPNP_DLS and @ref SOLVEPNP_UPNP cannot be used as the current implementations are
+///        np.ascontiguousarray(D\[:,:2\]).reshape((N,1,2))
+///    *   The methods `SOLVEPNP_DLS` and `SOLVEPNP_UPNP` cannot be used as the current implementations are
 ///        unstable and sometimes give completely wrong results. If you pass one of these two
-///        flags, @ref SOLVEPNP_EPNP method will be used instead.
-///    *   The minimum number of points is 4 in the general case. In the case of @ref SOLVEPNP_P3P and @ref SOLVEPNP_AP3P
+///        flags, `SOLVEPNP_EPNP` method will be used instead.
+///    *   The minimum number of points is 4 in the general case. In the case of `SOLVEPNP_P3P` and `SOLVEPNP_AP3P`
 ///        methods, it is required to use exactly 4 points (the first 3 points are used to estimate all the solutions
 ///        of the P3P problem, the last one is used to retain the best solution that minimizes the reprojection error).
-///    *   With @ref SOLVEPNP_ITERATIVE method and `useExtrinsicGuess=true`, the minimum number of points is 3 (3 points
+///    *   With `SOLVEPNP_ITERATIVE` method and `useExtrinsicGuess=true`, the minimum number of points is 3 (3 points
 ///        are sufficient to compute a pose but there are up to 4 solutions). The initial solution should be close to the
 ///        global solution to converge.
-///    *   With @ref SOLVEPNP_IPPE input points must be >= 4 and object points must be coplanar.
-///    *   With @ref SOLVEPNP_IPPE_SQUARE this is a special case suitable for marker pose estimation.
+///    *   With `SOLVEPNP_IPPE` input points must be >= 4 and object points must be coplanar.
+///    *   With `SOLVEPNP_IPPE_SQUARE` this is a special case suitable for marker pose estimation.
 ///        Number of input points must be 4. Object points must be defined in the following order:
-///          - point 0: [-squareLength / 2,  squareLength / 2, 0]
-///          - point 1: [ squareLength / 2,  squareLength / 2, 0]
-///          - point 2: [ squareLength / 2, -squareLength / 2, 0]
-///          - point 3: [-squareLength / 2, -squareLength / 2, 0]
+///          - point 0: \[-squareLength / 2,  squareLength / 2, 0\]
+///          - point 1: \[ squareLength / 2,  squareLength / 2, 0\]
+///          - point 2: \[ squareLength / 2, -squareLength / 2, 0\]
+///          - point 3: \[-squareLength / 2, -squareLength / 2, 0\]
 /// 
 /// ## C++ default parameters
 /// * use_extrinsic_guess: false
@@ -4365,15 +6035,79 @@ pub fn solve_pnp_generic(object_points: &dyn core::ToInputArray, image_points: &
 	input_array_arg!(tvec);
 	output_array_arg!(reprojection_error);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_solvePnPGeneric_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_bool_SolvePnPMethod_const__InputArrayR_const__InputArrayR_const__OutputArrayR(object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), use_extrinsic_guess, flags, rvec.as_raw__InputArray(), tvec.as_raw__InputArray(), reprojection_error.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_solvePnPGeneric_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_bool_SolvePnPMethod_const__InputArrayR_const__InputArrayR_const__OutputArrayR(object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), use_extrinsic_guess, flags, rvec.as_raw__InputArray(), tvec.as_raw__InputArray(), reprojection_error.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `solve_pnp_generic()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct SolvePnpGenericBuilder<'a> {
+	object_points: &'a dyn core::ToInputArray,
+	image_points: &'a dyn core::ToInputArray,
+	camera_matrix: &'a dyn core::ToInputArray,
+	dist_coeffs: &'a dyn core::ToInputArray,
+	rvecs: &'a mut dyn core::ToOutputArray,
+	tvecs: &'a mut dyn core::ToOutputArray,
+	use_extrinsic_guess: bool,
+	flags: crate::calib3d::SolvePnPMethod,
+	rvec: Option<&'a dyn core::ToInputArray>,
+	tvec: Option<&'a dyn core::ToInputArray>,
+	reprojection_error: Option<&'a mut dyn core::ToOutputArray>,
+}
+
+impl<'a> SolvePnpGenericBuilder<'a> {
+	#[inline]
+	pub fn new(object_points: &'a dyn core::ToInputArray, image_points: &'a dyn core::ToInputArray, camera_matrix: &'a dyn core::ToInputArray, dist_coeffs: &'a dyn core::ToInputArray, rvecs: &'a mut dyn core::ToOutputArray, tvecs: &'a mut dyn core::ToOutputArray, flags: crate::calib3d::SolvePnPMethod) -> Self {
+		Self { object_points, image_points, camera_matrix, dist_coeffs, rvecs, tvecs, use_extrinsic_guess: false, flags, rvec: None, tvec: None, reprojection_error: None }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_use_extrinsic_guess(mut self, use_extrinsic_guess: bool) -> Self {
+		self.use_extrinsic_guess = use_extrinsic_guess;
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_rvec(mut self, rvec: &'a dyn core::ToInputArray) -> Self {
+		self.rvec = Some(rvec);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_tvec(mut self, tvec: &'a dyn core::ToInputArray) -> Self {
+		self.tvec = Some(tvec);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_reprojection_error(mut self, reprojection_error: &'a mut dyn core::ToOutputArray) -> Self {
+		self.reprojection_error = Some(reprojection_error);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<i32> {
+		let rvec_no_array = core::no_array();
+		let rvec: &dyn core::ToInputArray = match self.rvec { Some(rvec) => rvec, None => &rvec_no_array };
+		let tvec_no_array = core::no_array();
+		let tvec: &dyn core::ToInputArray = match self.tvec { Some(tvec) => tvec, None => &tvec_no_array };
+		let mut reprojection_error_no_array = core::no_array();
+		let reprojection_error: &mut dyn core::ToOutputArray = match self.reprojection_error { Some(reprojection_error) => reprojection_error, None => &mut reprojection_error_no_array };
+		solve_pnp_generic(self.object_points, self.image_points, self.camera_matrix, self.dist_coeffs, self.rvecs, self.tvecs, self.use_extrinsic_guess, self.flags, rvec, tvec, reprojection_error)
+	}
+}
+
 /// Finds an object pose from 3D-2D point correspondences using the RANSAC scheme.
 /// ## See also
-/// @ref calib3d_solvePnP
+/// calib3d_solvePnP
 /// 
 /// ## Parameters
 /// * objectPoints: Array of object points in the object coordinate space, Nx3 1-channel or
@@ -4384,10 +6118,10 @@ pub fn solve_pnp_generic(object_points: &dyn core::ToInputArray, image_points: &
 /// * distCoeffs: Input vector of distortion coefficients
 /// ![inline formula](https://latex.codecogs.com/png.latex?%5Cdistcoeffs). If the vector is NULL/empty, the zero distortion coefficients are
 /// assumed.
-/// * rvec: Output rotation vector (see @ref Rodrigues ) that, together with tvec, brings points from
+/// * rvec: Output rotation vector (see [rodrigues](crate::calib3d::rodrigues) ) that, together with tvec, brings points from
 /// the model coordinate system to the camera coordinate system.
 /// * tvec: Output translation vector.
-/// * useExtrinsicGuess: Parameter used for @ref SOLVEPNP_ITERATIVE. If true (1), the function uses
+/// * useExtrinsicGuess: Parameter used for `SOLVEPNP_ITERATIVE`. If true (1), the function uses
 /// the provided rvec and tvec values as initial approximations of the rotation and translation
 /// vectors, respectively, and further optimizes them.
 /// * iterationsCount: Number of iterations.
@@ -4396,12 +6130,12 @@ pub fn solve_pnp_generic(object_points: &dyn core::ToInputArray, image_points: &
 /// an inlier.
 /// * confidence: The probability that the algorithm produces a useful result.
 /// * inliers: Output vector that contains indices of inliers in objectPoints and imagePoints .
-/// * flags: Method for solving a PnP problem (see @ref solvePnP ).
+/// * flags: Method for solving a PnP problem (see [solve_pnp](crate::calib3d::solve_pnp) ).
 /// 
 /// The function estimates an object pose given a set of object points, their corresponding image
 /// projections, as well as the camera intrinsic matrix and the distortion coefficients. This function finds such
 /// a pose that minimizes reprojection error, that is, the sum of squared distances between the observed
-/// projections imagePoints and the projected (using @ref projectPoints ) objectPoints. The use of RANSAC
+/// projections imagePoints and the projected (using [project_points](crate::calib3d::project_points) ) objectPoints. The use of RANSAC
 /// makes the function resistant to outliers.
 /// 
 /// 
@@ -4433,12 +6167,80 @@ pub fn solve_pnp_ransac(object_points: &dyn core::ToInputArray, image_points: &d
 	output_array_arg!(tvec);
 	output_array_arg!(inliers);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_solvePnPRansac_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_bool_int_float_double_const__OutputArrayR_int(object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvec.as_raw__OutputArray(), tvec.as_raw__OutputArray(), use_extrinsic_guess, iterations_count, reprojection_error, confidence, inliers.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_solvePnPRansac_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_bool_int_float_double_const__OutputArrayR_int(object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvec.as_raw__OutputArray(), tvec.as_raw__OutputArray(), use_extrinsic_guess, iterations_count, reprojection_error, confidence, inliers.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `solve_pnp_ransac()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct SolvePnpRansacBuilder<'a> {
+	object_points: &'a dyn core::ToInputArray,
+	image_points: &'a dyn core::ToInputArray,
+	camera_matrix: &'a dyn core::ToInputArray,
+	dist_coeffs: &'a dyn core::ToInputArray,
+	rvec: &'a mut dyn core::ToOutputArray,
+	tvec: &'a mut dyn core::ToOutputArray,
+	use_extrinsic_guess: bool,
+	iterations_count: i32,
+	reprojection_error: f32,
+	confidence: f64,
+	inliers: Option<&'a mut dyn core::ToOutputArray>,
+	flags: i32,
+}
+
+impl<'a> SolvePnpRansacBuilder<'a> {
+	#[inline]
+	pub fn new(object_points: &'a dyn core::ToInputArray, image_points: &'a dyn core::ToInputArray, camera_matrix: &'a dyn core::ToInputArray, dist_coeffs: &'a dyn core::ToInputArray, rvec: &'a mut dyn core::ToOutputArray, tvec: &'a mut dyn core::ToOutputArray, flags: i32) -> Self {
+		Self { object_points, image_points, camera_matrix, dist_coeffs, rvec, tvec, use_extrinsic_guess: false, iterations_count: 100, reprojection_error: 8.0, confidence: 0.99, inliers: None, flags }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_use_extrinsic_guess(mut self, use_extrinsic_guess: bool) -> Self {
+		self.use_extrinsic_guess = use_extrinsic_guess;
+		self
+	}
+	
+	/// C++ default: `100`
+	#[inline]
+	pub fn with_iterations_count(mut self, iterations_count: i32) -> Self {
+		self.iterations_count = iterations_count;
+		self
+	}
+	
+	/// C++ default: `8.0`
+	#[inline]
+	pub fn with_reprojection_error(mut self, reprojection_error: f32) -> Self {
+		self.reprojection_error = reprojection_error;
+		self
+	}
+	
+	/// C++ default: `0.99`
+	#[inline]
+	pub fn with_confidence(mut self, confidence: f64) -> Self {
+		self.confidence = confidence;
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_inliers(mut self, inliers: &'a mut dyn core::ToOutputArray) -> Self {
+		self.inliers = Some(inliers);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<bool> {
+		let mut inliers_no_array = core::no_array();
+		let inliers: &mut dyn core::ToOutputArray = match self.inliers { Some(inliers) => inliers, None => &mut inliers_no_array };
+		solve_pnp_ransac(self.object_points, self.image_points, self.camera_matrix, self.dist_coeffs, self.rvec, self.tvec, self.use_extrinsic_guess, self.iterations_count, self.reprojection_error, self.confidence, inliers, self.flags)
+	}
+}
+
 /// ## C++ default parameters
 /// * params: UsacParams()
 #[inline]
@@ -4451,7 +6253,7 @@ pub fn solve_pnp_ransac_1(object_points: &dyn core::ToInputArray, image_points:
 	output_array_arg!(tvec);
 	output_array_arg!(inliers);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_solvePnPRansac_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const_UsacParamsR(object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), camera_matrix.as_raw__InputOutputArray(), dist_coeffs.as_raw__InputArray(), rvec.as_raw__OutputArray(), tvec.as_raw__OutputArray(), inliers.as_raw__OutputArray(), &params, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_solvePnPRansac_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const_UsacParamsR(object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), camera_matrix.as_raw__InputOutputArray(), dist_coeffs.as_raw__InputArray(), rvec.as_raw__OutputArray(), tvec.as_raw__OutputArray(), inliers.as_raw__OutputArray(), &params, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -4460,7 +6262,7 @@ pub fn solve_pnp_ransac_1(object_points: &dyn core::ToInputArray, image_points:
 /// Refine a pose (the translation and the rotation that transform a 3D point expressed in the object coordinate frame
 /// to the camera coordinate frame) from a 3D-2D point correspondences and starting from an initial solution.
 /// ## See also
-/// @ref calib3d_solvePnP
+/// calib3d_solvePnP
 /// 
 /// ## Parameters
 /// * objectPoints: Array of object points in the object coordinate space, Nx3 1-channel or 1xN/Nx1 3-channel,
@@ -4471,7 +6273,7 @@ pub fn solve_pnp_ransac_1(object_points: &dyn core::ToInputArray, image_points:
 /// * distCoeffs: Input vector of distortion coefficients
 /// ![inline formula](https://latex.codecogs.com/png.latex?%5Cdistcoeffs). If the vector is NULL/empty, the zero distortion coefficients are
 /// assumed.
-/// * rvec: Input/Output rotation vector (see @ref Rodrigues ) that, together with tvec, brings points from
+/// * rvec: Input/Output rotation vector (see [rodrigues](crate::calib3d::rodrigues) ) that, together with tvec, brings points from
 /// the model coordinate system to the camera coordinate system. Input values are used as an initial solution.
 /// * tvec: Input/Output translation vector. Input values are used as an initial solution.
 /// * criteria: Criteria when to stop the Levenberg-Marquard iterative algorithm.
@@ -4493,7 +6295,7 @@ pub fn solve_pnp_refine_lm(object_points: &dyn core::ToInputArray, image_points:
 	input_output_array_arg!(rvec);
 	input_output_array_arg!(tvec);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_solvePnPRefineLM_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_TermCriteria(object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvec.as_raw__InputOutputArray(), tvec.as_raw__InputOutputArray(), criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_solvePnPRefineLM_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_TermCriteria(object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvec.as_raw__InputOutputArray(), tvec.as_raw__InputOutputArray(), criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -4502,7 +6304,7 @@ pub fn solve_pnp_refine_lm(object_points: &dyn core::ToInputArray, image_points:
 /// Refine a pose (the translation and the rotation that transform a 3D point expressed in the object coordinate frame
 /// to the camera coordinate frame) from a 3D-2D point correspondences and starting from an initial solution.
 /// ## See also
-/// @ref calib3d_solvePnP
+/// calib3d_solvePnP
 /// 
 /// ## Parameters
 /// * objectPoints: Array of object points in the object coordinate space, Nx3 1-channel or 1xN/Nx1 3-channel,
@@ -4513,7 +6315,7 @@ pub fn solve_pnp_refine_lm(object_points: &dyn core::ToInputArray, image_points:
 /// * distCoeffs: Input vector of distortion coefficients
 /// ![inline formula](https://latex.codecogs.com/png.latex?%5Cdistcoeffs). If the vector is NULL/empty, the zero distortion coefficients are
 /// assumed.
-/// * rvec: Input/Output rotation vector (see @ref Rodrigues ) that, together with tvec, brings points from
+/// * rvec: Input/Output rotation vector (see [rodrigues](crate::calib3d::rodrigues) ) that, together with tvec, brings points from
 /// the model coordinate system to the camera coordinate system. Input values are used as an initial solution.
 /// * tvec: Input/Output translation vector. Input values are used as an initial solution.
 /// * criteria: Criteria when to stop the Levenberg-Marquard iterative algorithm.
@@ -4538,26 +6340,60 @@ pub fn solve_pnp_refine_vvs(object_points: &dyn core::ToInputArray, image_points
 	input_output_array_arg!(rvec);
 	input_output_array_arg!(tvec);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_solvePnPRefineVVS_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_TermCriteria_double(object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvec.as_raw__InputOutputArray(), tvec.as_raw__InputOutputArray(), criteria.opencv_as_extern(), vv_slambda, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_solvePnPRefineVVS_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_TermCriteria_double(object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvec.as_raw__InputOutputArray(), tvec.as_raw__InputOutputArray(), criteria.opencv_as_extern(), vv_slambda, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `solve_pnp_refine_vvs()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct SolvePnpRefineVvsBuilder<'a> {
+	object_points: &'a dyn core::ToInputArray,
+	image_points: &'a dyn core::ToInputArray,
+	camera_matrix: &'a dyn core::ToInputArray,
+	dist_coeffs: &'a dyn core::ToInputArray,
+	rvec: &'a mut dyn core::ToInputOutputArray,
+	tvec: &'a mut dyn core::ToInputOutputArray,
+	criteria: core::TermCriteria,
+	vv_slambda: f64,
+}
+
+impl<'a> SolvePnpRefineVvsBuilder<'a> {
+	#[inline]
+	pub fn new(object_points: &'a dyn core::ToInputArray, image_points: &'a dyn core::ToInputArray, camera_matrix: &'a dyn core::ToInputArray, dist_coeffs: &'a dyn core::ToInputArray, rvec: &'a mut dyn core::ToInputOutputArray, tvec: &'a mut dyn core::ToInputOutputArray, criteria: core::TermCriteria) -> Self {
+		Self { object_points, image_points, camera_matrix, dist_coeffs, rvec, tvec, criteria, vv_slambda: 1. }
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_vv_slambda(mut self, vv_slambda: f64) -> Self {
+		self.vv_slambda = vv_slambda;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		solve_pnp_refine_vvs(self.object_points, self.image_points, self.camera_matrix, self.dist_coeffs, self.rvec, self.tvec, self.criteria, self.vv_slambda)
+	}
+}
+
 /// Finds an object pose from 3D-2D point correspondences.
 /// ## See also
-/// @ref calib3d_solvePnP
+/// calib3d_solvePnP
 /// 
 /// This function returns the rotation and the translation vectors that transform a 3D point expressed in the object
 /// coordinate frame to the camera coordinate frame, using different methods:
-/// - P3P methods (@ref SOLVEPNP_P3P, @ref SOLVEPNP_AP3P): need 4 input points to return a unique solution.
-/// - @ref SOLVEPNP_IPPE Input points must be >= 4 and object points must be coplanar.
-/// - @ref SOLVEPNP_IPPE_SQUARE Special case suitable for marker pose estimation.
+/// - P3P methods (`SOLVEPNP_P3P`, `SOLVEPNP_AP3P`): need 4 input points to return a unique solution.
+/// - `SOLVEPNP_IPPE` Input points must be >= 4 and object points must be coplanar.
+/// - `SOLVEPNP_IPPE_SQUARE` Special case suitable for marker pose estimation.
 /// Number of input points must be 4. Object points must be defined in the following order:
-///   - point 0: [-squareLength / 2,  squareLength / 2, 0]
-///   - point 1: [ squareLength / 2,  squareLength / 2, 0]
-///   - point 2: [ squareLength / 2, -squareLength / 2, 0]
-///   - point 3: [-squareLength / 2, -squareLength / 2, 0]
+///   - point 0: \[-squareLength / 2,  squareLength / 2, 0\]
+///   - point 1: \[ squareLength / 2,  squareLength / 2, 0\]
+///   - point 2: \[ squareLength / 2, -squareLength / 2, 0\]
+///   - point 3: \[-squareLength / 2, -squareLength / 2, 0\]
 /// - for all the other flags, number of input points must be >= 4 and object points can be in any configuration.
 /// 
 /// ## Parameters
@@ -4569,15 +6405,15 @@ pub fn solve_pnp_refine_vvs(object_points: &dyn core::ToInputArray, image_points
 /// * distCoeffs: Input vector of distortion coefficients
 /// ![inline formula](https://latex.codecogs.com/png.latex?%5Cdistcoeffs). If the vector is NULL/empty, the zero distortion coefficients are
 /// assumed.
-/// * rvec: Output rotation vector (see @ref Rodrigues ) that, together with tvec, brings points from
+/// * rvec: Output rotation vector (see [rodrigues](crate::calib3d::rodrigues) ) that, together with tvec, brings points from
 /// the model coordinate system to the camera coordinate system.
 /// * tvec: Output translation vector.
 /// * useExtrinsicGuess: Parameter used for #SOLVEPNP_ITERATIVE. If true (1), the function uses
 /// the provided rvec and tvec values as initial approximations of the rotation and translation
 /// vectors, respectively, and further optimizes them.
-/// * flags: Method for solving a PnP problem: see @ref calib3d_solvePnP_flags
+/// * flags: Method for solving a PnP problem: see calib3d_solvePnP_flags
 /// 
-/// More information about Perspective-n-Points is described in @ref calib3d_solvePnP
+/// More information about Perspective-n-Points is described in calib3d_solvePnP
 /// 
 /// 
 /// Note:
@@ -4592,24 +6428,24 @@ pub fn solve_pnp_refine_vvs(object_points: &dyn core::ToInputArray, image_points
 ///        which requires 2-channel information.
 ///        - Thus, given some data D = np.array(...) where D.shape = (N,M), in order to use a subset of
 ///        it as, e.g., imagePoints, one must effectively copy it into a new array: imagePoints =
-///        np.ascontiguousarray(D[:,:2]).reshape((N,1,2))
-///    *   The methods @ref SOLVEPNP_DLS and @ref SOLVEPNP_UPNP cannot be used as the current implementations are
+///        np.ascontiguousarray(D\[:,:2\]).reshape((N,1,2))
+///    *   The methods `SOLVEPNP_DLS` and `SOLVEPNP_UPNP` cannot be used as the current implementations are
 ///        unstable and sometimes give completely wrong results. If you pass one of these two
-///        flags, @ref SOLVEPNP_EPNP method will be used instead.
-///    *   The minimum number of points is 4 in the general case. In the case of @ref SOLVEPNP_P3P and @ref SOLVEPNP_AP3P
+///        flags, `SOLVEPNP_EPNP` method will be used instead.
+///    *   The minimum number of points is 4 in the general case. In the case of `SOLVEPNP_P3P` and `SOLVEPNP_AP3P`
 ///        methods, it is required to use exactly 4 points (the first 3 points are used to estimate all the solutions
 ///        of the P3P problem, the last one is used to retain the best solution that minimizes the reprojection error).
-///    *   With @ref SOLVEPNP_ITERATIVE method and `useExtrinsicGuess=true`, the minimum number of points is 3 (3 points
+///    *   With `SOLVEPNP_ITERATIVE` method and `useExtrinsicGuess=true`, the minimum number of points is 3 (3 points
 ///        are sufficient to compute a pose but there are up to 4 solutions). The initial solution should be close to the
 ///        global solution to converge.
-///    *   With @ref SOLVEPNP_IPPE input points must be >= 4 and object points must be coplanar.
-///    *   With @ref SOLVEPNP_IPPE_SQUARE this is a special case suitable for marker pose estimation.
+///    *   With `SOLVEPNP_IPPE` input points must be >= 4 and object points must be coplanar.
+///    *   With `SOLVEPNP_IPPE_SQUARE` this is a special case suitable for marker pose estimation.
 ///        Number of input points must be 4. Object points must be defined in the following order:
-///          - point 0: [-squareLength / 2,  squareLength / 2, 0]
-///          - point 1: [ squareLength / 2,  squareLength / 2, 0]
-///          - point 2: [ squareLength / 2, -squareLength / 2, 0]
-///          - point 3: [-squareLength / 2, -squareLength / 2, 0]
-///    *  With @ref SOLVEPNP_SQPNP input points must be >= 3
+///          - point 0: \[-squareLength / 2,  squareLength / 2, 0\]
+///          - point 1: \[ squareLength / 2,  squareLength / 2, 0\]
+///          - point 2: \[ squareLength / 2, -squareLength / 2, 0\]
+///          - point 3: \[-squareLength / 2, -squareLength / 2, 0\]
+///    *  With `SOLVEPNP_SQPNP` input points must be >= 3
 /// 
 /// ## C++ default parameters
 /// * use_extrinsic_guess: false
@@ -4623,29 +6459,63 @@ pub fn solve_pnp(object_points: &dyn core::ToInputArray, image_points: &dyn core
 	output_array_arg!(rvec);
 	output_array_arg!(tvec);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_solvePnP_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_bool_int(object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvec.as_raw__OutputArray(), tvec.as_raw__OutputArray(), use_extrinsic_guess, flags, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_solvePnP_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_bool_int(object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvec.as_raw__OutputArray(), tvec.as_raw__OutputArray(), use_extrinsic_guess, flags, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `solve_pnp()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct SolvePnpBuilder<'a> {
+	object_points: &'a dyn core::ToInputArray,
+	image_points: &'a dyn core::ToInputArray,
+	camera_matrix: &'a dyn core::ToInputArray,
+	dist_coeffs: &'a dyn core::ToInputArray,
+	rvec: &'a mut dyn core::ToOutputArray,
+	tvec: &'a mut dyn core::ToOutputArray,
+	use_extrinsic_guess: bool,
+	flags: i32,
+}
+
+impl<'a> SolvePnpBuilder<'a> {
+	#[inline]
+	pub fn new(object_points: &'a dyn core::ToInputArray, image_points: &'a dyn core::ToInputArray, camera_matrix: &'a dyn core::ToInputArray, dist_coeffs: &'a dyn core::ToInputArray, rvec: &'a mut dyn core::ToOutputArray, tvec: &'a mut dyn core::ToOutputArray, flags: i32) -> Self {
+		Self { object_points, image_points, camera_matrix, dist_coeffs, rvec, tvec, use_extrinsic_guess: false, flags }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_use_extrinsic_guess(mut self, use_extrinsic_guess: bool) -> Self {
+		self.use_extrinsic_guess = use_extrinsic_guess;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<bool> {
+		solve_pnp(self.object_points, self.image_points, self.camera_matrix, self.dist_coeffs, self.rvec, self.tvec, self.use_extrinsic_guess, self.flags)
+	}
+}
+
 /// Calibrates a stereo camera set up. This function finds the intrinsic parameters
 /// for each of the two cameras and the extrinsic parameters between the two cameras.
 /// 
 /// ## Parameters
 /// * objectPoints: Vector of vectors of the calibration pattern points. The same structure as
-/// in @ref calibrateCamera. For each pattern view, both cameras need to see the same object
+/// in [calibrate_camera](crate::calib3d::calibrate_camera). For each pattern view, both cameras need to see the same object
 /// points. Therefore, objectPoints.size(), imagePoints1.size(), and imagePoints2.size() need to be
-/// equal as well as objectPoints[i].size(), imagePoints1[i].size(), and imagePoints2[i].size() need to
+/// equal as well as objectPoints\[i\].size(), imagePoints1\[i\].size(), and imagePoints2\[i\].size() need to
 /// be equal for each i.
 /// * imagePoints1: Vector of vectors of the projections of the calibration pattern points,
-/// observed by the first camera. The same structure as in @ref calibrateCamera.
+/// observed by the first camera. The same structure as in [calibrate_camera](crate::calib3d::calibrate_camera).
 /// * imagePoints2: Vector of vectors of the projections of the calibration pattern points,
-/// observed by the second camera. The same structure as in @ref calibrateCamera.
+/// observed by the second camera. The same structure as in [calibrate_camera](crate::calib3d::calibrate_camera).
 /// * cameraMatrix1: Input/output camera intrinsic matrix for the first camera, the same as in
-/// @ref calibrateCamera. Furthermore, for the stereo case, additional flags may be used, see below.
+/// [calibrate_camera](crate::calib3d::calibrate_camera). Furthermore, for the stereo case, additional flags may be used, see below.
 /// * distCoeffs1: Input/output vector of distortion coefficients, the same as in
-/// @ref calibrateCamera.
+/// [calibrate_camera](crate::calib3d::calibrate_camera).
 /// * cameraMatrix2: Input/output second camera intrinsic matrix for the second camera. See description for
 /// cameraMatrix1.
 /// * distCoeffs2: Input/output lens distortion coefficients for the second camera. See
@@ -4662,39 +6532,39 @@ pub fn solve_pnp(object_points: &dyn core::ToInputArray, image_points: &dyn core
 /// * F: Output fundamental matrix.
 /// * perViewErrors: Output vector of the RMS re-projection error estimated for each pattern view.
 /// * flags: Different flags that may be zero or a combination of the following values:
-/// *   @ref CALIB_FIX_INTRINSIC Fix cameraMatrix? and distCoeffs? so that only R, T, E, and F
+/// *   `CALIB_FIX_INTRINSIC` Fix cameraMatrix? and distCoeffs? so that only R, T, E, and F
 /// matrices are estimated.
-/// *   @ref CALIB_USE_INTRINSIC_GUESS Optimize some or all of the intrinsic parameters
+/// *   `CALIB_USE_INTRINSIC_GUESS` Optimize some or all of the intrinsic parameters
 /// according to the specified flags. Initial values are provided by the user.
-/// *   @ref CALIB_USE_EXTRINSIC_GUESS R and T contain valid initial values that are optimized further.
+/// *   `CALIB_USE_EXTRINSIC_GUESS` R and T contain valid initial values that are optimized further.
 /// Otherwise R and T are initialized to the median value of the pattern views (each dimension separately).
-/// *   @ref CALIB_FIX_PRINCIPAL_POINT Fix the principal points during the optimization.
-/// *   @ref CALIB_FIX_FOCAL_LENGTH Fix ![inline formula](https://latex.codecogs.com/png.latex?f%5E%7B%28j%29%7D%5Fx) and ![inline formula](https://latex.codecogs.com/png.latex?f%5E%7B%28j%29%7D%5Fy) .
-/// *   @ref CALIB_FIX_ASPECT_RATIO Optimize ![inline formula](https://latex.codecogs.com/png.latex?f%5E%7B%28j%29%7D%5Fy) . Fix the ratio ![inline formula](https://latex.codecogs.com/png.latex?f%5E%7B%28j%29%7D%5Fx%2Ff%5E%7B%28j%29%7D%5Fy)
+/// *   `CALIB_FIX_PRINCIPAL_POINT` Fix the principal points during the optimization.
+/// *   `CALIB_FIX_FOCAL_LENGTH` Fix ![inline formula](https://latex.codecogs.com/png.latex?f%5E%7B%28j%29%7D%5Fx) and ![inline formula](https://latex.codecogs.com/png.latex?f%5E%7B%28j%29%7D%5Fy) .
+/// *   `CALIB_FIX_ASPECT_RATIO` Optimize ![inline formula](https://latex.codecogs.com/png.latex?f%5E%7B%28j%29%7D%5Fy) . Fix the ratio ![inline formula](https://latex.codecogs.com/png.latex?f%5E%7B%28j%29%7D%5Fx%2Ff%5E%7B%28j%29%7D%5Fy)
 /// .
-/// *   @ref CALIB_SAME_FOCAL_LENGTH Enforce ![inline formula](https://latex.codecogs.com/png.latex?f%5E%7B%280%29%7D%5Fx%3Df%5E%7B%281%29%7D%5Fx) and ![inline formula](https://latex.codecogs.com/png.latex?f%5E%7B%280%29%7D%5Fy%3Df%5E%7B%281%29%7D%5Fy) .
-/// *   @ref CALIB_ZERO_TANGENT_DIST Set tangential distortion coefficients for each camera to
+/// *   `CALIB_SAME_FOCAL_LENGTH` Enforce ![inline formula](https://latex.codecogs.com/png.latex?f%5E%7B%280%29%7D%5Fx%3Df%5E%7B%281%29%7D%5Fx) and ![inline formula](https://latex.codecogs.com/png.latex?f%5E%7B%280%29%7D%5Fy%3Df%5E%7B%281%29%7D%5Fy) .
+/// *   `CALIB_ZERO_TANGENT_DIST` Set tangential distortion coefficients for each camera to
 /// zeros and fix there.
-/// *   @ref CALIB_FIX_K1,..., @ref CALIB_FIX_K6 Do not change the corresponding radial
-/// distortion coefficient during the optimization. If @ref CALIB_USE_INTRINSIC_GUESS is set,
+/// *   `CALIB_FIX_K1`,..., `CALIB_FIX_K6` Do not change the corresponding radial
+/// distortion coefficient during the optimization. If `CALIB_USE_INTRINSIC_GUESS` is set,
 /// the coefficient from the supplied distCoeffs matrix is used. Otherwise, it is set to 0.
-/// *   @ref CALIB_RATIONAL_MODEL Enable coefficients k4, k5, and k6. To provide the backward
+/// *   `CALIB_RATIONAL_MODEL` Enable coefficients k4, k5, and k6. To provide the backward
 /// compatibility, this extra flag should be explicitly specified to make the calibration
 /// function use the rational model and return 8 coefficients. If the flag is not set, the
 /// function computes and returns only 5 distortion coefficients.
-/// *   @ref CALIB_THIN_PRISM_MODEL Coefficients s1, s2, s3 and s4 are enabled. To provide the
+/// *   `CALIB_THIN_PRISM_MODEL` Coefficients s1, s2, s3 and s4 are enabled. To provide the
 /// backward compatibility, this extra flag should be explicitly specified to make the
 /// calibration function use the thin prism model and return 12 coefficients. If the flag is not
 /// set, the function computes and returns only 5 distortion coefficients.
-/// *   @ref CALIB_FIX_S1_S2_S3_S4 The thin prism distortion coefficients are not changed during
-/// the optimization. If @ref CALIB_USE_INTRINSIC_GUESS is set, the coefficient from the
+/// *   `CALIB_FIX_S1_S2_S3_S4` The thin prism distortion coefficients are not changed during
+/// the optimization. If `CALIB_USE_INTRINSIC_GUESS` is set, the coefficient from the
 /// supplied distCoeffs matrix is used. Otherwise, it is set to 0.
-/// *   @ref CALIB_TILTED_MODEL Coefficients tauX and tauY are enabled. To provide the
+/// *   `CALIB_TILTED_MODEL` Coefficients tauX and tauY are enabled. To provide the
 /// backward compatibility, this extra flag should be explicitly specified to make the
 /// calibration function use the tilted sensor model and return 14 coefficients. If the flag is not
 /// set, the function computes and returns only 5 distortion coefficients.
-/// *   @ref CALIB_FIX_TAUX_TAUY The coefficients of the tilted sensor model are not changed during
-/// the optimization. If @ref CALIB_USE_INTRINSIC_GUESS is set, the coefficient from the
+/// *   `CALIB_FIX_TAUX_TAUY` The coefficients of the tilted sensor model are not changed during
+/// the optimization. If `CALIB_USE_INTRINSIC_GUESS` is set, the coefficient from the
 /// supplied distCoeffs matrix is used. Otherwise, it is set to 0.
 /// * criteria: Termination criteria for the iterative optimization algorithm.
 /// 
@@ -4729,10 +6599,10 @@ pub fn solve_pnp(object_points: &dyn core::ToInputArray, image_points: &dyn core
 /// the two cameras. However, due to the high dimensionality of the parameter space and noise in the
 /// input data, the function can diverge from the correct solution. If the intrinsic parameters can be
 /// estimated with high accuracy for each of the cameras individually (for example, using
-/// #calibrateCamera ), you are recommended to do so and then pass @ref CALIB_FIX_INTRINSIC flag to the
+/// #calibrateCamera ), you are recommended to do so and then pass `CALIB_FIX_INTRINSIC` flag to the
 /// function along with the computed intrinsic parameters. Otherwise, if all the parameters are
 /// estimated at once, it makes sense to restrict some parameters, for example, pass
-///  @ref CALIB_SAME_FOCAL_LENGTH and @ref CALIB_ZERO_TANGENT_DIST flags, which is usually a
+///  `CALIB_SAME_FOCAL_LENGTH` and `CALIB_ZERO_TANGENT_DIST` flags, which is usually a
 /// reasonable assumption.
 /// 
 /// Similarly to #calibrateCamera, the function minimizes the total re-projection error for all the
@@ -4757,7 +6627,7 @@ pub fn stereo_calibrate_extended(object_points: &dyn core::ToInputArray, image_p
 	output_array_arg!(f);
 	output_array_arg!(per_view_errors);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_stereoCalibrate_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_Size_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int_TermCriteria(object_points.as_raw__InputArray(), image_points1.as_raw__InputArray(), image_points2.as_raw__InputArray(), camera_matrix1.as_raw__InputOutputArray(), dist_coeffs1.as_raw__InputOutputArray(), camera_matrix2.as_raw__InputOutputArray(), dist_coeffs2.as_raw__InputOutputArray(), image_size.opencv_as_extern(), r.as_raw__InputOutputArray(), t.as_raw__InputOutputArray(), e.as_raw__OutputArray(), f.as_raw__OutputArray(), per_view_errors.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_stereoCalibrate_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_Size_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int_TermCriteria(object_points.as_raw__InputArray(), image_points1.as_raw__InputArray(), image_points2.as_raw__InputArray(), camera_matrix1.as_raw__InputOutputArray(), dist_coeffs1.as_raw__InputOutputArray(), camera_matrix2.as_raw__InputOutputArray(), dist_coeffs2.as_raw__InputOutputArray(), image_size.opencv_as_extern(), r.as_raw__InputOutputArray(), t.as_raw__InputOutputArray(), e.as_raw__OutputArray(), f.as_raw__OutputArray(), per_view_errors.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -4768,18 +6638,18 @@ pub fn stereo_calibrate_extended(object_points: &dyn core::ToInputArray, image_p
 /// 
 /// ## Parameters
 /// * objectPoints: Vector of vectors of the calibration pattern points. The same structure as
-/// in @ref calibrateCamera. For each pattern view, both cameras need to see the same object
+/// in [calibrate_camera](crate::calib3d::calibrate_camera). For each pattern view, both cameras need to see the same object
 /// points. Therefore, objectPoints.size(), imagePoints1.size(), and imagePoints2.size() need to be
-/// equal as well as objectPoints[i].size(), imagePoints1[i].size(), and imagePoints2[i].size() need to
+/// equal as well as objectPoints\[i\].size(), imagePoints1\[i\].size(), and imagePoints2\[i\].size() need to
 /// be equal for each i.
 /// * imagePoints1: Vector of vectors of the projections of the calibration pattern points,
-/// observed by the first camera. The same structure as in @ref calibrateCamera.
+/// observed by the first camera. The same structure as in [calibrate_camera](crate::calib3d::calibrate_camera).
 /// * imagePoints2: Vector of vectors of the projections of the calibration pattern points,
-/// observed by the second camera. The same structure as in @ref calibrateCamera.
+/// observed by the second camera. The same structure as in [calibrate_camera](crate::calib3d::calibrate_camera).
 /// * cameraMatrix1: Input/output camera intrinsic matrix for the first camera, the same as in
-/// @ref calibrateCamera. Furthermore, for the stereo case, additional flags may be used, see below.
+/// [calibrate_camera](crate::calib3d::calibrate_camera). Furthermore, for the stereo case, additional flags may be used, see below.
 /// * distCoeffs1: Input/output vector of distortion coefficients, the same as in
-/// @ref calibrateCamera.
+/// [calibrate_camera](crate::calib3d::calibrate_camera).
 /// * cameraMatrix2: Input/output second camera intrinsic matrix for the second camera. See description for
 /// cameraMatrix1.
 /// * distCoeffs2: Input/output lens distortion coefficients for the second camera. See
@@ -4796,39 +6666,39 @@ pub fn stereo_calibrate_extended(object_points: &dyn core::ToInputArray, image_p
 /// * F: Output fundamental matrix.
 /// * perViewErrors: Output vector of the RMS re-projection error estimated for each pattern view.
 /// * flags: Different flags that may be zero or a combination of the following values:
-/// *   @ref CALIB_FIX_INTRINSIC Fix cameraMatrix? and distCoeffs? so that only R, T, E, and F
+/// *   `CALIB_FIX_INTRINSIC` Fix cameraMatrix? and distCoeffs? so that only R, T, E, and F
 /// matrices are estimated.
-/// *   @ref CALIB_USE_INTRINSIC_GUESS Optimize some or all of the intrinsic parameters
+/// *   `CALIB_USE_INTRINSIC_GUESS` Optimize some or all of the intrinsic parameters
 /// according to the specified flags. Initial values are provided by the user.
-/// *   @ref CALIB_USE_EXTRINSIC_GUESS R and T contain valid initial values that are optimized further.
+/// *   `CALIB_USE_EXTRINSIC_GUESS` R and T contain valid initial values that are optimized further.
 /// Otherwise R and T are initialized to the median value of the pattern views (each dimension separately).
-/// *   @ref CALIB_FIX_PRINCIPAL_POINT Fix the principal points during the optimization.
-/// *   @ref CALIB_FIX_FOCAL_LENGTH Fix ![inline formula](https://latex.codecogs.com/png.latex?f%5E%7B%28j%29%7D%5Fx) and ![inline formula](https://latex.codecogs.com/png.latex?f%5E%7B%28j%29%7D%5Fy) .
-/// *   @ref CALIB_FIX_ASPECT_RATIO Optimize ![inline formula](https://latex.codecogs.com/png.latex?f%5E%7B%28j%29%7D%5Fy) . Fix the ratio ![inline formula](https://latex.codecogs.com/png.latex?f%5E%7B%28j%29%7D%5Fx%2Ff%5E%7B%28j%29%7D%5Fy)
+/// *   `CALIB_FIX_PRINCIPAL_POINT` Fix the principal points during the optimization.
+/// *   `CALIB_FIX_FOCAL_LENGTH` Fix ![inline formula](https://latex.codecogs.com/png.latex?f%5E%7B%28j%29%7D%5Fx) and ![inline formula](https://latex.codecogs.com/png.latex?f%5E%7B%28j%29%7D%5Fy) .
+/// *   `CALIB_FIX_ASPECT_RATIO` Optimize ![inline formula](https://latex.codecogs.com/png.latex?f%5E%7B%28j%29%7D%5Fy) . Fix the ratio ![inline formula](https://latex.codecogs.com/png.latex?f%5E%7B%28j%29%7D%5Fx%2Ff%5E%7B%28j%29%7D%5Fy)
 /// .
-/// *   @ref CALIB_SAME_FOCAL_LENGTH Enforce ![inline formula](https://latex.codecogs.com/png.latex?f%5E%7B%280%29%7D%5Fx%3Df%5E%7B%281%29%7D%5Fx) and ![inline formula](https://latex.codecogs.com/png.latex?f%5E%7B%280%29%7D%5Fy%3Df%5E%7B%281%29%7D%5Fy) .
-/// *   @ref CALIB_ZERO_TANGENT_DIST Set tangential distortion coefficients for each camera to
+/// *   `CALIB_SAME_FOCAL_LENGTH` Enforce ![inline formula](https://latex.codecogs.com/png.latex?f%5E%7B%280%29%7D%5Fx%3Df%5E%7B%281%29%7D%5Fx) and ![inline formula](https://latex.codecogs.com/png.latex?f%5E%7B%280%29%7D%5Fy%3Df%5E%7B%281%29%7D%5Fy) .
+/// *   `CALIB_ZERO_TANGENT_DIST` Set tangential distortion coefficients for each camera to
 /// zeros and fix there.
-/// *   @ref CALIB_FIX_K1,..., @ref CALIB_FIX_K6 Do not change the corresponding radial
-/// distortion coefficient during the optimization. If @ref CALIB_USE_INTRINSIC_GUESS is set,
+/// *   `CALIB_FIX_K1`,..., `CALIB_FIX_K6` Do not change the corresponding radial
+/// distortion coefficient during the optimization. If `CALIB_USE_INTRINSIC_GUESS` is set,
 /// the coefficient from the supplied distCoeffs matrix is used. Otherwise, it is set to 0.
-/// *   @ref CALIB_RATIONAL_MODEL Enable coefficients k4, k5, and k6. To provide the backward
+/// *   `CALIB_RATIONAL_MODEL` Enable coefficients k4, k5, and k6. To provide the backward
 /// compatibility, this extra flag should be explicitly specified to make the calibration
 /// function use the rational model and return 8 coefficients. If the flag is not set, the
 /// function computes and returns only 5 distortion coefficients.
-/// *   @ref CALIB_THIN_PRISM_MODEL Coefficients s1, s2, s3 and s4 are enabled. To provide the
+/// *   `CALIB_THIN_PRISM_MODEL` Coefficients s1, s2, s3 and s4 are enabled. To provide the
 /// backward compatibility, this extra flag should be explicitly specified to make the
 /// calibration function use the thin prism model and return 12 coefficients. If the flag is not
 /// set, the function computes and returns only 5 distortion coefficients.
-/// *   @ref CALIB_FIX_S1_S2_S3_S4 The thin prism distortion coefficients are not changed during
-/// the optimization. If @ref CALIB_USE_INTRINSIC_GUESS is set, the coefficient from the
+/// *   `CALIB_FIX_S1_S2_S3_S4` The thin prism distortion coefficients are not changed during
+/// the optimization. If `CALIB_USE_INTRINSIC_GUESS` is set, the coefficient from the
 /// supplied distCoeffs matrix is used. Otherwise, it is set to 0.
-/// *   @ref CALIB_TILTED_MODEL Coefficients tauX and tauY are enabled. To provide the
+/// *   `CALIB_TILTED_MODEL` Coefficients tauX and tauY are enabled. To provide the
 /// backward compatibility, this extra flag should be explicitly specified to make the
 /// calibration function use the tilted sensor model and return 14 coefficients. If the flag is not
 /// set, the function computes and returns only 5 distortion coefficients.
-/// *   @ref CALIB_FIX_TAUX_TAUY The coefficients of the tilted sensor model are not changed during
-/// the optimization. If @ref CALIB_USE_INTRINSIC_GUESS is set, the coefficient from the
+/// *   `CALIB_FIX_TAUX_TAUY` The coefficients of the tilted sensor model are not changed during
+/// the optimization. If `CALIB_USE_INTRINSIC_GUESS` is set, the coefficient from the
 /// supplied distCoeffs matrix is used. Otherwise, it is set to 0.
 /// * criteria: Termination criteria for the iterative optimization algorithm.
 /// 
@@ -4863,10 +6733,10 @@ pub fn stereo_calibrate_extended(object_points: &dyn core::ToInputArray, image_p
 /// the two cameras. However, due to the high dimensionality of the parameter space and noise in the
 /// input data, the function can diverge from the correct solution. If the intrinsic parameters can be
 /// estimated with high accuracy for each of the cameras individually (for example, using
-/// #calibrateCamera ), you are recommended to do so and then pass @ref CALIB_FIX_INTRINSIC flag to the
+/// #calibrateCamera ), you are recommended to do so and then pass `CALIB_FIX_INTRINSIC` flag to the
 /// function along with the computed intrinsic parameters. Otherwise, if all the parameters are
 /// estimated at once, it makes sense to restrict some parameters, for example, pass
-///  @ref CALIB_SAME_FOCAL_LENGTH and @ref CALIB_ZERO_TANGENT_DIST flags, which is usually a
+///  `CALIB_SAME_FOCAL_LENGTH` and `CALIB_ZERO_TANGENT_DIST` flags, which is usually a
 /// reasonable assumption.
 /// 
 /// Similarly to #calibrateCamera, the function minimizes the total re-projection error for all the
@@ -4892,7 +6762,7 @@ pub fn stereo_calibrate(object_points: &dyn core::ToInputArray, image_points1: &
 	output_array_arg!(e);
 	output_array_arg!(f);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_stereoCalibrate_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_Size_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int_TermCriteria(object_points.as_raw__InputArray(), image_points1.as_raw__InputArray(), image_points2.as_raw__InputArray(), camera_matrix1.as_raw__InputOutputArray(), dist_coeffs1.as_raw__InputOutputArray(), camera_matrix2.as_raw__InputOutputArray(), dist_coeffs2.as_raw__InputOutputArray(), image_size.opencv_as_extern(), r.as_raw__OutputArray(), t.as_raw__OutputArray(), e.as_raw__OutputArray(), f.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_stereoCalibrate_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_Size_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int_TermCriteria(object_points.as_raw__InputArray(), image_points1.as_raw__InputArray(), image_points2.as_raw__InputArray(), camera_matrix1.as_raw__InputOutputArray(), dist_coeffs1.as_raw__InputOutputArray(), camera_matrix2.as_raw__InputOutputArray(), dist_coeffs2.as_raw__InputOutputArray(), image_size.opencv_as_extern(), r.as_raw__OutputArray(), t.as_raw__OutputArray(), e.as_raw__OutputArray(), f.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -4939,12 +6809,45 @@ pub fn stereo_rectify_uncalibrated(points1: &dyn core::ToInputArray, points2: &d
 	output_array_arg!(h1);
 	output_array_arg!(h2);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_stereoRectifyUncalibrated_const__InputArrayR_const__InputArrayR_const__InputArrayR_Size_const__OutputArrayR_const__OutputArrayR_double(points1.as_raw__InputArray(), points2.as_raw__InputArray(), f.as_raw__InputArray(), img_size.opencv_as_extern(), h1.as_raw__OutputArray(), h2.as_raw__OutputArray(), threshold, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_stereoRectifyUncalibrated_const__InputArrayR_const__InputArrayR_const__InputArrayR_Size_const__OutputArrayR_const__OutputArrayR_double(points1.as_raw__InputArray(), points2.as_raw__InputArray(), f.as_raw__InputArray(), img_size.opencv_as_extern(), h1.as_raw__OutputArray(), h2.as_raw__OutputArray(), threshold, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `stereo_rectify_uncalibrated()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct StereoRectifyUncalibratedBuilder<'a> {
+	points1: &'a dyn core::ToInputArray,
+	points2: &'a dyn core::ToInputArray,
+	f: &'a dyn core::ToInputArray,
+	img_size: core::Size,
+	h1: &'a mut dyn core::ToOutputArray,
+	h2: &'a mut dyn core::ToOutputArray,
+	threshold: f64,
+}
+
+impl<'a> StereoRectifyUncalibratedBuilder<'a> {
+	#[inline]
+	pub fn new(points1: &'a dyn core::ToInputArray, points2: &'a dyn core::ToInputArray, f: &'a dyn core::ToInputArray, img_size: core::Size, h1: &'a mut dyn core::ToOutputArray, h2: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { points1, points2, f, img_size, h1, h2, threshold: 5. }
+	}
+	
+	/// C++ default: `5`
+	#[inline]
+	pub fn with_threshold(mut self, threshold: f64) -> Self {
+		self.threshold = threshold;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<bool> {
+		stereo_rectify_uncalibrated(self.points1, self.points2, self.f, self.img_size, self.h1, self.h2, self.threshold)
+	}
+}
+
 /// Computes rectification transforms for each head of a calibrated stereo camera.
 /// 
 /// ## Parameters
@@ -4954,9 +6857,9 @@ pub fn stereo_rectify_uncalibrated(points1: &dyn core::ToInputArray, points2: &d
 /// * distCoeffs2: Second camera distortion parameters.
 /// * imageSize: Size of the image used for stereo calibration.
 /// * R: Rotation matrix from the coordinate system of the first camera to the second camera,
-/// see @ref stereoCalibrate.
+/// see [stereo_calibrate](crate::calib3d::stereo_calibrate).
 /// * T: Translation vector from the coordinate system of the first camera to the second camera,
-/// see @ref stereoCalibrate.
+/// see [stereo_calibrate](crate::calib3d::stereo_calibrate).
 /// * R1: Output 3x3 rectification transform (rotation matrix) for the first camera. This matrix
 /// brings points given in the unrectified first camera's coordinate system to points in the rectified
 /// first camera's coordinate system. In more technical terms, it performs a change of basis from the
@@ -4971,8 +6874,8 @@ pub fn stereo_rectify_uncalibrated(points1: &dyn core::ToInputArray, points2: &d
 /// * P2: Output 3x4 projection matrix in the new (rectified) coordinate systems for the second
 /// camera, i.e. it projects points given in the rectified first camera coordinate system into the
 /// rectified second camera's image.
-/// * Q: Output ![inline formula](https://latex.codecogs.com/png.latex?4%20%5Ctimes%204) disparity-to-depth mapping matrix (see @ref reprojectImageTo3D).
-/// * flags: Operation flags that may be zero or @ref CALIB_ZERO_DISPARITY . If the flag is set,
+/// * Q: Output ![inline formula](https://latex.codecogs.com/png.latex?4%20%5Ctimes%204) disparity-to-depth mapping matrix (see [reproject_image_to_3d](crate::calib3d::reproject_image_to_3d)).
+/// * flags: Operation flags that may be zero or `CALIB_ZERO_DISPARITY` . If the flag is set,
 /// the function makes the principal points of each camera have the same pixel coordinates in the
 /// rectified views. And if the flag is not set, the function may still shift the images in the
 /// horizontal or vertical direction (depending on the orientation of epipolar lines) to maximize the
@@ -5011,7 +6914,7 @@ pub fn stereo_rectify_uncalibrated(points1: &dyn core::ToInputArray, points2: &d
 ///    ![block formula](https://latex.codecogs.com/png.latex?%5Ctexttt%7BP2%7D%20%3D%20%5Cbegin%7Bbmatrix%7D%0A%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20f%20%26%200%20%26%20cx%5F2%20%26%20T%5Fx%2Af%20%5C%5C%0A%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%200%20%26%20f%20%26%20cy%20%26%200%20%5C%5C%0A%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%200%20%26%200%20%26%201%20%26%200%0A%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%5Cend%7Bbmatrix%7D%20%2C)
 /// 
 ///    where ![inline formula](https://latex.codecogs.com/png.latex?T%5Fx) is a horizontal shift between the cameras and ![inline formula](https://latex.codecogs.com/png.latex?cx%5F1%3Dcx%5F2) if
-///    @ref CALIB_ZERO_DISPARITY is set.
+///    `CALIB_ZERO_DISPARITY` is set.
 /// 
 /// *   **Vertical stereo**: the first and the second camera views are shifted relative to each other
 ///    mainly in the vertical direction (and probably a bit in the horizontal direction too). The epipolar
@@ -5022,7 +6925,7 @@ pub fn stereo_rectify_uncalibrated(points1: &dyn core::ToInputArray, points2: &d
 ///    ![block formula](https://latex.codecogs.com/png.latex?%5Ctexttt%7BP2%7D%20%3D%20%5Cbegin%7Bbmatrix%7D%0A%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20f%20%26%200%20%26%20cx%20%26%200%20%5C%5C%0A%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%200%20%26%20f%20%26%20cy%5F2%20%26%20T%5Fy%2Af%20%5C%5C%0A%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%200%20%26%200%20%26%201%20%26%200%0A%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%5Cend%7Bbmatrix%7D%2C)
 /// 
 ///    where ![inline formula](https://latex.codecogs.com/png.latex?T%5Fy) is a vertical shift between the cameras and ![inline formula](https://latex.codecogs.com/png.latex?cy%5F1%3Dcy%5F2) if
-///    @ref CALIB_ZERO_DISPARITY is set.
+///    `CALIB_ZERO_DISPARITY` is set.
 /// 
 /// As you can see, the first three columns of P1 and P2 will effectively be the new "rectified" camera
 /// matrices. The matrices, together with R1 and R2 , can then be passed to #initUndistortRectifyMap to
@@ -5055,12 +6958,62 @@ pub fn stereo_rectify(camera_matrix1: &dyn core::ToInputArray, dist_coeffs1: &dy
 	output_array_arg!(p2);
 	output_array_arg!(q);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_stereoRectify_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_Size_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int_double_Size_RectX_RectX(camera_matrix1.as_raw__InputArray(), dist_coeffs1.as_raw__InputArray(), camera_matrix2.as_raw__InputArray(), dist_coeffs2.as_raw__InputArray(), image_size.opencv_as_extern(), r.as_raw__InputArray(), t.as_raw__InputArray(), r1.as_raw__OutputArray(), r2.as_raw__OutputArray(), p1.as_raw__OutputArray(), p2.as_raw__OutputArray(), q.as_raw__OutputArray(), flags, alpha, new_image_size.opencv_as_extern(), valid_pix_roi1, valid_pix_roi2, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_stereoRectify_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_Size_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int_double_Size_RectX_RectX(camera_matrix1.as_raw__InputArray(), dist_coeffs1.as_raw__InputArray(), camera_matrix2.as_raw__InputArray(), dist_coeffs2.as_raw__InputArray(), image_size.opencv_as_extern(), r.as_raw__InputArray(), t.as_raw__InputArray(), r1.as_raw__OutputArray(), r2.as_raw__OutputArray(), p1.as_raw__OutputArray(), p2.as_raw__OutputArray(), q.as_raw__OutputArray(), flags, alpha, new_image_size.opencv_as_extern(), valid_pix_roi1, valid_pix_roi2, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `stereo_rectify()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct StereoRectifyBuilder<'a> {
+	camera_matrix1: &'a dyn core::ToInputArray,
+	dist_coeffs1: &'a dyn core::ToInputArray,
+	camera_matrix2: &'a dyn core::ToInputArray,
+	dist_coeffs2: &'a dyn core::ToInputArray,
+	image_size: core::Size,
+	r: &'a dyn core::ToInputArray,
+	t: &'a dyn core::ToInputArray,
+	r1: &'a mut dyn core::ToOutputArray,
+	r2: &'a mut dyn core::ToOutputArray,
+	p1: &'a mut dyn core::ToOutputArray,
+	p2: &'a mut dyn core::ToOutputArray,
+	q: &'a mut dyn core::ToOutputArray,
+	flags: i32,
+	alpha: f64,
+	new_image_size: core::Size,
+	valid_pix_roi1: &'a mut core::Rect,
+	valid_pix_roi2: &'a mut core::Rect,
+}
+
+impl<'a> StereoRectifyBuilder<'a> {
+	#[inline]
+	pub fn new(camera_matrix1: &'a dyn core::ToInputArray, dist_coeffs1: &'a dyn core::ToInputArray, camera_matrix2: &'a dyn core::ToInputArray, dist_coeffs2: &'a dyn core::ToInputArray, image_size: core::Size, r: &'a dyn core::ToInputArray, t: &'a dyn core::ToInputArray, r1: &'a mut dyn core::ToOutputArray, r2: &'a mut dyn core::ToOutputArray, p1: &'a mut dyn core::ToOutputArray, p2: &'a mut dyn core::ToOutputArray, q: &'a mut dyn core::ToOutputArray, flags: i32, valid_pix_roi1: &'a mut core::Rect, valid_pix_roi2: &'a mut core::Rect) -> Self {
+		Self { camera_matrix1, dist_coeffs1, camera_matrix2, dist_coeffs2, image_size, r, t, r1, r2, p1, p2, q, flags, alpha: -1., new_image_size: core::Size::default(), valid_pix_roi1, valid_pix_roi2 }
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_alpha(mut self, alpha: f64) -> Self {
+		self.alpha = alpha;
+		self
+	}
+	
+	/// C++ default: `Size()`
+	#[inline]
+	pub fn with_new_image_size(mut self, new_image_size: core::Size) -> Self {
+		self.new_image_size = new_image_size;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		stereo_rectify(self.camera_matrix1, self.dist_coeffs1, self.camera_matrix2, self.dist_coeffs2, self.image_size, self.r, self.t, self.r1, self.r2, self.p1, self.p2, self.q, self.flags, self.alpha, self.new_image_size, self.valid_pix_roi1, self.valid_pix_roi2)
+	}
+}
+
 /// This function reconstructs 3-dimensional points (in homogeneous coordinates) by using
 /// their observations with a stereo camera.
 /// 
@@ -5082,10 +7035,10 @@ pub fn stereo_rectify(camera_matrix1: &dyn core::ToInputArray, dist_coeffs1: &dy
 /// 
 /// 
 /// Note:
-///    If the projection matrices from @ref stereoRectify are used, then the returned points are
+///    If the projection matrices from [stereo_rectify](crate::calib3d::stereo_rectify) are used, then the returned points are
 ///    represented in the first camera's rectified coordinate system.
 /// ## See also
-/// reprojectImageTo3D
+/// [reproject_image_to_3d](crate::calib3d::reproject_image_to_3d)
 #[inline]
 pub fn triangulate_points(proj_matr1: &dyn core::ToInputArray, proj_matr2: &dyn core::ToInputArray, proj_points1: &dyn core::ToInputArray, proj_points2: &dyn core::ToInputArray, points4_d: &mut dyn core::ToOutputArray) -> Result<()> {
 	input_array_arg!(proj_matr1);
@@ -5094,7 +7047,7 @@ pub fn triangulate_points(proj_matr1: &dyn core::ToInputArray, proj_matr2: &dyn
 	input_array_arg!(proj_points2);
 	output_array_arg!(points4_d);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_triangulatePoints_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR(proj_matr1.as_raw__InputArray(), proj_matr2.as_raw__InputArray(), proj_points1.as_raw__InputArray(), proj_points2.as_raw__InputArray(), points4_d.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_triangulatePoints_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR(proj_matr1.as_raw__InputArray(), proj_matr2.as_raw__InputArray(), proj_points1.as_raw__InputArray(), proj_points2.as_raw__InputArray(), points4_d.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -5141,12 +7094,55 @@ pub fn undistort_points(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutp
 	input_array_arg!(r);
 	input_array_arg!(p);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_undistortPoints_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), r.as_raw__InputArray(), p.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_undistortPoints_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), r.as_raw__InputArray(), p.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `undistort_points()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct UndistortPointsBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	camera_matrix: &'a dyn core::ToInputArray,
+	dist_coeffs: &'a dyn core::ToInputArray,
+	r: Option<&'a dyn core::ToInputArray>,
+	p: Option<&'a dyn core::ToInputArray>,
+}
+
+impl<'a> UndistortPointsBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, camera_matrix: &'a dyn core::ToInputArray, dist_coeffs: &'a dyn core::ToInputArray) -> Self {
+		Self { src, dst, camera_matrix, dist_coeffs, r: None, p: None }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_r(mut self, r: &'a dyn core::ToInputArray) -> Self {
+		self.r = Some(r);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_p(mut self, p: &'a dyn core::ToInputArray) -> Self {
+		self.p = Some(p);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let r_no_array = core::no_array();
+		let r: &dyn core::ToInputArray = match self.r { Some(r) => r, None => &r_no_array };
+		let p_no_array = core::no_array();
+		let p: &dyn core::ToInputArray = match self.p { Some(p) => p, None => &p_no_array };
+		undistort_points(self.src, self.dst, self.camera_matrix, self.dist_coeffs, r, p)
+	}
+}
+
 /// Computes the ideal point coordinates from the observed point coordinates.
 /// 
 /// The function is similar to #undistort and #initUndistortRectifyMap but it operates on a
@@ -5189,7 +7185,7 @@ pub fn undistort_points_iter(src: &dyn core::ToInputArray, dst: &mut dyn core::T
 	input_array_arg!(r);
 	input_array_arg!(p);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_undistortPoints_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_TermCriteria(src.as_raw__InputArray(), dst.as_raw__OutputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), r.as_raw__InputArray(), p.as_raw__InputArray(), criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_undistortPoints_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_TermCriteria(src.as_raw__InputArray(), dst.as_raw__OutputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), r.as_raw__InputArray(), p.as_raw__InputArray(), criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -5234,7 +7230,7 @@ pub fn undistort(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 	input_array_arg!(dist_coeffs);
 	input_array_arg!(new_camera_matrix);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_undistort_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), new_camera_matrix.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_undistort_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), new_camera_matrix.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -5249,7 +7245,7 @@ pub fn validate_disparity(disparity: &mut dyn core::ToInputOutputArray, cost: &d
 	input_output_array_arg!(disparity);
 	input_array_arg!(cost);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_validateDisparity_const__InputOutputArrayR_const__InputArrayR_int_int_int(disparity.as_raw__InputOutputArray(), cost.as_raw__InputArray(), min_disparity, number_of_disparities, disp12_max_disp, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_validateDisparity_const__InputOutputArrayR_const__InputArrayR_int_int_int(disparity.as_raw__InputOutputArray(), cost.as_raw__InputArray(), min_disparity, number_of_disparities, disp12_max_disp, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -5284,7 +7280,7 @@ impl CirclesGridFinderParameters {
 	#[inline]
 	pub fn default() -> Result<crate::calib3d::CirclesGridFinderParameters> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CirclesGridFinderParameters_CirclesGridFinderParameters(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CirclesGridFinderParameters_CirclesGridFinderParameters(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5313,9 +7309,10 @@ pub trait LMSolverConst: core::AlgorithmTraitConst {
 	/// computed by the callback.
 	#[inline]
 	fn run(&self, param: &mut dyn core::ToInputOutputArray) -> Result<i32> {
+		crate::core::validity::check("LMSolver", self.as_raw_LMSolver())?;
 		input_output_array_arg!(param);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_LMSolver_run_const_const__InputOutputArrayR(self.as_raw_LMSolver(), param.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_LMSolver_run_const_const__InputOutputArrayR(self.as_raw_LMSolver(), param.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5324,8 +7321,9 @@ pub trait LMSolverConst: core::AlgorithmTraitConst {
 	/// Retrieves the current maximum number of iterations
 	#[inline]
 	fn get_max_iters(&self) -> Result<i32> {
+		crate::core::validity::check("LMSolver", self.as_raw_LMSolver())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_LMSolver_getMaxIters_const(self.as_raw_LMSolver(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_LMSolver_getMaxIters_const(self.as_raw_LMSolver(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5341,8 +7339,9 @@ pub trait LMSolver: core::AlgorithmTrait + crate::calib3d::LMSolverConst {
 	/// * maxIters: the number of iterations
 	#[inline]
 	fn set_max_iters(&mut self, max_iters: i32) -> Result<()> {
+		crate::core::validity::check("LMSolver", self.as_raw_mut_LMSolver())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_LMSolver_setMaxIters_int(self.as_raw_mut_LMSolver(), max_iters, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_LMSolver_setMaxIters_int(self.as_raw_mut_LMSolver(), max_iters, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5360,7 +7359,7 @@ impl dyn LMSolver + '_ {
 	#[inline]
 	pub fn create(cb: &core::Ptr<dyn crate::calib3d::LMSolver_Callback>, max_iters: i32) -> Result<core::Ptr<dyn crate::calib3d::LMSolver>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_LMSolver_create_const_Ptr_Callback_R_int(cb.as_raw_PtrOfLMSolver_Callback(), max_iters, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_LMSolver_create_const_Ptr_Callback_R_int(cb.as_raw_PtrOfLMSolver_Callback(), max_iters, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::calib3d::LMSolver>::opencv_from_extern(ret) };
@@ -5370,7 +7369,7 @@ impl dyn LMSolver + '_ {
 	#[inline]
 	pub fn create_ext(cb: &core::Ptr<dyn crate::calib3d::LMSolver_Callback>, max_iters: i32, eps: f64) -> Result<core::Ptr<dyn crate::calib3d::LMSolver>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_LMSolver_create_const_Ptr_Callback_R_int_double(cb.as_raw_PtrOfLMSolver_Callback(), max_iters, eps, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_LMSolver_create_const_Ptr_Callback_R_int_double(cb.as_raw_PtrOfLMSolver_Callback(), max_iters, eps, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::calib3d::LMSolver>::opencv_from_extern(ret) };
@@ -5394,11 +7393,12 @@ pub trait LMSolver_CallbackConst {
 	/// (unless it's noArray()).
 	#[inline]
 	fn compute(&self, param: &dyn core::ToInputArray, err: &mut dyn core::ToOutputArray, j: &mut dyn core::ToOutputArray) -> Result<bool> {
+		crate::core::validity::check("LMSolver_Callback", self.as_raw_LMSolver_Callback())?;
 		input_array_arg!(param);
 		output_array_arg!(err);
 		output_array_arg!(j);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_LMSolver_Callback_compute_const_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_LMSolver_Callback(), param.as_raw__InputArray(), err.as_raw__OutputArray(), j.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_LMSolver_Callback_compute_const_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_LMSolver_Callback(), param.as_raw__InputArray(), err.as_raw__OutputArray(), j.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5418,8 +7418,9 @@ pub trait StereoBMConst: crate::calib3d::StereoMatcherConst {
 
 	#[inline]
 	fn get_pre_filter_type(&self) -> Result<i32> {
+		crate::core::validity::check("StereoBM", self.as_raw_StereoBM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoBM_getPreFilterType_const(self.as_raw_StereoBM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoBM_getPreFilterType_const(self.as_raw_StereoBM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5427,8 +7428,9 @@ pub trait StereoBMConst: crate::calib3d::StereoMatcherConst {
 	
 	#[inline]
 	fn get_pre_filter_size(&self) -> Result<i32> {
+		crate::core::validity::check("StereoBM", self.as_raw_StereoBM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoBM_getPreFilterSize_const(self.as_raw_StereoBM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoBM_getPreFilterSize_const(self.as_raw_StereoBM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5436,8 +7438,9 @@ pub trait StereoBMConst: crate::calib3d::StereoMatcherConst {
 	
 	#[inline]
 	fn get_pre_filter_cap(&self) -> Result<i32> {
+		crate::core::validity::check("StereoBM", self.as_raw_StereoBM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoBM_getPreFilterCap_const(self.as_raw_StereoBM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoBM_getPreFilterCap_const(self.as_raw_StereoBM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5445,8 +7448,9 @@ pub trait StereoBMConst: crate::calib3d::StereoMatcherConst {
 	
 	#[inline]
 	fn get_texture_threshold(&self) -> Result<i32> {
+		crate::core::validity::check("StereoBM", self.as_raw_StereoBM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoBM_getTextureThreshold_const(self.as_raw_StereoBM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoBM_getTextureThreshold_const(self.as_raw_StereoBM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5454,8 +7458,9 @@ pub trait StereoBMConst: crate::calib3d::StereoMatcherConst {
 	
 	#[inline]
 	fn get_uniqueness_ratio(&self) -> Result<i32> {
+		crate::core::validity::check("StereoBM", self.as_raw_StereoBM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoBM_getUniquenessRatio_const(self.as_raw_StereoBM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoBM_getUniquenessRatio_const(self.as_raw_StereoBM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5463,8 +7468,9 @@ pub trait StereoBMConst: crate::calib3d::StereoMatcherConst {
 	
 	#[inline]
 	fn get_smaller_block_size(&self) -> Result<i32> {
+		crate::core::validity::check("StereoBM", self.as_raw_StereoBM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoBM_getSmallerBlockSize_const(self.as_raw_StereoBM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoBM_getSmallerBlockSize_const(self.as_raw_StereoBM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5472,8 +7478,9 @@ pub trait StereoBMConst: crate::calib3d::StereoMatcherConst {
 	
 	#[inline]
 	fn get_roi1(&self) -> Result<core::Rect> {
+		crate::core::validity::check("StereoBM", self.as_raw_StereoBM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoBM_getROI1_const(self.as_raw_StereoBM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoBM_getROI1_const(self.as_raw_StereoBM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5481,8 +7488,9 @@ pub trait StereoBMConst: crate::calib3d::StereoMatcherConst {
 	
 	#[inline]
 	fn get_roi2(&self) -> Result<core::Rect> {
+		crate::core::validity::check("StereoBM", self.as_raw_StereoBM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoBM_getROI2_const(self.as_raw_StereoBM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoBM_getROI2_const(self.as_raw_StereoBM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5495,8 +7503,9 @@ pub trait StereoBM: crate::calib3d::StereoBMConst + crate::calib3d::StereoMatche
 
 	#[inline]
 	fn set_pre_filter_type(&mut self, pre_filter_type: i32) -> Result<()> {
+		crate::core::validity::check("StereoBM", self.as_raw_mut_StereoBM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoBM_setPreFilterType_int(self.as_raw_mut_StereoBM(), pre_filter_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoBM_setPreFilterType_int(self.as_raw_mut_StereoBM(), pre_filter_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5504,8 +7513,9 @@ pub trait StereoBM: crate::calib3d::StereoBMConst + crate::calib3d::StereoMatche
 	
 	#[inline]
 	fn set_pre_filter_size(&mut self, pre_filter_size: i32) -> Result<()> {
+		crate::core::validity::check("StereoBM", self.as_raw_mut_StereoBM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoBM_setPreFilterSize_int(self.as_raw_mut_StereoBM(), pre_filter_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoBM_setPreFilterSize_int(self.as_raw_mut_StereoBM(), pre_filter_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5513,8 +7523,9 @@ pub trait StereoBM: crate::calib3d::StereoBMConst + crate::calib3d::StereoMatche
 	
 	#[inline]
 	fn set_pre_filter_cap(&mut self, pre_filter_cap: i32) -> Result<()> {
+		crate::core::validity::check("StereoBM", self.as_raw_mut_StereoBM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoBM_setPreFilterCap_int(self.as_raw_mut_StereoBM(), pre_filter_cap, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoBM_setPreFilterCap_int(self.as_raw_mut_StereoBM(), pre_filter_cap, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5522,8 +7533,9 @@ pub trait StereoBM: crate::calib3d::StereoBMConst + crate::calib3d::StereoMatche
 	
 	#[inline]
 	fn set_texture_threshold(&mut self, texture_threshold: i32) -> Result<()> {
+		crate::core::validity::check("StereoBM", self.as_raw_mut_StereoBM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoBM_setTextureThreshold_int(self.as_raw_mut_StereoBM(), texture_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoBM_setTextureThreshold_int(self.as_raw_mut_StereoBM(), texture_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5531,8 +7543,9 @@ pub trait StereoBM: crate::calib3d::StereoBMConst + crate::calib3d::StereoMatche
 	
 	#[inline]
 	fn set_uniqueness_ratio(&mut self, uniqueness_ratio: i32) -> Result<()> {
+		crate::core::validity::check("StereoBM", self.as_raw_mut_StereoBM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoBM_setUniquenessRatio_int(self.as_raw_mut_StereoBM(), uniqueness_ratio, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoBM_setUniquenessRatio_int(self.as_raw_mut_StereoBM(), uniqueness_ratio, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5540,8 +7553,9 @@ pub trait StereoBM: crate::calib3d::StereoBMConst + crate::calib3d::StereoMatche
 	
 	#[inline]
 	fn set_smaller_block_size(&mut self, block_size: i32) -> Result<()> {
+		crate::core::validity::check("StereoBM", self.as_raw_mut_StereoBM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoBM_setSmallerBlockSize_int(self.as_raw_mut_StereoBM(), block_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoBM_setSmallerBlockSize_int(self.as_raw_mut_StereoBM(), block_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5549,8 +7563,9 @@ pub trait StereoBM: crate::calib3d::StereoBMConst + crate::calib3d::StereoMatche
 	
 	#[inline]
 	fn set_roi1(&mut self, roi1: core::Rect) -> Result<()> {
+		crate::core::validity::check("StereoBM", self.as_raw_mut_StereoBM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoBM_setROI1_Rect(self.as_raw_mut_StereoBM(), roi1.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoBM_setROI1_Rect(self.as_raw_mut_StereoBM(), roi1.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5558,8 +7573,9 @@ pub trait StereoBM: crate::calib3d::StereoBMConst + crate::calib3d::StereoMatche
 	
 	#[inline]
 	fn set_roi2(&mut self, roi2: core::Rect) -> Result<()> {
+		crate::core::validity::check("StereoBM", self.as_raw_mut_StereoBM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoBM_setROI2_Rect(self.as_raw_mut_StereoBM(), roi2.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoBM_setROI2_Rect(self.as_raw_mut_StereoBM(), roi2.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5588,7 +7604,7 @@ impl dyn StereoBM + '_ {
 	#[inline]
 	pub fn create(num_disparities: i32, block_size: i32) -> Result<core::Ptr<dyn crate::calib3d::StereoBM>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoBM_create_int_int(num_disparities, block_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoBM_create_int_int(num_disparities, block_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::calib3d::StereoBM>::opencv_from_extern(ret) };
@@ -5602,8 +7618,9 @@ pub trait StereoMatcherConst: core::AlgorithmTraitConst {
 
 	#[inline]
 	fn get_min_disparity(&self) -> Result<i32> {
+		crate::core::validity::check("StereoMatcher", self.as_raw_StereoMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoMatcher_getMinDisparity_const(self.as_raw_StereoMatcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoMatcher_getMinDisparity_const(self.as_raw_StereoMatcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5611,8 +7628,9 @@ pub trait StereoMatcherConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_num_disparities(&self) -> Result<i32> {
+		crate::core::validity::check("StereoMatcher", self.as_raw_StereoMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoMatcher_getNumDisparities_const(self.as_raw_StereoMatcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoMatcher_getNumDisparities_const(self.as_raw_StereoMatcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5620,8 +7638,9 @@ pub trait StereoMatcherConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_block_size(&self) -> Result<i32> {
+		crate::core::validity::check("StereoMatcher", self.as_raw_StereoMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoMatcher_getBlockSize_const(self.as_raw_StereoMatcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoMatcher_getBlockSize_const(self.as_raw_StereoMatcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5629,8 +7648,9 @@ pub trait StereoMatcherConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_speckle_window_size(&self) -> Result<i32> {
+		crate::core::validity::check("StereoMatcher", self.as_raw_StereoMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoMatcher_getSpeckleWindowSize_const(self.as_raw_StereoMatcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoMatcher_getSpeckleWindowSize_const(self.as_raw_StereoMatcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5638,8 +7658,9 @@ pub trait StereoMatcherConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_speckle_range(&self) -> Result<i32> {
+		crate::core::validity::check("StereoMatcher", self.as_raw_StereoMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoMatcher_getSpeckleRange_const(self.as_raw_StereoMatcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoMatcher_getSpeckleRange_const(self.as_raw_StereoMatcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5647,8 +7668,9 @@ pub trait StereoMatcherConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_disp12_max_diff(&self) -> Result<i32> {
+		crate::core::validity::check("StereoMatcher", self.as_raw_StereoMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoMatcher_getDisp12MaxDiff_const(self.as_raw_StereoMatcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoMatcher_getDisp12MaxDiff_const(self.as_raw_StereoMatcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5669,11 +7691,12 @@ pub trait StereoMatcher: core::AlgorithmTrait + crate::calib3d::StereoMatcherCon
 	/// has 4 fractional bits), whereas other algorithms output 32-bit floating-point disparity map.
 	#[inline]
 	fn compute(&mut self, left: &dyn core::ToInputArray, right: &dyn core::ToInputArray, disparity: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("StereoMatcher", self.as_raw_mut_StereoMatcher())?;
 		input_array_arg!(left);
 		input_array_arg!(right);
 		output_array_arg!(disparity);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoMatcher_compute_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_StereoMatcher(), left.as_raw__InputArray(), right.as_raw__InputArray(), disparity.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoMatcher_compute_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_StereoMatcher(), left.as_raw__InputArray(), right.as_raw__InputArray(), disparity.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5681,8 +7704,9 @@ pub trait StereoMatcher: core::AlgorithmTrait + crate::calib3d::StereoMatcherCon
 	
 	#[inline]
 	fn set_min_disparity(&mut self, min_disparity: i32) -> Result<()> {
+		crate::core::validity::check("StereoMatcher", self.as_raw_mut_StereoMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoMatcher_setMinDisparity_int(self.as_raw_mut_StereoMatcher(), min_disparity, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoMatcher_setMinDisparity_int(self.as_raw_mut_StereoMatcher(), min_disparity, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5690,8 +7714,9 @@ pub trait StereoMatcher: core::AlgorithmTrait + crate::calib3d::StereoMatcherCon
 	
 	#[inline]
 	fn set_num_disparities(&mut self, num_disparities: i32) -> Result<()> {
+		crate::core::validity::check("StereoMatcher", self.as_raw_mut_StereoMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoMatcher_setNumDisparities_int(self.as_raw_mut_StereoMatcher(), num_disparities, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoMatcher_setNumDisparities_int(self.as_raw_mut_StereoMatcher(), num_disparities, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5699,8 +7724,9 @@ pub trait StereoMatcher: core::AlgorithmTrait + crate::calib3d::StereoMatcherCon
 	
 	#[inline]
 	fn set_block_size(&mut self, block_size: i32) -> Result<()> {
+		crate::core::validity::check("StereoMatcher", self.as_raw_mut_StereoMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoMatcher_setBlockSize_int(self.as_raw_mut_StereoMatcher(), block_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoMatcher_setBlockSize_int(self.as_raw_mut_StereoMatcher(), block_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5708,8 +7734,9 @@ pub trait StereoMatcher: core::AlgorithmTrait + crate::calib3d::StereoMatcherCon
 	
 	#[inline]
 	fn set_speckle_window_size(&mut self, speckle_window_size: i32) -> Result<()> {
+		crate::core::validity::check("StereoMatcher", self.as_raw_mut_StereoMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoMatcher_setSpeckleWindowSize_int(self.as_raw_mut_StereoMatcher(), speckle_window_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoMatcher_setSpeckleWindowSize_int(self.as_raw_mut_StereoMatcher(), speckle_window_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5717,8 +7744,9 @@ pub trait StereoMatcher: core::AlgorithmTrait + crate::calib3d::StereoMatcherCon
 	
 	#[inline]
 	fn set_speckle_range(&mut self, speckle_range: i32) -> Result<()> {
+		crate::core::validity::check("StereoMatcher", self.as_raw_mut_StereoMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoMatcher_setSpeckleRange_int(self.as_raw_mut_StereoMatcher(), speckle_range, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoMatcher_setSpeckleRange_int(self.as_raw_mut_StereoMatcher(), speckle_range, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5726,8 +7754,9 @@ pub trait StereoMatcher: core::AlgorithmTrait + crate::calib3d::StereoMatcherCon
 	
 	#[inline]
 	fn set_disp12_max_diff(&mut self, disp12_max_diff: i32) -> Result<()> {
+		crate::core::validity::check("StereoMatcher", self.as_raw_mut_StereoMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoMatcher_setDisp12MaxDiff_int(self.as_raw_mut_StereoMatcher(), disp12_max_diff, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoMatcher_setDisp12MaxDiff_int(self.as_raw_mut_StereoMatcher(), disp12_max_diff, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5758,8 +7787,9 @@ pub trait StereoSGBMConst: crate::calib3d::StereoMatcherConst {
 
 	#[inline]
 	fn get_pre_filter_cap(&self) -> Result<i32> {
+		crate::core::validity::check("StereoSGBM", self.as_raw_StereoSGBM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoSGBM_getPreFilterCap_const(self.as_raw_StereoSGBM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoSGBM_getPreFilterCap_const(self.as_raw_StereoSGBM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5767,8 +7797,9 @@ pub trait StereoSGBMConst: crate::calib3d::StereoMatcherConst {
 	
 	#[inline]
 	fn get_uniqueness_ratio(&self) -> Result<i32> {
+		crate::core::validity::check("StereoSGBM", self.as_raw_StereoSGBM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoSGBM_getUniquenessRatio_const(self.as_raw_StereoSGBM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoSGBM_getUniquenessRatio_const(self.as_raw_StereoSGBM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5776,8 +7807,9 @@ pub trait StereoSGBMConst: crate::calib3d::StereoMatcherConst {
 	
 	#[inline]
 	fn get_p1(&self) -> Result<i32> {
+		crate::core::validity::check("StereoSGBM", self.as_raw_StereoSGBM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoSGBM_getP1_const(self.as_raw_StereoSGBM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoSGBM_getP1_const(self.as_raw_StereoSGBM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5785,8 +7817,9 @@ pub trait StereoSGBMConst: crate::calib3d::StereoMatcherConst {
 	
 	#[inline]
 	fn get_p2(&self) -> Result<i32> {
+		crate::core::validity::check("StereoSGBM", self.as_raw_StereoSGBM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoSGBM_getP2_const(self.as_raw_StereoSGBM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoSGBM_getP2_const(self.as_raw_StereoSGBM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5794,8 +7827,9 @@ pub trait StereoSGBMConst: crate::calib3d::StereoMatcherConst {
 	
 	#[inline]
 	fn get_mode(&self) -> Result<i32> {
+		crate::core::validity::check("StereoSGBM", self.as_raw_StereoSGBM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoSGBM_getMode_const(self.as_raw_StereoSGBM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoSGBM_getMode_const(self.as_raw_StereoSGBM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5808,8 +7842,9 @@ pub trait StereoSGBM: crate::calib3d::StereoMatcher + crate::calib3d::StereoSGBM
 
 	#[inline]
 	fn set_pre_filter_cap(&mut self, pre_filter_cap: i32) -> Result<()> {
+		crate::core::validity::check("StereoSGBM", self.as_raw_mut_StereoSGBM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoSGBM_setPreFilterCap_int(self.as_raw_mut_StereoSGBM(), pre_filter_cap, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoSGBM_setPreFilterCap_int(self.as_raw_mut_StereoSGBM(), pre_filter_cap, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5817,8 +7852,9 @@ pub trait StereoSGBM: crate::calib3d::StereoMatcher + crate::calib3d::StereoSGBM
 	
 	#[inline]
 	fn set_uniqueness_ratio(&mut self, uniqueness_ratio: i32) -> Result<()> {
+		crate::core::validity::check("StereoSGBM", self.as_raw_mut_StereoSGBM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoSGBM_setUniquenessRatio_int(self.as_raw_mut_StereoSGBM(), uniqueness_ratio, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoSGBM_setUniquenessRatio_int(self.as_raw_mut_StereoSGBM(), uniqueness_ratio, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5826,8 +7862,9 @@ pub trait StereoSGBM: crate::calib3d::StereoMatcher + crate::calib3d::StereoSGBM
 	
 	#[inline]
 	fn set_p1(&mut self, p1: i32) -> Result<()> {
+		crate::core::validity::check("StereoSGBM", self.as_raw_mut_StereoSGBM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoSGBM_setP1_int(self.as_raw_mut_StereoSGBM(), p1, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoSGBM_setP1_int(self.as_raw_mut_StereoSGBM(), p1, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5835,8 +7872,9 @@ pub trait StereoSGBM: crate::calib3d::StereoMatcher + crate::calib3d::StereoSGBM
 	
 	#[inline]
 	fn set_p2(&mut self, p2: i32) -> Result<()> {
+		crate::core::validity::check("StereoSGBM", self.as_raw_mut_StereoSGBM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoSGBM_setP2_int(self.as_raw_mut_StereoSGBM(), p2, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoSGBM_setP2_int(self.as_raw_mut_StereoSGBM(), p2, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5844,8 +7882,9 @@ pub trait StereoSGBM: crate::calib3d::StereoMatcher + crate::calib3d::StereoSGBM
 	
 	#[inline]
 	fn set_mode(&mut self, mode: i32) -> Result<()> {
+		crate::core::validity::check("StereoSGBM", self.as_raw_mut_StereoSGBM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoSGBM_setMode_int(self.as_raw_mut_StereoSGBM(), mode, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoSGBM_setMode_int(self.as_raw_mut_StereoSGBM(), mode, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5873,7 +7912,7 @@ impl dyn StereoSGBM + '_ {
 	/// * disp12MaxDiff: Maximum allowed difference (in integer pixel units) in the left-right
 	/// disparity check. Set it to a non-positive value to disable the check.
 	/// * preFilterCap: Truncation value for the prefiltered image pixels. The algorithm first
-	/// computes x-derivative at each pixel and clips its value by [-preFilterCap, preFilterCap] interval.
+	/// computes x-derivative at each pixel and clips its value by \[-preFilterCap, preFilterCap\] interval.
 	/// The result values are passed to the Birchfield-Tomasi pixel cost function.
 	/// * uniquenessRatio: Margin in percentage by which the best (minimum) computed cost function
 	/// value should "win" the second best value to consider the found match correct. Normally, a value
@@ -5907,7 +7946,7 @@ impl dyn StereoSGBM + '_ {
 	#[inline]
 	pub fn create(min_disparity: i32, num_disparities: i32, block_size: i32, p1: i32, p2: i32, disp12_max_diff: i32, pre_filter_cap: i32, uniqueness_ratio: i32, speckle_window_size: i32, speckle_range: i32, mode: i32) -> Result<core::Ptr<dyn crate::calib3d::StereoSGBM>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereoSGBM_create_int_int_int_int_int_int_int_int_int_int_int(min_disparity, num_disparities, block_size, p1, p2, disp12_max_diff, pre_filter_cap, uniqueness_ratio, speckle_window_size, speckle_range, mode, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereoSGBM_create_int_int_int_int_int_int_int_int_int_int_int(min_disparity, num_disparities, block_size, p1, p2, disp12_max_diff, pre_filter_cap, uniqueness_ratio, speckle_window_size, speckle_range, mode, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::calib3d::StereoSGBM>::opencv_from_extern(ret) };
@@ -5915,6 +7954,106 @@ impl dyn StereoSGBM + '_ {
 	}
 	
 }
+
+/// Builder for `<dyn StereoSGBM>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct StereoSGBM_CreateBuilder {
+	min_disparity: i32,
+	num_disparities: i32,
+	block_size: i32,
+	p1: i32,
+	p2: i32,
+	disp12_max_diff: i32,
+	pre_filter_cap: i32,
+	uniqueness_ratio: i32,
+	speckle_window_size: i32,
+	speckle_range: i32,
+	mode: i32,
+}
+
+impl StereoSGBM_CreateBuilder {
+	#[inline]
+	pub fn new(mode: i32) -> Self {
+		Self { min_disparity: 0, num_disparities: 16, block_size: 3, p1: 0, p2: 0, disp12_max_diff: 0, pre_filter_cap: 0, uniqueness_ratio: 0, speckle_window_size: 0, speckle_range: 0, mode }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_min_disparity(mut self, min_disparity: i32) -> Self {
+		self.min_disparity = min_disparity;
+		self
+	}
+	
+	/// C++ default: `16`
+	#[inline]
+	pub fn with_num_disparities(mut self, num_disparities: i32) -> Self {
+		self.num_disparities = num_disparities;
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_block_size(mut self, block_size: i32) -> Self {
+		self.block_size = block_size;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_p1(mut self, p1: i32) -> Self {
+		self.p1 = p1;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_p2(mut self, p2: i32) -> Self {
+		self.p2 = p2;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_disp12_max_diff(mut self, disp12_max_diff: i32) -> Self {
+		self.disp12_max_diff = disp12_max_diff;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_pre_filter_cap(mut self, pre_filter_cap: i32) -> Self {
+		self.pre_filter_cap = pre_filter_cap;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_uniqueness_ratio(mut self, uniqueness_ratio: i32) -> Self {
+		self.uniqueness_ratio = uniqueness_ratio;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_speckle_window_size(mut self, speckle_window_size: i32) -> Self {
+		self.speckle_window_size = speckle_window_size;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_speckle_range(mut self, speckle_range: i32) -> Self {
+		self.speckle_range = speckle_range;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::calib3d::StereoSGBM>> {
+		<dyn StereoSGBM>::create(self.min_disparity, self.num_disparities, self.block_size, self.p1, self.p2, self.disp12_max_diff, self.pre_filter_cap, self.uniqueness_ratio, self.speckle_window_size, self.speckle_range, self.mode)
+	}
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct UsacParams {
@@ -5937,10 +8076,11 @@ impl UsacParams {
 	#[inline]
 	pub fn default() -> Result<crate::calib3d::UsacParams> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_UsacParams_UsacParams(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_UsacParams_UsacParams(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 }
+pub use crate::manual::calib3d::*;
@@ -63,12 +63,51 @@ pub fn calibrate(object_points: &dyn core::ToInputArray, image_points: &dyn core
 	output_array_arg!(tvecs);
 	output_array_arg!(idx);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_omnidir_calibrate_const__InputArrayR_const__InputArrayR_Size_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_int_TermCriteria_const__OutputArrayR(object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), size.opencv_as_extern(), k.as_raw__InputOutputArray(), xi.as_raw__InputOutputArray(), d.as_raw__InputOutputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), idx.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_omnidir_calibrate_const__InputArrayR_const__InputArrayR_Size_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_int_TermCriteria_const__OutputArrayR(object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), size.opencv_as_extern(), k.as_raw__InputOutputArray(), xi.as_raw__InputOutputArray(), d.as_raw__InputOutputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), idx.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `calibrate()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CalibrateBuilder<'a> {
+	object_points: &'a dyn core::ToInputArray,
+	image_points: &'a dyn core::ToInputArray,
+	size: core::Size,
+	k: &'a mut dyn core::ToInputOutputArray,
+	xi: &'a mut dyn core::ToInputOutputArray,
+	d: &'a mut dyn core::ToInputOutputArray,
+	rvecs: &'a mut dyn core::ToOutputArray,
+	tvecs: &'a mut dyn core::ToOutputArray,
+	flags: i32,
+	criteria: core::TermCriteria,
+	idx: Option<&'a mut dyn core::ToOutputArray>,
+}
+
+impl<'a> CalibrateBuilder<'a> {
+	#[inline]
+	pub fn new(object_points: &'a dyn core::ToInputArray, image_points: &'a dyn core::ToInputArray, size: core::Size, k: &'a mut dyn core::ToInputOutputArray, xi: &'a mut dyn core::ToInputOutputArray, d: &'a mut dyn core::ToInputOutputArray, rvecs: &'a mut dyn core::ToOutputArray, tvecs: &'a mut dyn core::ToOutputArray, flags: i32, criteria: core::TermCriteria) -> Self {
+		Self { object_points, image_points, size, k, xi, d, rvecs, tvecs, flags, criteria, idx: None }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_idx(mut self, idx: &'a mut dyn core::ToOutputArray) -> Self {
+		self.idx = Some(idx);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<f64> {
+		let mut idx_no_array = core::no_array();
+		let idx: &mut dyn core::ToOutputArray = match self.idx { Some(idx) => idx, None => &mut idx_no_array };
+		calibrate(self.object_points, self.image_points, self.size, self.k, self.xi, self.d, self.rvecs, self.tvecs, self.flags, self.criteria, idx)
+	}
+}
+
 /// Computes undistortion and rectification maps for omnidirectional camera image transform by a rotation R.
 /// It output two maps that are used for cv::remap(). If D is empty then zero distortion is used,
 /// if R or P is empty then identity matrices are used.
@@ -96,7 +135,7 @@ pub fn init_undistort_rectify_map(k: &dyn core::ToInputArray, d: &dyn core::ToIn
 	output_array_arg!(map1);
 	output_array_arg!(map2);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_omnidir_initUndistortRectifyMap_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const_SizeR_int_const__OutputArrayR_const__OutputArrayR_int(k.as_raw__InputArray(), d.as_raw__InputArray(), xi.as_raw__InputArray(), r.as_raw__InputArray(), p.as_raw__InputArray(), &size, m1type, map1.as_raw__OutputArray(), map2.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_omnidir_initUndistortRectifyMap_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const_SizeR_int_const__OutputArrayR_const__OutputArrayR_int(k.as_raw__InputArray(), d.as_raw__InputArray(), xi.as_raw__InputArray(), r.as_raw__InputArray(), p.as_raw__InputArray(), &size, m1type, map1.as_raw__OutputArray(), map2.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -134,12 +173,47 @@ pub fn project_points_1(object_points: &dyn core::ToInputArray, image_points: &m
 	input_array_arg!(d);
 	output_array_arg!(jacobian);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_omnidir_projectPoints_const__InputArrayR_const__OutputArrayR_const_Affine3dR_const__InputArrayR_double_const__InputArrayR_const__OutputArrayR(object_points.as_raw__InputArray(), image_points.as_raw__OutputArray(), &affine, k.as_raw__InputArray(), xi, d.as_raw__InputArray(), jacobian.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_omnidir_projectPoints_const__InputArrayR_const__OutputArrayR_const_Affine3dR_const__InputArrayR_double_const__InputArrayR_const__OutputArrayR(object_points.as_raw__InputArray(), image_points.as_raw__OutputArray(), &affine, k.as_raw__InputArray(), xi, d.as_raw__InputArray(), jacobian.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `project_points_1()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct ProjectPoints1Builder<'a> {
+	object_points: &'a dyn core::ToInputArray,
+	image_points: &'a mut dyn core::ToOutputArray,
+	affine: core::Affine3d,
+	k: &'a dyn core::ToInputArray,
+	xi: f64,
+	d: &'a dyn core::ToInputArray,
+	jacobian: Option<&'a mut dyn core::ToOutputArray>,
+}
+
+impl<'a> ProjectPoints1Builder<'a> {
+	#[inline]
+	pub fn new(object_points: &'a dyn core::ToInputArray, image_points: &'a mut dyn core::ToOutputArray, affine: core::Affine3d, k: &'a dyn core::ToInputArray, xi: f64, d: &'a dyn core::ToInputArray) -> Self {
+		Self { object_points, image_points, affine, k, xi, d, jacobian: None }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_jacobian(mut self, jacobian: &'a mut dyn core::ToOutputArray) -> Self {
+		self.jacobian = Some(jacobian);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let mut jacobian_no_array = core::no_array();
+		let jacobian: &mut dyn core::ToOutputArray = match self.jacobian { Some(jacobian) => jacobian, None => &mut jacobian_no_array };
+		project_points_1(self.object_points, self.image_points, self.affine, self.k, self.xi, self.d, jacobian)
+	}
+}
+
 /// Projects points for omnidirectional camera using CMei's model
 /// 
 /// ## Parameters
@@ -172,12 +246,48 @@ pub fn project_points(object_points: &dyn core::ToInputArray, image_points: &mut
 	input_array_arg!(d);
 	output_array_arg!(jacobian);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_omnidir_projectPoints_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_double_const__InputArrayR_const__OutputArrayR(object_points.as_raw__InputArray(), image_points.as_raw__OutputArray(), rvec.as_raw__InputArray(), tvec.as_raw__InputArray(), k.as_raw__InputArray(), xi, d.as_raw__InputArray(), jacobian.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_omnidir_projectPoints_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_double_const__InputArrayR_const__OutputArrayR(object_points.as_raw__InputArray(), image_points.as_raw__OutputArray(), rvec.as_raw__InputArray(), tvec.as_raw__InputArray(), k.as_raw__InputArray(), xi, d.as_raw__InputArray(), jacobian.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `project_points()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct ProjectPointsBuilder<'a> {
+	object_points: &'a dyn core::ToInputArray,
+	image_points: &'a mut dyn core::ToOutputArray,
+	rvec: &'a dyn core::ToInputArray,
+	tvec: &'a dyn core::ToInputArray,
+	k: &'a dyn core::ToInputArray,
+	xi: f64,
+	d: &'a dyn core::ToInputArray,
+	jacobian: Option<&'a mut dyn core::ToOutputArray>,
+}
+
+impl<'a> ProjectPointsBuilder<'a> {
+	#[inline]
+	pub fn new(object_points: &'a dyn core::ToInputArray, image_points: &'a mut dyn core::ToOutputArray, rvec: &'a dyn core::ToInputArray, tvec: &'a dyn core::ToInputArray, k: &'a dyn core::ToInputArray, xi: f64, d: &'a dyn core::ToInputArray) -> Self {
+		Self { object_points, image_points, rvec, tvec, k, xi, d, jacobian: None }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_jacobian(mut self, jacobian: &'a mut dyn core::ToOutputArray) -> Self {
+		self.jacobian = Some(jacobian);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let mut jacobian_no_array = core::no_array();
+		let jacobian: &mut dyn core::ToOutputArray = match self.jacobian { Some(jacobian) => jacobian, None => &mut jacobian_no_array };
+		project_points(self.object_points, self.image_points, self.rvec, self.tvec, self.k, self.xi, self.d, jacobian)
+	}
+}
+
 /// Stereo calibration for omnidirectional camera model. It computes the intrinsic parameters for two
 /// cameras and the extrinsic parameters between two cameras. The default depth of outputs is CV_64F.
 /// 
@@ -225,12 +335,58 @@ pub fn stereo_calibrate(object_points: &mut dyn core::ToInputOutputArray, image_
 	output_array_arg!(tvecs_l);
 	output_array_arg!(idx);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_omnidir_stereoCalibrate_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const_SizeR_const_SizeR_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int_TermCriteria_const__OutputArrayR(object_points.as_raw__InputOutputArray(), image_points1.as_raw__InputOutputArray(), image_points2.as_raw__InputOutputArray(), &image_size1, &image_size2, k1.as_raw__InputOutputArray(), xi1.as_raw__InputOutputArray(), d1.as_raw__InputOutputArray(), k2.as_raw__InputOutputArray(), xi2.as_raw__InputOutputArray(), d2.as_raw__InputOutputArray(), rvec.as_raw__OutputArray(), tvec.as_raw__OutputArray(), rvecs_l.as_raw__OutputArray(), tvecs_l.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), idx.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_omnidir_stereoCalibrate_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const_SizeR_const_SizeR_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int_TermCriteria_const__OutputArrayR(object_points.as_raw__InputOutputArray(), image_points1.as_raw__InputOutputArray(), image_points2.as_raw__InputOutputArray(), &image_size1, &image_size2, k1.as_raw__InputOutputArray(), xi1.as_raw__InputOutputArray(), d1.as_raw__InputOutputArray(), k2.as_raw__InputOutputArray(), xi2.as_raw__InputOutputArray(), d2.as_raw__InputOutputArray(), rvec.as_raw__OutputArray(), tvec.as_raw__OutputArray(), rvecs_l.as_raw__OutputArray(), tvecs_l.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), idx.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `stereo_calibrate()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct StereoCalibrateBuilder<'a> {
+	object_points: &'a mut dyn core::ToInputOutputArray,
+	image_points1: &'a mut dyn core::ToInputOutputArray,
+	image_points2: &'a mut dyn core::ToInputOutputArray,
+	image_size1: core::Size,
+	image_size2: core::Size,
+	k1: &'a mut dyn core::ToInputOutputArray,
+	xi1: &'a mut dyn core::ToInputOutputArray,
+	d1: &'a mut dyn core::ToInputOutputArray,
+	k2: &'a mut dyn core::ToInputOutputArray,
+	xi2: &'a mut dyn core::ToInputOutputArray,
+	d2: &'a mut dyn core::ToInputOutputArray,
+	rvec: &'a mut dyn core::ToOutputArray,
+	tvec: &'a mut dyn core::ToOutputArray,
+	rvecs_l: &'a mut dyn core::ToOutputArray,
+	tvecs_l: &'a mut dyn core::ToOutputArray,
+	flags: i32,
+	criteria: core::TermCriteria,
+	idx: Option<&'a mut dyn core::ToOutputArray>,
+}
+
+impl<'a> StereoCalibrateBuilder<'a> {
+	#[inline]
+	pub fn new(object_points: &'a mut dyn core::ToInputOutputArray, image_points1: &'a mut dyn core::ToInputOutputArray, image_points2: &'a mut dyn core::ToInputOutputArray, image_size1: core::Size, image_size2: core::Size, k1: &'a mut dyn core::ToInputOutputArray, xi1: &'a mut dyn core::ToInputOutputArray, d1: &'a mut dyn core::ToInputOutputArray, k2: &'a mut dyn core::ToInputOutputArray, xi2: &'a mut dyn core::ToInputOutputArray, d2: &'a mut dyn core::ToInputOutputArray, rvec: &'a mut dyn core::ToOutputArray, tvec: &'a mut dyn core::ToOutputArray, rvecs_l: &'a mut dyn core::ToOutputArray, tvecs_l: &'a mut dyn core::ToOutputArray, flags: i32, criteria: core::TermCriteria) -> Self {
+		Self { object_points, image_points1, image_points2, image_size1, image_size2, k1, xi1, d1, k2, xi2, d2, rvec, tvec, rvecs_l, tvecs_l, flags, criteria, idx: None }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_idx(mut self, idx: &'a mut dyn core::ToOutputArray) -> Self {
+		self.idx = Some(idx);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<f64> {
+		let mut idx_no_array = core::no_array();
+		let idx: &mut dyn core::ToOutputArray = match self.idx { Some(idx) => idx, None => &mut idx_no_array };
+		stereo_calibrate(self.object_points, self.image_points1, self.image_points2, self.image_size1, self.image_size2, self.k1, self.xi1, self.d1, self.k2, self.xi2, self.d2, self.rvec, self.tvec, self.rvecs_l, self.tvecs_l, self.flags, self.criteria, idx)
+	}
+}
+
 /// Stereo 3D reconstruction from a pair of images
 /// 
 /// ## Parameters
@@ -278,12 +434,76 @@ pub fn stereo_reconstruct(image1: &dyn core::ToInputArray, image2: &dyn core::To
 	input_array_arg!(knew);
 	output_array_arg!(point_cloud);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_omnidir_stereoReconstruct_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_int_int_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const_SizeR_const__InputArrayR_const__OutputArrayR_int(image1.as_raw__InputArray(), image2.as_raw__InputArray(), k1.as_raw__InputArray(), d1.as_raw__InputArray(), xi1.as_raw__InputArray(), k2.as_raw__InputArray(), d2.as_raw__InputArray(), xi2.as_raw__InputArray(), r.as_raw__InputArray(), t.as_raw__InputArray(), flag, num_disparities, sad_window_size, disparity.as_raw__OutputArray(), image1_rec.as_raw__OutputArray(), image2_rec.as_raw__OutputArray(), &new_size, knew.as_raw__InputArray(), point_cloud.as_raw__OutputArray(), point_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_omnidir_stereoReconstruct_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_int_int_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const_SizeR_const__InputArrayR_const__OutputArrayR_int(image1.as_raw__InputArray(), image2.as_raw__InputArray(), k1.as_raw__InputArray(), d1.as_raw__InputArray(), xi1.as_raw__InputArray(), k2.as_raw__InputArray(), d2.as_raw__InputArray(), xi2.as_raw__InputArray(), r.as_raw__InputArray(), t.as_raw__InputArray(), flag, num_disparities, sad_window_size, disparity.as_raw__OutputArray(), image1_rec.as_raw__OutputArray(), image2_rec.as_raw__OutputArray(), &new_size, knew.as_raw__InputArray(), point_cloud.as_raw__OutputArray(), point_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `stereo_reconstruct()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct StereoReconstructBuilder<'a> {
+	image1: &'a dyn core::ToInputArray,
+	image2: &'a dyn core::ToInputArray,
+	k1: &'a dyn core::ToInputArray,
+	d1: &'a dyn core::ToInputArray,
+	xi1: &'a dyn core::ToInputArray,
+	k2: &'a dyn core::ToInputArray,
+	d2: &'a dyn core::ToInputArray,
+	xi2: &'a dyn core::ToInputArray,
+	r: &'a dyn core::ToInputArray,
+	t: &'a dyn core::ToInputArray,
+	flag: i32,
+	num_disparities: i32,
+	sad_window_size: i32,
+	disparity: &'a mut dyn core::ToOutputArray,
+	image1_rec: &'a mut dyn core::ToOutputArray,
+	image2_rec: &'a mut dyn core::ToOutputArray,
+	new_size: core::Size,
+	knew: Option<&'a dyn core::ToInputArray>,
+	point_cloud: Option<&'a mut dyn core::ToOutputArray>,
+	point_type: i32,
+}
+
+impl<'a> StereoReconstructBuilder<'a> {
+	#[inline]
+	pub fn new(image1: &'a dyn core::ToInputArray, image2: &'a dyn core::ToInputArray, k1: &'a dyn core::ToInputArray, d1: &'a dyn core::ToInputArray, xi1: &'a dyn core::ToInputArray, k2: &'a dyn core::ToInputArray, d2: &'a dyn core::ToInputArray, xi2: &'a dyn core::ToInputArray, r: &'a dyn core::ToInputArray, t: &'a dyn core::ToInputArray, flag: i32, num_disparities: i32, sad_window_size: i32, disparity: &'a mut dyn core::ToOutputArray, image1_rec: &'a mut dyn core::ToOutputArray, image2_rec: &'a mut dyn core::ToOutputArray, point_type: i32) -> Self {
+		Self { image1, image2, k1, d1, xi1, k2, d2, xi2, r, t, flag, num_disparities, sad_window_size, disparity, image1_rec, image2_rec, new_size: core::Size::default(), knew: None, point_cloud: None, point_type }
+	}
+	
+	/// C++ default: `Size()`
+	#[inline]
+	pub fn with_new_size(mut self, new_size: core::Size) -> Self {
+		self.new_size = new_size;
+		self
+	}
+	
+	/// C++ default: `cv::noArray()`
+	#[inline]
+	pub fn with_knew(mut self, knew: &'a dyn core::ToInputArray) -> Self {
+		self.knew = Some(knew);
+		self
+	}
+	
+	/// C++ default: `cv::noArray()`
+	#[inline]
+	pub fn with_point_cloud(mut self, point_cloud: &'a mut dyn core::ToOutputArray) -> Self {
+		self.point_cloud = Some(point_cloud);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let knew_no_array = core::no_array();
+		let knew: &dyn core::ToInputArray = match self.knew { Some(knew) => knew, None => &knew_no_array };
+		let mut point_cloud_no_array = core::no_array();
+		let point_cloud: &mut dyn core::ToOutputArray = match self.point_cloud { Some(point_cloud) => point_cloud, None => &mut point_cloud_no_array };
+		stereo_reconstruct(self.image1, self.image2, self.k1, self.d1, self.xi1, self.k2, self.d2, self.xi2, self.r, self.t, self.flag, self.num_disparities, self.sad_window_size, self.disparity, self.image1_rec, self.image2_rec, self.new_size, knew, point_cloud, self.point_type)
+	}
+}
+
 /// Stereo rectification for omnidirectional camera model. It computes the rectification rotations for two cameras
 /// 
 /// ## Parameters
@@ -298,7 +518,7 @@ pub fn stereo_rectify(r: &dyn core::ToInputArray, t: &dyn core::ToInputArray, r1
 	output_array_arg!(r1);
 	output_array_arg!(r2);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_omnidir_stereoRectify_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(r.as_raw__InputArray(), t.as_raw__InputArray(), r1.as_raw__OutputArray(), r2.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_omnidir_stereoRectify_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(r.as_raw__InputArray(), t.as_raw__InputArray(), r1.as_raw__OutputArray(), r2.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -331,12 +551,56 @@ pub fn undistort_image(distorted: &dyn core::ToInputArray, undistorted: &mut dyn
 	input_array_arg!(knew);
 	input_array_arg!(r);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_omnidir_undistortImage_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_const__InputArrayR_const_SizeR_const__InputArrayR(distorted.as_raw__InputArray(), undistorted.as_raw__OutputArray(), k.as_raw__InputArray(), d.as_raw__InputArray(), xi.as_raw__InputArray(), flags, knew.as_raw__InputArray(), &new_size, r.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_omnidir_undistortImage_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_const__InputArrayR_const_SizeR_const__InputArrayR(distorted.as_raw__InputArray(), undistorted.as_raw__OutputArray(), k.as_raw__InputArray(), d.as_raw__InputArray(), xi.as_raw__InputArray(), flags, knew.as_raw__InputArray(), &new_size, r.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `undistort_image()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct UndistortImageBuilder<'a> {
+	distorted: &'a dyn core::ToInputArray,
+	undistorted: &'a mut dyn core::ToOutputArray,
+	k: &'a dyn core::ToInputArray,
+	d: &'a dyn core::ToInputArray,
+	xi: &'a dyn core::ToInputArray,
+	flags: i32,
+	knew: Option<&'a dyn core::ToInputArray>,
+	new_size: core::Size,
+	r: &'a dyn core::ToInputArray,
+}
+
+impl<'a> UndistortImageBuilder<'a> {
+	#[inline]
+	pub fn new(distorted: &'a dyn core::ToInputArray, undistorted: &'a mut dyn core::ToOutputArray, k: &'a dyn core::ToInputArray, d: &'a dyn core::ToInputArray, xi: &'a dyn core::ToInputArray, flags: i32, r: &'a dyn core::ToInputArray) -> Self {
+		Self { distorted, undistorted, k, d, xi, flags, knew: None, new_size: core::Size::default(), r }
+	}
+	
+	/// C++ default: `cv::noArray()`
+	#[inline]
+	pub fn with_knew(mut self, knew: &'a dyn core::ToInputArray) -> Self {
+		self.knew = Some(knew);
+		self
+	}
+	
+	/// C++ default: `Size()`
+	#[inline]
+	pub fn with_new_size(mut self, new_size: core::Size) -> Self {
+		self.new_size = new_size;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let knew_no_array = core::no_array();
+		let knew: &dyn core::ToInputArray = match self.knew { Some(knew) => knew, None => &knew_no_array };
+		undistort_image(self.distorted, self.undistorted, self.k, self.d, self.xi, self.flags, knew, self.new_size, self.r)
+	}
+}
+
 /// Undistort 2D image points for omnidirectional camera using CMei's model
 /// 
 /// ## Parameters
@@ -358,7 +622,7 @@ pub fn undistort_points(distorted: &dyn core::ToInputArray, undistorted: &mut dy
 	input_array_arg!(xi);
 	input_array_arg!(r);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_omnidir_undistortPoints_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR(distorted.as_raw__InputArray(), undistorted.as_raw__OutputArray(), k.as_raw__InputArray(), d.as_raw__InputArray(), xi.as_raw__InputArray(), r.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_omnidir_undistortPoints_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR(distorted.as_raw__InputArray(), undistorted.as_raw__OutputArray(), k.as_raw__InputArray(), d.as_raw__InputArray(), xi.as_raw__InputArray(), r.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -376,10 +640,11 @@ pub trait CustomPatternTrait: core::AlgorithmTrait + crate::ccalib::CustomPatter
 	/// * output: noArray()
 	#[inline]
 	fn create(&mut self, pattern: &dyn core::ToInputArray, board_size: core::Size2f, output: &mut dyn core::ToOutputArray) -> Result<bool> {
+		crate::core::validity::check("CustomPattern", self.as_raw_mut_CustomPattern())?;
 		input_array_arg!(pattern);
 		output_array_arg!(output);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccalib_CustomPattern_create_const__InputArrayR_const_Size2f_const__OutputArrayR(self.as_raw_mut_CustomPattern(), pattern.as_raw__InputArray(), board_size.opencv_as_extern(), output.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccalib_CustomPattern_create_const__InputArrayR_const_Size2f_const__OutputArrayR(self.as_raw_mut_CustomPattern(), pattern.as_raw__InputArray(), board_size.opencv_as_extern(), output.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -394,6 +659,7 @@ pub trait CustomPatternTrait: core::AlgorithmTrait + crate::ccalib::CustomPatter
 	/// * pattern_corners: noArray()
 	#[inline]
 	fn find_pattern(&mut self, image: &dyn core::ToInputArray, matched_features: &mut dyn core::ToOutputArray, pattern_points: &mut dyn core::ToOutputArray, ratio: f64, proj_error: f64, refine_position: bool, out: &mut dyn core::ToOutputArray, h: &mut dyn core::ToOutputArray, pattern_corners: &mut dyn core::ToOutputArray) -> Result<bool> {
+		crate::core::validity::check("CustomPattern", self.as_raw_mut_CustomPattern())?;
 		input_array_arg!(image);
 		output_array_arg!(matched_features);
 		output_array_arg!(pattern_points);
@@ -401,7 +667,7 @@ pub trait CustomPatternTrait: core::AlgorithmTrait + crate::ccalib::CustomPatter
 		output_array_arg!(h);
 		output_array_arg!(pattern_corners);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccalib_CustomPattern_findPattern_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const_double_const_double_const_bool_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_CustomPattern(), image.as_raw__InputArray(), matched_features.as_raw__OutputArray(), pattern_points.as_raw__OutputArray(), ratio, proj_error, refine_position, out.as_raw__OutputArray(), h.as_raw__OutputArray(), pattern_corners.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccalib_CustomPattern_findPattern_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const_double_const_double_const_bool_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_CustomPattern(), image.as_raw__InputArray(), matched_features.as_raw__OutputArray(), pattern_points.as_raw__OutputArray(), ratio, proj_error, refine_position, out.as_raw__OutputArray(), h.as_raw__OutputArray(), pattern_corners.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -409,8 +675,9 @@ pub trait CustomPatternTrait: core::AlgorithmTrait + crate::ccalib::CustomPatter
 	
 	#[inline]
 	fn is_initialized(&mut self) -> Result<bool> {
+		crate::core::validity::check("CustomPattern", self.as_raw_mut_CustomPattern())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccalib_CustomPattern_isInitialized(self.as_raw_mut_CustomPattern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccalib_CustomPattern_isInitialized(self.as_raw_mut_CustomPattern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -418,8 +685,9 @@ pub trait CustomPatternTrait: core::AlgorithmTrait + crate::ccalib::CustomPatter
 	
 	#[inline]
 	fn get_pattern_points(&mut self, original_points: &mut core::Vector<core::KeyPoint>) -> Result<()> {
+		crate::core::validity::check("CustomPattern", self.as_raw_mut_CustomPattern())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccalib_CustomPattern_getPatternPoints_vector_KeyPoint_R(self.as_raw_mut_CustomPattern(), original_points.as_raw_mut_VectorOfKeyPoint(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccalib_CustomPattern_getPatternPoints_vector_KeyPoint_R(self.as_raw_mut_CustomPattern(), original_points.as_raw_mut_VectorOfKeyPoint(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -427,8 +695,9 @@ pub trait CustomPatternTrait: core::AlgorithmTrait + crate::ccalib::CustomPatter
 	
 	#[inline]
 	fn get_pixel_size(&mut self) -> Result<f64> {
+		crate::core::validity::check("CustomPattern", self.as_raw_mut_CustomPattern())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccalib_CustomPattern_getPixelSize(self.as_raw_mut_CustomPattern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccalib_CustomPattern_getPixelSize(self.as_raw_mut_CustomPattern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -436,8 +705,9 @@ pub trait CustomPatternTrait: core::AlgorithmTrait + crate::ccalib::CustomPatter
 	
 	#[inline]
 	fn set_feature_detector(&mut self, mut feature_detector: core::Ptr<crate::features2d::Feature2D>) -> Result<bool> {
+		crate::core::validity::check("CustomPattern", self.as_raw_mut_CustomPattern())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccalib_CustomPattern_setFeatureDetector_Ptr_Feature2D_(self.as_raw_mut_CustomPattern(), feature_detector.as_raw_mut_PtrOfFeature2D(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccalib_CustomPattern_setFeatureDetector_Ptr_Feature2D_(self.as_raw_mut_CustomPattern(), feature_detector.as_raw_mut_PtrOfFeature2D(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -445,8 +715,9 @@ pub trait CustomPatternTrait: core::AlgorithmTrait + crate::ccalib::CustomPatter
 	
 	#[inline]
 	fn set_descriptor_extractor(&mut self, mut extractor: core::Ptr<crate::features2d::Feature2D>) -> Result<bool> {
+		crate::core::validity::check("CustomPattern", self.as_raw_mut_CustomPattern())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccalib_CustomPattern_setDescriptorExtractor_Ptr_Feature2D_(self.as_raw_mut_CustomPattern(), extractor.as_raw_mut_PtrOfFeature2D(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccalib_CustomPattern_setDescriptorExtractor_Ptr_Feature2D_(self.as_raw_mut_CustomPattern(), extractor.as_raw_mut_PtrOfFeature2D(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -454,8 +725,9 @@ pub trait CustomPatternTrait: core::AlgorithmTrait + crate::ccalib::CustomPatter
 	
 	#[inline]
 	fn set_descriptor_matcher(&mut self, mut matcher: core::Ptr<dyn crate::features2d::DescriptorMatcher>) -> Result<bool> {
+		crate::core::validity::check("CustomPattern", self.as_raw_mut_CustomPattern())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccalib_CustomPattern_setDescriptorMatcher_Ptr_DescriptorMatcher_(self.as_raw_mut_CustomPattern(), matcher.as_raw_mut_PtrOfDescriptorMatcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccalib_CustomPattern_setDescriptorMatcher_Ptr_DescriptorMatcher_(self.as_raw_mut_CustomPattern(), matcher.as_raw_mut_PtrOfDescriptorMatcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -463,8 +735,9 @@ pub trait CustomPatternTrait: core::AlgorithmTrait + crate::ccalib::CustomPatter
 	
 	#[inline]
 	fn get_feature_detector(&mut self) -> Result<core::Ptr<crate::features2d::Feature2D>> {
+		crate::core::validity::check("CustomPattern", self.as_raw_mut_CustomPattern())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccalib_CustomPattern_getFeatureDetector(self.as_raw_mut_CustomPattern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccalib_CustomPattern_getFeatureDetector(self.as_raw_mut_CustomPattern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::features2d::Feature2D>::opencv_from_extern(ret) };
@@ -473,8 +746,9 @@ pub trait CustomPatternTrait: core::AlgorithmTrait + crate::ccalib::CustomPatter
 	
 	#[inline]
 	fn get_descriptor_extractor(&mut self) -> Result<core::Ptr<crate::features2d::Feature2D>> {
+		crate::core::validity::check("CustomPattern", self.as_raw_mut_CustomPattern())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccalib_CustomPattern_getDescriptorExtractor(self.as_raw_mut_CustomPattern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccalib_CustomPattern_getDescriptorExtractor(self.as_raw_mut_CustomPattern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::features2d::Feature2D>::opencv_from_extern(ret) };
@@ -483,8 +757,9 @@ pub trait CustomPatternTrait: core::AlgorithmTrait + crate::ccalib::CustomPatter
 	
 	#[inline]
 	fn get_descriptor_matcher(&mut self) -> Result<core::Ptr<dyn crate::features2d::DescriptorMatcher>> {
+		crate::core::validity::check("CustomPattern", self.as_raw_mut_CustomPattern())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccalib_CustomPattern_getDescriptorMatcher(self.as_raw_mut_CustomPattern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccalib_CustomPattern_getDescriptorMatcher(self.as_raw_mut_CustomPattern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::features2d::DescriptorMatcher>::opencv_from_extern(ret) };
@@ -496,6 +771,7 @@ pub trait CustomPatternTrait: core::AlgorithmTrait + crate::ccalib::CustomPatter
 	/// * criteria: TermCriteria(TermCriteria::COUNT+TermCriteria::EPS,30,DBL_EPSILON)
 	#[inline]
 	fn calibrate(&mut self, object_points: &dyn core::ToInputArray, image_points: &dyn core::ToInputArray, image_size: core::Size, camera_matrix: &mut dyn core::ToInputOutputArray, dist_coeffs: &mut dyn core::ToInputOutputArray, rvecs: &mut dyn core::ToOutputArray, tvecs: &mut dyn core::ToOutputArray, flags: i32, criteria: core::TermCriteria) -> Result<f64> {
+		crate::core::validity::check("CustomPattern", self.as_raw_mut_CustomPattern())?;
 		input_array_arg!(object_points);
 		input_array_arg!(image_points);
 		input_output_array_arg!(camera_matrix);
@@ -503,7 +779,7 @@ pub trait CustomPatternTrait: core::AlgorithmTrait + crate::ccalib::CustomPatter
 		output_array_arg!(rvecs);
 		output_array_arg!(tvecs);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccalib_CustomPattern_calibrate_const__InputArrayR_const__InputArrayR_Size_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_int_TermCriteria(self.as_raw_mut_CustomPattern(), object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), image_size.opencv_as_extern(), camera_matrix.as_raw__InputOutputArray(), dist_coeffs.as_raw__InputOutputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccalib_CustomPattern_calibrate_const__InputArrayR_const__InputArrayR_Size_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_int_TermCriteria(self.as_raw_mut_CustomPattern(), object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), image_size.opencv_as_extern(), camera_matrix.as_raw__InputOutputArray(), dist_coeffs.as_raw__InputOutputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -514,6 +790,7 @@ pub trait CustomPatternTrait: core::AlgorithmTrait + crate::ccalib::CustomPatter
 	/// * flags: SOLVEPNP_ITERATIVE
 	#[inline]
 	fn find_rt(&mut self, object_points: &dyn core::ToInputArray, image_points: &dyn core::ToInputArray, camera_matrix: &dyn core::ToInputArray, dist_coeffs: &dyn core::ToInputArray, rvec: &mut dyn core::ToInputOutputArray, tvec: &mut dyn core::ToInputOutputArray, use_extrinsic_guess: bool, flags: i32) -> Result<bool> {
+		crate::core::validity::check("CustomPattern", self.as_raw_mut_CustomPattern())?;
 		input_array_arg!(object_points);
 		input_array_arg!(image_points);
 		input_array_arg!(camera_matrix);
@@ -521,7 +798,7 @@ pub trait CustomPatternTrait: core::AlgorithmTrait + crate::ccalib::CustomPatter
 		input_output_array_arg!(rvec);
 		input_output_array_arg!(tvec);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccalib_CustomPattern_findRt_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_bool_int(self.as_raw_mut_CustomPattern(), object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvec.as_raw__InputOutputArray(), tvec.as_raw__InputOutputArray(), use_extrinsic_guess, flags, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccalib_CustomPattern_findRt_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_bool_int(self.as_raw_mut_CustomPattern(), object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvec.as_raw__InputOutputArray(), tvec.as_raw__InputOutputArray(), use_extrinsic_guess, flags, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -532,13 +809,14 @@ pub trait CustomPatternTrait: core::AlgorithmTrait + crate::ccalib::CustomPatter
 	/// * flags: SOLVEPNP_ITERATIVE
 	#[inline]
 	fn find_rt_1(&mut self, image: &dyn core::ToInputArray, camera_matrix: &dyn core::ToInputArray, dist_coeffs: &dyn core::ToInputArray, rvec: &mut dyn core::ToInputOutputArray, tvec: &mut dyn core::ToInputOutputArray, use_extrinsic_guess: bool, flags: i32) -> Result<bool> {
+		crate::core::validity::check("CustomPattern", self.as_raw_mut_CustomPattern())?;
 		input_array_arg!(image);
 		input_array_arg!(camera_matrix);
 		input_array_arg!(dist_coeffs);
 		input_output_array_arg!(rvec);
 		input_output_array_arg!(tvec);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccalib_CustomPattern_findRt_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_bool_int(self.as_raw_mut_CustomPattern(), image.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvec.as_raw__InputOutputArray(), tvec.as_raw__InputOutputArray(), use_extrinsic_guess, flags, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccalib_CustomPattern_findRt_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_bool_int(self.as_raw_mut_CustomPattern(), image.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvec.as_raw__InputOutputArray(), tvec.as_raw__InputOutputArray(), use_extrinsic_guess, flags, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -553,6 +831,7 @@ pub trait CustomPatternTrait: core::AlgorithmTrait + crate::ccalib::CustomPatter
 	/// * flags: SOLVEPNP_ITERATIVE
 	#[inline]
 	fn find_rt_ransac(&mut self, object_points: &dyn core::ToInputArray, image_points: &dyn core::ToInputArray, camera_matrix: &dyn core::ToInputArray, dist_coeffs: &dyn core::ToInputArray, rvec: &mut dyn core::ToInputOutputArray, tvec: &mut dyn core::ToInputOutputArray, use_extrinsic_guess: bool, iterations_count: i32, reprojection_error: f32, min_inliers_count: i32, inliers: &mut dyn core::ToOutputArray, flags: i32) -> Result<bool> {
+		crate::core::validity::check("CustomPattern", self.as_raw_mut_CustomPattern())?;
 		input_array_arg!(object_points);
 		input_array_arg!(image_points);
 		input_array_arg!(camera_matrix);
@@ -561,7 +840,7 @@ pub trait CustomPatternTrait: core::AlgorithmTrait + crate::ccalib::CustomPatter
 		input_output_array_arg!(tvec);
 		output_array_arg!(inliers);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccalib_CustomPattern_findRtRANSAC_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_bool_int_float_int_const__OutputArrayR_int(self.as_raw_mut_CustomPattern(), object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvec.as_raw__InputOutputArray(), tvec.as_raw__InputOutputArray(), use_extrinsic_guess, iterations_count, reprojection_error, min_inliers_count, inliers.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccalib_CustomPattern_findRtRANSAC_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_bool_int_float_int_const__OutputArrayR_int(self.as_raw_mut_CustomPattern(), object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvec.as_raw__InputOutputArray(), tvec.as_raw__InputOutputArray(), use_extrinsic_guess, iterations_count, reprojection_error, min_inliers_count, inliers.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -576,6 +855,7 @@ pub trait CustomPatternTrait: core::AlgorithmTrait + crate::ccalib::CustomPatter
 	/// * flags: SOLVEPNP_ITERATIVE
 	#[inline]
 	fn find_rt_ransac_1(&mut self, image: &dyn core::ToInputArray, camera_matrix: &dyn core::ToInputArray, dist_coeffs: &dyn core::ToInputArray, rvec: &mut dyn core::ToInputOutputArray, tvec: &mut dyn core::ToInputOutputArray, use_extrinsic_guess: bool, iterations_count: i32, reprojection_error: f32, min_inliers_count: i32, inliers: &mut dyn core::ToOutputArray, flags: i32) -> Result<bool> {
+		crate::core::validity::check("CustomPattern", self.as_raw_mut_CustomPattern())?;
 		input_array_arg!(image);
 		input_array_arg!(camera_matrix);
 		input_array_arg!(dist_coeffs);
@@ -583,7 +863,7 @@ pub trait CustomPatternTrait: core::AlgorithmTrait + crate::ccalib::CustomPatter
 		input_output_array_arg!(tvec);
 		output_array_arg!(inliers);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccalib_CustomPattern_findRtRANSAC_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_bool_int_float_int_const__OutputArrayR_int(self.as_raw_mut_CustomPattern(), image.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvec.as_raw__InputOutputArray(), tvec.as_raw__InputOutputArray(), use_extrinsic_guess, iterations_count, reprojection_error, min_inliers_count, inliers.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccalib_CustomPattern_findRtRANSAC_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_bool_int_float_int_const__OutputArrayR_int(self.as_raw_mut_CustomPattern(), image.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvec.as_raw__InputOutputArray(), tvec.as_raw__InputOutputArray(), use_extrinsic_guess, iterations_count, reprojection_error, min_inliers_count, inliers.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -594,13 +874,14 @@ pub trait CustomPatternTrait: core::AlgorithmTrait + crate::ccalib::CustomPatter
 	/// * axis_width: 2
 	#[inline]
 	fn draw_orientation(&mut self, image: &mut dyn core::ToInputOutputArray, tvec: &dyn core::ToInputArray, rvec: &dyn core::ToInputArray, camera_matrix: &dyn core::ToInputArray, dist_coeffs: &dyn core::ToInputArray, axis_length: f64, axis_width: i32) -> Result<()> {
+		crate::core::validity::check("CustomPattern", self.as_raw_mut_CustomPattern())?;
 		input_output_array_arg!(image);
 		input_array_arg!(tvec);
 		input_array_arg!(rvec);
 		input_array_arg!(camera_matrix);
 		input_array_arg!(dist_coeffs);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccalib_CustomPattern_drawOrientation_const__InputOutputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_double_int(self.as_raw_mut_CustomPattern(), image.as_raw__InputOutputArray(), tvec.as_raw__InputArray(), rvec.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), axis_length, axis_width, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccalib_CustomPattern_drawOrientation_const__InputOutputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_double_int(self.as_raw_mut_CustomPattern(), image.as_raw__InputOutputArray(), tvec.as_raw__InputArray(), rvec.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), axis_length, axis_width, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -608,6 +889,366 @@ pub trait CustomPatternTrait: core::AlgorithmTrait + crate::ccalib::CustomPatter
 	
 }
 
+/// Builder for `CustomPatternTrait::find_pattern()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CustomPattern_FindPatternBuilder<'a, T: ?Sized + CustomPatternTrait> {
+	instance: &'a mut T,
+	image: &'a dyn core::ToInputArray,
+	matched_features: &'a mut dyn core::ToOutputArray,
+	pattern_points: &'a mut dyn core::ToOutputArray,
+	ratio: f64,
+	proj_error: f64,
+	refine_position: bool,
+	out: Option<&'a mut dyn core::ToOutputArray>,
+	h: Option<&'a mut dyn core::ToOutputArray>,
+	pattern_corners: Option<&'a mut dyn core::ToOutputArray>,
+}
+
+impl<'a, T: ?Sized + CustomPatternTrait> CustomPattern_FindPatternBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, image: &'a dyn core::ToInputArray, matched_features: &'a mut dyn core::ToOutputArray, pattern_points: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { instance, image, matched_features, pattern_points, ratio: 0.7, proj_error: 8.0, refine_position: false, out: None, h: None, pattern_corners: None }
+	}
+	
+	/// C++ default: `0.7`
+	#[inline]
+	pub fn with_ratio(mut self, ratio: f64) -> Self {
+		self.ratio = ratio;
+		self
+	}
+	
+	/// C++ default: `8.0`
+	#[inline]
+	pub fn with_proj_error(mut self, proj_error: f64) -> Self {
+		self.proj_error = proj_error;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_refine_position(mut self, refine_position: bool) -> Self {
+		self.refine_position = refine_position;
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_out(mut self, out: &'a mut dyn core::ToOutputArray) -> Self {
+		self.out = Some(out);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_h(mut self, h: &'a mut dyn core::ToOutputArray) -> Self {
+		self.h = Some(h);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_pattern_corners(mut self, pattern_corners: &'a mut dyn core::ToOutputArray) -> Self {
+		self.pattern_corners = Some(pattern_corners);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<bool> {
+		let mut out_no_array = core::no_array();
+		let out: &mut dyn core::ToOutputArray = match self.out { Some(out) => out, None => &mut out_no_array };
+		let mut h_no_array = core::no_array();
+		let h: &mut dyn core::ToOutputArray = match self.h { Some(h) => h, None => &mut h_no_array };
+		let mut pattern_corners_no_array = core::no_array();
+		let pattern_corners: &mut dyn core::ToOutputArray = match self.pattern_corners { Some(pattern_corners) => pattern_corners, None => &mut pattern_corners_no_array };
+		CustomPatternTrait::find_pattern(self.instance, self.image, self.matched_features, self.pattern_points, self.ratio, self.proj_error, self.refine_position, out, h, pattern_corners)
+	}
+}
+
+/// Builder for `CustomPatternTrait::calibrate()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CustomPattern_CalibrateBuilder<'a, T: ?Sized + CustomPatternTrait> {
+	instance: &'a mut T,
+	object_points: &'a dyn core::ToInputArray,
+	image_points: &'a dyn core::ToInputArray,
+	image_size: core::Size,
+	camera_matrix: &'a mut dyn core::ToInputOutputArray,
+	dist_coeffs: &'a mut dyn core::ToInputOutputArray,
+	rvecs: &'a mut dyn core::ToOutputArray,
+	tvecs: &'a mut dyn core::ToOutputArray,
+	flags: i32,
+	criteria: core::TermCriteria,
+}
+
+impl<'a, T: ?Sized + CustomPatternTrait> CustomPattern_CalibrateBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, object_points: &'a dyn core::ToInputArray, image_points: &'a dyn core::ToInputArray, image_size: core::Size, camera_matrix: &'a mut dyn core::ToInputOutputArray, dist_coeffs: &'a mut dyn core::ToInputOutputArray, rvecs: &'a mut dyn core::ToOutputArray, tvecs: &'a mut dyn core::ToOutputArray, criteria: core::TermCriteria) -> Self {
+		Self { instance, object_points, image_points, image_size, camera_matrix, dist_coeffs, rvecs, tvecs, flags: 0, criteria }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_flags(mut self, flags: i32) -> Self {
+		self.flags = flags;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<f64> {
+		CustomPatternTrait::calibrate(self.instance, self.object_points, self.image_points, self.image_size, self.camera_matrix, self.dist_coeffs, self.rvecs, self.tvecs, self.flags, self.criteria)
+	}
+}
+
+/// Builder for `CustomPatternTrait::find_rt()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CustomPattern_FindRtBuilder<'a, T: ?Sized + CustomPatternTrait> {
+	instance: &'a mut T,
+	object_points: &'a dyn core::ToInputArray,
+	image_points: &'a dyn core::ToInputArray,
+	camera_matrix: &'a dyn core::ToInputArray,
+	dist_coeffs: &'a dyn core::ToInputArray,
+	rvec: &'a mut dyn core::ToInputOutputArray,
+	tvec: &'a mut dyn core::ToInputOutputArray,
+	use_extrinsic_guess: bool,
+	flags: i32,
+}
+
+impl<'a, T: ?Sized + CustomPatternTrait> CustomPattern_FindRtBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, object_points: &'a dyn core::ToInputArray, image_points: &'a dyn core::ToInputArray, camera_matrix: &'a dyn core::ToInputArray, dist_coeffs: &'a dyn core::ToInputArray, rvec: &'a mut dyn core::ToInputOutputArray, tvec: &'a mut dyn core::ToInputOutputArray, flags: i32) -> Self {
+		Self { instance, object_points, image_points, camera_matrix, dist_coeffs, rvec, tvec, use_extrinsic_guess: false, flags }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_use_extrinsic_guess(mut self, use_extrinsic_guess: bool) -> Self {
+		self.use_extrinsic_guess = use_extrinsic_guess;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<bool> {
+		CustomPatternTrait::find_rt(self.instance, self.object_points, self.image_points, self.camera_matrix, self.dist_coeffs, self.rvec, self.tvec, self.use_extrinsic_guess, self.flags)
+	}
+}
+
+/// Builder for `CustomPatternTrait::find_rt_1()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CustomPattern_FindRt1Builder<'a, T: ?Sized + CustomPatternTrait> {
+	instance: &'a mut T,
+	image: &'a dyn core::ToInputArray,
+	camera_matrix: &'a dyn core::ToInputArray,
+	dist_coeffs: &'a dyn core::ToInputArray,
+	rvec: &'a mut dyn core::ToInputOutputArray,
+	tvec: &'a mut dyn core::ToInputOutputArray,
+	use_extrinsic_guess: bool,
+	flags: i32,
+}
+
+impl<'a, T: ?Sized + CustomPatternTrait> CustomPattern_FindRt1Builder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, image: &'a dyn core::ToInputArray, camera_matrix: &'a dyn core::ToInputArray, dist_coeffs: &'a dyn core::ToInputArray, rvec: &'a mut dyn core::ToInputOutputArray, tvec: &'a mut dyn core::ToInputOutputArray, flags: i32) -> Self {
+		Self { instance, image, camera_matrix, dist_coeffs, rvec, tvec, use_extrinsic_guess: false, flags }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_use_extrinsic_guess(mut self, use_extrinsic_guess: bool) -> Self {
+		self.use_extrinsic_guess = use_extrinsic_guess;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<bool> {
+		CustomPatternTrait::find_rt_1(self.instance, self.image, self.camera_matrix, self.dist_coeffs, self.rvec, self.tvec, self.use_extrinsic_guess, self.flags)
+	}
+}
+
+/// Builder for `CustomPatternTrait::find_rt_ransac()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CustomPattern_FindRtRansacBuilder<'a, T: ?Sized + CustomPatternTrait> {
+	instance: &'a mut T,
+	object_points: &'a dyn core::ToInputArray,
+	image_points: &'a dyn core::ToInputArray,
+	camera_matrix: &'a dyn core::ToInputArray,
+	dist_coeffs: &'a dyn core::ToInputArray,
+	rvec: &'a mut dyn core::ToInputOutputArray,
+	tvec: &'a mut dyn core::ToInputOutputArray,
+	use_extrinsic_guess: bool,
+	iterations_count: i32,
+	reprojection_error: f32,
+	min_inliers_count: i32,
+	inliers: Option<&'a mut dyn core::ToOutputArray>,
+	flags: i32,
+}
+
+impl<'a, T: ?Sized + CustomPatternTrait> CustomPattern_FindRtRansacBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, object_points: &'a dyn core::ToInputArray, image_points: &'a dyn core::ToInputArray, camera_matrix: &'a dyn core::ToInputArray, dist_coeffs: &'a dyn core::ToInputArray, rvec: &'a mut dyn core::ToInputOutputArray, tvec: &'a mut dyn core::ToInputOutputArray, flags: i32) -> Self {
+		Self { instance, object_points, image_points, camera_matrix, dist_coeffs, rvec, tvec, use_extrinsic_guess: false, iterations_count: 100, reprojection_error: 8.0, min_inliers_count: 100, inliers: None, flags }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_use_extrinsic_guess(mut self, use_extrinsic_guess: bool) -> Self {
+		self.use_extrinsic_guess = use_extrinsic_guess;
+		self
+	}
+	
+	/// C++ default: `100`
+	#[inline]
+	pub fn with_iterations_count(mut self, iterations_count: i32) -> Self {
+		self.iterations_count = iterations_count;
+		self
+	}
+	
+	/// C++ default: `8.0`
+	#[inline]
+	pub fn with_reprojection_error(mut self, reprojection_error: f32) -> Self {
+		self.reprojection_error = reprojection_error;
+		self
+	}
+	
+	/// C++ default: `100`
+	#[inline]
+	pub fn with_min_inliers_count(mut self, min_inliers_count: i32) -> Self {
+		self.min_inliers_count = min_inliers_count;
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_inliers(mut self, inliers: &'a mut dyn core::ToOutputArray) -> Self {
+		self.inliers = Some(inliers);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<bool> {
+		let mut inliers_no_array = core::no_array();
+		let inliers: &mut dyn core::ToOutputArray = match self.inliers { Some(inliers) => inliers, None => &mut inliers_no_array };
+		CustomPatternTrait::find_rt_ransac(self.instance, self.object_points, self.image_points, self.camera_matrix, self.dist_coeffs, self.rvec, self.tvec, self.use_extrinsic_guess, self.iterations_count, self.reprojection_error, self.min_inliers_count, inliers, self.flags)
+	}
+}
+
+/// Builder for `CustomPatternTrait::find_rt_ransac_1()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CustomPattern_FindRtRansac1Builder<'a, T: ?Sized + CustomPatternTrait> {
+	instance: &'a mut T,
+	image: &'a dyn core::ToInputArray,
+	camera_matrix: &'a dyn core::ToInputArray,
+	dist_coeffs: &'a dyn core::ToInputArray,
+	rvec: &'a mut dyn core::ToInputOutputArray,
+	tvec: &'a mut dyn core::ToInputOutputArray,
+	use_extrinsic_guess: bool,
+	iterations_count: i32,
+	reprojection_error: f32,
+	min_inliers_count: i32,
+	inliers: Option<&'a mut dyn core::ToOutputArray>,
+	flags: i32,
+}
+
+impl<'a, T: ?Sized + CustomPatternTrait> CustomPattern_FindRtRansac1Builder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, image: &'a dyn core::ToInputArray, camera_matrix: &'a dyn core::ToInputArray, dist_coeffs: &'a dyn core::ToInputArray, rvec: &'a mut dyn core::ToInputOutputArray, tvec: &'a mut dyn core::ToInputOutputArray, flags: i32) -> Self {
+		Self { instance, image, camera_matrix, dist_coeffs, rvec, tvec, use_extrinsic_guess: false, iterations_count: 100, reprojection_error: 8.0, min_inliers_count: 100, inliers: None, flags }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_use_extrinsic_guess(mut self, use_extrinsic_guess: bool) -> Self {
+		self.use_extrinsic_guess = use_extrinsic_guess;
+		self
+	}
+	
+	/// C++ default: `100`
+	#[inline]
+	pub fn with_iterations_count(mut self, iterations_count: i32) -> Self {
+		self.iterations_count = iterations_count;
+		self
+	}
+	
+	/// C++ default: `8.0`
+	#[inline]
+	pub fn with_reprojection_error(mut self, reprojection_error: f32) -> Self {
+		self.reprojection_error = reprojection_error;
+		self
+	}
+	
+	/// C++ default: `100`
+	#[inline]
+	pub fn with_min_inliers_count(mut self, min_inliers_count: i32) -> Self {
+		self.min_inliers_count = min_inliers_count;
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_inliers(mut self, inliers: &'a mut dyn core::ToOutputArray) -> Self {
+		self.inliers = Some(inliers);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<bool> {
+		let mut inliers_no_array = core::no_array();
+		let inliers: &mut dyn core::ToOutputArray = match self.inliers { Some(inliers) => inliers, None => &mut inliers_no_array };
+		CustomPatternTrait::find_rt_ransac_1(self.instance, self.image, self.camera_matrix, self.dist_coeffs, self.rvec, self.tvec, self.use_extrinsic_guess, self.iterations_count, self.reprojection_error, self.min_inliers_count, inliers, self.flags)
+	}
+}
+
+/// Builder for `CustomPatternTrait::draw_orientation()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CustomPattern_DrawOrientationBuilder<'a, T: ?Sized + CustomPatternTrait> {
+	instance: &'a mut T,
+	image: &'a mut dyn core::ToInputOutputArray,
+	tvec: &'a dyn core::ToInputArray,
+	rvec: &'a dyn core::ToInputArray,
+	camera_matrix: &'a dyn core::ToInputArray,
+	dist_coeffs: &'a dyn core::ToInputArray,
+	axis_length: f64,
+	axis_width: i32,
+}
+
+impl<'a, T: ?Sized + CustomPatternTrait> CustomPattern_DrawOrientationBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, image: &'a mut dyn core::ToInputOutputArray, tvec: &'a dyn core::ToInputArray, rvec: &'a dyn core::ToInputArray, camera_matrix: &'a dyn core::ToInputArray, dist_coeffs: &'a dyn core::ToInputArray) -> Self {
+		Self { instance, image, tvec, rvec, camera_matrix, dist_coeffs, axis_length: 3., axis_width: 2 }
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_axis_length(mut self, axis_length: f64) -> Self {
+		self.axis_length = axis_length;
+		self
+	}
+	
+	/// C++ default: `2`
+	#[inline]
+	pub fn with_axis_width(mut self, axis_width: i32) -> Self {
+		self.axis_width = axis_width;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		CustomPatternTrait::draw_orientation(self.instance, self.image, self.tvec, self.rvec, self.camera_matrix, self.dist_coeffs, self.axis_length, self.axis_width)
+	}
+}
+
 pub struct CustomPattern {
 	ptr: *mut c_void
 }
@@ -617,7 +1258,10 @@ opencv_type_boxed! { CustomPattern }
 impl Drop for CustomPattern {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CustomPattern_delete(instance: *mut c_void); }
-		unsafe { cv_CustomPattern_delete(self.as_raw_mut_CustomPattern()) };
+		let ptr = self.as_raw_mut_CustomPattern();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_CustomPattern_delete(ptr) };
 	}
 }
 
@@ -643,7 +1287,7 @@ impl CustomPattern {
 	#[inline]
 	pub fn default() -> Result<crate::ccalib::CustomPattern> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccalib_CustomPattern_CustomPattern(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccalib_CustomPattern_CustomPattern(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::ccalib::CustomPattern::opencv_from_extern(ret) };
@@ -676,8 +1320,9 @@ pub trait MultiCameraCalibrationTrait: crate::ccalib::MultiCameraCalibrationTrai
 
 	#[inline]
 	fn load_images(&mut self) -> Result<()> {
+		crate::core::validity::check("MultiCameraCalibration", self.as_raw_mut_MultiCameraCalibration())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_multicalib_MultiCameraCalibration_loadImages(self.as_raw_mut_MultiCameraCalibration(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_multicalib_MultiCameraCalibration_loadImages(self.as_raw_mut_MultiCameraCalibration(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -685,8 +1330,9 @@ pub trait MultiCameraCalibrationTrait: crate::ccalib::MultiCameraCalibrationTrai
 	
 	#[inline]
 	fn initialize(&mut self) -> Result<()> {
+		crate::core::validity::check("MultiCameraCalibration", self.as_raw_mut_MultiCameraCalibration())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_multicalib_MultiCameraCalibration_initialize(self.as_raw_mut_MultiCameraCalibration(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_multicalib_MultiCameraCalibration_initialize(self.as_raw_mut_MultiCameraCalibration(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -694,8 +1340,9 @@ pub trait MultiCameraCalibrationTrait: crate::ccalib::MultiCameraCalibrationTrai
 	
 	#[inline]
 	fn optimize_extrinsics(&mut self) -> Result<f64> {
+		crate::core::validity::check("MultiCameraCalibration", self.as_raw_mut_MultiCameraCalibration())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_multicalib_MultiCameraCalibration_optimizeExtrinsics(self.as_raw_mut_MultiCameraCalibration(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_multicalib_MultiCameraCalibration_optimizeExtrinsics(self.as_raw_mut_MultiCameraCalibration(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -703,8 +1350,9 @@ pub trait MultiCameraCalibrationTrait: crate::ccalib::MultiCameraCalibrationTrai
 	
 	#[inline]
 	fn run(&mut self) -> Result<f64> {
+		crate::core::validity::check("MultiCameraCalibration", self.as_raw_mut_MultiCameraCalibration())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_multicalib_MultiCameraCalibration_run(self.as_raw_mut_MultiCameraCalibration(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_multicalib_MultiCameraCalibration_run(self.as_raw_mut_MultiCameraCalibration(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -712,9 +1360,10 @@ pub trait MultiCameraCalibrationTrait: crate::ccalib::MultiCameraCalibrationTrai
 	
 	#[inline]
 	fn write_parameters(&mut self, filename: &str) -> Result<()> {
+		crate::core::validity::check("MultiCameraCalibration", self.as_raw_mut_MultiCameraCalibration())?;
 		extern_container_arg!(filename);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_multicalib_MultiCameraCalibration_writeParameters_const_stringR(self.as_raw_mut_MultiCameraCalibration(), filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_multicalib_MultiCameraCalibration_writeParameters_const_stringR(self.as_raw_mut_MultiCameraCalibration(), filename.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -743,7 +1392,10 @@ opencv_type_boxed! { MultiCameraCalibration }
 impl Drop for MultiCameraCalibration {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MultiCameraCalibration_delete(instance: *mut c_void); }
-		unsafe { cv_MultiCameraCalibration_delete(self.as_raw_mut_MultiCameraCalibration()) };
+		let ptr = self.as_raw_mut_MultiCameraCalibration();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_MultiCameraCalibration_delete(ptr) };
 	}
 }
 
@@ -771,7 +1423,7 @@ impl MultiCameraCalibration {
 	pub fn new(camera_type: i32, n_cameras: i32, file_name: &str, pattern_width: f32, pattern_height: f32, verbose: i32, show_extration: i32, n_mini_matches: i32, flags: i32, criteria: core::TermCriteria, mut detector: core::Ptr<crate::features2d::Feature2D>, mut descriptor: core::Ptr<crate::features2d::Feature2D>, mut matcher: core::Ptr<dyn crate::features2d::DescriptorMatcher>) -> Result<crate::ccalib::MultiCameraCalibration> {
 		extern_container_arg!(file_name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_multicalib_MultiCameraCalibration_MultiCameraCalibration_int_int_const_stringR_float_float_int_int_int_int_TermCriteria_Ptr_Feature2D__Ptr_Feature2D__Ptr_DescriptorMatcher_(camera_type, n_cameras, file_name.opencv_as_extern(), pattern_width, pattern_height, verbose, show_extration, n_mini_matches, flags, criteria.opencv_as_extern(), detector.as_raw_mut_PtrOfFeature2D(), descriptor.as_raw_mut_PtrOfFeature2D(), matcher.as_raw_mut_PtrOfDescriptorMatcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_multicalib_MultiCameraCalibration_MultiCameraCalibration_int_int_const_stringR_float_float_int_int_int_int_TermCriteria_Ptr_Feature2D__Ptr_Feature2D__Ptr_DescriptorMatcher_(camera_type, n_cameras, file_name.opencv_as_extern(), pattern_width, pattern_height, verbose, show_extration, n_mini_matches, flags, criteria.opencv_as_extern(), detector.as_raw_mut_PtrOfFeature2D(), descriptor.as_raw_mut_PtrOfFeature2D(), matcher.as_raw_mut_PtrOfDescriptorMatcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::ccalib::MultiCameraCalibration::opencv_from_extern(ret) };
@@ -780,34 +1432,130 @@ impl MultiCameraCalibration {
 	
 }
 
+/// Builder for `MultiCameraCalibration::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct MultiCameraCalibration_NewBuilder<'a> {
+	camera_type: i32,
+	n_cameras: i32,
+	file_name: &'a str,
+	pattern_width: f32,
+	pattern_height: f32,
+	verbose: i32,
+	show_extration: i32,
+	n_mini_matches: i32,
+	flags: i32,
+	criteria: core::TermCriteria,
+	detector: core::Ptr<crate::features2d::Feature2D>,
+	descriptor: core::Ptr<crate::features2d::Feature2D>,
+	matcher: core::Ptr<dyn crate::features2d::DescriptorMatcher>,
+}
+
+impl<'a> MultiCameraCalibration_NewBuilder<'a> {
+	#[inline]
+	pub fn new(camera_type: i32, n_cameras: i32, file_name: &'a str, pattern_width: f32, pattern_height: f32, criteria: core::TermCriteria, detector: core::Ptr<crate::features2d::Feature2D>, descriptor: core::Ptr<crate::features2d::Feature2D>, matcher: core::Ptr<dyn crate::features2d::DescriptorMatcher>) -> Self {
+		Self { camera_type, n_cameras, file_name, pattern_width, pattern_height, verbose: 0, show_extration: 0, n_mini_matches: 20, flags: 0, criteria, detector, descriptor, matcher }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_verbose(mut self, verbose: i32) -> Self {
+		self.verbose = verbose;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_show_extration(mut self, show_extration: i32) -> Self {
+		self.show_extration = show_extration;
+		self
+	}
+	
+	/// C++ default: `20`
+	#[inline]
+	pub fn with_n_mini_matches(mut self, n_mini_matches: i32) -> Self {
+		self.n_mini_matches = n_mini_matches;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_flags(mut self, flags: i32) -> Self {
+		self.flags = flags;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::ccalib::MultiCameraCalibration> {
+		MultiCameraCalibration::new(self.camera_type, self.n_cameras, self.file_name, self.pattern_width, self.pattern_height, self.verbose, self.show_extration, self.n_mini_matches, self.flags, self.criteria, self.detector, self.descriptor, self.matcher)
+	}
+}
+
 pub trait MultiCameraCalibration_edgeTraitConst {
 	fn as_raw_MultiCameraCalibration_edge(&self) -> *const c_void;
 
 	#[inline]
 	fn camera_vertex(&self) -> i32 {
-		let ret = unsafe { sys::cv_multicalib_MultiCameraCalibration_edge_getPropCameraVertex_const(self.as_raw_MultiCameraCalibration_edge()) };
+		crate::core::validity::debug_check("MultiCameraCalibration_edge", self.as_raw_MultiCameraCalibration_edge());
+		let ret = unsafe { ffi_call!(cv_multicalib_MultiCameraCalibration_edge_getPropCameraVertex_const(self.as_raw_MultiCameraCalibration_edge())) };
 		ret
 	}
 	
+	/// Non-panicking version of [camera_vertex](MultiCameraCalibration_edgeTraitConst::camera_vertex), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_camera_vertex(&self) -> Result<i32> {
+		crate::core::validity::check("MultiCameraCalibration_edge", self.as_raw_MultiCameraCalibration_edge())?;
+		Ok(MultiCameraCalibration_edgeTraitConst::camera_vertex(self))
+	}
+	
 	#[inline]
 	fn photo_vertex(&self) -> i32 {
-		let ret = unsafe { sys::cv_multicalib_MultiCameraCalibration_edge_getPropPhotoVertex_const(self.as_raw_MultiCameraCalibration_edge()) };
+		crate::core::validity::debug_check("MultiCameraCalibration_edge", self.as_raw_MultiCameraCalibration_edge());
+		let ret = unsafe { ffi_call!(cv_multicalib_MultiCameraCalibration_edge_getPropPhotoVertex_const(self.as_raw_MultiCameraCalibration_edge())) };
 		ret
 	}
 	
+	/// Non-panicking version of [photo_vertex](MultiCameraCalibration_edgeTraitConst::photo_vertex), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_photo_vertex(&self) -> Result<i32> {
+		crate::core::validity::check("MultiCameraCalibration_edge", self.as_raw_MultiCameraCalibration_edge())?;
+		Ok(MultiCameraCalibration_edgeTraitConst::photo_vertex(self))
+	}
+	
 	#[inline]
 	fn photo_index(&self) -> i32 {
-		let ret = unsafe { sys::cv_multicalib_MultiCameraCalibration_edge_getPropPhotoIndex_const(self.as_raw_MultiCameraCalibration_edge()) };
+		crate::core::validity::debug_check("MultiCameraCalibration_edge", self.as_raw_MultiCameraCalibration_edge());
+		let ret = unsafe { ffi_call!(cv_multicalib_MultiCameraCalibration_edge_getPropPhotoIndex_const(self.as_raw_MultiCameraCalibration_edge())) };
 		ret
 	}
 	
+	/// Non-panicking version of [photo_index](MultiCameraCalibration_edgeTraitConst::photo_index), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_photo_index(&self) -> Result<i32> {
+		crate::core::validity::check("MultiCameraCalibration_edge", self.as_raw_MultiCameraCalibration_edge())?;
+		Ok(MultiCameraCalibration_edgeTraitConst::photo_index(self))
+	}
+	
 	#[inline]
 	fn transform(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_multicalib_MultiCameraCalibration_edge_getPropTransform_const(self.as_raw_MultiCameraCalibration_edge()) };
+		crate::core::validity::debug_check("MultiCameraCalibration_edge", self.as_raw_MultiCameraCalibration_edge());
+		let ret = unsafe { ffi_call!(cv_multicalib_MultiCameraCalibration_edge_getPropTransform_const(self.as_raw_MultiCameraCalibration_edge())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [transform](MultiCameraCalibration_edgeTraitConst::transform), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_transform(&self) -> Result<core::Mat> {
+		crate::core::validity::check("MultiCameraCalibration_edge", self.as_raw_MultiCameraCalibration_edge())?;
+		Ok(MultiCameraCalibration_edgeTraitConst::transform(self))
+	}
+	
 }
 
 pub trait MultiCameraCalibration_edgeTrait: crate::ccalib::MultiCameraCalibration_edgeTraitConst {
@@ -815,25 +1563,29 @@ pub trait MultiCameraCalibration_edgeTrait: crate::ccalib::MultiCameraCalibratio
 
 	#[inline]
 	fn set_camera_vertex(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_multicalib_MultiCameraCalibration_edge_setPropCameraVertex_int(self.as_raw_mut_MultiCameraCalibration_edge(), val) };
+		crate::core::validity::debug_check("MultiCameraCalibration_edge", self.as_raw_mut_MultiCameraCalibration_edge());
+		let ret = unsafe { ffi_call!(cv_multicalib_MultiCameraCalibration_edge_setPropCameraVertex_int(self.as_raw_mut_MultiCameraCalibration_edge(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_photo_vertex(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_multicalib_MultiCameraCalibration_edge_setPropPhotoVertex_int(self.as_raw_mut_MultiCameraCalibration_edge(), val) };
+		crate::core::validity::debug_check("MultiCameraCalibration_edge", self.as_raw_mut_MultiCameraCalibration_edge());
+		let ret = unsafe { ffi_call!(cv_multicalib_MultiCameraCalibration_edge_setPropPhotoVertex_int(self.as_raw_mut_MultiCameraCalibration_edge(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_photo_index(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_multicalib_MultiCameraCalibration_edge_setPropPhotoIndex_int(self.as_raw_mut_MultiCameraCalibration_edge(), val) };
+		crate::core::validity::debug_check("MultiCameraCalibration_edge", self.as_raw_mut_MultiCameraCalibration_edge());
+		let ret = unsafe { ffi_call!(cv_multicalib_MultiCameraCalibration_edge_setPropPhotoIndex_int(self.as_raw_mut_MultiCameraCalibration_edge(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_transform(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_multicalib_MultiCameraCalibration_edge_setPropTransform_Mat(self.as_raw_mut_MultiCameraCalibration_edge(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("MultiCameraCalibration_edge", self.as_raw_mut_MultiCameraCalibration_edge());
+		let ret = unsafe { ffi_call!(cv_multicalib_MultiCameraCalibration_edge_setPropTransform_Mat(self.as_raw_mut_MultiCameraCalibration_edge(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
@@ -848,7 +1600,10 @@ opencv_type_boxed! { MultiCameraCalibration_edge }
 impl Drop for MultiCameraCalibration_edge {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MultiCameraCalibration_edge_delete(instance: *mut c_void); }
-		unsafe { cv_MultiCameraCalibration_edge_delete(self.as_raw_mut_MultiCameraCalibration_edge()) };
+		let ptr = self.as_raw_mut_MultiCameraCalibration_edge();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_MultiCameraCalibration_edge_delete(ptr) };
 	}
 }
 
@@ -866,7 +1621,7 @@ impl MultiCameraCalibration_edge {
 	#[inline]
 	pub fn new(cv: i32, pv: i32, pi: i32, mut trans: core::Mat) -> Result<crate::ccalib::MultiCameraCalibration_edge> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_multicalib_MultiCameraCalibration_edge_edge_int_int_int_Mat(cv, pv, pi, trans.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_multicalib_MultiCameraCalibration_edge_edge_int_int_int_Mat(cv, pv, pi, trans.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::ccalib::MultiCameraCalibration_edge::opencv_from_extern(ret) };
@@ -880,17 +1635,35 @@ pub trait MultiCameraCalibration_vertexTraitConst {
 
 	#[inline]
 	fn pose(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_multicalib_MultiCameraCalibration_vertex_getPropPose_const(self.as_raw_MultiCameraCalibration_vertex()) };
+		crate::core::validity::debug_check("MultiCameraCalibration_vertex", self.as_raw_MultiCameraCalibration_vertex());
+		let ret = unsafe { ffi_call!(cv_multicalib_MultiCameraCalibration_vertex_getPropPose_const(self.as_raw_MultiCameraCalibration_vertex())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [pose](MultiCameraCalibration_vertexTraitConst::pose), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pose(&self) -> Result<core::Mat> {
+		crate::core::validity::check("MultiCameraCalibration_vertex", self.as_raw_MultiCameraCalibration_vertex())?;
+		Ok(MultiCameraCalibration_vertexTraitConst::pose(self))
+	}
+	
 	#[inline]
 	fn timestamp(&self) -> i32 {
-		let ret = unsafe { sys::cv_multicalib_MultiCameraCalibration_vertex_getPropTimestamp_const(self.as_raw_MultiCameraCalibration_vertex()) };
+		crate::core::validity::debug_check("MultiCameraCalibration_vertex", self.as_raw_MultiCameraCalibration_vertex());
+		let ret = unsafe { ffi_call!(cv_multicalib_MultiCameraCalibration_vertex_getPropTimestamp_const(self.as_raw_MultiCameraCalibration_vertex())) };
 		ret
 	}
 	
+	/// Non-panicking version of [timestamp](MultiCameraCalibration_vertexTraitConst::timestamp), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_timestamp(&self) -> Result<i32> {
+		crate::core::validity::check("MultiCameraCalibration_vertex", self.as_raw_MultiCameraCalibration_vertex())?;
+		Ok(MultiCameraCalibration_vertexTraitConst::timestamp(self))
+	}
+	
 }
 
 pub trait MultiCameraCalibration_vertexTrait: crate::ccalib::MultiCameraCalibration_vertexTraitConst {
@@ -898,13 +1671,15 @@ pub trait MultiCameraCalibration_vertexTrait: crate::ccalib::MultiCameraCalibrat
 
 	#[inline]
 	fn set_pose(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_multicalib_MultiCameraCalibration_vertex_setPropPose_Mat(self.as_raw_mut_MultiCameraCalibration_vertex(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("MultiCameraCalibration_vertex", self.as_raw_mut_MultiCameraCalibration_vertex());
+		let ret = unsafe { ffi_call!(cv_multicalib_MultiCameraCalibration_vertex_setPropPose_Mat(self.as_raw_mut_MultiCameraCalibration_vertex(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_timestamp(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_multicalib_MultiCameraCalibration_vertex_setPropTimestamp_int(self.as_raw_mut_MultiCameraCalibration_vertex(), val) };
+		crate::core::validity::debug_check("MultiCameraCalibration_vertex", self.as_raw_mut_MultiCameraCalibration_vertex());
+		let ret = unsafe { ffi_call!(cv_multicalib_MultiCameraCalibration_vertex_setPropTimestamp_int(self.as_raw_mut_MultiCameraCalibration_vertex(), val)) };
 		ret
 	}
 	
@@ -919,7 +1694,10 @@ opencv_type_boxed! { MultiCameraCalibration_vertex }
 impl Drop for MultiCameraCalibration_vertex {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MultiCameraCalibration_vertex_delete(instance: *mut c_void); }
-		unsafe { cv_MultiCameraCalibration_vertex_delete(self.as_raw_mut_MultiCameraCalibration_vertex()) };
+		let ptr = self.as_raw_mut_MultiCameraCalibration_vertex();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_MultiCameraCalibration_vertex_delete(ptr) };
 	}
 }
 
@@ -937,7 +1715,7 @@ impl MultiCameraCalibration_vertex {
 	#[inline]
 	pub fn new(mut po: core::Mat, ts: i32) -> Result<crate::ccalib::MultiCameraCalibration_vertex> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_multicalib_MultiCameraCalibration_vertex_vertex_Mat_int(po.as_raw_mut_Mat(), ts, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_multicalib_MultiCameraCalibration_vertex_vertex_Mat_int(po.as_raw_mut_Mat(), ts, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::ccalib::MultiCameraCalibration_vertex::opencv_from_extern(ret) };
@@ -947,7 +1725,7 @@ impl MultiCameraCalibration_vertex {
 	#[inline]
 	pub fn default() -> Result<crate::ccalib::MultiCameraCalibration_vertex> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_multicalib_MultiCameraCalibration_vertex_vertex(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_multicalib_MultiCameraCalibration_vertex_vertex(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::ccalib::MultiCameraCalibration_vertex::opencv_from_extern(ret) };
@@ -975,8 +1753,9 @@ pub trait RandomPatternCornerFinderTrait: crate::ccalib::RandomPatternCornerFind
 
 	#[inline]
 	fn load_pattern(&mut self, pattern_image: &core::Mat) -> Result<()> {
+		crate::core::validity::check("RandomPatternCornerFinder", self.as_raw_mut_RandomPatternCornerFinder())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_randpattern_RandomPatternCornerFinder_loadPattern_const_MatR(self.as_raw_mut_RandomPatternCornerFinder(), pattern_image.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_randpattern_RandomPatternCornerFinder_loadPattern_const_MatR(self.as_raw_mut_RandomPatternCornerFinder(), pattern_image.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -984,8 +1763,9 @@ pub trait RandomPatternCornerFinderTrait: crate::ccalib::RandomPatternCornerFind
 	
 	#[inline]
 	fn load_pattern_1(&mut self, pattern_image: &core::Mat, pattern_key_points: &core::Vector<core::KeyPoint>, pattern_descriptors: &core::Mat) -> Result<()> {
+		crate::core::validity::check("RandomPatternCornerFinder", self.as_raw_mut_RandomPatternCornerFinder())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_randpattern_RandomPatternCornerFinder_loadPattern_const_MatR_const_vector_KeyPoint_R_const_MatR(self.as_raw_mut_RandomPatternCornerFinder(), pattern_image.as_raw_Mat(), pattern_key_points.as_raw_VectorOfKeyPoint(), pattern_descriptors.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_randpattern_RandomPatternCornerFinder_loadPattern_const_MatR_const_vector_KeyPoint_R_const_MatR(self.as_raw_mut_RandomPatternCornerFinder(), pattern_image.as_raw_Mat(), pattern_key_points.as_raw_VectorOfKeyPoint(), pattern_descriptors.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -993,8 +1773,9 @@ pub trait RandomPatternCornerFinderTrait: crate::ccalib::RandomPatternCornerFind
 	
 	#[inline]
 	fn compute_object_image_points(&mut self, mut input_images: core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("RandomPatternCornerFinder", self.as_raw_mut_RandomPatternCornerFinder())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_randpattern_RandomPatternCornerFinder_computeObjectImagePoints_vector_Mat_(self.as_raw_mut_RandomPatternCornerFinder(), input_images.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_randpattern_RandomPatternCornerFinder_computeObjectImagePoints_vector_Mat_(self.as_raw_mut_RandomPatternCornerFinder(), input_images.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1002,8 +1783,9 @@ pub trait RandomPatternCornerFinderTrait: crate::ccalib::RandomPatternCornerFind
 	
 	#[inline]
 	fn compute_object_image_points_for_single(&mut self, mut input_image: core::Mat) -> Result<core::Vector<core::Mat>> {
+		crate::core::validity::check("RandomPatternCornerFinder", self.as_raw_mut_RandomPatternCornerFinder())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_randpattern_RandomPatternCornerFinder_computeObjectImagePointsForSingle_Mat(self.as_raw_mut_RandomPatternCornerFinder(), input_image.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_randpattern_RandomPatternCornerFinder_computeObjectImagePointsForSingle_Mat(self.as_raw_mut_RandomPatternCornerFinder(), input_image.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<core::Mat>::opencv_from_extern(ret) };
@@ -1012,8 +1794,9 @@ pub trait RandomPatternCornerFinderTrait: crate::ccalib::RandomPatternCornerFind
 	
 	#[inline]
 	fn get_object_points(&mut self) -> Result<core::Vector<core::Mat>> {
+		crate::core::validity::check("RandomPatternCornerFinder", self.as_raw_mut_RandomPatternCornerFinder())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_randpattern_RandomPatternCornerFinder_getObjectPoints(self.as_raw_mut_RandomPatternCornerFinder(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_randpattern_RandomPatternCornerFinder_getObjectPoints(self.as_raw_mut_RandomPatternCornerFinder(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<core::Mat>::opencv_from_extern(ret) };
@@ -1022,8 +1805,9 @@ pub trait RandomPatternCornerFinderTrait: crate::ccalib::RandomPatternCornerFind
 	
 	#[inline]
 	fn get_image_points(&mut self) -> Result<core::Vector<core::Mat>> {
+		crate::core::validity::check("RandomPatternCornerFinder", self.as_raw_mut_RandomPatternCornerFinder())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_randpattern_RandomPatternCornerFinder_getImagePoints(self.as_raw_mut_RandomPatternCornerFinder(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_randpattern_RandomPatternCornerFinder_getImagePoints(self.as_raw_mut_RandomPatternCornerFinder(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<core::Mat>::opencv_from_extern(ret) };
@@ -1050,7 +1834,10 @@ opencv_type_boxed! { RandomPatternCornerFinder }
 impl Drop for RandomPatternCornerFinder {
 	fn drop(&mut self) {
 		extern "C" { fn cv_RandomPatternCornerFinder_delete(instance: *mut c_void); }
-		unsafe { cv_RandomPatternCornerFinder_delete(self.as_raw_mut_RandomPatternCornerFinder()) };
+		let ptr = self.as_raw_mut_RandomPatternCornerFinder();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_RandomPatternCornerFinder_delete(ptr) };
 	}
 }
 
@@ -1076,7 +1863,7 @@ impl RandomPatternCornerFinder {
 	#[inline]
 	pub fn new(pattern_width: f32, pattern_height: f32, nmini_match: i32, depth: i32, verbose: i32, show_extraction: i32, mut detector: core::Ptr<crate::features2d::Feature2D>, mut descriptor: core::Ptr<crate::features2d::Feature2D>, mut matcher: core::Ptr<dyn crate::features2d::DescriptorMatcher>) -> Result<crate::ccalib::RandomPatternCornerFinder> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_randpattern_RandomPatternCornerFinder_RandomPatternCornerFinder_float_float_int_int_int_int_Ptr_Feature2D__Ptr_Feature2D__Ptr_DescriptorMatcher_(pattern_width, pattern_height, nmini_match, depth, verbose, show_extraction, detector.as_raw_mut_PtrOfFeature2D(), descriptor.as_raw_mut_PtrOfFeature2D(), matcher.as_raw_mut_PtrOfDescriptorMatcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_randpattern_RandomPatternCornerFinder_RandomPatternCornerFinder_float_float_int_int_int_int_Ptr_Feature2D__Ptr_Feature2D__Ptr_DescriptorMatcher_(pattern_width, pattern_height, nmini_match, depth, verbose, show_extraction, detector.as_raw_mut_PtrOfFeature2D(), descriptor.as_raw_mut_PtrOfFeature2D(), matcher.as_raw_mut_PtrOfDescriptorMatcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::ccalib::RandomPatternCornerFinder::opencv_from_extern(ret) };
@@ -1085,6 +1872,55 @@ impl RandomPatternCornerFinder {
 	
 }
 
+/// Builder for `RandomPatternCornerFinder::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct RandomPatternCornerFinder_NewBuilder {
+	pattern_width: f32,
+	pattern_height: f32,
+	nmini_match: i32,
+	depth: i32,
+	verbose: i32,
+	show_extraction: i32,
+	detector: core::Ptr<crate::features2d::Feature2D>,
+	descriptor: core::Ptr<crate::features2d::Feature2D>,
+	matcher: core::Ptr<dyn crate::features2d::DescriptorMatcher>,
+}
+
+impl RandomPatternCornerFinder_NewBuilder {
+	#[inline]
+	pub fn new(pattern_width: f32, pattern_height: f32, depth: i32, detector: core::Ptr<crate::features2d::Feature2D>, descriptor: core::Ptr<crate::features2d::Feature2D>, matcher: core::Ptr<dyn crate::features2d::DescriptorMatcher>) -> Self {
+		Self { pattern_width, pattern_height, nmini_match: 20, depth, verbose: 0, show_extraction: 0, detector, descriptor, matcher }
+	}
+	
+	/// C++ default: `20`
+	#[inline]
+	pub fn with_nmini_match(mut self, nmini_match: i32) -> Self {
+		self.nmini_match = nmini_match;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_verbose(mut self, verbose: i32) -> Self {
+		self.verbose = verbose;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_show_extraction(mut self, show_extraction: i32) -> Self {
+		self.show_extraction = show_extraction;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::ccalib::RandomPatternCornerFinder> {
+		RandomPatternCornerFinder::new(self.pattern_width, self.pattern_height, self.nmini_match, self.depth, self.verbose, self.show_extraction, self.detector, self.descriptor, self.matcher)
+	}
+}
+
 pub trait RandomPatternGeneratorTraitConst {
 	fn as_raw_RandomPatternGenerator(&self) -> *const c_void;
 
@@ -1095,8 +1931,9 @@ pub trait RandomPatternGeneratorTrait: crate::ccalib::RandomPatternGeneratorTrai
 
 	#[inline]
 	fn generate_pattern(&mut self) -> Result<()> {
+		crate::core::validity::check("RandomPatternGenerator", self.as_raw_mut_RandomPatternGenerator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_randpattern_RandomPatternGenerator_generatePattern(self.as_raw_mut_RandomPatternGenerator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_randpattern_RandomPatternGenerator_generatePattern(self.as_raw_mut_RandomPatternGenerator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1104,8 +1941,9 @@ pub trait RandomPatternGeneratorTrait: crate::ccalib::RandomPatternGeneratorTrai
 	
 	#[inline]
 	fn get_pattern(&mut self) -> Result<core::Mat> {
+		crate::core::validity::check("RandomPatternGenerator", self.as_raw_mut_RandomPatternGenerator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_randpattern_RandomPatternGenerator_getPattern(self.as_raw_mut_RandomPatternGenerator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_randpattern_RandomPatternGenerator_getPattern(self.as_raw_mut_RandomPatternGenerator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -1123,7 +1961,10 @@ opencv_type_boxed! { RandomPatternGenerator }
 impl Drop for RandomPatternGenerator {
 	fn drop(&mut self) {
 		extern "C" { fn cv_RandomPatternGenerator_delete(instance: *mut c_void); }
-		unsafe { cv_RandomPatternGenerator_delete(self.as_raw_mut_RandomPatternGenerator()) };
+		let ptr = self.as_raw_mut_RandomPatternGenerator();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_RandomPatternGenerator_delete(ptr) };
 	}
 }
 
@@ -1141,7 +1982,7 @@ impl RandomPatternGenerator {
 	#[inline]
 	pub fn new(image_width: i32, image_height: i32) -> Result<crate::ccalib::RandomPatternGenerator> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_randpattern_RandomPatternGenerator_RandomPatternGenerator_int_int(image_width, image_height, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_randpattern_RandomPatternGenerator_RandomPatternGenerator_int_int(image_width, image_height, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::ccalib::RandomPatternGenerator::opencv_from_extern(ret) };
@@ -1149,3 +1990,4 @@ impl RandomPatternGenerator {
 	}
 	
 }
+pub use crate::manual::ccalib::*;
@@ -30,7 +30,7 @@ pub fn abs_sum(src: &dyn core::ToInputArray, mask: &dyn core::ToInputArray) -> R
 	input_array_arg!(src);
 	input_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_absSum_const__InputArrayR_const__InputArrayR(src.as_raw__InputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_absSum_const__InputArrayR_const__InputArrayR(src.as_raw__InputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -43,7 +43,7 @@ pub fn abs_sum(src: &dyn core::ToInputArray, mask: &dyn core::ToInputArray) -> R
 /// * dst: Destination matrix with the same size and type as src .
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// abs
+/// [abs](crate::cudaarithm::abs)
 /// 
 /// ## C++ default parameters
 /// * stream: Stream::Null()
@@ -52,7 +52,7 @@ pub fn abs(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, stre
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_abs_const__InputArrayR_const__OutputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_abs_const__InputArrayR_const__OutputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -66,7 +66,7 @@ pub fn abs(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, stre
 /// * dst: Destination matrix that has the same size and type as the input array(s).
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// absdiff
+/// [absdiff](crate::cudaarithm::absdiff)
 /// 
 /// ## C++ default parameters
 /// * stream: Stream::Null()
@@ -76,7 +76,7 @@ pub fn absdiff(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst
 	input_array_arg!(src2);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_absdiff_const__InputArrayR_const__InputArrayR_const__OutputArrayR_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_absdiff_const__InputArrayR_const__InputArrayR_const__OutputArrayR_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -102,7 +102,7 @@ pub fn absdiff(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst
 /// where I is a multi-dimensional index of array elements. In case of multi-channel arrays, each
 /// channel is processed independently.
 /// ## See also
-/// addWeighted
+/// [add_weighted](crate::cudaarithm::add_weighted)
 /// 
 /// ## C++ default parameters
 /// * dtype: -1
@@ -113,12 +113,46 @@ pub fn add_weighted(src1: &dyn core::ToInputArray, alpha: f64, src2: &dyn core::
 	input_array_arg!(src2);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_addWeighted_const__InputArrayR_double_const__InputArrayR_double_double_const__OutputArrayR_int_StreamR(src1.as_raw__InputArray(), alpha, src2.as_raw__InputArray(), beta, gamma, dst.as_raw__OutputArray(), dtype, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_addWeighted_const__InputArrayR_double_const__InputArrayR_double_double_const__OutputArrayR_int_StreamR(src1.as_raw__InputArray(), alpha, src2.as_raw__InputArray(), beta, gamma, dst.as_raw__OutputArray(), dtype, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `add_weighted()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct AddWeightedBuilder<'a> {
+	src1: &'a dyn core::ToInputArray,
+	alpha: f64,
+	src2: &'a dyn core::ToInputArray,
+	beta: f64,
+	gamma: f64,
+	dst: &'a mut dyn core::ToOutputArray,
+	dtype: i32,
+	stream: &'a mut core::Stream,
+}
+
+impl<'a> AddWeightedBuilder<'a> {
+	#[inline]
+	pub fn new(src1: &'a dyn core::ToInputArray, alpha: f64, src2: &'a dyn core::ToInputArray, beta: f64, gamma: f64, dst: &'a mut dyn core::ToOutputArray, stream: &'a mut core::Stream) -> Self {
+		Self { src1, alpha, src2, beta, gamma, dst, dtype: -1, stream }
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_dtype(mut self, dtype: i32) -> Self {
+		self.dtype = dtype;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		add_weighted(self.src1, self.alpha, self.src2, self.beta, self.gamma, self.dst, self.dtype, self.stream)
+	}
+}
+
 /// Computes a matrix-matrix or matrix-scalar sum.
 /// 
 /// ## Parameters
@@ -131,7 +165,7 @@ pub fn add_weighted(src1: &dyn core::ToInputArray, alpha: f64, src2: &dyn core::
 /// * dtype: Optional depth of the output array.
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// add
+/// [add](crate::cudaarithm::add)
 /// 
 /// ## C++ default parameters
 /// * mask: noArray()
@@ -144,12 +178,53 @@ pub fn add(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &m
 	output_array_arg!(dst);
 	input_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_add_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR_int_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), dtype, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_add_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR_int_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), dtype, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `add()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct AddBuilder<'a> {
+	src1: &'a dyn core::ToInputArray,
+	src2: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	mask: Option<&'a dyn core::ToInputArray>,
+	dtype: i32,
+	stream: &'a mut core::Stream,
+}
+
+impl<'a> AddBuilder<'a> {
+	#[inline]
+	pub fn new(src1: &'a dyn core::ToInputArray, src2: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, stream: &'a mut core::Stream) -> Self {
+		Self { src1, src2, dst, mask: None, dtype: -1, stream }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_mask(mut self, mask: &'a dyn core::ToInputArray) -> Self {
+		self.mask = Some(mask);
+		self
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_dtype(mut self, dtype: i32) -> Self {
+		self.dtype = dtype;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let mask_no_array = core::no_array();
+		let mask: &dyn core::ToInputArray = match self.mask { Some(mask) => mask, None => &mask_no_array };
+		add(self.src1, self.src2, self.dst, mask, self.dtype, self.stream)
+	}
+}
+
 /// Performs a per-element bitwise conjunction of two matrices (or of matrix and scalar).
 /// 
 /// ## Parameters
@@ -170,7 +245,7 @@ pub fn bitwise_and(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray,
 	output_array_arg!(dst);
 	input_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_bitwise_and_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_bitwise_and_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -194,7 +269,7 @@ pub fn bitwise_not(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArr
 	output_array_arg!(dst);
 	input_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_bitwise_not_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_bitwise_not_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -220,7 +295,7 @@ pub fn bitwise_or(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray,
 	output_array_arg!(dst);
 	input_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_bitwise_or_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_bitwise_or_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -246,7 +321,7 @@ pub fn bitwise_xor(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray,
 	output_array_arg!(dst);
 	input_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_bitwise_xor_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_bitwise_xor_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -261,7 +336,7 @@ pub fn calc_abs_sum(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputAr
 	output_array_arg!(dst);
 	input_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_calcAbsSum_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_calcAbsSum_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -276,7 +351,7 @@ pub fn calc_norm_diff(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArr
 	input_array_arg!(src2);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_calcNormDiff_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), norm_type, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_calcNormDiff_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), norm_type, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -291,7 +366,7 @@ pub fn calc_norm(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 	output_array_arg!(dst);
 	input_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_calcNorm_const__InputArrayR_const__OutputArrayR_int_const__InputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), norm_type, mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_calcNorm_const__InputArrayR_const__OutputArrayR_int_const__InputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), norm_type, mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -306,7 +381,7 @@ pub fn calc_sqr_sum(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputAr
 	output_array_arg!(dst);
 	input_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_calcSqrSum_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_calcSqrSum_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -321,7 +396,7 @@ pub fn calc_sum(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray,
 	output_array_arg!(dst);
 	input_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_calcSum_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_calcSum_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -337,7 +412,7 @@ pub fn calc_sum(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray,
 /// * angleInDegrees: Flag for angles that must be evaluated in degrees.
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// cartToPolar
+/// [cart_to_polar](crate::cudaarithm::cart_to_polar)
 /// 
 /// ## C++ default parameters
 /// * angle_in_degrees: false
@@ -349,12 +424,44 @@ pub fn cart_to_polar(x: &dyn core::ToInputArray, y: &dyn core::ToInputArray, mag
 	output_array_arg!(magnitude);
 	output_array_arg!(angle);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_cartToPolar_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_bool_StreamR(x.as_raw__InputArray(), y.as_raw__InputArray(), magnitude.as_raw__OutputArray(), angle.as_raw__OutputArray(), angle_in_degrees, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_cartToPolar_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_bool_StreamR(x.as_raw__InputArray(), y.as_raw__InputArray(), magnitude.as_raw__OutputArray(), angle.as_raw__OutputArray(), angle_in_degrees, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `cart_to_polar()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CartToPolarBuilder<'a> {
+	x: &'a dyn core::ToInputArray,
+	y: &'a dyn core::ToInputArray,
+	magnitude: &'a mut dyn core::ToOutputArray,
+	angle: &'a mut dyn core::ToOutputArray,
+	angle_in_degrees: bool,
+	stream: &'a mut core::Stream,
+}
+
+impl<'a> CartToPolarBuilder<'a> {
+	#[inline]
+	pub fn new(x: &'a dyn core::ToInputArray, y: &'a dyn core::ToInputArray, magnitude: &'a mut dyn core::ToOutputArray, angle: &'a mut dyn core::ToOutputArray, stream: &'a mut core::Stream) -> Self {
+		Self { x, y, magnitude, angle, angle_in_degrees: false, stream }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_angle_in_degrees(mut self, angle_in_degrees: bool) -> Self {
+		self.angle_in_degrees = angle_in_degrees;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		cart_to_polar(self.x, self.y, self.magnitude, self.angle, self.angle_in_degrees, self.stream)
+	}
+}
+
 /// Compares elements of two matrices (or of a matrix and scalar).
 /// 
 /// ## Parameters
@@ -370,7 +477,7 @@ pub fn cart_to_polar(x: &dyn core::ToInputArray, y: &dyn core::ToInputArray, mag
 /// *   **CMP_NE:** a(.) != b(.)
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// compare
+/// [compare](crate::cudaarithm::compare)
 /// 
 /// ## C++ default parameters
 /// * stream: Stream::Null()
@@ -380,7 +487,7 @@ pub fn compare(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst
 	input_array_arg!(src2);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_compare_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), cmpop, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_compare_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), cmpop, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -410,12 +517,47 @@ pub fn copy_make_border(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutp
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_copyMakeBorder_const__InputArrayR_const__OutputArrayR_int_int_int_int_int_Scalar_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), top, bottom, left, right, border_type, value.opencv_as_extern(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_copyMakeBorder_const__InputArrayR_const__OutputArrayR_int_int_int_int_int_Scalar_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), top, bottom, left, right, border_type, value.opencv_as_extern(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `copy_make_border()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CopyMakeBorderBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	top: i32,
+	bottom: i32,
+	left: i32,
+	right: i32,
+	border_type: i32,
+	value: core::Scalar,
+	stream: &'a mut core::Stream,
+}
+
+impl<'a> CopyMakeBorderBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, top: i32, bottom: i32, left: i32, right: i32, border_type: i32, stream: &'a mut core::Stream) -> Self {
+		Self { src, dst, top, bottom, left, right, border_type, value: core::Scalar::default(), stream }
+	}
+	
+	/// C++ default: `Scalar()`
+	#[inline]
+	pub fn with_value(mut self, value: core::Scalar) -> Self {
+		self.value = value;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		copy_make_border(self.src, self.dst, self.top, self.bottom, self.left, self.right, self.border_type, self.value, self.stream)
+	}
+}
+
 /// Counts non-zero matrix elements.
 /// 
 /// ## Parameters
@@ -423,12 +565,12 @@ pub fn copy_make_border(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutp
 /// 
 /// The function does not work with CV_64F images on GPUs with the compute capability \< 1.3.
 /// ## See also
-/// countNonZero
+/// [count_non_zero](crate::cudaarithm::count_non_zero)
 #[inline]
 pub fn count_non_zero(src: &dyn core::ToInputArray) -> Result<i32> {
 	input_array_arg!(src);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_countNonZero_const__InputArrayR(src.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_countNonZero_const__InputArrayR(src.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -441,7 +583,7 @@ pub fn count_non_zero(src: &dyn core::ToInputArray) -> Result<i32> {
 /// 
 /// The function does not work with CV_64F images on GPUs with the compute capability \< 1.3.
 /// ## See also
-/// countNonZero
+/// [count_non_zero](crate::cudaarithm::count_non_zero)
 /// 
 /// ## Overloaded parameters
 /// 
@@ -452,7 +594,7 @@ pub fn count_non_zero_1(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutp
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_countNonZero_const__InputArrayR_const__OutputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_countNonZero_const__InputArrayR_const__OutputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -470,7 +612,7 @@ pub fn count_non_zero_1(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutp
 #[inline]
 pub fn create_convolution(user_block_size: core::Size) -> Result<core::Ptr<dyn crate::cudaarithm::Convolution>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createConvolution_Size(user_block_size.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createConvolution_Size(user_block_size.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudaarithm::Convolution>::opencv_from_extern(ret) };
@@ -493,7 +635,7 @@ pub fn create_convolution(user_block_size: core::Size) -> Result<core::Ptr<dyn c
 #[inline]
 pub fn create_dft(dft_size: core::Size, flags: i32) -> Result<core::Ptr<dyn crate::cudaarithm::DFT>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createDFT_Size_int(dft_size.opencv_as_extern(), flags, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createDFT_Size_int(dft_size.opencv_as_extern(), flags, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudaarithm::DFT>::opencv_from_extern(ret) };
@@ -508,7 +650,7 @@ pub fn create_dft(dft_size: core::Size, flags: i32) -> Result<core::Ptr<dyn crat
 pub fn create_look_up_table(lut: &dyn core::ToInputArray) -> Result<core::Ptr<dyn crate::cudaarithm::LookUpTable>> {
 	input_array_arg!(lut);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createLookUpTable_const__InputArrayR(lut.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createLookUpTable_const__InputArrayR(lut.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudaarithm::LookUpTable>::opencv_from_extern(ret) };
@@ -549,7 +691,7 @@ pub fn create_look_up_table(lut: &dyn core::ToInputArray) -> Result<core::Ptr<dy
 /// is dft_size.width / 2 + 1 . But if the source is a single column, the height is reduced
 /// instead of the width.
 /// ## See also
-/// dft
+/// [dft](crate::cudaarithm::dft)
 /// 
 /// ## C++ default parameters
 /// * flags: 0
@@ -559,7 +701,7 @@ pub fn dft(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, dft_
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_dft_const__InputArrayR_const__OutputArrayR_Size_int_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), dft_size.opencv_as_extern(), flags, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_dft_const__InputArrayR_const__OutputArrayR_Size_int_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), dft_size.opencv_as_extern(), flags, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -578,7 +720,7 @@ pub fn dft(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, dft_
 /// 
 /// This function, in contrast to divide, uses a round-down rounding mode.
 /// ## See also
-/// divide
+/// [divide](crate::cudaarithm::divide)
 /// 
 /// ## C++ default parameters
 /// * scale: 1
@@ -590,12 +732,51 @@ pub fn divide(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst:
 	input_array_arg!(src2);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_divide_const__InputArrayR_const__InputArrayR_const__OutputArrayR_double_int_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), scale, dtype, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_divide_const__InputArrayR_const__InputArrayR_const__OutputArrayR_double_int_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), scale, dtype, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `divide()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct DivideBuilder<'a> {
+	src1: &'a dyn core::ToInputArray,
+	src2: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	scale: f64,
+	dtype: i32,
+	stream: &'a mut core::Stream,
+}
+
+impl<'a> DivideBuilder<'a> {
+	#[inline]
+	pub fn new(src1: &'a dyn core::ToInputArray, src2: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, stream: &'a mut core::Stream) -> Self {
+		Self { src1, src2, dst, scale: 1., dtype: -1, stream }
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_scale(mut self, scale: f64) -> Self {
+		self.scale = scale;
+		self
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_dtype(mut self, dtype: i32) -> Self {
+		self.dtype = dtype;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		divide(self.src1, self.src2, self.dst, self.scale, self.dtype, self.stream)
+	}
+}
+
 /// Computes an exponent of each matrix element.
 /// 
 /// ## Parameters
@@ -603,7 +784,7 @@ pub fn divide(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst:
 /// * dst: Destination matrix with the same size and type as src .
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// exp
+/// [exp](crate::cudaarithm::exp)
 /// 
 /// ## C++ default parameters
 /// * stream: Stream::Null()
@@ -612,7 +793,7 @@ pub fn exp(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, stre
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_exp_const__InputArrayR_const__OutputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_exp_const__InputArrayR_const__OutputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -628,7 +809,7 @@ pub fn find_min_max_loc(src: &dyn core::ToInputArray, min_max_vals: &mut dyn cor
 	output_array_arg!(loc);
 	input_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_findMinMaxLoc_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(src.as_raw__InputArray(), min_max_vals.as_raw__OutputArray(), loc.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_findMinMaxLoc_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(src.as_raw__InputArray(), min_max_vals.as_raw__OutputArray(), loc.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -643,7 +824,7 @@ pub fn find_min_max(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputAr
 	output_array_arg!(dst);
 	input_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_findMinMax_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_findMinMax_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -661,7 +842,7 @@ pub fn find_min_max(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputAr
 /// *   \< 0 Flips around both axes.
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// flip
+/// [flip](crate::cudaarithm::flip)
 /// 
 /// ## C++ default parameters
 /// * stream: Stream::Null()
@@ -670,7 +851,7 @@ pub fn flip(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, fli
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_flip_const__InputArrayR_const__OutputArrayR_int_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), flip_code, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_flip_const__InputArrayR_const__OutputArrayR_int_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), flip_code, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -701,7 +882,7 @@ pub fn flip(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, fli
 /// 
 /// Note: Transposition operation doesn't support CV_64FC2 input type.
 /// ## See also
-/// gemm
+/// [gemm](crate::cudaarithm::gemm)
 /// 
 /// ## C++ default parameters
 /// * flags: 0
@@ -713,12 +894,46 @@ pub fn gemm(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, alpha:
 	input_array_arg!(src3);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_gemm_const__InputArrayR_const__InputArrayR_double_const__InputArrayR_double_const__OutputArrayR_int_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), alpha, src3.as_raw__InputArray(), beta, dst.as_raw__OutputArray(), flags, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_gemm_const__InputArrayR_const__InputArrayR_double_const__InputArrayR_double_const__OutputArrayR_int_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), alpha, src3.as_raw__InputArray(), beta, dst.as_raw__OutputArray(), flags, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `gemm()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct GemmBuilder<'a> {
+	src1: &'a dyn core::ToInputArray,
+	src2: &'a dyn core::ToInputArray,
+	alpha: f64,
+	src3: &'a dyn core::ToInputArray,
+	beta: f64,
+	dst: &'a mut dyn core::ToOutputArray,
+	flags: i32,
+	stream: &'a mut core::Stream,
+}
+
+impl<'a> GemmBuilder<'a> {
+	#[inline]
+	pub fn new(src1: &'a dyn core::ToInputArray, src2: &'a dyn core::ToInputArray, alpha: f64, src3: &'a dyn core::ToInputArray, beta: f64, dst: &'a mut dyn core::ToOutputArray, stream: &'a mut core::Stream) -> Self {
+		Self { src1, src2, alpha, src3, beta, dst, flags: 0, stream }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_flags(mut self, flags: i32) -> Self {
+		self.flags = flags;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		gemm(self.src1, self.src2, self.alpha, self.src3, self.beta, self.dst, self.flags, self.stream)
+	}
+}
+
 ///  Checks if array elements lie between two scalars.
 /// 
 /// The function checks the range as follows:
@@ -741,7 +956,7 @@ pub fn gemm(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, alpha:
 /// * dst: output array of the same size as src and CV_8U type.
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// cv::inRange
+/// [in_range](crate::cudaarithm::in_range)
 /// 
 /// ## C++ default parameters
 /// * stream: Stream::Null()
@@ -750,7 +965,7 @@ pub fn in_range(src: &dyn core::ToInputArray, lowerb: core::Scalar, upperb: core
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_inRange_const__InputArrayR_const_ScalarR_const_ScalarR_const__OutputArrayR_StreamR(src.as_raw__InputArray(), &lowerb, &upperb, dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_inRange_const__InputArrayR_const_ScalarR_const_ScalarR_const__OutputArrayR_StreamR(src.as_raw__InputArray(), &lowerb, &upperb, dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -763,7 +978,7 @@ pub fn in_range(src: &dyn core::ToInputArray, lowerb: core::Scalar, upperb: core
 /// * sum: Integral image containing 32-bit unsigned integer values packed into CV_32SC1 .
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// integral
+/// [integral](crate::cudaarithm::integral)
 /// 
 /// ## C++ default parameters
 /// * stream: Stream::Null()
@@ -772,7 +987,7 @@ pub fn integral(src: &dyn core::ToInputArray, sum: &mut dyn core::ToOutputArray,
 	input_array_arg!(src);
 	output_array_arg!(sum);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_integral_const__InputArrayR_const__OutputArrayR_StreamR(src.as_raw__InputArray(), sum.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_integral_const__InputArrayR_const__OutputArrayR_StreamR(src.as_raw__InputArray(), sum.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -785,7 +1000,7 @@ pub fn integral(src: &dyn core::ToInputArray, sum: &mut dyn core::ToOutputArray,
 /// * dst: Destination matrix with the same size and type as src .
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// log
+/// [log](crate::cudaarithm::log)
 /// 
 /// ## C++ default parameters
 /// * stream: Stream::Null()
@@ -794,7 +1009,7 @@ pub fn log(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, stre
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_log_const__InputArrayR_const__OutputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_log_const__InputArrayR_const__OutputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -816,7 +1031,7 @@ pub fn lshift(src: &dyn core::ToInputArray, val: core::Scalar_<i32>, dst: &mut d
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_lshift_const__InputArrayR_Scalar__int__const__OutputArrayR_StreamR(src.as_raw__InputArray(), val.opencv_as_extern(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_lshift_const__InputArrayR_Scalar__int__const__OutputArrayR_StreamR(src.as_raw__InputArray(), val.opencv_as_extern(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -829,7 +1044,7 @@ pub fn lshift_1(src: &dyn core::ToInputArray, val: core::Scalar, dst: &mut dyn c
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_lshift_const__InputArrayR_Scalar_const__OutputArrayR_StreamR(src.as_raw__InputArray(), val.opencv_as_extern(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_lshift_const__InputArrayR_Scalar_const__OutputArrayR_StreamR(src.as_raw__InputArray(), val.opencv_as_extern(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -859,7 +1074,7 @@ pub fn magnitude_sqr_1(x: &dyn core::ToInputArray, y: &dyn core::ToInputArray, m
 	input_array_arg!(y);
 	output_array_arg!(magnitude);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_magnitudeSqr_const__InputArrayR_const__InputArrayR_const__OutputArrayR_StreamR(x.as_raw__InputArray(), y.as_raw__InputArray(), magnitude.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_magnitudeSqr_const__InputArrayR_const__InputArrayR_const__OutputArrayR_StreamR(x.as_raw__InputArray(), y.as_raw__InputArray(), magnitude.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -879,7 +1094,7 @@ pub fn magnitude_sqr(xy: &dyn core::ToInputArray, magnitude: &mut dyn core::ToOu
 	input_array_arg!(xy);
 	output_array_arg!(magnitude);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_magnitudeSqr_const__InputArrayR_const__OutputArrayR_StreamR(xy.as_raw__InputArray(), magnitude.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_magnitudeSqr_const__InputArrayR_const__OutputArrayR_StreamR(xy.as_raw__InputArray(), magnitude.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -892,7 +1107,7 @@ pub fn magnitude_sqr(xy: &dyn core::ToInputArray, magnitude: &mut dyn core::ToOu
 /// * magnitude: Destination matrix of float magnitudes ( CV_32FC1 ).
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// magnitude
+/// [magnitude](crate::cudaarithm::magnitude)
 /// 
 /// ## Overloaded parameters
 /// 
@@ -911,7 +1126,7 @@ pub fn magnitude_1(x: &dyn core::ToInputArray, y: &dyn core::ToInputArray, magni
 	input_array_arg!(y);
 	output_array_arg!(magnitude);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_magnitude_const__InputArrayR_const__InputArrayR_const__OutputArrayR_StreamR(x.as_raw__InputArray(), y.as_raw__InputArray(), magnitude.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_magnitude_const__InputArrayR_const__InputArrayR_const__OutputArrayR_StreamR(x.as_raw__InputArray(), y.as_raw__InputArray(), magnitude.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -924,7 +1139,7 @@ pub fn magnitude_1(x: &dyn core::ToInputArray, y: &dyn core::ToInputArray, magni
 /// * magnitude: Destination matrix of float magnitudes ( CV_32FC1 ).
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// magnitude
+/// [magnitude](crate::cudaarithm::magnitude)
 /// 
 /// ## C++ default parameters
 /// * stream: Stream::Null()
@@ -933,7 +1148,7 @@ pub fn magnitude(xy: &dyn core::ToInputArray, magnitude: &mut dyn core::ToOutput
 	input_array_arg!(xy);
 	output_array_arg!(magnitude);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_magnitude_const__InputArrayR_const__OutputArrayR_StreamR(xy.as_raw__InputArray(), magnitude.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_magnitude_const__InputArrayR_const__OutputArrayR_StreamR(xy.as_raw__InputArray(), magnitude.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -947,7 +1162,7 @@ pub fn magnitude(xy: &dyn core::ToInputArray, magnitude: &mut dyn core::ToOutput
 /// * dst: Destination matrix that has the same size and type as the input array(s).
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// max
+/// [max](crate::cudaarithm::max)
 /// 
 /// ## C++ default parameters
 /// * stream: Stream::Null()
@@ -957,7 +1172,7 @@ pub fn max(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &m
 	input_array_arg!(src2);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_max_const__InputArrayR_const__InputArrayR_const__OutputArrayR_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_max_const__InputArrayR_const__InputArrayR_const__OutputArrayR_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -970,12 +1185,12 @@ pub fn max(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &m
 /// * mean: Mean value.
 /// * stddev: Standard deviation value.
 /// ## See also
-/// meanStdDev
+/// [mean_std_dev](crate::cudaarithm::mean_std_dev)
 #[inline]
 pub fn mean_std_dev(mtx: &dyn core::ToInputArray, mean: &mut core::Scalar, stddev: &mut core::Scalar) -> Result<()> {
 	input_array_arg!(mtx);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_meanStdDev_const__InputArrayR_ScalarR_ScalarR(mtx.as_raw__InputArray(), mean, stddev, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_meanStdDev_const__InputArrayR_ScalarR_ScalarR(mtx.as_raw__InputArray(), mean, stddev, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -988,7 +1203,7 @@ pub fn mean_std_dev(mtx: &dyn core::ToInputArray, mean: &mut core::Scalar, stdde
 /// * mean: Mean value.
 /// * stddev: Standard deviation value.
 /// ## See also
-/// meanStdDev
+/// [mean_std_dev](crate::cudaarithm::mean_std_dev)
 /// 
 /// ## Overloaded parameters
 /// 
@@ -999,7 +1214,7 @@ pub fn mean_std_dev_1(mtx: &dyn core::ToInputArray, dst: &mut dyn core::ToOutput
 	input_array_arg!(mtx);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_meanStdDev_const__InputArrayR_const__OutputArrayR_StreamR(mtx.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_meanStdDev_const__InputArrayR_const__OutputArrayR_StreamR(mtx.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1013,7 +1228,7 @@ pub fn mean_std_dev_1(mtx: &dyn core::ToInputArray, dst: &mut dyn core::ToOutput
 /// * dst: Destination matrix.
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// merge
+/// [merge](crate::cudaarithm::merge)
 /// 
 /// ## C++ default parameters
 /// * stream: Stream::Null()
@@ -1021,7 +1236,7 @@ pub fn mean_std_dev_1(mtx: &dyn core::ToInputArray, dst: &mut dyn core::ToOutput
 pub fn merge(src: &core::GpuMat, n: size_t, dst: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_merge_const_GpuMatX_size_t_const__OutputArrayR_StreamR(src.as_raw_GpuMat(), n, dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_merge_const_GpuMatX_size_t_const__OutputArrayR_StreamR(src.as_raw_GpuMat(), n, dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1035,7 +1250,7 @@ pub fn merge(src: &core::GpuMat, n: size_t, dst: &mut dyn core::ToOutputArray, s
 /// * dst: Destination matrix.
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// merge
+/// [merge](crate::cudaarithm::merge)
 /// 
 /// ## Overloaded parameters
 /// 
@@ -1045,7 +1260,7 @@ pub fn merge(src: &core::GpuMat, n: size_t, dst: &mut dyn core::ToOutputArray, s
 pub fn merge_1(src: &core::Vector<core::GpuMat>, dst: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_merge_const_vector_GpuMat_R_const__OutputArrayR_StreamR(src.as_raw_VectorOfGpuMat(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_merge_const_vector_GpuMat_R_const__OutputArrayR_StreamR(src.as_raw_VectorOfGpuMat(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1063,7 +1278,7 @@ pub fn merge_1(src: &core::Vector<core::GpuMat>, dst: &mut dyn core::ToOutputArr
 /// 
 /// The function does not work with CV_64F images on GPU with the compute capability \< 1.3.
 /// ## See also
-/// minMaxLoc
+/// [min_max_loc](crate::cudaarithm::min_max_loc)
 /// 
 /// ## C++ default parameters
 /// * mask: noArray()
@@ -1072,12 +1287,46 @@ pub fn min_max_loc(src: &dyn core::ToInputArray, min_val: &mut f64, max_val: &mu
 	input_array_arg!(src);
 	input_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_minMaxLoc_const__InputArrayR_doubleX_doubleX_PointX_PointX_const__InputArrayR(src.as_raw__InputArray(), min_val, max_val, min_loc, max_loc, mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_minMaxLoc_const__InputArrayR_doubleX_doubleX_PointX_PointX_const__InputArrayR(src.as_raw__InputArray(), min_val, max_val, min_loc, max_loc, mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `min_max_loc()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct MinMaxLocBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	min_val: &'a mut f64,
+	max_val: &'a mut f64,
+	min_loc: &'a mut core::Point,
+	max_loc: &'a mut core::Point,
+	mask: Option<&'a dyn core::ToInputArray>,
+}
+
+impl<'a> MinMaxLocBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, min_val: &'a mut f64, max_val: &'a mut f64, min_loc: &'a mut core::Point, max_loc: &'a mut core::Point) -> Self {
+		Self { src, min_val, max_val, min_loc, max_loc, mask: None }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_mask(mut self, mask: &'a dyn core::ToInputArray) -> Self {
+		self.mask = Some(mask);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let mask_no_array = core::no_array();
+		let mask: &dyn core::ToInputArray = match self.mask { Some(mask) => mask, None => &mask_no_array };
+		min_max_loc(self.src, self.min_val, self.max_val, self.min_loc, self.max_loc, mask)
+	}
+}
+
 /// Finds global minimum and maximum matrix elements and returns their values.
 /// 
 /// ## Parameters
@@ -1088,7 +1337,7 @@ pub fn min_max_loc(src: &dyn core::ToInputArray, min_val: &mut f64, max_val: &mu
 /// 
 /// The function does not work with CV_64F images on GPUs with the compute capability \< 1.3.
 /// ## See also
-/// minMaxLoc
+/// [min_max_loc](crate::cudaarithm::min_max_loc)
 /// 
 /// ## C++ default parameters
 /// * mask: noArray()
@@ -1097,7 +1346,7 @@ pub fn min_max(src: &dyn core::ToInputArray, min_val: &mut f64, max_val: &mut f6
 	input_array_arg!(src);
 	input_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_minMax_const__InputArrayR_doubleX_doubleX_const__InputArrayR(src.as_raw__InputArray(), min_val, max_val, mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_minMax_const__InputArrayR_doubleX_doubleX_const__InputArrayR(src.as_raw__InputArray(), min_val, max_val, mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1111,7 +1360,7 @@ pub fn min_max(src: &dyn core::ToInputArray, min_val: &mut f64, max_val: &mut f6
 /// * dst: Destination matrix that has the same size and type as the input array(s).
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// min
+/// [min](crate::cudaarithm::min)
 /// 
 /// ## C++ default parameters
 /// * stream: Stream::Null()
@@ -1121,7 +1370,7 @@ pub fn min(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &m
 	input_array_arg!(src2);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_min_const__InputArrayR_const__InputArrayR_const__OutputArrayR_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_min_const__InputArrayR_const__InputArrayR_const__OutputArrayR_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1141,7 +1390,7 @@ pub fn min(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &m
 /// 
 /// Only full (not packed) CV_32FC2 complex spectrums in the interleaved format are supported for now.
 /// ## See also
-/// mulSpectrums
+/// [mul_spectrums](crate::cudaarithm::mul_spectrums)
 /// 
 /// ## C++ default parameters
 /// * conj_b: false
@@ -1152,12 +1401,45 @@ pub fn mul_and_scale_spectrums(src1: &dyn core::ToInputArray, src2: &dyn core::T
 	input_array_arg!(src2);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_mulAndScaleSpectrums_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_float_bool_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), flags, scale, conj_b, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_mulAndScaleSpectrums_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_float_bool_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), flags, scale, conj_b, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `mul_and_scale_spectrums()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct MulAndScaleSpectrumsBuilder<'a> {
+	src1: &'a dyn core::ToInputArray,
+	src2: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	flags: i32,
+	scale: f32,
+	conj_b: bool,
+	stream: &'a mut core::Stream,
+}
+
+impl<'a> MulAndScaleSpectrumsBuilder<'a> {
+	#[inline]
+	pub fn new(src1: &'a dyn core::ToInputArray, src2: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, flags: i32, scale: f32, stream: &'a mut core::Stream) -> Self {
+		Self { src1, src2, dst, flags, scale, conj_b: false, stream }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_conj_b(mut self, conj_b: bool) -> Self {
+		self.conj_b = conj_b;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		mul_and_scale_spectrums(self.src1, self.src2, self.dst, self.flags, self.scale, self.conj_b, self.stream)
+	}
+}
+
 /// Performs a per-element multiplication of two Fourier spectrums.
 /// 
 /// ## Parameters
@@ -1171,7 +1453,7 @@ pub fn mul_and_scale_spectrums(src1: &dyn core::ToInputArray, src2: &dyn core::T
 /// 
 /// Only full (not packed) CV_32FC2 complex spectrums in the interleaved format are supported for now.
 /// ## See also
-/// mulSpectrums
+/// [mul_spectrums](crate::cudaarithm::mul_spectrums)
 /// 
 /// ## C++ default parameters
 /// * conj_b: false
@@ -1182,12 +1464,44 @@ pub fn mul_spectrums(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArra
 	input_array_arg!(src2);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_mulSpectrums_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_bool_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), flags, conj_b, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_mulSpectrums_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_bool_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), flags, conj_b, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `mul_spectrums()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct MulSpectrumsBuilder<'a> {
+	src1: &'a dyn core::ToInputArray,
+	src2: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	flags: i32,
+	conj_b: bool,
+	stream: &'a mut core::Stream,
+}
+
+impl<'a> MulSpectrumsBuilder<'a> {
+	#[inline]
+	pub fn new(src1: &'a dyn core::ToInputArray, src2: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, flags: i32, stream: &'a mut core::Stream) -> Self {
+		Self { src1, src2, dst, flags, conj_b: false, stream }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_conj_b(mut self, conj_b: bool) -> Self {
+		self.conj_b = conj_b;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		mul_spectrums(self.src1, self.src2, self.dst, self.flags, self.conj_b, self.stream)
+	}
+}
+
 /// Computes a matrix-matrix or matrix-scalar per-element product.
 /// 
 /// ## Parameters
@@ -1199,7 +1513,7 @@ pub fn mul_spectrums(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArra
 /// * dtype: Optional depth of the output array.
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// multiply
+/// [multiply](crate::cudaarithm::multiply)
 /// 
 /// ## C++ default parameters
 /// * scale: 1
@@ -1211,12 +1525,51 @@ pub fn multiply(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, ds
 	input_array_arg!(src2);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_multiply_const__InputArrayR_const__InputArrayR_const__OutputArrayR_double_int_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), scale, dtype, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_multiply_const__InputArrayR_const__InputArrayR_const__OutputArrayR_double_int_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), scale, dtype, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `multiply()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct MultiplyBuilder<'a> {
+	src1: &'a dyn core::ToInputArray,
+	src2: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	scale: f64,
+	dtype: i32,
+	stream: &'a mut core::Stream,
+}
+
+impl<'a> MultiplyBuilder<'a> {
+	#[inline]
+	pub fn new(src1: &'a dyn core::ToInputArray, src2: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, stream: &'a mut core::Stream) -> Self {
+		Self { src1, src2, dst, scale: 1., dtype: -1, stream }
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_scale(mut self, scale: f64) -> Self {
+		self.scale = scale;
+		self
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_dtype(mut self, dtype: i32) -> Self {
+		self.dtype = dtype;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		multiply(self.src1, self.src2, self.dst, self.scale, self.dtype, self.stream)
+	}
+}
+
 /// Returns the difference of two matrices.
 /// 
 /// ## Parameters
@@ -1224,7 +1577,7 @@ pub fn multiply(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, ds
 /// * src2: Second source matrix (if any) with the same size and type as src1.
 /// * normType: Norm type. NORM_L1 , NORM_L2 , and NORM_INF are supported for now.
 /// ## See also
-/// norm
+/// [norm](crate::cudaarithm::norm)
 /// 
 /// ## C++ default parameters
 /// * norm_type: NORM_L2
@@ -1233,7 +1586,7 @@ pub fn norm_1(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, norm
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_norm_const__InputArrayR_const__InputArrayR_int(src1.as_raw__InputArray(), src2.as_raw__InputArray(), norm_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_norm_const__InputArrayR_const__InputArrayR_int(src1.as_raw__InputArray(), src2.as_raw__InputArray(), norm_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1246,7 +1599,7 @@ pub fn norm_1(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, norm
 /// * normType: Norm type. NORM_L1 , NORM_L2 , and NORM_INF are supported for now.
 /// * mask: optional operation mask; it must have the same size as src1 and CV_8UC1 type.
 /// ## See also
-/// norm
+/// [norm](crate::cudaarithm::norm)
 /// 
 /// ## C++ default parameters
 /// * mask: noArray()
@@ -1255,7 +1608,7 @@ pub fn norm(src1: &dyn core::ToInputArray, norm_type: i32, mask: &dyn core::ToIn
 	input_array_arg!(src1);
 	input_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_norm_const__InputArrayR_int_const__InputArrayR(src1.as_raw__InputArray(), norm_type, mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_norm_const__InputArrayR_int_const__InputArrayR(src1.as_raw__InputArray(), norm_type, mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1276,7 +1629,7 @@ pub fn norm(src1: &dyn core::ToInputArray, norm_type: i32, mask: &dyn core::ToIn
 /// * mask: Optional operation mask.
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// normalize
+/// [normalize](crate::cudaarithm::normalize)
 /// 
 /// ## C++ default parameters
 /// * mask: noArray()
@@ -1287,12 +1640,48 @@ pub fn normalize(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 	output_array_arg!(dst);
 	input_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_normalize_const__InputArrayR_const__OutputArrayR_double_double_int_int_const__InputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), alpha, beta, norm_type, dtype, mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_normalize_const__InputArrayR_const__OutputArrayR_double_double_int_int_const__InputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), alpha, beta, norm_type, dtype, mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `normalize()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct NormalizeBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	alpha: f64,
+	beta: f64,
+	norm_type: i32,
+	dtype: i32,
+	mask: Option<&'a dyn core::ToInputArray>,
+	stream: &'a mut core::Stream,
+}
+
+impl<'a> NormalizeBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, alpha: f64, beta: f64, norm_type: i32, dtype: i32, stream: &'a mut core::Stream) -> Self {
+		Self { src, dst, alpha, beta, norm_type, dtype, mask: None, stream }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_mask(mut self, mask: &'a dyn core::ToInputArray) -> Self {
+		self.mask = Some(mask);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let mask_no_array = core::no_array();
+		let mask: &dyn core::ToInputArray = match self.mask { Some(mask) => mask, None => &mask_no_array };
+		normalize(self.src, self.dst, self.alpha, self.beta, self.norm_type, self.dtype, mask, self.stream)
+	}
+}
+
 /// Computes polar angles of complex matrix elements.
 /// 
 /// ## Parameters
@@ -1302,7 +1691,7 @@ pub fn normalize(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 /// * angleInDegrees: Flag for angles that must be evaluated in degrees.
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// phase
+/// [phase](crate::cudaarithm::phase)
 /// 
 /// ## C++ default parameters
 /// * angle_in_degrees: false
@@ -1313,7 +1702,7 @@ pub fn phase(x: &dyn core::ToInputArray, y: &dyn core::ToInputArray, angle: &mut
 	input_array_arg!(y);
 	output_array_arg!(angle);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_phase_const__InputArrayR_const__InputArrayR_const__OutputArrayR_bool_StreamR(x.as_raw__InputArray(), y.as_raw__InputArray(), angle.as_raw__OutputArray(), angle_in_degrees, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_phase_const__InputArrayR_const__InputArrayR_const__OutputArrayR_bool_StreamR(x.as_raw__InputArray(), y.as_raw__InputArray(), angle.as_raw__OutputArray(), angle_in_degrees, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1339,12 +1728,44 @@ pub fn polar_to_cart(magnitude: &dyn core::ToInputArray, angle: &dyn core::ToInp
 	output_array_arg!(x);
 	output_array_arg!(y);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_polarToCart_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_bool_StreamR(magnitude.as_raw__InputArray(), angle.as_raw__InputArray(), x.as_raw__OutputArray(), y.as_raw__OutputArray(), angle_in_degrees, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_polarToCart_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_bool_StreamR(magnitude.as_raw__InputArray(), angle.as_raw__InputArray(), x.as_raw__OutputArray(), y.as_raw__OutputArray(), angle_in_degrees, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `polar_to_cart()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct PolarToCartBuilder<'a> {
+	magnitude: &'a dyn core::ToInputArray,
+	angle: &'a dyn core::ToInputArray,
+	x: &'a mut dyn core::ToOutputArray,
+	y: &'a mut dyn core::ToOutputArray,
+	angle_in_degrees: bool,
+	stream: &'a mut core::Stream,
+}
+
+impl<'a> PolarToCartBuilder<'a> {
+	#[inline]
+	pub fn new(magnitude: &'a dyn core::ToInputArray, angle: &'a dyn core::ToInputArray, x: &'a mut dyn core::ToOutputArray, y: &'a mut dyn core::ToOutputArray, stream: &'a mut core::Stream) -> Self {
+		Self { magnitude, angle, x, y, angle_in_degrees: false, stream }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_angle_in_degrees(mut self, angle_in_degrees: bool) -> Self {
+		self.angle_in_degrees = angle_in_degrees;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		polar_to_cart(self.magnitude, self.angle, self.x, self.y, self.angle_in_degrees, self.stream)
+	}
+}
+
 /// Raises every matrix element to a power.
 /// 
 /// ## Parameters
@@ -1357,7 +1778,7 @@ pub fn polar_to_cart(magnitude: &dyn core::ToInputArray, angle: &dyn core::ToInp
 /// 
 /// ![block formula](https://latex.codecogs.com/png.latex?%5Ctexttt%7Bdst%7D%20%28I%29%20%3D%20%20%5Cfork%7B%5Ctexttt%7Bsrc%7D%28I%29%5Epower%7D%7Bif%20%5Ctexttt%7Bpower%7D%20is%20integer%7D%7B%7C%5Ctexttt%7Bsrc%7D%28I%29%7C%5Epower%7D%7Botherwise%7D)
 /// ## See also
-/// pow
+/// [pow](crate::cudaarithm::pow)
 /// 
 /// ## C++ default parameters
 /// * stream: Stream::Null()
@@ -1366,7 +1787,7 @@ pub fn pow(src: &dyn core::ToInputArray, power: f64, dst: &mut dyn core::ToOutpu
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_pow_const__InputArrayR_double_const__OutputArrayR_StreamR(src.as_raw__InputArray(), power, dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_pow_const__InputArrayR_double_const__OutputArrayR_StreamR(src.as_raw__InputArray(), power, dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1389,7 +1810,7 @@ pub fn rect_std_dev(src: &dyn core::ToInputArray, sqr: &dyn core::ToInputArray,
 	input_array_arg!(sqr);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_rectStdDev_const__InputArrayR_const__InputArrayR_const__OutputArrayR_Rect_StreamR(src.as_raw__InputArray(), sqr.as_raw__InputArray(), dst.as_raw__OutputArray(), rect.opencv_as_extern(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_rectStdDev_const__InputArrayR_const__InputArrayR_const__OutputArrayR_Rect_StreamR(src.as_raw__InputArray(), sqr.as_raw__InputArray(), dst.as_raw__OutputArray(), rect.opencv_as_extern(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1420,7 +1841,7 @@ pub fn rect_std_dev(src: &dyn core::ToInputArray, sqr: &dyn core::ToInputArray,
 /// bit-depth to preserve accuracy. And multi-channel arrays are also supported in these two reduction
 /// modes.
 /// ## See also
-/// reduce
+/// [reduce](crate::cudaarithm::reduce)
 /// 
 /// ## C++ default parameters
 /// * dtype: -1
@@ -1430,12 +1851,44 @@ pub fn reduce(mtx: &dyn core::ToInputArray, vec: &mut dyn core::ToOutputArray, d
 	input_array_arg!(mtx);
 	output_array_arg!(vec);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_reduce_const__InputArrayR_const__OutputArrayR_int_int_int_StreamR(mtx.as_raw__InputArray(), vec.as_raw__OutputArray(), dim, reduce_op, dtype, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_reduce_const__InputArrayR_const__OutputArrayR_int_int_int_StreamR(mtx.as_raw__InputArray(), vec.as_raw__OutputArray(), dim, reduce_op, dtype, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `reduce()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct ReduceBuilder<'a> {
+	mtx: &'a dyn core::ToInputArray,
+	vec: &'a mut dyn core::ToOutputArray,
+	dim: i32,
+	reduce_op: i32,
+	dtype: i32,
+	stream: &'a mut core::Stream,
+}
+
+impl<'a> ReduceBuilder<'a> {
+	#[inline]
+	pub fn new(mtx: &'a dyn core::ToInputArray, vec: &'a mut dyn core::ToOutputArray, dim: i32, reduce_op: i32, stream: &'a mut core::Stream) -> Self {
+		Self { mtx, vec, dim, reduce_op, dtype: -1, stream }
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_dtype(mut self, dtype: i32) -> Self {
+		self.dtype = dtype;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		reduce(self.mtx, self.vec, self.dim, self.reduce_op, self.dtype, self.stream)
+	}
+}
+
 /// Performs pixel by pixel right shift of an image by a constant value.
 /// 
 /// ## Parameters
@@ -1451,7 +1904,7 @@ pub fn rshift(src: &dyn core::ToInputArray, val: core::Scalar_<i32>, dst: &mut d
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_rshift_const__InputArrayR_Scalar__int__const__OutputArrayR_StreamR(src.as_raw__InputArray(), val.opencv_as_extern(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_rshift_const__InputArrayR_Scalar__int__const__OutputArrayR_StreamR(src.as_raw__InputArray(), val.opencv_as_extern(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1464,7 +1917,7 @@ pub fn rshift_1(src: &dyn core::ToInputArray, val: core::Scalar, dst: &mut dyn c
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_rshift_const__InputArrayR_Scalar_const__OutputArrayR_StreamR(src.as_raw__InputArray(), val.opencv_as_extern(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_rshift_const__InputArrayR_Scalar_const__OutputArrayR_StreamR(src.as_raw__InputArray(), val.opencv_as_extern(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1477,7 +1930,7 @@ pub fn rshift_1(src: &dyn core::ToInputArray, val: core::Scalar, dst: &mut dyn c
 /// * dst: Destination array/vector of single-channel matrices.
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// split
+/// [split](crate::cudaarithm::split)
 /// 
 /// ## C++ default parameters
 /// * stream: Stream::Null()
@@ -1485,7 +1938,7 @@ pub fn rshift_1(src: &dyn core::ToInputArray, val: core::Scalar, dst: &mut dyn c
 pub fn split(src: &dyn core::ToInputArray, dst: &mut core::GpuMat, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_split_const__InputArrayR_GpuMatX_StreamR(src.as_raw__InputArray(), dst.as_raw_mut_GpuMat(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_split_const__InputArrayR_GpuMatX_StreamR(src.as_raw__InputArray(), dst.as_raw_mut_GpuMat(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1498,7 +1951,7 @@ pub fn split(src: &dyn core::ToInputArray, dst: &mut core::GpuMat, stream: &mut
 /// * dst: Destination array/vector of single-channel matrices.
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// split
+/// [split](crate::cudaarithm::split)
 /// 
 /// ## Overloaded parameters
 /// 
@@ -1508,7 +1961,7 @@ pub fn split(src: &dyn core::ToInputArray, dst: &mut core::GpuMat, stream: &mut
 pub fn split_1(src: &dyn core::ToInputArray, dst: &mut core::Vector<core::GpuMat>, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_split_const__InputArrayR_vector_GpuMat_R_StreamR(src.as_raw__InputArray(), dst.as_raw_mut_VectorOfGpuMat(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_split_const__InputArrayR_vector_GpuMat_R_StreamR(src.as_raw__InputArray(), dst.as_raw_mut_VectorOfGpuMat(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1529,7 +1982,7 @@ pub fn sqr_integral(src: &dyn core::ToInputArray, sqsum: &mut dyn core::ToOutput
 	input_array_arg!(src);
 	output_array_arg!(sqsum);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_sqrIntegral_const__InputArrayR_const__OutputArrayR_StreamR(src.as_raw__InputArray(), sqsum.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_sqrIntegral_const__InputArrayR_const__OutputArrayR_StreamR(src.as_raw__InputArray(), sqsum.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1548,7 +2001,7 @@ pub fn sqr_sum(src: &dyn core::ToInputArray, mask: &dyn core::ToInputArray) -> R
 	input_array_arg!(src);
 	input_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_sqrSum_const__InputArrayR_const__InputArrayR(src.as_raw__InputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_sqrSum_const__InputArrayR_const__InputArrayR(src.as_raw__InputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1568,7 +2021,7 @@ pub fn sqr(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, stre
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_sqr_const__InputArrayR_const__OutputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_sqr_const__InputArrayR_const__OutputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1581,7 +2034,7 @@ pub fn sqr(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, stre
 /// * dst: Destination matrix with the same size and type as src .
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// sqrt
+/// [sqrt](crate::cudaarithm::sqrt)
 /// 
 /// ## C++ default parameters
 /// * stream: Stream::Null()
@@ -1590,7 +2043,7 @@ pub fn sqrt(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, str
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_sqrt_const__InputArrayR_const__OutputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_sqrt_const__InputArrayR_const__OutputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1608,7 +2061,7 @@ pub fn sqrt(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, str
 /// * dtype: Optional depth of the output array.
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// subtract
+/// [subtract](crate::cudaarithm::subtract)
 /// 
 /// ## C++ default parameters
 /// * mask: noArray()
@@ -1621,19 +2074,60 @@ pub fn subtract(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, ds
 	output_array_arg!(dst);
 	input_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_subtract_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR_int_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), dtype, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_subtract_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR_int_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), dtype, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `subtract()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct SubtractBuilder<'a> {
+	src1: &'a dyn core::ToInputArray,
+	src2: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	mask: Option<&'a dyn core::ToInputArray>,
+	dtype: i32,
+	stream: &'a mut core::Stream,
+}
+
+impl<'a> SubtractBuilder<'a> {
+	#[inline]
+	pub fn new(src1: &'a dyn core::ToInputArray, src2: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, stream: &'a mut core::Stream) -> Self {
+		Self { src1, src2, dst, mask: None, dtype: -1, stream }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_mask(mut self, mask: &'a dyn core::ToInputArray) -> Self {
+		self.mask = Some(mask);
+		self
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_dtype(mut self, dtype: i32) -> Self {
+		self.dtype = dtype;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let mask_no_array = core::no_array();
+		let mask: &dyn core::ToInputArray = match self.mask { Some(mask) => mask, None => &mask_no_array };
+		subtract(self.src1, self.src2, self.dst, mask, self.dtype, self.stream)
+	}
+}
+
 /// Returns the sum of matrix elements.
 /// 
 /// ## Parameters
 /// * src: Source image of any depth except for CV_64F .
 /// * mask: optional operation mask; it must have the same size as src1 and CV_8UC1 type.
 /// ## See also
-/// sum
+/// [sum](crate::cudaarithm::sum)
 /// 
 /// ## C++ default parameters
 /// * mask: noArray()
@@ -1642,7 +2136,7 @@ pub fn sum(src: &dyn core::ToInputArray, mask: &dyn core::ToInputArray) -> Resul
 	input_array_arg!(src);
 	input_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_sum_const__InputArrayR_const__InputArrayR(src.as_raw__InputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_sum_const__InputArrayR_const__InputArrayR(src.as_raw__InputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1659,7 +2153,7 @@ pub fn sum(src: &dyn core::ToInputArray, mask: &dyn core::ToInputArray) -> Resul
 /// threshold types are not supported.
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// threshold
+/// [threshold](crate::cudaarithm::threshold)
 /// 
 /// ## C++ default parameters
 /// * stream: Stream::Null()
@@ -1668,7 +2162,7 @@ pub fn threshold(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_threshold_const__InputArrayR_const__OutputArrayR_double_double_int_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), thresh, maxval, typ, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_threshold_const__InputArrayR_const__OutputArrayR_double_double_int_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), thresh, maxval, typ, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1681,7 +2175,7 @@ pub fn threshold(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 /// * dst: Destination matrix.
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// transpose
+/// [transpose](crate::cudaarithm::transpose)
 /// 
 /// ## C++ default parameters
 /// * stream: Stream::Null()
@@ -1690,7 +2184,7 @@ pub fn transpose(src1: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArra
 	input_array_arg!(src1);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_transpose_const__InputArrayR_const__OutputArrayR_StreamR(src1.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_transpose_const__InputArrayR_const__OutputArrayR_StreamR(src1.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1721,11 +2215,12 @@ pub trait Convolution: core::AlgorithmTrait + crate::cudaarithm::ConvolutionCons
 	/// * stream: Stream::Null()
 	#[inline]
 	fn convolve(&mut self, image: &dyn core::ToInputArray, templ: &dyn core::ToInputArray, result: &mut dyn core::ToOutputArray, ccorr: bool, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("Convolution", self.as_raw_mut_Convolution())?;
 		input_array_arg!(image);
 		input_array_arg!(templ);
 		output_array_arg!(result);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_Convolution_convolve_const__InputArrayR_const__InputArrayR_const__OutputArrayR_bool_StreamR(self.as_raw_mut_Convolution(), image.as_raw__InputArray(), templ.as_raw__InputArray(), result.as_raw__OutputArray(), ccorr, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_Convolution_convolve_const__InputArrayR_const__InputArrayR_const__OutputArrayR_bool_StreamR(self.as_raw_mut_Convolution(), image.as_raw__InputArray(), templ.as_raw__InputArray(), result.as_raw__OutputArray(), ccorr, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1753,10 +2248,11 @@ pub trait DFT: core::AlgorithmTrait + crate::cudaarithm::DFTConst {
 	/// * stream: Stream::Null()
 	#[inline]
 	fn compute(&mut self, image: &dyn core::ToInputArray, result: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("DFT", self.as_raw_mut_DFT())?;
 		input_array_arg!(image);
 		output_array_arg!(result);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DFT_compute_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_DFT(), image.as_raw__InputArray(), result.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DFT_compute_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_DFT(), image.as_raw__InputArray(), result.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1785,10 +2281,11 @@ pub trait LookUpTable: core::AlgorithmTrait + crate::cudaarithm::LookUpTableCons
 	/// * stream: Stream::Null()
 	#[inline]
 	fn transform(&mut self, src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("LookUpTable", self.as_raw_mut_LookUpTable())?;
 		input_array_arg!(src);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_LookUpTable_transform_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_LookUpTable(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_LookUpTable_transform_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_LookUpTable(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
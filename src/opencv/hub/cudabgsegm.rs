@@ -30,13 +30,63 @@ pub mod prelude {
 #[inline]
 pub fn create_background_subtractor_mog2(history: i32, var_threshold: f64, detect_shadows: bool) -> Result<core::Ptr<dyn crate::cudabgsegm::CUDA_BackgroundSubtractorMOG2>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createBackgroundSubtractorMOG2_int_double_bool(history, var_threshold, detect_shadows, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createBackgroundSubtractorMOG2_int_double_bool(history, var_threshold, detect_shadows, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudabgsegm::CUDA_BackgroundSubtractorMOG2>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_background_subtractor_mog2()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateBackgroundSubtractorMog2Builder {
+	history: i32,
+	var_threshold: f64,
+	detect_shadows: bool,
+}
+
+impl CreateBackgroundSubtractorMog2Builder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { history: 500, var_threshold: 16., detect_shadows: true }
+	}
+	
+	/// C++ default: `500`
+	#[inline]
+	pub fn with_history(mut self, history: i32) -> Self {
+		self.history = history;
+		self
+	}
+	
+	/// C++ default: `16`
+	#[inline]
+	pub fn with_var_threshold(mut self, var_threshold: f64) -> Self {
+		self.var_threshold = var_threshold;
+		self
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_detect_shadows(mut self, detect_shadows: bool) -> Self {
+		self.detect_shadows = detect_shadows;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::cudabgsegm::CUDA_BackgroundSubtractorMOG2>> {
+		create_background_subtractor_mog2(self.history, self.var_threshold, self.detect_shadows)
+	}
+}
+
+impl Default for CreateBackgroundSubtractorMog2Builder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 /// Creates mixture-of-gaussian background subtractor
 /// 
 /// ## Parameters
@@ -54,20 +104,78 @@ pub fn create_background_subtractor_mog2(history: i32, var_threshold: f64, detec
 #[inline]
 pub fn create_background_subtractor_mog(history: i32, nmixtures: i32, background_ratio: f64, noise_sigma: f64) -> Result<core::Ptr<dyn crate::cudabgsegm::CUDA_BackgroundSubtractorMOG>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createBackgroundSubtractorMOG_int_int_double_double(history, nmixtures, background_ratio, noise_sigma, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createBackgroundSubtractorMOG_int_int_double_double(history, nmixtures, background_ratio, noise_sigma, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudabgsegm::CUDA_BackgroundSubtractorMOG>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_background_subtractor_mog()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateBackgroundSubtractorMogBuilder {
+	history: i32,
+	nmixtures: i32,
+	background_ratio: f64,
+	noise_sigma: f64,
+}
+
+impl CreateBackgroundSubtractorMogBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { history: 200, nmixtures: 5, background_ratio: 0.7, noise_sigma: 0. }
+	}
+	
+	/// C++ default: `200`
+	#[inline]
+	pub fn with_history(mut self, history: i32) -> Self {
+		self.history = history;
+		self
+	}
+	
+	/// C++ default: `5`
+	#[inline]
+	pub fn with_nmixtures(mut self, nmixtures: i32) -> Self {
+		self.nmixtures = nmixtures;
+		self
+	}
+	
+	/// C++ default: `0.7`
+	#[inline]
+	pub fn with_background_ratio(mut self, background_ratio: f64) -> Self {
+		self.background_ratio = background_ratio;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_noise_sigma(mut self, noise_sigma: f64) -> Self {
+		self.noise_sigma = noise_sigma;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::cudabgsegm::CUDA_BackgroundSubtractorMOG>> {
+		create_background_subtractor_mog(self.history, self.nmixtures, self.background_ratio, self.noise_sigma)
+	}
+}
+
+impl Default for CreateBackgroundSubtractorMogBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 /// Gaussian Mixture-based Background/Foreground Segmentation Algorithm.
 /// 
 /// The class discriminates between foreground and background pixels by building and maintaining a model
 /// of the background. Any pixel which does not fit this model is then deemed to be foreground. The
 /// class implements algorithm described in [MOG2001](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_MOG2001) .
 /// ## See also
-/// BackgroundSubtractorMOG
+/// `BackgroundSubtractorMOG`
 /// 
 /// 
 /// Note:
@@ -78,9 +186,10 @@ pub trait CUDA_BackgroundSubtractorMOGConst: crate::video::BackgroundSubtractorC
 
 	#[inline]
 	fn get_background_image(&self, background_image: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CUDA_BackgroundSubtractorMOG", self.as_raw_CUDA_BackgroundSubtractorMOG())?;
 		output_array_arg!(background_image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_BackgroundSubtractorMOG_getBackgroundImage_const_const__OutputArrayR_StreamR(self.as_raw_CUDA_BackgroundSubtractorMOG(), background_image.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_BackgroundSubtractorMOG_getBackgroundImage_const_const__OutputArrayR_StreamR(self.as_raw_CUDA_BackgroundSubtractorMOG(), background_image.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -88,8 +197,9 @@ pub trait CUDA_BackgroundSubtractorMOGConst: crate::video::BackgroundSubtractorC
 	
 	#[inline]
 	fn get_history(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_BackgroundSubtractorMOG", self.as_raw_CUDA_BackgroundSubtractorMOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_BackgroundSubtractorMOG_getHistory_const(self.as_raw_CUDA_BackgroundSubtractorMOG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_BackgroundSubtractorMOG_getHistory_const(self.as_raw_CUDA_BackgroundSubtractorMOG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -97,8 +207,9 @@ pub trait CUDA_BackgroundSubtractorMOGConst: crate::video::BackgroundSubtractorC
 	
 	#[inline]
 	fn get_n_mixtures(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_BackgroundSubtractorMOG", self.as_raw_CUDA_BackgroundSubtractorMOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_BackgroundSubtractorMOG_getNMixtures_const(self.as_raw_CUDA_BackgroundSubtractorMOG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_BackgroundSubtractorMOG_getNMixtures_const(self.as_raw_CUDA_BackgroundSubtractorMOG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -106,8 +217,9 @@ pub trait CUDA_BackgroundSubtractorMOGConst: crate::video::BackgroundSubtractorC
 	
 	#[inline]
 	fn get_background_ratio(&self) -> Result<f64> {
+		crate::core::validity::check("CUDA_BackgroundSubtractorMOG", self.as_raw_CUDA_BackgroundSubtractorMOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_BackgroundSubtractorMOG_getBackgroundRatio_const(self.as_raw_CUDA_BackgroundSubtractorMOG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_BackgroundSubtractorMOG_getBackgroundRatio_const(self.as_raw_CUDA_BackgroundSubtractorMOG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -115,8 +227,9 @@ pub trait CUDA_BackgroundSubtractorMOGConst: crate::video::BackgroundSubtractorC
 	
 	#[inline]
 	fn get_noise_sigma(&self) -> Result<f64> {
+		crate::core::validity::check("CUDA_BackgroundSubtractorMOG", self.as_raw_CUDA_BackgroundSubtractorMOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_BackgroundSubtractorMOG_getNoiseSigma_const(self.as_raw_CUDA_BackgroundSubtractorMOG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_BackgroundSubtractorMOG_getNoiseSigma_const(self.as_raw_CUDA_BackgroundSubtractorMOG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -129,10 +242,11 @@ pub trait CUDA_BackgroundSubtractorMOG: crate::cudabgsegm::CUDA_BackgroundSubtra
 
 	#[inline]
 	fn apply(&mut self, image: &dyn core::ToInputArray, fgmask: &mut dyn core::ToOutputArray, learning_rate: f64, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CUDA_BackgroundSubtractorMOG", self.as_raw_mut_CUDA_BackgroundSubtractorMOG())?;
 		input_array_arg!(image);
 		output_array_arg!(fgmask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_BackgroundSubtractorMOG_apply_const__InputArrayR_const__OutputArrayR_double_StreamR(self.as_raw_mut_CUDA_BackgroundSubtractorMOG(), image.as_raw__InputArray(), fgmask.as_raw__OutputArray(), learning_rate, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_BackgroundSubtractorMOG_apply_const__InputArrayR_const__OutputArrayR_double_StreamR(self.as_raw_mut_CUDA_BackgroundSubtractorMOG(), image.as_raw__InputArray(), fgmask.as_raw__OutputArray(), learning_rate, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -140,8 +254,9 @@ pub trait CUDA_BackgroundSubtractorMOG: crate::cudabgsegm::CUDA_BackgroundSubtra
 	
 	#[inline]
 	fn get_background_image_1(&mut self, background_image: &mut core::GpuMat, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CUDA_BackgroundSubtractorMOG", self.as_raw_mut_CUDA_BackgroundSubtractorMOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_BackgroundSubtractorMOG_getBackgroundImage_GpuMatR_StreamR(self.as_raw_mut_CUDA_BackgroundSubtractorMOG(), background_image.as_raw_mut_GpuMat(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_BackgroundSubtractorMOG_getBackgroundImage_GpuMatR_StreamR(self.as_raw_mut_CUDA_BackgroundSubtractorMOG(), background_image.as_raw_mut_GpuMat(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -149,8 +264,9 @@ pub trait CUDA_BackgroundSubtractorMOG: crate::cudabgsegm::CUDA_BackgroundSubtra
 	
 	#[inline]
 	fn set_history(&mut self, nframes: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_BackgroundSubtractorMOG", self.as_raw_mut_CUDA_BackgroundSubtractorMOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_BackgroundSubtractorMOG_setHistory_int(self.as_raw_mut_CUDA_BackgroundSubtractorMOG(), nframes, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_BackgroundSubtractorMOG_setHistory_int(self.as_raw_mut_CUDA_BackgroundSubtractorMOG(), nframes, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -158,8 +274,9 @@ pub trait CUDA_BackgroundSubtractorMOG: crate::cudabgsegm::CUDA_BackgroundSubtra
 	
 	#[inline]
 	fn set_n_mixtures(&mut self, nmix: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_BackgroundSubtractorMOG", self.as_raw_mut_CUDA_BackgroundSubtractorMOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_BackgroundSubtractorMOG_setNMixtures_int(self.as_raw_mut_CUDA_BackgroundSubtractorMOG(), nmix, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_BackgroundSubtractorMOG_setNMixtures_int(self.as_raw_mut_CUDA_BackgroundSubtractorMOG(), nmix, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -167,8 +284,9 @@ pub trait CUDA_BackgroundSubtractorMOG: crate::cudabgsegm::CUDA_BackgroundSubtra
 	
 	#[inline]
 	fn set_background_ratio(&mut self, background_ratio: f64) -> Result<()> {
+		crate::core::validity::check("CUDA_BackgroundSubtractorMOG", self.as_raw_mut_CUDA_BackgroundSubtractorMOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_BackgroundSubtractorMOG_setBackgroundRatio_double(self.as_raw_mut_CUDA_BackgroundSubtractorMOG(), background_ratio, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_BackgroundSubtractorMOG_setBackgroundRatio_double(self.as_raw_mut_CUDA_BackgroundSubtractorMOG(), background_ratio, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -176,8 +294,9 @@ pub trait CUDA_BackgroundSubtractorMOG: crate::cudabgsegm::CUDA_BackgroundSubtra
 	
 	#[inline]
 	fn set_noise_sigma(&mut self, noise_sigma: f64) -> Result<()> {
+		crate::core::validity::check("CUDA_BackgroundSubtractorMOG", self.as_raw_mut_CUDA_BackgroundSubtractorMOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_BackgroundSubtractorMOG_setNoiseSigma_double(self.as_raw_mut_CUDA_BackgroundSubtractorMOG(), noise_sigma, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_BackgroundSubtractorMOG_setNoiseSigma_double(self.as_raw_mut_CUDA_BackgroundSubtractorMOG(), noise_sigma, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -191,15 +310,16 @@ pub trait CUDA_BackgroundSubtractorMOG: crate::cudabgsegm::CUDA_BackgroundSubtra
 /// of the background. Any pixel which does not fit this model is then deemed to be foreground. The
 /// class implements algorithm described in [Zivkovic2004](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_Zivkovic2004) .
 /// ## See also
-/// BackgroundSubtractorMOG2
+/// `BackgroundSubtractorMOG2`
 pub trait CUDA_BackgroundSubtractorMOG2Const: crate::video::BackgroundSubtractorMOG2Const {
 	fn as_raw_CUDA_BackgroundSubtractorMOG2(&self) -> *const c_void;
 
 	#[inline]
 	fn get_background_image(&self, background_image: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CUDA_BackgroundSubtractorMOG2", self.as_raw_CUDA_BackgroundSubtractorMOG2())?;
 		output_array_arg!(background_image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_BackgroundSubtractorMOG2_getBackgroundImage_const_const__OutputArrayR_StreamR(self.as_raw_CUDA_BackgroundSubtractorMOG2(), background_image.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_BackgroundSubtractorMOG2_getBackgroundImage_const_const__OutputArrayR_StreamR(self.as_raw_CUDA_BackgroundSubtractorMOG2(), background_image.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -212,10 +332,11 @@ pub trait CUDA_BackgroundSubtractorMOG2: crate::cudabgsegm::CUDA_BackgroundSubtr
 
 	#[inline]
 	fn apply(&mut self, image: &dyn core::ToInputArray, fgmask: &mut dyn core::ToOutputArray, learning_rate: f64, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CUDA_BackgroundSubtractorMOG2", self.as_raw_mut_CUDA_BackgroundSubtractorMOG2())?;
 		input_array_arg!(image);
 		output_array_arg!(fgmask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_BackgroundSubtractorMOG2_apply_const__InputArrayR_const__OutputArrayR_double_StreamR(self.as_raw_mut_CUDA_BackgroundSubtractorMOG2(), image.as_raw__InputArray(), fgmask.as_raw__OutputArray(), learning_rate, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_BackgroundSubtractorMOG2_apply_const__InputArrayR_const__OutputArrayR_double_StreamR(self.as_raw_mut_CUDA_BackgroundSubtractorMOG2(), image.as_raw__InputArray(), fgmask.as_raw__OutputArray(), learning_rate, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -223,8 +344,9 @@ pub trait CUDA_BackgroundSubtractorMOG2: crate::cudabgsegm::CUDA_BackgroundSubtr
 	
 	#[inline]
 	fn get_background_image_1(&mut self, background_image: &mut core::GpuMat, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CUDA_BackgroundSubtractorMOG2", self.as_raw_mut_CUDA_BackgroundSubtractorMOG2())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_BackgroundSubtractorMOG2_getBackgroundImage_GpuMatR_StreamR(self.as_raw_mut_CUDA_BackgroundSubtractorMOG2(), background_image.as_raw_mut_GpuMat(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_BackgroundSubtractorMOG2_getBackgroundImage_GpuMatR_StreamR(self.as_raw_mut_CUDA_BackgroundSubtractorMOG2(), background_image.as_raw_mut_GpuMat(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
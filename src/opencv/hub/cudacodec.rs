@@ -185,7 +185,7 @@ opencv_type_enum! { crate::cudacodec::VideoReaderProps }
 #[inline]
 pub fn create_video_reader_1(source: &core::Ptr<dyn crate::cudacodec::RawVideoSource>, raw_mode: bool) -> Result<core::Ptr<dyn crate::cudacodec::VideoReader>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cudacodec_createVideoReader_const_Ptr_RawVideoSource_R_const_bool(source.as_raw_PtrOfRawVideoSource(), raw_mode, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cudacodec_createVideoReader_const_Ptr_RawVideoSource_R_const_bool(source.as_raw_PtrOfRawVideoSource(), raw_mode, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudacodec::VideoReader>::opencv_from_extern(ret) };
@@ -206,7 +206,7 @@ pub fn create_video_reader_1(source: &core::Ptr<dyn crate::cudacodec::RawVideoSo
 pub fn create_video_reader(filename: &str, raw_mode: bool) -> Result<core::Ptr<dyn crate::cudacodec::VideoReader>> {
 	extern_container_arg!(filename);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cudacodec_createVideoReader_const_StringR_const_bool(filename.opencv_as_extern(), raw_mode, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cudacodec_createVideoReader_const_StringR_const_bool(filename.opencv_as_extern(), raw_mode, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudacodec::VideoReader>::opencv_from_extern(ret) };
@@ -241,7 +241,7 @@ pub fn create_video_reader(filename: &str, raw_mode: bool) -> Result<core::Ptr<d
 #[inline]
 pub fn create_video_writer_2(encoder_callback: &core::Ptr<dyn crate::cudacodec::EncoderCallBack>, frame_size: core::Size, fps: f64, format: crate::cudacodec::SurfaceFormat) -> Result<core::Ptr<dyn crate::cudacodec::VideoWriter>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cudacodec_createVideoWriter_const_Ptr_EncoderCallBack_R_Size_double_SurfaceFormat(encoder_callback.as_raw_PtrOfEncoderCallBack(), frame_size.opencv_as_extern(), fps, format, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cudacodec_createVideoWriter_const_Ptr_EncoderCallBack_R_Size_double_SurfaceFormat(encoder_callback.as_raw_PtrOfEncoderCallBack(), frame_size.opencv_as_extern(), fps, format, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudacodec::VideoWriter>::opencv_from_extern(ret) };
@@ -277,7 +277,7 @@ pub fn create_video_writer_2(encoder_callback: &core::Ptr<dyn crate::cudacodec::
 #[inline]
 pub fn create_video_writer_3(encoder_callback: &core::Ptr<dyn crate::cudacodec::EncoderCallBack>, frame_size: core::Size, fps: f64, params: &crate::cudacodec::EncoderParams, format: crate::cudacodec::SurfaceFormat) -> Result<core::Ptr<dyn crate::cudacodec::VideoWriter>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cudacodec_createVideoWriter_const_Ptr_EncoderCallBack_R_Size_double_const_EncoderParamsR_SurfaceFormat(encoder_callback.as_raw_PtrOfEncoderCallBack(), frame_size.opencv_as_extern(), fps, params.as_raw_EncoderParams(), format, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cudacodec_createVideoWriter_const_Ptr_EncoderCallBack_R_Size_double_const_EncoderParamsR_SurfaceFormat(encoder_callback.as_raw_PtrOfEncoderCallBack(), frame_size.opencv_as_extern(), fps, params.as_raw_EncoderParams(), format, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudacodec::VideoWriter>::opencv_from_extern(ret) };
@@ -303,7 +303,7 @@ pub fn create_video_writer_3(encoder_callback: &core::Ptr<dyn crate::cudacodec::
 pub fn create_video_writer(file_name: &str, frame_size: core::Size, fps: f64, format: crate::cudacodec::SurfaceFormat) -> Result<core::Ptr<dyn crate::cudacodec::VideoWriter>> {
 	extern_container_arg!(file_name);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cudacodec_createVideoWriter_const_StringR_Size_double_SurfaceFormat(file_name.opencv_as_extern(), frame_size.opencv_as_extern(), fps, format, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cudacodec_createVideoWriter_const_StringR_Size_double_SurfaceFormat(file_name.opencv_as_extern(), frame_size.opencv_as_extern(), fps, format, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudacodec::VideoWriter>::opencv_from_extern(ret) };
@@ -339,7 +339,7 @@ pub fn create_video_writer(file_name: &str, frame_size: core::Size, fps: f64, fo
 pub fn create_video_writer_1(file_name: &str, frame_size: core::Size, fps: f64, params: &crate::cudacodec::EncoderParams, format: crate::cudacodec::SurfaceFormat) -> Result<core::Ptr<dyn crate::cudacodec::VideoWriter>> {
 	extern_container_arg!(file_name);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cudacodec_createVideoWriter_const_StringR_Size_double_const_EncoderParamsR_SurfaceFormat(file_name.opencv_as_extern(), frame_size.opencv_as_extern(), fps, params.as_raw_EncoderParams(), format, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cudacodec_createVideoWriter_const_StringR_Size_double_const_EncoderParamsR_SurfaceFormat(file_name.opencv_as_extern(), frame_size.opencv_as_extern(), fps, params.as_raw_EncoderParams(), format, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudacodec::VideoWriter>::opencv_from_extern(ret) };
@@ -360,8 +360,9 @@ pub trait EncoderCallBack: crate::cudacodec::EncoderCallBackConst {
 	/// Callback must allocate buffer for CUDA encoder and return pointer to it and it's size.
 	#[inline]
 	fn acquire_bit_stream(&mut self, buffer_size: &mut i32) -> Result<*mut u8> {
+		crate::core::validity::check("EncoderCallBack", self.as_raw_mut_EncoderCallBack())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cudacodec_EncoderCallBack_acquireBitStream_intX(self.as_raw_mut_EncoderCallBack(), buffer_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cudacodec_EncoderCallBack_acquireBitStream_intX(self.as_raw_mut_EncoderCallBack(), buffer_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -370,8 +371,9 @@ pub trait EncoderCallBack: crate::cudacodec::EncoderCallBackConst {
 	/// Callback function to signal that the encoded bitstream is ready to be written to file.
 	#[inline]
 	fn release_bit_stream(&mut self, data: &mut u8, size: i32) -> Result<()> {
+		crate::core::validity::check("EncoderCallBack", self.as_raw_mut_EncoderCallBack())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cudacodec_EncoderCallBack_releaseBitStream_unsigned_charX_int(self.as_raw_mut_EncoderCallBack(), data, size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cudacodec_EncoderCallBack_releaseBitStream_unsigned_charX_int(self.as_raw_mut_EncoderCallBack(), data, size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -384,8 +386,9 @@ pub trait EncoderCallBack: crate::cudacodec::EncoderCallBackConst {
 	/// * picType: Specify frame type (I-Frame, P-Frame or B-Frame).
 	#[inline]
 	fn on_begin_frame(&mut self, frame_number: i32, pic_type: crate::cudacodec::EncoderCallBack_PicType) -> Result<()> {
+		crate::core::validity::check("EncoderCallBack", self.as_raw_mut_EncoderCallBack())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cudacodec_EncoderCallBack_onBeginFrame_int_PicType(self.as_raw_mut_EncoderCallBack(), frame_number, pic_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cudacodec_EncoderCallBack_onBeginFrame_int_PicType(self.as_raw_mut_EncoderCallBack(), frame_number, pic_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -398,8 +401,9 @@ pub trait EncoderCallBack: crate::cudacodec::EncoderCallBackConst {
 	/// * picType: Specify frame type (I-Frame, P-Frame or B-Frame).
 	#[inline]
 	fn on_end_frame(&mut self, frame_number: i32, pic_type: crate::cudacodec::EncoderCallBack_PicType) -> Result<()> {
+		crate::core::validity::check("EncoderCallBack", self.as_raw_mut_EncoderCallBack())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cudacodec_EncoderCallBack_onEndFrame_int_PicType(self.as_raw_mut_EncoderCallBack(), frame_number, pic_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cudacodec_EncoderCallBack_onEndFrame_int_PicType(self.as_raw_mut_EncoderCallBack(), frame_number, pic_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -414,145 +418,317 @@ pub trait EncoderParamsTraitConst {
 	/// NVVE_P_INTERVAL,
 	#[inline]
 	fn p_interval(&self) -> i32 {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_getPropP_Interval_const(self.as_raw_EncoderParams()) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_getPropP_Interval_const(self.as_raw_EncoderParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [p_interval](EncoderParamsTraitConst::p_interval), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_p_interval(&self) -> Result<i32> {
+		crate::core::validity::check("EncoderParams", self.as_raw_EncoderParams())?;
+		Ok(EncoderParamsTraitConst::p_interval(self))
+	}
+	
 	/// NVVE_IDR_PERIOD,
 	#[inline]
 	fn idr_period(&self) -> i32 {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_getPropIDR_Period_const(self.as_raw_EncoderParams()) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_getPropIDR_Period_const(self.as_raw_EncoderParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [idr_period](EncoderParamsTraitConst::idr_period), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_idr_period(&self) -> Result<i32> {
+		crate::core::validity::check("EncoderParams", self.as_raw_EncoderParams())?;
+		Ok(EncoderParamsTraitConst::idr_period(self))
+	}
+	
 	/// NVVE_DYNAMIC_GOP,
 	#[inline]
 	fn dynamic_gop(&self) -> i32 {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_getPropDynamicGOP_const(self.as_raw_EncoderParams()) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_getPropDynamicGOP_const(self.as_raw_EncoderParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [dynamic_gop](EncoderParamsTraitConst::dynamic_gop), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_dynamic_gop(&self) -> Result<i32> {
+		crate::core::validity::check("EncoderParams", self.as_raw_EncoderParams())?;
+		Ok(EncoderParamsTraitConst::dynamic_gop(self))
+	}
+	
 	/// NVVE_RC_TYPE,
 	#[inline]
 	fn rc_type(&self) -> i32 {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_getPropRCType_const(self.as_raw_EncoderParams()) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_getPropRCType_const(self.as_raw_EncoderParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [rc_type](EncoderParamsTraitConst::rc_type), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_rc_type(&self) -> Result<i32> {
+		crate::core::validity::check("EncoderParams", self.as_raw_EncoderParams())?;
+		Ok(EncoderParamsTraitConst::rc_type(self))
+	}
+	
 	/// NVVE_AVG_BITRATE,
 	#[inline]
 	fn avg_bitrate(&self) -> i32 {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_getPropAvgBitrate_const(self.as_raw_EncoderParams()) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_getPropAvgBitrate_const(self.as_raw_EncoderParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [avg_bitrate](EncoderParamsTraitConst::avg_bitrate), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_avg_bitrate(&self) -> Result<i32> {
+		crate::core::validity::check("EncoderParams", self.as_raw_EncoderParams())?;
+		Ok(EncoderParamsTraitConst::avg_bitrate(self))
+	}
+	
 	/// NVVE_PEAK_BITRATE,
 	#[inline]
 	fn peak_bitrate(&self) -> i32 {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_getPropPeakBitrate_const(self.as_raw_EncoderParams()) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_getPropPeakBitrate_const(self.as_raw_EncoderParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [peak_bitrate](EncoderParamsTraitConst::peak_bitrate), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_peak_bitrate(&self) -> Result<i32> {
+		crate::core::validity::check("EncoderParams", self.as_raw_EncoderParams())?;
+		Ok(EncoderParamsTraitConst::peak_bitrate(self))
+	}
+	
 	/// NVVE_QP_LEVEL_INTRA,
 	#[inline]
 	fn qp_level_intra(&self) -> i32 {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_getPropQP_Level_Intra_const(self.as_raw_EncoderParams()) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_getPropQP_Level_Intra_const(self.as_raw_EncoderParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [qp_level_intra](EncoderParamsTraitConst::qp_level_intra), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_qp_level_intra(&self) -> Result<i32> {
+		crate::core::validity::check("EncoderParams", self.as_raw_EncoderParams())?;
+		Ok(EncoderParamsTraitConst::qp_level_intra(self))
+	}
+	
 	/// NVVE_QP_LEVEL_INTER_P,
 	#[inline]
 	fn qp_level_inter_p(&self) -> i32 {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_getPropQP_Level_InterP_const(self.as_raw_EncoderParams()) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_getPropQP_Level_InterP_const(self.as_raw_EncoderParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [qp_level_inter_p](EncoderParamsTraitConst::qp_level_inter_p), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_qp_level_inter_p(&self) -> Result<i32> {
+		crate::core::validity::check("EncoderParams", self.as_raw_EncoderParams())?;
+		Ok(EncoderParamsTraitConst::qp_level_inter_p(self))
+	}
+	
 	/// NVVE_QP_LEVEL_INTER_B,
 	#[inline]
 	fn qp_level_inter_b(&self) -> i32 {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_getPropQP_Level_InterB_const(self.as_raw_EncoderParams()) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_getPropQP_Level_InterB_const(self.as_raw_EncoderParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [qp_level_inter_b](EncoderParamsTraitConst::qp_level_inter_b), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_qp_level_inter_b(&self) -> Result<i32> {
+		crate::core::validity::check("EncoderParams", self.as_raw_EncoderParams())?;
+		Ok(EncoderParamsTraitConst::qp_level_inter_b(self))
+	}
+	
 	/// NVVE_DEBLOCK_MODE,
 	#[inline]
 	fn deblock_mode(&self) -> i32 {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_getPropDeblockMode_const(self.as_raw_EncoderParams()) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_getPropDeblockMode_const(self.as_raw_EncoderParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [deblock_mode](EncoderParamsTraitConst::deblock_mode), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_deblock_mode(&self) -> Result<i32> {
+		crate::core::validity::check("EncoderParams", self.as_raw_EncoderParams())?;
+		Ok(EncoderParamsTraitConst::deblock_mode(self))
+	}
+	
 	/// NVVE_PROFILE_LEVEL,
 	#[inline]
 	fn profile_level(&self) -> i32 {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_getPropProfileLevel_const(self.as_raw_EncoderParams()) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_getPropProfileLevel_const(self.as_raw_EncoderParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [profile_level](EncoderParamsTraitConst::profile_level), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_profile_level(&self) -> Result<i32> {
+		crate::core::validity::check("EncoderParams", self.as_raw_EncoderParams())?;
+		Ok(EncoderParamsTraitConst::profile_level(self))
+	}
+	
 	/// NVVE_FORCE_INTRA,
 	#[inline]
 	fn force_intra(&self) -> i32 {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_getPropForceIntra_const(self.as_raw_EncoderParams()) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_getPropForceIntra_const(self.as_raw_EncoderParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [force_intra](EncoderParamsTraitConst::force_intra), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_force_intra(&self) -> Result<i32> {
+		crate::core::validity::check("EncoderParams", self.as_raw_EncoderParams())?;
+		Ok(EncoderParamsTraitConst::force_intra(self))
+	}
+	
 	/// NVVE_FORCE_IDR,
 	#[inline]
 	fn force_idr(&self) -> i32 {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_getPropForceIDR_const(self.as_raw_EncoderParams()) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_getPropForceIDR_const(self.as_raw_EncoderParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [force_idr](EncoderParamsTraitConst::force_idr), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_force_idr(&self) -> Result<i32> {
+		crate::core::validity::check("EncoderParams", self.as_raw_EncoderParams())?;
+		Ok(EncoderParamsTraitConst::force_idr(self))
+	}
+	
 	/// NVVE_CLEAR_STAT,
 	#[inline]
 	fn clear_stat(&self) -> i32 {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_getPropClearStat_const(self.as_raw_EncoderParams()) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_getPropClearStat_const(self.as_raw_EncoderParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [clear_stat](EncoderParamsTraitConst::clear_stat), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_clear_stat(&self) -> Result<i32> {
+		crate::core::validity::check("EncoderParams", self.as_raw_EncoderParams())?;
+		Ok(EncoderParamsTraitConst::clear_stat(self))
+	}
+	
 	/// NVVE_SET_DEINTERLACE,
 	#[inline]
 	fn di_mode(&self) -> i32 {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_getPropDIMode_const(self.as_raw_EncoderParams()) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_getPropDIMode_const(self.as_raw_EncoderParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [di_mode](EncoderParamsTraitConst::di_mode), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_di_mode(&self) -> Result<i32> {
+		crate::core::validity::check("EncoderParams", self.as_raw_EncoderParams())?;
+		Ok(EncoderParamsTraitConst::di_mode(self))
+	}
+	
 	/// NVVE_PRESETS,
 	#[inline]
 	fn presets(&self) -> i32 {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_getPropPresets_const(self.as_raw_EncoderParams()) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_getPropPresets_const(self.as_raw_EncoderParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [presets](EncoderParamsTraitConst::presets), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_presets(&self) -> Result<i32> {
+		crate::core::validity::check("EncoderParams", self.as_raw_EncoderParams())?;
+		Ok(EncoderParamsTraitConst::presets(self))
+	}
+	
 	/// NVVE_DISABLE_CABAC,
 	#[inline]
 	fn disable_cabac(&self) -> i32 {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_getPropDisableCabac_const(self.as_raw_EncoderParams()) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_getPropDisableCabac_const(self.as_raw_EncoderParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [disable_cabac](EncoderParamsTraitConst::disable_cabac), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_disable_cabac(&self) -> Result<i32> {
+		crate::core::validity::check("EncoderParams", self.as_raw_EncoderParams())?;
+		Ok(EncoderParamsTraitConst::disable_cabac(self))
+	}
+	
 	/// NVVE_CONFIGURE_NALU_FRAMING_TYPE
 	#[inline]
 	fn nalu_framing_type(&self) -> i32 {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_getPropNaluFramingType_const(self.as_raw_EncoderParams()) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_getPropNaluFramingType_const(self.as_raw_EncoderParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [nalu_framing_type](EncoderParamsTraitConst::nalu_framing_type), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_nalu_framing_type(&self) -> Result<i32> {
+		crate::core::validity::check("EncoderParams", self.as_raw_EncoderParams())?;
+		Ok(EncoderParamsTraitConst::nalu_framing_type(self))
+	}
+	
 	/// NVVE_DISABLE_SPS_PPS
 	#[inline]
 	fn disable_spspps(&self) -> i32 {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_getPropDisableSPSPPS_const(self.as_raw_EncoderParams()) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_getPropDisableSPSPPS_const(self.as_raw_EncoderParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [disable_spspps](EncoderParamsTraitConst::disable_spspps), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_disable_spspps(&self) -> Result<i32> {
+		crate::core::validity::check("EncoderParams", self.as_raw_EncoderParams())?;
+		Ok(EncoderParamsTraitConst::disable_spspps(self))
+	}
+	
 	/// Saves parameters to config file.
 	/// 
 	/// ## Parameters
 	/// * configFile: Config file name.
 	#[inline]
 	fn save(&self, config_file: &str) -> Result<()> {
+		crate::core::validity::check("EncoderParams", self.as_raw_EncoderParams())?;
 		extern_container_arg!(config_file);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cudacodec_EncoderParams_save_const_const_StringR(self.as_raw_EncoderParams(), config_file.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cudacodec_EncoderParams_save_const_const_StringR(self.as_raw_EncoderParams(), config_file.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -566,133 +742,152 @@ pub trait EncoderParamsTrait: crate::cudacodec::EncoderParamsTraitConst {
 	/// NVVE_P_INTERVAL,
 	#[inline]
 	fn set_p_interval(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_setPropP_Interval_int(self.as_raw_mut_EncoderParams(), val) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_mut_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_setPropP_Interval_int(self.as_raw_mut_EncoderParams(), val)) };
 		ret
 	}
 	
 	/// NVVE_IDR_PERIOD,
 	#[inline]
 	fn set_idr_period(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_setPropIDR_Period_int(self.as_raw_mut_EncoderParams(), val) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_mut_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_setPropIDR_Period_int(self.as_raw_mut_EncoderParams(), val)) };
 		ret
 	}
 	
 	/// NVVE_DYNAMIC_GOP,
 	#[inline]
 	fn set_dynamic_gop(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_setPropDynamicGOP_int(self.as_raw_mut_EncoderParams(), val) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_mut_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_setPropDynamicGOP_int(self.as_raw_mut_EncoderParams(), val)) };
 		ret
 	}
 	
 	/// NVVE_RC_TYPE,
 	#[inline]
 	fn set_rc_type(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_setPropRCType_int(self.as_raw_mut_EncoderParams(), val) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_mut_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_setPropRCType_int(self.as_raw_mut_EncoderParams(), val)) };
 		ret
 	}
 	
 	/// NVVE_AVG_BITRATE,
 	#[inline]
 	fn set_avg_bitrate(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_setPropAvgBitrate_int(self.as_raw_mut_EncoderParams(), val) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_mut_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_setPropAvgBitrate_int(self.as_raw_mut_EncoderParams(), val)) };
 		ret
 	}
 	
 	/// NVVE_PEAK_BITRATE,
 	#[inline]
 	fn set_peak_bitrate(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_setPropPeakBitrate_int(self.as_raw_mut_EncoderParams(), val) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_mut_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_setPropPeakBitrate_int(self.as_raw_mut_EncoderParams(), val)) };
 		ret
 	}
 	
 	/// NVVE_QP_LEVEL_INTRA,
 	#[inline]
 	fn set_qp_level_intra(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_setPropQP_Level_Intra_int(self.as_raw_mut_EncoderParams(), val) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_mut_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_setPropQP_Level_Intra_int(self.as_raw_mut_EncoderParams(), val)) };
 		ret
 	}
 	
 	/// NVVE_QP_LEVEL_INTER_P,
 	#[inline]
 	fn set_qp_level_inter_p(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_setPropQP_Level_InterP_int(self.as_raw_mut_EncoderParams(), val) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_mut_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_setPropQP_Level_InterP_int(self.as_raw_mut_EncoderParams(), val)) };
 		ret
 	}
 	
 	/// NVVE_QP_LEVEL_INTER_B,
 	#[inline]
 	fn set_qp_level_inter_b(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_setPropQP_Level_InterB_int(self.as_raw_mut_EncoderParams(), val) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_mut_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_setPropQP_Level_InterB_int(self.as_raw_mut_EncoderParams(), val)) };
 		ret
 	}
 	
 	/// NVVE_DEBLOCK_MODE,
 	#[inline]
 	fn set_deblock_mode(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_setPropDeblockMode_int(self.as_raw_mut_EncoderParams(), val) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_mut_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_setPropDeblockMode_int(self.as_raw_mut_EncoderParams(), val)) };
 		ret
 	}
 	
 	/// NVVE_PROFILE_LEVEL,
 	#[inline]
 	fn set_profile_level(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_setPropProfileLevel_int(self.as_raw_mut_EncoderParams(), val) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_mut_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_setPropProfileLevel_int(self.as_raw_mut_EncoderParams(), val)) };
 		ret
 	}
 	
 	/// NVVE_FORCE_INTRA,
 	#[inline]
 	fn set_force_intra(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_setPropForceIntra_int(self.as_raw_mut_EncoderParams(), val) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_mut_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_setPropForceIntra_int(self.as_raw_mut_EncoderParams(), val)) };
 		ret
 	}
 	
 	/// NVVE_FORCE_IDR,
 	#[inline]
 	fn set_force_idr(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_setPropForceIDR_int(self.as_raw_mut_EncoderParams(), val) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_mut_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_setPropForceIDR_int(self.as_raw_mut_EncoderParams(), val)) };
 		ret
 	}
 	
 	/// NVVE_CLEAR_STAT,
 	#[inline]
 	fn set_clear_stat(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_setPropClearStat_int(self.as_raw_mut_EncoderParams(), val) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_mut_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_setPropClearStat_int(self.as_raw_mut_EncoderParams(), val)) };
 		ret
 	}
 	
 	/// NVVE_SET_DEINTERLACE,
 	#[inline]
 	fn set_di_mode(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_setPropDIMode_int(self.as_raw_mut_EncoderParams(), val) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_mut_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_setPropDIMode_int(self.as_raw_mut_EncoderParams(), val)) };
 		ret
 	}
 	
 	/// NVVE_PRESETS,
 	#[inline]
 	fn set_presets(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_setPropPresets_int(self.as_raw_mut_EncoderParams(), val) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_mut_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_setPropPresets_int(self.as_raw_mut_EncoderParams(), val)) };
 		ret
 	}
 	
 	/// NVVE_DISABLE_CABAC,
 	#[inline]
 	fn set_disable_cabac(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_setPropDisableCabac_int(self.as_raw_mut_EncoderParams(), val) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_mut_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_setPropDisableCabac_int(self.as_raw_mut_EncoderParams(), val)) };
 		ret
 	}
 	
 	/// NVVE_CONFIGURE_NALU_FRAMING_TYPE
 	#[inline]
 	fn set_nalu_framing_type(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_setPropNaluFramingType_int(self.as_raw_mut_EncoderParams(), val) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_mut_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_setPropNaluFramingType_int(self.as_raw_mut_EncoderParams(), val)) };
 		ret
 	}
 	
 	/// NVVE_DISABLE_SPS_PPS
 	#[inline]
 	fn set_disable_spspps(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_cudacodec_EncoderParams_setPropDisableSPSPPS_int(self.as_raw_mut_EncoderParams(), val) };
+		crate::core::validity::debug_check("EncoderParams", self.as_raw_mut_EncoderParams());
+		let ret = unsafe { ffi_call!(cv_cudacodec_EncoderParams_setPropDisableSPSPPS_int(self.as_raw_mut_EncoderParams(), val)) };
 		ret
 	}
 	
@@ -702,9 +897,10 @@ pub trait EncoderParamsTrait: crate::cudacodec::EncoderParamsTraitConst {
 	/// * configFile: Config file name.
 	#[inline]
 	fn load(&mut self, config_file: &str) -> Result<()> {
+		crate::core::validity::check("EncoderParams", self.as_raw_mut_EncoderParams())?;
 		extern_container_arg!(config_file);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cudacodec_EncoderParams_load_const_StringR(self.as_raw_mut_EncoderParams(), config_file.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cudacodec_EncoderParams_load_const_StringR(self.as_raw_mut_EncoderParams(), config_file.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -722,7 +918,10 @@ opencv_type_boxed! { EncoderParams }
 impl Drop for EncoderParams {
 	fn drop(&mut self) {
 		extern "C" { fn cv_EncoderParams_delete(instance: *mut c_void); }
-		unsafe { cv_EncoderParams_delete(self.as_raw_mut_EncoderParams()) };
+		let ptr = self.as_raw_mut_EncoderParams();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_EncoderParams_delete(ptr) };
 	}
 }
 
@@ -740,7 +939,7 @@ impl EncoderParams {
 	#[inline]
 	pub fn default() -> Result<crate::cudacodec::EncoderParams> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cudacodec_EncoderParams_EncoderParams(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cudacodec_EncoderParams_EncoderParams(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::cudacodec::EncoderParams::opencv_from_extern(ret) };
@@ -757,7 +956,7 @@ impl EncoderParams {
 	pub fn new(config_file: &str) -> Result<crate::cudacodec::EncoderParams> {
 		extern_container_arg!(config_file);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cudacodec_EncoderParams_EncoderParams_const_StringR(config_file.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cudacodec_EncoderParams_EncoderParams_const_StringR(config_file.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::cudacodec::EncoderParams::opencv_from_extern(ret) };
@@ -806,8 +1005,9 @@ pub trait RawVideoSourceConst {
 	/// Returns true if the last packet contained a key frame.
 	#[inline]
 	fn last_packet_contains_key_frame(&self) -> Result<bool> {
+		crate::core::validity::check("RawVideoSource", self.as_raw_RawVideoSource())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cudacodec_RawVideoSource_lastPacketContainsKeyFrame_const(self.as_raw_RawVideoSource(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cudacodec_RawVideoSource_lastPacketContainsKeyFrame_const(self.as_raw_RawVideoSource(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -816,8 +1016,9 @@ pub trait RawVideoSourceConst {
 	/// Returns information about video file format.
 	#[inline]
 	fn format(&self) -> Result<crate::cudacodec::FormatInfo> {
+		crate::core::validity::check("RawVideoSource", self.as_raw_RawVideoSource())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cudacodec_RawVideoSource_format_const(self.as_raw_RawVideoSource(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cudacodec_RawVideoSource_format_const(self.as_raw_RawVideoSource(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -829,8 +1030,9 @@ pub trait RawVideoSourceConst {
 	/// * extraData: 1D cv::Mat containing the extra data if it exists.
 	#[inline]
 	fn get_extra_data(&self, extra_data: &mut core::Mat) -> Result<()> {
+		crate::core::validity::check("RawVideoSource", self.as_raw_RawVideoSource())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cudacodec_RawVideoSource_getExtraData_const_MatR(self.as_raw_RawVideoSource(), extra_data.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cudacodec_RawVideoSource_getExtraData_const_MatR(self.as_raw_RawVideoSource(), extra_data.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -848,8 +1050,9 @@ pub trait RawVideoSource: crate::cudacodec::RawVideoSourceConst {
 	/// * size: Size in bytes of current frame.
 	#[inline]
 	fn get_next_packet(&mut self, data: &mut &mut u8, size: &mut size_t) -> Result<bool> {
+		crate::core::validity::check("RawVideoSource", self.as_raw_mut_RawVideoSource())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cudacodec_RawVideoSource_getNextPacket_unsigned_charXX_size_tX(self.as_raw_mut_RawVideoSource(), data as *mut  _ as *mut  *mut  _, size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cudacodec_RawVideoSource_getNextPacket_unsigned_charXX_size_tX(self.as_raw_mut_RawVideoSource(), data as *mut  _ as *mut  *mut  _, size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -858,8 +1061,9 @@ pub trait RawVideoSource: crate::cudacodec::RawVideoSourceConst {
 	/// Updates the coded width and height inside format.
 	#[inline]
 	fn update_format(&mut self, video_format: crate::cudacodec::FormatInfo) -> Result<()> {
+		crate::core::validity::check("RawVideoSource", self.as_raw_mut_RawVideoSource())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cudacodec_RawVideoSource_updateFormat_const_FormatInfoR(self.as_raw_mut_RawVideoSource(), &video_format, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cudacodec_RawVideoSource_updateFormat_const_FormatInfoR(self.as_raw_mut_RawVideoSource(), &video_format, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -879,8 +1083,9 @@ pub trait VideoReaderConst {
 	/// Returns information about video file format.
 	#[inline]
 	fn format(&self) -> Result<crate::cudacodec::FormatInfo> {
+		crate::core::validity::check("VideoReader", self.as_raw_VideoReader())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cudacodec_VideoReader_format_const(self.as_raw_VideoReader(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cudacodec_VideoReader_format_const(self.as_raw_VideoReader(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -889,7 +1094,7 @@ pub trait VideoReaderConst {
 	/// Returns previously grabbed video data.
 	/// 
 	/// ## Parameters
-	/// * frame:[out] The returned data which depends on the provided idx.  If there is no new data since the last call to grab() the image will be empty.
+	/// * frame:\[out\] The returned data which depends on the provided idx.  If there is no new data since the last call to grab() the image will be empty.
 	/// * idx: Determins the returned data inside image. The returned data can be the:
 	/// Decoded frame, idx = get(PROP_DECODED_FRAME_IDX).
 	/// Extra data if available, idx = get(PROP_EXTRA_DATA_INDEX).
@@ -904,9 +1109,10 @@ pub trait VideoReaderConst {
 	/// * idx: static_cast<size_t>(VideoReaderProps::PROP_DECODED_FRAME_IDX)
 	#[inline]
 	fn retrieve(&self, frame: &mut dyn core::ToOutputArray, idx: size_t) -> Result<bool> {
+		crate::core::validity::check("VideoReader", self.as_raw_VideoReader())?;
 		output_array_arg!(frame);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cudacodec_VideoReader_retrieve_const_const__OutputArrayR_const_size_t(self.as_raw_VideoReader(), frame.as_raw__OutputArray(), idx, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cudacodec_VideoReader_retrieve_const_const__OutputArrayR_const_size_t(self.as_raw_VideoReader(), frame.as_raw__OutputArray(), idx, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -924,8 +1130,9 @@ pub trait VideoReaderConst {
 	/// * property_val: -1
 	#[inline]
 	fn get(&self, property: crate::cudacodec::VideoReaderProps, property_val: i32) -> Result<i32> {
+		crate::core::validity::check("VideoReader", self.as_raw_VideoReader())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cudacodec_VideoReader_get_const_const_VideoReaderProps_const_int(self.as_raw_VideoReader(), property, property_val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cudacodec_VideoReader_get_const_const_VideoReaderProps_const_int(self.as_raw_VideoReader(), property, property_val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -945,8 +1152,9 @@ pub trait VideoReader: crate::cudacodec::VideoReaderConst {
 	/// * stream: Stream::Null()
 	#[inline]
 	fn next_frame(&mut self, frame: &mut core::GpuMat, stream: &mut core::Stream) -> Result<bool> {
+		crate::core::validity::check("VideoReader", self.as_raw_mut_VideoReader())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cudacodec_VideoReader_nextFrame_GpuMatR_StreamR(self.as_raw_mut_VideoReader(), frame.as_raw_mut_GpuMat(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cudacodec_VideoReader_nextFrame_GpuMatR_StreamR(self.as_raw_mut_VideoReader(), frame.as_raw_mut_GpuMat(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -967,8 +1175,9 @@ pub trait VideoReader: crate::cudacodec::VideoReaderConst {
 	/// * stream: Stream::Null()
 	#[inline]
 	fn grab(&mut self, stream: &mut core::Stream) -> Result<bool> {
+		crate::core::validity::check("VideoReader", self.as_raw_mut_VideoReader())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cudacodec_VideoReader_grab_StreamR(self.as_raw_mut_VideoReader(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cudacodec_VideoReader_grab_StreamR(self.as_raw_mut_VideoReader(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -983,8 +1192,9 @@ pub trait VideoReader: crate::cudacodec::VideoReaderConst {
 	/// `true` if the property has been set.
 	#[inline]
 	fn set(&mut self, property: crate::cudacodec::VideoReaderProps, property_val: f64) -> Result<bool> {
+		crate::core::validity::check("VideoReader", self.as_raw_mut_VideoReader())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cudacodec_VideoReader_set_const_VideoReaderProps_const_double(self.as_raw_mut_VideoReader(), property, property_val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cudacodec_VideoReader_set_const_VideoReaderProps_const_double(self.as_raw_mut_VideoReader(), property, property_val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1008,8 +1218,9 @@ pub trait VideoWriterConst {
 
 	#[inline]
 	fn get_encoder_params(&self) -> Result<crate::cudacodec::EncoderParams> {
+		crate::core::validity::check("VideoWriter", self.as_raw_VideoWriter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cudacodec_VideoWriter_getEncoderParams_const(self.as_raw_VideoWriter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cudacodec_VideoWriter_getEncoderParams_const(self.as_raw_VideoWriter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::cudacodec::EncoderParams::opencv_from_extern(ret) };
@@ -1034,9 +1245,10 @@ pub trait VideoWriter: crate::cudacodec::VideoWriterConst {
 	/// * last_frame: false
 	#[inline]
 	fn write(&mut self, frame: &dyn core::ToInputArray, last_frame: bool) -> Result<()> {
+		crate::core::validity::check("VideoWriter", self.as_raw_mut_VideoWriter())?;
 		input_array_arg!(frame);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cudacodec_VideoWriter_write_const__InputArrayR_bool(self.as_raw_mut_VideoWriter(), frame.as_raw__InputArray(), last_frame, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cudacodec_VideoWriter_write_const__InputArrayR_bool(self.as_raw_mut_VideoWriter(), frame.as_raw__InputArray(), last_frame, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
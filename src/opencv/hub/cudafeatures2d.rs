@@ -23,8 +23,9 @@ pub trait CUDA_DescriptorMatcherConst: core::AlgorithmTraitConst {
 	/// Returns true if the descriptor matcher supports masking permissible matches.
 	#[inline]
 	fn is_mask_supported(&self) -> Result<bool> {
+		crate::core::validity::check("CUDA_DescriptorMatcher", self.as_raw_CUDA_DescriptorMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DescriptorMatcher_isMaskSupported_const(self.as_raw_CUDA_DescriptorMatcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DescriptorMatcher_isMaskSupported_const(self.as_raw_CUDA_DescriptorMatcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -33,8 +34,9 @@ pub trait CUDA_DescriptorMatcherConst: core::AlgorithmTraitConst {
 	/// Returns a constant link to the train descriptor collection.
 	#[inline]
 	fn get_train_descriptors(&self) -> Result<core::Vector<core::GpuMat>> {
+		crate::core::validity::check("CUDA_DescriptorMatcher", self.as_raw_CUDA_DescriptorMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DescriptorMatcher_getTrainDescriptors_const(self.as_raw_CUDA_DescriptorMatcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DescriptorMatcher_getTrainDescriptors_const(self.as_raw_CUDA_DescriptorMatcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<core::GpuMat>::opencv_from_extern(ret) };
@@ -44,8 +46,9 @@ pub trait CUDA_DescriptorMatcherConst: core::AlgorithmTraitConst {
 	/// Returns true if there are no train descriptors in the collection.
 	#[inline]
 	fn empty(&self) -> Result<bool> {
+		crate::core::validity::check("CUDA_DescriptorMatcher", self.as_raw_CUDA_DescriptorMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DescriptorMatcher_empty_const(self.as_raw_CUDA_DescriptorMatcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DescriptorMatcher_empty_const(self.as_raw_CUDA_DescriptorMatcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -61,12 +64,13 @@ pub trait CUDA_DescriptorMatcher: core::AlgorithmTrait + crate::cudafeatures2d::
 	/// If the collection is not empty, the new descriptors are added to existing train descriptors.
 	/// 
 	/// ## Parameters
-	/// * descriptors: Descriptors to add. Each descriptors[i] is a set of descriptors from the same
+	/// * descriptors: Descriptors to add. Each descriptors\[i\] is a set of descriptors from the same
 	/// train image.
 	#[inline]
 	fn add(&mut self, descriptors: &core::Vector<core::GpuMat>) -> Result<()> {
+		crate::core::validity::check("CUDA_DescriptorMatcher", self.as_raw_mut_CUDA_DescriptorMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DescriptorMatcher_add_const_vector_GpuMat_R(self.as_raw_mut_CUDA_DescriptorMatcher(), descriptors.as_raw_VectorOfGpuMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DescriptorMatcher_add_const_vector_GpuMat_R(self.as_raw_mut_CUDA_DescriptorMatcher(), descriptors.as_raw_VectorOfGpuMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -75,8 +79,9 @@ pub trait CUDA_DescriptorMatcher: core::AlgorithmTrait + crate::cudafeatures2d::
 	/// Clears the train descriptor collection.
 	#[inline]
 	fn clear(&mut self) -> Result<()> {
+		crate::core::validity::check("CUDA_DescriptorMatcher", self.as_raw_mut_CUDA_DescriptorMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DescriptorMatcher_clear(self.as_raw_mut_CUDA_DescriptorMatcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DescriptorMatcher_clear(self.as_raw_mut_CUDA_DescriptorMatcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -88,8 +93,9 @@ pub trait CUDA_DescriptorMatcher: core::AlgorithmTrait + crate::cudafeatures2d::
 	/// train() is run every time before matching.
 	#[inline]
 	fn train(&mut self) -> Result<()> {
+		crate::core::validity::check("CUDA_DescriptorMatcher", self.as_raw_mut_CUDA_DescriptorMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DescriptorMatcher_train(self.as_raw_mut_CUDA_DescriptorMatcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DescriptorMatcher_train(self.as_raw_mut_CUDA_DescriptorMatcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -109,18 +115,19 @@ pub trait CUDA_DescriptorMatcher: core::AlgorithmTrait + crate::cudafeatures2d::
 	/// In the first variant of this method, the train descriptors are passed as an input argument. In the
 	/// second variant of the method, train descriptors collection that was set by DescriptorMatcher::add is
 	/// used. Optional mask (or masks) can be passed to specify which query and training descriptors can be
-	/// matched. Namely, queryDescriptors[i] can be matched with trainDescriptors[j] only if
+	/// matched. Namely, queryDescriptors\[i\] can be matched with trainDescriptors\[j\] only if
 	/// mask.at\<uchar\>(i,j) is non-zero.
 	/// 
 	/// ## C++ default parameters
 	/// * mask: noArray()
 	#[inline]
 	fn match_(&mut self, query_descriptors: &dyn core::ToInputArray, train_descriptors: &dyn core::ToInputArray, matches: &mut core::Vector<core::DMatch>, mask: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("CUDA_DescriptorMatcher", self.as_raw_mut_CUDA_DescriptorMatcher())?;
 		input_array_arg!(query_descriptors);
 		input_array_arg!(train_descriptors);
 		input_array_arg!(mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DescriptorMatcher_match_const__InputArrayR_const__InputArrayR_vector_DMatch_R_const__InputArrayR(self.as_raw_mut_CUDA_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), train_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfDMatch(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DescriptorMatcher_match_const__InputArrayR_const__InputArrayR_vector_DMatch_R_const__InputArrayR(self.as_raw_mut_CUDA_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), train_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfDMatch(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -140,7 +147,7 @@ pub trait CUDA_DescriptorMatcher: core::AlgorithmTrait + crate::cudafeatures2d::
 	/// In the first variant of this method, the train descriptors are passed as an input argument. In the
 	/// second variant of the method, train descriptors collection that was set by DescriptorMatcher::add is
 	/// used. Optional mask (or masks) can be passed to specify which query and training descriptors can be
-	/// matched. Namely, queryDescriptors[i] can be matched with trainDescriptors[j] only if
+	/// matched. Namely, queryDescriptors\[i\] can be matched with trainDescriptors\[j\] only if
 	/// mask.at\<uchar\>(i,j) is non-zero.
 	/// 
 	/// ## Overloaded parameters
@@ -149,9 +156,10 @@ pub trait CUDA_DescriptorMatcher: core::AlgorithmTrait + crate::cudafeatures2d::
 	/// * masks: std::vector<GpuMat>()
 	#[inline]
 	fn match__1(&mut self, query_descriptors: &dyn core::ToInputArray, matches: &mut core::Vector<core::DMatch>, masks: &core::Vector<core::GpuMat>) -> Result<()> {
+		crate::core::validity::check("CUDA_DescriptorMatcher", self.as_raw_mut_CUDA_DescriptorMatcher())?;
 		input_array_arg!(query_descriptors);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DescriptorMatcher_match_const__InputArrayR_vector_DMatch_R_const_vector_GpuMat_R(self.as_raw_mut_CUDA_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfDMatch(), masks.as_raw_VectorOfGpuMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DescriptorMatcher_match_const__InputArrayR_vector_DMatch_R_const_vector_GpuMat_R(self.as_raw_mut_CUDA_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfDMatch(), masks.as_raw_VectorOfGpuMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -172,7 +180,7 @@ pub trait CUDA_DescriptorMatcher: core::AlgorithmTrait + crate::cudafeatures2d::
 	/// In the first variant of this method, the train descriptors are passed as an input argument. In the
 	/// second variant of the method, train descriptors collection that was set by DescriptorMatcher::add is
 	/// used. Optional mask (or masks) can be passed to specify which query and training descriptors can be
-	/// matched. Namely, queryDescriptors[i] can be matched with trainDescriptors[j] only if
+	/// matched. Namely, queryDescriptors\[i\] can be matched with trainDescriptors\[j\] only if
 	/// mask.at\<uchar\>(i,j) is non-zero.
 	/// 
 	/// ## C++ default parameters
@@ -180,12 +188,13 @@ pub trait CUDA_DescriptorMatcher: core::AlgorithmTrait + crate::cudafeatures2d::
 	/// * stream: Stream::Null()
 	#[inline]
 	fn match_async(&mut self, query_descriptors: &dyn core::ToInputArray, train_descriptors: &dyn core::ToInputArray, matches: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CUDA_DescriptorMatcher", self.as_raw_mut_CUDA_DescriptorMatcher())?;
 		input_array_arg!(query_descriptors);
 		input_array_arg!(train_descriptors);
 		output_array_arg!(matches);
 		input_array_arg!(mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DescriptorMatcher_matchAsync_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(self.as_raw_mut_CUDA_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), train_descriptors.as_raw__InputArray(), matches.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DescriptorMatcher_matchAsync_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(self.as_raw_mut_CUDA_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), train_descriptors.as_raw__InputArray(), matches.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -206,7 +215,7 @@ pub trait CUDA_DescriptorMatcher: core::AlgorithmTrait + crate::cudafeatures2d::
 	/// In the first variant of this method, the train descriptors are passed as an input argument. In the
 	/// second variant of the method, train descriptors collection that was set by DescriptorMatcher::add is
 	/// used. Optional mask (or masks) can be passed to specify which query and training descriptors can be
-	/// matched. Namely, queryDescriptors[i] can be matched with trainDescriptors[j] only if
+	/// matched. Namely, queryDescriptors\[i\] can be matched with trainDescriptors\[j\] only if
 	/// mask.at\<uchar\>(i,j) is non-zero.
 	/// 
 	/// ## Overloaded parameters
@@ -216,10 +225,11 @@ pub trait CUDA_DescriptorMatcher: core::AlgorithmTrait + crate::cudafeatures2d::
 	/// * stream: Stream::Null()
 	#[inline]
 	fn match_async_1(&mut self, query_descriptors: &dyn core::ToInputArray, matches: &mut dyn core::ToOutputArray, masks: &core::Vector<core::GpuMat>, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CUDA_DescriptorMatcher", self.as_raw_mut_CUDA_DescriptorMatcher())?;
 		input_array_arg!(query_descriptors);
 		output_array_arg!(matches);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DescriptorMatcher_matchAsync_const__InputArrayR_const__OutputArrayR_const_vector_GpuMat_R_StreamR(self.as_raw_mut_CUDA_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), matches.as_raw__OutputArray(), masks.as_raw_VectorOfGpuMat(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DescriptorMatcher_matchAsync_const__InputArrayR_const__OutputArrayR_const_vector_GpuMat_R_StreamR(self.as_raw_mut_CUDA_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), matches.as_raw__OutputArray(), masks.as_raw_VectorOfGpuMat(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -235,9 +245,10 @@ pub trait CUDA_DescriptorMatcher: core::AlgorithmTrait + crate::cudafeatures2d::
 	/// * matches: Vector of DMatch objects.
 	#[inline]
 	fn match_convert(&mut self, gpu_matches: &dyn core::ToInputArray, matches: &mut core::Vector<core::DMatch>) -> Result<()> {
+		crate::core::validity::check("CUDA_DescriptorMatcher", self.as_raw_mut_CUDA_DescriptorMatcher())?;
 		input_array_arg!(gpu_matches);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DescriptorMatcher_matchConvert_const__InputArrayR_vector_DMatch_R(self.as_raw_mut_CUDA_DescriptorMatcher(), gpu_matches.as_raw__InputArray(), matches.as_raw_mut_VectorOfDMatch(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DescriptorMatcher_matchConvert_const__InputArrayR_vector_DMatch_R(self.as_raw_mut_CUDA_DescriptorMatcher(), gpu_matches.as_raw__InputArray(), matches.as_raw_mut_VectorOfDMatch(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -249,7 +260,7 @@ pub trait CUDA_DescriptorMatcher: core::AlgorithmTrait + crate::cudafeatures2d::
 	/// * queryDescriptors: Query set of descriptors.
 	/// * trainDescriptors: Train set of descriptors. This set is not added to the train descriptors
 	/// collection stored in the class object.
-	/// * matches: Matches. Each matches[i] is k or less matches for the same query descriptor.
+	/// * matches: Matches. Each matches\[i\] is k or less matches for the same query descriptor.
 	/// * k: Count of best matches found per each query descriptor or less if a query descriptor has
 	/// less than k possible matches in total.
 	/// * mask: Mask specifying permissible matches between an input query and train matrices of
@@ -267,11 +278,12 @@ pub trait CUDA_DescriptorMatcher: core::AlgorithmTrait + crate::cudafeatures2d::
 	/// * compact_result: false
 	#[inline]
 	fn knn_match(&mut self, query_descriptors: &dyn core::ToInputArray, train_descriptors: &dyn core::ToInputArray, matches: &mut core::Vector<core::Vector<core::DMatch>>, k: i32, mask: &dyn core::ToInputArray, compact_result: bool) -> Result<()> {
+		crate::core::validity::check("CUDA_DescriptorMatcher", self.as_raw_mut_CUDA_DescriptorMatcher())?;
 		input_array_arg!(query_descriptors);
 		input_array_arg!(train_descriptors);
 		input_array_arg!(mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DescriptorMatcher_knnMatch_const__InputArrayR_const__InputArrayR_vector_vector_DMatch__R_int_const__InputArrayR_bool(self.as_raw_mut_CUDA_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), train_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfVectorOfDMatch(), k, mask.as_raw__InputArray(), compact_result, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DescriptorMatcher_knnMatch_const__InputArrayR_const__InputArrayR_vector_vector_DMatch__R_int_const__InputArrayR_bool(self.as_raw_mut_CUDA_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), train_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfVectorOfDMatch(), k, mask.as_raw__InputArray(), compact_result, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -283,7 +295,7 @@ pub trait CUDA_DescriptorMatcher: core::AlgorithmTrait + crate::cudafeatures2d::
 	/// * queryDescriptors: Query set of descriptors.
 	/// * trainDescriptors: Train set of descriptors. This set is not added to the train descriptors
 	/// collection stored in the class object.
-	/// * matches: Matches. Each matches[i] is k or less matches for the same query descriptor.
+	/// * matches: Matches. Each matches\[i\] is k or less matches for the same query descriptor.
 	/// * k: Count of best matches found per each query descriptor or less if a query descriptor has
 	/// less than k possible matches in total.
 	/// * mask: Mask specifying permissible matches between an input query and train matrices of
@@ -303,9 +315,10 @@ pub trait CUDA_DescriptorMatcher: core::AlgorithmTrait + crate::cudafeatures2d::
 	/// * compact_result: false
 	#[inline]
 	fn knn_match_1(&mut self, query_descriptors: &dyn core::ToInputArray, matches: &mut core::Vector<core::Vector<core::DMatch>>, k: i32, masks: &core::Vector<core::GpuMat>, compact_result: bool) -> Result<()> {
+		crate::core::validity::check("CUDA_DescriptorMatcher", self.as_raw_mut_CUDA_DescriptorMatcher())?;
 		input_array_arg!(query_descriptors);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DescriptorMatcher_knnMatch_const__InputArrayR_vector_vector_DMatch__R_int_const_vector_GpuMat_R_bool(self.as_raw_mut_CUDA_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfVectorOfDMatch(), k, masks.as_raw_VectorOfGpuMat(), compact_result, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DescriptorMatcher_knnMatch_const__InputArrayR_vector_vector_DMatch__R_int_const_vector_GpuMat_R_bool(self.as_raw_mut_CUDA_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfVectorOfDMatch(), k, masks.as_raw_VectorOfGpuMat(), compact_result, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -334,12 +347,13 @@ pub trait CUDA_DescriptorMatcher: core::AlgorithmTrait + crate::cudafeatures2d::
 	/// * stream: Stream::Null()
 	#[inline]
 	fn knn_match_async(&mut self, query_descriptors: &dyn core::ToInputArray, train_descriptors: &dyn core::ToInputArray, matches: &mut dyn core::ToOutputArray, k: i32, mask: &dyn core::ToInputArray, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CUDA_DescriptorMatcher", self.as_raw_mut_CUDA_DescriptorMatcher())?;
 		input_array_arg!(query_descriptors);
 		input_array_arg!(train_descriptors);
 		output_array_arg!(matches);
 		input_array_arg!(mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DescriptorMatcher_knnMatchAsync_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_const__InputArrayR_StreamR(self.as_raw_mut_CUDA_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), train_descriptors.as_raw__InputArray(), matches.as_raw__OutputArray(), k, mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DescriptorMatcher_knnMatchAsync_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_const__InputArrayR_StreamR(self.as_raw_mut_CUDA_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), train_descriptors.as_raw__InputArray(), matches.as_raw__OutputArray(), k, mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -370,10 +384,11 @@ pub trait CUDA_DescriptorMatcher: core::AlgorithmTrait + crate::cudafeatures2d::
 	/// * stream: Stream::Null()
 	#[inline]
 	fn knn_match_async_1(&mut self, query_descriptors: &dyn core::ToInputArray, matches: &mut dyn core::ToOutputArray, k: i32, masks: &core::Vector<core::GpuMat>, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CUDA_DescriptorMatcher", self.as_raw_mut_CUDA_DescriptorMatcher())?;
 		input_array_arg!(query_descriptors);
 		output_array_arg!(matches);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DescriptorMatcher_knnMatchAsync_const__InputArrayR_const__OutputArrayR_int_const_vector_GpuMat_R_StreamR(self.as_raw_mut_CUDA_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), matches.as_raw__OutputArray(), k, masks.as_raw_VectorOfGpuMat(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DescriptorMatcher_knnMatchAsync_const__InputArrayR_const__OutputArrayR_int_const_vector_GpuMat_R_StreamR(self.as_raw_mut_CUDA_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), matches.as_raw__OutputArray(), k, masks.as_raw_VectorOfGpuMat(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -395,9 +410,10 @@ pub trait CUDA_DescriptorMatcher: core::AlgorithmTrait + crate::cudafeatures2d::
 	/// * compact_result: false
 	#[inline]
 	fn knn_match_convert(&mut self, gpu_matches: &dyn core::ToInputArray, matches: &mut core::Vector<core::Vector<core::DMatch>>, compact_result: bool) -> Result<()> {
+		crate::core::validity::check("CUDA_DescriptorMatcher", self.as_raw_mut_CUDA_DescriptorMatcher())?;
 		input_array_arg!(gpu_matches);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DescriptorMatcher_knnMatchConvert_const__InputArrayR_vector_vector_DMatch__R_bool(self.as_raw_mut_CUDA_DescriptorMatcher(), gpu_matches.as_raw__InputArray(), matches.as_raw_mut_VectorOfVectorOfDMatch(), compact_result, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DescriptorMatcher_knnMatchConvert_const__InputArrayR_vector_vector_DMatch__R_bool(self.as_raw_mut_CUDA_DescriptorMatcher(), gpu_matches.as_raw__InputArray(), matches.as_raw_mut_VectorOfVectorOfDMatch(), compact_result, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -428,11 +444,12 @@ pub trait CUDA_DescriptorMatcher: core::AlgorithmTrait + crate::cudafeatures2d::
 	/// * compact_result: false
 	#[inline]
 	fn radius_match(&mut self, query_descriptors: &dyn core::ToInputArray, train_descriptors: &dyn core::ToInputArray, matches: &mut core::Vector<core::Vector<core::DMatch>>, max_distance: f32, mask: &dyn core::ToInputArray, compact_result: bool) -> Result<()> {
+		crate::core::validity::check("CUDA_DescriptorMatcher", self.as_raw_mut_CUDA_DescriptorMatcher())?;
 		input_array_arg!(query_descriptors);
 		input_array_arg!(train_descriptors);
 		input_array_arg!(mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DescriptorMatcher_radiusMatch_const__InputArrayR_const__InputArrayR_vector_vector_DMatch__R_float_const__InputArrayR_bool(self.as_raw_mut_CUDA_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), train_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfVectorOfDMatch(), max_distance, mask.as_raw__InputArray(), compact_result, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DescriptorMatcher_radiusMatch_const__InputArrayR_const__InputArrayR_vector_vector_DMatch__R_float_const__InputArrayR_bool(self.as_raw_mut_CUDA_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), train_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfVectorOfDMatch(), max_distance, mask.as_raw__InputArray(), compact_result, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -465,9 +482,10 @@ pub trait CUDA_DescriptorMatcher: core::AlgorithmTrait + crate::cudafeatures2d::
 	/// * compact_result: false
 	#[inline]
 	fn radius_match_1(&mut self, query_descriptors: &dyn core::ToInputArray, matches: &mut core::Vector<core::Vector<core::DMatch>>, max_distance: f32, masks: &core::Vector<core::GpuMat>, compact_result: bool) -> Result<()> {
+		crate::core::validity::check("CUDA_DescriptorMatcher", self.as_raw_mut_CUDA_DescriptorMatcher())?;
 		input_array_arg!(query_descriptors);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DescriptorMatcher_radiusMatch_const__InputArrayR_vector_vector_DMatch__R_float_const_vector_GpuMat_R_bool(self.as_raw_mut_CUDA_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfVectorOfDMatch(), max_distance, masks.as_raw_VectorOfGpuMat(), compact_result, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DescriptorMatcher_radiusMatch_const__InputArrayR_vector_vector_DMatch__R_float_const_vector_GpuMat_R_bool(self.as_raw_mut_CUDA_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfVectorOfDMatch(), max_distance, masks.as_raw_VectorOfGpuMat(), compact_result, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -497,12 +515,13 @@ pub trait CUDA_DescriptorMatcher: core::AlgorithmTrait + crate::cudafeatures2d::
 	/// * stream: Stream::Null()
 	#[inline]
 	fn radius_match_async(&mut self, query_descriptors: &dyn core::ToInputArray, train_descriptors: &dyn core::ToInputArray, matches: &mut dyn core::ToOutputArray, max_distance: f32, mask: &dyn core::ToInputArray, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CUDA_DescriptorMatcher", self.as_raw_mut_CUDA_DescriptorMatcher())?;
 		input_array_arg!(query_descriptors);
 		input_array_arg!(train_descriptors);
 		output_array_arg!(matches);
 		input_array_arg!(mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DescriptorMatcher_radiusMatchAsync_const__InputArrayR_const__InputArrayR_const__OutputArrayR_float_const__InputArrayR_StreamR(self.as_raw_mut_CUDA_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), train_descriptors.as_raw__InputArray(), matches.as_raw__OutputArray(), max_distance, mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DescriptorMatcher_radiusMatchAsync_const__InputArrayR_const__InputArrayR_const__OutputArrayR_float_const__InputArrayR_StreamR(self.as_raw_mut_CUDA_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), train_descriptors.as_raw__InputArray(), matches.as_raw__OutputArray(), max_distance, mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -534,10 +553,11 @@ pub trait CUDA_DescriptorMatcher: core::AlgorithmTrait + crate::cudafeatures2d::
 	/// * stream: Stream::Null()
 	#[inline]
 	fn radius_match_async_1(&mut self, query_descriptors: &dyn core::ToInputArray, matches: &mut dyn core::ToOutputArray, max_distance: f32, masks: &core::Vector<core::GpuMat>, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CUDA_DescriptorMatcher", self.as_raw_mut_CUDA_DescriptorMatcher())?;
 		input_array_arg!(query_descriptors);
 		output_array_arg!(matches);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DescriptorMatcher_radiusMatchAsync_const__InputArrayR_const__OutputArrayR_float_const_vector_GpuMat_R_StreamR(self.as_raw_mut_CUDA_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), matches.as_raw__OutputArray(), max_distance, masks.as_raw_VectorOfGpuMat(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DescriptorMatcher_radiusMatchAsync_const__InputArrayR_const__OutputArrayR_float_const_vector_GpuMat_R_StreamR(self.as_raw_mut_CUDA_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), matches.as_raw__OutputArray(), max_distance, masks.as_raw_VectorOfGpuMat(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -559,9 +579,10 @@ pub trait CUDA_DescriptorMatcher: core::AlgorithmTrait + crate::cudafeatures2d::
 	/// * compact_result: false
 	#[inline]
 	fn radius_match_convert(&mut self, gpu_matches: &dyn core::ToInputArray, matches: &mut core::Vector<core::Vector<core::DMatch>>, compact_result: bool) -> Result<()> {
+		crate::core::validity::check("CUDA_DescriptorMatcher", self.as_raw_mut_CUDA_DescriptorMatcher())?;
 		input_array_arg!(gpu_matches);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DescriptorMatcher_radiusMatchConvert_const__InputArrayR_vector_vector_DMatch__R_bool(self.as_raw_mut_CUDA_DescriptorMatcher(), gpu_matches.as_raw__InputArray(), matches.as_raw_mut_VectorOfVectorOfDMatch(), compact_result, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DescriptorMatcher_radiusMatchConvert_const__InputArrayR_vector_vector_DMatch__R_bool(self.as_raw_mut_CUDA_DescriptorMatcher(), gpu_matches.as_raw__InputArray(), matches.as_raw_mut_VectorOfVectorOfDMatch(), compact_result, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -569,6 +590,160 @@ pub trait CUDA_DescriptorMatcher: core::AlgorithmTrait + crate::cudafeatures2d::
 	
 }
 
+/// Builder for `CUDA_DescriptorMatcher::knn_match()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CUDA_DescriptorMatcher_KnnMatchBuilder<'a, T: ?Sized + CUDA_DescriptorMatcher> {
+	instance: &'a mut T,
+	query_descriptors: &'a dyn core::ToInputArray,
+	train_descriptors: &'a dyn core::ToInputArray,
+	matches: &'a mut core::Vector<core::Vector<core::DMatch>>,
+	k: i32,
+	mask: Option<&'a dyn core::ToInputArray>,
+	compact_result: bool,
+}
+
+impl<'a, T: ?Sized + CUDA_DescriptorMatcher> CUDA_DescriptorMatcher_KnnMatchBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, query_descriptors: &'a dyn core::ToInputArray, train_descriptors: &'a dyn core::ToInputArray, matches: &'a mut core::Vector<core::Vector<core::DMatch>>, k: i32) -> Self {
+		Self { instance, query_descriptors, train_descriptors, matches, k, mask: None, compact_result: false }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_mask(mut self, mask: &'a dyn core::ToInputArray) -> Self {
+		self.mask = Some(mask);
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_compact_result(mut self, compact_result: bool) -> Self {
+		self.compact_result = compact_result;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let mask_no_array = core::no_array();
+		let mask: &dyn core::ToInputArray = match self.mask { Some(mask) => mask, None => &mask_no_array };
+		CUDA_DescriptorMatcher::knn_match(self.instance, self.query_descriptors, self.train_descriptors, self.matches, self.k, mask, self.compact_result)
+	}
+}
+
+/// Builder for `CUDA_DescriptorMatcher::knn_match_async()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CUDA_DescriptorMatcher_KnnMatchAsyncBuilder<'a, T: ?Sized + CUDA_DescriptorMatcher> {
+	instance: &'a mut T,
+	query_descriptors: &'a dyn core::ToInputArray,
+	train_descriptors: &'a dyn core::ToInputArray,
+	matches: &'a mut dyn core::ToOutputArray,
+	k: i32,
+	mask: Option<&'a dyn core::ToInputArray>,
+	stream: &'a mut core::Stream,
+}
+
+impl<'a, T: ?Sized + CUDA_DescriptorMatcher> CUDA_DescriptorMatcher_KnnMatchAsyncBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, query_descriptors: &'a dyn core::ToInputArray, train_descriptors: &'a dyn core::ToInputArray, matches: &'a mut dyn core::ToOutputArray, k: i32, stream: &'a mut core::Stream) -> Self {
+		Self { instance, query_descriptors, train_descriptors, matches, k, mask: None, stream }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_mask(mut self, mask: &'a dyn core::ToInputArray) -> Self {
+		self.mask = Some(mask);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let mask_no_array = core::no_array();
+		let mask: &dyn core::ToInputArray = match self.mask { Some(mask) => mask, None => &mask_no_array };
+		CUDA_DescriptorMatcher::knn_match_async(self.instance, self.query_descriptors, self.train_descriptors, self.matches, self.k, mask, self.stream)
+	}
+}
+
+/// Builder for `CUDA_DescriptorMatcher::radius_match()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CUDA_DescriptorMatcher_RadiusMatchBuilder<'a, T: ?Sized + CUDA_DescriptorMatcher> {
+	instance: &'a mut T,
+	query_descriptors: &'a dyn core::ToInputArray,
+	train_descriptors: &'a dyn core::ToInputArray,
+	matches: &'a mut core::Vector<core::Vector<core::DMatch>>,
+	max_distance: f32,
+	mask: Option<&'a dyn core::ToInputArray>,
+	compact_result: bool,
+}
+
+impl<'a, T: ?Sized + CUDA_DescriptorMatcher> CUDA_DescriptorMatcher_RadiusMatchBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, query_descriptors: &'a dyn core::ToInputArray, train_descriptors: &'a dyn core::ToInputArray, matches: &'a mut core::Vector<core::Vector<core::DMatch>>, max_distance: f32) -> Self {
+		Self { instance, query_descriptors, train_descriptors, matches, max_distance, mask: None, compact_result: false }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_mask(mut self, mask: &'a dyn core::ToInputArray) -> Self {
+		self.mask = Some(mask);
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_compact_result(mut self, compact_result: bool) -> Self {
+		self.compact_result = compact_result;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let mask_no_array = core::no_array();
+		let mask: &dyn core::ToInputArray = match self.mask { Some(mask) => mask, None => &mask_no_array };
+		CUDA_DescriptorMatcher::radius_match(self.instance, self.query_descriptors, self.train_descriptors, self.matches, self.max_distance, mask, self.compact_result)
+	}
+}
+
+/// Builder for `CUDA_DescriptorMatcher::radius_match_async()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CUDA_DescriptorMatcher_RadiusMatchAsyncBuilder<'a, T: ?Sized + CUDA_DescriptorMatcher> {
+	instance: &'a mut T,
+	query_descriptors: &'a dyn core::ToInputArray,
+	train_descriptors: &'a dyn core::ToInputArray,
+	matches: &'a mut dyn core::ToOutputArray,
+	max_distance: f32,
+	mask: Option<&'a dyn core::ToInputArray>,
+	stream: &'a mut core::Stream,
+}
+
+impl<'a, T: ?Sized + CUDA_DescriptorMatcher> CUDA_DescriptorMatcher_RadiusMatchAsyncBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, query_descriptors: &'a dyn core::ToInputArray, train_descriptors: &'a dyn core::ToInputArray, matches: &'a mut dyn core::ToOutputArray, max_distance: f32, stream: &'a mut core::Stream) -> Self {
+		Self { instance, query_descriptors, train_descriptors, matches, max_distance, mask: None, stream }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_mask(mut self, mask: &'a dyn core::ToInputArray) -> Self {
+		self.mask = Some(mask);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let mask_no_array = core::no_array();
+		let mask: &dyn core::ToInputArray = match self.mask { Some(mask) => mask, None => &mask_no_array };
+		CUDA_DescriptorMatcher::radius_match_async(self.instance, self.query_descriptors, self.train_descriptors, self.matches, self.max_distance, mask, self.stream)
+	}
+}
+
 impl dyn CUDA_DescriptorMatcher + '_ {
 	/// Brute-force descriptor matcher.
 	/// 
@@ -586,7 +761,7 @@ impl dyn CUDA_DescriptorMatcher + '_ {
 	#[inline]
 	pub fn create_bf_matcher(norm_type: i32) -> Result<core::Ptr<dyn crate::cudafeatures2d::CUDA_DescriptorMatcher>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DescriptorMatcher_createBFMatcher_int(norm_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DescriptorMatcher_createBFMatcher_int(norm_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::cudafeatures2d::CUDA_DescriptorMatcher>::opencv_from_extern(ret) };
@@ -600,8 +775,9 @@ pub trait CUDA_FastFeatureDetectorConst: crate::cudafeatures2d::CUDA_Feature2DAs
 
 	#[inline]
 	fn get_max_num_points(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_FastFeatureDetector", self.as_raw_CUDA_FastFeatureDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_FastFeatureDetector_getMaxNumPoints_const(self.as_raw_CUDA_FastFeatureDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_FastFeatureDetector_getMaxNumPoints_const(self.as_raw_CUDA_FastFeatureDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -614,8 +790,9 @@ pub trait CUDA_FastFeatureDetector: crate::cudafeatures2d::CUDA_FastFeatureDetec
 
 	#[inline]
 	fn set_threshold(&mut self, threshold: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_FastFeatureDetector", self.as_raw_mut_CUDA_FastFeatureDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_FastFeatureDetector_setThreshold_int(self.as_raw_mut_CUDA_FastFeatureDetector(), threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_FastFeatureDetector_setThreshold_int(self.as_raw_mut_CUDA_FastFeatureDetector(), threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -623,8 +800,9 @@ pub trait CUDA_FastFeatureDetector: crate::cudafeatures2d::CUDA_FastFeatureDetec
 	
 	#[inline]
 	fn set_max_num_points(&mut self, max_npoints: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_FastFeatureDetector", self.as_raw_mut_CUDA_FastFeatureDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_FastFeatureDetector_setMaxNumPoints_int(self.as_raw_mut_CUDA_FastFeatureDetector(), max_npoints, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_FastFeatureDetector_setMaxNumPoints_int(self.as_raw_mut_CUDA_FastFeatureDetector(), max_npoints, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -645,7 +823,7 @@ impl dyn CUDA_FastFeatureDetector + '_ {
 	#[inline]
 	pub fn create(threshold: i32, nonmax_suppression: bool, typ: i32, max_npoints: i32) -> Result<core::Ptr<dyn crate::cudafeatures2d::CUDA_FastFeatureDetector>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_FastFeatureDetector_create_int_bool_int_int(threshold, nonmax_suppression, typ, max_npoints, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_FastFeatureDetector_create_int_bool_int_int(threshold, nonmax_suppression, typ, max_npoints, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::cudafeatures2d::CUDA_FastFeatureDetector>::opencv_from_extern(ret) };
@@ -653,6 +831,50 @@ impl dyn CUDA_FastFeatureDetector + '_ {
 	}
 	
 }
+
+/// Builder for `<dyn CUDA_FastFeatureDetector>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CUDA_FastFeatureDetector_CreateBuilder {
+	threshold: i32,
+	nonmax_suppression: bool,
+	typ: i32,
+	max_npoints: i32,
+}
+
+impl CUDA_FastFeatureDetector_CreateBuilder {
+	#[inline]
+	pub fn new(typ: i32) -> Self {
+		Self { threshold: 10, nonmax_suppression: true, typ, max_npoints: 5000 }
+	}
+	
+	/// C++ default: `10`
+	#[inline]
+	pub fn with_threshold(mut self, threshold: i32) -> Self {
+		self.threshold = threshold;
+		self
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_nonmax_suppression(mut self, nonmax_suppression: bool) -> Self {
+		self.nonmax_suppression = nonmax_suppression;
+		self
+	}
+	
+	/// C++ default: `5000`
+	#[inline]
+	pub fn with_max_npoints(mut self, max_npoints: i32) -> Self {
+		self.max_npoints = max_npoints;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::cudafeatures2d::CUDA_FastFeatureDetector>> {
+		<dyn CUDA_FastFeatureDetector>::create(self.threshold, self.nonmax_suppression, self.typ, self.max_npoints)
+	}
+}
 /// Abstract base class for CUDA asynchronous 2D image feature detectors and descriptor extractors.
 pub trait CUDA_Feature2DAsyncConst: crate::features2d::Feature2DTraitConst {
 	fn as_raw_CUDA_Feature2DAsync(&self) -> *const c_void;
@@ -676,11 +898,12 @@ pub trait CUDA_Feature2DAsync: crate::cudafeatures2d::CUDA_Feature2DAsyncConst +
 	/// * stream: Stream::Null()
 	#[inline]
 	fn detect_async(&mut self, image: &dyn core::ToInputArray, keypoints: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CUDA_Feature2DAsync", self.as_raw_mut_CUDA_Feature2DAsync())?;
 		input_array_arg!(image);
 		output_array_arg!(keypoints);
 		input_array_arg!(mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_Feature2DAsync_detectAsync_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(self.as_raw_mut_CUDA_Feature2DAsync(), image.as_raw__InputArray(), keypoints.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_Feature2DAsync_detectAsync_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(self.as_raw_mut_CUDA_Feature2DAsync(), image.as_raw__InputArray(), keypoints.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -698,11 +921,12 @@ pub trait CUDA_Feature2DAsync: crate::cudafeatures2d::CUDA_Feature2DAsyncConst +
 	/// * stream: Stream::Null()
 	#[inline]
 	fn compute_async(&mut self, image: &dyn core::ToInputArray, keypoints: &mut dyn core::ToOutputArray, descriptors: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CUDA_Feature2DAsync", self.as_raw_mut_CUDA_Feature2DAsync())?;
 		input_array_arg!(image);
 		output_array_arg!(keypoints);
 		output_array_arg!(descriptors);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_Feature2DAsync_computeAsync_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_Feature2DAsync(), image.as_raw__InputArray(), keypoints.as_raw__OutputArray(), descriptors.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_Feature2DAsync_computeAsync_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_Feature2DAsync(), image.as_raw__InputArray(), keypoints.as_raw__OutputArray(), descriptors.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -715,12 +939,13 @@ pub trait CUDA_Feature2DAsync: crate::cudafeatures2d::CUDA_Feature2DAsyncConst +
 	/// * stream: Stream::Null()
 	#[inline]
 	fn detect_and_compute_async(&mut self, image: &dyn core::ToInputArray, mask: &dyn core::ToInputArray, keypoints: &mut dyn core::ToOutputArray, descriptors: &mut dyn core::ToOutputArray, use_provided_keypoints: bool, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CUDA_Feature2DAsync", self.as_raw_mut_CUDA_Feature2DAsync())?;
 		input_array_arg!(image);
 		input_array_arg!(mask);
 		output_array_arg!(keypoints);
 		output_array_arg!(descriptors);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_Feature2DAsync_detectAndComputeAsync_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_bool_StreamR(self.as_raw_mut_CUDA_Feature2DAsync(), image.as_raw__InputArray(), mask.as_raw__InputArray(), keypoints.as_raw__OutputArray(), descriptors.as_raw__OutputArray(), use_provided_keypoints, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_Feature2DAsync_detectAndComputeAsync_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_bool_StreamR(self.as_raw_mut_CUDA_Feature2DAsync(), image.as_raw__InputArray(), mask.as_raw__InputArray(), keypoints.as_raw__OutputArray(), descriptors.as_raw__OutputArray(), use_provided_keypoints, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -729,9 +954,10 @@ pub trait CUDA_Feature2DAsync: crate::cudafeatures2d::CUDA_Feature2DAsyncConst +
 	/// Converts keypoints array from internal representation to standard vector.
 	#[inline]
 	fn convert(&mut self, gpu_keypoints: &dyn core::ToInputArray, keypoints: &mut core::Vector<core::KeyPoint>) -> Result<()> {
+		crate::core::validity::check("CUDA_Feature2DAsync", self.as_raw_mut_CUDA_Feature2DAsync())?;
 		input_array_arg!(gpu_keypoints);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_Feature2DAsync_convert_const__InputArrayR_vector_KeyPoint_R(self.as_raw_mut_CUDA_Feature2DAsync(), gpu_keypoints.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfKeyPoint(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_Feature2DAsync_convert_const__InputArrayR_vector_KeyPoint_R(self.as_raw_mut_CUDA_Feature2DAsync(), gpu_keypoints.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfKeyPoint(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -739,16 +965,50 @@ pub trait CUDA_Feature2DAsync: crate::cudafeatures2d::CUDA_Feature2DAsyncConst +
 	
 }
 
+/// Builder for `CUDA_Feature2DAsync::detect_and_compute_async()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CUDA_Feature2DAsync_DetectAndComputeAsyncBuilder<'a, T: ?Sized + CUDA_Feature2DAsync> {
+	instance: &'a mut T,
+	image: &'a dyn core::ToInputArray,
+	mask: &'a dyn core::ToInputArray,
+	keypoints: &'a mut dyn core::ToOutputArray,
+	descriptors: &'a mut dyn core::ToOutputArray,
+	use_provided_keypoints: bool,
+	stream: &'a mut core::Stream,
+}
+
+impl<'a, T: ?Sized + CUDA_Feature2DAsync> CUDA_Feature2DAsync_DetectAndComputeAsyncBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, image: &'a dyn core::ToInputArray, mask: &'a dyn core::ToInputArray, keypoints: &'a mut dyn core::ToOutputArray, descriptors: &'a mut dyn core::ToOutputArray, stream: &'a mut core::Stream) -> Self {
+		Self { instance, image, mask, keypoints, descriptors, use_provided_keypoints: false, stream }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_use_provided_keypoints(mut self, use_provided_keypoints: bool) -> Self {
+		self.use_provided_keypoints = use_provided_keypoints;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		CUDA_Feature2DAsync::detect_and_compute_async(self.instance, self.image, self.mask, self.keypoints, self.descriptors, self.use_provided_keypoints, self.stream)
+	}
+}
+
 /// Class implementing the ORB (*oriented BRIEF*) keypoint detector and descriptor extractor
 /// ## See also
-/// cv::ORB
+/// `ORB`
 pub trait CUDA_ORBConst: crate::cudafeatures2d::CUDA_Feature2DAsyncConst {
 	fn as_raw_CUDA_ORB(&self) -> *const c_void;
 
 	#[inline]
 	fn get_blur_for_descriptor(&self) -> Result<bool> {
+		crate::core::validity::check("CUDA_ORB", self.as_raw_CUDA_ORB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_ORB_getBlurForDescriptor_const(self.as_raw_CUDA_ORB(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_ORB_getBlurForDescriptor_const(self.as_raw_CUDA_ORB(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -756,8 +1016,9 @@ pub trait CUDA_ORBConst: crate::cudafeatures2d::CUDA_Feature2DAsyncConst {
 	
 	#[inline]
 	fn get_fast_threshold(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_ORB", self.as_raw_CUDA_ORB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_ORB_getFastThreshold_const(self.as_raw_CUDA_ORB(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_ORB_getFastThreshold_const(self.as_raw_CUDA_ORB(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -771,8 +1032,9 @@ pub trait CUDA_ORB: crate::cudafeatures2d::CUDA_Feature2DAsync + crate::cudafeat
 	/// if true, image will be blurred before descriptors calculation
 	#[inline]
 	fn set_blur_for_descriptor(&mut self, blur_for_descriptor: bool) -> Result<()> {
+		crate::core::validity::check("CUDA_ORB", self.as_raw_mut_CUDA_ORB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_ORB_setBlurForDescriptor_bool(self.as_raw_mut_CUDA_ORB(), blur_for_descriptor, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_ORB_setBlurForDescriptor_bool(self.as_raw_mut_CUDA_ORB(), blur_for_descriptor, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -780,8 +1042,9 @@ pub trait CUDA_ORB: crate::cudafeatures2d::CUDA_Feature2DAsync + crate::cudafeat
 	
 	#[inline]
 	fn set_fast_threshold(&mut self, fast_threshold: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_ORB", self.as_raw_mut_CUDA_ORB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_ORB_setFastThreshold_int(self.as_raw_mut_CUDA_ORB(), fast_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_ORB_setFastThreshold_int(self.as_raw_mut_CUDA_ORB(), fast_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -811,11 +1074,103 @@ impl dyn CUDA_ORB + '_ {
 	#[inline]
 	pub fn create(nfeatures: i32, scale_factor: f32, nlevels: i32, edge_threshold: i32, first_level: i32, wta_k: i32, score_type: i32, patch_size: i32, fast_threshold: i32, blur_for_descriptor: bool) -> Result<core::Ptr<dyn crate::cudafeatures2d::CUDA_ORB>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_ORB_create_int_float_int_int_int_int_int_int_int_bool(nfeatures, scale_factor, nlevels, edge_threshold, first_level, wta_k, score_type, patch_size, fast_threshold, blur_for_descriptor, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_ORB_create_int_float_int_int_int_int_int_int_int_bool(nfeatures, scale_factor, nlevels, edge_threshold, first_level, wta_k, score_type, patch_size, fast_threshold, blur_for_descriptor, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::cudafeatures2d::CUDA_ORB>::opencv_from_extern(ret) };
 		Ok(ret)
 	}
 	
+}
+
+/// Builder for `<dyn CUDA_ORB>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CUDA_ORB_CreateBuilder {
+	nfeatures: i32,
+	scale_factor: f32,
+	nlevels: i32,
+	edge_threshold: i32,
+	first_level: i32,
+	wta_k: i32,
+	score_type: i32,
+	patch_size: i32,
+	fast_threshold: i32,
+	blur_for_descriptor: bool,
+}
+
+impl CUDA_ORB_CreateBuilder {
+	#[inline]
+	pub fn new(score_type: i32) -> Self {
+		Self { nfeatures: 500, scale_factor: 1.2, nlevels: 8, edge_threshold: 31, first_level: 0, wta_k: 2, score_type, patch_size: 31, fast_threshold: 20, blur_for_descriptor: false }
+	}
+	
+	/// C++ default: `500`
+	#[inline]
+	pub fn with_nfeatures(mut self, nfeatures: i32) -> Self {
+		self.nfeatures = nfeatures;
+		self
+	}
+	
+	/// C++ default: `1.2f`
+	#[inline]
+	pub fn with_scale_factor(mut self, scale_factor: f32) -> Self {
+		self.scale_factor = scale_factor;
+		self
+	}
+	
+	/// C++ default: `8`
+	#[inline]
+	pub fn with_nlevels(mut self, nlevels: i32) -> Self {
+		self.nlevels = nlevels;
+		self
+	}
+	
+	/// C++ default: `31`
+	#[inline]
+	pub fn with_edge_threshold(mut self, edge_threshold: i32) -> Self {
+		self.edge_threshold = edge_threshold;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_first_level(mut self, first_level: i32) -> Self {
+		self.first_level = first_level;
+		self
+	}
+	
+	/// C++ default: `2`
+	#[inline]
+	pub fn with_wta_k(mut self, wta_k: i32) -> Self {
+		self.wta_k = wta_k;
+		self
+	}
+	
+	/// C++ default: `31`
+	#[inline]
+	pub fn with_patch_size(mut self, patch_size: i32) -> Self {
+		self.patch_size = patch_size;
+		self
+	}
+	
+	/// C++ default: `20`
+	#[inline]
+	pub fn with_fast_threshold(mut self, fast_threshold: i32) -> Self {
+		self.fast_threshold = fast_threshold;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_blur_for_descriptor(mut self, blur_for_descriptor: bool) -> Self {
+		self.blur_for_descriptor = blur_for_descriptor;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::cudafeatures2d::CUDA_ORB>> {
+		<dyn CUDA_ORB>::create(self.nfeatures, self.scale_factor, self.nlevels, self.edge_threshold, self.first_level, self.wta_k, self.score_type, self.patch_size, self.fast_threshold, self.blur_for_descriptor)
+	}
 }
\ No newline at end of file
@@ -32,7 +32,7 @@ pub mod prelude {
 /// * borderMode: Pixel extrapolation method. For details, see borderInterpolate .
 /// * borderVal: Default border value.
 /// ## See also
-/// boxFilter
+/// `box_filter`
 /// 
 /// ## C++ default parameters
 /// * anchor: Point(-1,-1)
@@ -41,13 +41,52 @@ pub mod prelude {
 #[inline]
 pub fn create_box_filter(src_type: i32, dst_type: i32, ksize: core::Size, anchor: core::Point, border_mode: i32, border_val: core::Scalar) -> Result<core::Ptr<dyn crate::cudafilters::Filter>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createBoxFilter_int_int_Size_Point_int_Scalar(src_type, dst_type, ksize.opencv_as_extern(), anchor.opencv_as_extern(), border_mode, border_val.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createBoxFilter_int_int_Size_Point_int_Scalar(src_type, dst_type, ksize.opencv_as_extern(), anchor.opencv_as_extern(), border_mode, border_val.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudafilters::Filter>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_box_filter()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateBoxFilterBuilder {
+	src_type: i32,
+	dst_type: i32,
+	ksize: core::Size,
+	anchor: core::Point,
+	border_mode: i32,
+	border_val: core::Scalar,
+}
+
+impl CreateBoxFilterBuilder {
+	#[inline]
+	pub fn new(src_type: i32, dst_type: i32, ksize: core::Size, border_mode: i32) -> Self {
+		Self { src_type, dst_type, ksize, anchor: core::Point::new(-1, -1), border_mode, border_val: core::Scalar::all(0.) }
+	}
+	
+	/// C++ default: `Point(-1,-1)`
+	#[inline]
+	pub fn with_anchor(mut self, anchor: core::Point) -> Self {
+		self.anchor = anchor;
+		self
+	}
+	
+	/// C++ default: `Scalar::all(0)`
+	#[inline]
+	pub fn with_border_val(mut self, border_val: core::Scalar) -> Self {
+		self.border_val = border_val;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::cudafilters::Filter>> {
+		create_box_filter(self.src_type, self.dst_type, self.ksize, self.anchor, self.border_mode, self.border_val)
+	}
+}
+
 /// Creates the maximum filter.
 /// 
 /// ## Parameters
@@ -64,13 +103,51 @@ pub fn create_box_filter(src_type: i32, dst_type: i32, ksize: core::Size, anchor
 #[inline]
 pub fn create_box_max_filter(src_type: i32, ksize: core::Size, anchor: core::Point, border_mode: i32, border_val: core::Scalar) -> Result<core::Ptr<dyn crate::cudafilters::Filter>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createBoxMaxFilter_int_Size_Point_int_Scalar(src_type, ksize.opencv_as_extern(), anchor.opencv_as_extern(), border_mode, border_val.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createBoxMaxFilter_int_Size_Point_int_Scalar(src_type, ksize.opencv_as_extern(), anchor.opencv_as_extern(), border_mode, border_val.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudafilters::Filter>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_box_max_filter()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateBoxMaxFilterBuilder {
+	src_type: i32,
+	ksize: core::Size,
+	anchor: core::Point,
+	border_mode: i32,
+	border_val: core::Scalar,
+}
+
+impl CreateBoxMaxFilterBuilder {
+	#[inline]
+	pub fn new(src_type: i32, ksize: core::Size, border_mode: i32) -> Self {
+		Self { src_type, ksize, anchor: core::Point::new(-1, -1), border_mode, border_val: core::Scalar::all(0.) }
+	}
+	
+	/// C++ default: `Point(-1,-1)`
+	#[inline]
+	pub fn with_anchor(mut self, anchor: core::Point) -> Self {
+		self.anchor = anchor;
+		self
+	}
+	
+	/// C++ default: `Scalar::all(0)`
+	#[inline]
+	pub fn with_border_val(mut self, border_val: core::Scalar) -> Self {
+		self.border_val = border_val;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::cudafilters::Filter>> {
+		create_box_max_filter(self.src_type, self.ksize, self.anchor, self.border_mode, self.border_val)
+	}
+}
+
 /// Creates the minimum filter.
 /// 
 /// ## Parameters
@@ -87,13 +164,51 @@ pub fn create_box_max_filter(src_type: i32, ksize: core::Size, anchor: core::Poi
 #[inline]
 pub fn create_box_min_filter(src_type: i32, ksize: core::Size, anchor: core::Point, border_mode: i32, border_val: core::Scalar) -> Result<core::Ptr<dyn crate::cudafilters::Filter>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createBoxMinFilter_int_Size_Point_int_Scalar(src_type, ksize.opencv_as_extern(), anchor.opencv_as_extern(), border_mode, border_val.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createBoxMinFilter_int_Size_Point_int_Scalar(src_type, ksize.opencv_as_extern(), anchor.opencv_as_extern(), border_mode, border_val.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudafilters::Filter>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_box_min_filter()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateBoxMinFilterBuilder {
+	src_type: i32,
+	ksize: core::Size,
+	anchor: core::Point,
+	border_mode: i32,
+	border_val: core::Scalar,
+}
+
+impl CreateBoxMinFilterBuilder {
+	#[inline]
+	pub fn new(src_type: i32, ksize: core::Size, border_mode: i32) -> Self {
+		Self { src_type, ksize, anchor: core::Point::new(-1, -1), border_mode, border_val: core::Scalar::all(0.) }
+	}
+	
+	/// C++ default: `Point(-1,-1)`
+	#[inline]
+	pub fn with_anchor(mut self, anchor: core::Point) -> Self {
+		self.anchor = anchor;
+		self
+	}
+	
+	/// C++ default: `Scalar::all(0)`
+	#[inline]
+	pub fn with_border_val(mut self, border_val: core::Scalar) -> Self {
+		self.border_val = border_val;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::cudafilters::Filter>> {
+		create_box_min_filter(self.src_type, self.ksize, self.anchor, self.border_mode, self.border_val)
+	}
+}
+
 /// Creates a vertical 1D box filter.
 /// 
 /// ## Parameters
@@ -111,13 +226,52 @@ pub fn create_box_min_filter(src_type: i32, ksize: core::Size, anchor: core::Poi
 #[inline]
 pub fn create_column_sum_filter(src_type: i32, dst_type: i32, ksize: i32, anchor: i32, border_mode: i32, border_val: core::Scalar) -> Result<core::Ptr<dyn crate::cudafilters::Filter>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createColumnSumFilter_int_int_int_int_int_Scalar(src_type, dst_type, ksize, anchor, border_mode, border_val.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createColumnSumFilter_int_int_int_int_int_Scalar(src_type, dst_type, ksize, anchor, border_mode, border_val.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudafilters::Filter>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_column_sum_filter()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateColumnSumFilterBuilder {
+	src_type: i32,
+	dst_type: i32,
+	ksize: i32,
+	anchor: i32,
+	border_mode: i32,
+	border_val: core::Scalar,
+}
+
+impl CreateColumnSumFilterBuilder {
+	#[inline]
+	pub fn new(src_type: i32, dst_type: i32, ksize: i32, border_mode: i32) -> Self {
+		Self { src_type, dst_type, ksize, anchor: -1, border_mode, border_val: core::Scalar::all(0.) }
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_anchor(mut self, anchor: i32) -> Self {
+		self.anchor = anchor;
+		self
+	}
+	
+	/// C++ default: `Scalar::all(0)`
+	#[inline]
+	pub fn with_border_val(mut self, border_val: core::Scalar) -> Self {
+		self.border_val = border_val;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::cudafilters::Filter>> {
+		create_column_sum_filter(self.src_type, self.dst_type, self.ksize, self.anchor, self.border_mode, self.border_val)
+	}
+}
+
 /// Creates a generalized Deriv operator.
 /// 
 /// ## Parameters
@@ -142,13 +296,62 @@ pub fn create_column_sum_filter(src_type: i32, dst_type: i32, ksize: i32, anchor
 #[inline]
 pub fn create_deriv_filter(src_type: i32, dst_type: i32, dx: i32, dy: i32, ksize: i32, normalize: bool, scale: f64, row_border_mode: i32, column_border_mode: i32) -> Result<core::Ptr<dyn crate::cudafilters::Filter>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createDerivFilter_int_int_int_int_int_bool_double_int_int(src_type, dst_type, dx, dy, ksize, normalize, scale, row_border_mode, column_border_mode, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createDerivFilter_int_int_int_int_int_bool_double_int_int(src_type, dst_type, dx, dy, ksize, normalize, scale, row_border_mode, column_border_mode, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudafilters::Filter>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_deriv_filter()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateDerivFilterBuilder {
+	src_type: i32,
+	dst_type: i32,
+	dx: i32,
+	dy: i32,
+	ksize: i32,
+	normalize: bool,
+	scale: f64,
+	row_border_mode: i32,
+	column_border_mode: i32,
+}
+
+impl CreateDerivFilterBuilder {
+	#[inline]
+	pub fn new(src_type: i32, dst_type: i32, dx: i32, dy: i32, ksize: i32, row_border_mode: i32) -> Self {
+		Self { src_type, dst_type, dx, dy, ksize, normalize: false, scale: 1., row_border_mode, column_border_mode: -1 }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_normalize(mut self, normalize: bool) -> Self {
+		self.normalize = normalize;
+		self
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_scale(mut self, scale: f64) -> Self {
+		self.scale = scale;
+		self
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_column_border_mode(mut self, column_border_mode: i32) -> Self {
+		self.column_border_mode = column_border_mode;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::cudafilters::Filter>> {
+		create_deriv_filter(self.src_type, self.dst_type, self.dx, self.dy, self.ksize, self.normalize, self.scale, self.row_border_mode, self.column_border_mode)
+	}
+}
+
 /// Creates a Gaussian filter.
 /// 
 /// ## Parameters
@@ -162,7 +365,7 @@ pub fn create_deriv_filter(src_type: i32, dst_type: i32, dx: i32, dy: i32, ksize
 /// borderInterpolate.
 /// * columnBorderMode: Pixel extrapolation method in the horizontal direction.
 /// ## See also
-/// GaussianBlur
+/// `GaussianBlur`
 /// 
 /// ## C++ default parameters
 /// * sigma2: 0
@@ -171,13 +374,53 @@ pub fn create_deriv_filter(src_type: i32, dst_type: i32, dx: i32, dy: i32, ksize
 #[inline]
 pub fn create_gaussian_filter(src_type: i32, dst_type: i32, ksize: core::Size, sigma1: f64, sigma2: f64, row_border_mode: i32, column_border_mode: i32) -> Result<core::Ptr<dyn crate::cudafilters::Filter>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createGaussianFilter_int_int_Size_double_double_int_int(src_type, dst_type, ksize.opencv_as_extern(), sigma1, sigma2, row_border_mode, column_border_mode, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createGaussianFilter_int_int_Size_double_double_int_int(src_type, dst_type, ksize.opencv_as_extern(), sigma1, sigma2, row_border_mode, column_border_mode, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudafilters::Filter>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_gaussian_filter()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateGaussianFilterBuilder {
+	src_type: i32,
+	dst_type: i32,
+	ksize: core::Size,
+	sigma1: f64,
+	sigma2: f64,
+	row_border_mode: i32,
+	column_border_mode: i32,
+}
+
+impl CreateGaussianFilterBuilder {
+	#[inline]
+	pub fn new(src_type: i32, dst_type: i32, ksize: core::Size, sigma1: f64, row_border_mode: i32) -> Self {
+		Self { src_type, dst_type, ksize, sigma1, sigma2: 0., row_border_mode, column_border_mode: -1 }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_sigma2(mut self, sigma2: f64) -> Self {
+		self.sigma2 = sigma2;
+		self
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_column_border_mode(mut self, column_border_mode: i32) -> Self {
+		self.column_border_mode = column_border_mode;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::cudafilters::Filter>> {
+		create_gaussian_filter(self.src_type, self.dst_type, self.ksize, self.sigma1, self.sigma2, self.row_border_mode, self.column_border_mode)
+	}
+}
+
 /// Creates a Laplacian operator.
 /// 
 /// ## Parameters
@@ -190,7 +433,7 @@ pub fn create_gaussian_filter(src_type: i32, dst_type: i32, ksize: core::Size, s
 /// * borderMode: Pixel extrapolation method. For details, see borderInterpolate .
 /// * borderVal: Default border value.
 /// ## See also
-/// Laplacian
+/// `Laplacian`
 /// 
 /// ## C++ default parameters
 /// * ksize: 1
@@ -200,13 +443,59 @@ pub fn create_gaussian_filter(src_type: i32, dst_type: i32, ksize: core::Size, s
 #[inline]
 pub fn create_laplacian_filter(src_type: i32, dst_type: i32, ksize: i32, scale: f64, border_mode: i32, border_val: core::Scalar) -> Result<core::Ptr<dyn crate::cudafilters::Filter>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createLaplacianFilter_int_int_int_double_int_Scalar(src_type, dst_type, ksize, scale, border_mode, border_val.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createLaplacianFilter_int_int_int_double_int_Scalar(src_type, dst_type, ksize, scale, border_mode, border_val.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudafilters::Filter>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_laplacian_filter()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateLaplacianFilterBuilder {
+	src_type: i32,
+	dst_type: i32,
+	ksize: i32,
+	scale: f64,
+	border_mode: i32,
+	border_val: core::Scalar,
+}
+
+impl CreateLaplacianFilterBuilder {
+	#[inline]
+	pub fn new(src_type: i32, dst_type: i32, border_mode: i32) -> Self {
+		Self { src_type, dst_type, ksize: 1, scale: 1., border_mode, border_val: core::Scalar::all(0.) }
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_ksize(mut self, ksize: i32) -> Self {
+		self.ksize = ksize;
+		self
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_scale(mut self, scale: f64) -> Self {
+		self.scale = scale;
+		self
+	}
+	
+	/// C++ default: `Scalar::all(0)`
+	#[inline]
+	pub fn with_border_val(mut self, border_val: core::Scalar) -> Self {
+		self.border_val = border_val;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::cudafilters::Filter>> {
+		create_laplacian_filter(self.src_type, self.dst_type, self.ksize, self.scale, self.border_mode, self.border_val)
+	}
+}
+
 /// Creates a non-separable linear 2D filter.
 /// 
 /// ## Parameters
@@ -218,7 +507,7 @@ pub fn create_laplacian_filter(src_type: i32, dst_type: i32, ksize: i32, scale:
 /// * borderMode: Pixel extrapolation method. For details, see borderInterpolate .
 /// * borderVal: Default border value.
 /// ## See also
-/// filter2D
+/// `filter_2d`
 /// 
 /// ## C++ default parameters
 /// * anchor: Point(-1,-1)
@@ -228,13 +517,52 @@ pub fn create_laplacian_filter(src_type: i32, dst_type: i32, ksize: i32, scale:
 pub fn create_linear_filter(src_type: i32, dst_type: i32, kernel: &dyn core::ToInputArray, anchor: core::Point, border_mode: i32, border_val: core::Scalar) -> Result<core::Ptr<dyn crate::cudafilters::Filter>> {
 	input_array_arg!(kernel);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createLinearFilter_int_int_const__InputArrayR_Point_int_Scalar(src_type, dst_type, kernel.as_raw__InputArray(), anchor.opencv_as_extern(), border_mode, border_val.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createLinearFilter_int_int_const__InputArrayR_Point_int_Scalar(src_type, dst_type, kernel.as_raw__InputArray(), anchor.opencv_as_extern(), border_mode, border_val.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudafilters::Filter>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_linear_filter()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateLinearFilterBuilder<'a> {
+	src_type: i32,
+	dst_type: i32,
+	kernel: &'a dyn core::ToInputArray,
+	anchor: core::Point,
+	border_mode: i32,
+	border_val: core::Scalar,
+}
+
+impl<'a> CreateLinearFilterBuilder<'a> {
+	#[inline]
+	pub fn new(src_type: i32, dst_type: i32, kernel: &'a dyn core::ToInputArray, border_mode: i32) -> Self {
+		Self { src_type, dst_type, kernel, anchor: core::Point::new(-1, -1), border_mode, border_val: core::Scalar::all(0.) }
+	}
+	
+	/// C++ default: `Point(-1,-1)`
+	#[inline]
+	pub fn with_anchor(mut self, anchor: core::Point) -> Self {
+		self.anchor = anchor;
+		self
+	}
+	
+	/// C++ default: `Scalar::all(0)`
+	#[inline]
+	pub fn with_border_val(mut self, border_val: core::Scalar) -> Self {
+		self.border_val = border_val;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::cudafilters::Filter>> {
+		create_linear_filter(self.src_type, self.dst_type, self.kernel, self.anchor, self.border_mode, self.border_val)
+	}
+}
+
 /// Performs median filtering for each point of the source image.
 /// 
 /// ## Parameters
@@ -253,7 +581,7 @@ pub fn create_linear_filter(src_type: i32, dst_type: i32, kernel: &dyn core::ToI
 #[inline]
 pub fn create_median_filter(src_type: i32, window_size: i32, partition: i32) -> Result<core::Ptr<dyn crate::cudafilters::Filter>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createMedianFilter_int_int_int(src_type, window_size, partition, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createMedianFilter_int_int_int(src_type, window_size, partition, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudafilters::Filter>::opencv_from_extern(ret) };
@@ -277,7 +605,7 @@ pub fn create_median_filter(src_type: i32, window_size: i32, partition: i32) ->
 /// is at the center.
 /// * iterations: Number of times erosion and dilation to be applied.
 /// ## See also
-/// morphologyEx
+/// `morphology_ex`
 /// 
 /// ## C++ default parameters
 /// * anchor: Point(-1,-1)
@@ -286,7 +614,7 @@ pub fn create_median_filter(src_type: i32, window_size: i32, partition: i32) ->
 pub fn create_morphology_filter(op: i32, src_type: i32, kernel: &dyn core::ToInputArray, anchor: core::Point, iterations: i32) -> Result<core::Ptr<dyn crate::cudafilters::Filter>> {
 	input_array_arg!(kernel);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createMorphologyFilter_int_int_const__InputArrayR_Point_int(op, src_type, kernel.as_raw__InputArray(), anchor.opencv_as_extern(), iterations, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createMorphologyFilter_int_int_const__InputArrayR_Point_int(op, src_type, kernel.as_raw__InputArray(), anchor.opencv_as_extern(), iterations, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudafilters::Filter>::opencv_from_extern(ret) };
@@ -310,13 +638,52 @@ pub fn create_morphology_filter(op: i32, src_type: i32, kernel: &dyn core::ToInp
 #[inline]
 pub fn create_row_sum_filter(src_type: i32, dst_type: i32, ksize: i32, anchor: i32, border_mode: i32, border_val: core::Scalar) -> Result<core::Ptr<dyn crate::cudafilters::Filter>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createRowSumFilter_int_int_int_int_int_Scalar(src_type, dst_type, ksize, anchor, border_mode, border_val.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createRowSumFilter_int_int_int_int_int_Scalar(src_type, dst_type, ksize, anchor, border_mode, border_val.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudafilters::Filter>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_row_sum_filter()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateRowSumFilterBuilder {
+	src_type: i32,
+	dst_type: i32,
+	ksize: i32,
+	anchor: i32,
+	border_mode: i32,
+	border_val: core::Scalar,
+}
+
+impl CreateRowSumFilterBuilder {
+	#[inline]
+	pub fn new(src_type: i32, dst_type: i32, ksize: i32, border_mode: i32) -> Self {
+		Self { src_type, dst_type, ksize, anchor: -1, border_mode, border_val: core::Scalar::all(0.) }
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_anchor(mut self, anchor: i32) -> Self {
+		self.anchor = anchor;
+		self
+	}
+	
+	/// C++ default: `Scalar::all(0)`
+	#[inline]
+	pub fn with_border_val(mut self, border_val: core::Scalar) -> Self {
+		self.border_val = border_val;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::cudafilters::Filter>> {
+		create_row_sum_filter(self.src_type, self.dst_type, self.ksize, self.anchor, self.border_mode, self.border_val)
+	}
+}
+
 /// Creates a vertical or horizontal Scharr operator.
 /// 
 /// ## Parameters
@@ -330,7 +697,7 @@ pub fn create_row_sum_filter(src_type: i32, dst_type: i32, ksize: i32, anchor: i
 /// borderInterpolate.
 /// * columnBorderMode: Pixel extrapolation method in the horizontal direction.
 /// ## See also
-/// Scharr
+/// `Scharr`
 /// 
 /// ## C++ default parameters
 /// * scale: 1
@@ -339,13 +706,53 @@ pub fn create_row_sum_filter(src_type: i32, dst_type: i32, ksize: i32, anchor: i
 #[inline]
 pub fn create_scharr_filter(src_type: i32, dst_type: i32, dx: i32, dy: i32, scale: f64, row_border_mode: i32, column_border_mode: i32) -> Result<core::Ptr<dyn crate::cudafilters::Filter>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createScharrFilter_int_int_int_int_double_int_int(src_type, dst_type, dx, dy, scale, row_border_mode, column_border_mode, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createScharrFilter_int_int_int_int_double_int_int(src_type, dst_type, dx, dy, scale, row_border_mode, column_border_mode, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudafilters::Filter>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_scharr_filter()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateScharrFilterBuilder {
+	src_type: i32,
+	dst_type: i32,
+	dx: i32,
+	dy: i32,
+	scale: f64,
+	row_border_mode: i32,
+	column_border_mode: i32,
+}
+
+impl CreateScharrFilterBuilder {
+	#[inline]
+	pub fn new(src_type: i32, dst_type: i32, dx: i32, dy: i32, row_border_mode: i32) -> Self {
+		Self { src_type, dst_type, dx, dy, scale: 1., row_border_mode, column_border_mode: -1 }
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_scale(mut self, scale: f64) -> Self {
+		self.scale = scale;
+		self
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_column_border_mode(mut self, column_border_mode: i32) -> Self {
+		self.column_border_mode = column_border_mode;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::cudafilters::Filter>> {
+		create_scharr_filter(self.src_type, self.dst_type, self.dx, self.dy, self.scale, self.row_border_mode, self.column_border_mode)
+	}
+}
+
 /// Creates a separable linear filter.
 /// 
 /// ## Parameters
@@ -359,7 +766,7 @@ pub fn create_scharr_filter(src_type: i32, dst_type: i32, dx: i32, dy: i32, scal
 /// borderInterpolate.
 /// * columnBorderMode: Pixel extrapolation method in the horizontal direction.
 /// ## See also
-/// sepFilter2D
+/// `sep_filter_2d`
 /// 
 /// ## C++ default parameters
 /// * anchor: Point(-1,-1)
@@ -370,13 +777,53 @@ pub fn create_separable_linear_filter(src_type: i32, dst_type: i32, row_kernel:
 	input_array_arg!(row_kernel);
 	input_array_arg!(column_kernel);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createSeparableLinearFilter_int_int_const__InputArrayR_const__InputArrayR_Point_int_int(src_type, dst_type, row_kernel.as_raw__InputArray(), column_kernel.as_raw__InputArray(), anchor.opencv_as_extern(), row_border_mode, column_border_mode, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createSeparableLinearFilter_int_int_const__InputArrayR_const__InputArrayR_Point_int_int(src_type, dst_type, row_kernel.as_raw__InputArray(), column_kernel.as_raw__InputArray(), anchor.opencv_as_extern(), row_border_mode, column_border_mode, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudafilters::Filter>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_separable_linear_filter()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateSeparableLinearFilterBuilder<'a> {
+	src_type: i32,
+	dst_type: i32,
+	row_kernel: &'a dyn core::ToInputArray,
+	column_kernel: &'a dyn core::ToInputArray,
+	anchor: core::Point,
+	row_border_mode: i32,
+	column_border_mode: i32,
+}
+
+impl<'a> CreateSeparableLinearFilterBuilder<'a> {
+	#[inline]
+	pub fn new(src_type: i32, dst_type: i32, row_kernel: &'a dyn core::ToInputArray, column_kernel: &'a dyn core::ToInputArray, row_border_mode: i32) -> Self {
+		Self { src_type, dst_type, row_kernel, column_kernel, anchor: core::Point::new(-1, -1), row_border_mode, column_border_mode: -1 }
+	}
+	
+	/// C++ default: `Point(-1,-1)`
+	#[inline]
+	pub fn with_anchor(mut self, anchor: core::Point) -> Self {
+		self.anchor = anchor;
+		self
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_column_border_mode(mut self, column_border_mode: i32) -> Self {
+		self.column_border_mode = column_border_mode;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::cudafilters::Filter>> {
+		create_separable_linear_filter(self.src_type, self.dst_type, self.row_kernel, self.column_kernel, self.anchor, self.row_border_mode, self.column_border_mode)
+	}
+}
+
 /// Creates a Sobel operator.
 /// 
 /// ## Parameters
@@ -391,7 +838,7 @@ pub fn create_separable_linear_filter(src_type: i32, dst_type: i32, row_kernel:
 /// borderInterpolate.
 /// * columnBorderMode: Pixel extrapolation method in the horizontal direction.
 /// ## See also
-/// Sobel
+/// `Sobel`
 /// 
 /// ## C++ default parameters
 /// * ksize: 3
@@ -401,13 +848,61 @@ pub fn create_separable_linear_filter(src_type: i32, dst_type: i32, row_kernel:
 #[inline]
 pub fn create_sobel_filter(src_type: i32, dst_type: i32, dx: i32, dy: i32, ksize: i32, scale: f64, row_border_mode: i32, column_border_mode: i32) -> Result<core::Ptr<dyn crate::cudafilters::Filter>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createSobelFilter_int_int_int_int_int_double_int_int(src_type, dst_type, dx, dy, ksize, scale, row_border_mode, column_border_mode, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createSobelFilter_int_int_int_int_int_double_int_int(src_type, dst_type, dx, dy, ksize, scale, row_border_mode, column_border_mode, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudafilters::Filter>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_sobel_filter()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateSobelFilterBuilder {
+	src_type: i32,
+	dst_type: i32,
+	dx: i32,
+	dy: i32,
+	ksize: i32,
+	scale: f64,
+	row_border_mode: i32,
+	column_border_mode: i32,
+}
+
+impl CreateSobelFilterBuilder {
+	#[inline]
+	pub fn new(src_type: i32, dst_type: i32, dx: i32, dy: i32, row_border_mode: i32) -> Self {
+		Self { src_type, dst_type, dx, dy, ksize: 3, scale: 1., row_border_mode, column_border_mode: -1 }
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_ksize(mut self, ksize: i32) -> Self {
+		self.ksize = ksize;
+		self
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_scale(mut self, scale: f64) -> Self {
+		self.scale = scale;
+		self
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_column_border_mode(mut self, column_border_mode: i32) -> Self {
+		self.column_border_mode = column_border_mode;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::cudafilters::Filter>> {
+		create_sobel_filter(self.src_type, self.dst_type, self.dx, self.dy, self.ksize, self.scale, self.row_border_mode, self.column_border_mode)
+	}
+}
+
 /// Common interface for all CUDA filters :
 pub trait FilterConst: core::AlgorithmTraitConst {
 	fn as_raw_Filter(&self) -> *const c_void;
@@ -428,10 +923,11 @@ pub trait Filter: core::AlgorithmTrait + crate::cudafilters::FilterConst {
 	/// * stream: Stream::Null()
 	#[inline]
 	fn apply(&mut self, src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("Filter", self.as_raw_mut_Filter())?;
 		input_array_arg!(src);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_Filter_apply_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_Filter(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_Filter_apply_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_Filter(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -140,7 +140,7 @@ pub fn alpha_comp(img1: &dyn core::ToInputArray, img2: &dyn core::ToInputArray,
 	input_array_arg!(img2);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_alphaComp_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_StreamR(img1.as_raw__InputArray(), img2.as_raw__InputArray(), dst.as_raw__OutputArray(), alpha_op, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_alphaComp_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_StreamR(img1.as_raw__InputArray(), img2.as_raw__InputArray(), dst.as_raw__OutputArray(), alpha_op, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -159,7 +159,7 @@ pub fn alpha_comp(img1: &dyn core::ToInputArray, img2: &dyn core::ToInputArray,
 /// BORDER_REPLICATE , BORDER_CONSTANT , BORDER_REFLECT and BORDER_WRAP are supported for now.
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// bilateralFilter
+/// [bilateral_filter](crate::cudaimgproc::bilateral_filter)
 /// 
 /// ## C++ default parameters
 /// * border_mode: BORDER_DEFAULT
@@ -169,7 +169,7 @@ pub fn bilateral_filter(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutp
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_bilateralFilter_const__InputArrayR_const__OutputArrayR_int_float_float_int_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), kernel_size, sigma_color, sigma_spatial, border_mode, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_bilateralFilter_const__InputArrayR_const__OutputArrayR_int_float_float_int_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), kernel_size, sigma_color, sigma_spatial, border_mode, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -197,7 +197,7 @@ pub fn blend_linear(img1: &dyn core::ToInputArray, img2: &dyn core::ToInputArray
 	input_array_arg!(weights2);
 	output_array_arg!(result);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_blendLinear_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_StreamR(img1.as_raw__InputArray(), img2.as_raw__InputArray(), weights1.as_raw__InputArray(), weights2.as_raw__InputArray(), result.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_blendLinear_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_StreamR(img1.as_raw__InputArray(), img2.as_raw__InputArray(), weights1.as_raw__InputArray(), weights2.as_raw__InputArray(), result.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -219,7 +219,7 @@ pub fn calc_hist_1(src: &dyn core::ToInputArray, mask: &dyn core::ToInputArray,
 	input_array_arg!(mask);
 	output_array_arg!(hist);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_calcHist_const__InputArrayR_const__InputArrayR_const__OutputArrayR_StreamR(src.as_raw__InputArray(), mask.as_raw__InputArray(), hist.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_calcHist_const__InputArrayR_const__InputArrayR_const__OutputArrayR_StreamR(src.as_raw__InputArray(), mask.as_raw__InputArray(), hist.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -239,7 +239,7 @@ pub fn calc_hist(src: &dyn core::ToInputArray, hist: &mut dyn core::ToOutputArra
 	input_array_arg!(src);
 	output_array_arg!(hist);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_calcHist_const__InputArrayR_const__OutputArrayR_StreamR(src.as_raw__InputArray(), hist.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_calcHist_const__InputArrayR_const__OutputArrayR_StreamR(src.as_raw__InputArray(), hist.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -258,7 +258,7 @@ pub fn calc_hist(src: &dyn core::ToInputArray, hist: &mut dyn core::ToOutputArra
 #[inline]
 pub fn create_clahe(clip_limit: f64, tile_grid_size: core::Size) -> Result<core::Ptr<dyn crate::cudaimgproc::CUDA_CLAHE>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createCLAHE_double_Size(clip_limit, tile_grid_size.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createCLAHE_double_Size(clip_limit, tile_grid_size.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudaimgproc::CUDA_CLAHE>::opencv_from_extern(ret) };
@@ -282,7 +282,7 @@ pub fn create_clahe(clip_limit: f64, tile_grid_size: core::Size) -> Result<core:
 #[inline]
 pub fn create_canny_edge_detector(low_thresh: f64, high_thresh: f64, apperture_size: i32, l2gradient: bool) -> Result<core::Ptr<dyn crate::cudaimgproc::CUDA_CannyEdgeDetector>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createCannyEdgeDetector_double_double_int_bool(low_thresh, high_thresh, apperture_size, l2gradient, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createCannyEdgeDetector_double_double_int_bool(low_thresh, high_thresh, apperture_size, l2gradient, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudaimgproc::CUDA_CannyEdgeDetector>::opencv_from_extern(ret) };
@@ -293,7 +293,7 @@ pub fn create_canny_edge_detector(low_thresh: f64, high_thresh: f64, apperture_s
 #[inline]
 pub fn create_generalized_hough_ballard() -> Result<core::Ptr<dyn crate::imgproc::GeneralizedHoughBallard>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createGeneralizedHoughBallard(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createGeneralizedHoughBallard(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::imgproc::GeneralizedHoughBallard>::opencv_from_extern(ret) };
@@ -304,7 +304,7 @@ pub fn create_generalized_hough_ballard() -> Result<core::Ptr<dyn crate::imgproc
 #[inline]
 pub fn create_generalized_hough_guil() -> Result<core::Ptr<dyn crate::imgproc::GeneralizedHoughGuil>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createGeneralizedHoughGuil(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createGeneralizedHoughGuil(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::imgproc::GeneralizedHoughGuil>::opencv_from_extern(ret) };
@@ -340,13 +340,81 @@ pub fn create_generalized_hough_guil() -> Result<core::Ptr<dyn crate::imgproc::G
 #[inline]
 pub fn create_good_features_to_track_detector(src_type: i32, max_corners: i32, quality_level: f64, min_distance: f64, block_size: i32, use_harris_detector: bool, harris_k: f64) -> Result<core::Ptr<dyn crate::cudaimgproc::CUDA_CornersDetector>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createGoodFeaturesToTrackDetector_int_int_double_double_int_bool_double(src_type, max_corners, quality_level, min_distance, block_size, use_harris_detector, harris_k, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createGoodFeaturesToTrackDetector_int_int_double_double_int_bool_double(src_type, max_corners, quality_level, min_distance, block_size, use_harris_detector, harris_k, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudaimgproc::CUDA_CornersDetector>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_good_features_to_track_detector()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateGoodFeaturesToTrackDetectorBuilder {
+	src_type: i32,
+	max_corners: i32,
+	quality_level: f64,
+	min_distance: f64,
+	block_size: i32,
+	use_harris_detector: bool,
+	harris_k: f64,
+}
+
+impl CreateGoodFeaturesToTrackDetectorBuilder {
+	#[inline]
+	pub fn new(src_type: i32) -> Self {
+		Self { src_type, max_corners: 1000, quality_level: 0.01, min_distance: 0.0, block_size: 3, use_harris_detector: false, harris_k: 0.04 }
+	}
+	
+	/// C++ default: `1000`
+	#[inline]
+	pub fn with_max_corners(mut self, max_corners: i32) -> Self {
+		self.max_corners = max_corners;
+		self
+	}
+	
+	/// C++ default: `0.01`
+	#[inline]
+	pub fn with_quality_level(mut self, quality_level: f64) -> Self {
+		self.quality_level = quality_level;
+		self
+	}
+	
+	/// C++ default: `0.0`
+	#[inline]
+	pub fn with_min_distance(mut self, min_distance: f64) -> Self {
+		self.min_distance = min_distance;
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_block_size(mut self, block_size: i32) -> Self {
+		self.block_size = block_size;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_use_harris_detector(mut self, use_harris_detector: bool) -> Self {
+		self.use_harris_detector = use_harris_detector;
+		self
+	}
+	
+	/// C++ default: `0.04`
+	#[inline]
+	pub fn with_harris_k(mut self, harris_k: f64) -> Self {
+		self.harris_k = harris_k;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::cudaimgproc::CUDA_CornersDetector>> {
+		create_good_features_to_track_detector(self.src_type, self.max_corners, self.quality_level, self.min_distance, self.block_size, self.use_harris_detector, self.harris_k)
+	}
+}
+
 /// Creates implementation for Harris cornerness criteria.
 /// 
 /// ## Parameters
@@ -357,14 +425,14 @@ pub fn create_good_features_to_track_detector(src_type: i32, max_corners: i32, q
 /// * borderType: Pixel extrapolation method. Only BORDER_REFLECT101 and BORDER_REPLICATE are
 /// supported for now.
 /// ## See also
-/// cornerHarris
+/// `corner_harris`
 /// 
 /// ## C++ default parameters
 /// * border_type: BORDER_REFLECT101
 #[inline]
 pub fn create_harris_corner(src_type: i32, block_size: i32, ksize: i32, k: f64, border_type: i32) -> Result<core::Ptr<dyn crate::cudaimgproc::CUDA_CornernessCriteria>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createHarrisCorner_int_int_int_double_int(src_type, block_size, ksize, k, border_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createHarrisCorner_int_int_int_double_int(src_type, block_size, ksize, k, border_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudaimgproc::CUDA_CornernessCriteria>::opencv_from_extern(ret) };
@@ -393,13 +461,46 @@ pub fn create_harris_corner(src_type: i32, block_size: i32, ksize: i32, k: f64,
 #[inline]
 pub fn create_hough_circles_detector(dp: f32, min_dist: f32, canny_threshold: i32, votes_threshold: i32, min_radius: i32, max_radius: i32, max_circles: i32) -> Result<core::Ptr<dyn crate::cudaimgproc::CUDA_HoughCirclesDetector>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createHoughCirclesDetector_float_float_int_int_int_int_int(dp, min_dist, canny_threshold, votes_threshold, min_radius, max_radius, max_circles, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createHoughCirclesDetector_float_float_int_int_int_int_int(dp, min_dist, canny_threshold, votes_threshold, min_radius, max_radius, max_circles, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudaimgproc::CUDA_HoughCirclesDetector>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_hough_circles_detector()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateHoughCirclesDetectorBuilder {
+	dp: f32,
+	min_dist: f32,
+	canny_threshold: i32,
+	votes_threshold: i32,
+	min_radius: i32,
+	max_radius: i32,
+	max_circles: i32,
+}
+
+impl CreateHoughCirclesDetectorBuilder {
+	#[inline]
+	pub fn new(dp: f32, min_dist: f32, canny_threshold: i32, votes_threshold: i32, min_radius: i32, max_radius: i32) -> Self {
+		Self { dp, min_dist, canny_threshold, votes_threshold, min_radius, max_radius, max_circles: 4096 }
+	}
+	
+	/// C++ default: `4096`
+	#[inline]
+	pub fn with_max_circles(mut self, max_circles: i32) -> Self {
+		self.max_circles = max_circles;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::cudaimgproc::CUDA_HoughCirclesDetector>> {
+		create_hough_circles_detector(self.dp, self.min_dist, self.canny_threshold, self.votes_threshold, self.min_radius, self.max_radius, self.max_circles)
+	}
+}
+
 /// Creates implementation for cuda::HoughLinesDetector .
 /// 
 /// ## Parameters
@@ -416,7 +517,7 @@ pub fn create_hough_circles_detector(dp: f32, min_dist: f32, canny_threshold: i3
 #[inline]
 pub fn create_hough_lines_detector(rho: f32, theta: f32, threshold: i32, do_sort: bool, max_lines: i32) -> Result<core::Ptr<dyn crate::cudaimgproc::CUDA_HoughLinesDetector>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createHoughLinesDetector_float_float_int_bool_int(rho, theta, threshold, do_sort, max_lines, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createHoughLinesDetector_float_float_int_bool_int(rho, theta, threshold, do_sort, max_lines, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudaimgproc::CUDA_HoughLinesDetector>::opencv_from_extern(ret) };
@@ -437,7 +538,7 @@ pub fn create_hough_lines_detector(rho: f32, theta: f32, threshold: i32, do_sort
 #[inline]
 pub fn create_hough_segment_detector(rho: f32, theta: f32, min_line_length: i32, max_line_gap: i32, max_lines: i32) -> Result<core::Ptr<dyn crate::cudaimgproc::CUDA_HoughSegmentDetector>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createHoughSegmentDetector_float_float_int_int_int(rho, theta, min_line_length, max_line_gap, max_lines, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createHoughSegmentDetector_float_float_int_int_int(rho, theta, min_line_length, max_line_gap, max_lines, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudaimgproc::CUDA_HoughSegmentDetector>::opencv_from_extern(ret) };
@@ -454,14 +555,14 @@ pub fn create_hough_segment_detector(rho: f32, theta: f32, min_line_length: i32,
 /// * borderType: Pixel extrapolation method. Only BORDER_REFLECT101 and BORDER_REPLICATE are
 /// supported for now.
 /// ## See also
-/// cornerMinEigenVal
+/// `corner_min_eigen_val`
 /// 
 /// ## C++ default parameters
 /// * border_type: BORDER_REFLECT101
 #[inline]
 pub fn create_min_eigen_val_corner(src_type: i32, block_size: i32, ksize: i32, border_type: i32) -> Result<core::Ptr<dyn crate::cudaimgproc::CUDA_CornernessCriteria>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createMinEigenValCorner_int_int_int_int(src_type, block_size, ksize, border_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createMinEigenValCorner_int_int_int_int(src_type, block_size, ksize, border_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudaimgproc::CUDA_CornernessCriteria>::opencv_from_extern(ret) };
@@ -493,14 +594,14 @@ pub fn create_min_eigen_val_corner(src_type: i32, block_size: i32, ksize: i32, b
 /// *   CV_TM_SQDIFF
 /// *   CV_TM_CCORR
 /// ## See also
-/// matchTemplate
+/// `match_template`
 /// 
 /// ## C++ default parameters
 /// * user_block_size: Size()
 #[inline]
 pub fn create_template_matching(src_type: i32, method: i32, user_block_size: core::Size) -> Result<core::Ptr<dyn crate::cudaimgproc::CUDA_TemplateMatching>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createTemplateMatching_int_int_Size(src_type, method, user_block_size.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createTemplateMatching_int_int_Size(src_type, method, user_block_size.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudaimgproc::CUDA_TemplateMatching>::opencv_from_extern(ret) };
@@ -520,7 +621,7 @@ pub fn create_template_matching(src_type: i32, method: i32, user_block_size: cor
 /// 3-channel color spaces (like HSV, XYZ, and so on) can be stored in a 4-channel image for better
 /// performance.
 /// ## See also
-/// cvtColor
+/// [cvt_color](crate::cudaimgproc::cvt_color)
 /// 
 /// ## C++ default parameters
 /// * dcn: 0
@@ -530,7 +631,7 @@ pub fn cvt_color(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_cvtColor_const__InputArrayR_const__OutputArrayR_int_int_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), code, dcn, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_cvtColor_const__InputArrayR_const__OutputArrayR_int_int_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), code, dcn, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -560,7 +661,7 @@ pub fn cvt_color(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 ///    > -   COLOR_BayerBG2BGR_MHT , COLOR_BayerGB2BGR_MHT , COLOR_BayerRG2BGR_MHT ,
 ///    >     COLOR_BayerGR2BGR_MHT
 /// ## See also
-/// cvtColor
+/// [cvt_color](crate::cudaimgproc::cvt_color)
 /// 
 /// ## C++ default parameters
 /// * dcn: -1
@@ -570,7 +671,7 @@ pub fn demosaicing(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArr
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_demosaicing_const__InputArrayR_const__OutputArrayR_int_int_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), code, dcn, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_demosaicing_const__InputArrayR_const__OutputArrayR_int_int_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), code, dcn, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -583,7 +684,7 @@ pub fn demosaicing(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArr
 /// * dst: Destination image.
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// equalizeHist
+/// [equalize_hist](crate::cudaimgproc::equalize_hist)
 /// 
 /// ## C++ default parameters
 /// * stream: Stream::Null()
@@ -592,7 +693,7 @@ pub fn equalize_hist(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputA
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_equalizeHist_const__InputArrayR_const__OutputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_equalizeHist_const__InputArrayR_const__OutputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -613,7 +714,7 @@ pub fn equalize_hist(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputA
 pub fn even_levels(levels: &mut dyn core::ToOutputArray, n_levels: i32, lower_level: i32, upper_level: i32, stream: &mut core::Stream) -> Result<()> {
 	output_array_arg!(levels);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_evenLevels_const__OutputArrayR_int_int_int_StreamR(levels.as_raw__OutputArray(), n_levels, lower_level, upper_level, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_evenLevels_const__OutputArrayR_int_int_int_StreamR(levels.as_raw__OutputArray(), n_levels, lower_level, upper_level, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -635,7 +736,7 @@ pub fn gamma_correction(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutp
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_gammaCorrection_const__InputArrayR_const__OutputArrayR_bool_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), forward, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_gammaCorrection_const__InputArrayR_const__OutputArrayR_bool_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), forward, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -659,7 +760,7 @@ pub fn hist_even(src: &dyn core::ToInputArray, hist: &mut dyn core::ToOutputArra
 	input_array_arg!(src);
 	output_array_arg!(hist);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_histEven_const__InputArrayR_const__OutputArrayR_int_int_int_StreamR(src.as_raw__InputArray(), hist.as_raw__OutputArray(), hist_size, lower_level, upper_level, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_histEven_const__InputArrayR_const__OutputArrayR_int_int_int_StreamR(src.as_raw__InputArray(), hist.as_raw__OutputArray(), hist_size, lower_level, upper_level, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -682,7 +783,7 @@ pub fn hist_range(src: &dyn core::ToInputArray, hist: &mut dyn core::ToOutputArr
 	output_array_arg!(hist);
 	input_array_arg!(levels);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_histRange_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(src.as_raw__InputArray(), hist.as_raw__OutputArray(), levels.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_histRange_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(src.as_raw__InputArray(), hist.as_raw__OutputArray(), levels.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -710,7 +811,7 @@ pub fn mean_shift_filtering(src: &dyn core::ToInputArray, dst: &mut dyn core::To
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_meanShiftFiltering_const__InputArrayR_const__OutputArrayR_int_int_TermCriteria_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), sp, sr, criteria.opencv_as_extern(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_meanShiftFiltering_const__InputArrayR_const__OutputArrayR_int_int_TermCriteria_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), sp, sr, criteria.opencv_as_extern(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -741,7 +842,7 @@ pub fn mean_shift_proc(src: &dyn core::ToInputArray, dstr: &mut dyn core::ToOutp
 	output_array_arg!(dstr);
 	output_array_arg!(dstsp);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_meanShiftProc_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_int_int_TermCriteria_StreamR(src.as_raw__InputArray(), dstr.as_raw__OutputArray(), dstsp.as_raw__OutputArray(), sp, sr, criteria.opencv_as_extern(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_meanShiftProc_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_int_int_TermCriteria_StreamR(src.as_raw__InputArray(), dstr.as_raw__OutputArray(), dstsp.as_raw__OutputArray(), sp, sr, criteria.opencv_as_extern(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -766,7 +867,7 @@ pub fn mean_shift_segmentation(src: &dyn core::ToInputArray, dst: &mut dyn core:
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_meanShiftSegmentation_const__InputArrayR_const__OutputArrayR_int_int_int_TermCriteria_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), sp, sr, minsize, criteria.opencv_as_extern(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_meanShiftSegmentation_const__InputArrayR_const__OutputArrayR_int_int_int_TermCriteria_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), sp, sr, minsize, criteria.opencv_as_extern(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -778,7 +879,7 @@ pub fn mean_shift_segmentation(src: &dyn core::ToInputArray, dst: &mut dyn core:
 /// * image: Source image. Supports only CV_8UC4 type.
 /// * dstOrder: Integer array describing how channel values are permutated. The n-th entry of the
 /// array contains the number of the channel that is stored in the n-th channel of the output image.
-/// E.g. Given an RGBA image, aDstOrder = [3,2,1,0] converts this to ABGR channel order.
+/// E.g. Given an RGBA image, aDstOrder = \[3,2,1,0\] converts this to ABGR channel order.
 /// * stream: Stream for the asynchronous version.
 /// 
 /// The methods support arbitrary permutations of the original channels, including replication.
@@ -789,7 +890,7 @@ pub fn mean_shift_segmentation(src: &dyn core::ToInputArray, dst: &mut dyn core:
 pub fn swap_channels(image: &mut dyn core::ToInputOutputArray, dst_order: &[i32; 4], stream: &mut core::Stream) -> Result<()> {
 	input_output_array_arg!(image);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_swapChannels_const__InputOutputArrayR_const_intXX_StreamR(image.as_raw__InputOutputArray(), dst_order, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_swapChannels_const__InputOutputArrayR_const_intXX_StreamR(image.as_raw__InputOutputArray(), dst_order, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -812,10 +913,11 @@ pub trait CUDA_CLAHE: crate::cudaimgproc::CUDA_CLAHEConst + crate::imgproc::CLAH
 	/// * stream: Stream for the asynchronous version.
 	#[inline]
 	fn apply(&mut self, src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CUDA_CLAHE", self.as_raw_mut_CUDA_CLAHE())?;
 		input_array_arg!(src);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_CLAHE_apply_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_CLAHE(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_CLAHE_apply_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_CLAHE(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -829,8 +931,9 @@ pub trait CUDA_CannyEdgeDetectorConst: core::AlgorithmTraitConst {
 
 	#[inline]
 	fn get_low_threshold(&self) -> Result<f64> {
+		crate::core::validity::check("CUDA_CannyEdgeDetector", self.as_raw_CUDA_CannyEdgeDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_CannyEdgeDetector_getLowThreshold_const(self.as_raw_CUDA_CannyEdgeDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_CannyEdgeDetector_getLowThreshold_const(self.as_raw_CUDA_CannyEdgeDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -838,8 +941,9 @@ pub trait CUDA_CannyEdgeDetectorConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_high_threshold(&self) -> Result<f64> {
+		crate::core::validity::check("CUDA_CannyEdgeDetector", self.as_raw_CUDA_CannyEdgeDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_CannyEdgeDetector_getHighThreshold_const(self.as_raw_CUDA_CannyEdgeDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_CannyEdgeDetector_getHighThreshold_const(self.as_raw_CUDA_CannyEdgeDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -847,8 +951,9 @@ pub trait CUDA_CannyEdgeDetectorConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_apperture_size(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_CannyEdgeDetector", self.as_raw_CUDA_CannyEdgeDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_CannyEdgeDetector_getAppertureSize_const(self.as_raw_CUDA_CannyEdgeDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_CannyEdgeDetector_getAppertureSize_const(self.as_raw_CUDA_CannyEdgeDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -856,8 +961,9 @@ pub trait CUDA_CannyEdgeDetectorConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_l2_gradient(&self) -> Result<bool> {
+		crate::core::validity::check("CUDA_CannyEdgeDetector", self.as_raw_CUDA_CannyEdgeDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_CannyEdgeDetector_getL2Gradient_const(self.as_raw_CUDA_CannyEdgeDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_CannyEdgeDetector_getL2Gradient_const(self.as_raw_CUDA_CannyEdgeDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -879,10 +985,11 @@ pub trait CUDA_CannyEdgeDetector: core::AlgorithmTrait + crate::cudaimgproc::CUD
 	/// * stream: Stream::Null()
 	#[inline]
 	fn detect(&mut self, image: &dyn core::ToInputArray, edges: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CUDA_CannyEdgeDetector", self.as_raw_mut_CUDA_CannyEdgeDetector())?;
 		input_array_arg!(image);
 		output_array_arg!(edges);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_CannyEdgeDetector_detect_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_CannyEdgeDetector(), image.as_raw__InputArray(), edges.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_CannyEdgeDetector_detect_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_CannyEdgeDetector(), image.as_raw__InputArray(), edges.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -906,11 +1013,12 @@ pub trait CUDA_CannyEdgeDetector: core::AlgorithmTrait + crate::cudaimgproc::CUD
 	/// * stream: Stream::Null()
 	#[inline]
 	fn detect_1(&mut self, dx: &dyn core::ToInputArray, dy: &dyn core::ToInputArray, edges: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CUDA_CannyEdgeDetector", self.as_raw_mut_CUDA_CannyEdgeDetector())?;
 		input_array_arg!(dx);
 		input_array_arg!(dy);
 		output_array_arg!(edges);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_CannyEdgeDetector_detect_const__InputArrayR_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_CannyEdgeDetector(), dx.as_raw__InputArray(), dy.as_raw__InputArray(), edges.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_CannyEdgeDetector_detect_const__InputArrayR_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_CannyEdgeDetector(), dx.as_raw__InputArray(), dy.as_raw__InputArray(), edges.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -918,8 +1026,9 @@ pub trait CUDA_CannyEdgeDetector: core::AlgorithmTrait + crate::cudaimgproc::CUD
 	
 	#[inline]
 	fn set_low_threshold(&mut self, low_thresh: f64) -> Result<()> {
+		crate::core::validity::check("CUDA_CannyEdgeDetector", self.as_raw_mut_CUDA_CannyEdgeDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_CannyEdgeDetector_setLowThreshold_double(self.as_raw_mut_CUDA_CannyEdgeDetector(), low_thresh, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_CannyEdgeDetector_setLowThreshold_double(self.as_raw_mut_CUDA_CannyEdgeDetector(), low_thresh, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -927,8 +1036,9 @@ pub trait CUDA_CannyEdgeDetector: core::AlgorithmTrait + crate::cudaimgproc::CUD
 	
 	#[inline]
 	fn set_high_threshold(&mut self, high_thresh: f64) -> Result<()> {
+		crate::core::validity::check("CUDA_CannyEdgeDetector", self.as_raw_mut_CUDA_CannyEdgeDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_CannyEdgeDetector_setHighThreshold_double(self.as_raw_mut_CUDA_CannyEdgeDetector(), high_thresh, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_CannyEdgeDetector_setHighThreshold_double(self.as_raw_mut_CUDA_CannyEdgeDetector(), high_thresh, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -936,8 +1046,9 @@ pub trait CUDA_CannyEdgeDetector: core::AlgorithmTrait + crate::cudaimgproc::CUD
 	
 	#[inline]
 	fn set_apperture_size(&mut self, apperture_size: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_CannyEdgeDetector", self.as_raw_mut_CUDA_CannyEdgeDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_CannyEdgeDetector_setAppertureSize_int(self.as_raw_mut_CUDA_CannyEdgeDetector(), apperture_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_CannyEdgeDetector_setAppertureSize_int(self.as_raw_mut_CUDA_CannyEdgeDetector(), apperture_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -945,8 +1056,9 @@ pub trait CUDA_CannyEdgeDetector: core::AlgorithmTrait + crate::cudaimgproc::CUD
 	
 	#[inline]
 	fn set_l2_gradient(&mut self, l2gradient: bool) -> Result<()> {
+		crate::core::validity::check("CUDA_CannyEdgeDetector", self.as_raw_mut_CUDA_CannyEdgeDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_CannyEdgeDetector_setL2Gradient_bool(self.as_raw_mut_CUDA_CannyEdgeDetector(), l2gradient, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_CannyEdgeDetector_setL2Gradient_bool(self.as_raw_mut_CUDA_CannyEdgeDetector(), l2gradient, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -975,10 +1087,11 @@ pub trait CUDA_CornernessCriteria: core::AlgorithmTrait + crate::cudaimgproc::CU
 	/// * stream: Stream::Null()
 	#[inline]
 	fn compute(&mut self, src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CUDA_CornernessCriteria", self.as_raw_mut_CUDA_CornernessCriteria())?;
 		input_array_arg!(src);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_CornernessCriteria_compute_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_CornernessCriteria(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_CornernessCriteria_compute_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_CornernessCriteria(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1010,11 +1123,12 @@ pub trait CUDA_CornersDetector: core::AlgorithmTrait + crate::cudaimgproc::CUDA_
 	/// * stream: Stream::Null()
 	#[inline]
 	fn detect(&mut self, image: &dyn core::ToInputArray, corners: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CUDA_CornersDetector", self.as_raw_mut_CUDA_CornersDetector())?;
 		input_array_arg!(image);
 		output_array_arg!(corners);
 		input_array_arg!(mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_CornersDetector_detect_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(self.as_raw_mut_CUDA_CornersDetector(), image.as_raw__InputArray(), corners.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_CornersDetector_detect_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(self.as_raw_mut_CUDA_CornersDetector(), image.as_raw__InputArray(), corners.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1028,8 +1142,9 @@ pub trait CUDA_HoughCirclesDetectorConst: core::AlgorithmTraitConst {
 
 	#[inline]
 	fn get_dp(&self) -> Result<f32> {
+		crate::core::validity::check("CUDA_HoughCirclesDetector", self.as_raw_CUDA_HoughCirclesDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughCirclesDetector_getDp_const(self.as_raw_CUDA_HoughCirclesDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughCirclesDetector_getDp_const(self.as_raw_CUDA_HoughCirclesDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1037,8 +1152,9 @@ pub trait CUDA_HoughCirclesDetectorConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_min_dist(&self) -> Result<f32> {
+		crate::core::validity::check("CUDA_HoughCirclesDetector", self.as_raw_CUDA_HoughCirclesDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughCirclesDetector_getMinDist_const(self.as_raw_CUDA_HoughCirclesDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughCirclesDetector_getMinDist_const(self.as_raw_CUDA_HoughCirclesDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1046,8 +1162,9 @@ pub trait CUDA_HoughCirclesDetectorConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_canny_threshold(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_HoughCirclesDetector", self.as_raw_CUDA_HoughCirclesDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughCirclesDetector_getCannyThreshold_const(self.as_raw_CUDA_HoughCirclesDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughCirclesDetector_getCannyThreshold_const(self.as_raw_CUDA_HoughCirclesDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1055,8 +1172,9 @@ pub trait CUDA_HoughCirclesDetectorConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_votes_threshold(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_HoughCirclesDetector", self.as_raw_CUDA_HoughCirclesDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughCirclesDetector_getVotesThreshold_const(self.as_raw_CUDA_HoughCirclesDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughCirclesDetector_getVotesThreshold_const(self.as_raw_CUDA_HoughCirclesDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1064,8 +1182,9 @@ pub trait CUDA_HoughCirclesDetectorConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_min_radius(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_HoughCirclesDetector", self.as_raw_CUDA_HoughCirclesDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughCirclesDetector_getMinRadius_const(self.as_raw_CUDA_HoughCirclesDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughCirclesDetector_getMinRadius_const(self.as_raw_CUDA_HoughCirclesDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1073,8 +1192,9 @@ pub trait CUDA_HoughCirclesDetectorConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_max_radius(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_HoughCirclesDetector", self.as_raw_CUDA_HoughCirclesDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughCirclesDetector_getMaxRadius_const(self.as_raw_CUDA_HoughCirclesDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughCirclesDetector_getMaxRadius_const(self.as_raw_CUDA_HoughCirclesDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1082,8 +1202,9 @@ pub trait CUDA_HoughCirclesDetectorConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_max_circles(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_HoughCirclesDetector", self.as_raw_CUDA_HoughCirclesDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughCirclesDetector_getMaxCircles_const(self.as_raw_CUDA_HoughCirclesDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughCirclesDetector_getMaxCircles_const(self.as_raw_CUDA_HoughCirclesDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1102,16 +1223,17 @@ pub trait CUDA_HoughCirclesDetector: core::AlgorithmTrait + crate::cudaimgproc::
 	/// floating-point vector ![inline formula](https://latex.codecogs.com/png.latex?%28x%2C%20y%2C%20radius%29) .
 	/// * stream: Stream for the asynchronous version.
 	/// ## See also
-	/// HoughCircles
+	/// `HoughCircles`
 	/// 
 	/// ## C++ default parameters
 	/// * stream: Stream::Null()
 	#[inline]
 	fn detect(&mut self, src: &dyn core::ToInputArray, circles: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CUDA_HoughCirclesDetector", self.as_raw_mut_CUDA_HoughCirclesDetector())?;
 		input_array_arg!(src);
 		output_array_arg!(circles);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughCirclesDetector_detect_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_HoughCirclesDetector(), src.as_raw__InputArray(), circles.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughCirclesDetector_detect_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_HoughCirclesDetector(), src.as_raw__InputArray(), circles.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1119,8 +1241,9 @@ pub trait CUDA_HoughCirclesDetector: core::AlgorithmTrait + crate::cudaimgproc::
 	
 	#[inline]
 	fn set_dp(&mut self, dp: f32) -> Result<()> {
+		crate::core::validity::check("CUDA_HoughCirclesDetector", self.as_raw_mut_CUDA_HoughCirclesDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughCirclesDetector_setDp_float(self.as_raw_mut_CUDA_HoughCirclesDetector(), dp, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughCirclesDetector_setDp_float(self.as_raw_mut_CUDA_HoughCirclesDetector(), dp, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1128,8 +1251,9 @@ pub trait CUDA_HoughCirclesDetector: core::AlgorithmTrait + crate::cudaimgproc::
 	
 	#[inline]
 	fn set_min_dist(&mut self, min_dist: f32) -> Result<()> {
+		crate::core::validity::check("CUDA_HoughCirclesDetector", self.as_raw_mut_CUDA_HoughCirclesDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughCirclesDetector_setMinDist_float(self.as_raw_mut_CUDA_HoughCirclesDetector(), min_dist, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughCirclesDetector_setMinDist_float(self.as_raw_mut_CUDA_HoughCirclesDetector(), min_dist, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1137,8 +1261,9 @@ pub trait CUDA_HoughCirclesDetector: core::AlgorithmTrait + crate::cudaimgproc::
 	
 	#[inline]
 	fn set_canny_threshold(&mut self, canny_threshold: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_HoughCirclesDetector", self.as_raw_mut_CUDA_HoughCirclesDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughCirclesDetector_setCannyThreshold_int(self.as_raw_mut_CUDA_HoughCirclesDetector(), canny_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughCirclesDetector_setCannyThreshold_int(self.as_raw_mut_CUDA_HoughCirclesDetector(), canny_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1146,8 +1271,9 @@ pub trait CUDA_HoughCirclesDetector: core::AlgorithmTrait + crate::cudaimgproc::
 	
 	#[inline]
 	fn set_votes_threshold(&mut self, votes_threshold: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_HoughCirclesDetector", self.as_raw_mut_CUDA_HoughCirclesDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughCirclesDetector_setVotesThreshold_int(self.as_raw_mut_CUDA_HoughCirclesDetector(), votes_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughCirclesDetector_setVotesThreshold_int(self.as_raw_mut_CUDA_HoughCirclesDetector(), votes_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1155,8 +1281,9 @@ pub trait CUDA_HoughCirclesDetector: core::AlgorithmTrait + crate::cudaimgproc::
 	
 	#[inline]
 	fn set_min_radius(&mut self, min_radius: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_HoughCirclesDetector", self.as_raw_mut_CUDA_HoughCirclesDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughCirclesDetector_setMinRadius_int(self.as_raw_mut_CUDA_HoughCirclesDetector(), min_radius, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughCirclesDetector_setMinRadius_int(self.as_raw_mut_CUDA_HoughCirclesDetector(), min_radius, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1164,8 +1291,9 @@ pub trait CUDA_HoughCirclesDetector: core::AlgorithmTrait + crate::cudaimgproc::
 	
 	#[inline]
 	fn set_max_radius(&mut self, max_radius: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_HoughCirclesDetector", self.as_raw_mut_CUDA_HoughCirclesDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughCirclesDetector_setMaxRadius_int(self.as_raw_mut_CUDA_HoughCirclesDetector(), max_radius, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughCirclesDetector_setMaxRadius_int(self.as_raw_mut_CUDA_HoughCirclesDetector(), max_radius, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1173,8 +1301,9 @@ pub trait CUDA_HoughCirclesDetector: core::AlgorithmTrait + crate::cudaimgproc::
 	
 	#[inline]
 	fn set_max_circles(&mut self, max_circles: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_HoughCirclesDetector", self.as_raw_mut_CUDA_HoughCirclesDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughCirclesDetector_setMaxCircles_int(self.as_raw_mut_CUDA_HoughCirclesDetector(), max_circles, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughCirclesDetector_setMaxCircles_int(self.as_raw_mut_CUDA_HoughCirclesDetector(), max_circles, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1188,8 +1317,9 @@ pub trait CUDA_HoughLinesDetectorConst: core::AlgorithmTraitConst {
 
 	#[inline]
 	fn get_rho(&self) -> Result<f32> {
+		crate::core::validity::check("CUDA_HoughLinesDetector", self.as_raw_CUDA_HoughLinesDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughLinesDetector_getRho_const(self.as_raw_CUDA_HoughLinesDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughLinesDetector_getRho_const(self.as_raw_CUDA_HoughLinesDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1197,8 +1327,9 @@ pub trait CUDA_HoughLinesDetectorConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_theta(&self) -> Result<f32> {
+		crate::core::validity::check("CUDA_HoughLinesDetector", self.as_raw_CUDA_HoughLinesDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughLinesDetector_getTheta_const(self.as_raw_CUDA_HoughLinesDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughLinesDetector_getTheta_const(self.as_raw_CUDA_HoughLinesDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1206,8 +1337,9 @@ pub trait CUDA_HoughLinesDetectorConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_threshold(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_HoughLinesDetector", self.as_raw_CUDA_HoughLinesDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughLinesDetector_getThreshold_const(self.as_raw_CUDA_HoughLinesDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughLinesDetector_getThreshold_const(self.as_raw_CUDA_HoughLinesDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1215,8 +1347,9 @@ pub trait CUDA_HoughLinesDetectorConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_do_sort(&self) -> Result<bool> {
+		crate::core::validity::check("CUDA_HoughLinesDetector", self.as_raw_CUDA_HoughLinesDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughLinesDetector_getDoSort_const(self.as_raw_CUDA_HoughLinesDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughLinesDetector_getDoSort_const(self.as_raw_CUDA_HoughLinesDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1224,8 +1357,9 @@ pub trait CUDA_HoughLinesDetectorConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_max_lines(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_HoughLinesDetector", self.as_raw_CUDA_HoughLinesDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughLinesDetector_getMaxLines_const(self.as_raw_CUDA_HoughLinesDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughLinesDetector_getMaxLines_const(self.as_raw_CUDA_HoughLinesDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1246,16 +1380,17 @@ pub trait CUDA_HoughLinesDetector: core::AlgorithmTrait + crate::cudaimgproc::CU
 	/// ![inline formula](https://latex.codecogs.com/png.latex?0%20%5Csim%20%5Ctextrm%7Bvertical%20line%7D%2C%20%5Cpi%2F2%20%5Csim%20%5Ctextrm%7Bhorizontal%20line%7D) ).
 	/// * stream: Stream for the asynchronous version.
 	/// ## See also
-	/// HoughLines
+	/// `HoughLines`
 	/// 
 	/// ## C++ default parameters
 	/// * stream: Stream::Null()
 	#[inline]
 	fn detect(&mut self, src: &dyn core::ToInputArray, lines: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CUDA_HoughLinesDetector", self.as_raw_mut_CUDA_HoughLinesDetector())?;
 		input_array_arg!(src);
 		output_array_arg!(lines);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughLinesDetector_detect_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_HoughLinesDetector(), src.as_raw__InputArray(), lines.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughLinesDetector_detect_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_HoughLinesDetector(), src.as_raw__InputArray(), lines.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1274,11 +1409,12 @@ pub trait CUDA_HoughLinesDetector: core::AlgorithmTrait + crate::cudaimgproc::CU
 	/// * stream: Stream::Null()
 	#[inline]
 	fn download_results(&mut self, d_lines: &dyn core::ToInputArray, h_lines: &mut dyn core::ToOutputArray, h_votes: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CUDA_HoughLinesDetector", self.as_raw_mut_CUDA_HoughLinesDetector())?;
 		input_array_arg!(d_lines);
 		output_array_arg!(h_lines);
 		output_array_arg!(h_votes);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughLinesDetector_downloadResults_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_HoughLinesDetector(), d_lines.as_raw__InputArray(), h_lines.as_raw__OutputArray(), h_votes.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughLinesDetector_downloadResults_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_HoughLinesDetector(), d_lines.as_raw__InputArray(), h_lines.as_raw__OutputArray(), h_votes.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1286,8 +1422,9 @@ pub trait CUDA_HoughLinesDetector: core::AlgorithmTrait + crate::cudaimgproc::CU
 	
 	#[inline]
 	fn set_rho(&mut self, rho: f32) -> Result<()> {
+		crate::core::validity::check("CUDA_HoughLinesDetector", self.as_raw_mut_CUDA_HoughLinesDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughLinesDetector_setRho_float(self.as_raw_mut_CUDA_HoughLinesDetector(), rho, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughLinesDetector_setRho_float(self.as_raw_mut_CUDA_HoughLinesDetector(), rho, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1295,8 +1432,9 @@ pub trait CUDA_HoughLinesDetector: core::AlgorithmTrait + crate::cudaimgproc::CU
 	
 	#[inline]
 	fn set_theta(&mut self, theta: f32) -> Result<()> {
+		crate::core::validity::check("CUDA_HoughLinesDetector", self.as_raw_mut_CUDA_HoughLinesDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughLinesDetector_setTheta_float(self.as_raw_mut_CUDA_HoughLinesDetector(), theta, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughLinesDetector_setTheta_float(self.as_raw_mut_CUDA_HoughLinesDetector(), theta, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1304,8 +1442,9 @@ pub trait CUDA_HoughLinesDetector: core::AlgorithmTrait + crate::cudaimgproc::CU
 	
 	#[inline]
 	fn set_threshold(&mut self, threshold: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_HoughLinesDetector", self.as_raw_mut_CUDA_HoughLinesDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughLinesDetector_setThreshold_int(self.as_raw_mut_CUDA_HoughLinesDetector(), threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughLinesDetector_setThreshold_int(self.as_raw_mut_CUDA_HoughLinesDetector(), threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1313,8 +1452,9 @@ pub trait CUDA_HoughLinesDetector: core::AlgorithmTrait + crate::cudaimgproc::CU
 	
 	#[inline]
 	fn set_do_sort(&mut self, do_sort: bool) -> Result<()> {
+		crate::core::validity::check("CUDA_HoughLinesDetector", self.as_raw_mut_CUDA_HoughLinesDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughLinesDetector_setDoSort_bool(self.as_raw_mut_CUDA_HoughLinesDetector(), do_sort, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughLinesDetector_setDoSort_bool(self.as_raw_mut_CUDA_HoughLinesDetector(), do_sort, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1322,8 +1462,9 @@ pub trait CUDA_HoughLinesDetector: core::AlgorithmTrait + crate::cudaimgproc::CU
 	
 	#[inline]
 	fn set_max_lines(&mut self, max_lines: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_HoughLinesDetector", self.as_raw_mut_CUDA_HoughLinesDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughLinesDetector_setMaxLines_int(self.as_raw_mut_CUDA_HoughLinesDetector(), max_lines, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughLinesDetector_setMaxLines_int(self.as_raw_mut_CUDA_HoughLinesDetector(), max_lines, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1337,8 +1478,9 @@ pub trait CUDA_HoughSegmentDetectorConst: core::AlgorithmTraitConst {
 
 	#[inline]
 	fn get_rho(&self) -> Result<f32> {
+		crate::core::validity::check("CUDA_HoughSegmentDetector", self.as_raw_CUDA_HoughSegmentDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughSegmentDetector_getRho_const(self.as_raw_CUDA_HoughSegmentDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughSegmentDetector_getRho_const(self.as_raw_CUDA_HoughSegmentDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1346,8 +1488,9 @@ pub trait CUDA_HoughSegmentDetectorConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_theta(&self) -> Result<f32> {
+		crate::core::validity::check("CUDA_HoughSegmentDetector", self.as_raw_CUDA_HoughSegmentDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughSegmentDetector_getTheta_const(self.as_raw_CUDA_HoughSegmentDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughSegmentDetector_getTheta_const(self.as_raw_CUDA_HoughSegmentDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1355,8 +1498,9 @@ pub trait CUDA_HoughSegmentDetectorConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_min_line_length(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_HoughSegmentDetector", self.as_raw_CUDA_HoughSegmentDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughSegmentDetector_getMinLineLength_const(self.as_raw_CUDA_HoughSegmentDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughSegmentDetector_getMinLineLength_const(self.as_raw_CUDA_HoughSegmentDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1364,8 +1508,9 @@ pub trait CUDA_HoughSegmentDetectorConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_max_line_gap(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_HoughSegmentDetector", self.as_raw_CUDA_HoughSegmentDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughSegmentDetector_getMaxLineGap_const(self.as_raw_CUDA_HoughSegmentDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughSegmentDetector_getMaxLineGap_const(self.as_raw_CUDA_HoughSegmentDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1373,8 +1518,9 @@ pub trait CUDA_HoughSegmentDetectorConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_max_lines(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_HoughSegmentDetector", self.as_raw_CUDA_HoughSegmentDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughSegmentDetector_getMaxLines_const(self.as_raw_CUDA_HoughSegmentDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughSegmentDetector_getMaxLines_const(self.as_raw_CUDA_HoughSegmentDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1394,16 +1540,17 @@ pub trait CUDA_HoughSegmentDetector: core::AlgorithmTrait + crate::cudaimgproc::
 	/// line segment.
 	/// * stream: Stream for the asynchronous version.
 	/// ## See also
-	/// HoughLinesP
+	/// `HoughLinesP`
 	/// 
 	/// ## C++ default parameters
 	/// * stream: Stream::Null()
 	#[inline]
 	fn detect(&mut self, src: &dyn core::ToInputArray, lines: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CUDA_HoughSegmentDetector", self.as_raw_mut_CUDA_HoughSegmentDetector())?;
 		input_array_arg!(src);
 		output_array_arg!(lines);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughSegmentDetector_detect_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_HoughSegmentDetector(), src.as_raw__InputArray(), lines.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughSegmentDetector_detect_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_HoughSegmentDetector(), src.as_raw__InputArray(), lines.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1411,8 +1558,9 @@ pub trait CUDA_HoughSegmentDetector: core::AlgorithmTrait + crate::cudaimgproc::
 	
 	#[inline]
 	fn set_rho(&mut self, rho: f32) -> Result<()> {
+		crate::core::validity::check("CUDA_HoughSegmentDetector", self.as_raw_mut_CUDA_HoughSegmentDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughSegmentDetector_setRho_float(self.as_raw_mut_CUDA_HoughSegmentDetector(), rho, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughSegmentDetector_setRho_float(self.as_raw_mut_CUDA_HoughSegmentDetector(), rho, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1420,8 +1568,9 @@ pub trait CUDA_HoughSegmentDetector: core::AlgorithmTrait + crate::cudaimgproc::
 	
 	#[inline]
 	fn set_theta(&mut self, theta: f32) -> Result<()> {
+		crate::core::validity::check("CUDA_HoughSegmentDetector", self.as_raw_mut_CUDA_HoughSegmentDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughSegmentDetector_setTheta_float(self.as_raw_mut_CUDA_HoughSegmentDetector(), theta, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughSegmentDetector_setTheta_float(self.as_raw_mut_CUDA_HoughSegmentDetector(), theta, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1429,8 +1578,9 @@ pub trait CUDA_HoughSegmentDetector: core::AlgorithmTrait + crate::cudaimgproc::
 	
 	#[inline]
 	fn set_min_line_length(&mut self, min_line_length: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_HoughSegmentDetector", self.as_raw_mut_CUDA_HoughSegmentDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughSegmentDetector_setMinLineLength_int(self.as_raw_mut_CUDA_HoughSegmentDetector(), min_line_length, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughSegmentDetector_setMinLineLength_int(self.as_raw_mut_CUDA_HoughSegmentDetector(), min_line_length, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1438,8 +1588,9 @@ pub trait CUDA_HoughSegmentDetector: core::AlgorithmTrait + crate::cudaimgproc::
 	
 	#[inline]
 	fn set_max_line_gap(&mut self, max_line_gap: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_HoughSegmentDetector", self.as_raw_mut_CUDA_HoughSegmentDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughSegmentDetector_setMaxLineGap_int(self.as_raw_mut_CUDA_HoughSegmentDetector(), max_line_gap, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughSegmentDetector_setMaxLineGap_int(self.as_raw_mut_CUDA_HoughSegmentDetector(), max_line_gap, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1447,8 +1598,9 @@ pub trait CUDA_HoughSegmentDetector: core::AlgorithmTrait + crate::cudaimgproc::
 	
 	#[inline]
 	fn set_max_lines(&mut self, max_lines: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_HoughSegmentDetector", self.as_raw_mut_CUDA_HoughSegmentDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HoughSegmentDetector_setMaxLines_int(self.as_raw_mut_CUDA_HoughSegmentDetector(), max_lines, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HoughSegmentDetector_setMaxLines_int(self.as_raw_mut_CUDA_HoughSegmentDetector(), max_lines, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1478,11 +1630,12 @@ pub trait CUDA_TemplateMatching: core::AlgorithmTrait + crate::cudaimgproc::CUDA
 	/// * stream: Stream::Null()
 	#[inline]
 	fn match_(&mut self, image: &dyn core::ToInputArray, templ: &dyn core::ToInputArray, result: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CUDA_TemplateMatching", self.as_raw_mut_CUDA_TemplateMatching())?;
 		input_array_arg!(image);
 		input_array_arg!(templ);
 		output_array_arg!(result);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_TemplateMatching_match_const__InputArrayR_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_TemplateMatching(), image.as_raw__InputArray(), templ.as_raw__InputArray(), result.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_TemplateMatching_match_const__InputArrayR_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_TemplateMatching(), image.as_raw__InputArray(), templ.as_raw__InputArray(), result.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -26,8 +26,9 @@ pub trait CascadeClassifierConst: core::AlgorithmTraitConst {
 
 	#[inline]
 	fn get_max_object_size(&self) -> Result<core::Size> {
+		crate::core::validity::check("CascadeClassifier", self.as_raw_CascadeClassifier())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_CascadeClassifier_getMaxObjectSize_const(self.as_raw_CascadeClassifier(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_CascadeClassifier_getMaxObjectSize_const(self.as_raw_CascadeClassifier(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -35,8 +36,9 @@ pub trait CascadeClassifierConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_min_object_size(&self) -> Result<core::Size> {
+		crate::core::validity::check("CascadeClassifier", self.as_raw_CascadeClassifier())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_CascadeClassifier_getMinObjectSize_const(self.as_raw_CascadeClassifier(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_CascadeClassifier_getMinObjectSize_const(self.as_raw_CascadeClassifier(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -44,8 +46,9 @@ pub trait CascadeClassifierConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_scale_factor(&self) -> Result<f64> {
+		crate::core::validity::check("CascadeClassifier", self.as_raw_CascadeClassifier())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_CascadeClassifier_getScaleFactor_const(self.as_raw_CascadeClassifier(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_CascadeClassifier_getScaleFactor_const(self.as_raw_CascadeClassifier(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -53,8 +56,9 @@ pub trait CascadeClassifierConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_min_neighbors(&self) -> Result<i32> {
+		crate::core::validity::check("CascadeClassifier", self.as_raw_CascadeClassifier())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_CascadeClassifier_getMinNeighbors_const(self.as_raw_CascadeClassifier(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_CascadeClassifier_getMinNeighbors_const(self.as_raw_CascadeClassifier(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -62,8 +66,9 @@ pub trait CascadeClassifierConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_max_num_objects(&self) -> Result<i32> {
+		crate::core::validity::check("CascadeClassifier", self.as_raw_CascadeClassifier())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_CascadeClassifier_getMaxNumObjects_const(self.as_raw_CascadeClassifier(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_CascadeClassifier_getMaxNumObjects_const(self.as_raw_CascadeClassifier(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -71,8 +76,9 @@ pub trait CascadeClassifierConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_classifier_size(&self) -> Result<core::Size> {
+		crate::core::validity::check("CascadeClassifier", self.as_raw_CascadeClassifier())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_CascadeClassifier_getClassifierSize_const(self.as_raw_CascadeClassifier(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_CascadeClassifier_getClassifierSize_const(self.as_raw_CascadeClassifier(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -87,8 +93,9 @@ pub trait CascadeClassifier: core::AlgorithmTrait + crate::cudaobjdetect::Cascad
 	/// second signature and supported only for LBP cascades.
 	#[inline]
 	fn set_max_object_size(&mut self, max_object_size: core::Size) -> Result<()> {
+		crate::core::validity::check("CascadeClassifier", self.as_raw_mut_CascadeClassifier())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_CascadeClassifier_setMaxObjectSize_Size(self.as_raw_mut_CascadeClassifier(), max_object_size.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_CascadeClassifier_setMaxObjectSize_Size(self.as_raw_mut_CascadeClassifier(), max_object_size.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -97,8 +104,9 @@ pub trait CascadeClassifier: core::AlgorithmTrait + crate::cudaobjdetect::Cascad
 	/// Minimum possible object size. Objects smaller than that are ignored.
 	#[inline]
 	fn set_min_object_size(&mut self, min_size: core::Size) -> Result<()> {
+		crate::core::validity::check("CascadeClassifier", self.as_raw_mut_CascadeClassifier())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_CascadeClassifier_setMinObjectSize_Size(self.as_raw_mut_CascadeClassifier(), min_size.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_CascadeClassifier_setMinObjectSize_Size(self.as_raw_mut_CascadeClassifier(), min_size.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -107,8 +115,9 @@ pub trait CascadeClassifier: core::AlgorithmTrait + crate::cudaobjdetect::Cascad
 	/// Parameter specifying how much the image size is reduced at each image scale.
 	#[inline]
 	fn set_scale_factor(&mut self, scale_factor: f64) -> Result<()> {
+		crate::core::validity::check("CascadeClassifier", self.as_raw_mut_CascadeClassifier())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_CascadeClassifier_setScaleFactor_double(self.as_raw_mut_CascadeClassifier(), scale_factor, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_CascadeClassifier_setScaleFactor_double(self.as_raw_mut_CascadeClassifier(), scale_factor, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -118,8 +127,9 @@ pub trait CascadeClassifier: core::AlgorithmTrait + crate::cudaobjdetect::Cascad
 	/// to retain it.
 	#[inline]
 	fn set_min_neighbors(&mut self, min_neighbors: i32) -> Result<()> {
+		crate::core::validity::check("CascadeClassifier", self.as_raw_mut_CascadeClassifier())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_CascadeClassifier_setMinNeighbors_int(self.as_raw_mut_CascadeClassifier(), min_neighbors, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_CascadeClassifier_setMinNeighbors_int(self.as_raw_mut_CascadeClassifier(), min_neighbors, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -127,8 +137,9 @@ pub trait CascadeClassifier: core::AlgorithmTrait + crate::cudaobjdetect::Cascad
 	
 	#[inline]
 	fn set_find_largest_object(&mut self, find_largest_object: bool) -> Result<()> {
+		crate::core::validity::check("CascadeClassifier", self.as_raw_mut_CascadeClassifier())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_CascadeClassifier_setFindLargestObject_bool(self.as_raw_mut_CascadeClassifier(), find_largest_object, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_CascadeClassifier_setFindLargestObject_bool(self.as_raw_mut_CascadeClassifier(), find_largest_object, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -136,8 +147,9 @@ pub trait CascadeClassifier: core::AlgorithmTrait + crate::cudaobjdetect::Cascad
 	
 	#[inline]
 	fn get_find_largest_object(&mut self) -> Result<bool> {
+		crate::core::validity::check("CascadeClassifier", self.as_raw_mut_CascadeClassifier())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_CascadeClassifier_getFindLargestObject(self.as_raw_mut_CascadeClassifier(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_CascadeClassifier_getFindLargestObject(self.as_raw_mut_CascadeClassifier(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -145,8 +157,9 @@ pub trait CascadeClassifier: core::AlgorithmTrait + crate::cudaobjdetect::Cascad
 	
 	#[inline]
 	fn set_max_num_objects(&mut self, max_num_objects: i32) -> Result<()> {
+		crate::core::validity::check("CascadeClassifier", self.as_raw_mut_CascadeClassifier())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_CascadeClassifier_setMaxNumObjects_int(self.as_raw_mut_CascadeClassifier(), max_num_objects, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_CascadeClassifier_setMaxNumObjects_int(self.as_raw_mut_CascadeClassifier(), max_num_objects, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -179,16 +192,17 @@ pub trait CascadeClassifier: core::AlgorithmTrait + crate::cudaobjdetect::Cascad
 	///    imshow("Faces", image_cpu);
 	/// ```
 	/// ## See also
-	/// CascadeClassifier::detectMultiScale
+	/// [CascadeClassifier::detect_multi_scale](crate::cudaobjdetect::CascadeClassifier)
 	/// 
 	/// ## C++ default parameters
 	/// * stream: Stream::Null()
 	#[inline]
 	fn detect_multi_scale(&mut self, image: &dyn core::ToInputArray, objects: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CascadeClassifier", self.as_raw_mut_CascadeClassifier())?;
 		input_array_arg!(image);
 		output_array_arg!(objects);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_CascadeClassifier_detectMultiScale_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CascadeClassifier(), image.as_raw__InputArray(), objects.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_CascadeClassifier_detectMultiScale_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CascadeClassifier(), image.as_raw__InputArray(), objects.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -201,9 +215,10 @@ pub trait CascadeClassifier: core::AlgorithmTrait + crate::cudaobjdetect::Cascad
 	/// * objects: Resulting array.
 	#[inline]
 	fn convert(&mut self, gpu_objects: &mut dyn core::ToOutputArray, objects: &mut core::Vector<core::Rect>) -> Result<()> {
+		crate::core::validity::check("CascadeClassifier", self.as_raw_mut_CascadeClassifier())?;
 		output_array_arg!(gpu_objects);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_CascadeClassifier_convert_const__OutputArrayR_vector_Rect_R(self.as_raw_mut_CascadeClassifier(), gpu_objects.as_raw__OutputArray(), objects.as_raw_mut_VectorOfRect(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_CascadeClassifier_convert_const__OutputArrayR_vector_Rect_R(self.as_raw_mut_CascadeClassifier(), gpu_objects.as_raw__OutputArray(), objects.as_raw_mut_VectorOfRect(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -222,7 +237,7 @@ impl dyn CascadeClassifier + '_ {
 	pub fn create(filename: &str) -> Result<core::Ptr<dyn crate::cudaobjdetect::CascadeClassifier>> {
 		extern_container_arg!(filename);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_CascadeClassifier_create_const_StringR(filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_CascadeClassifier_create_const_StringR(filename.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::cudaobjdetect::CascadeClassifier>::opencv_from_extern(ret) };
@@ -240,7 +255,7 @@ impl dyn CascadeClassifier + '_ {
 	#[inline]
 	pub fn create_1(file: &core::FileStorage) -> Result<core::Ptr<dyn crate::cudaobjdetect::CascadeClassifier>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_CascadeClassifier_create_const_FileStorageR(file.as_raw_FileStorage(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_CascadeClassifier_create_const_FileStorageR(file.as_raw_FileStorage(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::cudaobjdetect::CascadeClassifier>::opencv_from_extern(ret) };
@@ -263,8 +278,9 @@ pub trait HOGConst: core::AlgorithmTraitConst {
 
 	#[inline]
 	fn get_win_sigma(&self) -> Result<f64> {
+		crate::core::validity::check("HOG", self.as_raw_HOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HOG_getWinSigma_const(self.as_raw_HOG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HOG_getWinSigma_const(self.as_raw_HOG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -272,8 +288,9 @@ pub trait HOGConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_l2_hys_threshold(&self) -> Result<f64> {
+		crate::core::validity::check("HOG", self.as_raw_HOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HOG_getL2HysThreshold_const(self.as_raw_HOG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HOG_getL2HysThreshold_const(self.as_raw_HOG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -281,8 +298,9 @@ pub trait HOGConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_gamma_correction(&self) -> Result<bool> {
+		crate::core::validity::check("HOG", self.as_raw_HOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HOG_getGammaCorrection_const(self.as_raw_HOG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HOG_getGammaCorrection_const(self.as_raw_HOG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -290,8 +308,9 @@ pub trait HOGConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_num_levels(&self) -> Result<i32> {
+		crate::core::validity::check("HOG", self.as_raw_HOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HOG_getNumLevels_const(self.as_raw_HOG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HOG_getNumLevels_const(self.as_raw_HOG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -299,8 +318,9 @@ pub trait HOGConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_hit_threshold(&self) -> Result<f64> {
+		crate::core::validity::check("HOG", self.as_raw_HOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HOG_getHitThreshold_const(self.as_raw_HOG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HOG_getHitThreshold_const(self.as_raw_HOG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -308,8 +328,9 @@ pub trait HOGConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_win_stride(&self) -> Result<core::Size> {
+		crate::core::validity::check("HOG", self.as_raw_HOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HOG_getWinStride_const(self.as_raw_HOG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HOG_getWinStride_const(self.as_raw_HOG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -317,8 +338,9 @@ pub trait HOGConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_scale_factor(&self) -> Result<f64> {
+		crate::core::validity::check("HOG", self.as_raw_HOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HOG_getScaleFactor_const(self.as_raw_HOG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HOG_getScaleFactor_const(self.as_raw_HOG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -326,8 +348,9 @@ pub trait HOGConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_group_threshold(&self) -> Result<i32> {
+		crate::core::validity::check("HOG", self.as_raw_HOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HOG_getGroupThreshold_const(self.as_raw_HOG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HOG_getGroupThreshold_const(self.as_raw_HOG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -335,8 +358,9 @@ pub trait HOGConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_descriptor_format(&self) -> Result<crate::objdetect::HOGDescriptor_DescriptorStorageFormat> {
+		crate::core::validity::check("HOG", self.as_raw_HOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HOG_getDescriptorFormat_const(self.as_raw_HOG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HOG_getDescriptorFormat_const(self.as_raw_HOG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -345,8 +369,9 @@ pub trait HOGConst: core::AlgorithmTraitConst {
 	/// Returns the number of coefficients required for the classification.
 	#[inline]
 	fn get_descriptor_size(&self) -> Result<size_t> {
+		crate::core::validity::check("HOG", self.as_raw_HOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HOG_getDescriptorSize_const(self.as_raw_HOG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HOG_getDescriptorSize_const(self.as_raw_HOG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -355,8 +380,9 @@ pub trait HOGConst: core::AlgorithmTraitConst {
 	/// Returns the block histogram size.
 	#[inline]
 	fn get_block_histogram_size(&self) -> Result<size_t> {
+		crate::core::validity::check("HOG", self.as_raw_HOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HOG_getBlockHistogramSize_const(self.as_raw_HOG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HOG_getBlockHistogramSize_const(self.as_raw_HOG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -365,8 +391,9 @@ pub trait HOGConst: core::AlgorithmTraitConst {
 	/// Returns coefficients of the classifier trained for people detection.
 	#[inline]
 	fn get_default_people_detector(&self) -> Result<core::Mat> {
+		crate::core::validity::check("HOG", self.as_raw_HOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HOG_getDefaultPeopleDetector_const(self.as_raw_HOG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HOG_getDefaultPeopleDetector_const(self.as_raw_HOG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -381,8 +408,9 @@ pub trait HOG: core::AlgorithmTrait + crate::cudaobjdetect::HOGConst {
 	/// Gaussian smoothing window parameter.
 	#[inline]
 	fn set_win_sigma(&mut self, win_sigma: f64) -> Result<()> {
+		crate::core::validity::check("HOG", self.as_raw_mut_HOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HOG_setWinSigma_double(self.as_raw_mut_HOG(), win_sigma, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HOG_setWinSigma_double(self.as_raw_mut_HOG(), win_sigma, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -391,8 +419,9 @@ pub trait HOG: core::AlgorithmTrait + crate::cudaobjdetect::HOGConst {
 	/// L2-Hys normalization method shrinkage.
 	#[inline]
 	fn set_l2_hys_threshold(&mut self, threshold_l2hys: f64) -> Result<()> {
+		crate::core::validity::check("HOG", self.as_raw_mut_HOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HOG_setL2HysThreshold_double(self.as_raw_mut_HOG(), threshold_l2hys, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HOG_setL2HysThreshold_double(self.as_raw_mut_HOG(), threshold_l2hys, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -401,8 +430,9 @@ pub trait HOG: core::AlgorithmTrait + crate::cudaobjdetect::HOGConst {
 	/// Flag to specify whether the gamma correction preprocessing is required or not.
 	#[inline]
 	fn set_gamma_correction(&mut self, gamma_correction: bool) -> Result<()> {
+		crate::core::validity::check("HOG", self.as_raw_mut_HOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HOG_setGammaCorrection_bool(self.as_raw_mut_HOG(), gamma_correction, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HOG_setGammaCorrection_bool(self.as_raw_mut_HOG(), gamma_correction, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -411,8 +441,9 @@ pub trait HOG: core::AlgorithmTrait + crate::cudaobjdetect::HOGConst {
 	/// Maximum number of detection window increases.
 	#[inline]
 	fn set_num_levels(&mut self, nlevels: i32) -> Result<()> {
+		crate::core::validity::check("HOG", self.as_raw_mut_HOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HOG_setNumLevels_int(self.as_raw_mut_HOG(), nlevels, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HOG_setNumLevels_int(self.as_raw_mut_HOG(), nlevels, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -424,8 +455,9 @@ pub trait HOG: core::AlgorithmTrait + crate::cudaobjdetect::HOGConst {
 	/// manually here.
 	#[inline]
 	fn set_hit_threshold(&mut self, hit_threshold: f64) -> Result<()> {
+		crate::core::validity::check("HOG", self.as_raw_mut_HOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HOG_setHitThreshold_double(self.as_raw_mut_HOG(), hit_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HOG_setHitThreshold_double(self.as_raw_mut_HOG(), hit_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -434,8 +466,9 @@ pub trait HOG: core::AlgorithmTrait + crate::cudaobjdetect::HOGConst {
 	/// Window stride. It must be a multiple of block stride.
 	#[inline]
 	fn set_win_stride(&mut self, win_stride: core::Size) -> Result<()> {
+		crate::core::validity::check("HOG", self.as_raw_mut_HOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HOG_setWinStride_Size(self.as_raw_mut_HOG(), win_stride.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HOG_setWinStride_Size(self.as_raw_mut_HOG(), win_stride.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -444,8 +477,9 @@ pub trait HOG: core::AlgorithmTrait + crate::cudaobjdetect::HOGConst {
 	/// Coefficient of the detection window increase.
 	#[inline]
 	fn set_scale_factor(&mut self, scale0: f64) -> Result<()> {
+		crate::core::validity::check("HOG", self.as_raw_mut_HOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HOG_setScaleFactor_double(self.as_raw_mut_HOG(), scale0, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HOG_setScaleFactor_double(self.as_raw_mut_HOG(), scale0, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -456,8 +490,9 @@ pub trait HOG: core::AlgorithmTrait + crate::cudaobjdetect::HOGConst {
 	/// See groupRectangles.
 	#[inline]
 	fn set_group_threshold(&mut self, group_threshold: i32) -> Result<()> {
+		crate::core::validity::check("HOG", self.as_raw_mut_HOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HOG_setGroupThreshold_int(self.as_raw_mut_HOG(), group_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HOG_setGroupThreshold_int(self.as_raw_mut_HOG(), group_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -468,8 +503,9 @@ pub trait HOG: core::AlgorithmTrait + crate::cudaobjdetect::HOGConst {
 	/// - **DESCR_FORMAT_COL_BY_COL** - Column-major order.
 	#[inline]
 	fn set_descriptor_format(&mut self, descr_format: crate::objdetect::HOGDescriptor_DescriptorStorageFormat) -> Result<()> {
+		crate::core::validity::check("HOG", self.as_raw_mut_HOG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HOG_setDescriptorFormat_DescriptorStorageFormat(self.as_raw_mut_HOG(), descr_format, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HOG_setDescriptorFormat_DescriptorStorageFormat(self.as_raw_mut_HOG(), descr_format, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -478,9 +514,10 @@ pub trait HOG: core::AlgorithmTrait + crate::cudaobjdetect::HOGConst {
 	/// Sets coefficients for the linear SVM classifier.
 	#[inline]
 	fn set_svm_detector(&mut self, detector: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("HOG", self.as_raw_mut_HOG())?;
 		input_array_arg!(detector);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HOG_setSVMDetector_const__InputArrayR(self.as_raw_mut_HOG(), detector.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HOG_setSVMDetector_const__InputArrayR(self.as_raw_mut_HOG(), detector.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -497,9 +534,10 @@ pub trait HOG: core::AlgorithmTrait + crate::cudaobjdetect::HOGConst {
 	/// * confidences: NULL
 	#[inline]
 	fn detect(&mut self, img: &dyn core::ToInputArray, found_locations: &mut core::Vector<core::Point>, confidences: &mut core::Vector<f64>) -> Result<()> {
+		crate::core::validity::check("HOG", self.as_raw_mut_HOG())?;
 		input_array_arg!(img);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HOG_detect_const__InputArrayR_vector_Point_R_vector_double_X(self.as_raw_mut_HOG(), img.as_raw__InputArray(), found_locations.as_raw_mut_VectorOfPoint(), confidences.as_raw_mut_VectorOff64(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HOG_detect_const__InputArrayR_vector_Point_R_vector_double_X(self.as_raw_mut_HOG(), img.as_raw__InputArray(), found_locations.as_raw_mut_VectorOfPoint(), confidences.as_raw_mut_VectorOff64(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -507,9 +545,10 @@ pub trait HOG: core::AlgorithmTrait + crate::cudaobjdetect::HOGConst {
 	
 	#[inline]
 	fn detect_1(&mut self, img: &dyn core::ToInputArray, found_locations: &mut core::Vector<core::Point>, confidences: &mut core::Vector<f64>) -> Result<()> {
+		crate::core::validity::check("HOG", self.as_raw_mut_HOG())?;
 		input_array_arg!(img);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HOG_detect_const__InputArrayR_vector_Point_R_vector_double_R(self.as_raw_mut_HOG(), img.as_raw__InputArray(), found_locations.as_raw_mut_VectorOfPoint(), confidences.as_raw_mut_VectorOff64(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HOG_detect_const__InputArrayR_vector_Point_R_vector_double_R(self.as_raw_mut_HOG(), img.as_raw__InputArray(), found_locations.as_raw_mut_VectorOfPoint(), confidences.as_raw_mut_VectorOff64(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -522,9 +561,10 @@ pub trait HOG: core::AlgorithmTrait + crate::cudaobjdetect::HOGConst {
 	/// * found_locations: Left-top corner points of detected objects boundaries.
 	#[inline]
 	fn detect_without_conf(&mut self, img: &dyn core::ToInputArray, found_locations: &mut core::Vector<core::Point>) -> Result<()> {
+		crate::core::validity::check("HOG", self.as_raw_mut_HOG())?;
 		input_array_arg!(img);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HOG_detectWithoutConf_const__InputArrayR_vector_Point_R(self.as_raw_mut_HOG(), img.as_raw__InputArray(), found_locations.as_raw_mut_VectorOfPoint(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HOG_detectWithoutConf_const__InputArrayR_vector_Point_R(self.as_raw_mut_HOG(), img.as_raw__InputArray(), found_locations.as_raw_mut_VectorOfPoint(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -541,9 +581,10 @@ pub trait HOG: core::AlgorithmTrait + crate::cudaobjdetect::HOGConst {
 	/// * confidences: NULL
 	#[inline]
 	fn detect_multi_scale(&mut self, img: &dyn core::ToInputArray, found_locations: &mut core::Vector<core::Rect>, confidences: &mut core::Vector<f64>) -> Result<()> {
+		crate::core::validity::check("HOG", self.as_raw_mut_HOG())?;
 		input_array_arg!(img);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HOG_detectMultiScale_const__InputArrayR_vector_Rect_R_vector_double_X(self.as_raw_mut_HOG(), img.as_raw__InputArray(), found_locations.as_raw_mut_VectorOfRect(), confidences.as_raw_mut_VectorOff64(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HOG_detectMultiScale_const__InputArrayR_vector_Rect_R_vector_double_X(self.as_raw_mut_HOG(), img.as_raw__InputArray(), found_locations.as_raw_mut_VectorOfRect(), confidences.as_raw_mut_VectorOff64(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -551,9 +592,10 @@ pub trait HOG: core::AlgorithmTrait + crate::cudaobjdetect::HOGConst {
 	
 	#[inline]
 	fn detect_multi_scale_1(&mut self, img: &dyn core::ToInputArray, found_locations: &mut core::Vector<core::Rect>, confidences: &mut core::Vector<f64>) -> Result<()> {
+		crate::core::validity::check("HOG", self.as_raw_mut_HOG())?;
 		input_array_arg!(img);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HOG_detectMultiScale_const__InputArrayR_vector_Rect_R_vector_double_R(self.as_raw_mut_HOG(), img.as_raw__InputArray(), found_locations.as_raw_mut_VectorOfRect(), confidences.as_raw_mut_VectorOff64(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HOG_detectMultiScale_const__InputArrayR_vector_Rect_R_vector_double_R(self.as_raw_mut_HOG(), img.as_raw__InputArray(), found_locations.as_raw_mut_VectorOfRect(), confidences.as_raw_mut_VectorOff64(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -566,9 +608,10 @@ pub trait HOG: core::AlgorithmTrait + crate::cudaobjdetect::HOGConst {
 	/// * found_locations: Detected objects boundaries.
 	#[inline]
 	fn detect_multi_scale_without_conf(&mut self, img: &dyn core::ToInputArray, found_locations: &mut core::Vector<core::Rect>) -> Result<()> {
+		crate::core::validity::check("HOG", self.as_raw_mut_HOG())?;
 		input_array_arg!(img);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HOG_detectMultiScaleWithoutConf_const__InputArrayR_vector_Rect_R(self.as_raw_mut_HOG(), img.as_raw__InputArray(), found_locations.as_raw_mut_VectorOfRect(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HOG_detectMultiScaleWithoutConf_const__InputArrayR_vector_Rect_R(self.as_raw_mut_HOG(), img.as_raw__InputArray(), found_locations.as_raw_mut_VectorOfRect(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -585,10 +628,11 @@ pub trait HOG: core::AlgorithmTrait + crate::cudaobjdetect::HOGConst {
 	/// * stream: Stream::Null()
 	#[inline]
 	fn compute(&mut self, img: &dyn core::ToInputArray, descriptors: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("HOG", self.as_raw_mut_HOG())?;
 		input_array_arg!(img);
 		output_array_arg!(descriptors);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HOG_compute_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_HOG(), img.as_raw__InputArray(), descriptors.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HOG_compute_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_HOG(), img.as_raw__InputArray(), descriptors.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -615,11 +659,77 @@ impl dyn HOG + '_ {
 	#[inline]
 	pub fn create(win_size: core::Size, block_size: core::Size, block_stride: core::Size, cell_size: core::Size, nbins: i32) -> Result<core::Ptr<dyn crate::cudaobjdetect::HOG>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_HOG_create_Size_Size_Size_Size_int(win_size.opencv_as_extern(), block_size.opencv_as_extern(), block_stride.opencv_as_extern(), cell_size.opencv_as_extern(), nbins, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_HOG_create_Size_Size_Size_Size_int(win_size.opencv_as_extern(), block_size.opencv_as_extern(), block_stride.opencv_as_extern(), cell_size.opencv_as_extern(), nbins, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::cudaobjdetect::HOG>::opencv_from_extern(ret) };
 		Ok(ret)
 	}
 	
+}
+
+/// Builder for `<dyn HOG>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct HOG_CreateBuilder {
+	win_size: core::Size,
+	block_size: core::Size,
+	block_stride: core::Size,
+	cell_size: core::Size,
+	nbins: i32,
+}
+
+impl HOG_CreateBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { win_size: core::Size::new(64, 128), block_size: core::Size::new(16, 16), block_stride: core::Size::new(8, 8), cell_size: core::Size::new(8, 8), nbins: 9 }
+	}
+	
+	/// C++ default: `Size(64,128)`
+	#[inline]
+	pub fn with_win_size(mut self, win_size: core::Size) -> Self {
+		self.win_size = win_size;
+		self
+	}
+	
+	/// C++ default: `Size(16,16)`
+	#[inline]
+	pub fn with_block_size(mut self, block_size: core::Size) -> Self {
+		self.block_size = block_size;
+		self
+	}
+	
+	/// C++ default: `Size(8,8)`
+	#[inline]
+	pub fn with_block_stride(mut self, block_stride: core::Size) -> Self {
+		self.block_stride = block_stride;
+		self
+	}
+	
+	/// C++ default: `Size(8,8)`
+	#[inline]
+	pub fn with_cell_size(mut self, cell_size: core::Size) -> Self {
+		self.cell_size = cell_size;
+		self
+	}
+	
+	/// C++ default: `9`
+	#[inline]
+	pub fn with_nbins(mut self, nbins: i32) -> Self {
+		self.nbins = nbins;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::cudaobjdetect::HOG>> {
+		<dyn HOG>::create(self.win_size, self.block_size, self.block_stride, self.cell_size, self.nbins)
+	}
+}
+
+impl Default for HOG_CreateBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
 }
\ No newline at end of file
@@ -85,8 +85,9 @@ pub trait CUDA_BroxOpticalFlowConst: crate::cudaoptflow::CUDA_DenseOpticalFlowCo
 
 	#[inline]
 	fn get_flow_smoothness(&self) -> Result<f64> {
+		crate::core::validity::check("CUDA_BroxOpticalFlow", self.as_raw_CUDA_BroxOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_BroxOpticalFlow_getFlowSmoothness_const(self.as_raw_CUDA_BroxOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_BroxOpticalFlow_getFlowSmoothness_const(self.as_raw_CUDA_BroxOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -94,8 +95,9 @@ pub trait CUDA_BroxOpticalFlowConst: crate::cudaoptflow::CUDA_DenseOpticalFlowCo
 	
 	#[inline]
 	fn get_gradient_constancy_importance(&self) -> Result<f64> {
+		crate::core::validity::check("CUDA_BroxOpticalFlow", self.as_raw_CUDA_BroxOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_BroxOpticalFlow_getGradientConstancyImportance_const(self.as_raw_CUDA_BroxOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_BroxOpticalFlow_getGradientConstancyImportance_const(self.as_raw_CUDA_BroxOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -103,8 +105,9 @@ pub trait CUDA_BroxOpticalFlowConst: crate::cudaoptflow::CUDA_DenseOpticalFlowCo
 	
 	#[inline]
 	fn get_pyramid_scale_factor(&self) -> Result<f64> {
+		crate::core::validity::check("CUDA_BroxOpticalFlow", self.as_raw_CUDA_BroxOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_BroxOpticalFlow_getPyramidScaleFactor_const(self.as_raw_CUDA_BroxOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_BroxOpticalFlow_getPyramidScaleFactor_const(self.as_raw_CUDA_BroxOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -113,8 +116,9 @@ pub trait CUDA_BroxOpticalFlowConst: crate::cudaoptflow::CUDA_DenseOpticalFlowCo
 	/// number of lagged non-linearity iterations (inner loop)
 	#[inline]
 	fn get_inner_iterations(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_BroxOpticalFlow", self.as_raw_CUDA_BroxOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_BroxOpticalFlow_getInnerIterations_const(self.as_raw_CUDA_BroxOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_BroxOpticalFlow_getInnerIterations_const(self.as_raw_CUDA_BroxOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -123,8 +127,9 @@ pub trait CUDA_BroxOpticalFlowConst: crate::cudaoptflow::CUDA_DenseOpticalFlowCo
 	/// number of warping iterations (number of pyramid levels)
 	#[inline]
 	fn get_outer_iterations(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_BroxOpticalFlow", self.as_raw_CUDA_BroxOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_BroxOpticalFlow_getOuterIterations_const(self.as_raw_CUDA_BroxOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_BroxOpticalFlow_getOuterIterations_const(self.as_raw_CUDA_BroxOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -133,8 +138,9 @@ pub trait CUDA_BroxOpticalFlowConst: crate::cudaoptflow::CUDA_DenseOpticalFlowCo
 	/// number of linear system solver iterations
 	#[inline]
 	fn get_solver_iterations(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_BroxOpticalFlow", self.as_raw_CUDA_BroxOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_BroxOpticalFlow_getSolverIterations_const(self.as_raw_CUDA_BroxOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_BroxOpticalFlow_getSolverIterations_const(self.as_raw_CUDA_BroxOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -147,8 +153,9 @@ pub trait CUDA_BroxOpticalFlow: crate::cudaoptflow::CUDA_BroxOpticalFlowConst +
 
 	#[inline]
 	fn set_flow_smoothness(&mut self, alpha: f64) -> Result<()> {
+		crate::core::validity::check("CUDA_BroxOpticalFlow", self.as_raw_mut_CUDA_BroxOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_BroxOpticalFlow_setFlowSmoothness_double(self.as_raw_mut_CUDA_BroxOpticalFlow(), alpha, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_BroxOpticalFlow_setFlowSmoothness_double(self.as_raw_mut_CUDA_BroxOpticalFlow(), alpha, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -156,8 +163,9 @@ pub trait CUDA_BroxOpticalFlow: crate::cudaoptflow::CUDA_BroxOpticalFlowConst +
 	
 	#[inline]
 	fn set_gradient_constancy_importance(&mut self, gamma: f64) -> Result<()> {
+		crate::core::validity::check("CUDA_BroxOpticalFlow", self.as_raw_mut_CUDA_BroxOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_BroxOpticalFlow_setGradientConstancyImportance_double(self.as_raw_mut_CUDA_BroxOpticalFlow(), gamma, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_BroxOpticalFlow_setGradientConstancyImportance_double(self.as_raw_mut_CUDA_BroxOpticalFlow(), gamma, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -165,8 +173,9 @@ pub trait CUDA_BroxOpticalFlow: crate::cudaoptflow::CUDA_BroxOpticalFlowConst +
 	
 	#[inline]
 	fn set_pyramid_scale_factor(&mut self, scale_factor: f64) -> Result<()> {
+		crate::core::validity::check("CUDA_BroxOpticalFlow", self.as_raw_mut_CUDA_BroxOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_BroxOpticalFlow_setPyramidScaleFactor_double(self.as_raw_mut_CUDA_BroxOpticalFlow(), scale_factor, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_BroxOpticalFlow_setPyramidScaleFactor_double(self.as_raw_mut_CUDA_BroxOpticalFlow(), scale_factor, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -174,8 +183,9 @@ pub trait CUDA_BroxOpticalFlow: crate::cudaoptflow::CUDA_BroxOpticalFlowConst +
 	
 	#[inline]
 	fn set_inner_iterations(&mut self, inner_iterations: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_BroxOpticalFlow", self.as_raw_mut_CUDA_BroxOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_BroxOpticalFlow_setInnerIterations_int(self.as_raw_mut_CUDA_BroxOpticalFlow(), inner_iterations, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_BroxOpticalFlow_setInnerIterations_int(self.as_raw_mut_CUDA_BroxOpticalFlow(), inner_iterations, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -183,8 +193,9 @@ pub trait CUDA_BroxOpticalFlow: crate::cudaoptflow::CUDA_BroxOpticalFlowConst +
 	
 	#[inline]
 	fn set_outer_iterations(&mut self, outer_iterations: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_BroxOpticalFlow", self.as_raw_mut_CUDA_BroxOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_BroxOpticalFlow_setOuterIterations_int(self.as_raw_mut_CUDA_BroxOpticalFlow(), outer_iterations, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_BroxOpticalFlow_setOuterIterations_int(self.as_raw_mut_CUDA_BroxOpticalFlow(), outer_iterations, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -192,8 +203,9 @@ pub trait CUDA_BroxOpticalFlow: crate::cudaoptflow::CUDA_BroxOpticalFlowConst +
 	
 	#[inline]
 	fn set_solver_iterations(&mut self, solver_iterations: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_BroxOpticalFlow", self.as_raw_mut_CUDA_BroxOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_BroxOpticalFlow_setSolverIterations_int(self.as_raw_mut_CUDA_BroxOpticalFlow(), solver_iterations, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_BroxOpticalFlow_setSolverIterations_int(self.as_raw_mut_CUDA_BroxOpticalFlow(), solver_iterations, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -212,7 +224,7 @@ impl dyn CUDA_BroxOpticalFlow + '_ {
 	#[inline]
 	pub fn create(alpha: f64, gamma: f64, scale_factor: f64, inner_iterations: i32, outer_iterations: i32, solver_iterations: i32) -> Result<core::Ptr<dyn crate::cudaoptflow::CUDA_BroxOpticalFlow>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_BroxOpticalFlow_create_double_double_double_int_int_int(alpha, gamma, scale_factor, inner_iterations, outer_iterations, solver_iterations, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_BroxOpticalFlow_create_double_double_double_int_int_int(alpha, gamma, scale_factor, inner_iterations, outer_iterations, solver_iterations, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::cudaoptflow::CUDA_BroxOpticalFlow>::opencv_from_extern(ret) };
@@ -220,6 +232,80 @@ impl dyn CUDA_BroxOpticalFlow + '_ {
 	}
 	
 }
+
+/// Builder for `<dyn CUDA_BroxOpticalFlow>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CUDA_BroxOpticalFlow_CreateBuilder {
+	alpha: f64,
+	gamma: f64,
+	scale_factor: f64,
+	inner_iterations: i32,
+	outer_iterations: i32,
+	solver_iterations: i32,
+}
+
+impl CUDA_BroxOpticalFlow_CreateBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { alpha: 0.197, gamma: 50.0, scale_factor: 0.8, inner_iterations: 5, outer_iterations: 150, solver_iterations: 10 }
+	}
+	
+	/// C++ default: `0.197`
+	#[inline]
+	pub fn with_alpha(mut self, alpha: f64) -> Self {
+		self.alpha = alpha;
+		self
+	}
+	
+	/// C++ default: `50.0`
+	#[inline]
+	pub fn with_gamma(mut self, gamma: f64) -> Self {
+		self.gamma = gamma;
+		self
+	}
+	
+	/// C++ default: `0.8`
+	#[inline]
+	pub fn with_scale_factor(mut self, scale_factor: f64) -> Self {
+		self.scale_factor = scale_factor;
+		self
+	}
+	
+	/// C++ default: `5`
+	#[inline]
+	pub fn with_inner_iterations(mut self, inner_iterations: i32) -> Self {
+		self.inner_iterations = inner_iterations;
+		self
+	}
+	
+	/// C++ default: `150`
+	#[inline]
+	pub fn with_outer_iterations(mut self, outer_iterations: i32) -> Self {
+		self.outer_iterations = outer_iterations;
+		self
+	}
+	
+	/// C++ default: `10`
+	#[inline]
+	pub fn with_solver_iterations(mut self, solver_iterations: i32) -> Self {
+		self.solver_iterations = solver_iterations;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::cudaoptflow::CUDA_BroxOpticalFlow>> {
+		<dyn CUDA_BroxOpticalFlow>::create(self.alpha, self.gamma, self.scale_factor, self.inner_iterations, self.outer_iterations, self.solver_iterations)
+	}
+}
+
+impl Default for CUDA_BroxOpticalFlow_CreateBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
 /// Base interface for dense optical flow algorithms.
 pub trait CUDA_DenseOpticalFlowConst: core::AlgorithmTraitConst {
 	fn as_raw_CUDA_DenseOpticalFlow(&self) -> *const c_void;
@@ -241,11 +327,12 @@ pub trait CUDA_DenseOpticalFlow: core::AlgorithmTrait + crate::cudaoptflow::CUDA
 	/// * stream: Stream::Null()
 	#[inline]
 	fn calc(&mut self, i0: &dyn core::ToInputArray, i1: &dyn core::ToInputArray, flow: &mut dyn core::ToInputOutputArray, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CUDA_DenseOpticalFlow", self.as_raw_mut_CUDA_DenseOpticalFlow())?;
 		input_array_arg!(i0);
 		input_array_arg!(i1);
 		input_output_array_arg!(flow);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DenseOpticalFlow_calc_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_StreamR(self.as_raw_mut_CUDA_DenseOpticalFlow(), i0.as_raw__InputArray(), i1.as_raw__InputArray(), flow.as_raw__InputOutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DenseOpticalFlow_calc_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_StreamR(self.as_raw_mut_CUDA_DenseOpticalFlow(), i0.as_raw__InputArray(), i1.as_raw__InputArray(), flow.as_raw__InputOutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -262,8 +349,9 @@ pub trait CUDA_DensePyrLKOpticalFlowConst: crate::cudaoptflow::CUDA_DenseOptical
 
 	#[inline]
 	fn get_win_size(&self) -> Result<core::Size> {
+		crate::core::validity::check("CUDA_DensePyrLKOpticalFlow", self.as_raw_CUDA_DensePyrLKOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DensePyrLKOpticalFlow_getWinSize_const(self.as_raw_CUDA_DensePyrLKOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DensePyrLKOpticalFlow_getWinSize_const(self.as_raw_CUDA_DensePyrLKOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -271,8 +359,9 @@ pub trait CUDA_DensePyrLKOpticalFlowConst: crate::cudaoptflow::CUDA_DenseOptical
 	
 	#[inline]
 	fn get_max_level(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_DensePyrLKOpticalFlow", self.as_raw_CUDA_DensePyrLKOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DensePyrLKOpticalFlow_getMaxLevel_const(self.as_raw_CUDA_DensePyrLKOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DensePyrLKOpticalFlow_getMaxLevel_const(self.as_raw_CUDA_DensePyrLKOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -280,8 +369,9 @@ pub trait CUDA_DensePyrLKOpticalFlowConst: crate::cudaoptflow::CUDA_DenseOptical
 	
 	#[inline]
 	fn get_num_iters(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_DensePyrLKOpticalFlow", self.as_raw_CUDA_DensePyrLKOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DensePyrLKOpticalFlow_getNumIters_const(self.as_raw_CUDA_DensePyrLKOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DensePyrLKOpticalFlow_getNumIters_const(self.as_raw_CUDA_DensePyrLKOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -289,8 +379,9 @@ pub trait CUDA_DensePyrLKOpticalFlowConst: crate::cudaoptflow::CUDA_DenseOptical
 	
 	#[inline]
 	fn get_use_initial_flow(&self) -> Result<bool> {
+		crate::core::validity::check("CUDA_DensePyrLKOpticalFlow", self.as_raw_CUDA_DensePyrLKOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DensePyrLKOpticalFlow_getUseInitialFlow_const(self.as_raw_CUDA_DensePyrLKOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DensePyrLKOpticalFlow_getUseInitialFlow_const(self.as_raw_CUDA_DensePyrLKOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -303,8 +394,9 @@ pub trait CUDA_DensePyrLKOpticalFlow: crate::cudaoptflow::CUDA_DenseOpticalFlow
 
 	#[inline]
 	fn set_win_size(&mut self, win_size: core::Size) -> Result<()> {
+		crate::core::validity::check("CUDA_DensePyrLKOpticalFlow", self.as_raw_mut_CUDA_DensePyrLKOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DensePyrLKOpticalFlow_setWinSize_Size(self.as_raw_mut_CUDA_DensePyrLKOpticalFlow(), win_size.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DensePyrLKOpticalFlow_setWinSize_Size(self.as_raw_mut_CUDA_DensePyrLKOpticalFlow(), win_size.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -312,8 +404,9 @@ pub trait CUDA_DensePyrLKOpticalFlow: crate::cudaoptflow::CUDA_DenseOpticalFlow
 	
 	#[inline]
 	fn set_max_level(&mut self, max_level: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_DensePyrLKOpticalFlow", self.as_raw_mut_CUDA_DensePyrLKOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DensePyrLKOpticalFlow_setMaxLevel_int(self.as_raw_mut_CUDA_DensePyrLKOpticalFlow(), max_level, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DensePyrLKOpticalFlow_setMaxLevel_int(self.as_raw_mut_CUDA_DensePyrLKOpticalFlow(), max_level, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -321,8 +414,9 @@ pub trait CUDA_DensePyrLKOpticalFlow: crate::cudaoptflow::CUDA_DenseOpticalFlow
 	
 	#[inline]
 	fn set_num_iters(&mut self, iters: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_DensePyrLKOpticalFlow", self.as_raw_mut_CUDA_DensePyrLKOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DensePyrLKOpticalFlow_setNumIters_int(self.as_raw_mut_CUDA_DensePyrLKOpticalFlow(), iters, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DensePyrLKOpticalFlow_setNumIters_int(self.as_raw_mut_CUDA_DensePyrLKOpticalFlow(), iters, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -330,8 +424,9 @@ pub trait CUDA_DensePyrLKOpticalFlow: crate::cudaoptflow::CUDA_DenseOpticalFlow
 	
 	#[inline]
 	fn set_use_initial_flow(&mut self, use_initial_flow: bool) -> Result<()> {
+		crate::core::validity::check("CUDA_DensePyrLKOpticalFlow", self.as_raw_mut_CUDA_DensePyrLKOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DensePyrLKOpticalFlow_setUseInitialFlow_bool(self.as_raw_mut_CUDA_DensePyrLKOpticalFlow(), use_initial_flow, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DensePyrLKOpticalFlow_setUseInitialFlow_bool(self.as_raw_mut_CUDA_DensePyrLKOpticalFlow(), use_initial_flow, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -348,7 +443,7 @@ impl dyn CUDA_DensePyrLKOpticalFlow + '_ {
 	#[inline]
 	pub fn create(win_size: core::Size, max_level: i32, iters: i32, use_initial_flow: bool) -> Result<core::Ptr<dyn crate::cudaoptflow::CUDA_DensePyrLKOpticalFlow>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DensePyrLKOpticalFlow_create_Size_int_int_bool(win_size.opencv_as_extern(), max_level, iters, use_initial_flow, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DensePyrLKOpticalFlow_create_Size_int_int_bool(win_size.opencv_as_extern(), max_level, iters, use_initial_flow, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::cudaoptflow::CUDA_DensePyrLKOpticalFlow>::opencv_from_extern(ret) };
@@ -356,14 +451,73 @@ impl dyn CUDA_DensePyrLKOpticalFlow + '_ {
 	}
 	
 }
+
+/// Builder for `<dyn CUDA_DensePyrLKOpticalFlow>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CUDA_DensePyrLKOpticalFlow_CreateBuilder {
+	win_size: core::Size,
+	max_level: i32,
+	iters: i32,
+	use_initial_flow: bool,
+}
+
+impl CUDA_DensePyrLKOpticalFlow_CreateBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { win_size: core::Size::new(13, 13), max_level: 3, iters: 30, use_initial_flow: false }
+	}
+	
+	/// C++ default: `Size(13,13)`
+	#[inline]
+	pub fn with_win_size(mut self, win_size: core::Size) -> Self {
+		self.win_size = win_size;
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_max_level(mut self, max_level: i32) -> Self {
+		self.max_level = max_level;
+		self
+	}
+	
+	/// C++ default: `30`
+	#[inline]
+	pub fn with_iters(mut self, iters: i32) -> Self {
+		self.iters = iters;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_use_initial_flow(mut self, use_initial_flow: bool) -> Self {
+		self.use_initial_flow = use_initial_flow;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::cudaoptflow::CUDA_DensePyrLKOpticalFlow>> {
+		<dyn CUDA_DensePyrLKOpticalFlow>::create(self.win_size, self.max_level, self.iters, self.use_initial_flow)
+	}
+}
+
+impl Default for CUDA_DensePyrLKOpticalFlow_CreateBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
 /// Class computing a dense optical flow using the Gunnar Farneback's algorithm.
 pub trait CUDA_FarnebackOpticalFlowConst: crate::cudaoptflow::CUDA_DenseOpticalFlowConst {
 	fn as_raw_CUDA_FarnebackOpticalFlow(&self) -> *const c_void;
 
 	#[inline]
 	fn get_num_levels(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_FarnebackOpticalFlow", self.as_raw_CUDA_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_FarnebackOpticalFlow_getNumLevels_const(self.as_raw_CUDA_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_FarnebackOpticalFlow_getNumLevels_const(self.as_raw_CUDA_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -371,8 +525,9 @@ pub trait CUDA_FarnebackOpticalFlowConst: crate::cudaoptflow::CUDA_DenseOpticalF
 	
 	#[inline]
 	fn get_pyr_scale(&self) -> Result<f64> {
+		crate::core::validity::check("CUDA_FarnebackOpticalFlow", self.as_raw_CUDA_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_FarnebackOpticalFlow_getPyrScale_const(self.as_raw_CUDA_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_FarnebackOpticalFlow_getPyrScale_const(self.as_raw_CUDA_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -380,8 +535,9 @@ pub trait CUDA_FarnebackOpticalFlowConst: crate::cudaoptflow::CUDA_DenseOpticalF
 	
 	#[inline]
 	fn get_fast_pyramids(&self) -> Result<bool> {
+		crate::core::validity::check("CUDA_FarnebackOpticalFlow", self.as_raw_CUDA_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_FarnebackOpticalFlow_getFastPyramids_const(self.as_raw_CUDA_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_FarnebackOpticalFlow_getFastPyramids_const(self.as_raw_CUDA_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -389,8 +545,9 @@ pub trait CUDA_FarnebackOpticalFlowConst: crate::cudaoptflow::CUDA_DenseOpticalF
 	
 	#[inline]
 	fn get_win_size(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_FarnebackOpticalFlow", self.as_raw_CUDA_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_FarnebackOpticalFlow_getWinSize_const(self.as_raw_CUDA_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_FarnebackOpticalFlow_getWinSize_const(self.as_raw_CUDA_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -398,8 +555,9 @@ pub trait CUDA_FarnebackOpticalFlowConst: crate::cudaoptflow::CUDA_DenseOpticalF
 	
 	#[inline]
 	fn get_num_iters(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_FarnebackOpticalFlow", self.as_raw_CUDA_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_FarnebackOpticalFlow_getNumIters_const(self.as_raw_CUDA_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_FarnebackOpticalFlow_getNumIters_const(self.as_raw_CUDA_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -407,8 +565,9 @@ pub trait CUDA_FarnebackOpticalFlowConst: crate::cudaoptflow::CUDA_DenseOpticalF
 	
 	#[inline]
 	fn get_poly_n(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_FarnebackOpticalFlow", self.as_raw_CUDA_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_FarnebackOpticalFlow_getPolyN_const(self.as_raw_CUDA_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_FarnebackOpticalFlow_getPolyN_const(self.as_raw_CUDA_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -416,8 +575,9 @@ pub trait CUDA_FarnebackOpticalFlowConst: crate::cudaoptflow::CUDA_DenseOpticalF
 	
 	#[inline]
 	fn get_poly_sigma(&self) -> Result<f64> {
+		crate::core::validity::check("CUDA_FarnebackOpticalFlow", self.as_raw_CUDA_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_FarnebackOpticalFlow_getPolySigma_const(self.as_raw_CUDA_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_FarnebackOpticalFlow_getPolySigma_const(self.as_raw_CUDA_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -425,8 +585,9 @@ pub trait CUDA_FarnebackOpticalFlowConst: crate::cudaoptflow::CUDA_DenseOpticalF
 	
 	#[inline]
 	fn get_flags(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_FarnebackOpticalFlow", self.as_raw_CUDA_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_FarnebackOpticalFlow_getFlags_const(self.as_raw_CUDA_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_FarnebackOpticalFlow_getFlags_const(self.as_raw_CUDA_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -439,8 +600,9 @@ pub trait CUDA_FarnebackOpticalFlow: crate::cudaoptflow::CUDA_DenseOpticalFlow +
 
 	#[inline]
 	fn set_num_levels(&mut self, num_levels: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_FarnebackOpticalFlow", self.as_raw_mut_CUDA_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_FarnebackOpticalFlow_setNumLevels_int(self.as_raw_mut_CUDA_FarnebackOpticalFlow(), num_levels, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_FarnebackOpticalFlow_setNumLevels_int(self.as_raw_mut_CUDA_FarnebackOpticalFlow(), num_levels, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -448,8 +610,9 @@ pub trait CUDA_FarnebackOpticalFlow: crate::cudaoptflow::CUDA_DenseOpticalFlow +
 	
 	#[inline]
 	fn set_pyr_scale(&mut self, pyr_scale: f64) -> Result<()> {
+		crate::core::validity::check("CUDA_FarnebackOpticalFlow", self.as_raw_mut_CUDA_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_FarnebackOpticalFlow_setPyrScale_double(self.as_raw_mut_CUDA_FarnebackOpticalFlow(), pyr_scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_FarnebackOpticalFlow_setPyrScale_double(self.as_raw_mut_CUDA_FarnebackOpticalFlow(), pyr_scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -457,8 +620,9 @@ pub trait CUDA_FarnebackOpticalFlow: crate::cudaoptflow::CUDA_DenseOpticalFlow +
 	
 	#[inline]
 	fn set_fast_pyramids(&mut self, fast_pyramids: bool) -> Result<()> {
+		crate::core::validity::check("CUDA_FarnebackOpticalFlow", self.as_raw_mut_CUDA_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_FarnebackOpticalFlow_setFastPyramids_bool(self.as_raw_mut_CUDA_FarnebackOpticalFlow(), fast_pyramids, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_FarnebackOpticalFlow_setFastPyramids_bool(self.as_raw_mut_CUDA_FarnebackOpticalFlow(), fast_pyramids, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -466,8 +630,9 @@ pub trait CUDA_FarnebackOpticalFlow: crate::cudaoptflow::CUDA_DenseOpticalFlow +
 	
 	#[inline]
 	fn set_win_size(&mut self, win_size: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_FarnebackOpticalFlow", self.as_raw_mut_CUDA_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_FarnebackOpticalFlow_setWinSize_int(self.as_raw_mut_CUDA_FarnebackOpticalFlow(), win_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_FarnebackOpticalFlow_setWinSize_int(self.as_raw_mut_CUDA_FarnebackOpticalFlow(), win_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -475,8 +640,9 @@ pub trait CUDA_FarnebackOpticalFlow: crate::cudaoptflow::CUDA_DenseOpticalFlow +
 	
 	#[inline]
 	fn set_num_iters(&mut self, num_iters: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_FarnebackOpticalFlow", self.as_raw_mut_CUDA_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_FarnebackOpticalFlow_setNumIters_int(self.as_raw_mut_CUDA_FarnebackOpticalFlow(), num_iters, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_FarnebackOpticalFlow_setNumIters_int(self.as_raw_mut_CUDA_FarnebackOpticalFlow(), num_iters, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -484,8 +650,9 @@ pub trait CUDA_FarnebackOpticalFlow: crate::cudaoptflow::CUDA_DenseOpticalFlow +
 	
 	#[inline]
 	fn set_poly_n(&mut self, poly_n: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_FarnebackOpticalFlow", self.as_raw_mut_CUDA_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_FarnebackOpticalFlow_setPolyN_int(self.as_raw_mut_CUDA_FarnebackOpticalFlow(), poly_n, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_FarnebackOpticalFlow_setPolyN_int(self.as_raw_mut_CUDA_FarnebackOpticalFlow(), poly_n, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -493,8 +660,9 @@ pub trait CUDA_FarnebackOpticalFlow: crate::cudaoptflow::CUDA_DenseOpticalFlow +
 	
 	#[inline]
 	fn set_poly_sigma(&mut self, poly_sigma: f64) -> Result<()> {
+		crate::core::validity::check("CUDA_FarnebackOpticalFlow", self.as_raw_mut_CUDA_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_FarnebackOpticalFlow_setPolySigma_double(self.as_raw_mut_CUDA_FarnebackOpticalFlow(), poly_sigma, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_FarnebackOpticalFlow_setPolySigma_double(self.as_raw_mut_CUDA_FarnebackOpticalFlow(), poly_sigma, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -502,8 +670,9 @@ pub trait CUDA_FarnebackOpticalFlow: crate::cudaoptflow::CUDA_DenseOpticalFlow +
 	
 	#[inline]
 	fn set_flags(&mut self, flags: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_FarnebackOpticalFlow", self.as_raw_mut_CUDA_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_FarnebackOpticalFlow_setFlags_int(self.as_raw_mut_CUDA_FarnebackOpticalFlow(), flags, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_FarnebackOpticalFlow_setFlags_int(self.as_raw_mut_CUDA_FarnebackOpticalFlow(), flags, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -524,7 +693,7 @@ impl dyn CUDA_FarnebackOpticalFlow + '_ {
 	#[inline]
 	pub fn create(num_levels: i32, pyr_scale: f64, fast_pyramids: bool, win_size: i32, num_iters: i32, poly_n: i32, poly_sigma: f64, flags: i32) -> Result<core::Ptr<dyn crate::cudaoptflow::CUDA_FarnebackOpticalFlow>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_FarnebackOpticalFlow_create_int_double_bool_int_int_int_double_int(num_levels, pyr_scale, fast_pyramids, win_size, num_iters, poly_n, poly_sigma, flags, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_FarnebackOpticalFlow_create_int_double_bool_int_int_int_double_int(num_levels, pyr_scale, fast_pyramids, win_size, num_iters, poly_n, poly_sigma, flags, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::cudaoptflow::CUDA_FarnebackOpticalFlow>::opencv_from_extern(ret) };
@@ -532,6 +701,96 @@ impl dyn CUDA_FarnebackOpticalFlow + '_ {
 	}
 	
 }
+
+/// Builder for `<dyn CUDA_FarnebackOpticalFlow>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CUDA_FarnebackOpticalFlow_CreateBuilder {
+	num_levels: i32,
+	pyr_scale: f64,
+	fast_pyramids: bool,
+	win_size: i32,
+	num_iters: i32,
+	poly_n: i32,
+	poly_sigma: f64,
+	flags: i32,
+}
+
+impl CUDA_FarnebackOpticalFlow_CreateBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { num_levels: 5, pyr_scale: 0.5, fast_pyramids: false, win_size: 13, num_iters: 10, poly_n: 5, poly_sigma: 1.1, flags: 0 }
+	}
+	
+	/// C++ default: `5`
+	#[inline]
+	pub fn with_num_levels(mut self, num_levels: i32) -> Self {
+		self.num_levels = num_levels;
+		self
+	}
+	
+	/// C++ default: `0.5`
+	#[inline]
+	pub fn with_pyr_scale(mut self, pyr_scale: f64) -> Self {
+		self.pyr_scale = pyr_scale;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_fast_pyramids(mut self, fast_pyramids: bool) -> Self {
+		self.fast_pyramids = fast_pyramids;
+		self
+	}
+	
+	/// C++ default: `13`
+	#[inline]
+	pub fn with_win_size(mut self, win_size: i32) -> Self {
+		self.win_size = win_size;
+		self
+	}
+	
+	/// C++ default: `10`
+	#[inline]
+	pub fn with_num_iters(mut self, num_iters: i32) -> Self {
+		self.num_iters = num_iters;
+		self
+	}
+	
+	/// C++ default: `5`
+	#[inline]
+	pub fn with_poly_n(mut self, poly_n: i32) -> Self {
+		self.poly_n = poly_n;
+		self
+	}
+	
+	/// C++ default: `1.1`
+	#[inline]
+	pub fn with_poly_sigma(mut self, poly_sigma: f64) -> Self {
+		self.poly_sigma = poly_sigma;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_flags(mut self, flags: i32) -> Self {
+		self.flags = flags;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::cudaoptflow::CUDA_FarnebackOpticalFlow>> {
+		<dyn CUDA_FarnebackOpticalFlow>::create(self.num_levels, self.pyr_scale, self.fast_pyramids, self.win_size, self.num_iters, self.poly_n, self.poly_sigma, self.flags)
+	}
+}
+
+impl Default for CUDA_FarnebackOpticalFlow_CreateBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
 /// Base Interface for optical flow algorithms using NVIDIA Optical Flow SDK.
 pub trait CUDA_NvidiaHWOpticalFlowConst: core::AlgorithmTraitConst {
 	fn as_raw_CUDA_NvidiaHWOpticalFlow(&self) -> *const c_void;
@@ -539,8 +798,9 @@ pub trait CUDA_NvidiaHWOpticalFlowConst: core::AlgorithmTraitConst {
 	/// Returns grid size of output buffer as per the hardware's capability.
 	#[inline]
 	fn get_grid_size(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_NvidiaHWOpticalFlow", self.as_raw_CUDA_NvidiaHWOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_NvidiaHWOpticalFlow_getGridSize_const(self.as_raw_CUDA_NvidiaHWOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_NvidiaHWOpticalFlow_getGridSize_const(self.as_raw_CUDA_NvidiaHWOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -581,13 +841,14 @@ pub trait CUDA_NvidiaHWOpticalFlow: core::AlgorithmTrait + crate::cudaoptflow::C
 	/// * cost: cv::noArray()
 	#[inline]
 	fn calc(&mut self, input_image: &dyn core::ToInputArray, reference_image: &dyn core::ToInputArray, flow: &mut dyn core::ToInputOutputArray, stream: &mut core::Stream, hint: &dyn core::ToInputArray, cost: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("CUDA_NvidiaHWOpticalFlow", self.as_raw_mut_CUDA_NvidiaHWOpticalFlow())?;
 		input_array_arg!(input_image);
 		input_array_arg!(reference_image);
 		input_output_array_arg!(flow);
 		input_array_arg!(hint);
 		output_array_arg!(cost);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_NvidiaHWOpticalFlow_calc_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_StreamR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_CUDA_NvidiaHWOpticalFlow(), input_image.as_raw__InputArray(), reference_image.as_raw__InputArray(), flow.as_raw__InputOutputArray(), stream.as_raw_mut_Stream(), hint.as_raw__InputArray(), cost.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_NvidiaHWOpticalFlow_calc_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_StreamR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_CUDA_NvidiaHWOpticalFlow(), input_image.as_raw__InputArray(), reference_image.as_raw__InputArray(), flow.as_raw__InputOutputArray(), stream.as_raw_mut_Stream(), hint.as_raw__InputArray(), cost.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -596,8 +857,9 @@ pub trait CUDA_NvidiaHWOpticalFlow: core::AlgorithmTrait + crate::cudaoptflow::C
 	/// Releases all buffers, contexts and device pointers.
 	#[inline]
 	fn collect_garbage(&mut self) -> Result<()> {
+		crate::core::validity::check("CUDA_NvidiaHWOpticalFlow", self.as_raw_mut_CUDA_NvidiaHWOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_NvidiaHWOpticalFlow_collectGarbage(self.as_raw_mut_CUDA_NvidiaHWOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_NvidiaHWOpticalFlow_collectGarbage(self.as_raw_mut_CUDA_NvidiaHWOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -605,6 +867,50 @@ pub trait CUDA_NvidiaHWOpticalFlow: core::AlgorithmTrait + crate::cudaoptflow::C
 	
 }
 
+/// Builder for `CUDA_NvidiaHWOpticalFlow::calc()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CUDA_NvidiaHWOpticalFlow_CalcBuilder<'a, T: ?Sized + CUDA_NvidiaHWOpticalFlow> {
+	instance: &'a mut T,
+	input_image: &'a dyn core::ToInputArray,
+	reference_image: &'a dyn core::ToInputArray,
+	flow: &'a mut dyn core::ToInputOutputArray,
+	stream: &'a mut core::Stream,
+	hint: Option<&'a dyn core::ToInputArray>,
+	cost: Option<&'a mut dyn core::ToOutputArray>,
+}
+
+impl<'a, T: ?Sized + CUDA_NvidiaHWOpticalFlow> CUDA_NvidiaHWOpticalFlow_CalcBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, input_image: &'a dyn core::ToInputArray, reference_image: &'a dyn core::ToInputArray, flow: &'a mut dyn core::ToInputOutputArray, stream: &'a mut core::Stream) -> Self {
+		Self { instance, input_image, reference_image, flow, stream, hint: None, cost: None }
+	}
+	
+	/// C++ default: `cv::noArray()`
+	#[inline]
+	pub fn with_hint(mut self, hint: &'a dyn core::ToInputArray) -> Self {
+		self.hint = Some(hint);
+		self
+	}
+	
+	/// C++ default: `cv::noArray()`
+	#[inline]
+	pub fn with_cost(mut self, cost: &'a mut dyn core::ToOutputArray) -> Self {
+		self.cost = Some(cost);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let hint_no_array = core::no_array();
+		let hint: &dyn core::ToInputArray = match self.hint { Some(hint) => hint, None => &hint_no_array };
+		let mut cost_no_array = core::no_array();
+		let cost: &mut dyn core::ToOutputArray = match self.cost { Some(cost) => cost, None => &mut cost_no_array };
+		CUDA_NvidiaHWOpticalFlow::calc(self.instance, self.input_image, self.reference_image, self.flow, self.stream, hint, cost)
+	}
+}
+
 /// Class for computing the optical flow vectors between two images using NVIDIA Optical Flow hardware and Optical Flow SDK 1.0.
 /// 
 /// Note:
@@ -631,10 +937,11 @@ pub trait CUDA_NvidiaOpticalFlow_1_0: crate::cudaoptflow::CUDA_NvidiaHWOpticalFl
 	/// * upsampledFlow: Buffer of type CV_32FC2, containing upsampled flow vectors, each flow vector for 1 pixel, in the pitch-linear layout.
 	#[inline]
 	fn up_sampler(&mut self, flow: &dyn core::ToInputArray, image_size: core::Size, grid_size: i32, upsampled_flow: &mut dyn core::ToInputOutputArray) -> Result<()> {
+		crate::core::validity::check("CUDA_NvidiaOpticalFlow_1_0", self.as_raw_mut_CUDA_NvidiaOpticalFlow_1_0())?;
 		input_array_arg!(flow);
 		input_output_array_arg!(upsampled_flow);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_NvidiaOpticalFlow_1_0_upSampler_const__InputArrayR_Size_int_const__InputOutputArrayR(self.as_raw_mut_CUDA_NvidiaOpticalFlow_1_0(), flow.as_raw__InputArray(), image_size.opencv_as_extern(), grid_size, upsampled_flow.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_NvidiaOpticalFlow_1_0_upSampler_const__InputArrayR_Size_int_const__InputOutputArrayR(self.as_raw_mut_CUDA_NvidiaOpticalFlow_1_0(), flow.as_raw__InputArray(), image_size.opencv_as_extern(), grid_size, upsampled_flow.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -673,7 +980,7 @@ impl dyn CUDA_NvidiaOpticalFlow_1_0 + '_ {
 	#[inline]
 	pub fn create(image_size: core::Size, perf_preset: crate::cudaoptflow::CUDA_NvidiaOpticalFlow_1_0_NVIDIA_OF_PERF_LEVEL, enable_temporal_hints: bool, enable_external_hints: bool, enable_cost_buffer: bool, gpu_id: i32, input_stream: &mut core::Stream, output_stream: &mut core::Stream) -> Result<core::Ptr<dyn crate::cudaoptflow::CUDA_NvidiaOpticalFlow_1_0>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_NvidiaOpticalFlow_1_0_create_Size_NVIDIA_OF_PERF_LEVEL_bool_bool_bool_int_StreamR_StreamR(image_size.opencv_as_extern(), perf_preset, enable_temporal_hints, enable_external_hints, enable_cost_buffer, gpu_id, input_stream.as_raw_mut_Stream(), output_stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_NvidiaOpticalFlow_1_0_create_Size_NVIDIA_OF_PERF_LEVEL_bool_bool_bool_int_StreamR_StreamR(image_size.opencv_as_extern(), perf_preset, enable_temporal_hints, enable_external_hints, enable_cost_buffer, gpu_id, input_stream.as_raw_mut_Stream(), output_stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::cudaoptflow::CUDA_NvidiaOpticalFlow_1_0>::opencv_from_extern(ret) };
@@ -681,6 +988,61 @@ impl dyn CUDA_NvidiaOpticalFlow_1_0 + '_ {
 	}
 	
 }
+
+/// Builder for `<dyn CUDA_NvidiaOpticalFlow_1_0>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CUDA_NvidiaOpticalFlow_1_0_CreateBuilder<'a> {
+	image_size: core::Size,
+	perf_preset: crate::cudaoptflow::CUDA_NvidiaOpticalFlow_1_0_NVIDIA_OF_PERF_LEVEL,
+	enable_temporal_hints: bool,
+	enable_external_hints: bool,
+	enable_cost_buffer: bool,
+	gpu_id: i32,
+	input_stream: &'a mut core::Stream,
+	output_stream: &'a mut core::Stream,
+}
+
+impl<'a> CUDA_NvidiaOpticalFlow_1_0_CreateBuilder<'a> {
+	#[inline]
+	pub fn new(image_size: core::Size, perf_preset: crate::cudaoptflow::CUDA_NvidiaOpticalFlow_1_0_NVIDIA_OF_PERF_LEVEL, input_stream: &'a mut core::Stream, output_stream: &'a mut core::Stream) -> Self {
+		Self { image_size, perf_preset, enable_temporal_hints: false, enable_external_hints: false, enable_cost_buffer: false, gpu_id: 0, input_stream, output_stream }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_enable_temporal_hints(mut self, enable_temporal_hints: bool) -> Self {
+		self.enable_temporal_hints = enable_temporal_hints;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_enable_external_hints(mut self, enable_external_hints: bool) -> Self {
+		self.enable_external_hints = enable_external_hints;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_enable_cost_buffer(mut self, enable_cost_buffer: bool) -> Self {
+		self.enable_cost_buffer = enable_cost_buffer;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_gpu_id(mut self, gpu_id: i32) -> Self {
+		self.gpu_id = gpu_id;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::cudaoptflow::CUDA_NvidiaOpticalFlow_1_0>> {
+		<dyn CUDA_NvidiaOpticalFlow_1_0>::create(self.image_size, self.perf_preset, self.enable_temporal_hints, self.enable_external_hints, self.enable_cost_buffer, self.gpu_id, self.input_stream, self.output_stream)
+	}
+}
 /// Class for computing the optical flow vectors between two images using NVIDIA Optical Flow hardware and Optical Flow SDK 2.0.
 /// 
 /// Note:
@@ -704,10 +1066,11 @@ pub trait CUDA_NvidiaOpticalFlow_2_0: crate::cudaoptflow::CUDA_NvidiaHWOpticalFl
 	/// * floatFlow: Buffer of type CV_32FC2, containing flow vectors in floating point representation, each flow vector for 1 pixel per gridSize, in the pitch-linear layout.
 	#[inline]
 	fn convert_to_float(&mut self, flow: &dyn core::ToInputArray, float_flow: &mut dyn core::ToInputOutputArray) -> Result<()> {
+		crate::core::validity::check("CUDA_NvidiaOpticalFlow_2_0", self.as_raw_mut_CUDA_NvidiaOpticalFlow_2_0())?;
 		input_array_arg!(flow);
 		input_output_array_arg!(float_flow);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_NvidiaOpticalFlow_2_0_convertToFloat_const__InputArrayR_const__InputOutputArrayR(self.as_raw_mut_CUDA_NvidiaOpticalFlow_2_0(), flow.as_raw__InputArray(), float_flow.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_NvidiaOpticalFlow_2_0_convertToFloat_const__InputArrayR_const__InputOutputArrayR(self.as_raw_mut_CUDA_NvidiaOpticalFlow_2_0(), flow.as_raw__InputArray(), float_flow.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -752,7 +1115,7 @@ impl dyn CUDA_NvidiaOpticalFlow_2_0 + '_ {
 	#[inline]
 	pub fn create(image_size: core::Size, perf_preset: crate::cudaoptflow::CUDA_NvidiaOpticalFlow_2_0_NVIDIA_OF_PERF_LEVEL, output_grid_size: crate::cudaoptflow::CUDA_NvidiaOpticalFlow_2_0_NVIDIA_OF_OUTPUT_VECTOR_GRID_SIZE, hint_grid_size: crate::cudaoptflow::CUDA_NvidiaOpticalFlow_2_0_NVIDIA_OF_HINT_VECTOR_GRID_SIZE, enable_temporal_hints: bool, enable_external_hints: bool, enable_cost_buffer: bool, gpu_id: i32, input_stream: &mut core::Stream, output_stream: &mut core::Stream) -> Result<core::Ptr<dyn crate::cudaoptflow::CUDA_NvidiaOpticalFlow_2_0>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_NvidiaOpticalFlow_2_0_create_Size_NVIDIA_OF_PERF_LEVEL_NVIDIA_OF_OUTPUT_VECTOR_GRID_SIZE_NVIDIA_OF_HINT_VECTOR_GRID_SIZE_bool_bool_bool_int_StreamR_StreamR(image_size.opencv_as_extern(), perf_preset, output_grid_size, hint_grid_size, enable_temporal_hints, enable_external_hints, enable_cost_buffer, gpu_id, input_stream.as_raw_mut_Stream(), output_stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_NvidiaOpticalFlow_2_0_create_Size_NVIDIA_OF_PERF_LEVEL_NVIDIA_OF_OUTPUT_VECTOR_GRID_SIZE_NVIDIA_OF_HINT_VECTOR_GRID_SIZE_bool_bool_bool_int_StreamR_StreamR(image_size.opencv_as_extern(), perf_preset, output_grid_size, hint_grid_size, enable_temporal_hints, enable_external_hints, enable_cost_buffer, gpu_id, input_stream.as_raw_mut_Stream(), output_stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::cudaoptflow::CUDA_NvidiaOpticalFlow_2_0>::opencv_from_extern(ret) };
@@ -796,7 +1159,7 @@ impl dyn CUDA_NvidiaOpticalFlow_2_0 + '_ {
 	#[inline]
 	pub fn create_1(image_size: core::Size, mut roi_data: core::Vector<core::Rect>, perf_preset: crate::cudaoptflow::CUDA_NvidiaOpticalFlow_2_0_NVIDIA_OF_PERF_LEVEL, output_grid_size: crate::cudaoptflow::CUDA_NvidiaOpticalFlow_2_0_NVIDIA_OF_OUTPUT_VECTOR_GRID_SIZE, hint_grid_size: crate::cudaoptflow::CUDA_NvidiaOpticalFlow_2_0_NVIDIA_OF_HINT_VECTOR_GRID_SIZE, enable_temporal_hints: bool, enable_external_hints: bool, enable_cost_buffer: bool, gpu_id: i32, input_stream: &mut core::Stream, output_stream: &mut core::Stream) -> Result<core::Ptr<dyn crate::cudaoptflow::CUDA_NvidiaOpticalFlow_2_0>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_NvidiaOpticalFlow_2_0_create_Size_vector_Rect__NVIDIA_OF_PERF_LEVEL_NVIDIA_OF_OUTPUT_VECTOR_GRID_SIZE_NVIDIA_OF_HINT_VECTOR_GRID_SIZE_bool_bool_bool_int_StreamR_StreamR(image_size.opencv_as_extern(), roi_data.as_raw_mut_VectorOfRect(), perf_preset, output_grid_size, hint_grid_size, enable_temporal_hints, enable_external_hints, enable_cost_buffer, gpu_id, input_stream.as_raw_mut_Stream(), output_stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_NvidiaOpticalFlow_2_0_create_Size_vector_Rect__NVIDIA_OF_PERF_LEVEL_NVIDIA_OF_OUTPUT_VECTOR_GRID_SIZE_NVIDIA_OF_HINT_VECTOR_GRID_SIZE_bool_bool_bool_int_StreamR_StreamR(image_size.opencv_as_extern(), roi_data.as_raw_mut_VectorOfRect(), perf_preset, output_grid_size, hint_grid_size, enable_temporal_hints, enable_external_hints, enable_cost_buffer, gpu_id, input_stream.as_raw_mut_Stream(), output_stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::cudaoptflow::CUDA_NvidiaOpticalFlow_2_0>::opencv_from_extern(ret) };
@@ -804,6 +1167,121 @@ impl dyn CUDA_NvidiaOpticalFlow_2_0 + '_ {
 	}
 	
 }
+
+/// Builder for `<dyn CUDA_NvidiaOpticalFlow_2_0>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CUDA_NvidiaOpticalFlow_2_0_CreateBuilder<'a> {
+	image_size: core::Size,
+	perf_preset: crate::cudaoptflow::CUDA_NvidiaOpticalFlow_2_0_NVIDIA_OF_PERF_LEVEL,
+	output_grid_size: crate::cudaoptflow::CUDA_NvidiaOpticalFlow_2_0_NVIDIA_OF_OUTPUT_VECTOR_GRID_SIZE,
+	hint_grid_size: crate::cudaoptflow::CUDA_NvidiaOpticalFlow_2_0_NVIDIA_OF_HINT_VECTOR_GRID_SIZE,
+	enable_temporal_hints: bool,
+	enable_external_hints: bool,
+	enable_cost_buffer: bool,
+	gpu_id: i32,
+	input_stream: &'a mut core::Stream,
+	output_stream: &'a mut core::Stream,
+}
+
+impl<'a> CUDA_NvidiaOpticalFlow_2_0_CreateBuilder<'a> {
+	#[inline]
+	pub fn new(image_size: core::Size, perf_preset: crate::cudaoptflow::CUDA_NvidiaOpticalFlow_2_0_NVIDIA_OF_PERF_LEVEL, output_grid_size: crate::cudaoptflow::CUDA_NvidiaOpticalFlow_2_0_NVIDIA_OF_OUTPUT_VECTOR_GRID_SIZE, hint_grid_size: crate::cudaoptflow::CUDA_NvidiaOpticalFlow_2_0_NVIDIA_OF_HINT_VECTOR_GRID_SIZE, input_stream: &'a mut core::Stream, output_stream: &'a mut core::Stream) -> Self {
+		Self { image_size, perf_preset, output_grid_size, hint_grid_size, enable_temporal_hints: false, enable_external_hints: false, enable_cost_buffer: false, gpu_id: 0, input_stream, output_stream }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_enable_temporal_hints(mut self, enable_temporal_hints: bool) -> Self {
+		self.enable_temporal_hints = enable_temporal_hints;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_enable_external_hints(mut self, enable_external_hints: bool) -> Self {
+		self.enable_external_hints = enable_external_hints;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_enable_cost_buffer(mut self, enable_cost_buffer: bool) -> Self {
+		self.enable_cost_buffer = enable_cost_buffer;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_gpu_id(mut self, gpu_id: i32) -> Self {
+		self.gpu_id = gpu_id;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::cudaoptflow::CUDA_NvidiaOpticalFlow_2_0>> {
+		<dyn CUDA_NvidiaOpticalFlow_2_0>::create(self.image_size, self.perf_preset, self.output_grid_size, self.hint_grid_size, self.enable_temporal_hints, self.enable_external_hints, self.enable_cost_buffer, self.gpu_id, self.input_stream, self.output_stream)
+	}
+}
+
+/// Builder for `<dyn CUDA_NvidiaOpticalFlow_2_0>::create_1()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CUDA_NvidiaOpticalFlow_2_0_Create1Builder<'a> {
+	image_size: core::Size,
+	roi_data: core::Vector<core::Rect>,
+	perf_preset: crate::cudaoptflow::CUDA_NvidiaOpticalFlow_2_0_NVIDIA_OF_PERF_LEVEL,
+	output_grid_size: crate::cudaoptflow::CUDA_NvidiaOpticalFlow_2_0_NVIDIA_OF_OUTPUT_VECTOR_GRID_SIZE,
+	hint_grid_size: crate::cudaoptflow::CUDA_NvidiaOpticalFlow_2_0_NVIDIA_OF_HINT_VECTOR_GRID_SIZE,
+	enable_temporal_hints: bool,
+	enable_external_hints: bool,
+	enable_cost_buffer: bool,
+	gpu_id: i32,
+	input_stream: &'a mut core::Stream,
+	output_stream: &'a mut core::Stream,
+}
+
+impl<'a> CUDA_NvidiaOpticalFlow_2_0_Create1Builder<'a> {
+	#[inline]
+	pub fn new(image_size: core::Size, roi_data: core::Vector<core::Rect>, perf_preset: crate::cudaoptflow::CUDA_NvidiaOpticalFlow_2_0_NVIDIA_OF_PERF_LEVEL, output_grid_size: crate::cudaoptflow::CUDA_NvidiaOpticalFlow_2_0_NVIDIA_OF_OUTPUT_VECTOR_GRID_SIZE, hint_grid_size: crate::cudaoptflow::CUDA_NvidiaOpticalFlow_2_0_NVIDIA_OF_HINT_VECTOR_GRID_SIZE, input_stream: &'a mut core::Stream, output_stream: &'a mut core::Stream) -> Self {
+		Self { image_size, roi_data, perf_preset, output_grid_size, hint_grid_size, enable_temporal_hints: false, enable_external_hints: false, enable_cost_buffer: false, gpu_id: 0, input_stream, output_stream }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_enable_temporal_hints(mut self, enable_temporal_hints: bool) -> Self {
+		self.enable_temporal_hints = enable_temporal_hints;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_enable_external_hints(mut self, enable_external_hints: bool) -> Self {
+		self.enable_external_hints = enable_external_hints;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_enable_cost_buffer(mut self, enable_cost_buffer: bool) -> Self {
+		self.enable_cost_buffer = enable_cost_buffer;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_gpu_id(mut self, gpu_id: i32) -> Self {
+		self.gpu_id = gpu_id;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::cudaoptflow::CUDA_NvidiaOpticalFlow_2_0>> {
+		<dyn CUDA_NvidiaOpticalFlow_2_0>::create_1(self.image_size, self.roi_data, self.perf_preset, self.output_grid_size, self.hint_grid_size, self.enable_temporal_hints, self.enable_external_hints, self.enable_cost_buffer, self.gpu_id, self.input_stream, self.output_stream)
+	}
+}
 /// Implementation of the Zach, Pock and Bischof Dual TV-L1 Optical Flow method.
 /// 
 /// 
@@ -816,8 +1294,9 @@ pub trait CUDA_OpticalFlowDual_TVL1Const: crate::cudaoptflow::CUDA_DenseOpticalF
 	/// Time step of the numerical scheme.
 	#[inline]
 	fn get_tau(&self) -> Result<f64> {
+		crate::core::validity::check("CUDA_OpticalFlowDual_TVL1", self.as_raw_CUDA_OpticalFlowDual_TVL1())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_OpticalFlowDual_TVL1_getTau_const(self.as_raw_CUDA_OpticalFlowDual_TVL1(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_OpticalFlowDual_TVL1_getTau_const(self.as_raw_CUDA_OpticalFlowDual_TVL1(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -829,8 +1308,9 @@ pub trait CUDA_OpticalFlowDual_TVL1Const: crate::cudaoptflow::CUDA_DenseOpticalF
 	/// It depends on the range of motions of the images, so its value should be adapted to each image sequence.
 	#[inline]
 	fn get_lambda(&self) -> Result<f64> {
+		crate::core::validity::check("CUDA_OpticalFlowDual_TVL1", self.as_raw_CUDA_OpticalFlowDual_TVL1())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_OpticalFlowDual_TVL1_getLambda_const(self.as_raw_CUDA_OpticalFlowDual_TVL1(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_OpticalFlowDual_TVL1_getLambda_const(self.as_raw_CUDA_OpticalFlowDual_TVL1(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -842,8 +1322,9 @@ pub trait CUDA_OpticalFlowDual_TVL1Const: crate::cudaoptflow::CUDA_DenseOpticalF
 	/// The method is stable for a large range of values of this parameter.
 	#[inline]
 	fn get_gamma(&self) -> Result<f64> {
+		crate::core::validity::check("CUDA_OpticalFlowDual_TVL1", self.as_raw_CUDA_OpticalFlowDual_TVL1())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_OpticalFlowDual_TVL1_getGamma_const(self.as_raw_CUDA_OpticalFlowDual_TVL1(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_OpticalFlowDual_TVL1_getGamma_const(self.as_raw_CUDA_OpticalFlowDual_TVL1(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -855,8 +1336,9 @@ pub trait CUDA_OpticalFlowDual_TVL1Const: crate::cudaoptflow::CUDA_DenseOpticalF
 	/// Journal of Mathematical imaging and vision, may 2011 Vol 40 issue 1, pp 120-145
 	#[inline]
 	fn get_theta(&self) -> Result<f64> {
+		crate::core::validity::check("CUDA_OpticalFlowDual_TVL1", self.as_raw_CUDA_OpticalFlowDual_TVL1())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_OpticalFlowDual_TVL1_getTheta_const(self.as_raw_CUDA_OpticalFlowDual_TVL1(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_OpticalFlowDual_TVL1_getTheta_const(self.as_raw_CUDA_OpticalFlowDual_TVL1(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -865,8 +1347,9 @@ pub trait CUDA_OpticalFlowDual_TVL1Const: crate::cudaoptflow::CUDA_DenseOpticalF
 	/// Number of scales used to create the pyramid of images.
 	#[inline]
 	fn get_num_scales(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_OpticalFlowDual_TVL1", self.as_raw_CUDA_OpticalFlowDual_TVL1())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_OpticalFlowDual_TVL1_getNumScales_const(self.as_raw_CUDA_OpticalFlowDual_TVL1(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_OpticalFlowDual_TVL1_getNumScales_const(self.as_raw_CUDA_OpticalFlowDual_TVL1(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -878,8 +1361,9 @@ pub trait CUDA_OpticalFlowDual_TVL1Const: crate::cudaoptflow::CUDA_DenseOpticalF
 	/// It also affects the running time, so it is a compromise between speed and accuracy.
 	#[inline]
 	fn get_num_warps(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_OpticalFlowDual_TVL1", self.as_raw_CUDA_OpticalFlowDual_TVL1())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_OpticalFlowDual_TVL1_getNumWarps_const(self.as_raw_CUDA_OpticalFlowDual_TVL1(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_OpticalFlowDual_TVL1_getNumWarps_const(self.as_raw_CUDA_OpticalFlowDual_TVL1(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -889,8 +1373,9 @@ pub trait CUDA_OpticalFlowDual_TVL1Const: crate::cudaoptflow::CUDA_DenseOpticalF
 	/// A small value will yield more accurate solutions at the expense of a slower convergence.
 	#[inline]
 	fn get_epsilon(&self) -> Result<f64> {
+		crate::core::validity::check("CUDA_OpticalFlowDual_TVL1", self.as_raw_CUDA_OpticalFlowDual_TVL1())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_OpticalFlowDual_TVL1_getEpsilon_const(self.as_raw_CUDA_OpticalFlowDual_TVL1(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_OpticalFlowDual_TVL1_getEpsilon_const(self.as_raw_CUDA_OpticalFlowDual_TVL1(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -899,8 +1384,9 @@ pub trait CUDA_OpticalFlowDual_TVL1Const: crate::cudaoptflow::CUDA_DenseOpticalF
 	/// Stopping criterion iterations number used in the numerical scheme.
 	#[inline]
 	fn get_num_iterations(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_OpticalFlowDual_TVL1", self.as_raw_CUDA_OpticalFlowDual_TVL1())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_OpticalFlowDual_TVL1_getNumIterations_const(self.as_raw_CUDA_OpticalFlowDual_TVL1(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_OpticalFlowDual_TVL1_getNumIterations_const(self.as_raw_CUDA_OpticalFlowDual_TVL1(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -908,8 +1394,9 @@ pub trait CUDA_OpticalFlowDual_TVL1Const: crate::cudaoptflow::CUDA_DenseOpticalF
 	
 	#[inline]
 	fn get_scale_step(&self) -> Result<f64> {
+		crate::core::validity::check("CUDA_OpticalFlowDual_TVL1", self.as_raw_CUDA_OpticalFlowDual_TVL1())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_OpticalFlowDual_TVL1_getScaleStep_const(self.as_raw_CUDA_OpticalFlowDual_TVL1(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_OpticalFlowDual_TVL1_getScaleStep_const(self.as_raw_CUDA_OpticalFlowDual_TVL1(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -917,8 +1404,9 @@ pub trait CUDA_OpticalFlowDual_TVL1Const: crate::cudaoptflow::CUDA_DenseOpticalF
 	
 	#[inline]
 	fn get_use_initial_flow(&self) -> Result<bool> {
+		crate::core::validity::check("CUDA_OpticalFlowDual_TVL1", self.as_raw_CUDA_OpticalFlowDual_TVL1())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_OpticalFlowDual_TVL1_getUseInitialFlow_const(self.as_raw_CUDA_OpticalFlowDual_TVL1(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_OpticalFlowDual_TVL1_getUseInitialFlow_const(self.as_raw_CUDA_OpticalFlowDual_TVL1(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -931,8 +1419,9 @@ pub trait CUDA_OpticalFlowDual_TVL1: crate::cudaoptflow::CUDA_DenseOpticalFlow +
 
 	#[inline]
 	fn set_tau(&mut self, tau: f64) -> Result<()> {
+		crate::core::validity::check("CUDA_OpticalFlowDual_TVL1", self.as_raw_mut_CUDA_OpticalFlowDual_TVL1())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_OpticalFlowDual_TVL1_setTau_double(self.as_raw_mut_CUDA_OpticalFlowDual_TVL1(), tau, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_OpticalFlowDual_TVL1_setTau_double(self.as_raw_mut_CUDA_OpticalFlowDual_TVL1(), tau, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -940,8 +1429,9 @@ pub trait CUDA_OpticalFlowDual_TVL1: crate::cudaoptflow::CUDA_DenseOpticalFlow +
 	
 	#[inline]
 	fn set_lambda(&mut self, lambda: f64) -> Result<()> {
+		crate::core::validity::check("CUDA_OpticalFlowDual_TVL1", self.as_raw_mut_CUDA_OpticalFlowDual_TVL1())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_OpticalFlowDual_TVL1_setLambda_double(self.as_raw_mut_CUDA_OpticalFlowDual_TVL1(), lambda, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_OpticalFlowDual_TVL1_setLambda_double(self.as_raw_mut_CUDA_OpticalFlowDual_TVL1(), lambda, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -949,8 +1439,9 @@ pub trait CUDA_OpticalFlowDual_TVL1: crate::cudaoptflow::CUDA_DenseOpticalFlow +
 	
 	#[inline]
 	fn set_gamma(&mut self, gamma: f64) -> Result<()> {
+		crate::core::validity::check("CUDA_OpticalFlowDual_TVL1", self.as_raw_mut_CUDA_OpticalFlowDual_TVL1())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_OpticalFlowDual_TVL1_setGamma_double(self.as_raw_mut_CUDA_OpticalFlowDual_TVL1(), gamma, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_OpticalFlowDual_TVL1_setGamma_double(self.as_raw_mut_CUDA_OpticalFlowDual_TVL1(), gamma, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -958,8 +1449,9 @@ pub trait CUDA_OpticalFlowDual_TVL1: crate::cudaoptflow::CUDA_DenseOpticalFlow +
 	
 	#[inline]
 	fn set_theta(&mut self, theta: f64) -> Result<()> {
+		crate::core::validity::check("CUDA_OpticalFlowDual_TVL1", self.as_raw_mut_CUDA_OpticalFlowDual_TVL1())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_OpticalFlowDual_TVL1_setTheta_double(self.as_raw_mut_CUDA_OpticalFlowDual_TVL1(), theta, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_OpticalFlowDual_TVL1_setTheta_double(self.as_raw_mut_CUDA_OpticalFlowDual_TVL1(), theta, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -967,8 +1459,9 @@ pub trait CUDA_OpticalFlowDual_TVL1: crate::cudaoptflow::CUDA_DenseOpticalFlow +
 	
 	#[inline]
 	fn set_num_scales(&mut self, nscales: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_OpticalFlowDual_TVL1", self.as_raw_mut_CUDA_OpticalFlowDual_TVL1())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_OpticalFlowDual_TVL1_setNumScales_int(self.as_raw_mut_CUDA_OpticalFlowDual_TVL1(), nscales, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_OpticalFlowDual_TVL1_setNumScales_int(self.as_raw_mut_CUDA_OpticalFlowDual_TVL1(), nscales, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -976,8 +1469,9 @@ pub trait CUDA_OpticalFlowDual_TVL1: crate::cudaoptflow::CUDA_DenseOpticalFlow +
 	
 	#[inline]
 	fn set_num_warps(&mut self, warps: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_OpticalFlowDual_TVL1", self.as_raw_mut_CUDA_OpticalFlowDual_TVL1())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_OpticalFlowDual_TVL1_setNumWarps_int(self.as_raw_mut_CUDA_OpticalFlowDual_TVL1(), warps, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_OpticalFlowDual_TVL1_setNumWarps_int(self.as_raw_mut_CUDA_OpticalFlowDual_TVL1(), warps, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -985,8 +1479,9 @@ pub trait CUDA_OpticalFlowDual_TVL1: crate::cudaoptflow::CUDA_DenseOpticalFlow +
 	
 	#[inline]
 	fn set_epsilon(&mut self, epsilon: f64) -> Result<()> {
+		crate::core::validity::check("CUDA_OpticalFlowDual_TVL1", self.as_raw_mut_CUDA_OpticalFlowDual_TVL1())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_OpticalFlowDual_TVL1_setEpsilon_double(self.as_raw_mut_CUDA_OpticalFlowDual_TVL1(), epsilon, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_OpticalFlowDual_TVL1_setEpsilon_double(self.as_raw_mut_CUDA_OpticalFlowDual_TVL1(), epsilon, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -994,8 +1489,9 @@ pub trait CUDA_OpticalFlowDual_TVL1: crate::cudaoptflow::CUDA_DenseOpticalFlow +
 	
 	#[inline]
 	fn set_num_iterations(&mut self, iterations: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_OpticalFlowDual_TVL1", self.as_raw_mut_CUDA_OpticalFlowDual_TVL1())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_OpticalFlowDual_TVL1_setNumIterations_int(self.as_raw_mut_CUDA_OpticalFlowDual_TVL1(), iterations, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_OpticalFlowDual_TVL1_setNumIterations_int(self.as_raw_mut_CUDA_OpticalFlowDual_TVL1(), iterations, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1003,8 +1499,9 @@ pub trait CUDA_OpticalFlowDual_TVL1: crate::cudaoptflow::CUDA_DenseOpticalFlow +
 	
 	#[inline]
 	fn set_scale_step(&mut self, scale_step: f64) -> Result<()> {
+		crate::core::validity::check("CUDA_OpticalFlowDual_TVL1", self.as_raw_mut_CUDA_OpticalFlowDual_TVL1())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_OpticalFlowDual_TVL1_setScaleStep_double(self.as_raw_mut_CUDA_OpticalFlowDual_TVL1(), scale_step, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_OpticalFlowDual_TVL1_setScaleStep_double(self.as_raw_mut_CUDA_OpticalFlowDual_TVL1(), scale_step, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1012,8 +1509,9 @@ pub trait CUDA_OpticalFlowDual_TVL1: crate::cudaoptflow::CUDA_DenseOpticalFlow +
 	
 	#[inline]
 	fn set_use_initial_flow(&mut self, use_initial_flow: bool) -> Result<()> {
+		crate::core::validity::check("CUDA_OpticalFlowDual_TVL1", self.as_raw_mut_CUDA_OpticalFlowDual_TVL1())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_OpticalFlowDual_TVL1_setUseInitialFlow_bool(self.as_raw_mut_CUDA_OpticalFlowDual_TVL1(), use_initial_flow, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_OpticalFlowDual_TVL1_setUseInitialFlow_bool(self.as_raw_mut_CUDA_OpticalFlowDual_TVL1(), use_initial_flow, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1036,7 +1534,7 @@ impl dyn CUDA_OpticalFlowDual_TVL1 + '_ {
 	#[inline]
 	pub fn create(tau: f64, lambda: f64, theta: f64, nscales: i32, warps: i32, epsilon: f64, iterations: i32, scale_step: f64, gamma: f64, use_initial_flow: bool) -> Result<core::Ptr<dyn crate::cudaoptflow::CUDA_OpticalFlowDual_TVL1>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_OpticalFlowDual_TVL1_create_double_double_double_int_int_double_int_double_double_bool(tau, lambda, theta, nscales, warps, epsilon, iterations, scale_step, gamma, use_initial_flow, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_OpticalFlowDual_TVL1_create_double_double_double_int_int_double_int_double_double_bool(tau, lambda, theta, nscales, warps, epsilon, iterations, scale_step, gamma, use_initial_flow, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::cudaoptflow::CUDA_OpticalFlowDual_TVL1>::opencv_from_extern(ret) };
@@ -1044,6 +1542,112 @@ impl dyn CUDA_OpticalFlowDual_TVL1 + '_ {
 	}
 	
 }
+
+/// Builder for `<dyn CUDA_OpticalFlowDual_TVL1>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CUDA_OpticalFlowDual_TVL1_CreateBuilder {
+	tau: f64,
+	lambda: f64,
+	theta: f64,
+	nscales: i32,
+	warps: i32,
+	epsilon: f64,
+	iterations: i32,
+	scale_step: f64,
+	gamma: f64,
+	use_initial_flow: bool,
+}
+
+impl CUDA_OpticalFlowDual_TVL1_CreateBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { tau: 0.25, lambda: 0.15, theta: 0.3, nscales: 5, warps: 5, epsilon: 0.01, iterations: 300, scale_step: 0.8, gamma: 0.0, use_initial_flow: false }
+	}
+	
+	/// C++ default: `0.25`
+	#[inline]
+	pub fn with_tau(mut self, tau: f64) -> Self {
+		self.tau = tau;
+		self
+	}
+	
+	/// C++ default: `0.15`
+	#[inline]
+	pub fn with_lambda(mut self, lambda: f64) -> Self {
+		self.lambda = lambda;
+		self
+	}
+	
+	/// C++ default: `0.3`
+	#[inline]
+	pub fn with_theta(mut self, theta: f64) -> Self {
+		self.theta = theta;
+		self
+	}
+	
+	/// C++ default: `5`
+	#[inline]
+	pub fn with_nscales(mut self, nscales: i32) -> Self {
+		self.nscales = nscales;
+		self
+	}
+	
+	/// C++ default: `5`
+	#[inline]
+	pub fn with_warps(mut self, warps: i32) -> Self {
+		self.warps = warps;
+		self
+	}
+	
+	/// C++ default: `0.01`
+	#[inline]
+	pub fn with_epsilon(mut self, epsilon: f64) -> Self {
+		self.epsilon = epsilon;
+		self
+	}
+	
+	/// C++ default: `300`
+	#[inline]
+	pub fn with_iterations(mut self, iterations: i32) -> Self {
+		self.iterations = iterations;
+		self
+	}
+	
+	/// C++ default: `0.8`
+	#[inline]
+	pub fn with_scale_step(mut self, scale_step: f64) -> Self {
+		self.scale_step = scale_step;
+		self
+	}
+	
+	/// C++ default: `0.0`
+	#[inline]
+	pub fn with_gamma(mut self, gamma: f64) -> Self {
+		self.gamma = gamma;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_use_initial_flow(mut self, use_initial_flow: bool) -> Self {
+		self.use_initial_flow = use_initial_flow;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::cudaoptflow::CUDA_OpticalFlowDual_TVL1>> {
+		<dyn CUDA_OpticalFlowDual_TVL1>::create(self.tau, self.lambda, self.theta, self.nscales, self.warps, self.epsilon, self.iterations, self.scale_step, self.gamma, self.use_initial_flow)
+	}
+}
+
+impl Default for CUDA_OpticalFlowDual_TVL1_CreateBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
 /// Base interface for sparse optical flow algorithms.
 pub trait CUDA_SparseOpticalFlowConst: core::AlgorithmTraitConst {
 	fn as_raw_CUDA_SparseOpticalFlow(&self) -> *const c_void;
@@ -1070,6 +1674,7 @@ pub trait CUDA_SparseOpticalFlow: core::AlgorithmTrait + crate::cudaoptflow::CUD
 	/// * stream: Stream::Null()
 	#[inline]
 	fn calc(&mut self, prev_img: &dyn core::ToInputArray, next_img: &dyn core::ToInputArray, prev_pts: &dyn core::ToInputArray, next_pts: &mut dyn core::ToInputOutputArray, status: &mut dyn core::ToOutputArray, err: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CUDA_SparseOpticalFlow", self.as_raw_mut_CUDA_SparseOpticalFlow())?;
 		input_array_arg!(prev_img);
 		input_array_arg!(next_img);
 		input_array_arg!(prev_pts);
@@ -1077,7 +1682,7 @@ pub trait CUDA_SparseOpticalFlow: core::AlgorithmTrait + crate::cudaoptflow::CUD
 		output_array_arg!(status);
 		output_array_arg!(err);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_SparseOpticalFlow_calc_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_SparseOpticalFlow(), prev_img.as_raw__InputArray(), next_img.as_raw__InputArray(), prev_pts.as_raw__InputArray(), next_pts.as_raw__InputOutputArray(), status.as_raw__OutputArray(), err.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_SparseOpticalFlow_calc_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_SparseOpticalFlow(), prev_img.as_raw__InputArray(), next_img.as_raw__InputArray(), prev_pts.as_raw__InputArray(), next_pts.as_raw__InputOutputArray(), status.as_raw__OutputArray(), err.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1085,12 +1690,48 @@ pub trait CUDA_SparseOpticalFlow: core::AlgorithmTrait + crate::cudaoptflow::CUD
 	
 }
 
+/// Builder for `CUDA_SparseOpticalFlow::calc()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CUDA_SparseOpticalFlow_CalcBuilder<'a, T: ?Sized + CUDA_SparseOpticalFlow> {
+	instance: &'a mut T,
+	prev_img: &'a dyn core::ToInputArray,
+	next_img: &'a dyn core::ToInputArray,
+	prev_pts: &'a dyn core::ToInputArray,
+	next_pts: &'a mut dyn core::ToInputOutputArray,
+	status: &'a mut dyn core::ToOutputArray,
+	err: Option<&'a mut dyn core::ToOutputArray>,
+	stream: &'a mut core::Stream,
+}
+
+impl<'a, T: ?Sized + CUDA_SparseOpticalFlow> CUDA_SparseOpticalFlow_CalcBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, prev_img: &'a dyn core::ToInputArray, next_img: &'a dyn core::ToInputArray, prev_pts: &'a dyn core::ToInputArray, next_pts: &'a mut dyn core::ToInputOutputArray, status: &'a mut dyn core::ToOutputArray, stream: &'a mut core::Stream) -> Self {
+		Self { instance, prev_img, next_img, prev_pts, next_pts, status, err: None, stream }
+	}
+	
+	/// C++ default: `cv::noArray()`
+	#[inline]
+	pub fn with_err(mut self, err: &'a mut dyn core::ToOutputArray) -> Self {
+		self.err = Some(err);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let mut err_no_array = core::no_array();
+		let err: &mut dyn core::ToOutputArray = match self.err { Some(err) => err, None => &mut err_no_array };
+		CUDA_SparseOpticalFlow::calc(self.instance, self.prev_img, self.next_img, self.prev_pts, self.next_pts, self.status, err, self.stream)
+	}
+}
+
 /// Class used for calculating a sparse optical flow.
 /// 
 /// The class can calculate an optical flow for a sparse feature set using the
 /// iterative Lucas-Kanade method with pyramids.
 /// ## See also
-/// calcOpticalFlowPyrLK
+/// `calc_optical_flow_pyr_lk`
 /// 
 /// 
 /// Note:
@@ -1101,8 +1742,9 @@ pub trait CUDA_SparsePyrLKOpticalFlowConst: crate::cudaoptflow::CUDA_SparseOptic
 
 	#[inline]
 	fn get_win_size(&self) -> Result<core::Size> {
+		crate::core::validity::check("CUDA_SparsePyrLKOpticalFlow", self.as_raw_CUDA_SparsePyrLKOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_SparsePyrLKOpticalFlow_getWinSize_const(self.as_raw_CUDA_SparsePyrLKOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_SparsePyrLKOpticalFlow_getWinSize_const(self.as_raw_CUDA_SparsePyrLKOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1110,8 +1752,9 @@ pub trait CUDA_SparsePyrLKOpticalFlowConst: crate::cudaoptflow::CUDA_SparseOptic
 	
 	#[inline]
 	fn get_max_level(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_SparsePyrLKOpticalFlow", self.as_raw_CUDA_SparsePyrLKOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_SparsePyrLKOpticalFlow_getMaxLevel_const(self.as_raw_CUDA_SparsePyrLKOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_SparsePyrLKOpticalFlow_getMaxLevel_const(self.as_raw_CUDA_SparsePyrLKOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1119,8 +1762,9 @@ pub trait CUDA_SparsePyrLKOpticalFlowConst: crate::cudaoptflow::CUDA_SparseOptic
 	
 	#[inline]
 	fn get_num_iters(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_SparsePyrLKOpticalFlow", self.as_raw_CUDA_SparsePyrLKOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_SparsePyrLKOpticalFlow_getNumIters_const(self.as_raw_CUDA_SparsePyrLKOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_SparsePyrLKOpticalFlow_getNumIters_const(self.as_raw_CUDA_SparsePyrLKOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1128,8 +1772,9 @@ pub trait CUDA_SparsePyrLKOpticalFlowConst: crate::cudaoptflow::CUDA_SparseOptic
 	
 	#[inline]
 	fn get_use_initial_flow(&self) -> Result<bool> {
+		crate::core::validity::check("CUDA_SparsePyrLKOpticalFlow", self.as_raw_CUDA_SparsePyrLKOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_SparsePyrLKOpticalFlow_getUseInitialFlow_const(self.as_raw_CUDA_SparsePyrLKOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_SparsePyrLKOpticalFlow_getUseInitialFlow_const(self.as_raw_CUDA_SparsePyrLKOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1142,8 +1787,9 @@ pub trait CUDA_SparsePyrLKOpticalFlow: crate::cudaoptflow::CUDA_SparseOpticalFlo
 
 	#[inline]
 	fn set_win_size(&mut self, win_size: core::Size) -> Result<()> {
+		crate::core::validity::check("CUDA_SparsePyrLKOpticalFlow", self.as_raw_mut_CUDA_SparsePyrLKOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_SparsePyrLKOpticalFlow_setWinSize_Size(self.as_raw_mut_CUDA_SparsePyrLKOpticalFlow(), win_size.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_SparsePyrLKOpticalFlow_setWinSize_Size(self.as_raw_mut_CUDA_SparsePyrLKOpticalFlow(), win_size.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1151,8 +1797,9 @@ pub trait CUDA_SparsePyrLKOpticalFlow: crate::cudaoptflow::CUDA_SparseOpticalFlo
 	
 	#[inline]
 	fn set_max_level(&mut self, max_level: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_SparsePyrLKOpticalFlow", self.as_raw_mut_CUDA_SparsePyrLKOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_SparsePyrLKOpticalFlow_setMaxLevel_int(self.as_raw_mut_CUDA_SparsePyrLKOpticalFlow(), max_level, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_SparsePyrLKOpticalFlow_setMaxLevel_int(self.as_raw_mut_CUDA_SparsePyrLKOpticalFlow(), max_level, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1160,8 +1807,9 @@ pub trait CUDA_SparsePyrLKOpticalFlow: crate::cudaoptflow::CUDA_SparseOpticalFlo
 	
 	#[inline]
 	fn set_num_iters(&mut self, iters: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_SparsePyrLKOpticalFlow", self.as_raw_mut_CUDA_SparsePyrLKOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_SparsePyrLKOpticalFlow_setNumIters_int(self.as_raw_mut_CUDA_SparsePyrLKOpticalFlow(), iters, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_SparsePyrLKOpticalFlow_setNumIters_int(self.as_raw_mut_CUDA_SparsePyrLKOpticalFlow(), iters, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1169,8 +1817,9 @@ pub trait CUDA_SparsePyrLKOpticalFlow: crate::cudaoptflow::CUDA_SparseOpticalFlo
 	
 	#[inline]
 	fn set_use_initial_flow(&mut self, use_initial_flow: bool) -> Result<()> {
+		crate::core::validity::check("CUDA_SparsePyrLKOpticalFlow", self.as_raw_mut_CUDA_SparsePyrLKOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_SparsePyrLKOpticalFlow_setUseInitialFlow_bool(self.as_raw_mut_CUDA_SparsePyrLKOpticalFlow(), use_initial_flow, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_SparsePyrLKOpticalFlow_setUseInitialFlow_bool(self.as_raw_mut_CUDA_SparsePyrLKOpticalFlow(), use_initial_flow, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1187,11 +1836,69 @@ impl dyn CUDA_SparsePyrLKOpticalFlow + '_ {
 	#[inline]
 	pub fn create(win_size: core::Size, max_level: i32, iters: i32, use_initial_flow: bool) -> Result<core::Ptr<dyn crate::cudaoptflow::CUDA_SparsePyrLKOpticalFlow>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_SparsePyrLKOpticalFlow_create_Size_int_int_bool(win_size.opencv_as_extern(), max_level, iters, use_initial_flow, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_SparsePyrLKOpticalFlow_create_Size_int_int_bool(win_size.opencv_as_extern(), max_level, iters, use_initial_flow, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::cudaoptflow::CUDA_SparsePyrLKOpticalFlow>::opencv_from_extern(ret) };
 		Ok(ret)
 	}
 	
+}
+
+/// Builder for `<dyn CUDA_SparsePyrLKOpticalFlow>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CUDA_SparsePyrLKOpticalFlow_CreateBuilder {
+	win_size: core::Size,
+	max_level: i32,
+	iters: i32,
+	use_initial_flow: bool,
+}
+
+impl CUDA_SparsePyrLKOpticalFlow_CreateBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { win_size: core::Size::new(21, 21), max_level: 3, iters: 30, use_initial_flow: false }
+	}
+	
+	/// C++ default: `Size(21,21)`
+	#[inline]
+	pub fn with_win_size(mut self, win_size: core::Size) -> Self {
+		self.win_size = win_size;
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_max_level(mut self, max_level: i32) -> Self {
+		self.max_level = max_level;
+		self
+	}
+	
+	/// C++ default: `30`
+	#[inline]
+	pub fn with_iters(mut self, iters: i32) -> Self {
+		self.iters = iters;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_use_initial_flow(mut self, use_initial_flow: bool) -> Self {
+		self.use_initial_flow = use_initial_flow;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::cudaoptflow::CUDA_SparsePyrLKOpticalFlow>> {
+		<dyn CUDA_SparsePyrLKOpticalFlow>::create(self.win_size, self.max_level, self.iters, self.use_initial_flow)
+	}
+}
+
+impl Default for CUDA_SparsePyrLKOpticalFlow_CreateBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
 }
\ No newline at end of file
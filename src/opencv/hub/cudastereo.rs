@@ -27,13 +27,63 @@ pub mod prelude {
 #[inline]
 pub fn create_disparity_bilateral_filter(ndisp: i32, radius: i32, iters: i32) -> Result<core::Ptr<dyn crate::cudastereo::CUDA_DisparityBilateralFilter>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createDisparityBilateralFilter_int_int_int(ndisp, radius, iters, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createDisparityBilateralFilter_int_int_int(ndisp, radius, iters, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudastereo::CUDA_DisparityBilateralFilter>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_disparity_bilateral_filter()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateDisparityBilateralFilterBuilder {
+	ndisp: i32,
+	radius: i32,
+	iters: i32,
+}
+
+impl CreateDisparityBilateralFilterBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { ndisp: 64, radius: 3, iters: 1 }
+	}
+	
+	/// C++ default: `64`
+	#[inline]
+	pub fn with_ndisp(mut self, ndisp: i32) -> Self {
+		self.ndisp = ndisp;
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_radius(mut self, radius: i32) -> Self {
+		self.radius = radius;
+		self
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_iters(mut self, iters: i32) -> Self {
+		self.iters = iters;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::cudastereo::CUDA_DisparityBilateralFilter>> {
+		create_disparity_bilateral_filter(self.ndisp, self.radius, self.iters)
+	}
+}
+
+impl Default for CreateDisparityBilateralFilterBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 /// Creates StereoBM object.
 /// 
 /// ## Parameters
@@ -51,7 +101,7 @@ pub fn create_disparity_bilateral_filter(ndisp: i32, radius: i32, iters: i32) ->
 #[inline]
 pub fn create_stereo_bm(num_disparities: i32, block_size: i32) -> Result<core::Ptr<dyn crate::cudastereo::CUDA_StereoBM>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createStereoBM_int_int(num_disparities, block_size, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createStereoBM_int_int(num_disparities, block_size, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudastereo::CUDA_StereoBM>::opencv_from_extern(ret) };
@@ -74,13 +124,57 @@ pub fn create_stereo_bm(num_disparities: i32, block_size: i32) -> Result<core::P
 #[inline]
 pub fn create_stereo_belief_propagation(ndisp: i32, iters: i32, levels: i32, msg_type: i32) -> Result<core::Ptr<dyn crate::cudastereo::CUDA_StereoBeliefPropagation>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createStereoBeliefPropagation_int_int_int_int(ndisp, iters, levels, msg_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createStereoBeliefPropagation_int_int_int_int(ndisp, iters, levels, msg_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudastereo::CUDA_StereoBeliefPropagation>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_stereo_belief_propagation()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateStereoBeliefPropagationBuilder {
+	ndisp: i32,
+	iters: i32,
+	levels: i32,
+	msg_type: i32,
+}
+
+impl CreateStereoBeliefPropagationBuilder {
+	#[inline]
+	pub fn new(msg_type: i32) -> Self {
+		Self { ndisp: 64, iters: 5, levels: 5, msg_type }
+	}
+	
+	/// C++ default: `64`
+	#[inline]
+	pub fn with_ndisp(mut self, ndisp: i32) -> Self {
+		self.ndisp = ndisp;
+		self
+	}
+	
+	/// C++ default: `5`
+	#[inline]
+	pub fn with_iters(mut self, iters: i32) -> Self {
+		self.iters = iters;
+		self
+	}
+	
+	/// C++ default: `5`
+	#[inline]
+	pub fn with_levels(mut self, levels: i32) -> Self {
+		self.levels = levels;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::cudastereo::CUDA_StereoBeliefPropagation>> {
+		create_stereo_belief_propagation(self.ndisp, self.iters, self.levels, self.msg_type)
+	}
+}
+
 /// Creates StereoConstantSpaceBP object.
 /// 
 /// ## Parameters
@@ -99,13 +193,65 @@ pub fn create_stereo_belief_propagation(ndisp: i32, iters: i32, levels: i32, msg
 #[inline]
 pub fn create_stereo_constant_space_bp(ndisp: i32, iters: i32, levels: i32, nr_plane: i32, msg_type: i32) -> Result<core::Ptr<dyn crate::cudastereo::CUDA_StereoConstantSpaceBP>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createStereoConstantSpaceBP_int_int_int_int_int(ndisp, iters, levels, nr_plane, msg_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createStereoConstantSpaceBP_int_int_int_int_int(ndisp, iters, levels, nr_plane, msg_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudastereo::CUDA_StereoConstantSpaceBP>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_stereo_constant_space_bp()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateStereoConstantSpaceBpBuilder {
+	ndisp: i32,
+	iters: i32,
+	levels: i32,
+	nr_plane: i32,
+	msg_type: i32,
+}
+
+impl CreateStereoConstantSpaceBpBuilder {
+	#[inline]
+	pub fn new(msg_type: i32) -> Self {
+		Self { ndisp: 128, iters: 8, levels: 4, nr_plane: 4, msg_type }
+	}
+	
+	/// C++ default: `128`
+	#[inline]
+	pub fn with_ndisp(mut self, ndisp: i32) -> Self {
+		self.ndisp = ndisp;
+		self
+	}
+	
+	/// C++ default: `8`
+	#[inline]
+	pub fn with_iters(mut self, iters: i32) -> Self {
+		self.iters = iters;
+		self
+	}
+	
+	/// C++ default: `4`
+	#[inline]
+	pub fn with_levels(mut self, levels: i32) -> Self {
+		self.levels = levels;
+		self
+	}
+	
+	/// C++ default: `4`
+	#[inline]
+	pub fn with_nr_plane(mut self, nr_plane: i32) -> Self {
+		self.nr_plane = nr_plane;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::cudastereo::CUDA_StereoConstantSpaceBP>> {
+		create_stereo_constant_space_bp(self.ndisp, self.iters, self.levels, self.nr_plane, self.msg_type)
+	}
+}
+
 /// Creates StereoSGM object.
 /// 
 /// ## Parameters
@@ -129,13 +275,73 @@ pub fn create_stereo_constant_space_bp(ndisp: i32, iters: i32, levels: i32, nr_p
 #[inline]
 pub fn create_stereo_sgm(min_disparity: i32, num_disparities: i32, p1: i32, p2: i32, uniqueness_ratio: i32, mode: i32) -> Result<core::Ptr<dyn crate::cudastereo::CUDA_StereoSGM>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_createStereoSGM_int_int_int_int_int_int(min_disparity, num_disparities, p1, p2, uniqueness_ratio, mode, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_createStereoSGM_int_int_int_int_int_int(min_disparity, num_disparities, p1, p2, uniqueness_ratio, mode, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::cudastereo::CUDA_StereoSGM>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_stereo_sgm()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateStereoSgmBuilder {
+	min_disparity: i32,
+	num_disparities: i32,
+	p1: i32,
+	p2: i32,
+	uniqueness_ratio: i32,
+	mode: i32,
+}
+
+impl CreateStereoSgmBuilder {
+	#[inline]
+	pub fn new(mode: i32) -> Self {
+		Self { min_disparity: 0, num_disparities: 128, p1: 10, p2: 120, uniqueness_ratio: 5, mode }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_min_disparity(mut self, min_disparity: i32) -> Self {
+		self.min_disparity = min_disparity;
+		self
+	}
+	
+	/// C++ default: `128`
+	#[inline]
+	pub fn with_num_disparities(mut self, num_disparities: i32) -> Self {
+		self.num_disparities = num_disparities;
+		self
+	}
+	
+	/// C++ default: `10`
+	#[inline]
+	pub fn with_p1(mut self, p1: i32) -> Self {
+		self.p1 = p1;
+		self
+	}
+	
+	/// C++ default: `120`
+	#[inline]
+	pub fn with_p2(mut self, p2: i32) -> Self {
+		self.p2 = p2;
+		self
+	}
+	
+	/// C++ default: `5`
+	#[inline]
+	pub fn with_uniqueness_ratio(mut self, uniqueness_ratio: i32) -> Self {
+		self.uniqueness_ratio = uniqueness_ratio;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::cudastereo::CUDA_StereoSGM>> {
+		create_stereo_sgm(self.min_disparity, self.num_disparities, self.p1, self.p2, self.uniqueness_ratio, self.mode)
+	}
+}
+
 /// Colors a disparity image.
 /// 
 /// ## Parameters
@@ -147,7 +353,7 @@ pub fn create_stereo_sgm(min_disparity: i32, num_disparities: i32, p1: i32, p2:
 /// * ndisp: Number of disparities.
 /// * stream: Stream for the asynchronous version.
 /// 
-/// This function draws a colored disparity map by converting disparity values from [0..ndisp) interval
+/// This function draws a colored disparity map by converting disparity values from \[0..ndisp) interval
 /// first to HSV color space (where different disparity values correspond to different hues) and then
 /// converting the pixels to RGB for visualization.
 /// 
@@ -158,7 +364,7 @@ pub fn draw_color_disp(src_disp: &dyn core::ToInputArray, dst_disp: &mut dyn cor
 	input_array_arg!(src_disp);
 	output_array_arg!(dst_disp);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_drawColorDisp_const__InputArrayR_const__OutputArrayR_int_StreamR(src_disp.as_raw__InputArray(), dst_disp.as_raw__OutputArray(), ndisp, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_drawColorDisp_const__InputArrayR_const__OutputArrayR_int_StreamR(src_disp.as_raw__InputArray(), dst_disp.as_raw__OutputArray(), ndisp, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -177,7 +383,7 @@ pub fn draw_color_disp(src_disp: &dyn core::ToInputArray, dst_disp: &mut dyn cor
 /// * dst_cn: The number of channels for output image. Can be 3 or 4.
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// reprojectImageTo3D
+/// [reproject_image_to_3d](crate::cudastereo::reproject_image_to_3d)
 /// 
 /// ## C++ default parameters
 /// * dst_cn: 4
@@ -188,7 +394,7 @@ pub fn reproject_image_to_3d(disp: &dyn core::ToInputArray, xyzw: &mut dyn core:
 	output_array_arg!(xyzw);
 	input_array_arg!(q);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_reprojectImageTo3D_const__InputArrayR_const__OutputArrayR_const__InputArrayR_int_StreamR(disp.as_raw__InputArray(), xyzw.as_raw__OutputArray(), q.as_raw__InputArray(), dst_cn, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_reprojectImageTo3D_const__InputArrayR_const__OutputArrayR_const__InputArrayR_int_StreamR(disp.as_raw__InputArray(), xyzw.as_raw__OutputArray(), q.as_raw__InputArray(), dst_cn, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -202,8 +408,9 @@ pub trait CUDA_DisparityBilateralFilterConst: core::AlgorithmTraitConst {
 
 	#[inline]
 	fn get_num_disparities(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_DisparityBilateralFilter", self.as_raw_CUDA_DisparityBilateralFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DisparityBilateralFilter_getNumDisparities_const(self.as_raw_CUDA_DisparityBilateralFilter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DisparityBilateralFilter_getNumDisparities_const(self.as_raw_CUDA_DisparityBilateralFilter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -211,8 +418,9 @@ pub trait CUDA_DisparityBilateralFilterConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_radius(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_DisparityBilateralFilter", self.as_raw_CUDA_DisparityBilateralFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DisparityBilateralFilter_getRadius_const(self.as_raw_CUDA_DisparityBilateralFilter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DisparityBilateralFilter_getRadius_const(self.as_raw_CUDA_DisparityBilateralFilter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -220,8 +428,9 @@ pub trait CUDA_DisparityBilateralFilterConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_num_iters(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_DisparityBilateralFilter", self.as_raw_CUDA_DisparityBilateralFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DisparityBilateralFilter_getNumIters_const(self.as_raw_CUDA_DisparityBilateralFilter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DisparityBilateralFilter_getNumIters_const(self.as_raw_CUDA_DisparityBilateralFilter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -230,8 +439,9 @@ pub trait CUDA_DisparityBilateralFilterConst: core::AlgorithmTraitConst {
 	/// truncation of data continuity
 	#[inline]
 	fn get_edge_threshold(&self) -> Result<f64> {
+		crate::core::validity::check("CUDA_DisparityBilateralFilter", self.as_raw_CUDA_DisparityBilateralFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DisparityBilateralFilter_getEdgeThreshold_const(self.as_raw_CUDA_DisparityBilateralFilter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DisparityBilateralFilter_getEdgeThreshold_const(self.as_raw_CUDA_DisparityBilateralFilter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -240,8 +450,9 @@ pub trait CUDA_DisparityBilateralFilterConst: core::AlgorithmTraitConst {
 	/// truncation of disparity continuity
 	#[inline]
 	fn get_max_disc_threshold(&self) -> Result<f64> {
+		crate::core::validity::check("CUDA_DisparityBilateralFilter", self.as_raw_CUDA_DisparityBilateralFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DisparityBilateralFilter_getMaxDiscThreshold_const(self.as_raw_CUDA_DisparityBilateralFilter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DisparityBilateralFilter_getMaxDiscThreshold_const(self.as_raw_CUDA_DisparityBilateralFilter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -250,8 +461,9 @@ pub trait CUDA_DisparityBilateralFilterConst: core::AlgorithmTraitConst {
 	/// filter range sigma
 	#[inline]
 	fn get_sigma_range(&self) -> Result<f64> {
+		crate::core::validity::check("CUDA_DisparityBilateralFilter", self.as_raw_CUDA_DisparityBilateralFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DisparityBilateralFilter_getSigmaRange_const(self.as_raw_CUDA_DisparityBilateralFilter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DisparityBilateralFilter_getSigmaRange_const(self.as_raw_CUDA_DisparityBilateralFilter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -274,11 +486,12 @@ pub trait CUDA_DisparityBilateralFilter: core::AlgorithmTrait + crate::cudastere
 	/// * stream: Stream::Null()
 	#[inline]
 	fn apply(&mut self, disparity: &dyn core::ToInputArray, image: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CUDA_DisparityBilateralFilter", self.as_raw_mut_CUDA_DisparityBilateralFilter())?;
 		input_array_arg!(disparity);
 		input_array_arg!(image);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DisparityBilateralFilter_apply_const__InputArrayR_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_DisparityBilateralFilter(), disparity.as_raw__InputArray(), image.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DisparityBilateralFilter_apply_const__InputArrayR_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_DisparityBilateralFilter(), disparity.as_raw__InputArray(), image.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -286,8 +499,9 @@ pub trait CUDA_DisparityBilateralFilter: core::AlgorithmTrait + crate::cudastere
 	
 	#[inline]
 	fn set_num_disparities(&mut self, num_disparities: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_DisparityBilateralFilter", self.as_raw_mut_CUDA_DisparityBilateralFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DisparityBilateralFilter_setNumDisparities_int(self.as_raw_mut_CUDA_DisparityBilateralFilter(), num_disparities, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DisparityBilateralFilter_setNumDisparities_int(self.as_raw_mut_CUDA_DisparityBilateralFilter(), num_disparities, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -295,8 +509,9 @@ pub trait CUDA_DisparityBilateralFilter: core::AlgorithmTrait + crate::cudastere
 	
 	#[inline]
 	fn set_radius(&mut self, radius: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_DisparityBilateralFilter", self.as_raw_mut_CUDA_DisparityBilateralFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DisparityBilateralFilter_setRadius_int(self.as_raw_mut_CUDA_DisparityBilateralFilter(), radius, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DisparityBilateralFilter_setRadius_int(self.as_raw_mut_CUDA_DisparityBilateralFilter(), radius, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -304,8 +519,9 @@ pub trait CUDA_DisparityBilateralFilter: core::AlgorithmTrait + crate::cudastere
 	
 	#[inline]
 	fn set_num_iters(&mut self, iters: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_DisparityBilateralFilter", self.as_raw_mut_CUDA_DisparityBilateralFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DisparityBilateralFilter_setNumIters_int(self.as_raw_mut_CUDA_DisparityBilateralFilter(), iters, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DisparityBilateralFilter_setNumIters_int(self.as_raw_mut_CUDA_DisparityBilateralFilter(), iters, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -313,8 +529,9 @@ pub trait CUDA_DisparityBilateralFilter: core::AlgorithmTrait + crate::cudastere
 	
 	#[inline]
 	fn set_edge_threshold(&mut self, edge_threshold: f64) -> Result<()> {
+		crate::core::validity::check("CUDA_DisparityBilateralFilter", self.as_raw_mut_CUDA_DisparityBilateralFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DisparityBilateralFilter_setEdgeThreshold_double(self.as_raw_mut_CUDA_DisparityBilateralFilter(), edge_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DisparityBilateralFilter_setEdgeThreshold_double(self.as_raw_mut_CUDA_DisparityBilateralFilter(), edge_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -322,8 +539,9 @@ pub trait CUDA_DisparityBilateralFilter: core::AlgorithmTrait + crate::cudastere
 	
 	#[inline]
 	fn set_max_disc_threshold(&mut self, max_disc_threshold: f64) -> Result<()> {
+		crate::core::validity::check("CUDA_DisparityBilateralFilter", self.as_raw_mut_CUDA_DisparityBilateralFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DisparityBilateralFilter_setMaxDiscThreshold_double(self.as_raw_mut_CUDA_DisparityBilateralFilter(), max_disc_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DisparityBilateralFilter_setMaxDiscThreshold_double(self.as_raw_mut_CUDA_DisparityBilateralFilter(), max_disc_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -331,8 +549,9 @@ pub trait CUDA_DisparityBilateralFilter: core::AlgorithmTrait + crate::cudastere
 	
 	#[inline]
 	fn set_sigma_range(&mut self, sigma_range: f64) -> Result<()> {
+		crate::core::validity::check("CUDA_DisparityBilateralFilter", self.as_raw_mut_CUDA_DisparityBilateralFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_DisparityBilateralFilter_setSigmaRange_double(self.as_raw_mut_CUDA_DisparityBilateralFilter(), sigma_range, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_DisparityBilateralFilter_setSigmaRange_double(self.as_raw_mut_CUDA_DisparityBilateralFilter(), sigma_range, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -342,7 +561,7 @@ pub trait CUDA_DisparityBilateralFilter: core::AlgorithmTrait + crate::cudastere
 
 /// Class computing stereo correspondence (disparity map) using the block matching algorithm. :
 /// ## See also
-/// StereoBM
+/// `StereoBM`
 pub trait CUDA_StereoBMConst: crate::calib3d::StereoBMConst {
 	fn as_raw_CUDA_StereoBM(&self) -> *const c_void;
 
@@ -353,11 +572,12 @@ pub trait CUDA_StereoBM: crate::calib3d::StereoBM + crate::cudastereo::CUDA_Ster
 
 	#[inline]
 	fn compute(&mut self, left: &dyn core::ToInputArray, right: &dyn core::ToInputArray, disparity: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CUDA_StereoBM", self.as_raw_mut_CUDA_StereoBM())?;
 		input_array_arg!(left);
 		input_array_arg!(right);
 		output_array_arg!(disparity);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_StereoBM_compute_const__InputArrayR_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_StereoBM(), left.as_raw__InputArray(), right.as_raw__InputArray(), disparity.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_StereoBM_compute_const__InputArrayR_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_StereoBM(), left.as_raw__InputArray(), right.as_raw__InputArray(), disparity.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -397,15 +617,16 @@ pub trait CUDA_StereoBM: crate::calib3d::StereoBM + crate::cudastereo::CUDA_Ster
 /// 
 /// ![block formula](https://latex.codecogs.com/png.latex?10%20%20%5Ccdot%202%5E%7Blevels%2D1%7D%20%20%5Ccdot%20max%20%5C%5F%20data%20%5C%5F%20term%20%3C%20SHRT%20%5C%5F%20MAX)
 /// ## See also
-/// StereoMatcher
+/// `StereoMatcher`
 pub trait CUDA_StereoBeliefPropagationConst: crate::calib3d::StereoMatcherConst {
 	fn as_raw_CUDA_StereoBeliefPropagation(&self) -> *const c_void;
 
 	/// number of BP iterations on each level
 	#[inline]
 	fn get_num_iters(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_StereoBeliefPropagation", self.as_raw_CUDA_StereoBeliefPropagation())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_StereoBeliefPropagation_getNumIters_const(self.as_raw_CUDA_StereoBeliefPropagation(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_StereoBeliefPropagation_getNumIters_const(self.as_raw_CUDA_StereoBeliefPropagation(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -414,8 +635,9 @@ pub trait CUDA_StereoBeliefPropagationConst: crate::calib3d::StereoMatcherConst
 	/// number of levels
 	#[inline]
 	fn get_num_levels(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_StereoBeliefPropagation", self.as_raw_CUDA_StereoBeliefPropagation())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_StereoBeliefPropagation_getNumLevels_const(self.as_raw_CUDA_StereoBeliefPropagation(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_StereoBeliefPropagation_getNumLevels_const(self.as_raw_CUDA_StereoBeliefPropagation(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -424,8 +646,9 @@ pub trait CUDA_StereoBeliefPropagationConst: crate::calib3d::StereoMatcherConst
 	/// truncation of data cost
 	#[inline]
 	fn get_max_data_term(&self) -> Result<f64> {
+		crate::core::validity::check("CUDA_StereoBeliefPropagation", self.as_raw_CUDA_StereoBeliefPropagation())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_StereoBeliefPropagation_getMaxDataTerm_const(self.as_raw_CUDA_StereoBeliefPropagation(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_StereoBeliefPropagation_getMaxDataTerm_const(self.as_raw_CUDA_StereoBeliefPropagation(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -434,8 +657,9 @@ pub trait CUDA_StereoBeliefPropagationConst: crate::calib3d::StereoMatcherConst
 	/// data weight
 	#[inline]
 	fn get_data_weight(&self) -> Result<f64> {
+		crate::core::validity::check("CUDA_StereoBeliefPropagation", self.as_raw_CUDA_StereoBeliefPropagation())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_StereoBeliefPropagation_getDataWeight_const(self.as_raw_CUDA_StereoBeliefPropagation(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_StereoBeliefPropagation_getDataWeight_const(self.as_raw_CUDA_StereoBeliefPropagation(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -444,8 +668,9 @@ pub trait CUDA_StereoBeliefPropagationConst: crate::calib3d::StereoMatcherConst
 	/// truncation of discontinuity cost
 	#[inline]
 	fn get_max_disc_term(&self) -> Result<f64> {
+		crate::core::validity::check("CUDA_StereoBeliefPropagation", self.as_raw_CUDA_StereoBeliefPropagation())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_StereoBeliefPropagation_getMaxDiscTerm_const(self.as_raw_CUDA_StereoBeliefPropagation(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_StereoBeliefPropagation_getMaxDiscTerm_const(self.as_raw_CUDA_StereoBeliefPropagation(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -454,8 +679,9 @@ pub trait CUDA_StereoBeliefPropagationConst: crate::calib3d::StereoMatcherConst
 	/// discontinuity single jump
 	#[inline]
 	fn get_disc_single_jump(&self) -> Result<f64> {
+		crate::core::validity::check("CUDA_StereoBeliefPropagation", self.as_raw_CUDA_StereoBeliefPropagation())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_StereoBeliefPropagation_getDiscSingleJump_const(self.as_raw_CUDA_StereoBeliefPropagation(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_StereoBeliefPropagation_getDiscSingleJump_const(self.as_raw_CUDA_StereoBeliefPropagation(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -464,8 +690,9 @@ pub trait CUDA_StereoBeliefPropagationConst: crate::calib3d::StereoMatcherConst
 	/// type for messages (CV_16SC1 or CV_32FC1)
 	#[inline]
 	fn get_msg_type(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_StereoBeliefPropagation", self.as_raw_CUDA_StereoBeliefPropagation())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_StereoBeliefPropagation_getMsgType_const(self.as_raw_CUDA_StereoBeliefPropagation(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_StereoBeliefPropagation_getMsgType_const(self.as_raw_CUDA_StereoBeliefPropagation(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -489,11 +716,12 @@ pub trait CUDA_StereoBeliefPropagation: crate::calib3d::StereoMatcher + crate::c
 	/// ## Overloaded parameters
 	#[inline]
 	fn compute(&mut self, left: &dyn core::ToInputArray, right: &dyn core::ToInputArray, disparity: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CUDA_StereoBeliefPropagation", self.as_raw_mut_CUDA_StereoBeliefPropagation())?;
 		input_array_arg!(left);
 		input_array_arg!(right);
 		output_array_arg!(disparity);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_StereoBeliefPropagation_compute_const__InputArrayR_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_StereoBeliefPropagation(), left.as_raw__InputArray(), right.as_raw__InputArray(), disparity.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_StereoBeliefPropagation_compute_const__InputArrayR_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_StereoBeliefPropagation(), left.as_raw__InputArray(), right.as_raw__InputArray(), disparity.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -513,10 +741,11 @@ pub trait CUDA_StereoBeliefPropagation: crate::calib3d::StereoMatcher + crate::c
 	/// * stream: Stream::Null()
 	#[inline]
 	fn compute_1(&mut self, data: &dyn core::ToInputArray, disparity: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CUDA_StereoBeliefPropagation", self.as_raw_mut_CUDA_StereoBeliefPropagation())?;
 		input_array_arg!(data);
 		output_array_arg!(disparity);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_StereoBeliefPropagation_compute_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_StereoBeliefPropagation(), data.as_raw__InputArray(), disparity.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_StereoBeliefPropagation_compute_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_StereoBeliefPropagation(), data.as_raw__InputArray(), disparity.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -524,8 +753,9 @@ pub trait CUDA_StereoBeliefPropagation: crate::calib3d::StereoMatcher + crate::c
 	
 	#[inline]
 	fn set_num_iters(&mut self, iters: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_StereoBeliefPropagation", self.as_raw_mut_CUDA_StereoBeliefPropagation())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_StereoBeliefPropagation_setNumIters_int(self.as_raw_mut_CUDA_StereoBeliefPropagation(), iters, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_StereoBeliefPropagation_setNumIters_int(self.as_raw_mut_CUDA_StereoBeliefPropagation(), iters, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -533,8 +763,9 @@ pub trait CUDA_StereoBeliefPropagation: crate::calib3d::StereoMatcher + crate::c
 	
 	#[inline]
 	fn set_num_levels(&mut self, levels: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_StereoBeliefPropagation", self.as_raw_mut_CUDA_StereoBeliefPropagation())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_StereoBeliefPropagation_setNumLevels_int(self.as_raw_mut_CUDA_StereoBeliefPropagation(), levels, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_StereoBeliefPropagation_setNumLevels_int(self.as_raw_mut_CUDA_StereoBeliefPropagation(), levels, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -542,8 +773,9 @@ pub trait CUDA_StereoBeliefPropagation: crate::calib3d::StereoMatcher + crate::c
 	
 	#[inline]
 	fn set_max_data_term(&mut self, max_data_term: f64) -> Result<()> {
+		crate::core::validity::check("CUDA_StereoBeliefPropagation", self.as_raw_mut_CUDA_StereoBeliefPropagation())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_StereoBeliefPropagation_setMaxDataTerm_double(self.as_raw_mut_CUDA_StereoBeliefPropagation(), max_data_term, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_StereoBeliefPropagation_setMaxDataTerm_double(self.as_raw_mut_CUDA_StereoBeliefPropagation(), max_data_term, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -551,8 +783,9 @@ pub trait CUDA_StereoBeliefPropagation: crate::calib3d::StereoMatcher + crate::c
 	
 	#[inline]
 	fn set_data_weight(&mut self, data_weight: f64) -> Result<()> {
+		crate::core::validity::check("CUDA_StereoBeliefPropagation", self.as_raw_mut_CUDA_StereoBeliefPropagation())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_StereoBeliefPropagation_setDataWeight_double(self.as_raw_mut_CUDA_StereoBeliefPropagation(), data_weight, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_StereoBeliefPropagation_setDataWeight_double(self.as_raw_mut_CUDA_StereoBeliefPropagation(), data_weight, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -560,8 +793,9 @@ pub trait CUDA_StereoBeliefPropagation: crate::calib3d::StereoMatcher + crate::c
 	
 	#[inline]
 	fn set_max_disc_term(&mut self, max_disc_term: f64) -> Result<()> {
+		crate::core::validity::check("CUDA_StereoBeliefPropagation", self.as_raw_mut_CUDA_StereoBeliefPropagation())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_StereoBeliefPropagation_setMaxDiscTerm_double(self.as_raw_mut_CUDA_StereoBeliefPropagation(), max_disc_term, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_StereoBeliefPropagation_setMaxDiscTerm_double(self.as_raw_mut_CUDA_StereoBeliefPropagation(), max_disc_term, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -569,8 +803,9 @@ pub trait CUDA_StereoBeliefPropagation: crate::calib3d::StereoMatcher + crate::c
 	
 	#[inline]
 	fn set_disc_single_jump(&mut self, disc_single_jump: f64) -> Result<()> {
+		crate::core::validity::check("CUDA_StereoBeliefPropagation", self.as_raw_mut_CUDA_StereoBeliefPropagation())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_StereoBeliefPropagation_setDiscSingleJump_double(self.as_raw_mut_CUDA_StereoBeliefPropagation(), disc_single_jump, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_StereoBeliefPropagation_setDiscSingleJump_double(self.as_raw_mut_CUDA_StereoBeliefPropagation(), disc_single_jump, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -578,8 +813,9 @@ pub trait CUDA_StereoBeliefPropagation: crate::calib3d::StereoMatcher + crate::c
 	
 	#[inline]
 	fn set_msg_type(&mut self, msg_type: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_StereoBeliefPropagation", self.as_raw_mut_CUDA_StereoBeliefPropagation())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_StereoBeliefPropagation_setMsgType_int(self.as_raw_mut_CUDA_StereoBeliefPropagation(), msg_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_StereoBeliefPropagation_setMsgType_int(self.as_raw_mut_CUDA_StereoBeliefPropagation(), msg_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -593,7 +829,7 @@ impl dyn CUDA_StereoBeliefPropagation + '_ {
 	#[inline]
 	pub fn estimate_recommended_params(width: i32, height: i32, ndisp: &mut i32, iters: &mut i32, levels: &mut i32) -> Result<()> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_StereoBeliefPropagation_estimateRecommendedParams_int_int_intR_intR_intR(width, height, ndisp, iters, levels, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_StereoBeliefPropagation_estimateRecommendedParams_int_int_intR_intR_intR(width, height, ndisp, iters, levels, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -627,8 +863,9 @@ pub trait CUDA_StereoConstantSpaceBPConst: crate::cudastereo::CUDA_StereoBeliefP
 	/// number of active disparity on the first level
 	#[inline]
 	fn get_nr_plane(&self) -> Result<i32> {
+		crate::core::validity::check("CUDA_StereoConstantSpaceBP", self.as_raw_CUDA_StereoConstantSpaceBP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_StereoConstantSpaceBP_getNrPlane_const(self.as_raw_CUDA_StereoConstantSpaceBP(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_StereoConstantSpaceBP_getNrPlane_const(self.as_raw_CUDA_StereoConstantSpaceBP(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -636,8 +873,9 @@ pub trait CUDA_StereoConstantSpaceBPConst: crate::cudastereo::CUDA_StereoBeliefP
 	
 	#[inline]
 	fn get_use_local_init_data_cost(&self) -> Result<bool> {
+		crate::core::validity::check("CUDA_StereoConstantSpaceBP", self.as_raw_CUDA_StereoConstantSpaceBP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_StereoConstantSpaceBP_getUseLocalInitDataCost_const(self.as_raw_CUDA_StereoConstantSpaceBP(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_StereoConstantSpaceBP_getUseLocalInitDataCost_const(self.as_raw_CUDA_StereoConstantSpaceBP(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -650,8 +888,9 @@ pub trait CUDA_StereoConstantSpaceBP: crate::cudastereo::CUDA_StereoBeliefPropag
 
 	#[inline]
 	fn set_nr_plane(&mut self, nr_plane: i32) -> Result<()> {
+		crate::core::validity::check("CUDA_StereoConstantSpaceBP", self.as_raw_mut_CUDA_StereoConstantSpaceBP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_StereoConstantSpaceBP_setNrPlane_int(self.as_raw_mut_CUDA_StereoConstantSpaceBP(), nr_plane, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_StereoConstantSpaceBP_setNrPlane_int(self.as_raw_mut_CUDA_StereoConstantSpaceBP(), nr_plane, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -659,8 +898,9 @@ pub trait CUDA_StereoConstantSpaceBP: crate::cudastereo::CUDA_StereoBeliefPropag
 	
 	#[inline]
 	fn set_use_local_init_data_cost(&mut self, use_local_init_data_cost: bool) -> Result<()> {
+		crate::core::validity::check("CUDA_StereoConstantSpaceBP", self.as_raw_mut_CUDA_StereoConstantSpaceBP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_StereoConstantSpaceBP_setUseLocalInitDataCost_bool(self.as_raw_mut_CUDA_StereoConstantSpaceBP(), use_local_init_data_cost, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_StereoConstantSpaceBP_setUseLocalInitDataCost_bool(self.as_raw_mut_CUDA_StereoConstantSpaceBP(), use_local_init_data_cost, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -674,7 +914,7 @@ impl dyn CUDA_StereoConstantSpaceBP + '_ {
 	#[inline]
 	pub fn estimate_recommended_params(width: i32, height: i32, ndisp: &mut i32, iters: &mut i32, levels: &mut i32, nr_plane: &mut i32) -> Result<()> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_StereoConstantSpaceBP_estimateRecommendedParams_int_int_intR_intR_intR_intR(width, height, ndisp, iters, levels, nr_plane, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_StereoConstantSpaceBP_estimateRecommendedParams_int_int_intR_intR_intR_intR(width, height, ndisp, iters, levels, nr_plane, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -690,7 +930,7 @@ impl dyn CUDA_StereoConstantSpaceBP + '_ {
 /// Instead, Center-Symmetric Census Transform with ![inline formula](https://latex.codecogs.com/png.latex?9%20%5Ctimes%207) window size from [Spangenberg2013](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_Spangenberg2013)
 /// is used for robustness.
 /// ## See also
-/// cv::StereoSGBM
+/// `StereoSGBM`
 pub trait CUDA_StereoSGMConst: crate::calib3d::StereoSGBMConst {
 	fn as_raw_CUDA_StereoSGM(&self) -> *const c_void;
 
@@ -708,11 +948,12 @@ pub trait CUDA_StereoSGM: crate::calib3d::StereoSGBM + crate::cudastereo::CUDA_S
 	/// StereoSGM computes 16-bit fixed-point disparity map (where each disparity value has 4 fractional bits).
 	#[inline]
 	fn compute(&mut self, left: &dyn core::ToInputArray, right: &dyn core::ToInputArray, disparity: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("CUDA_StereoSGM", self.as_raw_mut_CUDA_StereoSGM())?;
 		input_array_arg!(left);
 		input_array_arg!(right);
 		output_array_arg!(disparity);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_StereoSGM_compute_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_CUDA_StereoSGM(), left.as_raw__InputArray(), right.as_raw__InputArray(), disparity.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_StereoSGM_compute_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_CUDA_StereoSGM(), left.as_raw__InputArray(), right.as_raw__InputArray(), disparity.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -720,14 +961,15 @@ pub trait CUDA_StereoSGM: crate::calib3d::StereoSGBM + crate::cudastereo::CUDA_S
 	
 	/// Computes disparity map with specified CUDA Stream
 	/// ## See also
-	/// compute
+	/// `compute`
 	#[inline]
 	fn compute_with_stream(&mut self, left: &dyn core::ToInputArray, right: &dyn core::ToInputArray, disparity: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+		crate::core::validity::check("CUDA_StereoSGM", self.as_raw_mut_CUDA_StereoSGM())?;
 		input_array_arg!(left);
 		input_array_arg!(right);
 		output_array_arg!(disparity);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_StereoSGM_compute_const__InputArrayR_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_StereoSGM(), left.as_raw__InputArray(), right.as_raw__InputArray(), disparity.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_StereoSGM_compute_const__InputArrayR_const__InputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_StereoSGM(), left.as_raw__InputArray(), right.as_raw__InputArray(), disparity.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
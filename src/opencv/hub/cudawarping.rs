@@ -18,7 +18,7 @@ pub mod prelude {
 #[inline]
 pub fn build_warp_affine_maps_2(mut m: core::Mat, inverse: bool, dsize: core::Size, xmap: &mut core::GpuMat, ymap: &mut core::GpuMat, stream: &mut core::Stream) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_buildWarpAffineMaps_Mat_bool_Size_GpuMatR_GpuMatR_StreamR(m.as_raw_mut_Mat(), inverse, dsize.opencv_as_extern(), xmap.as_raw_mut_GpuMat(), ymap.as_raw_mut_GpuMat(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_buildWarpAffineMaps_Mat_bool_Size_GpuMatR_GpuMatR_StreamR(m.as_raw_mut_Mat(), inverse, dsize.opencv_as_extern(), xmap.as_raw_mut_GpuMat(), ymap.as_raw_mut_GpuMat(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -29,7 +29,7 @@ pub fn build_warp_affine_maps_2(mut m: core::Mat, inverse: bool, dsize: core::Si
 #[inline]
 pub fn build_warp_affine_maps_1(mut m: core::UMat, inverse: bool, dsize: core::Size, xmap: &mut core::GpuMat, ymap: &mut core::GpuMat, stream: &mut core::Stream) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_buildWarpAffineMaps_UMat_bool_Size_GpuMatR_GpuMatR_StreamR(m.as_raw_mut_UMat(), inverse, dsize.opencv_as_extern(), xmap.as_raw_mut_GpuMat(), ymap.as_raw_mut_GpuMat(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_buildWarpAffineMaps_UMat_bool_Size_GpuMatR_GpuMatR_StreamR(m.as_raw_mut_UMat(), inverse, dsize.opencv_as_extern(), xmap.as_raw_mut_GpuMat(), ymap.as_raw_mut_GpuMat(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -55,7 +55,7 @@ pub fn build_warp_affine_maps(m: &dyn core::ToInputArray, inverse: bool, dsize:
 	output_array_arg!(xmap);
 	output_array_arg!(ymap);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_buildWarpAffineMaps_const__InputArrayR_bool_Size_const__OutputArrayR_const__OutputArrayR_StreamR(m.as_raw__InputArray(), inverse, dsize.opencv_as_extern(), xmap.as_raw__OutputArray(), ymap.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_buildWarpAffineMaps_const__InputArrayR_bool_Size_const__OutputArrayR_const__OutputArrayR_StreamR(m.as_raw__InputArray(), inverse, dsize.opencv_as_extern(), xmap.as_raw__OutputArray(), ymap.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -66,7 +66,7 @@ pub fn build_warp_affine_maps(m: &dyn core::ToInputArray, inverse: bool, dsize:
 #[inline]
 pub fn build_warp_perspective_maps_2(mut m: core::Mat, inverse: bool, dsize: core::Size, xmap: &mut core::GpuMat, ymap: &mut core::GpuMat, stream: &mut core::Stream) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_buildWarpPerspectiveMaps_Mat_bool_Size_GpuMatR_GpuMatR_StreamR(m.as_raw_mut_Mat(), inverse, dsize.opencv_as_extern(), xmap.as_raw_mut_GpuMat(), ymap.as_raw_mut_GpuMat(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_buildWarpPerspectiveMaps_Mat_bool_Size_GpuMatR_GpuMatR_StreamR(m.as_raw_mut_Mat(), inverse, dsize.opencv_as_extern(), xmap.as_raw_mut_GpuMat(), ymap.as_raw_mut_GpuMat(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -77,7 +77,7 @@ pub fn build_warp_perspective_maps_2(mut m: core::Mat, inverse: bool, dsize: cor
 #[inline]
 pub fn build_warp_perspective_maps_1(mut m: core::UMat, inverse: bool, dsize: core::Size, xmap: &mut core::GpuMat, ymap: &mut core::GpuMat, stream: &mut core::Stream) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_buildWarpPerspectiveMaps_UMat_bool_Size_GpuMatR_GpuMatR_StreamR(m.as_raw_mut_UMat(), inverse, dsize.opencv_as_extern(), xmap.as_raw_mut_GpuMat(), ymap.as_raw_mut_GpuMat(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_buildWarpPerspectiveMaps_UMat_bool_Size_GpuMatR_GpuMatR_StreamR(m.as_raw_mut_UMat(), inverse, dsize.opencv_as_extern(), xmap.as_raw_mut_GpuMat(), ymap.as_raw_mut_GpuMat(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -103,7 +103,7 @@ pub fn build_warp_perspective_maps(m: &dyn core::ToInputArray, inverse: bool, ds
 	output_array_arg!(xmap);
 	output_array_arg!(ymap);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_buildWarpPerspectiveMaps_const__InputArrayR_bool_Size_const__OutputArrayR_const__OutputArrayR_StreamR(m.as_raw__InputArray(), inverse, dsize.opencv_as_extern(), xmap.as_raw__OutputArray(), ymap.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_buildWarpPerspectiveMaps_const__InputArrayR_bool_Size_const__OutputArrayR_const__OutputArrayR_StreamR(m.as_raw__InputArray(), inverse, dsize.opencv_as_extern(), xmap.as_raw__OutputArray(), ymap.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -117,7 +117,7 @@ pub fn build_warp_perspective_maps(m: &dyn core::ToInputArray, inverse: bool, ds
 /// type as src .
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// pyrDown
+/// [pyr_down](crate::cudawarping::pyr_down)
 /// 
 /// ## C++ default parameters
 /// * stream: Stream::Null()
@@ -126,7 +126,7 @@ pub fn pyr_down(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray,
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_pyrDown_const__InputArrayR_const__OutputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_pyrDown_const__InputArrayR_const__OutputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -147,7 +147,7 @@ pub fn pyr_up(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, s
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_pyrUp_const__InputArrayR_const__OutputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_pyrUp_const__InputArrayR_const__OutputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -173,7 +173,7 @@ pub fn pyr_up(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, s
 /// 
 /// Values of pixels with non-integer coordinates are computed using the bilinear interpolation.
 /// ## See also
-/// remap
+/// [remap](crate::cudawarping::remap)
 /// 
 /// ## C++ default parameters
 /// * border_mode: BORDER_CONSTANT
@@ -186,12 +186,46 @@ pub fn remap(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, xm
 	input_array_arg!(xmap);
 	input_array_arg!(ymap);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_remap_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_int_int_Scalar_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), xmap.as_raw__InputArray(), ymap.as_raw__InputArray(), interpolation, border_mode, border_value.opencv_as_extern(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_remap_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_int_int_Scalar_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), xmap.as_raw__InputArray(), ymap.as_raw__InputArray(), interpolation, border_mode, border_value.opencv_as_extern(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `remap()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct RemapBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	xmap: &'a dyn core::ToInputArray,
+	ymap: &'a dyn core::ToInputArray,
+	interpolation: i32,
+	border_mode: i32,
+	border_value: core::Scalar,
+	stream: &'a mut core::Stream,
+}
+
+impl<'a> RemapBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, xmap: &'a dyn core::ToInputArray, ymap: &'a dyn core::ToInputArray, interpolation: i32, border_mode: i32, stream: &'a mut core::Stream) -> Self {
+		Self { src, dst, xmap, ymap, interpolation, border_mode, border_value: core::Scalar::default(), stream }
+	}
+	
+	/// C++ default: `Scalar()`
+	#[inline]
+	pub fn with_border_value(mut self, border_value: core::Scalar) -> Self {
+		self.border_value = border_value;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		remap(self.src, self.dst, self.xmap, self.ymap, self.interpolation, self.border_mode, self.border_value, self.stream)
+	}
+}
+
 /// Resizes an image.
 /// 
 /// ## Parameters
@@ -209,7 +243,7 @@ pub fn remap(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, xm
 /// supported for now.
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// resize
+/// [resize](crate::cudawarping::resize)
 /// 
 /// ## C++ default parameters
 /// * fx: 0
@@ -221,12 +255,52 @@ pub fn resize(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, d
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_resize_const__InputArrayR_const__OutputArrayR_Size_double_double_int_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), dsize.opencv_as_extern(), fx, fy, interpolation, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_resize_const__InputArrayR_const__OutputArrayR_Size_double_double_int_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), dsize.opencv_as_extern(), fx, fy, interpolation, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `resize()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct ResizeBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	dsize: core::Size,
+	fx: f64,
+	fy: f64,
+	interpolation: i32,
+	stream: &'a mut core::Stream,
+}
+
+impl<'a> ResizeBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, dsize: core::Size, interpolation: i32, stream: &'a mut core::Stream) -> Self {
+		Self { src, dst, dsize, fx: 0., fy: 0., interpolation, stream }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_fx(mut self, fx: f64) -> Self {
+		self.fx = fx;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_fy(mut self, fy: f64) -> Self {
+		self.fy = fy;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		resize(self.src, self.dst, self.dsize, self.fx, self.fy, self.interpolation, self.stream)
+	}
+}
+
 /// Rotates an image around the origin (0,0) and then shifts it.
 /// 
 /// ## Parameters
@@ -253,12 +327,53 @@ pub fn rotate(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, d
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_rotate_const__InputArrayR_const__OutputArrayR_Size_double_double_double_int_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), dsize.opencv_as_extern(), angle, x_shift, y_shift, interpolation, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_rotate_const__InputArrayR_const__OutputArrayR_Size_double_double_double_int_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), dsize.opencv_as_extern(), angle, x_shift, y_shift, interpolation, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `rotate()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct RotateBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	dsize: core::Size,
+	angle: f64,
+	x_shift: f64,
+	y_shift: f64,
+	interpolation: i32,
+	stream: &'a mut core::Stream,
+}
+
+impl<'a> RotateBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, dsize: core::Size, angle: f64, interpolation: i32, stream: &'a mut core::Stream) -> Self {
+		Self { src, dst, dsize, angle, x_shift: 0., y_shift: 0., interpolation, stream }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_x_shift(mut self, x_shift: f64) -> Self {
+		self.x_shift = x_shift;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_y_shift(mut self, y_shift: f64) -> Self {
+		self.y_shift = y_shift;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		rotate(self.src, self.dst, self.dsize, self.angle, self.x_shift, self.y_shift, self.interpolation, self.stream)
+	}
+}
+
 /// ## C++ default parameters
 /// * flags: INTER_LINEAR
 /// * border_mode: BORDER_CONSTANT
@@ -269,12 +384,46 @@ pub fn warp_affine_2(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputA
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_warpAffine_const__InputArrayR_const__OutputArrayR_Mat_Size_int_int_Scalar_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), m.as_raw_mut_Mat(), dsize.opencv_as_extern(), flags, border_mode, border_value.opencv_as_extern(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_warpAffine_const__InputArrayR_const__OutputArrayR_Mat_Size_int_int_Scalar_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), m.as_raw_mut_Mat(), dsize.opencv_as_extern(), flags, border_mode, border_value.opencv_as_extern(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `warp_affine_2()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct WarpAffine2Builder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	m: core::Mat,
+	dsize: core::Size,
+	flags: i32,
+	border_mode: i32,
+	border_value: core::Scalar,
+	stream: &'a mut core::Stream,
+}
+
+impl<'a> WarpAffine2Builder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, m: core::Mat, dsize: core::Size, flags: i32, border_mode: i32, stream: &'a mut core::Stream) -> Self {
+		Self { src, dst, m, dsize, flags, border_mode, border_value: core::Scalar::default(), stream }
+	}
+	
+	/// C++ default: `Scalar()`
+	#[inline]
+	pub fn with_border_value(mut self, border_value: core::Scalar) -> Self {
+		self.border_value = border_value;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		warp_affine_2(self.src, self.dst, self.m, self.dsize, self.flags, self.border_mode, self.border_value, self.stream)
+	}
+}
+
 /// ## C++ default parameters
 /// * flags: INTER_LINEAR
 /// * border_mode: BORDER_CONSTANT
@@ -285,12 +434,46 @@ pub fn warp_affine_1(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputA
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_warpAffine_const__InputArrayR_const__OutputArrayR_UMat_Size_int_int_Scalar_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), m.as_raw_mut_UMat(), dsize.opencv_as_extern(), flags, border_mode, border_value.opencv_as_extern(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_warpAffine_const__InputArrayR_const__OutputArrayR_UMat_Size_int_int_Scalar_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), m.as_raw_mut_UMat(), dsize.opencv_as_extern(), flags, border_mode, border_value.opencv_as_extern(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `warp_affine_1()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct WarpAffine1Builder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	m: core::UMat,
+	dsize: core::Size,
+	flags: i32,
+	border_mode: i32,
+	border_value: core::Scalar,
+	stream: &'a mut core::Stream,
+}
+
+impl<'a> WarpAffine1Builder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, m: core::UMat, dsize: core::Size, flags: i32, border_mode: i32, stream: &'a mut core::Stream) -> Self {
+		Self { src, dst, m, dsize, flags, border_mode, border_value: core::Scalar::default(), stream }
+	}
+	
+	/// C++ default: `Scalar()`
+	#[inline]
+	pub fn with_border_value(mut self, border_value: core::Scalar) -> Self {
+		self.border_value = border_value;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		warp_affine_1(self.src, self.dst, self.m, self.dsize, self.flags, self.border_mode, self.border_value, self.stream)
+	}
+}
+
 /// Applies an affine transformation to an image.
 /// 
 /// ## Parameters
@@ -306,7 +489,7 @@ pub fn warp_affine_1(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputA
 /// * borderValue: 
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// warpAffine
+/// [warp_affine](crate::cudawarping::warp_affine)
 /// 
 /// ## C++ default parameters
 /// * flags: INTER_LINEAR
@@ -319,12 +502,46 @@ pub fn warp_affine(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArr
 	output_array_arg!(dst);
 	input_array_arg!(m);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_warpAffine_const__InputArrayR_const__OutputArrayR_const__InputArrayR_Size_int_int_Scalar_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), m.as_raw__InputArray(), dsize.opencv_as_extern(), flags, border_mode, border_value.opencv_as_extern(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_warpAffine_const__InputArrayR_const__OutputArrayR_const__InputArrayR_Size_int_int_Scalar_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), m.as_raw__InputArray(), dsize.opencv_as_extern(), flags, border_mode, border_value.opencv_as_extern(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `warp_affine()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct WarpAffineBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	m: &'a dyn core::ToInputArray,
+	dsize: core::Size,
+	flags: i32,
+	border_mode: i32,
+	border_value: core::Scalar,
+	stream: &'a mut core::Stream,
+}
+
+impl<'a> WarpAffineBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, m: &'a dyn core::ToInputArray, dsize: core::Size, flags: i32, border_mode: i32, stream: &'a mut core::Stream) -> Self {
+		Self { src, dst, m, dsize, flags, border_mode, border_value: core::Scalar::default(), stream }
+	}
+	
+	/// C++ default: `Scalar()`
+	#[inline]
+	pub fn with_border_value(mut self, border_value: core::Scalar) -> Self {
+		self.border_value = border_value;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		warp_affine(self.src, self.dst, self.m, self.dsize, self.flags, self.border_mode, self.border_value, self.stream)
+	}
+}
+
 /// ## C++ default parameters
 /// * flags: INTER_LINEAR
 /// * border_mode: BORDER_CONSTANT
@@ -335,12 +552,46 @@ pub fn warp_perspective_2(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOu
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_warpPerspective_const__InputArrayR_const__OutputArrayR_Mat_Size_int_int_Scalar_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), m.as_raw_mut_Mat(), dsize.opencv_as_extern(), flags, border_mode, border_value.opencv_as_extern(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_warpPerspective_const__InputArrayR_const__OutputArrayR_Mat_Size_int_int_Scalar_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), m.as_raw_mut_Mat(), dsize.opencv_as_extern(), flags, border_mode, border_value.opencv_as_extern(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `warp_perspective_2()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct WarpPerspective2Builder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	m: core::Mat,
+	dsize: core::Size,
+	flags: i32,
+	border_mode: i32,
+	border_value: core::Scalar,
+	stream: &'a mut core::Stream,
+}
+
+impl<'a> WarpPerspective2Builder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, m: core::Mat, dsize: core::Size, flags: i32, border_mode: i32, stream: &'a mut core::Stream) -> Self {
+		Self { src, dst, m, dsize, flags, border_mode, border_value: core::Scalar::default(), stream }
+	}
+	
+	/// C++ default: `Scalar()`
+	#[inline]
+	pub fn with_border_value(mut self, border_value: core::Scalar) -> Self {
+		self.border_value = border_value;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		warp_perspective_2(self.src, self.dst, self.m, self.dsize, self.flags, self.border_mode, self.border_value, self.stream)
+	}
+}
+
 /// ## C++ default parameters
 /// * flags: INTER_LINEAR
 /// * border_mode: BORDER_CONSTANT
@@ -351,12 +602,46 @@ pub fn warp_perspective_1(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOu
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_warpPerspective_const__InputArrayR_const__OutputArrayR_UMat_Size_int_int_Scalar_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), m.as_raw_mut_UMat(), dsize.opencv_as_extern(), flags, border_mode, border_value.opencv_as_extern(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_warpPerspective_const__InputArrayR_const__OutputArrayR_UMat_Size_int_int_Scalar_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), m.as_raw_mut_UMat(), dsize.opencv_as_extern(), flags, border_mode, border_value.opencv_as_extern(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `warp_perspective_1()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct WarpPerspective1Builder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	m: core::UMat,
+	dsize: core::Size,
+	flags: i32,
+	border_mode: i32,
+	border_value: core::Scalar,
+	stream: &'a mut core::Stream,
+}
+
+impl<'a> WarpPerspective1Builder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, m: core::UMat, dsize: core::Size, flags: i32, border_mode: i32, stream: &'a mut core::Stream) -> Self {
+		Self { src, dst, m, dsize, flags, border_mode, border_value: core::Scalar::default(), stream }
+	}
+	
+	/// C++ default: `Scalar()`
+	#[inline]
+	pub fn with_border_value(mut self, border_value: core::Scalar) -> Self {
+		self.border_value = border_value;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		warp_perspective_1(self.src, self.dst, self.m, self.dsize, self.flags, self.border_mode, self.border_value, self.stream)
+	}
+}
+
 /// Applies a perspective transformation to an image.
 /// 
 /// ## Parameters
@@ -372,7 +657,7 @@ pub fn warp_perspective_1(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOu
 /// * borderValue: 
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// warpPerspective
+/// [warp_perspective](crate::cudawarping::warp_perspective)
 /// 
 /// ## C++ default parameters
 /// * flags: INTER_LINEAR
@@ -385,8 +670,42 @@ pub fn warp_perspective(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutp
 	output_array_arg!(dst);
 	input_array_arg!(m);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_warpPerspective_const__InputArrayR_const__OutputArrayR_const__InputArrayR_Size_int_int_Scalar_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), m.as_raw__InputArray(), dsize.opencv_as_extern(), flags, border_mode, border_value.opencv_as_extern(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_warpPerspective_const__InputArrayR_const__OutputArrayR_const__InputArrayR_Size_int_int_Scalar_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), m.as_raw__InputArray(), dsize.opencv_as_extern(), flags, border_mode, border_value.opencv_as_extern(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
+
+/// Builder for `warp_perspective()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct WarpPerspectiveBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	m: &'a dyn core::ToInputArray,
+	dsize: core::Size,
+	flags: i32,
+	border_mode: i32,
+	border_value: core::Scalar,
+	stream: &'a mut core::Stream,
+}
+
+impl<'a> WarpPerspectiveBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, m: &'a dyn core::ToInputArray, dsize: core::Size, flags: i32, border_mode: i32, stream: &'a mut core::Stream) -> Self {
+		Self { src, dst, m, dsize, flags, border_mode, border_value: core::Scalar::default(), stream }
+	}
+	
+	/// C++ default: `Scalar()`
+	#[inline]
+	pub fn with_border_value(mut self, border_value: core::Scalar) -> Self {
+		self.border_value = border_value;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		warp_perspective(self.src, self.dst, self.m, self.dsize, self.flags, self.border_mode, self.border_value, self.stream)
+	}
+}
@@ -30,7 +30,7 @@ pub fn debug_d_match(img1: &dyn core::ToInputArray, mut keypoints1: core::Vector
 	extern_container_arg!(description);
 	extern_container_arg!(view);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cvv_impl_debugDMatch_const__InputArrayR_vector_KeyPoint__const__InputArrayR_vector_KeyPoint__vector_DMatch__const_CallMetaDataR_const_charX_const_charX_bool(img1.as_raw__InputArray(), keypoints1.as_raw_mut_VectorOfKeyPoint(), img2.as_raw__InputArray(), keypoints2.as_raw_mut_VectorOfKeyPoint(), matches.as_raw_mut_VectorOfDMatch(), data.as_raw_CallMetaData(), description.opencv_as_extern(), view.opencv_as_extern(), use_train_descriptor, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cvv_impl_debugDMatch_const__InputArrayR_vector_KeyPoint__const__InputArrayR_vector_KeyPoint__vector_DMatch__const_CallMetaDataR_const_charX_const_charX_bool(img1.as_raw__InputArray(), keypoints1.as_raw_mut_VectorOfKeyPoint(), img2.as_raw__InputArray(), keypoints2.as_raw_mut_VectorOfKeyPoint(), matches.as_raw_mut_VectorOfDMatch(), data.as_raw_CallMetaData(), description.opencv_as_extern(), view.opencv_as_extern(), use_train_descriptor, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -43,7 +43,7 @@ pub fn debug_filter(original: &dyn core::ToInputArray, result: &dyn core::ToInpu
 	extern_container_arg!(description);
 	extern_container_arg!(view);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cvv_impl_debugFilter_const__InputArrayR_const__InputArrayR_const_CallMetaDataR_const_charX_const_charX(original.as_raw__InputArray(), result.as_raw__InputArray(), data.as_raw_CallMetaData(), description.opencv_as_extern(), view.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cvv_impl_debugFilter_const__InputArrayR_const__InputArrayR_const_CallMetaDataR_const_charX_const_charX(original.as_raw__InputArray(), result.as_raw__InputArray(), data.as_raw_CallMetaData(), description.opencv_as_extern(), view.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -52,7 +52,7 @@ pub fn debug_filter(original: &dyn core::ToInputArray, result: &dyn core::ToInpu
 #[inline]
 pub fn final_show() -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cvv_impl_finalShow(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cvv_impl_finalShow(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -64,7 +64,7 @@ pub fn show_image(img: &dyn core::ToInputArray, data: &crate::cvv::CallMetaData,
 	extern_container_arg!(description);
 	extern_container_arg!(view);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cvv_impl_showImage_const__InputArrayR_const_CallMetaDataR_const_charX_const_charX(img.as_raw__InputArray(), data.as_raw_CallMetaData(), description.opencv_as_extern(), view.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cvv_impl_showImage_const__InputArrayR_const_CallMetaDataR_const_charX_const_charX(img.as_raw__InputArray(), data.as_raw_CallMetaData(), description.opencv_as_extern(), view.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -76,31 +76,63 @@ pub trait CallMetaDataTraitConst {
 
 	#[inline]
 	fn file(&self) -> String {
-		let ret = unsafe { sys::cvv_impl_CallMetaData_getPropFile_const(self.as_raw_CallMetaData()) };
+		let ret = unsafe { ffi_call!(cvv_impl_CallMetaData_getPropFile_const(self.as_raw_CallMetaData())) };
 		let ret = unsafe { String::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [file](CallMetaDataTraitConst::file), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_file(&self) -> Result<String> {
+		crate::core::validity::check("CallMetaData", self.as_raw_CallMetaData())?;
+		Ok(CallMetaDataTraitConst::file(self))
+	}
+	
 	#[inline]
 	fn line(&self) -> size_t {
-		let ret = unsafe { sys::cvv_impl_CallMetaData_getPropLine_const(self.as_raw_CallMetaData()) };
+		let ret = unsafe { ffi_call!(cvv_impl_CallMetaData_getPropLine_const(self.as_raw_CallMetaData())) };
 		ret
 	}
 	
+	/// Non-panicking version of [line](CallMetaDataTraitConst::line), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_line(&self) -> Result<size_t> {
+		crate::core::validity::check("CallMetaData", self.as_raw_CallMetaData())?;
+		Ok(CallMetaDataTraitConst::line(self))
+	}
+	
 	#[inline]
 	fn function(&self) -> String {
-		let ret = unsafe { sys::cvv_impl_CallMetaData_getPropFunction_const(self.as_raw_CallMetaData()) };
+		let ret = unsafe { ffi_call!(cvv_impl_CallMetaData_getPropFunction_const(self.as_raw_CallMetaData())) };
 		let ret = unsafe { String::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [function](CallMetaDataTraitConst::function), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_function(&self) -> Result<String> {
+		crate::core::validity::check("CallMetaData", self.as_raw_CallMetaData())?;
+		Ok(CallMetaDataTraitConst::function(self))
+	}
+	
 	/// Whether *this holds actual data.
 	#[inline]
 	fn is_known(&self) -> bool {
-		let ret = unsafe { sys::cvv_impl_CallMetaData_getPropIsKnown_const(self.as_raw_CallMetaData()) };
+		let ret = unsafe { ffi_call!(cvv_impl_CallMetaData_getPropIsKnown_const(self.as_raw_CallMetaData())) };
 		ret
 	}
 	
+	/// Non-panicking version of [is_known](CallMetaDataTraitConst::is_known), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_is_known(&self) -> Result<bool> {
+		crate::core::validity::check("CallMetaData", self.as_raw_CallMetaData())?;
+		Ok(CallMetaDataTraitConst::is_known(self))
+	}
+	
 }
 
 pub trait CallMetaDataTrait: crate::cvv::CallMetaDataTraitConst {
@@ -109,7 +141,7 @@ pub trait CallMetaDataTrait: crate::cvv::CallMetaDataTraitConst {
 	#[inline]
 	fn to_bool(&mut self) -> Result<bool> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cvv_impl_CallMetaData_operator_bool(self.as_raw_mut_CallMetaData(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cvv_impl_CallMetaData_operator_bool(self.as_raw_mut_CallMetaData(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -127,7 +159,10 @@ opencv_type_boxed! { CallMetaData }
 impl Drop for CallMetaData {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CallMetaData_delete(instance: *mut c_void); }
-		unsafe { cv_CallMetaData_delete(self.as_raw_mut_CallMetaData()) };
+		let ptr = self.as_raw_mut_CallMetaData();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_CallMetaData_delete(ptr) };
 	}
 }
 
@@ -146,7 +181,7 @@ impl CallMetaData {
 	#[inline]
 	pub fn default() -> Result<crate::cvv::CallMetaData> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cvv_impl_CallMetaData_CallMetaData(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cvv_impl_CallMetaData_CallMetaData(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::cvv::CallMetaData::opencv_from_extern(ret) };
@@ -161,7 +196,7 @@ impl CallMetaData {
 		extern_container_arg!(file);
 		extern_container_arg!(function);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cvv_impl_CallMetaData_CallMetaData_const_charX_size_t_const_charX(file.opencv_as_extern(), line, function.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cvv_impl_CallMetaData_CallMetaData_const_charX_size_t_const_charX(file.opencv_as_extern(), line, function.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::cvv::CallMetaData::opencv_from_extern(ret) };
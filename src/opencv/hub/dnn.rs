@@ -39,7 +39,7 @@ pub const DNN_BACKEND_DEFAULT: i32 = 0;
 pub const DNN_BACKEND_HALIDE: i32 = 1;
 /// Intel's Inference Engine computational backend
 /// ## See also
-/// setInferenceEngineBackendType
+/// `set_inference_engine_backend_type`
 pub const DNN_BACKEND_INFERENCE_ENGINE: i32 = 2;
 pub const DNN_BACKEND_OPENCV: i32 = 3;
 pub const DNN_BACKEND_VKCOM: i32 = 4;
@@ -59,7 +59,7 @@ pub const SoftNMSMethod_SOFTNMS_GAUSSIAN: i32 = 2;
 pub const SoftNMSMethod_SOFTNMS_LINEAR: i32 = 1;
 /// Enum of computation backends supported by layers.
 /// ## See also
-/// Net::setPreferableBackend
+/// `Net::set_preferable_backend`
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum Backend {
@@ -73,7 +73,7 @@ pub enum Backend {
 	DNN_BACKEND_HALIDE = 1,
 	/// Intel's Inference Engine computational backend
 	/// ## See also
-	/// setInferenceEngineBackendType
+	/// `set_inference_engine_backend_type`
 	DNN_BACKEND_INFERENCE_ENGINE = 2,
 	DNN_BACKEND_OPENCV = 3,
 	DNN_BACKEND_VKCOM = 4,
@@ -85,7 +85,7 @@ opencv_type_enum! { crate::dnn::Backend }
 
 /// Enum of Soft NMS methods.
 /// ## See also
-/// softNMSBoxes
+/// `soft_nms_boxes`
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum SoftNMSMethod {
@@ -97,7 +97,7 @@ opencv_type_enum! { crate::dnn::SoftNMSMethod }
 
 /// Enum of target devices for computations.
 /// ## See also
-/// Net::setPreferableTarget
+/// `Net::set_preferable_target`
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum Target {
@@ -126,12 +126,52 @@ pub type Net_LayerId = crate::dnn::DictValue;
 #[inline]
 pub fn nms_boxes_f64(bboxes: &core::Vector<core::Rect2d>, scores: &core::Vector<f32>, score_threshold: f32, nms_threshold: f32, indices: &mut core::Vector<i32>, eta: f32, top_k: i32) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_NMSBoxes_const_vector_Rect2d_R_const_vector_float_R_const_float_const_float_vector_int_R_const_float_const_int(bboxes.as_raw_VectorOfRect2d(), scores.as_raw_VectorOff32(), score_threshold, nms_threshold, indices.as_raw_mut_VectorOfi32(), eta, top_k, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_NMSBoxes_const_vector_Rect2d_R_const_vector_float_R_const_float_const_float_vector_int_R_const_float_const_int(bboxes.as_raw_VectorOfRect2d(), scores.as_raw_VectorOff32(), score_threshold, nms_threshold, indices.as_raw_mut_VectorOfi32(), eta, top_k, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `nms_boxes_f64()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct NmsBoxesF64Builder<'a> {
+	bboxes: &'a core::Vector<core::Rect2d>,
+	scores: &'a core::Vector<f32>,
+	score_threshold: f32,
+	nms_threshold: f32,
+	indices: &'a mut core::Vector<i32>,
+	eta: f32,
+	top_k: i32,
+}
+
+impl<'a> NmsBoxesF64Builder<'a> {
+	#[inline]
+	pub fn new(bboxes: &'a core::Vector<core::Rect2d>, scores: &'a core::Vector<f32>, score_threshold: f32, nms_threshold: f32, indices: &'a mut core::Vector<i32>) -> Self {
+		Self { bboxes, scores, score_threshold, nms_threshold, indices, eta: 1., top_k: 0 }
+	}
+	
+	/// C++ default: `1.f`
+	#[inline]
+	pub fn with_eta(mut self, eta: f32) -> Self {
+		self.eta = eta;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_top_k(mut self, top_k: i32) -> Self {
+		self.top_k = top_k;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		nms_boxes_f64(self.bboxes, self.scores, self.score_threshold, self.nms_threshold, self.indices, self.eta, self.top_k)
+	}
+}
+
 /// Performs non maximum suppression given boxes and corresponding scores.
 /// 
 /// ## Parameters
@@ -149,24 +189,104 @@ pub fn nms_boxes_f64(bboxes: &core::Vector<core::Rect2d>, scores: &core::Vector<
 #[inline]
 pub fn nms_boxes(bboxes: &core::Vector<core::Rect>, scores: &core::Vector<f32>, score_threshold: f32, nms_threshold: f32, indices: &mut core::Vector<i32>, eta: f32, top_k: i32) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_NMSBoxes_const_vector_Rect_R_const_vector_float_R_const_float_const_float_vector_int_R_const_float_const_int(bboxes.as_raw_VectorOfRect(), scores.as_raw_VectorOff32(), score_threshold, nms_threshold, indices.as_raw_mut_VectorOfi32(), eta, top_k, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_NMSBoxes_const_vector_Rect_R_const_vector_float_R_const_float_const_float_vector_int_R_const_float_const_int(bboxes.as_raw_VectorOfRect(), scores.as_raw_VectorOff32(), score_threshold, nms_threshold, indices.as_raw_mut_VectorOfi32(), eta, top_k, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `nms_boxes()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct NmsBoxesBuilder<'a> {
+	bboxes: &'a core::Vector<core::Rect>,
+	scores: &'a core::Vector<f32>,
+	score_threshold: f32,
+	nms_threshold: f32,
+	indices: &'a mut core::Vector<i32>,
+	eta: f32,
+	top_k: i32,
+}
+
+impl<'a> NmsBoxesBuilder<'a> {
+	#[inline]
+	pub fn new(bboxes: &'a core::Vector<core::Rect>, scores: &'a core::Vector<f32>, score_threshold: f32, nms_threshold: f32, indices: &'a mut core::Vector<i32>) -> Self {
+		Self { bboxes, scores, score_threshold, nms_threshold, indices, eta: 1., top_k: 0 }
+	}
+	
+	/// C++ default: `1.f`
+	#[inline]
+	pub fn with_eta(mut self, eta: f32) -> Self {
+		self.eta = eta;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_top_k(mut self, top_k: i32) -> Self {
+		self.top_k = top_k;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		nms_boxes(self.bboxes, self.scores, self.score_threshold, self.nms_threshold, self.indices, self.eta, self.top_k)
+	}
+}
+
 /// ## C++ default parameters
 /// * eta: 1.f
 /// * top_k: 0
 #[inline]
 pub fn nms_boxes_rotated(bboxes: &core::Vector<core::RotatedRect>, scores: &core::Vector<f32>, score_threshold: f32, nms_threshold: f32, indices: &mut core::Vector<i32>, eta: f32, top_k: i32) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_NMSBoxes_const_vector_RotatedRect_R_const_vector_float_R_const_float_const_float_vector_int_R_const_float_const_int(bboxes.as_raw_VectorOfRotatedRect(), scores.as_raw_VectorOff32(), score_threshold, nms_threshold, indices.as_raw_mut_VectorOfi32(), eta, top_k, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_NMSBoxes_const_vector_RotatedRect_R_const_vector_float_R_const_float_const_float_vector_int_R_const_float_const_int(bboxes.as_raw_VectorOfRotatedRect(), scores.as_raw_VectorOff32(), score_threshold, nms_threshold, indices.as_raw_mut_VectorOfi32(), eta, top_k, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `nms_boxes_rotated()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct NmsBoxesRotatedBuilder<'a> {
+	bboxes: &'a core::Vector<core::RotatedRect>,
+	scores: &'a core::Vector<f32>,
+	score_threshold: f32,
+	nms_threshold: f32,
+	indices: &'a mut core::Vector<i32>,
+	eta: f32,
+	top_k: i32,
+}
+
+impl<'a> NmsBoxesRotatedBuilder<'a> {
+	#[inline]
+	pub fn new(bboxes: &'a core::Vector<core::RotatedRect>, scores: &'a core::Vector<f32>, score_threshold: f32, nms_threshold: f32, indices: &'a mut core::Vector<i32>) -> Self {
+		Self { bboxes, scores, score_threshold, nms_threshold, indices, eta: 1., top_k: 0 }
+	}
+	
+	/// C++ default: `1.f`
+	#[inline]
+	pub fn with_eta(mut self, eta: f32) -> Self {
+		self.eta = eta;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_top_k(mut self, top_k: i32) -> Self {
+		self.top_k = top_k;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		nms_boxes_rotated(self.bboxes, self.scores, self.score_threshold, self.nms_threshold, self.indices, self.eta, self.top_k)
+	}
+}
+
 /// Creates 4-dimensional blob from image.
 /// @details This is an overloaded member function, provided for convenience.
 ///          It differs from the above function only in what argument(s) it accepts.
@@ -183,12 +303,74 @@ pub fn blob_from_image_to(image: &dyn core::ToInputArray, blob: &mut dyn core::T
 	input_array_arg!(image);
 	output_array_arg!(blob);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_blobFromImage_const__InputArrayR_const__OutputArrayR_double_const_SizeR_const_ScalarR_bool_bool_int(image.as_raw__InputArray(), blob.as_raw__OutputArray(), scalefactor, &size, &mean, swap_rb, crop, ddepth, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_blobFromImage_const__InputArrayR_const__OutputArrayR_double_const_SizeR_const_ScalarR_bool_bool_int(image.as_raw__InputArray(), blob.as_raw__OutputArray(), scalefactor, &size, &mean, swap_rb, crop, ddepth, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `blob_from_image_to()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct BlobFromImageToBuilder<'a> {
+	image: &'a dyn core::ToInputArray,
+	blob: &'a mut dyn core::ToOutputArray,
+	scalefactor: f64,
+	size: core::Size,
+	mean: core::Scalar,
+	swap_rb: bool,
+	crop: bool,
+	ddepth: i32,
+}
+
+impl<'a> BlobFromImageToBuilder<'a> {
+	#[inline]
+	pub fn new(image: &'a dyn core::ToInputArray, blob: &'a mut dyn core::ToOutputArray, ddepth: i32) -> Self {
+		Self { image, blob, scalefactor: 1.0, size: core::Size::default(), mean: core::Scalar::default(), swap_rb: false, crop: false, ddepth }
+	}
+	
+	/// C++ default: `1.0`
+	#[inline]
+	pub fn with_scalefactor(mut self, scalefactor: f64) -> Self {
+		self.scalefactor = scalefactor;
+		self
+	}
+	
+	/// C++ default: `Size()`
+	#[inline]
+	pub fn with_size(mut self, size: core::Size) -> Self {
+		self.size = size;
+		self
+	}
+	
+	/// C++ default: `Scalar()`
+	#[inline]
+	pub fn with_mean(mut self, mean: core::Scalar) -> Self {
+		self.mean = mean;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_swap_rb(mut self, swap_rb: bool) -> Self {
+		self.swap_rb = swap_rb;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_crop(mut self, crop: bool) -> Self {
+		self.crop = crop;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		blob_from_image_to(self.image, self.blob, self.scalefactor, self.size, self.mean, self.swap_rb, self.crop, self.ddepth)
+	}
+}
+
 /// Creates 4-dimensional blob from image. Optionally resizes and crops @p image from center,
 /// subtract @p mean values, scales values by @p scalefactor, swap Blue and Red channels.
 /// ## Parameters
@@ -218,13 +400,74 @@ pub fn blob_from_image_to(image: &dyn core::ToInputArray, blob: &mut dyn core::T
 pub fn blob_from_image(image: &dyn core::ToInputArray, scalefactor: f64, size: core::Size, mean: core::Scalar, swap_rb: bool, crop: bool, ddepth: i32) -> Result<core::Mat> {
 	input_array_arg!(image);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_blobFromImage_const__InputArrayR_double_const_SizeR_const_ScalarR_bool_bool_int(image.as_raw__InputArray(), scalefactor, &size, &mean, swap_rb, crop, ddepth, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_blobFromImage_const__InputArrayR_double_const_SizeR_const_ScalarR_bool_bool_int(image.as_raw__InputArray(), scalefactor, &size, &mean, swap_rb, crop, ddepth, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `blob_from_image()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct BlobFromImageBuilder<'a> {
+	image: &'a dyn core::ToInputArray,
+	scalefactor: f64,
+	size: core::Size,
+	mean: core::Scalar,
+	swap_rb: bool,
+	crop: bool,
+	ddepth: i32,
+}
+
+impl<'a> BlobFromImageBuilder<'a> {
+	#[inline]
+	pub fn new(image: &'a dyn core::ToInputArray, ddepth: i32) -> Self {
+		Self { image, scalefactor: 1.0, size: core::Size::default(), mean: core::Scalar::default(), swap_rb: false, crop: false, ddepth }
+	}
+	
+	/// C++ default: `1.0`
+	#[inline]
+	pub fn with_scalefactor(mut self, scalefactor: f64) -> Self {
+		self.scalefactor = scalefactor;
+		self
+	}
+	
+	/// C++ default: `Size()`
+	#[inline]
+	pub fn with_size(mut self, size: core::Size) -> Self {
+		self.size = size;
+		self
+	}
+	
+	/// C++ default: `Scalar()`
+	#[inline]
+	pub fn with_mean(mut self, mean: core::Scalar) -> Self {
+		self.mean = mean;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_swap_rb(mut self, swap_rb: bool) -> Self {
+		self.swap_rb = swap_rb;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_crop(mut self, crop: bool) -> Self {
+		self.crop = crop;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Mat> {
+		blob_from_image(self.image, self.scalefactor, self.size, self.mean, self.swap_rb, self.crop, self.ddepth)
+	}
+}
+
 /// Creates 4-dimensional blob from series of images.
 /// @details This is an overloaded member function, provided for convenience.
 ///          It differs from the above function only in what argument(s) it accepts.
@@ -241,12 +484,74 @@ pub fn blob_from_images_to(images: &dyn core::ToInputArray, blob: &mut dyn core:
 	input_array_arg!(images);
 	output_array_arg!(blob);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_blobFromImages_const__InputArrayR_const__OutputArrayR_double_Size_const_ScalarR_bool_bool_int(images.as_raw__InputArray(), blob.as_raw__OutputArray(), scalefactor, size.opencv_as_extern(), &mean, swap_rb, crop, ddepth, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_blobFromImages_const__InputArrayR_const__OutputArrayR_double_Size_const_ScalarR_bool_bool_int(images.as_raw__InputArray(), blob.as_raw__OutputArray(), scalefactor, size.opencv_as_extern(), &mean, swap_rb, crop, ddepth, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `blob_from_images_to()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct BlobFromImagesToBuilder<'a> {
+	images: &'a dyn core::ToInputArray,
+	blob: &'a mut dyn core::ToOutputArray,
+	scalefactor: f64,
+	size: core::Size,
+	mean: core::Scalar,
+	swap_rb: bool,
+	crop: bool,
+	ddepth: i32,
+}
+
+impl<'a> BlobFromImagesToBuilder<'a> {
+	#[inline]
+	pub fn new(images: &'a dyn core::ToInputArray, blob: &'a mut dyn core::ToOutputArray, ddepth: i32) -> Self {
+		Self { images, blob, scalefactor: 1.0, size: core::Size::default(), mean: core::Scalar::default(), swap_rb: false, crop: false, ddepth }
+	}
+	
+	/// C++ default: `1.0`
+	#[inline]
+	pub fn with_scalefactor(mut self, scalefactor: f64) -> Self {
+		self.scalefactor = scalefactor;
+		self
+	}
+	
+	/// C++ default: `Size()`
+	#[inline]
+	pub fn with_size(mut self, size: core::Size) -> Self {
+		self.size = size;
+		self
+	}
+	
+	/// C++ default: `Scalar()`
+	#[inline]
+	pub fn with_mean(mut self, mean: core::Scalar) -> Self {
+		self.mean = mean;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_swap_rb(mut self, swap_rb: bool) -> Self {
+		self.swap_rb = swap_rb;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_crop(mut self, crop: bool) -> Self {
+		self.crop = crop;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		blob_from_images_to(self.images, self.blob, self.scalefactor, self.size, self.mean, self.swap_rb, self.crop, self.ddepth)
+	}
+}
+
 /// Creates 4-dimensional blob from series of images. Optionally resizes and
 /// crops @p images from center, subtract @p mean values, scales values by @p scalefactor,
 /// swap Blue and Red channels.
@@ -277,17 +582,78 @@ pub fn blob_from_images_to(images: &dyn core::ToInputArray, blob: &mut dyn core:
 pub fn blob_from_images(images: &dyn core::ToInputArray, scalefactor: f64, size: core::Size, mean: core::Scalar, swap_rb: bool, crop: bool, ddepth: i32) -> Result<core::Mat> {
 	input_array_arg!(images);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_blobFromImages_const__InputArrayR_double_Size_const_ScalarR_bool_bool_int(images.as_raw__InputArray(), scalefactor, size.opencv_as_extern(), &mean, swap_rb, crop, ddepth, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_blobFromImages_const__InputArrayR_double_Size_const_ScalarR_bool_bool_int(images.as_raw__InputArray(), scalefactor, size.opencv_as_extern(), &mean, swap_rb, crop, ddepth, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `blob_from_images()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct BlobFromImagesBuilder<'a> {
+	images: &'a dyn core::ToInputArray,
+	scalefactor: f64,
+	size: core::Size,
+	mean: core::Scalar,
+	swap_rb: bool,
+	crop: bool,
+	ddepth: i32,
+}
+
+impl<'a> BlobFromImagesBuilder<'a> {
+	#[inline]
+	pub fn new(images: &'a dyn core::ToInputArray, ddepth: i32) -> Self {
+		Self { images, scalefactor: 1.0, size: core::Size::default(), mean: core::Scalar::default(), swap_rb: false, crop: false, ddepth }
+	}
+	
+	/// C++ default: `1.0`
+	#[inline]
+	pub fn with_scalefactor(mut self, scalefactor: f64) -> Self {
+		self.scalefactor = scalefactor;
+		self
+	}
+	
+	/// C++ default: `Size()`
+	#[inline]
+	pub fn with_size(mut self, size: core::Size) -> Self {
+		self.size = size;
+		self
+	}
+	
+	/// C++ default: `Scalar()`
+	#[inline]
+	pub fn with_mean(mut self, mean: core::Scalar) -> Self {
+		self.mean = mean;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_swap_rb(mut self, swap_rb: bool) -> Self {
+		self.swap_rb = swap_rb;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_crop(mut self, crop: bool) -> Self {
+		self.crop = crop;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Mat> {
+		blob_from_images(self.images, self.scalefactor, self.size, self.mean, self.swap_rb, self.crop, self.ddepth)
+	}
+}
+
 #[inline]
 pub fn concat(a: &crate::dnn::MatShape, b: &crate::dnn::MatShape) -> Result<core::Vector<i32>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_concat_const_MatShapeR_const_MatShapeR(a.as_raw_VectorOfi32(), b.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_concat_const_MatShapeR_const_MatShapeR(a.as_raw_VectorOfi32(), b.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Vector::<i32>::opencv_from_extern(ret) };
@@ -307,7 +673,7 @@ pub fn concat(a: &crate::dnn::MatShape, b: &crate::dnn::MatShape) -> Result<core
 #[inline]
 pub fn enable_model_diagnostics(is_diagnostics_mode: bool) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_enableModelDiagnostics_bool(is_diagnostics_mode, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_enableModelDiagnostics_bool(is_diagnostics_mode, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -316,7 +682,7 @@ pub fn enable_model_diagnostics(is_diagnostics_mode: bool) -> Result<()> {
 #[inline]
 pub fn get_available_targets(be: crate::dnn::Backend) -> Result<core::Vector<crate::dnn::Target>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_getAvailableTargets_Backend(be, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_getAvailableTargets_Backend(be, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Vector::<crate::dnn::Target>::opencv_from_extern(ret) };
@@ -331,7 +697,7 @@ pub fn get_available_targets(be: crate::dnn::Backend) -> Result<core::Vector<cra
 #[inline]
 pub fn get_inference_engine_backend_type() -> Result<String> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_getInferenceEngineBackendType(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_getInferenceEngineBackendType(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { String::opencv_from_extern(ret) };
@@ -344,7 +710,7 @@ pub fn get_inference_engine_backend_type() -> Result<String> {
 #[inline]
 pub fn get_inference_engine_cpu_type() -> Result<String> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_getInferenceEngineCPUType(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_getInferenceEngineCPUType(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { String::opencv_from_extern(ret) };
@@ -357,7 +723,7 @@ pub fn get_inference_engine_cpu_type() -> Result<String> {
 #[inline]
 pub fn get_inference_engine_vpu_type() -> Result<String> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_getInferenceEngineVPUType(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_getInferenceEngineVPUType(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { String::opencv_from_extern(ret) };
@@ -367,7 +733,7 @@ pub fn get_inference_engine_vpu_type() -> Result<String> {
 #[inline]
 pub fn get_plane(m: &core::Mat, n: i32, cn: i32) -> Result<core::Mat> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_getPlane_const_MatR_int_int(m.as_raw_Mat(), n, cn, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_getPlane_const_MatR_int_int(m.as_raw_Mat(), n, cn, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -379,14 +745,14 @@ pub fn get_plane(m: &core::Mat, n: i32, cn: i32) -> Result<core::Mat> {
 /// ## Parameters
 /// * blob_: 4 dimensional array (images, channels, height, width) in floating point precision (CV_32F) from
 /// which you would like to extract the images.
-/// * images_:[out] array of 2D Mat containing the images extracted from the blob in floating point precision
+/// * images_:\[out\] array of 2D Mat containing the images extracted from the blob in floating point precision
 /// (CV_32F). They are non normalized neither mean added. The number of returned images equals the first dimension
 /// of the blob (batch size). Every image has a number of channels equals to the second dimension of the blob (depth).
 #[inline]
 pub fn images_from_blob(blob_: &core::Mat, images_: &mut dyn core::ToOutputArray) -> Result<()> {
 	output_array_arg!(images_);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_imagesFromBlob_const_MatR_const__OutputArrayR(blob_.as_raw_Mat(), images_.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_imagesFromBlob_const_MatR_const__OutputArrayR(blob_.as_raw_Mat(), images_.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -398,7 +764,7 @@ pub fn images_from_blob(blob_: &core::Mat, images_: &mut dyn core::ToOutputArray
 pub fn print(shape: &crate::dnn::MatShape, name: &str) -> Result<()> {
 	extern_container_arg!(name);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_print_const_MatShapeR_const_StringR(shape.as_raw_VectorOfi32(), name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_print_const_MatShapeR_const_StringR(shape.as_raw_VectorOfi32(), name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -418,7 +784,7 @@ pub fn read_net_from_caffe(prototxt: &str, caffe_model: &str) -> Result<crate::d
 	extern_container_arg!(prototxt);
 	extern_container_arg!(caffe_model);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_readNetFromCaffe_const_StringR_const_StringR(prototxt.opencv_as_extern(), caffe_model.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_readNetFromCaffe_const_StringR_const_StringR(prototxt.opencv_as_extern(), caffe_model.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { crate::dnn::Net::opencv_from_extern(ret) };
@@ -444,7 +810,7 @@ pub fn read_net_from_caffe_str(buffer_proto: &str, len_proto: size_t, buffer_mod
 	extern_container_arg!(buffer_proto);
 	extern_container_arg!(buffer_model);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_readNetFromCaffe_const_charX_size_t_const_charX_size_t(buffer_proto.opencv_as_extern(), len_proto, buffer_model.opencv_as_extern(), len_model, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_readNetFromCaffe_const_charX_size_t_const_charX_size_t(buffer_proto.opencv_as_extern(), len_proto, buffer_model.opencv_as_extern(), len_model, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { crate::dnn::Net::opencv_from_extern(ret) };
@@ -463,7 +829,7 @@ pub fn read_net_from_caffe_str(buffer_proto: &str, len_proto: size_t, buffer_mod
 #[inline]
 pub fn read_net_from_caffe_buffer(buffer_proto: &core::Vector<u8>, buffer_model: &core::Vector<u8>) -> Result<crate::dnn::Net> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_readNetFromCaffe_const_vector_unsigned_char_R_const_vector_unsigned_char_R(buffer_proto.as_raw_VectorOfu8(), buffer_model.as_raw_VectorOfu8(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_readNetFromCaffe_const_vector_unsigned_char_R_const_vector_unsigned_char_R(buffer_proto.as_raw_VectorOfu8(), buffer_model.as_raw_VectorOfu8(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { crate::dnn::Net::opencv_from_extern(ret) };
@@ -486,7 +852,7 @@ pub fn read_net_from_darknet(cfg_file: &str, darknet_model: &str) -> Result<crat
 	extern_container_arg!(cfg_file);
 	extern_container_arg!(darknet_model);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_readNetFromDarknet_const_StringR_const_StringR(cfg_file.opencv_as_extern(), darknet_model.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_readNetFromDarknet_const_StringR_const_StringR(cfg_file.opencv_as_extern(), darknet_model.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { crate::dnn::Net::opencv_from_extern(ret) };
@@ -510,7 +876,7 @@ pub fn read_net_from_darknet_str(buffer_cfg: &str, len_cfg: size_t, buffer_model
 	extern_container_arg!(buffer_cfg);
 	extern_container_arg!(buffer_model);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_readNetFromDarknet_const_charX_size_t_const_charX_size_t(buffer_cfg.opencv_as_extern(), len_cfg, buffer_model.opencv_as_extern(), len_model, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_readNetFromDarknet_const_charX_size_t_const_charX_size_t(buffer_cfg.opencv_as_extern(), len_cfg, buffer_model.opencv_as_extern(), len_model, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { crate::dnn::Net::opencv_from_extern(ret) };
@@ -529,7 +895,7 @@ pub fn read_net_from_darknet_str(buffer_cfg: &str, len_cfg: size_t, buffer_model
 #[inline]
 pub fn read_net_from_darknet_buffer(buffer_cfg: &core::Vector<u8>, buffer_model: &core::Vector<u8>) -> Result<crate::dnn::Net> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_readNetFromDarknet_const_vector_unsigned_char_R_const_vector_unsigned_char_R(buffer_cfg.as_raw_VectorOfu8(), buffer_model.as_raw_VectorOfu8(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_readNetFromDarknet_const_vector_unsigned_char_R_const_vector_unsigned_char_R(buffer_cfg.as_raw_VectorOfu8(), buffer_model.as_raw_VectorOfu8(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { crate::dnn::Net::opencv_from_extern(ret) };
@@ -549,7 +915,7 @@ pub fn read_net_from_model_optimizer(xml: &str, bin: &str) -> Result<crate::dnn:
 	extern_container_arg!(xml);
 	extern_container_arg!(bin);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_readNetFromModelOptimizer_const_StringR_const_StringR(xml.opencv_as_extern(), bin.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_readNetFromModelOptimizer_const_StringR_const_StringR(xml.opencv_as_extern(), bin.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { crate::dnn::Net::opencv_from_extern(ret) };
@@ -569,7 +935,7 @@ pub fn read_net_from_model_optimizer(xml: &str, bin: &str) -> Result<crate::dnn:
 #[inline]
 pub fn read_net_from_model_optimizer_2(buffer_model_config_ptr: &u8, buffer_model_config_size: size_t, buffer_weights_ptr: &u8, buffer_weights_size: size_t) -> Result<crate::dnn::Net> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_readNetFromModelOptimizer_const_unsigned_charX_size_t_const_unsigned_charX_size_t(buffer_model_config_ptr, buffer_model_config_size, buffer_weights_ptr, buffer_weights_size, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_readNetFromModelOptimizer_const_unsigned_charX_size_t_const_unsigned_charX_size_t(buffer_model_config_ptr, buffer_model_config_size, buffer_weights_ptr, buffer_weights_size, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { crate::dnn::Net::opencv_from_extern(ret) };
@@ -587,7 +953,7 @@ pub fn read_net_from_model_optimizer_2(buffer_model_config_ptr: &u8, buffer_mode
 #[inline]
 pub fn read_net_from_model_optimizer_1(buffer_model_config: &core::Vector<u8>, buffer_weights: &core::Vector<u8>) -> Result<crate::dnn::Net> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_readNetFromModelOptimizer_const_vector_unsigned_char_R_const_vector_unsigned_char_R(buffer_model_config.as_raw_VectorOfu8(), buffer_weights.as_raw_VectorOfu8(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_readNetFromModelOptimizer_const_vector_unsigned_char_R_const_vector_unsigned_char_R(buffer_model_config.as_raw_VectorOfu8(), buffer_weights.as_raw_VectorOfu8(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { crate::dnn::Net::opencv_from_extern(ret) };
@@ -603,7 +969,7 @@ pub fn read_net_from_model_optimizer_1(buffer_model_config: &core::Vector<u8>, b
 pub fn read_net_from_onnx(onnx_file: &str) -> Result<crate::dnn::Net> {
 	extern_container_arg!(onnx_file);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_readNetFromONNX_const_StringR(onnx_file.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_readNetFromONNX_const_StringR(onnx_file.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { crate::dnn::Net::opencv_from_extern(ret) };
@@ -622,7 +988,7 @@ pub fn read_net_from_onnx(onnx_file: &str) -> Result<crate::dnn::Net> {
 pub fn read_net_from_onnx_str(buffer: &str, size_buffer: size_t) -> Result<crate::dnn::Net> {
 	extern_container_arg!(buffer);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_readNetFromONNX_const_charX_size_t(buffer.opencv_as_extern(), size_buffer, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_readNetFromONNX_const_charX_size_t(buffer.opencv_as_extern(), size_buffer, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { crate::dnn::Net::opencv_from_extern(ret) };
@@ -639,7 +1005,7 @@ pub fn read_net_from_onnx_str(buffer: &str, size_buffer: size_t) -> Result<crate
 #[inline]
 pub fn read_net_from_onnx_buffer(buffer: &core::Vector<u8>) -> Result<crate::dnn::Net> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_readNetFromONNX_const_vector_unsigned_char_R(buffer.as_raw_VectorOfu8(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_readNetFromONNX_const_vector_unsigned_char_R(buffer.as_raw_VectorOfu8(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { crate::dnn::Net::opencv_from_extern(ret) };
@@ -662,7 +1028,7 @@ pub fn read_net_from_tensorflow(model: &str, config: &str) -> Result<crate::dnn:
 	extern_container_arg!(model);
 	extern_container_arg!(config);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_readNetFromTensorflow_const_StringR_const_StringR(model.opencv_as_extern(), config.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_readNetFromTensorflow_const_StringR_const_StringR(model.opencv_as_extern(), config.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { crate::dnn::Net::opencv_from_extern(ret) };
@@ -686,7 +1052,7 @@ pub fn read_net_from_tensorflow_str(buffer_model: &str, len_model: size_t, buffe
 	extern_container_arg!(buffer_model);
 	extern_container_arg!(buffer_config);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_readNetFromTensorflow_const_charX_size_t_const_charX_size_t(buffer_model.opencv_as_extern(), len_model, buffer_config.opencv_as_extern(), len_config, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_readNetFromTensorflow_const_charX_size_t_const_charX_size_t(buffer_model.opencv_as_extern(), len_model, buffer_config.opencv_as_extern(), len_config, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { crate::dnn::Net::opencv_from_extern(ret) };
@@ -705,7 +1071,7 @@ pub fn read_net_from_tensorflow_str(buffer_model: &str, len_model: size_t, buffe
 #[inline]
 pub fn read_net_from_tensorflow_buffer(buffer_model: &core::Vector<u8>, buffer_config: &core::Vector<u8>) -> Result<crate::dnn::Net> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_readNetFromTensorflow_const_vector_unsigned_char_R_const_vector_unsigned_char_R(buffer_model.as_raw_VectorOfu8(), buffer_config.as_raw_VectorOfu8(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_readNetFromTensorflow_const_vector_unsigned_char_R_const_vector_unsigned_char_R(buffer_model.as_raw_VectorOfu8(), buffer_config.as_raw_VectorOfu8(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { crate::dnn::Net::opencv_from_extern(ret) };
@@ -747,7 +1113,7 @@ pub fn read_net_from_tensorflow_buffer(buffer_model: &core::Vector<u8>, buffer_c
 pub fn read_net_from_torch(model: &str, is_binary: bool, evaluate: bool) -> Result<crate::dnn::Net> {
 	extern_container_arg!(model);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_readNetFromTorch_const_StringR_bool_bool(model.opencv_as_extern(), is_binary, evaluate, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_readNetFromTorch_const_StringR_bool_bool(model.opencv_as_extern(), is_binary, evaluate, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { crate::dnn::Net::opencv_from_extern(ret) };
@@ -775,8 +1141,8 @@ pub fn read_net_from_torch(model: &str, is_binary: bool, evaluate: bool) -> Resu
 /// Net object.
 /// 
 /// This function automatically detects an origin framework of trained model
-/// and calls an appropriate function such @ref readNetFromCaffe, @ref readNetFromTensorflow,
-/// @ref readNetFromTorch or @ref readNetFromDarknet. An order of @p model and @p config
+/// and calls an appropriate function such [read_net_from_caffe](crate::dnn::read_net_from_caffe), [read_net_from_tensorflow](crate::dnn::read_net_from_tensorflow),
+/// [read_net_from_torch](crate::dnn::read_net_from_torch) or [read_net_from_darknet](crate::dnn::read_net_from_darknet). An order of @p model and @p config
 /// arguments does not matter.
 /// 
 /// ## C++ default parameters
@@ -788,7 +1154,7 @@ pub fn read_net(model: &str, config: &str, framework: &str) -> Result<crate::dnn
 	extern_container_arg!(config);
 	extern_container_arg!(framework);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_readNet_const_StringR_const_StringR_const_StringR(model.opencv_as_extern(), config.opencv_as_extern(), framework.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_readNet_const_StringR_const_StringR_const_StringR(model.opencv_as_extern(), config.opencv_as_extern(), framework.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { crate::dnn::Net::opencv_from_extern(ret) };
@@ -811,7 +1177,7 @@ pub fn read_net(model: &str, config: &str, framework: &str) -> Result<crate::dnn
 pub fn read_net_1(framework: &str, buffer_model: &core::Vector<u8>, buffer_config: &core::Vector<u8>) -> Result<crate::dnn::Net> {
 	extern_container_arg!(framework);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_readNet_const_StringR_const_vector_unsigned_char_R_const_vector_unsigned_char_R(framework.opencv_as_extern(), buffer_model.as_raw_VectorOfu8(), buffer_config.as_raw_VectorOfu8(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_readNet_const_StringR_const_vector_unsigned_char_R_const_vector_unsigned_char_R(framework.opencv_as_extern(), buffer_model.as_raw_VectorOfu8(), buffer_config.as_raw_VectorOfu8(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { crate::dnn::Net::opencv_from_extern(ret) };
@@ -827,7 +1193,7 @@ pub fn read_net_1(framework: &str, buffer_model: &core::Vector<u8>, buffer_confi
 pub fn read_tensor_from_onnx(path: &str) -> Result<core::Mat> {
 	extern_container_arg!(path);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_readTensorFromONNX_const_StringR(path.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_readTensorFromONNX_const_StringR(path.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -843,7 +1209,7 @@ pub fn read_tensor_from_onnx(path: &str) -> Result<core::Mat> {
 pub fn read_torch_blob(filename: &str, is_binary: bool) -> Result<core::Mat> {
 	extern_container_arg!(filename);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_readTorchBlob_const_StringR_bool(filename.opencv_as_extern(), is_binary, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_readTorchBlob_const_StringR_bool(filename.opencv_as_extern(), is_binary, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -854,7 +1220,7 @@ pub fn read_torch_blob(filename: &str, is_binary: bool) -> Result<core::Mat> {
 #[inline]
 pub fn release_hddl_plugin() -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_releaseHDDLPlugin(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_releaseHDDLPlugin(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -867,7 +1233,7 @@ pub fn release_hddl_plugin() -> Result<()> {
 #[inline]
 pub fn reset_myriad_device() -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_resetMyriadDevice(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_resetMyriadDevice(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -883,7 +1249,7 @@ pub fn reset_myriad_device() -> Result<()> {
 pub fn set_inference_engine_backend_type(new_backend_type: &str) -> Result<String> {
 	extern_container_arg!(new_backend_type);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_setInferenceEngineBackendType_const_StringR(new_backend_type.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_setInferenceEngineBackendType_const_StringR(new_backend_type.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { String::opencv_from_extern(ret) };
@@ -893,7 +1259,7 @@ pub fn set_inference_engine_backend_type(new_backend_type: &str) -> Result<Strin
 #[inline]
 pub fn shape_1(mat: &core::Mat) -> Result<core::Vector<i32>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_shape_const_MatR(mat.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_shape_const_MatR(mat.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Vector::<i32>::opencv_from_extern(ret) };
@@ -903,7 +1269,7 @@ pub fn shape_1(mat: &core::Mat) -> Result<core::Vector<i32>> {
 #[inline]
 pub fn shape_2(sz: &core::MatSize) -> Result<core::Vector<i32>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_shape_const_MatSizeR(sz.as_raw_MatSize(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_shape_const_MatSizeR(sz.as_raw_MatSize(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Vector::<i32>::opencv_from_extern(ret) };
@@ -913,7 +1279,7 @@ pub fn shape_2(sz: &core::MatSize) -> Result<core::Vector<i32>> {
 #[inline]
 pub fn shape_3(mat: &core::UMat) -> Result<core::Vector<i32>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_shape_const_UMatR(mat.as_raw_UMat(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_shape_const_UMatR(mat.as_raw_UMat(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Vector::<i32>::opencv_from_extern(ret) };
@@ -923,7 +1289,7 @@ pub fn shape_3(mat: &core::UMat) -> Result<core::Vector<i32>> {
 #[inline]
 pub fn shape(dims: &i32, n: i32) -> Result<core::Vector<i32>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_shape_const_intX_const_int(dims, n, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_shape_const_intX_const_int(dims, n, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Vector::<i32>::opencv_from_extern(ret) };
@@ -937,13 +1303,57 @@ pub fn shape(dims: &i32, n: i32) -> Result<core::Vector<i32>> {
 #[inline]
 pub fn shape_4(a0: i32, a1: i32, a2: i32, a3: i32) -> Result<core::Vector<i32>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_shape_int_int_int_int(a0, a1, a2, a3, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_shape_int_int_int_int(a0, a1, a2, a3, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Vector::<i32>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `shape_4()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct Shape4Builder {
+	a0: i32,
+	a1: i32,
+	a2: i32,
+	a3: i32,
+}
+
+impl Shape4Builder {
+	#[inline]
+	pub fn new(a0: i32) -> Self {
+		Self { a0, a1: -1, a2: -1, a3: -1 }
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_a1(mut self, a1: i32) -> Self {
+		self.a1 = a1;
+		self
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_a2(mut self, a2: i32) -> Self {
+		self.a2 = a2;
+		self
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_a3(mut self, a3: i32) -> Self {
+		self.a3 = a3;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Vector<i32>> {
+		shape_4(self.a0, self.a1, self.a2, self.a3)
+	}
+}
+
 /// Convert all weights of Caffe network to half precision floating point.
 /// ## Parameters
 /// * src: Path to origin model from Caffe framework contains single
@@ -966,7 +1376,7 @@ pub fn shrink_caffe_model(src: &str, dst: &str, layers_types: &core::Vector<Stri
 	extern_container_arg!(src);
 	extern_container_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_shrinkCaffeModel_const_StringR_const_StringR_const_vector_String_R(src.opencv_as_extern(), dst.opencv_as_extern(), layers_types.as_raw_VectorOfString(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_shrinkCaffeModel_const_StringR_const_StringR_const_vector_String_R(src.opencv_as_extern(), dst.opencv_as_extern(), layers_types.as_raw_VectorOfString(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -975,7 +1385,7 @@ pub fn shrink_caffe_model(src: &str, dst: &str, layers_types: &core::Vector<Stri
 #[inline]
 pub fn slice(m: &core::Mat, r0: &crate::dnn::_Range) -> Result<core::Mat> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_slice_const_MatR_const__RangeR(m.as_raw_Mat(), r0.as_raw__Range(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_slice_const_MatR_const__RangeR(m.as_raw_Mat(), r0.as_raw__Range(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -985,7 +1395,7 @@ pub fn slice(m: &core::Mat, r0: &crate::dnn::_Range) -> Result<core::Mat> {
 #[inline]
 pub fn slice_1(m: &core::Mat, r0: &crate::dnn::_Range, r1: &crate::dnn::_Range) -> Result<core::Mat> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_slice_const_MatR_const__RangeR_const__RangeR(m.as_raw_Mat(), r0.as_raw__Range(), r1.as_raw__Range(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_slice_const_MatR_const__RangeR_const__RangeR(m.as_raw_Mat(), r0.as_raw__Range(), r1.as_raw__Range(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -995,7 +1405,7 @@ pub fn slice_1(m: &core::Mat, r0: &crate::dnn::_Range, r1: &crate::dnn::_Range)
 #[inline]
 pub fn slice_2(m: &core::Mat, r0: &crate::dnn::_Range, r1: &crate::dnn::_Range, r2: &crate::dnn::_Range) -> Result<core::Mat> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_slice_const_MatR_const__RangeR_const__RangeR_const__RangeR(m.as_raw_Mat(), r0.as_raw__Range(), r1.as_raw__Range(), r2.as_raw__Range(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_slice_const_MatR_const__RangeR_const__RangeR_const__RangeR(m.as_raw_Mat(), r0.as_raw__Range(), r1.as_raw__Range(), r2.as_raw__Range(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -1005,7 +1415,7 @@ pub fn slice_2(m: &core::Mat, r0: &crate::dnn::_Range, r1: &crate::dnn::_Range,
 #[inline]
 pub fn slice_3(m: &core::Mat, r0: &crate::dnn::_Range, r1: &crate::dnn::_Range, r2: &crate::dnn::_Range, r3: &crate::dnn::_Range) -> Result<core::Mat> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_slice_const_MatR_const__RangeR_const__RangeR_const__RangeR_const__RangeR(m.as_raw_Mat(), r0.as_raw__Range(), r1.as_raw__Range(), r2.as_raw__Range(), r3.as_raw__Range(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_slice_const_MatR_const__RangeR_const__RangeR_const__RangeR_const__RangeR(m.as_raw_Mat(), r0.as_raw__Range(), r1.as_raw__Range(), r2.as_raw__Range(), r3.as_raw__Range(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -1025,7 +1435,7 @@ pub fn slice_3(m: &core::Mat, r0: &crate::dnn::_Range, r1: &crate::dnn::_Range,
 /// * sigma: parameter of Gaussian weighting.
 /// * method: Gaussian or linear.
 /// ## See also
-/// SoftNMSMethod
+/// `SoftNMSMethod`
 /// 
 /// ## C++ default parameters
 /// * top_k: 0
@@ -1034,19 +1444,61 @@ pub fn slice_3(m: &core::Mat, r0: &crate::dnn::_Range, r1: &crate::dnn::_Range,
 #[inline]
 pub fn soft_nms_boxes(bboxes: &core::Vector<core::Rect>, scores: &core::Vector<f32>, updated_scores: &mut core::Vector<f32>, score_threshold: f32, nms_threshold: f32, indices: &mut core::Vector<i32>, top_k: size_t, sigma: f32, method: crate::dnn::SoftNMSMethod) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_softNMSBoxes_const_vector_Rect_R_const_vector_float_R_vector_float_R_const_float_const_float_vector_int_R_size_t_const_float_SoftNMSMethod(bboxes.as_raw_VectorOfRect(), scores.as_raw_VectorOff32(), updated_scores.as_raw_mut_VectorOff32(), score_threshold, nms_threshold, indices.as_raw_mut_VectorOfi32(), top_k, sigma, method, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_softNMSBoxes_const_vector_Rect_R_const_vector_float_R_vector_float_R_const_float_const_float_vector_int_R_size_t_const_float_SoftNMSMethod(bboxes.as_raw_VectorOfRect(), scores.as_raw_VectorOff32(), updated_scores.as_raw_mut_VectorOff32(), score_threshold, nms_threshold, indices.as_raw_mut_VectorOfi32(), top_k, sigma, method, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `soft_nms_boxes()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct SoftNmsBoxesBuilder<'a> {
+	bboxes: &'a core::Vector<core::Rect>,
+	scores: &'a core::Vector<f32>,
+	updated_scores: &'a mut core::Vector<f32>,
+	score_threshold: f32,
+	nms_threshold: f32,
+	indices: &'a mut core::Vector<i32>,
+	top_k: size_t,
+	sigma: f32,
+	method: crate::dnn::SoftNMSMethod,
+}
+
+impl<'a> SoftNmsBoxesBuilder<'a> {
+	#[inline]
+	pub fn new(bboxes: &'a core::Vector<core::Rect>, scores: &'a core::Vector<f32>, updated_scores: &'a mut core::Vector<f32>, score_threshold: f32, nms_threshold: f32, indices: &'a mut core::Vector<i32>, method: crate::dnn::SoftNMSMethod) -> Self {
+		Self { bboxes, scores, updated_scores, score_threshold, nms_threshold, indices, top_k: 0, sigma: 0.5, method }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_top_k(mut self, top_k: size_t) -> Self {
+		self.top_k = top_k;
+		self
+	}
+	
+	/// C++ default: `0.5`
+	#[inline]
+	pub fn with_sigma(mut self, sigma: f32) -> Self {
+		self.sigma = sigma;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		soft_nms_boxes(self.bboxes, self.scores, self.updated_scores, self.score_threshold, self.nms_threshold, self.indices, self.top_k, self.sigma, self.method)
+	}
+}
+
 /// ## C++ default parameters
 /// * name: ""
 #[inline]
 pub fn to_string(shape: &crate::dnn::MatShape, name: &str) -> Result<String> {
 	extern_container_arg!(name);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_toString_const_MatShapeR_const_StringR(shape.as_raw_VectorOfi32(), name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_toString_const_MatShapeR_const_StringR(shape.as_raw_VectorOfi32(), name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { String::opencv_from_extern(ret) };
@@ -1059,7 +1511,7 @@ pub fn to_string(shape: &crate::dnn::MatShape, name: &str) -> Result<String> {
 #[inline]
 pub fn total(shape: &crate::dnn::MatShape, start: i32, end: i32) -> Result<i32> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_total_const_MatShapeR_int_int(shape.as_raw_VectorOfi32(), start, end, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_total_const_MatShapeR_int_int(shape.as_raw_VectorOfi32(), start, end, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1077,7 +1529,7 @@ pub fn write_text_graph(model: &str, output: &str) -> Result<()> {
 	extern_container_arg!(model);
 	extern_container_arg!(output);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dnn_writeTextGraph_const_StringR_const_StringR(model.opencv_as_extern(), output.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dnn_writeTextGraph_const_StringR_const_StringR(model.opencv_as_extern(), output.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1102,7 +1554,10 @@ opencv_type_boxed! { AbsLayer }
 impl Drop for AbsLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_AbsLayer_delete(instance: *mut c_void); }
-		unsafe { cv_AbsLayer_delete(self.as_raw_mut_AbsLayer()) };
+		let ptr = self.as_raw_mut_AbsLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_AbsLayer_delete(ptr) };
 	}
 }
 
@@ -1144,7 +1599,7 @@ impl AbsLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::AbsLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_AbsLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_AbsLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::AbsLayer>::opencv_from_extern(ret) };
@@ -1178,7 +1633,10 @@ opencv_type_boxed! { AccumLayer }
 impl Drop for AccumLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_AccumLayer_delete(instance: *mut c_void); }
-		unsafe { cv_AccumLayer_delete(self.as_raw_mut_AccumLayer()) };
+		let ptr = self.as_raw_mut_AccumLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_AccumLayer_delete(ptr) };
 	}
 }
 
@@ -1212,7 +1670,7 @@ impl AccumLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::AccumLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_AccumLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_AccumLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::AccumLayer>::opencv_from_extern(ret) };
@@ -1244,7 +1702,10 @@ opencv_type_boxed! { AcosLayer }
 impl Drop for AcosLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_AcosLayer_delete(instance: *mut c_void); }
-		unsafe { cv_AcosLayer_delete(self.as_raw_mut_AcosLayer()) };
+		let ptr = self.as_raw_mut_AcosLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_AcosLayer_delete(ptr) };
 	}
 }
 
@@ -1286,7 +1747,7 @@ impl AcosLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::AcosLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_AcosLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_AcosLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::AcosLayer>::opencv_from_extern(ret) };
@@ -1320,7 +1781,10 @@ opencv_type_boxed! { AcoshLayer }
 impl Drop for AcoshLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_AcoshLayer_delete(instance: *mut c_void); }
-		unsafe { cv_AcoshLayer_delete(self.as_raw_mut_AcoshLayer()) };
+		let ptr = self.as_raw_mut_AcoshLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_AcoshLayer_delete(ptr) };
 	}
 }
 
@@ -1362,7 +1826,7 @@ impl AcoshLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::AcoshLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_AcoshLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_AcoshLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::AcoshLayer>::opencv_from_extern(ret) };
@@ -1382,8 +1846,9 @@ pub trait ActivationLayerTraitConst: crate::dnn::LayerTraitConst {
 
 	#[inline]
 	fn forward_slice(&self, src: &f32, dst: &mut f32, len: i32, out_plane_size: size_t, cn0: i32, cn1: i32) -> Result<()> {
+		crate::core::validity::check("ActivationLayer", self.as_raw_ActivationLayer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_ActivationLayer_forwardSlice_const_const_floatX_floatX_int_size_t_int_int(self.as_raw_ActivationLayer(), src, dst, len, out_plane_size, cn0, cn1, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_ActivationLayer_forwardSlice_const_const_floatX_floatX_int_size_t_int_int(self.as_raw_ActivationLayer(), src, dst, len, out_plane_size, cn0, cn1, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1391,8 +1856,9 @@ pub trait ActivationLayerTraitConst: crate::dnn::LayerTraitConst {
 	
 	#[inline]
 	fn forward_slice_1(&self, src: &i32, lut: &i32, dst: &mut i32, len: i32, out_plane_size: size_t, cn0: i32, cn1: i32) -> Result<()> {
+		crate::core::validity::check("ActivationLayer", self.as_raw_ActivationLayer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_ActivationLayer_forwardSlice_const_const_intX_const_intX_intX_int_size_t_int_int(self.as_raw_ActivationLayer(), src, lut, dst, len, out_plane_size, cn0, cn1, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_ActivationLayer_forwardSlice_const_const_intX_const_intX_intX_int_size_t_int_int(self.as_raw_ActivationLayer(), src, lut, dst, len, out_plane_size, cn0, cn1, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1414,7 +1880,10 @@ opencv_type_boxed! { ActivationLayer }
 impl Drop for ActivationLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ActivationLayer_delete(instance: *mut c_void); }
-		unsafe { cv_ActivationLayer_delete(self.as_raw_mut_ActivationLayer()) };
+		let ptr = self.as_raw_mut_ActivationLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ActivationLayer_delete(ptr) };
 	}
 }
 
@@ -1548,7 +2017,10 @@ opencv_type_boxed! { ActivationLayerInt8 }
 impl Drop for ActivationLayerInt8 {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ActivationLayerInt8_delete(instance: *mut c_void); }
-		unsafe { cv_ActivationLayerInt8_delete(self.as_raw_mut_ActivationLayerInt8()) };
+		let ptr = self.as_raw_mut_ActivationLayerInt8();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ActivationLayerInt8_delete(ptr) };
 	}
 }
 
@@ -1590,7 +2062,7 @@ impl ActivationLayerInt8 {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ActivationLayerInt8>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_ActivationLayerInt8_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_ActivationLayerInt8_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::ActivationLayerInt8>::opencv_from_extern(ret) };
@@ -1607,7 +2079,7 @@ boxed_cast_base! { ActivationLayerInt8, crate::dnn::Layer, cv_ActivationLayerInt
 
 /// ArgMax/ArgMin layer
 /// 
-/// Note: returns indices as floats, which means the supported range is [-2^24; 2^24]
+/// Note: returns indices as floats, which means the supported range is \[-2^24; 2^24\]
 pub trait ArgLayerTraitConst: crate::dnn::LayerTraitConst {
 	fn as_raw_ArgLayer(&self) -> *const c_void;
 
@@ -1620,7 +2092,7 @@ pub trait ArgLayerTrait: crate::dnn::ArgLayerTraitConst + crate::dnn::LayerTrait
 
 /// ArgMax/ArgMin layer
 /// 
-/// Note: returns indices as floats, which means the supported range is [-2^24; 2^24]
+/// Note: returns indices as floats, which means the supported range is \[-2^24; 2^24\]
 pub struct ArgLayer {
 	ptr: *mut c_void
 }
@@ -1630,7 +2102,10 @@ opencv_type_boxed! { ArgLayer }
 impl Drop for ArgLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ArgLayer_delete(instance: *mut c_void); }
-		unsafe { cv_ArgLayer_delete(self.as_raw_mut_ArgLayer()) };
+		let ptr = self.as_raw_mut_ArgLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ArgLayer_delete(ptr) };
 	}
 }
 
@@ -1664,7 +2139,7 @@ impl ArgLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ArgLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_ArgLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_ArgLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::ArgLayer>::opencv_from_extern(ret) };
@@ -1696,7 +2171,10 @@ opencv_type_boxed! { AsinLayer }
 impl Drop for AsinLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_AsinLayer_delete(instance: *mut c_void); }
-		unsafe { cv_AsinLayer_delete(self.as_raw_mut_AsinLayer()) };
+		let ptr = self.as_raw_mut_AsinLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_AsinLayer_delete(ptr) };
 	}
 }
 
@@ -1738,7 +2216,7 @@ impl AsinLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::AsinLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_AsinLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_AsinLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::AsinLayer>::opencv_from_extern(ret) };
@@ -1772,7 +2250,10 @@ opencv_type_boxed! { AsinhLayer }
 impl Drop for AsinhLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_AsinhLayer_delete(instance: *mut c_void); }
-		unsafe { cv_AsinhLayer_delete(self.as_raw_mut_AsinhLayer()) };
+		let ptr = self.as_raw_mut_AsinhLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_AsinhLayer_delete(ptr) };
 	}
 }
 
@@ -1814,7 +2295,7 @@ impl AsinhLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::AsinhLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_AsinhLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_AsinhLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::AsinhLayer>::opencv_from_extern(ret) };
@@ -1848,7 +2329,10 @@ opencv_type_boxed! { AtanLayer }
 impl Drop for AtanLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_AtanLayer_delete(instance: *mut c_void); }
-		unsafe { cv_AtanLayer_delete(self.as_raw_mut_AtanLayer()) };
+		let ptr = self.as_raw_mut_AtanLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_AtanLayer_delete(ptr) };
 	}
 }
 
@@ -1890,7 +2374,7 @@ impl AtanLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::AtanLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_AtanLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_AtanLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::AtanLayer>::opencv_from_extern(ret) };
@@ -1924,7 +2408,10 @@ opencv_type_boxed! { AtanhLayer }
 impl Drop for AtanhLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_AtanhLayer_delete(instance: *mut c_void); }
-		unsafe { cv_AtanhLayer_delete(self.as_raw_mut_AtanhLayer()) };
+		let ptr = self.as_raw_mut_AtanhLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_AtanhLayer_delete(ptr) };
 	}
 }
 
@@ -1966,7 +2453,7 @@ impl AtanhLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::AtanhLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_AtanhLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_AtanhLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::AtanhLayer>::opencv_from_extern(ret) };
@@ -2000,7 +2487,10 @@ opencv_type_boxed! { BNLLLayer }
 impl Drop for BNLLLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BNLLLayer_delete(instance: *mut c_void); }
-		unsafe { cv_BNLLLayer_delete(self.as_raw_mut_BNLLLayer()) };
+		let ptr = self.as_raw_mut_BNLLLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_BNLLLayer_delete(ptr) };
 	}
 }
 
@@ -2042,7 +2532,7 @@ impl BNLLLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::BNLLLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_BNLLLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_BNLLLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::BNLLLayer>::opencv_from_extern(ret) };
@@ -2064,10 +2554,19 @@ pub trait BackendNodeTraitConst {
 	/// Backend identifier.
 	#[inline]
 	fn backend_id(&self) -> i32 {
-		let ret = unsafe { sys::cv_dnn_BackendNode_getPropBackendId_const(self.as_raw_BackendNode()) };
+		crate::core::validity::debug_check("BackendNode", self.as_raw_BackendNode());
+		let ret = unsafe { ffi_call!(cv_dnn_BackendNode_getPropBackendId_const(self.as_raw_BackendNode())) };
 		ret
 	}
 	
+	/// Non-panicking version of [backend_id](BackendNodeTraitConst::backend_id), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_backend_id(&self) -> Result<i32> {
+		crate::core::validity::check("BackendNode", self.as_raw_BackendNode())?;
+		Ok(BackendNodeTraitConst::backend_id(self))
+	}
+	
 }
 
 pub trait BackendNodeTrait: crate::dnn::BackendNodeTraitConst {
@@ -2076,7 +2575,8 @@ pub trait BackendNodeTrait: crate::dnn::BackendNodeTraitConst {
 	/// Backend identifier.
 	#[inline]
 	fn set_backend_id(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_dnn_BackendNode_setPropBackendId_int(self.as_raw_mut_BackendNode(), val) };
+		crate::core::validity::debug_check("BackendNode", self.as_raw_mut_BackendNode());
+		let ret = unsafe { ffi_call!(cv_dnn_BackendNode_setPropBackendId_int(self.as_raw_mut_BackendNode(), val)) };
 		ret
 	}
 	
@@ -2092,7 +2592,10 @@ opencv_type_boxed! { BackendNode }
 impl Drop for BackendNode {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BackendNode_delete(instance: *mut c_void); }
-		unsafe { cv_BackendNode_delete(self.as_raw_mut_BackendNode()) };
+		let ptr = self.as_raw_mut_BackendNode();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_BackendNode_delete(ptr) };
 	}
 }
 
@@ -2116,17 +2619,35 @@ pub trait BackendWrapperConst {
 	/// Backend identifier.
 	#[inline]
 	fn backend_id(&self) -> i32 {
-		let ret = unsafe { sys::cv_dnn_BackendWrapper_getPropBackendId_const(self.as_raw_BackendWrapper()) };
+		crate::core::validity::debug_check("BackendWrapper", self.as_raw_BackendWrapper());
+		let ret = unsafe { ffi_call!(cv_dnn_BackendWrapper_getPropBackendId_const(self.as_raw_BackendWrapper())) };
 		ret
 	}
 	
+	/// Non-panicking version of [backend_id](BackendWrapperConst::backend_id), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_backend_id(&self) -> Result<i32> {
+		crate::core::validity::check("BackendWrapper", self.as_raw_BackendWrapper())?;
+		Ok(BackendWrapperConst::backend_id(self))
+	}
+	
 	/// Target identifier.
 	#[inline]
 	fn target_id(&self) -> i32 {
-		let ret = unsafe { sys::cv_dnn_BackendWrapper_getPropTargetId_const(self.as_raw_BackendWrapper()) };
+		crate::core::validity::debug_check("BackendWrapper", self.as_raw_BackendWrapper());
+		let ret = unsafe { ffi_call!(cv_dnn_BackendWrapper_getPropTargetId_const(self.as_raw_BackendWrapper())) };
 		ret
 	}
 	
+	/// Non-panicking version of [target_id](BackendWrapperConst::target_id), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_target_id(&self) -> Result<i32> {
+		crate::core::validity::check("BackendWrapper", self.as_raw_BackendWrapper())?;
+		Ok(BackendWrapperConst::target_id(self))
+	}
+	
 }
 
 pub trait BackendWrapper: crate::dnn::BackendWrapperConst {
@@ -2135,22 +2656,25 @@ pub trait BackendWrapper: crate::dnn::BackendWrapperConst {
 	/// Backend identifier.
 	#[inline]
 	fn set_backend_id(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_dnn_BackendWrapper_setPropBackendId_int(self.as_raw_mut_BackendWrapper(), val) };
+		crate::core::validity::debug_check("BackendWrapper", self.as_raw_mut_BackendWrapper());
+		let ret = unsafe { ffi_call!(cv_dnn_BackendWrapper_setPropBackendId_int(self.as_raw_mut_BackendWrapper(), val)) };
 		ret
 	}
 	
 	/// Target identifier.
 	#[inline]
 	fn set_target_id(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_dnn_BackendWrapper_setPropTargetId_int(self.as_raw_mut_BackendWrapper(), val) };
+		crate::core::validity::debug_check("BackendWrapper", self.as_raw_mut_BackendWrapper());
+		let ret = unsafe { ffi_call!(cv_dnn_BackendWrapper_setPropTargetId_int(self.as_raw_mut_BackendWrapper(), val)) };
 		ret
 	}
 	
 	/// Transfer data to CPU host memory.
 	#[inline]
 	fn copy_to_host(&mut self) -> Result<()> {
+		crate::core::validity::check("BackendWrapper", self.as_raw_mut_BackendWrapper())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_BackendWrapper_copyToHost(self.as_raw_mut_BackendWrapper(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_BackendWrapper_copyToHost(self.as_raw_mut_BackendWrapper(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2159,8 +2683,9 @@ pub trait BackendWrapper: crate::dnn::BackendWrapperConst {
 	/// Indicate that an actual data is on CPU.
 	#[inline]
 	fn set_host_dirty(&mut self) -> Result<()> {
+		crate::core::validity::check("BackendWrapper", self.as_raw_mut_BackendWrapper())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_BackendWrapper_setHostDirty(self.as_raw_mut_BackendWrapper(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_BackendWrapper_setHostDirty(self.as_raw_mut_BackendWrapper(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2173,99 +2698,216 @@ pub trait BaseConvolutionLayerTraitConst: crate::dnn::LayerTraitConst {
 
 	#[inline]
 	fn kernel(&self) -> core::Size {
+		crate::core::validity::debug_check("BaseConvolutionLayer", self.as_raw_BaseConvolutionLayer());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_BaseConvolutionLayer_getPropKernel_const(self.as_raw_BaseConvolutionLayer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_BaseConvolutionLayer_getPropKernel_const(self.as_raw_BaseConvolutionLayer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [kernel](BaseConvolutionLayerTraitConst::kernel), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_kernel(&self) -> Result<core::Size> {
+		crate::core::validity::check("BaseConvolutionLayer", self.as_raw_BaseConvolutionLayer())?;
+		Ok(BaseConvolutionLayerTraitConst::kernel(self))
+	}
+	
 	#[inline]
 	fn stride(&self) -> core::Size {
+		crate::core::validity::debug_check("BaseConvolutionLayer", self.as_raw_BaseConvolutionLayer());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_BaseConvolutionLayer_getPropStride_const(self.as_raw_BaseConvolutionLayer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_BaseConvolutionLayer_getPropStride_const(self.as_raw_BaseConvolutionLayer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [stride](BaseConvolutionLayerTraitConst::stride), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_stride(&self) -> Result<core::Size> {
+		crate::core::validity::check("BaseConvolutionLayer", self.as_raw_BaseConvolutionLayer())?;
+		Ok(BaseConvolutionLayerTraitConst::stride(self))
+	}
+	
 	#[inline]
 	fn pad(&self) -> core::Size {
+		crate::core::validity::debug_check("BaseConvolutionLayer", self.as_raw_BaseConvolutionLayer());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_BaseConvolutionLayer_getPropPad_const(self.as_raw_BaseConvolutionLayer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_BaseConvolutionLayer_getPropPad_const(self.as_raw_BaseConvolutionLayer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [pad](BaseConvolutionLayerTraitConst::pad), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pad(&self) -> Result<core::Size> {
+		crate::core::validity::check("BaseConvolutionLayer", self.as_raw_BaseConvolutionLayer())?;
+		Ok(BaseConvolutionLayerTraitConst::pad(self))
+	}
+	
 	#[inline]
 	fn dilation(&self) -> core::Size {
+		crate::core::validity::debug_check("BaseConvolutionLayer", self.as_raw_BaseConvolutionLayer());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_BaseConvolutionLayer_getPropDilation_const(self.as_raw_BaseConvolutionLayer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_BaseConvolutionLayer_getPropDilation_const(self.as_raw_BaseConvolutionLayer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [dilation](BaseConvolutionLayerTraitConst::dilation), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_dilation(&self) -> Result<core::Size> {
+		crate::core::validity::check("BaseConvolutionLayer", self.as_raw_BaseConvolutionLayer())?;
+		Ok(BaseConvolutionLayerTraitConst::dilation(self))
+	}
+	
 	#[inline]
 	fn adjust_pad(&self) -> core::Size {
+		crate::core::validity::debug_check("BaseConvolutionLayer", self.as_raw_BaseConvolutionLayer());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_BaseConvolutionLayer_getPropAdjustPad_const(self.as_raw_BaseConvolutionLayer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_BaseConvolutionLayer_getPropAdjustPad_const(self.as_raw_BaseConvolutionLayer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [adjust_pad](BaseConvolutionLayerTraitConst::adjust_pad), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_adjust_pad(&self) -> Result<core::Size> {
+		crate::core::validity::check("BaseConvolutionLayer", self.as_raw_BaseConvolutionLayer())?;
+		Ok(BaseConvolutionLayerTraitConst::adjust_pad(self))
+	}
+	
 	#[inline]
 	fn adjust_pads(&self) -> core::Vector<size_t> {
-		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_getPropAdjust_pads_const(self.as_raw_BaseConvolutionLayer()) };
+		crate::core::validity::debug_check("BaseConvolutionLayer", self.as_raw_BaseConvolutionLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_BaseConvolutionLayer_getPropAdjust_pads_const(self.as_raw_BaseConvolutionLayer())) };
 		let ret = unsafe { core::Vector::<size_t>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [adjust_pads](BaseConvolutionLayerTraitConst::adjust_pads), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_adjust_pads(&self) -> Result<core::Vector<size_t>> {
+		crate::core::validity::check("BaseConvolutionLayer", self.as_raw_BaseConvolutionLayer())?;
+		Ok(BaseConvolutionLayerTraitConst::adjust_pads(self))
+	}
+	
 	#[inline]
 	fn kernel_size(&self) -> core::Vector<size_t> {
-		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_getPropKernel_size_const(self.as_raw_BaseConvolutionLayer()) };
+		crate::core::validity::debug_check("BaseConvolutionLayer", self.as_raw_BaseConvolutionLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_BaseConvolutionLayer_getPropKernel_size_const(self.as_raw_BaseConvolutionLayer())) };
 		let ret = unsafe { core::Vector::<size_t>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [kernel_size](BaseConvolutionLayerTraitConst::kernel_size), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_kernel_size(&self) -> Result<core::Vector<size_t>> {
+		crate::core::validity::check("BaseConvolutionLayer", self.as_raw_BaseConvolutionLayer())?;
+		Ok(BaseConvolutionLayerTraitConst::kernel_size(self))
+	}
+	
 	#[inline]
 	fn strides(&self) -> core::Vector<size_t> {
-		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_getPropStrides_const(self.as_raw_BaseConvolutionLayer()) };
+		crate::core::validity::debug_check("BaseConvolutionLayer", self.as_raw_BaseConvolutionLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_BaseConvolutionLayer_getPropStrides_const(self.as_raw_BaseConvolutionLayer())) };
 		let ret = unsafe { core::Vector::<size_t>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [strides](BaseConvolutionLayerTraitConst::strides), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_strides(&self) -> Result<core::Vector<size_t>> {
+		crate::core::validity::check("BaseConvolutionLayer", self.as_raw_BaseConvolutionLayer())?;
+		Ok(BaseConvolutionLayerTraitConst::strides(self))
+	}
+	
 	#[inline]
 	fn dilations(&self) -> core::Vector<size_t> {
-		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_getPropDilations_const(self.as_raw_BaseConvolutionLayer()) };
+		crate::core::validity::debug_check("BaseConvolutionLayer", self.as_raw_BaseConvolutionLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_BaseConvolutionLayer_getPropDilations_const(self.as_raw_BaseConvolutionLayer())) };
 		let ret = unsafe { core::Vector::<size_t>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [dilations](BaseConvolutionLayerTraitConst::dilations), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_dilations(&self) -> Result<core::Vector<size_t>> {
+		crate::core::validity::check("BaseConvolutionLayer", self.as_raw_BaseConvolutionLayer())?;
+		Ok(BaseConvolutionLayerTraitConst::dilations(self))
+	}
+	
 	#[inline]
 	fn pads_begin(&self) -> core::Vector<size_t> {
-		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_getPropPads_begin_const(self.as_raw_BaseConvolutionLayer()) };
+		crate::core::validity::debug_check("BaseConvolutionLayer", self.as_raw_BaseConvolutionLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_BaseConvolutionLayer_getPropPads_begin_const(self.as_raw_BaseConvolutionLayer())) };
 		let ret = unsafe { core::Vector::<size_t>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [pads_begin](BaseConvolutionLayerTraitConst::pads_begin), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pads_begin(&self) -> Result<core::Vector<size_t>> {
+		crate::core::validity::check("BaseConvolutionLayer", self.as_raw_BaseConvolutionLayer())?;
+		Ok(BaseConvolutionLayerTraitConst::pads_begin(self))
+	}
+	
 	#[inline]
 	fn pads_end(&self) -> core::Vector<size_t> {
-		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_getPropPads_end_const(self.as_raw_BaseConvolutionLayer()) };
+		crate::core::validity::debug_check("BaseConvolutionLayer", self.as_raw_BaseConvolutionLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_BaseConvolutionLayer_getPropPads_end_const(self.as_raw_BaseConvolutionLayer())) };
 		let ret = unsafe { core::Vector::<size_t>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [pads_end](BaseConvolutionLayerTraitConst::pads_end), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pads_end(&self) -> Result<core::Vector<size_t>> {
+		crate::core::validity::check("BaseConvolutionLayer", self.as_raw_BaseConvolutionLayer())?;
+		Ok(BaseConvolutionLayerTraitConst::pads_end(self))
+	}
+	
 	#[inline]
 	fn pad_mode(&self) -> String {
-		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_getPropPadMode_const(self.as_raw_BaseConvolutionLayer()) };
+		crate::core::validity::debug_check("BaseConvolutionLayer", self.as_raw_BaseConvolutionLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_BaseConvolutionLayer_getPropPadMode_const(self.as_raw_BaseConvolutionLayer())) };
 		let ret = unsafe { String::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [pad_mode](BaseConvolutionLayerTraitConst::pad_mode), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pad_mode(&self) -> Result<String> {
+		crate::core::validity::check("BaseConvolutionLayer", self.as_raw_BaseConvolutionLayer())?;
+		Ok(BaseConvolutionLayerTraitConst::pad_mode(self))
+	}
+	
 	#[inline]
 	fn num_output(&self) -> i32 {
-		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_getPropNumOutput_const(self.as_raw_BaseConvolutionLayer()) };
+		crate::core::validity::debug_check("BaseConvolutionLayer", self.as_raw_BaseConvolutionLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_BaseConvolutionLayer_getPropNumOutput_const(self.as_raw_BaseConvolutionLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [num_output](BaseConvolutionLayerTraitConst::num_output), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_num_output(&self) -> Result<i32> {
+		crate::core::validity::check("BaseConvolutionLayer", self.as_raw_BaseConvolutionLayer())?;
+		Ok(BaseConvolutionLayerTraitConst::num_output(self))
+	}
+	
 }
 
 pub trait BaseConvolutionLayerTrait: crate::dnn::BaseConvolutionLayerTraitConst + crate::dnn::LayerTrait {
@@ -2273,80 +2915,93 @@ pub trait BaseConvolutionLayerTrait: crate::dnn::BaseConvolutionLayerTraitConst
 
 	#[inline]
 	fn set_kernel(&mut self, val: core::Size) {
-		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_setPropKernel_Size(self.as_raw_mut_BaseConvolutionLayer(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("BaseConvolutionLayer", self.as_raw_mut_BaseConvolutionLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_BaseConvolutionLayer_setPropKernel_Size(self.as_raw_mut_BaseConvolutionLayer(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_stride(&mut self, val: core::Size) {
-		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_setPropStride_Size(self.as_raw_mut_BaseConvolutionLayer(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("BaseConvolutionLayer", self.as_raw_mut_BaseConvolutionLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_BaseConvolutionLayer_setPropStride_Size(self.as_raw_mut_BaseConvolutionLayer(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_pad(&mut self, val: core::Size) {
-		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_setPropPad_Size(self.as_raw_mut_BaseConvolutionLayer(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("BaseConvolutionLayer", self.as_raw_mut_BaseConvolutionLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_BaseConvolutionLayer_setPropPad_Size(self.as_raw_mut_BaseConvolutionLayer(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_dilation(&mut self, val: core::Size) {
-		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_setPropDilation_Size(self.as_raw_mut_BaseConvolutionLayer(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("BaseConvolutionLayer", self.as_raw_mut_BaseConvolutionLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_BaseConvolutionLayer_setPropDilation_Size(self.as_raw_mut_BaseConvolutionLayer(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_adjust_pad(&mut self, val: core::Size) {
-		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_setPropAdjustPad_Size(self.as_raw_mut_BaseConvolutionLayer(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("BaseConvolutionLayer", self.as_raw_mut_BaseConvolutionLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_BaseConvolutionLayer_setPropAdjustPad_Size(self.as_raw_mut_BaseConvolutionLayer(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_adjust_pads(&mut self, mut val: core::Vector<size_t>) {
-		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_setPropAdjust_pads_vector_size_t_(self.as_raw_mut_BaseConvolutionLayer(), val.as_raw_mut_VectorOfsize_t()) };
+		crate::core::validity::debug_check("BaseConvolutionLayer", self.as_raw_mut_BaseConvolutionLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_BaseConvolutionLayer_setPropAdjust_pads_vector_size_t_(self.as_raw_mut_BaseConvolutionLayer(), val.as_raw_mut_VectorOfsize_t())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_kernel_size(&mut self, mut val: core::Vector<size_t>) {
-		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_setPropKernel_size_vector_size_t_(self.as_raw_mut_BaseConvolutionLayer(), val.as_raw_mut_VectorOfsize_t()) };
+		crate::core::validity::debug_check("BaseConvolutionLayer", self.as_raw_mut_BaseConvolutionLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_BaseConvolutionLayer_setPropKernel_size_vector_size_t_(self.as_raw_mut_BaseConvolutionLayer(), val.as_raw_mut_VectorOfsize_t())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_strides(&mut self, mut val: core::Vector<size_t>) {
-		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_setPropStrides_vector_size_t_(self.as_raw_mut_BaseConvolutionLayer(), val.as_raw_mut_VectorOfsize_t()) };
+		crate::core::validity::debug_check("BaseConvolutionLayer", self.as_raw_mut_BaseConvolutionLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_BaseConvolutionLayer_setPropStrides_vector_size_t_(self.as_raw_mut_BaseConvolutionLayer(), val.as_raw_mut_VectorOfsize_t())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_dilations(&mut self, mut val: core::Vector<size_t>) {
-		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_setPropDilations_vector_size_t_(self.as_raw_mut_BaseConvolutionLayer(), val.as_raw_mut_VectorOfsize_t()) };
+		crate::core::validity::debug_check("BaseConvolutionLayer", self.as_raw_mut_BaseConvolutionLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_BaseConvolutionLayer_setPropDilations_vector_size_t_(self.as_raw_mut_BaseConvolutionLayer(), val.as_raw_mut_VectorOfsize_t())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_pads_begin(&mut self, mut val: core::Vector<size_t>) {
-		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_setPropPads_begin_vector_size_t_(self.as_raw_mut_BaseConvolutionLayer(), val.as_raw_mut_VectorOfsize_t()) };
+		crate::core::validity::debug_check("BaseConvolutionLayer", self.as_raw_mut_BaseConvolutionLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_BaseConvolutionLayer_setPropPads_begin_vector_size_t_(self.as_raw_mut_BaseConvolutionLayer(), val.as_raw_mut_VectorOfsize_t())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_pads_end(&mut self, mut val: core::Vector<size_t>) {
-		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_setPropPads_end_vector_size_t_(self.as_raw_mut_BaseConvolutionLayer(), val.as_raw_mut_VectorOfsize_t()) };
+		crate::core::validity::debug_check("BaseConvolutionLayer", self.as_raw_mut_BaseConvolutionLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_BaseConvolutionLayer_setPropPads_end_vector_size_t_(self.as_raw_mut_BaseConvolutionLayer(), val.as_raw_mut_VectorOfsize_t())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_pad_mode(&mut self, val: &str) {
+		crate::core::validity::debug_check("BaseConvolutionLayer", self.as_raw_mut_BaseConvolutionLayer());
 		extern_container_arg!(nofail mut val);
-		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_setPropPadMode_String(self.as_raw_mut_BaseConvolutionLayer(), val.opencv_as_extern_mut()) };
+		let ret = unsafe { ffi_call!(cv_dnn_BaseConvolutionLayer_setPropPadMode_String(self.as_raw_mut_BaseConvolutionLayer(), val.opencv_as_extern_mut())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_num_output(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_setPropNumOutput_int(self.as_raw_mut_BaseConvolutionLayer(), val) };
+		crate::core::validity::debug_check("BaseConvolutionLayer", self.as_raw_mut_BaseConvolutionLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_BaseConvolutionLayer_setPropNumOutput_int(self.as_raw_mut_BaseConvolutionLayer(), val)) };
 		ret
 	}
 	
@@ -2361,7 +3016,10 @@ opencv_type_boxed! { BaseConvolutionLayer }
 impl Drop for BaseConvolutionLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BaseConvolutionLayer_delete(instance: *mut c_void); }
-		unsafe { cv_BaseConvolutionLayer_delete(self.as_raw_mut_BaseConvolutionLayer()) };
+		let ptr = self.as_raw_mut_BaseConvolutionLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_BaseConvolutionLayer_delete(ptr) };
 	}
 }
 
@@ -2403,22 +3061,49 @@ pub trait BatchNormLayerTraitConst: crate::dnn::ActivationLayerTraitConst {
 
 	#[inline]
 	fn has_weights(&self) -> bool {
-		let ret = unsafe { sys::cv_dnn_BatchNormLayer_getPropHasWeights_const(self.as_raw_BatchNormLayer()) };
+		crate::core::validity::debug_check("BatchNormLayer", self.as_raw_BatchNormLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_BatchNormLayer_getPropHasWeights_const(self.as_raw_BatchNormLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [has_weights](BatchNormLayerTraitConst::has_weights), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_has_weights(&self) -> Result<bool> {
+		crate::core::validity::check("BatchNormLayer", self.as_raw_BatchNormLayer())?;
+		Ok(BatchNormLayerTraitConst::has_weights(self))
+	}
+	
 	#[inline]
 	fn has_bias(&self) -> bool {
-		let ret = unsafe { sys::cv_dnn_BatchNormLayer_getPropHasBias_const(self.as_raw_BatchNormLayer()) };
+		crate::core::validity::debug_check("BatchNormLayer", self.as_raw_BatchNormLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_BatchNormLayer_getPropHasBias_const(self.as_raw_BatchNormLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [has_bias](BatchNormLayerTraitConst::has_bias), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_has_bias(&self) -> Result<bool> {
+		crate::core::validity::check("BatchNormLayer", self.as_raw_BatchNormLayer())?;
+		Ok(BatchNormLayerTraitConst::has_bias(self))
+	}
+	
 	#[inline]
 	fn epsilon(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_BatchNormLayer_getPropEpsilon_const(self.as_raw_BatchNormLayer()) };
+		crate::core::validity::debug_check("BatchNormLayer", self.as_raw_BatchNormLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_BatchNormLayer_getPropEpsilon_const(self.as_raw_BatchNormLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [epsilon](BatchNormLayerTraitConst::epsilon), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_epsilon(&self) -> Result<f32> {
+		crate::core::validity::check("BatchNormLayer", self.as_raw_BatchNormLayer())?;
+		Ok(BatchNormLayerTraitConst::epsilon(self))
+	}
+	
 }
 
 pub trait BatchNormLayerTrait: crate::dnn::ActivationLayerTrait + crate::dnn::BatchNormLayerTraitConst {
@@ -2426,19 +3111,22 @@ pub trait BatchNormLayerTrait: crate::dnn::ActivationLayerTrait + crate::dnn::Ba
 
 	#[inline]
 	fn set_has_weights(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_dnn_BatchNormLayer_setPropHasWeights_bool(self.as_raw_mut_BatchNormLayer(), val) };
+		crate::core::validity::debug_check("BatchNormLayer", self.as_raw_mut_BatchNormLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_BatchNormLayer_setPropHasWeights_bool(self.as_raw_mut_BatchNormLayer(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_has_bias(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_dnn_BatchNormLayer_setPropHasBias_bool(self.as_raw_mut_BatchNormLayer(), val) };
+		crate::core::validity::debug_check("BatchNormLayer", self.as_raw_mut_BatchNormLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_BatchNormLayer_setPropHasBias_bool(self.as_raw_mut_BatchNormLayer(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_epsilon(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_BatchNormLayer_setPropEpsilon_float(self.as_raw_mut_BatchNormLayer(), val) };
+		crate::core::validity::debug_check("BatchNormLayer", self.as_raw_mut_BatchNormLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_BatchNormLayer_setPropEpsilon_float(self.as_raw_mut_BatchNormLayer(), val)) };
 		ret
 	}
 	
@@ -2453,7 +3141,10 @@ opencv_type_boxed! { BatchNormLayer }
 impl Drop for BatchNormLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BatchNormLayer_delete(instance: *mut c_void); }
-		unsafe { cv_BatchNormLayer_delete(self.as_raw_mut_BatchNormLayer()) };
+		let ptr = self.as_raw_mut_BatchNormLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_BatchNormLayer_delete(ptr) };
 	}
 }
 
@@ -2495,7 +3186,7 @@ impl BatchNormLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::BatchNormLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_BatchNormLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_BatchNormLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::BatchNormLayer>::opencv_from_extern(ret) };
@@ -2515,28 +3206,64 @@ pub trait BatchNormLayerInt8TraitConst: crate::dnn::BatchNormLayerTraitConst {
 
 	#[inline]
 	fn input_sc(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_BatchNormLayerInt8_getPropInput_sc_const(self.as_raw_BatchNormLayerInt8()) };
+		crate::core::validity::debug_check("BatchNormLayerInt8", self.as_raw_BatchNormLayerInt8());
+		let ret = unsafe { ffi_call!(cv_dnn_BatchNormLayerInt8_getPropInput_sc_const(self.as_raw_BatchNormLayerInt8())) };
 		ret
 	}
 	
+	/// Non-panicking version of [input_sc](BatchNormLayerInt8TraitConst::input_sc), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_input_sc(&self) -> Result<f32> {
+		crate::core::validity::check("BatchNormLayerInt8", self.as_raw_BatchNormLayerInt8())?;
+		Ok(BatchNormLayerInt8TraitConst::input_sc(self))
+	}
+	
 	#[inline]
 	fn output_sc(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_BatchNormLayerInt8_getPropOutput_sc_const(self.as_raw_BatchNormLayerInt8()) };
+		crate::core::validity::debug_check("BatchNormLayerInt8", self.as_raw_BatchNormLayerInt8());
+		let ret = unsafe { ffi_call!(cv_dnn_BatchNormLayerInt8_getPropOutput_sc_const(self.as_raw_BatchNormLayerInt8())) };
 		ret
 	}
 	
+	/// Non-panicking version of [output_sc](BatchNormLayerInt8TraitConst::output_sc), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_output_sc(&self) -> Result<f32> {
+		crate::core::validity::check("BatchNormLayerInt8", self.as_raw_BatchNormLayerInt8())?;
+		Ok(BatchNormLayerInt8TraitConst::output_sc(self))
+	}
+	
 	#[inline]
 	fn input_zp(&self) -> i32 {
-		let ret = unsafe { sys::cv_dnn_BatchNormLayerInt8_getPropInput_zp_const(self.as_raw_BatchNormLayerInt8()) };
+		crate::core::validity::debug_check("BatchNormLayerInt8", self.as_raw_BatchNormLayerInt8());
+		let ret = unsafe { ffi_call!(cv_dnn_BatchNormLayerInt8_getPropInput_zp_const(self.as_raw_BatchNormLayerInt8())) };
 		ret
 	}
 	
+	/// Non-panicking version of [input_zp](BatchNormLayerInt8TraitConst::input_zp), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_input_zp(&self) -> Result<i32> {
+		crate::core::validity::check("BatchNormLayerInt8", self.as_raw_BatchNormLayerInt8())?;
+		Ok(BatchNormLayerInt8TraitConst::input_zp(self))
+	}
+	
 	#[inline]
 	fn output_zp(&self) -> i32 {
-		let ret = unsafe { sys::cv_dnn_BatchNormLayerInt8_getPropOutput_zp_const(self.as_raw_BatchNormLayerInt8()) };
+		crate::core::validity::debug_check("BatchNormLayerInt8", self.as_raw_BatchNormLayerInt8());
+		let ret = unsafe { ffi_call!(cv_dnn_BatchNormLayerInt8_getPropOutput_zp_const(self.as_raw_BatchNormLayerInt8())) };
 		ret
 	}
 	
+	/// Non-panicking version of [output_zp](BatchNormLayerInt8TraitConst::output_zp), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_output_zp(&self) -> Result<i32> {
+		crate::core::validity::check("BatchNormLayerInt8", self.as_raw_BatchNormLayerInt8())?;
+		Ok(BatchNormLayerInt8TraitConst::output_zp(self))
+	}
+	
 }
 
 pub trait BatchNormLayerInt8Trait: crate::dnn::BatchNormLayerInt8TraitConst + crate::dnn::BatchNormLayerTrait {
@@ -2544,25 +3271,29 @@ pub trait BatchNormLayerInt8Trait: crate::dnn::BatchNormLayerInt8TraitConst + cr
 
 	#[inline]
 	fn set_input_sc(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_BatchNormLayerInt8_setPropInput_sc_float(self.as_raw_mut_BatchNormLayerInt8(), val) };
+		crate::core::validity::debug_check("BatchNormLayerInt8", self.as_raw_mut_BatchNormLayerInt8());
+		let ret = unsafe { ffi_call!(cv_dnn_BatchNormLayerInt8_setPropInput_sc_float(self.as_raw_mut_BatchNormLayerInt8(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_output_sc(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_BatchNormLayerInt8_setPropOutput_sc_float(self.as_raw_mut_BatchNormLayerInt8(), val) };
+		crate::core::validity::debug_check("BatchNormLayerInt8", self.as_raw_mut_BatchNormLayerInt8());
+		let ret = unsafe { ffi_call!(cv_dnn_BatchNormLayerInt8_setPropOutput_sc_float(self.as_raw_mut_BatchNormLayerInt8(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_input_zp(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_dnn_BatchNormLayerInt8_setPropInput_zp_int(self.as_raw_mut_BatchNormLayerInt8(), val) };
+		crate::core::validity::debug_check("BatchNormLayerInt8", self.as_raw_mut_BatchNormLayerInt8());
+		let ret = unsafe { ffi_call!(cv_dnn_BatchNormLayerInt8_setPropInput_zp_int(self.as_raw_mut_BatchNormLayerInt8(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_output_zp(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_dnn_BatchNormLayerInt8_setPropOutput_zp_int(self.as_raw_mut_BatchNormLayerInt8(), val) };
+		crate::core::validity::debug_check("BatchNormLayerInt8", self.as_raw_mut_BatchNormLayerInt8());
+		let ret = unsafe { ffi_call!(cv_dnn_BatchNormLayerInt8_setPropOutput_zp_int(self.as_raw_mut_BatchNormLayerInt8(), val)) };
 		ret
 	}
 	
@@ -2577,7 +3308,10 @@ opencv_type_boxed! { BatchNormLayerInt8 }
 impl Drop for BatchNormLayerInt8 {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BatchNormLayerInt8_delete(instance: *mut c_void); }
-		unsafe { cv_BatchNormLayerInt8_delete(self.as_raw_mut_BatchNormLayerInt8()) };
+		let ptr = self.as_raw_mut_BatchNormLayerInt8();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_BatchNormLayerInt8_delete(ptr) };
 	}
 }
 
@@ -2627,7 +3361,7 @@ impl BatchNormLayerInt8 {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::BatchNormLayerInt8>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_BatchNormLayerInt8_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_BatchNormLayerInt8_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::BatchNormLayerInt8>::opencv_from_extern(ret) };
@@ -2648,8 +3382,8 @@ boxed_cast_base! { BatchNormLayerInt8, crate::dnn::Layer, cv_BatchNormLayerInt8_
 /// This subsection of dnn module contains information about built-in layers and their descriptions.
 /// 
 /// Classes listed here, in fact, provides C++ API for creating instances of built-in layers.
-/// In addition to this way of layers instantiation, there is a more common factory API (see @ref dnnLayerFactory), it allows to create layers dynamically (by name) and register new ones.
-/// You can use both API, but factory API is less convenient for native C++ programming and basically designed for use inside importers (see @ref readNetFromCaffe(), @ref readNetFromTorch(), @ref readNetFromTensorflow()).
+/// In addition to this way of layers instantiation, there is a more common factory API (see `dnn_layer_factory`), it allows to create layers dynamically (by name) and register new ones.
+/// You can use both API, but factory API is less convenient for native C++ programming and basically designed for use inside importers (see [read_net_from_caffe](crate::dnn::read_net_from_caffe), [read_net_from_torch](crate::dnn::read_net_from_torch), [read_net_from_tensorflow](crate::dnn::read_net_from_tensorflow)).
 /// 
 /// Built-in layers partially reproduce functionality of corresponding Caffe and Torch7 layers.
 /// In particular, the following layers and Caffe importer were tested to reproduce <a href="http://caffe.berkeleyvision.org/tutorial/layers.html">Caffe</a> functionality:
@@ -2677,8 +3411,8 @@ pub trait BlankLayerTrait: crate::dnn::BlankLayerTraitConst + crate::dnn::LayerT
 /// This subsection of dnn module contains information about built-in layers and their descriptions.
 /// 
 /// Classes listed here, in fact, provides C++ API for creating instances of built-in layers.
-/// In addition to this way of layers instantiation, there is a more common factory API (see @ref dnnLayerFactory), it allows to create layers dynamically (by name) and register new ones.
-/// You can use both API, but factory API is less convenient for native C++ programming and basically designed for use inside importers (see @ref readNetFromCaffe(), @ref readNetFromTorch(), @ref readNetFromTensorflow()).
+/// In addition to this way of layers instantiation, there is a more common factory API (see `dnn_layer_factory`), it allows to create layers dynamically (by name) and register new ones.
+/// You can use both API, but factory API is less convenient for native C++ programming and basically designed for use inside importers (see [read_net_from_caffe](crate::dnn::read_net_from_caffe), [read_net_from_torch](crate::dnn::read_net_from_torch), [read_net_from_tensorflow](crate::dnn::read_net_from_tensorflow)).
 /// 
 /// Built-in layers partially reproduce functionality of corresponding Caffe and Torch7 layers.
 /// In particular, the following layers and Caffe importer were tested to reproduce <a href="http://caffe.berkeleyvision.org/tutorial/layers.html">Caffe</a> functionality:
@@ -2701,7 +3435,10 @@ opencv_type_boxed! { BlankLayer }
 impl Drop for BlankLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BlankLayer_delete(instance: *mut c_void); }
-		unsafe { cv_BlankLayer_delete(self.as_raw_mut_BlankLayer()) };
+		let ptr = self.as_raw_mut_BlankLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_BlankLayer_delete(ptr) };
 	}
 }
 
@@ -2735,7 +3472,7 @@ impl BlankLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::Layer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_BlankLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_BlankLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::Layer>::opencv_from_extern(ret) };
@@ -2767,7 +3504,10 @@ opencv_type_boxed! { CeilLayer }
 impl Drop for CeilLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CeilLayer_delete(instance: *mut c_void); }
-		unsafe { cv_CeilLayer_delete(self.as_raw_mut_CeilLayer()) };
+		let ptr = self.as_raw_mut_CeilLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_CeilLayer_delete(ptr) };
 	}
 }
 
@@ -2809,7 +3549,7 @@ impl CeilLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::CeilLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_CeilLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_CeilLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::CeilLayer>::opencv_from_extern(ret) };
@@ -2829,10 +3569,19 @@ pub trait CeluLayerTraitConst: crate::dnn::ActivationLayerTraitConst {
 
 	#[inline]
 	fn alpha(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_CeluLayer_getPropAlpha_const(self.as_raw_CeluLayer()) };
+		crate::core::validity::debug_check("CeluLayer", self.as_raw_CeluLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_CeluLayer_getPropAlpha_const(self.as_raw_CeluLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [alpha](CeluLayerTraitConst::alpha), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_alpha(&self) -> Result<f32> {
+		crate::core::validity::check("CeluLayer", self.as_raw_CeluLayer())?;
+		Ok(CeluLayerTraitConst::alpha(self))
+	}
+	
 }
 
 pub trait CeluLayerTrait: crate::dnn::ActivationLayerTrait + crate::dnn::CeluLayerTraitConst {
@@ -2840,7 +3589,8 @@ pub trait CeluLayerTrait: crate::dnn::ActivationLayerTrait + crate::dnn::CeluLay
 
 	#[inline]
 	fn set_alpha(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_CeluLayer_setPropAlpha_float(self.as_raw_mut_CeluLayer(), val) };
+		crate::core::validity::debug_check("CeluLayer", self.as_raw_mut_CeluLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_CeluLayer_setPropAlpha_float(self.as_raw_mut_CeluLayer(), val)) };
 		ret
 	}
 	
@@ -2855,7 +3605,10 @@ opencv_type_boxed! { CeluLayer }
 impl Drop for CeluLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CeluLayer_delete(instance: *mut c_void); }
-		unsafe { cv_CeluLayer_delete(self.as_raw_mut_CeluLayer()) };
+		let ptr = self.as_raw_mut_CeluLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_CeluLayer_delete(ptr) };
 	}
 }
 
@@ -2897,7 +3650,7 @@ impl CeluLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::CeluLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_CeluLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_CeluLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::CeluLayer>::opencv_from_extern(ret) };
@@ -2931,7 +3684,10 @@ opencv_type_boxed! { ChannelsPReLULayer }
 impl Drop for ChannelsPReLULayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ChannelsPReLULayer_delete(instance: *mut c_void); }
-		unsafe { cv_ChannelsPReLULayer_delete(self.as_raw_mut_ChannelsPReLULayer()) };
+		let ptr = self.as_raw_mut_ChannelsPReLULayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ChannelsPReLULayer_delete(ptr) };
 	}
 }
 
@@ -2973,7 +3729,7 @@ impl ChannelsPReLULayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::Layer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_ChannelsPReLULayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_ChannelsPReLULayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::Layer>::opencv_from_extern(ret) };
@@ -3008,9 +3764,10 @@ pub trait ClassificationModelTrait: crate::dnn::ClassificationModelTraitConst +
 	/// ## Overloaded parameters
 	#[inline]
 	fn classify(&mut self, frame: &dyn core::ToInputArray, class_id: &mut i32, conf: &mut f32) -> Result<()> {
+		crate::core::validity::check("ClassificationModel", self.as_raw_mut_ClassificationModel())?;
 		input_array_arg!(frame);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_ClassificationModel_classify_const__InputArrayR_intR_floatR(self.as_raw_mut_ClassificationModel(), frame.as_raw__InputArray(), class_id, conf, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_ClassificationModel_classify_const__InputArrayR_intR_floatR(self.as_raw_mut_ClassificationModel(), frame.as_raw__InputArray(), class_id, conf, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3032,7 +3789,10 @@ opencv_type_boxed! { ClassificationModel }
 impl Drop for ClassificationModel {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ClassificationModel_delete(instance: *mut c_void); }
-		unsafe { cv_ClassificationModel_delete(self.as_raw_mut_ClassificationModel()) };
+		let ptr = self.as_raw_mut_ClassificationModel();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ClassificationModel_delete(ptr) };
 	}
 }
 
@@ -3068,7 +3828,7 @@ impl ClassificationModel {
 		extern_container_arg!(model);
 		extern_container_arg!(config);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_ClassificationModel_ClassificationModel_const_StringR_const_StringR(model.opencv_as_extern(), config.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_ClassificationModel_ClassificationModel_const_StringR_const_StringR(model.opencv_as_extern(), config.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::ClassificationModel::opencv_from_extern(ret) };
@@ -3081,7 +3841,7 @@ impl ClassificationModel {
 	#[inline]
 	pub fn new_1(network: &crate::dnn::Net) -> Result<crate::dnn::ClassificationModel> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_ClassificationModel_ClassificationModel_const_NetR(network.as_raw_Net(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_ClassificationModel_ClassificationModel_const_NetR(network.as_raw_Net(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::ClassificationModel::opencv_from_extern(ret) };
@@ -3111,7 +3871,10 @@ opencv_type_boxed! { CompareLayer }
 impl Drop for CompareLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CompareLayer_delete(instance: *mut c_void); }
-		unsafe { cv_CompareLayer_delete(self.as_raw_mut_CompareLayer()) };
+		let ptr = self.as_raw_mut_CompareLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_CompareLayer_delete(ptr) };
 	}
 }
 
@@ -3145,7 +3908,7 @@ impl CompareLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::Layer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_CompareLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_CompareLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::Layer>::opencv_from_extern(ret) };
@@ -3163,26 +3926,53 @@ pub trait ConcatLayerTraitConst: crate::dnn::LayerTraitConst {
 
 	#[inline]
 	fn axis(&self) -> i32 {
-		let ret = unsafe { sys::cv_dnn_ConcatLayer_getPropAxis_const(self.as_raw_ConcatLayer()) };
+		crate::core::validity::debug_check("ConcatLayer", self.as_raw_ConcatLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_ConcatLayer_getPropAxis_const(self.as_raw_ConcatLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [axis](ConcatLayerTraitConst::axis), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_axis(&self) -> Result<i32> {
+		crate::core::validity::check("ConcatLayer", self.as_raw_ConcatLayer())?;
+		Ok(ConcatLayerTraitConst::axis(self))
+	}
+	
 	/// Add zero padding in case of concatenation of blobs with different
 	/// spatial sizes.
 	/// 
 	/// Details: https://github.com/torch/nn/blob/master/doc/containers.md#depthconcat
 	#[inline]
 	fn padding(&self) -> bool {
-		let ret = unsafe { sys::cv_dnn_ConcatLayer_getPropPadding_const(self.as_raw_ConcatLayer()) };
+		crate::core::validity::debug_check("ConcatLayer", self.as_raw_ConcatLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_ConcatLayer_getPropPadding_const(self.as_raw_ConcatLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [padding](ConcatLayerTraitConst::padding), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_padding(&self) -> Result<bool> {
+		crate::core::validity::check("ConcatLayer", self.as_raw_ConcatLayer())?;
+		Ok(ConcatLayerTraitConst::padding(self))
+	}
+	
 	#[inline]
 	fn padding_value(&self) -> i32 {
-		let ret = unsafe { sys::cv_dnn_ConcatLayer_getPropPaddingValue_const(self.as_raw_ConcatLayer()) };
+		crate::core::validity::debug_check("ConcatLayer", self.as_raw_ConcatLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_ConcatLayer_getPropPaddingValue_const(self.as_raw_ConcatLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [padding_value](ConcatLayerTraitConst::padding_value), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_padding_value(&self) -> Result<i32> {
+		crate::core::validity::check("ConcatLayer", self.as_raw_ConcatLayer())?;
+		Ok(ConcatLayerTraitConst::padding_value(self))
+	}
+	
 }
 
 pub trait ConcatLayerTrait: crate::dnn::ConcatLayerTraitConst + crate::dnn::LayerTrait {
@@ -3190,7 +3980,8 @@ pub trait ConcatLayerTrait: crate::dnn::ConcatLayerTraitConst + crate::dnn::Laye
 
 	#[inline]
 	fn set_axis(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_dnn_ConcatLayer_setPropAxis_int(self.as_raw_mut_ConcatLayer(), val) };
+		crate::core::validity::debug_check("ConcatLayer", self.as_raw_mut_ConcatLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_ConcatLayer_setPropAxis_int(self.as_raw_mut_ConcatLayer(), val)) };
 		ret
 	}
 	
@@ -3200,13 +3991,15 @@ pub trait ConcatLayerTrait: crate::dnn::ConcatLayerTraitConst + crate::dnn::Laye
 	/// Details: https://github.com/torch/nn/blob/master/doc/containers.md#depthconcat
 	#[inline]
 	fn set_padding(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_dnn_ConcatLayer_setPropPadding_bool(self.as_raw_mut_ConcatLayer(), val) };
+		crate::core::validity::debug_check("ConcatLayer", self.as_raw_mut_ConcatLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_ConcatLayer_setPropPadding_bool(self.as_raw_mut_ConcatLayer(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_padding_value(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_dnn_ConcatLayer_setPropPaddingValue_int(self.as_raw_mut_ConcatLayer(), val) };
+		crate::core::validity::debug_check("ConcatLayer", self.as_raw_mut_ConcatLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_ConcatLayer_setPropPaddingValue_int(self.as_raw_mut_ConcatLayer(), val)) };
 		ret
 	}
 	
@@ -3221,7 +4014,10 @@ opencv_type_boxed! { ConcatLayer }
 impl Drop for ConcatLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ConcatLayer_delete(instance: *mut c_void); }
-		unsafe { cv_ConcatLayer_delete(self.as_raw_mut_ConcatLayer()) };
+		let ptr = self.as_raw_mut_ConcatLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ConcatLayer_delete(ptr) };
 	}
 }
 
@@ -3255,7 +4051,7 @@ impl ConcatLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ConcatLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_ConcatLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_ConcatLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::ConcatLayer>::opencv_from_extern(ret) };
@@ -3289,7 +4085,10 @@ opencv_type_boxed! { ConstLayer }
 impl Drop for ConstLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ConstLayer_delete(instance: *mut c_void); }
-		unsafe { cv_ConstLayer_delete(self.as_raw_mut_ConstLayer()) };
+		let ptr = self.as_raw_mut_ConstLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ConstLayer_delete(ptr) };
 	}
 }
 
@@ -3323,7 +4122,7 @@ impl ConstLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::Layer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_ConstLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_ConstLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::Layer>::opencv_from_extern(ret) };
@@ -3355,7 +4154,10 @@ opencv_type_boxed! { ConvolutionLayer }
 impl Drop for ConvolutionLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ConvolutionLayer_delete(instance: *mut c_void); }
-		unsafe { cv_ConvolutionLayer_delete(self.as_raw_mut_ConvolutionLayer()) };
+		let ptr = self.as_raw_mut_ConvolutionLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ConvolutionLayer_delete(ptr) };
 	}
 }
 
@@ -3397,7 +4199,7 @@ impl ConvolutionLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::BaseConvolutionLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_ConvolutionLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_ConvolutionLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::BaseConvolutionLayer>::opencv_from_extern(ret) };
@@ -3417,22 +4219,49 @@ pub trait ConvolutionLayerInt8TraitConst: crate::dnn::BaseConvolutionLayerTraitC
 
 	#[inline]
 	fn input_zp(&self) -> i32 {
-		let ret = unsafe { sys::cv_dnn_ConvolutionLayerInt8_getPropInput_zp_const(self.as_raw_ConvolutionLayerInt8()) };
+		crate::core::validity::debug_check("ConvolutionLayerInt8", self.as_raw_ConvolutionLayerInt8());
+		let ret = unsafe { ffi_call!(cv_dnn_ConvolutionLayerInt8_getPropInput_zp_const(self.as_raw_ConvolutionLayerInt8())) };
 		ret
 	}
 	
+	/// Non-panicking version of [input_zp](ConvolutionLayerInt8TraitConst::input_zp), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_input_zp(&self) -> Result<i32> {
+		crate::core::validity::check("ConvolutionLayerInt8", self.as_raw_ConvolutionLayerInt8())?;
+		Ok(ConvolutionLayerInt8TraitConst::input_zp(self))
+	}
+	
 	#[inline]
 	fn output_zp(&self) -> i32 {
-		let ret = unsafe { sys::cv_dnn_ConvolutionLayerInt8_getPropOutput_zp_const(self.as_raw_ConvolutionLayerInt8()) };
+		crate::core::validity::debug_check("ConvolutionLayerInt8", self.as_raw_ConvolutionLayerInt8());
+		let ret = unsafe { ffi_call!(cv_dnn_ConvolutionLayerInt8_getPropOutput_zp_const(self.as_raw_ConvolutionLayerInt8())) };
 		ret
 	}
 	
+	/// Non-panicking version of [output_zp](ConvolutionLayerInt8TraitConst::output_zp), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_output_zp(&self) -> Result<i32> {
+		crate::core::validity::check("ConvolutionLayerInt8", self.as_raw_ConvolutionLayerInt8())?;
+		Ok(ConvolutionLayerInt8TraitConst::output_zp(self))
+	}
+	
 	#[inline]
 	fn output_sc(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_ConvolutionLayerInt8_getPropOutput_sc_const(self.as_raw_ConvolutionLayerInt8()) };
+		crate::core::validity::debug_check("ConvolutionLayerInt8", self.as_raw_ConvolutionLayerInt8());
+		let ret = unsafe { ffi_call!(cv_dnn_ConvolutionLayerInt8_getPropOutput_sc_const(self.as_raw_ConvolutionLayerInt8())) };
 		ret
 	}
 	
+	/// Non-panicking version of [output_sc](ConvolutionLayerInt8TraitConst::output_sc), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_output_sc(&self) -> Result<f32> {
+		crate::core::validity::check("ConvolutionLayerInt8", self.as_raw_ConvolutionLayerInt8())?;
+		Ok(ConvolutionLayerInt8TraitConst::output_sc(self))
+	}
+	
 }
 
 pub trait ConvolutionLayerInt8Trait: crate::dnn::BaseConvolutionLayerTrait + crate::dnn::ConvolutionLayerInt8TraitConst {
@@ -3440,19 +4269,22 @@ pub trait ConvolutionLayerInt8Trait: crate::dnn::BaseConvolutionLayerTrait + cra
 
 	#[inline]
 	fn set_input_zp(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_dnn_ConvolutionLayerInt8_setPropInput_zp_int(self.as_raw_mut_ConvolutionLayerInt8(), val) };
+		crate::core::validity::debug_check("ConvolutionLayerInt8", self.as_raw_mut_ConvolutionLayerInt8());
+		let ret = unsafe { ffi_call!(cv_dnn_ConvolutionLayerInt8_setPropInput_zp_int(self.as_raw_mut_ConvolutionLayerInt8(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_output_zp(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_dnn_ConvolutionLayerInt8_setPropOutput_zp_int(self.as_raw_mut_ConvolutionLayerInt8(), val) };
+		crate::core::validity::debug_check("ConvolutionLayerInt8", self.as_raw_mut_ConvolutionLayerInt8());
+		let ret = unsafe { ffi_call!(cv_dnn_ConvolutionLayerInt8_setPropOutput_zp_int(self.as_raw_mut_ConvolutionLayerInt8(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_output_sc(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_ConvolutionLayerInt8_setPropOutput_sc_float(self.as_raw_mut_ConvolutionLayerInt8(), val) };
+		crate::core::validity::debug_check("ConvolutionLayerInt8", self.as_raw_mut_ConvolutionLayerInt8());
+		let ret = unsafe { ffi_call!(cv_dnn_ConvolutionLayerInt8_setPropOutput_sc_float(self.as_raw_mut_ConvolutionLayerInt8(), val)) };
 		ret
 	}
 	
@@ -3467,7 +4299,10 @@ opencv_type_boxed! { ConvolutionLayerInt8 }
 impl Drop for ConvolutionLayerInt8 {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ConvolutionLayerInt8_delete(instance: *mut c_void); }
-		unsafe { cv_ConvolutionLayerInt8_delete(self.as_raw_mut_ConvolutionLayerInt8()) };
+		let ptr = self.as_raw_mut_ConvolutionLayerInt8();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ConvolutionLayerInt8_delete(ptr) };
 	}
 }
 
@@ -3509,7 +4344,7 @@ impl ConvolutionLayerInt8 {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::BaseConvolutionLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_ConvolutionLayerInt8_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_ConvolutionLayerInt8_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::BaseConvolutionLayer>::opencv_from_extern(ret) };
@@ -3543,7 +4378,10 @@ opencv_type_boxed! { CorrelationLayer }
 impl Drop for CorrelationLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CorrelationLayer_delete(instance: *mut c_void); }
-		unsafe { cv_CorrelationLayer_delete(self.as_raw_mut_CorrelationLayer()) };
+		let ptr = self.as_raw_mut_CorrelationLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_CorrelationLayer_delete(ptr) };
 	}
 }
 
@@ -3577,7 +4415,7 @@ impl CorrelationLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::CorrelationLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_CorrelationLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_CorrelationLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::CorrelationLayer>::opencv_from_extern(ret) };
@@ -3609,7 +4447,10 @@ opencv_type_boxed! { CosLayer }
 impl Drop for CosLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CosLayer_delete(instance: *mut c_void); }
-		unsafe { cv_CosLayer_delete(self.as_raw_mut_CosLayer()) };
+		let ptr = self.as_raw_mut_CosLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_CosLayer_delete(ptr) };
 	}
 }
 
@@ -3651,7 +4492,7 @@ impl CosLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::CosLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_CosLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_CosLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::CosLayer>::opencv_from_extern(ret) };
@@ -3685,7 +4526,10 @@ opencv_type_boxed! { CoshLayer }
 impl Drop for CoshLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CoshLayer_delete(instance: *mut c_void); }
-		unsafe { cv_CoshLayer_delete(self.as_raw_mut_CoshLayer()) };
+		let ptr = self.as_raw_mut_CoshLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_CoshLayer_delete(ptr) };
 	}
 }
 
@@ -3727,7 +4571,7 @@ impl CoshLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::CoshLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_CoshLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_CoshLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::CoshLayer>::opencv_from_extern(ret) };
@@ -3761,7 +4605,10 @@ opencv_type_boxed! { CropAndResizeLayer }
 impl Drop for CropAndResizeLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CropAndResizeLayer_delete(instance: *mut c_void); }
-		unsafe { cv_CropAndResizeLayer_delete(self.as_raw_mut_CropAndResizeLayer()) };
+		let ptr = self.as_raw_mut_CropAndResizeLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_CropAndResizeLayer_delete(ptr) };
 	}
 }
 
@@ -3795,7 +4642,7 @@ impl CropAndResizeLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::Layer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_CropAndResizeLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_CropAndResizeLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::Layer>::opencv_from_extern(ret) };
@@ -3827,7 +4674,10 @@ opencv_type_boxed! { CropLayer }
 impl Drop for CropLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CropLayer_delete(instance: *mut c_void); }
-		unsafe { cv_CropLayer_delete(self.as_raw_mut_CropLayer()) };
+		let ptr = self.as_raw_mut_CropLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_CropLayer_delete(ptr) };
 	}
 }
 
@@ -3861,7 +4711,7 @@ impl CropLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::Layer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_CropLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_CropLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::Layer>::opencv_from_extern(ret) };
@@ -3879,16 +4729,34 @@ pub trait CumSumLayerTraitConst: crate::dnn::LayerTraitConst {
 
 	#[inline]
 	fn exclusive(&self) -> i32 {
-		let ret = unsafe { sys::cv_dnn_CumSumLayer_getPropExclusive_const(self.as_raw_CumSumLayer()) };
+		crate::core::validity::debug_check("CumSumLayer", self.as_raw_CumSumLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_CumSumLayer_getPropExclusive_const(self.as_raw_CumSumLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [exclusive](CumSumLayerTraitConst::exclusive), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_exclusive(&self) -> Result<i32> {
+		crate::core::validity::check("CumSumLayer", self.as_raw_CumSumLayer())?;
+		Ok(CumSumLayerTraitConst::exclusive(self))
+	}
+	
 	#[inline]
 	fn reverse(&self) -> i32 {
-		let ret = unsafe { sys::cv_dnn_CumSumLayer_getPropReverse_const(self.as_raw_CumSumLayer()) };
+		crate::core::validity::debug_check("CumSumLayer", self.as_raw_CumSumLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_CumSumLayer_getPropReverse_const(self.as_raw_CumSumLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [reverse](CumSumLayerTraitConst::reverse), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_reverse(&self) -> Result<i32> {
+		crate::core::validity::check("CumSumLayer", self.as_raw_CumSumLayer())?;
+		Ok(CumSumLayerTraitConst::reverse(self))
+	}
+	
 }
 
 pub trait CumSumLayerTrait: crate::dnn::CumSumLayerTraitConst + crate::dnn::LayerTrait {
@@ -3896,13 +4764,15 @@ pub trait CumSumLayerTrait: crate::dnn::CumSumLayerTraitConst + crate::dnn::Laye
 
 	#[inline]
 	fn set_exclusive(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_dnn_CumSumLayer_setPropExclusive_int(self.as_raw_mut_CumSumLayer(), val) };
+		crate::core::validity::debug_check("CumSumLayer", self.as_raw_mut_CumSumLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_CumSumLayer_setPropExclusive_int(self.as_raw_mut_CumSumLayer(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_reverse(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_dnn_CumSumLayer_setPropReverse_int(self.as_raw_mut_CumSumLayer(), val) };
+		crate::core::validity::debug_check("CumSumLayer", self.as_raw_mut_CumSumLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_CumSumLayer_setPropReverse_int(self.as_raw_mut_CumSumLayer(), val)) };
 		ret
 	}
 	
@@ -3917,7 +4787,10 @@ opencv_type_boxed! { CumSumLayer }
 impl Drop for CumSumLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CumSumLayer_delete(instance: *mut c_void); }
-		unsafe { cv_CumSumLayer_delete(self.as_raw_mut_CumSumLayer()) };
+		let ptr = self.as_raw_mut_CumSumLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_CumSumLayer_delete(ptr) };
 	}
 }
 
@@ -3951,7 +4824,7 @@ impl CumSumLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::CumSumLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_CumSumLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_CumSumLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::CumSumLayer>::opencv_from_extern(ret) };
@@ -3983,7 +4856,10 @@ opencv_type_boxed! { DataAugmentationLayer }
 impl Drop for DataAugmentationLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DataAugmentationLayer_delete(instance: *mut c_void); }
-		unsafe { cv_DataAugmentationLayer_delete(self.as_raw_mut_DataAugmentationLayer()) };
+		let ptr = self.as_raw_mut_DataAugmentationLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_DataAugmentationLayer_delete(ptr) };
 	}
 }
 
@@ -4017,7 +4893,7 @@ impl DataAugmentationLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::DataAugmentationLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_DataAugmentationLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_DataAugmentationLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::DataAugmentationLayer>::opencv_from_extern(ret) };
@@ -4049,7 +4925,10 @@ opencv_type_boxed! { DeconvolutionLayer }
 impl Drop for DeconvolutionLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DeconvolutionLayer_delete(instance: *mut c_void); }
-		unsafe { cv_DeconvolutionLayer_delete(self.as_raw_mut_DeconvolutionLayer()) };
+		let ptr = self.as_raw_mut_DeconvolutionLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_DeconvolutionLayer_delete(ptr) };
 	}
 }
 
@@ -4091,7 +4970,7 @@ impl DeconvolutionLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::BaseConvolutionLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_DeconvolutionLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_DeconvolutionLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::BaseConvolutionLayer>::opencv_from_extern(ret) };
@@ -4111,16 +4990,34 @@ pub trait DequantizeLayerTraitConst: crate::dnn::LayerTraitConst {
 
 	#[inline]
 	fn scale(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_DequantizeLayer_getPropScale_const(self.as_raw_DequantizeLayer()) };
+		crate::core::validity::debug_check("DequantizeLayer", self.as_raw_DequantizeLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_DequantizeLayer_getPropScale_const(self.as_raw_DequantizeLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [scale](DequantizeLayerTraitConst::scale), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_scale(&self) -> Result<f32> {
+		crate::core::validity::check("DequantizeLayer", self.as_raw_DequantizeLayer())?;
+		Ok(DequantizeLayerTraitConst::scale(self))
+	}
+	
 	#[inline]
 	fn zeropoint(&self) -> i32 {
-		let ret = unsafe { sys::cv_dnn_DequantizeLayer_getPropZeropoint_const(self.as_raw_DequantizeLayer()) };
+		crate::core::validity::debug_check("DequantizeLayer", self.as_raw_DequantizeLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_DequantizeLayer_getPropZeropoint_const(self.as_raw_DequantizeLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [zeropoint](DequantizeLayerTraitConst::zeropoint), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_zeropoint(&self) -> Result<i32> {
+		crate::core::validity::check("DequantizeLayer", self.as_raw_DequantizeLayer())?;
+		Ok(DequantizeLayerTraitConst::zeropoint(self))
+	}
+	
 }
 
 pub trait DequantizeLayerTrait: crate::dnn::DequantizeLayerTraitConst + crate::dnn::LayerTrait {
@@ -4128,13 +5025,15 @@ pub trait DequantizeLayerTrait: crate::dnn::DequantizeLayerTraitConst + crate::d
 
 	#[inline]
 	fn set_scale(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_DequantizeLayer_setPropScale_float(self.as_raw_mut_DequantizeLayer(), val) };
+		crate::core::validity::debug_check("DequantizeLayer", self.as_raw_mut_DequantizeLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_DequantizeLayer_setPropScale_float(self.as_raw_mut_DequantizeLayer(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_zeropoint(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_dnn_DequantizeLayer_setPropZeropoint_int(self.as_raw_mut_DequantizeLayer(), val) };
+		crate::core::validity::debug_check("DequantizeLayer", self.as_raw_mut_DequantizeLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_DequantizeLayer_setPropZeropoint_int(self.as_raw_mut_DequantizeLayer(), val)) };
 		ret
 	}
 	
@@ -4149,7 +5048,10 @@ opencv_type_boxed! { DequantizeLayer }
 impl Drop for DequantizeLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DequantizeLayer_delete(instance: *mut c_void); }
-		unsafe { cv_DequantizeLayer_delete(self.as_raw_mut_DequantizeLayer()) };
+		let ptr = self.as_raw_mut_DequantizeLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_DequantizeLayer_delete(ptr) };
 	}
 }
 
@@ -4183,7 +5085,7 @@ impl DequantizeLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::DequantizeLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_DequantizeLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_DequantizeLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::DequantizeLayer>::opencv_from_extern(ret) };
@@ -4217,8 +5119,9 @@ pub trait DetectionModelTrait: crate::dnn::DetectionModelTraitConst + crate::dnn
 	/// * value: The new value for nmsAcrossClasses
 	#[inline]
 	fn set_nms_across_classes(&mut self, value: bool) -> Result<crate::dnn::DetectionModel> {
+		crate::core::validity::check("DetectionModel", self.as_raw_mut_DetectionModel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_DetectionModel_setNmsAcrossClasses_bool(self.as_raw_mut_DetectionModel(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_DetectionModel_setNmsAcrossClasses_bool(self.as_raw_mut_DetectionModel(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::DetectionModel::opencv_from_extern(ret) };
@@ -4229,8 +5132,9 @@ pub trait DetectionModelTrait: crate::dnn::DetectionModelTraitConst + crate::dnn
 	/// such that when non max suppression is used during the detect() function, it will do so only per-class
 	#[inline]
 	fn get_nms_across_classes(&mut self) -> Result<bool> {
+		crate::core::validity::check("DetectionModel", self.as_raw_mut_DetectionModel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_DetectionModel_getNmsAcrossClasses(self.as_raw_mut_DetectionModel(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_DetectionModel_getNmsAcrossClasses(self.as_raw_mut_DetectionModel(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4239,9 +5143,9 @@ pub trait DetectionModelTrait: crate::dnn::DetectionModelTraitConst + crate::dnn
 	/// Given the @p input frame, create input blob, run net and return result detections.
 	/// ## Parameters
 	/// * frame: The input image.
-	/// * classIds:[out] Class indexes in result detection.
-	/// * confidences:[out] A set of corresponding confidences.
-	/// * boxes:[out] A set of bounding boxes.
+	/// * classIds:\[out\] Class indexes in result detection.
+	/// * confidences:\[out\] A set of corresponding confidences.
+	/// * boxes:\[out\] A set of bounding boxes.
 	/// * confThreshold: A threshold used to filter boxes by confidences.
 	/// * nmsThreshold: A threshold used in non maximum suppression.
 	/// 
@@ -4250,9 +5154,10 @@ pub trait DetectionModelTrait: crate::dnn::DetectionModelTraitConst + crate::dnn
 	/// * nms_threshold: 0.0f
 	#[inline]
 	fn detect(&mut self, frame: &dyn core::ToInputArray, class_ids: &mut core::Vector<i32>, confidences: &mut core::Vector<f32>, boxes: &mut core::Vector<core::Rect>, conf_threshold: f32, nms_threshold: f32) -> Result<()> {
+		crate::core::validity::check("DetectionModel", self.as_raw_mut_DetectionModel())?;
 		input_array_arg!(frame);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_DetectionModel_detect_const__InputArrayR_vector_int_R_vector_float_R_vector_Rect_R_float_float(self.as_raw_mut_DetectionModel(), frame.as_raw__InputArray(), class_ids.as_raw_mut_VectorOfi32(), confidences.as_raw_mut_VectorOff32(), boxes.as_raw_mut_VectorOfRect(), conf_threshold, nms_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_DetectionModel_detect_const__InputArrayR_vector_int_R_vector_float_R_vector_Rect_R_float_float(self.as_raw_mut_DetectionModel(), frame.as_raw__InputArray(), class_ids.as_raw_mut_VectorOfi32(), confidences.as_raw_mut_VectorOff32(), boxes.as_raw_mut_VectorOfRect(), conf_threshold, nms_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4260,6 +5165,46 @@ pub trait DetectionModelTrait: crate::dnn::DetectionModelTraitConst + crate::dnn
 	
 }
 
+/// Builder for `DetectionModelTrait::detect()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct DetectionModel_DetectBuilder<'a, T: ?Sized + DetectionModelTrait> {
+	instance: &'a mut T,
+	frame: &'a dyn core::ToInputArray,
+	class_ids: &'a mut core::Vector<i32>,
+	confidences: &'a mut core::Vector<f32>,
+	boxes: &'a mut core::Vector<core::Rect>,
+	conf_threshold: f32,
+	nms_threshold: f32,
+}
+
+impl<'a, T: ?Sized + DetectionModelTrait> DetectionModel_DetectBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, frame: &'a dyn core::ToInputArray, class_ids: &'a mut core::Vector<i32>, confidences: &'a mut core::Vector<f32>, boxes: &'a mut core::Vector<core::Rect>) -> Self {
+		Self { instance, frame, class_ids, confidences, boxes, conf_threshold: 0.5, nms_threshold: 0.0 }
+	}
+	
+	/// C++ default: `0.5f`
+	#[inline]
+	pub fn with_conf_threshold(mut self, conf_threshold: f32) -> Self {
+		self.conf_threshold = conf_threshold;
+		self
+	}
+	
+	/// C++ default: `0.0f`
+	#[inline]
+	pub fn with_nms_threshold(mut self, nms_threshold: f32) -> Self {
+		self.nms_threshold = nms_threshold;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		DetectionModelTrait::detect(self.instance, self.frame, self.class_ids, self.confidences, self.boxes, self.conf_threshold, self.nms_threshold)
+	}
+}
+
 /// This class represents high-level API for object detection networks.
 /// 
 /// DetectionModel allows to set params for preprocessing input image.
@@ -4275,7 +5220,10 @@ opencv_type_boxed! { DetectionModel }
 impl Drop for DetectionModel {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DetectionModel_delete(instance: *mut c_void); }
-		unsafe { cv_DetectionModel_delete(self.as_raw_mut_DetectionModel()) };
+		let ptr = self.as_raw_mut_DetectionModel();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_DetectionModel_delete(ptr) };
 	}
 }
 
@@ -4311,7 +5259,7 @@ impl DetectionModel {
 		extern_container_arg!(model);
 		extern_container_arg!(config);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_DetectionModel_DetectionModel_const_StringR_const_StringR(model.opencv_as_extern(), config.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_DetectionModel_DetectionModel_const_StringR_const_StringR(model.opencv_as_extern(), config.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::DetectionModel::opencv_from_extern(ret) };
@@ -4324,7 +5272,7 @@ impl DetectionModel {
 	#[inline]
 	pub fn new_1(network: &crate::dnn::Net) -> Result<crate::dnn::DetectionModel> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_DetectionModel_DetectionModel_const_NetR(network.as_raw_Net(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_DetectionModel_DetectionModel_const_NetR(network.as_raw_Net(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::DetectionModel::opencv_from_extern(ret) };
@@ -4334,7 +5282,7 @@ impl DetectionModel {
 	#[inline]
 	pub fn default() -> Result<crate::dnn::DetectionModel> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_DetectionModel_DetectionModel(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_DetectionModel_DetectionModel(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::DetectionModel::opencv_from_extern(ret) };
@@ -4348,8 +5296,8 @@ boxed_cast_base! { DetectionModel, crate::dnn::Model, cv_DetectionModel_to_Model
 /// Detection output layer.
 /// 
 /// The layer size is: @f$ (1 \times 1 \times N \times 7) @f$
-///    where N is [keep_top_k] parameter multiplied by batch size. Each row is:
-///    [image_id, label, confidence, xmin, ymin, xmax, ymax]
+///    where N is \[keep_top_k\] parameter multiplied by batch size. Each row is:
+///    \[image_id, label, confidence, xmin, ymin, xmax, ymax\]
 ///    where image_id is the index of image input in the batch.
 pub trait DetectionOutputLayerTraitConst: crate::dnn::LayerTraitConst {
 	fn as_raw_DetectionOutputLayer(&self) -> *const c_void;
@@ -4364,8 +5312,8 @@ pub trait DetectionOutputLayerTrait: crate::dnn::DetectionOutputLayerTraitConst
 /// Detection output layer.
 /// 
 /// The layer size is: @f$ (1 \times 1 \times N \times 7) @f$
-///    where N is [keep_top_k] parameter multiplied by batch size. Each row is:
-///    [image_id, label, confidence, xmin, ymin, xmax, ymax]
+///    where N is \[keep_top_k\] parameter multiplied by batch size. Each row is:
+///    \[image_id, label, confidence, xmin, ymin, xmax, ymax\]
 ///    where image_id is the index of image input in the batch.
 pub struct DetectionOutputLayer {
 	ptr: *mut c_void
@@ -4376,7 +5324,10 @@ opencv_type_boxed! { DetectionOutputLayer }
 impl Drop for DetectionOutputLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DetectionOutputLayer_delete(instance: *mut c_void); }
-		unsafe { cv_DetectionOutputLayer_delete(self.as_raw_mut_DetectionOutputLayer()) };
+		let ptr = self.as_raw_mut_DetectionOutputLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_DetectionOutputLayer_delete(ptr) };
 	}
 }
 
@@ -4410,7 +5361,7 @@ impl DetectionOutputLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::DetectionOutputLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_DetectionOutputLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_DetectionOutputLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::DetectionOutputLayer>::opencv_from_extern(ret) };
@@ -4430,9 +5381,10 @@ pub trait DictTraitConst {
 	/// Checks a presence of the @p key in the dictionary.
 	#[inline]
 	fn has(&self, key: &str) -> Result<bool> {
+		crate::core::validity::check("Dict", self.as_raw_Dict())?;
 		extern_container_arg!(key);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Dict_has_const_const_StringR(self.as_raw_Dict(), key.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Dict_has_const_const_StringR(self.as_raw_Dict(), key.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4443,9 +5395,10 @@ pub trait DictTraitConst {
 	/// ## Overloaded parameters
 	#[inline]
 	unsafe fn ptr(&self, key: &str) -> Result<crate::dnn::DictValue> {
+		crate::core::validity::check("Dict", self.as_raw_Dict())?;
 		extern_container_arg!(key);
 		return_send!(via ocvrs_return);
-		{ sys::cv_dnn_Dict_ptr_const_const_StringR(self.as_raw_Dict(), key.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		{ ffi_call!(cv_dnn_Dict_ptr_const_const_StringR(self.as_raw_Dict(), key.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = { crate::dnn::DictValue::opencv_from_extern(ret) };
@@ -4455,9 +5408,10 @@ pub trait DictTraitConst {
 	/// If the @p key in the dictionary then returns its value, else an error will be generated.
 	#[inline]
 	fn get(&self, key: &str) -> Result<crate::dnn::DictValue> {
+		crate::core::validity::check("Dict", self.as_raw_Dict())?;
 		extern_container_arg!(key);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Dict_get_const_const_StringR(self.as_raw_Dict(), key.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Dict_get_const_const_StringR(self.as_raw_Dict(), key.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::DictValue::opencv_from_extern(ret) };
@@ -4472,9 +5426,10 @@ pub trait DictTrait: crate::dnn::DictTraitConst {
 	/// If the @p key in the dictionary then returns pointer to its value, else returns NULL.
 	#[inline]
 	unsafe fn ptr_mut(&mut self, key: &str) -> Result<crate::dnn::DictValue> {
+		crate::core::validity::check("Dict", self.as_raw_mut_Dict())?;
 		extern_container_arg!(key);
 		return_send!(via ocvrs_return);
-		{ sys::cv_dnn_Dict_ptr_const_StringR(self.as_raw_mut_Dict(), key.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		{ ffi_call!(cv_dnn_Dict_ptr_const_StringR(self.as_raw_mut_Dict(), key.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = { crate::dnn::DictValue::opencv_from_extern(ret) };
@@ -4484,10 +5439,11 @@ pub trait DictTrait: crate::dnn::DictTraitConst {
 	/// Sets new @p value for the @p key, or adds new key-value pair into the dictionary.
 	#[inline]
 	fn set_str(&mut self, key: &str, value: &str) -> Result<String> {
+		crate::core::validity::check("Dict", self.as_raw_mut_Dict())?;
 		extern_container_arg!(key);
 		extern_container_arg!(value);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Dict_set_cv_String_const_StringR_const_StringR(self.as_raw_mut_Dict(), key.opencv_as_extern(), value.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Dict_set_cv_String_const_StringR_const_StringR(self.as_raw_mut_Dict(), key.opencv_as_extern(), value.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -4497,9 +5453,10 @@ pub trait DictTrait: crate::dnn::DictTraitConst {
 	/// Sets new @p value for the @p key, or adds new key-value pair into the dictionary.
 	#[inline]
 	fn set(&mut self, key: &str, value: &crate::dnn::DictValue) -> Result<crate::dnn::DictValue> {
+		crate::core::validity::check("Dict", self.as_raw_mut_Dict())?;
 		extern_container_arg!(key);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Dict_set_cv_dnn_DictValue_const_StringR_const_DictValueR(self.as_raw_mut_Dict(), key.opencv_as_extern(), value.as_raw_DictValue(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Dict_set_cv_dnn_DictValue_const_StringR_const_DictValueR(self.as_raw_mut_Dict(), key.opencv_as_extern(), value.as_raw_DictValue(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::DictValue::opencv_from_extern(ret) };
@@ -4509,9 +5466,10 @@ pub trait DictTrait: crate::dnn::DictTraitConst {
 	/// Sets new @p value for the @p key, or adds new key-value pair into the dictionary.
 	#[inline]
 	fn set_f64(&mut self, key: &str, value: &f64) -> Result<f64> {
+		crate::core::validity::check("Dict", self.as_raw_mut_Dict())?;
 		extern_container_arg!(key);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Dict_set_double_const_StringR_const_doubleR(self.as_raw_mut_Dict(), key.opencv_as_extern(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Dict_set_double_const_StringR_const_doubleR(self.as_raw_mut_Dict(), key.opencv_as_extern(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4520,9 +5478,10 @@ pub trait DictTrait: crate::dnn::DictTraitConst {
 	/// Sets new @p value for the @p key, or adds new key-value pair into the dictionary.
 	#[inline]
 	fn set_i64(&mut self, key: &str, value: &i64) -> Result<i64> {
+		crate::core::validity::check("Dict", self.as_raw_mut_Dict())?;
 		extern_container_arg!(key);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Dict_set_int64_t_const_StringR_const_int64_tR(self.as_raw_mut_Dict(), key.opencv_as_extern(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Dict_set_int64_t_const_StringR_const_int64_tR(self.as_raw_mut_Dict(), key.opencv_as_extern(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4531,9 +5490,10 @@ pub trait DictTrait: crate::dnn::DictTraitConst {
 	/// Erase @p key from the dictionary.
 	#[inline]
 	fn erase(&mut self, key: &str) -> Result<()> {
+		crate::core::validity::check("Dict", self.as_raw_mut_Dict())?;
 		extern_container_arg!(key);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Dict_erase_const_StringR(self.as_raw_mut_Dict(), key.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Dict_erase_const_StringR(self.as_raw_mut_Dict(), key.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4551,7 +5511,10 @@ opencv_type_boxed! { Dict }
 impl Drop for Dict {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Dict_delete(instance: *mut c_void); }
-		unsafe { cv_Dict_delete(self.as_raw_mut_Dict()) };
+		let ptr = self.as_raw_mut_Dict();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Dict_delete(ptr) };
 	}
 }
 
@@ -4577,8 +5540,9 @@ pub trait DictValueTraitConst {
 	/// * idx: -1
 	#[inline]
 	fn get_str(&self, idx: i32) -> Result<String> {
+		crate::core::validity::check("DictValue", self.as_raw_DictValue())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_DictValue_get_cv_String_const_int(self.as_raw_DictValue(), idx, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_DictValue_get_cv_String_const_int(self.as_raw_DictValue(), idx, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -4589,8 +5553,9 @@ pub trait DictValueTraitConst {
 	/// * idx: -1
 	#[inline]
 	fn get_f64(&self, idx: i32) -> Result<f64> {
+		crate::core::validity::check("DictValue", self.as_raw_DictValue())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_DictValue_get_double_const_int(self.as_raw_DictValue(), idx, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_DictValue_get_double_const_int(self.as_raw_DictValue(), idx, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4600,8 +5565,9 @@ pub trait DictValueTraitConst {
 	/// * idx: -1
 	#[inline]
 	fn get_i32(&self, idx: i32) -> Result<i32> {
+		crate::core::validity::check("DictValue", self.as_raw_DictValue())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_DictValue_get_int_const_int(self.as_raw_DictValue(), idx, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_DictValue_get_int_const_int(self.as_raw_DictValue(), idx, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4611,8 +5577,9 @@ pub trait DictValueTraitConst {
 	/// * idx: -1
 	#[inline]
 	fn get_i64(&self, idx: i32) -> Result<i64> {
+		crate::core::validity::check("DictValue", self.as_raw_DictValue())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_DictValue_get_int64_t_const_int(self.as_raw_DictValue(), idx, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_DictValue_get_int64_t_const_int(self.as_raw_DictValue(), idx, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4620,8 +5587,9 @@ pub trait DictValueTraitConst {
 	
 	#[inline]
 	fn size(&self) -> Result<i32> {
+		crate::core::validity::check("DictValue", self.as_raw_DictValue())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_DictValue_size_const(self.as_raw_DictValue(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_DictValue_size_const(self.as_raw_DictValue(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4629,8 +5597,9 @@ pub trait DictValueTraitConst {
 	
 	#[inline]
 	fn is_int(&self) -> Result<bool> {
+		crate::core::validity::check("DictValue", self.as_raw_DictValue())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_DictValue_isInt_const(self.as_raw_DictValue(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_DictValue_isInt_const(self.as_raw_DictValue(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4638,8 +5607,9 @@ pub trait DictValueTraitConst {
 	
 	#[inline]
 	fn is_string(&self) -> Result<bool> {
+		crate::core::validity::check("DictValue", self.as_raw_DictValue())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_DictValue_isString_const(self.as_raw_DictValue(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_DictValue_isString_const(self.as_raw_DictValue(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4647,8 +5617,9 @@ pub trait DictValueTraitConst {
 	
 	#[inline]
 	fn is_real(&self) -> Result<bool> {
+		crate::core::validity::check("DictValue", self.as_raw_DictValue())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_DictValue_isReal_const(self.as_raw_DictValue(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_DictValue_isReal_const(self.as_raw_DictValue(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4658,8 +5629,9 @@ pub trait DictValueTraitConst {
 	/// * idx: -1
 	#[inline]
 	fn get_int_value(&self, idx: i32) -> Result<i32> {
+		crate::core::validity::check("DictValue", self.as_raw_DictValue())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_DictValue_getIntValue_const_int(self.as_raw_DictValue(), idx, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_DictValue_getIntValue_const_int(self.as_raw_DictValue(), idx, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4669,8 +5641,9 @@ pub trait DictValueTraitConst {
 	/// * idx: -1
 	#[inline]
 	fn get_real_value(&self, idx: i32) -> Result<f64> {
+		crate::core::validity::check("DictValue", self.as_raw_DictValue())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_DictValue_getRealValue_const_int(self.as_raw_DictValue(), idx, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_DictValue_getRealValue_const_int(self.as_raw_DictValue(), idx, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4680,8 +5653,9 @@ pub trait DictValueTraitConst {
 	/// * idx: -1
 	#[inline]
 	fn get_string_value(&self, idx: i32) -> Result<String> {
+		crate::core::validity::check("DictValue", self.as_raw_DictValue())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_DictValue_getStringValue_const_int(self.as_raw_DictValue(), idx, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_DictValue_getStringValue_const_int(self.as_raw_DictValue(), idx, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -4706,7 +5680,10 @@ opencv_type_boxed! { DictValue }
 impl Drop for DictValue {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DictValue_delete(instance: *mut c_void); }
-		unsafe { cv_DictValue_delete(self.as_raw_mut_DictValue()) };
+		let ptr = self.as_raw_mut_DictValue();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_DictValue_delete(ptr) };
 	}
 }
 
@@ -4724,7 +5701,7 @@ impl DictValue {
 	#[inline]
 	pub fn copy(r: &crate::dnn::DictValue) -> Result<crate::dnn::DictValue> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_DictValue_DictValue_const_DictValueR(r.as_raw_DictValue(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_DictValue_DictValue_const_DictValueR(r.as_raw_DictValue(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::DictValue::opencv_from_extern(ret) };
@@ -4734,7 +5711,7 @@ impl DictValue {
 	#[inline]
 	pub fn from_bool(i: bool) -> Result<crate::dnn::DictValue> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_DictValue_DictValue_bool(i, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_DictValue_DictValue_bool(i, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::DictValue::opencv_from_extern(ret) };
@@ -4746,7 +5723,7 @@ impl DictValue {
 	#[inline]
 	pub fn from_i64(i: i64) -> Result<crate::dnn::DictValue> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_DictValue_DictValue_int64_t(i, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_DictValue_DictValue_int64_t(i, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::DictValue::opencv_from_extern(ret) };
@@ -4756,7 +5733,7 @@ impl DictValue {
 	#[inline]
 	pub fn from_i32(i: i32) -> Result<crate::dnn::DictValue> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_DictValue_DictValue_int(i, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_DictValue_DictValue_int(i, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::DictValue::opencv_from_extern(ret) };
@@ -4766,7 +5743,7 @@ impl DictValue {
 	#[inline]
 	pub fn from_u32(p: u32) -> Result<crate::dnn::DictValue> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_DictValue_DictValue_unsigned_int(p, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_DictValue_DictValue_unsigned_int(p, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::DictValue::opencv_from_extern(ret) };
@@ -4776,7 +5753,7 @@ impl DictValue {
 	#[inline]
 	pub fn from_f64(p: f64) -> Result<crate::dnn::DictValue> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_DictValue_DictValue_double(p, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_DictValue_DictValue_double(p, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::DictValue::opencv_from_extern(ret) };
@@ -4787,7 +5764,7 @@ impl DictValue {
 	pub fn from_str(s: &str) -> Result<crate::dnn::DictValue> {
 		extern_container_arg!(s);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_DictValue_DictValue_const_charX(s.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_DictValue_DictValue_const_charX(s.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::DictValue::opencv_from_extern(ret) };
@@ -4801,10 +5778,19 @@ pub trait ELULayerTraitConst: crate::dnn::ActivationLayerTraitConst {
 
 	#[inline]
 	fn alpha(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_ELULayer_getPropAlpha_const(self.as_raw_ELULayer()) };
+		crate::core::validity::debug_check("ELULayer", self.as_raw_ELULayer());
+		let ret = unsafe { ffi_call!(cv_dnn_ELULayer_getPropAlpha_const(self.as_raw_ELULayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [alpha](ELULayerTraitConst::alpha), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_alpha(&self) -> Result<f32> {
+		crate::core::validity::check("ELULayer", self.as_raw_ELULayer())?;
+		Ok(ELULayerTraitConst::alpha(self))
+	}
+	
 }
 
 pub trait ELULayerTrait: crate::dnn::ActivationLayerTrait + crate::dnn::ELULayerTraitConst {
@@ -4812,7 +5798,8 @@ pub trait ELULayerTrait: crate::dnn::ActivationLayerTrait + crate::dnn::ELULayer
 
 	#[inline]
 	fn set_alpha(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_ELULayer_setPropAlpha_float(self.as_raw_mut_ELULayer(), val) };
+		crate::core::validity::debug_check("ELULayer", self.as_raw_mut_ELULayer());
+		let ret = unsafe { ffi_call!(cv_dnn_ELULayer_setPropAlpha_float(self.as_raw_mut_ELULayer(), val)) };
 		ret
 	}
 	
@@ -4827,7 +5814,10 @@ opencv_type_boxed! { ELULayer }
 impl Drop for ELULayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ELULayer_delete(instance: *mut c_void); }
-		unsafe { cv_ELULayer_delete(self.as_raw_mut_ELULayer()) };
+		let ptr = self.as_raw_mut_ELULayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ELULayer_delete(ptr) };
 	}
 }
 
@@ -4869,7 +5859,7 @@ impl ELULayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ELULayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_ELULayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_ELULayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::ELULayer>::opencv_from_extern(ret) };
@@ -4915,7 +5905,10 @@ opencv_type_boxed! { EltwiseLayer }
 impl Drop for EltwiseLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_EltwiseLayer_delete(instance: *mut c_void); }
-		unsafe { cv_EltwiseLayer_delete(self.as_raw_mut_EltwiseLayer()) };
+		let ptr = self.as_raw_mut_EltwiseLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_EltwiseLayer_delete(ptr) };
 	}
 }
 
@@ -4949,7 +5942,7 @@ impl EltwiseLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::EltwiseLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_EltwiseLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_EltwiseLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::EltwiseLayer>::opencv_from_extern(ret) };
@@ -4981,7 +5974,10 @@ opencv_type_boxed! { EltwiseLayerInt8 }
 impl Drop for EltwiseLayerInt8 {
 	fn drop(&mut self) {
 		extern "C" { fn cv_EltwiseLayerInt8_delete(instance: *mut c_void); }
-		unsafe { cv_EltwiseLayerInt8_delete(self.as_raw_mut_EltwiseLayerInt8()) };
+		let ptr = self.as_raw_mut_EltwiseLayerInt8();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_EltwiseLayerInt8_delete(ptr) };
 	}
 }
 
@@ -5015,7 +6011,7 @@ impl EltwiseLayerInt8 {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::EltwiseLayerInt8>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_EltwiseLayerInt8_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_EltwiseLayerInt8_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::EltwiseLayerInt8>::opencv_from_extern(ret) };
@@ -5047,7 +6043,10 @@ opencv_type_boxed! { ErfLayer }
 impl Drop for ErfLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ErfLayer_delete(instance: *mut c_void); }
-		unsafe { cv_ErfLayer_delete(self.as_raw_mut_ErfLayer()) };
+		let ptr = self.as_raw_mut_ErfLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ErfLayer_delete(ptr) };
 	}
 }
 
@@ -5089,7 +6088,7 @@ impl ErfLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ErfLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_ErfLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_ErfLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::ErfLayer>::opencv_from_extern(ret) };
@@ -5109,22 +6108,49 @@ pub trait ExpLayerTraitConst: crate::dnn::ActivationLayerTraitConst {
 
 	#[inline]
 	fn base(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_ExpLayer_getPropBase_const(self.as_raw_ExpLayer()) };
+		crate::core::validity::debug_check("ExpLayer", self.as_raw_ExpLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_ExpLayer_getPropBase_const(self.as_raw_ExpLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [base](ExpLayerTraitConst::base), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_base(&self) -> Result<f32> {
+		crate::core::validity::check("ExpLayer", self.as_raw_ExpLayer())?;
+		Ok(ExpLayerTraitConst::base(self))
+	}
+	
 	#[inline]
 	fn scale(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_ExpLayer_getPropScale_const(self.as_raw_ExpLayer()) };
+		crate::core::validity::debug_check("ExpLayer", self.as_raw_ExpLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_ExpLayer_getPropScale_const(self.as_raw_ExpLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [scale](ExpLayerTraitConst::scale), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_scale(&self) -> Result<f32> {
+		crate::core::validity::check("ExpLayer", self.as_raw_ExpLayer())?;
+		Ok(ExpLayerTraitConst::scale(self))
+	}
+	
 	#[inline]
 	fn shift(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_ExpLayer_getPropShift_const(self.as_raw_ExpLayer()) };
+		crate::core::validity::debug_check("ExpLayer", self.as_raw_ExpLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_ExpLayer_getPropShift_const(self.as_raw_ExpLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [shift](ExpLayerTraitConst::shift), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_shift(&self) -> Result<f32> {
+		crate::core::validity::check("ExpLayer", self.as_raw_ExpLayer())?;
+		Ok(ExpLayerTraitConst::shift(self))
+	}
+	
 }
 
 pub trait ExpLayerTrait: crate::dnn::ActivationLayerTrait + crate::dnn::ExpLayerTraitConst {
@@ -5132,19 +6158,22 @@ pub trait ExpLayerTrait: crate::dnn::ActivationLayerTrait + crate::dnn::ExpLayer
 
 	#[inline]
 	fn set_base(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_ExpLayer_setPropBase_float(self.as_raw_mut_ExpLayer(), val) };
+		crate::core::validity::debug_check("ExpLayer", self.as_raw_mut_ExpLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_ExpLayer_setPropBase_float(self.as_raw_mut_ExpLayer(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_scale(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_ExpLayer_setPropScale_float(self.as_raw_mut_ExpLayer(), val) };
+		crate::core::validity::debug_check("ExpLayer", self.as_raw_mut_ExpLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_ExpLayer_setPropScale_float(self.as_raw_mut_ExpLayer(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_shift(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_ExpLayer_setPropShift_float(self.as_raw_mut_ExpLayer(), val) };
+		crate::core::validity::debug_check("ExpLayer", self.as_raw_mut_ExpLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_ExpLayer_setPropShift_float(self.as_raw_mut_ExpLayer(), val)) };
 		ret
 	}
 	
@@ -5159,7 +6188,10 @@ opencv_type_boxed! { ExpLayer }
 impl Drop for ExpLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ExpLayer_delete(instance: *mut c_void); }
-		unsafe { cv_ExpLayer_delete(self.as_raw_mut_ExpLayer()) };
+		let ptr = self.as_raw_mut_ExpLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ExpLayer_delete(ptr) };
 	}
 }
 
@@ -5201,7 +6233,7 @@ impl ExpLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ExpLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_ExpLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_ExpLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::ExpLayer>::opencv_from_extern(ret) };
@@ -5235,7 +6267,10 @@ opencv_type_boxed! { FlattenLayer }
 impl Drop for FlattenLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FlattenLayer_delete(instance: *mut c_void); }
-		unsafe { cv_FlattenLayer_delete(self.as_raw_mut_FlattenLayer()) };
+		let ptr = self.as_raw_mut_FlattenLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_FlattenLayer_delete(ptr) };
 	}
 }
 
@@ -5269,7 +6304,7 @@ impl FlattenLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::FlattenLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_FlattenLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_FlattenLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::FlattenLayer>::opencv_from_extern(ret) };
@@ -5301,7 +6336,10 @@ opencv_type_boxed! { FloorLayer }
 impl Drop for FloorLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FloorLayer_delete(instance: *mut c_void); }
-		unsafe { cv_FloorLayer_delete(self.as_raw_mut_FloorLayer()) };
+		let ptr = self.as_raw_mut_FloorLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_FloorLayer_delete(ptr) };
 	}
 }
 
@@ -5343,7 +6381,7 @@ impl FloorLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::FloorLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_FloorLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_FloorLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::FloorLayer>::opencv_from_extern(ret) };
@@ -5377,7 +6415,10 @@ opencv_type_boxed! { FlowWarpLayer }
 impl Drop for FlowWarpLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FlowWarpLayer_delete(instance: *mut c_void); }
-		unsafe { cv_FlowWarpLayer_delete(self.as_raw_mut_FlowWarpLayer()) };
+		let ptr = self.as_raw_mut_FlowWarpLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_FlowWarpLayer_delete(ptr) };
 	}
 }
 
@@ -5411,7 +6452,7 @@ impl FlowWarpLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::FlowWarpLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_FlowWarpLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_FlowWarpLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::FlowWarpLayer>::opencv_from_extern(ret) };
@@ -5428,9 +6469,9 @@ boxed_cast_base! { FlowWarpLayer, crate::dnn::Layer, cv_FlowWarpLayer_to_Layer }
 /// 
 /// Accepts input sequence and computes the final hidden state for each element in the batch.
 /// 
-/// - input[0] containing the features of the input sequence.
-/// input[0] should have shape [`T`, `N`, `data_dims`] where `T` is sequence length, `N` is batch size, `data_dims` is input size
-/// - output would have shape [`T`, `N`, `D` * `hidden_size`] where `D = 2` if layer is bidirectional otherwise `D = 1`
+/// - input\[0\] containing the features of the input sequence.
+/// input\[0\] should have shape \[`T`, `N`, `data_dims`\] where `T` is sequence length, `N` is batch size, `data_dims` is input size
+/// - output would have shape \[`T`, `N`, `D` * `hidden_size`\] where `D = 2` if layer is bidirectional otherwise `D = 1`
 /// 
 /// Depends on the following attributes:
 /// - hidden_size - Number of neurons in the hidden layer
@@ -5464,9 +6505,9 @@ pub trait GRULayerTrait: crate::dnn::GRULayerTraitConst + crate::dnn::LayerTrait
 /// 
 /// Accepts input sequence and computes the final hidden state for each element in the batch.
 /// 
-/// - input[0] containing the features of the input sequence.
-/// input[0] should have shape [`T`, `N`, `data_dims`] where `T` is sequence length, `N` is batch size, `data_dims` is input size
-/// - output would have shape [`T`, `N`, `D` * `hidden_size`] where `D = 2` if layer is bidirectional otherwise `D = 1`
+/// - input\[0\] containing the features of the input sequence.
+/// input\[0\] should have shape \[`T`, `N`, `data_dims`\] where `T` is sequence length, `N` is batch size, `data_dims` is input size
+/// - output would have shape \[`T`, `N`, `D` * `hidden_size`\] where `D = 2` if layer is bidirectional otherwise `D = 1`
 /// 
 /// Depends on the following attributes:
 /// - hidden_size - Number of neurons in the hidden layer
@@ -5495,7 +6536,10 @@ opencv_type_boxed! { GRULayer }
 impl Drop for GRULayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_GRULayer_delete(instance: *mut c_void); }
-		unsafe { cv_GRULayer_delete(self.as_raw_mut_GRULayer()) };
+		let ptr = self.as_raw_mut_GRULayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_GRULayer_delete(ptr) };
 	}
 }
 
@@ -5530,7 +6574,7 @@ impl GRULayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::GRULayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_GRULayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_GRULayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::GRULayer>::opencv_from_extern(ret) };
@@ -5548,16 +6592,34 @@ pub trait HardSigmoidLayerTraitConst: crate::dnn::ActivationLayerTraitConst {
 
 	#[inline]
 	fn alpha(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_HardSigmoidLayer_getPropAlpha_const(self.as_raw_HardSigmoidLayer()) };
+		crate::core::validity::debug_check("HardSigmoidLayer", self.as_raw_HardSigmoidLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_HardSigmoidLayer_getPropAlpha_const(self.as_raw_HardSigmoidLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [alpha](HardSigmoidLayerTraitConst::alpha), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_alpha(&self) -> Result<f32> {
+		crate::core::validity::check("HardSigmoidLayer", self.as_raw_HardSigmoidLayer())?;
+		Ok(HardSigmoidLayerTraitConst::alpha(self))
+	}
+	
 	#[inline]
 	fn beta(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_HardSigmoidLayer_getPropBeta_const(self.as_raw_HardSigmoidLayer()) };
+		crate::core::validity::debug_check("HardSigmoidLayer", self.as_raw_HardSigmoidLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_HardSigmoidLayer_getPropBeta_const(self.as_raw_HardSigmoidLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [beta](HardSigmoidLayerTraitConst::beta), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_beta(&self) -> Result<f32> {
+		crate::core::validity::check("HardSigmoidLayer", self.as_raw_HardSigmoidLayer())?;
+		Ok(HardSigmoidLayerTraitConst::beta(self))
+	}
+	
 }
 
 pub trait HardSigmoidLayerTrait: crate::dnn::ActivationLayerTrait + crate::dnn::HardSigmoidLayerTraitConst {
@@ -5565,13 +6627,15 @@ pub trait HardSigmoidLayerTrait: crate::dnn::ActivationLayerTrait + crate::dnn::
 
 	#[inline]
 	fn set_alpha(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_HardSigmoidLayer_setPropAlpha_float(self.as_raw_mut_HardSigmoidLayer(), val) };
+		crate::core::validity::debug_check("HardSigmoidLayer", self.as_raw_mut_HardSigmoidLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_HardSigmoidLayer_setPropAlpha_float(self.as_raw_mut_HardSigmoidLayer(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_beta(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_HardSigmoidLayer_setPropBeta_float(self.as_raw_mut_HardSigmoidLayer(), val) };
+		crate::core::validity::debug_check("HardSigmoidLayer", self.as_raw_mut_HardSigmoidLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_HardSigmoidLayer_setPropBeta_float(self.as_raw_mut_HardSigmoidLayer(), val)) };
 		ret
 	}
 	
@@ -5586,7 +6650,10 @@ opencv_type_boxed! { HardSigmoidLayer }
 impl Drop for HardSigmoidLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_HardSigmoidLayer_delete(instance: *mut c_void); }
-		unsafe { cv_HardSigmoidLayer_delete(self.as_raw_mut_HardSigmoidLayer()) };
+		let ptr = self.as_raw_mut_HardSigmoidLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_HardSigmoidLayer_delete(ptr) };
 	}
 }
 
@@ -5628,7 +6695,7 @@ impl HardSigmoidLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::HardSigmoidLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_HardSigmoidLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_HardSigmoidLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::HardSigmoidLayer>::opencv_from_extern(ret) };
@@ -5662,7 +6729,10 @@ opencv_type_boxed! { HardSwishLayer }
 impl Drop for HardSwishLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_HardSwishLayer_delete(instance: *mut c_void); }
-		unsafe { cv_HardSwishLayer_delete(self.as_raw_mut_HardSwishLayer()) };
+		let ptr = self.as_raw_mut_HardSwishLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_HardSwishLayer_delete(ptr) };
 	}
 }
 
@@ -5704,7 +6774,7 @@ impl HardSwishLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::HardSwishLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_HardSwishLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_HardSwishLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::HardSwishLayer>::opencv_from_extern(ret) };
@@ -5724,10 +6794,19 @@ pub trait InnerProductLayerTraitConst: crate::dnn::LayerTraitConst {
 
 	#[inline]
 	fn axis(&self) -> i32 {
-		let ret = unsafe { sys::cv_dnn_InnerProductLayer_getPropAxis_const(self.as_raw_InnerProductLayer()) };
+		crate::core::validity::debug_check("InnerProductLayer", self.as_raw_InnerProductLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_InnerProductLayer_getPropAxis_const(self.as_raw_InnerProductLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [axis](InnerProductLayerTraitConst::axis), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_axis(&self) -> Result<i32> {
+		crate::core::validity::check("InnerProductLayer", self.as_raw_InnerProductLayer())?;
+		Ok(InnerProductLayerTraitConst::axis(self))
+	}
+	
 }
 
 pub trait InnerProductLayerTrait: crate::dnn::InnerProductLayerTraitConst + crate::dnn::LayerTrait {
@@ -5735,7 +6814,8 @@ pub trait InnerProductLayerTrait: crate::dnn::InnerProductLayerTraitConst + crat
 
 	#[inline]
 	fn set_axis(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_dnn_InnerProductLayer_setPropAxis_int(self.as_raw_mut_InnerProductLayer(), val) };
+		crate::core::validity::debug_check("InnerProductLayer", self.as_raw_mut_InnerProductLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_InnerProductLayer_setPropAxis_int(self.as_raw_mut_InnerProductLayer(), val)) };
 		ret
 	}
 	
@@ -5750,7 +6830,10 @@ opencv_type_boxed! { InnerProductLayer }
 impl Drop for InnerProductLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_InnerProductLayer_delete(instance: *mut c_void); }
-		unsafe { cv_InnerProductLayer_delete(self.as_raw_mut_InnerProductLayer()) };
+		let ptr = self.as_raw_mut_InnerProductLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_InnerProductLayer_delete(ptr) };
 	}
 }
 
@@ -5784,7 +6867,7 @@ impl InnerProductLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::InnerProductLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_InnerProductLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_InnerProductLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::InnerProductLayer>::opencv_from_extern(ret) };
@@ -5802,10 +6885,19 @@ pub trait InnerProductLayerInt8TraitConst: crate::dnn::InnerProductLayerTraitCon
 
 	#[inline]
 	fn output_zp(&self) -> i32 {
-		let ret = unsafe { sys::cv_dnn_InnerProductLayerInt8_getPropOutput_zp_const(self.as_raw_InnerProductLayerInt8()) };
+		crate::core::validity::debug_check("InnerProductLayerInt8", self.as_raw_InnerProductLayerInt8());
+		let ret = unsafe { ffi_call!(cv_dnn_InnerProductLayerInt8_getPropOutput_zp_const(self.as_raw_InnerProductLayerInt8())) };
 		ret
 	}
 	
+	/// Non-panicking version of [output_zp](InnerProductLayerInt8TraitConst::output_zp), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_output_zp(&self) -> Result<i32> {
+		crate::core::validity::check("InnerProductLayerInt8", self.as_raw_InnerProductLayerInt8())?;
+		Ok(InnerProductLayerInt8TraitConst::output_zp(self))
+	}
+	
 }
 
 pub trait InnerProductLayerInt8Trait: crate::dnn::InnerProductLayerInt8TraitConst + crate::dnn::InnerProductLayerTrait {
@@ -5813,7 +6905,8 @@ pub trait InnerProductLayerInt8Trait: crate::dnn::InnerProductLayerInt8TraitCons
 
 	#[inline]
 	fn set_output_zp(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_dnn_InnerProductLayerInt8_setPropOutput_zp_int(self.as_raw_mut_InnerProductLayerInt8(), val) };
+		crate::core::validity::debug_check("InnerProductLayerInt8", self.as_raw_mut_InnerProductLayerInt8());
+		let ret = unsafe { ffi_call!(cv_dnn_InnerProductLayerInt8_setPropOutput_zp_int(self.as_raw_mut_InnerProductLayerInt8(), val)) };
 		ret
 	}
 	
@@ -5828,7 +6921,10 @@ opencv_type_boxed! { InnerProductLayerInt8 }
 impl Drop for InnerProductLayerInt8 {
 	fn drop(&mut self) {
 		extern "C" { fn cv_InnerProductLayerInt8_delete(instance: *mut c_void); }
-		unsafe { cv_InnerProductLayerInt8_delete(self.as_raw_mut_InnerProductLayerInt8()) };
+		let ptr = self.as_raw_mut_InnerProductLayerInt8();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_InnerProductLayerInt8_delete(ptr) };
 	}
 }
 
@@ -5870,7 +6966,7 @@ impl InnerProductLayerInt8 {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::InnerProductLayerInt8>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_InnerProductLayerInt8_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_InnerProductLayerInt8_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::InnerProductLayerInt8>::opencv_from_extern(ret) };
@@ -5887,7 +6983,7 @@ boxed_cast_base! { InnerProductLayerInt8, crate::dnn::Layer, cv_InnerProductLaye
 
 /// Bilinear resize layer from https://github.com/cdmh/deeplab-public-ver2
 /// 
-/// It differs from @ref ResizeLayer in output shape and resize scales computations.
+/// It differs from [ResizeLayer](crate::dnn::ResizeLayer) in output shape and resize scales computations.
 pub trait InterpLayerTraitConst: crate::dnn::LayerTraitConst {
 	fn as_raw_InterpLayer(&self) -> *const c_void;
 
@@ -5900,7 +6996,7 @@ pub trait InterpLayerTrait: crate::dnn::InterpLayerTraitConst + crate::dnn::Laye
 
 /// Bilinear resize layer from https://github.com/cdmh/deeplab-public-ver2
 /// 
-/// It differs from @ref ResizeLayer in output shape and resize scales computations.
+/// It differs from [ResizeLayer](crate::dnn::ResizeLayer) in output shape and resize scales computations.
 pub struct InterpLayer {
 	ptr: *mut c_void
 }
@@ -5910,7 +7006,10 @@ opencv_type_boxed! { InterpLayer }
 impl Drop for InterpLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_InterpLayer_delete(instance: *mut c_void); }
-		unsafe { cv_InterpLayer_delete(self.as_raw_mut_InterpLayer()) };
+		let ptr = self.as_raw_mut_InterpLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_InterpLayer_delete(ptr) };
 	}
 }
 
@@ -5944,7 +7043,7 @@ impl InterpLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::Layer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_InterpLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_InterpLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::Layer>::opencv_from_extern(ret) };
@@ -5981,9 +7080,10 @@ pub trait KeypointsModelTrait: crate::dnn::KeypointsModelTraitConst + crate::dnn
 	/// * thresh: 0.5
 	#[inline]
 	fn estimate(&mut self, frame: &dyn core::ToInputArray, thresh: f32) -> Result<core::Vector<core::Point2f>> {
+		crate::core::validity::check("KeypointsModel", self.as_raw_mut_KeypointsModel())?;
 		input_array_arg!(frame);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_KeypointsModel_estimate_const__InputArrayR_float(self.as_raw_mut_KeypointsModel(), frame.as_raw__InputArray(), thresh, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_KeypointsModel_estimate_const__InputArrayR_float(self.as_raw_mut_KeypointsModel(), frame.as_raw__InputArray(), thresh, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<core::Point2f>::opencv_from_extern(ret) };
@@ -6006,7 +7106,10 @@ opencv_type_boxed! { KeypointsModel }
 impl Drop for KeypointsModel {
 	fn drop(&mut self) {
 		extern "C" { fn cv_KeypointsModel_delete(instance: *mut c_void); }
-		unsafe { cv_KeypointsModel_delete(self.as_raw_mut_KeypointsModel()) };
+		let ptr = self.as_raw_mut_KeypointsModel();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_KeypointsModel_delete(ptr) };
 	}
 }
 
@@ -6042,7 +7145,7 @@ impl KeypointsModel {
 		extern_container_arg!(model);
 		extern_container_arg!(config);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_KeypointsModel_KeypointsModel_const_StringR_const_StringR(model.opencv_as_extern(), config.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_KeypointsModel_KeypointsModel_const_StringR_const_StringR(model.opencv_as_extern(), config.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::KeypointsModel::opencv_from_extern(ret) };
@@ -6055,7 +7158,7 @@ impl KeypointsModel {
 	#[inline]
 	pub fn new_1(network: &crate::dnn::Net) -> Result<crate::dnn::KeypointsModel> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_KeypointsModel_KeypointsModel_const_NetR(network.as_raw_Net(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_KeypointsModel_KeypointsModel_const_NetR(network.as_raw_Net(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::KeypointsModel::opencv_from_extern(ret) };
@@ -6071,40 +7174,94 @@ pub trait LRNLayerTraitConst: crate::dnn::LayerTraitConst {
 
 	#[inline]
 	fn typ(&self) -> i32 {
-		let ret = unsafe { sys::cv_dnn_LRNLayer_getPropType_const(self.as_raw_LRNLayer()) };
+		crate::core::validity::debug_check("LRNLayer", self.as_raw_LRNLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_LRNLayer_getPropType_const(self.as_raw_LRNLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [typ](LRNLayerTraitConst::typ), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_typ(&self) -> Result<i32> {
+		crate::core::validity::check("LRNLayer", self.as_raw_LRNLayer())?;
+		Ok(LRNLayerTraitConst::typ(self))
+	}
+	
 	#[inline]
 	fn size(&self) -> i32 {
-		let ret = unsafe { sys::cv_dnn_LRNLayer_getPropSize_const(self.as_raw_LRNLayer()) };
+		crate::core::validity::debug_check("LRNLayer", self.as_raw_LRNLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_LRNLayer_getPropSize_const(self.as_raw_LRNLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [size](LRNLayerTraitConst::size), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_size(&self) -> Result<i32> {
+		crate::core::validity::check("LRNLayer", self.as_raw_LRNLayer())?;
+		Ok(LRNLayerTraitConst::size(self))
+	}
+	
 	#[inline]
 	fn alpha(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_LRNLayer_getPropAlpha_const(self.as_raw_LRNLayer()) };
+		crate::core::validity::debug_check("LRNLayer", self.as_raw_LRNLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_LRNLayer_getPropAlpha_const(self.as_raw_LRNLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [alpha](LRNLayerTraitConst::alpha), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_alpha(&self) -> Result<f32> {
+		crate::core::validity::check("LRNLayer", self.as_raw_LRNLayer())?;
+		Ok(LRNLayerTraitConst::alpha(self))
+	}
+	
 	#[inline]
 	fn beta(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_LRNLayer_getPropBeta_const(self.as_raw_LRNLayer()) };
+		crate::core::validity::debug_check("LRNLayer", self.as_raw_LRNLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_LRNLayer_getPropBeta_const(self.as_raw_LRNLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [beta](LRNLayerTraitConst::beta), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_beta(&self) -> Result<f32> {
+		crate::core::validity::check("LRNLayer", self.as_raw_LRNLayer())?;
+		Ok(LRNLayerTraitConst::beta(self))
+	}
+	
 	#[inline]
 	fn bias(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_LRNLayer_getPropBias_const(self.as_raw_LRNLayer()) };
+		crate::core::validity::debug_check("LRNLayer", self.as_raw_LRNLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_LRNLayer_getPropBias_const(self.as_raw_LRNLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [bias](LRNLayerTraitConst::bias), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_bias(&self) -> Result<f32> {
+		crate::core::validity::check("LRNLayer", self.as_raw_LRNLayer())?;
+		Ok(LRNLayerTraitConst::bias(self))
+	}
+	
 	#[inline]
 	fn norm_by_size(&self) -> bool {
-		let ret = unsafe { sys::cv_dnn_LRNLayer_getPropNormBySize_const(self.as_raw_LRNLayer()) };
+		crate::core::validity::debug_check("LRNLayer", self.as_raw_LRNLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_LRNLayer_getPropNormBySize_const(self.as_raw_LRNLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [norm_by_size](LRNLayerTraitConst::norm_by_size), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_norm_by_size(&self) -> Result<bool> {
+		crate::core::validity::check("LRNLayer", self.as_raw_LRNLayer())?;
+		Ok(LRNLayerTraitConst::norm_by_size(self))
+	}
+	
 }
 
 pub trait LRNLayerTrait: crate::dnn::LRNLayerTraitConst + crate::dnn::LayerTrait {
@@ -6112,37 +7269,43 @@ pub trait LRNLayerTrait: crate::dnn::LRNLayerTraitConst + crate::dnn::LayerTrait
 
 	#[inline]
 	fn set_type(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_dnn_LRNLayer_setPropType_int(self.as_raw_mut_LRNLayer(), val) };
+		crate::core::validity::debug_check("LRNLayer", self.as_raw_mut_LRNLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_LRNLayer_setPropType_int(self.as_raw_mut_LRNLayer(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_size(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_dnn_LRNLayer_setPropSize_int(self.as_raw_mut_LRNLayer(), val) };
+		crate::core::validity::debug_check("LRNLayer", self.as_raw_mut_LRNLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_LRNLayer_setPropSize_int(self.as_raw_mut_LRNLayer(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_alpha(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_LRNLayer_setPropAlpha_float(self.as_raw_mut_LRNLayer(), val) };
+		crate::core::validity::debug_check("LRNLayer", self.as_raw_mut_LRNLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_LRNLayer_setPropAlpha_float(self.as_raw_mut_LRNLayer(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_beta(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_LRNLayer_setPropBeta_float(self.as_raw_mut_LRNLayer(), val) };
+		crate::core::validity::debug_check("LRNLayer", self.as_raw_mut_LRNLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_LRNLayer_setPropBeta_float(self.as_raw_mut_LRNLayer(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_bias(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_LRNLayer_setPropBias_float(self.as_raw_mut_LRNLayer(), val) };
+		crate::core::validity::debug_check("LRNLayer", self.as_raw_mut_LRNLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_LRNLayer_setPropBias_float(self.as_raw_mut_LRNLayer(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_norm_by_size(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_dnn_LRNLayer_setPropNormBySize_bool(self.as_raw_mut_LRNLayer(), val) };
+		crate::core::validity::debug_check("LRNLayer", self.as_raw_mut_LRNLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_LRNLayer_setPropNormBySize_bool(self.as_raw_mut_LRNLayer(), val)) };
 		ret
 	}
 	
@@ -6157,7 +7320,10 @@ opencv_type_boxed! { LRNLayer }
 impl Drop for LRNLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_LRNLayer_delete(instance: *mut c_void); }
-		unsafe { cv_LRNLayer_delete(self.as_raw_mut_LRNLayer()) };
+		let ptr = self.as_raw_mut_LRNLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_LRNLayer_delete(ptr) };
 	}
 }
 
@@ -6191,7 +7357,7 @@ impl LRNLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::LRNLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_LRNLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_LRNLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::LRNLayer>::opencv_from_extern(ret) };
@@ -6237,10 +7403,10 @@ pub trait LSTMLayer: crate::dnn::LSTMLayerConst + crate::dnn::LayerTrait {
 	/// where @f$W_{x?}@f$, @f$W_{h?}@f$ and @f$b_{?}@f$ are learned weights represented as matrices:
 	/// @f$W_{x?} \in R^{N_h \times N_x}@f$, @f$W_{h?} \in R^{N_h \times N_h}@f$, @f$b_? \in R^{N_h}@f$.
 	/// 
-	/// For simplicity and performance purposes we use @f$ W_x = [W_{xi}; W_{xf}; W_{xo}, W_{xg}] @f$
+	/// For simplicity and performance purposes we use @f$ W_x = \[W_{xi}; W_{xf}; W_{xo}, W_{xg}\] @f$
 	/// (i.e. @f$W_x@f$ is vertical concatenation of @f$ W_{x?} @f$), @f$ W_x \in R^{4N_h \times N_x} @f$.
-	/// The same for @f$ W_h = [W_{hi}; W_{hf}; W_{ho}, W_{hg}], W_h \in R^{4N_h \times N_h} @f$
-	/// and for @f$ b = [b_i; b_f, b_o, b_g]@f$, @f$b \in R^{4N_h} @f$.
+	/// The same for @f$ W_h = \[W_{hi}; W_{hf}; W_{ho}, W_{hg}\], W_h \in R^{4N_h \times N_h} @f$
+	/// and for @f$ b = \[b_i; b_f, b_o, b_g\]@f$, @f$b \in R^{4N_h} @f$.
 	/// 
 	/// ## Parameters
 	/// * Wh: is matrix defining how previous output is transformed to internal gates (i.e. according to above mentioned notation is @f$ W_h @f$)
@@ -6249,23 +7415,25 @@ pub trait LSTMLayer: crate::dnn::LSTMLayerConst + crate::dnn::LayerTrait {
 	#[deprecated = "Use LayerParams::blobs instead."]
 	#[inline]
 	fn set_weights(&mut self, wh: &core::Mat, wx: &core::Mat, b: &core::Mat) -> Result<()> {
+		crate::core::validity::check("LSTMLayer", self.as_raw_mut_LSTMLayer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_LSTMLayer_setWeights_const_MatR_const_MatR_const_MatR(self.as_raw_mut_LSTMLayer(), wh.as_raw_Mat(), wx.as_raw_Mat(), b.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_LSTMLayer_setWeights_const_MatR_const_MatR_const_MatR(self.as_raw_mut_LSTMLayer(), wh.as_raw_Mat(), wx.as_raw_Mat(), b.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
-	/// Specifies shape of output blob which will be [[`T`], `N`] + @p outTailShape.
-	/// @details If this parameter is empty or unset then @p outTailShape = [`Wh`.size(0)] will be used,
+	/// Specifies shape of output blob which will be \[\[`T`\], `N`\] + @p outTailShape.
+	/// @details If this parameter is empty or unset then @p outTailShape = \[`Wh`.size(0)\] will be used,
 	/// where `Wh` is parameter from setWeights().
 	/// 
 	/// ## C++ default parameters
 	/// * out_tail_shape: MatShape()
 	#[inline]
 	fn set_out_shape(&mut self, out_tail_shape: &crate::dnn::MatShape) -> Result<()> {
+		crate::core::validity::check("LSTMLayer", self.as_raw_mut_LSTMLayer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_LSTMLayer_setOutShape_const_MatShapeR(self.as_raw_mut_LSTMLayer(), out_tail_shape.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_LSTMLayer_setOutShape_const_MatShapeR(self.as_raw_mut_LSTMLayer(), out_tail_shape.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6275,19 +7443,20 @@ pub trait LSTMLayer: crate::dnn::LSTMLayerConst + crate::dnn::LayerTrait {
 	/// **Deprecated**: Use flag `produce_cell_output` in LayerParams.
 	/// Specifies either interpret first dimension of input blob as timestamp dimension either as sample.
 	/// 
-	/// If flag is set to true then shape of input blob will be interpreted as [`T`, `N`, `[data dims]`] where `T` specifies number of timestamps, `N` is number of independent streams.
+	/// If flag is set to true then shape of input blob will be interpreted as \[`T`, `N`, `[data dims]`\] where `T` specifies number of timestamps, `N` is number of independent streams.
 	/// In this case each forward() call will iterate through `T` timestamps and update layer's state `T` times.
 	/// 
-	/// If flag is set to false then shape of input blob will be interpreted as [`N`, `[data dims]`].
-	/// In this case each forward() call will make one iteration and produce one timestamp with shape [`N`, `[out dims]`].
+	/// If flag is set to false then shape of input blob will be interpreted as \[`N`, `[data dims]`\].
+	/// In this case each forward() call will make one iteration and produce one timestamp with shape \[`N`, `[out dims]`\].
 	/// 
 	/// ## C++ default parameters
 	/// * use_: true
 	#[deprecated = "Use flag `produce_cell_output` in LayerParams."]
 	#[inline]
 	fn set_use_timstamps_dim(&mut self, use_: bool) -> Result<()> {
+		crate::core::validity::check("LSTMLayer", self.as_raw_mut_LSTMLayer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_LSTMLayer_setUseTimstampsDim_bool(self.as_raw_mut_LSTMLayer(), use_, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_LSTMLayer_setUseTimstampsDim_bool(self.as_raw_mut_LSTMLayer(), use_, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6303,8 +7472,9 @@ pub trait LSTMLayer: crate::dnn::LSTMLayerConst + crate::dnn::LayerTrait {
 	#[deprecated = "Use flag `use_timestamp_dim` in LayerParams."]
 	#[inline]
 	fn set_produce_cell_output(&mut self, produce: bool) -> Result<()> {
+		crate::core::validity::check("LSTMLayer", self.as_raw_mut_LSTMLayer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_LSTMLayer_setProduceCellOutput_bool(self.as_raw_mut_LSTMLayer(), produce, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_LSTMLayer_setProduceCellOutput_bool(self.as_raw_mut_LSTMLayer(), produce, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6312,9 +7482,10 @@ pub trait LSTMLayer: crate::dnn::LSTMLayerConst + crate::dnn::LayerTrait {
 	
 	#[inline]
 	fn input_name_to_index(&mut self, input_name: &str) -> Result<i32> {
+		crate::core::validity::check("LSTMLayer", self.as_raw_mut_LSTMLayer())?;
 		extern_container_arg!(mut input_name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_LSTMLayer_inputNameToIndex_String(self.as_raw_mut_LSTMLayer(), input_name.opencv_as_extern_mut(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_LSTMLayer_inputNameToIndex_String(self.as_raw_mut_LSTMLayer(), input_name.opencv_as_extern_mut(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6322,9 +7493,10 @@ pub trait LSTMLayer: crate::dnn::LSTMLayerConst + crate::dnn::LayerTrait {
 	
 	#[inline]
 	fn output_name_to_index(&mut self, output_name: &str) -> Result<i32> {
+		crate::core::validity::check("LSTMLayer", self.as_raw_mut_LSTMLayer())?;
 		extern_container_arg!(output_name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_LSTMLayer_outputNameToIndex_const_StringR(self.as_raw_mut_LSTMLayer(), output_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_LSTMLayer_outputNameToIndex_const_StringR(self.as_raw_mut_LSTMLayer(), output_name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6337,7 +7509,7 @@ impl dyn LSTMLayer + '_ {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<dyn crate::dnn::LSTMLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_LSTMLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_LSTMLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::dnn::LSTMLayer>::opencv_from_extern(ret) };
@@ -6348,41 +7520,77 @@ impl dyn LSTMLayer + '_ {
 /// This interface class allows to build new Layers - are building blocks of networks.
 /// 
 /// Each class, derived from Layer, must implement allocate() methods to declare own outputs and forward() to compute outputs.
-/// Also before using the new layer into networks you must register your layer by using one of @ref dnnLayerFactory "LayerFactory" macros.
+/// Also before using the new layer into networks you must register your layer by using one of LayerFactory macros.
 pub trait LayerTraitConst: core::AlgorithmTraitConst {
 	fn as_raw_Layer(&self) -> *const c_void;
 
 	/// List of learned parameters must be stored here to allow read them by using Net::getParam().
 	#[inline]
 	fn blobs(&self) -> core::Vector<core::Mat> {
-		let ret = unsafe { sys::cv_dnn_Layer_getPropBlobs_const(self.as_raw_Layer()) };
+		crate::core::validity::debug_check("Layer", self.as_raw_Layer());
+		let ret = unsafe { ffi_call!(cv_dnn_Layer_getPropBlobs_const(self.as_raw_Layer())) };
 		let ret = unsafe { core::Vector::<core::Mat>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [blobs](LayerTraitConst::blobs), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_blobs(&self) -> Result<core::Vector<core::Mat>> {
+		crate::core::validity::check("Layer", self.as_raw_Layer())?;
+		Ok(LayerTraitConst::blobs(self))
+	}
+	
 	/// Name of the layer instance, can be used for logging or other internal purposes.
 	#[inline]
 	fn name(&self) -> String {
-		let ret = unsafe { sys::cv_dnn_Layer_getPropName_const(self.as_raw_Layer()) };
+		crate::core::validity::debug_check("Layer", self.as_raw_Layer());
+		let ret = unsafe { ffi_call!(cv_dnn_Layer_getPropName_const(self.as_raw_Layer())) };
 		let ret = unsafe { String::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [name](LayerTraitConst::name), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_name(&self) -> Result<String> {
+		crate::core::validity::check("Layer", self.as_raw_Layer())?;
+		Ok(LayerTraitConst::name(self))
+	}
+	
 	/// Type name which was used for creating layer by layer factory.
 	#[inline]
 	fn typ(&self) -> String {
-		let ret = unsafe { sys::cv_dnn_Layer_getPropType_const(self.as_raw_Layer()) };
+		crate::core::validity::debug_check("Layer", self.as_raw_Layer());
+		let ret = unsafe { ffi_call!(cv_dnn_Layer_getPropType_const(self.as_raw_Layer())) };
 		let ret = unsafe { String::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [typ](LayerTraitConst::typ), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_typ(&self) -> Result<String> {
+		crate::core::validity::check("Layer", self.as_raw_Layer())?;
+		Ok(LayerTraitConst::typ(self))
+	}
+	
 	/// prefer target for layer forwarding
 	#[inline]
 	fn preferable_target(&self) -> i32 {
-		let ret = unsafe { sys::cv_dnn_Layer_getPropPreferableTarget_const(self.as_raw_Layer()) };
+		crate::core::validity::debug_check("Layer", self.as_raw_Layer());
+		let ret = unsafe { ffi_call!(cv_dnn_Layer_getPropPreferableTarget_const(self.as_raw_Layer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [preferable_target](LayerTraitConst::preferable_target), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_preferable_target(&self) -> Result<i32> {
+		crate::core::validity::check("Layer", self.as_raw_Layer())?;
+		Ok(LayerTraitConst::preferable_target(self))
+	}
+	
 	/// Automatic Halide scheduling based on layer hyper-parameters.
 	/// ## Parameters
 	/// * node: Backend node with Halide functions.
@@ -6390,14 +7598,15 @@ pub trait LayerTraitConst: core::AlgorithmTraitConst {
 	/// * outputs: Blobs that will be used in forward invocations.
 	/// * targetId: Target identifier
 	/// ## See also
-	/// BackendNode, Target
+	/// [BackendNode](crate::dnn::BackendNode), `Target`
 	/// 
 	/// Layer don't use own Halide::Func members because we can have applied
 	/// layers fusing. In this way the fused function should be scheduled.
 	#[inline]
 	fn apply_halide_scheduler(&self, node: &mut core::Ptr<crate::dnn::BackendNode>, inputs: &core::Vector<core::Mat>, outputs: &core::Vector<core::Mat>, target_id: i32) -> Result<()> {
+		crate::core::validity::check("Layer", self.as_raw_Layer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Layer_applyHalideScheduler_const_Ptr_BackendNode_R_const_vector_MatX_R_const_vector_Mat_R_int(self.as_raw_Layer(), node.as_raw_mut_PtrOfBackendNode(), inputs.as_raw_VectorOfMat(), outputs.as_raw_VectorOfMat(), target_id, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Layer_applyHalideScheduler_const_Ptr_BackendNode_R_const_vector_MatX_R_const_vector_Mat_R_int(self.as_raw_Layer(), node.as_raw_mut_PtrOfBackendNode(), inputs.as_raw_VectorOfMat(), outputs.as_raw_VectorOfMat(), target_id, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6405,9 +7614,9 @@ pub trait LayerTraitConst: core::AlgorithmTraitConst {
 	
 	/// Returns parameters of layers with channel-wise multiplication and addition.
 	/// ## Parameters
-	/// * scale:[out] Channel-wise multipliers. Total number of values should
+	/// * scale:\[out\] Channel-wise multipliers. Total number of values should
 	///                   be equal to number of channels.
-	/// * shift:[out] Channel-wise offsets. Total number of values should
+	/// * shift:\[out\] Channel-wise offsets. Total number of values should
 	///                   be equal to number of channels.
 	/// 
 	/// Some layers can fuse their transformations with further layers.
@@ -6417,8 +7626,9 @@ pub trait LayerTraitConst: core::AlgorithmTraitConst {
 	/// element-wise multiplications or additions.
 	#[inline]
 	fn get_scale_shift(&self, scale: &mut core::Mat, shift: &mut core::Mat) -> Result<()> {
+		crate::core::validity::check("Layer", self.as_raw_Layer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Layer_getScaleShift_const_MatR_MatR(self.as_raw_Layer(), scale.as_raw_mut_Mat(), shift.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Layer_getScaleShift_const_MatR_MatR(self.as_raw_Layer(), scale.as_raw_mut_Mat(), shift.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6426,14 +7636,15 @@ pub trait LayerTraitConst: core::AlgorithmTraitConst {
 	
 	/// Returns scale and zeropoint of layers
 	/// ## Parameters
-	/// * scale:[out] Output scale
-	/// * zeropoint:[out] Output zeropoint
+	/// * scale:\[out\] Output scale
+	/// * zeropoint:\[out\] Output zeropoint
 	/// 
 	/// By default, @p scale is 1 and @p zeropoint is 0.
 	#[inline]
 	fn get_scale_zeropoint(&self, scale: &mut f32, zeropoint: &mut i32) -> Result<()> {
+		crate::core::validity::check("Layer", self.as_raw_Layer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Layer_getScaleZeropoint_const_floatR_intR(self.as_raw_Layer(), scale, zeropoint, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Layer_getScaleZeropoint_const_floatR_intR(self.as_raw_Layer(), scale, zeropoint, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6441,8 +7652,9 @@ pub trait LayerTraitConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_memory_shapes(&self, inputs: &core::Vector<crate::dnn::MatShape>, required_outputs: i32, outputs: &mut core::Vector<crate::dnn::MatShape>, internals: &mut core::Vector<crate::dnn::MatShape>) -> Result<bool> {
+		crate::core::validity::check("Layer", self.as_raw_Layer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Layer_getMemoryShapes_const_const_vector_MatShape_R_const_int_vector_MatShape_R_vector_MatShape_R(self.as_raw_Layer(), inputs.as_raw_VectorOfMatShape(), required_outputs, outputs.as_raw_mut_VectorOfMatShape(), internals.as_raw_mut_VectorOfMatShape(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Layer_getMemoryShapes_const_const_vector_MatShape_R_const_int_vector_MatShape_R_vector_MatShape_R(self.as_raw_Layer(), inputs.as_raw_VectorOfMatShape(), required_outputs, outputs.as_raw_mut_VectorOfMatShape(), internals.as_raw_mut_VectorOfMatShape(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6450,8 +7662,9 @@ pub trait LayerTraitConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_flops(&self, inputs: &core::Vector<crate::dnn::MatShape>, outputs: &core::Vector<crate::dnn::MatShape>) -> Result<i64> {
+		crate::core::validity::check("Layer", self.as_raw_Layer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Layer_getFLOPS_const_const_vector_MatShape_R_const_vector_MatShape_R(self.as_raw_Layer(), inputs.as_raw_VectorOfMatShape(), outputs.as_raw_VectorOfMatShape(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Layer_getFLOPS_const_const_vector_MatShape_R_const_vector_MatShape_R(self.as_raw_Layer(), inputs.as_raw_VectorOfMatShape(), outputs.as_raw_VectorOfMatShape(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6465,46 +7678,51 @@ pub trait LayerTrait: core::AlgorithmTrait + crate::dnn::LayerTraitConst {
 	/// List of learned parameters must be stored here to allow read them by using Net::getParam().
 	#[inline]
 	fn set_blobs(&mut self, mut val: core::Vector<core::Mat>) {
-		let ret = unsafe { sys::cv_dnn_Layer_setPropBlobs_vector_Mat_(self.as_raw_mut_Layer(), val.as_raw_mut_VectorOfMat()) };
+		crate::core::validity::debug_check("Layer", self.as_raw_mut_Layer());
+		let ret = unsafe { ffi_call!(cv_dnn_Layer_setPropBlobs_vector_Mat_(self.as_raw_mut_Layer(), val.as_raw_mut_VectorOfMat())) };
 		ret
 	}
 	
 	/// Name of the layer instance, can be used for logging or other internal purposes.
 	#[inline]
 	fn set_name(&mut self, val: &str) {
+		crate::core::validity::debug_check("Layer", self.as_raw_mut_Layer());
 		extern_container_arg!(nofail mut val);
-		let ret = unsafe { sys::cv_dnn_Layer_setPropName_String(self.as_raw_mut_Layer(), val.opencv_as_extern_mut()) };
+		let ret = unsafe { ffi_call!(cv_dnn_Layer_setPropName_String(self.as_raw_mut_Layer(), val.opencv_as_extern_mut())) };
 		ret
 	}
 	
 	/// Type name which was used for creating layer by layer factory.
 	#[inline]
 	fn set_type(&mut self, val: &str) {
+		crate::core::validity::debug_check("Layer", self.as_raw_mut_Layer());
 		extern_container_arg!(nofail mut val);
-		let ret = unsafe { sys::cv_dnn_Layer_setPropType_String(self.as_raw_mut_Layer(), val.opencv_as_extern_mut()) };
+		let ret = unsafe { ffi_call!(cv_dnn_Layer_setPropType_String(self.as_raw_mut_Layer(), val.opencv_as_extern_mut())) };
 		ret
 	}
 	
 	/// prefer target for layer forwarding
 	#[inline]
 	fn set_preferable_target(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_dnn_Layer_setPropPreferableTarget_int(self.as_raw_mut_Layer(), val) };
+		crate::core::validity::debug_check("Layer", self.as_raw_mut_Layer());
+		let ret = unsafe { ffi_call!(cv_dnn_Layer_setPropPreferableTarget_int(self.as_raw_mut_Layer(), val)) };
 		ret
 	}
 	
 	/// Computes and sets internal parameters according to inputs, outputs and blobs.
 	/// ## Parameters
 	/// * inputs: vector of already allocated input blobs
-	/// * outputs:[out] vector of already allocated output blobs
+	/// * outputs:\[out\] vector of already allocated output blobs
 	/// 
 	/// If this method is called after network has allocated all memory for input and output blobs
 	/// and before inferencing.
 	#[inline]
 	fn finalize(&mut self, inputs: &dyn core::ToInputArray, outputs: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("Layer", self.as_raw_mut_Layer())?;
 		input_array_arg!(inputs);
 		output_array_arg!(outputs);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Layer_finalize_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_Layer(), inputs.as_raw__InputArray(), outputs.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Layer_finalize_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_Layer(), inputs.as_raw__InputArray(), outputs.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6515,13 +7733,14 @@ pub trait LayerTrait: core::AlgorithmTrait + crate::dnn::LayerTraitConst {
 	/// **Deprecated**: Use Layer::forward(InputArrayOfArrays, OutputArrayOfArrays, OutputArrayOfArrays) instead
 	/// ## Parameters
 	/// * input: the input blobs.
-	/// * output:[out] allocated output blobs, which will store results of the computation.
-	/// * internals:[out] allocated internal blobs
+	/// * output:\[out\] allocated output blobs, which will store results of the computation.
+	/// * internals:\[out\] allocated internal blobs
 	#[deprecated = "Use Layer::forward(InputArrayOfArrays, OutputArrayOfArrays, OutputArrayOfArrays) instead"]
 	#[inline]
 	fn forward_mat(&mut self, input: &mut core::Vector<core::Mat>, output: &mut core::Vector<core::Mat>, internals: &mut core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("Layer", self.as_raw_mut_Layer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Layer_forward_vector_MatX_R_vector_Mat_R_vector_Mat_R(self.as_raw_mut_Layer(), input.as_raw_mut_VectorOfMat(), output.as_raw_mut_VectorOfMat(), internals.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Layer_forward_vector_MatX_R_vector_Mat_R_vector_Mat_R(self.as_raw_mut_Layer(), input.as_raw_mut_VectorOfMat(), output.as_raw_mut_VectorOfMat(), internals.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6530,15 +7749,16 @@ pub trait LayerTrait: core::AlgorithmTrait + crate::dnn::LayerTraitConst {
 	/// Given the @p input blobs, computes the output @p blobs.
 	/// ## Parameters
 	/// * inputs: the input blobs.
-	/// * outputs:[out] allocated output blobs, which will store results of the computation.
-	/// * internals:[out] allocated internal blobs
+	/// * outputs:\[out\] allocated output blobs, which will store results of the computation.
+	/// * internals:\[out\] allocated internal blobs
 	#[inline]
 	fn forward(&mut self, inputs: &dyn core::ToInputArray, outputs: &mut dyn core::ToOutputArray, internals: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("Layer", self.as_raw_mut_Layer())?;
 		input_array_arg!(inputs);
 		output_array_arg!(outputs);
 		output_array_arg!(internals);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Layer_forward_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_Layer(), inputs.as_raw__InputArray(), outputs.as_raw__OutputArray(), internals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Layer_forward_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_Layer(), inputs.as_raw__InputArray(), outputs.as_raw__OutputArray(), internals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6548,13 +7768,14 @@ pub trait LayerTrait: core::AlgorithmTrait + crate::dnn::LayerTraitConst {
 	/// ## Parameters
 	/// * scales: input and output scales.
 	/// * zeropoints: input and output zeropoints.
-	/// * params:[out] Quantized parameters required for fixed point implementation of that layer.
+	/// * params:\[out\] Quantized parameters required for fixed point implementation of that layer.
 	/// ## Returns
 	/// True if layer can be quantized.
 	#[inline]
 	fn try_quantize(&mut self, scales: &core::Vector<core::Vector<f32>>, zeropoints: &core::Vector<core::Vector<i32>>, params: &mut crate::dnn::LayerParams) -> Result<bool> {
+		crate::core::validity::check("Layer", self.as_raw_mut_Layer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Layer_tryQuantize_const_vector_vector_float__R_const_vector_vector_int__R_LayerParamsR(self.as_raw_mut_Layer(), scales.as_raw_VectorOfVectorOff32(), zeropoints.as_raw_VectorOfVectorOfi32(), params.as_raw_mut_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Layer_tryQuantize_const_vector_vector_float__R_const_vector_vector_int__R_LayerParamsR(self.as_raw_mut_Layer(), scales.as_raw_VectorOfVectorOff32(), zeropoints.as_raw_VectorOfVectorOfi32(), params.as_raw_mut_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6563,15 +7784,16 @@ pub trait LayerTrait: core::AlgorithmTrait + crate::dnn::LayerTraitConst {
 	/// Given the @p input blobs, computes the output @p blobs.
 	/// ## Parameters
 	/// * inputs: the input blobs.
-	/// * outputs:[out] allocated output blobs, which will store results of the computation.
-	/// * internals:[out] allocated internal blobs
+	/// * outputs:\[out\] allocated output blobs, which will store results of the computation.
+	/// * internals:\[out\] allocated internal blobs
 	#[inline]
 	fn forward_fallback(&mut self, inputs: &dyn core::ToInputArray, outputs: &mut dyn core::ToOutputArray, internals: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("Layer", self.as_raw_mut_Layer())?;
 		input_array_arg!(inputs);
 		output_array_arg!(outputs);
 		output_array_arg!(internals);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Layer_forward_fallback_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_Layer(), inputs.as_raw__InputArray(), outputs.as_raw__OutputArray(), internals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Layer_forward_fallback_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_Layer(), inputs.as_raw__InputArray(), outputs.as_raw__OutputArray(), internals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6581,7 +7803,7 @@ pub trait LayerTrait: core::AlgorithmTrait + crate::dnn::LayerTraitConst {
 	///  Computes and sets internal parameters according to inputs, outputs and blobs.
 	/// ## Parameters
 	/// * inputs: vector of already allocated input blobs
-	/// * outputs:[out] vector of already allocated output blobs
+	/// * outputs:\[out\] vector of already allocated output blobs
 	/// 
 	/// If this method is called after network has allocated all memory for input and output blobs
 	/// and before inferencing.
@@ -6593,8 +7815,9 @@ pub trait LayerTrait: core::AlgorithmTrait + crate::dnn::LayerTraitConst {
 	#[deprecated = "Use Layer::finalize(InputArrayOfArrays, OutputArrayOfArrays) instead"]
 	#[inline]
 	fn finalize_mat_to(&mut self, inputs: &core::Vector<core::Mat>, outputs: &mut core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("Layer", self.as_raw_mut_Layer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Layer_finalize_const_vector_Mat_R_vector_Mat_R(self.as_raw_mut_Layer(), inputs.as_raw_VectorOfMat(), outputs.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Layer_finalize_const_vector_Mat_R_vector_Mat_R(self.as_raw_mut_Layer(), inputs.as_raw_VectorOfMat(), outputs.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6604,7 +7827,7 @@ pub trait LayerTrait: core::AlgorithmTrait + crate::dnn::LayerTraitConst {
 	///  Computes and sets internal parameters according to inputs, outputs and blobs.
 	/// ## Parameters
 	/// * inputs: vector of already allocated input blobs
-	/// * outputs:[out] vector of already allocated output blobs
+	/// * outputs:\[out\] vector of already allocated output blobs
 	/// 
 	/// If this method is called after network has allocated all memory for input and output blobs
 	/// and before inferencing.
@@ -6616,8 +7839,9 @@ pub trait LayerTrait: core::AlgorithmTrait + crate::dnn::LayerTraitConst {
 	#[deprecated = "Use Layer::finalize(InputArrayOfArrays, OutputArrayOfArrays) instead"]
 	#[inline]
 	fn finalize_mat(&mut self, inputs: &core::Vector<core::Mat>) -> Result<core::Vector<core::Mat>> {
+		crate::core::validity::check("Layer", self.as_raw_mut_Layer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Layer_finalize_const_vector_Mat_R(self.as_raw_mut_Layer(), inputs.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Layer_finalize_const_vector_Mat_R(self.as_raw_mut_Layer(), inputs.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<core::Mat>::opencv_from_extern(ret) };
@@ -6630,8 +7854,9 @@ pub trait LayerTrait: core::AlgorithmTrait + crate::dnn::LayerTraitConst {
 	#[deprecated = "This method will be removed in the future release."]
 	#[inline]
 	fn run(&mut self, inputs: &core::Vector<core::Mat>, outputs: &mut core::Vector<core::Mat>, internals: &mut core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("Layer", self.as_raw_mut_Layer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Layer_run_const_vector_Mat_R_vector_Mat_R_vector_Mat_R(self.as_raw_mut_Layer(), inputs.as_raw_VectorOfMat(), outputs.as_raw_mut_VectorOfMat(), internals.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Layer_run_const_vector_Mat_R_vector_Mat_R_vector_Mat_R(self.as_raw_mut_Layer(), inputs.as_raw_VectorOfMat(), outputs.as_raw_mut_VectorOfMat(), internals.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6641,13 +7866,14 @@ pub trait LayerTrait: core::AlgorithmTrait + crate::dnn::LayerTraitConst {
 	/// ## Parameters
 	/// * inputName: label of input blob
 	/// 
-	/// Each layer input and output can be labeled to easily identify them using "%<layer_name%>[.output_name]" notation.
+	/// Each layer input and output can be labeled to easily identify them using "%<layer_name%>\[.output_name\]" notation.
 	/// This method maps label of input blob to its index into input vector.
 	#[inline]
 	fn input_name_to_index(&mut self, input_name: &str) -> Result<i32> {
+		crate::core::validity::check("Layer", self.as_raw_mut_Layer())?;
 		extern_container_arg!(mut input_name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Layer_inputNameToIndex_String(self.as_raw_mut_Layer(), input_name.opencv_as_extern_mut(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Layer_inputNameToIndex_String(self.as_raw_mut_Layer(), input_name.opencv_as_extern_mut(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6655,12 +7881,13 @@ pub trait LayerTrait: core::AlgorithmTrait + crate::dnn::LayerTraitConst {
 	
 	/// Returns index of output blob in output array.
 	/// ## See also
-	/// inputNameToIndex()
+	/// `input_name_to_index`
 	#[inline]
 	fn output_name_to_index(&mut self, output_name: &str) -> Result<i32> {
+		crate::core::validity::check("Layer", self.as_raw_mut_Layer())?;
 		extern_container_arg!(output_name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Layer_outputNameToIndex_const_StringR(self.as_raw_mut_Layer(), output_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Layer_outputNameToIndex_const_StringR(self.as_raw_mut_Layer(), output_name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6670,11 +7897,12 @@ pub trait LayerTrait: core::AlgorithmTrait + crate::dnn::LayerTraitConst {
 	/// ## Parameters
 	/// * backendId: computation backend identifier.
 	/// ## See also
-	/// Backend
+	/// `Backend`
 	#[inline]
 	fn support_backend(&mut self, backend_id: i32) -> Result<bool> {
+		crate::core::validity::check("Layer", self.as_raw_mut_Layer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Layer_supportBackend_int(self.as_raw_mut_Layer(), backend_id, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Layer_supportBackend_int(self.as_raw_mut_Layer(), backend_id, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6684,7 +7912,7 @@ pub trait LayerTrait: core::AlgorithmTrait + crate::dnn::LayerTraitConst {
 	/// ## Parameters
 	/// * inputs: Input Halide buffers.
 	/// ## See also
-	/// BackendNode, BackendWrapper
+	/// [BackendNode](crate::dnn::BackendNode), [BackendWrapper](crate::dnn::BackendWrapper)
 	/// 
 	/// Input buffers should be exactly the same that will be used in forward invocations.
 	/// Despite we can use Halide::ImageParam based on input shape only,
@@ -6692,8 +7920,9 @@ pub trait LayerTrait: core::AlgorithmTrait + crate::dnn::LayerTraitConst {
 	/// Halide tests will be failed).
 	#[inline]
 	fn init_halide(&mut self, inputs: &core::Vector<core::Ptr<dyn crate::dnn::BackendWrapper>>) -> Result<core::Ptr<crate::dnn::BackendNode>> {
+		crate::core::validity::check("Layer", self.as_raw_mut_Layer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Layer_initHalide_const_vector_Ptr_BackendWrapper__R(self.as_raw_mut_Layer(), inputs.as_raw_VectorOfPtrOfBackendWrapper(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Layer_initHalide_const_vector_Ptr_BackendWrapper__R(self.as_raw_mut_Layer(), inputs.as_raw_VectorOfPtrOfBackendWrapper(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::BackendNode>::opencv_from_extern(ret) };
@@ -6702,8 +7931,9 @@ pub trait LayerTrait: core::AlgorithmTrait + crate::dnn::LayerTraitConst {
 	
 	#[inline]
 	fn init_inf_engine(&mut self, inputs: &core::Vector<core::Ptr<dyn crate::dnn::BackendWrapper>>) -> Result<core::Ptr<crate::dnn::BackendNode>> {
+		crate::core::validity::check("Layer", self.as_raw_mut_Layer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Layer_initInfEngine_const_vector_Ptr_BackendWrapper__R(self.as_raw_mut_Layer(), inputs.as_raw_VectorOfPtrOfBackendWrapper(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Layer_initInfEngine_const_vector_Ptr_BackendWrapper__R(self.as_raw_mut_Layer(), inputs.as_raw_VectorOfPtrOfBackendWrapper(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::BackendNode>::opencv_from_extern(ret) };
@@ -6712,8 +7942,9 @@ pub trait LayerTrait: core::AlgorithmTrait + crate::dnn::LayerTraitConst {
 	
 	#[inline]
 	fn init_ngraph(&mut self, inputs: &core::Vector<core::Ptr<dyn crate::dnn::BackendWrapper>>, nodes: &core::Vector<core::Ptr<crate::dnn::BackendNode>>) -> Result<core::Ptr<crate::dnn::BackendNode>> {
+		crate::core::validity::check("Layer", self.as_raw_mut_Layer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Layer_initNgraph_const_vector_Ptr_BackendWrapper__R_const_vector_Ptr_BackendNode__R(self.as_raw_mut_Layer(), inputs.as_raw_VectorOfPtrOfBackendWrapper(), nodes.as_raw_VectorOfPtrOfBackendNode(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Layer_initNgraph_const_vector_Ptr_BackendWrapper__R_const_vector_Ptr_BackendNode__R(self.as_raw_mut_Layer(), inputs.as_raw_VectorOfPtrOfBackendWrapper(), nodes.as_raw_VectorOfPtrOfBackendNode(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::BackendNode>::opencv_from_extern(ret) };
@@ -6722,8 +7953,9 @@ pub trait LayerTrait: core::AlgorithmTrait + crate::dnn::LayerTraitConst {
 	
 	#[inline]
 	fn init_vk_com(&mut self, inputs: &core::Vector<core::Ptr<dyn crate::dnn::BackendWrapper>>) -> Result<core::Ptr<crate::dnn::BackendNode>> {
+		crate::core::validity::check("Layer", self.as_raw_mut_Layer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Layer_initVkCom_const_vector_Ptr_BackendWrapper__R(self.as_raw_mut_Layer(), inputs.as_raw_VectorOfPtrOfBackendWrapper(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Layer_initVkCom_const_vector_Ptr_BackendWrapper__R(self.as_raw_mut_Layer(), inputs.as_raw_VectorOfPtrOfBackendWrapper(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::BackendNode>::opencv_from_extern(ret) };
@@ -6732,8 +7964,9 @@ pub trait LayerTrait: core::AlgorithmTrait + crate::dnn::LayerTraitConst {
 	
 	#[inline]
 	fn init_webnn(&mut self, inputs: &core::Vector<core::Ptr<dyn crate::dnn::BackendWrapper>>, nodes: &core::Vector<core::Ptr<crate::dnn::BackendNode>>) -> Result<core::Ptr<crate::dnn::BackendNode>> {
+		crate::core::validity::check("Layer", self.as_raw_mut_Layer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Layer_initWebnn_const_vector_Ptr_BackendWrapper__R_const_vector_Ptr_BackendNode__R(self.as_raw_mut_Layer(), inputs.as_raw_VectorOfPtrOfBackendWrapper(), nodes.as_raw_VectorOfPtrOfBackendNode(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Layer_initWebnn_const_vector_Ptr_BackendWrapper__R_const_vector_Ptr_BackendNode__R(self.as_raw_mut_Layer(), inputs.as_raw_VectorOfPtrOfBackendWrapper(), nodes.as_raw_VectorOfPtrOfBackendNode(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::BackendNode>::opencv_from_extern(ret) };
@@ -6748,8 +7981,9 @@ pub trait LayerTrait: core::AlgorithmTrait + crate::dnn::LayerTraitConst {
 	/// * outputs: layer outputs
 	#[inline]
 	unsafe fn init_cuda(&mut self, context: *mut c_void, inputs: &core::Vector<core::Ptr<dyn crate::dnn::BackendWrapper>>, outputs: &core::Vector<core::Ptr<dyn crate::dnn::BackendWrapper>>) -> Result<core::Ptr<crate::dnn::BackendNode>> {
+		crate::core::validity::check("Layer", self.as_raw_mut_Layer())?;
 		return_send!(via ocvrs_return);
-		{ sys::cv_dnn_Layer_initCUDA_voidX_const_vector_Ptr_BackendWrapper__R_const_vector_Ptr_BackendWrapper__R(self.as_raw_mut_Layer(), context, inputs.as_raw_VectorOfPtrOfBackendWrapper(), outputs.as_raw_VectorOfPtrOfBackendWrapper(), ocvrs_return.as_mut_ptr()) };
+		{ ffi_call!(cv_dnn_Layer_initCUDA_voidX_const_vector_Ptr_BackendWrapper__R_const_vector_Ptr_BackendWrapper__R(self.as_raw_mut_Layer(), context, inputs.as_raw_VectorOfPtrOfBackendWrapper(), outputs.as_raw_VectorOfPtrOfBackendWrapper(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = { core::Ptr::<crate::dnn::BackendNode>::opencv_from_extern(ret) };
@@ -6760,15 +7994,16 @@ pub trait LayerTrait: core::AlgorithmTrait + crate::dnn::LayerTraitConst {
 	/// ## Parameters
 	/// * node: Backend node of bottom layer.
 	/// ## See also
-	/// BackendNode
+	/// [BackendNode](crate::dnn::BackendNode)
 	/// 
 	/// Actual for graph-based backends. If layer attached successfully,
 	/// returns non-empty cv::Ptr to node of the same backend.
 	/// Fuse only over the last function.
 	#[inline]
 	fn try_attach(&mut self, node: &core::Ptr<crate::dnn::BackendNode>) -> Result<core::Ptr<crate::dnn::BackendNode>> {
+		crate::core::validity::check("Layer", self.as_raw_mut_Layer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Layer_tryAttach_const_Ptr_BackendNode_R(self.as_raw_mut_Layer(), node.as_raw_PtrOfBackendNode(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Layer_tryAttach_const_Ptr_BackendNode_R(self.as_raw_mut_Layer(), node.as_raw_PtrOfBackendNode(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::BackendNode>::opencv_from_extern(ret) };
@@ -6782,8 +8017,9 @@ pub trait LayerTrait: core::AlgorithmTrait + crate::dnn::LayerTraitConst {
 	/// Returns true if the activation layer has been attached successfully.
 	#[inline]
 	fn set_activation(&mut self, layer: &core::Ptr<crate::dnn::ActivationLayer>) -> Result<bool> {
+		crate::core::validity::check("Layer", self.as_raw_mut_Layer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Layer_setActivation_const_Ptr_ActivationLayer_R(self.as_raw_mut_Layer(), layer.as_raw_PtrOfActivationLayer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Layer_setActivation_const_Ptr_ActivationLayer_R(self.as_raw_mut_Layer(), layer.as_raw_PtrOfActivationLayer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6796,8 +8032,9 @@ pub trait LayerTrait: core::AlgorithmTrait + crate::dnn::LayerTraitConst {
 	/// True if fusion was performed.
 	#[inline]
 	fn try_fuse(&mut self, top: &mut core::Ptr<crate::dnn::Layer>) -> Result<bool> {
+		crate::core::validity::check("Layer", self.as_raw_mut_Layer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Layer_tryFuse_Ptr_Layer_R(self.as_raw_mut_Layer(), top.as_raw_mut_PtrOfLayer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Layer_tryFuse_Ptr_Layer_R(self.as_raw_mut_Layer(), top.as_raw_mut_PtrOfLayer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6806,8 +8043,9 @@ pub trait LayerTrait: core::AlgorithmTrait + crate::dnn::LayerTraitConst {
 	/// "Deattaches" all the layers, attached to particular layer.
 	#[inline]
 	fn unset_attached(&mut self) -> Result<()> {
+		crate::core::validity::check("Layer", self.as_raw_mut_Layer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Layer_unsetAttached(self.as_raw_mut_Layer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Layer_unsetAttached(self.as_raw_mut_Layer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6815,8 +8053,9 @@ pub trait LayerTrait: core::AlgorithmTrait + crate::dnn::LayerTraitConst {
 	
 	#[inline]
 	fn update_memory_shapes(&mut self, inputs: &core::Vector<crate::dnn::MatShape>) -> Result<bool> {
+		crate::core::validity::check("Layer", self.as_raw_mut_Layer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Layer_updateMemoryShapes_const_vector_MatShape_R(self.as_raw_mut_Layer(), inputs.as_raw_VectorOfMatShape(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Layer_updateMemoryShapes_const_vector_MatShape_R(self.as_raw_mut_Layer(), inputs.as_raw_VectorOfMatShape(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6824,8 +8063,9 @@ pub trait LayerTrait: core::AlgorithmTrait + crate::dnn::LayerTraitConst {
 	
 	#[inline]
 	fn set_params_from(&mut self, params: &crate::dnn::LayerParams) -> Result<()> {
+		crate::core::validity::check("Layer", self.as_raw_mut_Layer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Layer_setParamsFrom_const_LayerParamsR(self.as_raw_mut_Layer(), params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Layer_setParamsFrom_const_LayerParamsR(self.as_raw_mut_Layer(), params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6836,7 +8076,7 @@ pub trait LayerTrait: core::AlgorithmTrait + crate::dnn::LayerTraitConst {
 /// This interface class allows to build new Layers - are building blocks of networks.
 /// 
 /// Each class, derived from Layer, must implement allocate() methods to declare own outputs and forward() to compute outputs.
-/// Also before using the new layer into networks you must register your layer by using one of @ref dnnLayerFactory "LayerFactory" macros.
+/// Also before using the new layer into networks you must register your layer by using one of LayerFactory macros.
 pub struct Layer {
 	ptr: *mut c_void
 }
@@ -6846,7 +8086,10 @@ opencv_type_boxed! { Layer }
 impl Drop for Layer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Layer_delete(instance: *mut c_void); }
-		unsafe { cv_Layer_delete(self.as_raw_mut_Layer()) };
+		let ptr = self.as_raw_mut_Layer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Layer_delete(ptr) };
 	}
 }
 
@@ -6872,7 +8115,7 @@ impl Layer {
 	#[inline]
 	pub fn default() -> Result<crate::dnn::Layer> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Layer_Layer(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Layer_Layer(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::Layer::opencv_from_extern(ret) };
@@ -6882,7 +8125,7 @@ impl Layer {
 	#[inline]
 	pub fn new(params: &crate::dnn::LayerParams) -> Result<crate::dnn::Layer> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Layer_Layer_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Layer_Layer_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::Layer::opencv_from_extern(ret) };
@@ -7002,7 +8245,10 @@ opencv_type_boxed! { LayerFactory }
 impl Drop for LayerFactory {
 	fn drop(&mut self) {
 		extern "C" { fn cv_LayerFactory_delete(instance: *mut c_void); }
-		unsafe { cv_LayerFactory_delete(self.as_raw_mut_LayerFactory()) };
+		let ptr = self.as_raw_mut_LayerFactory();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_LayerFactory_delete(ptr) };
 	}
 }
 
@@ -7022,7 +8268,7 @@ impl LayerFactory {
 	pub fn register_layer(typ: &str, constructor: crate::dnn::LayerFactory_Constructor) -> Result<()> {
 		extern_container_arg!(typ);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_LayerFactory_registerLayer_const_StringR_Constructor(typ.opencv_as_extern(), constructor, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_LayerFactory_registerLayer_const_StringR_Constructor(typ.opencv_as_extern(), constructor, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7033,7 +8279,7 @@ impl LayerFactory {
 	pub fn unregister_layer(typ: &str) -> Result<()> {
 		extern_container_arg!(typ);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_LayerFactory_unregisterLayer_const_StringR(typ.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_LayerFactory_unregisterLayer_const_StringR(typ.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7049,7 +8295,7 @@ impl LayerFactory {
 	pub fn create_layer_instance(typ: &str, params: &mut crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::Layer>> {
 		extern_container_arg!(typ);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_LayerFactory_createLayerInstance_const_StringR_LayerParamsR(typ.opencv_as_extern(), params.as_raw_mut_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_LayerFactory_createLayerInstance_const_StringR_LayerParamsR(typ.opencv_as_extern(), params.as_raw_mut_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::Layer>::opencv_from_extern(ret) };
@@ -7068,27 +8314,54 @@ pub trait LayerParamsTraitConst: crate::dnn::DictTraitConst {
 	/// List of learned parameters stored as blobs.
 	#[inline]
 	fn blobs(&self) -> core::Vector<core::Mat> {
-		let ret = unsafe { sys::cv_dnn_LayerParams_getPropBlobs_const(self.as_raw_LayerParams()) };
+		crate::core::validity::debug_check("LayerParams", self.as_raw_LayerParams());
+		let ret = unsafe { ffi_call!(cv_dnn_LayerParams_getPropBlobs_const(self.as_raw_LayerParams())) };
 		let ret = unsafe { core::Vector::<core::Mat>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [blobs](LayerParamsTraitConst::blobs), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_blobs(&self) -> Result<core::Vector<core::Mat>> {
+		crate::core::validity::check("LayerParams", self.as_raw_LayerParams())?;
+		Ok(LayerParamsTraitConst::blobs(self))
+	}
+	
 	/// Name of the layer instance (optional, can be used internal purposes).
 	#[inline]
 	fn name(&self) -> String {
-		let ret = unsafe { sys::cv_dnn_LayerParams_getPropName_const(self.as_raw_LayerParams()) };
+		crate::core::validity::debug_check("LayerParams", self.as_raw_LayerParams());
+		let ret = unsafe { ffi_call!(cv_dnn_LayerParams_getPropName_const(self.as_raw_LayerParams())) };
 		let ret = unsafe { String::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [name](LayerParamsTraitConst::name), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_name(&self) -> Result<String> {
+		crate::core::validity::check("LayerParams", self.as_raw_LayerParams())?;
+		Ok(LayerParamsTraitConst::name(self))
+	}
+	
 	/// Type name which was used for creating layer by layer factory (optional).
 	#[inline]
 	fn typ(&self) -> String {
-		let ret = unsafe { sys::cv_dnn_LayerParams_getPropType_const(self.as_raw_LayerParams()) };
+		crate::core::validity::debug_check("LayerParams", self.as_raw_LayerParams());
+		let ret = unsafe { ffi_call!(cv_dnn_LayerParams_getPropType_const(self.as_raw_LayerParams())) };
 		let ret = unsafe { String::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [typ](LayerParamsTraitConst::typ), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_typ(&self) -> Result<String> {
+		crate::core::validity::check("LayerParams", self.as_raw_LayerParams())?;
+		Ok(LayerParamsTraitConst::typ(self))
+	}
+	
 }
 
 pub trait LayerParamsTrait: crate::dnn::DictTrait + crate::dnn::LayerParamsTraitConst {
@@ -7097,23 +8370,26 @@ pub trait LayerParamsTrait: crate::dnn::DictTrait + crate::dnn::LayerParamsTrait
 	/// List of learned parameters stored as blobs.
 	#[inline]
 	fn set_blobs(&mut self, mut val: core::Vector<core::Mat>) {
-		let ret = unsafe { sys::cv_dnn_LayerParams_setPropBlobs_vector_Mat_(self.as_raw_mut_LayerParams(), val.as_raw_mut_VectorOfMat()) };
+		crate::core::validity::debug_check("LayerParams", self.as_raw_mut_LayerParams());
+		let ret = unsafe { ffi_call!(cv_dnn_LayerParams_setPropBlobs_vector_Mat_(self.as_raw_mut_LayerParams(), val.as_raw_mut_VectorOfMat())) };
 		ret
 	}
 	
 	/// Name of the layer instance (optional, can be used internal purposes).
 	#[inline]
 	fn set_name(&mut self, val: &str) {
+		crate::core::validity::debug_check("LayerParams", self.as_raw_mut_LayerParams());
 		extern_container_arg!(nofail mut val);
-		let ret = unsafe { sys::cv_dnn_LayerParams_setPropName_String(self.as_raw_mut_LayerParams(), val.opencv_as_extern_mut()) };
+		let ret = unsafe { ffi_call!(cv_dnn_LayerParams_setPropName_String(self.as_raw_mut_LayerParams(), val.opencv_as_extern_mut())) };
 		ret
 	}
 	
 	/// Type name which was used for creating layer by layer factory (optional).
 	#[inline]
 	fn set_type(&mut self, val: &str) {
+		crate::core::validity::debug_check("LayerParams", self.as_raw_mut_LayerParams());
 		extern_container_arg!(nofail mut val);
-		let ret = unsafe { sys::cv_dnn_LayerParams_setPropType_String(self.as_raw_mut_LayerParams(), val.opencv_as_extern_mut()) };
+		let ret = unsafe { ffi_call!(cv_dnn_LayerParams_setPropType_String(self.as_raw_mut_LayerParams(), val.opencv_as_extern_mut())) };
 		ret
 	}
 	
@@ -7132,7 +8408,10 @@ opencv_type_boxed! { LayerParams }
 impl Drop for LayerParams {
 	fn drop(&mut self) {
 		extern "C" { fn cv_LayerParams_delete(instance: *mut c_void); }
-		unsafe { cv_LayerParams_delete(self.as_raw_mut_LayerParams()) };
+		let ptr = self.as_raw_mut_LayerParams();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_LayerParams_delete(ptr) };
 	}
 }
 
@@ -7178,7 +8457,10 @@ opencv_type_boxed! { LogLayer }
 impl Drop for LogLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_LogLayer_delete(instance: *mut c_void); }
-		unsafe { cv_LogLayer_delete(self.as_raw_mut_LogLayer()) };
+		let ptr = self.as_raw_mut_LogLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_LogLayer_delete(ptr) };
 	}
 }
 
@@ -7220,7 +8502,7 @@ impl LogLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::LogLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_LogLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_LogLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::LogLayer>::opencv_from_extern(ret) };
@@ -7240,22 +8522,49 @@ pub trait MVNLayerTraitConst: crate::dnn::LayerTraitConst {
 
 	#[inline]
 	fn eps(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_MVNLayer_getPropEps_const(self.as_raw_MVNLayer()) };
+		crate::core::validity::debug_check("MVNLayer", self.as_raw_MVNLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_MVNLayer_getPropEps_const(self.as_raw_MVNLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [eps](MVNLayerTraitConst::eps), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_eps(&self) -> Result<f32> {
+		crate::core::validity::check("MVNLayer", self.as_raw_MVNLayer())?;
+		Ok(MVNLayerTraitConst::eps(self))
+	}
+	
 	#[inline]
 	fn norm_variance(&self) -> bool {
-		let ret = unsafe { sys::cv_dnn_MVNLayer_getPropNormVariance_const(self.as_raw_MVNLayer()) };
+		crate::core::validity::debug_check("MVNLayer", self.as_raw_MVNLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_MVNLayer_getPropNormVariance_const(self.as_raw_MVNLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [norm_variance](MVNLayerTraitConst::norm_variance), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_norm_variance(&self) -> Result<bool> {
+		crate::core::validity::check("MVNLayer", self.as_raw_MVNLayer())?;
+		Ok(MVNLayerTraitConst::norm_variance(self))
+	}
+	
 	#[inline]
 	fn across_channels(&self) -> bool {
-		let ret = unsafe { sys::cv_dnn_MVNLayer_getPropAcrossChannels_const(self.as_raw_MVNLayer()) };
+		crate::core::validity::debug_check("MVNLayer", self.as_raw_MVNLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_MVNLayer_getPropAcrossChannels_const(self.as_raw_MVNLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [across_channels](MVNLayerTraitConst::across_channels), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_across_channels(&self) -> Result<bool> {
+		crate::core::validity::check("MVNLayer", self.as_raw_MVNLayer())?;
+		Ok(MVNLayerTraitConst::across_channels(self))
+	}
+	
 }
 
 pub trait MVNLayerTrait: crate::dnn::LayerTrait + crate::dnn::MVNLayerTraitConst {
@@ -7263,19 +8572,22 @@ pub trait MVNLayerTrait: crate::dnn::LayerTrait + crate::dnn::MVNLayerTraitConst
 
 	#[inline]
 	fn set_eps(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_MVNLayer_setPropEps_float(self.as_raw_mut_MVNLayer(), val) };
+		crate::core::validity::debug_check("MVNLayer", self.as_raw_mut_MVNLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_MVNLayer_setPropEps_float(self.as_raw_mut_MVNLayer(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_norm_variance(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_dnn_MVNLayer_setPropNormVariance_bool(self.as_raw_mut_MVNLayer(), val) };
+		crate::core::validity::debug_check("MVNLayer", self.as_raw_mut_MVNLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_MVNLayer_setPropNormVariance_bool(self.as_raw_mut_MVNLayer(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_across_channels(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_dnn_MVNLayer_setPropAcrossChannels_bool(self.as_raw_mut_MVNLayer(), val) };
+		crate::core::validity::debug_check("MVNLayer", self.as_raw_mut_MVNLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_MVNLayer_setPropAcrossChannels_bool(self.as_raw_mut_MVNLayer(), val)) };
 		ret
 	}
 	
@@ -7290,7 +8602,10 @@ opencv_type_boxed! { MVNLayer }
 impl Drop for MVNLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MVNLayer_delete(instance: *mut c_void); }
-		unsafe { cv_MVNLayer_delete(self.as_raw_mut_MVNLayer()) };
+		let ptr = self.as_raw_mut_MVNLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_MVNLayer_delete(ptr) };
 	}
 }
 
@@ -7324,7 +8639,7 @@ impl MVNLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::MVNLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_MVNLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_MVNLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::MVNLayer>::opencv_from_extern(ret) };
@@ -7342,28 +8657,55 @@ pub trait MaxUnpoolLayerTraitConst: crate::dnn::LayerTraitConst {
 
 	#[inline]
 	fn pool_kernel(&self) -> core::Size {
+		crate::core::validity::debug_check("MaxUnpoolLayer", self.as_raw_MaxUnpoolLayer());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_MaxUnpoolLayer_getPropPoolKernel_const(self.as_raw_MaxUnpoolLayer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_MaxUnpoolLayer_getPropPoolKernel_const(self.as_raw_MaxUnpoolLayer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [pool_kernel](MaxUnpoolLayerTraitConst::pool_kernel), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pool_kernel(&self) -> Result<core::Size> {
+		crate::core::validity::check("MaxUnpoolLayer", self.as_raw_MaxUnpoolLayer())?;
+		Ok(MaxUnpoolLayerTraitConst::pool_kernel(self))
+	}
+	
 	#[inline]
 	fn pool_pad(&self) -> core::Size {
+		crate::core::validity::debug_check("MaxUnpoolLayer", self.as_raw_MaxUnpoolLayer());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_MaxUnpoolLayer_getPropPoolPad_const(self.as_raw_MaxUnpoolLayer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_MaxUnpoolLayer_getPropPoolPad_const(self.as_raw_MaxUnpoolLayer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [pool_pad](MaxUnpoolLayerTraitConst::pool_pad), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pool_pad(&self) -> Result<core::Size> {
+		crate::core::validity::check("MaxUnpoolLayer", self.as_raw_MaxUnpoolLayer())?;
+		Ok(MaxUnpoolLayerTraitConst::pool_pad(self))
+	}
+	
 	#[inline]
 	fn pool_stride(&self) -> core::Size {
+		crate::core::validity::debug_check("MaxUnpoolLayer", self.as_raw_MaxUnpoolLayer());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_MaxUnpoolLayer_getPropPoolStride_const(self.as_raw_MaxUnpoolLayer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_MaxUnpoolLayer_getPropPoolStride_const(self.as_raw_MaxUnpoolLayer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [pool_stride](MaxUnpoolLayerTraitConst::pool_stride), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pool_stride(&self) -> Result<core::Size> {
+		crate::core::validity::check("MaxUnpoolLayer", self.as_raw_MaxUnpoolLayer())?;
+		Ok(MaxUnpoolLayerTraitConst::pool_stride(self))
+	}
+	
 }
 
 pub trait MaxUnpoolLayerTrait: crate::dnn::LayerTrait + crate::dnn::MaxUnpoolLayerTraitConst {
@@ -7371,19 +8713,22 @@ pub trait MaxUnpoolLayerTrait: crate::dnn::LayerTrait + crate::dnn::MaxUnpoolLay
 
 	#[inline]
 	fn set_pool_kernel(&mut self, val: core::Size) {
-		let ret = unsafe { sys::cv_dnn_MaxUnpoolLayer_setPropPoolKernel_Size(self.as_raw_mut_MaxUnpoolLayer(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("MaxUnpoolLayer", self.as_raw_mut_MaxUnpoolLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_MaxUnpoolLayer_setPropPoolKernel_Size(self.as_raw_mut_MaxUnpoolLayer(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_pool_pad(&mut self, val: core::Size) {
-		let ret = unsafe { sys::cv_dnn_MaxUnpoolLayer_setPropPoolPad_Size(self.as_raw_mut_MaxUnpoolLayer(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("MaxUnpoolLayer", self.as_raw_mut_MaxUnpoolLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_MaxUnpoolLayer_setPropPoolPad_Size(self.as_raw_mut_MaxUnpoolLayer(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_pool_stride(&mut self, val: core::Size) {
-		let ret = unsafe { sys::cv_dnn_MaxUnpoolLayer_setPropPoolStride_Size(self.as_raw_mut_MaxUnpoolLayer(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("MaxUnpoolLayer", self.as_raw_mut_MaxUnpoolLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_MaxUnpoolLayer_setPropPoolStride_Size(self.as_raw_mut_MaxUnpoolLayer(), val.opencv_as_extern())) };
 		ret
 	}
 	
@@ -7398,7 +8743,10 @@ opencv_type_boxed! { MaxUnpoolLayer }
 impl Drop for MaxUnpoolLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MaxUnpoolLayer_delete(instance: *mut c_void); }
-		unsafe { cv_MaxUnpoolLayer_delete(self.as_raw_mut_MaxUnpoolLayer()) };
+		let ptr = self.as_raw_mut_MaxUnpoolLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_MaxUnpoolLayer_delete(ptr) };
 	}
 }
 
@@ -7432,7 +8780,7 @@ impl MaxUnpoolLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::MaxUnpoolLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_MaxUnpoolLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_MaxUnpoolLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::MaxUnpoolLayer>::opencv_from_extern(ret) };
@@ -7464,7 +8812,10 @@ opencv_type_boxed! { MishLayer }
 impl Drop for MishLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MishLayer_delete(instance: *mut c_void); }
-		unsafe { cv_MishLayer_delete(self.as_raw_mut_MishLayer()) };
+		let ptr = self.as_raw_mut_MishLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_MishLayer_delete(ptr) };
 	}
 }
 
@@ -7506,7 +8857,7 @@ impl MishLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::MishLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_MishLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_MishLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::MishLayer>::opencv_from_extern(ret) };
@@ -7532,13 +8883,14 @@ pub trait ModelTraitConst {
 	/// Given the @p input frame, create input blob, run net and return the output @p blobs.
 	/// ## Parameters
 	/// * frame: The input image.
-	/// * outs:[out] Allocated output blobs, which will store results of the computation.
+	/// * outs:\[out\] Allocated output blobs, which will store results of the computation.
 	#[inline]
 	fn predict(&self, frame: &dyn core::ToInputArray, outs: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("Model", self.as_raw_Model())?;
 		input_array_arg!(frame);
 		output_array_arg!(outs);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Model_predict_const_const__InputArrayR_const__OutputArrayR(self.as_raw_Model(), frame.as_raw__InputArray(), outs.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Model_predict_const_const__InputArrayR_const__OutputArrayR(self.as_raw_Model(), frame.as_raw__InputArray(), outs.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7546,8 +8898,9 @@ pub trait ModelTraitConst {
 	
 	#[inline]
 	fn get_network_(&self) -> Result<crate::dnn::Net> {
+		crate::core::validity::check("Model", self.as_raw_Model())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Model_getNetwork__const(self.as_raw_Model(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Model_getNetwork__const(self.as_raw_Model(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::Net::opencv_from_extern(ret) };
@@ -7566,8 +8919,9 @@ pub trait ModelTrait: crate::dnn::ModelTraitConst {
 	/// Note: If shape of the new blob less than 0, then frame size not change.
 	#[inline]
 	fn set_input_size(&mut self, size: core::Size) -> Result<crate::dnn::Model> {
+		crate::core::validity::check("Model", self.as_raw_mut_Model())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Model_setInputSize_const_SizeR(self.as_raw_mut_Model(), &size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Model_setInputSize_const_SizeR(self.as_raw_mut_Model(), &size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::Model::opencv_from_extern(ret) };
@@ -7586,8 +8940,9 @@ pub trait ModelTrait: crate::dnn::ModelTraitConst {
 	/// * height: New input height.
 	#[inline]
 	fn set_input_size_1(&mut self, width: i32, height: i32) -> Result<crate::dnn::Model> {
+		crate::core::validity::check("Model", self.as_raw_mut_Model())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Model_setInputSize_int_int(self.as_raw_mut_Model(), width, height, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Model_setInputSize_int_int(self.as_raw_mut_Model(), width, height, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::Model::opencv_from_extern(ret) };
@@ -7599,8 +8954,9 @@ pub trait ModelTrait: crate::dnn::ModelTraitConst {
 	/// * mean: Scalar with mean values which are subtracted from channels.
 	#[inline]
 	fn set_input_mean(&mut self, mean: core::Scalar) -> Result<crate::dnn::Model> {
+		crate::core::validity::check("Model", self.as_raw_mut_Model())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Model_setInputMean_const_ScalarR(self.as_raw_mut_Model(), &mean, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Model_setInputMean_const_ScalarR(self.as_raw_mut_Model(), &mean, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::Model::opencv_from_extern(ret) };
@@ -7612,8 +8968,9 @@ pub trait ModelTrait: crate::dnn::ModelTraitConst {
 	/// * scale: Multiplier for frame values.
 	#[inline]
 	fn set_input_scale(&mut self, scale: f64) -> Result<crate::dnn::Model> {
+		crate::core::validity::check("Model", self.as_raw_mut_Model())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Model_setInputScale_double(self.as_raw_mut_Model(), scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Model_setInputScale_double(self.as_raw_mut_Model(), scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::Model::opencv_from_extern(ret) };
@@ -7625,8 +8982,9 @@ pub trait ModelTrait: crate::dnn::ModelTraitConst {
 	/// * crop: Flag which indicates whether image will be cropped after resize or not.
 	#[inline]
 	fn set_input_crop(&mut self, crop: bool) -> Result<crate::dnn::Model> {
+		crate::core::validity::check("Model", self.as_raw_mut_Model())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Model_setInputCrop_bool(self.as_raw_mut_Model(), crop, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Model_setInputCrop_bool(self.as_raw_mut_Model(), crop, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::Model::opencv_from_extern(ret) };
@@ -7638,8 +8996,9 @@ pub trait ModelTrait: crate::dnn::ModelTraitConst {
 	/// * swapRB: Flag which indicates that swap first and last channels.
 	#[inline]
 	fn set_input_swap_rb(&mut self, swap_rb: bool) -> Result<crate::dnn::Model> {
+		crate::core::validity::check("Model", self.as_raw_mut_Model())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Model_setInputSwapRB_bool(self.as_raw_mut_Model(), swap_rb, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Model_setInputSwapRB_bool(self.as_raw_mut_Model(), swap_rb, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::Model::opencv_from_extern(ret) };
@@ -7663,19 +9022,21 @@ pub trait ModelTrait: crate::dnn::ModelTraitConst {
 	/// * crop: false
 	#[inline]
 	fn set_input_params(&mut self, scale: f64, size: core::Size, mean: core::Scalar, swap_rb: bool, crop: bool) -> Result<()> {
+		crate::core::validity::check("Model", self.as_raw_mut_Model())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Model_setInputParams_double_const_SizeR_const_ScalarR_bool_bool(self.as_raw_mut_Model(), scale, &size, &mean, swap_rb, crop, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Model_setInputParams_double_const_SizeR_const_ScalarR_bool_bool(self.as_raw_mut_Model(), scale, &size, &mean, swap_rb, crop, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// Net::setPreferableBackend
+	/// [Net::set_preferable_backend](crate::dnn::Net)
 	#[inline]
 	fn set_preferable_backend(&mut self, backend_id: crate::dnn::Backend) -> Result<crate::dnn::Model> {
+		crate::core::validity::check("Model", self.as_raw_mut_Model())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Model_setPreferableBackend_Backend(self.as_raw_mut_Model(), backend_id, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Model_setPreferableBackend_Backend(self.as_raw_mut_Model(), backend_id, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::Model::opencv_from_extern(ret) };
@@ -7683,11 +9044,12 @@ pub trait ModelTrait: crate::dnn::ModelTraitConst {
 	}
 	
 	/// ## See also
-	/// Net::setPreferableTarget
+	/// [Net::set_preferable_target](crate::dnn::Net)
 	#[inline]
 	fn set_preferable_target(&mut self, target_id: crate::dnn::Target) -> Result<crate::dnn::Model> {
+		crate::core::validity::check("Model", self.as_raw_mut_Model())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Model_setPreferableTarget_Target(self.as_raw_mut_Model(), target_id, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Model_setPreferableTarget_Target(self.as_raw_mut_Model(), target_id, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::Model::opencv_from_extern(ret) };
@@ -7696,8 +9058,9 @@ pub trait ModelTrait: crate::dnn::ModelTraitConst {
 	
 	#[inline]
 	fn get_network__1(&mut self) -> Result<crate::dnn::Net> {
+		crate::core::validity::check("Model", self.as_raw_mut_Model())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Model_getNetwork_(self.as_raw_mut_Model(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Model_getNetwork_(self.as_raw_mut_Model(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::Net::opencv_from_extern(ret) };
@@ -7706,6 +9069,66 @@ pub trait ModelTrait: crate::dnn::ModelTraitConst {
 	
 }
 
+/// Builder for `ModelTrait::set_input_params()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct Model_SetInputParamsBuilder<'a, T: ?Sized + ModelTrait> {
+	instance: &'a mut T,
+	scale: f64,
+	size: core::Size,
+	mean: core::Scalar,
+	swap_rb: bool,
+	crop: bool,
+}
+
+impl<'a, T: ?Sized + ModelTrait> Model_SetInputParamsBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T) -> Self {
+		Self { instance, scale: 1.0, size: core::Size::default(), mean: core::Scalar::default(), swap_rb: false, crop: false }
+	}
+	
+	/// C++ default: `1.0`
+	#[inline]
+	pub fn with_scale(mut self, scale: f64) -> Self {
+		self.scale = scale;
+		self
+	}
+	
+	/// C++ default: `Size()`
+	#[inline]
+	pub fn with_size(mut self, size: core::Size) -> Self {
+		self.size = size;
+		self
+	}
+	
+	/// C++ default: `Scalar()`
+	#[inline]
+	pub fn with_mean(mut self, mean: core::Scalar) -> Self {
+		self.mean = mean;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_swap_rb(mut self, swap_rb: bool) -> Self {
+		self.swap_rb = swap_rb;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_crop(mut self, crop: bool) -> Self {
+		self.crop = crop;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		ModelTrait::set_input_params(self.instance, self.scale, self.size, self.mean, self.swap_rb, self.crop)
+	}
+}
+
 /// This class is presented high-level API for neural networks.
 /// 
 /// Model allows to set params for preprocessing input image.
@@ -7720,7 +9143,10 @@ opencv_type_boxed! { Model }
 impl Drop for Model {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Model_delete(instance: *mut c_void); }
-		unsafe { cv_Model_delete(self.as_raw_mut_Model()) };
+		let ptr = self.as_raw_mut_Model();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Model_delete(ptr) };
 	}
 }
 
@@ -7738,7 +9164,7 @@ impl Model {
 	#[inline]
 	pub fn default() -> Result<crate::dnn::Model> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Model_Model(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Model_Model(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::Model::opencv_from_extern(ret) };
@@ -7747,14 +9173,14 @@ impl Model {
 	
 	#[inline]
 	pub fn copy(unnamed: &crate::dnn::Model) -> crate::dnn::Model {
-		let ret = unsafe { sys::cv_dnn_Model_Model_const_ModelR(unnamed.as_raw_Model()) };
+		let ret = unsafe { ffi_call!(cv_dnn_Model_Model_const_ModelR(unnamed.as_raw_Model())) };
 		let ret = unsafe { crate::dnn::Model::opencv_from_extern(ret) };
 		ret
 	}
 	
 	#[inline]
 	pub fn copy_mut(unnamed: &mut crate::dnn::Model) -> crate::dnn::Model {
-		let ret = unsafe { sys::cv_dnn_Model_Model_ModelR(unnamed.as_raw_mut_Model()) };
+		let ret = unsafe { ffi_call!(cv_dnn_Model_Model_ModelR(unnamed.as_raw_mut_Model())) };
 		let ret = unsafe { crate::dnn::Model::opencv_from_extern(ret) };
 		ret
 	}
@@ -7772,7 +9198,7 @@ impl Model {
 		extern_container_arg!(model);
 		extern_container_arg!(config);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Model_Model_const_StringR_const_StringR(model.opencv_as_extern(), config.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Model_Model_const_StringR_const_StringR(model.opencv_as_extern(), config.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::Model::opencv_from_extern(ret) };
@@ -7785,7 +9211,7 @@ impl Model {
 	#[inline]
 	pub fn new_1(network: &crate::dnn::Net) -> Result<crate::dnn::Model> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Model_Model_const_NetR(network.as_raw_Net(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Model_Model_const_NetR(network.as_raw_Net(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::Model::opencv_from_extern(ret) };
@@ -7809,8 +9235,9 @@ pub trait NetTraitConst {
 	/// Returns true if there are no layers in the network.
 	#[inline]
 	fn empty(&self) -> Result<bool> {
+		crate::core::validity::check("Net", self.as_raw_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_empty_const(self.as_raw_Net(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_empty_const(self.as_raw_Net(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7818,8 +9245,9 @@ pub trait NetTraitConst {
 	
 	#[inline]
 	fn get_layer_names(&self) -> Result<core::Vector<String>> {
+		crate::core::validity::check("Net", self.as_raw_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_getLayerNames_const(self.as_raw_Net(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_getLayerNames_const(self.as_raw_Net(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<String>::opencv_from_extern(ret) };
@@ -7832,8 +9260,9 @@ pub trait NetTraitConst {
 	/// * zeropoints: output parameter for returning input zeropoints.
 	#[inline]
 	fn get_input_details(&self, scales: &mut core::Vector<f32>, zeropoints: &mut core::Vector<i32>) -> Result<()> {
+		crate::core::validity::check("Net", self.as_raw_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_getInputDetails_const_vector_float_R_vector_int_R(self.as_raw_Net(), scales.as_raw_mut_VectorOff32(), zeropoints.as_raw_mut_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_getInputDetails_const_vector_float_R_vector_int_R(self.as_raw_Net(), scales.as_raw_mut_VectorOff32(), zeropoints.as_raw_mut_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7845,8 +9274,9 @@ pub trait NetTraitConst {
 	/// * zeropoints: output parameter for returning output zeropoints.
 	#[inline]
 	fn get_output_details(&self, scales: &mut core::Vector<f32>, zeropoints: &mut core::Vector<i32>) -> Result<()> {
+		crate::core::validity::check("Net", self.as_raw_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_getOutputDetails_const_vector_float_R_vector_int_R(self.as_raw_Net(), scales.as_raw_mut_VectorOff32(), zeropoints.as_raw_mut_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_getOutputDetails_const_vector_float_R_vector_int_R(self.as_raw_Net(), scales.as_raw_mut_VectorOff32(), zeropoints.as_raw_mut_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7855,8 +9285,9 @@ pub trait NetTraitConst {
 	/// Returns indexes of layers with unconnected outputs.
 	#[inline]
 	fn get_unconnected_out_layers(&self) -> Result<core::Vector<i32>> {
+		crate::core::validity::check("Net", self.as_raw_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_getUnconnectedOutLayers_const(self.as_raw_Net(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_getUnconnectedOutLayers_const(self.as_raw_Net(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<i32>::opencv_from_extern(ret) };
@@ -7866,8 +9297,9 @@ pub trait NetTraitConst {
 	/// Returns names of layers with unconnected outputs.
 	#[inline]
 	fn get_unconnected_out_layers_names(&self) -> Result<core::Vector<String>> {
+		crate::core::validity::check("Net", self.as_raw_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_getUnconnectedOutLayersNames_const(self.as_raw_Net(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_getUnconnectedOutLayersNames_const(self.as_raw_Net(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<String>::opencv_from_extern(ret) };
@@ -7885,8 +9317,9 @@ pub trait NetTraitConst {
 	/// order is the same as in layersIds
 	#[inline]
 	fn get_layers_shapes(&self, net_input_shapes: &core::Vector<crate::dnn::MatShape>, layers_ids: &mut core::Vector<i32>, in_layers_shapes: &mut core::Vector<core::Vector<crate::dnn::MatShape>>, out_layers_shapes: &mut core::Vector<core::Vector<crate::dnn::MatShape>>) -> Result<()> {
+		crate::core::validity::check("Net", self.as_raw_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_getLayersShapes_const_const_vector_MatShape_R_vector_int_R_vector_vector_MatShape__R_vector_vector_MatShape__R(self.as_raw_Net(), net_input_shapes.as_raw_VectorOfMatShape(), layers_ids.as_raw_mut_VectorOfi32(), in_layers_shapes.as_raw_mut_VectorOfVectorOfMatShape(), out_layers_shapes.as_raw_mut_VectorOfVectorOfMatShape(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_getLayersShapes_const_const_vector_MatShape_R_vector_int_R_vector_vector_MatShape__R_vector_vector_MatShape__R(self.as_raw_Net(), net_input_shapes.as_raw_VectorOfMatShape(), layers_ids.as_raw_mut_VectorOfi32(), in_layers_shapes.as_raw_mut_VectorOfVectorOfMatShape(), out_layers_shapes.as_raw_mut_VectorOfVectorOfMatShape(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7905,8 +9338,9 @@ pub trait NetTraitConst {
 	/// ## Overloaded parameters
 	#[inline]
 	fn get_layers_shapes_1(&self, net_input_shape: &crate::dnn::MatShape, layers_ids: &mut core::Vector<i32>, in_layers_shapes: &mut core::Vector<core::Vector<crate::dnn::MatShape>>, out_layers_shapes: &mut core::Vector<core::Vector<crate::dnn::MatShape>>) -> Result<()> {
+		crate::core::validity::check("Net", self.as_raw_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_getLayersShapes_const_const_MatShapeR_vector_int_R_vector_vector_MatShape__R_vector_vector_MatShape__R(self.as_raw_Net(), net_input_shape.as_raw_VectorOfi32(), layers_ids.as_raw_mut_VectorOfi32(), in_layers_shapes.as_raw_mut_VectorOfVectorOfMatShape(), out_layers_shapes.as_raw_mut_VectorOfVectorOfMatShape(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_getLayersShapes_const_const_MatShapeR_vector_int_R_vector_vector_MatShape__R_vector_vector_MatShape__R(self.as_raw_Net(), net_input_shape.as_raw_VectorOfi32(), layers_ids.as_raw_mut_VectorOfi32(), in_layers_shapes.as_raw_mut_VectorOfVectorOfMatShape(), out_layers_shapes.as_raw_mut_VectorOfVectorOfMatShape(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7923,8 +9357,9 @@ pub trait NetTraitConst {
 	/// order is the same as in layersIds
 	#[inline]
 	fn get_layer_shapes(&self, net_input_shape: &crate::dnn::MatShape, layer_id: i32, in_layer_shapes: &mut core::Vector<crate::dnn::MatShape>, out_layer_shapes: &mut core::Vector<crate::dnn::MatShape>) -> Result<()> {
+		crate::core::validity::check("Net", self.as_raw_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_getLayerShapes_const_const_MatShapeR_const_int_vector_MatShape_R_vector_MatShape_R(self.as_raw_Net(), net_input_shape.as_raw_VectorOfi32(), layer_id, in_layer_shapes.as_raw_mut_VectorOfMatShape(), out_layer_shapes.as_raw_mut_VectorOfMatShape(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_getLayerShapes_const_const_MatShapeR_const_int_vector_MatShape_R_vector_MatShape_R(self.as_raw_Net(), net_input_shape.as_raw_VectorOfi32(), layer_id, in_layer_shapes.as_raw_mut_VectorOfMatShape(), out_layer_shapes.as_raw_mut_VectorOfMatShape(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7943,8 +9378,9 @@ pub trait NetTraitConst {
 	/// ## Overloaded parameters
 	#[inline]
 	fn get_layer_shapes_1(&self, net_input_shapes: &core::Vector<crate::dnn::MatShape>, layer_id: i32, in_layer_shapes: &mut core::Vector<crate::dnn::MatShape>, out_layer_shapes: &mut core::Vector<crate::dnn::MatShape>) -> Result<()> {
+		crate::core::validity::check("Net", self.as_raw_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_getLayerShapes_const_const_vector_MatShape_R_const_int_vector_MatShape_R_vector_MatShape_R(self.as_raw_Net(), net_input_shapes.as_raw_VectorOfMatShape(), layer_id, in_layer_shapes.as_raw_mut_VectorOfMatShape(), out_layer_shapes.as_raw_mut_VectorOfMatShape(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_getLayerShapes_const_const_vector_MatShape_R_const_int_vector_MatShape_R_vector_MatShape_R(self.as_raw_Net(), net_input_shapes.as_raw_VectorOfMatShape(), layer_id, in_layer_shapes.as_raw_mut_VectorOfMatShape(), out_layer_shapes.as_raw_mut_VectorOfMatShape(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7957,8 +9393,9 @@ pub trait NetTraitConst {
 	/// computed FLOP.
 	#[inline]
 	fn get_flops(&self, net_input_shapes: &core::Vector<crate::dnn::MatShape>) -> Result<i64> {
+		crate::core::validity::check("Net", self.as_raw_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_getFLOPS_const_const_vector_MatShape_R(self.as_raw_Net(), net_input_shapes.as_raw_VectorOfMatShape(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_getFLOPS_const_const_vector_MatShape_R(self.as_raw_Net(), net_input_shapes.as_raw_VectorOfMatShape(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7973,8 +9410,9 @@ pub trait NetTraitConst {
 	/// ## Overloaded parameters
 	#[inline]
 	fn get_flops_1(&self, net_input_shape: &crate::dnn::MatShape) -> Result<i64> {
+		crate::core::validity::check("Net", self.as_raw_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_getFLOPS_const_const_MatShapeR(self.as_raw_Net(), net_input_shape.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_getFLOPS_const_const_MatShapeR(self.as_raw_Net(), net_input_shape.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7989,8 +9427,9 @@ pub trait NetTraitConst {
 	/// ## Overloaded parameters
 	#[inline]
 	fn get_flops_2(&self, layer_id: i32, net_input_shapes: &core::Vector<crate::dnn::MatShape>) -> Result<i64> {
+		crate::core::validity::check("Net", self.as_raw_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_getFLOPS_const_const_int_const_vector_MatShape_R(self.as_raw_Net(), layer_id, net_input_shapes.as_raw_VectorOfMatShape(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_getFLOPS_const_const_int_const_vector_MatShape_R(self.as_raw_Net(), layer_id, net_input_shapes.as_raw_VectorOfMatShape(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8005,8 +9444,9 @@ pub trait NetTraitConst {
 	/// ## Overloaded parameters
 	#[inline]
 	fn get_flops_3(&self, layer_id: i32, net_input_shape: &crate::dnn::MatShape) -> Result<i64> {
+		crate::core::validity::check("Net", self.as_raw_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_getFLOPS_const_const_int_const_MatShapeR(self.as_raw_Net(), layer_id, net_input_shape.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_getFLOPS_const_const_int_const_MatShapeR(self.as_raw_Net(), layer_id, net_input_shape.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8017,8 +9457,9 @@ pub trait NetTraitConst {
 	/// * layersTypes: output parameter for returning types.
 	#[inline]
 	fn get_layer_types(&self, layers_types: &mut core::Vector<String>) -> Result<()> {
+		crate::core::validity::check("Net", self.as_raw_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_getLayerTypes_const_vector_String_R(self.as_raw_Net(), layers_types.as_raw_mut_VectorOfString(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_getLayerTypes_const_vector_String_R(self.as_raw_Net(), layers_types.as_raw_mut_VectorOfString(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8031,9 +9472,10 @@ pub trait NetTraitConst {
 	/// count of layers
 	#[inline]
 	fn get_layers_count(&self, layer_type: &str) -> Result<i32> {
+		crate::core::validity::check("Net", self.as_raw_Net())?;
 		extern_container_arg!(layer_type);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_getLayersCount_const_const_StringR(self.as_raw_Net(), layer_type.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_getLayersCount_const_const_StringR(self.as_raw_Net(), layer_type.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8047,8 +9489,9 @@ pub trait NetTraitConst {
 	/// * blobs: output parameter to store resulting bytes for intermediate blobs.
 	#[inline]
 	fn get_memory_consumption(&self, net_input_shapes: &core::Vector<crate::dnn::MatShape>, weights: &mut size_t, blobs: &mut size_t) -> Result<()> {
+		crate::core::validity::check("Net", self.as_raw_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_getMemoryConsumption_const_const_vector_MatShape_R_size_tR_size_tR(self.as_raw_Net(), net_input_shapes.as_raw_VectorOfMatShape(), weights, blobs, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_getMemoryConsumption_const_const_vector_MatShape_R_size_tR_size_tR(self.as_raw_Net(), net_input_shapes.as_raw_VectorOfMatShape(), weights, blobs, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8065,8 +9508,9 @@ pub trait NetTraitConst {
 	/// ## Overloaded parameters
 	#[inline]
 	fn get_memory_consumption_1(&self, net_input_shape: &crate::dnn::MatShape, weights: &mut size_t, blobs: &mut size_t) -> Result<()> {
+		crate::core::validity::check("Net", self.as_raw_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_getMemoryConsumption_const_const_MatShapeR_size_tR_size_tR(self.as_raw_Net(), net_input_shape.as_raw_VectorOfi32(), weights, blobs, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_getMemoryConsumption_const_const_MatShapeR_size_tR_size_tR(self.as_raw_Net(), net_input_shape.as_raw_VectorOfi32(), weights, blobs, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8083,8 +9527,9 @@ pub trait NetTraitConst {
 	/// ## Overloaded parameters
 	#[inline]
 	fn get_memory_consumption_for_layer(&self, layer_id: i32, net_input_shapes: &core::Vector<crate::dnn::MatShape>, weights: &mut size_t, blobs: &mut size_t) -> Result<()> {
+		crate::core::validity::check("Net", self.as_raw_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_getMemoryConsumption_const_const_int_const_vector_MatShape_R_size_tR_size_tR(self.as_raw_Net(), layer_id, net_input_shapes.as_raw_VectorOfMatShape(), weights, blobs, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_getMemoryConsumption_const_const_int_const_vector_MatShape_R_size_tR_size_tR(self.as_raw_Net(), layer_id, net_input_shapes.as_raw_VectorOfMatShape(), weights, blobs, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8101,8 +9546,9 @@ pub trait NetTraitConst {
 	/// ## Overloaded parameters
 	#[inline]
 	fn get_memory_consumption_2(&self, layer_id: i32, net_input_shape: &crate::dnn::MatShape, weights: &mut size_t, blobs: &mut size_t) -> Result<()> {
+		crate::core::validity::check("Net", self.as_raw_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_getMemoryConsumption_const_const_int_const_MatShapeR_size_tR_size_tR(self.as_raw_Net(), layer_id, net_input_shape.as_raw_VectorOfi32(), weights, blobs, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_getMemoryConsumption_const_const_int_const_MatShapeR_size_tR_size_tR(self.as_raw_Net(), layer_id, net_input_shape.as_raw_VectorOfi32(), weights, blobs, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8117,8 +9563,9 @@ pub trait NetTraitConst {
 	/// * blobs: output parameter to store resulting bytes for intermediate blobs.
 	#[inline]
 	fn get_memory_consumption_for_layers(&self, net_input_shapes: &core::Vector<crate::dnn::MatShape>, layer_ids: &mut core::Vector<i32>, weights: &mut core::Vector<size_t>, blobs: &mut core::Vector<size_t>) -> Result<()> {
+		crate::core::validity::check("Net", self.as_raw_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_getMemoryConsumption_const_const_vector_MatShape_R_vector_int_R_vector_size_t_R_vector_size_t_R(self.as_raw_Net(), net_input_shapes.as_raw_VectorOfMatShape(), layer_ids.as_raw_mut_VectorOfi32(), weights.as_raw_mut_VectorOfsize_t(), blobs.as_raw_mut_VectorOfsize_t(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_getMemoryConsumption_const_const_vector_MatShape_R_vector_int_R_vector_size_t_R_vector_size_t_R(self.as_raw_Net(), net_input_shapes.as_raw_VectorOfMatShape(), layer_ids.as_raw_mut_VectorOfi32(), weights.as_raw_mut_VectorOfsize_t(), blobs.as_raw_mut_VectorOfsize_t(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8135,8 +9582,9 @@ pub trait NetTraitConst {
 	/// ## Overloaded parameters
 	#[inline]
 	fn get_memory_consumption_3(&self, net_input_shape: &crate::dnn::MatShape, layer_ids: &mut core::Vector<i32>, weights: &mut core::Vector<size_t>, blobs: &mut core::Vector<size_t>) -> Result<()> {
+		crate::core::validity::check("Net", self.as_raw_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_getMemoryConsumption_const_const_MatShapeR_vector_int_R_vector_size_t_R_vector_size_t_R(self.as_raw_Net(), net_input_shape.as_raw_VectorOfi32(), layer_ids.as_raw_mut_VectorOfi32(), weights.as_raw_mut_VectorOfsize_t(), blobs.as_raw_mut_VectorOfsize_t(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_getMemoryConsumption_const_const_MatShapeR_vector_int_R_vector_size_t_R_vector_size_t_R(self.as_raw_Net(), net_input_shape.as_raw_VectorOfi32(), layer_ids.as_raw_mut_VectorOfi32(), weights.as_raw_mut_VectorOfsize_t(), blobs.as_raw_mut_VectorOfsize_t(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8153,8 +9601,9 @@ pub trait NetTrait: crate::dnn::NetTraitConst {
 	/// Call method after setInput(). To see correct backend, target and fusion run after forward().
 	#[inline]
 	fn dump(&mut self) -> Result<String> {
+		crate::core::validity::check("Net", self.as_raw_mut_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_dump(self.as_raw_mut_Net(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_dump(self.as_raw_mut_Net(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -8165,12 +9614,13 @@ pub trait NetTrait: crate::dnn::NetTraitConst {
 	/// ## Parameters
 	/// * path: path to output file with .dot extension
 	/// ## See also
-	/// dump()
+	/// `dump`
 	#[inline]
 	fn dump_to_file(&mut self, path: &str) -> Result<()> {
+		crate::core::validity::check("Net", self.as_raw_mut_Net())?;
 		extern_container_arg!(path);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_dumpToFile_const_StringR(self.as_raw_mut_Net(), path.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_dumpToFile_const_StringR(self.as_raw_mut_Net(), path.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8186,10 +9636,11 @@ pub trait NetTrait: crate::dnn::NetTraitConst {
 	/// unique identifier of created layer, or -1 if a failure will happen.
 	#[inline]
 	fn add_layer_type(&mut self, name: &str, typ: &str, dtype: &i32, params: &mut crate::dnn::LayerParams) -> Result<i32> {
+		crate::core::validity::check("Net", self.as_raw_mut_Net())?;
 		extern_container_arg!(name);
 		extern_container_arg!(typ);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_addLayer_const_StringR_const_StringR_const_intR_LayerParamsR(self.as_raw_mut_Net(), name.opencv_as_extern(), typ.opencv_as_extern(), dtype, params.as_raw_mut_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_addLayer_const_StringR_const_StringR_const_intR_LayerParamsR(self.as_raw_mut_Net(), name.opencv_as_extern(), typ.opencv_as_extern(), dtype, params.as_raw_mut_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8208,10 +9659,11 @@ pub trait NetTrait: crate::dnn::NetTraitConst {
 	///  Datatype of output blobs set to default CV_32F
 	#[inline]
 	fn add_layer(&mut self, name: &str, typ: &str, params: &mut crate::dnn::LayerParams) -> Result<i32> {
+		crate::core::validity::check("Net", self.as_raw_mut_Net())?;
 		extern_container_arg!(name);
 		extern_container_arg!(typ);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_addLayer_const_StringR_const_StringR_LayerParamsR(self.as_raw_mut_Net(), name.opencv_as_extern(), typ.opencv_as_extern(), params.as_raw_mut_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_addLayer_const_StringR_const_StringR_LayerParamsR(self.as_raw_mut_Net(), name.opencv_as_extern(), typ.opencv_as_extern(), params.as_raw_mut_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8219,13 +9671,14 @@ pub trait NetTrait: crate::dnn::NetTraitConst {
 	
 	/// Adds new layer and connects its first input to the first output of previously added layer.
 	/// ## See also
-	/// addLayer()
+	/// `add_layer`
 	#[inline]
 	fn add_layer_to_prev_type(&mut self, name: &str, typ: &str, dtype: &i32, params: &mut crate::dnn::LayerParams) -> Result<i32> {
+		crate::core::validity::check("Net", self.as_raw_mut_Net())?;
 		extern_container_arg!(name);
 		extern_container_arg!(typ);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_addLayerToPrev_const_StringR_const_StringR_const_intR_LayerParamsR(self.as_raw_mut_Net(), name.opencv_as_extern(), typ.opencv_as_extern(), dtype, params.as_raw_mut_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_addLayerToPrev_const_StringR_const_StringR_const_intR_LayerParamsR(self.as_raw_mut_Net(), name.opencv_as_extern(), typ.opencv_as_extern(), dtype, params.as_raw_mut_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8233,15 +9686,16 @@ pub trait NetTrait: crate::dnn::NetTraitConst {
 	
 	/// Adds new layer and connects its first input to the first output of previously added layer.
 	/// ## See also
-	/// addLayer()
+	/// `add_layer`
 	/// 
 	/// ## Overloaded parameters
 	#[inline]
 	fn add_layer_to_prev(&mut self, name: &str, typ: &str, params: &mut crate::dnn::LayerParams) -> Result<i32> {
+		crate::core::validity::check("Net", self.as_raw_mut_Net())?;
 		extern_container_arg!(name);
 		extern_container_arg!(typ);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_addLayerToPrev_const_StringR_const_StringR_LayerParamsR(self.as_raw_mut_Net(), name.opencv_as_extern(), typ.opencv_as_extern(), params.as_raw_mut_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_addLayerToPrev_const_StringR_const_StringR_LayerParamsR(self.as_raw_mut_Net(), name.opencv_as_extern(), typ.opencv_as_extern(), params.as_raw_mut_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8252,9 +9706,10 @@ pub trait NetTrait: crate::dnn::NetTraitConst {
 	/// id of the layer, or -1 if the layer wasn't found.
 	#[inline]
 	fn get_layer_id(&mut self, layer: &str) -> Result<i32> {
+		crate::core::validity::check("Net", self.as_raw_mut_Net())?;
 		extern_container_arg!(layer);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_getLayerId_const_StringR(self.as_raw_mut_Net(), layer.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_getLayerId_const_StringR(self.as_raw_mut_Net(), layer.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8263,8 +9718,9 @@ pub trait NetTrait: crate::dnn::NetTraitConst {
 	/// Returns pointer to layer with specified id or name which the network use.
 	#[inline]
 	fn get_layer(&mut self, mut layer_id: crate::dnn::Net_LayerId) -> Result<core::Ptr<crate::dnn::Layer>> {
+		crate::core::validity::check("Net", self.as_raw_mut_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_getLayer_LayerId(self.as_raw_mut_Net(), layer_id.as_raw_mut_DictValue(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_getLayer_LayerId(self.as_raw_mut_Net(), layer_id.as_raw_mut_DictValue(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::Layer>::opencv_from_extern(ret) };
@@ -8274,8 +9730,9 @@ pub trait NetTrait: crate::dnn::NetTraitConst {
 	/// Returns pointers to input layers of specific layer.
 	#[inline]
 	fn get_layer_inputs(&mut self, mut layer_id: crate::dnn::Net_LayerId) -> Result<core::Vector<core::Ptr<crate::dnn::Layer>>> {
+		crate::core::validity::check("Net", self.as_raw_mut_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_getLayerInputs_LayerId(self.as_raw_mut_Net(), layer_id.as_raw_mut_DictValue(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_getLayerInputs_LayerId(self.as_raw_mut_Net(), layer_id.as_raw_mut_DictValue(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<core::Ptr<crate::dnn::Layer>>::opencv_from_extern(ret) };
@@ -8287,20 +9744,21 @@ pub trait NetTrait: crate::dnn::NetTraitConst {
 	/// * outPin: descriptor of the first layer output.
 	/// * inpPin: descriptor of the second layer input.
 	/// 
-	/// Descriptors have the following template <DFN>&lt;layer_name&gt;[.input_number]</DFN>:
+	/// Descriptors have the following template <DFN>&lt;layer_name&gt;\[.input_number\]</DFN>:
 	/// - the first part of the template <DFN>layer_name</DFN> is string name of the added layer.
 	///   If this part is empty then the network input pseudo layer will be used;
 	/// - the second optional part of the template <DFN>input_number</DFN>
 	///   is either number of the layer input, either label one.
 	///   If this part is omitted then the first layer input will be used.
 	/// ## See also
-	/// setNetInputs(), Layer::inputNameToIndex(), Layer::outputNameToIndex()
+	/// `set_net_inputs`, [Layer::input_name_to_index](crate::dnn::Layer), [Layer::output_name_to_index](crate::dnn::Layer)
 	#[inline]
 	fn connect_first_second(&mut self, out_pin: &str, inp_pin: &str) -> Result<()> {
+		crate::core::validity::check("Net", self.as_raw_mut_Net())?;
 		extern_container_arg!(mut out_pin);
 		extern_container_arg!(mut inp_pin);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_connect_String_String(self.as_raw_mut_Net(), out_pin.opencv_as_extern_mut(), inp_pin.opencv_as_extern_mut(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_connect_String_String(self.as_raw_mut_Net(), out_pin.opencv_as_extern_mut(), inp_pin.opencv_as_extern_mut(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8314,8 +9772,9 @@ pub trait NetTrait: crate::dnn::NetTraitConst {
 	/// * inpNum: number of the second layer input
 	#[inline]
 	fn connect(&mut self, out_layer_id: i32, out_num: i32, inp_layer_id: i32, inp_num: i32) -> Result<()> {
+		crate::core::validity::check("Net", self.as_raw_mut_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_connect_int_int_int_int(self.as_raw_mut_Net(), out_layer_id, out_num, inp_layer_id, inp_num, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_connect_int_int_int_int(self.as_raw_mut_Net(), out_layer_id, out_num, inp_layer_id, inp_num, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8329,8 +9788,9 @@ pub trait NetTrait: crate::dnn::NetTraitConst {
 	/// As any other layer, this layer can label its outputs and this function provides an easy way to do this.
 	#[inline]
 	fn set_inputs_names(&mut self, input_blob_names: &core::Vector<String>) -> Result<()> {
+		crate::core::validity::check("Net", self.as_raw_mut_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_setInputsNames_const_vector_String_R(self.as_raw_mut_Net(), input_blob_names.as_raw_VectorOfString(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_setInputsNames_const_vector_String_R(self.as_raw_mut_Net(), input_blob_names.as_raw_VectorOfString(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8339,9 +9799,10 @@ pub trait NetTrait: crate::dnn::NetTraitConst {
 	/// Specify shape of network input.
 	#[inline]
 	fn set_input_shape(&mut self, input_name: &str, shape: &crate::dnn::MatShape) -> Result<()> {
+		crate::core::validity::check("Net", self.as_raw_mut_Net())?;
 		extern_container_arg!(input_name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_setInputShape_const_StringR_const_MatShapeR(self.as_raw_mut_Net(), input_name.opencv_as_extern(), shape.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_setInputShape_const_StringR_const_MatShapeR(self.as_raw_mut_Net(), input_name.opencv_as_extern(), shape.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8358,9 +9819,10 @@ pub trait NetTrait: crate::dnn::NetTraitConst {
 	/// * output_name: String()
 	#[inline]
 	fn forward_single(&mut self, output_name: &str) -> Result<core::Mat> {
+		crate::core::validity::check("Net", self.as_raw_mut_Net())?;
 		extern_container_arg!(output_name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_forward_const_StringR(self.as_raw_mut_Net(), output_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_forward_const_StringR(self.as_raw_mut_Net(), output_name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -8379,9 +9841,10 @@ pub trait NetTrait: crate::dnn::NetTraitConst {
 	/// * output_name: String()
 	#[inline]
 	fn forward_async(&mut self, output_name: &str) -> Result<core::AsyncArray> {
+		crate::core::validity::check("Net", self.as_raw_mut_Net())?;
 		extern_container_arg!(output_name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_forwardAsync_const_StringR(self.as_raw_mut_Net(), output_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_forwardAsync_const_StringR(self.as_raw_mut_Net(), output_name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::AsyncArray::opencv_from_extern(ret) };
@@ -8398,10 +9861,11 @@ pub trait NetTrait: crate::dnn::NetTraitConst {
 	/// * output_name: String()
 	#[inline]
 	fn forward_layer(&mut self, output_blobs: &mut dyn core::ToOutputArray, output_name: &str) -> Result<()> {
+		crate::core::validity::check("Net", self.as_raw_mut_Net())?;
 		output_array_arg!(output_blobs);
 		extern_container_arg!(output_name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_forward_const__OutputArrayR_const_StringR(self.as_raw_mut_Net(), output_blobs.as_raw__OutputArray(), output_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_forward_const__OutputArrayR_const_StringR(self.as_raw_mut_Net(), output_blobs.as_raw__OutputArray(), output_name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8413,9 +9877,10 @@ pub trait NetTrait: crate::dnn::NetTraitConst {
 	/// * outBlobNames: names for layers which outputs are needed to get
 	#[inline]
 	fn forward(&mut self, output_blobs: &mut dyn core::ToOutputArray, out_blob_names: &core::Vector<String>) -> Result<()> {
+		crate::core::validity::check("Net", self.as_raw_mut_Net())?;
 		output_array_arg!(output_blobs);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_forward_const__OutputArrayR_const_vector_String_R(self.as_raw_mut_Net(), output_blobs.as_raw__OutputArray(), out_blob_names.as_raw_VectorOfString(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_forward_const__OutputArrayR_const_vector_String_R(self.as_raw_mut_Net(), output_blobs.as_raw__OutputArray(), out_blob_names.as_raw_VectorOfString(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8427,8 +9892,9 @@ pub trait NetTrait: crate::dnn::NetTraitConst {
 	/// * outBlobNames: names for layers which outputs are needed to get
 	#[inline]
 	fn forward_and_retrieve(&mut self, output_blobs: &mut core::Vector<core::Vector<core::Mat>>, out_blob_names: &core::Vector<String>) -> Result<()> {
+		crate::core::validity::check("Net", self.as_raw_mut_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_forward_vector_vector_Mat__R_const_vector_String_R(self.as_raw_mut_Net(), output_blobs.as_raw_mut_VectorOfVectorOfMat(), out_blob_names.as_raw_VectorOfString(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_forward_vector_vector_Mat__R_const_vector_String_R(self.as_raw_mut_Net(), output_blobs.as_raw_mut_VectorOfVectorOfMat(), out_blob_names.as_raw_VectorOfString(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8441,9 +9907,10 @@ pub trait NetTrait: crate::dnn::NetTraitConst {
 	/// * outputsDtype: Datatype of quantized net's outputs. Can be CV_32F or CV_8S.
 	#[inline]
 	fn quantize(&mut self, calib_data: &dyn core::ToInputArray, inputs_dtype: i32, outputs_dtype: i32) -> Result<crate::dnn::Net> {
+		crate::core::validity::check("Net", self.as_raw_mut_Net())?;
 		input_array_arg!(calib_data);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_quantize_const__InputArrayR_int_int(self.as_raw_mut_Net(), calib_data.as_raw__InputArray(), inputs_dtype, outputs_dtype, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_quantize_const__InputArrayR_int_int(self.as_raw_mut_Net(), calib_data.as_raw__InputArray(), inputs_dtype, outputs_dtype, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::Net::opencv_from_extern(ret) };
@@ -8454,16 +9921,17 @@ pub trait NetTrait: crate::dnn::NetTraitConst {
 	/// ## Parameters
 	/// * scheduler: Path to YAML file with scheduling directives.
 	/// ## See also
-	/// setPreferableBackend
+	/// `set_preferable_backend`
 	/// 
 	/// Schedule layers that support Halide backend. Then compile them for
 	/// specific target. For layers that not represented in scheduling file
 	/// or if no manual scheduling used at all, automatic scheduling will be applied.
 	#[inline]
 	fn set_halide_scheduler(&mut self, scheduler: &str) -> Result<()> {
+		crate::core::validity::check("Net", self.as_raw_mut_Net())?;
 		extern_container_arg!(scheduler);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_setHalideScheduler_const_StringR(self.as_raw_mut_Net(), scheduler.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_setHalideScheduler_const_StringR(self.as_raw_mut_Net(), scheduler.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8473,14 +9941,15 @@ pub trait NetTrait: crate::dnn::NetTraitConst {
 	/// ## Parameters
 	/// * backendId: backend identifier.
 	/// ## See also
-	/// Backend
+	/// `Backend`
 	/// 
 	/// If OpenCV is compiled with Intel's Inference Engine library, DNN_BACKEND_DEFAULT
 	/// means DNN_BACKEND_INFERENCE_ENGINE. Otherwise it equals to DNN_BACKEND_OPENCV.
 	#[inline]
 	fn set_preferable_backend(&mut self, backend_id: i32) -> Result<()> {
+		crate::core::validity::check("Net", self.as_raw_mut_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_setPreferableBackend_int(self.as_raw_mut_Net(), backend_id, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_setPreferableBackend_int(self.as_raw_mut_Net(), backend_id, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8490,7 +9959,7 @@ pub trait NetTrait: crate::dnn::NetTraitConst {
 	/// ## Parameters
 	/// * targetId: target identifier.
 	/// ## See also
-	/// Target
+	/// `Target`
 	/// 
 	/// List of supported combinations backend / target:
 	/// |                        | DNN_BACKEND_OPENCV | DNN_BACKEND_INFERENCE_ENGINE | DNN_BACKEND_HALIDE |  DNN_BACKEND_CUDA |
@@ -8505,8 +9974,9 @@ pub trait NetTrait: crate::dnn::NetTraitConst {
 	/// | DNN_TARGET_HDDL        |                    |                            + |                    |                   |
 	#[inline]
 	fn set_preferable_target(&mut self, target_id: i32) -> Result<()> {
+		crate::core::validity::check("Net", self.as_raw_mut_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_setPreferableTarget_int(self.as_raw_mut_Net(), target_id, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_setPreferableTarget_int(self.as_raw_mut_Net(), target_id, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8531,10 +10001,11 @@ pub trait NetTrait: crate::dnn::NetTraitConst {
 	/// * mean: Scalar()
 	#[inline]
 	fn set_input(&mut self, blob: &dyn core::ToInputArray, name: &str, scalefactor: f64, mean: core::Scalar) -> Result<()> {
+		crate::core::validity::check("Net", self.as_raw_mut_Net())?;
 		input_array_arg!(blob);
 		extern_container_arg!(name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_setInput_const__InputArrayR_const_StringR_double_const_ScalarR(self.as_raw_mut_Net(), blob.as_raw__InputArray(), name.opencv_as_extern(), scalefactor, &mean, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_setInput_const__InputArrayR_const_StringR_double_const_ScalarR(self.as_raw_mut_Net(), blob.as_raw__InputArray(), name.opencv_as_extern(), scalefactor, &mean, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8546,14 +10017,15 @@ pub trait NetTrait: crate::dnn::NetTraitConst {
 	/// * numParam: index of the layer parameter in the Layer::blobs array.
 	/// * blob: the new value.
 	/// ## See also
-	/// Layer::blobs
+	/// [Layer::blobs](crate::dnn::Layer)
 	/// 
 	/// Note: If shape of the new blob differs from the previous shape,
 	/// then the following forward pass may fail.
 	#[inline]
 	fn set_param(&mut self, mut layer: crate::dnn::Net_LayerId, num_param: i32, blob: &core::Mat) -> Result<()> {
+		crate::core::validity::check("Net", self.as_raw_mut_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_setParam_LayerId_int_const_MatR(self.as_raw_mut_Net(), layer.as_raw_mut_DictValue(), num_param, blob.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_setParam_LayerId_int_const_MatR(self.as_raw_mut_Net(), layer.as_raw_mut_DictValue(), num_param, blob.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8564,14 +10036,15 @@ pub trait NetTrait: crate::dnn::NetTraitConst {
 	/// * layer: name or id of the layer.
 	/// * numParam: index of the layer parameter in the Layer::blobs array.
 	/// ## See also
-	/// Layer::blobs
+	/// [Layer::blobs](crate::dnn::Layer)
 	/// 
 	/// ## C++ default parameters
 	/// * num_param: 0
 	#[inline]
 	fn get_param(&mut self, mut layer: crate::dnn::Net_LayerId, num_param: i32) -> Result<core::Mat> {
+		crate::core::validity::check("Net", self.as_raw_mut_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_getParam_LayerId_int(self.as_raw_mut_Net(), layer.as_raw_mut_DictValue(), num_param, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_getParam_LayerId_int(self.as_raw_mut_Net(), layer.as_raw_mut_DictValue(), num_param, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -8583,8 +10056,9 @@ pub trait NetTrait: crate::dnn::NetTraitConst {
 	/// * fusion: true to enable the fusion, false to disable. The fusion is enabled by default.
 	#[inline]
 	fn enable_fusion(&mut self, fusion: bool) -> Result<()> {
+		crate::core::validity::check("Net", self.as_raw_mut_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_enableFusion_bool(self.as_raw_mut_Net(), fusion, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_enableFusion_bool(self.as_raw_mut_Net(), fusion, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8596,13 +10070,14 @@ pub trait NetTrait: crate::dnn::NetTraitConst {
 	/// in this case zero ticks count will be return for that skipped layers. Supported by DNN_BACKEND_OPENCV on DNN_TARGET_CPU only.
 	/// 
 	/// ## Parameters
-	/// * timings:[out] vector for tick timings for all layers.
+	/// * timings:\[out\] vector for tick timings for all layers.
 	/// ## Returns
 	/// overall ticks for model inference.
 	#[inline]
 	fn get_perf_profile(&mut self, timings: &mut core::Vector<f64>) -> Result<i64> {
+		crate::core::validity::check("Net", self.as_raw_mut_Net())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_getPerfProfile_vector_double_R(self.as_raw_mut_Net(), timings.as_raw_mut_VectorOff64(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_getPerfProfile_vector_double_R(self.as_raw_mut_Net(), timings.as_raw_mut_VectorOff64(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8610,6 +10085,51 @@ pub trait NetTrait: crate::dnn::NetTraitConst {
 	
 }
 
+/// Builder for `NetTrait::set_input()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct Net_SetInputBuilder<'a, T: ?Sized + NetTrait> {
+	instance: &'a mut T,
+	blob: &'a dyn core::ToInputArray,
+	name: &'a str,
+	scalefactor: f64,
+	mean: core::Scalar,
+}
+
+impl<'a, T: ?Sized + NetTrait> Net_SetInputBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, blob: &'a dyn core::ToInputArray) -> Self {
+		Self { instance, blob, name: "", scalefactor: 1.0, mean: core::Scalar::default() }
+	}
+	
+	/// C++ default: `""`
+	#[inline]
+	pub fn with_name(mut self, name: &'a str) -> Self {
+		self.name = name;
+		self
+	}
+	
+	/// C++ default: `1.0`
+	#[inline]
+	pub fn with_scalefactor(mut self, scalefactor: f64) -> Self {
+		self.scalefactor = scalefactor;
+		self
+	}
+	
+	/// C++ default: `Scalar()`
+	#[inline]
+	pub fn with_mean(mut self, mean: core::Scalar) -> Self {
+		self.mean = mean;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		NetTrait::set_input(self.instance, self.blob, self.name, self.scalefactor, self.mean)
+	}
+}
+
 /// This class allows to create and manipulate comprehensive artificial neural networks.
 /// 
 /// Neural network is presented as directed acyclic graph (DAG), where vertices are Layer instances,
@@ -8628,7 +10148,10 @@ opencv_type_boxed! { Net }
 impl Drop for Net {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Net_delete(instance: *mut c_void); }
-		unsafe { cv_Net_delete(self.as_raw_mut_Net()) };
+		let ptr = self.as_raw_mut_Net();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Net_delete(ptr) };
 	}
 }
 
@@ -8646,7 +10169,7 @@ impl Net {
 	#[inline]
 	pub fn default() -> Result<crate::dnn::Net> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_Net(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_Net(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::Net::opencv_from_extern(ret) };
@@ -8664,7 +10187,7 @@ impl Net {
 		extern_container_arg!(xml);
 		extern_container_arg!(bin);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_readFromModelOptimizer_const_StringR_const_StringR(xml.opencv_as_extern(), bin.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_readFromModelOptimizer_const_StringR_const_StringR(xml.opencv_as_extern(), bin.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::Net::opencv_from_extern(ret) };
@@ -8680,7 +10203,7 @@ impl Net {
 	#[inline]
 	pub fn read_from_model_optimizer_1(buffer_model_config: &core::Vector<u8>, buffer_weights: &core::Vector<u8>) -> Result<crate::dnn::Net> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_readFromModelOptimizer_const_vector_unsigned_char_R_const_vector_unsigned_char_R(buffer_model_config.as_raw_VectorOfu8(), buffer_weights.as_raw_VectorOfu8(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_readFromModelOptimizer_const_vector_unsigned_char_R_const_vector_unsigned_char_R(buffer_model_config.as_raw_VectorOfu8(), buffer_weights.as_raw_VectorOfu8(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::Net::opencv_from_extern(ret) };
@@ -8698,7 +10221,7 @@ impl Net {
 	#[inline]
 	pub fn read_from_model_optimizer_2(buffer_model_config_ptr: &u8, buffer_model_config_size: size_t, buffer_weights_ptr: &u8, buffer_weights_size: size_t) -> Result<crate::dnn::Net> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_readFromModelOptimizer_const_unsigned_charX_size_t_const_unsigned_charX_size_t(buffer_model_config_ptr, buffer_model_config_size, buffer_weights_ptr, buffer_weights_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_Net_readFromModelOptimizer_const_unsigned_charX_size_t_const_unsigned_charX_size_t(buffer_model_config_ptr, buffer_model_config_size, buffer_weights_ptr, buffer_weights_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::Net::opencv_from_extern(ret) };
@@ -8716,16 +10239,16 @@ impl Net {
 ///                       Otherwise normalize an every channel separately.
 /// 
 /// Across spatial:
-/// @f[
-/// norm = \sqrt[p]{\epsilon + \sum_{x, y, c} |src(x, y, c)|^p } \\
+/// @f\[
+/// norm = \sqrt\[p\]{\epsilon + \sum_{x, y, c} |src(x, y, c)|^p } \\
 /// dst(x, y, c) = \frac{ src(x, y, c) }{norm}
-/// @f]
+/// @f\]
 /// 
 /// Channel wise normalization:
-/// @f[
-/// norm(c) = \sqrt[p]{\epsilon + \sum_{x, y} |src(x, y, c)|^p } \\
+/// @f\[
+/// norm(c) = \sqrt\[p\]{\epsilon + \sum_{x, y} |src(x, y, c)|^p } \\
 /// dst(x, y, c) = \frac{ src(x, y, c) }{norm(c)}
-/// @f]
+/// @f\]
 /// 
 /// Where `x, y` - spatial coordinates, `c` - channel.
 /// 
@@ -8736,22 +10259,49 @@ pub trait NormalizeBBoxLayerTraitConst: crate::dnn::LayerTraitConst {
 
 	#[inline]
 	fn pnorm(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_NormalizeBBoxLayer_getPropPnorm_const(self.as_raw_NormalizeBBoxLayer()) };
+		crate::core::validity::debug_check("NormalizeBBoxLayer", self.as_raw_NormalizeBBoxLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_NormalizeBBoxLayer_getPropPnorm_const(self.as_raw_NormalizeBBoxLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [pnorm](NormalizeBBoxLayerTraitConst::pnorm), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pnorm(&self) -> Result<f32> {
+		crate::core::validity::check("NormalizeBBoxLayer", self.as_raw_NormalizeBBoxLayer())?;
+		Ok(NormalizeBBoxLayerTraitConst::pnorm(self))
+	}
+	
 	#[inline]
 	fn epsilon(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_NormalizeBBoxLayer_getPropEpsilon_const(self.as_raw_NormalizeBBoxLayer()) };
+		crate::core::validity::debug_check("NormalizeBBoxLayer", self.as_raw_NormalizeBBoxLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_NormalizeBBoxLayer_getPropEpsilon_const(self.as_raw_NormalizeBBoxLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [epsilon](NormalizeBBoxLayerTraitConst::epsilon), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_epsilon(&self) -> Result<f32> {
+		crate::core::validity::check("NormalizeBBoxLayer", self.as_raw_NormalizeBBoxLayer())?;
+		Ok(NormalizeBBoxLayerTraitConst::epsilon(self))
+	}
+	
 	#[inline]
 	fn across_spatial(&self) -> bool {
-		let ret = unsafe { sys::cv_dnn_NormalizeBBoxLayer_getPropAcrossSpatial_const(self.as_raw_NormalizeBBoxLayer()) };
+		crate::core::validity::debug_check("NormalizeBBoxLayer", self.as_raw_NormalizeBBoxLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_NormalizeBBoxLayer_getPropAcrossSpatial_const(self.as_raw_NormalizeBBoxLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [across_spatial](NormalizeBBoxLayerTraitConst::across_spatial), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_across_spatial(&self) -> Result<bool> {
+		crate::core::validity::check("NormalizeBBoxLayer", self.as_raw_NormalizeBBoxLayer())?;
+		Ok(NormalizeBBoxLayerTraitConst::across_spatial(self))
+	}
+	
 }
 
 pub trait NormalizeBBoxLayerTrait: crate::dnn::LayerTrait + crate::dnn::NormalizeBBoxLayerTraitConst {
@@ -8759,19 +10309,22 @@ pub trait NormalizeBBoxLayerTrait: crate::dnn::LayerTrait + crate::dnn::Normaliz
 
 	#[inline]
 	fn set_pnorm(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_NormalizeBBoxLayer_setPropPnorm_float(self.as_raw_mut_NormalizeBBoxLayer(), val) };
+		crate::core::validity::debug_check("NormalizeBBoxLayer", self.as_raw_mut_NormalizeBBoxLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_NormalizeBBoxLayer_setPropPnorm_float(self.as_raw_mut_NormalizeBBoxLayer(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_epsilon(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_NormalizeBBoxLayer_setPropEpsilon_float(self.as_raw_mut_NormalizeBBoxLayer(), val) };
+		crate::core::validity::debug_check("NormalizeBBoxLayer", self.as_raw_mut_NormalizeBBoxLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_NormalizeBBoxLayer_setPropEpsilon_float(self.as_raw_mut_NormalizeBBoxLayer(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_across_spatial(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_dnn_NormalizeBBoxLayer_setPropAcrossSpatial_bool(self.as_raw_mut_NormalizeBBoxLayer(), val) };
+		crate::core::validity::debug_check("NormalizeBBoxLayer", self.as_raw_mut_NormalizeBBoxLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_NormalizeBBoxLayer_setPropAcrossSpatial_bool(self.as_raw_mut_NormalizeBBoxLayer(), val)) };
 		ret
 	}
 	
@@ -8786,16 +10339,16 @@ pub trait NormalizeBBoxLayerTrait: crate::dnn::LayerTrait + crate::dnn::Normaliz
 ///                       Otherwise normalize an every channel separately.
 /// 
 /// Across spatial:
-/// @f[
-/// norm = \sqrt[p]{\epsilon + \sum_{x, y, c} |src(x, y, c)|^p } \\
+/// @f\[
+/// norm = \sqrt\[p\]{\epsilon + \sum_{x, y, c} |src(x, y, c)|^p } \\
 /// dst(x, y, c) = \frac{ src(x, y, c) }{norm}
-/// @f]
+/// @f\]
 /// 
 /// Channel wise normalization:
-/// @f[
-/// norm(c) = \sqrt[p]{\epsilon + \sum_{x, y} |src(x, y, c)|^p } \\
+/// @f\[
+/// norm(c) = \sqrt\[p\]{\epsilon + \sum_{x, y} |src(x, y, c)|^p } \\
 /// dst(x, y, c) = \frac{ src(x, y, c) }{norm(c)}
-/// @f]
+/// @f\]
 /// 
 /// Where `x, y` - spatial coordinates, `c` - channel.
 /// 
@@ -8810,7 +10363,10 @@ opencv_type_boxed! { NormalizeBBoxLayer }
 impl Drop for NormalizeBBoxLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_NormalizeBBoxLayer_delete(instance: *mut c_void); }
-		unsafe { cv_NormalizeBBoxLayer_delete(self.as_raw_mut_NormalizeBBoxLayer()) };
+		let ptr = self.as_raw_mut_NormalizeBBoxLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_NormalizeBBoxLayer_delete(ptr) };
 	}
 }
 
@@ -8844,7 +10400,7 @@ impl NormalizeBBoxLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::NormalizeBBoxLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_NormalizeBBoxLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_NormalizeBBoxLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::NormalizeBBoxLayer>::opencv_from_extern(ret) };
@@ -8876,7 +10432,10 @@ opencv_type_boxed! { NotLayer }
 impl Drop for NotLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_NotLayer_delete(instance: *mut c_void); }
-		unsafe { cv_NotLayer_delete(self.as_raw_mut_NotLayer()) };
+		let ptr = self.as_raw_mut_NotLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_NotLayer_delete(ptr) };
 	}
 }
 
@@ -8918,7 +10477,7 @@ impl NotLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::NotLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_NotLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_NotLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::NotLayer>::opencv_from_extern(ret) };
@@ -8992,7 +10551,10 @@ opencv_type_boxed! { PaddingLayer }
 impl Drop for PaddingLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_PaddingLayer_delete(instance: *mut c_void); }
-		unsafe { cv_PaddingLayer_delete(self.as_raw_mut_PaddingLayer()) };
+		let ptr = self.as_raw_mut_PaddingLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_PaddingLayer_delete(ptr) };
 	}
 }
 
@@ -9026,7 +10588,7 @@ impl PaddingLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::PaddingLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_PaddingLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_PaddingLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::PaddingLayer>::opencv_from_extern(ret) };
@@ -9058,7 +10620,10 @@ opencv_type_boxed! { PermuteLayer }
 impl Drop for PermuteLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_PermuteLayer_delete(instance: *mut c_void); }
-		unsafe { cv_PermuteLayer_delete(self.as_raw_mut_PermuteLayer()) };
+		let ptr = self.as_raw_mut_PermuteLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_PermuteLayer_delete(ptr) };
 	}
 }
 
@@ -9092,7 +10657,7 @@ impl PermuteLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::PermuteLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_PermuteLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_PermuteLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::PermuteLayer>::opencv_from_extern(ret) };
@@ -9110,97 +10675,223 @@ pub trait PoolingLayerTraitConst: crate::dnn::LayerTraitConst {
 
 	#[inline]
 	fn typ(&self) -> i32 {
-		let ret = unsafe { sys::cv_dnn_PoolingLayer_getPropType_const(self.as_raw_PoolingLayer()) };
+		crate::core::validity::debug_check("PoolingLayer", self.as_raw_PoolingLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_PoolingLayer_getPropType_const(self.as_raw_PoolingLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [typ](PoolingLayerTraitConst::typ), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_typ(&self) -> Result<i32> {
+		crate::core::validity::check("PoolingLayer", self.as_raw_PoolingLayer())?;
+		Ok(PoolingLayerTraitConst::typ(self))
+	}
+	
 	#[inline]
 	fn kernel_size(&self) -> core::Vector<size_t> {
-		let ret = unsafe { sys::cv_dnn_PoolingLayer_getPropKernel_size_const(self.as_raw_PoolingLayer()) };
+		crate::core::validity::debug_check("PoolingLayer", self.as_raw_PoolingLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_PoolingLayer_getPropKernel_size_const(self.as_raw_PoolingLayer())) };
 		let ret = unsafe { core::Vector::<size_t>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [kernel_size](PoolingLayerTraitConst::kernel_size), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_kernel_size(&self) -> Result<core::Vector<size_t>> {
+		crate::core::validity::check("PoolingLayer", self.as_raw_PoolingLayer())?;
+		Ok(PoolingLayerTraitConst::kernel_size(self))
+	}
+	
 	#[inline]
 	fn strides(&self) -> core::Vector<size_t> {
-		let ret = unsafe { sys::cv_dnn_PoolingLayer_getPropStrides_const(self.as_raw_PoolingLayer()) };
+		crate::core::validity::debug_check("PoolingLayer", self.as_raw_PoolingLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_PoolingLayer_getPropStrides_const(self.as_raw_PoolingLayer())) };
 		let ret = unsafe { core::Vector::<size_t>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [strides](PoolingLayerTraitConst::strides), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_strides(&self) -> Result<core::Vector<size_t>> {
+		crate::core::validity::check("PoolingLayer", self.as_raw_PoolingLayer())?;
+		Ok(PoolingLayerTraitConst::strides(self))
+	}
+	
 	#[inline]
 	fn pads_begin(&self) -> core::Vector<size_t> {
-		let ret = unsafe { sys::cv_dnn_PoolingLayer_getPropPads_begin_const(self.as_raw_PoolingLayer()) };
+		crate::core::validity::debug_check("PoolingLayer", self.as_raw_PoolingLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_PoolingLayer_getPropPads_begin_const(self.as_raw_PoolingLayer())) };
 		let ret = unsafe { core::Vector::<size_t>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [pads_begin](PoolingLayerTraitConst::pads_begin), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pads_begin(&self) -> Result<core::Vector<size_t>> {
+		crate::core::validity::check("PoolingLayer", self.as_raw_PoolingLayer())?;
+		Ok(PoolingLayerTraitConst::pads_begin(self))
+	}
+	
 	#[inline]
 	fn pads_end(&self) -> core::Vector<size_t> {
-		let ret = unsafe { sys::cv_dnn_PoolingLayer_getPropPads_end_const(self.as_raw_PoolingLayer()) };
+		crate::core::validity::debug_check("PoolingLayer", self.as_raw_PoolingLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_PoolingLayer_getPropPads_end_const(self.as_raw_PoolingLayer())) };
 		let ret = unsafe { core::Vector::<size_t>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [pads_end](PoolingLayerTraitConst::pads_end), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pads_end(&self) -> Result<core::Vector<size_t>> {
+		crate::core::validity::check("PoolingLayer", self.as_raw_PoolingLayer())?;
+		Ok(PoolingLayerTraitConst::pads_end(self))
+	}
+	
 	/// Flag is true if at least one of the axes is global pooled.
 	#[inline]
 	fn global_pooling(&self) -> bool {
-		let ret = unsafe { sys::cv_dnn_PoolingLayer_getPropGlobalPooling_const(self.as_raw_PoolingLayer()) };
+		crate::core::validity::debug_check("PoolingLayer", self.as_raw_PoolingLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_PoolingLayer_getPropGlobalPooling_const(self.as_raw_PoolingLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [global_pooling](PoolingLayerTraitConst::global_pooling), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_global_pooling(&self) -> Result<bool> {
+		crate::core::validity::check("PoolingLayer", self.as_raw_PoolingLayer())?;
+		Ok(PoolingLayerTraitConst::global_pooling(self))
+	}
+	
 	#[inline]
 	fn is_global_pooling(&self) -> core::Vector<bool> {
-		let ret = unsafe { sys::cv_dnn_PoolingLayer_getPropIsGlobalPooling_const(self.as_raw_PoolingLayer()) };
+		crate::core::validity::debug_check("PoolingLayer", self.as_raw_PoolingLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_PoolingLayer_getPropIsGlobalPooling_const(self.as_raw_PoolingLayer())) };
 		let ret = unsafe { core::Vector::<bool>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [is_global_pooling](PoolingLayerTraitConst::is_global_pooling), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_is_global_pooling(&self) -> Result<core::Vector<bool>> {
+		crate::core::validity::check("PoolingLayer", self.as_raw_PoolingLayer())?;
+		Ok(PoolingLayerTraitConst::is_global_pooling(self))
+	}
+	
 	#[inline]
 	fn compute_max_idx(&self) -> bool {
-		let ret = unsafe { sys::cv_dnn_PoolingLayer_getPropComputeMaxIdx_const(self.as_raw_PoolingLayer()) };
+		crate::core::validity::debug_check("PoolingLayer", self.as_raw_PoolingLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_PoolingLayer_getPropComputeMaxIdx_const(self.as_raw_PoolingLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [compute_max_idx](PoolingLayerTraitConst::compute_max_idx), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_compute_max_idx(&self) -> Result<bool> {
+		crate::core::validity::check("PoolingLayer", self.as_raw_PoolingLayer())?;
+		Ok(PoolingLayerTraitConst::compute_max_idx(self))
+	}
+	
 	#[inline]
 	fn pad_mode(&self) -> String {
-		let ret = unsafe { sys::cv_dnn_PoolingLayer_getPropPadMode_const(self.as_raw_PoolingLayer()) };
+		crate::core::validity::debug_check("PoolingLayer", self.as_raw_PoolingLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_PoolingLayer_getPropPadMode_const(self.as_raw_PoolingLayer())) };
 		let ret = unsafe { String::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [pad_mode](PoolingLayerTraitConst::pad_mode), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pad_mode(&self) -> Result<String> {
+		crate::core::validity::check("PoolingLayer", self.as_raw_PoolingLayer())?;
+		Ok(PoolingLayerTraitConst::pad_mode(self))
+	}
+	
 	#[inline]
 	fn ceil_mode(&self) -> bool {
-		let ret = unsafe { sys::cv_dnn_PoolingLayer_getPropCeilMode_const(self.as_raw_PoolingLayer()) };
+		crate::core::validity::debug_check("PoolingLayer", self.as_raw_PoolingLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_PoolingLayer_getPropCeilMode_const(self.as_raw_PoolingLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [ceil_mode](PoolingLayerTraitConst::ceil_mode), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_ceil_mode(&self) -> Result<bool> {
+		crate::core::validity::check("PoolingLayer", self.as_raw_PoolingLayer())?;
+		Ok(PoolingLayerTraitConst::ceil_mode(self))
+	}
+	
 	#[inline]
 	fn ave_pool_padded_area(&self) -> bool {
-		let ret = unsafe { sys::cv_dnn_PoolingLayer_getPropAvePoolPaddedArea_const(self.as_raw_PoolingLayer()) };
+		crate::core::validity::debug_check("PoolingLayer", self.as_raw_PoolingLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_PoolingLayer_getPropAvePoolPaddedArea_const(self.as_raw_PoolingLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [ave_pool_padded_area](PoolingLayerTraitConst::ave_pool_padded_area), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_ave_pool_padded_area(&self) -> Result<bool> {
+		crate::core::validity::check("PoolingLayer", self.as_raw_PoolingLayer())?;
+		Ok(PoolingLayerTraitConst::ave_pool_padded_area(self))
+	}
+	
 	#[inline]
 	fn pooled_size(&self) -> core::Size {
+		crate::core::validity::debug_check("PoolingLayer", self.as_raw_PoolingLayer());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_PoolingLayer_getPropPooledSize_const(self.as_raw_PoolingLayer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_PoolingLayer_getPropPooledSize_const(self.as_raw_PoolingLayer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [pooled_size](PoolingLayerTraitConst::pooled_size), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pooled_size(&self) -> Result<core::Size> {
+		crate::core::validity::check("PoolingLayer", self.as_raw_PoolingLayer())?;
+		Ok(PoolingLayerTraitConst::pooled_size(self))
+	}
+	
 	#[inline]
 	fn spatial_scale(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_PoolingLayer_getPropSpatialScale_const(self.as_raw_PoolingLayer()) };
+		crate::core::validity::debug_check("PoolingLayer", self.as_raw_PoolingLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_PoolingLayer_getPropSpatialScale_const(self.as_raw_PoolingLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [spatial_scale](PoolingLayerTraitConst::spatial_scale), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_spatial_scale(&self) -> Result<f32> {
+		crate::core::validity::check("PoolingLayer", self.as_raw_PoolingLayer())?;
+		Ok(PoolingLayerTraitConst::spatial_scale(self))
+	}
+	
 	#[inline]
 	fn ps_roi_out_channels(&self) -> i32 {
-		let ret = unsafe { sys::cv_dnn_PoolingLayer_getPropPsRoiOutChannels_const(self.as_raw_PoolingLayer()) };
+		crate::core::validity::debug_check("PoolingLayer", self.as_raw_PoolingLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_PoolingLayer_getPropPsRoiOutChannels_const(self.as_raw_PoolingLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [ps_roi_out_channels](PoolingLayerTraitConst::ps_roi_out_channels), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_ps_roi_out_channels(&self) -> Result<i32> {
+		crate::core::validity::check("PoolingLayer", self.as_raw_PoolingLayer())?;
+		Ok(PoolingLayerTraitConst::ps_roi_out_channels(self))
+	}
+	
 }
 
 pub trait PoolingLayerTrait: crate::dnn::LayerTrait + crate::dnn::PoolingLayerTraitConst {
@@ -9208,87 +10899,101 @@ pub trait PoolingLayerTrait: crate::dnn::LayerTrait + crate::dnn::PoolingLayerTr
 
 	#[inline]
 	fn set_type(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_dnn_PoolingLayer_setPropType_int(self.as_raw_mut_PoolingLayer(), val) };
+		crate::core::validity::debug_check("PoolingLayer", self.as_raw_mut_PoolingLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_PoolingLayer_setPropType_int(self.as_raw_mut_PoolingLayer(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_kernel_size(&mut self, mut val: core::Vector<size_t>) {
-		let ret = unsafe { sys::cv_dnn_PoolingLayer_setPropKernel_size_vector_size_t_(self.as_raw_mut_PoolingLayer(), val.as_raw_mut_VectorOfsize_t()) };
+		crate::core::validity::debug_check("PoolingLayer", self.as_raw_mut_PoolingLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_PoolingLayer_setPropKernel_size_vector_size_t_(self.as_raw_mut_PoolingLayer(), val.as_raw_mut_VectorOfsize_t())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_strides(&mut self, mut val: core::Vector<size_t>) {
-		let ret = unsafe { sys::cv_dnn_PoolingLayer_setPropStrides_vector_size_t_(self.as_raw_mut_PoolingLayer(), val.as_raw_mut_VectorOfsize_t()) };
+		crate::core::validity::debug_check("PoolingLayer", self.as_raw_mut_PoolingLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_PoolingLayer_setPropStrides_vector_size_t_(self.as_raw_mut_PoolingLayer(), val.as_raw_mut_VectorOfsize_t())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_pads_begin(&mut self, mut val: core::Vector<size_t>) {
-		let ret = unsafe { sys::cv_dnn_PoolingLayer_setPropPads_begin_vector_size_t_(self.as_raw_mut_PoolingLayer(), val.as_raw_mut_VectorOfsize_t()) };
+		crate::core::validity::debug_check("PoolingLayer", self.as_raw_mut_PoolingLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_PoolingLayer_setPropPads_begin_vector_size_t_(self.as_raw_mut_PoolingLayer(), val.as_raw_mut_VectorOfsize_t())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_pads_end(&mut self, mut val: core::Vector<size_t>) {
-		let ret = unsafe { sys::cv_dnn_PoolingLayer_setPropPads_end_vector_size_t_(self.as_raw_mut_PoolingLayer(), val.as_raw_mut_VectorOfsize_t()) };
+		crate::core::validity::debug_check("PoolingLayer", self.as_raw_mut_PoolingLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_PoolingLayer_setPropPads_end_vector_size_t_(self.as_raw_mut_PoolingLayer(), val.as_raw_mut_VectorOfsize_t())) };
 		ret
 	}
 	
 	/// Flag is true if at least one of the axes is global pooled.
 	#[inline]
 	fn set_global_pooling(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_dnn_PoolingLayer_setPropGlobalPooling_bool(self.as_raw_mut_PoolingLayer(), val) };
+		crate::core::validity::debug_check("PoolingLayer", self.as_raw_mut_PoolingLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_PoolingLayer_setPropGlobalPooling_bool(self.as_raw_mut_PoolingLayer(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_is_global_pooling(&mut self, mut val: core::Vector<bool>) {
-		let ret = unsafe { sys::cv_dnn_PoolingLayer_setPropIsGlobalPooling_vector_bool_(self.as_raw_mut_PoolingLayer(), val.as_raw_mut_VectorOfbool()) };
+		crate::core::validity::debug_check("PoolingLayer", self.as_raw_mut_PoolingLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_PoolingLayer_setPropIsGlobalPooling_vector_bool_(self.as_raw_mut_PoolingLayer(), val.as_raw_mut_VectorOfbool())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_compute_max_idx(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_dnn_PoolingLayer_setPropComputeMaxIdx_bool(self.as_raw_mut_PoolingLayer(), val) };
+		crate::core::validity::debug_check("PoolingLayer", self.as_raw_mut_PoolingLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_PoolingLayer_setPropComputeMaxIdx_bool(self.as_raw_mut_PoolingLayer(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_pad_mode(&mut self, val: &str) {
+		crate::core::validity::debug_check("PoolingLayer", self.as_raw_mut_PoolingLayer());
 		extern_container_arg!(nofail mut val);
-		let ret = unsafe { sys::cv_dnn_PoolingLayer_setPropPadMode_String(self.as_raw_mut_PoolingLayer(), val.opencv_as_extern_mut()) };
+		let ret = unsafe { ffi_call!(cv_dnn_PoolingLayer_setPropPadMode_String(self.as_raw_mut_PoolingLayer(), val.opencv_as_extern_mut())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_ceil_mode(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_dnn_PoolingLayer_setPropCeilMode_bool(self.as_raw_mut_PoolingLayer(), val) };
+		crate::core::validity::debug_check("PoolingLayer", self.as_raw_mut_PoolingLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_PoolingLayer_setPropCeilMode_bool(self.as_raw_mut_PoolingLayer(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_ave_pool_padded_area(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_dnn_PoolingLayer_setPropAvePoolPaddedArea_bool(self.as_raw_mut_PoolingLayer(), val) };
+		crate::core::validity::debug_check("PoolingLayer", self.as_raw_mut_PoolingLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_PoolingLayer_setPropAvePoolPaddedArea_bool(self.as_raw_mut_PoolingLayer(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_pooled_size(&mut self, val: core::Size) {
-		let ret = unsafe { sys::cv_dnn_PoolingLayer_setPropPooledSize_Size(self.as_raw_mut_PoolingLayer(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("PoolingLayer", self.as_raw_mut_PoolingLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_PoolingLayer_setPropPooledSize_Size(self.as_raw_mut_PoolingLayer(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_spatial_scale(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_PoolingLayer_setPropSpatialScale_float(self.as_raw_mut_PoolingLayer(), val) };
+		crate::core::validity::debug_check("PoolingLayer", self.as_raw_mut_PoolingLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_PoolingLayer_setPropSpatialScale_float(self.as_raw_mut_PoolingLayer(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_ps_roi_out_channels(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_dnn_PoolingLayer_setPropPsRoiOutChannels_int(self.as_raw_mut_PoolingLayer(), val) };
+		crate::core::validity::debug_check("PoolingLayer", self.as_raw_mut_PoolingLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_PoolingLayer_setPropPsRoiOutChannels_int(self.as_raw_mut_PoolingLayer(), val)) };
 		ret
 	}
 	
@@ -9303,7 +11008,10 @@ opencv_type_boxed! { PoolingLayer }
 impl Drop for PoolingLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_PoolingLayer_delete(instance: *mut c_void); }
-		unsafe { cv_PoolingLayer_delete(self.as_raw_mut_PoolingLayer()) };
+		let ptr = self.as_raw_mut_PoolingLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_PoolingLayer_delete(ptr) };
 	}
 }
 
@@ -9337,7 +11045,7 @@ impl PoolingLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::PoolingLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_PoolingLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_PoolingLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::PoolingLayer>::opencv_from_extern(ret) };
@@ -9355,16 +11063,34 @@ pub trait PoolingLayerInt8TraitConst: crate::dnn::PoolingLayerTraitConst {
 
 	#[inline]
 	fn input_zp(&self) -> i32 {
-		let ret = unsafe { sys::cv_dnn_PoolingLayerInt8_getPropInput_zp_const(self.as_raw_PoolingLayerInt8()) };
+		crate::core::validity::debug_check("PoolingLayerInt8", self.as_raw_PoolingLayerInt8());
+		let ret = unsafe { ffi_call!(cv_dnn_PoolingLayerInt8_getPropInput_zp_const(self.as_raw_PoolingLayerInt8())) };
 		ret
 	}
 	
+	/// Non-panicking version of [input_zp](PoolingLayerInt8TraitConst::input_zp), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_input_zp(&self) -> Result<i32> {
+		crate::core::validity::check("PoolingLayerInt8", self.as_raw_PoolingLayerInt8())?;
+		Ok(PoolingLayerInt8TraitConst::input_zp(self))
+	}
+	
 	#[inline]
 	fn output_zp(&self) -> i32 {
-		let ret = unsafe { sys::cv_dnn_PoolingLayerInt8_getPropOutput_zp_const(self.as_raw_PoolingLayerInt8()) };
+		crate::core::validity::debug_check("PoolingLayerInt8", self.as_raw_PoolingLayerInt8());
+		let ret = unsafe { ffi_call!(cv_dnn_PoolingLayerInt8_getPropOutput_zp_const(self.as_raw_PoolingLayerInt8())) };
 		ret
 	}
 	
+	/// Non-panicking version of [output_zp](PoolingLayerInt8TraitConst::output_zp), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_output_zp(&self) -> Result<i32> {
+		crate::core::validity::check("PoolingLayerInt8", self.as_raw_PoolingLayerInt8())?;
+		Ok(PoolingLayerInt8TraitConst::output_zp(self))
+	}
+	
 }
 
 pub trait PoolingLayerInt8Trait: crate::dnn::PoolingLayerInt8TraitConst + crate::dnn::PoolingLayerTrait {
@@ -9372,13 +11098,15 @@ pub trait PoolingLayerInt8Trait: crate::dnn::PoolingLayerInt8TraitConst + crate:
 
 	#[inline]
 	fn set_input_zp(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_dnn_PoolingLayerInt8_setPropInput_zp_int(self.as_raw_mut_PoolingLayerInt8(), val) };
+		crate::core::validity::debug_check("PoolingLayerInt8", self.as_raw_mut_PoolingLayerInt8());
+		let ret = unsafe { ffi_call!(cv_dnn_PoolingLayerInt8_setPropInput_zp_int(self.as_raw_mut_PoolingLayerInt8(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_output_zp(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_dnn_PoolingLayerInt8_setPropOutput_zp_int(self.as_raw_mut_PoolingLayerInt8(), val) };
+		crate::core::validity::debug_check("PoolingLayerInt8", self.as_raw_mut_PoolingLayerInt8());
+		let ret = unsafe { ffi_call!(cv_dnn_PoolingLayerInt8_setPropOutput_zp_int(self.as_raw_mut_PoolingLayerInt8(), val)) };
 		ret
 	}
 	
@@ -9393,7 +11121,10 @@ opencv_type_boxed! { PoolingLayerInt8 }
 impl Drop for PoolingLayerInt8 {
 	fn drop(&mut self) {
 		extern "C" { fn cv_PoolingLayerInt8_delete(instance: *mut c_void); }
-		unsafe { cv_PoolingLayerInt8_delete(self.as_raw_mut_PoolingLayerInt8()) };
+		let ptr = self.as_raw_mut_PoolingLayerInt8();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_PoolingLayerInt8_delete(ptr) };
 	}
 }
 
@@ -9435,7 +11166,7 @@ impl PoolingLayerInt8 {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::PoolingLayerInt8>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_PoolingLayerInt8_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_PoolingLayerInt8_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::PoolingLayerInt8>::opencv_from_extern(ret) };
@@ -9455,22 +11186,49 @@ pub trait PowerLayerTraitConst: crate::dnn::ActivationLayerTraitConst {
 
 	#[inline]
 	fn power(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_PowerLayer_getPropPower_const(self.as_raw_PowerLayer()) };
+		crate::core::validity::debug_check("PowerLayer", self.as_raw_PowerLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_PowerLayer_getPropPower_const(self.as_raw_PowerLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [power](PowerLayerTraitConst::power), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_power(&self) -> Result<f32> {
+		crate::core::validity::check("PowerLayer", self.as_raw_PowerLayer())?;
+		Ok(PowerLayerTraitConst::power(self))
+	}
+	
 	#[inline]
 	fn scale(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_PowerLayer_getPropScale_const(self.as_raw_PowerLayer()) };
+		crate::core::validity::debug_check("PowerLayer", self.as_raw_PowerLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_PowerLayer_getPropScale_const(self.as_raw_PowerLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [scale](PowerLayerTraitConst::scale), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_scale(&self) -> Result<f32> {
+		crate::core::validity::check("PowerLayer", self.as_raw_PowerLayer())?;
+		Ok(PowerLayerTraitConst::scale(self))
+	}
+	
 	#[inline]
 	fn shift(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_PowerLayer_getPropShift_const(self.as_raw_PowerLayer()) };
+		crate::core::validity::debug_check("PowerLayer", self.as_raw_PowerLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_PowerLayer_getPropShift_const(self.as_raw_PowerLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [shift](PowerLayerTraitConst::shift), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_shift(&self) -> Result<f32> {
+		crate::core::validity::check("PowerLayer", self.as_raw_PowerLayer())?;
+		Ok(PowerLayerTraitConst::shift(self))
+	}
+	
 }
 
 pub trait PowerLayerTrait: crate::dnn::ActivationLayerTrait + crate::dnn::PowerLayerTraitConst {
@@ -9478,19 +11236,22 @@ pub trait PowerLayerTrait: crate::dnn::ActivationLayerTrait + crate::dnn::PowerL
 
 	#[inline]
 	fn set_power(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_PowerLayer_setPropPower_float(self.as_raw_mut_PowerLayer(), val) };
+		crate::core::validity::debug_check("PowerLayer", self.as_raw_mut_PowerLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_PowerLayer_setPropPower_float(self.as_raw_mut_PowerLayer(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_scale(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_PowerLayer_setPropScale_float(self.as_raw_mut_PowerLayer(), val) };
+		crate::core::validity::debug_check("PowerLayer", self.as_raw_mut_PowerLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_PowerLayer_setPropScale_float(self.as_raw_mut_PowerLayer(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_shift(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_PowerLayer_setPropShift_float(self.as_raw_mut_PowerLayer(), val) };
+		crate::core::validity::debug_check("PowerLayer", self.as_raw_mut_PowerLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_PowerLayer_setPropShift_float(self.as_raw_mut_PowerLayer(), val)) };
 		ret
 	}
 	
@@ -9505,7 +11266,10 @@ opencv_type_boxed! { PowerLayer }
 impl Drop for PowerLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_PowerLayer_delete(instance: *mut c_void); }
-		unsafe { cv_PowerLayer_delete(self.as_raw_mut_PowerLayer()) };
+		let ptr = self.as_raw_mut_PowerLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_PowerLayer_delete(ptr) };
 	}
 }
 
@@ -9547,7 +11311,7 @@ impl PowerLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::PowerLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_PowerLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_PowerLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::PowerLayer>::opencv_from_extern(ret) };
@@ -9581,7 +11345,10 @@ opencv_type_boxed! { PriorBoxLayer }
 impl Drop for PriorBoxLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_PriorBoxLayer_delete(instance: *mut c_void); }
-		unsafe { cv_PriorBoxLayer_delete(self.as_raw_mut_PriorBoxLayer()) };
+		let ptr = self.as_raw_mut_PriorBoxLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_PriorBoxLayer_delete(ptr) };
 	}
 }
 
@@ -9615,7 +11382,7 @@ impl PriorBoxLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::PriorBoxLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_PriorBoxLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_PriorBoxLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::PriorBoxLayer>::opencv_from_extern(ret) };
@@ -9647,7 +11414,10 @@ opencv_type_boxed! { ProposalLayer }
 impl Drop for ProposalLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ProposalLayer_delete(instance: *mut c_void); }
-		unsafe { cv_ProposalLayer_delete(self.as_raw_mut_ProposalLayer()) };
+		let ptr = self.as_raw_mut_ProposalLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ProposalLayer_delete(ptr) };
 	}
 }
 
@@ -9681,7 +11451,7 @@ impl ProposalLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ProposalLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_ProposalLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_ProposalLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::ProposalLayer>::opencv_from_extern(ret) };
@@ -9699,16 +11469,34 @@ pub trait QuantizeLayerTraitConst: crate::dnn::LayerTraitConst {
 
 	#[inline]
 	fn scale(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_QuantizeLayer_getPropScale_const(self.as_raw_QuantizeLayer()) };
+		crate::core::validity::debug_check("QuantizeLayer", self.as_raw_QuantizeLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_QuantizeLayer_getPropScale_const(self.as_raw_QuantizeLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [scale](QuantizeLayerTraitConst::scale), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_scale(&self) -> Result<f32> {
+		crate::core::validity::check("QuantizeLayer", self.as_raw_QuantizeLayer())?;
+		Ok(QuantizeLayerTraitConst::scale(self))
+	}
+	
 	#[inline]
 	fn zeropoint(&self) -> i32 {
-		let ret = unsafe { sys::cv_dnn_QuantizeLayer_getPropZeropoint_const(self.as_raw_QuantizeLayer()) };
+		crate::core::validity::debug_check("QuantizeLayer", self.as_raw_QuantizeLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_QuantizeLayer_getPropZeropoint_const(self.as_raw_QuantizeLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [zeropoint](QuantizeLayerTraitConst::zeropoint), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_zeropoint(&self) -> Result<i32> {
+		crate::core::validity::check("QuantizeLayer", self.as_raw_QuantizeLayer())?;
+		Ok(QuantizeLayerTraitConst::zeropoint(self))
+	}
+	
 }
 
 pub trait QuantizeLayerTrait: crate::dnn::LayerTrait + crate::dnn::QuantizeLayerTraitConst {
@@ -9716,13 +11504,15 @@ pub trait QuantizeLayerTrait: crate::dnn::LayerTrait + crate::dnn::QuantizeLayer
 
 	#[inline]
 	fn set_scale(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_QuantizeLayer_setPropScale_float(self.as_raw_mut_QuantizeLayer(), val) };
+		crate::core::validity::debug_check("QuantizeLayer", self.as_raw_mut_QuantizeLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_QuantizeLayer_setPropScale_float(self.as_raw_mut_QuantizeLayer(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_zeropoint(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_dnn_QuantizeLayer_setPropZeropoint_int(self.as_raw_mut_QuantizeLayer(), val) };
+		crate::core::validity::debug_check("QuantizeLayer", self.as_raw_mut_QuantizeLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_QuantizeLayer_setPropZeropoint_int(self.as_raw_mut_QuantizeLayer(), val)) };
 		ret
 	}
 	
@@ -9737,7 +11527,10 @@ opencv_type_boxed! { QuantizeLayer }
 impl Drop for QuantizeLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_QuantizeLayer_delete(instance: *mut c_void); }
-		unsafe { cv_QuantizeLayer_delete(self.as_raw_mut_QuantizeLayer()) };
+		let ptr = self.as_raw_mut_QuantizeLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_QuantizeLayer_delete(ptr) };
 	}
 }
 
@@ -9771,7 +11564,7 @@ impl QuantizeLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::QuantizeLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_QuantizeLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_QuantizeLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::QuantizeLayer>::opencv_from_extern(ret) };
@@ -9791,11 +11584,11 @@ boxed_cast_base! { QuantizeLayer, crate::dnn::Layer, cv_QuantizeLayer_to_Layer }
 /// - input: should contain packed input @f$x_t@f$.
 /// - output: should contain output @f$o_t@f$ (and @f$h_t@f$ if setProduceHiddenOutput() is set to true).
 /// 
-/// input[0] should have shape [`T`, `N`, `data_dims`] where `T` and `N` is number of timestamps and number of independent samples of @f$x_t@f$ respectively.
+/// input\[0\] should have shape \[`T`, `N`, `data_dims`\] where `T` and `N` is number of timestamps and number of independent samples of @f$x_t@f$ respectively.
 /// 
-/// output[0] will have shape [`T`, `N`, @f$N_o@f$], where @f$N_o@f$ is number of rows in @f$ W_{xo} @f$ matrix.
+/// output\[0\] will have shape \[`T`, `N`, @f$N_o@f$\], where @f$N_o@f$ is number of rows in @f$ W_{xo} @f$ matrix.
 /// 
-/// If setProduceHiddenOutput() is set to true then @p output[1] will contain a Mat with shape [`T`, `N`, @f$N_h@f$], where @f$N_h@f$ is number of rows in @f$ W_{hh} @f$ matrix.
+/// If setProduceHiddenOutput() is set to true then @p output\[1\] will contain a Mat with shape \[`T`, `N`, @f$N_h@f$\], where @f$N_h@f$ is number of rows in @f$ W_{hh} @f$ matrix.
 pub trait RNNLayerConst: crate::dnn::LayerTraitConst {
 	fn as_raw_RNNLayer(&self) -> *const c_void;
 
@@ -9820,8 +11613,9 @@ pub trait RNNLayer: crate::dnn::LayerTrait + crate::dnn::RNNLayerConst {
 	/// * bo: is @f$ b_{o}  @f$ vector
 	#[inline]
 	fn set_weights(&mut self, wxh: &core::Mat, bh: &core::Mat, whh: &core::Mat, who: &core::Mat, bo: &core::Mat) -> Result<()> {
+		crate::core::validity::check("RNNLayer", self.as_raw_mut_RNNLayer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_RNNLayer_setWeights_const_MatR_const_MatR_const_MatR_const_MatR_const_MatR(self.as_raw_mut_RNNLayer(), wxh.as_raw_Mat(), bh.as_raw_Mat(), whh.as_raw_Mat(), who.as_raw_Mat(), bo.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_RNNLayer_setWeights_const_MatR_const_MatR_const_MatR_const_MatR_const_MatR(self.as_raw_mut_RNNLayer(), wxh.as_raw_Mat(), bh.as_raw_Mat(), whh.as_raw_Mat(), who.as_raw_Mat(), bo.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -9834,8 +11628,9 @@ pub trait RNNLayer: crate::dnn::LayerTrait + crate::dnn::RNNLayerConst {
 	/// * produce: false
 	#[inline]
 	fn set_produce_hidden_output(&mut self, produce: bool) -> Result<()> {
+		crate::core::validity::check("RNNLayer", self.as_raw_mut_RNNLayer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_RNNLayer_setProduceHiddenOutput_bool(self.as_raw_mut_RNNLayer(), produce, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_RNNLayer_setProduceHiddenOutput_bool(self.as_raw_mut_RNNLayer(), produce, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -9848,7 +11643,7 @@ impl dyn RNNLayer + '_ {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<dyn crate::dnn::RNNLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_RNNLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_RNNLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::dnn::RNNLayer>::opencv_from_extern(ret) };
@@ -9861,16 +11656,34 @@ pub trait ReLU6LayerTraitConst: crate::dnn::ActivationLayerTraitConst {
 
 	#[inline]
 	fn min_value(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_ReLU6Layer_getPropMinValue_const(self.as_raw_ReLU6Layer()) };
+		crate::core::validity::debug_check("ReLU6Layer", self.as_raw_ReLU6Layer());
+		let ret = unsafe { ffi_call!(cv_dnn_ReLU6Layer_getPropMinValue_const(self.as_raw_ReLU6Layer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [min_value](ReLU6LayerTraitConst::min_value), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_min_value(&self) -> Result<f32> {
+		crate::core::validity::check("ReLU6Layer", self.as_raw_ReLU6Layer())?;
+		Ok(ReLU6LayerTraitConst::min_value(self))
+	}
+	
 	#[inline]
 	fn max_value(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_ReLU6Layer_getPropMaxValue_const(self.as_raw_ReLU6Layer()) };
+		crate::core::validity::debug_check("ReLU6Layer", self.as_raw_ReLU6Layer());
+		let ret = unsafe { ffi_call!(cv_dnn_ReLU6Layer_getPropMaxValue_const(self.as_raw_ReLU6Layer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [max_value](ReLU6LayerTraitConst::max_value), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_max_value(&self) -> Result<f32> {
+		crate::core::validity::check("ReLU6Layer", self.as_raw_ReLU6Layer())?;
+		Ok(ReLU6LayerTraitConst::max_value(self))
+	}
+	
 }
 
 pub trait ReLU6LayerTrait: crate::dnn::ActivationLayerTrait + crate::dnn::ReLU6LayerTraitConst {
@@ -9878,13 +11691,15 @@ pub trait ReLU6LayerTrait: crate::dnn::ActivationLayerTrait + crate::dnn::ReLU6L
 
 	#[inline]
 	fn set_min_value(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_ReLU6Layer_setPropMinValue_float(self.as_raw_mut_ReLU6Layer(), val) };
+		crate::core::validity::debug_check("ReLU6Layer", self.as_raw_mut_ReLU6Layer());
+		let ret = unsafe { ffi_call!(cv_dnn_ReLU6Layer_setPropMinValue_float(self.as_raw_mut_ReLU6Layer(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_max_value(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_ReLU6Layer_setPropMaxValue_float(self.as_raw_mut_ReLU6Layer(), val) };
+		crate::core::validity::debug_check("ReLU6Layer", self.as_raw_mut_ReLU6Layer());
+		let ret = unsafe { ffi_call!(cv_dnn_ReLU6Layer_setPropMaxValue_float(self.as_raw_mut_ReLU6Layer(), val)) };
 		ret
 	}
 	
@@ -9899,7 +11714,10 @@ opencv_type_boxed! { ReLU6Layer }
 impl Drop for ReLU6Layer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ReLU6Layer_delete(instance: *mut c_void); }
-		unsafe { cv_ReLU6Layer_delete(self.as_raw_mut_ReLU6Layer()) };
+		let ptr = self.as_raw_mut_ReLU6Layer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ReLU6Layer_delete(ptr) };
 	}
 }
 
@@ -9941,7 +11759,7 @@ impl ReLU6Layer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ReLU6Layer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_ReLU6Layer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_ReLU6Layer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::ReLU6Layer>::opencv_from_extern(ret) };
@@ -9961,10 +11779,19 @@ pub trait ReLULayerTraitConst: crate::dnn::ActivationLayerTraitConst {
 
 	#[inline]
 	fn negative_slope(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_ReLULayer_getPropNegativeSlope_const(self.as_raw_ReLULayer()) };
+		crate::core::validity::debug_check("ReLULayer", self.as_raw_ReLULayer());
+		let ret = unsafe { ffi_call!(cv_dnn_ReLULayer_getPropNegativeSlope_const(self.as_raw_ReLULayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [negative_slope](ReLULayerTraitConst::negative_slope), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_negative_slope(&self) -> Result<f32> {
+		crate::core::validity::check("ReLULayer", self.as_raw_ReLULayer())?;
+		Ok(ReLULayerTraitConst::negative_slope(self))
+	}
+	
 }
 
 pub trait ReLULayerTrait: crate::dnn::ActivationLayerTrait + crate::dnn::ReLULayerTraitConst {
@@ -9972,7 +11799,8 @@ pub trait ReLULayerTrait: crate::dnn::ActivationLayerTrait + crate::dnn::ReLULay
 
 	#[inline]
 	fn set_negative_slope(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_ReLULayer_setPropNegativeSlope_float(self.as_raw_mut_ReLULayer(), val) };
+		crate::core::validity::debug_check("ReLULayer", self.as_raw_mut_ReLULayer());
+		let ret = unsafe { ffi_call!(cv_dnn_ReLULayer_setPropNegativeSlope_float(self.as_raw_mut_ReLULayer(), val)) };
 		ret
 	}
 	
@@ -9987,7 +11815,10 @@ opencv_type_boxed! { ReLULayer }
 impl Drop for ReLULayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ReLULayer_delete(instance: *mut c_void); }
-		unsafe { cv_ReLULayer_delete(self.as_raw_mut_ReLULayer()) };
+		let ptr = self.as_raw_mut_ReLULayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ReLULayer_delete(ptr) };
 	}
 }
 
@@ -10029,7 +11860,7 @@ impl ReLULayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ReLULayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_ReLULayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_ReLULayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::ReLULayer>::opencv_from_extern(ret) };
@@ -10049,10 +11880,19 @@ pub trait RegionLayerTraitConst: crate::dnn::LayerTraitConst {
 
 	#[inline]
 	fn nms_threshold(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_RegionLayer_getPropNmsThreshold_const(self.as_raw_RegionLayer()) };
+		crate::core::validity::debug_check("RegionLayer", self.as_raw_RegionLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_RegionLayer_getPropNmsThreshold_const(self.as_raw_RegionLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [nms_threshold](RegionLayerTraitConst::nms_threshold), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_nms_threshold(&self) -> Result<f32> {
+		crate::core::validity::check("RegionLayer", self.as_raw_RegionLayer())?;
+		Ok(RegionLayerTraitConst::nms_threshold(self))
+	}
+	
 }
 
 pub trait RegionLayerTrait: crate::dnn::LayerTrait + crate::dnn::RegionLayerTraitConst {
@@ -10060,7 +11900,8 @@ pub trait RegionLayerTrait: crate::dnn::LayerTrait + crate::dnn::RegionLayerTrai
 
 	#[inline]
 	fn set_nms_threshold(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_RegionLayer_setPropNmsThreshold_float(self.as_raw_mut_RegionLayer(), val) };
+		crate::core::validity::debug_check("RegionLayer", self.as_raw_mut_RegionLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_RegionLayer_setPropNmsThreshold_float(self.as_raw_mut_RegionLayer(), val)) };
 		ret
 	}
 	
@@ -10075,7 +11916,10 @@ opencv_type_boxed! { RegionLayer }
 impl Drop for RegionLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_RegionLayer_delete(instance: *mut c_void); }
-		unsafe { cv_RegionLayer_delete(self.as_raw_mut_RegionLayer()) };
+		let ptr = self.as_raw_mut_RegionLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_RegionLayer_delete(ptr) };
 	}
 }
 
@@ -10109,7 +11953,7 @@ impl RegionLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::RegionLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_RegionLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_RegionLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::RegionLayer>::opencv_from_extern(ret) };
@@ -10141,7 +11985,10 @@ opencv_type_boxed! { ReorgLayer }
 impl Drop for ReorgLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ReorgLayer_delete(instance: *mut c_void); }
-		unsafe { cv_ReorgLayer_delete(self.as_raw_mut_ReorgLayer()) };
+		let ptr = self.as_raw_mut_ReorgLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ReorgLayer_delete(ptr) };
 	}
 }
 
@@ -10175,7 +12022,7 @@ impl ReorgLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ReorgLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_ReorgLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_ReorgLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::ReorgLayer>::opencv_from_extern(ret) };
@@ -10193,16 +12040,34 @@ pub trait RequantizeLayerTraitConst: crate::dnn::LayerTraitConst {
 
 	#[inline]
 	fn scale(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_RequantizeLayer_getPropScale_const(self.as_raw_RequantizeLayer()) };
+		crate::core::validity::debug_check("RequantizeLayer", self.as_raw_RequantizeLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_RequantizeLayer_getPropScale_const(self.as_raw_RequantizeLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [scale](RequantizeLayerTraitConst::scale), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_scale(&self) -> Result<f32> {
+		crate::core::validity::check("RequantizeLayer", self.as_raw_RequantizeLayer())?;
+		Ok(RequantizeLayerTraitConst::scale(self))
+	}
+	
 	#[inline]
 	fn shift(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_RequantizeLayer_getPropShift_const(self.as_raw_RequantizeLayer()) };
+		crate::core::validity::debug_check("RequantizeLayer", self.as_raw_RequantizeLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_RequantizeLayer_getPropShift_const(self.as_raw_RequantizeLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [shift](RequantizeLayerTraitConst::shift), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_shift(&self) -> Result<f32> {
+		crate::core::validity::check("RequantizeLayer", self.as_raw_RequantizeLayer())?;
+		Ok(RequantizeLayerTraitConst::shift(self))
+	}
+	
 }
 
 pub trait RequantizeLayerTrait: crate::dnn::LayerTrait + crate::dnn::RequantizeLayerTraitConst {
@@ -10210,13 +12075,15 @@ pub trait RequantizeLayerTrait: crate::dnn::LayerTrait + crate::dnn::RequantizeL
 
 	#[inline]
 	fn set_scale(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_RequantizeLayer_setPropScale_float(self.as_raw_mut_RequantizeLayer(), val) };
+		crate::core::validity::debug_check("RequantizeLayer", self.as_raw_mut_RequantizeLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_RequantizeLayer_setPropScale_float(self.as_raw_mut_RequantizeLayer(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_shift(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_RequantizeLayer_setPropShift_float(self.as_raw_mut_RequantizeLayer(), val) };
+		crate::core::validity::debug_check("RequantizeLayer", self.as_raw_mut_RequantizeLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_RequantizeLayer_setPropShift_float(self.as_raw_mut_RequantizeLayer(), val)) };
 		ret
 	}
 	
@@ -10231,7 +12098,10 @@ opencv_type_boxed! { RequantizeLayer }
 impl Drop for RequantizeLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_RequantizeLayer_delete(instance: *mut c_void); }
-		unsafe { cv_RequantizeLayer_delete(self.as_raw_mut_RequantizeLayer()) };
+		let ptr = self.as_raw_mut_RequantizeLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_RequantizeLayer_delete(ptr) };
 	}
 }
 
@@ -10265,7 +12135,7 @@ impl RequantizeLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::RequantizeLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_RequantizeLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_RequantizeLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::RequantizeLayer>::opencv_from_extern(ret) };
@@ -10283,18 +12153,36 @@ pub trait ReshapeLayerTraitConst: crate::dnn::LayerTraitConst {
 
 	#[inline]
 	fn new_shape_desc(&self) -> core::Vector<i32> {
-		let ret = unsafe { sys::cv_dnn_ReshapeLayer_getPropNewShapeDesc_const(self.as_raw_ReshapeLayer()) };
+		crate::core::validity::debug_check("ReshapeLayer", self.as_raw_ReshapeLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_ReshapeLayer_getPropNewShapeDesc_const(self.as_raw_ReshapeLayer())) };
 		let ret = unsafe { core::Vector::<i32>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [new_shape_desc](ReshapeLayerTraitConst::new_shape_desc), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_new_shape_desc(&self) -> Result<core::Vector<i32>> {
+		crate::core::validity::check("ReshapeLayer", self.as_raw_ReshapeLayer())?;
+		Ok(ReshapeLayerTraitConst::new_shape_desc(self))
+	}
+	
 	#[inline]
 	fn new_shape_range(&self) -> core::Range {
-		let ret = unsafe { sys::cv_dnn_ReshapeLayer_getPropNewShapeRange_const(self.as_raw_ReshapeLayer()) };
+		crate::core::validity::debug_check("ReshapeLayer", self.as_raw_ReshapeLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_ReshapeLayer_getPropNewShapeRange_const(self.as_raw_ReshapeLayer())) };
 		let ret = unsafe { core::Range::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [new_shape_range](ReshapeLayerTraitConst::new_shape_range), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_new_shape_range(&self) -> Result<core::Range> {
+		crate::core::validity::check("ReshapeLayer", self.as_raw_ReshapeLayer())?;
+		Ok(ReshapeLayerTraitConst::new_shape_range(self))
+	}
+	
 }
 
 pub trait ReshapeLayerTrait: crate::dnn::LayerTrait + crate::dnn::ReshapeLayerTraitConst {
@@ -10302,13 +12190,15 @@ pub trait ReshapeLayerTrait: crate::dnn::LayerTrait + crate::dnn::ReshapeLayerTr
 
 	#[inline]
 	fn set_new_shape_desc(&mut self, mut val: crate::dnn::MatShape) {
-		let ret = unsafe { sys::cv_dnn_ReshapeLayer_setPropNewShapeDesc_MatShape(self.as_raw_mut_ReshapeLayer(), val.as_raw_mut_VectorOfi32()) };
+		crate::core::validity::debug_check("ReshapeLayer", self.as_raw_mut_ReshapeLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_ReshapeLayer_setPropNewShapeDesc_MatShape(self.as_raw_mut_ReshapeLayer(), val.as_raw_mut_VectorOfi32())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_new_shape_range(&mut self, mut val: core::Range) {
-		let ret = unsafe { sys::cv_dnn_ReshapeLayer_setPropNewShapeRange_Range(self.as_raw_mut_ReshapeLayer(), val.as_raw_mut_Range()) };
+		crate::core::validity::debug_check("ReshapeLayer", self.as_raw_mut_ReshapeLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_ReshapeLayer_setPropNewShapeRange_Range(self.as_raw_mut_ReshapeLayer(), val.as_raw_mut_Range())) };
 		ret
 	}
 	
@@ -10323,7 +12213,10 @@ opencv_type_boxed! { ReshapeLayer }
 impl Drop for ReshapeLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ReshapeLayer_delete(instance: *mut c_void); }
-		unsafe { cv_ReshapeLayer_delete(self.as_raw_mut_ReshapeLayer()) };
+		let ptr = self.as_raw_mut_ReshapeLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ReshapeLayer_delete(ptr) };
 	}
 }
 
@@ -10357,7 +12250,7 @@ impl ReshapeLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ReshapeLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_ReshapeLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_ReshapeLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::ReshapeLayer>::opencv_from_extern(ret) };
@@ -10395,7 +12288,10 @@ opencv_type_boxed! { ResizeLayer }
 impl Drop for ResizeLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ResizeLayer_delete(instance: *mut c_void); }
-		unsafe { cv_ResizeLayer_delete(self.as_raw_mut_ResizeLayer()) };
+		let ptr = self.as_raw_mut_ResizeLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ResizeLayer_delete(ptr) };
 	}
 }
 
@@ -10429,7 +12325,7 @@ impl ResizeLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ResizeLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_ResizeLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_ResizeLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::ResizeLayer>::opencv_from_extern(ret) };
@@ -10461,7 +12357,10 @@ opencv_type_boxed! { RoundLayer }
 impl Drop for RoundLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_RoundLayer_delete(instance: *mut c_void); }
-		unsafe { cv_RoundLayer_delete(self.as_raw_mut_RoundLayer()) };
+		let ptr = self.as_raw_mut_RoundLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_RoundLayer_delete(ptr) };
 	}
 }
 
@@ -10503,7 +12402,7 @@ impl RoundLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::RoundLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_RoundLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_RoundLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::RoundLayer>::opencv_from_extern(ret) };
@@ -10523,23 +12422,50 @@ pub trait ScaleLayerTraitConst: crate::dnn::LayerTraitConst {
 
 	#[inline]
 	fn has_bias(&self) -> bool {
-		let ret = unsafe { sys::cv_dnn_ScaleLayer_getPropHasBias_const(self.as_raw_ScaleLayer()) };
+		crate::core::validity::debug_check("ScaleLayer", self.as_raw_ScaleLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_ScaleLayer_getPropHasBias_const(self.as_raw_ScaleLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [has_bias](ScaleLayerTraitConst::has_bias), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_has_bias(&self) -> Result<bool> {
+		crate::core::validity::check("ScaleLayer", self.as_raw_ScaleLayer())?;
+		Ok(ScaleLayerTraitConst::has_bias(self))
+	}
+	
 	#[inline]
 	fn axis(&self) -> i32 {
-		let ret = unsafe { sys::cv_dnn_ScaleLayer_getPropAxis_const(self.as_raw_ScaleLayer()) };
+		crate::core::validity::debug_check("ScaleLayer", self.as_raw_ScaleLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_ScaleLayer_getPropAxis_const(self.as_raw_ScaleLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [axis](ScaleLayerTraitConst::axis), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_axis(&self) -> Result<i32> {
+		crate::core::validity::check("ScaleLayer", self.as_raw_ScaleLayer())?;
+		Ok(ScaleLayerTraitConst::axis(self))
+	}
+	
 	#[inline]
 	fn mode(&self) -> String {
-		let ret = unsafe { sys::cv_dnn_ScaleLayer_getPropMode_const(self.as_raw_ScaleLayer()) };
+		crate::core::validity::debug_check("ScaleLayer", self.as_raw_ScaleLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_ScaleLayer_getPropMode_const(self.as_raw_ScaleLayer())) };
 		let ret = unsafe { String::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [mode](ScaleLayerTraitConst::mode), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_mode(&self) -> Result<String> {
+		crate::core::validity::check("ScaleLayer", self.as_raw_ScaleLayer())?;
+		Ok(ScaleLayerTraitConst::mode(self))
+	}
+	
 }
 
 pub trait ScaleLayerTrait: crate::dnn::LayerTrait + crate::dnn::ScaleLayerTraitConst {
@@ -10547,20 +12473,23 @@ pub trait ScaleLayerTrait: crate::dnn::LayerTrait + crate::dnn::ScaleLayerTraitC
 
 	#[inline]
 	fn set_has_bias(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_dnn_ScaleLayer_setPropHasBias_bool(self.as_raw_mut_ScaleLayer(), val) };
+		crate::core::validity::debug_check("ScaleLayer", self.as_raw_mut_ScaleLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_ScaleLayer_setPropHasBias_bool(self.as_raw_mut_ScaleLayer(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_axis(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_dnn_ScaleLayer_setPropAxis_int(self.as_raw_mut_ScaleLayer(), val) };
+		crate::core::validity::debug_check("ScaleLayer", self.as_raw_mut_ScaleLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_ScaleLayer_setPropAxis_int(self.as_raw_mut_ScaleLayer(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_mode(&mut self, val: &str) {
+		crate::core::validity::debug_check("ScaleLayer", self.as_raw_mut_ScaleLayer());
 		extern_container_arg!(nofail mut val);
-		let ret = unsafe { sys::cv_dnn_ScaleLayer_setPropMode_String(self.as_raw_mut_ScaleLayer(), val.opencv_as_extern_mut()) };
+		let ret = unsafe { ffi_call!(cv_dnn_ScaleLayer_setPropMode_String(self.as_raw_mut_ScaleLayer(), val.opencv_as_extern_mut())) };
 		ret
 	}
 	
@@ -10575,7 +12504,10 @@ opencv_type_boxed! { ScaleLayer }
 impl Drop for ScaleLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ScaleLayer_delete(instance: *mut c_void); }
-		unsafe { cv_ScaleLayer_delete(self.as_raw_mut_ScaleLayer()) };
+		let ptr = self.as_raw_mut_ScaleLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ScaleLayer_delete(ptr) };
 	}
 }
 
@@ -10609,7 +12541,7 @@ impl ScaleLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ScaleLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_ScaleLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_ScaleLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::ScaleLayer>::opencv_from_extern(ret) };
@@ -10627,16 +12559,34 @@ pub trait ScaleLayerInt8TraitConst: crate::dnn::ScaleLayerTraitConst {
 
 	#[inline]
 	fn output_sc(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_ScaleLayerInt8_getPropOutput_sc_const(self.as_raw_ScaleLayerInt8()) };
+		crate::core::validity::debug_check("ScaleLayerInt8", self.as_raw_ScaleLayerInt8());
+		let ret = unsafe { ffi_call!(cv_dnn_ScaleLayerInt8_getPropOutput_sc_const(self.as_raw_ScaleLayerInt8())) };
 		ret
 	}
 	
+	/// Non-panicking version of [output_sc](ScaleLayerInt8TraitConst::output_sc), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_output_sc(&self) -> Result<f32> {
+		crate::core::validity::check("ScaleLayerInt8", self.as_raw_ScaleLayerInt8())?;
+		Ok(ScaleLayerInt8TraitConst::output_sc(self))
+	}
+	
 	#[inline]
 	fn output_zp(&self) -> i32 {
-		let ret = unsafe { sys::cv_dnn_ScaleLayerInt8_getPropOutput_zp_const(self.as_raw_ScaleLayerInt8()) };
+		crate::core::validity::debug_check("ScaleLayerInt8", self.as_raw_ScaleLayerInt8());
+		let ret = unsafe { ffi_call!(cv_dnn_ScaleLayerInt8_getPropOutput_zp_const(self.as_raw_ScaleLayerInt8())) };
 		ret
 	}
 	
+	/// Non-panicking version of [output_zp](ScaleLayerInt8TraitConst::output_zp), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_output_zp(&self) -> Result<i32> {
+		crate::core::validity::check("ScaleLayerInt8", self.as_raw_ScaleLayerInt8())?;
+		Ok(ScaleLayerInt8TraitConst::output_zp(self))
+	}
+	
 }
 
 pub trait ScaleLayerInt8Trait: crate::dnn::ScaleLayerInt8TraitConst + crate::dnn::ScaleLayerTrait {
@@ -10644,13 +12594,15 @@ pub trait ScaleLayerInt8Trait: crate::dnn::ScaleLayerInt8TraitConst + crate::dnn
 
 	#[inline]
 	fn set_output_sc(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_ScaleLayerInt8_setPropOutput_sc_float(self.as_raw_mut_ScaleLayerInt8(), val) };
+		crate::core::validity::debug_check("ScaleLayerInt8", self.as_raw_mut_ScaleLayerInt8());
+		let ret = unsafe { ffi_call!(cv_dnn_ScaleLayerInt8_setPropOutput_sc_float(self.as_raw_mut_ScaleLayerInt8(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_output_zp(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_dnn_ScaleLayerInt8_setPropOutput_zp_int(self.as_raw_mut_ScaleLayerInt8(), val) };
+		crate::core::validity::debug_check("ScaleLayerInt8", self.as_raw_mut_ScaleLayerInt8());
+		let ret = unsafe { ffi_call!(cv_dnn_ScaleLayerInt8_setPropOutput_zp_int(self.as_raw_mut_ScaleLayerInt8(), val)) };
 		ret
 	}
 	
@@ -10665,7 +12617,10 @@ opencv_type_boxed! { ScaleLayerInt8 }
 impl Drop for ScaleLayerInt8 {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ScaleLayerInt8_delete(instance: *mut c_void); }
-		unsafe { cv_ScaleLayerInt8_delete(self.as_raw_mut_ScaleLayerInt8()) };
+		let ptr = self.as_raw_mut_ScaleLayerInt8();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ScaleLayerInt8_delete(ptr) };
 	}
 }
 
@@ -10707,7 +12662,7 @@ impl ScaleLayerInt8 {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ScaleLayerInt8>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_ScaleLayerInt8_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_ScaleLayerInt8_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::ScaleLayerInt8>::opencv_from_extern(ret) };
@@ -10738,13 +12693,14 @@ pub trait SegmentationModelTrait: crate::dnn::ModelTrait + crate::dnn::Segmentat
 	/// Given the @p input frame, create input blob, run net
 	/// ## Parameters
 	/// * frame: The input image.
-	/// * mask:[out] Allocated class prediction for each pixel
+	/// * mask:\[out\] Allocated class prediction for each pixel
 	#[inline]
 	fn segment(&mut self, frame: &dyn core::ToInputArray, mask: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("SegmentationModel", self.as_raw_mut_SegmentationModel())?;
 		input_array_arg!(frame);
 		output_array_arg!(mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_SegmentationModel_segment_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_SegmentationModel(), frame.as_raw__InputArray(), mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_SegmentationModel_segment_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_SegmentationModel(), frame.as_raw__InputArray(), mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -10766,7 +12722,10 @@ opencv_type_boxed! { SegmentationModel }
 impl Drop for SegmentationModel {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SegmentationModel_delete(instance: *mut c_void); }
-		unsafe { cv_SegmentationModel_delete(self.as_raw_mut_SegmentationModel()) };
+		let ptr = self.as_raw_mut_SegmentationModel();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_SegmentationModel_delete(ptr) };
 	}
 }
 
@@ -10802,7 +12761,7 @@ impl SegmentationModel {
 		extern_container_arg!(model);
 		extern_container_arg!(config);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_SegmentationModel_SegmentationModel_const_StringR_const_StringR(model.opencv_as_extern(), config.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_SegmentationModel_SegmentationModel_const_StringR_const_StringR(model.opencv_as_extern(), config.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::SegmentationModel::opencv_from_extern(ret) };
@@ -10815,7 +12774,7 @@ impl SegmentationModel {
 	#[inline]
 	pub fn new_1(network: &crate::dnn::Net) -> Result<crate::dnn::SegmentationModel> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_SegmentationModel_SegmentationModel_const_NetR(network.as_raw_Net(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_SegmentationModel_SegmentationModel_const_NetR(network.as_raw_Net(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::SegmentationModel::opencv_from_extern(ret) };
@@ -10831,16 +12790,34 @@ pub trait SeluLayerTraitConst: crate::dnn::ActivationLayerTraitConst {
 
 	#[inline]
 	fn alpha(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_SeluLayer_getPropAlpha_const(self.as_raw_SeluLayer()) };
+		crate::core::validity::debug_check("SeluLayer", self.as_raw_SeluLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_SeluLayer_getPropAlpha_const(self.as_raw_SeluLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [alpha](SeluLayerTraitConst::alpha), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_alpha(&self) -> Result<f32> {
+		crate::core::validity::check("SeluLayer", self.as_raw_SeluLayer())?;
+		Ok(SeluLayerTraitConst::alpha(self))
+	}
+	
 	#[inline]
 	fn gamma(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_SeluLayer_getPropGamma_const(self.as_raw_SeluLayer()) };
+		crate::core::validity::debug_check("SeluLayer", self.as_raw_SeluLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_SeluLayer_getPropGamma_const(self.as_raw_SeluLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [gamma](SeluLayerTraitConst::gamma), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_gamma(&self) -> Result<f32> {
+		crate::core::validity::check("SeluLayer", self.as_raw_SeluLayer())?;
+		Ok(SeluLayerTraitConst::gamma(self))
+	}
+	
 }
 
 pub trait SeluLayerTrait: crate::dnn::ActivationLayerTrait + crate::dnn::SeluLayerTraitConst {
@@ -10848,13 +12825,15 @@ pub trait SeluLayerTrait: crate::dnn::ActivationLayerTrait + crate::dnn::SeluLay
 
 	#[inline]
 	fn set_alpha(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_SeluLayer_setPropAlpha_float(self.as_raw_mut_SeluLayer(), val) };
+		crate::core::validity::debug_check("SeluLayer", self.as_raw_mut_SeluLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_SeluLayer_setPropAlpha_float(self.as_raw_mut_SeluLayer(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_gamma(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_SeluLayer_setPropGamma_float(self.as_raw_mut_SeluLayer(), val) };
+		crate::core::validity::debug_check("SeluLayer", self.as_raw_mut_SeluLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_SeluLayer_setPropGamma_float(self.as_raw_mut_SeluLayer(), val)) };
 		ret
 	}
 	
@@ -10869,7 +12848,10 @@ opencv_type_boxed! { SeluLayer }
 impl Drop for SeluLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SeluLayer_delete(instance: *mut c_void); }
-		unsafe { cv_SeluLayer_delete(self.as_raw_mut_SeluLayer()) };
+		let ptr = self.as_raw_mut_SeluLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_SeluLayer_delete(ptr) };
 	}
 }
 
@@ -10911,7 +12893,7 @@ impl SeluLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::SeluLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_SeluLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_SeluLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::SeluLayer>::opencv_from_extern(ret) };
@@ -10945,7 +12927,10 @@ opencv_type_boxed! { ShiftLayer }
 impl Drop for ShiftLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ShiftLayer_delete(instance: *mut c_void); }
-		unsafe { cv_ShiftLayer_delete(self.as_raw_mut_ShiftLayer()) };
+		let ptr = self.as_raw_mut_ShiftLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ShiftLayer_delete(ptr) };
 	}
 }
 
@@ -10979,7 +12964,7 @@ impl ShiftLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::Layer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_ShiftLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_ShiftLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::Layer>::opencv_from_extern(ret) };
@@ -11011,7 +12996,10 @@ opencv_type_boxed! { ShiftLayerInt8 }
 impl Drop for ShiftLayerInt8 {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ShiftLayerInt8_delete(instance: *mut c_void); }
-		unsafe { cv_ShiftLayerInt8_delete(self.as_raw_mut_ShiftLayerInt8()) };
+		let ptr = self.as_raw_mut_ShiftLayerInt8();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ShiftLayerInt8_delete(ptr) };
 	}
 }
 
@@ -11045,7 +13033,7 @@ impl ShiftLayerInt8 {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::Layer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_ShiftLayerInt8_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_ShiftLayerInt8_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::Layer>::opencv_from_extern(ret) };
@@ -11071,10 +13059,19 @@ pub trait ShuffleChannelLayerTraitConst: crate::dnn::LayerTraitConst {
 
 	#[inline]
 	fn group(&self) -> i32 {
-		let ret = unsafe { sys::cv_dnn_ShuffleChannelLayer_getPropGroup_const(self.as_raw_ShuffleChannelLayer()) };
+		crate::core::validity::debug_check("ShuffleChannelLayer", self.as_raw_ShuffleChannelLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_ShuffleChannelLayer_getPropGroup_const(self.as_raw_ShuffleChannelLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [group](ShuffleChannelLayerTraitConst::group), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_group(&self) -> Result<i32> {
+		crate::core::validity::check("ShuffleChannelLayer", self.as_raw_ShuffleChannelLayer())?;
+		Ok(ShuffleChannelLayerTraitConst::group(self))
+	}
+	
 }
 
 pub trait ShuffleChannelLayerTrait: crate::dnn::LayerTrait + crate::dnn::ShuffleChannelLayerTraitConst {
@@ -11082,7 +13079,8 @@ pub trait ShuffleChannelLayerTrait: crate::dnn::LayerTrait + crate::dnn::Shuffle
 
 	#[inline]
 	fn set_group(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_dnn_ShuffleChannelLayer_setPropGroup_int(self.as_raw_mut_ShuffleChannelLayer(), val) };
+		crate::core::validity::debug_check("ShuffleChannelLayer", self.as_raw_mut_ShuffleChannelLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_ShuffleChannelLayer_setPropGroup_int(self.as_raw_mut_ShuffleChannelLayer(), val)) };
 		ret
 	}
 	
@@ -11105,7 +13103,10 @@ opencv_type_boxed! { ShuffleChannelLayer }
 impl Drop for ShuffleChannelLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ShuffleChannelLayer_delete(instance: *mut c_void); }
-		unsafe { cv_ShuffleChannelLayer_delete(self.as_raw_mut_ShuffleChannelLayer()) };
+		let ptr = self.as_raw_mut_ShuffleChannelLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ShuffleChannelLayer_delete(ptr) };
 	}
 }
 
@@ -11139,7 +13140,7 @@ impl ShuffleChannelLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::Layer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_ShuffleChannelLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_ShuffleChannelLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::Layer>::opencv_from_extern(ret) };
@@ -11171,7 +13172,10 @@ opencv_type_boxed! { SigmoidLayer }
 impl Drop for SigmoidLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SigmoidLayer_delete(instance: *mut c_void); }
-		unsafe { cv_SigmoidLayer_delete(self.as_raw_mut_SigmoidLayer()) };
+		let ptr = self.as_raw_mut_SigmoidLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_SigmoidLayer_delete(ptr) };
 	}
 }
 
@@ -11213,7 +13217,7 @@ impl SigmoidLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::SigmoidLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_SigmoidLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_SigmoidLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::SigmoidLayer>::opencv_from_extern(ret) };
@@ -11247,7 +13251,10 @@ opencv_type_boxed! { SinLayer }
 impl Drop for SinLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SinLayer_delete(instance: *mut c_void); }
-		unsafe { cv_SinLayer_delete(self.as_raw_mut_SinLayer()) };
+		let ptr = self.as_raw_mut_SinLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_SinLayer_delete(ptr) };
 	}
 }
 
@@ -11289,7 +13296,7 @@ impl SinLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::SinLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_SinLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_SinLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::SinLayer>::opencv_from_extern(ret) };
@@ -11323,7 +13330,10 @@ opencv_type_boxed! { SinhLayer }
 impl Drop for SinhLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SinhLayer_delete(instance: *mut c_void); }
-		unsafe { cv_SinhLayer_delete(self.as_raw_mut_SinhLayer()) };
+		let ptr = self.as_raw_mut_SinhLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_SinhLayer_delete(ptr) };
 	}
 }
 
@@ -11365,7 +13375,7 @@ impl SinhLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::SinhLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_SinhLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_SinhLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::SinhLayer>::opencv_from_extern(ret) };
@@ -11387,10 +13397,10 @@ boxed_cast_base! { SinhLayer, crate::dnn::Layer, cv_SinhLayer_to_Layer }
 /// * slice_point: Array of split points
 /// 
 /// Number of output blobs equals to number of split points plus one. The
-/// first blob is a slice on input from 0 to @p slice_point[0] - 1 by @p axis,
-/// the second output blob is a slice of input from @p slice_point[0] to
-/// @p slice_point[1] - 1 by @p axis and the last output blob is a slice of
-/// input from @p slice_point[-1] up to the end of @p axis size.
+/// first blob is a slice on input from 0 to @p slice_point\[0\] - 1 by @p axis,
+/// the second output blob is a slice of input from @p slice_point\[0\] to
+/// @p slice_point\[1\] - 1 by @p axis and the last output blob is a slice of
+/// input from @p slice_point\[-1\] up to the end of @p axis size.
 /// 
 /// 2. TensorFlow mode
 /// * begin: Vector of start indices
@@ -11412,30 +13422,66 @@ pub trait SliceLayerTraitConst: crate::dnn::LayerTraitConst {
 	/// Inner vector has slice ranges for the first number of input dimensions.
 	#[inline]
 	fn slice_ranges(&self) -> core::Vector<core::Vector<core::Range>> {
-		let ret = unsafe { sys::cv_dnn_SliceLayer_getPropSliceRanges_const(self.as_raw_SliceLayer()) };
+		crate::core::validity::debug_check("SliceLayer", self.as_raw_SliceLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_SliceLayer_getPropSliceRanges_const(self.as_raw_SliceLayer())) };
 		let ret = unsafe { core::Vector::<core::Vector<core::Range>>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [slice_ranges](SliceLayerTraitConst::slice_ranges), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_slice_ranges(&self) -> Result<core::Vector<core::Vector<core::Range>>> {
+		crate::core::validity::check("SliceLayer", self.as_raw_SliceLayer())?;
+		Ok(SliceLayerTraitConst::slice_ranges(self))
+	}
+	
 	#[inline]
 	fn slice_steps(&self) -> core::Vector<core::Vector<i32>> {
-		let ret = unsafe { sys::cv_dnn_SliceLayer_getPropSliceSteps_const(self.as_raw_SliceLayer()) };
+		crate::core::validity::debug_check("SliceLayer", self.as_raw_SliceLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_SliceLayer_getPropSliceSteps_const(self.as_raw_SliceLayer())) };
 		let ret = unsafe { core::Vector::<core::Vector<i32>>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [slice_steps](SliceLayerTraitConst::slice_steps), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_slice_steps(&self) -> Result<core::Vector<core::Vector<i32>>> {
+		crate::core::validity::check("SliceLayer", self.as_raw_SliceLayer())?;
+		Ok(SliceLayerTraitConst::slice_steps(self))
+	}
+	
 	#[inline]
 	fn axis(&self) -> i32 {
-		let ret = unsafe { sys::cv_dnn_SliceLayer_getPropAxis_const(self.as_raw_SliceLayer()) };
+		crate::core::validity::debug_check("SliceLayer", self.as_raw_SliceLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_SliceLayer_getPropAxis_const(self.as_raw_SliceLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [axis](SliceLayerTraitConst::axis), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_axis(&self) -> Result<i32> {
+		crate::core::validity::check("SliceLayer", self.as_raw_SliceLayer())?;
+		Ok(SliceLayerTraitConst::axis(self))
+	}
+	
 	#[inline]
 	fn num_split(&self) -> i32 {
-		let ret = unsafe { sys::cv_dnn_SliceLayer_getPropNum_split_const(self.as_raw_SliceLayer()) };
+		crate::core::validity::debug_check("SliceLayer", self.as_raw_SliceLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_SliceLayer_getPropNum_split_const(self.as_raw_SliceLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [num_split](SliceLayerTraitConst::num_split), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_num_split(&self) -> Result<i32> {
+		crate::core::validity::check("SliceLayer", self.as_raw_SliceLayer())?;
+		Ok(SliceLayerTraitConst::num_split(self))
+	}
+	
 }
 
 pub trait SliceLayerTrait: crate::dnn::LayerTrait + crate::dnn::SliceLayerTraitConst {
@@ -11447,25 +13493,29 @@ pub trait SliceLayerTrait: crate::dnn::LayerTrait + crate::dnn::SliceLayerTraitC
 	/// Inner vector has slice ranges for the first number of input dimensions.
 	#[inline]
 	fn set_slice_ranges(&mut self, mut val: core::Vector<core::Vector<core::Range>>) {
-		let ret = unsafe { sys::cv_dnn_SliceLayer_setPropSliceRanges_vector_vector_Range__(self.as_raw_mut_SliceLayer(), val.as_raw_mut_VectorOfVectorOfRange()) };
+		crate::core::validity::debug_check("SliceLayer", self.as_raw_mut_SliceLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_SliceLayer_setPropSliceRanges_vector_vector_Range__(self.as_raw_mut_SliceLayer(), val.as_raw_mut_VectorOfVectorOfRange())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_slice_steps(&mut self, mut val: core::Vector<core::Vector<i32>>) {
-		let ret = unsafe { sys::cv_dnn_SliceLayer_setPropSliceSteps_vector_vector_int__(self.as_raw_mut_SliceLayer(), val.as_raw_mut_VectorOfVectorOfi32()) };
+		crate::core::validity::debug_check("SliceLayer", self.as_raw_mut_SliceLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_SliceLayer_setPropSliceSteps_vector_vector_int__(self.as_raw_mut_SliceLayer(), val.as_raw_mut_VectorOfVectorOfi32())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_axis(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_dnn_SliceLayer_setPropAxis_int(self.as_raw_mut_SliceLayer(), val) };
+		crate::core::validity::debug_check("SliceLayer", self.as_raw_mut_SliceLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_SliceLayer_setPropAxis_int(self.as_raw_mut_SliceLayer(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_num_split(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_dnn_SliceLayer_setPropNum_split_int(self.as_raw_mut_SliceLayer(), val) };
+		crate::core::validity::debug_check("SliceLayer", self.as_raw_mut_SliceLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_SliceLayer_setPropNum_split_int(self.as_raw_mut_SliceLayer(), val)) };
 		ret
 	}
 	
@@ -11478,10 +13528,10 @@ pub trait SliceLayerTrait: crate::dnn::LayerTrait + crate::dnn::SliceLayerTraitC
 /// * slice_point: Array of split points
 /// 
 /// Number of output blobs equals to number of split points plus one. The
-/// first blob is a slice on input from 0 to @p slice_point[0] - 1 by @p axis,
-/// the second output blob is a slice of input from @p slice_point[0] to
-/// @p slice_point[1] - 1 by @p axis and the last output blob is a slice of
-/// input from @p slice_point[-1] up to the end of @p axis size.
+/// first blob is a slice on input from 0 to @p slice_point\[0\] - 1 by @p axis,
+/// the second output blob is a slice of input from @p slice_point\[0\] to
+/// @p slice_point\[1\] - 1 by @p axis and the last output blob is a slice of
+/// input from @p slice_point\[-1\] up to the end of @p axis size.
 /// 
 /// 2. TensorFlow mode
 /// * begin: Vector of start indices
@@ -11503,7 +13553,10 @@ opencv_type_boxed! { SliceLayer }
 impl Drop for SliceLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SliceLayer_delete(instance: *mut c_void); }
-		unsafe { cv_SliceLayer_delete(self.as_raw_mut_SliceLayer()) };
+		let ptr = self.as_raw_mut_SliceLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_SliceLayer_delete(ptr) };
 	}
 }
 
@@ -11537,7 +13590,7 @@ impl SliceLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::SliceLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_SliceLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_SliceLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::SliceLayer>::opencv_from_extern(ret) };
@@ -11555,10 +13608,19 @@ pub trait SoftmaxLayerTraitConst: crate::dnn::LayerTraitConst {
 
 	#[inline]
 	fn log_soft_max(&self) -> bool {
-		let ret = unsafe { sys::cv_dnn_SoftmaxLayer_getPropLogSoftMax_const(self.as_raw_SoftmaxLayer()) };
+		crate::core::validity::debug_check("SoftmaxLayer", self.as_raw_SoftmaxLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_SoftmaxLayer_getPropLogSoftMax_const(self.as_raw_SoftmaxLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [log_soft_max](SoftmaxLayerTraitConst::log_soft_max), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_log_soft_max(&self) -> Result<bool> {
+		crate::core::validity::check("SoftmaxLayer", self.as_raw_SoftmaxLayer())?;
+		Ok(SoftmaxLayerTraitConst::log_soft_max(self))
+	}
+	
 }
 
 pub trait SoftmaxLayerTrait: crate::dnn::LayerTrait + crate::dnn::SoftmaxLayerTraitConst {
@@ -11566,7 +13628,8 @@ pub trait SoftmaxLayerTrait: crate::dnn::LayerTrait + crate::dnn::SoftmaxLayerTr
 
 	#[inline]
 	fn set_log_soft_max(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_dnn_SoftmaxLayer_setPropLogSoftMax_bool(self.as_raw_mut_SoftmaxLayer(), val) };
+		crate::core::validity::debug_check("SoftmaxLayer", self.as_raw_mut_SoftmaxLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_SoftmaxLayer_setPropLogSoftMax_bool(self.as_raw_mut_SoftmaxLayer(), val)) };
 		ret
 	}
 	
@@ -11581,7 +13644,10 @@ opencv_type_boxed! { SoftmaxLayer }
 impl Drop for SoftmaxLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SoftmaxLayer_delete(instance: *mut c_void); }
-		unsafe { cv_SoftmaxLayer_delete(self.as_raw_mut_SoftmaxLayer()) };
+		let ptr = self.as_raw_mut_SoftmaxLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_SoftmaxLayer_delete(ptr) };
 	}
 }
 
@@ -11615,7 +13681,7 @@ impl SoftmaxLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::SoftmaxLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_SoftmaxLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_SoftmaxLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::SoftmaxLayer>::opencv_from_extern(ret) };
@@ -11633,16 +13699,34 @@ pub trait SoftmaxLayerInt8TraitConst: crate::dnn::SoftmaxLayerTraitConst {
 
 	#[inline]
 	fn output_sc(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_SoftmaxLayerInt8_getPropOutput_sc_const(self.as_raw_SoftmaxLayerInt8()) };
+		crate::core::validity::debug_check("SoftmaxLayerInt8", self.as_raw_SoftmaxLayerInt8());
+		let ret = unsafe { ffi_call!(cv_dnn_SoftmaxLayerInt8_getPropOutput_sc_const(self.as_raw_SoftmaxLayerInt8())) };
 		ret
 	}
 	
+	/// Non-panicking version of [output_sc](SoftmaxLayerInt8TraitConst::output_sc), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_output_sc(&self) -> Result<f32> {
+		crate::core::validity::check("SoftmaxLayerInt8", self.as_raw_SoftmaxLayerInt8())?;
+		Ok(SoftmaxLayerInt8TraitConst::output_sc(self))
+	}
+	
 	#[inline]
 	fn output_zp(&self) -> i32 {
-		let ret = unsafe { sys::cv_dnn_SoftmaxLayerInt8_getPropOutput_zp_const(self.as_raw_SoftmaxLayerInt8()) };
+		crate::core::validity::debug_check("SoftmaxLayerInt8", self.as_raw_SoftmaxLayerInt8());
+		let ret = unsafe { ffi_call!(cv_dnn_SoftmaxLayerInt8_getPropOutput_zp_const(self.as_raw_SoftmaxLayerInt8())) };
 		ret
 	}
 	
+	/// Non-panicking version of [output_zp](SoftmaxLayerInt8TraitConst::output_zp), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_output_zp(&self) -> Result<i32> {
+		crate::core::validity::check("SoftmaxLayerInt8", self.as_raw_SoftmaxLayerInt8())?;
+		Ok(SoftmaxLayerInt8TraitConst::output_zp(self))
+	}
+	
 }
 
 pub trait SoftmaxLayerInt8Trait: crate::dnn::SoftmaxLayerInt8TraitConst + crate::dnn::SoftmaxLayerTrait {
@@ -11650,13 +13734,15 @@ pub trait SoftmaxLayerInt8Trait: crate::dnn::SoftmaxLayerInt8TraitConst + crate:
 
 	#[inline]
 	fn set_output_sc(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_SoftmaxLayerInt8_setPropOutput_sc_float(self.as_raw_mut_SoftmaxLayerInt8(), val) };
+		crate::core::validity::debug_check("SoftmaxLayerInt8", self.as_raw_mut_SoftmaxLayerInt8());
+		let ret = unsafe { ffi_call!(cv_dnn_SoftmaxLayerInt8_setPropOutput_sc_float(self.as_raw_mut_SoftmaxLayerInt8(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_output_zp(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_dnn_SoftmaxLayerInt8_setPropOutput_zp_int(self.as_raw_mut_SoftmaxLayerInt8(), val) };
+		crate::core::validity::debug_check("SoftmaxLayerInt8", self.as_raw_mut_SoftmaxLayerInt8());
+		let ret = unsafe { ffi_call!(cv_dnn_SoftmaxLayerInt8_setPropOutput_zp_int(self.as_raw_mut_SoftmaxLayerInt8(), val)) };
 		ret
 	}
 	
@@ -11671,7 +13757,10 @@ opencv_type_boxed! { SoftmaxLayerInt8 }
 impl Drop for SoftmaxLayerInt8 {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SoftmaxLayerInt8_delete(instance: *mut c_void); }
-		unsafe { cv_SoftmaxLayerInt8_delete(self.as_raw_mut_SoftmaxLayerInt8()) };
+		let ptr = self.as_raw_mut_SoftmaxLayerInt8();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_SoftmaxLayerInt8_delete(ptr) };
 	}
 }
 
@@ -11713,7 +13802,7 @@ impl SoftmaxLayerInt8 {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::SoftmaxLayerInt8>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_SoftmaxLayerInt8_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_SoftmaxLayerInt8_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::SoftmaxLayerInt8>::opencv_from_extern(ret) };
@@ -11747,7 +13836,10 @@ opencv_type_boxed! { SoftplusLayer }
 impl Drop for SoftplusLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SoftplusLayer_delete(instance: *mut c_void); }
-		unsafe { cv_SoftplusLayer_delete(self.as_raw_mut_SoftplusLayer()) };
+		let ptr = self.as_raw_mut_SoftplusLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_SoftplusLayer_delete(ptr) };
 	}
 }
 
@@ -11789,7 +13881,7 @@ impl SoftplusLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::SoftplusLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_SoftplusLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_SoftplusLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::SoftplusLayer>::opencv_from_extern(ret) };
@@ -11823,7 +13915,10 @@ opencv_type_boxed! { SoftsignLayer }
 impl Drop for SoftsignLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SoftsignLayer_delete(instance: *mut c_void); }
-		unsafe { cv_SoftsignLayer_delete(self.as_raw_mut_SoftsignLayer()) };
+		let ptr = self.as_raw_mut_SoftsignLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_SoftsignLayer_delete(ptr) };
 	}
 }
 
@@ -11865,7 +13960,7 @@ impl SoftsignLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::SoftsignLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_SoftsignLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_SoftsignLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::SoftsignLayer>::opencv_from_extern(ret) };
@@ -11886,10 +13981,19 @@ pub trait SplitLayerTraitConst: crate::dnn::LayerTraitConst {
 	/// Number of copies that will be produced (is ignored when negative).
 	#[inline]
 	fn outputs_count(&self) -> i32 {
-		let ret = unsafe { sys::cv_dnn_SplitLayer_getPropOutputsCount_const(self.as_raw_SplitLayer()) };
+		crate::core::validity::debug_check("SplitLayer", self.as_raw_SplitLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_SplitLayer_getPropOutputsCount_const(self.as_raw_SplitLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [outputs_count](SplitLayerTraitConst::outputs_count), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_outputs_count(&self) -> Result<i32> {
+		crate::core::validity::check("SplitLayer", self.as_raw_SplitLayer())?;
+		Ok(SplitLayerTraitConst::outputs_count(self))
+	}
+	
 }
 
 pub trait SplitLayerTrait: crate::dnn::LayerTrait + crate::dnn::SplitLayerTraitConst {
@@ -11898,7 +14002,8 @@ pub trait SplitLayerTrait: crate::dnn::LayerTrait + crate::dnn::SplitLayerTraitC
 	/// Number of copies that will be produced (is ignored when negative).
 	#[inline]
 	fn set_outputs_count(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_dnn_SplitLayer_setPropOutputsCount_int(self.as_raw_mut_SplitLayer(), val) };
+		crate::core::validity::debug_check("SplitLayer", self.as_raw_mut_SplitLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_SplitLayer_setPropOutputsCount_int(self.as_raw_mut_SplitLayer(), val)) };
 		ret
 	}
 	
@@ -11913,7 +14018,10 @@ opencv_type_boxed! { SplitLayer }
 impl Drop for SplitLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SplitLayer_delete(instance: *mut c_void); }
-		unsafe { cv_SplitLayer_delete(self.as_raw_mut_SplitLayer()) };
+		let ptr = self.as_raw_mut_SplitLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_SplitLayer_delete(ptr) };
 	}
 }
 
@@ -11947,7 +14055,7 @@ impl SplitLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::SplitLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_SplitLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_SplitLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::SplitLayer>::opencv_from_extern(ret) };
@@ -11979,7 +14087,10 @@ opencv_type_boxed! { SqrtLayer }
 impl Drop for SqrtLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SqrtLayer_delete(instance: *mut c_void); }
-		unsafe { cv_SqrtLayer_delete(self.as_raw_mut_SqrtLayer()) };
+		let ptr = self.as_raw_mut_SqrtLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_SqrtLayer_delete(ptr) };
 	}
 }
 
@@ -12021,7 +14132,7 @@ impl SqrtLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::SqrtLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_SqrtLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_SqrtLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::SqrtLayer>::opencv_from_extern(ret) };
@@ -12055,7 +14166,10 @@ opencv_type_boxed! { SwishLayer }
 impl Drop for SwishLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SwishLayer_delete(instance: *mut c_void); }
-		unsafe { cv_SwishLayer_delete(self.as_raw_mut_SwishLayer()) };
+		let ptr = self.as_raw_mut_SwishLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_SwishLayer_delete(ptr) };
 	}
 }
 
@@ -12097,7 +14211,7 @@ impl SwishLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::SwishLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_SwishLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_SwishLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::SwishLayer>::opencv_from_extern(ret) };
@@ -12131,7 +14245,10 @@ opencv_type_boxed! { TanHLayer }
 impl Drop for TanHLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_TanHLayer_delete(instance: *mut c_void); }
-		unsafe { cv_TanHLayer_delete(self.as_raw_mut_TanHLayer()) };
+		let ptr = self.as_raw_mut_TanHLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_TanHLayer_delete(ptr) };
 	}
 }
 
@@ -12173,7 +14290,7 @@ impl TanHLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::TanHLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TanHLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TanHLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::TanHLayer>::opencv_from_extern(ret) };
@@ -12207,7 +14324,10 @@ opencv_type_boxed! { TanLayer }
 impl Drop for TanLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_TanLayer_delete(instance: *mut c_void); }
-		unsafe { cv_TanLayer_delete(self.as_raw_mut_TanLayer()) };
+		let ptr = self.as_raw_mut_TanLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_TanLayer_delete(ptr) };
 	}
 }
 
@@ -12249,7 +14369,7 @@ impl TanLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::TanLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TanLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TanLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::TanLayer>::opencv_from_extern(ret) };
@@ -12285,13 +14405,14 @@ pub trait TextDetectionModelTraitConst: crate::dnn::ModelTraitConst {
 	/// 
 	/// ## Parameters
 	/// * frame: The input image
-	/// * detections:[out] array with detections' quadrangles (4 points per result)
-	/// * confidences:[out] array with detection confidences
+	/// * detections:\[out\] array with detections' quadrangles (4 points per result)
+	/// * confidences:\[out\] array with detection confidences
 	#[inline]
 	fn detect_with_confidences(&self, frame: &dyn core::ToInputArray, detections: &mut core::Vector<core::Vector<core::Point>>, confidences: &mut core::Vector<f32>) -> Result<()> {
+		crate::core::validity::check("TextDetectionModel", self.as_raw_TextDetectionModel())?;
 		input_array_arg!(frame);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TextDetectionModel_detect_const_const__InputArrayR_vector_vector_Point__R_vector_float_R(self.as_raw_TextDetectionModel(), frame.as_raw__InputArray(), detections.as_raw_mut_VectorOfVectorOfPoint(), confidences.as_raw_mut_VectorOff32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TextDetectionModel_detect_const_const__InputArrayR_vector_vector_Point__R_vector_float_R(self.as_raw_TextDetectionModel(), frame.as_raw__InputArray(), detections.as_raw_mut_VectorOfVectorOfPoint(), confidences.as_raw_mut_VectorOff32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -12314,15 +14435,16 @@ pub trait TextDetectionModelTraitConst: crate::dnn::ModelTraitConst {
 	/// 
 	/// ## Parameters
 	/// * frame: The input image
-	/// * detections:[out] array with detections' quadrangles (4 points per result)
-	/// * confidences:[out] array with detection confidences
+	/// * detections:\[out\] array with detections' quadrangles (4 points per result)
+	/// * confidences:\[out\] array with detection confidences
 	/// 
 	/// ## Overloaded parameters
 	#[inline]
 	fn detect(&self, frame: &dyn core::ToInputArray, detections: &mut core::Vector<core::Vector<core::Point>>) -> Result<()> {
+		crate::core::validity::check("TextDetectionModel", self.as_raw_TextDetectionModel())?;
 		input_array_arg!(frame);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TextDetectionModel_detect_const_const__InputArrayR_vector_vector_Point__R(self.as_raw_TextDetectionModel(), frame.as_raw__InputArray(), detections.as_raw_mut_VectorOfVectorOfPoint(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TextDetectionModel_detect_const_const__InputArrayR_vector_vector_Point__R(self.as_raw_TextDetectionModel(), frame.as_raw__InputArray(), detections.as_raw_mut_VectorOfVectorOfPoint(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -12339,13 +14461,14 @@ pub trait TextDetectionModelTraitConst: crate::dnn::ModelTraitConst {
 	/// 
 	/// ## Parameters
 	/// * frame: the input image
-	/// * detections:[out] array with detections' RotationRect results
-	/// * confidences:[out] array with detection confidences
+	/// * detections:\[out\] array with detections' RotationRect results
+	/// * confidences:\[out\] array with detection confidences
 	#[inline]
 	fn detect_text_rectangles(&self, frame: &dyn core::ToInputArray, detections: &mut core::Vector<core::RotatedRect>, confidences: &mut core::Vector<f32>) -> Result<()> {
+		crate::core::validity::check("TextDetectionModel", self.as_raw_TextDetectionModel())?;
 		input_array_arg!(frame);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TextDetectionModel_detectTextRectangles_const_const__InputArrayR_vector_RotatedRect_R_vector_float_R(self.as_raw_TextDetectionModel(), frame.as_raw__InputArray(), detections.as_raw_mut_VectorOfRotatedRect(), confidences.as_raw_mut_VectorOff32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TextDetectionModel_detectTextRectangles_const_const__InputArrayR_vector_RotatedRect_R_vector_float_R(self.as_raw_TextDetectionModel(), frame.as_raw__InputArray(), detections.as_raw_mut_VectorOfRotatedRect(), confidences.as_raw_mut_VectorOff32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -12362,15 +14485,16 @@ pub trait TextDetectionModelTraitConst: crate::dnn::ModelTraitConst {
 	/// 
 	/// ## Parameters
 	/// * frame: the input image
-	/// * detections:[out] array with detections' RotationRect results
-	/// * confidences:[out] array with detection confidences
+	/// * detections:\[out\] array with detections' RotationRect results
+	/// * confidences:\[out\] array with detection confidences
 	/// 
 	/// ## Overloaded parameters
 	#[inline]
 	fn detect_text_rectangles_1(&self, frame: &dyn core::ToInputArray, detections: &mut core::Vector<core::RotatedRect>) -> Result<()> {
+		crate::core::validity::check("TextDetectionModel", self.as_raw_TextDetectionModel())?;
 		input_array_arg!(frame);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TextDetectionModel_detectTextRectangles_const_const__InputArrayR_vector_RotatedRect_R(self.as_raw_TextDetectionModel(), frame.as_raw__InputArray(), detections.as_raw_mut_VectorOfRotatedRect(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TextDetectionModel_detectTextRectangles_const_const__InputArrayR_vector_RotatedRect_R(self.as_raw_TextDetectionModel(), frame.as_raw__InputArray(), detections.as_raw_mut_VectorOfRotatedRect(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -12393,7 +14517,10 @@ opencv_type_boxed! { TextDetectionModel }
 impl Drop for TextDetectionModel {
 	fn drop(&mut self) {
 		extern "C" { fn cv_TextDetectionModel_delete(instance: *mut c_void); }
-		unsafe { cv_TextDetectionModel_delete(self.as_raw_mut_TextDetectionModel()) };
+		let ptr = self.as_raw_mut_TextDetectionModel();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_TextDetectionModel_delete(ptr) };
 	}
 }
 
@@ -12436,8 +14563,9 @@ pub trait TextDetectionModel_DBTraitConst: crate::dnn::TextDetectionModelTraitCo
 
 	#[inline]
 	fn get_binary_threshold(&self) -> Result<f32> {
+		crate::core::validity::check("TextDetectionModel_DB", self.as_raw_TextDetectionModel_DB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TextDetectionModel_DB_getBinaryThreshold_const(self.as_raw_TextDetectionModel_DB(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TextDetectionModel_DB_getBinaryThreshold_const(self.as_raw_TextDetectionModel_DB(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -12445,8 +14573,9 @@ pub trait TextDetectionModel_DBTraitConst: crate::dnn::TextDetectionModelTraitCo
 	
 	#[inline]
 	fn get_polygon_threshold(&self) -> Result<f32> {
+		crate::core::validity::check("TextDetectionModel_DB", self.as_raw_TextDetectionModel_DB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TextDetectionModel_DB_getPolygonThreshold_const(self.as_raw_TextDetectionModel_DB(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TextDetectionModel_DB_getPolygonThreshold_const(self.as_raw_TextDetectionModel_DB(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -12454,8 +14583,9 @@ pub trait TextDetectionModel_DBTraitConst: crate::dnn::TextDetectionModelTraitCo
 	
 	#[inline]
 	fn get_unclip_ratio(&self) -> Result<f64> {
+		crate::core::validity::check("TextDetectionModel_DB", self.as_raw_TextDetectionModel_DB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TextDetectionModel_DB_getUnclipRatio_const(self.as_raw_TextDetectionModel_DB(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TextDetectionModel_DB_getUnclipRatio_const(self.as_raw_TextDetectionModel_DB(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -12463,8 +14593,9 @@ pub trait TextDetectionModel_DBTraitConst: crate::dnn::TextDetectionModelTraitCo
 	
 	#[inline]
 	fn get_max_candidates(&self) -> Result<i32> {
+		crate::core::validity::check("TextDetectionModel_DB", self.as_raw_TextDetectionModel_DB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TextDetectionModel_DB_getMaxCandidates_const(self.as_raw_TextDetectionModel_DB(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TextDetectionModel_DB_getMaxCandidates_const(self.as_raw_TextDetectionModel_DB(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -12477,8 +14608,9 @@ pub trait TextDetectionModel_DBTrait: crate::dnn::TextDetectionModelTrait + crat
 
 	#[inline]
 	fn set_binary_threshold(&mut self, binary_threshold: f32) -> Result<crate::dnn::TextDetectionModel_DB> {
+		crate::core::validity::check("TextDetectionModel_DB", self.as_raw_mut_TextDetectionModel_DB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TextDetectionModel_DB_setBinaryThreshold_float(self.as_raw_mut_TextDetectionModel_DB(), binary_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TextDetectionModel_DB_setBinaryThreshold_float(self.as_raw_mut_TextDetectionModel_DB(), binary_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::TextDetectionModel_DB::opencv_from_extern(ret) };
@@ -12487,8 +14619,9 @@ pub trait TextDetectionModel_DBTrait: crate::dnn::TextDetectionModelTrait + crat
 	
 	#[inline]
 	fn set_polygon_threshold(&mut self, polygon_threshold: f32) -> Result<crate::dnn::TextDetectionModel_DB> {
+		crate::core::validity::check("TextDetectionModel_DB", self.as_raw_mut_TextDetectionModel_DB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TextDetectionModel_DB_setPolygonThreshold_float(self.as_raw_mut_TextDetectionModel_DB(), polygon_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TextDetectionModel_DB_setPolygonThreshold_float(self.as_raw_mut_TextDetectionModel_DB(), polygon_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::TextDetectionModel_DB::opencv_from_extern(ret) };
@@ -12497,8 +14630,9 @@ pub trait TextDetectionModel_DBTrait: crate::dnn::TextDetectionModelTrait + crat
 	
 	#[inline]
 	fn set_unclip_ratio(&mut self, unclip_ratio: f64) -> Result<crate::dnn::TextDetectionModel_DB> {
+		crate::core::validity::check("TextDetectionModel_DB", self.as_raw_mut_TextDetectionModel_DB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TextDetectionModel_DB_setUnclipRatio_double(self.as_raw_mut_TextDetectionModel_DB(), unclip_ratio, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TextDetectionModel_DB_setUnclipRatio_double(self.as_raw_mut_TextDetectionModel_DB(), unclip_ratio, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::TextDetectionModel_DB::opencv_from_extern(ret) };
@@ -12507,8 +14641,9 @@ pub trait TextDetectionModel_DBTrait: crate::dnn::TextDetectionModelTrait + crat
 	
 	#[inline]
 	fn set_max_candidates(&mut self, max_candidates: i32) -> Result<crate::dnn::TextDetectionModel_DB> {
+		crate::core::validity::check("TextDetectionModel_DB", self.as_raw_mut_TextDetectionModel_DB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TextDetectionModel_DB_setMaxCandidates_int(self.as_raw_mut_TextDetectionModel_DB(), max_candidates, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TextDetectionModel_DB_setMaxCandidates_int(self.as_raw_mut_TextDetectionModel_DB(), max_candidates, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::TextDetectionModel_DB::opencv_from_extern(ret) };
@@ -12537,7 +14672,10 @@ opencv_type_boxed! { TextDetectionModel_DB }
 impl Drop for TextDetectionModel_DB {
 	fn drop(&mut self) {
 		extern "C" { fn cv_TextDetectionModel_DB_delete(instance: *mut c_void); }
-		unsafe { cv_TextDetectionModel_DB_delete(self.as_raw_mut_TextDetectionModel_DB()) };
+		let ptr = self.as_raw_mut_TextDetectionModel_DB();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_TextDetectionModel_DB_delete(ptr) };
 	}
 }
 
@@ -12571,7 +14709,7 @@ impl TextDetectionModel_DB {
 	#[inline]
 	pub fn default() -> Result<crate::dnn::TextDetectionModel_DB> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TextDetectionModel_DB_TextDetectionModel_DB(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TextDetectionModel_DB_TextDetectionModel_DB(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::TextDetectionModel_DB::opencv_from_extern(ret) };
@@ -12584,7 +14722,7 @@ impl TextDetectionModel_DB {
 	#[inline]
 	pub fn new(network: &crate::dnn::Net) -> Result<crate::dnn::TextDetectionModel_DB> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TextDetectionModel_DB_TextDetectionModel_DB_const_NetR(network.as_raw_Net(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TextDetectionModel_DB_TextDetectionModel_DB_const_NetR(network.as_raw_Net(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::TextDetectionModel_DB::opencv_from_extern(ret) };
@@ -12604,7 +14742,7 @@ impl TextDetectionModel_DB {
 		extern_container_arg!(model);
 		extern_container_arg!(config);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TextDetectionModel_DB_TextDetectionModel_DB_const_stringR_const_stringR(model.opencv_as_extern(), config.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TextDetectionModel_DB_TextDetectionModel_DB_const_stringR_const_stringR(model.opencv_as_extern(), config.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::TextDetectionModel_DB::opencv_from_extern(ret) };
@@ -12628,8 +14766,9 @@ pub trait TextDetectionModel_EASTTraitConst: crate::dnn::TextDetectionModelTrait
 	/// Get the detection confidence threshold
 	#[inline]
 	fn get_confidence_threshold(&self) -> Result<f32> {
+		crate::core::validity::check("TextDetectionModel_EAST", self.as_raw_TextDetectionModel_EAST())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TextDetectionModel_EAST_getConfidenceThreshold_const(self.as_raw_TextDetectionModel_EAST(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TextDetectionModel_EAST_getConfidenceThreshold_const(self.as_raw_TextDetectionModel_EAST(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -12638,8 +14777,9 @@ pub trait TextDetectionModel_EASTTraitConst: crate::dnn::TextDetectionModelTrait
 	/// Get the detection confidence threshold
 	#[inline]
 	fn get_nms_threshold(&self) -> Result<f32> {
+		crate::core::validity::check("TextDetectionModel_EAST", self.as_raw_TextDetectionModel_EAST())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TextDetectionModel_EAST_getNMSThreshold_const(self.as_raw_TextDetectionModel_EAST(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TextDetectionModel_EAST_getNMSThreshold_const(self.as_raw_TextDetectionModel_EAST(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -12655,8 +14795,9 @@ pub trait TextDetectionModel_EASTTrait: crate::dnn::TextDetectionModelTrait + cr
 	/// * confThreshold: A threshold used to filter boxes by confidences
 	#[inline]
 	fn set_confidence_threshold(&mut self, conf_threshold: f32) -> Result<crate::dnn::TextDetectionModel_EAST> {
+		crate::core::validity::check("TextDetectionModel_EAST", self.as_raw_mut_TextDetectionModel_EAST())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TextDetectionModel_EAST_setConfidenceThreshold_float(self.as_raw_mut_TextDetectionModel_EAST(), conf_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TextDetectionModel_EAST_setConfidenceThreshold_float(self.as_raw_mut_TextDetectionModel_EAST(), conf_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::TextDetectionModel_EAST::opencv_from_extern(ret) };
@@ -12668,8 +14809,9 @@ pub trait TextDetectionModel_EASTTrait: crate::dnn::TextDetectionModelTrait + cr
 	/// * nmsThreshold: A threshold used in non maximum suppression
 	#[inline]
 	fn set_nms_threshold(&mut self, nms_threshold: f32) -> Result<crate::dnn::TextDetectionModel_EAST> {
+		crate::core::validity::check("TextDetectionModel_EAST", self.as_raw_mut_TextDetectionModel_EAST())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TextDetectionModel_EAST_setNMSThreshold_float(self.as_raw_mut_TextDetectionModel_EAST(), nms_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TextDetectionModel_EAST_setNMSThreshold_float(self.as_raw_mut_TextDetectionModel_EAST(), nms_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::TextDetectionModel_EAST::opencv_from_extern(ret) };
@@ -12692,7 +14834,10 @@ opencv_type_boxed! { TextDetectionModel_EAST }
 impl Drop for TextDetectionModel_EAST {
 	fn drop(&mut self) {
 		extern "C" { fn cv_TextDetectionModel_EAST_delete(instance: *mut c_void); }
-		unsafe { cv_TextDetectionModel_EAST_delete(self.as_raw_mut_TextDetectionModel_EAST()) };
+		let ptr = self.as_raw_mut_TextDetectionModel_EAST();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_TextDetectionModel_EAST_delete(ptr) };
 	}
 }
 
@@ -12726,7 +14871,7 @@ impl TextDetectionModel_EAST {
 	#[inline]
 	pub fn default() -> Result<crate::dnn::TextDetectionModel_EAST> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TextDetectionModel_EAST_TextDetectionModel_EAST(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TextDetectionModel_EAST_TextDetectionModel_EAST(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::TextDetectionModel_EAST::opencv_from_extern(ret) };
@@ -12739,7 +14884,7 @@ impl TextDetectionModel_EAST {
 	#[inline]
 	pub fn new(network: &crate::dnn::Net) -> Result<crate::dnn::TextDetectionModel_EAST> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TextDetectionModel_EAST_TextDetectionModel_EAST_const_NetR(network.as_raw_Net(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TextDetectionModel_EAST_TextDetectionModel_EAST_const_NetR(network.as_raw_Net(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::TextDetectionModel_EAST::opencv_from_extern(ret) };
@@ -12759,7 +14904,7 @@ impl TextDetectionModel_EAST {
 		extern_container_arg!(model);
 		extern_container_arg!(config);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TextDetectionModel_EAST_TextDetectionModel_EAST_const_stringR_const_stringR(model.opencv_as_extern(), config.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TextDetectionModel_EAST_TextDetectionModel_EAST_const_stringR_const_stringR(model.opencv_as_extern(), config.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::TextDetectionModel_EAST::opencv_from_extern(ret) };
@@ -12786,8 +14931,9 @@ pub trait TextRecognitionModelTraitConst: crate::dnn::ModelTraitConst {
 	/// the decoding method
 	#[inline]
 	fn get_decode_type(&self) -> Result<String> {
+		crate::core::validity::check("TextRecognitionModel", self.as_raw_TextRecognitionModel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TextRecognitionModel_getDecodeType_const(self.as_raw_TextRecognitionModel(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TextRecognitionModel_getDecodeType_const(self.as_raw_TextRecognitionModel(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -12799,8 +14945,9 @@ pub trait TextRecognitionModelTraitConst: crate::dnn::ModelTraitConst {
 	/// vocabulary the associated vocabulary
 	#[inline]
 	fn get_vocabulary(&self) -> Result<core::Vector<String>> {
+		crate::core::validity::check("TextRecognitionModel", self.as_raw_TextRecognitionModel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TextRecognitionModel_getVocabulary_const(self.as_raw_TextRecognitionModel(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TextRecognitionModel_getVocabulary_const(self.as_raw_TextRecognitionModel(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<String>::opencv_from_extern(ret) };
@@ -12814,9 +14961,10 @@ pub trait TextRecognitionModelTraitConst: crate::dnn::ModelTraitConst {
 	/// The text recognition result
 	#[inline]
 	fn recognize(&self, frame: &dyn core::ToInputArray) -> Result<String> {
+		crate::core::validity::check("TextRecognitionModel", self.as_raw_TextRecognitionModel())?;
 		input_array_arg!(frame);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TextRecognitionModel_recognize_const_const__InputArrayR(self.as_raw_TextRecognitionModel(), frame.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TextRecognitionModel_recognize_const_const__InputArrayR(self.as_raw_TextRecognitionModel(), frame.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -12827,13 +14975,14 @@ pub trait TextRecognitionModelTraitConst: crate::dnn::ModelTraitConst {
 	/// ## Parameters
 	/// * frame: The input image
 	/// * roiRects: List of text detection regions of interest (cv::Rect, CV_32SC4). ROIs is be cropped as the network inputs
-	/// * results:[out] A set of text recognition results.
+	/// * results:\[out\] A set of text recognition results.
 	#[inline]
 	fn recognize_1(&self, frame: &dyn core::ToInputArray, roi_rects: &dyn core::ToInputArray, results: &mut core::Vector<String>) -> Result<()> {
+		crate::core::validity::check("TextRecognitionModel", self.as_raw_TextRecognitionModel())?;
 		input_array_arg!(frame);
 		input_array_arg!(roi_rects);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TextRecognitionModel_recognize_const_const__InputArrayR_const__InputArrayR_vector_string_R(self.as_raw_TextRecognitionModel(), frame.as_raw__InputArray(), roi_rects.as_raw__InputArray(), results.as_raw_mut_VectorOfString(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TextRecognitionModel_recognize_const_const__InputArrayR_const__InputArrayR_vector_string_R(self.as_raw_TextRecognitionModel(), frame.as_raw__InputArray(), roi_rects.as_raw__InputArray(), results.as_raw_mut_VectorOfString(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -12851,9 +15000,10 @@ pub trait TextRecognitionModelTrait: crate::dnn::ModelTrait + crate::dnn::TextRe
 	///    - `"CTC-prefix-beam-search"` Prefix beam search decoding for the output of CTC-based methods
 	#[inline]
 	fn set_decode_type(&mut self, decode_type: &str) -> Result<crate::dnn::TextRecognitionModel> {
+		crate::core::validity::check("TextRecognitionModel", self.as_raw_mut_TextRecognitionModel())?;
 		extern_container_arg!(decode_type);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TextRecognitionModel_setDecodeType_const_stringR(self.as_raw_mut_TextRecognitionModel(), decode_type.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TextRecognitionModel_setDecodeType_const_stringR(self.as_raw_mut_TextRecognitionModel(), decode_type.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::TextRecognitionModel::opencv_from_extern(ret) };
@@ -12870,8 +15020,9 @@ pub trait TextRecognitionModelTrait: crate::dnn::ModelTrait + crate::dnn::TextRe
 	/// * voc_prune_size: 0
 	#[inline]
 	fn set_decode_opts_ctc_prefix_beam_search(&mut self, beam_size: i32, voc_prune_size: i32) -> Result<crate::dnn::TextRecognitionModel> {
+		crate::core::validity::check("TextRecognitionModel", self.as_raw_mut_TextRecognitionModel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TextRecognitionModel_setDecodeOptsCTCPrefixBeamSearch_int_int(self.as_raw_mut_TextRecognitionModel(), beam_size, voc_prune_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TextRecognitionModel_setDecodeOptsCTCPrefixBeamSearch_int_int(self.as_raw_mut_TextRecognitionModel(), beam_size, voc_prune_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::TextRecognitionModel::opencv_from_extern(ret) };
@@ -12883,8 +15034,9 @@ pub trait TextRecognitionModelTrait: crate::dnn::ModelTrait + crate::dnn::TextRe
 	/// * vocabulary: the associated vocabulary of the network.
 	#[inline]
 	fn set_vocabulary(&mut self, vocabulary: &core::Vector<String>) -> Result<crate::dnn::TextRecognitionModel> {
+		crate::core::validity::check("TextRecognitionModel", self.as_raw_mut_TextRecognitionModel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TextRecognitionModel_setVocabulary_const_vector_string_R(self.as_raw_mut_TextRecognitionModel(), vocabulary.as_raw_VectorOfString(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TextRecognitionModel_setVocabulary_const_vector_string_R(self.as_raw_mut_TextRecognitionModel(), vocabulary.as_raw_VectorOfString(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::TextRecognitionModel::opencv_from_extern(ret) };
@@ -12908,7 +15060,10 @@ opencv_type_boxed! { TextRecognitionModel }
 impl Drop for TextRecognitionModel {
 	fn drop(&mut self) {
 		extern "C" { fn cv_TextRecognitionModel_delete(instance: *mut c_void); }
-		unsafe { cv_TextRecognitionModel_delete(self.as_raw_mut_TextRecognitionModel()) };
+		let ptr = self.as_raw_mut_TextRecognitionModel();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_TextRecognitionModel_delete(ptr) };
 	}
 }
 
@@ -12934,7 +15089,7 @@ impl TextRecognitionModel {
 	#[inline]
 	pub fn default() -> Result<crate::dnn::TextRecognitionModel> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TextRecognitionModel_TextRecognitionModel(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TextRecognitionModel_TextRecognitionModel(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::TextRecognitionModel::opencv_from_extern(ret) };
@@ -12948,7 +15103,7 @@ impl TextRecognitionModel {
 	#[inline]
 	pub fn new(network: &crate::dnn::Net) -> Result<crate::dnn::TextRecognitionModel> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TextRecognitionModel_TextRecognitionModel_const_NetR(network.as_raw_Net(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TextRecognitionModel_TextRecognitionModel_const_NetR(network.as_raw_Net(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::TextRecognitionModel::opencv_from_extern(ret) };
@@ -12968,7 +15123,7 @@ impl TextRecognitionModel {
 		extern_container_arg!(model);
 		extern_container_arg!(config);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_TextRecognitionModel_TextRecognitionModel_const_stringR_const_stringR(model.opencv_as_extern(), config.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_TextRecognitionModel_TextRecognitionModel_const_stringR_const_stringR(model.opencv_as_extern(), config.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::TextRecognitionModel::opencv_from_extern(ret) };
@@ -12984,10 +15139,19 @@ pub trait ThresholdedReluLayerTraitConst: crate::dnn::ActivationLayerTraitConst
 
 	#[inline]
 	fn alpha(&self) -> f32 {
-		let ret = unsafe { sys::cv_dnn_ThresholdedReluLayer_getPropAlpha_const(self.as_raw_ThresholdedReluLayer()) };
+		crate::core::validity::debug_check("ThresholdedReluLayer", self.as_raw_ThresholdedReluLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_ThresholdedReluLayer_getPropAlpha_const(self.as_raw_ThresholdedReluLayer())) };
 		ret
 	}
 	
+	/// Non-panicking version of [alpha](ThresholdedReluLayerTraitConst::alpha), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_alpha(&self) -> Result<f32> {
+		crate::core::validity::check("ThresholdedReluLayer", self.as_raw_ThresholdedReluLayer())?;
+		Ok(ThresholdedReluLayerTraitConst::alpha(self))
+	}
+	
 }
 
 pub trait ThresholdedReluLayerTrait: crate::dnn::ActivationLayerTrait + crate::dnn::ThresholdedReluLayerTraitConst {
@@ -12995,7 +15159,8 @@ pub trait ThresholdedReluLayerTrait: crate::dnn::ActivationLayerTrait + crate::d
 
 	#[inline]
 	fn set_alpha(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dnn_ThresholdedReluLayer_setPropAlpha_float(self.as_raw_mut_ThresholdedReluLayer(), val) };
+		crate::core::validity::debug_check("ThresholdedReluLayer", self.as_raw_mut_ThresholdedReluLayer());
+		let ret = unsafe { ffi_call!(cv_dnn_ThresholdedReluLayer_setPropAlpha_float(self.as_raw_mut_ThresholdedReluLayer(), val)) };
 		ret
 	}
 	
@@ -13010,7 +15175,10 @@ opencv_type_boxed! { ThresholdedReluLayer }
 impl Drop for ThresholdedReluLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ThresholdedReluLayer_delete(instance: *mut c_void); }
-		unsafe { cv_ThresholdedReluLayer_delete(self.as_raw_mut_ThresholdedReluLayer()) };
+		let ptr = self.as_raw_mut_ThresholdedReluLayer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ThresholdedReluLayer_delete(ptr) };
 	}
 }
 
@@ -13052,7 +15220,7 @@ impl ThresholdedReluLayer {
 	#[inline]
 	pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ThresholdedReluLayer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_ThresholdedReluLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_ThresholdedReluLayer_create_const_LayerParamsR(params.as_raw_LayerParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn::ThresholdedReluLayer>::opencv_from_extern(ret) };
@@ -13086,7 +15254,10 @@ opencv_type_boxed! { _Range }
 impl Drop for _Range {
 	fn drop(&mut self) {
 		extern "C" { fn cv__Range_delete(instance: *mut c_void); }
-		unsafe { cv__Range_delete(self.as_raw_mut__Range()) };
+		let ptr = self.as_raw_mut__Range();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv__Range_delete(ptr) };
 	}
 }
 
@@ -13112,7 +15283,7 @@ impl _Range {
 	#[inline]
 	pub fn new(r: &core::Range) -> Result<crate::dnn::_Range> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn__Range__Range_const_RangeR(r.as_raw_Range(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn__Range__Range_const_RangeR(r.as_raw_Range(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::_Range::opencv_from_extern(ret) };
@@ -13124,7 +15295,7 @@ impl _Range {
 	#[inline]
 	pub fn new_1(start_: i32, size_: i32) -> Result<crate::dnn::_Range> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn__Range__Range_int_int(start_, size_, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn__Range__Range_int_int(start_, size_, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn::_Range::opencv_from_extern(ret) };
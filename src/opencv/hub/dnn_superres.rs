@@ -41,9 +41,10 @@ pub trait DnnSuperResImplTrait: crate::dnn_superres::DnnSuperResImplTraitConst {
 	/// * path: Path to the model file.
 	#[inline]
 	fn read_model(&mut self, path: &str) -> Result<()> {
+		crate::core::validity::check("DnnSuperResImpl", self.as_raw_mut_DnnSuperResImpl())?;
 		extern_container_arg!(path);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_superres_DnnSuperResImpl_readModel_const_StringR(self.as_raw_mut_DnnSuperResImpl(), path.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_superres_DnnSuperResImpl_readModel_const_StringR(self.as_raw_mut_DnnSuperResImpl(), path.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -55,10 +56,11 @@ pub trait DnnSuperResImplTrait: crate::dnn_superres::DnnSuperResImplTraitConst {
 	/// * definition: Path to the model definition file.
 	#[inline]
 	fn read_model_1(&mut self, weights: &str, definition: &str) -> Result<()> {
+		crate::core::validity::check("DnnSuperResImpl", self.as_raw_mut_DnnSuperResImpl())?;
 		extern_container_arg!(weights);
 		extern_container_arg!(definition);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_superres_DnnSuperResImpl_readModel_const_StringR_const_StringR(self.as_raw_mut_DnnSuperResImpl(), weights.opencv_as_extern(), definition.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_superres_DnnSuperResImpl_readModel_const_StringR_const_StringR(self.as_raw_mut_DnnSuperResImpl(), weights.opencv_as_extern(), definition.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -74,9 +76,10 @@ pub trait DnnSuperResImplTrait: crate::dnn_superres::DnnSuperResImplTraitConst {
 	/// * scale: Integer specifying the upscale factor
 	#[inline]
 	fn set_model(&mut self, algo: &str, scale: i32) -> Result<()> {
+		crate::core::validity::check("DnnSuperResImpl", self.as_raw_mut_DnnSuperResImpl())?;
 		extern_container_arg!(algo);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_superres_DnnSuperResImpl_setModel_const_StringR_int(self.as_raw_mut_DnnSuperResImpl(), algo.opencv_as_extern(), scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_superres_DnnSuperResImpl_setModel_const_StringR_int(self.as_raw_mut_DnnSuperResImpl(), algo.opencv_as_extern(), scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -85,8 +88,9 @@ pub trait DnnSuperResImplTrait: crate::dnn_superres::DnnSuperResImplTraitConst {
 	/// Set computation backend
 	#[inline]
 	fn set_preferable_backend(&mut self, backend_id: i32) -> Result<()> {
+		crate::core::validity::check("DnnSuperResImpl", self.as_raw_mut_DnnSuperResImpl())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_superres_DnnSuperResImpl_setPreferableBackend_int(self.as_raw_mut_DnnSuperResImpl(), backend_id, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_superres_DnnSuperResImpl_setPreferableBackend_int(self.as_raw_mut_DnnSuperResImpl(), backend_id, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -95,8 +99,9 @@ pub trait DnnSuperResImplTrait: crate::dnn_superres::DnnSuperResImplTraitConst {
 	/// Set computation target
 	#[inline]
 	fn set_preferable_target(&mut self, target_id: i32) -> Result<()> {
+		crate::core::validity::check("DnnSuperResImpl", self.as_raw_mut_DnnSuperResImpl())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_superres_DnnSuperResImpl_setPreferableTarget_int(self.as_raw_mut_DnnSuperResImpl(), target_id, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_superres_DnnSuperResImpl_setPreferableTarget_int(self.as_raw_mut_DnnSuperResImpl(), target_id, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -108,10 +113,11 @@ pub trait DnnSuperResImplTrait: crate::dnn_superres::DnnSuperResImplTraitConst {
 	/// * result: Destination upscaled image
 	#[inline]
 	fn upsample(&mut self, img: &dyn core::ToInputArray, result: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("DnnSuperResImpl", self.as_raw_mut_DnnSuperResImpl())?;
 		input_array_arg!(img);
 		output_array_arg!(result);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_superres_DnnSuperResImpl_upsample_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_DnnSuperResImpl(), img.as_raw__InputArray(), result.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_superres_DnnSuperResImpl_upsample_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_DnnSuperResImpl(), img.as_raw__InputArray(), result.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -125,9 +131,10 @@ pub trait DnnSuperResImplTrait: crate::dnn_superres::DnnSuperResImplTraitConst {
 	/// * node_names: Names of the output nodes in the neural network
 	#[inline]
 	fn upsample_multioutput(&mut self, img: &dyn core::ToInputArray, imgs_new: &mut core::Vector<core::Mat>, scale_factors: &core::Vector<i32>, node_names: &core::Vector<String>) -> Result<()> {
+		crate::core::validity::check("DnnSuperResImpl", self.as_raw_mut_DnnSuperResImpl())?;
 		input_array_arg!(img);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_superres_DnnSuperResImpl_upsampleMultioutput_const__InputArrayR_vector_Mat_R_const_vector_int_R_const_vector_String_R(self.as_raw_mut_DnnSuperResImpl(), img.as_raw__InputArray(), imgs_new.as_raw_mut_VectorOfMat(), scale_factors.as_raw_VectorOfi32(), node_names.as_raw_VectorOfString(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_superres_DnnSuperResImpl_upsampleMultioutput_const__InputArrayR_vector_Mat_R_const_vector_int_R_const_vector_String_R(self.as_raw_mut_DnnSuperResImpl(), img.as_raw__InputArray(), imgs_new.as_raw_mut_VectorOfMat(), scale_factors.as_raw_VectorOfi32(), node_names.as_raw_VectorOfString(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -138,8 +145,9 @@ pub trait DnnSuperResImplTrait: crate::dnn_superres::DnnSuperResImplTraitConst {
 	/// Current scale factor.
 	#[inline]
 	fn get_scale(&mut self) -> Result<i32> {
+		crate::core::validity::check("DnnSuperResImpl", self.as_raw_mut_DnnSuperResImpl())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_superres_DnnSuperResImpl_getScale(self.as_raw_mut_DnnSuperResImpl(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_superres_DnnSuperResImpl_getScale(self.as_raw_mut_DnnSuperResImpl(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -150,8 +158,9 @@ pub trait DnnSuperResImplTrait: crate::dnn_superres::DnnSuperResImplTraitConst {
 	/// Current algorithm.
 	#[inline]
 	fn get_algorithm(&mut self) -> Result<String> {
+		crate::core::validity::check("DnnSuperResImpl", self.as_raw_mut_DnnSuperResImpl())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_superres_DnnSuperResImpl_getAlgorithm(self.as_raw_mut_DnnSuperResImpl(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_superres_DnnSuperResImpl_getAlgorithm(self.as_raw_mut_DnnSuperResImpl(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -176,7 +185,10 @@ opencv_type_boxed! { DnnSuperResImpl }
 impl Drop for DnnSuperResImpl {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DnnSuperResImpl_delete(instance: *mut c_void); }
-		unsafe { cv_DnnSuperResImpl_delete(self.as_raw_mut_DnnSuperResImpl()) };
+		let ptr = self.as_raw_mut_DnnSuperResImpl();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_DnnSuperResImpl_delete(ptr) };
 	}
 }
 
@@ -195,7 +207,7 @@ impl DnnSuperResImpl {
 	#[inline]
 	pub fn create() -> Result<core::Ptr<crate::dnn_superres::DnnSuperResImpl>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_superres_DnnSuperResImpl_create(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_superres_DnnSuperResImpl_create(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::dnn_superres::DnnSuperResImpl>::opencv_from_extern(ret) };
@@ -205,7 +217,7 @@ impl DnnSuperResImpl {
 	#[inline]
 	pub fn default() -> Result<crate::dnn_superres::DnnSuperResImpl> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_superres_DnnSuperResImpl_DnnSuperResImpl(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_superres_DnnSuperResImpl_DnnSuperResImpl(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn_superres::DnnSuperResImpl::opencv_from_extern(ret) };
@@ -224,7 +236,7 @@ impl DnnSuperResImpl {
 	pub fn new(algo: &str, scale: i32) -> Result<crate::dnn_superres::DnnSuperResImpl> {
 		extern_container_arg!(algo);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_superres_DnnSuperResImpl_DnnSuperResImpl_const_StringR_int(algo.opencv_as_extern(), scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dnn_superres_DnnSuperResImpl_DnnSuperResImpl_const_StringR_int(algo.opencv_as_extern(), scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dnn_superres::DnnSuperResImpl::opencv_from_extern(ret) };
@@ -49,8 +49,9 @@ pub trait DPMDetectorConst {
 
 	#[inline]
 	fn is_empty(&self) -> Result<bool> {
+		crate::core::validity::check("DPMDetector", self.as_raw_DPMDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dpm_DPMDetector_isEmpty_const(self.as_raw_DPMDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dpm_DPMDetector_isEmpty_const(self.as_raw_DPMDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -60,8 +61,9 @@ pub trait DPMDetectorConst {
 	/// models filenames in those methods.
 	#[inline]
 	fn get_class_names(&self) -> Result<core::Vector<String>> {
+		crate::core::validity::check("DPMDetector", self.as_raw_DPMDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dpm_DPMDetector_getClassNames_const(self.as_raw_DPMDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dpm_DPMDetector_getClassNames_const(self.as_raw_DPMDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<String>::opencv_from_extern(ret) };
@@ -71,8 +73,9 @@ pub trait DPMDetectorConst {
 	/// Return a count of loaded models (classes).
 	#[inline]
 	fn get_class_count(&self) -> Result<size_t> {
+		crate::core::validity::check("DPMDetector", self.as_raw_DPMDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dpm_DPMDetector_getClassCount_const(self.as_raw_DPMDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dpm_DPMDetector_getClassCount_const(self.as_raw_DPMDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -90,8 +93,9 @@ pub trait DPMDetector: crate::dpm::DPMDetectorConst {
 	/// * objects: The detections: rectangulars, scores and class IDs.
 	#[inline]
 	fn detect(&mut self, image: &mut core::Mat, objects: &mut core::Vector<crate::dpm::DPMDetector_ObjectDetection>) -> Result<()> {
+		crate::core::validity::check("DPMDetector", self.as_raw_mut_DPMDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dpm_DPMDetector_detect_MatR_vector_ObjectDetection_R(self.as_raw_mut_DPMDetector(), image.as_raw_mut_Mat(), objects.as_raw_mut_VectorOfDPMDetector_ObjectDetection(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dpm_DPMDetector_detect_MatR_vector_ObjectDetection_R(self.as_raw_mut_DPMDetector(), image.as_raw_mut_Mat(), objects.as_raw_mut_VectorOfDPMDetector_ObjectDetection(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -113,7 +117,7 @@ impl dyn DPMDetector + '_ {
 	#[inline]
 	pub fn create(filenames: &core::Vector<String>, class_names: &core::Vector<String>) -> Result<core::Ptr<dyn crate::dpm::DPMDetector>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dpm_DPMDetector_create_const_vector_string_R_const_vector_string_R(filenames.as_raw_VectorOfString(), class_names.as_raw_VectorOfString(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dpm_DPMDetector_create_const_vector_string_R_const_vector_string_R(filenames.as_raw_VectorOfString(), class_names.as_raw_VectorOfString(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::dpm::DPMDetector>::opencv_from_extern(ret) };
@@ -126,24 +130,51 @@ pub trait DPMDetector_ObjectDetectionTraitConst {
 
 	#[inline]
 	fn rect(&self) -> core::Rect {
+		crate::core::validity::debug_check("DPMDetector_ObjectDetection", self.as_raw_DPMDetector_ObjectDetection());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dpm_DPMDetector_ObjectDetection_getPropRect_const(self.as_raw_DPMDetector_ObjectDetection(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dpm_DPMDetector_ObjectDetection_getPropRect_const(self.as_raw_DPMDetector_ObjectDetection(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [rect](DPMDetector_ObjectDetectionTraitConst::rect), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_rect(&self) -> Result<core::Rect> {
+		crate::core::validity::check("DPMDetector_ObjectDetection", self.as_raw_DPMDetector_ObjectDetection())?;
+		Ok(DPMDetector_ObjectDetectionTraitConst::rect(self))
+	}
+	
 	#[inline]
 	fn score(&self) -> f32 {
-		let ret = unsafe { sys::cv_dpm_DPMDetector_ObjectDetection_getPropScore_const(self.as_raw_DPMDetector_ObjectDetection()) };
+		crate::core::validity::debug_check("DPMDetector_ObjectDetection", self.as_raw_DPMDetector_ObjectDetection());
+		let ret = unsafe { ffi_call!(cv_dpm_DPMDetector_ObjectDetection_getPropScore_const(self.as_raw_DPMDetector_ObjectDetection())) };
 		ret
 	}
 	
+	/// Non-panicking version of [score](DPMDetector_ObjectDetectionTraitConst::score), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_score(&self) -> Result<f32> {
+		crate::core::validity::check("DPMDetector_ObjectDetection", self.as_raw_DPMDetector_ObjectDetection())?;
+		Ok(DPMDetector_ObjectDetectionTraitConst::score(self))
+	}
+	
 	#[inline]
 	fn class_id(&self) -> i32 {
-		let ret = unsafe { sys::cv_dpm_DPMDetector_ObjectDetection_getPropClassID_const(self.as_raw_DPMDetector_ObjectDetection()) };
+		crate::core::validity::debug_check("DPMDetector_ObjectDetection", self.as_raw_DPMDetector_ObjectDetection());
+		let ret = unsafe { ffi_call!(cv_dpm_DPMDetector_ObjectDetection_getPropClassID_const(self.as_raw_DPMDetector_ObjectDetection())) };
 		ret
 	}
 	
+	/// Non-panicking version of [class_id](DPMDetector_ObjectDetectionTraitConst::class_id), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_class_id(&self) -> Result<i32> {
+		crate::core::validity::check("DPMDetector_ObjectDetection", self.as_raw_DPMDetector_ObjectDetection())?;
+		Ok(DPMDetector_ObjectDetectionTraitConst::class_id(self))
+	}
+	
 }
 
 pub trait DPMDetector_ObjectDetectionTrait: crate::dpm::DPMDetector_ObjectDetectionTraitConst {
@@ -151,19 +182,22 @@ pub trait DPMDetector_ObjectDetectionTrait: crate::dpm::DPMDetector_ObjectDetect
 
 	#[inline]
 	fn set_rect(&mut self, val: core::Rect) {
-		let ret = unsafe { sys::cv_dpm_DPMDetector_ObjectDetection_setPropRect_Rect(self.as_raw_mut_DPMDetector_ObjectDetection(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("DPMDetector_ObjectDetection", self.as_raw_mut_DPMDetector_ObjectDetection());
+		let ret = unsafe { ffi_call!(cv_dpm_DPMDetector_ObjectDetection_setPropRect_Rect(self.as_raw_mut_DPMDetector_ObjectDetection(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_score(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_dpm_DPMDetector_ObjectDetection_setPropScore_float(self.as_raw_mut_DPMDetector_ObjectDetection(), val) };
+		crate::core::validity::debug_check("DPMDetector_ObjectDetection", self.as_raw_mut_DPMDetector_ObjectDetection());
+		let ret = unsafe { ffi_call!(cv_dpm_DPMDetector_ObjectDetection_setPropScore_float(self.as_raw_mut_DPMDetector_ObjectDetection(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_class_id(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_dpm_DPMDetector_ObjectDetection_setPropClassID_int(self.as_raw_mut_DPMDetector_ObjectDetection(), val) };
+		crate::core::validity::debug_check("DPMDetector_ObjectDetection", self.as_raw_mut_DPMDetector_ObjectDetection());
+		let ret = unsafe { ffi_call!(cv_dpm_DPMDetector_ObjectDetection_setPropClassID_int(self.as_raw_mut_DPMDetector_ObjectDetection(), val)) };
 		ret
 	}
 	
@@ -178,7 +212,10 @@ opencv_type_boxed! { DPMDetector_ObjectDetection }
 impl Drop for DPMDetector_ObjectDetection {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DPMDetector_ObjectDetection_delete(instance: *mut c_void); }
-		unsafe { cv_DPMDetector_ObjectDetection_delete(self.as_raw_mut_DPMDetector_ObjectDetection()) };
+		let ptr = self.as_raw_mut_DPMDetector_ObjectDetection();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_DPMDetector_ObjectDetection_delete(ptr) };
 	}
 }
 
@@ -196,7 +233,7 @@ impl DPMDetector_ObjectDetection {
 	#[inline]
 	pub fn default() -> Result<crate::dpm::DPMDetector_ObjectDetection> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dpm_DPMDetector_ObjectDetection_ObjectDetection(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dpm_DPMDetector_ObjectDetection_ObjectDetection(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dpm::DPMDetector_ObjectDetection::opencv_from_extern(ret) };
@@ -208,7 +245,7 @@ impl DPMDetector_ObjectDetection {
 	#[inline]
 	pub fn new(rect: core::Rect, score: f32, class_id: i32) -> Result<crate::dpm::DPMDetector_ObjectDetection> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dpm_DPMDetector_ObjectDetection_ObjectDetection_const_RectR_float_int(&rect, score, class_id, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dpm_DPMDetector_ObjectDetection_ObjectDetection_const_RectR_float_int(&rect, score, class_id, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::dpm::DPMDetector_ObjectDetection::opencv_from_extern(ret) };
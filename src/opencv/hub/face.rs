@@ -9,8 +9,8 @@
 )]
 //! # Face Analysis
 //! 
-//! - @ref face_changelog
-//! - @ref tutorial_face_main
+//! - face_changelog
+//! - tutorial_face_main
 use crate::{mod_prelude::*, core, sys, types};
 pub mod prelude {
 	pub use { super::PredictCollectorConst, super::PredictCollector, super::StandardCollectorTraitConst, super::StandardCollectorTrait, super::FaceRecognizerConst, super::FaceRecognizer, super::BasicFaceRecognizerConst, super::BasicFaceRecognizer, super::EigenFaceRecognizerConst, super::EigenFaceRecognizer, super::FisherFaceRecognizerConst, super::FisherFaceRecognizer, super::LBPHFaceRecognizerConst, super::LBPHFaceRecognizer, super::FacemarkConst, super::Facemark, super::CParamsTraitConst, super::CParamsTrait, super::FacemarkTrainConst, super::FacemarkTrain, super::FacemarkLBF_ParamsTraitConst, super::FacemarkLBF_ParamsTrait, super::FacemarkLBFConst, super::FacemarkLBF, super::FacemarkAAM_ParamsTraitConst, super::FacemarkAAM_ParamsTrait, super::FacemarkAAM_ConfigTraitConst, super::FacemarkAAM_ConfigTrait, super::FacemarkAAM_DataTraitConst, super::FacemarkAAM_DataTrait, super::FacemarkAAM_Model_TextureTraitConst, super::FacemarkAAM_Model_TextureTrait, super::FacemarkAAM_ModelTraitConst, super::FacemarkAAM_ModelTrait, super::FacemarkAAMConst, super::FacemarkAAM, super::FacemarkKazemi_ParamsTraitConst, super::FacemarkKazemi_ParamsTrait, super::FacemarkKazemiConst, super::FacemarkKazemi, super::MACEConst, super::MACE, super::BIFConst, super::BIF };
@@ -21,7 +21,7 @@ pub type FN_FaceDetector = Option<Box<dyn FnMut(*const c_void, *const c_void) ->
 #[inline]
 pub fn create_facemark_aam() -> Result<core::Ptr<dyn crate::face::Facemark>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_face_createFacemarkAAM(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_face_createFacemarkAAM(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::face::Facemark>::opencv_from_extern(ret) };
@@ -32,7 +32,7 @@ pub fn create_facemark_aam() -> Result<core::Ptr<dyn crate::face::Facemark>> {
 #[inline]
 pub fn create_facemark_kazemi() -> Result<core::Ptr<dyn crate::face::Facemark>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_face_createFacemarkKazemi(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_face_createFacemarkKazemi(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::face::Facemark>::opencv_from_extern(ret) };
@@ -43,7 +43,7 @@ pub fn create_facemark_kazemi() -> Result<core::Ptr<dyn crate::face::Facemark>>
 #[inline]
 pub fn create_facemark_lbf() -> Result<core::Ptr<dyn crate::face::Facemark>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_face_createFacemarkLBF(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_face_createFacemarkLBF(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::face::Facemark>::opencv_from_extern(ret) };
@@ -76,7 +76,7 @@ pub fn draw_facemarks(image: &mut dyn core::ToInputOutputArray, points: &dyn cor
 	input_output_array_arg!(image);
 	input_array_arg!(points);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_face_drawFacemarks_const__InputOutputArrayR_const__InputArrayR_Scalar(image.as_raw__InputOutputArray(), points.as_raw__InputArray(), color.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_face_drawFacemarks_const__InputOutputArrayR_const__InputArrayR_Scalar(image.as_raw__InputOutputArray(), points.as_raw__InputArray(), color.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -88,7 +88,7 @@ pub fn get_faces_haar(image: &dyn core::ToInputArray, faces: &mut dyn core::ToOu
 	output_array_arg!(faces);
 	extern_container_arg!(face_cascade_name);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_face_getFacesHAAR_const__InputArrayR_const__OutputArrayR_const_StringR(image.as_raw__InputArray(), faces.as_raw__OutputArray(), face_cascade_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_face_getFacesHAAR_const__InputArrayR_const__OutputArrayR_const_StringR(image.as_raw__InputArray(), faces.as_raw__OutputArray(), face_cascade_name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -121,7 +121,7 @@ pub fn get_faces(image: &dyn core::ToInputArray, faces: &mut dyn core::ToOutputA
 	input_array_arg!(image);
 	output_array_arg!(faces);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_face_getFaces_const__InputArrayR_const__OutputArrayR_CParamsX(image.as_raw__InputArray(), faces.as_raw__OutputArray(), params.as_raw_mut_CParams(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_face_getFaces_const__InputArrayR_const__OutputArrayR_CParamsX(image.as_raw__InputArray(), faces.as_raw__OutputArray(), params.as_raw_mut_CParams(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -148,7 +148,7 @@ pub fn load_dataset_list(image_list: &str, annotation_list: &str, images: &mut c
 	extern_container_arg!(mut image_list);
 	extern_container_arg!(mut annotation_list);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_face_loadDatasetList_String_String_vector_String_R_vector_String_R(image_list.opencv_as_extern_mut(), annotation_list.opencv_as_extern_mut(), images.as_raw_mut_VectorOfString(), annotations.as_raw_mut_VectorOfString(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_face_loadDatasetList_String_String_vector_String_R_vector_String_R(image_list.opencv_as_extern_mut(), annotation_list.opencv_as_extern_mut(), images.as_raw_mut_VectorOfString(), annotations.as_raw_mut_VectorOfString(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -189,7 +189,7 @@ pub fn load_face_points(filename: &str, points: &mut dyn core::ToOutputArray, of
 	extern_container_arg!(mut filename);
 	output_array_arg!(points);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_face_loadFacePoints_String_const__OutputArrayR_float(filename.opencv_as_extern_mut(), points.as_raw__OutputArray(), offset, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_face_loadFacePoints_String_const__OutputArrayR_float(filename.opencv_as_extern_mut(), points.as_raw__OutputArray(), offset, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -243,7 +243,7 @@ pub fn load_training_data_1(image_list: &str, ground_truth: &str, images: &mut c
 	extern_container_arg!(mut ground_truth);
 	output_array_arg!(face_points);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_face_loadTrainingData_String_String_vector_String_R_const__OutputArrayR_float(image_list.opencv_as_extern_mut(), ground_truth.opencv_as_extern_mut(), images.as_raw_mut_VectorOfString(), face_points.as_raw__OutputArray(), offset, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_face_loadTrainingData_String_String_vector_String_R_const__OutputArrayR_float(image_list.opencv_as_extern_mut(), ground_truth.opencv_as_extern_mut(), images.as_raw_mut_VectorOfString(), face_points.as_raw__OutputArray(), offset, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -285,7 +285,7 @@ pub fn load_training_data(filename: &str, images: &mut core::Vector<String>, fac
 	extern_container_arg!(mut filename);
 	output_array_arg!(face_points);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_face_loadTrainingData_String_vector_String_R_const__OutputArrayR_char_float(filename.opencv_as_extern_mut(), images.as_raw_mut_VectorOfString(), face_points.as_raw__OutputArray(), delim, offset, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_face_loadTrainingData_String_vector_String_R_const__OutputArrayR_char_float(filename.opencv_as_extern_mut(), images.as_raw_mut_VectorOfString(), face_points.as_raw__OutputArray(), delim, offset, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -311,7 +311,7 @@ pub fn load_training_data(filename: &str, images: &mut core::Vector<String>, fac
 #[inline]
 pub fn load_training_data_2(mut filename: core::Vector<String>, trainlandmarks: &mut core::Vector<core::Vector<core::Point2f>>, trainimages: &mut core::Vector<String>) -> Result<bool> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_face_loadTrainingData_vector_String__vector_vector_Point2f__R_vector_String_R(filename.as_raw_mut_VectorOfString(), trainlandmarks.as_raw_mut_VectorOfVectorOfPoint2f(), trainimages.as_raw_mut_VectorOfString(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_face_loadTrainingData_vector_String__vector_vector_Point2f__R_vector_String_R(filename.as_raw_mut_VectorOfString(), trainlandmarks.as_raw_mut_VectorOfVectorOfPoint2f(), trainimages.as_raw_mut_VectorOfString(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -327,8 +327,9 @@ pub trait BIFConst: core::AlgorithmTraitConst {
 	/// The number of filter bands used for computing BIF.
 	#[inline]
 	fn get_num_bands(&self) -> Result<i32> {
+		crate::core::validity::check("BIF", self.as_raw_BIF())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_BIF_getNumBands_const(self.as_raw_BIF(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_BIF_getNumBands_const(self.as_raw_BIF(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -338,8 +339,9 @@ pub trait BIFConst: core::AlgorithmTraitConst {
 	/// The number of image rotations.
 	#[inline]
 	fn get_num_rotations(&self) -> Result<i32> {
+		crate::core::validity::check("BIF", self.as_raw_BIF())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_BIF_getNumRotations_const(self.as_raw_BIF(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_BIF_getNumRotations_const(self.as_raw_BIF(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -351,10 +353,11 @@ pub trait BIFConst: core::AlgorithmTraitConst {
 	/// * features: Feature vector (CV_32FC1).
 	#[inline]
 	fn compute(&self, image: &dyn core::ToInputArray, features: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("BIF", self.as_raw_BIF())?;
 		input_array_arg!(image);
 		output_array_arg!(features);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_BIF_compute_const_const__InputArrayR_const__OutputArrayR(self.as_raw_BIF(), image.as_raw__InputArray(), features.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_BIF_compute_const_const__InputArrayR_const__OutputArrayR(self.as_raw_BIF(), image.as_raw__InputArray(), features.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -380,7 +383,7 @@ impl dyn BIF + '_ {
 	#[inline]
 	pub fn create(num_bands: i32, num_rotations: i32) -> Result<core::Ptr<dyn crate::face::BIF>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_BIF_create_int_int(num_bands, num_rotations, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_BIF_create_int_int(num_bands, num_rotations, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::face::BIF>::opencv_from_extern(ret) };
@@ -392,22 +395,24 @@ pub trait BasicFaceRecognizerConst: crate::face::FaceRecognizerConst {
 	fn as_raw_BasicFaceRecognizer(&self) -> *const c_void;
 
 	/// ## See also
-	/// setNumComponents
+	/// `set_num_components`
 	#[inline]
 	fn get_num_components(&self) -> Result<i32> {
+		crate::core::validity::check("BasicFaceRecognizer", self.as_raw_BasicFaceRecognizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_BasicFaceRecognizer_getNumComponents_const(self.as_raw_BasicFaceRecognizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_BasicFaceRecognizer_getNumComponents_const(self.as_raw_BasicFaceRecognizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setThreshold
+	/// `set_threshold`
 	#[inline]
 	fn get_threshold(&self) -> Result<f64> {
+		crate::core::validity::check("BasicFaceRecognizer", self.as_raw_BasicFaceRecognizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_BasicFaceRecognizer_getThreshold_const(self.as_raw_BasicFaceRecognizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_BasicFaceRecognizer_getThreshold_const(self.as_raw_BasicFaceRecognizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -415,8 +420,9 @@ pub trait BasicFaceRecognizerConst: crate::face::FaceRecognizerConst {
 	
 	#[inline]
 	fn get_projections(&self) -> Result<core::Vector<core::Mat>> {
+		crate::core::validity::check("BasicFaceRecognizer", self.as_raw_BasicFaceRecognizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_BasicFaceRecognizer_getProjections_const(self.as_raw_BasicFaceRecognizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_BasicFaceRecognizer_getProjections_const(self.as_raw_BasicFaceRecognizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<core::Mat>::opencv_from_extern(ret) };
@@ -425,8 +431,9 @@ pub trait BasicFaceRecognizerConst: crate::face::FaceRecognizerConst {
 	
 	#[inline]
 	fn get_labels(&self) -> Result<core::Mat> {
+		crate::core::validity::check("BasicFaceRecognizer", self.as_raw_BasicFaceRecognizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_BasicFaceRecognizer_getLabels_const(self.as_raw_BasicFaceRecognizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_BasicFaceRecognizer_getLabels_const(self.as_raw_BasicFaceRecognizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -435,8 +442,9 @@ pub trait BasicFaceRecognizerConst: crate::face::FaceRecognizerConst {
 	
 	#[inline]
 	fn get_eigen_values(&self) -> Result<core::Mat> {
+		crate::core::validity::check("BasicFaceRecognizer", self.as_raw_BasicFaceRecognizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_BasicFaceRecognizer_getEigenValues_const(self.as_raw_BasicFaceRecognizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_BasicFaceRecognizer_getEigenValues_const(self.as_raw_BasicFaceRecognizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -445,8 +453,9 @@ pub trait BasicFaceRecognizerConst: crate::face::FaceRecognizerConst {
 	
 	#[inline]
 	fn get_eigen_vectors(&self) -> Result<core::Mat> {
+		crate::core::validity::check("BasicFaceRecognizer", self.as_raw_BasicFaceRecognizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_BasicFaceRecognizer_getEigenVectors_const(self.as_raw_BasicFaceRecognizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_BasicFaceRecognizer_getEigenVectors_const(self.as_raw_BasicFaceRecognizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -455,8 +464,9 @@ pub trait BasicFaceRecognizerConst: crate::face::FaceRecognizerConst {
 	
 	#[inline]
 	fn get_mean(&self) -> Result<core::Mat> {
+		crate::core::validity::check("BasicFaceRecognizer", self.as_raw_BasicFaceRecognizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_BasicFaceRecognizer_getMean_const(self.as_raw_BasicFaceRecognizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_BasicFaceRecognizer_getMean_const(self.as_raw_BasicFaceRecognizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -465,8 +475,9 @@ pub trait BasicFaceRecognizerConst: crate::face::FaceRecognizerConst {
 	
 	#[inline]
 	fn write(&self, fs: &mut core::FileStorage) -> Result<()> {
+		crate::core::validity::check("BasicFaceRecognizer", self.as_raw_BasicFaceRecognizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_BasicFaceRecognizer_write_const_FileStorageR(self.as_raw_BasicFaceRecognizer(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_BasicFaceRecognizer_write_const_FileStorageR(self.as_raw_BasicFaceRecognizer(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -474,8 +485,9 @@ pub trait BasicFaceRecognizerConst: crate::face::FaceRecognizerConst {
 	
 	#[inline]
 	fn empty(&self) -> Result<bool> {
+		crate::core::validity::check("BasicFaceRecognizer", self.as_raw_BasicFaceRecognizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_BasicFaceRecognizer_empty_const(self.as_raw_BasicFaceRecognizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_BasicFaceRecognizer_empty_const(self.as_raw_BasicFaceRecognizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -490,8 +502,9 @@ pub trait BasicFaceRecognizer: crate::face::BasicFaceRecognizerConst + crate::fa
 	/// setNumComponents getNumComponents
 	#[inline]
 	fn set_num_components(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("BasicFaceRecognizer", self.as_raw_mut_BasicFaceRecognizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_BasicFaceRecognizer_setNumComponents_int(self.as_raw_mut_BasicFaceRecognizer(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_BasicFaceRecognizer_setNumComponents_int(self.as_raw_mut_BasicFaceRecognizer(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -501,8 +514,9 @@ pub trait BasicFaceRecognizer: crate::face::BasicFaceRecognizerConst + crate::fa
 	/// setThreshold getThreshold
 	#[inline]
 	fn set_threshold(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("BasicFaceRecognizer", self.as_raw_mut_BasicFaceRecognizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_BasicFaceRecognizer_setThreshold_double(self.as_raw_mut_BasicFaceRecognizer(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_BasicFaceRecognizer_setThreshold_double(self.as_raw_mut_BasicFaceRecognizer(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -510,8 +524,9 @@ pub trait BasicFaceRecognizer: crate::face::BasicFaceRecognizerConst + crate::fa
 	
 	#[inline]
 	fn read(&mut self, fn_: &core::FileNode) -> Result<()> {
+		crate::core::validity::check("BasicFaceRecognizer", self.as_raw_mut_BasicFaceRecognizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_BasicFaceRecognizer_read_const_FileNodeR(self.as_raw_mut_BasicFaceRecognizer(), fn_.as_raw_FileNode(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_BasicFaceRecognizer_read_const_FileNodeR(self.as_raw_mut_BasicFaceRecognizer(), fn_.as_raw_FileNode(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -525,50 +540,104 @@ pub trait CParamsTraitConst {
 	/// the face detector
 	#[inline]
 	fn cascade(&self) -> String {
-		let ret = unsafe { sys::cv_face_CParams_getPropCascade_const(self.as_raw_CParams()) };
+		crate::core::validity::debug_check("CParams", self.as_raw_CParams());
+		let ret = unsafe { ffi_call!(cv_face_CParams_getPropCascade_const(self.as_raw_CParams())) };
 		let ret = unsafe { String::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [cascade](CParamsTraitConst::cascade), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_cascade(&self) -> Result<String> {
+		crate::core::validity::check("CParams", self.as_raw_CParams())?;
+		Ok(CParamsTraitConst::cascade(self))
+	}
+	
 	/// Parameter specifying how much the image size is reduced at each image scale.
 	#[inline]
 	fn scale_factor(&self) -> f64 {
-		let ret = unsafe { sys::cv_face_CParams_getPropScaleFactor_const(self.as_raw_CParams()) };
+		crate::core::validity::debug_check("CParams", self.as_raw_CParams());
+		let ret = unsafe { ffi_call!(cv_face_CParams_getPropScaleFactor_const(self.as_raw_CParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [scale_factor](CParamsTraitConst::scale_factor), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_scale_factor(&self) -> Result<f64> {
+		crate::core::validity::check("CParams", self.as_raw_CParams())?;
+		Ok(CParamsTraitConst::scale_factor(self))
+	}
+	
 	/// Parameter specifying how many neighbors each candidate rectangle should have to retain it.
 	#[inline]
 	fn min_neighbors(&self) -> i32 {
-		let ret = unsafe { sys::cv_face_CParams_getPropMinNeighbors_const(self.as_raw_CParams()) };
+		crate::core::validity::debug_check("CParams", self.as_raw_CParams());
+		let ret = unsafe { ffi_call!(cv_face_CParams_getPropMinNeighbors_const(self.as_raw_CParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [min_neighbors](CParamsTraitConst::min_neighbors), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_min_neighbors(&self) -> Result<i32> {
+		crate::core::validity::check("CParams", self.as_raw_CParams())?;
+		Ok(CParamsTraitConst::min_neighbors(self))
+	}
+	
 	/// Minimum possible object size.
 	#[inline]
 	fn min_size(&self) -> core::Size {
+		crate::core::validity::debug_check("CParams", self.as_raw_CParams());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_CParams_getPropMinSize_const(self.as_raw_CParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_CParams_getPropMinSize_const(self.as_raw_CParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [min_size](CParamsTraitConst::min_size), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_min_size(&self) -> Result<core::Size> {
+		crate::core::validity::check("CParams", self.as_raw_CParams())?;
+		Ok(CParamsTraitConst::min_size(self))
+	}
+	
 	/// Maximum possible object size.
 	#[inline]
 	fn max_size(&self) -> core::Size {
+		crate::core::validity::debug_check("CParams", self.as_raw_CParams());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_CParams_getPropMaxSize_const(self.as_raw_CParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_CParams_getPropMaxSize_const(self.as_raw_CParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [max_size](CParamsTraitConst::max_size), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_max_size(&self) -> Result<core::Size> {
+		crate::core::validity::check("CParams", self.as_raw_CParams())?;
+		Ok(CParamsTraitConst::max_size(self))
+	}
+	
 	#[inline]
 	fn face_cascade(&self) -> crate::objdetect::CascadeClassifier {
-		let ret = unsafe { sys::cv_face_CParams_getPropFace_cascade_const(self.as_raw_CParams()) };
+		crate::core::validity::debug_check("CParams", self.as_raw_CParams());
+		let ret = unsafe { ffi_call!(cv_face_CParams_getPropFace_cascade_const(self.as_raw_CParams())) };
 		let ret = unsafe { crate::objdetect::CascadeClassifier::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [face_cascade](CParamsTraitConst::face_cascade), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_face_cascade(&self) -> Result<crate::objdetect::CascadeClassifier> {
+		crate::core::validity::check("CParams", self.as_raw_CParams())?;
+		Ok(CParamsTraitConst::face_cascade(self))
+	}
+	
 }
 
 pub trait CParamsTrait: crate::face::CParamsTraitConst {
@@ -577,42 +646,48 @@ pub trait CParamsTrait: crate::face::CParamsTraitConst {
 	/// the face detector
 	#[inline]
 	fn set_cascade(&mut self, val: &str) {
+		crate::core::validity::debug_check("CParams", self.as_raw_mut_CParams());
 		extern_container_arg!(nofail mut val);
-		let ret = unsafe { sys::cv_face_CParams_setPropCascade_String(self.as_raw_mut_CParams(), val.opencv_as_extern_mut()) };
+		let ret = unsafe { ffi_call!(cv_face_CParams_setPropCascade_String(self.as_raw_mut_CParams(), val.opencv_as_extern_mut())) };
 		ret
 	}
 	
 	/// Parameter specifying how much the image size is reduced at each image scale.
 	#[inline]
 	fn set_scale_factor(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_face_CParams_setPropScaleFactor_double(self.as_raw_mut_CParams(), val) };
+		crate::core::validity::debug_check("CParams", self.as_raw_mut_CParams());
+		let ret = unsafe { ffi_call!(cv_face_CParams_setPropScaleFactor_double(self.as_raw_mut_CParams(), val)) };
 		ret
 	}
 	
 	/// Parameter specifying how many neighbors each candidate rectangle should have to retain it.
 	#[inline]
 	fn set_min_neighbors(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_face_CParams_setPropMinNeighbors_int(self.as_raw_mut_CParams(), val) };
+		crate::core::validity::debug_check("CParams", self.as_raw_mut_CParams());
+		let ret = unsafe { ffi_call!(cv_face_CParams_setPropMinNeighbors_int(self.as_raw_mut_CParams(), val)) };
 		ret
 	}
 	
 	/// Minimum possible object size.
 	#[inline]
 	fn set_min_size(&mut self, val: core::Size) {
-		let ret = unsafe { sys::cv_face_CParams_setPropMinSize_Size(self.as_raw_mut_CParams(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("CParams", self.as_raw_mut_CParams());
+		let ret = unsafe { ffi_call!(cv_face_CParams_setPropMinSize_Size(self.as_raw_mut_CParams(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	/// Maximum possible object size.
 	#[inline]
 	fn set_max_size(&mut self, val: core::Size) {
-		let ret = unsafe { sys::cv_face_CParams_setPropMaxSize_Size(self.as_raw_mut_CParams(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("CParams", self.as_raw_mut_CParams());
+		let ret = unsafe { ffi_call!(cv_face_CParams_setPropMaxSize_Size(self.as_raw_mut_CParams(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_face_cascade(&mut self, mut val: crate::objdetect::CascadeClassifier) {
-		let ret = unsafe { sys::cv_face_CParams_setPropFace_cascade_CascadeClassifier(self.as_raw_mut_CParams(), val.as_raw_mut_CascadeClassifier()) };
+		crate::core::validity::debug_check("CParams", self.as_raw_mut_CParams());
+		let ret = unsafe { ffi_call!(cv_face_CParams_setPropFace_cascade_CascadeClassifier(self.as_raw_mut_CParams(), val.as_raw_mut_CascadeClassifier())) };
 		ret
 	}
 	
@@ -627,7 +702,10 @@ opencv_type_boxed! { CParams }
 impl Drop for CParams {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CParams_delete(instance: *mut c_void); }
-		unsafe { cv_CParams_delete(self.as_raw_mut_CParams()) };
+		let ptr = self.as_raw_mut_CParams();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_CParams_delete(ptr) };
 	}
 }
 
@@ -651,7 +729,7 @@ impl CParams {
 	pub fn new(cascade_model: &str, sf: f64, min_n: i32, min_sz: core::Size, max_sz: core::Size) -> Result<crate::face::CParams> {
 		extern_container_arg!(mut cascade_model);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_CParams_CParams_String_double_int_Size_Size(cascade_model.opencv_as_extern_mut(), sf, min_n, min_sz.opencv_as_extern(), max_sz.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_CParams_CParams_String_double_int_Size_Size(cascade_model.opencv_as_extern_mut(), sf, min_n, min_sz.opencv_as_extern(), max_sz.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::face::CParams::opencv_from_extern(ret) };
@@ -660,6 +738,58 @@ impl CParams {
 	
 }
 
+/// Builder for `CParams::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CParams_NewBuilder<'a> {
+	cascade_model: &'a str,
+	sf: f64,
+	min_n: i32,
+	min_sz: core::Size,
+	max_sz: core::Size,
+}
+
+impl<'a> CParams_NewBuilder<'a> {
+	#[inline]
+	pub fn new(cascade_model: &'a str) -> Self {
+		Self { cascade_model, sf: 1.1, min_n: 3, min_sz: core::Size::new(30, 30), max_sz: core::Size::default() }
+	}
+	
+	/// C++ default: `1.1`
+	#[inline]
+	pub fn with_sf(mut self, sf: f64) -> Self {
+		self.sf = sf;
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_min_n(mut self, min_n: i32) -> Self {
+		self.min_n = min_n;
+		self
+	}
+	
+	/// C++ default: `Size(30,30)`
+	#[inline]
+	pub fn with_min_sz(mut self, min_sz: core::Size) -> Self {
+		self.min_sz = min_sz;
+		self
+	}
+	
+	/// C++ default: `Size()`
+	#[inline]
+	pub fn with_max_sz(mut self, max_sz: core::Size) -> Self {
+		self.max_sz = max_sz;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::face::CParams> {
+		CParams::new(self.cascade_model, self.sf, self.min_n, self.min_sz, self.max_sz)
+	}
+}
+
 pub trait EigenFaceRecognizerConst: crate::face::BasicFaceRecognizerConst {
 	fn as_raw_EigenFaceRecognizer(&self) -> *const c_void;
 
@@ -706,7 +836,7 @@ impl dyn EigenFaceRecognizer + '_ {
 	#[inline]
 	pub fn create(num_components: i32, threshold: f64) -> Result<core::Ptr<dyn crate::face::EigenFaceRecognizer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_EigenFaceRecognizer_create_int_double(num_components, threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_EigenFaceRecognizer_create_int_double(num_components, threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::face::EigenFaceRecognizer>::opencv_from_extern(ret) };
@@ -829,9 +959,10 @@ pub trait FaceRecognizerConst: core::AlgorithmTraitConst {
 	/// ## Overloaded parameters
 	#[inline]
 	fn predict_label(&self, src: &dyn core::ToInputArray) -> Result<i32> {
+		crate::core::validity::check("FaceRecognizer", self.as_raw_FaceRecognizer())?;
 		input_array_arg!(src);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FaceRecognizer_predict_const_const__InputArrayR(self.as_raw_FaceRecognizer(), src.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FaceRecognizer_predict_const_const__InputArrayR(self.as_raw_FaceRecognizer(), src.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -876,9 +1007,10 @@ pub trait FaceRecognizerConst: core::AlgorithmTraitConst {
 	/// 
 	#[inline]
 	fn predict(&self, src: &dyn core::ToInputArray, label: &mut i32, confidence: &mut f64) -> Result<()> {
+		crate::core::validity::check("FaceRecognizer", self.as_raw_FaceRecognizer())?;
 		input_array_arg!(src);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FaceRecognizer_predict_const_const__InputArrayR_intR_doubleR(self.as_raw_FaceRecognizer(), src.as_raw__InputArray(), label, confidence, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FaceRecognizer_predict_const_const__InputArrayR_intR_doubleR(self.as_raw_FaceRecognizer(), src.as_raw__InputArray(), label, confidence, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -893,9 +1025,10 @@ pub trait FaceRecognizerConst: core::AlgorithmTraitConst {
 	/// not try to get "best@ result, just resend it to caller side with given collector
 	#[inline]
 	fn predict_collect(&self, src: &dyn core::ToInputArray, mut collector: core::Ptr<dyn crate::face::PredictCollector>) -> Result<()> {
+		crate::core::validity::check("FaceRecognizer", self.as_raw_FaceRecognizer())?;
 		input_array_arg!(src);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FaceRecognizer_predict_const_const__InputArrayR_Ptr_PredictCollector_(self.as_raw_FaceRecognizer(), src.as_raw__InputArray(), collector.as_raw_mut_PtrOfPredictCollector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FaceRecognizer_predict_const_const__InputArrayR_Ptr_PredictCollector_(self.as_raw_FaceRecognizer(), src.as_raw__InputArray(), collector.as_raw_mut_PtrOfPredictCollector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -915,9 +1048,10 @@ pub trait FaceRecognizerConst: core::AlgorithmTraitConst {
 	/// be safely called from within different threads.
 	#[inline]
 	fn write(&self, filename: &str) -> Result<()> {
+		crate::core::validity::check("FaceRecognizer", self.as_raw_FaceRecognizer())?;
 		extern_container_arg!(filename);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FaceRecognizer_write_const_const_StringR(self.as_raw_FaceRecognizer(), filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FaceRecognizer_write_const_const_StringR(self.as_raw_FaceRecognizer(), filename.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -942,8 +1076,9 @@ pub trait FaceRecognizerConst: core::AlgorithmTraitConst {
 	/// * fs: The FileStorage to store this FaceRecognizer to.
 	#[inline]
 	fn write_1(&self, fs: &mut core::FileStorage) -> Result<()> {
+		crate::core::validity::check("FaceRecognizer", self.as_raw_FaceRecognizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FaceRecognizer_write_const_FileStorageR(self.as_raw_FaceRecognizer(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FaceRecognizer_write_const_FileStorageR(self.as_raw_FaceRecognizer(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -951,8 +1086,9 @@ pub trait FaceRecognizerConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn empty(&self) -> Result<bool> {
+		crate::core::validity::check("FaceRecognizer", self.as_raw_FaceRecognizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FaceRecognizer_empty_const(self.as_raw_FaceRecognizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FaceRecognizer_empty_const(self.as_raw_FaceRecognizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -964,8 +1100,9 @@ pub trait FaceRecognizerConst: core::AlgorithmTraitConst {
 	/// label id the method returns an empty string.
 	#[inline]
 	fn get_label_info(&self, label: i32) -> Result<String> {
+		crate::core::validity::check("FaceRecognizer", self.as_raw_FaceRecognizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FaceRecognizer_getLabelInfo_const_int(self.as_raw_FaceRecognizer(), label, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FaceRecognizer_getLabelInfo_const_int(self.as_raw_FaceRecognizer(), label, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -978,9 +1115,10 @@ pub trait FaceRecognizerConst: core::AlgorithmTraitConst {
 	/// info.
 	#[inline]
 	fn get_labels_by_string(&self, str: &str) -> Result<core::Vector<i32>> {
+		crate::core::validity::check("FaceRecognizer", self.as_raw_FaceRecognizer())?;
 		extern_container_arg!(str);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FaceRecognizer_getLabelsByString_const_const_StringR(self.as_raw_FaceRecognizer(), str.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FaceRecognizer_getLabelsByString_const_const_StringR(self.as_raw_FaceRecognizer(), str.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<i32>::opencv_from_extern(ret) };
@@ -990,8 +1128,9 @@ pub trait FaceRecognizerConst: core::AlgorithmTraitConst {
 	/// threshold parameter accessor - required for default BestMinDist collector
 	#[inline]
 	fn get_threshold(&self) -> Result<f64> {
+		crate::core::validity::check("FaceRecognizer", self.as_raw_FaceRecognizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FaceRecognizer_getThreshold_const(self.as_raw_FaceRecognizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FaceRecognizer_getThreshold_const(self.as_raw_FaceRecognizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1056,10 +1195,11 @@ pub trait FaceRecognizer: core::AlgorithmTrait + crate::face::FaceRecognizerCons
 	/// 
 	#[inline]
 	fn train(&mut self, src: &dyn core::ToInputArray, labels: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("FaceRecognizer", self.as_raw_mut_FaceRecognizer())?;
 		input_array_arg!(src);
 		input_array_arg!(labels);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FaceRecognizer_train_const__InputArrayR_const__InputArrayR(self.as_raw_mut_FaceRecognizer(), src.as_raw__InputArray(), labels.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FaceRecognizer_train_const__InputArrayR_const__InputArrayR(self.as_raw_mut_FaceRecognizer(), src.as_raw__InputArray(), labels.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1117,10 +1257,11 @@ pub trait FaceRecognizer: core::AlgorithmTrait + crate::face::FaceRecognizerCons
 	/// responsible for maintaining the dataset, he want to work with.
 	#[inline]
 	fn update(&mut self, src: &dyn core::ToInputArray, labels: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("FaceRecognizer", self.as_raw_mut_FaceRecognizer())?;
 		input_array_arg!(src);
 		input_array_arg!(labels);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FaceRecognizer_update_const__InputArrayR_const__InputArrayR(self.as_raw_mut_FaceRecognizer(), src.as_raw__InputArray(), labels.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FaceRecognizer_update_const__InputArrayR_const__InputArrayR(self.as_raw_mut_FaceRecognizer(), src.as_raw__InputArray(), labels.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1134,9 +1275,10 @@ pub trait FaceRecognizer: core::AlgorithmTrait + crate::face::FaceRecognizerCons
 	/// FaceRecognizer::load(const String& filename), to ease saving a model.
 	#[inline]
 	fn read(&mut self, filename: &str) -> Result<()> {
+		crate::core::validity::check("FaceRecognizer", self.as_raw_mut_FaceRecognizer())?;
 		extern_container_arg!(filename);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FaceRecognizer_read_const_StringR(self.as_raw_mut_FaceRecognizer(), filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FaceRecognizer_read_const_StringR(self.as_raw_mut_FaceRecognizer(), filename.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1152,8 +1294,9 @@ pub trait FaceRecognizer: core::AlgorithmTrait + crate::face::FaceRecognizerCons
 	/// ## Overloaded parameters
 	#[inline]
 	fn read_1(&mut self, fn_: &core::FileNode) -> Result<()> {
+		crate::core::validity::check("FaceRecognizer", self.as_raw_mut_FaceRecognizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FaceRecognizer_read_const_FileNodeR(self.as_raw_mut_FaceRecognizer(), fn_.as_raw_FileNode(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FaceRecognizer_read_const_FileNodeR(self.as_raw_mut_FaceRecognizer(), fn_.as_raw_FileNode(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1164,9 +1307,10 @@ pub trait FaceRecognizer: core::AlgorithmTrait + crate::face::FaceRecognizerCons
 	/// The string info is replaced by the provided value if it was set before for the specified label.
 	#[inline]
 	fn set_label_info(&mut self, label: i32, str_info: &str) -> Result<()> {
+		crate::core::validity::check("FaceRecognizer", self.as_raw_mut_FaceRecognizer())?;
 		extern_container_arg!(str_info);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FaceRecognizer_setLabelInfo_int_const_StringR(self.as_raw_mut_FaceRecognizer(), label, str_info.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FaceRecognizer_setLabelInfo_int_const_StringR(self.as_raw_mut_FaceRecognizer(), label, str_info.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1175,8 +1319,9 @@ pub trait FaceRecognizer: core::AlgorithmTrait + crate::face::FaceRecognizerCons
 	/// Sets threshold of model
 	#[inline]
 	fn set_threshold(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("FaceRecognizer", self.as_raw_mut_FaceRecognizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FaceRecognizer_setThreshold_double(self.as_raw_mut_FaceRecognizer(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FaceRecognizer_setThreshold_double(self.as_raw_mut_FaceRecognizer(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1186,7 +1331,7 @@ pub trait FaceRecognizer: core::AlgorithmTrait + crate::face::FaceRecognizerCons
 
 /// Abstract base class for all facemark models
 /// 
-/// To utilize this API in your program, please take a look at the @ref tutorial_table_of_content_facemark
+/// To utilize this API in your program, please take a look at the tutorial_table_of_content_facemark
 /// ### Description
 /// 
 /// Facemark is a base class which provides universal access to any specific facemark algorithm.
@@ -1221,9 +1366,10 @@ pub trait Facemark: core::AlgorithmTrait + crate::face::FacemarkConst {
 	/// 
 	#[inline]
 	fn load_model(&mut self, model: &str) -> Result<()> {
+		crate::core::validity::check("Facemark", self.as_raw_mut_Facemark())?;
 		extern_container_arg!(mut model);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_Facemark_loadModel_String(self.as_raw_mut_Facemark(), model.opencv_as_extern_mut(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_Facemark_loadModel_String(self.as_raw_mut_Facemark(), model.opencv_as_extern_mut(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1246,11 +1392,12 @@ pub trait Facemark: core::AlgorithmTrait + crate::face::FacemarkConst {
 	/// 
 	#[inline]
 	fn fit(&mut self, image: &dyn core::ToInputArray, faces: &dyn core::ToInputArray, landmarks: &mut dyn core::ToOutputArray) -> Result<bool> {
+		crate::core::validity::check("Facemark", self.as_raw_mut_Facemark())?;
 		input_array_arg!(image);
 		input_array_arg!(faces);
 		output_array_arg!(landmarks);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_Facemark_fit_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_Facemark(), image.as_raw__InputArray(), faces.as_raw__InputArray(), landmarks.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_Facemark_fit_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_Facemark(), image.as_raw__InputArray(), faces.as_raw__InputArray(), landmarks.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1269,11 +1416,12 @@ pub trait FacemarkAAM: crate::face::FacemarkAAMConst + crate::face::FacemarkTrai
 	/// overload with additional Config structures
 	#[inline]
 	fn fit_config(&mut self, image: &dyn core::ToInputArray, roi: &dyn core::ToInputArray, _landmarks: &mut dyn core::ToOutputArray, runtime_params: &core::Vector<crate::face::FacemarkAAM_Config>) -> Result<bool> {
+		crate::core::validity::check("FacemarkAAM", self.as_raw_mut_FacemarkAAM())?;
 		input_array_arg!(image);
 		input_array_arg!(roi);
 		output_array_arg!(_landmarks);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FacemarkAAM_fitConfig_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const_vector_Config_R(self.as_raw_mut_FacemarkAAM(), image.as_raw__InputArray(), roi.as_raw__InputArray(), _landmarks.as_raw__OutputArray(), runtime_params.as_raw_VectorOfFacemarkAAM_Config(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FacemarkAAM_fitConfig_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const_vector_Config_R(self.as_raw_mut_FacemarkAAM(), image.as_raw__InputArray(), roi.as_raw__InputArray(), _landmarks.as_raw__OutputArray(), runtime_params.as_raw_VectorOfFacemarkAAM_Config(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1289,7 +1437,7 @@ impl dyn FacemarkAAM + '_ {
 	#[inline]
 	pub fn create(parameters: &crate::face::FacemarkAAM_Params) -> Result<core::Ptr<dyn crate::face::FacemarkAAM>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FacemarkAAM_create_const_ParamsR(parameters.as_raw_FacemarkAAM_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FacemarkAAM_create_const_ParamsR(parameters.as_raw_FacemarkAAM_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::face::FacemarkAAM>::opencv_from_extern(ret) };
@@ -1303,31 +1451,67 @@ pub trait FacemarkAAM_ConfigTraitConst {
 
 	#[inline]
 	fn r(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Config_getPropR_const(self.as_raw_FacemarkAAM_Config()) };
+		crate::core::validity::debug_check("FacemarkAAM_Config", self.as_raw_FacemarkAAM_Config());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Config_getPropR_const(self.as_raw_FacemarkAAM_Config())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [r](FacemarkAAM_ConfigTraitConst::r), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_r(&self) -> Result<core::Mat> {
+		crate::core::validity::check("FacemarkAAM_Config", self.as_raw_FacemarkAAM_Config())?;
+		Ok(FacemarkAAM_ConfigTraitConst::r(self))
+	}
+	
 	#[inline]
 	fn t(&self) -> core::Point2f {
+		crate::core::validity::debug_check("FacemarkAAM_Config", self.as_raw_FacemarkAAM_Config());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FacemarkAAM_Config_getPropT_const(self.as_raw_FacemarkAAM_Config(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FacemarkAAM_Config_getPropT_const(self.as_raw_FacemarkAAM_Config(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [t](FacemarkAAM_ConfigTraitConst::t), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_t(&self) -> Result<core::Point2f> {
+		crate::core::validity::check("FacemarkAAM_Config", self.as_raw_FacemarkAAM_Config())?;
+		Ok(FacemarkAAM_ConfigTraitConst::t(self))
+	}
+	
 	#[inline]
 	fn scale(&self) -> f32 {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Config_getPropScale_const(self.as_raw_FacemarkAAM_Config()) };
+		crate::core::validity::debug_check("FacemarkAAM_Config", self.as_raw_FacemarkAAM_Config());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Config_getPropScale_const(self.as_raw_FacemarkAAM_Config())) };
 		ret
 	}
 	
+	/// Non-panicking version of [scale](FacemarkAAM_ConfigTraitConst::scale), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_scale(&self) -> Result<f32> {
+		crate::core::validity::check("FacemarkAAM_Config", self.as_raw_FacemarkAAM_Config())?;
+		Ok(FacemarkAAM_ConfigTraitConst::scale(self))
+	}
+	
 	#[inline]
 	fn model_scale_idx(&self) -> i32 {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Config_getPropModel_scale_idx_const(self.as_raw_FacemarkAAM_Config()) };
+		crate::core::validity::debug_check("FacemarkAAM_Config", self.as_raw_FacemarkAAM_Config());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Config_getPropModel_scale_idx_const(self.as_raw_FacemarkAAM_Config())) };
 		ret
 	}
 	
+	/// Non-panicking version of [model_scale_idx](FacemarkAAM_ConfigTraitConst::model_scale_idx), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_model_scale_idx(&self) -> Result<i32> {
+		crate::core::validity::check("FacemarkAAM_Config", self.as_raw_FacemarkAAM_Config())?;
+		Ok(FacemarkAAM_ConfigTraitConst::model_scale_idx(self))
+	}
+	
 }
 
 pub trait FacemarkAAM_ConfigTrait: crate::face::FacemarkAAM_ConfigTraitConst {
@@ -1335,25 +1519,29 @@ pub trait FacemarkAAM_ConfigTrait: crate::face::FacemarkAAM_ConfigTraitConst {
 
 	#[inline]
 	fn set_r(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Config_setPropR_Mat(self.as_raw_mut_FacemarkAAM_Config(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("FacemarkAAM_Config", self.as_raw_mut_FacemarkAAM_Config());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Config_setPropR_Mat(self.as_raw_mut_FacemarkAAM_Config(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_t(&mut self, val: core::Point2f) {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Config_setPropT_Point2f(self.as_raw_mut_FacemarkAAM_Config(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("FacemarkAAM_Config", self.as_raw_mut_FacemarkAAM_Config());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Config_setPropT_Point2f(self.as_raw_mut_FacemarkAAM_Config(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_scale(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Config_setPropScale_float(self.as_raw_mut_FacemarkAAM_Config(), val) };
+		crate::core::validity::debug_check("FacemarkAAM_Config", self.as_raw_mut_FacemarkAAM_Config());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Config_setPropScale_float(self.as_raw_mut_FacemarkAAM_Config(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_model_scale_idx(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Config_setPropModel_scale_idx_int(self.as_raw_mut_FacemarkAAM_Config(), val) };
+		crate::core::validity::debug_check("FacemarkAAM_Config", self.as_raw_mut_FacemarkAAM_Config());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Config_setPropModel_scale_idx_int(self.as_raw_mut_FacemarkAAM_Config(), val)) };
 		ret
 	}
 	
@@ -1369,7 +1557,10 @@ opencv_type_boxed! { FacemarkAAM_Config }
 impl Drop for FacemarkAAM_Config {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FacemarkAAM_Config_delete(instance: *mut c_void); }
-		unsafe { cv_FacemarkAAM_Config_delete(self.as_raw_mut_FacemarkAAM_Config()) };
+		let ptr = self.as_raw_mut_FacemarkAAM_Config();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_FacemarkAAM_Config_delete(ptr) };
 	}
 }
 
@@ -1392,7 +1583,7 @@ impl FacemarkAAM_Config {
 	#[inline]
 	pub fn new(mut rot: core::Mat, trans: core::Point2f, scaling: f32, scale_id: i32) -> Result<crate::face::FacemarkAAM_Config> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FacemarkAAM_Config_Config_Mat_Point2f_float_int(rot.as_raw_mut_Mat(), trans.opencv_as_extern(), scaling, scale_id, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FacemarkAAM_Config_Config_Mat_Point2f_float_int(rot.as_raw_mut_Mat(), trans.opencv_as_extern(), scaling, scale_id, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::face::FacemarkAAM_Config::opencv_from_extern(ret) };
@@ -1401,17 +1592,70 @@ impl FacemarkAAM_Config {
 	
 }
 
+/// Builder for `FacemarkAAM_Config::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FacemarkAAM_Config_NewBuilder {
+	rot: core::Mat,
+	trans: core::Point2f,
+	scaling: f32,
+	scale_id: i32,
+}
+
+impl FacemarkAAM_Config_NewBuilder {
+	#[inline]
+	pub fn new(rot: core::Mat) -> Self {
+		Self { rot, trans: core::Point2f::new(0.0, 0.0), scaling: 1.0, scale_id: 0 }
+	}
+	
+	/// C++ default: `Point2f(0.0f,0.0f)`
+	#[inline]
+	pub fn with_trans(mut self, trans: core::Point2f) -> Self {
+		self.trans = trans;
+		self
+	}
+	
+	/// C++ default: `1.0f`
+	#[inline]
+	pub fn with_scaling(mut self, scaling: f32) -> Self {
+		self.scaling = scaling;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_scale_id(mut self, scale_id: i32) -> Self {
+		self.scale_id = scale_id;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::face::FacemarkAAM_Config> {
+		FacemarkAAM_Config::new(self.rot, self.trans, self.scaling, self.scale_id)
+	}
+}
+
 /// \brief Data container for the facemark::getData function
 pub trait FacemarkAAM_DataTraitConst {
 	fn as_raw_FacemarkAAM_Data(&self) -> *const c_void;
 
 	#[inline]
 	fn s0(&self) -> core::Vector<core::Point2f> {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Data_getPropS0_const(self.as_raw_FacemarkAAM_Data()) };
+		crate::core::validity::debug_check("FacemarkAAM_Data", self.as_raw_FacemarkAAM_Data());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Data_getPropS0_const(self.as_raw_FacemarkAAM_Data())) };
 		let ret = unsafe { core::Vector::<core::Point2f>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [s0](FacemarkAAM_DataTraitConst::s0), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_s0(&self) -> Result<core::Vector<core::Point2f>> {
+		crate::core::validity::check("FacemarkAAM_Data", self.as_raw_FacemarkAAM_Data())?;
+		Ok(FacemarkAAM_DataTraitConst::s0(self))
+	}
+	
 }
 
 pub trait FacemarkAAM_DataTrait: crate::face::FacemarkAAM_DataTraitConst {
@@ -1419,7 +1663,8 @@ pub trait FacemarkAAM_DataTrait: crate::face::FacemarkAAM_DataTraitConst {
 
 	#[inline]
 	fn set_s0(&mut self, mut val: core::Vector<core::Point2f>) {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Data_setPropS0_vector_Point2f_(self.as_raw_mut_FacemarkAAM_Data(), val.as_raw_mut_VectorOfPoint2f()) };
+		crate::core::validity::debug_check("FacemarkAAM_Data", self.as_raw_mut_FacemarkAAM_Data());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Data_setPropS0_vector_Point2f_(self.as_raw_mut_FacemarkAAM_Data(), val.as_raw_mut_VectorOfPoint2f())) };
 		ret
 	}
 	
@@ -1435,7 +1680,10 @@ opencv_type_boxed! { FacemarkAAM_Data }
 impl Drop for FacemarkAAM_Data {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FacemarkAAM_Data_delete(instance: *mut c_void); }
-		unsafe { cv_FacemarkAAM_Data_delete(self.as_raw_mut_FacemarkAAM_Data()) };
+		let ptr = self.as_raw_mut_FacemarkAAM_Data();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_FacemarkAAM_Data_delete(ptr) };
 	}
 }
 
@@ -1458,46 +1706,100 @@ pub trait FacemarkAAM_ModelTraitConst {
 
 	#[inline]
 	fn scales(&self) -> core::Vector<f32> {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_getPropScales_const(self.as_raw_FacemarkAAM_Model()) };
+		crate::core::validity::debug_check("FacemarkAAM_Model", self.as_raw_FacemarkAAM_Model());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Model_getPropScales_const(self.as_raw_FacemarkAAM_Model())) };
 		let ret = unsafe { core::Vector::<f32>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [scales](FacemarkAAM_ModelTraitConst::scales), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_scales(&self) -> Result<core::Vector<f32>> {
+		crate::core::validity::check("FacemarkAAM_Model", self.as_raw_FacemarkAAM_Model())?;
+		Ok(FacemarkAAM_ModelTraitConst::scales(self))
+	}
+	
 	#[inline]
 	fn triangles(&self) -> core::Vector<core::Vec3i> {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_getPropTriangles_const(self.as_raw_FacemarkAAM_Model()) };
+		crate::core::validity::debug_check("FacemarkAAM_Model", self.as_raw_FacemarkAAM_Model());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Model_getPropTriangles_const(self.as_raw_FacemarkAAM_Model())) };
 		let ret = unsafe { core::Vector::<core::Vec3i>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [triangles](FacemarkAAM_ModelTraitConst::triangles), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_triangles(&self) -> Result<core::Vector<core::Vec3i>> {
+		crate::core::validity::check("FacemarkAAM_Model", self.as_raw_FacemarkAAM_Model())?;
+		Ok(FacemarkAAM_ModelTraitConst::triangles(self))
+	}
+	
 	#[inline]
 	fn textures(&self) -> core::Vector<crate::face::FacemarkAAM_Model_Texture> {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_getPropTextures_const(self.as_raw_FacemarkAAM_Model()) };
+		crate::core::validity::debug_check("FacemarkAAM_Model", self.as_raw_FacemarkAAM_Model());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Model_getPropTextures_const(self.as_raw_FacemarkAAM_Model())) };
 		let ret = unsafe { core::Vector::<crate::face::FacemarkAAM_Model_Texture>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [textures](FacemarkAAM_ModelTraitConst::textures), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_textures(&self) -> Result<core::Vector<crate::face::FacemarkAAM_Model_Texture>> {
+		crate::core::validity::check("FacemarkAAM_Model", self.as_raw_FacemarkAAM_Model())?;
+		Ok(FacemarkAAM_ModelTraitConst::textures(self))
+	}
+	
 	#[inline]
 	fn s0(&self) -> core::Vector<core::Point2f> {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_getPropS0_const(self.as_raw_FacemarkAAM_Model()) };
+		crate::core::validity::debug_check("FacemarkAAM_Model", self.as_raw_FacemarkAAM_Model());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Model_getPropS0_const(self.as_raw_FacemarkAAM_Model())) };
 		let ret = unsafe { core::Vector::<core::Point2f>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [s0](FacemarkAAM_ModelTraitConst::s0), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_s0(&self) -> Result<core::Vector<core::Point2f>> {
+		crate::core::validity::check("FacemarkAAM_Model", self.as_raw_FacemarkAAM_Model())?;
+		Ok(FacemarkAAM_ModelTraitConst::s0(self))
+	}
+	
 	#[inline]
 	fn s(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_getPropS_const(self.as_raw_FacemarkAAM_Model()) };
+		crate::core::validity::debug_check("FacemarkAAM_Model", self.as_raw_FacemarkAAM_Model());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Model_getPropS_const(self.as_raw_FacemarkAAM_Model())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [s](FacemarkAAM_ModelTraitConst::s), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_s(&self) -> Result<core::Mat> {
+		crate::core::validity::check("FacemarkAAM_Model", self.as_raw_FacemarkAAM_Model())?;
+		Ok(FacemarkAAM_ModelTraitConst::s(self))
+	}
+	
 	#[inline]
 	fn q(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_getPropQ_const(self.as_raw_FacemarkAAM_Model()) };
+		crate::core::validity::debug_check("FacemarkAAM_Model", self.as_raw_FacemarkAAM_Model());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Model_getPropQ_const(self.as_raw_FacemarkAAM_Model())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [q](FacemarkAAM_ModelTraitConst::q), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_q(&self) -> Result<core::Mat> {
+		crate::core::validity::check("FacemarkAAM_Model", self.as_raw_FacemarkAAM_Model())?;
+		Ok(FacemarkAAM_ModelTraitConst::q(self))
+	}
+	
 }
 
 pub trait FacemarkAAM_ModelTrait: crate::face::FacemarkAAM_ModelTraitConst {
@@ -1505,37 +1807,43 @@ pub trait FacemarkAAM_ModelTrait: crate::face::FacemarkAAM_ModelTraitConst {
 
 	#[inline]
 	fn set_scales(&mut self, mut val: core::Vector<f32>) {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_setPropScales_vector_float_(self.as_raw_mut_FacemarkAAM_Model(), val.as_raw_mut_VectorOff32()) };
+		crate::core::validity::debug_check("FacemarkAAM_Model", self.as_raw_mut_FacemarkAAM_Model());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Model_setPropScales_vector_float_(self.as_raw_mut_FacemarkAAM_Model(), val.as_raw_mut_VectorOff32())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_triangles(&mut self, mut val: core::Vector<core::Vec3i>) {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_setPropTriangles_vector_Vec3i_(self.as_raw_mut_FacemarkAAM_Model(), val.as_raw_mut_VectorOfVec3i()) };
+		crate::core::validity::debug_check("FacemarkAAM_Model", self.as_raw_mut_FacemarkAAM_Model());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Model_setPropTriangles_vector_Vec3i_(self.as_raw_mut_FacemarkAAM_Model(), val.as_raw_mut_VectorOfVec3i())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_textures(&mut self, mut val: core::Vector<crate::face::FacemarkAAM_Model_Texture>) {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_setPropTextures_vector_Texture_(self.as_raw_mut_FacemarkAAM_Model(), val.as_raw_mut_VectorOfFacemarkAAM_Model_Texture()) };
+		crate::core::validity::debug_check("FacemarkAAM_Model", self.as_raw_mut_FacemarkAAM_Model());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Model_setPropTextures_vector_Texture_(self.as_raw_mut_FacemarkAAM_Model(), val.as_raw_mut_VectorOfFacemarkAAM_Model_Texture())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_s0(&mut self, mut val: core::Vector<core::Point2f>) {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_setPropS0_vector_Point2f_(self.as_raw_mut_FacemarkAAM_Model(), val.as_raw_mut_VectorOfPoint2f()) };
+		crate::core::validity::debug_check("FacemarkAAM_Model", self.as_raw_mut_FacemarkAAM_Model());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Model_setPropS0_vector_Point2f_(self.as_raw_mut_FacemarkAAM_Model(), val.as_raw_mut_VectorOfPoint2f())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_s(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_setPropS_Mat(self.as_raw_mut_FacemarkAAM_Model(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("FacemarkAAM_Model", self.as_raw_mut_FacemarkAAM_Model());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Model_setPropS_Mat(self.as_raw_mut_FacemarkAAM_Model(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_q(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_setPropQ_Mat(self.as_raw_mut_FacemarkAAM_Model(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("FacemarkAAM_Model", self.as_raw_mut_FacemarkAAM_Model());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Model_setPropQ_Mat(self.as_raw_mut_FacemarkAAM_Model(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
@@ -1551,7 +1859,10 @@ opencv_type_boxed! { FacemarkAAM_Model }
 impl Drop for FacemarkAAM_Model {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FacemarkAAM_Model_delete(instance: *mut c_void); }
-		unsafe { cv_FacemarkAAM_Model_delete(self.as_raw_mut_FacemarkAAM_Model()) };
+		let ptr = self.as_raw_mut_FacemarkAAM_Model();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_FacemarkAAM_Model_delete(ptr) };
 	}
 }
 
@@ -1574,74 +1885,164 @@ pub trait FacemarkAAM_Model_TextureTraitConst {
 	/// unused delete
 	#[inline]
 	fn max_m(&self) -> i32 {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_getPropMax_m_const(self.as_raw_FacemarkAAM_Model_Texture()) };
+		crate::core::validity::debug_check("FacemarkAAM_Model_Texture", self.as_raw_FacemarkAAM_Model_Texture());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Model_Texture_getPropMax_m_const(self.as_raw_FacemarkAAM_Model_Texture())) };
 		ret
 	}
 	
+	/// Non-panicking version of [max_m](FacemarkAAM_Model_TextureTraitConst::max_m), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_max_m(&self) -> Result<i32> {
+		crate::core::validity::check("FacemarkAAM_Model_Texture", self.as_raw_FacemarkAAM_Model_Texture())?;
+		Ok(FacemarkAAM_Model_TextureTraitConst::max_m(self))
+	}
+	
 	#[inline]
 	fn resolution(&self) -> core::Rect {
+		crate::core::validity::debug_check("FacemarkAAM_Model_Texture", self.as_raw_FacemarkAAM_Model_Texture());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FacemarkAAM_Model_Texture_getPropResolution_const(self.as_raw_FacemarkAAM_Model_Texture(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FacemarkAAM_Model_Texture_getPropResolution_const(self.as_raw_FacemarkAAM_Model_Texture(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [resolution](FacemarkAAM_Model_TextureTraitConst::resolution), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_resolution(&self) -> Result<core::Rect> {
+		crate::core::validity::check("FacemarkAAM_Model_Texture", self.as_raw_FacemarkAAM_Model_Texture())?;
+		Ok(FacemarkAAM_Model_TextureTraitConst::resolution(self))
+	}
+	
 	#[inline]
 	fn a(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_getPropA_const(self.as_raw_FacemarkAAM_Model_Texture()) };
+		crate::core::validity::debug_check("FacemarkAAM_Model_Texture", self.as_raw_FacemarkAAM_Model_Texture());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Model_Texture_getPropA_const(self.as_raw_FacemarkAAM_Model_Texture())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [a](FacemarkAAM_Model_TextureTraitConst::a), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_a(&self) -> Result<core::Mat> {
+		crate::core::validity::check("FacemarkAAM_Model_Texture", self.as_raw_FacemarkAAM_Model_Texture())?;
+		Ok(FacemarkAAM_Model_TextureTraitConst::a(self))
+	}
+	
 	#[inline]
 	fn a0(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_getPropA0_const(self.as_raw_FacemarkAAM_Model_Texture()) };
+		crate::core::validity::debug_check("FacemarkAAM_Model_Texture", self.as_raw_FacemarkAAM_Model_Texture());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Model_Texture_getPropA0_const(self.as_raw_FacemarkAAM_Model_Texture())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [a0](FacemarkAAM_Model_TextureTraitConst::a0), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_a0(&self) -> Result<core::Mat> {
+		crate::core::validity::check("FacemarkAAM_Model_Texture", self.as_raw_FacemarkAAM_Model_Texture())?;
+		Ok(FacemarkAAM_Model_TextureTraitConst::a0(self))
+	}
+	
 	#[inline]
 	fn aa(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_getPropAA_const(self.as_raw_FacemarkAAM_Model_Texture()) };
+		crate::core::validity::debug_check("FacemarkAAM_Model_Texture", self.as_raw_FacemarkAAM_Model_Texture());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Model_Texture_getPropAA_const(self.as_raw_FacemarkAAM_Model_Texture())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [aa](FacemarkAAM_Model_TextureTraitConst::aa), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_aa(&self) -> Result<core::Mat> {
+		crate::core::validity::check("FacemarkAAM_Model_Texture", self.as_raw_FacemarkAAM_Model_Texture())?;
+		Ok(FacemarkAAM_Model_TextureTraitConst::aa(self))
+	}
+	
 	#[inline]
 	fn aa0(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_getPropAA0_const(self.as_raw_FacemarkAAM_Model_Texture()) };
+		crate::core::validity::debug_check("FacemarkAAM_Model_Texture", self.as_raw_FacemarkAAM_Model_Texture());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Model_Texture_getPropAA0_const(self.as_raw_FacemarkAAM_Model_Texture())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [aa0](FacemarkAAM_Model_TextureTraitConst::aa0), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_aa0(&self) -> Result<core::Mat> {
+		crate::core::validity::check("FacemarkAAM_Model_Texture", self.as_raw_FacemarkAAM_Model_Texture())?;
+		Ok(FacemarkAAM_Model_TextureTraitConst::aa0(self))
+	}
+	
 	#[inline]
 	fn texture_idx(&self) -> core::Vector<core::Vector<core::Point>> {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_getPropTextureIdx_const(self.as_raw_FacemarkAAM_Model_Texture()) };
+		crate::core::validity::debug_check("FacemarkAAM_Model_Texture", self.as_raw_FacemarkAAM_Model_Texture());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Model_Texture_getPropTextureIdx_const(self.as_raw_FacemarkAAM_Model_Texture())) };
 		let ret = unsafe { core::Vector::<core::Vector<core::Point>>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [texture_idx](FacemarkAAM_Model_TextureTraitConst::texture_idx), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_texture_idx(&self) -> Result<core::Vector<core::Vector<core::Point>>> {
+		crate::core::validity::check("FacemarkAAM_Model_Texture", self.as_raw_FacemarkAAM_Model_Texture())?;
+		Ok(FacemarkAAM_Model_TextureTraitConst::texture_idx(self))
+	}
+	
 	#[inline]
 	fn base_shape(&self) -> core::Vector<core::Point2f> {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_getPropBase_shape_const(self.as_raw_FacemarkAAM_Model_Texture()) };
+		crate::core::validity::debug_check("FacemarkAAM_Model_Texture", self.as_raw_FacemarkAAM_Model_Texture());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Model_Texture_getPropBase_shape_const(self.as_raw_FacemarkAAM_Model_Texture())) };
 		let ret = unsafe { core::Vector::<core::Point2f>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [base_shape](FacemarkAAM_Model_TextureTraitConst::base_shape), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_base_shape(&self) -> Result<core::Vector<core::Point2f>> {
+		crate::core::validity::check("FacemarkAAM_Model_Texture", self.as_raw_FacemarkAAM_Model_Texture())?;
+		Ok(FacemarkAAM_Model_TextureTraitConst::base_shape(self))
+	}
+	
 	#[inline]
 	fn ind1(&self) -> core::Vector<i32> {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_getPropInd1_const(self.as_raw_FacemarkAAM_Model_Texture()) };
+		crate::core::validity::debug_check("FacemarkAAM_Model_Texture", self.as_raw_FacemarkAAM_Model_Texture());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Model_Texture_getPropInd1_const(self.as_raw_FacemarkAAM_Model_Texture())) };
 		let ret = unsafe { core::Vector::<i32>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [ind1](FacemarkAAM_Model_TextureTraitConst::ind1), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_ind1(&self) -> Result<core::Vector<i32>> {
+		crate::core::validity::check("FacemarkAAM_Model_Texture", self.as_raw_FacemarkAAM_Model_Texture())?;
+		Ok(FacemarkAAM_Model_TextureTraitConst::ind1(self))
+	}
+	
 	#[inline]
 	fn ind2(&self) -> core::Vector<i32> {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_getPropInd2_const(self.as_raw_FacemarkAAM_Model_Texture()) };
+		crate::core::validity::debug_check("FacemarkAAM_Model_Texture", self.as_raw_FacemarkAAM_Model_Texture());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Model_Texture_getPropInd2_const(self.as_raw_FacemarkAAM_Model_Texture())) };
 		let ret = unsafe { core::Vector::<i32>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [ind2](FacemarkAAM_Model_TextureTraitConst::ind2), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_ind2(&self) -> Result<core::Vector<i32>> {
+		crate::core::validity::check("FacemarkAAM_Model_Texture", self.as_raw_FacemarkAAM_Model_Texture())?;
+		Ok(FacemarkAAM_Model_TextureTraitConst::ind2(self))
+	}
+	
 }
 
 pub trait FacemarkAAM_Model_TextureTrait: crate::face::FacemarkAAM_Model_TextureTraitConst {
@@ -1650,61 +2051,71 @@ pub trait FacemarkAAM_Model_TextureTrait: crate::face::FacemarkAAM_Model_Texture
 	/// unused delete
 	#[inline]
 	fn set_max_m(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_setPropMax_m_int(self.as_raw_mut_FacemarkAAM_Model_Texture(), val) };
+		crate::core::validity::debug_check("FacemarkAAM_Model_Texture", self.as_raw_mut_FacemarkAAM_Model_Texture());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Model_Texture_setPropMax_m_int(self.as_raw_mut_FacemarkAAM_Model_Texture(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_resolution(&mut self, val: core::Rect) {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_setPropResolution_Rect(self.as_raw_mut_FacemarkAAM_Model_Texture(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("FacemarkAAM_Model_Texture", self.as_raw_mut_FacemarkAAM_Model_Texture());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Model_Texture_setPropResolution_Rect(self.as_raw_mut_FacemarkAAM_Model_Texture(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_a(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_setPropA_Mat(self.as_raw_mut_FacemarkAAM_Model_Texture(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("FacemarkAAM_Model_Texture", self.as_raw_mut_FacemarkAAM_Model_Texture());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Model_Texture_setPropA_Mat(self.as_raw_mut_FacemarkAAM_Model_Texture(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_a0(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_setPropA0_Mat(self.as_raw_mut_FacemarkAAM_Model_Texture(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("FacemarkAAM_Model_Texture", self.as_raw_mut_FacemarkAAM_Model_Texture());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Model_Texture_setPropA0_Mat(self.as_raw_mut_FacemarkAAM_Model_Texture(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_aa(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_setPropAA_Mat(self.as_raw_mut_FacemarkAAM_Model_Texture(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("FacemarkAAM_Model_Texture", self.as_raw_mut_FacemarkAAM_Model_Texture());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Model_Texture_setPropAA_Mat(self.as_raw_mut_FacemarkAAM_Model_Texture(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_aa0(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_setPropAA0_Mat(self.as_raw_mut_FacemarkAAM_Model_Texture(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("FacemarkAAM_Model_Texture", self.as_raw_mut_FacemarkAAM_Model_Texture());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Model_Texture_setPropAA0_Mat(self.as_raw_mut_FacemarkAAM_Model_Texture(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_texture_idx(&mut self, mut val: core::Vector<core::Vector<core::Point>>) {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_setPropTextureIdx_vector_vector_Point__(self.as_raw_mut_FacemarkAAM_Model_Texture(), val.as_raw_mut_VectorOfVectorOfPoint()) };
+		crate::core::validity::debug_check("FacemarkAAM_Model_Texture", self.as_raw_mut_FacemarkAAM_Model_Texture());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Model_Texture_setPropTextureIdx_vector_vector_Point__(self.as_raw_mut_FacemarkAAM_Model_Texture(), val.as_raw_mut_VectorOfVectorOfPoint())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_base_shape(&mut self, mut val: core::Vector<core::Point2f>) {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_setPropBase_shape_vector_Point2f_(self.as_raw_mut_FacemarkAAM_Model_Texture(), val.as_raw_mut_VectorOfPoint2f()) };
+		crate::core::validity::debug_check("FacemarkAAM_Model_Texture", self.as_raw_mut_FacemarkAAM_Model_Texture());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Model_Texture_setPropBase_shape_vector_Point2f_(self.as_raw_mut_FacemarkAAM_Model_Texture(), val.as_raw_mut_VectorOfPoint2f())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_ind1(&mut self, mut val: core::Vector<i32>) {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_setPropInd1_vector_int_(self.as_raw_mut_FacemarkAAM_Model_Texture(), val.as_raw_mut_VectorOfi32()) };
+		crate::core::validity::debug_check("FacemarkAAM_Model_Texture", self.as_raw_mut_FacemarkAAM_Model_Texture());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Model_Texture_setPropInd1_vector_int_(self.as_raw_mut_FacemarkAAM_Model_Texture(), val.as_raw_mut_VectorOfi32())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_ind2(&mut self, mut val: core::Vector<i32>) {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_setPropInd2_vector_int_(self.as_raw_mut_FacemarkAAM_Model_Texture(), val.as_raw_mut_VectorOfi32()) };
+		crate::core::validity::debug_check("FacemarkAAM_Model_Texture", self.as_raw_mut_FacemarkAAM_Model_Texture());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Model_Texture_setPropInd2_vector_int_(self.as_raw_mut_FacemarkAAM_Model_Texture(), val.as_raw_mut_VectorOfi32())) };
 		ret
 	}
 	
@@ -1719,7 +2130,10 @@ opencv_type_boxed! { FacemarkAAM_Model_Texture }
 impl Drop for FacemarkAAM_Model_Texture {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FacemarkAAM_Model_Texture_delete(instance: *mut c_void); }
-		unsafe { cv_FacemarkAAM_Model_Texture_delete(self.as_raw_mut_FacemarkAAM_Model_Texture()) };
+		let ptr = self.as_raw_mut_FacemarkAAM_Model_Texture();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_FacemarkAAM_Model_Texture_delete(ptr) };
 	}
 }
 
@@ -1741,71 +2155,162 @@ pub trait FacemarkAAM_ParamsTraitConst {
 
 	#[inline]
 	fn model_filename(&self) -> String {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Params_getPropModel_filename_const(self.as_raw_FacemarkAAM_Params()) };
+		crate::core::validity::debug_check("FacemarkAAM_Params", self.as_raw_FacemarkAAM_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Params_getPropModel_filename_const(self.as_raw_FacemarkAAM_Params())) };
 		let ret = unsafe { String::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [model_filename](FacemarkAAM_ParamsTraitConst::model_filename), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_model_filename(&self) -> Result<String> {
+		crate::core::validity::check("FacemarkAAM_Params", self.as_raw_FacemarkAAM_Params())?;
+		Ok(FacemarkAAM_ParamsTraitConst::model_filename(self))
+	}
+	
 	#[inline]
 	fn m(&self) -> i32 {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Params_getPropM_const(self.as_raw_FacemarkAAM_Params()) };
+		crate::core::validity::debug_check("FacemarkAAM_Params", self.as_raw_FacemarkAAM_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Params_getPropM_const(self.as_raw_FacemarkAAM_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [m](FacemarkAAM_ParamsTraitConst::m), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_m(&self) -> Result<i32> {
+		crate::core::validity::check("FacemarkAAM_Params", self.as_raw_FacemarkAAM_Params())?;
+		Ok(FacemarkAAM_ParamsTraitConst::m(self))
+	}
+	
 	#[inline]
 	fn n(&self) -> i32 {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Params_getPropN_const(self.as_raw_FacemarkAAM_Params()) };
+		crate::core::validity::debug_check("FacemarkAAM_Params", self.as_raw_FacemarkAAM_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Params_getPropN_const(self.as_raw_FacemarkAAM_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [n](FacemarkAAM_ParamsTraitConst::n), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_n(&self) -> Result<i32> {
+		crate::core::validity::check("FacemarkAAM_Params", self.as_raw_FacemarkAAM_Params())?;
+		Ok(FacemarkAAM_ParamsTraitConst::n(self))
+	}
+	
 	#[inline]
 	fn n_iter(&self) -> i32 {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Params_getPropN_iter_const(self.as_raw_FacemarkAAM_Params()) };
+		crate::core::validity::debug_check("FacemarkAAM_Params", self.as_raw_FacemarkAAM_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Params_getPropN_iter_const(self.as_raw_FacemarkAAM_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [n_iter](FacemarkAAM_ParamsTraitConst::n_iter), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_n_iter(&self) -> Result<i32> {
+		crate::core::validity::check("FacemarkAAM_Params", self.as_raw_FacemarkAAM_Params())?;
+		Ok(FacemarkAAM_ParamsTraitConst::n_iter(self))
+	}
+	
 	#[inline]
 	fn verbose(&self) -> bool {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Params_getPropVerbose_const(self.as_raw_FacemarkAAM_Params()) };
+		crate::core::validity::debug_check("FacemarkAAM_Params", self.as_raw_FacemarkAAM_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Params_getPropVerbose_const(self.as_raw_FacemarkAAM_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [verbose](FacemarkAAM_ParamsTraitConst::verbose), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_verbose(&self) -> Result<bool> {
+		crate::core::validity::check("FacemarkAAM_Params", self.as_raw_FacemarkAAM_Params())?;
+		Ok(FacemarkAAM_ParamsTraitConst::verbose(self))
+	}
+	
 	#[inline]
 	fn save_model(&self) -> bool {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Params_getPropSave_model_const(self.as_raw_FacemarkAAM_Params()) };
+		crate::core::validity::debug_check("FacemarkAAM_Params", self.as_raw_FacemarkAAM_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Params_getPropSave_model_const(self.as_raw_FacemarkAAM_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [save_model](FacemarkAAM_ParamsTraitConst::save_model), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_save_model(&self) -> Result<bool> {
+		crate::core::validity::check("FacemarkAAM_Params", self.as_raw_FacemarkAAM_Params())?;
+		Ok(FacemarkAAM_ParamsTraitConst::save_model(self))
+	}
+	
 	#[inline]
 	fn max_m(&self) -> i32 {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Params_getPropMax_m_const(self.as_raw_FacemarkAAM_Params()) };
+		crate::core::validity::debug_check("FacemarkAAM_Params", self.as_raw_FacemarkAAM_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Params_getPropMax_m_const(self.as_raw_FacemarkAAM_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [max_m](FacemarkAAM_ParamsTraitConst::max_m), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_max_m(&self) -> Result<i32> {
+		crate::core::validity::check("FacemarkAAM_Params", self.as_raw_FacemarkAAM_Params())?;
+		Ok(FacemarkAAM_ParamsTraitConst::max_m(self))
+	}
+	
 	#[inline]
 	fn max_n(&self) -> i32 {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Params_getPropMax_n_const(self.as_raw_FacemarkAAM_Params()) };
+		crate::core::validity::debug_check("FacemarkAAM_Params", self.as_raw_FacemarkAAM_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Params_getPropMax_n_const(self.as_raw_FacemarkAAM_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [max_n](FacemarkAAM_ParamsTraitConst::max_n), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_max_n(&self) -> Result<i32> {
+		crate::core::validity::check("FacemarkAAM_Params", self.as_raw_FacemarkAAM_Params())?;
+		Ok(FacemarkAAM_ParamsTraitConst::max_n(self))
+	}
+	
 	#[inline]
 	fn texture_max_m(&self) -> i32 {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Params_getPropTexture_max_m_const(self.as_raw_FacemarkAAM_Params()) };
+		crate::core::validity::debug_check("FacemarkAAM_Params", self.as_raw_FacemarkAAM_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Params_getPropTexture_max_m_const(self.as_raw_FacemarkAAM_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [texture_max_m](FacemarkAAM_ParamsTraitConst::texture_max_m), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_texture_max_m(&self) -> Result<i32> {
+		crate::core::validity::check("FacemarkAAM_Params", self.as_raw_FacemarkAAM_Params())?;
+		Ok(FacemarkAAM_ParamsTraitConst::texture_max_m(self))
+	}
+	
 	#[inline]
 	fn scales(&self) -> core::Vector<f32> {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Params_getPropScales_const(self.as_raw_FacemarkAAM_Params()) };
+		crate::core::validity::debug_check("FacemarkAAM_Params", self.as_raw_FacemarkAAM_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Params_getPropScales_const(self.as_raw_FacemarkAAM_Params())) };
 		let ret = unsafe { core::Vector::<f32>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [scales](FacemarkAAM_ParamsTraitConst::scales), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_scales(&self) -> Result<core::Vector<f32>> {
+		crate::core::validity::check("FacemarkAAM_Params", self.as_raw_FacemarkAAM_Params())?;
+		Ok(FacemarkAAM_ParamsTraitConst::scales(self))
+	}
+	
 	/// \brief Read parameters from file, currently unused
 	#[inline]
 	fn write(&self, unnamed: &mut core::FileStorage) -> Result<()> {
+		crate::core::validity::check("FacemarkAAM_Params", self.as_raw_FacemarkAAM_Params())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FacemarkAAM_Params_write_const_FileStorageR(self.as_raw_FacemarkAAM_Params(), unnamed.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FacemarkAAM_Params_write_const_FileStorageR(self.as_raw_FacemarkAAM_Params(), unnamed.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1818,70 +2323,81 @@ pub trait FacemarkAAM_ParamsTrait: crate::face::FacemarkAAM_ParamsTraitConst {
 
 	#[inline]
 	fn set_model_filename(&mut self, val: &str) {
+		crate::core::validity::debug_check("FacemarkAAM_Params", self.as_raw_mut_FacemarkAAM_Params());
 		extern_container_arg!(nofail mut val);
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Params_setPropModel_filename_string(self.as_raw_mut_FacemarkAAM_Params(), val.opencv_as_extern_mut()) };
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Params_setPropModel_filename_string(self.as_raw_mut_FacemarkAAM_Params(), val.opencv_as_extern_mut())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_m(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Params_setPropM_int(self.as_raw_mut_FacemarkAAM_Params(), val) };
+		crate::core::validity::debug_check("FacemarkAAM_Params", self.as_raw_mut_FacemarkAAM_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Params_setPropM_int(self.as_raw_mut_FacemarkAAM_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_n(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Params_setPropN_int(self.as_raw_mut_FacemarkAAM_Params(), val) };
+		crate::core::validity::debug_check("FacemarkAAM_Params", self.as_raw_mut_FacemarkAAM_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Params_setPropN_int(self.as_raw_mut_FacemarkAAM_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_n_iter(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Params_setPropN_iter_int(self.as_raw_mut_FacemarkAAM_Params(), val) };
+		crate::core::validity::debug_check("FacemarkAAM_Params", self.as_raw_mut_FacemarkAAM_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Params_setPropN_iter_int(self.as_raw_mut_FacemarkAAM_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_verbose(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Params_setPropVerbose_bool(self.as_raw_mut_FacemarkAAM_Params(), val) };
+		crate::core::validity::debug_check("FacemarkAAM_Params", self.as_raw_mut_FacemarkAAM_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Params_setPropVerbose_bool(self.as_raw_mut_FacemarkAAM_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_save_model(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Params_setPropSave_model_bool(self.as_raw_mut_FacemarkAAM_Params(), val) };
+		crate::core::validity::debug_check("FacemarkAAM_Params", self.as_raw_mut_FacemarkAAM_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Params_setPropSave_model_bool(self.as_raw_mut_FacemarkAAM_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_max_m(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Params_setPropMax_m_int(self.as_raw_mut_FacemarkAAM_Params(), val) };
+		crate::core::validity::debug_check("FacemarkAAM_Params", self.as_raw_mut_FacemarkAAM_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Params_setPropMax_m_int(self.as_raw_mut_FacemarkAAM_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_max_n(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Params_setPropMax_n_int(self.as_raw_mut_FacemarkAAM_Params(), val) };
+		crate::core::validity::debug_check("FacemarkAAM_Params", self.as_raw_mut_FacemarkAAM_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Params_setPropMax_n_int(self.as_raw_mut_FacemarkAAM_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_texture_max_m(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Params_setPropTexture_max_m_int(self.as_raw_mut_FacemarkAAM_Params(), val) };
+		crate::core::validity::debug_check("FacemarkAAM_Params", self.as_raw_mut_FacemarkAAM_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Params_setPropTexture_max_m_int(self.as_raw_mut_FacemarkAAM_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_scales(&mut self, mut val: core::Vector<f32>) {
-		let ret = unsafe { sys::cv_face_FacemarkAAM_Params_setPropScales_vector_float_(self.as_raw_mut_FacemarkAAM_Params(), val.as_raw_mut_VectorOff32()) };
+		crate::core::validity::debug_check("FacemarkAAM_Params", self.as_raw_mut_FacemarkAAM_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkAAM_Params_setPropScales_vector_float_(self.as_raw_mut_FacemarkAAM_Params(), val.as_raw_mut_VectorOff32())) };
 		ret
 	}
 	
 	/// \brief Read parameters from file, currently unused
 	#[inline]
 	fn read(&mut self, unnamed: &core::FileNode) -> Result<()> {
+		crate::core::validity::check("FacemarkAAM_Params", self.as_raw_mut_FacemarkAAM_Params())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FacemarkAAM_Params_read_const_FileNodeR(self.as_raw_mut_FacemarkAAM_Params(), unnamed.as_raw_FileNode(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FacemarkAAM_Params_read_const_FileNodeR(self.as_raw_mut_FacemarkAAM_Params(), unnamed.as_raw_FileNode(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1898,7 +2414,10 @@ opencv_type_boxed! { FacemarkAAM_Params }
 impl Drop for FacemarkAAM_Params {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FacemarkAAM_Params_delete(instance: *mut c_void); }
-		unsafe { cv_FacemarkAAM_Params_delete(self.as_raw_mut_FacemarkAAM_Params()) };
+		let ptr = self.as_raw_mut_FacemarkAAM_Params();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_FacemarkAAM_Params_delete(ptr) };
 	}
 }
 
@@ -1917,7 +2436,7 @@ impl FacemarkAAM_Params {
 	#[inline]
 	pub fn default() -> Result<crate::face::FacemarkAAM_Params> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FacemarkAAM_Params_Params(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FacemarkAAM_Params_Params(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::face::FacemarkAAM_Params::opencv_from_extern(ret) };
@@ -1949,10 +2468,11 @@ pub trait FacemarkKazemi: crate::face::Facemark + crate::face::FacemarkKazemiCon
 	/// * model_filename: "face_landmarks.dat"
 	#[inline]
 	fn training(&mut self, images: &mut core::Vector<core::Mat>, landmarks: &mut core::Vector<core::Vector<core::Point2f>>, configfile: &str, scale: core::Size, model_filename: &str) -> Result<bool> {
+		crate::core::validity::check("FacemarkKazemi", self.as_raw_mut_FacemarkKazemi())?;
 		extern_container_arg!(mut configfile);
 		extern_container_arg!(mut model_filename);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FacemarkKazemi_training_vector_Mat_R_vector_vector_Point2f__R_string_Size_string(self.as_raw_mut_FacemarkKazemi(), images.as_raw_mut_VectorOfMat(), landmarks.as_raw_mut_VectorOfVectorOfPoint2f(), configfile.opencv_as_extern_mut(), scale.opencv_as_extern(), model_filename.opencv_as_extern_mut(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FacemarkKazemi_training_vector_Mat_R_vector_vector_Point2f__R_string_Size_string(self.as_raw_mut_FacemarkKazemi(), images.as_raw_mut_VectorOfMat(), landmarks.as_raw_mut_VectorOfVectorOfPoint2f(), configfile.opencv_as_extern_mut(), scale.opencv_as_extern(), model_filename.opencv_as_extern_mut(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1961,10 +2481,11 @@ pub trait FacemarkKazemi: crate::face::Facemark + crate::face::FacemarkKazemiCon
 	/// set the custom face detector
 	#[inline]
 	fn set_face_detector(&mut self, f: Option<Box<dyn FnMut(*const c_void, *const c_void) -> bool + Send + Sync + 'static>>) -> Result<bool> {
+		crate::core::validity::check("FacemarkKazemi", self.as_raw_mut_FacemarkKazemi())?;
 		callback_arg!(f_trampoline(unnamed: *const c_void, unnamed_1: *const c_void, unnamed_2: *mut c_void) -> bool => unnamed_2 in callbacks => f(unnamed: *const c_void, unnamed_1: *const c_void) -> bool);
 		userdata_arg!(user_data in callbacks => f);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FacemarkKazemi_setFaceDetector_bool__X__const_cv__InputArrayR__const_cv__OutputArrayR__voidX__voidX(self.as_raw_mut_FacemarkKazemi(), f_trampoline, user_data, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FacemarkKazemi_setFaceDetector_bool__X__const_cv__InputArrayR__const_cv__OutputArrayR__voidX__voidX(self.as_raw_mut_FacemarkKazemi(), f_trampoline, user_data, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1973,10 +2494,11 @@ pub trait FacemarkKazemi: crate::face::Facemark + crate::face::FacemarkKazemiCon
 	/// get faces using the custom detector
 	#[inline]
 	fn get_faces(&mut self, image: &dyn core::ToInputArray, faces: &mut dyn core::ToOutputArray) -> Result<bool> {
+		crate::core::validity::check("FacemarkKazemi", self.as_raw_mut_FacemarkKazemi())?;
 		input_array_arg!(image);
 		output_array_arg!(faces);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FacemarkKazemi_getFaces_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_FacemarkKazemi(), image.as_raw__InputArray(), faces.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FacemarkKazemi_getFaces_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_FacemarkKazemi(), image.as_raw__InputArray(), faces.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1990,7 +2512,7 @@ impl dyn FacemarkKazemi + '_ {
 	#[inline]
 	pub fn create(parameters: &crate::face::FacemarkKazemi_Params) -> Result<core::Ptr<dyn crate::face::FacemarkKazemi>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FacemarkKazemi_create_const_ParamsR(parameters.as_raw_FacemarkKazemi_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FacemarkKazemi_create_const_ParamsR(parameters.as_raw_FacemarkKazemi_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::face::FacemarkKazemi>::opencv_from_extern(ret) };
@@ -2004,67 +2526,148 @@ pub trait FacemarkKazemi_ParamsTraitConst {
 	/// cascade_depth This stores the deapth of cascade used for training.
 	#[inline]
 	fn cascade_depth(&self) -> u32 {
-		let ret = unsafe { sys::cv_face_FacemarkKazemi_Params_getPropCascade_depth_const(self.as_raw_FacemarkKazemi_Params()) };
+		crate::core::validity::debug_check("FacemarkKazemi_Params", self.as_raw_FacemarkKazemi_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkKazemi_Params_getPropCascade_depth_const(self.as_raw_FacemarkKazemi_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [cascade_depth](FacemarkKazemi_ParamsTraitConst::cascade_depth), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_cascade_depth(&self) -> Result<u32> {
+		crate::core::validity::check("FacemarkKazemi_Params", self.as_raw_FacemarkKazemi_Params())?;
+		Ok(FacemarkKazemi_ParamsTraitConst::cascade_depth(self))
+	}
+	
 	/// tree_depth This stores the max height of the regression tree built.
 	#[inline]
 	fn tree_depth(&self) -> u32 {
-		let ret = unsafe { sys::cv_face_FacemarkKazemi_Params_getPropTree_depth_const(self.as_raw_FacemarkKazemi_Params()) };
+		crate::core::validity::debug_check("FacemarkKazemi_Params", self.as_raw_FacemarkKazemi_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkKazemi_Params_getPropTree_depth_const(self.as_raw_FacemarkKazemi_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [tree_depth](FacemarkKazemi_ParamsTraitConst::tree_depth), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_tree_depth(&self) -> Result<u32> {
+		crate::core::validity::check("FacemarkKazemi_Params", self.as_raw_FacemarkKazemi_Params())?;
+		Ok(FacemarkKazemi_ParamsTraitConst::tree_depth(self))
+	}
+	
 	/// num_trees_per_cascade_level This stores number of trees fit per cascade level.
 	#[inline]
 	fn num_trees_per_cascade_level(&self) -> u32 {
-		let ret = unsafe { sys::cv_face_FacemarkKazemi_Params_getPropNum_trees_per_cascade_level_const(self.as_raw_FacemarkKazemi_Params()) };
+		crate::core::validity::debug_check("FacemarkKazemi_Params", self.as_raw_FacemarkKazemi_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkKazemi_Params_getPropNum_trees_per_cascade_level_const(self.as_raw_FacemarkKazemi_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [num_trees_per_cascade_level](FacemarkKazemi_ParamsTraitConst::num_trees_per_cascade_level), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_num_trees_per_cascade_level(&self) -> Result<u32> {
+		crate::core::validity::check("FacemarkKazemi_Params", self.as_raw_FacemarkKazemi_Params())?;
+		Ok(FacemarkKazemi_ParamsTraitConst::num_trees_per_cascade_level(self))
+	}
+	
 	/// learning_rate stores the learning rate in gradient boosting, also referred as shrinkage.
 	#[inline]
 	fn learning_rate(&self) -> f32 {
-		let ret = unsafe { sys::cv_face_FacemarkKazemi_Params_getPropLearning_rate_const(self.as_raw_FacemarkKazemi_Params()) };
+		crate::core::validity::debug_check("FacemarkKazemi_Params", self.as_raw_FacemarkKazemi_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkKazemi_Params_getPropLearning_rate_const(self.as_raw_FacemarkKazemi_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [learning_rate](FacemarkKazemi_ParamsTraitConst::learning_rate), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_learning_rate(&self) -> Result<f32> {
+		crate::core::validity::check("FacemarkKazemi_Params", self.as_raw_FacemarkKazemi_Params())?;
+		Ok(FacemarkKazemi_ParamsTraitConst::learning_rate(self))
+	}
+	
 	/// oversampling_amount stores number of initialisations used to create training samples.
 	#[inline]
 	fn oversampling_amount(&self) -> u32 {
-		let ret = unsafe { sys::cv_face_FacemarkKazemi_Params_getPropOversampling_amount_const(self.as_raw_FacemarkKazemi_Params()) };
+		crate::core::validity::debug_check("FacemarkKazemi_Params", self.as_raw_FacemarkKazemi_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkKazemi_Params_getPropOversampling_amount_const(self.as_raw_FacemarkKazemi_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [oversampling_amount](FacemarkKazemi_ParamsTraitConst::oversampling_amount), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_oversampling_amount(&self) -> Result<u32> {
+		crate::core::validity::check("FacemarkKazemi_Params", self.as_raw_FacemarkKazemi_Params())?;
+		Ok(FacemarkKazemi_ParamsTraitConst::oversampling_amount(self))
+	}
+	
 	/// num_test_coordinates stores number of test coordinates.
 	#[inline]
 	fn num_test_coordinates(&self) -> u32 {
-		let ret = unsafe { sys::cv_face_FacemarkKazemi_Params_getPropNum_test_coordinates_const(self.as_raw_FacemarkKazemi_Params()) };
+		crate::core::validity::debug_check("FacemarkKazemi_Params", self.as_raw_FacemarkKazemi_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkKazemi_Params_getPropNum_test_coordinates_const(self.as_raw_FacemarkKazemi_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [num_test_coordinates](FacemarkKazemi_ParamsTraitConst::num_test_coordinates), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_num_test_coordinates(&self) -> Result<u32> {
+		crate::core::validity::check("FacemarkKazemi_Params", self.as_raw_FacemarkKazemi_Params())?;
+		Ok(FacemarkKazemi_ParamsTraitConst::num_test_coordinates(self))
+	}
+	
 	/// lambda stores a value to calculate probability of closeness of two coordinates.
 	#[inline]
 	fn lambda(&self) -> f32 {
-		let ret = unsafe { sys::cv_face_FacemarkKazemi_Params_getPropLambda_const(self.as_raw_FacemarkKazemi_Params()) };
+		crate::core::validity::debug_check("FacemarkKazemi_Params", self.as_raw_FacemarkKazemi_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkKazemi_Params_getPropLambda_const(self.as_raw_FacemarkKazemi_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [lambda](FacemarkKazemi_ParamsTraitConst::lambda), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_lambda(&self) -> Result<f32> {
+		crate::core::validity::check("FacemarkKazemi_Params", self.as_raw_FacemarkKazemi_Params())?;
+		Ok(FacemarkKazemi_ParamsTraitConst::lambda(self))
+	}
+	
 	/// num_test_splits stores number of random test splits generated.
 	#[inline]
 	fn num_test_splits(&self) -> u32 {
-		let ret = unsafe { sys::cv_face_FacemarkKazemi_Params_getPropNum_test_splits_const(self.as_raw_FacemarkKazemi_Params()) };
+		crate::core::validity::debug_check("FacemarkKazemi_Params", self.as_raw_FacemarkKazemi_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkKazemi_Params_getPropNum_test_splits_const(self.as_raw_FacemarkKazemi_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [num_test_splits](FacemarkKazemi_ParamsTraitConst::num_test_splits), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_num_test_splits(&self) -> Result<u32> {
+		crate::core::validity::check("FacemarkKazemi_Params", self.as_raw_FacemarkKazemi_Params())?;
+		Ok(FacemarkKazemi_ParamsTraitConst::num_test_splits(self))
+	}
+	
 	/// configfile stores the name of the file containing the values of training parameters
 	#[inline]
 	fn configfile(&self) -> String {
-		let ret = unsafe { sys::cv_face_FacemarkKazemi_Params_getPropConfigfile_const(self.as_raw_FacemarkKazemi_Params()) };
+		crate::core::validity::debug_check("FacemarkKazemi_Params", self.as_raw_FacemarkKazemi_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkKazemi_Params_getPropConfigfile_const(self.as_raw_FacemarkKazemi_Params())) };
 		let ret = unsafe { String::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [configfile](FacemarkKazemi_ParamsTraitConst::configfile), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_configfile(&self) -> Result<String> {
+		crate::core::validity::check("FacemarkKazemi_Params", self.as_raw_FacemarkKazemi_Params())?;
+		Ok(FacemarkKazemi_ParamsTraitConst::configfile(self))
+	}
+	
 }
 
 pub trait FacemarkKazemi_ParamsTrait: crate::face::FacemarkKazemi_ParamsTraitConst {
@@ -2073,64 +2676,73 @@ pub trait FacemarkKazemi_ParamsTrait: crate::face::FacemarkKazemi_ParamsTraitCon
 	/// cascade_depth This stores the deapth of cascade used for training.
 	#[inline]
 	fn set_cascade_depth(&mut self, val: u32) {
-		let ret = unsafe { sys::cv_face_FacemarkKazemi_Params_setPropCascade_depth_unsigned_long(self.as_raw_mut_FacemarkKazemi_Params(), val) };
+		crate::core::validity::debug_check("FacemarkKazemi_Params", self.as_raw_mut_FacemarkKazemi_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkKazemi_Params_setPropCascade_depth_unsigned_long(self.as_raw_mut_FacemarkKazemi_Params(), val)) };
 		ret
 	}
 	
 	/// tree_depth This stores the max height of the regression tree built.
 	#[inline]
 	fn set_tree_depth(&mut self, val: u32) {
-		let ret = unsafe { sys::cv_face_FacemarkKazemi_Params_setPropTree_depth_unsigned_long(self.as_raw_mut_FacemarkKazemi_Params(), val) };
+		crate::core::validity::debug_check("FacemarkKazemi_Params", self.as_raw_mut_FacemarkKazemi_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkKazemi_Params_setPropTree_depth_unsigned_long(self.as_raw_mut_FacemarkKazemi_Params(), val)) };
 		ret
 	}
 	
 	/// num_trees_per_cascade_level This stores number of trees fit per cascade level.
 	#[inline]
 	fn set_num_trees_per_cascade_level(&mut self, val: u32) {
-		let ret = unsafe { sys::cv_face_FacemarkKazemi_Params_setPropNum_trees_per_cascade_level_unsigned_long(self.as_raw_mut_FacemarkKazemi_Params(), val) };
+		crate::core::validity::debug_check("FacemarkKazemi_Params", self.as_raw_mut_FacemarkKazemi_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkKazemi_Params_setPropNum_trees_per_cascade_level_unsigned_long(self.as_raw_mut_FacemarkKazemi_Params(), val)) };
 		ret
 	}
 	
 	/// learning_rate stores the learning rate in gradient boosting, also referred as shrinkage.
 	#[inline]
 	fn set_learning_rate(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_face_FacemarkKazemi_Params_setPropLearning_rate_float(self.as_raw_mut_FacemarkKazemi_Params(), val) };
+		crate::core::validity::debug_check("FacemarkKazemi_Params", self.as_raw_mut_FacemarkKazemi_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkKazemi_Params_setPropLearning_rate_float(self.as_raw_mut_FacemarkKazemi_Params(), val)) };
 		ret
 	}
 	
 	/// oversampling_amount stores number of initialisations used to create training samples.
 	#[inline]
 	fn set_oversampling_amount(&mut self, val: u32) {
-		let ret = unsafe { sys::cv_face_FacemarkKazemi_Params_setPropOversampling_amount_unsigned_long(self.as_raw_mut_FacemarkKazemi_Params(), val) };
+		crate::core::validity::debug_check("FacemarkKazemi_Params", self.as_raw_mut_FacemarkKazemi_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkKazemi_Params_setPropOversampling_amount_unsigned_long(self.as_raw_mut_FacemarkKazemi_Params(), val)) };
 		ret
 	}
 	
 	/// num_test_coordinates stores number of test coordinates.
 	#[inline]
 	fn set_num_test_coordinates(&mut self, val: u32) {
-		let ret = unsafe { sys::cv_face_FacemarkKazemi_Params_setPropNum_test_coordinates_unsigned_long(self.as_raw_mut_FacemarkKazemi_Params(), val) };
+		crate::core::validity::debug_check("FacemarkKazemi_Params", self.as_raw_mut_FacemarkKazemi_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkKazemi_Params_setPropNum_test_coordinates_unsigned_long(self.as_raw_mut_FacemarkKazemi_Params(), val)) };
 		ret
 	}
 	
 	/// lambda stores a value to calculate probability of closeness of two coordinates.
 	#[inline]
 	fn set_lambda(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_face_FacemarkKazemi_Params_setPropLambda_float(self.as_raw_mut_FacemarkKazemi_Params(), val) };
+		crate::core::validity::debug_check("FacemarkKazemi_Params", self.as_raw_mut_FacemarkKazemi_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkKazemi_Params_setPropLambda_float(self.as_raw_mut_FacemarkKazemi_Params(), val)) };
 		ret
 	}
 	
 	/// num_test_splits stores number of random test splits generated.
 	#[inline]
 	fn set_num_test_splits(&mut self, val: u32) {
-		let ret = unsafe { sys::cv_face_FacemarkKazemi_Params_setPropNum_test_splits_unsigned_long(self.as_raw_mut_FacemarkKazemi_Params(), val) };
+		crate::core::validity::debug_check("FacemarkKazemi_Params", self.as_raw_mut_FacemarkKazemi_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkKazemi_Params_setPropNum_test_splits_unsigned_long(self.as_raw_mut_FacemarkKazemi_Params(), val)) };
 		ret
 	}
 	
 	/// configfile stores the name of the file containing the values of training parameters
 	#[inline]
 	fn set_configfile(&mut self, val: &str) {
+		crate::core::validity::debug_check("FacemarkKazemi_Params", self.as_raw_mut_FacemarkKazemi_Params());
 		extern_container_arg!(nofail mut val);
-		let ret = unsafe { sys::cv_face_FacemarkKazemi_Params_setPropConfigfile_String(self.as_raw_mut_FacemarkKazemi_Params(), val.opencv_as_extern_mut()) };
+		let ret = unsafe { ffi_call!(cv_face_FacemarkKazemi_Params_setPropConfigfile_String(self.as_raw_mut_FacemarkKazemi_Params(), val.opencv_as_extern_mut())) };
 		ret
 	}
 	
@@ -2145,7 +2757,10 @@ opencv_type_boxed! { FacemarkKazemi_Params }
 impl Drop for FacemarkKazemi_Params {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FacemarkKazemi_Params_delete(instance: *mut c_void); }
-		unsafe { cv_FacemarkKazemi_Params_delete(self.as_raw_mut_FacemarkKazemi_Params()) };
+		let ptr = self.as_raw_mut_FacemarkKazemi_Params();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_FacemarkKazemi_Params_delete(ptr) };
 	}
 }
 
@@ -2164,7 +2779,7 @@ impl FacemarkKazemi_Params {
 	#[inline]
 	pub fn default() -> Result<crate::face::FacemarkKazemi_Params> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FacemarkKazemi_Params_Params(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FacemarkKazemi_Params_Params(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::face::FacemarkKazemi_Params::opencv_from_extern(ret) };
@@ -2189,7 +2804,7 @@ impl dyn FacemarkLBF + '_ {
 	#[inline]
 	pub fn create(parameters: &crate::face::FacemarkLBF_Params) -> Result<core::Ptr<dyn crate::face::FacemarkLBF>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FacemarkLBF_create_const_ParamsR(parameters.as_raw_FacemarkLBF_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FacemarkLBF_create_const_ParamsR(parameters.as_raw_FacemarkLBF_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::face::FacemarkLBF>::opencv_from_extern(ret) };
@@ -2202,106 +2817,242 @@ pub trait FacemarkLBF_ParamsTraitConst {
 
 	#[inline]
 	fn shape_offset(&self) -> f64 {
-		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_getPropShape_offset_const(self.as_raw_FacemarkLBF_Params()) };
+		crate::core::validity::debug_check("FacemarkLBF_Params", self.as_raw_FacemarkLBF_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkLBF_Params_getPropShape_offset_const(self.as_raw_FacemarkLBF_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [shape_offset](FacemarkLBF_ParamsTraitConst::shape_offset), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_shape_offset(&self) -> Result<f64> {
+		crate::core::validity::check("FacemarkLBF_Params", self.as_raw_FacemarkLBF_Params())?;
+		Ok(FacemarkLBF_ParamsTraitConst::shape_offset(self))
+	}
+	
 	#[inline]
 	fn cascade_face(&self) -> String {
-		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_getPropCascade_face_const(self.as_raw_FacemarkLBF_Params()) };
+		crate::core::validity::debug_check("FacemarkLBF_Params", self.as_raw_FacemarkLBF_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkLBF_Params_getPropCascade_face_const(self.as_raw_FacemarkLBF_Params())) };
 		let ret = unsafe { String::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [cascade_face](FacemarkLBF_ParamsTraitConst::cascade_face), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_cascade_face(&self) -> Result<String> {
+		crate::core::validity::check("FacemarkLBF_Params", self.as_raw_FacemarkLBF_Params())?;
+		Ok(FacemarkLBF_ParamsTraitConst::cascade_face(self))
+	}
+	
 	#[inline]
 	fn verbose(&self) -> bool {
-		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_getPropVerbose_const(self.as_raw_FacemarkLBF_Params()) };
+		crate::core::validity::debug_check("FacemarkLBF_Params", self.as_raw_FacemarkLBF_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkLBF_Params_getPropVerbose_const(self.as_raw_FacemarkLBF_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [verbose](FacemarkLBF_ParamsTraitConst::verbose), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_verbose(&self) -> Result<bool> {
+		crate::core::validity::check("FacemarkLBF_Params", self.as_raw_FacemarkLBF_Params())?;
+		Ok(FacemarkLBF_ParamsTraitConst::verbose(self))
+	}
+	
 	#[inline]
 	fn n_landmarks(&self) -> i32 {
-		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_getPropN_landmarks_const(self.as_raw_FacemarkLBF_Params()) };
+		crate::core::validity::debug_check("FacemarkLBF_Params", self.as_raw_FacemarkLBF_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkLBF_Params_getPropN_landmarks_const(self.as_raw_FacemarkLBF_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [n_landmarks](FacemarkLBF_ParamsTraitConst::n_landmarks), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_n_landmarks(&self) -> Result<i32> {
+		crate::core::validity::check("FacemarkLBF_Params", self.as_raw_FacemarkLBF_Params())?;
+		Ok(FacemarkLBF_ParamsTraitConst::n_landmarks(self))
+	}
+	
 	#[inline]
 	fn init_shape_n(&self) -> i32 {
-		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_getPropInitShape_n_const(self.as_raw_FacemarkLBF_Params()) };
+		crate::core::validity::debug_check("FacemarkLBF_Params", self.as_raw_FacemarkLBF_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkLBF_Params_getPropInitShape_n_const(self.as_raw_FacemarkLBF_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [init_shape_n](FacemarkLBF_ParamsTraitConst::init_shape_n), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_init_shape_n(&self) -> Result<i32> {
+		crate::core::validity::check("FacemarkLBF_Params", self.as_raw_FacemarkLBF_Params())?;
+		Ok(FacemarkLBF_ParamsTraitConst::init_shape_n(self))
+	}
+	
 	#[inline]
 	fn stages_n(&self) -> i32 {
-		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_getPropStages_n_const(self.as_raw_FacemarkLBF_Params()) };
+		crate::core::validity::debug_check("FacemarkLBF_Params", self.as_raw_FacemarkLBF_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkLBF_Params_getPropStages_n_const(self.as_raw_FacemarkLBF_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [stages_n](FacemarkLBF_ParamsTraitConst::stages_n), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_stages_n(&self) -> Result<i32> {
+		crate::core::validity::check("FacemarkLBF_Params", self.as_raw_FacemarkLBF_Params())?;
+		Ok(FacemarkLBF_ParamsTraitConst::stages_n(self))
+	}
+	
 	#[inline]
 	fn tree_n(&self) -> i32 {
-		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_getPropTree_n_const(self.as_raw_FacemarkLBF_Params()) };
+		crate::core::validity::debug_check("FacemarkLBF_Params", self.as_raw_FacemarkLBF_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkLBF_Params_getPropTree_n_const(self.as_raw_FacemarkLBF_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [tree_n](FacemarkLBF_ParamsTraitConst::tree_n), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_tree_n(&self) -> Result<i32> {
+		crate::core::validity::check("FacemarkLBF_Params", self.as_raw_FacemarkLBF_Params())?;
+		Ok(FacemarkLBF_ParamsTraitConst::tree_n(self))
+	}
+	
 	#[inline]
 	fn tree_depth(&self) -> i32 {
-		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_getPropTree_depth_const(self.as_raw_FacemarkLBF_Params()) };
+		crate::core::validity::debug_check("FacemarkLBF_Params", self.as_raw_FacemarkLBF_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkLBF_Params_getPropTree_depth_const(self.as_raw_FacemarkLBF_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [tree_depth](FacemarkLBF_ParamsTraitConst::tree_depth), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_tree_depth(&self) -> Result<i32> {
+		crate::core::validity::check("FacemarkLBF_Params", self.as_raw_FacemarkLBF_Params())?;
+		Ok(FacemarkLBF_ParamsTraitConst::tree_depth(self))
+	}
+	
 	#[inline]
 	fn bagging_overlap(&self) -> f64 {
-		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_getPropBagging_overlap_const(self.as_raw_FacemarkLBF_Params()) };
+		crate::core::validity::debug_check("FacemarkLBF_Params", self.as_raw_FacemarkLBF_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkLBF_Params_getPropBagging_overlap_const(self.as_raw_FacemarkLBF_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [bagging_overlap](FacemarkLBF_ParamsTraitConst::bagging_overlap), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_bagging_overlap(&self) -> Result<f64> {
+		crate::core::validity::check("FacemarkLBF_Params", self.as_raw_FacemarkLBF_Params())?;
+		Ok(FacemarkLBF_ParamsTraitConst::bagging_overlap(self))
+	}
+	
 	#[inline]
 	fn model_filename(&self) -> String {
-		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_getPropModel_filename_const(self.as_raw_FacemarkLBF_Params()) };
+		crate::core::validity::debug_check("FacemarkLBF_Params", self.as_raw_FacemarkLBF_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkLBF_Params_getPropModel_filename_const(self.as_raw_FacemarkLBF_Params())) };
 		let ret = unsafe { String::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [model_filename](FacemarkLBF_ParamsTraitConst::model_filename), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_model_filename(&self) -> Result<String> {
+		crate::core::validity::check("FacemarkLBF_Params", self.as_raw_FacemarkLBF_Params())?;
+		Ok(FacemarkLBF_ParamsTraitConst::model_filename(self))
+	}
+	
 	/// flag to save the trained model or not
 	#[inline]
 	fn save_model(&self) -> bool {
-		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_getPropSave_model_const(self.as_raw_FacemarkLBF_Params()) };
+		crate::core::validity::debug_check("FacemarkLBF_Params", self.as_raw_FacemarkLBF_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkLBF_Params_getPropSave_model_const(self.as_raw_FacemarkLBF_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [save_model](FacemarkLBF_ParamsTraitConst::save_model), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_save_model(&self) -> Result<bool> {
+		crate::core::validity::check("FacemarkLBF_Params", self.as_raw_FacemarkLBF_Params())?;
+		Ok(FacemarkLBF_ParamsTraitConst::save_model(self))
+	}
+	
 	/// seed for shuffling the training data
 	#[inline]
 	fn seed(&self) -> u32 {
-		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_getPropSeed_const(self.as_raw_FacemarkLBF_Params()) };
+		crate::core::validity::debug_check("FacemarkLBF_Params", self.as_raw_FacemarkLBF_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkLBF_Params_getPropSeed_const(self.as_raw_FacemarkLBF_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [seed](FacemarkLBF_ParamsTraitConst::seed), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_seed(&self) -> Result<u32> {
+		crate::core::validity::check("FacemarkLBF_Params", self.as_raw_FacemarkLBF_Params())?;
+		Ok(FacemarkLBF_ParamsTraitConst::seed(self))
+	}
+	
 	#[inline]
 	fn feats_m(&self) -> core::Vector<i32> {
-		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_getPropFeats_m_const(self.as_raw_FacemarkLBF_Params()) };
+		crate::core::validity::debug_check("FacemarkLBF_Params", self.as_raw_FacemarkLBF_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkLBF_Params_getPropFeats_m_const(self.as_raw_FacemarkLBF_Params())) };
 		let ret = unsafe { core::Vector::<i32>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [feats_m](FacemarkLBF_ParamsTraitConst::feats_m), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_feats_m(&self) -> Result<core::Vector<i32>> {
+		crate::core::validity::check("FacemarkLBF_Params", self.as_raw_FacemarkLBF_Params())?;
+		Ok(FacemarkLBF_ParamsTraitConst::feats_m(self))
+	}
+	
 	#[inline]
 	fn radius_m(&self) -> core::Vector<f64> {
-		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_getPropRadius_m_const(self.as_raw_FacemarkLBF_Params()) };
+		crate::core::validity::debug_check("FacemarkLBF_Params", self.as_raw_FacemarkLBF_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkLBF_Params_getPropRadius_m_const(self.as_raw_FacemarkLBF_Params())) };
 		let ret = unsafe { core::Vector::<f64>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [radius_m](FacemarkLBF_ParamsTraitConst::radius_m), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_radius_m(&self) -> Result<core::Vector<f64>> {
+		crate::core::validity::check("FacemarkLBF_Params", self.as_raw_FacemarkLBF_Params())?;
+		Ok(FacemarkLBF_ParamsTraitConst::radius_m(self))
+	}
+	
 	#[inline]
 	fn detect_roi(&self) -> core::Rect {
+		crate::core::validity::debug_check("FacemarkLBF_Params", self.as_raw_FacemarkLBF_Params());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FacemarkLBF_Params_getPropDetectROI_const(self.as_raw_FacemarkLBF_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FacemarkLBF_Params_getPropDetectROI_const(self.as_raw_FacemarkLBF_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [detect_roi](FacemarkLBF_ParamsTraitConst::detect_roi), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_detect_roi(&self) -> Result<core::Rect> {
+		crate::core::validity::check("FacemarkLBF_Params", self.as_raw_FacemarkLBF_Params())?;
+		Ok(FacemarkLBF_ParamsTraitConst::detect_roi(self))
+	}
+	
 	#[inline]
 	fn write(&self, unnamed: &mut core::FileStorage) -> Result<()> {
+		crate::core::validity::check("FacemarkLBF_Params", self.as_raw_FacemarkLBF_Params())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FacemarkLBF_Params_write_const_FileStorageR(self.as_raw_FacemarkLBF_Params(), unnamed.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FacemarkLBF_Params_write_const_FileStorageR(self.as_raw_FacemarkLBF_Params(), unnamed.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2314,102 +3065,118 @@ pub trait FacemarkLBF_ParamsTrait: crate::face::FacemarkLBF_ParamsTraitConst {
 
 	#[inline]
 	fn set_shape_offset(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_setPropShape_offset_double(self.as_raw_mut_FacemarkLBF_Params(), val) };
+		crate::core::validity::debug_check("FacemarkLBF_Params", self.as_raw_mut_FacemarkLBF_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkLBF_Params_setPropShape_offset_double(self.as_raw_mut_FacemarkLBF_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_cascade_face(&mut self, val: &str) {
+		crate::core::validity::debug_check("FacemarkLBF_Params", self.as_raw_mut_FacemarkLBF_Params());
 		extern_container_arg!(nofail mut val);
-		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_setPropCascade_face_String(self.as_raw_mut_FacemarkLBF_Params(), val.opencv_as_extern_mut()) };
+		let ret = unsafe { ffi_call!(cv_face_FacemarkLBF_Params_setPropCascade_face_String(self.as_raw_mut_FacemarkLBF_Params(), val.opencv_as_extern_mut())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_verbose(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_setPropVerbose_bool(self.as_raw_mut_FacemarkLBF_Params(), val) };
+		crate::core::validity::debug_check("FacemarkLBF_Params", self.as_raw_mut_FacemarkLBF_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkLBF_Params_setPropVerbose_bool(self.as_raw_mut_FacemarkLBF_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_n_landmarks(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_setPropN_landmarks_int(self.as_raw_mut_FacemarkLBF_Params(), val) };
+		crate::core::validity::debug_check("FacemarkLBF_Params", self.as_raw_mut_FacemarkLBF_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkLBF_Params_setPropN_landmarks_int(self.as_raw_mut_FacemarkLBF_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_init_shape_n(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_setPropInitShape_n_int(self.as_raw_mut_FacemarkLBF_Params(), val) };
+		crate::core::validity::debug_check("FacemarkLBF_Params", self.as_raw_mut_FacemarkLBF_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkLBF_Params_setPropInitShape_n_int(self.as_raw_mut_FacemarkLBF_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_stages_n(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_setPropStages_n_int(self.as_raw_mut_FacemarkLBF_Params(), val) };
+		crate::core::validity::debug_check("FacemarkLBF_Params", self.as_raw_mut_FacemarkLBF_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkLBF_Params_setPropStages_n_int(self.as_raw_mut_FacemarkLBF_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_tree_n(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_setPropTree_n_int(self.as_raw_mut_FacemarkLBF_Params(), val) };
+		crate::core::validity::debug_check("FacemarkLBF_Params", self.as_raw_mut_FacemarkLBF_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkLBF_Params_setPropTree_n_int(self.as_raw_mut_FacemarkLBF_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_tree_depth(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_setPropTree_depth_int(self.as_raw_mut_FacemarkLBF_Params(), val) };
+		crate::core::validity::debug_check("FacemarkLBF_Params", self.as_raw_mut_FacemarkLBF_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkLBF_Params_setPropTree_depth_int(self.as_raw_mut_FacemarkLBF_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_bagging_overlap(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_setPropBagging_overlap_double(self.as_raw_mut_FacemarkLBF_Params(), val) };
+		crate::core::validity::debug_check("FacemarkLBF_Params", self.as_raw_mut_FacemarkLBF_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkLBF_Params_setPropBagging_overlap_double(self.as_raw_mut_FacemarkLBF_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_model_filename(&mut self, val: &str) {
+		crate::core::validity::debug_check("FacemarkLBF_Params", self.as_raw_mut_FacemarkLBF_Params());
 		extern_container_arg!(nofail mut val);
-		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_setPropModel_filename_string(self.as_raw_mut_FacemarkLBF_Params(), val.opencv_as_extern_mut()) };
+		let ret = unsafe { ffi_call!(cv_face_FacemarkLBF_Params_setPropModel_filename_string(self.as_raw_mut_FacemarkLBF_Params(), val.opencv_as_extern_mut())) };
 		ret
 	}
 	
 	/// flag to save the trained model or not
 	#[inline]
 	fn set_save_model(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_setPropSave_model_bool(self.as_raw_mut_FacemarkLBF_Params(), val) };
+		crate::core::validity::debug_check("FacemarkLBF_Params", self.as_raw_mut_FacemarkLBF_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkLBF_Params_setPropSave_model_bool(self.as_raw_mut_FacemarkLBF_Params(), val)) };
 		ret
 	}
 	
 	/// seed for shuffling the training data
 	#[inline]
 	fn set_seed(&mut self, val: u32) {
-		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_setPropSeed_unsigned_int(self.as_raw_mut_FacemarkLBF_Params(), val) };
+		crate::core::validity::debug_check("FacemarkLBF_Params", self.as_raw_mut_FacemarkLBF_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkLBF_Params_setPropSeed_unsigned_int(self.as_raw_mut_FacemarkLBF_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_feats_m(&mut self, mut val: core::Vector<i32>) {
-		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_setPropFeats_m_vector_int_(self.as_raw_mut_FacemarkLBF_Params(), val.as_raw_mut_VectorOfi32()) };
+		crate::core::validity::debug_check("FacemarkLBF_Params", self.as_raw_mut_FacemarkLBF_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkLBF_Params_setPropFeats_m_vector_int_(self.as_raw_mut_FacemarkLBF_Params(), val.as_raw_mut_VectorOfi32())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_radius_m(&mut self, mut val: core::Vector<f64>) {
-		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_setPropRadius_m_vector_double_(self.as_raw_mut_FacemarkLBF_Params(), val.as_raw_mut_VectorOff64()) };
+		crate::core::validity::debug_check("FacemarkLBF_Params", self.as_raw_mut_FacemarkLBF_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkLBF_Params_setPropRadius_m_vector_double_(self.as_raw_mut_FacemarkLBF_Params(), val.as_raw_mut_VectorOff64())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_detect_roi(&mut self, val: core::Rect) {
-		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_setPropDetectROI_Rect(self.as_raw_mut_FacemarkLBF_Params(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("FacemarkLBF_Params", self.as_raw_mut_FacemarkLBF_Params());
+		let ret = unsafe { ffi_call!(cv_face_FacemarkLBF_Params_setPropDetectROI_Rect(self.as_raw_mut_FacemarkLBF_Params(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	#[inline]
 	fn read(&mut self, unnamed: &core::FileNode) -> Result<()> {
+		crate::core::validity::check("FacemarkLBF_Params", self.as_raw_mut_FacemarkLBF_Params())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FacemarkLBF_Params_read_const_FileNodeR(self.as_raw_mut_FacemarkLBF_Params(), unnamed.as_raw_FileNode(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FacemarkLBF_Params_read_const_FileNodeR(self.as_raw_mut_FacemarkLBF_Params(), unnamed.as_raw_FileNode(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2426,7 +3193,10 @@ opencv_type_boxed! { FacemarkLBF_Params }
 impl Drop for FacemarkLBF_Params {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FacemarkLBF_Params_delete(instance: *mut c_void); }
-		unsafe { cv_FacemarkLBF_Params_delete(self.as_raw_mut_FacemarkLBF_Params()) };
+		let ptr = self.as_raw_mut_FacemarkLBF_Params();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_FacemarkLBF_Params_delete(ptr) };
 	}
 }
 
@@ -2445,7 +3215,7 @@ impl FacemarkLBF_Params {
 	#[inline]
 	pub fn default() -> Result<crate::face::FacemarkLBF_Params> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FacemarkLBF_Params_Params(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FacemarkLBF_Params_Params(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::face::FacemarkLBF_Params::opencv_from_extern(ret) };
@@ -2456,7 +3226,7 @@ impl FacemarkLBF_Params {
 
 /// Abstract base class for trainable facemark models
 /// 
-/// To utilize this API in your program, please take a look at the @ref tutorial_table_of_content_facemark
+/// To utilize this API in your program, please take a look at the tutorial_table_of_content_facemark
 /// ### Description
 /// 
 /// The AAM and LBF facemark models in OpenCV are derived from the abstract base class FacemarkTrain, which
@@ -2536,10 +3306,11 @@ pub trait FacemarkTrain: crate::face::Facemark + crate::face::FacemarkTrainConst
 	/// 
 	#[inline]
 	fn add_training_sample(&mut self, image: &dyn core::ToInputArray, landmarks: &dyn core::ToInputArray) -> Result<bool> {
+		crate::core::validity::check("FacemarkTrain", self.as_raw_mut_FacemarkTrain())?;
 		input_array_arg!(image);
 		input_array_arg!(landmarks);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FacemarkTrain_addTrainingSample_const__InputArrayR_const__InputArrayR(self.as_raw_mut_FacemarkTrain(), image.as_raw__InputArray(), landmarks.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FacemarkTrain_addTrainingSample_const__InputArrayR_const__InputArrayR(self.as_raw_mut_FacemarkTrain(), image.as_raw__InputArray(), landmarks.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2568,8 +3339,9 @@ pub trait FacemarkTrain: crate::face::Facemark + crate::face::FacemarkTrainConst
 	/// * parameters: 0
 	#[inline]
 	unsafe fn training(&mut self, parameters: *mut c_void) -> Result<()> {
+		crate::core::validity::check("FacemarkTrain", self.as_raw_mut_FacemarkTrain())?;
 		return_send!(via ocvrs_return);
-		{ sys::cv_face_FacemarkTrain_training_voidX(self.as_raw_mut_FacemarkTrain(), parameters, ocvrs_return.as_mut_ptr()) };
+		{ ffi_call!(cv_face_FacemarkTrain_training_voidX(self.as_raw_mut_FacemarkTrain(), parameters, ocvrs_return.as_mut_ptr())) };
 		return_receive!(ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2603,10 +3375,11 @@ pub trait FacemarkTrain: crate::face::Facemark + crate::face::FacemarkTrainConst
 	/// * user_data: 0
 	#[inline]
 	fn set_face_detector(&mut self, detector: crate::face::FN_FaceDetector) -> Result<bool> {
+		crate::core::validity::check("FacemarkTrain", self.as_raw_mut_FacemarkTrain())?;
 		callback_arg!(detector_trampoline(unnamed: *const c_void, unnamed_1: *const c_void, user_data: *mut c_void) -> bool => user_data in callbacks => detector(unnamed: *const c_void, unnamed_1: *const c_void) -> bool);
 		userdata_arg!(user_data in callbacks => detector);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FacemarkTrain_setFaceDetector_FN_FaceDetector_voidX(self.as_raw_mut_FacemarkTrain(), detector_trampoline, user_data, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FacemarkTrain_setFaceDetector_FN_FaceDetector_voidX(self.as_raw_mut_FacemarkTrain(), detector_trampoline, user_data, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2630,10 +3403,11 @@ pub trait FacemarkTrain: crate::face::Facemark + crate::face::FacemarkTrainConst
 	/// 
 	#[inline]
 	fn get_faces(&mut self, image: &dyn core::ToInputArray, faces: &mut dyn core::ToOutputArray) -> Result<bool> {
+		crate::core::validity::check("FacemarkTrain", self.as_raw_mut_FacemarkTrain())?;
 		input_array_arg!(image);
 		output_array_arg!(faces);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FacemarkTrain_getFaces_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_FacemarkTrain(), image.as_raw__InputArray(), faces.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FacemarkTrain_getFaces_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_FacemarkTrain(), image.as_raw__InputArray(), faces.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2661,8 +3435,9 @@ pub trait FacemarkTrain: crate::face::Facemark + crate::face::FacemarkTrainConst
 	/// * items: 0
 	#[inline]
 	unsafe fn get_data(&mut self, items: *mut c_void) -> Result<bool> {
+		crate::core::validity::check("FacemarkTrain", self.as_raw_mut_FacemarkTrain())?;
 		return_send!(via ocvrs_return);
-		{ sys::cv_face_FacemarkTrain_getData_voidX(self.as_raw_mut_FacemarkTrain(), items, ocvrs_return.as_mut_ptr()) };
+		{ ffi_call!(cv_face_FacemarkTrain_getData_voidX(self.as_raw_mut_FacemarkTrain(), items, ocvrs_return.as_mut_ptr())) };
 		return_receive!(ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2717,7 +3492,7 @@ impl dyn FisherFaceRecognizer + '_ {
 	#[inline]
 	pub fn create(num_components: i32, threshold: f64) -> Result<core::Ptr<dyn crate::face::FisherFaceRecognizer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FisherFaceRecognizer_create_int_double(num_components, threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_FisherFaceRecognizer_create_int_double(num_components, threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::face::FisherFaceRecognizer>::opencv_from_extern(ret) };
@@ -2729,55 +3504,60 @@ pub trait LBPHFaceRecognizerConst: crate::face::FaceRecognizerConst {
 	fn as_raw_LBPHFaceRecognizer(&self) -> *const c_void;
 
 	/// ## See also
-	/// setGridX
+	/// `set_grid_x`
 	#[inline]
 	fn get_grid_x(&self) -> Result<i32> {
+		crate::core::validity::check("LBPHFaceRecognizer", self.as_raw_LBPHFaceRecognizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_LBPHFaceRecognizer_getGridX_const(self.as_raw_LBPHFaceRecognizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_LBPHFaceRecognizer_getGridX_const(self.as_raw_LBPHFaceRecognizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setGridY
+	/// `set_grid_y`
 	#[inline]
 	fn get_grid_y(&self) -> Result<i32> {
+		crate::core::validity::check("LBPHFaceRecognizer", self.as_raw_LBPHFaceRecognizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_LBPHFaceRecognizer_getGridY_const(self.as_raw_LBPHFaceRecognizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_LBPHFaceRecognizer_getGridY_const(self.as_raw_LBPHFaceRecognizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setRadius
+	/// `set_radius`
 	#[inline]
 	fn get_radius(&self) -> Result<i32> {
+		crate::core::validity::check("LBPHFaceRecognizer", self.as_raw_LBPHFaceRecognizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_LBPHFaceRecognizer_getRadius_const(self.as_raw_LBPHFaceRecognizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_LBPHFaceRecognizer_getRadius_const(self.as_raw_LBPHFaceRecognizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setNeighbors
+	/// `set_neighbors`
 	#[inline]
 	fn get_neighbors(&self) -> Result<i32> {
+		crate::core::validity::check("LBPHFaceRecognizer", self.as_raw_LBPHFaceRecognizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_LBPHFaceRecognizer_getNeighbors_const(self.as_raw_LBPHFaceRecognizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_LBPHFaceRecognizer_getNeighbors_const(self.as_raw_LBPHFaceRecognizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setThreshold
+	/// `set_threshold`
 	#[inline]
 	fn get_threshold(&self) -> Result<f64> {
+		crate::core::validity::check("LBPHFaceRecognizer", self.as_raw_LBPHFaceRecognizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_LBPHFaceRecognizer_getThreshold_const(self.as_raw_LBPHFaceRecognizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_LBPHFaceRecognizer_getThreshold_const(self.as_raw_LBPHFaceRecognizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2785,8 +3565,9 @@ pub trait LBPHFaceRecognizerConst: crate::face::FaceRecognizerConst {
 	
 	#[inline]
 	fn get_histograms(&self) -> Result<core::Vector<core::Mat>> {
+		crate::core::validity::check("LBPHFaceRecognizer", self.as_raw_LBPHFaceRecognizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_LBPHFaceRecognizer_getHistograms_const(self.as_raw_LBPHFaceRecognizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_LBPHFaceRecognizer_getHistograms_const(self.as_raw_LBPHFaceRecognizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<core::Mat>::opencv_from_extern(ret) };
@@ -2795,8 +3576,9 @@ pub trait LBPHFaceRecognizerConst: crate::face::FaceRecognizerConst {
 	
 	#[inline]
 	fn get_labels(&self) -> Result<core::Mat> {
+		crate::core::validity::check("LBPHFaceRecognizer", self.as_raw_LBPHFaceRecognizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_LBPHFaceRecognizer_getLabels_const(self.as_raw_LBPHFaceRecognizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_LBPHFaceRecognizer_getLabels_const(self.as_raw_LBPHFaceRecognizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -2812,8 +3594,9 @@ pub trait LBPHFaceRecognizer: crate::face::FaceRecognizer + crate::face::LBPHFac
 	/// setGridX getGridX
 	#[inline]
 	fn set_grid_x(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("LBPHFaceRecognizer", self.as_raw_mut_LBPHFaceRecognizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_LBPHFaceRecognizer_setGridX_int(self.as_raw_mut_LBPHFaceRecognizer(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_LBPHFaceRecognizer_setGridX_int(self.as_raw_mut_LBPHFaceRecognizer(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2823,8 +3606,9 @@ pub trait LBPHFaceRecognizer: crate::face::FaceRecognizer + crate::face::LBPHFac
 	/// setGridY getGridY
 	#[inline]
 	fn set_grid_y(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("LBPHFaceRecognizer", self.as_raw_mut_LBPHFaceRecognizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_LBPHFaceRecognizer_setGridY_int(self.as_raw_mut_LBPHFaceRecognizer(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_LBPHFaceRecognizer_setGridY_int(self.as_raw_mut_LBPHFaceRecognizer(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2834,8 +3618,9 @@ pub trait LBPHFaceRecognizer: crate::face::FaceRecognizer + crate::face::LBPHFac
 	/// setRadius getRadius
 	#[inline]
 	fn set_radius(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("LBPHFaceRecognizer", self.as_raw_mut_LBPHFaceRecognizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_LBPHFaceRecognizer_setRadius_int(self.as_raw_mut_LBPHFaceRecognizer(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_LBPHFaceRecognizer_setRadius_int(self.as_raw_mut_LBPHFaceRecognizer(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2845,8 +3630,9 @@ pub trait LBPHFaceRecognizer: crate::face::FaceRecognizer + crate::face::LBPHFac
 	/// setNeighbors getNeighbors
 	#[inline]
 	fn set_neighbors(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("LBPHFaceRecognizer", self.as_raw_mut_LBPHFaceRecognizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_LBPHFaceRecognizer_setNeighbors_int(self.as_raw_mut_LBPHFaceRecognizer(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_LBPHFaceRecognizer_setNeighbors_int(self.as_raw_mut_LBPHFaceRecognizer(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2856,8 +3642,9 @@ pub trait LBPHFaceRecognizer: crate::face::FaceRecognizer + crate::face::LBPHFac
 	/// setThreshold getThreshold
 	#[inline]
 	fn set_threshold(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("LBPHFaceRecognizer", self.as_raw_mut_LBPHFaceRecognizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_LBPHFaceRecognizer_setThreshold_double(self.as_raw_mut_LBPHFaceRecognizer(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_LBPHFaceRecognizer_setThreshold_double(self.as_raw_mut_LBPHFaceRecognizer(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2907,7 +3694,7 @@ impl dyn LBPHFaceRecognizer + '_ {
 	#[inline]
 	pub fn create(radius: i32, neighbors: i32, grid_x: i32, grid_y: i32, threshold: f64) -> Result<core::Ptr<dyn crate::face::LBPHFaceRecognizer>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_LBPHFaceRecognizer_create_int_int_int_int_double(radius, neighbors, grid_x, grid_y, threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_LBPHFaceRecognizer_create_int_int_int_int_double(radius, neighbors, grid_x, grid_y, threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::face::LBPHFaceRecognizer>::opencv_from_extern(ret) };
@@ -2915,6 +3702,58 @@ impl dyn LBPHFaceRecognizer + '_ {
 	}
 	
 }
+
+/// Builder for `<dyn LBPHFaceRecognizer>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct LBPHFaceRecognizer_CreateBuilder {
+	radius: i32,
+	neighbors: i32,
+	grid_x: i32,
+	grid_y: i32,
+	threshold: f64,
+}
+
+impl LBPHFaceRecognizer_CreateBuilder {
+	#[inline]
+	pub fn new(threshold: f64) -> Self {
+		Self { radius: 1, neighbors: 8, grid_x: 8, grid_y: 8, threshold }
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_radius(mut self, radius: i32) -> Self {
+		self.radius = radius;
+		self
+	}
+	
+	/// C++ default: `8`
+	#[inline]
+	pub fn with_neighbors(mut self, neighbors: i32) -> Self {
+		self.neighbors = neighbors;
+		self
+	}
+	
+	/// C++ default: `8`
+	#[inline]
+	pub fn with_grid_x(mut self, grid_x: i32) -> Self {
+		self.grid_x = grid_x;
+		self
+	}
+	
+	/// C++ default: `8`
+	#[inline]
+	pub fn with_grid_y(mut self, grid_y: i32) -> Self {
+		self.grid_y = grid_y;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::face::LBPHFaceRecognizer>> {
+		<dyn LBPHFaceRecognizer>::create(self.radius, self.neighbors, self.grid_x, self.grid_y, self.threshold)
+	}
+}
 /// Minimum Average Correlation Energy Filter
 ///    useful for authentication with (cancellable) biometrical features.
 ///    (does not need many positives to train (10-50), and no negatives at all, also robust to noise/salting)
@@ -2975,9 +3814,10 @@ pub trait MACEConst: core::AlgorithmTraitConst {
 	/// * query: a Mat with query image
 	#[inline]
 	fn same(&self, query: &dyn core::ToInputArray) -> Result<bool> {
+		crate::core::validity::check("MACE", self.as_raw_MACE())?;
 		input_array_arg!(query);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_MACE_same_const_const__InputArrayR(self.as_raw_MACE(), query.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_MACE_same_const_const__InputArrayR(self.as_raw_MACE(), query.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2993,9 +3833,10 @@ pub trait MACE: core::AlgorithmTrait + crate::face::MACEConst {
 	/// * passphrase: a crc64 random seed will get generated from this
 	#[inline]
 	fn salt(&mut self, passphrase: &str) -> Result<()> {
+		crate::core::validity::check("MACE", self.as_raw_mut_MACE())?;
 		extern_container_arg!(passphrase);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_MACE_salt_const_StringR(self.as_raw_mut_MACE(), passphrase.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_MACE_salt_const_StringR(self.as_raw_mut_MACE(), passphrase.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3008,9 +3849,10 @@ pub trait MACE: core::AlgorithmTrait + crate::face::MACEConst {
 	/// * images: a vector<Mat> with the train images
 	#[inline]
 	fn train(&mut self, images: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("MACE", self.as_raw_mut_MACE())?;
 		input_array_arg!(images);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_MACE_train_const__InputArrayR(self.as_raw_mut_MACE(), images.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_MACE_train_const__InputArrayR(self.as_raw_mut_MACE(), images.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3031,7 +3873,7 @@ impl dyn MACE + '_ {
 		extern_container_arg!(filename);
 		extern_container_arg!(objname);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_MACE_load_const_StringR_const_StringR(filename.opencv_as_extern(), objname.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_MACE_load_const_StringR_const_StringR(filename.opencv_as_extern(), objname.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::face::MACE>::opencv_from_extern(ret) };
@@ -3047,7 +3889,7 @@ impl dyn MACE + '_ {
 	#[inline]
 	pub fn create(imgsize: i32) -> Result<core::Ptr<dyn crate::face::MACE>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_MACE_create_int(imgsize, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_MACE_create_int(imgsize, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::face::MACE>::opencv_from_extern(ret) };
@@ -3069,8 +3911,9 @@ pub trait PredictCollector: crate::face::PredictCollectorConst {
 	/// * size: total size of prediction evaluation that recognizer could perform
 	#[inline]
 	fn init(&mut self, size: size_t) -> Result<()> {
+		crate::core::validity::check("PredictCollector", self.as_raw_mut_PredictCollector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_PredictCollector_init_size_t(self.as_raw_mut_PredictCollector(), size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_PredictCollector_init_size_t(self.as_raw_mut_PredictCollector(), size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3082,8 +3925,9 @@ pub trait PredictCollector: crate::face::PredictCollectorConst {
 	/// * dist: current prediction distance (confidence)
 	#[inline]
 	fn collect(&mut self, label: i32, dist: f64) -> Result<bool> {
+		crate::core::validity::check("PredictCollector", self.as_raw_mut_PredictCollector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_PredictCollector_collect_int_double(self.as_raw_mut_PredictCollector(), label, dist, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_PredictCollector_collect_int_double(self.as_raw_mut_PredictCollector(), label, dist, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3100,8 +3944,9 @@ pub trait StandardCollectorTraitConst: crate::face::PredictCollectorConst {
 	/// Returns label with minimal distance
 	#[inline]
 	fn get_min_label(&self) -> Result<i32> {
+		crate::core::validity::check("StandardCollector", self.as_raw_StandardCollector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_StandardCollector_getMinLabel_const(self.as_raw_StandardCollector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_StandardCollector_getMinLabel_const(self.as_raw_StandardCollector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3110,8 +3955,9 @@ pub trait StandardCollectorTraitConst: crate::face::PredictCollectorConst {
 	/// Returns minimal distance value
 	#[inline]
 	fn get_min_dist(&self) -> Result<f64> {
+		crate::core::validity::check("StandardCollector", self.as_raw_StandardCollector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_StandardCollector_getMinDist_const(self.as_raw_StandardCollector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_StandardCollector_getMinDist_const(self.as_raw_StandardCollector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3125,8 +3971,9 @@ pub trait StandardCollectorTrait: crate::face::PredictCollector + crate::face::S
 	/// overloaded interface method
 	#[inline]
 	fn init(&mut self, size: size_t) -> Result<()> {
+		crate::core::validity::check("StandardCollector", self.as_raw_mut_StandardCollector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_StandardCollector_init_size_t(self.as_raw_mut_StandardCollector(), size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_StandardCollector_init_size_t(self.as_raw_mut_StandardCollector(), size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3135,8 +3982,9 @@ pub trait StandardCollectorTrait: crate::face::PredictCollector + crate::face::S
 	/// overloaded interface method
 	#[inline]
 	fn collect(&mut self, label: i32, dist: f64) -> Result<bool> {
+		crate::core::validity::check("StandardCollector", self.as_raw_mut_StandardCollector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_StandardCollector_collect_int_double(self.as_raw_mut_StandardCollector(), label, dist, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_StandardCollector_collect_int_double(self.as_raw_mut_StandardCollector(), label, dist, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3156,7 +4004,10 @@ opencv_type_boxed! { StandardCollector }
 impl Drop for StandardCollector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_StandardCollector_delete(instance: *mut c_void); }
-		unsafe { cv_StandardCollector_delete(self.as_raw_mut_StandardCollector()) };
+		let ptr = self.as_raw_mut_StandardCollector();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_StandardCollector_delete(ptr) };
 	}
 }
 
@@ -3188,7 +4039,7 @@ impl StandardCollector {
 	#[inline]
 	pub fn new(threshold_: f64) -> Result<crate::face::StandardCollector> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_StandardCollector_StandardCollector_double(threshold_, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_StandardCollector_StandardCollector_double(threshold_, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::face::StandardCollector::opencv_from_extern(ret) };
@@ -3204,7 +4055,7 @@ impl StandardCollector {
 	#[inline]
 	pub fn create(threshold: f64) -> Result<core::Ptr<crate::face::StandardCollector>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_StandardCollector_create_double(threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_StandardCollector_create_double(threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::face::StandardCollector>::opencv_from_extern(ret) };
@@ -3229,7 +4080,7 @@ impl StandardCollector_PredictResult {
 	#[inline]
 	pub fn new(label_: i32, distance_: f64) -> Result<crate::face::StandardCollector_PredictResult> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_StandardCollector_PredictResult_PredictResult_int_double(label_, distance_, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_face_StandardCollector_PredictResult_PredictResult_int_double(label_, distance_, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -174,7 +174,7 @@ pub type SiftFeatureDetector = crate::features2d::SIFT;
 pub fn AGAST(image: &dyn core::ToInputArray, keypoints: &mut core::Vector<core::KeyPoint>, threshold: i32, nonmax_suppression: bool) -> Result<()> {
 	input_array_arg!(image);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_AGAST_const__InputArrayR_vector_KeyPoint_R_int_bool(image.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfKeyPoint(), threshold, nonmax_suppression, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_AGAST_const__InputArrayR_vector_KeyPoint_R_int_bool(image.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfKeyPoint(), threshold, nonmax_suppression, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -201,7 +201,7 @@ pub fn AGAST(image: &dyn core::ToInputArray, keypoints: &mut core::Vector<core::
 pub fn AGAST_with_type(image: &dyn core::ToInputArray, keypoints: &mut core::Vector<core::KeyPoint>, threshold: i32, nonmax_suppression: bool, typ: crate::features2d::AgastFeatureDetector_DetectorType) -> Result<()> {
 	input_array_arg!(image);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_AGAST_const__InputArrayR_vector_KeyPoint_R_int_bool_DetectorType(image.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfKeyPoint(), threshold, nonmax_suppression, typ, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_AGAST_const__InputArrayR_vector_KeyPoint_R_int_bool_DetectorType(image.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfKeyPoint(), threshold, nonmax_suppression, typ, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -235,7 +235,7 @@ pub fn AGAST_with_type(image: &dyn core::ToInputArray, keypoints: &mut core::Vec
 pub fn FAST(image: &dyn core::ToInputArray, keypoints: &mut core::Vector<core::KeyPoint>, threshold: i32, nonmax_suppression: bool) -> Result<()> {
 	input_array_arg!(image);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_FAST_const__InputArrayR_vector_KeyPoint_R_int_bool(image.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfKeyPoint(), threshold, nonmax_suppression, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_FAST_const__InputArrayR_vector_KeyPoint_R_int_bool(image.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfKeyPoint(), threshold, nonmax_suppression, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -264,7 +264,7 @@ pub fn FAST(image: &dyn core::ToInputArray, keypoints: &mut core::Vector<core::K
 pub fn FAST_with_type(image: &dyn core::ToInputArray, keypoints: &mut core::Vector<core::KeyPoint>, threshold: i32, nonmax_suppression: bool, typ: crate::features2d::FastFeatureDetector_DetectorType) -> Result<()> {
 	input_array_arg!(image);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_FAST_const__InputArrayR_vector_KeyPoint_R_int_bool_DetectorType(image.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfKeyPoint(), threshold, nonmax_suppression, typ, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_FAST_const__InputArrayR_vector_KeyPoint_R_int_bool_DetectorType(image.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfKeyPoint(), threshold, nonmax_suppression, typ, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -273,7 +273,7 @@ pub fn FAST_with_type(image: &dyn core::ToInputArray, keypoints: &mut core::Vect
 #[inline]
 pub fn compute_recall_precision_curve(matches1to2: &core::Vector<core::Vector<core::DMatch>>, correct_matches1to2_mask: &core::Vector<core::Vector<u8>>, recall_precision_curve: &mut core::Vector<core::Point2f>) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_computeRecallPrecisionCurve_const_vector_vector_DMatch__R_const_vector_vector_unsigned_char__R_vector_Point2f_R(matches1to2.as_raw_VectorOfVectorOfDMatch(), correct_matches1to2_mask.as_raw_VectorOfVectorOfu8(), recall_precision_curve.as_raw_mut_VectorOfPoint2f(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_computeRecallPrecisionCurve_const_vector_vector_DMatch__R_const_vector_vector_unsigned_char__R_vector_Point2f_R(matches1to2.as_raw_VectorOfVectorOfDMatch(), correct_matches1to2_mask.as_raw_VectorOfVectorOfu8(), recall_precision_curve.as_raw_mut_VectorOfPoint2f(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -304,7 +304,7 @@ pub fn draw_keypoints(image: &dyn core::ToInputArray, keypoints: &core::Vector<c
 	input_array_arg!(image);
 	input_output_array_arg!(out_image);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_drawKeypoints_const__InputArrayR_const_vector_KeyPoint_R_const__InputOutputArrayR_const_ScalarR_DrawMatchesFlags(image.as_raw__InputArray(), keypoints.as_raw_VectorOfKeyPoint(), out_image.as_raw__InputOutputArray(), &color, flags, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_drawKeypoints_const__InputArrayR_const_vector_KeyPoint_R_const__InputOutputArrayR_const_ScalarR_DrawMatchesFlags(image.as_raw__InputArray(), keypoints.as_raw_VectorOfKeyPoint(), out_image.as_raw__InputOutputArray(), &color, flags, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -317,8 +317,8 @@ pub fn draw_keypoints(image: &dyn core::ToInputArray, keypoints: &core::Vector<c
 /// * keypoints1: Keypoints from the first source image.
 /// * img2: Second source image.
 /// * keypoints2: Keypoints from the second source image.
-/// * matches1to2: Matches from the first image to the second one, which means that keypoints1[i]
-/// has a corresponding point in keypoints2[matches[i]] .
+/// * matches1to2: Matches from the first image to the second one, which means that keypoints1\[i\]
+/// has a corresponding point in keypoints2\[matches\[i\]\] .
 /// * outImg: Output image. Its content depends on the flags value defining what is drawn in the
 /// output image. See possible flags bit values below.
 /// * matchColor: Color of matches (lines and connected keypoints). If matchColor==Scalar::all(-1)
@@ -344,12 +344,55 @@ pub fn draw_matches(img1: &dyn core::ToInputArray, keypoints1: &core::Vector<cor
 	input_array_arg!(img2);
 	input_output_array_arg!(out_img);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_drawMatches_const__InputArrayR_const_vector_KeyPoint_R_const__InputArrayR_const_vector_KeyPoint_R_const_vector_DMatch_R_const__InputOutputArrayR_const_ScalarR_const_ScalarR_const_vector_char_R_DrawMatchesFlags(img1.as_raw__InputArray(), keypoints1.as_raw_VectorOfKeyPoint(), img2.as_raw__InputArray(), keypoints2.as_raw_VectorOfKeyPoint(), matches1to2.as_raw_VectorOfDMatch(), out_img.as_raw__InputOutputArray(), &match_color, &single_point_color, matches_mask.as_raw_VectorOfi8(), flags, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_drawMatches_const__InputArrayR_const_vector_KeyPoint_R_const__InputArrayR_const_vector_KeyPoint_R_const_vector_DMatch_R_const__InputOutputArrayR_const_ScalarR_const_ScalarR_const_vector_char_R_DrawMatchesFlags(img1.as_raw__InputArray(), keypoints1.as_raw_VectorOfKeyPoint(), img2.as_raw__InputArray(), keypoints2.as_raw_VectorOfKeyPoint(), matches1to2.as_raw_VectorOfDMatch(), out_img.as_raw__InputOutputArray(), &match_color, &single_point_color, matches_mask.as_raw_VectorOfi8(), flags, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `draw_matches()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct DrawMatchesBuilder<'a> {
+	img1: &'a dyn core::ToInputArray,
+	keypoints1: &'a core::Vector<core::KeyPoint>,
+	img2: &'a dyn core::ToInputArray,
+	keypoints2: &'a core::Vector<core::KeyPoint>,
+	matches1to2: &'a core::Vector<core::DMatch>,
+	out_img: &'a mut dyn core::ToInputOutputArray,
+	match_color: core::Scalar,
+	single_point_color: core::Scalar,
+	matches_mask: &'a core::Vector<i8>,
+	flags: crate::features2d::DrawMatchesFlags,
+}
+
+impl<'a> DrawMatchesBuilder<'a> {
+	#[inline]
+	pub fn new(img1: &'a dyn core::ToInputArray, keypoints1: &'a core::Vector<core::KeyPoint>, img2: &'a dyn core::ToInputArray, keypoints2: &'a core::Vector<core::KeyPoint>, matches1to2: &'a core::Vector<core::DMatch>, out_img: &'a mut dyn core::ToInputOutputArray, matches_mask: &'a core::Vector<i8>, flags: crate::features2d::DrawMatchesFlags) -> Self {
+		Self { img1, keypoints1, img2, keypoints2, matches1to2, out_img, match_color: core::Scalar::all(-1.), single_point_color: core::Scalar::all(-1.), matches_mask, flags }
+	}
+	
+	/// C++ default: `Scalar::all(-1)`
+	#[inline]
+	pub fn with_match_color(mut self, match_color: core::Scalar) -> Self {
+		self.match_color = match_color;
+		self
+	}
+	
+	/// C++ default: `Scalar::all(-1)`
+	#[inline]
+	pub fn with_single_point_color(mut self, single_point_color: core::Scalar) -> Self {
+		self.single_point_color = single_point_color;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		draw_matches(self.img1, self.keypoints1, self.img2, self.keypoints2, self.matches1to2, self.out_img, self.match_color, self.single_point_color, self.matches_mask, self.flags)
+	}
+}
+
 /// Draws the found matches of keypoints from two images.
 /// 
 /// ## Parameters
@@ -357,8 +400,8 @@ pub fn draw_matches(img1: &dyn core::ToInputArray, keypoints1: &core::Vector<cor
 /// * keypoints1: Keypoints from the first source image.
 /// * img2: Second source image.
 /// * keypoints2: Keypoints from the second source image.
-/// * matches1to2: Matches from the first image to the second one, which means that keypoints1[i]
-/// has a corresponding point in keypoints2[matches[i]] .
+/// * matches1to2: Matches from the first image to the second one, which means that keypoints1\[i\]
+/// has a corresponding point in keypoints2\[matches\[i\]\] .
 /// * outImg: Output image. Its content depends on the flags value defining what is drawn in the
 /// output image. See possible flags bit values below.
 /// * matchColor: Color of matches (lines and connected keypoints). If matchColor==Scalar::all(-1)
@@ -386,12 +429,56 @@ pub fn draw_matches_1(img1: &dyn core::ToInputArray, keypoints1: &core::Vector<c
 	input_array_arg!(img2);
 	input_output_array_arg!(out_img);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_drawMatches_const__InputArrayR_const_vector_KeyPoint_R_const__InputArrayR_const_vector_KeyPoint_R_const_vector_DMatch_R_const__InputOutputArrayR_const_int_const_ScalarR_const_ScalarR_const_vector_char_R_DrawMatchesFlags(img1.as_raw__InputArray(), keypoints1.as_raw_VectorOfKeyPoint(), img2.as_raw__InputArray(), keypoints2.as_raw_VectorOfKeyPoint(), matches1to2.as_raw_VectorOfDMatch(), out_img.as_raw__InputOutputArray(), matches_thickness, &match_color, &single_point_color, matches_mask.as_raw_VectorOfi8(), flags, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_drawMatches_const__InputArrayR_const_vector_KeyPoint_R_const__InputArrayR_const_vector_KeyPoint_R_const_vector_DMatch_R_const__InputOutputArrayR_const_int_const_ScalarR_const_ScalarR_const_vector_char_R_DrawMatchesFlags(img1.as_raw__InputArray(), keypoints1.as_raw_VectorOfKeyPoint(), img2.as_raw__InputArray(), keypoints2.as_raw_VectorOfKeyPoint(), matches1to2.as_raw_VectorOfDMatch(), out_img.as_raw__InputOutputArray(), matches_thickness, &match_color, &single_point_color, matches_mask.as_raw_VectorOfi8(), flags, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `draw_matches_1()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct DrawMatches1Builder<'a> {
+	img1: &'a dyn core::ToInputArray,
+	keypoints1: &'a core::Vector<core::KeyPoint>,
+	img2: &'a dyn core::ToInputArray,
+	keypoints2: &'a core::Vector<core::KeyPoint>,
+	matches1to2: &'a core::Vector<core::DMatch>,
+	out_img: &'a mut dyn core::ToInputOutputArray,
+	matches_thickness: i32,
+	match_color: core::Scalar,
+	single_point_color: core::Scalar,
+	matches_mask: &'a core::Vector<i8>,
+	flags: crate::features2d::DrawMatchesFlags,
+}
+
+impl<'a> DrawMatches1Builder<'a> {
+	#[inline]
+	pub fn new(img1: &'a dyn core::ToInputArray, keypoints1: &'a core::Vector<core::KeyPoint>, img2: &'a dyn core::ToInputArray, keypoints2: &'a core::Vector<core::KeyPoint>, matches1to2: &'a core::Vector<core::DMatch>, out_img: &'a mut dyn core::ToInputOutputArray, matches_thickness: i32, matches_mask: &'a core::Vector<i8>, flags: crate::features2d::DrawMatchesFlags) -> Self {
+		Self { img1, keypoints1, img2, keypoints2, matches1to2, out_img, matches_thickness, match_color: core::Scalar::all(-1.), single_point_color: core::Scalar::all(-1.), matches_mask, flags }
+	}
+	
+	/// C++ default: `Scalar::all(-1)`
+	#[inline]
+	pub fn with_match_color(mut self, match_color: core::Scalar) -> Self {
+		self.match_color = match_color;
+		self
+	}
+	
+	/// C++ default: `Scalar::all(-1)`
+	#[inline]
+	pub fn with_single_point_color(mut self, single_point_color: core::Scalar) -> Self {
+		self.single_point_color = single_point_color;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		draw_matches_1(self.img1, self.keypoints1, self.img2, self.keypoints2, self.matches1to2, self.out_img, self.matches_thickness, self.match_color, self.single_point_color, self.matches_mask, self.flags)
+	}
+}
+
 /// ## C++ default parameters
 /// * match_color: Scalar::all(-1)
 /// * single_point_color: Scalar::all(-1)
@@ -403,14 +490,57 @@ pub fn draw_matches_knn(img1: &dyn core::ToInputArray, keypoints1: &core::Vector
 	input_array_arg!(img2);
 	input_output_array_arg!(out_img);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_drawMatches_const__InputArrayR_const_vector_KeyPoint_R_const__InputArrayR_const_vector_KeyPoint_R_const_vector_vector_DMatch__R_const__InputOutputArrayR_const_ScalarR_const_ScalarR_const_vector_vector_char__R_DrawMatchesFlags(img1.as_raw__InputArray(), keypoints1.as_raw_VectorOfKeyPoint(), img2.as_raw__InputArray(), keypoints2.as_raw_VectorOfKeyPoint(), matches1to2.as_raw_VectorOfVectorOfDMatch(), out_img.as_raw__InputOutputArray(), &match_color, &single_point_color, matches_mask.as_raw_VectorOfVectorOfi8(), flags, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_drawMatches_const__InputArrayR_const_vector_KeyPoint_R_const__InputArrayR_const_vector_KeyPoint_R_const_vector_vector_DMatch__R_const__InputOutputArrayR_const_ScalarR_const_ScalarR_const_vector_vector_char__R_DrawMatchesFlags(img1.as_raw__InputArray(), keypoints1.as_raw_VectorOfKeyPoint(), img2.as_raw__InputArray(), keypoints2.as_raw_VectorOfKeyPoint(), matches1to2.as_raw_VectorOfVectorOfDMatch(), out_img.as_raw__InputOutputArray(), &match_color, &single_point_color, matches_mask.as_raw_VectorOfVectorOfi8(), flags, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `draw_matches_knn()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct DrawMatchesKnnBuilder<'a> {
+	img1: &'a dyn core::ToInputArray,
+	keypoints1: &'a core::Vector<core::KeyPoint>,
+	img2: &'a dyn core::ToInputArray,
+	keypoints2: &'a core::Vector<core::KeyPoint>,
+	matches1to2: &'a core::Vector<core::Vector<core::DMatch>>,
+	out_img: &'a mut dyn core::ToInputOutputArray,
+	match_color: core::Scalar,
+	single_point_color: core::Scalar,
+	matches_mask: &'a core::Vector<core::Vector<i8>>,
+	flags: crate::features2d::DrawMatchesFlags,
+}
+
+impl<'a> DrawMatchesKnnBuilder<'a> {
+	#[inline]
+	pub fn new(img1: &'a dyn core::ToInputArray, keypoints1: &'a core::Vector<core::KeyPoint>, img2: &'a dyn core::ToInputArray, keypoints2: &'a core::Vector<core::KeyPoint>, matches1to2: &'a core::Vector<core::Vector<core::DMatch>>, out_img: &'a mut dyn core::ToInputOutputArray, matches_mask: &'a core::Vector<core::Vector<i8>>, flags: crate::features2d::DrawMatchesFlags) -> Self {
+		Self { img1, keypoints1, img2, keypoints2, matches1to2, out_img, match_color: core::Scalar::all(-1.), single_point_color: core::Scalar::all(-1.), matches_mask, flags }
+	}
+	
+	/// C++ default: `Scalar::all(-1)`
+	#[inline]
+	pub fn with_match_color(mut self, match_color: core::Scalar) -> Self {
+		self.match_color = match_color;
+		self
+	}
+	
+	/// C++ default: `Scalar::all(-1)`
+	#[inline]
+	pub fn with_single_point_color(mut self, single_point_color: core::Scalar) -> Self {
+		self.single_point_color = single_point_color;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		draw_matches_knn(self.img1, self.keypoints1, self.img2, self.keypoints2, self.matches1to2, self.out_img, self.match_color, self.single_point_color, self.matches_mask, self.flags)
+	}
+}
+
 /// *************************************************************************************\
-///    Functions to evaluate the feature detectors and [generic] descriptor extractors      *
+///    Functions to evaluate the feature detectors and \[generic\] descriptor extractors      *
 /// \***************************************************************************************
 /// 
 /// ## C++ default parameters
@@ -418,7 +548,7 @@ pub fn draw_matches_knn(img1: &dyn core::ToInputArray, keypoints1: &core::Vector
 #[inline]
 pub fn evaluate_feature_detector(img1: &core::Mat, img2: &core::Mat, h1to2: &core::Mat, keypoints1: &mut core::Vector<core::KeyPoint>, keypoints2: &mut core::Vector<core::KeyPoint>, repeatability: &mut f32, corresp_count: &mut i32, fdetector: &core::Ptr<crate::features2d::Feature2D>) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_evaluateFeatureDetector_const_MatR_const_MatR_const_MatR_vector_KeyPoint_X_vector_KeyPoint_X_floatR_intR_const_Ptr_Feature2D_R(img1.as_raw_Mat(), img2.as_raw_Mat(), h1to2.as_raw_Mat(), keypoints1.as_raw_mut_VectorOfKeyPoint(), keypoints2.as_raw_mut_VectorOfKeyPoint(), repeatability, corresp_count, fdetector.as_raw_PtrOfFeature2D(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_evaluateFeatureDetector_const_MatR_const_MatR_const_MatR_vector_KeyPoint_X_vector_KeyPoint_X_floatR_intR_const_Ptr_Feature2D_R(img1.as_raw_Mat(), img2.as_raw_Mat(), h1to2.as_raw_Mat(), keypoints1.as_raw_mut_VectorOfKeyPoint(), keypoints2.as_raw_mut_VectorOfKeyPoint(), repeatability, corresp_count, fdetector.as_raw_PtrOfFeature2D(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -427,7 +557,7 @@ pub fn evaluate_feature_detector(img1: &core::Mat, img2: &core::Mat, h1to2: &cor
 #[inline]
 pub fn get_nearest_point(recall_precision_curve: &core::Vector<core::Point2f>, l_precision: f32) -> Result<i32> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_getNearestPoint_const_vector_Point2f_R_float(recall_precision_curve.as_raw_VectorOfPoint2f(), l_precision, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_getNearestPoint_const_vector_Point2f_R_float(recall_precision_curve.as_raw_VectorOfPoint2f(), l_precision, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -436,7 +566,7 @@ pub fn get_nearest_point(recall_precision_curve: &core::Vector<core::Point2f>, l
 #[inline]
 pub fn get_recall(recall_precision_curve: &core::Vector<core::Point2f>, l_precision: f32) -> Result<f32> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_getRecall_const_vector_Point2f_R_float(recall_precision_curve.as_raw_VectorOfPoint2f(), l_precision, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_getRecall_const_vector_Point2f_R_float(recall_precision_curve.as_raw_VectorOfPoint2f(), l_precision, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -456,7 +586,7 @@ pub fn get_recall(recall_precision_curve: &core::Vector<core::Point2f>, l_precis
 /// will use OpenCL.
 /// 
 /// 
-/// Note: [ANB13] Fast Explicit Diffusion for Accelerated Features in Nonlinear
+/// Note: \[ANB13\] Fast Explicit Diffusion for Accelerated Features in Nonlinear
 /// Scale Spaces. Pablo F. Alcantarilla, Jesús Nuevo and Adrien Bartoli. In
 /// British Machine Vision Conference (BMVC), Bristol, UK, September 2013.
 pub trait AKAZEConst: crate::features2d::Feature2DTraitConst {
@@ -464,8 +594,9 @@ pub trait AKAZEConst: crate::features2d::Feature2DTraitConst {
 
 	#[inline]
 	fn get_descriptor_type(&self) -> Result<crate::features2d::AKAZE_DescriptorType> {
+		crate::core::validity::check("AKAZE", self.as_raw_AKAZE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AKAZE_getDescriptorType_const(self.as_raw_AKAZE(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AKAZE_getDescriptorType_const(self.as_raw_AKAZE(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -473,8 +604,9 @@ pub trait AKAZEConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_descriptor_size(&self) -> Result<i32> {
+		crate::core::validity::check("AKAZE", self.as_raw_AKAZE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AKAZE_getDescriptorSize_const(self.as_raw_AKAZE(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AKAZE_getDescriptorSize_const(self.as_raw_AKAZE(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -482,8 +614,9 @@ pub trait AKAZEConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_descriptor_channels(&self) -> Result<i32> {
+		crate::core::validity::check("AKAZE", self.as_raw_AKAZE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AKAZE_getDescriptorChannels_const(self.as_raw_AKAZE(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AKAZE_getDescriptorChannels_const(self.as_raw_AKAZE(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -491,8 +624,9 @@ pub trait AKAZEConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_threshold(&self) -> Result<f64> {
+		crate::core::validity::check("AKAZE", self.as_raw_AKAZE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AKAZE_getThreshold_const(self.as_raw_AKAZE(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AKAZE_getThreshold_const(self.as_raw_AKAZE(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -500,8 +634,9 @@ pub trait AKAZEConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_n_octaves(&self) -> Result<i32> {
+		crate::core::validity::check("AKAZE", self.as_raw_AKAZE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AKAZE_getNOctaves_const(self.as_raw_AKAZE(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AKAZE_getNOctaves_const(self.as_raw_AKAZE(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -509,8 +644,9 @@ pub trait AKAZEConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_n_octave_layers(&self) -> Result<i32> {
+		crate::core::validity::check("AKAZE", self.as_raw_AKAZE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AKAZE_getNOctaveLayers_const(self.as_raw_AKAZE(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AKAZE_getNOctaveLayers_const(self.as_raw_AKAZE(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -518,8 +654,9 @@ pub trait AKAZEConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_diffusivity(&self) -> Result<crate::features2d::KAZE_DiffusivityType> {
+		crate::core::validity::check("AKAZE", self.as_raw_AKAZE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AKAZE_getDiffusivity_const(self.as_raw_AKAZE(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AKAZE_getDiffusivity_const(self.as_raw_AKAZE(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -527,8 +664,9 @@ pub trait AKAZEConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_default_name(&self) -> Result<String> {
+		crate::core::validity::check("AKAZE", self.as_raw_AKAZE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AKAZE_getDefaultName_const(self.as_raw_AKAZE(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AKAZE_getDefaultName_const(self.as_raw_AKAZE(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -542,8 +680,9 @@ pub trait AKAZE: crate::features2d::AKAZEConst + crate::features2d::Feature2DTra
 
 	#[inline]
 	fn set_descriptor_type(&mut self, dtype: crate::features2d::AKAZE_DescriptorType) -> Result<()> {
+		crate::core::validity::check("AKAZE", self.as_raw_mut_AKAZE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AKAZE_setDescriptorType_DescriptorType(self.as_raw_mut_AKAZE(), dtype, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AKAZE_setDescriptorType_DescriptorType(self.as_raw_mut_AKAZE(), dtype, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -551,8 +690,9 @@ pub trait AKAZE: crate::features2d::AKAZEConst + crate::features2d::Feature2DTra
 	
 	#[inline]
 	fn set_descriptor_size(&mut self, dsize: i32) -> Result<()> {
+		crate::core::validity::check("AKAZE", self.as_raw_mut_AKAZE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AKAZE_setDescriptorSize_int(self.as_raw_mut_AKAZE(), dsize, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AKAZE_setDescriptorSize_int(self.as_raw_mut_AKAZE(), dsize, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -560,8 +700,9 @@ pub trait AKAZE: crate::features2d::AKAZEConst + crate::features2d::Feature2DTra
 	
 	#[inline]
 	fn set_descriptor_channels(&mut self, dch: i32) -> Result<()> {
+		crate::core::validity::check("AKAZE", self.as_raw_mut_AKAZE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AKAZE_setDescriptorChannels_int(self.as_raw_mut_AKAZE(), dch, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AKAZE_setDescriptorChannels_int(self.as_raw_mut_AKAZE(), dch, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -569,8 +710,9 @@ pub trait AKAZE: crate::features2d::AKAZEConst + crate::features2d::Feature2DTra
 	
 	#[inline]
 	fn set_threshold(&mut self, threshold: f64) -> Result<()> {
+		crate::core::validity::check("AKAZE", self.as_raw_mut_AKAZE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AKAZE_setThreshold_double(self.as_raw_mut_AKAZE(), threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AKAZE_setThreshold_double(self.as_raw_mut_AKAZE(), threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -578,8 +720,9 @@ pub trait AKAZE: crate::features2d::AKAZEConst + crate::features2d::Feature2DTra
 	
 	#[inline]
 	fn set_n_octaves(&mut self, octaves: i32) -> Result<()> {
+		crate::core::validity::check("AKAZE", self.as_raw_mut_AKAZE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AKAZE_setNOctaves_int(self.as_raw_mut_AKAZE(), octaves, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AKAZE_setNOctaves_int(self.as_raw_mut_AKAZE(), octaves, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -587,8 +730,9 @@ pub trait AKAZE: crate::features2d::AKAZEConst + crate::features2d::Feature2DTra
 	
 	#[inline]
 	fn set_n_octave_layers(&mut self, octave_layers: i32) -> Result<()> {
+		crate::core::validity::check("AKAZE", self.as_raw_mut_AKAZE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AKAZE_setNOctaveLayers_int(self.as_raw_mut_AKAZE(), octave_layers, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AKAZE_setNOctaveLayers_int(self.as_raw_mut_AKAZE(), octave_layers, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -596,8 +740,9 @@ pub trait AKAZE: crate::features2d::AKAZEConst + crate::features2d::Feature2DTra
 	
 	#[inline]
 	fn set_diffusivity(&mut self, diff: crate::features2d::KAZE_DiffusivityType) -> Result<()> {
+		crate::core::validity::check("AKAZE", self.as_raw_mut_AKAZE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AKAZE_setDiffusivity_DiffusivityType(self.as_raw_mut_AKAZE(), diff, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AKAZE_setDiffusivity_DiffusivityType(self.as_raw_mut_AKAZE(), diff, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -630,7 +775,7 @@ impl dyn AKAZE + '_ {
 	#[inline]
 	pub fn create(descriptor_type: crate::features2d::AKAZE_DescriptorType, descriptor_size: i32, descriptor_channels: i32, threshold: f32, n_octaves: i32, n_octave_layers: i32, diffusivity: crate::features2d::KAZE_DiffusivityType) -> Result<core::Ptr<dyn crate::features2d::AKAZE>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AKAZE_create_DescriptorType_int_int_float_int_int_DiffusivityType(descriptor_type, descriptor_size, descriptor_channels, threshold, n_octaves, n_octave_layers, diffusivity, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AKAZE_create_DescriptorType_int_int_float_int_int_DiffusivityType(descriptor_type, descriptor_size, descriptor_channels, threshold, n_octaves, n_octave_layers, diffusivity, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::features2d::AKAZE>::opencv_from_extern(ret) };
@@ -638,6 +783,67 @@ impl dyn AKAZE + '_ {
 	}
 	
 }
+
+/// Builder for `<dyn AKAZE>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct AKAZE_CreateBuilder {
+	descriptor_type: crate::features2d::AKAZE_DescriptorType,
+	descriptor_size: i32,
+	descriptor_channels: i32,
+	threshold: f32,
+	n_octaves: i32,
+	n_octave_layers: i32,
+	diffusivity: crate::features2d::KAZE_DiffusivityType,
+}
+
+impl AKAZE_CreateBuilder {
+	#[inline]
+	pub fn new(descriptor_type: crate::features2d::AKAZE_DescriptorType, diffusivity: crate::features2d::KAZE_DiffusivityType) -> Self {
+		Self { descriptor_type, descriptor_size: 0, descriptor_channels: 3, threshold: 0.001, n_octaves: 4, n_octave_layers: 4, diffusivity }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_descriptor_size(mut self, descriptor_size: i32) -> Self {
+		self.descriptor_size = descriptor_size;
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_descriptor_channels(mut self, descriptor_channels: i32) -> Self {
+		self.descriptor_channels = descriptor_channels;
+		self
+	}
+	
+	/// C++ default: `0.001f`
+	#[inline]
+	pub fn with_threshold(mut self, threshold: f32) -> Self {
+		self.threshold = threshold;
+		self
+	}
+	
+	/// C++ default: `4`
+	#[inline]
+	pub fn with_n_octaves(mut self, n_octaves: i32) -> Self {
+		self.n_octaves = n_octaves;
+		self
+	}
+	
+	/// C++ default: `4`
+	#[inline]
+	pub fn with_n_octave_layers(mut self, n_octave_layers: i32) -> Self {
+		self.n_octave_layers = n_octave_layers;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::features2d::AKAZE>> {
+		<dyn AKAZE>::create(self.descriptor_type, self.descriptor_size, self.descriptor_channels, self.threshold, self.n_octaves, self.n_octave_layers, self.diffusivity)
+	}
+}
 /// Class for implementing the wrapper which makes detectors and extractors to be affine invariant,
 /// described as ASIFT in [YM11](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_YM11) .
 pub trait AffineFeatureConst: crate::features2d::Feature2DTraitConst {
@@ -645,8 +851,9 @@ pub trait AffineFeatureConst: crate::features2d::Feature2DTraitConst {
 
 	#[inline]
 	fn get_view_params(&self, tilts: &mut core::Vector<f32>, rolls: &mut core::Vector<f32>) -> Result<()> {
+		crate::core::validity::check("AffineFeature", self.as_raw_AffineFeature())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AffineFeature_getViewParams_const_vector_float_R_vector_float_R(self.as_raw_AffineFeature(), tilts.as_raw_mut_VectorOff32(), rolls.as_raw_mut_VectorOff32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AffineFeature_getViewParams_const_vector_float_R_vector_float_R(self.as_raw_AffineFeature(), tilts.as_raw_mut_VectorOff32(), rolls.as_raw_mut_VectorOff32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -654,8 +861,9 @@ pub trait AffineFeatureConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_default_name(&self) -> Result<String> {
+		crate::core::validity::check("AffineFeature", self.as_raw_AffineFeature())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AffineFeature_getDefaultName_const(self.as_raw_AffineFeature(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AffineFeature_getDefaultName_const(self.as_raw_AffineFeature(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -669,8 +877,9 @@ pub trait AffineFeature: crate::features2d::AffineFeatureConst + crate::features
 
 	#[inline]
 	fn set_view_params(&mut self, tilts: &core::Vector<f32>, rolls: &core::Vector<f32>) -> Result<()> {
+		crate::core::validity::check("AffineFeature", self.as_raw_mut_AffineFeature())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AffineFeature_setViewParams_const_vector_float_R_const_vector_float_R(self.as_raw_mut_AffineFeature(), tilts.as_raw_VectorOff32(), rolls.as_raw_VectorOff32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AffineFeature_setViewParams_const_vector_float_R_const_vector_float_R(self.as_raw_mut_AffineFeature(), tilts.as_raw_VectorOff32(), rolls.as_raw_VectorOff32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -694,7 +903,7 @@ impl dyn AffineFeature + '_ {
 	#[inline]
 	pub fn create(backend: &core::Ptr<crate::features2d::Feature2D>, max_tilt: i32, min_tilt: i32, tilt_step: f32, rotate_step_base: f32) -> Result<core::Ptr<dyn crate::features2d::AffineFeature>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AffineFeature_create_const_Ptr_Feature2D_R_int_int_float_float(backend.as_raw_PtrOfFeature2D(), max_tilt, min_tilt, tilt_step, rotate_step_base, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AffineFeature_create_const_Ptr_Feature2D_R_int_int_float_float(backend.as_raw_PtrOfFeature2D(), max_tilt, min_tilt, tilt_step, rotate_step_base, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::features2d::AffineFeature>::opencv_from_extern(ret) };
@@ -702,14 +911,67 @@ impl dyn AffineFeature + '_ {
 	}
 	
 }
+
+/// Builder for `<dyn AffineFeature>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct AffineFeature_CreateBuilder<'a> {
+	backend: &'a core::Ptr<crate::features2d::Feature2D>,
+	max_tilt: i32,
+	min_tilt: i32,
+	tilt_step: f32,
+	rotate_step_base: f32,
+}
+
+impl<'a> AffineFeature_CreateBuilder<'a> {
+	#[inline]
+	pub fn new(backend: &'a core::Ptr<crate::features2d::Feature2D>) -> Self {
+		Self { backend, max_tilt: 5, min_tilt: 0, tilt_step: 1.4142135623730951, rotate_step_base: 72. }
+	}
+	
+	/// C++ default: `5`
+	#[inline]
+	pub fn with_max_tilt(mut self, max_tilt: i32) -> Self {
+		self.max_tilt = max_tilt;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_min_tilt(mut self, min_tilt: i32) -> Self {
+		self.min_tilt = min_tilt;
+		self
+	}
+	
+	/// C++ default: `1.4142135623730951f`
+	#[inline]
+	pub fn with_tilt_step(mut self, tilt_step: f32) -> Self {
+		self.tilt_step = tilt_step;
+		self
+	}
+	
+	/// C++ default: `72`
+	#[inline]
+	pub fn with_rotate_step_base(mut self, rotate_step_base: f32) -> Self {
+		self.rotate_step_base = rotate_step_base;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::features2d::AffineFeature>> {
+		<dyn AffineFeature>::create(self.backend, self.max_tilt, self.min_tilt, self.tilt_step, self.rotate_step_base)
+	}
+}
 /// Wrapping class for feature detection using the AGAST method. :
 pub trait AgastFeatureDetectorConst: crate::features2d::Feature2DTraitConst {
 	fn as_raw_AgastFeatureDetector(&self) -> *const c_void;
 
 	#[inline]
 	fn get_threshold(&self) -> Result<i32> {
+		crate::core::validity::check("AgastFeatureDetector", self.as_raw_AgastFeatureDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AgastFeatureDetector_getThreshold_const(self.as_raw_AgastFeatureDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AgastFeatureDetector_getThreshold_const(self.as_raw_AgastFeatureDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -717,8 +979,9 @@ pub trait AgastFeatureDetectorConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_nonmax_suppression(&self) -> Result<bool> {
+		crate::core::validity::check("AgastFeatureDetector", self.as_raw_AgastFeatureDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AgastFeatureDetector_getNonmaxSuppression_const(self.as_raw_AgastFeatureDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AgastFeatureDetector_getNonmaxSuppression_const(self.as_raw_AgastFeatureDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -726,8 +989,9 @@ pub trait AgastFeatureDetectorConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_type(&self) -> Result<crate::features2d::AgastFeatureDetector_DetectorType> {
+		crate::core::validity::check("AgastFeatureDetector", self.as_raw_AgastFeatureDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AgastFeatureDetector_getType_const(self.as_raw_AgastFeatureDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AgastFeatureDetector_getType_const(self.as_raw_AgastFeatureDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -735,8 +999,9 @@ pub trait AgastFeatureDetectorConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_default_name(&self) -> Result<String> {
+		crate::core::validity::check("AgastFeatureDetector", self.as_raw_AgastFeatureDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AgastFeatureDetector_getDefaultName_const(self.as_raw_AgastFeatureDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AgastFeatureDetector_getDefaultName_const(self.as_raw_AgastFeatureDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -750,8 +1015,9 @@ pub trait AgastFeatureDetector: crate::features2d::AgastFeatureDetectorConst + c
 
 	#[inline]
 	fn set_threshold(&mut self, threshold: i32) -> Result<()> {
+		crate::core::validity::check("AgastFeatureDetector", self.as_raw_mut_AgastFeatureDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AgastFeatureDetector_setThreshold_int(self.as_raw_mut_AgastFeatureDetector(), threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AgastFeatureDetector_setThreshold_int(self.as_raw_mut_AgastFeatureDetector(), threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -759,8 +1025,9 @@ pub trait AgastFeatureDetector: crate::features2d::AgastFeatureDetectorConst + c
 	
 	#[inline]
 	fn set_nonmax_suppression(&mut self, f: bool) -> Result<()> {
+		crate::core::validity::check("AgastFeatureDetector", self.as_raw_mut_AgastFeatureDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AgastFeatureDetector_setNonmaxSuppression_bool(self.as_raw_mut_AgastFeatureDetector(), f, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AgastFeatureDetector_setNonmaxSuppression_bool(self.as_raw_mut_AgastFeatureDetector(), f, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -768,8 +1035,9 @@ pub trait AgastFeatureDetector: crate::features2d::AgastFeatureDetectorConst + c
 	
 	#[inline]
 	fn set_type(&mut self, typ: crate::features2d::AgastFeatureDetector_DetectorType) -> Result<()> {
+		crate::core::validity::check("AgastFeatureDetector", self.as_raw_mut_AgastFeatureDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AgastFeatureDetector_setType_DetectorType(self.as_raw_mut_AgastFeatureDetector(), typ, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AgastFeatureDetector_setType_DetectorType(self.as_raw_mut_AgastFeatureDetector(), typ, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -785,7 +1053,7 @@ impl dyn AgastFeatureDetector + '_ {
 	#[inline]
 	pub fn create(threshold: i32, nonmax_suppression: bool, typ: crate::features2d::AgastFeatureDetector_DetectorType) -> Result<core::Ptr<dyn crate::features2d::AgastFeatureDetector>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AgastFeatureDetector_create_int_bool_DetectorType(threshold, nonmax_suppression, typ, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AgastFeatureDetector_create_int_bool_DetectorType(threshold, nonmax_suppression, typ, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::features2d::AgastFeatureDetector>::opencv_from_extern(ret) };
@@ -793,6 +1061,42 @@ impl dyn AgastFeatureDetector + '_ {
 	}
 	
 }
+
+/// Builder for `<dyn AgastFeatureDetector>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct AgastFeatureDetector_CreateBuilder {
+	threshold: i32,
+	nonmax_suppression: bool,
+	typ: crate::features2d::AgastFeatureDetector_DetectorType,
+}
+
+impl AgastFeatureDetector_CreateBuilder {
+	#[inline]
+	pub fn new(typ: crate::features2d::AgastFeatureDetector_DetectorType) -> Self {
+		Self { threshold: 10, nonmax_suppression: true, typ }
+	}
+	
+	/// C++ default: `10`
+	#[inline]
+	pub fn with_threshold(mut self, threshold: i32) -> Self {
+		self.threshold = threshold;
+		self
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_nonmax_suppression(mut self, nonmax_suppression: bool) -> Self {
+		self.nonmax_suppression = nonmax_suppression;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::features2d::AgastFeatureDetector>> {
+		<dyn AgastFeatureDetector>::create(self.threshold, self.nonmax_suppression, self.typ)
+	}
+}
 /// Brute-force descriptor matcher.
 /// 
 /// For each descriptor in the first set, this matcher finds the closest descriptor in the second set
@@ -803,8 +1107,9 @@ pub trait BFMatcherTraitConst: crate::features2d::DescriptorMatcherConst {
 
 	#[inline]
 	fn is_mask_supported(&self) -> Result<bool> {
+		crate::core::validity::check("BFMatcher", self.as_raw_BFMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BFMatcher_isMaskSupported_const(self.as_raw_BFMatcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BFMatcher_isMaskSupported_const(self.as_raw_BFMatcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -815,8 +1120,9 @@ pub trait BFMatcherTraitConst: crate::features2d::DescriptorMatcherConst {
 	#[inline]
 	#[must_use]
 	fn clone(&self, empty_train_data: bool) -> Result<core::Ptr<dyn crate::features2d::DescriptorMatcher>> {
+		crate::core::validity::check("BFMatcher", self.as_raw_BFMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BFMatcher_clone_const_bool(self.as_raw_BFMatcher(), empty_train_data, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BFMatcher_clone_const_bool(self.as_raw_BFMatcher(), empty_train_data, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::features2d::DescriptorMatcher>::opencv_from_extern(ret) };
@@ -844,7 +1150,10 @@ opencv_type_boxed! { BFMatcher }
 impl Drop for BFMatcher {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BFMatcher_delete(instance: *mut c_void); }
-		unsafe { cv_BFMatcher_delete(self.as_raw_mut_BFMatcher()) };
+		let ptr = self.as_raw_mut_BFMatcher();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_BFMatcher_delete(ptr) };
 	}
 }
 
@@ -883,7 +1192,7 @@ impl BFMatcher {
 	#[inline]
 	pub fn new(norm_type: i32, cross_check: bool) -> Result<crate::features2d::BFMatcher> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BFMatcher_BFMatcher_int_bool(norm_type, cross_check, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BFMatcher_BFMatcher_int_bool(norm_type, cross_check, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::features2d::BFMatcher::opencv_from_extern(ret) };
@@ -909,7 +1218,7 @@ impl BFMatcher {
 	#[inline]
 	pub fn create(norm_type: i32, cross_check: bool) -> Result<core::Ptr<crate::features2d::BFMatcher>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BFMatcher_create_int_bool(norm_type, cross_check, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BFMatcher_create_int_bool(norm_type, cross_check, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::features2d::BFMatcher>::opencv_from_extern(ret) };
@@ -935,8 +1244,9 @@ pub trait BOWImgDescriptorExtractorTraitConst {
 	/// Returns the set vocabulary.
 	#[inline]
 	fn get_vocabulary(&self) -> Result<core::Mat> {
+		crate::core::validity::check("BOWImgDescriptorExtractor", self.as_raw_BOWImgDescriptorExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BOWImgDescriptorExtractor_getVocabulary_const(self.as_raw_BOWImgDescriptorExtractor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BOWImgDescriptorExtractor_getVocabulary_const(self.as_raw_BOWImgDescriptorExtractor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -946,8 +1256,9 @@ pub trait BOWImgDescriptorExtractorTraitConst {
 	/// Returns an image descriptor size if the vocabulary is set. Otherwise, it returns 0.
 	#[inline]
 	fn descriptor_size(&self) -> Result<i32> {
+		crate::core::validity::check("BOWImgDescriptorExtractor", self.as_raw_BOWImgDescriptorExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BOWImgDescriptorExtractor_descriptorSize_const(self.as_raw_BOWImgDescriptorExtractor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BOWImgDescriptorExtractor_descriptorSize_const(self.as_raw_BOWImgDescriptorExtractor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -956,8 +1267,9 @@ pub trait BOWImgDescriptorExtractorTraitConst {
 	/// Returns an image descriptor type.
 	#[inline]
 	fn descriptor_type(&self) -> Result<i32> {
+		crate::core::validity::check("BOWImgDescriptorExtractor", self.as_raw_BOWImgDescriptorExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BOWImgDescriptorExtractor_descriptorType_const(self.as_raw_BOWImgDescriptorExtractor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BOWImgDescriptorExtractor_descriptorType_const(self.as_raw_BOWImgDescriptorExtractor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -975,8 +1287,9 @@ pub trait BOWImgDescriptorExtractorTrait: crate::features2d::BOWImgDescriptorExt
 	/// vocabulary is a visual word (cluster center).
 	#[inline]
 	fn set_vocabulary(&mut self, vocabulary: &core::Mat) -> Result<()> {
+		crate::core::validity::check("BOWImgDescriptorExtractor", self.as_raw_mut_BOWImgDescriptorExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BOWImgDescriptorExtractor_setVocabulary_const_MatR(self.as_raw_mut_BOWImgDescriptorExtractor(), vocabulary.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BOWImgDescriptorExtractor_setVocabulary_const_MatR(self.as_raw_mut_BOWImgDescriptorExtractor(), vocabulary.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -989,7 +1302,7 @@ pub trait BOWImgDescriptorExtractorTrait: crate::features2d::BOWImgDescriptorExt
 	/// * keypoints: Keypoints detected in the input image.
 	/// * imgDescriptor: Computed output image descriptor.
 	/// * pointIdxsOfClusters: Indices of keypoints that belong to the cluster. This means that
-	/// pointIdxsOfClusters[i] are keypoint indices that belong to the i -th cluster (word of vocabulary)
+	/// pointIdxsOfClusters\[i\] are keypoint indices that belong to the i -th cluster (word of vocabulary)
 	/// returned if it is non-zero.
 	/// * descriptors: Descriptors of the image keypoints that are returned if they are non-zero.
 	/// 
@@ -998,10 +1311,11 @@ pub trait BOWImgDescriptorExtractorTrait: crate::features2d::BOWImgDescriptorExt
 	/// * descriptors: 0
 	#[inline]
 	fn compute_desc(&mut self, image: &dyn core::ToInputArray, keypoints: &mut core::Vector<core::KeyPoint>, img_descriptor: &mut dyn core::ToOutputArray, point_idxs_of_clusters: &mut core::Vector<core::Vector<i32>>, descriptors: &mut core::Mat) -> Result<()> {
+		crate::core::validity::check("BOWImgDescriptorExtractor", self.as_raw_mut_BOWImgDescriptorExtractor())?;
 		input_array_arg!(image);
 		output_array_arg!(img_descriptor);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BOWImgDescriptorExtractor_compute_const__InputArrayR_vector_KeyPoint_R_const__OutputArrayR_vector_vector_int__X_MatX(self.as_raw_mut_BOWImgDescriptorExtractor(), image.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfKeyPoint(), img_descriptor.as_raw__OutputArray(), point_idxs_of_clusters.as_raw_mut_VectorOfVectorOfi32(), descriptors.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BOWImgDescriptorExtractor_compute_const__InputArrayR_vector_KeyPoint_R_const__OutputArrayR_vector_vector_int__X_MatX(self.as_raw_mut_BOWImgDescriptorExtractor(), image.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfKeyPoint(), img_descriptor.as_raw__OutputArray(), point_idxs_of_clusters.as_raw_mut_VectorOfVectorOfi32(), descriptors.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1014,7 +1328,7 @@ pub trait BOWImgDescriptorExtractorTrait: crate::features2d::BOWImgDescriptorExt
 	/// * keypoints: Keypoints detected in the input image.
 	/// * imgDescriptor: Computed output image descriptor.
 	/// * pointIdxsOfClusters: Indices of keypoints that belong to the cluster. This means that
-	/// pointIdxsOfClusters[i] are keypoint indices that belong to the i -th cluster (word of vocabulary)
+	/// pointIdxsOfClusters\[i\] are keypoint indices that belong to the i -th cluster (word of vocabulary)
 	/// returned if it is non-zero.
 	/// * descriptors: Descriptors of the image keypoints that are returned if they are non-zero.
 	/// 
@@ -1023,17 +1337,18 @@ pub trait BOWImgDescriptorExtractorTrait: crate::features2d::BOWImgDescriptorExt
 	/// * keypointDescriptors: Computed descriptors to match with vocabulary.
 	/// * imgDescriptor: Computed output image descriptor.
 	/// * pointIdxsOfClusters: Indices of keypoints that belong to the cluster. This means that
-	///    pointIdxsOfClusters[i] are keypoint indices that belong to the i -th cluster (word of vocabulary)
+	///    pointIdxsOfClusters\[i\] are keypoint indices that belong to the i -th cluster (word of vocabulary)
 	///    returned if it is non-zero.
 	/// 
 	/// ## C++ default parameters
 	/// * point_idxs_of_clusters: 0
 	#[inline]
 	fn compute(&mut self, keypoint_descriptors: &dyn core::ToInputArray, img_descriptor: &mut dyn core::ToOutputArray, point_idxs_of_clusters: &mut core::Vector<core::Vector<i32>>) -> Result<()> {
+		crate::core::validity::check("BOWImgDescriptorExtractor", self.as_raw_mut_BOWImgDescriptorExtractor())?;
 		input_array_arg!(keypoint_descriptors);
 		output_array_arg!(img_descriptor);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BOWImgDescriptorExtractor_compute_const__InputArrayR_const__OutputArrayR_vector_vector_int__X(self.as_raw_mut_BOWImgDescriptorExtractor(), keypoint_descriptors.as_raw__InputArray(), img_descriptor.as_raw__OutputArray(), point_idxs_of_clusters.as_raw_mut_VectorOfVectorOfi32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BOWImgDescriptorExtractor_compute_const__InputArrayR_const__OutputArrayR_vector_vector_int__X(self.as_raw_mut_BOWImgDescriptorExtractor(), keypoint_descriptors.as_raw__InputArray(), img_descriptor.as_raw__OutputArray(), point_idxs_of_clusters.as_raw_mut_VectorOfVectorOfi32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1041,8 +1356,9 @@ pub trait BOWImgDescriptorExtractorTrait: crate::features2d::BOWImgDescriptorExt
 	
 	#[inline]
 	fn compute2(&mut self, image: &core::Mat, keypoints: &mut core::Vector<core::KeyPoint>, img_descriptor: &mut core::Mat) -> Result<()> {
+		crate::core::validity::check("BOWImgDescriptorExtractor", self.as_raw_mut_BOWImgDescriptorExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BOWImgDescriptorExtractor_compute2_const_MatR_vector_KeyPoint_R_MatR(self.as_raw_mut_BOWImgDescriptorExtractor(), image.as_raw_Mat(), keypoints.as_raw_mut_VectorOfKeyPoint(), img_descriptor.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BOWImgDescriptorExtractor_compute2_const_MatR_vector_KeyPoint_R_MatR(self.as_raw_mut_BOWImgDescriptorExtractor(), image.as_raw_Mat(), keypoints.as_raw_mut_VectorOfKeyPoint(), img_descriptor.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1068,7 +1384,10 @@ opencv_type_boxed! { BOWImgDescriptorExtractor }
 impl Drop for BOWImgDescriptorExtractor {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BOWImgDescriptorExtractor_delete(instance: *mut c_void); }
-		unsafe { cv_BOWImgDescriptorExtractor_delete(self.as_raw_mut_BOWImgDescriptorExtractor()) };
+		let ptr = self.as_raw_mut_BOWImgDescriptorExtractor();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_BOWImgDescriptorExtractor_delete(ptr) };
 	}
 }
 
@@ -1093,7 +1412,7 @@ impl BOWImgDescriptorExtractor {
 	#[inline]
 	pub fn new(dextractor: &core::Ptr<crate::features2d::Feature2D>, dmatcher: &core::Ptr<dyn crate::features2d::DescriptorMatcher>) -> Result<crate::features2d::BOWImgDescriptorExtractor> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BOWImgDescriptorExtractor_BOWImgDescriptorExtractor_const_Ptr_Feature2D_R_const_Ptr_DescriptorMatcher_R(dextractor.as_raw_PtrOfFeature2D(), dmatcher.as_raw_PtrOfDescriptorMatcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BOWImgDescriptorExtractor_BOWImgDescriptorExtractor_const_Ptr_Feature2D_R_const_Ptr_DescriptorMatcher_R(dextractor.as_raw_PtrOfFeature2D(), dmatcher.as_raw_PtrOfDescriptorMatcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::features2d::BOWImgDescriptorExtractor::opencv_from_extern(ret) };
@@ -1112,7 +1431,7 @@ impl BOWImgDescriptorExtractor {
 	#[inline]
 	pub fn new_1(dmatcher: &core::Ptr<dyn crate::features2d::DescriptorMatcher>) -> Result<crate::features2d::BOWImgDescriptorExtractor> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BOWImgDescriptorExtractor_BOWImgDescriptorExtractor_const_Ptr_DescriptorMatcher_R(dmatcher.as_raw_PtrOfDescriptorMatcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BOWImgDescriptorExtractor_BOWImgDescriptorExtractor_const_Ptr_DescriptorMatcher_R(dmatcher.as_raw_PtrOfDescriptorMatcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::features2d::BOWImgDescriptorExtractor::opencv_from_extern(ret) };
@@ -1127,8 +1446,9 @@ pub trait BOWKMeansTrainerTraitConst: crate::features2d::BOWTrainerConst {
 
 	#[inline]
 	fn cluster(&self) -> Result<core::Mat> {
+		crate::core::validity::check("BOWKMeansTrainer", self.as_raw_BOWKMeansTrainer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BOWKMeansTrainer_cluster_const(self.as_raw_BOWKMeansTrainer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BOWKMeansTrainer_cluster_const(self.as_raw_BOWKMeansTrainer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -1137,8 +1457,9 @@ pub trait BOWKMeansTrainerTraitConst: crate::features2d::BOWTrainerConst {
 	
 	#[inline]
 	fn cluster_1(&self, descriptors: &core::Mat) -> Result<core::Mat> {
+		crate::core::validity::check("BOWKMeansTrainer", self.as_raw_BOWKMeansTrainer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BOWKMeansTrainer_cluster_const_const_MatR(self.as_raw_BOWKMeansTrainer(), descriptors.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BOWKMeansTrainer_cluster_const_const_MatR(self.as_raw_BOWKMeansTrainer(), descriptors.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -1162,7 +1483,10 @@ opencv_type_boxed! { BOWKMeansTrainer }
 impl Drop for BOWKMeansTrainer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BOWKMeansTrainer_delete(instance: *mut c_void); }
-		unsafe { cv_BOWKMeansTrainer_delete(self.as_raw_mut_BOWKMeansTrainer()) };
+		let ptr = self.as_raw_mut_BOWKMeansTrainer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_BOWKMeansTrainer_delete(ptr) };
 	}
 }
 
@@ -1187,7 +1511,7 @@ impl crate::features2d::BOWKMeansTrainerTrait for BOWKMeansTrainer {
 impl BOWKMeansTrainer {
 	/// The constructor.
 	/// ## See also
-	/// cv::kmeans
+	/// [kmeans](crate::core::kmeans)
 	/// 
 	/// ## C++ default parameters
 	/// * termcrit: TermCriteria()
@@ -1196,7 +1520,7 @@ impl BOWKMeansTrainer {
 	#[inline]
 	pub fn new(cluster_count: i32, termcrit: core::TermCriteria, attempts: i32, flags: i32) -> Result<crate::features2d::BOWKMeansTrainer> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BOWKMeansTrainer_BOWKMeansTrainer_int_const_TermCriteriaR_int_int(cluster_count, &termcrit, attempts, flags, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BOWKMeansTrainer_BOWKMeansTrainer_int_const_TermCriteriaR_int_int(cluster_count, &termcrit, attempts, flags, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::features2d::BOWKMeansTrainer::opencv_from_extern(ret) };
@@ -1205,6 +1529,36 @@ impl BOWKMeansTrainer {
 	
 }
 
+/// Builder for `BOWKMeansTrainer::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct BOWKMeansTrainer_NewBuilder {
+	cluster_count: i32,
+	termcrit: core::TermCriteria,
+	attempts: i32,
+	flags: i32,
+}
+
+impl BOWKMeansTrainer_NewBuilder {
+	#[inline]
+	pub fn new(cluster_count: i32, termcrit: core::TermCriteria, flags: i32) -> Self {
+		Self { cluster_count, termcrit, attempts: 3, flags }
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_attempts(mut self, attempts: i32) -> Self {
+		self.attempts = attempts;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::features2d::BOWKMeansTrainer> {
+		BOWKMeansTrainer::new(self.cluster_count, self.termcrit, self.attempts, self.flags)
+	}
+}
+
 /// Abstract base class for training the *bag of visual words* vocabulary from a set of descriptors.
 /// 
 /// For details, see, for example, *Visual Categorization with Bags of Keypoints* by Gabriella Csurka,
@@ -1215,8 +1569,9 @@ pub trait BOWTrainerConst {
 	/// Returns a training set of descriptors.
 	#[inline]
 	fn get_descriptors(&self) -> Result<core::Vector<core::Mat>> {
+		crate::core::validity::check("BOWTrainer", self.as_raw_BOWTrainer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BOWTrainer_getDescriptors_const(self.as_raw_BOWTrainer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BOWTrainer_getDescriptors_const(self.as_raw_BOWTrainer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<core::Mat>::opencv_from_extern(ret) };
@@ -1226,8 +1581,9 @@ pub trait BOWTrainerConst {
 	/// Returns the count of all descriptors stored in the training set.
 	#[inline]
 	fn descriptors_count(&self) -> Result<i32> {
+		crate::core::validity::check("BOWTrainer", self.as_raw_BOWTrainer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BOWTrainer_descriptorsCount_const(self.as_raw_BOWTrainer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BOWTrainer_descriptorsCount_const(self.as_raw_BOWTrainer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1246,8 +1602,9 @@ pub trait BOWTrainerConst {
 	/// ## Overloaded parameters
 	#[inline]
 	fn cluster(&self) -> Result<core::Mat> {
+		crate::core::validity::check("BOWTrainer", self.as_raw_BOWTrainer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BOWTrainer_cluster_const(self.as_raw_BOWTrainer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BOWTrainer_cluster_const(self.as_raw_BOWTrainer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -1265,8 +1622,9 @@ pub trait BOWTrainerConst {
 	/// input descriptors are clustered.
 	#[inline]
 	fn cluster_with_descriptors(&self, descriptors: &core::Mat) -> Result<core::Mat> {
+		crate::core::validity::check("BOWTrainer", self.as_raw_BOWTrainer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BOWTrainer_cluster_const_const_MatR(self.as_raw_BOWTrainer(), descriptors.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BOWTrainer_cluster_const_const_MatR(self.as_raw_BOWTrainer(), descriptors.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -1287,8 +1645,9 @@ pub trait BOWTrainer: crate::features2d::BOWTrainerConst {
 	/// The training set is clustered using clustermethod to construct the vocabulary.
 	#[inline]
 	fn add(&mut self, descriptors: &core::Mat) -> Result<()> {
+		crate::core::validity::check("BOWTrainer", self.as_raw_mut_BOWTrainer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BOWTrainer_add_const_MatR(self.as_raw_mut_BOWTrainer(), descriptors.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BOWTrainer_add_const_MatR(self.as_raw_mut_BOWTrainer(), descriptors.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1296,8 +1655,9 @@ pub trait BOWTrainer: crate::features2d::BOWTrainerConst {
 	
 	#[inline]
 	fn clear(&mut self) -> Result<()> {
+		crate::core::validity::check("BOWTrainer", self.as_raw_mut_BOWTrainer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BOWTrainer_clear(self.as_raw_mut_BOWTrainer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BOWTrainer_clear(self.as_raw_mut_BOWTrainer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1311,8 +1671,9 @@ pub trait BRISKTraitConst: crate::features2d::Feature2DTraitConst {
 
 	#[inline]
 	fn get_default_name(&self) -> Result<String> {
+		crate::core::validity::check("BRISK", self.as_raw_BRISK())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BRISK_getDefaultName_const(self.as_raw_BRISK(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BRISK_getDefaultName_const(self.as_raw_BRISK(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -1321,8 +1682,9 @@ pub trait BRISKTraitConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_threshold(&self) -> Result<i32> {
+		crate::core::validity::check("BRISK", self.as_raw_BRISK())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BRISK_getThreshold_const(self.as_raw_BRISK(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BRISK_getThreshold_const(self.as_raw_BRISK(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1330,8 +1692,9 @@ pub trait BRISKTraitConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_octaves(&self) -> Result<i32> {
+		crate::core::validity::check("BRISK", self.as_raw_BRISK())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BRISK_getOctaves_const(self.as_raw_BRISK(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BRISK_getOctaves_const(self.as_raw_BRISK(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1347,8 +1710,9 @@ pub trait BRISKTrait: crate::features2d::BRISKTraitConst + crate::features2d::Fe
 	/// * threshold: AGAST detection threshold score.
 	#[inline]
 	fn set_threshold(&mut self, threshold: i32) -> Result<()> {
+		crate::core::validity::check("BRISK", self.as_raw_mut_BRISK())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BRISK_setThreshold_int(self.as_raw_mut_BRISK(), threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BRISK_setThreshold_int(self.as_raw_mut_BRISK(), threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1359,8 +1723,9 @@ pub trait BRISKTrait: crate::features2d::BRISKTraitConst + crate::features2d::Fe
 	/// * octaves: detection octaves. Use 0 to do single scale.
 	#[inline]
 	fn set_octaves(&mut self, octaves: i32) -> Result<()> {
+		crate::core::validity::check("BRISK", self.as_raw_mut_BRISK())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BRISK_setOctaves_int(self.as_raw_mut_BRISK(), octaves, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BRISK_setOctaves_int(self.as_raw_mut_BRISK(), octaves, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1378,7 +1743,10 @@ opencv_type_boxed! { BRISK }
 impl Drop for BRISK {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BRISK_delete(instance: *mut c_void); }
-		unsafe { cv_BRISK_delete(self.as_raw_mut_BRISK()) };
+		let ptr = self.as_raw_mut_BRISK();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_BRISK_delete(ptr) };
 	}
 }
 
@@ -1424,7 +1792,7 @@ impl BRISK {
 	#[inline]
 	pub fn create(thresh: i32, octaves: i32, pattern_scale: f32) -> Result<core::Ptr<crate::features2d::BRISK>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BRISK_create_int_int_float(thresh, octaves, pattern_scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BRISK_create_int_int_float(thresh, octaves, pattern_scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::features2d::BRISK>::opencv_from_extern(ret) };
@@ -1451,7 +1819,7 @@ impl BRISK {
 	#[inline]
 	pub fn create_with_pattern(radius_list: &core::Vector<f32>, number_list: &core::Vector<i32>, d_max: f32, d_min: f32, index_change: &core::Vector<i32>) -> Result<core::Ptr<crate::features2d::BRISK>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BRISK_create_const_vector_float_R_const_vector_int_R_float_float_const_vector_int_R(radius_list.as_raw_VectorOff32(), number_list.as_raw_VectorOfi32(), d_max, d_min, index_change.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BRISK_create_const_vector_float_R_const_vector_int_R_float_float_const_vector_int_R(radius_list.as_raw_VectorOff32(), number_list.as_raw_VectorOfi32(), d_max, d_min, index_change.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::features2d::BRISK>::opencv_from_extern(ret) };
@@ -1480,7 +1848,7 @@ impl BRISK {
 	#[inline]
 	pub fn create_with_pattern_threshold_octaves(thresh: i32, octaves: i32, radius_list: &core::Vector<f32>, number_list: &core::Vector<i32>, d_max: f32, d_min: f32, index_change: &core::Vector<i32>) -> Result<core::Ptr<crate::features2d::BRISK>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BRISK_create_int_int_const_vector_float_R_const_vector_int_R_float_float_const_vector_int_R(thresh, octaves, radius_list.as_raw_VectorOff32(), number_list.as_raw_VectorOfi32(), d_max, d_min, index_change.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BRISK_create_int_int_const_vector_float_R_const_vector_int_R_float_float_const_vector_int_R(thresh, octaves, radius_list.as_raw_VectorOff32(), number_list.as_raw_VectorOfi32(), d_max, d_min, index_change.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::features2d::BRISK>::opencv_from_extern(ret) };
@@ -1489,6 +1857,134 @@ impl BRISK {
 	
 }
 
+/// Builder for `BRISK::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct BRISK_CreateBuilder {
+	thresh: i32,
+	octaves: i32,
+	pattern_scale: f32,
+}
+
+impl BRISK_CreateBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { thresh: 30, octaves: 3, pattern_scale: 1.0 }
+	}
+	
+	/// C++ default: `30`
+	#[inline]
+	pub fn with_thresh(mut self, thresh: i32) -> Self {
+		self.thresh = thresh;
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_octaves(mut self, octaves: i32) -> Self {
+		self.octaves = octaves;
+		self
+	}
+	
+	/// C++ default: `1.0f`
+	#[inline]
+	pub fn with_pattern_scale(mut self, pattern_scale: f32) -> Self {
+		self.pattern_scale = pattern_scale;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<crate::features2d::BRISK>> {
+		BRISK::create(self.thresh, self.octaves, self.pattern_scale)
+	}
+}
+
+impl Default for BRISK_CreateBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Builder for `BRISK::create_with_pattern()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct BRISK_CreateWithPatternBuilder<'a> {
+	radius_list: &'a core::Vector<f32>,
+	number_list: &'a core::Vector<i32>,
+	d_max: f32,
+	d_min: f32,
+	index_change: &'a core::Vector<i32>,
+}
+
+impl<'a> BRISK_CreateWithPatternBuilder<'a> {
+	#[inline]
+	pub fn new(radius_list: &'a core::Vector<f32>, number_list: &'a core::Vector<i32>, index_change: &'a core::Vector<i32>) -> Self {
+		Self { radius_list, number_list, d_max: 5.85, d_min: 8.2, index_change }
+	}
+	
+	/// C++ default: `5.85f`
+	#[inline]
+	pub fn with_d_max(mut self, d_max: f32) -> Self {
+		self.d_max = d_max;
+		self
+	}
+	
+	/// C++ default: `8.2f`
+	#[inline]
+	pub fn with_d_min(mut self, d_min: f32) -> Self {
+		self.d_min = d_min;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<crate::features2d::BRISK>> {
+		BRISK::create_with_pattern(self.radius_list, self.number_list, self.d_max, self.d_min, self.index_change)
+	}
+}
+
+/// Builder for `BRISK::create_with_pattern_threshold_octaves()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct BRISK_CreateWithPatternThresholdOctavesBuilder<'a> {
+	thresh: i32,
+	octaves: i32,
+	radius_list: &'a core::Vector<f32>,
+	number_list: &'a core::Vector<i32>,
+	d_max: f32,
+	d_min: f32,
+	index_change: &'a core::Vector<i32>,
+}
+
+impl<'a> BRISK_CreateWithPatternThresholdOctavesBuilder<'a> {
+	#[inline]
+	pub fn new(thresh: i32, octaves: i32, radius_list: &'a core::Vector<f32>, number_list: &'a core::Vector<i32>, index_change: &'a core::Vector<i32>) -> Self {
+		Self { thresh, octaves, radius_list, number_list, d_max: 5.85, d_min: 8.2, index_change }
+	}
+	
+	/// C++ default: `5.85f`
+	#[inline]
+	pub fn with_d_max(mut self, d_max: f32) -> Self {
+		self.d_max = d_max;
+		self
+	}
+	
+	/// C++ default: `8.2f`
+	#[inline]
+	pub fn with_d_min(mut self, d_min: f32) -> Self {
+		self.d_min = d_min;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<crate::features2d::BRISK>> {
+		BRISK::create_with_pattern_threshold_octaves(self.thresh, self.octaves, self.radius_list, self.number_list, self.d_max, self.d_min, self.index_change)
+	}
+}
+
 boxed_cast_base! { BRISK, core::Algorithm, cv_BRISK_to_Algorithm }
 
 boxed_cast_base! { BRISK, crate::features2d::Feature2D, cv_BRISK_to_Feature2D }
@@ -1503,8 +1999,9 @@ pub trait DescriptorMatcherConst: core::AlgorithmTraitConst {
 	/// Returns a constant link to the train descriptor collection trainDescCollection .
 	#[inline]
 	fn get_train_descriptors(&self) -> Result<core::Vector<core::Mat>> {
+		crate::core::validity::check("DescriptorMatcher", self.as_raw_DescriptorMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DescriptorMatcher_getTrainDescriptors_const(self.as_raw_DescriptorMatcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DescriptorMatcher_getTrainDescriptors_const(self.as_raw_DescriptorMatcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<core::Mat>::opencv_from_extern(ret) };
@@ -1514,8 +2011,9 @@ pub trait DescriptorMatcherConst: core::AlgorithmTraitConst {
 	/// Returns true if there are no train descriptors in the both collections.
 	#[inline]
 	fn empty(&self) -> Result<bool> {
+		crate::core::validity::check("DescriptorMatcher", self.as_raw_DescriptorMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DescriptorMatcher_empty_const(self.as_raw_DescriptorMatcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DescriptorMatcher_empty_const(self.as_raw_DescriptorMatcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1524,8 +2022,9 @@ pub trait DescriptorMatcherConst: core::AlgorithmTraitConst {
 	/// Returns true if the descriptor matcher supports masking permissible matches.
 	#[inline]
 	fn is_mask_supported(&self) -> Result<bool> {
+		crate::core::validity::check("DescriptorMatcher", self.as_raw_DescriptorMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DescriptorMatcher_isMaskSupported_const(self.as_raw_DescriptorMatcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DescriptorMatcher_isMaskSupported_const(self.as_raw_DescriptorMatcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1545,18 +2044,19 @@ pub trait DescriptorMatcherConst: core::AlgorithmTraitConst {
 	/// In the first variant of this method, the train descriptors are passed as an input argument. In the
 	/// second variant of the method, train descriptors collection that was set by DescriptorMatcher::add is
 	/// used. Optional mask (or masks) can be passed to specify which query and training descriptors can be
-	/// matched. Namely, queryDescriptors[i] can be matched with trainDescriptors[j] only if
+	/// matched. Namely, queryDescriptors\[i\] can be matched with trainDescriptors\[j\] only if
 	/// mask.at\<uchar\>(i,j) is non-zero.
 	/// 
 	/// ## C++ default parameters
 	/// * mask: noArray()
 	#[inline]
 	fn train_match(&self, query_descriptors: &dyn core::ToInputArray, train_descriptors: &dyn core::ToInputArray, matches: &mut core::Vector<core::DMatch>, mask: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("DescriptorMatcher", self.as_raw_DescriptorMatcher())?;
 		input_array_arg!(query_descriptors);
 		input_array_arg!(train_descriptors);
 		input_array_arg!(mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DescriptorMatcher_match_const_const__InputArrayR_const__InputArrayR_vector_DMatch_R_const__InputArrayR(self.as_raw_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), train_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfDMatch(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DescriptorMatcher_match_const_const__InputArrayR_const__InputArrayR_vector_DMatch_R_const__InputArrayR(self.as_raw_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), train_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfDMatch(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1570,7 +2070,7 @@ pub trait DescriptorMatcherConst: core::AlgorithmTraitConst {
 	/// collection stored in the class object.
 	/// * mask: Mask specifying permissible matches between an input query and train matrices of
 	/// descriptors.
-	/// * matches: Matches. Each matches[i] is k or less matches for the same query descriptor.
+	/// * matches: Matches. Each matches\[i\] is k or less matches for the same query descriptor.
 	/// * k: Count of best matches found per each query descriptor or less if a query descriptor has
 	/// less than k possible matches in total.
 	/// * compactResult: Parameter used when the mask (or masks) is not empty. If compactResult is
@@ -1586,11 +2086,12 @@ pub trait DescriptorMatcherConst: core::AlgorithmTraitConst {
 	/// * compact_result: false
 	#[inline]
 	fn knn_train_match(&self, query_descriptors: &dyn core::ToInputArray, train_descriptors: &dyn core::ToInputArray, matches: &mut core::Vector<core::Vector<core::DMatch>>, k: i32, mask: &dyn core::ToInputArray, compact_result: bool) -> Result<()> {
+		crate::core::validity::check("DescriptorMatcher", self.as_raw_DescriptorMatcher())?;
 		input_array_arg!(query_descriptors);
 		input_array_arg!(train_descriptors);
 		input_array_arg!(mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DescriptorMatcher_knnMatch_const_const__InputArrayR_const__InputArrayR_vector_vector_DMatch__R_int_const__InputArrayR_bool(self.as_raw_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), train_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfVectorOfDMatch(), k, mask.as_raw__InputArray(), compact_result, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DescriptorMatcher_knnMatch_const_const__InputArrayR_const__InputArrayR_vector_vector_DMatch__R_int_const__InputArrayR_bool(self.as_raw_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), train_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfVectorOfDMatch(), k, mask.as_raw__InputArray(), compact_result, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1621,11 +2122,12 @@ pub trait DescriptorMatcherConst: core::AlgorithmTraitConst {
 	/// * compact_result: false
 	#[inline]
 	fn radius_train_match(&self, query_descriptors: &dyn core::ToInputArray, train_descriptors: &dyn core::ToInputArray, matches: &mut core::Vector<core::Vector<core::DMatch>>, max_distance: f32, mask: &dyn core::ToInputArray, compact_result: bool) -> Result<()> {
+		crate::core::validity::check("DescriptorMatcher", self.as_raw_DescriptorMatcher())?;
 		input_array_arg!(query_descriptors);
 		input_array_arg!(train_descriptors);
 		input_array_arg!(mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DescriptorMatcher_radiusMatch_const_const__InputArrayR_const__InputArrayR_vector_vector_DMatch__R_float_const__InputArrayR_bool(self.as_raw_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), train_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfVectorOfDMatch(), max_distance, mask.as_raw__InputArray(), compact_result, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DescriptorMatcher_radiusMatch_const_const__InputArrayR_const__InputArrayR_vector_vector_DMatch__R_float_const__InputArrayR_bool(self.as_raw_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), train_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfVectorOfDMatch(), max_distance, mask.as_raw__InputArray(), compact_result, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1633,9 +2135,10 @@ pub trait DescriptorMatcherConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn write(&self, file_name: &str) -> Result<()> {
+		crate::core::validity::check("DescriptorMatcher", self.as_raw_DescriptorMatcher())?;
 		extern_container_arg!(file_name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DescriptorMatcher_write_const_const_StringR(self.as_raw_DescriptorMatcher(), file_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DescriptorMatcher_write_const_const_StringR(self.as_raw_DescriptorMatcher(), file_name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1643,8 +2146,9 @@ pub trait DescriptorMatcherConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn write_1(&self, unnamed: &mut core::FileStorage) -> Result<()> {
+		crate::core::validity::check("DescriptorMatcher", self.as_raw_DescriptorMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DescriptorMatcher_write_const_FileStorageR(self.as_raw_DescriptorMatcher(), unnamed.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DescriptorMatcher_write_const_FileStorageR(self.as_raw_DescriptorMatcher(), unnamed.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1662,8 +2166,9 @@ pub trait DescriptorMatcherConst: core::AlgorithmTraitConst {
 	#[inline]
 	#[must_use]
 	fn clone(&self, empty_train_data: bool) -> Result<core::Ptr<dyn crate::features2d::DescriptorMatcher>> {
+		crate::core::validity::check("DescriptorMatcher", self.as_raw_DescriptorMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DescriptorMatcher_clone_const_bool(self.as_raw_DescriptorMatcher(), empty_train_data, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DescriptorMatcher_clone_const_bool(self.as_raw_DescriptorMatcher(), empty_train_data, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::features2d::DescriptorMatcher>::opencv_from_extern(ret) };
@@ -1674,9 +2179,10 @@ pub trait DescriptorMatcherConst: core::AlgorithmTraitConst {
 	/// * name: String()
 	#[inline]
 	fn write_2(&self, fs: &core::Ptr<core::FileStorage>, name: &str) -> Result<()> {
+		crate::core::validity::check("DescriptorMatcher", self.as_raw_DescriptorMatcher())?;
 		extern_container_arg!(name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DescriptorMatcher_write_const_const_Ptr_FileStorage_R_const_StringR(self.as_raw_DescriptorMatcher(), fs.as_raw_PtrOfFileStorage(), name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DescriptorMatcher_write_const_const_Ptr_FileStorage_R_const_StringR(self.as_raw_DescriptorMatcher(), fs.as_raw_PtrOfFileStorage(), name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1684,6 +2190,90 @@ pub trait DescriptorMatcherConst: core::AlgorithmTraitConst {
 	
 }
 
+/// Builder for `DescriptorMatcherConst::knn_train_match()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct DescriptorMatcher_KnnTrainMatchBuilder<'a, T: ?Sized + DescriptorMatcherConst> {
+	instance: &'a T,
+	query_descriptors: &'a dyn core::ToInputArray,
+	train_descriptors: &'a dyn core::ToInputArray,
+	matches: &'a mut core::Vector<core::Vector<core::DMatch>>,
+	k: i32,
+	mask: Option<&'a dyn core::ToInputArray>,
+	compact_result: bool,
+}
+
+impl<'a, T: ?Sized + DescriptorMatcherConst> DescriptorMatcher_KnnTrainMatchBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a T, query_descriptors: &'a dyn core::ToInputArray, train_descriptors: &'a dyn core::ToInputArray, matches: &'a mut core::Vector<core::Vector<core::DMatch>>, k: i32) -> Self {
+		Self { instance, query_descriptors, train_descriptors, matches, k, mask: None, compact_result: false }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_mask(mut self, mask: &'a dyn core::ToInputArray) -> Self {
+		self.mask = Some(mask);
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_compact_result(mut self, compact_result: bool) -> Self {
+		self.compact_result = compact_result;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let mask_no_array = core::no_array();
+		let mask: &dyn core::ToInputArray = match self.mask { Some(mask) => mask, None => &mask_no_array };
+		DescriptorMatcherConst::knn_train_match(self.instance, self.query_descriptors, self.train_descriptors, self.matches, self.k, mask, self.compact_result)
+	}
+}
+
+/// Builder for `DescriptorMatcherConst::radius_train_match()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct DescriptorMatcher_RadiusTrainMatchBuilder<'a, T: ?Sized + DescriptorMatcherConst> {
+	instance: &'a T,
+	query_descriptors: &'a dyn core::ToInputArray,
+	train_descriptors: &'a dyn core::ToInputArray,
+	matches: &'a mut core::Vector<core::Vector<core::DMatch>>,
+	max_distance: f32,
+	mask: Option<&'a dyn core::ToInputArray>,
+	compact_result: bool,
+}
+
+impl<'a, T: ?Sized + DescriptorMatcherConst> DescriptorMatcher_RadiusTrainMatchBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a T, query_descriptors: &'a dyn core::ToInputArray, train_descriptors: &'a dyn core::ToInputArray, matches: &'a mut core::Vector<core::Vector<core::DMatch>>, max_distance: f32) -> Self {
+		Self { instance, query_descriptors, train_descriptors, matches, max_distance, mask: None, compact_result: false }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_mask(mut self, mask: &'a dyn core::ToInputArray) -> Self {
+		self.mask = Some(mask);
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_compact_result(mut self, compact_result: bool) -> Self {
+		self.compact_result = compact_result;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let mask_no_array = core::no_array();
+		let mask: &dyn core::ToInputArray = match self.mask { Some(mask) => mask, None => &mask_no_array };
+		DescriptorMatcherConst::radius_train_match(self.instance, self.query_descriptors, self.train_descriptors, self.matches, self.max_distance, mask, self.compact_result)
+	}
+}
+
 pub trait DescriptorMatcher: core::AlgorithmTrait + crate::features2d::DescriptorMatcherConst {
 	fn as_raw_mut_DescriptorMatcher(&mut self) -> *mut c_void;
 
@@ -1693,13 +2283,14 @@ pub trait DescriptorMatcher: core::AlgorithmTrait + crate::features2d::Descripto
 	/// If the collection is not empty, the new descriptors are added to existing train descriptors.
 	/// 
 	/// ## Parameters
-	/// * descriptors: Descriptors to add. Each descriptors[i] is a set of descriptors from the same
+	/// * descriptors: Descriptors to add. Each descriptors\[i\] is a set of descriptors from the same
 	/// train image.
 	#[inline]
 	fn add(&mut self, descriptors: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("DescriptorMatcher", self.as_raw_mut_DescriptorMatcher())?;
 		input_array_arg!(descriptors);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DescriptorMatcher_add_const__InputArrayR(self.as_raw_mut_DescriptorMatcher(), descriptors.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DescriptorMatcher_add_const__InputArrayR(self.as_raw_mut_DescriptorMatcher(), descriptors.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1708,8 +2299,9 @@ pub trait DescriptorMatcher: core::AlgorithmTrait + crate::features2d::Descripto
 	/// Clears the train descriptor collections.
 	#[inline]
 	fn clear(&mut self) -> Result<()> {
+		crate::core::validity::check("DescriptorMatcher", self.as_raw_mut_DescriptorMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DescriptorMatcher_clear(self.as_raw_mut_DescriptorMatcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DescriptorMatcher_clear(self.as_raw_mut_DescriptorMatcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1723,8 +2315,9 @@ pub trait DescriptorMatcher: core::AlgorithmTrait + crate::features2d::Descripto
 	/// example, FlannBasedMatcher trains flann::Index ).
 	#[inline]
 	fn train(&mut self) -> Result<()> {
+		crate::core::validity::check("DescriptorMatcher", self.as_raw_mut_DescriptorMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DescriptorMatcher_train(self.as_raw_mut_DescriptorMatcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DescriptorMatcher_train(self.as_raw_mut_DescriptorMatcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1744,7 +2337,7 @@ pub trait DescriptorMatcher: core::AlgorithmTrait + crate::features2d::Descripto
 	/// In the first variant of this method, the train descriptors are passed as an input argument. In the
 	/// second variant of the method, train descriptors collection that was set by DescriptorMatcher::add is
 	/// used. Optional mask (or masks) can be passed to specify which query and training descriptors can be
-	/// matched. Namely, queryDescriptors[i] can be matched with trainDescriptors[j] only if
+	/// matched. Namely, queryDescriptors\[i\] can be matched with trainDescriptors\[j\] only if
 	/// mask.at\<uchar\>(i,j) is non-zero.
 	/// 
 	/// ## Overloaded parameters
@@ -1752,17 +2345,18 @@ pub trait DescriptorMatcher: core::AlgorithmTrait + crate::features2d::Descripto
 	/// * queryDescriptors: Query set of descriptors.
 	/// * matches: Matches. If a query descriptor is masked out in mask , no match is added for this
 	///    descriptor. So, matches size may be smaller than the query descriptors count.
-	/// * masks: Set of masks. Each masks[i] specifies permissible matches between the input query
-	///    descriptors and stored train descriptors from the i-th image trainDescCollection[i].
+	/// * masks: Set of masks. Each masks\[i\] specifies permissible matches between the input query
+	///    descriptors and stored train descriptors from the i-th image trainDescCollection\[i\].
 	/// 
 	/// ## C++ default parameters
 	/// * masks: noArray()
 	#[inline]
 	fn match_(&mut self, query_descriptors: &dyn core::ToInputArray, matches: &mut core::Vector<core::DMatch>, masks: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("DescriptorMatcher", self.as_raw_mut_DescriptorMatcher())?;
 		input_array_arg!(query_descriptors);
 		input_array_arg!(masks);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DescriptorMatcher_match_const__InputArrayR_vector_DMatch_R_const__InputArrayR(self.as_raw_mut_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfDMatch(), masks.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DescriptorMatcher_match_const__InputArrayR_vector_DMatch_R_const__InputArrayR(self.as_raw_mut_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfDMatch(), masks.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1776,7 +2370,7 @@ pub trait DescriptorMatcher: core::AlgorithmTrait + crate::features2d::Descripto
 	/// collection stored in the class object.
 	/// * mask: Mask specifying permissible matches between an input query and train matrices of
 	/// descriptors.
-	/// * matches: Matches. Each matches[i] is k or less matches for the same query descriptor.
+	/// * matches: Matches. Each matches\[i\] is k or less matches for the same query descriptor.
 	/// * k: Count of best matches found per each query descriptor or less if a query descriptor has
 	/// less than k possible matches in total.
 	/// * compactResult: Parameter used when the mask (or masks) is not empty. If compactResult is
@@ -1790,11 +2384,11 @@ pub trait DescriptorMatcher: core::AlgorithmTrait + crate::features2d::Descripto
 	/// ## Overloaded parameters
 	/// 
 	/// * queryDescriptors: Query set of descriptors.
-	/// * matches: Matches. Each matches[i] is k or less matches for the same query descriptor.
+	/// * matches: Matches. Each matches\[i\] is k or less matches for the same query descriptor.
 	/// * k: Count of best matches found per each query descriptor or less if a query descriptor has
 	///    less than k possible matches in total.
-	/// * masks: Set of masks. Each masks[i] specifies permissible matches between the input query
-	///    descriptors and stored train descriptors from the i-th image trainDescCollection[i].
+	/// * masks: Set of masks. Each masks\[i\] specifies permissible matches between the input query
+	///    descriptors and stored train descriptors from the i-th image trainDescCollection\[i\].
 	/// * compactResult: Parameter used when the mask (or masks) is not empty. If compactResult is
 	///    false, the matches vector has the same size as queryDescriptors rows. If compactResult is true,
 	///    the matches vector does not contain matches for fully masked-out query descriptors.
@@ -1804,10 +2398,11 @@ pub trait DescriptorMatcher: core::AlgorithmTrait + crate::features2d::Descripto
 	/// * compact_result: false
 	#[inline]
 	fn knn_match(&mut self, query_descriptors: &dyn core::ToInputArray, matches: &mut core::Vector<core::Vector<core::DMatch>>, k: i32, masks: &dyn core::ToInputArray, compact_result: bool) -> Result<()> {
+		crate::core::validity::check("DescriptorMatcher", self.as_raw_mut_DescriptorMatcher())?;
 		input_array_arg!(query_descriptors);
 		input_array_arg!(masks);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DescriptorMatcher_knnMatch_const__InputArrayR_vector_vector_DMatch__R_int_const__InputArrayR_bool(self.as_raw_mut_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfVectorOfDMatch(), k, masks.as_raw__InputArray(), compact_result, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DescriptorMatcher_knnMatch_const__InputArrayR_vector_vector_DMatch__R_int_const__InputArrayR_bool(self.as_raw_mut_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfVectorOfDMatch(), k, masks.as_raw__InputArray(), compact_result, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1840,8 +2435,8 @@ pub trait DescriptorMatcher: core::AlgorithmTrait + crate::features2d::Descripto
 	/// * maxDistance: Threshold for the distance between matched descriptors. Distance means here
 	///    metric distance (e.g. Hamming distance), not the distance between coordinates (which is measured
 	///    in Pixels)!
-	/// * masks: Set of masks. Each masks[i] specifies permissible matches between the input query
-	///    descriptors and stored train descriptors from the i-th image trainDescCollection[i].
+	/// * masks: Set of masks. Each masks\[i\] specifies permissible matches between the input query
+	///    descriptors and stored train descriptors from the i-th image trainDescCollection\[i\].
 	/// * compactResult: Parameter used when the mask (or masks) is not empty. If compactResult is
 	///    false, the matches vector has the same size as queryDescriptors rows. If compactResult is true,
 	///    the matches vector does not contain matches for fully masked-out query descriptors.
@@ -1851,10 +2446,11 @@ pub trait DescriptorMatcher: core::AlgorithmTrait + crate::features2d::Descripto
 	/// * compact_result: false
 	#[inline]
 	fn radius_match(&mut self, query_descriptors: &dyn core::ToInputArray, matches: &mut core::Vector<core::Vector<core::DMatch>>, max_distance: f32, masks: &dyn core::ToInputArray, compact_result: bool) -> Result<()> {
+		crate::core::validity::check("DescriptorMatcher", self.as_raw_mut_DescriptorMatcher())?;
 		input_array_arg!(query_descriptors);
 		input_array_arg!(masks);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DescriptorMatcher_radiusMatch_const__InputArrayR_vector_vector_DMatch__R_float_const__InputArrayR_bool(self.as_raw_mut_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfVectorOfDMatch(), max_distance, masks.as_raw__InputArray(), compact_result, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DescriptorMatcher_radiusMatch_const__InputArrayR_vector_vector_DMatch__R_float_const__InputArrayR_bool(self.as_raw_mut_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfVectorOfDMatch(), max_distance, masks.as_raw__InputArray(), compact_result, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1862,9 +2458,10 @@ pub trait DescriptorMatcher: core::AlgorithmTrait + crate::features2d::Descripto
 	
 	#[inline]
 	fn read(&mut self, file_name: &str) -> Result<()> {
+		crate::core::validity::check("DescriptorMatcher", self.as_raw_mut_DescriptorMatcher())?;
 		extern_container_arg!(file_name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DescriptorMatcher_read_const_StringR(self.as_raw_mut_DescriptorMatcher(), file_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DescriptorMatcher_read_const_StringR(self.as_raw_mut_DescriptorMatcher(), file_name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1872,8 +2469,9 @@ pub trait DescriptorMatcher: core::AlgorithmTrait + crate::features2d::Descripto
 	
 	#[inline]
 	fn read_1(&mut self, unnamed: &core::FileNode) -> Result<()> {
+		crate::core::validity::check("DescriptorMatcher", self.as_raw_mut_DescriptorMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DescriptorMatcher_read_const_FileNodeR(self.as_raw_mut_DescriptorMatcher(), unnamed.as_raw_FileNode(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DescriptorMatcher_read_const_FileNodeR(self.as_raw_mut_DescriptorMatcher(), unnamed.as_raw_FileNode(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1897,7 +2495,7 @@ impl dyn DescriptorMatcher + '_ {
 	pub fn create(descriptor_matcher_type: &str) -> Result<core::Ptr<dyn crate::features2d::DescriptorMatcher>> {
 		extern_container_arg!(descriptor_matcher_type);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DescriptorMatcher_create_const_StringR(descriptor_matcher_type.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DescriptorMatcher_create_const_StringR(descriptor_matcher_type.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::features2d::DescriptorMatcher>::opencv_from_extern(ret) };
@@ -1907,7 +2505,7 @@ impl dyn DescriptorMatcher + '_ {
 	#[inline]
 	pub fn create_with_matcher_type(matcher_type: crate::features2d::DescriptorMatcher_MatcherType) -> Result<core::Ptr<dyn crate::features2d::DescriptorMatcher>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DescriptorMatcher_create_const_MatcherTypeR(&matcher_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DescriptorMatcher_create_const_MatcherTypeR(&matcher_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::features2d::DescriptorMatcher>::opencv_from_extern(ret) };
@@ -1921,8 +2519,9 @@ pub trait FastFeatureDetectorConst: crate::features2d::Feature2DTraitConst {
 
 	#[inline]
 	fn get_threshold(&self) -> Result<i32> {
+		crate::core::validity::check("FastFeatureDetector", self.as_raw_FastFeatureDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FastFeatureDetector_getThreshold_const(self.as_raw_FastFeatureDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FastFeatureDetector_getThreshold_const(self.as_raw_FastFeatureDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1930,8 +2529,9 @@ pub trait FastFeatureDetectorConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_nonmax_suppression(&self) -> Result<bool> {
+		crate::core::validity::check("FastFeatureDetector", self.as_raw_FastFeatureDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FastFeatureDetector_getNonmaxSuppression_const(self.as_raw_FastFeatureDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FastFeatureDetector_getNonmaxSuppression_const(self.as_raw_FastFeatureDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1939,8 +2539,9 @@ pub trait FastFeatureDetectorConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_type(&self) -> Result<crate::features2d::FastFeatureDetector_DetectorType> {
+		crate::core::validity::check("FastFeatureDetector", self.as_raw_FastFeatureDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FastFeatureDetector_getType_const(self.as_raw_FastFeatureDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FastFeatureDetector_getType_const(self.as_raw_FastFeatureDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1948,8 +2549,9 @@ pub trait FastFeatureDetectorConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_default_name(&self) -> Result<String> {
+		crate::core::validity::check("FastFeatureDetector", self.as_raw_FastFeatureDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FastFeatureDetector_getDefaultName_const(self.as_raw_FastFeatureDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FastFeatureDetector_getDefaultName_const(self.as_raw_FastFeatureDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -1963,8 +2565,9 @@ pub trait FastFeatureDetector: crate::features2d::FastFeatureDetectorConst + cra
 
 	#[inline]
 	fn set_threshold(&mut self, threshold: i32) -> Result<()> {
+		crate::core::validity::check("FastFeatureDetector", self.as_raw_mut_FastFeatureDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FastFeatureDetector_setThreshold_int(self.as_raw_mut_FastFeatureDetector(), threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FastFeatureDetector_setThreshold_int(self.as_raw_mut_FastFeatureDetector(), threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1972,8 +2575,9 @@ pub trait FastFeatureDetector: crate::features2d::FastFeatureDetectorConst + cra
 	
 	#[inline]
 	fn set_nonmax_suppression(&mut self, f: bool) -> Result<()> {
+		crate::core::validity::check("FastFeatureDetector", self.as_raw_mut_FastFeatureDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FastFeatureDetector_setNonmaxSuppression_bool(self.as_raw_mut_FastFeatureDetector(), f, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FastFeatureDetector_setNonmaxSuppression_bool(self.as_raw_mut_FastFeatureDetector(), f, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1981,8 +2585,9 @@ pub trait FastFeatureDetector: crate::features2d::FastFeatureDetectorConst + cra
 	
 	#[inline]
 	fn set_type(&mut self, typ: crate::features2d::FastFeatureDetector_DetectorType) -> Result<()> {
+		crate::core::validity::check("FastFeatureDetector", self.as_raw_mut_FastFeatureDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FastFeatureDetector_setType_DetectorType(self.as_raw_mut_FastFeatureDetector(), typ, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FastFeatureDetector_setType_DetectorType(self.as_raw_mut_FastFeatureDetector(), typ, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1998,7 +2603,7 @@ impl dyn FastFeatureDetector + '_ {
 	#[inline]
 	pub fn create(threshold: i32, nonmax_suppression: bool, typ: crate::features2d::FastFeatureDetector_DetectorType) -> Result<core::Ptr<dyn crate::features2d::FastFeatureDetector>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FastFeatureDetector_create_int_bool_DetectorType(threshold, nonmax_suppression, typ, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FastFeatureDetector_create_int_bool_DetectorType(threshold, nonmax_suppression, typ, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::features2d::FastFeatureDetector>::opencv_from_extern(ret) };
@@ -2006,13 +2611,50 @@ impl dyn FastFeatureDetector + '_ {
 	}
 	
 }
+
+/// Builder for `<dyn FastFeatureDetector>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FastFeatureDetector_CreateBuilder {
+	threshold: i32,
+	nonmax_suppression: bool,
+	typ: crate::features2d::FastFeatureDetector_DetectorType,
+}
+
+impl FastFeatureDetector_CreateBuilder {
+	#[inline]
+	pub fn new(typ: crate::features2d::FastFeatureDetector_DetectorType) -> Self {
+		Self { threshold: 10, nonmax_suppression: true, typ }
+	}
+	
+	/// C++ default: `10`
+	#[inline]
+	pub fn with_threshold(mut self, threshold: i32) -> Self {
+		self.threshold = threshold;
+		self
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_nonmax_suppression(mut self, nonmax_suppression: bool) -> Self {
+		self.nonmax_suppression = nonmax_suppression;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::features2d::FastFeatureDetector>> {
+		<dyn FastFeatureDetector>::create(self.threshold, self.nonmax_suppression, self.typ)
+	}
+}
 pub trait Feature2DTraitConst: core::AlgorithmTraitConst {
 	fn as_raw_Feature2D(&self) -> *const c_void;
 
 	#[inline]
 	fn descriptor_size(&self) -> Result<i32> {
+		crate::core::validity::check("Feature2D", self.as_raw_Feature2D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Feature2D_descriptorSize_const(self.as_raw_Feature2D(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Feature2D_descriptorSize_const(self.as_raw_Feature2D(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2020,8 +2662,9 @@ pub trait Feature2DTraitConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn descriptor_type(&self) -> Result<i32> {
+		crate::core::validity::check("Feature2D", self.as_raw_Feature2D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Feature2D_descriptorType_const(self.as_raw_Feature2D(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Feature2D_descriptorType_const(self.as_raw_Feature2D(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2029,8 +2672,9 @@ pub trait Feature2DTraitConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn default_norm(&self) -> Result<i32> {
+		crate::core::validity::check("Feature2D", self.as_raw_Feature2D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Feature2D_defaultNorm_const(self.as_raw_Feature2D(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Feature2D_defaultNorm_const(self.as_raw_Feature2D(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2038,9 +2682,10 @@ pub trait Feature2DTraitConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn write(&self, file_name: &str) -> Result<()> {
+		crate::core::validity::check("Feature2D", self.as_raw_Feature2D())?;
 		extern_container_arg!(file_name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Feature2D_write_const_const_StringR(self.as_raw_Feature2D(), file_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Feature2D_write_const_const_StringR(self.as_raw_Feature2D(), file_name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2048,8 +2693,9 @@ pub trait Feature2DTraitConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn write_1(&self, unnamed: &mut core::FileStorage) -> Result<()> {
+		crate::core::validity::check("Feature2D", self.as_raw_Feature2D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Feature2D_write_const_FileStorageR(self.as_raw_Feature2D(), unnamed.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Feature2D_write_const_FileStorageR(self.as_raw_Feature2D(), unnamed.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2058,8 +2704,9 @@ pub trait Feature2DTraitConst: core::AlgorithmTraitConst {
 	/// Return true if detector object is empty
 	#[inline]
 	fn empty(&self) -> Result<bool> {
+		crate::core::validity::check("Feature2D", self.as_raw_Feature2D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Feature2D_empty_const(self.as_raw_Feature2D(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Feature2D_empty_const(self.as_raw_Feature2D(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2067,8 +2714,9 @@ pub trait Feature2DTraitConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_default_name(&self) -> Result<String> {
+		crate::core::validity::check("Feature2D", self.as_raw_Feature2D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Feature2D_getDefaultName_const(self.as_raw_Feature2D(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Feature2D_getDefaultName_const(self.as_raw_Feature2D(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -2079,9 +2727,10 @@ pub trait Feature2DTraitConst: core::AlgorithmTraitConst {
 	/// * name: String()
 	#[inline]
 	fn write_2(&self, fs: &core::Ptr<core::FileStorage>, name: &str) -> Result<()> {
+		crate::core::validity::check("Feature2D", self.as_raw_Feature2D())?;
 		extern_container_arg!(name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Feature2D_write_const_const_Ptr_FileStorage_R_const_StringR(self.as_raw_Feature2D(), fs.as_raw_PtrOfFileStorage(), name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Feature2D_write_const_const_Ptr_FileStorage_R_const_StringR(self.as_raw_Feature2D(), fs.as_raw_PtrOfFileStorage(), name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2096,8 +2745,8 @@ pub trait Feature2DTrait: core::AlgorithmTrait + crate::features2d::Feature2DTra
 	/// 
 	/// ## Parameters
 	/// * image: Image.
-	/// * keypoints: The detected keypoints. In the second variant of the method keypoints[i] is a set
-	/// of keypoints detected in images[i] .
+	/// * keypoints: The detected keypoints. In the second variant of the method keypoints\[i\] is a set
+	/// of keypoints detected in images\[i\] .
 	/// * mask: Mask specifying where to look for keypoints (optional). It must be a 8-bit integer
 	/// matrix with non-zero values in the region of interest.
 	/// 
@@ -2105,10 +2754,11 @@ pub trait Feature2DTrait: core::AlgorithmTrait + crate::features2d::Feature2DTra
 	/// * mask: noArray()
 	#[inline]
 	fn detect(&mut self, image: &dyn core::ToInputArray, keypoints: &mut core::Vector<core::KeyPoint>, mask: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("Feature2D", self.as_raw_mut_Feature2D())?;
 		input_array_arg!(image);
 		input_array_arg!(mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Feature2D_detect_const__InputArrayR_vector_KeyPoint_R_const__InputArrayR(self.as_raw_mut_Feature2D(), image.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfKeyPoint(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Feature2D_detect_const__InputArrayR_vector_KeyPoint_R_const__InputArrayR(self.as_raw_mut_Feature2D(), image.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfKeyPoint(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2118,27 +2768,28 @@ pub trait Feature2DTrait: core::AlgorithmTrait + crate::features2d::Feature2DTra
 	/// 
 	/// ## Parameters
 	/// * image: Image.
-	/// * keypoints: The detected keypoints. In the second variant of the method keypoints[i] is a set
-	/// of keypoints detected in images[i] .
+	/// * keypoints: The detected keypoints. In the second variant of the method keypoints\[i\] is a set
+	/// of keypoints detected in images\[i\] .
 	/// * mask: Mask specifying where to look for keypoints (optional). It must be a 8-bit integer
 	/// matrix with non-zero values in the region of interest.
 	/// 
 	/// ## Overloaded parameters
 	/// 
 	/// * images: Image set.
-	/// * keypoints: The detected keypoints. In the second variant of the method keypoints[i] is a set
-	///    of keypoints detected in images[i] .
+	/// * keypoints: The detected keypoints. In the second variant of the method keypoints\[i\] is a set
+	///    of keypoints detected in images\[i\] .
 	/// * masks: Masks for each input image specifying where to look for keypoints (optional).
-	///    masks[i] is a mask for images[i].
+	///    masks\[i\] is a mask for images\[i\].
 	/// 
 	/// ## C++ default parameters
 	/// * masks: noArray()
 	#[inline]
 	fn detect_multiple(&mut self, images: &dyn core::ToInputArray, keypoints: &mut core::Vector<core::Vector<core::KeyPoint>>, masks: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("Feature2D", self.as_raw_mut_Feature2D())?;
 		input_array_arg!(images);
 		input_array_arg!(masks);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Feature2D_detect_const__InputArrayR_vector_vector_KeyPoint__R_const__InputArrayR(self.as_raw_mut_Feature2D(), images.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfVectorOfKeyPoint(), masks.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Feature2D_detect_const__InputArrayR_vector_vector_KeyPoint__R_const__InputArrayR(self.as_raw_mut_Feature2D(), images.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfVectorOfKeyPoint(), masks.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2152,15 +2803,16 @@ pub trait Feature2DTrait: core::AlgorithmTrait + crate::features2d::Feature2DTra
 	/// * keypoints: Input collection of keypoints. Keypoints for which a descriptor cannot be
 	/// computed are removed. Sometimes new keypoints can be added, for example: SIFT duplicates keypoint
 	/// with several dominant orientations (for each orientation).
-	/// * descriptors: Computed descriptors. In the second variant of the method descriptors[i] are
-	/// descriptors computed for a keypoints[i]. Row j is the keypoints (or keypoints[i]) is the
+	/// * descriptors: Computed descriptors. In the second variant of the method descriptors\[i\] are
+	/// descriptors computed for a keypoints\[i\]. Row j is the keypoints (or keypoints\[i\]) is the
 	/// descriptor for keypoint j-th keypoint.
 	#[inline]
 	fn compute(&mut self, image: &dyn core::ToInputArray, keypoints: &mut core::Vector<core::KeyPoint>, descriptors: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("Feature2D", self.as_raw_mut_Feature2D())?;
 		input_array_arg!(image);
 		output_array_arg!(descriptors);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Feature2D_compute_const__InputArrayR_vector_KeyPoint_R_const__OutputArrayR(self.as_raw_mut_Feature2D(), image.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfKeyPoint(), descriptors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Feature2D_compute_const__InputArrayR_vector_KeyPoint_R_const__OutputArrayR(self.as_raw_mut_Feature2D(), image.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfKeyPoint(), descriptors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2174,8 +2826,8 @@ pub trait Feature2DTrait: core::AlgorithmTrait + crate::features2d::Feature2DTra
 	/// * keypoints: Input collection of keypoints. Keypoints for which a descriptor cannot be
 	/// computed are removed. Sometimes new keypoints can be added, for example: SIFT duplicates keypoint
 	/// with several dominant orientations (for each orientation).
-	/// * descriptors: Computed descriptors. In the second variant of the method descriptors[i] are
-	/// descriptors computed for a keypoints[i]. Row j is the keypoints (or keypoints[i]) is the
+	/// * descriptors: Computed descriptors. In the second variant of the method descriptors\[i\] are
+	/// descriptors computed for a keypoints\[i\]. Row j is the keypoints (or keypoints\[i\]) is the
 	/// descriptor for keypoint j-th keypoint.
 	/// 
 	/// ## Overloaded parameters
@@ -2185,15 +2837,16 @@ pub trait Feature2DTrait: core::AlgorithmTrait + crate::features2d::Feature2DTra
 	/// * keypoints: Input collection of keypoints. Keypoints for which a descriptor cannot be
 	///    computed are removed. Sometimes new keypoints can be added, for example: SIFT duplicates keypoint
 	///    with several dominant orientations (for each orientation).
-	/// * descriptors: Computed descriptors. In the second variant of the method descriptors[i] are
-	///    descriptors computed for a keypoints[i]. Row j is the keypoints (or keypoints[i]) is the
+	/// * descriptors: Computed descriptors. In the second variant of the method descriptors\[i\] are
+	///    descriptors computed for a keypoints\[i\]. Row j is the keypoints (or keypoints\[i\]) is the
 	///    descriptor for keypoint j-th keypoint.
 	#[inline]
 	fn compute_multiple(&mut self, images: &dyn core::ToInputArray, keypoints: &mut core::Vector<core::Vector<core::KeyPoint>>, descriptors: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("Feature2D", self.as_raw_mut_Feature2D())?;
 		input_array_arg!(images);
 		output_array_arg!(descriptors);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Feature2D_compute_const__InputArrayR_vector_vector_KeyPoint__R_const__OutputArrayR(self.as_raw_mut_Feature2D(), images.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfVectorOfKeyPoint(), descriptors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Feature2D_compute_const__InputArrayR_vector_vector_KeyPoint__R_const__OutputArrayR(self.as_raw_mut_Feature2D(), images.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfVectorOfKeyPoint(), descriptors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2205,11 +2858,12 @@ pub trait Feature2DTrait: core::AlgorithmTrait + crate::features2d::Feature2DTra
 	/// * use_provided_keypoints: false
 	#[inline]
 	fn detect_and_compute(&mut self, image: &dyn core::ToInputArray, mask: &dyn core::ToInputArray, keypoints: &mut core::Vector<core::KeyPoint>, descriptors: &mut dyn core::ToOutputArray, use_provided_keypoints: bool) -> Result<()> {
+		crate::core::validity::check("Feature2D", self.as_raw_mut_Feature2D())?;
 		input_array_arg!(image);
 		input_array_arg!(mask);
 		output_array_arg!(descriptors);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Feature2D_detectAndCompute_const__InputArrayR_const__InputArrayR_vector_KeyPoint_R_const__OutputArrayR_bool(self.as_raw_mut_Feature2D(), image.as_raw__InputArray(), mask.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfKeyPoint(), descriptors.as_raw__OutputArray(), use_provided_keypoints, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Feature2D_detectAndCompute_const__InputArrayR_const__InputArrayR_vector_KeyPoint_R_const__OutputArrayR_bool(self.as_raw_mut_Feature2D(), image.as_raw__InputArray(), mask.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfKeyPoint(), descriptors.as_raw__OutputArray(), use_provided_keypoints, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2217,9 +2871,10 @@ pub trait Feature2DTrait: core::AlgorithmTrait + crate::features2d::Feature2DTra
 	
 	#[inline]
 	fn read(&mut self, file_name: &str) -> Result<()> {
+		crate::core::validity::check("Feature2D", self.as_raw_mut_Feature2D())?;
 		extern_container_arg!(file_name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Feature2D_read_const_StringR(self.as_raw_mut_Feature2D(), file_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Feature2D_read_const_StringR(self.as_raw_mut_Feature2D(), file_name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2227,8 +2882,9 @@ pub trait Feature2DTrait: core::AlgorithmTrait + crate::features2d::Feature2DTra
 	
 	#[inline]
 	fn read_1(&mut self, unnamed: &core::FileNode) -> Result<()> {
+		crate::core::validity::check("Feature2D", self.as_raw_mut_Feature2D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Feature2D_read_const_FileNodeR(self.as_raw_mut_Feature2D(), unnamed.as_raw_FileNode(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Feature2D_read_const_FileNodeR(self.as_raw_mut_Feature2D(), unnamed.as_raw_FileNode(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2245,7 +2901,10 @@ opencv_type_boxed! { Feature2D }
 impl Drop for Feature2D {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Feature2D_delete(instance: *mut c_void); }
-		unsafe { cv_Feature2D_delete(self.as_raw_mut_Feature2D()) };
+		let ptr = self.as_raw_mut_Feature2D();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Feature2D_delete(ptr) };
 	}
 }
 
@@ -2289,8 +2948,9 @@ pub trait FlannBasedMatcherTraitConst: crate::features2d::DescriptorMatcherConst
 
 	#[inline]
 	fn write(&self, unnamed: &mut core::FileStorage) -> Result<()> {
+		crate::core::validity::check("FlannBasedMatcher", self.as_raw_FlannBasedMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FlannBasedMatcher_write_const_FileStorageR(self.as_raw_FlannBasedMatcher(), unnamed.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FlannBasedMatcher_write_const_FileStorageR(self.as_raw_FlannBasedMatcher(), unnamed.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2298,8 +2958,9 @@ pub trait FlannBasedMatcherTraitConst: crate::features2d::DescriptorMatcherConst
 	
 	#[inline]
 	fn is_mask_supported(&self) -> Result<bool> {
+		crate::core::validity::check("FlannBasedMatcher", self.as_raw_FlannBasedMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FlannBasedMatcher_isMaskSupported_const(self.as_raw_FlannBasedMatcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FlannBasedMatcher_isMaskSupported_const(self.as_raw_FlannBasedMatcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2310,8 +2971,9 @@ pub trait FlannBasedMatcherTraitConst: crate::features2d::DescriptorMatcherConst
 	#[inline]
 	#[must_use]
 	fn clone(&self, empty_train_data: bool) -> Result<core::Ptr<dyn crate::features2d::DescriptorMatcher>> {
+		crate::core::validity::check("FlannBasedMatcher", self.as_raw_FlannBasedMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FlannBasedMatcher_clone_const_bool(self.as_raw_FlannBasedMatcher(), empty_train_data, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FlannBasedMatcher_clone_const_bool(self.as_raw_FlannBasedMatcher(), empty_train_data, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::features2d::DescriptorMatcher>::opencv_from_extern(ret) };
@@ -2325,9 +2987,10 @@ pub trait FlannBasedMatcherTrait: crate::features2d::DescriptorMatcher + crate::
 
 	#[inline]
 	fn add(&mut self, descriptors: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("FlannBasedMatcher", self.as_raw_mut_FlannBasedMatcher())?;
 		input_array_arg!(descriptors);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FlannBasedMatcher_add_const__InputArrayR(self.as_raw_mut_FlannBasedMatcher(), descriptors.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FlannBasedMatcher_add_const__InputArrayR(self.as_raw_mut_FlannBasedMatcher(), descriptors.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2335,8 +2998,9 @@ pub trait FlannBasedMatcherTrait: crate::features2d::DescriptorMatcher + crate::
 	
 	#[inline]
 	fn clear(&mut self) -> Result<()> {
+		crate::core::validity::check("FlannBasedMatcher", self.as_raw_mut_FlannBasedMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FlannBasedMatcher_clear(self.as_raw_mut_FlannBasedMatcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FlannBasedMatcher_clear(self.as_raw_mut_FlannBasedMatcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2344,8 +3008,9 @@ pub trait FlannBasedMatcherTrait: crate::features2d::DescriptorMatcher + crate::
 	
 	#[inline]
 	fn read(&mut self, unnamed: &core::FileNode) -> Result<()> {
+		crate::core::validity::check("FlannBasedMatcher", self.as_raw_mut_FlannBasedMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FlannBasedMatcher_read_const_FileNodeR(self.as_raw_mut_FlannBasedMatcher(), unnamed.as_raw_FileNode(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FlannBasedMatcher_read_const_FileNodeR(self.as_raw_mut_FlannBasedMatcher(), unnamed.as_raw_FileNode(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2353,8 +3018,9 @@ pub trait FlannBasedMatcherTrait: crate::features2d::DescriptorMatcher + crate::
 	
 	#[inline]
 	fn train(&mut self) -> Result<()> {
+		crate::core::validity::check("FlannBasedMatcher", self.as_raw_mut_FlannBasedMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FlannBasedMatcher_train(self.as_raw_mut_FlannBasedMatcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FlannBasedMatcher_train(self.as_raw_mut_FlannBasedMatcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2377,7 +3043,10 @@ opencv_type_boxed! { FlannBasedMatcher }
 impl Drop for FlannBasedMatcher {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FlannBasedMatcher_delete(instance: *mut c_void); }
-		unsafe { cv_FlannBasedMatcher_delete(self.as_raw_mut_FlannBasedMatcher()) };
+		let ptr = self.as_raw_mut_FlannBasedMatcher();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_FlannBasedMatcher_delete(ptr) };
 	}
 }
 
@@ -2414,7 +3083,7 @@ impl FlannBasedMatcher {
 	#[inline]
 	pub fn new(index_params: &core::Ptr<crate::flann::IndexParams>, search_params: &core::Ptr<crate::flann::SearchParams>) -> Result<crate::features2d::FlannBasedMatcher> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FlannBasedMatcher_FlannBasedMatcher_const_Ptr_IndexParams_R_const_Ptr_SearchParams_R(index_params.as_raw_PtrOfIndexParams(), search_params.as_raw_PtrOfSearchParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FlannBasedMatcher_FlannBasedMatcher_const_Ptr_IndexParams_R_const_Ptr_SearchParams_R(index_params.as_raw_PtrOfIndexParams(), search_params.as_raw_PtrOfSearchParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::features2d::FlannBasedMatcher::opencv_from_extern(ret) };
@@ -2424,7 +3093,7 @@ impl FlannBasedMatcher {
 	#[inline]
 	pub fn create() -> Result<core::Ptr<crate::features2d::FlannBasedMatcher>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FlannBasedMatcher_create(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FlannBasedMatcher_create(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::features2d::FlannBasedMatcher>::opencv_from_extern(ret) };
@@ -2441,8 +3110,9 @@ pub trait GFTTDetectorConst: crate::features2d::Feature2DTraitConst {
 
 	#[inline]
 	fn get_max_features(&self) -> Result<i32> {
+		crate::core::validity::check("GFTTDetector", self.as_raw_GFTTDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GFTTDetector_getMaxFeatures_const(self.as_raw_GFTTDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GFTTDetector_getMaxFeatures_const(self.as_raw_GFTTDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2450,8 +3120,9 @@ pub trait GFTTDetectorConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_quality_level(&self) -> Result<f64> {
+		crate::core::validity::check("GFTTDetector", self.as_raw_GFTTDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GFTTDetector_getQualityLevel_const(self.as_raw_GFTTDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GFTTDetector_getQualityLevel_const(self.as_raw_GFTTDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2459,8 +3130,9 @@ pub trait GFTTDetectorConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_min_distance(&self) -> Result<f64> {
+		crate::core::validity::check("GFTTDetector", self.as_raw_GFTTDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GFTTDetector_getMinDistance_const(self.as_raw_GFTTDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GFTTDetector_getMinDistance_const(self.as_raw_GFTTDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2468,8 +3140,9 @@ pub trait GFTTDetectorConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_block_size(&self) -> Result<i32> {
+		crate::core::validity::check("GFTTDetector", self.as_raw_GFTTDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GFTTDetector_getBlockSize_const(self.as_raw_GFTTDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GFTTDetector_getBlockSize_const(self.as_raw_GFTTDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2477,8 +3150,9 @@ pub trait GFTTDetectorConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_harris_detector(&self) -> Result<bool> {
+		crate::core::validity::check("GFTTDetector", self.as_raw_GFTTDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GFTTDetector_getHarrisDetector_const(self.as_raw_GFTTDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GFTTDetector_getHarrisDetector_const(self.as_raw_GFTTDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2486,8 +3160,9 @@ pub trait GFTTDetectorConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_k(&self) -> Result<f64> {
+		crate::core::validity::check("GFTTDetector", self.as_raw_GFTTDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GFTTDetector_getK_const(self.as_raw_GFTTDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GFTTDetector_getK_const(self.as_raw_GFTTDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2495,8 +3170,9 @@ pub trait GFTTDetectorConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_default_name(&self) -> Result<String> {
+		crate::core::validity::check("GFTTDetector", self.as_raw_GFTTDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GFTTDetector_getDefaultName_const(self.as_raw_GFTTDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GFTTDetector_getDefaultName_const(self.as_raw_GFTTDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -2510,8 +3186,9 @@ pub trait GFTTDetector: crate::features2d::Feature2DTrait + crate::features2d::G
 
 	#[inline]
 	fn set_max_features(&mut self, max_features: i32) -> Result<()> {
+		crate::core::validity::check("GFTTDetector", self.as_raw_mut_GFTTDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GFTTDetector_setMaxFeatures_int(self.as_raw_mut_GFTTDetector(), max_features, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GFTTDetector_setMaxFeatures_int(self.as_raw_mut_GFTTDetector(), max_features, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2519,8 +3196,9 @@ pub trait GFTTDetector: crate::features2d::Feature2DTrait + crate::features2d::G
 	
 	#[inline]
 	fn set_quality_level(&mut self, qlevel: f64) -> Result<()> {
+		crate::core::validity::check("GFTTDetector", self.as_raw_mut_GFTTDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GFTTDetector_setQualityLevel_double(self.as_raw_mut_GFTTDetector(), qlevel, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GFTTDetector_setQualityLevel_double(self.as_raw_mut_GFTTDetector(), qlevel, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2528,8 +3206,9 @@ pub trait GFTTDetector: crate::features2d::Feature2DTrait + crate::features2d::G
 	
 	#[inline]
 	fn set_min_distance(&mut self, min_distance: f64) -> Result<()> {
+		crate::core::validity::check("GFTTDetector", self.as_raw_mut_GFTTDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GFTTDetector_setMinDistance_double(self.as_raw_mut_GFTTDetector(), min_distance, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GFTTDetector_setMinDistance_double(self.as_raw_mut_GFTTDetector(), min_distance, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2537,8 +3216,9 @@ pub trait GFTTDetector: crate::features2d::Feature2DTrait + crate::features2d::G
 	
 	#[inline]
 	fn set_block_size(&mut self, block_size: i32) -> Result<()> {
+		crate::core::validity::check("GFTTDetector", self.as_raw_mut_GFTTDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GFTTDetector_setBlockSize_int(self.as_raw_mut_GFTTDetector(), block_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GFTTDetector_setBlockSize_int(self.as_raw_mut_GFTTDetector(), block_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2546,8 +3226,9 @@ pub trait GFTTDetector: crate::features2d::Feature2DTrait + crate::features2d::G
 	
 	#[inline]
 	fn set_harris_detector(&mut self, val: bool) -> Result<()> {
+		crate::core::validity::check("GFTTDetector", self.as_raw_mut_GFTTDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GFTTDetector_setHarrisDetector_bool(self.as_raw_mut_GFTTDetector(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GFTTDetector_setHarrisDetector_bool(self.as_raw_mut_GFTTDetector(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2555,8 +3236,9 @@ pub trait GFTTDetector: crate::features2d::Feature2DTrait + crate::features2d::G
 	
 	#[inline]
 	fn set_k(&mut self, k: f64) -> Result<()> {
+		crate::core::validity::check("GFTTDetector", self.as_raw_mut_GFTTDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GFTTDetector_setK_double(self.as_raw_mut_GFTTDetector(), k, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GFTTDetector_setK_double(self.as_raw_mut_GFTTDetector(), k, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2575,7 +3257,7 @@ impl dyn GFTTDetector + '_ {
 	#[inline]
 	pub fn create(max_corners: i32, quality_level: f64, min_distance: f64, block_size: i32, use_harris_detector: bool, k: f64) -> Result<core::Ptr<dyn crate::features2d::GFTTDetector>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GFTTDetector_create_int_double_double_int_bool_double(max_corners, quality_level, min_distance, block_size, use_harris_detector, k, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GFTTDetector_create_int_double_double_int_bool_double(max_corners, quality_level, min_distance, block_size, use_harris_detector, k, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::features2d::GFTTDetector>::opencv_from_extern(ret) };
@@ -2588,7 +3270,7 @@ impl dyn GFTTDetector + '_ {
 	#[inline]
 	pub fn create_with_gradient(max_corners: i32, quality_level: f64, min_distance: f64, block_size: i32, gradiant_size: i32, use_harris_detector: bool, k: f64) -> Result<core::Ptr<dyn crate::features2d::GFTTDetector>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GFTTDetector_create_int_double_double_int_int_bool_double(max_corners, quality_level, min_distance, block_size, gradiant_size, use_harris_detector, k, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GFTTDetector_create_int_double_double_int_int_bool_double(max_corners, quality_level, min_distance, block_size, gradiant_size, use_harris_detector, k, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::features2d::GFTTDetector>::opencv_from_extern(ret) };
@@ -2596,10 +3278,124 @@ impl dyn GFTTDetector + '_ {
 	}
 	
 }
+
+/// Builder for `<dyn GFTTDetector>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct GFTTDetector_CreateBuilder {
+	max_corners: i32,
+	quality_level: f64,
+	min_distance: f64,
+	block_size: i32,
+	use_harris_detector: bool,
+	k: f64,
+}
+
+impl GFTTDetector_CreateBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { max_corners: 1000, quality_level: 0.01, min_distance: 1., block_size: 3, use_harris_detector: false, k: 0.04 }
+	}
+	
+	/// C++ default: `1000`
+	#[inline]
+	pub fn with_max_corners(mut self, max_corners: i32) -> Self {
+		self.max_corners = max_corners;
+		self
+	}
+	
+	/// C++ default: `0.01`
+	#[inline]
+	pub fn with_quality_level(mut self, quality_level: f64) -> Self {
+		self.quality_level = quality_level;
+		self
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_min_distance(mut self, min_distance: f64) -> Self {
+		self.min_distance = min_distance;
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_block_size(mut self, block_size: i32) -> Self {
+		self.block_size = block_size;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_use_harris_detector(mut self, use_harris_detector: bool) -> Self {
+		self.use_harris_detector = use_harris_detector;
+		self
+	}
+	
+	/// C++ default: `0.04`
+	#[inline]
+	pub fn with_k(mut self, k: f64) -> Self {
+		self.k = k;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::features2d::GFTTDetector>> {
+		<dyn GFTTDetector>::create(self.max_corners, self.quality_level, self.min_distance, self.block_size, self.use_harris_detector, self.k)
+	}
+}
+
+impl Default for GFTTDetector_CreateBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Builder for `<dyn GFTTDetector>::create_with_gradient()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct GFTTDetector_CreateWithGradientBuilder {
+	max_corners: i32,
+	quality_level: f64,
+	min_distance: f64,
+	block_size: i32,
+	gradiant_size: i32,
+	use_harris_detector: bool,
+	k: f64,
+}
+
+impl GFTTDetector_CreateWithGradientBuilder {
+	#[inline]
+	pub fn new(max_corners: i32, quality_level: f64, min_distance: f64, block_size: i32, gradiant_size: i32) -> Self {
+		Self { max_corners, quality_level, min_distance, block_size, gradiant_size, use_harris_detector: false, k: 0.04 }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_use_harris_detector(mut self, use_harris_detector: bool) -> Self {
+		self.use_harris_detector = use_harris_detector;
+		self
+	}
+	
+	/// C++ default: `0.04`
+	#[inline]
+	pub fn with_k(mut self, k: f64) -> Self {
+		self.k = k;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::features2d::GFTTDetector>> {
+		<dyn GFTTDetector>::create_with_gradient(self.max_corners, self.quality_level, self.min_distance, self.block_size, self.gradiant_size, self.use_harris_detector, self.k)
+	}
+}
 /// Class implementing the KAZE keypoint detector and descriptor extractor, described in [ABD12](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_ABD12) .
 /// 
 /// 
-/// Note: AKAZE descriptor can only be used with KAZE or AKAZE keypoints .. [ABD12] KAZE Features. Pablo
+/// Note: AKAZE descriptor can only be used with KAZE or AKAZE keypoints .. \[ABD12\] KAZE Features. Pablo
 /// F. Alcantarilla, Adrien Bartoli and Andrew J. Davison. In European Conference on Computer Vision
 /// (ECCV), Fiorenze, Italy, October 2012.
 pub trait KAZEConst: crate::features2d::Feature2DTraitConst {
@@ -2607,8 +3403,9 @@ pub trait KAZEConst: crate::features2d::Feature2DTraitConst {
 
 	#[inline]
 	fn get_extended(&self) -> Result<bool> {
+		crate::core::validity::check("KAZE", self.as_raw_KAZE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_KAZE_getExtended_const(self.as_raw_KAZE(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_KAZE_getExtended_const(self.as_raw_KAZE(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2616,8 +3413,9 @@ pub trait KAZEConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_upright(&self) -> Result<bool> {
+		crate::core::validity::check("KAZE", self.as_raw_KAZE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_KAZE_getUpright_const(self.as_raw_KAZE(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_KAZE_getUpright_const(self.as_raw_KAZE(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2625,8 +3423,9 @@ pub trait KAZEConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_threshold(&self) -> Result<f64> {
+		crate::core::validity::check("KAZE", self.as_raw_KAZE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_KAZE_getThreshold_const(self.as_raw_KAZE(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_KAZE_getThreshold_const(self.as_raw_KAZE(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2634,8 +3433,9 @@ pub trait KAZEConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_n_octaves(&self) -> Result<i32> {
+		crate::core::validity::check("KAZE", self.as_raw_KAZE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_KAZE_getNOctaves_const(self.as_raw_KAZE(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_KAZE_getNOctaves_const(self.as_raw_KAZE(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2643,8 +3443,9 @@ pub trait KAZEConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_n_octave_layers(&self) -> Result<i32> {
+		crate::core::validity::check("KAZE", self.as_raw_KAZE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_KAZE_getNOctaveLayers_const(self.as_raw_KAZE(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_KAZE_getNOctaveLayers_const(self.as_raw_KAZE(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2652,8 +3453,9 @@ pub trait KAZEConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_diffusivity(&self) -> Result<crate::features2d::KAZE_DiffusivityType> {
+		crate::core::validity::check("KAZE", self.as_raw_KAZE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_KAZE_getDiffusivity_const(self.as_raw_KAZE(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_KAZE_getDiffusivity_const(self.as_raw_KAZE(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2661,8 +3463,9 @@ pub trait KAZEConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_default_name(&self) -> Result<String> {
+		crate::core::validity::check("KAZE", self.as_raw_KAZE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_KAZE_getDefaultName_const(self.as_raw_KAZE(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_KAZE_getDefaultName_const(self.as_raw_KAZE(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -2676,8 +3479,9 @@ pub trait KAZE: crate::features2d::Feature2DTrait + crate::features2d::KAZEConst
 
 	#[inline]
 	fn set_extended(&mut self, extended: bool) -> Result<()> {
+		crate::core::validity::check("KAZE", self.as_raw_mut_KAZE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_KAZE_setExtended_bool(self.as_raw_mut_KAZE(), extended, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_KAZE_setExtended_bool(self.as_raw_mut_KAZE(), extended, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2685,8 +3489,9 @@ pub trait KAZE: crate::features2d::Feature2DTrait + crate::features2d::KAZEConst
 	
 	#[inline]
 	fn set_upright(&mut self, upright: bool) -> Result<()> {
+		crate::core::validity::check("KAZE", self.as_raw_mut_KAZE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_KAZE_setUpright_bool(self.as_raw_mut_KAZE(), upright, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_KAZE_setUpright_bool(self.as_raw_mut_KAZE(), upright, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2694,8 +3499,9 @@ pub trait KAZE: crate::features2d::Feature2DTrait + crate::features2d::KAZEConst
 	
 	#[inline]
 	fn set_threshold(&mut self, threshold: f64) -> Result<()> {
+		crate::core::validity::check("KAZE", self.as_raw_mut_KAZE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_KAZE_setThreshold_double(self.as_raw_mut_KAZE(), threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_KAZE_setThreshold_double(self.as_raw_mut_KAZE(), threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2703,8 +3509,9 @@ pub trait KAZE: crate::features2d::Feature2DTrait + crate::features2d::KAZEConst
 	
 	#[inline]
 	fn set_n_octaves(&mut self, octaves: i32) -> Result<()> {
+		crate::core::validity::check("KAZE", self.as_raw_mut_KAZE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_KAZE_setNOctaves_int(self.as_raw_mut_KAZE(), octaves, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_KAZE_setNOctaves_int(self.as_raw_mut_KAZE(), octaves, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2712,8 +3519,9 @@ pub trait KAZE: crate::features2d::Feature2DTrait + crate::features2d::KAZEConst
 	
 	#[inline]
 	fn set_n_octave_layers(&mut self, octave_layers: i32) -> Result<()> {
+		crate::core::validity::check("KAZE", self.as_raw_mut_KAZE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_KAZE_setNOctaveLayers_int(self.as_raw_mut_KAZE(), octave_layers, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_KAZE_setNOctaveLayers_int(self.as_raw_mut_KAZE(), octave_layers, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2721,8 +3529,9 @@ pub trait KAZE: crate::features2d::Feature2DTrait + crate::features2d::KAZEConst
 	
 	#[inline]
 	fn set_diffusivity(&mut self, diff: crate::features2d::KAZE_DiffusivityType) -> Result<()> {
+		crate::core::validity::check("KAZE", self.as_raw_mut_KAZE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_KAZE_setDiffusivity_DiffusivityType(self.as_raw_mut_KAZE(), diff, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_KAZE_setDiffusivity_DiffusivityType(self.as_raw_mut_KAZE(), diff, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2752,7 +3561,7 @@ impl dyn KAZE + '_ {
 	#[inline]
 	pub fn create(extended: bool, upright: bool, threshold: f32, n_octaves: i32, n_octave_layers: i32, diffusivity: crate::features2d::KAZE_DiffusivityType) -> Result<core::Ptr<dyn crate::features2d::KAZE>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_KAZE_create_bool_bool_float_int_int_DiffusivityType(extended, upright, threshold, n_octaves, n_octave_layers, diffusivity, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_KAZE_create_bool_bool_float_int_int_DiffusivityType(extended, upright, threshold, n_octaves, n_octave_layers, diffusivity, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::features2d::KAZE>::opencv_from_extern(ret) };
@@ -2760,6 +3569,66 @@ impl dyn KAZE + '_ {
 	}
 	
 }
+
+/// Builder for `<dyn KAZE>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct KAZE_CreateBuilder {
+	extended: bool,
+	upright: bool,
+	threshold: f32,
+	n_octaves: i32,
+	n_octave_layers: i32,
+	diffusivity: crate::features2d::KAZE_DiffusivityType,
+}
+
+impl KAZE_CreateBuilder {
+	#[inline]
+	pub fn new(diffusivity: crate::features2d::KAZE_DiffusivityType) -> Self {
+		Self { extended: false, upright: false, threshold: 0.001, n_octaves: 4, n_octave_layers: 4, diffusivity }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_extended(mut self, extended: bool) -> Self {
+		self.extended = extended;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_upright(mut self, upright: bool) -> Self {
+		self.upright = upright;
+		self
+	}
+	
+	/// C++ default: `0.001f`
+	#[inline]
+	pub fn with_threshold(mut self, threshold: f32) -> Self {
+		self.threshold = threshold;
+		self
+	}
+	
+	/// C++ default: `4`
+	#[inline]
+	pub fn with_n_octaves(mut self, n_octaves: i32) -> Self {
+		self.n_octaves = n_octaves;
+		self
+	}
+	
+	/// C++ default: `4`
+	#[inline]
+	pub fn with_n_octave_layers(mut self, n_octave_layers: i32) -> Self {
+		self.n_octave_layers = n_octave_layers;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::features2d::KAZE>> {
+		<dyn KAZE>::create(self.extended, self.upright, self.threshold, self.n_octaves, self.n_octave_layers, self.diffusivity)
+	}
+}
 /// A class filters a vector of keypoints.
 /// 
 /// Because now it is difficult to provide a convenient interface for all usage scenarios of the
@@ -2787,7 +3656,10 @@ opencv_type_boxed! { KeyPointsFilter }
 impl Drop for KeyPointsFilter {
 	fn drop(&mut self) {
 		extern "C" { fn cv_KeyPointsFilter_delete(instance: *mut c_void); }
-		unsafe { cv_KeyPointsFilter_delete(self.as_raw_mut_KeyPointsFilter()) };
+		let ptr = self.as_raw_mut_KeyPointsFilter();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_KeyPointsFilter_delete(ptr) };
 	}
 }
 
@@ -2805,7 +3677,7 @@ impl KeyPointsFilter {
 	#[inline]
 	pub fn default() -> Result<crate::features2d::KeyPointsFilter> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_KeyPointsFilter_KeyPointsFilter(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_KeyPointsFilter_KeyPointsFilter(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::features2d::KeyPointsFilter::opencv_from_extern(ret) };
@@ -2815,7 +3687,7 @@ impl KeyPointsFilter {
 	#[inline]
 	pub fn run_by_image_border(keypoints: &mut core::Vector<core::KeyPoint>, image_size: core::Size, border_size: i32) -> Result<()> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_KeyPointsFilter_runByImageBorder_vector_KeyPoint_R_Size_int(keypoints.as_raw_mut_VectorOfKeyPoint(), image_size.opencv_as_extern(), border_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_KeyPointsFilter_runByImageBorder_vector_KeyPoint_R_Size_int(keypoints.as_raw_mut_VectorOfKeyPoint(), image_size.opencv_as_extern(), border_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2826,7 +3698,7 @@ impl KeyPointsFilter {
 	#[inline]
 	pub fn run_by_keypoint_size(keypoints: &mut core::Vector<core::KeyPoint>, min_size: f32, max_size: f32) -> Result<()> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_KeyPointsFilter_runByKeypointSize_vector_KeyPoint_R_float_float(keypoints.as_raw_mut_VectorOfKeyPoint(), min_size, max_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_KeyPointsFilter_runByKeypointSize_vector_KeyPoint_R_float_float(keypoints.as_raw_mut_VectorOfKeyPoint(), min_size, max_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2835,7 +3707,7 @@ impl KeyPointsFilter {
 	#[inline]
 	pub fn run_by_pixels_mask(keypoints: &mut core::Vector<core::KeyPoint>, mask: &core::Mat) -> Result<()> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_KeyPointsFilter_runByPixelsMask_vector_KeyPoint_R_const_MatR(keypoints.as_raw_mut_VectorOfKeyPoint(), mask.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_KeyPointsFilter_runByPixelsMask_vector_KeyPoint_R_const_MatR(keypoints.as_raw_mut_VectorOfKeyPoint(), mask.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2844,7 +3716,7 @@ impl KeyPointsFilter {
 	#[inline]
 	pub fn remove_duplicated(keypoints: &mut core::Vector<core::KeyPoint>) -> Result<()> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_KeyPointsFilter_removeDuplicated_vector_KeyPoint_R(keypoints.as_raw_mut_VectorOfKeyPoint(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_KeyPointsFilter_removeDuplicated_vector_KeyPoint_R(keypoints.as_raw_mut_VectorOfKeyPoint(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2853,7 +3725,7 @@ impl KeyPointsFilter {
 	#[inline]
 	pub fn remove_duplicated_sorted(keypoints: &mut core::Vector<core::KeyPoint>) -> Result<()> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_KeyPointsFilter_removeDuplicatedSorted_vector_KeyPoint_R(keypoints.as_raw_mut_VectorOfKeyPoint(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_KeyPointsFilter_removeDuplicatedSorted_vector_KeyPoint_R(keypoints.as_raw_mut_VectorOfKeyPoint(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2862,7 +3734,7 @@ impl KeyPointsFilter {
 	#[inline]
 	pub fn retain_best(keypoints: &mut core::Vector<core::KeyPoint>, npoints: i32) -> Result<()> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_KeyPointsFilter_retainBest_vector_KeyPoint_R_int(keypoints.as_raw_mut_VectorOfKeyPoint(), npoints, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_KeyPointsFilter_retainBest_vector_KeyPoint_R_int(keypoints.as_raw_mut_VectorOfKeyPoint(), npoints, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2872,8 +3744,8 @@ impl KeyPointsFilter {
 
 /// Maximally stable extremal region extractor
 /// 
-/// The class encapsulates all the parameters of the %MSER extraction algorithm (see [wiki
-/// article](http://en.wikipedia.org/wiki/Maximally_stable_extremal_regions)).
+/// The class encapsulates all the parameters of the %MSER extraction algorithm (see \[wiki
+/// article\](http://en.wikipedia.org/wiki/Maximally_stable_extremal_regions)).
 /// 
 /// - there are two different implementation of %MSER: one for grey image, one for color image
 /// 
@@ -2889,8 +3761,9 @@ pub trait MSERConst: crate::features2d::Feature2DTraitConst {
 
 	#[inline]
 	fn get_delta(&self) -> Result<i32> {
+		crate::core::validity::check("MSER", self.as_raw_MSER())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_MSER_getDelta_const(self.as_raw_MSER(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_MSER_getDelta_const(self.as_raw_MSER(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2898,8 +3771,9 @@ pub trait MSERConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_min_area(&self) -> Result<i32> {
+		crate::core::validity::check("MSER", self.as_raw_MSER())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_MSER_getMinArea_const(self.as_raw_MSER(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_MSER_getMinArea_const(self.as_raw_MSER(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2907,8 +3781,9 @@ pub trait MSERConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_max_area(&self) -> Result<i32> {
+		crate::core::validity::check("MSER", self.as_raw_MSER())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_MSER_getMaxArea_const(self.as_raw_MSER(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_MSER_getMaxArea_const(self.as_raw_MSER(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2916,8 +3791,9 @@ pub trait MSERConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_pass2_only(&self) -> Result<bool> {
+		crate::core::validity::check("MSER", self.as_raw_MSER())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_MSER_getPass2Only_const(self.as_raw_MSER(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_MSER_getPass2Only_const(self.as_raw_MSER(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2925,8 +3801,9 @@ pub trait MSERConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_default_name(&self) -> Result<String> {
+		crate::core::validity::check("MSER", self.as_raw_MSER())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_MSER_getDefaultName_const(self.as_raw_MSER(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_MSER_getDefaultName_const(self.as_raw_MSER(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -2946,9 +3823,10 @@ pub trait MSER: crate::features2d::Feature2DTrait + crate::features2d::MSERConst
 	/// * bboxes: resulting bounding boxes
 	#[inline]
 	fn detect_regions(&mut self, image: &dyn core::ToInputArray, msers: &mut core::Vector<core::Vector<core::Point>>, bboxes: &mut core::Vector<core::Rect>) -> Result<()> {
+		crate::core::validity::check("MSER", self.as_raw_mut_MSER())?;
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_MSER_detectRegions_const__InputArrayR_vector_vector_Point__R_vector_Rect_R(self.as_raw_mut_MSER(), image.as_raw__InputArray(), msers.as_raw_mut_VectorOfVectorOfPoint(), bboxes.as_raw_mut_VectorOfRect(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_MSER_detectRegions_const__InputArrayR_vector_vector_Point__R_vector_Rect_R(self.as_raw_mut_MSER(), image.as_raw__InputArray(), msers.as_raw_mut_VectorOfVectorOfPoint(), bboxes.as_raw_mut_VectorOfRect(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2956,8 +3834,9 @@ pub trait MSER: crate::features2d::Feature2DTrait + crate::features2d::MSERConst
 	
 	#[inline]
 	fn set_delta(&mut self, delta: i32) -> Result<()> {
+		crate::core::validity::check("MSER", self.as_raw_mut_MSER())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_MSER_setDelta_int(self.as_raw_mut_MSER(), delta, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_MSER_setDelta_int(self.as_raw_mut_MSER(), delta, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2965,8 +3844,9 @@ pub trait MSER: crate::features2d::Feature2DTrait + crate::features2d::MSERConst
 	
 	#[inline]
 	fn set_min_area(&mut self, min_area: i32) -> Result<()> {
+		crate::core::validity::check("MSER", self.as_raw_mut_MSER())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_MSER_setMinArea_int(self.as_raw_mut_MSER(), min_area, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_MSER_setMinArea_int(self.as_raw_mut_MSER(), min_area, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2974,8 +3854,9 @@ pub trait MSER: crate::features2d::Feature2DTrait + crate::features2d::MSERConst
 	
 	#[inline]
 	fn set_max_area(&mut self, max_area: i32) -> Result<()> {
+		crate::core::validity::check("MSER", self.as_raw_mut_MSER())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_MSER_setMaxArea_int(self.as_raw_mut_MSER(), max_area, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_MSER_setMaxArea_int(self.as_raw_mut_MSER(), max_area, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2983,8 +3864,9 @@ pub trait MSER: crate::features2d::Feature2DTrait + crate::features2d::MSERConst
 	
 	#[inline]
 	fn set_pass2_only(&mut self, f: bool) -> Result<()> {
+		crate::core::validity::check("MSER", self.as_raw_mut_MSER())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_MSER_setPass2Only_bool(self.as_raw_mut_MSER(), f, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_MSER_setPass2Only_bool(self.as_raw_mut_MSER(), f, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3019,7 +3901,7 @@ impl dyn MSER + '_ {
 	#[inline]
 	pub fn create(delta: i32, min_area: i32, max_area: i32, max_variation: f64, min_diversity: f64, max_evolution: i32, area_threshold: f64, min_margin: f64, edge_blur_size: i32) -> Result<core::Ptr<dyn crate::features2d::MSER>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_MSER_create_int_int_int_double_double_int_double_double_int(delta, min_area, max_area, max_variation, min_diversity, max_evolution, area_threshold, min_margin, edge_blur_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_MSER_create_int_int_int_double_double_int_double_double_int(delta, min_area, max_area, max_variation, min_diversity, max_evolution, area_threshold, min_margin, edge_blur_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::features2d::MSER>::opencv_from_extern(ret) };
@@ -3027,6 +3909,104 @@ impl dyn MSER + '_ {
 	}
 	
 }
+
+/// Builder for `<dyn MSER>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct MSER_CreateBuilder {
+	delta: i32,
+	min_area: i32,
+	max_area: i32,
+	max_variation: f64,
+	min_diversity: f64,
+	max_evolution: i32,
+	area_threshold: f64,
+	min_margin: f64,
+	edge_blur_size: i32,
+}
+
+impl MSER_CreateBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { delta: 5, min_area: 60, max_area: 14400, max_variation: 0.25, min_diversity: 0.2, max_evolution: 200, area_threshold: 1.01, min_margin: 0.003, edge_blur_size: 5 }
+	}
+	
+	/// C++ default: `5`
+	#[inline]
+	pub fn with_delta(mut self, delta: i32) -> Self {
+		self.delta = delta;
+		self
+	}
+	
+	/// C++ default: `60`
+	#[inline]
+	pub fn with_min_area(mut self, min_area: i32) -> Self {
+		self.min_area = min_area;
+		self
+	}
+	
+	/// C++ default: `14400`
+	#[inline]
+	pub fn with_max_area(mut self, max_area: i32) -> Self {
+		self.max_area = max_area;
+		self
+	}
+	
+	/// C++ default: `0.25`
+	#[inline]
+	pub fn with_max_variation(mut self, max_variation: f64) -> Self {
+		self.max_variation = max_variation;
+		self
+	}
+	
+	/// C++ default: `.2`
+	#[inline]
+	pub fn with_min_diversity(mut self, min_diversity: f64) -> Self {
+		self.min_diversity = min_diversity;
+		self
+	}
+	
+	/// C++ default: `200`
+	#[inline]
+	pub fn with_max_evolution(mut self, max_evolution: i32) -> Self {
+		self.max_evolution = max_evolution;
+		self
+	}
+	
+	/// C++ default: `1.01`
+	#[inline]
+	pub fn with_area_threshold(mut self, area_threshold: f64) -> Self {
+		self.area_threshold = area_threshold;
+		self
+	}
+	
+	/// C++ default: `0.003`
+	#[inline]
+	pub fn with_min_margin(mut self, min_margin: f64) -> Self {
+		self.min_margin = min_margin;
+		self
+	}
+	
+	/// C++ default: `5`
+	#[inline]
+	pub fn with_edge_blur_size(mut self, edge_blur_size: i32) -> Self {
+		self.edge_blur_size = edge_blur_size;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::features2d::MSER>> {
+		<dyn MSER>::create(self.delta, self.min_area, self.max_area, self.max_variation, self.min_diversity, self.max_evolution, self.area_threshold, self.min_margin, self.edge_blur_size)
+	}
+}
+
+impl Default for MSER_CreateBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
 /// Class implementing the ORB (*oriented BRIEF*) keypoint detector and descriptor extractor
 /// 
 /// described in [RRKB11](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_RRKB11) . The algorithm uses FAST in pyramids to detect stable keypoints, selects
@@ -3038,8 +4018,9 @@ pub trait ORBConst: crate::features2d::Feature2DTraitConst {
 
 	#[inline]
 	fn get_max_features(&self) -> Result<i32> {
+		crate::core::validity::check("ORB", self.as_raw_ORB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ORB_getMaxFeatures_const(self.as_raw_ORB(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ORB_getMaxFeatures_const(self.as_raw_ORB(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3047,8 +4028,9 @@ pub trait ORBConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_scale_factor(&self) -> Result<f64> {
+		crate::core::validity::check("ORB", self.as_raw_ORB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ORB_getScaleFactor_const(self.as_raw_ORB(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ORB_getScaleFactor_const(self.as_raw_ORB(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3056,8 +4038,9 @@ pub trait ORBConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_n_levels(&self) -> Result<i32> {
+		crate::core::validity::check("ORB", self.as_raw_ORB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ORB_getNLevels_const(self.as_raw_ORB(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ORB_getNLevels_const(self.as_raw_ORB(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3065,8 +4048,9 @@ pub trait ORBConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_edge_threshold(&self) -> Result<i32> {
+		crate::core::validity::check("ORB", self.as_raw_ORB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ORB_getEdgeThreshold_const(self.as_raw_ORB(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ORB_getEdgeThreshold_const(self.as_raw_ORB(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3074,8 +4058,9 @@ pub trait ORBConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_first_level(&self) -> Result<i32> {
+		crate::core::validity::check("ORB", self.as_raw_ORB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ORB_getFirstLevel_const(self.as_raw_ORB(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ORB_getFirstLevel_const(self.as_raw_ORB(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3083,8 +4068,9 @@ pub trait ORBConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_wta_k(&self) -> Result<i32> {
+		crate::core::validity::check("ORB", self.as_raw_ORB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ORB_getWTA_K_const(self.as_raw_ORB(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ORB_getWTA_K_const(self.as_raw_ORB(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3092,8 +4078,9 @@ pub trait ORBConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_score_type(&self) -> Result<crate::features2d::ORB_ScoreType> {
+		crate::core::validity::check("ORB", self.as_raw_ORB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ORB_getScoreType_const(self.as_raw_ORB(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ORB_getScoreType_const(self.as_raw_ORB(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3101,8 +4088,9 @@ pub trait ORBConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_patch_size(&self) -> Result<i32> {
+		crate::core::validity::check("ORB", self.as_raw_ORB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ORB_getPatchSize_const(self.as_raw_ORB(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ORB_getPatchSize_const(self.as_raw_ORB(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3110,8 +4098,9 @@ pub trait ORBConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_fast_threshold(&self) -> Result<i32> {
+		crate::core::validity::check("ORB", self.as_raw_ORB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ORB_getFastThreshold_const(self.as_raw_ORB(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ORB_getFastThreshold_const(self.as_raw_ORB(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3119,8 +4108,9 @@ pub trait ORBConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_default_name(&self) -> Result<String> {
+		crate::core::validity::check("ORB", self.as_raw_ORB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ORB_getDefaultName_const(self.as_raw_ORB(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ORB_getDefaultName_const(self.as_raw_ORB(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -3134,8 +4124,9 @@ pub trait ORB: crate::features2d::Feature2DTrait + crate::features2d::ORBConst {
 
 	#[inline]
 	fn set_max_features(&mut self, max_features: i32) -> Result<()> {
+		crate::core::validity::check("ORB", self.as_raw_mut_ORB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ORB_setMaxFeatures_int(self.as_raw_mut_ORB(), max_features, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ORB_setMaxFeatures_int(self.as_raw_mut_ORB(), max_features, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3143,8 +4134,9 @@ pub trait ORB: crate::features2d::Feature2DTrait + crate::features2d::ORBConst {
 	
 	#[inline]
 	fn set_scale_factor(&mut self, scale_factor: f64) -> Result<()> {
+		crate::core::validity::check("ORB", self.as_raw_mut_ORB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ORB_setScaleFactor_double(self.as_raw_mut_ORB(), scale_factor, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ORB_setScaleFactor_double(self.as_raw_mut_ORB(), scale_factor, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3152,8 +4144,9 @@ pub trait ORB: crate::features2d::Feature2DTrait + crate::features2d::ORBConst {
 	
 	#[inline]
 	fn set_n_levels(&mut self, nlevels: i32) -> Result<()> {
+		crate::core::validity::check("ORB", self.as_raw_mut_ORB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ORB_setNLevels_int(self.as_raw_mut_ORB(), nlevels, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ORB_setNLevels_int(self.as_raw_mut_ORB(), nlevels, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3161,8 +4154,9 @@ pub trait ORB: crate::features2d::Feature2DTrait + crate::features2d::ORBConst {
 	
 	#[inline]
 	fn set_edge_threshold(&mut self, edge_threshold: i32) -> Result<()> {
+		crate::core::validity::check("ORB", self.as_raw_mut_ORB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ORB_setEdgeThreshold_int(self.as_raw_mut_ORB(), edge_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ORB_setEdgeThreshold_int(self.as_raw_mut_ORB(), edge_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3170,8 +4164,9 @@ pub trait ORB: crate::features2d::Feature2DTrait + crate::features2d::ORBConst {
 	
 	#[inline]
 	fn set_first_level(&mut self, first_level: i32) -> Result<()> {
+		crate::core::validity::check("ORB", self.as_raw_mut_ORB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ORB_setFirstLevel_int(self.as_raw_mut_ORB(), first_level, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ORB_setFirstLevel_int(self.as_raw_mut_ORB(), first_level, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3179,8 +4174,9 @@ pub trait ORB: crate::features2d::Feature2DTrait + crate::features2d::ORBConst {
 	
 	#[inline]
 	fn set_wta_k(&mut self, wta_k: i32) -> Result<()> {
+		crate::core::validity::check("ORB", self.as_raw_mut_ORB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ORB_setWTA_K_int(self.as_raw_mut_ORB(), wta_k, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ORB_setWTA_K_int(self.as_raw_mut_ORB(), wta_k, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3188,8 +4184,9 @@ pub trait ORB: crate::features2d::Feature2DTrait + crate::features2d::ORBConst {
 	
 	#[inline]
 	fn set_score_type(&mut self, score_type: crate::features2d::ORB_ScoreType) -> Result<()> {
+		crate::core::validity::check("ORB", self.as_raw_mut_ORB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ORB_setScoreType_ScoreType(self.as_raw_mut_ORB(), score_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ORB_setScoreType_ScoreType(self.as_raw_mut_ORB(), score_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3197,8 +4194,9 @@ pub trait ORB: crate::features2d::Feature2DTrait + crate::features2d::ORBConst {
 	
 	#[inline]
 	fn set_patch_size(&mut self, patch_size: i32) -> Result<()> {
+		crate::core::validity::check("ORB", self.as_raw_mut_ORB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ORB_setPatchSize_int(self.as_raw_mut_ORB(), patch_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ORB_setPatchSize_int(self.as_raw_mut_ORB(), patch_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3206,8 +4204,9 @@ pub trait ORB: crate::features2d::Feature2DTrait + crate::features2d::ORBConst {
 	
 	#[inline]
 	fn set_fast_threshold(&mut self, fast_threshold: i32) -> Result<()> {
+		crate::core::validity::check("ORB", self.as_raw_mut_ORB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ORB_setFastThreshold_int(self.as_raw_mut_ORB(), fast_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ORB_setFastThreshold_int(self.as_raw_mut_ORB(), fast_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3262,7 +4261,7 @@ impl dyn ORB + '_ {
 	#[inline]
 	pub fn create(nfeatures: i32, scale_factor: f32, nlevels: i32, edge_threshold: i32, first_level: i32, wta_k: i32, score_type: crate::features2d::ORB_ScoreType, patch_size: i32, fast_threshold: i32) -> Result<core::Ptr<dyn crate::features2d::ORB>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ORB_create_int_float_int_int_int_int_ScoreType_int_int(nfeatures, scale_factor, nlevels, edge_threshold, first_level, wta_k, score_type, patch_size, fast_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ORB_create_int_float_int_int_int_int_ScoreType_int_int(nfeatures, scale_factor, nlevels, edge_threshold, first_level, wta_k, score_type, patch_size, fast_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::features2d::ORB>::opencv_from_extern(ret) };
@@ -3270,6 +4269,90 @@ impl dyn ORB + '_ {
 	}
 	
 }
+
+/// Builder for `<dyn ORB>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct ORB_CreateBuilder {
+	nfeatures: i32,
+	scale_factor: f32,
+	nlevels: i32,
+	edge_threshold: i32,
+	first_level: i32,
+	wta_k: i32,
+	score_type: crate::features2d::ORB_ScoreType,
+	patch_size: i32,
+	fast_threshold: i32,
+}
+
+impl ORB_CreateBuilder {
+	#[inline]
+	pub fn new(score_type: crate::features2d::ORB_ScoreType) -> Self {
+		Self { nfeatures: 500, scale_factor: 1.2, nlevels: 8, edge_threshold: 31, first_level: 0, wta_k: 2, score_type, patch_size: 31, fast_threshold: 20 }
+	}
+	
+	/// C++ default: `500`
+	#[inline]
+	pub fn with_nfeatures(mut self, nfeatures: i32) -> Self {
+		self.nfeatures = nfeatures;
+		self
+	}
+	
+	/// C++ default: `1.2f`
+	#[inline]
+	pub fn with_scale_factor(mut self, scale_factor: f32) -> Self {
+		self.scale_factor = scale_factor;
+		self
+	}
+	
+	/// C++ default: `8`
+	#[inline]
+	pub fn with_nlevels(mut self, nlevels: i32) -> Self {
+		self.nlevels = nlevels;
+		self
+	}
+	
+	/// C++ default: `31`
+	#[inline]
+	pub fn with_edge_threshold(mut self, edge_threshold: i32) -> Self {
+		self.edge_threshold = edge_threshold;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_first_level(mut self, first_level: i32) -> Self {
+		self.first_level = first_level;
+		self
+	}
+	
+	/// C++ default: `2`
+	#[inline]
+	pub fn with_wta_k(mut self, wta_k: i32) -> Self {
+		self.wta_k = wta_k;
+		self
+	}
+	
+	/// C++ default: `31`
+	#[inline]
+	pub fn with_patch_size(mut self, patch_size: i32) -> Self {
+		self.patch_size = patch_size;
+		self
+	}
+	
+	/// C++ default: `20`
+	#[inline]
+	pub fn with_fast_threshold(mut self, fast_threshold: i32) -> Self {
+		self.fast_threshold = fast_threshold;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::features2d::ORB>> {
+		<dyn ORB>::create(self.nfeatures, self.scale_factor, self.nlevels, self.edge_threshold, self.first_level, self.wta_k, self.score_type, self.patch_size, self.fast_threshold)
+	}
+}
 /// Class for extracting keypoints and computing descriptors using the Scale Invariant Feature Transform
 /// (SIFT) algorithm by D. Lowe [Lowe04](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_Lowe04) .
 pub trait SIFTTraitConst: crate::features2d::Feature2DTraitConst {
@@ -3277,8 +4360,9 @@ pub trait SIFTTraitConst: crate::features2d::Feature2DTraitConst {
 
 	#[inline]
 	fn get_default_name(&self) -> Result<String> {
+		crate::core::validity::check("SIFT", self.as_raw_SIFT())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_SIFT_getDefaultName_const(self.as_raw_SIFT(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_SIFT_getDefaultName_const(self.as_raw_SIFT(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -3303,7 +4387,10 @@ opencv_type_boxed! { SIFT }
 impl Drop for SIFT {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SIFT_delete(instance: *mut c_void); }
-		unsafe { cv_SIFT_delete(self.as_raw_mut_SIFT()) };
+		let ptr = self.as_raw_mut_SIFT();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_SIFT_delete(ptr) };
 	}
 }
 
@@ -3365,7 +4452,7 @@ impl SIFT {
 	#[inline]
 	pub fn create(nfeatures: i32, n_octave_layers: i32, contrast_threshold: f64, edge_threshold: f64, sigma: f64) -> Result<core::Ptr<crate::features2d::SIFT>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_SIFT_create_int_int_double_double_double(nfeatures, n_octave_layers, contrast_threshold, edge_threshold, sigma, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_SIFT_create_int_int_double_double_double(nfeatures, n_octave_layers, contrast_threshold, edge_threshold, sigma, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::features2d::SIFT>::opencv_from_extern(ret) };
@@ -3399,7 +4486,7 @@ impl SIFT {
 	#[inline]
 	pub fn create_1(nfeatures: i32, n_octave_layers: i32, contrast_threshold: f64, edge_threshold: f64, sigma: f64, descriptor_type: i32) -> Result<core::Ptr<crate::features2d::SIFT>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_SIFT_create_int_int_double_double_double_int(nfeatures, n_octave_layers, contrast_threshold, edge_threshold, sigma, descriptor_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_SIFT_create_int_int_double_double_double_int(nfeatures, n_octave_layers, contrast_threshold, edge_threshold, sigma, descriptor_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::features2d::SIFT>::opencv_from_extern(ret) };
@@ -3408,6 +4495,72 @@ impl SIFT {
 	
 }
 
+/// Builder for `SIFT::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct SIFT_CreateBuilder {
+	nfeatures: i32,
+	n_octave_layers: i32,
+	contrast_threshold: f64,
+	edge_threshold: f64,
+	sigma: f64,
+}
+
+impl SIFT_CreateBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { nfeatures: 0, n_octave_layers: 3, contrast_threshold: 0.04, edge_threshold: 10., sigma: 1.6 }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_nfeatures(mut self, nfeatures: i32) -> Self {
+		self.nfeatures = nfeatures;
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_n_octave_layers(mut self, n_octave_layers: i32) -> Self {
+		self.n_octave_layers = n_octave_layers;
+		self
+	}
+	
+	/// C++ default: `0.04`
+	#[inline]
+	pub fn with_contrast_threshold(mut self, contrast_threshold: f64) -> Self {
+		self.contrast_threshold = contrast_threshold;
+		self
+	}
+	
+	/// C++ default: `10`
+	#[inline]
+	pub fn with_edge_threshold(mut self, edge_threshold: f64) -> Self {
+		self.edge_threshold = edge_threshold;
+		self
+	}
+	
+	/// C++ default: `1.6`
+	#[inline]
+	pub fn with_sigma(mut self, sigma: f64) -> Self {
+		self.sigma = sigma;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<crate::features2d::SIFT>> {
+		SIFT::create(self.nfeatures, self.n_octave_layers, self.contrast_threshold, self.edge_threshold, self.sigma)
+	}
+}
+
+impl Default for SIFT_CreateBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 boxed_cast_base! { SIFT, core::Algorithm, cv_SIFT_to_Algorithm }
 
 boxed_cast_base! { SIFT, crate::features2d::Feature2D, cv_SIFT_to_Feature2D }
@@ -3447,8 +4600,9 @@ pub trait SimpleBlobDetectorTraitConst: crate::features2d::Feature2DTraitConst {
 
 	#[inline]
 	fn get_default_name(&self) -> Result<String> {
+		crate::core::validity::check("SimpleBlobDetector", self.as_raw_SimpleBlobDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_SimpleBlobDetector_getDefaultName_const(self.as_raw_SimpleBlobDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_SimpleBlobDetector_getDefaultName_const(self.as_raw_SimpleBlobDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -3501,7 +4655,10 @@ opencv_type_boxed! { SimpleBlobDetector }
 impl Drop for SimpleBlobDetector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SimpleBlobDetector_delete(instance: *mut c_void); }
-		unsafe { cv_SimpleBlobDetector_delete(self.as_raw_mut_SimpleBlobDetector()) };
+		let ptr = self.as_raw_mut_SimpleBlobDetector();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_SimpleBlobDetector_delete(ptr) };
 	}
 }
 
@@ -3537,7 +4694,7 @@ impl SimpleBlobDetector {
 	#[inline]
 	pub fn create(parameters: crate::features2d::SimpleBlobDetector_Params) -> Result<core::Ptr<crate::features2d::SimpleBlobDetector>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_SimpleBlobDetector_create_const_ParamsR(&parameters, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_SimpleBlobDetector_create_const_ParamsR(&parameters, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::features2d::SimpleBlobDetector>::opencv_from_extern(ret) };
@@ -3580,7 +4737,7 @@ impl SimpleBlobDetector_Params {
 	#[inline]
 	pub fn write(self, fs: &mut core::FileStorage) -> Result<()> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_SimpleBlobDetector_Params_write_const_FileStorageR(self.opencv_as_extern(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_SimpleBlobDetector_Params_write_const_FileStorageR(self.opencv_as_extern(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3589,7 +4746,7 @@ impl SimpleBlobDetector_Params {
 	#[inline]
 	pub fn default() -> Result<crate::features2d::SimpleBlobDetector_Params> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_SimpleBlobDetector_Params_Params(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_SimpleBlobDetector_Params_Params(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3598,7 +4755,7 @@ impl SimpleBlobDetector_Params {
 	#[inline]
 	pub fn read(self, fn_: &core::FileNode) -> Result<()> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_SimpleBlobDetector_Params_read_const_FileNodeR(self.opencv_as_extern(), fn_.as_raw_FileNode(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_SimpleBlobDetector_Params_read_const_FileNodeR(self.opencv_as_extern(), fn_.as_raw_FileNode(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -232,7 +232,7 @@ pub type feature_index = u32;
 #[inline]
 pub fn flann_distance_type() -> Result<crate::flann::flann_distance_t> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cvflann_flann_distance_type(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cvflann_flann_distance_type(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -241,7 +241,7 @@ pub fn flann_distance_type() -> Result<crate::flann::flann_distance_t> {
 #[inline]
 pub fn set_distance_type(distance_type: crate::flann::flann_distance_t, order: i32) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cvflann_set_distance_type_flann_distance_t_int(distance_type, order, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cvflann_set_distance_type_flann_distance_t_int(distance_type, order, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -266,7 +266,10 @@ opencv_type_boxed! { AutotunedIndexParams }
 impl Drop for AutotunedIndexParams {
 	fn drop(&mut self) {
 		extern "C" { fn cv_AutotunedIndexParams_delete(instance: *mut c_void); }
-		unsafe { cv_AutotunedIndexParams_delete(self.as_raw_mut_AutotunedIndexParams()) };
+		let ptr = self.as_raw_mut_AutotunedIndexParams();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_AutotunedIndexParams_delete(ptr) };
 	}
 }
 
@@ -297,7 +300,7 @@ impl AutotunedIndexParams {
 	#[inline]
 	pub fn new(target_precision: f32, build_weight: f32, memory_weight: f32, sample_fraction: f32) -> Result<crate::flann::AutotunedIndexParams> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_flann_AutotunedIndexParams_AutotunedIndexParams_float_float_float_float(target_precision, build_weight, memory_weight, sample_fraction, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_flann_AutotunedIndexParams_AutotunedIndexParams_float_float_float_float(target_precision, build_weight, memory_weight, sample_fraction, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::flann::AutotunedIndexParams::opencv_from_extern(ret) };
@@ -306,6 +309,64 @@ impl AutotunedIndexParams {
 	
 }
 
+/// Builder for `AutotunedIndexParams::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct AutotunedIndexParams_NewBuilder {
+	target_precision: f32,
+	build_weight: f32,
+	memory_weight: f32,
+	sample_fraction: f32,
+}
+
+impl AutotunedIndexParams_NewBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { target_precision: 0.8, build_weight: 0.01, memory_weight: 0., sample_fraction: 0.1 }
+	}
+	
+	/// C++ default: `0.8f`
+	#[inline]
+	pub fn with_target_precision(mut self, target_precision: f32) -> Self {
+		self.target_precision = target_precision;
+		self
+	}
+	
+	/// C++ default: `0.01f`
+	#[inline]
+	pub fn with_build_weight(mut self, build_weight: f32) -> Self {
+		self.build_weight = build_weight;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_memory_weight(mut self, memory_weight: f32) -> Self {
+		self.memory_weight = memory_weight;
+		self
+	}
+	
+	/// C++ default: `0.1f`
+	#[inline]
+	pub fn with_sample_fraction(mut self, sample_fraction: f32) -> Self {
+		self.sample_fraction = sample_fraction;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::flann::AutotunedIndexParams> {
+		AutotunedIndexParams::new(self.target_precision, self.build_weight, self.memory_weight, self.sample_fraction)
+	}
+}
+
+impl Default for AutotunedIndexParams_NewBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 boxed_cast_base! { AutotunedIndexParams, crate::flann::IndexParams, cv_AutotunedIndexParams_to_IndexParams }
 
 pub trait CompositeIndexParamsTraitConst: crate::flann::IndexParamsTraitConst {
@@ -327,7 +388,10 @@ opencv_type_boxed! { CompositeIndexParams }
 impl Drop for CompositeIndexParams {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CompositeIndexParams_delete(instance: *mut c_void); }
-		unsafe { cv_CompositeIndexParams_delete(self.as_raw_mut_CompositeIndexParams()) };
+		let ptr = self.as_raw_mut_CompositeIndexParams();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_CompositeIndexParams_delete(ptr) };
 	}
 }
 
@@ -359,7 +423,7 @@ impl CompositeIndexParams {
 	#[inline]
 	pub fn new(trees: i32, branching: i32, iterations: i32, centers_init: crate::flann::flann_centers_init_t, cb_index: f32) -> Result<crate::flann::CompositeIndexParams> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_flann_CompositeIndexParams_CompositeIndexParams_int_int_int_flann_centers_init_t_float(trees, branching, iterations, centers_init, cb_index, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_flann_CompositeIndexParams_CompositeIndexParams_int_int_int_flann_centers_init_t_float(trees, branching, iterations, centers_init, cb_index, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::flann::CompositeIndexParams::opencv_from_extern(ret) };
@@ -368,6 +432,58 @@ impl CompositeIndexParams {
 	
 }
 
+/// Builder for `CompositeIndexParams::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CompositeIndexParams_NewBuilder {
+	trees: i32,
+	branching: i32,
+	iterations: i32,
+	centers_init: crate::flann::flann_centers_init_t,
+	cb_index: f32,
+}
+
+impl CompositeIndexParams_NewBuilder {
+	#[inline]
+	pub fn new(centers_init: crate::flann::flann_centers_init_t) -> Self {
+		Self { trees: 4, branching: 32, iterations: 11, centers_init, cb_index: 0.2 }
+	}
+	
+	/// C++ default: `4`
+	#[inline]
+	pub fn with_trees(mut self, trees: i32) -> Self {
+		self.trees = trees;
+		self
+	}
+	
+	/// C++ default: `32`
+	#[inline]
+	pub fn with_branching(mut self, branching: i32) -> Self {
+		self.branching = branching;
+		self
+	}
+	
+	/// C++ default: `11`
+	#[inline]
+	pub fn with_iterations(mut self, iterations: i32) -> Self {
+		self.iterations = iterations;
+		self
+	}
+	
+	/// C++ default: `0.2f`
+	#[inline]
+	pub fn with_cb_index(mut self, cb_index: f32) -> Self {
+		self.cb_index = cb_index;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::flann::CompositeIndexParams> {
+		CompositeIndexParams::new(self.trees, self.branching, self.iterations, self.centers_init, self.cb_index)
+	}
+}
+
 boxed_cast_base! { CompositeIndexParams, crate::flann::IndexParams, cv_CompositeIndexParams_to_IndexParams }
 
 pub trait HierarchicalClusteringIndexParamsTraitConst: crate::flann::IndexParamsTraitConst {
@@ -389,7 +505,10 @@ opencv_type_boxed! { HierarchicalClusteringIndexParams }
 impl Drop for HierarchicalClusteringIndexParams {
 	fn drop(&mut self) {
 		extern "C" { fn cv_HierarchicalClusteringIndexParams_delete(instance: *mut c_void); }
-		unsafe { cv_HierarchicalClusteringIndexParams_delete(self.as_raw_mut_HierarchicalClusteringIndexParams()) };
+		let ptr = self.as_raw_mut_HierarchicalClusteringIndexParams();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_HierarchicalClusteringIndexParams_delete(ptr) };
 	}
 }
 
@@ -420,7 +539,7 @@ impl HierarchicalClusteringIndexParams {
 	#[inline]
 	pub fn new(branching: i32, centers_init: crate::flann::flann_centers_init_t, trees: i32, leaf_size: i32) -> Result<crate::flann::HierarchicalClusteringIndexParams> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_flann_HierarchicalClusteringIndexParams_HierarchicalClusteringIndexParams_int_flann_centers_init_t_int_int(branching, centers_init, trees, leaf_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_flann_HierarchicalClusteringIndexParams_HierarchicalClusteringIndexParams_int_flann_centers_init_t_int_int(branching, centers_init, trees, leaf_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::flann::HierarchicalClusteringIndexParams::opencv_from_extern(ret) };
@@ -429,6 +548,50 @@ impl HierarchicalClusteringIndexParams {
 	
 }
 
+/// Builder for `HierarchicalClusteringIndexParams::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct HierarchicalClusteringIndexParams_NewBuilder {
+	branching: i32,
+	centers_init: crate::flann::flann_centers_init_t,
+	trees: i32,
+	leaf_size: i32,
+}
+
+impl HierarchicalClusteringIndexParams_NewBuilder {
+	#[inline]
+	pub fn new(centers_init: crate::flann::flann_centers_init_t) -> Self {
+		Self { branching: 32, centers_init, trees: 4, leaf_size: 100 }
+	}
+	
+	/// C++ default: `32`
+	#[inline]
+	pub fn with_branching(mut self, branching: i32) -> Self {
+		self.branching = branching;
+		self
+	}
+	
+	/// C++ default: `4`
+	#[inline]
+	pub fn with_trees(mut self, trees: i32) -> Self {
+		self.trees = trees;
+		self
+	}
+	
+	/// C++ default: `100`
+	#[inline]
+	pub fn with_leaf_size(mut self, leaf_size: i32) -> Self {
+		self.leaf_size = leaf_size;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::flann::HierarchicalClusteringIndexParams> {
+		HierarchicalClusteringIndexParams::new(self.branching, self.centers_init, self.trees, self.leaf_size)
+	}
+}
+
 boxed_cast_base! { HierarchicalClusteringIndexParams, crate::flann::IndexParams, cv_HierarchicalClusteringIndexParams_to_IndexParams }
 
 pub trait IndexTraitConst {
@@ -436,9 +599,10 @@ pub trait IndexTraitConst {
 
 	#[inline]
 	fn save(&self, filename: &str) -> Result<()> {
+		crate::core::validity::check("Index", self.as_raw_Index())?;
 		extern_container_arg!(filename);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_flann_Index_save_const_const_StringR(self.as_raw_Index(), filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_flann_Index_save_const_const_StringR(self.as_raw_Index(), filename.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -446,8 +610,9 @@ pub trait IndexTraitConst {
 	
 	#[inline]
 	fn get_distance(&self) -> Result<crate::flann::flann_distance_t> {
+		crate::core::validity::check("Index", self.as_raw_Index())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_flann_Index_getDistance_const(self.as_raw_Index(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_flann_Index_getDistance_const(self.as_raw_Index(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -455,8 +620,9 @@ pub trait IndexTraitConst {
 	
 	#[inline]
 	fn get_algorithm(&self) -> Result<crate::flann::flann_algorithm_t> {
+		crate::core::validity::check("Index", self.as_raw_Index())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_flann_Index_getAlgorithm_const(self.as_raw_Index(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_flann_Index_getAlgorithm_const(self.as_raw_Index(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -471,9 +637,10 @@ pub trait IndexTrait: crate::flann::IndexTraitConst {
 	/// * dist_type: cvflann::FLANN_DIST_L2
 	#[inline]
 	fn build(&mut self, features: &dyn core::ToInputArray, params: &crate::flann::IndexParams, dist_type: crate::flann::flann_distance_t) -> Result<()> {
+		crate::core::validity::check("Index", self.as_raw_mut_Index())?;
 		input_array_arg!(features);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_flann_Index_build_const__InputArrayR_const_IndexParamsR_flann_distance_t(self.as_raw_mut_Index(), features.as_raw__InputArray(), params.as_raw_IndexParams(), dist_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_flann_Index_build_const__InputArrayR_const_IndexParamsR_flann_distance_t(self.as_raw_mut_Index(), features.as_raw__InputArray(), params.as_raw_IndexParams(), dist_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -483,11 +650,12 @@ pub trait IndexTrait: crate::flann::IndexTraitConst {
 	/// * params: SearchParams()
 	#[inline]
 	fn knn_search(&mut self, query: &dyn core::ToInputArray, indices: &mut dyn core::ToOutputArray, dists: &mut dyn core::ToOutputArray, knn: i32, params: &crate::flann::SearchParams) -> Result<()> {
+		crate::core::validity::check("Index", self.as_raw_mut_Index())?;
 		input_array_arg!(query);
 		output_array_arg!(indices);
 		output_array_arg!(dists);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_flann_Index_knnSearch_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_int_const_SearchParamsR(self.as_raw_mut_Index(), query.as_raw__InputArray(), indices.as_raw__OutputArray(), dists.as_raw__OutputArray(), knn, params.as_raw_SearchParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_flann_Index_knnSearch_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_int_const_SearchParamsR(self.as_raw_mut_Index(), query.as_raw__InputArray(), indices.as_raw__OutputArray(), dists.as_raw__OutputArray(), knn, params.as_raw_SearchParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -497,11 +665,12 @@ pub trait IndexTrait: crate::flann::IndexTraitConst {
 	/// * params: SearchParams()
 	#[inline]
 	fn radius_search(&mut self, query: &dyn core::ToInputArray, indices: &mut dyn core::ToOutputArray, dists: &mut dyn core::ToOutputArray, radius: f64, max_results: i32, params: &crate::flann::SearchParams) -> Result<i32> {
+		crate::core::validity::check("Index", self.as_raw_mut_Index())?;
 		input_array_arg!(query);
 		output_array_arg!(indices);
 		output_array_arg!(dists);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_flann_Index_radiusSearch_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_double_int_const_SearchParamsR(self.as_raw_mut_Index(), query.as_raw__InputArray(), indices.as_raw__OutputArray(), dists.as_raw__OutputArray(), radius, max_results, params.as_raw_SearchParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_flann_Index_radiusSearch_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_double_int_const_SearchParamsR(self.as_raw_mut_Index(), query.as_raw__InputArray(), indices.as_raw__OutputArray(), dists.as_raw__OutputArray(), radius, max_results, params.as_raw_SearchParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -509,10 +678,11 @@ pub trait IndexTrait: crate::flann::IndexTraitConst {
 	
 	#[inline]
 	fn load(&mut self, features: &dyn core::ToInputArray, filename: &str) -> Result<bool> {
+		crate::core::validity::check("Index", self.as_raw_mut_Index())?;
 		input_array_arg!(features);
 		extern_container_arg!(filename);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_flann_Index_load_const__InputArrayR_const_StringR(self.as_raw_mut_Index(), features.as_raw__InputArray(), filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_flann_Index_load_const__InputArrayR_const_StringR(self.as_raw_mut_Index(), features.as_raw__InputArray(), filename.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -520,8 +690,9 @@ pub trait IndexTrait: crate::flann::IndexTraitConst {
 	
 	#[inline]
 	fn release(&mut self) -> Result<()> {
+		crate::core::validity::check("Index", self.as_raw_mut_Index())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_flann_Index_release(self.as_raw_mut_Index(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_flann_Index_release(self.as_raw_mut_Index(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -538,7 +709,10 @@ opencv_type_boxed! { Index }
 impl Drop for Index {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Index_delete(instance: *mut c_void); }
-		unsafe { cv_Index_delete(self.as_raw_mut_Index()) };
+		let ptr = self.as_raw_mut_Index();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Index_delete(ptr) };
 	}
 }
 
@@ -556,7 +730,7 @@ impl Index {
 	#[inline]
 	pub fn default() -> Result<crate::flann::Index> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_flann_Index_Index(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_flann_Index_Index(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::flann::Index::opencv_from_extern(ret) };
@@ -569,7 +743,7 @@ impl Index {
 	pub fn new(features: &dyn core::ToInputArray, params: &crate::flann::IndexParams, dist_type: crate::flann::flann_distance_t) -> Result<crate::flann::Index> {
 		input_array_arg!(features);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_flann_Index_Index_const__InputArrayR_const_IndexParamsR_flann_distance_t(features.as_raw__InputArray(), params.as_raw_IndexParams(), dist_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_flann_Index_Index_const__InputArrayR_const_IndexParamsR_flann_distance_t(features.as_raw__InputArray(), params.as_raw_IndexParams(), dist_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::flann::Index::opencv_from_extern(ret) };
@@ -585,10 +759,11 @@ pub trait IndexParamsTraitConst {
 	/// * default_val: String()
 	#[inline]
 	fn get_string(&self, key: &str, default_val: &str) -> Result<String> {
+		crate::core::validity::check("IndexParams", self.as_raw_IndexParams())?;
 		extern_container_arg!(key);
 		extern_container_arg!(default_val);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_flann_IndexParams_getString_const_const_StringR_const_StringR(self.as_raw_IndexParams(), key.opencv_as_extern(), default_val.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_flann_IndexParams_getString_const_const_StringR_const_StringR(self.as_raw_IndexParams(), key.opencv_as_extern(), default_val.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -599,9 +774,10 @@ pub trait IndexParamsTraitConst {
 	/// * default_val: -1
 	#[inline]
 	fn get_int(&self, key: &str, default_val: i32) -> Result<i32> {
+		crate::core::validity::check("IndexParams", self.as_raw_IndexParams())?;
 		extern_container_arg!(key);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_flann_IndexParams_getInt_const_const_StringR_int(self.as_raw_IndexParams(), key.opencv_as_extern(), default_val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_flann_IndexParams_getInt_const_const_StringR_int(self.as_raw_IndexParams(), key.opencv_as_extern(), default_val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -611,9 +787,10 @@ pub trait IndexParamsTraitConst {
 	/// * default_val: -1
 	#[inline]
 	fn get_double(&self, key: &str, default_val: f64) -> Result<f64> {
+		crate::core::validity::check("IndexParams", self.as_raw_IndexParams())?;
 		extern_container_arg!(key);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_flann_IndexParams_getDouble_const_const_StringR_double(self.as_raw_IndexParams(), key.opencv_as_extern(), default_val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_flann_IndexParams_getDouble_const_const_StringR_double(self.as_raw_IndexParams(), key.opencv_as_extern(), default_val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -621,8 +798,9 @@ pub trait IndexParamsTraitConst {
 	
 	#[inline]
 	fn get_all(&self, names: &mut core::Vector<String>, types: &mut core::Vector<crate::flann::FlannIndexType>, str_values: &mut core::Vector<String>, num_values: &mut core::Vector<f64>) -> Result<()> {
+		crate::core::validity::check("IndexParams", self.as_raw_IndexParams())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_flann_IndexParams_getAll_const_vector_String_R_vector_FlannIndexType_R_vector_String_R_vector_double_R(self.as_raw_IndexParams(), names.as_raw_mut_VectorOfString(), types.as_raw_mut_VectorOfFlannIndexType(), str_values.as_raw_mut_VectorOfString(), num_values.as_raw_mut_VectorOff64(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_flann_IndexParams_getAll_const_vector_String_R_vector_FlannIndexType_R_vector_String_R_vector_double_R(self.as_raw_IndexParams(), names.as_raw_mut_VectorOfString(), types.as_raw_mut_VectorOfFlannIndexType(), str_values.as_raw_mut_VectorOfString(), num_values.as_raw_mut_VectorOff64(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -635,22 +813,33 @@ pub trait IndexParamsTrait: crate::flann::IndexParamsTraitConst {
 
 	#[inline]
 	fn params(&mut self) -> *mut c_void {
-		let ret = unsafe { sys::cv_flann_IndexParams_getPropParams(self.as_raw_mut_IndexParams()) };
+		crate::core::validity::debug_check("IndexParams", self.as_raw_mut_IndexParams());
+		let ret = unsafe { ffi_call!(cv_flann_IndexParams_getPropParams(self.as_raw_mut_IndexParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [params](IndexParamsTrait::params), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_params(&mut self) -> Result<*mut c_void> {
+		crate::core::validity::check("IndexParams", self.as_raw_mut_IndexParams())?;
+		Ok(IndexParamsTrait::params(self))
+	}
+	
 	#[inline]
 	unsafe fn set_params(&mut self, val: *mut c_void) {
-		let ret = { sys::cv_flann_IndexParams_setPropParams_voidX(self.as_raw_mut_IndexParams(), val) };
+		crate::core::validity::debug_check("IndexParams", self.as_raw_mut_IndexParams());
+		let ret = { ffi_call!(cv_flann_IndexParams_setPropParams_voidX(self.as_raw_mut_IndexParams(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_string(&mut self, key: &str, value: &str) -> Result<()> {
+		crate::core::validity::check("IndexParams", self.as_raw_mut_IndexParams())?;
 		extern_container_arg!(key);
 		extern_container_arg!(value);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_flann_IndexParams_setString_const_StringR_const_StringR(self.as_raw_mut_IndexParams(), key.opencv_as_extern(), value.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_flann_IndexParams_setString_const_StringR_const_StringR(self.as_raw_mut_IndexParams(), key.opencv_as_extern(), value.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -658,9 +847,10 @@ pub trait IndexParamsTrait: crate::flann::IndexParamsTraitConst {
 	
 	#[inline]
 	fn set_int(&mut self, key: &str, value: i32) -> Result<()> {
+		crate::core::validity::check("IndexParams", self.as_raw_mut_IndexParams())?;
 		extern_container_arg!(key);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_flann_IndexParams_setInt_const_StringR_int(self.as_raw_mut_IndexParams(), key.opencv_as_extern(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_flann_IndexParams_setInt_const_StringR_int(self.as_raw_mut_IndexParams(), key.opencv_as_extern(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -668,9 +858,10 @@ pub trait IndexParamsTrait: crate::flann::IndexParamsTraitConst {
 	
 	#[inline]
 	fn set_double(&mut self, key: &str, value: f64) -> Result<()> {
+		crate::core::validity::check("IndexParams", self.as_raw_mut_IndexParams())?;
 		extern_container_arg!(key);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_flann_IndexParams_setDouble_const_StringR_double(self.as_raw_mut_IndexParams(), key.opencv_as_extern(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_flann_IndexParams_setDouble_const_StringR_double(self.as_raw_mut_IndexParams(), key.opencv_as_extern(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -678,9 +869,10 @@ pub trait IndexParamsTrait: crate::flann::IndexParamsTraitConst {
 	
 	#[inline]
 	fn set_float(&mut self, key: &str, value: f32) -> Result<()> {
+		crate::core::validity::check("IndexParams", self.as_raw_mut_IndexParams())?;
 		extern_container_arg!(key);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_flann_IndexParams_setFloat_const_StringR_float(self.as_raw_mut_IndexParams(), key.opencv_as_extern(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_flann_IndexParams_setFloat_const_StringR_float(self.as_raw_mut_IndexParams(), key.opencv_as_extern(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -688,9 +880,10 @@ pub trait IndexParamsTrait: crate::flann::IndexParamsTraitConst {
 	
 	#[inline]
 	fn set_bool(&mut self, key: &str, value: bool) -> Result<()> {
+		crate::core::validity::check("IndexParams", self.as_raw_mut_IndexParams())?;
 		extern_container_arg!(key);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_flann_IndexParams_setBool_const_StringR_bool(self.as_raw_mut_IndexParams(), key.opencv_as_extern(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_flann_IndexParams_setBool_const_StringR_bool(self.as_raw_mut_IndexParams(), key.opencv_as_extern(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -698,8 +891,9 @@ pub trait IndexParamsTrait: crate::flann::IndexParamsTraitConst {
 	
 	#[inline]
 	fn set_algorithm(&mut self, value: i32) -> Result<()> {
+		crate::core::validity::check("IndexParams", self.as_raw_mut_IndexParams())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_flann_IndexParams_setAlgorithm_int(self.as_raw_mut_IndexParams(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_flann_IndexParams_setAlgorithm_int(self.as_raw_mut_IndexParams(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -716,7 +910,10 @@ opencv_type_boxed! { IndexParams }
 impl Drop for IndexParams {
 	fn drop(&mut self) {
 		extern "C" { fn cv_IndexParams_delete(instance: *mut c_void); }
-		unsafe { cv_IndexParams_delete(self.as_raw_mut_IndexParams()) };
+		let ptr = self.as_raw_mut_IndexParams();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_IndexParams_delete(ptr) };
 	}
 }
 
@@ -734,7 +931,7 @@ impl IndexParams {
 	#[inline]
 	pub fn default() -> Result<crate::flann::IndexParams> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_flann_IndexParams_IndexParams(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_flann_IndexParams_IndexParams(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::flann::IndexParams::opencv_from_extern(ret) };
@@ -762,7 +959,10 @@ opencv_type_boxed! { KDTreeIndexParams }
 impl Drop for KDTreeIndexParams {
 	fn drop(&mut self) {
 		extern "C" { fn cv_KDTreeIndexParams_delete(instance: *mut c_void); }
-		unsafe { cv_KDTreeIndexParams_delete(self.as_raw_mut_KDTreeIndexParams()) };
+		let ptr = self.as_raw_mut_KDTreeIndexParams();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_KDTreeIndexParams_delete(ptr) };
 	}
 }
 
@@ -790,7 +990,7 @@ impl KDTreeIndexParams {
 	#[inline]
 	pub fn new(trees: i32) -> Result<crate::flann::KDTreeIndexParams> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_flann_KDTreeIndexParams_KDTreeIndexParams_int(trees, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_flann_KDTreeIndexParams_KDTreeIndexParams_int(trees, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::flann::KDTreeIndexParams::opencv_from_extern(ret) };
@@ -820,7 +1020,10 @@ opencv_type_boxed! { KMeansIndexParams }
 impl Drop for KMeansIndexParams {
 	fn drop(&mut self) {
 		extern "C" { fn cv_KMeansIndexParams_delete(instance: *mut c_void); }
-		unsafe { cv_KMeansIndexParams_delete(self.as_raw_mut_KMeansIndexParams()) };
+		let ptr = self.as_raw_mut_KMeansIndexParams();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_KMeansIndexParams_delete(ptr) };
 	}
 }
 
@@ -851,7 +1054,7 @@ impl KMeansIndexParams {
 	#[inline]
 	pub fn new(branching: i32, iterations: i32, centers_init: crate::flann::flann_centers_init_t, cb_index: f32) -> Result<crate::flann::KMeansIndexParams> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_flann_KMeansIndexParams_KMeansIndexParams_int_int_flann_centers_init_t_float(branching, iterations, centers_init, cb_index, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_flann_KMeansIndexParams_KMeansIndexParams_int_int_flann_centers_init_t_float(branching, iterations, centers_init, cb_index, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::flann::KMeansIndexParams::opencv_from_extern(ret) };
@@ -860,6 +1063,50 @@ impl KMeansIndexParams {
 	
 }
 
+/// Builder for `KMeansIndexParams::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct KMeansIndexParams_NewBuilder {
+	branching: i32,
+	iterations: i32,
+	centers_init: crate::flann::flann_centers_init_t,
+	cb_index: f32,
+}
+
+impl KMeansIndexParams_NewBuilder {
+	#[inline]
+	pub fn new(centers_init: crate::flann::flann_centers_init_t) -> Self {
+		Self { branching: 32, iterations: 11, centers_init, cb_index: 0.2 }
+	}
+	
+	/// C++ default: `32`
+	#[inline]
+	pub fn with_branching(mut self, branching: i32) -> Self {
+		self.branching = branching;
+		self
+	}
+	
+	/// C++ default: `11`
+	#[inline]
+	pub fn with_iterations(mut self, iterations: i32) -> Self {
+		self.iterations = iterations;
+		self
+	}
+	
+	/// C++ default: `0.2f`
+	#[inline]
+	pub fn with_cb_index(mut self, cb_index: f32) -> Self {
+		self.cb_index = cb_index;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::flann::KMeansIndexParams> {
+		KMeansIndexParams::new(self.branching, self.iterations, self.centers_init, self.cb_index)
+	}
+}
+
 boxed_cast_base! { KMeansIndexParams, crate::flann::IndexParams, cv_KMeansIndexParams_to_IndexParams }
 
 pub trait LinearIndexParamsTraitConst: crate::flann::IndexParamsTraitConst {
@@ -881,7 +1128,10 @@ opencv_type_boxed! { LinearIndexParams }
 impl Drop for LinearIndexParams {
 	fn drop(&mut self) {
 		extern "C" { fn cv_LinearIndexParams_delete(instance: *mut c_void); }
-		unsafe { cv_LinearIndexParams_delete(self.as_raw_mut_LinearIndexParams()) };
+		let ptr = self.as_raw_mut_LinearIndexParams();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_LinearIndexParams_delete(ptr) };
 	}
 }
 
@@ -907,7 +1157,7 @@ impl LinearIndexParams {
 	#[inline]
 	pub fn default() -> Result<crate::flann::LinearIndexParams> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_flann_LinearIndexParams_LinearIndexParams(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_flann_LinearIndexParams_LinearIndexParams(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::flann::LinearIndexParams::opencv_from_extern(ret) };
@@ -937,7 +1187,10 @@ opencv_type_boxed! { LshIndexParams }
 impl Drop for LshIndexParams {
 	fn drop(&mut self) {
 		extern "C" { fn cv_LshIndexParams_delete(instance: *mut c_void); }
-		unsafe { cv_LshIndexParams_delete(self.as_raw_mut_LshIndexParams()) };
+		let ptr = self.as_raw_mut_LshIndexParams();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_LshIndexParams_delete(ptr) };
 	}
 }
 
@@ -963,7 +1216,7 @@ impl LshIndexParams {
 	#[inline]
 	pub fn new(table_number: i32, key_size: i32, multi_probe_level: i32) -> Result<crate::flann::LshIndexParams> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_flann_LshIndexParams_LshIndexParams_int_int_int(table_number, key_size, multi_probe_level, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_flann_LshIndexParams_LshIndexParams_int_int_int(table_number, key_size, multi_probe_level, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::flann::LshIndexParams::opencv_from_extern(ret) };
@@ -993,7 +1246,10 @@ opencv_type_boxed! { SavedIndexParams }
 impl Drop for SavedIndexParams {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SavedIndexParams_delete(instance: *mut c_void); }
-		unsafe { cv_SavedIndexParams_delete(self.as_raw_mut_SavedIndexParams()) };
+		let ptr = self.as_raw_mut_SavedIndexParams();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_SavedIndexParams_delete(ptr) };
 	}
 }
 
@@ -1020,7 +1276,7 @@ impl SavedIndexParams {
 	pub fn new(filename: &str) -> Result<crate::flann::SavedIndexParams> {
 		extern_container_arg!(filename);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_flann_SavedIndexParams_SavedIndexParams_const_StringR(filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_flann_SavedIndexParams_SavedIndexParams_const_StringR(filename.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::flann::SavedIndexParams::opencv_from_extern(ret) };
@@ -1050,7 +1306,10 @@ opencv_type_boxed! { SearchParams }
 impl Drop for SearchParams {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SearchParams_delete(instance: *mut c_void); }
-		unsafe { cv_SearchParams_delete(self.as_raw_mut_SearchParams()) };
+		let ptr = self.as_raw_mut_SearchParams();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_SearchParams_delete(ptr) };
 	}
 }
 
@@ -1076,7 +1335,7 @@ impl SearchParams {
 	#[inline]
 	pub fn new(checks: i32, eps: f32, sorted: bool, explore_all_trees: bool) -> Result<crate::flann::SearchParams> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_flann_SearchParams_SearchParams_int_float_bool_bool(checks, eps, sorted, explore_all_trees, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_flann_SearchParams_SearchParams_int_float_bool_bool(checks, eps, sorted, explore_all_trees, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::flann::SearchParams::opencv_from_extern(ret) };
@@ -1090,7 +1349,7 @@ impl SearchParams {
 	#[inline]
 	pub fn new_1(checks: i32, eps: f32, sorted: bool) -> Result<crate::flann::SearchParams> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_flann_SearchParams_SearchParams_int_float_bool(checks, eps, sorted, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_flann_SearchParams_SearchParams_int_float_bool(checks, eps, sorted, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::flann::SearchParams::opencv_from_extern(ret) };
@@ -1099,4 +1358,54 @@ impl SearchParams {
 	
 }
 
+/// Builder for `SearchParams::new_1()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct SearchParams_New1Builder {
+	checks: i32,
+	eps: f32,
+	sorted: bool,
+}
+
+impl SearchParams_New1Builder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { checks: 32, eps: 0., sorted: true }
+	}
+	
+	/// C++ default: `32`
+	#[inline]
+	pub fn with_checks(mut self, checks: i32) -> Self {
+		self.checks = checks;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_eps(mut self, eps: f32) -> Self {
+		self.eps = eps;
+		self
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_sorted(mut self, sorted: bool) -> Self {
+		self.sorted = sorted;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::flann::SearchParams> {
+		SearchParams::new_1(self.checks, self.eps, self.sorted)
+	}
+}
+
+impl Default for SearchParams_New1Builder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 boxed_cast_base! { SearchParams, crate::flann::IndexParams, cv_SearchParams_to_IndexParams }
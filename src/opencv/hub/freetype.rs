@@ -30,7 +30,7 @@ pub mod prelude {
 #[inline]
 pub fn create_free_type2() -> Result<core::Ptr<dyn crate::freetype::FreeType2>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_freetype_createFreeType2(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_freetype_createFreeType2(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::freetype::FreeType2>::opencv_from_extern(ret) };
@@ -54,9 +54,10 @@ pub trait FreeType2: core::AlgorithmTrait + crate::freetype::FreeType2Const {
 	/// * id: face_index to select a font faces in a single file.
 	#[inline]
 	fn load_font_data(&mut self, font_file_name: &str, id: i32) -> Result<()> {
+		crate::core::validity::check("FreeType2", self.as_raw_mut_FreeType2())?;
 		extern_container_arg!(mut font_file_name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_freetype_FreeType2_loadFontData_String_int(self.as_raw_mut_FreeType2(), font_file_name.opencv_as_extern_mut(), id, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_freetype_FreeType2_loadFontData_String_int(self.as_raw_mut_FreeType2(), font_file_name.opencv_as_extern_mut(), id, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -72,8 +73,9 @@ pub trait FreeType2: core::AlgorithmTrait + crate::freetype::FreeType2Const {
 	/// * num: number of split points from bezier-curve to line
 	#[inline]
 	fn set_split_number(&mut self, num: i32) -> Result<()> {
+		crate::core::validity::check("FreeType2", self.as_raw_mut_FreeType2())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_freetype_FreeType2_setSplitNumber_int(self.as_raw_mut_FreeType2(), num, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_freetype_FreeType2_setSplitNumber_int(self.as_raw_mut_FreeType2(), num, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -94,10 +96,11 @@ pub trait FreeType2: core::AlgorithmTrait + crate::freetype::FreeType2Const {
 	/// * bottomLeftOrigin: When true, the image data origin is at the bottom-left corner. Otherwise, it is at the top-left corner.
 	#[inline]
 	fn put_text(&mut self, img: &mut dyn core::ToInputOutputArray, text: &str, org: core::Point, font_height: i32, color: core::Scalar, thickness: i32, line_type: i32, bottom_left_origin: bool) -> Result<()> {
+		crate::core::validity::check("FreeType2", self.as_raw_mut_FreeType2())?;
 		input_output_array_arg!(img);
 		extern_container_arg!(text);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_freetype_FreeType2_putText_const__InputOutputArrayR_const_StringR_Point_int_Scalar_int_int_bool(self.as_raw_mut_FreeType2(), img.as_raw__InputOutputArray(), text.opencv_as_extern(), org.opencv_as_extern(), font_height, color.opencv_as_extern(), thickness, line_type, bottom_left_origin, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_freetype_FreeType2_putText_const__InputOutputArrayR_const_StringR_Point_int_Scalar_int_int_bool(self.as_raw_mut_FreeType2(), img.as_raw__InputOutputArray(), text.opencv_as_extern(), org.opencv_as_extern(), font_height, color.opencv_as_extern(), thickness, line_type, bottom_left_origin, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -154,17 +157,18 @@ pub trait FreeType2: core::AlgorithmTrait + crate::freetype::FreeType2Const {
 	/// * text: Input text string.
 	/// * fontHeight: Drawing font size by pixel unit.
 	/// * thickness: Thickness of lines used to render the text. See putText for details.
-	/// * baseLine:[out] y-coordinate of the baseline relative to the bottom-most text
+	/// * baseLine:\[out\] y-coordinate of the baseline relative to the bottom-most text
 	/// point.
 	/// ## Returns
 	/// The size of a box that contains the specified text.
 	/// ## See also
-	/// cv::putText
+	/// `put_text`
 	#[inline]
 	fn get_text_size(&mut self, text: &str, font_height: i32, thickness: i32, base_line: &mut i32) -> Result<core::Size> {
+		crate::core::validity::check("FreeType2", self.as_raw_mut_FreeType2())?;
 		extern_container_arg!(text);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_freetype_FreeType2_getTextSize_const_StringR_int_int_intX(self.as_raw_mut_FreeType2(), text.opencv_as_extern(), font_height, thickness, base_line, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_freetype_FreeType2_getTextSize_const_StringR_int_int_intX(self.as_raw_mut_FreeType2(), text.opencv_as_extern(), font_height, thickness, base_line, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
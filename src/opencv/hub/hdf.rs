@@ -82,7 +82,7 @@ pub const HDF5_H5_UNLIMITED: i32 = -1;
 pub fn open(hdf5_filename: &str) -> Result<core::Ptr<dyn crate::hdf::HDF5>> {
 	extern_container_arg!(hdf5_filename);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_hdf_open_const_StringR(hdf5_filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_hdf_open_const_StringR(hdf5_filename.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::hdf::HDF5>::opencv_from_extern(ret) };
@@ -105,9 +105,10 @@ pub trait HDF5Const {
 	/// Note: Checks if dataset, group or other object type (hdf5 link) exists under the label name. It is thread safe.
 	#[inline]
 	fn hlexists(&self, label: &str) -> Result<bool> {
+		crate::core::validity::check("HDF5", self.as_raw_HDF5())?;
 		extern_container_arg!(label);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_hlexists_const_const_StringR(self.as_raw_HDF5(), label.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_hlexists_const_const_StringR(self.as_raw_HDF5(), label.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -120,12 +121,13 @@ pub trait HDF5Const {
 	/// ## Returns
 	/// true if the attribute exists, false otherwise.
 	/// ## See also
-	/// atdelete, atwrite, atread
+	/// `atdelete`, `atwrite`, `atread`
 	#[inline]
 	fn atexists(&self, atlabel: &str) -> Result<bool> {
+		crate::core::validity::check("HDF5", self.as_raw_HDF5())?;
 		extern_container_arg!(atlabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_atexists_const_const_StringR(self.as_raw_HDF5(), atlabel.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_atexists_const_const_StringR(self.as_raw_HDF5(), atlabel.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -212,9 +214,10 @@ pub trait HDF5Const {
 	/// ## Overloaded parameters
 	#[inline]
 	fn dscreate(&self, rows: i32, cols: i32, typ: i32, dslabel: &str) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_HDF5())?;
 		extern_container_arg!(dslabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_dscreate_const_const_int_const_int_const_int_const_StringR(self.as_raw_HDF5(), rows, cols, typ, dslabel.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_dscreate_const_const_int_const_int_const_int_const_StringR(self.as_raw_HDF5(), rows, cols, typ, dslabel.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -301,9 +304,10 @@ pub trait HDF5Const {
 	/// ## Overloaded parameters
 	#[inline]
 	fn dscreate_1(&self, rows: i32, cols: i32, typ: i32, dslabel: &str, compresslevel: i32) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_HDF5())?;
 		extern_container_arg!(dslabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_dscreate_const_const_int_const_int_const_int_const_StringR_const_int(self.as_raw_HDF5(), rows, cols, typ, dslabel.opencv_as_extern(), compresslevel, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_dscreate_const_const_int_const_int_const_int_const_StringR_const_int(self.as_raw_HDF5(), rows, cols, typ, dslabel.opencv_as_extern(), compresslevel, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -390,9 +394,10 @@ pub trait HDF5Const {
 	/// ## Overloaded parameters
 	#[inline]
 	fn dscreate_2(&self, rows: i32, cols: i32, typ: i32, dslabel: &str, compresslevel: i32, dims_chunks: &core::Vector<i32>) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_HDF5())?;
 		extern_container_arg!(dslabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_dscreate_const_const_int_const_int_const_int_const_StringR_const_int_const_vector_int_R(self.as_raw_HDF5(), rows, cols, typ, dslabel.opencv_as_extern(), compresslevel, dims_chunks.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_dscreate_const_const_int_const_int_const_int_const_StringR_const_int_const_vector_int_R(self.as_raw_HDF5(), rows, cols, typ, dslabel.opencv_as_extern(), compresslevel, dims_chunks.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -474,9 +479,10 @@ pub trait HDF5Const {
 	/// Multiple datasets inside a single hdf5 file are allowed.
 	#[inline]
 	fn dscreate_3(&self, rows: i32, cols: i32, typ: i32, dslabel: &str, compresslevel: i32, dims_chunks: &i32) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_HDF5())?;
 		extern_container_arg!(dslabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_dscreate_const_const_int_const_int_const_int_const_StringR_const_int_const_intX(self.as_raw_HDF5(), rows, cols, typ, dslabel.opencv_as_extern(), compresslevel, dims_chunks, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_dscreate_const_const_int_const_int_const_int_const_StringR_const_int_const_intX(self.as_raw_HDF5(), rows, cols, typ, dslabel.opencv_as_extern(), compresslevel, dims_chunks, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -484,9 +490,10 @@ pub trait HDF5Const {
 	
 	#[inline]
 	fn dscreate_4(&self, n_dims: i32, sizes: &i32, typ: i32, dslabel: &str) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_HDF5())?;
 		extern_container_arg!(dslabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_dscreate_const_const_int_const_intX_const_int_const_StringR(self.as_raw_HDF5(), n_dims, sizes, typ, dslabel.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_dscreate_const_const_int_const_intX_const_int_const_StringR(self.as_raw_HDF5(), n_dims, sizes, typ, dslabel.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -494,9 +501,10 @@ pub trait HDF5Const {
 	
 	#[inline]
 	fn dscreate_5(&self, n_dims: i32, sizes: &i32, typ: i32, dslabel: &str, compresslevel: i32) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_HDF5())?;
 		extern_container_arg!(dslabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_dscreate_const_const_int_const_intX_const_int_const_StringR_const_int(self.as_raw_HDF5(), n_dims, sizes, typ, dslabel.opencv_as_extern(), compresslevel, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_dscreate_const_const_int_const_intX_const_int_const_StringR_const_int(self.as_raw_HDF5(), n_dims, sizes, typ, dslabel.opencv_as_extern(), compresslevel, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -507,9 +515,10 @@ pub trait HDF5Const {
 	/// * dims_chunks: vector<int>()
 	#[inline]
 	fn dscreate_6(&self, sizes: &core::Vector<i32>, typ: i32, dslabel: &str, compresslevel: i32, dims_chunks: &core::Vector<i32>) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_HDF5())?;
 		extern_container_arg!(dslabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_dscreate_const_const_vector_int_R_const_int_const_StringR_const_int_const_vector_int_R(self.as_raw_HDF5(), sizes.as_raw_VectorOfi32(), typ, dslabel.opencv_as_extern(), compresslevel, dims_chunks.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_dscreate_const_const_vector_int_R_const_int_const_StringR_const_int_const_vector_int_R(self.as_raw_HDF5(), sizes.as_raw_VectorOfi32(), typ, dslabel.opencv_as_extern(), compresslevel, dims_chunks.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -594,9 +603,10 @@ pub trait HDF5Const {
 	/// 
 	#[inline]
 	fn dscreate_7(&self, n_dims: i32, sizes: &i32, typ: i32, dslabel: &str, compresslevel: i32, dims_chunks: &i32) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_HDF5())?;
 		extern_container_arg!(dslabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_dscreate_const_const_int_const_intX_const_int_const_StringR_const_int_const_intX(self.as_raw_HDF5(), n_dims, sizes, typ, dslabel.opencv_as_extern(), compresslevel, dims_chunks, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_dscreate_const_const_int_const_intX_const_int_const_StringR_const_int_const_intX(self.as_raw_HDF5(), n_dims, sizes, typ, dslabel.opencv_as_extern(), compresslevel, dims_chunks, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -623,9 +633,10 @@ pub trait HDF5Const {
 	/// * dims_flag: HDF5::H5_GETDIMS
 	#[inline]
 	fn dsgetsize(&self, dslabel: &str, dims_flag: i32) -> Result<core::Vector<i32>> {
+		crate::core::validity::check("HDF5", self.as_raw_HDF5())?;
 		extern_container_arg!(dslabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_dsgetsize_const_const_StringR_int(self.as_raw_HDF5(), dslabel.opencv_as_extern(), dims_flag, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_dsgetsize_const_const_StringR_int(self.as_raw_HDF5(), dslabel.opencv_as_extern(), dims_flag, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<i32>::opencv_from_extern(ret) };
@@ -644,9 +655,10 @@ pub trait HDF5Const {
 	/// It is thread safe.
 	#[inline]
 	fn dsgettype(&self, dslabel: &str) -> Result<i32> {
+		crate::core::validity::check("HDF5", self.as_raw_HDF5())?;
 		extern_container_arg!(dslabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_dsgettype_const_const_StringR(self.as_raw_HDF5(), dslabel.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_dsgettype_const_const_StringR(self.as_raw_HDF5(), dslabel.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -654,10 +666,11 @@ pub trait HDF5Const {
 	
 	#[inline]
 	fn dswrite(&self, array: &dyn core::ToInputArray, dslabel: &str) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_HDF5())?;
 		input_array_arg!(array);
 		extern_container_arg!(dslabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_dswrite_const_const__InputArrayR_const_StringR(self.as_raw_HDF5(), array.as_raw__InputArray(), dslabel.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_dswrite_const_const__InputArrayR_const_StringR(self.as_raw_HDF5(), array.as_raw__InputArray(), dslabel.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -665,10 +678,11 @@ pub trait HDF5Const {
 	
 	#[inline]
 	fn dswrite_1(&self, array: &dyn core::ToInputArray, dslabel: &str, dims_offset: &i32) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_HDF5())?;
 		input_array_arg!(array);
 		extern_container_arg!(dslabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_dswrite_const_const__InputArrayR_const_StringR_const_intX(self.as_raw_HDF5(), array.as_raw__InputArray(), dslabel.opencv_as_extern(), dims_offset, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_dswrite_const_const__InputArrayR_const_StringR_const_intX(self.as_raw_HDF5(), array.as_raw__InputArray(), dslabel.opencv_as_extern(), dims_offset, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -678,10 +692,11 @@ pub trait HDF5Const {
 	/// * dims_counts: vector<int>()
 	#[inline]
 	fn dswrite_2(&self, array: &dyn core::ToInputArray, dslabel: &str, dims_offset: &core::Vector<i32>, dims_counts: &core::Vector<i32>) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_HDF5())?;
 		input_array_arg!(array);
 		extern_container_arg!(dslabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_dswrite_const_const__InputArrayR_const_StringR_const_vector_int_R_const_vector_int_R(self.as_raw_HDF5(), array.as_raw__InputArray(), dslabel.opencv_as_extern(), dims_offset.as_raw_VectorOfi32(), dims_counts.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_dswrite_const_const__InputArrayR_const_StringR_const_vector_int_R_const_vector_int_R(self.as_raw_HDF5(), array.as_raw__InputArray(), dslabel.opencv_as_extern(), dims_offset.as_raw_VectorOfi32(), dims_counts.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -754,10 +769,11 @@ pub trait HDF5Const {
 	/// 
 	#[inline]
 	fn dswrite_3(&self, array: &dyn core::ToInputArray, dslabel: &str, dims_offset: &i32, dims_counts: &i32) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_HDF5())?;
 		input_array_arg!(array);
 		extern_container_arg!(dslabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_dswrite_const_const__InputArrayR_const_StringR_const_intX_const_intX(self.as_raw_HDF5(), array.as_raw__InputArray(), dslabel.opencv_as_extern(), dims_offset, dims_counts, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_dswrite_const_const__InputArrayR_const_StringR_const_intX_const_intX(self.as_raw_HDF5(), array.as_raw__InputArray(), dslabel.opencv_as_extern(), dims_offset, dims_counts, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -765,10 +781,11 @@ pub trait HDF5Const {
 	
 	#[inline]
 	fn dsinsert(&self, array: &dyn core::ToInputArray, dslabel: &str) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_HDF5())?;
 		input_array_arg!(array);
 		extern_container_arg!(dslabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_dsinsert_const_const__InputArrayR_const_StringR(self.as_raw_HDF5(), array.as_raw__InputArray(), dslabel.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_dsinsert_const_const__InputArrayR_const_StringR(self.as_raw_HDF5(), array.as_raw__InputArray(), dslabel.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -776,10 +793,11 @@ pub trait HDF5Const {
 	
 	#[inline]
 	fn dsinsert_1(&self, array: &dyn core::ToInputArray, dslabel: &str, dims_offset: &i32) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_HDF5())?;
 		input_array_arg!(array);
 		extern_container_arg!(dslabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_dsinsert_const_const__InputArrayR_const_StringR_const_intX(self.as_raw_HDF5(), array.as_raw__InputArray(), dslabel.opencv_as_extern(), dims_offset, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_dsinsert_const_const__InputArrayR_const_StringR_const_intX(self.as_raw_HDF5(), array.as_raw__InputArray(), dslabel.opencv_as_extern(), dims_offset, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -789,10 +807,11 @@ pub trait HDF5Const {
 	/// * dims_counts: vector<int>()
 	#[inline]
 	fn dsinsert_2(&self, array: &dyn core::ToInputArray, dslabel: &str, dims_offset: &core::Vector<i32>, dims_counts: &core::Vector<i32>) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_HDF5())?;
 		input_array_arg!(array);
 		extern_container_arg!(dslabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_dsinsert_const_const__InputArrayR_const_StringR_const_vector_int_R_const_vector_int_R(self.as_raw_HDF5(), array.as_raw__InputArray(), dslabel.opencv_as_extern(), dims_offset.as_raw_VectorOfi32(), dims_counts.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_dsinsert_const_const__InputArrayR_const_StringR_const_vector_int_R_const_vector_int_R(self.as_raw_HDF5(), array.as_raw__InputArray(), dslabel.opencv_as_extern(), dims_offset.as_raw_VectorOfi32(), dims_counts.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -849,10 +868,11 @@ pub trait HDF5Const {
 	/// 
 	#[inline]
 	fn dsinsert_3(&self, array: &dyn core::ToInputArray, dslabel: &str, dims_offset: &i32, dims_counts: &i32) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_HDF5())?;
 		input_array_arg!(array);
 		extern_container_arg!(dslabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_dsinsert_const_const__InputArrayR_const_StringR_const_intX_const_intX(self.as_raw_HDF5(), array.as_raw__InputArray(), dslabel.opencv_as_extern(), dims_offset, dims_counts, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_dsinsert_const_const__InputArrayR_const_StringR_const_intX_const_intX(self.as_raw_HDF5(), array.as_raw__InputArray(), dslabel.opencv_as_extern(), dims_offset, dims_counts, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -860,10 +880,11 @@ pub trait HDF5Const {
 	
 	#[inline]
 	fn dsread(&self, array: &mut dyn core::ToOutputArray, dslabel: &str) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_HDF5())?;
 		output_array_arg!(array);
 		extern_container_arg!(dslabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_dsread_const_const__OutputArrayR_const_StringR(self.as_raw_HDF5(), array.as_raw__OutputArray(), dslabel.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_dsread_const_const__OutputArrayR_const_StringR(self.as_raw_HDF5(), array.as_raw__OutputArray(), dslabel.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -871,10 +892,11 @@ pub trait HDF5Const {
 	
 	#[inline]
 	fn dsread_1(&self, array: &mut dyn core::ToOutputArray, dslabel: &str, dims_offset: &i32) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_HDF5())?;
 		output_array_arg!(array);
 		extern_container_arg!(dslabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_dsread_const_const__OutputArrayR_const_StringR_const_intX(self.as_raw_HDF5(), array.as_raw__OutputArray(), dslabel.opencv_as_extern(), dims_offset, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_dsread_const_const__OutputArrayR_const_StringR_const_intX(self.as_raw_HDF5(), array.as_raw__OutputArray(), dslabel.opencv_as_extern(), dims_offset, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -884,10 +906,11 @@ pub trait HDF5Const {
 	/// * dims_counts: vector<int>()
 	#[inline]
 	fn dsread_2(&self, array: &mut dyn core::ToOutputArray, dslabel: &str, dims_offset: &core::Vector<i32>, dims_counts: &core::Vector<i32>) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_HDF5())?;
 		output_array_arg!(array);
 		extern_container_arg!(dslabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_dsread_const_const__OutputArrayR_const_StringR_const_vector_int_R_const_vector_int_R(self.as_raw_HDF5(), array.as_raw__OutputArray(), dslabel.opencv_as_extern(), dims_offset.as_raw_VectorOfi32(), dims_counts.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_dsread_const_const__OutputArrayR_const_StringR_const_vector_int_R_const_vector_int_R(self.as_raw_HDF5(), array.as_raw__OutputArray(), dslabel.opencv_as_extern(), dims_offset.as_raw_VectorOfi32(), dims_counts.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -937,10 +960,11 @@ pub trait HDF5Const {
 	/// 
 	#[inline]
 	fn dsread_3(&self, array: &mut dyn core::ToOutputArray, dslabel: &str, dims_offset: &i32, dims_counts: &i32) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_HDF5())?;
 		output_array_arg!(array);
 		extern_container_arg!(dslabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_dsread_const_const__OutputArrayR_const_StringR_const_intX_const_intX(self.as_raw_HDF5(), array.as_raw__OutputArray(), dslabel.opencv_as_extern(), dims_offset, dims_counts, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_dsread_const_const__OutputArrayR_const_StringR_const_intX_const_intX(self.as_raw_HDF5(), array.as_raw__OutputArray(), dslabel.opencv_as_extern(), dims_offset, dims_counts, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -965,9 +989,10 @@ pub trait HDF5Const {
 	/// * dims_flag: HDF5::H5_GETDIMS
 	#[inline]
 	fn kpgetsize(&self, kplabel: &str, dims_flag: i32) -> Result<i32> {
+		crate::core::validity::check("HDF5", self.as_raw_HDF5())?;
 		extern_container_arg!(kplabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_kpgetsize_const_const_StringR_int(self.as_raw_HDF5(), kplabel.opencv_as_extern(), dims_flag, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_kpgetsize_const_const_StringR_int(self.as_raw_HDF5(), kplabel.opencv_as_extern(), dims_flag, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1018,9 +1043,10 @@ pub trait HDF5Const {
 	/// * chunks: H5_NONE
 	#[inline]
 	fn kpcreate(&self, size: i32, kplabel: &str, compresslevel: i32, chunks: i32) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_HDF5())?;
 		extern_container_arg!(kplabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_kpcreate_const_const_int_const_StringR_const_int_const_int(self.as_raw_HDF5(), size, kplabel.opencv_as_extern(), compresslevel, chunks, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_kpcreate_const_const_int_const_StringR_const_int_const_int(self.as_raw_HDF5(), size, kplabel.opencv_as_extern(), compresslevel, chunks, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1085,9 +1111,10 @@ pub trait HDF5Const {
 	/// * counts: H5_NONE
 	#[inline]
 	fn kpwrite(&self, keypoints: core::Vector<core::KeyPoint>, kplabel: &str, offset: i32, counts: i32) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_HDF5())?;
 		extern_container_arg!(kplabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_kpwrite_const_const_vector_KeyPoint__const_StringR_const_int_const_int(self.as_raw_HDF5(), keypoints.as_raw_VectorOfKeyPoint(), kplabel.opencv_as_extern(), offset, counts, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_kpwrite_const_const_vector_KeyPoint__const_StringR_const_int_const_int(self.as_raw_HDF5(), keypoints.as_raw_VectorOfKeyPoint(), kplabel.opencv_as_extern(), offset, counts, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1133,9 +1160,10 @@ pub trait HDF5Const {
 	/// * counts: H5_NONE
 	#[inline]
 	fn kpinsert(&self, keypoints: core::Vector<core::KeyPoint>, kplabel: &str, offset: i32, counts: i32) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_HDF5())?;
 		extern_container_arg!(kplabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_kpinsert_const_const_vector_KeyPoint__const_StringR_const_int_const_int(self.as_raw_HDF5(), keypoints.as_raw_VectorOfKeyPoint(), kplabel.opencv_as_extern(), offset, counts, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_kpinsert_const_const_vector_KeyPoint__const_StringR_const_int_const_int(self.as_raw_HDF5(), keypoints.as_raw_VectorOfKeyPoint(), kplabel.opencv_as_extern(), offset, counts, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1185,9 +1213,10 @@ pub trait HDF5Const {
 	/// * counts: H5_NONE
 	#[inline]
 	fn kpread(&self, keypoints: &mut core::Vector<core::KeyPoint>, kplabel: &str, offset: i32, counts: i32) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_HDF5())?;
 		extern_container_arg!(kplabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_kpread_const_vector_KeyPoint_R_const_StringR_const_int_const_int(self.as_raw_HDF5(), keypoints.as_raw_mut_VectorOfKeyPoint(), kplabel.opencv_as_extern(), offset, counts, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_kpread_const_vector_KeyPoint_R_const_StringR_const_int_const_int(self.as_raw_HDF5(), keypoints.as_raw_mut_VectorOfKeyPoint(), kplabel.opencv_as_extern(), offset, counts, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1201,8 +1230,9 @@ pub trait HDF5: crate::hdf::HDF5Const {
 	/// Close and release hdf5 object.
 	#[inline]
 	fn close(&mut self) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_mut_HDF5())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_close(self.as_raw_mut_HDF5(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_close(self.as_raw_mut_HDF5(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1232,9 +1262,10 @@ pub trait HDF5: crate::hdf::HDF5Const {
 	/// full path within the label. In our example, it would be: 'Group1/SubGroup1/MyDataSet'. It is not thread safe.
 	#[inline]
 	fn grcreate(&mut self, grlabel: &str) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_mut_HDF5())?;
 		extern_container_arg!(grlabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_grcreate_const_StringR(self.as_raw_mut_HDF5(), grlabel.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_grcreate_const_StringR(self.as_raw_mut_HDF5(), grlabel.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1249,12 +1280,13 @@ pub trait HDF5: crate::hdf::HDF5Const {
 	/// Note: CV_Error() is called if the given attribute does not exist. Use atexists()
 	/// to check whether it exists or not beforehand.
 	/// ## See also
-	/// atexists, atwrite, atread
+	/// `atexists`, `atwrite`, `atread`
 	#[inline]
 	fn atdelete(&mut self, atlabel: &str) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_mut_HDF5())?;
 		extern_container_arg!(atlabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_atdelete_const_StringR(self.as_raw_mut_HDF5(), atlabel.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_atdelete_const_StringR(self.as_raw_mut_HDF5(), atlabel.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1275,12 +1307,13 @@ pub trait HDF5: crate::hdf::HDF5Const {
 	/// to check whether it exists or not beforehand. And use atdelete() to delete
 	/// it if it already exists.
 	/// ## See also
-	/// atexists, atdelete, atread
+	/// `atexists`, `atdelete`, `atread`
 	#[inline]
 	fn atwrite(&mut self, value: i32, atlabel: &str) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_mut_HDF5())?;
 		extern_container_arg!(atlabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_atwrite_const_int_const_StringR(self.as_raw_mut_HDF5(), value, atlabel.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_atwrite_const_int_const_StringR(self.as_raw_mut_HDF5(), value, atlabel.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1300,12 +1333,13 @@ pub trait HDF5: crate::hdf::HDF5Const {
 	/// Note: The attribute MUST exist, otherwise CV_Error() is called. Use atexists()
 	/// to check if it exists beforehand.
 	/// ## See also
-	/// atexists, atdelete, atwrite
+	/// `atexists`, `atdelete`, `atwrite`
 	#[inline]
 	fn atread(&mut self, value: &mut i32, atlabel: &str) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_mut_HDF5())?;
 		extern_container_arg!(atlabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_atread_intX_const_StringR(self.as_raw_mut_HDF5(), value, atlabel.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_atread_intX_const_StringR(self.as_raw_mut_HDF5(), value, atlabel.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1322,14 +1356,15 @@ pub trait HDF5: crate::hdf::HDF5Const {
 	/// to check whether it exists or not beforehand. And use atdelete() to delete
 	/// it if it already exists.
 	/// ## See also
-	/// atexists, atdelete, atread.
+	/// `atexists`, `atdelete`, `atread`.
 	/// 
 	/// ## Overloaded parameters
 	#[inline]
 	fn atwrite_1(&mut self, value: f64, atlabel: &str) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_mut_HDF5())?;
 		extern_container_arg!(atlabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_atwrite_const_double_const_StringR(self.as_raw_mut_HDF5(), value, atlabel.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_atwrite_const_double_const_StringR(self.as_raw_mut_HDF5(), value, atlabel.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1345,14 +1380,15 @@ pub trait HDF5: crate::hdf::HDF5Const {
 	/// Note: The attribute MUST exist, otherwise CV_Error() is called. Use atexists()
 	/// to check if it exists beforehand.
 	/// ## See also
-	/// atexists, atdelete, atwrite
+	/// `atexists`, `atdelete`, `atwrite`
 	/// 
 	/// ## Overloaded parameters
 	#[inline]
 	fn atread_1(&mut self, value: &mut f64, atlabel: &str) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_mut_HDF5())?;
 		extern_container_arg!(atlabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_atread_doubleX_const_StringR(self.as_raw_mut_HDF5(), value, atlabel.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_atread_doubleX_const_StringR(self.as_raw_mut_HDF5(), value, atlabel.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1369,15 +1405,16 @@ pub trait HDF5: crate::hdf::HDF5Const {
 	/// to check whether it exists or not beforehand. And use atdelete() to delete
 	/// it if it already exists.
 	/// ## See also
-	/// atexists, atdelete, atread.
+	/// `atexists`, `atdelete`, `atread`.
 	/// 
 	/// ## Overloaded parameters
 	#[inline]
 	fn atwrite_2(&mut self, value: &str, atlabel: &str) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_mut_HDF5())?;
 		extern_container_arg!(value);
 		extern_container_arg!(atlabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_atwrite_const_StringR_const_StringR(self.as_raw_mut_HDF5(), value.opencv_as_extern(), atlabel.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_atwrite_const_StringR_const_StringR(self.as_raw_mut_HDF5(), value.opencv_as_extern(), atlabel.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1393,15 +1430,16 @@ pub trait HDF5: crate::hdf::HDF5Const {
 	/// Note: The attribute MUST exist, otherwise CV_Error() is called. Use atexists()
 	/// to check if it exists beforehand.
 	/// ## See also
-	/// atexists, atdelete, atwrite
+	/// `atexists`, `atdelete`, `atwrite`
 	/// 
 	/// ## Overloaded parameters
 	#[inline]
 	fn atread_2(&mut self, value: &mut String, atlabel: &str) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_mut_HDF5())?;
 		string_arg_output_send!(via value_via);
 		extern_container_arg!(atlabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_atread_StringX_const_StringR(self.as_raw_mut_HDF5(), &mut value_via, atlabel.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_atread_StringX_const_StringR(self.as_raw_mut_HDF5(), &mut value_via, atlabel.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		string_arg_output_receive!(value_via => value);
@@ -1419,13 +1457,14 @@ pub trait HDF5: crate::hdf::HDF5Const {
 	/// to check whether it exists or not beforehand. And use atdelete() to delete
 	/// it if it already exists.
 	/// ## See also
-	/// atexists, atdelete, atread.
+	/// `atexists`, `atdelete`, `atread`.
 	#[inline]
 	fn atwrite_3(&mut self, value: &dyn core::ToInputArray, atlabel: &str) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_mut_HDF5())?;
 		input_array_arg!(value);
 		extern_container_arg!(atlabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_atwrite_const__InputArrayR_const_StringR(self.as_raw_mut_HDF5(), value.as_raw__InputArray(), atlabel.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_atwrite_const__InputArrayR_const_StringR(self.as_raw_mut_HDF5(), value.as_raw__InputArray(), atlabel.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1441,13 +1480,14 @@ pub trait HDF5: crate::hdf::HDF5Const {
 	/// Note: The attribute MUST exist, otherwise CV_Error() is called. Use atexists()
 	/// to check if it exists beforehand.
 	/// ## See also
-	/// atexists, atdelete, atwrite
+	/// `atexists`, `atdelete`, `atwrite`
 	#[inline]
 	fn atread_3(&mut self, value: &mut dyn core::ToOutputArray, atlabel: &str) -> Result<()> {
+		crate::core::validity::check("HDF5", self.as_raw_mut_HDF5())?;
 		output_array_arg!(value);
 		extern_container_arg!(atlabel);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hdf_HDF5_atread_const__OutputArrayR_const_StringR(self.as_raw_mut_HDF5(), value.as_raw__OutputArray(), atlabel.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hdf_HDF5_atread_const__OutputArrayR_const_StringR(self.as_raw_mut_HDF5(), value.as_raw__OutputArray(), atlabel.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -58,8 +58,9 @@ pub trait HfsSegment: core::AlgorithmTrait + crate::hfs::HfsSegmentConst {
 	/// tends to have more regions remained if this value is large and vice versa.
 	#[inline]
 	fn set_seg_egb_threshold_i(&mut self, c: f32) -> Result<()> {
+		crate::core::validity::check("HfsSegment", self.as_raw_mut_HfsSegment())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hfs_HfsSegment_setSegEgbThresholdI_float(self.as_raw_mut_HfsSegment(), c, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hfs_HfsSegment_setSegEgbThresholdI_float(self.as_raw_mut_HfsSegment(), c, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -67,8 +68,9 @@ pub trait HfsSegment: core::AlgorithmTrait + crate::hfs::HfsSegmentConst {
 	
 	#[inline]
 	fn get_seg_egb_threshold_i(&mut self) -> Result<f32> {
+		crate::core::validity::check("HfsSegment", self.as_raw_mut_HfsSegment())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hfs_HfsSegment_getSegEgbThresholdI(self.as_raw_mut_HfsSegment(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hfs_HfsSegment_getSegEgbThresholdI(self.as_raw_mut_HfsSegment(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -80,8 +82,9 @@ pub trait HfsSegment: core::AlgorithmTrait + crate::hfs::HfsSegmentConst {
 	/// pixels then this parameter will be merged into it's adjacent region.
 	#[inline]
 	fn set_min_region_size_i(&mut self, n: i32) -> Result<()> {
+		crate::core::validity::check("HfsSegment", self.as_raw_mut_HfsSegment())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hfs_HfsSegment_setMinRegionSizeI_int(self.as_raw_mut_HfsSegment(), n, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hfs_HfsSegment_setMinRegionSizeI_int(self.as_raw_mut_HfsSegment(), n, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -89,8 +92,9 @@ pub trait HfsSegment: core::AlgorithmTrait + crate::hfs::HfsSegmentConst {
 	
 	#[inline]
 	fn get_min_region_size_i(&mut self) -> Result<i32> {
+		crate::core::validity::check("HfsSegment", self.as_raw_mut_HfsSegment())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hfs_HfsSegment_getMinRegionSizeI(self.as_raw_mut_HfsSegment(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hfs_HfsSegment_getMinRegionSizeI(self.as_raw_mut_HfsSegment(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -103,8 +107,9 @@ pub trait HfsSegment: core::AlgorithmTrait + crate::hfs::HfsSegmentConst {
 	/// this value is large and vice versa.
 	#[inline]
 	fn set_seg_egb_threshold_ii(&mut self, c: f32) -> Result<()> {
+		crate::core::validity::check("HfsSegment", self.as_raw_mut_HfsSegment())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hfs_HfsSegment_setSegEgbThresholdII_float(self.as_raw_mut_HfsSegment(), c, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hfs_HfsSegment_setSegEgbThresholdII_float(self.as_raw_mut_HfsSegment(), c, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -112,8 +117,9 @@ pub trait HfsSegment: core::AlgorithmTrait + crate::hfs::HfsSegmentConst {
 	
 	#[inline]
 	fn get_seg_egb_threshold_ii(&mut self) -> Result<f32> {
+		crate::core::validity::check("HfsSegment", self.as_raw_mut_HfsSegment())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hfs_HfsSegment_getSegEgbThresholdII(self.as_raw_mut_HfsSegment(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hfs_HfsSegment_getSegEgbThresholdII(self.as_raw_mut_HfsSegment(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -124,8 +130,9 @@ pub trait HfsSegment: core::AlgorithmTrait + crate::hfs::HfsSegmentConst {
 	/// mentioned above. It serves the same purpose as minRegionSizeI
 	#[inline]
 	fn set_min_region_size_ii(&mut self, n: i32) -> Result<()> {
+		crate::core::validity::check("HfsSegment", self.as_raw_mut_HfsSegment())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hfs_HfsSegment_setMinRegionSizeII_int(self.as_raw_mut_HfsSegment(), n, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hfs_HfsSegment_setMinRegionSizeII_int(self.as_raw_mut_HfsSegment(), n, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -133,8 +140,9 @@ pub trait HfsSegment: core::AlgorithmTrait + crate::hfs::HfsSegmentConst {
 	
 	#[inline]
 	fn get_min_region_size_ii(&mut self) -> Result<i32> {
+		crate::core::validity::check("HfsSegment", self.as_raw_mut_HfsSegment())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hfs_HfsSegment_getMinRegionSizeII(self.as_raw_mut_HfsSegment(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hfs_HfsSegment_getMinRegionSizeII(self.as_raw_mut_HfsSegment(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -150,8 +158,9 @@ pub trait HfsSegment: core::AlgorithmTrait + crate::hfs::HfsSegmentConst {
 	/// if this value is larger.
 	#[inline]
 	fn set_spatial_weight(&mut self, w: f32) -> Result<()> {
+		crate::core::validity::check("HfsSegment", self.as_raw_mut_HfsSegment())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hfs_HfsSegment_setSpatialWeight_float(self.as_raw_mut_HfsSegment(), w, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hfs_HfsSegment_setSpatialWeight_float(self.as_raw_mut_HfsSegment(), w, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -159,8 +168,9 @@ pub trait HfsSegment: core::AlgorithmTrait + crate::hfs::HfsSegmentConst {
 	
 	#[inline]
 	fn get_spatial_weight(&mut self) -> Result<f32> {
+		crate::core::validity::check("HfsSegment", self.as_raw_mut_HfsSegment())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hfs_HfsSegment_getSpatialWeight(self.as_raw_mut_HfsSegment(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hfs_HfsSegment_getSpatialWeight(self.as_raw_mut_HfsSegment(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -174,8 +184,9 @@ pub trait HfsSegment: core::AlgorithmTrait + crate::hfs::HfsSegmentConst {
 	/// pixels in the beginning.
 	#[inline]
 	fn set_slic_spixel_size(&mut self, n: i32) -> Result<()> {
+		crate::core::validity::check("HfsSegment", self.as_raw_mut_HfsSegment())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hfs_HfsSegment_setSlicSpixelSize_int(self.as_raw_mut_HfsSegment(), n, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hfs_HfsSegment_setSlicSpixelSize_int(self.as_raw_mut_HfsSegment(), n, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -183,8 +194,9 @@ pub trait HfsSegment: core::AlgorithmTrait + crate::hfs::HfsSegmentConst {
 	
 	#[inline]
 	fn get_slic_spixel_size(&mut self) -> Result<i32> {
+		crate::core::validity::check("HfsSegment", self.as_raw_mut_HfsSegment())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hfs_HfsSegment_getSlicSpixelSize(self.as_raw_mut_HfsSegment(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hfs_HfsSegment_getSlicSpixelSize(self.as_raw_mut_HfsSegment(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -195,8 +207,9 @@ pub trait HfsSegment: core::AlgorithmTrait + crate::hfs::HfsSegmentConst {
 	/// describes how many iteration to perform when executing SLIC.
 	#[inline]
 	fn set_num_slic_iter(&mut self, n: i32) -> Result<()> {
+		crate::core::validity::check("HfsSegment", self.as_raw_mut_HfsSegment())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hfs_HfsSegment_setNumSlicIter_int(self.as_raw_mut_HfsSegment(), n, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hfs_HfsSegment_setNumSlicIter_int(self.as_raw_mut_HfsSegment(), n, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -204,8 +217,9 @@ pub trait HfsSegment: core::AlgorithmTrait + crate::hfs::HfsSegmentConst {
 	
 	#[inline]
 	fn get_num_slic_iter(&mut self) -> Result<i32> {
+		crate::core::validity::check("HfsSegment", self.as_raw_mut_HfsSegment())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hfs_HfsSegment_getNumSlicIter(self.as_raw_mut_HfsSegment(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hfs_HfsSegment_getNumSlicIter(self.as_raw_mut_HfsSegment(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -225,9 +239,10 @@ pub trait HfsSegment: core::AlgorithmTrait + crate::hfs::HfsSegmentConst {
 	/// * if_draw: true
 	#[inline]
 	fn perform_segment_gpu(&mut self, src: &dyn core::ToInputArray, if_draw: bool) -> Result<core::Mat> {
+		crate::core::validity::check("HfsSegment", self.as_raw_mut_HfsSegment())?;
 		input_array_arg!(src);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hfs_HfsSegment_performSegmentGpu_const__InputArrayR_bool(self.as_raw_mut_HfsSegment(), src.as_raw__InputArray(), if_draw, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hfs_HfsSegment_performSegmentGpu_const__InputArrayR_bool(self.as_raw_mut_HfsSegment(), src.as_raw__InputArray(), if_draw, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -242,9 +257,10 @@ pub trait HfsSegment: core::AlgorithmTrait + crate::hfs::HfsSegmentConst {
 	/// * if_draw: true
 	#[inline]
 	fn perform_segment_cpu(&mut self, src: &dyn core::ToInputArray, if_draw: bool) -> Result<core::Mat> {
+		crate::core::validity::check("HfsSegment", self.as_raw_mut_HfsSegment())?;
 		input_array_arg!(src);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hfs_HfsSegment_performSegmentCpu_const__InputArrayR_bool(self.as_raw_mut_HfsSegment(), src.as_raw__InputArray(), if_draw, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hfs_HfsSegment_performSegmentCpu_const__InputArrayR_bool(self.as_raw_mut_HfsSegment(), src.as_raw__InputArray(), if_draw, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -277,11 +293,88 @@ impl dyn HfsSegment + '_ {
 	#[inline]
 	pub fn create(height: i32, width: i32, seg_egb_threshold_i: f32, min_region_size_i: i32, seg_egb_threshold_ii: f32, min_region_size_ii: i32, spatial_weight: f32, slic_spixel_size: i32, num_slic_iter: i32) -> Result<core::Ptr<dyn crate::hfs::HfsSegment>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_hfs_HfsSegment_create_int_int_float_int_float_int_float_int_int(height, width, seg_egb_threshold_i, min_region_size_i, seg_egb_threshold_ii, min_region_size_ii, spatial_weight, slic_spixel_size, num_slic_iter, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_hfs_HfsSegment_create_int_int_float_int_float_int_float_int_int(height, width, seg_egb_threshold_i, min_region_size_i, seg_egb_threshold_ii, min_region_size_ii, spatial_weight, slic_spixel_size, num_slic_iter, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::hfs::HfsSegment>::opencv_from_extern(ret) };
 		Ok(ret)
 	}
 	
+}
+
+/// Builder for `<dyn HfsSegment>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct HfsSegment_CreateBuilder {
+	height: i32,
+	width: i32,
+	seg_egb_threshold_i: f32,
+	min_region_size_i: i32,
+	seg_egb_threshold_ii: f32,
+	min_region_size_ii: i32,
+	spatial_weight: f32,
+	slic_spixel_size: i32,
+	num_slic_iter: i32,
+}
+
+impl HfsSegment_CreateBuilder {
+	#[inline]
+	pub fn new(height: i32, width: i32) -> Self {
+		Self { height, width, seg_egb_threshold_i: 0.08, min_region_size_i: 100, seg_egb_threshold_ii: 0.28, min_region_size_ii: 200, spatial_weight: 0.6, slic_spixel_size: 8, num_slic_iter: 5 }
+	}
+	
+	/// C++ default: `0.08f`
+	#[inline]
+	pub fn with_seg_egb_threshold_i(mut self, seg_egb_threshold_i: f32) -> Self {
+		self.seg_egb_threshold_i = seg_egb_threshold_i;
+		self
+	}
+	
+	/// C++ default: `100`
+	#[inline]
+	pub fn with_min_region_size_i(mut self, min_region_size_i: i32) -> Self {
+		self.min_region_size_i = min_region_size_i;
+		self
+	}
+	
+	/// C++ default: `0.28f`
+	#[inline]
+	pub fn with_seg_egb_threshold_ii(mut self, seg_egb_threshold_ii: f32) -> Self {
+		self.seg_egb_threshold_ii = seg_egb_threshold_ii;
+		self
+	}
+	
+	/// C++ default: `200`
+	#[inline]
+	pub fn with_min_region_size_ii(mut self, min_region_size_ii: i32) -> Self {
+		self.min_region_size_ii = min_region_size_ii;
+		self
+	}
+	
+	/// C++ default: `0.6f`
+	#[inline]
+	pub fn with_spatial_weight(mut self, spatial_weight: f32) -> Self {
+		self.spatial_weight = spatial_weight;
+		self
+	}
+	
+	/// C++ default: `8`
+	#[inline]
+	pub fn with_slic_spixel_size(mut self, slic_spixel_size: i32) -> Self {
+		self.slic_spixel_size = slic_spixel_size;
+		self
+	}
+	
+	/// C++ default: `5`
+	#[inline]
+	pub fn with_num_slic_iter(mut self, num_slic_iter: i32) -> Self {
+		self.num_slic_iter = num_slic_iter;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::hfs::HfsSegment>> {
+		<dyn HfsSegment>::create(self.height, self.width, self.seg_egb_threshold_i, self.min_region_size_i, self.seg_egb_threshold_ii, self.min_region_size_ii, self.spatial_weight, self.slic_spixel_size, self.num_slic_iter)
+	}
 }
\ No newline at end of file
@@ -401,7 +401,7 @@ pub type TrackbarCallback = Option<Box<dyn FnMut(i32) -> () + Send + Sync + 'sta
 pub fn add_text(img: &core::Mat, text: &str, org: core::Point, font: &crate::highgui::QtFont) -> Result<()> {
 	extern_container_arg!(text);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_addText_const_MatR_const_StringR_Point_const_QtFontR(img.as_raw_Mat(), text.opencv_as_extern(), org.opencv_as_extern(), font.as_raw_QtFont(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_addText_const_MatR_const_StringR_Point_const_QtFontR(img.as_raw_Mat(), text.opencv_as_extern(), org.opencv_as_extern(), font.as_raw_QtFont(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -433,12 +433,61 @@ pub fn add_text_with_font(img: &core::Mat, text: &str, org: core::Point, name_fo
 	extern_container_arg!(text);
 	extern_container_arg!(name_font);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_addText_const_MatR_const_StringR_Point_const_StringR_int_Scalar_int_int_int(img.as_raw_Mat(), text.opencv_as_extern(), org.opencv_as_extern(), name_font.opencv_as_extern(), point_size, color.opencv_as_extern(), weight, style, spacing, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_addText_const_MatR_const_StringR_Point_const_StringR_int_Scalar_int_int_int(img.as_raw_Mat(), text.opencv_as_extern(), org.opencv_as_extern(), name_font.opencv_as_extern(), point_size, color.opencv_as_extern(), weight, style, spacing, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `add_text_with_font()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct AddTextWithFontBuilder<'a> {
+	img: &'a core::Mat,
+	text: &'a str,
+	org: core::Point,
+	name_font: &'a str,
+	point_size: i32,
+	color: core::Scalar,
+	weight: i32,
+	style: i32,
+	spacing: i32,
+}
+
+impl<'a> AddTextWithFontBuilder<'a> {
+	#[inline]
+	pub fn new(img: &'a core::Mat, text: &'a str, org: core::Point, name_font: &'a str, weight: i32, style: i32) -> Self {
+		Self { img, text, org, name_font, point_size: -1, color: core::Scalar::all(0.), weight, style, spacing: 0 }
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_point_size(mut self, point_size: i32) -> Self {
+		self.point_size = point_size;
+		self
+	}
+	
+	/// C++ default: `Scalar::all(0)`
+	#[inline]
+	pub fn with_color(mut self, color: core::Scalar) -> Self {
+		self.color = color;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_spacing(mut self, spacing: i32) -> Self {
+		self.spacing = spacing;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		add_text_with_font(self.img, self.text, self.org, self.name_font, self.point_size, self.color, self.weight, self.style, self.spacing)
+	}
+}
+
 /// Attaches a button to the control panel.
 /// 
 /// The function createButton attaches a button to the control panel. Each button is added to a
@@ -477,7 +526,7 @@ pub fn create_button(bar_name: &str, on_change: crate::highgui::ButtonCallback,
 	callback_arg!(on_change_trampoline(state: i32, userdata: *mut c_void) -> () => userdata in callbacks => on_change(state: i32) -> ());
 	userdata_arg!(userdata in callbacks => on_change);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_createButton_const_StringR_ButtonCallback_voidX_int_bool(bar_name.opencv_as_extern(), on_change_trampoline, userdata, typ, initial_button_state, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_createButton_const_StringR_ButtonCallback_voidX_int_bool(bar_name.opencv_as_extern(), on_change_trampoline, userdata, typ, initial_button_state, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -493,7 +542,7 @@ pub fn create_button(bar_name: &str, on_change: crate::highgui::ButtonCallback,
 /// 
 /// Note:
 /// 
-/// [__Qt Backend Only__] winname can be empty if the trackbar should be attached to the
+/// \[__Qt Backend Only__\] winname can be empty if the trackbar should be attached to the
 /// control panel.
 /// 
 /// Clicking the label of each trackbar enables editing the trackbar values manually.
@@ -521,7 +570,7 @@ pub fn create_trackbar(trackbarname: &str, winname: &str, value: Option<&mut i32
 	callback_arg!(on_change_trampoline(pos: i32, userdata: *mut c_void) -> () => userdata in callbacks => on_change(pos: i32) -> ());
 	userdata_arg!(userdata in callbacks => on_change);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_createTrackbar_const_StringR_const_StringR_intX_int_TrackbarCallback_voidX(trackbarname.opencv_as_extern(), winname.opencv_as_extern(), value.map_or(::core::ptr::null_mut(), |value| value as *mut _), count, on_change_trampoline, userdata, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_createTrackbar_const_StringR_const_StringR_intX_int_TrackbarCallback_voidX(trackbarname.opencv_as_extern(), winname.opencv_as_extern(), value.map_or(::core::ptr::null_mut(), |value| value as *mut _), count, on_change_trampoline, userdata, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -533,7 +582,7 @@ pub fn create_trackbar(trackbarname: &str, winname: &str, value: Option<&mut i32
 #[inline]
 pub fn destroy_all_windows() -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_destroyAllWindows(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_destroyAllWindows(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -549,7 +598,7 @@ pub fn destroy_all_windows() -> Result<()> {
 pub fn destroy_window(winname: &str) -> Result<()> {
 	extern_container_arg!(winname);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_destroyWindow_const_StringR(winname.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_destroyWindow_const_StringR(winname.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -575,7 +624,7 @@ pub fn display_overlay(winname: &str, text: &str, delayms: i32) -> Result<()> {
 	extern_container_arg!(winname);
 	extern_container_arg!(text);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_displayOverlay_const_StringR_const_StringR_int(winname.opencv_as_extern(), text.opencv_as_extern(), delayms, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_displayOverlay_const_StringR_const_StringR_int(winname.opencv_as_extern(), text.opencv_as_extern(), delayms, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -601,7 +650,7 @@ pub fn display_status_bar(winname: &str, text: &str, delayms: i32) -> Result<()>
 	extern_container_arg!(winname);
 	extern_container_arg!(text);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_displayStatusBar_const_StringR_const_StringR_int(winname.opencv_as_extern(), text.opencv_as_extern(), delayms, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_displayStatusBar_const_StringR_const_StringR_int(winname.opencv_as_extern(), text.opencv_as_extern(), delayms, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -639,13 +688,59 @@ pub fn display_status_bar(winname: &str, text: &str, delayms: i32) -> Result<()>
 pub fn font_qt(name_font: &str, point_size: i32, color: core::Scalar, weight: i32, style: i32, spacing: i32) -> Result<crate::highgui::QtFont> {
 	extern_container_arg!(name_font);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_fontQt_const_StringR_int_Scalar_int_int_int(name_font.opencv_as_extern(), point_size, color.opencv_as_extern(), weight, style, spacing, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_fontQt_const_StringR_int_Scalar_int_int_int(name_font.opencv_as_extern(), point_size, color.opencv_as_extern(), weight, style, spacing, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { crate::highgui::QtFont::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `font_qt()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FontQtBuilder<'a> {
+	name_font: &'a str,
+	point_size: i32,
+	color: core::Scalar,
+	weight: i32,
+	style: i32,
+	spacing: i32,
+}
+
+impl<'a> FontQtBuilder<'a> {
+	#[inline]
+	pub fn new(name_font: &'a str, weight: i32, style: i32) -> Self {
+		Self { name_font, point_size: -1, color: core::Scalar::all(0.), weight, style, spacing: 0 }
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_point_size(mut self, point_size: i32) -> Self {
+		self.point_size = point_size;
+		self
+	}
+	
+	/// C++ default: `Scalar::all(0)`
+	#[inline]
+	pub fn with_color(mut self, color: core::Scalar) -> Self {
+		self.color = color;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_spacing(mut self, spacing: i32) -> Self {
+		self.spacing = spacing;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::highgui::QtFont> {
+		font_qt(self.name_font, self.point_size, self.color, self.weight, self.style, self.spacing)
+	}
+}
+
 /// Gets the mouse-wheel motion delta, when handling mouse-wheel events cv::EVENT_MOUSEWHEEL and
 /// cv::EVENT_MOUSEHWHEEL.
 /// 
@@ -668,7 +763,7 @@ pub fn font_qt(name_font: &str, point_size: i32, color: core::Scalar, weight: i3
 #[inline]
 pub fn get_mouse_wheel_delta(flags: i32) -> Result<i32> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_getMouseWheelDelta_int(flags, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_getMouseWheelDelta_int(flags, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -681,7 +776,7 @@ pub fn get_mouse_wheel_delta(flags: i32) -> Result<i32> {
 /// 
 /// Note:
 /// 
-/// [__Qt Backend Only__] winname can be empty if the trackbar is attached to the control
+/// \[__Qt Backend Only__\] winname can be empty if the trackbar is attached to the control
 /// panel.
 /// 
 /// ## Parameters
@@ -692,7 +787,7 @@ pub fn get_trackbar_pos(trackbarname: &str, winname: &str) -> Result<i32> {
 	extern_container_arg!(trackbarname);
 	extern_container_arg!(winname);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_getTrackbarPos_const_StringR_const_StringR(trackbarname.opencv_as_extern(), winname.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_getTrackbarPos_const_StringR_const_StringR(trackbarname.opencv_as_extern(), winname.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -710,7 +805,7 @@ pub fn get_trackbar_pos(trackbarname: &str, winname: &str) -> Result<i32> {
 pub fn get_window_image_rect(winname: &str) -> Result<core::Rect> {
 	extern_container_arg!(winname);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_getWindowImageRect_const_StringR(winname.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_getWindowImageRect_const_StringR(winname.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -724,12 +819,12 @@ pub fn get_window_image_rect(winname: &str) -> Result<core::Rect> {
 /// * winname: Name of the window.
 /// * prop_id: Window property to retrieve. The following operation flags are available: (cv::WindowPropertyFlags)
 /// ## See also
-/// setWindowProperty
+/// [set_window_property](crate::highgui::set_window_property)
 #[inline]
 pub fn get_window_property(winname: &str, prop_id: i32) -> Result<f64> {
 	extern_container_arg!(winname);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_getWindowProperty_const_StringR_int(winname.opencv_as_extern(), prop_id, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_getWindowProperty_const_StringR_int(winname.opencv_as_extern(), prop_id, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -743,9 +838,9 @@ pub fn get_window_property(winname: &str, prop_id: i32) -> Result<f64> {
 /// 
 /// *   If the image is 8-bit unsigned, it is displayed as is.
 /// *   If the image is 16-bit unsigned, the pixels are divided by 256. That is, the
-///    value range [0,255\*256] is mapped to [0,255].
+///    value range \[0,255\*256\] is mapped to \[0,255\].
 /// *   If the image is 32-bit or 64-bit floating-point, the pixel values are multiplied by 255. That is, the
-///    value range [0,1] is mapped to [0,255].
+///    value range \[0,1\] is mapped to \[0,255\].
 /// *   32-bit integer images are not processed anymore due to ambiguouty of required transform.
 ///    Convert to 8-bit unsigned matrix using a custom preprocessing specific to image's context.
 /// 
@@ -767,9 +862,9 @@ pub fn get_window_property(winname: &str, prop_id: i32) -> Result<f64> {
 /// 
 /// Note:
 /// 
-/// [__Windows Backend Only__] Pressing Ctrl+C will copy the image to the clipboard.
+/// \[__Windows Backend Only__\] Pressing Ctrl+C will copy the image to the clipboard.
 /// 
-/// [__Windows Backend Only__] Pressing Ctrl+S will show a dialog to save the image.
+/// \[__Windows Backend Only__\] Pressing Ctrl+S will show a dialog to save the image.
 /// 
 /// ## Parameters
 /// * winname: Name of the window.
@@ -779,7 +874,7 @@ pub fn imshow(winname: &str, mat: &dyn core::ToInputArray) -> Result<()> {
 	extern_container_arg!(winname);
 	input_array_arg!(mat);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_imshow_const_StringR_const__InputArrayR(winname.opencv_as_extern(), mat.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_imshow_const_StringR_const__InputArrayR(winname.opencv_as_extern(), mat.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -796,7 +891,7 @@ pub fn imshow(winname: &str, mat: &dyn core::ToInputArray) -> Result<()> {
 pub fn load_window_parameters(window_name: &str) -> Result<()> {
 	extern_container_arg!(window_name);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_loadWindowParameters_const_StringR(window_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_loadWindowParameters_const_StringR(window_name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -812,7 +907,7 @@ pub fn load_window_parameters(window_name: &str) -> Result<()> {
 pub fn move_window(winname: &str, x: i32, y: i32) -> Result<()> {
 	extern_container_arg!(winname);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_moveWindow_const_StringR_int_int(winname.opencv_as_extern(), x, y, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_moveWindow_const_StringR_int_int(winname.opencv_as_extern(), x, y, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -852,7 +947,7 @@ pub fn move_window(winname: &str, x: i32, y: i32) -> Result<()> {
 pub fn named_window(winname: &str, flags: i32) -> Result<()> {
 	extern_container_arg!(winname);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_namedWindow_const_StringR_int(winname.opencv_as_extern(), flags, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_namedWindow_const_StringR_int(winname.opencv_as_extern(), flags, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -874,7 +969,7 @@ pub fn named_window(winname: &str, flags: i32) -> Result<()> {
 #[inline]
 pub fn poll_key() -> Result<i32> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_pollKey(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_pollKey(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -901,7 +996,7 @@ pub fn poll_key() -> Result<i32> {
 pub fn resize_window_size(winname: &str, size: core::Size) -> Result<()> {
 	extern_container_arg!(winname);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_resizeWindow_const_StringR_const_SizeR(winname.opencv_as_extern(), &size, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_resizeWindow_const_StringR_const_SizeR(winname.opencv_as_extern(), &size, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -923,7 +1018,7 @@ pub fn resize_window_size(winname: &str, size: core::Size) -> Result<()> {
 pub fn resize_window(winname: &str, width: i32, height: i32) -> Result<()> {
 	extern_container_arg!(winname);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_resizeWindow_const_StringR_int_int(winname.opencv_as_extern(), width, height, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_resizeWindow_const_StringR_int_int(winname.opencv_as_extern(), width, height, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -940,7 +1035,7 @@ pub fn resize_window(winname: &str, width: i32, height: i32) -> Result<()> {
 pub fn save_window_parameters(window_name: &str) -> Result<()> {
 	extern_container_arg!(window_name);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_saveWindowParameters_const_StringR(window_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_saveWindowParameters_const_StringR(window_name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -972,7 +1067,7 @@ pub fn select_roi_for_window(window_name: &str, img: &dyn core::ToInputArray, sh
 	extern_container_arg!(window_name);
 	input_array_arg!(img);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_selectROI_const_StringR_const__InputArrayR_bool_bool(window_name.opencv_as_extern(), img.as_raw__InputArray(), show_crosshair, from_center, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_selectROI_const_StringR_const__InputArrayR_bool_bool(window_name.opencv_as_extern(), img.as_raw__InputArray(), show_crosshair, from_center, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1005,7 +1100,7 @@ pub fn select_roi_for_window(window_name: &str, img: &dyn core::ToInputArray, sh
 pub fn select_roi(img: &dyn core::ToInputArray, show_crosshair: bool, from_center: bool) -> Result<core::Rect> {
 	input_array_arg!(img);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_selectROI_const__InputArrayR_bool_bool(img.as_raw__InputArray(), show_crosshair, from_center, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_selectROI_const__InputArrayR_bool_bool(img.as_raw__InputArray(), show_crosshair, from_center, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1037,7 +1132,7 @@ pub fn select_rois(window_name: &str, img: &dyn core::ToInputArray, bounding_box
 	extern_container_arg!(window_name);
 	input_array_arg!(img);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_selectROIs_const_StringR_const__InputArrayR_vector_Rect_R_bool_bool(window_name.opencv_as_extern(), img.as_raw__InputArray(), bounding_boxes.as_raw_mut_VectorOfRect(), show_crosshair, from_center, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_selectROIs_const_StringR_const__InputArrayR_vector_Rect_R_bool_bool(window_name.opencv_as_extern(), img.as_raw__InputArray(), bounding_boxes.as_raw_mut_VectorOfRect(), show_crosshair, from_center, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1061,7 +1156,7 @@ pub fn set_mouse_callback(winname: &str, on_mouse: crate::highgui::MouseCallback
 	callback_arg!(on_mouse_trampoline(event: i32, x: i32, y: i32, flags: i32, userdata: *mut c_void) -> () => userdata in callbacks => on_mouse(event: i32, x: i32, y: i32, flags: i32) -> ());
 	userdata_arg!(userdata in callbacks => on_mouse);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_setMouseCallback_const_StringR_MouseCallback_voidX(winname.opencv_as_extern(), on_mouse_trampoline, userdata, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_setMouseCallback_const_StringR_MouseCallback_voidX(winname.opencv_as_extern(), on_mouse_trampoline, userdata, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1075,7 +1170,7 @@ pub fn set_mouse_callback(winname: &str, on_mouse: crate::highgui::MouseCallback
 pub fn set_opengl_context(winname: &str) -> Result<()> {
 	extern_container_arg!(winname);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_setOpenGlContext_const_StringR(winname.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_setOpenGlContext_const_StringR(winname.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1131,7 +1226,7 @@ pub fn set_opengl_draw_callback(winname: &str, on_opengl_draw: crate::highgui::O
 	callback_arg!(on_opengl_draw_trampoline(userdata: *mut c_void) -> () => userdata in callbacks => on_opengl_draw() -> ());
 	userdata_arg!(userdata in callbacks => on_opengl_draw);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_setOpenGlDrawCallback_const_StringR_OpenGlDrawCallback_voidX(winname.opencv_as_extern(), on_opengl_draw_trampoline, userdata, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_setOpenGlDrawCallback_const_StringR_OpenGlDrawCallback_voidX(winname.opencv_as_extern(), on_opengl_draw_trampoline, userdata, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1144,7 +1239,7 @@ pub fn set_opengl_draw_callback(winname: &str, on_opengl_draw: crate::highgui::O
 /// 
 /// Note:
 /// 
-/// [__Qt Backend Only__] winname can be empty if the trackbar is attached to the control
+/// \[__Qt Backend Only__\] winname can be empty if the trackbar is attached to the control
 /// panel.
 /// 
 /// ## Parameters
@@ -1156,7 +1251,7 @@ pub fn set_trackbar_max(trackbarname: &str, winname: &str, maxval: i32) -> Resul
 	extern_container_arg!(trackbarname);
 	extern_container_arg!(winname);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_setTrackbarMax_const_StringR_const_StringR_int(trackbarname.opencv_as_extern(), winname.opencv_as_extern(), maxval, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_setTrackbarMax_const_StringR_const_StringR_int(trackbarname.opencv_as_extern(), winname.opencv_as_extern(), maxval, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1169,7 +1264,7 @@ pub fn set_trackbar_max(trackbarname: &str, winname: &str, maxval: i32) -> Resul
 /// 
 /// Note:
 /// 
-/// [__Qt Backend Only__] winname can be empty if the trackbar is attached to the control
+/// \[__Qt Backend Only__\] winname can be empty if the trackbar is attached to the control
 /// panel.
 /// 
 /// ## Parameters
@@ -1181,7 +1276,7 @@ pub fn set_trackbar_min(trackbarname: &str, winname: &str, minval: i32) -> Resul
 	extern_container_arg!(trackbarname);
 	extern_container_arg!(winname);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_setTrackbarMin_const_StringR_const_StringR_int(trackbarname.opencv_as_extern(), winname.opencv_as_extern(), minval, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_setTrackbarMin_const_StringR_const_StringR_int(trackbarname.opencv_as_extern(), winname.opencv_as_extern(), minval, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1194,7 +1289,7 @@ pub fn set_trackbar_min(trackbarname: &str, winname: &str, minval: i32) -> Resul
 /// 
 /// Note:
 /// 
-/// [__Qt Backend Only__] winname can be empty if the trackbar is attached to the control
+/// \[__Qt Backend Only__\] winname can be empty if the trackbar is attached to the control
 /// panel.
 /// 
 /// ## Parameters
@@ -1206,7 +1301,7 @@ pub fn set_trackbar_pos(trackbarname: &str, winname: &str, pos: i32) -> Result<(
 	extern_container_arg!(trackbarname);
 	extern_container_arg!(winname);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_setTrackbarPos_const_StringR_const_StringR_int(trackbarname.opencv_as_extern(), winname.opencv_as_extern(), pos, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_setTrackbarPos_const_StringR_const_StringR_int(trackbarname.opencv_as_extern(), winname.opencv_as_extern(), pos, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1224,7 +1319,7 @@ pub fn set_trackbar_pos(trackbarname: &str, winname: &str, pos: i32) -> Result<(
 pub fn set_window_property(winname: &str, prop_id: i32, prop_value: f64) -> Result<()> {
 	extern_container_arg!(winname);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_setWindowProperty_const_StringR_int_double(winname.opencv_as_extern(), prop_id, prop_value, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_setWindowProperty_const_StringR_int_double(winname.opencv_as_extern(), prop_id, prop_value, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1239,7 +1334,7 @@ pub fn set_window_title(winname: &str, title: &str) -> Result<()> {
 	extern_container_arg!(winname);
 	extern_container_arg!(title);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_setWindowTitle_const_StringR_const_StringR(winname.opencv_as_extern(), title.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_setWindowTitle_const_StringR_const_StringR(winname.opencv_as_extern(), title.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1249,7 +1344,7 @@ pub fn set_window_title(winname: &str, title: &str) -> Result<()> {
 pub fn start_loop(pt2_func: Option<unsafe extern "C" fn(i32, *mut *mut c_char) -> i32>, argc: i32, argv: &mut [&str]) -> Result<i32> {
 	string_array_arg_mut!(argv);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_startLoop_int__X__int__charXX__int_charXX(pt2_func, argc, argv.as_mut_ptr(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_startLoop_int__X__int__charXX__int_charXX(pt2_func, argc, argv.as_mut_ptr(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1258,7 +1353,7 @@ pub fn start_loop(pt2_func: Option<unsafe extern "C" fn(i32, *mut *mut c_char) -
 #[inline]
 pub fn start_window_thread() -> Result<i32> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_startWindowThread(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_startWindowThread(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1267,7 +1362,7 @@ pub fn start_window_thread() -> Result<i32> {
 #[inline]
 pub fn stop_loop() -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_stopLoop(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_stopLoop(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1281,7 +1376,7 @@ pub fn stop_loop() -> Result<()> {
 pub fn update_window(winname: &str) -> Result<()> {
 	extern_container_arg!(winname);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_updateWindow_const_StringR(winname.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_updateWindow_const_StringR(winname.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1299,7 +1394,7 @@ pub fn update_window(winname: &str) -> Result<()> {
 #[inline]
 pub fn wait_key_ex(delay: i32) -> Result<i32> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_waitKeyEx_int(delay, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_waitKeyEx_int(delay, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1331,7 +1426,7 @@ pub fn wait_key_ex(delay: i32) -> Result<i32> {
 #[inline]
 pub fn wait_key(delay: i32) -> Result<i32> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_waitKey_int(delay, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_waitKey_int(delay, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1344,142 +1439,258 @@ pub trait QtFontTraitConst {
 	/// Name of the font
 	#[inline]
 	fn name_font(&self) -> String {
-		let ret = unsafe { sys::cv_QtFont_getPropNameFont_const(self.as_raw_QtFont()) };
+		crate::core::validity::debug_check("QtFont", self.as_raw_QtFont());
+		let ret = unsafe { ffi_call!(cv_QtFont_getPropNameFont_const(self.as_raw_QtFont())) };
 		let ret = unsafe { String::opencv_from_extern(ret) };
 		ret
 	}
 	
-	/// Color of the font. Scalar(blue_component, green_component, red_component[, alpha_component])
+	/// Non-panicking version of [name_font](QtFontTraitConst::name_font), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_name_font(&self) -> Result<String> {
+		crate::core::validity::check("QtFont", self.as_raw_QtFont())?;
+		Ok(QtFontTraitConst::name_font(self))
+	}
+	
+	/// Color of the font. Scalar(blue_component, green_component, red_component\[, alpha_component\])
 	#[inline]
 	fn color(&self) -> core::Scalar {
+		crate::core::validity::debug_check("QtFont", self.as_raw_QtFont());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_QtFont_getPropColor_const(self.as_raw_QtFont(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_QtFont_getPropColor_const(self.as_raw_QtFont(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [color](QtFontTraitConst::color), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_color(&self) -> Result<core::Scalar> {
+		crate::core::validity::check("QtFont", self.as_raw_QtFont())?;
+		Ok(QtFontTraitConst::color(self))
+	}
+	
 	/// See cv::QtFontStyles
 	#[inline]
 	fn font_face(&self) -> i32 {
-		let ret = unsafe { sys::cv_QtFont_getPropFont_face_const(self.as_raw_QtFont()) };
+		crate::core::validity::debug_check("QtFont", self.as_raw_QtFont());
+		let ret = unsafe { ffi_call!(cv_QtFont_getPropFont_face_const(self.as_raw_QtFont())) };
 		ret
 	}
 	
+	/// Non-panicking version of [font_face](QtFontTraitConst::font_face), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_font_face(&self) -> Result<i32> {
+		crate::core::validity::check("QtFont", self.as_raw_QtFont())?;
+		Ok(QtFontTraitConst::font_face(self))
+	}
+	
 	/// font data and metrics
 	#[inline]
 	fn ascii(&self) -> *const i32 {
-		let ret = unsafe { sys::cv_QtFont_getPropAscii_const(self.as_raw_QtFont()) };
+		crate::core::validity::debug_check("QtFont", self.as_raw_QtFont());
+		let ret = unsafe { ffi_call!(cv_QtFont_getPropAscii_const(self.as_raw_QtFont())) };
 		ret
 	}
 	
+	/// Non-panicking version of [ascii](QtFontTraitConst::ascii), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_ascii(&self) -> Result<*const i32> {
+		crate::core::validity::check("QtFont", self.as_raw_QtFont())?;
+		Ok(QtFontTraitConst::ascii(self))
+	}
+	
 	#[inline]
 	fn greek(&self) -> *const i32 {
-		let ret = unsafe { sys::cv_QtFont_getPropGreek_const(self.as_raw_QtFont()) };
+		crate::core::validity::debug_check("QtFont", self.as_raw_QtFont());
+		let ret = unsafe { ffi_call!(cv_QtFont_getPropGreek_const(self.as_raw_QtFont())) };
 		ret
 	}
 	
+	/// Non-panicking version of [greek](QtFontTraitConst::greek), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_greek(&self) -> Result<*const i32> {
+		crate::core::validity::check("QtFont", self.as_raw_QtFont())?;
+		Ok(QtFontTraitConst::greek(self))
+	}
+	
 	#[inline]
 	fn cyrillic(&self) -> *const i32 {
-		let ret = unsafe { sys::cv_QtFont_getPropCyrillic_const(self.as_raw_QtFont()) };
+		crate::core::validity::debug_check("QtFont", self.as_raw_QtFont());
+		let ret = unsafe { ffi_call!(cv_QtFont_getPropCyrillic_const(self.as_raw_QtFont())) };
 		ret
 	}
 	
+	/// Non-panicking version of [cyrillic](QtFontTraitConst::cyrillic), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_cyrillic(&self) -> Result<*const i32> {
+		crate::core::validity::check("QtFont", self.as_raw_QtFont())?;
+		Ok(QtFontTraitConst::cyrillic(self))
+	}
+	
 	#[inline]
 	fn hscale(&self) -> f32 {
-		let ret = unsafe { sys::cv_QtFont_getPropHscale_const(self.as_raw_QtFont()) };
+		crate::core::validity::debug_check("QtFont", self.as_raw_QtFont());
+		let ret = unsafe { ffi_call!(cv_QtFont_getPropHscale_const(self.as_raw_QtFont())) };
 		ret
 	}
 	
+	/// Non-panicking version of [hscale](QtFontTraitConst::hscale), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_hscale(&self) -> Result<f32> {
+		crate::core::validity::check("QtFont", self.as_raw_QtFont())?;
+		Ok(QtFontTraitConst::hscale(self))
+	}
+	
 	#[inline]
 	fn vscale(&self) -> f32 {
-		let ret = unsafe { sys::cv_QtFont_getPropVscale_const(self.as_raw_QtFont()) };
+		crate::core::validity::debug_check("QtFont", self.as_raw_QtFont());
+		let ret = unsafe { ffi_call!(cv_QtFont_getPropVscale_const(self.as_raw_QtFont())) };
 		ret
 	}
 	
+	/// Non-panicking version of [vscale](QtFontTraitConst::vscale), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_vscale(&self) -> Result<f32> {
+		crate::core::validity::check("QtFont", self.as_raw_QtFont())?;
+		Ok(QtFontTraitConst::vscale(self))
+	}
+	
 	/// slope coefficient: 0 - normal, >0 - italic
 	#[inline]
 	fn shear(&self) -> f32 {
-		let ret = unsafe { sys::cv_QtFont_getPropShear_const(self.as_raw_QtFont()) };
+		crate::core::validity::debug_check("QtFont", self.as_raw_QtFont());
+		let ret = unsafe { ffi_call!(cv_QtFont_getPropShear_const(self.as_raw_QtFont())) };
 		ret
 	}
 	
+	/// Non-panicking version of [shear](QtFontTraitConst::shear), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_shear(&self) -> Result<f32> {
+		crate::core::validity::check("QtFont", self.as_raw_QtFont())?;
+		Ok(QtFontTraitConst::shear(self))
+	}
+	
 	/// See cv::QtFontWeights
 	#[inline]
 	fn thickness(&self) -> i32 {
-		let ret = unsafe { sys::cv_QtFont_getPropThickness_const(self.as_raw_QtFont()) };
+		crate::core::validity::debug_check("QtFont", self.as_raw_QtFont());
+		let ret = unsafe { ffi_call!(cv_QtFont_getPropThickness_const(self.as_raw_QtFont())) };
 		ret
 	}
 	
+	/// Non-panicking version of [thickness](QtFontTraitConst::thickness), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_thickness(&self) -> Result<i32> {
+		crate::core::validity::check("QtFont", self.as_raw_QtFont())?;
+		Ok(QtFontTraitConst::thickness(self))
+	}
+	
 	/// horizontal interval between letters
 	#[inline]
 	fn dx(&self) -> f32 {
-		let ret = unsafe { sys::cv_QtFont_getPropDx_const(self.as_raw_QtFont()) };
+		crate::core::validity::debug_check("QtFont", self.as_raw_QtFont());
+		let ret = unsafe { ffi_call!(cv_QtFont_getPropDx_const(self.as_raw_QtFont())) };
 		ret
 	}
 	
+	/// Non-panicking version of [dx](QtFontTraitConst::dx), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_dx(&self) -> Result<f32> {
+		crate::core::validity::check("QtFont", self.as_raw_QtFont())?;
+		Ok(QtFontTraitConst::dx(self))
+	}
+	
 	/// PointSize
 	#[inline]
 	fn line_type(&self) -> i32 {
-		let ret = unsafe { sys::cv_QtFont_getPropLine_type_const(self.as_raw_QtFont()) };
+		crate::core::validity::debug_check("QtFont", self.as_raw_QtFont());
+		let ret = unsafe { ffi_call!(cv_QtFont_getPropLine_type_const(self.as_raw_QtFont())) };
 		ret
 	}
 	
+	/// Non-panicking version of [line_type](QtFontTraitConst::line_type), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_line_type(&self) -> Result<i32> {
+		crate::core::validity::check("QtFont", self.as_raw_QtFont())?;
+		Ok(QtFontTraitConst::line_type(self))
+	}
+	
 }
 
 pub trait QtFontTrait: crate::highgui::QtFontTraitConst {
 	fn as_raw_mut_QtFont(&mut self) -> *mut c_void;
 
-	/// Color of the font. Scalar(blue_component, green_component, red_component[, alpha_component])
+	/// Color of the font. Scalar(blue_component, green_component, red_component\[, alpha_component\])
 	#[inline]
 	fn set_color(&mut self, val: core::Scalar) {
-		let ret = unsafe { sys::cv_QtFont_setPropColor_Scalar(self.as_raw_mut_QtFont(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("QtFont", self.as_raw_mut_QtFont());
+		let ret = unsafe { ffi_call!(cv_QtFont_setPropColor_Scalar(self.as_raw_mut_QtFont(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	/// See cv::QtFontStyles
 	#[inline]
 	fn set_font_face(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_QtFont_setPropFont_face_int(self.as_raw_mut_QtFont(), val) };
+		crate::core::validity::debug_check("QtFont", self.as_raw_mut_QtFont());
+		let ret = unsafe { ffi_call!(cv_QtFont_setPropFont_face_int(self.as_raw_mut_QtFont(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_hscale(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_QtFont_setPropHscale_float(self.as_raw_mut_QtFont(), val) };
+		crate::core::validity::debug_check("QtFont", self.as_raw_mut_QtFont());
+		let ret = unsafe { ffi_call!(cv_QtFont_setPropHscale_float(self.as_raw_mut_QtFont(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_vscale(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_QtFont_setPropVscale_float(self.as_raw_mut_QtFont(), val) };
+		crate::core::validity::debug_check("QtFont", self.as_raw_mut_QtFont());
+		let ret = unsafe { ffi_call!(cv_QtFont_setPropVscale_float(self.as_raw_mut_QtFont(), val)) };
 		ret
 	}
 	
 	/// slope coefficient: 0 - normal, >0 - italic
 	#[inline]
 	fn set_shear(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_QtFont_setPropShear_float(self.as_raw_mut_QtFont(), val) };
+		crate::core::validity::debug_check("QtFont", self.as_raw_mut_QtFont());
+		let ret = unsafe { ffi_call!(cv_QtFont_setPropShear_float(self.as_raw_mut_QtFont(), val)) };
 		ret
 	}
 	
 	/// See cv::QtFontWeights
 	#[inline]
 	fn set_thickness(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_QtFont_setPropThickness_int(self.as_raw_mut_QtFont(), val) };
+		crate::core::validity::debug_check("QtFont", self.as_raw_mut_QtFont());
+		let ret = unsafe { ffi_call!(cv_QtFont_setPropThickness_int(self.as_raw_mut_QtFont(), val)) };
 		ret
 	}
 	
 	/// horizontal interval between letters
 	#[inline]
 	fn set_dx(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_QtFont_setPropDx_float(self.as_raw_mut_QtFont(), val) };
+		crate::core::validity::debug_check("QtFont", self.as_raw_mut_QtFont());
+		let ret = unsafe { ffi_call!(cv_QtFont_setPropDx_float(self.as_raw_mut_QtFont(), val)) };
 		ret
 	}
 	
 	/// PointSize
 	#[inline]
 	fn set_line_type(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_QtFont_setPropLine_type_int(self.as_raw_mut_QtFont(), val) };
+		crate::core::validity::debug_check("QtFont", self.as_raw_mut_QtFont());
+		let ret = unsafe { ffi_call!(cv_QtFont_setPropLine_type_int(self.as_raw_mut_QtFont(), val)) };
 		ret
 	}
 	
@@ -1495,7 +1706,10 @@ opencv_type_boxed! { QtFont }
 impl Drop for QtFont {
 	fn drop(&mut self) {
 		extern "C" { fn cv_QtFont_delete(instance: *mut c_void); }
-		unsafe { cv_QtFont_delete(self.as_raw_mut_QtFont()) };
+		let ptr = self.as_raw_mut_QtFont();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_QtFont_delete(ptr) };
 	}
 }
 
@@ -1511,3 +1725,4 @@ impl crate::highgui::QtFontTrait for QtFont {
 
 impl QtFont {
 }
+pub use crate::manual::highgui::*;
@@ -95,7 +95,7 @@ pub fn average_hash(input_arr: &dyn core::ToInputArray, output_arr: &mut dyn cor
 	input_array_arg!(input_arr);
 	output_array_arg!(output_arr);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_img_hash_averageHash_const__InputArrayR_const__OutputArrayR(input_arr.as_raw__InputArray(), output_arr.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_img_hash_averageHash_const__InputArrayR_const__OutputArrayR(input_arr.as_raw__InputArray(), output_arr.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -114,7 +114,7 @@ pub fn block_mean_hash(input_arr: &dyn core::ToInputArray, output_arr: &mut dyn
 	input_array_arg!(input_arr);
 	output_array_arg!(output_arr);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_img_hash_blockMeanHash_const__InputArrayR_const__OutputArrayR_int(input_arr.as_raw__InputArray(), output_arr.as_raw__OutputArray(), mode, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_img_hash_blockMeanHash_const__InputArrayR_const__OutputArrayR_int(input_arr.as_raw__InputArray(), output_arr.as_raw__OutputArray(), mode, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -132,7 +132,7 @@ pub fn color_moment_hash(input_arr: &dyn core::ToInputArray, output_arr: &mut dy
 	input_array_arg!(input_arr);
 	output_array_arg!(output_arr);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_img_hash_colorMomentHash_const__InputArrayR_const__OutputArrayR(input_arr.as_raw__InputArray(), output_arr.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_img_hash_colorMomentHash_const__InputArrayR_const__OutputArrayR(input_arr.as_raw__InputArray(), output_arr.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -155,7 +155,7 @@ pub fn marr_hildreth_hash(input_arr: &dyn core::ToInputArray, output_arr: &mut d
 	input_array_arg!(input_arr);
 	output_array_arg!(output_arr);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_img_hash_marrHildrethHash_const__InputArrayR_const__OutputArrayR_float_float(input_arr.as_raw__InputArray(), output_arr.as_raw__OutputArray(), alpha, scale, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_img_hash_marrHildrethHash_const__InputArrayR_const__OutputArrayR_float_float(input_arr.as_raw__InputArray(), output_arr.as_raw__OutputArray(), alpha, scale, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -171,7 +171,7 @@ pub fn p_hash(input_arr: &dyn core::ToInputArray, output_arr: &mut dyn core::ToO
 	input_array_arg!(input_arr);
 	output_array_arg!(output_arr);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_img_hash_pHash_const__InputArrayR_const__OutputArrayR(input_arr.as_raw__InputArray(), output_arr.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_img_hash_pHash_const__InputArrayR_const__OutputArrayR(input_arr.as_raw__InputArray(), output_arr.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -193,7 +193,7 @@ pub fn radial_variance_hash(input_arr: &dyn core::ToInputArray, output_arr: &mut
 	input_array_arg!(input_arr);
 	output_array_arg!(output_arr);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_img_hash_radialVarianceHash_const__InputArrayR_const__OutputArrayR_double_int(input_arr.as_raw__InputArray(), output_arr.as_raw__OutputArray(), sigma, num_of_angle_line, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_img_hash_radialVarianceHash_const__InputArrayR_const__OutputArrayR_double_int(input_arr.as_raw__InputArray(), output_arr.as_raw__OutputArray(), sigma, num_of_angle_line, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -226,7 +226,10 @@ opencv_type_boxed! { AverageHash }
 impl Drop for AverageHash {
 	fn drop(&mut self) {
 		extern "C" { fn cv_AverageHash_delete(instance: *mut c_void); }
-		unsafe { cv_AverageHash_delete(self.as_raw_mut_AverageHash()) };
+		let ptr = self.as_raw_mut_AverageHash();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_AverageHash_delete(ptr) };
 	}
 }
 
@@ -260,7 +263,7 @@ impl AverageHash {
 	#[inline]
 	pub fn create() -> Result<core::Ptr<crate::img_hash::AverageHash>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_img_hash_AverageHash_create(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_img_hash_AverageHash_create(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::img_hash::AverageHash>::opencv_from_extern(ret) };
@@ -281,8 +284,9 @@ pub trait BlockMeanHashTraitConst: crate::img_hash::ImgHashBaseTraitConst {
 
 	#[inline]
 	fn get_mean(&self) -> Result<core::Vector<f64>> {
+		crate::core::validity::check("BlockMeanHash", self.as_raw_BlockMeanHash())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_img_hash_BlockMeanHash_getMean_const(self.as_raw_BlockMeanHash(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_img_hash_BlockMeanHash_getMean_const(self.as_raw_BlockMeanHash(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<f64>::opencv_from_extern(ret) };
@@ -299,8 +303,9 @@ pub trait BlockMeanHashTrait: crate::img_hash::BlockMeanHashTraitConst + crate::
 	/// * mode: the mode
 	#[inline]
 	fn set_mode(&mut self, mode: i32) -> Result<()> {
+		crate::core::validity::check("BlockMeanHash", self.as_raw_mut_BlockMeanHash())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_img_hash_BlockMeanHash_setMode_int(self.as_raw_mut_BlockMeanHash(), mode, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_img_hash_BlockMeanHash_setMode_int(self.as_raw_mut_BlockMeanHash(), mode, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -320,7 +325,10 @@ opencv_type_boxed! { BlockMeanHash }
 impl Drop for BlockMeanHash {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BlockMeanHash_delete(instance: *mut c_void); }
-		unsafe { cv_BlockMeanHash_delete(self.as_raw_mut_BlockMeanHash()) };
+		let ptr = self.as_raw_mut_BlockMeanHash();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_BlockMeanHash_delete(ptr) };
 	}
 }
 
@@ -356,7 +364,7 @@ impl BlockMeanHash {
 	#[inline]
 	pub fn create(mode: i32) -> Result<core::Ptr<crate::img_hash::BlockMeanHash>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_img_hash_BlockMeanHash_create_int(mode, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_img_hash_BlockMeanHash_create_int(mode, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::img_hash::BlockMeanHash>::opencv_from_extern(ret) };
@@ -394,7 +402,10 @@ opencv_type_boxed! { ColorMomentHash }
 impl Drop for ColorMomentHash {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ColorMomentHash_delete(instance: *mut c_void); }
-		unsafe { cv_ColorMomentHash_delete(self.as_raw_mut_ColorMomentHash()) };
+		let ptr = self.as_raw_mut_ColorMomentHash();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ColorMomentHash_delete(ptr) };
 	}
 }
 
@@ -428,7 +439,7 @@ impl ColorMomentHash {
 	#[inline]
 	pub fn create() -> Result<core::Ptr<crate::img_hash::ColorMomentHash>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_img_hash_ColorMomentHash_create(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_img_hash_ColorMomentHash_create(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::img_hash::ColorMomentHash>::opencv_from_extern(ret) };
@@ -454,10 +465,11 @@ pub trait ImgHashBaseTraitConst: core::AlgorithmTraitConst {
 	/// of the value vary from algorithms to algorithms
 	#[inline]
 	fn compare(&self, hash_one: &dyn core::ToInputArray, hash_two: &dyn core::ToInputArray) -> Result<f64> {
+		crate::core::validity::check("ImgHashBase", self.as_raw_ImgHashBase())?;
 		input_array_arg!(hash_one);
 		input_array_arg!(hash_two);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_img_hash_ImgHashBase_compare_const_const__InputArrayR_const__InputArrayR(self.as_raw_ImgHashBase(), hash_one.as_raw__InputArray(), hash_two.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_img_hash_ImgHashBase_compare_const_const__InputArrayR_const__InputArrayR(self.as_raw_ImgHashBase(), hash_one.as_raw__InputArray(), hash_two.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -474,10 +486,11 @@ pub trait ImgHashBaseTrait: core::AlgorithmTrait + crate::img_hash::ImgHashBaseT
 	/// * outputArr: hash of the image
 	#[inline]
 	fn compute(&mut self, input_arr: &dyn core::ToInputArray, output_arr: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("ImgHashBase", self.as_raw_mut_ImgHashBase())?;
 		input_array_arg!(input_arr);
 		output_array_arg!(output_arr);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_img_hash_ImgHashBase_compute_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_ImgHashBase(), input_arr.as_raw__InputArray(), output_arr.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_img_hash_ImgHashBase_compute_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_ImgHashBase(), input_arr.as_raw__InputArray(), output_arr.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -495,7 +508,10 @@ opencv_type_boxed! { ImgHashBase }
 impl Drop for ImgHashBase {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ImgHashBase_delete(instance: *mut c_void); }
-		unsafe { cv_ImgHashBase_delete(self.as_raw_mut_ImgHashBase()) };
+		let ptr = self.as_raw_mut_ImgHashBase();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ImgHashBase_delete(ptr) };
 	}
 }
 
@@ -531,8 +547,9 @@ pub trait MarrHildrethHashTraitConst: crate::img_hash::ImgHashBaseTraitConst {
 	/// self explain
 	#[inline]
 	fn get_alpha(&self) -> Result<f32> {
+		crate::core::validity::check("MarrHildrethHash", self.as_raw_MarrHildrethHash())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_img_hash_MarrHildrethHash_getAlpha_const(self.as_raw_MarrHildrethHash(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_img_hash_MarrHildrethHash_getAlpha_const(self.as_raw_MarrHildrethHash(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -541,8 +558,9 @@ pub trait MarrHildrethHashTraitConst: crate::img_hash::ImgHashBaseTraitConst {
 	/// self explain
 	#[inline]
 	fn get_scale(&self) -> Result<f32> {
+		crate::core::validity::check("MarrHildrethHash", self.as_raw_MarrHildrethHash())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_img_hash_MarrHildrethHash_getScale_const(self.as_raw_MarrHildrethHash(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_img_hash_MarrHildrethHash_getScale_const(self.as_raw_MarrHildrethHash(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -559,8 +577,9 @@ pub trait MarrHildrethHashTrait: crate::img_hash::ImgHashBaseTrait + crate::img_
 	/// * scale: int level of scale factor (default = 1)
 	#[inline]
 	fn set_kernel_param(&mut self, alpha: f32, scale: f32) -> Result<()> {
+		crate::core::validity::check("MarrHildrethHash", self.as_raw_mut_MarrHildrethHash())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_img_hash_MarrHildrethHash_setKernelParam_float_float(self.as_raw_mut_MarrHildrethHash(), alpha, scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_img_hash_MarrHildrethHash_setKernelParam_float_float(self.as_raw_mut_MarrHildrethHash(), alpha, scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -580,7 +599,10 @@ opencv_type_boxed! { MarrHildrethHash }
 impl Drop for MarrHildrethHash {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MarrHildrethHash_delete(instance: *mut c_void); }
-		unsafe { cv_MarrHildrethHash_delete(self.as_raw_mut_MarrHildrethHash()) };
+		let ptr = self.as_raw_mut_MarrHildrethHash();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_MarrHildrethHash_delete(ptr) };
 	}
 }
 
@@ -621,7 +643,7 @@ impl MarrHildrethHash {
 	#[inline]
 	pub fn create(alpha: f32, scale: f32) -> Result<core::Ptr<crate::img_hash::MarrHildrethHash>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_img_hash_MarrHildrethHash_create_float_float(alpha, scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_img_hash_MarrHildrethHash_create_float_float(alpha, scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::img_hash::MarrHildrethHash>::opencv_from_extern(ret) };
@@ -663,7 +685,10 @@ opencv_type_boxed! { PHash }
 impl Drop for PHash {
 	fn drop(&mut self) {
 		extern "C" { fn cv_PHash_delete(instance: *mut c_void); }
-		unsafe { cv_PHash_delete(self.as_raw_mut_PHash()) };
+		let ptr = self.as_raw_mut_PHash();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_PHash_delete(ptr) };
 	}
 }
 
@@ -697,7 +722,7 @@ impl PHash {
 	#[inline]
 	pub fn create() -> Result<core::Ptr<crate::img_hash::PHash>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_img_hash_PHash_create(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_img_hash_PHash_create(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::img_hash::PHash>::opencv_from_extern(ret) };
@@ -718,8 +743,9 @@ pub trait RadialVarianceHashTraitConst: crate::img_hash::ImgHashBaseTraitConst {
 
 	#[inline]
 	fn get_num_of_angle_line(&self) -> Result<i32> {
+		crate::core::validity::check("RadialVarianceHash", self.as_raw_RadialVarianceHash())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_img_hash_RadialVarianceHash_getNumOfAngleLine_const(self.as_raw_RadialVarianceHash(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_img_hash_RadialVarianceHash_getNumOfAngleLine_const(self.as_raw_RadialVarianceHash(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -727,8 +753,9 @@ pub trait RadialVarianceHashTraitConst: crate::img_hash::ImgHashBaseTraitConst {
 	
 	#[inline]
 	fn get_sigma(&self) -> Result<f64> {
+		crate::core::validity::check("RadialVarianceHash", self.as_raw_RadialVarianceHash())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_img_hash_RadialVarianceHash_getSigma_const(self.as_raw_RadialVarianceHash(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_img_hash_RadialVarianceHash_getSigma_const(self.as_raw_RadialVarianceHash(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -741,8 +768,9 @@ pub trait RadialVarianceHashTrait: crate::img_hash::ImgHashBaseTrait + crate::im
 
 	#[inline]
 	fn set_num_of_angle_line(&mut self, value: i32) -> Result<()> {
+		crate::core::validity::check("RadialVarianceHash", self.as_raw_mut_RadialVarianceHash())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_img_hash_RadialVarianceHash_setNumOfAngleLine_int(self.as_raw_mut_RadialVarianceHash(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_img_hash_RadialVarianceHash_setNumOfAngleLine_int(self.as_raw_mut_RadialVarianceHash(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -750,8 +778,9 @@ pub trait RadialVarianceHashTrait: crate::img_hash::ImgHashBaseTrait + crate::im
 	
 	#[inline]
 	fn set_sigma(&mut self, value: f64) -> Result<()> {
+		crate::core::validity::check("RadialVarianceHash", self.as_raw_mut_RadialVarianceHash())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_img_hash_RadialVarianceHash_setSigma_double(self.as_raw_mut_RadialVarianceHash(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_img_hash_RadialVarianceHash_setSigma_double(self.as_raw_mut_RadialVarianceHash(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -759,8 +788,9 @@ pub trait RadialVarianceHashTrait: crate::img_hash::ImgHashBaseTrait + crate::im
 	
 	#[inline]
 	fn get_features(&mut self) -> Result<core::Vector<f64>> {
+		crate::core::validity::check("RadialVarianceHash", self.as_raw_mut_RadialVarianceHash())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_img_hash_RadialVarianceHash_getFeatures(self.as_raw_mut_RadialVarianceHash(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_img_hash_RadialVarianceHash_getFeatures(self.as_raw_mut_RadialVarianceHash(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<f64>::opencv_from_extern(ret) };
@@ -769,8 +799,9 @@ pub trait RadialVarianceHashTrait: crate::img_hash::ImgHashBaseTrait + crate::im
 	
 	#[inline]
 	fn get_hash(&mut self) -> Result<core::Mat> {
+		crate::core::validity::check("RadialVarianceHash", self.as_raw_mut_RadialVarianceHash())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_img_hash_RadialVarianceHash_getHash(self.as_raw_mut_RadialVarianceHash(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_img_hash_RadialVarianceHash_getHash(self.as_raw_mut_RadialVarianceHash(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -779,8 +810,9 @@ pub trait RadialVarianceHashTrait: crate::img_hash::ImgHashBaseTrait + crate::im
 	
 	#[inline]
 	fn get_pix_per_line(&mut self, input: &core::Mat) -> Result<core::Mat> {
+		crate::core::validity::check("RadialVarianceHash", self.as_raw_mut_RadialVarianceHash())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_img_hash_RadialVarianceHash_getPixPerLine_const_MatR(self.as_raw_mut_RadialVarianceHash(), input.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_img_hash_RadialVarianceHash_getPixPerLine_const_MatR(self.as_raw_mut_RadialVarianceHash(), input.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -789,8 +821,9 @@ pub trait RadialVarianceHashTrait: crate::img_hash::ImgHashBaseTrait + crate::im
 	
 	#[inline]
 	fn get_projection(&mut self) -> Result<core::Mat> {
+		crate::core::validity::check("RadialVarianceHash", self.as_raw_mut_RadialVarianceHash())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_img_hash_RadialVarianceHash_getProjection(self.as_raw_mut_RadialVarianceHash(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_img_hash_RadialVarianceHash_getProjection(self.as_raw_mut_RadialVarianceHash(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -811,7 +844,10 @@ opencv_type_boxed! { RadialVarianceHash }
 impl Drop for RadialVarianceHash {
 	fn drop(&mut self) {
 		extern "C" { fn cv_RadialVarianceHash_delete(instance: *mut c_void); }
-		unsafe { cv_RadialVarianceHash_delete(self.as_raw_mut_RadialVarianceHash()) };
+		let ptr = self.as_raw_mut_RadialVarianceHash();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_RadialVarianceHash_delete(ptr) };
 	}
 }
 
@@ -848,7 +884,7 @@ impl RadialVarianceHash {
 	#[inline]
 	pub fn create(sigma: f64, num_of_angle_line: i32) -> Result<core::Ptr<crate::img_hash::RadialVarianceHash>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_img_hash_RadialVarianceHash_create_double_int(sigma, num_of_angle_line, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_img_hash_RadialVarianceHash_create_double_int(sigma, num_of_angle_line, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::img_hash::RadialVarianceHash>::opencv_from_extern(ret) };
@@ -860,3 +896,4 @@ impl RadialVarianceHash {
 boxed_cast_base! { RadialVarianceHash, core::Algorithm, cv_RadialVarianceHash_to_Algorithm }
 
 boxed_cast_base! { RadialVarianceHash, crate::img_hash::ImgHashBase, cv_RadialVarianceHash_to_ImgHashBase }
+pub use crate::manual::img_hash::*;
@@ -285,7 +285,7 @@ opencv_type_enum! { crate::imgcodecs::ImwritePNGFlags }
 pub fn have_image_reader(filename: &str) -> Result<bool> {
 	extern_container_arg!(filename);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_haveImageReader_const_StringR(filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_haveImageReader_const_StringR(filename.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -299,7 +299,7 @@ pub fn have_image_reader(filename: &str) -> Result<bool> {
 pub fn have_image_writer(filename: &str) -> Result<bool> {
 	extern_container_arg!(filename);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_haveImageWriter_const_StringR(filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_haveImageWriter_const_StringR(filename.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -318,7 +318,7 @@ pub fn have_image_writer(filename: &str) -> Result<bool> {
 pub fn imcount(filename: &str, flags: i32) -> Result<size_t> {
 	extern_container_arg!(filename);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_imcount_const_StringR_int(filename.opencv_as_extern(), flags, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_imcount_const_StringR_int(filename.opencv_as_extern(), flags, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -340,7 +340,7 @@ pub fn imcount(filename: &str, flags: i32) -> Result<size_t> {
 pub fn imdecode(buf: &dyn core::ToInputArray, flags: i32) -> Result<core::Mat> {
 	input_array_arg!(buf);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_imdecode_const__InputArrayR_int(buf.as_raw__InputArray(), flags, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_imdecode_const__InputArrayR_int(buf.as_raw__InputArray(), flags, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -370,7 +370,7 @@ pub fn imdecode(buf: &dyn core::ToInputArray, flags: i32) -> Result<core::Mat> {
 pub fn imdecode_to(buf: &dyn core::ToInputArray, flags: i32, dst: &mut core::Mat) -> Result<core::Mat> {
 	input_array_arg!(buf);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_imdecode_const__InputArrayR_int_MatX(buf.as_raw__InputArray(), flags, dst.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_imdecode_const__InputArrayR_int_MatX(buf.as_raw__InputArray(), flags, dst.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -395,7 +395,7 @@ pub fn imencode(ext: &str, img: &dyn core::ToInputArray, buf: &mut core::Vector<
 	extern_container_arg!(ext);
 	input_array_arg!(img);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_imencode_const_StringR_const__InputArrayR_vector_unsigned_char_R_const_vector_int_R(ext.opencv_as_extern(), img.as_raw__InputArray(), buf.as_raw_mut_VectorOfu8(), params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_imencode_const_StringR_const__InputArrayR_vector_unsigned_char_R_const_vector_int_R(ext.opencv_as_extern(), img.as_raw__InputArray(), buf.as_raw_mut_VectorOfu8(), params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -439,13 +439,13 @@ pub fn imencode(ext: &str, img: &dyn core::ToInputArray, buf: &mut core::Vector<
 ///    codecs supplied with an OS image. Install the relevant packages (do not forget the development
 ///    files, for example, "libjpeg-dev", in Debian\* and Ubuntu\*) to get the codec support or turn
 ///    on the OPENCV_BUILD_3RDPARTY_LIBS flag in CMake.
-/// *   In the case you set *WITH_GDAL* flag to true in CMake and @ref IMREAD_LOAD_GDAL to load the image,
+/// *   In the case you set *WITH_GDAL* flag to true in CMake and `IMREAD_LOAD_GDAL` to load the image,
 ///    then the [GDAL](http://www.gdal.org) driver will be used in order to decode the image, supporting
 ///    the following formats: [Raster](http://www.gdal.org/formats_list.html),
 ///    [Vector](http://www.gdal.org/ogr_formats.html).
 /// *   If EXIF information is embedded in the image file, the EXIF orientation will be taken into account
-///    and thus the image will be rotated accordingly except if the flags @ref IMREAD_IGNORE_ORIENTATION
-///    or @ref IMREAD_UNCHANGED are passed.
+///    and thus the image will be rotated accordingly except if the flags `IMREAD_IGNORE_ORIENTATION`
+///    or `IMREAD_UNCHANGED` are passed.
 /// *   Use the IMREAD_UNCHANGED flag to keep the floating point values from PFM image.
 /// *   By default number of pixels must be less than 2^30. Limit can be set using system
 ///    variable OPENCV_IO_MAX_IMAGE_PIXELS
@@ -460,7 +460,7 @@ pub fn imencode(ext: &str, img: &dyn core::ToInputArray, buf: &mut core::Vector<
 pub fn imread(filename: &str, flags: i32) -> Result<core::Mat> {
 	extern_container_arg!(filename);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_imread_const_StringR_int(filename.opencv_as_extern(), flags, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_imread_const_StringR_int(filename.opencv_as_extern(), flags, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -475,7 +475,7 @@ pub fn imread(filename: &str, flags: i32) -> Result<core::Mat> {
 /// * flags: Flag that can take values of cv::ImreadModes, default with cv::IMREAD_ANYCOLOR.
 /// * mats: A vector of Mat objects holding each page, if more than one.
 /// ## See also
-/// cv::imread
+/// [imread](crate::imgcodecs::imread)
 /// 
 /// ## C++ default parameters
 /// * flags: IMREAD_ANYCOLOR
@@ -483,7 +483,7 @@ pub fn imread(filename: &str, flags: i32) -> Result<core::Mat> {
 pub fn imreadmulti(filename: &str, mats: &mut core::Vector<core::Mat>, flags: i32) -> Result<bool> {
 	extern_container_arg!(filename);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_imreadmulti_const_StringR_vector_Mat_R_int(filename.opencv_as_extern(), mats.as_raw_mut_VectorOfMat(), flags, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_imreadmulti_const_StringR_vector_Mat_R_int(filename.opencv_as_extern(), mats.as_raw_mut_VectorOfMat(), flags, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -499,7 +499,7 @@ pub fn imreadmulti(filename: &str, mats: &mut core::Vector<core::Mat>, flags: i3
 /// * flags: Flag that can take values of cv::ImreadModes, default with cv::IMREAD_ANYCOLOR.
 /// * mats: A vector of Mat objects holding each page, if more than one.
 /// ## See also
-/// cv::imread
+/// [imread](crate::imgcodecs::imread)
 /// 
 /// ## C++ default parameters
 /// * flags: IMREAD_ANYCOLOR
@@ -507,7 +507,7 @@ pub fn imreadmulti(filename: &str, mats: &mut core::Vector<core::Mat>, flags: i3
 pub fn imreadmulti_range(filename: &str, mats: &mut core::Vector<core::Mat>, start: i32, count: i32, flags: i32) -> Result<bool> {
 	extern_container_arg!(filename);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_imreadmulti_const_StringR_vector_Mat_R_int_int_int(filename.opencv_as_extern(), mats.as_raw_mut_VectorOfMat(), start, count, flags, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_imreadmulti_const_StringR_vector_Mat_R_int_int_int(filename.opencv_as_extern(), mats.as_raw_mut_VectorOfMat(), start, count, flags, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -550,7 +550,7 @@ pub fn imwrite(filename: &str, img: &dyn core::ToInputArray, params: &core::Vect
 	extern_container_arg!(filename);
 	input_array_arg!(img);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_imwrite_const_StringR_const__InputArrayR_const_vector_int_R(filename.opencv_as_extern(), img.as_raw__InputArray(), params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_imwrite_const_StringR_const__InputArrayR_const_vector_int_R(filename.opencv_as_extern(), img.as_raw__InputArray(), params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -565,8 +565,9 @@ pub fn imwritemulti(filename: &str, img: &dyn core::ToInputArray, params: &core:
 	extern_container_arg!(filename);
 	input_array_arg!(img);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_imwritemulti_const_StringR_const__InputArrayR_const_vector_int_R(filename.opencv_as_extern(), img.as_raw__InputArray(), params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_imwritemulti_const_StringR_const__InputArrayR_const_vector_int_R(filename.opencv_as_extern(), img.as_raw__InputArray(), params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
+pub use crate::manual::imgcodecs::*;
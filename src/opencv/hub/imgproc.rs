@@ -107,7 +107,7 @@
 //! 
 //! 
 //! Note: The functions do not support alpha-transparency when the target image is 4-channel. In this
-//! case, the color[3] is simply copied to the repainted pixels. Thus, if you want to paint
+//! case, the color\[3\] is simply copied to the repainted pixels. Thus, if you want to paint
 //! semi-transparent shapes, you can paint them in a separate buffer and then blend it with the main
 //! image.
 //! 
@@ -125,7 +125,7 @@
 //! 
 //! @include snippets/imgproc_applyColorMap.cpp
 //! ## See also
-//! #ColormapTypes
+//! `ColormapTypes`
 //! 
 //!    # Planar Subdivision
 //! 
@@ -251,26 +251,26 @@ pub const COLOR_BGR2BGR555: i32 = 22;
 pub const COLOR_BGR2BGR565: i32 = 12;
 /// add alpha channel to RGB or BGR image
 pub const COLOR_BGR2BGRA: i32 = 0;
-/// convert between RGB/BGR and grayscale, @ref color_convert_rgb_gray "color conversions"
+/// convert between RGB/BGR and grayscale, color conversions
 pub const COLOR_BGR2GRAY: i32 = 6;
-/// convert RGB/BGR to HLS (hue lightness saturation) with H range 0..180 if 8 bit image, @ref color_convert_rgb_hls "color conversions"
+/// convert RGB/BGR to HLS (hue lightness saturation) with H range 0..180 if 8 bit image, color conversions
 pub const COLOR_BGR2HLS: i32 = 52;
-/// convert RGB/BGR to HLS (hue lightness saturation) with H range 0..255 if 8 bit image, @ref color_convert_rgb_hls "color conversions"
+/// convert RGB/BGR to HLS (hue lightness saturation) with H range 0..255 if 8 bit image, color conversions
 pub const COLOR_BGR2HLS_FULL: i32 = 68;
-/// convert RGB/BGR to HSV (hue saturation value) with H range 0..180 if 8 bit image, @ref color_convert_rgb_hsv "color conversions"
+/// convert RGB/BGR to HSV (hue saturation value) with H range 0..180 if 8 bit image, color conversions
 pub const COLOR_BGR2HSV: i32 = 40;
-/// convert RGB/BGR to HSV (hue saturation value) with H range 0..255 if 8 bit image, @ref color_convert_rgb_hsv "color conversions"
+/// convert RGB/BGR to HSV (hue saturation value) with H range 0..255 if 8 bit image, color conversions
 pub const COLOR_BGR2HSV_FULL: i32 = 66;
-/// convert RGB/BGR to CIE Lab, @ref color_convert_rgb_lab "color conversions"
+/// convert RGB/BGR to CIE Lab, color conversions
 pub const COLOR_BGR2Lab: i32 = 44;
-/// convert RGB/BGR to CIE Luv, @ref color_convert_rgb_luv "color conversions"
+/// convert RGB/BGR to CIE Luv, color conversions
 pub const COLOR_BGR2Luv: i32 = 50;
 pub const COLOR_BGR2RGB: i32 = 4;
 /// convert between RGB and BGR color spaces (with or without alpha channel)
 pub const COLOR_BGR2RGBA: i32 = 2;
-/// convert RGB/BGR to CIE XYZ, @ref color_convert_rgb_xyz "color conversions"
+/// convert RGB/BGR to CIE XYZ, color conversions
 pub const COLOR_BGR2XYZ: i32 = 32;
-/// convert RGB/BGR to luma-chroma (aka YCC), @ref color_convert_rgb_ycrcb "color conversions"
+/// convert RGB/BGR to luma-chroma (aka YCC), color conversions
 pub const COLOR_BGR2YCrCb: i32 = 36;
 /// convert between RGB/BGR and YUV
 pub const COLOR_BGR2YUV: i32 = 82;
@@ -920,7 +920,7 @@ pub const WARP_POLAR_LINEAR: i32 = 0;
 pub const WARP_POLAR_LOG: i32 = 256;
 /// adaptive threshold algorithm
 /// ## See also
-/// adaptiveThreshold
+/// `adaptive_threshold`
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum AdaptiveThresholdTypes {
@@ -937,7 +937,7 @@ opencv_type_enum! { crate::imgproc::AdaptiveThresholdTypes }
 
 /// the color conversion codes
 /// ## See also
-/// @ref imgproc_color_conversions
+/// imgproc_color_conversions
 /// @ingroup imgproc_color_conversions
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -957,7 +957,7 @@ pub enum ColorConversionCodes {
 	// COLOR_RGB2BGR = 4 as isize, // duplicate discriminant
 	COLOR_BGRA2RGBA = 5,
 	// COLOR_RGBA2BGRA = 5 as isize, // duplicate discriminant
-	/// convert between RGB/BGR and grayscale, @ref color_convert_rgb_gray "color conversions"
+	/// convert between RGB/BGR and grayscale, color conversions
 	COLOR_BGR2GRAY = 6,
 	COLOR_RGB2GRAY = 7,
 	COLOR_GRAY2BGR = 8,
@@ -990,26 +990,26 @@ pub enum ColorConversionCodes {
 	/// convert between grayscale and BGR555 (16-bit images)
 	COLOR_GRAY2BGR555 = 30,
 	COLOR_BGR5552GRAY = 31,
-	/// convert RGB/BGR to CIE XYZ, @ref color_convert_rgb_xyz "color conversions"
+	/// convert RGB/BGR to CIE XYZ, color conversions
 	COLOR_BGR2XYZ = 32,
 	COLOR_RGB2XYZ = 33,
 	COLOR_XYZ2BGR = 34,
 	COLOR_XYZ2RGB = 35,
-	/// convert RGB/BGR to luma-chroma (aka YCC), @ref color_convert_rgb_ycrcb "color conversions"
+	/// convert RGB/BGR to luma-chroma (aka YCC), color conversions
 	COLOR_BGR2YCrCb = 36,
 	COLOR_RGB2YCrCb = 37,
 	COLOR_YCrCb2BGR = 38,
 	COLOR_YCrCb2RGB = 39,
-	/// convert RGB/BGR to HSV (hue saturation value) with H range 0..180 if 8 bit image, @ref color_convert_rgb_hsv "color conversions"
+	/// convert RGB/BGR to HSV (hue saturation value) with H range 0..180 if 8 bit image, color conversions
 	COLOR_BGR2HSV = 40,
 	COLOR_RGB2HSV = 41,
-	/// convert RGB/BGR to CIE Lab, @ref color_convert_rgb_lab "color conversions"
+	/// convert RGB/BGR to CIE Lab, color conversions
 	COLOR_BGR2Lab = 44,
 	COLOR_RGB2Lab = 45,
-	/// convert RGB/BGR to CIE Luv, @ref color_convert_rgb_luv "color conversions"
+	/// convert RGB/BGR to CIE Luv, color conversions
 	COLOR_BGR2Luv = 50,
 	COLOR_RGB2Luv = 51,
-	/// convert RGB/BGR to HLS (hue lightness saturation) with H range 0..180 if 8 bit image, @ref color_convert_rgb_hls "color conversions"
+	/// convert RGB/BGR to HLS (hue lightness saturation) with H range 0..180 if 8 bit image, color conversions
 	COLOR_BGR2HLS = 52,
 	COLOR_RGB2HLS = 53,
 	/// backward conversions HSV to RGB/BGR with H range 0..180 if 8 bit image
@@ -1022,10 +1022,10 @@ pub enum ColorConversionCodes {
 	/// backward conversions HLS to RGB/BGR with H range 0..180 if 8 bit image
 	COLOR_HLS2BGR = 60,
 	COLOR_HLS2RGB = 61,
-	/// convert RGB/BGR to HSV (hue saturation value) with H range 0..255 if 8 bit image, @ref color_convert_rgb_hsv "color conversions"
+	/// convert RGB/BGR to HSV (hue saturation value) with H range 0..255 if 8 bit image, color conversions
 	COLOR_BGR2HSV_FULL = 66,
 	COLOR_RGB2HSV_FULL = 67,
-	/// convert RGB/BGR to HLS (hue lightness saturation) with H range 0..255 if 8 bit image, @ref color_convert_rgb_hls "color conversions"
+	/// convert RGB/BGR to HLS (hue lightness saturation) with H range 0..255 if 8 bit image, color conversions
 	COLOR_BGR2HLS_FULL = 68,
 	COLOR_RGB2HLS_FULL = 69,
 	/// backward conversions HSV to RGB/BGR with H range 0..255 if 8 bit image
@@ -1473,7 +1473,7 @@ opencv_type_enum! { crate::imgproc::DistanceTransformMasks }
 
 /// Distance types for Distance Transform and M-estimators
 /// ## See also
-/// distanceTransform, fitLine
+/// `distance_transform`, `fit_line`
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum DistanceTypes {
@@ -1901,7 +1901,7 @@ opencv_type_enum! { crate::imgproc::ThresholdTypes }
 
 /// \brief Specify the polar mapping mode
 /// ## See also
-/// warpPolar
+/// `warp_polar`
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum WarpPolarMode {
@@ -1936,12 +1936,44 @@ pub fn canny_derivative(dx: &dyn core::ToInputArray, dy: &dyn core::ToInputArray
 	input_array_arg!(dy);
 	output_array_arg!(edges);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_Canny_const__InputArrayR_const__InputArrayR_const__OutputArrayR_double_double_bool(dx.as_raw__InputArray(), dy.as_raw__InputArray(), edges.as_raw__OutputArray(), threshold1, threshold2, l2gradient, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_Canny_const__InputArrayR_const__InputArrayR_const__OutputArrayR_double_double_bool(dx.as_raw__InputArray(), dy.as_raw__InputArray(), edges.as_raw__OutputArray(), threshold1, threshold2, l2gradient, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `canny_derivative()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CannyDerivativeBuilder<'a> {
+	dx: &'a dyn core::ToInputArray,
+	dy: &'a dyn core::ToInputArray,
+	edges: &'a mut dyn core::ToOutputArray,
+	threshold1: f64,
+	threshold2: f64,
+	l2gradient: bool,
+}
+
+impl<'a> CannyDerivativeBuilder<'a> {
+	#[inline]
+	pub fn new(dx: &'a dyn core::ToInputArray, dy: &'a dyn core::ToInputArray, edges: &'a mut dyn core::ToOutputArray, threshold1: f64, threshold2: f64) -> Self {
+		Self { dx, dy, edges, threshold1, threshold2, l2gradient: false }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_l2gradient(mut self, l2gradient: bool) -> Self {
+		self.l2gradient = l2gradient;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		canny_derivative(self.dx, self.dy, self.edges, self.threshold1, self.threshold2, self.l2gradient)
+	}
+}
+
 /// Finds edges in an image using the Canny algorithm [Canny86](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_Canny86) .
 /// 
 /// The function finds edges in the input image and marks them in the output map edges using the
@@ -1968,12 +2000,51 @@ pub fn canny(image: &dyn core::ToInputArray, edges: &mut dyn core::ToOutputArray
 	input_array_arg!(image);
 	output_array_arg!(edges);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_Canny_const__InputArrayR_const__OutputArrayR_double_double_int_bool(image.as_raw__InputArray(), edges.as_raw__OutputArray(), threshold1, threshold2, aperture_size, l2gradient, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_Canny_const__InputArrayR_const__OutputArrayR_double_double_int_bool(image.as_raw__InputArray(), edges.as_raw__OutputArray(), threshold1, threshold2, aperture_size, l2gradient, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `canny()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CannyBuilder<'a> {
+	image: &'a dyn core::ToInputArray,
+	edges: &'a mut dyn core::ToOutputArray,
+	threshold1: f64,
+	threshold2: f64,
+	aperture_size: i32,
+	l2gradient: bool,
+}
+
+impl<'a> CannyBuilder<'a> {
+	#[inline]
+	pub fn new(image: &'a dyn core::ToInputArray, edges: &'a mut dyn core::ToOutputArray, threshold1: f64, threshold2: f64) -> Self {
+		Self { image, edges, threshold1, threshold2, aperture_size: 3, l2gradient: false }
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_aperture_size(mut self, aperture_size: i32) -> Self {
+		self.aperture_size = aperture_size;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_l2gradient(mut self, l2gradient: bool) -> Self {
+		self.l2gradient = l2gradient;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		canny(self.image, self.edges, self.threshold1, self.threshold2, self.aperture_size, self.l2gradient)
+	}
+}
+
 /// Computes the "minimal work" distance between two weighted point configurations.
 /// 
 /// The function computes the earth mover distance and/or a lower boundary of the distance between the
@@ -2020,12 +2091,55 @@ pub fn emd(signature1: &dyn core::ToInputArray, signature2: &dyn core::ToInputAr
 	input_array_arg!(cost);
 	output_array_arg!(flow);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_EMD_const__InputArrayR_const__InputArrayR_int_const__InputArrayR_floatX_const__OutputArrayR(signature1.as_raw__InputArray(), signature2.as_raw__InputArray(), dist_type, cost.as_raw__InputArray(), lower_bound, flow.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_EMD_const__InputArrayR_const__InputArrayR_int_const__InputArrayR_floatX_const__OutputArrayR(signature1.as_raw__InputArray(), signature2.as_raw__InputArray(), dist_type, cost.as_raw__InputArray(), lower_bound, flow.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `emd()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct EmdBuilder<'a> {
+	signature1: &'a dyn core::ToInputArray,
+	signature2: &'a dyn core::ToInputArray,
+	dist_type: i32,
+	cost: Option<&'a dyn core::ToInputArray>,
+	lower_bound: &'a mut f32,
+	flow: Option<&'a mut dyn core::ToOutputArray>,
+}
+
+impl<'a> EmdBuilder<'a> {
+	#[inline]
+	pub fn new(signature1: &'a dyn core::ToInputArray, signature2: &'a dyn core::ToInputArray, dist_type: i32, lower_bound: &'a mut f32) -> Self {
+		Self { signature1, signature2, dist_type, cost: None, lower_bound, flow: None }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_cost(mut self, cost: &'a dyn core::ToInputArray) -> Self {
+		self.cost = Some(cost);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_flow(mut self, flow: &'a mut dyn core::ToOutputArray) -> Self {
+		self.flow = Some(flow);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<f32> {
+		let cost_no_array = core::no_array();
+		let cost: &dyn core::ToInputArray = match self.cost { Some(cost) => cost, None => &cost_no_array };
+		let mut flow_no_array = core::no_array();
+		let flow: &mut dyn core::ToOutputArray = match self.flow { Some(flow) => flow, None => &mut flow_no_array };
+		emd(self.signature1, self.signature2, self.dist_type, cost, self.lower_bound, flow)
+	}
+}
+
 /// Blurs an image using a Gaussian filter.
 /// 
 /// The function convolves the source image with the specified Gaussian kernel. In-place filtering is
@@ -2045,7 +2159,7 @@ pub fn emd(signature1: &dyn core::ToInputArray, signature2: &dyn core::ToInputAr
 /// sigmaX, and sigmaY.
 /// * borderType: pixel extrapolation method, see #BorderTypes. #BORDER_WRAP is not supported.
 /// ## See also
-/// sepFilter2D, filter2D, blur, boxFilter, bilateralFilter, medianBlur
+/// [sep_filter_2d](crate::imgproc::sep_filter_2d), [filter_2d](crate::imgproc::filter_2d), [blur](crate::imgproc::blur), [box_filter](crate::imgproc::box_filter), [bilateral_filter](crate::imgproc::bilateral_filter), [median_blur](crate::imgproc::median_blur)
 /// 
 /// ## C++ default parameters
 /// * sigma_y: 0
@@ -2055,12 +2169,44 @@ pub fn gaussian_blur(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputA
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_GaussianBlur_const__InputArrayR_const__OutputArrayR_Size_double_double_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ksize.opencv_as_extern(), sigma_x, sigma_y, border_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_GaussianBlur_const__InputArrayR_const__OutputArrayR_Size_double_double_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ksize.opencv_as_extern(), sigma_x, sigma_y, border_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `gaussian_blur()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct GaussianBlurBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	ksize: core::Size,
+	sigma_x: f64,
+	sigma_y: f64,
+	border_type: i32,
+}
+
+impl<'a> GaussianBlurBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, ksize: core::Size, sigma_x: f64, border_type: i32) -> Self {
+		Self { src, dst, ksize, sigma_x, sigma_y: 0., border_type }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_sigma_y(mut self, sigma_y: f64) -> Self {
+		self.sigma_y = sigma_y;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		gaussian_blur(self.src, self.dst, self.ksize, self.sigma_x, self.sigma_y, self.border_type)
+	}
+}
+
 /// Finds circles in a grayscale image using the Hough transform.
 /// 
 /// The function finds circles in a grayscale image using a modification of the Hough transform.
@@ -2099,12 +2245,12 @@ pub fn gaussian_blur(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputA
 /// returned first. In the case of #HOUGH_GRADIENT_ALT algorithm, this is the circle "perfectness" measure.
 /// The closer it to 1, the better shaped circles algorithm selects. In most cases 0.9 should be fine.
 /// If you want get better detection of small circles, you may decrease it to 0.85, 0.8 or even less.
-/// But then also try to limit the search range [minRadius, maxRadius] to avoid many false circles.
+/// But then also try to limit the search range \[minRadius, maxRadius\] to avoid many false circles.
 /// * minRadius: Minimum circle radius.
 /// * maxRadius: Maximum circle radius. If <= 0, uses the maximum image dimension. If < 0, #HOUGH_GRADIENT returns
 /// centers without finding the radius. #HOUGH_GRADIENT_ALT always computes circle radiuses.
 /// ## See also
-/// fitEllipse, minEnclosingCircle
+/// [fit_ellipse](crate::imgproc::fit_ellipse), [min_enclosing_circle](crate::imgproc::min_enclosing_circle)
 /// 
 /// ## C++ default parameters
 /// * param1: 100
@@ -2116,12 +2262,68 @@ pub fn hough_circles(image: &dyn core::ToInputArray, circles: &mut dyn core::ToO
 	input_array_arg!(image);
 	output_array_arg!(circles);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_HoughCircles_const__InputArrayR_const__OutputArrayR_int_double_double_double_double_int_int(image.as_raw__InputArray(), circles.as_raw__OutputArray(), method, dp, min_dist, param1, param2, min_radius, max_radius, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_HoughCircles_const__InputArrayR_const__OutputArrayR_int_double_double_double_double_int_int(image.as_raw__InputArray(), circles.as_raw__OutputArray(), method, dp, min_dist, param1, param2, min_radius, max_radius, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `hough_circles()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct HoughCirclesBuilder<'a> {
+	image: &'a dyn core::ToInputArray,
+	circles: &'a mut dyn core::ToOutputArray,
+	method: i32,
+	dp: f64,
+	min_dist: f64,
+	param1: f64,
+	param2: f64,
+	min_radius: i32,
+	max_radius: i32,
+}
+
+impl<'a> HoughCirclesBuilder<'a> {
+	#[inline]
+	pub fn new(image: &'a dyn core::ToInputArray, circles: &'a mut dyn core::ToOutputArray, method: i32, dp: f64, min_dist: f64) -> Self {
+		Self { image, circles, method, dp, min_dist, param1: 100., param2: 100., min_radius: 0, max_radius: 0 }
+	}
+	
+	/// C++ default: `100`
+	#[inline]
+	pub fn with_param1(mut self, param1: f64) -> Self {
+		self.param1 = param1;
+		self
+	}
+	
+	/// C++ default: `100`
+	#[inline]
+	pub fn with_param2(mut self, param2: f64) -> Self {
+		self.param2 = param2;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_min_radius(mut self, min_radius: i32) -> Self {
+		self.min_radius = min_radius;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_max_radius(mut self, max_radius: i32) -> Self {
+		self.max_radius = max_radius;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		hough_circles(self.image, self.circles, self.method, self.dp, self.min_dist, self.param1, self.param2, self.min_radius, self.max_radius)
+	}
+}
+
 /// Finds line segments in a binary image using the probabilistic Hough transform.
 /// 
 /// The function implements the probabilistic Hough transform algorithm for line detection, described
@@ -2149,7 +2351,7 @@ pub fn hough_circles(image: &dyn core::ToInputArray, circles: &mut dyn core::ToO
 /// * minLineLength: Minimum line length. Line segments shorter than that are rejected.
 /// * maxLineGap: Maximum allowed gap between points on the same line to link them.
 /// ## See also
-/// LineSegmentDetector
+/// [LineSegmentDetector](crate::imgproc::LineSegmentDetector)
 /// 
 /// ## C++ default parameters
 /// * min_line_length: 0
@@ -2159,12 +2361,52 @@ pub fn hough_lines_p(image: &dyn core::ToInputArray, lines: &mut dyn core::ToOut
 	input_array_arg!(image);
 	output_array_arg!(lines);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_HoughLinesP_const__InputArrayR_const__OutputArrayR_double_double_int_double_double(image.as_raw__InputArray(), lines.as_raw__OutputArray(), rho, theta, threshold, min_line_length, max_line_gap, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_HoughLinesP_const__InputArrayR_const__OutputArrayR_double_double_int_double_double(image.as_raw__InputArray(), lines.as_raw__OutputArray(), rho, theta, threshold, min_line_length, max_line_gap, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `hough_lines_p()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct HoughLinesPBuilder<'a> {
+	image: &'a dyn core::ToInputArray,
+	lines: &'a mut dyn core::ToOutputArray,
+	rho: f64,
+	theta: f64,
+	threshold: i32,
+	min_line_length: f64,
+	max_line_gap: f64,
+}
+
+impl<'a> HoughLinesPBuilder<'a> {
+	#[inline]
+	pub fn new(image: &'a dyn core::ToInputArray, lines: &'a mut dyn core::ToOutputArray, rho: f64, theta: f64, threshold: i32) -> Self {
+		Self { image, lines, rho, theta, threshold, min_line_length: 0., max_line_gap: 0. }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_min_line_length(mut self, min_line_length: f64) -> Self {
+		self.min_line_length = min_line_length;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_max_line_gap(mut self, max_line_gap: f64) -> Self {
+		self.max_line_gap = max_line_gap;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		hough_lines_p(self.image, self.lines, self.rho, self.theta, self.threshold, self.min_line_length, self.max_line_gap)
+	}
+}
+
 /// Finds lines in a set of points using the standard Hough transform.
 /// 
 /// The function finds lines in a set of points using a modification of the Hough transform.
@@ -2187,7 +2429,7 @@ pub fn hough_lines_point_set(point: &dyn core::ToInputArray, lines: &mut dyn cor
 	input_array_arg!(point);
 	output_array_arg!(lines);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_HoughLinesPointSet_const__InputArrayR_const__OutputArrayR_int_int_double_double_double_double_double_double(point.as_raw__InputArray(), lines.as_raw__OutputArray(), lines_max, threshold, min_rho, max_rho, rho_step, min_theta, max_theta, theta_step, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_HoughLinesPointSet_const__InputArrayR_const__OutputArrayR_int_int_double_double_double_double_double_double(point.as_raw__InputArray(), lines.as_raw__OutputArray(), lines_max, threshold, min_rho, max_rho, rho_step, min_theta, max_theta, theta_step, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -2230,12 +2472,61 @@ pub fn hough_lines(image: &dyn core::ToInputArray, lines: &mut dyn core::ToOutpu
 	input_array_arg!(image);
 	output_array_arg!(lines);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_HoughLines_const__InputArrayR_const__OutputArrayR_double_double_int_double_double_double_double(image.as_raw__InputArray(), lines.as_raw__OutputArray(), rho, theta, threshold, srn, stn, min_theta, max_theta, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_HoughLines_const__InputArrayR_const__OutputArrayR_double_double_int_double_double_double_double(image.as_raw__InputArray(), lines.as_raw__OutputArray(), rho, theta, threshold, srn, stn, min_theta, max_theta, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `hough_lines()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct HoughLinesBuilder<'a> {
+	image: &'a dyn core::ToInputArray,
+	lines: &'a mut dyn core::ToOutputArray,
+	rho: f64,
+	theta: f64,
+	threshold: i32,
+	srn: f64,
+	stn: f64,
+	min_theta: f64,
+	max_theta: f64,
+}
+
+impl<'a> HoughLinesBuilder<'a> {
+	#[inline]
+	pub fn new(image: &'a dyn core::ToInputArray, lines: &'a mut dyn core::ToOutputArray, rho: f64, theta: f64, threshold: i32, max_theta: f64) -> Self {
+		Self { image, lines, rho, theta, threshold, srn: 0., stn: 0., min_theta: 0., max_theta }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_srn(mut self, srn: f64) -> Self {
+		self.srn = srn;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_stn(mut self, stn: f64) -> Self {
+		self.stn = stn;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_min_theta(mut self, min_theta: f64) -> Self {
+		self.min_theta = min_theta;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		hough_lines(self.image, self.lines, self.rho, self.theta, self.threshold, self.srn, self.stn, self.min_theta, self.max_theta)
+	}
+}
+
 /// Calculates seven Hu invariants.
 /// 
 /// The function calculates seven Hu invariants (introduced in [Hu62](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_Hu62); see also
@@ -2254,14 +2545,14 @@ pub fn hough_lines(image: &dyn core::ToInputArray, lines: &mut dyn core::ToOutpu
 /// * moments: Input moments computed with moments .
 /// * hu: Output Hu invariants.
 /// ## See also
-/// matchShapes
+/// [match_shapes](crate::imgproc::match_shapes)
 /// 
 /// ## Overloaded parameters
 #[inline]
 pub fn hu_moments_1(m: core::Moments, hu: &mut dyn core::ToOutputArray) -> Result<()> {
 	output_array_arg!(hu);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_HuMoments_const_MomentsR_const__OutputArrayR(&m, hu.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_HuMoments_const_MomentsR_const__OutputArrayR(&m, hu.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -2285,11 +2576,11 @@ pub fn hu_moments_1(m: core::Moments, hu: &mut dyn core::ToOutputArray) -> Resul
 /// * moments: Input moments computed with moments .
 /// * hu: Output Hu invariants.
 /// ## See also
-/// matchShapes
+/// [match_shapes](crate::imgproc::match_shapes)
 #[inline]
 pub fn hu_moments(moments: core::Moments, hu: &mut [f64; 7]) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_HuMoments_const_MomentsR_doubleXX(&moments, hu, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_HuMoments_const_MomentsR_doubleXX(&moments, hu, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -2318,7 +2609,7 @@ pub fn hu_moments(moments: core::Moments, hu: &mut [f64; 7]) -> Result<()> {
 /// * delta: Optional delta value that is added to the results prior to storing them in dst .
 /// * borderType: Pixel extrapolation method, see #BorderTypes. #BORDER_WRAP is not supported.
 /// ## See also
-/// Sobel, Scharr
+/// [sobel](crate::imgproc::sobel), [scharr](crate::imgproc::scharr)
 /// 
 /// ## C++ default parameters
 /// * ksize: 1
@@ -2330,12 +2621,59 @@ pub fn laplacian(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_Laplacian_const__InputArrayR_const__OutputArrayR_int_int_double_double_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ddepth, ksize, scale, delta, border_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_Laplacian_const__InputArrayR_const__OutputArrayR_int_int_double_double_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ddepth, ksize, scale, delta, border_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `laplacian()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct LaplacianBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	ddepth: i32,
+	ksize: i32,
+	scale: f64,
+	delta: f64,
+	border_type: i32,
+}
+
+impl<'a> LaplacianBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, ddepth: i32, border_type: i32) -> Self {
+		Self { src, dst, ddepth, ksize: 1, scale: 1., delta: 0., border_type }
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_ksize(mut self, ksize: i32) -> Self {
+		self.ksize = ksize;
+		self
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_scale(mut self, scale: f64) -> Self {
+		self.scale = scale;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_delta(mut self, delta: f64) -> Self {
+		self.delta = delta;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		laplacian(self.src, self.dst, self.ddepth, self.ksize, self.scale, self.delta, self.border_type)
+	}
+}
+
 /// Calculates the first x- or y- image derivative using Scharr operator.
 /// 
 /// The function computes the first x- or y- spatial image derivative using the Scharr operator. The
@@ -2350,7 +2688,7 @@ pub fn laplacian(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 /// ## Parameters
 /// * src: input image.
 /// * dst: output image of the same size and the same number of channels as src.
-/// * ddepth: output image depth, see @ref filter_depths "combinations"
+/// * ddepth: output image depth, see combinations
 /// * dx: order of the derivative x.
 /// * dy: order of the derivative y.
 /// * scale: optional scale factor for the computed derivative values; by default, no scaling is
@@ -2358,7 +2696,7 @@ pub fn laplacian(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 /// * delta: optional delta value that is added to the results prior to storing them in dst.
 /// * borderType: pixel extrapolation method, see #BorderTypes. #BORDER_WRAP is not supported.
 /// ## See also
-/// cartToPolar
+/// [cart_to_polar](crate::core::cart_to_polar)
 /// 
 /// ## C++ default parameters
 /// * scale: 1
@@ -2369,12 +2707,53 @@ pub fn scharr(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, d
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_Scharr_const__InputArrayR_const__OutputArrayR_int_int_int_double_double_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ddepth, dx, dy, scale, delta, border_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_Scharr_const__InputArrayR_const__OutputArrayR_int_int_int_double_double_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ddepth, dx, dy, scale, delta, border_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `scharr()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct ScharrBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	ddepth: i32,
+	dx: i32,
+	dy: i32,
+	scale: f64,
+	delta: f64,
+	border_type: i32,
+}
+
+impl<'a> ScharrBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, ddepth: i32, dx: i32, dy: i32, border_type: i32) -> Self {
+		Self { src, dst, ddepth, dx, dy, scale: 1., delta: 0., border_type }
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_scale(mut self, scale: f64) -> Self {
+		self.scale = scale;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_delta(mut self, delta: f64) -> Self {
+		self.delta = delta;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		scharr(self.src, self.dst, self.ddepth, self.dx, self.dy, self.scale, self.delta, self.border_type)
+	}
+}
+
 /// Calculates the first, second, third, or mixed image derivatives using an extended Sobel operator.
 /// 
 /// In all cases except one, the ![inline formula](https://latex.codecogs.com/png.latex?%5Ctexttt%7Bksize%7D%20%5Ctimes%20%5Ctexttt%7Bksize%7D) separable kernel is used to
@@ -2407,7 +2786,7 @@ pub fn scharr(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, d
 /// ## Parameters
 /// * src: input image.
 /// * dst: output image of the same size and the same number of channels as src .
-/// * ddepth: output image depth, see @ref filter_depths "combinations"; in the case of
+/// * ddepth: output image depth, see combinations; in the case of
 ///    8-bit input images it will result in truncated derivatives.
 /// * dx: order of the derivative x.
 /// * dy: order of the derivative y.
@@ -2417,7 +2796,7 @@ pub fn scharr(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, d
 /// * delta: optional delta value that is added to the results prior to storing them in dst.
 /// * borderType: pixel extrapolation method, see #BorderTypes. #BORDER_WRAP is not supported.
 /// ## See also
-/// Scharr, Laplacian, sepFilter2D, filter2D, GaussianBlur, cartToPolar
+/// [scharr](crate::imgproc::scharr), [laplacian](crate::imgproc::laplacian), [sep_filter_2d](crate::imgproc::sep_filter_2d), [filter_2d](crate::imgproc::filter_2d), [gaussian_blur](crate::imgproc::gaussian_blur), [cart_to_polar](crate::core::cart_to_polar)
 /// 
 /// ## C++ default parameters
 /// * ksize: 3
@@ -2429,12 +2808,61 @@ pub fn sobel(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, dd
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_Sobel_const__InputArrayR_const__OutputArrayR_int_int_int_int_double_double_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ddepth, dx, dy, ksize, scale, delta, border_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_Sobel_const__InputArrayR_const__OutputArrayR_int_int_int_int_double_double_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ddepth, dx, dy, ksize, scale, delta, border_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `sobel()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct SobelBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	ddepth: i32,
+	dx: i32,
+	dy: i32,
+	ksize: i32,
+	scale: f64,
+	delta: f64,
+	border_type: i32,
+}
+
+impl<'a> SobelBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, ddepth: i32, dx: i32, dy: i32, border_type: i32) -> Self {
+		Self { src, dst, ddepth, dx, dy, ksize: 3, scale: 1., delta: 0., border_type }
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_ksize(mut self, ksize: i32) -> Self {
+		self.ksize = ksize;
+		self
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_scale(mut self, scale: f64) -> Self {
+		self.scale = scale;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_delta(mut self, delta: f64) -> Self {
+		self.delta = delta;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		sobel(self.src, self.dst, self.ddepth, self.dx, self.dy, self.ksize, self.scale, self.delta, self.border_type)
+	}
+}
+
 /// Adds the per-element product of two input images to the accumulator image.
 /// 
 /// The function adds the product of two images or their selected regions to the accumulator dst :
@@ -2450,7 +2878,7 @@ pub fn sobel(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, dd
 /// floating-point.
 /// * mask: Optional operation mask.
 /// ## See also
-/// accumulate, accumulateSquare, accumulateWeighted
+/// [accumulate](crate::imgproc::accumulate), [accumulate_square](crate::imgproc::accumulate_square), [accumulate_weighted](crate::imgproc::accumulate_weighted)
 /// 
 /// ## C++ default parameters
 /// * mask: noArray()
@@ -2461,7 +2889,7 @@ pub fn accumulate_product(src1: &dyn core::ToInputArray, src2: &dyn core::ToInpu
 	input_output_array_arg!(dst);
 	input_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_accumulateProduct_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputArrayR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__InputOutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_accumulateProduct_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputArrayR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__InputOutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -2482,7 +2910,7 @@ pub fn accumulate_product(src1: &dyn core::ToInputArray, src2: &dyn core::ToInpu
 /// floating-point.
 /// * mask: Optional operation mask.
 /// ## See also
-/// accumulateSquare, accumulateProduct, accumulateWeighted
+/// [accumulate_square](crate::imgproc::accumulate_square), [accumulate_product](crate::imgproc::accumulate_product), [accumulate_weighted](crate::imgproc::accumulate_weighted)
 /// 
 /// ## C++ default parameters
 /// * mask: noArray()
@@ -2492,7 +2920,7 @@ pub fn accumulate_square(src: &dyn core::ToInputArray, dst: &mut dyn core::ToInp
 	input_output_array_arg!(dst);
 	input_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_accumulateSquare_const__InputArrayR_const__InputOutputArrayR_const__InputArrayR(src.as_raw__InputArray(), dst.as_raw__InputOutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_accumulateSquare_const__InputArrayR_const__InputOutputArrayR_const__InputArrayR(src.as_raw__InputArray(), dst.as_raw__InputOutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -2515,7 +2943,7 @@ pub fn accumulate_square(src: &dyn core::ToInputArray, dst: &mut dyn core::ToInp
 /// * alpha: Weight of the input image.
 /// * mask: Optional operation mask.
 /// ## See also
-/// accumulate, accumulateSquare, accumulateProduct
+/// [accumulate](crate::imgproc::accumulate), [accumulate_square](crate::imgproc::accumulate_square), [accumulate_product](crate::imgproc::accumulate_product)
 /// 
 /// ## C++ default parameters
 /// * mask: noArray()
@@ -2525,7 +2953,7 @@ pub fn accumulate_weighted(src: &dyn core::ToInputArray, dst: &mut dyn core::ToI
 	input_output_array_arg!(dst);
 	input_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_accumulateWeighted_const__InputArrayR_const__InputOutputArrayR_double_const__InputArrayR(src.as_raw__InputArray(), dst.as_raw__InputOutputArray(), alpha, mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_accumulateWeighted_const__InputArrayR_const__InputOutputArrayR_double_const__InputArrayR(src.as_raw__InputArray(), dst.as_raw__InputOutputArray(), alpha, mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -2547,7 +2975,7 @@ pub fn accumulate_weighted(src: &dyn core::ToInputArray, dst: &mut dyn core::ToI
 /// * dst: %Accumulator image with the same number of channels as input image, and a depth of CV_32F or CV_64F.
 /// * mask: Optional operation mask.
 /// ## See also
-/// accumulateSquare, accumulateProduct, accumulateWeighted
+/// [accumulate_square](crate::imgproc::accumulate_square), [accumulate_product](crate::imgproc::accumulate_product), [accumulate_weighted](crate::imgproc::accumulate_weighted)
 /// 
 /// ## C++ default parameters
 /// * mask: noArray()
@@ -2557,7 +2985,7 @@ pub fn accumulate(src: &dyn core::ToInputArray, dst: &mut dyn core::ToInputOutpu
 	input_output_array_arg!(dst);
 	input_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_accumulate_const__InputArrayR_const__InputOutputArrayR_const__InputArrayR(src.as_raw__InputArray(), dst.as_raw__InputOutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_accumulate_const__InputArrayR_const__InputOutputArrayR_const__InputArrayR(src.as_raw__InputArray(), dst.as_raw__InputOutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -2587,13 +3015,13 @@ pub fn accumulate(src: &dyn core::ToInputArray, dst: &mut dyn core::ToInputOutpu
 /// * C: Constant subtracted from the mean or weighted mean (see the details below). Normally, it
 /// is positive but may be zero or negative as well.
 /// ## See also
-/// threshold, blur, GaussianBlur
+/// [threshold](crate::imgproc::threshold), [blur](crate::imgproc::blur), [gaussian_blur](crate::imgproc::gaussian_blur)
 #[inline]
 pub fn adaptive_threshold(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, max_value: f64, adaptive_method: i32, threshold_type: i32, block_size: i32, c: f64) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_adaptiveThreshold_const__InputArrayR_const__OutputArrayR_double_int_int_int_double(src.as_raw__InputArray(), dst.as_raw__OutputArray(), max_value, adaptive_method, threshold_type, block_size, c, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_adaptiveThreshold_const__InputArrayR_const__OutputArrayR_double_int_int_int_double(src.as_raw__InputArray(), dst.as_raw__OutputArray(), max_value, adaptive_method, threshold_type, block_size, c, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -2611,7 +3039,7 @@ pub fn apply_color_map_user(src: &dyn core::ToInputArray, dst: &mut dyn core::To
 	output_array_arg!(dst);
 	input_array_arg!(user_color);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_applyColorMap_const__InputArrayR_const__OutputArrayR_const__InputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), user_color.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_applyColorMap_const__InputArrayR_const__OutputArrayR_const__InputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), user_color.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -2628,7 +3056,7 @@ pub fn apply_color_map(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutpu
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_applyColorMap_const__InputArrayR_const__OutputArrayR_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), colormap, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_applyColorMap_const__InputArrayR_const__OutputArrayR_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), colormap, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -2652,7 +3080,7 @@ pub fn approx_poly_dp(curve: &dyn core::ToInputArray, approx_curve: &mut dyn cor
 	input_array_arg!(curve);
 	output_array_arg!(approx_curve);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_approxPolyDP_const__InputArrayR_const__OutputArrayR_double_bool(curve.as_raw__InputArray(), approx_curve.as_raw__OutputArray(), epsilon, closed, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_approxPolyDP_const__InputArrayR_const__OutputArrayR_double_bool(curve.as_raw__InputArray(), approx_curve.as_raw__OutputArray(), epsilon, closed, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -2669,7 +3097,7 @@ pub fn approx_poly_dp(curve: &dyn core::ToInputArray, approx_curve: &mut dyn cor
 pub fn arc_length(curve: &dyn core::ToInputArray, closed: bool) -> Result<f64> {
 	input_array_arg!(curve);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_arcLength_const__InputArrayR_bool(curve.as_raw__InputArray(), closed, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_arcLength_const__InputArrayR_bool(curve.as_raw__InputArray(), closed, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -2698,12 +3126,67 @@ pub fn arc_length(curve: &dyn core::ToInputArray, closed: bool) -> Result<f64> {
 pub fn arrowed_line(img: &mut dyn core::ToInputOutputArray, pt1: core::Point, pt2: core::Point, color: core::Scalar, thickness: i32, line_type: i32, shift: i32, tip_length: f64) -> Result<()> {
 	input_output_array_arg!(img);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_arrowedLine_const__InputOutputArrayR_Point_Point_const_ScalarR_int_int_int_double(img.as_raw__InputOutputArray(), pt1.opencv_as_extern(), pt2.opencv_as_extern(), &color, thickness, line_type, shift, tip_length, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_arrowedLine_const__InputOutputArrayR_Point_Point_const_ScalarR_int_int_int_double(img.as_raw__InputOutputArray(), pt1.opencv_as_extern(), pt2.opencv_as_extern(), &color, thickness, line_type, shift, tip_length, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `arrowed_line()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct ArrowedLineBuilder<'a> {
+	img: &'a mut dyn core::ToInputOutputArray,
+	pt1: core::Point,
+	pt2: core::Point,
+	color: core::Scalar,
+	thickness: i32,
+	line_type: i32,
+	shift: i32,
+	tip_length: f64,
+}
+
+impl<'a> ArrowedLineBuilder<'a> {
+	#[inline]
+	pub fn new(img: &'a mut dyn core::ToInputOutputArray, pt1: core::Point, pt2: core::Point, color: core::Scalar) -> Self {
+		Self { img, pt1, pt2, color, thickness: 1, line_type: 8, shift: 0, tip_length: 0.1 }
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_thickness(mut self, thickness: i32) -> Self {
+		self.thickness = thickness;
+		self
+	}
+	
+	/// C++ default: `8`
+	#[inline]
+	pub fn with_line_type(mut self, line_type: i32) -> Self {
+		self.line_type = line_type;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_shift(mut self, shift: i32) -> Self {
+		self.shift = shift;
+		self
+	}
+	
+	/// C++ default: `0.1`
+	#[inline]
+	pub fn with_tip_length(mut self, tip_length: f64) -> Self {
+		self.tip_length = tip_length;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		arrowed_line(self.img, self.pt1, self.pt2, self.color, self.thickness, self.line_type, self.shift, self.tip_length)
+	}
+}
+
 /// Applies the bilateral filter to an image.
 /// 
 /// The function applies bilateral filtering to the input image, as described in
@@ -2740,7 +3223,7 @@ pub fn bilateral_filter(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutp
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_bilateralFilter_const__InputArrayR_const__OutputArrayR_int_double_double_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), d, sigma_color, sigma_space, border_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_bilateralFilter_const__InputArrayR_const__OutputArrayR_int_double_double_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), d, sigma_color, sigma_space, border_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -2762,7 +3245,7 @@ pub fn blend_linear(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray
 	input_array_arg!(weights2);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_blendLinear_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), weights1.as_raw__InputArray(), weights2.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_blendLinear_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), weights1.as_raw__InputArray(), weights2.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -2786,7 +3269,7 @@ pub fn blend_linear(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray
 /// center.
 /// * borderType: border mode used to extrapolate pixels outside of the image, see #BorderTypes. #BORDER_WRAP is not supported.
 /// ## See also
-/// boxFilter, bilateralFilter, GaussianBlur, medianBlur
+/// [box_filter](crate::imgproc::box_filter), [bilateral_filter](crate::imgproc::bilateral_filter), [gaussian_blur](crate::imgproc::gaussian_blur), [median_blur](crate::imgproc::median_blur)
 /// 
 /// ## C++ default parameters
 /// * anchor: Point(-1,-1)
@@ -2796,7 +3279,7 @@ pub fn blur(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, ksi
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_blur_const__InputArrayR_const__OutputArrayR_Size_Point_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ksize.opencv_as_extern(), anchor.opencv_as_extern(), border_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_blur_const__InputArrayR_const__OutputArrayR_Size_Point_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ksize.opencv_as_extern(), anchor.opencv_as_extern(), border_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -2813,7 +3296,7 @@ pub fn blur(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, ksi
 pub fn bounding_rect(array: &dyn core::ToInputArray) -> Result<core::Rect> {
 	input_array_arg!(array);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_boundingRect_const__InputArrayR(array.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_boundingRect_const__InputArrayR(array.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -2843,7 +3326,7 @@ pub fn bounding_rect(array: &dyn core::ToInputArray) -> Result<core::Rect> {
 /// * normalize: flag, specifying whether the kernel is normalized by its area or not.
 /// * borderType: border mode used to extrapolate pixels outside of the image, see #BorderTypes. #BORDER_WRAP is not supported.
 /// ## See also
-/// blur, bilateralFilter, GaussianBlur, medianBlur, integral
+/// [blur](crate::imgproc::blur), [bilateral_filter](crate::imgproc::bilateral_filter), [gaussian_blur](crate::imgproc::gaussian_blur), [median_blur](crate::imgproc::median_blur), [integral](crate::imgproc::integral)
 /// 
 /// ## C++ default parameters
 /// * anchor: Point(-1,-1)
@@ -2854,17 +3337,57 @@ pub fn box_filter(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArra
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_boxFilter_const__InputArrayR_const__OutputArrayR_int_Size_Point_bool_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ddepth, ksize.opencv_as_extern(), anchor.opencv_as_extern(), normalize, border_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_boxFilter_const__InputArrayR_const__OutputArrayR_int_Size_Point_bool_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ddepth, ksize.opencv_as_extern(), anchor.opencv_as_extern(), normalize, border_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `box_filter()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct BoxFilterBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	ddepth: i32,
+	ksize: core::Size,
+	anchor: core::Point,
+	normalize: bool,
+	border_type: i32,
+}
+
+impl<'a> BoxFilterBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, ddepth: i32, ksize: core::Size, border_type: i32) -> Self {
+		Self { src, dst, ddepth, ksize, anchor: core::Point::new(-1, -1), normalize: true, border_type }
+	}
+	
+	/// C++ default: `Point(-1,-1)`
+	#[inline]
+	pub fn with_anchor(mut self, anchor: core::Point) -> Self {
+		self.anchor = anchor;
+		self
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_normalize(mut self, normalize: bool) -> Self {
+		self.normalize = normalize;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		box_filter(self.src, self.dst, self.ddepth, self.ksize, self.anchor, self.normalize, self.border_type)
+	}
+}
+
 /// Finds the four vertices of a rotated rect. Useful to draw the rotated rectangle.
 /// 
 /// The function finds the four vertices of a rotated rectangle. This function is useful to draw the
 /// rectangle. In C++, instead of using this function, you can directly use RotatedRect::points method. Please
-/// visit the @ref tutorial_bounding_rotated_ellipses "tutorial on Creating Bounding rotated boxes and ellipses for contours" for more information.
+/// visit the tutorial on Creating Bounding rotated boxes and ellipses for contours for more information.
 /// 
 /// ## Parameters
 /// * box: The input rotated rectangle. It may be the output of
@@ -2873,7 +3396,7 @@ pub fn box_filter(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArra
 pub fn box_points(mut box_: core::RotatedRect, points: &mut dyn core::ToOutputArray) -> Result<()> {
 	output_array_arg!(points);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_boxPoints_RotatedRect_const__OutputArrayR(box_.as_raw_mut_RotatedRect(), points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_boxPoints_RotatedRect_const__OutputArrayR(box_.as_raw_mut_RotatedRect(), points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -2886,8 +3409,8 @@ pub fn box_points(mut box_: core::RotatedRect, points: &mut dyn core::ToOutputAr
 /// 
 /// ## Parameters
 /// * src: Source image. Check pyrDown for the list of supported types.
-/// * dst: Destination vector of maxlevel+1 images of the same type as src. dst[0] will be the
-/// same as src. dst[1] is the next pyramid layer, a smoothed and down-sized src, and so on.
+/// * dst: Destination vector of maxlevel+1 images of the same type as src. dst\[0\] will be the
+/// same as src. dst\[1\] is the next pyramid layer, a smoothed and down-sized src, and so on.
 /// * maxlevel: 0-based index of the last (the smallest) pyramid layer. It must be non-negative.
 /// * borderType: Pixel extrapolation method, see #BorderTypes (#BORDER_CONSTANT isn't supported)
 /// 
@@ -2898,7 +3421,7 @@ pub fn build_pyramid(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputA
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_buildPyramid_const__InputArrayR_const__OutputArrayR_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), maxlevel, border_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_buildPyramid_const__InputArrayR_const__OutputArrayR_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), maxlevel, border_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -2933,16 +3456,16 @@ pub fn build_pyramid(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputA
 /// * nimages: Number of source images.
 /// * channels: The list of channels used to compute the back projection. The number of channels
 /// must match the histogram dimensionality. The first array channels are numerated from 0 to
-/// images[0].channels()-1 , the second array channels are counted from images[0].channels() to
-/// images[0].channels() + images[1].channels()-1, and so on.
+/// images\[0\].channels()-1 , the second array channels are counted from images\[0\].channels() to
+/// images\[0\].channels() + images\[1\].channels()-1, and so on.
 /// * hist: Input histogram that can be dense or sparse.
 /// * backProject: Destination back projection array that is a single-channel array of the same
-/// size and depth as images[0] .
+/// size and depth as images\[0\] .
 /// * ranges: Array of arrays of the histogram bin boundaries in each dimension. See #calcHist .
 /// * scale: Optional scale factor for the output back projection.
 /// * uniform: Flag indicating whether the histogram is uniform or not (see above).
 /// ## See also
-/// calcHist, compareHist
+/// [calc_hist](crate::imgproc::calc_hist), [compare_hist](crate::imgproc::compare_hist)
 /// 
 /// ## Overloaded parameters
 #[inline]
@@ -2951,7 +3474,7 @@ pub fn calc_back_project(images: &dyn core::ToInputArray, channels: &core::Vecto
 	input_array_arg!(hist);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_calcBackProject_const__InputArrayR_const_vector_int_R_const__InputArrayR_const__OutputArrayR_const_vector_float_R_double(images.as_raw__InputArray(), channels.as_raw_VectorOfi32(), hist.as_raw__InputArray(), dst.as_raw__OutputArray(), ranges.as_raw_VectorOff32(), scale, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_calcBackProject_const__InputArrayR_const_vector_int_R_const__InputArrayR_const__OutputArrayR_const_vector_float_R_double(images.as_raw__InputArray(), channels.as_raw_VectorOfi32(), hist.as_raw__InputArray(), dst.as_raw__OutputArray(), ranges.as_raw_VectorOff32(), scale, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -2969,10 +3492,10 @@ pub fn calc_back_project(images: &dyn core::ToInputArray, channels: &core::Vecto
 /// size. Each of them can have an arbitrary number of channels.
 /// * nimages: Number of source images.
 /// * channels: List of the dims channels used to compute the histogram. The first array channels
-/// are numerated from 0 to images[0].channels()-1 , the second array channels are counted from
-/// images[0].channels() to images[0].channels() + images[1].channels()-1, and so on.
+/// are numerated from 0 to images\[0\].channels()-1 , the second array channels are counted from
+/// images\[0\].channels() to images\[0\].channels() + images\[1\].channels()-1, and so on.
 /// * mask: Optional mask. If the matrix is not empty, it must be an 8-bit array of the same size
-/// as images[i] . The non-zero mask elements mark the array elements counted in the histogram.
+/// as images\[i\] . The non-zero mask elements mark the array elements counted in the histogram.
 /// * hist: Output histogram, which is a dense or sparse dims -dimensional array.
 /// * dims: Histogram dimensionality that must be positive and not greater than CV_MAX_DIMS
 /// (equal to 32 in the current OpenCV version).
@@ -2980,9 +3503,9 @@ pub fn calc_back_project(images: &dyn core::ToInputArray, channels: &core::Vecto
 /// * ranges: Array of the dims arrays of the histogram bin boundaries in each dimension. When the
 /// histogram is uniform ( uniform =true), then for each dimension i it is enough to specify the lower
 /// (inclusive) boundary ![inline formula](https://latex.codecogs.com/png.latex?L%5F0) of the 0-th histogram bin and the upper (exclusive) boundary
-/// ![inline formula](https://latex.codecogs.com/png.latex?U%5F%7B%5Ctexttt%7BhistSize%7D%5Bi%5D%2D1%7D) for the last histogram bin histSize[i]-1 . That is, in case of a
-/// uniform histogram each of ranges[i] is an array of 2 elements. When the histogram is not uniform (
-/// uniform=false ), then each of ranges[i] contains histSize[i]+1 elements:
+/// ![inline formula](https://latex.codecogs.com/png.latex?U%5F%7B%5Ctexttt%7BhistSize%7D%5Bi%5D%2D1%7D) for the last histogram bin histSize\[i\]-1 . That is, in case of a
+/// uniform histogram each of ranges\[i\] is an array of 2 elements. When the histogram is not uniform (
+/// uniform=false ), then each of ranges\[i\] contains histSize\[i\]+1 elements:
 /// ![inline formula](https://latex.codecogs.com/png.latex?L%5F0%2C%20U%5F0%3DL%5F1%2C%20U%5F1%3DL%5F2%2C%20%2E%2E%2E%2C%20U%5F%7B%5Ctexttt%7BhistSize%5Bi%5D%7D%2D2%7D%3DL%5F%7B%5Ctexttt%7BhistSize%5Bi%5D%7D%2D1%7D%2C%20U%5F%7B%5Ctexttt%7BhistSize%5Bi%5D%7D%2D1%7D)
 /// . The array elements, that are not between ![inline formula](https://latex.codecogs.com/png.latex?L%5F0) and ![inline formula](https://latex.codecogs.com/png.latex?U%5F%7B%5Ctexttt%7BhistSize%5Bi%5D%7D%2D1%7D) , are not
 /// counted in the histogram.
@@ -3001,12 +3524,45 @@ pub fn calc_hist(images: &dyn core::ToInputArray, channels: &core::Vector<i32>,
 	input_array_arg!(mask);
 	output_array_arg!(hist);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_calcHist_const__InputArrayR_const_vector_int_R_const__InputArrayR_const__OutputArrayR_const_vector_int_R_const_vector_float_R_bool(images.as_raw__InputArray(), channels.as_raw_VectorOfi32(), mask.as_raw__InputArray(), hist.as_raw__OutputArray(), hist_size.as_raw_VectorOfi32(), ranges.as_raw_VectorOff32(), accumulate, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_calcHist_const__InputArrayR_const_vector_int_R_const__InputArrayR_const__OutputArrayR_const_vector_int_R_const_vector_float_R_bool(images.as_raw__InputArray(), channels.as_raw_VectorOfi32(), mask.as_raw__InputArray(), hist.as_raw__OutputArray(), hist_size.as_raw_VectorOfi32(), ranges.as_raw_VectorOff32(), accumulate, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `calc_hist()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CalcHistBuilder<'a> {
+	images: &'a dyn core::ToInputArray,
+	channels: &'a core::Vector<i32>,
+	mask: &'a dyn core::ToInputArray,
+	hist: &'a mut dyn core::ToOutputArray,
+	hist_size: &'a core::Vector<i32>,
+	ranges: &'a core::Vector<f32>,
+	accumulate: bool,
+}
+
+impl<'a> CalcHistBuilder<'a> {
+	#[inline]
+	pub fn new(images: &'a dyn core::ToInputArray, channels: &'a core::Vector<i32>, mask: &'a dyn core::ToInputArray, hist: &'a mut dyn core::ToOutputArray, hist_size: &'a core::Vector<i32>, ranges: &'a core::Vector<f32>) -> Self {
+		Self { images, channels, mask, hist, hist_size, ranges, accumulate: false }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_accumulate(mut self, accumulate: bool) -> Self {
+		self.accumulate = accumulate;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		calc_hist(self.images, self.channels, self.mask, self.hist, self.hist_size, self.ranges, self.accumulate)
+	}
+}
+
 /// Draws a circle.
 /// 
 /// The function cv::circle draws a simple or filled circle with a given center and radius.
@@ -3028,12 +3584,52 @@ pub fn calc_hist(images: &dyn core::ToInputArray, channels: &core::Vector<i32>,
 pub fn circle(img: &mut dyn core::ToInputOutputArray, center: core::Point, radius: i32, color: core::Scalar, thickness: i32, line_type: i32, shift: i32) -> Result<()> {
 	input_output_array_arg!(img);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_circle_const__InputOutputArrayR_Point_int_const_ScalarR_int_int_int(img.as_raw__InputOutputArray(), center.opencv_as_extern(), radius, &color, thickness, line_type, shift, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_circle_const__InputOutputArrayR_Point_int_const_ScalarR_int_int_int(img.as_raw__InputOutputArray(), center.opencv_as_extern(), radius, &color, thickness, line_type, shift, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `circle()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CircleBuilder<'a> {
+	img: &'a mut dyn core::ToInputOutputArray,
+	center: core::Point,
+	radius: i32,
+	color: core::Scalar,
+	thickness: i32,
+	line_type: i32,
+	shift: i32,
+}
+
+impl<'a> CircleBuilder<'a> {
+	#[inline]
+	pub fn new(img: &'a mut dyn core::ToInputOutputArray, center: core::Point, radius: i32, color: core::Scalar, line_type: i32) -> Self {
+		Self { img, center, radius, color, thickness: 1, line_type, shift: 0 }
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_thickness(mut self, thickness: i32) -> Self {
+		self.thickness = thickness;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_shift(mut self, shift: i32) -> Self {
+		self.shift = shift;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		circle(self.img, self.center, self.radius, self.color, self.thickness, self.line_type, self.shift)
+	}
+}
+
 /// Clips the line against the image rectangle.
 /// 
 /// The function cv::clipLine calculates a part of the line segment that is entirely within the specified
@@ -3052,7 +3648,7 @@ pub fn circle(img: &mut dyn core::ToInputOutputArray, center: core::Point, radiu
 #[inline]
 pub fn clip_line(img_rect: core::Rect, pt1: &mut core::Point, pt2: &mut core::Point) -> Result<bool> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_clipLine_Rect_PointR_PointR(img_rect.opencv_as_extern(), pt1, pt2, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_clipLine_Rect_PointR_PointR(img_rect.opencv_as_extern(), pt1, pt2, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -3076,7 +3672,7 @@ pub fn clip_line(img_rect: core::Rect, pt1: &mut core::Point, pt2: &mut core::Po
 #[inline]
 pub fn clip_line_size_i64(img_size: core::Size2l, pt1: &mut core::Point2l, pt2: &mut core::Point2l) -> Result<bool> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_clipLine_Size2l_Point2lR_Point2lR(img_size.opencv_as_extern(), pt1, pt2, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_clipLine_Size2l_Point2lR_Point2lR(img_size.opencv_as_extern(), pt1, pt2, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -3094,7 +3690,7 @@ pub fn clip_line_size_i64(img_size: core::Size2l, pt1: &mut core::Point2l, pt2:
 #[inline]
 pub fn clip_line_size(img_size: core::Size, pt1: &mut core::Point, pt2: &mut core::Point) -> Result<bool> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_clipLine_Size_PointR_PointR(img_size.opencv_as_extern(), pt1, pt2, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_clipLine_Size_PointR_PointR(img_size.opencv_as_extern(), pt1, pt2, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -3120,7 +3716,7 @@ pub fn clip_line_size(img_size: core::Size, pt1: &mut core::Point, pt2: &mut cor
 #[inline]
 pub fn compare_hist_1(h1: &core::SparseMat, h2: &core::SparseMat, method: i32) -> Result<f64> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_compareHist_const_SparseMatR_const_SparseMatR_int(h1.as_raw_SparseMat(), h2.as_raw_SparseMat(), method, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_compareHist_const_SparseMatR_const_SparseMatR_int(h1.as_raw_SparseMat(), h2.as_raw_SparseMat(), method, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -3146,7 +3742,7 @@ pub fn compare_hist(h1: &dyn core::ToInputArray, h2: &dyn core::ToInputArray, me
 	input_array_arg!(h1);
 	input_array_arg!(h2);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_compareHist_const__InputArrayR_const__InputArrayR_int(h1.as_raw__InputArray(), h2.as_raw__InputArray(), method, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_compareHist_const__InputArrayR_const__InputArrayR_int(h1.as_raw__InputArray(), h2.as_raw__InputArray(), method, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -3154,7 +3750,7 @@ pub fn compare_hist(h1: &dyn core::ToInputArray, h2: &dyn core::ToInputArray, me
 
 /// computes the connected components labeled image of boolean image and also produces a statistics output for each label
 /// 
-/// image with 4 or 8 way connectivity - returns N, the total number of labels [0, N-1] where 0
+/// image with 4 or 8 way connectivity - returns N, the total number of labels \[0, N-1\] where 0
 /// represents the background label. ltype specifies the output label image type, an important
 /// consideration based on the total number of labels or alternatively the total number of pixels in
 /// the source image. ccltype specifies the connected components labeling algorithm to use, currently
@@ -3198,15 +3794,47 @@ pub fn connected_components_with_stats(image: &dyn core::ToInputArray, labels: &
 	output_array_arg!(stats);
 	output_array_arg!(centroids);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_connectedComponentsWithStats_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int_int(image.as_raw__InputArray(), labels.as_raw__OutputArray(), stats.as_raw__OutputArray(), centroids.as_raw__OutputArray(), connectivity, ltype, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_connectedComponentsWithStats_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int_int(image.as_raw__InputArray(), labels.as_raw__OutputArray(), stats.as_raw__OutputArray(), centroids.as_raw__OutputArray(), connectivity, ltype, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `connected_components_with_stats()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct ConnectedComponentsWithStatsBuilder<'a> {
+	image: &'a dyn core::ToInputArray,
+	labels: &'a mut dyn core::ToOutputArray,
+	stats: &'a mut dyn core::ToOutputArray,
+	centroids: &'a mut dyn core::ToOutputArray,
+	connectivity: i32,
+	ltype: i32,
+}
+
+impl<'a> ConnectedComponentsWithStatsBuilder<'a> {
+	#[inline]
+	pub fn new(image: &'a dyn core::ToInputArray, labels: &'a mut dyn core::ToOutputArray, stats: &'a mut dyn core::ToOutputArray, centroids: &'a mut dyn core::ToOutputArray, ltype: i32) -> Self {
+		Self { image, labels, stats, centroids, connectivity: 8, ltype }
+	}
+	
+	/// C++ default: `8`
+	#[inline]
+	pub fn with_connectivity(mut self, connectivity: i32) -> Self {
+		self.connectivity = connectivity;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<i32> {
+		connected_components_with_stats(self.image, self.labels, self.stats, self.centroids, self.connectivity, self.ltype)
+	}
+}
+
 /// computes the connected components labeled image of boolean image and also produces a statistics output for each label
 /// 
-/// image with 4 or 8 way connectivity - returns N, the total number of labels [0, N-1] where 0
+/// image with 4 or 8 way connectivity - returns N, the total number of labels \[0, N-1\] where 0
 /// represents the background label. ltype specifies the output label image type, an important
 /// consideration based on the total number of labels or alternatively the total number of pixels in
 /// the source image. ccltype specifies the connected components labeling algorithm to use, currently
@@ -3234,7 +3862,7 @@ pub fn connected_components_with_stats_with_algorithm(image: &dyn core::ToInputA
 	output_array_arg!(stats);
 	output_array_arg!(centroids);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_connectedComponentsWithStats_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int_int_int(image.as_raw__InputArray(), labels.as_raw__OutputArray(), stats.as_raw__OutputArray(), centroids.as_raw__OutputArray(), connectivity, ltype, ccltype, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_connectedComponentsWithStats_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int_int_int(image.as_raw__InputArray(), labels.as_raw__OutputArray(), stats.as_raw__OutputArray(), centroids.as_raw__OutputArray(), connectivity, ltype, ccltype, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -3242,7 +3870,7 @@ pub fn connected_components_with_stats_with_algorithm(image: &dyn core::ToInputA
 
 /// computes the connected components labeled image of boolean image
 /// 
-/// image with 4 or 8 way connectivity - returns N, the total number of labels [0, N-1] where 0
+/// image with 4 or 8 way connectivity - returns N, the total number of labels \[0, N-1\] where 0
 /// represents the background label. ltype specifies the output label image type, an important
 /// consideration based on the total number of labels or alternatively the total number of pixels in
 /// the source image. ccltype specifies the connected components labeling algorithm to use, currently
@@ -3275,7 +3903,7 @@ pub fn connected_components(image: &dyn core::ToInputArray, labels: &mut dyn cor
 	input_array_arg!(image);
 	output_array_arg!(labels);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_connectedComponents_const__InputArrayR_const__OutputArrayR_int_int(image.as_raw__InputArray(), labels.as_raw__OutputArray(), connectivity, ltype, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_connectedComponents_const__InputArrayR_const__OutputArrayR_int_int(image.as_raw__InputArray(), labels.as_raw__OutputArray(), connectivity, ltype, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -3283,7 +3911,7 @@ pub fn connected_components(image: &dyn core::ToInputArray, labels: &mut dyn cor
 
 /// computes the connected components labeled image of boolean image
 /// 
-/// image with 4 or 8 way connectivity - returns N, the total number of labels [0, N-1] where 0
+/// image with 4 or 8 way connectivity - returns N, the total number of labels \[0, N-1\] where 0
 /// represents the background label. ltype specifies the output label image type, an important
 /// consideration based on the total number of labels or alternatively the total number of pixels in
 /// the source image. ccltype specifies the connected components labeling algorithm to use, currently
@@ -3304,7 +3932,7 @@ pub fn connected_components_with_algorithm(image: &dyn core::ToInputArray, label
 	input_array_arg!(image);
 	output_array_arg!(labels);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_connectedComponents_const__InputArrayR_const__OutputArrayR_int_int_int(image.as_raw__InputArray(), labels.as_raw__OutputArray(), connectivity, ltype, ccltype, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_connectedComponents_const__InputArrayR_const__OutputArrayR_int_int_int(image.as_raw__InputArray(), labels.as_raw__OutputArray(), connectivity, ltype, ccltype, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -3348,7 +3976,7 @@ pub fn connected_components_with_algorithm(image: &dyn core::ToInputArray, label
 pub fn contour_area(contour: &dyn core::ToInputArray, oriented: bool) -> Result<f64> {
 	input_array_arg!(contour);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_contourArea_const__InputArrayR_bool(contour.as_raw__InputArray(), oriented, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_contourArea_const__InputArrayR_bool(contour.as_raw__InputArray(), oriented, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -3383,7 +4011,7 @@ pub fn contour_area(contour: &dyn core::ToInputArray, oriented: bool) -> Result<
 /// * nninterpolation: Flag indicating whether the fixed-point maps are used for the
 /// nearest-neighbor or for a more complex interpolation.
 /// ## See also
-/// remap, undistort, initUndistortRectifyMap
+/// [remap](crate::imgproc::remap), `undistort`, `init_undistort_rectify_map`
 /// 
 /// ## C++ default parameters
 /// * nninterpolation: false
@@ -3394,12 +4022,44 @@ pub fn convert_maps(map1: &dyn core::ToInputArray, map2: &dyn core::ToInputArray
 	output_array_arg!(dstmap1);
 	output_array_arg!(dstmap2);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_convertMaps_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_int_bool(map1.as_raw__InputArray(), map2.as_raw__InputArray(), dstmap1.as_raw__OutputArray(), dstmap2.as_raw__OutputArray(), dstmap1type, nninterpolation, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_convertMaps_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_int_bool(map1.as_raw__InputArray(), map2.as_raw__InputArray(), dstmap1.as_raw__OutputArray(), dstmap2.as_raw__OutputArray(), dstmap1type, nninterpolation, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `convert_maps()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct ConvertMapsBuilder<'a> {
+	map1: &'a dyn core::ToInputArray,
+	map2: &'a dyn core::ToInputArray,
+	dstmap1: &'a mut dyn core::ToOutputArray,
+	dstmap2: &'a mut dyn core::ToOutputArray,
+	dstmap1type: i32,
+	nninterpolation: bool,
+}
+
+impl<'a> ConvertMapsBuilder<'a> {
+	#[inline]
+	pub fn new(map1: &'a dyn core::ToInputArray, map2: &'a dyn core::ToInputArray, dstmap1: &'a mut dyn core::ToOutputArray, dstmap2: &'a mut dyn core::ToOutputArray, dstmap1type: i32) -> Self {
+		Self { map1, map2, dstmap1, dstmap2, dstmap1type, nninterpolation: false }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_nninterpolation(mut self, nninterpolation: bool) -> Self {
+		self.nninterpolation = nninterpolation;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		convert_maps(self.map1, self.map2, self.dstmap1, self.dstmap2, self.dstmap1type, self.nninterpolation)
+	}
+}
+
 /// Finds the convex hull of a point set.
 /// 
 /// The function cv::convexHull finds the convex hull of a 2D point set using the Sklansky's algorithm [Sklansky82](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_Sklansky82)
@@ -3423,7 +4083,7 @@ pub fn convert_maps(map1: &dyn core::ToInputArray, map2: &dyn core::ToInputArray
 /// 
 /// Note: `points` and `hull` should be different arrays, inplace processing isn't supported.
 /// 
-/// Check @ref tutorial_hull "the corresponding tutorial" for more details.
+/// Check the corresponding tutorial for more details.
 /// 
 /// useful links:
 /// 
@@ -3437,7 +4097,7 @@ pub fn convex_hull(points: &dyn core::ToInputArray, hull: &mut dyn core::ToOutpu
 	input_array_arg!(points);
 	output_array_arg!(hull);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_convexHull_const__InputArrayR_const__OutputArrayR_bool_bool(points.as_raw__InputArray(), hull.as_raw__OutputArray(), clockwise, return_points, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_convexHull_const__InputArrayR_const__OutputArrayR_bool_bool(points.as_raw__InputArray(), hull.as_raw__OutputArray(), clockwise, return_points, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -3466,7 +4126,7 @@ pub fn convexity_defects(contour: &dyn core::ToInputArray, convexhull: &dyn core
 	input_array_arg!(convexhull);
 	output_array_arg!(convexity_defects);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_convexityDefects_const__InputArrayR_const__InputArrayR_const__OutputArrayR(contour.as_raw__InputArray(), convexhull.as_raw__InputArray(), convexity_defects.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_convexityDefects_const__InputArrayR_const__InputArrayR_const__OutputArrayR(contour.as_raw__InputArray(), convexhull.as_raw__InputArray(), convexity_defects.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -3497,7 +4157,7 @@ pub fn convexity_defects(contour: &dyn core::ToInputArray, convexhull: &dyn core
 /// * ksize: Aperture parameter for the Sobel operator.
 /// * borderType: Pixel extrapolation method. See #BorderTypes. #BORDER_WRAP is not supported.
 /// ## See also
-/// cornerMinEigenVal, cornerHarris, preCornerDetect
+/// [corner_min_eigen_val](crate::imgproc::corner_min_eigen_val), [corner_harris](crate::imgproc::corner_harris), [pre_corner_detect](crate::imgproc::pre_corner_detect)
 /// 
 /// ## C++ default parameters
 /// * border_type: BORDER_DEFAULT
@@ -3506,7 +4166,7 @@ pub fn corner_eigen_vals_and_vecs(src: &dyn core::ToInputArray, dst: &mut dyn co
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cornerEigenValsAndVecs_const__InputArrayR_const__OutputArrayR_int_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), block_size, ksize, border_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cornerEigenValsAndVecs_const__InputArrayR_const__OutputArrayR_int_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), block_size, ksize, border_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -3539,7 +4199,7 @@ pub fn corner_harris(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputA
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cornerHarris_const__InputArrayR_const__OutputArrayR_int_int_double_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), block_size, ksize, k, border_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cornerHarris_const__InputArrayR_const__OutputArrayR_int_int_double_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), block_size, ksize, k, border_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -3567,7 +4227,7 @@ pub fn corner_min_eigen_val(src: &dyn core::ToInputArray, dst: &mut dyn core::To
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cornerMinEigenVal_const__InputArrayR_const__OutputArrayR_int_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), block_size, ksize, border_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cornerMinEigenVal_const__InputArrayR_const__OutputArrayR_int_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), block_size, ksize, border_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -3618,7 +4278,7 @@ pub fn corner_sub_pix(image: &dyn core::ToInputArray, corners: &mut dyn core::To
 	input_array_arg!(image);
 	input_output_array_arg!(corners);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cornerSubPix_const__InputArrayR_const__InputOutputArrayR_Size_Size_TermCriteria(image.as_raw__InputArray(), corners.as_raw__InputOutputArray(), win_size.opencv_as_extern(), zero_zone.opencv_as_extern(), criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cornerSubPix_const__InputArrayR_const__InputOutputArrayR_Size_Size_TermCriteria(image.as_raw__InputArray(), corners.as_raw__InputOutputArray(), win_size.opencv_as_extern(), zero_zone.opencv_as_extern(), criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -3637,7 +4297,7 @@ pub fn corner_sub_pix(image: &dyn core::ToInputArray, corners: &mut dyn core::To
 #[inline]
 pub fn create_clahe(clip_limit: f64, tile_grid_size: core::Size) -> Result<core::Ptr<dyn crate::imgproc::CLAHE>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_createCLAHE_double_Size(clip_limit, tile_grid_size.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_createCLAHE_double_Size(clip_limit, tile_grid_size.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::imgproc::CLAHE>::opencv_from_extern(ret) };
@@ -3648,7 +4308,7 @@ pub fn create_clahe(clip_limit: f64, tile_grid_size: core::Size) -> Result<core:
 #[inline]
 pub fn create_generalized_hough_ballard() -> Result<core::Ptr<dyn crate::imgproc::GeneralizedHoughBallard>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_createGeneralizedHoughBallard(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_createGeneralizedHoughBallard(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::imgproc::GeneralizedHoughBallard>::opencv_from_extern(ret) };
@@ -3659,7 +4319,7 @@ pub fn create_generalized_hough_ballard() -> Result<core::Ptr<dyn crate::imgproc
 #[inline]
 pub fn create_generalized_hough_guil() -> Result<core::Ptr<dyn crate::imgproc::GeneralizedHoughGuil>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_createGeneralizedHoughGuil(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_createGeneralizedHoughGuil(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::imgproc::GeneralizedHoughGuil>::opencv_from_extern(ret) };
@@ -3686,7 +4346,7 @@ pub fn create_generalized_hough_guil() -> Result<core::Ptr<dyn crate::imgproc::G
 pub fn create_hanning_window(dst: &mut dyn core::ToOutputArray, win_size: core::Size, typ: i32) -> Result<()> {
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_createHanningWindow_const__OutputArrayR_Size_int(dst.as_raw__OutputArray(), win_size.opencv_as_extern(), typ, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_createHanningWindow_const__OutputArrayR_Size_int(dst.as_raw__OutputArray(), win_size.opencv_as_extern(), typ, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -3699,7 +4359,7 @@ pub fn create_hanning_window(dst: &mut dyn core::ToOutputArray, win_size: core::
 /// 
 /// ## Parameters
 /// * refine: The way found lines will be refined, see #LineSegmentDetectorModes
-/// * scale: The scale of the image that will be used to find the lines. Range (0..1].
+/// * scale: The scale of the image that will be used to find the lines. Range (0..1\].
 /// * sigma_scale: Sigma for Gaussian filter. It is computed as sigma = sigma_scale/scale.
 /// * quant: Bound to the quantization error on the gradient norm.
 /// * ang_th: Gradient angle tolerance in degrees.
@@ -3719,13 +4379,89 @@ pub fn create_hanning_window(dst: &mut dyn core::ToOutputArray, win_size: core::
 #[inline]
 pub fn create_line_segment_detector(refine: i32, scale: f64, sigma_scale: f64, quant: f64, ang_th: f64, log_eps: f64, density_th: f64, n_bins: i32) -> Result<core::Ptr<dyn crate::imgproc::LineSegmentDetector>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_createLineSegmentDetector_int_double_double_double_double_double_double_int(refine, scale, sigma_scale, quant, ang_th, log_eps, density_th, n_bins, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_createLineSegmentDetector_int_double_double_double_double_double_double_int(refine, scale, sigma_scale, quant, ang_th, log_eps, density_th, n_bins, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::imgproc::LineSegmentDetector>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_line_segment_detector()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateLineSegmentDetectorBuilder {
+	refine: i32,
+	scale: f64,
+	sigma_scale: f64,
+	quant: f64,
+	ang_th: f64,
+	log_eps: f64,
+	density_th: f64,
+	n_bins: i32,
+}
+
+impl CreateLineSegmentDetectorBuilder {
+	#[inline]
+	pub fn new(refine: i32) -> Self {
+		Self { refine, scale: 0.8, sigma_scale: 0.6, quant: 2.0, ang_th: 22.5, log_eps: 0., density_th: 0.7, n_bins: 1024 }
+	}
+	
+	/// C++ default: `0.8`
+	#[inline]
+	pub fn with_scale(mut self, scale: f64) -> Self {
+		self.scale = scale;
+		self
+	}
+	
+	/// C++ default: `0.6`
+	#[inline]
+	pub fn with_sigma_scale(mut self, sigma_scale: f64) -> Self {
+		self.sigma_scale = sigma_scale;
+		self
+	}
+	
+	/// C++ default: `2.0`
+	#[inline]
+	pub fn with_quant(mut self, quant: f64) -> Self {
+		self.quant = quant;
+		self
+	}
+	
+	/// C++ default: `22.5`
+	#[inline]
+	pub fn with_ang_th(mut self, ang_th: f64) -> Self {
+		self.ang_th = ang_th;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_log_eps(mut self, log_eps: f64) -> Self {
+		self.log_eps = log_eps;
+		self
+	}
+	
+	/// C++ default: `0.7`
+	#[inline]
+	pub fn with_density_th(mut self, density_th: f64) -> Self {
+		self.density_th = density_th;
+		self
+	}
+	
+	/// C++ default: `1024`
+	#[inline]
+	pub fn with_n_bins(mut self, n_bins: i32) -> Self {
+		self.n_bins = n_bins;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::imgproc::LineSegmentDetector>> {
+		create_line_segment_detector(self.refine, self.scale, self.sigma_scale, self.quant, self.ang_th, self.log_eps, self.density_th, self.n_bins)
+	}
+}
+
 /// Converts an image from one color space to another where the source image is
 /// stored in two planes.
 /// 
@@ -3750,7 +4486,7 @@ pub fn cvt_color_two_plane(src1: &dyn core::ToInputArray, src2: &dyn core::ToInp
 	input_array_arg!(src2);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cvtColorTwoPlane_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), code, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cvtColorTwoPlane_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), code, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -3797,7 +4533,7 @@ pub fn cvt_color_two_plane(src1: &dyn core::ToInputArray, src2: &dyn core::ToInp
 /// * dstCn: number of channels in the destination image; if the parameter is 0, the number of the
 /// channels is derived automatically from src and code.
 /// ## See also
-/// @ref imgproc_color_conversions
+/// imgproc_color_conversions
 /// 
 /// ## C++ default parameters
 /// * dst_cn: 0
@@ -3806,7 +4542,7 @@ pub fn cvt_color(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cvtColor_const__InputArrayR_const__OutputArrayR_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), code, dst_cn, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cvtColor_const__InputArrayR_const__OutputArrayR_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), code, dst_cn, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -3841,7 +4577,7 @@ pub fn cvt_color(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 /// 
 ///    #COLOR_BayerBG2BGRA , #COLOR_BayerGB2BGRA , #COLOR_BayerRG2BGRA , #COLOR_BayerGR2BGRA
 /// ## See also
-/// cvtColor
+/// [cvt_color](crate::imgproc::cvt_color)
 /// 
 /// ## C++ default parameters
 /// * dst_cn: 0
@@ -3850,7 +4586,7 @@ pub fn demosaicing(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArr
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_demosaicing_const__InputArrayR_const__OutputArrayR_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), code, dst_cn, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_demosaicing_const__InputArrayR_const__OutputArrayR_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), code, dst_cn, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -3877,7 +4613,7 @@ pub fn demosaicing(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArr
 /// * borderType: pixel extrapolation method, see #BorderTypes. #BORDER_WRAP is not suported.
 /// * borderValue: border value in case of a constant border
 /// ## See also
-/// erode, morphologyEx, getStructuringElement
+/// [erode](crate::imgproc::erode), [morphology_ex](crate::imgproc::morphology_ex), [get_structuring_element](crate::imgproc::get_structuring_element)
 /// 
 /// ## C++ default parameters
 /// * anchor: Point(-1,-1)
@@ -3890,12 +4626,52 @@ pub fn dilate(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, k
 	output_array_arg!(dst);
 	input_array_arg!(kernel);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_dilate_const__InputArrayR_const__OutputArrayR_const__InputArrayR_Point_int_int_const_ScalarR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), kernel.as_raw__InputArray(), anchor.opencv_as_extern(), iterations, border_type, &border_value, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_dilate_const__InputArrayR_const__OutputArrayR_const__InputArrayR_Point_int_int_const_ScalarR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), kernel.as_raw__InputArray(), anchor.opencv_as_extern(), iterations, border_type, &border_value, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `dilate()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct DilateBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	kernel: &'a dyn core::ToInputArray,
+	anchor: core::Point,
+	iterations: i32,
+	border_type: i32,
+	border_value: core::Scalar,
+}
+
+impl<'a> DilateBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, kernel: &'a dyn core::ToInputArray, border_type: i32, border_value: core::Scalar) -> Self {
+		Self { src, dst, kernel, anchor: core::Point::new(-1, -1), iterations: 1, border_type, border_value }
+	}
+	
+	/// C++ default: `Point(-1,-1)`
+	#[inline]
+	pub fn with_anchor(mut self, anchor: core::Point) -> Self {
+		self.anchor = anchor;
+		self
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_iterations(mut self, iterations: i32) -> Self {
+		self.iterations = iterations;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		dilate(self.src, self.dst, self.kernel, self.anchor, self.iterations, self.border_type, self.border_value)
+	}
+}
+
 /// Calculates the distance to the closest zero pixel for each pixel of the source image.
 /// 
 /// The function cv::distanceTransform calculates the approximate or precise distance from every binary
@@ -3957,7 +4733,7 @@ pub fn distance_transform_with_labels(src: &dyn core::ToInputArray, dst: &mut dy
 	output_array_arg!(dst);
 	output_array_arg!(labels);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_distanceTransform_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_int_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), labels.as_raw__OutputArray(), distance_type, mask_size, label_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_distanceTransform_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_int_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), labels.as_raw__OutputArray(), distance_type, mask_size, label_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -4035,7 +4811,7 @@ pub fn distance_transform(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOu
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_distanceTransform_const__InputArrayR_const__OutputArrayR_int_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), distance_type, mask_size, dst_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_distanceTransform_const__InputArrayR_const__OutputArrayR_int_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), distance_type, mask_size, dst_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -4063,7 +4839,7 @@ pub fn div_spectrums(a: &dyn core::ToInputArray, b: &dyn core::ToInputArray, c:
 	input_array_arg!(b);
 	output_array_arg!(c);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_divSpectrums_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_bool(a.as_raw__InputArray(), b.as_raw__InputArray(), c.as_raw__OutputArray(), flags, conj_b, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_divSpectrums_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_bool(a.as_raw__InputArray(), b.as_raw__InputArray(), c.as_raw__OutputArray(), flags, conj_b, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -4111,12 +4887,63 @@ pub fn draw_contours(image: &mut dyn core::ToInputOutputArray, contours: &dyn co
 	input_array_arg!(contours);
 	input_array_arg!(hierarchy);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_drawContours_const__InputOutputArrayR_const__InputArrayR_int_const_ScalarR_int_int_const__InputArrayR_int_Point(image.as_raw__InputOutputArray(), contours.as_raw__InputArray(), contour_idx, &color, thickness, line_type, hierarchy.as_raw__InputArray(), max_level, offset.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_drawContours_const__InputOutputArrayR_const__InputArrayR_int_const_ScalarR_int_int_const__InputArrayR_int_Point(image.as_raw__InputOutputArray(), contours.as_raw__InputArray(), contour_idx, &color, thickness, line_type, hierarchy.as_raw__InputArray(), max_level, offset.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `draw_contours()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct DrawContoursBuilder<'a> {
+	image: &'a mut dyn core::ToInputOutputArray,
+	contours: &'a dyn core::ToInputArray,
+	contour_idx: i32,
+	color: core::Scalar,
+	thickness: i32,
+	line_type: i32,
+	hierarchy: Option<&'a dyn core::ToInputArray>,
+	max_level: i32,
+	offset: core::Point,
+}
+
+impl<'a> DrawContoursBuilder<'a> {
+	#[inline]
+	pub fn new(image: &'a mut dyn core::ToInputOutputArray, contours: &'a dyn core::ToInputArray, contour_idx: i32, color: core::Scalar, line_type: i32, max_level: i32) -> Self {
+		Self { image, contours, contour_idx, color, thickness: 1, line_type, hierarchy: None, max_level, offset: core::Point::default() }
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_thickness(mut self, thickness: i32) -> Self {
+		self.thickness = thickness;
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_hierarchy(mut self, hierarchy: &'a dyn core::ToInputArray) -> Self {
+		self.hierarchy = Some(hierarchy);
+		self
+	}
+	
+	/// C++ default: `Point()`
+	#[inline]
+	pub fn with_offset(mut self, offset: core::Point) -> Self {
+		self.offset = offset;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let hierarchy_no_array = core::no_array();
+		let hierarchy: &dyn core::ToInputArray = match self.hierarchy { Some(hierarchy) => hierarchy, None => &hierarchy_no_array };
+		draw_contours(self.image, self.contours, self.contour_idx, self.color, self.thickness, self.line_type, hierarchy, self.max_level, self.offset)
+	}
+}
+
 /// Draws a marker on a predefined position in an image.
 /// 
 /// The function cv::drawMarker draws a marker on a given position in the image. For the moment several
@@ -4129,7 +4956,7 @@ pub fn draw_contours(image: &mut dyn core::ToInputOutputArray, contours: &dyn co
 /// * markerType: The specific type of marker you want to use, see #MarkerTypes
 /// * thickness: Line thickness.
 /// * line_type: Type of the line, See #LineTypes
-/// * markerSize: The length of the marker axis [default = 20 pixels]
+/// * markerSize: The length of the marker axis \[default = 20 pixels\]
 /// 
 /// ## C++ default parameters
 /// * marker_type: MARKER_CROSS
@@ -4140,12 +4967,59 @@ pub fn draw_contours(image: &mut dyn core::ToInputOutputArray, contours: &dyn co
 pub fn draw_marker(img: &mut dyn core::ToInputOutputArray, position: core::Point, color: core::Scalar, marker_type: i32, marker_size: i32, thickness: i32, line_type: i32) -> Result<()> {
 	input_output_array_arg!(img);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_drawMarker_const__InputOutputArrayR_Point_const_ScalarR_int_int_int_int(img.as_raw__InputOutputArray(), position.opencv_as_extern(), &color, marker_type, marker_size, thickness, line_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_drawMarker_const__InputOutputArrayR_Point_const_ScalarR_int_int_int_int(img.as_raw__InputOutputArray(), position.opencv_as_extern(), &color, marker_type, marker_size, thickness, line_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `draw_marker()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct DrawMarkerBuilder<'a> {
+	img: &'a mut dyn core::ToInputOutputArray,
+	position: core::Point,
+	color: core::Scalar,
+	marker_type: i32,
+	marker_size: i32,
+	thickness: i32,
+	line_type: i32,
+}
+
+impl<'a> DrawMarkerBuilder<'a> {
+	#[inline]
+	pub fn new(img: &'a mut dyn core::ToInputOutputArray, position: core::Point, color: core::Scalar, marker_type: i32) -> Self {
+		Self { img, position, color, marker_type, marker_size: 20, thickness: 1, line_type: 8 }
+	}
+	
+	/// C++ default: `20`
+	#[inline]
+	pub fn with_marker_size(mut self, marker_size: i32) -> Self {
+		self.marker_size = marker_size;
+		self
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_thickness(mut self, thickness: i32) -> Self {
+		self.thickness = thickness;
+		self
+	}
+	
+	/// C++ default: `8`
+	#[inline]
+	pub fn with_line_type(mut self, line_type: i32) -> Self {
+		self.line_type = line_type;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		draw_marker(self.img, self.position, self.color, self.marker_type, self.marker_size, self.thickness, self.line_type)
+	}
+}
+
 /// Approximates an elliptic arc with a polyline.
 /// 
 /// The function ellipse2Poly computes the vertices of a polyline that approximates the specified
@@ -4173,7 +5047,7 @@ pub fn draw_marker(img: &mut dyn core::ToInputOutputArray, position: core::Point
 #[inline]
 pub fn ellipse_2_poly_f64(center: core::Point2d, axes: core::Size2d, angle: i32, arc_start: i32, arc_end: i32, delta: i32, pts: &mut core::Vector<core::Point2d>) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ellipse2Poly_Point2d_Size2d_int_int_int_int_vector_Point2d_R(center.opencv_as_extern(), axes.opencv_as_extern(), angle, arc_start, arc_end, delta, pts.as_raw_mut_VectorOfPoint2d(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ellipse2Poly_Point2d_Size2d_int_int_int_int_vector_Point2d_R(center.opencv_as_extern(), axes.opencv_as_extern(), angle, arc_start, arc_end, delta, pts.as_raw_mut_VectorOfPoint2d(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -4196,7 +5070,7 @@ pub fn ellipse_2_poly_f64(center: core::Point2d, axes: core::Size2d, angle: i32,
 #[inline]
 pub fn ellipse_2_poly(center: core::Point, axes: core::Size, angle: i32, arc_start: i32, arc_end: i32, delta: i32, pts: &mut core::Vector<core::Point>) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ellipse2Poly_Point_Size_int_int_int_int_vector_Point_R(center.opencv_as_extern(), axes.opencv_as_extern(), angle, arc_start, arc_end, delta, pts.as_raw_mut_VectorOfPoint(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ellipse2Poly_Point_Size_int_int_int_int_vector_Point_R(center.opencv_as_extern(), axes.opencv_as_extern(), angle, arc_start, arc_end, delta, pts.as_raw_mut_VectorOfPoint(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -4236,12 +5110,55 @@ pub fn ellipse_2_poly(center: core::Point, axes: core::Size, angle: i32, arc_sta
 pub fn ellipse(img: &mut dyn core::ToInputOutputArray, center: core::Point, axes: core::Size, angle: f64, start_angle: f64, end_angle: f64, color: core::Scalar, thickness: i32, line_type: i32, shift: i32) -> Result<()> {
 	input_output_array_arg!(img);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ellipse_const__InputOutputArrayR_Point_Size_double_double_double_const_ScalarR_int_int_int(img.as_raw__InputOutputArray(), center.opencv_as_extern(), axes.opencv_as_extern(), angle, start_angle, end_angle, &color, thickness, line_type, shift, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ellipse_const__InputOutputArrayR_Point_Size_double_double_double_const_ScalarR_int_int_int(img.as_raw__InputOutputArray(), center.opencv_as_extern(), axes.opencv_as_extern(), angle, start_angle, end_angle, &color, thickness, line_type, shift, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `ellipse()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct EllipseBuilder<'a> {
+	img: &'a mut dyn core::ToInputOutputArray,
+	center: core::Point,
+	axes: core::Size,
+	angle: f64,
+	start_angle: f64,
+	end_angle: f64,
+	color: core::Scalar,
+	thickness: i32,
+	line_type: i32,
+	shift: i32,
+}
+
+impl<'a> EllipseBuilder<'a> {
+	#[inline]
+	pub fn new(img: &'a mut dyn core::ToInputOutputArray, center: core::Point, axes: core::Size, angle: f64, start_angle: f64, end_angle: f64, color: core::Scalar, line_type: i32) -> Self {
+		Self { img, center, axes, angle, start_angle, end_angle, color, thickness: 1, line_type, shift: 0 }
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_thickness(mut self, thickness: i32) -> Self {
+		self.thickness = thickness;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_shift(mut self, shift: i32) -> Self {
+		self.shift = shift;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		ellipse(self.img, self.center, self.axes, self.angle, self.start_angle, self.end_angle, self.color, self.thickness, self.line_type, self.shift)
+	}
+}
+
 /// Draws a simple or thick elliptic arc or fills an ellipse sector.
 /// 
 /// The function cv::ellipse with more parameters draws an ellipse outline, a filled ellipse, an elliptic
@@ -4285,7 +5202,7 @@ pub fn ellipse(img: &mut dyn core::ToInputOutputArray, center: core::Point, axes
 pub fn ellipse_rotated_rect(img: &mut dyn core::ToInputOutputArray, box_: &core::RotatedRect, color: core::Scalar, thickness: i32, line_type: i32) -> Result<()> {
 	input_output_array_arg!(img);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ellipse_const__InputOutputArrayR_const_RotatedRectR_const_ScalarR_int_int(img.as_raw__InputOutputArray(), box_.as_raw_RotatedRect(), &color, thickness, line_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ellipse_const__InputOutputArrayR_const_RotatedRectR_const_ScalarR_int_int(img.as_raw__InputOutputArray(), box_.as_raw_RotatedRect(), &color, thickness, line_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -4311,7 +5228,7 @@ pub fn equalize_hist(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputA
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_equalizeHist_const__InputArrayR_const__OutputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_equalizeHist_const__InputArrayR_const__OutputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -4339,7 +5256,7 @@ pub fn equalize_hist(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputA
 /// * borderType: pixel extrapolation method, see #BorderTypes. #BORDER_WRAP is not supported.
 /// * borderValue: border value in case of a constant border
 /// ## See also
-/// dilate, morphologyEx, getStructuringElement
+/// [dilate](crate::imgproc::dilate), [morphology_ex](crate::imgproc::morphology_ex), [get_structuring_element](crate::imgproc::get_structuring_element)
 /// 
 /// ## C++ default parameters
 /// * anchor: Point(-1,-1)
@@ -4352,12 +5269,52 @@ pub fn erode(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, ke
 	output_array_arg!(dst);
 	input_array_arg!(kernel);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_erode_const__InputArrayR_const__OutputArrayR_const__InputArrayR_Point_int_int_const_ScalarR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), kernel.as_raw__InputArray(), anchor.opencv_as_extern(), iterations, border_type, &border_value, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_erode_const__InputArrayR_const__OutputArrayR_const__InputArrayR_Point_int_int_const_ScalarR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), kernel.as_raw__InputArray(), anchor.opencv_as_extern(), iterations, border_type, &border_value, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `erode()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct ErodeBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	kernel: &'a dyn core::ToInputArray,
+	anchor: core::Point,
+	iterations: i32,
+	border_type: i32,
+	border_value: core::Scalar,
+}
+
+impl<'a> ErodeBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, kernel: &'a dyn core::ToInputArray, border_type: i32, border_value: core::Scalar) -> Self {
+		Self { src, dst, kernel, anchor: core::Point::new(-1, -1), iterations: 1, border_type, border_value }
+	}
+	
+	/// C++ default: `Point(-1,-1)`
+	#[inline]
+	pub fn with_anchor(mut self, anchor: core::Point) -> Self {
+		self.anchor = anchor;
+		self
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_iterations(mut self, iterations: i32) -> Self {
+		self.iterations = iterations;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		erode(self.src, self.dst, self.kernel, self.anchor, self.iterations, self.border_type, self.border_value)
+	}
+}
+
 /// Fills a convex polygon.
 /// 
 /// The function cv::fillConvexPoly draws a filled convex polygon. This function is much faster than the
@@ -4380,7 +5337,7 @@ pub fn fill_convex_poly(img: &mut dyn core::ToInputOutputArray, points: &dyn cor
 	input_output_array_arg!(img);
 	input_array_arg!(points);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_fillConvexPoly_const__InputOutputArrayR_const__InputArrayR_const_ScalarR_int_int(img.as_raw__InputOutputArray(), points.as_raw__InputArray(), &color, line_type, shift, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_fillConvexPoly_const__InputOutputArrayR_const__InputArrayR_const_ScalarR_int_int(img.as_raw__InputOutputArray(), points.as_raw__InputArray(), &color, line_type, shift, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -4409,12 +5366,51 @@ pub fn fill_poly(img: &mut dyn core::ToInputOutputArray, pts: &dyn core::ToInput
 	input_output_array_arg!(img);
 	input_array_arg!(pts);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_fillPoly_const__InputOutputArrayR_const__InputArrayR_const_ScalarR_int_int_Point(img.as_raw__InputOutputArray(), pts.as_raw__InputArray(), &color, line_type, shift, offset.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_fillPoly_const__InputOutputArrayR_const__InputArrayR_const_ScalarR_int_int_Point(img.as_raw__InputOutputArray(), pts.as_raw__InputArray(), &color, line_type, shift, offset.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `fill_poly()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FillPolyBuilder<'a> {
+	img: &'a mut dyn core::ToInputOutputArray,
+	pts: &'a dyn core::ToInputArray,
+	color: core::Scalar,
+	line_type: i32,
+	shift: i32,
+	offset: core::Point,
+}
+
+impl<'a> FillPolyBuilder<'a> {
+	#[inline]
+	pub fn new(img: &'a mut dyn core::ToInputOutputArray, pts: &'a dyn core::ToInputArray, color: core::Scalar, line_type: i32) -> Self {
+		Self { img, pts, color, line_type, shift: 0, offset: core::Point::default() }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_shift(mut self, shift: i32) -> Self {
+		self.shift = shift;
+		self
+	}
+	
+	/// C++ default: `Point()`
+	#[inline]
+	pub fn with_offset(mut self, offset: core::Point) -> Self {
+		self.offset = offset;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		fill_poly(self.img, self.pts, self.color, self.line_type, self.shift, self.offset)
+	}
+}
+
 /// Convolves an image with the kernel.
 /// 
 /// The function applies an arbitrary linear filter to an image. In-place operation is supported. When
@@ -4435,7 +5431,7 @@ pub fn fill_poly(img: &mut dyn core::ToInputOutputArray, pts: &dyn core::ToInput
 /// ## Parameters
 /// * src: input image.
 /// * dst: output image of the same size and the same number of channels as src.
-/// * ddepth: desired depth of the destination image, see @ref filter_depths "combinations"
+/// * ddepth: desired depth of the destination image, see combinations
 /// * kernel: convolution kernel (or rather a correlation kernel), a single-channel floating point
 /// matrix; if you want to apply different kernels to different channels, split the image into
 /// separate color planes using split and process them individually.
@@ -4445,7 +5441,7 @@ pub fn fill_poly(img: &mut dyn core::ToInputOutputArray, pts: &dyn core::ToInput
 /// * delta: optional value added to the filtered pixels before storing them in dst.
 /// * borderType: pixel extrapolation method, see #BorderTypes. #BORDER_WRAP is not supported.
 /// ## See also
-/// sepFilter2D, dft, matchTemplate
+/// [sep_filter_2d](crate::imgproc::sep_filter_2d), [dft](crate::core::dft), [match_template](crate::imgproc::match_template)
 /// 
 /// ## C++ default parameters
 /// * anchor: Point(-1,-1)
@@ -4457,12 +5453,52 @@ pub fn filter_2d(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 	output_array_arg!(dst);
 	input_array_arg!(kernel);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_filter2D_const__InputArrayR_const__OutputArrayR_int_const__InputArrayR_Point_double_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ddepth, kernel.as_raw__InputArray(), anchor.opencv_as_extern(), delta, border_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_filter2D_const__InputArrayR_const__OutputArrayR_int_const__InputArrayR_Point_double_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ddepth, kernel.as_raw__InputArray(), anchor.opencv_as_extern(), delta, border_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `filter_2d()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct Filter2dBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	ddepth: i32,
+	kernel: &'a dyn core::ToInputArray,
+	anchor: core::Point,
+	delta: f64,
+	border_type: i32,
+}
+
+impl<'a> Filter2dBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, ddepth: i32, kernel: &'a dyn core::ToInputArray, border_type: i32) -> Self {
+		Self { src, dst, ddepth, kernel, anchor: core::Point::new(-1, -1), delta: 0., border_type }
+	}
+	
+	/// C++ default: `Point(-1,-1)`
+	#[inline]
+	pub fn with_anchor(mut self, anchor: core::Point) -> Self {
+		self.anchor = anchor;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_delta(mut self, delta: f64) -> Self {
+		self.delta = delta;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		filter_2d(self.src, self.dst, self.ddepth, self.kernel, self.anchor, self.delta, self.border_type)
+	}
+}
+
 /// Finds contours in a binary image.
 /// 
 /// The function retrieves contours from the binary image using the algorithm [Suzuki85](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_Suzuki85) . The contours
@@ -4479,13 +5515,13 @@ pub fn filter_2d(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 /// * contours: Detected contours. Each contour is stored as a vector of points (e.g.
 /// std::vector<std::vector<cv::Point> >).
 /// * hierarchy: Optional output vector (e.g. std::vector<cv::Vec4i>), containing information about the image topology. It has
-/// as many elements as the number of contours. For each i-th contour contours[i], the elements
-/// hierarchy[i][0] , hierarchy[i][1] , hierarchy[i][2] , and hierarchy[i][3] are set to 0-based indices
+/// as many elements as the number of contours. For each i-th contour contours\[i\], the elements
+/// hierarchy\[i\]\[0\] , hierarchy\[i\]\[1\] , hierarchy\[i\]\[2\] , and hierarchy\[i\]\[3\] are set to 0-based indices
 /// in contours of the next and previous contours at the same hierarchical level, the first child
 /// contour and the parent contour, respectively. If for the contour i there are no next, previous,
-/// parent, or nested contours, the corresponding elements of hierarchy[i] will be negative.
+/// parent, or nested contours, the corresponding elements of hierarchy\[i\] will be negative.
 /// 
-/// Note: In Python, hierarchy is nested inside a top level array. Use hierarchy[0][i] to access hierarchical elements of i-th contour.
+/// Note: In Python, hierarchy is nested inside a top level array. Use hierarchy\[0\]\[i\] to access hierarchical elements of i-th contour.
 /// * mode: Contour retrieval mode, see #RetrievalModes
 /// * method: Contour approximation method, see #ContourApproximationModes
 /// * offset: Optional offset by which every contour point is shifted. This is useful if the
@@ -4500,12 +5536,44 @@ pub fn find_contours_with_hierarchy(image: &dyn core::ToInputArray, contours: &m
 	output_array_arg!(contours);
 	output_array_arg!(hierarchy);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_findContours_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_int_int_Point(image.as_raw__InputArray(), contours.as_raw__OutputArray(), hierarchy.as_raw__OutputArray(), mode, method, offset.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_findContours_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_int_int_Point(image.as_raw__InputArray(), contours.as_raw__OutputArray(), hierarchy.as_raw__OutputArray(), mode, method, offset.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `find_contours_with_hierarchy()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FindContoursWithHierarchyBuilder<'a> {
+	image: &'a dyn core::ToInputArray,
+	contours: &'a mut dyn core::ToOutputArray,
+	hierarchy: &'a mut dyn core::ToOutputArray,
+	mode: i32,
+	method: i32,
+	offset: core::Point,
+}
+
+impl<'a> FindContoursWithHierarchyBuilder<'a> {
+	#[inline]
+	pub fn new(image: &'a dyn core::ToInputArray, contours: &'a mut dyn core::ToOutputArray, hierarchy: &'a mut dyn core::ToOutputArray, mode: i32, method: i32) -> Self {
+		Self { image, contours, hierarchy, mode, method, offset: core::Point::default() }
+	}
+	
+	/// C++ default: `Point()`
+	#[inline]
+	pub fn with_offset(mut self, offset: core::Point) -> Self {
+		self.offset = offset;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		find_contours_with_hierarchy(self.image, self.contours, self.hierarchy, self.mode, self.method, self.offset)
+	}
+}
+
 /// Finds contours in a binary image.
 /// 
 /// The function retrieves contours from the binary image using the algorithm [Suzuki85](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_Suzuki85) . The contours
@@ -4522,13 +5590,13 @@ pub fn find_contours_with_hierarchy(image: &dyn core::ToInputArray, contours: &m
 /// * contours: Detected contours. Each contour is stored as a vector of points (e.g.
 /// std::vector<std::vector<cv::Point> >).
 /// * hierarchy: Optional output vector (e.g. std::vector<cv::Vec4i>), containing information about the image topology. It has
-/// as many elements as the number of contours. For each i-th contour contours[i], the elements
-/// hierarchy[i][0] , hierarchy[i][1] , hierarchy[i][2] , and hierarchy[i][3] are set to 0-based indices
+/// as many elements as the number of contours. For each i-th contour contours\[i\], the elements
+/// hierarchy\[i\]\[0\] , hierarchy\[i\]\[1\] , hierarchy\[i\]\[2\] , and hierarchy\[i\]\[3\] are set to 0-based indices
 /// in contours of the next and previous contours at the same hierarchical level, the first child
 /// contour and the parent contour, respectively. If for the contour i there are no next, previous,
-/// parent, or nested contours, the corresponding elements of hierarchy[i] will be negative.
+/// parent, or nested contours, the corresponding elements of hierarchy\[i\] will be negative.
 /// 
-/// Note: In Python, hierarchy is nested inside a top level array. Use hierarchy[0][i] to access hierarchical elements of i-th contour.
+/// Note: In Python, hierarchy is nested inside a top level array. Use hierarchy\[0\]\[i\] to access hierarchical elements of i-th contour.
 /// * mode: Contour retrieval mode, see #RetrievalModes
 /// * method: Contour approximation method, see #ContourApproximationModes
 /// * offset: Optional offset by which every contour point is shifted. This is useful if the
@@ -4544,7 +5612,7 @@ pub fn find_contours(image: &dyn core::ToInputArray, contours: &mut dyn core::To
 	input_array_arg!(image);
 	output_array_arg!(contours);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_findContours_const__InputArrayR_const__OutputArrayR_int_int_Point(image.as_raw__InputArray(), contours.as_raw__OutputArray(), mode, method, offset.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_findContours_const__InputArrayR_const__OutputArrayR_int_int_Point(image.as_raw__InputArray(), contours.as_raw__OutputArray(), mode, method, offset.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -4589,7 +5657,7 @@ pub fn find_contours(image: &dyn core::ToInputArray, contours: &mut dyn core::To
 pub fn fit_ellipse_ams(points: &dyn core::ToInputArray) -> Result<core::RotatedRect> {
 	input_array_arg!(points);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_fitEllipseAMS_const__InputArrayR(points.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_fitEllipseAMS_const__InputArrayR(points.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::RotatedRect::opencv_from_extern(ret) };
@@ -4642,7 +5710,7 @@ pub fn fit_ellipse_ams(points: &dyn core::ToInputArray) -> Result<core::RotatedR
 pub fn fit_ellipse_direct(points: &dyn core::ToInputArray) -> Result<core::RotatedRect> {
 	input_array_arg!(points);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_fitEllipseDirect_const__InputArrayR(points.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_fitEllipseDirect_const__InputArrayR(points.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::RotatedRect::opencv_from_extern(ret) };
@@ -4663,7 +5731,7 @@ pub fn fit_ellipse_direct(points: &dyn core::ToInputArray) -> Result<core::Rotat
 pub fn fit_ellipse(points: &dyn core::ToInputArray) -> Result<core::RotatedRect> {
 	input_array_arg!(points);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_fitEllipse_const__InputArrayR(points.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_fitEllipse_const__InputArrayR(points.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::RotatedRect::opencv_from_extern(ret) };
@@ -4709,7 +5777,7 @@ pub fn fit_line(points: &dyn core::ToInputArray, line: &mut dyn core::ToOutputAr
 	input_array_arg!(points);
 	output_array_arg!(line);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_fitLine_const__InputArrayR_const__OutputArrayR_int_double_double_double(points.as_raw__InputArray(), line.as_raw__OutputArray(), dist_type, param, reps, aeps, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_fitLine_const__InputArrayR_const__OutputArrayR_int_double_double_double(points.as_raw__InputArray(), line.as_raw__OutputArray(), dist_type, param, reps, aeps, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -4786,7 +5854,7 @@ pub fn fit_line(points: &dyn core::ToInputArray, line: &mut dyn core::ToOutputAr
 /// Note: Since the mask is larger than the filled image, a pixel ![inline formula](https://latex.codecogs.com/png.latex?%28x%2C%20y%29) in image corresponds to the
 /// pixel ![inline formula](https://latex.codecogs.com/png.latex?%28x%2B1%2C%20y%2B1%29) in the mask .
 /// ## See also
-/// findContours
+/// [find_contours](crate::imgproc::find_contours)
 /// 
 /// ## Overloaded parameters
 /// 
@@ -4802,12 +5870,59 @@ pub fn fit_line(points: &dyn core::ToInputArray, line: &mut dyn core::ToOutputAr
 pub fn flood_fill(image: &mut dyn core::ToInputOutputArray, seed_point: core::Point, new_val: core::Scalar, rect: &mut core::Rect, lo_diff: core::Scalar, up_diff: core::Scalar, flags: i32) -> Result<i32> {
 	input_output_array_arg!(image);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_floodFill_const__InputOutputArrayR_Point_Scalar_RectX_Scalar_Scalar_int(image.as_raw__InputOutputArray(), seed_point.opencv_as_extern(), new_val.opencv_as_extern(), rect, lo_diff.opencv_as_extern(), up_diff.opencv_as_extern(), flags, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_floodFill_const__InputOutputArrayR_Point_Scalar_RectX_Scalar_Scalar_int(image.as_raw__InputOutputArray(), seed_point.opencv_as_extern(), new_val.opencv_as_extern(), rect, lo_diff.opencv_as_extern(), up_diff.opencv_as_extern(), flags, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `flood_fill()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FloodFillBuilder<'a> {
+	image: &'a mut dyn core::ToInputOutputArray,
+	seed_point: core::Point,
+	new_val: core::Scalar,
+	rect: &'a mut core::Rect,
+	lo_diff: core::Scalar,
+	up_diff: core::Scalar,
+	flags: i32,
+}
+
+impl<'a> FloodFillBuilder<'a> {
+	#[inline]
+	pub fn new(image: &'a mut dyn core::ToInputOutputArray, seed_point: core::Point, new_val: core::Scalar, rect: &'a mut core::Rect) -> Self {
+		Self { image, seed_point, new_val, rect, lo_diff: core::Scalar::default(), up_diff: core::Scalar::default(), flags: 4 }
+	}
+	
+	/// C++ default: `Scalar()`
+	#[inline]
+	pub fn with_lo_diff(mut self, lo_diff: core::Scalar) -> Self {
+		self.lo_diff = lo_diff;
+		self
+	}
+	
+	/// C++ default: `Scalar()`
+	#[inline]
+	pub fn with_up_diff(mut self, up_diff: core::Scalar) -> Self {
+		self.up_diff = up_diff;
+		self
+	}
+	
+	/// C++ default: `4`
+	#[inline]
+	pub fn with_flags(mut self, flags: i32) -> Self {
+		self.flags = flags;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<i32> {
+		flood_fill(self.image, self.seed_point, self.new_val, self.rect, self.lo_diff, self.up_diff, self.flags)
+	}
+}
+
 /// Fills a connected component with the given color.
 /// 
 /// The function cv::floodFill fills a connected component starting from the seed point with the specified
@@ -4879,7 +5994,7 @@ pub fn flood_fill(image: &mut dyn core::ToInputOutputArray, seed_point: core::Po
 /// Note: Since the mask is larger than the filled image, a pixel ![inline formula](https://latex.codecogs.com/png.latex?%28x%2C%20y%29) in image corresponds to the
 /// pixel ![inline formula](https://latex.codecogs.com/png.latex?%28x%2B1%2C%20y%2B1%29) in the mask .
 /// ## See also
-/// findContours
+/// [find_contours](crate::imgproc::find_contours)
 /// 
 /// ## C++ default parameters
 /// * rect: 0
@@ -4891,12 +6006,60 @@ pub fn flood_fill_mask(image: &mut dyn core::ToInputOutputArray, mask: &mut dyn
 	input_output_array_arg!(image);
 	input_output_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_floodFill_const__InputOutputArrayR_const__InputOutputArrayR_Point_Scalar_RectX_Scalar_Scalar_int(image.as_raw__InputOutputArray(), mask.as_raw__InputOutputArray(), seed_point.opencv_as_extern(), new_val.opencv_as_extern(), rect, lo_diff.opencv_as_extern(), up_diff.opencv_as_extern(), flags, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_floodFill_const__InputOutputArrayR_const__InputOutputArrayR_Point_Scalar_RectX_Scalar_Scalar_int(image.as_raw__InputOutputArray(), mask.as_raw__InputOutputArray(), seed_point.opencv_as_extern(), new_val.opencv_as_extern(), rect, lo_diff.opencv_as_extern(), up_diff.opencv_as_extern(), flags, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `flood_fill_mask()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FloodFillMaskBuilder<'a> {
+	image: &'a mut dyn core::ToInputOutputArray,
+	mask: &'a mut dyn core::ToInputOutputArray,
+	seed_point: core::Point,
+	new_val: core::Scalar,
+	rect: &'a mut core::Rect,
+	lo_diff: core::Scalar,
+	up_diff: core::Scalar,
+	flags: i32,
+}
+
+impl<'a> FloodFillMaskBuilder<'a> {
+	#[inline]
+	pub fn new(image: &'a mut dyn core::ToInputOutputArray, mask: &'a mut dyn core::ToInputOutputArray, seed_point: core::Point, new_val: core::Scalar, rect: &'a mut core::Rect) -> Self {
+		Self { image, mask, seed_point, new_val, rect, lo_diff: core::Scalar::default(), up_diff: core::Scalar::default(), flags: 4 }
+	}
+	
+	/// C++ default: `Scalar()`
+	#[inline]
+	pub fn with_lo_diff(mut self, lo_diff: core::Scalar) -> Self {
+		self.lo_diff = lo_diff;
+		self
+	}
+	
+	/// C++ default: `Scalar()`
+	#[inline]
+	pub fn with_up_diff(mut self, up_diff: core::Scalar) -> Self {
+		self.up_diff = up_diff;
+		self
+	}
+	
+	/// C++ default: `4`
+	#[inline]
+	pub fn with_flags(mut self, flags: i32) -> Self {
+		self.flags = flags;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<i32> {
+		flood_fill_mask(self.image, self.mask, self.seed_point, self.new_val, self.rect, self.lo_diff, self.up_diff, self.flags)
+	}
+}
+
 /// Calculates an affine transform from three pairs of the corresponding points.
 /// 
 /// The function calculates the ![inline formula](https://latex.codecogs.com/png.latex?2%20%5Ctimes%203) matrix of an affine transform so that:
@@ -4911,11 +6074,11 @@ pub fn flood_fill_mask(image: &mut dyn core::ToInputOutputArray, mask: &mut dyn
 /// * src: Coordinates of triangle vertices in the source image.
 /// * dst: Coordinates of the corresponding triangle vertices in the destination image.
 /// ## See also
-/// warpAffine, transform
+/// [warp_affine](crate::imgproc::warp_affine), [transform](crate::core::transform)
 #[inline]
 pub fn get_affine_transform_slice(src: &[core::Point2f], dst: &[core::Point2f]) -> Result<core::Mat> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_getAffineTransform_const_Point2fX_const_Point2fX(src.as_ptr(), dst.as_ptr(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_getAffineTransform_const_Point2fX_const_Point2fX(src.as_ptr(), dst.as_ptr(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -4927,7 +6090,7 @@ pub fn get_affine_transform(src: &dyn core::ToInputArray, dst: &dyn core::ToInpu
 	input_array_arg!(src);
 	input_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_getAffineTransform_const__InputArrayR_const__InputArrayR(src.as_raw__InputArray(), dst.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_getAffineTransform_const__InputArrayR_const__InputArrayR(src.as_raw__InputArray(), dst.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -4961,12 +6124,45 @@ pub fn get_deriv_kernels(kx: &mut dyn core::ToOutputArray, ky: &mut dyn core::To
 	output_array_arg!(kx);
 	output_array_arg!(ky);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_getDerivKernels_const__OutputArrayR_const__OutputArrayR_int_int_int_bool_int(kx.as_raw__OutputArray(), ky.as_raw__OutputArray(), dx, dy, ksize, normalize, ktype, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_getDerivKernels_const__OutputArrayR_const__OutputArrayR_int_int_int_bool_int(kx.as_raw__OutputArray(), ky.as_raw__OutputArray(), dx, dy, ksize, normalize, ktype, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `get_deriv_kernels()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct GetDerivKernelsBuilder<'a> {
+	kx: &'a mut dyn core::ToOutputArray,
+	ky: &'a mut dyn core::ToOutputArray,
+	dx: i32,
+	dy: i32,
+	ksize: i32,
+	normalize: bool,
+	ktype: i32,
+}
+
+impl<'a> GetDerivKernelsBuilder<'a> {
+	#[inline]
+	pub fn new(kx: &'a mut dyn core::ToOutputArray, ky: &'a mut dyn core::ToOutputArray, dx: i32, dy: i32, ksize: i32, ktype: i32) -> Self {
+		Self { kx, ky, dx, dy, ksize, normalize: false, ktype }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_normalize(mut self, normalize: bool) -> Self {
+		self.normalize = normalize;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		get_deriv_kernels(self.kx, self.ky, self.dx, self.dy, self.ksize, self.normalize, self.ktype)
+	}
+}
+
 /// Calculates the font-specific size to use to achieve a given height in pixels.
 /// 
 /// ## Parameters
@@ -4976,14 +6172,14 @@ pub fn get_deriv_kernels(kx: &mut dyn core::ToOutputArray, ky: &mut dyn core::To
 /// ## Returns
 /// The fontSize to use for cv::putText
 /// ## See also
-/// cv::putText
+/// [put_text](crate::imgproc::put_text)
 /// 
 /// ## C++ default parameters
 /// * thickness: 1
 #[inline]
 pub fn get_font_scale_from_height(font_face: i32, pixel_height: i32, thickness: i32) -> Result<f64> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_getFontScaleFromHeight_const_int_const_int_const_int(font_face, pixel_height, thickness, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_getFontScaleFromHeight_const_int_const_int_const_int(font_face, pixel_height, thickness, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -4991,8 +6187,8 @@ pub fn get_font_scale_from_height(font_face: i32, pixel_height: i32, thickness:
 
 /// Returns Gabor filter coefficients.
 /// 
-/// For more details about gabor filter equations and parameters, see: [Gabor
-/// Filter](http://en.wikipedia.org/wiki/Gabor_filter).
+/// For more details about gabor filter equations and parameters, see: \[Gabor
+/// Filter\](http://en.wikipedia.org/wiki/Gabor_filter).
 /// 
 /// ## Parameters
 /// * ksize: Size of the filter returned.
@@ -5009,7 +6205,7 @@ pub fn get_font_scale_from_height(font_face: i32, pixel_height: i32, thickness:
 #[inline]
 pub fn get_gabor_kernel(ksize: core::Size, sigma: f64, theta: f64, lambd: f64, gamma: f64, psi: f64, ktype: i32) -> Result<core::Mat> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_getGaborKernel_Size_double_double_double_double_double_int(ksize.opencv_as_extern(), sigma, theta, lambd, gamma, psi, ktype, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_getGaborKernel_Size_double_double_double_double_double_int(ksize.opencv_as_extern(), sigma, theta, lambd, gamma, psi, ktype, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -5034,14 +6230,14 @@ pub fn get_gabor_kernel(ksize: core::Size, sigma: f64, theta: f64, lambd: f64, g
 /// `sigma = 0.3*((ksize-1)*0.5 - 1) + 0.8`.
 /// * ktype: Type of filter coefficients. It can be CV_32F or CV_64F .
 /// ## See also
-/// sepFilter2D, getDerivKernels, getStructuringElement, GaussianBlur
+/// [sep_filter_2d](crate::imgproc::sep_filter_2d), [get_deriv_kernels](crate::imgproc::get_deriv_kernels), [get_structuring_element](crate::imgproc::get_structuring_element), [gaussian_blur](crate::imgproc::gaussian_blur)
 /// 
 /// ## C++ default parameters
 /// * ktype: CV_64F
 #[inline]
 pub fn get_gaussian_kernel(ksize: i32, sigma: f64, ktype: i32) -> Result<core::Mat> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_getGaussianKernel_int_double_int(ksize, sigma, ktype, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_getGaussianKernel_int_double_int(ksize, sigma, ktype, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -5063,7 +6259,7 @@ pub fn get_gaussian_kernel(ksize: i32, sigma: f64, ktype: i32) -> Result<core::M
 /// * dst: Coordinates of the corresponding quadrangle vertices in the destination image.
 /// * solveMethod: method passed to cv::solve (#DecompTypes)
 /// ## See also
-/// findHomography, warpPerspective, perspectiveTransform
+/// `find_homography`, [warp_perspective](crate::imgproc::warp_perspective), [perspective_transform](crate::core::perspective_transform)
 /// 
 /// ## Overloaded parameters
 /// 
@@ -5072,7 +6268,7 @@ pub fn get_gaussian_kernel(ksize: i32, sigma: f64, ktype: i32) -> Result<core::M
 #[inline]
 pub fn get_perspective_transform_slice(src: &[core::Point2f], dst: &[core::Point2f], solve_method: i32) -> Result<core::Mat> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_getPerspectiveTransform_const_Point2fX_const_Point2fX_int(src.as_ptr(), dst.as_ptr(), solve_method, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_getPerspectiveTransform_const_Point2fX_const_Point2fX_int(src.as_ptr(), dst.as_ptr(), solve_method, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -5094,7 +6290,7 @@ pub fn get_perspective_transform_slice(src: &[core::Point2f], dst: &[core::Point
 /// * dst: Coordinates of the corresponding quadrangle vertices in the destination image.
 /// * solveMethod: method passed to cv::solve (#DecompTypes)
 /// ## See also
-/// findHomography, warpPerspective, perspectiveTransform
+/// `find_homography`, [warp_perspective](crate::imgproc::warp_perspective), [perspective_transform](crate::core::perspective_transform)
 /// 
 /// ## C++ default parameters
 /// * solve_method: DECOMP_LU
@@ -5103,7 +6299,7 @@ pub fn get_perspective_transform(src: &dyn core::ToInputArray, dst: &dyn core::T
 	input_array_arg!(src);
 	input_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_getPerspectiveTransform_const__InputArrayR_const__InputArrayR_int(src.as_raw__InputArray(), dst.as_raw__InputArray(), solve_method, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_getPerspectiveTransform_const__InputArrayR_const__InputArrayR_int(src.as_raw__InputArray(), dst.as_raw__InputArray(), solve_method, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -5129,7 +6325,7 @@ pub fn get_perspective_transform(src: &dyn core::ToInputArray, dst: &dyn core::T
 /// * patch: Extracted patch that has the size patchSize and the same number of channels as src .
 /// * patchType: Depth of the extracted pixels. By default, they have the same depth as src .
 /// ## See also
-/// warpAffine, warpPerspective
+/// [warp_affine](crate::imgproc::warp_affine), [warp_perspective](crate::imgproc::warp_perspective)
 /// 
 /// ## C++ default parameters
 /// * patch_type: -1
@@ -5138,7 +6334,7 @@ pub fn get_rect_sub_pix(image: &dyn core::ToInputArray, patch_size: core::Size,
 	input_array_arg!(image);
 	output_array_arg!(patch);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_getRectSubPix_const__InputArrayR_Size_Point2f_const__OutputArrayR_int(image.as_raw__InputArray(), patch_size.opencv_as_extern(), center.opencv_as_extern(), patch.as_raw__OutputArray(), patch_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_getRectSubPix_const__InputArrayR_Size_Point2f_const__OutputArrayR_int(image.as_raw__InputArray(), patch_size.opencv_as_extern(), center.opencv_as_extern(), patch.as_raw__OutputArray(), patch_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -5162,11 +6358,11 @@ pub fn get_rect_sub_pix(image: &dyn core::ToInputArray, patch_size: core::Size,
 /// coordinate origin is assumed to be the top-left corner).
 /// * scale: Isotropic scale factor.
 /// ## See also
-/// getAffineTransform, warpAffine, transform
+/// [get_affine_transform](crate::imgproc::get_affine_transform), [warp_affine](crate::imgproc::warp_affine), [transform](crate::core::transform)
 #[inline]
 pub fn get_rotation_matrix_2d(center: core::Point2f, angle: f64, scale: f64) -> Result<core::Mat> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_getRotationMatrix2D_Point2f_double_double(center.opencv_as_extern(), angle, scale, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_getRotationMatrix2D_Point2f_double_double(center.opencv_as_extern(), angle, scale, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -5174,12 +6370,12 @@ pub fn get_rotation_matrix_2d(center: core::Point2f, angle: f64, scale: f64) ->
 }
 
 /// ## See also
-/// getRotationMatrix2D
+/// [get_rotation_matrix_2d](crate::imgproc::get_rotation_matrix_2d)
 #[inline]
 #[cfg(not(target_os = "windows"))]
 pub fn get_rotation_matrix_2d_matx(center: core::Point2f, angle: f64, scale: f64) -> Result<core::Matx23d> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_getRotationMatrix2D__Point2f_double_double(center.opencv_as_extern(), angle, scale, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_getRotationMatrix2D__Point2f_double_double(center.opencv_as_extern(), angle, scale, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -5204,7 +6400,7 @@ pub fn get_rotation_matrix_2d_matx(center: core::Point2f, angle: f64, scale: f64
 #[inline]
 pub fn get_structuring_element(shape: i32, ksize: core::Size, anchor: core::Point) -> Result<core::Mat> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_getStructuringElement_int_Size_Point(shape, ksize.opencv_as_extern(), anchor.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_getStructuringElement_int_Size_Point(shape, ksize.opencv_as_extern(), anchor.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -5252,17 +6448,17 @@ pub fn get_structuring_element(shape: i32, ksize: core::Size, anchor: core::Poin
 /// * fontFace: Font to use, see #HersheyFonts.
 /// * fontScale: Font scale factor that is multiplied by the font-specific base size.
 /// * thickness: Thickness of lines used to render the text. See #putText for details.
-/// * baseLine:[out] y-coordinate of the baseline relative to the bottom-most text
+/// * baseLine:\[out\] y-coordinate of the baseline relative to the bottom-most text
 /// point.
 /// ## Returns
 /// The size of a box that contains the specified text.
 /// ## See also
-/// putText
+/// [put_text](crate::imgproc::put_text)
 #[inline]
 pub fn get_text_size(text: &str, font_face: i32, font_scale: f64, thickness: i32, base_line: &mut i32) -> Result<core::Size> {
 	extern_container_arg!(text);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_getTextSize_const_StringR_int_double_int_intX(text.opencv_as_extern(), font_face, font_scale, thickness, base_line, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_getTextSize_const_StringR_int_double_int_intX(text.opencv_as_extern(), font_face, font_scale, thickness, base_line, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -5306,12 +6502,70 @@ pub fn good_features_to_track_with_quality(image: &dyn core::ToInputArray, corne
 	input_array_arg!(mask);
 	output_array_arg!(corners_quality);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_goodFeaturesToTrack_const__InputArrayR_const__OutputArrayR_int_double_double_const__InputArrayR_const__OutputArrayR_int_int_bool_double(image.as_raw__InputArray(), corners.as_raw__OutputArray(), max_corners, quality_level, min_distance, mask.as_raw__InputArray(), corners_quality.as_raw__OutputArray(), block_size, gradient_size, use_harris_detector, k, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_goodFeaturesToTrack_const__InputArrayR_const__OutputArrayR_int_double_double_const__InputArrayR_const__OutputArrayR_int_int_bool_double(image.as_raw__InputArray(), corners.as_raw__OutputArray(), max_corners, quality_level, min_distance, mask.as_raw__InputArray(), corners_quality.as_raw__OutputArray(), block_size, gradient_size, use_harris_detector, k, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `good_features_to_track_with_quality()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct GoodFeaturesToTrackWithQualityBuilder<'a> {
+	image: &'a dyn core::ToInputArray,
+	corners: &'a mut dyn core::ToOutputArray,
+	max_corners: i32,
+	quality_level: f64,
+	min_distance: f64,
+	mask: &'a dyn core::ToInputArray,
+	corners_quality: &'a mut dyn core::ToOutputArray,
+	block_size: i32,
+	gradient_size: i32,
+	use_harris_detector: bool,
+	k: f64,
+}
+
+impl<'a> GoodFeaturesToTrackWithQualityBuilder<'a> {
+	#[inline]
+	pub fn new(image: &'a dyn core::ToInputArray, corners: &'a mut dyn core::ToOutputArray, max_corners: i32, quality_level: f64, min_distance: f64, mask: &'a dyn core::ToInputArray, corners_quality: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { image, corners, max_corners, quality_level, min_distance, mask, corners_quality, block_size: 3, gradient_size: 3, use_harris_detector: false, k: 0.04 }
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_block_size(mut self, block_size: i32) -> Self {
+		self.block_size = block_size;
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_gradient_size(mut self, gradient_size: i32) -> Self {
+		self.gradient_size = gradient_size;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_use_harris_detector(mut self, use_harris_detector: bool) -> Self {
+		self.use_harris_detector = use_harris_detector;
+		self
+	}
+	
+	/// C++ default: `0.04`
+	#[inline]
+	pub fn with_k(mut self, k: f64) -> Self {
+		self.k = k;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		good_features_to_track_with_quality(self.image, self.corners, self.max_corners, self.quality_level, self.min_distance, self.mask, self.corners_quality, self.block_size, self.gradient_size, self.use_harris_detector, self.k)
+	}
+}
+
 /// Determines strong corners on an image.
 /// 
 /// The function finds the most prominent corners in the image or in the specified image region, as
@@ -5355,7 +6609,7 @@ pub fn good_features_to_track_with_quality(image: &dyn core::ToInputArray, corne
 /// or #cornerMinEigenVal.
 /// * k: Free parameter of the Harris detector.
 /// ## See also
-/// cornerMinEigenVal, cornerHarris, calcOpticalFlowPyrLK, estimateRigidTransform,
+/// [corner_min_eigen_val](crate::imgproc::corner_min_eigen_val), [corner_harris](crate::imgproc::corner_harris), `calc_optical_flow_pyr_lk`, `estimate_rigid_transform`,
 /// 
 /// ## C++ default parameters
 /// * mask: noArray()
@@ -5368,12 +6622,70 @@ pub fn good_features_to_track(image: &dyn core::ToInputArray, corners: &mut dyn
 	output_array_arg!(corners);
 	input_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_goodFeaturesToTrack_const__InputArrayR_const__OutputArrayR_int_double_double_const__InputArrayR_int_bool_double(image.as_raw__InputArray(), corners.as_raw__OutputArray(), max_corners, quality_level, min_distance, mask.as_raw__InputArray(), block_size, use_harris_detector, k, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_goodFeaturesToTrack_const__InputArrayR_const__OutputArrayR_int_double_double_const__InputArrayR_int_bool_double(image.as_raw__InputArray(), corners.as_raw__OutputArray(), max_corners, quality_level, min_distance, mask.as_raw__InputArray(), block_size, use_harris_detector, k, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `good_features_to_track()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct GoodFeaturesToTrackBuilder<'a> {
+	image: &'a dyn core::ToInputArray,
+	corners: &'a mut dyn core::ToOutputArray,
+	max_corners: i32,
+	quality_level: f64,
+	min_distance: f64,
+	mask: Option<&'a dyn core::ToInputArray>,
+	block_size: i32,
+	use_harris_detector: bool,
+	k: f64,
+}
+
+impl<'a> GoodFeaturesToTrackBuilder<'a> {
+	#[inline]
+	pub fn new(image: &'a dyn core::ToInputArray, corners: &'a mut dyn core::ToOutputArray, max_corners: i32, quality_level: f64, min_distance: f64) -> Self {
+		Self { image, corners, max_corners, quality_level, min_distance, mask: None, block_size: 3, use_harris_detector: false, k: 0.04 }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_mask(mut self, mask: &'a dyn core::ToInputArray) -> Self {
+		self.mask = Some(mask);
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_block_size(mut self, block_size: i32) -> Self {
+		self.block_size = block_size;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_use_harris_detector(mut self, use_harris_detector: bool) -> Self {
+		self.use_harris_detector = use_harris_detector;
+		self
+	}
+	
+	/// C++ default: `0.04`
+	#[inline]
+	pub fn with_k(mut self, k: f64) -> Self {
+		self.k = k;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let mask_no_array = core::no_array();
+		let mask: &dyn core::ToInputArray = match self.mask { Some(mask) => mask, None => &mask_no_array };
+		good_features_to_track(self.image, self.corners, self.max_corners, self.quality_level, self.min_distance, mask, self.block_size, self.use_harris_detector, self.k)
+	}
+}
+
 /// ## C++ default parameters
 /// * use_harris_detector: false
 /// * k: 0.04
@@ -5383,12 +6695,55 @@ pub fn good_features_to_track_with_gradient(image: &dyn core::ToInputArray, corn
 	output_array_arg!(corners);
 	input_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_goodFeaturesToTrack_const__InputArrayR_const__OutputArrayR_int_double_double_const__InputArrayR_int_int_bool_double(image.as_raw__InputArray(), corners.as_raw__OutputArray(), max_corners, quality_level, min_distance, mask.as_raw__InputArray(), block_size, gradient_size, use_harris_detector, k, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_goodFeaturesToTrack_const__InputArrayR_const__OutputArrayR_int_double_double_const__InputArrayR_int_int_bool_double(image.as_raw__InputArray(), corners.as_raw__OutputArray(), max_corners, quality_level, min_distance, mask.as_raw__InputArray(), block_size, gradient_size, use_harris_detector, k, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `good_features_to_track_with_gradient()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct GoodFeaturesToTrackWithGradientBuilder<'a> {
+	image: &'a dyn core::ToInputArray,
+	corners: &'a mut dyn core::ToOutputArray,
+	max_corners: i32,
+	quality_level: f64,
+	min_distance: f64,
+	mask: &'a dyn core::ToInputArray,
+	block_size: i32,
+	gradient_size: i32,
+	use_harris_detector: bool,
+	k: f64,
+}
+
+impl<'a> GoodFeaturesToTrackWithGradientBuilder<'a> {
+	#[inline]
+	pub fn new(image: &'a dyn core::ToInputArray, corners: &'a mut dyn core::ToOutputArray, max_corners: i32, quality_level: f64, min_distance: f64, mask: &'a dyn core::ToInputArray, block_size: i32, gradient_size: i32) -> Self {
+		Self { image, corners, max_corners, quality_level, min_distance, mask, block_size, gradient_size, use_harris_detector: false, k: 0.04 }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_use_harris_detector(mut self, use_harris_detector: bool) -> Self {
+		self.use_harris_detector = use_harris_detector;
+		self
+	}
+	
+	/// C++ default: `0.04`
+	#[inline]
+	pub fn with_k(mut self, k: f64) -> Self {
+		self.k = k;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		good_features_to_track_with_gradient(self.image, self.corners, self.max_corners, self.quality_level, self.min_distance, self.mask, self.block_size, self.gradient_size, self.use_harris_detector, self.k)
+	}
+}
+
 /// Runs the GrabCut algorithm.
 /// 
 /// The function implements the [GrabCut image segmentation algorithm](http://en.wikipedia.org/wiki/GrabCut).
@@ -5417,7 +6772,7 @@ pub fn grab_cut(img: &dyn core::ToInputArray, mask: &mut dyn core::ToInputOutput
 	input_output_array_arg!(bgd_model);
 	input_output_array_arg!(fgd_model);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_grabCut_const__InputArrayR_const__InputOutputArrayR_Rect_const__InputOutputArrayR_const__InputOutputArrayR_int_int(img.as_raw__InputArray(), mask.as_raw__InputOutputArray(), rect.opencv_as_extern(), bgd_model.as_raw__InputOutputArray(), fgd_model.as_raw__InputOutputArray(), iter_count, mode, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_grabCut_const__InputArrayR_const__InputOutputArrayR_Rect_const__InputOutputArrayR_const__InputOutputArrayR_int_int(img.as_raw__InputArray(), mask.as_raw__InputOutputArray(), rect.opencv_as_extern(), bgd_model.as_raw__InputOutputArray(), fgd_model.as_raw__InputOutputArray(), iter_count, mode, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -5468,12 +6823,51 @@ pub fn integral3(src: &dyn core::ToInputArray, sum: &mut dyn core::ToOutputArray
 	output_array_arg!(sqsum);
 	output_array_arg!(tilted);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_integral_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int_int(src.as_raw__InputArray(), sum.as_raw__OutputArray(), sqsum.as_raw__OutputArray(), tilted.as_raw__OutputArray(), sdepth, sqdepth, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_integral_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int_int(src.as_raw__InputArray(), sum.as_raw__OutputArray(), sqsum.as_raw__OutputArray(), tilted.as_raw__OutputArray(), sdepth, sqdepth, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `integral3()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct Integral3Builder<'a> {
+	src: &'a dyn core::ToInputArray,
+	sum: &'a mut dyn core::ToOutputArray,
+	sqsum: &'a mut dyn core::ToOutputArray,
+	tilted: &'a mut dyn core::ToOutputArray,
+	sdepth: i32,
+	sqdepth: i32,
+}
+
+impl<'a> Integral3Builder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, sum: &'a mut dyn core::ToOutputArray, sqsum: &'a mut dyn core::ToOutputArray, tilted: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { src, sum, sqsum, tilted, sdepth: -1, sqdepth: -1 }
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_sdepth(mut self, sdepth: i32) -> Self {
+		self.sdepth = sdepth;
+		self
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_sqdepth(mut self, sqdepth: i32) -> Self {
+		self.sqdepth = sqdepth;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		integral3(self.src, self.sum, self.sqsum, self.tilted, self.sdepth, self.sqdepth)
+	}
+}
+
 /// Calculates the integral of an image.
 /// 
 /// The function calculates one or more integral images for the source image as follows:
@@ -5520,7 +6914,7 @@ pub fn integral2(src: &dyn core::ToInputArray, sum: &mut dyn core::ToOutputArray
 	output_array_arg!(sum);
 	output_array_arg!(sqsum);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_integral_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_int_int(src.as_raw__InputArray(), sum.as_raw__OutputArray(), sqsum.as_raw__OutputArray(), sdepth, sqdepth, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_integral_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_int_int(src.as_raw__InputArray(), sum.as_raw__OutputArray(), sqsum.as_raw__OutputArray(), sdepth, sqdepth, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -5570,7 +6964,7 @@ pub fn integral(src: &dyn core::ToInputArray, sum: &mut dyn core::ToOutputArray,
 	input_array_arg!(src);
 	output_array_arg!(sum);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_integral_const__InputArrayR_const__OutputArrayR_int(src.as_raw__InputArray(), sum.as_raw__OutputArray(), sdepth, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_integral_const__InputArrayR_const__OutputArrayR_int(src.as_raw__InputArray(), sum.as_raw__OutputArray(), sdepth, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -5600,7 +6994,7 @@ pub fn intersect_convex_convex(p1: &dyn core::ToInputArray, p2: &dyn core::ToInp
 	input_array_arg!(p2);
 	output_array_arg!(p12);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_intersectConvexConvex_const__InputArrayR_const__InputArrayR_const__OutputArrayR_bool(p1.as_raw__InputArray(), p2.as_raw__InputArray(), p12.as_raw__OutputArray(), handle_nested, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_intersectConvexConvex_const__InputArrayR_const__InputArrayR_const__OutputArrayR_bool(p1.as_raw__InputArray(), p2.as_raw__InputArray(), p12.as_raw__OutputArray(), handle_nested, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -5622,7 +7016,7 @@ pub fn invert_affine_transform(m: &dyn core::ToInputArray, i_m: &mut dyn core::T
 	input_array_arg!(m);
 	output_array_arg!(i_m);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_invertAffineTransform_const__InputArrayR_const__OutputArrayR(m.as_raw__InputArray(), i_m.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_invertAffineTransform_const__InputArrayR_const__OutputArrayR(m.as_raw__InputArray(), i_m.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -5639,7 +7033,7 @@ pub fn invert_affine_transform(m: &dyn core::ToInputArray, i_m: &mut dyn core::T
 pub fn is_contour_convex(contour: &dyn core::ToInputArray) -> Result<bool> {
 	input_array_arg!(contour);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_isContourConvex_const__InputArrayR(contour.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_isContourConvex_const__InputArrayR(contour.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -5669,19 +7063,59 @@ pub fn is_contour_convex(contour: &dyn core::ToInputArray) -> Result<bool> {
 pub fn line(img: &mut dyn core::ToInputOutputArray, pt1: core::Point, pt2: core::Point, color: core::Scalar, thickness: i32, line_type: i32, shift: i32) -> Result<()> {
 	input_output_array_arg!(img);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_line_const__InputOutputArrayR_Point_Point_const_ScalarR_int_int_int(img.as_raw__InputOutputArray(), pt1.opencv_as_extern(), pt2.opencv_as_extern(), &color, thickness, line_type, shift, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_line_const__InputOutputArrayR_Point_Point_const_ScalarR_int_int_int(img.as_raw__InputOutputArray(), pt1.opencv_as_extern(), pt2.opencv_as_extern(), &color, thickness, line_type, shift, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `line()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct LineBuilder<'a> {
+	img: &'a mut dyn core::ToInputOutputArray,
+	pt1: core::Point,
+	pt2: core::Point,
+	color: core::Scalar,
+	thickness: i32,
+	line_type: i32,
+	shift: i32,
+}
+
+impl<'a> LineBuilder<'a> {
+	#[inline]
+	pub fn new(img: &'a mut dyn core::ToInputOutputArray, pt1: core::Point, pt2: core::Point, color: core::Scalar, line_type: i32) -> Self {
+		Self { img, pt1, pt2, color, thickness: 1, line_type, shift: 0 }
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_thickness(mut self, thickness: i32) -> Self {
+		self.thickness = thickness;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_shift(mut self, shift: i32) -> Self {
+		self.shift = shift;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		line(self.img, self.pt1, self.pt2, self.color, self.thickness, self.line_type, self.shift)
+	}
+}
+
 /// Remaps an image to polar coordinates space.
 /// 
 /// 
 /// **Deprecated**: This function produces same result as cv::warpPolar(src, dst, src.size(), center, maxRadius, flags)
 /// 
 /// @internal
-/// Transform the source image using the following transformation (See @ref polar_remaps_reference_image "Polar remaps reference image c)"):
+/// Transform the source image using the following transformation (See Polar remaps reference image c)):
 /// ![block formula](https://latex.codecogs.com/png.latex?%5Cbegin%7Barray%7D%7Bl%7D%0A%20%20dst%28%20%5Crho%20%2C%20%5Cphi%20%29%20%3D%20src%28x%2Cy%29%20%5C%5C%0A%20%20dst%2Esize%28%29%20%5Cleftarrow%20src%2Esize%28%29%0A%5Cend%7Barray%7D)
 /// 
 /// where
@@ -5703,7 +7137,7 @@ pub fn line(img: &mut dyn core::ToInputOutputArray, pt1: core::Point, pt2: core:
 /// *   The function can not operate in-place.
 /// *   To calculate magnitude and angle in degrees #cartToPolar is used internally thus angles are measured from 0 to 360 with accuracy about 0.3 degrees.
 /// ## See also
-/// cv::logPolar
+/// [log_polar](crate::imgproc::log_polar)
 /// @endinternal
 #[deprecated = "This function produces same result as cv::warpPolar(src, dst, src.size(), center, maxRadius, flags)"]
 #[inline]
@@ -5711,7 +7145,7 @@ pub fn linear_polar(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputAr
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_linearPolar_const__InputArrayR_const__OutputArrayR_Point2f_double_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), center.opencv_as_extern(), max_radius, flags, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_linearPolar_const__InputArrayR_const__OutputArrayR_Point2f_double_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), center.opencv_as_extern(), max_radius, flags, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -5723,7 +7157,7 @@ pub fn linear_polar(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputAr
 /// **Deprecated**: This function produces same result as cv::warpPolar(src, dst, src.size(), center, maxRadius, flags+WARP_POLAR_LOG);
 /// 
 /// @internal
-/// Transform the source image using the following transformation (See @ref polar_remaps_reference_image "Polar remaps reference image d)"):
+/// Transform the source image using the following transformation (See Polar remaps reference image d)):
 /// ![block formula](https://latex.codecogs.com/png.latex?%5Cbegin%7Barray%7D%7Bl%7D%0A%20%20dst%28%20%5Crho%20%2C%20%5Cphi%20%29%20%3D%20src%28x%2Cy%29%20%5C%5C%0A%20%20dst%2Esize%28%29%20%5Cleftarrow%20src%2Esize%28%29%0A%5Cend%7Barray%7D)
 /// 
 /// where
@@ -5746,7 +7180,7 @@ pub fn linear_polar(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputAr
 /// *   The function can not operate in-place.
 /// *   To calculate magnitude and angle in degrees #cartToPolar is used internally thus angles are measured from 0 to 360 with accuracy about 0.3 degrees.
 /// ## See also
-/// cv::linearPolar
+/// [linear_polar](crate::imgproc::linear_polar)
 /// @endinternal
 #[deprecated = "This function produces same result as cv::warpPolar(src, dst, src.size(), center, maxRadius, flags+WARP_POLAR_LOG);"]
 #[inline]
@@ -5754,7 +7188,7 @@ pub fn log_polar(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_logPolar_const__InputArrayR_const__OutputArrayR_Point2f_double_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), center.opencv_as_extern(), m, flags, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_logPolar_const__InputArrayR_const__OutputArrayR_Point2f_double_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), center.opencv_as_extern(), m, flags, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -5774,7 +7208,7 @@ pub fn match_shapes(contour1: &dyn core::ToInputArray, contour2: &dyn core::ToIn
 	input_array_arg!(contour1);
 	input_array_arg!(contour2);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_matchShapes_const__InputArrayR_const__InputArrayR_int_double(contour1.as_raw__InputArray(), contour2.as_raw__InputArray(), method, parameter, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_matchShapes_const__InputArrayR_const__InputArrayR_int_double(contour1.as_raw__InputArray(), contour2.as_raw__InputArray(), method, parameter, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -5818,7 +7252,7 @@ pub fn match_template(image: &dyn core::ToInputArray, templ: &dyn core::ToInputA
 	output_array_arg!(result);
 	input_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_matchTemplate_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_const__InputArrayR(image.as_raw__InputArray(), templ.as_raw__InputArray(), result.as_raw__OutputArray(), method, mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_matchTemplate_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_const__InputArrayR(image.as_raw__InputArray(), templ.as_raw__InputArray(), result.as_raw__OutputArray(), method, mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -5838,13 +7272,13 @@ pub fn match_template(image: &dyn core::ToInputArray, templ: &dyn core::ToInputA
 /// * dst: destination array of the same size and type as src.
 /// * ksize: aperture linear size; it must be odd and greater than 1, for example: 3, 5, 7 ...
 /// ## See also
-/// bilateralFilter, blur, boxFilter, GaussianBlur
+/// [bilateral_filter](crate::imgproc::bilateral_filter), [blur](crate::imgproc::blur), [box_filter](crate::imgproc::box_filter), [gaussian_blur](crate::imgproc::gaussian_blur)
 #[inline]
 pub fn median_blur(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, ksize: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_medianBlur_const__InputArrayR_const__OutputArrayR_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ksize, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_medianBlur_const__InputArrayR_const__OutputArrayR_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ksize, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -5862,7 +7296,7 @@ pub fn median_blur(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArr
 pub fn min_area_rect(points: &dyn core::ToInputArray) -> Result<core::RotatedRect> {
 	input_array_arg!(points);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_minAreaRect_const__InputArrayR(points.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_minAreaRect_const__InputArrayR(points.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::RotatedRect::opencv_from_extern(ret) };
@@ -5881,7 +7315,7 @@ pub fn min_area_rect(points: &dyn core::ToInputArray) -> Result<core::RotatedRec
 pub fn min_enclosing_circle(points: &dyn core::ToInputArray, center: &mut core::Point2f, radius: &mut f32) -> Result<()> {
 	input_array_arg!(points);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_minEnclosingCircle_const__InputArrayR_Point2fR_floatR(points.as_raw__InputArray(), center, radius, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_minEnclosingCircle_const__InputArrayR_Point2fR_floatR(points.as_raw__InputArray(), center, radius, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -5911,7 +7345,7 @@ pub fn min_enclosing_triangle(points: &dyn core::ToInputArray, triangle: &mut dy
 	input_array_arg!(points);
 	output_array_arg!(triangle);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_minEnclosingTriangle_const__InputArrayR_const__OutputArrayR(points.as_raw__InputArray(), triangle.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_minEnclosingTriangle_const__InputArrayR_const__OutputArrayR(points.as_raw__InputArray(), triangle.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -5934,7 +7368,7 @@ pub fn min_enclosing_triangle(points: &dyn core::ToInputArray, triangle: &mut dy
 /// Note: Only applicable to contour moments calculations from Python bindings: Note that the numpy
 /// type for the input array should be either np.int32 or np.float32.
 /// ## See also
-/// contourArea, arcLength
+/// [contour_area](crate::imgproc::contour_area), [arc_length](crate::imgproc::arc_length)
 /// 
 /// ## C++ default parameters
 /// * binary_image: false
@@ -5942,7 +7376,7 @@ pub fn min_enclosing_triangle(points: &dyn core::ToInputArray, triangle: &mut dy
 pub fn moments(array: &dyn core::ToInputArray, binary_image: bool) -> Result<core::Moments> {
 	input_array_arg!(array);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_moments_const__InputArrayR_bool(array.as_raw__InputArray(), binary_image, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_moments_const__InputArrayR_bool(array.as_raw__InputArray(), binary_image, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -5952,7 +7386,7 @@ pub fn moments(array: &dyn core::ToInputArray, binary_image: bool) -> Result<cor
 #[inline]
 pub fn morphology_default_border_value() -> Result<core::Scalar> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_morphologyDefaultBorderValue(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_morphologyDefaultBorderValue(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -5979,7 +7413,7 @@ pub fn morphology_default_border_value() -> Result<core::Scalar> {
 /// * borderValue: Border value in case of a constant border. The default value has a special
 /// meaning.
 /// ## See also
-/// dilate, erode, getStructuringElement
+/// [dilate](crate::imgproc::dilate), [erode](crate::imgproc::erode), [get_structuring_element](crate::imgproc::get_structuring_element)
 /// 
 /// Note: The number of iterations is the number of times erosion or dilatation operation will be applied.
 /// For instance, an opening operation (#MORPH_OPEN) with two iterations is equivalent to apply
@@ -5996,12 +7430,53 @@ pub fn morphology_ex(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputA
 	output_array_arg!(dst);
 	input_array_arg!(kernel);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_morphologyEx_const__InputArrayR_const__OutputArrayR_int_const__InputArrayR_Point_int_int_const_ScalarR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), op, kernel.as_raw__InputArray(), anchor.opencv_as_extern(), iterations, border_type, &border_value, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_morphologyEx_const__InputArrayR_const__OutputArrayR_int_const__InputArrayR_Point_int_int_const_ScalarR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), op, kernel.as_raw__InputArray(), anchor.opencv_as_extern(), iterations, border_type, &border_value, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `morphology_ex()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct MorphologyExBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	op: i32,
+	kernel: &'a dyn core::ToInputArray,
+	anchor: core::Point,
+	iterations: i32,
+	border_type: i32,
+	border_value: core::Scalar,
+}
+
+impl<'a> MorphologyExBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, op: i32, kernel: &'a dyn core::ToInputArray, border_type: i32, border_value: core::Scalar) -> Self {
+		Self { src, dst, op, kernel, anchor: core::Point::new(-1, -1), iterations: 1, border_type, border_value }
+	}
+	
+	/// C++ default: `Point(-1,-1)`
+	#[inline]
+	pub fn with_anchor(mut self, anchor: core::Point) -> Self {
+		self.anchor = anchor;
+		self
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_iterations(mut self, iterations: i32) -> Self {
+		self.iterations = iterations;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		morphology_ex(self.src, self.dst, self.op, self.kernel, self.anchor, self.iterations, self.border_type, self.border_value)
+	}
+}
+
 /// The function is used to detect translational shifts that occur between two images.
 /// 
 /// The operation takes advantage of the Fourier shift theorem for detecting the translational shift in
@@ -6048,7 +7523,7 @@ pub fn phase_correlate(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputAr
 	input_array_arg!(src2);
 	input_array_arg!(window);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_phaseCorrelate_const__InputArrayR_const__InputArrayR_const__InputArrayR_doubleX(src1.as_raw__InputArray(), src2.as_raw__InputArray(), window.as_raw__InputArray(), response, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_phaseCorrelate_const__InputArrayR_const__InputArrayR_const__InputArrayR_doubleX(src1.as_raw__InputArray(), src2.as_raw__InputArray(), window.as_raw__InputArray(), response, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -6074,7 +7549,7 @@ pub fn phase_correlate(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputAr
 pub fn point_polygon_test(contour: &dyn core::ToInputArray, pt: core::Point2f, measure_dist: bool) -> Result<f64> {
 	input_array_arg!(contour);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_pointPolygonTest_const__InputArrayR_Point2f_bool(contour.as_raw__InputArray(), pt.opencv_as_extern(), measure_dist, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_pointPolygonTest_const__InputArrayR_Point2f_bool(contour.as_raw__InputArray(), pt.opencv_as_extern(), measure_dist, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -6103,12 +7578,52 @@ pub fn polylines(img: &mut dyn core::ToInputOutputArray, pts: &dyn core::ToInput
 	input_output_array_arg!(img);
 	input_array_arg!(pts);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_polylines_const__InputOutputArrayR_const__InputArrayR_bool_const_ScalarR_int_int_int(img.as_raw__InputOutputArray(), pts.as_raw__InputArray(), is_closed, &color, thickness, line_type, shift, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_polylines_const__InputOutputArrayR_const__InputArrayR_bool_const_ScalarR_int_int_int(img.as_raw__InputOutputArray(), pts.as_raw__InputArray(), is_closed, &color, thickness, line_type, shift, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `polylines()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct PolylinesBuilder<'a> {
+	img: &'a mut dyn core::ToInputOutputArray,
+	pts: &'a dyn core::ToInputArray,
+	is_closed: bool,
+	color: core::Scalar,
+	thickness: i32,
+	line_type: i32,
+	shift: i32,
+}
+
+impl<'a> PolylinesBuilder<'a> {
+	#[inline]
+	pub fn new(img: &'a mut dyn core::ToInputOutputArray, pts: &'a dyn core::ToInputArray, is_closed: bool, color: core::Scalar, line_type: i32) -> Self {
+		Self { img, pts, is_closed, color, thickness: 1, line_type, shift: 0 }
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_thickness(mut self, thickness: i32) -> Self {
+		self.thickness = thickness;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_shift(mut self, shift: i32) -> Self {
+		self.shift = shift;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		polylines(self.img, self.pts, self.is_closed, self.color, self.thickness, self.line_type, self.shift)
+	}
+}
+
 /// Calculates a feature map for corner detection.
 /// 
 /// The function calculates the complex spatial derivative-based function of the source image
@@ -6141,7 +7656,7 @@ pub fn pre_corner_detect(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOut
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_preCornerDetect_const__InputArrayR_const__OutputArrayR_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ksize, border_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_preCornerDetect_const__InputArrayR_const__OutputArrayR_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ksize, border_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -6174,12 +7689,54 @@ pub fn put_text(img: &mut dyn core::ToInputOutputArray, text: &str, org: core::P
 	input_output_array_arg!(img);
 	extern_container_arg!(text);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_putText_const__InputOutputArrayR_const_StringR_Point_int_double_Scalar_int_int_bool(img.as_raw__InputOutputArray(), text.opencv_as_extern(), org.opencv_as_extern(), font_face, font_scale, color.opencv_as_extern(), thickness, line_type, bottom_left_origin, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_putText_const__InputOutputArrayR_const_StringR_Point_int_double_Scalar_int_int_bool(img.as_raw__InputOutputArray(), text.opencv_as_extern(), org.opencv_as_extern(), font_face, font_scale, color.opencv_as_extern(), thickness, line_type, bottom_left_origin, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `put_text()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct PutTextBuilder<'a> {
+	img: &'a mut dyn core::ToInputOutputArray,
+	text: &'a str,
+	org: core::Point,
+	font_face: i32,
+	font_scale: f64,
+	color: core::Scalar,
+	thickness: i32,
+	line_type: i32,
+	bottom_left_origin: bool,
+}
+
+impl<'a> PutTextBuilder<'a> {
+	#[inline]
+	pub fn new(img: &'a mut dyn core::ToInputOutputArray, text: &'a str, org: core::Point, font_face: i32, font_scale: f64, color: core::Scalar, line_type: i32) -> Self {
+		Self { img, text, org, font_face, font_scale, color, thickness: 1, line_type, bottom_left_origin: false }
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_thickness(mut self, thickness: i32) -> Self {
+		self.thickness = thickness;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_bottom_left_origin(mut self, bottom_left_origin: bool) -> Self {
+		self.bottom_left_origin = bottom_left_origin;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		put_text(self.img, self.text, self.org, self.font_face, self.font_scale, self.color, self.thickness, self.line_type, self.bottom_left_origin)
+	}
+}
+
 /// Blurs an image and downsamples it.
 /// 
 /// By default, size of the output image is computed as `Size((src.cols+1)/2, (src.rows+1)/2)`, but in
@@ -6208,7 +7765,7 @@ pub fn pyr_down(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray,
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_pyrDown_const__InputArrayR_const__OutputArrayR_const_SizeR_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), &dstsize, border_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_pyrDown_const__InputArrayR_const__OutputArrayR_const_SizeR_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), &dstsize, border_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -6259,12 +7816,44 @@ pub fn pyr_mean_shift_filtering(src: &dyn core::ToInputArray, dst: &mut dyn core
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_pyrMeanShiftFiltering_const__InputArrayR_const__OutputArrayR_double_double_int_TermCriteria(src.as_raw__InputArray(), dst.as_raw__OutputArray(), sp, sr, max_level, termcrit.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_pyrMeanShiftFiltering_const__InputArrayR_const__OutputArrayR_double_double_int_TermCriteria(src.as_raw__InputArray(), dst.as_raw__OutputArray(), sp, sr, max_level, termcrit.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `pyr_mean_shift_filtering()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct PyrMeanShiftFilteringBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	sp: f64,
+	sr: f64,
+	max_level: i32,
+	termcrit: core::TermCriteria,
+}
+
+impl<'a> PyrMeanShiftFilteringBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, sp: f64, sr: f64, termcrit: core::TermCriteria) -> Self {
+		Self { src, dst, sp, sr, max_level: 1, termcrit }
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_max_level(mut self, max_level: i32) -> Self {
+		self.max_level = max_level;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		pyr_mean_shift_filtering(self.src, self.dst, self.sp, self.sr, self.max_level, self.termcrit)
+	}
+}
+
 /// Upsamples an image and then blurs it.
 /// 
 /// By default, size of the output image is computed as `Size(src.cols\*2, (src.rows\*2)`, but in any
@@ -6291,7 +7880,7 @@ pub fn pyr_up(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, d
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_pyrUp_const__InputArrayR_const__OutputArrayR_const_SizeR_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), &dstsize, border_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_pyrUp_const__InputArrayR_const__OutputArrayR_const_SizeR_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), &dstsize, border_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -6320,12 +7909,52 @@ pub fn pyr_up(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, d
 pub fn rectangle_points(img: &mut dyn core::ToInputOutputArray, pt1: core::Point, pt2: core::Point, color: core::Scalar, thickness: i32, line_type: i32, shift: i32) -> Result<()> {
 	input_output_array_arg!(img);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_rectangle_const__InputOutputArrayR_Point_Point_const_ScalarR_int_int_int(img.as_raw__InputOutputArray(), pt1.opencv_as_extern(), pt2.opencv_as_extern(), &color, thickness, line_type, shift, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_rectangle_const__InputOutputArrayR_Point_Point_const_ScalarR_int_int_int(img.as_raw__InputOutputArray(), pt1.opencv_as_extern(), pt2.opencv_as_extern(), &color, thickness, line_type, shift, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `rectangle_points()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct RectanglePointsBuilder<'a> {
+	img: &'a mut dyn core::ToInputOutputArray,
+	pt1: core::Point,
+	pt2: core::Point,
+	color: core::Scalar,
+	thickness: i32,
+	line_type: i32,
+	shift: i32,
+}
+
+impl<'a> RectanglePointsBuilder<'a> {
+	#[inline]
+	pub fn new(img: &'a mut dyn core::ToInputOutputArray, pt1: core::Point, pt2: core::Point, color: core::Scalar, line_type: i32) -> Self {
+		Self { img, pt1, pt2, color, thickness: 1, line_type, shift: 0 }
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_thickness(mut self, thickness: i32) -> Self {
+		self.thickness = thickness;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_shift(mut self, shift: i32) -> Self {
+		self.shift = shift;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		rectangle_points(self.img, self.pt1, self.pt2, self.color, self.thickness, self.line_type, self.shift)
+	}
+}
+
 /// Draws a simple, thick, or filled up-right rectangle.
 /// 
 /// The function cv::rectangle draws a rectangle outline or a filled rectangle whose two opposite corners
@@ -6355,12 +7984,51 @@ pub fn rectangle_points(img: &mut dyn core::ToInputOutputArray, pt1: core::Point
 pub fn rectangle(img: &mut dyn core::ToInputOutputArray, rec: core::Rect, color: core::Scalar, thickness: i32, line_type: i32, shift: i32) -> Result<()> {
 	input_output_array_arg!(img);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_rectangle_const__InputOutputArrayR_Rect_const_ScalarR_int_int_int(img.as_raw__InputOutputArray(), rec.opencv_as_extern(), &color, thickness, line_type, shift, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_rectangle_const__InputOutputArrayR_Rect_const_ScalarR_int_int_int(img.as_raw__InputOutputArray(), rec.opencv_as_extern(), &color, thickness, line_type, shift, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `rectangle()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct RectangleBuilder<'a> {
+	img: &'a mut dyn core::ToInputOutputArray,
+	rec: core::Rect,
+	color: core::Scalar,
+	thickness: i32,
+	line_type: i32,
+	shift: i32,
+}
+
+impl<'a> RectangleBuilder<'a> {
+	#[inline]
+	pub fn new(img: &'a mut dyn core::ToInputOutputArray, rec: core::Rect, color: core::Scalar, line_type: i32) -> Self {
+		Self { img, rec, color, thickness: 1, line_type, shift: 0 }
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_thickness(mut self, thickness: i32) -> Self {
+		self.thickness = thickness;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_shift(mut self, shift: i32) -> Self {
+		self.shift = shift;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		rectangle(self.img, self.rec, self.color, self.thickness, self.line_type, self.shift)
+	}
+}
+
 /// Applies a generic geometrical transformation to an image.
 /// 
 /// The function remap transforms the source image using the specified map:
@@ -6405,12 +8073,45 @@ pub fn remap(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, ma
 	input_array_arg!(map1);
 	input_array_arg!(map2);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_remap_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_int_int_const_ScalarR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), map1.as_raw__InputArray(), map2.as_raw__InputArray(), interpolation, border_mode, &border_value, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_remap_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_int_int_const_ScalarR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), map1.as_raw__InputArray(), map2.as_raw__InputArray(), interpolation, border_mode, &border_value, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `remap()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct RemapBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	map1: &'a dyn core::ToInputArray,
+	map2: &'a dyn core::ToInputArray,
+	interpolation: i32,
+	border_mode: i32,
+	border_value: core::Scalar,
+}
+
+impl<'a> RemapBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, map1: &'a dyn core::ToInputArray, map2: &'a dyn core::ToInputArray, interpolation: i32, border_mode: i32) -> Self {
+		Self { src, dst, map1, map2, interpolation, border_mode, border_value: core::Scalar::default() }
+	}
+	
+	/// C++ default: `Scalar()`
+	#[inline]
+	pub fn with_border_value(mut self, border_value: core::Scalar) -> Self {
+		self.border_value = border_value;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		remap(self.src, self.dst, self.map1, self.map2, self.interpolation, self.border_mode, self.border_value)
+	}
+}
+
 /// Resizes an image.
 /// 
 /// The function resize resizes the image src down to or up to the specified size. Note that the
@@ -6446,7 +8147,7 @@ pub fn remap(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, ma
 /// ![block formula](https://latex.codecogs.com/png.latex?%5Ctexttt%7B%28double%29dsize%2Eheight%2Fsrc%2Erows%7D)
 /// * interpolation: interpolation method, see #InterpolationFlags
 /// ## See also
-/// warpAffine, warpPerspective, remap
+/// [warp_affine](crate::imgproc::warp_affine), [warp_perspective](crate::imgproc::warp_perspective), [remap](crate::imgproc::remap)
 /// 
 /// ## C++ default parameters
 /// * fx: 0
@@ -6457,12 +8158,51 @@ pub fn resize(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, d
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_resize_const__InputArrayR_const__OutputArrayR_Size_double_double_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), dsize.opencv_as_extern(), fx, fy, interpolation, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_resize_const__InputArrayR_const__OutputArrayR_Size_double_double_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), dsize.opencv_as_extern(), fx, fy, interpolation, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `resize()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct ResizeBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	dsize: core::Size,
+	fx: f64,
+	fy: f64,
+	interpolation: i32,
+}
+
+impl<'a> ResizeBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, dsize: core::Size, interpolation: i32) -> Self {
+		Self { src, dst, dsize, fx: 0., fy: 0., interpolation }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_fx(mut self, fx: f64) -> Self {
+		self.fx = fx;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_fy(mut self, fy: f64) -> Self {
+		self.fy = fy;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		resize(self.src, self.dst, self.dsize, self.fx, self.fy, self.interpolation)
+	}
+}
+
 /// Finds out if there is any intersection between two rotated rectangles.
 /// 
 /// If there is then the vertices of the intersecting region are returned as well.
@@ -6483,7 +8223,7 @@ pub fn resize(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, d
 pub fn rotated_rectangle_intersection(rect1: &core::RotatedRect, rect2: &core::RotatedRect, intersecting_region: &mut dyn core::ToOutputArray) -> Result<i32> {
 	output_array_arg!(intersecting_region);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_rotatedRectangleIntersection_const_RotatedRectR_const_RotatedRectR_const__OutputArrayR(rect1.as_raw_RotatedRect(), rect2.as_raw_RotatedRect(), intersecting_region.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_rotatedRectangleIntersection_const_RotatedRectR_const_RotatedRectR_const__OutputArrayR(rect1.as_raw_RotatedRect(), rect2.as_raw_RotatedRect(), intersecting_region.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -6498,7 +8238,7 @@ pub fn rotated_rectangle_intersection(rect1: &core::RotatedRect, rect2: &core::R
 /// ## Parameters
 /// * src: Source image.
 /// * dst: Destination image of the same size and the same number of channels as src .
-/// * ddepth: Destination image depth, see @ref filter_depths "combinations"
+/// * ddepth: Destination image depth, see combinations
 /// * kernelX: Coefficients for filtering each row.
 /// * kernelY: Coefficients for filtering each column.
 /// * anchor: Anchor position within the kernel. The default value ![inline formula](https://latex.codecogs.com/png.latex?%28%2D1%2C%2D1%29) means that the anchor
@@ -6506,7 +8246,7 @@ pub fn rotated_rectangle_intersection(rect1: &core::RotatedRect, rect2: &core::R
 /// * delta: Value added to the filtered results before storing them.
 /// * borderType: Pixel extrapolation method, see #BorderTypes. #BORDER_WRAP is not supported.
 /// ## See also
-/// filter2D, Sobel, GaussianBlur, boxFilter, blur
+/// [filter_2d](crate::imgproc::filter_2d), [sobel](crate::imgproc::sobel), [gaussian_blur](crate::imgproc::gaussian_blur), [box_filter](crate::imgproc::box_filter), [blur](crate::imgproc::blur)
 /// 
 /// ## C++ default parameters
 /// * anchor: Point(-1,-1)
@@ -6519,12 +8259,53 @@ pub fn sep_filter_2d(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputA
 	input_array_arg!(kernel_x);
 	input_array_arg!(kernel_y);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sepFilter2D_const__InputArrayR_const__OutputArrayR_int_const__InputArrayR_const__InputArrayR_Point_double_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ddepth, kernel_x.as_raw__InputArray(), kernel_y.as_raw__InputArray(), anchor.opencv_as_extern(), delta, border_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sepFilter2D_const__InputArrayR_const__OutputArrayR_int_const__InputArrayR_const__InputArrayR_Point_double_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ddepth, kernel_x.as_raw__InputArray(), kernel_y.as_raw__InputArray(), anchor.opencv_as_extern(), delta, border_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `sep_filter_2d()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct SepFilter2dBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	ddepth: i32,
+	kernel_x: &'a dyn core::ToInputArray,
+	kernel_y: &'a dyn core::ToInputArray,
+	anchor: core::Point,
+	delta: f64,
+	border_type: i32,
+}
+
+impl<'a> SepFilter2dBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, ddepth: i32, kernel_x: &'a dyn core::ToInputArray, kernel_y: &'a dyn core::ToInputArray, border_type: i32) -> Self {
+		Self { src, dst, ddepth, kernel_x, kernel_y, anchor: core::Point::new(-1, -1), delta: 0., border_type }
+	}
+	
+	/// C++ default: `Point(-1,-1)`
+	#[inline]
+	pub fn with_anchor(mut self, anchor: core::Point) -> Self {
+		self.anchor = anchor;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_delta(mut self, delta: f64) -> Self {
+		self.delta = delta;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		sep_filter_2d(self.src, self.dst, self.ddepth, self.kernel_x, self.kernel_y, self.anchor, self.delta, self.border_type)
+	}
+}
+
 /// Calculates the first order image derivative in both x and y using a Sobel operator
 /// 
 /// Equivalent to calling:
@@ -6543,7 +8324,7 @@ pub fn sep_filter_2d(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputA
 /// * borderType: pixel extrapolation method, see #BorderTypes.
 ///                   Only #BORDER_DEFAULT=#BORDER_REFLECT_101 and #BORDER_REPLICATE are supported.
 /// ## See also
-/// Sobel
+/// [sobel](crate::imgproc::sobel)
 /// 
 /// ## C++ default parameters
 /// * ksize: 3
@@ -6554,7 +8335,7 @@ pub fn spatial_gradient(src: &dyn core::ToInputArray, dx: &mut dyn core::ToOutpu
 	output_array_arg!(dx);
 	output_array_arg!(dy);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_spatialGradient_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_int_int(src.as_raw__InputArray(), dx.as_raw__OutputArray(), dy.as_raw__OutputArray(), ksize, border_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_spatialGradient_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_int_int(src.as_raw__InputArray(), dx.as_raw__OutputArray(), dy.as_raw__OutputArray(), ksize, border_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -6578,7 +8359,7 @@ pub fn spatial_gradient(src: &dyn core::ToInputArray, dx: &mut dyn core::ToOutpu
 /// * normalize: flag, specifying whether the kernel is to be normalized by it's area or not.
 /// * borderType: border mode used to extrapolate pixels outside of the image, see #BorderTypes. #BORDER_WRAP is not supported.
 /// ## See also
-/// boxFilter
+/// [box_filter](crate::imgproc::box_filter)
 /// 
 /// ## C++ default parameters
 /// * anchor: Point(-1,-1)
@@ -6589,12 +8370,52 @@ pub fn sqr_box_filter(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutput
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sqrBoxFilter_const__InputArrayR_const__OutputArrayR_int_Size_Point_bool_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ddepth, ksize.opencv_as_extern(), anchor.opencv_as_extern(), normalize, border_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sqrBoxFilter_const__InputArrayR_const__OutputArrayR_int_Size_Point_bool_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ddepth, ksize.opencv_as_extern(), anchor.opencv_as_extern(), normalize, border_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `sqr_box_filter()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct SqrBoxFilterBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	ddepth: i32,
+	ksize: core::Size,
+	anchor: core::Point,
+	normalize: bool,
+	border_type: i32,
+}
+
+impl<'a> SqrBoxFilterBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, ddepth: i32, ksize: core::Size, border_type: i32) -> Self {
+		Self { src, dst, ddepth, ksize, anchor: core::Point::new(-1, -1), normalize: true, border_type }
+	}
+	
+	/// C++ default: `Point(-1,-1)`
+	#[inline]
+	pub fn with_anchor(mut self, anchor: core::Point) -> Self {
+		self.anchor = anchor;
+		self
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_normalize(mut self, normalize: bool) -> Self {
+		self.normalize = normalize;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		sqr_box_filter(self.src, self.dst, self.ddepth, self.ksize, self.anchor, self.normalize, self.border_type)
+	}
+}
+
 /// Applies a fixed-level threshold to each array element.
 /// 
 /// The function applies fixed-level thresholding to a multiple-channel array. The function is typically
@@ -6620,13 +8441,13 @@ pub fn sqr_box_filter(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutput
 /// ## Returns
 /// the computed threshold value if Otsu's or Triangle methods used.
 /// ## See also
-/// adaptiveThreshold, findContours, compare, min, max
+/// [adaptive_threshold](crate::imgproc::adaptive_threshold), [find_contours](crate::imgproc::find_contours), [compare](crate::core::compare), [min](crate::core::min), [max](crate::core::max)
 #[inline]
 pub fn threshold(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, thresh: f64, maxval: f64, typ: i32) -> Result<f64> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_threshold_const__InputArrayR_const__OutputArrayR_double_double_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), thresh, maxval, typ, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_threshold_const__InputArrayR_const__OutputArrayR_double_double_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), thresh, maxval, typ, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -6655,7 +8476,7 @@ pub fn threshold(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 /// the "outliers" in the source image are not modified by the function.
 /// * borderValue: value used in case of a constant border; by default, it is 0.
 /// ## See also
-/// warpPerspective, resize, remap, getRectSubPix, transform
+/// [warp_perspective](crate::imgproc::warp_perspective), [resize](crate::imgproc::resize), [remap](crate::imgproc::remap), [get_rect_sub_pix](crate::imgproc::get_rect_sub_pix), [transform](crate::core::transform)
 /// 
 /// ## C++ default parameters
 /// * flags: INTER_LINEAR
@@ -6667,12 +8488,45 @@ pub fn warp_affine(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArr
 	output_array_arg!(dst);
 	input_array_arg!(m);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_warpAffine_const__InputArrayR_const__OutputArrayR_const__InputArrayR_Size_int_int_const_ScalarR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), m.as_raw__InputArray(), dsize.opencv_as_extern(), flags, border_mode, &border_value, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_warpAffine_const__InputArrayR_const__OutputArrayR_const__InputArrayR_Size_int_int_const_ScalarR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), m.as_raw__InputArray(), dsize.opencv_as_extern(), flags, border_mode, &border_value, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `warp_affine()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct WarpAffineBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	m: &'a dyn core::ToInputArray,
+	dsize: core::Size,
+	flags: i32,
+	border_mode: i32,
+	border_value: core::Scalar,
+}
+
+impl<'a> WarpAffineBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, m: &'a dyn core::ToInputArray, dsize: core::Size, flags: i32, border_mode: i32) -> Self {
+		Self { src, dst, m, dsize, flags, border_mode, border_value: core::Scalar::default() }
+	}
+	
+	/// C++ default: `Scalar()`
+	#[inline]
+	pub fn with_border_value(mut self, border_value: core::Scalar) -> Self {
+		self.border_value = border_value;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		warp_affine(self.src, self.dst, self.m, self.dsize, self.flags, self.border_mode, self.border_value)
+	}
+}
+
 /// Applies a perspective transformation to an image.
 /// 
 /// The function warpPerspective transforms the source image using the specified matrix:
@@ -6693,7 +8547,7 @@ pub fn warp_affine(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArr
 /// * borderMode: pixel extrapolation method (#BORDER_CONSTANT or #BORDER_REPLICATE).
 /// * borderValue: value used in case of a constant border; by default, it equals 0.
 /// ## See also
-/// warpAffine, resize, remap, getRectSubPix, perspectiveTransform
+/// [warp_affine](crate::imgproc::warp_affine), [resize](crate::imgproc::resize), [remap](crate::imgproc::remap), [get_rect_sub_pix](crate::imgproc::get_rect_sub_pix), [perspective_transform](crate::core::perspective_transform)
 /// 
 /// ## C++ default parameters
 /// * flags: INTER_LINEAR
@@ -6705,12 +8559,45 @@ pub fn warp_perspective(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutp
 	output_array_arg!(dst);
 	input_array_arg!(m);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_warpPerspective_const__InputArrayR_const__OutputArrayR_const__InputArrayR_Size_int_int_const_ScalarR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), m.as_raw__InputArray(), dsize.opencv_as_extern(), flags, border_mode, &border_value, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_warpPerspective_const__InputArrayR_const__OutputArrayR_const__InputArrayR_Size_int_int_const_ScalarR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), m.as_raw__InputArray(), dsize.opencv_as_extern(), flags, border_mode, &border_value, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `warp_perspective()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct WarpPerspectiveBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	m: &'a dyn core::ToInputArray,
+	dsize: core::Size,
+	flags: i32,
+	border_mode: i32,
+	border_value: core::Scalar,
+}
+
+impl<'a> WarpPerspectiveBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, m: &'a dyn core::ToInputArray, dsize: core::Size, flags: i32, border_mode: i32) -> Self {
+		Self { src, dst, m, dsize, flags, border_mode, border_value: core::Scalar::default() }
+	}
+	
+	/// C++ default: `Scalar()`
+	#[inline]
+	pub fn with_border_value(mut self, border_value: core::Scalar) -> Self {
+		self.border_value = border_value;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		warp_perspective(self.src, self.dst, self.m, self.dsize, self.flags, self.border_mode, self.border_value)
+	}
+}
+
 /// \brief Remaps an image to polar or semilog-polar coordinates space
 /// 
 /// @anchor polar_remaps_reference_image
@@ -6774,13 +8661,13 @@ pub fn warp_perspective(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutp
 /// *  To calculate magnitude and angle in degrees #cartToPolar is used internally thus angles are measured from 0 to 360 with accuracy about 0.3 degrees.
 /// *  This function uses #remap. Due to current implementation limitations the size of an input and output images should be less than 32767x32767.
 /// ## See also
-/// cv::remap
+/// [remap](crate::imgproc::remap)
 #[inline]
 pub fn warp_polar(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, dsize: core::Size, center: core::Point2f, max_radius: f64, flags: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_warpPolar_const__InputArrayR_const__OutputArrayR_Size_Point2f_double_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), dsize.opencv_as_extern(), center.opencv_as_extern(), max_radius, flags, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_warpPolar_const__InputArrayR_const__OutputArrayR_Size_Point2f_double_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), dsize.opencv_as_extern(), center.opencv_as_extern(), max_radius, flags, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -6810,13 +8697,13 @@ pub fn warp_polar(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArra
 /// * markers: Input/output 32-bit single-channel image (map) of markers. It should have the same
 /// size as image .
 /// ## See also
-/// findContours
+/// [find_contours](crate::imgproc::find_contours)
 #[inline]
 pub fn watershed(image: &dyn core::ToInputArray, markers: &mut dyn core::ToInputOutputArray) -> Result<()> {
 	input_array_arg!(image);
 	input_output_array_arg!(markers);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_watershed_const__InputArrayR_const__InputOutputArrayR(image.as_raw__InputArray(), markers.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_watershed_const__InputArrayR_const__InputOutputArrayR(image.as_raw__InputArray(), markers.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -6833,12 +8720,55 @@ pub fn emd_1(signature1: &dyn core::ToInputArray, signature2: &dyn core::ToInput
 	input_array_arg!(cost);
 	output_array_arg!(flow);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_wrapperEMD_const__InputArrayR_const__InputArrayR_int_const__InputArrayR_Ptr_float__const__OutputArrayR(signature1.as_raw__InputArray(), signature2.as_raw__InputArray(), dist_type, cost.as_raw__InputArray(), lower_bound.as_raw_mut_PtrOff32(), flow.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_wrapperEMD_const__InputArrayR_const__InputArrayR_int_const__InputArrayR_Ptr_float__const__OutputArrayR(signature1.as_raw__InputArray(), signature2.as_raw__InputArray(), dist_type, cost.as_raw__InputArray(), lower_bound.as_raw_mut_PtrOff32(), flow.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `emd_1()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct Emd1Builder<'a> {
+	signature1: &'a dyn core::ToInputArray,
+	signature2: &'a dyn core::ToInputArray,
+	dist_type: i32,
+	cost: Option<&'a dyn core::ToInputArray>,
+	lower_bound: core::Ptr<f32>,
+	flow: Option<&'a mut dyn core::ToOutputArray>,
+}
+
+impl<'a> Emd1Builder<'a> {
+	#[inline]
+	pub fn new(signature1: &'a dyn core::ToInputArray, signature2: &'a dyn core::ToInputArray, dist_type: i32, lower_bound: core::Ptr<f32>) -> Self {
+		Self { signature1, signature2, dist_type, cost: None, lower_bound, flow: None }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_cost(mut self, cost: &'a dyn core::ToInputArray) -> Self {
+		self.cost = Some(cost);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_flow(mut self, flow: &'a mut dyn core::ToOutputArray) -> Self {
+		self.flow = Some(flow);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<f32> {
+		let cost_no_array = core::no_array();
+		let cost: &dyn core::ToInputArray = match self.cost { Some(cost) => cost, None => &cost_no_array };
+		let mut flow_no_array = core::no_array();
+		let flow: &mut dyn core::ToOutputArray = match self.flow { Some(flow) => flow, None => &mut flow_no_array };
+		emd_1(self.signature1, self.signature2, self.dist_type, cost, self.lower_bound, flow)
+	}
+}
+
 /// Base class for Contrast Limited Adaptive Histogram Equalization.
 pub trait CLAHEConst: core::AlgorithmTraitConst {
 	fn as_raw_CLAHE(&self) -> *const c_void;
@@ -6846,8 +8776,9 @@ pub trait CLAHEConst: core::AlgorithmTraitConst {
 	/// Returns threshold value for contrast limiting.
 	#[inline]
 	fn get_clip_limit(&self) -> Result<f64> {
+		crate::core::validity::check("CLAHE", self.as_raw_CLAHE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CLAHE_getClipLimit_const(self.as_raw_CLAHE(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CLAHE_getClipLimit_const(self.as_raw_CLAHE(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6856,8 +8787,9 @@ pub trait CLAHEConst: core::AlgorithmTraitConst {
 	/// Returns Size defines the number of tiles in row and column.
 	#[inline]
 	fn get_tiles_grid_size(&self) -> Result<core::Size> {
+		crate::core::validity::check("CLAHE", self.as_raw_CLAHE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CLAHE_getTilesGridSize_const(self.as_raw_CLAHE(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CLAHE_getTilesGridSize_const(self.as_raw_CLAHE(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6875,10 +8807,11 @@ pub trait CLAHE: core::AlgorithmTrait + crate::imgproc::CLAHEConst {
 	/// * dst: Destination image.
 	#[inline]
 	fn apply(&mut self, src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("CLAHE", self.as_raw_mut_CLAHE())?;
 		input_array_arg!(src);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CLAHE_apply_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_CLAHE(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CLAHE_apply_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_CLAHE(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6890,8 +8823,9 @@ pub trait CLAHE: core::AlgorithmTrait + crate::imgproc::CLAHEConst {
 	/// * clipLimit: threshold value.
 	#[inline]
 	fn set_clip_limit(&mut self, clip_limit: f64) -> Result<()> {
+		crate::core::validity::check("CLAHE", self.as_raw_mut_CLAHE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CLAHE_setClipLimit_double(self.as_raw_mut_CLAHE(), clip_limit, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CLAHE_setClipLimit_double(self.as_raw_mut_CLAHE(), clip_limit, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6904,8 +8838,9 @@ pub trait CLAHE: core::AlgorithmTrait + crate::imgproc::CLAHEConst {
 	/// * tileGridSize: defines the number of tiles in row and column.
 	#[inline]
 	fn set_tiles_grid_size(&mut self, tile_grid_size: core::Size) -> Result<()> {
+		crate::core::validity::check("CLAHE", self.as_raw_mut_CLAHE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CLAHE_setTilesGridSize_Size(self.as_raw_mut_CLAHE(), tile_grid_size.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CLAHE_setTilesGridSize_Size(self.as_raw_mut_CLAHE(), tile_grid_size.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6913,8 +8848,9 @@ pub trait CLAHE: core::AlgorithmTrait + crate::imgproc::CLAHEConst {
 	
 	#[inline]
 	fn collect_garbage(&mut self) -> Result<()> {
+		crate::core::validity::check("CLAHE", self.as_raw_mut_CLAHE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CLAHE_collectGarbage(self.as_raw_mut_CLAHE(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CLAHE_collectGarbage(self.as_raw_mut_CLAHE(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6928,8 +8864,9 @@ pub trait GeneralizedHoughConst: core::AlgorithmTraitConst {
 
 	#[inline]
 	fn get_canny_low_thresh(&self) -> Result<i32> {
+		crate::core::validity::check("GeneralizedHough", self.as_raw_GeneralizedHough())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHough_getCannyLowThresh_const(self.as_raw_GeneralizedHough(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHough_getCannyLowThresh_const(self.as_raw_GeneralizedHough(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6937,8 +8874,9 @@ pub trait GeneralizedHoughConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_canny_high_thresh(&self) -> Result<i32> {
+		crate::core::validity::check("GeneralizedHough", self.as_raw_GeneralizedHough())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHough_getCannyHighThresh_const(self.as_raw_GeneralizedHough(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHough_getCannyHighThresh_const(self.as_raw_GeneralizedHough(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6946,8 +8884,9 @@ pub trait GeneralizedHoughConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_min_dist(&self) -> Result<f64> {
+		crate::core::validity::check("GeneralizedHough", self.as_raw_GeneralizedHough())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHough_getMinDist_const(self.as_raw_GeneralizedHough(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHough_getMinDist_const(self.as_raw_GeneralizedHough(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6955,8 +8894,9 @@ pub trait GeneralizedHoughConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_dp(&self) -> Result<f64> {
+		crate::core::validity::check("GeneralizedHough", self.as_raw_GeneralizedHough())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHough_getDp_const(self.as_raw_GeneralizedHough(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHough_getDp_const(self.as_raw_GeneralizedHough(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6964,8 +8904,9 @@ pub trait GeneralizedHoughConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_max_buffer_size(&self) -> Result<i32> {
+		crate::core::validity::check("GeneralizedHough", self.as_raw_GeneralizedHough())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHough_getMaxBufferSize_const(self.as_raw_GeneralizedHough(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHough_getMaxBufferSize_const(self.as_raw_GeneralizedHough(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6982,9 +8923,10 @@ pub trait GeneralizedHough: core::AlgorithmTrait + crate::imgproc::GeneralizedHo
 	/// * templ_center: Point(-1,-1)
 	#[inline]
 	fn set_template(&mut self, templ: &dyn core::ToInputArray, templ_center: core::Point) -> Result<()> {
+		crate::core::validity::check("GeneralizedHough", self.as_raw_mut_GeneralizedHough())?;
 		input_array_arg!(templ);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHough_setTemplate_const__InputArrayR_Point(self.as_raw_mut_GeneralizedHough(), templ.as_raw__InputArray(), templ_center.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHough_setTemplate_const__InputArrayR_Point(self.as_raw_mut_GeneralizedHough(), templ.as_raw__InputArray(), templ_center.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6994,11 +8936,12 @@ pub trait GeneralizedHough: core::AlgorithmTrait + crate::imgproc::GeneralizedHo
 	/// * templ_center: Point(-1,-1)
 	#[inline]
 	fn set_template_1(&mut self, edges: &dyn core::ToInputArray, dx: &dyn core::ToInputArray, dy: &dyn core::ToInputArray, templ_center: core::Point) -> Result<()> {
+		crate::core::validity::check("GeneralizedHough", self.as_raw_mut_GeneralizedHough())?;
 		input_array_arg!(edges);
 		input_array_arg!(dx);
 		input_array_arg!(dy);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHough_setTemplate_const__InputArrayR_const__InputArrayR_const__InputArrayR_Point(self.as_raw_mut_GeneralizedHough(), edges.as_raw__InputArray(), dx.as_raw__InputArray(), dy.as_raw__InputArray(), templ_center.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHough_setTemplate_const__InputArrayR_const__InputArrayR_const__InputArrayR_Point(self.as_raw_mut_GeneralizedHough(), edges.as_raw__InputArray(), dx.as_raw__InputArray(), dy.as_raw__InputArray(), templ_center.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7010,11 +8953,12 @@ pub trait GeneralizedHough: core::AlgorithmTrait + crate::imgproc::GeneralizedHo
 	/// * votes: noArray()
 	#[inline]
 	fn detect(&mut self, image: &dyn core::ToInputArray, positions: &mut dyn core::ToOutputArray, votes: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("GeneralizedHough", self.as_raw_mut_GeneralizedHough())?;
 		input_array_arg!(image);
 		output_array_arg!(positions);
 		output_array_arg!(votes);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHough_detect_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_GeneralizedHough(), image.as_raw__InputArray(), positions.as_raw__OutputArray(), votes.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHough_detect_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_GeneralizedHough(), image.as_raw__InputArray(), positions.as_raw__OutputArray(), votes.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7024,13 +8968,14 @@ pub trait GeneralizedHough: core::AlgorithmTrait + crate::imgproc::GeneralizedHo
 	/// * votes: noArray()
 	#[inline]
 	fn detect_with_edges(&mut self, edges: &dyn core::ToInputArray, dx: &dyn core::ToInputArray, dy: &dyn core::ToInputArray, positions: &mut dyn core::ToOutputArray, votes: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("GeneralizedHough", self.as_raw_mut_GeneralizedHough())?;
 		input_array_arg!(edges);
 		input_array_arg!(dx);
 		input_array_arg!(dy);
 		output_array_arg!(positions);
 		output_array_arg!(votes);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHough_detect_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_GeneralizedHough(), edges.as_raw__InputArray(), dx.as_raw__InputArray(), dy.as_raw__InputArray(), positions.as_raw__OutputArray(), votes.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHough_detect_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_GeneralizedHough(), edges.as_raw__InputArray(), dx.as_raw__InputArray(), dy.as_raw__InputArray(), positions.as_raw__OutputArray(), votes.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7039,8 +8984,9 @@ pub trait GeneralizedHough: core::AlgorithmTrait + crate::imgproc::GeneralizedHo
 	/// Canny low threshold.
 	#[inline]
 	fn set_canny_low_thresh(&mut self, canny_low_thresh: i32) -> Result<()> {
+		crate::core::validity::check("GeneralizedHough", self.as_raw_mut_GeneralizedHough())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHough_setCannyLowThresh_int(self.as_raw_mut_GeneralizedHough(), canny_low_thresh, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHough_setCannyLowThresh_int(self.as_raw_mut_GeneralizedHough(), canny_low_thresh, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7049,8 +8995,9 @@ pub trait GeneralizedHough: core::AlgorithmTrait + crate::imgproc::GeneralizedHo
 	/// Canny high threshold.
 	#[inline]
 	fn set_canny_high_thresh(&mut self, canny_high_thresh: i32) -> Result<()> {
+		crate::core::validity::check("GeneralizedHough", self.as_raw_mut_GeneralizedHough())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHough_setCannyHighThresh_int(self.as_raw_mut_GeneralizedHough(), canny_high_thresh, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHough_setCannyHighThresh_int(self.as_raw_mut_GeneralizedHough(), canny_high_thresh, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7059,8 +9006,9 @@ pub trait GeneralizedHough: core::AlgorithmTrait + crate::imgproc::GeneralizedHo
 	/// Minimum distance between the centers of the detected objects.
 	#[inline]
 	fn set_min_dist(&mut self, min_dist: f64) -> Result<()> {
+		crate::core::validity::check("GeneralizedHough", self.as_raw_mut_GeneralizedHough())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHough_setMinDist_double(self.as_raw_mut_GeneralizedHough(), min_dist, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHough_setMinDist_double(self.as_raw_mut_GeneralizedHough(), min_dist, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7069,8 +9017,9 @@ pub trait GeneralizedHough: core::AlgorithmTrait + crate::imgproc::GeneralizedHo
 	/// Inverse ratio of the accumulator resolution to the image resolution.
 	#[inline]
 	fn set_dp(&mut self, dp: f64) -> Result<()> {
+		crate::core::validity::check("GeneralizedHough", self.as_raw_mut_GeneralizedHough())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHough_setDp_double(self.as_raw_mut_GeneralizedHough(), dp, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHough_setDp_double(self.as_raw_mut_GeneralizedHough(), dp, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7079,8 +9028,9 @@ pub trait GeneralizedHough: core::AlgorithmTrait + crate::imgproc::GeneralizedHo
 	/// Maximal size of inner buffers.
 	#[inline]
 	fn set_max_buffer_size(&mut self, max_buffer_size: i32) -> Result<()> {
+		crate::core::validity::check("GeneralizedHough", self.as_raw_mut_GeneralizedHough())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHough_setMaxBufferSize_int(self.as_raw_mut_GeneralizedHough(), max_buffer_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHough_setMaxBufferSize_int(self.as_raw_mut_GeneralizedHough(), max_buffer_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7096,8 +9046,9 @@ pub trait GeneralizedHoughBallardConst: crate::imgproc::GeneralizedHoughConst {
 
 	#[inline]
 	fn get_levels(&self) -> Result<i32> {
+		crate::core::validity::check("GeneralizedHoughBallard", self.as_raw_GeneralizedHoughBallard())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHoughBallard_getLevels_const(self.as_raw_GeneralizedHoughBallard(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHoughBallard_getLevels_const(self.as_raw_GeneralizedHoughBallard(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7105,8 +9056,9 @@ pub trait GeneralizedHoughBallardConst: crate::imgproc::GeneralizedHoughConst {
 	
 	#[inline]
 	fn get_votes_threshold(&self) -> Result<i32> {
+		crate::core::validity::check("GeneralizedHoughBallard", self.as_raw_GeneralizedHoughBallard())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHoughBallard_getVotesThreshold_const(self.as_raw_GeneralizedHoughBallard(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHoughBallard_getVotesThreshold_const(self.as_raw_GeneralizedHoughBallard(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7120,8 +9072,9 @@ pub trait GeneralizedHoughBallard: crate::imgproc::GeneralizedHough + crate::img
 	/// R-Table levels.
 	#[inline]
 	fn set_levels(&mut self, levels: i32) -> Result<()> {
+		crate::core::validity::check("GeneralizedHoughBallard", self.as_raw_mut_GeneralizedHoughBallard())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHoughBallard_setLevels_int(self.as_raw_mut_GeneralizedHoughBallard(), levels, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHoughBallard_setLevels_int(self.as_raw_mut_GeneralizedHoughBallard(), levels, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7130,8 +9083,9 @@ pub trait GeneralizedHoughBallard: crate::imgproc::GeneralizedHough + crate::img
 	/// The accumulator threshold for the template centers at the detection stage. The smaller it is, the more false positions may be detected.
 	#[inline]
 	fn set_votes_threshold(&mut self, votes_threshold: i32) -> Result<()> {
+		crate::core::validity::check("GeneralizedHoughBallard", self.as_raw_mut_GeneralizedHoughBallard())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHoughBallard_setVotesThreshold_int(self.as_raw_mut_GeneralizedHoughBallard(), votes_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHoughBallard_setVotesThreshold_int(self.as_raw_mut_GeneralizedHoughBallard(), votes_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7147,8 +9101,9 @@ pub trait GeneralizedHoughGuilConst: crate::imgproc::GeneralizedHoughConst {
 
 	#[inline]
 	fn get_xi(&self) -> Result<f64> {
+		crate::core::validity::check("GeneralizedHoughGuil", self.as_raw_GeneralizedHoughGuil())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHoughGuil_getXi_const(self.as_raw_GeneralizedHoughGuil(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHoughGuil_getXi_const(self.as_raw_GeneralizedHoughGuil(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7156,8 +9111,9 @@ pub trait GeneralizedHoughGuilConst: crate::imgproc::GeneralizedHoughConst {
 	
 	#[inline]
 	fn get_levels(&self) -> Result<i32> {
+		crate::core::validity::check("GeneralizedHoughGuil", self.as_raw_GeneralizedHoughGuil())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHoughGuil_getLevels_const(self.as_raw_GeneralizedHoughGuil(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHoughGuil_getLevels_const(self.as_raw_GeneralizedHoughGuil(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7165,8 +9121,9 @@ pub trait GeneralizedHoughGuilConst: crate::imgproc::GeneralizedHoughConst {
 	
 	#[inline]
 	fn get_angle_epsilon(&self) -> Result<f64> {
+		crate::core::validity::check("GeneralizedHoughGuil", self.as_raw_GeneralizedHoughGuil())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHoughGuil_getAngleEpsilon_const(self.as_raw_GeneralizedHoughGuil(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHoughGuil_getAngleEpsilon_const(self.as_raw_GeneralizedHoughGuil(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7174,8 +9131,9 @@ pub trait GeneralizedHoughGuilConst: crate::imgproc::GeneralizedHoughConst {
 	
 	#[inline]
 	fn get_min_angle(&self) -> Result<f64> {
+		crate::core::validity::check("GeneralizedHoughGuil", self.as_raw_GeneralizedHoughGuil())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHoughGuil_getMinAngle_const(self.as_raw_GeneralizedHoughGuil(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHoughGuil_getMinAngle_const(self.as_raw_GeneralizedHoughGuil(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7183,8 +9141,9 @@ pub trait GeneralizedHoughGuilConst: crate::imgproc::GeneralizedHoughConst {
 	
 	#[inline]
 	fn get_max_angle(&self) -> Result<f64> {
+		crate::core::validity::check("GeneralizedHoughGuil", self.as_raw_GeneralizedHoughGuil())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHoughGuil_getMaxAngle_const(self.as_raw_GeneralizedHoughGuil(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHoughGuil_getMaxAngle_const(self.as_raw_GeneralizedHoughGuil(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7192,8 +9151,9 @@ pub trait GeneralizedHoughGuilConst: crate::imgproc::GeneralizedHoughConst {
 	
 	#[inline]
 	fn get_angle_step(&self) -> Result<f64> {
+		crate::core::validity::check("GeneralizedHoughGuil", self.as_raw_GeneralizedHoughGuil())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHoughGuil_getAngleStep_const(self.as_raw_GeneralizedHoughGuil(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHoughGuil_getAngleStep_const(self.as_raw_GeneralizedHoughGuil(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7201,8 +9161,9 @@ pub trait GeneralizedHoughGuilConst: crate::imgproc::GeneralizedHoughConst {
 	
 	#[inline]
 	fn get_angle_thresh(&self) -> Result<i32> {
+		crate::core::validity::check("GeneralizedHoughGuil", self.as_raw_GeneralizedHoughGuil())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHoughGuil_getAngleThresh_const(self.as_raw_GeneralizedHoughGuil(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHoughGuil_getAngleThresh_const(self.as_raw_GeneralizedHoughGuil(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7210,8 +9171,9 @@ pub trait GeneralizedHoughGuilConst: crate::imgproc::GeneralizedHoughConst {
 	
 	#[inline]
 	fn get_min_scale(&self) -> Result<f64> {
+		crate::core::validity::check("GeneralizedHoughGuil", self.as_raw_GeneralizedHoughGuil())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHoughGuil_getMinScale_const(self.as_raw_GeneralizedHoughGuil(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHoughGuil_getMinScale_const(self.as_raw_GeneralizedHoughGuil(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7219,8 +9181,9 @@ pub trait GeneralizedHoughGuilConst: crate::imgproc::GeneralizedHoughConst {
 	
 	#[inline]
 	fn get_max_scale(&self) -> Result<f64> {
+		crate::core::validity::check("GeneralizedHoughGuil", self.as_raw_GeneralizedHoughGuil())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHoughGuil_getMaxScale_const(self.as_raw_GeneralizedHoughGuil(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHoughGuil_getMaxScale_const(self.as_raw_GeneralizedHoughGuil(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7228,8 +9191,9 @@ pub trait GeneralizedHoughGuilConst: crate::imgproc::GeneralizedHoughConst {
 	
 	#[inline]
 	fn get_scale_step(&self) -> Result<f64> {
+		crate::core::validity::check("GeneralizedHoughGuil", self.as_raw_GeneralizedHoughGuil())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHoughGuil_getScaleStep_const(self.as_raw_GeneralizedHoughGuil(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHoughGuil_getScaleStep_const(self.as_raw_GeneralizedHoughGuil(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7237,8 +9201,9 @@ pub trait GeneralizedHoughGuilConst: crate::imgproc::GeneralizedHoughConst {
 	
 	#[inline]
 	fn get_scale_thresh(&self) -> Result<i32> {
+		crate::core::validity::check("GeneralizedHoughGuil", self.as_raw_GeneralizedHoughGuil())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHoughGuil_getScaleThresh_const(self.as_raw_GeneralizedHoughGuil(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHoughGuil_getScaleThresh_const(self.as_raw_GeneralizedHoughGuil(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7246,8 +9211,9 @@ pub trait GeneralizedHoughGuilConst: crate::imgproc::GeneralizedHoughConst {
 	
 	#[inline]
 	fn get_pos_thresh(&self) -> Result<i32> {
+		crate::core::validity::check("GeneralizedHoughGuil", self.as_raw_GeneralizedHoughGuil())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHoughGuil_getPosThresh_const(self.as_raw_GeneralizedHoughGuil(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHoughGuil_getPosThresh_const(self.as_raw_GeneralizedHoughGuil(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7261,8 +9227,9 @@ pub trait GeneralizedHoughGuil: crate::imgproc::GeneralizedHough + crate::imgpro
 	/// Angle difference in degrees between two points in feature.
 	#[inline]
 	fn set_xi(&mut self, xi: f64) -> Result<()> {
+		crate::core::validity::check("GeneralizedHoughGuil", self.as_raw_mut_GeneralizedHoughGuil())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHoughGuil_setXi_double(self.as_raw_mut_GeneralizedHoughGuil(), xi, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHoughGuil_setXi_double(self.as_raw_mut_GeneralizedHoughGuil(), xi, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7271,8 +9238,9 @@ pub trait GeneralizedHoughGuil: crate::imgproc::GeneralizedHough + crate::imgpro
 	/// Feature table levels.
 	#[inline]
 	fn set_levels(&mut self, levels: i32) -> Result<()> {
+		crate::core::validity::check("GeneralizedHoughGuil", self.as_raw_mut_GeneralizedHoughGuil())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHoughGuil_setLevels_int(self.as_raw_mut_GeneralizedHoughGuil(), levels, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHoughGuil_setLevels_int(self.as_raw_mut_GeneralizedHoughGuil(), levels, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7281,8 +9249,9 @@ pub trait GeneralizedHoughGuil: crate::imgproc::GeneralizedHough + crate::imgpro
 	/// Maximal difference between angles that treated as equal.
 	#[inline]
 	fn set_angle_epsilon(&mut self, angle_epsilon: f64) -> Result<()> {
+		crate::core::validity::check("GeneralizedHoughGuil", self.as_raw_mut_GeneralizedHoughGuil())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHoughGuil_setAngleEpsilon_double(self.as_raw_mut_GeneralizedHoughGuil(), angle_epsilon, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHoughGuil_setAngleEpsilon_double(self.as_raw_mut_GeneralizedHoughGuil(), angle_epsilon, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7291,8 +9260,9 @@ pub trait GeneralizedHoughGuil: crate::imgproc::GeneralizedHough + crate::imgpro
 	/// Minimal rotation angle to detect in degrees.
 	#[inline]
 	fn set_min_angle(&mut self, min_angle: f64) -> Result<()> {
+		crate::core::validity::check("GeneralizedHoughGuil", self.as_raw_mut_GeneralizedHoughGuil())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHoughGuil_setMinAngle_double(self.as_raw_mut_GeneralizedHoughGuil(), min_angle, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHoughGuil_setMinAngle_double(self.as_raw_mut_GeneralizedHoughGuil(), min_angle, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7301,8 +9271,9 @@ pub trait GeneralizedHoughGuil: crate::imgproc::GeneralizedHough + crate::imgpro
 	/// Maximal rotation angle to detect in degrees.
 	#[inline]
 	fn set_max_angle(&mut self, max_angle: f64) -> Result<()> {
+		crate::core::validity::check("GeneralizedHoughGuil", self.as_raw_mut_GeneralizedHoughGuil())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHoughGuil_setMaxAngle_double(self.as_raw_mut_GeneralizedHoughGuil(), max_angle, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHoughGuil_setMaxAngle_double(self.as_raw_mut_GeneralizedHoughGuil(), max_angle, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7311,8 +9282,9 @@ pub trait GeneralizedHoughGuil: crate::imgproc::GeneralizedHough + crate::imgpro
 	/// Angle step in degrees.
 	#[inline]
 	fn set_angle_step(&mut self, angle_step: f64) -> Result<()> {
+		crate::core::validity::check("GeneralizedHoughGuil", self.as_raw_mut_GeneralizedHoughGuil())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHoughGuil_setAngleStep_double(self.as_raw_mut_GeneralizedHoughGuil(), angle_step, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHoughGuil_setAngleStep_double(self.as_raw_mut_GeneralizedHoughGuil(), angle_step, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7321,8 +9293,9 @@ pub trait GeneralizedHoughGuil: crate::imgproc::GeneralizedHough + crate::imgpro
 	/// Angle votes threshold.
 	#[inline]
 	fn set_angle_thresh(&mut self, angle_thresh: i32) -> Result<()> {
+		crate::core::validity::check("GeneralizedHoughGuil", self.as_raw_mut_GeneralizedHoughGuil())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHoughGuil_setAngleThresh_int(self.as_raw_mut_GeneralizedHoughGuil(), angle_thresh, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHoughGuil_setAngleThresh_int(self.as_raw_mut_GeneralizedHoughGuil(), angle_thresh, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7331,8 +9304,9 @@ pub trait GeneralizedHoughGuil: crate::imgproc::GeneralizedHough + crate::imgpro
 	/// Minimal scale to detect.
 	#[inline]
 	fn set_min_scale(&mut self, min_scale: f64) -> Result<()> {
+		crate::core::validity::check("GeneralizedHoughGuil", self.as_raw_mut_GeneralizedHoughGuil())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHoughGuil_setMinScale_double(self.as_raw_mut_GeneralizedHoughGuil(), min_scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHoughGuil_setMinScale_double(self.as_raw_mut_GeneralizedHoughGuil(), min_scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7341,8 +9315,9 @@ pub trait GeneralizedHoughGuil: crate::imgproc::GeneralizedHough + crate::imgpro
 	/// Maximal scale to detect.
 	#[inline]
 	fn set_max_scale(&mut self, max_scale: f64) -> Result<()> {
+		crate::core::validity::check("GeneralizedHoughGuil", self.as_raw_mut_GeneralizedHoughGuil())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHoughGuil_setMaxScale_double(self.as_raw_mut_GeneralizedHoughGuil(), max_scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHoughGuil_setMaxScale_double(self.as_raw_mut_GeneralizedHoughGuil(), max_scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7351,8 +9326,9 @@ pub trait GeneralizedHoughGuil: crate::imgproc::GeneralizedHough + crate::imgpro
 	/// Scale step.
 	#[inline]
 	fn set_scale_step(&mut self, scale_step: f64) -> Result<()> {
+		crate::core::validity::check("GeneralizedHoughGuil", self.as_raw_mut_GeneralizedHoughGuil())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHoughGuil_setScaleStep_double(self.as_raw_mut_GeneralizedHoughGuil(), scale_step, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHoughGuil_setScaleStep_double(self.as_raw_mut_GeneralizedHoughGuil(), scale_step, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7361,8 +9337,9 @@ pub trait GeneralizedHoughGuil: crate::imgproc::GeneralizedHough + crate::imgpro
 	/// Scale votes threshold.
 	#[inline]
 	fn set_scale_thresh(&mut self, scale_thresh: i32) -> Result<()> {
+		crate::core::validity::check("GeneralizedHoughGuil", self.as_raw_mut_GeneralizedHoughGuil())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHoughGuil_setScaleThresh_int(self.as_raw_mut_GeneralizedHoughGuil(), scale_thresh, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHoughGuil_setScaleThresh_int(self.as_raw_mut_GeneralizedHoughGuil(), scale_thresh, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7371,8 +9348,9 @@ pub trait GeneralizedHoughGuil: crate::imgproc::GeneralizedHough + crate::imgpro
 	/// Position votes threshold.
 	#[inline]
 	fn set_pos_thresh(&mut self, pos_thresh: i32) -> Result<()> {
+		crate::core::validity::check("GeneralizedHoughGuil", self.as_raw_mut_GeneralizedHoughGuil())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_GeneralizedHoughGuil_setPosThresh_int(self.as_raw_mut_GeneralizedHoughGuil(), pos_thresh, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_GeneralizedHoughGuil_setPosThresh_int(self.as_raw_mut_GeneralizedHoughGuil(), pos_thresh, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7417,89 +9395,207 @@ pub trait LineIteratorTraitConst {
 
 	#[inline]
 	fn ptr0(&self) -> *const u8 {
-		let ret = unsafe { sys::cv_LineIterator_getPropPtr0_const(self.as_raw_LineIterator()) };
+		crate::core::validity::debug_check("LineIterator", self.as_raw_LineIterator());
+		let ret = unsafe { ffi_call!(cv_LineIterator_getPropPtr0_const(self.as_raw_LineIterator())) };
 		ret
 	}
 	
+	/// Non-panicking version of [ptr0](LineIteratorTraitConst::ptr0), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_ptr0(&self) -> Result<*const u8> {
+		crate::core::validity::check("LineIterator", self.as_raw_LineIterator())?;
+		Ok(LineIteratorTraitConst::ptr0(self))
+	}
+	
 	#[inline]
 	fn step(&self) -> i32 {
-		let ret = unsafe { sys::cv_LineIterator_getPropStep_const(self.as_raw_LineIterator()) };
+		crate::core::validity::debug_check("LineIterator", self.as_raw_LineIterator());
+		let ret = unsafe { ffi_call!(cv_LineIterator_getPropStep_const(self.as_raw_LineIterator())) };
 		ret
 	}
 	
+	/// Non-panicking version of [step](LineIteratorTraitConst::step), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_step(&self) -> Result<i32> {
+		crate::core::validity::check("LineIterator", self.as_raw_LineIterator())?;
+		Ok(LineIteratorTraitConst::step(self))
+	}
+	
 	#[inline]
 	fn elem_size(&self) -> i32 {
-		let ret = unsafe { sys::cv_LineIterator_getPropElemSize_const(self.as_raw_LineIterator()) };
+		crate::core::validity::debug_check("LineIterator", self.as_raw_LineIterator());
+		let ret = unsafe { ffi_call!(cv_LineIterator_getPropElemSize_const(self.as_raw_LineIterator())) };
 		ret
 	}
 	
+	/// Non-panicking version of [elem_size](LineIteratorTraitConst::elem_size), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_elem_size(&self) -> Result<i32> {
+		crate::core::validity::check("LineIterator", self.as_raw_LineIterator())?;
+		Ok(LineIteratorTraitConst::elem_size(self))
+	}
+	
 	#[inline]
 	fn err(&self) -> i32 {
-		let ret = unsafe { sys::cv_LineIterator_getPropErr_const(self.as_raw_LineIterator()) };
+		crate::core::validity::debug_check("LineIterator", self.as_raw_LineIterator());
+		let ret = unsafe { ffi_call!(cv_LineIterator_getPropErr_const(self.as_raw_LineIterator())) };
 		ret
 	}
 	
+	/// Non-panicking version of [err](LineIteratorTraitConst::err), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_err(&self) -> Result<i32> {
+		crate::core::validity::check("LineIterator", self.as_raw_LineIterator())?;
+		Ok(LineIteratorTraitConst::err(self))
+	}
+	
 	#[inline]
 	fn count(&self) -> i32 {
-		let ret = unsafe { sys::cv_LineIterator_getPropCount_const(self.as_raw_LineIterator()) };
+		crate::core::validity::debug_check("LineIterator", self.as_raw_LineIterator());
+		let ret = unsafe { ffi_call!(cv_LineIterator_getPropCount_const(self.as_raw_LineIterator())) };
 		ret
 	}
 	
+	/// Non-panicking version of [count](LineIteratorTraitConst::count), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_count(&self) -> Result<i32> {
+		crate::core::validity::check("LineIterator", self.as_raw_LineIterator())?;
+		Ok(LineIteratorTraitConst::count(self))
+	}
+	
 	#[inline]
 	fn minus_delta(&self) -> i32 {
-		let ret = unsafe { sys::cv_LineIterator_getPropMinusDelta_const(self.as_raw_LineIterator()) };
+		crate::core::validity::debug_check("LineIterator", self.as_raw_LineIterator());
+		let ret = unsafe { ffi_call!(cv_LineIterator_getPropMinusDelta_const(self.as_raw_LineIterator())) };
 		ret
 	}
 	
+	/// Non-panicking version of [minus_delta](LineIteratorTraitConst::minus_delta), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_minus_delta(&self) -> Result<i32> {
+		crate::core::validity::check("LineIterator", self.as_raw_LineIterator())?;
+		Ok(LineIteratorTraitConst::minus_delta(self))
+	}
+	
 	#[inline]
 	fn plus_delta(&self) -> i32 {
-		let ret = unsafe { sys::cv_LineIterator_getPropPlusDelta_const(self.as_raw_LineIterator()) };
+		crate::core::validity::debug_check("LineIterator", self.as_raw_LineIterator());
+		let ret = unsafe { ffi_call!(cv_LineIterator_getPropPlusDelta_const(self.as_raw_LineIterator())) };
 		ret
 	}
 	
+	/// Non-panicking version of [plus_delta](LineIteratorTraitConst::plus_delta), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_plus_delta(&self) -> Result<i32> {
+		crate::core::validity::check("LineIterator", self.as_raw_LineIterator())?;
+		Ok(LineIteratorTraitConst::plus_delta(self))
+	}
+	
 	#[inline]
 	fn minus_step(&self) -> i32 {
-		let ret = unsafe { sys::cv_LineIterator_getPropMinusStep_const(self.as_raw_LineIterator()) };
+		crate::core::validity::debug_check("LineIterator", self.as_raw_LineIterator());
+		let ret = unsafe { ffi_call!(cv_LineIterator_getPropMinusStep_const(self.as_raw_LineIterator())) };
 		ret
 	}
 	
+	/// Non-panicking version of [minus_step](LineIteratorTraitConst::minus_step), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_minus_step(&self) -> Result<i32> {
+		crate::core::validity::check("LineIterator", self.as_raw_LineIterator())?;
+		Ok(LineIteratorTraitConst::minus_step(self))
+	}
+	
 	#[inline]
 	fn plus_step(&self) -> i32 {
-		let ret = unsafe { sys::cv_LineIterator_getPropPlusStep_const(self.as_raw_LineIterator()) };
+		crate::core::validity::debug_check("LineIterator", self.as_raw_LineIterator());
+		let ret = unsafe { ffi_call!(cv_LineIterator_getPropPlusStep_const(self.as_raw_LineIterator())) };
 		ret
 	}
 	
+	/// Non-panicking version of [plus_step](LineIteratorTraitConst::plus_step), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_plus_step(&self) -> Result<i32> {
+		crate::core::validity::check("LineIterator", self.as_raw_LineIterator())?;
+		Ok(LineIteratorTraitConst::plus_step(self))
+	}
+	
 	#[inline]
 	fn minus_shift(&self) -> i32 {
-		let ret = unsafe { sys::cv_LineIterator_getPropMinusShift_const(self.as_raw_LineIterator()) };
+		crate::core::validity::debug_check("LineIterator", self.as_raw_LineIterator());
+		let ret = unsafe { ffi_call!(cv_LineIterator_getPropMinusShift_const(self.as_raw_LineIterator())) };
 		ret
 	}
 	
+	/// Non-panicking version of [minus_shift](LineIteratorTraitConst::minus_shift), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_minus_shift(&self) -> Result<i32> {
+		crate::core::validity::check("LineIterator", self.as_raw_LineIterator())?;
+		Ok(LineIteratorTraitConst::minus_shift(self))
+	}
+	
 	#[inline]
 	fn plus_shift(&self) -> i32 {
-		let ret = unsafe { sys::cv_LineIterator_getPropPlusShift_const(self.as_raw_LineIterator()) };
+		crate::core::validity::debug_check("LineIterator", self.as_raw_LineIterator());
+		let ret = unsafe { ffi_call!(cv_LineIterator_getPropPlusShift_const(self.as_raw_LineIterator())) };
 		ret
 	}
 	
+	/// Non-panicking version of [plus_shift](LineIteratorTraitConst::plus_shift), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_plus_shift(&self) -> Result<i32> {
+		crate::core::validity::check("LineIterator", self.as_raw_LineIterator())?;
+		Ok(LineIteratorTraitConst::plus_shift(self))
+	}
+	
 	#[inline]
 	fn p(&self) -> core::Point {
+		crate::core::validity::debug_check("LineIterator", self.as_raw_LineIterator());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_LineIterator_getPropP_const(self.as_raw_LineIterator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_LineIterator_getPropP_const(self.as_raw_LineIterator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [p](LineIteratorTraitConst::p), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_p(&self) -> Result<core::Point> {
+		crate::core::validity::check("LineIterator", self.as_raw_LineIterator())?;
+		Ok(LineIteratorTraitConst::p(self))
+	}
+	
 	#[inline]
 	fn ptmode(&self) -> bool {
-		let ret = unsafe { sys::cv_LineIterator_getPropPtmode_const(self.as_raw_LineIterator()) };
+		crate::core::validity::debug_check("LineIterator", self.as_raw_LineIterator());
+		let ret = unsafe { ffi_call!(cv_LineIterator_getPropPtmode_const(self.as_raw_LineIterator())) };
 		ret
 	}
 	
+	/// Non-panicking version of [ptmode](LineIteratorTraitConst::ptmode), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_ptmode(&self) -> Result<bool> {
+		crate::core::validity::check("LineIterator", self.as_raw_LineIterator())?;
+		Ok(LineIteratorTraitConst::ptmode(self))
+	}
+	
 	/// returns coordinates of the current pixel
 	#[inline]
 	fn pos(&self) -> Result<core::Point> {
+		crate::core::validity::check("LineIterator", self.as_raw_LineIterator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_LineIterator_pos_const(self.as_raw_LineIterator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_LineIterator_pos_const(self.as_raw_LineIterator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7512,92 +9608,115 @@ pub trait LineIteratorTrait: crate::imgproc::LineIteratorTraitConst {
 
 	#[inline]
 	fn ptr(&mut self) -> *mut u8 {
-		let ret = unsafe { sys::cv_LineIterator_getPropPtr(self.as_raw_mut_LineIterator()) };
+		crate::core::validity::debug_check("LineIterator", self.as_raw_mut_LineIterator());
+		let ret = unsafe { ffi_call!(cv_LineIterator_getPropPtr(self.as_raw_mut_LineIterator())) };
 		ret
 	}
 	
+	/// Non-panicking version of [ptr](LineIteratorTrait::ptr), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_ptr(&mut self) -> Result<*mut u8> {
+		crate::core::validity::check("LineIterator", self.as_raw_mut_LineIterator())?;
+		Ok(LineIteratorTrait::ptr(self))
+	}
+	
 	#[inline]
 	unsafe fn set_ptr(&mut self, val: *mut u8) {
-		let ret = { sys::cv_LineIterator_setPropPtr_unsigned_charX(self.as_raw_mut_LineIterator(), val) };
+		crate::core::validity::debug_check("LineIterator", self.as_raw_mut_LineIterator());
+		let ret = { ffi_call!(cv_LineIterator_setPropPtr_unsigned_charX(self.as_raw_mut_LineIterator(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_step(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_LineIterator_setPropStep_int(self.as_raw_mut_LineIterator(), val) };
+		crate::core::validity::debug_check("LineIterator", self.as_raw_mut_LineIterator());
+		let ret = unsafe { ffi_call!(cv_LineIterator_setPropStep_int(self.as_raw_mut_LineIterator(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_elem_size(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_LineIterator_setPropElemSize_int(self.as_raw_mut_LineIterator(), val) };
+		crate::core::validity::debug_check("LineIterator", self.as_raw_mut_LineIterator());
+		let ret = unsafe { ffi_call!(cv_LineIterator_setPropElemSize_int(self.as_raw_mut_LineIterator(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_err(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_LineIterator_setPropErr_int(self.as_raw_mut_LineIterator(), val) };
+		crate::core::validity::debug_check("LineIterator", self.as_raw_mut_LineIterator());
+		let ret = unsafe { ffi_call!(cv_LineIterator_setPropErr_int(self.as_raw_mut_LineIterator(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_count(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_LineIterator_setPropCount_int(self.as_raw_mut_LineIterator(), val) };
+		crate::core::validity::debug_check("LineIterator", self.as_raw_mut_LineIterator());
+		let ret = unsafe { ffi_call!(cv_LineIterator_setPropCount_int(self.as_raw_mut_LineIterator(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_minus_delta(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_LineIterator_setPropMinusDelta_int(self.as_raw_mut_LineIterator(), val) };
+		crate::core::validity::debug_check("LineIterator", self.as_raw_mut_LineIterator());
+		let ret = unsafe { ffi_call!(cv_LineIterator_setPropMinusDelta_int(self.as_raw_mut_LineIterator(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_plus_delta(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_LineIterator_setPropPlusDelta_int(self.as_raw_mut_LineIterator(), val) };
+		crate::core::validity::debug_check("LineIterator", self.as_raw_mut_LineIterator());
+		let ret = unsafe { ffi_call!(cv_LineIterator_setPropPlusDelta_int(self.as_raw_mut_LineIterator(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_minus_step(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_LineIterator_setPropMinusStep_int(self.as_raw_mut_LineIterator(), val) };
+		crate::core::validity::debug_check("LineIterator", self.as_raw_mut_LineIterator());
+		let ret = unsafe { ffi_call!(cv_LineIterator_setPropMinusStep_int(self.as_raw_mut_LineIterator(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_plus_step(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_LineIterator_setPropPlusStep_int(self.as_raw_mut_LineIterator(), val) };
+		crate::core::validity::debug_check("LineIterator", self.as_raw_mut_LineIterator());
+		let ret = unsafe { ffi_call!(cv_LineIterator_setPropPlusStep_int(self.as_raw_mut_LineIterator(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_minus_shift(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_LineIterator_setPropMinusShift_int(self.as_raw_mut_LineIterator(), val) };
+		crate::core::validity::debug_check("LineIterator", self.as_raw_mut_LineIterator());
+		let ret = unsafe { ffi_call!(cv_LineIterator_setPropMinusShift_int(self.as_raw_mut_LineIterator(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_plus_shift(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_LineIterator_setPropPlusShift_int(self.as_raw_mut_LineIterator(), val) };
+		crate::core::validity::debug_check("LineIterator", self.as_raw_mut_LineIterator());
+		let ret = unsafe { ffi_call!(cv_LineIterator_setPropPlusShift_int(self.as_raw_mut_LineIterator(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_p(&mut self, val: core::Point) {
-		let ret = unsafe { sys::cv_LineIterator_setPropP_Point(self.as_raw_mut_LineIterator(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("LineIterator", self.as_raw_mut_LineIterator());
+		let ret = unsafe { ffi_call!(cv_LineIterator_setPropP_Point(self.as_raw_mut_LineIterator(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_ptmode(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_LineIterator_setPropPtmode_bool(self.as_raw_mut_LineIterator(), val) };
+		crate::core::validity::debug_check("LineIterator", self.as_raw_mut_LineIterator());
+		let ret = unsafe { ffi_call!(cv_LineIterator_setPropPtmode_bool(self.as_raw_mut_LineIterator(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn init(&mut self, img: &core::Mat, bounding_area_rect: core::Rect, pt1: core::Point, pt2: core::Point, connectivity: i32, left_to_right: bool) -> Result<()> {
+		crate::core::validity::check("LineIterator", self.as_raw_mut_LineIterator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_LineIterator_init_const_MatX_Rect_Point_Point_int_bool(self.as_raw_mut_LineIterator(), img.as_raw_Mat(), bounding_area_rect.opencv_as_extern(), pt1.opencv_as_extern(), pt2.opencv_as_extern(), connectivity, left_to_right, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_LineIterator_init_const_MatX_Rect_Point_Point_int_bool(self.as_raw_mut_LineIterator(), img.as_raw_Mat(), bounding_area_rect.opencv_as_extern(), pt1.opencv_as_extern(), pt2.opencv_as_extern(), connectivity, left_to_right, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7606,8 +9725,9 @@ pub trait LineIteratorTrait: crate::imgproc::LineIteratorTraitConst {
 	/// returns pointer to the current pixel
 	#[inline]
 	fn try_deref_mut(&mut self) -> Result<*mut u8> {
+		crate::core::validity::check("LineIterator", self.as_raw_mut_LineIterator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_LineIterator_operatorX(self.as_raw_mut_LineIterator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_LineIterator_operatorX(self.as_raw_mut_LineIterator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7616,8 +9736,9 @@ pub trait LineIteratorTrait: crate::imgproc::LineIteratorTraitConst {
 	/// prefix increment operator (++it). shifts iterator to the next pixel
 	#[inline]
 	fn incr(&mut self) -> Result<crate::imgproc::LineIterator> {
+		crate::core::validity::check("LineIterator", self.as_raw_mut_LineIterator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_LineIterator_operatorAA(self.as_raw_mut_LineIterator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_LineIterator_operatorAA(self.as_raw_mut_LineIterator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::imgproc::LineIterator::opencv_from_extern(ret) };
@@ -7667,7 +9788,10 @@ opencv_type_boxed! { LineIterator }
 impl Drop for LineIterator {
 	fn drop(&mut self) {
 		extern "C" { fn cv_LineIterator_delete(instance: *mut c_void); }
-		unsafe { cv_LineIterator_delete(self.as_raw_mut_LineIterator()) };
+		let ptr = self.as_raw_mut_LineIterator();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_LineIterator_delete(ptr) };
 	}
 }
 
@@ -7697,7 +9821,7 @@ impl LineIterator {
 	#[inline]
 	pub fn new(img: &core::Mat, pt1: core::Point, pt2: core::Point, connectivity: i32, left_to_right: bool) -> Result<crate::imgproc::LineIterator> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_LineIterator_LineIterator_const_MatR_Point_Point_int_bool(img.as_raw_Mat(), pt1.opencv_as_extern(), pt2.opencv_as_extern(), connectivity, left_to_right, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_LineIterator_LineIterator_const_MatR_Point_Point_int_bool(img.as_raw_Mat(), pt1.opencv_as_extern(), pt2.opencv_as_extern(), connectivity, left_to_right, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::imgproc::LineIterator::opencv_from_extern(ret) };
@@ -7710,7 +9834,7 @@ impl LineIterator {
 	#[inline]
 	pub fn new_1(pt1: core::Point, pt2: core::Point, connectivity: i32, left_to_right: bool) -> Result<crate::imgproc::LineIterator> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_LineIterator_LineIterator_Point_Point_int_bool(pt1.opencv_as_extern(), pt2.opencv_as_extern(), connectivity, left_to_right, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_LineIterator_LineIterator_Point_Point_int_bool(pt1.opencv_as_extern(), pt2.opencv_as_extern(), connectivity, left_to_right, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::imgproc::LineIterator::opencv_from_extern(ret) };
@@ -7723,7 +9847,7 @@ impl LineIterator {
 	#[inline]
 	pub fn new_2(bounding_area_size: core::Size, pt1: core::Point, pt2: core::Point, connectivity: i32, left_to_right: bool) -> Result<crate::imgproc::LineIterator> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_LineIterator_LineIterator_Size_Point_Point_int_bool(bounding_area_size.opencv_as_extern(), pt1.opencv_as_extern(), pt2.opencv_as_extern(), connectivity, left_to_right, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_LineIterator_LineIterator_Size_Point_Point_int_bool(bounding_area_size.opencv_as_extern(), pt1.opencv_as_extern(), pt2.opencv_as_extern(), connectivity, left_to_right, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::imgproc::LineIterator::opencv_from_extern(ret) };
@@ -7736,7 +9860,7 @@ impl LineIterator {
 	#[inline]
 	pub fn new_3(bounding_area_rect: core::Rect, pt1: core::Point, pt2: core::Point, connectivity: i32, left_to_right: bool) -> Result<crate::imgproc::LineIterator> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_LineIterator_LineIterator_Rect_Point_Point_int_bool(bounding_area_rect.opencv_as_extern(), pt1.opencv_as_extern(), pt2.opencv_as_extern(), connectivity, left_to_right, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_LineIterator_LineIterator_Rect_Point_Point_int_bool(bounding_area_rect.opencv_as_extern(), pt1.opencv_as_extern(), pt2.opencv_as_extern(), connectivity, left_to_right, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::imgproc::LineIterator::opencv_from_extern(ret) };
@@ -7787,13 +9911,14 @@ pub trait LineSegmentDetector: core::AlgorithmTrait + crate::imgproc::LineSegmen
 	/// * nfa: noArray()
 	#[inline]
 	fn detect(&mut self, image: &dyn core::ToInputArray, lines: &mut dyn core::ToOutputArray, width: &mut dyn core::ToOutputArray, prec: &mut dyn core::ToOutputArray, nfa: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("LineSegmentDetector", self.as_raw_mut_LineSegmentDetector())?;
 		input_array_arg!(image);
 		output_array_arg!(lines);
 		output_array_arg!(width);
 		output_array_arg!(prec);
 		output_array_arg!(nfa);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_LineSegmentDetector_detect_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_LineSegmentDetector(), image.as_raw__InputArray(), lines.as_raw__OutputArray(), width.as_raw__OutputArray(), prec.as_raw__OutputArray(), nfa.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_LineSegmentDetector_detect_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_LineSegmentDetector(), image.as_raw__InputArray(), lines.as_raw__OutputArray(), width.as_raw__OutputArray(), prec.as_raw__OutputArray(), nfa.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7806,10 +9931,11 @@ pub trait LineSegmentDetector: core::AlgorithmTrait + crate::imgproc::LineSegmen
 	/// * lines: A vector of the lines that needed to be drawn.
 	#[inline]
 	fn draw_segments(&mut self, image: &mut dyn core::ToInputOutputArray, lines: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("LineSegmentDetector", self.as_raw_mut_LineSegmentDetector())?;
 		input_output_array_arg!(image);
 		input_array_arg!(lines);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_LineSegmentDetector_drawSegments_const__InputOutputArrayR_const__InputArrayR(self.as_raw_mut_LineSegmentDetector(), image.as_raw__InputOutputArray(), lines.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_LineSegmentDetector_drawSegments_const__InputOutputArrayR_const__InputArrayR(self.as_raw_mut_LineSegmentDetector(), image.as_raw__InputOutputArray(), lines.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7828,11 +9954,12 @@ pub trait LineSegmentDetector: core::AlgorithmTrait + crate::imgproc::LineSegmen
 	/// * image: noArray()
 	#[inline]
 	fn compare_segments(&mut self, size: core::Size, lines1: &dyn core::ToInputArray, lines2: &dyn core::ToInputArray, image: &mut dyn core::ToInputOutputArray) -> Result<i32> {
+		crate::core::validity::check("LineSegmentDetector", self.as_raw_mut_LineSegmentDetector())?;
 		input_array_arg!(lines1);
 		input_array_arg!(lines2);
 		input_output_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_LineSegmentDetector_compareSegments_const_SizeR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR(self.as_raw_mut_LineSegmentDetector(), &size, lines1.as_raw__InputArray(), lines2.as_raw__InputArray(), image.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_LineSegmentDetector_compareSegments_const_SizeR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR(self.as_raw_mut_LineSegmentDetector(), &size, lines1.as_raw__InputArray(), lines2.as_raw__InputArray(), image.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7840,6 +9967,58 @@ pub trait LineSegmentDetector: core::AlgorithmTrait + crate::imgproc::LineSegmen
 	
 }
 
+/// Builder for `LineSegmentDetector::detect()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct LineSegmentDetector_DetectBuilder<'a, T: ?Sized + LineSegmentDetector> {
+	instance: &'a mut T,
+	image: &'a dyn core::ToInputArray,
+	lines: &'a mut dyn core::ToOutputArray,
+	width: Option<&'a mut dyn core::ToOutputArray>,
+	prec: Option<&'a mut dyn core::ToOutputArray>,
+	nfa: Option<&'a mut dyn core::ToOutputArray>,
+}
+
+impl<'a, T: ?Sized + LineSegmentDetector> LineSegmentDetector_DetectBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, image: &'a dyn core::ToInputArray, lines: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { instance, image, lines, width: None, prec: None, nfa: None }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_width(mut self, width: &'a mut dyn core::ToOutputArray) -> Self {
+		self.width = Some(width);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_prec(mut self, prec: &'a mut dyn core::ToOutputArray) -> Self {
+		self.prec = Some(prec);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_nfa(mut self, nfa: &'a mut dyn core::ToOutputArray) -> Self {
+		self.nfa = Some(nfa);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let mut width_no_array = core::no_array();
+		let width: &mut dyn core::ToOutputArray = match self.width { Some(width) => width, None => &mut width_no_array };
+		let mut prec_no_array = core::no_array();
+		let prec: &mut dyn core::ToOutputArray = match self.prec { Some(prec) => prec, None => &mut prec_no_array };
+		let mut nfa_no_array = core::no_array();
+		let nfa: &mut dyn core::ToOutputArray = match self.nfa { Some(nfa) => nfa, None => &mut nfa_no_array };
+		LineSegmentDetector::detect(self.instance, self.image, self.lines, width, prec, nfa)
+	}
+}
+
 pub trait Subdiv2DTraitConst {
 	fn as_raw_Subdiv2D(&self) -> *const c_void;
 
@@ -7849,11 +10028,12 @@ pub trait Subdiv2DTraitConst {
 	/// * edgeList: Output vector.
 	/// 
 	/// The function gives each edge as a 4 numbers vector, where each two are one of the edge
-	/// vertices. i.e. org_x = v[0], org_y = v[1], dst_x = v[2], dst_y = v[3].
+	/// vertices. i.e. org_x = v\[0\], org_y = v\[1\], dst_x = v\[2\], dst_y = v\[3\].
 	#[inline]
 	fn get_edge_list(&self, edge_list: &mut core::Vector<core::Vec4f>) -> Result<()> {
+		crate::core::validity::check("Subdiv2D", self.as_raw_Subdiv2D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Subdiv2D_getEdgeList_const_vector_Vec4f_R(self.as_raw_Subdiv2D(), edge_list.as_raw_mut_VectorOfVec4f(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Subdiv2D_getEdgeList_const_vector_Vec4f_R(self.as_raw_Subdiv2D(), edge_list.as_raw_mut_VectorOfVec4f(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7867,8 +10047,9 @@ pub trait Subdiv2DTraitConst {
 	/// The function gives one edge ID for each triangle.
 	#[inline]
 	fn get_leading_edge_list(&self, leading_edge_list: &mut core::Vector<i32>) -> Result<()> {
+		crate::core::validity::check("Subdiv2D", self.as_raw_Subdiv2D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Subdiv2D_getLeadingEdgeList_const_vector_int_R(self.as_raw_Subdiv2D(), leading_edge_list.as_raw_mut_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Subdiv2D_getLeadingEdgeList_const_vector_int_R(self.as_raw_Subdiv2D(), leading_edge_list.as_raw_mut_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7880,11 +10061,12 @@ pub trait Subdiv2DTraitConst {
 	/// * triangleList: Output vector.
 	/// 
 	/// The function gives each triangle as a 6 numbers vector, where each two are one of the triangle
-	/// vertices. i.e. p1_x = v[0], p1_y = v[1], p2_x = v[2], p2_y = v[3], p3_x = v[4], p3_y = v[5].
+	/// vertices. i.e. p1_x = v\[0\], p1_y = v\[1\], p2_x = v\[2\], p2_y = v\[3\], p3_x = v\[4\], p3_y = v\[5\].
 	#[inline]
 	fn get_triangle_list(&self, triangle_list: &mut core::Vector<core::Vec6f>) -> Result<()> {
+		crate::core::validity::check("Subdiv2D", self.as_raw_Subdiv2D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Subdiv2D_getTriangleList_const_vector_Vec6f_R(self.as_raw_Subdiv2D(), triangle_list.as_raw_mut_VectorOfVec6f(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Subdiv2D_getTriangleList_const_vector_Vec6f_R(self.as_raw_Subdiv2D(), triangle_list.as_raw_mut_VectorOfVec6f(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7902,8 +10084,9 @@ pub trait Subdiv2DTraitConst {
 	/// * first_edge: 0
 	#[inline]
 	fn get_vertex(&self, vertex: i32, first_edge: &mut i32) -> Result<core::Point2f> {
+		crate::core::validity::check("Subdiv2D", self.as_raw_Subdiv2D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Subdiv2D_getVertex_const_int_intX(self.as_raw_Subdiv2D(), vertex, first_edge, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Subdiv2D_getVertex_const_int_intX(self.as_raw_Subdiv2D(), vertex, first_edge, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7930,8 +10113,9 @@ pub trait Subdiv2DTraitConst {
 	/// edge ID related to the input edge.
 	#[inline]
 	fn get_edge(&self, edge: i32, next_edge_type: i32) -> Result<i32> {
+		crate::core::validity::check("Subdiv2D", self.as_raw_Subdiv2D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Subdiv2D_getEdge_const_int_int(self.as_raw_Subdiv2D(), edge, next_edge_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Subdiv2D_getEdge_const_int_int(self.as_raw_Subdiv2D(), edge, next_edge_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7947,8 +10131,9 @@ pub trait Subdiv2DTraitConst {
 	/// picture above if e is the input edge).
 	#[inline]
 	fn next_edge(&self, edge: i32) -> Result<i32> {
+		crate::core::validity::check("Subdiv2D", self.as_raw_Subdiv2D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Subdiv2D_nextEdge_const_int(self.as_raw_Subdiv2D(), edge, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Subdiv2D_nextEdge_const_int(self.as_raw_Subdiv2D(), edge, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7969,8 +10154,9 @@ pub trait Subdiv2DTraitConst {
 	/// one of the edges ID of the same quad-edge as the input edge.
 	#[inline]
 	fn rotate_edge(&self, edge: i32, rotate: i32) -> Result<i32> {
+		crate::core::validity::check("Subdiv2D", self.as_raw_Subdiv2D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Subdiv2D_rotateEdge_const_int_int(self.as_raw_Subdiv2D(), edge, rotate, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Subdiv2D_rotateEdge_const_int_int(self.as_raw_Subdiv2D(), edge, rotate, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7978,8 +10164,9 @@ pub trait Subdiv2DTraitConst {
 	
 	#[inline]
 	fn sym_edge(&self, edge: i32) -> Result<i32> {
+		crate::core::validity::check("Subdiv2D", self.as_raw_Subdiv2D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Subdiv2D_symEdge_const_int(self.as_raw_Subdiv2D(), edge, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Subdiv2D_symEdge_const_int(self.as_raw_Subdiv2D(), edge, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7998,8 +10185,9 @@ pub trait Subdiv2DTraitConst {
 	/// * orgpt: 0
 	#[inline]
 	fn edge_org(&self, edge: i32, orgpt: &mut core::Point2f) -> Result<i32> {
+		crate::core::validity::check("Subdiv2D", self.as_raw_Subdiv2D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Subdiv2D_edgeOrg_const_int_Point2fX(self.as_raw_Subdiv2D(), edge, orgpt, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Subdiv2D_edgeOrg_const_int_Point2fX(self.as_raw_Subdiv2D(), edge, orgpt, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8018,8 +10206,9 @@ pub trait Subdiv2DTraitConst {
 	/// * dstpt: 0
 	#[inline]
 	fn edge_dst(&self, edge: i32, dstpt: &mut core::Point2f) -> Result<i32> {
+		crate::core::validity::check("Subdiv2D", self.as_raw_Subdiv2D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Subdiv2D_edgeDst_const_int_Point2fX(self.as_raw_Subdiv2D(), edge, dstpt, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Subdiv2D_edgeDst_const_int_Point2fX(self.as_raw_Subdiv2D(), edge, dstpt, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8036,8 +10225,9 @@ pub trait Subdiv2DTrait: crate::imgproc::Subdiv2DTraitConst {
 	/// * rect: Rectangle that includes all of the 2D points that are to be added to the subdivision.
 	#[inline]
 	fn init_delaunay(&mut self, rect: core::Rect) -> Result<()> {
+		crate::core::validity::check("Subdiv2D", self.as_raw_mut_Subdiv2D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Subdiv2D_initDelaunay_Rect(self.as_raw_mut_Subdiv2D(), rect.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Subdiv2D_initDelaunay_Rect(self.as_raw_mut_Subdiv2D(), rect.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8057,8 +10247,9 @@ pub trait Subdiv2DTrait: crate::imgproc::Subdiv2DTraitConst {
 	/// Note: If the point is outside of the triangulation specified rect a runtime error is raised.
 	#[inline]
 	fn insert(&mut self, pt: core::Point2f) -> Result<i32> {
+		crate::core::validity::check("Subdiv2D", self.as_raw_mut_Subdiv2D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Subdiv2D_insert_Point2f(self.as_raw_mut_Subdiv2D(), pt.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Subdiv2D_insert_Point2f(self.as_raw_mut_Subdiv2D(), pt.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8073,8 +10264,9 @@ pub trait Subdiv2DTrait: crate::imgproc::Subdiv2DTraitConst {
 	/// appropriately.
 	#[inline]
 	fn insert_multiple(&mut self, ptvec: &core::Vector<core::Point2f>) -> Result<()> {
+		crate::core::validity::check("Subdiv2D", self.as_raw_mut_Subdiv2D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Subdiv2D_insert_const_vector_Point2f_R(self.as_raw_mut_Subdiv2D(), ptvec.as_raw_VectorOfPoint2f(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Subdiv2D_insert_const_vector_Point2f_R(self.as_raw_mut_Subdiv2D(), ptvec.as_raw_VectorOfPoint2f(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8103,8 +10295,9 @@ pub trait Subdiv2DTrait: crate::imgproc::Subdiv2DTraitConst {
 	///    processing mode is selected, #PTLOC_ERROR is returned.
 	#[inline]
 	fn locate(&mut self, pt: core::Point2f, edge: &mut i32, vertex: &mut i32) -> Result<i32> {
+		crate::core::validity::check("Subdiv2D", self.as_raw_mut_Subdiv2D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Subdiv2D_locate_Point2f_intR_intR(self.as_raw_mut_Subdiv2D(), pt.opencv_as_extern(), edge, vertex, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Subdiv2D_locate_Point2f_intR_intR(self.as_raw_mut_Subdiv2D(), pt.opencv_as_extern(), edge, vertex, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8128,8 +10321,9 @@ pub trait Subdiv2DTrait: crate::imgproc::Subdiv2DTraitConst {
 	/// * nearest_pt: 0
 	#[inline]
 	fn find_nearest(&mut self, pt: core::Point2f, nearest_pt: &mut core::Point2f) -> Result<i32> {
+		crate::core::validity::check("Subdiv2D", self.as_raw_mut_Subdiv2D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Subdiv2D_findNearest_Point2f_Point2fX(self.as_raw_mut_Subdiv2D(), pt.opencv_as_extern(), nearest_pt, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Subdiv2D_findNearest_Point2f_Point2fX(self.as_raw_mut_Subdiv2D(), pt.opencv_as_extern(), nearest_pt, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8143,8 +10337,9 @@ pub trait Subdiv2DTrait: crate::imgproc::Subdiv2DTraitConst {
 	/// * facetCenters: Output vector of the Voronoi facets center points.
 	#[inline]
 	fn get_voronoi_facet_list(&mut self, idx: &core::Vector<i32>, facet_list: &mut core::Vector<core::Vector<core::Point2f>>, facet_centers: &mut core::Vector<core::Point2f>) -> Result<()> {
+		crate::core::validity::check("Subdiv2D", self.as_raw_mut_Subdiv2D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Subdiv2D_getVoronoiFacetList_const_vector_int_R_vector_vector_Point2f__R_vector_Point2f_R(self.as_raw_mut_Subdiv2D(), idx.as_raw_VectorOfi32(), facet_list.as_raw_mut_VectorOfVectorOfPoint2f(), facet_centers.as_raw_mut_VectorOfPoint2f(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Subdiv2D_getVoronoiFacetList_const_vector_int_R_vector_vector_Point2f__R_vector_Point2f_R(self.as_raw_mut_Subdiv2D(), idx.as_raw_VectorOfi32(), facet_list.as_raw_mut_VectorOfVectorOfPoint2f(), facet_centers.as_raw_mut_VectorOfPoint2f(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8161,7 +10356,10 @@ opencv_type_boxed! { Subdiv2D }
 impl Drop for Subdiv2D {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Subdiv2D_delete(instance: *mut c_void); }
-		unsafe { cv_Subdiv2D_delete(self.as_raw_mut_Subdiv2D()) };
+		let ptr = self.as_raw_mut_Subdiv2D();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Subdiv2D_delete(ptr) };
 	}
 }
 
@@ -8181,7 +10379,7 @@ impl Subdiv2D {
 	#[inline]
 	pub fn default() -> Result<crate::imgproc::Subdiv2D> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Subdiv2D_Subdiv2D(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Subdiv2D_Subdiv2D(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::imgproc::Subdiv2D::opencv_from_extern(ret) };
@@ -8203,7 +10401,7 @@ impl Subdiv2D {
 	#[inline]
 	pub fn new(rect: core::Rect) -> Result<crate::imgproc::Subdiv2D> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Subdiv2D_Subdiv2D_Rect(rect.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Subdiv2D_Subdiv2D_Rect(rect.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::imgproc::Subdiv2D::opencv_from_extern(ret) };
@@ -8233,16 +10431,17 @@ pub trait IntelligentScissorsMBTraitConst {
 	/// 
 	/// ## Parameters
 	/// * targetPt: The target point
-	/// * contour:[out] The list of pixels which contains optimal path between the source and the target points of the image. Type is CV_32SC2 (compatible with `std::vector<Point>`)
+	/// * contour:\[out\] The list of pixels which contains optimal path between the source and the target points of the image. Type is CV_32SC2 (compatible with `std::vector<Point>`)
 	/// * backward: Flag to indicate reverse order of retrived pixels (use "true" value to fetch points from the target to the source point)
 	/// 
 	/// ## C++ default parameters
 	/// * backward: false
 	#[inline]
 	fn get_contour(&self, target_pt: core::Point, contour: &mut dyn core::ToOutputArray, backward: bool) -> Result<()> {
+		crate::core::validity::check("IntelligentScissorsMB", self.as_raw_IntelligentScissorsMB())?;
 		output_array_arg!(contour);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_segmentation_IntelligentScissorsMB_getContour_const_const_PointR_const__OutputArrayR_bool(self.as_raw_IntelligentScissorsMB(), &target_pt, contour.as_raw__OutputArray(), backward, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_segmentation_IntelligentScissorsMB_getContour_const_const_PointR_const__OutputArrayR_bool(self.as_raw_IntelligentScissorsMB(), &target_pt, contour.as_raw__OutputArray(), backward, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8264,8 +10463,9 @@ pub trait IntelligentScissorsMBTrait: crate::imgproc::IntelligentScissorsMBTrait
 	/// * weight_gradient_magnitude: Specify cost of gradient magnitude function (default: 0.14f)
 	#[inline]
 	fn set_weights(&mut self, weight_non_edge: f32, weight_gradient_direction: f32, weight_gradient_magnitude: f32) -> Result<crate::imgproc::IntelligentScissorsMB> {
+		crate::core::validity::check("IntelligentScissorsMB", self.as_raw_mut_IntelligentScissorsMB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_segmentation_IntelligentScissorsMB_setWeights_float_float_float(self.as_raw_mut_IntelligentScissorsMB(), weight_non_edge, weight_gradient_direction, weight_gradient_magnitude, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_segmentation_IntelligentScissorsMB_setWeights_float_float_float(self.as_raw_mut_IntelligentScissorsMB(), weight_non_edge, weight_gradient_direction, weight_gradient_magnitude, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::imgproc::IntelligentScissorsMB::opencv_from_extern(ret) };
@@ -8287,8 +10487,9 @@ pub trait IntelligentScissorsMBTrait: crate::imgproc::IntelligentScissorsMBTrait
 	/// * gradient_magnitude_threshold_max: 0.0f
 	#[inline]
 	fn set_gradient_magnitude_max_limit(&mut self, gradient_magnitude_threshold_max: f32) -> Result<crate::imgproc::IntelligentScissorsMB> {
+		crate::core::validity::check("IntelligentScissorsMB", self.as_raw_mut_IntelligentScissorsMB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_segmentation_IntelligentScissorsMB_setGradientMagnitudeMaxLimit_float(self.as_raw_mut_IntelligentScissorsMB(), gradient_magnitude_threshold_max, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_segmentation_IntelligentScissorsMB_setGradientMagnitudeMaxLimit_float(self.as_raw_mut_IntelligentScissorsMB(), gradient_magnitude_threshold_max, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::imgproc::IntelligentScissorsMB::opencv_from_extern(ret) };
@@ -8315,8 +10516,9 @@ pub trait IntelligentScissorsMBTrait: crate::imgproc::IntelligentScissorsMBTrait
 	/// * gradient_magnitude_min_value: 0.0f
 	#[inline]
 	fn set_edge_feature_zero_crossing_parameters(&mut self, gradient_magnitude_min_value: f32) -> Result<crate::imgproc::IntelligentScissorsMB> {
+		crate::core::validity::check("IntelligentScissorsMB", self.as_raw_mut_IntelligentScissorsMB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_segmentation_IntelligentScissorsMB_setEdgeFeatureZeroCrossingParameters_float(self.as_raw_mut_IntelligentScissorsMB(), gradient_magnitude_min_value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_segmentation_IntelligentScissorsMB_setEdgeFeatureZeroCrossingParameters_float(self.as_raw_mut_IntelligentScissorsMB(), gradient_magnitude_min_value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::imgproc::IntelligentScissorsMB::opencv_from_extern(ret) };
@@ -8328,15 +10530,16 @@ pub trait IntelligentScissorsMBTrait: crate::imgproc::IntelligentScissorsMBTrait
 	/// 
 	/// Note: "Laplacian Zero-Crossing" feature extractor is used by default (following to original article)
 	/// ## See also
-	/// Canny
+	/// [canny](crate::imgproc::canny)
 	/// 
 	/// ## C++ default parameters
 	/// * aperture_size: 3
 	/// * l2gradient: false
 	#[inline]
 	fn set_edge_feature_canny_parameters(&mut self, threshold1: f64, threshold2: f64, aperture_size: i32, l2gradient: bool) -> Result<crate::imgproc::IntelligentScissorsMB> {
+		crate::core::validity::check("IntelligentScissorsMB", self.as_raw_mut_IntelligentScissorsMB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_segmentation_IntelligentScissorsMB_setEdgeFeatureCannyParameters_double_double_int_bool(self.as_raw_mut_IntelligentScissorsMB(), threshold1, threshold2, aperture_size, l2gradient, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_segmentation_IntelligentScissorsMB_setEdgeFeatureCannyParameters_double_double_int_bool(self.as_raw_mut_IntelligentScissorsMB(), threshold1, threshold2, aperture_size, l2gradient, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::imgproc::IntelligentScissorsMB::opencv_from_extern(ret) };
@@ -8349,9 +10552,10 @@ pub trait IntelligentScissorsMBTrait: crate::imgproc::IntelligentScissorsMBTrait
 	/// * image: input image. Type is #CV_8UC1 / #CV_8UC3
 	#[inline]
 	fn apply_image(&mut self, image: &dyn core::ToInputArray) -> Result<crate::imgproc::IntelligentScissorsMB> {
+		crate::core::validity::check("IntelligentScissorsMB", self.as_raw_mut_IntelligentScissorsMB())?;
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_segmentation_IntelligentScissorsMB_applyImage_const__InputArrayR(self.as_raw_mut_IntelligentScissorsMB(), image.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_segmentation_IntelligentScissorsMB_applyImage_const__InputArrayR(self.as_raw_mut_IntelligentScissorsMB(), image.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::imgproc::IntelligentScissorsMB::opencv_from_extern(ret) };
@@ -8372,12 +10576,13 @@ pub trait IntelligentScissorsMBTrait: crate::imgproc::IntelligentScissorsMBTrait
 	/// * image: noArray()
 	#[inline]
 	fn apply_image_features(&mut self, non_edge: &dyn core::ToInputArray, gradient_direction: &dyn core::ToInputArray, gradient_magnitude: &dyn core::ToInputArray, image: &dyn core::ToInputArray) -> Result<crate::imgproc::IntelligentScissorsMB> {
+		crate::core::validity::check("IntelligentScissorsMB", self.as_raw_mut_IntelligentScissorsMB())?;
 		input_array_arg!(non_edge);
 		input_array_arg!(gradient_direction);
 		input_array_arg!(gradient_magnitude);
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_segmentation_IntelligentScissorsMB_applyImageFeatures_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_IntelligentScissorsMB(), non_edge.as_raw__InputArray(), gradient_direction.as_raw__InputArray(), gradient_magnitude.as_raw__InputArray(), image.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_segmentation_IntelligentScissorsMB_applyImageFeatures_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_IntelligentScissorsMB(), non_edge.as_raw__InputArray(), gradient_direction.as_raw__InputArray(), gradient_magnitude.as_raw__InputArray(), image.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::imgproc::IntelligentScissorsMB::opencv_from_extern(ret) };
@@ -8393,8 +10598,9 @@ pub trait IntelligentScissorsMBTrait: crate::imgproc::IntelligentScissorsMBTrait
 	/// * sourcePt: The source point used to find the paths
 	#[inline]
 	fn build_map(&mut self, source_pt: core::Point) -> Result<()> {
+		crate::core::validity::check("IntelligentScissorsMB", self.as_raw_mut_IntelligentScissorsMB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_segmentation_IntelligentScissorsMB_buildMap_const_PointR(self.as_raw_mut_IntelligentScissorsMB(), &source_pt, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_segmentation_IntelligentScissorsMB_buildMap_const_PointR(self.as_raw_mut_IntelligentScissorsMB(), &source_pt, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8422,7 +10628,10 @@ opencv_type_boxed! { IntelligentScissorsMB }
 impl Drop for IntelligentScissorsMB {
 	fn drop(&mut self) {
 		extern "C" { fn cv_IntelligentScissorsMB_delete(instance: *mut c_void); }
-		unsafe { cv_IntelligentScissorsMB_delete(self.as_raw_mut_IntelligentScissorsMB()) };
+		let ptr = self.as_raw_mut_IntelligentScissorsMB();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_IntelligentScissorsMB_delete(ptr) };
 	}
 }
 
@@ -8440,7 +10649,7 @@ impl IntelligentScissorsMB {
 	#[inline]
 	pub fn default() -> Result<crate::imgproc::IntelligentScissorsMB> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_segmentation_IntelligentScissorsMB_IntelligentScissorsMB(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_segmentation_IntelligentScissorsMB_IntelligentScissorsMB(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::imgproc::IntelligentScissorsMB::opencv_from_extern(ret) };
@@ -8448,3 +10657,4 @@ impl IntelligentScissorsMB {
 	}
 	
 }
+pub use crate::manual::imgproc::*;
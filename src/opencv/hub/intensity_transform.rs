@@ -19,7 +19,7 @@
 //! - BIMEF, A Bio-Inspired Multi-Exposure Fusion Framework for Low-light Image Enhancement [ying2017bio](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_ying2017bio) [ying2017new](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_ying2017new)
 //! 
 //! References from following book and websites:
-//! - Digital Image Processing 4th Edition Chapter 3 [Rafael C. Gonzalez, Richard E. Woods] [Gonzalez2018](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_Gonzalez2018)
+//! - Digital Image Processing 4th Edition Chapter 3 \[Rafael C. Gonzalez, Richard E. Woods\] [Gonzalez2018](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_Gonzalez2018)
 //! - http://www.cs.uregina.ca/Links/class-info/425/Lab3/ [lcs435lab](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_lcs435lab)
 //! - https://theailearner.com/2019/01/30/contrast-stretching/ [theailearner](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_theailearner)
 use crate::{mod_prelude::*, core, sys, types};
@@ -49,12 +49,57 @@ pub fn bimef(input: &dyn core::ToInputArray, output: &mut dyn core::ToOutputArra
 	input_array_arg!(input);
 	output_array_arg!(output);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_intensity_transform_BIMEF_const__InputArrayR_const__OutputArrayR_float_float_float(input.as_raw__InputArray(), output.as_raw__OutputArray(), mu, a, b, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_intensity_transform_BIMEF_const__InputArrayR_const__OutputArrayR_float_float_float(input.as_raw__InputArray(), output.as_raw__OutputArray(), mu, a, b, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `bimef()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct BimefBuilder<'a> {
+	input: &'a dyn core::ToInputArray,
+	output: &'a mut dyn core::ToOutputArray,
+	mu: f32,
+	a: f32,
+	b: f32,
+}
+
+impl<'a> BimefBuilder<'a> {
+	#[inline]
+	pub fn new(input: &'a dyn core::ToInputArray, output: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { input, output, mu: 0.5, a: -0.3293, b: 1.1258 }
+	}
+	
+	/// C++ default: `0.5f`
+	#[inline]
+	pub fn with_mu(mut self, mu: f32) -> Self {
+		self.mu = mu;
+		self
+	}
+	
+	/// C++ default: `-0.3293f`
+	#[inline]
+	pub fn with_a(mut self, a: f32) -> Self {
+		self.a = a;
+		self
+	}
+	
+	/// C++ default: `1.1258f`
+	#[inline]
+	pub fn with_b(mut self, b: f32) -> Self {
+		self.b = b;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		bimef(self.input, self.output, self.mu, self.a, self.b)
+	}
+}
+
 /// Given an input color image, enhance low-light images using the BIMEF method ([ying2017bio](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_ying2017bio) [ying2017new](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_ying2017new)).
 /// 
 /// This is an overloaded function with the exposure ratio given as parameter.
@@ -75,13 +120,13 @@ pub fn bimef2(input: &dyn core::ToInputArray, output: &mut dyn core::ToOutputArr
 	input_array_arg!(input);
 	output_array_arg!(output);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_intensity_transform_BIMEF_const__InputArrayR_const__OutputArrayR_float_float_float_float(input.as_raw__InputArray(), output.as_raw__OutputArray(), k, mu, a, b, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_intensity_transform_BIMEF_const__InputArrayR_const__OutputArrayR_float_float_float_float(input.as_raw__InputArray(), output.as_raw__OutputArray(), k, mu, a, b, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
-/// Given an input bgr or grayscale image, apply autoscaling on domain [0, 255] to increase
+/// Given an input bgr or grayscale image, apply autoscaling on domain \[0, 255\] to increase
 /// the contrast of the input image and return the resulting image.
 /// 
 /// ## Parameters
@@ -90,13 +135,13 @@ pub fn bimef2(input: &dyn core::ToInputArray, output: &mut dyn core::ToOutputArr
 #[inline]
 pub fn autoscaling(input: core::Mat, output: &mut core::Mat) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_intensity_transform_autoscaling_const_Mat_MatR(input.as_raw_Mat(), output.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_intensity_transform_autoscaling_const_Mat_MatR(input.as_raw_Mat(), output.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
-/// Given an input bgr or grayscale image, apply linear contrast stretching on domain [0, 255]
+/// Given an input bgr or grayscale image, apply linear contrast stretching on domain \[0, 255\]
 /// and return the resulting image.
 /// 
 /// ## Parameters
@@ -109,14 +154,14 @@ pub fn autoscaling(input: core::Mat, output: &mut core::Mat) -> Result<()> {
 #[inline]
 pub fn contrast_stretching(input: core::Mat, output: &mut core::Mat, r1: i32, s1: i32, r2: i32, s2: i32) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_intensity_transform_contrastStretching_const_Mat_MatR_const_int_const_int_const_int_const_int(input.as_raw_Mat(), output.as_raw_mut_Mat(), r1, s1, r2, s2, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_intensity_transform_contrastStretching_const_Mat_MatR_const_int_const_int_const_int_const_int(input.as_raw_Mat(), output.as_raw_mut_Mat(), r1, s1, r2, s2, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
 /// Given an input bgr or grayscale image and constant gamma, apply power-law transformation,
-/// a.k.a. gamma correction to the image on domain [0, 255] and return the resulting image.
+/// a.k.a. gamma correction to the image on domain \[0, 255\] and return the resulting image.
 /// 
 /// ## Parameters
 /// * input: input bgr or grayscale image.
@@ -125,14 +170,14 @@ pub fn contrast_stretching(input: core::Mat, output: &mut core::Mat, r1: i32, s1
 #[inline]
 pub fn gamma_correction(input: core::Mat, output: &mut core::Mat, gamma: f32) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_intensity_transform_gammaCorrection_const_Mat_MatR_const_float(input.as_raw_Mat(), output.as_raw_mut_Mat(), gamma, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_intensity_transform_gammaCorrection_const_Mat_MatR_const_float(input.as_raw_Mat(), output.as_raw_mut_Mat(), gamma, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
 /// Given an input bgr or grayscale image and constant c, apply log transformation to the image
-/// on domain [0, 255] and return the resulting image.
+/// on domain \[0, 255\] and return the resulting image.
 /// 
 /// ## Parameters
 /// * input: input bgr or grayscale image.
@@ -140,7 +185,7 @@ pub fn gamma_correction(input: core::Mat, output: &mut core::Mat, gamma: f32) ->
 #[inline]
 pub fn log_transform(input: core::Mat, output: &mut core::Mat) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_intensity_transform_logTransform_const_Mat_MatR(input.as_raw_Mat(), output.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_intensity_transform_logTransform_const_Mat_MatR(input.as_raw_Mat(), output.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -114,7 +114,7 @@ pub type uint8 = u8;
 #[inline]
 pub fn draw_keylines(image: &core::Mat, keylines: &core::Vector<crate::line_descriptor::KeyLine>, out_image: &mut core::Mat, color: core::Scalar, flags: i32) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_line_descriptor_drawKeylines_const_MatR_const_vector_KeyLine_R_MatR_const_ScalarR_int(image.as_raw_Mat(), keylines.as_raw_VectorOfKeyLine(), out_image.as_raw_mut_Mat(), &color, flags, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_line_descriptor_drawKeylines_const_MatR_const_vector_KeyLine_R_MatR_const_ScalarR_int(image.as_raw_Mat(), keylines.as_raw_VectorOfKeyLine(), out_image.as_raw_mut_Mat(), &color, flags, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -146,17 +146,60 @@ pub fn draw_keylines(image: &core::Mat, keylines: &core::Vector<crate::line_desc
 #[inline]
 pub fn draw_line_matches(img1: &core::Mat, keylines1: &core::Vector<crate::line_descriptor::KeyLine>, img2: &core::Mat, keylines2: &core::Vector<crate::line_descriptor::KeyLine>, matches1to2: &core::Vector<core::DMatch>, out_img: &mut core::Mat, match_color: core::Scalar, single_line_color: core::Scalar, matches_mask: &core::Vector<i8>, flags: i32) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_line_descriptor_drawLineMatches_const_MatR_const_vector_KeyLine_R_const_MatR_const_vector_KeyLine_R_const_vector_DMatch_R_MatR_const_ScalarR_const_ScalarR_const_vector_char_R_int(img1.as_raw_Mat(), keylines1.as_raw_VectorOfKeyLine(), img2.as_raw_Mat(), keylines2.as_raw_VectorOfKeyLine(), matches1to2.as_raw_VectorOfDMatch(), out_img.as_raw_mut_Mat(), &match_color, &single_line_color, matches_mask.as_raw_VectorOfi8(), flags, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_line_descriptor_drawLineMatches_const_MatR_const_vector_KeyLine_R_const_MatR_const_vector_KeyLine_R_const_vector_DMatch_R_MatR_const_ScalarR_const_ScalarR_const_vector_char_R_int(img1.as_raw_Mat(), keylines1.as_raw_VectorOfKeyLine(), img2.as_raw_Mat(), keylines2.as_raw_VectorOfKeyLine(), matches1to2.as_raw_VectorOfDMatch(), out_img.as_raw_mut_Mat(), &match_color, &single_line_color, matches_mask.as_raw_VectorOfi8(), flags, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `draw_line_matches()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct DrawLineMatchesBuilder<'a> {
+	img1: &'a core::Mat,
+	keylines1: &'a core::Vector<crate::line_descriptor::KeyLine>,
+	img2: &'a core::Mat,
+	keylines2: &'a core::Vector<crate::line_descriptor::KeyLine>,
+	matches1to2: &'a core::Vector<core::DMatch>,
+	out_img: &'a mut core::Mat,
+	match_color: core::Scalar,
+	single_line_color: core::Scalar,
+	matches_mask: &'a core::Vector<i8>,
+	flags: i32,
+}
+
+impl<'a> DrawLineMatchesBuilder<'a> {
+	#[inline]
+	pub fn new(img1: &'a core::Mat, keylines1: &'a core::Vector<crate::line_descriptor::KeyLine>, img2: &'a core::Mat, keylines2: &'a core::Vector<crate::line_descriptor::KeyLine>, matches1to2: &'a core::Vector<core::DMatch>, out_img: &'a mut core::Mat, matches_mask: &'a core::Vector<i8>, flags: i32) -> Self {
+		Self { img1, keylines1, img2, keylines2, matches1to2, out_img, match_color: core::Scalar::all(-1.), single_line_color: core::Scalar::all(-1.), matches_mask, flags }
+	}
+	
+	/// C++ default: `Scalar::all(-1)`
+	#[inline]
+	pub fn with_match_color(mut self, match_color: core::Scalar) -> Self {
+		self.match_color = match_color;
+		self
+	}
+	
+	/// C++ default: `Scalar::all(-1)`
+	#[inline]
+	pub fn with_single_line_color(mut self, single_line_color: core::Scalar) -> Self {
+		self.single_line_color = single_line_color;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		draw_line_matches(self.img1, self.keylines1, self.img2, self.keylines2, self.matches1to2, self.out_img, self.match_color, self.single_line_color, self.matches_mask, self.flags)
+	}
+}
+
 /// Class implements both functionalities for detection of lines and computation of their
 /// binary descriptor.
 /// 
 /// Class' interface is mainly based on the ones of classical detectors and extractors, such as
-/// Feature2d's @ref features2d_main and @ref features2d_match. Retrieved information about lines is
+/// Feature2d's features2d_main and features2d_match. Retrieved information about lines is
 /// stored in line_descriptor::KeyLine objects.
 pub trait BinaryDescriptorTraitConst: core::AlgorithmTraitConst {
 	fn as_raw_BinaryDescriptor(&self) -> *const c_void;
@@ -167,8 +210,9 @@ pub trait BinaryDescriptorTraitConst: core::AlgorithmTraitConst {
 	/// * fs: output FileStorage file
 	#[inline]
 	fn write(&self, fs: &mut core::FileStorage) -> Result<()> {
+		crate::core::validity::check("BinaryDescriptor", self.as_raw_BinaryDescriptor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptor_write_const_FileStorageR(self.as_raw_BinaryDescriptor(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptor_write_const_FileStorageR(self.as_raw_BinaryDescriptor(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -192,8 +236,9 @@ pub trait BinaryDescriptorTraitConst: core::AlgorithmTraitConst {
 	/// * masks: std::vector<Mat>()
 	#[inline]
 	fn detect(&self, images: &core::Vector<core::Mat>, keylines: &mut core::Vector<core::Vector<crate::line_descriptor::KeyLine>>, masks: &core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("BinaryDescriptor", self.as_raw_BinaryDescriptor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptor_detect_const_const_vector_Mat_R_vector_vector_KeyLine__R_const_vector_Mat_R(self.as_raw_BinaryDescriptor(), images.as_raw_VectorOfMat(), keylines.as_raw_mut_VectorOfVectorOfKeyLine(), masks.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptor_detect_const_const_vector_Mat_R_vector_vector_KeyLine__R_const_vector_Mat_R(self.as_raw_BinaryDescriptor(), images.as_raw_VectorOfMat(), keylines.as_raw_mut_VectorOfVectorOfKeyLine(), masks.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -211,8 +256,9 @@ pub trait BinaryDescriptorTraitConst: core::AlgorithmTraitConst {
 	/// * return_float_descr: false
 	#[inline]
 	fn compute(&self, image: &core::Mat, keylines: &mut core::Vector<crate::line_descriptor::KeyLine>, descriptors: &mut core::Mat, return_float_descr: bool) -> Result<()> {
+		crate::core::validity::check("BinaryDescriptor", self.as_raw_BinaryDescriptor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptor_compute_const_const_MatR_vector_KeyLine_R_MatR_bool(self.as_raw_BinaryDescriptor(), image.as_raw_Mat(), keylines.as_raw_mut_VectorOfKeyLine(), descriptors.as_raw_mut_Mat(), return_float_descr, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptor_compute_const_const_MatR_vector_KeyLine_R_MatR_bool(self.as_raw_BinaryDescriptor(), image.as_raw_Mat(), keylines.as_raw_mut_VectorOfKeyLine(), descriptors.as_raw_mut_Mat(), return_float_descr, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -238,8 +284,9 @@ pub trait BinaryDescriptorTraitConst: core::AlgorithmTraitConst {
 	/// * return_float_descr: false
 	#[inline]
 	fn compute_1(&self, images: &core::Vector<core::Mat>, keylines: &mut core::Vector<core::Vector<crate::line_descriptor::KeyLine>>, descriptors: &mut core::Vector<core::Mat>, return_float_descr: bool) -> Result<()> {
+		crate::core::validity::check("BinaryDescriptor", self.as_raw_BinaryDescriptor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptor_compute_const_const_vector_Mat_R_vector_vector_KeyLine__R_vector_Mat_R_bool(self.as_raw_BinaryDescriptor(), images.as_raw_VectorOfMat(), keylines.as_raw_mut_VectorOfVectorOfKeyLine(), descriptors.as_raw_mut_VectorOfMat(), return_float_descr, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptor_compute_const_const_vector_Mat_R_vector_vector_KeyLine__R_vector_Mat_R_bool(self.as_raw_BinaryDescriptor(), images.as_raw_VectorOfMat(), keylines.as_raw_mut_VectorOfVectorOfKeyLine(), descriptors.as_raw_mut_VectorOfMat(), return_float_descr, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -248,8 +295,9 @@ pub trait BinaryDescriptorTraitConst: core::AlgorithmTraitConst {
 	/// Return descriptor size
 	#[inline]
 	fn descriptor_size(&self) -> Result<i32> {
+		crate::core::validity::check("BinaryDescriptor", self.as_raw_BinaryDescriptor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptor_descriptorSize_const(self.as_raw_BinaryDescriptor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptor_descriptorSize_const(self.as_raw_BinaryDescriptor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -258,8 +306,9 @@ pub trait BinaryDescriptorTraitConst: core::AlgorithmTraitConst {
 	/// Return data type
 	#[inline]
 	fn descriptor_type(&self) -> Result<i32> {
+		crate::core::validity::check("BinaryDescriptor", self.as_raw_BinaryDescriptor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptor_descriptorType_const(self.as_raw_BinaryDescriptor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptor_descriptorType_const(self.as_raw_BinaryDescriptor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -268,8 +317,9 @@ pub trait BinaryDescriptorTraitConst: core::AlgorithmTraitConst {
 	/// returns norm mode
 	#[inline]
 	fn default_norm(&self) -> Result<i32> {
+		crate::core::validity::check("BinaryDescriptor", self.as_raw_BinaryDescriptor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptor_defaultNorm_const(self.as_raw_BinaryDescriptor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptor_defaultNorm_const(self.as_raw_BinaryDescriptor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -283,8 +333,9 @@ pub trait BinaryDescriptorTrait: core::AlgorithmTrait + crate::line_descriptor::
 	/// Get current number of octaves
 	#[inline]
 	fn get_num_of_octaves(&mut self) -> Result<i32> {
+		crate::core::validity::check("BinaryDescriptor", self.as_raw_mut_BinaryDescriptor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptor_getNumOfOctaves(self.as_raw_mut_BinaryDescriptor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptor_getNumOfOctaves(self.as_raw_mut_BinaryDescriptor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -295,8 +346,9 @@ pub trait BinaryDescriptorTrait: core::AlgorithmTrait + crate::line_descriptor::
 	/// * octaves: number of octaves
 	#[inline]
 	fn set_num_of_octaves(&mut self, octaves: i32) -> Result<()> {
+		crate::core::validity::check("BinaryDescriptor", self.as_raw_mut_BinaryDescriptor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptor_setNumOfOctaves_int(self.as_raw_mut_BinaryDescriptor(), octaves, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptor_setNumOfOctaves_int(self.as_raw_mut_BinaryDescriptor(), octaves, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -305,8 +357,9 @@ pub trait BinaryDescriptorTrait: core::AlgorithmTrait + crate::line_descriptor::
 	/// Get current width of bands
 	#[inline]
 	fn get_width_of_band(&mut self) -> Result<i32> {
+		crate::core::validity::check("BinaryDescriptor", self.as_raw_mut_BinaryDescriptor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptor_getWidthOfBand(self.as_raw_mut_BinaryDescriptor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptor_getWidthOfBand(self.as_raw_mut_BinaryDescriptor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -317,8 +370,9 @@ pub trait BinaryDescriptorTrait: core::AlgorithmTrait + crate::line_descriptor::
 	/// * width: width of bands
 	#[inline]
 	fn set_width_of_band(&mut self, width: i32) -> Result<()> {
+		crate::core::validity::check("BinaryDescriptor", self.as_raw_mut_BinaryDescriptor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptor_setWidthOfBand_int(self.as_raw_mut_BinaryDescriptor(), width, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptor_setWidthOfBand_int(self.as_raw_mut_BinaryDescriptor(), width, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -327,8 +381,9 @@ pub trait BinaryDescriptorTrait: core::AlgorithmTrait + crate::line_descriptor::
 	/// Get current reduction ratio (used in Gaussian pyramids)
 	#[inline]
 	fn get_reduction_ratio(&mut self) -> Result<i32> {
+		crate::core::validity::check("BinaryDescriptor", self.as_raw_mut_BinaryDescriptor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptor_getReductionRatio(self.as_raw_mut_BinaryDescriptor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptor_getReductionRatio(self.as_raw_mut_BinaryDescriptor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -339,8 +394,9 @@ pub trait BinaryDescriptorTrait: core::AlgorithmTrait + crate::line_descriptor::
 	/// * rRatio: reduction ratio
 	#[inline]
 	fn set_reduction_ratio(&mut self, r_ratio: i32) -> Result<()> {
+		crate::core::validity::check("BinaryDescriptor", self.as_raw_mut_BinaryDescriptor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptor_setReductionRatio_int(self.as_raw_mut_BinaryDescriptor(), r_ratio, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptor_setReductionRatio_int(self.as_raw_mut_BinaryDescriptor(), r_ratio, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -352,8 +408,9 @@ pub trait BinaryDescriptorTrait: core::AlgorithmTrait + crate::line_descriptor::
 	/// * fn: source FileNode file
 	#[inline]
 	fn read(&mut self, fn_: &core::FileNode) -> Result<()> {
+		crate::core::validity::check("BinaryDescriptor", self.as_raw_mut_BinaryDescriptor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptor_read_const_FileNodeR(self.as_raw_mut_BinaryDescriptor(), fn_.as_raw_FileNode(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptor_read_const_FileNodeR(self.as_raw_mut_BinaryDescriptor(), fn_.as_raw_FileNode(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -370,8 +427,9 @@ pub trait BinaryDescriptorTrait: core::AlgorithmTrait + crate::line_descriptor::
 	/// * mask: Mat()
 	#[inline]
 	fn detect_1(&mut self, image: &core::Mat, keypoints: &mut core::Vector<crate::line_descriptor::KeyLine>, mask: &core::Mat) -> Result<()> {
+		crate::core::validity::check("BinaryDescriptor", self.as_raw_mut_BinaryDescriptor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptor_detect_const_MatR_vector_KeyLine_R_const_MatR(self.as_raw_mut_BinaryDescriptor(), image.as_raw_Mat(), keypoints.as_raw_mut_VectorOfKeyLine(), mask.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptor_detect_const_MatR_vector_KeyLine_R_const_MatR(self.as_raw_mut_BinaryDescriptor(), image.as_raw_Mat(), keypoints.as_raw_mut_VectorOfKeyLine(), mask.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -383,7 +441,7 @@ pub trait BinaryDescriptorTrait: core::AlgorithmTrait + crate::line_descriptor::
 /// binary descriptor.
 /// 
 /// Class' interface is mainly based on the ones of classical detectors and extractors, such as
-/// Feature2d's @ref features2d_main and @ref features2d_match. Retrieved information about lines is
+/// Feature2d's features2d_main and features2d_match. Retrieved information about lines is
 /// stored in line_descriptor::KeyLine objects.
 pub struct BinaryDescriptor {
 	ptr: *mut c_void
@@ -394,7 +452,10 @@ opencv_type_boxed! { BinaryDescriptor }
 impl Drop for BinaryDescriptor {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BinaryDescriptor_delete(instance: *mut c_void); }
-		unsafe { cv_BinaryDescriptor_delete(self.as_raw_mut_BinaryDescriptor()) };
+		let ptr = self.as_raw_mut_BinaryDescriptor();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_BinaryDescriptor_delete(ptr) };
 	}
 }
 
@@ -430,7 +491,7 @@ impl BinaryDescriptor {
 	#[inline]
 	pub fn new(parameters: &crate::line_descriptor::BinaryDescriptor_Params) -> Result<crate::line_descriptor::BinaryDescriptor> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptor_BinaryDescriptor_const_ParamsR(parameters.as_raw_BinaryDescriptor_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptor_BinaryDescriptor_const_ParamsR(parameters.as_raw_BinaryDescriptor_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::line_descriptor::BinaryDescriptor::opencv_from_extern(ret) };
@@ -442,7 +503,7 @@ impl BinaryDescriptor {
 	#[inline]
 	pub fn create_binary_descriptor() -> Result<core::Ptr<crate::line_descriptor::BinaryDescriptor>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptor_createBinaryDescriptor(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptor_createBinaryDescriptor(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::line_descriptor::BinaryDescriptor>::opencv_from_extern(ret) };
@@ -452,7 +513,7 @@ impl BinaryDescriptor {
 	#[inline]
 	pub fn create_binary_descriptor_1(mut parameters: crate::line_descriptor::BinaryDescriptor_Params) -> Result<core::Ptr<crate::line_descriptor::BinaryDescriptor>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptor_createBinaryDescriptor_Params(parameters.as_raw_mut_BinaryDescriptor_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptor_createBinaryDescriptor_Params(parameters.as_raw_mut_BinaryDescriptor_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::line_descriptor::BinaryDescriptor>::opencv_from_extern(ret) };
@@ -470,35 +531,72 @@ pub trait BinaryDescriptor_ParamsTraitConst {
 	/// the number of image octaves (default = 1)
 	#[inline]
 	fn num_of_octave_(&self) -> i32 {
-		let ret = unsafe { sys::cv_line_descriptor_BinaryDescriptor_Params_getPropNumOfOctave__const(self.as_raw_BinaryDescriptor_Params()) };
+		crate::core::validity::debug_check("BinaryDescriptor_Params", self.as_raw_BinaryDescriptor_Params());
+		let ret = unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptor_Params_getPropNumOfOctave__const(self.as_raw_BinaryDescriptor_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [num_of_octave_](BinaryDescriptor_ParamsTraitConst::num_of_octave_), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_num_of_octave_(&self) -> Result<i32> {
+		crate::core::validity::check("BinaryDescriptor_Params", self.as_raw_BinaryDescriptor_Params())?;
+		Ok(BinaryDescriptor_ParamsTraitConst::num_of_octave_(self))
+	}
+	
 	/// the width of band; (default: 7)
 	#[inline]
 	fn width_of_band_(&self) -> i32 {
-		let ret = unsafe { sys::cv_line_descriptor_BinaryDescriptor_Params_getPropWidthOfBand__const(self.as_raw_BinaryDescriptor_Params()) };
+		crate::core::validity::debug_check("BinaryDescriptor_Params", self.as_raw_BinaryDescriptor_Params());
+		let ret = unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptor_Params_getPropWidthOfBand__const(self.as_raw_BinaryDescriptor_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [width_of_band_](BinaryDescriptor_ParamsTraitConst::width_of_band_), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_width_of_band_(&self) -> Result<i32> {
+		crate::core::validity::check("BinaryDescriptor_Params", self.as_raw_BinaryDescriptor_Params())?;
+		Ok(BinaryDescriptor_ParamsTraitConst::width_of_band_(self))
+	}
+	
 	/// image's reduction ratio in construction of Gaussian pyramids
 	#[inline]
 	fn reduction_ratio(&self) -> i32 {
-		let ret = unsafe { sys::cv_line_descriptor_BinaryDescriptor_Params_getPropReductionRatio_const(self.as_raw_BinaryDescriptor_Params()) };
+		crate::core::validity::debug_check("BinaryDescriptor_Params", self.as_raw_BinaryDescriptor_Params());
+		let ret = unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptor_Params_getPropReductionRatio_const(self.as_raw_BinaryDescriptor_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [reduction_ratio](BinaryDescriptor_ParamsTraitConst::reduction_ratio), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_reduction_ratio(&self) -> Result<i32> {
+		crate::core::validity::check("BinaryDescriptor_Params", self.as_raw_BinaryDescriptor_Params())?;
+		Ok(BinaryDescriptor_ParamsTraitConst::reduction_ratio(self))
+	}
+	
 	#[inline]
 	fn ksize_(&self) -> i32 {
-		let ret = unsafe { sys::cv_line_descriptor_BinaryDescriptor_Params_getPropKsize__const(self.as_raw_BinaryDescriptor_Params()) };
+		crate::core::validity::debug_check("BinaryDescriptor_Params", self.as_raw_BinaryDescriptor_Params());
+		let ret = unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptor_Params_getPropKsize__const(self.as_raw_BinaryDescriptor_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [ksize_](BinaryDescriptor_ParamsTraitConst::ksize_), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_ksize_(&self) -> Result<i32> {
+		crate::core::validity::check("BinaryDescriptor_Params", self.as_raw_BinaryDescriptor_Params())?;
+		Ok(BinaryDescriptor_ParamsTraitConst::ksize_(self))
+	}
+	
 	/// store parameters to a FileStorage object (struct function)
 	#[inline]
 	fn write(&self, fs: &mut core::FileStorage) -> Result<()> {
+		crate::core::validity::check("BinaryDescriptor_Params", self.as_raw_BinaryDescriptor_Params())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptor_Params_write_const_FileStorageR(self.as_raw_BinaryDescriptor_Params(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptor_Params_write_const_FileStorageR(self.as_raw_BinaryDescriptor_Params(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -512,35 +610,40 @@ pub trait BinaryDescriptor_ParamsTrait: crate::line_descriptor::BinaryDescriptor
 	/// the number of image octaves (default = 1)
 	#[inline]
 	fn set_num_of_octave_(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_line_descriptor_BinaryDescriptor_Params_setPropNumOfOctave__int(self.as_raw_mut_BinaryDescriptor_Params(), val) };
+		crate::core::validity::debug_check("BinaryDescriptor_Params", self.as_raw_mut_BinaryDescriptor_Params());
+		let ret = unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptor_Params_setPropNumOfOctave__int(self.as_raw_mut_BinaryDescriptor_Params(), val)) };
 		ret
 	}
 	
 	/// the width of band; (default: 7)
 	#[inline]
 	fn set_width_of_band_(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_line_descriptor_BinaryDescriptor_Params_setPropWidthOfBand__int(self.as_raw_mut_BinaryDescriptor_Params(), val) };
+		crate::core::validity::debug_check("BinaryDescriptor_Params", self.as_raw_mut_BinaryDescriptor_Params());
+		let ret = unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptor_Params_setPropWidthOfBand__int(self.as_raw_mut_BinaryDescriptor_Params(), val)) };
 		ret
 	}
 	
 	/// image's reduction ratio in construction of Gaussian pyramids
 	#[inline]
 	fn set_reduction_ratio(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_line_descriptor_BinaryDescriptor_Params_setPropReductionRatio_int(self.as_raw_mut_BinaryDescriptor_Params(), val) };
+		crate::core::validity::debug_check("BinaryDescriptor_Params", self.as_raw_mut_BinaryDescriptor_Params());
+		let ret = unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptor_Params_setPropReductionRatio_int(self.as_raw_mut_BinaryDescriptor_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_ksize_(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_line_descriptor_BinaryDescriptor_Params_setPropKsize__int(self.as_raw_mut_BinaryDescriptor_Params(), val) };
+		crate::core::validity::debug_check("BinaryDescriptor_Params", self.as_raw_mut_BinaryDescriptor_Params());
+		let ret = unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptor_Params_setPropKsize__int(self.as_raw_mut_BinaryDescriptor_Params(), val)) };
 		ret
 	}
 	
 	/// read parameters from a FileNode object and store them (struct function)
 	#[inline]
 	fn read(&mut self, fn_: &core::FileNode) -> Result<()> {
+		crate::core::validity::check("BinaryDescriptor_Params", self.as_raw_mut_BinaryDescriptor_Params())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptor_Params_read_const_FileNodeR(self.as_raw_mut_BinaryDescriptor_Params(), fn_.as_raw_FileNode(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptor_Params_read_const_FileNodeR(self.as_raw_mut_BinaryDescriptor_Params(), fn_.as_raw_FileNode(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -558,7 +661,10 @@ opencv_type_boxed! { BinaryDescriptor_Params }
 impl Drop for BinaryDescriptor_Params {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BinaryDescriptor_Params_delete(instance: *mut c_void); }
-		unsafe { cv_BinaryDescriptor_Params_delete(self.as_raw_mut_BinaryDescriptor_Params()) };
+		let ptr = self.as_raw_mut_BinaryDescriptor_Params();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_BinaryDescriptor_Params_delete(ptr) };
 	}
 }
 
@@ -576,7 +682,7 @@ impl BinaryDescriptor_Params {
 	#[inline]
 	pub fn default() -> Result<crate::line_descriptor::BinaryDescriptor_Params> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptor_Params_Params(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptor_Params_Params(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::line_descriptor::BinaryDescriptor_Params::opencv_from_extern(ret) };
@@ -586,7 +692,7 @@ impl BinaryDescriptor_Params {
 }
 
 /// furnishes all functionalities for querying a dataset provided by user or internal to
-/// class (that user must, anyway, populate) on the model of @ref features2d_match
+/// class (that user must, anyway, populate) on the model of features2d_match
 /// 
 /// 
 /// Once descriptors have been extracted from an image (both they represent lines and points), it
@@ -638,8 +744,9 @@ pub trait BinaryDescriptorMatcherTraitConst: core::AlgorithmTraitConst {
 	/// * mask: Mat()
 	#[inline]
 	fn match_(&self, query_descriptors: &core::Mat, train_descriptors: &core::Mat, matches: &mut core::Vector<core::DMatch>, mask: &core::Mat) -> Result<()> {
+		crate::core::validity::check("BinaryDescriptorMatcher", self.as_raw_BinaryDescriptorMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptorMatcher_match_const_const_MatR_const_MatR_vector_DMatch_R_const_MatR(self.as_raw_BinaryDescriptorMatcher(), query_descriptors.as_raw_Mat(), train_descriptors.as_raw_Mat(), matches.as_raw_mut_VectorOfDMatch(), mask.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptorMatcher_match_const_const_MatR_const_MatR_vector_DMatch_R_const_MatR(self.as_raw_BinaryDescriptorMatcher(), query_descriptors.as_raw_Mat(), train_descriptors.as_raw_Mat(), matches.as_raw_mut_VectorOfDMatch(), mask.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -662,8 +769,9 @@ pub trait BinaryDescriptorMatcherTraitConst: core::AlgorithmTraitConst {
 	/// * compact_result: false
 	#[inline]
 	fn knn_match(&self, query_descriptors: &core::Mat, train_descriptors: &core::Mat, matches: &mut core::Vector<core::Vector<core::DMatch>>, k: i32, mask: &core::Mat, compact_result: bool) -> Result<()> {
+		crate::core::validity::check("BinaryDescriptorMatcher", self.as_raw_BinaryDescriptorMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptorMatcher_knnMatch_const_const_MatR_const_MatR_vector_vector_DMatch__R_int_const_MatR_bool(self.as_raw_BinaryDescriptorMatcher(), query_descriptors.as_raw_Mat(), train_descriptors.as_raw_Mat(), matches.as_raw_mut_VectorOfVectorOfDMatch(), k, mask.as_raw_Mat(), compact_result, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptorMatcher_knnMatch_const_const_MatR_const_MatR_vector_vector_DMatch__R_int_const_MatR_bool(self.as_raw_BinaryDescriptorMatcher(), query_descriptors.as_raw_Mat(), train_descriptors.as_raw_Mat(), matches.as_raw_mut_VectorOfVectorOfDMatch(), k, mask.as_raw_Mat(), compact_result, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -686,8 +794,9 @@ pub trait BinaryDescriptorMatcherTraitConst: core::AlgorithmTraitConst {
 	/// * compact_result: false
 	#[inline]
 	fn radius_match(&self, query_descriptors: &core::Mat, train_descriptors: &core::Mat, matches: &mut core::Vector<core::Vector<core::DMatch>>, max_distance: f32, mask: &core::Mat, compact_result: bool) -> Result<()> {
+		crate::core::validity::check("BinaryDescriptorMatcher", self.as_raw_BinaryDescriptorMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptorMatcher_radiusMatch_const_const_MatR_const_MatR_vector_vector_DMatch__R_float_const_MatR_bool(self.as_raw_BinaryDescriptorMatcher(), query_descriptors.as_raw_Mat(), train_descriptors.as_raw_Mat(), matches.as_raw_mut_VectorOfVectorOfDMatch(), max_distance, mask.as_raw_Mat(), compact_result, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptorMatcher_radiusMatch_const_const_MatR_const_MatR_vector_vector_DMatch__R_float_const_MatR_bool(self.as_raw_BinaryDescriptorMatcher(), query_descriptors.as_raw_Mat(), train_descriptors.as_raw_Mat(), matches.as_raw_mut_VectorOfVectorOfDMatch(), max_distance, mask.as_raw_Mat(), compact_result, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -695,6 +804,72 @@ pub trait BinaryDescriptorMatcherTraitConst: core::AlgorithmTraitConst {
 	
 }
 
+/// Builder for `BinaryDescriptorMatcherTraitConst::knn_match()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct BinaryDescriptorMatcher_KnnMatchBuilder<'a, T: ?Sized + BinaryDescriptorMatcherTraitConst> {
+	instance: &'a T,
+	query_descriptors: &'a core::Mat,
+	train_descriptors: &'a core::Mat,
+	matches: &'a mut core::Vector<core::Vector<core::DMatch>>,
+	k: i32,
+	mask: &'a core::Mat,
+	compact_result: bool,
+}
+
+impl<'a, T: ?Sized + BinaryDescriptorMatcherTraitConst> BinaryDescriptorMatcher_KnnMatchBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a T, query_descriptors: &'a core::Mat, train_descriptors: &'a core::Mat, matches: &'a mut core::Vector<core::Vector<core::DMatch>>, k: i32, mask: &'a core::Mat) -> Self {
+		Self { instance, query_descriptors, train_descriptors, matches, k, mask, compact_result: false }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_compact_result(mut self, compact_result: bool) -> Self {
+		self.compact_result = compact_result;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		BinaryDescriptorMatcherTraitConst::knn_match(self.instance, self.query_descriptors, self.train_descriptors, self.matches, self.k, self.mask, self.compact_result)
+	}
+}
+
+/// Builder for `BinaryDescriptorMatcherTraitConst::radius_match()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct BinaryDescriptorMatcher_RadiusMatchBuilder<'a, T: ?Sized + BinaryDescriptorMatcherTraitConst> {
+	instance: &'a T,
+	query_descriptors: &'a core::Mat,
+	train_descriptors: &'a core::Mat,
+	matches: &'a mut core::Vector<core::Vector<core::DMatch>>,
+	max_distance: f32,
+	mask: &'a core::Mat,
+	compact_result: bool,
+}
+
+impl<'a, T: ?Sized + BinaryDescriptorMatcherTraitConst> BinaryDescriptorMatcher_RadiusMatchBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a T, query_descriptors: &'a core::Mat, train_descriptors: &'a core::Mat, matches: &'a mut core::Vector<core::Vector<core::DMatch>>, max_distance: f32, mask: &'a core::Mat) -> Self {
+		Self { instance, query_descriptors, train_descriptors, matches, max_distance, mask, compact_result: false }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_compact_result(mut self, compact_result: bool) -> Self {
+		self.compact_result = compact_result;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		BinaryDescriptorMatcherTraitConst::radius_match(self.instance, self.query_descriptors, self.train_descriptors, self.matches, self.max_distance, self.mask, self.compact_result)
+	}
+}
+
 pub trait BinaryDescriptorMatcherTrait: core::AlgorithmTrait + crate::line_descriptor::BinaryDescriptorMatcherTraitConst {
 	fn as_raw_mut_BinaryDescriptorMatcher(&mut self) -> *mut c_void;
 
@@ -719,8 +894,9 @@ pub trait BinaryDescriptorMatcherTrait: core::AlgorithmTrait + crate::line_descr
 	/// * masks: std::vector<Mat>()
 	#[inline]
 	fn match_query(&mut self, query_descriptors: &core::Mat, matches: &mut core::Vector<core::DMatch>, masks: &core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("BinaryDescriptorMatcher", self.as_raw_mut_BinaryDescriptorMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptorMatcher_match_const_MatR_vector_DMatch_R_const_vector_Mat_R(self.as_raw_mut_BinaryDescriptorMatcher(), query_descriptors.as_raw_Mat(), matches.as_raw_mut_VectorOfDMatch(), masks.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptorMatcher_match_const_MatR_vector_DMatch_R_const_vector_Mat_R(self.as_raw_mut_BinaryDescriptorMatcher(), query_descriptors.as_raw_Mat(), matches.as_raw_mut_VectorOfDMatch(), masks.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -754,8 +930,9 @@ pub trait BinaryDescriptorMatcherTrait: core::AlgorithmTrait + crate::line_descr
 	/// * compact_result: false
 	#[inline]
 	fn knn_match_query(&mut self, query_descriptors: &core::Mat, matches: &mut core::Vector<core::Vector<core::DMatch>>, k: i32, masks: &core::Vector<core::Mat>, compact_result: bool) -> Result<()> {
+		crate::core::validity::check("BinaryDescriptorMatcher", self.as_raw_mut_BinaryDescriptorMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptorMatcher_knnMatch_const_MatR_vector_vector_DMatch__R_int_const_vector_Mat_R_bool(self.as_raw_mut_BinaryDescriptorMatcher(), query_descriptors.as_raw_Mat(), matches.as_raw_mut_VectorOfVectorOfDMatch(), k, masks.as_raw_VectorOfMat(), compact_result, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptorMatcher_knnMatch_const_MatR_vector_vector_DMatch__R_int_const_vector_Mat_R_bool(self.as_raw_mut_BinaryDescriptorMatcher(), query_descriptors.as_raw_Mat(), matches.as_raw_mut_VectorOfVectorOfDMatch(), k, masks.as_raw_VectorOfMat(), compact_result, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -789,8 +966,9 @@ pub trait BinaryDescriptorMatcherTrait: core::AlgorithmTrait + crate::line_descr
 	/// * compact_result: false
 	#[inline]
 	fn radius_match_1(&mut self, query_descriptors: &core::Mat, matches: &mut core::Vector<core::Vector<core::DMatch>>, max_distance: f32, masks: &core::Vector<core::Mat>, compact_result: bool) -> Result<()> {
+		crate::core::validity::check("BinaryDescriptorMatcher", self.as_raw_mut_BinaryDescriptorMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptorMatcher_radiusMatch_const_MatR_vector_vector_DMatch__R_float_const_vector_Mat_R_bool(self.as_raw_mut_BinaryDescriptorMatcher(), query_descriptors.as_raw_Mat(), matches.as_raw_mut_VectorOfVectorOfDMatch(), max_distance, masks.as_raw_VectorOfMat(), compact_result, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptorMatcher_radiusMatch_const_MatR_vector_vector_DMatch__R_float_const_vector_Mat_R_bool(self.as_raw_mut_BinaryDescriptorMatcher(), query_descriptors.as_raw_Mat(), matches.as_raw_mut_VectorOfVectorOfDMatch(), max_distance, masks.as_raw_VectorOfMat(), compact_result, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -806,8 +984,9 @@ pub trait BinaryDescriptorMatcherTrait: core::AlgorithmTrait + crate::line_descr
 	/// *i*-th image.
 	#[inline]
 	fn add(&mut self, descriptors: &core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("BinaryDescriptorMatcher", self.as_raw_mut_BinaryDescriptorMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptorMatcher_add_const_vector_Mat_R(self.as_raw_mut_BinaryDescriptorMatcher(), descriptors.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptorMatcher_add_const_vector_Mat_R(self.as_raw_mut_BinaryDescriptorMatcher(), descriptors.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -820,8 +999,9 @@ pub trait BinaryDescriptorMatcherTrait: core::AlgorithmTrait + crate::line_descr
 	/// are inserted into dataset. The locally stored copy of just inserted descriptors is then removed.
 	#[inline]
 	fn train(&mut self) -> Result<()> {
+		crate::core::validity::check("BinaryDescriptorMatcher", self.as_raw_mut_BinaryDescriptorMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptorMatcher_train(self.as_raw_mut_BinaryDescriptorMatcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptorMatcher_train(self.as_raw_mut_BinaryDescriptorMatcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -830,8 +1010,9 @@ pub trait BinaryDescriptorMatcherTrait: core::AlgorithmTrait + crate::line_descr
 	/// Clear dataset and internal data
 	#[inline]
 	fn clear(&mut self) -> Result<()> {
+		crate::core::validity::check("BinaryDescriptorMatcher", self.as_raw_mut_BinaryDescriptorMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptorMatcher_clear(self.as_raw_mut_BinaryDescriptorMatcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptorMatcher_clear(self.as_raw_mut_BinaryDescriptorMatcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -840,7 +1021,7 @@ pub trait BinaryDescriptorMatcherTrait: core::AlgorithmTrait + crate::line_descr
 }
 
 /// furnishes all functionalities for querying a dataset provided by user or internal to
-/// class (that user must, anyway, populate) on the model of @ref features2d_match
+/// class (that user must, anyway, populate) on the model of features2d_match
 /// 
 /// 
 /// Once descriptors have been extracted from an image (both they represent lines and points), it
@@ -885,7 +1066,10 @@ opencv_type_boxed! { BinaryDescriptorMatcher }
 impl Drop for BinaryDescriptorMatcher {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BinaryDescriptorMatcher_delete(instance: *mut c_void); }
-		unsafe { cv_BinaryDescriptorMatcher_delete(self.as_raw_mut_BinaryDescriptorMatcher()) };
+		let ptr = self.as_raw_mut_BinaryDescriptorMatcher();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_BinaryDescriptorMatcher_delete(ptr) };
 	}
 }
 
@@ -912,7 +1096,7 @@ impl BinaryDescriptorMatcher {
 	#[inline]
 	pub fn create_binary_descriptor_matcher() -> Result<core::Ptr<crate::line_descriptor::BinaryDescriptorMatcher>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptorMatcher_createBinaryDescriptorMatcher(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptorMatcher_createBinaryDescriptorMatcher(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::line_descriptor::BinaryDescriptorMatcher>::opencv_from_extern(ret) };
@@ -925,7 +1109,7 @@ impl BinaryDescriptorMatcher {
 	#[inline]
 	pub fn default() -> Result<crate::line_descriptor::BinaryDescriptorMatcher> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptorMatcher_BinaryDescriptorMatcher(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_BinaryDescriptorMatcher_BinaryDescriptorMatcher(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::line_descriptor::BinaryDescriptorMatcher::opencv_from_extern(ret) };
@@ -1009,7 +1193,7 @@ impl KeyLine {
 	#[inline]
 	pub fn get_start_point(self) -> Result<core::Point2f> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_KeyLine_getStartPoint_const(self.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_KeyLine_getStartPoint_const(self.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1019,7 +1203,7 @@ impl KeyLine {
 	#[inline]
 	pub fn get_end_point(self) -> Result<core::Point2f> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_KeyLine_getEndPoint_const(self.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_KeyLine_getEndPoint_const(self.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1029,7 +1213,7 @@ impl KeyLine {
 	#[inline]
 	pub fn get_start_point_in_octave(self) -> Result<core::Point2f> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_KeyLine_getStartPointInOctave_const(self.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_KeyLine_getStartPointInOctave_const(self.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1039,7 +1223,7 @@ impl KeyLine {
 	#[inline]
 	pub fn get_end_point_in_octave(self) -> Result<core::Point2f> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_KeyLine_getEndPointInOctave_const(self.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_KeyLine_getEndPointInOctave_const(self.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1049,7 +1233,7 @@ impl KeyLine {
 	#[inline]
 	pub fn default() -> Result<crate::line_descriptor::KeyLine> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_KeyLine_KeyLine(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_KeyLine_KeyLine(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1081,8 +1265,9 @@ pub trait LSDDetectorTraitConst: core::AlgorithmTraitConst {
 	/// * masks: std::vector<Mat>()
 	#[inline]
 	fn detect_multiple(&self, images: &core::Vector<core::Mat>, keylines: &mut core::Vector<core::Vector<crate::line_descriptor::KeyLine>>, scale: i32, num_octaves: i32, masks: &core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("LSDDetector", self.as_raw_LSDDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_LSDDetector_detect_const_const_vector_Mat_R_vector_vector_KeyLine__R_int_int_const_vector_Mat_R(self.as_raw_LSDDetector(), images.as_raw_VectorOfMat(), keylines.as_raw_mut_VectorOfVectorOfKeyLine(), scale, num_octaves, masks.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_LSDDetector_detect_const_const_vector_Mat_R_vector_vector_KeyLine__R_int_int_const_vector_Mat_R(self.as_raw_LSDDetector(), images.as_raw_VectorOfMat(), keylines.as_raw_mut_VectorOfVectorOfKeyLine(), scale, num_octaves, masks.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1106,8 +1291,9 @@ pub trait LSDDetectorTrait: core::AlgorithmTrait + crate::line_descriptor::LSDDe
 	/// * mask: Mat()
 	#[inline]
 	fn detect(&mut self, image: &core::Mat, keypoints: &mut core::Vector<crate::line_descriptor::KeyLine>, scale: i32, num_octaves: i32, mask: &core::Mat) -> Result<()> {
+		crate::core::validity::check("LSDDetector", self.as_raw_mut_LSDDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_LSDDetector_detect_const_MatR_vector_KeyLine_R_int_int_const_MatR(self.as_raw_mut_LSDDetector(), image.as_raw_Mat(), keypoints.as_raw_mut_VectorOfKeyLine(), scale, num_octaves, mask.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_LSDDetector_detect_const_MatR_vector_KeyLine_R_int_int_const_MatR(self.as_raw_mut_LSDDetector(), image.as_raw_Mat(), keypoints.as_raw_mut_VectorOfKeyLine(), scale, num_octaves, mask.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1124,7 +1310,10 @@ opencv_type_boxed! { LSDDetector }
 impl Drop for LSDDetector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_LSDDetector_delete(instance: *mut c_void); }
-		unsafe { cv_LSDDetector_delete(self.as_raw_mut_LSDDetector()) };
+		let ptr = self.as_raw_mut_LSDDetector();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_LSDDetector_delete(ptr) };
 	}
 }
 
@@ -1150,7 +1339,7 @@ impl LSDDetector {
 	#[inline]
 	pub fn default() -> Result<crate::line_descriptor::LSDDetector> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_LSDDetector_LSDDetector(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_LSDDetector_LSDDetector(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::line_descriptor::LSDDetector::opencv_from_extern(ret) };
@@ -1160,7 +1349,7 @@ impl LSDDetector {
 	#[inline]
 	pub fn new(_params: crate::line_descriptor::LSDParam) -> Result<crate::line_descriptor::LSDDetector> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_LSDDetector_LSDDetector_LSDParam(_params.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_LSDDetector_LSDDetector_LSDParam(_params.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::line_descriptor::LSDDetector::opencv_from_extern(ret) };
@@ -1171,7 +1360,7 @@ impl LSDDetector {
 	#[inline]
 	pub fn create_lsd_detector() -> Result<core::Ptr<crate::line_descriptor::LSDDetector>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_LSDDetector_createLSDDetector(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_LSDDetector_createLSDDetector(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::line_descriptor::LSDDetector>::opencv_from_extern(ret) };
@@ -1181,7 +1370,7 @@ impl LSDDetector {
 	#[inline]
 	pub fn create_lsd_detector_with_params(params: crate::line_descriptor::LSDParam) -> Result<core::Ptr<crate::line_descriptor::LSDDetector>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_LSDDetector_createLSDDetector_LSDParam(params.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_LSDDetector_createLSDDetector_LSDParam(params.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::line_descriptor::LSDDetector>::opencv_from_extern(ret) };
@@ -1225,7 +1414,7 @@ impl LSDParam {
 	#[inline]
 	pub fn default() -> Result<crate::line_descriptor::LSDParam> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_LSDParam_LSDParam(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_line_descriptor_LSDParam_LSDParam(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -357,7 +357,7 @@ opencv_type_enum! { crate::mcc::INITIAL_METHOD_TYPE }
 /// 
 /// #### Fitting Channels Respectively
 /// 
-/// Use three polynomials, ![inline formula](https://latex.codecogs.com/png.latex?r%28x%29%2C%20g%28x%29%2C%20b%28x%29),  to linearize each channel of the RGB color space[1-3]:
+/// Use three polynomials, ![inline formula](https://latex.codecogs.com/png.latex?r%28x%29%2C%20g%28x%29%2C%20b%28x%29),  to linearize each channel of the RGB color space\[1-3\]:
 /// ![block formula](https://latex.codecogs.com/png.latex?%0AR%5F%7Bsl%7D%3Dr%28R%5Fs%29%5C%5C%0AG%5F%7Bsl%7D%3Dg%28G%5Fs%29%5C%5C%0AB%5F%7Bsl%7D%3Db%28B%5Fs%29%5C%5C%0A)
 /// The polynomial is generated by minimizing the residual sum of squares between the detected data and the linearized reference data.
 /// Take the R-channel as an example:
@@ -383,8 +383,8 @@ opencv_type_enum! { crate::mcc::INITIAL_METHOD_TYPE }
 /// 
 /// ![block formula](https://latex.codecogs.com/png.latex?%0AR%3Dpolyfit%28R%5FS%2C%20R%5F%7Bdl%7D%29%0A)
 /// 
-/// Note that, in general, the polynomial that we want to obtain is guaranteed to monotonically increase in the interval [0,1] ,
-/// but this means that nonlinear method is needed to generate the polynomials(see [4] for detail).
+/// Note that, in general, the polynomial that we want to obtain is guaranteed to monotonically increase in the interval \[0,1\] ,
+/// but this means that nonlinear method is needed to generate the polynomials(see \[4\] for detail).
 /// This would greatly increases the complexity of the program.
 /// Considering that the monotonicity does not affect the correct operation of the color correction program, polyfit is still used to implement the program.
 /// 
@@ -392,14 +392,14 @@ opencv_type_enum! { crate::mcc::INITIAL_METHOD_TYPE }
 /// 
 /// #### Grayscale Polynomial Fitting
 /// 
-/// In this method[2], single polynomial is used for all channels.
+/// In this method\[2\], single polynomial is used for all channels.
 /// The polynomial is still a polyfit result from the detected colors to the linear reference colors.
 /// However, only the gray of the reference colors can participate in the calculation.
 /// 
 /// Since the detected colors corresponding to the gray of reference colors is not necessarily gray, it needs to be grayed.
 /// Grayscale refers to the Y channel of the XYZ color space.
 /// The color space of the detected data is not determined and cannot be converted into the XYZ space.
-/// Therefore, the sRGB formula is used to approximate[5].
+/// Therefore, the sRGB formula is used to approximate\[5\].
 /// ![block formula](https://latex.codecogs.com/png.latex?%0AG%5F%7Bs%7D%3D0%2E2126R%5F%7Bs%7D%2B0%2E7152G%5F%7Bs%7D%2B0%2E0722B%5F%7Bs%7D%0A)
 /// Then the polynomial parameters can be obtained by using the polyfit.
 /// ![block formula](https://latex.codecogs.com/png.latex?%0Af%3Dpolyfit%28G%5F%7Bs%7D%2C%20G%5F%7Bdl%7D%29%0A)
@@ -409,7 +409,7 @@ opencv_type_enum! { crate::mcc::INITIAL_METHOD_TYPE }
 /// 
 /// For gamma correction formula, we take the logarithm:
 /// ![block formula](https://latex.codecogs.com/png.latex?%0Aln%28C%5F%7Bsl%7D%29%3D%7B%5Cgamma%7Dln%28C%5Fs%29%2C%5Cqquad%20C%5Fs%5Cge0%5C%0A)
-/// It can be seen that there is a linear relationship between ![inline formula](https://latex.codecogs.com/png.latex?ln%28C%5Fs%29) and ![inline formula](https://latex.codecogs.com/png.latex?ln%28C%5F%7Bsl%7D%29). It can be considered that the formula is an approximation of a polynomial relationship, that is, there exists a polynomial ![inline formula](https://latex.codecogs.com/png.latex?f), which makes[2]:
+/// It can be seen that there is a linear relationship between ![inline formula](https://latex.codecogs.com/png.latex?ln%28C%5Fs%29) and ![inline formula](https://latex.codecogs.com/png.latex?ln%28C%5F%7Bsl%7D%29). It can be considered that the formula is an approximation of a polynomial relationship, that is, there exists a polynomial ![inline formula](https://latex.codecogs.com/png.latex?f), which makes\[2\]:
 /// ![block formula](https://latex.codecogs.com/png.latex?%0Aln%28C%5F%7Bsl%7D%29%3Df%28ln%28C%5Fs%29%29%2C%20%5Cqquad%20C%5Fs%3E0%5C%5C%0AC%5F%7Bsl%7D%3D0%2C%20%5Cqquad%20C%5Fs%3D0%0A)
 /// 
 /// Because ![inline formula](https://latex.codecogs.com/png.latex?exp%28ln%280%29%29%5Cto%5Cinfty%20), the channel whose component is 0 is directly mapped to 0 in the formula above.
@@ -470,8 +470,9 @@ pub trait ColorCorrectionModelTraitConst {
 
 	#[inline]
 	fn get_ccm(&self) -> Result<core::Mat> {
+		crate::core::validity::check("ColorCorrectionModel", self.as_raw_ColorCorrectionModel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccm_ColorCorrectionModel_getCCM_const(self.as_raw_ColorCorrectionModel(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccm_ColorCorrectionModel_getCCM_const(self.as_raw_ColorCorrectionModel(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -480,8 +481,9 @@ pub trait ColorCorrectionModelTraitConst {
 	
 	#[inline]
 	fn get_loss(&self) -> Result<f64> {
+		crate::core::validity::check("ColorCorrectionModel", self.as_raw_ColorCorrectionModel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccm_ColorCorrectionModel_getLoss_const(self.as_raw_ColorCorrectionModel(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccm_ColorCorrectionModel_getLoss_const(self.as_raw_ColorCorrectionModel(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -489,8 +491,9 @@ pub trait ColorCorrectionModelTraitConst {
 	
 	#[inline]
 	fn get_src_rgbl(&self) -> Result<core::Mat> {
+		crate::core::validity::check("ColorCorrectionModel", self.as_raw_ColorCorrectionModel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccm_ColorCorrectionModel_get_src_rgbl_const(self.as_raw_ColorCorrectionModel(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccm_ColorCorrectionModel_get_src_rgbl_const(self.as_raw_ColorCorrectionModel(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -499,8 +502,9 @@ pub trait ColorCorrectionModelTraitConst {
 	
 	#[inline]
 	fn get_dst_rgbl(&self) -> Result<core::Mat> {
+		crate::core::validity::check("ColorCorrectionModel", self.as_raw_ColorCorrectionModel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccm_ColorCorrectionModel_get_dst_rgbl_const(self.as_raw_ColorCorrectionModel(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccm_ColorCorrectionModel_get_dst_rgbl_const(self.as_raw_ColorCorrectionModel(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -509,8 +513,9 @@ pub trait ColorCorrectionModelTraitConst {
 	
 	#[inline]
 	fn get_mask(&self) -> Result<core::Mat> {
+		crate::core::validity::check("ColorCorrectionModel", self.as_raw_ColorCorrectionModel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccm_ColorCorrectionModel_getMask_const(self.as_raw_ColorCorrectionModel(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccm_ColorCorrectionModel_getMask_const(self.as_raw_ColorCorrectionModel(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -519,8 +524,9 @@ pub trait ColorCorrectionModelTraitConst {
 	
 	#[inline]
 	fn get_weights(&self) -> Result<core::Mat> {
+		crate::core::validity::check("ColorCorrectionModel", self.as_raw_ColorCorrectionModel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccm_ColorCorrectionModel_getWeights_const(self.as_raw_ColorCorrectionModel(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccm_ColorCorrectionModel_getWeights_const(self.as_raw_ColorCorrectionModel(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -536,22 +542,23 @@ pub trait ColorCorrectionModelTrait: crate::mcc::ColorCorrectionModelTraitConst
 	/// 
 	/// Note: It should be some RGB color space;
 	/// Supported list of color cards:
-	/// - @ref COLOR_SPACE_sRGB
-	/// - @ref COLOR_SPACE_AdobeRGB
-	/// - @ref COLOR_SPACE_WideGamutRGB
-	/// - @ref COLOR_SPACE_ProPhotoRGB
-	/// - @ref COLOR_SPACE_DCI_P3_RGB
-	/// - @ref COLOR_SPACE_AppleRGB
-	/// - @ref COLOR_SPACE_REC_709_RGB
-	/// - @ref COLOR_SPACE_REC_2020_RGB
+	/// - `COLOR_SPACE_sRGB`
+	/// - `COLOR_SPACE_AdobeRGB`
+	/// - `COLOR_SPACE_WideGamutRGB`
+	/// - `COLOR_SPACE_ProPhotoRGB`
+	/// - `COLOR_SPACE_DCI_P3_RGB`
+	/// - `COLOR_SPACE_AppleRGB`
+	/// - `COLOR_SPACE_REC_709_RGB`
+	/// - `COLOR_SPACE_REC_2020_RGB`
 	/// ## Parameters
 	/// * cs: the absolute color space that detected colors convert to;
 	/// 
-	///           default: @ref COLOR_SPACE_sRGB
+	///           default: `COLOR_SPACE_sRGB`
 	#[inline]
 	fn set_color_space(&mut self, cs: crate::mcc::COLOR_SPACE) -> Result<()> {
+		crate::core::validity::check("ColorCorrectionModel", self.as_raw_mut_ColorCorrectionModel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccm_ColorCorrectionModel_setColorSpace_COLOR_SPACE(self.as_raw_mut_ColorCorrectionModel(), cs, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccm_ColorCorrectionModel_setColorSpace_COLOR_SPACE(self.as_raw_mut_ColorCorrectionModel(), cs, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -561,11 +568,12 @@ pub trait ColorCorrectionModelTrait: crate::mcc::ColorCorrectionModelTraitConst
 	/// ## Parameters
 	/// * ccm_type: the shape of color correction matrix(CCM);
 	/// 
-	///                default: @ref CCM_3x3
+	///                default: `CCM_3x3`
 	#[inline]
 	fn set_ccm_type(&mut self, ccm_type: crate::mcc::CCM_TYPE) -> Result<()> {
+		crate::core::validity::check("ColorCorrectionModel", self.as_raw_mut_ColorCorrectionModel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccm_ColorCorrectionModel_setCCM_TYPE_CCM_TYPE(self.as_raw_mut_ColorCorrectionModel(), ccm_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccm_ColorCorrectionModel_setCCM_TYPE_CCM_TYPE(self.as_raw_mut_ColorCorrectionModel(), ccm_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -575,11 +583,12 @@ pub trait ColorCorrectionModelTrait: crate::mcc::ColorCorrectionModelTraitConst
 	/// ## Parameters
 	/// * distance: the type of color distance;
 	/// 
-	///                default: @ref DISTANCE_CIE2000
+	///                default: `DISTANCE_CIE2000`
 	#[inline]
 	fn set_distance(&mut self, distance: crate::mcc::DISTANCE_TYPE) -> Result<()> {
+		crate::core::validity::check("ColorCorrectionModel", self.as_raw_mut_ColorCorrectionModel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccm_ColorCorrectionModel_setDistance_DISTANCE_TYPE(self.as_raw_mut_ColorCorrectionModel(), distance, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccm_ColorCorrectionModel_setDistance_DISTANCE_TYPE(self.as_raw_mut_ColorCorrectionModel(), distance, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -589,11 +598,12 @@ pub trait ColorCorrectionModelTrait: crate::mcc::ColorCorrectionModelTraitConst
 	/// ## Parameters
 	/// * linear_type: the method of linearization;
 	/// 
-	///                   default: @ref LINEARIZATION_GAMMA
+	///                   default: `LINEARIZATION_GAMMA`
 	#[inline]
 	fn set_linear(&mut self, linear_type: crate::mcc::LINEAR_TYPE) -> Result<()> {
+		crate::core::validity::check("ColorCorrectionModel", self.as_raw_mut_ColorCorrectionModel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccm_ColorCorrectionModel_setLinear_LINEAR_TYPE(self.as_raw_mut_ColorCorrectionModel(), linear_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccm_ColorCorrectionModel_setLinear_LINEAR_TYPE(self.as_raw_mut_ColorCorrectionModel(), linear_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -611,8 +621,9 @@ pub trait ColorCorrectionModelTrait: crate::mcc::ColorCorrectionModelTraitConst
 	///              default: 2.2;
 	#[inline]
 	fn set_linear_gamma(&mut self, gamma: &f64) -> Result<()> {
+		crate::core::validity::check("ColorCorrectionModel", self.as_raw_mut_ColorCorrectionModel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccm_ColorCorrectionModel_setLinearGamma_const_doubleR(self.as_raw_mut_ColorCorrectionModel(), gamma, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccm_ColorCorrectionModel_setLinearGamma_const_doubleR(self.as_raw_mut_ColorCorrectionModel(), gamma, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -621,10 +632,10 @@ pub trait ColorCorrectionModelTrait: crate::mcc::ColorCorrectionModelTraitConst
 	/// set degree
 	///     
 	/// Note: only valid when linear is set to
-	///    - @ref LINEARIZATION_COLORPOLYFIT
-	///    - @ref LINEARIZATION_GRAYPOLYFIT
-	///    - @ref LINEARIZATION_COLORLOGPOLYFIT
-	///    - @ref LINEARIZATION_GRAYLOGPOLYFIT
+	///    - `LINEARIZATION_COLORPOLYFIT`
+	///    - `LINEARIZATION_GRAYPOLYFIT`
+	///    - `LINEARIZATION_COLORLOGPOLYFIT`
+	///    - `LINEARIZATION_GRAYLOGPOLYFIT`
 	/// 
 	/// ## Parameters
 	/// * deg: the degree of linearization polynomial;
@@ -632,15 +643,16 @@ pub trait ColorCorrectionModelTrait: crate::mcc::ColorCorrectionModelTraitConst
 	///        default: 3
 	#[inline]
 	fn set_linear_degree(&mut self, deg: &i32) -> Result<()> {
+		crate::core::validity::check("ColorCorrectionModel", self.as_raw_mut_ColorCorrectionModel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccm_ColorCorrectionModel_setLinearDegree_const_intR(self.as_raw_mut_ColorCorrectionModel(), deg, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccm_ColorCorrectionModel_setLinearDegree_const_intR(self.as_raw_mut_ColorCorrectionModel(), deg, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// set SaturatedThreshold.
-	///            The colors in the closed interval [lower, upper] are reserved to participate
+	///            The colors in the closed interval \[lower, upper\] are reserved to participate
 	///            in the calculation of the loss function and initialization parameters
 	/// ## Parameters
 	/// * lower: the lower threshold to determine saturation;
@@ -651,8 +663,9 @@ pub trait ColorCorrectionModelTrait: crate::mcc::ColorCorrectionModelTraitConst
 	///              default: 0
 	#[inline]
 	fn set_saturated_threshold(&mut self, lower: &f64, upper: &f64) -> Result<()> {
+		crate::core::validity::check("ColorCorrectionModel", self.as_raw_mut_ColorCorrectionModel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccm_ColorCorrectionModel_setSaturatedThreshold_const_doubleR_const_doubleR(self.as_raw_mut_ColorCorrectionModel(), lower, upper, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccm_ColorCorrectionModel_setSaturatedThreshold_const_doubleR_const_doubleR(self.as_raw_mut_ColorCorrectionModel(), lower, upper, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -665,8 +678,9 @@ pub trait ColorCorrectionModelTrait: crate::mcc::ColorCorrectionModelTraitConst
 	///                    default: empty array
 	#[inline]
 	fn set_weights_list(&mut self, weights_list: &core::Mat) -> Result<()> {
+		crate::core::validity::check("ColorCorrectionModel", self.as_raw_mut_ColorCorrectionModel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccm_ColorCorrectionModel_setWeightsList_const_MatR(self.as_raw_mut_ColorCorrectionModel(), weights_list.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccm_ColorCorrectionModel_setWeightsList_const_MatR(self.as_raw_mut_ColorCorrectionModel(), weights_list.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -679,8 +693,9 @@ pub trait ColorCorrectionModelTrait: crate::mcc::ColorCorrectionModelTraitConst
 	///                      default: 0
 	#[inline]
 	fn set_weight_coeff(&mut self, weights_coeff: &f64) -> Result<()> {
+		crate::core::validity::check("ColorCorrectionModel", self.as_raw_mut_ColorCorrectionModel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccm_ColorCorrectionModel_setWeightCoeff_const_doubleR(self.as_raw_mut_ColorCorrectionModel(), weights_coeff, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccm_ColorCorrectionModel_setWeightCoeff_const_doubleR(self.as_raw_mut_ColorCorrectionModel(), weights_coeff, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -693,8 +708,9 @@ pub trait ColorCorrectionModelTrait: crate::mcc::ColorCorrectionModelTraitConst
 	///        default: INITIAL_METHOD_LEAST_SQUARE
 	#[inline]
 	fn set_initial_method(&mut self, initial_method_type: crate::mcc::INITIAL_METHOD_TYPE) -> Result<()> {
+		crate::core::validity::check("ColorCorrectionModel", self.as_raw_mut_ColorCorrectionModel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccm_ColorCorrectionModel_setInitialMethod_INITIAL_METHOD_TYPE(self.as_raw_mut_ColorCorrectionModel(), initial_method_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccm_ColorCorrectionModel_setInitialMethod_INITIAL_METHOD_TYPE(self.as_raw_mut_ColorCorrectionModel(), initial_method_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -709,8 +725,9 @@ pub trait ColorCorrectionModelTrait: crate::mcc::ColorCorrectionModelTraitConst
 	///                  default: 5000;
 	#[inline]
 	fn set_max_count(&mut self, max_count: &i32) -> Result<()> {
+		crate::core::validity::check("ColorCorrectionModel", self.as_raw_mut_ColorCorrectionModel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccm_ColorCorrectionModel_setMaxCount_const_intR(self.as_raw_mut_ColorCorrectionModel(), max_count, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccm_ColorCorrectionModel_setMaxCount_const_intR(self.as_raw_mut_ColorCorrectionModel(), max_count, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -725,8 +742,9 @@ pub trait ColorCorrectionModelTrait: crate::mcc::ColorCorrectionModelTraitConst
 	///               default: 1e-4;
 	#[inline]
 	fn set_epsilon(&mut self, epsilon: &f64) -> Result<()> {
+		crate::core::validity::check("ColorCorrectionModel", self.as_raw_mut_ColorCorrectionModel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccm_ColorCorrectionModel_setEpsilon_const_doubleR(self.as_raw_mut_ColorCorrectionModel(), epsilon, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccm_ColorCorrectionModel_setEpsilon_const_doubleR(self.as_raw_mut_ColorCorrectionModel(), epsilon, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -735,8 +753,9 @@ pub trait ColorCorrectionModelTrait: crate::mcc::ColorCorrectionModelTraitConst
 	/// make color correction
 	#[inline]
 	fn run(&mut self) -> Result<()> {
+		crate::core::validity::check("ColorCorrectionModel", self.as_raw_mut_ColorCorrectionModel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccm_ColorCorrectionModel_run(self.as_raw_mut_ColorCorrectionModel(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccm_ColorCorrectionModel_run(self.as_raw_mut_ColorCorrectionModel(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -753,8 +772,9 @@ pub trait ColorCorrectionModelTrait: crate::mcc::ColorCorrectionModelTraitConst
 	/// * islinear: false
 	#[inline]
 	fn infer(&mut self, img: &core::Mat, islinear: bool) -> Result<core::Mat> {
+		crate::core::validity::check("ColorCorrectionModel", self.as_raw_mut_ColorCorrectionModel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccm_ColorCorrectionModel_infer_const_MatR_bool(self.as_raw_mut_ColorCorrectionModel(), img.as_raw_Mat(), islinear, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccm_ColorCorrectionModel_infer_const_MatR_bool(self.as_raw_mut_ColorCorrectionModel(), img.as_raw_Mat(), islinear, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -775,7 +795,10 @@ opencv_type_boxed! { ColorCorrectionModel }
 impl Drop for ColorCorrectionModel {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ColorCorrectionModel_delete(instance: *mut c_void); }
-		unsafe { cv_ColorCorrectionModel_delete(self.as_raw_mut_ColorCorrectionModel()) };
+		let ptr = self.as_raw_mut_ColorCorrectionModel();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ColorCorrectionModel_delete(ptr) };
 	}
 }
 
@@ -793,19 +816,19 @@ impl ColorCorrectionModel {
 	/// Color Correction Model
 	/// 
 	/// Supported list of color cards:
-	/// - @ref COLORCHECKER_Macbeth, the Macbeth ColorChecker
-	/// - @ref COLORCHECKER_Vinyl, the DKK ColorChecker
-	/// - @ref COLORCHECKER_DigitalSG, the DigitalSG ColorChecker with 140 squares
+	/// - `COLORCHECKER_Macbeth`, the Macbeth ColorChecker
+	/// - `COLORCHECKER_Vinyl`, the DKK ColorChecker
+	/// - `COLORCHECKER_DigitalSG`, the DigitalSG ColorChecker with 140 squares
 	/// 
 	/// ## Parameters
 	/// * src: detected colors of ColorChecker patches;
 	/// 
-	///            the color type is RGB not BGR, and the color values are in [0, 1];
+	///            the color type is RGB not BGR, and the color values are in \[0, 1\];
 	/// * constcolor: the Built-in color card
 	#[inline]
 	pub fn new(src: &core::Mat, constcolor: crate::mcc::CONST_COLOR) -> Result<crate::mcc::ColorCorrectionModel> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccm_ColorCorrectionModel_ColorCorrectionModel_const_MatR_CONST_COLOR(src.as_raw_Mat(), constcolor, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccm_ColorCorrectionModel_ColorCorrectionModel_const_MatR_CONST_COLOR(src.as_raw_Mat(), constcolor, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::mcc::ColorCorrectionModel::opencv_from_extern(ret) };
@@ -816,8 +839,8 @@ impl ColorCorrectionModel {
 	/// ## Parameters
 	/// * src: detected colors of ColorChecker patches;
 	/// 
-	///           the color type is RGB not BGR, and the color values are in [0, 1];
-	/// * colors: the reference color values, the color values are in [0, 1].
+	///           the color type is RGB not BGR, and the color values are in \[0, 1\];
+	/// * colors: the reference color values, the color values are in \[0, 1\].
 	/// 
 	/// * ref_cs: the corresponding color space
 	///            If the color type is some RGB, the format is RGB not BGR;
@@ -825,7 +848,7 @@ impl ColorCorrectionModel {
 	#[inline]
 	pub fn new_1(src: &core::Mat, mut colors: core::Mat, ref_cs: crate::mcc::COLOR_SPACE) -> Result<crate::mcc::ColorCorrectionModel> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccm_ColorCorrectionModel_ColorCorrectionModel_const_MatR_Mat_COLOR_SPACE(src.as_raw_Mat(), colors.as_raw_mut_Mat(), ref_cs, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccm_ColorCorrectionModel_ColorCorrectionModel_const_MatR_Mat_COLOR_SPACE(src.as_raw_Mat(), colors.as_raw_mut_Mat(), ref_cs, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::mcc::ColorCorrectionModel::opencv_from_extern(ret) };
@@ -836,15 +859,15 @@ impl ColorCorrectionModel {
 	/// ## Parameters
 	/// * src: detected colors of ColorChecker patches;
 	/// 
-	///            the color type is RGB not BGR, and the color values are in [0, 1];
-	/// * colors: the reference color values, the color values are in [0, 1].
+	///            the color type is RGB not BGR, and the color values are in \[0, 1\];
+	/// * colors: the reference color values, the color values are in \[0, 1\].
 	/// * ref_cs: the corresponding color space
 	///            If the color type is some RGB, the format is RGB not BGR;
 	/// * colored: mask of colored color
 	#[inline]
 	pub fn new_2(src: &core::Mat, mut colors: core::Mat, ref_cs: crate::mcc::COLOR_SPACE, mut colored: core::Mat) -> Result<crate::mcc::ColorCorrectionModel> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ccm_ColorCorrectionModel_ColorCorrectionModel_const_MatR_Mat_COLOR_SPACE_Mat(src.as_raw_Mat(), colors.as_raw_mut_Mat(), ref_cs, colored.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ccm_ColorCorrectionModel_ColorCorrectionModel_const_MatR_Mat_COLOR_SPACE_Mat(src.as_raw_Mat(), colors.as_raw_mut_Mat(), ref_cs, colored.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::mcc::ColorCorrectionModel::opencv_from_extern(ret) };
@@ -870,8 +893,9 @@ pub trait MCC_CChecker: crate::mcc::MCC_CCheckerConst {
 
 	#[inline]
 	fn set_target(&mut self, _target: crate::mcc::MCC_TYPECHART) -> Result<()> {
+		crate::core::validity::check("MCC_CChecker", self.as_raw_mut_MCC_CChecker())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_mcc_CChecker_setTarget_TYPECHART(self.as_raw_mut_MCC_CChecker(), _target, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_mcc_CChecker_setTarget_TYPECHART(self.as_raw_mut_MCC_CChecker(), _target, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -879,8 +903,9 @@ pub trait MCC_CChecker: crate::mcc::MCC_CCheckerConst {
 	
 	#[inline]
 	fn set_box(&mut self, mut _box: core::Vector<core::Point2f>) -> Result<()> {
+		crate::core::validity::check("MCC_CChecker", self.as_raw_mut_MCC_CChecker())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_mcc_CChecker_setBox_vector_Point2f_(self.as_raw_mut_MCC_CChecker(), _box.as_raw_mut_VectorOfPoint2f(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_mcc_CChecker_setBox_vector_Point2f_(self.as_raw_mut_MCC_CChecker(), _box.as_raw_mut_VectorOfPoint2f(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -888,8 +913,9 @@ pub trait MCC_CChecker: crate::mcc::MCC_CCheckerConst {
 	
 	#[inline]
 	fn set_charts_rgb(&mut self, mut _charts_rgb: core::Mat) -> Result<()> {
+		crate::core::validity::check("MCC_CChecker", self.as_raw_mut_MCC_CChecker())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_mcc_CChecker_setChartsRGB_Mat(self.as_raw_mut_MCC_CChecker(), _charts_rgb.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_mcc_CChecker_setChartsRGB_Mat(self.as_raw_mut_MCC_CChecker(), _charts_rgb.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -897,8 +923,9 @@ pub trait MCC_CChecker: crate::mcc::MCC_CCheckerConst {
 	
 	#[inline]
 	fn set_charts_y_cb_cr(&mut self, mut _charts_y_cb_cr: core::Mat) -> Result<()> {
+		crate::core::validity::check("MCC_CChecker", self.as_raw_mut_MCC_CChecker())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_mcc_CChecker_setChartsYCbCr_Mat(self.as_raw_mut_MCC_CChecker(), _charts_y_cb_cr.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_mcc_CChecker_setChartsYCbCr_Mat(self.as_raw_mut_MCC_CChecker(), _charts_y_cb_cr.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -906,8 +933,9 @@ pub trait MCC_CChecker: crate::mcc::MCC_CCheckerConst {
 	
 	#[inline]
 	fn set_cost(&mut self, _cost: f32) -> Result<()> {
+		crate::core::validity::check("MCC_CChecker", self.as_raw_mut_MCC_CChecker())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_mcc_CChecker_setCost_float(self.as_raw_mut_MCC_CChecker(), _cost, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_mcc_CChecker_setCost_float(self.as_raw_mut_MCC_CChecker(), _cost, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -915,8 +943,9 @@ pub trait MCC_CChecker: crate::mcc::MCC_CCheckerConst {
 	
 	#[inline]
 	fn set_center(&mut self, _center: core::Point2f) -> Result<()> {
+		crate::core::validity::check("MCC_CChecker", self.as_raw_mut_MCC_CChecker())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_mcc_CChecker_setCenter_Point2f(self.as_raw_mut_MCC_CChecker(), _center.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_mcc_CChecker_setCenter_Point2f(self.as_raw_mut_MCC_CChecker(), _center.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -924,8 +953,9 @@ pub trait MCC_CChecker: crate::mcc::MCC_CCheckerConst {
 	
 	#[inline]
 	fn get_target(&mut self) -> Result<crate::mcc::MCC_TYPECHART> {
+		crate::core::validity::check("MCC_CChecker", self.as_raw_mut_MCC_CChecker())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_mcc_CChecker_getTarget(self.as_raw_mut_MCC_CChecker(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_mcc_CChecker_getTarget(self.as_raw_mut_MCC_CChecker(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -933,8 +963,9 @@ pub trait MCC_CChecker: crate::mcc::MCC_CCheckerConst {
 	
 	#[inline]
 	fn get_box(&mut self) -> Result<core::Vector<core::Point2f>> {
+		crate::core::validity::check("MCC_CChecker", self.as_raw_mut_MCC_CChecker())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_mcc_CChecker_getBox(self.as_raw_mut_MCC_CChecker(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_mcc_CChecker_getBox(self.as_raw_mut_MCC_CChecker(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<core::Point2f>::opencv_from_extern(ret) };
@@ -943,8 +974,9 @@ pub trait MCC_CChecker: crate::mcc::MCC_CCheckerConst {
 	
 	#[inline]
 	fn get_charts_rgb(&mut self) -> Result<core::Mat> {
+		crate::core::validity::check("MCC_CChecker", self.as_raw_mut_MCC_CChecker())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_mcc_CChecker_getChartsRGB(self.as_raw_mut_MCC_CChecker(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_mcc_CChecker_getChartsRGB(self.as_raw_mut_MCC_CChecker(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -953,8 +985,9 @@ pub trait MCC_CChecker: crate::mcc::MCC_CCheckerConst {
 	
 	#[inline]
 	fn get_charts_y_cb_cr(&mut self) -> Result<core::Mat> {
+		crate::core::validity::check("MCC_CChecker", self.as_raw_mut_MCC_CChecker())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_mcc_CChecker_getChartsYCbCr(self.as_raw_mut_MCC_CChecker(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_mcc_CChecker_getChartsYCbCr(self.as_raw_mut_MCC_CChecker(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -963,8 +996,9 @@ pub trait MCC_CChecker: crate::mcc::MCC_CCheckerConst {
 	
 	#[inline]
 	fn get_cost(&mut self) -> Result<f32> {
+		crate::core::validity::check("MCC_CChecker", self.as_raw_mut_MCC_CChecker())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_mcc_CChecker_getCost(self.as_raw_mut_MCC_CChecker(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_mcc_CChecker_getCost(self.as_raw_mut_MCC_CChecker(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -972,8 +1006,9 @@ pub trait MCC_CChecker: crate::mcc::MCC_CCheckerConst {
 	
 	#[inline]
 	fn get_center(&mut self) -> Result<core::Point2f> {
+		crate::core::validity::check("MCC_CChecker", self.as_raw_mut_MCC_CChecker())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_mcc_CChecker_getCenter(self.as_raw_mut_MCC_CChecker(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_mcc_CChecker_getCenter(self.as_raw_mut_MCC_CChecker(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -987,7 +1022,7 @@ impl dyn MCC_CChecker + '_ {
 	#[inline]
 	pub fn create() -> Result<core::Ptr<dyn crate::mcc::MCC_CChecker>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_mcc_CChecker_create(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_mcc_CChecker_create(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::mcc::MCC_CChecker>::opencv_from_extern(ret) };
@@ -1016,8 +1051,9 @@ pub trait MCC_CCheckerDetector: core::AlgorithmTrait + crate::mcc::MCC_CCheckerD
 	///        false otherwise.
 	#[inline]
 	fn set_net(&mut self, mut net: crate::dnn::Net) -> Result<bool> {
+		crate::core::validity::check("MCC_CCheckerDetector", self.as_raw_mut_MCC_CCheckerDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_mcc_CCheckerDetector_setNet_Net(self.as_raw_mut_MCC_CCheckerDetector(), net.as_raw_mut_Net(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_mcc_CCheckerDetector_setNet_Net(self.as_raw_mut_MCC_CCheckerDetector(), net.as_raw_mut_Net(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1048,9 +1084,10 @@ pub trait MCC_CCheckerDetector: core::AlgorithmTrait + crate::mcc::MCC_CCheckerD
 	/// * params: DetectorParameters::create()
 	#[inline]
 	fn process_with_roi(&mut self, image: &dyn core::ToInputArray, chart_type: crate::mcc::MCC_TYPECHART, regions_of_interest: &core::Vector<core::Rect>, nc: i32, use_net: bool, params: &core::Ptr<crate::mcc::MCC_DetectorParameters>) -> Result<bool> {
+		crate::core::validity::check("MCC_CCheckerDetector", self.as_raw_mut_MCC_CCheckerDetector())?;
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_mcc_CCheckerDetector_process_const__InputArrayR_const_TYPECHART_const_vector_Rect_R_const_int_bool_const_Ptr_DetectorParameters_R(self.as_raw_mut_MCC_CCheckerDetector(), image.as_raw__InputArray(), chart_type, regions_of_interest.as_raw_VectorOfRect(), nc, use_net, params.as_raw_PtrOfMCC_DetectorParameters(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_mcc_CCheckerDetector_process_const__InputArrayR_const_TYPECHART_const_vector_Rect_R_const_int_bool_const_Ptr_DetectorParameters_R(self.as_raw_mut_MCC_CCheckerDetector(), image.as_raw__InputArray(), chart_type, regions_of_interest.as_raw_VectorOfRect(), nc, use_net, params.as_raw_PtrOfMCC_DetectorParameters(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1082,9 +1119,10 @@ pub trait MCC_CCheckerDetector: core::AlgorithmTrait + crate::mcc::MCC_CCheckerD
 	/// * params: DetectorParameters::create()
 	#[inline]
 	fn process(&mut self, image: &dyn core::ToInputArray, chart_type: crate::mcc::MCC_TYPECHART, nc: i32, use_net: bool, params: &core::Ptr<crate::mcc::MCC_DetectorParameters>) -> Result<bool> {
+		crate::core::validity::check("MCC_CCheckerDetector", self.as_raw_mut_MCC_CCheckerDetector())?;
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_mcc_CCheckerDetector_process_const__InputArrayR_const_TYPECHART_const_int_bool_const_Ptr_DetectorParameters_R(self.as_raw_mut_MCC_CCheckerDetector(), image.as_raw__InputArray(), chart_type, nc, use_net, params.as_raw_PtrOfMCC_DetectorParameters(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_mcc_CCheckerDetector_process_const__InputArrayR_const_TYPECHART_const_int_bool_const_Ptr_DetectorParameters_R(self.as_raw_mut_MCC_CCheckerDetector(), image.as_raw__InputArray(), chart_type, nc, use_net, params.as_raw_PtrOfMCC_DetectorParameters(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1096,8 +1134,9 @@ pub trait MCC_CCheckerDetector: core::AlgorithmTrait + crate::mcc::MCC_CCheckerD
 	///                was detected, 'nullptr' otherwise.
 	#[inline]
 	fn get_best_color_checker(&mut self) -> Result<core::Ptr<dyn crate::mcc::MCC_CChecker>> {
+		crate::core::validity::check("MCC_CCheckerDetector", self.as_raw_mut_MCC_CCheckerDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_mcc_CCheckerDetector_getBestColorChecker(self.as_raw_mut_MCC_CCheckerDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_mcc_CCheckerDetector_getBestColorChecker(self.as_raw_mut_MCC_CCheckerDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::mcc::MCC_CChecker>::opencv_from_extern(ret) };
@@ -1108,8 +1147,9 @@ pub trait MCC_CCheckerDetector: core::AlgorithmTrait + crate::mcc::MCC_CCheckerD
 	/// \return checkers vector of colorcheckers
 	#[inline]
 	fn get_list_color_checker(&mut self) -> Result<core::Vector<core::Ptr<dyn crate::mcc::MCC_CChecker>>> {
+		crate::core::validity::check("MCC_CCheckerDetector", self.as_raw_mut_MCC_CCheckerDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_mcc_CCheckerDetector_getListColorChecker(self.as_raw_mut_MCC_CCheckerDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_mcc_CCheckerDetector_getListColorChecker(self.as_raw_mut_MCC_CCheckerDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<core::Ptr<dyn crate::mcc::MCC_CChecker>>::opencv_from_extern(ret) };
@@ -1118,12 +1158,91 @@ pub trait MCC_CCheckerDetector: core::AlgorithmTrait + crate::mcc::MCC_CCheckerD
 	
 }
 
+/// Builder for `MCC_CCheckerDetector::process_with_roi()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct MCC_CCheckerDetector_ProcessWithRoiBuilder<'a, T: ?Sized + MCC_CCheckerDetector> {
+	instance: &'a mut T,
+	image: &'a dyn core::ToInputArray,
+	chart_type: crate::mcc::MCC_TYPECHART,
+	regions_of_interest: &'a core::Vector<core::Rect>,
+	nc: i32,
+	use_net: bool,
+	params: &'a core::Ptr<crate::mcc::MCC_DetectorParameters>,
+}
+
+impl<'a, T: ?Sized + MCC_CCheckerDetector> MCC_CCheckerDetector_ProcessWithRoiBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, image: &'a dyn core::ToInputArray, chart_type: crate::mcc::MCC_TYPECHART, regions_of_interest: &'a core::Vector<core::Rect>, params: &'a core::Ptr<crate::mcc::MCC_DetectorParameters>) -> Self {
+		Self { instance, image, chart_type, regions_of_interest, nc: 1, use_net: false, params }
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_nc(mut self, nc: i32) -> Self {
+		self.nc = nc;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_use_net(mut self, use_net: bool) -> Self {
+		self.use_net = use_net;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<bool> {
+		MCC_CCheckerDetector::process_with_roi(self.instance, self.image, self.chart_type, self.regions_of_interest, self.nc, self.use_net, self.params)
+	}
+}
+
+/// Builder for `MCC_CCheckerDetector::process()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct MCC_CCheckerDetector_ProcessBuilder<'a, T: ?Sized + MCC_CCheckerDetector> {
+	instance: &'a mut T,
+	image: &'a dyn core::ToInputArray,
+	chart_type: crate::mcc::MCC_TYPECHART,
+	nc: i32,
+	use_net: bool,
+	params: &'a core::Ptr<crate::mcc::MCC_DetectorParameters>,
+}
+
+impl<'a, T: ?Sized + MCC_CCheckerDetector> MCC_CCheckerDetector_ProcessBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, image: &'a dyn core::ToInputArray, chart_type: crate::mcc::MCC_TYPECHART, params: &'a core::Ptr<crate::mcc::MCC_DetectorParameters>) -> Self {
+		Self { instance, image, chart_type, nc: 1, use_net: false, params }
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_nc(mut self, nc: i32) -> Self {
+		self.nc = nc;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_use_net(mut self, use_net: bool) -> Self {
+		self.use_net = use_net;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<bool> {
+		MCC_CCheckerDetector::process(self.instance, self.image, self.chart_type, self.nc, self.use_net, self.params)
+	}
+}
+
 impl dyn MCC_CCheckerDetector + '_ {
 	/// \brief Returns the implementation of the CCheckerDetector.
 	#[inline]
 	pub fn create() -> Result<core::Ptr<dyn crate::mcc::MCC_CCheckerDetector>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_mcc_CCheckerDetector_create(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_mcc_CCheckerDetector_create(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::mcc::MCC_CCheckerDetector>::opencv_from_extern(ret) };
@@ -1157,9 +1276,10 @@ pub trait MCC_CCheckerDraw: crate::mcc::MCC_CCheckerDrawConst {
 	/// \return void
 	#[inline]
 	fn draw(&mut self, img: &mut dyn core::ToInputOutputArray) -> Result<()> {
+		crate::core::validity::check("MCC_CCheckerDraw", self.as_raw_mut_MCC_CCheckerDraw())?;
 		input_output_array_arg!(img);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_mcc_CCheckerDraw_draw_const__InputOutputArrayR(self.as_raw_mut_MCC_CCheckerDraw(), img.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_mcc_CCheckerDraw_draw_const__InputOutputArrayR(self.as_raw_mut_MCC_CCheckerDraw(), img.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1182,7 +1302,7 @@ impl dyn MCC_CCheckerDraw + '_ {
 	#[inline]
 	pub fn create(mut p_checker: core::Ptr<dyn crate::mcc::MCC_CChecker>, color: core::Scalar, thickness: i32) -> Result<core::Ptr<dyn crate::mcc::MCC_CCheckerDraw>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_mcc_CCheckerDraw_create_Ptr_CChecker__Scalar_int(p_checker.as_raw_mut_PtrOfMCC_CChecker(), color.opencv_as_extern(), thickness, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_mcc_CCheckerDraw_create_Ptr_CChecker__Scalar_int(p_checker.as_raw_mut_PtrOfMCC_CChecker(), color.opencv_as_extern(), thickness, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::mcc::MCC_CCheckerDraw>::opencv_from_extern(ret) };
@@ -1242,112 +1362,274 @@ pub trait MCC_DetectorParametersTraitConst {
 
 	#[inline]
 	fn adaptive_thresh_win_size_min(&self) -> i32 {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_getPropAdaptiveThreshWinSizeMin_const(self.as_raw_MCC_DetectorParameters()) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_getPropAdaptiveThreshWinSizeMin_const(self.as_raw_MCC_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [adaptive_thresh_win_size_min](MCC_DetectorParametersTraitConst::adaptive_thresh_win_size_min), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_adaptive_thresh_win_size_min(&self) -> Result<i32> {
+		crate::core::validity::check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters())?;
+		Ok(MCC_DetectorParametersTraitConst::adaptive_thresh_win_size_min(self))
+	}
+	
 	#[inline]
 	fn adaptive_thresh_win_size_max(&self) -> i32 {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_getPropAdaptiveThreshWinSizeMax_const(self.as_raw_MCC_DetectorParameters()) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_getPropAdaptiveThreshWinSizeMax_const(self.as_raw_MCC_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [adaptive_thresh_win_size_max](MCC_DetectorParametersTraitConst::adaptive_thresh_win_size_max), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_adaptive_thresh_win_size_max(&self) -> Result<i32> {
+		crate::core::validity::check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters())?;
+		Ok(MCC_DetectorParametersTraitConst::adaptive_thresh_win_size_max(self))
+	}
+	
 	#[inline]
 	fn adaptive_thresh_win_size_step(&self) -> i32 {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_getPropAdaptiveThreshWinSizeStep_const(self.as_raw_MCC_DetectorParameters()) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_getPropAdaptiveThreshWinSizeStep_const(self.as_raw_MCC_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [adaptive_thresh_win_size_step](MCC_DetectorParametersTraitConst::adaptive_thresh_win_size_step), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_adaptive_thresh_win_size_step(&self) -> Result<i32> {
+		crate::core::validity::check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters())?;
+		Ok(MCC_DetectorParametersTraitConst::adaptive_thresh_win_size_step(self))
+	}
+	
 	#[inline]
 	fn adaptive_thresh_constant(&self) -> f64 {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_getPropAdaptiveThreshConstant_const(self.as_raw_MCC_DetectorParameters()) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_getPropAdaptiveThreshConstant_const(self.as_raw_MCC_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [adaptive_thresh_constant](MCC_DetectorParametersTraitConst::adaptive_thresh_constant), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_adaptive_thresh_constant(&self) -> Result<f64> {
+		crate::core::validity::check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters())?;
+		Ok(MCC_DetectorParametersTraitConst::adaptive_thresh_constant(self))
+	}
+	
 	#[inline]
 	fn min_contours_area_rate(&self) -> f64 {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_getPropMinContoursAreaRate_const(self.as_raw_MCC_DetectorParameters()) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_getPropMinContoursAreaRate_const(self.as_raw_MCC_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [min_contours_area_rate](MCC_DetectorParametersTraitConst::min_contours_area_rate), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_min_contours_area_rate(&self) -> Result<f64> {
+		crate::core::validity::check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters())?;
+		Ok(MCC_DetectorParametersTraitConst::min_contours_area_rate(self))
+	}
+	
 	#[inline]
 	fn min_contours_area(&self) -> f64 {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_getPropMinContoursArea_const(self.as_raw_MCC_DetectorParameters()) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_getPropMinContoursArea_const(self.as_raw_MCC_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [min_contours_area](MCC_DetectorParametersTraitConst::min_contours_area), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_min_contours_area(&self) -> Result<f64> {
+		crate::core::validity::check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters())?;
+		Ok(MCC_DetectorParametersTraitConst::min_contours_area(self))
+	}
+	
 	#[inline]
 	fn confidence_threshold(&self) -> f64 {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_getPropConfidenceThreshold_const(self.as_raw_MCC_DetectorParameters()) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_getPropConfidenceThreshold_const(self.as_raw_MCC_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [confidence_threshold](MCC_DetectorParametersTraitConst::confidence_threshold), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_confidence_threshold(&self) -> Result<f64> {
+		crate::core::validity::check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters())?;
+		Ok(MCC_DetectorParametersTraitConst::confidence_threshold(self))
+	}
+	
 	#[inline]
 	fn min_contour_solidity(&self) -> f64 {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_getPropMinContourSolidity_const(self.as_raw_MCC_DetectorParameters()) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_getPropMinContourSolidity_const(self.as_raw_MCC_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [min_contour_solidity](MCC_DetectorParametersTraitConst::min_contour_solidity), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_min_contour_solidity(&self) -> Result<f64> {
+		crate::core::validity::check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters())?;
+		Ok(MCC_DetectorParametersTraitConst::min_contour_solidity(self))
+	}
+	
 	#[inline]
 	fn find_candidates_approx_poly_dp_eps_multiplier(&self) -> f64 {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_getPropFindCandidatesApproxPolyDPEpsMultiplier_const(self.as_raw_MCC_DetectorParameters()) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_getPropFindCandidatesApproxPolyDPEpsMultiplier_const(self.as_raw_MCC_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [find_candidates_approx_poly_dp_eps_multiplier](MCC_DetectorParametersTraitConst::find_candidates_approx_poly_dp_eps_multiplier), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_find_candidates_approx_poly_dp_eps_multiplier(&self) -> Result<f64> {
+		crate::core::validity::check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters())?;
+		Ok(MCC_DetectorParametersTraitConst::find_candidates_approx_poly_dp_eps_multiplier(self))
+	}
+	
 	#[inline]
 	fn border_width(&self) -> i32 {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_getPropBorderWidth_const(self.as_raw_MCC_DetectorParameters()) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_getPropBorderWidth_const(self.as_raw_MCC_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [border_width](MCC_DetectorParametersTraitConst::border_width), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_border_width(&self) -> Result<i32> {
+		crate::core::validity::check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters())?;
+		Ok(MCC_DetectorParametersTraitConst::border_width(self))
+	}
+	
 	#[inline]
 	fn b0factor(&self) -> f32 {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_getPropB0factor_const(self.as_raw_MCC_DetectorParameters()) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_getPropB0factor_const(self.as_raw_MCC_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [b0factor](MCC_DetectorParametersTraitConst::b0factor), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_b0factor(&self) -> Result<f32> {
+		crate::core::validity::check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters())?;
+		Ok(MCC_DetectorParametersTraitConst::b0factor(self))
+	}
+	
 	#[inline]
 	fn max_error(&self) -> f32 {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_getPropMaxError_const(self.as_raw_MCC_DetectorParameters()) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_getPropMaxError_const(self.as_raw_MCC_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [max_error](MCC_DetectorParametersTraitConst::max_error), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_max_error(&self) -> Result<f32> {
+		crate::core::validity::check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters())?;
+		Ok(MCC_DetectorParametersTraitConst::max_error(self))
+	}
+	
 	#[inline]
 	fn min_contour_points_allowed(&self) -> i32 {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_getPropMinContourPointsAllowed_const(self.as_raw_MCC_DetectorParameters()) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_getPropMinContourPointsAllowed_const(self.as_raw_MCC_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [min_contour_points_allowed](MCC_DetectorParametersTraitConst::min_contour_points_allowed), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_min_contour_points_allowed(&self) -> Result<i32> {
+		crate::core::validity::check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters())?;
+		Ok(MCC_DetectorParametersTraitConst::min_contour_points_allowed(self))
+	}
+	
 	#[inline]
 	fn min_contour_length_allowed(&self) -> i32 {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_getPropMinContourLengthAllowed_const(self.as_raw_MCC_DetectorParameters()) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_getPropMinContourLengthAllowed_const(self.as_raw_MCC_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [min_contour_length_allowed](MCC_DetectorParametersTraitConst::min_contour_length_allowed), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_min_contour_length_allowed(&self) -> Result<i32> {
+		crate::core::validity::check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters())?;
+		Ok(MCC_DetectorParametersTraitConst::min_contour_length_allowed(self))
+	}
+	
 	#[inline]
 	fn min_inter_contour_distance(&self) -> i32 {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_getPropMinInterContourDistance_const(self.as_raw_MCC_DetectorParameters()) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_getPropMinInterContourDistance_const(self.as_raw_MCC_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [min_inter_contour_distance](MCC_DetectorParametersTraitConst::min_inter_contour_distance), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_min_inter_contour_distance(&self) -> Result<i32> {
+		crate::core::validity::check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters())?;
+		Ok(MCC_DetectorParametersTraitConst::min_inter_contour_distance(self))
+	}
+	
 	#[inline]
 	fn min_inter_checker_distance(&self) -> i32 {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_getPropMinInterCheckerDistance_const(self.as_raw_MCC_DetectorParameters()) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_getPropMinInterCheckerDistance_const(self.as_raw_MCC_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [min_inter_checker_distance](MCC_DetectorParametersTraitConst::min_inter_checker_distance), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_min_inter_checker_distance(&self) -> Result<i32> {
+		crate::core::validity::check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters())?;
+		Ok(MCC_DetectorParametersTraitConst::min_inter_checker_distance(self))
+	}
+	
 	#[inline]
 	fn min_image_size(&self) -> i32 {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_getPropMinImageSize_const(self.as_raw_MCC_DetectorParameters()) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_getPropMinImageSize_const(self.as_raw_MCC_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [min_image_size](MCC_DetectorParametersTraitConst::min_image_size), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_min_image_size(&self) -> Result<i32> {
+		crate::core::validity::check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters())?;
+		Ok(MCC_DetectorParametersTraitConst::min_image_size(self))
+	}
+	
 	#[inline]
 	fn min_group_size(&self) -> u32 {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_getPropMinGroupSize_const(self.as_raw_MCC_DetectorParameters()) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_getPropMinGroupSize_const(self.as_raw_MCC_DetectorParameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [min_group_size](MCC_DetectorParametersTraitConst::min_group_size), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_min_group_size(&self) -> Result<u32> {
+		crate::core::validity::check("MCC_DetectorParameters", self.as_raw_MCC_DetectorParameters())?;
+		Ok(MCC_DetectorParametersTraitConst::min_group_size(self))
+	}
+	
 }
 
 pub trait MCC_DetectorParametersTrait: crate::mcc::MCC_DetectorParametersTraitConst {
@@ -1355,109 +1637,127 @@ pub trait MCC_DetectorParametersTrait: crate::mcc::MCC_DetectorParametersTraitCo
 
 	#[inline]
 	fn set_adaptive_thresh_win_size_min(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_setPropAdaptiveThreshWinSizeMin_int(self.as_raw_mut_MCC_DetectorParameters(), val) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_mut_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_setPropAdaptiveThreshWinSizeMin_int(self.as_raw_mut_MCC_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_adaptive_thresh_win_size_max(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_setPropAdaptiveThreshWinSizeMax_int(self.as_raw_mut_MCC_DetectorParameters(), val) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_mut_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_setPropAdaptiveThreshWinSizeMax_int(self.as_raw_mut_MCC_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_adaptive_thresh_win_size_step(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_setPropAdaptiveThreshWinSizeStep_int(self.as_raw_mut_MCC_DetectorParameters(), val) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_mut_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_setPropAdaptiveThreshWinSizeStep_int(self.as_raw_mut_MCC_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_adaptive_thresh_constant(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_setPropAdaptiveThreshConstant_double(self.as_raw_mut_MCC_DetectorParameters(), val) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_mut_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_setPropAdaptiveThreshConstant_double(self.as_raw_mut_MCC_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_min_contours_area_rate(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_setPropMinContoursAreaRate_double(self.as_raw_mut_MCC_DetectorParameters(), val) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_mut_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_setPropMinContoursAreaRate_double(self.as_raw_mut_MCC_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_min_contours_area(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_setPropMinContoursArea_double(self.as_raw_mut_MCC_DetectorParameters(), val) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_mut_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_setPropMinContoursArea_double(self.as_raw_mut_MCC_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_confidence_threshold(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_setPropConfidenceThreshold_double(self.as_raw_mut_MCC_DetectorParameters(), val) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_mut_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_setPropConfidenceThreshold_double(self.as_raw_mut_MCC_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_min_contour_solidity(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_setPropMinContourSolidity_double(self.as_raw_mut_MCC_DetectorParameters(), val) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_mut_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_setPropMinContourSolidity_double(self.as_raw_mut_MCC_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_find_candidates_approx_poly_dp_eps_multiplier(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_setPropFindCandidatesApproxPolyDPEpsMultiplier_double(self.as_raw_mut_MCC_DetectorParameters(), val) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_mut_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_setPropFindCandidatesApproxPolyDPEpsMultiplier_double(self.as_raw_mut_MCC_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_border_width(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_setPropBorderWidth_int(self.as_raw_mut_MCC_DetectorParameters(), val) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_mut_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_setPropBorderWidth_int(self.as_raw_mut_MCC_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_b0factor(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_setPropB0factor_float(self.as_raw_mut_MCC_DetectorParameters(), val) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_mut_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_setPropB0factor_float(self.as_raw_mut_MCC_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_max_error(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_setPropMaxError_float(self.as_raw_mut_MCC_DetectorParameters(), val) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_mut_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_setPropMaxError_float(self.as_raw_mut_MCC_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_min_contour_points_allowed(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_setPropMinContourPointsAllowed_int(self.as_raw_mut_MCC_DetectorParameters(), val) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_mut_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_setPropMinContourPointsAllowed_int(self.as_raw_mut_MCC_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_min_contour_length_allowed(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_setPropMinContourLengthAllowed_int(self.as_raw_mut_MCC_DetectorParameters(), val) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_mut_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_setPropMinContourLengthAllowed_int(self.as_raw_mut_MCC_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_min_inter_contour_distance(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_setPropMinInterContourDistance_int(self.as_raw_mut_MCC_DetectorParameters(), val) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_mut_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_setPropMinInterContourDistance_int(self.as_raw_mut_MCC_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_min_inter_checker_distance(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_setPropMinInterCheckerDistance_int(self.as_raw_mut_MCC_DetectorParameters(), val) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_mut_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_setPropMinInterCheckerDistance_int(self.as_raw_mut_MCC_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_min_image_size(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_setPropMinImageSize_int(self.as_raw_mut_MCC_DetectorParameters(), val) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_mut_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_setPropMinImageSize_int(self.as_raw_mut_MCC_DetectorParameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_min_group_size(&mut self, val: u32) {
-		let ret = unsafe { sys::cv_mcc_DetectorParameters_setPropMinGroupSize_unsigned_int(self.as_raw_mut_MCC_DetectorParameters(), val) };
+		crate::core::validity::debug_check("MCC_DetectorParameters", self.as_raw_mut_MCC_DetectorParameters());
+		let ret = unsafe { ffi_call!(cv_mcc_DetectorParameters_setPropMinGroupSize_unsigned_int(self.as_raw_mut_MCC_DetectorParameters(), val)) };
 		ret
 	}
 	
@@ -1519,7 +1819,10 @@ opencv_type_boxed! { MCC_DetectorParameters }
 impl Drop for MCC_DetectorParameters {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MCC_DetectorParameters_delete(instance: *mut c_void); }
-		unsafe { cv_MCC_DetectorParameters_delete(self.as_raw_mut_MCC_DetectorParameters()) };
+		let ptr = self.as_raw_mut_MCC_DetectorParameters();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_MCC_DetectorParameters_delete(ptr) };
 	}
 }
 
@@ -1537,7 +1840,7 @@ impl MCC_DetectorParameters {
 	#[inline]
 	pub fn default() -> Result<crate::mcc::MCC_DetectorParameters> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_mcc_DetectorParameters_DetectorParameters(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_mcc_DetectorParameters_DetectorParameters(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::mcc::MCC_DetectorParameters::opencv_from_extern(ret) };
@@ -1547,7 +1850,7 @@ impl MCC_DetectorParameters {
 	#[inline]
 	pub fn create() -> Result<core::Ptr<crate::mcc::MCC_DetectorParameters>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_mcc_DetectorParameters_create(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_mcc_DetectorParameters_create(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::mcc::MCC_DetectorParameters>::opencv_from_extern(ret) };
@@ -1555,3 +1858,4 @@ impl MCC_DetectorParameters {
 	}
 	
 }
+pub use crate::manual::mcc::*;
@@ -17,13 +17,14 @@
 //! categorical input variables), there is a little common ground between the classes. This common
 //! ground is defined by the class cv::ml::StatModel that all the other ML classes are derived from.
 //! 
-//! See detailed overview here: @ref ml_intro.
+//! See detailed overview here: ml_intro.
 use crate::{mod_prelude::*, core, sys, types};
 pub mod prelude {
 	pub use { super::ParamGridTraitConst, super::ParamGridTrait, super::TrainDataConst, super::TrainData, super::StatModelConst, super::StatModel, super::NormalBayesClassifierConst, super::NormalBayesClassifier, super::KNearestConst, super::KNearest, super::SVM_KernelConst, super::SVM_Kernel, super::SVMConst, super::SVM, super::EMConst, super::EM, super::DTrees_NodeTraitConst, super::DTrees_NodeTrait, super::DTrees_SplitTraitConst, super::DTrees_SplitTrait, super::DTreesConst, super::DTrees, super::RTreesConst, super::RTrees, super::BoostConst, super::Boost, super::ANN_MLPConst, super::ANN_MLP, super::LogisticRegressionConst, super::LogisticRegression, super::SVMSGDConst, super::SVMSGD };
 }
 
 /// each training sample occupies a column of samples
+#[deprecated = "Use SampleTypes::COL_SAMPLE instead"]
 pub const COL_SAMPLE: i32 = 1;
 pub const EM_DEFAULT_MAX_ITERS: i32 = 100;
 pub const EM_DEFAULT_NCLUSTERS: i32 = 5;
@@ -31,14 +32,20 @@ pub const EM_START_AUTO_STEP: i32 = 0;
 pub const EM_START_E_STEP: i32 = 1;
 pub const EM_START_M_STEP: i32 = 2;
 /// each training sample is a row of samples
+#[deprecated = "Use SampleTypes::ROW_SAMPLE instead"]
 pub const ROW_SAMPLE: i32 = 0;
+#[deprecated = "Use ErrorTypes::TEST_ERROR instead"]
 pub const TEST_ERROR: i32 = 0;
+#[deprecated = "Use ErrorTypes::TRAIN_ERROR instead"]
 pub const TRAIN_ERROR: i32 = 1;
 /// categorical variables
+#[deprecated = "Use VariableTypes::VAR_CATEGORICAL instead"]
 pub const VAR_CATEGORICAL: i32 = 1;
 /// same as VAR_ORDERED
+#[deprecated = "Use VariableTypes::VAR_NUMERICAL instead"]
 pub const VAR_NUMERICAL: i32 = 0;
 /// ordered variables
+#[deprecated = "Use VariableTypes::VAR_NUMERICAL instead"]
 pub const VAR_ORDERED: i32 = 0;
 /// possible activation functions
 #[repr(C)]
@@ -51,7 +58,7 @@ pub enum ANN_MLP_ActivationFunctions {
 	/// Note:
 	/// If you are using the default sigmoid activation function with the default parameter values
 	/// fparam1=0 and fparam2=0 then the function used is y = 1.7159\*tanh(2/3 \* x), so the output
-	/// will range from [-1.7159, 1.7159], instead of [0,1].
+	/// will range from \[-1.7159, 1.7159\], instead of \[0,1\].
 	SIGMOID_SYM = 1,
 	/// Gaussian function: ![inline formula](https://latex.codecogs.com/png.latex?f%28x%29%3D%5Cbeta%20e%5E%7B%2D%5Calpha%20x%2Ax%7D)
 	GAUSSIAN = 2,
@@ -346,7 +353,7 @@ pub fn create_concentric_spheres_test_set(nsamples: i32, nfeatures: i32, nclasse
 	output_array_arg!(samples);
 	output_array_arg!(responses);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ml_createConcentricSpheresTestSet_int_int_int_const__OutputArrayR_const__OutputArrayR(nsamples, nfeatures, nclasses, samples.as_raw__OutputArray(), responses.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ml_createConcentricSpheresTestSet_int_int_int_const__OutputArrayR_const__OutputArrayR(nsamples, nfeatures, nclasses, samples.as_raw__OutputArray(), responses.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -365,7 +372,7 @@ pub fn rand_mv_normal(mean: &dyn core::ToInputArray, cov: &dyn core::ToInputArra
 	input_array_arg!(cov);
 	output_array_arg!(samples);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ml_randMVNormal_const__InputArrayR_const__InputArrayR_int_const__OutputArrayR(mean.as_raw__InputArray(), cov.as_raw__InputArray(), nsamples, samples.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ml_randMVNormal_const__InputArrayR_const__InputArrayR_int_const__OutputArrayR(mean.as_raw__InputArray(), cov.as_raw__InputArray(), nsamples, samples.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -381,15 +388,16 @@ pub fn rand_mv_normal(mean: &dyn core::ToInputArray, cov: &dyn core::ToInputArra
 /// 
 /// Additional flags for StatModel::train are available: ANN_MLP::TrainFlags.
 /// ## See also
-/// @ref ml_intro_ann
+/// ml_intro_ann
 pub trait ANN_MLPConst: crate::ml::StatModelConst {
 	fn as_raw_ANN_MLP(&self) -> *const c_void;
 
 	/// Returns current training method
 	#[inline]
 	fn get_train_method(&self) -> Result<i32> {
+		crate::core::validity::check("ANN_MLP", self.as_raw_ANN_MLP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_getTrainMethod_const(self.as_raw_ANN_MLP(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_getTrainMethod_const(self.as_raw_ANN_MLP(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -399,11 +407,12 @@ pub trait ANN_MLPConst: crate::ml::StatModelConst {
 	/// The very first element specifies the number of elements in the input layer.
 	/// The last element - number of elements in the output layer.
 	/// ## See also
-	/// setLayerSizes
+	/// `set_layer_sizes`
 	#[inline]
 	fn get_layer_sizes(&self) -> Result<core::Mat> {
+		crate::core::validity::check("ANN_MLP", self.as_raw_ANN_MLP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_getLayerSizes_const(self.as_raw_ANN_MLP(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_getLayerSizes_const(self.as_raw_ANN_MLP(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -415,11 +424,12 @@ pub trait ANN_MLPConst: crate::ml::StatModelConst {
 	///    change between the iterations to make the algorithm continue (epsilon). Default value is
 	///    TermCriteria(TermCriteria::MAX_ITER + TermCriteria::EPS, 1000, 0.01).
 	/// ## See also
-	/// setTermCriteria
+	/// `set_term_criteria`
 	#[inline]
 	fn get_term_criteria(&self) -> Result<core::TermCriteria> {
+		crate::core::validity::check("ANN_MLP", self.as_raw_ANN_MLP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_getTermCriteria_const(self.as_raw_ANN_MLP(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_getTermCriteria_const(self.as_raw_ANN_MLP(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -428,11 +438,12 @@ pub trait ANN_MLPConst: crate::ml::StatModelConst {
 	/// BPROP: Strength of the weight gradient term.
 	///    The recommended value is about 0.1. Default value is 0.1.
 	/// ## See also
-	/// setBackpropWeightScale
+	/// `set_backprop_weight_scale`
 	#[inline]
 	fn get_backprop_weight_scale(&self) -> Result<f64> {
+		crate::core::validity::check("ANN_MLP", self.as_raw_ANN_MLP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_getBackpropWeightScale_const(self.as_raw_ANN_MLP(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_getBackpropWeightScale_const(self.as_raw_ANN_MLP(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -443,11 +454,12 @@ pub trait ANN_MLPConst: crate::ml::StatModelConst {
 	///    vary from 0 (the feature is disabled) to 1 and beyond. The value 0.1 or so is good enough.
 	///    Default value is 0.1.
 	/// ## See also
-	/// setBackpropMomentumScale
+	/// `set_backprop_momentum_scale`
 	#[inline]
 	fn get_backprop_momentum_scale(&self) -> Result<f64> {
+		crate::core::validity::check("ANN_MLP", self.as_raw_ANN_MLP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_getBackpropMomentumScale_const(self.as_raw_ANN_MLP(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_getBackpropMomentumScale_const(self.as_raw_ANN_MLP(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -456,11 +468,12 @@ pub trait ANN_MLPConst: crate::ml::StatModelConst {
 	/// RPROP: Initial value ![inline formula](https://latex.codecogs.com/png.latex?%5CDelta%5F0) of update-values ![inline formula](https://latex.codecogs.com/png.latex?%5CDelta%5F%7Bij%7D).
 	///    Default value is 0.1.
 	/// ## See also
-	/// setRpropDW0
+	/// `set_rprop_dw0`
 	#[inline]
 	fn get_rprop_dw0(&self) -> Result<f64> {
+		crate::core::validity::check("ANN_MLP", self.as_raw_ANN_MLP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_getRpropDW0_const(self.as_raw_ANN_MLP(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_getRpropDW0_const(self.as_raw_ANN_MLP(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -469,11 +482,12 @@ pub trait ANN_MLPConst: crate::ml::StatModelConst {
 	/// RPROP: Increase factor ![inline formula](https://latex.codecogs.com/png.latex?%5Ceta%5E%2B).
 	///    It must be \>1. Default value is 1.2.
 	/// ## See also
-	/// setRpropDWPlus
+	/// `set_rprop_dw_plus`
 	#[inline]
 	fn get_rprop_dw_plus(&self) -> Result<f64> {
+		crate::core::validity::check("ANN_MLP", self.as_raw_ANN_MLP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_getRpropDWPlus_const(self.as_raw_ANN_MLP(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_getRpropDWPlus_const(self.as_raw_ANN_MLP(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -482,11 +496,12 @@ pub trait ANN_MLPConst: crate::ml::StatModelConst {
 	/// RPROP: Decrease factor ![inline formula](https://latex.codecogs.com/png.latex?%5Ceta%5E%2D).
 	///    It must be \<1. Default value is 0.5.
 	/// ## See also
-	/// setRpropDWMinus
+	/// `set_rprop_dw_minus`
 	#[inline]
 	fn get_rprop_dw_minus(&self) -> Result<f64> {
+		crate::core::validity::check("ANN_MLP", self.as_raw_ANN_MLP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_getRpropDWMinus_const(self.as_raw_ANN_MLP(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_getRpropDWMinus_const(self.as_raw_ANN_MLP(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -495,11 +510,12 @@ pub trait ANN_MLPConst: crate::ml::StatModelConst {
 	/// RPROP: Update-values lower limit ![inline formula](https://latex.codecogs.com/png.latex?%5CDelta%5F%7Bmin%7D).
 	///    It must be positive. Default value is FLT_EPSILON.
 	/// ## See also
-	/// setRpropDWMin
+	/// `set_rprop_dw_min`
 	#[inline]
 	fn get_rprop_dw_min(&self) -> Result<f64> {
+		crate::core::validity::check("ANN_MLP", self.as_raw_ANN_MLP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_getRpropDWMin_const(self.as_raw_ANN_MLP(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_getRpropDWMin_const(self.as_raw_ANN_MLP(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -508,11 +524,12 @@ pub trait ANN_MLPConst: crate::ml::StatModelConst {
 	/// RPROP: Update-values upper limit ![inline formula](https://latex.codecogs.com/png.latex?%5CDelta%5F%7Bmax%7D).
 	///    It must be \>1. Default value is 50.
 	/// ## See also
-	/// setRpropDWMax
+	/// `set_rprop_dw_max`
 	#[inline]
 	fn get_rprop_dw_max(&self) -> Result<f64> {
+		crate::core::validity::check("ANN_MLP", self.as_raw_ANN_MLP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_getRpropDWMax_const(self.as_raw_ANN_MLP(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_getRpropDWMax_const(self.as_raw_ANN_MLP(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -521,11 +538,12 @@ pub trait ANN_MLPConst: crate::ml::StatModelConst {
 	/// ANNEAL: Update initial temperature.
 	///    It must be \>=0. Default value is 10.
 	/// ## See also
-	/// setAnnealInitialT
+	/// `set_anneal_initial_t`
 	#[inline]
 	fn get_anneal_initial_t(&self) -> Result<f64> {
+		crate::core::validity::check("ANN_MLP", self.as_raw_ANN_MLP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_getAnnealInitialT_const(self.as_raw_ANN_MLP(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_getAnnealInitialT_const(self.as_raw_ANN_MLP(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -534,11 +552,12 @@ pub trait ANN_MLPConst: crate::ml::StatModelConst {
 	/// ANNEAL: Update final temperature.
 	///    It must be \>=0 and less than initialT. Default value is 0.1.
 	/// ## See also
-	/// setAnnealFinalT
+	/// `set_anneal_final_t`
 	#[inline]
 	fn get_anneal_final_t(&self) -> Result<f64> {
+		crate::core::validity::check("ANN_MLP", self.as_raw_ANN_MLP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_getAnnealFinalT_const(self.as_raw_ANN_MLP(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_getAnnealFinalT_const(self.as_raw_ANN_MLP(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -547,11 +566,12 @@ pub trait ANN_MLPConst: crate::ml::StatModelConst {
 	/// ANNEAL: Update cooling ratio.
 	///    It must be \>0 and less than 1. Default value is 0.95.
 	/// ## See also
-	/// setAnnealCoolingRatio
+	/// `set_anneal_cooling_ratio`
 	#[inline]
 	fn get_anneal_cooling_ratio(&self) -> Result<f64> {
+		crate::core::validity::check("ANN_MLP", self.as_raw_ANN_MLP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_getAnnealCoolingRatio_const(self.as_raw_ANN_MLP(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_getAnnealCoolingRatio_const(self.as_raw_ANN_MLP(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -560,11 +580,12 @@ pub trait ANN_MLPConst: crate::ml::StatModelConst {
 	/// ANNEAL: Update iteration per step.
 	///    It must be \>0 . Default value is 10.
 	/// ## See also
-	/// setAnnealItePerStep
+	/// `set_anneal_ite_per_step`
 	#[inline]
 	fn get_anneal_ite_per_step(&self) -> Result<i32> {
+		crate::core::validity::check("ANN_MLP", self.as_raw_ANN_MLP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_getAnnealItePerStep_const(self.as_raw_ANN_MLP(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_getAnnealItePerStep_const(self.as_raw_ANN_MLP(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -572,8 +593,9 @@ pub trait ANN_MLPConst: crate::ml::StatModelConst {
 	
 	#[inline]
 	fn get_weights(&self, layer_idx: i32) -> Result<core::Mat> {
+		crate::core::validity::check("ANN_MLP", self.as_raw_ANN_MLP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_getWeights_const_int(self.as_raw_ANN_MLP(), layer_idx, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_getWeights_const_int(self.as_raw_ANN_MLP(), layer_idx, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -596,8 +618,9 @@ pub trait ANN_MLP: crate::ml::ANN_MLPConst + crate::ml::StatModel {
 	/// * param2: 0
 	#[inline]
 	fn set_train_method(&mut self, method: i32, param1: f64, param2: f64) -> Result<()> {
+		crate::core::validity::check("ANN_MLP", self.as_raw_mut_ANN_MLP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_setTrainMethod_int_double_double(self.as_raw_mut_ANN_MLP(), method, param1, param2, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_setTrainMethod_int_double_double(self.as_raw_mut_ANN_MLP(), method, param1, param2, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -615,8 +638,9 @@ pub trait ANN_MLP: crate::ml::ANN_MLPConst + crate::ml::StatModel {
 	/// * param2: 0
 	#[inline]
 	fn set_activation_function(&mut self, typ: i32, param1: f64, param2: f64) -> Result<()> {
+		crate::core::validity::check("ANN_MLP", self.as_raw_mut_ANN_MLP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_setActivationFunction_int_double_double(self.as_raw_mut_ANN_MLP(), typ, param1, param2, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_setActivationFunction_int_double_double(self.as_raw_mut_ANN_MLP(), typ, param1, param2, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -626,12 +650,13 @@ pub trait ANN_MLP: crate::ml::ANN_MLPConst + crate::ml::StatModel {
 	/// The very first element specifies the number of elements in the input layer.
 	/// The last element - number of elements in the output layer. Default value is empty Mat.
 	/// ## See also
-	/// getLayerSizes
+	/// `get_layer_sizes`
 	#[inline]
 	fn set_layer_sizes(&mut self, _layer_sizes: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("ANN_MLP", self.as_raw_mut_ANN_MLP())?;
 		input_array_arg!(_layer_sizes);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_setLayerSizes_const__InputArrayR(self.as_raw_mut_ANN_MLP(), _layer_sizes.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_setLayerSizes_const__InputArrayR(self.as_raw_mut_ANN_MLP(), _layer_sizes.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -645,8 +670,9 @@ pub trait ANN_MLP: crate::ml::ANN_MLPConst + crate::ml::StatModel {
 	/// setTermCriteria getTermCriteria
 	#[inline]
 	fn set_term_criteria(&mut self, val: core::TermCriteria) -> Result<()> {
+		crate::core::validity::check("ANN_MLP", self.as_raw_mut_ANN_MLP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_setTermCriteria_TermCriteria(self.as_raw_mut_ANN_MLP(), val.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_setTermCriteria_TermCriteria(self.as_raw_mut_ANN_MLP(), val.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -658,8 +684,9 @@ pub trait ANN_MLP: crate::ml::ANN_MLPConst + crate::ml::StatModel {
 	/// setBackpropWeightScale getBackpropWeightScale
 	#[inline]
 	fn set_backprop_weight_scale(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("ANN_MLP", self.as_raw_mut_ANN_MLP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_setBackpropWeightScale_double(self.as_raw_mut_ANN_MLP(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_setBackpropWeightScale_double(self.as_raw_mut_ANN_MLP(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -673,8 +700,9 @@ pub trait ANN_MLP: crate::ml::ANN_MLPConst + crate::ml::StatModel {
 	/// setBackpropMomentumScale getBackpropMomentumScale
 	#[inline]
 	fn set_backprop_momentum_scale(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("ANN_MLP", self.as_raw_mut_ANN_MLP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_setBackpropMomentumScale_double(self.as_raw_mut_ANN_MLP(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_setBackpropMomentumScale_double(self.as_raw_mut_ANN_MLP(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -686,8 +714,9 @@ pub trait ANN_MLP: crate::ml::ANN_MLPConst + crate::ml::StatModel {
 	/// setRpropDW0 getRpropDW0
 	#[inline]
 	fn set_rprop_dw0(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("ANN_MLP", self.as_raw_mut_ANN_MLP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_setRpropDW0_double(self.as_raw_mut_ANN_MLP(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_setRpropDW0_double(self.as_raw_mut_ANN_MLP(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -699,8 +728,9 @@ pub trait ANN_MLP: crate::ml::ANN_MLPConst + crate::ml::StatModel {
 	/// setRpropDWPlus getRpropDWPlus
 	#[inline]
 	fn set_rprop_dw_plus(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("ANN_MLP", self.as_raw_mut_ANN_MLP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_setRpropDWPlus_double(self.as_raw_mut_ANN_MLP(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_setRpropDWPlus_double(self.as_raw_mut_ANN_MLP(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -712,8 +742,9 @@ pub trait ANN_MLP: crate::ml::ANN_MLPConst + crate::ml::StatModel {
 	/// setRpropDWMinus getRpropDWMinus
 	#[inline]
 	fn set_rprop_dw_minus(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("ANN_MLP", self.as_raw_mut_ANN_MLP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_setRpropDWMinus_double(self.as_raw_mut_ANN_MLP(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_setRpropDWMinus_double(self.as_raw_mut_ANN_MLP(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -725,8 +756,9 @@ pub trait ANN_MLP: crate::ml::ANN_MLPConst + crate::ml::StatModel {
 	/// setRpropDWMin getRpropDWMin
 	#[inline]
 	fn set_rprop_dw_min(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("ANN_MLP", self.as_raw_mut_ANN_MLP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_setRpropDWMin_double(self.as_raw_mut_ANN_MLP(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_setRpropDWMin_double(self.as_raw_mut_ANN_MLP(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -738,8 +770,9 @@ pub trait ANN_MLP: crate::ml::ANN_MLPConst + crate::ml::StatModel {
 	/// setRpropDWMax getRpropDWMax
 	#[inline]
 	fn set_rprop_dw_max(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("ANN_MLP", self.as_raw_mut_ANN_MLP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_setRpropDWMax_double(self.as_raw_mut_ANN_MLP(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_setRpropDWMax_double(self.as_raw_mut_ANN_MLP(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -751,8 +784,9 @@ pub trait ANN_MLP: crate::ml::ANN_MLPConst + crate::ml::StatModel {
 	/// setAnnealInitialT getAnnealInitialT
 	#[inline]
 	fn set_anneal_initial_t(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("ANN_MLP", self.as_raw_mut_ANN_MLP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_setAnnealInitialT_double(self.as_raw_mut_ANN_MLP(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_setAnnealInitialT_double(self.as_raw_mut_ANN_MLP(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -764,8 +798,9 @@ pub trait ANN_MLP: crate::ml::ANN_MLPConst + crate::ml::StatModel {
 	/// setAnnealFinalT getAnnealFinalT
 	#[inline]
 	fn set_anneal_final_t(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("ANN_MLP", self.as_raw_mut_ANN_MLP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_setAnnealFinalT_double(self.as_raw_mut_ANN_MLP(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_setAnnealFinalT_double(self.as_raw_mut_ANN_MLP(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -777,8 +812,9 @@ pub trait ANN_MLP: crate::ml::ANN_MLPConst + crate::ml::StatModel {
 	/// setAnnealCoolingRatio getAnnealCoolingRatio
 	#[inline]
 	fn set_anneal_cooling_ratio(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("ANN_MLP", self.as_raw_mut_ANN_MLP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_setAnnealCoolingRatio_double(self.as_raw_mut_ANN_MLP(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_setAnnealCoolingRatio_double(self.as_raw_mut_ANN_MLP(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -790,8 +826,9 @@ pub trait ANN_MLP: crate::ml::ANN_MLPConst + crate::ml::StatModel {
 	/// setAnnealItePerStep getAnnealItePerStep
 	#[inline]
 	fn set_anneal_ite_per_step(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("ANN_MLP", self.as_raw_mut_ANN_MLP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_setAnnealItePerStep_int(self.as_raw_mut_ANN_MLP(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_setAnnealItePerStep_int(self.as_raw_mut_ANN_MLP(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -800,8 +837,9 @@ pub trait ANN_MLP: crate::ml::ANN_MLPConst + crate::ml::StatModel {
 	/// Set/initialize anneal RNG
 	#[inline]
 	fn set_anneal_energy_rng(&mut self, rng: &core::RNG) -> Result<()> {
+		crate::core::validity::check("ANN_MLP", self.as_raw_mut_ANN_MLP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_setAnnealEnergyRNG_const_RNGR(self.as_raw_mut_ANN_MLP(), rng.as_raw_RNG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_setAnnealEnergyRNG_const_RNGR(self.as_raw_mut_ANN_MLP(), rng.as_raw_RNG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -817,7 +855,7 @@ impl dyn ANN_MLP + '_ {
 	#[inline]
 	pub fn create() -> Result<core::Ptr<dyn crate::ml::ANN_MLP>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_create(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_create(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::ml::ANN_MLP>::opencv_from_extern(ret) };
@@ -835,7 +873,7 @@ impl dyn ANN_MLP + '_ {
 	pub fn load(filepath: &str) -> Result<core::Ptr<dyn crate::ml::ANN_MLP>> {
 		extern_container_arg!(filepath);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ANN_MLP_load_const_StringR(filepath.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ANN_MLP_load_const_StringR(filepath.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::ml::ANN_MLP>::opencv_from_extern(ret) };
@@ -845,18 +883,19 @@ impl dyn ANN_MLP + '_ {
 }
 /// Boosted tree classifier derived from DTrees
 /// ## See also
-/// @ref ml_intro_boost
+/// ml_intro_boost
 pub trait BoostConst: crate::ml::DTreesConst {
 	fn as_raw_Boost(&self) -> *const c_void;
 
 	/// Type of the boosting algorithm.
 	///    See Boost::Types. Default value is Boost::REAL.
 	/// ## See also
-	/// setBoostType
+	/// `set_boost_type`
 	#[inline]
 	fn get_boost_type(&self) -> Result<i32> {
+		crate::core::validity::check("Boost", self.as_raw_Boost())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_Boost_getBoostType_const(self.as_raw_Boost(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_Boost_getBoostType_const(self.as_raw_Boost(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -865,11 +904,12 @@ pub trait BoostConst: crate::ml::DTreesConst {
 	/// The number of weak classifiers.
 	///    Default value is 100.
 	/// ## See also
-	/// setWeakCount
+	/// `set_weak_count`
 	#[inline]
 	fn get_weak_count(&self) -> Result<i32> {
+		crate::core::validity::check("Boost", self.as_raw_Boost())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_Boost_getWeakCount_const(self.as_raw_Boost(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_Boost_getWeakCount_const(self.as_raw_Boost(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -879,11 +919,12 @@ pub trait BoostConst: crate::ml::DTreesConst {
 	///    Samples with summary weight ![inline formula](https://latex.codecogs.com/png.latex?%5Cleq%201%20%2D%20weight%5Ftrim%5Frate) do not participate in the *next*
 	///    iteration of training. Set this parameter to 0 to turn off this functionality. Default value is 0.95.
 	/// ## See also
-	/// setWeightTrimRate
+	/// `set_weight_trim_rate`
 	#[inline]
 	fn get_weight_trim_rate(&self) -> Result<f64> {
+		crate::core::validity::check("Boost", self.as_raw_Boost())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_Boost_getWeightTrimRate_const(self.as_raw_Boost(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_Boost_getWeightTrimRate_const(self.as_raw_Boost(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -900,8 +941,9 @@ pub trait Boost: crate::ml::BoostConst + crate::ml::DTrees {
 	/// setBoostType getBoostType
 	#[inline]
 	fn set_boost_type(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("Boost", self.as_raw_mut_Boost())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_Boost_setBoostType_int(self.as_raw_mut_Boost(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_Boost_setBoostType_int(self.as_raw_mut_Boost(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -913,8 +955,9 @@ pub trait Boost: crate::ml::BoostConst + crate::ml::DTrees {
 	/// setWeakCount getWeakCount
 	#[inline]
 	fn set_weak_count(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("Boost", self.as_raw_mut_Boost())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_Boost_setWeakCount_int(self.as_raw_mut_Boost(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_Boost_setWeakCount_int(self.as_raw_mut_Boost(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -927,8 +970,9 @@ pub trait Boost: crate::ml::BoostConst + crate::ml::DTrees {
 	/// setWeightTrimRate getWeightTrimRate
 	#[inline]
 	fn set_weight_trim_rate(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("Boost", self.as_raw_mut_Boost())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_Boost_setWeightTrimRate_double(self.as_raw_mut_Boost(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_Boost_setWeightTrimRate_double(self.as_raw_mut_Boost(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -942,7 +986,7 @@ impl dyn Boost + '_ {
 	#[inline]
 	pub fn create() -> Result<core::Ptr<dyn crate::ml::Boost>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_Boost_create(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_Boost_create(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::ml::Boost>::opencv_from_extern(ret) };
@@ -966,7 +1010,7 @@ impl dyn Boost + '_ {
 		extern_container_arg!(filepath);
 		extern_container_arg!(node_name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_Boost_load_const_StringR_const_StringR(filepath.opencv_as_extern(), node_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_Boost_load_const_StringR_const_StringR(filepath.opencv_as_extern(), node_name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::ml::Boost>::opencv_from_extern(ret) };
@@ -981,7 +1025,7 @@ impl dyn Boost + '_ {
 /// responses or using a voting schemes), and the derived from DTrees classes (such as RTrees and Boost)
 /// use this capability to implement decision tree ensembles.
 /// ## See also
-/// @ref ml_intro_trees
+/// ml_intro_trees
 pub trait DTreesConst: crate::ml::StatModelConst {
 	fn as_raw_DTrees(&self) -> *const c_void;
 
@@ -997,11 +1041,12 @@ pub trait DTreesConst: crate::ml::StatModelConst {
 	///    efficiently without employing clustering, thus the parameter is not used in these cases.
 	///    Default value is 10.
 	/// ## See also
-	/// setMaxCategories
+	/// `set_max_categories`
 	#[inline]
 	fn get_max_categories(&self) -> Result<i32> {
+		crate::core::validity::check("DTrees", self.as_raw_DTrees())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_DTrees_getMaxCategories_const(self.as_raw_DTrees(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_DTrees_getMaxCategories_const(self.as_raw_DTrees(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1010,14 +1055,15 @@ pub trait DTreesConst: crate::ml::StatModelConst {
 	/// The maximum possible depth of the tree.
 	///    That is the training algorithms attempts to split a node while its depth is less than maxDepth.
 	///    The root node has zero depth. The actual depth may be smaller if the other termination criteria
-	///    are met (see the outline of the training procedure @ref ml_intro_trees "here"), and/or if the
+	///    are met (see the outline of the training procedure here), and/or if the
 	///    tree is pruned. Default value is INT_MAX.
 	/// ## See also
-	/// setMaxDepth
+	/// `set_max_depth`
 	#[inline]
 	fn get_max_depth(&self) -> Result<i32> {
+		crate::core::validity::check("DTrees", self.as_raw_DTrees())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_DTrees_getMaxDepth_const(self.as_raw_DTrees(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_DTrees_getMaxDepth_const(self.as_raw_DTrees(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1027,11 +1073,12 @@ pub trait DTreesConst: crate::ml::StatModelConst {
 	/// 
 	///    Default value is 10.
 	/// ## See also
-	/// setMinSampleCount
+	/// `set_min_sample_count`
 	#[inline]
 	fn get_min_sample_count(&self) -> Result<i32> {
+		crate::core::validity::check("DTrees", self.as_raw_DTrees())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_DTrees_getMinSampleCount_const(self.as_raw_DTrees(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_DTrees_getMinSampleCount_const(self.as_raw_DTrees(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1041,11 +1088,12 @@ pub trait DTreesConst: crate::ml::StatModelConst {
 	///    cross-validation procedure where K is equal to CVFolds.
 	///    Default value is 10.
 	/// ## See also
-	/// setCVFolds
+	/// `set_cv_folds`
 	#[inline]
 	fn get_cv_folds(&self) -> Result<i32> {
+		crate::core::validity::check("DTrees", self.as_raw_DTrees())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_DTrees_getCVFolds_const(self.as_raw_DTrees(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_DTrees_getCVFolds_const(self.as_raw_DTrees(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1057,11 +1105,12 @@ pub trait DTreesConst: crate::ml::StatModelConst {
 	///     
 	/// Note: currently it's not implemented.
 	/// ## See also
-	/// setUseSurrogates
+	/// `set_use_surrogates`
 	#[inline]
 	fn get_use_surrogates(&self) -> Result<bool> {
+		crate::core::validity::check("DTrees", self.as_raw_DTrees())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_DTrees_getUseSurrogates_const(self.as_raw_DTrees(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_DTrees_getUseSurrogates_const(self.as_raw_DTrees(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1071,11 +1120,12 @@ pub trait DTreesConst: crate::ml::StatModelConst {
 	///    This will make a tree more compact and more resistant to the training data noise but a bit less
 	///    accurate. Default value is true.
 	/// ## See also
-	/// setUse1SERule
+	/// `set_use1_se_rule`
 	#[inline]
 	fn get_use1_se_rule(&self) -> Result<bool> {
+		crate::core::validity::check("DTrees", self.as_raw_DTrees())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_DTrees_getUse1SERule_const(self.as_raw_DTrees(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_DTrees_getUse1SERule_const(self.as_raw_DTrees(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1085,11 +1135,12 @@ pub trait DTreesConst: crate::ml::StatModelConst {
 	///    Otherwise they are retained and it is possible to get results from the original unpruned (or
 	///    pruned less aggressively) tree. Default value is true.
 	/// ## See also
-	/// setTruncatePrunedTree
+	/// `set_truncate_pruned_tree`
 	#[inline]
 	fn get_truncate_pruned_tree(&self) -> Result<bool> {
+		crate::core::validity::check("DTrees", self.as_raw_DTrees())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_DTrees_getTruncatePrunedTree_const(self.as_raw_DTrees(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_DTrees_getTruncatePrunedTree_const(self.as_raw_DTrees(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1100,11 +1151,12 @@ pub trait DTreesConst: crate::ml::StatModelConst {
 	///    in this node are less than this parameter then the node will not be split further. Default
 	///    value is 0.01f
 	/// ## See also
-	/// setRegressionAccuracy
+	/// `set_regression_accuracy`
 	#[inline]
 	fn get_regression_accuracy(&self) -> Result<f32> {
+		crate::core::validity::check("DTrees", self.as_raw_DTrees())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_DTrees_getRegressionAccuracy_const(self.as_raw_DTrees(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_DTrees_getRegressionAccuracy_const(self.as_raw_DTrees(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1126,11 +1178,12 @@ pub trait DTreesConst: crate::ml::StatModelConst {
 	///    the second category is equivalent to making 10 mistakes in predicting the first category.
 	///    Default value is empty Mat.
 	/// ## See also
-	/// setPriors
+	/// `set_priors`
 	#[inline]
 	fn get_priors(&self) -> Result<core::Mat> {
+		crate::core::validity::check("DTrees", self.as_raw_DTrees())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_DTrees_getPriors_const(self.as_raw_DTrees(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_DTrees_getPriors_const(self.as_raw_DTrees(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -1140,8 +1193,9 @@ pub trait DTreesConst: crate::ml::StatModelConst {
 	/// Returns indices of root nodes
 	#[inline]
 	fn get_roots(&self) -> Result<core::Vector<i32>> {
+		crate::core::validity::check("DTrees", self.as_raw_DTrees())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_DTrees_getRoots_const(self.as_raw_DTrees(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_DTrees_getRoots_const(self.as_raw_DTrees(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<i32>::opencv_from_extern(ret) };
@@ -1153,8 +1207,9 @@ pub trait DTreesConst: crate::ml::StatModelConst {
 	/// all the node indices are indices in the returned vector
 	#[inline]
 	fn get_nodes(&self) -> Result<core::Vector<crate::ml::DTrees_Node>> {
+		crate::core::validity::check("DTrees", self.as_raw_DTrees())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_DTrees_getNodes_const(self.as_raw_DTrees(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_DTrees_getNodes_const(self.as_raw_DTrees(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<crate::ml::DTrees_Node>::opencv_from_extern(ret) };
@@ -1166,8 +1221,9 @@ pub trait DTreesConst: crate::ml::StatModelConst {
 	/// all the split indices are indices in the returned vector
 	#[inline]
 	fn get_splits(&self) -> Result<core::Vector<crate::ml::DTrees_Split>> {
+		crate::core::validity::check("DTrees", self.as_raw_DTrees())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_DTrees_getSplits_const(self.as_raw_DTrees(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_DTrees_getSplits_const(self.as_raw_DTrees(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<crate::ml::DTrees_Split>::opencv_from_extern(ret) };
@@ -1179,8 +1235,9 @@ pub trait DTreesConst: crate::ml::StatModelConst {
 	/// Split::subsetOfs is an offset in the returned vector
 	#[inline]
 	fn get_subsets(&self) -> Result<core::Vector<i32>> {
+		crate::core::validity::check("DTrees", self.as_raw_DTrees())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_DTrees_getSubsets_const(self.as_raw_DTrees(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_DTrees_getSubsets_const(self.as_raw_DTrees(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<i32>::opencv_from_extern(ret) };
@@ -1207,8 +1264,9 @@ pub trait DTrees: crate::ml::DTreesConst + crate::ml::StatModel {
 	/// setMaxCategories getMaxCategories
 	#[inline]
 	fn set_max_categories(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("DTrees", self.as_raw_mut_DTrees())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_DTrees_setMaxCategories_int(self.as_raw_mut_DTrees(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_DTrees_setMaxCategories_int(self.as_raw_mut_DTrees(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1217,14 +1275,15 @@ pub trait DTrees: crate::ml::DTreesConst + crate::ml::StatModel {
 	/// The maximum possible depth of the tree.
 	///    That is the training algorithms attempts to split a node while its depth is less than maxDepth.
 	///    The root node has zero depth. The actual depth may be smaller if the other termination criteria
-	///    are met (see the outline of the training procedure @ref ml_intro_trees "here"), and/or if the
+	///    are met (see the outline of the training procedure here), and/or if the
 	///    tree is pruned. Default value is INT_MAX.
 	/// ## See also
 	/// setMaxDepth getMaxDepth
 	#[inline]
 	fn set_max_depth(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("DTrees", self.as_raw_mut_DTrees())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_DTrees_setMaxDepth_int(self.as_raw_mut_DTrees(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_DTrees_setMaxDepth_int(self.as_raw_mut_DTrees(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1237,8 +1296,9 @@ pub trait DTrees: crate::ml::DTreesConst + crate::ml::StatModel {
 	/// setMinSampleCount getMinSampleCount
 	#[inline]
 	fn set_min_sample_count(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("DTrees", self.as_raw_mut_DTrees())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_DTrees_setMinSampleCount_int(self.as_raw_mut_DTrees(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_DTrees_setMinSampleCount_int(self.as_raw_mut_DTrees(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1251,8 +1311,9 @@ pub trait DTrees: crate::ml::DTreesConst + crate::ml::StatModel {
 	/// setCVFolds getCVFolds
 	#[inline]
 	fn set_cv_folds(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("DTrees", self.as_raw_mut_DTrees())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_DTrees_setCVFolds_int(self.as_raw_mut_DTrees(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_DTrees_setCVFolds_int(self.as_raw_mut_DTrees(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1267,8 +1328,9 @@ pub trait DTrees: crate::ml::DTreesConst + crate::ml::StatModel {
 	/// setUseSurrogates getUseSurrogates
 	#[inline]
 	fn set_use_surrogates(&mut self, val: bool) -> Result<()> {
+		crate::core::validity::check("DTrees", self.as_raw_mut_DTrees())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_DTrees_setUseSurrogates_bool(self.as_raw_mut_DTrees(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_DTrees_setUseSurrogates_bool(self.as_raw_mut_DTrees(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1281,8 +1343,9 @@ pub trait DTrees: crate::ml::DTreesConst + crate::ml::StatModel {
 	/// setUse1SERule getUse1SERule
 	#[inline]
 	fn set_use1_se_rule(&mut self, val: bool) -> Result<()> {
+		crate::core::validity::check("DTrees", self.as_raw_mut_DTrees())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_DTrees_setUse1SERule_bool(self.as_raw_mut_DTrees(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_DTrees_setUse1SERule_bool(self.as_raw_mut_DTrees(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1295,8 +1358,9 @@ pub trait DTrees: crate::ml::DTreesConst + crate::ml::StatModel {
 	/// setTruncatePrunedTree getTruncatePrunedTree
 	#[inline]
 	fn set_truncate_pruned_tree(&mut self, val: bool) -> Result<()> {
+		crate::core::validity::check("DTrees", self.as_raw_mut_DTrees())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_DTrees_setTruncatePrunedTree_bool(self.as_raw_mut_DTrees(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_DTrees_setTruncatePrunedTree_bool(self.as_raw_mut_DTrees(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1310,8 +1374,9 @@ pub trait DTrees: crate::ml::DTreesConst + crate::ml::StatModel {
 	/// setRegressionAccuracy getRegressionAccuracy
 	#[inline]
 	fn set_regression_accuracy(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("DTrees", self.as_raw_mut_DTrees())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_DTrees_setRegressionAccuracy_float(self.as_raw_mut_DTrees(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_DTrees_setRegressionAccuracy_float(self.as_raw_mut_DTrees(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1336,8 +1401,9 @@ pub trait DTrees: crate::ml::DTreesConst + crate::ml::StatModel {
 	/// setPriors getPriors
 	#[inline]
 	fn set_priors(&mut self, val: &core::Mat) -> Result<()> {
+		crate::core::validity::check("DTrees", self.as_raw_mut_DTrees())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_DTrees_setPriors_const_MatR(self.as_raw_mut_DTrees(), val.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_DTrees_setPriors_const_MatR(self.as_raw_mut_DTrees(), val.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1354,7 +1420,7 @@ impl dyn DTrees + '_ {
 	#[inline]
 	pub fn create() -> Result<core::Ptr<dyn crate::ml::DTrees>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_DTrees_create(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_DTrees_create(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::ml::DTrees>::opencv_from_extern(ret) };
@@ -1378,7 +1444,7 @@ impl dyn DTrees + '_ {
 		extern_container_arg!(filepath);
 		extern_container_arg!(node_name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_DTrees_load_const_StringR_const_StringR(filepath.opencv_as_extern(), node_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_DTrees_load_const_StringR_const_StringR(filepath.opencv_as_extern(), node_name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::ml::DTrees>::opencv_from_extern(ret) };
@@ -1394,54 +1460,117 @@ pub trait DTrees_NodeTraitConst {
 	/// function value in case of regression.
 	#[inline]
 	fn value(&self) -> f64 {
-		let ret = unsafe { sys::cv_ml_DTrees_Node_getPropValue_const(self.as_raw_DTrees_Node()) };
+		crate::core::validity::debug_check("DTrees_Node", self.as_raw_DTrees_Node());
+		let ret = unsafe { ffi_call!(cv_ml_DTrees_Node_getPropValue_const(self.as_raw_DTrees_Node())) };
 		ret
 	}
 	
+	/// Non-panicking version of [value](DTrees_NodeTraitConst::value), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_value(&self) -> Result<f64> {
+		crate::core::validity::check("DTrees_Node", self.as_raw_DTrees_Node())?;
+		Ok(DTrees_NodeTraitConst::value(self))
+	}
+	
 	/// Class index normalized to 0..class_count-1 range and assigned to the
 	/// node. It is used internally in classification trees and tree ensembles.
 	#[inline]
 	fn class_idx(&self) -> i32 {
-		let ret = unsafe { sys::cv_ml_DTrees_Node_getPropClassIdx_const(self.as_raw_DTrees_Node()) };
+		crate::core::validity::debug_check("DTrees_Node", self.as_raw_DTrees_Node());
+		let ret = unsafe { ffi_call!(cv_ml_DTrees_Node_getPropClassIdx_const(self.as_raw_DTrees_Node())) };
 		ret
 	}
 	
+	/// Non-panicking version of [class_idx](DTrees_NodeTraitConst::class_idx), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_class_idx(&self) -> Result<i32> {
+		crate::core::validity::check("DTrees_Node", self.as_raw_DTrees_Node())?;
+		Ok(DTrees_NodeTraitConst::class_idx(self))
+	}
+	
 	/// Index of the parent node
 	#[inline]
 	fn parent(&self) -> i32 {
-		let ret = unsafe { sys::cv_ml_DTrees_Node_getPropParent_const(self.as_raw_DTrees_Node()) };
+		crate::core::validity::debug_check("DTrees_Node", self.as_raw_DTrees_Node());
+		let ret = unsafe { ffi_call!(cv_ml_DTrees_Node_getPropParent_const(self.as_raw_DTrees_Node())) };
 		ret
 	}
 	
+	/// Non-panicking version of [parent](DTrees_NodeTraitConst::parent), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_parent(&self) -> Result<i32> {
+		crate::core::validity::check("DTrees_Node", self.as_raw_DTrees_Node())?;
+		Ok(DTrees_NodeTraitConst::parent(self))
+	}
+	
 	/// Index of the left child node
 	#[inline]
 	fn left(&self) -> i32 {
-		let ret = unsafe { sys::cv_ml_DTrees_Node_getPropLeft_const(self.as_raw_DTrees_Node()) };
+		crate::core::validity::debug_check("DTrees_Node", self.as_raw_DTrees_Node());
+		let ret = unsafe { ffi_call!(cv_ml_DTrees_Node_getPropLeft_const(self.as_raw_DTrees_Node())) };
 		ret
 	}
 	
+	/// Non-panicking version of [left](DTrees_NodeTraitConst::left), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_left(&self) -> Result<i32> {
+		crate::core::validity::check("DTrees_Node", self.as_raw_DTrees_Node())?;
+		Ok(DTrees_NodeTraitConst::left(self))
+	}
+	
 	/// Index of right child node
 	#[inline]
 	fn right(&self) -> i32 {
-		let ret = unsafe { sys::cv_ml_DTrees_Node_getPropRight_const(self.as_raw_DTrees_Node()) };
+		crate::core::validity::debug_check("DTrees_Node", self.as_raw_DTrees_Node());
+		let ret = unsafe { ffi_call!(cv_ml_DTrees_Node_getPropRight_const(self.as_raw_DTrees_Node())) };
 		ret
 	}
 	
+	/// Non-panicking version of [right](DTrees_NodeTraitConst::right), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_right(&self) -> Result<i32> {
+		crate::core::validity::check("DTrees_Node", self.as_raw_DTrees_Node())?;
+		Ok(DTrees_NodeTraitConst::right(self))
+	}
+	
 	/// Default direction where to go (-1: left or +1: right). It helps in the
 	/// case of missing values.
 	#[inline]
 	fn default_dir(&self) -> i32 {
-		let ret = unsafe { sys::cv_ml_DTrees_Node_getPropDefaultDir_const(self.as_raw_DTrees_Node()) };
+		crate::core::validity::debug_check("DTrees_Node", self.as_raw_DTrees_Node());
+		let ret = unsafe { ffi_call!(cv_ml_DTrees_Node_getPropDefaultDir_const(self.as_raw_DTrees_Node())) };
 		ret
 	}
 	
+	/// Non-panicking version of [default_dir](DTrees_NodeTraitConst::default_dir), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_default_dir(&self) -> Result<i32> {
+		crate::core::validity::check("DTrees_Node", self.as_raw_DTrees_Node())?;
+		Ok(DTrees_NodeTraitConst::default_dir(self))
+	}
+	
 	/// Index of the first split
 	#[inline]
 	fn split(&self) -> i32 {
-		let ret = unsafe { sys::cv_ml_DTrees_Node_getPropSplit_const(self.as_raw_DTrees_Node()) };
+		crate::core::validity::debug_check("DTrees_Node", self.as_raw_DTrees_Node());
+		let ret = unsafe { ffi_call!(cv_ml_DTrees_Node_getPropSplit_const(self.as_raw_DTrees_Node())) };
 		ret
 	}
 	
+	/// Non-panicking version of [split](DTrees_NodeTraitConst::split), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_split(&self) -> Result<i32> {
+		crate::core::validity::check("DTrees_Node", self.as_raw_DTrees_Node())?;
+		Ok(DTrees_NodeTraitConst::split(self))
+	}
+	
 }
 
 pub trait DTrees_NodeTrait: crate::ml::DTrees_NodeTraitConst {
@@ -1451,7 +1580,8 @@ pub trait DTrees_NodeTrait: crate::ml::DTrees_NodeTraitConst {
 	/// function value in case of regression.
 	#[inline]
 	fn set_value(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_ml_DTrees_Node_setPropValue_double(self.as_raw_mut_DTrees_Node(), val) };
+		crate::core::validity::debug_check("DTrees_Node", self.as_raw_mut_DTrees_Node());
+		let ret = unsafe { ffi_call!(cv_ml_DTrees_Node_setPropValue_double(self.as_raw_mut_DTrees_Node(), val)) };
 		ret
 	}
 	
@@ -1459,28 +1589,32 @@ pub trait DTrees_NodeTrait: crate::ml::DTrees_NodeTraitConst {
 	/// node. It is used internally in classification trees and tree ensembles.
 	#[inline]
 	fn set_class_idx(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_ml_DTrees_Node_setPropClassIdx_int(self.as_raw_mut_DTrees_Node(), val) };
+		crate::core::validity::debug_check("DTrees_Node", self.as_raw_mut_DTrees_Node());
+		let ret = unsafe { ffi_call!(cv_ml_DTrees_Node_setPropClassIdx_int(self.as_raw_mut_DTrees_Node(), val)) };
 		ret
 	}
 	
 	/// Index of the parent node
 	#[inline]
 	fn set_parent(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_ml_DTrees_Node_setPropParent_int(self.as_raw_mut_DTrees_Node(), val) };
+		crate::core::validity::debug_check("DTrees_Node", self.as_raw_mut_DTrees_Node());
+		let ret = unsafe { ffi_call!(cv_ml_DTrees_Node_setPropParent_int(self.as_raw_mut_DTrees_Node(), val)) };
 		ret
 	}
 	
 	/// Index of the left child node
 	#[inline]
 	fn set_left(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_ml_DTrees_Node_setPropLeft_int(self.as_raw_mut_DTrees_Node(), val) };
+		crate::core::validity::debug_check("DTrees_Node", self.as_raw_mut_DTrees_Node());
+		let ret = unsafe { ffi_call!(cv_ml_DTrees_Node_setPropLeft_int(self.as_raw_mut_DTrees_Node(), val)) };
 		ret
 	}
 	
 	/// Index of right child node
 	#[inline]
 	fn set_right(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_ml_DTrees_Node_setPropRight_int(self.as_raw_mut_DTrees_Node(), val) };
+		crate::core::validity::debug_check("DTrees_Node", self.as_raw_mut_DTrees_Node());
+		let ret = unsafe { ffi_call!(cv_ml_DTrees_Node_setPropRight_int(self.as_raw_mut_DTrees_Node(), val)) };
 		ret
 	}
 	
@@ -1488,14 +1622,16 @@ pub trait DTrees_NodeTrait: crate::ml::DTrees_NodeTraitConst {
 	/// case of missing values.
 	#[inline]
 	fn set_default_dir(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_ml_DTrees_Node_setPropDefaultDir_int(self.as_raw_mut_DTrees_Node(), val) };
+		crate::core::validity::debug_check("DTrees_Node", self.as_raw_mut_DTrees_Node());
+		let ret = unsafe { ffi_call!(cv_ml_DTrees_Node_setPropDefaultDir_int(self.as_raw_mut_DTrees_Node(), val)) };
 		ret
 	}
 	
 	/// Index of the first split
 	#[inline]
 	fn set_split(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_ml_DTrees_Node_setPropSplit_int(self.as_raw_mut_DTrees_Node(), val) };
+		crate::core::validity::debug_check("DTrees_Node", self.as_raw_mut_DTrees_Node());
+		let ret = unsafe { ffi_call!(cv_ml_DTrees_Node_setPropSplit_int(self.as_raw_mut_DTrees_Node(), val)) };
 		ret
 	}
 	
@@ -1511,7 +1647,10 @@ opencv_type_boxed! { DTrees_Node }
 impl Drop for DTrees_Node {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DTrees_Node_delete(instance: *mut c_void); }
-		unsafe { cv_DTrees_Node_delete(self.as_raw_mut_DTrees_Node()) };
+		let ptr = self.as_raw_mut_DTrees_Node();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_DTrees_Node_delete(ptr) };
 	}
 }
 
@@ -1529,7 +1668,7 @@ impl DTrees_Node {
 	#[inline]
 	pub fn default() -> Result<crate::ml::DTrees_Node> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_DTrees_Node_Node(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_DTrees_Node_Node(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::ml::DTrees_Node::opencv_from_extern(ret) };
@@ -1545,32 +1684,68 @@ pub trait DTrees_SplitTraitConst {
 	/// Index of variable on which the split is created.
 	#[inline]
 	fn var_idx(&self) -> i32 {
-		let ret = unsafe { sys::cv_ml_DTrees_Split_getPropVarIdx_const(self.as_raw_DTrees_Split()) };
+		crate::core::validity::debug_check("DTrees_Split", self.as_raw_DTrees_Split());
+		let ret = unsafe { ffi_call!(cv_ml_DTrees_Split_getPropVarIdx_const(self.as_raw_DTrees_Split())) };
 		ret
 	}
 	
+	/// Non-panicking version of [var_idx](DTrees_SplitTraitConst::var_idx), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_var_idx(&self) -> Result<i32> {
+		crate::core::validity::check("DTrees_Split", self.as_raw_DTrees_Split())?;
+		Ok(DTrees_SplitTraitConst::var_idx(self))
+	}
+	
 	/// If true, then the inverse split rule is used (i.e. left and right
 	/// branches are exchanged in the rule expressions below).
 	#[inline]
 	fn inversed(&self) -> bool {
-		let ret = unsafe { sys::cv_ml_DTrees_Split_getPropInversed_const(self.as_raw_DTrees_Split()) };
+		crate::core::validity::debug_check("DTrees_Split", self.as_raw_DTrees_Split());
+		let ret = unsafe { ffi_call!(cv_ml_DTrees_Split_getPropInversed_const(self.as_raw_DTrees_Split())) };
 		ret
 	}
 	
+	/// Non-panicking version of [inversed](DTrees_SplitTraitConst::inversed), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_inversed(&self) -> Result<bool> {
+		crate::core::validity::check("DTrees_Split", self.as_raw_DTrees_Split())?;
+		Ok(DTrees_SplitTraitConst::inversed(self))
+	}
+	
 	/// The split quality, a positive number. It is used to choose the best split.
 	#[inline]
 	fn quality(&self) -> f32 {
-		let ret = unsafe { sys::cv_ml_DTrees_Split_getPropQuality_const(self.as_raw_DTrees_Split()) };
+		crate::core::validity::debug_check("DTrees_Split", self.as_raw_DTrees_Split());
+		let ret = unsafe { ffi_call!(cv_ml_DTrees_Split_getPropQuality_const(self.as_raw_DTrees_Split())) };
 		ret
 	}
 	
+	/// Non-panicking version of [quality](DTrees_SplitTraitConst::quality), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_quality(&self) -> Result<f32> {
+		crate::core::validity::check("DTrees_Split", self.as_raw_DTrees_Split())?;
+		Ok(DTrees_SplitTraitConst::quality(self))
+	}
+	
 	/// Index of the next split in the list of splits for the node
 	#[inline]
 	fn next(&self) -> i32 {
-		let ret = unsafe { sys::cv_ml_DTrees_Split_getPropNext_const(self.as_raw_DTrees_Split()) };
+		crate::core::validity::debug_check("DTrees_Split", self.as_raw_DTrees_Split());
+		let ret = unsafe { ffi_call!(cv_ml_DTrees_Split_getPropNext_const(self.as_raw_DTrees_Split())) };
 		ret
 	}
 	
+	/// Non-panicking version of [next](DTrees_SplitTraitConst::next), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_next(&self) -> Result<i32> {
+		crate::core::validity::check("DTrees_Split", self.as_raw_DTrees_Split())?;
+		Ok(DTrees_SplitTraitConst::next(self))
+	}
+	
 	/// < The threshold value in case of split on an ordered variable.
 	/// The rule is:
 	/// ```ignore
@@ -1581,10 +1756,19 @@ pub trait DTrees_SplitTraitConst {
 	/// 
 	#[inline]
 	fn c(&self) -> f32 {
-		let ret = unsafe { sys::cv_ml_DTrees_Split_getPropC_const(self.as_raw_DTrees_Split()) };
+		crate::core::validity::debug_check("DTrees_Split", self.as_raw_DTrees_Split());
+		let ret = unsafe { ffi_call!(cv_ml_DTrees_Split_getPropC_const(self.as_raw_DTrees_Split())) };
 		ret
 	}
 	
+	/// Non-panicking version of [c](DTrees_SplitTraitConst::c), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_c(&self) -> Result<f32> {
+		crate::core::validity::check("DTrees_Split", self.as_raw_DTrees_Split())?;
+		Ok(DTrees_SplitTraitConst::c(self))
+	}
+	
 	/// < Offset of the bitset used by the split on a categorical variable.
 	/// The rule is:
 	/// ```ignore
@@ -1595,10 +1779,19 @@ pub trait DTrees_SplitTraitConst {
 	/// 
 	#[inline]
 	fn subset_ofs(&self) -> i32 {
-		let ret = unsafe { sys::cv_ml_DTrees_Split_getPropSubsetOfs_const(self.as_raw_DTrees_Split()) };
+		crate::core::validity::debug_check("DTrees_Split", self.as_raw_DTrees_Split());
+		let ret = unsafe { ffi_call!(cv_ml_DTrees_Split_getPropSubsetOfs_const(self.as_raw_DTrees_Split())) };
 		ret
 	}
 	
+	/// Non-panicking version of [subset_ofs](DTrees_SplitTraitConst::subset_ofs), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_subset_ofs(&self) -> Result<i32> {
+		crate::core::validity::check("DTrees_Split", self.as_raw_DTrees_Split())?;
+		Ok(DTrees_SplitTraitConst::subset_ofs(self))
+	}
+	
 }
 
 pub trait DTrees_SplitTrait: crate::ml::DTrees_SplitTraitConst {
@@ -1607,7 +1800,8 @@ pub trait DTrees_SplitTrait: crate::ml::DTrees_SplitTraitConst {
 	/// Index of variable on which the split is created.
 	#[inline]
 	fn set_var_idx(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_ml_DTrees_Split_setPropVarIdx_int(self.as_raw_mut_DTrees_Split(), val) };
+		crate::core::validity::debug_check("DTrees_Split", self.as_raw_mut_DTrees_Split());
+		let ret = unsafe { ffi_call!(cv_ml_DTrees_Split_setPropVarIdx_int(self.as_raw_mut_DTrees_Split(), val)) };
 		ret
 	}
 	
@@ -1615,21 +1809,24 @@ pub trait DTrees_SplitTrait: crate::ml::DTrees_SplitTraitConst {
 	/// branches are exchanged in the rule expressions below).
 	#[inline]
 	fn set_inversed(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_ml_DTrees_Split_setPropInversed_bool(self.as_raw_mut_DTrees_Split(), val) };
+		crate::core::validity::debug_check("DTrees_Split", self.as_raw_mut_DTrees_Split());
+		let ret = unsafe { ffi_call!(cv_ml_DTrees_Split_setPropInversed_bool(self.as_raw_mut_DTrees_Split(), val)) };
 		ret
 	}
 	
 	/// The split quality, a positive number. It is used to choose the best split.
 	#[inline]
 	fn set_quality(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_ml_DTrees_Split_setPropQuality_float(self.as_raw_mut_DTrees_Split(), val) };
+		crate::core::validity::debug_check("DTrees_Split", self.as_raw_mut_DTrees_Split());
+		let ret = unsafe { ffi_call!(cv_ml_DTrees_Split_setPropQuality_float(self.as_raw_mut_DTrees_Split(), val)) };
 		ret
 	}
 	
 	/// Index of the next split in the list of splits for the node
 	#[inline]
 	fn set_next(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_ml_DTrees_Split_setPropNext_int(self.as_raw_mut_DTrees_Split(), val) };
+		crate::core::validity::debug_check("DTrees_Split", self.as_raw_mut_DTrees_Split());
+		let ret = unsafe { ffi_call!(cv_ml_DTrees_Split_setPropNext_int(self.as_raw_mut_DTrees_Split(), val)) };
 		ret
 	}
 	
@@ -1643,7 +1840,8 @@ pub trait DTrees_SplitTrait: crate::ml::DTrees_SplitTraitConst {
 	/// 
 	#[inline]
 	fn set_c(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_ml_DTrees_Split_setPropC_float(self.as_raw_mut_DTrees_Split(), val) };
+		crate::core::validity::debug_check("DTrees_Split", self.as_raw_mut_DTrees_Split());
+		let ret = unsafe { ffi_call!(cv_ml_DTrees_Split_setPropC_float(self.as_raw_mut_DTrees_Split(), val)) };
 		ret
 	}
 	
@@ -1657,7 +1855,8 @@ pub trait DTrees_SplitTrait: crate::ml::DTrees_SplitTraitConst {
 	/// 
 	#[inline]
 	fn set_subset_ofs(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_ml_DTrees_Split_setPropSubsetOfs_int(self.as_raw_mut_DTrees_Split(), val) };
+		crate::core::validity::debug_check("DTrees_Split", self.as_raw_mut_DTrees_Split());
+		let ret = unsafe { ffi_call!(cv_ml_DTrees_Split_setPropSubsetOfs_int(self.as_raw_mut_DTrees_Split(), val)) };
 		ret
 	}
 	
@@ -1673,7 +1872,10 @@ opencv_type_boxed! { DTrees_Split }
 impl Drop for DTrees_Split {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DTrees_Split_delete(instance: *mut c_void); }
-		unsafe { cv_DTrees_Split_delete(self.as_raw_mut_DTrees_Split()) };
+		let ptr = self.as_raw_mut_DTrees_Split();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_DTrees_Split_delete(ptr) };
 	}
 }
 
@@ -1691,7 +1893,7 @@ impl DTrees_Split {
 	#[inline]
 	pub fn default() -> Result<crate::ml::DTrees_Split> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_DTrees_Split_Split(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_DTrees_Split_Split(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::ml::DTrees_Split::opencv_from_extern(ret) };
@@ -1702,7 +1904,7 @@ impl DTrees_Split {
 
 /// The class implements the Expectation Maximization algorithm.
 /// ## See also
-/// @ref ml_intro_em
+/// ml_intro_em
 pub trait EMConst: crate::ml::StatModelConst {
 	fn as_raw_EM(&self) -> *const c_void;
 
@@ -1711,11 +1913,12 @@ pub trait EMConst: crate::ml::StatModelConst {
 	///    determine the optimal number of mixtures within a specified value range, but that is not the
 	///    case in ML yet.
 	/// ## See also
-	/// setClustersNumber
+	/// `set_clusters_number`
 	#[inline]
 	fn get_clusters_number(&self) -> Result<i32> {
+		crate::core::validity::check("EM", self.as_raw_EM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_EM_getClustersNumber_const(self.as_raw_EM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_EM_getClustersNumber_const(self.as_raw_EM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1724,11 +1927,12 @@ pub trait EMConst: crate::ml::StatModelConst {
 	/// Constraint on covariance matrices which defines type of matrices.
 	///    See EM::Types.
 	/// ## See also
-	/// setCovarianceMatrixType
+	/// `set_covariance_matrix_type`
 	#[inline]
 	fn get_covariance_matrix_type(&self) -> Result<i32> {
+		crate::core::validity::check("EM", self.as_raw_EM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_EM_getCovarianceMatrixType_const(self.as_raw_EM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_EM_getCovarianceMatrixType_const(self.as_raw_EM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1739,11 +1943,12 @@ pub trait EMConst: crate::ml::StatModelConst {
 	///    M-steps) or when relative change of likelihood logarithm is less than termCrit.epsilon. Default
 	///    maximum number of iterations is EM::DEFAULT_MAX_ITERS=100.
 	/// ## See also
-	/// setTermCriteria
+	/// `set_term_criteria`
 	#[inline]
 	fn get_term_criteria(&self) -> Result<core::TermCriteria> {
+		crate::core::validity::check("EM", self.as_raw_EM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_EM_getTermCriteria_const(self.as_raw_EM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_EM_getTermCriteria_const(self.as_raw_EM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1754,8 +1959,9 @@ pub trait EMConst: crate::ml::StatModelConst {
 	/// Returns vector with the number of elements equal to the number of mixtures.
 	#[inline]
 	fn get_weights(&self) -> Result<core::Mat> {
+		crate::core::validity::check("EM", self.as_raw_EM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_EM_getWeights_const(self.as_raw_EM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_EM_getWeights_const(self.as_raw_EM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -1768,8 +1974,9 @@ pub trait EMConst: crate::ml::StatModelConst {
 	/// equal to the space dimensionality.
 	#[inline]
 	fn get_means(&self) -> Result<core::Mat> {
+		crate::core::validity::check("EM", self.as_raw_EM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_EM_getMeans_const(self.as_raw_EM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_EM_getMeans_const(self.as_raw_EM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -1782,8 +1989,9 @@ pub trait EMConst: crate::ml::StatModelConst {
 	/// each matrix is a square floating-point matrix NxN, where N is the space dimensionality.
 	#[inline]
 	fn get_covs(&self, covs: &mut core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("EM", self.as_raw_EM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_EM_getCovs_const_vector_Mat_R(self.as_raw_EM(), covs.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_EM_getCovs_const_vector_Mat_R(self.as_raw_EM(), covs.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1802,10 +2010,11 @@ pub trait EMConst: crate::ml::StatModelConst {
 	/// * flags: 0
 	#[inline]
 	fn predict(&self, samples: &dyn core::ToInputArray, results: &mut dyn core::ToOutputArray, flags: i32) -> Result<f32> {
+		crate::core::validity::check("EM", self.as_raw_EM())?;
 		input_array_arg!(samples);
 		output_array_arg!(results);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_EM_predict_const_const__InputArrayR_const__OutputArrayR_int(self.as_raw_EM(), samples.as_raw__InputArray(), results.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_EM_predict_const_const__InputArrayR_const__OutputArrayR_int(self.as_raw_EM(), samples.as_raw__InputArray(), results.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1825,10 +2034,11 @@ pub trait EMConst: crate::ml::StatModelConst {
 	/// sample.
 	#[inline]
 	fn predict2(&self, sample: &dyn core::ToInputArray, probs: &mut dyn core::ToOutputArray) -> Result<core::Vec2d> {
+		crate::core::validity::check("EM", self.as_raw_EM())?;
 		input_array_arg!(sample);
 		output_array_arg!(probs);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_EM_predict2_const_const__InputArrayR_const__OutputArrayR(self.as_raw_EM(), sample.as_raw__InputArray(), probs.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_EM_predict2_const_const__InputArrayR_const__OutputArrayR(self.as_raw_EM(), sample.as_raw__InputArray(), probs.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1847,8 +2057,9 @@ pub trait EM: crate::ml::EMConst + crate::ml::StatModel {
 	/// setClustersNumber getClustersNumber
 	#[inline]
 	fn set_clusters_number(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("EM", self.as_raw_mut_EM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_EM_setClustersNumber_int(self.as_raw_mut_EM(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_EM_setClustersNumber_int(self.as_raw_mut_EM(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1860,8 +2071,9 @@ pub trait EM: crate::ml::EMConst + crate::ml::StatModel {
 	/// setCovarianceMatrixType getCovarianceMatrixType
 	#[inline]
 	fn set_covariance_matrix_type(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("EM", self.as_raw_mut_EM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_EM_setCovarianceMatrixType_int(self.as_raw_mut_EM(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_EM_setCovarianceMatrixType_int(self.as_raw_mut_EM(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1875,8 +2087,9 @@ pub trait EM: crate::ml::EMConst + crate::ml::StatModel {
 	/// setTermCriteria getTermCriteria
 	#[inline]
 	fn set_term_criteria(&mut self, val: core::TermCriteria) -> Result<()> {
+		crate::core::validity::check("EM", self.as_raw_mut_EM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_EM_setTermCriteria_const_TermCriteriaR(self.as_raw_mut_EM(), &val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_EM_setTermCriteria_const_TermCriteriaR(self.as_raw_mut_EM(), &val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1891,7 +2104,7 @@ pub trait EM: crate::ml::EMConst + crate::ml::StatModel {
 	/// responses (class labels or function values) as input. Instead, it computes the *Maximum
 	/// Likelihood Estimate* of the Gaussian mixture parameters from an input sample set, stores all the
 	/// parameters inside the structure: ![inline formula](https://latex.codecogs.com/png.latex?p%5F%7Bi%2Ck%7D) in probs, ![inline formula](https://latex.codecogs.com/png.latex?a%5Fk) in means , ![inline formula](https://latex.codecogs.com/png.latex?S%5Fk) in
-	/// covs[k], ![inline formula](https://latex.codecogs.com/png.latex?%5Cpi%5Fk) in weights , and optionally computes the output "class label" for each
+	/// covs\[k\], ![inline formula](https://latex.codecogs.com/png.latex?%5Cpi%5Fk) in weights , and optionally computes the output "class label" for each
 	/// sample: ![inline formula](https://latex.codecogs.com/png.latex?%5Ctexttt%7Blabels%7D%5Fi%3D%5Ctexttt%7Barg%20max%7D%5Fk%28p%5F%7Bi%2Ck%7D%29%2C%20i%3D1%2E%2EN) (indices of the most
 	/// probable mixture component for each sample).
 	/// 
@@ -1917,12 +2130,13 @@ pub trait EM: crate::ml::EMConst + crate::ml::StatModel {
 	/// * probs: noArray()
 	#[inline]
 	fn train_em(&mut self, samples: &dyn core::ToInputArray, log_likelihoods: &mut dyn core::ToOutputArray, labels: &mut dyn core::ToOutputArray, probs: &mut dyn core::ToOutputArray) -> Result<bool> {
+		crate::core::validity::check("EM", self.as_raw_mut_EM())?;
 		input_array_arg!(samples);
 		output_array_arg!(log_likelihoods);
 		output_array_arg!(labels);
 		output_array_arg!(probs);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_EM_trainEM_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_EM(), samples.as_raw__InputArray(), log_likelihoods.as_raw__OutputArray(), labels.as_raw__OutputArray(), probs.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_EM_trainEM_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_EM(), samples.as_raw__InputArray(), log_likelihoods.as_raw__OutputArray(), labels.as_raw__OutputArray(), probs.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1964,6 +2178,7 @@ pub trait EM: crate::ml::EMConst + crate::ml::StatModel {
 	/// * probs: noArray()
 	#[inline]
 	fn train_e(&mut self, samples: &dyn core::ToInputArray, means0: &dyn core::ToInputArray, covs0: &dyn core::ToInputArray, weights0: &dyn core::ToInputArray, log_likelihoods: &mut dyn core::ToOutputArray, labels: &mut dyn core::ToOutputArray, probs: &mut dyn core::ToOutputArray) -> Result<bool> {
+		crate::core::validity::check("EM", self.as_raw_mut_EM())?;
 		input_array_arg!(samples);
 		input_array_arg!(means0);
 		input_array_arg!(covs0);
@@ -1972,7 +2187,7 @@ pub trait EM: crate::ml::EMConst + crate::ml::StatModel {
 		output_array_arg!(labels);
 		output_array_arg!(probs);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_EM_trainE_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_EM(), samples.as_raw__InputArray(), means0.as_raw__InputArray(), covs0.as_raw__InputArray(), weights0.as_raw__InputArray(), log_likelihoods.as_raw__OutputArray(), labels.as_raw__OutputArray(), probs.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_EM_trainE_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_EM(), samples.as_raw__InputArray(), means0.as_raw__InputArray(), covs0.as_raw__InputArray(), weights0.as_raw__InputArray(), log_likelihoods.as_raw__OutputArray(), labels.as_raw__OutputArray(), probs.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2003,13 +2218,14 @@ pub trait EM: crate::ml::EMConst + crate::ml::StatModel {
 	/// * probs: noArray()
 	#[inline]
 	fn train_m(&mut self, samples: &dyn core::ToInputArray, probs0: &dyn core::ToInputArray, log_likelihoods: &mut dyn core::ToOutputArray, labels: &mut dyn core::ToOutputArray, probs: &mut dyn core::ToOutputArray) -> Result<bool> {
+		crate::core::validity::check("EM", self.as_raw_mut_EM())?;
 		input_array_arg!(samples);
 		input_array_arg!(probs0);
 		output_array_arg!(log_likelihoods);
 		output_array_arg!(labels);
 		output_array_arg!(probs);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_EM_trainM_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_EM(), samples.as_raw__InputArray(), probs0.as_raw__InputArray(), log_likelihoods.as_raw__OutputArray(), labels.as_raw__OutputArray(), probs.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_EM_trainM_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_EM(), samples.as_raw__InputArray(), probs0.as_raw__InputArray(), log_likelihoods.as_raw__OutputArray(), labels.as_raw__OutputArray(), probs.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2017,6 +2233,181 @@ pub trait EM: crate::ml::EMConst + crate::ml::StatModel {
 	
 }
 
+/// Builder for `EM::train_em()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct EM_TrainEmBuilder<'a, T: ?Sized + EM> {
+	instance: &'a mut T,
+	samples: &'a dyn core::ToInputArray,
+	log_likelihoods: Option<&'a mut dyn core::ToOutputArray>,
+	labels: Option<&'a mut dyn core::ToOutputArray>,
+	probs: Option<&'a mut dyn core::ToOutputArray>,
+}
+
+impl<'a, T: ?Sized + EM> EM_TrainEmBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, samples: &'a dyn core::ToInputArray) -> Self {
+		Self { instance, samples, log_likelihoods: None, labels: None, probs: None }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_log_likelihoods(mut self, log_likelihoods: &'a mut dyn core::ToOutputArray) -> Self {
+		self.log_likelihoods = Some(log_likelihoods);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_labels(mut self, labels: &'a mut dyn core::ToOutputArray) -> Self {
+		self.labels = Some(labels);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_probs(mut self, probs: &'a mut dyn core::ToOutputArray) -> Self {
+		self.probs = Some(probs);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<bool> {
+		let mut log_likelihoods_no_array = core::no_array();
+		let log_likelihoods: &mut dyn core::ToOutputArray = match self.log_likelihoods { Some(log_likelihoods) => log_likelihoods, None => &mut log_likelihoods_no_array };
+		let mut labels_no_array = core::no_array();
+		let labels: &mut dyn core::ToOutputArray = match self.labels { Some(labels) => labels, None => &mut labels_no_array };
+		let mut probs_no_array = core::no_array();
+		let probs: &mut dyn core::ToOutputArray = match self.probs { Some(probs) => probs, None => &mut probs_no_array };
+		EM::train_em(self.instance, self.samples, log_likelihoods, labels, probs)
+	}
+}
+
+/// Builder for `EM::train_e()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct EM_TrainEBuilder<'a, T: ?Sized + EM> {
+	instance: &'a mut T,
+	samples: &'a dyn core::ToInputArray,
+	means0: &'a dyn core::ToInputArray,
+	covs0: Option<&'a dyn core::ToInputArray>,
+	weights0: Option<&'a dyn core::ToInputArray>,
+	log_likelihoods: Option<&'a mut dyn core::ToOutputArray>,
+	labels: Option<&'a mut dyn core::ToOutputArray>,
+	probs: Option<&'a mut dyn core::ToOutputArray>,
+}
+
+impl<'a, T: ?Sized + EM> EM_TrainEBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, samples: &'a dyn core::ToInputArray, means0: &'a dyn core::ToInputArray) -> Self {
+		Self { instance, samples, means0, covs0: None, weights0: None, log_likelihoods: None, labels: None, probs: None }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_covs0(mut self, covs0: &'a dyn core::ToInputArray) -> Self {
+		self.covs0 = Some(covs0);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_weights0(mut self, weights0: &'a dyn core::ToInputArray) -> Self {
+		self.weights0 = Some(weights0);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_log_likelihoods(mut self, log_likelihoods: &'a mut dyn core::ToOutputArray) -> Self {
+		self.log_likelihoods = Some(log_likelihoods);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_labels(mut self, labels: &'a mut dyn core::ToOutputArray) -> Self {
+		self.labels = Some(labels);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_probs(mut self, probs: &'a mut dyn core::ToOutputArray) -> Self {
+		self.probs = Some(probs);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<bool> {
+		let covs0_no_array = core::no_array();
+		let covs0: &dyn core::ToInputArray = match self.covs0 { Some(covs0) => covs0, None => &covs0_no_array };
+		let weights0_no_array = core::no_array();
+		let weights0: &dyn core::ToInputArray = match self.weights0 { Some(weights0) => weights0, None => &weights0_no_array };
+		let mut log_likelihoods_no_array = core::no_array();
+		let log_likelihoods: &mut dyn core::ToOutputArray = match self.log_likelihoods { Some(log_likelihoods) => log_likelihoods, None => &mut log_likelihoods_no_array };
+		let mut labels_no_array = core::no_array();
+		let labels: &mut dyn core::ToOutputArray = match self.labels { Some(labels) => labels, None => &mut labels_no_array };
+		let mut probs_no_array = core::no_array();
+		let probs: &mut dyn core::ToOutputArray = match self.probs { Some(probs) => probs, None => &mut probs_no_array };
+		EM::train_e(self.instance, self.samples, self.means0, covs0, weights0, log_likelihoods, labels, probs)
+	}
+}
+
+/// Builder for `EM::train_m()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct EM_TrainMBuilder<'a, T: ?Sized + EM> {
+	instance: &'a mut T,
+	samples: &'a dyn core::ToInputArray,
+	probs0: &'a dyn core::ToInputArray,
+	log_likelihoods: Option<&'a mut dyn core::ToOutputArray>,
+	labels: Option<&'a mut dyn core::ToOutputArray>,
+	probs: Option<&'a mut dyn core::ToOutputArray>,
+}
+
+impl<'a, T: ?Sized + EM> EM_TrainMBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, samples: &'a dyn core::ToInputArray, probs0: &'a dyn core::ToInputArray) -> Self {
+		Self { instance, samples, probs0, log_likelihoods: None, labels: None, probs: None }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_log_likelihoods(mut self, log_likelihoods: &'a mut dyn core::ToOutputArray) -> Self {
+		self.log_likelihoods = Some(log_likelihoods);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_labels(mut self, labels: &'a mut dyn core::ToOutputArray) -> Self {
+		self.labels = Some(labels);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_probs(mut self, probs: &'a mut dyn core::ToOutputArray) -> Self {
+		self.probs = Some(probs);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<bool> {
+		let mut log_likelihoods_no_array = core::no_array();
+		let log_likelihoods: &mut dyn core::ToOutputArray = match self.log_likelihoods { Some(log_likelihoods) => log_likelihoods, None => &mut log_likelihoods_no_array };
+		let mut labels_no_array = core::no_array();
+		let labels: &mut dyn core::ToOutputArray = match self.labels { Some(labels) => labels, None => &mut labels_no_array };
+		let mut probs_no_array = core::no_array();
+		let probs: &mut dyn core::ToOutputArray = match self.probs { Some(probs) => probs, None => &mut probs_no_array };
+		EM::train_m(self.instance, self.samples, self.probs0, log_likelihoods, labels, probs)
+	}
+}
+
 impl dyn EM + '_ {
 	/// Creates empty %EM model.
 	/// The model should be trained then using StatModel::train(traindata, flags) method. Alternatively, you
@@ -2024,7 +2415,7 @@ impl dyn EM + '_ {
 	#[inline]
 	pub fn create() -> Result<core::Ptr<dyn crate::ml::EM>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_EM_create(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_EM_create(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::ml::EM>::opencv_from_extern(ret) };
@@ -2048,7 +2439,7 @@ impl dyn EM + '_ {
 		extern_container_arg!(filepath);
 		extern_container_arg!(node_name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_EM_load_const_StringR_const_StringR(filepath.opencv_as_extern(), node_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_EM_load_const_StringR_const_StringR(filepath.opencv_as_extern(), node_name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::ml::EM>::opencv_from_extern(ret) };
@@ -2058,17 +2449,18 @@ impl dyn EM + '_ {
 }
 /// The class implements K-Nearest Neighbors model
 /// ## See also
-/// @ref ml_intro_knn
+/// ml_intro_knn
 pub trait KNearestConst: crate::ml::StatModelConst {
 	fn as_raw_KNearest(&self) -> *const c_void;
 
 	/// Default number of neighbors to use in predict method.
 	/// ## See also
-	/// setDefaultK
+	/// `set_default_k`
 	#[inline]
 	fn get_default_k(&self) -> Result<i32> {
+		crate::core::validity::check("KNearest", self.as_raw_KNearest())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_KNearest_getDefaultK_const(self.as_raw_KNearest(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_KNearest_getDefaultK_const(self.as_raw_KNearest(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2076,11 +2468,12 @@ pub trait KNearestConst: crate::ml::StatModelConst {
 	
 	/// Whether classification or regression model should be trained.
 	/// ## See also
-	/// setIsClassifier
+	/// `set_is_classifier`
 	#[inline]
 	fn get_is_classifier(&self) -> Result<bool> {
+		crate::core::validity::check("KNearest", self.as_raw_KNearest())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_KNearest_getIsClassifier_const(self.as_raw_KNearest(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_KNearest_getIsClassifier_const(self.as_raw_KNearest(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2088,11 +2481,12 @@ pub trait KNearestConst: crate::ml::StatModelConst {
 	
 	/// Parameter for KDTree implementation.
 	/// ## See also
-	/// setEmax
+	/// `set_emax`
 	#[inline]
 	fn get_emax(&self) -> Result<i32> {
+		crate::core::validity::check("KNearest", self.as_raw_KNearest())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_KNearest_getEmax_const(self.as_raw_KNearest(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_KNearest_getEmax_const(self.as_raw_KNearest(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2100,11 +2494,12 @@ pub trait KNearestConst: crate::ml::StatModelConst {
 	
 	/// %Algorithm type, one of KNearest::Types.
 	/// ## See also
-	/// setAlgorithmType
+	/// `set_algorithm_type`
 	#[inline]
 	fn get_algorithm_type(&self) -> Result<i32> {
+		crate::core::validity::check("KNearest", self.as_raw_KNearest())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_KNearest_getAlgorithmType_const(self.as_raw_KNearest(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_KNearest_getAlgorithmType_const(self.as_raw_KNearest(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2142,12 +2537,13 @@ pub trait KNearestConst: crate::ml::StatModelConst {
 	/// * dist: noArray()
 	#[inline]
 	fn find_nearest(&self, samples: &dyn core::ToInputArray, k: i32, results: &mut dyn core::ToOutputArray, neighbor_responses: &mut dyn core::ToOutputArray, dist: &mut dyn core::ToOutputArray) -> Result<f32> {
+		crate::core::validity::check("KNearest", self.as_raw_KNearest())?;
 		input_array_arg!(samples);
 		output_array_arg!(results);
 		output_array_arg!(neighbor_responses);
 		output_array_arg!(dist);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_KNearest_findNearest_const_const__InputArrayR_int_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_KNearest(), samples.as_raw__InputArray(), k, results.as_raw__OutputArray(), neighbor_responses.as_raw__OutputArray(), dist.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_KNearest_findNearest_const_const__InputArrayR_int_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_KNearest(), samples.as_raw__InputArray(), k, results.as_raw__OutputArray(), neighbor_responses.as_raw__OutputArray(), dist.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2163,8 +2559,9 @@ pub trait KNearest: crate::ml::KNearestConst + crate::ml::StatModel {
 	/// setDefaultK getDefaultK
 	#[inline]
 	fn set_default_k(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("KNearest", self.as_raw_mut_KNearest())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_KNearest_setDefaultK_int(self.as_raw_mut_KNearest(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_KNearest_setDefaultK_int(self.as_raw_mut_KNearest(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2175,8 +2572,9 @@ pub trait KNearest: crate::ml::KNearestConst + crate::ml::StatModel {
 	/// setIsClassifier getIsClassifier
 	#[inline]
 	fn set_is_classifier(&mut self, val: bool) -> Result<()> {
+		crate::core::validity::check("KNearest", self.as_raw_mut_KNearest())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_KNearest_setIsClassifier_bool(self.as_raw_mut_KNearest(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_KNearest_setIsClassifier_bool(self.as_raw_mut_KNearest(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2187,8 +2585,9 @@ pub trait KNearest: crate::ml::KNearestConst + crate::ml::StatModel {
 	/// setEmax getEmax
 	#[inline]
 	fn set_emax(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("KNearest", self.as_raw_mut_KNearest())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_KNearest_setEmax_int(self.as_raw_mut_KNearest(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_KNearest_setEmax_int(self.as_raw_mut_KNearest(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2199,8 +2598,9 @@ pub trait KNearest: crate::ml::KNearestConst + crate::ml::StatModel {
 	/// setAlgorithmType getAlgorithmType
 	#[inline]
 	fn set_algorithm_type(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("KNearest", self.as_raw_mut_KNearest())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_KNearest_setAlgorithmType_int(self.as_raw_mut_KNearest(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_KNearest_setAlgorithmType_int(self.as_raw_mut_KNearest(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2215,7 +2615,7 @@ impl dyn KNearest + '_ {
 	#[inline]
 	pub fn create() -> Result<core::Ptr<dyn crate::ml::KNearest>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_KNearest_create(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_KNearest_create(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::ml::KNearest>::opencv_from_extern(ret) };
@@ -2233,7 +2633,7 @@ impl dyn KNearest + '_ {
 	pub fn load(filepath: &str) -> Result<core::Ptr<dyn crate::ml::KNearest>> {
 		extern_container_arg!(filepath);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_KNearest_load_const_StringR(filepath.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_KNearest_load_const_StringR(filepath.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::ml::KNearest>::opencv_from_extern(ret) };
@@ -2243,17 +2643,18 @@ impl dyn KNearest + '_ {
 }
 /// Implements Logistic Regression classifier.
 /// ## See also
-/// @ref ml_intro_lr
+/// ml_intro_lr
 pub trait LogisticRegressionConst: crate::ml::StatModelConst {
 	fn as_raw_LogisticRegression(&self) -> *const c_void;
 
 	/// Learning rate.
 	/// ## See also
-	/// setLearningRate
+	/// `set_learning_rate`
 	#[inline]
 	fn get_learning_rate(&self) -> Result<f64> {
+		crate::core::validity::check("LogisticRegression", self.as_raw_LogisticRegression())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_LogisticRegression_getLearningRate_const(self.as_raw_LogisticRegression(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_LogisticRegression_getLearningRate_const(self.as_raw_LogisticRegression(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2261,11 +2662,12 @@ pub trait LogisticRegressionConst: crate::ml::StatModelConst {
 	
 	/// Number of iterations.
 	/// ## See also
-	/// setIterations
+	/// `set_iterations`
 	#[inline]
 	fn get_iterations(&self) -> Result<i32> {
+		crate::core::validity::check("LogisticRegression", self.as_raw_LogisticRegression())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_LogisticRegression_getIterations_const(self.as_raw_LogisticRegression(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_LogisticRegression_getIterations_const(self.as_raw_LogisticRegression(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2273,11 +2675,12 @@ pub trait LogisticRegressionConst: crate::ml::StatModelConst {
 	
 	/// Kind of regularization to be applied. See LogisticRegression::RegKinds.
 	/// ## See also
-	/// setRegularization
+	/// `set_regularization`
 	#[inline]
 	fn get_regularization(&self) -> Result<i32> {
+		crate::core::validity::check("LogisticRegression", self.as_raw_LogisticRegression())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_LogisticRegression_getRegularization_const(self.as_raw_LogisticRegression(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_LogisticRegression_getRegularization_const(self.as_raw_LogisticRegression(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2285,11 +2688,12 @@ pub trait LogisticRegressionConst: crate::ml::StatModelConst {
 	
 	/// Kind of training method used. See LogisticRegression::Methods.
 	/// ## See also
-	/// setTrainMethod
+	/// `set_train_method`
 	#[inline]
 	fn get_train_method(&self) -> Result<i32> {
+		crate::core::validity::check("LogisticRegression", self.as_raw_LogisticRegression())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_LogisticRegression_getTrainMethod_const(self.as_raw_LogisticRegression(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_LogisticRegression_getTrainMethod_const(self.as_raw_LogisticRegression(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2299,11 +2703,12 @@ pub trait LogisticRegressionConst: crate::ml::StatModelConst {
 	///    Descent. Will only be used if using LogisticRegression::MINI_BATCH training algorithm. It
 	///    has to take values less than the total number of training samples.
 	/// ## See also
-	/// setMiniBatchSize
+	/// `set_mini_batch_size`
 	#[inline]
 	fn get_mini_batch_size(&self) -> Result<i32> {
+		crate::core::validity::check("LogisticRegression", self.as_raw_LogisticRegression())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_LogisticRegression_getMiniBatchSize_const(self.as_raw_LogisticRegression(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_LogisticRegression_getMiniBatchSize_const(self.as_raw_LogisticRegression(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2311,11 +2716,12 @@ pub trait LogisticRegressionConst: crate::ml::StatModelConst {
 	
 	/// Termination criteria of the algorithm.
 	/// ## See also
-	/// setTermCriteria
+	/// `set_term_criteria`
 	#[inline]
 	fn get_term_criteria(&self) -> Result<core::TermCriteria> {
+		crate::core::validity::check("LogisticRegression", self.as_raw_LogisticRegression())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_LogisticRegression_getTermCriteria_const(self.as_raw_LogisticRegression(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_LogisticRegression_getTermCriteria_const(self.as_raw_LogisticRegression(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2324,7 +2730,7 @@ pub trait LogisticRegressionConst: crate::ml::StatModelConst {
 	/// Predicts responses for input samples and returns a float type.
 	/// 
 	/// ## Parameters
-	/// * samples: The input data for the prediction algorithm. Matrix [m x n], where each row
+	/// * samples: The input data for the prediction algorithm. Matrix \[m x n\], where each row
 	///    contains variables (features) of one object being classified. Should have data type CV_32F.
 	/// * results: Predicted labels as a column matrix of type CV_32S.
 	/// * flags: Not used.
@@ -2334,10 +2740,11 @@ pub trait LogisticRegressionConst: crate::ml::StatModelConst {
 	/// * flags: 0
 	#[inline]
 	fn predict(&self, samples: &dyn core::ToInputArray, results: &mut dyn core::ToOutputArray, flags: i32) -> Result<f32> {
+		crate::core::validity::check("LogisticRegression", self.as_raw_LogisticRegression())?;
 		input_array_arg!(samples);
 		output_array_arg!(results);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_LogisticRegression_predict_const_const__InputArrayR_const__OutputArrayR_int(self.as_raw_LogisticRegression(), samples.as_raw__InputArray(), results.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_LogisticRegression_predict_const_const__InputArrayR_const__OutputArrayR_int(self.as_raw_LogisticRegression(), samples.as_raw__InputArray(), results.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2349,8 +2756,9 @@ pub trait LogisticRegressionConst: crate::ml::StatModelConst {
 	/// the Logistic Regression as a matrix of type CV_32F.
 	#[inline]
 	fn get_learnt_thetas(&self) -> Result<core::Mat> {
+		crate::core::validity::check("LogisticRegression", self.as_raw_LogisticRegression())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_LogisticRegression_get_learnt_thetas_const(self.as_raw_LogisticRegression(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_LogisticRegression_get_learnt_thetas_const(self.as_raw_LogisticRegression(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -2367,8 +2775,9 @@ pub trait LogisticRegression: crate::ml::LogisticRegressionConst + crate::ml::St
 	/// setLearningRate getLearningRate
 	#[inline]
 	fn set_learning_rate(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("LogisticRegression", self.as_raw_mut_LogisticRegression())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_LogisticRegression_setLearningRate_double(self.as_raw_mut_LogisticRegression(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_LogisticRegression_setLearningRate_double(self.as_raw_mut_LogisticRegression(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2379,8 +2788,9 @@ pub trait LogisticRegression: crate::ml::LogisticRegressionConst + crate::ml::St
 	/// setIterations getIterations
 	#[inline]
 	fn set_iterations(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("LogisticRegression", self.as_raw_mut_LogisticRegression())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_LogisticRegression_setIterations_int(self.as_raw_mut_LogisticRegression(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_LogisticRegression_setIterations_int(self.as_raw_mut_LogisticRegression(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2391,8 +2801,9 @@ pub trait LogisticRegression: crate::ml::LogisticRegressionConst + crate::ml::St
 	/// setRegularization getRegularization
 	#[inline]
 	fn set_regularization(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("LogisticRegression", self.as_raw_mut_LogisticRegression())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_LogisticRegression_setRegularization_int(self.as_raw_mut_LogisticRegression(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_LogisticRegression_setRegularization_int(self.as_raw_mut_LogisticRegression(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2403,8 +2814,9 @@ pub trait LogisticRegression: crate::ml::LogisticRegressionConst + crate::ml::St
 	/// setTrainMethod getTrainMethod
 	#[inline]
 	fn set_train_method(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("LogisticRegression", self.as_raw_mut_LogisticRegression())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_LogisticRegression_setTrainMethod_int(self.as_raw_mut_LogisticRegression(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_LogisticRegression_setTrainMethod_int(self.as_raw_mut_LogisticRegression(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2417,8 +2829,9 @@ pub trait LogisticRegression: crate::ml::LogisticRegressionConst + crate::ml::St
 	/// setMiniBatchSize getMiniBatchSize
 	#[inline]
 	fn set_mini_batch_size(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("LogisticRegression", self.as_raw_mut_LogisticRegression())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_LogisticRegression_setMiniBatchSize_int(self.as_raw_mut_LogisticRegression(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_LogisticRegression_setMiniBatchSize_int(self.as_raw_mut_LogisticRegression(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2429,8 +2842,9 @@ pub trait LogisticRegression: crate::ml::LogisticRegressionConst + crate::ml::St
 	/// setTermCriteria getTermCriteria
 	#[inline]
 	fn set_term_criteria(&mut self, val: core::TermCriteria) -> Result<()> {
+		crate::core::validity::check("LogisticRegression", self.as_raw_mut_LogisticRegression())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_LogisticRegression_setTermCriteria_TermCriteria(self.as_raw_mut_LogisticRegression(), val.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_LogisticRegression_setTermCriteria_TermCriteria(self.as_raw_mut_LogisticRegression(), val.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2445,7 +2859,7 @@ impl dyn LogisticRegression + '_ {
 	#[inline]
 	pub fn create() -> Result<core::Ptr<dyn crate::ml::LogisticRegression>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_LogisticRegression_create(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_LogisticRegression_create(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::ml::LogisticRegression>::opencv_from_extern(ret) };
@@ -2469,7 +2883,7 @@ impl dyn LogisticRegression + '_ {
 		extern_container_arg!(filepath);
 		extern_container_arg!(node_name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_LogisticRegression_load_const_StringR_const_StringR(filepath.opencv_as_extern(), node_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_LogisticRegression_load_const_StringR_const_StringR(filepath.opencv_as_extern(), node_name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::ml::LogisticRegression>::opencv_from_extern(ret) };
@@ -2479,7 +2893,7 @@ impl dyn LogisticRegression + '_ {
 }
 /// Bayes classifier for normally distributed data.
 /// ## See also
-/// @ref ml_intro_bayes
+/// ml_intro_bayes
 pub trait NormalBayesClassifierConst: crate::ml::StatModelConst {
 	fn as_raw_NormalBayesClassifier(&self) -> *const c_void;
 
@@ -2495,11 +2909,12 @@ pub trait NormalBayesClassifierConst: crate::ml::StatModelConst {
 	/// * flags: 0
 	#[inline]
 	fn predict_prob(&self, inputs: &dyn core::ToInputArray, outputs: &mut dyn core::ToOutputArray, output_probs: &mut dyn core::ToOutputArray, flags: i32) -> Result<f32> {
+		crate::core::validity::check("NormalBayesClassifier", self.as_raw_NormalBayesClassifier())?;
 		input_array_arg!(inputs);
 		output_array_arg!(outputs);
 		output_array_arg!(output_probs);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_NormalBayesClassifier_predictProb_const_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_int(self.as_raw_NormalBayesClassifier(), inputs.as_raw__InputArray(), outputs.as_raw__OutputArray(), output_probs.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_NormalBayesClassifier_predictProb_const_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_int(self.as_raw_NormalBayesClassifier(), inputs.as_raw__InputArray(), outputs.as_raw__OutputArray(), output_probs.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2518,7 +2933,7 @@ impl dyn NormalBayesClassifier + '_ {
 	#[inline]
 	pub fn create() -> Result<core::Ptr<dyn crate::ml::NormalBayesClassifier>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_NormalBayesClassifier_create(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_NormalBayesClassifier_create(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::ml::NormalBayesClassifier>::opencv_from_extern(ret) };
@@ -2542,7 +2957,7 @@ impl dyn NormalBayesClassifier + '_ {
 		extern_container_arg!(filepath);
 		extern_container_arg!(node_name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_NormalBayesClassifier_load_const_StringR_const_StringR(filepath.opencv_as_extern(), node_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_NormalBayesClassifier_load_const_StringR_const_StringR(filepath.opencv_as_extern(), node_name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::ml::NormalBayesClassifier>::opencv_from_extern(ret) };
@@ -2560,17 +2975,35 @@ pub trait ParamGridTraitConst {
 	/// Minimum value of the statmodel parameter. Default value is 0.
 	#[inline]
 	fn min_val(&self) -> f64 {
-		let ret = unsafe { sys::cv_ml_ParamGrid_getPropMinVal_const(self.as_raw_ParamGrid()) };
+		crate::core::validity::debug_check("ParamGrid", self.as_raw_ParamGrid());
+		let ret = unsafe { ffi_call!(cv_ml_ParamGrid_getPropMinVal_const(self.as_raw_ParamGrid())) };
 		ret
 	}
 	
+	/// Non-panicking version of [min_val](ParamGridTraitConst::min_val), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_min_val(&self) -> Result<f64> {
+		crate::core::validity::check("ParamGrid", self.as_raw_ParamGrid())?;
+		Ok(ParamGridTraitConst::min_val(self))
+	}
+	
 	/// Maximum value of the statmodel parameter. Default value is 0.
 	#[inline]
 	fn max_val(&self) -> f64 {
-		let ret = unsafe { sys::cv_ml_ParamGrid_getPropMaxVal_const(self.as_raw_ParamGrid()) };
+		crate::core::validity::debug_check("ParamGrid", self.as_raw_ParamGrid());
+		let ret = unsafe { ffi_call!(cv_ml_ParamGrid_getPropMaxVal_const(self.as_raw_ParamGrid())) };
 		ret
 	}
 	
+	/// Non-panicking version of [max_val](ParamGridTraitConst::max_val), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_max_val(&self) -> Result<f64> {
+		crate::core::validity::check("ParamGrid", self.as_raw_ParamGrid())?;
+		Ok(ParamGridTraitConst::max_val(self))
+	}
+	
 	/// Logarithmic step for iterating the statmodel parameter.
 	/// 
 	/// The grid determines the following iteration sequence of the statmodel parameter values:
@@ -2580,10 +3013,19 @@ pub trait ParamGridTraitConst {
 	/// The grid is logarithmic, so logStep must always be greater than 1. Default value is 1.
 	#[inline]
 	fn log_step(&self) -> f64 {
-		let ret = unsafe { sys::cv_ml_ParamGrid_getPropLogStep_const(self.as_raw_ParamGrid()) };
+		crate::core::validity::debug_check("ParamGrid", self.as_raw_ParamGrid());
+		let ret = unsafe { ffi_call!(cv_ml_ParamGrid_getPropLogStep_const(self.as_raw_ParamGrid())) };
 		ret
 	}
 	
+	/// Non-panicking version of [log_step](ParamGridTraitConst::log_step), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_log_step(&self) -> Result<f64> {
+		crate::core::validity::check("ParamGrid", self.as_raw_ParamGrid())?;
+		Ok(ParamGridTraitConst::log_step(self))
+	}
+	
 }
 
 pub trait ParamGridTrait: crate::ml::ParamGridTraitConst {
@@ -2592,14 +3034,16 @@ pub trait ParamGridTrait: crate::ml::ParamGridTraitConst {
 	/// Minimum value of the statmodel parameter. Default value is 0.
 	#[inline]
 	fn set_min_val(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_ml_ParamGrid_setPropMinVal_double(self.as_raw_mut_ParamGrid(), val) };
+		crate::core::validity::debug_check("ParamGrid", self.as_raw_mut_ParamGrid());
+		let ret = unsafe { ffi_call!(cv_ml_ParamGrid_setPropMinVal_double(self.as_raw_mut_ParamGrid(), val)) };
 		ret
 	}
 	
 	/// Maximum value of the statmodel parameter. Default value is 0.
 	#[inline]
 	fn set_max_val(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_ml_ParamGrid_setPropMaxVal_double(self.as_raw_mut_ParamGrid(), val) };
+		crate::core::validity::debug_check("ParamGrid", self.as_raw_mut_ParamGrid());
+		let ret = unsafe { ffi_call!(cv_ml_ParamGrid_setPropMaxVal_double(self.as_raw_mut_ParamGrid(), val)) };
 		ret
 	}
 	
@@ -2612,7 +3056,8 @@ pub trait ParamGridTrait: crate::ml::ParamGridTraitConst {
 	/// The grid is logarithmic, so logStep must always be greater than 1. Default value is 1.
 	#[inline]
 	fn set_log_step(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_ml_ParamGrid_setPropLogStep_double(self.as_raw_mut_ParamGrid(), val) };
+		crate::core::validity::debug_check("ParamGrid", self.as_raw_mut_ParamGrid());
+		let ret = unsafe { ffi_call!(cv_ml_ParamGrid_setPropLogStep_double(self.as_raw_mut_ParamGrid(), val)) };
 		ret
 	}
 	
@@ -2631,7 +3076,10 @@ opencv_type_boxed! { ParamGrid }
 impl Drop for ParamGrid {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ParamGrid_delete(instance: *mut c_void); }
-		unsafe { cv_ParamGrid_delete(self.as_raw_mut_ParamGrid()) };
+		let ptr = self.as_raw_mut_ParamGrid();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ParamGrid_delete(ptr) };
 	}
 }
 
@@ -2650,7 +3098,7 @@ impl ParamGrid {
 	#[inline]
 	pub fn default() -> Result<crate::ml::ParamGrid> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ParamGrid_ParamGrid(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ParamGrid_ParamGrid(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::ml::ParamGrid::opencv_from_extern(ret) };
@@ -2661,7 +3109,7 @@ impl ParamGrid {
 	#[inline]
 	pub fn for_range(_min_val: f64, _max_val: f64, _log_step: f64) -> Result<crate::ml::ParamGrid> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ParamGrid_ParamGrid_double_double_double(_min_val, _max_val, _log_step, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ParamGrid_ParamGrid_double_double_double(_min_val, _max_val, _log_step, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::ml::ParamGrid::opencv_from_extern(ret) };
@@ -2682,7 +3130,7 @@ impl ParamGrid {
 	#[inline]
 	pub fn create(min_val: f64, max_val: f64, logstep: f64) -> Result<core::Ptr<crate::ml::ParamGrid>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_ParamGrid_create_double_double_double(min_val, max_val, logstep, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_ParamGrid_create_double_double_double(min_val, max_val, logstep, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::ml::ParamGrid>::opencv_from_extern(ret) };
@@ -2691,20 +3139,71 @@ impl ParamGrid {
 	
 }
 
+/// Builder for `ParamGrid::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct ParamGrid_CreateBuilder {
+	min_val: f64,
+	max_val: f64,
+	logstep: f64,
+}
+
+impl ParamGrid_CreateBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { min_val: 0., max_val: 0., logstep: 1. }
+	}
+	
+	/// C++ default: `0.`
+	#[inline]
+	pub fn with_min_val(mut self, min_val: f64) -> Self {
+		self.min_val = min_val;
+		self
+	}
+	
+	/// C++ default: `0.`
+	#[inline]
+	pub fn with_max_val(mut self, max_val: f64) -> Self {
+		self.max_val = max_val;
+		self
+	}
+	
+	/// C++ default: `1.`
+	#[inline]
+	pub fn with_logstep(mut self, logstep: f64) -> Self {
+		self.logstep = logstep;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<crate::ml::ParamGrid>> {
+		ParamGrid::create(self.min_val, self.max_val, self.logstep)
+	}
+}
+
+impl Default for ParamGrid_CreateBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 /// The class implements the random forest predictor.
 /// ## See also
-/// @ref ml_intro_rtrees
+/// ml_intro_rtrees
 pub trait RTreesConst: crate::ml::DTreesConst {
 	fn as_raw_RTrees(&self) -> *const c_void;
 
 	/// If true then variable importance will be calculated and then it can be retrieved by RTrees::getVarImportance.
 	///    Default value is false.
 	/// ## See also
-	/// setCalculateVarImportance
+	/// `set_calculate_var_importance`
 	#[inline]
 	fn get_calculate_var_importance(&self) -> Result<bool> {
+		crate::core::validity::check("RTrees", self.as_raw_RTrees())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_RTrees_getCalculateVarImportance_const(self.as_raw_RTrees(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_RTrees_getCalculateVarImportance_const(self.as_raw_RTrees(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2715,11 +3214,12 @@ pub trait RTreesConst: crate::ml::DTreesConst {
 	///    If you set it to 0 then the size will be set to the square root of the total number of
 	///    features. Default value is 0.
 	/// ## See also
-	/// setActiveVarCount
+	/// `set_active_var_count`
 	#[inline]
 	fn get_active_var_count(&self) -> Result<i32> {
+		crate::core::validity::check("RTrees", self.as_raw_RTrees())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_RTrees_getActiveVarCount_const(self.as_raw_RTrees(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_RTrees_getActiveVarCount_const(self.as_raw_RTrees(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2733,11 +3233,12 @@ pub trait RTreesConst: crate::ml::DTreesConst {
 	///    prediction time linearly. Default value is TermCriteria(TermCriteria::MAX_ITERS +
 	///    TermCriteria::EPS, 50, 0.1)
 	/// ## See also
-	/// setTermCriteria
+	/// `set_term_criteria`
 	#[inline]
 	fn get_term_criteria(&self) -> Result<core::TermCriteria> {
+		crate::core::validity::check("RTrees", self.as_raw_RTrees())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_RTrees_getTermCriteria_const(self.as_raw_RTrees(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_RTrees_getTermCriteria_const(self.as_raw_RTrees(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2749,8 +3250,9 @@ pub trait RTreesConst: crate::ml::DTreesConst {
 	/// returned.
 	#[inline]
 	fn get_var_importance(&self) -> Result<core::Mat> {
+		crate::core::validity::check("RTrees", self.as_raw_RTrees())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_RTrees_getVarImportance_const(self.as_raw_RTrees(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_RTrees_getVarImportance_const(self.as_raw_RTrees(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -2768,10 +3270,11 @@ pub trait RTreesConst: crate::ml::DTreesConst {
 	/// * flags: Flags for defining the type of RTrees.
 	#[inline]
 	fn get_votes(&self, samples: &dyn core::ToInputArray, results: &mut dyn core::ToOutputArray, flags: i32) -> Result<()> {
+		crate::core::validity::check("RTrees", self.as_raw_RTrees())?;
 		input_array_arg!(samples);
 		output_array_arg!(results);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_RTrees_getVotes_const_const__InputArrayR_const__OutputArrayR_int(self.as_raw_RTrees(), samples.as_raw__InputArray(), results.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_RTrees_getVotes_const_const__InputArrayR_const__OutputArrayR_int(self.as_raw_RTrees(), samples.as_raw__InputArray(), results.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2779,8 +3282,9 @@ pub trait RTreesConst: crate::ml::DTreesConst {
 	
 	#[inline]
 	fn get_oob_error(&self) -> Result<f64> {
+		crate::core::validity::check("RTrees", self.as_raw_RTrees())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_RTrees_getOOBError_const(self.as_raw_RTrees(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_RTrees_getOOBError_const(self.as_raw_RTrees(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2797,8 +3301,9 @@ pub trait RTrees: crate::ml::DTrees + crate::ml::RTreesConst {
 	/// setCalculateVarImportance getCalculateVarImportance
 	#[inline]
 	fn set_calculate_var_importance(&mut self, val: bool) -> Result<()> {
+		crate::core::validity::check("RTrees", self.as_raw_mut_RTrees())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_RTrees_setCalculateVarImportance_bool(self.as_raw_mut_RTrees(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_RTrees_setCalculateVarImportance_bool(self.as_raw_mut_RTrees(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2812,8 +3317,9 @@ pub trait RTrees: crate::ml::DTrees + crate::ml::RTreesConst {
 	/// setActiveVarCount getActiveVarCount
 	#[inline]
 	fn set_active_var_count(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("RTrees", self.as_raw_mut_RTrees())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_RTrees_setActiveVarCount_int(self.as_raw_mut_RTrees(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_RTrees_setActiveVarCount_int(self.as_raw_mut_RTrees(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2830,8 +3336,9 @@ pub trait RTrees: crate::ml::DTrees + crate::ml::RTreesConst {
 	/// setTermCriteria getTermCriteria
 	#[inline]
 	fn set_term_criteria(&mut self, val: core::TermCriteria) -> Result<()> {
+		crate::core::validity::check("RTrees", self.as_raw_mut_RTrees())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_RTrees_setTermCriteria_const_TermCriteriaR(self.as_raw_mut_RTrees(), &val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_RTrees_setTermCriteria_const_TermCriteriaR(self.as_raw_mut_RTrees(), &val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2846,7 +3353,7 @@ impl dyn RTrees + '_ {
 	#[inline]
 	pub fn create() -> Result<core::Ptr<dyn crate::ml::RTrees>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_RTrees_create(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_RTrees_create(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::ml::RTrees>::opencv_from_extern(ret) };
@@ -2870,7 +3377,7 @@ impl dyn RTrees + '_ {
 		extern_container_arg!(filepath);
 		extern_container_arg!(node_name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_RTrees_load_const_StringR_const_StringR(filepath.opencv_as_extern(), node_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_RTrees_load_const_StringR_const_StringR(filepath.opencv_as_extern(), node_name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::ml::RTrees>::opencv_from_extern(ret) };
@@ -2880,18 +3387,19 @@ impl dyn RTrees + '_ {
 }
 /// Support Vector Machines.
 /// ## See also
-/// @ref ml_intro_svm
+/// ml_intro_svm
 pub trait SVMConst: crate::ml::StatModelConst {
 	fn as_raw_SVM(&self) -> *const c_void;
 
 	/// Type of a %SVM formulation.
 	///    See SVM::Types. Default value is SVM::C_SVC.
 	/// ## See also
-	/// setType
+	/// `set_type`
 	#[inline]
 	fn get_type(&self) -> Result<i32> {
+		crate::core::validity::check("SVM", self.as_raw_SVM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVM_getType_const(self.as_raw_SVM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVM_getType_const(self.as_raw_SVM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2900,11 +3408,12 @@ pub trait SVMConst: crate::ml::StatModelConst {
 	/// Parameter ![inline formula](https://latex.codecogs.com/png.latex?%5Cgamma) of a kernel function.
 	///    For SVM::POLY, SVM::RBF, SVM::SIGMOID or SVM::CHI2. Default value is 1.
 	/// ## See also
-	/// setGamma
+	/// `set_gamma`
 	#[inline]
 	fn get_gamma(&self) -> Result<f64> {
+		crate::core::validity::check("SVM", self.as_raw_SVM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVM_getGamma_const(self.as_raw_SVM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVM_getGamma_const(self.as_raw_SVM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2913,11 +3422,12 @@ pub trait SVMConst: crate::ml::StatModelConst {
 	/// Parameter _coef0_ of a kernel function.
 	///    For SVM::POLY or SVM::SIGMOID. Default value is 0.
 	/// ## See also
-	/// setCoef0
+	/// `set_coef0`
 	#[inline]
 	fn get_coef0(&self) -> Result<f64> {
+		crate::core::validity::check("SVM", self.as_raw_SVM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVM_getCoef0_const(self.as_raw_SVM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVM_getCoef0_const(self.as_raw_SVM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2926,11 +3436,12 @@ pub trait SVMConst: crate::ml::StatModelConst {
 	/// Parameter _degree_ of a kernel function.
 	///    For SVM::POLY. Default value is 0.
 	/// ## See also
-	/// setDegree
+	/// `set_degree`
 	#[inline]
 	fn get_degree(&self) -> Result<f64> {
+		crate::core::validity::check("SVM", self.as_raw_SVM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVM_getDegree_const(self.as_raw_SVM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVM_getDegree_const(self.as_raw_SVM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2939,11 +3450,12 @@ pub trait SVMConst: crate::ml::StatModelConst {
 	/// Parameter _C_ of a %SVM optimization problem.
 	///    For SVM::C_SVC, SVM::EPS_SVR or SVM::NU_SVR. Default value is 0.
 	/// ## See also
-	/// setC
+	/// `set_c`
 	#[inline]
 	fn get_c(&self) -> Result<f64> {
+		crate::core::validity::check("SVM", self.as_raw_SVM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVM_getC_const(self.as_raw_SVM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVM_getC_const(self.as_raw_SVM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2952,11 +3464,12 @@ pub trait SVMConst: crate::ml::StatModelConst {
 	/// Parameter ![inline formula](https://latex.codecogs.com/png.latex?%5Cnu) of a %SVM optimization problem.
 	///    For SVM::NU_SVC, SVM::ONE_CLASS or SVM::NU_SVR. Default value is 0.
 	/// ## See also
-	/// setNu
+	/// `set_nu`
 	#[inline]
 	fn get_nu(&self) -> Result<f64> {
+		crate::core::validity::check("SVM", self.as_raw_SVM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVM_getNu_const(self.as_raw_SVM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVM_getNu_const(self.as_raw_SVM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2965,11 +3478,12 @@ pub trait SVMConst: crate::ml::StatModelConst {
 	/// Parameter ![inline formula](https://latex.codecogs.com/png.latex?%5Cepsilon) of a %SVM optimization problem.
 	///    For SVM::EPS_SVR. Default value is 0.
 	/// ## See also
-	/// setP
+	/// `set_p`
 	#[inline]
 	fn get_p(&self) -> Result<f64> {
+		crate::core::validity::check("SVM", self.as_raw_SVM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVM_getP_const(self.as_raw_SVM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVM_getP_const(self.as_raw_SVM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2981,11 +3495,12 @@ pub trait SVMConst: crate::ml::StatModelConst {
 	///    the larger penalty on misclassification of data from the corresponding class. Default value is
 	///    empty Mat.
 	/// ## See also
-	/// setClassWeights
+	/// `set_class_weights`
 	#[inline]
 	fn get_class_weights(&self) -> Result<core::Mat> {
+		crate::core::validity::check("SVM", self.as_raw_SVM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVM_getClassWeights_const(self.as_raw_SVM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVM_getClassWeights_const(self.as_raw_SVM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -2997,11 +3512,12 @@ pub trait SVMConst: crate::ml::StatModelConst {
 	///    You can specify tolerance and/or the maximum number of iterations. Default value is
 	///    `TermCriteria( TermCriteria::MAX_ITER + TermCriteria::EPS, 1000, FLT_EPSILON )`;
 	/// ## See also
-	/// setTermCriteria
+	/// `set_term_criteria`
 	#[inline]
 	fn get_term_criteria(&self) -> Result<core::TermCriteria> {
+		crate::core::validity::check("SVM", self.as_raw_SVM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVM_getTermCriteria_const(self.as_raw_SVM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVM_getTermCriteria_const(self.as_raw_SVM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3011,8 +3527,9 @@ pub trait SVMConst: crate::ml::StatModelConst {
 	/// See SVM::KernelTypes. Default value is SVM::RBF.
 	#[inline]
 	fn get_kernel_type(&self) -> Result<i32> {
+		crate::core::validity::check("SVM", self.as_raw_SVM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVM_getKernelType_const(self.as_raw_SVM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVM_getKernelType_const(self.as_raw_SVM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3024,8 +3541,9 @@ pub trait SVMConst: crate::ml::StatModelConst {
 	/// stored as matrix rows.
 	#[inline]
 	fn get_support_vectors(&self) -> Result<core::Mat> {
+		crate::core::validity::check("SVM", self.as_raw_SVM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVM_getSupportVectors_const(self.as_raw_SVM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVM_getSupportVectors_const(self.as_raw_SVM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -3039,8 +3557,9 @@ pub trait SVMConst: crate::ml::StatModelConst {
 	/// matrix, where the support vectors are stored as matrix rows.
 	#[inline]
 	fn get_uncompressed_support_vectors(&self) -> Result<core::Mat> {
+		crate::core::validity::check("SVM", self.as_raw_SVM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVM_getUncompressedSupportVectors_const(self.as_raw_SVM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVM_getUncompressedSupportVectors_const(self.as_raw_SVM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -3064,10 +3583,11 @@ pub trait SVMConst: crate::ml::StatModelConst {
 	/// sum of kernel responses.
 	#[inline]
 	fn get_decision_function(&self, i: i32, alpha: &mut dyn core::ToOutputArray, svidx: &mut dyn core::ToOutputArray) -> Result<f64> {
+		crate::core::validity::check("SVM", self.as_raw_SVM())?;
 		output_array_arg!(alpha);
 		output_array_arg!(svidx);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVM_getDecisionFunction_const_int_const__OutputArrayR_const__OutputArrayR(self.as_raw_SVM(), i, alpha.as_raw__OutputArray(), svidx.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVM_getDecisionFunction_const_int_const__OutputArrayR_const__OutputArrayR(self.as_raw_SVM(), i, alpha.as_raw__OutputArray(), svidx.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3084,8 +3604,9 @@ pub trait SVM: crate::ml::SVMConst + crate::ml::StatModel {
 	/// setType getType
 	#[inline]
 	fn set_type(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("SVM", self.as_raw_mut_SVM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVM_setType_int(self.as_raw_mut_SVM(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVM_setType_int(self.as_raw_mut_SVM(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3097,8 +3618,9 @@ pub trait SVM: crate::ml::SVMConst + crate::ml::StatModel {
 	/// setGamma getGamma
 	#[inline]
 	fn set_gamma(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("SVM", self.as_raw_mut_SVM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVM_setGamma_double(self.as_raw_mut_SVM(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVM_setGamma_double(self.as_raw_mut_SVM(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3110,8 +3632,9 @@ pub trait SVM: crate::ml::SVMConst + crate::ml::StatModel {
 	/// setCoef0 getCoef0
 	#[inline]
 	fn set_coef0(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("SVM", self.as_raw_mut_SVM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVM_setCoef0_double(self.as_raw_mut_SVM(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVM_setCoef0_double(self.as_raw_mut_SVM(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3123,8 +3646,9 @@ pub trait SVM: crate::ml::SVMConst + crate::ml::StatModel {
 	/// setDegree getDegree
 	#[inline]
 	fn set_degree(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("SVM", self.as_raw_mut_SVM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVM_setDegree_double(self.as_raw_mut_SVM(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVM_setDegree_double(self.as_raw_mut_SVM(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3136,8 +3660,9 @@ pub trait SVM: crate::ml::SVMConst + crate::ml::StatModel {
 	/// setC getC
 	#[inline]
 	fn set_c(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("SVM", self.as_raw_mut_SVM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVM_setC_double(self.as_raw_mut_SVM(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVM_setC_double(self.as_raw_mut_SVM(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3149,8 +3674,9 @@ pub trait SVM: crate::ml::SVMConst + crate::ml::StatModel {
 	/// setNu getNu
 	#[inline]
 	fn set_nu(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("SVM", self.as_raw_mut_SVM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVM_setNu_double(self.as_raw_mut_SVM(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVM_setNu_double(self.as_raw_mut_SVM(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3162,8 +3688,9 @@ pub trait SVM: crate::ml::SVMConst + crate::ml::StatModel {
 	/// setP getP
 	#[inline]
 	fn set_p(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("SVM", self.as_raw_mut_SVM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVM_setP_double(self.as_raw_mut_SVM(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVM_setP_double(self.as_raw_mut_SVM(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3178,8 +3705,9 @@ pub trait SVM: crate::ml::SVMConst + crate::ml::StatModel {
 	/// setClassWeights getClassWeights
 	#[inline]
 	fn set_class_weights(&mut self, val: &core::Mat) -> Result<()> {
+		crate::core::validity::check("SVM", self.as_raw_mut_SVM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVM_setClassWeights_const_MatR(self.as_raw_mut_SVM(), val.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVM_setClassWeights_const_MatR(self.as_raw_mut_SVM(), val.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3193,8 +3721,9 @@ pub trait SVM: crate::ml::SVMConst + crate::ml::StatModel {
 	/// setTermCriteria getTermCriteria
 	#[inline]
 	fn set_term_criteria(&mut self, val: core::TermCriteria) -> Result<()> {
+		crate::core::validity::check("SVM", self.as_raw_mut_SVM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVM_setTermCriteria_const_TermCriteriaR(self.as_raw_mut_SVM(), &val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVM_setTermCriteria_const_TermCriteriaR(self.as_raw_mut_SVM(), &val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3204,8 +3733,9 @@ pub trait SVM: crate::ml::SVMConst + crate::ml::StatModel {
 	/// See SVM::KernelTypes.
 	#[inline]
 	fn set_kernel(&mut self, kernel_type: i32) -> Result<()> {
+		crate::core::validity::check("SVM", self.as_raw_mut_SVM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVM_setKernel_int(self.as_raw_mut_SVM(), kernel_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVM_setKernel_int(self.as_raw_mut_SVM(), kernel_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3215,8 +3745,9 @@ pub trait SVM: crate::ml::SVMConst + crate::ml::StatModel {
 	/// See SVM::Kernel class for implementation details
 	#[inline]
 	fn set_custom_kernel(&mut self, _kernel: &core::Ptr<dyn crate::ml::SVM_Kernel>) -> Result<()> {
+		crate::core::validity::check("SVM", self.as_raw_mut_SVM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVM_setCustomKernel_const_Ptr_Kernel_R(self.as_raw_mut_SVM(), _kernel.as_raw_PtrOfSVM_Kernel(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVM_setCustomKernel_const_Ptr_Kernel_R(self.as_raw_mut_SVM(), _kernel.as_raw_PtrOfSVM_Kernel(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3268,8 +3799,9 @@ pub trait SVM: crate::ml::SVMConst + crate::ml::StatModel {
 	/// * balanced: false
 	#[inline]
 	fn train_auto(&mut self, data: &core::Ptr<dyn crate::ml::TrainData>, k_fold: i32, mut cgrid: crate::ml::ParamGrid, mut gamma_grid: crate::ml::ParamGrid, mut p_grid: crate::ml::ParamGrid, mut nu_grid: crate::ml::ParamGrid, mut coeff_grid: crate::ml::ParamGrid, mut degree_grid: crate::ml::ParamGrid, balanced: bool) -> Result<bool> {
+		crate::core::validity::check("SVM", self.as_raw_mut_SVM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVM_trainAuto_const_Ptr_TrainData_R_int_ParamGrid_ParamGrid_ParamGrid_ParamGrid_ParamGrid_ParamGrid_bool(self.as_raw_mut_SVM(), data.as_raw_PtrOfTrainData(), k_fold, cgrid.as_raw_mut_ParamGrid(), gamma_grid.as_raw_mut_ParamGrid(), p_grid.as_raw_mut_ParamGrid(), nu_grid.as_raw_mut_ParamGrid(), coeff_grid.as_raw_mut_ParamGrid(), degree_grid.as_raw_mut_ParamGrid(), balanced, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVM_trainAuto_const_Ptr_TrainData_R_int_ParamGrid_ParamGrid_ParamGrid_ParamGrid_ParamGrid_ParamGrid_bool(self.as_raw_mut_SVM(), data.as_raw_PtrOfTrainData(), k_fold, cgrid.as_raw_mut_ParamGrid(), gamma_grid.as_raw_mut_ParamGrid(), p_grid.as_raw_mut_ParamGrid(), nu_grid.as_raw_mut_ParamGrid(), coeff_grid.as_raw_mut_ParamGrid(), degree_grid.as_raw_mut_ParamGrid(), balanced, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3315,10 +3847,11 @@ pub trait SVM: crate::ml::SVMConst + crate::ml::StatModel {
 	/// * balanced: false
 	#[inline]
 	fn train_auto_with_data(&mut self, samples: &dyn core::ToInputArray, layout: i32, responses: &dyn core::ToInputArray, k_fold: i32, mut cgrid: core::Ptr<crate::ml::ParamGrid>, mut gamma_grid: core::Ptr<crate::ml::ParamGrid>, mut p_grid: core::Ptr<crate::ml::ParamGrid>, mut nu_grid: core::Ptr<crate::ml::ParamGrid>, mut coeff_grid: core::Ptr<crate::ml::ParamGrid>, mut degree_grid: core::Ptr<crate::ml::ParamGrid>, balanced: bool) -> Result<bool> {
+		crate::core::validity::check("SVM", self.as_raw_mut_SVM())?;
 		input_array_arg!(samples);
 		input_array_arg!(responses);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVM_trainAuto_const__InputArrayR_int_const__InputArrayR_int_Ptr_ParamGrid__Ptr_ParamGrid__Ptr_ParamGrid__Ptr_ParamGrid__Ptr_ParamGrid__Ptr_ParamGrid__bool(self.as_raw_mut_SVM(), samples.as_raw__InputArray(), layout, responses.as_raw__InputArray(), k_fold, cgrid.as_raw_mut_PtrOfParamGrid(), gamma_grid.as_raw_mut_PtrOfParamGrid(), p_grid.as_raw_mut_PtrOfParamGrid(), nu_grid.as_raw_mut_PtrOfParamGrid(), coeff_grid.as_raw_mut_PtrOfParamGrid(), degree_grid.as_raw_mut_PtrOfParamGrid(), balanced, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVM_trainAuto_const__InputArrayR_int_const__InputArrayR_int_Ptr_ParamGrid__Ptr_ParamGrid__Ptr_ParamGrid__Ptr_ParamGrid__Ptr_ParamGrid__Ptr_ParamGrid__bool(self.as_raw_mut_SVM(), samples.as_raw__InputArray(), layout, responses.as_raw__InputArray(), k_fold, cgrid.as_raw_mut_PtrOfParamGrid(), gamma_grid.as_raw_mut_PtrOfParamGrid(), p_grid.as_raw_mut_PtrOfParamGrid(), nu_grid.as_raw_mut_PtrOfParamGrid(), coeff_grid.as_raw_mut_PtrOfParamGrid(), degree_grid.as_raw_mut_PtrOfParamGrid(), balanced, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3326,6 +3859,94 @@ pub trait SVM: crate::ml::SVMConst + crate::ml::StatModel {
 	
 }
 
+/// Builder for `SVM::train_auto()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct SVM_TrainAutoBuilder<'a, T: ?Sized + SVM> {
+	instance: &'a mut T,
+	data: &'a core::Ptr<dyn crate::ml::TrainData>,
+	k_fold: i32,
+	cgrid: crate::ml::ParamGrid,
+	gamma_grid: crate::ml::ParamGrid,
+	p_grid: crate::ml::ParamGrid,
+	nu_grid: crate::ml::ParamGrid,
+	coeff_grid: crate::ml::ParamGrid,
+	degree_grid: crate::ml::ParamGrid,
+	balanced: bool,
+}
+
+impl<'a, T: ?Sized + SVM> SVM_TrainAutoBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, data: &'a core::Ptr<dyn crate::ml::TrainData>, cgrid: crate::ml::ParamGrid, gamma_grid: crate::ml::ParamGrid, p_grid: crate::ml::ParamGrid, nu_grid: crate::ml::ParamGrid, coeff_grid: crate::ml::ParamGrid, degree_grid: crate::ml::ParamGrid) -> Self {
+		Self { instance, data, k_fold: 10, cgrid, gamma_grid, p_grid, nu_grid, coeff_grid, degree_grid, balanced: false }
+	}
+	
+	/// C++ default: `10`
+	#[inline]
+	pub fn with_k_fold(mut self, k_fold: i32) -> Self {
+		self.k_fold = k_fold;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_balanced(mut self, balanced: bool) -> Self {
+		self.balanced = balanced;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<bool> {
+		SVM::train_auto(self.instance, self.data, self.k_fold, self.cgrid, self.gamma_grid, self.p_grid, self.nu_grid, self.coeff_grid, self.degree_grid, self.balanced)
+	}
+}
+
+/// Builder for `SVM::train_auto_with_data()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct SVM_TrainAutoWithDataBuilder<'a, T: ?Sized + SVM> {
+	instance: &'a mut T,
+	samples: &'a dyn core::ToInputArray,
+	layout: i32,
+	responses: &'a dyn core::ToInputArray,
+	k_fold: i32,
+	cgrid: core::Ptr<crate::ml::ParamGrid>,
+	gamma_grid: core::Ptr<crate::ml::ParamGrid>,
+	p_grid: core::Ptr<crate::ml::ParamGrid>,
+	nu_grid: core::Ptr<crate::ml::ParamGrid>,
+	coeff_grid: core::Ptr<crate::ml::ParamGrid>,
+	degree_grid: core::Ptr<crate::ml::ParamGrid>,
+	balanced: bool,
+}
+
+impl<'a, T: ?Sized + SVM> SVM_TrainAutoWithDataBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, samples: &'a dyn core::ToInputArray, layout: i32, responses: &'a dyn core::ToInputArray, cgrid: core::Ptr<crate::ml::ParamGrid>, gamma_grid: core::Ptr<crate::ml::ParamGrid>, p_grid: core::Ptr<crate::ml::ParamGrid>, nu_grid: core::Ptr<crate::ml::ParamGrid>, coeff_grid: core::Ptr<crate::ml::ParamGrid>, degree_grid: core::Ptr<crate::ml::ParamGrid>) -> Self {
+		Self { instance, samples, layout, responses, k_fold: 10, cgrid, gamma_grid, p_grid, nu_grid, coeff_grid, degree_grid, balanced: false }
+	}
+	
+	/// C++ default: `10`
+	#[inline]
+	pub fn with_k_fold(mut self, k_fold: i32) -> Self {
+		self.k_fold = k_fold;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_balanced(mut self, balanced: bool) -> Self {
+		self.balanced = balanced;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<bool> {
+		SVM::train_auto_with_data(self.instance, self.samples, self.layout, self.responses, self.k_fold, self.cgrid, self.gamma_grid, self.p_grid, self.nu_grid, self.coeff_grid, self.degree_grid, self.balanced)
+	}
+}
+
 impl dyn SVM + '_ {
 	/// Generates a grid for %SVM parameters.
 	/// 
@@ -3338,7 +3959,7 @@ impl dyn SVM + '_ {
 	#[inline]
 	pub fn get_default_grid(param_id: i32) -> Result<crate::ml::ParamGrid> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVM_getDefaultGrid_int(param_id, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVM_getDefaultGrid_int(param_id, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::ml::ParamGrid::opencv_from_extern(ret) };
@@ -3356,7 +3977,7 @@ impl dyn SVM + '_ {
 	#[inline]
 	pub fn get_default_grid_ptr(param_id: i32) -> Result<core::Ptr<crate::ml::ParamGrid>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVM_getDefaultGridPtr_int(param_id, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVM_getDefaultGridPtr_int(param_id, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::ml::ParamGrid>::opencv_from_extern(ret) };
@@ -3366,10 +3987,27 @@ impl dyn SVM + '_ {
 	/// Creates empty model.
 	/// Use StatModel::train to train the model. Since %SVM has several parameters, you may want to
 	/// find the best parameters for your problem, it can be done with SVM::trainAuto.
+	/// 
+	/// ## Example
+	/// ```
+	/// use opencv::{core::{self, Mat}, ml, prelude::*};
+	/// 
+	/// let samples = Mat::from_slice_2d(&[[0f32, 0.], [1., 1.], [10., 10.], [11., 11.]])?;
+	/// let responses = Mat::from_slice(&[0i32, 0, 1, 1])?;
+	/// 
+	/// let mut svm = <dyn ml::SVM>::create()?;
+	/// svm.set_type(ml::SVM_Types::C_SVC as i32)?;
+	/// svm.set_kernel(ml::SVM_KernelTypes::LINEAR as i32)?;
+	/// svm.train_with_layout(&samples, ml::SampleTypes::ROW_SAMPLE, &responses)?;
+	/// 
+	/// let sample = Mat::from_slice_2d(&[[10.5f32, 10.5]])?;
+	/// assert_eq!(1., svm.predict(&sample, &mut core::no_array(), 0)?);
+	/// # Ok::<(), opencv::Error>(())
+	/// ```
 	#[inline]
 	pub fn create() -> Result<core::Ptr<dyn crate::ml::SVM>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVM_create(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVM_create(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::ml::SVM>::opencv_from_extern(ret) };
@@ -3387,7 +4025,7 @@ impl dyn SVM + '_ {
 	pub fn load(filepath: &str) -> Result<core::Ptr<dyn crate::ml::SVM>> {
 		extern_container_arg!(filepath);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVM_load_const_StringR(filepath.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVM_load_const_StringR(filepath.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::ml::SVM>::opencv_from_extern(ret) };
@@ -3400,8 +4038,9 @@ pub trait SVM_KernelConst: core::AlgorithmTraitConst {
 
 	#[inline]
 	fn get_type(&self) -> Result<i32> {
+		crate::core::validity::check("SVM_Kernel", self.as_raw_SVM_Kernel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVM_Kernel_getType_const(self.as_raw_SVM_Kernel(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVM_Kernel_getType_const(self.as_raw_SVM_Kernel(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3414,8 +4053,9 @@ pub trait SVM_Kernel: core::AlgorithmTrait + crate::ml::SVM_KernelConst {
 
 	#[inline]
 	fn calc(&mut self, vcount: i32, n: i32, vecs: &f32, another: &f32, results: &mut f32) -> Result<()> {
+		crate::core::validity::check("SVM_Kernel", self.as_raw_mut_SVM_Kernel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVM_Kernel_calc_int_int_const_floatX_const_floatX_floatX(self.as_raw_mut_SVM_Kernel(), vcount, n, vecs, another, results, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVM_Kernel_calc_int_int_const_floatX_const_floatX_floatX(self.as_raw_mut_SVM_Kernel(), vcount, n, vecs, another, results, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3499,11 +4139,12 @@ pub trait SVMSGDConst: crate::ml::StatModelConst {
 
 	/// %Algorithm type, one of SVMSGD::SvmsgdType.
 	/// ## See also
-	/// setSvmsgdType
+	/// `set_svmsgd_type`
 	#[inline]
 	fn get_svmsgd_type(&self) -> Result<i32> {
+		crate::core::validity::check("SVMSGD", self.as_raw_SVMSGD())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVMSGD_getSvmsgdType_const(self.as_raw_SVMSGD(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVMSGD_getSvmsgdType_const(self.as_raw_SVMSGD(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3511,11 +4152,12 @@ pub trait SVMSGDConst: crate::ml::StatModelConst {
 	
 	/// %Margin type, one of SVMSGD::MarginType.
 	/// ## See also
-	/// setMarginType
+	/// `set_margin_type`
 	#[inline]
 	fn get_margin_type(&self) -> Result<i32> {
+		crate::core::validity::check("SVMSGD", self.as_raw_SVMSGD())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVMSGD_getMarginType_const(self.as_raw_SVMSGD(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVMSGD_getMarginType_const(self.as_raw_SVMSGD(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3523,11 +4165,12 @@ pub trait SVMSGDConst: crate::ml::StatModelConst {
 	
 	/// Parameter marginRegularization of a %SVMSGD optimization problem.
 	/// ## See also
-	/// setMarginRegularization
+	/// `set_margin_regularization`
 	#[inline]
 	fn get_margin_regularization(&self) -> Result<f32> {
+		crate::core::validity::check("SVMSGD", self.as_raw_SVMSGD())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVMSGD_getMarginRegularization_const(self.as_raw_SVMSGD(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVMSGD_getMarginRegularization_const(self.as_raw_SVMSGD(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3535,11 +4178,12 @@ pub trait SVMSGDConst: crate::ml::StatModelConst {
 	
 	/// Parameter initialStepSize of a %SVMSGD optimization problem.
 	/// ## See also
-	/// setInitialStepSize
+	/// `set_initial_step_size`
 	#[inline]
 	fn get_initial_step_size(&self) -> Result<f32> {
+		crate::core::validity::check("SVMSGD", self.as_raw_SVMSGD())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVMSGD_getInitialStepSize_const(self.as_raw_SVMSGD(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVMSGD_getInitialStepSize_const(self.as_raw_SVMSGD(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3547,11 +4191,12 @@ pub trait SVMSGDConst: crate::ml::StatModelConst {
 	
 	/// Parameter stepDecreasingPower of a %SVMSGD optimization problem.
 	/// ## See also
-	/// setStepDecreasingPower
+	/// `set_step_decreasing_power`
 	#[inline]
 	fn get_step_decreasing_power(&self) -> Result<f32> {
+		crate::core::validity::check("SVMSGD", self.as_raw_SVMSGD())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVMSGD_getStepDecreasingPower_const(self.as_raw_SVMSGD(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVMSGD_getStepDecreasingPower_const(self.as_raw_SVMSGD(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3561,11 +4206,12 @@ pub trait SVMSGDConst: crate::ml::StatModelConst {
 	///    You can specify the maximum number of iterations (maxCount) and/or how much the error could
 	///    change between the iterations to make the algorithm continue (epsilon).
 	/// ## See also
-	/// setTermCriteria
+	/// `set_term_criteria`
 	#[inline]
 	fn get_term_criteria(&self) -> Result<core::TermCriteria> {
+		crate::core::validity::check("SVMSGD", self.as_raw_SVMSGD())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVMSGD_getTermCriteria_const(self.as_raw_SVMSGD(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVMSGD_getTermCriteria_const(self.as_raw_SVMSGD(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3580,8 +4226,9 @@ pub trait SVMSGD: crate::ml::SVMSGDConst + crate::ml::StatModel {
 	/// the weights of the trained model (decision function f(x) = weights * x + shift).
 	#[inline]
 	fn get_weights(&mut self) -> Result<core::Mat> {
+		crate::core::validity::check("SVMSGD", self.as_raw_mut_SVMSGD())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVMSGD_getWeights(self.as_raw_mut_SVMSGD(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVMSGD_getWeights(self.as_raw_mut_SVMSGD(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -3592,8 +4239,9 @@ pub trait SVMSGD: crate::ml::SVMSGDConst + crate::ml::StatModel {
 	/// the shift of the trained model (decision function f(x) = weights * x + shift).
 	#[inline]
 	fn get_shift(&mut self) -> Result<f32> {
+		crate::core::validity::check("SVMSGD", self.as_raw_mut_SVMSGD())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVMSGD_getShift(self.as_raw_mut_SVMSGD(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVMSGD_getShift(self.as_raw_mut_SVMSGD(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3609,8 +4257,9 @@ pub trait SVMSGD: crate::ml::SVMSGDConst + crate::ml::StatModel {
 	/// * margin_type: SVMSGD::SOFT_MARGIN
 	#[inline]
 	fn set_optimal_parameters(&mut self, svmsgd_type: i32, margin_type: i32) -> Result<()> {
+		crate::core::validity::check("SVMSGD", self.as_raw_mut_SVMSGD())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVMSGD_setOptimalParameters_int_int(self.as_raw_mut_SVMSGD(), svmsgd_type, margin_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVMSGD_setOptimalParameters_int_int(self.as_raw_mut_SVMSGD(), svmsgd_type, margin_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3621,8 +4270,9 @@ pub trait SVMSGD: crate::ml::SVMSGDConst + crate::ml::StatModel {
 	/// setSvmsgdType getSvmsgdType
 	#[inline]
 	fn set_svmsgd_type(&mut self, svmsgd_type: i32) -> Result<()> {
+		crate::core::validity::check("SVMSGD", self.as_raw_mut_SVMSGD())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVMSGD_setSvmsgdType_int(self.as_raw_mut_SVMSGD(), svmsgd_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVMSGD_setSvmsgdType_int(self.as_raw_mut_SVMSGD(), svmsgd_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3633,8 +4283,9 @@ pub trait SVMSGD: crate::ml::SVMSGDConst + crate::ml::StatModel {
 	/// setMarginType getMarginType
 	#[inline]
 	fn set_margin_type(&mut self, margin_type: i32) -> Result<()> {
+		crate::core::validity::check("SVMSGD", self.as_raw_mut_SVMSGD())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVMSGD_setMarginType_int(self.as_raw_mut_SVMSGD(), margin_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVMSGD_setMarginType_int(self.as_raw_mut_SVMSGD(), margin_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3645,8 +4296,9 @@ pub trait SVMSGD: crate::ml::SVMSGDConst + crate::ml::StatModel {
 	/// setMarginRegularization getMarginRegularization
 	#[inline]
 	fn set_margin_regularization(&mut self, margin_regularization: f32) -> Result<()> {
+		crate::core::validity::check("SVMSGD", self.as_raw_mut_SVMSGD())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVMSGD_setMarginRegularization_float(self.as_raw_mut_SVMSGD(), margin_regularization, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVMSGD_setMarginRegularization_float(self.as_raw_mut_SVMSGD(), margin_regularization, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3657,8 +4309,9 @@ pub trait SVMSGD: crate::ml::SVMSGDConst + crate::ml::StatModel {
 	/// setInitialStepSize getInitialStepSize
 	#[inline]
 	fn set_initial_step_size(&mut self, initial_step_size: f32) -> Result<()> {
+		crate::core::validity::check("SVMSGD", self.as_raw_mut_SVMSGD())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVMSGD_setInitialStepSize_float(self.as_raw_mut_SVMSGD(), initial_step_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVMSGD_setInitialStepSize_float(self.as_raw_mut_SVMSGD(), initial_step_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3669,8 +4322,9 @@ pub trait SVMSGD: crate::ml::SVMSGDConst + crate::ml::StatModel {
 	/// setStepDecreasingPower getStepDecreasingPower
 	#[inline]
 	fn set_step_decreasing_power(&mut self, step_decreasing_power: f32) -> Result<()> {
+		crate::core::validity::check("SVMSGD", self.as_raw_mut_SVMSGD())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVMSGD_setStepDecreasingPower_float(self.as_raw_mut_SVMSGD(), step_decreasing_power, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVMSGD_setStepDecreasingPower_float(self.as_raw_mut_SVMSGD(), step_decreasing_power, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3683,8 +4337,9 @@ pub trait SVMSGD: crate::ml::SVMSGDConst + crate::ml::StatModel {
 	/// setTermCriteria getTermCriteria
 	#[inline]
 	fn set_term_criteria(&mut self, val: core::TermCriteria) -> Result<()> {
+		crate::core::validity::check("SVMSGD", self.as_raw_mut_SVMSGD())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVMSGD_setTermCriteria_const_TermCriteriaR(self.as_raw_mut_SVMSGD(), &val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVMSGD_setTermCriteria_const_TermCriteriaR(self.as_raw_mut_SVMSGD(), &val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3699,7 +4354,7 @@ impl dyn SVMSGD + '_ {
 	#[inline]
 	pub fn create() -> Result<core::Ptr<dyn crate::ml::SVMSGD>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVMSGD_create(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVMSGD_create(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::ml::SVMSGD>::opencv_from_extern(ret) };
@@ -3723,7 +4378,7 @@ impl dyn SVMSGD + '_ {
 		extern_container_arg!(filepath);
 		extern_container_arg!(node_name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_SVMSGD_load_const_StringR_const_StringR(filepath.opencv_as_extern(), node_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_SVMSGD_load_const_StringR_const_StringR(filepath.opencv_as_extern(), node_name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::ml::SVMSGD>::opencv_from_extern(ret) };
@@ -3738,8 +4393,9 @@ pub trait StatModelConst: core::AlgorithmTraitConst {
 	/// Returns the number of variables in training samples
 	#[inline]
 	fn get_var_count(&self) -> Result<i32> {
+		crate::core::validity::check("StatModel", self.as_raw_StatModel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_StatModel_getVarCount_const(self.as_raw_StatModel(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_StatModel_getVarCount_const(self.as_raw_StatModel(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3747,8 +4403,9 @@ pub trait StatModelConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn empty(&self) -> Result<bool> {
+		crate::core::validity::check("StatModel", self.as_raw_StatModel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_StatModel_empty_const(self.as_raw_StatModel(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_StatModel_empty_const(self.as_raw_StatModel(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3757,8 +4414,9 @@ pub trait StatModelConst: core::AlgorithmTraitConst {
 	/// Returns true if the model is trained
 	#[inline]
 	fn is_trained(&self) -> Result<bool> {
+		crate::core::validity::check("StatModel", self.as_raw_StatModel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_StatModel_isTrained_const(self.as_raw_StatModel(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_StatModel_isTrained_const(self.as_raw_StatModel(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3767,8 +4425,9 @@ pub trait StatModelConst: core::AlgorithmTraitConst {
 	/// Returns true if the model is classifier
 	#[inline]
 	fn is_classifier(&self) -> Result<bool> {
+		crate::core::validity::check("StatModel", self.as_raw_StatModel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_StatModel_isClassifier_const(self.as_raw_StatModel(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_StatModel_isClassifier_const(self.as_raw_StatModel(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3789,9 +4448,10 @@ pub trait StatModelConst: core::AlgorithmTraitConst {
 	/// computed as RMS, for classifiers - as a percent of missclassified samples (0%-100%).
 	#[inline]
 	fn calc_error(&self, data: &core::Ptr<dyn crate::ml::TrainData>, test: bool, resp: &mut dyn core::ToOutputArray) -> Result<f32> {
+		crate::core::validity::check("StatModel", self.as_raw_StatModel())?;
 		output_array_arg!(resp);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_StatModel_calcError_const_const_Ptr_TrainData_R_bool_const__OutputArrayR(self.as_raw_StatModel(), data.as_raw_PtrOfTrainData(), test, resp.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_StatModel_calcError_const_const_Ptr_TrainData_R_bool_const__OutputArrayR(self.as_raw_StatModel(), data.as_raw_PtrOfTrainData(), test, resp.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3809,10 +4469,11 @@ pub trait StatModelConst: core::AlgorithmTraitConst {
 	/// * flags: 0
 	#[inline]
 	fn predict(&self, samples: &dyn core::ToInputArray, results: &mut dyn core::ToOutputArray, flags: i32) -> Result<f32> {
+		crate::core::validity::check("StatModel", self.as_raw_StatModel())?;
 		input_array_arg!(samples);
 		output_array_arg!(results);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_StatModel_predict_const_const__InputArrayR_const__OutputArrayR_int(self.as_raw_StatModel(), samples.as_raw__InputArray(), results.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_StatModel_predict_const_const__InputArrayR_const__OutputArrayR_int(self.as_raw_StatModel(), samples.as_raw__InputArray(), results.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3835,8 +4496,9 @@ pub trait StatModel: core::AlgorithmTrait + crate::ml::StatModelConst {
 	/// * flags: 0
 	#[inline]
 	fn train_with_data(&mut self, train_data: &core::Ptr<dyn crate::ml::TrainData>, flags: i32) -> Result<bool> {
+		crate::core::validity::check("StatModel", self.as_raw_mut_StatModel())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_StatModel_train_const_Ptr_TrainData_R_int(self.as_raw_mut_StatModel(), train_data.as_raw_PtrOfTrainData(), flags, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_StatModel_train_const_Ptr_TrainData_R_int(self.as_raw_mut_StatModel(), train_data.as_raw_PtrOfTrainData(), flags, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3850,10 +4512,11 @@ pub trait StatModel: core::AlgorithmTrait + crate::ml::StatModelConst {
 	/// * responses: vector of responses associated with the training samples.
 	#[inline]
 	fn train(&mut self, samples: &dyn core::ToInputArray, layout: i32, responses: &dyn core::ToInputArray) -> Result<bool> {
+		crate::core::validity::check("StatModel", self.as_raw_mut_StatModel())?;
 		input_array_arg!(samples);
 		input_array_arg!(responses);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_StatModel_train_const__InputArrayR_int_const__InputArrayR(self.as_raw_mut_StatModel(), samples.as_raw__InputArray(), layout, responses.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_StatModel_train_const__InputArrayR_int_const__InputArrayR(self.as_raw_mut_StatModel(), samples.as_raw__InputArray(), layout, responses.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3868,14 +4531,15 @@ pub trait StatModel: core::AlgorithmTrait + crate::ml::StatModelConst {
 /// words, you can create your own class derived from TrainData and pass smart pointer to the instance
 /// of this class into StatModel::train.
 /// ## See also
-/// @ref ml_intro_data
+/// ml_intro_data
 pub trait TrainDataConst {
 	fn as_raw_TrainData(&self) -> *const c_void;
 
 	#[inline]
 	fn get_layout(&self) -> Result<i32> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getLayout_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getLayout_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3883,8 +4547,9 @@ pub trait TrainDataConst {
 	
 	#[inline]
 	fn get_n_train_samples(&self) -> Result<i32> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getNTrainSamples_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getNTrainSamples_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3892,8 +4557,9 @@ pub trait TrainDataConst {
 	
 	#[inline]
 	fn get_n_test_samples(&self) -> Result<i32> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getNTestSamples_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getNTestSamples_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3901,8 +4567,9 @@ pub trait TrainDataConst {
 	
 	#[inline]
 	fn get_n_samples(&self) -> Result<i32> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getNSamples_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getNSamples_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3910,8 +4577,9 @@ pub trait TrainDataConst {
 	
 	#[inline]
 	fn get_n_vars(&self) -> Result<i32> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getNVars_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getNVars_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3919,8 +4587,9 @@ pub trait TrainDataConst {
 	
 	#[inline]
 	fn get_n_all_vars(&self) -> Result<i32> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getNAllVars_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getNAllVars_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3928,9 +4597,10 @@ pub trait TrainDataConst {
 	
 	#[inline]
 	fn get_sample(&self, var_idx: &dyn core::ToInputArray, sidx: i32, buf: &mut f32) -> Result<()> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		input_array_arg!(var_idx);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getSample_const_const__InputArrayR_int_floatX(self.as_raw_TrainData(), var_idx.as_raw__InputArray(), sidx, buf, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getSample_const_const__InputArrayR_int_floatX(self.as_raw_TrainData(), var_idx.as_raw__InputArray(), sidx, buf, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3938,8 +4608,9 @@ pub trait TrainDataConst {
 	
 	#[inline]
 	fn get_samples(&self) -> Result<core::Mat> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getSamples_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getSamples_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -3948,8 +4619,9 @@ pub trait TrainDataConst {
 	
 	#[inline]
 	fn get_missing(&self) -> Result<core::Mat> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getMissing_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getMissing_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -3975,8 +4647,9 @@ pub trait TrainDataConst {
 	/// * compress_vars: true
 	#[inline]
 	fn get_train_samples(&self, layout: i32, compress_samples: bool, compress_vars: bool) -> Result<core::Mat> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getTrainSamples_const_int_bool_bool(self.as_raw_TrainData(), layout, compress_samples, compress_vars, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getTrainSamples_const_int_bool_bool(self.as_raw_TrainData(), layout, compress_samples, compress_vars, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -3989,8 +4662,9 @@ pub trait TrainDataConst {
 	/// regression algorithms.
 	#[inline]
 	fn get_train_responses(&self) -> Result<core::Mat> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getTrainResponses_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getTrainResponses_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -4004,8 +4678,9 @@ pub trait TrainDataConst {
 	/// TrainData::getClassLabels.
 	#[inline]
 	fn get_train_norm_cat_responses(&self) -> Result<core::Mat> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getTrainNormCatResponses_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getTrainNormCatResponses_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -4014,8 +4689,9 @@ pub trait TrainDataConst {
 	
 	#[inline]
 	fn get_test_responses(&self) -> Result<core::Mat> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getTestResponses_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getTestResponses_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -4024,8 +4700,9 @@ pub trait TrainDataConst {
 	
 	#[inline]
 	fn get_test_norm_cat_responses(&self) -> Result<core::Mat> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getTestNormCatResponses_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getTestNormCatResponses_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -4034,8 +4711,9 @@ pub trait TrainDataConst {
 	
 	#[inline]
 	fn get_responses(&self) -> Result<core::Mat> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getResponses_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getResponses_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -4044,8 +4722,9 @@ pub trait TrainDataConst {
 	
 	#[inline]
 	fn get_norm_cat_responses(&self) -> Result<core::Mat> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getNormCatResponses_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getNormCatResponses_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -4054,8 +4733,9 @@ pub trait TrainDataConst {
 	
 	#[inline]
 	fn get_sample_weights(&self) -> Result<core::Mat> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getSampleWeights_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getSampleWeights_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -4064,8 +4744,9 @@ pub trait TrainDataConst {
 	
 	#[inline]
 	fn get_train_sample_weights(&self) -> Result<core::Mat> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getTrainSampleWeights_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getTrainSampleWeights_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -4074,8 +4755,9 @@ pub trait TrainDataConst {
 	
 	#[inline]
 	fn get_test_sample_weights(&self) -> Result<core::Mat> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getTestSampleWeights_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getTestSampleWeights_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -4084,8 +4766,9 @@ pub trait TrainDataConst {
 	
 	#[inline]
 	fn get_var_idx(&self) -> Result<core::Mat> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getVarIdx_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getVarIdx_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -4094,8 +4777,9 @@ pub trait TrainDataConst {
 	
 	#[inline]
 	fn get_var_type(&self) -> Result<core::Mat> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getVarType_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getVarType_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -4104,8 +4788,9 @@ pub trait TrainDataConst {
 	
 	#[inline]
 	fn get_var_symbol_flags(&self) -> Result<core::Mat> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getVarSymbolFlags_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getVarSymbolFlags_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -4114,8 +4799,9 @@ pub trait TrainDataConst {
 	
 	#[inline]
 	fn get_response_type(&self) -> Result<i32> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getResponseType_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getResponseType_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4123,8 +4809,9 @@ pub trait TrainDataConst {
 	
 	#[inline]
 	fn get_train_sample_idx(&self) -> Result<core::Mat> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getTrainSampleIdx_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getTrainSampleIdx_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -4133,8 +4820,9 @@ pub trait TrainDataConst {
 	
 	#[inline]
 	fn get_test_sample_idx(&self) -> Result<core::Mat> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getTestSampleIdx_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getTestSampleIdx_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -4143,9 +4831,10 @@ pub trait TrainDataConst {
 	
 	#[inline]
 	fn get_values(&self, vi: i32, sidx: &dyn core::ToInputArray, values: &mut f32) -> Result<()> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		input_array_arg!(sidx);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getValues_const_int_const__InputArrayR_floatX(self.as_raw_TrainData(), vi, sidx.as_raw__InputArray(), values, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getValues_const_int_const__InputArrayR_floatX(self.as_raw_TrainData(), vi, sidx.as_raw__InputArray(), values, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4153,9 +4842,10 @@ pub trait TrainDataConst {
 	
 	#[inline]
 	fn get_norm_cat_values(&self, vi: i32, sidx: &dyn core::ToInputArray, values: &mut i32) -> Result<()> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		input_array_arg!(sidx);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getNormCatValues_const_int_const__InputArrayR_intX(self.as_raw_TrainData(), vi, sidx.as_raw__InputArray(), values, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getNormCatValues_const_int_const__InputArrayR_intX(self.as_raw_TrainData(), vi, sidx.as_raw__InputArray(), values, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4163,8 +4853,9 @@ pub trait TrainDataConst {
 	
 	#[inline]
 	fn get_default_subst_values(&self) -> Result<core::Mat> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getDefaultSubstValues_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getDefaultSubstValues_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -4173,8 +4864,9 @@ pub trait TrainDataConst {
 	
 	#[inline]
 	fn get_cat_count(&self, vi: i32) -> Result<i32> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getCatCount_const_int(self.as_raw_TrainData(), vi, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getCatCount_const_int(self.as_raw_TrainData(), vi, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4185,8 +4877,9 @@ pub trait TrainDataConst {
 	/// The function returns vector of unique labels occurred in the responses.
 	#[inline]
 	fn get_class_labels(&self) -> Result<core::Mat> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getClassLabels_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getClassLabels_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -4195,8 +4888,9 @@ pub trait TrainDataConst {
 	
 	#[inline]
 	fn get_cat_ofs(&self) -> Result<core::Mat> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getCatOfs_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getCatOfs_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -4205,8 +4899,9 @@ pub trait TrainDataConst {
 	
 	#[inline]
 	fn get_cat_map(&self) -> Result<core::Mat> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getCatMap_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getCatMap_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -4216,8 +4911,9 @@ pub trait TrainDataConst {
 	/// Returns matrix of test samples
 	#[inline]
 	fn get_test_samples(&self) -> Result<core::Mat> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getTestSamples_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getTestSamples_const(self.as_raw_TrainData(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -4227,8 +4923,9 @@ pub trait TrainDataConst {
 	/// Returns vector of symbolic names captured in loadFromCSV()
 	#[inline]
 	fn get_names(&self, names: &mut core::Vector<String>) -> Result<()> {
+		crate::core::validity::check("TrainData", self.as_raw_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getNames_const_vector_String_R(self.as_raw_TrainData(), names.as_raw_mut_VectorOfString(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getNames_const_vector_String_R(self.as_raw_TrainData(), names.as_raw_mut_VectorOfString(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4236,19 +4933,57 @@ pub trait TrainDataConst {
 	
 }
 
+/// Builder for `TrainDataConst::get_train_samples()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct TrainData_GetTrainSamplesBuilder<'a, T: ?Sized + TrainDataConst> {
+	instance: &'a T,
+	layout: i32,
+	compress_samples: bool,
+	compress_vars: bool,
+}
+
+impl<'a, T: ?Sized + TrainDataConst> TrainData_GetTrainSamplesBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a T, layout: i32) -> Self {
+		Self { instance, layout, compress_samples: true, compress_vars: true }
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_compress_samples(mut self, compress_samples: bool) -> Self {
+		self.compress_samples = compress_samples;
+		self
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_compress_vars(mut self, compress_vars: bool) -> Self {
+		self.compress_vars = compress_vars;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Mat> {
+		TrainDataConst::get_train_samples(self.instance, self.layout, self.compress_samples, self.compress_vars)
+	}
+}
+
 pub trait TrainData: crate::ml::TrainDataConst {
 	fn as_raw_mut_TrainData(&mut self) -> *mut c_void;
 
 	/// Splits the training data into the training and test parts
 	/// ## See also
-	/// TrainData::setTrainTestSplitRatio
+	/// [TrainData::set_train_test_split_ratio](crate::ml::TrainData)
 	/// 
 	/// ## C++ default parameters
 	/// * shuffle: true
 	#[inline]
 	fn set_train_test_split(&mut self, count: i32, shuffle: bool) -> Result<()> {
+		crate::core::validity::check("TrainData", self.as_raw_mut_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_setTrainTestSplit_int_bool(self.as_raw_mut_TrainData(), count, shuffle, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_setTrainTestSplit_int_bool(self.as_raw_mut_TrainData(), count, shuffle, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4261,14 +4996,15 @@ pub trait TrainData: crate::ml::TrainDataConst {
 	/// each of TrainData::getTrain\* there is corresponding TrainData::getTest\*, so that the test
 	/// subset can be retrieved and processed as well.
 	/// ## See also
-	/// TrainData::setTrainTestSplit
+	/// [TrainData::set_train_test_split](crate::ml::TrainData)
 	/// 
 	/// ## C++ default parameters
 	/// * shuffle: true
 	#[inline]
 	fn set_train_test_split_ratio(&mut self, ratio: f64, shuffle: bool) -> Result<()> {
+		crate::core::validity::check("TrainData", self.as_raw_mut_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_setTrainTestSplitRatio_double_bool(self.as_raw_mut_TrainData(), ratio, shuffle, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_setTrainTestSplitRatio_double_bool(self.as_raw_mut_TrainData(), ratio, shuffle, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4276,8 +5012,9 @@ pub trait TrainData: crate::ml::TrainDataConst {
 	
 	#[inline]
 	fn shuffle_train_test(&mut self) -> Result<()> {
+		crate::core::validity::check("TrainData", self.as_raw_mut_TrainData())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_shuffleTrainTest(self.as_raw_mut_TrainData(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_shuffleTrainTest(self.as_raw_mut_TrainData(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4289,7 +5026,7 @@ impl dyn TrainData + '_ {
 	#[inline]
 	pub fn missing_value() -> Result<f32> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_missingValue(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_missingValue(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4302,7 +5039,7 @@ impl dyn TrainData + '_ {
 	#[inline]
 	pub fn get_sub_vector(vec: &core::Mat, idx: &core::Mat) -> Result<core::Mat> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getSubVector_const_MatR_const_MatR(vec.as_raw_Mat(), idx.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getSubVector_const_MatR_const_MatR(vec.as_raw_Mat(), idx.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -4317,7 +5054,7 @@ impl dyn TrainData + '_ {
 	#[inline]
 	pub fn get_sub_matrix(matrix: &core::Mat, idx: &core::Mat, layout: i32) -> Result<core::Mat> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_getSubMatrix_const_MatR_const_MatR_int(matrix.as_raw_Mat(), idx.as_raw_Mat(), layout, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_getSubMatrix_const_MatR_const_MatR_int(matrix.as_raw_Mat(), idx.as_raw_Mat(), layout, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -4366,7 +5103,7 @@ impl dyn TrainData + '_ {
 		extern_container_arg!(filename);
 		extern_container_arg!(var_type_spec);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_loadFromCSV_const_StringR_int_int_int_const_StringR_char_char(filename.opencv_as_extern(), header_line_count, response_start_idx, response_end_idx, var_type_spec.opencv_as_extern(), delimiter, missch, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_loadFromCSV_const_StringR_int_int_int_const_StringR_char_char(filename.opencv_as_extern(), header_line_count, response_start_idx, response_end_idx, var_type_spec.opencv_as_extern(), delimiter, missch, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::ml::TrainData>::opencv_from_extern(ret) };
@@ -4398,6 +5135,19 @@ impl dyn TrainData + '_ {
 	/// * sample_idx: noArray()
 	/// * sample_weights: noArray()
 	/// * var_type: noArray()
+	/// 
+	/// ## Example
+	/// ```
+	/// use opencv::{core::Mat, ml, prelude::*};
+	/// 
+	/// let samples = Mat::from_slice_2d(&[[0f32, 0., 0.], [1., 1., 1.], [10., 10., 10.], [11., 11., 11.]])?;
+	/// let responses = Mat::from_slice(&[0i32, 0, 1, 1])?;
+	/// 
+	/// let data = ml::TrainData_CreateBuilder::new(&samples, ml::SampleTypes::ROW_SAMPLE as i32, &responses).call()?;
+	/// assert_eq!(4, data.get_n_samples()?);
+	/// assert_eq!(3, data.get_n_vars()?);
+	/// # Ok::<(), opencv::Error>(())
+	/// ```
 	#[inline]
 	pub fn create(samples: &dyn core::ToInputArray, layout: i32, responses: &dyn core::ToInputArray, var_idx: &dyn core::ToInputArray, sample_idx: &dyn core::ToInputArray, sample_weights: &dyn core::ToInputArray, var_type: &dyn core::ToInputArray) -> Result<core::Ptr<dyn crate::ml::TrainData>> {
 		input_array_arg!(samples);
@@ -4407,11 +5157,135 @@ impl dyn TrainData + '_ {
 		input_array_arg!(sample_weights);
 		input_array_arg!(var_type);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ml_TrainData_create_const__InputArrayR_int_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR(samples.as_raw__InputArray(), layout, responses.as_raw__InputArray(), var_idx.as_raw__InputArray(), sample_idx.as_raw__InputArray(), sample_weights.as_raw__InputArray(), var_type.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ml_TrainData_create_const__InputArrayR_int_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR(samples.as_raw__InputArray(), layout, responses.as_raw__InputArray(), var_idx.as_raw__InputArray(), sample_idx.as_raw__InputArray(), sample_weights.as_raw__InputArray(), var_type.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::ml::TrainData>::opencv_from_extern(ret) };
 		Ok(ret)
 	}
 	
-}
\ No newline at end of file
+}
+
+/// Builder for `<dyn TrainData>::load_from_csv()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct TrainData_LoadFromCsvBuilder<'a> {
+	filename: &'a str,
+	header_line_count: i32,
+	response_start_idx: i32,
+	response_end_idx: i32,
+	var_type_spec: &'a str,
+	delimiter: i8,
+	missch: i8,
+}
+
+impl<'a> TrainData_LoadFromCsvBuilder<'a> {
+	#[inline]
+	pub fn new(filename: &'a str, header_line_count: i32) -> Self {
+		Self { filename, header_line_count, response_start_idx: -1, response_end_idx: -1, var_type_spec: "", delimiter: b',' as i8, missch: b'?' as i8 }
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_response_start_idx(mut self, response_start_idx: i32) -> Self {
+		self.response_start_idx = response_start_idx;
+		self
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_response_end_idx(mut self, response_end_idx: i32) -> Self {
+		self.response_end_idx = response_end_idx;
+		self
+	}
+	
+	/// C++ default: `String()`
+	#[inline]
+	pub fn with_var_type_spec(mut self, var_type_spec: &'a str) -> Self {
+		self.var_type_spec = var_type_spec;
+		self
+	}
+	
+	/// C++ default: `','`
+	#[inline]
+	pub fn with_delimiter(mut self, delimiter: i8) -> Self {
+		self.delimiter = delimiter;
+		self
+	}
+	
+	/// C++ default: `'?'`
+	#[inline]
+	pub fn with_missch(mut self, missch: i8) -> Self {
+		self.missch = missch;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::ml::TrainData>> {
+		<dyn TrainData>::load_from_csv(self.filename, self.header_line_count, self.response_start_idx, self.response_end_idx, self.var_type_spec, self.delimiter, self.missch)
+	}
+}
+
+/// Builder for `<dyn TrainData>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct TrainData_CreateBuilder<'a> {
+	samples: &'a dyn core::ToInputArray,
+	layout: i32,
+	responses: &'a dyn core::ToInputArray,
+	var_idx: Option<&'a dyn core::ToInputArray>,
+	sample_idx: Option<&'a dyn core::ToInputArray>,
+	sample_weights: Option<&'a dyn core::ToInputArray>,
+	var_type: Option<&'a dyn core::ToInputArray>,
+}
+
+impl<'a> TrainData_CreateBuilder<'a> {
+	#[inline]
+	pub fn new(samples: &'a dyn core::ToInputArray, layout: i32, responses: &'a dyn core::ToInputArray) -> Self {
+		Self { samples, layout, responses, var_idx: None, sample_idx: None, sample_weights: None, var_type: None }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_var_idx(mut self, var_idx: &'a dyn core::ToInputArray) -> Self {
+		self.var_idx = Some(var_idx);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_sample_idx(mut self, sample_idx: &'a dyn core::ToInputArray) -> Self {
+		self.sample_idx = Some(sample_idx);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_sample_weights(mut self, sample_weights: &'a dyn core::ToInputArray) -> Self {
+		self.sample_weights = Some(sample_weights);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_var_type(mut self, var_type: &'a dyn core::ToInputArray) -> Self {
+		self.var_type = Some(var_type);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::ml::TrainData>> {
+		let var_idx_no_array = core::no_array();
+		let var_idx: &dyn core::ToInputArray = match self.var_idx { Some(var_idx) => var_idx, None => &var_idx_no_array };
+		let sample_idx_no_array = core::no_array();
+		let sample_idx: &dyn core::ToInputArray = match self.sample_idx { Some(sample_idx) => sample_idx, None => &sample_idx_no_array };
+		let sample_weights_no_array = core::no_array();
+		let sample_weights: &dyn core::ToInputArray = match self.sample_weights { Some(sample_weights) => sample_weights, None => &sample_weights_no_array };
+		let var_type_no_array = core::no_array();
+		let var_type: &dyn core::ToInputArray = match self.var_type { Some(var_type) => var_type, None => &var_type_no_array };
+		<dyn TrainData>::create(self.samples, self.layout, self.responses, var_idx, sample_idx, sample_weights, var_type)
+	}
+}
+pub use crate::manual::ml::*;
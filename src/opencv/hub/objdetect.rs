@@ -58,7 +58,7 @@
 //! 
 //! 
 //! Note: In the new C++ interface it is also possible to use LBP (local binary pattern) features in
-//! addition to Haar-like features. .. [Viola01] Paul Viola and Michael J. Jones. Rapid Object Detection
+//! addition to Haar-like features. .. \[Viola01\] Paul Viola and Michael J. Jones. Rapid Object Detection
 //! using a Boosted Cascade of Simple Features. IEEE CVPR, 2001. The paper is available online at
 //! <http://research.microsoft.com/en-us/um/people/viola/Pubs/Detect/violaJones_CVPR2001.pdf>
 //!    # C API
@@ -148,7 +148,7 @@ opencv_type_enum! { crate::objdetect::QRCodeEncoder_EncodeMode }
 #[inline]
 pub fn create_face_detection_mask_generator() -> Result<core::Ptr<dyn crate::objdetect::BaseCascadeClassifier_MaskGenerator>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_createFaceDetectionMaskGenerator(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_createFaceDetectionMaskGenerator(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::objdetect::BaseCascadeClassifier_MaskGenerator>::opencv_from_extern(ret) };
@@ -161,7 +161,7 @@ pub fn create_face_detection_mask_generator() -> Result<core::Ptr<dyn crate::obj
 #[inline]
 pub fn group_rectangles_meanshift(rect_list: &mut core::Vector<core::Rect>, found_weights: &mut core::Vector<f64>, found_scales: &mut core::Vector<f64>, detect_threshold: f64, win_det_size: core::Size) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_groupRectangles_meanshift_vector_Rect_R_vector_double_R_vector_double_R_double_Size(rect_list.as_raw_mut_VectorOfRect(), found_weights.as_raw_mut_VectorOff64(), found_scales.as_raw_mut_VectorOff64(), detect_threshold, win_det_size.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_groupRectangles_meanshift_vector_Rect_R_vector_double_R_vector_double_R_double_Size(rect_list.as_raw_mut_VectorOfRect(), found_weights.as_raw_mut_VectorOff64(), found_scales.as_raw_mut_VectorOff64(), detect_threshold, win_det_size.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -188,7 +188,7 @@ pub fn group_rectangles_meanshift(rect_list: &mut core::Vector<core::Rect>, foun
 #[inline]
 pub fn group_rectangles(rect_list: &mut core::Vector<core::Rect>, group_threshold: i32, eps: f64) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_groupRectangles_vector_Rect_R_int_double(rect_list.as_raw_mut_VectorOfRect(), group_threshold, eps, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_groupRectangles_vector_Rect_R_int_double(rect_list.as_raw_mut_VectorOfRect(), group_threshold, eps, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -214,7 +214,7 @@ pub fn group_rectangles(rect_list: &mut core::Vector<core::Rect>, group_threshol
 #[inline]
 pub fn group_rectangles_levelweights(rect_list: &mut core::Vector<core::Rect>, group_threshold: i32, eps: f64, weights: &mut core::Vector<i32>, level_weights: &mut core::Vector<f64>) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_groupRectangles_vector_Rect_R_int_double_vector_int_X_vector_double_X(rect_list.as_raw_mut_VectorOfRect(), group_threshold, eps, weights.as_raw_mut_VectorOfi32(), level_weights.as_raw_mut_VectorOff64(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_groupRectangles_vector_Rect_R_int_double_vector_int_X_vector_double_X(rect_list.as_raw_mut_VectorOfRect(), group_threshold, eps, weights.as_raw_mut_VectorOfi32(), level_weights.as_raw_mut_VectorOff64(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -243,7 +243,7 @@ pub fn group_rectangles_levelweights(rect_list: &mut core::Vector<core::Rect>, g
 #[inline]
 pub fn group_rectangles_weights(rect_list: &mut core::Vector<core::Rect>, weights: &mut core::Vector<i32>, group_threshold: i32, eps: f64) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_groupRectangles_vector_Rect_R_vector_int_R_int_double(rect_list.as_raw_mut_VectorOfRect(), weights.as_raw_mut_VectorOfi32(), group_threshold, eps, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_groupRectangles_vector_Rect_R_vector_int_R_int_double(rect_list.as_raw_mut_VectorOfRect(), weights.as_raw_mut_VectorOfi32(), group_threshold, eps, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -272,7 +272,7 @@ pub fn group_rectangles_weights(rect_list: &mut core::Vector<core::Rect>, weight
 #[inline]
 pub fn group_rectangles_levels(rect_list: &mut core::Vector<core::Rect>, reject_levels: &mut core::Vector<i32>, level_weights: &mut core::Vector<f64>, group_threshold: i32, eps: f64) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_groupRectangles_vector_Rect_R_vector_int_R_vector_double_R_int_double(rect_list.as_raw_mut_VectorOfRect(), reject_levels.as_raw_mut_VectorOfi32(), level_weights.as_raw_mut_VectorOff64(), group_threshold, eps, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_groupRectangles_vector_Rect_R_vector_int_R_vector_double_R_int_double(rect_list.as_raw_mut_VectorOfRect(), reject_levels.as_raw_mut_VectorOfi32(), level_weights.as_raw_mut_VectorOff64(), group_threshold, eps, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -283,8 +283,9 @@ pub trait BaseCascadeClassifierConst: core::AlgorithmTraitConst {
 
 	#[inline]
 	fn empty(&self) -> Result<bool> {
+		crate::core::validity::check("BaseCascadeClassifier", self.as_raw_BaseCascadeClassifier())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BaseCascadeClassifier_empty_const(self.as_raw_BaseCascadeClassifier(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BaseCascadeClassifier_empty_const(self.as_raw_BaseCascadeClassifier(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -292,8 +293,9 @@ pub trait BaseCascadeClassifierConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn is_old_format_cascade(&self) -> Result<bool> {
+		crate::core::validity::check("BaseCascadeClassifier", self.as_raw_BaseCascadeClassifier())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BaseCascadeClassifier_isOldFormatCascade_const(self.as_raw_BaseCascadeClassifier(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BaseCascadeClassifier_isOldFormatCascade_const(self.as_raw_BaseCascadeClassifier(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -301,8 +303,9 @@ pub trait BaseCascadeClassifierConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_original_window_size(&self) -> Result<core::Size> {
+		crate::core::validity::check("BaseCascadeClassifier", self.as_raw_BaseCascadeClassifier())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BaseCascadeClassifier_getOriginalWindowSize_const(self.as_raw_BaseCascadeClassifier(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BaseCascadeClassifier_getOriginalWindowSize_const(self.as_raw_BaseCascadeClassifier(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -310,8 +313,9 @@ pub trait BaseCascadeClassifierConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_feature_type(&self) -> Result<i32> {
+		crate::core::validity::check("BaseCascadeClassifier", self.as_raw_BaseCascadeClassifier())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BaseCascadeClassifier_getFeatureType_const(self.as_raw_BaseCascadeClassifier(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BaseCascadeClassifier_getFeatureType_const(self.as_raw_BaseCascadeClassifier(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -324,9 +328,10 @@ pub trait BaseCascadeClassifier: core::AlgorithmTrait + crate::objdetect::BaseCa
 
 	#[inline]
 	fn load(&mut self, filename: &str) -> Result<bool> {
+		crate::core::validity::check("BaseCascadeClassifier", self.as_raw_mut_BaseCascadeClassifier())?;
 		extern_container_arg!(filename);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BaseCascadeClassifier_load_const_StringR(self.as_raw_mut_BaseCascadeClassifier(), filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BaseCascadeClassifier_load_const_StringR(self.as_raw_mut_BaseCascadeClassifier(), filename.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -334,9 +339,10 @@ pub trait BaseCascadeClassifier: core::AlgorithmTrait + crate::objdetect::BaseCa
 	
 	#[inline]
 	fn detect_multi_scale(&mut self, image: &dyn core::ToInputArray, objects: &mut core::Vector<core::Rect>, scale_factor: f64, min_neighbors: i32, flags: i32, min_size: core::Size, max_size: core::Size) -> Result<()> {
+		crate::core::validity::check("BaseCascadeClassifier", self.as_raw_mut_BaseCascadeClassifier())?;
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BaseCascadeClassifier_detectMultiScale_const__InputArrayR_vector_Rect_R_double_int_int_Size_Size(self.as_raw_mut_BaseCascadeClassifier(), image.as_raw__InputArray(), objects.as_raw_mut_VectorOfRect(), scale_factor, min_neighbors, flags, min_size.opencv_as_extern(), max_size.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BaseCascadeClassifier_detectMultiScale_const__InputArrayR_vector_Rect_R_double_int_int_Size_Size(self.as_raw_mut_BaseCascadeClassifier(), image.as_raw__InputArray(), objects.as_raw_mut_VectorOfRect(), scale_factor, min_neighbors, flags, min_size.opencv_as_extern(), max_size.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -344,9 +350,10 @@ pub trait BaseCascadeClassifier: core::AlgorithmTrait + crate::objdetect::BaseCa
 	
 	#[inline]
 	fn detect_multi_scale_num(&mut self, image: &dyn core::ToInputArray, objects: &mut core::Vector<core::Rect>, num_detections: &mut core::Vector<i32>, scale_factor: f64, min_neighbors: i32, flags: i32, min_size: core::Size, max_size: core::Size) -> Result<()> {
+		crate::core::validity::check("BaseCascadeClassifier", self.as_raw_mut_BaseCascadeClassifier())?;
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BaseCascadeClassifier_detectMultiScale_const__InputArrayR_vector_Rect_R_vector_int_R_double_int_int_Size_Size(self.as_raw_mut_BaseCascadeClassifier(), image.as_raw__InputArray(), objects.as_raw_mut_VectorOfRect(), num_detections.as_raw_mut_VectorOfi32(), scale_factor, min_neighbors, flags, min_size.opencv_as_extern(), max_size.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BaseCascadeClassifier_detectMultiScale_const__InputArrayR_vector_Rect_R_vector_int_R_double_int_int_Size_Size(self.as_raw_mut_BaseCascadeClassifier(), image.as_raw__InputArray(), objects.as_raw_mut_VectorOfRect(), num_detections.as_raw_mut_VectorOfi32(), scale_factor, min_neighbors, flags, min_size.opencv_as_extern(), max_size.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -354,9 +361,10 @@ pub trait BaseCascadeClassifier: core::AlgorithmTrait + crate::objdetect::BaseCa
 	
 	#[inline]
 	fn detect_multi_scale_levels(&mut self, image: &dyn core::ToInputArray, objects: &mut core::Vector<core::Rect>, reject_levels: &mut core::Vector<i32>, level_weights: &mut core::Vector<f64>, scale_factor: f64, min_neighbors: i32, flags: i32, min_size: core::Size, max_size: core::Size, output_reject_levels: bool) -> Result<()> {
+		crate::core::validity::check("BaseCascadeClassifier", self.as_raw_mut_BaseCascadeClassifier())?;
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BaseCascadeClassifier_detectMultiScale_const__InputArrayR_vector_Rect_R_vector_int_R_vector_double_R_double_int_int_Size_Size_bool(self.as_raw_mut_BaseCascadeClassifier(), image.as_raw__InputArray(), objects.as_raw_mut_VectorOfRect(), reject_levels.as_raw_mut_VectorOfi32(), level_weights.as_raw_mut_VectorOff64(), scale_factor, min_neighbors, flags, min_size.opencv_as_extern(), max_size.opencv_as_extern(), output_reject_levels, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BaseCascadeClassifier_detectMultiScale_const__InputArrayR_vector_Rect_R_vector_int_R_vector_double_R_double_int_int_Size_Size_bool(self.as_raw_mut_BaseCascadeClassifier(), image.as_raw__InputArray(), objects.as_raw_mut_VectorOfRect(), reject_levels.as_raw_mut_VectorOfi32(), level_weights.as_raw_mut_VectorOff64(), scale_factor, min_neighbors, flags, min_size.opencv_as_extern(), max_size.opencv_as_extern(), output_reject_levels, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -364,8 +372,9 @@ pub trait BaseCascadeClassifier: core::AlgorithmTrait + crate::objdetect::BaseCa
 	
 	#[inline]
 	fn get_old_cascade(&mut self) -> Result<*mut c_void> {
+		crate::core::validity::check("BaseCascadeClassifier", self.as_raw_mut_BaseCascadeClassifier())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BaseCascadeClassifier_getOldCascade(self.as_raw_mut_BaseCascadeClassifier(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BaseCascadeClassifier_getOldCascade(self.as_raw_mut_BaseCascadeClassifier(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -373,8 +382,9 @@ pub trait BaseCascadeClassifier: core::AlgorithmTrait + crate::objdetect::BaseCa
 	
 	#[inline]
 	fn set_mask_generator(&mut self, mask_generator: &core::Ptr<dyn crate::objdetect::BaseCascadeClassifier_MaskGenerator>) -> Result<()> {
+		crate::core::validity::check("BaseCascadeClassifier", self.as_raw_mut_BaseCascadeClassifier())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BaseCascadeClassifier_setMaskGenerator_const_Ptr_MaskGenerator_R(self.as_raw_mut_BaseCascadeClassifier(), mask_generator.as_raw_PtrOfBaseCascadeClassifier_MaskGenerator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BaseCascadeClassifier_setMaskGenerator_const_Ptr_MaskGenerator_R(self.as_raw_mut_BaseCascadeClassifier(), mask_generator.as_raw_PtrOfBaseCascadeClassifier_MaskGenerator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -382,8 +392,9 @@ pub trait BaseCascadeClassifier: core::AlgorithmTrait + crate::objdetect::BaseCa
 	
 	#[inline]
 	fn get_mask_generator(&mut self) -> Result<core::Ptr<dyn crate::objdetect::BaseCascadeClassifier_MaskGenerator>> {
+		crate::core::validity::check("BaseCascadeClassifier", self.as_raw_mut_BaseCascadeClassifier())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BaseCascadeClassifier_getMaskGenerator(self.as_raw_mut_BaseCascadeClassifier(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BaseCascadeClassifier_getMaskGenerator(self.as_raw_mut_BaseCascadeClassifier(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::objdetect::BaseCascadeClassifier_MaskGenerator>::opencv_from_extern(ret) };
@@ -402,8 +413,9 @@ pub trait BaseCascadeClassifier_MaskGenerator: crate::objdetect::BaseCascadeClas
 
 	#[inline]
 	fn generate_mask(&mut self, src: &core::Mat) -> Result<core::Mat> {
+		crate::core::validity::check("BaseCascadeClassifier_MaskGenerator", self.as_raw_mut_BaseCascadeClassifier_MaskGenerator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BaseCascadeClassifier_MaskGenerator_generateMask_const_MatR(self.as_raw_mut_BaseCascadeClassifier_MaskGenerator(), src.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BaseCascadeClassifier_MaskGenerator_generateMask_const_MatR(self.as_raw_mut_BaseCascadeClassifier_MaskGenerator(), src.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -412,8 +424,9 @@ pub trait BaseCascadeClassifier_MaskGenerator: crate::objdetect::BaseCascadeClas
 	
 	#[inline]
 	fn initialize_mask(&mut self, unnamed: &core::Mat) -> Result<()> {
+		crate::core::validity::check("BaseCascadeClassifier_MaskGenerator", self.as_raw_mut_BaseCascadeClassifier_MaskGenerator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BaseCascadeClassifier_MaskGenerator_initializeMask_const_MatR(self.as_raw_mut_BaseCascadeClassifier_MaskGenerator(), unnamed.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BaseCascadeClassifier_MaskGenerator_initializeMask_const_MatR(self.as_raw_mut_BaseCascadeClassifier_MaskGenerator(), unnamed.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -432,8 +445,9 @@ pub trait CascadeClassifierTraitConst {
 	/// Checks whether the classifier has been loaded.
 	#[inline]
 	fn empty(&self) -> Result<bool> {
+		crate::core::validity::check("CascadeClassifier", self.as_raw_CascadeClassifier())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CascadeClassifier_empty_const(self.as_raw_CascadeClassifier(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CascadeClassifier_empty_const(self.as_raw_CascadeClassifier(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -441,8 +455,9 @@ pub trait CascadeClassifierTraitConst {
 	
 	#[inline]
 	fn is_old_format_cascade(&self) -> Result<bool> {
+		crate::core::validity::check("CascadeClassifier", self.as_raw_CascadeClassifier())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CascadeClassifier_isOldFormatCascade_const(self.as_raw_CascadeClassifier(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CascadeClassifier_isOldFormatCascade_const(self.as_raw_CascadeClassifier(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -450,8 +465,9 @@ pub trait CascadeClassifierTraitConst {
 	
 	#[inline]
 	fn get_original_window_size(&self) -> Result<core::Size> {
+		crate::core::validity::check("CascadeClassifier", self.as_raw_CascadeClassifier())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CascadeClassifier_getOriginalWindowSize_const(self.as_raw_CascadeClassifier(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CascadeClassifier_getOriginalWindowSize_const(self.as_raw_CascadeClassifier(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -459,8 +475,9 @@ pub trait CascadeClassifierTraitConst {
 	
 	#[inline]
 	fn get_feature_type(&self) -> Result<i32> {
+		crate::core::validity::check("CascadeClassifier", self.as_raw_CascadeClassifier())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CascadeClassifier_getFeatureType_const(self.as_raw_CascadeClassifier(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CascadeClassifier_getFeatureType_const(self.as_raw_CascadeClassifier(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -473,14 +490,24 @@ pub trait CascadeClassifierTrait: crate::objdetect::CascadeClassifierTraitConst
 
 	#[inline]
 	fn cc(&mut self) -> core::Ptr<dyn crate::objdetect::BaseCascadeClassifier> {
-		let ret = unsafe { sys::cv_CascadeClassifier_getPropCc(self.as_raw_mut_CascadeClassifier()) };
+		crate::core::validity::debug_check("CascadeClassifier", self.as_raw_mut_CascadeClassifier());
+		let ret = unsafe { ffi_call!(cv_CascadeClassifier_getPropCc(self.as_raw_mut_CascadeClassifier())) };
 		let ret = unsafe { core::Ptr::<dyn crate::objdetect::BaseCascadeClassifier>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [cc](CascadeClassifierTrait::cc), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_cc(&mut self) -> Result<core::Ptr<dyn crate::objdetect::BaseCascadeClassifier>> {
+		crate::core::validity::check("CascadeClassifier", self.as_raw_mut_CascadeClassifier())?;
+		Ok(CascadeClassifierTrait::cc(self))
+	}
+	
 	#[inline]
 	fn set_cc(&mut self, mut val: core::Ptr<dyn crate::objdetect::BaseCascadeClassifier>) {
-		let ret = unsafe { sys::cv_CascadeClassifier_setPropCc_Ptr_BaseCascadeClassifier_(self.as_raw_mut_CascadeClassifier(), val.as_raw_mut_PtrOfBaseCascadeClassifier()) };
+		crate::core::validity::debug_check("CascadeClassifier", self.as_raw_mut_CascadeClassifier());
+		let ret = unsafe { ffi_call!(cv_CascadeClassifier_setPropCc_Ptr_BaseCascadeClassifier_(self.as_raw_mut_CascadeClassifier(), val.as_raw_mut_PtrOfBaseCascadeClassifier())) };
 		ret
 	}
 	
@@ -492,9 +519,10 @@ pub trait CascadeClassifierTrait: crate::objdetect::CascadeClassifierTraitConst
 	/// traincascade application.
 	#[inline]
 	fn load(&mut self, filename: &str) -> Result<bool> {
+		crate::core::validity::check("CascadeClassifier", self.as_raw_mut_CascadeClassifier())?;
 		extern_container_arg!(filename);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CascadeClassifier_load_const_StringR(self.as_raw_mut_CascadeClassifier(), filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CascadeClassifier_load_const_StringR(self.as_raw_mut_CascadeClassifier(), filename.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -506,8 +534,9 @@ pub trait CascadeClassifierTrait: crate::objdetect::CascadeClassifierTraitConst
 	/// Note: The file may contain a new cascade classifier (trained traincascade application) only.
 	#[inline]
 	fn read(&mut self, node: &core::FileNode) -> Result<bool> {
+		crate::core::validity::check("CascadeClassifier", self.as_raw_mut_CascadeClassifier())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CascadeClassifier_read_const_FileNodeR(self.as_raw_mut_CascadeClassifier(), node.as_raw_FileNode(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CascadeClassifier_read_const_FileNodeR(self.as_raw_mut_CascadeClassifier(), node.as_raw_FileNode(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -543,9 +572,10 @@ pub trait CascadeClassifierTrait: crate::objdetect::CascadeClassifierTraitConst
 	/// * max_size: Size()
 	#[inline]
 	fn detect_multi_scale(&mut self, image: &dyn core::ToInputArray, objects: &mut core::Vector<core::Rect>, scale_factor: f64, min_neighbors: i32, flags: i32, min_size: core::Size, max_size: core::Size) -> Result<()> {
+		crate::core::validity::check("CascadeClassifier", self.as_raw_mut_CascadeClassifier())?;
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CascadeClassifier_detectMultiScale_const__InputArrayR_vector_Rect_R_double_int_int_Size_Size(self.as_raw_mut_CascadeClassifier(), image.as_raw__InputArray(), objects.as_raw_mut_VectorOfRect(), scale_factor, min_neighbors, flags, min_size.opencv_as_extern(), max_size.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CascadeClassifier_detectMultiScale_const__InputArrayR_vector_Rect_R_double_int_int_Size_Size(self.as_raw_mut_CascadeClassifier(), image.as_raw__InputArray(), objects.as_raw_mut_VectorOfRect(), scale_factor, min_neighbors, flags, min_size.opencv_as_extern(), max_size.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -597,9 +627,10 @@ pub trait CascadeClassifierTrait: crate::objdetect::CascadeClassifierTraitConst
 	/// * max_size: Size()
 	#[inline]
 	fn detect_multi_scale2(&mut self, image: &dyn core::ToInputArray, objects: &mut core::Vector<core::Rect>, num_detections: &mut core::Vector<i32>, scale_factor: f64, min_neighbors: i32, flags: i32, min_size: core::Size, max_size: core::Size) -> Result<()> {
+		crate::core::validity::check("CascadeClassifier", self.as_raw_mut_CascadeClassifier())?;
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CascadeClassifier_detectMultiScale_const__InputArrayR_vector_Rect_R_vector_int_R_double_int_int_Size_Size(self.as_raw_mut_CascadeClassifier(), image.as_raw__InputArray(), objects.as_raw_mut_VectorOfRect(), num_detections.as_raw_mut_VectorOfi32(), scale_factor, min_neighbors, flags, min_size.opencv_as_extern(), max_size.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CascadeClassifier_detectMultiScale_const__InputArrayR_vector_Rect_R_vector_int_R_double_int_int_Size_Size(self.as_raw_mut_CascadeClassifier(), image.as_raw__InputArray(), objects.as_raw_mut_VectorOfRect(), num_detections.as_raw_mut_VectorOfi32(), scale_factor, min_neighbors, flags, min_size.opencv_as_extern(), max_size.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -655,9 +686,10 @@ pub trait CascadeClassifierTrait: crate::objdetect::CascadeClassifierTraitConst
 	/// * output_reject_levels: false
 	#[inline]
 	fn detect_multi_scale3(&mut self, image: &dyn core::ToInputArray, objects: &mut core::Vector<core::Rect>, reject_levels: &mut core::Vector<i32>, level_weights: &mut core::Vector<f64>, scale_factor: f64, min_neighbors: i32, flags: i32, min_size: core::Size, max_size: core::Size, output_reject_levels: bool) -> Result<()> {
+		crate::core::validity::check("CascadeClassifier", self.as_raw_mut_CascadeClassifier())?;
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CascadeClassifier_detectMultiScale_const__InputArrayR_vector_Rect_R_vector_int_R_vector_double_R_double_int_int_Size_Size_bool(self.as_raw_mut_CascadeClassifier(), image.as_raw__InputArray(), objects.as_raw_mut_VectorOfRect(), reject_levels.as_raw_mut_VectorOfi32(), level_weights.as_raw_mut_VectorOff64(), scale_factor, min_neighbors, flags, min_size.opencv_as_extern(), max_size.opencv_as_extern(), output_reject_levels, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CascadeClassifier_detectMultiScale_const__InputArrayR_vector_Rect_R_vector_int_R_vector_double_R_double_int_int_Size_Size_bool(self.as_raw_mut_CascadeClassifier(), image.as_raw__InputArray(), objects.as_raw_mut_VectorOfRect(), reject_levels.as_raw_mut_VectorOfi32(), level_weights.as_raw_mut_VectorOff64(), scale_factor, min_neighbors, flags, min_size.opencv_as_extern(), max_size.opencv_as_extern(), output_reject_levels, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -665,8 +697,9 @@ pub trait CascadeClassifierTrait: crate::objdetect::CascadeClassifierTraitConst
 	
 	#[inline]
 	fn get_old_cascade(&mut self) -> Result<*mut c_void> {
+		crate::core::validity::check("CascadeClassifier", self.as_raw_mut_CascadeClassifier())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CascadeClassifier_getOldCascade(self.as_raw_mut_CascadeClassifier(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CascadeClassifier_getOldCascade(self.as_raw_mut_CascadeClassifier(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -674,8 +707,9 @@ pub trait CascadeClassifierTrait: crate::objdetect::CascadeClassifierTraitConst
 	
 	#[inline]
 	fn set_mask_generator(&mut self, mask_generator: &core::Ptr<dyn crate::objdetect::BaseCascadeClassifier_MaskGenerator>) -> Result<()> {
+		crate::core::validity::check("CascadeClassifier", self.as_raw_mut_CascadeClassifier())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CascadeClassifier_setMaskGenerator_const_Ptr_MaskGenerator_R(self.as_raw_mut_CascadeClassifier(), mask_generator.as_raw_PtrOfBaseCascadeClassifier_MaskGenerator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CascadeClassifier_setMaskGenerator_const_Ptr_MaskGenerator_R(self.as_raw_mut_CascadeClassifier(), mask_generator.as_raw_PtrOfBaseCascadeClassifier_MaskGenerator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -683,8 +717,9 @@ pub trait CascadeClassifierTrait: crate::objdetect::CascadeClassifierTraitConst
 	
 	#[inline]
 	fn get_mask_generator(&mut self) -> Result<core::Ptr<dyn crate::objdetect::BaseCascadeClassifier_MaskGenerator>> {
+		crate::core::validity::check("CascadeClassifier", self.as_raw_mut_CascadeClassifier())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CascadeClassifier_getMaskGenerator(self.as_raw_mut_CascadeClassifier(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CascadeClassifier_getMaskGenerator(self.as_raw_mut_CascadeClassifier(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::objdetect::BaseCascadeClassifier_MaskGenerator>::opencv_from_extern(ret) };
@@ -693,6 +728,203 @@ pub trait CascadeClassifierTrait: crate::objdetect::CascadeClassifierTraitConst
 	
 }
 
+/// Builder for `CascadeClassifierTrait::detect_multi_scale()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CascadeClassifier_DetectMultiScaleBuilder<'a, T: ?Sized + CascadeClassifierTrait> {
+	instance: &'a mut T,
+	image: &'a dyn core::ToInputArray,
+	objects: &'a mut core::Vector<core::Rect>,
+	scale_factor: f64,
+	min_neighbors: i32,
+	flags: i32,
+	min_size: core::Size,
+	max_size: core::Size,
+}
+
+impl<'a, T: ?Sized + CascadeClassifierTrait> CascadeClassifier_DetectMultiScaleBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, image: &'a dyn core::ToInputArray, objects: &'a mut core::Vector<core::Rect>) -> Self {
+		Self { instance, image, objects, scale_factor: 1.1, min_neighbors: 3, flags: 0, min_size: core::Size::default(), max_size: core::Size::default() }
+	}
+	
+	/// C++ default: `1.1`
+	#[inline]
+	pub fn with_scale_factor(mut self, scale_factor: f64) -> Self {
+		self.scale_factor = scale_factor;
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_min_neighbors(mut self, min_neighbors: i32) -> Self {
+		self.min_neighbors = min_neighbors;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_flags(mut self, flags: i32) -> Self {
+		self.flags = flags;
+		self
+	}
+	
+	/// C++ default: `Size()`
+	#[inline]
+	pub fn with_min_size(mut self, min_size: core::Size) -> Self {
+		self.min_size = min_size;
+		self
+	}
+	
+	/// C++ default: `Size()`
+	#[inline]
+	pub fn with_max_size(mut self, max_size: core::Size) -> Self {
+		self.max_size = max_size;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		CascadeClassifierTrait::detect_multi_scale(self.instance, self.image, self.objects, self.scale_factor, self.min_neighbors, self.flags, self.min_size, self.max_size)
+	}
+}
+
+/// Builder for `CascadeClassifierTrait::detect_multi_scale2()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CascadeClassifier_DetectMultiScale2Builder<'a, T: ?Sized + CascadeClassifierTrait> {
+	instance: &'a mut T,
+	image: &'a dyn core::ToInputArray,
+	objects: &'a mut core::Vector<core::Rect>,
+	num_detections: &'a mut core::Vector<i32>,
+	scale_factor: f64,
+	min_neighbors: i32,
+	flags: i32,
+	min_size: core::Size,
+	max_size: core::Size,
+}
+
+impl<'a, T: ?Sized + CascadeClassifierTrait> CascadeClassifier_DetectMultiScale2Builder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, image: &'a dyn core::ToInputArray, objects: &'a mut core::Vector<core::Rect>, num_detections: &'a mut core::Vector<i32>) -> Self {
+		Self { instance, image, objects, num_detections, scale_factor: 1.1, min_neighbors: 3, flags: 0, min_size: core::Size::default(), max_size: core::Size::default() }
+	}
+	
+	/// C++ default: `1.1`
+	#[inline]
+	pub fn with_scale_factor(mut self, scale_factor: f64) -> Self {
+		self.scale_factor = scale_factor;
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_min_neighbors(mut self, min_neighbors: i32) -> Self {
+		self.min_neighbors = min_neighbors;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_flags(mut self, flags: i32) -> Self {
+		self.flags = flags;
+		self
+	}
+	
+	/// C++ default: `Size()`
+	#[inline]
+	pub fn with_min_size(mut self, min_size: core::Size) -> Self {
+		self.min_size = min_size;
+		self
+	}
+	
+	/// C++ default: `Size()`
+	#[inline]
+	pub fn with_max_size(mut self, max_size: core::Size) -> Self {
+		self.max_size = max_size;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		CascadeClassifierTrait::detect_multi_scale2(self.instance, self.image, self.objects, self.num_detections, self.scale_factor, self.min_neighbors, self.flags, self.min_size, self.max_size)
+	}
+}
+
+/// Builder for `CascadeClassifierTrait::detect_multi_scale3()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CascadeClassifier_DetectMultiScale3Builder<'a, T: ?Sized + CascadeClassifierTrait> {
+	instance: &'a mut T,
+	image: &'a dyn core::ToInputArray,
+	objects: &'a mut core::Vector<core::Rect>,
+	reject_levels: &'a mut core::Vector<i32>,
+	level_weights: &'a mut core::Vector<f64>,
+	scale_factor: f64,
+	min_neighbors: i32,
+	flags: i32,
+	min_size: core::Size,
+	max_size: core::Size,
+	output_reject_levels: bool,
+}
+
+impl<'a, T: ?Sized + CascadeClassifierTrait> CascadeClassifier_DetectMultiScale3Builder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, image: &'a dyn core::ToInputArray, objects: &'a mut core::Vector<core::Rect>, reject_levels: &'a mut core::Vector<i32>, level_weights: &'a mut core::Vector<f64>) -> Self {
+		Self { instance, image, objects, reject_levels, level_weights, scale_factor: 1.1, min_neighbors: 3, flags: 0, min_size: core::Size::default(), max_size: core::Size::default(), output_reject_levels: false }
+	}
+	
+	/// C++ default: `1.1`
+	#[inline]
+	pub fn with_scale_factor(mut self, scale_factor: f64) -> Self {
+		self.scale_factor = scale_factor;
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_min_neighbors(mut self, min_neighbors: i32) -> Self {
+		self.min_neighbors = min_neighbors;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_flags(mut self, flags: i32) -> Self {
+		self.flags = flags;
+		self
+	}
+	
+	/// C++ default: `Size()`
+	#[inline]
+	pub fn with_min_size(mut self, min_size: core::Size) -> Self {
+		self.min_size = min_size;
+		self
+	}
+	
+	/// C++ default: `Size()`
+	#[inline]
+	pub fn with_max_size(mut self, max_size: core::Size) -> Self {
+		self.max_size = max_size;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_output_reject_levels(mut self, output_reject_levels: bool) -> Self {
+		self.output_reject_levels = output_reject_levels;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		CascadeClassifierTrait::detect_multi_scale3(self.instance, self.image, self.objects, self.reject_levels, self.level_weights, self.scale_factor, self.min_neighbors, self.flags, self.min_size, self.max_size, self.output_reject_levels)
+	}
+}
+
 /// @example samples/cpp/facedetect.cpp
 /// This program demonstrates usage of the Cascade classifier class
 /// \image html Cascade_Classifier_Tutorial_Result_Haar.jpg "Sample screenshot" width=321 height=254
@@ -707,7 +939,10 @@ opencv_type_boxed! { CascadeClassifier }
 impl Drop for CascadeClassifier {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CascadeClassifier_delete(instance: *mut c_void); }
-		unsafe { cv_CascadeClassifier_delete(self.as_raw_mut_CascadeClassifier()) };
+		let ptr = self.as_raw_mut_CascadeClassifier();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_CascadeClassifier_delete(ptr) };
 	}
 }
 
@@ -725,7 +960,7 @@ impl CascadeClassifier {
 	#[inline]
 	pub fn default() -> Result<crate::objdetect::CascadeClassifier> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CascadeClassifier_CascadeClassifier(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CascadeClassifier_CascadeClassifier(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::objdetect::CascadeClassifier::opencv_from_extern(ret) };
@@ -740,7 +975,7 @@ impl CascadeClassifier {
 	pub fn new(filename: &str) -> Result<crate::objdetect::CascadeClassifier> {
 		extern_container_arg!(filename);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CascadeClassifier_CascadeClassifier_const_StringR(filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CascadeClassifier_CascadeClassifier_const_StringR(filename.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::objdetect::CascadeClassifier::opencv_from_extern(ret) };
@@ -752,7 +987,7 @@ impl CascadeClassifier {
 		extern_container_arg!(oldcascade);
 		extern_container_arg!(newcascade);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CascadeClassifier_convert_const_StringR_const_StringR(oldcascade.opencv_as_extern(), newcascade.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CascadeClassifier_convert_const_StringR_const_StringR(oldcascade.opencv_as_extern(), newcascade.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -765,8 +1000,9 @@ pub trait DetectionBasedTrackerTraitConst {
 
 	#[inline]
 	fn get_parameters(&self) -> Result<crate::objdetect::DetectionBasedTracker_Parameters> {
+		crate::core::validity::check("DetectionBasedTracker", self.as_raw_DetectionBasedTracker())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DetectionBasedTracker_getParameters_const(self.as_raw_DetectionBasedTracker(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DetectionBasedTracker_getParameters_const(self.as_raw_DetectionBasedTracker(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::objdetect::DetectionBasedTracker_Parameters::opencv_from_extern(ret) };
@@ -775,8 +1011,9 @@ pub trait DetectionBasedTrackerTraitConst {
 	
 	#[inline]
 	fn get_objects(&self, result: &mut core::Vector<core::Rect>) -> Result<()> {
+		crate::core::validity::check("DetectionBasedTracker", self.as_raw_DetectionBasedTracker())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DetectionBasedTracker_getObjects_const_vector_Rect_R(self.as_raw_DetectionBasedTracker(), result.as_raw_mut_VectorOfRect(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DetectionBasedTracker_getObjects_const_vector_Rect_R(self.as_raw_DetectionBasedTracker(), result.as_raw_mut_VectorOfRect(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -784,8 +1021,9 @@ pub trait DetectionBasedTrackerTraitConst {
 	
 	#[inline]
 	fn get_objects_1(&self, result: &mut core::Vector<crate::objdetect::DetectionBasedTracker_ExtObject>) -> Result<()> {
+		crate::core::validity::check("DetectionBasedTracker", self.as_raw_DetectionBasedTracker())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DetectionBasedTracker_getObjects_const_vector_ExtObject_R(self.as_raw_DetectionBasedTracker(), result.as_raw_mut_VectorOfDetectionBasedTracker_ExtObject(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DetectionBasedTracker_getObjects_const_vector_ExtObject_R(self.as_raw_DetectionBasedTracker(), result.as_raw_mut_VectorOfDetectionBasedTracker_ExtObject(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -798,8 +1036,9 @@ pub trait DetectionBasedTrackerTrait: crate::objdetect::DetectionBasedTrackerTra
 
 	#[inline]
 	fn run(&mut self) -> Result<bool> {
+		crate::core::validity::check("DetectionBasedTracker", self.as_raw_mut_DetectionBasedTracker())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DetectionBasedTracker_run(self.as_raw_mut_DetectionBasedTracker(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DetectionBasedTracker_run(self.as_raw_mut_DetectionBasedTracker(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -807,8 +1046,9 @@ pub trait DetectionBasedTrackerTrait: crate::objdetect::DetectionBasedTrackerTra
 	
 	#[inline]
 	fn stop(&mut self) -> Result<()> {
+		crate::core::validity::check("DetectionBasedTracker", self.as_raw_mut_DetectionBasedTracker())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DetectionBasedTracker_stop(self.as_raw_mut_DetectionBasedTracker(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DetectionBasedTracker_stop(self.as_raw_mut_DetectionBasedTracker(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -816,8 +1056,9 @@ pub trait DetectionBasedTrackerTrait: crate::objdetect::DetectionBasedTrackerTra
 	
 	#[inline]
 	fn reset_tracking(&mut self) -> Result<()> {
+		crate::core::validity::check("DetectionBasedTracker", self.as_raw_mut_DetectionBasedTracker())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DetectionBasedTracker_resetTracking(self.as_raw_mut_DetectionBasedTracker(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DetectionBasedTracker_resetTracking(self.as_raw_mut_DetectionBasedTracker(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -825,8 +1066,9 @@ pub trait DetectionBasedTrackerTrait: crate::objdetect::DetectionBasedTrackerTra
 	
 	#[inline]
 	fn process(&mut self, image_gray: &core::Mat) -> Result<()> {
+		crate::core::validity::check("DetectionBasedTracker", self.as_raw_mut_DetectionBasedTracker())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DetectionBasedTracker_process_const_MatR(self.as_raw_mut_DetectionBasedTracker(), image_gray.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DetectionBasedTracker_process_const_MatR(self.as_raw_mut_DetectionBasedTracker(), image_gray.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -834,8 +1076,9 @@ pub trait DetectionBasedTrackerTrait: crate::objdetect::DetectionBasedTrackerTra
 	
 	#[inline]
 	fn set_parameters(&mut self, params: &crate::objdetect::DetectionBasedTracker_Parameters) -> Result<bool> {
+		crate::core::validity::check("DetectionBasedTracker", self.as_raw_mut_DetectionBasedTracker())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DetectionBasedTracker_setParameters_const_ParametersR(self.as_raw_mut_DetectionBasedTracker(), params.as_raw_DetectionBasedTracker_Parameters(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DetectionBasedTracker_setParameters_const_ParametersR(self.as_raw_mut_DetectionBasedTracker(), params.as_raw_DetectionBasedTracker_Parameters(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -843,8 +1086,9 @@ pub trait DetectionBasedTrackerTrait: crate::objdetect::DetectionBasedTrackerTra
 	
 	#[inline]
 	fn add_object(&mut self, location: core::Rect) -> Result<i32> {
+		crate::core::validity::check("DetectionBasedTracker", self.as_raw_mut_DetectionBasedTracker())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DetectionBasedTracker_addObject_const_RectR(self.as_raw_mut_DetectionBasedTracker(), &location, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DetectionBasedTracker_addObject_const_RectR(self.as_raw_mut_DetectionBasedTracker(), &location, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -861,7 +1105,10 @@ opencv_type_boxed! { DetectionBasedTracker }
 impl Drop for DetectionBasedTracker {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DetectionBasedTracker_delete(instance: *mut c_void); }
-		unsafe { cv_DetectionBasedTracker_delete(self.as_raw_mut_DetectionBasedTracker()) };
+		let ptr = self.as_raw_mut_DetectionBasedTracker();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_DetectionBasedTracker_delete(ptr) };
 	}
 }
 
@@ -879,7 +1126,7 @@ impl DetectionBasedTracker {
 	#[inline]
 	pub fn new(mut main_detector: core::Ptr<dyn crate::objdetect::DetectionBasedTracker_IDetector>, mut tracking_detector: core::Ptr<dyn crate::objdetect::DetectionBasedTracker_IDetector>, params: &crate::objdetect::DetectionBasedTracker_Parameters) -> Result<crate::objdetect::DetectionBasedTracker> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DetectionBasedTracker_DetectionBasedTracker_Ptr_IDetector__Ptr_IDetector__const_ParametersR(main_detector.as_raw_mut_PtrOfDetectionBasedTracker_IDetector(), tracking_detector.as_raw_mut_PtrOfDetectionBasedTracker_IDetector(), params.as_raw_DetectionBasedTracker_Parameters(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DetectionBasedTracker_DetectionBasedTracker_Ptr_IDetector__Ptr_IDetector__const_ParametersR(main_detector.as_raw_mut_PtrOfDetectionBasedTracker_IDetector(), tracking_detector.as_raw_mut_PtrOfDetectionBasedTracker_IDetector(), params.as_raw_DetectionBasedTracker_Parameters(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::objdetect::DetectionBasedTracker::opencv_from_extern(ret) };
@@ -893,26 +1140,53 @@ pub trait DetectionBasedTracker_ExtObjectTraitConst {
 
 	#[inline]
 	fn id(&self) -> i32 {
-		let ret = unsafe { sys::cv_DetectionBasedTracker_ExtObject_getPropId_const(self.as_raw_DetectionBasedTracker_ExtObject()) };
+		crate::core::validity::debug_check("DetectionBasedTracker_ExtObject", self.as_raw_DetectionBasedTracker_ExtObject());
+		let ret = unsafe { ffi_call!(cv_DetectionBasedTracker_ExtObject_getPropId_const(self.as_raw_DetectionBasedTracker_ExtObject())) };
 		ret
 	}
 	
+	/// Non-panicking version of [id](DetectionBasedTracker_ExtObjectTraitConst::id), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_id(&self) -> Result<i32> {
+		crate::core::validity::check("DetectionBasedTracker_ExtObject", self.as_raw_DetectionBasedTracker_ExtObject())?;
+		Ok(DetectionBasedTracker_ExtObjectTraitConst::id(self))
+	}
+	
 	#[inline]
 	fn location(&self) -> core::Rect {
+		crate::core::validity::debug_check("DetectionBasedTracker_ExtObject", self.as_raw_DetectionBasedTracker_ExtObject());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DetectionBasedTracker_ExtObject_getPropLocation_const(self.as_raw_DetectionBasedTracker_ExtObject(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DetectionBasedTracker_ExtObject_getPropLocation_const(self.as_raw_DetectionBasedTracker_ExtObject(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [location](DetectionBasedTracker_ExtObjectTraitConst::location), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_location(&self) -> Result<core::Rect> {
+		crate::core::validity::check("DetectionBasedTracker_ExtObject", self.as_raw_DetectionBasedTracker_ExtObject())?;
+		Ok(DetectionBasedTracker_ExtObjectTraitConst::location(self))
+	}
+	
 	#[inline]
 	fn status(&self) -> crate::objdetect::DetectionBasedTracker_ObjectStatus {
+		crate::core::validity::debug_check("DetectionBasedTracker_ExtObject", self.as_raw_DetectionBasedTracker_ExtObject());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DetectionBasedTracker_ExtObject_getPropStatus_const(self.as_raw_DetectionBasedTracker_ExtObject(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DetectionBasedTracker_ExtObject_getPropStatus_const(self.as_raw_DetectionBasedTracker_ExtObject(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [status](DetectionBasedTracker_ExtObjectTraitConst::status), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_status(&self) -> Result<crate::objdetect::DetectionBasedTracker_ObjectStatus> {
+		crate::core::validity::check("DetectionBasedTracker_ExtObject", self.as_raw_DetectionBasedTracker_ExtObject())?;
+		Ok(DetectionBasedTracker_ExtObjectTraitConst::status(self))
+	}
+	
 }
 
 pub trait DetectionBasedTracker_ExtObjectTrait: crate::objdetect::DetectionBasedTracker_ExtObjectTraitConst {
@@ -920,19 +1194,22 @@ pub trait DetectionBasedTracker_ExtObjectTrait: crate::objdetect::DetectionBased
 
 	#[inline]
 	fn set_id(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_DetectionBasedTracker_ExtObject_setPropId_int(self.as_raw_mut_DetectionBasedTracker_ExtObject(), val) };
+		crate::core::validity::debug_check("DetectionBasedTracker_ExtObject", self.as_raw_mut_DetectionBasedTracker_ExtObject());
+		let ret = unsafe { ffi_call!(cv_DetectionBasedTracker_ExtObject_setPropId_int(self.as_raw_mut_DetectionBasedTracker_ExtObject(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_location(&mut self, val: core::Rect) {
-		let ret = unsafe { sys::cv_DetectionBasedTracker_ExtObject_setPropLocation_Rect(self.as_raw_mut_DetectionBasedTracker_ExtObject(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("DetectionBasedTracker_ExtObject", self.as_raw_mut_DetectionBasedTracker_ExtObject());
+		let ret = unsafe { ffi_call!(cv_DetectionBasedTracker_ExtObject_setPropLocation_Rect(self.as_raw_mut_DetectionBasedTracker_ExtObject(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_status(&mut self, val: crate::objdetect::DetectionBasedTracker_ObjectStatus) {
-		let ret = unsafe { sys::cv_DetectionBasedTracker_ExtObject_setPropStatus_ObjectStatus(self.as_raw_mut_DetectionBasedTracker_ExtObject(), val) };
+		crate::core::validity::debug_check("DetectionBasedTracker_ExtObject", self.as_raw_mut_DetectionBasedTracker_ExtObject());
+		let ret = unsafe { ffi_call!(cv_DetectionBasedTracker_ExtObject_setPropStatus_ObjectStatus(self.as_raw_mut_DetectionBasedTracker_ExtObject(), val)) };
 		ret
 	}
 	
@@ -947,7 +1224,10 @@ opencv_type_boxed! { DetectionBasedTracker_ExtObject }
 impl Drop for DetectionBasedTracker_ExtObject {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DetectionBasedTracker_ExtObject_delete(instance: *mut c_void); }
-		unsafe { cv_DetectionBasedTracker_ExtObject_delete(self.as_raw_mut_DetectionBasedTracker_ExtObject()) };
+		let ptr = self.as_raw_mut_DetectionBasedTracker_ExtObject();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_DetectionBasedTracker_ExtObject_delete(ptr) };
 	}
 }
 
@@ -965,7 +1245,7 @@ impl DetectionBasedTracker_ExtObject {
 	#[inline]
 	pub fn new(_id: i32, _location: core::Rect, _status: crate::objdetect::DetectionBasedTracker_ObjectStatus) -> Result<crate::objdetect::DetectionBasedTracker_ExtObject> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DetectionBasedTracker_ExtObject_ExtObject_int_Rect_ObjectStatus(_id, _location.opencv_as_extern(), _status, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DetectionBasedTracker_ExtObject_ExtObject_int_Rect_ObjectStatus(_id, _location.opencv_as_extern(), _status, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::objdetect::DetectionBasedTracker_ExtObject::opencv_from_extern(ret) };
@@ -979,8 +1259,9 @@ pub trait DetectionBasedTracker_IDetectorConst {
 
 	#[inline]
 	fn get_min_object_size(&self) -> Result<core::Size> {
+		crate::core::validity::check("DetectionBasedTracker_IDetector", self.as_raw_DetectionBasedTracker_IDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DetectionBasedTracker_IDetector_getMinObjectSize_const(self.as_raw_DetectionBasedTracker_IDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DetectionBasedTracker_IDetector_getMinObjectSize_const(self.as_raw_DetectionBasedTracker_IDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -988,8 +1269,9 @@ pub trait DetectionBasedTracker_IDetectorConst {
 	
 	#[inline]
 	fn get_max_object_size(&self) -> Result<core::Size> {
+		crate::core::validity::check("DetectionBasedTracker_IDetector", self.as_raw_DetectionBasedTracker_IDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DetectionBasedTracker_IDetector_getMaxObjectSize_const(self.as_raw_DetectionBasedTracker_IDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DetectionBasedTracker_IDetector_getMaxObjectSize_const(self.as_raw_DetectionBasedTracker_IDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1002,8 +1284,9 @@ pub trait DetectionBasedTracker_IDetector: crate::objdetect::DetectionBasedTrack
 
 	#[inline]
 	fn detect(&mut self, image: &core::Mat, objects: &mut core::Vector<core::Rect>) -> Result<()> {
+		crate::core::validity::check("DetectionBasedTracker_IDetector", self.as_raw_mut_DetectionBasedTracker_IDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DetectionBasedTracker_IDetector_detect_const_MatR_vector_Rect_R(self.as_raw_mut_DetectionBasedTracker_IDetector(), image.as_raw_Mat(), objects.as_raw_mut_VectorOfRect(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DetectionBasedTracker_IDetector_detect_const_MatR_vector_Rect_R(self.as_raw_mut_DetectionBasedTracker_IDetector(), image.as_raw_Mat(), objects.as_raw_mut_VectorOfRect(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1011,8 +1294,9 @@ pub trait DetectionBasedTracker_IDetector: crate::objdetect::DetectionBasedTrack
 	
 	#[inline]
 	fn set_min_object_size(&mut self, min: core::Size) -> Result<()> {
+		crate::core::validity::check("DetectionBasedTracker_IDetector", self.as_raw_mut_DetectionBasedTracker_IDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DetectionBasedTracker_IDetector_setMinObjectSize_const_SizeR(self.as_raw_mut_DetectionBasedTracker_IDetector(), &min, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DetectionBasedTracker_IDetector_setMinObjectSize_const_SizeR(self.as_raw_mut_DetectionBasedTracker_IDetector(), &min, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1020,8 +1304,9 @@ pub trait DetectionBasedTracker_IDetector: crate::objdetect::DetectionBasedTrack
 	
 	#[inline]
 	fn set_max_object_size(&mut self, max: core::Size) -> Result<()> {
+		crate::core::validity::check("DetectionBasedTracker_IDetector", self.as_raw_mut_DetectionBasedTracker_IDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DetectionBasedTracker_IDetector_setMaxObjectSize_const_SizeR(self.as_raw_mut_DetectionBasedTracker_IDetector(), &max, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DetectionBasedTracker_IDetector_setMaxObjectSize_const_SizeR(self.as_raw_mut_DetectionBasedTracker_IDetector(), &max, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1029,8 +1314,9 @@ pub trait DetectionBasedTracker_IDetector: crate::objdetect::DetectionBasedTrack
 	
 	#[inline]
 	fn get_scale_factor(&mut self) -> Result<f32> {
+		crate::core::validity::check("DetectionBasedTracker_IDetector", self.as_raw_mut_DetectionBasedTracker_IDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DetectionBasedTracker_IDetector_getScaleFactor(self.as_raw_mut_DetectionBasedTracker_IDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DetectionBasedTracker_IDetector_getScaleFactor(self.as_raw_mut_DetectionBasedTracker_IDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1038,8 +1324,9 @@ pub trait DetectionBasedTracker_IDetector: crate::objdetect::DetectionBasedTrack
 	
 	#[inline]
 	fn set_scale_factor(&mut self, value: f32) -> Result<()> {
+		crate::core::validity::check("DetectionBasedTracker_IDetector", self.as_raw_mut_DetectionBasedTracker_IDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DetectionBasedTracker_IDetector_setScaleFactor_float(self.as_raw_mut_DetectionBasedTracker_IDetector(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DetectionBasedTracker_IDetector_setScaleFactor_float(self.as_raw_mut_DetectionBasedTracker_IDetector(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1047,8 +1334,9 @@ pub trait DetectionBasedTracker_IDetector: crate::objdetect::DetectionBasedTrack
 	
 	#[inline]
 	fn get_min_neighbours(&mut self) -> Result<i32> {
+		crate::core::validity::check("DetectionBasedTracker_IDetector", self.as_raw_mut_DetectionBasedTracker_IDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DetectionBasedTracker_IDetector_getMinNeighbours(self.as_raw_mut_DetectionBasedTracker_IDetector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DetectionBasedTracker_IDetector_getMinNeighbours(self.as_raw_mut_DetectionBasedTracker_IDetector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1056,8 +1344,9 @@ pub trait DetectionBasedTracker_IDetector: crate::objdetect::DetectionBasedTrack
 	
 	#[inline]
 	fn set_min_neighbours(&mut self, value: i32) -> Result<()> {
+		crate::core::validity::check("DetectionBasedTracker_IDetector", self.as_raw_mut_DetectionBasedTracker_IDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DetectionBasedTracker_IDetector_setMinNeighbours_int(self.as_raw_mut_DetectionBasedTracker_IDetector(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DetectionBasedTracker_IDetector_setMinNeighbours_int(self.as_raw_mut_DetectionBasedTracker_IDetector(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1070,16 +1359,34 @@ pub trait DetectionBasedTracker_ParametersTraitConst {
 
 	#[inline]
 	fn max_track_lifetime(&self) -> i32 {
-		let ret = unsafe { sys::cv_DetectionBasedTracker_Parameters_getPropMaxTrackLifetime_const(self.as_raw_DetectionBasedTracker_Parameters()) };
+		crate::core::validity::debug_check("DetectionBasedTracker_Parameters", self.as_raw_DetectionBasedTracker_Parameters());
+		let ret = unsafe { ffi_call!(cv_DetectionBasedTracker_Parameters_getPropMaxTrackLifetime_const(self.as_raw_DetectionBasedTracker_Parameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [max_track_lifetime](DetectionBasedTracker_ParametersTraitConst::max_track_lifetime), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_max_track_lifetime(&self) -> Result<i32> {
+		crate::core::validity::check("DetectionBasedTracker_Parameters", self.as_raw_DetectionBasedTracker_Parameters())?;
+		Ok(DetectionBasedTracker_ParametersTraitConst::max_track_lifetime(self))
+	}
+	
 	#[inline]
 	fn min_detection_period(&self) -> i32 {
-		let ret = unsafe { sys::cv_DetectionBasedTracker_Parameters_getPropMinDetectionPeriod_const(self.as_raw_DetectionBasedTracker_Parameters()) };
+		crate::core::validity::debug_check("DetectionBasedTracker_Parameters", self.as_raw_DetectionBasedTracker_Parameters());
+		let ret = unsafe { ffi_call!(cv_DetectionBasedTracker_Parameters_getPropMinDetectionPeriod_const(self.as_raw_DetectionBasedTracker_Parameters())) };
 		ret
 	}
 	
+	/// Non-panicking version of [min_detection_period](DetectionBasedTracker_ParametersTraitConst::min_detection_period), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_min_detection_period(&self) -> Result<i32> {
+		crate::core::validity::check("DetectionBasedTracker_Parameters", self.as_raw_DetectionBasedTracker_Parameters())?;
+		Ok(DetectionBasedTracker_ParametersTraitConst::min_detection_period(self))
+	}
+	
 }
 
 pub trait DetectionBasedTracker_ParametersTrait: crate::objdetect::DetectionBasedTracker_ParametersTraitConst {
@@ -1087,13 +1394,15 @@ pub trait DetectionBasedTracker_ParametersTrait: crate::objdetect::DetectionBase
 
 	#[inline]
 	fn set_max_track_lifetime(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_DetectionBasedTracker_Parameters_setPropMaxTrackLifetime_int(self.as_raw_mut_DetectionBasedTracker_Parameters(), val) };
+		crate::core::validity::debug_check("DetectionBasedTracker_Parameters", self.as_raw_mut_DetectionBasedTracker_Parameters());
+		let ret = unsafe { ffi_call!(cv_DetectionBasedTracker_Parameters_setPropMaxTrackLifetime_int(self.as_raw_mut_DetectionBasedTracker_Parameters(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_min_detection_period(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_DetectionBasedTracker_Parameters_setPropMinDetectionPeriod_int(self.as_raw_mut_DetectionBasedTracker_Parameters(), val) };
+		crate::core::validity::debug_check("DetectionBasedTracker_Parameters", self.as_raw_mut_DetectionBasedTracker_Parameters());
+		let ret = unsafe { ffi_call!(cv_DetectionBasedTracker_Parameters_setPropMinDetectionPeriod_int(self.as_raw_mut_DetectionBasedTracker_Parameters(), val)) };
 		ret
 	}
 	
@@ -1108,7 +1417,10 @@ opencv_type_boxed! { DetectionBasedTracker_Parameters }
 impl Drop for DetectionBasedTracker_Parameters {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DetectionBasedTracker_Parameters_delete(instance: *mut c_void); }
-		unsafe { cv_DetectionBasedTracker_Parameters_delete(self.as_raw_mut_DetectionBasedTracker_Parameters()) };
+		let ptr = self.as_raw_mut_DetectionBasedTracker_Parameters();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_DetectionBasedTracker_Parameters_delete(ptr) };
 	}
 }
 
@@ -1126,7 +1438,7 @@ impl DetectionBasedTracker_Parameters {
 	#[inline]
 	pub fn default() -> Result<crate::objdetect::DetectionBasedTracker_Parameters> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DetectionBasedTracker_Parameters_Parameters(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DetectionBasedTracker_Parameters_Parameters(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::objdetect::DetectionBasedTracker_Parameters::opencv_from_extern(ret) };
@@ -1142,26 +1454,53 @@ pub trait DetectionROITraitConst {
 	/// scale(size) of the bounding box
 	#[inline]
 	fn scale(&self) -> f64 {
-		let ret = unsafe { sys::cv_DetectionROI_getPropScale_const(self.as_raw_DetectionROI()) };
+		crate::core::validity::debug_check("DetectionROI", self.as_raw_DetectionROI());
+		let ret = unsafe { ffi_call!(cv_DetectionROI_getPropScale_const(self.as_raw_DetectionROI())) };
 		ret
 	}
 	
+	/// Non-panicking version of [scale](DetectionROITraitConst::scale), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_scale(&self) -> Result<f64> {
+		crate::core::validity::check("DetectionROI", self.as_raw_DetectionROI())?;
+		Ok(DetectionROITraitConst::scale(self))
+	}
+	
 	/// set of requested locations to be evaluated
 	#[inline]
 	fn locations(&self) -> core::Vector<core::Point> {
-		let ret = unsafe { sys::cv_DetectionROI_getPropLocations_const(self.as_raw_DetectionROI()) };
+		crate::core::validity::debug_check("DetectionROI", self.as_raw_DetectionROI());
+		let ret = unsafe { ffi_call!(cv_DetectionROI_getPropLocations_const(self.as_raw_DetectionROI())) };
 		let ret = unsafe { core::Vector::<core::Point>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [locations](DetectionROITraitConst::locations), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_locations(&self) -> Result<core::Vector<core::Point>> {
+		crate::core::validity::check("DetectionROI", self.as_raw_DetectionROI())?;
+		Ok(DetectionROITraitConst::locations(self))
+	}
+	
 	/// vector that will contain confidence values for each location
 	#[inline]
 	fn confidences(&self) -> core::Vector<f64> {
-		let ret = unsafe { sys::cv_DetectionROI_getPropConfidences_const(self.as_raw_DetectionROI()) };
+		crate::core::validity::debug_check("DetectionROI", self.as_raw_DetectionROI());
+		let ret = unsafe { ffi_call!(cv_DetectionROI_getPropConfidences_const(self.as_raw_DetectionROI())) };
 		let ret = unsafe { core::Vector::<f64>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [confidences](DetectionROITraitConst::confidences), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_confidences(&self) -> Result<core::Vector<f64>> {
+		crate::core::validity::check("DetectionROI", self.as_raw_DetectionROI())?;
+		Ok(DetectionROITraitConst::confidences(self))
+	}
+	
 }
 
 pub trait DetectionROITrait: crate::objdetect::DetectionROITraitConst {
@@ -1170,21 +1509,24 @@ pub trait DetectionROITrait: crate::objdetect::DetectionROITraitConst {
 	/// scale(size) of the bounding box
 	#[inline]
 	fn set_scale(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_DetectionROI_setPropScale_double(self.as_raw_mut_DetectionROI(), val) };
+		crate::core::validity::debug_check("DetectionROI", self.as_raw_mut_DetectionROI());
+		let ret = unsafe { ffi_call!(cv_DetectionROI_setPropScale_double(self.as_raw_mut_DetectionROI(), val)) };
 		ret
 	}
 	
 	/// set of requested locations to be evaluated
 	#[inline]
 	fn set_locations(&mut self, mut val: core::Vector<core::Point>) {
-		let ret = unsafe { sys::cv_DetectionROI_setPropLocations_vector_Point_(self.as_raw_mut_DetectionROI(), val.as_raw_mut_VectorOfPoint()) };
+		crate::core::validity::debug_check("DetectionROI", self.as_raw_mut_DetectionROI());
+		let ret = unsafe { ffi_call!(cv_DetectionROI_setPropLocations_vector_Point_(self.as_raw_mut_DetectionROI(), val.as_raw_mut_VectorOfPoint())) };
 		ret
 	}
 	
 	/// vector that will contain confidence values for each location
 	#[inline]
 	fn set_confidences(&mut self, mut val: core::Vector<f64>) {
-		let ret = unsafe { sys::cv_DetectionROI_setPropConfidences_vector_double_(self.as_raw_mut_DetectionROI(), val.as_raw_mut_VectorOff64()) };
+		crate::core::validity::debug_check("DetectionROI", self.as_raw_mut_DetectionROI());
+		let ret = unsafe { ffi_call!(cv_DetectionROI_setPropConfidences_vector_double_(self.as_raw_mut_DetectionROI(), val.as_raw_mut_VectorOff64())) };
 		ret
 	}
 	
@@ -1200,7 +1542,10 @@ opencv_type_boxed! { DetectionROI }
 impl Drop for DetectionROI {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DetectionROI_delete(instance: *mut c_void); }
-		unsafe { cv_DetectionROI_delete(self.as_raw_mut_DetectionROI()) };
+		let ptr = self.as_raw_mut_DetectionROI();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_DetectionROI_delete(ptr) };
 	}
 }
 
@@ -1232,8 +1577,9 @@ pub trait FaceDetectorYN: crate::objdetect::FaceDetectorYNConst {
 	/// * input_size: the size of the input image
 	#[inline]
 	fn set_input_size(&mut self, input_size: core::Size) -> Result<()> {
+		crate::core::validity::check("FaceDetectorYN", self.as_raw_mut_FaceDetectorYN())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FaceDetectorYN_setInputSize_const_SizeR(self.as_raw_mut_FaceDetectorYN(), &input_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FaceDetectorYN_setInputSize_const_SizeR(self.as_raw_mut_FaceDetectorYN(), &input_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1241,8 +1587,9 @@ pub trait FaceDetectorYN: crate::objdetect::FaceDetectorYNConst {
 	
 	#[inline]
 	fn get_input_size(&mut self) -> Result<core::Size> {
+		crate::core::validity::check("FaceDetectorYN", self.as_raw_mut_FaceDetectorYN())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FaceDetectorYN_getInputSize(self.as_raw_mut_FaceDetectorYN(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FaceDetectorYN_getInputSize(self.as_raw_mut_FaceDetectorYN(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1254,8 +1601,9 @@ pub trait FaceDetectorYN: crate::objdetect::FaceDetectorYNConst {
 	/// * score_threshold: threshold for filtering out bounding boxes
 	#[inline]
 	fn set_score_threshold(&mut self, score_threshold: f32) -> Result<()> {
+		crate::core::validity::check("FaceDetectorYN", self.as_raw_mut_FaceDetectorYN())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FaceDetectorYN_setScoreThreshold_float(self.as_raw_mut_FaceDetectorYN(), score_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FaceDetectorYN_setScoreThreshold_float(self.as_raw_mut_FaceDetectorYN(), score_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1263,8 +1611,9 @@ pub trait FaceDetectorYN: crate::objdetect::FaceDetectorYNConst {
 	
 	#[inline]
 	fn get_score_threshold(&mut self) -> Result<f32> {
+		crate::core::validity::check("FaceDetectorYN", self.as_raw_mut_FaceDetectorYN())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FaceDetectorYN_getScoreThreshold(self.as_raw_mut_FaceDetectorYN(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FaceDetectorYN_getScoreThreshold(self.as_raw_mut_FaceDetectorYN(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1276,8 +1625,9 @@ pub trait FaceDetectorYN: crate::objdetect::FaceDetectorYNConst {
 	/// * nms_threshold: threshold for NMS operation
 	#[inline]
 	fn set_nms_threshold(&mut self, nms_threshold: f32) -> Result<()> {
+		crate::core::validity::check("FaceDetectorYN", self.as_raw_mut_FaceDetectorYN())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FaceDetectorYN_setNMSThreshold_float(self.as_raw_mut_FaceDetectorYN(), nms_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FaceDetectorYN_setNMSThreshold_float(self.as_raw_mut_FaceDetectorYN(), nms_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1285,8 +1635,9 @@ pub trait FaceDetectorYN: crate::objdetect::FaceDetectorYNConst {
 	
 	#[inline]
 	fn get_nms_threshold(&mut self) -> Result<f32> {
+		crate::core::validity::check("FaceDetectorYN", self.as_raw_mut_FaceDetectorYN())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FaceDetectorYN_getNMSThreshold(self.as_raw_mut_FaceDetectorYN(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FaceDetectorYN_getNMSThreshold(self.as_raw_mut_FaceDetectorYN(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1298,8 +1649,9 @@ pub trait FaceDetectorYN: crate::objdetect::FaceDetectorYNConst {
 	/// * top_k: the number of bounding boxes to preserve from top rank based on score
 	#[inline]
 	fn set_top_k(&mut self, top_k: i32) -> Result<()> {
+		crate::core::validity::check("FaceDetectorYN", self.as_raw_mut_FaceDetectorYN())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FaceDetectorYN_setTopK_int(self.as_raw_mut_FaceDetectorYN(), top_k, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FaceDetectorYN_setTopK_int(self.as_raw_mut_FaceDetectorYN(), top_k, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1307,8 +1659,9 @@ pub trait FaceDetectorYN: crate::objdetect::FaceDetectorYNConst {
 	
 	#[inline]
 	fn get_top_k(&mut self) -> Result<i32> {
+		crate::core::validity::check("FaceDetectorYN", self.as_raw_mut_FaceDetectorYN())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FaceDetectorYN_getTopK(self.as_raw_mut_FaceDetectorYN(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FaceDetectorYN_getTopK(self.as_raw_mut_FaceDetectorYN(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1321,10 +1674,11 @@ pub trait FaceDetectorYN: crate::objdetect::FaceDetectorYNConst {
 	/// * faces: detection results stored in a cv::Mat
 	#[inline]
 	fn detect(&mut self, image: &dyn core::ToInputArray, faces: &mut dyn core::ToOutputArray) -> Result<i32> {
+		crate::core::validity::check("FaceDetectorYN", self.as_raw_mut_FaceDetectorYN())?;
 		input_array_arg!(image);
 		output_array_arg!(faces);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FaceDetectorYN_detect_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_FaceDetectorYN(), image.as_raw__InputArray(), faces.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FaceDetectorYN_detect_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_FaceDetectorYN(), image.as_raw__InputArray(), faces.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1356,7 +1710,7 @@ impl dyn FaceDetectorYN + '_ {
 		extern_container_arg!(model);
 		extern_container_arg!(config);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FaceDetectorYN_create_const_StringR_const_StringR_const_SizeR_float_float_int_int_int(model.opencv_as_extern(), config.opencv_as_extern(), &input_size, score_threshold, nms_threshold, top_k, backend_id, target_id, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FaceDetectorYN_create_const_StringR_const_StringR_const_SizeR_float_float_int_int_int(model.opencv_as_extern(), config.opencv_as_extern(), &input_size, score_threshold, nms_threshold, top_k, backend_id, target_id, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::objdetect::FaceDetectorYN>::opencv_from_extern(ret) };
@@ -1364,6 +1718,68 @@ impl dyn FaceDetectorYN + '_ {
 	}
 	
 }
+
+/// Builder for `<dyn FaceDetectorYN>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FaceDetectorYN_CreateBuilder<'a> {
+	model: &'a str,
+	config: &'a str,
+	input_size: core::Size,
+	score_threshold: f32,
+	nms_threshold: f32,
+	top_k: i32,
+	backend_id: i32,
+	target_id: i32,
+}
+
+impl<'a> FaceDetectorYN_CreateBuilder<'a> {
+	#[inline]
+	pub fn new(model: &'a str, config: &'a str, input_size: core::Size) -> Self {
+		Self { model, config, input_size, score_threshold: 0.9, nms_threshold: 0.3, top_k: 5000, backend_id: 0, target_id: 0 }
+	}
+	
+	/// C++ default: `0.9f`
+	#[inline]
+	pub fn with_score_threshold(mut self, score_threshold: f32) -> Self {
+		self.score_threshold = score_threshold;
+		self
+	}
+	
+	/// C++ default: `0.3f`
+	#[inline]
+	pub fn with_nms_threshold(mut self, nms_threshold: f32) -> Self {
+		self.nms_threshold = nms_threshold;
+		self
+	}
+	
+	/// C++ default: `5000`
+	#[inline]
+	pub fn with_top_k(mut self, top_k: i32) -> Self {
+		self.top_k = top_k;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_backend_id(mut self, backend_id: i32) -> Self {
+		self.backend_id = backend_id;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_target_id(mut self, target_id: i32) -> Self {
+		self.target_id = target_id;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::objdetect::FaceDetectorYN>> {
+		<dyn FaceDetectorYN>::create(self.model, self.config, self.input_size, self.score_threshold, self.nms_threshold, self.top_k, self.backend_id, self.target_id)
+	}
+}
 /// DNN-based face recognizer, model download link: https://drive.google.com/file/d/1ClK9WiB492c5OZFKveF3XiHCejoOxINW/view.
 pub trait FaceRecognizerSFConst {
 	fn as_raw_FaceRecognizerSF(&self) -> *const c_void;
@@ -1375,11 +1791,12 @@ pub trait FaceRecognizerSFConst {
 	/// * aligned_img: output aligned image
 	#[inline]
 	fn align_crop(&self, src_img: &dyn core::ToInputArray, face_box: &dyn core::ToInputArray, aligned_img: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("FaceRecognizerSF", self.as_raw_FaceRecognizerSF())?;
 		input_array_arg!(src_img);
 		input_array_arg!(face_box);
 		output_array_arg!(aligned_img);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FaceRecognizerSF_alignCrop_const_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_FaceRecognizerSF(), src_img.as_raw__InputArray(), face_box.as_raw__InputArray(), aligned_img.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FaceRecognizerSF_alignCrop_const_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_FaceRecognizerSF(), src_img.as_raw__InputArray(), face_box.as_raw__InputArray(), aligned_img.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1395,10 +1812,11 @@ pub trait FaceRecognizerSFConst {
 	/// * dis_type: FaceRecognizerSF::FR_COSINE
 	#[inline]
 	fn match_(&self, _face_feature1: &dyn core::ToInputArray, _face_feature2: &dyn core::ToInputArray, dis_type: i32) -> Result<f64> {
+		crate::core::validity::check("FaceRecognizerSF", self.as_raw_FaceRecognizerSF())?;
 		input_array_arg!(_face_feature1);
 		input_array_arg!(_face_feature2);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FaceRecognizerSF_match_const_const__InputArrayR_const__InputArrayR_int(self.as_raw_FaceRecognizerSF(), _face_feature1.as_raw__InputArray(), _face_feature2.as_raw__InputArray(), dis_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FaceRecognizerSF_match_const_const__InputArrayR_const__InputArrayR_int(self.as_raw_FaceRecognizerSF(), _face_feature1.as_raw__InputArray(), _face_feature2.as_raw__InputArray(), dis_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1415,10 +1833,11 @@ pub trait FaceRecognizerSF: crate::objdetect::FaceRecognizerSFConst {
 	/// * face_feature: output face feature
 	#[inline]
 	fn feature(&mut self, aligned_img: &dyn core::ToInputArray, face_feature: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("FaceRecognizerSF", self.as_raw_mut_FaceRecognizerSF())?;
 		input_array_arg!(aligned_img);
 		output_array_arg!(face_feature);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FaceRecognizerSF_feature_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_FaceRecognizerSF(), aligned_img.as_raw__InputArray(), face_feature.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FaceRecognizerSF_feature_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_FaceRecognizerSF(), aligned_img.as_raw__InputArray(), face_feature.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1442,7 +1861,7 @@ impl dyn FaceRecognizerSF + '_ {
 		extern_container_arg!(model);
 		extern_container_arg!(config);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FaceRecognizerSF_create_const_StringR_const_StringR_int_int(model.opencv_as_extern(), config.opencv_as_extern(), backend_id, target_id, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FaceRecognizerSF_create_const_StringR_const_StringR_int_int(model.opencv_as_extern(), config.opencv_as_extern(), backend_id, target_id, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::objdetect::FaceRecognizerSF>::opencv_from_extern(ret) };
@@ -1471,125 +1890,261 @@ pub trait HOGDescriptorTraitConst {
 	/// Detection window size. Align to block size and block stride. Default value is Size(64,128).
 	#[inline]
 	fn win_size(&self) -> core::Size {
+		crate::core::validity::debug_check("HOGDescriptor", self.as_raw_HOGDescriptor());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HOGDescriptor_getPropWinSize_const(self.as_raw_HOGDescriptor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HOGDescriptor_getPropWinSize_const(self.as_raw_HOGDescriptor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [win_size](HOGDescriptorTraitConst::win_size), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_win_size(&self) -> Result<core::Size> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_HOGDescriptor())?;
+		Ok(HOGDescriptorTraitConst::win_size(self))
+	}
+	
 	/// Block size in pixels. Align to cell size. Default value is Size(16,16).
 	#[inline]
 	fn block_size(&self) -> core::Size {
+		crate::core::validity::debug_check("HOGDescriptor", self.as_raw_HOGDescriptor());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HOGDescriptor_getPropBlockSize_const(self.as_raw_HOGDescriptor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HOGDescriptor_getPropBlockSize_const(self.as_raw_HOGDescriptor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [block_size](HOGDescriptorTraitConst::block_size), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_block_size(&self) -> Result<core::Size> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_HOGDescriptor())?;
+		Ok(HOGDescriptorTraitConst::block_size(self))
+	}
+	
 	/// Block stride. It must be a multiple of cell size. Default value is Size(8,8).
 	#[inline]
 	fn block_stride(&self) -> core::Size {
+		crate::core::validity::debug_check("HOGDescriptor", self.as_raw_HOGDescriptor());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HOGDescriptor_getPropBlockStride_const(self.as_raw_HOGDescriptor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HOGDescriptor_getPropBlockStride_const(self.as_raw_HOGDescriptor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [block_stride](HOGDescriptorTraitConst::block_stride), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_block_stride(&self) -> Result<core::Size> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_HOGDescriptor())?;
+		Ok(HOGDescriptorTraitConst::block_stride(self))
+	}
+	
 	/// Cell size. Default value is Size(8,8).
 	#[inline]
 	fn cell_size(&self) -> core::Size {
+		crate::core::validity::debug_check("HOGDescriptor", self.as_raw_HOGDescriptor());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HOGDescriptor_getPropCellSize_const(self.as_raw_HOGDescriptor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HOGDescriptor_getPropCellSize_const(self.as_raw_HOGDescriptor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [cell_size](HOGDescriptorTraitConst::cell_size), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_cell_size(&self) -> Result<core::Size> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_HOGDescriptor())?;
+		Ok(HOGDescriptorTraitConst::cell_size(self))
+	}
+	
 	/// Number of bins used in the calculation of histogram of gradients. Default value is 9.
 	#[inline]
 	fn nbins(&self) -> i32 {
-		let ret = unsafe { sys::cv_HOGDescriptor_getPropNbins_const(self.as_raw_HOGDescriptor()) };
+		crate::core::validity::debug_check("HOGDescriptor", self.as_raw_HOGDescriptor());
+		let ret = unsafe { ffi_call!(cv_HOGDescriptor_getPropNbins_const(self.as_raw_HOGDescriptor())) };
 		ret
 	}
 	
+	/// Non-panicking version of [nbins](HOGDescriptorTraitConst::nbins), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_nbins(&self) -> Result<i32> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_HOGDescriptor())?;
+		Ok(HOGDescriptorTraitConst::nbins(self))
+	}
+	
 	/// not documented
 	#[inline]
 	fn deriv_aperture(&self) -> i32 {
-		let ret = unsafe { sys::cv_HOGDescriptor_getPropDerivAperture_const(self.as_raw_HOGDescriptor()) };
+		crate::core::validity::debug_check("HOGDescriptor", self.as_raw_HOGDescriptor());
+		let ret = unsafe { ffi_call!(cv_HOGDescriptor_getPropDerivAperture_const(self.as_raw_HOGDescriptor())) };
 		ret
 	}
 	
+	/// Non-panicking version of [deriv_aperture](HOGDescriptorTraitConst::deriv_aperture), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_deriv_aperture(&self) -> Result<i32> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_HOGDescriptor())?;
+		Ok(HOGDescriptorTraitConst::deriv_aperture(self))
+	}
+	
 	/// Gaussian smoothing window parameter.
 	#[inline]
 	fn win_sigma(&self) -> f64 {
-		let ret = unsafe { sys::cv_HOGDescriptor_getPropWinSigma_const(self.as_raw_HOGDescriptor()) };
+		crate::core::validity::debug_check("HOGDescriptor", self.as_raw_HOGDescriptor());
+		let ret = unsafe { ffi_call!(cv_HOGDescriptor_getPropWinSigma_const(self.as_raw_HOGDescriptor())) };
 		ret
 	}
 	
+	/// Non-panicking version of [win_sigma](HOGDescriptorTraitConst::win_sigma), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_win_sigma(&self) -> Result<f64> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_HOGDescriptor())?;
+		Ok(HOGDescriptorTraitConst::win_sigma(self))
+	}
+	
 	/// histogramNormType
 	#[inline]
 	fn histogram_norm_type(&self) -> crate::objdetect::HOGDescriptor_HistogramNormType {
+		crate::core::validity::debug_check("HOGDescriptor", self.as_raw_HOGDescriptor());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HOGDescriptor_getPropHistogramNormType_const(self.as_raw_HOGDescriptor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HOGDescriptor_getPropHistogramNormType_const(self.as_raw_HOGDescriptor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [histogram_norm_type](HOGDescriptorTraitConst::histogram_norm_type), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_histogram_norm_type(&self) -> Result<crate::objdetect::HOGDescriptor_HistogramNormType> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_HOGDescriptor())?;
+		Ok(HOGDescriptorTraitConst::histogram_norm_type(self))
+	}
+	
 	/// L2-Hys normalization method shrinkage.
 	#[inline]
 	fn l2_hys_threshold(&self) -> f64 {
-		let ret = unsafe { sys::cv_HOGDescriptor_getPropL2HysThreshold_const(self.as_raw_HOGDescriptor()) };
+		crate::core::validity::debug_check("HOGDescriptor", self.as_raw_HOGDescriptor());
+		let ret = unsafe { ffi_call!(cv_HOGDescriptor_getPropL2HysThreshold_const(self.as_raw_HOGDescriptor())) };
 		ret
 	}
 	
+	/// Non-panicking version of [l2_hys_threshold](HOGDescriptorTraitConst::l2_hys_threshold), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_l2_hys_threshold(&self) -> Result<f64> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_HOGDescriptor())?;
+		Ok(HOGDescriptorTraitConst::l2_hys_threshold(self))
+	}
+	
 	/// Flag to specify whether the gamma correction preprocessing is required or not.
 	#[inline]
 	fn gamma_correction(&self) -> bool {
-		let ret = unsafe { sys::cv_HOGDescriptor_getPropGammaCorrection_const(self.as_raw_HOGDescriptor()) };
+		crate::core::validity::debug_check("HOGDescriptor", self.as_raw_HOGDescriptor());
+		let ret = unsafe { ffi_call!(cv_HOGDescriptor_getPropGammaCorrection_const(self.as_raw_HOGDescriptor())) };
 		ret
 	}
 	
+	/// Non-panicking version of [gamma_correction](HOGDescriptorTraitConst::gamma_correction), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_gamma_correction(&self) -> Result<bool> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_HOGDescriptor())?;
+		Ok(HOGDescriptorTraitConst::gamma_correction(self))
+	}
+	
 	/// coefficients for the linear SVM classifier.
 	#[inline]
 	fn svm_detector(&self) -> core::Vector<f32> {
-		let ret = unsafe { sys::cv_HOGDescriptor_getPropSvmDetector_const(self.as_raw_HOGDescriptor()) };
+		crate::core::validity::debug_check("HOGDescriptor", self.as_raw_HOGDescriptor());
+		let ret = unsafe { ffi_call!(cv_HOGDescriptor_getPropSvmDetector_const(self.as_raw_HOGDescriptor())) };
 		let ret = unsafe { core::Vector::<f32>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [svm_detector](HOGDescriptorTraitConst::svm_detector), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_svm_detector(&self) -> Result<core::Vector<f32>> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_HOGDescriptor())?;
+		Ok(HOGDescriptorTraitConst::svm_detector(self))
+	}
+	
 	/// coefficients for the linear SVM classifier used when OpenCL is enabled
 	#[inline]
 	fn ocl_svm_detector(&self) -> core::UMat {
-		let ret = unsafe { sys::cv_HOGDescriptor_getPropOclSvmDetector_const(self.as_raw_HOGDescriptor()) };
+		crate::core::validity::debug_check("HOGDescriptor", self.as_raw_HOGDescriptor());
+		let ret = unsafe { ffi_call!(cv_HOGDescriptor_getPropOclSvmDetector_const(self.as_raw_HOGDescriptor())) };
 		let ret = unsafe { core::UMat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [ocl_svm_detector](HOGDescriptorTraitConst::ocl_svm_detector), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_ocl_svm_detector(&self) -> Result<core::UMat> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_HOGDescriptor())?;
+		Ok(HOGDescriptorTraitConst::ocl_svm_detector(self))
+	}
+	
 	/// not documented
 	#[inline]
 	fn free_coef(&self) -> f32 {
-		let ret = unsafe { sys::cv_HOGDescriptor_getPropFree_coef_const(self.as_raw_HOGDescriptor()) };
+		crate::core::validity::debug_check("HOGDescriptor", self.as_raw_HOGDescriptor());
+		let ret = unsafe { ffi_call!(cv_HOGDescriptor_getPropFree_coef_const(self.as_raw_HOGDescriptor())) };
 		ret
 	}
 	
+	/// Non-panicking version of [free_coef](HOGDescriptorTraitConst::free_coef), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_free_coef(&self) -> Result<f32> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_HOGDescriptor())?;
+		Ok(HOGDescriptorTraitConst::free_coef(self))
+	}
+	
 	/// Maximum number of detection window increases. Default value is 64
 	#[inline]
 	fn nlevels(&self) -> i32 {
-		let ret = unsafe { sys::cv_HOGDescriptor_getPropNlevels_const(self.as_raw_HOGDescriptor()) };
+		crate::core::validity::debug_check("HOGDescriptor", self.as_raw_HOGDescriptor());
+		let ret = unsafe { ffi_call!(cv_HOGDescriptor_getPropNlevels_const(self.as_raw_HOGDescriptor())) };
 		ret
 	}
 	
+	/// Non-panicking version of [nlevels](HOGDescriptorTraitConst::nlevels), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_nlevels(&self) -> Result<i32> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_HOGDescriptor())?;
+		Ok(HOGDescriptorTraitConst::nlevels(self))
+	}
+	
 	/// Indicates signed gradient will be used or not
 	#[inline]
 	fn signed_gradient(&self) -> bool {
-		let ret = unsafe { sys::cv_HOGDescriptor_getPropSignedGradient_const(self.as_raw_HOGDescriptor()) };
+		crate::core::validity::debug_check("HOGDescriptor", self.as_raw_HOGDescriptor());
+		let ret = unsafe { ffi_call!(cv_HOGDescriptor_getPropSignedGradient_const(self.as_raw_HOGDescriptor())) };
 		ret
 	}
 	
+	/// Non-panicking version of [signed_gradient](HOGDescriptorTraitConst::signed_gradient), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_signed_gradient(&self) -> Result<bool> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_HOGDescriptor())?;
+		Ok(HOGDescriptorTraitConst::signed_gradient(self))
+	}
+	
 	/// Returns the number of coefficients required for the classification.
 	#[inline]
 	fn get_descriptor_size(&self) -> Result<size_t> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_HOGDescriptor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HOGDescriptor_getDescriptorSize_const(self.as_raw_HOGDescriptor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HOGDescriptor_getDescriptorSize_const(self.as_raw_HOGDescriptor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1598,8 +2153,9 @@ pub trait HOGDescriptorTraitConst {
 	/// Checks if detector size equal to descriptor size.
 	#[inline]
 	fn check_detector_size(&self) -> Result<bool> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_HOGDescriptor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HOGDescriptor_checkDetectorSize_const(self.as_raw_HOGDescriptor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HOGDescriptor_checkDetectorSize_const(self.as_raw_HOGDescriptor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1608,8 +2164,9 @@ pub trait HOGDescriptorTraitConst {
 	/// Returns winSigma value
 	#[inline]
 	fn get_win_sigma(&self) -> Result<f64> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_HOGDescriptor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HOGDescriptor_getWinSigma_const(self.as_raw_HOGDescriptor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HOGDescriptor_getWinSigma_const(self.as_raw_HOGDescriptor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1621,9 +2178,10 @@ pub trait HOGDescriptorTraitConst {
 	/// * objname: Object name
 	#[inline]
 	fn write(&self, fs: &mut core::FileStorage, objname: &str) -> Result<()> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_HOGDescriptor())?;
 		extern_container_arg!(objname);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HOGDescriptor_write_const_FileStorageR_const_StringR(self.as_raw_HOGDescriptor(), fs.as_raw_mut_FileStorage(), objname.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HOGDescriptor_write_const_FileStorageR_const_StringR(self.as_raw_HOGDescriptor(), fs.as_raw_mut_FileStorage(), objname.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1638,10 +2196,11 @@ pub trait HOGDescriptorTraitConst {
 	/// * objname: String()
 	#[inline]
 	fn save(&self, filename: &str, objname: &str) -> Result<()> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_HOGDescriptor())?;
 		extern_container_arg!(filename);
 		extern_container_arg!(objname);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HOGDescriptor_save_const_const_StringR_const_StringR(self.as_raw_HOGDescriptor(), filename.opencv_as_extern(), objname.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HOGDescriptor_save_const_const_StringR_const_StringR(self.as_raw_HOGDescriptor(), filename.opencv_as_extern(), objname.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1652,8 +2211,9 @@ pub trait HOGDescriptorTraitConst {
 	/// * c: cloned HOGDescriptor
 	#[inline]
 	fn copy_to(&self, c: &mut crate::objdetect::HOGDescriptor) -> Result<()> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_HOGDescriptor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HOGDescriptor_copyTo_const_HOGDescriptorR(self.as_raw_HOGDescriptor(), c.as_raw_mut_HOGDescriptor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HOGDescriptor_copyTo_const_HOGDescriptorR(self.as_raw_HOGDescriptor(), c.as_raw_mut_HOGDescriptor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1675,9 +2235,10 @@ pub trait HOGDescriptorTraitConst {
 	/// * locations: std::vector<Point>()
 	#[inline]
 	fn compute(&self, img: &dyn core::ToInputArray, descriptors: &mut core::Vector<f32>, win_stride: core::Size, padding: core::Size, locations: &core::Vector<core::Point>) -> Result<()> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_HOGDescriptor())?;
 		input_array_arg!(img);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HOGDescriptor_compute_const_const__InputArrayR_vector_float_R_Size_Size_const_vector_Point_R(self.as_raw_HOGDescriptor(), img.as_raw__InputArray(), descriptors.as_raw_mut_VectorOff32(), win_stride.opencv_as_extern(), padding.opencv_as_extern(), locations.as_raw_VectorOfPoint(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HOGDescriptor_compute_const_const__InputArrayR_vector_float_R_Size_Size_const_vector_Point_R(self.as_raw_HOGDescriptor(), img.as_raw__InputArray(), descriptors.as_raw_mut_VectorOff32(), win_stride.opencv_as_extern(), padding.opencv_as_extern(), locations.as_raw_VectorOfPoint(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1702,9 +2263,10 @@ pub trait HOGDescriptorTraitConst {
 	/// * search_locations: std::vector<Point>()
 	#[inline]
 	fn detect_weights(&self, img: &dyn core::ToInputArray, found_locations: &mut core::Vector<core::Point>, weights: &mut core::Vector<f64>, hit_threshold: f64, win_stride: core::Size, padding: core::Size, search_locations: &core::Vector<core::Point>) -> Result<()> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_HOGDescriptor())?;
 		input_array_arg!(img);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HOGDescriptor_detect_const_const__InputArrayR_vector_Point_R_vector_double_R_double_Size_Size_const_vector_Point_R(self.as_raw_HOGDescriptor(), img.as_raw__InputArray(), found_locations.as_raw_mut_VectorOfPoint(), weights.as_raw_mut_VectorOff64(), hit_threshold, win_stride.opencv_as_extern(), padding.opencv_as_extern(), search_locations.as_raw_VectorOfPoint(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HOGDescriptor_detect_const_const__InputArrayR_vector_Point_R_vector_double_R_double_Size_Size_const_vector_Point_R(self.as_raw_HOGDescriptor(), img.as_raw__InputArray(), found_locations.as_raw_mut_VectorOfPoint(), weights.as_raw_mut_VectorOff64(), hit_threshold, win_stride.opencv_as_extern(), padding.opencv_as_extern(), search_locations.as_raw_VectorOfPoint(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1728,9 +2290,10 @@ pub trait HOGDescriptorTraitConst {
 	/// * search_locations: std::vector<Point>()
 	#[inline]
 	fn detect(&self, img: &dyn core::ToInputArray, found_locations: &mut core::Vector<core::Point>, hit_threshold: f64, win_stride: core::Size, padding: core::Size, search_locations: &core::Vector<core::Point>) -> Result<()> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_HOGDescriptor())?;
 		input_array_arg!(img);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HOGDescriptor_detect_const_const__InputArrayR_vector_Point_R_double_Size_Size_const_vector_Point_R(self.as_raw_HOGDescriptor(), img.as_raw__InputArray(), found_locations.as_raw_mut_VectorOfPoint(), hit_threshold, win_stride.opencv_as_extern(), padding.opencv_as_extern(), search_locations.as_raw_VectorOfPoint(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HOGDescriptor_detect_const_const__InputArrayR_vector_Point_R_double_Size_Size_const_vector_Point_R(self.as_raw_HOGDescriptor(), img.as_raw__InputArray(), found_locations.as_raw_mut_VectorOfPoint(), hit_threshold, win_stride.opencv_as_extern(), padding.opencv_as_extern(), search_locations.as_raw_VectorOfPoint(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1760,9 +2323,10 @@ pub trait HOGDescriptorTraitConst {
 	/// * use_meanshift_grouping: false
 	#[inline]
 	fn detect_multi_scale_weights(&self, img: &dyn core::ToInputArray, found_locations: &mut core::Vector<core::Rect>, found_weights: &mut core::Vector<f64>, hit_threshold: f64, win_stride: core::Size, padding: core::Size, scale: f64, final_threshold: f64, use_meanshift_grouping: bool) -> Result<()> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_HOGDescriptor())?;
 		input_array_arg!(img);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HOGDescriptor_detectMultiScale_const_const__InputArrayR_vector_Rect_R_vector_double_R_double_Size_Size_double_double_bool(self.as_raw_HOGDescriptor(), img.as_raw__InputArray(), found_locations.as_raw_mut_VectorOfRect(), found_weights.as_raw_mut_VectorOff64(), hit_threshold, win_stride.opencv_as_extern(), padding.opencv_as_extern(), scale, final_threshold, use_meanshift_grouping, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HOGDescriptor_detectMultiScale_const_const__InputArrayR_vector_Rect_R_vector_double_R_double_Size_Size_double_double_bool(self.as_raw_HOGDescriptor(), img.as_raw__InputArray(), found_locations.as_raw_mut_VectorOfRect(), found_weights.as_raw_mut_VectorOff64(), hit_threshold, win_stride.opencv_as_extern(), padding.opencv_as_extern(), scale, final_threshold, use_meanshift_grouping, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1791,9 +2355,10 @@ pub trait HOGDescriptorTraitConst {
 	/// * use_meanshift_grouping: false
 	#[inline]
 	fn detect_multi_scale(&self, img: &dyn core::ToInputArray, found_locations: &mut core::Vector<core::Rect>, hit_threshold: f64, win_stride: core::Size, padding: core::Size, scale: f64, final_threshold: f64, use_meanshift_grouping: bool) -> Result<()> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_HOGDescriptor())?;
 		input_array_arg!(img);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HOGDescriptor_detectMultiScale_const_const__InputArrayR_vector_Rect_R_double_Size_Size_double_double_bool(self.as_raw_HOGDescriptor(), img.as_raw__InputArray(), found_locations.as_raw_mut_VectorOfRect(), hit_threshold, win_stride.opencv_as_extern(), padding.opencv_as_extern(), scale, final_threshold, use_meanshift_grouping, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HOGDescriptor_detectMultiScale_const_const__InputArrayR_vector_Rect_R_double_Size_Size_double_double_bool(self.as_raw_HOGDescriptor(), img.as_raw__InputArray(), found_locations.as_raw_mut_VectorOfRect(), hit_threshold, win_stride.opencv_as_extern(), padding.opencv_as_extern(), scale, final_threshold, use_meanshift_grouping, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1812,11 +2377,12 @@ pub trait HOGDescriptorTraitConst {
 	/// * padding_br: Size()
 	#[inline]
 	fn compute_gradient(&self, img: &dyn core::ToInputArray, grad: &mut dyn core::ToInputOutputArray, angle_ofs: &mut dyn core::ToInputOutputArray, padding_tl: core::Size, padding_br: core::Size) -> Result<()> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_HOGDescriptor())?;
 		input_array_arg!(img);
 		input_output_array_arg!(grad);
 		input_output_array_arg!(angle_ofs);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HOGDescriptor_computeGradient_const_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_Size_Size(self.as_raw_HOGDescriptor(), img.as_raw__InputArray(), grad.as_raw__InputOutputArray(), angle_ofs.as_raw__InputOutputArray(), padding_tl.opencv_as_extern(), padding_br.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HOGDescriptor_computeGradient_const_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_Size_Size(self.as_raw_HOGDescriptor(), img.as_raw__InputArray(), grad.as_raw__InputOutputArray(), angle_ofs.as_raw__InputOutputArray(), padding_tl.opencv_as_extern(), padding_br.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1840,9 +2406,10 @@ pub trait HOGDescriptorTraitConst {
 	/// * padding: Size()
 	#[inline]
 	fn detect_roi(&self, img: &dyn core::ToInputArray, locations: &core::Vector<core::Point>, found_locations: &mut core::Vector<core::Point>, confidences: &mut core::Vector<f64>, hit_threshold: f64, win_stride: core::Size, padding: core::Size) -> Result<()> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_HOGDescriptor())?;
 		input_array_arg!(img);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HOGDescriptor_detectROI_const_const__InputArrayR_const_vector_Point_R_vector_Point_R_vector_double_R_double_Size_Size(self.as_raw_HOGDescriptor(), img.as_raw__InputArray(), locations.as_raw_VectorOfPoint(), found_locations.as_raw_mut_VectorOfPoint(), confidences.as_raw_mut_VectorOff64(), hit_threshold, win_stride.opencv_as_extern(), padding.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HOGDescriptor_detectROI_const_const__InputArrayR_const_vector_Point_R_vector_Point_R_vector_double_R_double_Size_Size(self.as_raw_HOGDescriptor(), img.as_raw__InputArray(), locations.as_raw_VectorOfPoint(), found_locations.as_raw_mut_VectorOfPoint(), confidences.as_raw_mut_VectorOff64(), hit_threshold, win_stride.opencv_as_extern(), padding.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1862,9 +2429,10 @@ pub trait HOGDescriptorTraitConst {
 	/// * group_threshold: 0
 	#[inline]
 	fn detect_multi_scale_roi(&self, img: &dyn core::ToInputArray, found_locations: &mut core::Vector<core::Rect>, locations: &mut core::Vector<crate::objdetect::DetectionROI>, hit_threshold: f64, group_threshold: i32) -> Result<()> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_HOGDescriptor())?;
 		input_array_arg!(img);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HOGDescriptor_detectMultiScaleROI_const_const__InputArrayR_vector_Rect_R_vector_DetectionROI_R_double_int(self.as_raw_HOGDescriptor(), img.as_raw__InputArray(), found_locations.as_raw_mut_VectorOfRect(), locations.as_raw_mut_VectorOfDetectionROI(), hit_threshold, group_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HOGDescriptor_detectMultiScaleROI_const_const__InputArrayR_vector_Rect_R_vector_DetectionROI_R_double_int(self.as_raw_HOGDescriptor(), img.as_raw__InputArray(), found_locations.as_raw_mut_VectorOfRect(), locations.as_raw_mut_VectorOfDetectionROI(), hit_threshold, group_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1878,8 +2446,9 @@ pub trait HOGDescriptorTraitConst {
 	/// * eps: Relative difference between sides of the rectangles to merge them into a group.
 	#[inline]
 	fn group_rectangles(&self, rect_list: &mut core::Vector<core::Rect>, weights: &mut core::Vector<f64>, group_threshold: i32, eps: f64) -> Result<()> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_HOGDescriptor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HOGDescriptor_groupRectangles_const_vector_Rect_R_vector_double_R_int_double(self.as_raw_HOGDescriptor(), rect_list.as_raw_mut_VectorOfRect(), weights.as_raw_mut_VectorOff64(), group_threshold, eps, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HOGDescriptor_groupRectangles_const_vector_Rect_R_vector_double_R_int_double(self.as_raw_HOGDescriptor(), rect_list.as_raw_mut_VectorOfRect(), weights.as_raw_mut_VectorOff64(), group_threshold, eps, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1887,111 +2456,449 @@ pub trait HOGDescriptorTraitConst {
 	
 }
 
+/// Builder for `HOGDescriptorTraitConst::compute()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct HOGDescriptor_ComputeBuilder<'a, T: ?Sized + HOGDescriptorTraitConst> {
+	instance: &'a T,
+	img: &'a dyn core::ToInputArray,
+	descriptors: &'a mut core::Vector<f32>,
+	win_stride: core::Size,
+	padding: core::Size,
+	locations: &'a core::Vector<core::Point>,
+}
+
+impl<'a, T: ?Sized + HOGDescriptorTraitConst> HOGDescriptor_ComputeBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a T, img: &'a dyn core::ToInputArray, descriptors: &'a mut core::Vector<f32>, locations: &'a core::Vector<core::Point>) -> Self {
+		Self { instance, img, descriptors, win_stride: core::Size::default(), padding: core::Size::default(), locations }
+	}
+	
+	/// C++ default: `Size()`
+	#[inline]
+	pub fn with_win_stride(mut self, win_stride: core::Size) -> Self {
+		self.win_stride = win_stride;
+		self
+	}
+	
+	/// C++ default: `Size()`
+	#[inline]
+	pub fn with_padding(mut self, padding: core::Size) -> Self {
+		self.padding = padding;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		HOGDescriptorTraitConst::compute(self.instance, self.img, self.descriptors, self.win_stride, self.padding, self.locations)
+	}
+}
+
+/// Builder for `HOGDescriptorTraitConst::detect_weights()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct HOGDescriptor_DetectWeightsBuilder<'a, T: ?Sized + HOGDescriptorTraitConst> {
+	instance: &'a T,
+	img: &'a dyn core::ToInputArray,
+	found_locations: &'a mut core::Vector<core::Point>,
+	weights: &'a mut core::Vector<f64>,
+	hit_threshold: f64,
+	win_stride: core::Size,
+	padding: core::Size,
+	search_locations: &'a core::Vector<core::Point>,
+}
+
+impl<'a, T: ?Sized + HOGDescriptorTraitConst> HOGDescriptor_DetectWeightsBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a T, img: &'a dyn core::ToInputArray, found_locations: &'a mut core::Vector<core::Point>, weights: &'a mut core::Vector<f64>, search_locations: &'a core::Vector<core::Point>) -> Self {
+		Self { instance, img, found_locations, weights, hit_threshold: 0., win_stride: core::Size::default(), padding: core::Size::default(), search_locations }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_hit_threshold(mut self, hit_threshold: f64) -> Self {
+		self.hit_threshold = hit_threshold;
+		self
+	}
+	
+	/// C++ default: `Size()`
+	#[inline]
+	pub fn with_win_stride(mut self, win_stride: core::Size) -> Self {
+		self.win_stride = win_stride;
+		self
+	}
+	
+	/// C++ default: `Size()`
+	#[inline]
+	pub fn with_padding(mut self, padding: core::Size) -> Self {
+		self.padding = padding;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		HOGDescriptorTraitConst::detect_weights(self.instance, self.img, self.found_locations, self.weights, self.hit_threshold, self.win_stride, self.padding, self.search_locations)
+	}
+}
+
+/// Builder for `HOGDescriptorTraitConst::detect()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct HOGDescriptor_DetectBuilder<'a, T: ?Sized + HOGDescriptorTraitConst> {
+	instance: &'a T,
+	img: &'a dyn core::ToInputArray,
+	found_locations: &'a mut core::Vector<core::Point>,
+	hit_threshold: f64,
+	win_stride: core::Size,
+	padding: core::Size,
+	search_locations: &'a core::Vector<core::Point>,
+}
+
+impl<'a, T: ?Sized + HOGDescriptorTraitConst> HOGDescriptor_DetectBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a T, img: &'a dyn core::ToInputArray, found_locations: &'a mut core::Vector<core::Point>, search_locations: &'a core::Vector<core::Point>) -> Self {
+		Self { instance, img, found_locations, hit_threshold: 0., win_stride: core::Size::default(), padding: core::Size::default(), search_locations }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_hit_threshold(mut self, hit_threshold: f64) -> Self {
+		self.hit_threshold = hit_threshold;
+		self
+	}
+	
+	/// C++ default: `Size()`
+	#[inline]
+	pub fn with_win_stride(mut self, win_stride: core::Size) -> Self {
+		self.win_stride = win_stride;
+		self
+	}
+	
+	/// C++ default: `Size()`
+	#[inline]
+	pub fn with_padding(mut self, padding: core::Size) -> Self {
+		self.padding = padding;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		HOGDescriptorTraitConst::detect(self.instance, self.img, self.found_locations, self.hit_threshold, self.win_stride, self.padding, self.search_locations)
+	}
+}
+
+/// Builder for `HOGDescriptorTraitConst::detect_multi_scale_weights()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct HOGDescriptor_DetectMultiScaleWeightsBuilder<'a, T: ?Sized + HOGDescriptorTraitConst> {
+	instance: &'a T,
+	img: &'a dyn core::ToInputArray,
+	found_locations: &'a mut core::Vector<core::Rect>,
+	found_weights: &'a mut core::Vector<f64>,
+	hit_threshold: f64,
+	win_stride: core::Size,
+	padding: core::Size,
+	scale: f64,
+	final_threshold: f64,
+	use_meanshift_grouping: bool,
+}
+
+impl<'a, T: ?Sized + HOGDescriptorTraitConst> HOGDescriptor_DetectMultiScaleWeightsBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a T, img: &'a dyn core::ToInputArray, found_locations: &'a mut core::Vector<core::Rect>, found_weights: &'a mut core::Vector<f64>) -> Self {
+		Self { instance, img, found_locations, found_weights, hit_threshold: 0., win_stride: core::Size::default(), padding: core::Size::default(), scale: 1.05, final_threshold: 2.0, use_meanshift_grouping: false }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_hit_threshold(mut self, hit_threshold: f64) -> Self {
+		self.hit_threshold = hit_threshold;
+		self
+	}
+	
+	/// C++ default: `Size()`
+	#[inline]
+	pub fn with_win_stride(mut self, win_stride: core::Size) -> Self {
+		self.win_stride = win_stride;
+		self
+	}
+	
+	/// C++ default: `Size()`
+	#[inline]
+	pub fn with_padding(mut self, padding: core::Size) -> Self {
+		self.padding = padding;
+		self
+	}
+	
+	/// C++ default: `1.05`
+	#[inline]
+	pub fn with_scale(mut self, scale: f64) -> Self {
+		self.scale = scale;
+		self
+	}
+	
+	/// C++ default: `2.0`
+	#[inline]
+	pub fn with_final_threshold(mut self, final_threshold: f64) -> Self {
+		self.final_threshold = final_threshold;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_use_meanshift_grouping(mut self, use_meanshift_grouping: bool) -> Self {
+		self.use_meanshift_grouping = use_meanshift_grouping;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		HOGDescriptorTraitConst::detect_multi_scale_weights(self.instance, self.img, self.found_locations, self.found_weights, self.hit_threshold, self.win_stride, self.padding, self.scale, self.final_threshold, self.use_meanshift_grouping)
+	}
+}
+
+/// Builder for `HOGDescriptorTraitConst::detect_multi_scale()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct HOGDescriptor_DetectMultiScaleBuilder<'a, T: ?Sized + HOGDescriptorTraitConst> {
+	instance: &'a T,
+	img: &'a dyn core::ToInputArray,
+	found_locations: &'a mut core::Vector<core::Rect>,
+	hit_threshold: f64,
+	win_stride: core::Size,
+	padding: core::Size,
+	scale: f64,
+	final_threshold: f64,
+	use_meanshift_grouping: bool,
+}
+
+impl<'a, T: ?Sized + HOGDescriptorTraitConst> HOGDescriptor_DetectMultiScaleBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a T, img: &'a dyn core::ToInputArray, found_locations: &'a mut core::Vector<core::Rect>) -> Self {
+		Self { instance, img, found_locations, hit_threshold: 0., win_stride: core::Size::default(), padding: core::Size::default(), scale: 1.05, final_threshold: 2.0, use_meanshift_grouping: false }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_hit_threshold(mut self, hit_threshold: f64) -> Self {
+		self.hit_threshold = hit_threshold;
+		self
+	}
+	
+	/// C++ default: `Size()`
+	#[inline]
+	pub fn with_win_stride(mut self, win_stride: core::Size) -> Self {
+		self.win_stride = win_stride;
+		self
+	}
+	
+	/// C++ default: `Size()`
+	#[inline]
+	pub fn with_padding(mut self, padding: core::Size) -> Self {
+		self.padding = padding;
+		self
+	}
+	
+	/// C++ default: `1.05`
+	#[inline]
+	pub fn with_scale(mut self, scale: f64) -> Self {
+		self.scale = scale;
+		self
+	}
+	
+	/// C++ default: `2.0`
+	#[inline]
+	pub fn with_final_threshold(mut self, final_threshold: f64) -> Self {
+		self.final_threshold = final_threshold;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_use_meanshift_grouping(mut self, use_meanshift_grouping: bool) -> Self {
+		self.use_meanshift_grouping = use_meanshift_grouping;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		HOGDescriptorTraitConst::detect_multi_scale(self.instance, self.img, self.found_locations, self.hit_threshold, self.win_stride, self.padding, self.scale, self.final_threshold, self.use_meanshift_grouping)
+	}
+}
+
+/// Builder for `HOGDescriptorTraitConst::detect_roi()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct HOGDescriptor_DetectRoiBuilder<'a, T: ?Sized + HOGDescriptorTraitConst> {
+	instance: &'a T,
+	img: &'a dyn core::ToInputArray,
+	locations: &'a core::Vector<core::Point>,
+	found_locations: &'a mut core::Vector<core::Point>,
+	confidences: &'a mut core::Vector<f64>,
+	hit_threshold: f64,
+	win_stride: core::Size,
+	padding: core::Size,
+}
+
+impl<'a, T: ?Sized + HOGDescriptorTraitConst> HOGDescriptor_DetectRoiBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a T, img: &'a dyn core::ToInputArray, locations: &'a core::Vector<core::Point>, found_locations: &'a mut core::Vector<core::Point>, confidences: &'a mut core::Vector<f64>) -> Self {
+		Self { instance, img, locations, found_locations, confidences, hit_threshold: 0., win_stride: core::Size::default(), padding: core::Size::default() }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_hit_threshold(mut self, hit_threshold: f64) -> Self {
+		self.hit_threshold = hit_threshold;
+		self
+	}
+	
+	/// C++ default: `Size()`
+	#[inline]
+	pub fn with_win_stride(mut self, win_stride: core::Size) -> Self {
+		self.win_stride = win_stride;
+		self
+	}
+	
+	/// C++ default: `Size()`
+	#[inline]
+	pub fn with_padding(mut self, padding: core::Size) -> Self {
+		self.padding = padding;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		HOGDescriptorTraitConst::detect_roi(self.instance, self.img, self.locations, self.found_locations, self.confidences, self.hit_threshold, self.win_stride, self.padding)
+	}
+}
+
 pub trait HOGDescriptorTrait: crate::objdetect::HOGDescriptorTraitConst {
 	fn as_raw_mut_HOGDescriptor(&mut self) -> *mut c_void;
 
 	/// Detection window size. Align to block size and block stride. Default value is Size(64,128).
 	#[inline]
 	fn set_win_size(&mut self, val: core::Size) {
-		let ret = unsafe { sys::cv_HOGDescriptor_setPropWinSize_Size(self.as_raw_mut_HOGDescriptor(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("HOGDescriptor", self.as_raw_mut_HOGDescriptor());
+		let ret = unsafe { ffi_call!(cv_HOGDescriptor_setPropWinSize_Size(self.as_raw_mut_HOGDescriptor(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	/// Block size in pixels. Align to cell size. Default value is Size(16,16).
 	#[inline]
 	fn set_block_size(&mut self, val: core::Size) {
-		let ret = unsafe { sys::cv_HOGDescriptor_setPropBlockSize_Size(self.as_raw_mut_HOGDescriptor(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("HOGDescriptor", self.as_raw_mut_HOGDescriptor());
+		let ret = unsafe { ffi_call!(cv_HOGDescriptor_setPropBlockSize_Size(self.as_raw_mut_HOGDescriptor(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	/// Block stride. It must be a multiple of cell size. Default value is Size(8,8).
 	#[inline]
 	fn set_block_stride(&mut self, val: core::Size) {
-		let ret = unsafe { sys::cv_HOGDescriptor_setPropBlockStride_Size(self.as_raw_mut_HOGDescriptor(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("HOGDescriptor", self.as_raw_mut_HOGDescriptor());
+		let ret = unsafe { ffi_call!(cv_HOGDescriptor_setPropBlockStride_Size(self.as_raw_mut_HOGDescriptor(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	/// Cell size. Default value is Size(8,8).
 	#[inline]
 	fn set_cell_size(&mut self, val: core::Size) {
-		let ret = unsafe { sys::cv_HOGDescriptor_setPropCellSize_Size(self.as_raw_mut_HOGDescriptor(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("HOGDescriptor", self.as_raw_mut_HOGDescriptor());
+		let ret = unsafe { ffi_call!(cv_HOGDescriptor_setPropCellSize_Size(self.as_raw_mut_HOGDescriptor(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	/// Number of bins used in the calculation of histogram of gradients. Default value is 9.
 	#[inline]
 	fn set_nbins(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_HOGDescriptor_setPropNbins_int(self.as_raw_mut_HOGDescriptor(), val) };
+		crate::core::validity::debug_check("HOGDescriptor", self.as_raw_mut_HOGDescriptor());
+		let ret = unsafe { ffi_call!(cv_HOGDescriptor_setPropNbins_int(self.as_raw_mut_HOGDescriptor(), val)) };
 		ret
 	}
 	
 	/// not documented
 	#[inline]
 	fn set_deriv_aperture(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_HOGDescriptor_setPropDerivAperture_int(self.as_raw_mut_HOGDescriptor(), val) };
+		crate::core::validity::debug_check("HOGDescriptor", self.as_raw_mut_HOGDescriptor());
+		let ret = unsafe { ffi_call!(cv_HOGDescriptor_setPropDerivAperture_int(self.as_raw_mut_HOGDescriptor(), val)) };
 		ret
 	}
 	
 	/// Gaussian smoothing window parameter.
 	#[inline]
 	fn set_win_sigma(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_HOGDescriptor_setPropWinSigma_double(self.as_raw_mut_HOGDescriptor(), val) };
+		crate::core::validity::debug_check("HOGDescriptor", self.as_raw_mut_HOGDescriptor());
+		let ret = unsafe { ffi_call!(cv_HOGDescriptor_setPropWinSigma_double(self.as_raw_mut_HOGDescriptor(), val)) };
 		ret
 	}
 	
 	/// histogramNormType
 	#[inline]
 	fn set_histogram_norm_type(&mut self, val: crate::objdetect::HOGDescriptor_HistogramNormType) {
-		let ret = unsafe { sys::cv_HOGDescriptor_setPropHistogramNormType_HistogramNormType(self.as_raw_mut_HOGDescriptor(), val) };
+		crate::core::validity::debug_check("HOGDescriptor", self.as_raw_mut_HOGDescriptor());
+		let ret = unsafe { ffi_call!(cv_HOGDescriptor_setPropHistogramNormType_HistogramNormType(self.as_raw_mut_HOGDescriptor(), val)) };
 		ret
 	}
 	
 	/// L2-Hys normalization method shrinkage.
 	#[inline]
 	fn set_l2_hys_threshold(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_HOGDescriptor_setPropL2HysThreshold_double(self.as_raw_mut_HOGDescriptor(), val) };
+		crate::core::validity::debug_check("HOGDescriptor", self.as_raw_mut_HOGDescriptor());
+		let ret = unsafe { ffi_call!(cv_HOGDescriptor_setPropL2HysThreshold_double(self.as_raw_mut_HOGDescriptor(), val)) };
 		ret
 	}
 	
 	/// Flag to specify whether the gamma correction preprocessing is required or not.
 	#[inline]
 	fn set_gamma_correction(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_HOGDescriptor_setPropGammaCorrection_bool(self.as_raw_mut_HOGDescriptor(), val) };
+		crate::core::validity::debug_check("HOGDescriptor", self.as_raw_mut_HOGDescriptor());
+		let ret = unsafe { ffi_call!(cv_HOGDescriptor_setPropGammaCorrection_bool(self.as_raw_mut_HOGDescriptor(), val)) };
 		ret
 	}
 	
 	/// coefficients for the linear SVM classifier.
 	#[inline]
 	fn set_svm_detector_vec(&mut self, mut val: core::Vector<f32>) {
-		let ret = unsafe { sys::cv_HOGDescriptor_setPropSvmDetector_vector_float_(self.as_raw_mut_HOGDescriptor(), val.as_raw_mut_VectorOff32()) };
+		crate::core::validity::debug_check("HOGDescriptor", self.as_raw_mut_HOGDescriptor());
+		let ret = unsafe { ffi_call!(cv_HOGDescriptor_setPropSvmDetector_vector_float_(self.as_raw_mut_HOGDescriptor(), val.as_raw_mut_VectorOff32())) };
 		ret
 	}
 	
 	/// coefficients for the linear SVM classifier used when OpenCL is enabled
 	#[inline]
 	fn set_ocl_svm_detector(&mut self, mut val: core::UMat) {
-		let ret = unsafe { sys::cv_HOGDescriptor_setPropOclSvmDetector_UMat(self.as_raw_mut_HOGDescriptor(), val.as_raw_mut_UMat()) };
+		crate::core::validity::debug_check("HOGDescriptor", self.as_raw_mut_HOGDescriptor());
+		let ret = unsafe { ffi_call!(cv_HOGDescriptor_setPropOclSvmDetector_UMat(self.as_raw_mut_HOGDescriptor(), val.as_raw_mut_UMat())) };
 		ret
 	}
 	
 	/// not documented
 	#[inline]
 	fn set_free_coef(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_HOGDescriptor_setPropFree_coef_float(self.as_raw_mut_HOGDescriptor(), val) };
+		crate::core::validity::debug_check("HOGDescriptor", self.as_raw_mut_HOGDescriptor());
+		let ret = unsafe { ffi_call!(cv_HOGDescriptor_setPropFree_coef_float(self.as_raw_mut_HOGDescriptor(), val)) };
 		ret
 	}
 	
 	/// Maximum number of detection window increases. Default value is 64
 	#[inline]
 	fn set_nlevels(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_HOGDescriptor_setPropNlevels_int(self.as_raw_mut_HOGDescriptor(), val) };
+		crate::core::validity::debug_check("HOGDescriptor", self.as_raw_mut_HOGDescriptor());
+		let ret = unsafe { ffi_call!(cv_HOGDescriptor_setPropNlevels_int(self.as_raw_mut_HOGDescriptor(), val)) };
 		ret
 	}
 	
 	/// Indicates signed gradient will be used or not
 	#[inline]
 	fn set_signed_gradient(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_HOGDescriptor_setPropSignedGradient_bool(self.as_raw_mut_HOGDescriptor(), val) };
+		crate::core::validity::debug_check("HOGDescriptor", self.as_raw_mut_HOGDescriptor());
+		let ret = unsafe { ffi_call!(cv_HOGDescriptor_setPropSignedGradient_bool(self.as_raw_mut_HOGDescriptor(), val)) };
 		ret
 	}
 	
@@ -2002,9 +2909,10 @@ pub trait HOGDescriptorTrait: crate::objdetect::HOGDescriptorTraitConst {
 	/// * svmdetector: coefficients for the linear SVM classifier.
 	#[inline]
 	fn set_svm_detector(&mut self, svmdetector: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_mut_HOGDescriptor())?;
 		input_array_arg!(svmdetector);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HOGDescriptor_setSVMDetector_const__InputArrayR(self.as_raw_mut_HOGDescriptor(), svmdetector.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HOGDescriptor_setSVMDetector_const__InputArrayR(self.as_raw_mut_HOGDescriptor(), svmdetector.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2015,8 +2923,9 @@ pub trait HOGDescriptorTrait: crate::objdetect::HOGDescriptorTraitConst {
 	/// * fn: File node
 	#[inline]
 	fn read(&mut self, fn_: &mut core::FileNode) -> Result<bool> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_mut_HOGDescriptor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HOGDescriptor_read_FileNodeR(self.as_raw_mut_HOGDescriptor(), fn_.as_raw_mut_FileNode(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HOGDescriptor_read_FileNodeR(self.as_raw_mut_HOGDescriptor(), fn_.as_raw_mut_FileNode(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2031,10 +2940,11 @@ pub trait HOGDescriptorTrait: crate::objdetect::HOGDescriptorTraitConst {
 	/// * objname: String()
 	#[inline]
 	fn load(&mut self, filename: &str, objname: &str) -> Result<bool> {
+		crate::core::validity::check("HOGDescriptor", self.as_raw_mut_HOGDescriptor())?;
 		extern_container_arg!(filename);
 		extern_container_arg!(objname);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HOGDescriptor_load_const_StringR_const_StringR(self.as_raw_mut_HOGDescriptor(), filename.opencv_as_extern(), objname.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HOGDescriptor_load_const_StringR_const_StringR(self.as_raw_mut_HOGDescriptor(), filename.opencv_as_extern(), objname.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2066,7 +2976,10 @@ opencv_type_boxed! { HOGDescriptor }
 impl Drop for HOGDescriptor {
 	fn drop(&mut self) {
 		extern "C" { fn cv_HOGDescriptor_delete(instance: *mut c_void); }
-		unsafe { cv_HOGDescriptor_delete(self.as_raw_mut_HOGDescriptor()) };
+		let ptr = self.as_raw_mut_HOGDescriptor();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_HOGDescriptor_delete(ptr) };
 	}
 }
 
@@ -2087,7 +3000,7 @@ impl HOGDescriptor {
 	#[inline]
 	pub fn default() -> Result<crate::objdetect::HOGDescriptor> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HOGDescriptor_HOGDescriptor(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HOGDescriptor_HOGDescriptor(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::objdetect::HOGDescriptor::opencv_from_extern(ret) };
@@ -2125,7 +3038,7 @@ impl HOGDescriptor {
 	#[inline]
 	pub fn new(_win_size: core::Size, _block_size: core::Size, _block_stride: core::Size, _cell_size: core::Size, _nbins: i32, _deriv_aperture: i32, _win_sigma: f64, _histogram_norm_type: crate::objdetect::HOGDescriptor_HistogramNormType, _l2_hys_threshold: f64, _gamma_correction: bool, _nlevels: i32, _signed_gradient: bool) -> Result<crate::objdetect::HOGDescriptor> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HOGDescriptor_HOGDescriptor_Size_Size_Size_Size_int_int_double_HistogramNormType_double_bool_int_bool(_win_size.opencv_as_extern(), _block_size.opencv_as_extern(), _block_stride.opencv_as_extern(), _cell_size.opencv_as_extern(), _nbins, _deriv_aperture, _win_sigma, _histogram_norm_type, _l2_hys_threshold, _gamma_correction, _nlevels, _signed_gradient, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HOGDescriptor_HOGDescriptor_Size_Size_Size_Size_int_int_double_HistogramNormType_double_bool_int_bool(_win_size.opencv_as_extern(), _block_size.opencv_as_extern(), _block_stride.opencv_as_extern(), _cell_size.opencv_as_extern(), _nbins, _deriv_aperture, _win_sigma, _histogram_norm_type, _l2_hys_threshold, _gamma_correction, _nlevels, _signed_gradient, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::objdetect::HOGDescriptor::opencv_from_extern(ret) };
@@ -2144,7 +3057,7 @@ impl HOGDescriptor {
 	pub fn new_from_file(filename: &str) -> Result<crate::objdetect::HOGDescriptor> {
 		extern_container_arg!(filename);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HOGDescriptor_HOGDescriptor_const_StringR(filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HOGDescriptor_HOGDescriptor_const_StringR(filename.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::objdetect::HOGDescriptor::opencv_from_extern(ret) };
@@ -2162,7 +3075,7 @@ impl HOGDescriptor {
 	#[inline]
 	pub fn copy(d: &crate::objdetect::HOGDescriptor) -> Result<crate::objdetect::HOGDescriptor> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HOGDescriptor_HOGDescriptor_const_HOGDescriptorR(d.as_raw_HOGDescriptor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HOGDescriptor_HOGDescriptor_const_HOGDescriptorR(d.as_raw_HOGDescriptor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::objdetect::HOGDescriptor::opencv_from_extern(ret) };
@@ -2173,7 +3086,7 @@ impl HOGDescriptor {
 	#[inline]
 	pub fn get_default_people_detector() -> Result<core::Vector<f32>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HOGDescriptor_getDefaultPeopleDetector(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HOGDescriptor_getDefaultPeopleDetector(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<f32>::opencv_from_extern(ret) };
@@ -2186,7 +3099,7 @@ impl HOGDescriptor {
 	#[inline]
 	pub fn get_daimler_people_detector() -> Result<core::Vector<f32>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HOGDescriptor_getDaimlerPeopleDetector(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HOGDescriptor_getDaimlerPeopleDetector(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<f32>::opencv_from_extern(ret) };
@@ -2195,6 +3108,72 @@ impl HOGDescriptor {
 	
 }
 
+/// Builder for `HOGDescriptor::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct HOGDescriptor_NewBuilder {
+	_win_size: core::Size,
+	_block_size: core::Size,
+	_block_stride: core::Size,
+	_cell_size: core::Size,
+	_nbins: i32,
+	_deriv_aperture: i32,
+	_win_sigma: f64,
+	_histogram_norm_type: crate::objdetect::HOGDescriptor_HistogramNormType,
+	_l2_hys_threshold: f64,
+	_gamma_correction: bool,
+	_nlevels: i32,
+	_signed_gradient: bool,
+}
+
+impl HOGDescriptor_NewBuilder {
+	#[inline]
+	pub fn new(_win_size: core::Size, _block_size: core::Size, _block_stride: core::Size, _cell_size: core::Size, _nbins: i32, _histogram_norm_type: crate::objdetect::HOGDescriptor_HistogramNormType, _nlevels: i32) -> Self {
+		Self { _win_size, _block_size, _block_stride, _cell_size, _nbins, _deriv_aperture: 1, _win_sigma: -1., _histogram_norm_type, _l2_hys_threshold: 0.2, _gamma_correction: false, _nlevels, _signed_gradient: false }
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with__deriv_aperture(mut self, _deriv_aperture: i32) -> Self {
+		self._deriv_aperture = _deriv_aperture;
+		self
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with__win_sigma(mut self, _win_sigma: f64) -> Self {
+		self._win_sigma = _win_sigma;
+		self
+	}
+	
+	/// C++ default: `0.2`
+	#[inline]
+	pub fn with__l2_hys_threshold(mut self, _l2_hys_threshold: f64) -> Self {
+		self._l2_hys_threshold = _l2_hys_threshold;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with__gamma_correction(mut self, _gamma_correction: bool) -> Self {
+		self._gamma_correction = _gamma_correction;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with__signed_gradient(mut self, _signed_gradient: bool) -> Self {
+		self._signed_gradient = _signed_gradient;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::objdetect::HOGDescriptor> {
+		HOGDescriptor::new(self._win_size, self._block_size, self._block_stride, self._cell_size, self._nbins, self._deriv_aperture, self._win_sigma, self._histogram_norm_type, self._l2_hys_threshold, self._gamma_correction, self._nlevels, self._signed_gradient)
+	}
+}
+
 pub trait QRCodeDetectorTraitConst {
 	fn as_raw_QRCodeDetector(&self) -> *const c_void;
 
@@ -2204,10 +3183,11 @@ pub trait QRCodeDetectorTraitConst {
 	/// * points: Output vector of vertices of the minimum-area quadrangle containing the code.
 	#[inline]
 	fn detect(&self, img: &dyn core::ToInputArray, points: &mut dyn core::ToOutputArray) -> Result<bool> {
+		crate::core::validity::check("QRCodeDetector", self.as_raw_QRCodeDetector())?;
 		input_array_arg!(img);
 		output_array_arg!(points);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_QRCodeDetector_detect_const_const__InputArrayR_const__OutputArrayR(self.as_raw_QRCodeDetector(), img.as_raw__InputArray(), points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_QRCodeDetector_detect_const_const__InputArrayR_const__OutputArrayR(self.as_raw_QRCodeDetector(), img.as_raw__InputArray(), points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2219,10 +3199,11 @@ pub trait QRCodeDetectorTraitConst {
 	/// * points: Output vector of vector of vertices of the minimum-area quadrangle containing the codes.
 	#[inline]
 	fn detect_multi(&self, img: &dyn core::ToInputArray, points: &mut dyn core::ToOutputArray) -> Result<bool> {
+		crate::core::validity::check("QRCodeDetector", self.as_raw_QRCodeDetector())?;
 		input_array_arg!(img);
 		output_array_arg!(points);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_QRCodeDetector_detectMulti_const_const__InputArrayR_const__OutputArrayR(self.as_raw_QRCodeDetector(), img.as_raw__InputArray(), points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_QRCodeDetector_detectMulti_const_const__InputArrayR_const__OutputArrayR(self.as_raw_QRCodeDetector(), img.as_raw__InputArray(), points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2239,11 +3220,12 @@ pub trait QRCodeDetectorTraitConst {
 	/// * straight_qrcode: noArray()
 	#[inline]
 	fn decode_multi(&self, img: &dyn core::ToInputArray, points: &dyn core::ToInputArray, decoded_info: &mut core::Vector<String>, straight_qrcode: &mut dyn core::ToOutputArray) -> Result<bool> {
+		crate::core::validity::check("QRCodeDetector", self.as_raw_QRCodeDetector())?;
 		input_array_arg!(img);
 		input_array_arg!(points);
 		output_array_arg!(straight_qrcode);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_QRCodeDetector_decodeMulti_const_const__InputArrayR_const__InputArrayR_vector_string_R_const__OutputArrayR(self.as_raw_QRCodeDetector(), img.as_raw__InputArray(), points.as_raw__InputArray(), decoded_info.as_raw_mut_VectorOfString(), straight_qrcode.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_QRCodeDetector_decodeMulti_const_const__InputArrayR_const__InputArrayR_vector_string_R_const__OutputArrayR(self.as_raw_QRCodeDetector(), img.as_raw__InputArray(), points.as_raw__InputArray(), decoded_info.as_raw_mut_VectorOfString(), straight_qrcode.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2260,8 +3242,9 @@ pub trait QRCodeDetectorTrait: crate::objdetect::QRCodeDetectorTraitConst {
 	/// of the scheme 1:1:3:1:1 according to QR code standard.
 	#[inline]
 	fn set_eps_x(&mut self, eps_x: f64) -> Result<()> {
+		crate::core::validity::check("QRCodeDetector", self.as_raw_mut_QRCodeDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_QRCodeDetector_setEpsX_double(self.as_raw_mut_QRCodeDetector(), eps_x, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_QRCodeDetector_setEpsX_double(self.as_raw_mut_QRCodeDetector(), eps_x, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2273,8 +3256,9 @@ pub trait QRCodeDetectorTrait: crate::objdetect::QRCodeDetectorTraitConst {
 	/// of the scheme 1:1:3:1:1 according to QR code standard.
 	#[inline]
 	fn set_eps_y(&mut self, eps_y: f64) -> Result<()> {
+		crate::core::validity::check("QRCodeDetector", self.as_raw_mut_QRCodeDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_QRCodeDetector_setEpsY_double(self.as_raw_mut_QRCodeDetector(), eps_y, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_QRCodeDetector_setEpsY_double(self.as_raw_mut_QRCodeDetector(), eps_y, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2292,11 +3276,12 @@ pub trait QRCodeDetectorTrait: crate::objdetect::QRCodeDetectorTraitConst {
 	/// * straight_qrcode: noArray()
 	#[inline]
 	fn decode(&mut self, img: &dyn core::ToInputArray, points: &dyn core::ToInputArray, straight_qrcode: &mut dyn core::ToOutputArray) -> Result<Vec<u8>> {
+		crate::core::validity::check("QRCodeDetector", self.as_raw_mut_QRCodeDetector())?;
 		input_array_arg!(img);
 		input_array_arg!(points);
 		output_array_arg!(straight_qrcode);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_QRCodeDetector_decode_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_QRCodeDetector(), img.as_raw__InputArray(), points.as_raw__InputArray(), straight_qrcode.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_QRCodeDetector_decode_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_QRCodeDetector(), img.as_raw__InputArray(), points.as_raw__InputArray(), straight_qrcode.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { Vec::<u8>::opencv_from_extern(ret) };
@@ -2315,11 +3300,12 @@ pub trait QRCodeDetectorTrait: crate::objdetect::QRCodeDetectorTraitConst {
 	/// * straight_qrcode: noArray()
 	#[inline]
 	fn decode_curved(&mut self, img: &dyn core::ToInputArray, points: &dyn core::ToInputArray, straight_qrcode: &mut dyn core::ToOutputArray) -> Result<Vec<u8>> {
+		crate::core::validity::check("QRCodeDetector", self.as_raw_mut_QRCodeDetector())?;
 		input_array_arg!(img);
 		input_array_arg!(points);
 		output_array_arg!(straight_qrcode);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_QRCodeDetector_decodeCurved_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_QRCodeDetector(), img.as_raw__InputArray(), points.as_raw__InputArray(), straight_qrcode.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_QRCodeDetector_decodeCurved_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_QRCodeDetector(), img.as_raw__InputArray(), points.as_raw__InputArray(), straight_qrcode.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { Vec::<u8>::opencv_from_extern(ret) };
@@ -2338,11 +3324,12 @@ pub trait QRCodeDetectorTrait: crate::objdetect::QRCodeDetectorTraitConst {
 	/// * straight_qrcode: noArray()
 	#[inline]
 	fn detect_and_decode(&mut self, img: &dyn core::ToInputArray, points: &mut dyn core::ToOutputArray, straight_qrcode: &mut dyn core::ToOutputArray) -> Result<Vec<u8>> {
+		crate::core::validity::check("QRCodeDetector", self.as_raw_mut_QRCodeDetector())?;
 		input_array_arg!(img);
 		output_array_arg!(points);
 		output_array_arg!(straight_qrcode);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_QRCodeDetector_detectAndDecode_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_QRCodeDetector(), img.as_raw__InputArray(), points.as_raw__OutputArray(), straight_qrcode.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_QRCodeDetector_detectAndDecode_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_QRCodeDetector(), img.as_raw__InputArray(), points.as_raw__OutputArray(), straight_qrcode.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { Vec::<u8>::opencv_from_extern(ret) };
@@ -2361,11 +3348,12 @@ pub trait QRCodeDetectorTrait: crate::objdetect::QRCodeDetectorTraitConst {
 	/// * straight_qrcode: noArray()
 	#[inline]
 	fn detect_and_decode_curved(&mut self, img: &dyn core::ToInputArray, points: &mut dyn core::ToOutputArray, straight_qrcode: &mut dyn core::ToOutputArray) -> Result<Vec<u8>> {
+		crate::core::validity::check("QRCodeDetector", self.as_raw_mut_QRCodeDetector())?;
 		input_array_arg!(img);
 		output_array_arg!(points);
 		output_array_arg!(straight_qrcode);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_QRCodeDetector_detectAndDecodeCurved_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_QRCodeDetector(), img.as_raw__InputArray(), points.as_raw__OutputArray(), straight_qrcode.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_QRCodeDetector_detectAndDecodeCurved_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_QRCodeDetector(), img.as_raw__InputArray(), points.as_raw__OutputArray(), straight_qrcode.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { Vec::<u8>::opencv_from_extern(ret) };
@@ -2383,7 +3371,10 @@ opencv_type_boxed! { QRCodeDetector }
 impl Drop for QRCodeDetector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_QRCodeDetector_delete(instance: *mut c_void); }
-		unsafe { cv_QRCodeDetector_delete(self.as_raw_mut_QRCodeDetector()) };
+		let ptr = self.as_raw_mut_QRCodeDetector();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_QRCodeDetector_delete(ptr) };
 	}
 }
 
@@ -2401,7 +3392,7 @@ impl QRCodeDetector {
 	#[inline]
 	pub fn default() -> Result<crate::objdetect::QRCodeDetector> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_QRCodeDetector_QRCodeDetector(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_QRCodeDetector_QRCodeDetector(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::objdetect::QRCodeDetector::opencv_from_extern(ret) };
@@ -2424,10 +3415,11 @@ pub trait QRCodeEncoder: crate::objdetect::QRCodeEncoderConst {
 	/// * qrcode: Generated QR code.
 	#[inline]
 	fn encode(&mut self, encoded_info: &str, qrcode: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("QRCodeEncoder", self.as_raw_mut_QRCodeEncoder())?;
 		extern_container_arg!(encoded_info);
 		output_array_arg!(qrcode);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_QRCodeEncoder_encode_const_StringR_const__OutputArrayR(self.as_raw_mut_QRCodeEncoder(), encoded_info.opencv_as_extern(), qrcode.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_QRCodeEncoder_encode_const_StringR_const__OutputArrayR(self.as_raw_mut_QRCodeEncoder(), encoded_info.opencv_as_extern(), qrcode.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2439,10 +3431,11 @@ pub trait QRCodeEncoder: crate::objdetect::QRCodeEncoderConst {
 	/// * qrcodes: Vector of generated QR codes.
 	#[inline]
 	fn encode_structured_append(&mut self, encoded_info: &str, qrcodes: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("QRCodeEncoder", self.as_raw_mut_QRCodeEncoder())?;
 		extern_container_arg!(encoded_info);
 		output_array_arg!(qrcodes);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_QRCodeEncoder_encodeStructuredAppend_const_StringR_const__OutputArrayR(self.as_raw_mut_QRCodeEncoder(), encoded_info.opencv_as_extern(), qrcodes.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_QRCodeEncoder_encodeStructuredAppend_const_StringR_const__OutputArrayR(self.as_raw_mut_QRCodeEncoder(), encoded_info.opencv_as_extern(), qrcodes.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2460,7 +3453,7 @@ impl dyn QRCodeEncoder + '_ {
 	#[inline]
 	pub fn create(parameters: crate::objdetect::QRCodeEncoder_Params) -> Result<core::Ptr<dyn crate::objdetect::QRCodeEncoder>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_QRCodeEncoder_create_const_ParamsR(&parameters, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_QRCodeEncoder_create_const_ParamsR(&parameters, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::objdetect::QRCodeEncoder>::opencv_from_extern(ret) };
@@ -2490,7 +3483,7 @@ impl QRCodeEncoder_Params {
 	#[inline]
 	pub fn default() -> Result<crate::objdetect::QRCodeEncoder_Params> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_QRCodeEncoder_Params_Params(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_QRCodeEncoder_Params_Params(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2505,10 +3498,19 @@ pub trait SimilarRectsTraitConst {
 
 	#[inline]
 	fn eps(&self) -> f64 {
-		let ret = unsafe { sys::cv_SimilarRects_getPropEps_const(self.as_raw_SimilarRects()) };
+		crate::core::validity::debug_check("SimilarRects", self.as_raw_SimilarRects());
+		let ret = unsafe { ffi_call!(cv_SimilarRects_getPropEps_const(self.as_raw_SimilarRects())) };
 		ret
 	}
 	
+	/// Non-panicking version of [eps](SimilarRectsTraitConst::eps), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_eps(&self) -> Result<f64> {
+		crate::core::validity::check("SimilarRects", self.as_raw_SimilarRects())?;
+		Ok(SimilarRectsTraitConst::eps(self))
+	}
+	
 }
 
 pub trait SimilarRectsTrait: crate::objdetect::SimilarRectsTraitConst {
@@ -2516,7 +3518,8 @@ pub trait SimilarRectsTrait: crate::objdetect::SimilarRectsTraitConst {
 
 	#[inline]
 	fn set_eps(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_SimilarRects_setPropEps_double(self.as_raw_mut_SimilarRects(), val) };
+		crate::core::validity::debug_check("SimilarRects", self.as_raw_mut_SimilarRects());
+		let ret = unsafe { ffi_call!(cv_SimilarRects_setPropEps_double(self.as_raw_mut_SimilarRects(), val)) };
 		ret
 	}
 	
@@ -2533,7 +3536,10 @@ opencv_type_boxed! { SimilarRects }
 impl Drop for SimilarRects {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SimilarRects_delete(instance: *mut c_void); }
-		unsafe { cv_SimilarRects_delete(self.as_raw_mut_SimilarRects()) };
+		let ptr = self.as_raw_mut_SimilarRects();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_SimilarRects_delete(ptr) };
 	}
 }
 
@@ -2551,7 +3557,7 @@ impl SimilarRects {
 	#[inline]
 	pub fn new(_eps: f64) -> Result<crate::objdetect::SimilarRects> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_SimilarRects_SimilarRects_double(_eps, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_SimilarRects_SimilarRects_double(_eps, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::objdetect::SimilarRects::opencv_from_extern(ret) };
@@ -120,7 +120,7 @@ pub fn calc_global_orientation(orientation: &dyn core::ToInputArray, mask: &dyn
 	input_array_arg!(mask);
 	input_array_arg!(mhi);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_motempl_calcGlobalOrientation_const__InputArrayR_const__InputArrayR_const__InputArrayR_double_double(orientation.as_raw__InputArray(), mask.as_raw__InputArray(), mhi.as_raw__InputArray(), timestamp, duration, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_motempl_calcGlobalOrientation_const__InputArrayR_const__InputArrayR_const__InputArrayR_double_double(orientation.as_raw__InputArray(), mask.as_raw__InputArray(), mhi.as_raw__InputArray(), timestamp, duration, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -164,12 +164,44 @@ pub fn calc_motion_gradient(mhi: &dyn core::ToInputArray, mask: &mut dyn core::T
 	output_array_arg!(mask);
 	output_array_arg!(orientation);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_motempl_calcMotionGradient_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_double_double_int(mhi.as_raw__InputArray(), mask.as_raw__OutputArray(), orientation.as_raw__OutputArray(), delta1, delta2, aperture_size, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_motempl_calcMotionGradient_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_double_double_int(mhi.as_raw__InputArray(), mask.as_raw__OutputArray(), orientation.as_raw__OutputArray(), delta1, delta2, aperture_size, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `calc_motion_gradient()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CalcMotionGradientBuilder<'a> {
+	mhi: &'a dyn core::ToInputArray,
+	mask: &'a mut dyn core::ToOutputArray,
+	orientation: &'a mut dyn core::ToOutputArray,
+	delta1: f64,
+	delta2: f64,
+	aperture_size: i32,
+}
+
+impl<'a> CalcMotionGradientBuilder<'a> {
+	#[inline]
+	pub fn new(mhi: &'a dyn core::ToInputArray, mask: &'a mut dyn core::ToOutputArray, orientation: &'a mut dyn core::ToOutputArray, delta1: f64, delta2: f64) -> Self {
+		Self { mhi, mask, orientation, delta1, delta2, aperture_size: 3 }
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_aperture_size(mut self, aperture_size: i32) -> Self {
+		self.aperture_size = aperture_size;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		calc_motion_gradient(self.mhi, self.mask, self.orientation, self.delta1, self.delta2, self.aperture_size)
+	}
+}
+
 /// Splits a motion history image into a few parts corresponding to separate independent motions (for
 /// example, left hand, right hand).
 /// 
@@ -190,7 +222,7 @@ pub fn segment_motion(mhi: &dyn core::ToInputArray, segmask: &mut dyn core::ToOu
 	input_array_arg!(mhi);
 	output_array_arg!(segmask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_motempl_segmentMotion_const__InputArrayR_const__OutputArrayR_vector_Rect_R_double_double(mhi.as_raw__InputArray(), segmask.as_raw__OutputArray(), bounding_rects.as_raw_mut_VectorOfRect(), timestamp, seg_thresh, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_motempl_segmentMotion_const__InputArrayR_const__OutputArrayR_vector_Rect_R_double_double(mhi.as_raw__InputArray(), segmask.as_raw__OutputArray(), bounding_rects.as_raw_mut_VectorOfRect(), timestamp, seg_thresh, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -219,7 +251,7 @@ pub fn update_motion_history(silhouette: &dyn core::ToInputArray, mhi: &mut dyn
 	input_array_arg!(silhouette);
 	input_output_array_arg!(mhi);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_motempl_updateMotionHistory_const__InputArrayR_const__InputOutputArrayR_double_double(silhouette.as_raw__InputArray(), mhi.as_raw__InputOutputArray(), timestamp, duration, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_motempl_updateMotionHistory_const__InputArrayR_const__InputOutputArrayR_double_double(silhouette.as_raw__InputArray(), mhi.as_raw__InputOutputArray(), timestamp, duration, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -303,12 +335,124 @@ pub fn calc_optical_flow_dense_rlof(i0: &dyn core::ToInputArray, i1: &dyn core::
 	input_array_arg!(i1);
 	input_output_array_arg!(flow);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_optflow_calcOpticalFlowDenseRLOF_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_Ptr_RLOFOpticalFlowParameter__float_Size_InterpolationType_int_float_float_int_int_bool_float_float_bool(i0.as_raw__InputArray(), i1.as_raw__InputArray(), flow.as_raw__InputOutputArray(), rlof_param.as_raw_mut_PtrOfRLOFOpticalFlowParameter(), forward_backward_threshold, grid_step.opencv_as_extern(), interp_type, epic_k, epic_sigma, epic_lambda, ric_sp_size, ric_slic_type, use_post_proc, fgs_lambda, fgs_sigma, use_variational_refinement, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_optflow_calcOpticalFlowDenseRLOF_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_Ptr_RLOFOpticalFlowParameter__float_Size_InterpolationType_int_float_float_int_int_bool_float_float_bool(i0.as_raw__InputArray(), i1.as_raw__InputArray(), flow.as_raw__InputOutputArray(), rlof_param.as_raw_mut_PtrOfRLOFOpticalFlowParameter(), forward_backward_threshold, grid_step.opencv_as_extern(), interp_type, epic_k, epic_sigma, epic_lambda, ric_sp_size, ric_slic_type, use_post_proc, fgs_lambda, fgs_sigma, use_variational_refinement, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `calc_optical_flow_dense_rlof()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CalcOpticalFlowDenseRlofBuilder<'a> {
+	i0: &'a dyn core::ToInputArray,
+	i1: &'a dyn core::ToInputArray,
+	flow: &'a mut dyn core::ToInputOutputArray,
+	rlof_param: core::Ptr<crate::optflow::RLOFOpticalFlowParameter>,
+	forward_backward_threshold: f32,
+	grid_step: core::Size,
+	interp_type: crate::optflow::InterpolationType,
+	epic_k: i32,
+	epic_sigma: f32,
+	epic_lambda: f32,
+	ric_sp_size: i32,
+	ric_slic_type: i32,
+	use_post_proc: bool,
+	fgs_lambda: f32,
+	fgs_sigma: f32,
+	use_variational_refinement: bool,
+}
+
+impl<'a> CalcOpticalFlowDenseRlofBuilder<'a> {
+	#[inline]
+	pub fn new(i0: &'a dyn core::ToInputArray, i1: &'a dyn core::ToInputArray, flow: &'a mut dyn core::ToInputOutputArray, rlof_param: core::Ptr<crate::optflow::RLOFOpticalFlowParameter>, interp_type: crate::optflow::InterpolationType) -> Self {
+		Self { i0, i1, flow, rlof_param, forward_backward_threshold: 0., grid_step: core::Size::new(6, 6), interp_type, epic_k: 128, epic_sigma: 0.05, epic_lambda: 100., ric_sp_size: 15, ric_slic_type: 100, use_post_proc: true, fgs_lambda: 500.0, fgs_sigma: 1.5, use_variational_refinement: false }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_forward_backward_threshold(mut self, forward_backward_threshold: f32) -> Self {
+		self.forward_backward_threshold = forward_backward_threshold;
+		self
+	}
+	
+	/// C++ default: `Size(6,6)`
+	#[inline]
+	pub fn with_grid_step(mut self, grid_step: core::Size) -> Self {
+		self.grid_step = grid_step;
+		self
+	}
+	
+	/// C++ default: `128`
+	#[inline]
+	pub fn with_epic_k(mut self, epic_k: i32) -> Self {
+		self.epic_k = epic_k;
+		self
+	}
+	
+	/// C++ default: `0.05f`
+	#[inline]
+	pub fn with_epic_sigma(mut self, epic_sigma: f32) -> Self {
+		self.epic_sigma = epic_sigma;
+		self
+	}
+	
+	/// C++ default: `100.f`
+	#[inline]
+	pub fn with_epic_lambda(mut self, epic_lambda: f32) -> Self {
+		self.epic_lambda = epic_lambda;
+		self
+	}
+	
+	/// C++ default: `15`
+	#[inline]
+	pub fn with_ric_sp_size(mut self, ric_sp_size: i32) -> Self {
+		self.ric_sp_size = ric_sp_size;
+		self
+	}
+	
+	/// C++ default: `100`
+	#[inline]
+	pub fn with_ric_slic_type(mut self, ric_slic_type: i32) -> Self {
+		self.ric_slic_type = ric_slic_type;
+		self
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_use_post_proc(mut self, use_post_proc: bool) -> Self {
+		self.use_post_proc = use_post_proc;
+		self
+	}
+	
+	/// C++ default: `500.0f`
+	#[inline]
+	pub fn with_fgs_lambda(mut self, fgs_lambda: f32) -> Self {
+		self.fgs_lambda = fgs_lambda;
+		self
+	}
+	
+	/// C++ default: `1.5f`
+	#[inline]
+	pub fn with_fgs_sigma(mut self, fgs_sigma: f32) -> Self {
+		self.fgs_sigma = fgs_sigma;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_use_variational_refinement(mut self, use_variational_refinement: bool) -> Self {
+		self.use_variational_refinement = use_variational_refinement;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		calc_optical_flow_dense_rlof(self.i0, self.i1, self.flow, self.rlof_param, self.forward_backward_threshold, self.grid_step, self.interp_type, self.epic_k, self.epic_sigma, self.epic_lambda, self.ric_sp_size, self.ric_slic_type, self.use_post_proc, self.fgs_lambda, self.fgs_sigma, self.use_variational_refinement)
+	}
+}
+
 /// Calculate an optical flow using "SimpleFlow" algorithm.
 /// 
 /// ## Parameters
@@ -344,7 +488,7 @@ pub fn calc_optical_flow_sf(from: &dyn core::ToInputArray, to: &dyn core::ToInpu
 	input_array_arg!(to);
 	output_array_arg!(flow);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_optflow_calcOpticalFlowSF_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_int_int(from.as_raw__InputArray(), to.as_raw__InputArray(), flow.as_raw__OutputArray(), layers, averaging_block_size, max_flow, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_optflow_calcOpticalFlowSF_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_int_int(from.as_raw__InputArray(), to.as_raw__InputArray(), flow.as_raw__OutputArray(), layers, averaging_block_size, max_flow, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -383,7 +527,7 @@ pub fn calc_optical_flow_sf_1(from: &dyn core::ToInputArray, to: &dyn core::ToIn
 	input_array_arg!(to);
 	output_array_arg!(flow);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_optflow_calcOpticalFlowSF_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_int_int_double_double_int_double_double_double_int_double_double_double(from.as_raw__InputArray(), to.as_raw__InputArray(), flow.as_raw__OutputArray(), layers, averaging_block_size, max_flow, sigma_dist, sigma_color, postprocess_window, sigma_dist_fix, sigma_color_fix, occ_thr, upscale_averaging_radius, upscale_sigma_dist, upscale_sigma_color, speed_up_thr, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_optflow_calcOpticalFlowSF_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_int_int_double_double_int_double_double_double_int_double_double_double(from.as_raw__InputArray(), to.as_raw__InputArray(), flow.as_raw__OutputArray(), layers, averaging_block_size, max_flow, sigma_dist, sigma_color, postprocess_window, sigma_dist_fix, sigma_color_fix, occ_thr, upscale_averaging_radius, upscale_sigma_dist, upscale_sigma_color, speed_up_thr, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -431,12 +575,46 @@ pub fn calc_optical_flow_sparse_rlof(prev_img: &dyn core::ToInputArray, next_img
 	output_array_arg!(status);
 	output_array_arg!(err);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_optflow_calcOpticalFlowSparseRLOF_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_Ptr_RLOFOpticalFlowParameter__float(prev_img.as_raw__InputArray(), next_img.as_raw__InputArray(), prev_pts.as_raw__InputArray(), next_pts.as_raw__InputOutputArray(), status.as_raw__OutputArray(), err.as_raw__OutputArray(), rlof_param.as_raw_mut_PtrOfRLOFOpticalFlowParameter(), forward_backward_threshold, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_optflow_calcOpticalFlowSparseRLOF_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_Ptr_RLOFOpticalFlowParameter__float(prev_img.as_raw__InputArray(), next_img.as_raw__InputArray(), prev_pts.as_raw__InputArray(), next_pts.as_raw__InputOutputArray(), status.as_raw__OutputArray(), err.as_raw__OutputArray(), rlof_param.as_raw_mut_PtrOfRLOFOpticalFlowParameter(), forward_backward_threshold, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `calc_optical_flow_sparse_rlof()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CalcOpticalFlowSparseRlofBuilder<'a> {
+	prev_img: &'a dyn core::ToInputArray,
+	next_img: &'a dyn core::ToInputArray,
+	prev_pts: &'a dyn core::ToInputArray,
+	next_pts: &'a mut dyn core::ToInputOutputArray,
+	status: &'a mut dyn core::ToOutputArray,
+	err: &'a mut dyn core::ToOutputArray,
+	rlof_param: core::Ptr<crate::optflow::RLOFOpticalFlowParameter>,
+	forward_backward_threshold: f32,
+}
+
+impl<'a> CalcOpticalFlowSparseRlofBuilder<'a> {
+	#[inline]
+	pub fn new(prev_img: &'a dyn core::ToInputArray, next_img: &'a dyn core::ToInputArray, prev_pts: &'a dyn core::ToInputArray, next_pts: &'a mut dyn core::ToInputOutputArray, status: &'a mut dyn core::ToOutputArray, err: &'a mut dyn core::ToOutputArray, rlof_param: core::Ptr<crate::optflow::RLOFOpticalFlowParameter>) -> Self {
+		Self { prev_img, next_img, prev_pts, next_pts, status, err, rlof_param, forward_backward_threshold: 0. }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_forward_backward_threshold(mut self, forward_backward_threshold: f32) -> Self {
+		self.forward_backward_threshold = forward_backward_threshold;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		calc_optical_flow_sparse_rlof(self.prev_img, self.next_img, self.prev_pts, self.next_pts, self.status, self.err, self.rlof_param, self.forward_backward_threshold)
+	}
+}
+
 /// Fast dense optical flow based on PyrLK sparse matches interpolation.
 /// 
 /// ## Parameters
@@ -469,12 +647,82 @@ pub fn calc_optical_flow_sparse_to_dense(from: &dyn core::ToInputArray, to: &dyn
 	input_array_arg!(to);
 	output_array_arg!(flow);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_optflow_calcOpticalFlowSparseToDense_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_int_float_bool_float_float(from.as_raw__InputArray(), to.as_raw__InputArray(), flow.as_raw__OutputArray(), grid_step, k, sigma, use_post_proc, fgs_lambda, fgs_sigma, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_optflow_calcOpticalFlowSparseToDense_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_int_float_bool_float_float(from.as_raw__InputArray(), to.as_raw__InputArray(), flow.as_raw__OutputArray(), grid_step, k, sigma, use_post_proc, fgs_lambda, fgs_sigma, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `calc_optical_flow_sparse_to_dense()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CalcOpticalFlowSparseToDenseBuilder<'a> {
+	from: &'a dyn core::ToInputArray,
+	to: &'a dyn core::ToInputArray,
+	flow: &'a mut dyn core::ToOutputArray,
+	grid_step: i32,
+	k: i32,
+	sigma: f32,
+	use_post_proc: bool,
+	fgs_lambda: f32,
+	fgs_sigma: f32,
+}
+
+impl<'a> CalcOpticalFlowSparseToDenseBuilder<'a> {
+	#[inline]
+	pub fn new(from: &'a dyn core::ToInputArray, to: &'a dyn core::ToInputArray, flow: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { from, to, flow, grid_step: 8, k: 128, sigma: 0.05, use_post_proc: true, fgs_lambda: 500.0, fgs_sigma: 1.5 }
+	}
+	
+	/// C++ default: `8`
+	#[inline]
+	pub fn with_grid_step(mut self, grid_step: i32) -> Self {
+		self.grid_step = grid_step;
+		self
+	}
+	
+	/// C++ default: `128`
+	#[inline]
+	pub fn with_k(mut self, k: i32) -> Self {
+		self.k = k;
+		self
+	}
+	
+	/// C++ default: `0.05f`
+	#[inline]
+	pub fn with_sigma(mut self, sigma: f32) -> Self {
+		self.sigma = sigma;
+		self
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_use_post_proc(mut self, use_post_proc: bool) -> Self {
+		self.use_post_proc = use_post_proc;
+		self
+	}
+	
+	/// C++ default: `500.0f`
+	#[inline]
+	pub fn with_fgs_lambda(mut self, fgs_lambda: f32) -> Self {
+		self.fgs_lambda = fgs_lambda;
+		self
+	}
+	
+	/// C++ default: `1.5f`
+	#[inline]
+	pub fn with_fgs_sigma(mut self, fgs_sigma: f32) -> Self {
+		self.fgs_sigma = fgs_sigma;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		calc_optical_flow_sparse_to_dense(self.from, self.to, self.flow, self.grid_step, self.k, self.sigma, self.use_post_proc, self.fgs_lambda, self.fgs_sigma)
+	}
+}
+
 /// DeepFlow optical flow algorithm implementation.
 /// 
 /// The class implements the DeepFlow optical flow algorithm described in [Weinzaepfel2013](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_Weinzaepfel2013) . See
@@ -502,7 +750,7 @@ pub fn calc_optical_flow_sparse_to_dense(from: &dyn core::ToInputArray, to: &dyn
 #[inline]
 pub fn create_opt_flow_deep_flow() -> Result<core::Ptr<dyn crate::video::DenseOpticalFlow>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_optflow_createOptFlow_DeepFlow(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_optflow_createOptFlow_DeepFlow(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::video::DenseOpticalFlow>::opencv_from_extern(ret) };
@@ -513,7 +761,7 @@ pub fn create_opt_flow_deep_flow() -> Result<core::Ptr<dyn crate::video::DenseOp
 #[inline]
 pub fn create_opt_flow_dense_rlof() -> Result<core::Ptr<dyn crate::video::DenseOpticalFlow>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_optflow_createOptFlow_DenseRLOF(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_optflow_createOptFlow_DenseRLOF(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::video::DenseOpticalFlow>::opencv_from_extern(ret) };
@@ -524,7 +772,7 @@ pub fn create_opt_flow_dense_rlof() -> Result<core::Ptr<dyn crate::video::DenseO
 #[inline]
 pub fn create_opt_flow_dual_tvl1() -> Result<core::Ptr<dyn crate::optflow::DualTVL1OpticalFlow>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_optflow_createOptFlow_DualTVL1(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_optflow_createOptFlow_DualTVL1(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::optflow::DualTVL1OpticalFlow>::opencv_from_extern(ret) };
@@ -535,7 +783,7 @@ pub fn create_opt_flow_dual_tvl1() -> Result<core::Ptr<dyn crate::optflow::DualT
 #[inline]
 pub fn create_opt_flow_farneback() -> Result<core::Ptr<dyn crate::video::DenseOpticalFlow>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_optflow_createOptFlow_Farneback(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_optflow_createOptFlow_Farneback(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::video::DenseOpticalFlow>::opencv_from_extern(ret) };
@@ -546,7 +794,7 @@ pub fn create_opt_flow_farneback() -> Result<core::Ptr<dyn crate::video::DenseOp
 #[inline]
 pub fn create_opt_flow_pca_flow() -> Result<core::Ptr<dyn crate::video::DenseOpticalFlow>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_optflow_createOptFlow_PCAFlow(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_optflow_createOptFlow_PCAFlow(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::video::DenseOpticalFlow>::opencv_from_extern(ret) };
@@ -557,7 +805,7 @@ pub fn create_opt_flow_pca_flow() -> Result<core::Ptr<dyn crate::video::DenseOpt
 #[inline]
 pub fn create_opt_flow_simple_flow() -> Result<core::Ptr<dyn crate::video::DenseOpticalFlow>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_optflow_createOptFlow_SimpleFlow(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_optflow_createOptFlow_SimpleFlow(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::video::DenseOpticalFlow>::opencv_from_extern(ret) };
@@ -568,7 +816,7 @@ pub fn create_opt_flow_simple_flow() -> Result<core::Ptr<dyn crate::video::Dense
 #[inline]
 pub fn create_opt_flow_sparse_rlof() -> Result<core::Ptr<dyn crate::video::SparseOpticalFlow>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_optflow_createOptFlow_SparseRLOF(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_optflow_createOptFlow_SparseRLOF(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::video::SparseOpticalFlow>::opencv_from_extern(ret) };
@@ -579,7 +827,7 @@ pub fn create_opt_flow_sparse_rlof() -> Result<core::Ptr<dyn crate::video::Spars
 #[inline]
 pub fn create_opt_flow_sparse_to_dense() -> Result<core::Ptr<dyn crate::video::DenseOpticalFlow>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_optflow_createOptFlow_SparseToDense(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_optflow_createOptFlow_SparseToDense(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::video::DenseOpticalFlow>::opencv_from_extern(ret) };
@@ -589,7 +837,7 @@ pub fn create_opt_flow_sparse_to_dense() -> Result<core::Ptr<dyn crate::video::D
 #[inline]
 pub fn read(fn_: &core::FileNode, node: &mut crate::optflow::GPCTree_Node, unnamed: crate::optflow::GPCTree_Node) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_read_const_FileNodeR_NodeR_Node(fn_.as_raw_FileNode(), node, unnamed.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_read_const_FileNodeR_NodeR_Node(fn_.as_raw_FileNode(), node, unnamed.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -599,7 +847,7 @@ pub fn read(fn_: &core::FileNode, node: &mut crate::optflow::GPCTree_Node, unnam
 pub fn write(fs: &mut core::FileStorage, name: &str, node: crate::optflow::GPCTree_Node) -> Result<()> {
 	extern_container_arg!(name);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_write_FileStorageR_const_StringR_const_NodeR(fs.as_raw_mut_FileStorage(), name.opencv_as_extern(), &node, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_write_FileStorageR_const_StringR_const_NodeR(fs.as_raw_mut_FileStorage(), name.opencv_as_extern(), &node, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -637,12 +885,13 @@ pub trait DenseRLOFOpticalFlowConst: crate::video::DenseOpticalFlowConst {
 
 	/// Configuration of the RLOF alogrithm.
 	/// ## See also
-	/// optflow::RLOFOpticalFlowParameter, getRLOFOpticalFlowParameter
-	///    optflow::RLOFOpticalFlowParameter, setRLOFOpticalFlowParameter
+	/// optflow::RLOFOpticalFlowParameter, `get_rlof_optical_flow_parameter`
+	///    optflow::RLOFOpticalFlowParameter, `set_rlof_optical_flow_parameter`
 	#[inline]
 	fn get_rlof_optical_flow_parameter(&self) -> Result<core::Ptr<crate::optflow::RLOFOpticalFlowParameter>> {
+		crate::core::validity::check("DenseRLOFOpticalFlow", self.as_raw_DenseRLOFOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_getRLOFOpticalFlowParameter_const(self.as_raw_DenseRLOFOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DenseRLOFOpticalFlow_getRLOFOpticalFlowParameter_const(self.as_raw_DenseRLOFOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::optflow::RLOFOpticalFlowParameter>::opencv_from_extern(ret) };
@@ -657,11 +906,12 @@ pub trait DenseRLOFOpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	///      *    will only be applied if the threshold > 0. This may results into a doubled runtime for the motion estimation.
 	///      *    getForwardBackward, setGridStep
 	/// ## See also
-	/// setForwardBackward
+	/// `set_forward_backward`
 	#[inline]
 	fn get_forward_backward(&self) -> Result<f32> {
+		crate::core::validity::check("DenseRLOFOpticalFlow", self.as_raw_DenseRLOFOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_getForwardBackward_const(self.as_raw_DenseRLOFOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DenseRLOFOpticalFlow_getForwardBackward_const(self.as_raw_DenseRLOFOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -673,8 +923,9 @@ pub trait DenseRLOFOpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	///      *    see also: getForwardBackward, setGridStep
 	#[inline]
 	fn get_grid_step(&self) -> Result<core::Size> {
+		crate::core::validity::check("DenseRLOFOpticalFlow", self.as_raw_DenseRLOFOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_getGridStep_const(self.as_raw_DenseRLOFOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DenseRLOFOpticalFlow_getGridStep_const(self.as_raw_DenseRLOFOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -688,8 +939,9 @@ pub trait DenseRLOFOpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	///      *    see also: ximgproc::EdgeAwareInterpolator, setInterpolation
 	#[inline]
 	fn get_interpolation(&self) -> Result<crate::optflow::InterpolationType> {
+		crate::core::validity::check("DenseRLOFOpticalFlow", self.as_raw_DenseRLOFOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_getInterpolation_const(self.as_raw_DenseRLOFOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DenseRLOFOpticalFlow_getInterpolation_const(self.as_raw_DenseRLOFOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -701,8 +953,9 @@ pub trait DenseRLOFOpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	///      *    see also: ximgproc::EdgeAwareInterpolator,  setEPICK
 	#[inline]
 	fn get_epick(&self) -> Result<i32> {
+		crate::core::validity::check("DenseRLOFOpticalFlow", self.as_raw_DenseRLOFOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_getEPICK_const(self.as_raw_DenseRLOFOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DenseRLOFOpticalFlow_getEPICK_const(self.as_raw_DenseRLOFOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -715,8 +968,9 @@ pub trait DenseRLOFOpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	///      *    see also: ximgproc::EdgeAwareInterpolator, setEPICSigma
 	#[inline]
 	fn get_epic_sigma(&self) -> Result<f32> {
+		crate::core::validity::check("DenseRLOFOpticalFlow", self.as_raw_DenseRLOFOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_getEPICSigma_const(self.as_raw_DenseRLOFOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DenseRLOFOpticalFlow_getEPICSigma_const(self.as_raw_DenseRLOFOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -728,8 +982,9 @@ pub trait DenseRLOFOpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	///      *    see also: ximgproc::EdgeAwareInterpolator, setEPICSigma
 	#[inline]
 	fn get_epic_lambda(&self) -> Result<f32> {
+		crate::core::validity::check("DenseRLOFOpticalFlow", self.as_raw_DenseRLOFOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_getEPICLambda_const(self.as_raw_DenseRLOFOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DenseRLOFOpticalFlow_getEPICLambda_const(self.as_raw_DenseRLOFOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -740,8 +995,9 @@ pub trait DenseRLOFOpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	///      *    see also: ximgproc::EdgeAwareInterpolator, setFgsLambda
 	#[inline]
 	fn get_fgs_lambda(&self) -> Result<f32> {
+		crate::core::validity::check("DenseRLOFOpticalFlow", self.as_raw_DenseRLOFOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_getFgsLambda_const(self.as_raw_DenseRLOFOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DenseRLOFOpticalFlow_getFgsLambda_const(self.as_raw_DenseRLOFOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -752,8 +1008,9 @@ pub trait DenseRLOFOpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	///      *    see also: ximgproc::EdgeAwareInterpolator, ximgproc::fastGlobalSmootherFilter, setFgsSigma
 	#[inline]
 	fn get_fgs_sigma(&self) -> Result<f32> {
+		crate::core::validity::check("DenseRLOFOpticalFlow", self.as_raw_DenseRLOFOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_getFgsSigma_const(self.as_raw_DenseRLOFOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DenseRLOFOpticalFlow_getFgsSigma_const(self.as_raw_DenseRLOFOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -765,8 +1022,9 @@ pub trait DenseRLOFOpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	///      *    see also: ximgproc::fastGlobalSmootherFilter, setUsePostProc
 	#[inline]
 	fn get_use_post_proc(&self) -> Result<bool> {
+		crate::core::validity::check("DenseRLOFOpticalFlow", self.as_raw_DenseRLOFOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_getUsePostProc_const(self.as_raw_DenseRLOFOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DenseRLOFOpticalFlow_getUsePostProc_const(self.as_raw_DenseRLOFOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -778,8 +1036,9 @@ pub trait DenseRLOFOpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	///      *    see also: ximgproc::fastGlobalSmootherFilter, setUsePostProc
 	#[inline]
 	fn get_use_variational_refinement(&self) -> Result<bool> {
+		crate::core::validity::check("DenseRLOFOpticalFlow", self.as_raw_DenseRLOFOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_getUseVariationalRefinement_const(self.as_raw_DenseRLOFOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DenseRLOFOpticalFlow_getUseVariationalRefinement_const(self.as_raw_DenseRLOFOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -791,8 +1050,9 @@ pub trait DenseRLOFOpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	///    *    see also: setRICSPSize
 	#[inline]
 	fn get_ricsp_size(&self) -> Result<i32> {
+		crate::core::validity::check("DenseRLOFOpticalFlow", self.as_raw_DenseRLOFOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_getRICSPSize_const(self.as_raw_DenseRLOFOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DenseRLOFOpticalFlow_getRICSPSize_const(self.as_raw_DenseRLOFOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -807,8 +1067,9 @@ pub trait DenseRLOFOpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	///      *    setRICSLICType
 	#[inline]
 	fn get_ricslic_type(&self) -> Result<i32> {
+		crate::core::validity::check("DenseRLOFOpticalFlow", self.as_raw_DenseRLOFOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_getRICSLICType_const(self.as_raw_DenseRLOFOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DenseRLOFOpticalFlow_getRICSLICType_const(self.as_raw_DenseRLOFOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -821,11 +1082,12 @@ pub trait DenseRLOFOpticalFlow: crate::optflow::DenseRLOFOpticalFlowConst + crat
 
 	/// Configuration of the RLOF alogrithm.
 	/// ## See also
-	/// optflow::RLOFOpticalFlowParameter, getRLOFOpticalFlowParameter
+	/// optflow::RLOFOpticalFlowParameter, `get_rlof_optical_flow_parameter`
 	#[inline]
 	fn set_rlof_optical_flow_parameter(&mut self, mut val: core::Ptr<crate::optflow::RLOFOpticalFlowParameter>) -> Result<()> {
+		crate::core::validity::check("DenseRLOFOpticalFlow", self.as_raw_mut_DenseRLOFOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_setRLOFOpticalFlowParameter_Ptr_RLOFOpticalFlowParameter_(self.as_raw_mut_DenseRLOFOpticalFlow(), val.as_raw_mut_PtrOfRLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DenseRLOFOpticalFlow_setRLOFOpticalFlowParameter_Ptr_RLOFOpticalFlowParameter_(self.as_raw_mut_DenseRLOFOpticalFlow(), val.as_raw_mut_PtrOfRLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -840,8 +1102,9 @@ pub trait DenseRLOFOpticalFlow: crate::optflow::DenseRLOFOpticalFlowConst + crat
 	///      *    see also: getForwardBackward, setGridStep
 	#[inline]
 	fn set_forward_backward(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("DenseRLOFOpticalFlow", self.as_raw_mut_DenseRLOFOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_setForwardBackward_float(self.as_raw_mut_DenseRLOFOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DenseRLOFOpticalFlow_setForwardBackward_float(self.as_raw_mut_DenseRLOFOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -854,8 +1117,9 @@ pub trait DenseRLOFOpticalFlow: crate::optflow::DenseRLOFOpticalFlowConst + crat
 	///      *    see also: getGridStep
 	#[inline]
 	fn set_grid_step(&mut self, val: core::Size) -> Result<()> {
+		crate::core::validity::check("DenseRLOFOpticalFlow", self.as_raw_mut_DenseRLOFOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_setGridStep_Size(self.as_raw_mut_DenseRLOFOpticalFlow(), val.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DenseRLOFOpticalFlow_setGridStep_Size(self.as_raw_mut_DenseRLOFOpticalFlow(), val.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -868,8 +1132,9 @@ pub trait DenseRLOFOpticalFlow: crate::optflow::DenseRLOFOpticalFlowConst + crat
 	///      * see also: ximgproc::EdgeAwareInterpolator, getInterpolation
 	#[inline]
 	fn set_interpolation(&mut self, val: crate::optflow::InterpolationType) -> Result<()> {
+		crate::core::validity::check("DenseRLOFOpticalFlow", self.as_raw_mut_DenseRLOFOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_setInterpolation_InterpolationType(self.as_raw_mut_DenseRLOFOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DenseRLOFOpticalFlow_setInterpolation_InterpolationType(self.as_raw_mut_DenseRLOFOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -882,8 +1147,9 @@ pub trait DenseRLOFOpticalFlow: crate::optflow::DenseRLOFOpticalFlowConst + crat
 	///      *    see also: ximgproc::EdgeAwareInterpolator, getEPICK
 	#[inline]
 	fn set_epick(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("DenseRLOFOpticalFlow", self.as_raw_mut_DenseRLOFOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_setEPICK_int(self.as_raw_mut_DenseRLOFOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DenseRLOFOpticalFlow_setEPICK_int(self.as_raw_mut_DenseRLOFOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -897,8 +1163,9 @@ pub trait DenseRLOFOpticalFlow: crate::optflow::DenseRLOFOpticalFlowConst + crat
 	///      *  see also: ximgproc::EdgeAwareInterpolator, getEPICSigma
 	#[inline]
 	fn set_epic_sigma(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("DenseRLOFOpticalFlow", self.as_raw_mut_DenseRLOFOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_setEPICSigma_float(self.as_raw_mut_DenseRLOFOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DenseRLOFOpticalFlow_setEPICSigma_float(self.as_raw_mut_DenseRLOFOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -911,8 +1178,9 @@ pub trait DenseRLOFOpticalFlow: crate::optflow::DenseRLOFOpticalFlowConst + crat
 	///      *    see also: ximgproc::EdgeAwareInterpolator, getEPICLambda
 	#[inline]
 	fn set_epic_lambda(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("DenseRLOFOpticalFlow", self.as_raw_mut_DenseRLOFOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_setEPICLambda_float(self.as_raw_mut_DenseRLOFOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DenseRLOFOpticalFlow_setEPICLambda_float(self.as_raw_mut_DenseRLOFOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -924,8 +1192,9 @@ pub trait DenseRLOFOpticalFlow: crate::optflow::DenseRLOFOpticalFlowConst + crat
 	///      *    see also: ximgproc::EdgeAwareInterpolator, ximgproc::fastGlobalSmootherFilter, getFgsLambda
 	#[inline]
 	fn set_fgs_lambda(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("DenseRLOFOpticalFlow", self.as_raw_mut_DenseRLOFOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_setFgsLambda_float(self.as_raw_mut_DenseRLOFOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DenseRLOFOpticalFlow_setFgsLambda_float(self.as_raw_mut_DenseRLOFOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -937,8 +1206,9 @@ pub trait DenseRLOFOpticalFlow: crate::optflow::DenseRLOFOpticalFlowConst + crat
 	///      *    see also: ximgproc::EdgeAwareInterpolator, ximgproc::fastGlobalSmootherFilter, getFgsSigma
 	#[inline]
 	fn set_fgs_sigma(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("DenseRLOFOpticalFlow", self.as_raw_mut_DenseRLOFOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_setFgsSigma_float(self.as_raw_mut_DenseRLOFOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DenseRLOFOpticalFlow_setFgsSigma_float(self.as_raw_mut_DenseRLOFOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -949,8 +1219,9 @@ pub trait DenseRLOFOpticalFlow: crate::optflow::DenseRLOFOpticalFlowConst + crat
 	/// * see also: getUsePostProc
 	#[inline]
 	fn set_use_post_proc(&mut self, val: bool) -> Result<()> {
+		crate::core::validity::check("DenseRLOFOpticalFlow", self.as_raw_mut_DenseRLOFOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_setUsePostProc_bool(self.as_raw_mut_DenseRLOFOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DenseRLOFOpticalFlow_setUsePostProc_bool(self.as_raw_mut_DenseRLOFOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -961,8 +1232,9 @@ pub trait DenseRLOFOpticalFlow: crate::optflow::DenseRLOFOpticalFlowConst + crat
 	/// * see also: getUseVariationalRefinement
 	#[inline]
 	fn set_use_variational_refinement(&mut self, val: bool) -> Result<()> {
+		crate::core::validity::check("DenseRLOFOpticalFlow", self.as_raw_mut_DenseRLOFOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_setUseVariationalRefinement_bool(self.as_raw_mut_DenseRLOFOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DenseRLOFOpticalFlow_setUseVariationalRefinement_bool(self.as_raw_mut_DenseRLOFOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -973,8 +1245,9 @@ pub trait DenseRLOFOpticalFlow: crate::optflow::DenseRLOFOpticalFlowConst + crat
 	/// * see also: cv::ximgproc::createSuperpixelSLIC, cv::ximgproc::RICInterpolator
 	#[inline]
 	fn set_ricsp_size(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("DenseRLOFOpticalFlow", self.as_raw_mut_DenseRLOFOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_setRICSPSize_int(self.as_raw_mut_DenseRLOFOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DenseRLOFOpticalFlow_setRICSPSize_int(self.as_raw_mut_DenseRLOFOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -988,8 +1261,9 @@ pub trait DenseRLOFOpticalFlow: crate::optflow::DenseRLOFOpticalFlowConst + crat
 	/// cv::ximgproc::createSuperpixelSLIC, cv::ximgproc::RICInterpolator
 	#[inline]
 	fn set_ricslic_type(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("DenseRLOFOpticalFlow", self.as_raw_mut_DenseRLOFOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_setRICSLICType_int(self.as_raw_mut_DenseRLOFOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DenseRLOFOpticalFlow_setRICSLICType_int(self.as_raw_mut_DenseRLOFOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1032,7 +1306,7 @@ impl dyn DenseRLOFOpticalFlow + '_ {
 	#[inline]
 	pub fn create(mut rlof_param: core::Ptr<crate::optflow::RLOFOpticalFlowParameter>, forward_backward_threshold: f32, grid_step: core::Size, interp_type: crate::optflow::InterpolationType, epic_k: i32, epic_sigma: f32, epic_lambda: f32, ric_sp_size: i32, ric_slic_type: i32, use_post_proc: bool, fgs_lambda: f32, fgs_sigma: f32, use_variational_refinement: bool) -> Result<core::Ptr<dyn crate::optflow::DenseRLOFOpticalFlow>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_create_Ptr_RLOFOpticalFlowParameter__float_Size_InterpolationType_int_float_float_int_int_bool_float_float_bool(rlof_param.as_raw_mut_PtrOfRLOFOpticalFlowParameter(), forward_backward_threshold, grid_step.opencv_as_extern(), interp_type, epic_k, epic_sigma, epic_lambda, ric_sp_size, ric_slic_type, use_post_proc, fgs_lambda, fgs_sigma, use_variational_refinement, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DenseRLOFOpticalFlow_create_Ptr_RLOFOpticalFlowParameter__float_Size_InterpolationType_int_float_float_int_int_bool_float_float_bool(rlof_param.as_raw_mut_PtrOfRLOFOpticalFlowParameter(), forward_backward_threshold, grid_step.opencv_as_extern(), interp_type, epic_k, epic_sigma, epic_lambda, ric_sp_size, ric_slic_type, use_post_proc, fgs_lambda, fgs_sigma, use_variational_refinement, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::optflow::DenseRLOFOpticalFlow>::opencv_from_extern(ret) };
@@ -1040,6 +1314,115 @@ impl dyn DenseRLOFOpticalFlow + '_ {
 	}
 	
 }
+
+/// Builder for `<dyn DenseRLOFOpticalFlow>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct DenseRLOFOpticalFlow_CreateBuilder {
+	rlof_param: core::Ptr<crate::optflow::RLOFOpticalFlowParameter>,
+	forward_backward_threshold: f32,
+	grid_step: core::Size,
+	interp_type: crate::optflow::InterpolationType,
+	epic_k: i32,
+	epic_sigma: f32,
+	epic_lambda: f32,
+	ric_sp_size: i32,
+	ric_slic_type: i32,
+	use_post_proc: bool,
+	fgs_lambda: f32,
+	fgs_sigma: f32,
+	use_variational_refinement: bool,
+}
+
+impl DenseRLOFOpticalFlow_CreateBuilder {
+	#[inline]
+	pub fn new(rlof_param: core::Ptr<crate::optflow::RLOFOpticalFlowParameter>, interp_type: crate::optflow::InterpolationType) -> Self {
+		Self { rlof_param, forward_backward_threshold: 1., grid_step: core::Size::new(6, 6), interp_type, epic_k: 128, epic_sigma: 0.05, epic_lambda: 999.0, ric_sp_size: 15, ric_slic_type: 100, use_post_proc: true, fgs_lambda: 500.0, fgs_sigma: 1.5, use_variational_refinement: false }
+	}
+	
+	/// C++ default: `1.f`
+	#[inline]
+	pub fn with_forward_backward_threshold(mut self, forward_backward_threshold: f32) -> Self {
+		self.forward_backward_threshold = forward_backward_threshold;
+		self
+	}
+	
+	/// C++ default: `Size(6,6)`
+	#[inline]
+	pub fn with_grid_step(mut self, grid_step: core::Size) -> Self {
+		self.grid_step = grid_step;
+		self
+	}
+	
+	/// C++ default: `128`
+	#[inline]
+	pub fn with_epic_k(mut self, epic_k: i32) -> Self {
+		self.epic_k = epic_k;
+		self
+	}
+	
+	/// C++ default: `0.05f`
+	#[inline]
+	pub fn with_epic_sigma(mut self, epic_sigma: f32) -> Self {
+		self.epic_sigma = epic_sigma;
+		self
+	}
+	
+	/// C++ default: `999.0f`
+	#[inline]
+	pub fn with_epic_lambda(mut self, epic_lambda: f32) -> Self {
+		self.epic_lambda = epic_lambda;
+		self
+	}
+	
+	/// C++ default: `15`
+	#[inline]
+	pub fn with_ric_sp_size(mut self, ric_sp_size: i32) -> Self {
+		self.ric_sp_size = ric_sp_size;
+		self
+	}
+	
+	/// C++ default: `100`
+	#[inline]
+	pub fn with_ric_slic_type(mut self, ric_slic_type: i32) -> Self {
+		self.ric_slic_type = ric_slic_type;
+		self
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_use_post_proc(mut self, use_post_proc: bool) -> Self {
+		self.use_post_proc = use_post_proc;
+		self
+	}
+	
+	/// C++ default: `500.0f`
+	#[inline]
+	pub fn with_fgs_lambda(mut self, fgs_lambda: f32) -> Self {
+		self.fgs_lambda = fgs_lambda;
+		self
+	}
+	
+	/// C++ default: `1.5f`
+	#[inline]
+	pub fn with_fgs_sigma(mut self, fgs_sigma: f32) -> Self {
+		self.fgs_sigma = fgs_sigma;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_use_variational_refinement(mut self, use_variational_refinement: bool) -> Self {
+		self.use_variational_refinement = use_variational_refinement;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::optflow::DenseRLOFOpticalFlow>> {
+		<dyn DenseRLOFOpticalFlow>::create(self.rlof_param, self.forward_backward_threshold, self.grid_step, self.interp_type, self.epic_k, self.epic_sigma, self.epic_lambda, self.ric_sp_size, self.ric_slic_type, self.use_post_proc, self.fgs_lambda, self.fgs_sigma, self.use_variational_refinement)
+	}
+}
 /// "Dual TV L1" Optical Flow Algorithm.
 /// 
 /// The class implements the "Dual TV L1" optical flow algorithm described in [Zach2007](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_Zach2007) and
@@ -1086,11 +1469,12 @@ pub trait DualTVL1OpticalFlowConst: crate::video::DenseOpticalFlowConst {
 
 	/// Time step of the numerical scheme
 	/// ## See also
-	/// setTau
+	/// `set_tau`
 	#[inline]
 	fn get_tau(&self) -> Result<f64> {
+		crate::core::validity::check("DualTVL1OpticalFlow", self.as_raw_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DualTVL1OpticalFlow_getTau_const(self.as_raw_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DualTVL1OpticalFlow_getTau_const(self.as_raw_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1098,11 +1482,12 @@ pub trait DualTVL1OpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	
 	/// Weight parameter for the data term, attachment parameter
 	/// ## See also
-	/// setLambda
+	/// `set_lambda`
 	#[inline]
 	fn get_lambda(&self) -> Result<f64> {
+		crate::core::validity::check("DualTVL1OpticalFlow", self.as_raw_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DualTVL1OpticalFlow_getLambda_const(self.as_raw_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DualTVL1OpticalFlow_getLambda_const(self.as_raw_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1110,11 +1495,12 @@ pub trait DualTVL1OpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	
 	/// Weight parameter for (u - v)^2, tightness parameter
 	/// ## See also
-	/// setTheta
+	/// `set_theta`
 	#[inline]
 	fn get_theta(&self) -> Result<f64> {
+		crate::core::validity::check("DualTVL1OpticalFlow", self.as_raw_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DualTVL1OpticalFlow_getTheta_const(self.as_raw_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DualTVL1OpticalFlow_getTheta_const(self.as_raw_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1122,11 +1508,12 @@ pub trait DualTVL1OpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	
 	/// coefficient for additional illumination variation term
 	/// ## See also
-	/// setGamma
+	/// `set_gamma`
 	#[inline]
 	fn get_gamma(&self) -> Result<f64> {
+		crate::core::validity::check("DualTVL1OpticalFlow", self.as_raw_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DualTVL1OpticalFlow_getGamma_const(self.as_raw_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DualTVL1OpticalFlow_getGamma_const(self.as_raw_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1134,11 +1521,12 @@ pub trait DualTVL1OpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	
 	/// Number of scales used to create the pyramid of images
 	/// ## See also
-	/// setScalesNumber
+	/// `set_scales_number`
 	#[inline]
 	fn get_scales_number(&self) -> Result<i32> {
+		crate::core::validity::check("DualTVL1OpticalFlow", self.as_raw_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DualTVL1OpticalFlow_getScalesNumber_const(self.as_raw_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DualTVL1OpticalFlow_getScalesNumber_const(self.as_raw_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1146,11 +1534,12 @@ pub trait DualTVL1OpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	
 	/// Number of warpings per scale
 	/// ## See also
-	/// setWarpingsNumber
+	/// `set_warpings_number`
 	#[inline]
 	fn get_warpings_number(&self) -> Result<i32> {
+		crate::core::validity::check("DualTVL1OpticalFlow", self.as_raw_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DualTVL1OpticalFlow_getWarpingsNumber_const(self.as_raw_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DualTVL1OpticalFlow_getWarpingsNumber_const(self.as_raw_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1158,11 +1547,12 @@ pub trait DualTVL1OpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	
 	/// Stopping criterion threshold used in the numerical scheme, which is a trade-off between precision and running time
 	/// ## See also
-	/// setEpsilon
+	/// `set_epsilon`
 	#[inline]
 	fn get_epsilon(&self) -> Result<f64> {
+		crate::core::validity::check("DualTVL1OpticalFlow", self.as_raw_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DualTVL1OpticalFlow_getEpsilon_const(self.as_raw_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DualTVL1OpticalFlow_getEpsilon_const(self.as_raw_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1170,11 +1560,12 @@ pub trait DualTVL1OpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	
 	/// Inner iterations (between outlier filtering) used in the numerical scheme
 	/// ## See also
-	/// setInnerIterations
+	/// `set_inner_iterations`
 	#[inline]
 	fn get_inner_iterations(&self) -> Result<i32> {
+		crate::core::validity::check("DualTVL1OpticalFlow", self.as_raw_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DualTVL1OpticalFlow_getInnerIterations_const(self.as_raw_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DualTVL1OpticalFlow_getInnerIterations_const(self.as_raw_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1182,11 +1573,12 @@ pub trait DualTVL1OpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	
 	/// Outer iterations (number of inner loops) used in the numerical scheme
 	/// ## See also
-	/// setOuterIterations
+	/// `set_outer_iterations`
 	#[inline]
 	fn get_outer_iterations(&self) -> Result<i32> {
+		crate::core::validity::check("DualTVL1OpticalFlow", self.as_raw_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DualTVL1OpticalFlow_getOuterIterations_const(self.as_raw_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DualTVL1OpticalFlow_getOuterIterations_const(self.as_raw_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1194,11 +1586,12 @@ pub trait DualTVL1OpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	
 	/// Use initial flow
 	/// ## See also
-	/// setUseInitialFlow
+	/// `set_use_initial_flow`
 	#[inline]
 	fn get_use_initial_flow(&self) -> Result<bool> {
+		crate::core::validity::check("DualTVL1OpticalFlow", self.as_raw_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DualTVL1OpticalFlow_getUseInitialFlow_const(self.as_raw_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DualTVL1OpticalFlow_getUseInitialFlow_const(self.as_raw_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1206,11 +1599,12 @@ pub trait DualTVL1OpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	
 	/// Step between scales (<1)
 	/// ## See also
-	/// setScaleStep
+	/// `set_scale_step`
 	#[inline]
 	fn get_scale_step(&self) -> Result<f64> {
+		crate::core::validity::check("DualTVL1OpticalFlow", self.as_raw_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DualTVL1OpticalFlow_getScaleStep_const(self.as_raw_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DualTVL1OpticalFlow_getScaleStep_const(self.as_raw_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1218,11 +1612,12 @@ pub trait DualTVL1OpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	
 	/// Median filter kernel size (1 = no filter) (3 or 5)
 	/// ## See also
-	/// setMedianFiltering
+	/// `set_median_filtering`
 	#[inline]
 	fn get_median_filtering(&self) -> Result<i32> {
+		crate::core::validity::check("DualTVL1OpticalFlow", self.as_raw_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DualTVL1OpticalFlow_getMedianFiltering_const(self.as_raw_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DualTVL1OpticalFlow_getMedianFiltering_const(self.as_raw_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1238,8 +1633,9 @@ pub trait DualTVL1OpticalFlow: crate::optflow::DualTVL1OpticalFlowConst + crate:
 	/// setTau getTau
 	#[inline]
 	fn set_tau(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("DualTVL1OpticalFlow", self.as_raw_mut_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DualTVL1OpticalFlow_setTau_double(self.as_raw_mut_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DualTVL1OpticalFlow_setTau_double(self.as_raw_mut_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1250,8 +1646,9 @@ pub trait DualTVL1OpticalFlow: crate::optflow::DualTVL1OpticalFlowConst + crate:
 	/// setLambda getLambda
 	#[inline]
 	fn set_lambda(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("DualTVL1OpticalFlow", self.as_raw_mut_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DualTVL1OpticalFlow_setLambda_double(self.as_raw_mut_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DualTVL1OpticalFlow_setLambda_double(self.as_raw_mut_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1262,8 +1659,9 @@ pub trait DualTVL1OpticalFlow: crate::optflow::DualTVL1OpticalFlowConst + crate:
 	/// setTheta getTheta
 	#[inline]
 	fn set_theta(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("DualTVL1OpticalFlow", self.as_raw_mut_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DualTVL1OpticalFlow_setTheta_double(self.as_raw_mut_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DualTVL1OpticalFlow_setTheta_double(self.as_raw_mut_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1274,8 +1672,9 @@ pub trait DualTVL1OpticalFlow: crate::optflow::DualTVL1OpticalFlowConst + crate:
 	/// setGamma getGamma
 	#[inline]
 	fn set_gamma(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("DualTVL1OpticalFlow", self.as_raw_mut_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DualTVL1OpticalFlow_setGamma_double(self.as_raw_mut_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DualTVL1OpticalFlow_setGamma_double(self.as_raw_mut_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1286,8 +1685,9 @@ pub trait DualTVL1OpticalFlow: crate::optflow::DualTVL1OpticalFlowConst + crate:
 	/// setScalesNumber getScalesNumber
 	#[inline]
 	fn set_scales_number(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("DualTVL1OpticalFlow", self.as_raw_mut_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DualTVL1OpticalFlow_setScalesNumber_int(self.as_raw_mut_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DualTVL1OpticalFlow_setScalesNumber_int(self.as_raw_mut_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1298,8 +1698,9 @@ pub trait DualTVL1OpticalFlow: crate::optflow::DualTVL1OpticalFlowConst + crate:
 	/// setWarpingsNumber getWarpingsNumber
 	#[inline]
 	fn set_warpings_number(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("DualTVL1OpticalFlow", self.as_raw_mut_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DualTVL1OpticalFlow_setWarpingsNumber_int(self.as_raw_mut_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DualTVL1OpticalFlow_setWarpingsNumber_int(self.as_raw_mut_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1310,8 +1711,9 @@ pub trait DualTVL1OpticalFlow: crate::optflow::DualTVL1OpticalFlowConst + crate:
 	/// setEpsilon getEpsilon
 	#[inline]
 	fn set_epsilon(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("DualTVL1OpticalFlow", self.as_raw_mut_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DualTVL1OpticalFlow_setEpsilon_double(self.as_raw_mut_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DualTVL1OpticalFlow_setEpsilon_double(self.as_raw_mut_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1322,8 +1724,9 @@ pub trait DualTVL1OpticalFlow: crate::optflow::DualTVL1OpticalFlowConst + crate:
 	/// setInnerIterations getInnerIterations
 	#[inline]
 	fn set_inner_iterations(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("DualTVL1OpticalFlow", self.as_raw_mut_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DualTVL1OpticalFlow_setInnerIterations_int(self.as_raw_mut_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DualTVL1OpticalFlow_setInnerIterations_int(self.as_raw_mut_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1334,8 +1737,9 @@ pub trait DualTVL1OpticalFlow: crate::optflow::DualTVL1OpticalFlowConst + crate:
 	/// setOuterIterations getOuterIterations
 	#[inline]
 	fn set_outer_iterations(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("DualTVL1OpticalFlow", self.as_raw_mut_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DualTVL1OpticalFlow_setOuterIterations_int(self.as_raw_mut_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DualTVL1OpticalFlow_setOuterIterations_int(self.as_raw_mut_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1346,8 +1750,9 @@ pub trait DualTVL1OpticalFlow: crate::optflow::DualTVL1OpticalFlowConst + crate:
 	/// setUseInitialFlow getUseInitialFlow
 	#[inline]
 	fn set_use_initial_flow(&mut self, val: bool) -> Result<()> {
+		crate::core::validity::check("DualTVL1OpticalFlow", self.as_raw_mut_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DualTVL1OpticalFlow_setUseInitialFlow_bool(self.as_raw_mut_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DualTVL1OpticalFlow_setUseInitialFlow_bool(self.as_raw_mut_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1358,8 +1763,9 @@ pub trait DualTVL1OpticalFlow: crate::optflow::DualTVL1OpticalFlowConst + crate:
 	/// setScaleStep getScaleStep
 	#[inline]
 	fn set_scale_step(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("DualTVL1OpticalFlow", self.as_raw_mut_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DualTVL1OpticalFlow_setScaleStep_double(self.as_raw_mut_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DualTVL1OpticalFlow_setScaleStep_double(self.as_raw_mut_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1370,8 +1776,9 @@ pub trait DualTVL1OpticalFlow: crate::optflow::DualTVL1OpticalFlowConst + crate:
 	/// setMedianFiltering getMedianFiltering
 	#[inline]
 	fn set_median_filtering(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("DualTVL1OpticalFlow", self.as_raw_mut_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DualTVL1OpticalFlow_setMedianFiltering_int(self.as_raw_mut_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DualTVL1OpticalFlow_setMedianFiltering_int(self.as_raw_mut_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1398,7 +1805,7 @@ impl dyn DualTVL1OpticalFlow + '_ {
 	#[inline]
 	pub fn create(tau: f64, lambda: f64, theta: f64, nscales: i32, warps: i32, epsilon: f64, innner_iterations: i32, outer_iterations: i32, scale_step: f64, gamma: f64, median_filtering: i32, use_initial_flow: bool) -> Result<core::Ptr<dyn crate::optflow::DualTVL1OpticalFlow>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_DualTVL1OpticalFlow_create_double_double_double_int_int_double_int_int_double_double_int_bool(tau, lambda, theta, nscales, warps, epsilon, innner_iterations, outer_iterations, scale_step, gamma, median_filtering, use_initial_flow, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_DualTVL1OpticalFlow_create_double_double_double_int_int_double_int_int_double_double_int_bool(tau, lambda, theta, nscales, warps, epsilon, innner_iterations, outer_iterations, scale_step, gamma, median_filtering, use_initial_flow, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::optflow::DualTVL1OpticalFlow>::opencv_from_extern(ret) };
@@ -1406,6 +1813,128 @@ impl dyn DualTVL1OpticalFlow + '_ {
 	}
 	
 }
+
+/// Builder for `<dyn DualTVL1OpticalFlow>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct DualTVL1OpticalFlow_CreateBuilder {
+	tau: f64,
+	lambda: f64,
+	theta: f64,
+	nscales: i32,
+	warps: i32,
+	epsilon: f64,
+	innner_iterations: i32,
+	outer_iterations: i32,
+	scale_step: f64,
+	gamma: f64,
+	median_filtering: i32,
+	use_initial_flow: bool,
+}
+
+impl DualTVL1OpticalFlow_CreateBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { tau: 0.25, lambda: 0.15, theta: 0.3, nscales: 5, warps: 5, epsilon: 0.01, innner_iterations: 30, outer_iterations: 10, scale_step: 0.8, gamma: 0.0, median_filtering: 5, use_initial_flow: false }
+	}
+	
+	/// C++ default: `0.25`
+	#[inline]
+	pub fn with_tau(mut self, tau: f64) -> Self {
+		self.tau = tau;
+		self
+	}
+	
+	/// C++ default: `0.15`
+	#[inline]
+	pub fn with_lambda(mut self, lambda: f64) -> Self {
+		self.lambda = lambda;
+		self
+	}
+	
+	/// C++ default: `0.3`
+	#[inline]
+	pub fn with_theta(mut self, theta: f64) -> Self {
+		self.theta = theta;
+		self
+	}
+	
+	/// C++ default: `5`
+	#[inline]
+	pub fn with_nscales(mut self, nscales: i32) -> Self {
+		self.nscales = nscales;
+		self
+	}
+	
+	/// C++ default: `5`
+	#[inline]
+	pub fn with_warps(mut self, warps: i32) -> Self {
+		self.warps = warps;
+		self
+	}
+	
+	/// C++ default: `0.01`
+	#[inline]
+	pub fn with_epsilon(mut self, epsilon: f64) -> Self {
+		self.epsilon = epsilon;
+		self
+	}
+	
+	/// C++ default: `30`
+	#[inline]
+	pub fn with_innner_iterations(mut self, innner_iterations: i32) -> Self {
+		self.innner_iterations = innner_iterations;
+		self
+	}
+	
+	/// C++ default: `10`
+	#[inline]
+	pub fn with_outer_iterations(mut self, outer_iterations: i32) -> Self {
+		self.outer_iterations = outer_iterations;
+		self
+	}
+	
+	/// C++ default: `0.8`
+	#[inline]
+	pub fn with_scale_step(mut self, scale_step: f64) -> Self {
+		self.scale_step = scale_step;
+		self
+	}
+	
+	/// C++ default: `0.0`
+	#[inline]
+	pub fn with_gamma(mut self, gamma: f64) -> Self {
+		self.gamma = gamma;
+		self
+	}
+	
+	/// C++ default: `5`
+	#[inline]
+	pub fn with_median_filtering(mut self, median_filtering: i32) -> Self {
+		self.median_filtering = median_filtering;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_use_initial_flow(mut self, use_initial_flow: bool) -> Self {
+		self.use_initial_flow = use_initial_flow;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::optflow::DualTVL1OpticalFlow>> {
+		<dyn DualTVL1OpticalFlow>::create(self.tau, self.lambda, self.theta, self.nscales, self.warps, self.epsilon, self.innner_iterations, self.outer_iterations, self.scale_step, self.gamma, self.median_filtering, self.use_initial_flow)
+	}
+}
+
+impl Default for DualTVL1OpticalFlow_CreateBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
 pub trait GPCDetailsTraitConst {
 	fn as_raw_GPCDetails(&self) -> *const c_void;
 
@@ -1425,7 +1954,10 @@ opencv_type_boxed! { GPCDetails }
 impl Drop for GPCDetails {
 	fn drop(&mut self) {
 		extern "C" { fn cv_GPCDetails_delete(instance: *mut c_void); }
-		unsafe { cv_GPCDetails_delete(self.as_raw_mut_GPCDetails()) };
+		let ptr = self.as_raw_mut_GPCDetails();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_GPCDetails_delete(ptr) };
 	}
 }
 
@@ -1443,7 +1975,7 @@ impl GPCDetails {
 	#[inline]
 	pub fn get_all_descriptors_for_image(img_ch: &core::Mat, descr: &mut core::Vector<crate::optflow::GPCPatchDescriptor>, mp: crate::optflow::GPCMatchingParams, typ: i32) -> Result<()> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_GPCDetails_getAllDescriptorsForImage_const_MatX_vector_GPCPatchDescriptor_R_const_GPCMatchingParamsR_int(img_ch.as_raw_Mat(), descr.as_raw_mut_VectorOfGPCPatchDescriptor(), &mp, typ, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_GPCDetails_getAllDescriptorsForImage_const_MatX_vector_GPCPatchDescriptor_R_const_GPCMatchingParamsR_int(img_ch.as_raw_Mat(), descr.as_raw_mut_VectorOfGPCPatchDescriptor(), &mp, typ, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1452,7 +1984,7 @@ impl GPCDetails {
 	#[inline]
 	pub fn get_coordinates_from_index(index: size_t, sz: core::Size, x: &mut i32, y: &mut i32) -> Result<()> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_GPCDetails_getCoordinatesFromIndex_size_t_Size_intR_intR(index, sz.opencv_as_extern(), x, y, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_GPCDetails_getCoordinatesFromIndex_size_t_Size_intR_intR(index, sz.opencv_as_extern(), x, y, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1476,7 +2008,7 @@ impl GPCMatchingParams {
 	#[inline]
 	pub fn new(_use_opencl: bool) -> Result<crate::optflow::GPCMatchingParams> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_GPCMatchingParams_GPCMatchingParams_bool(_use_opencl, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_GPCMatchingParams_GPCMatchingParams_bool(_use_opencl, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1485,7 +2017,7 @@ impl GPCMatchingParams {
 	#[inline]
 	pub fn copy(params: crate::optflow::GPCMatchingParams) -> Result<crate::optflow::GPCMatchingParams> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_GPCMatchingParams_GPCMatchingParams_const_GPCMatchingParamsR(&params, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_GPCMatchingParams_GPCMatchingParams_const_GPCMatchingParamsR(&params, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1498,16 +2030,26 @@ pub trait GPCPatchDescriptorTraitConst {
 
 	#[inline]
 	fn feature(&self) -> core::VecN<f64, 18> {
+		crate::core::validity::debug_check("GPCPatchDescriptor", self.as_raw_GPCPatchDescriptor());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_GPCPatchDescriptor_getPropFeature_const(self.as_raw_GPCPatchDescriptor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_GPCPatchDescriptor_getPropFeature_const(self.as_raw_GPCPatchDescriptor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [feature](GPCPatchDescriptorTraitConst::feature), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_feature(&self) -> Result<core::VecN<f64, 18>> {
+		crate::core::validity::check("GPCPatchDescriptor", self.as_raw_GPCPatchDescriptor())?;
+		Ok(GPCPatchDescriptorTraitConst::feature(self))
+	}
+	
 	#[inline]
 	fn dot(&self, coef: core::VecN<f64, 18>) -> Result<f64> {
+		crate::core::validity::check("GPCPatchDescriptor", self.as_raw_GPCPatchDescriptor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_GPCPatchDescriptor_dot_const_const_Vec_double__18_R(self.as_raw_GPCPatchDescriptor(), &coef, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_GPCPatchDescriptor_dot_const_const_Vec_double__18_R(self.as_raw_GPCPatchDescriptor(), &coef, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1515,8 +2057,9 @@ pub trait GPCPatchDescriptorTraitConst {
 	
 	#[inline]
 	fn is_separated(&self) -> Result<bool> {
+		crate::core::validity::check("GPCPatchDescriptor", self.as_raw_GPCPatchDescriptor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_GPCPatchDescriptor_isSeparated_const(self.as_raw_GPCPatchDescriptor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_GPCPatchDescriptor_isSeparated_const(self.as_raw_GPCPatchDescriptor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1529,14 +2072,16 @@ pub trait GPCPatchDescriptorTrait: crate::optflow::GPCPatchDescriptorTraitConst
 
 	#[inline]
 	fn set_feature(&mut self, val: core::VecN<f64, 18>) {
-		let ret = unsafe { sys::cv_optflow_GPCPatchDescriptor_setPropFeature_Vec_double__18_(self.as_raw_mut_GPCPatchDescriptor(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("GPCPatchDescriptor", self.as_raw_mut_GPCPatchDescriptor());
+		let ret = unsafe { ffi_call!(cv_optflow_GPCPatchDescriptor_setPropFeature_Vec_double__18_(self.as_raw_mut_GPCPatchDescriptor(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	#[inline]
 	fn mark_as_separated(&mut self) -> Result<()> {
+		crate::core::validity::check("GPCPatchDescriptor", self.as_raw_mut_GPCPatchDescriptor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_GPCPatchDescriptor_markAsSeparated(self.as_raw_mut_GPCPatchDescriptor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_GPCPatchDescriptor_markAsSeparated(self.as_raw_mut_GPCPatchDescriptor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1553,7 +2098,10 @@ opencv_type_boxed! { GPCPatchDescriptor }
 impl Drop for GPCPatchDescriptor {
 	fn drop(&mut self) {
 		extern "C" { fn cv_GPCPatchDescriptor_delete(instance: *mut c_void); }
-		unsafe { cv_GPCPatchDescriptor_delete(self.as_raw_mut_GPCPatchDescriptor()) };
+		let ptr = self.as_raw_mut_GPCPatchDescriptor();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_GPCPatchDescriptor_delete(ptr) };
 	}
 }
 
@@ -1577,29 +2125,57 @@ pub trait GPCPatchSampleTraitConst {
 
 	#[inline]
 	fn ref_(&self) -> crate::optflow::GPCPatchDescriptor {
-		let ret = unsafe { sys::cv_optflow_GPCPatchSample_getPropRef_const(self.as_raw_GPCPatchSample()) };
+		crate::core::validity::debug_check("GPCPatchSample", self.as_raw_GPCPatchSample());
+		let ret = unsafe { ffi_call!(cv_optflow_GPCPatchSample_getPropRef_const(self.as_raw_GPCPatchSample())) };
 		let ret = unsafe { crate::optflow::GPCPatchDescriptor::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [ref_](GPCPatchSampleTraitConst::ref_), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_ref_(&self) -> Result<crate::optflow::GPCPatchDescriptor> {
+		crate::core::validity::check("GPCPatchSample", self.as_raw_GPCPatchSample())?;
+		Ok(GPCPatchSampleTraitConst::ref_(self))
+	}
+	
 	#[inline]
 	fn pos(&self) -> crate::optflow::GPCPatchDescriptor {
-		let ret = unsafe { sys::cv_optflow_GPCPatchSample_getPropPos_const(self.as_raw_GPCPatchSample()) };
+		crate::core::validity::debug_check("GPCPatchSample", self.as_raw_GPCPatchSample());
+		let ret = unsafe { ffi_call!(cv_optflow_GPCPatchSample_getPropPos_const(self.as_raw_GPCPatchSample())) };
 		let ret = unsafe { crate::optflow::GPCPatchDescriptor::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [pos](GPCPatchSampleTraitConst::pos), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pos(&self) -> Result<crate::optflow::GPCPatchDescriptor> {
+		crate::core::validity::check("GPCPatchSample", self.as_raw_GPCPatchSample())?;
+		Ok(GPCPatchSampleTraitConst::pos(self))
+	}
+	
 	#[inline]
 	fn neg(&self) -> crate::optflow::GPCPatchDescriptor {
-		let ret = unsafe { sys::cv_optflow_GPCPatchSample_getPropNeg_const(self.as_raw_GPCPatchSample()) };
+		crate::core::validity::debug_check("GPCPatchSample", self.as_raw_GPCPatchSample());
+		let ret = unsafe { ffi_call!(cv_optflow_GPCPatchSample_getPropNeg_const(self.as_raw_GPCPatchSample())) };
 		let ret = unsafe { crate::optflow::GPCPatchDescriptor::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [neg](GPCPatchSampleTraitConst::neg), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_neg(&self) -> Result<crate::optflow::GPCPatchDescriptor> {
+		crate::core::validity::check("GPCPatchSample", self.as_raw_GPCPatchSample())?;
+		Ok(GPCPatchSampleTraitConst::neg(self))
+	}
+	
 	#[inline]
 	fn get_directions(&self, refdir: &mut bool, posdir: &mut bool, negdir: &mut bool, coef: core::VecN<f64, 18>, rhs: f64) -> Result<()> {
+		crate::core::validity::check("GPCPatchSample", self.as_raw_GPCPatchSample())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_GPCPatchSample_getDirections_const_boolR_boolR_boolR_const_Vec_double__18_R_double(self.as_raw_GPCPatchSample(), refdir, posdir, negdir, &coef, rhs, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_GPCPatchSample_getDirections_const_boolR_boolR_boolR_const_Vec_double__18_R_double(self.as_raw_GPCPatchSample(), refdir, posdir, negdir, &coef, rhs, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1612,19 +2188,22 @@ pub trait GPCPatchSampleTrait: crate::optflow::GPCPatchSampleTraitConst {
 
 	#[inline]
 	fn set_ref(&mut self, mut val: crate::optflow::GPCPatchDescriptor) {
-		let ret = unsafe { sys::cv_optflow_GPCPatchSample_setPropRef_GPCPatchDescriptor(self.as_raw_mut_GPCPatchSample(), val.as_raw_mut_GPCPatchDescriptor()) };
+		crate::core::validity::debug_check("GPCPatchSample", self.as_raw_mut_GPCPatchSample());
+		let ret = unsafe { ffi_call!(cv_optflow_GPCPatchSample_setPropRef_GPCPatchDescriptor(self.as_raw_mut_GPCPatchSample(), val.as_raw_mut_GPCPatchDescriptor())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_pos(&mut self, mut val: crate::optflow::GPCPatchDescriptor) {
-		let ret = unsafe { sys::cv_optflow_GPCPatchSample_setPropPos_GPCPatchDescriptor(self.as_raw_mut_GPCPatchSample(), val.as_raw_mut_GPCPatchDescriptor()) };
+		crate::core::validity::debug_check("GPCPatchSample", self.as_raw_mut_GPCPatchSample());
+		let ret = unsafe { ffi_call!(cv_optflow_GPCPatchSample_setPropPos_GPCPatchDescriptor(self.as_raw_mut_GPCPatchSample(), val.as_raw_mut_GPCPatchDescriptor())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_neg(&mut self, mut val: crate::optflow::GPCPatchDescriptor) {
-		let ret = unsafe { sys::cv_optflow_GPCPatchSample_setPropNeg_GPCPatchDescriptor(self.as_raw_mut_GPCPatchSample(), val.as_raw_mut_GPCPatchDescriptor()) };
+		crate::core::validity::debug_check("GPCPatchSample", self.as_raw_mut_GPCPatchSample());
+		let ret = unsafe { ffi_call!(cv_optflow_GPCPatchSample_setPropNeg_GPCPatchDescriptor(self.as_raw_mut_GPCPatchSample(), val.as_raw_mut_GPCPatchDescriptor())) };
 		ret
 	}
 	
@@ -1639,7 +2218,10 @@ opencv_type_boxed! { GPCPatchSample }
 impl Drop for GPCPatchSample {
 	fn drop(&mut self) {
 		extern "C" { fn cv_GPCPatchSample_delete(instance: *mut c_void); }
-		unsafe { cv_GPCPatchSample_delete(self.as_raw_mut_GPCPatchSample()) };
+		let ptr = self.as_raw_mut_GPCPatchSample();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_GPCPatchSample_delete(ptr) };
 	}
 }
 
@@ -1676,7 +2258,7 @@ impl GPCTrainingParams {
 	#[inline]
 	pub fn check(self) -> Result<bool> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_GPCTrainingParams_check_const(self.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_GPCTrainingParams_check_const(self.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1690,7 +2272,7 @@ impl GPCTrainingParams {
 	#[inline]
 	pub fn new(_max_tree_depth: u32, _min_number_of_samples: i32, _descriptor_type: crate::optflow::GPCDescType, _print_progress: bool) -> Result<crate::optflow::GPCTrainingParams> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_GPCTrainingParams_GPCTrainingParams_unsigned_int_int_GPCDescType_bool(_max_tree_depth, _min_number_of_samples, _descriptor_type, _print_progress, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_GPCTrainingParams_GPCTrainingParams_unsigned_int_int_GPCDescType_bool(_max_tree_depth, _min_number_of_samples, _descriptor_type, _print_progress, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1698,14 +2280,59 @@ impl GPCTrainingParams {
 	
 }
 
+/// Builder for `GPCTrainingParams::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct GPCTrainingParams_NewBuilder {
+	_max_tree_depth: u32,
+	_min_number_of_samples: i32,
+	_descriptor_type: crate::optflow::GPCDescType,
+	_print_progress: bool,
+}
+
+impl GPCTrainingParams_NewBuilder {
+	#[inline]
+	pub fn new(_descriptor_type: crate::optflow::GPCDescType) -> Self {
+		Self { _max_tree_depth: 20, _min_number_of_samples: 3, _descriptor_type, _print_progress: true }
+	}
+	
+	/// C++ default: `20`
+	#[inline]
+	pub fn with__max_tree_depth(mut self, _max_tree_depth: u32) -> Self {
+		self._max_tree_depth = _max_tree_depth;
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with__min_number_of_samples(mut self, _min_number_of_samples: i32) -> Self {
+		self._min_number_of_samples = _min_number_of_samples;
+		self
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with__print_progress(mut self, _print_progress: bool) -> Self {
+		self._print_progress = _print_progress;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::optflow::GPCTrainingParams> {
+		GPCTrainingParams::new(self._max_tree_depth, self._min_number_of_samples, self._descriptor_type, self._print_progress)
+	}
+}
+
 /// Class encapsulating training samples.
 pub trait GPCTrainingSamplesTraitConst {
 	fn as_raw_GPCTrainingSamples(&self) -> *const c_void;
 
 	#[inline]
 	fn size(&self) -> Result<size_t> {
+		crate::core::validity::check("GPCTrainingSamples", self.as_raw_GPCTrainingSamples())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_GPCTrainingSamples_size_const(self.as_raw_GPCTrainingSamples(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_GPCTrainingSamples_size_const(self.as_raw_GPCTrainingSamples(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1713,8 +2340,9 @@ pub trait GPCTrainingSamplesTraitConst {
 	
 	#[inline]
 	fn typ(&self) -> Result<i32> {
+		crate::core::validity::check("GPCTrainingSamples", self.as_raw_GPCTrainingSamples())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_GPCTrainingSamples_type_const(self.as_raw_GPCTrainingSamples(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_GPCTrainingSamples_type_const(self.as_raw_GPCTrainingSamples(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1737,7 +2365,10 @@ opencv_type_boxed! { GPCTrainingSamples }
 impl Drop for GPCTrainingSamples {
 	fn drop(&mut self) {
 		extern "C" { fn cv_GPCTrainingSamples_delete(instance: *mut c_void); }
-		unsafe { cv_GPCTrainingSamples_delete(self.as_raw_mut_GPCTrainingSamples()) };
+		let ptr = self.as_raw_mut_GPCTrainingSamples();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_GPCTrainingSamples_delete(ptr) };
 	}
 }
 
@@ -1757,7 +2388,7 @@ impl GPCTrainingSamples {
 	#[inline]
 	pub fn create(images_from: &core::Vector<String>, images_to: &core::Vector<String>, gt: &core::Vector<String>, descriptor_type: i32) -> Result<core::Ptr<crate::optflow::GPCTrainingSamples>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_GPCTrainingSamples_create_const_vector_String_R_const_vector_String_R_const_vector_String_R_int(images_from.as_raw_VectorOfString(), images_to.as_raw_VectorOfString(), gt.as_raw_VectorOfString(), descriptor_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_GPCTrainingSamples_create_const_vector_String_R_const_vector_String_R_const_vector_String_R_int(images_from.as_raw_VectorOfString(), images_to.as_raw_VectorOfString(), gt.as_raw_VectorOfString(), descriptor_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::optflow::GPCTrainingSamples>::opencv_from_extern(ret) };
@@ -1770,7 +2401,7 @@ impl GPCTrainingSamples {
 		input_array_arg!(images_to);
 		input_array_arg!(gt);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_GPCTrainingSamples_create_const__InputArrayR_const__InputArrayR_const__InputArrayR_int(images_from.as_raw__InputArray(), images_to.as_raw__InputArray(), gt.as_raw__InputArray(), descriptor_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_GPCTrainingSamples_create_const__InputArrayR_const__InputArrayR_const__InputArrayR_int(images_from.as_raw__InputArray(), images_to.as_raw__InputArray(), gt.as_raw__InputArray(), descriptor_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::optflow::GPCTrainingSamples>::opencv_from_extern(ret) };
@@ -1785,8 +2416,9 @@ pub trait GPCTreeTraitConst: core::AlgorithmTraitConst {
 
 	#[inline]
 	fn write(&self, fs: &mut core::FileStorage) -> Result<()> {
+		crate::core::validity::check("GPCTree", self.as_raw_GPCTree())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_GPCTree_write_const_FileStorageR(self.as_raw_GPCTree(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_GPCTree_write_const_FileStorageR(self.as_raw_GPCTree(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1794,8 +2426,9 @@ pub trait GPCTreeTraitConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn find_leaf_for_patch(&self, descr: &crate::optflow::GPCPatchDescriptor) -> Result<u32> {
+		crate::core::validity::check("GPCTree", self.as_raw_GPCTree())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_GPCTree_findLeafForPatch_const_const_GPCPatchDescriptorR(self.as_raw_GPCTree(), descr.as_raw_GPCPatchDescriptor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_GPCTree_findLeafForPatch_const_const_GPCPatchDescriptorR(self.as_raw_GPCTree(), descr.as_raw_GPCPatchDescriptor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1803,8 +2436,9 @@ pub trait GPCTreeTraitConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn equals(&self, t: &crate::optflow::GPCTree) -> Result<bool> {
+		crate::core::validity::check("GPCTree", self.as_raw_GPCTree())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_GPCTree_operatorEQ_const_const_GPCTreeR(self.as_raw_GPCTree(), t.as_raw_GPCTree(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_GPCTree_operatorEQ_const_const_GPCTreeR(self.as_raw_GPCTree(), t.as_raw_GPCTree(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1812,8 +2446,9 @@ pub trait GPCTreeTraitConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_descriptor_type(&self) -> Result<i32> {
+		crate::core::validity::check("GPCTree", self.as_raw_GPCTree())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_GPCTree_getDescriptorType_const(self.as_raw_GPCTree(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_GPCTree_getDescriptorType_const(self.as_raw_GPCTree(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1828,8 +2463,9 @@ pub trait GPCTreeTrait: core::AlgorithmTrait + crate::optflow::GPCTreeTraitConst
 	/// * params: GPCTrainingParams()
 	#[inline]
 	fn train(&mut self, samples: &mut crate::optflow::GPCTrainingSamples, params: crate::optflow::GPCTrainingParams) -> Result<()> {
+		crate::core::validity::check("GPCTree", self.as_raw_mut_GPCTree())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_GPCTree_train_GPCTrainingSamplesR_const_GPCTrainingParams(self.as_raw_mut_GPCTree(), samples.as_raw_mut_GPCTrainingSamples(), params.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_GPCTree_train_GPCTrainingSamplesR_const_GPCTrainingParams(self.as_raw_mut_GPCTree(), samples.as_raw_mut_GPCTrainingSamples(), params.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1837,8 +2473,9 @@ pub trait GPCTreeTrait: core::AlgorithmTrait + crate::optflow::GPCTreeTraitConst
 	
 	#[inline]
 	fn read(&mut self, fn_: &core::FileNode) -> Result<()> {
+		crate::core::validity::check("GPCTree", self.as_raw_mut_GPCTree())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_GPCTree_read_const_FileNodeR(self.as_raw_mut_GPCTree(), fn_.as_raw_FileNode(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_GPCTree_read_const_FileNodeR(self.as_raw_mut_GPCTree(), fn_.as_raw_FileNode(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1856,7 +2493,10 @@ opencv_type_boxed! { GPCTree }
 impl Drop for GPCTree {
 	fn drop(&mut self) {
 		extern "C" { fn cv_GPCTree_delete(instance: *mut c_void); }
-		unsafe { cv_GPCTree_delete(self.as_raw_mut_GPCTree()) };
+		let ptr = self.as_raw_mut_GPCTree();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_GPCTree_delete(ptr) };
 	}
 }
 
@@ -1882,7 +2522,7 @@ impl GPCTree {
 	#[inline]
 	pub fn create() -> Result<core::Ptr<crate::optflow::GPCTree>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_GPCTree_create(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_GPCTree_create(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::optflow::GPCTree>::opencv_from_extern(ret) };
@@ -1910,7 +2550,7 @@ impl GPCTree_Node {
 	#[inline]
 	pub fn equals(self, n: crate::optflow::GPCTree_Node) -> Result<bool> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_GPCTree_Node_operatorEQ_const_const_NodeR(self.opencv_as_extern(), &n, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_GPCTree_Node_operatorEQ_const_const_NodeR(self.opencv_as_extern(), &n, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1929,11 +2569,12 @@ pub trait OpticalFlowPCAFlowTrait: crate::optflow::OpticalFlowPCAFlowTraitConst
 
 	#[inline]
 	fn calc(&mut self, i0: &dyn core::ToInputArray, i1: &dyn core::ToInputArray, flow: &mut dyn core::ToInputOutputArray) -> Result<()> {
+		crate::core::validity::check("OpticalFlowPCAFlow", self.as_raw_mut_OpticalFlowPCAFlow())?;
 		input_array_arg!(i0);
 		input_array_arg!(i1);
 		input_output_array_arg!(flow);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_OpticalFlowPCAFlow_calc_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR(self.as_raw_mut_OpticalFlowPCAFlow(), i0.as_raw__InputArray(), i1.as_raw__InputArray(), flow.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_OpticalFlowPCAFlow_calc_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR(self.as_raw_mut_OpticalFlowPCAFlow(), i0.as_raw__InputArray(), i1.as_raw__InputArray(), flow.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1941,8 +2582,9 @@ pub trait OpticalFlowPCAFlowTrait: crate::optflow::OpticalFlowPCAFlowTraitConst
 	
 	#[inline]
 	fn collect_garbage(&mut self) -> Result<()> {
+		crate::core::validity::check("OpticalFlowPCAFlow", self.as_raw_mut_OpticalFlowPCAFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_OpticalFlowPCAFlow_collectGarbage(self.as_raw_mut_OpticalFlowPCAFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_OpticalFlowPCAFlow_collectGarbage(self.as_raw_mut_OpticalFlowPCAFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1960,7 +2602,10 @@ opencv_type_boxed! { OpticalFlowPCAFlow }
 impl Drop for OpticalFlowPCAFlow {
 	fn drop(&mut self) {
 		extern "C" { fn cv_OpticalFlowPCAFlow_delete(instance: *mut c_void); }
-		unsafe { cv_OpticalFlowPCAFlow_delete(self.as_raw_mut_OpticalFlowPCAFlow()) };
+		let ptr = self.as_raw_mut_OpticalFlowPCAFlow();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_OpticalFlowPCAFlow_delete(ptr) };
 	}
 }
 
@@ -2012,7 +2657,7 @@ impl OpticalFlowPCAFlow {
 	#[inline]
 	pub fn new(_prior: core::Ptr<crate::optflow::PCAPrior>, _basis_size: core::Size, _sparse_rate: f32, _retained_corners_fraction: f32, _occlusions_threshold: f32, _damping_factor: f32, _clahe_clip: f32) -> Result<crate::optflow::OpticalFlowPCAFlow> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_OpticalFlowPCAFlow_OpticalFlowPCAFlow_Ptr_const_PCAPrior__const_Size_float_float_float_float_float(_prior.as_raw_PtrOfPCAPrior(), _basis_size.opencv_as_extern(), _sparse_rate, _retained_corners_fraction, _occlusions_threshold, _damping_factor, _clahe_clip, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_OpticalFlowPCAFlow_OpticalFlowPCAFlow_Ptr_const_PCAPrior__const_Size_float_float_float_float_float(_prior.as_raw_PtrOfPCAPrior(), _basis_size.opencv_as_extern(), _sparse_rate, _retained_corners_fraction, _occlusions_threshold, _damping_factor, _clahe_clip, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::optflow::OpticalFlowPCAFlow::opencv_from_extern(ret) };
@@ -2021,6 +2666,74 @@ impl OpticalFlowPCAFlow {
 	
 }
 
+/// Builder for `OpticalFlowPCAFlow::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct OpticalFlowPCAFlow_NewBuilder {
+	_prior: core::Ptr<crate::optflow::PCAPrior>,
+	_basis_size: core::Size,
+	_sparse_rate: f32,
+	_retained_corners_fraction: f32,
+	_occlusions_threshold: f32,
+	_damping_factor: f32,
+	_clahe_clip: f32,
+}
+
+impl OpticalFlowPCAFlow_NewBuilder {
+	#[inline]
+	pub fn new(_prior: core::Ptr<crate::optflow::PCAPrior>) -> Self {
+		Self { _prior, _basis_size: core::Size::new(18, 14), _sparse_rate: 0.024, _retained_corners_fraction: 0.2, _occlusions_threshold: 0.0003, _damping_factor: 0.00002, _clahe_clip: 14. }
+	}
+	
+	/// C++ default: `Size(18,14)`
+	#[inline]
+	pub fn with__basis_size(mut self, _basis_size: core::Size) -> Self {
+		self._basis_size = _basis_size;
+		self
+	}
+	
+	/// C++ default: `0.024`
+	#[inline]
+	pub fn with__sparse_rate(mut self, _sparse_rate: f32) -> Self {
+		self._sparse_rate = _sparse_rate;
+		self
+	}
+	
+	/// C++ default: `0.2`
+	#[inline]
+	pub fn with__retained_corners_fraction(mut self, _retained_corners_fraction: f32) -> Self {
+		self._retained_corners_fraction = _retained_corners_fraction;
+		self
+	}
+	
+	/// C++ default: `0.0003`
+	#[inline]
+	pub fn with__occlusions_threshold(mut self, _occlusions_threshold: f32) -> Self {
+		self._occlusions_threshold = _occlusions_threshold;
+		self
+	}
+	
+	/// C++ default: `0.00002`
+	#[inline]
+	pub fn with__damping_factor(mut self, _damping_factor: f32) -> Self {
+		self._damping_factor = _damping_factor;
+		self
+	}
+	
+	/// C++ default: `14`
+	#[inline]
+	pub fn with__clahe_clip(mut self, _clahe_clip: f32) -> Self {
+		self._clahe_clip = _clahe_clip;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::optflow::OpticalFlowPCAFlow> {
+		OpticalFlowPCAFlow::new(self._prior, self._basis_size, self._sparse_rate, self._retained_corners_fraction, self._occlusions_threshold, self._damping_factor, self._clahe_clip)
+	}
+}
+
 boxed_cast_base! { OpticalFlowPCAFlow, core::Algorithm, cv_OpticalFlowPCAFlow_to_Algorithm }
 
 /// @brief
@@ -2032,8 +2745,9 @@ pub trait PCAPriorTraitConst {
 
 	#[inline]
 	fn get_padding(&self) -> Result<i32> {
+		crate::core::validity::check("PCAPrior", self.as_raw_PCAPrior())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_PCAPrior_getPadding_const(self.as_raw_PCAPrior(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_PCAPrior_getPadding_const(self.as_raw_PCAPrior(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2041,8 +2755,9 @@ pub trait PCAPriorTraitConst {
 	
 	#[inline]
 	fn get_basis_size(&self) -> Result<i32> {
+		crate::core::validity::check("PCAPrior", self.as_raw_PCAPrior())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_PCAPrior_getBasisSize_const(self.as_raw_PCAPrior(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_PCAPrior_getBasisSize_const(self.as_raw_PCAPrior(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2050,8 +2765,9 @@ pub trait PCAPriorTraitConst {
 	
 	#[inline]
 	fn fill_constraints(&self, a1: &mut f32, a2: &mut f32, b1: &mut f32, b2: &mut f32) -> Result<()> {
+		crate::core::validity::check("PCAPrior", self.as_raw_PCAPrior())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_PCAPrior_fillConstraints_const_floatX_floatX_floatX_floatX(self.as_raw_PCAPrior(), a1, a2, b1, b2, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_PCAPrior_fillConstraints_const_floatX_floatX_floatX_floatX(self.as_raw_PCAPrior(), a1, a2, b1, b2, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2077,7 +2793,10 @@ opencv_type_boxed! { PCAPrior }
 impl Drop for PCAPrior {
 	fn drop(&mut self) {
 		extern "C" { fn cv_PCAPrior_delete(instance: *mut c_void); }
-		unsafe { cv_PCAPrior_delete(self.as_raw_mut_PCAPrior()) };
+		let ptr = self.as_raw_mut_PCAPrior();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_PCAPrior_delete(ptr) };
 	}
 }
 
@@ -2096,7 +2815,7 @@ impl PCAPrior {
 	pub fn new(path_to_prior: &str) -> Result<crate::optflow::PCAPrior> {
 		extern_container_arg!(path_to_prior);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_PCAPrior_PCAPrior_const_charX(path_to_prior.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_PCAPrior_PCAPrior_const_charX(path_to_prior.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::optflow::PCAPrior::opencv_from_extern(ret) };
@@ -2136,96 +2855,223 @@ pub trait RLOFOpticalFlowParameterTraitConst {
 
 	#[inline]
 	fn solver_type(&self) -> crate::optflow::SolverType {
+		crate::core::validity::debug_check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getPropSolverType_const(self.as_raw_RLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_getPropSolverType_const(self.as_raw_RLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [solver_type](RLOFOpticalFlowParameterTraitConst::solver_type), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_solver_type(&self) -> Result<crate::optflow::SolverType> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter())?;
+		Ok(RLOFOpticalFlowParameterTraitConst::solver_type(self))
+	}
+	
 	#[inline]
 	fn support_region_type(&self) -> crate::optflow::SupportRegionType {
+		crate::core::validity::debug_check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getPropSupportRegionType_const(self.as_raw_RLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_getPropSupportRegionType_const(self.as_raw_RLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [support_region_type](RLOFOpticalFlowParameterTraitConst::support_region_type), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_support_region_type(&self) -> Result<crate::optflow::SupportRegionType> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter())?;
+		Ok(RLOFOpticalFlowParameterTraitConst::support_region_type(self))
+	}
+	
 	#[inline]
 	fn norm_sigma0(&self) -> f32 {
-		let ret = unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getPropNormSigma0_const(self.as_raw_RLOFOpticalFlowParameter()) };
+		crate::core::validity::debug_check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter());
+		let ret = unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_getPropNormSigma0_const(self.as_raw_RLOFOpticalFlowParameter())) };
 		ret
 	}
 	
+	/// Non-panicking version of [norm_sigma0](RLOFOpticalFlowParameterTraitConst::norm_sigma0), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_norm_sigma0(&self) -> Result<f32> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter())?;
+		Ok(RLOFOpticalFlowParameterTraitConst::norm_sigma0(self))
+	}
+	
 	#[inline]
 	fn norm_sigma1(&self) -> f32 {
-		let ret = unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getPropNormSigma1_const(self.as_raw_RLOFOpticalFlowParameter()) };
+		crate::core::validity::debug_check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter());
+		let ret = unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_getPropNormSigma1_const(self.as_raw_RLOFOpticalFlowParameter())) };
 		ret
 	}
 	
+	/// Non-panicking version of [norm_sigma1](RLOFOpticalFlowParameterTraitConst::norm_sigma1), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_norm_sigma1(&self) -> Result<f32> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter())?;
+		Ok(RLOFOpticalFlowParameterTraitConst::norm_sigma1(self))
+	}
+	
 	#[inline]
 	fn small_win_size(&self) -> i32 {
-		let ret = unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getPropSmallWinSize_const(self.as_raw_RLOFOpticalFlowParameter()) };
+		crate::core::validity::debug_check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter());
+		let ret = unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_getPropSmallWinSize_const(self.as_raw_RLOFOpticalFlowParameter())) };
 		ret
 	}
 	
+	/// Non-panicking version of [small_win_size](RLOFOpticalFlowParameterTraitConst::small_win_size), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_small_win_size(&self) -> Result<i32> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter())?;
+		Ok(RLOFOpticalFlowParameterTraitConst::small_win_size(self))
+	}
+	
 	#[inline]
 	fn large_win_size(&self) -> i32 {
-		let ret = unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getPropLargeWinSize_const(self.as_raw_RLOFOpticalFlowParameter()) };
+		crate::core::validity::debug_check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter());
+		let ret = unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_getPropLargeWinSize_const(self.as_raw_RLOFOpticalFlowParameter())) };
 		ret
 	}
 	
+	/// Non-panicking version of [large_win_size](RLOFOpticalFlowParameterTraitConst::large_win_size), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_large_win_size(&self) -> Result<i32> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter())?;
+		Ok(RLOFOpticalFlowParameterTraitConst::large_win_size(self))
+	}
+	
 	#[inline]
 	fn cross_segmentation_threshold(&self) -> i32 {
-		let ret = unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getPropCrossSegmentationThreshold_const(self.as_raw_RLOFOpticalFlowParameter()) };
+		crate::core::validity::debug_check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter());
+		let ret = unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_getPropCrossSegmentationThreshold_const(self.as_raw_RLOFOpticalFlowParameter())) };
 		ret
 	}
 	
+	/// Non-panicking version of [cross_segmentation_threshold](RLOFOpticalFlowParameterTraitConst::cross_segmentation_threshold), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_cross_segmentation_threshold(&self) -> Result<i32> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter())?;
+		Ok(RLOFOpticalFlowParameterTraitConst::cross_segmentation_threshold(self))
+	}
+	
 	#[inline]
 	fn max_level(&self) -> i32 {
-		let ret = unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getPropMaxLevel_const(self.as_raw_RLOFOpticalFlowParameter()) };
+		crate::core::validity::debug_check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter());
+		let ret = unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_getPropMaxLevel_const(self.as_raw_RLOFOpticalFlowParameter())) };
 		ret
 	}
 	
+	/// Non-panicking version of [max_level](RLOFOpticalFlowParameterTraitConst::max_level), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_max_level(&self) -> Result<i32> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter())?;
+		Ok(RLOFOpticalFlowParameterTraitConst::max_level(self))
+	}
+	
 	#[inline]
 	fn use_initial_flow(&self) -> bool {
-		let ret = unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getPropUseInitialFlow_const(self.as_raw_RLOFOpticalFlowParameter()) };
+		crate::core::validity::debug_check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter());
+		let ret = unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_getPropUseInitialFlow_const(self.as_raw_RLOFOpticalFlowParameter())) };
 		ret
 	}
 	
+	/// Non-panicking version of [use_initial_flow](RLOFOpticalFlowParameterTraitConst::use_initial_flow), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_use_initial_flow(&self) -> Result<bool> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter())?;
+		Ok(RLOFOpticalFlowParameterTraitConst::use_initial_flow(self))
+	}
+	
 	#[inline]
 	fn use_illumination_model(&self) -> bool {
-		let ret = unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getPropUseIlluminationModel_const(self.as_raw_RLOFOpticalFlowParameter()) };
+		crate::core::validity::debug_check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter());
+		let ret = unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_getPropUseIlluminationModel_const(self.as_raw_RLOFOpticalFlowParameter())) };
 		ret
 	}
 	
+	/// Non-panicking version of [use_illumination_model](RLOFOpticalFlowParameterTraitConst::use_illumination_model), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_use_illumination_model(&self) -> Result<bool> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter())?;
+		Ok(RLOFOpticalFlowParameterTraitConst::use_illumination_model(self))
+	}
+	
 	#[inline]
 	fn use_global_motion_prior(&self) -> bool {
-		let ret = unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getPropUseGlobalMotionPrior_const(self.as_raw_RLOFOpticalFlowParameter()) };
+		crate::core::validity::debug_check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter());
+		let ret = unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_getPropUseGlobalMotionPrior_const(self.as_raw_RLOFOpticalFlowParameter())) };
 		ret
 	}
 	
+	/// Non-panicking version of [use_global_motion_prior](RLOFOpticalFlowParameterTraitConst::use_global_motion_prior), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_use_global_motion_prior(&self) -> Result<bool> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter())?;
+		Ok(RLOFOpticalFlowParameterTraitConst::use_global_motion_prior(self))
+	}
+	
 	#[inline]
 	fn max_iteration(&self) -> i32 {
-		let ret = unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getPropMaxIteration_const(self.as_raw_RLOFOpticalFlowParameter()) };
+		crate::core::validity::debug_check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter());
+		let ret = unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_getPropMaxIteration_const(self.as_raw_RLOFOpticalFlowParameter())) };
 		ret
 	}
 	
+	/// Non-panicking version of [max_iteration](RLOFOpticalFlowParameterTraitConst::max_iteration), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_max_iteration(&self) -> Result<i32> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter())?;
+		Ok(RLOFOpticalFlowParameterTraitConst::max_iteration(self))
+	}
+	
 	#[inline]
 	fn min_eigen_value(&self) -> f32 {
-		let ret = unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getPropMinEigenValue_const(self.as_raw_RLOFOpticalFlowParameter()) };
+		crate::core::validity::debug_check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter());
+		let ret = unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_getPropMinEigenValue_const(self.as_raw_RLOFOpticalFlowParameter())) };
 		ret
 	}
 	
+	/// Non-panicking version of [min_eigen_value](RLOFOpticalFlowParameterTraitConst::min_eigen_value), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_min_eigen_value(&self) -> Result<f32> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter())?;
+		Ok(RLOFOpticalFlowParameterTraitConst::min_eigen_value(self))
+	}
+	
 	#[inline]
 	fn global_motion_ransac_threshold(&self) -> f32 {
-		let ret = unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getPropGlobalMotionRansacThreshold_const(self.as_raw_RLOFOpticalFlowParameter()) };
+		crate::core::validity::debug_check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter());
+		let ret = unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_getPropGlobalMotionRansacThreshold_const(self.as_raw_RLOFOpticalFlowParameter())) };
 		ret
 	}
 	
+	/// Non-panicking version of [global_motion_ransac_threshold](RLOFOpticalFlowParameterTraitConst::global_motion_ransac_threshold), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_global_motion_ransac_threshold(&self) -> Result<f32> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter())?;
+		Ok(RLOFOpticalFlowParameterTraitConst::global_motion_ransac_threshold(self))
+	}
+	
 	#[inline]
 	fn get_solver_type(&self) -> Result<crate::optflow::SolverType> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getSolverType_const(self.as_raw_RLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_getSolverType_const(self.as_raw_RLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2233,8 +3079,9 @@ pub trait RLOFOpticalFlowParameterTraitConst {
 	
 	#[inline]
 	fn get_support_region_type(&self) -> Result<crate::optflow::SupportRegionType> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getSupportRegionType_const(self.as_raw_RLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_getSupportRegionType_const(self.as_raw_RLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2242,8 +3089,9 @@ pub trait RLOFOpticalFlowParameterTraitConst {
 	
 	#[inline]
 	fn get_norm_sigma0(&self) -> Result<f32> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getNormSigma0_const(self.as_raw_RLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_getNormSigma0_const(self.as_raw_RLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2251,8 +3099,9 @@ pub trait RLOFOpticalFlowParameterTraitConst {
 	
 	#[inline]
 	fn get_norm_sigma1(&self) -> Result<f32> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getNormSigma1_const(self.as_raw_RLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_getNormSigma1_const(self.as_raw_RLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2260,8 +3109,9 @@ pub trait RLOFOpticalFlowParameterTraitConst {
 	
 	#[inline]
 	fn get_small_win_size(&self) -> Result<i32> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getSmallWinSize_const(self.as_raw_RLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_getSmallWinSize_const(self.as_raw_RLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2269,8 +3119,9 @@ pub trait RLOFOpticalFlowParameterTraitConst {
 	
 	#[inline]
 	fn get_large_win_size(&self) -> Result<i32> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getLargeWinSize_const(self.as_raw_RLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_getLargeWinSize_const(self.as_raw_RLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2278,8 +3129,9 @@ pub trait RLOFOpticalFlowParameterTraitConst {
 	
 	#[inline]
 	fn get_cross_segmentation_threshold(&self) -> Result<i32> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getCrossSegmentationThreshold_const(self.as_raw_RLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_getCrossSegmentationThreshold_const(self.as_raw_RLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2287,8 +3139,9 @@ pub trait RLOFOpticalFlowParameterTraitConst {
 	
 	#[inline]
 	fn get_max_level(&self) -> Result<i32> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getMaxLevel_const(self.as_raw_RLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_getMaxLevel_const(self.as_raw_RLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2296,8 +3149,9 @@ pub trait RLOFOpticalFlowParameterTraitConst {
 	
 	#[inline]
 	fn get_use_initial_flow(&self) -> Result<bool> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getUseInitialFlow_const(self.as_raw_RLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_getUseInitialFlow_const(self.as_raw_RLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2305,8 +3159,9 @@ pub trait RLOFOpticalFlowParameterTraitConst {
 	
 	#[inline]
 	fn get_use_illumination_model(&self) -> Result<bool> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getUseIlluminationModel_const(self.as_raw_RLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_getUseIlluminationModel_const(self.as_raw_RLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2314,8 +3169,9 @@ pub trait RLOFOpticalFlowParameterTraitConst {
 	
 	#[inline]
 	fn get_use_global_motion_prior(&self) -> Result<bool> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getUseGlobalMotionPrior_const(self.as_raw_RLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_getUseGlobalMotionPrior_const(self.as_raw_RLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2323,8 +3179,9 @@ pub trait RLOFOpticalFlowParameterTraitConst {
 	
 	#[inline]
 	fn get_max_iteration(&self) -> Result<i32> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getMaxIteration_const(self.as_raw_RLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_getMaxIteration_const(self.as_raw_RLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2332,8 +3189,9 @@ pub trait RLOFOpticalFlowParameterTraitConst {
 	
 	#[inline]
 	fn get_min_eigen_value(&self) -> Result<f32> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getMinEigenValue_const(self.as_raw_RLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_getMinEigenValue_const(self.as_raw_RLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2341,8 +3199,9 @@ pub trait RLOFOpticalFlowParameterTraitConst {
 	
 	#[inline]
 	fn get_global_motion_ransac_threshold(&self) -> Result<f32> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_RLOFOpticalFlowParameter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getGlobalMotionRansacThreshold_const(self.as_raw_RLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_getGlobalMotionRansacThreshold_const(self.as_raw_RLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2355,85 +3214,99 @@ pub trait RLOFOpticalFlowParameterTrait: crate::optflow::RLOFOpticalFlowParamete
 
 	#[inline]
 	fn set_solver_type(&mut self, val: crate::optflow::SolverType) {
-		let ret = unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setPropSolverType_SolverType(self.as_raw_mut_RLOFOpticalFlowParameter(), val) };
+		crate::core::validity::debug_check("RLOFOpticalFlowParameter", self.as_raw_mut_RLOFOpticalFlowParameter());
+		let ret = unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_setPropSolverType_SolverType(self.as_raw_mut_RLOFOpticalFlowParameter(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_support_region_type(&mut self, val: crate::optflow::SupportRegionType) {
-		let ret = unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setPropSupportRegionType_SupportRegionType(self.as_raw_mut_RLOFOpticalFlowParameter(), val) };
+		crate::core::validity::debug_check("RLOFOpticalFlowParameter", self.as_raw_mut_RLOFOpticalFlowParameter());
+		let ret = unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_setPropSupportRegionType_SupportRegionType(self.as_raw_mut_RLOFOpticalFlowParameter(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_norm_sigma0(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setPropNormSigma0_float(self.as_raw_mut_RLOFOpticalFlowParameter(), val) };
+		crate::core::validity::debug_check("RLOFOpticalFlowParameter", self.as_raw_mut_RLOFOpticalFlowParameter());
+		let ret = unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_setPropNormSigma0_float(self.as_raw_mut_RLOFOpticalFlowParameter(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_norm_sigma1(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setPropNormSigma1_float(self.as_raw_mut_RLOFOpticalFlowParameter(), val) };
+		crate::core::validity::debug_check("RLOFOpticalFlowParameter", self.as_raw_mut_RLOFOpticalFlowParameter());
+		let ret = unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_setPropNormSigma1_float(self.as_raw_mut_RLOFOpticalFlowParameter(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_small_win_size(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setPropSmallWinSize_int(self.as_raw_mut_RLOFOpticalFlowParameter(), val) };
+		crate::core::validity::debug_check("RLOFOpticalFlowParameter", self.as_raw_mut_RLOFOpticalFlowParameter());
+		let ret = unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_setPropSmallWinSize_int(self.as_raw_mut_RLOFOpticalFlowParameter(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_large_win_size(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setPropLargeWinSize_int(self.as_raw_mut_RLOFOpticalFlowParameter(), val) };
+		crate::core::validity::debug_check("RLOFOpticalFlowParameter", self.as_raw_mut_RLOFOpticalFlowParameter());
+		let ret = unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_setPropLargeWinSize_int(self.as_raw_mut_RLOFOpticalFlowParameter(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_cross_segmentation_threshold(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setPropCrossSegmentationThreshold_int(self.as_raw_mut_RLOFOpticalFlowParameter(), val) };
+		crate::core::validity::debug_check("RLOFOpticalFlowParameter", self.as_raw_mut_RLOFOpticalFlowParameter());
+		let ret = unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_setPropCrossSegmentationThreshold_int(self.as_raw_mut_RLOFOpticalFlowParameter(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_max_level(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setPropMaxLevel_int(self.as_raw_mut_RLOFOpticalFlowParameter(), val) };
+		crate::core::validity::debug_check("RLOFOpticalFlowParameter", self.as_raw_mut_RLOFOpticalFlowParameter());
+		let ret = unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_setPropMaxLevel_int(self.as_raw_mut_RLOFOpticalFlowParameter(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_use_initial_flow(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setPropUseInitialFlow_bool(self.as_raw_mut_RLOFOpticalFlowParameter(), val) };
+		crate::core::validity::debug_check("RLOFOpticalFlowParameter", self.as_raw_mut_RLOFOpticalFlowParameter());
+		let ret = unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_setPropUseInitialFlow_bool(self.as_raw_mut_RLOFOpticalFlowParameter(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_use_illumination_model(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setPropUseIlluminationModel_bool(self.as_raw_mut_RLOFOpticalFlowParameter(), val) };
+		crate::core::validity::debug_check("RLOFOpticalFlowParameter", self.as_raw_mut_RLOFOpticalFlowParameter());
+		let ret = unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_setPropUseIlluminationModel_bool(self.as_raw_mut_RLOFOpticalFlowParameter(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_use_global_motion_prior(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setPropUseGlobalMotionPrior_bool(self.as_raw_mut_RLOFOpticalFlowParameter(), val) };
+		crate::core::validity::debug_check("RLOFOpticalFlowParameter", self.as_raw_mut_RLOFOpticalFlowParameter());
+		let ret = unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_setPropUseGlobalMotionPrior_bool(self.as_raw_mut_RLOFOpticalFlowParameter(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_max_iteration(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setPropMaxIteration_int(self.as_raw_mut_RLOFOpticalFlowParameter(), val) };
+		crate::core::validity::debug_check("RLOFOpticalFlowParameter", self.as_raw_mut_RLOFOpticalFlowParameter());
+		let ret = unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_setPropMaxIteration_int(self.as_raw_mut_RLOFOpticalFlowParameter(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_min_eigen_value(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setPropMinEigenValue_float(self.as_raw_mut_RLOFOpticalFlowParameter(), val) };
+		crate::core::validity::debug_check("RLOFOpticalFlowParameter", self.as_raw_mut_RLOFOpticalFlowParameter());
+		let ret = unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_setPropMinEigenValue_float(self.as_raw_mut_RLOFOpticalFlowParameter(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_global_motion_ransac_threshold(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setPropGlobalMotionRansacThreshold_float(self.as_raw_mut_RLOFOpticalFlowParameter(), val) };
+		crate::core::validity::debug_check("RLOFOpticalFlowParameter", self.as_raw_mut_RLOFOpticalFlowParameter());
+		let ret = unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_setPropGlobalMotionRansacThreshold_float(self.as_raw_mut_RLOFOpticalFlowParameter(), val)) };
 		ret
 	}
 	
@@ -2445,8 +3318,9 @@ pub trait RLOFOpticalFlowParameterTrait: crate::optflow::RLOFOpticalFlowParamete
 	///      *    see also: setNormSigma0, setNormSigma1
 	#[inline]
 	fn set_use_m_estimator(&mut self, val: bool) -> Result<()> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_mut_RLOFOpticalFlowParameter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setUseMEstimator_bool(self.as_raw_mut_RLOFOpticalFlowParameter(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_setUseMEstimator_bool(self.as_raw_mut_RLOFOpticalFlowParameter(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2454,8 +3328,9 @@ pub trait RLOFOpticalFlowParameterTrait: crate::optflow::RLOFOpticalFlowParamete
 	
 	#[inline]
 	fn set_solver_type_1(&mut self, val: crate::optflow::SolverType) -> Result<()> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_mut_RLOFOpticalFlowParameter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setSolverType_SolverType(self.as_raw_mut_RLOFOpticalFlowParameter(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_setSolverType_SolverType(self.as_raw_mut_RLOFOpticalFlowParameter(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2463,8 +3338,9 @@ pub trait RLOFOpticalFlowParameterTrait: crate::optflow::RLOFOpticalFlowParamete
 	
 	#[inline]
 	fn set_support_region_type_1(&mut self, val: crate::optflow::SupportRegionType) -> Result<()> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_mut_RLOFOpticalFlowParameter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setSupportRegionType_SupportRegionType(self.as_raw_mut_RLOFOpticalFlowParameter(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_setSupportRegionType_SupportRegionType(self.as_raw_mut_RLOFOpticalFlowParameter(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2472,8 +3348,9 @@ pub trait RLOFOpticalFlowParameterTrait: crate::optflow::RLOFOpticalFlowParamete
 	
 	#[inline]
 	fn set_norm_sigma0_1(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_mut_RLOFOpticalFlowParameter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setNormSigma0_float(self.as_raw_mut_RLOFOpticalFlowParameter(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_setNormSigma0_float(self.as_raw_mut_RLOFOpticalFlowParameter(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2481,8 +3358,9 @@ pub trait RLOFOpticalFlowParameterTrait: crate::optflow::RLOFOpticalFlowParamete
 	
 	#[inline]
 	fn set_norm_sigma1_1(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_mut_RLOFOpticalFlowParameter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setNormSigma1_float(self.as_raw_mut_RLOFOpticalFlowParameter(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_setNormSigma1_float(self.as_raw_mut_RLOFOpticalFlowParameter(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2490,8 +3368,9 @@ pub trait RLOFOpticalFlowParameterTrait: crate::optflow::RLOFOpticalFlowParamete
 	
 	#[inline]
 	fn set_small_win_size_1(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_mut_RLOFOpticalFlowParameter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setSmallWinSize_int(self.as_raw_mut_RLOFOpticalFlowParameter(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_setSmallWinSize_int(self.as_raw_mut_RLOFOpticalFlowParameter(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2499,8 +3378,9 @@ pub trait RLOFOpticalFlowParameterTrait: crate::optflow::RLOFOpticalFlowParamete
 	
 	#[inline]
 	fn set_large_win_size_1(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_mut_RLOFOpticalFlowParameter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setLargeWinSize_int(self.as_raw_mut_RLOFOpticalFlowParameter(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_setLargeWinSize_int(self.as_raw_mut_RLOFOpticalFlowParameter(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2508,8 +3388,9 @@ pub trait RLOFOpticalFlowParameterTrait: crate::optflow::RLOFOpticalFlowParamete
 	
 	#[inline]
 	fn set_cross_segmentation_threshold_1(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_mut_RLOFOpticalFlowParameter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setCrossSegmentationThreshold_int(self.as_raw_mut_RLOFOpticalFlowParameter(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_setCrossSegmentationThreshold_int(self.as_raw_mut_RLOFOpticalFlowParameter(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2517,8 +3398,9 @@ pub trait RLOFOpticalFlowParameterTrait: crate::optflow::RLOFOpticalFlowParamete
 	
 	#[inline]
 	fn set_max_level_1(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_mut_RLOFOpticalFlowParameter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setMaxLevel_int(self.as_raw_mut_RLOFOpticalFlowParameter(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_setMaxLevel_int(self.as_raw_mut_RLOFOpticalFlowParameter(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2526,8 +3408,9 @@ pub trait RLOFOpticalFlowParameterTrait: crate::optflow::RLOFOpticalFlowParamete
 	
 	#[inline]
 	fn set_use_initial_flow_1(&mut self, val: bool) -> Result<()> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_mut_RLOFOpticalFlowParameter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setUseInitialFlow_bool(self.as_raw_mut_RLOFOpticalFlowParameter(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_setUseInitialFlow_bool(self.as_raw_mut_RLOFOpticalFlowParameter(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2535,8 +3418,9 @@ pub trait RLOFOpticalFlowParameterTrait: crate::optflow::RLOFOpticalFlowParamete
 	
 	#[inline]
 	fn set_use_illumination_model_1(&mut self, val: bool) -> Result<()> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_mut_RLOFOpticalFlowParameter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setUseIlluminationModel_bool(self.as_raw_mut_RLOFOpticalFlowParameter(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_setUseIlluminationModel_bool(self.as_raw_mut_RLOFOpticalFlowParameter(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2544,8 +3428,9 @@ pub trait RLOFOpticalFlowParameterTrait: crate::optflow::RLOFOpticalFlowParamete
 	
 	#[inline]
 	fn set_use_global_motion_prior_1(&mut self, val: bool) -> Result<()> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_mut_RLOFOpticalFlowParameter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setUseGlobalMotionPrior_bool(self.as_raw_mut_RLOFOpticalFlowParameter(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_setUseGlobalMotionPrior_bool(self.as_raw_mut_RLOFOpticalFlowParameter(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2553,8 +3438,9 @@ pub trait RLOFOpticalFlowParameterTrait: crate::optflow::RLOFOpticalFlowParamete
 	
 	#[inline]
 	fn set_max_iteration_1(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_mut_RLOFOpticalFlowParameter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setMaxIteration_int(self.as_raw_mut_RLOFOpticalFlowParameter(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_setMaxIteration_int(self.as_raw_mut_RLOFOpticalFlowParameter(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2562,8 +3448,9 @@ pub trait RLOFOpticalFlowParameterTrait: crate::optflow::RLOFOpticalFlowParamete
 	
 	#[inline]
 	fn set_min_eigen_value_1(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_mut_RLOFOpticalFlowParameter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setMinEigenValue_float(self.as_raw_mut_RLOFOpticalFlowParameter(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_setMinEigenValue_float(self.as_raw_mut_RLOFOpticalFlowParameter(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2571,8 +3458,9 @@ pub trait RLOFOpticalFlowParameterTrait: crate::optflow::RLOFOpticalFlowParamete
 	
 	#[inline]
 	fn set_global_motion_ransac_threshold_1(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("RLOFOpticalFlowParameter", self.as_raw_mut_RLOFOpticalFlowParameter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setGlobalMotionRansacThreshold_float(self.as_raw_mut_RLOFOpticalFlowParameter(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_setGlobalMotionRansacThreshold_float(self.as_raw_mut_RLOFOpticalFlowParameter(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2615,7 +3503,10 @@ opencv_type_boxed! { RLOFOpticalFlowParameter }
 impl Drop for RLOFOpticalFlowParameter {
 	fn drop(&mut self) {
 		extern "C" { fn cv_RLOFOpticalFlowParameter_delete(instance: *mut c_void); }
-		unsafe { cv_RLOFOpticalFlowParameter_delete(self.as_raw_mut_RLOFOpticalFlowParameter()) };
+		let ptr = self.as_raw_mut_RLOFOpticalFlowParameter();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_RLOFOpticalFlowParameter_delete(ptr) };
 	}
 }
 
@@ -2633,7 +3524,7 @@ impl RLOFOpticalFlowParameter {
 	#[inline]
 	pub fn default() -> Result<crate::optflow::RLOFOpticalFlowParameter> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_RLOFOpticalFlowParameter(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_RLOFOpticalFlowParameter(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::optflow::RLOFOpticalFlowParameter::opencv_from_extern(ret) };
@@ -2644,7 +3535,7 @@ impl RLOFOpticalFlowParameter {
 	#[inline]
 	pub fn create() -> Result<core::Ptr<crate::optflow::RLOFOpticalFlowParameter>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_create(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_RLOFOpticalFlowParameter_create(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::optflow::RLOFOpticalFlowParameter>::opencv_from_extern(ret) };
@@ -2672,11 +3563,12 @@ pub trait SparseRLOFOpticalFlowConst: crate::video::SparseOpticalFlowConst {
 
 	/// @copydoc DenseRLOFOpticalFlow::setRLOFOpticalFlowParameter
 	/// ## See also
-	/// setRLOFOpticalFlowParameter
+	/// `set_rlof_optical_flow_parameter`
 	#[inline]
 	fn get_rlof_optical_flow_parameter(&self) -> Result<core::Ptr<crate::optflow::RLOFOpticalFlowParameter>> {
+		crate::core::validity::check("SparseRLOFOpticalFlow", self.as_raw_SparseRLOFOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_SparseRLOFOpticalFlow_getRLOFOpticalFlowParameter_const(self.as_raw_SparseRLOFOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_SparseRLOFOpticalFlow_getRLOFOpticalFlowParameter_const(self.as_raw_SparseRLOFOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::optflow::RLOFOpticalFlowParameter>::opencv_from_extern(ret) };
@@ -2693,8 +3585,9 @@ pub trait SparseRLOFOpticalFlowConst: crate::video::SparseOpticalFlowConst {
 	///      *    see also: setForwardBackward
 	#[inline]
 	fn get_forward_backward(&self) -> Result<f32> {
+		crate::core::validity::check("SparseRLOFOpticalFlow", self.as_raw_SparseRLOFOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_SparseRLOFOpticalFlow_getForwardBackward_const(self.as_raw_SparseRLOFOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_SparseRLOFOpticalFlow_getForwardBackward_const(self.as_raw_SparseRLOFOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2708,8 +3601,9 @@ pub trait SparseRLOFOpticalFlow: crate::optflow::SparseRLOFOpticalFlowConst + cr
 	/// @copydoc DenseRLOFOpticalFlow::setRLOFOpticalFlowParameter
 	#[inline]
 	fn set_rlof_optical_flow_parameter(&mut self, mut val: core::Ptr<crate::optflow::RLOFOpticalFlowParameter>) -> Result<()> {
+		crate::core::validity::check("SparseRLOFOpticalFlow", self.as_raw_mut_SparseRLOFOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_SparseRLOFOpticalFlow_setRLOFOpticalFlowParameter_Ptr_RLOFOpticalFlowParameter_(self.as_raw_mut_SparseRLOFOpticalFlow(), val.as_raw_mut_PtrOfRLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_SparseRLOFOpticalFlow_setRLOFOpticalFlowParameter_Ptr_RLOFOpticalFlowParameter_(self.as_raw_mut_SparseRLOFOpticalFlow(), val.as_raw_mut_PtrOfRLOFOpticalFlowParameter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2724,8 +3618,9 @@ pub trait SparseRLOFOpticalFlow: crate::optflow::SparseRLOFOpticalFlowConst + cr
 	///      *    see also: setForwardBackward
 	#[inline]
 	fn set_forward_backward(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("SparseRLOFOpticalFlow", self.as_raw_mut_SparseRLOFOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_SparseRLOFOpticalFlow_setForwardBackward_float(self.as_raw_mut_SparseRLOFOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_SparseRLOFOpticalFlow_setForwardBackward_float(self.as_raw_mut_SparseRLOFOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2746,11 +3641,12 @@ impl dyn SparseRLOFOpticalFlow + '_ {
 	#[inline]
 	pub fn create(mut rlof_param: core::Ptr<crate::optflow::RLOFOpticalFlowParameter>, forward_backward_threshold: f32) -> Result<core::Ptr<dyn crate::optflow::SparseRLOFOpticalFlow>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_optflow_SparseRLOFOpticalFlow_create_Ptr_RLOFOpticalFlowParameter__float(rlof_param.as_raw_mut_PtrOfRLOFOpticalFlowParameter(), forward_backward_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_optflow_SparseRLOFOpticalFlow_create_Ptr_RLOFOpticalFlowParameter__float(rlof_param.as_raw_mut_PtrOfRLOFOpticalFlowParameter(), forward_backward_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::optflow::SparseRLOFOpticalFlow>::opencv_from_extern(ret) };
 		Ok(ret)
 	}
 	
-}
\ No newline at end of file
+}
+pub use crate::manual::optflow::*;
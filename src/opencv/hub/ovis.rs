@@ -14,11 +14,11 @@
 //! and [Ogre Script](https://ogrecave.github.io/ogre/api/latest/_scripts.html) is assumed to be used for advanced customization.
 //! 
 //! Besides the API you see here, there are several environment variables that control the behavior of ovis.
-//! They are documented in @ref createWindow.
+//! They are documented in `create_window`.
 //! 
 //! ## Loading geometry
 //! 
-//! You can create geometry [on the fly](@ref createTriangleMesh) or by loading Ogre `.mesh` files.
+//! You can create geometry on the fly or by loading Ogre `.mesh` files.
 //! 
 //! ### Blender
 //! For converting/ creating geometry [Blender](https://www.blender.org/) is recommended.
@@ -30,7 +30,7 @@
 //! 
 //! ### Assimp
 //! When using Ogre 1.12.9 or later, enabling the Assimp plugin allows to load arbitrary geometry.
-//! Simply pass `bunny.obj` instead of `bunny.mesh` as `meshname` in @ref WindowScene::createEntity.
+//! Simply pass `bunny.obj` instead of `bunny.mesh` as `meshname` in `WindowScene::create_entity`.
 //! 
 //! You should still use ogre-meshviewer to verify that the geometry is converted correctly.
 use crate::{mod_prelude::*, core, sys, types};
@@ -57,11 +57,11 @@ pub const MATERIAL_TEXTURE3: i32 = 8;
 pub const SCENE_AA: i32 = 8;
 /// allow the user to control the camera.
 pub const SCENE_INTERACTIVE: i32 = 2;
-/// Render off-screen without a window. Allows separate AA setting. Requires manual update via @ref WindowScene::update
+/// Render off-screen without a window. Allows separate AA setting. Requires manual update via `WindowScene::update`
 pub const SCENE_OFFSCREEN: i32 = 16;
 /// the window will use a separate scene. The scene will be shared otherwise.
 pub const SCENE_SEPARATE: i32 = 1;
-/// Enable real-time shadows in the scene. All entities cast shadows by default. Control via @ref ENTITY_CAST_SHADOWS
+/// Enable real-time shadows in the scene. All entities cast shadows by default. Control via `ENTITY_CAST_SHADOWS`
 pub const SCENE_SHADOWS: i32 = 32;
 /// draw coordinate system crosses for debugging
 pub const SCENE_SHOW_CS_CROSS: i32 = 4;
@@ -105,9 +105,9 @@ pub enum SceneSettings {
 	SCENE_SHOW_CS_CROSS = 4,
 	/// Apply anti-aliasing. The first window determines the setting for all windows.
 	SCENE_AA = 8,
-	/// Render off-screen without a window. Allows separate AA setting. Requires manual update via @ref WindowScene::update
+	/// Render off-screen without a window. Allows separate AA setting. Requires manual update via `WindowScene::update`
 	SCENE_OFFSCREEN = 16,
-	/// Enable real-time shadows in the scene. All entities cast shadows by default. Control via @ref ENTITY_CAST_SHADOWS
+	/// Enable real-time shadows in the scene. All entities cast shadows by default. Control via `ENTITY_CAST_SHADOWS`
 	SCENE_SHADOWS = 32,
 }
 
@@ -123,7 +123,7 @@ opencv_type_enum! { crate::ovis::SceneSettings }
 pub fn add_resource_location(path: &str) -> Result<()> {
 	extern_container_arg!(path);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ovis_addResourceLocation_const_StringR(path.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ovis_addResourceLocation_const_StringR(path.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -143,7 +143,7 @@ pub fn add_resource_location(path: &str) -> Result<()> {
 pub fn create_grid_mesh(name: &str, size: core::Size2f, segments: core::Size) -> Result<()> {
 	extern_container_arg!(name);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ovis_createGridMesh_const_StringR_const_Size2fR_const_SizeR(name.opencv_as_extern(), &size, &segments, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ovis_createGridMesh_const_StringR_const_Size2fR_const_SizeR(name.opencv_as_extern(), &size, &segments, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -164,7 +164,7 @@ pub fn create_plane_mesh(name: &str, size: core::Size2f, image: &dyn core::ToInp
 	extern_container_arg!(name);
 	input_array_arg!(image);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ovis_createPlaneMesh_const_StringR_const_Size2fR_const__InputArrayR(name.opencv_as_extern(), &size, image.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ovis_createPlaneMesh_const_StringR_const_Size2fR_const__InputArrayR(name.opencv_as_extern(), &size, image.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -186,7 +186,7 @@ pub fn create_point_cloud_mesh(name: &str, vertices: &dyn core::ToInputArray, co
 	input_array_arg!(vertices);
 	input_array_arg!(colors);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ovis_createPointCloudMesh_const_StringR_const__InputArrayR_const__InputArrayR(name.opencv_as_extern(), vertices.as_raw__InputArray(), colors.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ovis_createPointCloudMesh_const_StringR_const__InputArrayR_const__InputArrayR(name.opencv_as_extern(), vertices.as_raw__InputArray(), colors.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -211,7 +211,7 @@ pub fn create_triangle_mesh(name: &str, vertices: &dyn core::ToInputArray, norma
 	input_array_arg!(normals);
 	input_array_arg!(indices);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ovis_createTriangleMesh_const_StringR_const__InputArrayR_const__InputArrayR_const__InputArrayR(name.opencv_as_extern(), vertices.as_raw__InputArray(), normals.as_raw__InputArray(), indices.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ovis_createTriangleMesh_const_StringR_const__InputArrayR_const__InputArrayR_const__InputArrayR(name.opencv_as_extern(), vertices.as_raw__InputArray(), normals.as_raw__InputArray(), indices.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -221,7 +221,7 @@ pub fn create_triangle_mesh(name: &str, vertices: &dyn core::ToInputArray, norma
 /// ## Parameters
 /// * title: window title
 /// * size: size of the window
-/// * flags: a combination of @ref SceneSettings
+/// * flags: a combination of `SceneSettings`
 /// 
 /// Furthermore, the behavior is controlled by the following environment variables
 /// - OPENCV_OVIS_VERBOSE_LOG: print all of OGRE log output
@@ -234,7 +234,7 @@ pub fn create_triangle_mesh(name: &str, vertices: &dyn core::ToInputArray, norma
 pub fn create_window(title: &str, size: core::Size, flags: i32) -> Result<core::Ptr<dyn crate::ovis::WindowScene>> {
 	extern_container_arg!(title);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ovis_createWindow_const_StringR_const_SizeR_int(title.opencv_as_extern(), &size, flags, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ovis_createWindow_const_StringR_const_SizeR_int(title.opencv_as_extern(), &size, flags, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::ovis::WindowScene>::opencv_from_extern(ret) };
@@ -251,7 +251,7 @@ pub fn set_material_property_2(name: &str, prop: &str, value: core::Scalar) -> R
 	extern_container_arg!(name);
 	extern_container_arg!(prop);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ovis_setMaterialProperty_const_StringR_const_StringR_const_ScalarR(name.opencv_as_extern(), prop.opencv_as_extern(), &value, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ovis_setMaterialProperty_const_StringR_const_StringR_const_ScalarR(name.opencv_as_extern(), prop.opencv_as_extern(), &value, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -260,13 +260,13 @@ pub fn set_material_property_2(name: &str, prop: &str, value: core::Scalar) -> R
 /// set the property of a material to the given value
 /// ## Parameters
 /// * name: material name
-/// * prop: @ref MaterialProperty
+/// * prop: `MaterialProperty`
 /// * value: the value
 #[inline]
 pub fn set_material_property(name: &str, prop: i32, value: core::Scalar) -> Result<()> {
 	extern_container_arg!(name);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ovis_setMaterialProperty_const_StringR_int_const_ScalarR(name.opencv_as_extern(), prop, &value, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ovis_setMaterialProperty_const_StringR_int_const_ScalarR(name.opencv_as_extern(), prop, &value, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -284,7 +284,7 @@ pub fn set_material_property_1(name: &str, prop: i32, value: &str) -> Result<()>
 	extern_container_arg!(name);
 	extern_container_arg!(value);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ovis_setMaterialProperty_const_StringR_int_const_StringR(name.opencv_as_extern(), prop, value.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ovis_setMaterialProperty_const_StringR_int_const_StringR(name.opencv_as_extern(), prop, value.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -293,14 +293,14 @@ pub fn set_material_property_1(name: &str, prop: i32, value: &str) -> Result<()>
 /// set the texture of a material to the given value
 /// ## Parameters
 /// * name: material name
-/// * prop: @ref MaterialProperty
+/// * prop: `MaterialProperty`
 /// * value: the texture data
 #[inline]
 pub fn set_material_texture(name: &str, prop: i32, value: &dyn core::ToInputArray) -> Result<()> {
 	extern_container_arg!(name);
 	input_array_arg!(value);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ovis_setMaterialProperty_const_StringR_int_const__InputArrayR(name.opencv_as_extern(), prop, value.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ovis_setMaterialProperty_const_StringR_int_const__InputArrayR(name.opencv_as_extern(), prop, value.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -314,7 +314,7 @@ pub fn update_texture(name: &str, image: &dyn core::ToInputArray) -> Result<()>
 	extern_container_arg!(name);
 	input_array_arg!(image);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ovis_updateTexture_const_StringR_const__InputArrayR(name.opencv_as_extern(), image.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ovis_updateTexture_const_StringR_const__InputArrayR(name.opencv_as_extern(), image.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -333,7 +333,7 @@ pub fn update_texture(name: &str, image: &dyn core::ToInputArray) -> Result<()>
 #[inline]
 pub fn wait_key(delay: i32) -> Result<i32> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ovis_waitKey_int(delay, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ovis_waitKey_int(delay, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -353,9 +353,10 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// * image: 
 	#[inline]
 	fn set_background(&mut self, image: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("WindowScene", self.as_raw_mut_WindowScene())?;
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ovis_WindowScene_setBackground_const__InputArrayR(self.as_raw_mut_WindowScene(), image.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ovis_WindowScene_setBackground_const__InputArrayR(self.as_raw_mut_WindowScene(), image.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -368,8 +369,9 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// ## Overloaded parameters
 	#[inline]
 	fn set_background_color(&mut self, color: core::Scalar) -> Result<()> {
+		crate::core::validity::check("WindowScene", self.as_raw_mut_WindowScene())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ovis_WindowScene_setBackground_const_ScalarR(self.as_raw_mut_WindowScene(), &color, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ovis_WindowScene_setBackground_const_ScalarR(self.as_raw_mut_WindowScene(), &color, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -380,13 +382,14 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// this way you can add distortion or SSAO effects.
 	/// The effects themselves must be defined inside Ogre .compositor scripts.
 	/// ## See also
-	/// addResourceLocation
+	/// [add_resource_location](crate::ovis::add_resource_location)
 	/// ## Parameters
 	/// * names: compositor names that will be applied in order of appearance
 	#[inline]
 	fn set_compositors(&mut self, names: &core::Vector<String>) -> Result<()> {
+		crate::core::validity::check("WindowScene", self.as_raw_mut_WindowScene())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ovis_WindowScene_setCompositors_const_vector_String_R(self.as_raw_mut_WindowScene(), names.as_raw_VectorOfString(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ovis_WindowScene_setCompositors_const_vector_String_R(self.as_raw_mut_WindowScene(), names.as_raw_VectorOfString(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -395,13 +398,13 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// place an entity of a mesh in the scene
 	/// 
 	/// the mesh needs to be created beforehand. Either programmatically
-	/// by e.g. @ref createPointCloudMesh or by placing an Ogre .mesh file in a resource location.
+	/// by e.g. [create_point_cloud_mesh](crate::ovis::create_point_cloud_mesh) or by placing an Ogre .mesh file in a resource location.
 	/// ## See also
-	/// addResourceLocation
+	/// [add_resource_location](crate::ovis::add_resource_location)
 	/// ## Parameters
 	/// * name: entity name
 	/// * meshname: mesh name
-	/// * rot: @ref Rodrigues vector or 3x3 rotation matrix
+	/// * rot: `Rodrigues` vector or 3x3 rotation matrix
 	/// * tvec: translation
 	/// 
 	/// ## C++ default parameters
@@ -409,12 +412,13 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// * rot: noArray()
 	#[inline]
 	fn create_entity(&mut self, name: &str, meshname: &str, tvec: &dyn core::ToInputArray, rot: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("WindowScene", self.as_raw_mut_WindowScene())?;
 		extern_container_arg!(name);
 		extern_container_arg!(meshname);
 		input_array_arg!(tvec);
 		input_array_arg!(rot);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ovis_WindowScene_createEntity_const_StringR_const_StringR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), meshname.opencv_as_extern(), tvec.as_raw__InputArray(), rot.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ovis_WindowScene_createEntity_const_StringR_const_StringR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), meshname.opencv_as_extern(), tvec.as_raw__InputArray(), rot.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -425,9 +429,10 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// * name: entity name
 	#[inline]
 	fn remove_entity(&mut self, name: &str) -> Result<()> {
+		crate::core::validity::check("WindowScene", self.as_raw_mut_WindowScene())?;
 		extern_container_arg!(name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ovis_WindowScene_removeEntity_const_StringR(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ovis_WindowScene_removeEntity_const_StringR(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -436,13 +441,14 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// set the property of an entity to the given value
 	/// ## Parameters
 	/// * name: entity name
-	/// * prop: @ref EntityProperty
+	/// * prop: `EntityProperty`
 	/// * value: the value
 	#[inline]
 	fn set_entity_property(&mut self, name: &str, prop: i32, value: core::Scalar) -> Result<()> {
+		crate::core::validity::check("WindowScene", self.as_raw_mut_WindowScene())?;
 		extern_container_arg!(name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ovis_WindowScene_setEntityProperty_const_StringR_int_const_ScalarR(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), prop, &value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ovis_WindowScene_setEntityProperty_const_StringR_int_const_ScalarR(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), prop, &value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -451,7 +457,7 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// set the property of an entity to the given value
 	/// ## Parameters
 	/// * name: entity name
-	/// * prop: @ref EntityProperty
+	/// * prop: `EntityProperty`
 	/// * value: the value
 	/// 
 	/// ## Overloaded parameters
@@ -460,10 +466,11 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// * sub_entity_idx: -1
 	#[inline]
 	fn set_entity_property_1(&mut self, name: &str, prop: i32, value: &str, sub_entity_idx: i32) -> Result<()> {
+		crate::core::validity::check("WindowScene", self.as_raw_mut_WindowScene())?;
 		extern_container_arg!(name);
 		extern_container_arg!(value);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ovis_WindowScene_setEntityProperty_const_StringR_int_const_StringR_int(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), prop, value.opencv_as_extern(), sub_entity_idx, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ovis_WindowScene_setEntityProperty_const_StringR_int_const_StringR_int(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), prop, value.opencv_as_extern(), sub_entity_idx, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -472,14 +479,15 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// get the property of an entity
 	/// ## Parameters
 	/// * name: entity name
-	/// * prop: @ref EntityProperty
+	/// * prop: `EntityProperty`
 	/// * value: the value
 	#[inline]
 	fn get_entity_property(&mut self, name: &str, prop: i32, value: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("WindowScene", self.as_raw_mut_WindowScene())?;
 		extern_container_arg!(name);
 		output_array_arg!(value);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ovis_WindowScene_getEntityProperty_const_StringR_int_const__OutputArrayR(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), prop, value.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ovis_WindowScene_getEntityProperty_const_StringR_int_const__OutputArrayR(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), prop, value.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -492,7 +500,7 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// * K: intrinsic matrix
 	/// * imsize: image size
 	/// * zFar: far plane in camera coordinates
-	/// * rot: @ref Rodrigues vector or 3x3 rotation matrix
+	/// * rot: `Rodrigues` vector or 3x3 rotation matrix
 	/// * tvec: translation
 	/// * color: line color
 	/// ## Returns
@@ -505,12 +513,13 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// * color: Scalar::all(1)
 	#[inline]
 	fn create_camera_entity(&mut self, name: &str, k: &dyn core::ToInputArray, imsize: core::Size, z_far: f32, tvec: &dyn core::ToInputArray, rot: &dyn core::ToInputArray, color: core::Scalar) -> Result<core::Rect2d> {
+		crate::core::validity::check("WindowScene", self.as_raw_mut_WindowScene())?;
 		extern_container_arg!(name);
 		input_array_arg!(k);
 		input_array_arg!(tvec);
 		input_array_arg!(rot);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ovis_WindowScene_createCameraEntity_const_StringR_const__InputArrayR_const_SizeR_float_const__InputArrayR_const__InputArrayR_const_ScalarR(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), k.as_raw__InputArray(), &imsize, z_far, tvec.as_raw__InputArray(), rot.as_raw__InputArray(), &color, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ovis_WindowScene_createCameraEntity_const_StringR_const__InputArrayR_const_SizeR_float_const__InputArrayR_const__InputArrayR_const_ScalarR(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), k.as_raw__InputArray(), &imsize, z_far, tvec.as_raw__InputArray(), rot.as_raw__InputArray(), &color, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -519,7 +528,7 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// creates a point light in the scene
 	/// ## Parameters
 	/// * name: entity name
-	/// * rot: @ref Rodrigues vector or 3x3 rotation matrix
+	/// * rot: `Rodrigues` vector or 3x3 rotation matrix
 	/// * tvec: translation
 	/// * diffuseColor: 
 	/// * specularColor: 
@@ -531,11 +540,12 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// * specular_color: Scalar::all(1)
 	#[inline]
 	fn create_light_entity(&mut self, name: &str, tvec: &dyn core::ToInputArray, rot: &dyn core::ToInputArray, diffuse_color: core::Scalar, specular_color: core::Scalar) -> Result<()> {
+		crate::core::validity::check("WindowScene", self.as_raw_mut_WindowScene())?;
 		extern_container_arg!(name);
 		input_array_arg!(tvec);
 		input_array_arg!(rot);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ovis_WindowScene_createLightEntity_const_StringR_const__InputArrayR_const__InputArrayR_const_ScalarR_const_ScalarR(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), tvec.as_raw__InputArray(), rot.as_raw__InputArray(), &diffuse_color, &specular_color, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ovis_WindowScene_createLightEntity_const_StringR_const__InputArrayR_const__InputArrayR_const_ScalarR_const_ScalarR(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), tvec.as_raw__InputArray(), rot.as_raw__InputArray(), &diffuse_color, &specular_color, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -544,7 +554,7 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// update entity pose by transformation in the parent coordinate space. (pre-rotation)
 	/// ## Parameters
 	/// * name: entity name
-	/// * rot: @ref Rodrigues vector or 3x3 rotation matrix
+	/// * rot: `Rodrigues` vector or 3x3 rotation matrix
 	/// * tvec: translation
 	/// 
 	/// ## C++ default parameters
@@ -552,11 +562,12 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// * rot: noArray()
 	#[inline]
 	fn update_entity_pose(&mut self, name: &str, tvec: &dyn core::ToInputArray, rot: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("WindowScene", self.as_raw_mut_WindowScene())?;
 		extern_container_arg!(name);
 		input_array_arg!(tvec);
 		input_array_arg!(rot);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ovis_WindowScene_updateEntityPose_const_StringR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), tvec.as_raw__InputArray(), rot.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ovis_WindowScene_updateEntityPose_const_StringR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), tvec.as_raw__InputArray(), rot.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -565,7 +576,7 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// set entity pose in the world coordinate space.
 	/// ## Parameters
 	/// * name: enitity name
-	/// * rot: @ref Rodrigues vector or 3x3 rotation matrix
+	/// * rot: `Rodrigues` vector or 3x3 rotation matrix
 	/// * tvec: translation
 	/// * invert: use the inverse of the given pose
 	/// 
@@ -575,11 +586,12 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// * invert: false
 	#[inline]
 	fn set_entity_pose(&mut self, name: &str, tvec: &dyn core::ToInputArray, rot: &dyn core::ToInputArray, invert: bool) -> Result<()> {
+		crate::core::validity::check("WindowScene", self.as_raw_mut_WindowScene())?;
 		extern_container_arg!(name);
 		input_array_arg!(tvec);
 		input_array_arg!(rot);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ovis_WindowScene_setEntityPose_const_StringR_const__InputArrayR_const__InputArrayR_bool(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), tvec.as_raw__InputArray(), rot.as_raw__InputArray(), invert, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ovis_WindowScene_setEntityPose_const_StringR_const__InputArrayR_const__InputArrayR_bool(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), tvec.as_raw__InputArray(), rot.as_raw__InputArray(), invert, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -598,11 +610,12 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// * invert: false
 	#[inline]
 	fn get_entity_pose(&mut self, name: &str, r: &mut dyn core::ToOutputArray, tvec: &mut dyn core::ToOutputArray, invert: bool) -> Result<()> {
+		crate::core::validity::check("WindowScene", self.as_raw_mut_WindowScene())?;
 		extern_container_arg!(name);
 		output_array_arg!(r);
 		output_array_arg!(tvec);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ovis_WindowScene_getEntityPose_const_StringR_const__OutputArrayR_const__OutputArrayR_bool(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), r.as_raw__OutputArray(), tvec.as_raw__OutputArray(), invert, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ovis_WindowScene_getEntityPose_const_StringR_const__OutputArrayR_const__OutputArrayR_bool(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), r.as_raw__OutputArray(), tvec.as_raw__OutputArray(), invert, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -614,9 +627,10 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// * out: the animation names
 	#[inline]
 	fn get_entity_animations(&mut self, name: &str, out: &mut core::Vector<String>) -> Result<()> {
+		crate::core::validity::check("WindowScene", self.as_raw_mut_WindowScene())?;
 		extern_container_arg!(name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ovis_WindowScene_getEntityAnimations_const_StringR_vector_String_R(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), out.as_raw_mut_VectorOfString(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ovis_WindowScene_getEntityAnimations_const_StringR_vector_String_R(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), out.as_raw_mut_VectorOfString(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -628,16 +642,17 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// * animname: animation name
 	/// * loop: enable or disable animation loop
 	/// ## See also
-	/// getEntityAnimations
+	/// `get_entity_animations`
 	/// 
 	/// ## C++ default parameters
 	/// * loop_: true
 	#[inline]
 	fn play_entity_animation(&mut self, name: &str, animname: &str, loop_: bool) -> Result<()> {
+		crate::core::validity::check("WindowScene", self.as_raw_mut_WindowScene())?;
 		extern_container_arg!(name);
 		extern_container_arg!(animname);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ovis_WindowScene_playEntityAnimation_const_StringR_const_StringR_bool(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), animname.opencv_as_extern(), loop_, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ovis_WindowScene_playEntityAnimation_const_StringR_const_StringR_bool(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), animname.opencv_as_extern(), loop_, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -649,21 +664,23 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// * animname: animation name
 	#[inline]
 	fn stop_entity_animation(&mut self, name: &str, animname: &str) -> Result<()> {
+		crate::core::validity::check("WindowScene", self.as_raw_mut_WindowScene())?;
 		extern_container_arg!(name);
 		extern_container_arg!(animname);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ovis_WindowScene_stopEntityAnimation_const_StringR_const_StringR(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), animname.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ovis_WindowScene_stopEntityAnimation_const_StringR_const_StringR(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), animname.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
-	/// read back the image generated by the last call to @ref waitKey
+	/// read back the image generated by the last call to [wait_key](crate::ovis::wait_key)
 	#[inline]
 	fn get_screenshot(&mut self, frame: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("WindowScene", self.as_raw_mut_WindowScene())?;
 		output_array_arg!(frame);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ovis_WindowScene_getScreenshot_const__OutputArrayR(self.as_raw_mut_WindowScene(), frame.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ovis_WindowScene_getScreenshot_const__OutputArrayR(self.as_raw_mut_WindowScene(), frame.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -680,11 +697,12 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// * mrt_index: 0
 	#[inline]
 	fn get_compositor_texture(&mut self, compname: &str, texname: &str, out: &mut dyn core::ToOutputArray, mrt_index: i32) -> Result<()> {
+		crate::core::validity::check("WindowScene", self.as_raw_mut_WindowScene())?;
 		extern_container_arg!(compname);
 		extern_container_arg!(texname);
 		output_array_arg!(out);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ovis_WindowScene_getCompositorTexture_const_StringR_const_StringR_const__OutputArrayR_int(self.as_raw_mut_WindowScene(), compname.opencv_as_extern(), texname.opencv_as_extern(), out.as_raw__OutputArray(), mrt_index, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ovis_WindowScene_getCompositorTexture_const_StringR_const_StringR_const__OutputArrayR_int(self.as_raw_mut_WindowScene(), compname.opencv_as_extern(), texname.opencv_as_extern(), out.as_raw__OutputArray(), mrt_index, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -695,9 +713,10 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// return the per pixel distance to the camera in world units
 	#[inline]
 	fn get_depth(&mut self, depth: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("WindowScene", self.as_raw_mut_WindowScene())?;
 		output_array_arg!(depth);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ovis_WindowScene_getDepth_const__OutputArrayR(self.as_raw_mut_WindowScene(), depth.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ovis_WindowScene_getDepth_const__OutputArrayR(self.as_raw_mut_WindowScene(), depth.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -714,9 +733,10 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// * up: noArray()
 	#[inline]
 	fn fix_camera_yaw_axis(&mut self, use_fixed: bool, up: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("WindowScene", self.as_raw_mut_WindowScene())?;
 		input_array_arg!(up);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ovis_WindowScene_fixCameraYawAxis_bool_const__InputArrayR(self.as_raw_mut_WindowScene(), use_fixed, up.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ovis_WindowScene_fixCameraYawAxis_bool_const__InputArrayR(self.as_raw_mut_WindowScene(), use_fixed, up.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -724,7 +744,7 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	
 	/// Sets the current camera pose
 	/// ## Parameters
-	/// * rot: @ref Rodrigues vector or 3x3 rotation matrix
+	/// * rot: `Rodrigues` vector or 3x3 rotation matrix
 	/// * tvec: translation
 	/// * invert: use the inverse of the given pose
 	/// 
@@ -734,10 +754,11 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// * invert: false
 	#[inline]
 	fn set_camera_pose(&mut self, tvec: &dyn core::ToInputArray, rot: &dyn core::ToInputArray, invert: bool) -> Result<()> {
+		crate::core::validity::check("WindowScene", self.as_raw_mut_WindowScene())?;
 		input_array_arg!(tvec);
 		input_array_arg!(rot);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ovis_WindowScene_setCameraPose_const__InputArrayR_const__InputArrayR_bool(self.as_raw_mut_WindowScene(), tvec.as_raw__InputArray(), rot.as_raw__InputArray(), invert, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ovis_WindowScene_setCameraPose_const__InputArrayR_const__InputArrayR_bool(self.as_raw_mut_WindowScene(), tvec.as_raw__InputArray(), rot.as_raw__InputArray(), invert, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -752,10 +773,11 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// * offset: noArray()
 	#[inline]
 	fn set_camera_look_at(&mut self, target: &str, offset: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("WindowScene", self.as_raw_mut_WindowScene())?;
 		extern_container_arg!(target);
 		input_array_arg!(offset);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ovis_WindowScene_setCameraLookAt_const_StringR_const__InputArrayR(self.as_raw_mut_WindowScene(), target.opencv_as_extern(), offset.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ovis_WindowScene_setCameraLookAt_const_StringR_const__InputArrayR(self.as_raw_mut_WindowScene(), target.opencv_as_extern(), offset.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -772,11 +794,12 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// * offset: noArray()
 	#[inline]
 	fn set_entity_look_at(&mut self, origin: &str, target: &str, offset: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("WindowScene", self.as_raw_mut_WindowScene())?;
 		extern_container_arg!(origin);
 		extern_container_arg!(target);
 		input_array_arg!(offset);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ovis_WindowScene_setEntityLookAt_const_StringR_const_StringR_const__InputArrayR(self.as_raw_mut_WindowScene(), origin.opencv_as_extern(), target.opencv_as_extern(), offset.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ovis_WindowScene_setEntityLookAt_const_StringR_const_StringR_const__InputArrayR(self.as_raw_mut_WindowScene(), origin.opencv_as_extern(), target.opencv_as_extern(), offset.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -794,10 +817,11 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// * invert: false
 	#[inline]
 	fn get_camera_pose(&mut self, r: &mut dyn core::ToOutputArray, tvec: &mut dyn core::ToOutputArray, invert: bool) -> Result<()> {
+		crate::core::validity::check("WindowScene", self.as_raw_mut_WindowScene())?;
 		output_array_arg!(r);
 		output_array_arg!(tvec);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ovis_WindowScene_getCameraPose_const__OutputArrayR_const__OutputArrayR_bool(self.as_raw_mut_WindowScene(), r.as_raw__OutputArray(), tvec.as_raw__OutputArray(), invert, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ovis_WindowScene_getCameraPose_const__OutputArrayR_const__OutputArrayR_bool(self.as_raw_mut_WindowScene(), r.as_raw__OutputArray(), tvec.as_raw__OutputArray(), invert, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -817,22 +841,327 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// * z_far: -1
 	#[inline]
 	fn set_camera_intrinsics(&mut self, k: &dyn core::ToInputArray, imsize: core::Size, z_near: f32, z_far: f32) -> Result<()> {
+		crate::core::validity::check("WindowScene", self.as_raw_mut_WindowScene())?;
 		input_array_arg!(k);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ovis_WindowScene_setCameraIntrinsics_const__InputArrayR_const_SizeR_float_float(self.as_raw_mut_WindowScene(), k.as_raw__InputArray(), &imsize, z_near, z_far, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ovis_WindowScene_setCameraIntrinsics_const__InputArrayR_const_SizeR_float_float(self.as_raw_mut_WindowScene(), k.as_raw__InputArray(), &imsize, z_near, z_far, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
-	/// render this window, but do not swap buffers. Automatically called by @ref ovis::waitKey
+	/// render this window, but do not swap buffers. Automatically called by ovis::waitKey
 	#[inline]
 	fn update(&mut self) -> Result<()> {
+		crate::core::validity::check("WindowScene", self.as_raw_mut_WindowScene())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ovis_WindowScene_update(self.as_raw_mut_WindowScene(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ovis_WindowScene_update(self.as_raw_mut_WindowScene(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 }
+
+/// Builder for `WindowScene::create_camera_entity()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct WindowScene_CreateCameraEntityBuilder<'a, T: ?Sized + WindowScene> {
+	instance: &'a mut T,
+	name: &'a str,
+	k: &'a dyn core::ToInputArray,
+	imsize: core::Size,
+	z_far: f32,
+	tvec: Option<&'a dyn core::ToInputArray>,
+	rot: Option<&'a dyn core::ToInputArray>,
+	color: core::Scalar,
+}
+
+impl<'a, T: ?Sized + WindowScene> WindowScene_CreateCameraEntityBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, name: &'a str, k: &'a dyn core::ToInputArray, imsize: core::Size, z_far: f32) -> Self {
+		Self { instance, name, k, imsize, z_far, tvec: None, rot: None, color: core::Scalar::all(1.) }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_tvec(mut self, tvec: &'a dyn core::ToInputArray) -> Self {
+		self.tvec = Some(tvec);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_rot(mut self, rot: &'a dyn core::ToInputArray) -> Self {
+		self.rot = Some(rot);
+		self
+	}
+	
+	/// C++ default: `Scalar::all(1)`
+	#[inline]
+	pub fn with_color(mut self, color: core::Scalar) -> Self {
+		self.color = color;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Rect2d> {
+		let tvec_no_array = core::no_array();
+		let tvec: &dyn core::ToInputArray = match self.tvec { Some(tvec) => tvec, None => &tvec_no_array };
+		let rot_no_array = core::no_array();
+		let rot: &dyn core::ToInputArray = match self.rot { Some(rot) => rot, None => &rot_no_array };
+		WindowScene::create_camera_entity(self.instance, self.name, self.k, self.imsize, self.z_far, tvec, rot, self.color)
+	}
+}
+
+/// Builder for `WindowScene::create_light_entity()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct WindowScene_CreateLightEntityBuilder<'a, T: ?Sized + WindowScene> {
+	instance: &'a mut T,
+	name: &'a str,
+	tvec: Option<&'a dyn core::ToInputArray>,
+	rot: Option<&'a dyn core::ToInputArray>,
+	diffuse_color: core::Scalar,
+	specular_color: core::Scalar,
+}
+
+impl<'a, T: ?Sized + WindowScene> WindowScene_CreateLightEntityBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, name: &'a str) -> Self {
+		Self { instance, name, tvec: None, rot: None, diffuse_color: core::Scalar::all(1.), specular_color: core::Scalar::all(1.) }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_tvec(mut self, tvec: &'a dyn core::ToInputArray) -> Self {
+		self.tvec = Some(tvec);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_rot(mut self, rot: &'a dyn core::ToInputArray) -> Self {
+		self.rot = Some(rot);
+		self
+	}
+	
+	/// C++ default: `Scalar::all(1)`
+	#[inline]
+	pub fn with_diffuse_color(mut self, diffuse_color: core::Scalar) -> Self {
+		self.diffuse_color = diffuse_color;
+		self
+	}
+	
+	/// C++ default: `Scalar::all(1)`
+	#[inline]
+	pub fn with_specular_color(mut self, specular_color: core::Scalar) -> Self {
+		self.specular_color = specular_color;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let tvec_no_array = core::no_array();
+		let tvec: &dyn core::ToInputArray = match self.tvec { Some(tvec) => tvec, None => &tvec_no_array };
+		let rot_no_array = core::no_array();
+		let rot: &dyn core::ToInputArray = match self.rot { Some(rot) => rot, None => &rot_no_array };
+		WindowScene::create_light_entity(self.instance, self.name, tvec, rot, self.diffuse_color, self.specular_color)
+	}
+}
+
+/// Builder for `WindowScene::set_entity_pose()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct WindowScene_SetEntityPoseBuilder<'a, T: ?Sized + WindowScene> {
+	instance: &'a mut T,
+	name: &'a str,
+	tvec: Option<&'a dyn core::ToInputArray>,
+	rot: Option<&'a dyn core::ToInputArray>,
+	invert: bool,
+}
+
+impl<'a, T: ?Sized + WindowScene> WindowScene_SetEntityPoseBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, name: &'a str) -> Self {
+		Self { instance, name, tvec: None, rot: None, invert: false }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_tvec(mut self, tvec: &'a dyn core::ToInputArray) -> Self {
+		self.tvec = Some(tvec);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_rot(mut self, rot: &'a dyn core::ToInputArray) -> Self {
+		self.rot = Some(rot);
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_invert(mut self, invert: bool) -> Self {
+		self.invert = invert;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let tvec_no_array = core::no_array();
+		let tvec: &dyn core::ToInputArray = match self.tvec { Some(tvec) => tvec, None => &tvec_no_array };
+		let rot_no_array = core::no_array();
+		let rot: &dyn core::ToInputArray = match self.rot { Some(rot) => rot, None => &rot_no_array };
+		WindowScene::set_entity_pose(self.instance, self.name, tvec, rot, self.invert)
+	}
+}
+
+/// Builder for `WindowScene::get_entity_pose()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct WindowScene_GetEntityPoseBuilder<'a, T: ?Sized + WindowScene> {
+	instance: &'a mut T,
+	name: &'a str,
+	r: Option<&'a mut dyn core::ToOutputArray>,
+	tvec: Option<&'a mut dyn core::ToOutputArray>,
+	invert: bool,
+}
+
+impl<'a, T: ?Sized + WindowScene> WindowScene_GetEntityPoseBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, name: &'a str) -> Self {
+		Self { instance, name, r: None, tvec: None, invert: false }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_r(mut self, r: &'a mut dyn core::ToOutputArray) -> Self {
+		self.r = Some(r);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_tvec(mut self, tvec: &'a mut dyn core::ToOutputArray) -> Self {
+		self.tvec = Some(tvec);
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_invert(mut self, invert: bool) -> Self {
+		self.invert = invert;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let mut r_no_array = core::no_array();
+		let r: &mut dyn core::ToOutputArray = match self.r { Some(r) => r, None => &mut r_no_array };
+		let mut tvec_no_array = core::no_array();
+		let tvec: &mut dyn core::ToOutputArray = match self.tvec { Some(tvec) => tvec, None => &mut tvec_no_array };
+		WindowScene::get_entity_pose(self.instance, self.name, r, tvec, self.invert)
+	}
+}
+
+/// Builder for `WindowScene::set_camera_pose()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct WindowScene_SetCameraPoseBuilder<'a, T: ?Sized + WindowScene> {
+	instance: &'a mut T,
+	tvec: Option<&'a dyn core::ToInputArray>,
+	rot: Option<&'a dyn core::ToInputArray>,
+	invert: bool,
+}
+
+impl<'a, T: ?Sized + WindowScene> WindowScene_SetCameraPoseBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T) -> Self {
+		Self { instance, tvec: None, rot: None, invert: false }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_tvec(mut self, tvec: &'a dyn core::ToInputArray) -> Self {
+		self.tvec = Some(tvec);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_rot(mut self, rot: &'a dyn core::ToInputArray) -> Self {
+		self.rot = Some(rot);
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_invert(mut self, invert: bool) -> Self {
+		self.invert = invert;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let tvec_no_array = core::no_array();
+		let tvec: &dyn core::ToInputArray = match self.tvec { Some(tvec) => tvec, None => &tvec_no_array };
+		let rot_no_array = core::no_array();
+		let rot: &dyn core::ToInputArray = match self.rot { Some(rot) => rot, None => &rot_no_array };
+		WindowScene::set_camera_pose(self.instance, tvec, rot, self.invert)
+	}
+}
+
+/// Builder for `WindowScene::get_camera_pose()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct WindowScene_GetCameraPoseBuilder<'a, T: ?Sized + WindowScene> {
+	instance: &'a mut T,
+	r: Option<&'a mut dyn core::ToOutputArray>,
+	tvec: Option<&'a mut dyn core::ToOutputArray>,
+	invert: bool,
+}
+
+impl<'a, T: ?Sized + WindowScene> WindowScene_GetCameraPoseBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T) -> Self {
+		Self { instance, r: None, tvec: None, invert: false }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_r(mut self, r: &'a mut dyn core::ToOutputArray) -> Self {
+		self.r = Some(r);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_tvec(mut self, tvec: &'a mut dyn core::ToOutputArray) -> Self {
+		self.tvec = Some(tvec);
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_invert(mut self, invert: bool) -> Self {
+		self.invert = invert;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let mut r_no_array = core::no_array();
+		let r: &mut dyn core::ToOutputArray = match self.r { Some(r) => r, None => &mut r_no_array };
+		let mut tvec_no_array = core::no_array();
+		let tvec: &mut dyn core::ToOutputArray = match self.tvec { Some(tvec) => tvec, None => &mut tvec_no_array };
+		WindowScene::get_camera_pose(self.instance, r, tvec, self.invert)
+	}
+}
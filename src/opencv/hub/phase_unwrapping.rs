@@ -13,7 +13,7 @@
 //! in synthetic aperture radar (SAR), field mapping in magnetic resonance imaging or as a way of finding
 //! corresponding pixels in structured light reconstruction with sinusoidal patterns.
 //! 
-//! Given a phase map, wrapped between [-pi; pi], phase unwrapping aims at finding the "true" phase map
+//! Given a phase map, wrapped between \[-pi; pi\], phase unwrapping aims at finding the "true" phase map
 //! by adding the right number of 2*pi to each pixel.
 //! 
 //! The problem is straightforward for perfect wrapped phase map, but real data are usually not noise-free.
@@ -51,9 +51,10 @@ pub trait HistogramPhaseUnwrapping: crate::phase_unwrapping::HistogramPhaseUnwra
 	/// * reliabilityMap: Image where the reliability map is stored.
 	#[inline]
 	fn get_inverse_reliability_map(&mut self, reliability_map: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("HistogramPhaseUnwrapping", self.as_raw_mut_HistogramPhaseUnwrapping())?;
 		output_array_arg!(reliability_map);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_phase_unwrapping_HistogramPhaseUnwrapping_getInverseReliabilityMap_const__OutputArrayR(self.as_raw_mut_HistogramPhaseUnwrapping(), reliability_map.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_phase_unwrapping_HistogramPhaseUnwrapping_getInverseReliabilityMap_const__OutputArrayR(self.as_raw_mut_HistogramPhaseUnwrapping(), reliability_map.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -72,7 +73,7 @@ impl dyn HistogramPhaseUnwrapping + '_ {
 	#[inline]
 	pub fn create(parameters: crate::phase_unwrapping::HistogramPhaseUnwrapping_Params) -> Result<core::Ptr<dyn crate::phase_unwrapping::HistogramPhaseUnwrapping>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_phase_unwrapping_HistogramPhaseUnwrapping_create_const_ParamsR(&parameters, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_phase_unwrapping_HistogramPhaseUnwrapping_create_const_ParamsR(&parameters, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::phase_unwrapping::HistogramPhaseUnwrapping>::opencv_from_extern(ret) };
@@ -104,7 +105,7 @@ impl HistogramPhaseUnwrapping_Params {
 	#[inline]
 	pub fn default() -> Result<crate::phase_unwrapping::HistogramPhaseUnwrapping_Params> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_phase_unwrapping_HistogramPhaseUnwrapping_Params_Params(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_phase_unwrapping_HistogramPhaseUnwrapping_Params_Params(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -132,11 +133,12 @@ pub trait PhaseUnwrapping: core::AlgorithmTrait + crate::phase_unwrapping::Phase
 	/// * shadow_mask: noArray()
 	#[inline]
 	fn unwrap_phase_map(&mut self, wrapped_phase_map: &dyn core::ToInputArray, unwrapped_phase_map: &mut dyn core::ToOutputArray, shadow_mask: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("PhaseUnwrapping", self.as_raw_mut_PhaseUnwrapping())?;
 		input_array_arg!(wrapped_phase_map);
 		output_array_arg!(unwrapped_phase_map);
 		input_array_arg!(shadow_mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_phase_unwrapping_PhaseUnwrapping_unwrapPhaseMap_const__InputArrayR_const__OutputArrayR_const__InputArrayR(self.as_raw_mut_PhaseUnwrapping(), wrapped_phase_map.as_raw__InputArray(), unwrapped_phase_map.as_raw__OutputArray(), shadow_mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_phase_unwrapping_PhaseUnwrapping_unwrapPhaseMap_const__InputArrayR_const__OutputArrayR_const__InputArrayR(self.as_raw_mut_PhaseUnwrapping(), wrapped_phase_map.as_raw__InputArray(), unwrapped_phase_map.as_raw__OutputArray(), shadow_mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
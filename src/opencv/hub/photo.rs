@@ -82,12 +82,58 @@ pub fn color_change(src: &dyn core::ToInputArray, mask: &dyn core::ToInputArray,
 	input_array_arg!(mask);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_colorChange_const__InputArrayR_const__InputArrayR_const__OutputArrayR_float_float_float(src.as_raw__InputArray(), mask.as_raw__InputArray(), dst.as_raw__OutputArray(), red_mul, green_mul, blue_mul, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_colorChange_const__InputArrayR_const__InputArrayR_const__OutputArrayR_float_float_float(src.as_raw__InputArray(), mask.as_raw__InputArray(), dst.as_raw__OutputArray(), red_mul, green_mul, blue_mul, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `color_change()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct ColorChangeBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	mask: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	red_mul: f32,
+	green_mul: f32,
+	blue_mul: f32,
+}
+
+impl<'a> ColorChangeBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, mask: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { src, mask, dst, red_mul: 1.0, green_mul: 1.0, blue_mul: 1.0 }
+	}
+	
+	/// C++ default: `1.0f`
+	#[inline]
+	pub fn with_red_mul(mut self, red_mul: f32) -> Self {
+		self.red_mul = red_mul;
+		self
+	}
+	
+	/// C++ default: `1.0f`
+	#[inline]
+	pub fn with_green_mul(mut self, green_mul: f32) -> Self {
+		self.green_mul = green_mul;
+		self
+	}
+	
+	/// C++ default: `1.0f`
+	#[inline]
+	pub fn with_blue_mul(mut self, blue_mul: f32) -> Self {
+		self.blue_mul = blue_mul;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		color_change(self.src, self.mask, self.dst, self.red_mul, self.green_mul, self.blue_mul)
+	}
+}
+
 /// Creates AlignMTB object
 /// 
 /// ## Parameters
@@ -104,13 +150,63 @@ pub fn color_change(src: &dyn core::ToInputArray, mask: &dyn core::ToInputArray,
 #[inline]
 pub fn create_align_mtb(max_bits: i32, exclude_range: i32, cut: bool) -> Result<core::Ptr<dyn crate::photo::AlignMTB>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_createAlignMTB_int_int_bool(max_bits, exclude_range, cut, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_createAlignMTB_int_int_bool(max_bits, exclude_range, cut, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::photo::AlignMTB>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_align_mtb()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateAlignMtbBuilder {
+	max_bits: i32,
+	exclude_range: i32,
+	cut: bool,
+}
+
+impl CreateAlignMtbBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { max_bits: 6, exclude_range: 4, cut: true }
+	}
+	
+	/// C++ default: `6`
+	#[inline]
+	pub fn with_max_bits(mut self, max_bits: i32) -> Self {
+		self.max_bits = max_bits;
+		self
+	}
+	
+	/// C++ default: `4`
+	#[inline]
+	pub fn with_exclude_range(mut self, exclude_range: i32) -> Self {
+		self.exclude_range = exclude_range;
+		self
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_cut(mut self, cut: bool) -> Self {
+		self.cut = cut;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::photo::AlignMTB>> {
+		create_align_mtb(self.max_bits, self.exclude_range, self.cut)
+	}
+}
+
+impl Default for CreateAlignMtbBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 /// Creates CalibrateDebevec object
 /// 
 /// ## Parameters
@@ -127,13 +223,63 @@ pub fn create_align_mtb(max_bits: i32, exclude_range: i32, cut: bool) -> Result<
 #[inline]
 pub fn create_calibrate_debevec(samples: i32, lambda: f32, random: bool) -> Result<core::Ptr<dyn crate::photo::CalibrateDebevec>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_createCalibrateDebevec_int_float_bool(samples, lambda, random, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_createCalibrateDebevec_int_float_bool(samples, lambda, random, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::photo::CalibrateDebevec>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_calibrate_debevec()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateCalibrateDebevecBuilder {
+	samples: i32,
+	lambda: f32,
+	random: bool,
+}
+
+impl CreateCalibrateDebevecBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { samples: 70, lambda: 10.0, random: false }
+	}
+	
+	/// C++ default: `70`
+	#[inline]
+	pub fn with_samples(mut self, samples: i32) -> Self {
+		self.samples = samples;
+		self
+	}
+	
+	/// C++ default: `10.0f`
+	#[inline]
+	pub fn with_lambda(mut self, lambda: f32) -> Self {
+		self.lambda = lambda;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_random(mut self, random: bool) -> Self {
+		self.random = random;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::photo::CalibrateDebevec>> {
+		create_calibrate_debevec(self.samples, self.lambda, self.random)
+	}
+}
+
+impl Default for CreateCalibrateDebevecBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 /// Creates CalibrateRobertson object
 /// 
 /// ## Parameters
@@ -146,7 +292,7 @@ pub fn create_calibrate_debevec(samples: i32, lambda: f32, random: bool) -> Resu
 #[inline]
 pub fn create_calibrate_robertson(max_iter: i32, threshold: f32) -> Result<core::Ptr<dyn crate::photo::CalibrateRobertson>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_createCalibrateRobertson_int_float(max_iter, threshold, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_createCalibrateRobertson_int_float(max_iter, threshold, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::photo::CalibrateRobertson>::opencv_from_extern(ret) };
@@ -157,7 +303,7 @@ pub fn create_calibrate_robertson(max_iter: i32, threshold: f32) -> Result<core:
 #[inline]
 pub fn create_merge_debevec() -> Result<core::Ptr<dyn crate::photo::MergeDebevec>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_createMergeDebevec(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_createMergeDebevec(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::photo::MergeDebevec>::opencv_from_extern(ret) };
@@ -178,18 +324,68 @@ pub fn create_merge_debevec() -> Result<core::Ptr<dyn crate::photo::MergeDebevec
 #[inline]
 pub fn create_merge_mertens(contrast_weight: f32, saturation_weight: f32, exposure_weight: f32) -> Result<core::Ptr<dyn crate::photo::MergeMertens>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_createMergeMertens_float_float_float(contrast_weight, saturation_weight, exposure_weight, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_createMergeMertens_float_float_float(contrast_weight, saturation_weight, exposure_weight, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::photo::MergeMertens>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_merge_mertens()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateMergeMertensBuilder {
+	contrast_weight: f32,
+	saturation_weight: f32,
+	exposure_weight: f32,
+}
+
+impl CreateMergeMertensBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { contrast_weight: 1.0, saturation_weight: 1.0, exposure_weight: 0.0 }
+	}
+	
+	/// C++ default: `1.0f`
+	#[inline]
+	pub fn with_contrast_weight(mut self, contrast_weight: f32) -> Self {
+		self.contrast_weight = contrast_weight;
+		self
+	}
+	
+	/// C++ default: `1.0f`
+	#[inline]
+	pub fn with_saturation_weight(mut self, saturation_weight: f32) -> Self {
+		self.saturation_weight = saturation_weight;
+		self
+	}
+	
+	/// C++ default: `0.0f`
+	#[inline]
+	pub fn with_exposure_weight(mut self, exposure_weight: f32) -> Self {
+		self.exposure_weight = exposure_weight;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::photo::MergeMertens>> {
+		create_merge_mertens(self.contrast_weight, self.saturation_weight, self.exposure_weight)
+	}
+}
+
+impl Default for CreateMergeMertensBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 /// Creates MergeRobertson object
 #[inline]
 pub fn create_merge_robertson() -> Result<core::Ptr<dyn crate::photo::MergeRobertson>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_createMergeRobertson(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_createMergeRobertson(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::photo::MergeRobertson>::opencv_from_extern(ret) };
@@ -202,7 +398,7 @@ pub fn create_merge_robertson() -> Result<core::Ptr<dyn crate::photo::MergeRober
 /// * gamma: gamma value for gamma correction. See createTonemap
 /// * saturation: positive saturation enhancement value. 1.0 preserves saturation, values greater
 /// than 1 increase saturation and values less than 1 decrease it.
-/// * bias: value for bias function in [0, 1] range. Values from 0.7 to 0.9 usually give best
+/// * bias: value for bias function in \[0, 1\] range. Values from 0.7 to 0.9 usually give best
 /// results, default value is 0.85.
 /// 
 /// ## C++ default parameters
@@ -212,13 +408,63 @@ pub fn create_merge_robertson() -> Result<core::Ptr<dyn crate::photo::MergeRober
 #[inline]
 pub fn create_tonemap_drago(gamma: f32, saturation: f32, bias: f32) -> Result<core::Ptr<dyn crate::photo::TonemapDrago>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_createTonemapDrago_float_float_float(gamma, saturation, bias, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_createTonemapDrago_float_float_float(gamma, saturation, bias, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::photo::TonemapDrago>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_tonemap_drago()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateTonemapDragoBuilder {
+	gamma: f32,
+	saturation: f32,
+	bias: f32,
+}
+
+impl CreateTonemapDragoBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { gamma: 1.0, saturation: 1.0, bias: 0.85 }
+	}
+	
+	/// C++ default: `1.0f`
+	#[inline]
+	pub fn with_gamma(mut self, gamma: f32) -> Self {
+		self.gamma = gamma;
+		self
+	}
+	
+	/// C++ default: `1.0f`
+	#[inline]
+	pub fn with_saturation(mut self, saturation: f32) -> Self {
+		self.saturation = saturation;
+		self
+	}
+	
+	/// C++ default: `0.85f`
+	#[inline]
+	pub fn with_bias(mut self, bias: f32) -> Self {
+		self.bias = bias;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::photo::TonemapDrago>> {
+		create_tonemap_drago(self.gamma, self.saturation, self.bias)
+	}
+}
+
+impl Default for CreateTonemapDragoBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 /// Creates TonemapMantiuk object
 /// 
 /// ## Parameters
@@ -234,21 +480,71 @@ pub fn create_tonemap_drago(gamma: f32, saturation: f32, bias: f32) -> Result<co
 #[inline]
 pub fn create_tonemap_mantiuk(gamma: f32, scale: f32, saturation: f32) -> Result<core::Ptr<dyn crate::photo::TonemapMantiuk>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_createTonemapMantiuk_float_float_float(gamma, scale, saturation, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_createTonemapMantiuk_float_float_float(gamma, scale, saturation, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::photo::TonemapMantiuk>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_tonemap_mantiuk()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateTonemapMantiukBuilder {
+	gamma: f32,
+	scale: f32,
+	saturation: f32,
+}
+
+impl CreateTonemapMantiukBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { gamma: 1.0, scale: 0.7, saturation: 1.0 }
+	}
+	
+	/// C++ default: `1.0f`
+	#[inline]
+	pub fn with_gamma(mut self, gamma: f32) -> Self {
+		self.gamma = gamma;
+		self
+	}
+	
+	/// C++ default: `0.7f`
+	#[inline]
+	pub fn with_scale(mut self, scale: f32) -> Self {
+		self.scale = scale;
+		self
+	}
+	
+	/// C++ default: `1.0f`
+	#[inline]
+	pub fn with_saturation(mut self, saturation: f32) -> Self {
+		self.saturation = saturation;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::photo::TonemapMantiuk>> {
+		create_tonemap_mantiuk(self.gamma, self.scale, self.saturation)
+	}
+}
+
+impl Default for CreateTonemapMantiukBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 /// Creates TonemapReinhard object
 /// 
 /// ## Parameters
 /// * gamma: gamma value for gamma correction. See createTonemap
-/// * intensity: result intensity in [-8, 8] range. Greater intensity produces brighter results.
-/// * light_adapt: light adaptation in [0, 1] range. If 1 adaptation is based only on pixel
+/// * intensity: result intensity in \[-8, 8\] range. Greater intensity produces brighter results.
+/// * light_adapt: light adaptation in \[0, 1\] range. If 1 adaptation is based only on pixel
 /// value, if 0 it's global, otherwise it's a weighted mean of this two cases.
-/// * color_adapt: chromatic adaptation in [0, 1] range. If 1 channels are treated independently,
+/// * color_adapt: chromatic adaptation in \[0, 1\] range. If 1 channels are treated independently,
 /// if 0 adaptation level is the same for each channel.
 /// 
 /// ## C++ default parameters
@@ -259,13 +555,71 @@ pub fn create_tonemap_mantiuk(gamma: f32, scale: f32, saturation: f32) -> Result
 #[inline]
 pub fn create_tonemap_reinhard(gamma: f32, intensity: f32, light_adapt: f32, color_adapt: f32) -> Result<core::Ptr<dyn crate::photo::TonemapReinhard>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_createTonemapReinhard_float_float_float_float(gamma, intensity, light_adapt, color_adapt, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_createTonemapReinhard_float_float_float_float(gamma, intensity, light_adapt, color_adapt, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::photo::TonemapReinhard>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_tonemap_reinhard()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateTonemapReinhardBuilder {
+	gamma: f32,
+	intensity: f32,
+	light_adapt: f32,
+	color_adapt: f32,
+}
+
+impl CreateTonemapReinhardBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { gamma: 1.0, intensity: 0.0, light_adapt: 1.0, color_adapt: 0.0 }
+	}
+	
+	/// C++ default: `1.0f`
+	#[inline]
+	pub fn with_gamma(mut self, gamma: f32) -> Self {
+		self.gamma = gamma;
+		self
+	}
+	
+	/// C++ default: `0.0f`
+	#[inline]
+	pub fn with_intensity(mut self, intensity: f32) -> Self {
+		self.intensity = intensity;
+		self
+	}
+	
+	/// C++ default: `1.0f`
+	#[inline]
+	pub fn with_light_adapt(mut self, light_adapt: f32) -> Self {
+		self.light_adapt = light_adapt;
+		self
+	}
+	
+	/// C++ default: `0.0f`
+	#[inline]
+	pub fn with_color_adapt(mut self, color_adapt: f32) -> Self {
+		self.color_adapt = color_adapt;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::photo::TonemapReinhard>> {
+		create_tonemap_reinhard(self.gamma, self.intensity, self.light_adapt, self.color_adapt)
+	}
+}
+
+impl Default for CreateTonemapReinhardBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 /// Creates simple linear mapper with gamma correction
 /// 
 /// ## Parameters
@@ -278,7 +632,7 @@ pub fn create_tonemap_reinhard(gamma: f32, intensity: f32, light_adapt: f32, col
 #[inline]
 pub fn create_tonemap(gamma: f32) -> Result<core::Ptr<dyn crate::photo::Tonemap>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_createTonemap_float(gamma, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_createTonemap_float(gamma, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::photo::Tonemap>::opencv_from_extern(ret) };
@@ -304,7 +658,7 @@ pub fn create_tonemap(gamma: f32) -> Result<core::Ptr<dyn crate::photo::Tonemap>
 /// The function converts image to CIELAB colorspace and then separately denoise L and AB components
 /// with given h parameters using FastNonLocalMeansDenoising::simpleMethod function.
 /// ## See also
-/// fastNlMeansDenoisingColored
+/// [fast_nl_means_denoising_colored](crate::photo::fast_nl_means_denoising_colored)
 /// 
 /// ## C++ default parameters
 /// * search_window: 21
@@ -315,12 +669,52 @@ pub fn fast_nl_means_denoising_colored_cuda(src: &dyn core::ToInputArray, dst: &
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_fastNlMeansDenoisingColored_const__InputArrayR_const__OutputArrayR_float_float_int_int_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), h_luminance, photo_render, search_window, block_size, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_fastNlMeansDenoisingColored_const__InputArrayR_const__OutputArrayR_float_float_int_int_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), h_luminance, photo_render, search_window, block_size, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `fast_nl_means_denoising_colored_cuda()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FastNlMeansDenoisingColoredCudaBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	h_luminance: f32,
+	photo_render: f32,
+	search_window: i32,
+	block_size: i32,
+	stream: &'a mut core::Stream,
+}
+
+impl<'a> FastNlMeansDenoisingColoredCudaBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, h_luminance: f32, photo_render: f32, stream: &'a mut core::Stream) -> Self {
+		Self { src, dst, h_luminance, photo_render, search_window: 21, block_size: 7, stream }
+	}
+	
+	/// C++ default: `21`
+	#[inline]
+	pub fn with_search_window(mut self, search_window: i32) -> Self {
+		self.search_window = search_window;
+		self
+	}
+	
+	/// C++ default: `7`
+	#[inline]
+	pub fn with_block_size(mut self, block_size: i32) -> Self {
+		self.block_size = block_size;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		fast_nl_means_denoising_colored_cuda(self.src, self.dst, self.h_luminance, self.photo_render, self.search_window, self.block_size, self.stream)
+	}
+}
+
 /// Perform image denoising using Non-local Means Denoising algorithm
 /// <http://www.ipol.im/pub/algo/bcm_non_local_means_denoising> with several computational
 /// optimizations. Noise expected to be a gaussian white noise
@@ -340,7 +734,7 @@ pub fn fast_nl_means_denoising_colored_cuda(src: &dyn core::ToInputArray, dst: &
 /// This function expected to be applied to grayscale images. For colored images look at
 /// FastNonLocalMeansDenoising::labMethod.
 /// ## See also
-/// fastNlMeansDenoising
+/// [fast_nl_means_denoising](crate::photo::fast_nl_means_denoising)
 /// 
 /// ## C++ default parameters
 /// * search_window: 21
@@ -351,12 +745,51 @@ pub fn fast_nl_means_denoising_cuda(src: &dyn core::ToInputArray, dst: &mut dyn
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_fastNlMeansDenoising_const__InputArrayR_const__OutputArrayR_float_int_int_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), h, search_window, block_size, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_fastNlMeansDenoising_const__InputArrayR_const__OutputArrayR_float_int_int_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), h, search_window, block_size, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `fast_nl_means_denoising_cuda()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FastNlMeansDenoisingCudaBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	h: f32,
+	search_window: i32,
+	block_size: i32,
+	stream: &'a mut core::Stream,
+}
+
+impl<'a> FastNlMeansDenoisingCudaBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, h: f32, stream: &'a mut core::Stream) -> Self {
+		Self { src, dst, h, search_window: 21, block_size: 7, stream }
+	}
+	
+	/// C++ default: `21`
+	#[inline]
+	pub fn with_search_window(mut self, search_window: i32) -> Self {
+		self.search_window = search_window;
+		self
+	}
+	
+	/// C++ default: `7`
+	#[inline]
+	pub fn with_block_size(mut self, block_size: i32) -> Self {
+		self.block_size = block_size;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		fast_nl_means_denoising_cuda(self.src, self.dst, self.h, self.search_window, self.block_size, self.stream)
+	}
+}
+
 /// Performs pure non local means denoising without any simplification, and thus it is not fast.
 /// 
 /// ## Parameters
@@ -369,7 +802,7 @@ pub fn fast_nl_means_denoising_cuda(src: &dyn core::ToInputArray, dst: &mut dyn
 /// BORDER_REPLICATE , BORDER_CONSTANT , BORDER_REFLECT and BORDER_WRAP are supported for now.
 /// * stream: Stream for the asynchronous version.
 /// ## See also
-/// fastNlMeansDenoising
+/// [fast_nl_means_denoising](crate::photo::fast_nl_means_denoising)
 /// 
 /// ## C++ default parameters
 /// * search_window: 21
@@ -381,12 +814,52 @@ pub fn non_local_means(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutpu
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_cuda_nonLocalMeans_const__InputArrayR_const__OutputArrayR_float_int_int_int_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), h, search_window, block_size, border_mode, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_cuda_nonLocalMeans_const__InputArrayR_const__OutputArrayR_float_int_int_int_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), h, search_window, block_size, border_mode, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `non_local_means()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct NonLocalMeansBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	h: f32,
+	search_window: i32,
+	block_size: i32,
+	border_mode: i32,
+	stream: &'a mut core::Stream,
+}
+
+impl<'a> NonLocalMeansBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, h: f32, border_mode: i32, stream: &'a mut core::Stream) -> Self {
+		Self { src, dst, h, search_window: 21, block_size: 7, border_mode, stream }
+	}
+	
+	/// C++ default: `21`
+	#[inline]
+	pub fn with_search_window(mut self, search_window: i32) -> Self {
+		self.search_window = search_window;
+		self
+	}
+	
+	/// C++ default: `7`
+	#[inline]
+	pub fn with_block_size(mut self, block_size: i32) -> Self {
+		self.block_size = block_size;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		non_local_means(self.src, self.dst, self.h, self.search_window, self.block_size, self.border_mode, self.stream)
+	}
+}
+
 /// Transforms a color image to a grayscale image. It is a basic tool in digital printing, stylized
 /// black-and-white photograph rendering, and in many single channel image processing applications
 /// [CL12](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_CL12) .
@@ -403,7 +876,7 @@ pub fn decolor(src: &dyn core::ToInputArray, grayscale: &mut dyn core::ToOutputA
 	output_array_arg!(grayscale);
 	output_array_arg!(color_boost);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_decolor_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(src.as_raw__InputArray(), grayscale.as_raw__OutputArray(), color_boost.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_decolor_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(src.as_raw__InputArray(), grayscale.as_raw__OutputArray(), color_boost.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -453,7 +926,7 @@ pub fn decolor(src: &dyn core::ToInputArray, grayscale: &mut dyn core::ToOutputA
 #[inline]
 pub fn denoise_tvl1(observations: &core::Vector<core::Mat>, result: &mut core::Mat, lambda: f64, niters: i32) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_denoise_TVL1_const_vector_Mat_R_MatR_double_int(observations.as_raw_VectorOfMat(), result.as_raw_mut_Mat(), lambda, niters, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_denoise_TVL1_const_vector_Mat_R_MatR_double_int(observations.as_raw_VectorOfMat(), result.as_raw_mut_Mat(), lambda, niters, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -475,7 +948,7 @@ pub fn detail_enhance(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutput
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_detailEnhance_const__InputArrayR_const__OutputArrayR_float_float(src.as_raw__InputArray(), dst.as_raw__OutputArray(), sigma_s, sigma_r, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_detailEnhance_const__InputArrayR_const__OutputArrayR_float_float(src.as_raw__InputArray(), dst.as_raw__OutputArray(), sigma_s, sigma_r, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -500,12 +973,57 @@ pub fn edge_preserving_filter(src: &dyn core::ToInputArray, dst: &mut dyn core::
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_edgePreservingFilter_const__InputArrayR_const__OutputArrayR_int_float_float(src.as_raw__InputArray(), dst.as_raw__OutputArray(), flags, sigma_s, sigma_r, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_edgePreservingFilter_const__InputArrayR_const__OutputArrayR_int_float_float(src.as_raw__InputArray(), dst.as_raw__OutputArray(), flags, sigma_s, sigma_r, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `edge_preserving_filter()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct EdgePreservingFilterBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	flags: i32,
+	sigma_s: f32,
+	sigma_r: f32,
+}
+
+impl<'a> EdgePreservingFilterBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { src, dst, flags: 1, sigma_s: 60., sigma_r: 0.4 }
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_flags(mut self, flags: i32) -> Self {
+		self.flags = flags;
+		self
+	}
+	
+	/// C++ default: `60`
+	#[inline]
+	pub fn with_sigma_s(mut self, sigma_s: f32) -> Self {
+		self.sigma_s = sigma_s;
+		self
+	}
+	
+	/// C++ default: `0.4f`
+	#[inline]
+	pub fn with_sigma_r(mut self, sigma_r: f32) -> Self {
+		self.sigma_r = sigma_r;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		edge_preserving_filter(self.src, self.dst, self.flags, self.sigma_s, self.sigma_r)
+	}
+}
+
 /// Modification of fastNlMeansDenoisingMulti function for colored images sequences
 /// 
 /// ## Parameters
@@ -515,7 +1033,7 @@ pub fn edge_preserving_filter(src: &dyn core::ToInputArray, dst: &mut dyn core::
 /// * temporalWindowSize: Number of surrounding images to use for target image denoising. Should
 /// be odd. Images from imgToDenoiseIndex - temporalWindowSize / 2 to
 /// imgToDenoiseIndex - temporalWindowSize / 2 from srcImgs will be used to denoise
-/// srcImgs[imgToDenoiseIndex] image.
+/// srcImgs\[imgToDenoiseIndex\] image.
 /// * dst: Output image with the same size and type as srcImgs images.
 /// * templateWindowSize: Size in pixels of the template patch that is used to compute weights.
 /// Should be odd. Recommended value 7 pixels
@@ -540,12 +1058,67 @@ pub fn fast_nl_means_denoising_colored_multi(src_imgs: &dyn core::ToInputArray,
 	input_array_arg!(src_imgs);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_fastNlMeansDenoisingColoredMulti_const__InputArrayR_const__OutputArrayR_int_int_float_float_int_int(src_imgs.as_raw__InputArray(), dst.as_raw__OutputArray(), img_to_denoise_index, temporal_window_size, h, h_color, template_window_size, search_window_size, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_fastNlMeansDenoisingColoredMulti_const__InputArrayR_const__OutputArrayR_int_int_float_float_int_int(src_imgs.as_raw__InputArray(), dst.as_raw__OutputArray(), img_to_denoise_index, temporal_window_size, h, h_color, template_window_size, search_window_size, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `fast_nl_means_denoising_colored_multi()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FastNlMeansDenoisingColoredMultiBuilder<'a> {
+	src_imgs: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	img_to_denoise_index: i32,
+	temporal_window_size: i32,
+	h: f32,
+	h_color: f32,
+	template_window_size: i32,
+	search_window_size: i32,
+}
+
+impl<'a> FastNlMeansDenoisingColoredMultiBuilder<'a> {
+	#[inline]
+	pub fn new(src_imgs: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, img_to_denoise_index: i32, temporal_window_size: i32) -> Self {
+		Self { src_imgs, dst, img_to_denoise_index, temporal_window_size, h: 3., h_color: 3., template_window_size: 7, search_window_size: 21 }
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_h(mut self, h: f32) -> Self {
+		self.h = h;
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_h_color(mut self, h_color: f32) -> Self {
+		self.h_color = h_color;
+		self
+	}
+	
+	/// C++ default: `7`
+	#[inline]
+	pub fn with_template_window_size(mut self, template_window_size: i32) -> Self {
+		self.template_window_size = template_window_size;
+		self
+	}
+	
+	/// C++ default: `21`
+	#[inline]
+	pub fn with_search_window_size(mut self, search_window_size: i32) -> Self {
+		self.search_window_size = search_window_size;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		fast_nl_means_denoising_colored_multi(self.src_imgs, self.dst, self.img_to_denoise_index, self.temporal_window_size, self.h, self.h_color, self.template_window_size, self.search_window_size)
+	}
+}
+
 /// Modification of fastNlMeansDenoising function for colored images
 /// 
 /// ## Parameters
@@ -575,12 +1148,65 @@ pub fn fast_nl_means_denoising_colored(src: &dyn core::ToInputArray, dst: &mut d
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_fastNlMeansDenoisingColored_const__InputArrayR_const__OutputArrayR_float_float_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), h, h_color, template_window_size, search_window_size, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_fastNlMeansDenoisingColored_const__InputArrayR_const__OutputArrayR_float_float_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), h, h_color, template_window_size, search_window_size, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `fast_nl_means_denoising_colored()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FastNlMeansDenoisingColoredBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	h: f32,
+	h_color: f32,
+	template_window_size: i32,
+	search_window_size: i32,
+}
+
+impl<'a> FastNlMeansDenoisingColoredBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { src, dst, h: 3., h_color: 3., template_window_size: 7, search_window_size: 21 }
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_h(mut self, h: f32) -> Self {
+		self.h = h;
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_h_color(mut self, h_color: f32) -> Self {
+		self.h_color = h_color;
+		self
+	}
+	
+	/// C++ default: `7`
+	#[inline]
+	pub fn with_template_window_size(mut self, template_window_size: i32) -> Self {
+		self.template_window_size = template_window_size;
+		self
+	}
+	
+	/// C++ default: `21`
+	#[inline]
+	pub fn with_search_window_size(mut self, search_window_size: i32) -> Self {
+		self.search_window_size = search_window_size;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		fast_nl_means_denoising_colored(self.src, self.dst, self.h, self.h_color, self.template_window_size, self.search_window_size)
+	}
+}
+
 /// Modification of fastNlMeansDenoising function for images sequence where consecutive images have been
 /// captured in small period of time. For example video. This version of the function is for grayscale
 /// images or for manual manipulation with colorspaces. For more details see
@@ -594,7 +1220,7 @@ pub fn fast_nl_means_denoising_colored(src: &dyn core::ToInputArray, dst: &mut d
 /// * temporalWindowSize: Number of surrounding images to use for target image denoising. Should
 /// be odd. Images from imgToDenoiseIndex - temporalWindowSize / 2 to
 /// imgToDenoiseIndex - temporalWindowSize / 2 from srcImgs will be used to denoise
-/// srcImgs[imgToDenoiseIndex] image.
+/// srcImgs\[imgToDenoiseIndex\] image.
 /// * dst: Output image with the same size and type as srcImgs images.
 /// * templateWindowSize: Size in pixels of the template patch that is used to compute weights.
 /// Should be odd. Recommended value 7 pixels
@@ -616,12 +1242,53 @@ pub fn fast_nl_means_denoising_multi_vec(src_imgs: &dyn core::ToInputArray, dst:
 	input_array_arg!(src_imgs);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_fastNlMeansDenoisingMulti_const__InputArrayR_const__OutputArrayR_int_int_const_vector_float_R_int_int_int(src_imgs.as_raw__InputArray(), dst.as_raw__OutputArray(), img_to_denoise_index, temporal_window_size, h.as_raw_VectorOff32(), template_window_size, search_window_size, norm_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_fastNlMeansDenoisingMulti_const__InputArrayR_const__OutputArrayR_int_int_const_vector_float_R_int_int_int(src_imgs.as_raw__InputArray(), dst.as_raw__OutputArray(), img_to_denoise_index, temporal_window_size, h.as_raw_VectorOff32(), template_window_size, search_window_size, norm_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `fast_nl_means_denoising_multi_vec()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FastNlMeansDenoisingMultiVecBuilder<'a> {
+	src_imgs: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	img_to_denoise_index: i32,
+	temporal_window_size: i32,
+	h: &'a core::Vector<f32>,
+	template_window_size: i32,
+	search_window_size: i32,
+	norm_type: i32,
+}
+
+impl<'a> FastNlMeansDenoisingMultiVecBuilder<'a> {
+	#[inline]
+	pub fn new(src_imgs: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, img_to_denoise_index: i32, temporal_window_size: i32, h: &'a core::Vector<f32>, norm_type: i32) -> Self {
+		Self { src_imgs, dst, img_to_denoise_index, temporal_window_size, h, template_window_size: 7, search_window_size: 21, norm_type }
+	}
+	
+	/// C++ default: `7`
+	#[inline]
+	pub fn with_template_window_size(mut self, template_window_size: i32) -> Self {
+		self.template_window_size = template_window_size;
+		self
+	}
+	
+	/// C++ default: `21`
+	#[inline]
+	pub fn with_search_window_size(mut self, search_window_size: i32) -> Self {
+		self.search_window_size = search_window_size;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		fast_nl_means_denoising_multi_vec(self.src_imgs, self.dst, self.img_to_denoise_index, self.temporal_window_size, self.h, self.template_window_size, self.search_window_size, self.norm_type)
+	}
+}
+
 /// Modification of fastNlMeansDenoising function for images sequence where consecutive images have been
 /// captured in small period of time. For example video. This version of the function is for grayscale
 /// images or for manual manipulation with colorspaces. For more details see
@@ -635,7 +1302,7 @@ pub fn fast_nl_means_denoising_multi_vec(src_imgs: &dyn core::ToInputArray, dst:
 /// * temporalWindowSize: Number of surrounding images to use for target image denoising. Should
 /// be odd. Images from imgToDenoiseIndex - temporalWindowSize / 2 to
 /// imgToDenoiseIndex - temporalWindowSize / 2 from srcImgs will be used to denoise
-/// srcImgs[imgToDenoiseIndex] image.
+/// srcImgs\[imgToDenoiseIndex\] image.
 /// * dst: Output image with the same size and type as srcImgs images.
 /// * templateWindowSize: Size in pixels of the template patch that is used to compute weights.
 /// Should be odd. Recommended value 7 pixels
@@ -655,12 +1322,59 @@ pub fn fast_nl_means_denoising_multi(src_imgs: &dyn core::ToInputArray, dst: &mu
 	input_array_arg!(src_imgs);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_fastNlMeansDenoisingMulti_const__InputArrayR_const__OutputArrayR_int_int_float_int_int(src_imgs.as_raw__InputArray(), dst.as_raw__OutputArray(), img_to_denoise_index, temporal_window_size, h, template_window_size, search_window_size, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_fastNlMeansDenoisingMulti_const__InputArrayR_const__OutputArrayR_int_int_float_int_int(src_imgs.as_raw__InputArray(), dst.as_raw__OutputArray(), img_to_denoise_index, temporal_window_size, h, template_window_size, search_window_size, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `fast_nl_means_denoising_multi()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FastNlMeansDenoisingMultiBuilder<'a> {
+	src_imgs: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	img_to_denoise_index: i32,
+	temporal_window_size: i32,
+	h: f32,
+	template_window_size: i32,
+	search_window_size: i32,
+}
+
+impl<'a> FastNlMeansDenoisingMultiBuilder<'a> {
+	#[inline]
+	pub fn new(src_imgs: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, img_to_denoise_index: i32, temporal_window_size: i32) -> Self {
+		Self { src_imgs, dst, img_to_denoise_index, temporal_window_size, h: 3., template_window_size: 7, search_window_size: 21 }
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_h(mut self, h: f32) -> Self {
+		self.h = h;
+		self
+	}
+	
+	/// C++ default: `7`
+	#[inline]
+	pub fn with_template_window_size(mut self, template_window_size: i32) -> Self {
+		self.template_window_size = template_window_size;
+		self
+	}
+	
+	/// C++ default: `21`
+	#[inline]
+	pub fn with_search_window_size(mut self, search_window_size: i32) -> Self {
+		self.search_window_size = search_window_size;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		fast_nl_means_denoising_multi(self.src_imgs, self.dst, self.img_to_denoise_index, self.temporal_window_size, self.h, self.template_window_size, self.search_window_size)
+	}
+}
+
 /// Perform image denoising using Non-local Means Denoising algorithm
 /// <http://www.ipol.im/pub/algo/bcm_non_local_means_denoising/> with several computational
 /// optimizations. Noise expected to be a gaussian white noise
@@ -695,12 +1409,51 @@ pub fn fast_nl_means_denoising_vec(src: &dyn core::ToInputArray, dst: &mut dyn c
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_fastNlMeansDenoising_const__InputArrayR_const__OutputArrayR_const_vector_float_R_int_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), h.as_raw_VectorOff32(), template_window_size, search_window_size, norm_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_fastNlMeansDenoising_const__InputArrayR_const__OutputArrayR_const_vector_float_R_int_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), h.as_raw_VectorOff32(), template_window_size, search_window_size, norm_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `fast_nl_means_denoising_vec()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FastNlMeansDenoisingVecBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	h: &'a core::Vector<f32>,
+	template_window_size: i32,
+	search_window_size: i32,
+	norm_type: i32,
+}
+
+impl<'a> FastNlMeansDenoisingVecBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, h: &'a core::Vector<f32>, norm_type: i32) -> Self {
+		Self { src, dst, h, template_window_size: 7, search_window_size: 21, norm_type }
+	}
+	
+	/// C++ default: `7`
+	#[inline]
+	pub fn with_template_window_size(mut self, template_window_size: i32) -> Self {
+		self.template_window_size = template_window_size;
+		self
+	}
+	
+	/// C++ default: `21`
+	#[inline]
+	pub fn with_search_window_size(mut self, search_window_size: i32) -> Self {
+		self.search_window_size = search_window_size;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		fast_nl_means_denoising_vec(self.src, self.dst, self.h, self.template_window_size, self.search_window_size, self.norm_type)
+	}
+}
+
 /// Perform image denoising using Non-local Means Denoising algorithm
 /// <http://www.ipol.im/pub/algo/bcm_non_local_means_denoising/> with several computational
 /// optimizations. Noise expected to be a gaussian white noise
@@ -731,12 +1484,57 @@ pub fn fast_nl_means_denoising(src: &dyn core::ToInputArray, dst: &mut dyn core:
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_fastNlMeansDenoising_const__InputArrayR_const__OutputArrayR_float_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), h, template_window_size, search_window_size, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_fastNlMeansDenoising_const__InputArrayR_const__OutputArrayR_float_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), h, template_window_size, search_window_size, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `fast_nl_means_denoising()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FastNlMeansDenoisingBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	h: f32,
+	template_window_size: i32,
+	search_window_size: i32,
+}
+
+impl<'a> FastNlMeansDenoisingBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { src, dst, h: 3., template_window_size: 7, search_window_size: 21 }
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_h(mut self, h: f32) -> Self {
+		self.h = h;
+		self
+	}
+	
+	/// C++ default: `7`
+	#[inline]
+	pub fn with_template_window_size(mut self, template_window_size: i32) -> Self {
+		self.template_window_size = template_window_size;
+		self
+	}
+	
+	/// C++ default: `21`
+	#[inline]
+	pub fn with_search_window_size(mut self, search_window_size: i32) -> Self {
+		self.search_window_size = search_window_size;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		fast_nl_means_denoising(self.src, self.dst, self.h, self.template_window_size, self.search_window_size)
+	}
+}
+
 /// Applying an appropriate non-linear transformation to the gradient field inside the selection and
 /// then integrating back with a Poisson solver, modifies locally the apparent illumination of an image.
 /// 
@@ -758,7 +1556,7 @@ pub fn illumination_change(src: &dyn core::ToInputArray, mask: &dyn core::ToInpu
 	input_array_arg!(mask);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_illuminationChange_const__InputArrayR_const__InputArrayR_const__OutputArrayR_float_float(src.as_raw__InputArray(), mask.as_raw__InputArray(), dst.as_raw__OutputArray(), alpha, beta, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_illuminationChange_const__InputArrayR_const__InputArrayR_const__OutputArrayR_float_float(src.as_raw__InputArray(), mask.as_raw__InputArray(), dst.as_raw__OutputArray(), alpha, beta, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -791,7 +1589,7 @@ pub fn inpaint(src: &dyn core::ToInputArray, inpaint_mask: &dyn core::ToInputArr
 	input_array_arg!(inpaint_mask);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_inpaint_const__InputArrayR_const__InputArrayR_const__OutputArrayR_double_int(src.as_raw__InputArray(), inpaint_mask.as_raw__InputArray(), dst.as_raw__OutputArray(), inpaint_radius, flags, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_inpaint_const__InputArrayR_const__InputArrayR_const__OutputArrayR_double_int(src.as_raw__InputArray(), inpaint_mask.as_raw__InputArray(), dst.as_raw__OutputArray(), inpaint_radius, flags, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -820,12 +1618,58 @@ pub fn pencil_sketch(src: &dyn core::ToInputArray, dst1: &mut dyn core::ToOutput
 	output_array_arg!(dst1);
 	output_array_arg!(dst2);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_pencilSketch_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_float_float_float(src.as_raw__InputArray(), dst1.as_raw__OutputArray(), dst2.as_raw__OutputArray(), sigma_s, sigma_r, shade_factor, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_pencilSketch_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_float_float_float(src.as_raw__InputArray(), dst1.as_raw__OutputArray(), dst2.as_raw__OutputArray(), sigma_s, sigma_r, shade_factor, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `pencil_sketch()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct PencilSketchBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst1: &'a mut dyn core::ToOutputArray,
+	dst2: &'a mut dyn core::ToOutputArray,
+	sigma_s: f32,
+	sigma_r: f32,
+	shade_factor: f32,
+}
+
+impl<'a> PencilSketchBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst1: &'a mut dyn core::ToOutputArray, dst2: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { src, dst1, dst2, sigma_s: 60., sigma_r: 0.07, shade_factor: 0.02 }
+	}
+	
+	/// C++ default: `60`
+	#[inline]
+	pub fn with_sigma_s(mut self, sigma_s: f32) -> Self {
+		self.sigma_s = sigma_s;
+		self
+	}
+	
+	/// C++ default: `0.07f`
+	#[inline]
+	pub fn with_sigma_r(mut self, sigma_r: f32) -> Self {
+		self.sigma_r = sigma_r;
+		self
+	}
+	
+	/// C++ default: `0.02f`
+	#[inline]
+	pub fn with_shade_factor(mut self, shade_factor: f32) -> Self {
+		self.shade_factor = shade_factor;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		pencil_sketch(self.src, self.dst1, self.dst2, self.sigma_s, self.sigma_r, self.shade_factor)
+	}
+}
+
 /// @example samples/cpp/tutorial_code/photo/seamless_cloning/cloning_demo.cpp
 /// An example using seamlessClone function
 /// 
@@ -849,7 +1693,7 @@ pub fn seamless_clone(src: &dyn core::ToInputArray, dst: &dyn core::ToInputArray
 	input_array_arg!(mask);
 	output_array_arg!(blend);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_seamlessClone_const__InputArrayR_const__InputArrayR_const__InputArrayR_Point_const__OutputArrayR_int(src.as_raw__InputArray(), dst.as_raw__InputArray(), mask.as_raw__InputArray(), p.opencv_as_extern(), blend.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_seamlessClone_const__InputArrayR_const__InputArrayR_const__InputArrayR_Point_const__OutputArrayR_int(src.as_raw__InputArray(), dst.as_raw__InputArray(), mask.as_raw__InputArray(), p.opencv_as_extern(), blend.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -873,7 +1717,7 @@ pub fn stylization(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArr
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_stylization_const__InputArrayR_const__OutputArrayR_float_float(src.as_raw__InputArray(), dst.as_raw__OutputArray(), sigma_s, sigma_r, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_stylization_const__InputArrayR_const__OutputArrayR_float_float(src.as_raw__InputArray(), dst.as_raw__OutputArray(), sigma_s, sigma_r, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -906,12 +1750,58 @@ pub fn texture_flattening(src: &dyn core::ToInputArray, mask: &dyn core::ToInput
 	input_array_arg!(mask);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_textureFlattening_const__InputArrayR_const__InputArrayR_const__OutputArrayR_float_float_int(src.as_raw__InputArray(), mask.as_raw__InputArray(), dst.as_raw__OutputArray(), low_threshold, high_threshold, kernel_size, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_textureFlattening_const__InputArrayR_const__InputArrayR_const__OutputArrayR_float_float_int(src.as_raw__InputArray(), mask.as_raw__InputArray(), dst.as_raw__OutputArray(), low_threshold, high_threshold, kernel_size, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `texture_flattening()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct TextureFlatteningBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	mask: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	low_threshold: f32,
+	high_threshold: f32,
+	kernel_size: i32,
+}
+
+impl<'a> TextureFlatteningBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, mask: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { src, mask, dst, low_threshold: 30., high_threshold: 45., kernel_size: 3 }
+	}
+	
+	/// C++ default: `30`
+	#[inline]
+	pub fn with_low_threshold(mut self, low_threshold: f32) -> Self {
+		self.low_threshold = low_threshold;
+		self
+	}
+	
+	/// C++ default: `45`
+	#[inline]
+	pub fn with_high_threshold(mut self, high_threshold: f32) -> Self {
+		self.high_threshold = high_threshold;
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_kernel_size(mut self, kernel_size: i32) -> Self {
+		self.kernel_size = kernel_size;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		texture_flattening(self.src, self.mask, self.dst, self.low_threshold, self.high_threshold, self.kernel_size)
+	}
+}
+
 /// The base class for algorithms that align images of the same scene with different exposures
 pub trait AlignExposuresConst: core::AlgorithmTraitConst {
 	fn as_raw_AlignExposures(&self) -> *const c_void;
@@ -931,11 +1821,12 @@ pub trait AlignExposures: core::AlgorithmTrait + crate::photo::AlignExposuresCon
 	/// have the same number of channels as images.
 	#[inline]
 	fn process(&mut self, src: &dyn core::ToInputArray, dst: &mut core::Vector<core::Mat>, times: &dyn core::ToInputArray, response: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("AlignExposures", self.as_raw_mut_AlignExposures())?;
 		input_array_arg!(src);
 		input_array_arg!(times);
 		input_array_arg!(response);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AlignExposures_process_const__InputArrayR_vector_Mat_R_const__InputArrayR_const__InputArrayR(self.as_raw_mut_AlignExposures(), src.as_raw__InputArray(), dst.as_raw_mut_VectorOfMat(), times.as_raw__InputArray(), response.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AlignExposures_process_const__InputArrayR_vector_Mat_R_const__InputArrayR_const__InputArrayR(self.as_raw_mut_AlignExposures(), src.as_raw__InputArray(), dst.as_raw_mut_VectorOfMat(), times.as_raw__InputArray(), response.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -956,8 +1847,9 @@ pub trait AlignMTBConst: crate::photo::AlignExposuresConst {
 
 	#[inline]
 	fn get_max_bits(&self) -> Result<i32> {
+		crate::core::validity::check("AlignMTB", self.as_raw_AlignMTB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AlignMTB_getMaxBits_const(self.as_raw_AlignMTB(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AlignMTB_getMaxBits_const(self.as_raw_AlignMTB(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -965,8 +1857,9 @@ pub trait AlignMTBConst: crate::photo::AlignExposuresConst {
 	
 	#[inline]
 	fn get_exclude_range(&self) -> Result<i32> {
+		crate::core::validity::check("AlignMTB", self.as_raw_AlignMTB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AlignMTB_getExcludeRange_const(self.as_raw_AlignMTB(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AlignMTB_getExcludeRange_const(self.as_raw_AlignMTB(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -974,8 +1867,9 @@ pub trait AlignMTBConst: crate::photo::AlignExposuresConst {
 	
 	#[inline]
 	fn get_cut(&self) -> Result<bool> {
+		crate::core::validity::check("AlignMTB", self.as_raw_AlignMTB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AlignMTB_getCut_const(self.as_raw_AlignMTB(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AlignMTB_getCut_const(self.as_raw_AlignMTB(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -988,11 +1882,12 @@ pub trait AlignMTB: crate::photo::AlignExposures + crate::photo::AlignMTBConst {
 
 	#[inline]
 	fn process_with_response(&mut self, src: &dyn core::ToInputArray, dst: &mut core::Vector<core::Mat>, times: &dyn core::ToInputArray, response: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("AlignMTB", self.as_raw_mut_AlignMTB())?;
 		input_array_arg!(src);
 		input_array_arg!(times);
 		input_array_arg!(response);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AlignMTB_process_const__InputArrayR_vector_Mat_R_const__InputArrayR_const__InputArrayR(self.as_raw_mut_AlignMTB(), src.as_raw__InputArray(), dst.as_raw_mut_VectorOfMat(), times.as_raw__InputArray(), response.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AlignMTB_process_const__InputArrayR_vector_Mat_R_const__InputArrayR_const__InputArrayR(self.as_raw_mut_AlignMTB(), src.as_raw__InputArray(), dst.as_raw_mut_VectorOfMat(), times.as_raw__InputArray(), response.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1005,9 +1900,10 @@ pub trait AlignMTB: crate::photo::AlignExposures + crate::photo::AlignMTBConst {
 	/// * dst: vector of aligned images
 	#[inline]
 	fn process(&mut self, src: &dyn core::ToInputArray, dst: &mut core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("AlignMTB", self.as_raw_mut_AlignMTB())?;
 		input_array_arg!(src);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AlignMTB_process_const__InputArrayR_vector_Mat_R(self.as_raw_mut_AlignMTB(), src.as_raw__InputArray(), dst.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AlignMTB_process_const__InputArrayR_vector_Mat_R(self.as_raw_mut_AlignMTB(), src.as_raw__InputArray(), dst.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1021,10 +1917,11 @@ pub trait AlignMTB: crate::photo::AlignExposures + crate::photo::AlignMTBConst {
 	/// * img1: second image
 	#[inline]
 	fn calculate_shift(&mut self, img0: &dyn core::ToInputArray, img1: &dyn core::ToInputArray) -> Result<core::Point> {
+		crate::core::validity::check("AlignMTB", self.as_raw_mut_AlignMTB())?;
 		input_array_arg!(img0);
 		input_array_arg!(img1);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AlignMTB_calculateShift_const__InputArrayR_const__InputArrayR(self.as_raw_mut_AlignMTB(), img0.as_raw__InputArray(), img1.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AlignMTB_calculateShift_const__InputArrayR_const__InputArrayR(self.as_raw_mut_AlignMTB(), img0.as_raw__InputArray(), img1.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1038,10 +1935,11 @@ pub trait AlignMTB: crate::photo::AlignExposures + crate::photo::AlignMTBConst {
 	/// * shift: shift value
 	#[inline]
 	fn shift_mat(&mut self, src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, shift: core::Point) -> Result<()> {
+		crate::core::validity::check("AlignMTB", self.as_raw_mut_AlignMTB())?;
 		input_array_arg!(src);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AlignMTB_shiftMat_const__InputArrayR_const__OutputArrayR_const_Point(self.as_raw_mut_AlignMTB(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), shift.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AlignMTB_shiftMat_const__InputArrayR_const__OutputArrayR_const_Point(self.as_raw_mut_AlignMTB(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), shift.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1055,11 +1953,12 @@ pub trait AlignMTB: crate::photo::AlignExposures + crate::photo::AlignMTBConst {
 	/// * eb: exclude bitmap
 	#[inline]
 	fn compute_bitmaps(&mut self, img: &dyn core::ToInputArray, tb: &mut dyn core::ToOutputArray, eb: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("AlignMTB", self.as_raw_mut_AlignMTB())?;
 		input_array_arg!(img);
 		output_array_arg!(tb);
 		output_array_arg!(eb);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AlignMTB_computeBitmaps_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_AlignMTB(), img.as_raw__InputArray(), tb.as_raw__OutputArray(), eb.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AlignMTB_computeBitmaps_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_AlignMTB(), img.as_raw__InputArray(), tb.as_raw__OutputArray(), eb.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1067,8 +1966,9 @@ pub trait AlignMTB: crate::photo::AlignExposures + crate::photo::AlignMTBConst {
 	
 	#[inline]
 	fn set_max_bits(&mut self, max_bits: i32) -> Result<()> {
+		crate::core::validity::check("AlignMTB", self.as_raw_mut_AlignMTB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AlignMTB_setMaxBits_int(self.as_raw_mut_AlignMTB(), max_bits, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AlignMTB_setMaxBits_int(self.as_raw_mut_AlignMTB(), max_bits, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1076,8 +1976,9 @@ pub trait AlignMTB: crate::photo::AlignExposures + crate::photo::AlignMTBConst {
 	
 	#[inline]
 	fn set_exclude_range(&mut self, exclude_range: i32) -> Result<()> {
+		crate::core::validity::check("AlignMTB", self.as_raw_mut_AlignMTB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AlignMTB_setExcludeRange_int(self.as_raw_mut_AlignMTB(), exclude_range, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AlignMTB_setExcludeRange_int(self.as_raw_mut_AlignMTB(), exclude_range, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1085,8 +1986,9 @@ pub trait AlignMTB: crate::photo::AlignExposures + crate::photo::AlignMTBConst {
 	
 	#[inline]
 	fn set_cut(&mut self, value: bool) -> Result<()> {
+		crate::core::validity::check("AlignMTB", self.as_raw_mut_AlignMTB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AlignMTB_setCut_bool(self.as_raw_mut_AlignMTB(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AlignMTB_setCut_bool(self.as_raw_mut_AlignMTB(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1111,11 +2013,12 @@ pub trait CalibrateCRF: core::AlgorithmTrait + crate::photo::CalibrateCRFConst {
 	/// * times: vector of exposure time values for each image
 	#[inline]
 	fn process(&mut self, src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, times: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("CalibrateCRF", self.as_raw_mut_CalibrateCRF())?;
 		input_array_arg!(src);
 		output_array_arg!(dst);
 		input_array_arg!(times);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CalibrateCRF_process_const__InputArrayR_const__OutputArrayR_const__InputArrayR(self.as_raw_mut_CalibrateCRF(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), times.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CalibrateCRF_process_const__InputArrayR_const__OutputArrayR_const__InputArrayR(self.as_raw_mut_CalibrateCRF(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), times.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1133,8 +2036,9 @@ pub trait CalibrateDebevecConst: crate::photo::CalibrateCRFConst {
 
 	#[inline]
 	fn get_lambda(&self) -> Result<f32> {
+		crate::core::validity::check("CalibrateDebevec", self.as_raw_CalibrateDebevec())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CalibrateDebevec_getLambda_const(self.as_raw_CalibrateDebevec(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CalibrateDebevec_getLambda_const(self.as_raw_CalibrateDebevec(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1142,8 +2046,9 @@ pub trait CalibrateDebevecConst: crate::photo::CalibrateCRFConst {
 	
 	#[inline]
 	fn get_samples(&self) -> Result<i32> {
+		crate::core::validity::check("CalibrateDebevec", self.as_raw_CalibrateDebevec())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CalibrateDebevec_getSamples_const(self.as_raw_CalibrateDebevec(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CalibrateDebevec_getSamples_const(self.as_raw_CalibrateDebevec(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1151,8 +2056,9 @@ pub trait CalibrateDebevecConst: crate::photo::CalibrateCRFConst {
 	
 	#[inline]
 	fn get_random(&self) -> Result<bool> {
+		crate::core::validity::check("CalibrateDebevec", self.as_raw_CalibrateDebevec())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CalibrateDebevec_getRandom_const(self.as_raw_CalibrateDebevec(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CalibrateDebevec_getRandom_const(self.as_raw_CalibrateDebevec(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1165,8 +2071,9 @@ pub trait CalibrateDebevec: crate::photo::CalibrateCRF + crate::photo::Calibrate
 
 	#[inline]
 	fn set_lambda(&mut self, lambda: f32) -> Result<()> {
+		crate::core::validity::check("CalibrateDebevec", self.as_raw_mut_CalibrateDebevec())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CalibrateDebevec_setLambda_float(self.as_raw_mut_CalibrateDebevec(), lambda, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CalibrateDebevec_setLambda_float(self.as_raw_mut_CalibrateDebevec(), lambda, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1174,8 +2081,9 @@ pub trait CalibrateDebevec: crate::photo::CalibrateCRF + crate::photo::Calibrate
 	
 	#[inline]
 	fn set_samples(&mut self, samples: i32) -> Result<()> {
+		crate::core::validity::check("CalibrateDebevec", self.as_raw_mut_CalibrateDebevec())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CalibrateDebevec_setSamples_int(self.as_raw_mut_CalibrateDebevec(), samples, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CalibrateDebevec_setSamples_int(self.as_raw_mut_CalibrateDebevec(), samples, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1183,8 +2091,9 @@ pub trait CalibrateDebevec: crate::photo::CalibrateCRF + crate::photo::Calibrate
 	
 	#[inline]
 	fn set_random(&mut self, random: bool) -> Result<()> {
+		crate::core::validity::check("CalibrateDebevec", self.as_raw_mut_CalibrateDebevec())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CalibrateDebevec_setRandom_bool(self.as_raw_mut_CalibrateDebevec(), random, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CalibrateDebevec_setRandom_bool(self.as_raw_mut_CalibrateDebevec(), random, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1201,8 +2110,9 @@ pub trait CalibrateRobertsonConst: crate::photo::CalibrateCRFConst {
 
 	#[inline]
 	fn get_max_iter(&self) -> Result<i32> {
+		crate::core::validity::check("CalibrateRobertson", self.as_raw_CalibrateRobertson())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CalibrateRobertson_getMaxIter_const(self.as_raw_CalibrateRobertson(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CalibrateRobertson_getMaxIter_const(self.as_raw_CalibrateRobertson(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1210,8 +2120,9 @@ pub trait CalibrateRobertsonConst: crate::photo::CalibrateCRFConst {
 	
 	#[inline]
 	fn get_threshold(&self) -> Result<f32> {
+		crate::core::validity::check("CalibrateRobertson", self.as_raw_CalibrateRobertson())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CalibrateRobertson_getThreshold_const(self.as_raw_CalibrateRobertson(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CalibrateRobertson_getThreshold_const(self.as_raw_CalibrateRobertson(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1219,8 +2130,9 @@ pub trait CalibrateRobertsonConst: crate::photo::CalibrateCRFConst {
 	
 	#[inline]
 	fn get_radiance(&self) -> Result<core::Mat> {
+		crate::core::validity::check("CalibrateRobertson", self.as_raw_CalibrateRobertson())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CalibrateRobertson_getRadiance_const(self.as_raw_CalibrateRobertson(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CalibrateRobertson_getRadiance_const(self.as_raw_CalibrateRobertson(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -1234,8 +2146,9 @@ pub trait CalibrateRobertson: crate::photo::CalibrateCRF + crate::photo::Calibra
 
 	#[inline]
 	fn set_max_iter(&mut self, max_iter: i32) -> Result<()> {
+		crate::core::validity::check("CalibrateRobertson", self.as_raw_mut_CalibrateRobertson())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CalibrateRobertson_setMaxIter_int(self.as_raw_mut_CalibrateRobertson(), max_iter, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CalibrateRobertson_setMaxIter_int(self.as_raw_mut_CalibrateRobertson(), max_iter, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1243,8 +2156,9 @@ pub trait CalibrateRobertson: crate::photo::CalibrateCRF + crate::photo::Calibra
 	
 	#[inline]
 	fn set_threshold(&mut self, threshold: f32) -> Result<()> {
+		crate::core::validity::check("CalibrateRobertson", self.as_raw_mut_CalibrateRobertson())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CalibrateRobertson_setThreshold_float(self.as_raw_mut_CalibrateRobertson(), threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CalibrateRobertson_setThreshold_float(self.as_raw_mut_CalibrateRobertson(), threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1266,12 +2180,13 @@ pub trait MergeDebevec: crate::photo::MergeDebevecConst + crate::photo::MergeExp
 
 	#[inline]
 	fn process_with_response(&mut self, src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, times: &dyn core::ToInputArray, response: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("MergeDebevec", self.as_raw_mut_MergeDebevec())?;
 		input_array_arg!(src);
 		output_array_arg!(dst);
 		input_array_arg!(times);
 		input_array_arg!(response);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_MergeDebevec_process_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_MergeDebevec(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), times.as_raw__InputArray(), response.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_MergeDebevec_process_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_MergeDebevec(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), times.as_raw__InputArray(), response.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1279,11 +2194,12 @@ pub trait MergeDebevec: crate::photo::MergeDebevecConst + crate::photo::MergeExp
 	
 	#[inline]
 	fn process(&mut self, src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, times: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("MergeDebevec", self.as_raw_mut_MergeDebevec())?;
 		input_array_arg!(src);
 		output_array_arg!(dst);
 		input_array_arg!(times);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_MergeDebevec_process_const__InputArrayR_const__OutputArrayR_const__InputArrayR(self.as_raw_mut_MergeDebevec(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), times.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_MergeDebevec_process_const__InputArrayR_const__OutputArrayR_const__InputArrayR(self.as_raw_mut_MergeDebevec(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), times.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1310,12 +2226,13 @@ pub trait MergeExposures: core::AlgorithmTrait + crate::photo::MergeExposuresCon
 	/// have the same number of channels as images.
 	#[inline]
 	fn process(&mut self, src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, times: &dyn core::ToInputArray, response: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("MergeExposures", self.as_raw_mut_MergeExposures())?;
 		input_array_arg!(src);
 		output_array_arg!(dst);
 		input_array_arg!(times);
 		input_array_arg!(response);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_MergeExposures_process_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_MergeExposures(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), times.as_raw__InputArray(), response.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_MergeExposures_process_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_MergeExposures(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), times.as_raw__InputArray(), response.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1338,8 +2255,9 @@ pub trait MergeMertensConst: crate::photo::MergeExposuresConst {
 
 	#[inline]
 	fn get_contrast_weight(&self) -> Result<f32> {
+		crate::core::validity::check("MergeMertens", self.as_raw_MergeMertens())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_MergeMertens_getContrastWeight_const(self.as_raw_MergeMertens(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_MergeMertens_getContrastWeight_const(self.as_raw_MergeMertens(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1347,8 +2265,9 @@ pub trait MergeMertensConst: crate::photo::MergeExposuresConst {
 	
 	#[inline]
 	fn get_saturation_weight(&self) -> Result<f32> {
+		crate::core::validity::check("MergeMertens", self.as_raw_MergeMertens())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_MergeMertens_getSaturationWeight_const(self.as_raw_MergeMertens(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_MergeMertens_getSaturationWeight_const(self.as_raw_MergeMertens(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1356,8 +2275,9 @@ pub trait MergeMertensConst: crate::photo::MergeExposuresConst {
 	
 	#[inline]
 	fn get_exposure_weight(&self) -> Result<f32> {
+		crate::core::validity::check("MergeMertens", self.as_raw_MergeMertens())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_MergeMertens_getExposureWeight_const(self.as_raw_MergeMertens(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_MergeMertens_getExposureWeight_const(self.as_raw_MergeMertens(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1370,12 +2290,13 @@ pub trait MergeMertens: crate::photo::MergeExposures + crate::photo::MergeMerten
 
 	#[inline]
 	fn process_with_response(&mut self, src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, times: &dyn core::ToInputArray, response: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("MergeMertens", self.as_raw_mut_MergeMertens())?;
 		input_array_arg!(src);
 		output_array_arg!(dst);
 		input_array_arg!(times);
 		input_array_arg!(response);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_MergeMertens_process_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_MergeMertens(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), times.as_raw__InputArray(), response.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_MergeMertens_process_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_MergeMertens(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), times.as_raw__InputArray(), response.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1388,10 +2309,11 @@ pub trait MergeMertens: crate::photo::MergeExposures + crate::photo::MergeMerten
 	/// * dst: result image
 	#[inline]
 	fn process(&mut self, src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("MergeMertens", self.as_raw_mut_MergeMertens())?;
 		input_array_arg!(src);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_MergeMertens_process_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_MergeMertens(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_MergeMertens_process_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_MergeMertens(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1399,8 +2321,9 @@ pub trait MergeMertens: crate::photo::MergeExposures + crate::photo::MergeMerten
 	
 	#[inline]
 	fn set_contrast_weight(&mut self, contrast_weiht: f32) -> Result<()> {
+		crate::core::validity::check("MergeMertens", self.as_raw_mut_MergeMertens())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_MergeMertens_setContrastWeight_float(self.as_raw_mut_MergeMertens(), contrast_weiht, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_MergeMertens_setContrastWeight_float(self.as_raw_mut_MergeMertens(), contrast_weiht, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1408,8 +2331,9 @@ pub trait MergeMertens: crate::photo::MergeExposures + crate::photo::MergeMerten
 	
 	#[inline]
 	fn set_saturation_weight(&mut self, saturation_weight: f32) -> Result<()> {
+		crate::core::validity::check("MergeMertens", self.as_raw_mut_MergeMertens())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_MergeMertens_setSaturationWeight_float(self.as_raw_mut_MergeMertens(), saturation_weight, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_MergeMertens_setSaturationWeight_float(self.as_raw_mut_MergeMertens(), saturation_weight, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1417,8 +2341,9 @@ pub trait MergeMertens: crate::photo::MergeExposures + crate::photo::MergeMerten
 	
 	#[inline]
 	fn set_exposure_weight(&mut self, exposure_weight: f32) -> Result<()> {
+		crate::core::validity::check("MergeMertens", self.as_raw_mut_MergeMertens())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_MergeMertens_setExposureWeight_float(self.as_raw_mut_MergeMertens(), exposure_weight, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_MergeMertens_setExposureWeight_float(self.as_raw_mut_MergeMertens(), exposure_weight, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1440,12 +2365,13 @@ pub trait MergeRobertson: crate::photo::MergeExposures + crate::photo::MergeRobe
 
 	#[inline]
 	fn process_with_response(&mut self, src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, times: &dyn core::ToInputArray, response: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("MergeRobertson", self.as_raw_mut_MergeRobertson())?;
 		input_array_arg!(src);
 		output_array_arg!(dst);
 		input_array_arg!(times);
 		input_array_arg!(response);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_MergeRobertson_process_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_MergeRobertson(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), times.as_raw__InputArray(), response.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_MergeRobertson_process_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_MergeRobertson(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), times.as_raw__InputArray(), response.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1453,11 +2379,12 @@ pub trait MergeRobertson: crate::photo::MergeExposures + crate::photo::MergeRobe
 	
 	#[inline]
 	fn process(&mut self, src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, times: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("MergeRobertson", self.as_raw_mut_MergeRobertson())?;
 		input_array_arg!(src);
 		output_array_arg!(dst);
 		input_array_arg!(times);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_MergeRobertson_process_const__InputArrayR_const__OutputArrayR_const__InputArrayR(self.as_raw_mut_MergeRobertson(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), times.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_MergeRobertson_process_const__InputArrayR_const__OutputArrayR_const__InputArrayR(self.as_raw_mut_MergeRobertson(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), times.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1471,8 +2398,9 @@ pub trait TonemapConst: core::AlgorithmTraitConst {
 
 	#[inline]
 	fn get_gamma(&self) -> Result<f32> {
+		crate::core::validity::check("Tonemap", self.as_raw_Tonemap())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Tonemap_getGamma_const(self.as_raw_Tonemap(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Tonemap_getGamma_const(self.as_raw_Tonemap(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1487,13 +2415,14 @@ pub trait Tonemap: core::AlgorithmTrait + crate::photo::TonemapConst {
 	/// 
 	/// ## Parameters
 	/// * src: source image - CV_32FC3 Mat (float 32 bits 3 channels)
-	/// * dst: destination image - CV_32FC3 Mat with values in [0, 1] range
+	/// * dst: destination image - CV_32FC3 Mat with values in \[0, 1\] range
 	#[inline]
 	fn process(&mut self, src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("Tonemap", self.as_raw_mut_Tonemap())?;
 		input_array_arg!(src);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Tonemap_process_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_Tonemap(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Tonemap_process_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_Tonemap(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1501,8 +2430,9 @@ pub trait Tonemap: core::AlgorithmTrait + crate::photo::TonemapConst {
 	
 	#[inline]
 	fn set_gamma(&mut self, gamma: f32) -> Result<()> {
+		crate::core::validity::check("Tonemap", self.as_raw_mut_Tonemap())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Tonemap_setGamma_float(self.as_raw_mut_Tonemap(), gamma, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Tonemap_setGamma_float(self.as_raw_mut_Tonemap(), gamma, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1524,8 +2454,9 @@ pub trait TonemapDragoConst: crate::photo::TonemapConst {
 
 	#[inline]
 	fn get_saturation(&self) -> Result<f32> {
+		crate::core::validity::check("TonemapDrago", self.as_raw_TonemapDrago())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_TonemapDrago_getSaturation_const(self.as_raw_TonemapDrago(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_TonemapDrago_getSaturation_const(self.as_raw_TonemapDrago(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1533,8 +2464,9 @@ pub trait TonemapDragoConst: crate::photo::TonemapConst {
 	
 	#[inline]
 	fn get_bias(&self) -> Result<f32> {
+		crate::core::validity::check("TonemapDrago", self.as_raw_TonemapDrago())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_TonemapDrago_getBias_const(self.as_raw_TonemapDrago(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_TonemapDrago_getBias_const(self.as_raw_TonemapDrago(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1547,8 +2479,9 @@ pub trait TonemapDrago: crate::photo::Tonemap + crate::photo::TonemapDragoConst
 
 	#[inline]
 	fn set_saturation(&mut self, saturation: f32) -> Result<()> {
+		crate::core::validity::check("TonemapDrago", self.as_raw_mut_TonemapDrago())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_TonemapDrago_setSaturation_float(self.as_raw_mut_TonemapDrago(), saturation, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_TonemapDrago_setSaturation_float(self.as_raw_mut_TonemapDrago(), saturation, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1556,8 +2489,9 @@ pub trait TonemapDrago: crate::photo::Tonemap + crate::photo::TonemapDragoConst
 	
 	#[inline]
 	fn set_bias(&mut self, bias: f32) -> Result<()> {
+		crate::core::validity::check("TonemapDrago", self.as_raw_mut_TonemapDrago())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_TonemapDrago_setBias_float(self.as_raw_mut_TonemapDrago(), bias, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_TonemapDrago_setBias_float(self.as_raw_mut_TonemapDrago(), bias, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1575,8 +2509,9 @@ pub trait TonemapMantiukConst: crate::photo::TonemapConst {
 
 	#[inline]
 	fn get_scale(&self) -> Result<f32> {
+		crate::core::validity::check("TonemapMantiuk", self.as_raw_TonemapMantiuk())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_TonemapMantiuk_getScale_const(self.as_raw_TonemapMantiuk(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_TonemapMantiuk_getScale_const(self.as_raw_TonemapMantiuk(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1584,8 +2519,9 @@ pub trait TonemapMantiukConst: crate::photo::TonemapConst {
 	
 	#[inline]
 	fn get_saturation(&self) -> Result<f32> {
+		crate::core::validity::check("TonemapMantiuk", self.as_raw_TonemapMantiuk())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_TonemapMantiuk_getSaturation_const(self.as_raw_TonemapMantiuk(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_TonemapMantiuk_getSaturation_const(self.as_raw_TonemapMantiuk(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1598,8 +2534,9 @@ pub trait TonemapMantiuk: crate::photo::Tonemap + crate::photo::TonemapMantiukCo
 
 	#[inline]
 	fn set_scale(&mut self, scale: f32) -> Result<()> {
+		crate::core::validity::check("TonemapMantiuk", self.as_raw_mut_TonemapMantiuk())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_TonemapMantiuk_setScale_float(self.as_raw_mut_TonemapMantiuk(), scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_TonemapMantiuk_setScale_float(self.as_raw_mut_TonemapMantiuk(), scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1607,8 +2544,9 @@ pub trait TonemapMantiuk: crate::photo::Tonemap + crate::photo::TonemapMantiukCo
 	
 	#[inline]
 	fn set_saturation(&mut self, saturation: f32) -> Result<()> {
+		crate::core::validity::check("TonemapMantiuk", self.as_raw_mut_TonemapMantiuk())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_TonemapMantiuk_setSaturation_float(self.as_raw_mut_TonemapMantiuk(), saturation, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_TonemapMantiuk_setSaturation_float(self.as_raw_mut_TonemapMantiuk(), saturation, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1627,8 +2565,9 @@ pub trait TonemapReinhardConst: crate::photo::TonemapConst {
 
 	#[inline]
 	fn get_intensity(&self) -> Result<f32> {
+		crate::core::validity::check("TonemapReinhard", self.as_raw_TonemapReinhard())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_TonemapReinhard_getIntensity_const(self.as_raw_TonemapReinhard(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_TonemapReinhard_getIntensity_const(self.as_raw_TonemapReinhard(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1636,8 +2575,9 @@ pub trait TonemapReinhardConst: crate::photo::TonemapConst {
 	
 	#[inline]
 	fn get_light_adaptation(&self) -> Result<f32> {
+		crate::core::validity::check("TonemapReinhard", self.as_raw_TonemapReinhard())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_TonemapReinhard_getLightAdaptation_const(self.as_raw_TonemapReinhard(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_TonemapReinhard_getLightAdaptation_const(self.as_raw_TonemapReinhard(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1645,8 +2585,9 @@ pub trait TonemapReinhardConst: crate::photo::TonemapConst {
 	
 	#[inline]
 	fn get_color_adaptation(&self) -> Result<f32> {
+		crate::core::validity::check("TonemapReinhard", self.as_raw_TonemapReinhard())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_TonemapReinhard_getColorAdaptation_const(self.as_raw_TonemapReinhard(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_TonemapReinhard_getColorAdaptation_const(self.as_raw_TonemapReinhard(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1659,8 +2600,9 @@ pub trait TonemapReinhard: crate::photo::Tonemap + crate::photo::TonemapReinhard
 
 	#[inline]
 	fn set_intensity(&mut self, intensity: f32) -> Result<()> {
+		crate::core::validity::check("TonemapReinhard", self.as_raw_mut_TonemapReinhard())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_TonemapReinhard_setIntensity_float(self.as_raw_mut_TonemapReinhard(), intensity, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_TonemapReinhard_setIntensity_float(self.as_raw_mut_TonemapReinhard(), intensity, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1668,8 +2610,9 @@ pub trait TonemapReinhard: crate::photo::Tonemap + crate::photo::TonemapReinhard
 	
 	#[inline]
 	fn set_light_adaptation(&mut self, light_adapt: f32) -> Result<()> {
+		crate::core::validity::check("TonemapReinhard", self.as_raw_mut_TonemapReinhard())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_TonemapReinhard_setLightAdaptation_float(self.as_raw_mut_TonemapReinhard(), light_adapt, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_TonemapReinhard_setLightAdaptation_float(self.as_raw_mut_TonemapReinhard(), light_adapt, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1677,8 +2620,9 @@ pub trait TonemapReinhard: crate::photo::Tonemap + crate::photo::TonemapReinhard
 	
 	#[inline]
 	fn set_color_adaptation(&mut self, color_adapt: f32) -> Result<()> {
+		crate::core::validity::check("TonemapReinhard", self.as_raw_mut_TonemapReinhard())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_TonemapReinhard_setColorAdaptation_float(self.as_raw_mut_TonemapReinhard(), color_adapt, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_TonemapReinhard_setColorAdaptation_float(self.as_raw_mut_TonemapReinhard(), color_adapt, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -23,8 +23,9 @@ pub trait Plot2d: core::AlgorithmTrait + crate::plot::Plot2dConst {
 
 	#[inline]
 	fn set_min_x(&mut self, _plot_min_x: f64) -> Result<()> {
+		crate::core::validity::check("Plot2d", self.as_raw_mut_Plot2d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_plot_Plot2d_setMinX_double(self.as_raw_mut_Plot2d(), _plot_min_x, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_plot_Plot2d_setMinX_double(self.as_raw_mut_Plot2d(), _plot_min_x, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -32,8 +33,9 @@ pub trait Plot2d: core::AlgorithmTrait + crate::plot::Plot2dConst {
 	
 	#[inline]
 	fn set_min_y(&mut self, _plot_min_y: f64) -> Result<()> {
+		crate::core::validity::check("Plot2d", self.as_raw_mut_Plot2d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_plot_Plot2d_setMinY_double(self.as_raw_mut_Plot2d(), _plot_min_y, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_plot_Plot2d_setMinY_double(self.as_raw_mut_Plot2d(), _plot_min_y, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -41,8 +43,9 @@ pub trait Plot2d: core::AlgorithmTrait + crate::plot::Plot2dConst {
 	
 	#[inline]
 	fn set_max_x(&mut self, _plot_max_x: f64) -> Result<()> {
+		crate::core::validity::check("Plot2d", self.as_raw_mut_Plot2d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_plot_Plot2d_setMaxX_double(self.as_raw_mut_Plot2d(), _plot_max_x, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_plot_Plot2d_setMaxX_double(self.as_raw_mut_Plot2d(), _plot_max_x, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -50,8 +53,9 @@ pub trait Plot2d: core::AlgorithmTrait + crate::plot::Plot2dConst {
 	
 	#[inline]
 	fn set_max_y(&mut self, _plot_max_y: f64) -> Result<()> {
+		crate::core::validity::check("Plot2d", self.as_raw_mut_Plot2d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_plot_Plot2d_setMaxY_double(self.as_raw_mut_Plot2d(), _plot_max_y, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_plot_Plot2d_setMaxY_double(self.as_raw_mut_Plot2d(), _plot_max_y, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -59,8 +63,9 @@ pub trait Plot2d: core::AlgorithmTrait + crate::plot::Plot2dConst {
 	
 	#[inline]
 	fn set_plot_line_width(&mut self, _plot_line_width: i32) -> Result<()> {
+		crate::core::validity::check("Plot2d", self.as_raw_mut_Plot2d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_plot_Plot2d_setPlotLineWidth_int(self.as_raw_mut_Plot2d(), _plot_line_width, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_plot_Plot2d_setPlotLineWidth_int(self.as_raw_mut_Plot2d(), _plot_line_width, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -73,8 +78,9 @@ pub trait Plot2d: core::AlgorithmTrait + crate::plot::Plot2dConst {
 	/// In other case data will be plotted as a set of standalone points.
 	#[inline]
 	fn set_need_plot_line(&mut self, _need_plot_line: bool) -> Result<()> {
+		crate::core::validity::check("Plot2d", self.as_raw_mut_Plot2d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_plot_Plot2d_setNeedPlotLine_bool(self.as_raw_mut_Plot2d(), _need_plot_line, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_plot_Plot2d_setNeedPlotLine_bool(self.as_raw_mut_Plot2d(), _need_plot_line, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -82,8 +88,9 @@ pub trait Plot2d: core::AlgorithmTrait + crate::plot::Plot2dConst {
 	
 	#[inline]
 	fn set_plot_line_color(&mut self, _plot_line_color: core::Scalar) -> Result<()> {
+		crate::core::validity::check("Plot2d", self.as_raw_mut_Plot2d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_plot_Plot2d_setPlotLineColor_Scalar(self.as_raw_mut_Plot2d(), _plot_line_color.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_plot_Plot2d_setPlotLineColor_Scalar(self.as_raw_mut_Plot2d(), _plot_line_color.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -91,8 +98,9 @@ pub trait Plot2d: core::AlgorithmTrait + crate::plot::Plot2dConst {
 	
 	#[inline]
 	fn set_plot_background_color(&mut self, _plot_background_color: core::Scalar) -> Result<()> {
+		crate::core::validity::check("Plot2d", self.as_raw_mut_Plot2d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_plot_Plot2d_setPlotBackgroundColor_Scalar(self.as_raw_mut_Plot2d(), _plot_background_color.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_plot_Plot2d_setPlotBackgroundColor_Scalar(self.as_raw_mut_Plot2d(), _plot_background_color.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -100,8 +108,9 @@ pub trait Plot2d: core::AlgorithmTrait + crate::plot::Plot2dConst {
 	
 	#[inline]
 	fn set_plot_axis_color(&mut self, _plot_axis_color: core::Scalar) -> Result<()> {
+		crate::core::validity::check("Plot2d", self.as_raw_mut_Plot2d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_plot_Plot2d_setPlotAxisColor_Scalar(self.as_raw_mut_Plot2d(), _plot_axis_color.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_plot_Plot2d_setPlotAxisColor_Scalar(self.as_raw_mut_Plot2d(), _plot_axis_color.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -109,8 +118,9 @@ pub trait Plot2d: core::AlgorithmTrait + crate::plot::Plot2dConst {
 	
 	#[inline]
 	fn set_plot_grid_color(&mut self, _plot_grid_color: core::Scalar) -> Result<()> {
+		crate::core::validity::check("Plot2d", self.as_raw_mut_Plot2d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_plot_Plot2d_setPlotGridColor_Scalar(self.as_raw_mut_Plot2d(), _plot_grid_color.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_plot_Plot2d_setPlotGridColor_Scalar(self.as_raw_mut_Plot2d(), _plot_grid_color.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -118,8 +128,9 @@ pub trait Plot2d: core::AlgorithmTrait + crate::plot::Plot2dConst {
 	
 	#[inline]
 	fn set_plot_text_color(&mut self, _plot_text_color: core::Scalar) -> Result<()> {
+		crate::core::validity::check("Plot2d", self.as_raw_mut_Plot2d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_plot_Plot2d_setPlotTextColor_Scalar(self.as_raw_mut_Plot2d(), _plot_text_color.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_plot_Plot2d_setPlotTextColor_Scalar(self.as_raw_mut_Plot2d(), _plot_text_color.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -127,8 +138,9 @@ pub trait Plot2d: core::AlgorithmTrait + crate::plot::Plot2dConst {
 	
 	#[inline]
 	fn set_plot_size(&mut self, _plot_size_width: i32, _plot_size_height: i32) -> Result<()> {
+		crate::core::validity::check("Plot2d", self.as_raw_mut_Plot2d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_plot_Plot2d_setPlotSize_int_int(self.as_raw_mut_Plot2d(), _plot_size_width, _plot_size_height, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_plot_Plot2d_setPlotSize_int_int(self.as_raw_mut_Plot2d(), _plot_size_width, _plot_size_height, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -136,8 +148,9 @@ pub trait Plot2d: core::AlgorithmTrait + crate::plot::Plot2dConst {
 	
 	#[inline]
 	fn set_show_grid(&mut self, need_show_grid: bool) -> Result<()> {
+		crate::core::validity::check("Plot2d", self.as_raw_mut_Plot2d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_plot_Plot2d_setShowGrid_bool(self.as_raw_mut_Plot2d(), need_show_grid, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_plot_Plot2d_setShowGrid_bool(self.as_raw_mut_Plot2d(), need_show_grid, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -145,8 +158,9 @@ pub trait Plot2d: core::AlgorithmTrait + crate::plot::Plot2dConst {
 	
 	#[inline]
 	fn set_show_text(&mut self, need_show_text: bool) -> Result<()> {
+		crate::core::validity::check("Plot2d", self.as_raw_mut_Plot2d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_plot_Plot2d_setShowText_bool(self.as_raw_mut_Plot2d(), need_show_text, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_plot_Plot2d_setShowText_bool(self.as_raw_mut_Plot2d(), need_show_text, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -154,8 +168,9 @@ pub trait Plot2d: core::AlgorithmTrait + crate::plot::Plot2dConst {
 	
 	#[inline]
 	fn set_grid_lines_number(&mut self, grid_lines_number: i32) -> Result<()> {
+		crate::core::validity::check("Plot2d", self.as_raw_mut_Plot2d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_plot_Plot2d_setGridLinesNumber_int(self.as_raw_mut_Plot2d(), grid_lines_number, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_plot_Plot2d_setGridLinesNumber_int(self.as_raw_mut_Plot2d(), grid_lines_number, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -163,8 +178,9 @@ pub trait Plot2d: core::AlgorithmTrait + crate::plot::Plot2dConst {
 	
 	#[inline]
 	fn set_invert_orientation(&mut self, _invert_orientation: bool) -> Result<()> {
+		crate::core::validity::check("Plot2d", self.as_raw_mut_Plot2d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_plot_Plot2d_setInvertOrientation_bool(self.as_raw_mut_Plot2d(), _invert_orientation, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_plot_Plot2d_setInvertOrientation_bool(self.as_raw_mut_Plot2d(), _invert_orientation, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -176,8 +192,9 @@ pub trait Plot2d: core::AlgorithmTrait + crate::plot::Plot2dConst {
 	/// * pointIdx: index of the required point in data array.
 	#[inline]
 	fn set_point_idx_to_print(&mut self, point_idx: i32) -> Result<()> {
+		crate::core::validity::check("Plot2d", self.as_raw_mut_Plot2d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_plot_Plot2d_setPointIdxToPrint_int(self.as_raw_mut_Plot2d(), point_idx, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_plot_Plot2d_setPointIdxToPrint_int(self.as_raw_mut_Plot2d(), point_idx, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -185,9 +202,10 @@ pub trait Plot2d: core::AlgorithmTrait + crate::plot::Plot2dConst {
 	
 	#[inline]
 	fn render(&mut self, _plot_result: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("Plot2d", self.as_raw_mut_Plot2d())?;
 		output_array_arg!(_plot_result);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_plot_Plot2d_render_const__OutputArrayR(self.as_raw_mut_Plot2d(), _plot_result.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_plot_Plot2d_render_const__OutputArrayR(self.as_raw_mut_Plot2d(), _plot_result.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -205,7 +223,7 @@ impl dyn Plot2d + '_ {
 	pub fn create(data: &dyn core::ToInputArray) -> Result<core::Ptr<dyn crate::plot::Plot2d>> {
 		input_array_arg!(data);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_plot_Plot2d_create_const__InputArrayR(data.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_plot_Plot2d_create_const__InputArrayR(data.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::plot::Plot2d>::opencv_from_extern(ret) };
@@ -222,7 +240,7 @@ impl dyn Plot2d + '_ {
 		input_array_arg!(data_x);
 		input_array_arg!(data_y);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_plot_Plot2d_create_const__InputArrayR_const__InputArrayR(data_x.as_raw__InputArray(), data_y.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_plot_Plot2d_create_const__InputArrayR_const__InputArrayR(data_x.as_raw__InputArray(), data_y.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::plot::Plot2d>::opencv_from_extern(ret) };
@@ -35,9 +35,10 @@ pub trait QualityBRISQUETrait: crate::quality::QualityBRISQUETraitConst + crate:
 	/// cv::Scalar with the score in the first element.  The score ranges from 0 (best quality) to 100 (worst quality)
 	#[inline]
 	fn compute(&mut self, img: &dyn core::ToInputArray) -> Result<core::Scalar> {
+		crate::core::validity::check("QualityBRISQUE", self.as_raw_mut_QualityBRISQUE())?;
 		input_array_arg!(img);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_quality_QualityBRISQUE_compute_const__InputArrayR(self.as_raw_mut_QualityBRISQUE(), img.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_quality_QualityBRISQUE_compute_const__InputArrayR(self.as_raw_mut_QualityBRISQUE(), img.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -62,7 +63,10 @@ opencv_type_boxed! { QualityBRISQUE }
 impl Drop for QualityBRISQUE {
 	fn drop(&mut self) {
 		extern "C" { fn cv_QualityBRISQUE_delete(instance: *mut c_void); }
-		unsafe { cv_QualityBRISQUE_delete(self.as_raw_mut_QualityBRISQUE()) };
+		let ptr = self.as_raw_mut_QualityBRISQUE();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_QualityBRISQUE_delete(ptr) };
 	}
 }
 
@@ -102,7 +106,7 @@ impl QualityBRISQUE {
 		extern_container_arg!(model_file_path);
 		extern_container_arg!(range_file_path);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_quality_QualityBRISQUE_create_const_StringR_const_StringR(model_file_path.opencv_as_extern(), range_file_path.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_quality_QualityBRISQUE_create_const_StringR_const_StringR(model_file_path.opencv_as_extern(), range_file_path.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::quality::QualityBRISQUE>::opencv_from_extern(ret) };
@@ -116,7 +120,7 @@ impl QualityBRISQUE {
 	#[inline]
 	pub fn create_1(model: &core::Ptr<dyn crate::ml::SVM>, range: &core::Mat) -> Result<core::Ptr<crate::quality::QualityBRISQUE>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_quality_QualityBRISQUE_create_const_Ptr_SVM_R_const_MatR(model.as_raw_PtrOfSVM(), range.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_quality_QualityBRISQUE_create_const_Ptr_SVM_R_const_MatR(model.as_raw_PtrOfSVM(), range.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::quality::QualityBRISQUE>::opencv_from_extern(ret) };
@@ -136,7 +140,7 @@ impl QualityBRISQUE {
 		extern_container_arg!(model_file_path);
 		extern_container_arg!(range_file_path);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_quality_QualityBRISQUE_compute_const__InputArrayR_const_StringR_const_StringR(img.as_raw__InputArray(), model_file_path.opencv_as_extern(), range_file_path.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_quality_QualityBRISQUE_compute_const__InputArrayR_const_StringR_const_StringR(img.as_raw__InputArray(), model_file_path.opencv_as_extern(), range_file_path.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -151,7 +155,7 @@ impl QualityBRISQUE {
 		input_array_arg!(img);
 		output_array_arg!(features);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_quality_QualityBRISQUE_computeFeatures_const__InputArrayR_const__OutputArrayR(img.as_raw__InputArray(), features.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_quality_QualityBRISQUE_computeFeatures_const__InputArrayR_const__OutputArrayR(img.as_raw__InputArray(), features.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -168,9 +172,10 @@ pub trait QualityBaseConst: core::AlgorithmTraitConst {
 	/// Returns output quality map that was generated during computation, if supported by the algorithm
 	#[inline]
 	fn get_quality_map(&self, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("QualityBase", self.as_raw_QualityBase())?;
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_quality_QualityBase_getQualityMap_const_const__OutputArrayR(self.as_raw_QualityBase(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_quality_QualityBase_getQualityMap_const_const__OutputArrayR(self.as_raw_QualityBase(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -179,8 +184,9 @@ pub trait QualityBaseConst: core::AlgorithmTraitConst {
 	/// Implements Algorithm::empty()
 	#[inline]
 	fn empty(&self) -> Result<bool> {
+		crate::core::validity::check("QualityBase", self.as_raw_QualityBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_quality_QualityBase_empty_const(self.as_raw_QualityBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_quality_QualityBase_empty_const(self.as_raw_QualityBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -196,9 +202,10 @@ pub trait QualityBase: core::AlgorithmTrait + crate::quality::QualityBaseConst {
 	/// * img: comparison image, or image to evalute for no-reference quality algorithms
 	#[inline]
 	fn compute(&mut self, img: &dyn core::ToInputArray) -> Result<core::Scalar> {
+		crate::core::validity::check("QualityBase", self.as_raw_mut_QualityBase())?;
 		input_array_arg!(img);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_quality_QualityBase_compute_const__InputArrayR(self.as_raw_mut_QualityBase(), img.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_quality_QualityBase_compute_const__InputArrayR(self.as_raw_mut_QualityBase(), img.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -207,8 +214,9 @@ pub trait QualityBase: core::AlgorithmTrait + crate::quality::QualityBaseConst {
 	/// Implements Algorithm::clear()
 	#[inline]
 	fn clear(&mut self) -> Result<()> {
+		crate::core::validity::check("QualityBase", self.as_raw_mut_QualityBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_quality_QualityBase_clear(self.as_raw_mut_QualityBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_quality_QualityBase_clear(self.as_raw_mut_QualityBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -224,8 +232,9 @@ pub trait QualityGMSDTraitConst: crate::quality::QualityBaseConst {
 	/// Implements Algorithm::empty()
 	#[inline]
 	fn empty(&self) -> Result<bool> {
+		crate::core::validity::check("QualityGMSD", self.as_raw_QualityGMSD())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_quality_QualityGMSD_empty_const(self.as_raw_QualityGMSD(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_quality_QualityGMSD_empty_const(self.as_raw_QualityGMSD(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -243,9 +252,10 @@ pub trait QualityGMSDTrait: crate::quality::QualityBase + crate::quality::Qualit
 	/// cv::Scalar with per-channel quality value.  Values range from 0 (worst) to 1 (best)
 	#[inline]
 	fn compute(&mut self, cmp: &dyn core::ToInputArray) -> Result<core::Scalar> {
+		crate::core::validity::check("QualityGMSD", self.as_raw_mut_QualityGMSD())?;
 		input_array_arg!(cmp);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_quality_QualityGMSD_compute_const__InputArrayR(self.as_raw_mut_QualityGMSD(), cmp.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_quality_QualityGMSD_compute_const__InputArrayR(self.as_raw_mut_QualityGMSD(), cmp.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -254,8 +264,9 @@ pub trait QualityGMSDTrait: crate::quality::QualityBase + crate::quality::Qualit
 	/// Implements Algorithm::clear()
 	#[inline]
 	fn clear(&mut self) -> Result<()> {
+		crate::core::validity::check("QualityGMSD", self.as_raw_mut_QualityGMSD())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_quality_QualityGMSD_clear(self.as_raw_mut_QualityGMSD(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_quality_QualityGMSD_clear(self.as_raw_mut_QualityGMSD(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -274,7 +285,10 @@ opencv_type_boxed! { QualityGMSD }
 impl Drop for QualityGMSD {
 	fn drop(&mut self) {
 		extern "C" { fn cv_QualityGMSD_delete(instance: *mut c_void); }
-		unsafe { cv_QualityGMSD_delete(self.as_raw_mut_QualityGMSD()) };
+		let ptr = self.as_raw_mut_QualityGMSD();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_QualityGMSD_delete(ptr) };
 	}
 }
 
@@ -312,7 +326,7 @@ impl QualityGMSD {
 	pub fn create(ref_: &dyn core::ToInputArray) -> Result<core::Ptr<crate::quality::QualityGMSD>> {
 		input_array_arg!(ref_);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_quality_QualityGMSD_create_const__InputArrayR(ref_.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_quality_QualityGMSD_create_const__InputArrayR(ref_.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::quality::QualityGMSD>::opencv_from_extern(ret) };
@@ -332,7 +346,7 @@ impl QualityGMSD {
 		input_array_arg!(cmp);
 		output_array_arg!(quality_map);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_quality_QualityGMSD_compute_const__InputArrayR_const__InputArrayR_const__OutputArrayR(ref_.as_raw__InputArray(), cmp.as_raw__InputArray(), quality_map.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_quality_QualityGMSD_compute_const__InputArrayR_const__InputArrayR_const__OutputArrayR(ref_.as_raw__InputArray(), cmp.as_raw__InputArray(), quality_map.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -349,8 +363,9 @@ pub trait QualityMSETraitConst: crate::quality::QualityBaseConst {
 	/// Implements Algorithm::empty()
 	#[inline]
 	fn empty(&self) -> Result<bool> {
+		crate::core::validity::check("QualityMSE", self.as_raw_QualityMSE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_quality_QualityMSE_empty_const(self.as_raw_QualityMSE(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_quality_QualityMSE_empty_const(self.as_raw_QualityMSE(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -368,9 +383,10 @@ pub trait QualityMSETrait: crate::quality::QualityBase + crate::quality::Quality
 	/// cv::Scalar with per-channel quality values.  Values range from 0 (best) to potentially max float (worst)
 	#[inline]
 	fn compute(&mut self, cmp_imgs: &dyn core::ToInputArray) -> Result<core::Scalar> {
+		crate::core::validity::check("QualityMSE", self.as_raw_mut_QualityMSE())?;
 		input_array_arg!(cmp_imgs);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_quality_QualityMSE_compute_const__InputArrayR(self.as_raw_mut_QualityMSE(), cmp_imgs.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_quality_QualityMSE_compute_const__InputArrayR(self.as_raw_mut_QualityMSE(), cmp_imgs.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -379,8 +395,9 @@ pub trait QualityMSETrait: crate::quality::QualityBase + crate::quality::Quality
 	/// Implements Algorithm::clear()
 	#[inline]
 	fn clear(&mut self) -> Result<()> {
+		crate::core::validity::check("QualityMSE", self.as_raw_mut_QualityMSE())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_quality_QualityMSE_clear(self.as_raw_mut_QualityMSE(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_quality_QualityMSE_clear(self.as_raw_mut_QualityMSE(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -398,7 +415,10 @@ opencv_type_boxed! { QualityMSE }
 impl Drop for QualityMSE {
 	fn drop(&mut self) {
 		extern "C" { fn cv_QualityMSE_delete(instance: *mut c_void); }
-		unsafe { cv_QualityMSE_delete(self.as_raw_mut_QualityMSE()) };
+		let ptr = self.as_raw_mut_QualityMSE();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_QualityMSE_delete(ptr) };
 	}
 }
 
@@ -436,7 +456,7 @@ impl QualityMSE {
 	pub fn create(ref_: &dyn core::ToInputArray) -> Result<core::Ptr<crate::quality::QualityMSE>> {
 		input_array_arg!(ref_);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_quality_QualityMSE_create_const__InputArrayR(ref_.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_quality_QualityMSE_create_const__InputArrayR(ref_.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::quality::QualityMSE>::opencv_from_extern(ret) };
@@ -456,7 +476,7 @@ impl QualityMSE {
 		input_array_arg!(cmp);
 		output_array_arg!(quality_map);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_quality_QualityMSE_compute_const__InputArrayR_const__InputArrayR_const__OutputArrayR(ref_.as_raw__InputArray(), cmp.as_raw__InputArray(), quality_map.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_quality_QualityMSE_compute_const__InputArrayR_const__InputArrayR_const__OutputArrayR(ref_.as_raw__InputArray(), cmp.as_raw__InputArray(), quality_map.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -473,8 +493,9 @@ pub trait QualityPSNRTraitConst: crate::quality::QualityBaseConst {
 	/// Implements Algorithm::empty()
 	#[inline]
 	fn empty(&self) -> Result<bool> {
+		crate::core::validity::check("QualityPSNR", self.as_raw_QualityPSNR())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_quality_QualityPSNR_empty_const(self.as_raw_QualityPSNR(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_quality_QualityPSNR_empty_const(self.as_raw_QualityPSNR(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -483,8 +504,9 @@ pub trait QualityPSNRTraitConst: crate::quality::QualityBaseConst {
 	/// return the maximum pixel value used for PSNR computation
 	#[inline]
 	fn get_max_pixel_value(&self) -> Result<f64> {
+		crate::core::validity::check("QualityPSNR", self.as_raw_QualityPSNR())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_quality_QualityPSNR_getMaxPixelValue_const(self.as_raw_QualityPSNR(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_quality_QualityPSNR_getMaxPixelValue_const(self.as_raw_QualityPSNR(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -502,9 +524,10 @@ pub trait QualityPSNRTrait: crate::quality::QualityBase + crate::quality::Qualit
 	/// Per-channel PSNR value, or std::numeric_limits<double>::infinity() if the MSE between the two images == 0
 	#[inline]
 	fn compute(&mut self, cmp: &dyn core::ToInputArray) -> Result<core::Scalar> {
+		crate::core::validity::check("QualityPSNR", self.as_raw_mut_QualityPSNR())?;
 		input_array_arg!(cmp);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_quality_QualityPSNR_compute_const__InputArrayR(self.as_raw_mut_QualityPSNR(), cmp.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_quality_QualityPSNR_compute_const__InputArrayR(self.as_raw_mut_QualityPSNR(), cmp.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -513,8 +536,9 @@ pub trait QualityPSNRTrait: crate::quality::QualityBase + crate::quality::Qualit
 	/// Implements Algorithm::clear()
 	#[inline]
 	fn clear(&mut self) -> Result<()> {
+		crate::core::validity::check("QualityPSNR", self.as_raw_mut_QualityPSNR())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_quality_QualityPSNR_clear(self.as_raw_mut_QualityPSNR(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_quality_QualityPSNR_clear(self.as_raw_mut_QualityPSNR(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -525,8 +549,9 @@ pub trait QualityPSNRTrait: crate::quality::QualityBase + crate::quality::Qualit
 	/// * val: Maximum pixel value
 	#[inline]
 	fn set_max_pixel_value(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("QualityPSNR", self.as_raw_mut_QualityPSNR())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_quality_QualityPSNR_setMaxPixelValue_double(self.as_raw_mut_QualityPSNR(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_quality_QualityPSNR_setMaxPixelValue_double(self.as_raw_mut_QualityPSNR(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -544,7 +569,10 @@ opencv_type_boxed! { QualityPSNR }
 impl Drop for QualityPSNR {
 	fn drop(&mut self) {
 		extern "C" { fn cv_QualityPSNR_delete(instance: *mut c_void); }
-		unsafe { cv_QualityPSNR_delete(self.as_raw_mut_QualityPSNR()) };
+		let ptr = self.as_raw_mut_QualityPSNR();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_QualityPSNR_delete(ptr) };
 	}
 }
 
@@ -587,7 +615,7 @@ impl QualityPSNR {
 	pub fn create(ref_: &dyn core::ToInputArray, max_pixel_value: f64) -> Result<core::Ptr<crate::quality::QualityPSNR>> {
 		input_array_arg!(ref_);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_quality_QualityPSNR_create_const__InputArrayR_double(ref_.as_raw__InputArray(), max_pixel_value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_quality_QualityPSNR_create_const__InputArrayR_double(ref_.as_raw__InputArray(), max_pixel_value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::quality::QualityPSNR>::opencv_from_extern(ret) };
@@ -611,7 +639,7 @@ impl QualityPSNR {
 		input_array_arg!(cmp);
 		output_array_arg!(quality_map);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_quality_QualityPSNR_compute_const__InputArrayR_const__InputArrayR_const__OutputArrayR_double(ref_.as_raw__InputArray(), cmp.as_raw__InputArray(), quality_map.as_raw__OutputArray(), max_pixel_value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_quality_QualityPSNR_compute_const__InputArrayR_const__InputArrayR_const__OutputArrayR_double(ref_.as_raw__InputArray(), cmp.as_raw__InputArray(), quality_map.as_raw__OutputArray(), max_pixel_value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -628,8 +656,9 @@ pub trait QualitySSIMTraitConst: crate::quality::QualityBaseConst {
 	/// Implements Algorithm::empty()
 	#[inline]
 	fn empty(&self) -> Result<bool> {
+		crate::core::validity::check("QualitySSIM", self.as_raw_QualitySSIM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_quality_QualitySSIM_empty_const(self.as_raw_QualitySSIM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_quality_QualitySSIM_empty_const(self.as_raw_QualitySSIM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -647,9 +676,10 @@ pub trait QualitySSIMTrait: crate::quality::QualityBase + crate::quality::Qualit
 	/// cv::Scalar with per-channel quality values.  Values range from 0 (worst) to 1 (best)
 	#[inline]
 	fn compute(&mut self, cmp: &dyn core::ToInputArray) -> Result<core::Scalar> {
+		crate::core::validity::check("QualitySSIM", self.as_raw_mut_QualitySSIM())?;
 		input_array_arg!(cmp);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_quality_QualitySSIM_compute_const__InputArrayR(self.as_raw_mut_QualitySSIM(), cmp.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_quality_QualitySSIM_compute_const__InputArrayR(self.as_raw_mut_QualitySSIM(), cmp.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -658,8 +688,9 @@ pub trait QualitySSIMTrait: crate::quality::QualityBase + crate::quality::Qualit
 	/// Implements Algorithm::clear()
 	#[inline]
 	fn clear(&mut self) -> Result<()> {
+		crate::core::validity::check("QualitySSIM", self.as_raw_mut_QualitySSIM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_quality_QualitySSIM_clear(self.as_raw_mut_QualitySSIM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_quality_QualitySSIM_clear(self.as_raw_mut_QualitySSIM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -677,7 +708,10 @@ opencv_type_boxed! { QualitySSIM }
 impl Drop for QualitySSIM {
 	fn drop(&mut self) {
 		extern "C" { fn cv_QualitySSIM_delete(instance: *mut c_void); }
-		unsafe { cv_QualitySSIM_delete(self.as_raw_mut_QualitySSIM()) };
+		let ptr = self.as_raw_mut_QualitySSIM();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_QualitySSIM_delete(ptr) };
 	}
 }
 
@@ -715,7 +749,7 @@ impl QualitySSIM {
 	pub fn create(ref_: &dyn core::ToInputArray) -> Result<core::Ptr<crate::quality::QualitySSIM>> {
 		input_array_arg!(ref_);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_quality_QualitySSIM_create_const__InputArrayR(ref_.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_quality_QualitySSIM_create_const__InputArrayR(ref_.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::quality::QualitySSIM>::opencv_from_extern(ret) };
@@ -735,7 +769,7 @@ impl QualitySSIM {
 		input_array_arg!(cmp);
 		output_array_arg!(quality_map);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_quality_QualitySSIM_compute_const__InputArrayR_const__InputArrayR_const__OutputArrayR(ref_.as_raw__InputArray(), cmp.as_raw__InputArray(), quality_map.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_quality_QualitySSIM_compute_const__InputArrayR_const__InputArrayR_const__OutputArrayR(ref_.as_raw__InputArray(), cmp.as_raw__InputArray(), quality_map.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -744,3 +778,4 @@ impl QualitySSIM {
 }
 
 boxed_cast_base! { QualitySSIM, core::Algorithm, cv_QualitySSIM_to_Algorithm }
+pub use crate::manual::quality::*;
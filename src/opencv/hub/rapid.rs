@@ -34,7 +34,7 @@ pub fn convert_correspondencies(cols: &dyn core::ToInputArray, src_locations: &d
 	input_output_array_arg!(pts3d);
 	input_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_rapid_convertCorrespondencies_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputOutputArrayR_const__InputArrayR(cols.as_raw__InputArray(), src_locations.as_raw__InputArray(), pts2d.as_raw__OutputArray(), pts3d.as_raw__InputOutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_rapid_convertCorrespondencies_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputOutputArrayR_const__InputArrayR(cols.as_raw__InputArray(), src_locations.as_raw__InputArray(), pts2d.as_raw__OutputArray(), pts3d.as_raw__InputOutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -54,7 +54,7 @@ pub fn draw_correspondencies(bundle: &mut dyn core::ToInputOutputArray, cols: &d
 	input_array_arg!(cols);
 	input_array_arg!(colors);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_rapid_drawCorrespondencies_const__InputOutputArrayR_const__InputArrayR_const__InputArrayR(bundle.as_raw__InputOutputArray(), cols.as_raw__InputArray(), colors.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_rapid_drawCorrespondencies_const__InputOutputArrayR_const__InputArrayR_const__InputArrayR(bundle.as_raw__InputOutputArray(), cols.as_raw__InputArray(), colors.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -70,7 +70,7 @@ pub fn draw_search_lines(img: &mut dyn core::ToInputOutputArray, locations: &dyn
 	input_output_array_arg!(img);
 	input_array_arg!(locations);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_rapid_drawSearchLines_const__InputOutputArrayR_const__InputArrayR_const_ScalarR(img.as_raw__InputOutputArray(), locations.as_raw__InputArray(), &color, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_rapid_drawSearchLines_const__InputOutputArrayR_const__InputArrayR_const_ScalarR(img.as_raw__InputOutputArray(), locations.as_raw__InputArray(), &color, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -79,10 +79,10 @@ pub fn draw_search_lines(img: &mut dyn core::ToInputOutputArray, locations: &dyn
 /// Draw a wireframe of a triangle mesh
 /// ## Parameters
 /// * img: the output image
-/// * pts2d: the 2d points obtained by @ref projectPoints
+/// * pts2d: the 2d points obtained by `project_points`
 /// * tris: triangle face connectivity
 /// * color: line color
-/// * type: line type. See @ref LineTypes.
+/// * type: line type. See `LineTypes`.
 /// * cullBackface: enable back-face culling based on CCW order
 /// 
 /// ## C++ default parameters
@@ -94,12 +94,44 @@ pub fn draw_wireframe(img: &mut dyn core::ToInputOutputArray, pts2d: &dyn core::
 	input_array_arg!(pts2d);
 	input_array_arg!(tris);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_rapid_drawWireframe_const__InputOutputArrayR_const__InputArrayR_const__InputArrayR_const_ScalarR_int_bool(img.as_raw__InputOutputArray(), pts2d.as_raw__InputArray(), tris.as_raw__InputArray(), &color, typ, cull_backface, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_rapid_drawWireframe_const__InputOutputArrayR_const__InputArrayR_const__InputArrayR_const_ScalarR_int_bool(img.as_raw__InputOutputArray(), pts2d.as_raw__InputArray(), tris.as_raw__InputArray(), &color, typ, cull_backface, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `draw_wireframe()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct DrawWireframeBuilder<'a> {
+	img: &'a mut dyn core::ToInputOutputArray,
+	pts2d: &'a dyn core::ToInputArray,
+	tris: &'a dyn core::ToInputArray,
+	color: core::Scalar,
+	typ: i32,
+	cull_backface: bool,
+}
+
+impl<'a> DrawWireframeBuilder<'a> {
+	#[inline]
+	pub fn new(img: &'a mut dyn core::ToInputOutputArray, pts2d: &'a dyn core::ToInputArray, tris: &'a dyn core::ToInputArray, color: core::Scalar, typ: i32) -> Self {
+		Self { img, pts2d, tris, color, typ, cull_backface: false }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_cull_backface(mut self, cull_backface: bool) -> Self {
+		self.cull_backface = cull_backface;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		draw_wireframe(self.img, self.pts2d, self.tris, self.color, self.typ, self.cull_backface)
+	}
+}
+
 /// Extract control points from the projected silhouette of a mesh
 /// 
 /// see [drummond2002real](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_drummond2002real) Sec 2.1, Step b
@@ -124,7 +156,7 @@ pub fn extract_control_points(num: i32, len: i32, pts3d: &dyn core::ToInputArray
 	output_array_arg!(ctl2d);
 	output_array_arg!(ctl3d);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_rapid_extractControlPoints_int_int_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const_SizeR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(num, len, pts3d.as_raw__InputArray(), rvec.as_raw__InputArray(), tvec.as_raw__InputArray(), k.as_raw__InputArray(), &imsize, tris.as_raw__InputArray(), ctl2d.as_raw__OutputArray(), ctl3d.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_rapid_extractControlPoints_int_int_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const_SizeR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(num, len, pts3d.as_raw__InputArray(), rvec.as_raw__InputArray(), tvec.as_raw__InputArray(), k.as_raw__InputArray(), &imsize, tris.as_raw__InputArray(), ctl2d.as_raw__OutputArray(), ctl3d.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -145,7 +177,7 @@ pub fn extract_line_bundle(len: i32, ctl2d: &dyn core::ToInputArray, img: &dyn c
 	output_array_arg!(bundle);
 	output_array_arg!(src_locations);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_rapid_extractLineBundle_int_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(len, ctl2d.as_raw__InputArray(), img.as_raw__InputArray(), bundle.as_raw__OutputArray(), src_locations.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_rapid_extractLineBundle_int_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(len, ctl2d.as_raw__InputArray(), img.as_raw__InputArray(), bundle.as_raw__OutputArray(), src_locations.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -166,7 +198,7 @@ pub fn find_correspondencies(bundle: &dyn core::ToInputArray, cols: &mut dyn cor
 	output_array_arg!(cols);
 	output_array_arg!(response);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_rapid_findCorrespondencies_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(bundle.as_raw__InputArray(), cols.as_raw__OutputArray(), response.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_rapid_findCorrespondencies_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(bundle.as_raw__InputArray(), cols.as_raw__OutputArray(), response.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -174,11 +206,11 @@ pub fn find_correspondencies(bundle: &dyn core::ToInputArray, cols: &mut dyn cor
 
 /// High level function to execute a single rapid [harris1990rapid](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_harris1990rapid) iteration
 /// 
-/// 1. @ref extractControlPoints
-/// 2. @ref extractLineBundle
-/// 3. @ref findCorrespondencies
-/// 4. @ref convertCorrespondencies
-/// 5. @ref solvePnPRefineLM
+/// 1. [extract_control_points](crate::rapid::extract_control_points)
+/// 2. [extract_line_bundle](crate::rapid::extract_line_bundle)
+/// 3. [find_correspondencies](crate::rapid::find_correspondencies)
+/// 4. [convert_correspondencies](crate::rapid::convert_correspondencies)
+/// 5. `solve_pnp_refine_lm`
 /// 
 /// ## Parameters
 /// * img: the video frame
@@ -204,7 +236,7 @@ pub fn rapid(img: &dyn core::ToInputArray, num: i32, len: i32, pts3d: &dyn core:
 	input_output_array_arg!(rvec);
 	input_output_array_arg!(tvec);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_rapid_rapid_const__InputArrayR_int_int_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_doubleX(img.as_raw__InputArray(), num, len, pts3d.as_raw__InputArray(), tris.as_raw__InputArray(), k.as_raw__InputArray(), rvec.as_raw__InputOutputArray(), tvec.as_raw__InputOutputArray(), rmsd, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_rapid_rapid_const__InputArrayR_int_int_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_doubleX(img.as_raw__InputArray(), num, len, pts3d.as_raw__InputArray(), tris.as_raw__InputArray(), k.as_raw__InputArray(), rvec.as_raw__InputOutputArray(), tvec.as_raw__InputOutputArray(), rmsd, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -230,7 +262,7 @@ impl dyn GOSTracker + '_ {
 		input_array_arg!(pts3d);
 		input_array_arg!(tris);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rapid_GOSTracker_create_const__InputArrayR_const__InputArrayR_int_unsigned_char(pts3d.as_raw__InputArray(), tris.as_raw__InputArray(), hist_bins, sobel_thesh, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rapid_GOSTracker_create_const__InputArrayR_const__InputArrayR_int_unsigned_char(pts3d.as_raw__InputArray(), tris.as_raw__InputArray(), hist_bins, sobel_thesh, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::rapid::OLSTracker>::opencv_from_extern(ret) };
@@ -259,7 +291,7 @@ impl dyn OLSTracker + '_ {
 		input_array_arg!(pts3d);
 		input_array_arg!(tris);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rapid_OLSTracker_create_const__InputArrayR_const__InputArrayR_int_unsigned_char(pts3d.as_raw__InputArray(), tris.as_raw__InputArray(), hist_bins, sobel_thesh, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rapid_OLSTracker_create_const__InputArrayR_const__InputArrayR_int_unsigned_char(pts3d.as_raw__InputArray(), tris.as_raw__InputArray(), hist_bins, sobel_thesh, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::rapid::OLSTracker>::opencv_from_extern(ret) };
@@ -267,7 +299,7 @@ impl dyn OLSTracker + '_ {
 	}
 	
 }
-/// wrapper around @ref rapid function for uniform access
+/// wrapper around [rapid](crate::rapid::rapid) function for uniform access
 pub trait RapidConst: crate::rapid::TrackerConst {
 	fn as_raw_Rapid(&self) -> *const c_void;
 
@@ -284,7 +316,7 @@ impl dyn Rapid + '_ {
 		input_array_arg!(pts3d);
 		input_array_arg!(tris);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rapid_Rapid_create_const__InputArrayR_const__InputArrayR(pts3d.as_raw__InputArray(), tris.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rapid_Rapid_create_const__InputArrayR_const__InputArrayR(pts3d.as_raw__InputArray(), tris.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::rapid::Rapid>::opencv_from_extern(ret) };
@@ -305,12 +337,13 @@ pub trait Tracker: core::AlgorithmTrait + crate::rapid::TrackerConst {
 	/// * termcrit: TermCriteria(TermCriteria::MAX_ITER|TermCriteria::EPS,5,1.5)
 	#[inline]
 	fn compute(&mut self, img: &dyn core::ToInputArray, num: i32, len: i32, k: &dyn core::ToInputArray, rvec: &mut dyn core::ToInputOutputArray, tvec: &mut dyn core::ToInputOutputArray, termcrit: core::TermCriteria) -> Result<f32> {
+		crate::core::validity::check("Tracker", self.as_raw_mut_Tracker())?;
 		input_array_arg!(img);
 		input_array_arg!(k);
 		input_output_array_arg!(rvec);
 		input_output_array_arg!(tvec);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rapid_Tracker_compute_const__InputArrayR_int_int_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const_TermCriteriaR(self.as_raw_mut_Tracker(), img.as_raw__InputArray(), num, len, k.as_raw__InputArray(), rvec.as_raw__InputOutputArray(), tvec.as_raw__InputOutputArray(), &termcrit, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rapid_Tracker_compute_const__InputArrayR_int_int_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const_TermCriteriaR(self.as_raw_mut_Tracker(), img.as_raw__InputArray(), num, len, k.as_raw__InputArray(), rvec.as_raw__InputOutputArray(), tvec.as_raw__InputOutputArray(), &termcrit, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -318,8 +351,9 @@ pub trait Tracker: core::AlgorithmTrait + crate::rapid::TrackerConst {
 	
 	#[inline]
 	fn clear_state(&mut self) -> Result<()> {
+		crate::core::validity::check("Tracker", self.as_raw_mut_Tracker())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rapid_Tracker_clearState(self.as_raw_mut_Tracker(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rapid_Tracker_clearState(self.as_raw_mut_Tracker(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -9,7 +9,7 @@
 )]
 //! # RGB-Depth Processing
 //! 
-//! @ref kinfu_icp
+//! kinfu_icp
 use crate::{mod_prelude::*, core, sys, types};
 pub mod prelude {
 	pub use { super::Linemod_TemplateTraitConst, super::Linemod_TemplateTrait, super::Linemod_QuantizedPyramidConst, super::Linemod_QuantizedPyramid, super::Linemod_ModalityConst, super::Linemod_Modality, super::Linemod_ColorGradientTraitConst, super::Linemod_ColorGradientTrait, super::Linemod_DepthNormalTraitConst, super::Linemod_DepthNormalTrait, super::Linemod_MatchTraitConst, super::Linemod_MatchTrait, super::Linemod_DetectorTraitConst, super::Linemod_DetectorTrait, super::RgbdNormalsTraitConst, super::RgbdNormalsTrait, super::DepthCleanerTraitConst, super::DepthCleanerTrait, super::RgbdPlaneTraitConst, super::RgbdPlaneTrait, super::RgbdFrameTraitConst, super::RgbdFrameTrait, super::OdometryFrameTraitConst, super::OdometryFrameTrait, super::OdometryConst, super::Odometry, super::RgbdOdometryTraitConst, super::RgbdOdometryTrait, super::ICPOdometryTraitConst, super::ICPOdometryTrait, super::RgbdICPOdometryTraitConst, super::RgbdICPOdometryTrait, super::FastICPOdometryTraitConst, super::FastICPOdometryTrait, super::Kinfu_VolumeConst, super::Kinfu_Volume, super::Kinfu_VolumeParamsTraitConst, super::Kinfu_VolumeParamsTrait, super::Kinfu_ParamsTraitConst, super::Kinfu_ParamsTrait, super::Kinfu_KinFuConst, super::Kinfu_KinFu, super::Dynafu_DynaFuConst, super::Dynafu_DynaFu, super::ParamsTraitConst, super::ParamsTrait, super::LargeKinfuConst, super::LargeKinfu, super::Kinfu_Detail_PoseGraphConst, super::Kinfu_Detail_PoseGraph, super::ColoredKinfu_ParamsTraitConst, super::ColoredKinfu_ParamsTrait, super::ColoredKinfu_ColoredKinFuConst, super::ColoredKinfu_ColoredKinFu };
@@ -68,7 +68,7 @@ pub type Dynafu_Params = crate::rgbd::Kinfu_Params;
 #[inline]
 pub fn make_volume(_volume_type: crate::rgbd::Kinfu_VolumeType, _voxel_size: f32, _pose: core::Matx44f, _raycast_step_factor: f32, _trunc_dist: f32, _max_weight: i32, _truncate_threshold: f32, _resolution: core::Vec3i) -> Result<core::Ptr<dyn crate::rgbd::Kinfu_Volume>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_kinfu_makeVolume_VolumeType_float_Matx44f_float_float_int_float_Vec3i(_volume_type, _voxel_size, _pose.opencv_as_extern(), _raycast_step_factor, _trunc_dist, _max_weight, _truncate_threshold, _resolution.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_kinfu_makeVolume_VolumeType_float_Matx44f_float_float_int_float_Vec3i(_volume_type, _voxel_size, _pose.opencv_as_extern(), _raycast_step_factor, _trunc_dist, _max_weight, _truncate_threshold, _resolution.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::rgbd::Kinfu_Volume>::opencv_from_extern(ret) };
@@ -79,7 +79,7 @@ pub fn make_volume(_volume_type: crate::rgbd::Kinfu_VolumeType, _voxel_size: f32
 #[inline]
 pub fn colormap(quantized: &core::Mat, dst: &mut core::Mat) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_linemod_colormap_const_MatR_MatR(quantized.as_raw_Mat(), dst.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_linemod_colormap_const_MatR_MatR(quantized.as_raw_Mat(), dst.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -88,9 +88,9 @@ pub fn colormap(quantized: &core::Mat, dst: &mut core::Mat) -> Result<()> {
 /// \brief Debug function to draw linemod features
 /// ## Parameters
 /// * img: 
-/// * templates: see @ref Detector::addTemplate
-/// * tl: template bbox top-left offset see @ref Detector::addTemplate
-/// * size: marker size see @ref cv::drawMarker
+/// * templates: see `Detector::add_template`
+/// * tl: template bbox top-left offset see `Detector::add_template`
+/// * size: marker size see `draw_marker`
 /// 
 /// ## C++ default parameters
 /// * size: 10
@@ -98,7 +98,7 @@ pub fn colormap(quantized: &core::Mat, dst: &mut core::Mat) -> Result<()> {
 pub fn draw_features(img: &mut dyn core::ToInputOutputArray, templates: &core::Vector<crate::rgbd::Linemod_Template>, tl: core::Point2i, size: i32) -> Result<()> {
 	input_output_array_arg!(img);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_linemod_drawFeatures_const__InputOutputArrayR_const_vector_Template_R_const_Point2iR_int(img.as_raw__InputOutputArray(), templates.as_raw_VectorOfLinemod_Template(), &tl, size, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_linemod_drawFeatures_const__InputOutputArrayR_const_vector_Template_R_const_Point2iR_int(img.as_raw__InputOutputArray(), templates.as_raw_VectorOfLinemod_Template(), &tl, size, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -110,7 +110,7 @@ pub fn draw_features(img: &mut dyn core::ToInputOutputArray, templates: &core::V
 #[inline]
 pub fn get_default_line() -> Result<core::Ptr<crate::rgbd::Linemod_Detector>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_linemod_getDefaultLINE(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_linemod_getDefaultLINE(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<crate::rgbd::Linemod_Detector>::opencv_from_extern(ret) };
@@ -124,7 +124,7 @@ pub fn get_default_line() -> Result<core::Ptr<crate::rgbd::Linemod_Detector>> {
 #[inline]
 pub fn get_default_linemod() -> Result<core::Ptr<crate::rgbd::Linemod_Detector>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_linemod_getDefaultLINEMOD(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_linemod_getDefaultLINEMOD(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<crate::rgbd::Linemod_Detector>::opencv_from_extern(ret) };
@@ -143,7 +143,7 @@ pub fn depth_to3d_sparse(depth: &dyn core::ToInputArray, in_k: &dyn core::ToInpu
 	input_array_arg!(in_points);
 	output_array_arg!(points3d);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_rgbd_depthTo3dSparse_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR(depth.as_raw__InputArray(), in_k.as_raw__InputArray(), in_points.as_raw__InputArray(), points3d.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_rgbd_depthTo3dSparse_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR(depth.as_raw__InputArray(), in_k.as_raw__InputArray(), in_points.as_raw__InputArray(), points3d.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -168,7 +168,7 @@ pub fn depth_to3d(depth: &dyn core::ToInputArray, k: &dyn core::ToInputArray, po
 	output_array_arg!(points3d);
 	input_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_rgbd_depthTo3d_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR(depth.as_raw__InputArray(), k.as_raw__InputArray(), points3d.as_raw__OutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_rgbd_depthTo3d_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR(depth.as_raw__InputArray(), k.as_raw__InputArray(), points3d.as_raw__OutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -177,7 +177,7 @@ pub fn depth_to3d(depth: &dyn core::ToInputArray, k: &dyn core::ToInputArray, po
 #[inline]
 pub fn is_valid_depth_1(depth: &f64) -> Result<bool> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_rgbd_isValidDepth_const_doubleR(depth, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_rgbd_isValidDepth_const_doubleR(depth, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -190,7 +190,7 @@ pub fn is_valid_depth_1(depth: &f64) -> Result<bool> {
 #[inline]
 pub fn is_valid_depth(depth: &f32) -> Result<bool> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_rgbd_isValidDepth_const_floatR(depth, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_rgbd_isValidDepth_const_floatR(depth, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -199,7 +199,7 @@ pub fn is_valid_depth(depth: &f32) -> Result<bool> {
 #[inline]
 pub fn is_valid_depth_4(depth: &i32) -> Result<bool> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_rgbd_isValidDepth_const_intR(depth, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_rgbd_isValidDepth_const_intR(depth, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -208,7 +208,7 @@ pub fn is_valid_depth_4(depth: &i32) -> Result<bool> {
 #[inline]
 pub fn is_valid_depth_2(depth: &i16) -> Result<bool> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_rgbd_isValidDepth_const_shortR(depth, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_rgbd_isValidDepth_const_shortR(depth, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -217,7 +217,7 @@ pub fn is_valid_depth_2(depth: &i16) -> Result<bool> {
 #[inline]
 pub fn is_valid_depth_5(depth: &u32) -> Result<bool> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_rgbd_isValidDepth_const_unsigned_intR(depth, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_rgbd_isValidDepth_const_unsigned_intR(depth, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -226,7 +226,7 @@ pub fn is_valid_depth_5(depth: &u32) -> Result<bool> {
 #[inline]
 pub fn is_valid_depth_3(depth: &u16) -> Result<bool> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_rgbd_isValidDepth_const_unsigned_shortR(depth, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_rgbd_isValidDepth_const_unsigned_shortR(depth, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -237,7 +237,7 @@ pub fn is_valid_depth_3(depth: &u16) -> Result<bool> {
 /// the rigid body transformation between the cameras, and then projecting the
 /// transformed points into the RGB camera.
 /// 
-/// uv_rgb = K_rgb * [R | t] * z * inv(K_ir) * uv_ir
+/// uv_rgb = K_rgb * \[R | t\] * z * inv(K_ir) * uv_ir
 /// 
 /// Currently does not check for negative depth values.
 /// 
@@ -262,12 +262,46 @@ pub fn register_depth(unregistered_camera_matrix: &dyn core::ToInputArray, regis
 	input_array_arg!(unregistered_depth);
 	output_array_arg!(registered_depth);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_rgbd_registerDepth_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const_SizeR_const__OutputArrayR_bool(unregistered_camera_matrix.as_raw__InputArray(), registered_camera_matrix.as_raw__InputArray(), registered_dist_coeffs.as_raw__InputArray(), rt.as_raw__InputArray(), unregistered_depth.as_raw__InputArray(), &output_image_plane_size, registered_depth.as_raw__OutputArray(), depth_dilation, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_rgbd_registerDepth_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const_SizeR_const__OutputArrayR_bool(unregistered_camera_matrix.as_raw__InputArray(), registered_camera_matrix.as_raw__InputArray(), registered_dist_coeffs.as_raw__InputArray(), rt.as_raw__InputArray(), unregistered_depth.as_raw__InputArray(), &output_image_plane_size, registered_depth.as_raw__OutputArray(), depth_dilation, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `register_depth()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct RegisterDepthBuilder<'a> {
+	unregistered_camera_matrix: &'a dyn core::ToInputArray,
+	registered_camera_matrix: &'a dyn core::ToInputArray,
+	registered_dist_coeffs: &'a dyn core::ToInputArray,
+	rt: &'a dyn core::ToInputArray,
+	unregistered_depth: &'a dyn core::ToInputArray,
+	output_image_plane_size: core::Size,
+	registered_depth: &'a mut dyn core::ToOutputArray,
+	depth_dilation: bool,
+}
+
+impl<'a> RegisterDepthBuilder<'a> {
+	#[inline]
+	pub fn new(unregistered_camera_matrix: &'a dyn core::ToInputArray, registered_camera_matrix: &'a dyn core::ToInputArray, registered_dist_coeffs: &'a dyn core::ToInputArray, rt: &'a dyn core::ToInputArray, unregistered_depth: &'a dyn core::ToInputArray, output_image_plane_size: core::Size, registered_depth: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { unregistered_camera_matrix, registered_camera_matrix, registered_dist_coeffs, rt, unregistered_depth, output_image_plane_size, registered_depth, depth_dilation: false }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_depth_dilation(mut self, depth_dilation: bool) -> Self {
+		self.depth_dilation = depth_dilation;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		register_depth(self.unregistered_camera_matrix, self.registered_camera_matrix, self.registered_dist_coeffs, self.rt, self.unregistered_depth, self.output_image_plane_size, self.registered_depth, self.depth_dilation)
+	}
+}
+
 /// If the input image is of type CV_16UC1 (like the Kinect one), the image is converted to floats, divided
 /// by depth_factor to get a depth in meters, and the values 0 are converted to std::numeric_limits<float>::quiet_NaN()
 /// Otherwise, the image is simply converted to floats
@@ -285,7 +319,7 @@ pub fn rescale_depth(in_: &dyn core::ToInputArray, depth: i32, out: &mut dyn cor
 	input_array_arg!(in_);
 	output_array_arg!(out);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_rgbd_rescaleDepth_const__InputArrayR_int_const__OutputArrayR_double(in_.as_raw__InputArray(), depth, out.as_raw__OutputArray(), depth_factor, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_rgbd_rescaleDepth_const__InputArrayR_int_const__OutputArrayR_double(in_.as_raw__InputArray(), depth, out.as_raw__OutputArray(), depth_factor, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -314,12 +348,58 @@ pub fn warp_frame(image: &core::Mat, depth: &core::Mat, mask: &core::Mat, rt: &c
 	output_array_arg!(warped_depth);
 	output_array_arg!(warped_mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_rgbd_warpFrame_const_MatR_const_MatR_const_MatR_const_MatR_const_MatR_const_MatR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(image.as_raw_Mat(), depth.as_raw_Mat(), mask.as_raw_Mat(), rt.as_raw_Mat(), camera_matrix.as_raw_Mat(), dist_coeff.as_raw_Mat(), warped_image.as_raw__OutputArray(), warped_depth.as_raw__OutputArray(), warped_mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_rgbd_warpFrame_const_MatR_const_MatR_const_MatR_const_MatR_const_MatR_const_MatR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(image.as_raw_Mat(), depth.as_raw_Mat(), mask.as_raw_Mat(), rt.as_raw_Mat(), camera_matrix.as_raw_Mat(), dist_coeff.as_raw_Mat(), warped_image.as_raw__OutputArray(), warped_depth.as_raw__OutputArray(), warped_mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `warp_frame()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct WarpFrameBuilder<'a> {
+	image: &'a core::Mat,
+	depth: &'a core::Mat,
+	mask: &'a core::Mat,
+	rt: &'a core::Mat,
+	camera_matrix: &'a core::Mat,
+	dist_coeff: &'a core::Mat,
+	warped_image: &'a mut dyn core::ToOutputArray,
+	warped_depth: Option<&'a mut dyn core::ToOutputArray>,
+	warped_mask: Option<&'a mut dyn core::ToOutputArray>,
+}
+
+impl<'a> WarpFrameBuilder<'a> {
+	#[inline]
+	pub fn new(image: &'a core::Mat, depth: &'a core::Mat, mask: &'a core::Mat, rt: &'a core::Mat, camera_matrix: &'a core::Mat, dist_coeff: &'a core::Mat, warped_image: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { image, depth, mask, rt, camera_matrix, dist_coeff, warped_image, warped_depth: None, warped_mask: None }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_warped_depth(mut self, warped_depth: &'a mut dyn core::ToOutputArray) -> Self {
+		self.warped_depth = Some(warped_depth);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_warped_mask(mut self, warped_mask: &'a mut dyn core::ToOutputArray) -> Self {
+		self.warped_mask = Some(warped_mask);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let mut warped_depth_no_array = core::no_array();
+		let warped_depth: &mut dyn core::ToOutputArray = match self.warped_depth { Some(warped_depth) => warped_depth, None => &mut warped_depth_no_array };
+		let mut warped_mask_no_array = core::no_array();
+		let warped_mask: &mut dyn core::ToOutputArray = match self.warped_mask { Some(warped_mask) => warped_mask, None => &mut warped_mask_no_array };
+		warp_frame(self.image, self.depth, self.mask, self.rt, self.camera_matrix, self.dist_coeff, self.warped_image, warped_depth, warped_mask)
+	}
+}
+
 /// KinectFusion implementation
 /// 
 /// This class implements a 3d reconstruction algorithm described in
@@ -349,8 +429,9 @@ pub trait ColoredKinfu_ColoredKinFuConst {
 	/// Get current parameters
 	#[inline]
 	fn get_params(&self) -> Result<crate::rgbd::ColoredKinfu_Params> {
+		crate::core::validity::check("ColoredKinfu_ColoredKinFu", self.as_raw_ColoredKinfu_ColoredKinFu())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_colored_kinfu_ColoredKinFu_getParams_const(self.as_raw_ColoredKinfu_ColoredKinFu(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_colored_kinfu_ColoredKinFu_getParams_const(self.as_raw_ColoredKinfu_ColoredKinFu(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::ColoredKinfu_Params::opencv_from_extern(ret) };
@@ -366,9 +447,10 @@ pub trait ColoredKinfu_ColoredKinFuConst {
 	/// * image: resulting image
 	#[inline]
 	fn render(&self, image: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("ColoredKinfu_ColoredKinFu", self.as_raw_ColoredKinfu_ColoredKinFu())?;
 		output_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_colored_kinfu_ColoredKinFu_render_const_const__OutputArrayR(self.as_raw_ColoredKinfu_ColoredKinFu(), image.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_colored_kinfu_ColoredKinFu_render_const_const__OutputArrayR(self.as_raw_ColoredKinfu_ColoredKinFu(), image.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -385,9 +467,10 @@ pub trait ColoredKinfu_ColoredKinFuConst {
 	///   which is a last frame camera pose.
 	#[inline]
 	fn render_1(&self, image: &mut dyn core::ToOutputArray, camera_pose: core::Matx44f) -> Result<()> {
+		crate::core::validity::check("ColoredKinfu_ColoredKinFu", self.as_raw_ColoredKinfu_ColoredKinFu())?;
 		output_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_colored_kinfu_ColoredKinFu_render_const_const__OutputArrayR_const_Matx44fR(self.as_raw_ColoredKinfu_ColoredKinFu(), image.as_raw__OutputArray(), &camera_pose, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_colored_kinfu_ColoredKinFu_render_const_const__OutputArrayR_const_Matx44fR(self.as_raw_ColoredKinfu_ColoredKinFu(), image.as_raw__OutputArray(), &camera_pose, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -407,11 +490,12 @@ pub trait ColoredKinfu_ColoredKinFuConst {
 	/// * colors: noArray()
 	#[inline]
 	fn get_cloud(&self, points: &mut dyn core::ToOutputArray, normals: &mut dyn core::ToOutputArray, colors: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("ColoredKinfu_ColoredKinFu", self.as_raw_ColoredKinfu_ColoredKinFu())?;
 		output_array_arg!(points);
 		output_array_arg!(normals);
 		output_array_arg!(colors);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_colored_kinfu_ColoredKinFu_getCloud_const_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_ColoredKinfu_ColoredKinFu(), points.as_raw__OutputArray(), normals.as_raw__OutputArray(), colors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_colored_kinfu_ColoredKinFu_getCloud_const_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_ColoredKinfu_ColoredKinFu(), points.as_raw__OutputArray(), normals.as_raw__OutputArray(), colors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -425,9 +509,10 @@ pub trait ColoredKinfu_ColoredKinFuConst {
 	/// * points: vector of points which are 4-float vectors
 	#[inline]
 	fn get_points(&self, points: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("ColoredKinfu_ColoredKinFu", self.as_raw_ColoredKinfu_ColoredKinFu())?;
 		output_array_arg!(points);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_colored_kinfu_ColoredKinFu_getPoints_const_const__OutputArrayR(self.as_raw_ColoredKinfu_ColoredKinFu(), points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_colored_kinfu_ColoredKinFu_getPoints_const_const__OutputArrayR(self.as_raw_ColoredKinfu_ColoredKinFu(), points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -439,10 +524,11 @@ pub trait ColoredKinfu_ColoredKinFuConst {
 	/// * normals: output vector of corresponding normals which are 4-float vectors
 	#[inline]
 	fn get_normals(&self, points: &dyn core::ToInputArray, normals: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("ColoredKinfu_ColoredKinFu", self.as_raw_ColoredKinfu_ColoredKinFu())?;
 		input_array_arg!(points);
 		output_array_arg!(normals);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_colored_kinfu_ColoredKinFu_getNormals_const_const__InputArrayR_const__OutputArrayR(self.as_raw_ColoredKinfu_ColoredKinFu(), points.as_raw__InputArray(), normals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_colored_kinfu_ColoredKinFu_getNormals_const_const__InputArrayR_const__OutputArrayR(self.as_raw_ColoredKinfu_ColoredKinFu(), points.as_raw__InputArray(), normals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -451,8 +537,9 @@ pub trait ColoredKinfu_ColoredKinFuConst {
 	/// Get current pose in voxel space
 	#[inline]
 	fn get_pose(&self) -> Result<core::Affine3f> {
+		crate::core::validity::check("ColoredKinfu_ColoredKinFu", self.as_raw_ColoredKinfu_ColoredKinFu())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_colored_kinfu_ColoredKinFu_getPose_const(self.as_raw_ColoredKinfu_ColoredKinFu(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_colored_kinfu_ColoredKinFu_getPose_const(self.as_raw_ColoredKinfu_ColoredKinFu(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -468,8 +555,9 @@ pub trait ColoredKinfu_ColoredKinFu: crate::rgbd::ColoredKinfu_ColoredKinFuConst
 	/// Clears current model and resets a pose.
 	#[inline]
 	fn reset(&mut self) -> Result<()> {
+		crate::core::validity::check("ColoredKinfu_ColoredKinFu", self.as_raw_mut_ColoredKinfu_ColoredKinFu())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_colored_kinfu_ColoredKinFu_reset(self.as_raw_mut_ColoredKinfu_ColoredKinFu(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_colored_kinfu_ColoredKinFu_reset(self.as_raw_mut_ColoredKinfu_ColoredKinFu(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -484,10 +572,11 @@ pub trait ColoredKinfu_ColoredKinFu: crate::rgbd::ColoredKinfu_ColoredKinFuConst
 	/// true if succeeded to align new frame with current scene, false if opposite
 	#[inline]
 	fn update(&mut self, depth: &dyn core::ToInputArray, rgb: &dyn core::ToInputArray) -> Result<bool> {
+		crate::core::validity::check("ColoredKinfu_ColoredKinFu", self.as_raw_mut_ColoredKinfu_ColoredKinFu())?;
 		input_array_arg!(depth);
 		input_array_arg!(rgb);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_colored_kinfu_ColoredKinFu_update_const__InputArrayR_const__InputArrayR(self.as_raw_mut_ColoredKinfu_ColoredKinFu(), depth.as_raw__InputArray(), rgb.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_colored_kinfu_ColoredKinFu_update_const__InputArrayR_const__InputArrayR(self.as_raw_mut_ColoredKinfu_ColoredKinFu(), depth.as_raw__InputArray(), rgb.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -499,7 +588,7 @@ impl dyn ColoredKinfu_ColoredKinFu + '_ {
 	#[inline]
 	pub fn create(_params: &core::Ptr<crate::rgbd::ColoredKinfu_Params>) -> Result<core::Ptr<dyn crate::rgbd::ColoredKinfu_ColoredKinFu>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_colored_kinfu_ColoredKinFu_create_const_Ptr_Params_R(_params.as_raw_PtrOfColoredKinfu_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_colored_kinfu_ColoredKinFu_create_const_Ptr_Params_R(_params.as_raw_PtrOfColoredKinfu_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::rgbd::ColoredKinfu_ColoredKinFu>::opencv_from_extern(ret) };
@@ -513,47 +602,92 @@ pub trait ColoredKinfu_ParamsTraitConst {
 	/// frame size in pixels
 	#[inline]
 	fn frame_size(&self) -> core::Size {
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_colored_kinfu_Params_getPropFrameSize_const(self.as_raw_ColoredKinfu_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_colored_kinfu_Params_getPropFrameSize_const(self.as_raw_ColoredKinfu_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [frame_size](ColoredKinfu_ParamsTraitConst::frame_size), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_frame_size(&self) -> Result<core::Size> {
+		crate::core::validity::check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params())?;
+		Ok(ColoredKinfu_ParamsTraitConst::frame_size(self))
+	}
+	
 	/// rgb frame size in pixels
 	#[inline]
 	fn rgb_frame_size(&self) -> core::Size {
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_colored_kinfu_Params_getPropRgb_frameSize_const(self.as_raw_ColoredKinfu_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_colored_kinfu_Params_getPropRgb_frameSize_const(self.as_raw_ColoredKinfu_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [rgb_frame_size](ColoredKinfu_ParamsTraitConst::rgb_frame_size), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_rgb_frame_size(&self) -> Result<core::Size> {
+		crate::core::validity::check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params())?;
+		Ok(ColoredKinfu_ParamsTraitConst::rgb_frame_size(self))
+	}
+	
 	#[inline]
 	fn volume_type(&self) -> crate::rgbd::Kinfu_VolumeType {
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_colored_kinfu_Params_getPropVolumeType_const(self.as_raw_ColoredKinfu_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_colored_kinfu_Params_getPropVolumeType_const(self.as_raw_ColoredKinfu_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [volume_type](ColoredKinfu_ParamsTraitConst::volume_type), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_volume_type(&self) -> Result<crate::rgbd::Kinfu_VolumeType> {
+		crate::core::validity::check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params())?;
+		Ok(ColoredKinfu_ParamsTraitConst::volume_type(self))
+	}
+	
 	/// camera intrinsics
 	#[inline]
 	fn intr(&self) -> core::Matx33f {
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_colored_kinfu_Params_getPropIntr_const(self.as_raw_ColoredKinfu_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_colored_kinfu_Params_getPropIntr_const(self.as_raw_ColoredKinfu_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [intr](ColoredKinfu_ParamsTraitConst::intr), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_intr(&self) -> Result<core::Matx33f> {
+		crate::core::validity::check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params())?;
+		Ok(ColoredKinfu_ParamsTraitConst::intr(self))
+	}
+	
 	/// rgb camera intrinsics
 	#[inline]
 	fn rgb_intr(&self) -> core::Matx33f {
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_colored_kinfu_Params_getPropRgb_intr_const(self.as_raw_ColoredKinfu_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_colored_kinfu_Params_getPropRgb_intr_const(self.as_raw_ColoredKinfu_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [rgb_intr](ColoredKinfu_ParamsTraitConst::rgb_intr), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_rgb_intr(&self) -> Result<core::Matx33f> {
+		crate::core::validity::check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params())?;
+		Ok(ColoredKinfu_ParamsTraitConst::rgb_intr(self))
+	}
+	
 	/// pre-scale per 1 meter for input values
 	/// 
 	/// Typical values are:
@@ -562,141 +696,294 @@ pub trait ColoredKinfu_ParamsTraitConst {
 	///      * 1 per 1 meter for the 32-bit float images in the ROS bag files
 	#[inline]
 	fn depth_factor(&self) -> f32 {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_getPropDepthFactor_const(self.as_raw_ColoredKinfu_Params()) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_getPropDepthFactor_const(self.as_raw_ColoredKinfu_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [depth_factor](ColoredKinfu_ParamsTraitConst::depth_factor), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_depth_factor(&self) -> Result<f32> {
+		crate::core::validity::check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params())?;
+		Ok(ColoredKinfu_ParamsTraitConst::depth_factor(self))
+	}
+	
 	/// Depth sigma in meters for bilateral smooth
 	#[inline]
 	fn bilateral_sigma_depth(&self) -> f32 {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_getPropBilateral_sigma_depth_const(self.as_raw_ColoredKinfu_Params()) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_getPropBilateral_sigma_depth_const(self.as_raw_ColoredKinfu_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [bilateral_sigma_depth](ColoredKinfu_ParamsTraitConst::bilateral_sigma_depth), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_bilateral_sigma_depth(&self) -> Result<f32> {
+		crate::core::validity::check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params())?;
+		Ok(ColoredKinfu_ParamsTraitConst::bilateral_sigma_depth(self))
+	}
+	
 	/// Spatial sigma in pixels for bilateral smooth
 	#[inline]
 	fn bilateral_sigma_spatial(&self) -> f32 {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_getPropBilateral_sigma_spatial_const(self.as_raw_ColoredKinfu_Params()) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_getPropBilateral_sigma_spatial_const(self.as_raw_ColoredKinfu_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [bilateral_sigma_spatial](ColoredKinfu_ParamsTraitConst::bilateral_sigma_spatial), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_bilateral_sigma_spatial(&self) -> Result<f32> {
+		crate::core::validity::check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params())?;
+		Ok(ColoredKinfu_ParamsTraitConst::bilateral_sigma_spatial(self))
+	}
+	
 	/// Kernel size in pixels for bilateral smooth
 	#[inline]
 	fn bilateral_kernel_size(&self) -> i32 {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_getPropBilateral_kernel_size_const(self.as_raw_ColoredKinfu_Params()) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_getPropBilateral_kernel_size_const(self.as_raw_ColoredKinfu_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [bilateral_kernel_size](ColoredKinfu_ParamsTraitConst::bilateral_kernel_size), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_bilateral_kernel_size(&self) -> Result<i32> {
+		crate::core::validity::check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params())?;
+		Ok(ColoredKinfu_ParamsTraitConst::bilateral_kernel_size(self))
+	}
+	
 	/// Number of pyramid levels for ICP
 	#[inline]
 	fn pyramid_levels(&self) -> i32 {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_getPropPyramidLevels_const(self.as_raw_ColoredKinfu_Params()) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_getPropPyramidLevels_const(self.as_raw_ColoredKinfu_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [pyramid_levels](ColoredKinfu_ParamsTraitConst::pyramid_levels), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pyramid_levels(&self) -> Result<i32> {
+		crate::core::validity::check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params())?;
+		Ok(ColoredKinfu_ParamsTraitConst::pyramid_levels(self))
+	}
+	
 	/// Resolution of voxel space
 	/// 
 	/// Number of voxels in each dimension.
 	#[inline]
 	fn volume_dims(&self) -> core::Vec3i {
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_colored_kinfu_Params_getPropVolumeDims_const(self.as_raw_ColoredKinfu_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_colored_kinfu_Params_getPropVolumeDims_const(self.as_raw_ColoredKinfu_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [volume_dims](ColoredKinfu_ParamsTraitConst::volume_dims), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_volume_dims(&self) -> Result<core::Vec3i> {
+		crate::core::validity::check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params())?;
+		Ok(ColoredKinfu_ParamsTraitConst::volume_dims(self))
+	}
+	
 	/// Size of voxel in meters
 	#[inline]
 	fn voxel_size(&self) -> f32 {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_getPropVoxelSize_const(self.as_raw_ColoredKinfu_Params()) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_getPropVoxelSize_const(self.as_raw_ColoredKinfu_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [voxel_size](ColoredKinfu_ParamsTraitConst::voxel_size), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_voxel_size(&self) -> Result<f32> {
+		crate::core::validity::check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params())?;
+		Ok(ColoredKinfu_ParamsTraitConst::voxel_size(self))
+	}
+	
 	/// Minimal camera movement in meters
 	/// 
 	/// Integrate new depth frame only if camera movement exceeds this value.
 	#[inline]
 	fn tsdf_min_camera_movement(&self) -> f32 {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_getPropTsdf_min_camera_movement_const(self.as_raw_ColoredKinfu_Params()) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_getPropTsdf_min_camera_movement_const(self.as_raw_ColoredKinfu_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [tsdf_min_camera_movement](ColoredKinfu_ParamsTraitConst::tsdf_min_camera_movement), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_tsdf_min_camera_movement(&self) -> Result<f32> {
+		crate::core::validity::check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params())?;
+		Ok(ColoredKinfu_ParamsTraitConst::tsdf_min_camera_movement(self))
+	}
+	
 	/// initial volume pose in meters
 	#[inline]
 	fn volume_pose(&self) -> core::Affine3f {
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_colored_kinfu_Params_getPropVolumePose_const(self.as_raw_ColoredKinfu_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_colored_kinfu_Params_getPropVolumePose_const(self.as_raw_ColoredKinfu_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [volume_pose](ColoredKinfu_ParamsTraitConst::volume_pose), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_volume_pose(&self) -> Result<core::Affine3f> {
+		crate::core::validity::check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params())?;
+		Ok(ColoredKinfu_ParamsTraitConst::volume_pose(self))
+	}
+	
 	/// distance to truncate in meters
 	/// 
 	/// Distances to surface that exceed this value will be truncated to 1.0.
 	#[inline]
 	fn tsdf_trunc_dist(&self) -> f32 {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_getPropTsdf_trunc_dist_const(self.as_raw_ColoredKinfu_Params()) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_getPropTsdf_trunc_dist_const(self.as_raw_ColoredKinfu_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [tsdf_trunc_dist](ColoredKinfu_ParamsTraitConst::tsdf_trunc_dist), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_tsdf_trunc_dist(&self) -> Result<f32> {
+		crate::core::validity::check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params())?;
+		Ok(ColoredKinfu_ParamsTraitConst::tsdf_trunc_dist(self))
+	}
+	
 	/// max number of frames per voxel
 	/// 
 	/// Each voxel keeps running average of distances no longer than this value.
 	#[inline]
 	fn tsdf_max_weight(&self) -> i32 {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_getPropTsdf_max_weight_const(self.as_raw_ColoredKinfu_Params()) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_getPropTsdf_max_weight_const(self.as_raw_ColoredKinfu_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [tsdf_max_weight](ColoredKinfu_ParamsTraitConst::tsdf_max_weight), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_tsdf_max_weight(&self) -> Result<i32> {
+		crate::core::validity::check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params())?;
+		Ok(ColoredKinfu_ParamsTraitConst::tsdf_max_weight(self))
+	}
+	
 	/// A length of one raycast step
 	/// 
 	/// How much voxel sizes we skip each raycast step
 	#[inline]
 	fn raycast_step_factor(&self) -> f32 {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_getPropRaycast_step_factor_const(self.as_raw_ColoredKinfu_Params()) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_getPropRaycast_step_factor_const(self.as_raw_ColoredKinfu_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [raycast_step_factor](ColoredKinfu_ParamsTraitConst::raycast_step_factor), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_raycast_step_factor(&self) -> Result<f32> {
+		crate::core::validity::check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params())?;
+		Ok(ColoredKinfu_ParamsTraitConst::raycast_step_factor(self))
+	}
+	
 	/// light pose for rendering in meters
 	#[inline]
 	fn light_pose(&self) -> core::Vec3f {
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_colored_kinfu_Params_getPropLightPose_const(self.as_raw_ColoredKinfu_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_colored_kinfu_Params_getPropLightPose_const(self.as_raw_ColoredKinfu_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [light_pose](ColoredKinfu_ParamsTraitConst::light_pose), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_light_pose(&self) -> Result<core::Vec3f> {
+		crate::core::validity::check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params())?;
+		Ok(ColoredKinfu_ParamsTraitConst::light_pose(self))
+	}
+	
 	/// distance theshold for ICP in meters
 	#[inline]
 	fn icp_dist_thresh(&self) -> f32 {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_getPropIcpDistThresh_const(self.as_raw_ColoredKinfu_Params()) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_getPropIcpDistThresh_const(self.as_raw_ColoredKinfu_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [icp_dist_thresh](ColoredKinfu_ParamsTraitConst::icp_dist_thresh), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_icp_dist_thresh(&self) -> Result<f32> {
+		crate::core::validity::check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params())?;
+		Ok(ColoredKinfu_ParamsTraitConst::icp_dist_thresh(self))
+	}
+	
 	/// angle threshold for ICP in radians
 	#[inline]
 	fn icp_angle_thresh(&self) -> f32 {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_getPropIcpAngleThresh_const(self.as_raw_ColoredKinfu_Params()) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_getPropIcpAngleThresh_const(self.as_raw_ColoredKinfu_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [icp_angle_thresh](ColoredKinfu_ParamsTraitConst::icp_angle_thresh), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_icp_angle_thresh(&self) -> Result<f32> {
+		crate::core::validity::check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params())?;
+		Ok(ColoredKinfu_ParamsTraitConst::icp_angle_thresh(self))
+	}
+	
 	/// number of ICP iterations for each pyramid level
 	#[inline]
 	fn icp_iterations(&self) -> core::Vector<i32> {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_getPropIcpIterations_const(self.as_raw_ColoredKinfu_Params()) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_getPropIcpIterations_const(self.as_raw_ColoredKinfu_Params())) };
 		let ret = unsafe { core::Vector::<i32>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [icp_iterations](ColoredKinfu_ParamsTraitConst::icp_iterations), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_icp_iterations(&self) -> Result<core::Vector<i32>> {
+		crate::core::validity::check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params())?;
+		Ok(ColoredKinfu_ParamsTraitConst::icp_iterations(self))
+	}
+	
 	/// Threshold for depth truncation in meters
 	/// 
 	/// All depth values beyond this threshold will be set to zero
 	#[inline]
 	fn truncate_threshold(&self) -> f32 {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_getPropTruncateThreshold_const(self.as_raw_ColoredKinfu_Params()) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_getPropTruncateThreshold_const(self.as_raw_ColoredKinfu_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [truncate_threshold](ColoredKinfu_ParamsTraitConst::truncate_threshold), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_truncate_threshold(&self) -> Result<f32> {
+		crate::core::validity::check("ColoredKinfu_Params", self.as_raw_ColoredKinfu_Params())?;
+		Ok(ColoredKinfu_ParamsTraitConst::truncate_threshold(self))
+	}
+	
 }
 
 pub trait ColoredKinfu_ParamsTrait: crate::rgbd::ColoredKinfu_ParamsTraitConst {
@@ -705,34 +992,39 @@ pub trait ColoredKinfu_ParamsTrait: crate::rgbd::ColoredKinfu_ParamsTraitConst {
 	/// frame size in pixels
 	#[inline]
 	fn set_frame_size(&mut self, val: core::Size) {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_setPropFrameSize_Size(self.as_raw_mut_ColoredKinfu_Params(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_mut_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_setPropFrameSize_Size(self.as_raw_mut_ColoredKinfu_Params(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	/// rgb frame size in pixels
 	#[inline]
 	fn set_rgb_frame_size(&mut self, val: core::Size) {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_setPropRgb_frameSize_Size(self.as_raw_mut_ColoredKinfu_Params(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_mut_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_setPropRgb_frameSize_Size(self.as_raw_mut_ColoredKinfu_Params(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_volume_type(&mut self, val: crate::rgbd::Kinfu_VolumeType) {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_setPropVolumeType_VolumeType(self.as_raw_mut_ColoredKinfu_Params(), val) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_mut_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_setPropVolumeType_VolumeType(self.as_raw_mut_ColoredKinfu_Params(), val)) };
 		ret
 	}
 	
 	/// camera intrinsics
 	#[inline]
 	fn set_intr(&mut self, val: core::Matx33f) {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_setPropIntr_Matx33f(self.as_raw_mut_ColoredKinfu_Params(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_mut_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_setPropIntr_Matx33f(self.as_raw_mut_ColoredKinfu_Params(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	/// rgb camera intrinsics
 	#[inline]
 	fn set_rgb_intr(&mut self, val: core::Matx33f) {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_setPropRgb_intr_Matx33f(self.as_raw_mut_ColoredKinfu_Params(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_mut_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_setPropRgb_intr_Matx33f(self.as_raw_mut_ColoredKinfu_Params(), val.opencv_as_extern())) };
 		ret
 	}
 	
@@ -744,35 +1036,40 @@ pub trait ColoredKinfu_ParamsTrait: crate::rgbd::ColoredKinfu_ParamsTraitConst {
 	///      * 1 per 1 meter for the 32-bit float images in the ROS bag files
 	#[inline]
 	fn set_depth_factor(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_setPropDepthFactor_float(self.as_raw_mut_ColoredKinfu_Params(), val) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_mut_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_setPropDepthFactor_float(self.as_raw_mut_ColoredKinfu_Params(), val)) };
 		ret
 	}
 	
 	/// Depth sigma in meters for bilateral smooth
 	#[inline]
 	fn set_bilateral_sigma_depth(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_setPropBilateral_sigma_depth_float(self.as_raw_mut_ColoredKinfu_Params(), val) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_mut_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_setPropBilateral_sigma_depth_float(self.as_raw_mut_ColoredKinfu_Params(), val)) };
 		ret
 	}
 	
 	/// Spatial sigma in pixels for bilateral smooth
 	#[inline]
 	fn set_bilateral_sigma_spatial(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_setPropBilateral_sigma_spatial_float(self.as_raw_mut_ColoredKinfu_Params(), val) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_mut_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_setPropBilateral_sigma_spatial_float(self.as_raw_mut_ColoredKinfu_Params(), val)) };
 		ret
 	}
 	
 	/// Kernel size in pixels for bilateral smooth
 	#[inline]
 	fn set_bilateral_kernel_size(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_setPropBilateral_kernel_size_int(self.as_raw_mut_ColoredKinfu_Params(), val) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_mut_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_setPropBilateral_kernel_size_int(self.as_raw_mut_ColoredKinfu_Params(), val)) };
 		ret
 	}
 	
 	/// Number of pyramid levels for ICP
 	#[inline]
 	fn set_pyramid_levels(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_setPropPyramidLevels_int(self.as_raw_mut_ColoredKinfu_Params(), val) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_mut_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_setPropPyramidLevels_int(self.as_raw_mut_ColoredKinfu_Params(), val)) };
 		ret
 	}
 	
@@ -781,14 +1078,16 @@ pub trait ColoredKinfu_ParamsTrait: crate::rgbd::ColoredKinfu_ParamsTraitConst {
 	/// Number of voxels in each dimension.
 	#[inline]
 	fn set_volume_dims(&mut self, val: core::Vec3i) {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_setPropVolumeDims_Vec3i(self.as_raw_mut_ColoredKinfu_Params(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_mut_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_setPropVolumeDims_Vec3i(self.as_raw_mut_ColoredKinfu_Params(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	/// Size of voxel in meters
 	#[inline]
 	fn set_voxel_size(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_setPropVoxelSize_float(self.as_raw_mut_ColoredKinfu_Params(), val) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_mut_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_setPropVoxelSize_float(self.as_raw_mut_ColoredKinfu_Params(), val)) };
 		ret
 	}
 	
@@ -797,14 +1096,16 @@ pub trait ColoredKinfu_ParamsTrait: crate::rgbd::ColoredKinfu_ParamsTraitConst {
 	/// Integrate new depth frame only if camera movement exceeds this value.
 	#[inline]
 	fn set_tsdf_min_camera_movement(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_setPropTsdf_min_camera_movement_float(self.as_raw_mut_ColoredKinfu_Params(), val) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_mut_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_setPropTsdf_min_camera_movement_float(self.as_raw_mut_ColoredKinfu_Params(), val)) };
 		ret
 	}
 	
 	/// initial volume pose in meters
 	#[inline]
 	fn set_volume_pose(&mut self, val: core::Affine3f) {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_setPropVolumePose_Affine3f(self.as_raw_mut_ColoredKinfu_Params(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_mut_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_setPropVolumePose_Affine3f(self.as_raw_mut_ColoredKinfu_Params(), val.opencv_as_extern())) };
 		ret
 	}
 	
@@ -813,7 +1114,8 @@ pub trait ColoredKinfu_ParamsTrait: crate::rgbd::ColoredKinfu_ParamsTraitConst {
 	/// Distances to surface that exceed this value will be truncated to 1.0.
 	#[inline]
 	fn set_tsdf_trunc_dist(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_setPropTsdf_trunc_dist_float(self.as_raw_mut_ColoredKinfu_Params(), val) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_mut_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_setPropTsdf_trunc_dist_float(self.as_raw_mut_ColoredKinfu_Params(), val)) };
 		ret
 	}
 	
@@ -822,7 +1124,8 @@ pub trait ColoredKinfu_ParamsTrait: crate::rgbd::ColoredKinfu_ParamsTraitConst {
 	/// Each voxel keeps running average of distances no longer than this value.
 	#[inline]
 	fn set_tsdf_max_weight(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_setPropTsdf_max_weight_int(self.as_raw_mut_ColoredKinfu_Params(), val) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_mut_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_setPropTsdf_max_weight_int(self.as_raw_mut_ColoredKinfu_Params(), val)) };
 		ret
 	}
 	
@@ -831,35 +1134,40 @@ pub trait ColoredKinfu_ParamsTrait: crate::rgbd::ColoredKinfu_ParamsTraitConst {
 	/// How much voxel sizes we skip each raycast step
 	#[inline]
 	fn set_raycast_step_factor(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_setPropRaycast_step_factor_float(self.as_raw_mut_ColoredKinfu_Params(), val) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_mut_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_setPropRaycast_step_factor_float(self.as_raw_mut_ColoredKinfu_Params(), val)) };
 		ret
 	}
 	
 	/// light pose for rendering in meters
 	#[inline]
 	fn set_light_pose(&mut self, val: core::Vec3f) {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_setPropLightPose_Vec3f(self.as_raw_mut_ColoredKinfu_Params(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_mut_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_setPropLightPose_Vec3f(self.as_raw_mut_ColoredKinfu_Params(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	/// distance theshold for ICP in meters
 	#[inline]
 	fn set_icp_dist_thresh(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_setPropIcpDistThresh_float(self.as_raw_mut_ColoredKinfu_Params(), val) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_mut_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_setPropIcpDistThresh_float(self.as_raw_mut_ColoredKinfu_Params(), val)) };
 		ret
 	}
 	
 	/// angle threshold for ICP in radians
 	#[inline]
 	fn set_icp_angle_thresh(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_setPropIcpAngleThresh_float(self.as_raw_mut_ColoredKinfu_Params(), val) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_mut_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_setPropIcpAngleThresh_float(self.as_raw_mut_ColoredKinfu_Params(), val)) };
 		ret
 	}
 	
 	/// number of ICP iterations for each pyramid level
 	#[inline]
 	fn set_icp_iterations(&mut self, mut val: core::Vector<i32>) {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_setPropIcpIterations_vector_int_(self.as_raw_mut_ColoredKinfu_Params(), val.as_raw_mut_VectorOfi32()) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_mut_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_setPropIcpIterations_vector_int_(self.as_raw_mut_ColoredKinfu_Params(), val.as_raw_mut_VectorOfi32())) };
 		ret
 	}
 	
@@ -868,7 +1176,8 @@ pub trait ColoredKinfu_ParamsTrait: crate::rgbd::ColoredKinfu_ParamsTraitConst {
 	/// All depth values beyond this threshold will be set to zero
 	#[inline]
 	fn set_truncate_threshold(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_colored_kinfu_Params_setPropTruncateThreshold_float(self.as_raw_mut_ColoredKinfu_Params(), val) };
+		crate::core::validity::debug_check("ColoredKinfu_Params", self.as_raw_mut_ColoredKinfu_Params());
+		let ret = unsafe { ffi_call!(cv_colored_kinfu_Params_setPropTruncateThreshold_float(self.as_raw_mut_ColoredKinfu_Params(), val)) };
 		ret
 	}
 	
@@ -879,8 +1188,9 @@ pub trait ColoredKinfu_ParamsTrait: crate::rgbd::ColoredKinfu_ParamsTraitConst {
 	/// * t: translation vector
 	#[inline]
 	fn set_initial_volume_pose(&mut self, r: core::Matx33f, t: core::Vec3f) -> Result<()> {
+		crate::core::validity::check("ColoredKinfu_Params", self.as_raw_mut_ColoredKinfu_Params())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_colored_kinfu_Params_setInitialVolumePose_Matx33f_Vec3f(self.as_raw_mut_ColoredKinfu_Params(), r.opencv_as_extern(), t.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_colored_kinfu_Params_setInitialVolumePose_Matx33f_Vec3f(self.as_raw_mut_ColoredKinfu_Params(), r.opencv_as_extern(), t.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -892,8 +1202,9 @@ pub trait ColoredKinfu_ParamsTrait: crate::rgbd::ColoredKinfu_ParamsTraitConst {
 	/// * homogen_tf: 4 by 4 Homogeneous Transform matrix to set the intial pose of TSDF volume
 	#[inline]
 	fn set_initial_volume_pose_1(&mut self, homogen_tf: core::Matx44f) -> Result<()> {
+		crate::core::validity::check("ColoredKinfu_Params", self.as_raw_mut_ColoredKinfu_Params())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_colored_kinfu_Params_setInitialVolumePose_Matx44f(self.as_raw_mut_ColoredKinfu_Params(), homogen_tf.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_colored_kinfu_Params_setInitialVolumePose_Matx44f(self.as_raw_mut_ColoredKinfu_Params(), homogen_tf.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -910,7 +1221,10 @@ opencv_type_boxed! { ColoredKinfu_Params }
 impl Drop for ColoredKinfu_Params {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ColoredKinfu_Params_delete(instance: *mut c_void); }
-		unsafe { cv_ColoredKinfu_Params_delete(self.as_raw_mut_ColoredKinfu_Params()) };
+		let ptr = self.as_raw_mut_ColoredKinfu_Params();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ColoredKinfu_Params_delete(ptr) };
 	}
 }
 
@@ -928,7 +1242,7 @@ impl ColoredKinfu_Params {
 	#[inline]
 	pub fn default() -> Result<crate::rgbd::ColoredKinfu_Params> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_colored_kinfu_Params_Params(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_colored_kinfu_Params_Params(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::ColoredKinfu_Params::opencv_from_extern(ret) };
@@ -943,7 +1257,7 @@ impl ColoredKinfu_Params {
 	#[inline]
 	pub fn new(volume_initial_pose_rot: core::Matx33f, volume_initial_pose_transl: core::Vec3f) -> Result<crate::rgbd::ColoredKinfu_Params> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_colored_kinfu_Params_Params_Matx33f_Vec3f(volume_initial_pose_rot.opencv_as_extern(), volume_initial_pose_transl.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_colored_kinfu_Params_Params_Matx33f_Vec3f(volume_initial_pose_rot.opencv_as_extern(), volume_initial_pose_transl.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::ColoredKinfu_Params::opencv_from_extern(ret) };
@@ -957,7 +1271,7 @@ impl ColoredKinfu_Params {
 	#[inline]
 	pub fn new_1(volume_initial_pose: core::Matx44f) -> Result<crate::rgbd::ColoredKinfu_Params> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_colored_kinfu_Params_Params_Matx44f(volume_initial_pose.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_colored_kinfu_Params_Params_Matx44f(volume_initial_pose.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::ColoredKinfu_Params::opencv_from_extern(ret) };
@@ -969,7 +1283,7 @@ impl ColoredKinfu_Params {
 	#[inline]
 	pub fn default_params() -> Result<core::Ptr<crate::rgbd::ColoredKinfu_Params>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_colored_kinfu_Params_defaultParams(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_colored_kinfu_Params_defaultParams(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::rgbd::ColoredKinfu_Params>::opencv_from_extern(ret) };
@@ -982,7 +1296,7 @@ impl ColoredKinfu_Params {
 	#[inline]
 	pub fn coarse_params() -> Result<core::Ptr<crate::rgbd::ColoredKinfu_Params>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_colored_kinfu_Params_coarseParams(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_colored_kinfu_Params_coarseParams(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::rgbd::ColoredKinfu_Params>::opencv_from_extern(ret) };
@@ -994,7 +1308,7 @@ impl ColoredKinfu_Params {
 	#[inline]
 	pub fn hash_tsdf_params(is_coarse: bool) -> Result<core::Ptr<crate::rgbd::ColoredKinfu_Params>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_colored_kinfu_Params_hashTSDFParams_bool(is_coarse, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_colored_kinfu_Params_hashTSDFParams_bool(is_coarse, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::rgbd::ColoredKinfu_Params>::opencv_from_extern(ret) };
@@ -1006,7 +1320,7 @@ impl ColoredKinfu_Params {
 	#[inline]
 	pub fn colored_tsdf_params(is_coarse: bool) -> Result<core::Ptr<crate::rgbd::ColoredKinfu_Params>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_colored_kinfu_Params_coloredTSDFParams_bool(is_coarse, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_colored_kinfu_Params_coloredTSDFParams_bool(is_coarse, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::rgbd::ColoredKinfu_Params>::opencv_from_extern(ret) };
@@ -1021,8 +1335,9 @@ pub trait Dynafu_DynaFuConst {
 	/// Get current parameters
 	#[inline]
 	fn get_params(&self) -> Result<crate::rgbd::Kinfu_Params> {
+		crate::core::validity::check("Dynafu_DynaFu", self.as_raw_Dynafu_DynaFu())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dynafu_DynaFu_getParams_const(self.as_raw_Dynafu_DynaFu(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dynafu_DynaFu_getParams_const(self.as_raw_Dynafu_DynaFu(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::Kinfu_Params::opencv_from_extern(ret) };
@@ -1043,9 +1358,10 @@ pub trait Dynafu_DynaFuConst {
 	/// * camera_pose: Matx44f::eye()
 	#[inline]
 	fn render(&self, image: &mut dyn core::ToOutputArray, camera_pose: core::Matx44f) -> Result<()> {
+		crate::core::validity::check("Dynafu_DynaFu", self.as_raw_Dynafu_DynaFu())?;
 		output_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dynafu_DynaFu_render_const_const__OutputArrayR_const_Matx44fR(self.as_raw_Dynafu_DynaFu(), image.as_raw__OutputArray(), &camera_pose, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dynafu_DynaFu_render_const_const__OutputArrayR_const_Matx44fR(self.as_raw_Dynafu_DynaFu(), image.as_raw__OutputArray(), &camera_pose, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1061,10 +1377,11 @@ pub trait Dynafu_DynaFuConst {
 	/// * normals: vector of normals which are 4-float vectors
 	#[inline]
 	fn get_cloud(&self, points: &mut dyn core::ToOutputArray, normals: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("Dynafu_DynaFu", self.as_raw_Dynafu_DynaFu())?;
 		output_array_arg!(points);
 		output_array_arg!(normals);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dynafu_DynaFu_getCloud_const_const__OutputArrayR_const__OutputArrayR(self.as_raw_Dynafu_DynaFu(), points.as_raw__OutputArray(), normals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dynafu_DynaFu_getCloud_const_const__OutputArrayR_const__OutputArrayR(self.as_raw_Dynafu_DynaFu(), points.as_raw__OutputArray(), normals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1078,9 +1395,10 @@ pub trait Dynafu_DynaFuConst {
 	/// * points: vector of points which are 4-float vectors
 	#[inline]
 	fn get_points(&self, points: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("Dynafu_DynaFu", self.as_raw_Dynafu_DynaFu())?;
 		output_array_arg!(points);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dynafu_DynaFu_getPoints_const_const__OutputArrayR(self.as_raw_Dynafu_DynaFu(), points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dynafu_DynaFu_getPoints_const_const__OutputArrayR(self.as_raw_Dynafu_DynaFu(), points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1092,10 +1410,11 @@ pub trait Dynafu_DynaFuConst {
 	/// * normals: output vector of corresponding normals which are 4-float vectors
 	#[inline]
 	fn get_normals(&self, points: &dyn core::ToInputArray, normals: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("Dynafu_DynaFu", self.as_raw_Dynafu_DynaFu())?;
 		input_array_arg!(points);
 		output_array_arg!(normals);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dynafu_DynaFu_getNormals_const_const__InputArrayR_const__OutputArrayR(self.as_raw_Dynafu_DynaFu(), points.as_raw__InputArray(), normals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dynafu_DynaFu_getNormals_const_const__InputArrayR_const__OutputArrayR(self.as_raw_Dynafu_DynaFu(), points.as_raw__InputArray(), normals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1104,8 +1423,9 @@ pub trait Dynafu_DynaFuConst {
 	/// Get current pose in voxel space
 	#[inline]
 	fn get_pose(&self) -> Result<core::Affine3f> {
+		crate::core::validity::check("Dynafu_DynaFu", self.as_raw_Dynafu_DynaFu())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dynafu_DynaFu_getPose_const(self.as_raw_Dynafu_DynaFu(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dynafu_DynaFu_getPose_const(self.as_raw_Dynafu_DynaFu(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1113,8 +1433,9 @@ pub trait Dynafu_DynaFuConst {
 	
 	#[inline]
 	fn get_nodes_pos(&self) -> Result<core::Vector<core::Point3f>> {
+		crate::core::validity::check("Dynafu_DynaFu", self.as_raw_Dynafu_DynaFu())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dynafu_DynaFu_getNodesPos_const(self.as_raw_Dynafu_DynaFu(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dynafu_DynaFu_getNodesPos_const(self.as_raw_Dynafu_DynaFu(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<core::Point3f>::opencv_from_extern(ret) };
@@ -1123,10 +1444,11 @@ pub trait Dynafu_DynaFuConst {
 	
 	#[inline]
 	fn march_cubes(&self, vertices: &mut dyn core::ToOutputArray, edges: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("Dynafu_DynaFu", self.as_raw_Dynafu_DynaFu())?;
 		output_array_arg!(vertices);
 		output_array_arg!(edges);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dynafu_DynaFu_marchCubes_const_const__OutputArrayR_const__OutputArrayR(self.as_raw_Dynafu_DynaFu(), vertices.as_raw__OutputArray(), edges.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dynafu_DynaFu_marchCubes_const_const__OutputArrayR_const__OutputArrayR(self.as_raw_Dynafu_DynaFu(), vertices.as_raw__OutputArray(), edges.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1142,8 +1464,9 @@ pub trait Dynafu_DynaFu: crate::rgbd::Dynafu_DynaFuConst {
 	/// Clears current model and resets a pose.
 	#[inline]
 	fn reset(&mut self) -> Result<()> {
+		crate::core::validity::check("Dynafu_DynaFu", self.as_raw_mut_Dynafu_DynaFu())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dynafu_DynaFu_reset(self.as_raw_mut_Dynafu_DynaFu(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dynafu_DynaFu_reset(self.as_raw_mut_Dynafu_DynaFu(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1160,9 +1483,10 @@ pub trait Dynafu_DynaFu: crate::rgbd::Dynafu_DynaFuConst {
 	/// true if succeeded to align new frame with current scene, false if opposite
 	#[inline]
 	fn update(&mut self, depth: &dyn core::ToInputArray) -> Result<bool> {
+		crate::core::validity::check("Dynafu_DynaFu", self.as_raw_mut_Dynafu_DynaFu())?;
 		input_array_arg!(depth);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dynafu_DynaFu_update_const__InputArrayR(self.as_raw_mut_Dynafu_DynaFu(), depth.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dynafu_DynaFu_update_const__InputArrayR(self.as_raw_mut_Dynafu_DynaFu(), depth.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1172,11 +1496,12 @@ pub trait Dynafu_DynaFu: crate::rgbd::Dynafu_DynaFuConst {
 	/// * warp: true
 	#[inline]
 	fn render_surface(&mut self, depth_image: &mut dyn core::ToOutputArray, vert_image: &mut dyn core::ToOutputArray, norm_image: &mut dyn core::ToOutputArray, warp: bool) -> Result<()> {
+		crate::core::validity::check("Dynafu_DynaFu", self.as_raw_mut_Dynafu_DynaFu())?;
 		output_array_arg!(depth_image);
 		output_array_arg!(vert_image);
 		output_array_arg!(norm_image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dynafu_DynaFu_renderSurface_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_bool(self.as_raw_mut_Dynafu_DynaFu(), depth_image.as_raw__OutputArray(), vert_image.as_raw__OutputArray(), norm_image.as_raw__OutputArray(), warp, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dynafu_DynaFu_renderSurface_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_bool(self.as_raw_mut_Dynafu_DynaFu(), depth_image.as_raw__OutputArray(), vert_image.as_raw__OutputArray(), norm_image.as_raw__OutputArray(), warp, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1188,7 +1513,7 @@ impl dyn Dynafu_DynaFu + '_ {
 	#[inline]
 	pub fn create(_params: &core::Ptr<crate::rgbd::Kinfu_Params>) -> Result<core::Ptr<dyn crate::rgbd::Dynafu_DynaFu>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dynafu_DynaFu_create_const_Ptr_Params_R(_params.as_raw_PtrOfKinfu_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_dynafu_DynaFu_create_const_Ptr_Params_R(_params.as_raw_PtrOfKinfu_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::rgbd::Dynafu_DynaFu>::opencv_from_extern(ret) };
@@ -1211,7 +1536,7 @@ impl Kinfu_Intr {
 	#[inline]
 	pub fn scale(self, pyr: i32) -> Result<crate::rgbd::Kinfu_Intr> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Intr_scale_const_int(self.opencv_as_extern(), pyr, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Intr_scale_const_int(self.opencv_as_extern(), pyr, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1220,7 +1545,7 @@ impl Kinfu_Intr {
 	#[inline]
 	pub fn make_reprojector(self) -> Result<crate::rgbd::Kinfu_Intr_Reprojector> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Intr_makeReprojector_const(self.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Intr_makeReprojector_const(self.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1229,7 +1554,7 @@ impl Kinfu_Intr {
 	#[inline]
 	pub fn make_projector(self) -> Result<crate::rgbd::Kinfu_Intr_Projector> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Intr_makeProjector_const(self.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Intr_makeProjector_const(self.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1238,7 +1563,7 @@ impl Kinfu_Intr {
 	#[inline]
 	pub fn get_mat(self) -> Result<core::Matx33f> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Intr_getMat_const(self.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Intr_getMat_const(self.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1247,7 +1572,7 @@ impl Kinfu_Intr {
 	#[inline]
 	pub fn default() -> Result<crate::rgbd::Kinfu_Intr> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Intr_Intr(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Intr_Intr(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1256,7 +1581,7 @@ impl Kinfu_Intr {
 	#[inline]
 	pub fn new(_fx: f32, _fy: f32, _cx: f32, _cy: f32) -> Result<crate::rgbd::Kinfu_Intr> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Intr_Intr_float_float_float_float(_fx, _fy, _cx, _cy, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Intr_Intr_float_float_float_float(_fx, _fy, _cx, _cy, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1265,7 +1590,7 @@ impl Kinfu_Intr {
 	#[inline]
 	pub fn new_1(m: core::Matx33f) -> Result<crate::rgbd::Kinfu_Intr> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Intr_Intr_Matx33f(m.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Intr_Intr_Matx33f(m.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1289,7 +1614,7 @@ impl Kinfu_Intr_Projector {
 	#[inline]
 	pub fn new(intr: crate::rgbd::Kinfu_Intr) -> Result<crate::rgbd::Kinfu_Intr_Projector> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Intr_Projector_Projector_Intr(intr.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Intr_Projector_Projector_Intr(intr.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1314,7 +1639,7 @@ impl Kinfu_Intr_Reprojector {
 	#[inline]
 	pub fn default() -> Result<crate::rgbd::Kinfu_Intr_Reprojector> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Intr_Reprojector_Reprojector(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Intr_Reprojector_Reprojector(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1323,7 +1648,7 @@ impl Kinfu_Intr_Reprojector {
 	#[inline]
 	pub fn new(intr: crate::rgbd::Kinfu_Intr) -> Result<crate::rgbd::Kinfu_Intr_Reprojector> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Intr_Reprojector_Reprojector_Intr(intr.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Intr_Reprojector_Reprojector_Intr(intr.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1360,8 +1685,9 @@ pub trait Kinfu_KinFuConst {
 	/// Get current parameters
 	#[inline]
 	fn get_params(&self) -> Result<crate::rgbd::Kinfu_Params> {
+		crate::core::validity::check("Kinfu_KinFu", self.as_raw_Kinfu_KinFu())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_KinFu_getParams_const(self.as_raw_Kinfu_KinFu(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_KinFu_getParams_const(self.as_raw_Kinfu_KinFu(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::Kinfu_Params::opencv_from_extern(ret) };
@@ -1377,9 +1703,10 @@ pub trait Kinfu_KinFuConst {
 	/// * image: resulting image
 	#[inline]
 	fn render(&self, image: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("Kinfu_KinFu", self.as_raw_Kinfu_KinFu())?;
 		output_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_KinFu_render_const_const__OutputArrayR(self.as_raw_Kinfu_KinFu(), image.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_KinFu_render_const_const__OutputArrayR(self.as_raw_Kinfu_KinFu(), image.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1396,9 +1723,10 @@ pub trait Kinfu_KinFuConst {
 	///   which is a last frame camera pose.
 	#[inline]
 	fn render_1(&self, image: &mut dyn core::ToOutputArray, camera_pose: core::Matx44f) -> Result<()> {
+		crate::core::validity::check("Kinfu_KinFu", self.as_raw_Kinfu_KinFu())?;
 		output_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_KinFu_render_const_const__OutputArrayR_const_Matx44fR(self.as_raw_Kinfu_KinFu(), image.as_raw__OutputArray(), &camera_pose, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_KinFu_render_const_const__OutputArrayR_const_Matx44fR(self.as_raw_Kinfu_KinFu(), image.as_raw__OutputArray(), &camera_pose, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1414,10 +1742,11 @@ pub trait Kinfu_KinFuConst {
 	/// * normals: vector of normals which are 4-float vectors
 	#[inline]
 	fn get_cloud(&self, points: &mut dyn core::ToOutputArray, normals: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("Kinfu_KinFu", self.as_raw_Kinfu_KinFu())?;
 		output_array_arg!(points);
 		output_array_arg!(normals);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_KinFu_getCloud_const_const__OutputArrayR_const__OutputArrayR(self.as_raw_Kinfu_KinFu(), points.as_raw__OutputArray(), normals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_KinFu_getCloud_const_const__OutputArrayR_const__OutputArrayR(self.as_raw_Kinfu_KinFu(), points.as_raw__OutputArray(), normals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1431,9 +1760,10 @@ pub trait Kinfu_KinFuConst {
 	/// * points: vector of points which are 4-float vectors
 	#[inline]
 	fn get_points(&self, points: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("Kinfu_KinFu", self.as_raw_Kinfu_KinFu())?;
 		output_array_arg!(points);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_KinFu_getPoints_const_const__OutputArrayR(self.as_raw_Kinfu_KinFu(), points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_KinFu_getPoints_const_const__OutputArrayR(self.as_raw_Kinfu_KinFu(), points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1445,10 +1775,11 @@ pub trait Kinfu_KinFuConst {
 	/// * normals: output vector of corresponding normals which are 4-float vectors
 	#[inline]
 	fn get_normals(&self, points: &dyn core::ToInputArray, normals: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("Kinfu_KinFu", self.as_raw_Kinfu_KinFu())?;
 		input_array_arg!(points);
 		output_array_arg!(normals);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_KinFu_getNormals_const_const__InputArrayR_const__OutputArrayR(self.as_raw_Kinfu_KinFu(), points.as_raw__InputArray(), normals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_KinFu_getNormals_const_const__InputArrayR_const__OutputArrayR(self.as_raw_Kinfu_KinFu(), points.as_raw__InputArray(), normals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1457,8 +1788,9 @@ pub trait Kinfu_KinFuConst {
 	/// Get current pose in voxel space
 	#[inline]
 	fn get_pose(&self) -> Result<core::Affine3f> {
+		crate::core::validity::check("Kinfu_KinFu", self.as_raw_Kinfu_KinFu())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_KinFu_getPose_const(self.as_raw_Kinfu_KinFu(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_KinFu_getPose_const(self.as_raw_Kinfu_KinFu(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1474,8 +1806,9 @@ pub trait Kinfu_KinFu: crate::rgbd::Kinfu_KinFuConst {
 	/// Clears current model and resets a pose.
 	#[inline]
 	fn reset(&mut self) -> Result<()> {
+		crate::core::validity::check("Kinfu_KinFu", self.as_raw_mut_Kinfu_KinFu())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_KinFu_reset(self.as_raw_mut_Kinfu_KinFu(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_KinFu_reset(self.as_raw_mut_Kinfu_KinFu(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1492,9 +1825,10 @@ pub trait Kinfu_KinFu: crate::rgbd::Kinfu_KinFuConst {
 	/// true if succeeded to align new frame with current scene, false if opposite
 	#[inline]
 	fn update(&mut self, depth: &dyn core::ToInputArray) -> Result<bool> {
+		crate::core::validity::check("Kinfu_KinFu", self.as_raw_mut_Kinfu_KinFu())?;
 		input_array_arg!(depth);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_KinFu_update_const__InputArrayR(self.as_raw_mut_Kinfu_KinFu(), depth.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_KinFu_update_const__InputArrayR(self.as_raw_mut_Kinfu_KinFu(), depth.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1506,7 +1840,7 @@ impl dyn Kinfu_KinFu + '_ {
 	#[inline]
 	pub fn create(_params: &core::Ptr<crate::rgbd::Kinfu_Params>) -> Result<core::Ptr<dyn crate::rgbd::Kinfu_KinFu>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_KinFu_create_const_Ptr_Params_R(_params.as_raw_PtrOfKinfu_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_KinFu_create_const_Ptr_Params_R(_params.as_raw_PtrOfKinfu_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::rgbd::Kinfu_KinFu>::opencv_from_extern(ret) };
@@ -1520,39 +1854,75 @@ pub trait Kinfu_ParamsTraitConst {
 	/// frame size in pixels
 	#[inline]
 	fn frame_size(&self) -> core::Size {
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_Kinfu_Params());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Params_getPropFrameSize_const(self.as_raw_Kinfu_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Params_getPropFrameSize_const(self.as_raw_Kinfu_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [frame_size](Kinfu_ParamsTraitConst::frame_size), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_frame_size(&self) -> Result<core::Size> {
+		crate::core::validity::check("Kinfu_Params", self.as_raw_Kinfu_Params())?;
+		Ok(Kinfu_ParamsTraitConst::frame_size(self))
+	}
+	
 	/// rgb frame size in pixels
 	#[inline]
 	fn volume_type(&self) -> crate::rgbd::Kinfu_VolumeType {
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_Kinfu_Params());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Params_getPropVolumeType_const(self.as_raw_Kinfu_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Params_getPropVolumeType_const(self.as_raw_Kinfu_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [volume_type](Kinfu_ParamsTraitConst::volume_type), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_volume_type(&self) -> Result<crate::rgbd::Kinfu_VolumeType> {
+		crate::core::validity::check("Kinfu_Params", self.as_raw_Kinfu_Params())?;
+		Ok(Kinfu_ParamsTraitConst::volume_type(self))
+	}
+	
 	/// camera intrinsics
 	#[inline]
 	fn intr(&self) -> core::Matx33f {
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_Kinfu_Params());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Params_getPropIntr_const(self.as_raw_Kinfu_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Params_getPropIntr_const(self.as_raw_Kinfu_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [intr](Kinfu_ParamsTraitConst::intr), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_intr(&self) -> Result<core::Matx33f> {
+		crate::core::validity::check("Kinfu_Params", self.as_raw_Kinfu_Params())?;
+		Ok(Kinfu_ParamsTraitConst::intr(self))
+	}
+	
 	/// rgb camera intrinsics
 	#[inline]
 	fn rgb_intr(&self) -> core::Matx33f {
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_Kinfu_Params());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Params_getPropRgb_intr_const(self.as_raw_Kinfu_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Params_getPropRgb_intr_const(self.as_raw_Kinfu_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [rgb_intr](Kinfu_ParamsTraitConst::rgb_intr), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_rgb_intr(&self) -> Result<core::Matx33f> {
+		crate::core::validity::check("Kinfu_Params", self.as_raw_Kinfu_Params())?;
+		Ok(Kinfu_ParamsTraitConst::rgb_intr(self))
+	}
+	
 	/// pre-scale per 1 meter for input values
 	/// 
 	/// Typical values are:
@@ -1561,141 +1931,294 @@ pub trait Kinfu_ParamsTraitConst {
 	///      * 1 per 1 meter for the 32-bit float images in the ROS bag files
 	#[inline]
 	fn depth_factor(&self) -> f32 {
-		let ret = unsafe { sys::cv_kinfu_Params_getPropDepthFactor_const(self.as_raw_Kinfu_Params()) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_getPropDepthFactor_const(self.as_raw_Kinfu_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [depth_factor](Kinfu_ParamsTraitConst::depth_factor), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_depth_factor(&self) -> Result<f32> {
+		crate::core::validity::check("Kinfu_Params", self.as_raw_Kinfu_Params())?;
+		Ok(Kinfu_ParamsTraitConst::depth_factor(self))
+	}
+	
 	/// Depth sigma in meters for bilateral smooth
 	#[inline]
 	fn bilateral_sigma_depth(&self) -> f32 {
-		let ret = unsafe { sys::cv_kinfu_Params_getPropBilateral_sigma_depth_const(self.as_raw_Kinfu_Params()) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_getPropBilateral_sigma_depth_const(self.as_raw_Kinfu_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [bilateral_sigma_depth](Kinfu_ParamsTraitConst::bilateral_sigma_depth), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_bilateral_sigma_depth(&self) -> Result<f32> {
+		crate::core::validity::check("Kinfu_Params", self.as_raw_Kinfu_Params())?;
+		Ok(Kinfu_ParamsTraitConst::bilateral_sigma_depth(self))
+	}
+	
 	/// Spatial sigma in pixels for bilateral smooth
 	#[inline]
 	fn bilateral_sigma_spatial(&self) -> f32 {
-		let ret = unsafe { sys::cv_kinfu_Params_getPropBilateral_sigma_spatial_const(self.as_raw_Kinfu_Params()) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_getPropBilateral_sigma_spatial_const(self.as_raw_Kinfu_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [bilateral_sigma_spatial](Kinfu_ParamsTraitConst::bilateral_sigma_spatial), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_bilateral_sigma_spatial(&self) -> Result<f32> {
+		crate::core::validity::check("Kinfu_Params", self.as_raw_Kinfu_Params())?;
+		Ok(Kinfu_ParamsTraitConst::bilateral_sigma_spatial(self))
+	}
+	
 	/// Kernel size in pixels for bilateral smooth
 	#[inline]
 	fn bilateral_kernel_size(&self) -> i32 {
-		let ret = unsafe { sys::cv_kinfu_Params_getPropBilateral_kernel_size_const(self.as_raw_Kinfu_Params()) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_getPropBilateral_kernel_size_const(self.as_raw_Kinfu_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [bilateral_kernel_size](Kinfu_ParamsTraitConst::bilateral_kernel_size), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_bilateral_kernel_size(&self) -> Result<i32> {
+		crate::core::validity::check("Kinfu_Params", self.as_raw_Kinfu_Params())?;
+		Ok(Kinfu_ParamsTraitConst::bilateral_kernel_size(self))
+	}
+	
 	/// Number of pyramid levels for ICP
 	#[inline]
 	fn pyramid_levels(&self) -> i32 {
-		let ret = unsafe { sys::cv_kinfu_Params_getPropPyramidLevels_const(self.as_raw_Kinfu_Params()) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_getPropPyramidLevels_const(self.as_raw_Kinfu_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [pyramid_levels](Kinfu_ParamsTraitConst::pyramid_levels), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pyramid_levels(&self) -> Result<i32> {
+		crate::core::validity::check("Kinfu_Params", self.as_raw_Kinfu_Params())?;
+		Ok(Kinfu_ParamsTraitConst::pyramid_levels(self))
+	}
+	
 	/// Resolution of voxel space
 	/// 
 	/// Number of voxels in each dimension.
 	#[inline]
 	fn volume_dims(&self) -> core::Vec3i {
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_Kinfu_Params());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Params_getPropVolumeDims_const(self.as_raw_Kinfu_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Params_getPropVolumeDims_const(self.as_raw_Kinfu_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [volume_dims](Kinfu_ParamsTraitConst::volume_dims), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_volume_dims(&self) -> Result<core::Vec3i> {
+		crate::core::validity::check("Kinfu_Params", self.as_raw_Kinfu_Params())?;
+		Ok(Kinfu_ParamsTraitConst::volume_dims(self))
+	}
+	
 	/// Size of voxel in meters
 	#[inline]
 	fn voxel_size(&self) -> f32 {
-		let ret = unsafe { sys::cv_kinfu_Params_getPropVoxelSize_const(self.as_raw_Kinfu_Params()) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_getPropVoxelSize_const(self.as_raw_Kinfu_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [voxel_size](Kinfu_ParamsTraitConst::voxel_size), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_voxel_size(&self) -> Result<f32> {
+		crate::core::validity::check("Kinfu_Params", self.as_raw_Kinfu_Params())?;
+		Ok(Kinfu_ParamsTraitConst::voxel_size(self))
+	}
+	
 	/// Minimal camera movement in meters
 	/// 
 	/// Integrate new depth frame only if camera movement exceeds this value.
 	#[inline]
 	fn tsdf_min_camera_movement(&self) -> f32 {
-		let ret = unsafe { sys::cv_kinfu_Params_getPropTsdf_min_camera_movement_const(self.as_raw_Kinfu_Params()) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_getPropTsdf_min_camera_movement_const(self.as_raw_Kinfu_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [tsdf_min_camera_movement](Kinfu_ParamsTraitConst::tsdf_min_camera_movement), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_tsdf_min_camera_movement(&self) -> Result<f32> {
+		crate::core::validity::check("Kinfu_Params", self.as_raw_Kinfu_Params())?;
+		Ok(Kinfu_ParamsTraitConst::tsdf_min_camera_movement(self))
+	}
+	
 	/// initial volume pose in meters
 	#[inline]
 	fn volume_pose(&self) -> core::Affine3f {
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_Kinfu_Params());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Params_getPropVolumePose_const(self.as_raw_Kinfu_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Params_getPropVolumePose_const(self.as_raw_Kinfu_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [volume_pose](Kinfu_ParamsTraitConst::volume_pose), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_volume_pose(&self) -> Result<core::Affine3f> {
+		crate::core::validity::check("Kinfu_Params", self.as_raw_Kinfu_Params())?;
+		Ok(Kinfu_ParamsTraitConst::volume_pose(self))
+	}
+	
 	/// distance to truncate in meters
 	/// 
 	/// Distances to surface that exceed this value will be truncated to 1.0.
 	#[inline]
 	fn tsdf_trunc_dist(&self) -> f32 {
-		let ret = unsafe { sys::cv_kinfu_Params_getPropTsdf_trunc_dist_const(self.as_raw_Kinfu_Params()) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_getPropTsdf_trunc_dist_const(self.as_raw_Kinfu_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [tsdf_trunc_dist](Kinfu_ParamsTraitConst::tsdf_trunc_dist), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_tsdf_trunc_dist(&self) -> Result<f32> {
+		crate::core::validity::check("Kinfu_Params", self.as_raw_Kinfu_Params())?;
+		Ok(Kinfu_ParamsTraitConst::tsdf_trunc_dist(self))
+	}
+	
 	/// max number of frames per voxel
 	/// 
 	/// Each voxel keeps running average of distances no longer than this value.
 	#[inline]
 	fn tsdf_max_weight(&self) -> i32 {
-		let ret = unsafe { sys::cv_kinfu_Params_getPropTsdf_max_weight_const(self.as_raw_Kinfu_Params()) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_getPropTsdf_max_weight_const(self.as_raw_Kinfu_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [tsdf_max_weight](Kinfu_ParamsTraitConst::tsdf_max_weight), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_tsdf_max_weight(&self) -> Result<i32> {
+		crate::core::validity::check("Kinfu_Params", self.as_raw_Kinfu_Params())?;
+		Ok(Kinfu_ParamsTraitConst::tsdf_max_weight(self))
+	}
+	
 	/// A length of one raycast step
 	/// 
 	/// How much voxel sizes we skip each raycast step
 	#[inline]
 	fn raycast_step_factor(&self) -> f32 {
-		let ret = unsafe { sys::cv_kinfu_Params_getPropRaycast_step_factor_const(self.as_raw_Kinfu_Params()) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_getPropRaycast_step_factor_const(self.as_raw_Kinfu_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [raycast_step_factor](Kinfu_ParamsTraitConst::raycast_step_factor), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_raycast_step_factor(&self) -> Result<f32> {
+		crate::core::validity::check("Kinfu_Params", self.as_raw_Kinfu_Params())?;
+		Ok(Kinfu_ParamsTraitConst::raycast_step_factor(self))
+	}
+	
 	/// light pose for rendering in meters
 	#[inline]
 	fn light_pose(&self) -> core::Vec3f {
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_Kinfu_Params());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Params_getPropLightPose_const(self.as_raw_Kinfu_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Params_getPropLightPose_const(self.as_raw_Kinfu_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [light_pose](Kinfu_ParamsTraitConst::light_pose), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_light_pose(&self) -> Result<core::Vec3f> {
+		crate::core::validity::check("Kinfu_Params", self.as_raw_Kinfu_Params())?;
+		Ok(Kinfu_ParamsTraitConst::light_pose(self))
+	}
+	
 	/// distance theshold for ICP in meters
 	#[inline]
 	fn icp_dist_thresh(&self) -> f32 {
-		let ret = unsafe { sys::cv_kinfu_Params_getPropIcpDistThresh_const(self.as_raw_Kinfu_Params()) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_getPropIcpDistThresh_const(self.as_raw_Kinfu_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [icp_dist_thresh](Kinfu_ParamsTraitConst::icp_dist_thresh), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_icp_dist_thresh(&self) -> Result<f32> {
+		crate::core::validity::check("Kinfu_Params", self.as_raw_Kinfu_Params())?;
+		Ok(Kinfu_ParamsTraitConst::icp_dist_thresh(self))
+	}
+	
 	/// angle threshold for ICP in radians
 	#[inline]
 	fn icp_angle_thresh(&self) -> f32 {
-		let ret = unsafe { sys::cv_kinfu_Params_getPropIcpAngleThresh_const(self.as_raw_Kinfu_Params()) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_getPropIcpAngleThresh_const(self.as_raw_Kinfu_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [icp_angle_thresh](Kinfu_ParamsTraitConst::icp_angle_thresh), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_icp_angle_thresh(&self) -> Result<f32> {
+		crate::core::validity::check("Kinfu_Params", self.as_raw_Kinfu_Params())?;
+		Ok(Kinfu_ParamsTraitConst::icp_angle_thresh(self))
+	}
+	
 	/// number of ICP iterations for each pyramid level
 	#[inline]
 	fn icp_iterations(&self) -> core::Vector<i32> {
-		let ret = unsafe { sys::cv_kinfu_Params_getPropIcpIterations_const(self.as_raw_Kinfu_Params()) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_getPropIcpIterations_const(self.as_raw_Kinfu_Params())) };
 		let ret = unsafe { core::Vector::<i32>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [icp_iterations](Kinfu_ParamsTraitConst::icp_iterations), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_icp_iterations(&self) -> Result<core::Vector<i32>> {
+		crate::core::validity::check("Kinfu_Params", self.as_raw_Kinfu_Params())?;
+		Ok(Kinfu_ParamsTraitConst::icp_iterations(self))
+	}
+	
 	/// Threshold for depth truncation in meters
 	/// 
 	/// All depth values beyond this threshold will be set to zero
 	#[inline]
 	fn truncate_threshold(&self) -> f32 {
-		let ret = unsafe { sys::cv_kinfu_Params_getPropTruncateThreshold_const(self.as_raw_Kinfu_Params()) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_getPropTruncateThreshold_const(self.as_raw_Kinfu_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [truncate_threshold](Kinfu_ParamsTraitConst::truncate_threshold), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_truncate_threshold(&self) -> Result<f32> {
+		crate::core::validity::check("Kinfu_Params", self.as_raw_Kinfu_Params())?;
+		Ok(Kinfu_ParamsTraitConst::truncate_threshold(self))
+	}
+	
 }
 
 pub trait Kinfu_ParamsTrait: crate::rgbd::Kinfu_ParamsTraitConst {
@@ -1704,28 +2227,32 @@ pub trait Kinfu_ParamsTrait: crate::rgbd::Kinfu_ParamsTraitConst {
 	/// frame size in pixels
 	#[inline]
 	fn set_frame_size(&mut self, val: core::Size) {
-		let ret = unsafe { sys::cv_kinfu_Params_setPropFrameSize_Size(self.as_raw_mut_Kinfu_Params(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_mut_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_setPropFrameSize_Size(self.as_raw_mut_Kinfu_Params(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	/// rgb frame size in pixels
 	#[inline]
 	fn set_volume_type(&mut self, val: crate::rgbd::Kinfu_VolumeType) {
-		let ret = unsafe { sys::cv_kinfu_Params_setPropVolumeType_VolumeType(self.as_raw_mut_Kinfu_Params(), val) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_mut_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_setPropVolumeType_VolumeType(self.as_raw_mut_Kinfu_Params(), val)) };
 		ret
 	}
 	
 	/// camera intrinsics
 	#[inline]
 	fn set_intr(&mut self, val: core::Matx33f) {
-		let ret = unsafe { sys::cv_kinfu_Params_setPropIntr_Matx33f(self.as_raw_mut_Kinfu_Params(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_mut_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_setPropIntr_Matx33f(self.as_raw_mut_Kinfu_Params(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	/// rgb camera intrinsics
 	#[inline]
 	fn set_rgb_intr(&mut self, val: core::Matx33f) {
-		let ret = unsafe { sys::cv_kinfu_Params_setPropRgb_intr_Matx33f(self.as_raw_mut_Kinfu_Params(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_mut_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_setPropRgb_intr_Matx33f(self.as_raw_mut_Kinfu_Params(), val.opencv_as_extern())) };
 		ret
 	}
 	
@@ -1737,35 +2264,40 @@ pub trait Kinfu_ParamsTrait: crate::rgbd::Kinfu_ParamsTraitConst {
 	///      * 1 per 1 meter for the 32-bit float images in the ROS bag files
 	#[inline]
 	fn set_depth_factor(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_kinfu_Params_setPropDepthFactor_float(self.as_raw_mut_Kinfu_Params(), val) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_mut_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_setPropDepthFactor_float(self.as_raw_mut_Kinfu_Params(), val)) };
 		ret
 	}
 	
 	/// Depth sigma in meters for bilateral smooth
 	#[inline]
 	fn set_bilateral_sigma_depth(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_kinfu_Params_setPropBilateral_sigma_depth_float(self.as_raw_mut_Kinfu_Params(), val) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_mut_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_setPropBilateral_sigma_depth_float(self.as_raw_mut_Kinfu_Params(), val)) };
 		ret
 	}
 	
 	/// Spatial sigma in pixels for bilateral smooth
 	#[inline]
 	fn set_bilateral_sigma_spatial(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_kinfu_Params_setPropBilateral_sigma_spatial_float(self.as_raw_mut_Kinfu_Params(), val) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_mut_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_setPropBilateral_sigma_spatial_float(self.as_raw_mut_Kinfu_Params(), val)) };
 		ret
 	}
 	
 	/// Kernel size in pixels for bilateral smooth
 	#[inline]
 	fn set_bilateral_kernel_size(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_kinfu_Params_setPropBilateral_kernel_size_int(self.as_raw_mut_Kinfu_Params(), val) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_mut_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_setPropBilateral_kernel_size_int(self.as_raw_mut_Kinfu_Params(), val)) };
 		ret
 	}
 	
 	/// Number of pyramid levels for ICP
 	#[inline]
 	fn set_pyramid_levels(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_kinfu_Params_setPropPyramidLevels_int(self.as_raw_mut_Kinfu_Params(), val) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_mut_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_setPropPyramidLevels_int(self.as_raw_mut_Kinfu_Params(), val)) };
 		ret
 	}
 	
@@ -1774,14 +2306,16 @@ pub trait Kinfu_ParamsTrait: crate::rgbd::Kinfu_ParamsTraitConst {
 	/// Number of voxels in each dimension.
 	#[inline]
 	fn set_volume_dims(&mut self, val: core::Vec3i) {
-		let ret = unsafe { sys::cv_kinfu_Params_setPropVolumeDims_Vec3i(self.as_raw_mut_Kinfu_Params(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_mut_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_setPropVolumeDims_Vec3i(self.as_raw_mut_Kinfu_Params(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	/// Size of voxel in meters
 	#[inline]
 	fn set_voxel_size(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_kinfu_Params_setPropVoxelSize_float(self.as_raw_mut_Kinfu_Params(), val) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_mut_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_setPropVoxelSize_float(self.as_raw_mut_Kinfu_Params(), val)) };
 		ret
 	}
 	
@@ -1790,14 +2324,16 @@ pub trait Kinfu_ParamsTrait: crate::rgbd::Kinfu_ParamsTraitConst {
 	/// Integrate new depth frame only if camera movement exceeds this value.
 	#[inline]
 	fn set_tsdf_min_camera_movement(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_kinfu_Params_setPropTsdf_min_camera_movement_float(self.as_raw_mut_Kinfu_Params(), val) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_mut_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_setPropTsdf_min_camera_movement_float(self.as_raw_mut_Kinfu_Params(), val)) };
 		ret
 	}
 	
 	/// initial volume pose in meters
 	#[inline]
 	fn set_volume_pose(&mut self, val: core::Affine3f) {
-		let ret = unsafe { sys::cv_kinfu_Params_setPropVolumePose_Affine3f(self.as_raw_mut_Kinfu_Params(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_mut_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_setPropVolumePose_Affine3f(self.as_raw_mut_Kinfu_Params(), val.opencv_as_extern())) };
 		ret
 	}
 	
@@ -1806,7 +2342,8 @@ pub trait Kinfu_ParamsTrait: crate::rgbd::Kinfu_ParamsTraitConst {
 	/// Distances to surface that exceed this value will be truncated to 1.0.
 	#[inline]
 	fn set_tsdf_trunc_dist(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_kinfu_Params_setPropTsdf_trunc_dist_float(self.as_raw_mut_Kinfu_Params(), val) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_mut_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_setPropTsdf_trunc_dist_float(self.as_raw_mut_Kinfu_Params(), val)) };
 		ret
 	}
 	
@@ -1815,7 +2352,8 @@ pub trait Kinfu_ParamsTrait: crate::rgbd::Kinfu_ParamsTraitConst {
 	/// Each voxel keeps running average of distances no longer than this value.
 	#[inline]
 	fn set_tsdf_max_weight(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_kinfu_Params_setPropTsdf_max_weight_int(self.as_raw_mut_Kinfu_Params(), val) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_mut_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_setPropTsdf_max_weight_int(self.as_raw_mut_Kinfu_Params(), val)) };
 		ret
 	}
 	
@@ -1824,35 +2362,40 @@ pub trait Kinfu_ParamsTrait: crate::rgbd::Kinfu_ParamsTraitConst {
 	/// How much voxel sizes we skip each raycast step
 	#[inline]
 	fn set_raycast_step_factor(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_kinfu_Params_setPropRaycast_step_factor_float(self.as_raw_mut_Kinfu_Params(), val) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_mut_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_setPropRaycast_step_factor_float(self.as_raw_mut_Kinfu_Params(), val)) };
 		ret
 	}
 	
 	/// light pose for rendering in meters
 	#[inline]
 	fn set_light_pose(&mut self, val: core::Vec3f) {
-		let ret = unsafe { sys::cv_kinfu_Params_setPropLightPose_Vec3f(self.as_raw_mut_Kinfu_Params(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_mut_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_setPropLightPose_Vec3f(self.as_raw_mut_Kinfu_Params(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	/// distance theshold for ICP in meters
 	#[inline]
 	fn set_icp_dist_thresh(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_kinfu_Params_setPropIcpDistThresh_float(self.as_raw_mut_Kinfu_Params(), val) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_mut_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_setPropIcpDistThresh_float(self.as_raw_mut_Kinfu_Params(), val)) };
 		ret
 	}
 	
 	/// angle threshold for ICP in radians
 	#[inline]
 	fn set_icp_angle_thresh(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_kinfu_Params_setPropIcpAngleThresh_float(self.as_raw_mut_Kinfu_Params(), val) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_mut_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_setPropIcpAngleThresh_float(self.as_raw_mut_Kinfu_Params(), val)) };
 		ret
 	}
 	
 	/// number of ICP iterations for each pyramid level
 	#[inline]
 	fn set_icp_iterations(&mut self, mut val: core::Vector<i32>) {
-		let ret = unsafe { sys::cv_kinfu_Params_setPropIcpIterations_vector_int_(self.as_raw_mut_Kinfu_Params(), val.as_raw_mut_VectorOfi32()) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_mut_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_setPropIcpIterations_vector_int_(self.as_raw_mut_Kinfu_Params(), val.as_raw_mut_VectorOfi32())) };
 		ret
 	}
 	
@@ -1861,7 +2404,8 @@ pub trait Kinfu_ParamsTrait: crate::rgbd::Kinfu_ParamsTraitConst {
 	/// All depth values beyond this threshold will be set to zero
 	#[inline]
 	fn set_truncate_threshold(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_kinfu_Params_setPropTruncateThreshold_float(self.as_raw_mut_Kinfu_Params(), val) };
+		crate::core::validity::debug_check("Kinfu_Params", self.as_raw_mut_Kinfu_Params());
+		let ret = unsafe { ffi_call!(cv_kinfu_Params_setPropTruncateThreshold_float(self.as_raw_mut_Kinfu_Params(), val)) };
 		ret
 	}
 	
@@ -1872,8 +2416,9 @@ pub trait Kinfu_ParamsTrait: crate::rgbd::Kinfu_ParamsTraitConst {
 	/// * t: translation vector
 	#[inline]
 	fn set_initial_volume_pose(&mut self, r: core::Matx33f, t: core::Vec3f) -> Result<()> {
+		crate::core::validity::check("Kinfu_Params", self.as_raw_mut_Kinfu_Params())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Params_setInitialVolumePose_Matx33f_Vec3f(self.as_raw_mut_Kinfu_Params(), r.opencv_as_extern(), t.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Params_setInitialVolumePose_Matx33f_Vec3f(self.as_raw_mut_Kinfu_Params(), r.opencv_as_extern(), t.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1885,8 +2430,9 @@ pub trait Kinfu_ParamsTrait: crate::rgbd::Kinfu_ParamsTraitConst {
 	/// * homogen_tf: 4 by 4 Homogeneous Transform matrix to set the intial pose of TSDF volume
 	#[inline]
 	fn set_initial_volume_pose_1(&mut self, homogen_tf: core::Matx44f) -> Result<()> {
+		crate::core::validity::check("Kinfu_Params", self.as_raw_mut_Kinfu_Params())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Params_setInitialVolumePose_Matx44f(self.as_raw_mut_Kinfu_Params(), homogen_tf.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Params_setInitialVolumePose_Matx44f(self.as_raw_mut_Kinfu_Params(), homogen_tf.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1903,7 +2449,10 @@ opencv_type_boxed! { Kinfu_Params }
 impl Drop for Kinfu_Params {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Kinfu_Params_delete(instance: *mut c_void); }
-		unsafe { cv_Kinfu_Params_delete(self.as_raw_mut_Kinfu_Params()) };
+		let ptr = self.as_raw_mut_Kinfu_Params();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Kinfu_Params_delete(ptr) };
 	}
 }
 
@@ -1921,7 +2470,7 @@ impl Kinfu_Params {
 	#[inline]
 	pub fn default() -> Result<crate::rgbd::Kinfu_Params> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Params_Params(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Params_Params(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::Kinfu_Params::opencv_from_extern(ret) };
@@ -1936,7 +2485,7 @@ impl Kinfu_Params {
 	#[inline]
 	pub fn new(volume_initial_pose_rot: core::Matx33f, volume_initial_pose_transl: core::Vec3f) -> Result<crate::rgbd::Kinfu_Params> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Params_Params_Matx33f_Vec3f(volume_initial_pose_rot.opencv_as_extern(), volume_initial_pose_transl.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Params_Params_Matx33f_Vec3f(volume_initial_pose_rot.opencv_as_extern(), volume_initial_pose_transl.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::Kinfu_Params::opencv_from_extern(ret) };
@@ -1950,7 +2499,7 @@ impl Kinfu_Params {
 	#[inline]
 	pub fn new_1(volume_initial_pose: core::Matx44f) -> Result<crate::rgbd::Kinfu_Params> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Params_Params_Matx44f(volume_initial_pose.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Params_Params_Matx44f(volume_initial_pose.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::Kinfu_Params::opencv_from_extern(ret) };
@@ -1962,7 +2511,7 @@ impl Kinfu_Params {
 	#[inline]
 	pub fn default_params() -> Result<core::Ptr<crate::rgbd::Kinfu_Params>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Params_defaultParams(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Params_defaultParams(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::rgbd::Kinfu_Params>::opencv_from_extern(ret) };
@@ -1975,7 +2524,7 @@ impl Kinfu_Params {
 	#[inline]
 	pub fn coarse_params() -> Result<core::Ptr<crate::rgbd::Kinfu_Params>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Params_coarseParams(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Params_coarseParams(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::rgbd::Kinfu_Params>::opencv_from_extern(ret) };
@@ -1987,7 +2536,7 @@ impl Kinfu_Params {
 	#[inline]
 	pub fn hash_tsdf_params(is_coarse: bool) -> Result<core::Ptr<crate::rgbd::Kinfu_Params>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Params_hashTSDFParams_bool(is_coarse, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Params_hashTSDFParams_bool(is_coarse, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::rgbd::Kinfu_Params>::opencv_from_extern(ret) };
@@ -1999,7 +2548,7 @@ impl Kinfu_Params {
 	#[inline]
 	pub fn colored_tsdf_params(is_coarse: bool) -> Result<core::Ptr<crate::rgbd::Kinfu_Params>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Params_coloredTSDFParams_bool(is_coarse, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Params_coloredTSDFParams_bool(is_coarse, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::rgbd::Kinfu_Params>::opencv_from_extern(ret) };
@@ -2013,36 +2562,73 @@ pub trait Kinfu_VolumeConst {
 
 	#[inline]
 	fn voxel_size(&self) -> f32 {
-		let ret = unsafe { sys::cv_kinfu_Volume_getPropVoxelSize_const(self.as_raw_Kinfu_Volume()) };
+		crate::core::validity::debug_check("Kinfu_Volume", self.as_raw_Kinfu_Volume());
+		let ret = unsafe { ffi_call!(cv_kinfu_Volume_getPropVoxelSize_const(self.as_raw_Kinfu_Volume())) };
 		ret
 	}
 	
+	/// Non-panicking version of [voxel_size](Kinfu_VolumeConst::voxel_size), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_voxel_size(&self) -> Result<f32> {
+		crate::core::validity::check("Kinfu_Volume", self.as_raw_Kinfu_Volume())?;
+		Ok(Kinfu_VolumeConst::voxel_size(self))
+	}
+	
 	#[inline]
 	fn voxel_size_inv(&self) -> f32 {
-		let ret = unsafe { sys::cv_kinfu_Volume_getPropVoxelSizeInv_const(self.as_raw_Kinfu_Volume()) };
+		crate::core::validity::debug_check("Kinfu_Volume", self.as_raw_Kinfu_Volume());
+		let ret = unsafe { ffi_call!(cv_kinfu_Volume_getPropVoxelSizeInv_const(self.as_raw_Kinfu_Volume())) };
 		ret
 	}
 	
+	/// Non-panicking version of [voxel_size_inv](Kinfu_VolumeConst::voxel_size_inv), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_voxel_size_inv(&self) -> Result<f32> {
+		crate::core::validity::check("Kinfu_Volume", self.as_raw_Kinfu_Volume())?;
+		Ok(Kinfu_VolumeConst::voxel_size_inv(self))
+	}
+	
 	#[inline]
 	fn pose(&self) -> core::Affine3f {
+		crate::core::validity::debug_check("Kinfu_Volume", self.as_raw_Kinfu_Volume());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Volume_getPropPose_const(self.as_raw_Kinfu_Volume(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Volume_getPropPose_const(self.as_raw_Kinfu_Volume(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [pose](Kinfu_VolumeConst::pose), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pose(&self) -> Result<core::Affine3f> {
+		crate::core::validity::check("Kinfu_Volume", self.as_raw_Kinfu_Volume())?;
+		Ok(Kinfu_VolumeConst::pose(self))
+	}
+	
 	#[inline]
 	fn raycast_step_factor(&self) -> f32 {
-		let ret = unsafe { sys::cv_kinfu_Volume_getPropRaycastStepFactor_const(self.as_raw_Kinfu_Volume()) };
+		crate::core::validity::debug_check("Kinfu_Volume", self.as_raw_Kinfu_Volume());
+		let ret = unsafe { ffi_call!(cv_kinfu_Volume_getPropRaycastStepFactor_const(self.as_raw_Kinfu_Volume())) };
 		ret
 	}
 	
+	/// Non-panicking version of [raycast_step_factor](Kinfu_VolumeConst::raycast_step_factor), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_raycast_step_factor(&self) -> Result<f32> {
+		crate::core::validity::check("Kinfu_Volume", self.as_raw_Kinfu_Volume())?;
+		Ok(Kinfu_VolumeConst::raycast_step_factor(self))
+	}
+	
 	#[inline]
 	fn raycast(&self, camera_pose: core::Matx44f, intrinsics: crate::rgbd::Kinfu_Intr, frame_size: core::Size, points: &mut dyn core::ToOutputArray, normals: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("Kinfu_Volume", self.as_raw_Kinfu_Volume())?;
 		output_array_arg!(points);
 		output_array_arg!(normals);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Volume_raycast_const_const_Matx44fR_const_IntrR_const_SizeR_const__OutputArrayR_const__OutputArrayR(self.as_raw_Kinfu_Volume(), &camera_pose, &intrinsics, &frame_size, points.as_raw__OutputArray(), normals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Volume_raycast_const_const_Matx44fR_const_IntrR_const_SizeR_const__OutputArrayR_const__OutputArrayR(self.as_raw_Kinfu_Volume(), &camera_pose, &intrinsics, &frame_size, points.as_raw__OutputArray(), normals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2050,11 +2636,12 @@ pub trait Kinfu_VolumeConst {
 	
 	#[inline]
 	fn raycast_1(&self, camera_pose: core::Matx44f, intrinsics: crate::rgbd::Kinfu_Intr, frame_size: core::Size, points: &mut dyn core::ToOutputArray, normals: &mut dyn core::ToOutputArray, colors: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("Kinfu_Volume", self.as_raw_Kinfu_Volume())?;
 		output_array_arg!(points);
 		output_array_arg!(normals);
 		output_array_arg!(colors);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Volume_raycast_const_const_Matx44fR_const_IntrR_const_SizeR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_Kinfu_Volume(), &camera_pose, &intrinsics, &frame_size, points.as_raw__OutputArray(), normals.as_raw__OutputArray(), colors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Volume_raycast_const_const_Matx44fR_const_IntrR_const_SizeR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_Kinfu_Volume(), &camera_pose, &intrinsics, &frame_size, points.as_raw__OutputArray(), normals.as_raw__OutputArray(), colors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2062,10 +2649,11 @@ pub trait Kinfu_VolumeConst {
 	
 	#[inline]
 	fn fetch_normals(&self, points: &dyn core::ToInputArray, _normals: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("Kinfu_Volume", self.as_raw_Kinfu_Volume())?;
 		input_array_arg!(points);
 		output_array_arg!(_normals);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Volume_fetchNormals_const_const__InputArrayR_const__OutputArrayR(self.as_raw_Kinfu_Volume(), points.as_raw__InputArray(), _normals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Volume_fetchNormals_const_const__InputArrayR_const__OutputArrayR(self.as_raw_Kinfu_Volume(), points.as_raw__InputArray(), _normals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2073,10 +2661,11 @@ pub trait Kinfu_VolumeConst {
 	
 	#[inline]
 	fn fetch_points_normals(&self, points: &mut dyn core::ToOutputArray, normals: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("Kinfu_Volume", self.as_raw_Kinfu_Volume())?;
 		output_array_arg!(points);
 		output_array_arg!(normals);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Volume_fetchPointsNormals_const_const__OutputArrayR_const__OutputArrayR(self.as_raw_Kinfu_Volume(), points.as_raw__OutputArray(), normals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Volume_fetchPointsNormals_const_const__OutputArrayR_const__OutputArrayR(self.as_raw_Kinfu_Volume(), points.as_raw__OutputArray(), normals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2084,11 +2673,12 @@ pub trait Kinfu_VolumeConst {
 	
 	#[inline]
 	fn fetch_points_normals_colors(&self, unnamed: &mut dyn core::ToOutputArray, unnamed_1: &mut dyn core::ToOutputArray, unnamed_2: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("Kinfu_Volume", self.as_raw_Kinfu_Volume())?;
 		output_array_arg!(unnamed);
 		output_array_arg!(unnamed_1);
 		output_array_arg!(unnamed_2);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Volume_fetchPointsNormalsColors_const_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_Kinfu_Volume(), unnamed.as_raw__OutputArray(), unnamed_1.as_raw__OutputArray(), unnamed_2.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Volume_fetchPointsNormalsColors_const_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_Kinfu_Volume(), unnamed.as_raw__OutputArray(), unnamed_1.as_raw__OutputArray(), unnamed_2.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2103,9 +2693,10 @@ pub trait Kinfu_Volume: crate::rgbd::Kinfu_VolumeConst {
 	/// * frame_id: 0
 	#[inline]
 	fn integrate(&mut self, _depth: &dyn core::ToInputArray, depth_factor: f32, camera_pose: core::Matx44f, intrinsics: crate::rgbd::Kinfu_Intr, frame_id: i32) -> Result<()> {
+		crate::core::validity::check("Kinfu_Volume", self.as_raw_mut_Kinfu_Volume())?;
 		input_array_arg!(_depth);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Volume_integrate_const__InputArrayR_float_const_Matx44fR_const_IntrR_const_int(self.as_raw_mut_Kinfu_Volume(), _depth.as_raw__InputArray(), depth_factor, &camera_pose, &intrinsics, frame_id, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Volume_integrate_const__InputArrayR_float_const_Matx44fR_const_IntrR_const_int(self.as_raw_mut_Kinfu_Volume(), _depth.as_raw__InputArray(), depth_factor, &camera_pose, &intrinsics, frame_id, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2115,10 +2706,11 @@ pub trait Kinfu_Volume: crate::rgbd::Kinfu_VolumeConst {
 	/// * frame_id: 0
 	#[inline]
 	fn integrate_1(&mut self, _depth: &dyn core::ToInputArray, _rgb: &dyn core::ToInputArray, depth_factor: f32, camera_pose: core::Matx44f, intrinsics: crate::rgbd::Kinfu_Intr, rgb_intrinsics: crate::rgbd::Kinfu_Intr, frame_id: i32) -> Result<()> {
+		crate::core::validity::check("Kinfu_Volume", self.as_raw_mut_Kinfu_Volume())?;
 		input_array_arg!(_depth);
 		input_array_arg!(_rgb);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Volume_integrate_const__InputArrayR_const__InputArrayR_float_const_Matx44fR_const_IntrR_const_IntrR_const_int(self.as_raw_mut_Kinfu_Volume(), _depth.as_raw__InputArray(), _rgb.as_raw__InputArray(), depth_factor, &camera_pose, &intrinsics, &rgb_intrinsics, frame_id, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Volume_integrate_const__InputArrayR_const__InputArrayR_float_const_Matx44fR_const_IntrR_const_IntrR_const_int(self.as_raw_mut_Kinfu_Volume(), _depth.as_raw__InputArray(), _rgb.as_raw__InputArray(), depth_factor, &camera_pose, &intrinsics, &rgb_intrinsics, frame_id, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2126,8 +2718,9 @@ pub trait Kinfu_Volume: crate::rgbd::Kinfu_VolumeConst {
 	
 	#[inline]
 	fn reset(&mut self) -> Result<()> {
+		crate::core::validity::check("Kinfu_Volume", self.as_raw_mut_Kinfu_Volume())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_Volume_reset(self.as_raw_mut_Kinfu_Volume(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_Volume_reset(self.as_raw_mut_Kinfu_Volume(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2135,6 +2728,40 @@ pub trait Kinfu_Volume: crate::rgbd::Kinfu_VolumeConst {
 	
 }
 
+/// Builder for `Kinfu_Volume::integrate_1()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct Kinfu_Volume_Integrate1Builder<'a, T: ?Sized + Kinfu_Volume> {
+	instance: &'a mut T,
+	_depth: &'a dyn core::ToInputArray,
+	_rgb: &'a dyn core::ToInputArray,
+	depth_factor: f32,
+	camera_pose: core::Matx44f,
+	intrinsics: crate::rgbd::Kinfu_Intr,
+	rgb_intrinsics: crate::rgbd::Kinfu_Intr,
+	frame_id: i32,
+}
+
+impl<'a, T: ?Sized + Kinfu_Volume> Kinfu_Volume_Integrate1Builder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, _depth: &'a dyn core::ToInputArray, _rgb: &'a dyn core::ToInputArray, depth_factor: f32, camera_pose: core::Matx44f, intrinsics: crate::rgbd::Kinfu_Intr, rgb_intrinsics: crate::rgbd::Kinfu_Intr) -> Self {
+		Self { instance, _depth, _rgb, depth_factor, camera_pose, intrinsics, rgb_intrinsics, frame_id: 0 }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_frame_id(mut self, frame_id: i32) -> Self {
+		self.frame_id = frame_id;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		Kinfu_Volume::integrate_1(self.instance, self._depth, self._rgb, self.depth_factor, self.camera_pose, self.intrinsics, self.rgb_intrinsics, self.frame_id)
+	}
+}
+
 pub trait Kinfu_VolumeParamsTraitConst {
 	fn as_raw_Kinfu_VolumeParams(&self) -> *const c_void;
 
@@ -2142,82 +2769,163 @@ pub trait Kinfu_VolumeParamsTraitConst {
 	/// Values can be TSDF (single volume) or HASHTSDF (hashtable of volume units)
 	#[inline]
 	fn typ(&self) -> crate::rgbd::Kinfu_VolumeType {
+		crate::core::validity::debug_check("Kinfu_VolumeParams", self.as_raw_Kinfu_VolumeParams());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_VolumeParams_getPropType_const(self.as_raw_Kinfu_VolumeParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_VolumeParams_getPropType_const(self.as_raw_Kinfu_VolumeParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [typ](Kinfu_VolumeParamsTraitConst::typ), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_typ(&self) -> Result<crate::rgbd::Kinfu_VolumeType> {
+		crate::core::validity::check("Kinfu_VolumeParams", self.as_raw_Kinfu_VolumeParams())?;
+		Ok(Kinfu_VolumeParamsTraitConst::typ(self))
+	}
+	
 	/// Resolution of voxel space
 	/// Number of voxels in each dimension.
 	/// Applicable only for TSDF Volume.
 	/// HashTSDF volume only supports equal resolution in all three dimensions
 	#[inline]
 	fn resolution(&self) -> core::Vec3i {
+		crate::core::validity::debug_check("Kinfu_VolumeParams", self.as_raw_Kinfu_VolumeParams());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_VolumeParams_getPropResolution_const(self.as_raw_Kinfu_VolumeParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_VolumeParams_getPropResolution_const(self.as_raw_Kinfu_VolumeParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [resolution](Kinfu_VolumeParamsTraitConst::resolution), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_resolution(&self) -> Result<core::Vec3i> {
+		crate::core::validity::check("Kinfu_VolumeParams", self.as_raw_Kinfu_VolumeParams())?;
+		Ok(Kinfu_VolumeParamsTraitConst::resolution(self))
+	}
+	
 	/// Resolution of volumeUnit in voxel space
 	/// Number of voxels in each dimension for volumeUnit
 	/// Applicable only for hashTSDF.
 	#[inline]
 	fn unit_resolution(&self) -> i32 {
-		let ret = unsafe { sys::cv_kinfu_VolumeParams_getPropUnitResolution_const(self.as_raw_Kinfu_VolumeParams()) };
+		crate::core::validity::debug_check("Kinfu_VolumeParams", self.as_raw_Kinfu_VolumeParams());
+		let ret = unsafe { ffi_call!(cv_kinfu_VolumeParams_getPropUnitResolution_const(self.as_raw_Kinfu_VolumeParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [unit_resolution](Kinfu_VolumeParamsTraitConst::unit_resolution), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_unit_resolution(&self) -> Result<i32> {
+		crate::core::validity::check("Kinfu_VolumeParams", self.as_raw_Kinfu_VolumeParams())?;
+		Ok(Kinfu_VolumeParamsTraitConst::unit_resolution(self))
+	}
+	
 	/// Initial pose of the volume in meters
 	#[inline]
 	fn pose(&self) -> core::Affine3f {
+		crate::core::validity::debug_check("Kinfu_VolumeParams", self.as_raw_Kinfu_VolumeParams());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_VolumeParams_getPropPose_const(self.as_raw_Kinfu_VolumeParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_VolumeParams_getPropPose_const(self.as_raw_Kinfu_VolumeParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [pose](Kinfu_VolumeParamsTraitConst::pose), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pose(&self) -> Result<core::Affine3f> {
+		crate::core::validity::check("Kinfu_VolumeParams", self.as_raw_Kinfu_VolumeParams())?;
+		Ok(Kinfu_VolumeParamsTraitConst::pose(self))
+	}
+	
 	/// Length of voxels in meters
 	#[inline]
 	fn voxel_size(&self) -> f32 {
-		let ret = unsafe { sys::cv_kinfu_VolumeParams_getPropVoxelSize_const(self.as_raw_Kinfu_VolumeParams()) };
+		crate::core::validity::debug_check("Kinfu_VolumeParams", self.as_raw_Kinfu_VolumeParams());
+		let ret = unsafe { ffi_call!(cv_kinfu_VolumeParams_getPropVoxelSize_const(self.as_raw_Kinfu_VolumeParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [voxel_size](Kinfu_VolumeParamsTraitConst::voxel_size), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_voxel_size(&self) -> Result<f32> {
+		crate::core::validity::check("Kinfu_VolumeParams", self.as_raw_Kinfu_VolumeParams())?;
+		Ok(Kinfu_VolumeParamsTraitConst::voxel_size(self))
+	}
+	
 	/// TSDF truncation distance
 	/// Distances greater than value from surface will be truncated to 1.0
 	#[inline]
 	fn tsdf_trunc_dist(&self) -> f32 {
-		let ret = unsafe { sys::cv_kinfu_VolumeParams_getPropTsdfTruncDist_const(self.as_raw_Kinfu_VolumeParams()) };
+		crate::core::validity::debug_check("Kinfu_VolumeParams", self.as_raw_Kinfu_VolumeParams());
+		let ret = unsafe { ffi_call!(cv_kinfu_VolumeParams_getPropTsdfTruncDist_const(self.as_raw_Kinfu_VolumeParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [tsdf_trunc_dist](Kinfu_VolumeParamsTraitConst::tsdf_trunc_dist), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_tsdf_trunc_dist(&self) -> Result<f32> {
+		crate::core::validity::check("Kinfu_VolumeParams", self.as_raw_Kinfu_VolumeParams())?;
+		Ok(Kinfu_VolumeParamsTraitConst::tsdf_trunc_dist(self))
+	}
+	
 	/// Max number of frames to integrate per voxel
 	/// Represents the max number of frames over which a running average
 	/// of the TSDF is calculated for a voxel
 	#[inline]
 	fn max_weight(&self) -> i32 {
-		let ret = unsafe { sys::cv_kinfu_VolumeParams_getPropMaxWeight_const(self.as_raw_Kinfu_VolumeParams()) };
+		crate::core::validity::debug_check("Kinfu_VolumeParams", self.as_raw_Kinfu_VolumeParams());
+		let ret = unsafe { ffi_call!(cv_kinfu_VolumeParams_getPropMaxWeight_const(self.as_raw_Kinfu_VolumeParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [max_weight](Kinfu_VolumeParamsTraitConst::max_weight), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_max_weight(&self) -> Result<i32> {
+		crate::core::validity::check("Kinfu_VolumeParams", self.as_raw_Kinfu_VolumeParams())?;
+		Ok(Kinfu_VolumeParamsTraitConst::max_weight(self))
+	}
+	
 	/// Threshold for depth truncation in meters
 	/// Truncates the depth greater than threshold to 0
 	#[inline]
 	fn depth_trunc_threshold(&self) -> f32 {
-		let ret = unsafe { sys::cv_kinfu_VolumeParams_getPropDepthTruncThreshold_const(self.as_raw_Kinfu_VolumeParams()) };
+		crate::core::validity::debug_check("Kinfu_VolumeParams", self.as_raw_Kinfu_VolumeParams());
+		let ret = unsafe { ffi_call!(cv_kinfu_VolumeParams_getPropDepthTruncThreshold_const(self.as_raw_Kinfu_VolumeParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [depth_trunc_threshold](Kinfu_VolumeParamsTraitConst::depth_trunc_threshold), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_depth_trunc_threshold(&self) -> Result<f32> {
+		crate::core::validity::check("Kinfu_VolumeParams", self.as_raw_Kinfu_VolumeParams())?;
+		Ok(Kinfu_VolumeParamsTraitConst::depth_trunc_threshold(self))
+	}
+	
 	/// Length of single raycast step
 	/// Describes the percentage of voxel length that is skipped per march
 	#[inline]
 	fn raycast_step_factor(&self) -> f32 {
-		let ret = unsafe { sys::cv_kinfu_VolumeParams_getPropRaycastStepFactor_const(self.as_raw_Kinfu_VolumeParams()) };
+		crate::core::validity::debug_check("Kinfu_VolumeParams", self.as_raw_Kinfu_VolumeParams());
+		let ret = unsafe { ffi_call!(cv_kinfu_VolumeParams_getPropRaycastStepFactor_const(self.as_raw_Kinfu_VolumeParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [raycast_step_factor](Kinfu_VolumeParamsTraitConst::raycast_step_factor), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_raycast_step_factor(&self) -> Result<f32> {
+		crate::core::validity::check("Kinfu_VolumeParams", self.as_raw_Kinfu_VolumeParams())?;
+		Ok(Kinfu_VolumeParamsTraitConst::raycast_step_factor(self))
+	}
+	
 }
 
 pub trait Kinfu_VolumeParamsTrait: crate::rgbd::Kinfu_VolumeParamsTraitConst {
@@ -2227,7 +2935,8 @@ pub trait Kinfu_VolumeParamsTrait: crate::rgbd::Kinfu_VolumeParamsTraitConst {
 	/// Values can be TSDF (single volume) or HASHTSDF (hashtable of volume units)
 	#[inline]
 	fn set_type(&mut self, val: crate::rgbd::Kinfu_VolumeType) {
-		let ret = unsafe { sys::cv_kinfu_VolumeParams_setPropType_VolumeType(self.as_raw_mut_Kinfu_VolumeParams(), val) };
+		crate::core::validity::debug_check("Kinfu_VolumeParams", self.as_raw_mut_Kinfu_VolumeParams());
+		let ret = unsafe { ffi_call!(cv_kinfu_VolumeParams_setPropType_VolumeType(self.as_raw_mut_Kinfu_VolumeParams(), val)) };
 		ret
 	}
 	
@@ -2237,7 +2946,8 @@ pub trait Kinfu_VolumeParamsTrait: crate::rgbd::Kinfu_VolumeParamsTraitConst {
 	/// HashTSDF volume only supports equal resolution in all three dimensions
 	#[inline]
 	fn set_resolution(&mut self, val: core::Vec3i) {
-		let ret = unsafe { sys::cv_kinfu_VolumeParams_setPropResolution_Vec3i(self.as_raw_mut_Kinfu_VolumeParams(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("Kinfu_VolumeParams", self.as_raw_mut_Kinfu_VolumeParams());
+		let ret = unsafe { ffi_call!(cv_kinfu_VolumeParams_setPropResolution_Vec3i(self.as_raw_mut_Kinfu_VolumeParams(), val.opencv_as_extern())) };
 		ret
 	}
 	
@@ -2249,21 +2959,24 @@ pub trait Kinfu_VolumeParamsTrait: crate::rgbd::Kinfu_VolumeParamsTraitConst {
 	/// * val: {0}
 	#[inline]
 	fn set_unit_resolution(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_kinfu_VolumeParams_setPropUnitResolution_int(self.as_raw_mut_Kinfu_VolumeParams(), val) };
+		crate::core::validity::debug_check("Kinfu_VolumeParams", self.as_raw_mut_Kinfu_VolumeParams());
+		let ret = unsafe { ffi_call!(cv_kinfu_VolumeParams_setPropUnitResolution_int(self.as_raw_mut_Kinfu_VolumeParams(), val)) };
 		ret
 	}
 	
 	/// Initial pose of the volume in meters
 	#[inline]
 	fn set_pose(&mut self, val: core::Affine3f) {
-		let ret = unsafe { sys::cv_kinfu_VolumeParams_setPropPose_Affine3f(self.as_raw_mut_Kinfu_VolumeParams(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("Kinfu_VolumeParams", self.as_raw_mut_Kinfu_VolumeParams());
+		let ret = unsafe { ffi_call!(cv_kinfu_VolumeParams_setPropPose_Affine3f(self.as_raw_mut_Kinfu_VolumeParams(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	/// Length of voxels in meters
 	#[inline]
 	fn set_voxel_size(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_kinfu_VolumeParams_setPropVoxelSize_float(self.as_raw_mut_Kinfu_VolumeParams(), val) };
+		crate::core::validity::debug_check("Kinfu_VolumeParams", self.as_raw_mut_Kinfu_VolumeParams());
+		let ret = unsafe { ffi_call!(cv_kinfu_VolumeParams_setPropVoxelSize_float(self.as_raw_mut_Kinfu_VolumeParams(), val)) };
 		ret
 	}
 	
@@ -2271,7 +2984,8 @@ pub trait Kinfu_VolumeParamsTrait: crate::rgbd::Kinfu_VolumeParamsTraitConst {
 	/// Distances greater than value from surface will be truncated to 1.0
 	#[inline]
 	fn set_tsdf_trunc_dist(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_kinfu_VolumeParams_setPropTsdfTruncDist_float(self.as_raw_mut_Kinfu_VolumeParams(), val) };
+		crate::core::validity::debug_check("Kinfu_VolumeParams", self.as_raw_mut_Kinfu_VolumeParams());
+		let ret = unsafe { ffi_call!(cv_kinfu_VolumeParams_setPropTsdfTruncDist_float(self.as_raw_mut_Kinfu_VolumeParams(), val)) };
 		ret
 	}
 	
@@ -2280,7 +2994,8 @@ pub trait Kinfu_VolumeParamsTrait: crate::rgbd::Kinfu_VolumeParamsTraitConst {
 	/// of the TSDF is calculated for a voxel
 	#[inline]
 	fn set_max_weight(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_kinfu_VolumeParams_setPropMaxWeight_int(self.as_raw_mut_Kinfu_VolumeParams(), val) };
+		crate::core::validity::debug_check("Kinfu_VolumeParams", self.as_raw_mut_Kinfu_VolumeParams());
+		let ret = unsafe { ffi_call!(cv_kinfu_VolumeParams_setPropMaxWeight_int(self.as_raw_mut_Kinfu_VolumeParams(), val)) };
 		ret
 	}
 	
@@ -2288,7 +3003,8 @@ pub trait Kinfu_VolumeParamsTrait: crate::rgbd::Kinfu_VolumeParamsTraitConst {
 	/// Truncates the depth greater than threshold to 0
 	#[inline]
 	fn set_depth_trunc_threshold(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_kinfu_VolumeParams_setPropDepthTruncThreshold_float(self.as_raw_mut_Kinfu_VolumeParams(), val) };
+		crate::core::validity::debug_check("Kinfu_VolumeParams", self.as_raw_mut_Kinfu_VolumeParams());
+		let ret = unsafe { ffi_call!(cv_kinfu_VolumeParams_setPropDepthTruncThreshold_float(self.as_raw_mut_Kinfu_VolumeParams(), val)) };
 		ret
 	}
 	
@@ -2296,7 +3012,8 @@ pub trait Kinfu_VolumeParamsTrait: crate::rgbd::Kinfu_VolumeParamsTraitConst {
 	/// Describes the percentage of voxel length that is skipped per march
 	#[inline]
 	fn set_raycast_step_factor(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_kinfu_VolumeParams_setPropRaycastStepFactor_float(self.as_raw_mut_Kinfu_VolumeParams(), val) };
+		crate::core::validity::debug_check("Kinfu_VolumeParams", self.as_raw_mut_Kinfu_VolumeParams());
+		let ret = unsafe { ffi_call!(cv_kinfu_VolumeParams_setPropRaycastStepFactor_float(self.as_raw_mut_Kinfu_VolumeParams(), val)) };
 		ret
 	}
 	
@@ -2311,7 +3028,10 @@ opencv_type_boxed! { Kinfu_VolumeParams }
 impl Drop for Kinfu_VolumeParams {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Kinfu_VolumeParams_delete(instance: *mut c_void); }
-		unsafe { cv_Kinfu_VolumeParams_delete(self.as_raw_mut_Kinfu_VolumeParams()) };
+		let ptr = self.as_raw_mut_Kinfu_VolumeParams();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Kinfu_VolumeParams_delete(ptr) };
 	}
 }
 
@@ -2331,7 +3051,7 @@ impl Kinfu_VolumeParams {
 	#[inline]
 	pub fn default_params(_volume_type: crate::rgbd::Kinfu_VolumeType) -> Result<core::Ptr<crate::rgbd::Kinfu_VolumeParams>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_VolumeParams_defaultParams_VolumeType(_volume_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_VolumeParams_defaultParams_VolumeType(_volume_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::rgbd::Kinfu_VolumeParams>::opencv_from_extern(ret) };
@@ -2343,7 +3063,7 @@ impl Kinfu_VolumeParams {
 	#[inline]
 	pub fn coarse_params(_volume_type: crate::rgbd::Kinfu_VolumeType) -> Result<core::Ptr<crate::rgbd::Kinfu_VolumeParams>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_VolumeParams_coarseParams_VolumeType(_volume_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_VolumeParams_coarseParams_VolumeType(_volume_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::rgbd::Kinfu_VolumeParams>::opencv_from_extern(ret) };
@@ -2357,8 +3077,9 @@ pub trait Kinfu_Detail_PoseGraphConst {
 
 	#[inline]
 	fn is_node_exist(&self, node_id: size_t) -> Result<bool> {
+		crate::core::validity::check("Kinfu_Detail_PoseGraph", self.as_raw_Kinfu_Detail_PoseGraph())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_detail_PoseGraph_isNodeExist_const_size_t(self.as_raw_Kinfu_Detail_PoseGraph(), node_id, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_detail_PoseGraph_isNodeExist_const_size_t(self.as_raw_Kinfu_Detail_PoseGraph(), node_id, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2366,8 +3087,9 @@ pub trait Kinfu_Detail_PoseGraphConst {
 	
 	#[inline]
 	fn is_node_fixed(&self, node_id: size_t) -> Result<bool> {
+		crate::core::validity::check("Kinfu_Detail_PoseGraph", self.as_raw_Kinfu_Detail_PoseGraph())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_detail_PoseGraph_isNodeFixed_const_size_t(self.as_raw_Kinfu_Detail_PoseGraph(), node_id, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_detail_PoseGraph_isNodeFixed_const_size_t(self.as_raw_Kinfu_Detail_PoseGraph(), node_id, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2375,8 +3097,9 @@ pub trait Kinfu_Detail_PoseGraphConst {
 	
 	#[inline]
 	fn get_node_pose(&self, node_id: size_t) -> Result<core::Affine3d> {
+		crate::core::validity::check("Kinfu_Detail_PoseGraph", self.as_raw_Kinfu_Detail_PoseGraph())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_detail_PoseGraph_getNodePose_const_size_t(self.as_raw_Kinfu_Detail_PoseGraph(), node_id, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_detail_PoseGraph_getNodePose_const_size_t(self.as_raw_Kinfu_Detail_PoseGraph(), node_id, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2384,8 +3107,9 @@ pub trait Kinfu_Detail_PoseGraphConst {
 	
 	#[inline]
 	fn get_nodes_ids(&self) -> Result<core::Vector<size_t>> {
+		crate::core::validity::check("Kinfu_Detail_PoseGraph", self.as_raw_Kinfu_Detail_PoseGraph())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_detail_PoseGraph_getNodesIds_const(self.as_raw_Kinfu_Detail_PoseGraph(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_detail_PoseGraph_getNodesIds_const(self.as_raw_Kinfu_Detail_PoseGraph(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<size_t>::opencv_from_extern(ret) };
@@ -2394,8 +3118,9 @@ pub trait Kinfu_Detail_PoseGraphConst {
 	
 	#[inline]
 	fn get_num_nodes(&self) -> Result<size_t> {
+		crate::core::validity::check("Kinfu_Detail_PoseGraph", self.as_raw_Kinfu_Detail_PoseGraph())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_detail_PoseGraph_getNumNodes_const(self.as_raw_Kinfu_Detail_PoseGraph(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_detail_PoseGraph_getNumNodes_const(self.as_raw_Kinfu_Detail_PoseGraph(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2403,8 +3128,9 @@ pub trait Kinfu_Detail_PoseGraphConst {
 	
 	#[inline]
 	fn get_edge_start(&self, i: size_t) -> Result<size_t> {
+		crate::core::validity::check("Kinfu_Detail_PoseGraph", self.as_raw_Kinfu_Detail_PoseGraph())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_detail_PoseGraph_getEdgeStart_const_size_t(self.as_raw_Kinfu_Detail_PoseGraph(), i, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_detail_PoseGraph_getEdgeStart_const_size_t(self.as_raw_Kinfu_Detail_PoseGraph(), i, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2412,8 +3138,9 @@ pub trait Kinfu_Detail_PoseGraphConst {
 	
 	#[inline]
 	fn get_edge_end(&self, i: size_t) -> Result<size_t> {
+		crate::core::validity::check("Kinfu_Detail_PoseGraph", self.as_raw_Kinfu_Detail_PoseGraph())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_detail_PoseGraph_getEdgeEnd_const_size_t(self.as_raw_Kinfu_Detail_PoseGraph(), i, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_detail_PoseGraph_getEdgeEnd_const_size_t(self.as_raw_Kinfu_Detail_PoseGraph(), i, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2421,8 +3148,9 @@ pub trait Kinfu_Detail_PoseGraphConst {
 	
 	#[inline]
 	fn get_num_edges(&self) -> Result<size_t> {
+		crate::core::validity::check("Kinfu_Detail_PoseGraph", self.as_raw_Kinfu_Detail_PoseGraph())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_detail_PoseGraph_getNumEdges_const(self.as_raw_Kinfu_Detail_PoseGraph(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_detail_PoseGraph_getNumEdges_const(self.as_raw_Kinfu_Detail_PoseGraph(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2430,8 +3158,9 @@ pub trait Kinfu_Detail_PoseGraphConst {
 	
 	#[inline]
 	fn is_valid(&self) -> Result<bool> {
+		crate::core::validity::check("Kinfu_Detail_PoseGraph", self.as_raw_Kinfu_Detail_PoseGraph())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_detail_PoseGraph_isValid_const(self.as_raw_Kinfu_Detail_PoseGraph(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_detail_PoseGraph_isValid_const(self.as_raw_Kinfu_Detail_PoseGraph(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2439,8 +3168,9 @@ pub trait Kinfu_Detail_PoseGraphConst {
 	
 	#[inline]
 	fn calc_energy(&self) -> Result<f64> {
+		crate::core::validity::check("Kinfu_Detail_PoseGraph", self.as_raw_Kinfu_Detail_PoseGraph())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_detail_PoseGraph_calcEnergy_const(self.as_raw_Kinfu_Detail_PoseGraph(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_detail_PoseGraph_calcEnergy_const(self.as_raw_Kinfu_Detail_PoseGraph(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2453,8 +3183,9 @@ pub trait Kinfu_Detail_PoseGraph: crate::rgbd::Kinfu_Detail_PoseGraphConst {
 
 	#[inline]
 	fn add_node(&mut self, _node_id: size_t, _pose: core::Affine3d, fixed: bool) -> Result<()> {
+		crate::core::validity::check("Kinfu_Detail_PoseGraph", self.as_raw_mut_Kinfu_Detail_PoseGraph())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_detail_PoseGraph_addNode_size_t_const_Affine3dR_bool(self.as_raw_mut_Kinfu_Detail_PoseGraph(), _node_id, &_pose, fixed, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_detail_PoseGraph_addNode_size_t_const_Affine3dR_bool(self.as_raw_mut_Kinfu_Detail_PoseGraph(), _node_id, &_pose, fixed, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2462,8 +3193,9 @@ pub trait Kinfu_Detail_PoseGraph: crate::rgbd::Kinfu_Detail_PoseGraphConst {
 	
 	#[inline]
 	fn set_node_fixed(&mut self, node_id: size_t, fixed: bool) -> Result<bool> {
+		crate::core::validity::check("Kinfu_Detail_PoseGraph", self.as_raw_mut_Kinfu_Detail_PoseGraph())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_detail_PoseGraph_setNodeFixed_size_t_bool(self.as_raw_mut_Kinfu_Detail_PoseGraph(), node_id, fixed, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_detail_PoseGraph_setNodeFixed_size_t_bool(self.as_raw_mut_Kinfu_Detail_PoseGraph(), node_id, fixed, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2473,8 +3205,9 @@ pub trait Kinfu_Detail_PoseGraph: crate::rgbd::Kinfu_Detail_PoseGraphConst {
 	/// * _information: Matx66f::eye()
 	#[inline]
 	fn add_edge(&mut self, _source_node_id: size_t, _target_node_id: size_t, _transformation: core::Affine3f, _information: core::Matx66f) -> Result<()> {
+		crate::core::validity::check("Kinfu_Detail_PoseGraph", self.as_raw_mut_Kinfu_Detail_PoseGraph())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_detail_PoseGraph_addEdge_size_t_size_t_const_Affine3fR_const_Matx66fR(self.as_raw_mut_Kinfu_Detail_PoseGraph(), _source_node_id, _target_node_id, &_transformation, &_information, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_detail_PoseGraph_addEdge_size_t_size_t_const_Affine3fR_const_Matx66fR(self.as_raw_mut_Kinfu_Detail_PoseGraph(), _source_node_id, _target_node_id, &_transformation, &_information, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2484,8 +3217,9 @@ pub trait Kinfu_Detail_PoseGraph: crate::rgbd::Kinfu_Detail_PoseGraphConst {
 	/// * tc: cv::TermCriteria(TermCriteria::COUNT+TermCriteria::EPS,100,1e-6)
 	#[inline]
 	fn optimize(&mut self, tc: core::TermCriteria) -> Result<i32> {
+		crate::core::validity::check("Kinfu_Detail_PoseGraph", self.as_raw_mut_Kinfu_Detail_PoseGraph())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_detail_PoseGraph_optimize_const_TermCriteriaR(self.as_raw_mut_Kinfu_Detail_PoseGraph(), &tc, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_detail_PoseGraph_optimize_const_TermCriteriaR(self.as_raw_mut_Kinfu_Detail_PoseGraph(), &tc, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2497,7 +3231,7 @@ impl dyn Kinfu_Detail_PoseGraph + '_ {
 	#[inline]
 	pub fn create() -> Result<core::Ptr<dyn crate::rgbd::Kinfu_Detail_PoseGraph>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_kinfu_detail_PoseGraph_create(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_kinfu_detail_PoseGraph_create(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::rgbd::Kinfu_Detail_PoseGraph>::opencv_from_extern(ret) };
@@ -2539,8 +3273,9 @@ pub trait LargeKinfuConst {
 
 	#[inline]
 	fn get_params(&self) -> Result<crate::rgbd::Params> {
+		crate::core::validity::check("LargeKinfu", self.as_raw_LargeKinfu())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_large_kinfu_LargeKinfu_getParams_const(self.as_raw_LargeKinfu(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_large_kinfu_LargeKinfu_getParams_const(self.as_raw_LargeKinfu(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::Params::opencv_from_extern(ret) };
@@ -2549,9 +3284,10 @@ pub trait LargeKinfuConst {
 	
 	#[inline]
 	fn render(&self, image: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("LargeKinfu", self.as_raw_LargeKinfu())?;
 		output_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_large_kinfu_LargeKinfu_render_const_const__OutputArrayR(self.as_raw_LargeKinfu(), image.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_large_kinfu_LargeKinfu_render_const_const__OutputArrayR(self.as_raw_LargeKinfu(), image.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2559,9 +3295,10 @@ pub trait LargeKinfuConst {
 	
 	#[inline]
 	fn render_1(&self, image: &mut dyn core::ToOutputArray, camera_pose: core::Matx44f) -> Result<()> {
+		crate::core::validity::check("LargeKinfu", self.as_raw_LargeKinfu())?;
 		output_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_large_kinfu_LargeKinfu_render_const_const__OutputArrayR_const_Matx44fR(self.as_raw_LargeKinfu(), image.as_raw__OutputArray(), &camera_pose, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_large_kinfu_LargeKinfu_render_const_const__OutputArrayR_const_Matx44fR(self.as_raw_LargeKinfu(), image.as_raw__OutputArray(), &camera_pose, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2569,10 +3306,11 @@ pub trait LargeKinfuConst {
 	
 	#[inline]
 	fn get_cloud(&self, points: &mut dyn core::ToOutputArray, normals: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("LargeKinfu", self.as_raw_LargeKinfu())?;
 		output_array_arg!(points);
 		output_array_arg!(normals);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_large_kinfu_LargeKinfu_getCloud_const_const__OutputArrayR_const__OutputArrayR(self.as_raw_LargeKinfu(), points.as_raw__OutputArray(), normals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_large_kinfu_LargeKinfu_getCloud_const_const__OutputArrayR_const__OutputArrayR(self.as_raw_LargeKinfu(), points.as_raw__OutputArray(), normals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2580,9 +3318,10 @@ pub trait LargeKinfuConst {
 	
 	#[inline]
 	fn get_points(&self, points: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("LargeKinfu", self.as_raw_LargeKinfu())?;
 		output_array_arg!(points);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_large_kinfu_LargeKinfu_getPoints_const_const__OutputArrayR(self.as_raw_LargeKinfu(), points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_large_kinfu_LargeKinfu_getPoints_const_const__OutputArrayR(self.as_raw_LargeKinfu(), points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2590,10 +3329,11 @@ pub trait LargeKinfuConst {
 	
 	#[inline]
 	fn get_normals(&self, points: &dyn core::ToInputArray, normals: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("LargeKinfu", self.as_raw_LargeKinfu())?;
 		input_array_arg!(points);
 		output_array_arg!(normals);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_large_kinfu_LargeKinfu_getNormals_const_const__InputArrayR_const__OutputArrayR(self.as_raw_LargeKinfu(), points.as_raw__InputArray(), normals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_large_kinfu_LargeKinfu_getNormals_const_const__InputArrayR_const__OutputArrayR(self.as_raw_LargeKinfu(), points.as_raw__InputArray(), normals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2601,8 +3341,9 @@ pub trait LargeKinfuConst {
 	
 	#[inline]
 	fn get_pose(&self) -> Result<core::Affine3f> {
+		crate::core::validity::check("LargeKinfu", self.as_raw_LargeKinfu())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_large_kinfu_LargeKinfu_getPose_const(self.as_raw_LargeKinfu(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_large_kinfu_LargeKinfu_getPose_const(self.as_raw_LargeKinfu(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2615,8 +3356,9 @@ pub trait LargeKinfu: crate::rgbd::LargeKinfuConst {
 
 	#[inline]
 	fn reset(&mut self) -> Result<()> {
+		crate::core::validity::check("LargeKinfu", self.as_raw_mut_LargeKinfu())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_large_kinfu_LargeKinfu_reset(self.as_raw_mut_LargeKinfu(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_large_kinfu_LargeKinfu_reset(self.as_raw_mut_LargeKinfu(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2624,9 +3366,10 @@ pub trait LargeKinfu: crate::rgbd::LargeKinfuConst {
 	
 	#[inline]
 	fn update(&mut self, depth: &dyn core::ToInputArray) -> Result<bool> {
+		crate::core::validity::check("LargeKinfu", self.as_raw_mut_LargeKinfu())?;
 		input_array_arg!(depth);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_large_kinfu_LargeKinfu_update_const__InputArrayR(self.as_raw_mut_LargeKinfu(), depth.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_large_kinfu_LargeKinfu_update_const__InputArrayR(self.as_raw_mut_LargeKinfu(), depth.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2638,7 +3381,7 @@ impl dyn LargeKinfu + '_ {
 	#[inline]
 	pub fn create(_params: &core::Ptr<crate::rgbd::Params>) -> Result<core::Ptr<dyn crate::rgbd::LargeKinfu>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_large_kinfu_LargeKinfu_create_const_Ptr_Params_R(_params.as_raw_PtrOfParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_large_kinfu_LargeKinfu_create_const_Ptr_Params_R(_params.as_raw_PtrOfParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::rgbd::LargeKinfu>::opencv_from_extern(ret) };
@@ -2652,30 +3395,57 @@ pub trait ParamsTraitConst {
 	/// frame size in pixels
 	#[inline]
 	fn frame_size(&self) -> core::Size {
+		crate::core::validity::debug_check("Params", self.as_raw_Params());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_large_kinfu_Params_getPropFrameSize_const(self.as_raw_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_large_kinfu_Params_getPropFrameSize_const(self.as_raw_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [frame_size](ParamsTraitConst::frame_size), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_frame_size(&self) -> Result<core::Size> {
+		crate::core::validity::check("Params", self.as_raw_Params())?;
+		Ok(ParamsTraitConst::frame_size(self))
+	}
+	
 	/// camera intrinsics
 	#[inline]
 	fn intr(&self) -> core::Matx33f {
+		crate::core::validity::debug_check("Params", self.as_raw_Params());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_large_kinfu_Params_getPropIntr_const(self.as_raw_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_large_kinfu_Params_getPropIntr_const(self.as_raw_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [intr](ParamsTraitConst::intr), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_intr(&self) -> Result<core::Matx33f> {
+		crate::core::validity::check("Params", self.as_raw_Params())?;
+		Ok(ParamsTraitConst::intr(self))
+	}
+	
 	/// rgb camera intrinsics
 	#[inline]
 	fn rgb_intr(&self) -> core::Matx33f {
+		crate::core::validity::debug_check("Params", self.as_raw_Params());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_large_kinfu_Params_getPropRgb_intr_const(self.as_raw_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_large_kinfu_Params_getPropRgb_intr_const(self.as_raw_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [rgb_intr](ParamsTraitConst::rgb_intr), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_rgb_intr(&self) -> Result<core::Matx33f> {
+		crate::core::validity::check("Params", self.as_raw_Params())?;
+		Ok(ParamsTraitConst::rgb_intr(self))
+	}
+	
 	/// pre-scale per 1 meter for input values
 	/// Typical values are:
 	///      * 5000 per 1 meter for the 16-bit PNG files of TUM database
@@ -2683,93 +3453,201 @@ pub trait ParamsTraitConst {
 	///      * 1 per 1 meter for the 32-bit float images in the ROS bag files
 	#[inline]
 	fn depth_factor(&self) -> f32 {
-		let ret = unsafe { sys::cv_large_kinfu_Params_getPropDepthFactor_const(self.as_raw_Params()) };
+		crate::core::validity::debug_check("Params", self.as_raw_Params());
+		let ret = unsafe { ffi_call!(cv_large_kinfu_Params_getPropDepthFactor_const(self.as_raw_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [depth_factor](ParamsTraitConst::depth_factor), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_depth_factor(&self) -> Result<f32> {
+		crate::core::validity::check("Params", self.as_raw_Params())?;
+		Ok(ParamsTraitConst::depth_factor(self))
+	}
+	
 	/// Depth sigma in meters for bilateral smooth
 	#[inline]
 	fn bilateral_sigma_depth(&self) -> f32 {
-		let ret = unsafe { sys::cv_large_kinfu_Params_getPropBilateral_sigma_depth_const(self.as_raw_Params()) };
+		crate::core::validity::debug_check("Params", self.as_raw_Params());
+		let ret = unsafe { ffi_call!(cv_large_kinfu_Params_getPropBilateral_sigma_depth_const(self.as_raw_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [bilateral_sigma_depth](ParamsTraitConst::bilateral_sigma_depth), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_bilateral_sigma_depth(&self) -> Result<f32> {
+		crate::core::validity::check("Params", self.as_raw_Params())?;
+		Ok(ParamsTraitConst::bilateral_sigma_depth(self))
+	}
+	
 	/// Spatial sigma in pixels for bilateral smooth
 	#[inline]
 	fn bilateral_sigma_spatial(&self) -> f32 {
-		let ret = unsafe { sys::cv_large_kinfu_Params_getPropBilateral_sigma_spatial_const(self.as_raw_Params()) };
+		crate::core::validity::debug_check("Params", self.as_raw_Params());
+		let ret = unsafe { ffi_call!(cv_large_kinfu_Params_getPropBilateral_sigma_spatial_const(self.as_raw_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [bilateral_sigma_spatial](ParamsTraitConst::bilateral_sigma_spatial), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_bilateral_sigma_spatial(&self) -> Result<f32> {
+		crate::core::validity::check("Params", self.as_raw_Params())?;
+		Ok(ParamsTraitConst::bilateral_sigma_spatial(self))
+	}
+	
 	/// Kernel size in pixels for bilateral smooth
 	#[inline]
 	fn bilateral_kernel_size(&self) -> i32 {
-		let ret = unsafe { sys::cv_large_kinfu_Params_getPropBilateral_kernel_size_const(self.as_raw_Params()) };
+		crate::core::validity::debug_check("Params", self.as_raw_Params());
+		let ret = unsafe { ffi_call!(cv_large_kinfu_Params_getPropBilateral_kernel_size_const(self.as_raw_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [bilateral_kernel_size](ParamsTraitConst::bilateral_kernel_size), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_bilateral_kernel_size(&self) -> Result<i32> {
+		crate::core::validity::check("Params", self.as_raw_Params())?;
+		Ok(ParamsTraitConst::bilateral_kernel_size(self))
+	}
+	
 	/// Number of pyramid levels for ICP
 	#[inline]
 	fn pyramid_levels(&self) -> i32 {
-		let ret = unsafe { sys::cv_large_kinfu_Params_getPropPyramidLevels_const(self.as_raw_Params()) };
+		crate::core::validity::debug_check("Params", self.as_raw_Params());
+		let ret = unsafe { ffi_call!(cv_large_kinfu_Params_getPropPyramidLevels_const(self.as_raw_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [pyramid_levels](ParamsTraitConst::pyramid_levels), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pyramid_levels(&self) -> Result<i32> {
+		crate::core::validity::check("Params", self.as_raw_Params())?;
+		Ok(ParamsTraitConst::pyramid_levels(self))
+	}
+	
 	/// Minimal camera movement in meters
 	/// Integrate new depth frame only if camera movement exceeds this value.
 	#[inline]
 	fn tsdf_min_camera_movement(&self) -> f32 {
-		let ret = unsafe { sys::cv_large_kinfu_Params_getPropTsdf_min_camera_movement_const(self.as_raw_Params()) };
+		crate::core::validity::debug_check("Params", self.as_raw_Params());
+		let ret = unsafe { ffi_call!(cv_large_kinfu_Params_getPropTsdf_min_camera_movement_const(self.as_raw_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [tsdf_min_camera_movement](ParamsTraitConst::tsdf_min_camera_movement), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_tsdf_min_camera_movement(&self) -> Result<f32> {
+		crate::core::validity::check("Params", self.as_raw_Params())?;
+		Ok(ParamsTraitConst::tsdf_min_camera_movement(self))
+	}
+	
 	/// light pose for rendering in meters
 	#[inline]
 	fn light_pose(&self) -> core::Vec3f {
+		crate::core::validity::debug_check("Params", self.as_raw_Params());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_large_kinfu_Params_getPropLightPose_const(self.as_raw_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_large_kinfu_Params_getPropLightPose_const(self.as_raw_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [light_pose](ParamsTraitConst::light_pose), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_light_pose(&self) -> Result<core::Vec3f> {
+		crate::core::validity::check("Params", self.as_raw_Params())?;
+		Ok(ParamsTraitConst::light_pose(self))
+	}
+	
 	/// distance theshold for ICP in meters
 	#[inline]
 	fn icp_dist_thresh(&self) -> f32 {
-		let ret = unsafe { sys::cv_large_kinfu_Params_getPropIcpDistThresh_const(self.as_raw_Params()) };
+		crate::core::validity::debug_check("Params", self.as_raw_Params());
+		let ret = unsafe { ffi_call!(cv_large_kinfu_Params_getPropIcpDistThresh_const(self.as_raw_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [icp_dist_thresh](ParamsTraitConst::icp_dist_thresh), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_icp_dist_thresh(&self) -> Result<f32> {
+		crate::core::validity::check("Params", self.as_raw_Params())?;
+		Ok(ParamsTraitConst::icp_dist_thresh(self))
+	}
+	
 	/// angle threshold for ICP in radians
 	#[inline]
 	fn icp_angle_thresh(&self) -> f32 {
-		let ret = unsafe { sys::cv_large_kinfu_Params_getPropIcpAngleThresh_const(self.as_raw_Params()) };
+		crate::core::validity::debug_check("Params", self.as_raw_Params());
+		let ret = unsafe { ffi_call!(cv_large_kinfu_Params_getPropIcpAngleThresh_const(self.as_raw_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [icp_angle_thresh](ParamsTraitConst::icp_angle_thresh), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_icp_angle_thresh(&self) -> Result<f32> {
+		crate::core::validity::check("Params", self.as_raw_Params())?;
+		Ok(ParamsTraitConst::icp_angle_thresh(self))
+	}
+	
 	/// number of ICP iterations for each pyramid level
 	#[inline]
 	fn icp_iterations(&self) -> core::Vector<i32> {
-		let ret = unsafe { sys::cv_large_kinfu_Params_getPropIcpIterations_const(self.as_raw_Params()) };
+		crate::core::validity::debug_check("Params", self.as_raw_Params());
+		let ret = unsafe { ffi_call!(cv_large_kinfu_Params_getPropIcpIterations_const(self.as_raw_Params())) };
 		let ret = unsafe { core::Vector::<i32>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [icp_iterations](ParamsTraitConst::icp_iterations), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_icp_iterations(&self) -> Result<core::Vector<i32>> {
+		crate::core::validity::check("Params", self.as_raw_Params())?;
+		Ok(ParamsTraitConst::icp_iterations(self))
+	}
+	
 	/// Threshold for depth truncation in meters
 	/// All depth values beyond this threshold will be set to zero
 	#[inline]
 	fn truncate_threshold(&self) -> f32 {
-		let ret = unsafe { sys::cv_large_kinfu_Params_getPropTruncateThreshold_const(self.as_raw_Params()) };
+		crate::core::validity::debug_check("Params", self.as_raw_Params());
+		let ret = unsafe { ffi_call!(cv_large_kinfu_Params_getPropTruncateThreshold_const(self.as_raw_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [truncate_threshold](ParamsTraitConst::truncate_threshold), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_truncate_threshold(&self) -> Result<f32> {
+		crate::core::validity::check("Params", self.as_raw_Params())?;
+		Ok(ParamsTraitConst::truncate_threshold(self))
+	}
+	
 	/// Volume parameters
 	#[inline]
 	fn volume_params(&self) -> crate::rgbd::Kinfu_VolumeParams {
-		let ret = unsafe { sys::cv_large_kinfu_Params_getPropVolumeParams_const(self.as_raw_Params()) };
+		crate::core::validity::debug_check("Params", self.as_raw_Params());
+		let ret = unsafe { ffi_call!(cv_large_kinfu_Params_getPropVolumeParams_const(self.as_raw_Params())) };
 		let ret = unsafe { crate::rgbd::Kinfu_VolumeParams::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [volume_params](ParamsTraitConst::volume_params), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_volume_params(&self) -> Result<crate::rgbd::Kinfu_VolumeParams> {
+		crate::core::validity::check("Params", self.as_raw_Params())?;
+		Ok(ParamsTraitConst::volume_params(self))
+	}
+	
 }
 
 pub trait ParamsTrait: crate::rgbd::ParamsTraitConst {
@@ -2778,21 +3656,24 @@ pub trait ParamsTrait: crate::rgbd::ParamsTraitConst {
 	/// frame size in pixels
 	#[inline]
 	fn set_frame_size(&mut self, val: core::Size) {
-		let ret = unsafe { sys::cv_large_kinfu_Params_setPropFrameSize_Size(self.as_raw_mut_Params(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("Params", self.as_raw_mut_Params());
+		let ret = unsafe { ffi_call!(cv_large_kinfu_Params_setPropFrameSize_Size(self.as_raw_mut_Params(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	/// camera intrinsics
 	#[inline]
 	fn set_intr(&mut self, val: core::Matx33f) {
-		let ret = unsafe { sys::cv_large_kinfu_Params_setPropIntr_Matx33f(self.as_raw_mut_Params(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("Params", self.as_raw_mut_Params());
+		let ret = unsafe { ffi_call!(cv_large_kinfu_Params_setPropIntr_Matx33f(self.as_raw_mut_Params(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	/// rgb camera intrinsics
 	#[inline]
 	fn set_rgb_intr(&mut self, val: core::Matx33f) {
-		let ret = unsafe { sys::cv_large_kinfu_Params_setPropRgb_intr_Matx33f(self.as_raw_mut_Params(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("Params", self.as_raw_mut_Params());
+		let ret = unsafe { ffi_call!(cv_large_kinfu_Params_setPropRgb_intr_Matx33f(self.as_raw_mut_Params(), val.opencv_as_extern())) };
 		ret
 	}
 	
@@ -2803,35 +3684,40 @@ pub trait ParamsTrait: crate::rgbd::ParamsTraitConst {
 	///      * 1 per 1 meter for the 32-bit float images in the ROS bag files
 	#[inline]
 	fn set_depth_factor(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_large_kinfu_Params_setPropDepthFactor_float(self.as_raw_mut_Params(), val) };
+		crate::core::validity::debug_check("Params", self.as_raw_mut_Params());
+		let ret = unsafe { ffi_call!(cv_large_kinfu_Params_setPropDepthFactor_float(self.as_raw_mut_Params(), val)) };
 		ret
 	}
 	
 	/// Depth sigma in meters for bilateral smooth
 	#[inline]
 	fn set_bilateral_sigma_depth(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_large_kinfu_Params_setPropBilateral_sigma_depth_float(self.as_raw_mut_Params(), val) };
+		crate::core::validity::debug_check("Params", self.as_raw_mut_Params());
+		let ret = unsafe { ffi_call!(cv_large_kinfu_Params_setPropBilateral_sigma_depth_float(self.as_raw_mut_Params(), val)) };
 		ret
 	}
 	
 	/// Spatial sigma in pixels for bilateral smooth
 	#[inline]
 	fn set_bilateral_sigma_spatial(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_large_kinfu_Params_setPropBilateral_sigma_spatial_float(self.as_raw_mut_Params(), val) };
+		crate::core::validity::debug_check("Params", self.as_raw_mut_Params());
+		let ret = unsafe { ffi_call!(cv_large_kinfu_Params_setPropBilateral_sigma_spatial_float(self.as_raw_mut_Params(), val)) };
 		ret
 	}
 	
 	/// Kernel size in pixels for bilateral smooth
 	#[inline]
 	fn set_bilateral_kernel_size(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_large_kinfu_Params_setPropBilateral_kernel_size_int(self.as_raw_mut_Params(), val) };
+		crate::core::validity::debug_check("Params", self.as_raw_mut_Params());
+		let ret = unsafe { ffi_call!(cv_large_kinfu_Params_setPropBilateral_kernel_size_int(self.as_raw_mut_Params(), val)) };
 		ret
 	}
 	
 	/// Number of pyramid levels for ICP
 	#[inline]
 	fn set_pyramid_levels(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_large_kinfu_Params_setPropPyramidLevels_int(self.as_raw_mut_Params(), val) };
+		crate::core::validity::debug_check("Params", self.as_raw_mut_Params());
+		let ret = unsafe { ffi_call!(cv_large_kinfu_Params_setPropPyramidLevels_int(self.as_raw_mut_Params(), val)) };
 		ret
 	}
 	
@@ -2839,35 +3725,40 @@ pub trait ParamsTrait: crate::rgbd::ParamsTraitConst {
 	/// Integrate new depth frame only if camera movement exceeds this value.
 	#[inline]
 	fn set_tsdf_min_camera_movement(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_large_kinfu_Params_setPropTsdf_min_camera_movement_float(self.as_raw_mut_Params(), val) };
+		crate::core::validity::debug_check("Params", self.as_raw_mut_Params());
+		let ret = unsafe { ffi_call!(cv_large_kinfu_Params_setPropTsdf_min_camera_movement_float(self.as_raw_mut_Params(), val)) };
 		ret
 	}
 	
 	/// light pose for rendering in meters
 	#[inline]
 	fn set_light_pose(&mut self, val: core::Vec3f) {
-		let ret = unsafe { sys::cv_large_kinfu_Params_setPropLightPose_Vec3f(self.as_raw_mut_Params(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("Params", self.as_raw_mut_Params());
+		let ret = unsafe { ffi_call!(cv_large_kinfu_Params_setPropLightPose_Vec3f(self.as_raw_mut_Params(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	/// distance theshold for ICP in meters
 	#[inline]
 	fn set_icp_dist_thresh(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_large_kinfu_Params_setPropIcpDistThresh_float(self.as_raw_mut_Params(), val) };
+		crate::core::validity::debug_check("Params", self.as_raw_mut_Params());
+		let ret = unsafe { ffi_call!(cv_large_kinfu_Params_setPropIcpDistThresh_float(self.as_raw_mut_Params(), val)) };
 		ret
 	}
 	
 	/// angle threshold for ICP in radians
 	#[inline]
 	fn set_icp_angle_thresh(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_large_kinfu_Params_setPropIcpAngleThresh_float(self.as_raw_mut_Params(), val) };
+		crate::core::validity::debug_check("Params", self.as_raw_mut_Params());
+		let ret = unsafe { ffi_call!(cv_large_kinfu_Params_setPropIcpAngleThresh_float(self.as_raw_mut_Params(), val)) };
 		ret
 	}
 	
 	/// number of ICP iterations for each pyramid level
 	#[inline]
 	fn set_icp_iterations(&mut self, mut val: core::Vector<i32>) {
-		let ret = unsafe { sys::cv_large_kinfu_Params_setPropIcpIterations_vector_int_(self.as_raw_mut_Params(), val.as_raw_mut_VectorOfi32()) };
+		crate::core::validity::debug_check("Params", self.as_raw_mut_Params());
+		let ret = unsafe { ffi_call!(cv_large_kinfu_Params_setPropIcpIterations_vector_int_(self.as_raw_mut_Params(), val.as_raw_mut_VectorOfi32())) };
 		ret
 	}
 	
@@ -2875,14 +3766,16 @@ pub trait ParamsTrait: crate::rgbd::ParamsTraitConst {
 	/// All depth values beyond this threshold will be set to zero
 	#[inline]
 	fn set_truncate_threshold(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_large_kinfu_Params_setPropTruncateThreshold_float(self.as_raw_mut_Params(), val) };
+		crate::core::validity::debug_check("Params", self.as_raw_mut_Params());
+		let ret = unsafe { ffi_call!(cv_large_kinfu_Params_setPropTruncateThreshold_float(self.as_raw_mut_Params(), val)) };
 		ret
 	}
 	
 	/// Volume parameters
 	#[inline]
 	fn set_volume_params(&mut self, mut val: crate::rgbd::Kinfu_VolumeParams) {
-		let ret = unsafe { sys::cv_large_kinfu_Params_setPropVolumeParams_VolumeParams(self.as_raw_mut_Params(), val.as_raw_mut_Kinfu_VolumeParams()) };
+		crate::core::validity::debug_check("Params", self.as_raw_mut_Params());
+		let ret = unsafe { ffi_call!(cv_large_kinfu_Params_setPropVolumeParams_VolumeParams(self.as_raw_mut_Params(), val.as_raw_mut_Kinfu_VolumeParams())) };
 		ret
 	}
 	
@@ -2897,7 +3790,10 @@ opencv_type_boxed! { Params }
 impl Drop for Params {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Params_delete(instance: *mut c_void); }
-		unsafe { cv_Params_delete(self.as_raw_mut_Params()) };
+		let ptr = self.as_raw_mut_Params();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Params_delete(ptr) };
 	}
 }
 
@@ -2917,7 +3813,7 @@ impl Params {
 	#[inline]
 	pub fn default_params() -> Result<core::Ptr<crate::rgbd::Params>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_large_kinfu_Params_defaultParams(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_large_kinfu_Params_defaultParams(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::rgbd::Params>::opencv_from_extern(ret) };
@@ -2930,7 +3826,7 @@ impl Params {
 	#[inline]
 	pub fn coarse_params() -> Result<core::Ptr<crate::rgbd::Params>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_large_kinfu_Params_coarseParams(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_large_kinfu_Params_coarseParams(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::rgbd::Params>::opencv_from_extern(ret) };
@@ -2942,7 +3838,7 @@ impl Params {
 	#[inline]
 	pub fn hash_tsdf_params(is_coarse: bool) -> Result<core::Ptr<crate::rgbd::Params>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_large_kinfu_Params_hashTSDFParams_bool(is_coarse, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_large_kinfu_Params_hashTSDFParams_bool(is_coarse, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::rgbd::Params>::opencv_from_extern(ret) };
@@ -2957,26 +3853,54 @@ pub trait Linemod_ColorGradientTraitConst: crate::rgbd::Linemod_ModalityConst {
 
 	#[inline]
 	fn weak_threshold(&self) -> f32 {
-		let ret = unsafe { sys::cv_linemod_ColorGradient_getPropWeak_threshold_const(self.as_raw_Linemod_ColorGradient()) };
+		crate::core::validity::debug_check("Linemod_ColorGradient", self.as_raw_Linemod_ColorGradient());
+		let ret = unsafe { ffi_call!(cv_linemod_ColorGradient_getPropWeak_threshold_const(self.as_raw_Linemod_ColorGradient())) };
 		ret
 	}
 	
+	/// Non-panicking version of [weak_threshold](Linemod_ColorGradientTraitConst::weak_threshold), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_weak_threshold(&self) -> Result<f32> {
+		crate::core::validity::check("Linemod_ColorGradient", self.as_raw_Linemod_ColorGradient())?;
+		Ok(Linemod_ColorGradientTraitConst::weak_threshold(self))
+	}
+	
 	#[inline]
 	fn num_features(&self) -> size_t {
-		let ret = unsafe { sys::cv_linemod_ColorGradient_getPropNum_features_const(self.as_raw_Linemod_ColorGradient()) };
+		crate::core::validity::debug_check("Linemod_ColorGradient", self.as_raw_Linemod_ColorGradient());
+		let ret = unsafe { ffi_call!(cv_linemod_ColorGradient_getPropNum_features_const(self.as_raw_Linemod_ColorGradient())) };
 		ret
 	}
 	
+	/// Non-panicking version of [num_features](Linemod_ColorGradientTraitConst::num_features), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_num_features(&self) -> Result<size_t> {
+		crate::core::validity::check("Linemod_ColorGradient", self.as_raw_Linemod_ColorGradient())?;
+		Ok(Linemod_ColorGradientTraitConst::num_features(self))
+	}
+	
 	#[inline]
 	fn strong_threshold(&self) -> f32 {
-		let ret = unsafe { sys::cv_linemod_ColorGradient_getPropStrong_threshold_const(self.as_raw_Linemod_ColorGradient()) };
+		crate::core::validity::debug_check("Linemod_ColorGradient", self.as_raw_Linemod_ColorGradient());
+		let ret = unsafe { ffi_call!(cv_linemod_ColorGradient_getPropStrong_threshold_const(self.as_raw_Linemod_ColorGradient())) };
 		ret
 	}
 	
+	/// Non-panicking version of [strong_threshold](Linemod_ColorGradientTraitConst::strong_threshold), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_strong_threshold(&self) -> Result<f32> {
+		crate::core::validity::check("Linemod_ColorGradient", self.as_raw_Linemod_ColorGradient())?;
+		Ok(Linemod_ColorGradientTraitConst::strong_threshold(self))
+	}
+	
 	#[inline]
 	fn name(&self) -> Result<String> {
+		crate::core::validity::check("Linemod_ColorGradient", self.as_raw_Linemod_ColorGradient())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_ColorGradient_name_const(self.as_raw_Linemod_ColorGradient(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_ColorGradient_name_const(self.as_raw_Linemod_ColorGradient(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -2985,8 +3909,9 @@ pub trait Linemod_ColorGradientTraitConst: crate::rgbd::Linemod_ModalityConst {
 	
 	#[inline]
 	fn write(&self, fs: &mut core::FileStorage) -> Result<()> {
+		crate::core::validity::check("Linemod_ColorGradient", self.as_raw_Linemod_ColorGradient())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_ColorGradient_write_const_FileStorageR(self.as_raw_Linemod_ColorGradient(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_ColorGradient_write_const_FileStorageR(self.as_raw_Linemod_ColorGradient(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2999,26 +3924,30 @@ pub trait Linemod_ColorGradientTrait: crate::rgbd::Linemod_ColorGradientTraitCon
 
 	#[inline]
 	fn set_weak_threshold(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_linemod_ColorGradient_setPropWeak_threshold_float(self.as_raw_mut_Linemod_ColorGradient(), val) };
+		crate::core::validity::debug_check("Linemod_ColorGradient", self.as_raw_mut_Linemod_ColorGradient());
+		let ret = unsafe { ffi_call!(cv_linemod_ColorGradient_setPropWeak_threshold_float(self.as_raw_mut_Linemod_ColorGradient(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_num_features(&mut self, val: size_t) {
-		let ret = unsafe { sys::cv_linemod_ColorGradient_setPropNum_features_size_t(self.as_raw_mut_Linemod_ColorGradient(), val) };
+		crate::core::validity::debug_check("Linemod_ColorGradient", self.as_raw_mut_Linemod_ColorGradient());
+		let ret = unsafe { ffi_call!(cv_linemod_ColorGradient_setPropNum_features_size_t(self.as_raw_mut_Linemod_ColorGradient(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_strong_threshold(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_linemod_ColorGradient_setPropStrong_threshold_float(self.as_raw_mut_Linemod_ColorGradient(), val) };
+		crate::core::validity::debug_check("Linemod_ColorGradient", self.as_raw_mut_Linemod_ColorGradient());
+		let ret = unsafe { ffi_call!(cv_linemod_ColorGradient_setPropStrong_threshold_float(self.as_raw_mut_Linemod_ColorGradient(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn read(&mut self, fn_: &core::FileNode) -> Result<()> {
+		crate::core::validity::check("Linemod_ColorGradient", self.as_raw_mut_Linemod_ColorGradient())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_ColorGradient_read_const_FileNodeR(self.as_raw_mut_Linemod_ColorGradient(), fn_.as_raw_FileNode(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_ColorGradient_read_const_FileNodeR(self.as_raw_mut_Linemod_ColorGradient(), fn_.as_raw_FileNode(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3036,7 +3965,10 @@ opencv_type_boxed! { Linemod_ColorGradient }
 impl Drop for Linemod_ColorGradient {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Linemod_ColorGradient_delete(instance: *mut c_void); }
-		unsafe { cv_Linemod_ColorGradient_delete(self.as_raw_mut_Linemod_ColorGradient()) };
+		let ptr = self.as_raw_mut_Linemod_ColorGradient();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Linemod_ColorGradient_delete(ptr) };
 	}
 }
 
@@ -3063,7 +3995,7 @@ impl Linemod_ColorGradient {
 	#[inline]
 	pub fn default() -> Result<crate::rgbd::Linemod_ColorGradient> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_ColorGradient_ColorGradient(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_ColorGradient_ColorGradient(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::Linemod_ColorGradient::opencv_from_extern(ret) };
@@ -3079,7 +4011,7 @@ impl Linemod_ColorGradient {
 	#[inline]
 	pub fn new(weak_threshold: f32, num_features: size_t, strong_threshold: f32) -> Result<crate::rgbd::Linemod_ColorGradient> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_ColorGradient_ColorGradient_float_size_t_float(weak_threshold, num_features, strong_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_ColorGradient_ColorGradient_float_size_t_float(weak_threshold, num_features, strong_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::Linemod_ColorGradient::opencv_from_extern(ret) };
@@ -3089,7 +4021,7 @@ impl Linemod_ColorGradient {
 	#[inline]
 	pub fn create(weak_threshold: f32, num_features: size_t, strong_threshold: f32) -> Result<core::Ptr<crate::rgbd::Linemod_ColorGradient>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_ColorGradient_create_float_size_t_float(weak_threshold, num_features, strong_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_ColorGradient_create_float_size_t_float(weak_threshold, num_features, strong_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::rgbd::Linemod_ColorGradient>::opencv_from_extern(ret) };
@@ -3104,32 +4036,69 @@ pub trait Linemod_DepthNormalTraitConst: crate::rgbd::Linemod_ModalityConst {
 
 	#[inline]
 	fn distance_threshold(&self) -> i32 {
-		let ret = unsafe { sys::cv_linemod_DepthNormal_getPropDistance_threshold_const(self.as_raw_Linemod_DepthNormal()) };
+		crate::core::validity::debug_check("Linemod_DepthNormal", self.as_raw_Linemod_DepthNormal());
+		let ret = unsafe { ffi_call!(cv_linemod_DepthNormal_getPropDistance_threshold_const(self.as_raw_Linemod_DepthNormal())) };
 		ret
 	}
 	
+	/// Non-panicking version of [distance_threshold](Linemod_DepthNormalTraitConst::distance_threshold), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_distance_threshold(&self) -> Result<i32> {
+		crate::core::validity::check("Linemod_DepthNormal", self.as_raw_Linemod_DepthNormal())?;
+		Ok(Linemod_DepthNormalTraitConst::distance_threshold(self))
+	}
+	
 	#[inline]
 	fn difference_threshold(&self) -> i32 {
-		let ret = unsafe { sys::cv_linemod_DepthNormal_getPropDifference_threshold_const(self.as_raw_Linemod_DepthNormal()) };
+		crate::core::validity::debug_check("Linemod_DepthNormal", self.as_raw_Linemod_DepthNormal());
+		let ret = unsafe { ffi_call!(cv_linemod_DepthNormal_getPropDifference_threshold_const(self.as_raw_Linemod_DepthNormal())) };
 		ret
 	}
 	
+	/// Non-panicking version of [difference_threshold](Linemod_DepthNormalTraitConst::difference_threshold), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_difference_threshold(&self) -> Result<i32> {
+		crate::core::validity::check("Linemod_DepthNormal", self.as_raw_Linemod_DepthNormal())?;
+		Ok(Linemod_DepthNormalTraitConst::difference_threshold(self))
+	}
+	
 	#[inline]
 	fn num_features(&self) -> size_t {
-		let ret = unsafe { sys::cv_linemod_DepthNormal_getPropNum_features_const(self.as_raw_Linemod_DepthNormal()) };
+		crate::core::validity::debug_check("Linemod_DepthNormal", self.as_raw_Linemod_DepthNormal());
+		let ret = unsafe { ffi_call!(cv_linemod_DepthNormal_getPropNum_features_const(self.as_raw_Linemod_DepthNormal())) };
 		ret
 	}
 	
+	/// Non-panicking version of [num_features](Linemod_DepthNormalTraitConst::num_features), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_num_features(&self) -> Result<size_t> {
+		crate::core::validity::check("Linemod_DepthNormal", self.as_raw_Linemod_DepthNormal())?;
+		Ok(Linemod_DepthNormalTraitConst::num_features(self))
+	}
+	
 	#[inline]
 	fn extract_threshold(&self) -> i32 {
-		let ret = unsafe { sys::cv_linemod_DepthNormal_getPropExtract_threshold_const(self.as_raw_Linemod_DepthNormal()) };
+		crate::core::validity::debug_check("Linemod_DepthNormal", self.as_raw_Linemod_DepthNormal());
+		let ret = unsafe { ffi_call!(cv_linemod_DepthNormal_getPropExtract_threshold_const(self.as_raw_Linemod_DepthNormal())) };
 		ret
 	}
 	
+	/// Non-panicking version of [extract_threshold](Linemod_DepthNormalTraitConst::extract_threshold), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_extract_threshold(&self) -> Result<i32> {
+		crate::core::validity::check("Linemod_DepthNormal", self.as_raw_Linemod_DepthNormal())?;
+		Ok(Linemod_DepthNormalTraitConst::extract_threshold(self))
+	}
+	
 	#[inline]
 	fn name(&self) -> Result<String> {
+		crate::core::validity::check("Linemod_DepthNormal", self.as_raw_Linemod_DepthNormal())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_DepthNormal_name_const(self.as_raw_Linemod_DepthNormal(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_DepthNormal_name_const(self.as_raw_Linemod_DepthNormal(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -3138,8 +4107,9 @@ pub trait Linemod_DepthNormalTraitConst: crate::rgbd::Linemod_ModalityConst {
 	
 	#[inline]
 	fn write(&self, fs: &mut core::FileStorage) -> Result<()> {
+		crate::core::validity::check("Linemod_DepthNormal", self.as_raw_Linemod_DepthNormal())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_DepthNormal_write_const_FileStorageR(self.as_raw_Linemod_DepthNormal(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_DepthNormal_write_const_FileStorageR(self.as_raw_Linemod_DepthNormal(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3152,32 +4122,37 @@ pub trait Linemod_DepthNormalTrait: crate::rgbd::Linemod_DepthNormalTraitConst +
 
 	#[inline]
 	fn set_distance_threshold(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_linemod_DepthNormal_setPropDistance_threshold_int(self.as_raw_mut_Linemod_DepthNormal(), val) };
+		crate::core::validity::debug_check("Linemod_DepthNormal", self.as_raw_mut_Linemod_DepthNormal());
+		let ret = unsafe { ffi_call!(cv_linemod_DepthNormal_setPropDistance_threshold_int(self.as_raw_mut_Linemod_DepthNormal(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_difference_threshold(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_linemod_DepthNormal_setPropDifference_threshold_int(self.as_raw_mut_Linemod_DepthNormal(), val) };
+		crate::core::validity::debug_check("Linemod_DepthNormal", self.as_raw_mut_Linemod_DepthNormal());
+		let ret = unsafe { ffi_call!(cv_linemod_DepthNormal_setPropDifference_threshold_int(self.as_raw_mut_Linemod_DepthNormal(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_num_features(&mut self, val: size_t) {
-		let ret = unsafe { sys::cv_linemod_DepthNormal_setPropNum_features_size_t(self.as_raw_mut_Linemod_DepthNormal(), val) };
+		crate::core::validity::debug_check("Linemod_DepthNormal", self.as_raw_mut_Linemod_DepthNormal());
+		let ret = unsafe { ffi_call!(cv_linemod_DepthNormal_setPropNum_features_size_t(self.as_raw_mut_Linemod_DepthNormal(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_extract_threshold(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_linemod_DepthNormal_setPropExtract_threshold_int(self.as_raw_mut_Linemod_DepthNormal(), val) };
+		crate::core::validity::debug_check("Linemod_DepthNormal", self.as_raw_mut_Linemod_DepthNormal());
+		let ret = unsafe { ffi_call!(cv_linemod_DepthNormal_setPropExtract_threshold_int(self.as_raw_mut_Linemod_DepthNormal(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn read(&mut self, fn_: &core::FileNode) -> Result<()> {
+		crate::core::validity::check("Linemod_DepthNormal", self.as_raw_mut_Linemod_DepthNormal())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_DepthNormal_read_const_FileNodeR(self.as_raw_mut_Linemod_DepthNormal(), fn_.as_raw_FileNode(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_DepthNormal_read_const_FileNodeR(self.as_raw_mut_Linemod_DepthNormal(), fn_.as_raw_FileNode(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3195,7 +4170,10 @@ opencv_type_boxed! { Linemod_DepthNormal }
 impl Drop for Linemod_DepthNormal {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Linemod_DepthNormal_delete(instance: *mut c_void); }
-		unsafe { cv_Linemod_DepthNormal_delete(self.as_raw_mut_Linemod_DepthNormal()) };
+		let ptr = self.as_raw_mut_Linemod_DepthNormal();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Linemod_DepthNormal_delete(ptr) };
 	}
 }
 
@@ -3222,7 +4200,7 @@ impl Linemod_DepthNormal {
 	#[inline]
 	pub fn default() -> Result<crate::rgbd::Linemod_DepthNormal> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_DepthNormal_DepthNormal(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_DepthNormal_DepthNormal(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::Linemod_DepthNormal::opencv_from_extern(ret) };
@@ -3240,7 +4218,7 @@ impl Linemod_DepthNormal {
 	#[inline]
 	pub fn new(distance_threshold: i32, difference_threshold: i32, num_features: size_t, extract_threshold: i32) -> Result<crate::rgbd::Linemod_DepthNormal> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_DepthNormal_DepthNormal_int_int_size_t_int(distance_threshold, difference_threshold, num_features, extract_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_DepthNormal_DepthNormal_int_int_size_t_int(distance_threshold, difference_threshold, num_features, extract_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::Linemod_DepthNormal::opencv_from_extern(ret) };
@@ -3250,7 +4228,7 @@ impl Linemod_DepthNormal {
 	#[inline]
 	pub fn create(distance_threshold: i32, difference_threshold: i32, num_features: size_t, extract_threshold: i32) -> Result<core::Ptr<crate::rgbd::Linemod_DepthNormal>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_DepthNormal_create_int_int_size_t_int(distance_threshold, difference_threshold, num_features, extract_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_DepthNormal_create_int_int_size_t_int(distance_threshold, difference_threshold, num_features, extract_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::rgbd::Linemod_DepthNormal>::opencv_from_extern(ret) };
@@ -3270,9 +4248,9 @@ pub trait Linemod_DetectorTraitConst {
 	/// 
 	/// \param      sources   Source images, one for each modality.
 	/// \param      threshold Similarity threshold, a percentage between 0 and 100.
-	/// \param[out] matches   Template matches, sorted by similarity score.
+	/// \param\[out\] matches   Template matches, sorted by similarity score.
 	/// \param      class_ids If non-empty, only search for the desired object classes.
-	/// \param[out] quantized_images Optionally return vector<Mat> of quantized images.
+	/// \param\[out\] quantized_images Optionally return vector<Mat> of quantized images.
 	/// \param      masks     The masks for consideration during matching. The masks should be CV_8UC1
 	///                       where 255 represents a valid pixel.  If non-empty, the vector must be
 	///                       the same size as sources.  Each element must be
@@ -3284,9 +4262,10 @@ pub trait Linemod_DetectorTraitConst {
 	/// * masks: std::vector<Mat>()
 	#[inline]
 	fn match_(&self, sources: &core::Vector<core::Mat>, threshold: f32, matches: &mut core::Vector<crate::rgbd::Linemod_Match>, class_ids: &core::Vector<String>, quantized_images: &mut dyn core::ToOutputArray, masks: &core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("Linemod_Detector", self.as_raw_Linemod_Detector())?;
 		output_array_arg!(quantized_images);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Detector_match_const_const_vector_Mat_R_float_vector_Match_R_const_vector_String_R_const__OutputArrayR_const_vector_Mat_R(self.as_raw_Linemod_Detector(), sources.as_raw_VectorOfMat(), threshold, matches.as_raw_mut_VectorOfLinemod_Match(), class_ids.as_raw_VectorOfString(), quantized_images.as_raw__OutputArray(), masks.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Detector_match_const_const_vector_Mat_R_float_vector_Match_R_const_vector_String_R_const__OutputArrayR_const_vector_Mat_R(self.as_raw_Linemod_Detector(), sources.as_raw_VectorOfMat(), threshold, matches.as_raw_mut_VectorOfLinemod_Match(), class_ids.as_raw_VectorOfString(), quantized_images.as_raw__OutputArray(), masks.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3298,8 +4277,9 @@ pub trait Linemod_DetectorTraitConst {
 	/// tweak parameters.
 	#[inline]
 	fn get_modalities(&self) -> Result<core::Vector<core::Ptr<dyn crate::rgbd::Linemod_Modality>>> {
+		crate::core::validity::check("Linemod_Detector", self.as_raw_Linemod_Detector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Detector_getModalities_const(self.as_raw_Linemod_Detector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Detector_getModalities_const(self.as_raw_Linemod_Detector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<core::Ptr<dyn crate::rgbd::Linemod_Modality>>::opencv_from_extern(ret) };
@@ -3309,8 +4289,9 @@ pub trait Linemod_DetectorTraitConst {
 	/// \brief Get sampling step T at pyramid_level.
 	#[inline]
 	fn get_t(&self, pyramid_level: i32) -> Result<i32> {
+		crate::core::validity::check("Linemod_Detector", self.as_raw_Linemod_Detector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Detector_getT_const_int(self.as_raw_Linemod_Detector(), pyramid_level, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Detector_getT_const_int(self.as_raw_Linemod_Detector(), pyramid_level, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3319,8 +4300,9 @@ pub trait Linemod_DetectorTraitConst {
 	/// \brief Get number of pyramid levels used by this detector.
 	#[inline]
 	fn pyramid_levels(&self) -> Result<i32> {
+		crate::core::validity::check("Linemod_Detector", self.as_raw_Linemod_Detector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Detector_pyramidLevels_const(self.as_raw_Linemod_Detector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Detector_pyramidLevels_const(self.as_raw_Linemod_Detector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3332,9 +4314,10 @@ pub trait Linemod_DetectorTraitConst {
 	/// (L0, L1), the order is (GradientL0, NormalL0, GradientL1, NormalL1).
 	#[inline]
 	fn get_templates(&self, class_id: &str, template_id: i32) -> Result<core::Vector<crate::rgbd::Linemod_Template>> {
+		crate::core::validity::check("Linemod_Detector", self.as_raw_Linemod_Detector())?;
 		extern_container_arg!(class_id);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Detector_getTemplates_const_const_StringR_int(self.as_raw_Linemod_Detector(), class_id.opencv_as_extern(), template_id, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Detector_getTemplates_const_const_StringR_int(self.as_raw_Linemod_Detector(), class_id.opencv_as_extern(), template_id, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<crate::rgbd::Linemod_Template>::opencv_from_extern(ret) };
@@ -3343,8 +4326,9 @@ pub trait Linemod_DetectorTraitConst {
 	
 	#[inline]
 	fn num_templates(&self) -> Result<i32> {
+		crate::core::validity::check("Linemod_Detector", self.as_raw_Linemod_Detector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Detector_numTemplates_const(self.as_raw_Linemod_Detector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Detector_numTemplates_const(self.as_raw_Linemod_Detector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3352,9 +4336,10 @@ pub trait Linemod_DetectorTraitConst {
 	
 	#[inline]
 	fn num_templates_1(&self, class_id: &str) -> Result<i32> {
+		crate::core::validity::check("Linemod_Detector", self.as_raw_Linemod_Detector())?;
 		extern_container_arg!(class_id);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Detector_numTemplates_const_const_StringR(self.as_raw_Linemod_Detector(), class_id.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Detector_numTemplates_const_const_StringR(self.as_raw_Linemod_Detector(), class_id.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3362,8 +4347,9 @@ pub trait Linemod_DetectorTraitConst {
 	
 	#[inline]
 	fn num_classes(&self) -> Result<i32> {
+		crate::core::validity::check("Linemod_Detector", self.as_raw_Linemod_Detector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Detector_numClasses_const(self.as_raw_Linemod_Detector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Detector_numClasses_const(self.as_raw_Linemod_Detector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3371,8 +4357,9 @@ pub trait Linemod_DetectorTraitConst {
 	
 	#[inline]
 	fn class_ids(&self) -> Result<core::Vector<String>> {
+		crate::core::validity::check("Linemod_Detector", self.as_raw_Linemod_Detector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Detector_classIds_const(self.as_raw_Linemod_Detector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Detector_classIds_const(self.as_raw_Linemod_Detector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<String>::opencv_from_extern(ret) };
@@ -3381,8 +4368,9 @@ pub trait Linemod_DetectorTraitConst {
 	
 	#[inline]
 	fn write(&self, fs: &mut core::FileStorage) -> Result<()> {
+		crate::core::validity::check("Linemod_Detector", self.as_raw_Linemod_Detector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Detector_write_const_FileStorageR(self.as_raw_Linemod_Detector(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Detector_write_const_FileStorageR(self.as_raw_Linemod_Detector(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3390,9 +4378,10 @@ pub trait Linemod_DetectorTraitConst {
 	
 	#[inline]
 	fn write_class(&self, class_id: &str, fs: &mut core::FileStorage) -> Result<()> {
+		crate::core::validity::check("Linemod_Detector", self.as_raw_Linemod_Detector())?;
 		extern_container_arg!(class_id);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Detector_writeClass_const_const_StringR_FileStorageR(self.as_raw_Linemod_Detector(), class_id.opencv_as_extern(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Detector_writeClass_const_const_StringR_FileStorageR(self.as_raw_Linemod_Detector(), class_id.opencv_as_extern(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3402,9 +4391,10 @@ pub trait Linemod_DetectorTraitConst {
 	/// * format: "templates_%s.yml.gz"
 	#[inline]
 	fn write_classes(&self, format: &str) -> Result<()> {
+		crate::core::validity::check("Linemod_Detector", self.as_raw_Linemod_Detector())?;
 		extern_container_arg!(format);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Detector_writeClasses_const_const_StringR(self.as_raw_Linemod_Detector(), format.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Detector_writeClasses_const_const_StringR(self.as_raw_Linemod_Detector(), format.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3412,6 +4402,41 @@ pub trait Linemod_DetectorTraitConst {
 	
 }
 
+/// Builder for `Linemod_DetectorTraitConst::match_()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct Linemod_Detector_MatchBuilder<'a, T: ?Sized + Linemod_DetectorTraitConst> {
+	instance: &'a T,
+	sources: &'a core::Vector<core::Mat>,
+	threshold: f32,
+	matches: &'a mut core::Vector<crate::rgbd::Linemod_Match>,
+	class_ids: &'a core::Vector<String>,
+	quantized_images: Option<&'a mut dyn core::ToOutputArray>,
+	masks: &'a core::Vector<core::Mat>,
+}
+
+impl<'a, T: ?Sized + Linemod_DetectorTraitConst> Linemod_Detector_MatchBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a T, sources: &'a core::Vector<core::Mat>, threshold: f32, matches: &'a mut core::Vector<crate::rgbd::Linemod_Match>, class_ids: &'a core::Vector<String>, masks: &'a core::Vector<core::Mat>) -> Self {
+		Self { instance, sources, threshold, matches, class_ids, quantized_images: None, masks }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_quantized_images(mut self, quantized_images: &'a mut dyn core::ToOutputArray) -> Self {
+		self.quantized_images = Some(quantized_images);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let mut quantized_images_no_array = core::no_array();
+		let quantized_images: &mut dyn core::ToOutputArray = match self.quantized_images { Some(quantized_images) => quantized_images, None => &mut quantized_images_no_array };
+		Linemod_DetectorTraitConst::match_(self.instance, self.sources, self.threshold, self.matches, self.class_ids, quantized_images, self.masks)
+	}
+}
+
 pub trait Linemod_DetectorTrait: crate::rgbd::Linemod_DetectorTraitConst {
 	fn as_raw_mut_Linemod_Detector(&mut self) -> *mut c_void;
 
@@ -3420,7 +4445,7 @@ pub trait Linemod_DetectorTrait: crate::rgbd::Linemod_DetectorTraitConst {
 	/// \param      sources      Source images, one for each modality.
 	/// \param      class_id     Object class ID.
 	/// \param      object_mask  Mask separating object from background.
-	/// \param[out] bounding_box Optionally return bounding box of the extracted features.
+	/// \param\[out\] bounding_box Optionally return bounding box of the extracted features.
 	/// 
 	/// \return Template ID, or -1 if failed to extract a valid template.
 	/// 
@@ -3428,9 +4453,10 @@ pub trait Linemod_DetectorTrait: crate::rgbd::Linemod_DetectorTraitConst {
 	/// * bounding_box: NULL
 	#[inline]
 	fn add_template(&mut self, sources: &core::Vector<core::Mat>, class_id: &str, object_mask: &core::Mat, bounding_box: &mut core::Rect) -> Result<i32> {
+		crate::core::validity::check("Linemod_Detector", self.as_raw_mut_Linemod_Detector())?;
 		extern_container_arg!(class_id);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Detector_addTemplate_const_vector_Mat_R_const_StringR_const_MatR_RectX(self.as_raw_mut_Linemod_Detector(), sources.as_raw_VectorOfMat(), class_id.opencv_as_extern(), object_mask.as_raw_Mat(), bounding_box, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Detector_addTemplate_const_vector_Mat_R_const_StringR_const_MatR_RectX(self.as_raw_mut_Linemod_Detector(), sources.as_raw_VectorOfMat(), class_id.opencv_as_extern(), object_mask.as_raw_Mat(), bounding_box, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3439,9 +4465,10 @@ pub trait Linemod_DetectorTrait: crate::rgbd::Linemod_DetectorTraitConst {
 	/// \brief Add a new object template computed by external means.
 	#[inline]
 	fn add_synthetic_template(&mut self, templates: &core::Vector<crate::rgbd::Linemod_Template>, class_id: &str) -> Result<i32> {
+		crate::core::validity::check("Linemod_Detector", self.as_raw_mut_Linemod_Detector())?;
 		extern_container_arg!(class_id);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Detector_addSyntheticTemplate_const_vector_Template_R_const_StringR(self.as_raw_mut_Linemod_Detector(), templates.as_raw_VectorOfLinemod_Template(), class_id.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Detector_addSyntheticTemplate_const_vector_Template_R_const_StringR(self.as_raw_mut_Linemod_Detector(), templates.as_raw_VectorOfLinemod_Template(), class_id.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3449,8 +4476,9 @@ pub trait Linemod_DetectorTrait: crate::rgbd::Linemod_DetectorTraitConst {
 	
 	#[inline]
 	fn read(&mut self, fn_: &core::FileNode) -> Result<()> {
+		crate::core::validity::check("Linemod_Detector", self.as_raw_mut_Linemod_Detector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Detector_read_const_FileNodeR(self.as_raw_mut_Linemod_Detector(), fn_.as_raw_FileNode(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Detector_read_const_FileNodeR(self.as_raw_mut_Linemod_Detector(), fn_.as_raw_FileNode(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3460,9 +4488,10 @@ pub trait Linemod_DetectorTrait: crate::rgbd::Linemod_DetectorTraitConst {
 	/// * class_id_override: ""
 	#[inline]
 	fn read_class(&mut self, fn_: &core::FileNode, class_id_override: &str) -> Result<String> {
+		crate::core::validity::check("Linemod_Detector", self.as_raw_mut_Linemod_Detector())?;
 		extern_container_arg!(class_id_override);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Detector_readClass_const_FileNodeR_const_StringR(self.as_raw_mut_Linemod_Detector(), fn_.as_raw_FileNode(), class_id_override.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Detector_readClass_const_FileNodeR_const_StringR(self.as_raw_mut_Linemod_Detector(), fn_.as_raw_FileNode(), class_id_override.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -3473,9 +4502,10 @@ pub trait Linemod_DetectorTrait: crate::rgbd::Linemod_DetectorTraitConst {
 	/// * format: "templates_%s.yml.gz"
 	#[inline]
 	fn read_classes(&mut self, class_ids: &core::Vector<String>, format: &str) -> Result<()> {
+		crate::core::validity::check("Linemod_Detector", self.as_raw_mut_Linemod_Detector())?;
 		extern_container_arg!(format);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Detector_readClasses_const_vector_String_R_const_StringR(self.as_raw_mut_Linemod_Detector(), class_ids.as_raw_VectorOfString(), format.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Detector_readClasses_const_vector_String_R_const_StringR(self.as_raw_mut_Linemod_Detector(), class_ids.as_raw_VectorOfString(), format.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3494,7 +4524,10 @@ opencv_type_boxed! { Linemod_Detector }
 impl Drop for Linemod_Detector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Linemod_Detector_delete(instance: *mut c_void); }
-		unsafe { cv_Linemod_Detector_delete(self.as_raw_mut_Linemod_Detector()) };
+		let ptr = self.as_raw_mut_Linemod_Detector();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Linemod_Detector_delete(ptr) };
 	}
 }
 
@@ -3513,7 +4546,7 @@ impl Linemod_Detector {
 	#[inline]
 	pub fn default() -> Result<crate::rgbd::Linemod_Detector> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Detector_Detector(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Detector_Detector(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::Linemod_Detector::opencv_from_extern(ret) };
@@ -3528,7 +4561,7 @@ impl Linemod_Detector {
 	#[inline]
 	pub fn new(modalities: &core::Vector<core::Ptr<dyn crate::rgbd::Linemod_Modality>>, t_pyramid: &core::Vector<i32>) -> Result<crate::rgbd::Linemod_Detector> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Detector_Detector_const_vector_Ptr_Modality__R_const_vector_int_R(modalities.as_raw_VectorOfPtrOfLinemod_Modality(), t_pyramid.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Detector_Detector_const_vector_Ptr_Modality__R_const_vector_int_R(modalities.as_raw_VectorOfPtrOfLinemod_Modality(), t_pyramid.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::Linemod_Detector::opencv_from_extern(ret) };
@@ -3555,7 +4588,7 @@ impl Linemod_Feature {
 	#[inline]
 	pub fn write(self, fs: &mut core::FileStorage) -> Result<()> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Feature_write_const_FileStorageR(self.opencv_as_extern(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Feature_write_const_FileStorageR(self.opencv_as_extern(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3564,7 +4597,7 @@ impl Linemod_Feature {
 	#[inline]
 	pub fn default() -> Result<crate::rgbd::Linemod_Feature> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Feature_Feature(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Feature_Feature(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3573,7 +4606,7 @@ impl Linemod_Feature {
 	#[inline]
 	pub fn new(x: i32, y: i32, label: i32) -> Result<crate::rgbd::Linemod_Feature> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Feature_Feature_int_int_int(x, y, label, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Feature_Feature_int_int_int(x, y, label, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3582,7 +4615,7 @@ impl Linemod_Feature {
 	#[inline]
 	pub fn read(self, fn_: &core::FileNode) -> Result<()> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Feature_read_const_FileNodeR(self.opencv_as_extern(), fn_.as_raw_FileNode(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Feature_read_const_FileNodeR(self.opencv_as_extern(), fn_.as_raw_FileNode(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3596,39 +4629,85 @@ pub trait Linemod_MatchTraitConst {
 
 	#[inline]
 	fn x(&self) -> i32 {
-		let ret = unsafe { sys::cv_linemod_Match_getPropX_const(self.as_raw_Linemod_Match()) };
+		crate::core::validity::debug_check("Linemod_Match", self.as_raw_Linemod_Match());
+		let ret = unsafe { ffi_call!(cv_linemod_Match_getPropX_const(self.as_raw_Linemod_Match())) };
 		ret
 	}
 	
+	/// Non-panicking version of [x](Linemod_MatchTraitConst::x), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_x(&self) -> Result<i32> {
+		crate::core::validity::check("Linemod_Match", self.as_raw_Linemod_Match())?;
+		Ok(Linemod_MatchTraitConst::x(self))
+	}
+	
 	#[inline]
 	fn y(&self) -> i32 {
-		let ret = unsafe { sys::cv_linemod_Match_getPropY_const(self.as_raw_Linemod_Match()) };
+		crate::core::validity::debug_check("Linemod_Match", self.as_raw_Linemod_Match());
+		let ret = unsafe { ffi_call!(cv_linemod_Match_getPropY_const(self.as_raw_Linemod_Match())) };
 		ret
 	}
 	
+	/// Non-panicking version of [y](Linemod_MatchTraitConst::y), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_y(&self) -> Result<i32> {
+		crate::core::validity::check("Linemod_Match", self.as_raw_Linemod_Match())?;
+		Ok(Linemod_MatchTraitConst::y(self))
+	}
+	
 	#[inline]
 	fn similarity(&self) -> f32 {
-		let ret = unsafe { sys::cv_linemod_Match_getPropSimilarity_const(self.as_raw_Linemod_Match()) };
+		crate::core::validity::debug_check("Linemod_Match", self.as_raw_Linemod_Match());
+		let ret = unsafe { ffi_call!(cv_linemod_Match_getPropSimilarity_const(self.as_raw_Linemod_Match())) };
 		ret
 	}
 	
+	/// Non-panicking version of [similarity](Linemod_MatchTraitConst::similarity), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_similarity(&self) -> Result<f32> {
+		crate::core::validity::check("Linemod_Match", self.as_raw_Linemod_Match())?;
+		Ok(Linemod_MatchTraitConst::similarity(self))
+	}
+	
 	#[inline]
 	fn class_id(&self) -> String {
-		let ret = unsafe { sys::cv_linemod_Match_getPropClass_id_const(self.as_raw_Linemod_Match()) };
+		crate::core::validity::debug_check("Linemod_Match", self.as_raw_Linemod_Match());
+		let ret = unsafe { ffi_call!(cv_linemod_Match_getPropClass_id_const(self.as_raw_Linemod_Match())) };
 		let ret = unsafe { String::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [class_id](Linemod_MatchTraitConst::class_id), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_class_id(&self) -> Result<String> {
+		crate::core::validity::check("Linemod_Match", self.as_raw_Linemod_Match())?;
+		Ok(Linemod_MatchTraitConst::class_id(self))
+	}
+	
 	#[inline]
 	fn template_id(&self) -> i32 {
-		let ret = unsafe { sys::cv_linemod_Match_getPropTemplate_id_const(self.as_raw_Linemod_Match()) };
+		crate::core::validity::debug_check("Linemod_Match", self.as_raw_Linemod_Match());
+		let ret = unsafe { ffi_call!(cv_linemod_Match_getPropTemplate_id_const(self.as_raw_Linemod_Match())) };
 		ret
 	}
 	
+	/// Non-panicking version of [template_id](Linemod_MatchTraitConst::template_id), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_template_id(&self) -> Result<i32> {
+		crate::core::validity::check("Linemod_Match", self.as_raw_Linemod_Match())?;
+		Ok(Linemod_MatchTraitConst::template_id(self))
+	}
+	
 	#[inline]
 	fn equals(&self, rhs: &crate::rgbd::Linemod_Match) -> Result<bool> {
+		crate::core::validity::check("Linemod_Match", self.as_raw_Linemod_Match())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Match_operatorEQ_const_const_MatchR(self.as_raw_Linemod_Match(), rhs.as_raw_Linemod_Match(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Match_operatorEQ_const_const_MatchR(self.as_raw_Linemod_Match(), rhs.as_raw_Linemod_Match(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3641,32 +4720,37 @@ pub trait Linemod_MatchTrait: crate::rgbd::Linemod_MatchTraitConst {
 
 	#[inline]
 	fn set_x(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_linemod_Match_setPropX_int(self.as_raw_mut_Linemod_Match(), val) };
+		crate::core::validity::debug_check("Linemod_Match", self.as_raw_mut_Linemod_Match());
+		let ret = unsafe { ffi_call!(cv_linemod_Match_setPropX_int(self.as_raw_mut_Linemod_Match(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_y(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_linemod_Match_setPropY_int(self.as_raw_mut_Linemod_Match(), val) };
+		crate::core::validity::debug_check("Linemod_Match", self.as_raw_mut_Linemod_Match());
+		let ret = unsafe { ffi_call!(cv_linemod_Match_setPropY_int(self.as_raw_mut_Linemod_Match(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_similarity(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_linemod_Match_setPropSimilarity_float(self.as_raw_mut_Linemod_Match(), val) };
+		crate::core::validity::debug_check("Linemod_Match", self.as_raw_mut_Linemod_Match());
+		let ret = unsafe { ffi_call!(cv_linemod_Match_setPropSimilarity_float(self.as_raw_mut_Linemod_Match(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_class_id(&mut self, val: &str) {
+		crate::core::validity::debug_check("Linemod_Match", self.as_raw_mut_Linemod_Match());
 		extern_container_arg!(nofail mut val);
-		let ret = unsafe { sys::cv_linemod_Match_setPropClass_id_String(self.as_raw_mut_Linemod_Match(), val.opencv_as_extern_mut()) };
+		let ret = unsafe { ffi_call!(cv_linemod_Match_setPropClass_id_String(self.as_raw_mut_Linemod_Match(), val.opencv_as_extern_mut())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_template_id(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_linemod_Match_setPropTemplate_id_int(self.as_raw_mut_Linemod_Match(), val) };
+		crate::core::validity::debug_check("Linemod_Match", self.as_raw_mut_Linemod_Match());
+		let ret = unsafe { ffi_call!(cv_linemod_Match_setPropTemplate_id_int(self.as_raw_mut_Linemod_Match(), val)) };
 		ret
 	}
 	
@@ -3682,7 +4766,10 @@ opencv_type_boxed! { Linemod_Match }
 impl Drop for Linemod_Match {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Linemod_Match_delete(instance: *mut c_void); }
-		unsafe { cv_Linemod_Match_delete(self.as_raw_mut_Linemod_Match()) };
+		let ptr = self.as_raw_mut_Linemod_Match();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Linemod_Match_delete(ptr) };
 	}
 }
 
@@ -3700,7 +4787,7 @@ impl Linemod_Match {
 	#[inline]
 	pub fn default() -> Result<crate::rgbd::Linemod_Match> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Match_Match(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Match_Match(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::Linemod_Match::opencv_from_extern(ret) };
@@ -3711,7 +4798,7 @@ impl Linemod_Match {
 	pub fn new(x: i32, y: i32, similarity: f32, class_id: &str, template_id: i32) -> Result<crate::rgbd::Linemod_Match> {
 		extern_container_arg!(class_id);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Match_Match_int_int_float_const_StringR_int(x, y, similarity, class_id.opencv_as_extern(), template_id, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Match_Match_int_int_float_const_StringR_int(x, y, similarity, class_id.opencv_as_extern(), template_id, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::Linemod_Match::opencv_from_extern(ret) };
@@ -3728,16 +4815,17 @@ pub trait Linemod_ModalityConst {
 
 	/// \brief Form a quantized image pyramid from a source image.
 	/// 
-	/// \param[in] src  The source image. Type depends on the modality.
-	/// \param[in] mask Optional mask. If not empty, unmasked pixels are set to zero
+	/// \param\[in\] src  The source image. Type depends on the modality.
+	/// \param\[in\] mask Optional mask. If not empty, unmasked pixels are set to zero
 	///                in quantized image and cannot be extracted as features.
 	/// 
 	/// ## C++ default parameters
 	/// * mask: Mat()
 	#[inline]
 	fn process(&self, src: &core::Mat, mask: &core::Mat) -> Result<core::Ptr<dyn crate::rgbd::Linemod_QuantizedPyramid>> {
+		crate::core::validity::check("Linemod_Modality", self.as_raw_Linemod_Modality())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Modality_process_const_const_MatR_const_MatR(self.as_raw_Linemod_Modality(), src.as_raw_Mat(), mask.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Modality_process_const_const_MatR_const_MatR(self.as_raw_Linemod_Modality(), src.as_raw_Mat(), mask.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::rgbd::Linemod_QuantizedPyramid>::opencv_from_extern(ret) };
@@ -3746,8 +4834,9 @@ pub trait Linemod_ModalityConst {
 	
 	#[inline]
 	fn name(&self) -> Result<String> {
+		crate::core::validity::check("Linemod_Modality", self.as_raw_Linemod_Modality())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Modality_name_const(self.as_raw_Linemod_Modality(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Modality_name_const(self.as_raw_Linemod_Modality(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -3756,8 +4845,9 @@ pub trait Linemod_ModalityConst {
 	
 	#[inline]
 	fn write(&self, fs: &mut core::FileStorage) -> Result<()> {
+		crate::core::validity::check("Linemod_Modality", self.as_raw_Linemod_Modality())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Modality_write_const_FileStorageR(self.as_raw_Linemod_Modality(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Modality_write_const_FileStorageR(self.as_raw_Linemod_Modality(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3770,8 +4860,9 @@ pub trait Linemod_Modality: crate::rgbd::Linemod_ModalityConst {
 
 	#[inline]
 	fn read(&mut self, fn_: &core::FileNode) -> Result<()> {
+		crate::core::validity::check("Linemod_Modality", self.as_raw_mut_Linemod_Modality())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Modality_read_const_FileNodeR(self.as_raw_mut_Linemod_Modality(), fn_.as_raw_FileNode(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Modality_read_const_FileNodeR(self.as_raw_mut_Linemod_Modality(), fn_.as_raw_FileNode(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3789,7 +4880,7 @@ impl dyn Linemod_Modality + '_ {
 	pub fn create(modality_type: &str) -> Result<core::Ptr<dyn crate::rgbd::Linemod_Modality>> {
 		extern_container_arg!(modality_type);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Modality_create_const_StringR(modality_type.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Modality_create_const_StringR(modality_type.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::rgbd::Linemod_Modality>::opencv_from_extern(ret) };
@@ -3800,7 +4891,7 @@ impl dyn Linemod_Modality + '_ {
 	#[inline]
 	pub fn create_1(fn_: &core::FileNode) -> Result<core::Ptr<dyn crate::rgbd::Linemod_Modality>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Modality_create_const_FileNodeR(fn_.as_raw_FileNode(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Modality_create_const_FileNodeR(fn_.as_raw_FileNode(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::rgbd::Linemod_Modality>::opencv_from_extern(ret) };
@@ -3814,12 +4905,13 @@ pub trait Linemod_QuantizedPyramidConst {
 
 	/// \brief Compute quantized image at current pyramid level for online detection.
 	/// 
-	/// \param[out] dst The destination 8-bit image. For each pixel at most one bit is set,
+	/// \param\[out\] dst The destination 8-bit image. For each pixel at most one bit is set,
 	///                representing its classification.
 	#[inline]
 	fn quantize(&self, dst: &mut core::Mat) -> Result<()> {
+		crate::core::validity::check("Linemod_QuantizedPyramid", self.as_raw_Linemod_QuantizedPyramid())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_QuantizedPyramid_quantize_const_MatR(self.as_raw_Linemod_QuantizedPyramid(), dst.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_QuantizedPyramid_quantize_const_MatR(self.as_raw_Linemod_QuantizedPyramid(), dst.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3827,11 +4919,12 @@ pub trait Linemod_QuantizedPyramidConst {
 	
 	/// \brief Extract most discriminant features at current pyramid level to form a new template.
 	/// 
-	/// \param[out] templ The new template.
+	/// \param\[out\] templ The new template.
 	#[inline]
 	fn extract_template(&self, templ: &mut crate::rgbd::Linemod_Template) -> Result<bool> {
+		crate::core::validity::check("Linemod_QuantizedPyramid", self.as_raw_Linemod_QuantizedPyramid())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_QuantizedPyramid_extractTemplate_const_TemplateR(self.as_raw_Linemod_QuantizedPyramid(), templ.as_raw_mut_Linemod_Template(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_QuantizedPyramid_extractTemplate_const_TemplateR(self.as_raw_Linemod_QuantizedPyramid(), templ.as_raw_mut_Linemod_Template(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3847,8 +4940,9 @@ pub trait Linemod_QuantizedPyramid: crate::rgbd::Linemod_QuantizedPyramidConst {
 	/// \todo Allow pyramid scale factor other than 2
 	#[inline]
 	fn pyr_down(&mut self) -> Result<()> {
+		crate::core::validity::check("Linemod_QuantizedPyramid", self.as_raw_mut_Linemod_QuantizedPyramid())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_QuantizedPyramid_pyrDown(self.as_raw_mut_Linemod_QuantizedPyramid(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_QuantizedPyramid_pyrDown(self.as_raw_mut_Linemod_QuantizedPyramid(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3861,33 +4955,70 @@ pub trait Linemod_TemplateTraitConst {
 
 	#[inline]
 	fn width(&self) -> i32 {
-		let ret = unsafe { sys::cv_linemod_Template_getPropWidth_const(self.as_raw_Linemod_Template()) };
+		crate::core::validity::debug_check("Linemod_Template", self.as_raw_Linemod_Template());
+		let ret = unsafe { ffi_call!(cv_linemod_Template_getPropWidth_const(self.as_raw_Linemod_Template())) };
 		ret
 	}
 	
+	/// Non-panicking version of [width](Linemod_TemplateTraitConst::width), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_width(&self) -> Result<i32> {
+		crate::core::validity::check("Linemod_Template", self.as_raw_Linemod_Template())?;
+		Ok(Linemod_TemplateTraitConst::width(self))
+	}
+	
 	#[inline]
 	fn height(&self) -> i32 {
-		let ret = unsafe { sys::cv_linemod_Template_getPropHeight_const(self.as_raw_Linemod_Template()) };
+		crate::core::validity::debug_check("Linemod_Template", self.as_raw_Linemod_Template());
+		let ret = unsafe { ffi_call!(cv_linemod_Template_getPropHeight_const(self.as_raw_Linemod_Template())) };
 		ret
 	}
 	
+	/// Non-panicking version of [height](Linemod_TemplateTraitConst::height), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_height(&self) -> Result<i32> {
+		crate::core::validity::check("Linemod_Template", self.as_raw_Linemod_Template())?;
+		Ok(Linemod_TemplateTraitConst::height(self))
+	}
+	
 	#[inline]
 	fn pyramid_level(&self) -> i32 {
-		let ret = unsafe { sys::cv_linemod_Template_getPropPyramid_level_const(self.as_raw_Linemod_Template()) };
+		crate::core::validity::debug_check("Linemod_Template", self.as_raw_Linemod_Template());
+		let ret = unsafe { ffi_call!(cv_linemod_Template_getPropPyramid_level_const(self.as_raw_Linemod_Template())) };
 		ret
 	}
 	
+	/// Non-panicking version of [pyramid_level](Linemod_TemplateTraitConst::pyramid_level), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pyramid_level(&self) -> Result<i32> {
+		crate::core::validity::check("Linemod_Template", self.as_raw_Linemod_Template())?;
+		Ok(Linemod_TemplateTraitConst::pyramid_level(self))
+	}
+	
 	#[inline]
 	fn features(&self) -> core::Vector<crate::rgbd::Linemod_Feature> {
-		let ret = unsafe { sys::cv_linemod_Template_getPropFeatures_const(self.as_raw_Linemod_Template()) };
+		crate::core::validity::debug_check("Linemod_Template", self.as_raw_Linemod_Template());
+		let ret = unsafe { ffi_call!(cv_linemod_Template_getPropFeatures_const(self.as_raw_Linemod_Template())) };
 		let ret = unsafe { core::Vector::<crate::rgbd::Linemod_Feature>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [features](Linemod_TemplateTraitConst::features), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_features(&self) -> Result<core::Vector<crate::rgbd::Linemod_Feature>> {
+		crate::core::validity::check("Linemod_Template", self.as_raw_Linemod_Template())?;
+		Ok(Linemod_TemplateTraitConst::features(self))
+	}
+	
 	#[inline]
 	fn write(&self, fs: &mut core::FileStorage) -> Result<()> {
+		crate::core::validity::check("Linemod_Template", self.as_raw_Linemod_Template())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Template_write_const_FileStorageR(self.as_raw_Linemod_Template(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Template_write_const_FileStorageR(self.as_raw_Linemod_Template(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3900,32 +5031,37 @@ pub trait Linemod_TemplateTrait: crate::rgbd::Linemod_TemplateTraitConst {
 
 	#[inline]
 	fn set_width(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_linemod_Template_setPropWidth_int(self.as_raw_mut_Linemod_Template(), val) };
+		crate::core::validity::debug_check("Linemod_Template", self.as_raw_mut_Linemod_Template());
+		let ret = unsafe { ffi_call!(cv_linemod_Template_setPropWidth_int(self.as_raw_mut_Linemod_Template(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_height(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_linemod_Template_setPropHeight_int(self.as_raw_mut_Linemod_Template(), val) };
+		crate::core::validity::debug_check("Linemod_Template", self.as_raw_mut_Linemod_Template());
+		let ret = unsafe { ffi_call!(cv_linemod_Template_setPropHeight_int(self.as_raw_mut_Linemod_Template(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_pyramid_level(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_linemod_Template_setPropPyramid_level_int(self.as_raw_mut_Linemod_Template(), val) };
+		crate::core::validity::debug_check("Linemod_Template", self.as_raw_mut_Linemod_Template());
+		let ret = unsafe { ffi_call!(cv_linemod_Template_setPropPyramid_level_int(self.as_raw_mut_Linemod_Template(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_features(&mut self, mut val: core::Vector<crate::rgbd::Linemod_Feature>) {
-		let ret = unsafe { sys::cv_linemod_Template_setPropFeatures_vector_Feature_(self.as_raw_mut_Linemod_Template(), val.as_raw_mut_VectorOfLinemod_Feature()) };
+		crate::core::validity::debug_check("Linemod_Template", self.as_raw_mut_Linemod_Template());
+		let ret = unsafe { ffi_call!(cv_linemod_Template_setPropFeatures_vector_Feature_(self.as_raw_mut_Linemod_Template(), val.as_raw_mut_VectorOfLinemod_Feature())) };
 		ret
 	}
 	
 	#[inline]
 	fn read(&mut self, fn_: &core::FileNode) -> Result<()> {
+		crate::core::validity::check("Linemod_Template", self.as_raw_mut_Linemod_Template())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Template_read_const_FileNodeR(self.as_raw_mut_Linemod_Template(), fn_.as_raw_FileNode(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_linemod_Template_read_const_FileNodeR(self.as_raw_mut_Linemod_Template(), fn_.as_raw_FileNode(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3942,7 +5078,10 @@ opencv_type_boxed! { Linemod_Template }
 impl Drop for Linemod_Template {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Linemod_Template_delete(instance: *mut c_void); }
-		unsafe { cv_Linemod_Template_delete(self.as_raw_mut_Linemod_Template()) };
+		let ptr = self.as_raw_mut_Linemod_Template();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Linemod_Template_delete(ptr) };
 	}
 }
 
@@ -3967,8 +5106,9 @@ pub trait DepthCleanerTraitConst: core::AlgorithmTraitConst {
 	/// If that function is not called, it will be called the first time normals are computed
 	#[inline]
 	fn initialize(&self) -> Result<()> {
+		crate::core::validity::check("DepthCleaner", self.as_raw_DepthCleaner())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_DepthCleaner_initialize_const(self.as_raw_DepthCleaner(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_DepthCleaner_initialize_const(self.as_raw_DepthCleaner(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3976,8 +5116,9 @@ pub trait DepthCleanerTraitConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_window_size(&self) -> Result<i32> {
+		crate::core::validity::check("DepthCleaner", self.as_raw_DepthCleaner())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_DepthCleaner_getWindowSize_const(self.as_raw_DepthCleaner(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_DepthCleaner_getWindowSize_const(self.as_raw_DepthCleaner(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3985,8 +5126,9 @@ pub trait DepthCleanerTraitConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_depth(&self) -> Result<i32> {
+		crate::core::validity::check("DepthCleaner", self.as_raw_DepthCleaner())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_DepthCleaner_getDepth_const(self.as_raw_DepthCleaner(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_DepthCleaner_getDepth_const(self.as_raw_DepthCleaner(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3994,8 +5136,9 @@ pub trait DepthCleanerTraitConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_method(&self) -> Result<i32> {
+		crate::core::validity::check("DepthCleaner", self.as_raw_DepthCleaner())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_DepthCleaner_getMethod_const(self.as_raw_DepthCleaner(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_DepthCleaner_getMethod_const(self.as_raw_DepthCleaner(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4008,8 +5151,9 @@ pub trait DepthCleanerTrait: core::AlgorithmTrait + crate::rgbd::DepthCleanerTra
 
 	#[inline]
 	fn set_window_size(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("DepthCleaner", self.as_raw_mut_DepthCleaner())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_DepthCleaner_setWindowSize_int(self.as_raw_mut_DepthCleaner(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_DepthCleaner_setWindowSize_int(self.as_raw_mut_DepthCleaner(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4017,8 +5161,9 @@ pub trait DepthCleanerTrait: core::AlgorithmTrait + crate::rgbd::DepthCleanerTra
 	
 	#[inline]
 	fn set_depth(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("DepthCleaner", self.as_raw_mut_DepthCleaner())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_DepthCleaner_setDepth_int(self.as_raw_mut_DepthCleaner(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_DepthCleaner_setDepth_int(self.as_raw_mut_DepthCleaner(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4026,8 +5171,9 @@ pub trait DepthCleanerTrait: core::AlgorithmTrait + crate::rgbd::DepthCleanerTra
 	
 	#[inline]
 	fn set_method(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("DepthCleaner", self.as_raw_mut_DepthCleaner())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_DepthCleaner_setMethod_int(self.as_raw_mut_DepthCleaner(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_DepthCleaner_setMethod_int(self.as_raw_mut_DepthCleaner(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4045,7 +5191,10 @@ opencv_type_boxed! { DepthCleaner }
 impl Drop for DepthCleaner {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DepthCleaner_delete(instance: *mut c_void); }
-		unsafe { cv_DepthCleaner_delete(self.as_raw_mut_DepthCleaner()) };
+		let ptr = self.as_raw_mut_DepthCleaner();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_DepthCleaner_delete(ptr) };
 	}
 }
 
@@ -4071,7 +5220,7 @@ impl DepthCleaner {
 	#[inline]
 	pub fn default() -> Result<crate::rgbd::DepthCleaner> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_DepthCleaner_DepthCleaner(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_DepthCleaner_DepthCleaner(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::DepthCleaner::opencv_from_extern(ret) };
@@ -4090,7 +5239,7 @@ impl DepthCleaner {
 	#[inline]
 	pub fn new(depth: i32, window_size: i32, method: i32) -> Result<crate::rgbd::DepthCleaner> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_DepthCleaner_DepthCleaner_int_int_int(depth, window_size, method, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_DepthCleaner_DepthCleaner_int_int_int(depth, window_size, method, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::DepthCleaner::opencv_from_extern(ret) };
@@ -4103,7 +5252,7 @@ impl DepthCleaner {
 	#[inline]
 	pub fn create(depth: i32, window_size: i32, method: i32) -> Result<core::Ptr<crate::rgbd::DepthCleaner>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_DepthCleaner_create_int_int_int(depth, window_size, method, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_DepthCleaner_create_int_int_int(depth, window_size, method, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::rgbd::DepthCleaner>::opencv_from_extern(ret) };
@@ -4129,8 +5278,9 @@ pub trait FastICPOdometryTraitConst: crate::rgbd::OdometryConst {
 
 	#[inline]
 	fn prepare_frame_cache(&self, frame: &mut core::Ptr<crate::rgbd::OdometryFrame>, cache_type: i32) -> Result<core::Size> {
+		crate::core::validity::check("FastICPOdometry", self.as_raw_FastICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_FastICPOdometry_prepareFrameCache_const_Ptr_OdometryFrame_R_int(self.as_raw_FastICPOdometry(), frame.as_raw_mut_PtrOfOdometryFrame(), cache_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_FastICPOdometry_prepareFrameCache_const_Ptr_OdometryFrame_R_int(self.as_raw_FastICPOdometry(), frame.as_raw_mut_PtrOfOdometryFrame(), cache_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4138,8 +5288,9 @@ pub trait FastICPOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_camera_matrix(&self) -> Result<core::Mat> {
+		crate::core::validity::check("FastICPOdometry", self.as_raw_FastICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_FastICPOdometry_getCameraMatrix_const(self.as_raw_FastICPOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_FastICPOdometry_getCameraMatrix_const(self.as_raw_FastICPOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -4148,8 +5299,9 @@ pub trait FastICPOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_max_dist_diff(&self) -> Result<f64> {
+		crate::core::validity::check("FastICPOdometry", self.as_raw_FastICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_FastICPOdometry_getMaxDistDiff_const(self.as_raw_FastICPOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_FastICPOdometry_getMaxDistDiff_const(self.as_raw_FastICPOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4157,8 +5309,9 @@ pub trait FastICPOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_angle_threshold(&self) -> Result<f32> {
+		crate::core::validity::check("FastICPOdometry", self.as_raw_FastICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_FastICPOdometry_getAngleThreshold_const(self.as_raw_FastICPOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_FastICPOdometry_getAngleThreshold_const(self.as_raw_FastICPOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4166,8 +5319,9 @@ pub trait FastICPOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_sigma_depth(&self) -> Result<f32> {
+		crate::core::validity::check("FastICPOdometry", self.as_raw_FastICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_FastICPOdometry_getSigmaDepth_const(self.as_raw_FastICPOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_FastICPOdometry_getSigmaDepth_const(self.as_raw_FastICPOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4175,8 +5329,9 @@ pub trait FastICPOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_sigma_spatial(&self) -> Result<f32> {
+		crate::core::validity::check("FastICPOdometry", self.as_raw_FastICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_FastICPOdometry_getSigmaSpatial_const(self.as_raw_FastICPOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_FastICPOdometry_getSigmaSpatial_const(self.as_raw_FastICPOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4184,8 +5339,9 @@ pub trait FastICPOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_kernel_size(&self) -> Result<i32> {
+		crate::core::validity::check("FastICPOdometry", self.as_raw_FastICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_FastICPOdometry_getKernelSize_const(self.as_raw_FastICPOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_FastICPOdometry_getKernelSize_const(self.as_raw_FastICPOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4193,8 +5349,9 @@ pub trait FastICPOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_iteration_counts(&self) -> Result<core::Mat> {
+		crate::core::validity::check("FastICPOdometry", self.as_raw_FastICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_FastICPOdometry_getIterationCounts_const(self.as_raw_FastICPOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_FastICPOdometry_getIterationCounts_const(self.as_raw_FastICPOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -4203,8 +5360,9 @@ pub trait FastICPOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_transform_type(&self) -> Result<i32> {
+		crate::core::validity::check("FastICPOdometry", self.as_raw_FastICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_FastICPOdometry_getTransformType_const(self.as_raw_FastICPOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_FastICPOdometry_getTransformType_const(self.as_raw_FastICPOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4217,8 +5375,9 @@ pub trait FastICPOdometryTrait: crate::rgbd::FastICPOdometryTraitConst + crate::
 
 	#[inline]
 	fn set_camera_matrix(&mut self, val: &core::Mat) -> Result<()> {
+		crate::core::validity::check("FastICPOdometry", self.as_raw_mut_FastICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_FastICPOdometry_setCameraMatrix_const_MatR(self.as_raw_mut_FastICPOdometry(), val.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_FastICPOdometry_setCameraMatrix_const_MatR(self.as_raw_mut_FastICPOdometry(), val.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4226,8 +5385,9 @@ pub trait FastICPOdometryTrait: crate::rgbd::FastICPOdometryTraitConst + crate::
 	
 	#[inline]
 	fn set_max_dist_diff(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("FastICPOdometry", self.as_raw_mut_FastICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_FastICPOdometry_setMaxDistDiff_float(self.as_raw_mut_FastICPOdometry(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_FastICPOdometry_setMaxDistDiff_float(self.as_raw_mut_FastICPOdometry(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4235,8 +5395,9 @@ pub trait FastICPOdometryTrait: crate::rgbd::FastICPOdometryTraitConst + crate::
 	
 	#[inline]
 	fn set_angle_threshold(&mut self, f: f32) -> Result<()> {
+		crate::core::validity::check("FastICPOdometry", self.as_raw_mut_FastICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_FastICPOdometry_setAngleThreshold_float(self.as_raw_mut_FastICPOdometry(), f, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_FastICPOdometry_setAngleThreshold_float(self.as_raw_mut_FastICPOdometry(), f, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4244,8 +5405,9 @@ pub trait FastICPOdometryTrait: crate::rgbd::FastICPOdometryTraitConst + crate::
 	
 	#[inline]
 	fn set_sigma_depth(&mut self, f: f32) -> Result<()> {
+		crate::core::validity::check("FastICPOdometry", self.as_raw_mut_FastICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_FastICPOdometry_setSigmaDepth_float(self.as_raw_mut_FastICPOdometry(), f, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_FastICPOdometry_setSigmaDepth_float(self.as_raw_mut_FastICPOdometry(), f, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4253,8 +5415,9 @@ pub trait FastICPOdometryTrait: crate::rgbd::FastICPOdometryTraitConst + crate::
 	
 	#[inline]
 	fn set_sigma_spatial(&mut self, f: f32) -> Result<()> {
+		crate::core::validity::check("FastICPOdometry", self.as_raw_mut_FastICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_FastICPOdometry_setSigmaSpatial_float(self.as_raw_mut_FastICPOdometry(), f, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_FastICPOdometry_setSigmaSpatial_float(self.as_raw_mut_FastICPOdometry(), f, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4262,8 +5425,9 @@ pub trait FastICPOdometryTrait: crate::rgbd::FastICPOdometryTraitConst + crate::
 	
 	#[inline]
 	fn set_kernel_size(&mut self, f: i32) -> Result<()> {
+		crate::core::validity::check("FastICPOdometry", self.as_raw_mut_FastICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_FastICPOdometry_setKernelSize_int(self.as_raw_mut_FastICPOdometry(), f, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_FastICPOdometry_setKernelSize_int(self.as_raw_mut_FastICPOdometry(), f, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4271,8 +5435,9 @@ pub trait FastICPOdometryTrait: crate::rgbd::FastICPOdometryTraitConst + crate::
 	
 	#[inline]
 	fn set_iteration_counts(&mut self, val: &core::Mat) -> Result<()> {
+		crate::core::validity::check("FastICPOdometry", self.as_raw_mut_FastICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_FastICPOdometry_setIterationCounts_const_MatR(self.as_raw_mut_FastICPOdometry(), val.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_FastICPOdometry_setIterationCounts_const_MatR(self.as_raw_mut_FastICPOdometry(), val.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4280,8 +5445,9 @@ pub trait FastICPOdometryTrait: crate::rgbd::FastICPOdometryTraitConst + crate::
 	
 	#[inline]
 	fn set_transform_type(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("FastICPOdometry", self.as_raw_mut_FastICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_FastICPOdometry_setTransformType_int(self.as_raw_mut_FastICPOdometry(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_FastICPOdometry_setTransformType_int(self.as_raw_mut_FastICPOdometry(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4308,7 +5474,10 @@ opencv_type_boxed! { FastICPOdometry }
 impl Drop for FastICPOdometry {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FastICPOdometry_delete(instance: *mut c_void); }
-		unsafe { cv_FastICPOdometry_delete(self.as_raw_mut_FastICPOdometry()) };
+		let ptr = self.as_raw_mut_FastICPOdometry();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_FastICPOdometry_delete(ptr) };
 	}
 }
 
@@ -4342,7 +5511,7 @@ impl FastICPOdometry {
 	#[inline]
 	pub fn default() -> Result<crate::rgbd::FastICPOdometry> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_FastICPOdometry_FastICPOdometry(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_FastICPOdometry_FastICPOdometry(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::FastICPOdometry::opencv_from_extern(ret) };
@@ -4371,7 +5540,7 @@ impl FastICPOdometry {
 	#[inline]
 	pub fn new(camera_matrix: &core::Mat, max_dist_diff: f32, angle_threshold: f32, sigma_depth: f32, sigma_spatial: f32, kernel_size: i32, iter_counts: &core::Vector<i32>) -> Result<crate::rgbd::FastICPOdometry> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_FastICPOdometry_FastICPOdometry_const_MatR_float_float_float_float_int_const_vector_int_R(camera_matrix.as_raw_Mat(), max_dist_diff, angle_threshold, sigma_depth, sigma_spatial, kernel_size, iter_counts.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_FastICPOdometry_FastICPOdometry_const_MatR_float_float_float_float_int_const_vector_int_R(camera_matrix.as_raw_Mat(), max_dist_diff, angle_threshold, sigma_depth, sigma_spatial, kernel_size, iter_counts.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::FastICPOdometry::opencv_from_extern(ret) };
@@ -4388,7 +5557,7 @@ impl FastICPOdometry {
 	#[inline]
 	pub fn create(camera_matrix: &core::Mat, max_dist_diff: f32, angle_threshold: f32, sigma_depth: f32, sigma_spatial: f32, kernel_size: i32, iter_counts: &core::Vector<i32>) -> Result<core::Ptr<crate::rgbd::FastICPOdometry>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_FastICPOdometry_create_const_MatR_float_float_float_float_int_const_vector_int_R(camera_matrix.as_raw_Mat(), max_dist_diff, angle_threshold, sigma_depth, sigma_spatial, kernel_size, iter_counts.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_FastICPOdometry_create_const_MatR_float_float_float_float_int_const_vector_int_R(camera_matrix.as_raw_Mat(), max_dist_diff, angle_threshold, sigma_depth, sigma_spatial, kernel_size, iter_counts.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::rgbd::FastICPOdometry>::opencv_from_extern(ret) };
@@ -4397,6 +5566,100 @@ impl FastICPOdometry {
 	
 }
 
+/// Builder for `FastICPOdometry::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FastICPOdometry_NewBuilder<'a> {
+	camera_matrix: &'a core::Mat,
+	max_dist_diff: f32,
+	angle_threshold: f32,
+	sigma_depth: f32,
+	sigma_spatial: f32,
+	kernel_size: i32,
+	iter_counts: &'a core::Vector<i32>,
+}
+
+impl<'a> FastICPOdometry_NewBuilder<'a> {
+	#[inline]
+	pub fn new(camera_matrix: &'a core::Mat, max_dist_diff: f32, angle_threshold: f32, iter_counts: &'a core::Vector<i32>) -> Self {
+		Self { camera_matrix, max_dist_diff, angle_threshold, sigma_depth: 0.04, sigma_spatial: 4.5, kernel_size: 7, iter_counts }
+	}
+	
+	/// C++ default: `0.04f`
+	#[inline]
+	pub fn with_sigma_depth(mut self, sigma_depth: f32) -> Self {
+		self.sigma_depth = sigma_depth;
+		self
+	}
+	
+	/// C++ default: `4.5f`
+	#[inline]
+	pub fn with_sigma_spatial(mut self, sigma_spatial: f32) -> Self {
+		self.sigma_spatial = sigma_spatial;
+		self
+	}
+	
+	/// C++ default: `7`
+	#[inline]
+	pub fn with_kernel_size(mut self, kernel_size: i32) -> Self {
+		self.kernel_size = kernel_size;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::rgbd::FastICPOdometry> {
+		FastICPOdometry::new(self.camera_matrix, self.max_dist_diff, self.angle_threshold, self.sigma_depth, self.sigma_spatial, self.kernel_size, self.iter_counts)
+	}
+}
+
+/// Builder for `FastICPOdometry::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FastICPOdometry_CreateBuilder<'a> {
+	camera_matrix: &'a core::Mat,
+	max_dist_diff: f32,
+	angle_threshold: f32,
+	sigma_depth: f32,
+	sigma_spatial: f32,
+	kernel_size: i32,
+	iter_counts: &'a core::Vector<i32>,
+}
+
+impl<'a> FastICPOdometry_CreateBuilder<'a> {
+	#[inline]
+	pub fn new(camera_matrix: &'a core::Mat, max_dist_diff: f32, angle_threshold: f32, iter_counts: &'a core::Vector<i32>) -> Self {
+		Self { camera_matrix, max_dist_diff, angle_threshold, sigma_depth: 0.04, sigma_spatial: 4.5, kernel_size: 7, iter_counts }
+	}
+	
+	/// C++ default: `0.04f`
+	#[inline]
+	pub fn with_sigma_depth(mut self, sigma_depth: f32) -> Self {
+		self.sigma_depth = sigma_depth;
+		self
+	}
+	
+	/// C++ default: `4.5f`
+	#[inline]
+	pub fn with_sigma_spatial(mut self, sigma_spatial: f32) -> Self {
+		self.sigma_spatial = sigma_spatial;
+		self
+	}
+	
+	/// C++ default: `7`
+	#[inline]
+	pub fn with_kernel_size(mut self, kernel_size: i32) -> Self {
+		self.kernel_size = kernel_size;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<crate::rgbd::FastICPOdometry>> {
+		FastICPOdometry::create(self.camera_matrix, self.max_dist_diff, self.angle_threshold, self.sigma_depth, self.sigma_spatial, self.kernel_size, self.iter_counts)
+	}
+}
+
 boxed_cast_base! { FastICPOdometry, core::Algorithm, cv_FastICPOdometry_to_Algorithm }
 
 /// Odometry based on the paper "KinectFusion: Real-Time Dense Surface Mapping and Tracking",
@@ -4406,8 +5669,9 @@ pub trait ICPOdometryTraitConst: crate::rgbd::OdometryConst {
 
 	#[inline]
 	fn prepare_frame_cache(&self, frame: &mut core::Ptr<crate::rgbd::OdometryFrame>, cache_type: i32) -> Result<core::Size> {
+		crate::core::validity::check("ICPOdometry", self.as_raw_ICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_ICPOdometry_prepareFrameCache_const_Ptr_OdometryFrame_R_int(self.as_raw_ICPOdometry(), frame.as_raw_mut_PtrOfOdometryFrame(), cache_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_ICPOdometry_prepareFrameCache_const_Ptr_OdometryFrame_R_int(self.as_raw_ICPOdometry(), frame.as_raw_mut_PtrOfOdometryFrame(), cache_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4415,8 +5679,9 @@ pub trait ICPOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_camera_matrix(&self) -> Result<core::Mat> {
+		crate::core::validity::check("ICPOdometry", self.as_raw_ICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_ICPOdometry_getCameraMatrix_const(self.as_raw_ICPOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_ICPOdometry_getCameraMatrix_const(self.as_raw_ICPOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -4425,8 +5690,9 @@ pub trait ICPOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_min_depth(&self) -> Result<f64> {
+		crate::core::validity::check("ICPOdometry", self.as_raw_ICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_ICPOdometry_getMinDepth_const(self.as_raw_ICPOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_ICPOdometry_getMinDepth_const(self.as_raw_ICPOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4434,8 +5700,9 @@ pub trait ICPOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_max_depth(&self) -> Result<f64> {
+		crate::core::validity::check("ICPOdometry", self.as_raw_ICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_ICPOdometry_getMaxDepth_const(self.as_raw_ICPOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_ICPOdometry_getMaxDepth_const(self.as_raw_ICPOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4443,8 +5710,9 @@ pub trait ICPOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_max_depth_diff(&self) -> Result<f64> {
+		crate::core::validity::check("ICPOdometry", self.as_raw_ICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_ICPOdometry_getMaxDepthDiff_const(self.as_raw_ICPOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_ICPOdometry_getMaxDepthDiff_const(self.as_raw_ICPOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4452,8 +5720,9 @@ pub trait ICPOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_iteration_counts(&self) -> Result<core::Mat> {
+		crate::core::validity::check("ICPOdometry", self.as_raw_ICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_ICPOdometry_getIterationCounts_const(self.as_raw_ICPOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_ICPOdometry_getIterationCounts_const(self.as_raw_ICPOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -4462,8 +5731,9 @@ pub trait ICPOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_max_points_part(&self) -> Result<f64> {
+		crate::core::validity::check("ICPOdometry", self.as_raw_ICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_ICPOdometry_getMaxPointsPart_const(self.as_raw_ICPOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_ICPOdometry_getMaxPointsPart_const(self.as_raw_ICPOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4471,8 +5741,9 @@ pub trait ICPOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_transform_type(&self) -> Result<i32> {
+		crate::core::validity::check("ICPOdometry", self.as_raw_ICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_ICPOdometry_getTransformType_const(self.as_raw_ICPOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_ICPOdometry_getTransformType_const(self.as_raw_ICPOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4480,8 +5751,9 @@ pub trait ICPOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_max_translation(&self) -> Result<f64> {
+		crate::core::validity::check("ICPOdometry", self.as_raw_ICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_ICPOdometry_getMaxTranslation_const(self.as_raw_ICPOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_ICPOdometry_getMaxTranslation_const(self.as_raw_ICPOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4489,8 +5761,9 @@ pub trait ICPOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_max_rotation(&self) -> Result<f64> {
+		crate::core::validity::check("ICPOdometry", self.as_raw_ICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_ICPOdometry_getMaxRotation_const(self.as_raw_ICPOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_ICPOdometry_getMaxRotation_const(self.as_raw_ICPOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4498,8 +5771,9 @@ pub trait ICPOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_normals_computer(&self) -> Result<core::Ptr<crate::rgbd::RgbdNormals>> {
+		crate::core::validity::check("ICPOdometry", self.as_raw_ICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_ICPOdometry_getNormalsComputer_const(self.as_raw_ICPOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_ICPOdometry_getNormalsComputer_const(self.as_raw_ICPOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::rgbd::RgbdNormals>::opencv_from_extern(ret) };
@@ -4513,8 +5787,9 @@ pub trait ICPOdometryTrait: crate::rgbd::ICPOdometryTraitConst + crate::rgbd::Od
 
 	#[inline]
 	fn set_camera_matrix(&mut self, val: &core::Mat) -> Result<()> {
+		crate::core::validity::check("ICPOdometry", self.as_raw_mut_ICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_ICPOdometry_setCameraMatrix_const_MatR(self.as_raw_mut_ICPOdometry(), val.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_ICPOdometry_setCameraMatrix_const_MatR(self.as_raw_mut_ICPOdometry(), val.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4522,8 +5797,9 @@ pub trait ICPOdometryTrait: crate::rgbd::ICPOdometryTraitConst + crate::rgbd::Od
 	
 	#[inline]
 	fn set_min_depth(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("ICPOdometry", self.as_raw_mut_ICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_ICPOdometry_setMinDepth_double(self.as_raw_mut_ICPOdometry(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_ICPOdometry_setMinDepth_double(self.as_raw_mut_ICPOdometry(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4531,8 +5807,9 @@ pub trait ICPOdometryTrait: crate::rgbd::ICPOdometryTraitConst + crate::rgbd::Od
 	
 	#[inline]
 	fn set_max_depth(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("ICPOdometry", self.as_raw_mut_ICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_ICPOdometry_setMaxDepth_double(self.as_raw_mut_ICPOdometry(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_ICPOdometry_setMaxDepth_double(self.as_raw_mut_ICPOdometry(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4540,8 +5817,9 @@ pub trait ICPOdometryTrait: crate::rgbd::ICPOdometryTraitConst + crate::rgbd::Od
 	
 	#[inline]
 	fn set_max_depth_diff(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("ICPOdometry", self.as_raw_mut_ICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_ICPOdometry_setMaxDepthDiff_double(self.as_raw_mut_ICPOdometry(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_ICPOdometry_setMaxDepthDiff_double(self.as_raw_mut_ICPOdometry(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4549,8 +5827,9 @@ pub trait ICPOdometryTrait: crate::rgbd::ICPOdometryTraitConst + crate::rgbd::Od
 	
 	#[inline]
 	fn set_iteration_counts(&mut self, val: &core::Mat) -> Result<()> {
+		crate::core::validity::check("ICPOdometry", self.as_raw_mut_ICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_ICPOdometry_setIterationCounts_const_MatR(self.as_raw_mut_ICPOdometry(), val.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_ICPOdometry_setIterationCounts_const_MatR(self.as_raw_mut_ICPOdometry(), val.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4558,8 +5837,9 @@ pub trait ICPOdometryTrait: crate::rgbd::ICPOdometryTraitConst + crate::rgbd::Od
 	
 	#[inline]
 	fn set_max_points_part(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("ICPOdometry", self.as_raw_mut_ICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_ICPOdometry_setMaxPointsPart_double(self.as_raw_mut_ICPOdometry(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_ICPOdometry_setMaxPointsPart_double(self.as_raw_mut_ICPOdometry(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4567,8 +5847,9 @@ pub trait ICPOdometryTrait: crate::rgbd::ICPOdometryTraitConst + crate::rgbd::Od
 	
 	#[inline]
 	fn set_transform_type(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("ICPOdometry", self.as_raw_mut_ICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_ICPOdometry_setTransformType_int(self.as_raw_mut_ICPOdometry(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_ICPOdometry_setTransformType_int(self.as_raw_mut_ICPOdometry(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4576,8 +5857,9 @@ pub trait ICPOdometryTrait: crate::rgbd::ICPOdometryTraitConst + crate::rgbd::Od
 	
 	#[inline]
 	fn set_max_translation(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("ICPOdometry", self.as_raw_mut_ICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_ICPOdometry_setMaxTranslation_double(self.as_raw_mut_ICPOdometry(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_ICPOdometry_setMaxTranslation_double(self.as_raw_mut_ICPOdometry(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4585,8 +5867,9 @@ pub trait ICPOdometryTrait: crate::rgbd::ICPOdometryTraitConst + crate::rgbd::Od
 	
 	#[inline]
 	fn set_max_rotation(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("ICPOdometry", self.as_raw_mut_ICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_ICPOdometry_setMaxRotation_double(self.as_raw_mut_ICPOdometry(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_ICPOdometry_setMaxRotation_double(self.as_raw_mut_ICPOdometry(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4605,7 +5888,10 @@ opencv_type_boxed! { ICPOdometry }
 impl Drop for ICPOdometry {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ICPOdometry_delete(instance: *mut c_void); }
-		unsafe { cv_ICPOdometry_delete(self.as_raw_mut_ICPOdometry()) };
+		let ptr = self.as_raw_mut_ICPOdometry();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ICPOdometry_delete(ptr) };
 	}
 }
 
@@ -4639,7 +5925,7 @@ impl ICPOdometry {
 	#[inline]
 	pub fn default() -> Result<crate::rgbd::ICPOdometry> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_ICPOdometry_ICPOdometry(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_ICPOdometry_ICPOdometry(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::ICPOdometry::opencv_from_extern(ret) };
@@ -4667,7 +5953,7 @@ impl ICPOdometry {
 	#[inline]
 	pub fn new(camera_matrix: &core::Mat, min_depth: f32, max_depth: f32, max_depth_diff: f32, max_points_part: f32, iter_counts: &core::Vector<i32>, transform_type: i32) -> Result<crate::rgbd::ICPOdometry> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_ICPOdometry_ICPOdometry_const_MatR_float_float_float_float_const_vector_int_R_int(camera_matrix.as_raw_Mat(), min_depth, max_depth, max_depth_diff, max_points_part, iter_counts.as_raw_VectorOfi32(), transform_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_ICPOdometry_ICPOdometry_const_MatR_float_float_float_float_const_vector_int_R_int(camera_matrix.as_raw_Mat(), min_depth, max_depth, max_depth_diff, max_points_part, iter_counts.as_raw_VectorOfi32(), transform_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::ICPOdometry::opencv_from_extern(ret) };
@@ -4685,7 +5971,7 @@ impl ICPOdometry {
 	#[inline]
 	pub fn create(camera_matrix: &core::Mat, min_depth: f32, max_depth: f32, max_depth_diff: f32, max_points_part: f32, iter_counts: &core::Vector<i32>, transform_type: i32) -> Result<core::Ptr<crate::rgbd::ICPOdometry>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_ICPOdometry_create_const_MatR_float_float_float_float_const_vector_int_R_int(camera_matrix.as_raw_Mat(), min_depth, max_depth, max_depth_diff, max_points_part, iter_counts.as_raw_VectorOfi32(), transform_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_ICPOdometry_create_const_MatR_float_float_float_float_const_vector_int_R_int(camera_matrix.as_raw_Mat(), min_depth, max_depth, max_depth_diff, max_points_part, iter_counts.as_raw_VectorOfi32(), transform_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::rgbd::ICPOdometry>::opencv_from_extern(ret) };
@@ -4723,9 +6009,10 @@ pub trait OdometryConst: core::AlgorithmTraitConst {
 	/// * init_rt: Mat()
 	#[inline]
 	fn compute(&self, src_image: &core::Mat, src_depth: &core::Mat, src_mask: &core::Mat, dst_image: &core::Mat, dst_depth: &core::Mat, dst_mask: &core::Mat, rt: &mut dyn core::ToOutputArray, init_rt: &core::Mat) -> Result<bool> {
+		crate::core::validity::check("Odometry", self.as_raw_Odometry())?;
 		output_array_arg!(rt);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_Odometry_compute_const_const_MatR_const_MatR_const_MatR_const_MatR_const_MatR_const_MatR_const__OutputArrayR_const_MatR(self.as_raw_Odometry(), src_image.as_raw_Mat(), src_depth.as_raw_Mat(), src_mask.as_raw_Mat(), dst_image.as_raw_Mat(), dst_depth.as_raw_Mat(), dst_mask.as_raw_Mat(), rt.as_raw__OutputArray(), init_rt.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_Odometry_compute_const_const_MatR_const_MatR_const_MatR_const_MatR_const_MatR_const_MatR_const__OutputArrayR_const_MatR(self.as_raw_Odometry(), src_image.as_raw_Mat(), src_depth.as_raw_Mat(), src_mask.as_raw_Mat(), dst_image.as_raw_Mat(), dst_depth.as_raw_Mat(), dst_mask.as_raw_Mat(), rt.as_raw__OutputArray(), init_rt.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4738,9 +6025,10 @@ pub trait OdometryConst: core::AlgorithmTraitConst {
 	/// * init_rt: Mat()
 	#[inline]
 	fn compute2(&self, src_frame: &mut core::Ptr<crate::rgbd::OdometryFrame>, dst_frame: &mut core::Ptr<crate::rgbd::OdometryFrame>, rt: &mut dyn core::ToOutputArray, init_rt: &core::Mat) -> Result<bool> {
+		crate::core::validity::check("Odometry", self.as_raw_Odometry())?;
 		output_array_arg!(rt);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_Odometry_compute_const_Ptr_OdometryFrame_R_Ptr_OdometryFrame_R_const__OutputArrayR_const_MatR(self.as_raw_Odometry(), src_frame.as_raw_mut_PtrOfOdometryFrame(), dst_frame.as_raw_mut_PtrOfOdometryFrame(), rt.as_raw__OutputArray(), init_rt.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_Odometry_compute_const_Ptr_OdometryFrame_R_Ptr_OdometryFrame_R_const__OutputArrayR_const_MatR(self.as_raw_Odometry(), src_frame.as_raw_mut_PtrOfOdometryFrame(), dst_frame.as_raw_mut_PtrOfOdometryFrame(), rt.as_raw__OutputArray(), init_rt.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4754,19 +6042,21 @@ pub trait OdometryConst: core::AlgorithmTraitConst {
 	/// * cacheType: The cache type: CACHE_SRC, CACHE_DST or CACHE_ALL.
 	#[inline]
 	fn prepare_frame_cache(&self, frame: &mut core::Ptr<crate::rgbd::OdometryFrame>, cache_type: i32) -> Result<core::Size> {
+		crate::core::validity::check("Odometry", self.as_raw_Odometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_Odometry_prepareFrameCache_const_Ptr_OdometryFrame_R_int(self.as_raw_Odometry(), frame.as_raw_mut_PtrOfOdometryFrame(), cache_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_Odometry_prepareFrameCache_const_Ptr_OdometryFrame_R_int(self.as_raw_Odometry(), frame.as_raw_mut_PtrOfOdometryFrame(), cache_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setCameraMatrix
+	/// `set_camera_matrix`
 	#[inline]
 	fn get_camera_matrix(&self) -> Result<core::Mat> {
+		crate::core::validity::check("Odometry", self.as_raw_Odometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_Odometry_getCameraMatrix_const(self.as_raw_Odometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_Odometry_getCameraMatrix_const(self.as_raw_Odometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -4774,11 +6064,12 @@ pub trait OdometryConst: core::AlgorithmTraitConst {
 	}
 	
 	/// ## See also
-	/// setTransformType
+	/// `set_transform_type`
 	#[inline]
 	fn get_transform_type(&self) -> Result<i32> {
+		crate::core::validity::check("Odometry", self.as_raw_Odometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_Odometry_getTransformType_const(self.as_raw_Odometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_Odometry_getTransformType_const(self.as_raw_Odometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4793,8 +6084,9 @@ pub trait Odometry: core::AlgorithmTrait + crate::rgbd::OdometryConst {
 	/// setCameraMatrix getCameraMatrix
 	#[inline]
 	fn set_camera_matrix(&mut self, val: &core::Mat) -> Result<()> {
+		crate::core::validity::check("Odometry", self.as_raw_mut_Odometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_Odometry_setCameraMatrix_const_MatR(self.as_raw_mut_Odometry(), val.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_Odometry_setCameraMatrix_const_MatR(self.as_raw_mut_Odometry(), val.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4804,8 +6096,9 @@ pub trait Odometry: core::AlgorithmTrait + crate::rgbd::OdometryConst {
 	/// setTransformType getTransformType
 	#[inline]
 	fn set_transform_type(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("Odometry", self.as_raw_mut_Odometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_Odometry_setTransformType_int(self.as_raw_mut_Odometry(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_Odometry_setTransformType_int(self.as_raw_mut_Odometry(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4817,7 +6110,7 @@ impl dyn Odometry + '_ {
 	#[inline]
 	pub fn default_min_depth() -> Result<f32> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_Odometry_DEFAULT_MIN_DEPTH(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_Odometry_DEFAULT_MIN_DEPTH(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4826,7 +6119,7 @@ impl dyn Odometry + '_ {
 	#[inline]
 	pub fn default_max_depth() -> Result<f32> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_Odometry_DEFAULT_MAX_DEPTH(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_Odometry_DEFAULT_MAX_DEPTH(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4835,7 +6128,7 @@ impl dyn Odometry + '_ {
 	#[inline]
 	pub fn default_max_depth_diff() -> Result<f32> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_Odometry_DEFAULT_MAX_DEPTH_DIFF(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_Odometry_DEFAULT_MAX_DEPTH_DIFF(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4844,7 +6137,7 @@ impl dyn Odometry + '_ {
 	#[inline]
 	pub fn default_max_points_part() -> Result<f32> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_Odometry_DEFAULT_MAX_POINTS_PART(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_Odometry_DEFAULT_MAX_POINTS_PART(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4853,7 +6146,7 @@ impl dyn Odometry + '_ {
 	#[inline]
 	pub fn default_max_translation() -> Result<f32> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_Odometry_DEFAULT_MAX_TRANSLATION(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_Odometry_DEFAULT_MAX_TRANSLATION(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4862,7 +6155,7 @@ impl dyn Odometry + '_ {
 	#[inline]
 	pub fn default_max_rotation() -> Result<f32> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_Odometry_DEFAULT_MAX_ROTATION(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_Odometry_DEFAULT_MAX_ROTATION(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4872,7 +6165,7 @@ impl dyn Odometry + '_ {
 	pub fn create(odometry_type: &str) -> Result<core::Ptr<dyn crate::rgbd::Odometry>> {
 		extern_container_arg!(odometry_type);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_Odometry_create_const_StringR(odometry_type.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_Odometry_create_const_StringR(odometry_type.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::rgbd::Odometry>::opencv_from_extern(ret) };
@@ -4888,67 +6181,148 @@ pub trait OdometryFrameTraitConst: crate::rgbd::RgbdFrameTraitConst {
 
 	#[inline]
 	fn pyramid_image(&self) -> core::Vector<core::Mat> {
-		let ret = unsafe { sys::cv_rgbd_OdometryFrame_getPropPyramidImage_const(self.as_raw_OdometryFrame()) };
+		crate::core::validity::debug_check("OdometryFrame", self.as_raw_OdometryFrame());
+		let ret = unsafe { ffi_call!(cv_rgbd_OdometryFrame_getPropPyramidImage_const(self.as_raw_OdometryFrame())) };
 		let ret = unsafe { core::Vector::<core::Mat>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [pyramid_image](OdometryFrameTraitConst::pyramid_image), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pyramid_image(&self) -> Result<core::Vector<core::Mat>> {
+		crate::core::validity::check("OdometryFrame", self.as_raw_OdometryFrame())?;
+		Ok(OdometryFrameTraitConst::pyramid_image(self))
+	}
+	
 	#[inline]
 	fn pyramid_depth(&self) -> core::Vector<core::Mat> {
-		let ret = unsafe { sys::cv_rgbd_OdometryFrame_getPropPyramidDepth_const(self.as_raw_OdometryFrame()) };
+		crate::core::validity::debug_check("OdometryFrame", self.as_raw_OdometryFrame());
+		let ret = unsafe { ffi_call!(cv_rgbd_OdometryFrame_getPropPyramidDepth_const(self.as_raw_OdometryFrame())) };
 		let ret = unsafe { core::Vector::<core::Mat>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [pyramid_depth](OdometryFrameTraitConst::pyramid_depth), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pyramid_depth(&self) -> Result<core::Vector<core::Mat>> {
+		crate::core::validity::check("OdometryFrame", self.as_raw_OdometryFrame())?;
+		Ok(OdometryFrameTraitConst::pyramid_depth(self))
+	}
+	
 	#[inline]
 	fn pyramid_mask(&self) -> core::Vector<core::Mat> {
-		let ret = unsafe { sys::cv_rgbd_OdometryFrame_getPropPyramidMask_const(self.as_raw_OdometryFrame()) };
+		crate::core::validity::debug_check("OdometryFrame", self.as_raw_OdometryFrame());
+		let ret = unsafe { ffi_call!(cv_rgbd_OdometryFrame_getPropPyramidMask_const(self.as_raw_OdometryFrame())) };
 		let ret = unsafe { core::Vector::<core::Mat>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [pyramid_mask](OdometryFrameTraitConst::pyramid_mask), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pyramid_mask(&self) -> Result<core::Vector<core::Mat>> {
+		crate::core::validity::check("OdometryFrame", self.as_raw_OdometryFrame())?;
+		Ok(OdometryFrameTraitConst::pyramid_mask(self))
+	}
+	
 	#[inline]
 	fn pyramid_cloud(&self) -> core::Vector<core::Mat> {
-		let ret = unsafe { sys::cv_rgbd_OdometryFrame_getPropPyramidCloud_const(self.as_raw_OdometryFrame()) };
+		crate::core::validity::debug_check("OdometryFrame", self.as_raw_OdometryFrame());
+		let ret = unsafe { ffi_call!(cv_rgbd_OdometryFrame_getPropPyramidCloud_const(self.as_raw_OdometryFrame())) };
 		let ret = unsafe { core::Vector::<core::Mat>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [pyramid_cloud](OdometryFrameTraitConst::pyramid_cloud), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pyramid_cloud(&self) -> Result<core::Vector<core::Mat>> {
+		crate::core::validity::check("OdometryFrame", self.as_raw_OdometryFrame())?;
+		Ok(OdometryFrameTraitConst::pyramid_cloud(self))
+	}
+	
 	#[inline]
 	fn pyramid_d_i_dx(&self) -> core::Vector<core::Mat> {
-		let ret = unsafe { sys::cv_rgbd_OdometryFrame_getPropPyramid_dI_dx_const(self.as_raw_OdometryFrame()) };
+		crate::core::validity::debug_check("OdometryFrame", self.as_raw_OdometryFrame());
+		let ret = unsafe { ffi_call!(cv_rgbd_OdometryFrame_getPropPyramid_dI_dx_const(self.as_raw_OdometryFrame())) };
 		let ret = unsafe { core::Vector::<core::Mat>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [pyramid_d_i_dx](OdometryFrameTraitConst::pyramid_d_i_dx), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pyramid_d_i_dx(&self) -> Result<core::Vector<core::Mat>> {
+		crate::core::validity::check("OdometryFrame", self.as_raw_OdometryFrame())?;
+		Ok(OdometryFrameTraitConst::pyramid_d_i_dx(self))
+	}
+	
 	#[inline]
 	fn pyramid_d_i_dy(&self) -> core::Vector<core::Mat> {
-		let ret = unsafe { sys::cv_rgbd_OdometryFrame_getPropPyramid_dI_dy_const(self.as_raw_OdometryFrame()) };
+		crate::core::validity::debug_check("OdometryFrame", self.as_raw_OdometryFrame());
+		let ret = unsafe { ffi_call!(cv_rgbd_OdometryFrame_getPropPyramid_dI_dy_const(self.as_raw_OdometryFrame())) };
 		let ret = unsafe { core::Vector::<core::Mat>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [pyramid_d_i_dy](OdometryFrameTraitConst::pyramid_d_i_dy), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pyramid_d_i_dy(&self) -> Result<core::Vector<core::Mat>> {
+		crate::core::validity::check("OdometryFrame", self.as_raw_OdometryFrame())?;
+		Ok(OdometryFrameTraitConst::pyramid_d_i_dy(self))
+	}
+	
 	#[inline]
 	fn pyramid_textured_mask(&self) -> core::Vector<core::Mat> {
-		let ret = unsafe { sys::cv_rgbd_OdometryFrame_getPropPyramidTexturedMask_const(self.as_raw_OdometryFrame()) };
+		crate::core::validity::debug_check("OdometryFrame", self.as_raw_OdometryFrame());
+		let ret = unsafe { ffi_call!(cv_rgbd_OdometryFrame_getPropPyramidTexturedMask_const(self.as_raw_OdometryFrame())) };
 		let ret = unsafe { core::Vector::<core::Mat>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [pyramid_textured_mask](OdometryFrameTraitConst::pyramid_textured_mask), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pyramid_textured_mask(&self) -> Result<core::Vector<core::Mat>> {
+		crate::core::validity::check("OdometryFrame", self.as_raw_OdometryFrame())?;
+		Ok(OdometryFrameTraitConst::pyramid_textured_mask(self))
+	}
+	
 	#[inline]
 	fn pyramid_normals(&self) -> core::Vector<core::Mat> {
-		let ret = unsafe { sys::cv_rgbd_OdometryFrame_getPropPyramidNormals_const(self.as_raw_OdometryFrame()) };
+		crate::core::validity::debug_check("OdometryFrame", self.as_raw_OdometryFrame());
+		let ret = unsafe { ffi_call!(cv_rgbd_OdometryFrame_getPropPyramidNormals_const(self.as_raw_OdometryFrame())) };
 		let ret = unsafe { core::Vector::<core::Mat>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [pyramid_normals](OdometryFrameTraitConst::pyramid_normals), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pyramid_normals(&self) -> Result<core::Vector<core::Mat>> {
+		crate::core::validity::check("OdometryFrame", self.as_raw_OdometryFrame())?;
+		Ok(OdometryFrameTraitConst::pyramid_normals(self))
+	}
+	
 	#[inline]
 	fn pyramid_normals_mask(&self) -> core::Vector<core::Mat> {
-		let ret = unsafe { sys::cv_rgbd_OdometryFrame_getPropPyramidNormalsMask_const(self.as_raw_OdometryFrame()) };
+		crate::core::validity::debug_check("OdometryFrame", self.as_raw_OdometryFrame());
+		let ret = unsafe { ffi_call!(cv_rgbd_OdometryFrame_getPropPyramidNormalsMask_const(self.as_raw_OdometryFrame())) };
 		let ret = unsafe { core::Vector::<core::Mat>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [pyramid_normals_mask](OdometryFrameTraitConst::pyramid_normals_mask), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pyramid_normals_mask(&self) -> Result<core::Vector<core::Mat>> {
+		crate::core::validity::check("OdometryFrame", self.as_raw_OdometryFrame())?;
+		Ok(OdometryFrameTraitConst::pyramid_normals_mask(self))
+	}
+	
 }
 
 pub trait OdometryFrameTrait: crate::rgbd::OdometryFrameTraitConst + crate::rgbd::RgbdFrameTrait {
@@ -4956,62 +6330,72 @@ pub trait OdometryFrameTrait: crate::rgbd::OdometryFrameTraitConst + crate::rgbd
 
 	#[inline]
 	fn set_pyramid_image(&mut self, mut val: core::Vector<core::Mat>) {
-		let ret = unsafe { sys::cv_rgbd_OdometryFrame_setPropPyramidImage_vector_Mat_(self.as_raw_mut_OdometryFrame(), val.as_raw_mut_VectorOfMat()) };
+		crate::core::validity::debug_check("OdometryFrame", self.as_raw_mut_OdometryFrame());
+		let ret = unsafe { ffi_call!(cv_rgbd_OdometryFrame_setPropPyramidImage_vector_Mat_(self.as_raw_mut_OdometryFrame(), val.as_raw_mut_VectorOfMat())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_pyramid_depth(&mut self, mut val: core::Vector<core::Mat>) {
-		let ret = unsafe { sys::cv_rgbd_OdometryFrame_setPropPyramidDepth_vector_Mat_(self.as_raw_mut_OdometryFrame(), val.as_raw_mut_VectorOfMat()) };
+		crate::core::validity::debug_check("OdometryFrame", self.as_raw_mut_OdometryFrame());
+		let ret = unsafe { ffi_call!(cv_rgbd_OdometryFrame_setPropPyramidDepth_vector_Mat_(self.as_raw_mut_OdometryFrame(), val.as_raw_mut_VectorOfMat())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_pyramid_mask(&mut self, mut val: core::Vector<core::Mat>) {
-		let ret = unsafe { sys::cv_rgbd_OdometryFrame_setPropPyramidMask_vector_Mat_(self.as_raw_mut_OdometryFrame(), val.as_raw_mut_VectorOfMat()) };
+		crate::core::validity::debug_check("OdometryFrame", self.as_raw_mut_OdometryFrame());
+		let ret = unsafe { ffi_call!(cv_rgbd_OdometryFrame_setPropPyramidMask_vector_Mat_(self.as_raw_mut_OdometryFrame(), val.as_raw_mut_VectorOfMat())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_pyramid_cloud(&mut self, mut val: core::Vector<core::Mat>) {
-		let ret = unsafe { sys::cv_rgbd_OdometryFrame_setPropPyramidCloud_vector_Mat_(self.as_raw_mut_OdometryFrame(), val.as_raw_mut_VectorOfMat()) };
+		crate::core::validity::debug_check("OdometryFrame", self.as_raw_mut_OdometryFrame());
+		let ret = unsafe { ffi_call!(cv_rgbd_OdometryFrame_setPropPyramidCloud_vector_Mat_(self.as_raw_mut_OdometryFrame(), val.as_raw_mut_VectorOfMat())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_pyramid_d_i_dx(&mut self, mut val: core::Vector<core::Mat>) {
-		let ret = unsafe { sys::cv_rgbd_OdometryFrame_setPropPyramid_dI_dx_vector_Mat_(self.as_raw_mut_OdometryFrame(), val.as_raw_mut_VectorOfMat()) };
+		crate::core::validity::debug_check("OdometryFrame", self.as_raw_mut_OdometryFrame());
+		let ret = unsafe { ffi_call!(cv_rgbd_OdometryFrame_setPropPyramid_dI_dx_vector_Mat_(self.as_raw_mut_OdometryFrame(), val.as_raw_mut_VectorOfMat())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_pyramid_d_i_dy(&mut self, mut val: core::Vector<core::Mat>) {
-		let ret = unsafe { sys::cv_rgbd_OdometryFrame_setPropPyramid_dI_dy_vector_Mat_(self.as_raw_mut_OdometryFrame(), val.as_raw_mut_VectorOfMat()) };
+		crate::core::validity::debug_check("OdometryFrame", self.as_raw_mut_OdometryFrame());
+		let ret = unsafe { ffi_call!(cv_rgbd_OdometryFrame_setPropPyramid_dI_dy_vector_Mat_(self.as_raw_mut_OdometryFrame(), val.as_raw_mut_VectorOfMat())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_pyramid_textured_mask(&mut self, mut val: core::Vector<core::Mat>) {
-		let ret = unsafe { sys::cv_rgbd_OdometryFrame_setPropPyramidTexturedMask_vector_Mat_(self.as_raw_mut_OdometryFrame(), val.as_raw_mut_VectorOfMat()) };
+		crate::core::validity::debug_check("OdometryFrame", self.as_raw_mut_OdometryFrame());
+		let ret = unsafe { ffi_call!(cv_rgbd_OdometryFrame_setPropPyramidTexturedMask_vector_Mat_(self.as_raw_mut_OdometryFrame(), val.as_raw_mut_VectorOfMat())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_pyramid_normals(&mut self, mut val: core::Vector<core::Mat>) {
-		let ret = unsafe { sys::cv_rgbd_OdometryFrame_setPropPyramidNormals_vector_Mat_(self.as_raw_mut_OdometryFrame(), val.as_raw_mut_VectorOfMat()) };
+		crate::core::validity::debug_check("OdometryFrame", self.as_raw_mut_OdometryFrame());
+		let ret = unsafe { ffi_call!(cv_rgbd_OdometryFrame_setPropPyramidNormals_vector_Mat_(self.as_raw_mut_OdometryFrame(), val.as_raw_mut_VectorOfMat())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_pyramid_normals_mask(&mut self, mut val: core::Vector<core::Mat>) {
-		let ret = unsafe { sys::cv_rgbd_OdometryFrame_setPropPyramidNormalsMask_vector_Mat_(self.as_raw_mut_OdometryFrame(), val.as_raw_mut_VectorOfMat()) };
+		crate::core::validity::debug_check("OdometryFrame", self.as_raw_mut_OdometryFrame());
+		let ret = unsafe { ffi_call!(cv_rgbd_OdometryFrame_setPropPyramidNormalsMask_vector_Mat_(self.as_raw_mut_OdometryFrame(), val.as_raw_mut_VectorOfMat())) };
 		ret
 	}
 	
 	#[inline]
 	fn release(&mut self) -> Result<()> {
+		crate::core::validity::check("OdometryFrame", self.as_raw_mut_OdometryFrame())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_OdometryFrame_release(self.as_raw_mut_OdometryFrame(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_OdometryFrame_release(self.as_raw_mut_OdometryFrame(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5019,8 +6403,9 @@ pub trait OdometryFrameTrait: crate::rgbd::OdometryFrameTraitConst + crate::rgbd
 	
 	#[inline]
 	fn release_pyramids(&mut self) -> Result<()> {
+		crate::core::validity::check("OdometryFrame", self.as_raw_mut_OdometryFrame())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_OdometryFrame_releasePyramids(self.as_raw_mut_OdometryFrame(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_OdometryFrame_releasePyramids(self.as_raw_mut_OdometryFrame(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5040,7 +6425,10 @@ opencv_type_boxed! { OdometryFrame }
 impl Drop for OdometryFrame {
 	fn drop(&mut self) {
 		extern "C" { fn cv_OdometryFrame_delete(instance: *mut c_void); }
-		unsafe { cv_OdometryFrame_delete(self.as_raw_mut_OdometryFrame()) };
+		let ptr = self.as_raw_mut_OdometryFrame();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_OdometryFrame_delete(ptr) };
 	}
 }
 
@@ -5066,7 +6454,7 @@ impl OdometryFrame {
 	#[inline]
 	pub fn default() -> Result<crate::rgbd::OdometryFrame> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_OdometryFrame_OdometryFrame(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_OdometryFrame_OdometryFrame(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::OdometryFrame::opencv_from_extern(ret) };
@@ -5080,7 +6468,7 @@ impl OdometryFrame {
 	#[inline]
 	pub fn new(image: &core::Mat, depth: &core::Mat, mask: &core::Mat, normals: &core::Mat, id: i32) -> Result<crate::rgbd::OdometryFrame> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_OdometryFrame_OdometryFrame_const_MatR_const_MatR_const_MatR_const_MatR_int(image.as_raw_Mat(), depth.as_raw_Mat(), mask.as_raw_Mat(), normals.as_raw_Mat(), id, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_OdometryFrame_OdometryFrame_const_MatR_const_MatR_const_MatR_const_MatR_int(image.as_raw_Mat(), depth.as_raw_Mat(), mask.as_raw_Mat(), normals.as_raw_Mat(), id, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::OdometryFrame::opencv_from_extern(ret) };
@@ -5096,7 +6484,7 @@ impl OdometryFrame {
 	#[inline]
 	pub fn create(image: &core::Mat, depth: &core::Mat, mask: &core::Mat, normals: &core::Mat, id: i32) -> Result<core::Ptr<crate::rgbd::OdometryFrame>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_OdometryFrame_create_const_MatR_const_MatR_const_MatR_const_MatR_int(image.as_raw_Mat(), depth.as_raw_Mat(), mask.as_raw_Mat(), normals.as_raw_Mat(), id, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_OdometryFrame_create_const_MatR_const_MatR_const_MatR_const_MatR_int(image.as_raw_Mat(), depth.as_raw_Mat(), mask.as_raw_Mat(), normals.as_raw_Mat(), id, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::rgbd::OdometryFrame>::opencv_from_extern(ret) };
@@ -5105,6 +6493,68 @@ impl OdometryFrame {
 	
 }
 
+/// Builder for `OdometryFrame::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct OdometryFrame_NewBuilder<'a> {
+	image: &'a core::Mat,
+	depth: &'a core::Mat,
+	mask: &'a core::Mat,
+	normals: &'a core::Mat,
+	id: i32,
+}
+
+impl<'a> OdometryFrame_NewBuilder<'a> {
+	#[inline]
+	pub fn new(image: &'a core::Mat, depth: &'a core::Mat, mask: &'a core::Mat, normals: &'a core::Mat) -> Self {
+		Self { image, depth, mask, normals, id: -1 }
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_id(mut self, id: i32) -> Self {
+		self.id = id;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::rgbd::OdometryFrame> {
+		OdometryFrame::new(self.image, self.depth, self.mask, self.normals, self.id)
+	}
+}
+
+/// Builder for `OdometryFrame::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct OdometryFrame_CreateBuilder<'a> {
+	image: &'a core::Mat,
+	depth: &'a core::Mat,
+	mask: &'a core::Mat,
+	normals: &'a core::Mat,
+	id: i32,
+}
+
+impl<'a> OdometryFrame_CreateBuilder<'a> {
+	#[inline]
+	pub fn new(image: &'a core::Mat, depth: &'a core::Mat, mask: &'a core::Mat, normals: &'a core::Mat) -> Self {
+		Self { image, depth, mask, normals, id: -1 }
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_id(mut self, id: i32) -> Self {
+		self.id = id;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<crate::rgbd::OdometryFrame>> {
+		OdometryFrame::create(self.image, self.depth, self.mask, self.normals, self.id)
+	}
+}
+
 boxed_cast_base! { OdometryFrame, crate::rgbd::RgbdFrame, cv_OdometryFrame_to_RgbdFrame }
 
 /// Object that contains a frame data.
@@ -5113,38 +6563,83 @@ pub trait RgbdFrameTraitConst {
 
 	#[inline]
 	fn id(&self) -> i32 {
-		let ret = unsafe { sys::cv_rgbd_RgbdFrame_getPropID_const(self.as_raw_RgbdFrame()) };
+		crate::core::validity::debug_check("RgbdFrame", self.as_raw_RgbdFrame());
+		let ret = unsafe { ffi_call!(cv_rgbd_RgbdFrame_getPropID_const(self.as_raw_RgbdFrame())) };
 		ret
 	}
 	
+	/// Non-panicking version of [id](RgbdFrameTraitConst::id), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_id(&self) -> Result<i32> {
+		crate::core::validity::check("RgbdFrame", self.as_raw_RgbdFrame())?;
+		Ok(RgbdFrameTraitConst::id(self))
+	}
+	
 	#[inline]
 	fn image(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_rgbd_RgbdFrame_getPropImage_const(self.as_raw_RgbdFrame()) };
+		crate::core::validity::debug_check("RgbdFrame", self.as_raw_RgbdFrame());
+		let ret = unsafe { ffi_call!(cv_rgbd_RgbdFrame_getPropImage_const(self.as_raw_RgbdFrame())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [image](RgbdFrameTraitConst::image), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_image(&self) -> Result<core::Mat> {
+		crate::core::validity::check("RgbdFrame", self.as_raw_RgbdFrame())?;
+		Ok(RgbdFrameTraitConst::image(self))
+	}
+	
 	#[inline]
 	fn depth(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_rgbd_RgbdFrame_getPropDepth_const(self.as_raw_RgbdFrame()) };
+		crate::core::validity::debug_check("RgbdFrame", self.as_raw_RgbdFrame());
+		let ret = unsafe { ffi_call!(cv_rgbd_RgbdFrame_getPropDepth_const(self.as_raw_RgbdFrame())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [depth](RgbdFrameTraitConst::depth), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_depth(&self) -> Result<core::Mat> {
+		crate::core::validity::check("RgbdFrame", self.as_raw_RgbdFrame())?;
+		Ok(RgbdFrameTraitConst::depth(self))
+	}
+	
 	#[inline]
 	fn mask(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_rgbd_RgbdFrame_getPropMask_const(self.as_raw_RgbdFrame()) };
+		crate::core::validity::debug_check("RgbdFrame", self.as_raw_RgbdFrame());
+		let ret = unsafe { ffi_call!(cv_rgbd_RgbdFrame_getPropMask_const(self.as_raw_RgbdFrame())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [mask](RgbdFrameTraitConst::mask), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_mask(&self) -> Result<core::Mat> {
+		crate::core::validity::check("RgbdFrame", self.as_raw_RgbdFrame())?;
+		Ok(RgbdFrameTraitConst::mask(self))
+	}
+	
 	#[inline]
 	fn normals(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_rgbd_RgbdFrame_getPropNormals_const(self.as_raw_RgbdFrame()) };
+		crate::core::validity::debug_check("RgbdFrame", self.as_raw_RgbdFrame());
+		let ret = unsafe { ffi_call!(cv_rgbd_RgbdFrame_getPropNormals_const(self.as_raw_RgbdFrame())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [normals](RgbdFrameTraitConst::normals), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_normals(&self) -> Result<core::Mat> {
+		crate::core::validity::check("RgbdFrame", self.as_raw_RgbdFrame())?;
+		Ok(RgbdFrameTraitConst::normals(self))
+	}
+	
 }
 
 pub trait RgbdFrameTrait: crate::rgbd::RgbdFrameTraitConst {
@@ -5152,38 +6647,44 @@ pub trait RgbdFrameTrait: crate::rgbd::RgbdFrameTraitConst {
 
 	#[inline]
 	fn set_id(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_rgbd_RgbdFrame_setPropID_int(self.as_raw_mut_RgbdFrame(), val) };
+		crate::core::validity::debug_check("RgbdFrame", self.as_raw_mut_RgbdFrame());
+		let ret = unsafe { ffi_call!(cv_rgbd_RgbdFrame_setPropID_int(self.as_raw_mut_RgbdFrame(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_image(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_rgbd_RgbdFrame_setPropImage_Mat(self.as_raw_mut_RgbdFrame(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("RgbdFrame", self.as_raw_mut_RgbdFrame());
+		let ret = unsafe { ffi_call!(cv_rgbd_RgbdFrame_setPropImage_Mat(self.as_raw_mut_RgbdFrame(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_depth(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_rgbd_RgbdFrame_setPropDepth_Mat(self.as_raw_mut_RgbdFrame(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("RgbdFrame", self.as_raw_mut_RgbdFrame());
+		let ret = unsafe { ffi_call!(cv_rgbd_RgbdFrame_setPropDepth_Mat(self.as_raw_mut_RgbdFrame(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_mask(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_rgbd_RgbdFrame_setPropMask_Mat(self.as_raw_mut_RgbdFrame(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("RgbdFrame", self.as_raw_mut_RgbdFrame());
+		let ret = unsafe { ffi_call!(cv_rgbd_RgbdFrame_setPropMask_Mat(self.as_raw_mut_RgbdFrame(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_normals(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_rgbd_RgbdFrame_setPropNormals_Mat(self.as_raw_mut_RgbdFrame(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("RgbdFrame", self.as_raw_mut_RgbdFrame());
+		let ret = unsafe { ffi_call!(cv_rgbd_RgbdFrame_setPropNormals_Mat(self.as_raw_mut_RgbdFrame(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	#[inline]
 	fn release(&mut self) -> Result<()> {
+		crate::core::validity::check("RgbdFrame", self.as_raw_mut_RgbdFrame())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdFrame_release(self.as_raw_mut_RgbdFrame(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdFrame_release(self.as_raw_mut_RgbdFrame(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5201,7 +6702,10 @@ opencv_type_boxed! { RgbdFrame }
 impl Drop for RgbdFrame {
 	fn drop(&mut self) {
 		extern "C" { fn cv_RgbdFrame_delete(instance: *mut c_void); }
-		unsafe { cv_RgbdFrame_delete(self.as_raw_mut_RgbdFrame()) };
+		let ptr = self.as_raw_mut_RgbdFrame();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_RgbdFrame_delete(ptr) };
 	}
 }
 
@@ -5219,7 +6723,7 @@ impl RgbdFrame {
 	#[inline]
 	pub fn default() -> Result<crate::rgbd::RgbdFrame> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdFrame_RgbdFrame(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdFrame_RgbdFrame(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::RgbdFrame::opencv_from_extern(ret) };
@@ -5233,7 +6737,7 @@ impl RgbdFrame {
 	#[inline]
 	pub fn new(image: &core::Mat, depth: &core::Mat, mask: &core::Mat, normals: &core::Mat, id: i32) -> Result<crate::rgbd::RgbdFrame> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdFrame_RgbdFrame_const_MatR_const_MatR_const_MatR_const_MatR_int(image.as_raw_Mat(), depth.as_raw_Mat(), mask.as_raw_Mat(), normals.as_raw_Mat(), id, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdFrame_RgbdFrame_const_MatR_const_MatR_const_MatR_const_MatR_int(image.as_raw_Mat(), depth.as_raw_Mat(), mask.as_raw_Mat(), normals.as_raw_Mat(), id, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::RgbdFrame::opencv_from_extern(ret) };
@@ -5249,7 +6753,7 @@ impl RgbdFrame {
 	#[inline]
 	pub fn create(image: &core::Mat, depth: &core::Mat, mask: &core::Mat, normals: &core::Mat, id: i32) -> Result<core::Ptr<crate::rgbd::RgbdFrame>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdFrame_create_const_MatR_const_MatR_const_MatR_const_MatR_int(image.as_raw_Mat(), depth.as_raw_Mat(), mask.as_raw_Mat(), normals.as_raw_Mat(), id, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdFrame_create_const_MatR_const_MatR_const_MatR_const_MatR_int(image.as_raw_Mat(), depth.as_raw_Mat(), mask.as_raw_Mat(), normals.as_raw_Mat(), id, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::rgbd::RgbdFrame>::opencv_from_extern(ret) };
@@ -5258,6 +6762,68 @@ impl RgbdFrame {
 	
 }
 
+/// Builder for `RgbdFrame::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct RgbdFrame_NewBuilder<'a> {
+	image: &'a core::Mat,
+	depth: &'a core::Mat,
+	mask: &'a core::Mat,
+	normals: &'a core::Mat,
+	id: i32,
+}
+
+impl<'a> RgbdFrame_NewBuilder<'a> {
+	#[inline]
+	pub fn new(image: &'a core::Mat, depth: &'a core::Mat, mask: &'a core::Mat, normals: &'a core::Mat) -> Self {
+		Self { image, depth, mask, normals, id: -1 }
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_id(mut self, id: i32) -> Self {
+		self.id = id;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::rgbd::RgbdFrame> {
+		RgbdFrame::new(self.image, self.depth, self.mask, self.normals, self.id)
+	}
+}
+
+/// Builder for `RgbdFrame::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct RgbdFrame_CreateBuilder<'a> {
+	image: &'a core::Mat,
+	depth: &'a core::Mat,
+	mask: &'a core::Mat,
+	normals: &'a core::Mat,
+	id: i32,
+}
+
+impl<'a> RgbdFrame_CreateBuilder<'a> {
+	#[inline]
+	pub fn new(image: &'a core::Mat, depth: &'a core::Mat, mask: &'a core::Mat, normals: &'a core::Mat) -> Self {
+		Self { image, depth, mask, normals, id: -1 }
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_id(mut self, id: i32) -> Self {
+		self.id = id;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<crate::rgbd::RgbdFrame>> {
+		RgbdFrame::create(self.image, self.depth, self.mask, self.normals, self.id)
+	}
+}
+
 boxed_cast_descendant! { RgbdFrame, crate::rgbd::OdometryFrame, cv_RgbdFrame_to_OdometryFrame }
 
 /// Odometry that merges RgbdOdometry and ICPOdometry by minimize sum of their energy functions.
@@ -5266,8 +6832,9 @@ pub trait RgbdICPOdometryTraitConst: crate::rgbd::OdometryConst {
 
 	#[inline]
 	fn prepare_frame_cache(&self, frame: &mut core::Ptr<crate::rgbd::OdometryFrame>, cache_type: i32) -> Result<core::Size> {
+		crate::core::validity::check("RgbdICPOdometry", self.as_raw_RgbdICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdICPOdometry_prepareFrameCache_const_Ptr_OdometryFrame_R_int(self.as_raw_RgbdICPOdometry(), frame.as_raw_mut_PtrOfOdometryFrame(), cache_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdICPOdometry_prepareFrameCache_const_Ptr_OdometryFrame_R_int(self.as_raw_RgbdICPOdometry(), frame.as_raw_mut_PtrOfOdometryFrame(), cache_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5275,8 +6842,9 @@ pub trait RgbdICPOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_camera_matrix(&self) -> Result<core::Mat> {
+		crate::core::validity::check("RgbdICPOdometry", self.as_raw_RgbdICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdICPOdometry_getCameraMatrix_const(self.as_raw_RgbdICPOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdICPOdometry_getCameraMatrix_const(self.as_raw_RgbdICPOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -5285,8 +6853,9 @@ pub trait RgbdICPOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_min_depth(&self) -> Result<f64> {
+		crate::core::validity::check("RgbdICPOdometry", self.as_raw_RgbdICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdICPOdometry_getMinDepth_const(self.as_raw_RgbdICPOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdICPOdometry_getMinDepth_const(self.as_raw_RgbdICPOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5294,8 +6863,9 @@ pub trait RgbdICPOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_max_depth(&self) -> Result<f64> {
+		crate::core::validity::check("RgbdICPOdometry", self.as_raw_RgbdICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdICPOdometry_getMaxDepth_const(self.as_raw_RgbdICPOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdICPOdometry_getMaxDepth_const(self.as_raw_RgbdICPOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5303,8 +6873,9 @@ pub trait RgbdICPOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_max_depth_diff(&self) -> Result<f64> {
+		crate::core::validity::check("RgbdICPOdometry", self.as_raw_RgbdICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdICPOdometry_getMaxDepthDiff_const(self.as_raw_RgbdICPOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdICPOdometry_getMaxDepthDiff_const(self.as_raw_RgbdICPOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5312,8 +6883,9 @@ pub trait RgbdICPOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_max_points_part(&self) -> Result<f64> {
+		crate::core::validity::check("RgbdICPOdometry", self.as_raw_RgbdICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdICPOdometry_getMaxPointsPart_const(self.as_raw_RgbdICPOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdICPOdometry_getMaxPointsPart_const(self.as_raw_RgbdICPOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5321,8 +6893,9 @@ pub trait RgbdICPOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_iteration_counts(&self) -> Result<core::Mat> {
+		crate::core::validity::check("RgbdICPOdometry", self.as_raw_RgbdICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdICPOdometry_getIterationCounts_const(self.as_raw_RgbdICPOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdICPOdometry_getIterationCounts_const(self.as_raw_RgbdICPOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -5331,8 +6904,9 @@ pub trait RgbdICPOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_min_gradient_magnitudes(&self) -> Result<core::Mat> {
+		crate::core::validity::check("RgbdICPOdometry", self.as_raw_RgbdICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdICPOdometry_getMinGradientMagnitudes_const(self.as_raw_RgbdICPOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdICPOdometry_getMinGradientMagnitudes_const(self.as_raw_RgbdICPOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -5341,8 +6915,9 @@ pub trait RgbdICPOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_transform_type(&self) -> Result<i32> {
+		crate::core::validity::check("RgbdICPOdometry", self.as_raw_RgbdICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdICPOdometry_getTransformType_const(self.as_raw_RgbdICPOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdICPOdometry_getTransformType_const(self.as_raw_RgbdICPOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5350,8 +6925,9 @@ pub trait RgbdICPOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_max_translation(&self) -> Result<f64> {
+		crate::core::validity::check("RgbdICPOdometry", self.as_raw_RgbdICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdICPOdometry_getMaxTranslation_const(self.as_raw_RgbdICPOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdICPOdometry_getMaxTranslation_const(self.as_raw_RgbdICPOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5359,8 +6935,9 @@ pub trait RgbdICPOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_max_rotation(&self) -> Result<f64> {
+		crate::core::validity::check("RgbdICPOdometry", self.as_raw_RgbdICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdICPOdometry_getMaxRotation_const(self.as_raw_RgbdICPOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdICPOdometry_getMaxRotation_const(self.as_raw_RgbdICPOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5368,8 +6945,9 @@ pub trait RgbdICPOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_normals_computer(&self) -> Result<core::Ptr<crate::rgbd::RgbdNormals>> {
+		crate::core::validity::check("RgbdICPOdometry", self.as_raw_RgbdICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdICPOdometry_getNormalsComputer_const(self.as_raw_RgbdICPOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdICPOdometry_getNormalsComputer_const(self.as_raw_RgbdICPOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::rgbd::RgbdNormals>::opencv_from_extern(ret) };
@@ -5383,8 +6961,9 @@ pub trait RgbdICPOdometryTrait: crate::rgbd::Odometry + crate::rgbd::RgbdICPOdom
 
 	#[inline]
 	fn set_camera_matrix(&mut self, val: &core::Mat) -> Result<()> {
+		crate::core::validity::check("RgbdICPOdometry", self.as_raw_mut_RgbdICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdICPOdometry_setCameraMatrix_const_MatR(self.as_raw_mut_RgbdICPOdometry(), val.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdICPOdometry_setCameraMatrix_const_MatR(self.as_raw_mut_RgbdICPOdometry(), val.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5392,8 +6971,9 @@ pub trait RgbdICPOdometryTrait: crate::rgbd::Odometry + crate::rgbd::RgbdICPOdom
 	
 	#[inline]
 	fn set_min_depth(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("RgbdICPOdometry", self.as_raw_mut_RgbdICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdICPOdometry_setMinDepth_double(self.as_raw_mut_RgbdICPOdometry(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdICPOdometry_setMinDepth_double(self.as_raw_mut_RgbdICPOdometry(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5401,8 +6981,9 @@ pub trait RgbdICPOdometryTrait: crate::rgbd::Odometry + crate::rgbd::RgbdICPOdom
 	
 	#[inline]
 	fn set_max_depth(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("RgbdICPOdometry", self.as_raw_mut_RgbdICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdICPOdometry_setMaxDepth_double(self.as_raw_mut_RgbdICPOdometry(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdICPOdometry_setMaxDepth_double(self.as_raw_mut_RgbdICPOdometry(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5410,8 +6991,9 @@ pub trait RgbdICPOdometryTrait: crate::rgbd::Odometry + crate::rgbd::RgbdICPOdom
 	
 	#[inline]
 	fn set_max_depth_diff(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("RgbdICPOdometry", self.as_raw_mut_RgbdICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdICPOdometry_setMaxDepthDiff_double(self.as_raw_mut_RgbdICPOdometry(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdICPOdometry_setMaxDepthDiff_double(self.as_raw_mut_RgbdICPOdometry(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5419,8 +7001,9 @@ pub trait RgbdICPOdometryTrait: crate::rgbd::Odometry + crate::rgbd::RgbdICPOdom
 	
 	#[inline]
 	fn set_max_points_part(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("RgbdICPOdometry", self.as_raw_mut_RgbdICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdICPOdometry_setMaxPointsPart_double(self.as_raw_mut_RgbdICPOdometry(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdICPOdometry_setMaxPointsPart_double(self.as_raw_mut_RgbdICPOdometry(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5428,8 +7011,9 @@ pub trait RgbdICPOdometryTrait: crate::rgbd::Odometry + crate::rgbd::RgbdICPOdom
 	
 	#[inline]
 	fn set_iteration_counts(&mut self, val: &core::Mat) -> Result<()> {
+		crate::core::validity::check("RgbdICPOdometry", self.as_raw_mut_RgbdICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdICPOdometry_setIterationCounts_const_MatR(self.as_raw_mut_RgbdICPOdometry(), val.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdICPOdometry_setIterationCounts_const_MatR(self.as_raw_mut_RgbdICPOdometry(), val.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5437,8 +7021,9 @@ pub trait RgbdICPOdometryTrait: crate::rgbd::Odometry + crate::rgbd::RgbdICPOdom
 	
 	#[inline]
 	fn set_min_gradient_magnitudes(&mut self, val: &core::Mat) -> Result<()> {
+		crate::core::validity::check("RgbdICPOdometry", self.as_raw_mut_RgbdICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdICPOdometry_setMinGradientMagnitudes_const_MatR(self.as_raw_mut_RgbdICPOdometry(), val.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdICPOdometry_setMinGradientMagnitudes_const_MatR(self.as_raw_mut_RgbdICPOdometry(), val.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5446,8 +7031,9 @@ pub trait RgbdICPOdometryTrait: crate::rgbd::Odometry + crate::rgbd::RgbdICPOdom
 	
 	#[inline]
 	fn set_transform_type(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("RgbdICPOdometry", self.as_raw_mut_RgbdICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdICPOdometry_setTransformType_int(self.as_raw_mut_RgbdICPOdometry(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdICPOdometry_setTransformType_int(self.as_raw_mut_RgbdICPOdometry(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5455,8 +7041,9 @@ pub trait RgbdICPOdometryTrait: crate::rgbd::Odometry + crate::rgbd::RgbdICPOdom
 	
 	#[inline]
 	fn set_max_translation(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("RgbdICPOdometry", self.as_raw_mut_RgbdICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdICPOdometry_setMaxTranslation_double(self.as_raw_mut_RgbdICPOdometry(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdICPOdometry_setMaxTranslation_double(self.as_raw_mut_RgbdICPOdometry(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5464,8 +7051,9 @@ pub trait RgbdICPOdometryTrait: crate::rgbd::Odometry + crate::rgbd::RgbdICPOdom
 	
 	#[inline]
 	fn set_max_rotation(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("RgbdICPOdometry", self.as_raw_mut_RgbdICPOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdICPOdometry_setMaxRotation_double(self.as_raw_mut_RgbdICPOdometry(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdICPOdometry_setMaxRotation_double(self.as_raw_mut_RgbdICPOdometry(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5483,7 +7071,10 @@ opencv_type_boxed! { RgbdICPOdometry }
 impl Drop for RgbdICPOdometry {
 	fn drop(&mut self) {
 		extern "C" { fn cv_RgbdICPOdometry_delete(instance: *mut c_void); }
-		unsafe { cv_RgbdICPOdometry_delete(self.as_raw_mut_RgbdICPOdometry()) };
+		let ptr = self.as_raw_mut_RgbdICPOdometry();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_RgbdICPOdometry_delete(ptr) };
 	}
 }
 
@@ -5517,7 +7108,7 @@ impl RgbdICPOdometry {
 	#[inline]
 	pub fn default() -> Result<crate::rgbd::RgbdICPOdometry> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdICPOdometry_RgbdICPOdometry(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdICPOdometry_RgbdICPOdometry(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::RgbdICPOdometry::opencv_from_extern(ret) };
@@ -5534,7 +7125,7 @@ impl RgbdICPOdometry {
 	/// * maxPointsPart: The method uses a random pixels subset of size frameWidth x frameHeight x pointsPart
 	/// * iterCounts: Count of iterations on each pyramid level.
 	/// * minGradientMagnitudes: For each pyramid level the pixels will be filtered out
-	///                              if they have gradient magnitude less than minGradientMagnitudes[level].
+	///                              if they have gradient magnitude less than minGradientMagnitudes\[level\].
 	/// * transformType: Class of trasformation
 	/// 
 	/// ## C++ default parameters
@@ -5548,7 +7139,7 @@ impl RgbdICPOdometry {
 	#[inline]
 	pub fn new(camera_matrix: &core::Mat, min_depth: f32, max_depth: f32, max_depth_diff: f32, max_points_part: f32, iter_counts: &core::Vector<i32>, min_gradient_magnitudes: &core::Vector<f32>, transform_type: i32) -> Result<crate::rgbd::RgbdICPOdometry> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdICPOdometry_RgbdICPOdometry_const_MatR_float_float_float_float_const_vector_int_R_const_vector_float_R_int(camera_matrix.as_raw_Mat(), min_depth, max_depth, max_depth_diff, max_points_part, iter_counts.as_raw_VectorOfi32(), min_gradient_magnitudes.as_raw_VectorOff32(), transform_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdICPOdometry_RgbdICPOdometry_const_MatR_float_float_float_float_const_vector_int_R_const_vector_float_R_int(camera_matrix.as_raw_Mat(), min_depth, max_depth, max_depth_diff, max_points_part, iter_counts.as_raw_VectorOfi32(), min_gradient_magnitudes.as_raw_VectorOff32(), transform_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::RgbdICPOdometry::opencv_from_extern(ret) };
@@ -5567,7 +7158,7 @@ impl RgbdICPOdometry {
 	#[inline]
 	pub fn create(camera_matrix: &core::Mat, min_depth: f32, max_depth: f32, max_depth_diff: f32, max_points_part: f32, iter_counts: &core::Vector<i32>, min_gradient_magnitudes: &core::Vector<f32>, transform_type: i32) -> Result<core::Ptr<crate::rgbd::RgbdICPOdometry>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdICPOdometry_create_const_MatR_float_float_float_float_const_vector_int_R_const_vector_float_R_int(camera_matrix.as_raw_Mat(), min_depth, max_depth, max_depth_diff, max_points_part, iter_counts.as_raw_VectorOfi32(), min_gradient_magnitudes.as_raw_VectorOff32(), transform_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdICPOdometry_create_const_MatR_float_float_float_float_const_vector_int_R_const_vector_float_R_int(camera_matrix.as_raw_Mat(), min_depth, max_depth, max_depth_diff, max_points_part, iter_counts.as_raw_VectorOfi32(), min_gradient_magnitudes.as_raw_VectorOff32(), transform_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::rgbd::RgbdICPOdometry>::opencv_from_extern(ret) };
@@ -5594,8 +7185,9 @@ pub trait RgbdNormalsTraitConst: core::AlgorithmTraitConst {
 	/// If that function is not called, it will be called the first time normals are computed
 	#[inline]
 	fn initialize(&self) -> Result<()> {
+		crate::core::validity::check("RgbdNormals", self.as_raw_RgbdNormals())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdNormals_initialize_const(self.as_raw_RgbdNormals(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdNormals_initialize_const(self.as_raw_RgbdNormals(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5603,8 +7195,9 @@ pub trait RgbdNormalsTraitConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_rows(&self) -> Result<i32> {
+		crate::core::validity::check("RgbdNormals", self.as_raw_RgbdNormals())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdNormals_getRows_const(self.as_raw_RgbdNormals(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdNormals_getRows_const(self.as_raw_RgbdNormals(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5612,8 +7205,9 @@ pub trait RgbdNormalsTraitConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_cols(&self) -> Result<i32> {
+		crate::core::validity::check("RgbdNormals", self.as_raw_RgbdNormals())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdNormals_getCols_const(self.as_raw_RgbdNormals(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdNormals_getCols_const(self.as_raw_RgbdNormals(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5621,8 +7215,9 @@ pub trait RgbdNormalsTraitConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_window_size(&self) -> Result<i32> {
+		crate::core::validity::check("RgbdNormals", self.as_raw_RgbdNormals())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdNormals_getWindowSize_const(self.as_raw_RgbdNormals(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdNormals_getWindowSize_const(self.as_raw_RgbdNormals(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5630,8 +7225,9 @@ pub trait RgbdNormalsTraitConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_depth(&self) -> Result<i32> {
+		crate::core::validity::check("RgbdNormals", self.as_raw_RgbdNormals())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdNormals_getDepth_const(self.as_raw_RgbdNormals(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdNormals_getDepth_const(self.as_raw_RgbdNormals(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5639,8 +7235,9 @@ pub trait RgbdNormalsTraitConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_k(&self) -> Result<core::Mat> {
+		crate::core::validity::check("RgbdNormals", self.as_raw_RgbdNormals())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdNormals_getK_const(self.as_raw_RgbdNormals(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdNormals_getK_const(self.as_raw_RgbdNormals(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -5649,8 +7246,9 @@ pub trait RgbdNormalsTraitConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_method(&self) -> Result<i32> {
+		crate::core::validity::check("RgbdNormals", self.as_raw_RgbdNormals())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdNormals_getMethod_const(self.as_raw_RgbdNormals(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdNormals_getMethod_const(self.as_raw_RgbdNormals(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5663,8 +7261,9 @@ pub trait RgbdNormalsTrait: core::AlgorithmTrait + crate::rgbd::RgbdNormalsTrait
 
 	#[inline]
 	fn set_rows(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("RgbdNormals", self.as_raw_mut_RgbdNormals())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdNormals_setRows_int(self.as_raw_mut_RgbdNormals(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdNormals_setRows_int(self.as_raw_mut_RgbdNormals(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5672,8 +7271,9 @@ pub trait RgbdNormalsTrait: core::AlgorithmTrait + crate::rgbd::RgbdNormalsTrait
 	
 	#[inline]
 	fn set_cols(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("RgbdNormals", self.as_raw_mut_RgbdNormals())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdNormals_setCols_int(self.as_raw_mut_RgbdNormals(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdNormals_setCols_int(self.as_raw_mut_RgbdNormals(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5681,8 +7281,9 @@ pub trait RgbdNormalsTrait: core::AlgorithmTrait + crate::rgbd::RgbdNormalsTrait
 	
 	#[inline]
 	fn set_window_size(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("RgbdNormals", self.as_raw_mut_RgbdNormals())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdNormals_setWindowSize_int(self.as_raw_mut_RgbdNormals(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdNormals_setWindowSize_int(self.as_raw_mut_RgbdNormals(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5690,8 +7291,9 @@ pub trait RgbdNormalsTrait: core::AlgorithmTrait + crate::rgbd::RgbdNormalsTrait
 	
 	#[inline]
 	fn set_depth(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("RgbdNormals", self.as_raw_mut_RgbdNormals())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdNormals_setDepth_int(self.as_raw_mut_RgbdNormals(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdNormals_setDepth_int(self.as_raw_mut_RgbdNormals(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5699,8 +7301,9 @@ pub trait RgbdNormalsTrait: core::AlgorithmTrait + crate::rgbd::RgbdNormalsTrait
 	
 	#[inline]
 	fn set_k(&mut self, val: &core::Mat) -> Result<()> {
+		crate::core::validity::check("RgbdNormals", self.as_raw_mut_RgbdNormals())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdNormals_setK_const_MatR(self.as_raw_mut_RgbdNormals(), val.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdNormals_setK_const_MatR(self.as_raw_mut_RgbdNormals(), val.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5708,8 +7311,9 @@ pub trait RgbdNormalsTrait: core::AlgorithmTrait + crate::rgbd::RgbdNormalsTrait
 	
 	#[inline]
 	fn set_method(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("RgbdNormals", self.as_raw_mut_RgbdNormals())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdNormals_setMethod_int(self.as_raw_mut_RgbdNormals(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdNormals_setMethod_int(self.as_raw_mut_RgbdNormals(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5735,7 +7339,10 @@ opencv_type_boxed! { RgbdNormals }
 impl Drop for RgbdNormals {
 	fn drop(&mut self) {
 		extern "C" { fn cv_RgbdNormals_delete(instance: *mut c_void); }
-		unsafe { cv_RgbdNormals_delete(self.as_raw_mut_RgbdNormals()) };
+		let ptr = self.as_raw_mut_RgbdNormals();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_RgbdNormals_delete(ptr) };
 	}
 }
 
@@ -5761,7 +7368,7 @@ impl RgbdNormals {
 	#[inline]
 	pub fn default() -> Result<crate::rgbd::RgbdNormals> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdNormals_RgbdNormals(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdNormals_RgbdNormals(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::RgbdNormals::opencv_from_extern(ret) };
@@ -5784,7 +7391,7 @@ impl RgbdNormals {
 	pub fn new(rows: i32, cols: i32, depth: i32, k: &dyn core::ToInputArray, window_size: i32, method: i32) -> Result<crate::rgbd::RgbdNormals> {
 		input_array_arg!(k);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdNormals_RgbdNormals_int_int_int_const__InputArrayR_int_int(rows, cols, depth, k.as_raw__InputArray(), window_size, method, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdNormals_RgbdNormals_int_int_int_const__InputArrayR_int_int(rows, cols, depth, k.as_raw__InputArray(), window_size, method, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::RgbdNormals::opencv_from_extern(ret) };
@@ -5798,7 +7405,7 @@ impl RgbdNormals {
 	pub fn create(rows: i32, cols: i32, depth: i32, k: &dyn core::ToInputArray, window_size: i32, method: i32) -> Result<core::Ptr<crate::rgbd::RgbdNormals>> {
 		input_array_arg!(k);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdNormals_create_int_int_int_const__InputArrayR_int_int(rows, cols, depth, k.as_raw__InputArray(), window_size, method, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdNormals_create_int_int_int_const__InputArrayR_int_int(rows, cols, depth, k.as_raw__InputArray(), window_size, method, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::rgbd::RgbdNormals>::opencv_from_extern(ret) };
@@ -5807,6 +7414,70 @@ impl RgbdNormals {
 	
 }
 
+/// Builder for `RgbdNormals::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct RgbdNormals_NewBuilder<'a> {
+	rows: i32,
+	cols: i32,
+	depth: i32,
+	k: &'a dyn core::ToInputArray,
+	window_size: i32,
+	method: i32,
+}
+
+impl<'a> RgbdNormals_NewBuilder<'a> {
+	#[inline]
+	pub fn new(rows: i32, cols: i32, depth: i32, k: &'a dyn core::ToInputArray, method: i32) -> Self {
+		Self { rows, cols, depth, k, window_size: 5, method }
+	}
+	
+	/// C++ default: `5`
+	#[inline]
+	pub fn with_window_size(mut self, window_size: i32) -> Self {
+		self.window_size = window_size;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::rgbd::RgbdNormals> {
+		RgbdNormals::new(self.rows, self.cols, self.depth, self.k, self.window_size, self.method)
+	}
+}
+
+/// Builder for `RgbdNormals::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct RgbdNormals_CreateBuilder<'a> {
+	rows: i32,
+	cols: i32,
+	depth: i32,
+	k: &'a dyn core::ToInputArray,
+	window_size: i32,
+	method: i32,
+}
+
+impl<'a> RgbdNormals_CreateBuilder<'a> {
+	#[inline]
+	pub fn new(rows: i32, cols: i32, depth: i32, k: &'a dyn core::ToInputArray, method: i32) -> Self {
+		Self { rows, cols, depth, k, window_size: 5, method }
+	}
+	
+	/// C++ default: `5`
+	#[inline]
+	pub fn with_window_size(mut self, window_size: i32) -> Self {
+		self.window_size = window_size;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<crate::rgbd::RgbdNormals>> {
+		RgbdNormals::create(self.rows, self.cols, self.depth, self.k, self.window_size, self.method)
+	}
+}
+
 boxed_cast_base! { RgbdNormals, core::Algorithm, cv_RgbdNormals_to_Algorithm }
 
 /// Odometry based on the paper "Real-Time Visual Odometry from Dense RGB-D Images",
@@ -5816,8 +7487,9 @@ pub trait RgbdOdometryTraitConst: crate::rgbd::OdometryConst {
 
 	#[inline]
 	fn prepare_frame_cache(&self, frame: &mut core::Ptr<crate::rgbd::OdometryFrame>, cache_type: i32) -> Result<core::Size> {
+		crate::core::validity::check("RgbdOdometry", self.as_raw_RgbdOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdOdometry_prepareFrameCache_const_Ptr_OdometryFrame_R_int(self.as_raw_RgbdOdometry(), frame.as_raw_mut_PtrOfOdometryFrame(), cache_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdOdometry_prepareFrameCache_const_Ptr_OdometryFrame_R_int(self.as_raw_RgbdOdometry(), frame.as_raw_mut_PtrOfOdometryFrame(), cache_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5825,8 +7497,9 @@ pub trait RgbdOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_camera_matrix(&self) -> Result<core::Mat> {
+		crate::core::validity::check("RgbdOdometry", self.as_raw_RgbdOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdOdometry_getCameraMatrix_const(self.as_raw_RgbdOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdOdometry_getCameraMatrix_const(self.as_raw_RgbdOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -5835,8 +7508,9 @@ pub trait RgbdOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_min_depth(&self) -> Result<f64> {
+		crate::core::validity::check("RgbdOdometry", self.as_raw_RgbdOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdOdometry_getMinDepth_const(self.as_raw_RgbdOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdOdometry_getMinDepth_const(self.as_raw_RgbdOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5844,8 +7518,9 @@ pub trait RgbdOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_max_depth(&self) -> Result<f64> {
+		crate::core::validity::check("RgbdOdometry", self.as_raw_RgbdOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdOdometry_getMaxDepth_const(self.as_raw_RgbdOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdOdometry_getMaxDepth_const(self.as_raw_RgbdOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5853,8 +7528,9 @@ pub trait RgbdOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_max_depth_diff(&self) -> Result<f64> {
+		crate::core::validity::check("RgbdOdometry", self.as_raw_RgbdOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdOdometry_getMaxDepthDiff_const(self.as_raw_RgbdOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdOdometry_getMaxDepthDiff_const(self.as_raw_RgbdOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5862,8 +7538,9 @@ pub trait RgbdOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_iteration_counts(&self) -> Result<core::Mat> {
+		crate::core::validity::check("RgbdOdometry", self.as_raw_RgbdOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdOdometry_getIterationCounts_const(self.as_raw_RgbdOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdOdometry_getIterationCounts_const(self.as_raw_RgbdOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -5872,8 +7549,9 @@ pub trait RgbdOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_min_gradient_magnitudes(&self) -> Result<core::Mat> {
+		crate::core::validity::check("RgbdOdometry", self.as_raw_RgbdOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdOdometry_getMinGradientMagnitudes_const(self.as_raw_RgbdOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdOdometry_getMinGradientMagnitudes_const(self.as_raw_RgbdOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -5882,8 +7560,9 @@ pub trait RgbdOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_max_points_part(&self) -> Result<f64> {
+		crate::core::validity::check("RgbdOdometry", self.as_raw_RgbdOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdOdometry_getMaxPointsPart_const(self.as_raw_RgbdOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdOdometry_getMaxPointsPart_const(self.as_raw_RgbdOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5891,8 +7570,9 @@ pub trait RgbdOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_transform_type(&self) -> Result<i32> {
+		crate::core::validity::check("RgbdOdometry", self.as_raw_RgbdOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdOdometry_getTransformType_const(self.as_raw_RgbdOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdOdometry_getTransformType_const(self.as_raw_RgbdOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5900,8 +7580,9 @@ pub trait RgbdOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_max_translation(&self) -> Result<f64> {
+		crate::core::validity::check("RgbdOdometry", self.as_raw_RgbdOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdOdometry_getMaxTranslation_const(self.as_raw_RgbdOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdOdometry_getMaxTranslation_const(self.as_raw_RgbdOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5909,8 +7590,9 @@ pub trait RgbdOdometryTraitConst: crate::rgbd::OdometryConst {
 	
 	#[inline]
 	fn get_max_rotation(&self) -> Result<f64> {
+		crate::core::validity::check("RgbdOdometry", self.as_raw_RgbdOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdOdometry_getMaxRotation_const(self.as_raw_RgbdOdometry(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdOdometry_getMaxRotation_const(self.as_raw_RgbdOdometry(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5923,8 +7605,9 @@ pub trait RgbdOdometryTrait: crate::rgbd::Odometry + crate::rgbd::RgbdOdometryTr
 
 	#[inline]
 	fn set_camera_matrix(&mut self, val: &core::Mat) -> Result<()> {
+		crate::core::validity::check("RgbdOdometry", self.as_raw_mut_RgbdOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdOdometry_setCameraMatrix_const_MatR(self.as_raw_mut_RgbdOdometry(), val.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdOdometry_setCameraMatrix_const_MatR(self.as_raw_mut_RgbdOdometry(), val.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5932,8 +7615,9 @@ pub trait RgbdOdometryTrait: crate::rgbd::Odometry + crate::rgbd::RgbdOdometryTr
 	
 	#[inline]
 	fn set_min_depth(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("RgbdOdometry", self.as_raw_mut_RgbdOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdOdometry_setMinDepth_double(self.as_raw_mut_RgbdOdometry(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdOdometry_setMinDepth_double(self.as_raw_mut_RgbdOdometry(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5941,8 +7625,9 @@ pub trait RgbdOdometryTrait: crate::rgbd::Odometry + crate::rgbd::RgbdOdometryTr
 	
 	#[inline]
 	fn set_max_depth(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("RgbdOdometry", self.as_raw_mut_RgbdOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdOdometry_setMaxDepth_double(self.as_raw_mut_RgbdOdometry(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdOdometry_setMaxDepth_double(self.as_raw_mut_RgbdOdometry(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5950,8 +7635,9 @@ pub trait RgbdOdometryTrait: crate::rgbd::Odometry + crate::rgbd::RgbdOdometryTr
 	
 	#[inline]
 	fn set_max_depth_diff(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("RgbdOdometry", self.as_raw_mut_RgbdOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdOdometry_setMaxDepthDiff_double(self.as_raw_mut_RgbdOdometry(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdOdometry_setMaxDepthDiff_double(self.as_raw_mut_RgbdOdometry(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5959,8 +7645,9 @@ pub trait RgbdOdometryTrait: crate::rgbd::Odometry + crate::rgbd::RgbdOdometryTr
 	
 	#[inline]
 	fn set_iteration_counts(&mut self, val: &core::Mat) -> Result<()> {
+		crate::core::validity::check("RgbdOdometry", self.as_raw_mut_RgbdOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdOdometry_setIterationCounts_const_MatR(self.as_raw_mut_RgbdOdometry(), val.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdOdometry_setIterationCounts_const_MatR(self.as_raw_mut_RgbdOdometry(), val.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5968,8 +7655,9 @@ pub trait RgbdOdometryTrait: crate::rgbd::Odometry + crate::rgbd::RgbdOdometryTr
 	
 	#[inline]
 	fn set_min_gradient_magnitudes(&mut self, val: &core::Mat) -> Result<()> {
+		crate::core::validity::check("RgbdOdometry", self.as_raw_mut_RgbdOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdOdometry_setMinGradientMagnitudes_const_MatR(self.as_raw_mut_RgbdOdometry(), val.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdOdometry_setMinGradientMagnitudes_const_MatR(self.as_raw_mut_RgbdOdometry(), val.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5977,8 +7665,9 @@ pub trait RgbdOdometryTrait: crate::rgbd::Odometry + crate::rgbd::RgbdOdometryTr
 	
 	#[inline]
 	fn set_max_points_part(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("RgbdOdometry", self.as_raw_mut_RgbdOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdOdometry_setMaxPointsPart_double(self.as_raw_mut_RgbdOdometry(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdOdometry_setMaxPointsPart_double(self.as_raw_mut_RgbdOdometry(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5986,8 +7675,9 @@ pub trait RgbdOdometryTrait: crate::rgbd::Odometry + crate::rgbd::RgbdOdometryTr
 	
 	#[inline]
 	fn set_transform_type(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("RgbdOdometry", self.as_raw_mut_RgbdOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdOdometry_setTransformType_int(self.as_raw_mut_RgbdOdometry(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdOdometry_setTransformType_int(self.as_raw_mut_RgbdOdometry(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5995,8 +7685,9 @@ pub trait RgbdOdometryTrait: crate::rgbd::Odometry + crate::rgbd::RgbdOdometryTr
 	
 	#[inline]
 	fn set_max_translation(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("RgbdOdometry", self.as_raw_mut_RgbdOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdOdometry_setMaxTranslation_double(self.as_raw_mut_RgbdOdometry(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdOdometry_setMaxTranslation_double(self.as_raw_mut_RgbdOdometry(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6004,8 +7695,9 @@ pub trait RgbdOdometryTrait: crate::rgbd::Odometry + crate::rgbd::RgbdOdometryTr
 	
 	#[inline]
 	fn set_max_rotation(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("RgbdOdometry", self.as_raw_mut_RgbdOdometry())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdOdometry_setMaxRotation_double(self.as_raw_mut_RgbdOdometry(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdOdometry_setMaxRotation_double(self.as_raw_mut_RgbdOdometry(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6024,7 +7716,10 @@ opencv_type_boxed! { RgbdOdometry }
 impl Drop for RgbdOdometry {
 	fn drop(&mut self) {
 		extern "C" { fn cv_RgbdOdometry_delete(instance: *mut c_void); }
-		unsafe { cv_RgbdOdometry_delete(self.as_raw_mut_RgbdOdometry()) };
+		let ptr = self.as_raw_mut_RgbdOdometry();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_RgbdOdometry_delete(ptr) };
 	}
 }
 
@@ -6058,7 +7753,7 @@ impl RgbdOdometry {
 	#[inline]
 	pub fn default() -> Result<crate::rgbd::RgbdOdometry> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdOdometry_RgbdOdometry(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdOdometry_RgbdOdometry(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::RgbdOdometry::opencv_from_extern(ret) };
@@ -6074,7 +7769,7 @@ impl RgbdOdometry {
 	///                    if their depth difference is larger than maxDepthDiff (in meters)
 	/// * iterCounts: Count of iterations on each pyramid level.
 	/// * minGradientMagnitudes: For each pyramid level the pixels will be filtered out
-	///                              if they have gradient magnitude less than minGradientMagnitudes[level].
+	///                              if they have gradient magnitude less than minGradientMagnitudes\[level\].
 	/// * maxPointsPart: The method uses a random pixels subset of size frameWidth x frameHeight x pointsPart
 	/// * transformType: Class of transformation
 	/// 
@@ -6089,7 +7784,7 @@ impl RgbdOdometry {
 	#[inline]
 	pub fn new(camera_matrix: &core::Mat, min_depth: f32, max_depth: f32, max_depth_diff: f32, iter_counts: &core::Vector<i32>, min_gradient_magnitudes: &core::Vector<f32>, max_points_part: f32, transform_type: i32) -> Result<crate::rgbd::RgbdOdometry> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdOdometry_RgbdOdometry_const_MatR_float_float_float_const_vector_int_R_const_vector_float_R_float_int(camera_matrix.as_raw_Mat(), min_depth, max_depth, max_depth_diff, iter_counts.as_raw_VectorOfi32(), min_gradient_magnitudes.as_raw_VectorOff32(), max_points_part, transform_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdOdometry_RgbdOdometry_const_MatR_float_float_float_const_vector_int_R_const_vector_float_R_float_int(camera_matrix.as_raw_Mat(), min_depth, max_depth, max_depth_diff, iter_counts.as_raw_VectorOfi32(), min_gradient_magnitudes.as_raw_VectorOff32(), max_points_part, transform_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::RgbdOdometry::opencv_from_extern(ret) };
@@ -6108,7 +7803,7 @@ impl RgbdOdometry {
 	#[inline]
 	pub fn create(camera_matrix: &core::Mat, min_depth: f32, max_depth: f32, max_depth_diff: f32, iter_counts: &core::Vector<i32>, min_gradient_magnitudes: &core::Vector<f32>, max_points_part: f32, transform_type: i32) -> Result<core::Ptr<crate::rgbd::RgbdOdometry>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdOdometry_create_const_MatR_float_float_float_const_vector_int_R_const_vector_float_R_float_int(camera_matrix.as_raw_Mat(), min_depth, max_depth, max_depth_diff, iter_counts.as_raw_VectorOfi32(), min_gradient_magnitudes.as_raw_VectorOff32(), max_points_part, transform_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdOdometry_create_const_MatR_float_float_float_const_vector_int_R_const_vector_float_R_float_int(camera_matrix.as_raw_Mat(), min_depth, max_depth, max_depth_diff, iter_counts.as_raw_VectorOfi32(), min_gradient_magnitudes.as_raw_VectorOff32(), max_points_part, transform_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::rgbd::RgbdOdometry>::opencv_from_extern(ret) };
@@ -6125,8 +7820,9 @@ pub trait RgbdPlaneTraitConst: core::AlgorithmTraitConst {
 
 	#[inline]
 	fn get_block_size(&self) -> Result<i32> {
+		crate::core::validity::check("RgbdPlane", self.as_raw_RgbdPlane())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdPlane_getBlockSize_const(self.as_raw_RgbdPlane(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdPlane_getBlockSize_const(self.as_raw_RgbdPlane(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6134,8 +7830,9 @@ pub trait RgbdPlaneTraitConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_min_size(&self) -> Result<i32> {
+		crate::core::validity::check("RgbdPlane", self.as_raw_RgbdPlane())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdPlane_getMinSize_const(self.as_raw_RgbdPlane(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdPlane_getMinSize_const(self.as_raw_RgbdPlane(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6143,8 +7840,9 @@ pub trait RgbdPlaneTraitConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_method(&self) -> Result<i32> {
+		crate::core::validity::check("RgbdPlane", self.as_raw_RgbdPlane())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdPlane_getMethod_const(self.as_raw_RgbdPlane(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdPlane_getMethod_const(self.as_raw_RgbdPlane(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6152,8 +7850,9 @@ pub trait RgbdPlaneTraitConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_threshold(&self) -> Result<f64> {
+		crate::core::validity::check("RgbdPlane", self.as_raw_RgbdPlane())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdPlane_getThreshold_const(self.as_raw_RgbdPlane(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdPlane_getThreshold_const(self.as_raw_RgbdPlane(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6161,8 +7860,9 @@ pub trait RgbdPlaneTraitConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_sensor_error_a(&self) -> Result<f64> {
+		crate::core::validity::check("RgbdPlane", self.as_raw_RgbdPlane())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdPlane_getSensorErrorA_const(self.as_raw_RgbdPlane(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdPlane_getSensorErrorA_const(self.as_raw_RgbdPlane(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6170,8 +7870,9 @@ pub trait RgbdPlaneTraitConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_sensor_error_b(&self) -> Result<f64> {
+		crate::core::validity::check("RgbdPlane", self.as_raw_RgbdPlane())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdPlane_getSensorErrorB_const(self.as_raw_RgbdPlane(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdPlane_getSensorErrorB_const(self.as_raw_RgbdPlane(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6179,8 +7880,9 @@ pub trait RgbdPlaneTraitConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_sensor_error_c(&self) -> Result<f64> {
+		crate::core::validity::check("RgbdPlane", self.as_raw_RgbdPlane())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdPlane_getSensorErrorC_const(self.as_raw_RgbdPlane(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdPlane_getSensorErrorC_const(self.as_raw_RgbdPlane(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6193,8 +7895,9 @@ pub trait RgbdPlaneTrait: core::AlgorithmTrait + crate::rgbd::RgbdPlaneTraitCons
 
 	#[inline]
 	fn set_block_size(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("RgbdPlane", self.as_raw_mut_RgbdPlane())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdPlane_setBlockSize_int(self.as_raw_mut_RgbdPlane(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdPlane_setBlockSize_int(self.as_raw_mut_RgbdPlane(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6202,8 +7905,9 @@ pub trait RgbdPlaneTrait: core::AlgorithmTrait + crate::rgbd::RgbdPlaneTraitCons
 	
 	#[inline]
 	fn set_min_size(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("RgbdPlane", self.as_raw_mut_RgbdPlane())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdPlane_setMinSize_int(self.as_raw_mut_RgbdPlane(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdPlane_setMinSize_int(self.as_raw_mut_RgbdPlane(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6211,8 +7915,9 @@ pub trait RgbdPlaneTrait: core::AlgorithmTrait + crate::rgbd::RgbdPlaneTraitCons
 	
 	#[inline]
 	fn set_method(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("RgbdPlane", self.as_raw_mut_RgbdPlane())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdPlane_setMethod_int(self.as_raw_mut_RgbdPlane(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdPlane_setMethod_int(self.as_raw_mut_RgbdPlane(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6220,8 +7925,9 @@ pub trait RgbdPlaneTrait: core::AlgorithmTrait + crate::rgbd::RgbdPlaneTraitCons
 	
 	#[inline]
 	fn set_threshold(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("RgbdPlane", self.as_raw_mut_RgbdPlane())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdPlane_setThreshold_double(self.as_raw_mut_RgbdPlane(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdPlane_setThreshold_double(self.as_raw_mut_RgbdPlane(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6229,8 +7935,9 @@ pub trait RgbdPlaneTrait: core::AlgorithmTrait + crate::rgbd::RgbdPlaneTraitCons
 	
 	#[inline]
 	fn set_sensor_error_a(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("RgbdPlane", self.as_raw_mut_RgbdPlane())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdPlane_setSensorErrorA_double(self.as_raw_mut_RgbdPlane(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdPlane_setSensorErrorA_double(self.as_raw_mut_RgbdPlane(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6238,8 +7945,9 @@ pub trait RgbdPlaneTrait: core::AlgorithmTrait + crate::rgbd::RgbdPlaneTraitCons
 	
 	#[inline]
 	fn set_sensor_error_b(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("RgbdPlane", self.as_raw_mut_RgbdPlane())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdPlane_setSensorErrorB_double(self.as_raw_mut_RgbdPlane(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdPlane_setSensorErrorB_double(self.as_raw_mut_RgbdPlane(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6247,8 +7955,9 @@ pub trait RgbdPlaneTrait: core::AlgorithmTrait + crate::rgbd::RgbdPlaneTraitCons
 	
 	#[inline]
 	fn set_sensor_error_c(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("RgbdPlane", self.as_raw_mut_RgbdPlane())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdPlane_setSensorErrorC_double(self.as_raw_mut_RgbdPlane(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdPlane_setSensorErrorC_double(self.as_raw_mut_RgbdPlane(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6266,7 +7975,10 @@ opencv_type_boxed! { RgbdPlane }
 impl Drop for RgbdPlane {
 	fn drop(&mut self) {
 		extern "C" { fn cv_RgbdPlane_delete(instance: *mut c_void); }
-		unsafe { cv_RgbdPlane_delete(self.as_raw_mut_RgbdPlane()) };
+		let ptr = self.as_raw_mut_RgbdPlane();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_RgbdPlane_delete(ptr) };
 	}
 }
 
@@ -6294,7 +8006,7 @@ impl RgbdPlane {
 	#[inline]
 	pub fn new(method: i32) -> Result<crate::rgbd::RgbdPlane> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdPlane_RgbdPlane_int(method, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdPlane_RgbdPlane_int(method, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::RgbdPlane::opencv_from_extern(ret) };
@@ -6318,7 +8030,7 @@ impl RgbdPlane {
 	#[inline]
 	pub fn new_1(method: i32, block_size: i32, min_size: i32, threshold: f64, sensor_error_a: f64, sensor_error_b: f64, sensor_error_c: f64) -> Result<crate::rgbd::RgbdPlane> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdPlane_RgbdPlane_int_int_int_double_double_double_double(method, block_size, min_size, threshold, sensor_error_a, sensor_error_b, sensor_error_c, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdPlane_RgbdPlane_int_int_int_double_double_double_double(method, block_size, min_size, threshold, sensor_error_a, sensor_error_b, sensor_error_c, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::RgbdPlane::opencv_from_extern(ret) };
@@ -6332,7 +8044,7 @@ impl RgbdPlane {
 	#[inline]
 	pub fn create(method: i32, block_size: i32, min_size: i32, threshold: f64, sensor_error_a: f64, sensor_error_b: f64, sensor_error_c: f64) -> Result<core::Ptr<crate::rgbd::RgbdPlane>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdPlane_create_int_int_int_double_double_double_double(method, block_size, min_size, threshold, sensor_error_a, sensor_error_b, sensor_error_c, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_rgbd_RgbdPlane_create_int_int_int_double_double_double_double(method, block_size, min_size, threshold, sensor_error_a, sensor_error_b, sensor_error_c, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::rgbd::RgbdPlane>::opencv_from_extern(ret) };
@@ -6341,4 +8053,99 @@ impl RgbdPlane {
 	
 }
 
+/// Builder for `RgbdPlane::new_1()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct RgbdPlane_New1Builder {
+	method: i32,
+	block_size: i32,
+	min_size: i32,
+	threshold: f64,
+	sensor_error_a: f64,
+	sensor_error_b: f64,
+	sensor_error_c: f64,
+}
+
+impl RgbdPlane_New1Builder {
+	#[inline]
+	pub fn new(method: i32, block_size: i32, min_size: i32, threshold: f64) -> Self {
+		Self { method, block_size, min_size, threshold, sensor_error_a: 0., sensor_error_b: 0., sensor_error_c: 0. }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_sensor_error_a(mut self, sensor_error_a: f64) -> Self {
+		self.sensor_error_a = sensor_error_a;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_sensor_error_b(mut self, sensor_error_b: f64) -> Self {
+		self.sensor_error_b = sensor_error_b;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_sensor_error_c(mut self, sensor_error_c: f64) -> Self {
+		self.sensor_error_c = sensor_error_c;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::rgbd::RgbdPlane> {
+		RgbdPlane::new_1(self.method, self.block_size, self.min_size, self.threshold, self.sensor_error_a, self.sensor_error_b, self.sensor_error_c)
+	}
+}
+
+/// Builder for `RgbdPlane::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct RgbdPlane_CreateBuilder {
+	method: i32,
+	block_size: i32,
+	min_size: i32,
+	threshold: f64,
+	sensor_error_a: f64,
+	sensor_error_b: f64,
+	sensor_error_c: f64,
+}
+
+impl RgbdPlane_CreateBuilder {
+	#[inline]
+	pub fn new(method: i32, block_size: i32, min_size: i32, threshold: f64) -> Self {
+		Self { method, block_size, min_size, threshold, sensor_error_a: 0., sensor_error_b: 0., sensor_error_c: 0. }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_sensor_error_a(mut self, sensor_error_a: f64) -> Self {
+		self.sensor_error_a = sensor_error_a;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_sensor_error_b(mut self, sensor_error_b: f64) -> Self {
+		self.sensor_error_b = sensor_error_b;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_sensor_error_c(mut self, sensor_error_c: f64) -> Self {
+		self.sensor_error_c = sensor_error_c;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<crate::rgbd::RgbdPlane>> {
+		RgbdPlane::create(self.method, self.block_size, self.min_size, self.threshold, self.sensor_error_a, self.sensor_error_b, self.sensor_error_c)
+	}
+}
+
 boxed_cast_base! { RgbdPlane, core::Algorithm, cv_RgbdPlane_to_Algorithm }
+pub use crate::manual::rgbd::*;
@@ -59,8 +59,8 @@ pub trait MotionSaliency: crate::saliency::MotionSaliencyConst + crate::saliency
 /// !
 ///  * A Fast Self-tuning Background Subtraction Algorithm.
 ///  *
-///  * This background subtraction algorithm is inspired to the work of B. Wang and P. Dudek [2]
-///  * [2]  B. Wang and P. Dudek "A Fast Self-tuning Background Subtraction Algorithm", in proc of IEEE Workshop on Change Detection, 2014
+///  * This background subtraction algorithm is inspired to the work of B. Wang and P. Dudek \[2\]
+///  * \[2\]  B. Wang and P. Dudek "A Fast Self-tuning Background Subtraction Algorithm", in proc of IEEE Workshop on Change Detection, 2014
 ///  *
 /// 
 /// the Fast Self-tuning Background Subtraction Algorithm from [BinWangApr2014](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_BinWangApr2014)
@@ -69,8 +69,9 @@ pub trait MotionSaliencyBinWangApr2014TraitConst: crate::saliency::MotionSalienc
 
 	#[inline]
 	fn get_image_width(&self) -> Result<i32> {
+		crate::core::validity::check("MotionSaliencyBinWangApr2014", self.as_raw_MotionSaliencyBinWangApr2014())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_MotionSaliencyBinWangApr2014_getImageWidth_const(self.as_raw_MotionSaliencyBinWangApr2014(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_MotionSaliencyBinWangApr2014_getImageWidth_const(self.as_raw_MotionSaliencyBinWangApr2014(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -78,8 +79,9 @@ pub trait MotionSaliencyBinWangApr2014TraitConst: crate::saliency::MotionSalienc
 	
 	#[inline]
 	fn get_image_height(&self) -> Result<i32> {
+		crate::core::validity::check("MotionSaliencyBinWangApr2014", self.as_raw_MotionSaliencyBinWangApr2014())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_MotionSaliencyBinWangApr2014_getImageHeight_const(self.as_raw_MotionSaliencyBinWangApr2014(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_MotionSaliencyBinWangApr2014_getImageHeight_const(self.as_raw_MotionSaliencyBinWangApr2014(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -92,10 +94,11 @@ pub trait MotionSaliencyBinWangApr2014Trait: crate::saliency::MotionSaliency + c
 
 	#[inline]
 	fn compute_saliency(&mut self, image: &dyn core::ToInputArray, saliency_map: &mut dyn core::ToOutputArray) -> Result<bool> {
+		crate::core::validity::check("MotionSaliencyBinWangApr2014", self.as_raw_mut_MotionSaliencyBinWangApr2014())?;
 		input_array_arg!(image);
 		output_array_arg!(saliency_map);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_MotionSaliencyBinWangApr2014_computeSaliency_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_MotionSaliencyBinWangApr2014(), image.as_raw__InputArray(), saliency_map.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_MotionSaliencyBinWangApr2014_computeSaliency_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_MotionSaliencyBinWangApr2014(), image.as_raw__InputArray(), saliency_map.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -108,8 +111,9 @@ pub trait MotionSaliencyBinWangApr2014Trait: crate::saliency::MotionSaliency + c
 	/// * H: height of input image
 	#[inline]
 	fn set_imagesize(&mut self, w: i32, h: i32) -> Result<()> {
+		crate::core::validity::check("MotionSaliencyBinWangApr2014", self.as_raw_mut_MotionSaliencyBinWangApr2014())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_MotionSaliencyBinWangApr2014_setImagesize_int_int(self.as_raw_mut_MotionSaliencyBinWangApr2014(), w, h, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_MotionSaliencyBinWangApr2014_setImagesize_int_int(self.as_raw_mut_MotionSaliencyBinWangApr2014(), w, h, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -119,8 +123,9 @@ pub trait MotionSaliencyBinWangApr2014Trait: crate::saliency::MotionSaliency + c
 	/// algorithm.
 	#[inline]
 	fn init(&mut self) -> Result<bool> {
+		crate::core::validity::check("MotionSaliencyBinWangApr2014", self.as_raw_mut_MotionSaliencyBinWangApr2014())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_MotionSaliencyBinWangApr2014_init(self.as_raw_mut_MotionSaliencyBinWangApr2014(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_MotionSaliencyBinWangApr2014_init(self.as_raw_mut_MotionSaliencyBinWangApr2014(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -128,8 +133,9 @@ pub trait MotionSaliencyBinWangApr2014Trait: crate::saliency::MotionSaliency + c
 	
 	#[inline]
 	fn set_image_width(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("MotionSaliencyBinWangApr2014", self.as_raw_mut_MotionSaliencyBinWangApr2014())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_MotionSaliencyBinWangApr2014_setImageWidth_int(self.as_raw_mut_MotionSaliencyBinWangApr2014(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_MotionSaliencyBinWangApr2014_setImageWidth_int(self.as_raw_mut_MotionSaliencyBinWangApr2014(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -137,8 +143,9 @@ pub trait MotionSaliencyBinWangApr2014Trait: crate::saliency::MotionSaliency + c
 	
 	#[inline]
 	fn set_image_height(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("MotionSaliencyBinWangApr2014", self.as_raw_mut_MotionSaliencyBinWangApr2014())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_MotionSaliencyBinWangApr2014_setImageHeight_int(self.as_raw_mut_MotionSaliencyBinWangApr2014(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_MotionSaliencyBinWangApr2014_setImageHeight_int(self.as_raw_mut_MotionSaliencyBinWangApr2014(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -149,8 +156,8 @@ pub trait MotionSaliencyBinWangApr2014Trait: crate::saliency::MotionSaliency + c
 /// !
 ///  * A Fast Self-tuning Background Subtraction Algorithm.
 ///  *
-///  * This background subtraction algorithm is inspired to the work of B. Wang and P. Dudek [2]
-///  * [2]  B. Wang and P. Dudek "A Fast Self-tuning Background Subtraction Algorithm", in proc of IEEE Workshop on Change Detection, 2014
+///  * This background subtraction algorithm is inspired to the work of B. Wang and P. Dudek \[2\]
+///  * \[2\]  B. Wang and P. Dudek "A Fast Self-tuning Background Subtraction Algorithm", in proc of IEEE Workshop on Change Detection, 2014
 ///  *
 /// 
 /// the Fast Self-tuning Background Subtraction Algorithm from [BinWangApr2014](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_BinWangApr2014)
@@ -163,7 +170,10 @@ opencv_type_boxed! { MotionSaliencyBinWangApr2014 }
 impl Drop for MotionSaliencyBinWangApr2014 {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MotionSaliencyBinWangApr2014_delete(instance: *mut c_void); }
-		unsafe { cv_MotionSaliencyBinWangApr2014_delete(self.as_raw_mut_MotionSaliencyBinWangApr2014()) };
+		let ptr = self.as_raw_mut_MotionSaliencyBinWangApr2014();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_MotionSaliencyBinWangApr2014_delete(ptr) };
 	}
 }
 
@@ -205,7 +215,7 @@ impl MotionSaliencyBinWangApr2014 {
 	#[inline]
 	pub fn default() -> Result<crate::saliency::MotionSaliencyBinWangApr2014> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_MotionSaliencyBinWangApr2014_MotionSaliencyBinWangApr2014(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_MotionSaliencyBinWangApr2014_MotionSaliencyBinWangApr2014(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::saliency::MotionSaliencyBinWangApr2014::opencv_from_extern(ret) };
@@ -215,7 +225,7 @@ impl MotionSaliencyBinWangApr2014 {
 	#[inline]
 	pub fn create() -> Result<core::Ptr<crate::saliency::MotionSaliencyBinWangApr2014>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_MotionSaliencyBinWangApr2014_create(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_MotionSaliencyBinWangApr2014_create(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::saliency::MotionSaliencyBinWangApr2014>::opencv_from_extern(ret) };
@@ -243,8 +253,9 @@ pub trait ObjectnessBINGTraitConst: crate::saliency::ObjectnessConst {
 
 	#[inline]
 	fn write(&self) -> Result<()> {
+		crate::core::validity::check("ObjectnessBING", self.as_raw_ObjectnessBING())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_ObjectnessBING_write_const(self.as_raw_ObjectnessBING(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_ObjectnessBING_write_const(self.as_raw_ObjectnessBING(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -252,8 +263,9 @@ pub trait ObjectnessBINGTraitConst: crate::saliency::ObjectnessConst {
 	
 	#[inline]
 	fn get_base(&self) -> Result<f64> {
+		crate::core::validity::check("ObjectnessBING", self.as_raw_ObjectnessBING())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_ObjectnessBING_getBase_const(self.as_raw_ObjectnessBING(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_ObjectnessBING_getBase_const(self.as_raw_ObjectnessBING(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -261,8 +273,9 @@ pub trait ObjectnessBINGTraitConst: crate::saliency::ObjectnessConst {
 	
 	#[inline]
 	fn get_nss(&self) -> Result<i32> {
+		crate::core::validity::check("ObjectnessBING", self.as_raw_ObjectnessBING())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_ObjectnessBING_getNSS_const(self.as_raw_ObjectnessBING(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_ObjectnessBING_getNSS_const(self.as_raw_ObjectnessBING(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -270,8 +283,9 @@ pub trait ObjectnessBINGTraitConst: crate::saliency::ObjectnessConst {
 	
 	#[inline]
 	fn get_w(&self) -> Result<i32> {
+		crate::core::validity::check("ObjectnessBING", self.as_raw_ObjectnessBING())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_ObjectnessBING_getW_const(self.as_raw_ObjectnessBING(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_ObjectnessBING_getW_const(self.as_raw_ObjectnessBING(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -284,10 +298,11 @@ pub trait ObjectnessBINGTrait: crate::saliency::Objectness + crate::saliency::Ob
 
 	#[inline]
 	fn compute_saliency(&mut self, image: &dyn core::ToInputArray, saliency_map: &mut dyn core::ToOutputArray) -> Result<bool> {
+		crate::core::validity::check("ObjectnessBING", self.as_raw_mut_ObjectnessBING())?;
 		input_array_arg!(image);
 		output_array_arg!(saliency_map);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_ObjectnessBING_computeSaliency_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_ObjectnessBING(), image.as_raw__InputArray(), saliency_map.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_ObjectnessBING_computeSaliency_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_ObjectnessBING(), image.as_raw__InputArray(), saliency_map.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -295,8 +310,9 @@ pub trait ObjectnessBINGTrait: crate::saliency::Objectness + crate::saliency::Ob
 	
 	#[inline]
 	fn read(&mut self) -> Result<()> {
+		crate::core::validity::check("ObjectnessBING", self.as_raw_mut_ObjectnessBING())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_ObjectnessBING_read(self.as_raw_mut_ObjectnessBING(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_ObjectnessBING_read(self.as_raw_mut_ObjectnessBING(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -309,8 +325,9 @@ pub trait ObjectnessBINGTrait: crate::saliency::Objectness + crate::saliency::Ob
 	/// object window.
 	#[inline]
 	fn getobjectness_values(&mut self) -> Result<core::Vector<f32>> {
+		crate::core::validity::check("ObjectnessBING", self.as_raw_mut_ObjectnessBING())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_ObjectnessBING_getobjectnessValues(self.as_raw_mut_ObjectnessBING(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_ObjectnessBING_getobjectnessValues(self.as_raw_mut_ObjectnessBING(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<f32>::opencv_from_extern(ret) };
@@ -323,9 +340,10 @@ pub trait ObjectnessBINGTrait: crate::saliency::Objectness + crate::saliency::Ob
 	/// * trainingPath: trained model path
 	#[inline]
 	fn set_training_path(&mut self, training_path: &str) -> Result<()> {
+		crate::core::validity::check("ObjectnessBING", self.as_raw_mut_ObjectnessBING())?;
 		extern_container_arg!(training_path);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_ObjectnessBING_setTrainingPath_const_StringR(self.as_raw_mut_ObjectnessBING(), training_path.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_ObjectnessBING_setTrainingPath_const_StringR(self.as_raw_mut_ObjectnessBING(), training_path.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -340,9 +358,10 @@ pub trait ObjectnessBINGTrait: crate::saliency::Objectness + crate::saliency::Ob
 	/// * resultsDir: results' folder path
 	#[inline]
 	fn set_bb_res_dir(&mut self, results_dir: &str) -> Result<()> {
+		crate::core::validity::check("ObjectnessBING", self.as_raw_mut_ObjectnessBING())?;
 		extern_container_arg!(results_dir);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_ObjectnessBING_setBBResDir_const_StringR(self.as_raw_mut_ObjectnessBING(), results_dir.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_ObjectnessBING_setBBResDir_const_StringR(self.as_raw_mut_ObjectnessBING(), results_dir.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -350,8 +369,9 @@ pub trait ObjectnessBINGTrait: crate::saliency::Objectness + crate::saliency::Ob
 	
 	#[inline]
 	fn set_base(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("ObjectnessBING", self.as_raw_mut_ObjectnessBING())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_ObjectnessBING_setBase_double(self.as_raw_mut_ObjectnessBING(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_ObjectnessBING_setBase_double(self.as_raw_mut_ObjectnessBING(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -359,8 +379,9 @@ pub trait ObjectnessBINGTrait: crate::saliency::Objectness + crate::saliency::Ob
 	
 	#[inline]
 	fn set_nss(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("ObjectnessBING", self.as_raw_mut_ObjectnessBING())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_ObjectnessBING_setNSS_int(self.as_raw_mut_ObjectnessBING(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_ObjectnessBING_setNSS_int(self.as_raw_mut_ObjectnessBING(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -368,8 +389,9 @@ pub trait ObjectnessBINGTrait: crate::saliency::Objectness + crate::saliency::Ob
 	
 	#[inline]
 	fn set_w(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("ObjectnessBING", self.as_raw_mut_ObjectnessBING())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_ObjectnessBING_setW_int(self.as_raw_mut_ObjectnessBING(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_ObjectnessBING_setW_int(self.as_raw_mut_ObjectnessBING(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -387,7 +409,10 @@ opencv_type_boxed! { ObjectnessBING }
 impl Drop for ObjectnessBING {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ObjectnessBING_delete(instance: *mut c_void); }
-		unsafe { cv_ObjectnessBING_delete(self.as_raw_mut_ObjectnessBING()) };
+		let ptr = self.as_raw_mut_ObjectnessBING();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ObjectnessBING_delete(ptr) };
 	}
 }
 
@@ -429,7 +454,7 @@ impl ObjectnessBING {
 	#[inline]
 	pub fn default() -> Result<crate::saliency::ObjectnessBING> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_ObjectnessBING_ObjectnessBING(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_ObjectnessBING_ObjectnessBING(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::saliency::ObjectnessBING::opencv_from_extern(ret) };
@@ -439,7 +464,7 @@ impl ObjectnessBING {
 	#[inline]
 	pub fn create() -> Result<core::Ptr<crate::saliency::ObjectnessBING>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_ObjectnessBING_create(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_ObjectnessBING_create(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::saliency::ObjectnessBING>::opencv_from_extern(ret) };
@@ -465,10 +490,11 @@ pub trait Saliency: core::AlgorithmTrait + crate::saliency::SaliencyConst {
 	/// \return true if the saliency map is computed, false otherwise
 	#[inline]
 	fn compute_saliency(&mut self, image: &dyn core::ToInputArray, saliency_map: &mut dyn core::ToOutputArray) -> Result<bool> {
+		crate::core::validity::check("Saliency", self.as_raw_mut_Saliency())?;
 		input_array_arg!(image);
 		output_array_arg!(saliency_map);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_Saliency_computeSaliency_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_Saliency(), image.as_raw__InputArray(), saliency_map.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_Saliency_computeSaliency_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_Saliency(), image.as_raw__InputArray(), saliency_map.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -502,10 +528,11 @@ pub trait StaticSaliency: crate::saliency::Saliency + crate::saliency::StaticSal
 	/// * _binaryMap: the binary map
 	#[inline]
 	fn compute_binary_map(&mut self, _saliency_map: &dyn core::ToInputArray, _binary_map: &mut dyn core::ToOutputArray) -> Result<bool> {
+		crate::core::validity::check("StaticSaliency", self.as_raw_mut_StaticSaliency())?;
 		input_array_arg!(_saliency_map);
 		output_array_arg!(_binary_map);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_StaticSaliency_computeBinaryMap_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_StaticSaliency(), _saliency_map.as_raw__InputArray(), _binary_map.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_StaticSaliency_computeBinaryMap_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_StaticSaliency(), _saliency_map.as_raw__InputArray(), _binary_map.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -527,10 +554,11 @@ pub trait StaticSaliencyFineGrainedTrait: crate::saliency::StaticSaliency + crat
 
 	#[inline]
 	fn compute_saliency(&mut self, image: &dyn core::ToInputArray, saliency_map: &mut dyn core::ToOutputArray) -> Result<bool> {
+		crate::core::validity::check("StaticSaliencyFineGrained", self.as_raw_mut_StaticSaliencyFineGrained())?;
 		input_array_arg!(image);
 		output_array_arg!(saliency_map);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_StaticSaliencyFineGrained_computeSaliency_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_StaticSaliencyFineGrained(), image.as_raw__InputArray(), saliency_map.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_StaticSaliencyFineGrained_computeSaliency_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_StaticSaliencyFineGrained(), image.as_raw__InputArray(), saliency_map.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -551,7 +579,10 @@ opencv_type_boxed! { StaticSaliencyFineGrained }
 impl Drop for StaticSaliencyFineGrained {
 	fn drop(&mut self) {
 		extern "C" { fn cv_StaticSaliencyFineGrained_delete(instance: *mut c_void); }
-		unsafe { cv_StaticSaliencyFineGrained_delete(self.as_raw_mut_StaticSaliencyFineGrained()) };
+		let ptr = self.as_raw_mut_StaticSaliencyFineGrained();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_StaticSaliencyFineGrained_delete(ptr) };
 	}
 }
 
@@ -593,7 +624,7 @@ impl StaticSaliencyFineGrained {
 	#[inline]
 	pub fn default() -> Result<crate::saliency::StaticSaliencyFineGrained> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_StaticSaliencyFineGrained_StaticSaliencyFineGrained(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_StaticSaliencyFineGrained_StaticSaliencyFineGrained(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::saliency::StaticSaliencyFineGrained::opencv_from_extern(ret) };
@@ -603,7 +634,7 @@ impl StaticSaliencyFineGrained {
 	#[inline]
 	pub fn create() -> Result<core::Ptr<crate::saliency::StaticSaliencyFineGrained>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_StaticSaliencyFineGrained_create(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_StaticSaliencyFineGrained_create(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::saliency::StaticSaliencyFineGrained>::opencv_from_extern(ret) };
@@ -625,8 +656,9 @@ pub trait StaticSaliencySpectralResidualTraitConst: crate::saliency::StaticSalie
 
 	#[inline]
 	fn write(&self, fs: &mut core::FileStorage) -> Result<()> {
+		crate::core::validity::check("StaticSaliencySpectralResidual", self.as_raw_StaticSaliencySpectralResidual())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_StaticSaliencySpectralResidual_write_const_FileStorageR(self.as_raw_StaticSaliencySpectralResidual(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_StaticSaliencySpectralResidual_write_const_FileStorageR(self.as_raw_StaticSaliencySpectralResidual(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -634,8 +666,9 @@ pub trait StaticSaliencySpectralResidualTraitConst: crate::saliency::StaticSalie
 	
 	#[inline]
 	fn get_image_width(&self) -> Result<i32> {
+		crate::core::validity::check("StaticSaliencySpectralResidual", self.as_raw_StaticSaliencySpectralResidual())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_StaticSaliencySpectralResidual_getImageWidth_const(self.as_raw_StaticSaliencySpectralResidual(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_StaticSaliencySpectralResidual_getImageWidth_const(self.as_raw_StaticSaliencySpectralResidual(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -643,8 +676,9 @@ pub trait StaticSaliencySpectralResidualTraitConst: crate::saliency::StaticSalie
 	
 	#[inline]
 	fn get_image_height(&self) -> Result<i32> {
+		crate::core::validity::check("StaticSaliencySpectralResidual", self.as_raw_StaticSaliencySpectralResidual())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_StaticSaliencySpectralResidual_getImageHeight_const(self.as_raw_StaticSaliencySpectralResidual(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_StaticSaliencySpectralResidual_getImageHeight_const(self.as_raw_StaticSaliencySpectralResidual(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -657,10 +691,11 @@ pub trait StaticSaliencySpectralResidualTrait: crate::saliency::StaticSaliency +
 
 	#[inline]
 	fn compute_saliency(&mut self, image: &dyn core::ToInputArray, saliency_map: &mut dyn core::ToOutputArray) -> Result<bool> {
+		crate::core::validity::check("StaticSaliencySpectralResidual", self.as_raw_mut_StaticSaliencySpectralResidual())?;
 		input_array_arg!(image);
 		output_array_arg!(saliency_map);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_StaticSaliencySpectralResidual_computeSaliency_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_StaticSaliencySpectralResidual(), image.as_raw__InputArray(), saliency_map.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_StaticSaliencySpectralResidual_computeSaliency_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_StaticSaliencySpectralResidual(), image.as_raw__InputArray(), saliency_map.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -668,8 +703,9 @@ pub trait StaticSaliencySpectralResidualTrait: crate::saliency::StaticSaliency +
 	
 	#[inline]
 	fn read(&mut self, fn_: &core::FileNode) -> Result<()> {
+		crate::core::validity::check("StaticSaliencySpectralResidual", self.as_raw_mut_StaticSaliencySpectralResidual())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_StaticSaliencySpectralResidual_read_const_FileNodeR(self.as_raw_mut_StaticSaliencySpectralResidual(), fn_.as_raw_FileNode(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_StaticSaliencySpectralResidual_read_const_FileNodeR(self.as_raw_mut_StaticSaliencySpectralResidual(), fn_.as_raw_FileNode(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -677,8 +713,9 @@ pub trait StaticSaliencySpectralResidualTrait: crate::saliency::StaticSaliency +
 	
 	#[inline]
 	fn set_image_width(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("StaticSaliencySpectralResidual", self.as_raw_mut_StaticSaliencySpectralResidual())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_StaticSaliencySpectralResidual_setImageWidth_int(self.as_raw_mut_StaticSaliencySpectralResidual(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_StaticSaliencySpectralResidual_setImageWidth_int(self.as_raw_mut_StaticSaliencySpectralResidual(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -686,8 +723,9 @@ pub trait StaticSaliencySpectralResidualTrait: crate::saliency::StaticSaliency +
 	
 	#[inline]
 	fn set_image_height(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("StaticSaliencySpectralResidual", self.as_raw_mut_StaticSaliencySpectralResidual())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_StaticSaliencySpectralResidual_setImageHeight_int(self.as_raw_mut_StaticSaliencySpectralResidual(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_StaticSaliencySpectralResidual_setImageHeight_int(self.as_raw_mut_StaticSaliencySpectralResidual(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -710,7 +748,10 @@ opencv_type_boxed! { StaticSaliencySpectralResidual }
 impl Drop for StaticSaliencySpectralResidual {
 	fn drop(&mut self) {
 		extern "C" { fn cv_StaticSaliencySpectralResidual_delete(instance: *mut c_void); }
-		unsafe { cv_StaticSaliencySpectralResidual_delete(self.as_raw_mut_StaticSaliencySpectralResidual()) };
+		let ptr = self.as_raw_mut_StaticSaliencySpectralResidual();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_StaticSaliencySpectralResidual_delete(ptr) };
 	}
 }
 
@@ -752,7 +793,7 @@ impl StaticSaliencySpectralResidual {
 	#[inline]
 	pub fn default() -> Result<crate::saliency::StaticSaliencySpectralResidual> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_StaticSaliencySpectralResidual_StaticSaliencySpectralResidual(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_StaticSaliencySpectralResidual_StaticSaliencySpectralResidual(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::saliency::StaticSaliencySpectralResidual::opencv_from_extern(ret) };
@@ -762,7 +803,7 @@ impl StaticSaliencySpectralResidual {
 	#[inline]
 	pub fn create() -> Result<core::Ptr<crate::saliency::StaticSaliencySpectralResidual>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_saliency_StaticSaliencySpectralResidual_create(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_saliency_StaticSaliencySpectralResidual_create(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::saliency::StaticSaliencySpectralResidual>::opencv_from_extern(ret) };
@@ -38,7 +38,7 @@
 //! Note:
 //!   - Notice that it is compiled only when Eigen, GLog and GFlags are correctly installed.
 //! 
-//!    Check installation instructions in the following tutorial: @ref tutorial_sfm_installation
+//!    Check installation instructions in the following tutorial: tutorial_sfm_installation
 //!    # Conditioning
 //!    # Fundamental
 //!    # Input/Output
@@ -52,7 +52,7 @@
 //! Note:
 //!        - Notice that it is compiled only when Ceres Solver is correctly installed.
 //! 
-//!           Check installation instructions in the following tutorial: @ref tutorial_sfm_installation
+//!           Check installation instructions in the following tutorial: tutorial_sfm_installation
 //! 
 //! 
 //!    # Simple Pipeline
@@ -60,7 +60,7 @@
 //! Note:
 //!           - Notice that it is compiled only when Ceres Solver is correctly installed.
 //! 
-//!            Check installation instructions in the following tutorial: @ref tutorial_sfm_installation
+//!            Check installation instructions in the following tutorial: tutorial_sfm_installation
 use crate::{mod_prelude::*, core, sys, types};
 pub mod prelude {
 	pub use { super::BaseSFMConst, super::BaseSFM, super::SFMLibmvEuclideanReconstructionConst, super::SFMLibmvEuclideanReconstruction };
@@ -92,7 +92,7 @@ pub fn k_rt_from_projection(p: &dyn core::ToInputArray, k: &mut dyn core::ToOutp
 	output_array_arg!(r);
 	output_array_arg!(t);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sfm_KRtFromProjection_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(p.as_raw__InputArray(), k.as_raw__OutputArray(), r.as_raw__OutputArray(), t.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sfm_KRtFromProjection_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(p.as_raw__InputArray(), k.as_raw__OutputArray(), r.as_raw__OutputArray(), t.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -109,7 +109,7 @@ pub fn apply_transformation_to_points(points: &dyn core::ToInputArray, t: &dyn c
 	input_array_arg!(t);
 	output_array_arg!(transformed_points);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sfm_applyTransformationToPoints_const__InputArrayR_const__InputArrayR_const__OutputArrayR(points.as_raw__InputArray(), t.as_raw__InputArray(), transformed_points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sfm_applyTransformationToPoints_const__InputArrayR_const__InputArrayR_const__OutputArrayR(points.as_raw__InputArray(), t.as_raw__InputArray(), transformed_points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -132,7 +132,7 @@ pub fn compute_orientation(x1: &dyn core::ToInputArray, x2: &dyn core::ToInputAr
 	output_array_arg!(r);
 	output_array_arg!(t);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sfm_computeOrientation_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_double(x1.as_raw__InputArray(), x2.as_raw__InputArray(), r.as_raw__OutputArray(), t.as_raw__OutputArray(), s, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sfm_computeOrientation_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_double(x1.as_raw__InputArray(), x2.as_raw__InputArray(), r.as_raw__OutputArray(), t.as_raw__OutputArray(), s, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -149,7 +149,7 @@ pub fn depth(r: &dyn core::ToInputArray, t: &dyn core::ToInputArray, x: &dyn cor
 	input_array_arg!(t);
 	input_array_arg!(x);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sfm_depth_const__InputArrayR_const__InputArrayR_const__InputArrayR(r.as_raw__InputArray(), t.as_raw__InputArray(), x.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sfm_depth_const__InputArrayR_const__InputArrayR_const__InputArrayR(r.as_raw__InputArray(), t.as_raw__InputArray(), x.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -170,7 +170,7 @@ pub fn essential_from_fundamental(f: &dyn core::ToInputArray, k1: &dyn core::ToI
 	input_array_arg!(k2);
 	output_array_arg!(e);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sfm_essentialFromFundamental_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR(f.as_raw__InputArray(), k1.as_raw__InputArray(), k2.as_raw__InputArray(), e.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sfm_essentialFromFundamental_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR(f.as_raw__InputArray(), k1.as_raw__InputArray(), k2.as_raw__InputArray(), e.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -193,7 +193,7 @@ pub fn essential_from_rt(r1: &dyn core::ToInputArray, t1: &dyn core::ToInputArra
 	input_array_arg!(t2);
 	output_array_arg!(e);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sfm_essentialFromRt_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR(r1.as_raw__InputArray(), t1.as_raw__InputArray(), r2.as_raw__InputArray(), t2.as_raw__InputArray(), e.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sfm_essentialFromRt_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR(r1.as_raw__InputArray(), t1.as_raw__InputArray(), r2.as_raw__InputArray(), t2.as_raw__InputArray(), e.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -208,7 +208,7 @@ pub fn euclidean_to_homogeneous(src: &dyn core::ToInputArray, dst: &mut dyn core
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sfm_euclideanToHomogeneous_const__InputArrayR_const__OutputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sfm_euclideanToHomogeneous_const__InputArrayR_const__OutputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -221,7 +221,7 @@ pub fn euclidean_to_homogeneous(src: &dyn core::ToInputArray, dst: &mut dyn core
 /// * max_error: maximum error (in pixels).
 /// * F: Output 3x3 fundamental matrix such that ![inline formula](https://latex.codecogs.com/png.latex?x%5F2%5ET%20F%20x%5F1%3D0).
 /// * inliers: Output 1xN vector that contains the indexes of the detected inliers.
-/// * outliers_probability: outliers probability (in ]0,1[).
+/// * outliers_probability: outliers probability (in \]0,1\[).
 ///          The number of iterations is controlled using the following equation:
 ///          ![inline formula](https://latex.codecogs.com/png.latex?k%20%3D%20%5Cfrac%7Blog%281%2Dp%29%7D%7Blog%281%2E0%20%2D%20w%5En%20%29%7D) where ![inline formula](https://latex.codecogs.com/png.latex?k), ![inline formula](https://latex.codecogs.com/png.latex?w) and ![inline formula](https://latex.codecogs.com/png.latex?n) are the number of
 ///          iterations, the inliers ratio and minimun number of selected independent samples.
@@ -238,12 +238,44 @@ pub fn fundamental_from_correspondences7_point_robust(x1: &dyn core::ToInputArra
 	output_array_arg!(f);
 	output_array_arg!(inliers);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sfm_fundamentalFromCorrespondences7PointRobust_const__InputArrayR_const__InputArrayR_double_const__OutputArrayR_const__OutputArrayR_double(x1.as_raw__InputArray(), x2.as_raw__InputArray(), max_error, f.as_raw__OutputArray(), inliers.as_raw__OutputArray(), outliers_probability, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sfm_fundamentalFromCorrespondences7PointRobust_const__InputArrayR_const__InputArrayR_double_const__OutputArrayR_const__OutputArrayR_double(x1.as_raw__InputArray(), x2.as_raw__InputArray(), max_error, f.as_raw__OutputArray(), inliers.as_raw__OutputArray(), outliers_probability, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `fundamental_from_correspondences7_point_robust()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FundamentalFromCorrespondences7PointRobustBuilder<'a> {
+	x1: &'a dyn core::ToInputArray,
+	x2: &'a dyn core::ToInputArray,
+	max_error: f64,
+	f: &'a mut dyn core::ToOutputArray,
+	inliers: &'a mut dyn core::ToOutputArray,
+	outliers_probability: f64,
+}
+
+impl<'a> FundamentalFromCorrespondences7PointRobustBuilder<'a> {
+	#[inline]
+	pub fn new(x1: &'a dyn core::ToInputArray, x2: &'a dyn core::ToInputArray, max_error: f64, f: &'a mut dyn core::ToOutputArray, inliers: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { x1, x2, max_error, f, inliers, outliers_probability: 1e-2 }
+	}
+	
+	/// C++ default: `1e-2`
+	#[inline]
+	pub fn with_outliers_probability(mut self, outliers_probability: f64) -> Self {
+		self.outliers_probability = outliers_probability;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<f64> {
+		fundamental_from_correspondences7_point_robust(self.x1, self.x2, self.max_error, self.f, self.inliers, self.outliers_probability)
+	}
+}
+
 /// Estimate robustly the fundamental matrix between two dataset of 2D point (image coords space).
 /// ## Parameters
 /// * x1: Input 2xN Array of 2D points in view 1.
@@ -251,7 +283,7 @@ pub fn fundamental_from_correspondences7_point_robust(x1: &dyn core::ToInputArra
 /// * max_error: maximum error (in pixels).
 /// * F: Output 3x3 fundamental matrix such that ![inline formula](https://latex.codecogs.com/png.latex?x%5F2%5ET%20F%20x%5F1%3D0).
 /// * inliers: Output 1xN vector that contains the indexes of the detected inliers.
-/// * outliers_probability: outliers probability (in ]0,1[).
+/// * outliers_probability: outliers probability (in \]0,1\[).
 ///          The number of iterations is controlled using the following equation:
 ///          ![inline formula](https://latex.codecogs.com/png.latex?k%20%3D%20%5Cfrac%7Blog%281%2Dp%29%7D%7Blog%281%2E0%20%2D%20w%5En%20%29%7D) where ![inline formula](https://latex.codecogs.com/png.latex?k), ![inline formula](https://latex.codecogs.com/png.latex?w) and ![inline formula](https://latex.codecogs.com/png.latex?n) are the number of
 ///          iterations, the inliers ratio and minimun number of selected independent samples.
@@ -268,12 +300,44 @@ pub fn fundamental_from_correspondences8_point_robust(x1: &dyn core::ToInputArra
 	output_array_arg!(f);
 	output_array_arg!(inliers);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sfm_fundamentalFromCorrespondences8PointRobust_const__InputArrayR_const__InputArrayR_double_const__OutputArrayR_const__OutputArrayR_double(x1.as_raw__InputArray(), x2.as_raw__InputArray(), max_error, f.as_raw__OutputArray(), inliers.as_raw__OutputArray(), outliers_probability, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sfm_fundamentalFromCorrespondences8PointRobust_const__InputArrayR_const__InputArrayR_double_const__OutputArrayR_const__OutputArrayR_double(x1.as_raw__InputArray(), x2.as_raw__InputArray(), max_error, f.as_raw__OutputArray(), inliers.as_raw__OutputArray(), outliers_probability, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `fundamental_from_correspondences8_point_robust()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FundamentalFromCorrespondences8PointRobustBuilder<'a> {
+	x1: &'a dyn core::ToInputArray,
+	x2: &'a dyn core::ToInputArray,
+	max_error: f64,
+	f: &'a mut dyn core::ToOutputArray,
+	inliers: &'a mut dyn core::ToOutputArray,
+	outliers_probability: f64,
+}
+
+impl<'a> FundamentalFromCorrespondences8PointRobustBuilder<'a> {
+	#[inline]
+	pub fn new(x1: &'a dyn core::ToInputArray, x2: &'a dyn core::ToInputArray, max_error: f64, f: &'a mut dyn core::ToOutputArray, inliers: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { x1, x2, max_error, f, inliers, outliers_probability: 1e-2 }
+	}
+	
+	/// C++ default: `1e-2`
+	#[inline]
+	pub fn with_outliers_probability(mut self, outliers_probability: f64) -> Self {
+		self.outliers_probability = outliers_probability;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<f64> {
+		fundamental_from_correspondences8_point_robust(self.x1, self.x2, self.max_error, self.f, self.inliers, self.outliers_probability)
+	}
+}
+
 /// Get Essential matrix from Fundamental and Camera matrices.
 /// ## Parameters
 /// * E: Input 3x3 essential matrix.
@@ -289,7 +353,7 @@ pub fn fundamental_from_essential(e: &dyn core::ToInputArray, k1: &dyn core::ToI
 	input_array_arg!(k2);
 	output_array_arg!(f);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sfm_fundamentalFromEssential_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR(e.as_raw__InputArray(), k1.as_raw__InputArray(), k2.as_raw__InputArray(), f.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sfm_fundamentalFromEssential_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR(e.as_raw__InputArray(), k1.as_raw__InputArray(), k2.as_raw__InputArray(), f.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -306,7 +370,7 @@ pub fn fundamental_from_projections(p1: &dyn core::ToInputArray, p2: &dyn core::
 	input_array_arg!(p2);
 	output_array_arg!(f);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sfm_fundamentalFromProjections_const__InputArrayR_const__InputArrayR_const__OutputArrayR(p1.as_raw__InputArray(), p2.as_raw__InputArray(), f.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sfm_fundamentalFromProjections_const__InputArrayR_const__InputArrayR_const__OutputArrayR(p1.as_raw__InputArray(), p2.as_raw__InputArray(), f.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -321,7 +385,7 @@ pub fn homogeneous_to_euclidean(src: &dyn core::ToInputArray, dst: &mut dyn core
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sfm_homogeneousToEuclidean_const__InputArrayR_const__OutputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sfm_homogeneousToEuclidean_const__InputArrayR_const__OutputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -348,7 +412,7 @@ pub fn import_reconstruction(file: &str, rs: &mut dyn core::ToOutputArray, ts: &
 	output_array_arg!(ks);
 	output_array_arg!(points3d);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sfm_importReconstruction_const_StringR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int(file.opencv_as_extern(), rs.as_raw__OutputArray(), ts.as_raw__OutputArray(), ks.as_raw__OutputArray(), points3d.as_raw__OutputArray(), file_format, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sfm_importReconstruction_const_StringR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int(file.opencv_as_extern(), rs.as_raw__OutputArray(), ts.as_raw__OutputArray(), ks.as_raw__OutputArray(), points3d.as_raw__OutputArray(), file_format, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -368,7 +432,7 @@ pub fn isotropic_preconditioner_from_points(points: &dyn core::ToInputArray, t:
 	input_array_arg!(points);
 	output_array_arg!(t);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sfm_isotropicPreconditionerFromPoints_const__InputArrayR_const__OutputArrayR(points.as_raw__InputArray(), t.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sfm_isotropicPreconditionerFromPoints_const__InputArrayR_const__OutputArrayR(points.as_raw__InputArray(), t.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -380,14 +444,14 @@ pub fn isotropic_preconditioner_from_points(points: &dyn core::ToInputArray, t:
 /// * mean: Output Nx1 matrix with computed mean.
 /// * variance: Output Nx1 matrix with computed variance.
 /// 
-/// It computes in the same way as woud do @ref reduce but with \a Variance function.
+/// It computes in the same way as woud do [reduce](crate::core::reduce) but with \a Variance function.
 #[inline]
 pub fn mean_and_variance_along_rows(a: &dyn core::ToInputArray, mean: &mut dyn core::ToOutputArray, variance: &mut dyn core::ToOutputArray) -> Result<()> {
 	input_array_arg!(a);
 	output_array_arg!(mean);
 	output_array_arg!(variance);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sfm_meanAndVarianceAlongRows_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(a.as_raw__InputArray(), mean.as_raw__OutputArray(), variance.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sfm_meanAndVarianceAlongRows_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(a.as_raw__InputArray(), mean.as_raw__OutputArray(), variance.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -415,7 +479,7 @@ pub fn motion_from_essential_choose_solution(rs: &dyn core::ToInputArray, ts: &d
 	input_array_arg!(k2);
 	input_array_arg!(x2);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sfm_motionFromEssentialChooseSolution_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR(rs.as_raw__InputArray(), ts.as_raw__InputArray(), k1.as_raw__InputArray(), x1.as_raw__InputArray(), k2.as_raw__InputArray(), x2.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sfm_motionFromEssentialChooseSolution_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR(rs.as_raw__InputArray(), ts.as_raw__InputArray(), k1.as_raw__InputArray(), x1.as_raw__InputArray(), k2.as_raw__InputArray(), x2.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -434,7 +498,7 @@ pub fn motion_from_essential(e: &dyn core::ToInputArray, rs: &mut dyn core::ToOu
 	output_array_arg!(rs);
 	output_array_arg!(ts);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sfm_motionFromEssential_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(e.as_raw__InputArray(), rs.as_raw__OutputArray(), ts.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sfm_motionFromEssential_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(e.as_raw__InputArray(), rs.as_raw__OutputArray(), ts.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -451,7 +515,7 @@ pub fn normalize_fundamental(f: &dyn core::ToInputArray, f_normalized: &mut dyn
 	input_array_arg!(f);
 	output_array_arg!(f_normalized);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sfm_normalizeFundamental_const__InputArrayR_const__OutputArrayR(f.as_raw__InputArray(), f_normalized.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sfm_normalizeFundamental_const__InputArrayR_const__OutputArrayR(f.as_raw__InputArray(), f_normalized.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -463,7 +527,7 @@ pub fn normalize_fundamental(f: &dyn core::ToInputArray, f_normalized: &mut dyn
 /// * normalized_points: Output vector of the same N-dimensional points but with mean 0 and average norm ![inline formula](https://latex.codecogs.com/png.latex?%5Csqrt%7B2%7D).
 /// * T: Output 3x3 transform matrix such that ![inline formula](https://latex.codecogs.com/png.latex?x%20%3D%20T%2AX), where ![inline formula](https://latex.codecogs.com/png.latex?X) are the points to normalize and ![inline formula](https://latex.codecogs.com/png.latex?x) the normalized points.
 /// 
-/// Internally calls @ref preconditionerFromPoints in order to get the scaling matrix before applying @ref applyTransformationToPoints.
+/// Internally calls [preconditioner_from_points](crate::sfm::preconditioner_from_points) in order to get the scaling matrix before applying [apply_transformation_to_points](crate::sfm::apply_transformation_to_points).
 /// This operation is an essential step before applying the DLT algorithm in order to consider the result as optimal.
 /// 
 /// Reference: [HartleyZ00](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_HartleyZ00) 4.4.4 pag.107.
@@ -473,7 +537,7 @@ pub fn normalize_isotropic_points(points: &dyn core::ToInputArray, normalized_po
 	output_array_arg!(normalized_points);
 	output_array_arg!(t);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sfm_normalizeIsotropicPoints_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(points.as_raw__InputArray(), normalized_points.as_raw__OutputArray(), t.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sfm_normalizeIsotropicPoints_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(points.as_raw__InputArray(), normalized_points.as_raw__OutputArray(), t.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -485,7 +549,7 @@ pub fn normalize_isotropic_points(points: &dyn core::ToInputArray, normalized_po
 /// * normalized_points: Output vector of the same N-dimensional points but with mean 0 and average norm ![inline formula](https://latex.codecogs.com/png.latex?%5Csqrt%7B2%7D).
 /// * T: Output 3x3 transform matrix such that ![inline formula](https://latex.codecogs.com/png.latex?x%20%3D%20T%2AX), where ![inline formula](https://latex.codecogs.com/png.latex?X) are the points to normalize and ![inline formula](https://latex.codecogs.com/png.latex?x) the normalized points.
 /// 
-/// Internally calls @ref preconditionerFromPoints in order to get the scaling matrix before applying @ref applyTransformationToPoints.
+/// Internally calls [preconditioner_from_points](crate::sfm::preconditioner_from_points) in order to get the scaling matrix before applying [apply_transformation_to_points](crate::sfm::apply_transformation_to_points).
 /// This operation is an essential step before applying the DLT algorithm in order to consider the result as optimal.
 /// 
 /// Reference: [HartleyZ00](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_HartleyZ00) 4.4.4 pag.109
@@ -495,7 +559,7 @@ pub fn normalize_points(points: &dyn core::ToInputArray, normalized_points: &mut
 	output_array_arg!(normalized_points);
 	output_array_arg!(t);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sfm_normalizePoints_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(points.as_raw__InputArray(), normalized_points.as_raw__OutputArray(), t.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sfm_normalizePoints_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(points.as_raw__InputArray(), normalized_points.as_raw__OutputArray(), t.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -515,7 +579,7 @@ pub fn normalized_eight_point_solver(x1: &dyn core::ToInputArray, x2: &dyn core:
 	input_array_arg!(x2);
 	output_array_arg!(f);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sfm_normalizedEightPointSolver_const__InputArrayR_const__InputArrayR_const__OutputArrayR(x1.as_raw__InputArray(), x2.as_raw__InputArray(), f.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sfm_normalizedEightPointSolver_const__InputArrayR_const__InputArrayR_const__OutputArrayR(x1.as_raw__InputArray(), x2.as_raw__InputArray(), f.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -535,7 +599,7 @@ pub fn preconditioner_from_points(points: &dyn core::ToInputArray, t: &mut dyn c
 	input_array_arg!(points);
 	output_array_arg!(t);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sfm_preconditionerFromPoints_const__InputArrayR_const__OutputArrayR(points.as_raw__InputArray(), t.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sfm_preconditionerFromPoints_const__InputArrayR_const__OutputArrayR(points.as_raw__InputArray(), t.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -556,7 +620,7 @@ pub fn projection_from_k_rt(k: &dyn core::ToInputArray, r: &dyn core::ToInputArr
 	input_array_arg!(t);
 	output_array_arg!(p);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sfm_projectionFromKRt_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR(k.as_raw__InputArray(), r.as_raw__InputArray(), t.as_raw__InputArray(), p.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sfm_projectionFromKRt_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR(k.as_raw__InputArray(), r.as_raw__InputArray(), t.as_raw__InputArray(), p.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -573,7 +637,7 @@ pub fn projections_from_fundamental(f: &dyn core::ToInputArray, p1: &mut dyn cor
 	output_array_arg!(p1);
 	output_array_arg!(p2);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sfm_projectionsFromFundamental_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(f.as_raw__InputArray(), p1.as_raw__OutputArray(), p2.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sfm_projectionsFromFundamental_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(f.as_raw__InputArray(), p1.as_raw__OutputArray(), p2.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -595,6 +659,27 @@ pub fn projections_from_fundamental(f: &dyn core::ToInputArray, p1: &mut dyn cor
 /// 
 /// ## C++ default parameters
 /// * is_projective: false
+/// 
+/// ## Example
+/// ```no_run
+/// use opencv::{core::{self, Mat}, prelude::*, sfm};
+/// 
+/// // one 2xN matrix of the tracked points per image, the columns must correspond between images
+/// let mut points2d = core::Vector::<Mat>::new();
+/// for image in 0..3 {
+/// 	let shift = image as f64 * 5.;
+/// 	points2d.push(Mat::from_slice_2d(&[
+/// 		[10. + shift, 120. + shift, 30. + shift, 200. + shift, 75. + shift, 160. + shift, 40. + shift, 90. + shift],
+/// 		[20., 40., 150., 90., 60., 170., 110., 30.],
+/// 	])?);
+/// }
+/// let mut k = Mat::from_slice_2d(&[[350f64, 0., 240.], [0., 350., 360.], [0., 0., 1.]])?;
+/// 
+/// let mut ps = core::Vector::<Mat>::new();
+/// let mut points3d = core::Vector::<Mat>::new();
+/// sfm::reconstruct(&points2d, &mut ps, &mut points3d, &mut k, true)?;
+/// # Ok::<(), opencv::Error>(())
+/// ```
 #[inline]
 pub fn reconstruct(points2d: &dyn core::ToInputArray, ps: &mut dyn core::ToOutputArray, points3d: &mut dyn core::ToOutputArray, k: &mut dyn core::ToInputOutputArray, is_projective: bool) -> Result<()> {
 	input_array_arg!(points2d);
@@ -602,7 +687,7 @@ pub fn reconstruct(points2d: &dyn core::ToInputArray, ps: &mut dyn core::ToOutpu
 	output_array_arg!(points3d);
 	input_output_array_arg!(k);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sfm_reconstruct_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__InputOutputArrayR_bool(points2d.as_raw__InputArray(), ps.as_raw__OutputArray(), points3d.as_raw__OutputArray(), k.as_raw__InputOutputArray(), is_projective, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sfm_reconstruct_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__InputOutputArrayR_bool(points2d.as_raw__InputArray(), ps.as_raw__OutputArray(), points3d.as_raw__OutputArray(), k.as_raw__InputOutputArray(), is_projective, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -622,7 +707,7 @@ pub fn reconstruct(points2d: &dyn core::ToInputArray, ps: &mut dyn core::ToOutpu
 /// 
 /// Note:
 ///   - Tracks must be as precise as possible. It does not handle outliers and is very sensible to them.
-///   - To see a working example for camera motion reconstruction, check the following tutorial: @ref tutorial_sfm_trajectory_estimation.
+///   - To see a working example for camera motion reconstruction, check the following tutorial: tutorial_sfm_trajectory_estimation.
 /// 
 /// ## C++ default parameters
 /// * is_projective: false
@@ -634,12 +719,44 @@ pub fn reconstruct_1(points2d: &dyn core::ToInputArray, rs: &mut dyn core::ToOut
 	input_output_array_arg!(k);
 	output_array_arg!(points3d);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sfm_reconstruct_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__InputOutputArrayR_const__OutputArrayR_bool(points2d.as_raw__InputArray(), rs.as_raw__OutputArray(), ts.as_raw__OutputArray(), k.as_raw__InputOutputArray(), points3d.as_raw__OutputArray(), is_projective, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sfm_reconstruct_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__InputOutputArrayR_const__OutputArrayR_bool(points2d.as_raw__InputArray(), rs.as_raw__OutputArray(), ts.as_raw__OutputArray(), k.as_raw__InputOutputArray(), points3d.as_raw__OutputArray(), is_projective, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `reconstruct_1()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct Reconstruct1Builder<'a> {
+	points2d: &'a dyn core::ToInputArray,
+	rs: &'a mut dyn core::ToOutputArray,
+	ts: &'a mut dyn core::ToOutputArray,
+	k: &'a mut dyn core::ToInputOutputArray,
+	points3d: &'a mut dyn core::ToOutputArray,
+	is_projective: bool,
+}
+
+impl<'a> Reconstruct1Builder<'a> {
+	#[inline]
+	pub fn new(points2d: &'a dyn core::ToInputArray, rs: &'a mut dyn core::ToOutputArray, ts: &'a mut dyn core::ToOutputArray, k: &'a mut dyn core::ToInputOutputArray, points3d: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { points2d, rs, ts, k, points3d, is_projective: false }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_is_projective(mut self, is_projective: bool) -> Self {
+		self.is_projective = is_projective;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		reconstruct_1(self.points2d, self.rs, self.ts, self.k, self.points3d, self.is_projective)
+	}
+}
+
 /// Reconstruct 3d points from 2d images while performing autocalibration.
 /// ## Parameters
 /// * images: a vector of string with the images paths.
@@ -663,7 +780,7 @@ pub fn reconstruct_2(images: core::Vector<String>, ps: &mut dyn core::ToOutputAr
 	output_array_arg!(points3d);
 	input_output_array_arg!(k);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sfm_reconstruct_const_vector_String__const__OutputArrayR_const__OutputArrayR_const__InputOutputArrayR_bool(images.as_raw_VectorOfString(), ps.as_raw__OutputArray(), points3d.as_raw__OutputArray(), k.as_raw__InputOutputArray(), is_projective, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sfm_reconstruct_const_vector_String__const__OutputArrayR_const__OutputArrayR_const__InputOutputArrayR_bool(images.as_raw_VectorOfString(), ps.as_raw__OutputArray(), points3d.as_raw__OutputArray(), k.as_raw__InputOutputArray(), is_projective, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -684,7 +801,7 @@ pub fn reconstruct_2(images: core::Vector<String>, ps: &mut dyn core::ToOutputAr
 /// Note:
 ///   - The images must be ordered as they were an image sequence. Additionally, each frame should be as close as posible to the previous and posterior.
 ///   - For now DAISY features are used in order to compute the 2d points tracks and it only works for 3-4 images.
-///   - To see a working example for scene reconstruction, check the following tutorial: @ref tutorial_sfm_scene_reconstruction.
+///   - To see a working example for scene reconstruction, check the following tutorial: tutorial_sfm_scene_reconstruction.
 /// 
 /// ## C++ default parameters
 /// * is_projective: false
@@ -695,12 +812,44 @@ pub fn reconstruct_3(images: core::Vector<String>, rs: &mut dyn core::ToOutputAr
 	input_output_array_arg!(k);
 	output_array_arg!(points3d);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sfm_reconstruct_const_vector_String__const__OutputArrayR_const__OutputArrayR_const__InputOutputArrayR_const__OutputArrayR_bool(images.as_raw_VectorOfString(), rs.as_raw__OutputArray(), ts.as_raw__OutputArray(), k.as_raw__InputOutputArray(), points3d.as_raw__OutputArray(), is_projective, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sfm_reconstruct_const_vector_String__const__OutputArrayR_const__OutputArrayR_const__InputOutputArrayR_const__OutputArrayR_bool(images.as_raw_VectorOfString(), rs.as_raw__OutputArray(), ts.as_raw__OutputArray(), k.as_raw__InputOutputArray(), points3d.as_raw__OutputArray(), is_projective, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `reconstruct_3()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct Reconstruct3Builder<'a> {
+	images: core::Vector<String>,
+	rs: &'a mut dyn core::ToOutputArray,
+	ts: &'a mut dyn core::ToOutputArray,
+	k: &'a mut dyn core::ToInputOutputArray,
+	points3d: &'a mut dyn core::ToOutputArray,
+	is_projective: bool,
+}
+
+impl<'a> Reconstruct3Builder<'a> {
+	#[inline]
+	pub fn new(images: core::Vector<String>, rs: &'a mut dyn core::ToOutputArray, ts: &'a mut dyn core::ToOutputArray, k: &'a mut dyn core::ToInputOutputArray, points3d: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { images, rs, ts, k, points3d, is_projective: false }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_is_projective(mut self, is_projective: bool) -> Self {
+		self.is_projective = is_projective;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		reconstruct_3(self.images, self.rs, self.ts, self.k, self.points3d, self.is_projective)
+	}
+}
+
 /// Computes the relative camera motion between two cameras.
 /// ## Parameters
 /// * R1: Input 3x3 first camera rotation matrix.
@@ -722,7 +871,7 @@ pub fn relative_camera_motion(r1: &dyn core::ToInputArray, t1: &dyn core::ToInpu
 	output_array_arg!(r);
 	output_array_arg!(t);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sfm_relativeCameraMotion_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(r1.as_raw__InputArray(), t1.as_raw__InputArray(), r2.as_raw__InputArray(), t2.as_raw__InputArray(), r.as_raw__OutputArray(), t.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sfm_relativeCameraMotion_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(r1.as_raw__InputArray(), t1.as_raw__InputArray(), r2.as_raw__InputArray(), t2.as_raw__InputArray(), r.as_raw__OutputArray(), t.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -737,7 +886,7 @@ pub fn relative_camera_motion(r1: &dyn core::ToInputArray, t1: &dyn core::ToInpu
 pub fn skew(x: &dyn core::ToInputArray) -> Result<core::Mat> {
 	input_array_arg!(x);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sfm_skew_const__InputArrayR(x.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sfm_skew_const__InputArrayR(x.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -758,7 +907,7 @@ pub fn triangulate_points(points2d: &dyn core::ToInputArray, projection_matrices
 	input_array_arg!(projection_matrices);
 	output_array_arg!(points3d);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_sfm_triangulatePoints_const__InputArrayR_const__InputArrayR_const__OutputArrayR(points2d.as_raw__InputArray(), projection_matrices.as_raw__InputArray(), points3d.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_sfm_triangulatePoints_const__InputArrayR_const__InputArrayR_const__OutputArrayR(points2d.as_raw__InputArray(), projection_matrices.as_raw__InputArray(), points3d.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -770,8 +919,9 @@ pub trait BaseSFMConst {
 
 	#[inline]
 	fn get_error(&self) -> Result<f64> {
+		crate::core::validity::check("BaseSFM", self.as_raw_BaseSFM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_sfm_BaseSFM_getError_const(self.as_raw_BaseSFM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_sfm_BaseSFM_getError_const(self.as_raw_BaseSFM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -779,8 +929,9 @@ pub trait BaseSFMConst {
 	
 	#[inline]
 	fn get_intrinsics(&self) -> Result<core::Mat> {
+		crate::core::validity::check("BaseSFM", self.as_raw_BaseSFM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_sfm_BaseSFM_getIntrinsics_const(self.as_raw_BaseSFM(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_sfm_BaseSFM_getIntrinsics_const(self.as_raw_BaseSFM(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -794,9 +945,10 @@ pub trait BaseSFM: crate::sfm::BaseSFMConst {
 
 	#[inline]
 	fn run(&mut self, points2d: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("BaseSFM", self.as_raw_mut_BaseSFM())?;
 		input_array_arg!(points2d);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_sfm_BaseSFM_run_const__InputArrayR(self.as_raw_mut_BaseSFM(), points2d.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_sfm_BaseSFM_run_const__InputArrayR(self.as_raw_mut_BaseSFM(), points2d.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -804,13 +956,14 @@ pub trait BaseSFM: crate::sfm::BaseSFMConst {
 	
 	#[inline]
 	fn run_1(&mut self, points2d: &dyn core::ToInputArray, k: &mut dyn core::ToInputOutputArray, rs: &mut dyn core::ToOutputArray, ts: &mut dyn core::ToOutputArray, points3d: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("BaseSFM", self.as_raw_mut_BaseSFM())?;
 		input_array_arg!(points2d);
 		input_output_array_arg!(k);
 		output_array_arg!(rs);
 		output_array_arg!(ts);
 		output_array_arg!(points3d);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_sfm_BaseSFM_run_const__InputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_BaseSFM(), points2d.as_raw__InputArray(), k.as_raw__InputOutputArray(), rs.as_raw__OutputArray(), ts.as_raw__OutputArray(), points3d.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_sfm_BaseSFM_run_const__InputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_BaseSFM(), points2d.as_raw__InputArray(), k.as_raw__InputOutputArray(), rs.as_raw__OutputArray(), ts.as_raw__OutputArray(), points3d.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -818,8 +971,9 @@ pub trait BaseSFM: crate::sfm::BaseSFMConst {
 	
 	#[inline]
 	fn run_2(&mut self, images: &core::Vector<String>) -> Result<()> {
+		crate::core::validity::check("BaseSFM", self.as_raw_mut_BaseSFM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_sfm_BaseSFM_run_const_vector_String_R(self.as_raw_mut_BaseSFM(), images.as_raw_VectorOfString(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_sfm_BaseSFM_run_const_vector_String_R(self.as_raw_mut_BaseSFM(), images.as_raw_VectorOfString(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -827,12 +981,13 @@ pub trait BaseSFM: crate::sfm::BaseSFMConst {
 	
 	#[inline]
 	fn run_3(&mut self, images: &core::Vector<String>, k: &mut dyn core::ToInputOutputArray, rs: &mut dyn core::ToOutputArray, ts: &mut dyn core::ToOutputArray, points3d: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("BaseSFM", self.as_raw_mut_BaseSFM())?;
 		input_output_array_arg!(k);
 		output_array_arg!(rs);
 		output_array_arg!(ts);
 		output_array_arg!(points3d);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_sfm_BaseSFM_run_const_vector_String_R_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_BaseSFM(), images.as_raw_VectorOfString(), k.as_raw__InputOutputArray(), rs.as_raw__OutputArray(), ts.as_raw__OutputArray(), points3d.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_sfm_BaseSFM_run_const_vector_String_R_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_BaseSFM(), images.as_raw_VectorOfString(), k.as_raw__InputOutputArray(), rs.as_raw__OutputArray(), ts.as_raw__OutputArray(), points3d.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -840,9 +995,10 @@ pub trait BaseSFM: crate::sfm::BaseSFMConst {
 	
 	#[inline]
 	fn get_points(&mut self, points3d: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("BaseSFM", self.as_raw_mut_BaseSFM())?;
 		output_array_arg!(points3d);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_sfm_BaseSFM_getPoints_const__OutputArrayR(self.as_raw_mut_BaseSFM(), points3d.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_sfm_BaseSFM_getPoints_const__OutputArrayR(self.as_raw_mut_BaseSFM(), points3d.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -850,10 +1006,11 @@ pub trait BaseSFM: crate::sfm::BaseSFMConst {
 	
 	#[inline]
 	fn get_cameras(&mut self, rs: &mut dyn core::ToOutputArray, ts: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("BaseSFM", self.as_raw_mut_BaseSFM())?;
 		output_array_arg!(rs);
 		output_array_arg!(ts);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_sfm_BaseSFM_getCameras_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_BaseSFM(), rs.as_raw__OutputArray(), ts.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_sfm_BaseSFM_getCameras_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_BaseSFM(), rs.as_raw__OutputArray(), ts.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -861,8 +1018,9 @@ pub trait BaseSFM: crate::sfm::BaseSFMConst {
 	
 	#[inline]
 	fn set_reconstruction_options(&mut self, libmv_reconstruction_options: crate::sfm::libmv_ReconstructionOptions) -> Result<()> {
+		crate::core::validity::check("BaseSFM", self.as_raw_mut_BaseSFM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_sfm_BaseSFM_setReconstructionOptions_const_libmv_ReconstructionOptionsR(self.as_raw_mut_BaseSFM(), &libmv_reconstruction_options, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_sfm_BaseSFM_setReconstructionOptions_const_libmv_ReconstructionOptionsR(self.as_raw_mut_BaseSFM(), &libmv_reconstruction_options, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -870,8 +1028,9 @@ pub trait BaseSFM: crate::sfm::BaseSFMConst {
 	
 	#[inline]
 	fn set_camera_intrinsic_options(&mut self, libmv_camera_intrinsics_options: crate::sfm::libmv_CameraIntrinsicsOptions) -> Result<()> {
+		crate::core::validity::check("BaseSFM", self.as_raw_mut_BaseSFM())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_sfm_BaseSFM_setCameraIntrinsicOptions_const_libmv_CameraIntrinsicsOptionsR(self.as_raw_mut_BaseSFM(), &libmv_camera_intrinsics_options, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_sfm_BaseSFM_setCameraIntrinsicOptions_const_libmv_CameraIntrinsicsOptionsR(self.as_raw_mut_BaseSFM(), &libmv_camera_intrinsics_options, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -886,8 +1045,9 @@ pub trait SFMLibmvEuclideanReconstructionConst: crate::sfm::BaseSFMConst {
 	/// Returns the computed reprojection error.
 	#[inline]
 	fn get_error(&self) -> Result<f64> {
+		crate::core::validity::check("SFMLibmvEuclideanReconstruction", self.as_raw_SFMLibmvEuclideanReconstruction())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_sfm_SFMLibmvEuclideanReconstruction_getError_const(self.as_raw_SFMLibmvEuclideanReconstruction(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_sfm_SFMLibmvEuclideanReconstruction_getError_const(self.as_raw_SFMLibmvEuclideanReconstruction(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -896,8 +1056,9 @@ pub trait SFMLibmvEuclideanReconstructionConst: crate::sfm::BaseSFMConst {
 	/// Returns the refined camera calibration matrix.
 	#[inline]
 	fn get_intrinsics(&self) -> Result<core::Mat> {
+		crate::core::validity::check("SFMLibmvEuclideanReconstruction", self.as_raw_SFMLibmvEuclideanReconstruction())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_sfm_SFMLibmvEuclideanReconstruction_getIntrinsics_const(self.as_raw_SFMLibmvEuclideanReconstruction(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_sfm_SFMLibmvEuclideanReconstruction_getIntrinsics_const(self.as_raw_SFMLibmvEuclideanReconstruction(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -918,9 +1079,10 @@ pub trait SFMLibmvEuclideanReconstruction: crate::sfm::BaseSFM + crate::sfm::SFM
 	///   - Tracks must be as precise as possible. It does not handle outliers and is very sensible to them.
 	#[inline]
 	fn run(&mut self, points2d: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("SFMLibmvEuclideanReconstruction", self.as_raw_mut_SFMLibmvEuclideanReconstruction())?;
 		input_array_arg!(points2d);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_sfm_SFMLibmvEuclideanReconstruction_run_const__InputArrayR(self.as_raw_mut_SFMLibmvEuclideanReconstruction(), points2d.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_sfm_SFMLibmvEuclideanReconstruction_run_const__InputArrayR(self.as_raw_mut_SFMLibmvEuclideanReconstruction(), points2d.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -939,13 +1101,14 @@ pub trait SFMLibmvEuclideanReconstruction: crate::sfm::BaseSFM + crate::sfm::SFM
 	///   - Tracks must be as precise as possible. It does not handle outliers and is very sensible to them.
 	#[inline]
 	fn run_1(&mut self, points2d: &dyn core::ToInputArray, k: &mut dyn core::ToInputOutputArray, rs: &mut dyn core::ToOutputArray, ts: &mut dyn core::ToOutputArray, points3d: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("SFMLibmvEuclideanReconstruction", self.as_raw_mut_SFMLibmvEuclideanReconstruction())?;
 		input_array_arg!(points2d);
 		input_output_array_arg!(k);
 		output_array_arg!(rs);
 		output_array_arg!(ts);
 		output_array_arg!(points3d);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_sfm_SFMLibmvEuclideanReconstruction_run_const__InputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_SFMLibmvEuclideanReconstruction(), points2d.as_raw__InputArray(), k.as_raw__InputOutputArray(), rs.as_raw__OutputArray(), ts.as_raw__OutputArray(), points3d.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_sfm_SFMLibmvEuclideanReconstruction_run_const__InputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_SFMLibmvEuclideanReconstruction(), points2d.as_raw__InputArray(), k.as_raw__InputOutputArray(), rs.as_raw__OutputArray(), ts.as_raw__OutputArray(), points3d.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -961,8 +1124,9 @@ pub trait SFMLibmvEuclideanReconstruction: crate::sfm::BaseSFM + crate::sfm::SFM
 	///   - For now DAISY features are used in order to compute the 2d points tracks and it only works for 3-4 images.
 	#[inline]
 	fn run_2(&mut self, images: &core::Vector<String>) -> Result<()> {
+		crate::core::validity::check("SFMLibmvEuclideanReconstruction", self.as_raw_mut_SFMLibmvEuclideanReconstruction())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_sfm_SFMLibmvEuclideanReconstruction_run_const_vector_String_R(self.as_raw_mut_SFMLibmvEuclideanReconstruction(), images.as_raw_VectorOfString(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_sfm_SFMLibmvEuclideanReconstruction_run_const_vector_String_R(self.as_raw_mut_SFMLibmvEuclideanReconstruction(), images.as_raw_VectorOfString(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -982,12 +1146,13 @@ pub trait SFMLibmvEuclideanReconstruction: crate::sfm::BaseSFM + crate::sfm::SFM
 	///   - For now DAISY features are used in order to compute the 2d points tracks and it only works for 3-4 images.
 	#[inline]
 	fn run_3(&mut self, images: &core::Vector<String>, k: &mut dyn core::ToInputOutputArray, rs: &mut dyn core::ToOutputArray, ts: &mut dyn core::ToOutputArray, points3d: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("SFMLibmvEuclideanReconstruction", self.as_raw_mut_SFMLibmvEuclideanReconstruction())?;
 		input_output_array_arg!(k);
 		output_array_arg!(rs);
 		output_array_arg!(ts);
 		output_array_arg!(points3d);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_sfm_SFMLibmvEuclideanReconstruction_run_const_vector_String_R_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_SFMLibmvEuclideanReconstruction(), images.as_raw_VectorOfString(), k.as_raw__InputOutputArray(), rs.as_raw__OutputArray(), ts.as_raw__OutputArray(), points3d.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_sfm_SFMLibmvEuclideanReconstruction_run_const_vector_String_R_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_SFMLibmvEuclideanReconstruction(), images.as_raw_VectorOfString(), k.as_raw__InputOutputArray(), rs.as_raw__OutputArray(), ts.as_raw__OutputArray(), points3d.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -998,9 +1163,10 @@ pub trait SFMLibmvEuclideanReconstruction: crate::sfm::BaseSFM + crate::sfm::SFM
 	/// * points3d: Output array with estimated 3d points.
 	#[inline]
 	fn get_points(&mut self, points3d: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("SFMLibmvEuclideanReconstruction", self.as_raw_mut_SFMLibmvEuclideanReconstruction())?;
 		output_array_arg!(points3d);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_sfm_SFMLibmvEuclideanReconstruction_getPoints_const__OutputArrayR(self.as_raw_mut_SFMLibmvEuclideanReconstruction(), points3d.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_sfm_SFMLibmvEuclideanReconstruction_getPoints_const__OutputArrayR(self.as_raw_mut_SFMLibmvEuclideanReconstruction(), points3d.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1012,10 +1178,11 @@ pub trait SFMLibmvEuclideanReconstruction: crate::sfm::BaseSFM + crate::sfm::SFM
 	/// * Ts: Output vector of 3x1 translations of the camera.
 	#[inline]
 	fn get_cameras(&mut self, rs: &mut dyn core::ToOutputArray, ts: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("SFMLibmvEuclideanReconstruction", self.as_raw_mut_SFMLibmvEuclideanReconstruction())?;
 		output_array_arg!(rs);
 		output_array_arg!(ts);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_sfm_SFMLibmvEuclideanReconstruction_getCameras_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_SFMLibmvEuclideanReconstruction(), rs.as_raw__OutputArray(), ts.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_sfm_SFMLibmvEuclideanReconstruction_getCameras_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_SFMLibmvEuclideanReconstruction(), rs.as_raw__OutputArray(), ts.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1027,8 +1194,9 @@ pub trait SFMLibmvEuclideanReconstruction: crate::sfm::BaseSFM + crate::sfm::SFM
 	///   automatic keyframe selection, parameters to refine and the verbosity level.
 	#[inline]
 	fn set_reconstruction_options(&mut self, libmv_reconstruction_options: crate::sfm::libmv_ReconstructionOptions) -> Result<()> {
+		crate::core::validity::check("SFMLibmvEuclideanReconstruction", self.as_raw_mut_SFMLibmvEuclideanReconstruction())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_sfm_SFMLibmvEuclideanReconstruction_setReconstructionOptions_const_libmv_ReconstructionOptionsR(self.as_raw_mut_SFMLibmvEuclideanReconstruction(), &libmv_reconstruction_options, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_sfm_SFMLibmvEuclideanReconstruction_setReconstructionOptions_const_libmv_ReconstructionOptionsR(self.as_raw_mut_SFMLibmvEuclideanReconstruction(), &libmv_reconstruction_options, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1040,8 +1208,9 @@ pub trait SFMLibmvEuclideanReconstruction: crate::sfm::BaseSFM + crate::sfm::SFM
 	///   the internal camera parameters.
 	#[inline]
 	fn set_camera_intrinsic_options(&mut self, libmv_camera_intrinsics_options: crate::sfm::libmv_CameraIntrinsicsOptions) -> Result<()> {
+		crate::core::validity::check("SFMLibmvEuclideanReconstruction", self.as_raw_mut_SFMLibmvEuclideanReconstruction())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_sfm_SFMLibmvEuclideanReconstruction_setCameraIntrinsicOptions_const_libmv_CameraIntrinsicsOptionsR(self.as_raw_mut_SFMLibmvEuclideanReconstruction(), &libmv_camera_intrinsics_options, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_sfm_SFMLibmvEuclideanReconstruction_setCameraIntrinsicOptions_const_libmv_CameraIntrinsicsOptionsR(self.as_raw_mut_SFMLibmvEuclideanReconstruction(), &libmv_camera_intrinsics_options, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1058,7 +1227,7 @@ impl dyn SFMLibmvEuclideanReconstruction + '_ {
 	#[inline]
 	pub fn create(camera_instrinsic_options: crate::sfm::libmv_CameraIntrinsicsOptions, reconstruction_options: crate::sfm::libmv_ReconstructionOptions) -> Result<core::Ptr<dyn crate::sfm::SFMLibmvEuclideanReconstruction>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_sfm_SFMLibmvEuclideanReconstruction_create_const_libmv_CameraIntrinsicsOptionsR_const_libmv_ReconstructionOptionsR(&camera_instrinsic_options, &reconstruction_options, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_sfm_SFMLibmvEuclideanReconstruction_create_const_libmv_CameraIntrinsicsOptionsR_const_libmv_ReconstructionOptionsR(&camera_instrinsic_options, &reconstruction_options, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::sfm::SFMLibmvEuclideanReconstruction>::opencv_from_extern(ret) };
@@ -1119,7 +1288,7 @@ impl libmv_CameraIntrinsicsOptions {
 	#[inline]
 	pub fn new(_distortion_model: i32, _focal_length_x: f64, _focal_length_y: f64, _principal_point_x: f64, _principal_point_y: f64, _polynomial_k1: f64, _polynomial_k2: f64, _polynomial_k3: f64, _polynomial_p1: f64, _polynomial_p2: f64) -> Result<crate::sfm::libmv_CameraIntrinsicsOptions> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_sfm_libmv_CameraIntrinsicsOptions_libmv_CameraIntrinsicsOptions_const_int_const_double_const_double_const_double_const_double_const_double_const_double_const_double_const_double_const_double(_distortion_model, _focal_length_x, _focal_length_y, _principal_point_x, _principal_point_y, _polynomial_k1, _polynomial_k2, _polynomial_k3, _polynomial_p1, _polynomial_p2, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_sfm_libmv_CameraIntrinsicsOptions_libmv_CameraIntrinsicsOptions_const_int_const_double_const_double_const_double_const_double_const_double_const_double_const_double_const_double_const_double(_distortion_model, _focal_length_x, _focal_length_y, _principal_point_x, _principal_point_y, _polynomial_k1, _polynomial_k2, _polynomial_k3, _polynomial_p1, _polynomial_p2, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1127,6 +1296,112 @@ impl libmv_CameraIntrinsicsOptions {
 	
 }
 
+/// Builder for `libmv_CameraIntrinsicsOptions::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct libmv_CameraIntrinsicsOptions_NewBuilder {
+	_distortion_model: i32,
+	_focal_length_x: f64,
+	_focal_length_y: f64,
+	_principal_point_x: f64,
+	_principal_point_y: f64,
+	_polynomial_k1: f64,
+	_polynomial_k2: f64,
+	_polynomial_k3: f64,
+	_polynomial_p1: f64,
+	_polynomial_p2: f64,
+}
+
+impl libmv_CameraIntrinsicsOptions_NewBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { _distortion_model: 0, _focal_length_x: 0., _focal_length_y: 0., _principal_point_x: 0., _principal_point_y: 0., _polynomial_k1: 0., _polynomial_k2: 0., _polynomial_k3: 0., _polynomial_p1: 0., _polynomial_p2: 0. }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with__distortion_model(mut self, _distortion_model: i32) -> Self {
+		self._distortion_model = _distortion_model;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with__focal_length_x(mut self, _focal_length_x: f64) -> Self {
+		self._focal_length_x = _focal_length_x;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with__focal_length_y(mut self, _focal_length_y: f64) -> Self {
+		self._focal_length_y = _focal_length_y;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with__principal_point_x(mut self, _principal_point_x: f64) -> Self {
+		self._principal_point_x = _principal_point_x;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with__principal_point_y(mut self, _principal_point_y: f64) -> Self {
+		self._principal_point_y = _principal_point_y;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with__polynomial_k1(mut self, _polynomial_k1: f64) -> Self {
+		self._polynomial_k1 = _polynomial_k1;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with__polynomial_k2(mut self, _polynomial_k2: f64) -> Self {
+		self._polynomial_k2 = _polynomial_k2;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with__polynomial_k3(mut self, _polynomial_k3: f64) -> Self {
+		self._polynomial_k3 = _polynomial_k3;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with__polynomial_p1(mut self, _polynomial_p1: f64) -> Self {
+		self._polynomial_p1 = _polynomial_p1;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with__polynomial_p2(mut self, _polynomial_p2: f64) -> Self {
+		self._polynomial_p2 = _polynomial_p2;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::sfm::libmv_CameraIntrinsicsOptions> {
+		libmv_CameraIntrinsicsOptions::new(self._distortion_model, self._focal_length_x, self._focal_length_y, self._principal_point_x, self._principal_point_y, self._polynomial_k1, self._polynomial_k2, self._polynomial_k3, self._polynomial_p1, self._polynomial_p2)
+	}
+}
+
+impl Default for libmv_CameraIntrinsicsOptions_NewBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 /// Data structure describing the reconstruction options.
 /// ## Parameters
 /// * _keyframe1: first keyframe used in order to initialize the reconstruction.
@@ -1156,10 +1431,77 @@ impl libmv_ReconstructionOptions {
 	#[inline]
 	pub fn new(_keyframe1: i32, _keyframe2: i32, _refine_intrinsics: i32, _select_keyframes: i32, _verbosity_level: i32) -> Result<crate::sfm::libmv_ReconstructionOptions> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_sfm_libmv_ReconstructionOptions_libmv_ReconstructionOptions_const_int_const_int_const_int_const_int_const_int(_keyframe1, _keyframe2, _refine_intrinsics, _select_keyframes, _verbosity_level, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_sfm_libmv_ReconstructionOptions_libmv_ReconstructionOptions_const_int_const_int_const_int_const_int_const_int(_keyframe1, _keyframe2, _refine_intrinsics, _select_keyframes, _verbosity_level, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 }
+
+/// Builder for `libmv_ReconstructionOptions::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct libmv_ReconstructionOptions_NewBuilder {
+	_keyframe1: i32,
+	_keyframe2: i32,
+	_refine_intrinsics: i32,
+	_select_keyframes: i32,
+	_verbosity_level: i32,
+}
+
+impl libmv_ReconstructionOptions_NewBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { _keyframe1: 1, _keyframe2: 2, _refine_intrinsics: 1, _select_keyframes: 1, _verbosity_level: -1 }
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with__keyframe1(mut self, _keyframe1: i32) -> Self {
+		self._keyframe1 = _keyframe1;
+		self
+	}
+	
+	/// C++ default: `2`
+	#[inline]
+	pub fn with__keyframe2(mut self, _keyframe2: i32) -> Self {
+		self._keyframe2 = _keyframe2;
+		self
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with__refine_intrinsics(mut self, _refine_intrinsics: i32) -> Self {
+		self._refine_intrinsics = _refine_intrinsics;
+		self
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with__select_keyframes(mut self, _select_keyframes: i32) -> Self {
+		self._select_keyframes = _select_keyframes;
+		self
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with__verbosity_level(mut self, _verbosity_level: i32) -> Self {
+		self._verbosity_level = _verbosity_level;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::sfm::libmv_ReconstructionOptions> {
+		libmv_ReconstructionOptions::new(self._keyframe1, self._keyframe2, self._refine_intrinsics, self._select_keyframes, self._verbosity_level)
+	}
+}
+
+impl Default for libmv_ReconstructionOptions_NewBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+pub use crate::manual::sfm::*;
@@ -27,7 +27,7 @@ pub fn emdl1(signature1: &dyn core::ToInputArray, signature2: &dyn core::ToInput
 	input_array_arg!(signature1);
 	input_array_arg!(signature2);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_EMDL1_const__InputArrayR_const__InputArrayR(signature1.as_raw__InputArray(), signature2.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_EMDL1_const__InputArrayR_const__InputArrayR(signature1.as_raw__InputArray(), signature2.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -37,7 +37,7 @@ pub fn emdl1(signature1: &dyn core::ToInputArray, signature2: &dyn core::ToInput
 #[inline]
 pub fn create_affine_transformer(full_affine: bool) -> Result<core::Ptr<dyn crate::shape::AffineTransformer>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_createAffineTransformer_bool(full_affine, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_createAffineTransformer_bool(full_affine, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::shape::AffineTransformer>::opencv_from_extern(ret) };
@@ -50,7 +50,7 @@ pub fn create_affine_transformer(full_affine: bool) -> Result<core::Ptr<dyn crat
 #[inline]
 pub fn create_chi_histogram_cost_extractor(n_dummies: i32, default_cost: f32) -> Result<core::Ptr<dyn crate::shape::HistogramCostExtractor>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_createChiHistogramCostExtractor_int_float(n_dummies, default_cost, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_createChiHistogramCostExtractor_int_float(n_dummies, default_cost, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::shape::HistogramCostExtractor>::opencv_from_extern(ret) };
@@ -64,20 +64,56 @@ pub fn create_chi_histogram_cost_extractor(n_dummies: i32, default_cost: f32) ->
 #[inline]
 pub fn create_emd_histogram_cost_extractor(flag: i32, n_dummies: i32, default_cost: f32) -> Result<core::Ptr<dyn crate::shape::HistogramCostExtractor>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_createEMDHistogramCostExtractor_int_int_float(flag, n_dummies, default_cost, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_createEMDHistogramCostExtractor_int_int_float(flag, n_dummies, default_cost, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::shape::HistogramCostExtractor>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_emd_histogram_cost_extractor()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateEmdHistogramCostExtractorBuilder {
+	flag: i32,
+	n_dummies: i32,
+	default_cost: f32,
+}
+
+impl CreateEmdHistogramCostExtractorBuilder {
+	#[inline]
+	pub fn new(flag: i32) -> Self {
+		Self { flag, n_dummies: 25, default_cost: 0.2 }
+	}
+	
+	/// C++ default: `25`
+	#[inline]
+	pub fn with_n_dummies(mut self, n_dummies: i32) -> Self {
+		self.n_dummies = n_dummies;
+		self
+	}
+	
+	/// C++ default: `0.2f`
+	#[inline]
+	pub fn with_default_cost(mut self, default_cost: f32) -> Self {
+		self.default_cost = default_cost;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::shape::HistogramCostExtractor>> {
+		create_emd_histogram_cost_extractor(self.flag, self.n_dummies, self.default_cost)
+	}
+}
+
 /// ## C++ default parameters
 /// * n_dummies: 25
 /// * default_cost: 0.2f
 #[inline]
 pub fn create_emdl1_histogram_cost_extractor(n_dummies: i32, default_cost: f32) -> Result<core::Ptr<dyn crate::shape::HistogramCostExtractor>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_createEMDL1HistogramCostExtractor_int_float(n_dummies, default_cost, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_createEMDL1HistogramCostExtractor_int_float(n_dummies, default_cost, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::shape::HistogramCostExtractor>::opencv_from_extern(ret) };
@@ -90,7 +126,7 @@ pub fn create_emdl1_histogram_cost_extractor(n_dummies: i32, default_cost: f32)
 #[inline]
 pub fn create_hausdorff_distance_extractor(distance_flag: i32, rank_prop: f32) -> Result<core::Ptr<dyn crate::shape::HausdorffDistanceExtractor>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_createHausdorffDistanceExtractor_int_float(distance_flag, rank_prop, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_createHausdorffDistanceExtractor_int_float(distance_flag, rank_prop, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::shape::HausdorffDistanceExtractor>::opencv_from_extern(ret) };
@@ -104,13 +140,49 @@ pub fn create_hausdorff_distance_extractor(distance_flag: i32, rank_prop: f32) -
 #[inline]
 pub fn create_norm_histogram_cost_extractor(flag: i32, n_dummies: i32, default_cost: f32) -> Result<core::Ptr<dyn crate::shape::HistogramCostExtractor>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_createNormHistogramCostExtractor_int_int_float(flag, n_dummies, default_cost, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_createNormHistogramCostExtractor_int_int_float(flag, n_dummies, default_cost, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::shape::HistogramCostExtractor>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_norm_histogram_cost_extractor()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateNormHistogramCostExtractorBuilder {
+	flag: i32,
+	n_dummies: i32,
+	default_cost: f32,
+}
+
+impl CreateNormHistogramCostExtractorBuilder {
+	#[inline]
+	pub fn new(flag: i32) -> Self {
+		Self { flag, n_dummies: 25, default_cost: 0.2 }
+	}
+	
+	/// C++ default: `25`
+	#[inline]
+	pub fn with_n_dummies(mut self, n_dummies: i32) -> Self {
+		self.n_dummies = n_dummies;
+		self
+	}
+	
+	/// C++ default: `0.2f`
+	#[inline]
+	pub fn with_default_cost(mut self, default_cost: f32) -> Self {
+		self.default_cost = default_cost;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::shape::HistogramCostExtractor>> {
+		create_norm_histogram_cost_extractor(self.flag, self.n_dummies, self.default_cost)
+	}
+}
+
 /// ## C++ default parameters
 /// * n_angular_bins: 12
 /// * n_radial_bins: 4
@@ -122,13 +194,74 @@ pub fn create_norm_histogram_cost_extractor(flag: i32, n_dummies: i32, default_c
 #[inline]
 pub fn create_shape_context_distance_extractor(n_angular_bins: i32, n_radial_bins: i32, inner_radius: f32, outer_radius: f32, iterations: i32, comparer: &core::Ptr<dyn crate::shape::HistogramCostExtractor>, transformer: &core::Ptr<dyn crate::shape::ShapeTransformer>) -> Result<core::Ptr<dyn crate::shape::ShapeContextDistanceExtractor>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_createShapeContextDistanceExtractor_int_int_float_float_int_const_Ptr_HistogramCostExtractor_R_const_Ptr_ShapeTransformer_R(n_angular_bins, n_radial_bins, inner_radius, outer_radius, iterations, comparer.as_raw_PtrOfHistogramCostExtractor(), transformer.as_raw_PtrOfShapeTransformer(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_createShapeContextDistanceExtractor_int_int_float_float_int_const_Ptr_HistogramCostExtractor_R_const_Ptr_ShapeTransformer_R(n_angular_bins, n_radial_bins, inner_radius, outer_radius, iterations, comparer.as_raw_PtrOfHistogramCostExtractor(), transformer.as_raw_PtrOfShapeTransformer(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::shape::ShapeContextDistanceExtractor>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_shape_context_distance_extractor()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateShapeContextDistanceExtractorBuilder<'a> {
+	n_angular_bins: i32,
+	n_radial_bins: i32,
+	inner_radius: f32,
+	outer_radius: f32,
+	iterations: i32,
+	comparer: &'a core::Ptr<dyn crate::shape::HistogramCostExtractor>,
+	transformer: &'a core::Ptr<dyn crate::shape::ShapeTransformer>,
+}
+
+impl<'a> CreateShapeContextDistanceExtractorBuilder<'a> {
+	#[inline]
+	pub fn new(comparer: &'a core::Ptr<dyn crate::shape::HistogramCostExtractor>, transformer: &'a core::Ptr<dyn crate::shape::ShapeTransformer>) -> Self {
+		Self { n_angular_bins: 12, n_radial_bins: 4, inner_radius: 0.2, outer_radius: 2., iterations: 3, comparer, transformer }
+	}
+	
+	/// C++ default: `12`
+	#[inline]
+	pub fn with_n_angular_bins(mut self, n_angular_bins: i32) -> Self {
+		self.n_angular_bins = n_angular_bins;
+		self
+	}
+	
+	/// C++ default: `4`
+	#[inline]
+	pub fn with_n_radial_bins(mut self, n_radial_bins: i32) -> Self {
+		self.n_radial_bins = n_radial_bins;
+		self
+	}
+	
+	/// C++ default: `0.2f`
+	#[inline]
+	pub fn with_inner_radius(mut self, inner_radius: f32) -> Self {
+		self.inner_radius = inner_radius;
+		self
+	}
+	
+	/// C++ default: `2`
+	#[inline]
+	pub fn with_outer_radius(mut self, outer_radius: f32) -> Self {
+		self.outer_radius = outer_radius;
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_iterations(mut self, iterations: i32) -> Self {
+		self.iterations = iterations;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::shape::ShapeContextDistanceExtractor>> {
+		create_shape_context_distance_extractor(self.n_angular_bins, self.n_radial_bins, self.inner_radius, self.outer_radius, self.iterations, self.comparer, self.transformer)
+	}
+}
+
 /// Complete constructor
 /// 
 /// ## C++ default parameters
@@ -136,7 +269,7 @@ pub fn create_shape_context_distance_extractor(n_angular_bins: i32, n_radial_bin
 #[inline]
 pub fn create_thin_plate_spline_shape_transformer(regularization_parameter: f64) -> Result<core::Ptr<dyn crate::shape::ThinPlateSplineShapeTransformer>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_createThinPlateSplineShapeTransformer_double(regularization_parameter, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_createThinPlateSplineShapeTransformer_double(regularization_parameter, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::shape::ThinPlateSplineShapeTransformer>::opencv_from_extern(ret) };
@@ -149,8 +282,9 @@ pub trait AffineTransformerConst: crate::shape::ShapeTransformerConst {
 
 	#[inline]
 	fn get_full_affine(&self) -> Result<bool> {
+		crate::core::validity::check("AffineTransformer", self.as_raw_AffineTransformer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AffineTransformer_getFullAffine_const(self.as_raw_AffineTransformer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AffineTransformer_getFullAffine_const(self.as_raw_AffineTransformer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -163,8 +297,9 @@ pub trait AffineTransformer: crate::shape::AffineTransformerConst + crate::shape
 
 	#[inline]
 	fn set_full_affine(&mut self, full_affine: bool) -> Result<()> {
+		crate::core::validity::check("AffineTransformer", self.as_raw_mut_AffineTransformer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AffineTransformer_setFullAffine_bool(self.as_raw_mut_AffineTransformer(), full_affine, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AffineTransformer_setFullAffine_bool(self.as_raw_mut_AffineTransformer(), full_affine, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -189,8 +324,9 @@ pub trait EMDHistogramCostExtractorConst: crate::shape::HistogramCostExtractorCo
 
 	#[inline]
 	fn get_norm_flag(&self) -> Result<i32> {
+		crate::core::validity::check("EMDHistogramCostExtractor", self.as_raw_EMDHistogramCostExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_EMDHistogramCostExtractor_getNormFlag_const(self.as_raw_EMDHistogramCostExtractor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_EMDHistogramCostExtractor_getNormFlag_const(self.as_raw_EMDHistogramCostExtractor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -203,8 +339,9 @@ pub trait EMDHistogramCostExtractor: crate::shape::EMDHistogramCostExtractorCons
 
 	#[inline]
 	fn set_norm_flag(&mut self, flag: i32) -> Result<()> {
+		crate::core::validity::check("EMDHistogramCostExtractor", self.as_raw_mut_EMDHistogramCostExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_EMDHistogramCostExtractor_setNormFlag_int(self.as_raw_mut_EMDHistogramCostExtractor(), flag, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_EMDHistogramCostExtractor_setNormFlag_int(self.as_raw_mut_EMDHistogramCostExtractor(), flag, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -235,8 +372,9 @@ pub trait HausdorffDistanceExtractorConst: crate::shape::ShapeDistanceExtractorC
 
 	#[inline]
 	fn get_distance_flag(&self) -> Result<i32> {
+		crate::core::validity::check("HausdorffDistanceExtractor", self.as_raw_HausdorffDistanceExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HausdorffDistanceExtractor_getDistanceFlag_const(self.as_raw_HausdorffDistanceExtractor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HausdorffDistanceExtractor_getDistanceFlag_const(self.as_raw_HausdorffDistanceExtractor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -244,8 +382,9 @@ pub trait HausdorffDistanceExtractorConst: crate::shape::ShapeDistanceExtractorC
 	
 	#[inline]
 	fn get_rank_proportion(&self) -> Result<f32> {
+		crate::core::validity::check("HausdorffDistanceExtractor", self.as_raw_HausdorffDistanceExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HausdorffDistanceExtractor_getRankProportion_const(self.as_raw_HausdorffDistanceExtractor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HausdorffDistanceExtractor_getRankProportion_const(self.as_raw_HausdorffDistanceExtractor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -263,8 +402,9 @@ pub trait HausdorffDistanceExtractor: crate::shape::HausdorffDistanceExtractorCo
 	/// (NORM_L1, NORM_L2).
 	#[inline]
 	fn set_distance_flag(&mut self, distance_flag: i32) -> Result<()> {
+		crate::core::validity::check("HausdorffDistanceExtractor", self.as_raw_mut_HausdorffDistanceExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HausdorffDistanceExtractor_setDistanceFlag_int(self.as_raw_mut_HausdorffDistanceExtractor(), distance_flag, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HausdorffDistanceExtractor_setDistanceFlag_int(self.as_raw_mut_HausdorffDistanceExtractor(), distance_flag, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -278,8 +418,9 @@ pub trait HausdorffDistanceExtractor: crate::shape::HausdorffDistanceExtractorCo
 	/// * rankProportion: fractional value (between 0 and 1).
 	#[inline]
 	fn set_rank_proportion(&mut self, rank_proportion: f32) -> Result<()> {
+		crate::core::validity::check("HausdorffDistanceExtractor", self.as_raw_mut_HausdorffDistanceExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HausdorffDistanceExtractor_setRankProportion_float(self.as_raw_mut_HausdorffDistanceExtractor(), rank_proportion, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HausdorffDistanceExtractor_setRankProportion_float(self.as_raw_mut_HausdorffDistanceExtractor(), rank_proportion, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -293,8 +434,9 @@ pub trait HistogramCostExtractorConst: core::AlgorithmTraitConst {
 
 	#[inline]
 	fn get_n_dummies(&self) -> Result<i32> {
+		crate::core::validity::check("HistogramCostExtractor", self.as_raw_HistogramCostExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HistogramCostExtractor_getNDummies_const(self.as_raw_HistogramCostExtractor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HistogramCostExtractor_getNDummies_const(self.as_raw_HistogramCostExtractor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -302,8 +444,9 @@ pub trait HistogramCostExtractorConst: core::AlgorithmTraitConst {
 	
 	#[inline]
 	fn get_default_cost(&self) -> Result<f32> {
+		crate::core::validity::check("HistogramCostExtractor", self.as_raw_HistogramCostExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HistogramCostExtractor_getDefaultCost_const(self.as_raw_HistogramCostExtractor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HistogramCostExtractor_getDefaultCost_const(self.as_raw_HistogramCostExtractor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -316,11 +459,12 @@ pub trait HistogramCostExtractor: core::AlgorithmTrait + crate::shape::Histogram
 
 	#[inline]
 	fn build_cost_matrix(&mut self, descriptors1: &dyn core::ToInputArray, descriptors2: &dyn core::ToInputArray, cost_matrix: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("HistogramCostExtractor", self.as_raw_mut_HistogramCostExtractor())?;
 		input_array_arg!(descriptors1);
 		input_array_arg!(descriptors2);
 		output_array_arg!(cost_matrix);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HistogramCostExtractor_buildCostMatrix_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_HistogramCostExtractor(), descriptors1.as_raw__InputArray(), descriptors2.as_raw__InputArray(), cost_matrix.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HistogramCostExtractor_buildCostMatrix_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_HistogramCostExtractor(), descriptors1.as_raw__InputArray(), descriptors2.as_raw__InputArray(), cost_matrix.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -328,8 +472,9 @@ pub trait HistogramCostExtractor: core::AlgorithmTrait + crate::shape::Histogram
 	
 	#[inline]
 	fn set_n_dummies(&mut self, n_dummies: i32) -> Result<()> {
+		crate::core::validity::check("HistogramCostExtractor", self.as_raw_mut_HistogramCostExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HistogramCostExtractor_setNDummies_int(self.as_raw_mut_HistogramCostExtractor(), n_dummies, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HistogramCostExtractor_setNDummies_int(self.as_raw_mut_HistogramCostExtractor(), n_dummies, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -337,8 +482,9 @@ pub trait HistogramCostExtractor: core::AlgorithmTrait + crate::shape::Histogram
 	
 	#[inline]
 	fn set_default_cost(&mut self, default_cost: f32) -> Result<()> {
+		crate::core::validity::check("HistogramCostExtractor", self.as_raw_mut_HistogramCostExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_HistogramCostExtractor_setDefaultCost_float(self.as_raw_mut_HistogramCostExtractor(), default_cost, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_HistogramCostExtractor_setDefaultCost_float(self.as_raw_mut_HistogramCostExtractor(), default_cost, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -352,8 +498,9 @@ pub trait NormHistogramCostExtractorConst: crate::shape::HistogramCostExtractorC
 
 	#[inline]
 	fn get_norm_flag(&self) -> Result<i32> {
+		crate::core::validity::check("NormHistogramCostExtractor", self.as_raw_NormHistogramCostExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_NormHistogramCostExtractor_getNormFlag_const(self.as_raw_NormHistogramCostExtractor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_NormHistogramCostExtractor_getNormFlag_const(self.as_raw_NormHistogramCostExtractor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -366,8 +513,9 @@ pub trait NormHistogramCostExtractor: crate::shape::HistogramCostExtractor + cra
 
 	#[inline]
 	fn set_norm_flag(&mut self, flag: i32) -> Result<()> {
+		crate::core::validity::check("NormHistogramCostExtractor", self.as_raw_mut_NormHistogramCostExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_NormHistogramCostExtractor_setNormFlag_int(self.as_raw_mut_NormHistogramCostExtractor(), flag, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_NormHistogramCostExtractor_setNormFlag_int(self.as_raw_mut_NormHistogramCostExtractor(), flag, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -388,8 +536,9 @@ pub trait ShapeContextDistanceExtractorConst: crate::shape::ShapeDistanceExtract
 
 	#[inline]
 	fn get_angular_bins(&self) -> Result<i32> {
+		crate::core::validity::check("ShapeContextDistanceExtractor", self.as_raw_ShapeContextDistanceExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ShapeContextDistanceExtractor_getAngularBins_const(self.as_raw_ShapeContextDistanceExtractor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ShapeContextDistanceExtractor_getAngularBins_const(self.as_raw_ShapeContextDistanceExtractor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -397,8 +546,9 @@ pub trait ShapeContextDistanceExtractorConst: crate::shape::ShapeDistanceExtract
 	
 	#[inline]
 	fn get_radial_bins(&self) -> Result<i32> {
+		crate::core::validity::check("ShapeContextDistanceExtractor", self.as_raw_ShapeContextDistanceExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ShapeContextDistanceExtractor_getRadialBins_const(self.as_raw_ShapeContextDistanceExtractor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ShapeContextDistanceExtractor_getRadialBins_const(self.as_raw_ShapeContextDistanceExtractor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -406,8 +556,9 @@ pub trait ShapeContextDistanceExtractorConst: crate::shape::ShapeDistanceExtract
 	
 	#[inline]
 	fn get_inner_radius(&self) -> Result<f32> {
+		crate::core::validity::check("ShapeContextDistanceExtractor", self.as_raw_ShapeContextDistanceExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ShapeContextDistanceExtractor_getInnerRadius_const(self.as_raw_ShapeContextDistanceExtractor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ShapeContextDistanceExtractor_getInnerRadius_const(self.as_raw_ShapeContextDistanceExtractor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -415,8 +566,9 @@ pub trait ShapeContextDistanceExtractorConst: crate::shape::ShapeDistanceExtract
 	
 	#[inline]
 	fn get_outer_radius(&self) -> Result<f32> {
+		crate::core::validity::check("ShapeContextDistanceExtractor", self.as_raw_ShapeContextDistanceExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ShapeContextDistanceExtractor_getOuterRadius_const(self.as_raw_ShapeContextDistanceExtractor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ShapeContextDistanceExtractor_getOuterRadius_const(self.as_raw_ShapeContextDistanceExtractor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -424,8 +576,9 @@ pub trait ShapeContextDistanceExtractorConst: crate::shape::ShapeDistanceExtract
 	
 	#[inline]
 	fn get_rotation_invariant(&self) -> Result<bool> {
+		crate::core::validity::check("ShapeContextDistanceExtractor", self.as_raw_ShapeContextDistanceExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ShapeContextDistanceExtractor_getRotationInvariant_const(self.as_raw_ShapeContextDistanceExtractor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ShapeContextDistanceExtractor_getRotationInvariant_const(self.as_raw_ShapeContextDistanceExtractor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -433,8 +586,9 @@ pub trait ShapeContextDistanceExtractorConst: crate::shape::ShapeDistanceExtract
 	
 	#[inline]
 	fn get_shape_context_weight(&self) -> Result<f32> {
+		crate::core::validity::check("ShapeContextDistanceExtractor", self.as_raw_ShapeContextDistanceExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ShapeContextDistanceExtractor_getShapeContextWeight_const(self.as_raw_ShapeContextDistanceExtractor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ShapeContextDistanceExtractor_getShapeContextWeight_const(self.as_raw_ShapeContextDistanceExtractor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -442,8 +596,9 @@ pub trait ShapeContextDistanceExtractorConst: crate::shape::ShapeDistanceExtract
 	
 	#[inline]
 	fn get_image_appearance_weight(&self) -> Result<f32> {
+		crate::core::validity::check("ShapeContextDistanceExtractor", self.as_raw_ShapeContextDistanceExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ShapeContextDistanceExtractor_getImageAppearanceWeight_const(self.as_raw_ShapeContextDistanceExtractor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ShapeContextDistanceExtractor_getImageAppearanceWeight_const(self.as_raw_ShapeContextDistanceExtractor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -451,8 +606,9 @@ pub trait ShapeContextDistanceExtractorConst: crate::shape::ShapeDistanceExtract
 	
 	#[inline]
 	fn get_bending_energy_weight(&self) -> Result<f32> {
+		crate::core::validity::check("ShapeContextDistanceExtractor", self.as_raw_ShapeContextDistanceExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ShapeContextDistanceExtractor_getBendingEnergyWeight_const(self.as_raw_ShapeContextDistanceExtractor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ShapeContextDistanceExtractor_getBendingEnergyWeight_const(self.as_raw_ShapeContextDistanceExtractor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -460,10 +616,11 @@ pub trait ShapeContextDistanceExtractorConst: crate::shape::ShapeDistanceExtract
 	
 	#[inline]
 	fn get_images(&self, image1: &mut dyn core::ToOutputArray, image2: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("ShapeContextDistanceExtractor", self.as_raw_ShapeContextDistanceExtractor())?;
 		output_array_arg!(image1);
 		output_array_arg!(image2);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ShapeContextDistanceExtractor_getImages_const_const__OutputArrayR_const__OutputArrayR(self.as_raw_ShapeContextDistanceExtractor(), image1.as_raw__OutputArray(), image2.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ShapeContextDistanceExtractor_getImages_const_const__OutputArrayR_const__OutputArrayR(self.as_raw_ShapeContextDistanceExtractor(), image1.as_raw__OutputArray(), image2.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -471,8 +628,9 @@ pub trait ShapeContextDistanceExtractorConst: crate::shape::ShapeDistanceExtract
 	
 	#[inline]
 	fn get_iterations(&self) -> Result<i32> {
+		crate::core::validity::check("ShapeContextDistanceExtractor", self.as_raw_ShapeContextDistanceExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ShapeContextDistanceExtractor_getIterations_const(self.as_raw_ShapeContextDistanceExtractor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ShapeContextDistanceExtractor_getIterations_const(self.as_raw_ShapeContextDistanceExtractor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -480,8 +638,9 @@ pub trait ShapeContextDistanceExtractorConst: crate::shape::ShapeDistanceExtract
 	
 	#[inline]
 	fn get_cost_extractor(&self) -> Result<core::Ptr<dyn crate::shape::HistogramCostExtractor>> {
+		crate::core::validity::check("ShapeContextDistanceExtractor", self.as_raw_ShapeContextDistanceExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ShapeContextDistanceExtractor_getCostExtractor_const(self.as_raw_ShapeContextDistanceExtractor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ShapeContextDistanceExtractor_getCostExtractor_const(self.as_raw_ShapeContextDistanceExtractor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::shape::HistogramCostExtractor>::opencv_from_extern(ret) };
@@ -490,8 +649,9 @@ pub trait ShapeContextDistanceExtractorConst: crate::shape::ShapeDistanceExtract
 	
 	#[inline]
 	fn get_std_dev(&self) -> Result<f32> {
+		crate::core::validity::check("ShapeContextDistanceExtractor", self.as_raw_ShapeContextDistanceExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ShapeContextDistanceExtractor_getStdDev_const(self.as_raw_ShapeContextDistanceExtractor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ShapeContextDistanceExtractor_getStdDev_const(self.as_raw_ShapeContextDistanceExtractor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -499,8 +659,9 @@ pub trait ShapeContextDistanceExtractorConst: crate::shape::ShapeDistanceExtract
 	
 	#[inline]
 	fn get_transform_algorithm(&self) -> Result<core::Ptr<dyn crate::shape::ShapeTransformer>> {
+		crate::core::validity::check("ShapeContextDistanceExtractor", self.as_raw_ShapeContextDistanceExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ShapeContextDistanceExtractor_getTransformAlgorithm_const(self.as_raw_ShapeContextDistanceExtractor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ShapeContextDistanceExtractor_getTransformAlgorithm_const(self.as_raw_ShapeContextDistanceExtractor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::shape::ShapeTransformer>::opencv_from_extern(ret) };
@@ -519,8 +680,9 @@ pub trait ShapeContextDistanceExtractor: crate::shape::ShapeContextDistanceExtra
 	/// * nAngularBins: The number of angular bins in the shape context descriptor.
 	#[inline]
 	fn set_angular_bins(&mut self, n_angular_bins: i32) -> Result<()> {
+		crate::core::validity::check("ShapeContextDistanceExtractor", self.as_raw_mut_ShapeContextDistanceExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ShapeContextDistanceExtractor_setAngularBins_int(self.as_raw_mut_ShapeContextDistanceExtractor(), n_angular_bins, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ShapeContextDistanceExtractor_setAngularBins_int(self.as_raw_mut_ShapeContextDistanceExtractor(), n_angular_bins, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -533,8 +695,9 @@ pub trait ShapeContextDistanceExtractor: crate::shape::ShapeContextDistanceExtra
 	/// * nRadialBins: The number of radial bins in the shape context descriptor.
 	#[inline]
 	fn set_radial_bins(&mut self, n_radial_bins: i32) -> Result<()> {
+		crate::core::validity::check("ShapeContextDistanceExtractor", self.as_raw_mut_ShapeContextDistanceExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ShapeContextDistanceExtractor_setRadialBins_int(self.as_raw_mut_ShapeContextDistanceExtractor(), n_radial_bins, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ShapeContextDistanceExtractor_setRadialBins_int(self.as_raw_mut_ShapeContextDistanceExtractor(), n_radial_bins, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -546,8 +709,9 @@ pub trait ShapeContextDistanceExtractor: crate::shape::ShapeContextDistanceExtra
 	/// * innerRadius: The value of the inner radius.
 	#[inline]
 	fn set_inner_radius(&mut self, inner_radius: f32) -> Result<()> {
+		crate::core::validity::check("ShapeContextDistanceExtractor", self.as_raw_mut_ShapeContextDistanceExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ShapeContextDistanceExtractor_setInnerRadius_float(self.as_raw_mut_ShapeContextDistanceExtractor(), inner_radius, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ShapeContextDistanceExtractor_setInnerRadius_float(self.as_raw_mut_ShapeContextDistanceExtractor(), inner_radius, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -559,8 +723,9 @@ pub trait ShapeContextDistanceExtractor: crate::shape::ShapeContextDistanceExtra
 	/// * outerRadius: The value of the outer radius.
 	#[inline]
 	fn set_outer_radius(&mut self, outer_radius: f32) -> Result<()> {
+		crate::core::validity::check("ShapeContextDistanceExtractor", self.as_raw_mut_ShapeContextDistanceExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ShapeContextDistanceExtractor_setOuterRadius_float(self.as_raw_mut_ShapeContextDistanceExtractor(), outer_radius, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ShapeContextDistanceExtractor_setOuterRadius_float(self.as_raw_mut_ShapeContextDistanceExtractor(), outer_radius, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -568,8 +733,9 @@ pub trait ShapeContextDistanceExtractor: crate::shape::ShapeContextDistanceExtra
 	
 	#[inline]
 	fn set_rotation_invariant(&mut self, rotation_invariant: bool) -> Result<()> {
+		crate::core::validity::check("ShapeContextDistanceExtractor", self.as_raw_mut_ShapeContextDistanceExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ShapeContextDistanceExtractor_setRotationInvariant_bool(self.as_raw_mut_ShapeContextDistanceExtractor(), rotation_invariant, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ShapeContextDistanceExtractor_setRotationInvariant_bool(self.as_raw_mut_ShapeContextDistanceExtractor(), rotation_invariant, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -584,8 +750,9 @@ pub trait ShapeContextDistanceExtractor: crate::shape::ShapeContextDistanceExtra
 	/// * shapeContextWeight: The weight of the shape context distance in the final distance value.
 	#[inline]
 	fn set_shape_context_weight(&mut self, shape_context_weight: f32) -> Result<()> {
+		crate::core::validity::check("ShapeContextDistanceExtractor", self.as_raw_mut_ShapeContextDistanceExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ShapeContextDistanceExtractor_setShapeContextWeight_float(self.as_raw_mut_ShapeContextDistanceExtractor(), shape_context_weight, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ShapeContextDistanceExtractor_setShapeContextWeight_float(self.as_raw_mut_ShapeContextDistanceExtractor(), shape_context_weight, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -602,8 +769,9 @@ pub trait ShapeContextDistanceExtractor: crate::shape::ShapeContextDistanceExtra
 	/// * imageAppearanceWeight: The weight of the appearance cost in the final distance value.
 	#[inline]
 	fn set_image_appearance_weight(&mut self, image_appearance_weight: f32) -> Result<()> {
+		crate::core::validity::check("ShapeContextDistanceExtractor", self.as_raw_mut_ShapeContextDistanceExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ShapeContextDistanceExtractor_setImageAppearanceWeight_float(self.as_raw_mut_ShapeContextDistanceExtractor(), image_appearance_weight, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ShapeContextDistanceExtractor_setImageAppearanceWeight_float(self.as_raw_mut_ShapeContextDistanceExtractor(), image_appearance_weight, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -618,8 +786,9 @@ pub trait ShapeContextDistanceExtractor: crate::shape::ShapeContextDistanceExtra
 	/// * bendingEnergyWeight: The weight of the Bending Energy in the final distance value.
 	#[inline]
 	fn set_bending_energy_weight(&mut self, bending_energy_weight: f32) -> Result<()> {
+		crate::core::validity::check("ShapeContextDistanceExtractor", self.as_raw_mut_ShapeContextDistanceExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ShapeContextDistanceExtractor_setBendingEnergyWeight_float(self.as_raw_mut_ShapeContextDistanceExtractor(), bending_energy_weight, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ShapeContextDistanceExtractor_setBendingEnergyWeight_float(self.as_raw_mut_ShapeContextDistanceExtractor(), bending_energy_weight, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -633,10 +802,11 @@ pub trait ShapeContextDistanceExtractor: crate::shape::ShapeContextDistanceExtra
 	/// * image2: Image corresponding to the shape defined by contours2.
 	#[inline]
 	fn set_images(&mut self, image1: &dyn core::ToInputArray, image2: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("ShapeContextDistanceExtractor", self.as_raw_mut_ShapeContextDistanceExtractor())?;
 		input_array_arg!(image1);
 		input_array_arg!(image2);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ShapeContextDistanceExtractor_setImages_const__InputArrayR_const__InputArrayR(self.as_raw_mut_ShapeContextDistanceExtractor(), image1.as_raw__InputArray(), image2.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ShapeContextDistanceExtractor_setImages_const__InputArrayR_const__InputArrayR(self.as_raw_mut_ShapeContextDistanceExtractor(), image1.as_raw__InputArray(), image2.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -644,8 +814,9 @@ pub trait ShapeContextDistanceExtractor: crate::shape::ShapeContextDistanceExtra
 	
 	#[inline]
 	fn set_iterations(&mut self, iterations: i32) -> Result<()> {
+		crate::core::validity::check("ShapeContextDistanceExtractor", self.as_raw_mut_ShapeContextDistanceExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ShapeContextDistanceExtractor_setIterations_int(self.as_raw_mut_ShapeContextDistanceExtractor(), iterations, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ShapeContextDistanceExtractor_setIterations_int(self.as_raw_mut_ShapeContextDistanceExtractor(), iterations, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -658,8 +829,9 @@ pub trait ShapeContextDistanceExtractor: crate::shape::ShapeContextDistanceExtra
 	/// matrix between descriptors.
 	#[inline]
 	fn set_cost_extractor(&mut self, mut comparer: core::Ptr<dyn crate::shape::HistogramCostExtractor>) -> Result<()> {
+		crate::core::validity::check("ShapeContextDistanceExtractor", self.as_raw_mut_ShapeContextDistanceExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ShapeContextDistanceExtractor_setCostExtractor_Ptr_HistogramCostExtractor_(self.as_raw_mut_ShapeContextDistanceExtractor(), comparer.as_raw_mut_PtrOfHistogramCostExtractor(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ShapeContextDistanceExtractor_setCostExtractor_Ptr_HistogramCostExtractor_(self.as_raw_mut_ShapeContextDistanceExtractor(), comparer.as_raw_mut_PtrOfHistogramCostExtractor(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -671,8 +843,9 @@ pub trait ShapeContextDistanceExtractor: crate::shape::ShapeContextDistanceExtra
 	/// * sigma: Standard Deviation.
 	#[inline]
 	fn set_std_dev(&mut self, sigma: f32) -> Result<()> {
+		crate::core::validity::check("ShapeContextDistanceExtractor", self.as_raw_mut_ShapeContextDistanceExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ShapeContextDistanceExtractor_setStdDev_float(self.as_raw_mut_ShapeContextDistanceExtractor(), sigma, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ShapeContextDistanceExtractor_setStdDev_float(self.as_raw_mut_ShapeContextDistanceExtractor(), sigma, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -685,8 +858,9 @@ pub trait ShapeContextDistanceExtractor: crate::shape::ShapeContextDistanceExtra
 	/// transformation.
 	#[inline]
 	fn set_transform_algorithm(&mut self, mut transformer: core::Ptr<dyn crate::shape::ShapeTransformer>) -> Result<()> {
+		crate::core::validity::check("ShapeContextDistanceExtractor", self.as_raw_mut_ShapeContextDistanceExtractor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ShapeContextDistanceExtractor_setTransformAlgorithm_Ptr_ShapeTransformer_(self.as_raw_mut_ShapeContextDistanceExtractor(), transformer.as_raw_mut_PtrOfShapeTransformer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ShapeContextDistanceExtractor_setTransformAlgorithm_Ptr_ShapeTransformer_(self.as_raw_mut_ShapeContextDistanceExtractor(), transformer.as_raw_mut_PtrOfShapeTransformer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -713,10 +887,11 @@ pub trait ShapeDistanceExtractor: core::AlgorithmTrait + crate::shape::ShapeDist
 	/// * contour2: Contour defining second shape.
 	#[inline]
 	fn compute_distance(&mut self, contour1: &dyn core::ToInputArray, contour2: &dyn core::ToInputArray) -> Result<f32> {
+		crate::core::validity::check("ShapeDistanceExtractor", self.as_raw_mut_ShapeDistanceExtractor())?;
 		input_array_arg!(contour1);
 		input_array_arg!(contour2);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ShapeDistanceExtractor_computeDistance_const__InputArrayR_const__InputArrayR(self.as_raw_mut_ShapeDistanceExtractor(), contour1.as_raw__InputArray(), contour2.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ShapeDistanceExtractor_computeDistance_const__InputArrayR_const__InputArrayR(self.as_raw_mut_ShapeDistanceExtractor(), contour1.as_raw__InputArray(), contour2.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -743,10 +918,11 @@ pub trait ShapeTransformerConst: core::AlgorithmTraitConst {
 	/// * border_value: Scalar()
 	#[inline]
 	fn warp_image(&self, transforming_image: &dyn core::ToInputArray, output: &mut dyn core::ToOutputArray, flags: i32, border_mode: i32, border_value: core::Scalar) -> Result<()> {
+		crate::core::validity::check("ShapeTransformer", self.as_raw_ShapeTransformer())?;
 		input_array_arg!(transforming_image);
 		output_array_arg!(output);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ShapeTransformer_warpImage_const_const__InputArrayR_const__OutputArrayR_int_int_const_ScalarR(self.as_raw_ShapeTransformer(), transforming_image.as_raw__InputArray(), output.as_raw__OutputArray(), flags, border_mode, &border_value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ShapeTransformer_warpImage_const_const__InputArrayR_const__OutputArrayR_int_int_const_ScalarR(self.as_raw_ShapeTransformer(), transforming_image.as_raw__InputArray(), output.as_raw__OutputArray(), flags, border_mode, &border_value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -754,6 +930,38 @@ pub trait ShapeTransformerConst: core::AlgorithmTraitConst {
 	
 }
 
+/// Builder for `ShapeTransformerConst::warp_image()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct ShapeTransformer_WarpImageBuilder<'a, T: ?Sized + ShapeTransformerConst> {
+	instance: &'a T,
+	transforming_image: &'a dyn core::ToInputArray,
+	output: &'a mut dyn core::ToOutputArray,
+	flags: i32,
+	border_mode: i32,
+	border_value: core::Scalar,
+}
+
+impl<'a, T: ?Sized + ShapeTransformerConst> ShapeTransformer_WarpImageBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a T, transforming_image: &'a dyn core::ToInputArray, output: &'a mut dyn core::ToOutputArray, flags: i32, border_mode: i32) -> Self {
+		Self { instance, transforming_image, output, flags, border_mode, border_value: core::Scalar::default() }
+	}
+	
+	/// C++ default: `Scalar()`
+	#[inline]
+	pub fn with_border_value(mut self, border_value: core::Scalar) -> Self {
+		self.border_value = border_value;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		ShapeTransformerConst::warp_image(self.instance, self.transforming_image, self.output, self.flags, self.border_mode, self.border_value)
+	}
+}
+
 pub trait ShapeTransformer: core::AlgorithmTrait + crate::shape::ShapeTransformerConst {
 	fn as_raw_mut_ShapeTransformer(&mut self) -> *mut c_void;
 
@@ -765,10 +973,11 @@ pub trait ShapeTransformer: core::AlgorithmTrait + crate::shape::ShapeTransforme
 	/// * matches: Standard vector of Matches between points.
 	#[inline]
 	fn estimate_transformation(&mut self, transforming_shape: &dyn core::ToInputArray, target_shape: &dyn core::ToInputArray, matches: &mut core::Vector<core::DMatch>) -> Result<()> {
+		crate::core::validity::check("ShapeTransformer", self.as_raw_mut_ShapeTransformer())?;
 		input_array_arg!(transforming_shape);
 		input_array_arg!(target_shape);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ShapeTransformer_estimateTransformation_const__InputArrayR_const__InputArrayR_vector_DMatch_R(self.as_raw_mut_ShapeTransformer(), transforming_shape.as_raw__InputArray(), target_shape.as_raw__InputArray(), matches.as_raw_mut_VectorOfDMatch(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ShapeTransformer_estimateTransformation_const__InputArrayR_const__InputArrayR_vector_DMatch_R(self.as_raw_mut_ShapeTransformer(), transforming_shape.as_raw__InputArray(), target_shape.as_raw__InputArray(), matches.as_raw_mut_VectorOfDMatch(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -784,10 +993,11 @@ pub trait ShapeTransformer: core::AlgorithmTrait + crate::shape::ShapeTransforme
 	/// * output: noArray()
 	#[inline]
 	fn apply_transformation(&mut self, input: &dyn core::ToInputArray, output: &mut dyn core::ToOutputArray) -> Result<f32> {
+		crate::core::validity::check("ShapeTransformer", self.as_raw_mut_ShapeTransformer())?;
 		input_array_arg!(input);
 		output_array_arg!(output);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ShapeTransformer_applyTransformation_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_ShapeTransformer(), input.as_raw__InputArray(), output.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ShapeTransformer_applyTransformation_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_ShapeTransformer(), input.as_raw__InputArray(), output.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -804,8 +1014,9 @@ pub trait ThinPlateSplineShapeTransformerConst: crate::shape::ShapeTransformerCo
 
 	#[inline]
 	fn get_regularization_parameter(&self) -> Result<f64> {
+		crate::core::validity::check("ThinPlateSplineShapeTransformer", self.as_raw_ThinPlateSplineShapeTransformer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ThinPlateSplineShapeTransformer_getRegularizationParameter_const(self.as_raw_ThinPlateSplineShapeTransformer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ThinPlateSplineShapeTransformer_getRegularizationParameter_const(self.as_raw_ThinPlateSplineShapeTransformer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -823,8 +1034,9 @@ pub trait ThinPlateSplineShapeTransformer: crate::shape::ShapeTransformer + crat
 	/// * beta: value of the regularization parameter.
 	#[inline]
 	fn set_regularization_parameter(&mut self, beta: f64) -> Result<()> {
+		crate::core::validity::check("ThinPlateSplineShapeTransformer", self.as_raw_mut_ThinPlateSplineShapeTransformer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ThinPlateSplineShapeTransformer_setRegularizationParameter_double(self.as_raw_mut_ThinPlateSplineShapeTransformer(), beta, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ThinPlateSplineShapeTransformer_setRegularizationParameter_double(self.as_raw_mut_ThinPlateSplineShapeTransformer(), beta, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
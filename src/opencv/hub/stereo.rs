@@ -30,7 +30,7 @@ pub const CV_STAR_KERNEL: i32 = 6;
 #[inline]
 pub fn census_transform(image1: &core::Mat, image2: &core::Mat, kernel_size: i32, dist1: &mut core::Mat, dist2: &mut core::Mat, typ: i32) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_stereo_censusTransform_const_MatR_const_MatR_int_MatR_MatR_const_int(image1.as_raw_Mat(), image2.as_raw_Mat(), kernel_size, dist1.as_raw_mut_Mat(), dist2.as_raw_mut_Mat(), typ, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_stereo_censusTransform_const_MatR_const_MatR_int_MatR_MatR_const_int(image1.as_raw_Mat(), image2.as_raw_Mat(), kernel_size, dist1.as_raw_mut_Mat(), dist2.as_raw_mut_Mat(), typ, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -40,7 +40,7 @@ pub fn census_transform(image1: &core::Mat, image2: &core::Mat, kernel_size: i32
 #[inline]
 pub fn census_transform_1(image1: &core::Mat, kernel_size: i32, dist1: &mut core::Mat, typ: i32) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_stereo_censusTransform_const_MatR_int_MatR_const_int(image1.as_raw_Mat(), kernel_size, dist1.as_raw_mut_Mat(), typ, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_stereo_censusTransform_const_MatR_int_MatR_const_int(image1.as_raw_Mat(), kernel_size, dist1.as_raw_mut_Mat(), typ, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -57,12 +57,47 @@ pub fn census_transform_1(image1: &core::Mat, kernel_size: i32, dist1: &mut core
 #[inline]
 pub fn modified_census_transform(img1: &core::Mat, img2: &core::Mat, kernel_size: i32, dist1: &mut core::Mat, dist2: &mut core::Mat, typ: i32, t: i32, integral_image1: &core::Mat, integral_image2: &core::Mat) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_stereo_modifiedCensusTransform_const_MatR_const_MatR_int_MatR_MatR_const_int_int_const_MatR_const_MatR(img1.as_raw_Mat(), img2.as_raw_Mat(), kernel_size, dist1.as_raw_mut_Mat(), dist2.as_raw_mut_Mat(), typ, t, integral_image1.as_raw_Mat(), integral_image2.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_stereo_modifiedCensusTransform_const_MatR_const_MatR_int_MatR_MatR_const_int_int_const_MatR_const_MatR(img1.as_raw_Mat(), img2.as_raw_Mat(), kernel_size, dist1.as_raw_mut_Mat(), dist2.as_raw_mut_Mat(), typ, t, integral_image1.as_raw_Mat(), integral_image2.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `modified_census_transform()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct ModifiedCensusTransformBuilder<'a> {
+	img1: &'a core::Mat,
+	img2: &'a core::Mat,
+	kernel_size: i32,
+	dist1: &'a mut core::Mat,
+	dist2: &'a mut core::Mat,
+	typ: i32,
+	t: i32,
+	integral_image1: &'a core::Mat,
+	integral_image2: &'a core::Mat,
+}
+
+impl<'a> ModifiedCensusTransformBuilder<'a> {
+	#[inline]
+	pub fn new(img1: &'a core::Mat, img2: &'a core::Mat, kernel_size: i32, dist1: &'a mut core::Mat, dist2: &'a mut core::Mat, typ: i32, integral_image1: &'a core::Mat, integral_image2: &'a core::Mat) -> Self {
+		Self { img1, img2, kernel_size, dist1, dist2, typ, t: 0, integral_image1, integral_image2 }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_t(mut self, t: i32) -> Self {
+		self.t = t;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		modified_census_transform(self.img1, self.img2, self.kernel_size, self.dist1, self.dist2, self.typ, self.t, self.integral_image1, self.integral_image2)
+	}
+}
+
 /// single version of modified census transform descriptor
 /// 
 /// ## C++ default parameters
@@ -71,17 +106,49 @@ pub fn modified_census_transform(img1: &core::Mat, img2: &core::Mat, kernel_size
 #[inline]
 pub fn modified_census_transform_1(img1: &core::Mat, kernel_size: i32, dist: &mut core::Mat, typ: i32, t: i32, integral_image: &core::Mat) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_stereo_modifiedCensusTransform_const_MatR_int_MatR_const_int_int_const_MatR(img1.as_raw_Mat(), kernel_size, dist.as_raw_mut_Mat(), typ, t, integral_image.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_stereo_modifiedCensusTransform_const_MatR_int_MatR_const_int_int_const_MatR(img1.as_raw_Mat(), kernel_size, dist.as_raw_mut_Mat(), typ, t, integral_image.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `modified_census_transform_1()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct ModifiedCensusTransform1Builder<'a> {
+	img1: &'a core::Mat,
+	kernel_size: i32,
+	dist: &'a mut core::Mat,
+	typ: i32,
+	t: i32,
+	integral_image: &'a core::Mat,
+}
+
+impl<'a> ModifiedCensusTransform1Builder<'a> {
+	#[inline]
+	pub fn new(img1: &'a core::Mat, kernel_size: i32, dist: &'a mut core::Mat, typ: i32, integral_image: &'a core::Mat) -> Self {
+		Self { img1, kernel_size, dist, typ, t: 0, integral_image }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_t(mut self, t: i32) -> Self {
+		self.t = t;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		modified_census_transform_1(self.img1, self.kernel_size, self.dist, self.typ, self.t, self.integral_image)
+	}
+}
+
 /// in a 9x9 kernel only certain positions are choosen
 #[inline]
 pub fn star_census_transform(img1: &core::Mat, img2: &core::Mat, kernel_size: i32, dist1: &mut core::Mat, dist2: &mut core::Mat) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_stereo_starCensusTransform_const_MatR_const_MatR_int_MatR_MatR(img1.as_raw_Mat(), img2.as_raw_Mat(), kernel_size, dist1.as_raw_mut_Mat(), dist2.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_stereo_starCensusTransform_const_MatR_const_MatR_int_MatR_MatR(img1.as_raw_Mat(), img2.as_raw_Mat(), kernel_size, dist1.as_raw_mut_Mat(), dist2.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -91,7 +158,7 @@ pub fn star_census_transform(img1: &core::Mat, img2: &core::Mat, kernel_size: i3
 #[inline]
 pub fn star_census_transform_1(img1: &core::Mat, kernel_size: i32, dist: &mut core::Mat) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_stereo_starCensusTransform_const_MatR_int_MatR(img1.as_raw_Mat(), kernel_size, dist.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_stereo_starCensusTransform_const_MatR_int_MatR(img1.as_raw_Mat(), kernel_size, dist.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -103,7 +170,7 @@ pub fn star_census_transform_1(img1: &core::Mat, kernel_size: i32, dist: &mut co
 #[inline]
 pub fn symetric_census_transform(img1: &core::Mat, img2: &core::Mat, kernel_size: i32, dist1: &mut core::Mat, dist2: &mut core::Mat, typ: i32) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_stereo_symetricCensusTransform_const_MatR_const_MatR_int_MatR_MatR_const_int(img1.as_raw_Mat(), img2.as_raw_Mat(), kernel_size, dist1.as_raw_mut_Mat(), dist2.as_raw_mut_Mat(), typ, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_stereo_symetricCensusTransform_const_MatR_const_MatR_int_MatR_MatR_const_int(img1.as_raw_Mat(), img2.as_raw_Mat(), kernel_size, dist1.as_raw_mut_Mat(), dist2.as_raw_mut_Mat(), typ, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -113,7 +180,7 @@ pub fn symetric_census_transform(img1: &core::Mat, img2: &core::Mat, kernel_size
 #[inline]
 pub fn symetric_census_transform_1(img1: &core::Mat, kernel_size: i32, dist1: &mut core::Mat, typ: i32) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_stereo_symetricCensusTransform_const_MatR_int_MatR_const_int(img1.as_raw_Mat(), kernel_size, dist1.as_raw_mut_Mat(), typ, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_stereo_symetricCensusTransform_const_MatR_int_MatR_const_int(img1.as_raw_Mat(), kernel_size, dist1.as_raw_mut_Mat(), typ, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -134,7 +201,7 @@ impl MatchQuasiDense {
 	#[inline]
 	pub fn default() -> Result<crate::stereo::MatchQuasiDense> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_stereo_MatchQuasiDense_MatchQuasiDense(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_stereo_MatchQuasiDense_MatchQuasiDense(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -193,12 +260,21 @@ pub trait QuasiDenseStereoConst {
 
 	#[inline]
 	fn param(&self) -> crate::stereo::PropagationParameters {
+		crate::core::validity::debug_check("QuasiDenseStereo", self.as_raw_QuasiDenseStereo());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_stereo_QuasiDenseStereo_getPropParam_const(self.as_raw_QuasiDenseStereo(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_stereo_QuasiDenseStereo_getPropParam_const(self.as_raw_QuasiDenseStereo(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [param](QuasiDenseStereoConst::param), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_param(&self) -> Result<crate::stereo::PropagationParameters> {
+		crate::core::validity::check("QuasiDenseStereo", self.as_raw_QuasiDenseStereo())?;
+		Ok(QuasiDenseStereoConst::param(self))
+	}
+	
 }
 
 pub trait QuasiDenseStereo: crate::stereo::QuasiDenseStereoConst {
@@ -206,7 +282,8 @@ pub trait QuasiDenseStereo: crate::stereo::QuasiDenseStereoConst {
 
 	#[inline]
 	fn set_param(&mut self, val: crate::stereo::PropagationParameters) {
-		let ret = unsafe { sys::cv_stereo_QuasiDenseStereo_setPropParam_PropagationParameters(self.as_raw_mut_QuasiDenseStereo(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("QuasiDenseStereo", self.as_raw_mut_QuasiDenseStereo());
+		let ret = unsafe { ffi_call!(cv_stereo_QuasiDenseStereo_setPropParam_PropagationParameters(self.as_raw_mut_QuasiDenseStereo(), val.opencv_as_extern())) };
 		ret
 	}
 	
@@ -225,12 +302,13 @@ pub trait QuasiDenseStereo: crate::stereo::QuasiDenseStereoConst {
 	/// Note: Loading different parameters will have an effect on the output. This is useful for tuning
 	/// in case of video processing.
 	/// ## See also
-	/// loadParameters
+	/// `load_parameters`
 	#[inline]
 	fn load_parameters(&mut self, filepath: &str) -> Result<i32> {
+		crate::core::validity::check("QuasiDenseStereo", self.as_raw_mut_QuasiDenseStereo())?;
 		extern_container_arg!(mut filepath);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_stereo_QuasiDenseStereo_loadParameters_String(self.as_raw_mut_QuasiDenseStereo(), filepath.opencv_as_extern_mut(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_stereo_QuasiDenseStereo_loadParameters_String(self.as_raw_mut_QuasiDenseStereo(), filepath.opencv_as_extern_mut(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -245,12 +323,13 @@ pub trait QuasiDenseStereo: crate::stereo::QuasiDenseStereoConst {
 	/// 
 	/// Note: This method can be used to generate a template file for tuning the class.
 	/// ## See also
-	/// loadParameters
+	/// `load_parameters`
 	#[inline]
 	fn save_parameters(&mut self, filepath: &str) -> Result<i32> {
+		crate::core::validity::check("QuasiDenseStereo", self.as_raw_mut_QuasiDenseStereo())?;
 		extern_container_arg!(mut filepath);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_stereo_QuasiDenseStereo_saveParameters_String(self.as_raw_mut_QuasiDenseStereo(), filepath.opencv_as_extern_mut(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_stereo_QuasiDenseStereo_saveParameters_String(self.as_raw_mut_QuasiDenseStereo(), filepath.opencv_as_extern_mut(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -258,15 +337,16 @@ pub trait QuasiDenseStereo: crate::stereo::QuasiDenseStereoConst {
 	
 	/// Get The sparse corresponding points.
 	/// ## Parameters
-	/// * sMatches:[out] A vector containing all sparse correspondences.
+	/// * sMatches:\[out\] A vector containing all sparse correspondences.
 	/// 
 	/// Note: The method clears the sMatches vector.
 	/// 
 	/// Note: The returned Match elements inside the sMatches vector, do not use corr member.
 	#[inline]
 	fn get_sparse_matches(&mut self, s_matches: &mut core::Vector<crate::stereo::MatchQuasiDense>) -> Result<()> {
+		crate::core::validity::check("QuasiDenseStereo", self.as_raw_mut_QuasiDenseStereo())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_stereo_QuasiDenseStereo_getSparseMatches_vector_MatchQuasiDense_R(self.as_raw_mut_QuasiDenseStereo(), s_matches.as_raw_mut_VectorOfMatchQuasiDense(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_stereo_QuasiDenseStereo_getSparseMatches_vector_MatchQuasiDense_R(self.as_raw_mut_QuasiDenseStereo(), s_matches.as_raw_mut_VectorOfMatchQuasiDense(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -274,15 +354,16 @@ pub trait QuasiDenseStereo: crate::stereo::QuasiDenseStereoConst {
 	
 	/// Get The dense corresponding points.
 	/// ## Parameters
-	/// * denseMatches:[out] A vector containing all dense matches.
+	/// * denseMatches:\[out\] A vector containing all dense matches.
 	/// 
 	/// Note: The method clears the denseMatches vector.
 	/// 
 	/// Note: The returned Match elements inside the sMatches vector, do not use corr member.
 	#[inline]
 	fn get_dense_matches(&mut self, dense_matches: &mut core::Vector<crate::stereo::MatchQuasiDense>) -> Result<()> {
+		crate::core::validity::check("QuasiDenseStereo", self.as_raw_mut_QuasiDenseStereo())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_stereo_QuasiDenseStereo_getDenseMatches_vector_MatchQuasiDense_R(self.as_raw_mut_QuasiDenseStereo(), dense_matches.as_raw_mut_VectorOfMatchQuasiDense(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_stereo_QuasiDenseStereo_getDenseMatches_vector_MatchQuasiDense_R(self.as_raw_mut_QuasiDenseStereo(), dense_matches.as_raw_mut_VectorOfMatchQuasiDense(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -299,12 +380,13 @@ pub trait QuasiDenseStereo: crate::stereo::QuasiDenseStereoConst {
 	/// 
 	/// Note: If input images are in color, the method assumes that are BGR and converts them to grayscale.
 	/// ## See also
-	/// sparseMatching
-	/// quasiDenseMatching
+	/// `sparse_matching`
+	/// `quasi_dense_matching`
 	#[inline]
 	fn process(&mut self, img_left: &core::Mat, img_right: &core::Mat) -> Result<()> {
+		crate::core::validity::check("QuasiDenseStereo", self.as_raw_mut_QuasiDenseStereo())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_stereo_QuasiDenseStereo_process_const_MatR_const_MatR(self.as_raw_mut_QuasiDenseStereo(), img_left.as_raw_Mat(), img_right.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_stereo_QuasiDenseStereo_process_const_MatR_const_MatR(self.as_raw_mut_QuasiDenseStereo(), img_left.as_raw_Mat(), img_right.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -320,8 +402,9 @@ pub trait QuasiDenseStereo: crate::stereo::QuasiDenseStereoConst {
 	/// Note: This method should be always called after process, otherwise the matches will not be correct.
 	#[inline]
 	fn get_match(&mut self, x: i32, y: i32) -> Result<core::Point2f> {
+		crate::core::validity::check("QuasiDenseStereo", self.as_raw_mut_QuasiDenseStereo())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_stereo_QuasiDenseStereo_getMatch_const_int_const_int(self.as_raw_mut_QuasiDenseStereo(), x, y, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_stereo_QuasiDenseStereo_getMatch_const_int_const_int(self.as_raw_mut_QuasiDenseStereo(), x, y, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -333,12 +416,13 @@ pub trait QuasiDenseStereo: crate::stereo::QuasiDenseStereoConst {
 	/// ## Returns
 	/// cv::Mat containing a the disparity image in grayscale.
 	/// ## See also
-	/// computeDisparity
-	/// quantizeDisparity
+	/// `compute_disparity`
+	/// `quantize_disparity`
 	#[inline]
 	fn get_disparity(&mut self) -> Result<core::Mat> {
+		crate::core::validity::check("QuasiDenseStereo", self.as_raw_mut_QuasiDenseStereo())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_stereo_QuasiDenseStereo_getDisparity(self.as_raw_mut_QuasiDenseStereo(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_stereo_QuasiDenseStereo_getDisparity(self.as_raw_mut_QuasiDenseStereo(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -354,7 +438,7 @@ impl dyn QuasiDenseStereo + '_ {
 	pub fn create(mono_img_size: core::Size, param_filepath: &str) -> Result<core::Ptr<dyn crate::stereo::QuasiDenseStereo>> {
 		extern_container_arg!(mut param_filepath);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_stereo_QuasiDenseStereo_create_Size_String(mono_img_size.opencv_as_extern(), param_filepath.opencv_as_extern_mut(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_stereo_QuasiDenseStereo_create_Size_String(mono_img_size.opencv_as_extern(), param_filepath.opencv_as_extern_mut(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::stereo::QuasiDenseStereo>::opencv_from_extern(ret) };
@@ -24,20 +24,20 @@
 //! There are currently 2 camera models implemented in stitching pipeline.
 //! 
 //! - _Homography model_ expecting perspective transformations between images
-//!   implemented in @ref cv::detail::BestOf2NearestMatcher cv::detail::HomographyBasedEstimator
+//!   implemented in cv::detail::BestOf2NearestMatcher cv::detail::HomographyBasedEstimator
 //!   cv::detail::BundleAdjusterReproj cv::detail::BundleAdjusterRay
 //! - _Affine model_ expecting affine transformation with 6 DOF or 4 DOF implemented in
-//!   @ref cv::detail::AffineBestOf2NearestMatcher cv::detail::AffineBasedEstimator
+//!   cv::detail::AffineBestOf2NearestMatcher cv::detail::AffineBasedEstimator
 //!   cv::detail::BundleAdjusterAffine cv::detail::BundleAdjusterAffinePartial cv::AffineWarper
 //! 
 //! Homography model is useful for creating photo panoramas captured by camera,
 //! while affine-based model can be used to stitch scans and object captured by
-//! specialized devices. Use @ref cv::Stitcher::create to get preconfigured pipeline for one
+//! specialized devices. Use `Stitcher::create` to get preconfigured pipeline for one
 //! of those models.
 //! 
 //! 
 //! Note:
-//! Certain detailed settings of @ref cv::Stitcher might not make sense. Especially
+//! Certain detailed settings of `Stitcher` might not make sense. Especially
 //! you should not mix classes implementing affine model and classes implementing
 //! Homography model, as they work with different transformations.
 //!    # Features Finding and Images Matching
@@ -132,7 +132,7 @@ opencv_type_enum! { crate::stitching::Stitcher_Status }
 #[inline]
 pub fn auto_detect_wave_correct_kind(rmats: &core::Vector<core::Mat>) -> Result<crate::stitching::Detail_WaveCorrectKind> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_detail_autoDetectWaveCorrectKind_const_vector_Mat_R(rmats.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_detail_autoDetectWaveCorrectKind_const_vector_Mat_R(rmats.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -153,7 +153,7 @@ pub fn compute_image_features2(features_finder: &core::Ptr<crate::features2d::Fe
 	input_array_arg!(image);
 	input_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_detail_computeImageFeatures_const_Ptr_Feature2D_R_const__InputArrayR_ImageFeaturesR_const__InputArrayR(features_finder.as_raw_PtrOfFeature2D(), image.as_raw__InputArray(), features.as_raw_mut_Detail_ImageFeatures(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_detail_computeImageFeatures_const_Ptr_Feature2D_R_const__InputArrayR_ImageFeaturesR_const__InputArrayR(features_finder.as_raw_PtrOfFeature2D(), image.as_raw__InputArray(), features.as_raw_mut_Detail_ImageFeatures(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -174,7 +174,7 @@ pub fn compute_image_features(features_finder: &core::Ptr<crate::features2d::Fea
 	input_array_arg!(images);
 	input_array_arg!(masks);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_detail_computeImageFeatures_const_Ptr_Feature2D_R_const__InputArrayR_vector_ImageFeatures_R_const__InputArrayR(features_finder.as_raw_PtrOfFeature2D(), images.as_raw__InputArray(), features.as_raw_mut_VectorOfDetail_ImageFeatures(), masks.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_detail_computeImageFeatures_const_Ptr_Feature2D_R_const__InputArrayR_vector_ImageFeatures_R_const__InputArrayR(features_finder.as_raw_PtrOfFeature2D(), images.as_raw__InputArray(), features.as_raw_mut_VectorOfDetail_ImageFeatures(), masks.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -184,7 +184,7 @@ pub fn compute_image_features(features_finder: &core::Ptr<crate::features2d::Fea
 pub fn create_laplace_pyr_gpu(img: &dyn core::ToInputArray, num_levels: i32, pyr: &mut core::Vector<core::UMat>) -> Result<()> {
 	input_array_arg!(img);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_detail_createLaplacePyrGpu_const__InputArrayR_int_vector_UMat_R(img.as_raw__InputArray(), num_levels, pyr.as_raw_mut_VectorOfUMat(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_detail_createLaplacePyrGpu_const__InputArrayR_int_vector_UMat_R(img.as_raw__InputArray(), num_levels, pyr.as_raw_mut_VectorOfUMat(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -194,7 +194,7 @@ pub fn create_laplace_pyr_gpu(img: &dyn core::ToInputArray, num_levels: i32, pyr
 pub fn create_laplace_pyr(img: &dyn core::ToInputArray, num_levels: i32, pyr: &mut core::Vector<core::UMat>) -> Result<()> {
 	input_array_arg!(img);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_detail_createLaplacePyr_const__InputArrayR_int_vector_UMat_R(img.as_raw__InputArray(), num_levels, pyr.as_raw_mut_VectorOfUMat(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_detail_createLaplacePyr_const__InputArrayR_int_vector_UMat_R(img.as_raw__InputArray(), num_levels, pyr.as_raw_mut_VectorOfUMat(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -205,7 +205,7 @@ pub fn create_weight_map(mask: &dyn core::ToInputArray, sharpness: f32, weight:
 	input_array_arg!(mask);
 	input_output_array_arg!(weight);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_detail_createWeightMap_const__InputArrayR_float_const__InputOutputArrayR(mask.as_raw__InputArray(), sharpness, weight.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_detail_createWeightMap_const__InputArrayR_float_const__InputOutputArrayR(mask.as_raw__InputArray(), sharpness, weight.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -214,7 +214,7 @@ pub fn create_weight_map(mask: &dyn core::ToInputArray, sharpness: f32, weight:
 #[inline]
 pub fn find_max_spanning_tree(num_images: i32, pairwise_matches: &core::Vector<crate::stitching::Detail_MatchesInfo>, span_tree: &mut crate::stitching::Detail_Graph, centers: &mut core::Vector<i32>) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_detail_findMaxSpanningTree_int_const_vector_MatchesInfo_R_GraphR_vector_int_R(num_images, pairwise_matches.as_raw_VectorOfDetail_MatchesInfo(), span_tree.as_raw_mut_Detail_Graph(), centers.as_raw_mut_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_detail_findMaxSpanningTree_int_const_vector_MatchesInfo_R_GraphR_vector_int_R(num_images, pairwise_matches.as_raw_VectorOfDetail_MatchesInfo(), span_tree.as_raw_mut_Detail_Graph(), centers.as_raw_mut_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -223,7 +223,7 @@ pub fn find_max_spanning_tree(num_images: i32, pairwise_matches: &core::Vector<c
 #[inline]
 pub fn leave_biggest_component(features: &mut core::Vector<crate::stitching::Detail_ImageFeatures>, pairwise_matches: &mut core::Vector<crate::stitching::Detail_MatchesInfo>, conf_threshold: f32) -> Result<core::Vector<i32>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_detail_leaveBiggestComponent_vector_ImageFeatures_R_vector_MatchesInfo_R_float(features.as_raw_mut_VectorOfDetail_ImageFeatures(), pairwise_matches.as_raw_mut_VectorOfDetail_MatchesInfo(), conf_threshold, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_detail_leaveBiggestComponent_vector_ImageFeatures_R_vector_MatchesInfo_R_float(features.as_raw_mut_VectorOfDetail_ImageFeatures(), pairwise_matches.as_raw_mut_VectorOfDetail_MatchesInfo(), conf_threshold, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Vector::<i32>::opencv_from_extern(ret) };
@@ -234,7 +234,7 @@ pub fn leave_biggest_component(features: &mut core::Vector<crate::stitching::Det
 #[inline]
 pub fn matches_graph_as_string(pathes: &mut core::Vector<String>, pairwise_matches: &mut core::Vector<crate::stitching::Detail_MatchesInfo>, conf_threshold: f32) -> Result<String> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_detail_matchesGraphAsString_vector_String_R_vector_MatchesInfo_R_float(pathes.as_raw_mut_VectorOfString(), pairwise_matches.as_raw_mut_VectorOfDetail_MatchesInfo(), conf_threshold, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_detail_matchesGraphAsString_vector_String_R_vector_MatchesInfo_R_float(pathes.as_raw_mut_VectorOfString(), pairwise_matches.as_raw_mut_VectorOfDetail_MatchesInfo(), conf_threshold, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { String::opencv_from_extern(ret) };
@@ -247,7 +247,7 @@ pub fn normalize_using_weight_map(weight: &dyn core::ToInputArray, src: &mut dyn
 	input_array_arg!(weight);
 	input_output_array_arg!(src);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_detail_normalizeUsingWeightMap_const__InputArrayR_const__InputOutputArrayR(weight.as_raw__InputArray(), src.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_detail_normalizeUsingWeightMap_const__InputArrayR_const__InputOutputArrayR(weight.as_raw__InputArray(), src.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -257,7 +257,7 @@ pub fn normalize_using_weight_map(weight: &dyn core::ToInputArray, src: &mut dyn
 #[inline]
 pub fn overlap_roi(tl1: core::Point, tl2: core::Point, sz1: core::Size, sz2: core::Size, roi: &mut core::Rect) -> Result<bool> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_detail_overlapRoi_Point_Point_Size_Size_RectR(tl1.opencv_as_extern(), tl2.opencv_as_extern(), sz1.opencv_as_extern(), sz2.opencv_as_extern(), roi, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_detail_overlapRoi_Point_Point_Size_Size_RectR(tl1.opencv_as_extern(), tl2.opencv_as_extern(), sz1.opencv_as_extern(), sz2.opencv_as_extern(), roi, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -266,7 +266,7 @@ pub fn overlap_roi(tl1: core::Point, tl2: core::Point, sz1: core::Size, sz2: cor
 #[inline]
 pub fn restore_image_from_laplace_pyr_gpu(pyr: &mut core::Vector<core::UMat>) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_detail_restoreImageFromLaplacePyrGpu_vector_UMat_R(pyr.as_raw_mut_VectorOfUMat(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_detail_restoreImageFromLaplacePyrGpu_vector_UMat_R(pyr.as_raw_mut_VectorOfUMat(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -275,7 +275,7 @@ pub fn restore_image_from_laplace_pyr_gpu(pyr: &mut core::Vector<core::UMat>) ->
 #[inline]
 pub fn restore_image_from_laplace_pyr(pyr: &mut core::Vector<core::UMat>) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_detail_restoreImageFromLaplacePyr_vector_UMat_R(pyr.as_raw_mut_VectorOfUMat(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_detail_restoreImageFromLaplacePyr_vector_UMat_R(pyr.as_raw_mut_VectorOfUMat(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -284,7 +284,7 @@ pub fn restore_image_from_laplace_pyr(pyr: &mut core::Vector<core::UMat>) -> Res
 #[inline]
 pub fn result_roi_intersection(corners: &core::Vector<core::Point>, sizes: &core::Vector<core::Size>) -> Result<core::Rect> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_detail_resultRoiIntersection_const_vector_Point_R_const_vector_Size_R(corners.as_raw_VectorOfPoint(), sizes.as_raw_VectorOfSize(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_detail_resultRoiIntersection_const_vector_Point_R_const_vector_Size_R(corners.as_raw_VectorOfPoint(), sizes.as_raw_VectorOfSize(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -293,7 +293,7 @@ pub fn result_roi_intersection(corners: &core::Vector<core::Point>, sizes: &core
 #[inline]
 pub fn result_roi_1(corners: &core::Vector<core::Point>, sizes: &core::Vector<core::Size>) -> Result<core::Rect> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_detail_resultRoi_const_vector_Point_R_const_vector_Size_R(corners.as_raw_VectorOfPoint(), sizes.as_raw_VectorOfSize(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_detail_resultRoi_const_vector_Point_R_const_vector_Size_R(corners.as_raw_VectorOfPoint(), sizes.as_raw_VectorOfSize(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -302,7 +302,7 @@ pub fn result_roi_1(corners: &core::Vector<core::Point>, sizes: &core::Vector<co
 #[inline]
 pub fn result_roi(corners: &core::Vector<core::Point>, images: &core::Vector<core::UMat>) -> Result<core::Rect> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_detail_resultRoi_const_vector_Point_R_const_vector_UMat_R(corners.as_raw_VectorOfPoint(), images.as_raw_VectorOfUMat(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_detail_resultRoi_const_vector_Point_R_const_vector_UMat_R(corners.as_raw_VectorOfPoint(), images.as_raw_VectorOfUMat(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -311,7 +311,7 @@ pub fn result_roi(corners: &core::Vector<core::Point>, images: &core::Vector<cor
 #[inline]
 pub fn result_tl(corners: &core::Vector<core::Point>) -> Result<core::Point> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_detail_resultTl_const_vector_Point_R(corners.as_raw_VectorOfPoint(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_detail_resultTl_const_vector_Point_R(corners.as_raw_VectorOfPoint(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -320,7 +320,7 @@ pub fn result_tl(corners: &core::Vector<core::Point>) -> Result<core::Point> {
 #[inline]
 pub fn select_random_subset(count: i32, size: i32, subset: &mut core::Vector<i32>) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_detail_selectRandomSubset_int_int_vector_int_R(count, size, subset.as_raw_mut_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_detail_selectRandomSubset_int_int_vector_int_R(count, size, subset.as_raw_mut_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -329,7 +329,7 @@ pub fn select_random_subset(count: i32, size: i32, subset: &mut core::Vector<i32
 #[inline]
 pub fn stitching_log_level() -> Result<i32> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_detail_stitchingLogLevel(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_detail_stitchingLogLevel(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -343,7 +343,7 @@ pub fn stitching_log_level() -> Result<i32> {
 #[inline]
 pub fn wave_correct(rmats: &mut core::Vector<core::Mat>, kind: crate::stitching::Detail_WaveCorrectKind) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_detail_waveCorrect_vector_Mat_R_WaveCorrectKind(rmats.as_raw_mut_VectorOfMat(), kind, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_detail_waveCorrect_vector_Mat_R_WaveCorrectKind(rmats.as_raw_mut_VectorOfMat(), kind, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -357,8 +357,9 @@ pub trait AffineWarperTraitConst: crate::stitching::WarperCreatorConst {
 
 	#[inline]
 	fn create(&self, scale: f32) -> Result<core::Ptr<dyn crate::stitching::Detail_RotationWarper>> {
+		crate::core::validity::check("AffineWarper", self.as_raw_AffineWarper())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_AffineWarper_create_const_float(self.as_raw_AffineWarper(), scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_AffineWarper_create_const_float(self.as_raw_AffineWarper(), scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::stitching::Detail_RotationWarper>::opencv_from_extern(ret) };
@@ -384,7 +385,10 @@ opencv_type_boxed! { AffineWarper }
 impl Drop for AffineWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_AffineWarper_delete(instance: *mut c_void); }
-		unsafe { cv_AffineWarper_delete(self.as_raw_mut_AffineWarper()) };
+		let ptr = self.as_raw_mut_AffineWarper();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_AffineWarper_delete(ptr) };
 	}
 }
 
@@ -414,8 +418,9 @@ pub trait CompressedRectilinearPortraitWarperTraitConst: crate::stitching::Warpe
 
 	#[inline]
 	fn create(&self, scale: f32) -> Result<core::Ptr<dyn crate::stitching::Detail_RotationWarper>> {
+		crate::core::validity::check("CompressedRectilinearPortraitWarper", self.as_raw_CompressedRectilinearPortraitWarper())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CompressedRectilinearPortraitWarper_create_const_float(self.as_raw_CompressedRectilinearPortraitWarper(), scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CompressedRectilinearPortraitWarper_create_const_float(self.as_raw_CompressedRectilinearPortraitWarper(), scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::stitching::Detail_RotationWarper>::opencv_from_extern(ret) };
@@ -438,7 +443,10 @@ opencv_type_boxed! { CompressedRectilinearPortraitWarper }
 impl Drop for CompressedRectilinearPortraitWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CompressedRectilinearPortraitWarper_delete(instance: *mut c_void); }
-		unsafe { cv_CompressedRectilinearPortraitWarper_delete(self.as_raw_mut_CompressedRectilinearPortraitWarper()) };
+		let ptr = self.as_raw_mut_CompressedRectilinearPortraitWarper();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_CompressedRectilinearPortraitWarper_delete(ptr) };
 	}
 }
 
@@ -467,7 +475,7 @@ impl CompressedRectilinearPortraitWarper {
 	#[inline]
 	pub fn new(a: f32, b: f32) -> Result<crate::stitching::CompressedRectilinearPortraitWarper> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CompressedRectilinearPortraitWarper_CompressedRectilinearPortraitWarper_float_float(a, b, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CompressedRectilinearPortraitWarper_CompressedRectilinearPortraitWarper_float_float(a, b, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::CompressedRectilinearPortraitWarper::opencv_from_extern(ret) };
@@ -481,8 +489,9 @@ pub trait CompressedRectilinearWarperTraitConst: crate::stitching::WarperCreator
 
 	#[inline]
 	fn create(&self, scale: f32) -> Result<core::Ptr<dyn crate::stitching::Detail_RotationWarper>> {
+		crate::core::validity::check("CompressedRectilinearWarper", self.as_raw_CompressedRectilinearWarper())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CompressedRectilinearWarper_create_const_float(self.as_raw_CompressedRectilinearWarper(), scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CompressedRectilinearWarper_create_const_float(self.as_raw_CompressedRectilinearWarper(), scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::stitching::Detail_RotationWarper>::opencv_from_extern(ret) };
@@ -505,7 +514,10 @@ opencv_type_boxed! { CompressedRectilinearWarper }
 impl Drop for CompressedRectilinearWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CompressedRectilinearWarper_delete(instance: *mut c_void); }
-		unsafe { cv_CompressedRectilinearWarper_delete(self.as_raw_mut_CompressedRectilinearWarper()) };
+		let ptr = self.as_raw_mut_CompressedRectilinearWarper();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_CompressedRectilinearWarper_delete(ptr) };
 	}
 }
 
@@ -534,7 +546,7 @@ impl CompressedRectilinearWarper {
 	#[inline]
 	pub fn new(a: f32, b: f32) -> Result<crate::stitching::CompressedRectilinearWarper> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CompressedRectilinearWarper_CompressedRectilinearWarper_float_float(a, b, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CompressedRectilinearWarper_CompressedRectilinearWarper_float_float(a, b, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::CompressedRectilinearWarper::opencv_from_extern(ret) };
@@ -551,8 +563,9 @@ pub trait CylindricalWarperTraitConst: crate::stitching::WarperCreatorConst {
 
 	#[inline]
 	fn create(&self, scale: f32) -> Result<core::Ptr<dyn crate::stitching::Detail_RotationWarper>> {
+		crate::core::validity::check("CylindricalWarper", self.as_raw_CylindricalWarper())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CylindricalWarper_create_const_float(self.as_raw_CylindricalWarper(), scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CylindricalWarper_create_const_float(self.as_raw_CylindricalWarper(), scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::stitching::Detail_RotationWarper>::opencv_from_extern(ret) };
@@ -578,7 +591,10 @@ opencv_type_boxed! { CylindricalWarper }
 impl Drop for CylindricalWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CylindricalWarper_delete(instance: *mut c_void); }
-		unsafe { cv_CylindricalWarper_delete(self.as_raw_mut_CylindricalWarper()) };
+		let ptr = self.as_raw_mut_CylindricalWarper();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_CylindricalWarper_delete(ptr) };
 	}
 }
 
@@ -608,8 +624,9 @@ pub trait CylindricalWarperGpuTraitConst: crate::stitching::WarperCreatorConst {
 
 	#[inline]
 	fn create(&self, scale: f32) -> Result<core::Ptr<dyn crate::stitching::Detail_RotationWarper>> {
+		crate::core::validity::check("CylindricalWarperGpu", self.as_raw_CylindricalWarperGpu())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_CylindricalWarperGpu_create_const_float(self.as_raw_CylindricalWarperGpu(), scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_CylindricalWarperGpu_create_const_float(self.as_raw_CylindricalWarperGpu(), scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::stitching::Detail_RotationWarper>::opencv_from_extern(ret) };
@@ -632,7 +649,10 @@ opencv_type_boxed! { CylindricalWarperGpu }
 impl Drop for CylindricalWarperGpu {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CylindricalWarperGpu_delete(instance: *mut c_void); }
-		unsafe { cv_CylindricalWarperGpu_delete(self.as_raw_mut_CylindricalWarperGpu()) };
+		let ptr = self.as_raw_mut_CylindricalWarperGpu();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_CylindricalWarperGpu_delete(ptr) };
 	}
 }
 
@@ -662,8 +682,9 @@ pub trait FisheyeWarperTraitConst: crate::stitching::WarperCreatorConst {
 
 	#[inline]
 	fn create(&self, scale: f32) -> Result<core::Ptr<dyn crate::stitching::Detail_RotationWarper>> {
+		crate::core::validity::check("FisheyeWarper", self.as_raw_FisheyeWarper())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FisheyeWarper_create_const_float(self.as_raw_FisheyeWarper(), scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FisheyeWarper_create_const_float(self.as_raw_FisheyeWarper(), scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::stitching::Detail_RotationWarper>::opencv_from_extern(ret) };
@@ -686,7 +707,10 @@ opencv_type_boxed! { FisheyeWarper }
 impl Drop for FisheyeWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FisheyeWarper_delete(instance: *mut c_void); }
-		unsafe { cv_FisheyeWarper_delete(self.as_raw_mut_FisheyeWarper()) };
+		let ptr = self.as_raw_mut_FisheyeWarper();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_FisheyeWarper_delete(ptr) };
 	}
 }
 
@@ -716,8 +740,9 @@ pub trait MercatorWarperTraitConst: crate::stitching::WarperCreatorConst {
 
 	#[inline]
 	fn create(&self, scale: f32) -> Result<core::Ptr<dyn crate::stitching::Detail_RotationWarper>> {
+		crate::core::validity::check("MercatorWarper", self.as_raw_MercatorWarper())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_MercatorWarper_create_const_float(self.as_raw_MercatorWarper(), scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_MercatorWarper_create_const_float(self.as_raw_MercatorWarper(), scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::stitching::Detail_RotationWarper>::opencv_from_extern(ret) };
@@ -740,7 +765,10 @@ opencv_type_boxed! { MercatorWarper }
 impl Drop for MercatorWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MercatorWarper_delete(instance: *mut c_void); }
-		unsafe { cv_MercatorWarper_delete(self.as_raw_mut_MercatorWarper()) };
+		let ptr = self.as_raw_mut_MercatorWarper();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_MercatorWarper_delete(ptr) };
 	}
 }
 
@@ -770,8 +798,9 @@ pub trait PaniniPortraitWarperTraitConst: crate::stitching::WarperCreatorConst {
 
 	#[inline]
 	fn create(&self, scale: f32) -> Result<core::Ptr<dyn crate::stitching::Detail_RotationWarper>> {
+		crate::core::validity::check("PaniniPortraitWarper", self.as_raw_PaniniPortraitWarper())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_PaniniPortraitWarper_create_const_float(self.as_raw_PaniniPortraitWarper(), scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_PaniniPortraitWarper_create_const_float(self.as_raw_PaniniPortraitWarper(), scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::stitching::Detail_RotationWarper>::opencv_from_extern(ret) };
@@ -794,7 +823,10 @@ opencv_type_boxed! { PaniniPortraitWarper }
 impl Drop for PaniniPortraitWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_PaniniPortraitWarper_delete(instance: *mut c_void); }
-		unsafe { cv_PaniniPortraitWarper_delete(self.as_raw_mut_PaniniPortraitWarper()) };
+		let ptr = self.as_raw_mut_PaniniPortraitWarper();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_PaniniPortraitWarper_delete(ptr) };
 	}
 }
 
@@ -823,7 +855,7 @@ impl PaniniPortraitWarper {
 	#[inline]
 	pub fn new(a: f32, b: f32) -> Result<crate::stitching::PaniniPortraitWarper> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_PaniniPortraitWarper_PaniniPortraitWarper_float_float(a, b, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_PaniniPortraitWarper_PaniniPortraitWarper_float_float(a, b, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::PaniniPortraitWarper::opencv_from_extern(ret) };
@@ -837,8 +869,9 @@ pub trait PaniniWarperTraitConst: crate::stitching::WarperCreatorConst {
 
 	#[inline]
 	fn create(&self, scale: f32) -> Result<core::Ptr<dyn crate::stitching::Detail_RotationWarper>> {
+		crate::core::validity::check("PaniniWarper", self.as_raw_PaniniWarper())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_PaniniWarper_create_const_float(self.as_raw_PaniniWarper(), scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_PaniniWarper_create_const_float(self.as_raw_PaniniWarper(), scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::stitching::Detail_RotationWarper>::opencv_from_extern(ret) };
@@ -861,7 +894,10 @@ opencv_type_boxed! { PaniniWarper }
 impl Drop for PaniniWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_PaniniWarper_delete(instance: *mut c_void); }
-		unsafe { cv_PaniniWarper_delete(self.as_raw_mut_PaniniWarper()) };
+		let ptr = self.as_raw_mut_PaniniWarper();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_PaniniWarper_delete(ptr) };
 	}
 }
 
@@ -890,7 +926,7 @@ impl PaniniWarper {
 	#[inline]
 	pub fn new(a: f32, b: f32) -> Result<crate::stitching::PaniniWarper> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_PaniniWarper_PaniniWarper_float_float(a, b, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_PaniniWarper_PaniniWarper_float_float(a, b, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::PaniniWarper::opencv_from_extern(ret) };
@@ -907,8 +943,9 @@ pub trait PlaneWarperTraitConst: crate::stitching::WarperCreatorConst {
 
 	#[inline]
 	fn create(&self, scale: f32) -> Result<core::Ptr<dyn crate::stitching::Detail_RotationWarper>> {
+		crate::core::validity::check("PlaneWarper", self.as_raw_PlaneWarper())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_PlaneWarper_create_const_float(self.as_raw_PlaneWarper(), scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_PlaneWarper_create_const_float(self.as_raw_PlaneWarper(), scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::stitching::Detail_RotationWarper>::opencv_from_extern(ret) };
@@ -934,7 +971,10 @@ opencv_type_boxed! { PlaneWarper }
 impl Drop for PlaneWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_PlaneWarper_delete(instance: *mut c_void); }
-		unsafe { cv_PlaneWarper_delete(self.as_raw_mut_PlaneWarper()) };
+		let ptr = self.as_raw_mut_PlaneWarper();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_PlaneWarper_delete(ptr) };
 	}
 }
 
@@ -964,8 +1004,9 @@ pub trait PlaneWarperGpuTraitConst: crate::stitching::WarperCreatorConst {
 
 	#[inline]
 	fn create(&self, scale: f32) -> Result<core::Ptr<dyn crate::stitching::Detail_RotationWarper>> {
+		crate::core::validity::check("PlaneWarperGpu", self.as_raw_PlaneWarperGpu())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_PlaneWarperGpu_create_const_float(self.as_raw_PlaneWarperGpu(), scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_PlaneWarperGpu_create_const_float(self.as_raw_PlaneWarperGpu(), scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::stitching::Detail_RotationWarper>::opencv_from_extern(ret) };
@@ -988,7 +1029,10 @@ opencv_type_boxed! { PlaneWarperGpu }
 impl Drop for PlaneWarperGpu {
 	fn drop(&mut self) {
 		extern "C" { fn cv_PlaneWarperGpu_delete(instance: *mut c_void); }
-		unsafe { cv_PlaneWarperGpu_delete(self.as_raw_mut_PlaneWarperGpu()) };
+		let ptr = self.as_raw_mut_PlaneWarperGpu();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_PlaneWarperGpu_delete(ptr) };
 	}
 }
 
@@ -1018,8 +1062,9 @@ pub trait PyRotationWarperTraitConst {
 
 	#[inline]
 	fn get_scale(&self) -> Result<f32> {
+		crate::core::validity::check("PyRotationWarper", self.as_raw_PyRotationWarper())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_PyRotationWarper_getScale_const(self.as_raw_PyRotationWarper(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_PyRotationWarper_getScale_const(self.as_raw_PyRotationWarper(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1040,10 +1085,11 @@ pub trait PyRotationWarperTrait: crate::stitching::PyRotationWarperTraitConst {
 	/// Projected point
 	#[inline]
 	fn warp_point(&mut self, pt: core::Point2f, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray) -> Result<core::Point2f> {
+		crate::core::validity::check("PyRotationWarper", self.as_raw_mut_PyRotationWarper())?;
 		input_array_arg!(k);
 		input_array_arg!(r);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_PyRotationWarper_warpPoint_const_Point2fR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_PyRotationWarper(), &pt, k.as_raw__InputArray(), r.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_PyRotationWarper_warpPoint_const_Point2fR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_PyRotationWarper(), &pt, k.as_raw__InputArray(), r.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1051,10 +1097,11 @@ pub trait PyRotationWarperTrait: crate::stitching::PyRotationWarperTraitConst {
 	
 	#[inline]
 	fn warp_point_backward(&mut self, pt: core::Point2f, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray) -> Result<core::Point2f> {
+		crate::core::validity::check("PyRotationWarper", self.as_raw_mut_PyRotationWarper())?;
 		input_array_arg!(k);
 		input_array_arg!(r);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_PyRotationWarper_warpPointBackward_const_Point2fR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_PyRotationWarper(), &pt, k.as_raw__InputArray(), r.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_PyRotationWarper_warpPointBackward_const_Point2fR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_PyRotationWarper(), &pt, k.as_raw__InputArray(), r.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1072,12 +1119,13 @@ pub trait PyRotationWarperTrait: crate::stitching::PyRotationWarperTraitConst {
 	/// Projected image minimum bounding box
 	#[inline]
 	fn build_maps(&mut self, src_size: core::Size, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, xmap: &mut dyn core::ToOutputArray, ymap: &mut dyn core::ToOutputArray) -> Result<core::Rect> {
+		crate::core::validity::check("PyRotationWarper", self.as_raw_mut_PyRotationWarper())?;
 		input_array_arg!(k);
 		input_array_arg!(r);
 		output_array_arg!(xmap);
 		output_array_arg!(ymap);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_PyRotationWarper_buildMaps_Size_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_PyRotationWarper(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), xmap.as_raw__OutputArray(), ymap.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_PyRotationWarper_buildMaps_Size_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_PyRotationWarper(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), xmap.as_raw__OutputArray(), ymap.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1096,12 +1144,13 @@ pub trait PyRotationWarperTrait: crate::stitching::PyRotationWarperTraitConst {
 	/// Project image top-left corner
 	#[inline]
 	fn warp(&mut self, src: &dyn core::ToInputArray, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, interp_mode: i32, border_mode: i32, dst: &mut dyn core::ToOutputArray) -> Result<core::Point> {
+		crate::core::validity::check("PyRotationWarper", self.as_raw_mut_PyRotationWarper())?;
 		input_array_arg!(src);
 		input_array_arg!(k);
 		input_array_arg!(r);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_PyRotationWarper_warp_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_int_const__OutputArrayR(self.as_raw_mut_PyRotationWarper(), src.as_raw__InputArray(), k.as_raw__InputArray(), r.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_PyRotationWarper_warp_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_int_const__OutputArrayR(self.as_raw_mut_PyRotationWarper(), src.as_raw__InputArray(), k.as_raw__InputArray(), r.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1119,12 +1168,13 @@ pub trait PyRotationWarperTrait: crate::stitching::PyRotationWarperTraitConst {
 	/// * dst: Backward-projected image
 	#[inline]
 	fn warp_backward(&mut self, src: &dyn core::ToInputArray, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, interp_mode: i32, border_mode: i32, dst_size: core::Size, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("PyRotationWarper", self.as_raw_mut_PyRotationWarper())?;
 		input_array_arg!(src);
 		input_array_arg!(k);
 		input_array_arg!(r);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_PyRotationWarper_warpBackward_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_int_Size_const__OutputArrayR(self.as_raw_mut_PyRotationWarper(), src.as_raw__InputArray(), k.as_raw__InputArray(), r.as_raw__InputArray(), interp_mode, border_mode, dst_size.opencv_as_extern(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_PyRotationWarper_warpBackward_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_int_Size_const__OutputArrayR(self.as_raw_mut_PyRotationWarper(), src.as_raw__InputArray(), k.as_raw__InputArray(), r.as_raw__InputArray(), interp_mode, border_mode, dst_size.opencv_as_extern(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1138,10 +1188,11 @@ pub trait PyRotationWarperTrait: crate::stitching::PyRotationWarperTraitConst {
 	/// Projected image minimum bounding box
 	#[inline]
 	fn warp_roi(&mut self, src_size: core::Size, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray) -> Result<core::Rect> {
+		crate::core::validity::check("PyRotationWarper", self.as_raw_mut_PyRotationWarper())?;
 		input_array_arg!(k);
 		input_array_arg!(r);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_PyRotationWarper_warpRoi_Size_const__InputArrayR_const__InputArrayR(self.as_raw_mut_PyRotationWarper(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_PyRotationWarper_warpRoi_Size_const__InputArrayR_const__InputArrayR(self.as_raw_mut_PyRotationWarper(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1149,8 +1200,9 @@ pub trait PyRotationWarperTrait: crate::stitching::PyRotationWarperTraitConst {
 	
 	#[inline]
 	fn set_scale(&mut self, unnamed: f32) -> Result<()> {
+		crate::core::validity::check("PyRotationWarper", self.as_raw_mut_PyRotationWarper())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_PyRotationWarper_setScale_float(self.as_raw_mut_PyRotationWarper(), unnamed, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_PyRotationWarper_setScale_float(self.as_raw_mut_PyRotationWarper(), unnamed, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1167,7 +1219,10 @@ opencv_type_boxed! { PyRotationWarper }
 impl Drop for PyRotationWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_PyRotationWarper_delete(instance: *mut c_void); }
-		unsafe { cv_PyRotationWarper_delete(self.as_raw_mut_PyRotationWarper()) };
+		let ptr = self.as_raw_mut_PyRotationWarper();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_PyRotationWarper_delete(ptr) };
 	}
 }
 
@@ -1186,7 +1241,7 @@ impl PyRotationWarper {
 	pub fn new(typ: &str, scale: f32) -> Result<crate::stitching::PyRotationWarper> {
 		extern_container_arg!(mut typ);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_PyRotationWarper_PyRotationWarper_String_float(typ.opencv_as_extern_mut(), scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_PyRotationWarper_PyRotationWarper_String_float(typ.opencv_as_extern_mut(), scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::PyRotationWarper::opencv_from_extern(ret) };
@@ -1196,7 +1251,7 @@ impl PyRotationWarper {
 	#[inline]
 	pub fn default() -> Result<crate::stitching::PyRotationWarper> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_PyRotationWarper_PyRotationWarper(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_PyRotationWarper_PyRotationWarper(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::PyRotationWarper::opencv_from_extern(ret) };
@@ -1211,8 +1266,9 @@ pub trait SphericalWarperTraitConst: crate::stitching::WarperCreatorConst {
 
 	#[inline]
 	fn create(&self, scale: f32) -> Result<core::Ptr<dyn crate::stitching::Detail_RotationWarper>> {
+		crate::core::validity::check("SphericalWarper", self.as_raw_SphericalWarper())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_SphericalWarper_create_const_float(self.as_raw_SphericalWarper(), scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_SphericalWarper_create_const_float(self.as_raw_SphericalWarper(), scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::stitching::Detail_RotationWarper>::opencv_from_extern(ret) };
@@ -1236,7 +1292,10 @@ opencv_type_boxed! { SphericalWarper }
 impl Drop for SphericalWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SphericalWarper_delete(instance: *mut c_void); }
-		unsafe { cv_SphericalWarper_delete(self.as_raw_mut_SphericalWarper()) };
+		let ptr = self.as_raw_mut_SphericalWarper();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_SphericalWarper_delete(ptr) };
 	}
 }
 
@@ -1266,8 +1325,9 @@ pub trait SphericalWarperGpuTraitConst: crate::stitching::WarperCreatorConst {
 
 	#[inline]
 	fn create(&self, scale: f32) -> Result<core::Ptr<dyn crate::stitching::Detail_RotationWarper>> {
+		crate::core::validity::check("SphericalWarperGpu", self.as_raw_SphericalWarperGpu())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_SphericalWarperGpu_create_const_float(self.as_raw_SphericalWarperGpu(), scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_SphericalWarperGpu_create_const_float(self.as_raw_SphericalWarperGpu(), scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::stitching::Detail_RotationWarper>::opencv_from_extern(ret) };
@@ -1290,7 +1350,10 @@ opencv_type_boxed! { SphericalWarperGpu }
 impl Drop for SphericalWarperGpu {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SphericalWarperGpu_delete(instance: *mut c_void); }
-		unsafe { cv_SphericalWarperGpu_delete(self.as_raw_mut_SphericalWarperGpu()) };
+		let ptr = self.as_raw_mut_SphericalWarperGpu();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_SphericalWarperGpu_delete(ptr) };
 	}
 }
 
@@ -1320,8 +1383,9 @@ pub trait StereographicWarperTraitConst: crate::stitching::WarperCreatorConst {
 
 	#[inline]
 	fn create(&self, scale: f32) -> Result<core::Ptr<dyn crate::stitching::Detail_RotationWarper>> {
+		crate::core::validity::check("StereographicWarper", self.as_raw_StereographicWarper())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_StereographicWarper_create_const_float(self.as_raw_StereographicWarper(), scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_StereographicWarper_create_const_float(self.as_raw_StereographicWarper(), scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::stitching::Detail_RotationWarper>::opencv_from_extern(ret) };
@@ -1344,7 +1408,10 @@ opencv_type_boxed! { StereographicWarper }
 impl Drop for StereographicWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_StereographicWarper_delete(instance: *mut c_void); }
-		unsafe { cv_StereographicWarper_delete(self.as_raw_mut_StereographicWarper()) };
+		let ptr = self.as_raw_mut_StereographicWarper();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_StereographicWarper_delete(ptr) };
 	}
 }
 
@@ -1388,8 +1455,9 @@ pub trait StitcherTraitConst {
 
 	#[inline]
 	fn registration_resol(&self) -> Result<f64> {
+		crate::core::validity::check("Stitcher", self.as_raw_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_registrationResol_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_registrationResol_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1397,8 +1465,9 @@ pub trait StitcherTraitConst {
 	
 	#[inline]
 	fn seam_estimation_resol(&self) -> Result<f64> {
+		crate::core::validity::check("Stitcher", self.as_raw_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_seamEstimationResol_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_seamEstimationResol_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1406,8 +1475,9 @@ pub trait StitcherTraitConst {
 	
 	#[inline]
 	fn compositing_resol(&self) -> Result<f64> {
+		crate::core::validity::check("Stitcher", self.as_raw_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_compositingResol_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_compositingResol_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1415,8 +1485,9 @@ pub trait StitcherTraitConst {
 	
 	#[inline]
 	fn pano_confidence_thresh(&self) -> Result<f64> {
+		crate::core::validity::check("Stitcher", self.as_raw_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_panoConfidenceThresh_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_panoConfidenceThresh_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1424,8 +1495,9 @@ pub trait StitcherTraitConst {
 	
 	#[inline]
 	fn wave_correction(&self) -> Result<bool> {
+		crate::core::validity::check("Stitcher", self.as_raw_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_waveCorrection_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_waveCorrection_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1433,8 +1505,9 @@ pub trait StitcherTraitConst {
 	
 	#[inline]
 	fn interpolation_flags(&self) -> Result<crate::imgproc::InterpolationFlags> {
+		crate::core::validity::check("Stitcher", self.as_raw_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_interpolationFlags_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_interpolationFlags_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1442,8 +1515,9 @@ pub trait StitcherTraitConst {
 	
 	#[inline]
 	fn wave_correct_kind(&self) -> Result<crate::stitching::Detail_WaveCorrectKind> {
+		crate::core::validity::check("Stitcher", self.as_raw_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_waveCorrectKind_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_waveCorrectKind_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1451,8 +1525,9 @@ pub trait StitcherTraitConst {
 	
 	#[inline]
 	fn features_finder(&self) -> Result<core::Ptr<crate::features2d::Feature2D>> {
+		crate::core::validity::check("Stitcher", self.as_raw_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_featuresFinder_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_featuresFinder_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::features2d::Feature2D>::opencv_from_extern(ret) };
@@ -1461,8 +1536,9 @@ pub trait StitcherTraitConst {
 	
 	#[inline]
 	fn features_matcher(&self) -> Result<core::Ptr<dyn crate::stitching::Detail_FeaturesMatcher>> {
+		crate::core::validity::check("Stitcher", self.as_raw_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_featuresMatcher_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_featuresMatcher_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::stitching::Detail_FeaturesMatcher>::opencv_from_extern(ret) };
@@ -1471,8 +1547,9 @@ pub trait StitcherTraitConst {
 	
 	#[inline]
 	fn matching_mask(&self) -> Result<core::UMat> {
+		crate::core::validity::check("Stitcher", self.as_raw_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_matchingMask_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_matchingMask_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::UMat::opencv_from_extern(ret) };
@@ -1481,8 +1558,9 @@ pub trait StitcherTraitConst {
 	
 	#[inline]
 	fn bundle_adjuster(&self) -> Result<core::Ptr<dyn crate::stitching::Detail_BundleAdjusterBase>> {
+		crate::core::validity::check("Stitcher", self.as_raw_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_bundleAdjuster_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_bundleAdjuster_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::stitching::Detail_BundleAdjusterBase>::opencv_from_extern(ret) };
@@ -1491,8 +1569,9 @@ pub trait StitcherTraitConst {
 	
 	#[inline]
 	fn estimator(&self) -> Result<core::Ptr<dyn crate::stitching::Detail_Estimator>> {
+		crate::core::validity::check("Stitcher", self.as_raw_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_estimator_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_estimator_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::stitching::Detail_Estimator>::opencv_from_extern(ret) };
@@ -1501,8 +1580,9 @@ pub trait StitcherTraitConst {
 	
 	#[inline]
 	fn warper(&self) -> Result<core::Ptr<dyn crate::stitching::WarperCreator>> {
+		crate::core::validity::check("Stitcher", self.as_raw_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_warper_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_warper_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::stitching::WarperCreator>::opencv_from_extern(ret) };
@@ -1511,8 +1591,9 @@ pub trait StitcherTraitConst {
 	
 	#[inline]
 	fn exposure_compensator(&self) -> Result<core::Ptr<dyn crate::stitching::Detail_ExposureCompensator>> {
+		crate::core::validity::check("Stitcher", self.as_raw_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_exposureCompensator_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_exposureCompensator_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::stitching::Detail_ExposureCompensator>::opencv_from_extern(ret) };
@@ -1521,8 +1602,9 @@ pub trait StitcherTraitConst {
 	
 	#[inline]
 	fn seam_finder(&self) -> Result<core::Ptr<dyn crate::stitching::Detail_SeamFinder>> {
+		crate::core::validity::check("Stitcher", self.as_raw_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_seamFinder_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_seamFinder_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::stitching::Detail_SeamFinder>::opencv_from_extern(ret) };
@@ -1531,8 +1613,9 @@ pub trait StitcherTraitConst {
 	
 	#[inline]
 	fn blender(&self) -> Result<core::Ptr<crate::stitching::Detail_Blender>> {
+		crate::core::validity::check("Stitcher", self.as_raw_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_blender_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_blender_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::stitching::Detail_Blender>::opencv_from_extern(ret) };
@@ -1541,8 +1624,9 @@ pub trait StitcherTraitConst {
 	
 	#[inline]
 	fn component(&self) -> Result<core::Vector<i32>> {
+		crate::core::validity::check("Stitcher", self.as_raw_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_component_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_component_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<i32>::opencv_from_extern(ret) };
@@ -1551,8 +1635,9 @@ pub trait StitcherTraitConst {
 	
 	#[inline]
 	fn cameras(&self) -> Result<core::Vector<crate::stitching::Detail_CameraParams>> {
+		crate::core::validity::check("Stitcher", self.as_raw_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_cameras_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_cameras_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<crate::stitching::Detail_CameraParams>::opencv_from_extern(ret) };
@@ -1561,8 +1646,9 @@ pub trait StitcherTraitConst {
 	
 	#[inline]
 	fn work_scale(&self) -> Result<f64> {
+		crate::core::validity::check("Stitcher", self.as_raw_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_workScale_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_workScale_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1570,8 +1656,9 @@ pub trait StitcherTraitConst {
 	
 	#[inline]
 	fn result_mask(&self) -> Result<core::UMat> {
+		crate::core::validity::check("Stitcher", self.as_raw_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_resultMask_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_resultMask_const(self.as_raw_Stitcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::UMat::opencv_from_extern(ret) };
@@ -1585,8 +1672,9 @@ pub trait StitcherTrait: crate::stitching::StitcherTraitConst {
 
 	#[inline]
 	fn set_registration_resol(&mut self, resol_mpx: f64) -> Result<()> {
+		crate::core::validity::check("Stitcher", self.as_raw_mut_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_setRegistrationResol_double(self.as_raw_mut_Stitcher(), resol_mpx, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_setRegistrationResol_double(self.as_raw_mut_Stitcher(), resol_mpx, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1594,8 +1682,9 @@ pub trait StitcherTrait: crate::stitching::StitcherTraitConst {
 	
 	#[inline]
 	fn set_seam_estimation_resol(&mut self, resol_mpx: f64) -> Result<()> {
+		crate::core::validity::check("Stitcher", self.as_raw_mut_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_setSeamEstimationResol_double(self.as_raw_mut_Stitcher(), resol_mpx, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_setSeamEstimationResol_double(self.as_raw_mut_Stitcher(), resol_mpx, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1603,8 +1692,9 @@ pub trait StitcherTrait: crate::stitching::StitcherTraitConst {
 	
 	#[inline]
 	fn set_compositing_resol(&mut self, resol_mpx: f64) -> Result<()> {
+		crate::core::validity::check("Stitcher", self.as_raw_mut_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_setCompositingResol_double(self.as_raw_mut_Stitcher(), resol_mpx, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_setCompositingResol_double(self.as_raw_mut_Stitcher(), resol_mpx, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1612,8 +1702,9 @@ pub trait StitcherTrait: crate::stitching::StitcherTraitConst {
 	
 	#[inline]
 	fn set_pano_confidence_thresh(&mut self, conf_thresh: f64) -> Result<()> {
+		crate::core::validity::check("Stitcher", self.as_raw_mut_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_setPanoConfidenceThresh_double(self.as_raw_mut_Stitcher(), conf_thresh, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_setPanoConfidenceThresh_double(self.as_raw_mut_Stitcher(), conf_thresh, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1621,8 +1712,9 @@ pub trait StitcherTrait: crate::stitching::StitcherTraitConst {
 	
 	#[inline]
 	fn set_wave_correction(&mut self, flag: bool) -> Result<()> {
+		crate::core::validity::check("Stitcher", self.as_raw_mut_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_setWaveCorrection_bool(self.as_raw_mut_Stitcher(), flag, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_setWaveCorrection_bool(self.as_raw_mut_Stitcher(), flag, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1630,8 +1722,9 @@ pub trait StitcherTrait: crate::stitching::StitcherTraitConst {
 	
 	#[inline]
 	fn set_interpolation_flags(&mut self, interp_flags: crate::imgproc::InterpolationFlags) -> Result<()> {
+		crate::core::validity::check("Stitcher", self.as_raw_mut_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_setInterpolationFlags_InterpolationFlags(self.as_raw_mut_Stitcher(), interp_flags, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_setInterpolationFlags_InterpolationFlags(self.as_raw_mut_Stitcher(), interp_flags, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1639,8 +1732,9 @@ pub trait StitcherTrait: crate::stitching::StitcherTraitConst {
 	
 	#[inline]
 	fn set_wave_correct_kind(&mut self, kind: crate::stitching::Detail_WaveCorrectKind) -> Result<()> {
+		crate::core::validity::check("Stitcher", self.as_raw_mut_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_setWaveCorrectKind_WaveCorrectKind(self.as_raw_mut_Stitcher(), kind, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_setWaveCorrectKind_WaveCorrectKind(self.as_raw_mut_Stitcher(), kind, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1648,8 +1742,9 @@ pub trait StitcherTrait: crate::stitching::StitcherTraitConst {
 	
 	#[inline]
 	fn features_finder_1(&mut self) -> Result<core::Ptr<crate::features2d::Feature2D>> {
+		crate::core::validity::check("Stitcher", self.as_raw_mut_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_featuresFinder(self.as_raw_mut_Stitcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_featuresFinder(self.as_raw_mut_Stitcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::features2d::Feature2D>::opencv_from_extern(ret) };
@@ -1658,8 +1753,9 @@ pub trait StitcherTrait: crate::stitching::StitcherTraitConst {
 	
 	#[inline]
 	fn set_features_finder(&mut self, mut features_finder: core::Ptr<crate::features2d::Feature2D>) -> Result<()> {
+		crate::core::validity::check("Stitcher", self.as_raw_mut_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_setFeaturesFinder_Ptr_Feature2D_(self.as_raw_mut_Stitcher(), features_finder.as_raw_mut_PtrOfFeature2D(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_setFeaturesFinder_Ptr_Feature2D_(self.as_raw_mut_Stitcher(), features_finder.as_raw_mut_PtrOfFeature2D(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1667,8 +1763,9 @@ pub trait StitcherTrait: crate::stitching::StitcherTraitConst {
 	
 	#[inline]
 	fn features_matcher_1(&mut self) -> Result<core::Ptr<dyn crate::stitching::Detail_FeaturesMatcher>> {
+		crate::core::validity::check("Stitcher", self.as_raw_mut_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_featuresMatcher(self.as_raw_mut_Stitcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_featuresMatcher(self.as_raw_mut_Stitcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::stitching::Detail_FeaturesMatcher>::opencv_from_extern(ret) };
@@ -1677,8 +1774,9 @@ pub trait StitcherTrait: crate::stitching::StitcherTraitConst {
 	
 	#[inline]
 	fn set_features_matcher(&mut self, mut features_matcher: core::Ptr<dyn crate::stitching::Detail_FeaturesMatcher>) -> Result<()> {
+		crate::core::validity::check("Stitcher", self.as_raw_mut_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_setFeaturesMatcher_Ptr_FeaturesMatcher_(self.as_raw_mut_Stitcher(), features_matcher.as_raw_mut_PtrOfDetail_FeaturesMatcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_setFeaturesMatcher_Ptr_FeaturesMatcher_(self.as_raw_mut_Stitcher(), features_matcher.as_raw_mut_PtrOfDetail_FeaturesMatcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1686,8 +1784,9 @@ pub trait StitcherTrait: crate::stitching::StitcherTraitConst {
 	
 	#[inline]
 	fn set_matching_mask(&mut self, mask: &core::UMat) -> Result<()> {
+		crate::core::validity::check("Stitcher", self.as_raw_mut_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_setMatchingMask_const_UMatR(self.as_raw_mut_Stitcher(), mask.as_raw_UMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_setMatchingMask_const_UMatR(self.as_raw_mut_Stitcher(), mask.as_raw_UMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1695,8 +1794,9 @@ pub trait StitcherTrait: crate::stitching::StitcherTraitConst {
 	
 	#[inline]
 	fn bundle_adjuster_1(&mut self) -> Result<core::Ptr<dyn crate::stitching::Detail_BundleAdjusterBase>> {
+		crate::core::validity::check("Stitcher", self.as_raw_mut_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_bundleAdjuster(self.as_raw_mut_Stitcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_bundleAdjuster(self.as_raw_mut_Stitcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::stitching::Detail_BundleAdjusterBase>::opencv_from_extern(ret) };
@@ -1705,8 +1805,9 @@ pub trait StitcherTrait: crate::stitching::StitcherTraitConst {
 	
 	#[inline]
 	fn set_bundle_adjuster(&mut self, mut bundle_adjuster: core::Ptr<dyn crate::stitching::Detail_BundleAdjusterBase>) -> Result<()> {
+		crate::core::validity::check("Stitcher", self.as_raw_mut_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_setBundleAdjuster_Ptr_BundleAdjusterBase_(self.as_raw_mut_Stitcher(), bundle_adjuster.as_raw_mut_PtrOfDetail_BundleAdjusterBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_setBundleAdjuster_Ptr_BundleAdjusterBase_(self.as_raw_mut_Stitcher(), bundle_adjuster.as_raw_mut_PtrOfDetail_BundleAdjusterBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1714,8 +1815,9 @@ pub trait StitcherTrait: crate::stitching::StitcherTraitConst {
 	
 	#[inline]
 	fn estimator_1(&mut self) -> Result<core::Ptr<dyn crate::stitching::Detail_Estimator>> {
+		crate::core::validity::check("Stitcher", self.as_raw_mut_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_estimator(self.as_raw_mut_Stitcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_estimator(self.as_raw_mut_Stitcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::stitching::Detail_Estimator>::opencv_from_extern(ret) };
@@ -1724,8 +1826,9 @@ pub trait StitcherTrait: crate::stitching::StitcherTraitConst {
 	
 	#[inline]
 	fn set_estimator(&mut self, mut estimator: core::Ptr<dyn crate::stitching::Detail_Estimator>) -> Result<()> {
+		crate::core::validity::check("Stitcher", self.as_raw_mut_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_setEstimator_Ptr_Estimator_(self.as_raw_mut_Stitcher(), estimator.as_raw_mut_PtrOfDetail_Estimator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_setEstimator_Ptr_Estimator_(self.as_raw_mut_Stitcher(), estimator.as_raw_mut_PtrOfDetail_Estimator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1733,8 +1836,9 @@ pub trait StitcherTrait: crate::stitching::StitcherTraitConst {
 	
 	#[inline]
 	fn warper_1(&mut self) -> Result<core::Ptr<dyn crate::stitching::WarperCreator>> {
+		crate::core::validity::check("Stitcher", self.as_raw_mut_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_warper(self.as_raw_mut_Stitcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_warper(self.as_raw_mut_Stitcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::stitching::WarperCreator>::opencv_from_extern(ret) };
@@ -1743,8 +1847,9 @@ pub trait StitcherTrait: crate::stitching::StitcherTraitConst {
 	
 	#[inline]
 	fn set_warper(&mut self, mut creator: core::Ptr<dyn crate::stitching::WarperCreator>) -> Result<()> {
+		crate::core::validity::check("Stitcher", self.as_raw_mut_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_setWarper_Ptr_WarperCreator_(self.as_raw_mut_Stitcher(), creator.as_raw_mut_PtrOfWarperCreator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_setWarper_Ptr_WarperCreator_(self.as_raw_mut_Stitcher(), creator.as_raw_mut_PtrOfWarperCreator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1752,8 +1857,9 @@ pub trait StitcherTrait: crate::stitching::StitcherTraitConst {
 	
 	#[inline]
 	fn exposure_compensator_1(&mut self) -> Result<core::Ptr<dyn crate::stitching::Detail_ExposureCompensator>> {
+		crate::core::validity::check("Stitcher", self.as_raw_mut_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_exposureCompensator(self.as_raw_mut_Stitcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_exposureCompensator(self.as_raw_mut_Stitcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::stitching::Detail_ExposureCompensator>::opencv_from_extern(ret) };
@@ -1762,8 +1868,9 @@ pub trait StitcherTrait: crate::stitching::StitcherTraitConst {
 	
 	#[inline]
 	fn set_exposure_compensator(&mut self, mut exposure_comp: core::Ptr<dyn crate::stitching::Detail_ExposureCompensator>) -> Result<()> {
+		crate::core::validity::check("Stitcher", self.as_raw_mut_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_setExposureCompensator_Ptr_ExposureCompensator_(self.as_raw_mut_Stitcher(), exposure_comp.as_raw_mut_PtrOfDetail_ExposureCompensator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_setExposureCompensator_Ptr_ExposureCompensator_(self.as_raw_mut_Stitcher(), exposure_comp.as_raw_mut_PtrOfDetail_ExposureCompensator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1771,8 +1878,9 @@ pub trait StitcherTrait: crate::stitching::StitcherTraitConst {
 	
 	#[inline]
 	fn seam_finder_1(&mut self) -> Result<core::Ptr<dyn crate::stitching::Detail_SeamFinder>> {
+		crate::core::validity::check("Stitcher", self.as_raw_mut_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_seamFinder(self.as_raw_mut_Stitcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_seamFinder(self.as_raw_mut_Stitcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::stitching::Detail_SeamFinder>::opencv_from_extern(ret) };
@@ -1781,8 +1889,9 @@ pub trait StitcherTrait: crate::stitching::StitcherTraitConst {
 	
 	#[inline]
 	fn set_seam_finder(&mut self, mut seam_finder: core::Ptr<dyn crate::stitching::Detail_SeamFinder>) -> Result<()> {
+		crate::core::validity::check("Stitcher", self.as_raw_mut_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_setSeamFinder_Ptr_SeamFinder_(self.as_raw_mut_Stitcher(), seam_finder.as_raw_mut_PtrOfDetail_SeamFinder(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_setSeamFinder_Ptr_SeamFinder_(self.as_raw_mut_Stitcher(), seam_finder.as_raw_mut_PtrOfDetail_SeamFinder(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1790,8 +1899,9 @@ pub trait StitcherTrait: crate::stitching::StitcherTraitConst {
 	
 	#[inline]
 	fn blender_1(&mut self) -> Result<core::Ptr<crate::stitching::Detail_Blender>> {
+		crate::core::validity::check("Stitcher", self.as_raw_mut_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_blender(self.as_raw_mut_Stitcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_blender(self.as_raw_mut_Stitcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::stitching::Detail_Blender>::opencv_from_extern(ret) };
@@ -1800,8 +1910,9 @@ pub trait StitcherTrait: crate::stitching::StitcherTraitConst {
 	
 	#[inline]
 	fn set_blender(&mut self, mut b: core::Ptr<crate::stitching::Detail_Blender>) -> Result<()> {
+		crate::core::validity::check("Stitcher", self.as_raw_mut_Stitcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_setBlender_Ptr_Blender_(self.as_raw_mut_Stitcher(), b.as_raw_mut_PtrOfDetail_Blender(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_setBlender_Ptr_Blender_(self.as_raw_mut_Stitcher(), b.as_raw_mut_PtrOfDetail_Blender(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1823,17 +1934,18 @@ pub trait StitcherTrait: crate::stitching::StitcherTraitConst {
 	/// * masks: noArray()
 	#[inline]
 	fn estimate_transform(&mut self, images: &dyn core::ToInputArray, masks: &dyn core::ToInputArray) -> Result<crate::stitching::Stitcher_Status> {
+		crate::core::validity::check("Stitcher", self.as_raw_mut_Stitcher())?;
 		input_array_arg!(images);
 		input_array_arg!(masks);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_estimateTransform_const__InputArrayR_const__InputArrayR(self.as_raw_mut_Stitcher(), images.as_raw__InputArray(), masks.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_estimateTransform_const__InputArrayR_const__InputArrayR(self.as_raw_mut_Stitcher(), images.as_raw__InputArray(), masks.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// These function restors camera rotation and camera intrinsics of each camera
-	/// that can be got with @ref Stitcher::cameras call
+	/// that can be got with [Stitcher::cameras](crate::stitching::Stitcher) call
 	/// 
 	/// ## Parameters
 	/// * images: Input images.
@@ -1843,16 +1955,17 @@ pub trait StitcherTrait: crate::stitching::StitcherTraitConst {
 	/// Status code.
 	#[inline]
 	fn set_transform(&mut self, images: &dyn core::ToInputArray, cameras: &core::Vector<crate::stitching::Detail_CameraParams>, component: &core::Vector<i32>) -> Result<crate::stitching::Stitcher_Status> {
+		crate::core::validity::check("Stitcher", self.as_raw_mut_Stitcher())?;
 		input_array_arg!(images);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_setTransform_const__InputArrayR_const_vector_CameraParams_R_const_vector_int_R(self.as_raw_mut_Stitcher(), images.as_raw__InputArray(), cameras.as_raw_VectorOfDetail_CameraParams(), component.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_setTransform_const__InputArrayR_const_vector_CameraParams_R_const_vector_int_R(self.as_raw_mut_Stitcher(), images.as_raw__InputArray(), cameras.as_raw_VectorOfDetail_CameraParams(), component.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// These function restors camera rotation and camera intrinsics of each camera
-	/// that can be got with @ref Stitcher::cameras call
+	/// that can be got with [Stitcher::cameras](crate::stitching::Stitcher) call
 	/// 
 	/// ## Parameters
 	/// * images: Input images.
@@ -1864,9 +1977,10 @@ pub trait StitcherTrait: crate::stitching::StitcherTraitConst {
 	/// ## Overloaded parameters
 	#[inline]
 	fn set_transform_1(&mut self, images: &dyn core::ToInputArray, cameras: &core::Vector<crate::stitching::Detail_CameraParams>) -> Result<crate::stitching::Stitcher_Status> {
+		crate::core::validity::check("Stitcher", self.as_raw_mut_Stitcher())?;
 		input_array_arg!(images);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_setTransform_const__InputArrayR_const_vector_CameraParams_R(self.as_raw_mut_Stitcher(), images.as_raw__InputArray(), cameras.as_raw_VectorOfDetail_CameraParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_setTransform_const__InputArrayR_const_vector_CameraParams_R(self.as_raw_mut_Stitcher(), images.as_raw__InputArray(), cameras.as_raw_VectorOfDetail_CameraParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1889,9 +2003,10 @@ pub trait StitcherTrait: crate::stitching::StitcherTraitConst {
 	/// ## Overloaded parameters
 	#[inline]
 	fn compose_panorama(&mut self, pano: &mut dyn core::ToOutputArray) -> Result<crate::stitching::Stitcher_Status> {
+		crate::core::validity::check("Stitcher", self.as_raw_mut_Stitcher())?;
 		output_array_arg!(pano);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_composePanorama_const__OutputArrayR(self.as_raw_mut_Stitcher(), pano.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_composePanorama_const__OutputArrayR(self.as_raw_mut_Stitcher(), pano.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1912,10 +2027,11 @@ pub trait StitcherTrait: crate::stitching::StitcherTraitConst {
 	/// Status code.
 	#[inline]
 	fn compose_panorama_images(&mut self, images: &dyn core::ToInputArray, pano: &mut dyn core::ToOutputArray) -> Result<crate::stitching::Stitcher_Status> {
+		crate::core::validity::check("Stitcher", self.as_raw_mut_Stitcher())?;
 		input_array_arg!(images);
 		output_array_arg!(pano);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_composePanorama_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_Stitcher(), images.as_raw__InputArray(), pano.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_composePanorama_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_Stitcher(), images.as_raw__InputArray(), pano.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1933,10 +2049,11 @@ pub trait StitcherTrait: crate::stitching::StitcherTraitConst {
 	/// ## Overloaded parameters
 	#[inline]
 	fn stitch(&mut self, images: &dyn core::ToInputArray, pano: &mut dyn core::ToOutputArray) -> Result<crate::stitching::Stitcher_Status> {
+		crate::core::validity::check("Stitcher", self.as_raw_mut_Stitcher())?;
 		input_array_arg!(images);
 		output_array_arg!(pano);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_stitch_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_Stitcher(), images.as_raw__InputArray(), pano.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_stitch_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_Stitcher(), images.as_raw__InputArray(), pano.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1952,11 +2069,12 @@ pub trait StitcherTrait: crate::stitching::StitcherTraitConst {
 	/// Status code.
 	#[inline]
 	fn stitch_mask(&mut self, images: &dyn core::ToInputArray, masks: &dyn core::ToInputArray, pano: &mut dyn core::ToOutputArray) -> Result<crate::stitching::Stitcher_Status> {
+		crate::core::validity::check("Stitcher", self.as_raw_mut_Stitcher())?;
 		input_array_arg!(images);
 		input_array_arg!(masks);
 		output_array_arg!(pano);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_stitch_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_Stitcher(), images.as_raw__InputArray(), masks.as_raw__InputArray(), pano.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_stitch_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_Stitcher(), images.as_raw__InputArray(), masks.as_raw__InputArray(), pano.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1987,7 +2105,10 @@ opencv_type_boxed! { Stitcher }
 impl Drop for Stitcher {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Stitcher_delete(instance: *mut c_void); }
-		unsafe { cv_Stitcher_delete(self.as_raw_mut_Stitcher()) };
+		let ptr = self.as_raw_mut_Stitcher();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Stitcher_delete(ptr) };
 	}
 }
 
@@ -2017,7 +2138,7 @@ impl Stitcher {
 	#[inline]
 	pub fn create(mode: crate::stitching::Stitcher_Mode) -> Result<core::Ptr<crate::stitching::Stitcher>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Stitcher_create_Mode(mode, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Stitcher_create_Mode(mode, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::stitching::Stitcher>::opencv_from_extern(ret) };
@@ -2031,8 +2152,9 @@ pub trait TransverseMercatorWarperTraitConst: crate::stitching::WarperCreatorCon
 
 	#[inline]
 	fn create(&self, scale: f32) -> Result<core::Ptr<dyn crate::stitching::Detail_RotationWarper>> {
+		crate::core::validity::check("TransverseMercatorWarper", self.as_raw_TransverseMercatorWarper())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_TransverseMercatorWarper_create_const_float(self.as_raw_TransverseMercatorWarper(), scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_TransverseMercatorWarper_create_const_float(self.as_raw_TransverseMercatorWarper(), scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::stitching::Detail_RotationWarper>::opencv_from_extern(ret) };
@@ -2055,7 +2177,10 @@ opencv_type_boxed! { TransverseMercatorWarper }
 impl Drop for TransverseMercatorWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_TransverseMercatorWarper_delete(instance: *mut c_void); }
-		unsafe { cv_TransverseMercatorWarper_delete(self.as_raw_mut_TransverseMercatorWarper()) };
+		let ptr = self.as_raw_mut_TransverseMercatorWarper();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_TransverseMercatorWarper_delete(ptr) };
 	}
 }
 
@@ -2086,8 +2211,9 @@ pub trait WarperCreatorConst {
 
 	#[inline]
 	fn create(&self, scale: f32) -> Result<core::Ptr<dyn crate::stitching::Detail_RotationWarper>> {
+		crate::core::validity::check("WarperCreator", self.as_raw_WarperCreator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_WarperCreator_create_const_float(self.as_raw_WarperCreator(), scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_WarperCreator_create_const_float(self.as_raw_WarperCreator(), scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::stitching::Detail_RotationWarper>::opencv_from_extern(ret) };
@@ -2132,7 +2258,10 @@ opencv_type_boxed! { Detail_AffineBasedEstimator }
 impl Drop for Detail_AffineBasedEstimator {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_AffineBasedEstimator_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_AffineBasedEstimator_delete(self.as_raw_mut_Detail_AffineBasedEstimator()) };
+		let ptr = self.as_raw_mut_Detail_AffineBasedEstimator();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_AffineBasedEstimator_delete(ptr) };
 	}
 }
 
@@ -2158,7 +2287,7 @@ impl Detail_AffineBasedEstimator {
 	#[inline]
 	pub fn default() -> Result<crate::stitching::Detail_AffineBasedEstimator> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_AffineBasedEstimator_AffineBasedEstimator(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_AffineBasedEstimator_AffineBasedEstimator(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_AffineBasedEstimator::opencv_from_extern(ret) };
@@ -2202,7 +2331,10 @@ opencv_type_boxed! { Detail_AffineBestOf2NearestMatcher }
 impl Drop for Detail_AffineBestOf2NearestMatcher {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_AffineBestOf2NearestMatcher_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_AffineBestOf2NearestMatcher_delete(self.as_raw_mut_Detail_AffineBestOf2NearestMatcher()) };
+		let ptr = self.as_raw_mut_Detail_AffineBestOf2NearestMatcher();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_AffineBestOf2NearestMatcher_delete(ptr) };
 	}
 }
 
@@ -2254,7 +2386,7 @@ impl Detail_AffineBestOf2NearestMatcher {
 	#[inline]
 	pub fn new(full_affine: bool, try_use_gpu: bool, match_conf: f32, num_matches_thresh1: i32) -> Result<crate::stitching::Detail_AffineBestOf2NearestMatcher> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_AffineBestOf2NearestMatcher_AffineBestOf2NearestMatcher_bool_bool_float_int(full_affine, try_use_gpu, match_conf, num_matches_thresh1, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_AffineBestOf2NearestMatcher_AffineBestOf2NearestMatcher_bool_bool_float_int(full_affine, try_use_gpu, match_conf, num_matches_thresh1, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_AffineBestOf2NearestMatcher::opencv_from_extern(ret) };
@@ -2263,6 +2395,64 @@ impl Detail_AffineBestOf2NearestMatcher {
 	
 }
 
+/// Builder for `Detail_AffineBestOf2NearestMatcher::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct Detail_AffineBestOf2NearestMatcher_NewBuilder {
+	full_affine: bool,
+	try_use_gpu: bool,
+	match_conf: f32,
+	num_matches_thresh1: i32,
+}
+
+impl Detail_AffineBestOf2NearestMatcher_NewBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { full_affine: false, try_use_gpu: false, match_conf: 0.3, num_matches_thresh1: 6 }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_full_affine(mut self, full_affine: bool) -> Self {
+		self.full_affine = full_affine;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_try_use_gpu(mut self, try_use_gpu: bool) -> Self {
+		self.try_use_gpu = try_use_gpu;
+		self
+	}
+	
+	/// C++ default: `0.3f`
+	#[inline]
+	pub fn with_match_conf(mut self, match_conf: f32) -> Self {
+		self.match_conf = match_conf;
+		self
+	}
+	
+	/// C++ default: `6`
+	#[inline]
+	pub fn with_num_matches_thresh1(mut self, num_matches_thresh1: i32) -> Self {
+		self.num_matches_thresh1 = num_matches_thresh1;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::stitching::Detail_AffineBestOf2NearestMatcher> {
+		Detail_AffineBestOf2NearestMatcher::new(self.full_affine, self.try_use_gpu, self.match_conf, self.num_matches_thresh1)
+	}
+}
+
+impl Default for Detail_AffineBestOf2NearestMatcher_NewBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 boxed_cast_base! { Detail_AffineBestOf2NearestMatcher, crate::stitching::Detail_BestOf2NearestMatcher, cv_Detail_AffineBestOf2NearestMatcher_to_Detail_BestOf2NearestMatcher }
 
 /// Affine warper that uses rotations and translations
@@ -2287,10 +2477,11 @@ pub trait Detail_AffineWarperTrait: crate::stitching::Detail_AffineWarperTraitCo
 	/// Projected point
 	#[inline]
 	fn warp_point(&mut self, pt: core::Point2f, k: &dyn core::ToInputArray, h: &dyn core::ToInputArray) -> Result<core::Point2f> {
+		crate::core::validity::check("Detail_AffineWarper", self.as_raw_mut_Detail_AffineWarper())?;
 		input_array_arg!(k);
 		input_array_arg!(h);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_AffineWarper_warpPoint_const_Point2fR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_Detail_AffineWarper(), &pt, k.as_raw__InputArray(), h.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_AffineWarper_warpPoint_const_Point2fR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_Detail_AffineWarper(), &pt, k.as_raw__InputArray(), h.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2306,10 +2497,11 @@ pub trait Detail_AffineWarperTrait: crate::stitching::Detail_AffineWarperTraitCo
 	/// Backward-projected point
 	#[inline]
 	fn warp_point_backward(&mut self, pt: core::Point2f, k: &dyn core::ToInputArray, h: &dyn core::ToInputArray) -> Result<core::Point2f> {
+		crate::core::validity::check("Detail_AffineWarper", self.as_raw_mut_Detail_AffineWarper())?;
 		input_array_arg!(k);
 		input_array_arg!(h);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_AffineWarper_warpPointBackward_const_Point2fR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_Detail_AffineWarper(), &pt, k.as_raw__InputArray(), h.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_AffineWarper_warpPointBackward_const_Point2fR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_Detail_AffineWarper(), &pt, k.as_raw__InputArray(), h.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2327,12 +2519,13 @@ pub trait Detail_AffineWarperTrait: crate::stitching::Detail_AffineWarperTraitCo
 	/// Projected image minimum bounding box
 	#[inline]
 	fn build_maps(&mut self, src_size: core::Size, k: &dyn core::ToInputArray, h: &dyn core::ToInputArray, xmap: &mut dyn core::ToOutputArray, ymap: &mut dyn core::ToOutputArray) -> Result<core::Rect> {
+		crate::core::validity::check("Detail_AffineWarper", self.as_raw_mut_Detail_AffineWarper())?;
 		input_array_arg!(k);
 		input_array_arg!(h);
 		output_array_arg!(xmap);
 		output_array_arg!(ymap);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_AffineWarper_buildMaps_Size_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_Detail_AffineWarper(), src_size.opencv_as_extern(), k.as_raw__InputArray(), h.as_raw__InputArray(), xmap.as_raw__OutputArray(), ymap.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_AffineWarper_buildMaps_Size_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_Detail_AffineWarper(), src_size.opencv_as_extern(), k.as_raw__InputArray(), h.as_raw__InputArray(), xmap.as_raw__OutputArray(), ymap.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2351,12 +2544,13 @@ pub trait Detail_AffineWarperTrait: crate::stitching::Detail_AffineWarperTraitCo
 	/// Project image top-left corner
 	#[inline]
 	fn warp(&mut self, src: &dyn core::ToInputArray, k: &dyn core::ToInputArray, h: &dyn core::ToInputArray, interp_mode: i32, border_mode: i32, dst: &mut dyn core::ToOutputArray) -> Result<core::Point> {
+		crate::core::validity::check("Detail_AffineWarper", self.as_raw_mut_Detail_AffineWarper())?;
 		input_array_arg!(src);
 		input_array_arg!(k);
 		input_array_arg!(h);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_AffineWarper_warp_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_int_const__OutputArrayR(self.as_raw_mut_Detail_AffineWarper(), src.as_raw__InputArray(), k.as_raw__InputArray(), h.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_AffineWarper_warp_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_int_const__OutputArrayR(self.as_raw_mut_Detail_AffineWarper(), src.as_raw__InputArray(), k.as_raw__InputArray(), h.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2370,10 +2564,11 @@ pub trait Detail_AffineWarperTrait: crate::stitching::Detail_AffineWarperTraitCo
 	/// Projected image minimum bounding box
 	#[inline]
 	fn warp_roi(&mut self, src_size: core::Size, k: &dyn core::ToInputArray, h: &dyn core::ToInputArray) -> Result<core::Rect> {
+		crate::core::validity::check("Detail_AffineWarper", self.as_raw_mut_Detail_AffineWarper())?;
 		input_array_arg!(k);
 		input_array_arg!(h);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_AffineWarper_warpRoi_Size_const__InputArrayR_const__InputArrayR(self.as_raw_mut_Detail_AffineWarper(), src_size.opencv_as_extern(), k.as_raw__InputArray(), h.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_AffineWarper_warpRoi_Size_const__InputArrayR_const__InputArrayR(self.as_raw_mut_Detail_AffineWarper(), src_size.opencv_as_extern(), k.as_raw__InputArray(), h.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2394,7 +2589,10 @@ opencv_type_boxed! { Detail_AffineWarper }
 impl Drop for Detail_AffineWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_AffineWarper_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_AffineWarper_delete(self.as_raw_mut_Detail_AffineWarper()) };
+		let ptr = self.as_raw_mut_Detail_AffineWarper();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_AffineWarper_delete(ptr) };
 	}
 }
 
@@ -2435,7 +2633,7 @@ impl Detail_AffineWarper {
 	#[inline]
 	pub fn new(scale: f32) -> Result<crate::stitching::Detail_AffineWarper> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_AffineWarper_AffineWarper_float(scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_AffineWarper_AffineWarper_float(scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_AffineWarper::opencv_from_extern(ret) };
@@ -2460,8 +2658,9 @@ pub trait Detail_BestOf2NearestMatcherTrait: crate::stitching::Detail_BestOf2Nea
 
 	#[inline]
 	fn collect_garbage(&mut self) -> Result<()> {
+		crate::core::validity::check("Detail_BestOf2NearestMatcher", self.as_raw_mut_Detail_BestOf2NearestMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_BestOf2NearestMatcher_collectGarbage(self.as_raw_mut_Detail_BestOf2NearestMatcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_BestOf2NearestMatcher_collectGarbage(self.as_raw_mut_Detail_BestOf2NearestMatcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2482,7 +2681,10 @@ opencv_type_boxed! { Detail_BestOf2NearestMatcher }
 impl Drop for Detail_BestOf2NearestMatcher {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_BestOf2NearestMatcher_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_BestOf2NearestMatcher_delete(self.as_raw_mut_Detail_BestOf2NearestMatcher()) };
+		let ptr = self.as_raw_mut_Detail_BestOf2NearestMatcher();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_BestOf2NearestMatcher_delete(ptr) };
 	}
 }
 
@@ -2523,7 +2725,7 @@ impl Detail_BestOf2NearestMatcher {
 	#[inline]
 	pub fn new(try_use_gpu: bool, match_conf: f32, num_matches_thresh1: i32, num_matches_thresh2: i32) -> Result<crate::stitching::Detail_BestOf2NearestMatcher> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_BestOf2NearestMatcher_BestOf2NearestMatcher_bool_float_int_int(try_use_gpu, match_conf, num_matches_thresh1, num_matches_thresh2, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_BestOf2NearestMatcher_BestOf2NearestMatcher_bool_float_int_int(try_use_gpu, match_conf, num_matches_thresh1, num_matches_thresh2, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_BestOf2NearestMatcher::opencv_from_extern(ret) };
@@ -2538,7 +2740,7 @@ impl Detail_BestOf2NearestMatcher {
 	#[inline]
 	pub fn create(try_use_gpu: bool, match_conf: f32, num_matches_thresh1: i32, num_matches_thresh2: i32) -> Result<core::Ptr<crate::stitching::Detail_BestOf2NearestMatcher>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_BestOf2NearestMatcher_create_bool_float_int_int(try_use_gpu, match_conf, num_matches_thresh1, num_matches_thresh2, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_BestOf2NearestMatcher_create_bool_float_int_int(try_use_gpu, match_conf, num_matches_thresh1, num_matches_thresh2, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::stitching::Detail_BestOf2NearestMatcher>::opencv_from_extern(ret) };
@@ -2547,6 +2749,122 @@ impl Detail_BestOf2NearestMatcher {
 	
 }
 
+/// Builder for `Detail_BestOf2NearestMatcher::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct Detail_BestOf2NearestMatcher_NewBuilder {
+	try_use_gpu: bool,
+	match_conf: f32,
+	num_matches_thresh1: i32,
+	num_matches_thresh2: i32,
+}
+
+impl Detail_BestOf2NearestMatcher_NewBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { try_use_gpu: false, match_conf: 0.3, num_matches_thresh1: 6, num_matches_thresh2: 6 }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_try_use_gpu(mut self, try_use_gpu: bool) -> Self {
+		self.try_use_gpu = try_use_gpu;
+		self
+	}
+	
+	/// C++ default: `0.3f`
+	#[inline]
+	pub fn with_match_conf(mut self, match_conf: f32) -> Self {
+		self.match_conf = match_conf;
+		self
+	}
+	
+	/// C++ default: `6`
+	#[inline]
+	pub fn with_num_matches_thresh1(mut self, num_matches_thresh1: i32) -> Self {
+		self.num_matches_thresh1 = num_matches_thresh1;
+		self
+	}
+	
+	/// C++ default: `6`
+	#[inline]
+	pub fn with_num_matches_thresh2(mut self, num_matches_thresh2: i32) -> Self {
+		self.num_matches_thresh2 = num_matches_thresh2;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::stitching::Detail_BestOf2NearestMatcher> {
+		Detail_BestOf2NearestMatcher::new(self.try_use_gpu, self.match_conf, self.num_matches_thresh1, self.num_matches_thresh2)
+	}
+}
+
+impl Default for Detail_BestOf2NearestMatcher_NewBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Builder for `Detail_BestOf2NearestMatcher::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct Detail_BestOf2NearestMatcher_CreateBuilder {
+	try_use_gpu: bool,
+	match_conf: f32,
+	num_matches_thresh1: i32,
+	num_matches_thresh2: i32,
+}
+
+impl Detail_BestOf2NearestMatcher_CreateBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { try_use_gpu: false, match_conf: 0.3, num_matches_thresh1: 6, num_matches_thresh2: 6 }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_try_use_gpu(mut self, try_use_gpu: bool) -> Self {
+		self.try_use_gpu = try_use_gpu;
+		self
+	}
+	
+	/// C++ default: `0.3f`
+	#[inline]
+	pub fn with_match_conf(mut self, match_conf: f32) -> Self {
+		self.match_conf = match_conf;
+		self
+	}
+	
+	/// C++ default: `6`
+	#[inline]
+	pub fn with_num_matches_thresh1(mut self, num_matches_thresh1: i32) -> Self {
+		self.num_matches_thresh1 = num_matches_thresh1;
+		self
+	}
+	
+	/// C++ default: `6`
+	#[inline]
+	pub fn with_num_matches_thresh2(mut self, num_matches_thresh2: i32) -> Self {
+		self.num_matches_thresh2 = num_matches_thresh2;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<crate::stitching::Detail_BestOf2NearestMatcher>> {
+		Detail_BestOf2NearestMatcher::create(self.try_use_gpu, self.match_conf, self.num_matches_thresh1, self.num_matches_thresh2)
+	}
+}
+
+impl Default for Detail_BestOf2NearestMatcher_CreateBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 boxed_cast_descendant! { Detail_BestOf2NearestMatcher, crate::stitching::Detail_AffineBestOf2NearestMatcher, cv_Detail_BestOf2NearestMatcher_to_Detail_AffineBestOf2NearestMatcher }
 
 boxed_cast_descendant! { Detail_BestOf2NearestMatcher, crate::stitching::Detail_BestOf2NearestRangeMatcher, cv_Detail_BestOf2NearestMatcher_to_Detail_BestOf2NearestRangeMatcher }
@@ -2570,7 +2888,10 @@ opencv_type_boxed! { Detail_BestOf2NearestRangeMatcher }
 impl Drop for Detail_BestOf2NearestRangeMatcher {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_BestOf2NearestRangeMatcher_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_BestOf2NearestRangeMatcher_delete(self.as_raw_mut_Detail_BestOf2NearestRangeMatcher()) };
+		let ptr = self.as_raw_mut_Detail_BestOf2NearestRangeMatcher();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_BestOf2NearestRangeMatcher_delete(ptr) };
 	}
 }
 
@@ -2610,7 +2931,7 @@ impl Detail_BestOf2NearestRangeMatcher {
 	#[inline]
 	pub fn new(range_width: i32, try_use_gpu: bool, match_conf: f32, num_matches_thresh1: i32, num_matches_thresh2: i32) -> Result<crate::stitching::Detail_BestOf2NearestRangeMatcher> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_BestOf2NearestRangeMatcher_BestOf2NearestRangeMatcher_int_bool_float_int_int(range_width, try_use_gpu, match_conf, num_matches_thresh1, num_matches_thresh2, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_BestOf2NearestRangeMatcher_BestOf2NearestRangeMatcher_int_bool_float_int_int(range_width, try_use_gpu, match_conf, num_matches_thresh1, num_matches_thresh2, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_BestOf2NearestRangeMatcher::opencv_from_extern(ret) };
@@ -2619,6 +2940,72 @@ impl Detail_BestOf2NearestRangeMatcher {
 	
 }
 
+/// Builder for `Detail_BestOf2NearestRangeMatcher::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct Detail_BestOf2NearestRangeMatcher_NewBuilder {
+	range_width: i32,
+	try_use_gpu: bool,
+	match_conf: f32,
+	num_matches_thresh1: i32,
+	num_matches_thresh2: i32,
+}
+
+impl Detail_BestOf2NearestRangeMatcher_NewBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { range_width: 5, try_use_gpu: false, match_conf: 0.3, num_matches_thresh1: 6, num_matches_thresh2: 6 }
+	}
+	
+	/// C++ default: `5`
+	#[inline]
+	pub fn with_range_width(mut self, range_width: i32) -> Self {
+		self.range_width = range_width;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_try_use_gpu(mut self, try_use_gpu: bool) -> Self {
+		self.try_use_gpu = try_use_gpu;
+		self
+	}
+	
+	/// C++ default: `0.3f`
+	#[inline]
+	pub fn with_match_conf(mut self, match_conf: f32) -> Self {
+		self.match_conf = match_conf;
+		self
+	}
+	
+	/// C++ default: `6`
+	#[inline]
+	pub fn with_num_matches_thresh1(mut self, num_matches_thresh1: i32) -> Self {
+		self.num_matches_thresh1 = num_matches_thresh1;
+		self
+	}
+	
+	/// C++ default: `6`
+	#[inline]
+	pub fn with_num_matches_thresh2(mut self, num_matches_thresh2: i32) -> Self {
+		self.num_matches_thresh2 = num_matches_thresh2;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::stitching::Detail_BestOf2NearestRangeMatcher> {
+		Detail_BestOf2NearestRangeMatcher::new(self.range_width, self.try_use_gpu, self.match_conf, self.num_matches_thresh1, self.num_matches_thresh2)
+	}
+}
+
+impl Default for Detail_BestOf2NearestRangeMatcher_NewBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 boxed_cast_base! { Detail_BestOf2NearestRangeMatcher, crate::stitching::Detail_BestOf2NearestMatcher, cv_Detail_BestOf2NearestRangeMatcher_to_Detail_BestOf2NearestMatcher }
 
 /// Base class for all blenders.
@@ -2639,8 +3026,9 @@ pub trait Detail_BlenderTrait: crate::stitching::Detail_BlenderTraitConst {
 	/// * sizes: Source image sizes
 	#[inline]
 	fn prepare(&mut self, corners: &core::Vector<core::Point>, sizes: &core::Vector<core::Size>) -> Result<()> {
+		crate::core::validity::check("Detail_Blender", self.as_raw_mut_Detail_Blender())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_Blender_prepare_const_vector_Point_R_const_vector_Size_R(self.as_raw_mut_Detail_Blender(), corners.as_raw_VectorOfPoint(), sizes.as_raw_VectorOfSize(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_Blender_prepare_const_vector_Point_R_const_vector_Size_R(self.as_raw_mut_Detail_Blender(), corners.as_raw_VectorOfPoint(), sizes.as_raw_VectorOfSize(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2655,8 +3043,9 @@ pub trait Detail_BlenderTrait: crate::stitching::Detail_BlenderTraitConst {
 	/// ## Overloaded parameters
 	#[inline]
 	fn prepare_1(&mut self, dst_roi: core::Rect) -> Result<()> {
+		crate::core::validity::check("Detail_Blender", self.as_raw_mut_Detail_Blender())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_Blender_prepare_Rect(self.as_raw_mut_Detail_Blender(), dst_roi.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_Blender_prepare_Rect(self.as_raw_mut_Detail_Blender(), dst_roi.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2670,10 +3059,11 @@ pub trait Detail_BlenderTrait: crate::stitching::Detail_BlenderTraitConst {
 	/// * tl: Source image top-left corners
 	#[inline]
 	fn feed(&mut self, img: &dyn core::ToInputArray, mask: &dyn core::ToInputArray, tl: core::Point) -> Result<()> {
+		crate::core::validity::check("Detail_Blender", self.as_raw_mut_Detail_Blender())?;
 		input_array_arg!(img);
 		input_array_arg!(mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_Blender_feed_const__InputArrayR_const__InputArrayR_Point(self.as_raw_mut_Detail_Blender(), img.as_raw__InputArray(), mask.as_raw__InputArray(), tl.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_Blender_feed_const__InputArrayR_const__InputArrayR_Point(self.as_raw_mut_Detail_Blender(), img.as_raw__InputArray(), mask.as_raw__InputArray(), tl.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2686,10 +3076,11 @@ pub trait Detail_BlenderTrait: crate::stitching::Detail_BlenderTraitConst {
 	/// * dst_mask: Final pano mask
 	#[inline]
 	fn blend(&mut self, dst: &mut dyn core::ToInputOutputArray, dst_mask: &mut dyn core::ToInputOutputArray) -> Result<()> {
+		crate::core::validity::check("Detail_Blender", self.as_raw_mut_Detail_Blender())?;
 		input_output_array_arg!(dst);
 		input_output_array_arg!(dst_mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_Blender_blend_const__InputOutputArrayR_const__InputOutputArrayR(self.as_raw_mut_Detail_Blender(), dst.as_raw__InputOutputArray(), dst_mask.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_Blender_blend_const__InputOutputArrayR_const__InputOutputArrayR(self.as_raw_mut_Detail_Blender(), dst.as_raw__InputOutputArray(), dst_mask.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2709,7 +3100,10 @@ opencv_type_boxed! { Detail_Blender }
 impl Drop for Detail_Blender {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_Blender_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_Blender_delete(self.as_raw_mut_Detail_Blender()) };
+		let ptr = self.as_raw_mut_Detail_Blender();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_Blender_delete(ptr) };
 	}
 }
 
@@ -2729,7 +3123,7 @@ impl Detail_Blender {
 	#[inline]
 	pub fn create_default(typ: i32, try_gpu: bool) -> Result<core::Ptr<crate::stitching::Detail_Blender>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_Blender_createDefault_int_bool(typ, try_gpu, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_Blender_createDefault_int_bool(typ, try_gpu, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::stitching::Detail_Blender>::opencv_from_extern(ret) };
@@ -2765,7 +3159,10 @@ opencv_type_boxed! { Detail_BlocksChannelsCompensator }
 impl Drop for Detail_BlocksChannelsCompensator {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_BlocksChannelsCompensator_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_BlocksChannelsCompensator_delete(self.as_raw_mut_Detail_BlocksChannelsCompensator()) };
+		let ptr = self.as_raw_mut_Detail_BlocksChannelsCompensator();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_BlocksChannelsCompensator_delete(ptr) };
 	}
 }
 
@@ -2803,7 +3200,7 @@ impl Detail_BlocksChannelsCompensator {
 	#[inline]
 	pub fn new(bl_width: i32, bl_height: i32, nr_feeds: i32) -> Result<crate::stitching::Detail_BlocksChannelsCompensator> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_BlocksChannelsCompensator_BlocksChannelsCompensator_int_int_int(bl_width, bl_height, nr_feeds, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_BlocksChannelsCompensator_BlocksChannelsCompensator_int_int_int(bl_width, bl_height, nr_feeds, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_BlocksChannelsCompensator::opencv_from_extern(ret) };
@@ -2812,14 +3209,65 @@ impl Detail_BlocksChannelsCompensator {
 	
 }
 
+/// Builder for `Detail_BlocksChannelsCompensator::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct Detail_BlocksChannelsCompensator_NewBuilder {
+	bl_width: i32,
+	bl_height: i32,
+	nr_feeds: i32,
+}
+
+impl Detail_BlocksChannelsCompensator_NewBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { bl_width: 32, bl_height: 32, nr_feeds: 1 }
+	}
+	
+	/// C++ default: `32`
+	#[inline]
+	pub fn with_bl_width(mut self, bl_width: i32) -> Self {
+		self.bl_width = bl_width;
+		self
+	}
+	
+	/// C++ default: `32`
+	#[inline]
+	pub fn with_bl_height(mut self, bl_height: i32) -> Self {
+		self.bl_height = bl_height;
+		self
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_nr_feeds(mut self, nr_feeds: i32) -> Self {
+		self.nr_feeds = nr_feeds;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::stitching::Detail_BlocksChannelsCompensator> {
+		Detail_BlocksChannelsCompensator::new(self.bl_width, self.bl_height, self.nr_feeds)
+	}
+}
+
+impl Default for Detail_BlocksChannelsCompensator_NewBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 /// Exposure compensator which tries to remove exposure related artifacts by adjusting image blocks.
 pub trait Detail_BlocksCompensatorConst: crate::stitching::Detail_ExposureCompensatorConst {
 	fn as_raw_Detail_BlocksCompensator(&self) -> *const c_void;
 
 	#[inline]
 	fn get_similarity_threshold(&self) -> Result<f64> {
+		crate::core::validity::check("Detail_BlocksCompensator", self.as_raw_Detail_BlocksCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_BlocksCompensator_getSimilarityThreshold_const(self.as_raw_Detail_BlocksCompensator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_BlocksCompensator_getSimilarityThreshold_const(self.as_raw_Detail_BlocksCompensator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2827,8 +3275,9 @@ pub trait Detail_BlocksCompensatorConst: crate::stitching::Detail_ExposureCompen
 	
 	#[inline]
 	fn get_block_size(&self) -> Result<core::Size> {
+		crate::core::validity::check("Detail_BlocksCompensator", self.as_raw_Detail_BlocksCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_BlocksCompensator_getBlockSize_const(self.as_raw_Detail_BlocksCompensator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_BlocksCompensator_getBlockSize_const(self.as_raw_Detail_BlocksCompensator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2836,8 +3285,9 @@ pub trait Detail_BlocksCompensatorConst: crate::stitching::Detail_ExposureCompen
 	
 	#[inline]
 	fn get_nr_gains_filtering_iterations(&self) -> Result<i32> {
+		crate::core::validity::check("Detail_BlocksCompensator", self.as_raw_Detail_BlocksCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_BlocksCompensator_getNrGainsFilteringIterations_const(self.as_raw_Detail_BlocksCompensator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_BlocksCompensator_getNrGainsFilteringIterations_const(self.as_raw_Detail_BlocksCompensator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2850,10 +3300,11 @@ pub trait Detail_BlocksCompensator: crate::stitching::Detail_BlocksCompensatorCo
 
 	#[inline]
 	fn apply(&mut self, index: i32, corner: core::Point, image: &mut dyn core::ToInputOutputArray, mask: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("Detail_BlocksCompensator", self.as_raw_mut_Detail_BlocksCompensator())?;
 		input_output_array_arg!(image);
 		input_array_arg!(mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_BlocksCompensator_apply_int_Point_const__InputOutputArrayR_const__InputArrayR(self.as_raw_mut_Detail_BlocksCompensator(), index, corner.opencv_as_extern(), image.as_raw__InputOutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_BlocksCompensator_apply_int_Point_const__InputOutputArrayR_const__InputArrayR(self.as_raw_mut_Detail_BlocksCompensator(), index, corner.opencv_as_extern(), image.as_raw__InputOutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2861,8 +3312,9 @@ pub trait Detail_BlocksCompensator: crate::stitching::Detail_BlocksCompensatorCo
 	
 	#[inline]
 	fn get_mat_gains(&mut self, umv: &mut core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("Detail_BlocksCompensator", self.as_raw_mut_Detail_BlocksCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_BlocksCompensator_getMatGains_vector_Mat_R(self.as_raw_mut_Detail_BlocksCompensator(), umv.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_BlocksCompensator_getMatGains_vector_Mat_R(self.as_raw_mut_Detail_BlocksCompensator(), umv.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2870,8 +3322,9 @@ pub trait Detail_BlocksCompensator: crate::stitching::Detail_BlocksCompensatorCo
 	
 	#[inline]
 	fn set_mat_gains(&mut self, umv: &mut core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("Detail_BlocksCompensator", self.as_raw_mut_Detail_BlocksCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_BlocksCompensator_setMatGains_vector_Mat_R(self.as_raw_mut_Detail_BlocksCompensator(), umv.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_BlocksCompensator_setMatGains_vector_Mat_R(self.as_raw_mut_Detail_BlocksCompensator(), umv.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2879,8 +3332,9 @@ pub trait Detail_BlocksCompensator: crate::stitching::Detail_BlocksCompensatorCo
 	
 	#[inline]
 	fn set_nr_feeds(&mut self, nr_feeds: i32) -> Result<()> {
+		crate::core::validity::check("Detail_BlocksCompensator", self.as_raw_mut_Detail_BlocksCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_BlocksCompensator_setNrFeeds_int(self.as_raw_mut_Detail_BlocksCompensator(), nr_feeds, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_BlocksCompensator_setNrFeeds_int(self.as_raw_mut_Detail_BlocksCompensator(), nr_feeds, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2888,8 +3342,9 @@ pub trait Detail_BlocksCompensator: crate::stitching::Detail_BlocksCompensatorCo
 	
 	#[inline]
 	fn get_nr_feeds(&mut self) -> Result<i32> {
+		crate::core::validity::check("Detail_BlocksCompensator", self.as_raw_mut_Detail_BlocksCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_BlocksCompensator_getNrFeeds(self.as_raw_mut_Detail_BlocksCompensator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_BlocksCompensator_getNrFeeds(self.as_raw_mut_Detail_BlocksCompensator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2897,8 +3352,9 @@ pub trait Detail_BlocksCompensator: crate::stitching::Detail_BlocksCompensatorCo
 	
 	#[inline]
 	fn set_similarity_threshold(&mut self, similarity_threshold: f64) -> Result<()> {
+		crate::core::validity::check("Detail_BlocksCompensator", self.as_raw_mut_Detail_BlocksCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_BlocksCompensator_setSimilarityThreshold_double(self.as_raw_mut_Detail_BlocksCompensator(), similarity_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_BlocksCompensator_setSimilarityThreshold_double(self.as_raw_mut_Detail_BlocksCompensator(), similarity_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2906,8 +3362,9 @@ pub trait Detail_BlocksCompensator: crate::stitching::Detail_BlocksCompensatorCo
 	
 	#[inline]
 	fn set_block_size(&mut self, width: i32, height: i32) -> Result<()> {
+		crate::core::validity::check("Detail_BlocksCompensator", self.as_raw_mut_Detail_BlocksCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_BlocksCompensator_setBlockSize_int_int(self.as_raw_mut_Detail_BlocksCompensator(), width, height, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_BlocksCompensator_setBlockSize_int_int(self.as_raw_mut_Detail_BlocksCompensator(), width, height, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2915,8 +3372,9 @@ pub trait Detail_BlocksCompensator: crate::stitching::Detail_BlocksCompensatorCo
 	
 	#[inline]
 	fn set_block_size_1(&mut self, size: core::Size) -> Result<()> {
+		crate::core::validity::check("Detail_BlocksCompensator", self.as_raw_mut_Detail_BlocksCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_BlocksCompensator_setBlockSize_Size(self.as_raw_mut_Detail_BlocksCompensator(), size.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_BlocksCompensator_setBlockSize_Size(self.as_raw_mut_Detail_BlocksCompensator(), size.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2924,8 +3382,9 @@ pub trait Detail_BlocksCompensator: crate::stitching::Detail_BlocksCompensatorCo
 	
 	#[inline]
 	fn set_nr_gains_filtering_iterations(&mut self, nr_iterations: i32) -> Result<()> {
+		crate::core::validity::check("Detail_BlocksCompensator", self.as_raw_mut_Detail_BlocksCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_BlocksCompensator_setNrGainsFilteringIterations_int(self.as_raw_mut_Detail_BlocksCompensator(), nr_iterations, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_BlocksCompensator_setNrGainsFilteringIterations_int(self.as_raw_mut_Detail_BlocksCompensator(), nr_iterations, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2945,10 +3404,11 @@ pub trait Detail_BlocksGainCompensatorTrait: crate::stitching::Detail_BlocksComp
 
 	#[inline]
 	fn apply(&mut self, index: i32, corner: core::Point, image: &mut dyn core::ToInputOutputArray, mask: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("Detail_BlocksGainCompensator", self.as_raw_mut_Detail_BlocksGainCompensator())?;
 		input_output_array_arg!(image);
 		input_array_arg!(mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_BlocksGainCompensator_apply_int_Point_const__InputOutputArrayR_const__InputArrayR(self.as_raw_mut_Detail_BlocksGainCompensator(), index, corner.opencv_as_extern(), image.as_raw__InputOutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_BlocksGainCompensator_apply_int_Point_const__InputOutputArrayR_const__InputArrayR(self.as_raw_mut_Detail_BlocksGainCompensator(), index, corner.opencv_as_extern(), image.as_raw__InputOutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2956,8 +3416,9 @@ pub trait Detail_BlocksGainCompensatorTrait: crate::stitching::Detail_BlocksComp
 	
 	#[inline]
 	fn get_mat_gains(&mut self, umv: &mut core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("Detail_BlocksGainCompensator", self.as_raw_mut_Detail_BlocksGainCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_BlocksGainCompensator_getMatGains_vector_Mat_R(self.as_raw_mut_Detail_BlocksGainCompensator(), umv.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_BlocksGainCompensator_getMatGains_vector_Mat_R(self.as_raw_mut_Detail_BlocksGainCompensator(), umv.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2965,8 +3426,9 @@ pub trait Detail_BlocksGainCompensatorTrait: crate::stitching::Detail_BlocksComp
 	
 	#[inline]
 	fn set_mat_gains(&mut self, umv: &mut core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("Detail_BlocksGainCompensator", self.as_raw_mut_Detail_BlocksGainCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_BlocksGainCompensator_setMatGains_vector_Mat_R(self.as_raw_mut_Detail_BlocksGainCompensator(), umv.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_BlocksGainCompensator_setMatGains_vector_Mat_R(self.as_raw_mut_Detail_BlocksGainCompensator(), umv.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2985,7 +3447,10 @@ opencv_type_boxed! { Detail_BlocksGainCompensator }
 impl Drop for Detail_BlocksGainCompensator {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_BlocksGainCompensator_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_BlocksGainCompensator_delete(self.as_raw_mut_Detail_BlocksGainCompensator()) };
+		let ptr = self.as_raw_mut_Detail_BlocksGainCompensator();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_BlocksGainCompensator_delete(ptr) };
 	}
 }
 
@@ -3022,7 +3487,7 @@ impl Detail_BlocksGainCompensator {
 	#[inline]
 	pub fn new(bl_width: i32, bl_height: i32) -> Result<crate::stitching::Detail_BlocksGainCompensator> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_BlocksGainCompensator_BlocksGainCompensator_int_int(bl_width, bl_height, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_BlocksGainCompensator_BlocksGainCompensator_int_int(bl_width, bl_height, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_BlocksGainCompensator::opencv_from_extern(ret) };
@@ -3032,7 +3497,7 @@ impl Detail_BlocksGainCompensator {
 	#[inline]
 	pub fn new_1(bl_width: i32, bl_height: i32, nr_feeds: i32) -> Result<crate::stitching::Detail_BlocksGainCompensator> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_BlocksGainCompensator_BlocksGainCompensator_int_int_int(bl_width, bl_height, nr_feeds, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_BlocksGainCompensator_BlocksGainCompensator_int_int_int(bl_width, bl_height, nr_feeds, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_BlocksGainCompensator::opencv_from_extern(ret) };
@@ -3076,7 +3541,10 @@ opencv_type_boxed! { Detail_BundleAdjusterAffine }
 impl Drop for Detail_BundleAdjusterAffine {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_BundleAdjusterAffine_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_BundleAdjusterAffine_delete(self.as_raw_mut_Detail_BundleAdjusterAffine()) };
+		let ptr = self.as_raw_mut_Detail_BundleAdjusterAffine();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_BundleAdjusterAffine_delete(ptr) };
 	}
 }
 
@@ -3110,7 +3578,7 @@ impl Detail_BundleAdjusterAffine {
 	#[inline]
 	pub fn default() -> Result<crate::stitching::Detail_BundleAdjusterAffine> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_BundleAdjusterAffine_BundleAdjusterAffine(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_BundleAdjusterAffine_BundleAdjusterAffine(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_BundleAdjusterAffine::opencv_from_extern(ret) };
@@ -3154,7 +3622,10 @@ opencv_type_boxed! { Detail_BundleAdjusterAffinePartial }
 impl Drop for Detail_BundleAdjusterAffinePartial {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_BundleAdjusterAffinePartial_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_BundleAdjusterAffinePartial_delete(self.as_raw_mut_Detail_BundleAdjusterAffinePartial()) };
+		let ptr = self.as_raw_mut_Detail_BundleAdjusterAffinePartial();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_BundleAdjusterAffinePartial_delete(ptr) };
 	}
 }
 
@@ -3188,7 +3659,7 @@ impl Detail_BundleAdjusterAffinePartial {
 	#[inline]
 	pub fn default() -> Result<crate::stitching::Detail_BundleAdjusterAffinePartial> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_BundleAdjusterAffinePartial_BundleAdjusterAffinePartial(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_BundleAdjusterAffinePartial_BundleAdjusterAffinePartial(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_BundleAdjusterAffinePartial::opencv_from_extern(ret) };
@@ -3203,8 +3674,9 @@ pub trait Detail_BundleAdjusterBaseConst: crate::stitching::Detail_EstimatorCons
 
 	#[inline]
 	fn refinement_mask(&self) -> Result<core::Mat> {
+		crate::core::validity::check("Detail_BundleAdjusterBase", self.as_raw_Detail_BundleAdjusterBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_BundleAdjusterBase_refinementMask_const(self.as_raw_Detail_BundleAdjusterBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_BundleAdjusterBase_refinementMask_const(self.as_raw_Detail_BundleAdjusterBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -3213,8 +3685,9 @@ pub trait Detail_BundleAdjusterBaseConst: crate::stitching::Detail_EstimatorCons
 	
 	#[inline]
 	fn conf_thresh(&self) -> Result<f64> {
+		crate::core::validity::check("Detail_BundleAdjusterBase", self.as_raw_Detail_BundleAdjusterBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_BundleAdjusterBase_confThresh_const(self.as_raw_Detail_BundleAdjusterBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_BundleAdjusterBase_confThresh_const(self.as_raw_Detail_BundleAdjusterBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3227,8 +3700,9 @@ pub trait Detail_BundleAdjusterBase: crate::stitching::Detail_BundleAdjusterBase
 
 	#[inline]
 	fn set_refinement_mask(&mut self, mask: &core::Mat) -> Result<()> {
+		crate::core::validity::check("Detail_BundleAdjusterBase", self.as_raw_mut_Detail_BundleAdjusterBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_BundleAdjusterBase_setRefinementMask_const_MatR(self.as_raw_mut_Detail_BundleAdjusterBase(), mask.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_BundleAdjusterBase_setRefinementMask_const_MatR(self.as_raw_mut_Detail_BundleAdjusterBase(), mask.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3236,8 +3710,9 @@ pub trait Detail_BundleAdjusterBase: crate::stitching::Detail_BundleAdjusterBase
 	
 	#[inline]
 	fn set_conf_thresh(&mut self, conf_thresh: f64) -> Result<()> {
+		crate::core::validity::check("Detail_BundleAdjusterBase", self.as_raw_mut_Detail_BundleAdjusterBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_BundleAdjusterBase_setConfThresh_double(self.as_raw_mut_Detail_BundleAdjusterBase(), conf_thresh, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_BundleAdjusterBase_setConfThresh_double(self.as_raw_mut_Detail_BundleAdjusterBase(), conf_thresh, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3245,8 +3720,9 @@ pub trait Detail_BundleAdjusterBase: crate::stitching::Detail_BundleAdjusterBase
 	
 	#[inline]
 	fn term_criteria(&mut self) -> Result<core::TermCriteria> {
+		crate::core::validity::check("Detail_BundleAdjusterBase", self.as_raw_mut_Detail_BundleAdjusterBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_BundleAdjusterBase_termCriteria(self.as_raw_mut_Detail_BundleAdjusterBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_BundleAdjusterBase_termCriteria(self.as_raw_mut_Detail_BundleAdjusterBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3254,8 +3730,9 @@ pub trait Detail_BundleAdjusterBase: crate::stitching::Detail_BundleAdjusterBase
 	
 	#[inline]
 	fn set_term_criteria(&mut self, term_criteria: core::TermCriteria) -> Result<()> {
+		crate::core::validity::check("Detail_BundleAdjusterBase", self.as_raw_mut_Detail_BundleAdjusterBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_BundleAdjusterBase_setTermCriteria_const_TermCriteriaR(self.as_raw_mut_Detail_BundleAdjusterBase(), &term_criteria, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_BundleAdjusterBase_setTermCriteria_const_TermCriteriaR(self.as_raw_mut_Detail_BundleAdjusterBase(), &term_criteria, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3290,7 +3767,10 @@ opencv_type_boxed! { Detail_BundleAdjusterRay }
 impl Drop for Detail_BundleAdjusterRay {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_BundleAdjusterRay_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_BundleAdjusterRay_delete(self.as_raw_mut_Detail_BundleAdjusterRay()) };
+		let ptr = self.as_raw_mut_Detail_BundleAdjusterRay();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_BundleAdjusterRay_delete(ptr) };
 	}
 }
 
@@ -3324,7 +3804,7 @@ impl Detail_BundleAdjusterRay {
 	#[inline]
 	pub fn default() -> Result<crate::stitching::Detail_BundleAdjusterRay> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_BundleAdjusterRay_BundleAdjusterRay(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_BundleAdjusterRay_BundleAdjusterRay(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_BundleAdjusterRay::opencv_from_extern(ret) };
@@ -3362,7 +3842,10 @@ opencv_type_boxed! { Detail_BundleAdjusterReproj }
 impl Drop for Detail_BundleAdjusterReproj {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_BundleAdjusterReproj_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_BundleAdjusterReproj_delete(self.as_raw_mut_Detail_BundleAdjusterReproj()) };
+		let ptr = self.as_raw_mut_Detail_BundleAdjusterReproj();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_BundleAdjusterReproj_delete(ptr) };
 	}
 }
 
@@ -3396,7 +3879,7 @@ impl Detail_BundleAdjusterReproj {
 	#[inline]
 	pub fn default() -> Result<crate::stitching::Detail_BundleAdjusterReproj> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_BundleAdjusterReproj_BundleAdjusterReproj(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_BundleAdjusterReproj_BundleAdjusterReproj(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_BundleAdjusterReproj::opencv_from_extern(ret) };
@@ -3414,46 +3897,101 @@ pub trait Detail_CameraParamsTraitConst {
 
 	#[inline]
 	fn focal(&self) -> f64 {
-		let ret = unsafe { sys::cv_detail_CameraParams_getPropFocal_const(self.as_raw_Detail_CameraParams()) };
+		crate::core::validity::debug_check("Detail_CameraParams", self.as_raw_Detail_CameraParams());
+		let ret = unsafe { ffi_call!(cv_detail_CameraParams_getPropFocal_const(self.as_raw_Detail_CameraParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [focal](Detail_CameraParamsTraitConst::focal), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_focal(&self) -> Result<f64> {
+		crate::core::validity::check("Detail_CameraParams", self.as_raw_Detail_CameraParams())?;
+		Ok(Detail_CameraParamsTraitConst::focal(self))
+	}
+	
 	#[inline]
 	fn aspect(&self) -> f64 {
-		let ret = unsafe { sys::cv_detail_CameraParams_getPropAspect_const(self.as_raw_Detail_CameraParams()) };
+		crate::core::validity::debug_check("Detail_CameraParams", self.as_raw_Detail_CameraParams());
+		let ret = unsafe { ffi_call!(cv_detail_CameraParams_getPropAspect_const(self.as_raw_Detail_CameraParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [aspect](Detail_CameraParamsTraitConst::aspect), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_aspect(&self) -> Result<f64> {
+		crate::core::validity::check("Detail_CameraParams", self.as_raw_Detail_CameraParams())?;
+		Ok(Detail_CameraParamsTraitConst::aspect(self))
+	}
+	
 	#[inline]
 	fn ppx(&self) -> f64 {
-		let ret = unsafe { sys::cv_detail_CameraParams_getPropPpx_const(self.as_raw_Detail_CameraParams()) };
+		crate::core::validity::debug_check("Detail_CameraParams", self.as_raw_Detail_CameraParams());
+		let ret = unsafe { ffi_call!(cv_detail_CameraParams_getPropPpx_const(self.as_raw_Detail_CameraParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [ppx](Detail_CameraParamsTraitConst::ppx), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_ppx(&self) -> Result<f64> {
+		crate::core::validity::check("Detail_CameraParams", self.as_raw_Detail_CameraParams())?;
+		Ok(Detail_CameraParamsTraitConst::ppx(self))
+	}
+	
 	#[inline]
 	fn ppy(&self) -> f64 {
-		let ret = unsafe { sys::cv_detail_CameraParams_getPropPpy_const(self.as_raw_Detail_CameraParams()) };
+		crate::core::validity::debug_check("Detail_CameraParams", self.as_raw_Detail_CameraParams());
+		let ret = unsafe { ffi_call!(cv_detail_CameraParams_getPropPpy_const(self.as_raw_Detail_CameraParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [ppy](Detail_CameraParamsTraitConst::ppy), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_ppy(&self) -> Result<f64> {
+		crate::core::validity::check("Detail_CameraParams", self.as_raw_Detail_CameraParams())?;
+		Ok(Detail_CameraParamsTraitConst::ppy(self))
+	}
+	
 	#[inline]
 	fn r(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_detail_CameraParams_getPropR_const(self.as_raw_Detail_CameraParams()) };
+		crate::core::validity::debug_check("Detail_CameraParams", self.as_raw_Detail_CameraParams());
+		let ret = unsafe { ffi_call!(cv_detail_CameraParams_getPropR_const(self.as_raw_Detail_CameraParams())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [r](Detail_CameraParamsTraitConst::r), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_r(&self) -> Result<core::Mat> {
+		crate::core::validity::check("Detail_CameraParams", self.as_raw_Detail_CameraParams())?;
+		Ok(Detail_CameraParamsTraitConst::r(self))
+	}
+	
 	#[inline]
 	fn t(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_detail_CameraParams_getPropT_const(self.as_raw_Detail_CameraParams()) };
+		crate::core::validity::debug_check("Detail_CameraParams", self.as_raw_Detail_CameraParams());
+		let ret = unsafe { ffi_call!(cv_detail_CameraParams_getPropT_const(self.as_raw_Detail_CameraParams())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [t](Detail_CameraParamsTraitConst::t), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_t(&self) -> Result<core::Mat> {
+		crate::core::validity::check("Detail_CameraParams", self.as_raw_Detail_CameraParams())?;
+		Ok(Detail_CameraParamsTraitConst::t(self))
+	}
+	
 	#[inline]
 	fn k(&self) -> Result<core::Mat> {
+		crate::core::validity::check("Detail_CameraParams", self.as_raw_Detail_CameraParams())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_CameraParams_K_const(self.as_raw_Detail_CameraParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_CameraParams_K_const(self.as_raw_Detail_CameraParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -3467,37 +4005,43 @@ pub trait Detail_CameraParamsTrait: crate::stitching::Detail_CameraParamsTraitCo
 
 	#[inline]
 	fn set_focal(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_detail_CameraParams_setPropFocal_double(self.as_raw_mut_Detail_CameraParams(), val) };
+		crate::core::validity::debug_check("Detail_CameraParams", self.as_raw_mut_Detail_CameraParams());
+		let ret = unsafe { ffi_call!(cv_detail_CameraParams_setPropFocal_double(self.as_raw_mut_Detail_CameraParams(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_aspect(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_detail_CameraParams_setPropAspect_double(self.as_raw_mut_Detail_CameraParams(), val) };
+		crate::core::validity::debug_check("Detail_CameraParams", self.as_raw_mut_Detail_CameraParams());
+		let ret = unsafe { ffi_call!(cv_detail_CameraParams_setPropAspect_double(self.as_raw_mut_Detail_CameraParams(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_ppx(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_detail_CameraParams_setPropPpx_double(self.as_raw_mut_Detail_CameraParams(), val) };
+		crate::core::validity::debug_check("Detail_CameraParams", self.as_raw_mut_Detail_CameraParams());
+		let ret = unsafe { ffi_call!(cv_detail_CameraParams_setPropPpx_double(self.as_raw_mut_Detail_CameraParams(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_ppy(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_detail_CameraParams_setPropPpy_double(self.as_raw_mut_Detail_CameraParams(), val) };
+		crate::core::validity::debug_check("Detail_CameraParams", self.as_raw_mut_Detail_CameraParams());
+		let ret = unsafe { ffi_call!(cv_detail_CameraParams_setPropPpy_double(self.as_raw_mut_Detail_CameraParams(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_r(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_detail_CameraParams_setPropR_Mat(self.as_raw_mut_Detail_CameraParams(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("Detail_CameraParams", self.as_raw_mut_Detail_CameraParams());
+		let ret = unsafe { ffi_call!(cv_detail_CameraParams_setPropR_Mat(self.as_raw_mut_Detail_CameraParams(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_t(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_detail_CameraParams_setPropT_Mat(self.as_raw_mut_Detail_CameraParams(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("Detail_CameraParams", self.as_raw_mut_Detail_CameraParams());
+		let ret = unsafe { ffi_call!(cv_detail_CameraParams_setPropT_Mat(self.as_raw_mut_Detail_CameraParams(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
@@ -3516,7 +4060,10 @@ opencv_type_boxed! { Detail_CameraParams }
 impl Drop for Detail_CameraParams {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_CameraParams_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_CameraParams_delete(self.as_raw_mut_Detail_CameraParams()) };
+		let ptr = self.as_raw_mut_Detail_CameraParams();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_CameraParams_delete(ptr) };
 	}
 }
 
@@ -3534,7 +4081,7 @@ impl Detail_CameraParams {
 	#[inline]
 	pub fn default() -> Result<crate::stitching::Detail_CameraParams> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_CameraParams_CameraParams(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_CameraParams_CameraParams(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_CameraParams::opencv_from_extern(ret) };
@@ -3544,7 +4091,7 @@ impl Detail_CameraParams {
 	#[inline]
 	pub fn copy(other: &crate::stitching::Detail_CameraParams) -> Result<crate::stitching::Detail_CameraParams> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_CameraParams_CameraParams_const_CameraParamsR(other.as_raw_Detail_CameraParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_CameraParams_CameraParams_const_CameraParamsR(other.as_raw_Detail_CameraParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_CameraParams::opencv_from_extern(ret) };
@@ -3560,8 +4107,9 @@ pub trait Detail_ChannelsCompensatorTraitConst: crate::stitching::Detail_Exposur
 
 	#[inline]
 	fn get_similarity_threshold(&self) -> Result<f64> {
+		crate::core::validity::check("Detail_ChannelsCompensator", self.as_raw_Detail_ChannelsCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_ChannelsCompensator_getSimilarityThreshold_const(self.as_raw_Detail_ChannelsCompensator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_ChannelsCompensator_getSimilarityThreshold_const(self.as_raw_Detail_ChannelsCompensator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3569,8 +4117,9 @@ pub trait Detail_ChannelsCompensatorTraitConst: crate::stitching::Detail_Exposur
 	
 	#[inline]
 	fn gains(&self) -> Result<core::Vector<core::Scalar>> {
+		crate::core::validity::check("Detail_ChannelsCompensator", self.as_raw_Detail_ChannelsCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_ChannelsCompensator_gains_const(self.as_raw_Detail_ChannelsCompensator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_ChannelsCompensator_gains_const(self.as_raw_Detail_ChannelsCompensator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<core::Scalar>::opencv_from_extern(ret) };
@@ -3584,10 +4133,11 @@ pub trait Detail_ChannelsCompensatorTrait: crate::stitching::Detail_ChannelsComp
 
 	#[inline]
 	fn apply(&mut self, index: i32, corner: core::Point, image: &mut dyn core::ToInputOutputArray, mask: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("Detail_ChannelsCompensator", self.as_raw_mut_Detail_ChannelsCompensator())?;
 		input_output_array_arg!(image);
 		input_array_arg!(mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_ChannelsCompensator_apply_int_Point_const__InputOutputArrayR_const__InputArrayR(self.as_raw_mut_Detail_ChannelsCompensator(), index, corner.opencv_as_extern(), image.as_raw__InputOutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_ChannelsCompensator_apply_int_Point_const__InputOutputArrayR_const__InputArrayR(self.as_raw_mut_Detail_ChannelsCompensator(), index, corner.opencv_as_extern(), image.as_raw__InputOutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3595,8 +4145,9 @@ pub trait Detail_ChannelsCompensatorTrait: crate::stitching::Detail_ChannelsComp
 	
 	#[inline]
 	fn get_mat_gains(&mut self, umv: &mut core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("Detail_ChannelsCompensator", self.as_raw_mut_Detail_ChannelsCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_ChannelsCompensator_getMatGains_vector_Mat_R(self.as_raw_mut_Detail_ChannelsCompensator(), umv.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_ChannelsCompensator_getMatGains_vector_Mat_R(self.as_raw_mut_Detail_ChannelsCompensator(), umv.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3604,8 +4155,9 @@ pub trait Detail_ChannelsCompensatorTrait: crate::stitching::Detail_ChannelsComp
 	
 	#[inline]
 	fn set_mat_gains(&mut self, umv: &mut core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("Detail_ChannelsCompensator", self.as_raw_mut_Detail_ChannelsCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_ChannelsCompensator_setMatGains_vector_Mat_R(self.as_raw_mut_Detail_ChannelsCompensator(), umv.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_ChannelsCompensator_setMatGains_vector_Mat_R(self.as_raw_mut_Detail_ChannelsCompensator(), umv.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3613,8 +4165,9 @@ pub trait Detail_ChannelsCompensatorTrait: crate::stitching::Detail_ChannelsComp
 	
 	#[inline]
 	fn set_nr_feeds(&mut self, nr_feeds: i32) -> Result<()> {
+		crate::core::validity::check("Detail_ChannelsCompensator", self.as_raw_mut_Detail_ChannelsCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_ChannelsCompensator_setNrFeeds_int(self.as_raw_mut_Detail_ChannelsCompensator(), nr_feeds, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_ChannelsCompensator_setNrFeeds_int(self.as_raw_mut_Detail_ChannelsCompensator(), nr_feeds, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3622,8 +4175,9 @@ pub trait Detail_ChannelsCompensatorTrait: crate::stitching::Detail_ChannelsComp
 	
 	#[inline]
 	fn get_nr_feeds(&mut self) -> Result<i32> {
+		crate::core::validity::check("Detail_ChannelsCompensator", self.as_raw_mut_Detail_ChannelsCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_ChannelsCompensator_getNrFeeds(self.as_raw_mut_Detail_ChannelsCompensator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_ChannelsCompensator_getNrFeeds(self.as_raw_mut_Detail_ChannelsCompensator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3631,8 +4185,9 @@ pub trait Detail_ChannelsCompensatorTrait: crate::stitching::Detail_ChannelsComp
 	
 	#[inline]
 	fn set_similarity_threshold(&mut self, similarity_threshold: f64) -> Result<()> {
+		crate::core::validity::check("Detail_ChannelsCompensator", self.as_raw_mut_Detail_ChannelsCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_ChannelsCompensator_setSimilarityThreshold_double(self.as_raw_mut_Detail_ChannelsCompensator(), similarity_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_ChannelsCompensator_setSimilarityThreshold_double(self.as_raw_mut_Detail_ChannelsCompensator(), similarity_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3651,7 +4206,10 @@ opencv_type_boxed! { Detail_ChannelsCompensator }
 impl Drop for Detail_ChannelsCompensator {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_ChannelsCompensator_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_ChannelsCompensator_delete(self.as_raw_mut_Detail_ChannelsCompensator()) };
+		let ptr = self.as_raw_mut_Detail_ChannelsCompensator();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_ChannelsCompensator_delete(ptr) };
 	}
 }
 
@@ -3679,7 +4237,7 @@ impl Detail_ChannelsCompensator {
 	#[inline]
 	pub fn new(nr_feeds: i32) -> Result<crate::stitching::Detail_ChannelsCompensator> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_ChannelsCompensator_ChannelsCompensator_int(nr_feeds, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_ChannelsCompensator_ChannelsCompensator_int(nr_feeds, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_ChannelsCompensator::opencv_from_extern(ret) };
@@ -3693,16 +4251,34 @@ pub trait Detail_CompressedRectilinearPortraitProjectorTraitConst: crate::stitch
 
 	#[inline]
 	fn a(&self) -> f32 {
-		let ret = unsafe { sys::cv_detail_CompressedRectilinearPortraitProjector_getPropA_const(self.as_raw_Detail_CompressedRectilinearPortraitProjector()) };
+		crate::core::validity::debug_check("Detail_CompressedRectilinearPortraitProjector", self.as_raw_Detail_CompressedRectilinearPortraitProjector());
+		let ret = unsafe { ffi_call!(cv_detail_CompressedRectilinearPortraitProjector_getPropA_const(self.as_raw_Detail_CompressedRectilinearPortraitProjector())) };
 		ret
 	}
 	
+	/// Non-panicking version of [a](Detail_CompressedRectilinearPortraitProjectorTraitConst::a), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_a(&self) -> Result<f32> {
+		crate::core::validity::check("Detail_CompressedRectilinearPortraitProjector", self.as_raw_Detail_CompressedRectilinearPortraitProjector())?;
+		Ok(Detail_CompressedRectilinearPortraitProjectorTraitConst::a(self))
+	}
+	
 	#[inline]
 	fn b(&self) -> f32 {
-		let ret = unsafe { sys::cv_detail_CompressedRectilinearPortraitProjector_getPropB_const(self.as_raw_Detail_CompressedRectilinearPortraitProjector()) };
+		crate::core::validity::debug_check("Detail_CompressedRectilinearPortraitProjector", self.as_raw_Detail_CompressedRectilinearPortraitProjector());
+		let ret = unsafe { ffi_call!(cv_detail_CompressedRectilinearPortraitProjector_getPropB_const(self.as_raw_Detail_CompressedRectilinearPortraitProjector())) };
 		ret
 	}
 	
+	/// Non-panicking version of [b](Detail_CompressedRectilinearPortraitProjectorTraitConst::b), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_b(&self) -> Result<f32> {
+		crate::core::validity::check("Detail_CompressedRectilinearPortraitProjector", self.as_raw_Detail_CompressedRectilinearPortraitProjector())?;
+		Ok(Detail_CompressedRectilinearPortraitProjectorTraitConst::b(self))
+	}
+	
 }
 
 pub trait Detail_CompressedRectilinearPortraitProjectorTrait: crate::stitching::Detail_CompressedRectilinearPortraitProjectorTraitConst + crate::stitching::Detail_ProjectorBaseTrait {
@@ -3710,20 +4286,23 @@ pub trait Detail_CompressedRectilinearPortraitProjectorTrait: crate::stitching::
 
 	#[inline]
 	fn set_a(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_detail_CompressedRectilinearPortraitProjector_setPropA_float(self.as_raw_mut_Detail_CompressedRectilinearPortraitProjector(), val) };
+		crate::core::validity::debug_check("Detail_CompressedRectilinearPortraitProjector", self.as_raw_mut_Detail_CompressedRectilinearPortraitProjector());
+		let ret = unsafe { ffi_call!(cv_detail_CompressedRectilinearPortraitProjector_setPropA_float(self.as_raw_mut_Detail_CompressedRectilinearPortraitProjector(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_b(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_detail_CompressedRectilinearPortraitProjector_setPropB_float(self.as_raw_mut_Detail_CompressedRectilinearPortraitProjector(), val) };
+		crate::core::validity::debug_check("Detail_CompressedRectilinearPortraitProjector", self.as_raw_mut_Detail_CompressedRectilinearPortraitProjector());
+		let ret = unsafe { ffi_call!(cv_detail_CompressedRectilinearPortraitProjector_setPropB_float(self.as_raw_mut_Detail_CompressedRectilinearPortraitProjector(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn map_forward(&mut self, x: f32, y: f32, u: &mut f32, v: &mut f32) -> Result<()> {
+		crate::core::validity::check("Detail_CompressedRectilinearPortraitProjector", self.as_raw_mut_Detail_CompressedRectilinearPortraitProjector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_CompressedRectilinearPortraitProjector_mapForward_float_float_floatR_floatR(self.as_raw_mut_Detail_CompressedRectilinearPortraitProjector(), x, y, u, v, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_CompressedRectilinearPortraitProjector_mapForward_float_float_floatR_floatR(self.as_raw_mut_Detail_CompressedRectilinearPortraitProjector(), x, y, u, v, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3731,8 +4310,9 @@ pub trait Detail_CompressedRectilinearPortraitProjectorTrait: crate::stitching::
 	
 	#[inline]
 	fn map_backward(&mut self, u: f32, v: f32, x: &mut f32, y: &mut f32) -> Result<()> {
+		crate::core::validity::check("Detail_CompressedRectilinearPortraitProjector", self.as_raw_mut_Detail_CompressedRectilinearPortraitProjector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_CompressedRectilinearPortraitProjector_mapBackward_float_float_floatR_floatR(self.as_raw_mut_Detail_CompressedRectilinearPortraitProjector(), u, v, x, y, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_CompressedRectilinearPortraitProjector_mapBackward_float_float_floatR_floatR(self.as_raw_mut_Detail_CompressedRectilinearPortraitProjector(), u, v, x, y, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3749,7 +4329,10 @@ opencv_type_boxed! { Detail_CompressedRectilinearPortraitProjector }
 impl Drop for Detail_CompressedRectilinearPortraitProjector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_CompressedRectilinearPortraitProjector_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_CompressedRectilinearPortraitProjector_delete(self.as_raw_mut_Detail_CompressedRectilinearPortraitProjector()) };
+		let ptr = self.as_raw_mut_Detail_CompressedRectilinearPortraitProjector();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_CompressedRectilinearPortraitProjector_delete(ptr) };
 	}
 }
 
@@ -3795,7 +4378,10 @@ opencv_type_boxed! { Detail_CompressedRectilinearPortraitWarper }
 impl Drop for Detail_CompressedRectilinearPortraitWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_CompressedRectilinearPortraitWarper_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_CompressedRectilinearPortraitWarper_delete(self.as_raw_mut_Detail_CompressedRectilinearPortraitWarper()) };
+		let ptr = self.as_raw_mut_Detail_CompressedRectilinearPortraitWarper();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_CompressedRectilinearPortraitWarper_delete(ptr) };
 	}
 }
 
@@ -3824,7 +4410,7 @@ impl Detail_CompressedRectilinearPortraitWarper {
 	#[inline]
 	pub fn new(scale: f32, a: f32, b: f32) -> Result<crate::stitching::Detail_CompressedRectilinearPortraitWarper> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_CompressedRectilinearPortraitWarper_CompressedRectilinearPortraitWarper_float_float_float(scale, a, b, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_CompressedRectilinearPortraitWarper_CompressedRectilinearPortraitWarper_float_float_float(scale, a, b, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_CompressedRectilinearPortraitWarper::opencv_from_extern(ret) };
@@ -3838,16 +4424,34 @@ pub trait Detail_CompressedRectilinearProjectorTraitConst: crate::stitching::Det
 
 	#[inline]
 	fn a(&self) -> f32 {
-		let ret = unsafe { sys::cv_detail_CompressedRectilinearProjector_getPropA_const(self.as_raw_Detail_CompressedRectilinearProjector()) };
+		crate::core::validity::debug_check("Detail_CompressedRectilinearProjector", self.as_raw_Detail_CompressedRectilinearProjector());
+		let ret = unsafe { ffi_call!(cv_detail_CompressedRectilinearProjector_getPropA_const(self.as_raw_Detail_CompressedRectilinearProjector())) };
 		ret
 	}
 	
+	/// Non-panicking version of [a](Detail_CompressedRectilinearProjectorTraitConst::a), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_a(&self) -> Result<f32> {
+		crate::core::validity::check("Detail_CompressedRectilinearProjector", self.as_raw_Detail_CompressedRectilinearProjector())?;
+		Ok(Detail_CompressedRectilinearProjectorTraitConst::a(self))
+	}
+	
 	#[inline]
 	fn b(&self) -> f32 {
-		let ret = unsafe { sys::cv_detail_CompressedRectilinearProjector_getPropB_const(self.as_raw_Detail_CompressedRectilinearProjector()) };
+		crate::core::validity::debug_check("Detail_CompressedRectilinearProjector", self.as_raw_Detail_CompressedRectilinearProjector());
+		let ret = unsafe { ffi_call!(cv_detail_CompressedRectilinearProjector_getPropB_const(self.as_raw_Detail_CompressedRectilinearProjector())) };
 		ret
 	}
 	
+	/// Non-panicking version of [b](Detail_CompressedRectilinearProjectorTraitConst::b), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_b(&self) -> Result<f32> {
+		crate::core::validity::check("Detail_CompressedRectilinearProjector", self.as_raw_Detail_CompressedRectilinearProjector())?;
+		Ok(Detail_CompressedRectilinearProjectorTraitConst::b(self))
+	}
+	
 }
 
 pub trait Detail_CompressedRectilinearProjectorTrait: crate::stitching::Detail_CompressedRectilinearProjectorTraitConst + crate::stitching::Detail_ProjectorBaseTrait {
@@ -3855,20 +4459,23 @@ pub trait Detail_CompressedRectilinearProjectorTrait: crate::stitching::Detail_C
 
 	#[inline]
 	fn set_a(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_detail_CompressedRectilinearProjector_setPropA_float(self.as_raw_mut_Detail_CompressedRectilinearProjector(), val) };
+		crate::core::validity::debug_check("Detail_CompressedRectilinearProjector", self.as_raw_mut_Detail_CompressedRectilinearProjector());
+		let ret = unsafe { ffi_call!(cv_detail_CompressedRectilinearProjector_setPropA_float(self.as_raw_mut_Detail_CompressedRectilinearProjector(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_b(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_detail_CompressedRectilinearProjector_setPropB_float(self.as_raw_mut_Detail_CompressedRectilinearProjector(), val) };
+		crate::core::validity::debug_check("Detail_CompressedRectilinearProjector", self.as_raw_mut_Detail_CompressedRectilinearProjector());
+		let ret = unsafe { ffi_call!(cv_detail_CompressedRectilinearProjector_setPropB_float(self.as_raw_mut_Detail_CompressedRectilinearProjector(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn map_forward(&mut self, x: f32, y: f32, u: &mut f32, v: &mut f32) -> Result<()> {
+		crate::core::validity::check("Detail_CompressedRectilinearProjector", self.as_raw_mut_Detail_CompressedRectilinearProjector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_CompressedRectilinearProjector_mapForward_float_float_floatR_floatR(self.as_raw_mut_Detail_CompressedRectilinearProjector(), x, y, u, v, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_CompressedRectilinearProjector_mapForward_float_float_floatR_floatR(self.as_raw_mut_Detail_CompressedRectilinearProjector(), x, y, u, v, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3876,8 +4483,9 @@ pub trait Detail_CompressedRectilinearProjectorTrait: crate::stitching::Detail_C
 	
 	#[inline]
 	fn map_backward(&mut self, u: f32, v: f32, x: &mut f32, y: &mut f32) -> Result<()> {
+		crate::core::validity::check("Detail_CompressedRectilinearProjector", self.as_raw_mut_Detail_CompressedRectilinearProjector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_CompressedRectilinearProjector_mapBackward_float_float_floatR_floatR(self.as_raw_mut_Detail_CompressedRectilinearProjector(), u, v, x, y, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_CompressedRectilinearProjector_mapBackward_float_float_floatR_floatR(self.as_raw_mut_Detail_CompressedRectilinearProjector(), u, v, x, y, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3894,7 +4502,10 @@ opencv_type_boxed! { Detail_CompressedRectilinearProjector }
 impl Drop for Detail_CompressedRectilinearProjector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_CompressedRectilinearProjector_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_CompressedRectilinearProjector_delete(self.as_raw_mut_Detail_CompressedRectilinearProjector()) };
+		let ptr = self.as_raw_mut_Detail_CompressedRectilinearProjector();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_CompressedRectilinearProjector_delete(ptr) };
 	}
 }
 
@@ -3940,7 +4551,10 @@ opencv_type_boxed! { Detail_CompressedRectilinearWarper }
 impl Drop for Detail_CompressedRectilinearWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_CompressedRectilinearWarper_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_CompressedRectilinearWarper_delete(self.as_raw_mut_Detail_CompressedRectilinearWarper()) };
+		let ptr = self.as_raw_mut_Detail_CompressedRectilinearWarper();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_CompressedRectilinearWarper_delete(ptr) };
 	}
 }
 
@@ -3969,7 +4583,7 @@ impl Detail_CompressedRectilinearWarper {
 	#[inline]
 	pub fn new(scale: f32, a: f32, b: f32) -> Result<crate::stitching::Detail_CompressedRectilinearWarper> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_CompressedRectilinearWarper_CompressedRectilinearWarper_float_float_float(scale, a, b, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_CompressedRectilinearWarper_CompressedRectilinearWarper_float_float_float(scale, a, b, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_CompressedRectilinearWarper::opencv_from_extern(ret) };
@@ -3988,8 +4602,9 @@ pub trait Detail_CylindricalPortraitProjectorTrait: crate::stitching::Detail_Cyl
 
 	#[inline]
 	fn map_forward(&mut self, x: f32, y: f32, u: &mut f32, v: &mut f32) -> Result<()> {
+		crate::core::validity::check("Detail_CylindricalPortraitProjector", self.as_raw_mut_Detail_CylindricalPortraitProjector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_CylindricalPortraitProjector_mapForward_float_float_floatR_floatR(self.as_raw_mut_Detail_CylindricalPortraitProjector(), x, y, u, v, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_CylindricalPortraitProjector_mapForward_float_float_floatR_floatR(self.as_raw_mut_Detail_CylindricalPortraitProjector(), x, y, u, v, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3997,8 +4612,9 @@ pub trait Detail_CylindricalPortraitProjectorTrait: crate::stitching::Detail_Cyl
 	
 	#[inline]
 	fn map_backward(&mut self, u: f32, v: f32, x: &mut f32, y: &mut f32) -> Result<()> {
+		crate::core::validity::check("Detail_CylindricalPortraitProjector", self.as_raw_mut_Detail_CylindricalPortraitProjector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_CylindricalPortraitProjector_mapBackward_float_float_floatR_floatR(self.as_raw_mut_Detail_CylindricalPortraitProjector(), u, v, x, y, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_CylindricalPortraitProjector_mapBackward_float_float_floatR_floatR(self.as_raw_mut_Detail_CylindricalPortraitProjector(), u, v, x, y, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4015,7 +4631,10 @@ opencv_type_boxed! { Detail_CylindricalPortraitProjector }
 impl Drop for Detail_CylindricalPortraitProjector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_CylindricalPortraitProjector_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_CylindricalPortraitProjector_delete(self.as_raw_mut_Detail_CylindricalPortraitProjector()) };
+		let ptr = self.as_raw_mut_Detail_CylindricalPortraitProjector();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_CylindricalPortraitProjector_delete(ptr) };
 	}
 }
 
@@ -4061,7 +4680,10 @@ opencv_type_boxed! { Detail_CylindricalPortraitWarper }
 impl Drop for Detail_CylindricalPortraitWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_CylindricalPortraitWarper_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_CylindricalPortraitWarper_delete(self.as_raw_mut_Detail_CylindricalPortraitWarper()) };
+		let ptr = self.as_raw_mut_Detail_CylindricalPortraitWarper();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_CylindricalPortraitWarper_delete(ptr) };
 	}
 }
 
@@ -4087,7 +4709,7 @@ impl Detail_CylindricalPortraitWarper {
 	#[inline]
 	pub fn new(scale: f32) -> Result<crate::stitching::Detail_CylindricalPortraitWarper> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_CylindricalPortraitWarper_CylindricalPortraitWarper_float(scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_CylindricalPortraitWarper_CylindricalPortraitWarper_float(scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_CylindricalPortraitWarper::opencv_from_extern(ret) };
@@ -4106,8 +4728,9 @@ pub trait Detail_CylindricalProjectorTrait: crate::stitching::Detail_Cylindrical
 
 	#[inline]
 	fn map_forward(&mut self, x: f32, y: f32, u: &mut f32, v: &mut f32) -> Result<()> {
+		crate::core::validity::check("Detail_CylindricalProjector", self.as_raw_mut_Detail_CylindricalProjector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_CylindricalProjector_mapForward_float_float_floatR_floatR(self.as_raw_mut_Detail_CylindricalProjector(), x, y, u, v, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_CylindricalProjector_mapForward_float_float_floatR_floatR(self.as_raw_mut_Detail_CylindricalProjector(), x, y, u, v, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4115,8 +4738,9 @@ pub trait Detail_CylindricalProjectorTrait: crate::stitching::Detail_Cylindrical
 	
 	#[inline]
 	fn map_backward(&mut self, u: f32, v: f32, x: &mut f32, y: &mut f32) -> Result<()> {
+		crate::core::validity::check("Detail_CylindricalProjector", self.as_raw_mut_Detail_CylindricalProjector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_CylindricalProjector_mapBackward_float_float_floatR_floatR(self.as_raw_mut_Detail_CylindricalProjector(), u, v, x, y, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_CylindricalProjector_mapBackward_float_float_floatR_floatR(self.as_raw_mut_Detail_CylindricalProjector(), u, v, x, y, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4133,7 +4757,10 @@ opencv_type_boxed! { Detail_CylindricalProjector }
 impl Drop for Detail_CylindricalProjector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_CylindricalProjector_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_CylindricalProjector_delete(self.as_raw_mut_Detail_CylindricalProjector()) };
+		let ptr = self.as_raw_mut_Detail_CylindricalProjector();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_CylindricalProjector_delete(ptr) };
 	}
 }
 
@@ -4171,12 +4798,13 @@ pub trait Detail_CylindricalWarperTrait: crate::stitching::Detail_CylindricalWar
 
 	#[inline]
 	fn build_maps(&mut self, src_size: core::Size, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, xmap: &mut dyn core::ToOutputArray, ymap: &mut dyn core::ToOutputArray) -> Result<core::Rect> {
+		crate::core::validity::check("Detail_CylindricalWarper", self.as_raw_mut_Detail_CylindricalWarper())?;
 		input_array_arg!(k);
 		input_array_arg!(r);
 		output_array_arg!(xmap);
 		output_array_arg!(ymap);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_CylindricalWarper_buildMaps_Size_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_Detail_CylindricalWarper(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), xmap.as_raw__OutputArray(), ymap.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_CylindricalWarper_buildMaps_Size_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_Detail_CylindricalWarper(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), xmap.as_raw__OutputArray(), ymap.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4184,12 +4812,13 @@ pub trait Detail_CylindricalWarperTrait: crate::stitching::Detail_CylindricalWar
 	
 	#[inline]
 	fn warp(&mut self, src: &dyn core::ToInputArray, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, interp_mode: i32, border_mode: i32, dst: &mut dyn core::ToOutputArray) -> Result<core::Point> {
+		crate::core::validity::check("Detail_CylindricalWarper", self.as_raw_mut_Detail_CylindricalWarper())?;
 		input_array_arg!(src);
 		input_array_arg!(k);
 		input_array_arg!(r);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_CylindricalWarper_warp_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_int_const__OutputArrayR(self.as_raw_mut_Detail_CylindricalWarper(), src.as_raw__InputArray(), k.as_raw__InputArray(), r.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_CylindricalWarper_warp_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_int_const__OutputArrayR(self.as_raw_mut_Detail_CylindricalWarper(), src.as_raw__InputArray(), k.as_raw__InputArray(), r.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4207,7 +4836,10 @@ opencv_type_boxed! { Detail_CylindricalWarper }
 impl Drop for Detail_CylindricalWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_CylindricalWarper_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_CylindricalWarper_delete(self.as_raw_mut_Detail_CylindricalWarper()) };
+		let ptr = self.as_raw_mut_Detail_CylindricalWarper();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_CylindricalWarper_delete(ptr) };
 	}
 }
 
@@ -4237,7 +4869,7 @@ impl Detail_CylindricalWarper {
 	#[inline]
 	pub fn new(scale: f32) -> Result<crate::stitching::Detail_CylindricalWarper> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_CylindricalWarper_CylindricalWarper_float(scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_CylindricalWarper_CylindricalWarper_float(scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_CylindricalWarper::opencv_from_extern(ret) };
@@ -4258,12 +4890,13 @@ pub trait Detail_CylindricalWarperGpuTrait: crate::stitching::Detail_Cylindrical
 
 	#[inline]
 	fn build_maps(&mut self, src_size: core::Size, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, xmap: &mut dyn core::ToOutputArray, ymap: &mut dyn core::ToOutputArray) -> Result<core::Rect> {
+		crate::core::validity::check("Detail_CylindricalWarperGpu", self.as_raw_mut_Detail_CylindricalWarperGpu())?;
 		input_array_arg!(k);
 		input_array_arg!(r);
 		output_array_arg!(xmap);
 		output_array_arg!(ymap);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_CylindricalWarperGpu_buildMaps_Size_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_Detail_CylindricalWarperGpu(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), xmap.as_raw__OutputArray(), ymap.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_CylindricalWarperGpu_buildMaps_Size_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_Detail_CylindricalWarperGpu(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), xmap.as_raw__OutputArray(), ymap.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4271,12 +4904,13 @@ pub trait Detail_CylindricalWarperGpuTrait: crate::stitching::Detail_Cylindrical
 	
 	#[inline]
 	fn warp(&mut self, src: &dyn core::ToInputArray, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, interp_mode: i32, border_mode: i32, dst: &mut dyn core::ToOutputArray) -> Result<core::Point> {
+		crate::core::validity::check("Detail_CylindricalWarperGpu", self.as_raw_mut_Detail_CylindricalWarperGpu())?;
 		input_array_arg!(src);
 		input_array_arg!(k);
 		input_array_arg!(r);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_CylindricalWarperGpu_warp_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_int_const__OutputArrayR(self.as_raw_mut_Detail_CylindricalWarperGpu(), src.as_raw__InputArray(), k.as_raw__InputArray(), r.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_CylindricalWarperGpu_warp_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_int_const__OutputArrayR(self.as_raw_mut_Detail_CylindricalWarperGpu(), src.as_raw__InputArray(), k.as_raw__InputArray(), r.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4284,10 +4918,11 @@ pub trait Detail_CylindricalWarperGpuTrait: crate::stitching::Detail_Cylindrical
 	
 	#[inline]
 	fn build_maps_1(&mut self, src_size: core::Size, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, xmap: &mut core::GpuMat, ymap: &mut core::GpuMat) -> Result<core::Rect> {
+		crate::core::validity::check("Detail_CylindricalWarperGpu", self.as_raw_mut_Detail_CylindricalWarperGpu())?;
 		input_array_arg!(k);
 		input_array_arg!(r);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_CylindricalWarperGpu_buildMaps_Size_const__InputArrayR_const__InputArrayR_GpuMatR_GpuMatR(self.as_raw_mut_Detail_CylindricalWarperGpu(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), xmap.as_raw_mut_GpuMat(), ymap.as_raw_mut_GpuMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_CylindricalWarperGpu_buildMaps_Size_const__InputArrayR_const__InputArrayR_GpuMatR_GpuMatR(self.as_raw_mut_Detail_CylindricalWarperGpu(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), xmap.as_raw_mut_GpuMat(), ymap.as_raw_mut_GpuMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4295,10 +4930,11 @@ pub trait Detail_CylindricalWarperGpuTrait: crate::stitching::Detail_Cylindrical
 	
 	#[inline]
 	fn warp_1(&mut self, src: &core::GpuMat, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, interp_mode: i32, border_mode: i32, dst: &mut core::GpuMat) -> Result<core::Point> {
+		crate::core::validity::check("Detail_CylindricalWarperGpu", self.as_raw_mut_Detail_CylindricalWarperGpu())?;
 		input_array_arg!(k);
 		input_array_arg!(r);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_CylindricalWarperGpu_warp_const_GpuMatR_const__InputArrayR_const__InputArrayR_int_int_GpuMatR(self.as_raw_mut_Detail_CylindricalWarperGpu(), src.as_raw_GpuMat(), k.as_raw__InputArray(), r.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw_mut_GpuMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_CylindricalWarperGpu_warp_const_GpuMatR_const__InputArrayR_const__InputArrayR_int_int_GpuMatR(self.as_raw_mut_Detail_CylindricalWarperGpu(), src.as_raw_GpuMat(), k.as_raw__InputArray(), r.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw_mut_GpuMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4315,7 +4951,10 @@ opencv_type_boxed! { Detail_CylindricalWarperGpu }
 impl Drop for Detail_CylindricalWarperGpu {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_CylindricalWarperGpu_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_CylindricalWarperGpu_delete(self.as_raw_mut_Detail_CylindricalWarperGpu()) };
+		let ptr = self.as_raw_mut_Detail_CylindricalWarperGpu();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_CylindricalWarperGpu_delete(ptr) };
 	}
 }
 
@@ -4349,7 +4988,7 @@ impl Detail_CylindricalWarperGpu {
 	#[inline]
 	pub fn new(scale: f32) -> Result<crate::stitching::Detail_CylindricalWarperGpu> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_CylindricalWarperGpu_CylindricalWarperGpu_float(scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_CylindricalWarperGpu_CylindricalWarperGpu_float(scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_CylindricalWarperGpu::opencv_from_extern(ret) };
@@ -4365,18 +5004,36 @@ pub trait Detail_DisjointSetsTraitConst {
 
 	#[inline]
 	fn parent(&self) -> core::Vector<i32> {
-		let ret = unsafe { sys::cv_detail_DisjointSets_getPropParent_const(self.as_raw_Detail_DisjointSets()) };
+		crate::core::validity::debug_check("Detail_DisjointSets", self.as_raw_Detail_DisjointSets());
+		let ret = unsafe { ffi_call!(cv_detail_DisjointSets_getPropParent_const(self.as_raw_Detail_DisjointSets())) };
 		let ret = unsafe { core::Vector::<i32>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [parent](Detail_DisjointSetsTraitConst::parent), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_parent(&self) -> Result<core::Vector<i32>> {
+		crate::core::validity::check("Detail_DisjointSets", self.as_raw_Detail_DisjointSets())?;
+		Ok(Detail_DisjointSetsTraitConst::parent(self))
+	}
+	
 	#[inline]
 	fn size(&self) -> core::Vector<i32> {
-		let ret = unsafe { sys::cv_detail_DisjointSets_getPropSize_const(self.as_raw_Detail_DisjointSets()) };
+		crate::core::validity::debug_check("Detail_DisjointSets", self.as_raw_Detail_DisjointSets());
+		let ret = unsafe { ffi_call!(cv_detail_DisjointSets_getPropSize_const(self.as_raw_Detail_DisjointSets())) };
 		let ret = unsafe { core::Vector::<i32>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [size](Detail_DisjointSetsTraitConst::size), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_size(&self) -> Result<core::Vector<i32>> {
+		crate::core::validity::check("Detail_DisjointSets", self.as_raw_Detail_DisjointSets())?;
+		Ok(Detail_DisjointSetsTraitConst::size(self))
+	}
+	
 }
 
 pub trait Detail_DisjointSetsTrait: crate::stitching::Detail_DisjointSetsTraitConst {
@@ -4384,20 +5041,23 @@ pub trait Detail_DisjointSetsTrait: crate::stitching::Detail_DisjointSetsTraitCo
 
 	#[inline]
 	fn set_parent(&mut self, mut val: core::Vector<i32>) {
-		let ret = unsafe { sys::cv_detail_DisjointSets_setPropParent_vector_int_(self.as_raw_mut_Detail_DisjointSets(), val.as_raw_mut_VectorOfi32()) };
+		crate::core::validity::debug_check("Detail_DisjointSets", self.as_raw_mut_Detail_DisjointSets());
+		let ret = unsafe { ffi_call!(cv_detail_DisjointSets_setPropParent_vector_int_(self.as_raw_mut_Detail_DisjointSets(), val.as_raw_mut_VectorOfi32())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_size(&mut self, mut val: core::Vector<i32>) {
-		let ret = unsafe { sys::cv_detail_DisjointSets_setPropSize_vector_int_(self.as_raw_mut_Detail_DisjointSets(), val.as_raw_mut_VectorOfi32()) };
+		crate::core::validity::debug_check("Detail_DisjointSets", self.as_raw_mut_Detail_DisjointSets());
+		let ret = unsafe { ffi_call!(cv_detail_DisjointSets_setPropSize_vector_int_(self.as_raw_mut_Detail_DisjointSets(), val.as_raw_mut_VectorOfi32())) };
 		ret
 	}
 	
 	#[inline]
 	fn create_one_elem_sets(&mut self, elem_count: i32) -> Result<()> {
+		crate::core::validity::check("Detail_DisjointSets", self.as_raw_mut_Detail_DisjointSets())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_DisjointSets_createOneElemSets_int(self.as_raw_mut_Detail_DisjointSets(), elem_count, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_DisjointSets_createOneElemSets_int(self.as_raw_mut_Detail_DisjointSets(), elem_count, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4405,8 +5065,9 @@ pub trait Detail_DisjointSetsTrait: crate::stitching::Detail_DisjointSetsTraitCo
 	
 	#[inline]
 	fn find_set_by_elem(&mut self, elem: i32) -> Result<i32> {
+		crate::core::validity::check("Detail_DisjointSets", self.as_raw_mut_Detail_DisjointSets())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_DisjointSets_findSetByElem_int(self.as_raw_mut_Detail_DisjointSets(), elem, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_DisjointSets_findSetByElem_int(self.as_raw_mut_Detail_DisjointSets(), elem, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4414,8 +5075,9 @@ pub trait Detail_DisjointSetsTrait: crate::stitching::Detail_DisjointSetsTraitCo
 	
 	#[inline]
 	fn merge_sets(&mut self, set1: i32, set2: i32) -> Result<i32> {
+		crate::core::validity::check("Detail_DisjointSets", self.as_raw_mut_Detail_DisjointSets())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_DisjointSets_mergeSets_int_int(self.as_raw_mut_Detail_DisjointSets(), set1, set2, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_DisjointSets_mergeSets_int_int(self.as_raw_mut_Detail_DisjointSets(), set1, set2, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4432,7 +5094,10 @@ opencv_type_boxed! { Detail_DisjointSets }
 impl Drop for Detail_DisjointSets {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_DisjointSets_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_DisjointSets_delete(self.as_raw_mut_Detail_DisjointSets()) };
+		let ptr = self.as_raw_mut_Detail_DisjointSets();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_DisjointSets_delete(ptr) };
 	}
 }
 
@@ -4452,7 +5117,7 @@ impl Detail_DisjointSets {
 	#[inline]
 	pub fn new(elem_count: i32) -> Result<crate::stitching::Detail_DisjointSets> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_DisjointSets_DisjointSets_int(elem_count, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_DisjointSets_DisjointSets_int(elem_count, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_DisjointSets::opencv_from_extern(ret) };
@@ -4466,8 +5131,9 @@ pub trait Detail_DpSeamFinderTraitConst: crate::stitching::Detail_SeamFinderCons
 
 	#[inline]
 	fn cost_function(&self) -> Result<crate::stitching::Detail_DpSeamFinder_CostFunction> {
+		crate::core::validity::check("Detail_DpSeamFinder", self.as_raw_Detail_DpSeamFinder())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_DpSeamFinder_costFunction_const(self.as_raw_Detail_DpSeamFinder(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_DpSeamFinder_costFunction_const(self.as_raw_Detail_DpSeamFinder(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4480,8 +5146,9 @@ pub trait Detail_DpSeamFinderTrait: crate::stitching::Detail_DpSeamFinderTraitCo
 
 	#[inline]
 	fn set_cost_function(&mut self, val: crate::stitching::Detail_DpSeamFinder_CostFunction) -> Result<()> {
+		crate::core::validity::check("Detail_DpSeamFinder", self.as_raw_mut_Detail_DpSeamFinder())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_DpSeamFinder_setCostFunction_CostFunction(self.as_raw_mut_Detail_DpSeamFinder(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_DpSeamFinder_setCostFunction_CostFunction(self.as_raw_mut_Detail_DpSeamFinder(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4489,9 +5156,10 @@ pub trait Detail_DpSeamFinderTrait: crate::stitching::Detail_DpSeamFinderTraitCo
 	
 	#[inline]
 	fn set_cost_function_1(&mut self, val: &str) -> Result<()> {
+		crate::core::validity::check("Detail_DpSeamFinder", self.as_raw_mut_Detail_DpSeamFinder())?;
 		extern_container_arg!(mut val);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_DpSeamFinder_setCostFunction_String(self.as_raw_mut_Detail_DpSeamFinder(), val.opencv_as_extern_mut(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_DpSeamFinder_setCostFunction_String(self.as_raw_mut_Detail_DpSeamFinder(), val.opencv_as_extern_mut(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4499,8 +5167,9 @@ pub trait Detail_DpSeamFinderTrait: crate::stitching::Detail_DpSeamFinderTraitCo
 	
 	#[inline]
 	fn find(&mut self, src: &core::Vector<core::UMat>, corners: &core::Vector<core::Point>, masks: &mut core::Vector<core::UMat>) -> Result<()> {
+		crate::core::validity::check("Detail_DpSeamFinder", self.as_raw_mut_Detail_DpSeamFinder())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_DpSeamFinder_find_const_vector_UMat_R_const_vector_Point_R_vector_UMat_R(self.as_raw_mut_Detail_DpSeamFinder(), src.as_raw_VectorOfUMat(), corners.as_raw_VectorOfPoint(), masks.as_raw_mut_VectorOfUMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_DpSeamFinder_find_const_vector_UMat_R_const_vector_Point_R_vector_UMat_R(self.as_raw_mut_Detail_DpSeamFinder(), src.as_raw_VectorOfUMat(), corners.as_raw_VectorOfPoint(), masks.as_raw_mut_VectorOfUMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4517,7 +5186,10 @@ opencv_type_boxed! { Detail_DpSeamFinder }
 impl Drop for Detail_DpSeamFinder {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_DpSeamFinder_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_DpSeamFinder_delete(self.as_raw_mut_Detail_DpSeamFinder()) };
+		let ptr = self.as_raw_mut_Detail_DpSeamFinder();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_DpSeamFinder_delete(ptr) };
 	}
 }
 
@@ -4545,7 +5217,7 @@ impl Detail_DpSeamFinder {
 	#[inline]
 	pub fn new(cost_func: crate::stitching::Detail_DpSeamFinder_CostFunction) -> Result<crate::stitching::Detail_DpSeamFinder> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_DpSeamFinder_DpSeamFinder_CostFunction(cost_func, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_DpSeamFinder_DpSeamFinder_CostFunction(cost_func, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_DpSeamFinder::opencv_from_extern(ret) };
@@ -4556,7 +5228,7 @@ impl Detail_DpSeamFinder {
 	pub fn new_1(cost_func: &str) -> Result<crate::stitching::Detail_DpSeamFinder> {
 		extern_container_arg!(mut cost_func);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_DpSeamFinder_DpSeamFinder_String(cost_func.opencv_as_extern_mut(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_DpSeamFinder_DpSeamFinder_String(cost_func.opencv_as_extern_mut(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_DpSeamFinder::opencv_from_extern(ret) };
@@ -4599,8 +5271,9 @@ pub trait Detail_ExposureCompensator: crate::stitching::Detail_ExposureCompensat
 	/// to detect where image is)
 	#[inline]
 	fn feed(&mut self, corners: &core::Vector<core::Point>, images: &core::Vector<core::UMat>, masks: &core::Vector<core::UMat>) -> Result<()> {
+		crate::core::validity::check("Detail_ExposureCompensator", self.as_raw_mut_Detail_ExposureCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_ExposureCompensator_feed_const_vector_Point_R_const_vector_UMat_R_const_vector_UMat_R(self.as_raw_mut_Detail_ExposureCompensator(), corners.as_raw_VectorOfPoint(), images.as_raw_VectorOfUMat(), masks.as_raw_VectorOfUMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_ExposureCompensator_feed_const_vector_Point_R_const_vector_UMat_R_const_vector_UMat_R(self.as_raw_mut_Detail_ExposureCompensator(), corners.as_raw_VectorOfPoint(), images.as_raw_VectorOfUMat(), masks.as_raw_VectorOfUMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4615,10 +5288,11 @@ pub trait Detail_ExposureCompensator: crate::stitching::Detail_ExposureCompensat
 	/// * mask: Image mask
 	#[inline]
 	fn apply(&mut self, index: i32, corner: core::Point, image: &mut dyn core::ToInputOutputArray, mask: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("Detail_ExposureCompensator", self.as_raw_mut_Detail_ExposureCompensator())?;
 		input_output_array_arg!(image);
 		input_array_arg!(mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_ExposureCompensator_apply_int_Point_const__InputOutputArrayR_const__InputArrayR(self.as_raw_mut_Detail_ExposureCompensator(), index, corner.opencv_as_extern(), image.as_raw__InputOutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_ExposureCompensator_apply_int_Point_const__InputOutputArrayR_const__InputArrayR(self.as_raw_mut_Detail_ExposureCompensator(), index, corner.opencv_as_extern(), image.as_raw__InputOutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4626,8 +5300,9 @@ pub trait Detail_ExposureCompensator: crate::stitching::Detail_ExposureCompensat
 	
 	#[inline]
 	fn get_mat_gains(&mut self, unnamed: &mut core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("Detail_ExposureCompensator", self.as_raw_mut_Detail_ExposureCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_ExposureCompensator_getMatGains_vector_Mat_R(self.as_raw_mut_Detail_ExposureCompensator(), unnamed.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_ExposureCompensator_getMatGains_vector_Mat_R(self.as_raw_mut_Detail_ExposureCompensator(), unnamed.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4635,8 +5310,9 @@ pub trait Detail_ExposureCompensator: crate::stitching::Detail_ExposureCompensat
 	
 	#[inline]
 	fn set_mat_gains(&mut self, unnamed: &mut core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("Detail_ExposureCompensator", self.as_raw_mut_Detail_ExposureCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_ExposureCompensator_setMatGains_vector_Mat_R(self.as_raw_mut_Detail_ExposureCompensator(), unnamed.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_ExposureCompensator_setMatGains_vector_Mat_R(self.as_raw_mut_Detail_ExposureCompensator(), unnamed.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4644,8 +5320,9 @@ pub trait Detail_ExposureCompensator: crate::stitching::Detail_ExposureCompensat
 	
 	#[inline]
 	fn set_update_gain(&mut self, b: bool) -> Result<()> {
+		crate::core::validity::check("Detail_ExposureCompensator", self.as_raw_mut_Detail_ExposureCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_ExposureCompensator_setUpdateGain_bool(self.as_raw_mut_Detail_ExposureCompensator(), b, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_ExposureCompensator_setUpdateGain_bool(self.as_raw_mut_Detail_ExposureCompensator(), b, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4653,8 +5330,9 @@ pub trait Detail_ExposureCompensator: crate::stitching::Detail_ExposureCompensat
 	
 	#[inline]
 	fn get_update_gain(&mut self) -> Result<bool> {
+		crate::core::validity::check("Detail_ExposureCompensator", self.as_raw_mut_Detail_ExposureCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_ExposureCompensator_getUpdateGain(self.as_raw_mut_Detail_ExposureCompensator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_ExposureCompensator_getUpdateGain(self.as_raw_mut_Detail_ExposureCompensator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4666,7 +5344,7 @@ impl dyn Detail_ExposureCompensator + '_ {
 	#[inline]
 	pub fn create_default(typ: i32) -> Result<core::Ptr<dyn crate::stitching::Detail_ExposureCompensator>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_ExposureCompensator_createDefault_int(typ, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_ExposureCompensator_createDefault_int(typ, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::stitching::Detail_ExposureCompensator>::opencv_from_extern(ret) };
@@ -4680,8 +5358,9 @@ pub trait Detail_FeatherBlenderTraitConst: crate::stitching::Detail_BlenderTrait
 
 	#[inline]
 	fn sharpness(&self) -> Result<f32> {
+		crate::core::validity::check("Detail_FeatherBlender", self.as_raw_Detail_FeatherBlender())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_FeatherBlender_sharpness_const(self.as_raw_Detail_FeatherBlender(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_FeatherBlender_sharpness_const(self.as_raw_Detail_FeatherBlender(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4694,8 +5373,9 @@ pub trait Detail_FeatherBlenderTrait: crate::stitching::Detail_BlenderTrait + cr
 
 	#[inline]
 	fn set_sharpness(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("Detail_FeatherBlender", self.as_raw_mut_Detail_FeatherBlender())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_FeatherBlender_setSharpness_float(self.as_raw_mut_Detail_FeatherBlender(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_FeatherBlender_setSharpness_float(self.as_raw_mut_Detail_FeatherBlender(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4703,8 +5383,9 @@ pub trait Detail_FeatherBlenderTrait: crate::stitching::Detail_BlenderTrait + cr
 	
 	#[inline]
 	fn prepare(&mut self, dst_roi: core::Rect) -> Result<()> {
+		crate::core::validity::check("Detail_FeatherBlender", self.as_raw_mut_Detail_FeatherBlender())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_FeatherBlender_prepare_Rect(self.as_raw_mut_Detail_FeatherBlender(), dst_roi.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_FeatherBlender_prepare_Rect(self.as_raw_mut_Detail_FeatherBlender(), dst_roi.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4712,10 +5393,11 @@ pub trait Detail_FeatherBlenderTrait: crate::stitching::Detail_BlenderTrait + cr
 	
 	#[inline]
 	fn feed(&mut self, img: &dyn core::ToInputArray, mask: &dyn core::ToInputArray, tl: core::Point) -> Result<()> {
+		crate::core::validity::check("Detail_FeatherBlender", self.as_raw_mut_Detail_FeatherBlender())?;
 		input_array_arg!(img);
 		input_array_arg!(mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_FeatherBlender_feed_const__InputArrayR_const__InputArrayR_Point(self.as_raw_mut_Detail_FeatherBlender(), img.as_raw__InputArray(), mask.as_raw__InputArray(), tl.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_FeatherBlender_feed_const__InputArrayR_const__InputArrayR_Point(self.as_raw_mut_Detail_FeatherBlender(), img.as_raw__InputArray(), mask.as_raw__InputArray(), tl.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4723,10 +5405,11 @@ pub trait Detail_FeatherBlenderTrait: crate::stitching::Detail_BlenderTrait + cr
 	
 	#[inline]
 	fn blend(&mut self, dst: &mut dyn core::ToInputOutputArray, dst_mask: &mut dyn core::ToInputOutputArray) -> Result<()> {
+		crate::core::validity::check("Detail_FeatherBlender", self.as_raw_mut_Detail_FeatherBlender())?;
 		input_output_array_arg!(dst);
 		input_output_array_arg!(dst_mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_FeatherBlender_blend_const__InputOutputArrayR_const__InputOutputArrayR(self.as_raw_mut_Detail_FeatherBlender(), dst.as_raw__InputOutputArray(), dst_mask.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_FeatherBlender_blend_const__InputOutputArrayR_const__InputOutputArrayR(self.as_raw_mut_Detail_FeatherBlender(), dst.as_raw__InputOutputArray(), dst_mask.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4736,8 +5419,9 @@ pub trait Detail_FeatherBlenderTrait: crate::stitching::Detail_BlenderTrait + cr
 	/// Final image can be obtained by simple weighting of the source images.
 	#[inline]
 	fn create_weight_maps(&mut self, masks: &core::Vector<core::UMat>, corners: &core::Vector<core::Point>, weight_maps: &mut core::Vector<core::UMat>) -> Result<core::Rect> {
+		crate::core::validity::check("Detail_FeatherBlender", self.as_raw_mut_Detail_FeatherBlender())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_FeatherBlender_createWeightMaps_const_vector_UMat_R_const_vector_Point_R_vector_UMat_R(self.as_raw_mut_Detail_FeatherBlender(), masks.as_raw_VectorOfUMat(), corners.as_raw_VectorOfPoint(), weight_maps.as_raw_mut_VectorOfUMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_FeatherBlender_createWeightMaps_const_vector_UMat_R_const_vector_Point_R_vector_UMat_R(self.as_raw_mut_Detail_FeatherBlender(), masks.as_raw_VectorOfUMat(), corners.as_raw_VectorOfPoint(), weight_maps.as_raw_mut_VectorOfUMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4755,7 +5439,10 @@ opencv_type_boxed! { Detail_FeatherBlender }
 impl Drop for Detail_FeatherBlender {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_FeatherBlender_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_FeatherBlender_delete(self.as_raw_mut_Detail_FeatherBlender()) };
+		let ptr = self.as_raw_mut_Detail_FeatherBlender();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_FeatherBlender_delete(ptr) };
 	}
 }
 
@@ -4783,7 +5470,7 @@ impl Detail_FeatherBlender {
 	#[inline]
 	pub fn new(sharpness: f32) -> Result<crate::stitching::Detail_FeatherBlender> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_FeatherBlender_FeatherBlender_float(sharpness, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_FeatherBlender_FeatherBlender_float(sharpness, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_FeatherBlender::opencv_from_extern(ret) };
@@ -4802,8 +5489,9 @@ pub trait Detail_FeaturesMatcherConst {
 	/// True, if it's possible to use the same matcher instance in parallel, false otherwise
 	#[inline]
 	fn is_thread_safe(&self) -> Result<bool> {
+		crate::core::validity::check("Detail_FeaturesMatcher", self.as_raw_Detail_FeaturesMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_FeaturesMatcher_isThreadSafe_const(self.as_raw_Detail_FeaturesMatcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_FeaturesMatcher_isThreadSafe_const(self.as_raw_Detail_FeaturesMatcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4817,8 +5505,9 @@ pub trait Detail_FeaturesMatcher: crate::stitching::Detail_FeaturesMatcherConst
 	/// Frees unused memory allocated before if there is any.
 	#[inline]
 	fn collect_garbage(&mut self) -> Result<()> {
+		crate::core::validity::check("Detail_FeaturesMatcher", self.as_raw_mut_Detail_FeaturesMatcher())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_FeaturesMatcher_collectGarbage(self.as_raw_mut_Detail_FeaturesMatcher(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_FeaturesMatcher_collectGarbage(self.as_raw_mut_Detail_FeaturesMatcher(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4836,8 +5525,9 @@ pub trait Detail_FisheyeProjectorTrait: crate::stitching::Detail_FisheyeProjecto
 
 	#[inline]
 	fn map_forward(&mut self, x: f32, y: f32, u: &mut f32, v: &mut f32) -> Result<()> {
+		crate::core::validity::check("Detail_FisheyeProjector", self.as_raw_mut_Detail_FisheyeProjector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_FisheyeProjector_mapForward_float_float_floatR_floatR(self.as_raw_mut_Detail_FisheyeProjector(), x, y, u, v, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_FisheyeProjector_mapForward_float_float_floatR_floatR(self.as_raw_mut_Detail_FisheyeProjector(), x, y, u, v, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4845,8 +5535,9 @@ pub trait Detail_FisheyeProjectorTrait: crate::stitching::Detail_FisheyeProjecto
 	
 	#[inline]
 	fn map_backward(&mut self, u: f32, v: f32, x: &mut f32, y: &mut f32) -> Result<()> {
+		crate::core::validity::check("Detail_FisheyeProjector", self.as_raw_mut_Detail_FisheyeProjector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_FisheyeProjector_mapBackward_float_float_floatR_floatR(self.as_raw_mut_Detail_FisheyeProjector(), u, v, x, y, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_FisheyeProjector_mapBackward_float_float_floatR_floatR(self.as_raw_mut_Detail_FisheyeProjector(), u, v, x, y, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4863,7 +5554,10 @@ opencv_type_boxed! { Detail_FisheyeProjector }
 impl Drop for Detail_FisheyeProjector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_FisheyeProjector_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_FisheyeProjector_delete(self.as_raw_mut_Detail_FisheyeProjector()) };
+		let ptr = self.as_raw_mut_Detail_FisheyeProjector();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_FisheyeProjector_delete(ptr) };
 	}
 }
 
@@ -4909,7 +5603,10 @@ opencv_type_boxed! { Detail_FisheyeWarper }
 impl Drop for Detail_FisheyeWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_FisheyeWarper_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_FisheyeWarper_delete(self.as_raw_mut_Detail_FisheyeWarper()) };
+		let ptr = self.as_raw_mut_Detail_FisheyeWarper();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_FisheyeWarper_delete(ptr) };
 	}
 }
 
@@ -4935,7 +5632,7 @@ impl Detail_FisheyeWarper {
 	#[inline]
 	pub fn new(scale: f32) -> Result<crate::stitching::Detail_FisheyeWarper> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_FisheyeWarper_FisheyeWarper_float(scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_FisheyeWarper_FisheyeWarper_float(scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_FisheyeWarper::opencv_from_extern(ret) };
@@ -4951,8 +5648,9 @@ pub trait Detail_GainCompensatorTraitConst: crate::stitching::Detail_ExposureCom
 
 	#[inline]
 	fn get_similarity_threshold(&self) -> Result<f64> {
+		crate::core::validity::check("Detail_GainCompensator", self.as_raw_Detail_GainCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_GainCompensator_getSimilarityThreshold_const(self.as_raw_Detail_GainCompensator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_GainCompensator_getSimilarityThreshold_const(self.as_raw_Detail_GainCompensator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4960,8 +5658,9 @@ pub trait Detail_GainCompensatorTraitConst: crate::stitching::Detail_ExposureCom
 	
 	#[inline]
 	fn gains(&self) -> Result<core::Vector<f64>> {
+		crate::core::validity::check("Detail_GainCompensator", self.as_raw_Detail_GainCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_GainCompensator_gains_const(self.as_raw_Detail_GainCompensator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_GainCompensator_gains_const(self.as_raw_Detail_GainCompensator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<f64>::opencv_from_extern(ret) };
@@ -4975,10 +5674,11 @@ pub trait Detail_GainCompensatorTrait: crate::stitching::Detail_ExposureCompensa
 
 	#[inline]
 	fn apply(&mut self, index: i32, corner: core::Point, image: &mut dyn core::ToInputOutputArray, mask: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("Detail_GainCompensator", self.as_raw_mut_Detail_GainCompensator())?;
 		input_output_array_arg!(image);
 		input_array_arg!(mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_GainCompensator_apply_int_Point_const__InputOutputArrayR_const__InputArrayR(self.as_raw_mut_Detail_GainCompensator(), index, corner.opencv_as_extern(), image.as_raw__InputOutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_GainCompensator_apply_int_Point_const__InputOutputArrayR_const__InputArrayR(self.as_raw_mut_Detail_GainCompensator(), index, corner.opencv_as_extern(), image.as_raw__InputOutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4986,8 +5686,9 @@ pub trait Detail_GainCompensatorTrait: crate::stitching::Detail_ExposureCompensa
 	
 	#[inline]
 	fn get_mat_gains(&mut self, umv: &mut core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("Detail_GainCompensator", self.as_raw_mut_Detail_GainCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_GainCompensator_getMatGains_vector_Mat_R(self.as_raw_mut_Detail_GainCompensator(), umv.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_GainCompensator_getMatGains_vector_Mat_R(self.as_raw_mut_Detail_GainCompensator(), umv.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4995,8 +5696,9 @@ pub trait Detail_GainCompensatorTrait: crate::stitching::Detail_ExposureCompensa
 	
 	#[inline]
 	fn set_mat_gains(&mut self, umv: &mut core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("Detail_GainCompensator", self.as_raw_mut_Detail_GainCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_GainCompensator_setMatGains_vector_Mat_R(self.as_raw_mut_Detail_GainCompensator(), umv.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_GainCompensator_setMatGains_vector_Mat_R(self.as_raw_mut_Detail_GainCompensator(), umv.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5004,8 +5706,9 @@ pub trait Detail_GainCompensatorTrait: crate::stitching::Detail_ExposureCompensa
 	
 	#[inline]
 	fn set_nr_feeds(&mut self, nr_feeds: i32) -> Result<()> {
+		crate::core::validity::check("Detail_GainCompensator", self.as_raw_mut_Detail_GainCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_GainCompensator_setNrFeeds_int(self.as_raw_mut_Detail_GainCompensator(), nr_feeds, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_GainCompensator_setNrFeeds_int(self.as_raw_mut_Detail_GainCompensator(), nr_feeds, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5013,8 +5716,9 @@ pub trait Detail_GainCompensatorTrait: crate::stitching::Detail_ExposureCompensa
 	
 	#[inline]
 	fn get_nr_feeds(&mut self) -> Result<i32> {
+		crate::core::validity::check("Detail_GainCompensator", self.as_raw_mut_Detail_GainCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_GainCompensator_getNrFeeds(self.as_raw_mut_Detail_GainCompensator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_GainCompensator_getNrFeeds(self.as_raw_mut_Detail_GainCompensator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5022,8 +5726,9 @@ pub trait Detail_GainCompensatorTrait: crate::stitching::Detail_ExposureCompensa
 	
 	#[inline]
 	fn set_similarity_threshold(&mut self, similarity_threshold: f64) -> Result<()> {
+		crate::core::validity::check("Detail_GainCompensator", self.as_raw_mut_Detail_GainCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_GainCompensator_setSimilarityThreshold_double(self.as_raw_mut_Detail_GainCompensator(), similarity_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_GainCompensator_setSimilarityThreshold_double(self.as_raw_mut_Detail_GainCompensator(), similarity_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5031,8 +5736,9 @@ pub trait Detail_GainCompensatorTrait: crate::stitching::Detail_ExposureCompensa
 	
 	#[inline]
 	fn prepare_similarity_mask(&mut self, corners: &core::Vector<core::Point>, images: &core::Vector<core::UMat>) -> Result<()> {
+		crate::core::validity::check("Detail_GainCompensator", self.as_raw_mut_Detail_GainCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_GainCompensator_prepareSimilarityMask_const_vector_Point_R_const_vector_UMat_R(self.as_raw_mut_Detail_GainCompensator(), corners.as_raw_VectorOfPoint(), images.as_raw_VectorOfUMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_GainCompensator_prepareSimilarityMask_const_vector_Point_R_const_vector_UMat_R(self.as_raw_mut_Detail_GainCompensator(), corners.as_raw_VectorOfPoint(), images.as_raw_VectorOfUMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5051,7 +5757,10 @@ opencv_type_boxed! { Detail_GainCompensator }
 impl Drop for Detail_GainCompensator {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_GainCompensator_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_GainCompensator_delete(self.as_raw_mut_Detail_GainCompensator()) };
+		let ptr = self.as_raw_mut_Detail_GainCompensator();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_GainCompensator_delete(ptr) };
 	}
 }
 
@@ -5077,7 +5786,7 @@ impl Detail_GainCompensator {
 	#[inline]
 	pub fn default() -> Result<crate::stitching::Detail_GainCompensator> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_GainCompensator_GainCompensator(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_GainCompensator_GainCompensator(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_GainCompensator::opencv_from_extern(ret) };
@@ -5087,7 +5796,7 @@ impl Detail_GainCompensator {
 	#[inline]
 	pub fn new(nr_feeds: i32) -> Result<crate::stitching::Detail_GainCompensator> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_GainCompensator_GainCompensator_int(nr_feeds, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_GainCompensator_GainCompensator_int(nr_feeds, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_GainCompensator::opencv_from_extern(ret) };
@@ -5101,8 +5810,9 @@ pub trait Detail_GraphTraitConst {
 
 	#[inline]
 	fn num_vertices(&self) -> Result<i32> {
+		crate::core::validity::check("Detail_Graph", self.as_raw_Detail_Graph())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_Graph_numVertices_const(self.as_raw_Detail_Graph(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_Graph_numVertices_const(self.as_raw_Detail_Graph(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5115,8 +5825,9 @@ pub trait Detail_GraphTrait: crate::stitching::Detail_GraphTraitConst {
 
 	#[inline]
 	fn create(&mut self, num_vertices: i32) -> Result<()> {
+		crate::core::validity::check("Detail_Graph", self.as_raw_mut_Detail_Graph())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_Graph_create_int(self.as_raw_mut_Detail_Graph(), num_vertices, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_Graph_create_int(self.as_raw_mut_Detail_Graph(), num_vertices, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5124,8 +5835,9 @@ pub trait Detail_GraphTrait: crate::stitching::Detail_GraphTraitConst {
 	
 	#[inline]
 	fn add_edge(&mut self, from: i32, to: i32, weight: f32) -> Result<()> {
+		crate::core::validity::check("Detail_Graph", self.as_raw_mut_Detail_Graph())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_Graph_addEdge_int_int_float(self.as_raw_mut_Detail_Graph(), from, to, weight, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_Graph_addEdge_int_int_float(self.as_raw_mut_Detail_Graph(), from, to, weight, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5142,7 +5854,10 @@ opencv_type_boxed! { Detail_Graph }
 impl Drop for Detail_Graph {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_Graph_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_Graph_delete(self.as_raw_mut_Detail_Graph()) };
+		let ptr = self.as_raw_mut_Detail_Graph();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_Graph_delete(ptr) };
 	}
 }
 
@@ -5162,7 +5877,7 @@ impl Detail_Graph {
 	#[inline]
 	pub fn new(num_vertices: i32) -> Result<crate::stitching::Detail_Graph> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_Graph_Graph_int(num_vertices, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_Graph_Graph_int(num_vertices, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_Graph::opencv_from_extern(ret) };
@@ -5182,8 +5897,9 @@ pub trait Detail_GraphCutSeamFinderTrait: crate::stitching::Detail_GraphCutSeamF
 
 	#[inline]
 	fn find(&mut self, src: &core::Vector<core::UMat>, corners: &core::Vector<core::Point>, masks: &mut core::Vector<core::UMat>) -> Result<()> {
+		crate::core::validity::check("Detail_GraphCutSeamFinder", self.as_raw_mut_Detail_GraphCutSeamFinder())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_GraphCutSeamFinder_find_const_vector_UMat_R_const_vector_Point_R_vector_UMat_R(self.as_raw_mut_Detail_GraphCutSeamFinder(), src.as_raw_VectorOfUMat(), corners.as_raw_VectorOfPoint(), masks.as_raw_mut_VectorOfUMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_GraphCutSeamFinder_find_const_vector_UMat_R_const_vector_Point_R_vector_UMat_R(self.as_raw_mut_Detail_GraphCutSeamFinder(), src.as_raw_VectorOfUMat(), corners.as_raw_VectorOfPoint(), masks.as_raw_mut_VectorOfUMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5201,7 +5917,10 @@ opencv_type_boxed! { Detail_GraphCutSeamFinder }
 impl Drop for Detail_GraphCutSeamFinder {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_GraphCutSeamFinder_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_GraphCutSeamFinder_delete(self.as_raw_mut_Detail_GraphCutSeamFinder()) };
+		let ptr = self.as_raw_mut_Detail_GraphCutSeamFinder();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_GraphCutSeamFinder_delete(ptr) };
 	}
 }
 
@@ -5239,7 +5958,7 @@ impl Detail_GraphCutSeamFinder {
 	#[inline]
 	pub fn new(cost_type: i32, terminal_cost: f32, bad_region_penalty: f32) -> Result<crate::stitching::Detail_GraphCutSeamFinder> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_GraphCutSeamFinder_GraphCutSeamFinder_int_float_float(cost_type, terminal_cost, bad_region_penalty, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_GraphCutSeamFinder_GraphCutSeamFinder_int_float_float(cost_type, terminal_cost, bad_region_penalty, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_GraphCutSeamFinder::opencv_from_extern(ret) };
@@ -5253,7 +5972,7 @@ impl Detail_GraphCutSeamFinder {
 	pub fn new_1(cost_type: &str, terminal_cost: f32, bad_region_penalty: f32) -> Result<crate::stitching::Detail_GraphCutSeamFinder> {
 		extern_container_arg!(mut cost_type);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_GraphCutSeamFinder_GraphCutSeamFinder_String_float_float(cost_type.opencv_as_extern_mut(), terminal_cost, bad_region_penalty, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_GraphCutSeamFinder_GraphCutSeamFinder_String_float_float(cost_type.opencv_as_extern_mut(), terminal_cost, bad_region_penalty, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_GraphCutSeamFinder::opencv_from_extern(ret) };
@@ -5262,6 +5981,42 @@ impl Detail_GraphCutSeamFinder {
 	
 }
 
+/// Builder for `Detail_GraphCutSeamFinder::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct Detail_GraphCutSeamFinder_NewBuilder {
+	cost_type: i32,
+	terminal_cost: f32,
+	bad_region_penalty: f32,
+}
+
+impl Detail_GraphCutSeamFinder_NewBuilder {
+	#[inline]
+	pub fn new(cost_type: i32) -> Self {
+		Self { cost_type, terminal_cost: 10000., bad_region_penalty: 1000. }
+	}
+	
+	/// C++ default: `10000.f`
+	#[inline]
+	pub fn with_terminal_cost(mut self, terminal_cost: f32) -> Self {
+		self.terminal_cost = terminal_cost;
+		self
+	}
+	
+	/// C++ default: `1000.f`
+	#[inline]
+	pub fn with_bad_region_penalty(mut self, bad_region_penalty: f32) -> Self {
+		self.bad_region_penalty = bad_region_penalty;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::stitching::Detail_GraphCutSeamFinder> {
+		Detail_GraphCutSeamFinder::new(self.cost_type, self.terminal_cost, self.bad_region_penalty)
+	}
+}
+
 boxed_cast_base! { Detail_GraphCutSeamFinder, crate::stitching::Detail_GraphCutSeamFinderBase, cv_Detail_GraphCutSeamFinder_to_Detail_GraphCutSeamFinderBase }
 
 /// Base class for all minimum graph-cut-based seam estimators.
@@ -5285,7 +6040,10 @@ opencv_type_boxed! { Detail_GraphCutSeamFinderBase }
 impl Drop for Detail_GraphCutSeamFinderBase {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_GraphCutSeamFinderBase_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_GraphCutSeamFinderBase_delete(self.as_raw_mut_Detail_GraphCutSeamFinderBase()) };
+		let ptr = self.as_raw_mut_Detail_GraphCutSeamFinderBase();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_GraphCutSeamFinderBase_delete(ptr) };
 	}
 }
 
@@ -5312,8 +6070,9 @@ pub trait Detail_GraphCutSeamFinderGpuTrait: crate::stitching::Detail_GraphCutSe
 
 	#[inline]
 	fn find(&mut self, src: &core::Vector<core::UMat>, corners: &core::Vector<core::Point>, masks: &mut core::Vector<core::UMat>) -> Result<()> {
+		crate::core::validity::check("Detail_GraphCutSeamFinderGpu", self.as_raw_mut_Detail_GraphCutSeamFinderGpu())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_GraphCutSeamFinderGpu_find_const_vector_UMat_R_const_vector_Point_R_vector_UMat_R(self.as_raw_mut_Detail_GraphCutSeamFinderGpu(), src.as_raw_VectorOfUMat(), corners.as_raw_VectorOfPoint(), masks.as_raw_mut_VectorOfUMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_GraphCutSeamFinderGpu_find_const_vector_UMat_R_const_vector_Point_R_vector_UMat_R(self.as_raw_mut_Detail_GraphCutSeamFinderGpu(), src.as_raw_VectorOfUMat(), corners.as_raw_VectorOfPoint(), masks.as_raw_mut_VectorOfUMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5321,8 +6080,9 @@ pub trait Detail_GraphCutSeamFinderGpuTrait: crate::stitching::Detail_GraphCutSe
 	
 	#[inline]
 	fn find_in_pair(&mut self, first: size_t, second: size_t, roi: core::Rect) -> Result<()> {
+		crate::core::validity::check("Detail_GraphCutSeamFinderGpu", self.as_raw_mut_Detail_GraphCutSeamFinderGpu())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_GraphCutSeamFinderGpu_findInPair_size_t_size_t_Rect(self.as_raw_mut_Detail_GraphCutSeamFinderGpu(), first, second, roi.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_GraphCutSeamFinderGpu_findInPair_size_t_size_t_Rect(self.as_raw_mut_Detail_GraphCutSeamFinderGpu(), first, second, roi.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5339,7 +6099,10 @@ opencv_type_boxed! { Detail_GraphCutSeamFinderGpu }
 impl Drop for Detail_GraphCutSeamFinderGpu {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_GraphCutSeamFinderGpu_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_GraphCutSeamFinderGpu_delete(self.as_raw_mut_Detail_GraphCutSeamFinderGpu()) };
+		let ptr = self.as_raw_mut_Detail_GraphCutSeamFinderGpu();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_GraphCutSeamFinderGpu_delete(ptr) };
 	}
 }
 
@@ -5385,7 +6148,7 @@ impl Detail_GraphCutSeamFinderGpu {
 	#[inline]
 	pub fn new(cost_type: i32, terminal_cost: f32, bad_region_penalty: f32) -> Result<crate::stitching::Detail_GraphCutSeamFinderGpu> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_GraphCutSeamFinderGpu_GraphCutSeamFinderGpu_int_float_float(cost_type, terminal_cost, bad_region_penalty, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_GraphCutSeamFinderGpu_GraphCutSeamFinderGpu_int_float_float(cost_type, terminal_cost, bad_region_penalty, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_GraphCutSeamFinderGpu::opencv_from_extern(ret) };
@@ -5394,6 +6157,42 @@ impl Detail_GraphCutSeamFinderGpu {
 	
 }
 
+/// Builder for `Detail_GraphCutSeamFinderGpu::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct Detail_GraphCutSeamFinderGpu_NewBuilder {
+	cost_type: i32,
+	terminal_cost: f32,
+	bad_region_penalty: f32,
+}
+
+impl Detail_GraphCutSeamFinderGpu_NewBuilder {
+	#[inline]
+	pub fn new(cost_type: i32) -> Self {
+		Self { cost_type, terminal_cost: 10000., bad_region_penalty: 1000. }
+	}
+	
+	/// C++ default: `10000.f`
+	#[inline]
+	pub fn with_terminal_cost(mut self, terminal_cost: f32) -> Self {
+		self.terminal_cost = terminal_cost;
+		self
+	}
+	
+	/// C++ default: `1000.f`
+	#[inline]
+	pub fn with_bad_region_penalty(mut self, bad_region_penalty: f32) -> Self {
+		self.bad_region_penalty = bad_region_penalty;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::stitching::Detail_GraphCutSeamFinderGpu> {
+		Detail_GraphCutSeamFinderGpu::new(self.cost_type, self.terminal_cost, self.bad_region_penalty)
+	}
+}
+
 boxed_cast_base! { Detail_GraphCutSeamFinderGpu, crate::stitching::Detail_GraphCutSeamFinderBase, cv_Detail_GraphCutSeamFinderGpu_to_Detail_GraphCutSeamFinderBase }
 
 pub trait Detail_GraphEdgeTraitConst {
@@ -5401,22 +6200,49 @@ pub trait Detail_GraphEdgeTraitConst {
 
 	#[inline]
 	fn from(&self) -> i32 {
-		let ret = unsafe { sys::cv_detail_GraphEdge_getPropFrom_const(self.as_raw_Detail_GraphEdge()) };
+		crate::core::validity::debug_check("Detail_GraphEdge", self.as_raw_Detail_GraphEdge());
+		let ret = unsafe { ffi_call!(cv_detail_GraphEdge_getPropFrom_const(self.as_raw_Detail_GraphEdge())) };
 		ret
 	}
 	
+	/// Non-panicking version of [from](Detail_GraphEdgeTraitConst::from), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_from(&self) -> Result<i32> {
+		crate::core::validity::check("Detail_GraphEdge", self.as_raw_Detail_GraphEdge())?;
+		Ok(Detail_GraphEdgeTraitConst::from(self))
+	}
+	
 	#[inline]
 	fn to(&self) -> i32 {
-		let ret = unsafe { sys::cv_detail_GraphEdge_getPropTo_const(self.as_raw_Detail_GraphEdge()) };
+		crate::core::validity::debug_check("Detail_GraphEdge", self.as_raw_Detail_GraphEdge());
+		let ret = unsafe { ffi_call!(cv_detail_GraphEdge_getPropTo_const(self.as_raw_Detail_GraphEdge())) };
 		ret
 	}
 	
+	/// Non-panicking version of [to](Detail_GraphEdgeTraitConst::to), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_to(&self) -> Result<i32> {
+		crate::core::validity::check("Detail_GraphEdge", self.as_raw_Detail_GraphEdge())?;
+		Ok(Detail_GraphEdgeTraitConst::to(self))
+	}
+	
 	#[inline]
 	fn weight(&self) -> f32 {
-		let ret = unsafe { sys::cv_detail_GraphEdge_getPropWeight_const(self.as_raw_Detail_GraphEdge()) };
+		crate::core::validity::debug_check("Detail_GraphEdge", self.as_raw_Detail_GraphEdge());
+		let ret = unsafe { ffi_call!(cv_detail_GraphEdge_getPropWeight_const(self.as_raw_Detail_GraphEdge())) };
 		ret
 	}
 	
+	/// Non-panicking version of [weight](Detail_GraphEdgeTraitConst::weight), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_weight(&self) -> Result<f32> {
+		crate::core::validity::check("Detail_GraphEdge", self.as_raw_Detail_GraphEdge())?;
+		Ok(Detail_GraphEdgeTraitConst::weight(self))
+	}
+	
 }
 
 pub trait Detail_GraphEdgeTrait: crate::stitching::Detail_GraphEdgeTraitConst {
@@ -5424,19 +6250,22 @@ pub trait Detail_GraphEdgeTrait: crate::stitching::Detail_GraphEdgeTraitConst {
 
 	#[inline]
 	fn set_from(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_detail_GraphEdge_setPropFrom_int(self.as_raw_mut_Detail_GraphEdge(), val) };
+		crate::core::validity::debug_check("Detail_GraphEdge", self.as_raw_mut_Detail_GraphEdge());
+		let ret = unsafe { ffi_call!(cv_detail_GraphEdge_setPropFrom_int(self.as_raw_mut_Detail_GraphEdge(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_to(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_detail_GraphEdge_setPropTo_int(self.as_raw_mut_Detail_GraphEdge(), val) };
+		crate::core::validity::debug_check("Detail_GraphEdge", self.as_raw_mut_Detail_GraphEdge());
+		let ret = unsafe { ffi_call!(cv_detail_GraphEdge_setPropTo_int(self.as_raw_mut_Detail_GraphEdge(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_weight(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_detail_GraphEdge_setPropWeight_float(self.as_raw_mut_Detail_GraphEdge(), val) };
+		crate::core::validity::debug_check("Detail_GraphEdge", self.as_raw_mut_Detail_GraphEdge());
+		let ret = unsafe { ffi_call!(cv_detail_GraphEdge_setPropWeight_float(self.as_raw_mut_Detail_GraphEdge(), val)) };
 		ret
 	}
 	
@@ -5451,7 +6280,10 @@ opencv_type_boxed! { Detail_GraphEdge }
 impl Drop for Detail_GraphEdge {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_GraphEdge_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_GraphEdge_delete(self.as_raw_mut_Detail_GraphEdge()) };
+		let ptr = self.as_raw_mut_Detail_GraphEdge();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_GraphEdge_delete(ptr) };
 	}
 }
 
@@ -5469,7 +6301,7 @@ impl Detail_GraphEdge {
 	#[inline]
 	pub fn new(from: i32, to: i32, weight: f32) -> Result<crate::stitching::Detail_GraphEdge> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_GraphEdge_GraphEdge_int_int_float(from, to, weight, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_GraphEdge_GraphEdge_int_int_float(from, to, weight, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_GraphEdge::opencv_from_extern(ret) };
@@ -5499,7 +6331,10 @@ opencv_type_boxed! { Detail_HomographyBasedEstimator }
 impl Drop for Detail_HomographyBasedEstimator {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_HomographyBasedEstimator_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_HomographyBasedEstimator_delete(self.as_raw_mut_Detail_HomographyBasedEstimator()) };
+		let ptr = self.as_raw_mut_Detail_HomographyBasedEstimator();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_HomographyBasedEstimator_delete(ptr) };
 	}
 }
 
@@ -5527,7 +6362,7 @@ impl Detail_HomographyBasedEstimator {
 	#[inline]
 	pub fn new(is_focals_estimated: bool) -> Result<crate::stitching::Detail_HomographyBasedEstimator> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_HomographyBasedEstimator_HomographyBasedEstimator_bool(is_focals_estimated, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_HomographyBasedEstimator_HomographyBasedEstimator_bool(is_focals_estimated, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_HomographyBasedEstimator::opencv_from_extern(ret) };
@@ -5542,32 +6377,68 @@ pub trait Detail_ImageFeaturesTraitConst {
 
 	#[inline]
 	fn img_idx(&self) -> i32 {
-		let ret = unsafe { sys::cv_detail_ImageFeatures_getPropImg_idx_const(self.as_raw_Detail_ImageFeatures()) };
+		crate::core::validity::debug_check("Detail_ImageFeatures", self.as_raw_Detail_ImageFeatures());
+		let ret = unsafe { ffi_call!(cv_detail_ImageFeatures_getPropImg_idx_const(self.as_raw_Detail_ImageFeatures())) };
 		ret
 	}
 	
+	/// Non-panicking version of [img_idx](Detail_ImageFeaturesTraitConst::img_idx), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_img_idx(&self) -> Result<i32> {
+		crate::core::validity::check("Detail_ImageFeatures", self.as_raw_Detail_ImageFeatures())?;
+		Ok(Detail_ImageFeaturesTraitConst::img_idx(self))
+	}
+	
 	#[inline]
 	fn img_size(&self) -> core::Size {
+		crate::core::validity::debug_check("Detail_ImageFeatures", self.as_raw_Detail_ImageFeatures());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_ImageFeatures_getPropImg_size_const(self.as_raw_Detail_ImageFeatures(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_ImageFeatures_getPropImg_size_const(self.as_raw_Detail_ImageFeatures(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [img_size](Detail_ImageFeaturesTraitConst::img_size), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_img_size(&self) -> Result<core::Size> {
+		crate::core::validity::check("Detail_ImageFeatures", self.as_raw_Detail_ImageFeatures())?;
+		Ok(Detail_ImageFeaturesTraitConst::img_size(self))
+	}
+	
 	#[inline]
 	fn keypoints(&self) -> core::Vector<core::KeyPoint> {
-		let ret = unsafe { sys::cv_detail_ImageFeatures_getPropKeypoints_const(self.as_raw_Detail_ImageFeatures()) };
+		crate::core::validity::debug_check("Detail_ImageFeatures", self.as_raw_Detail_ImageFeatures());
+		let ret = unsafe { ffi_call!(cv_detail_ImageFeatures_getPropKeypoints_const(self.as_raw_Detail_ImageFeatures())) };
 		let ret = unsafe { core::Vector::<core::KeyPoint>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [keypoints](Detail_ImageFeaturesTraitConst::keypoints), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_keypoints(&self) -> Result<core::Vector<core::KeyPoint>> {
+		crate::core::validity::check("Detail_ImageFeatures", self.as_raw_Detail_ImageFeatures())?;
+		Ok(Detail_ImageFeaturesTraitConst::keypoints(self))
+	}
+	
 	#[inline]
 	fn descriptors(&self) -> core::UMat {
-		let ret = unsafe { sys::cv_detail_ImageFeatures_getPropDescriptors_const(self.as_raw_Detail_ImageFeatures()) };
+		crate::core::validity::debug_check("Detail_ImageFeatures", self.as_raw_Detail_ImageFeatures());
+		let ret = unsafe { ffi_call!(cv_detail_ImageFeatures_getPropDescriptors_const(self.as_raw_Detail_ImageFeatures())) };
 		let ret = unsafe { core::UMat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [descriptors](Detail_ImageFeaturesTraitConst::descriptors), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_descriptors(&self) -> Result<core::UMat> {
+		crate::core::validity::check("Detail_ImageFeatures", self.as_raw_Detail_ImageFeatures())?;
+		Ok(Detail_ImageFeaturesTraitConst::descriptors(self))
+	}
+	
 }
 
 pub trait Detail_ImageFeaturesTrait: crate::stitching::Detail_ImageFeaturesTraitConst {
@@ -5575,32 +6446,37 @@ pub trait Detail_ImageFeaturesTrait: crate::stitching::Detail_ImageFeaturesTrait
 
 	#[inline]
 	fn set_img_idx(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_detail_ImageFeatures_setPropImg_idx_int(self.as_raw_mut_Detail_ImageFeatures(), val) };
+		crate::core::validity::debug_check("Detail_ImageFeatures", self.as_raw_mut_Detail_ImageFeatures());
+		let ret = unsafe { ffi_call!(cv_detail_ImageFeatures_setPropImg_idx_int(self.as_raw_mut_Detail_ImageFeatures(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_img_size(&mut self, val: core::Size) {
-		let ret = unsafe { sys::cv_detail_ImageFeatures_setPropImg_size_Size(self.as_raw_mut_Detail_ImageFeatures(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("Detail_ImageFeatures", self.as_raw_mut_Detail_ImageFeatures());
+		let ret = unsafe { ffi_call!(cv_detail_ImageFeatures_setPropImg_size_Size(self.as_raw_mut_Detail_ImageFeatures(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_keypoints(&mut self, mut val: core::Vector<core::KeyPoint>) {
-		let ret = unsafe { sys::cv_detail_ImageFeatures_setPropKeypoints_vector_KeyPoint_(self.as_raw_mut_Detail_ImageFeatures(), val.as_raw_mut_VectorOfKeyPoint()) };
+		crate::core::validity::debug_check("Detail_ImageFeatures", self.as_raw_mut_Detail_ImageFeatures());
+		let ret = unsafe { ffi_call!(cv_detail_ImageFeatures_setPropKeypoints_vector_KeyPoint_(self.as_raw_mut_Detail_ImageFeatures(), val.as_raw_mut_VectorOfKeyPoint())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_descriptors(&mut self, mut val: core::UMat) {
-		let ret = unsafe { sys::cv_detail_ImageFeatures_setPropDescriptors_UMat(self.as_raw_mut_Detail_ImageFeatures(), val.as_raw_mut_UMat()) };
+		crate::core::validity::debug_check("Detail_ImageFeatures", self.as_raw_mut_Detail_ImageFeatures());
+		let ret = unsafe { ffi_call!(cv_detail_ImageFeatures_setPropDescriptors_UMat(self.as_raw_mut_Detail_ImageFeatures(), val.as_raw_mut_UMat())) };
 		ret
 	}
 	
 	#[inline]
 	fn get_keypoints(&mut self) -> Result<core::Vector<core::KeyPoint>> {
+		crate::core::validity::check("Detail_ImageFeatures", self.as_raw_mut_Detail_ImageFeatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_ImageFeatures_getKeypoints(self.as_raw_mut_Detail_ImageFeatures(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_ImageFeatures_getKeypoints(self.as_raw_mut_Detail_ImageFeatures(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<core::KeyPoint>::opencv_from_extern(ret) };
@@ -5619,7 +6495,10 @@ opencv_type_boxed! { Detail_ImageFeatures }
 impl Drop for Detail_ImageFeatures {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_ImageFeatures_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_ImageFeatures_delete(self.as_raw_mut_Detail_ImageFeatures()) };
+		let ptr = self.as_raw_mut_Detail_ImageFeatures();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_ImageFeatures_delete(ptr) };
 	}
 }
 
@@ -5647,54 +6526,117 @@ pub trait Detail_MatchesInfoTraitConst {
 
 	#[inline]
 	fn src_img_idx(&self) -> i32 {
-		let ret = unsafe { sys::cv_detail_MatchesInfo_getPropSrc_img_idx_const(self.as_raw_Detail_MatchesInfo()) };
+		crate::core::validity::debug_check("Detail_MatchesInfo", self.as_raw_Detail_MatchesInfo());
+		let ret = unsafe { ffi_call!(cv_detail_MatchesInfo_getPropSrc_img_idx_const(self.as_raw_Detail_MatchesInfo())) };
 		ret
 	}
 	
+	/// Non-panicking version of [src_img_idx](Detail_MatchesInfoTraitConst::src_img_idx), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_src_img_idx(&self) -> Result<i32> {
+		crate::core::validity::check("Detail_MatchesInfo", self.as_raw_Detail_MatchesInfo())?;
+		Ok(Detail_MatchesInfoTraitConst::src_img_idx(self))
+	}
+	
 	/// Images indices (optional)
 	#[inline]
 	fn dst_img_idx(&self) -> i32 {
-		let ret = unsafe { sys::cv_detail_MatchesInfo_getPropDst_img_idx_const(self.as_raw_Detail_MatchesInfo()) };
+		crate::core::validity::debug_check("Detail_MatchesInfo", self.as_raw_Detail_MatchesInfo());
+		let ret = unsafe { ffi_call!(cv_detail_MatchesInfo_getPropDst_img_idx_const(self.as_raw_Detail_MatchesInfo())) };
 		ret
 	}
 	
+	/// Non-panicking version of [dst_img_idx](Detail_MatchesInfoTraitConst::dst_img_idx), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_dst_img_idx(&self) -> Result<i32> {
+		crate::core::validity::check("Detail_MatchesInfo", self.as_raw_Detail_MatchesInfo())?;
+		Ok(Detail_MatchesInfoTraitConst::dst_img_idx(self))
+	}
+	
 	#[inline]
 	fn matches(&self) -> core::Vector<core::DMatch> {
-		let ret = unsafe { sys::cv_detail_MatchesInfo_getPropMatches_const(self.as_raw_Detail_MatchesInfo()) };
+		crate::core::validity::debug_check("Detail_MatchesInfo", self.as_raw_Detail_MatchesInfo());
+		let ret = unsafe { ffi_call!(cv_detail_MatchesInfo_getPropMatches_const(self.as_raw_Detail_MatchesInfo())) };
 		let ret = unsafe { core::Vector::<core::DMatch>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [matches](Detail_MatchesInfoTraitConst::matches), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_matches(&self) -> Result<core::Vector<core::DMatch>> {
+		crate::core::validity::check("Detail_MatchesInfo", self.as_raw_Detail_MatchesInfo())?;
+		Ok(Detail_MatchesInfoTraitConst::matches(self))
+	}
+	
 	/// Geometrically consistent matches mask
 	#[inline]
 	fn inliers_mask(&self) -> core::Vector<u8> {
-		let ret = unsafe { sys::cv_detail_MatchesInfo_getPropInliers_mask_const(self.as_raw_Detail_MatchesInfo()) };
+		crate::core::validity::debug_check("Detail_MatchesInfo", self.as_raw_Detail_MatchesInfo());
+		let ret = unsafe { ffi_call!(cv_detail_MatchesInfo_getPropInliers_mask_const(self.as_raw_Detail_MatchesInfo())) };
 		let ret = unsafe { core::Vector::<u8>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [inliers_mask](Detail_MatchesInfoTraitConst::inliers_mask), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_inliers_mask(&self) -> Result<core::Vector<u8>> {
+		crate::core::validity::check("Detail_MatchesInfo", self.as_raw_Detail_MatchesInfo())?;
+		Ok(Detail_MatchesInfoTraitConst::inliers_mask(self))
+	}
+	
 	/// Number of geometrically consistent matches
 	#[inline]
 	fn num_inliers(&self) -> i32 {
-		let ret = unsafe { sys::cv_detail_MatchesInfo_getPropNum_inliers_const(self.as_raw_Detail_MatchesInfo()) };
+		crate::core::validity::debug_check("Detail_MatchesInfo", self.as_raw_Detail_MatchesInfo());
+		let ret = unsafe { ffi_call!(cv_detail_MatchesInfo_getPropNum_inliers_const(self.as_raw_Detail_MatchesInfo())) };
 		ret
 	}
 	
+	/// Non-panicking version of [num_inliers](Detail_MatchesInfoTraitConst::num_inliers), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_num_inliers(&self) -> Result<i32> {
+		crate::core::validity::check("Detail_MatchesInfo", self.as_raw_Detail_MatchesInfo())?;
+		Ok(Detail_MatchesInfoTraitConst::num_inliers(self))
+	}
+	
 	/// Estimated transformation
 	#[inline]
 	fn h(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_detail_MatchesInfo_getPropH_const(self.as_raw_Detail_MatchesInfo()) };
+		crate::core::validity::debug_check("Detail_MatchesInfo", self.as_raw_Detail_MatchesInfo());
+		let ret = unsafe { ffi_call!(cv_detail_MatchesInfo_getPropH_const(self.as_raw_Detail_MatchesInfo())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [h](Detail_MatchesInfoTraitConst::h), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_h(&self) -> Result<core::Mat> {
+		crate::core::validity::check("Detail_MatchesInfo", self.as_raw_Detail_MatchesInfo())?;
+		Ok(Detail_MatchesInfoTraitConst::h(self))
+	}
+	
 	/// Confidence two images are from the same panorama
 	#[inline]
 	fn confidence(&self) -> f64 {
-		let ret = unsafe { sys::cv_detail_MatchesInfo_getPropConfidence_const(self.as_raw_Detail_MatchesInfo()) };
+		crate::core::validity::debug_check("Detail_MatchesInfo", self.as_raw_Detail_MatchesInfo());
+		let ret = unsafe { ffi_call!(cv_detail_MatchesInfo_getPropConfidence_const(self.as_raw_Detail_MatchesInfo())) };
 		ret
 	}
 	
+	/// Non-panicking version of [confidence](Detail_MatchesInfoTraitConst::confidence), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_confidence(&self) -> Result<f64> {
+		crate::core::validity::check("Detail_MatchesInfo", self.as_raw_Detail_MatchesInfo())?;
+		Ok(Detail_MatchesInfoTraitConst::confidence(self))
+	}
+	
 }
 
 pub trait Detail_MatchesInfoTrait: crate::stitching::Detail_MatchesInfoTraitConst {
@@ -5702,55 +6644,63 @@ pub trait Detail_MatchesInfoTrait: crate::stitching::Detail_MatchesInfoTraitCons
 
 	#[inline]
 	fn set_src_img_idx(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_detail_MatchesInfo_setPropSrc_img_idx_int(self.as_raw_mut_Detail_MatchesInfo(), val) };
+		crate::core::validity::debug_check("Detail_MatchesInfo", self.as_raw_mut_Detail_MatchesInfo());
+		let ret = unsafe { ffi_call!(cv_detail_MatchesInfo_setPropSrc_img_idx_int(self.as_raw_mut_Detail_MatchesInfo(), val)) };
 		ret
 	}
 	
 	/// Images indices (optional)
 	#[inline]
 	fn set_dst_img_idx(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_detail_MatchesInfo_setPropDst_img_idx_int(self.as_raw_mut_Detail_MatchesInfo(), val) };
+		crate::core::validity::debug_check("Detail_MatchesInfo", self.as_raw_mut_Detail_MatchesInfo());
+		let ret = unsafe { ffi_call!(cv_detail_MatchesInfo_setPropDst_img_idx_int(self.as_raw_mut_Detail_MatchesInfo(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_matches(&mut self, mut val: core::Vector<core::DMatch>) {
-		let ret = unsafe { sys::cv_detail_MatchesInfo_setPropMatches_vector_DMatch_(self.as_raw_mut_Detail_MatchesInfo(), val.as_raw_mut_VectorOfDMatch()) };
+		crate::core::validity::debug_check("Detail_MatchesInfo", self.as_raw_mut_Detail_MatchesInfo());
+		let ret = unsafe { ffi_call!(cv_detail_MatchesInfo_setPropMatches_vector_DMatch_(self.as_raw_mut_Detail_MatchesInfo(), val.as_raw_mut_VectorOfDMatch())) };
 		ret
 	}
 	
 	/// Geometrically consistent matches mask
 	#[inline]
 	fn set_inliers_mask(&mut self, mut val: core::Vector<u8>) {
-		let ret = unsafe { sys::cv_detail_MatchesInfo_setPropInliers_mask_vector_unsigned_char_(self.as_raw_mut_Detail_MatchesInfo(), val.as_raw_mut_VectorOfu8()) };
+		crate::core::validity::debug_check("Detail_MatchesInfo", self.as_raw_mut_Detail_MatchesInfo());
+		let ret = unsafe { ffi_call!(cv_detail_MatchesInfo_setPropInliers_mask_vector_unsigned_char_(self.as_raw_mut_Detail_MatchesInfo(), val.as_raw_mut_VectorOfu8())) };
 		ret
 	}
 	
 	/// Number of geometrically consistent matches
 	#[inline]
 	fn set_num_inliers(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_detail_MatchesInfo_setPropNum_inliers_int(self.as_raw_mut_Detail_MatchesInfo(), val) };
+		crate::core::validity::debug_check("Detail_MatchesInfo", self.as_raw_mut_Detail_MatchesInfo());
+		let ret = unsafe { ffi_call!(cv_detail_MatchesInfo_setPropNum_inliers_int(self.as_raw_mut_Detail_MatchesInfo(), val)) };
 		ret
 	}
 	
 	/// Estimated transformation
 	#[inline]
 	fn set_h(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_detail_MatchesInfo_setPropH_Mat(self.as_raw_mut_Detail_MatchesInfo(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("Detail_MatchesInfo", self.as_raw_mut_Detail_MatchesInfo());
+		let ret = unsafe { ffi_call!(cv_detail_MatchesInfo_setPropH_Mat(self.as_raw_mut_Detail_MatchesInfo(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	/// Confidence two images are from the same panorama
 	#[inline]
 	fn set_confidence(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_detail_MatchesInfo_setPropConfidence_double(self.as_raw_mut_Detail_MatchesInfo(), val) };
+		crate::core::validity::debug_check("Detail_MatchesInfo", self.as_raw_mut_Detail_MatchesInfo());
+		let ret = unsafe { ffi_call!(cv_detail_MatchesInfo_setPropConfidence_double(self.as_raw_mut_Detail_MatchesInfo(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn get_matches(&mut self) -> Result<core::Vector<core::DMatch>> {
+		crate::core::validity::check("Detail_MatchesInfo", self.as_raw_mut_Detail_MatchesInfo())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_MatchesInfo_getMatches(self.as_raw_mut_Detail_MatchesInfo(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_MatchesInfo_getMatches(self.as_raw_mut_Detail_MatchesInfo(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<core::DMatch>::opencv_from_extern(ret) };
@@ -5759,8 +6709,9 @@ pub trait Detail_MatchesInfoTrait: crate::stitching::Detail_MatchesInfoTraitCons
 	
 	#[inline]
 	fn get_inliers(&mut self) -> Result<core::Vector<u8>> {
+		crate::core::validity::check("Detail_MatchesInfo", self.as_raw_mut_Detail_MatchesInfo())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_MatchesInfo_getInliers(self.as_raw_mut_Detail_MatchesInfo(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_MatchesInfo_getInliers(self.as_raw_mut_Detail_MatchesInfo(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<u8>::opencv_from_extern(ret) };
@@ -5784,7 +6735,10 @@ opencv_type_boxed! { Detail_MatchesInfo }
 impl Drop for Detail_MatchesInfo {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_MatchesInfo_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_MatchesInfo_delete(self.as_raw_mut_Detail_MatchesInfo()) };
+		let ptr = self.as_raw_mut_Detail_MatchesInfo();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_MatchesInfo_delete(ptr) };
 	}
 }
 
@@ -5802,7 +6756,7 @@ impl Detail_MatchesInfo {
 	#[inline]
 	pub fn default() -> Result<crate::stitching::Detail_MatchesInfo> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_MatchesInfo_MatchesInfo(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_MatchesInfo_MatchesInfo(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_MatchesInfo::opencv_from_extern(ret) };
@@ -5812,7 +6766,7 @@ impl Detail_MatchesInfo {
 	#[inline]
 	pub fn copy(other: &crate::stitching::Detail_MatchesInfo) -> Result<crate::stitching::Detail_MatchesInfo> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_MatchesInfo_MatchesInfo_const_MatchesInfoR(other.as_raw_Detail_MatchesInfo(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_MatchesInfo_MatchesInfo_const_MatchesInfoR(other.as_raw_Detail_MatchesInfo(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_MatchesInfo::opencv_from_extern(ret) };
@@ -5831,8 +6785,9 @@ pub trait Detail_MercatorProjectorTrait: crate::stitching::Detail_MercatorProjec
 
 	#[inline]
 	fn map_forward(&mut self, x: f32, y: f32, u: &mut f32, v: &mut f32) -> Result<()> {
+		crate::core::validity::check("Detail_MercatorProjector", self.as_raw_mut_Detail_MercatorProjector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_MercatorProjector_mapForward_float_float_floatR_floatR(self.as_raw_mut_Detail_MercatorProjector(), x, y, u, v, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_MercatorProjector_mapForward_float_float_floatR_floatR(self.as_raw_mut_Detail_MercatorProjector(), x, y, u, v, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5840,8 +6795,9 @@ pub trait Detail_MercatorProjectorTrait: crate::stitching::Detail_MercatorProjec
 	
 	#[inline]
 	fn map_backward(&mut self, u: f32, v: f32, x: &mut f32, y: &mut f32) -> Result<()> {
+		crate::core::validity::check("Detail_MercatorProjector", self.as_raw_mut_Detail_MercatorProjector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_MercatorProjector_mapBackward_float_float_floatR_floatR(self.as_raw_mut_Detail_MercatorProjector(), u, v, x, y, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_MercatorProjector_mapBackward_float_float_floatR_floatR(self.as_raw_mut_Detail_MercatorProjector(), u, v, x, y, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5858,7 +6814,10 @@ opencv_type_boxed! { Detail_MercatorProjector }
 impl Drop for Detail_MercatorProjector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_MercatorProjector_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_MercatorProjector_delete(self.as_raw_mut_Detail_MercatorProjector()) };
+		let ptr = self.as_raw_mut_Detail_MercatorProjector();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_MercatorProjector_delete(ptr) };
 	}
 }
 
@@ -5904,7 +6863,10 @@ opencv_type_boxed! { Detail_MercatorWarper }
 impl Drop for Detail_MercatorWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_MercatorWarper_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_MercatorWarper_delete(self.as_raw_mut_Detail_MercatorWarper()) };
+		let ptr = self.as_raw_mut_Detail_MercatorWarper();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_MercatorWarper_delete(ptr) };
 	}
 }
 
@@ -5930,7 +6892,7 @@ impl Detail_MercatorWarper {
 	#[inline]
 	pub fn new(scale: f32) -> Result<crate::stitching::Detail_MercatorWarper> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_MercatorWarper_MercatorWarper_float(scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_MercatorWarper_MercatorWarper_float(scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_MercatorWarper::opencv_from_extern(ret) };
@@ -5945,8 +6907,9 @@ pub trait Detail_MultiBandBlenderTraitConst: crate::stitching::Detail_BlenderTra
 
 	#[inline]
 	fn num_bands(&self) -> Result<i32> {
+		crate::core::validity::check("Detail_MultiBandBlender", self.as_raw_Detail_MultiBandBlender())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_MultiBandBlender_numBands_const(self.as_raw_Detail_MultiBandBlender(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_MultiBandBlender_numBands_const(self.as_raw_Detail_MultiBandBlender(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5959,8 +6922,9 @@ pub trait Detail_MultiBandBlenderTrait: crate::stitching::Detail_BlenderTrait +
 
 	#[inline]
 	fn set_num_bands(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("Detail_MultiBandBlender", self.as_raw_mut_Detail_MultiBandBlender())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_MultiBandBlender_setNumBands_int(self.as_raw_mut_Detail_MultiBandBlender(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_MultiBandBlender_setNumBands_int(self.as_raw_mut_Detail_MultiBandBlender(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5968,8 +6932,9 @@ pub trait Detail_MultiBandBlenderTrait: crate::stitching::Detail_BlenderTrait +
 	
 	#[inline]
 	fn prepare(&mut self, dst_roi: core::Rect) -> Result<()> {
+		crate::core::validity::check("Detail_MultiBandBlender", self.as_raw_mut_Detail_MultiBandBlender())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_MultiBandBlender_prepare_Rect(self.as_raw_mut_Detail_MultiBandBlender(), dst_roi.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_MultiBandBlender_prepare_Rect(self.as_raw_mut_Detail_MultiBandBlender(), dst_roi.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5977,10 +6942,11 @@ pub trait Detail_MultiBandBlenderTrait: crate::stitching::Detail_BlenderTrait +
 	
 	#[inline]
 	fn feed(&mut self, img: &dyn core::ToInputArray, mask: &dyn core::ToInputArray, tl: core::Point) -> Result<()> {
+		crate::core::validity::check("Detail_MultiBandBlender", self.as_raw_mut_Detail_MultiBandBlender())?;
 		input_array_arg!(img);
 		input_array_arg!(mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_MultiBandBlender_feed_const__InputArrayR_const__InputArrayR_Point(self.as_raw_mut_Detail_MultiBandBlender(), img.as_raw__InputArray(), mask.as_raw__InputArray(), tl.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_MultiBandBlender_feed_const__InputArrayR_const__InputArrayR_Point(self.as_raw_mut_Detail_MultiBandBlender(), img.as_raw__InputArray(), mask.as_raw__InputArray(), tl.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -5988,10 +6954,11 @@ pub trait Detail_MultiBandBlenderTrait: crate::stitching::Detail_BlenderTrait +
 	
 	#[inline]
 	fn blend(&mut self, dst: &mut dyn core::ToInputOutputArray, dst_mask: &mut dyn core::ToInputOutputArray) -> Result<()> {
+		crate::core::validity::check("Detail_MultiBandBlender", self.as_raw_mut_Detail_MultiBandBlender())?;
 		input_output_array_arg!(dst);
 		input_output_array_arg!(dst_mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_MultiBandBlender_blend_const__InputOutputArrayR_const__InputOutputArrayR(self.as_raw_mut_Detail_MultiBandBlender(), dst.as_raw__InputOutputArray(), dst_mask.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_MultiBandBlender_blend_const__InputOutputArrayR_const__InputOutputArrayR(self.as_raw_mut_Detail_MultiBandBlender(), dst.as_raw__InputOutputArray(), dst_mask.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6009,7 +6976,10 @@ opencv_type_boxed! { Detail_MultiBandBlender }
 impl Drop for Detail_MultiBandBlender {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_MultiBandBlender_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_MultiBandBlender_delete(self.as_raw_mut_Detail_MultiBandBlender()) };
+		let ptr = self.as_raw_mut_Detail_MultiBandBlender();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_MultiBandBlender_delete(ptr) };
 	}
 }
 
@@ -6039,7 +7009,7 @@ impl Detail_MultiBandBlender {
 	#[inline]
 	pub fn new(try_gpu: i32, num_bands: i32, weight_type: i32) -> Result<crate::stitching::Detail_MultiBandBlender> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_MultiBandBlender_MultiBandBlender_int_int_int(try_gpu, num_bands, weight_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_MultiBandBlender_MultiBandBlender_int_int_int(try_gpu, num_bands, weight_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_MultiBandBlender::opencv_from_extern(ret) };
@@ -6048,6 +7018,35 @@ impl Detail_MultiBandBlender {
 	
 }
 
+/// Builder for `Detail_MultiBandBlender::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct Detail_MultiBandBlender_NewBuilder {
+	try_gpu: i32,
+	num_bands: i32,
+	weight_type: i32,
+}
+
+impl Detail_MultiBandBlender_NewBuilder {
+	#[inline]
+	pub fn new(try_gpu: i32, weight_type: i32) -> Self {
+		Self { try_gpu, num_bands: 5, weight_type }
+	}
+	
+	/// C++ default: `5`
+	#[inline]
+	pub fn with_num_bands(mut self, num_bands: i32) -> Self {
+		self.num_bands = num_bands;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::stitching::Detail_MultiBandBlender> {
+		Detail_MultiBandBlender::new(self.try_gpu, self.num_bands, self.weight_type)
+	}
+}
+
 boxed_cast_base! { Detail_MultiBandBlender, crate::stitching::Detail_Blender, cv_Detail_MultiBandBlender_to_Detail_Blender }
 
 /// Stub bundle adjuster that does nothing.
@@ -6071,7 +7070,10 @@ opencv_type_boxed! { Detail_NoBundleAdjuster }
 impl Drop for Detail_NoBundleAdjuster {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_NoBundleAdjuster_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_NoBundleAdjuster_delete(self.as_raw_mut_Detail_NoBundleAdjuster()) };
+		let ptr = self.as_raw_mut_Detail_NoBundleAdjuster();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_NoBundleAdjuster_delete(ptr) };
 	}
 }
 
@@ -6105,7 +7107,7 @@ impl Detail_NoBundleAdjuster {
 	#[inline]
 	pub fn default() -> Result<crate::stitching::Detail_NoBundleAdjuster> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_NoBundleAdjuster_NoBundleAdjuster(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_NoBundleAdjuster_NoBundleAdjuster(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_NoBundleAdjuster::opencv_from_extern(ret) };
@@ -6125,10 +7127,11 @@ pub trait Detail_NoExposureCompensatorTrait: crate::stitching::Detail_ExposureCo
 
 	#[inline]
 	fn apply(&mut self, unnamed: i32, unnamed_1: core::Point, unnamed_2: &mut dyn core::ToInputOutputArray, unnamed_3: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("Detail_NoExposureCompensator", self.as_raw_mut_Detail_NoExposureCompensator())?;
 		input_output_array_arg!(unnamed_2);
 		input_array_arg!(unnamed_3);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_NoExposureCompensator_apply_int_Point_const__InputOutputArrayR_const__InputArrayR(self.as_raw_mut_Detail_NoExposureCompensator(), unnamed, unnamed_1.opencv_as_extern(), unnamed_2.as_raw__InputOutputArray(), unnamed_3.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_NoExposureCompensator_apply_int_Point_const__InputOutputArrayR_const__InputArrayR(self.as_raw_mut_Detail_NoExposureCompensator(), unnamed, unnamed_1.opencv_as_extern(), unnamed_2.as_raw__InputOutputArray(), unnamed_3.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6136,8 +7139,9 @@ pub trait Detail_NoExposureCompensatorTrait: crate::stitching::Detail_ExposureCo
 	
 	#[inline]
 	fn get_mat_gains(&mut self, umv: &mut core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("Detail_NoExposureCompensator", self.as_raw_mut_Detail_NoExposureCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_NoExposureCompensator_getMatGains_vector_Mat_R(self.as_raw_mut_Detail_NoExposureCompensator(), umv.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_NoExposureCompensator_getMatGains_vector_Mat_R(self.as_raw_mut_Detail_NoExposureCompensator(), umv.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6145,8 +7149,9 @@ pub trait Detail_NoExposureCompensatorTrait: crate::stitching::Detail_ExposureCo
 	
 	#[inline]
 	fn set_mat_gains(&mut self, umv: &mut core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("Detail_NoExposureCompensator", self.as_raw_mut_Detail_NoExposureCompensator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_NoExposureCompensator_setMatGains_vector_Mat_R(self.as_raw_mut_Detail_NoExposureCompensator(), umv.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_NoExposureCompensator_setMatGains_vector_Mat_R(self.as_raw_mut_Detail_NoExposureCompensator(), umv.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6164,7 +7169,10 @@ opencv_type_boxed! { Detail_NoExposureCompensator }
 impl Drop for Detail_NoExposureCompensator {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_NoExposureCompensator_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_NoExposureCompensator_delete(self.as_raw_mut_Detail_NoExposureCompensator()) };
+		let ptr = self.as_raw_mut_Detail_NoExposureCompensator();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_NoExposureCompensator_delete(ptr) };
 	}
 }
 
@@ -6200,8 +7208,9 @@ pub trait Detail_NoSeamFinderTrait: crate::stitching::Detail_NoSeamFinderTraitCo
 
 	#[inline]
 	fn find(&mut self, unnamed: &core::Vector<core::UMat>, unnamed_1: &core::Vector<core::Point>, unnamed_2: &mut core::Vector<core::UMat>) -> Result<()> {
+		crate::core::validity::check("Detail_NoSeamFinder", self.as_raw_mut_Detail_NoSeamFinder())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_NoSeamFinder_find_const_vector_UMat_R_const_vector_Point_R_vector_UMat_R(self.as_raw_mut_Detail_NoSeamFinder(), unnamed.as_raw_VectorOfUMat(), unnamed_1.as_raw_VectorOfPoint(), unnamed_2.as_raw_mut_VectorOfUMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_NoSeamFinder_find_const_vector_UMat_R_const_vector_Point_R_vector_UMat_R(self.as_raw_mut_Detail_NoSeamFinder(), unnamed.as_raw_VectorOfUMat(), unnamed_1.as_raw_VectorOfPoint(), unnamed_2.as_raw_mut_VectorOfUMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6219,7 +7228,10 @@ opencv_type_boxed! { Detail_NoSeamFinder }
 impl Drop for Detail_NoSeamFinder {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_NoSeamFinder_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_NoSeamFinder_delete(self.as_raw_mut_Detail_NoSeamFinder()) };
+		let ptr = self.as_raw_mut_Detail_NoSeamFinder();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_NoSeamFinder_delete(ptr) };
 	}
 }
 
@@ -6255,8 +7267,9 @@ pub trait Detail_PairwiseSeamFinder: crate::stitching::Detail_PairwiseSeamFinder
 
 	#[inline]
 	fn find(&mut self, src: &core::Vector<core::UMat>, corners: &core::Vector<core::Point>, masks: &mut core::Vector<core::UMat>) -> Result<()> {
+		crate::core::validity::check("Detail_PairwiseSeamFinder", self.as_raw_mut_Detail_PairwiseSeamFinder())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_PairwiseSeamFinder_find_const_vector_UMat_R_const_vector_Point_R_vector_UMat_R(self.as_raw_mut_Detail_PairwiseSeamFinder(), src.as_raw_VectorOfUMat(), corners.as_raw_VectorOfPoint(), masks.as_raw_mut_VectorOfUMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_PairwiseSeamFinder_find_const_vector_UMat_R_const_vector_Point_R_vector_UMat_R(self.as_raw_mut_Detail_PairwiseSeamFinder(), src.as_raw_VectorOfUMat(), corners.as_raw_VectorOfPoint(), masks.as_raw_mut_VectorOfUMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6269,16 +7282,34 @@ pub trait Detail_PaniniPortraitProjectorTraitConst: crate::stitching::Detail_Pro
 
 	#[inline]
 	fn a(&self) -> f32 {
-		let ret = unsafe { sys::cv_detail_PaniniPortraitProjector_getPropA_const(self.as_raw_Detail_PaniniPortraitProjector()) };
+		crate::core::validity::debug_check("Detail_PaniniPortraitProjector", self.as_raw_Detail_PaniniPortraitProjector());
+		let ret = unsafe { ffi_call!(cv_detail_PaniniPortraitProjector_getPropA_const(self.as_raw_Detail_PaniniPortraitProjector())) };
 		ret
 	}
 	
+	/// Non-panicking version of [a](Detail_PaniniPortraitProjectorTraitConst::a), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_a(&self) -> Result<f32> {
+		crate::core::validity::check("Detail_PaniniPortraitProjector", self.as_raw_Detail_PaniniPortraitProjector())?;
+		Ok(Detail_PaniniPortraitProjectorTraitConst::a(self))
+	}
+	
 	#[inline]
 	fn b(&self) -> f32 {
-		let ret = unsafe { sys::cv_detail_PaniniPortraitProjector_getPropB_const(self.as_raw_Detail_PaniniPortraitProjector()) };
+		crate::core::validity::debug_check("Detail_PaniniPortraitProjector", self.as_raw_Detail_PaniniPortraitProjector());
+		let ret = unsafe { ffi_call!(cv_detail_PaniniPortraitProjector_getPropB_const(self.as_raw_Detail_PaniniPortraitProjector())) };
 		ret
 	}
 	
+	/// Non-panicking version of [b](Detail_PaniniPortraitProjectorTraitConst::b), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_b(&self) -> Result<f32> {
+		crate::core::validity::check("Detail_PaniniPortraitProjector", self.as_raw_Detail_PaniniPortraitProjector())?;
+		Ok(Detail_PaniniPortraitProjectorTraitConst::b(self))
+	}
+	
 }
 
 pub trait Detail_PaniniPortraitProjectorTrait: crate::stitching::Detail_PaniniPortraitProjectorTraitConst + crate::stitching::Detail_ProjectorBaseTrait {
@@ -6286,20 +7317,23 @@ pub trait Detail_PaniniPortraitProjectorTrait: crate::stitching::Detail_PaniniPo
 
 	#[inline]
 	fn set_a(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_detail_PaniniPortraitProjector_setPropA_float(self.as_raw_mut_Detail_PaniniPortraitProjector(), val) };
+		crate::core::validity::debug_check("Detail_PaniniPortraitProjector", self.as_raw_mut_Detail_PaniniPortraitProjector());
+		let ret = unsafe { ffi_call!(cv_detail_PaniniPortraitProjector_setPropA_float(self.as_raw_mut_Detail_PaniniPortraitProjector(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_b(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_detail_PaniniPortraitProjector_setPropB_float(self.as_raw_mut_Detail_PaniniPortraitProjector(), val) };
+		crate::core::validity::debug_check("Detail_PaniniPortraitProjector", self.as_raw_mut_Detail_PaniniPortraitProjector());
+		let ret = unsafe { ffi_call!(cv_detail_PaniniPortraitProjector_setPropB_float(self.as_raw_mut_Detail_PaniniPortraitProjector(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn map_forward(&mut self, x: f32, y: f32, u: &mut f32, v: &mut f32) -> Result<()> {
+		crate::core::validity::check("Detail_PaniniPortraitProjector", self.as_raw_mut_Detail_PaniniPortraitProjector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_PaniniPortraitProjector_mapForward_float_float_floatR_floatR(self.as_raw_mut_Detail_PaniniPortraitProjector(), x, y, u, v, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_PaniniPortraitProjector_mapForward_float_float_floatR_floatR(self.as_raw_mut_Detail_PaniniPortraitProjector(), x, y, u, v, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6307,8 +7341,9 @@ pub trait Detail_PaniniPortraitProjectorTrait: crate::stitching::Detail_PaniniPo
 	
 	#[inline]
 	fn map_backward(&mut self, u: f32, v: f32, x: &mut f32, y: &mut f32) -> Result<()> {
+		crate::core::validity::check("Detail_PaniniPortraitProjector", self.as_raw_mut_Detail_PaniniPortraitProjector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_PaniniPortraitProjector_mapBackward_float_float_floatR_floatR(self.as_raw_mut_Detail_PaniniPortraitProjector(), u, v, x, y, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_PaniniPortraitProjector_mapBackward_float_float_floatR_floatR(self.as_raw_mut_Detail_PaniniPortraitProjector(), u, v, x, y, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6325,7 +7360,10 @@ opencv_type_boxed! { Detail_PaniniPortraitProjector }
 impl Drop for Detail_PaniniPortraitProjector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_PaniniPortraitProjector_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_PaniniPortraitProjector_delete(self.as_raw_mut_Detail_PaniniPortraitProjector()) };
+		let ptr = self.as_raw_mut_Detail_PaniniPortraitProjector();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_PaniniPortraitProjector_delete(ptr) };
 	}
 }
 
@@ -6371,7 +7409,10 @@ opencv_type_boxed! { Detail_PaniniPortraitWarper }
 impl Drop for Detail_PaniniPortraitWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_PaniniPortraitWarper_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_PaniniPortraitWarper_delete(self.as_raw_mut_Detail_PaniniPortraitWarper()) };
+		let ptr = self.as_raw_mut_Detail_PaniniPortraitWarper();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_PaniniPortraitWarper_delete(ptr) };
 	}
 }
 
@@ -6400,7 +7441,7 @@ impl Detail_PaniniPortraitWarper {
 	#[inline]
 	pub fn new(scale: f32, a: f32, b: f32) -> Result<crate::stitching::Detail_PaniniPortraitWarper> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_PaniniPortraitWarper_PaniniPortraitWarper_float_float_float(scale, a, b, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_PaniniPortraitWarper_PaniniPortraitWarper_float_float_float(scale, a, b, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_PaniniPortraitWarper::opencv_from_extern(ret) };
@@ -6414,16 +7455,34 @@ pub trait Detail_PaniniProjectorTraitConst: crate::stitching::Detail_ProjectorBa
 
 	#[inline]
 	fn a(&self) -> f32 {
-		let ret = unsafe { sys::cv_detail_PaniniProjector_getPropA_const(self.as_raw_Detail_PaniniProjector()) };
+		crate::core::validity::debug_check("Detail_PaniniProjector", self.as_raw_Detail_PaniniProjector());
+		let ret = unsafe { ffi_call!(cv_detail_PaniniProjector_getPropA_const(self.as_raw_Detail_PaniniProjector())) };
 		ret
 	}
 	
+	/// Non-panicking version of [a](Detail_PaniniProjectorTraitConst::a), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_a(&self) -> Result<f32> {
+		crate::core::validity::check("Detail_PaniniProjector", self.as_raw_Detail_PaniniProjector())?;
+		Ok(Detail_PaniniProjectorTraitConst::a(self))
+	}
+	
 	#[inline]
 	fn b(&self) -> f32 {
-		let ret = unsafe { sys::cv_detail_PaniniProjector_getPropB_const(self.as_raw_Detail_PaniniProjector()) };
+		crate::core::validity::debug_check("Detail_PaniniProjector", self.as_raw_Detail_PaniniProjector());
+		let ret = unsafe { ffi_call!(cv_detail_PaniniProjector_getPropB_const(self.as_raw_Detail_PaniniProjector())) };
 		ret
 	}
 	
+	/// Non-panicking version of [b](Detail_PaniniProjectorTraitConst::b), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_b(&self) -> Result<f32> {
+		crate::core::validity::check("Detail_PaniniProjector", self.as_raw_Detail_PaniniProjector())?;
+		Ok(Detail_PaniniProjectorTraitConst::b(self))
+	}
+	
 }
 
 pub trait Detail_PaniniProjectorTrait: crate::stitching::Detail_PaniniProjectorTraitConst + crate::stitching::Detail_ProjectorBaseTrait {
@@ -6431,20 +7490,23 @@ pub trait Detail_PaniniProjectorTrait: crate::stitching::Detail_PaniniProjectorT
 
 	#[inline]
 	fn set_a(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_detail_PaniniProjector_setPropA_float(self.as_raw_mut_Detail_PaniniProjector(), val) };
+		crate::core::validity::debug_check("Detail_PaniniProjector", self.as_raw_mut_Detail_PaniniProjector());
+		let ret = unsafe { ffi_call!(cv_detail_PaniniProjector_setPropA_float(self.as_raw_mut_Detail_PaniniProjector(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_b(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_detail_PaniniProjector_setPropB_float(self.as_raw_mut_Detail_PaniniProjector(), val) };
+		crate::core::validity::debug_check("Detail_PaniniProjector", self.as_raw_mut_Detail_PaniniProjector());
+		let ret = unsafe { ffi_call!(cv_detail_PaniniProjector_setPropB_float(self.as_raw_mut_Detail_PaniniProjector(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn map_forward(&mut self, x: f32, y: f32, u: &mut f32, v: &mut f32) -> Result<()> {
+		crate::core::validity::check("Detail_PaniniProjector", self.as_raw_mut_Detail_PaniniProjector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_PaniniProjector_mapForward_float_float_floatR_floatR(self.as_raw_mut_Detail_PaniniProjector(), x, y, u, v, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_PaniniProjector_mapForward_float_float_floatR_floatR(self.as_raw_mut_Detail_PaniniProjector(), x, y, u, v, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6452,8 +7514,9 @@ pub trait Detail_PaniniProjectorTrait: crate::stitching::Detail_PaniniProjectorT
 	
 	#[inline]
 	fn map_backward(&mut self, u: f32, v: f32, x: &mut f32, y: &mut f32) -> Result<()> {
+		crate::core::validity::check("Detail_PaniniProjector", self.as_raw_mut_Detail_PaniniProjector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_PaniniProjector_mapBackward_float_float_floatR_floatR(self.as_raw_mut_Detail_PaniniProjector(), u, v, x, y, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_PaniniProjector_mapBackward_float_float_floatR_floatR(self.as_raw_mut_Detail_PaniniProjector(), u, v, x, y, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6470,7 +7533,10 @@ opencv_type_boxed! { Detail_PaniniProjector }
 impl Drop for Detail_PaniniProjector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_PaniniProjector_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_PaniniProjector_delete(self.as_raw_mut_Detail_PaniniProjector()) };
+		let ptr = self.as_raw_mut_Detail_PaniniProjector();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_PaniniProjector_delete(ptr) };
 	}
 }
 
@@ -6516,7 +7582,10 @@ opencv_type_boxed! { Detail_PaniniWarper }
 impl Drop for Detail_PaniniWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_PaniniWarper_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_PaniniWarper_delete(self.as_raw_mut_Detail_PaniniWarper()) };
+		let ptr = self.as_raw_mut_Detail_PaniniWarper();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_PaniniWarper_delete(ptr) };
 	}
 }
 
@@ -6545,7 +7614,7 @@ impl Detail_PaniniWarper {
 	#[inline]
 	pub fn new(scale: f32, a: f32, b: f32) -> Result<crate::stitching::Detail_PaniniWarper> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_PaniniWarper_PaniniWarper_float_float_float(scale, a, b, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_PaniniWarper_PaniniWarper_float_float_float(scale, a, b, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_PaniniWarper::opencv_from_extern(ret) };
@@ -6564,8 +7633,9 @@ pub trait Detail_PlanePortraitProjectorTrait: crate::stitching::Detail_PlanePort
 
 	#[inline]
 	fn map_forward(&mut self, x: f32, y: f32, u: &mut f32, v: &mut f32) -> Result<()> {
+		crate::core::validity::check("Detail_PlanePortraitProjector", self.as_raw_mut_Detail_PlanePortraitProjector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_PlanePortraitProjector_mapForward_float_float_floatR_floatR(self.as_raw_mut_Detail_PlanePortraitProjector(), x, y, u, v, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_PlanePortraitProjector_mapForward_float_float_floatR_floatR(self.as_raw_mut_Detail_PlanePortraitProjector(), x, y, u, v, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6573,8 +7643,9 @@ pub trait Detail_PlanePortraitProjectorTrait: crate::stitching::Detail_PlanePort
 	
 	#[inline]
 	fn map_backward(&mut self, u: f32, v: f32, x: &mut f32, y: &mut f32) -> Result<()> {
+		crate::core::validity::check("Detail_PlanePortraitProjector", self.as_raw_mut_Detail_PlanePortraitProjector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_PlanePortraitProjector_mapBackward_float_float_floatR_floatR(self.as_raw_mut_Detail_PlanePortraitProjector(), u, v, x, y, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_PlanePortraitProjector_mapBackward_float_float_floatR_floatR(self.as_raw_mut_Detail_PlanePortraitProjector(), u, v, x, y, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6591,7 +7662,10 @@ opencv_type_boxed! { Detail_PlanePortraitProjector }
 impl Drop for Detail_PlanePortraitProjector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_PlanePortraitProjector_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_PlanePortraitProjector_delete(self.as_raw_mut_Detail_PlanePortraitProjector()) };
+		let ptr = self.as_raw_mut_Detail_PlanePortraitProjector();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_PlanePortraitProjector_delete(ptr) };
 	}
 }
 
@@ -6637,7 +7711,10 @@ opencv_type_boxed! { Detail_PlanePortraitWarper }
 impl Drop for Detail_PlanePortraitWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_PlanePortraitWarper_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_PlanePortraitWarper_delete(self.as_raw_mut_Detail_PlanePortraitWarper()) };
+		let ptr = self.as_raw_mut_Detail_PlanePortraitWarper();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_PlanePortraitWarper_delete(ptr) };
 	}
 }
 
@@ -6663,7 +7740,7 @@ impl Detail_PlanePortraitWarper {
 	#[inline]
 	pub fn new(scale: f32) -> Result<crate::stitching::Detail_PlanePortraitWarper> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_PlanePortraitWarper_PlanePortraitWarper_float(scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_PlanePortraitWarper_PlanePortraitWarper_float(scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_PlanePortraitWarper::opencv_from_extern(ret) };
@@ -6682,8 +7759,9 @@ pub trait Detail_PlaneProjectorTrait: crate::stitching::Detail_PlaneProjectorTra
 
 	#[inline]
 	fn map_forward(&mut self, x: f32, y: f32, u: &mut f32, v: &mut f32) -> Result<()> {
+		crate::core::validity::check("Detail_PlaneProjector", self.as_raw_mut_Detail_PlaneProjector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_PlaneProjector_mapForward_float_float_floatR_floatR(self.as_raw_mut_Detail_PlaneProjector(), x, y, u, v, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_PlaneProjector_mapForward_float_float_floatR_floatR(self.as_raw_mut_Detail_PlaneProjector(), x, y, u, v, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6691,8 +7769,9 @@ pub trait Detail_PlaneProjectorTrait: crate::stitching::Detail_PlaneProjectorTra
 	
 	#[inline]
 	fn map_backward(&mut self, u: f32, v: f32, x: &mut f32, y: &mut f32) -> Result<()> {
+		crate::core::validity::check("Detail_PlaneProjector", self.as_raw_mut_Detail_PlaneProjector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_PlaneProjector_mapBackward_float_float_floatR_floatR(self.as_raw_mut_Detail_PlaneProjector(), u, v, x, y, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_PlaneProjector_mapBackward_float_float_floatR_floatR(self.as_raw_mut_Detail_PlaneProjector(), u, v, x, y, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6709,7 +7788,10 @@ opencv_type_boxed! { Detail_PlaneProjector }
 impl Drop for Detail_PlaneProjector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_PlaneProjector_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_PlaneProjector_delete(self.as_raw_mut_Detail_PlaneProjector()) };
+		let ptr = self.as_raw_mut_Detail_PlaneProjector();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_PlaneProjector_delete(ptr) };
 	}
 }
 
@@ -6747,10 +7829,11 @@ pub trait Detail_PlaneWarperTrait: crate::stitching::Detail_PlaneWarperTraitCons
 
 	#[inline]
 	fn warp_point(&mut self, pt: core::Point2f, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray) -> Result<core::Point2f> {
+		crate::core::validity::check("Detail_PlaneWarper", self.as_raw_mut_Detail_PlaneWarper())?;
 		input_array_arg!(k);
 		input_array_arg!(r);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_PlaneWarper_warpPoint_const_Point2fR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_Detail_PlaneWarper(), &pt, k.as_raw__InputArray(), r.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_PlaneWarper_warpPoint_const_Point2fR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_Detail_PlaneWarper(), &pt, k.as_raw__InputArray(), r.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6758,11 +7841,12 @@ pub trait Detail_PlaneWarperTrait: crate::stitching::Detail_PlaneWarperTraitCons
 	
 	#[inline]
 	fn warp_point_1(&mut self, pt: core::Point2f, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, t: &dyn core::ToInputArray) -> Result<core::Point2f> {
+		crate::core::validity::check("Detail_PlaneWarper", self.as_raw_mut_Detail_PlaneWarper())?;
 		input_array_arg!(k);
 		input_array_arg!(r);
 		input_array_arg!(t);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_PlaneWarper_warpPoint_const_Point2fR_const__InputArrayR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_Detail_PlaneWarper(), &pt, k.as_raw__InputArray(), r.as_raw__InputArray(), t.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_PlaneWarper_warpPoint_const_Point2fR_const__InputArrayR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_Detail_PlaneWarper(), &pt, k.as_raw__InputArray(), r.as_raw__InputArray(), t.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6770,10 +7854,11 @@ pub trait Detail_PlaneWarperTrait: crate::stitching::Detail_PlaneWarperTraitCons
 	
 	#[inline]
 	fn warp_point_backward(&mut self, pt: core::Point2f, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray) -> Result<core::Point2f> {
+		crate::core::validity::check("Detail_PlaneWarper", self.as_raw_mut_Detail_PlaneWarper())?;
 		input_array_arg!(k);
 		input_array_arg!(r);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_PlaneWarper_warpPointBackward_const_Point2fR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_Detail_PlaneWarper(), &pt, k.as_raw__InputArray(), r.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_PlaneWarper_warpPointBackward_const_Point2fR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_Detail_PlaneWarper(), &pt, k.as_raw__InputArray(), r.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6781,11 +7866,12 @@ pub trait Detail_PlaneWarperTrait: crate::stitching::Detail_PlaneWarperTraitCons
 	
 	#[inline]
 	fn warp_point_backward_1(&mut self, pt: core::Point2f, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, t: &dyn core::ToInputArray) -> Result<core::Point2f> {
+		crate::core::validity::check("Detail_PlaneWarper", self.as_raw_mut_Detail_PlaneWarper())?;
 		input_array_arg!(k);
 		input_array_arg!(r);
 		input_array_arg!(t);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_PlaneWarper_warpPointBackward_const_Point2fR_const__InputArrayR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_Detail_PlaneWarper(), &pt, k.as_raw__InputArray(), r.as_raw__InputArray(), t.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_PlaneWarper_warpPointBackward_const_Point2fR_const__InputArrayR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_Detail_PlaneWarper(), &pt, k.as_raw__InputArray(), r.as_raw__InputArray(), t.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6793,13 +7879,14 @@ pub trait Detail_PlaneWarperTrait: crate::stitching::Detail_PlaneWarperTraitCons
 	
 	#[inline]
 	fn build_maps(&mut self, src_size: core::Size, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, t: &dyn core::ToInputArray, xmap: &mut dyn core::ToOutputArray, ymap: &mut dyn core::ToOutputArray) -> Result<core::Rect> {
+		crate::core::validity::check("Detail_PlaneWarper", self.as_raw_mut_Detail_PlaneWarper())?;
 		input_array_arg!(k);
 		input_array_arg!(r);
 		input_array_arg!(t);
 		output_array_arg!(xmap);
 		output_array_arg!(ymap);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_PlaneWarper_buildMaps_Size_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_Detail_PlaneWarper(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), t.as_raw__InputArray(), xmap.as_raw__OutputArray(), ymap.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_PlaneWarper_buildMaps_Size_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_Detail_PlaneWarper(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), t.as_raw__InputArray(), xmap.as_raw__OutputArray(), ymap.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6807,12 +7894,13 @@ pub trait Detail_PlaneWarperTrait: crate::stitching::Detail_PlaneWarperTraitCons
 	
 	#[inline]
 	fn build_maps_1(&mut self, src_size: core::Size, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, xmap: &mut dyn core::ToOutputArray, ymap: &mut dyn core::ToOutputArray) -> Result<core::Rect> {
+		crate::core::validity::check("Detail_PlaneWarper", self.as_raw_mut_Detail_PlaneWarper())?;
 		input_array_arg!(k);
 		input_array_arg!(r);
 		output_array_arg!(xmap);
 		output_array_arg!(ymap);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_PlaneWarper_buildMaps_Size_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_Detail_PlaneWarper(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), xmap.as_raw__OutputArray(), ymap.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_PlaneWarper_buildMaps_Size_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_Detail_PlaneWarper(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), xmap.as_raw__OutputArray(), ymap.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6820,12 +7908,13 @@ pub trait Detail_PlaneWarperTrait: crate::stitching::Detail_PlaneWarperTraitCons
 	
 	#[inline]
 	fn warp(&mut self, src: &dyn core::ToInputArray, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, interp_mode: i32, border_mode: i32, dst: &mut dyn core::ToOutputArray) -> Result<core::Point> {
+		crate::core::validity::check("Detail_PlaneWarper", self.as_raw_mut_Detail_PlaneWarper())?;
 		input_array_arg!(src);
 		input_array_arg!(k);
 		input_array_arg!(r);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_PlaneWarper_warp_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_int_const__OutputArrayR(self.as_raw_mut_Detail_PlaneWarper(), src.as_raw__InputArray(), k.as_raw__InputArray(), r.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_PlaneWarper_warp_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_int_const__OutputArrayR(self.as_raw_mut_Detail_PlaneWarper(), src.as_raw__InputArray(), k.as_raw__InputArray(), r.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6833,13 +7922,14 @@ pub trait Detail_PlaneWarperTrait: crate::stitching::Detail_PlaneWarperTraitCons
 	
 	#[inline]
 	fn warp_1(&mut self, src: &dyn core::ToInputArray, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, t: &dyn core::ToInputArray, interp_mode: i32, border_mode: i32, dst: &mut dyn core::ToOutputArray) -> Result<core::Point> {
+		crate::core::validity::check("Detail_PlaneWarper", self.as_raw_mut_Detail_PlaneWarper())?;
 		input_array_arg!(src);
 		input_array_arg!(k);
 		input_array_arg!(r);
 		input_array_arg!(t);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_PlaneWarper_warp_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_int_const__OutputArrayR(self.as_raw_mut_Detail_PlaneWarper(), src.as_raw__InputArray(), k.as_raw__InputArray(), r.as_raw__InputArray(), t.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_PlaneWarper_warp_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_int_const__OutputArrayR(self.as_raw_mut_Detail_PlaneWarper(), src.as_raw__InputArray(), k.as_raw__InputArray(), r.as_raw__InputArray(), t.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6847,10 +7937,11 @@ pub trait Detail_PlaneWarperTrait: crate::stitching::Detail_PlaneWarperTraitCons
 	
 	#[inline]
 	fn warp_roi(&mut self, src_size: core::Size, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray) -> Result<core::Rect> {
+		crate::core::validity::check("Detail_PlaneWarper", self.as_raw_mut_Detail_PlaneWarper())?;
 		input_array_arg!(k);
 		input_array_arg!(r);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_PlaneWarper_warpRoi_Size_const__InputArrayR_const__InputArrayR(self.as_raw_mut_Detail_PlaneWarper(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_PlaneWarper_warpRoi_Size_const__InputArrayR_const__InputArrayR(self.as_raw_mut_Detail_PlaneWarper(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6858,11 +7949,12 @@ pub trait Detail_PlaneWarperTrait: crate::stitching::Detail_PlaneWarperTraitCons
 	
 	#[inline]
 	fn warp_roi_1(&mut self, src_size: core::Size, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, t: &dyn core::ToInputArray) -> Result<core::Rect> {
+		crate::core::validity::check("Detail_PlaneWarper", self.as_raw_mut_Detail_PlaneWarper())?;
 		input_array_arg!(k);
 		input_array_arg!(r);
 		input_array_arg!(t);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_PlaneWarper_warpRoi_Size_const__InputArrayR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_Detail_PlaneWarper(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), t.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_PlaneWarper_warpRoi_Size_const__InputArrayR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_Detail_PlaneWarper(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), t.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6880,7 +7972,10 @@ opencv_type_boxed! { Detail_PlaneWarper }
 impl Drop for Detail_PlaneWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_PlaneWarper_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_PlaneWarper_delete(self.as_raw_mut_Detail_PlaneWarper()) };
+		let ptr = self.as_raw_mut_Detail_PlaneWarper();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_PlaneWarper_delete(ptr) };
 	}
 }
 
@@ -6913,7 +8008,7 @@ impl Detail_PlaneWarper {
 	#[inline]
 	pub fn new(scale: f32) -> Result<crate::stitching::Detail_PlaneWarper> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_PlaneWarper_PlaneWarper_float(scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_PlaneWarper_PlaneWarper_float(scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_PlaneWarper::opencv_from_extern(ret) };
@@ -6936,12 +8031,13 @@ pub trait Detail_PlaneWarperGpuTrait: crate::stitching::Detail_PlaneWarperGpuTra
 
 	#[inline]
 	fn build_maps(&mut self, src_size: core::Size, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, xmap: &mut dyn core::ToOutputArray, ymap: &mut dyn core::ToOutputArray) -> Result<core::Rect> {
+		crate::core::validity::check("Detail_PlaneWarperGpu", self.as_raw_mut_Detail_PlaneWarperGpu())?;
 		input_array_arg!(k);
 		input_array_arg!(r);
 		output_array_arg!(xmap);
 		output_array_arg!(ymap);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_PlaneWarperGpu_buildMaps_Size_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_Detail_PlaneWarperGpu(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), xmap.as_raw__OutputArray(), ymap.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_PlaneWarperGpu_buildMaps_Size_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_Detail_PlaneWarperGpu(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), xmap.as_raw__OutputArray(), ymap.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6949,13 +8045,14 @@ pub trait Detail_PlaneWarperGpuTrait: crate::stitching::Detail_PlaneWarperGpuTra
 	
 	#[inline]
 	fn build_maps_1(&mut self, src_size: core::Size, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, t: &dyn core::ToInputArray, xmap: &mut dyn core::ToOutputArray, ymap: &mut dyn core::ToOutputArray) -> Result<core::Rect> {
+		crate::core::validity::check("Detail_PlaneWarperGpu", self.as_raw_mut_Detail_PlaneWarperGpu())?;
 		input_array_arg!(k);
 		input_array_arg!(r);
 		input_array_arg!(t);
 		output_array_arg!(xmap);
 		output_array_arg!(ymap);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_PlaneWarperGpu_buildMaps_Size_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_Detail_PlaneWarperGpu(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), t.as_raw__InputArray(), xmap.as_raw__OutputArray(), ymap.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_PlaneWarperGpu_buildMaps_Size_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_Detail_PlaneWarperGpu(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), t.as_raw__InputArray(), xmap.as_raw__OutputArray(), ymap.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6963,12 +8060,13 @@ pub trait Detail_PlaneWarperGpuTrait: crate::stitching::Detail_PlaneWarperGpuTra
 	
 	#[inline]
 	fn warp(&mut self, src: &dyn core::ToInputArray, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, interp_mode: i32, border_mode: i32, dst: &mut dyn core::ToOutputArray) -> Result<core::Point> {
+		crate::core::validity::check("Detail_PlaneWarperGpu", self.as_raw_mut_Detail_PlaneWarperGpu())?;
 		input_array_arg!(src);
 		input_array_arg!(k);
 		input_array_arg!(r);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_PlaneWarperGpu_warp_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_int_const__OutputArrayR(self.as_raw_mut_Detail_PlaneWarperGpu(), src.as_raw__InputArray(), k.as_raw__InputArray(), r.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_PlaneWarperGpu_warp_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_int_const__OutputArrayR(self.as_raw_mut_Detail_PlaneWarperGpu(), src.as_raw__InputArray(), k.as_raw__InputArray(), r.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6976,13 +8074,14 @@ pub trait Detail_PlaneWarperGpuTrait: crate::stitching::Detail_PlaneWarperGpuTra
 	
 	#[inline]
 	fn warp_1(&mut self, src: &dyn core::ToInputArray, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, t: &dyn core::ToInputArray, interp_mode: i32, border_mode: i32, dst: &mut dyn core::ToOutputArray) -> Result<core::Point> {
+		crate::core::validity::check("Detail_PlaneWarperGpu", self.as_raw_mut_Detail_PlaneWarperGpu())?;
 		input_array_arg!(src);
 		input_array_arg!(k);
 		input_array_arg!(r);
 		input_array_arg!(t);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_PlaneWarperGpu_warp_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_int_const__OutputArrayR(self.as_raw_mut_Detail_PlaneWarperGpu(), src.as_raw__InputArray(), k.as_raw__InputArray(), r.as_raw__InputArray(), t.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_PlaneWarperGpu_warp_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_int_const__OutputArrayR(self.as_raw_mut_Detail_PlaneWarperGpu(), src.as_raw__InputArray(), k.as_raw__InputArray(), r.as_raw__InputArray(), t.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -6990,10 +8089,11 @@ pub trait Detail_PlaneWarperGpuTrait: crate::stitching::Detail_PlaneWarperGpuTra
 	
 	#[inline]
 	fn build_maps_2(&mut self, src_size: core::Size, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, xmap: &mut core::GpuMat, ymap: &mut core::GpuMat) -> Result<core::Rect> {
+		crate::core::validity::check("Detail_PlaneWarperGpu", self.as_raw_mut_Detail_PlaneWarperGpu())?;
 		input_array_arg!(k);
 		input_array_arg!(r);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_PlaneWarperGpu_buildMaps_Size_const__InputArrayR_const__InputArrayR_GpuMatR_GpuMatR(self.as_raw_mut_Detail_PlaneWarperGpu(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), xmap.as_raw_mut_GpuMat(), ymap.as_raw_mut_GpuMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_PlaneWarperGpu_buildMaps_Size_const__InputArrayR_const__InputArrayR_GpuMatR_GpuMatR(self.as_raw_mut_Detail_PlaneWarperGpu(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), xmap.as_raw_mut_GpuMat(), ymap.as_raw_mut_GpuMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7001,11 +8101,12 @@ pub trait Detail_PlaneWarperGpuTrait: crate::stitching::Detail_PlaneWarperGpuTra
 	
 	#[inline]
 	fn build_maps_3(&mut self, src_size: core::Size, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, t: &dyn core::ToInputArray, xmap: &mut core::GpuMat, ymap: &mut core::GpuMat) -> Result<core::Rect> {
+		crate::core::validity::check("Detail_PlaneWarperGpu", self.as_raw_mut_Detail_PlaneWarperGpu())?;
 		input_array_arg!(k);
 		input_array_arg!(r);
 		input_array_arg!(t);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_PlaneWarperGpu_buildMaps_Size_const__InputArrayR_const__InputArrayR_const__InputArrayR_GpuMatR_GpuMatR(self.as_raw_mut_Detail_PlaneWarperGpu(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), t.as_raw__InputArray(), xmap.as_raw_mut_GpuMat(), ymap.as_raw_mut_GpuMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_PlaneWarperGpu_buildMaps_Size_const__InputArrayR_const__InputArrayR_const__InputArrayR_GpuMatR_GpuMatR(self.as_raw_mut_Detail_PlaneWarperGpu(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), t.as_raw__InputArray(), xmap.as_raw_mut_GpuMat(), ymap.as_raw_mut_GpuMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7013,10 +8114,11 @@ pub trait Detail_PlaneWarperGpuTrait: crate::stitching::Detail_PlaneWarperGpuTra
 	
 	#[inline]
 	fn warp_2(&mut self, src: &core::GpuMat, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, interp_mode: i32, border_mode: i32, dst: &mut core::GpuMat) -> Result<core::Point> {
+		crate::core::validity::check("Detail_PlaneWarperGpu", self.as_raw_mut_Detail_PlaneWarperGpu())?;
 		input_array_arg!(k);
 		input_array_arg!(r);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_PlaneWarperGpu_warp_const_GpuMatR_const__InputArrayR_const__InputArrayR_int_int_GpuMatR(self.as_raw_mut_Detail_PlaneWarperGpu(), src.as_raw_GpuMat(), k.as_raw__InputArray(), r.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw_mut_GpuMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_PlaneWarperGpu_warp_const_GpuMatR_const__InputArrayR_const__InputArrayR_int_int_GpuMatR(self.as_raw_mut_Detail_PlaneWarperGpu(), src.as_raw_GpuMat(), k.as_raw__InputArray(), r.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw_mut_GpuMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7024,11 +8126,12 @@ pub trait Detail_PlaneWarperGpuTrait: crate::stitching::Detail_PlaneWarperGpuTra
 	
 	#[inline]
 	fn warp_3(&mut self, src: &core::GpuMat, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, t: &dyn core::ToInputArray, interp_mode: i32, border_mode: i32, dst: &mut core::GpuMat) -> Result<core::Point> {
+		crate::core::validity::check("Detail_PlaneWarperGpu", self.as_raw_mut_Detail_PlaneWarperGpu())?;
 		input_array_arg!(k);
 		input_array_arg!(r);
 		input_array_arg!(t);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_PlaneWarperGpu_warp_const_GpuMatR_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_int_GpuMatR(self.as_raw_mut_Detail_PlaneWarperGpu(), src.as_raw_GpuMat(), k.as_raw__InputArray(), r.as_raw__InputArray(), t.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw_mut_GpuMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_PlaneWarperGpu_warp_const_GpuMatR_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_int_GpuMatR(self.as_raw_mut_Detail_PlaneWarperGpu(), src.as_raw_GpuMat(), k.as_raw__InputArray(), r.as_raw__InputArray(), t.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw_mut_GpuMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7045,7 +8148,10 @@ opencv_type_boxed! { Detail_PlaneWarperGpu }
 impl Drop for Detail_PlaneWarperGpu {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_PlaneWarperGpu_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_PlaneWarperGpu_delete(self.as_raw_mut_Detail_PlaneWarperGpu()) };
+		let ptr = self.as_raw_mut_Detail_PlaneWarperGpu();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_PlaneWarperGpu_delete(ptr) };
 	}
 }
 
@@ -7081,7 +8187,7 @@ impl Detail_PlaneWarperGpu {
 	#[inline]
 	pub fn new(scale: f32) -> Result<crate::stitching::Detail_PlaneWarperGpu> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_PlaneWarperGpu_PlaneWarperGpu_float(scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_PlaneWarperGpu_PlaneWarperGpu_float(scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_PlaneWarperGpu::opencv_from_extern(ret) };
@@ -7098,10 +8204,19 @@ pub trait Detail_ProjectorBaseTraitConst {
 
 	#[inline]
 	fn scale(&self) -> f32 {
-		let ret = unsafe { sys::cv_detail_ProjectorBase_getPropScale_const(self.as_raw_Detail_ProjectorBase()) };
+		crate::core::validity::debug_check("Detail_ProjectorBase", self.as_raw_Detail_ProjectorBase());
+		let ret = unsafe { ffi_call!(cv_detail_ProjectorBase_getPropScale_const(self.as_raw_Detail_ProjectorBase())) };
 		ret
 	}
 	
+	/// Non-panicking version of [scale](Detail_ProjectorBaseTraitConst::scale), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_scale(&self) -> Result<f32> {
+		crate::core::validity::check("Detail_ProjectorBase", self.as_raw_Detail_ProjectorBase())?;
+		Ok(Detail_ProjectorBaseTraitConst::scale(self))
+	}
+	
 }
 
 pub trait Detail_ProjectorBaseTrait: crate::stitching::Detail_ProjectorBaseTraitConst {
@@ -7109,43 +8224,64 @@ pub trait Detail_ProjectorBaseTrait: crate::stitching::Detail_ProjectorBaseTrait
 
 	#[inline]
 	fn set_scale(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_detail_ProjectorBase_setPropScale_float(self.as_raw_mut_Detail_ProjectorBase(), val) };
+		crate::core::validity::debug_check("Detail_ProjectorBase", self.as_raw_mut_Detail_ProjectorBase());
+		let ret = unsafe { ffi_call!(cv_detail_ProjectorBase_setPropScale_float(self.as_raw_mut_Detail_ProjectorBase(), val)) };
 		ret
 	}
 	
 	#[inline]
-	fn k(&mut self) -> &mut [f32; 9] {
-		let ret = unsafe { sys::cv_detail_ProjectorBase_getPropK(self.as_raw_mut_Detail_ProjectorBase()) };
-		let ret = unsafe { ret.as_mut() }.expect("Function returned null pointer");
-		ret
+	fn k(&mut self) -> Result<&mut [f32; 9]> {
+		crate::core::validity::check("Detail_ProjectorBase", self.as_raw_mut_Detail_ProjectorBase())?;
+		return_send!(via ocvrs_return);
+		unsafe { ffi_call!(cv_detail_ProjectorBase_getPropK(self.as_raw_mut_Detail_ProjectorBase(), ocvrs_return.as_mut_ptr())) };
+		return_receive!(unsafe ocvrs_return => ret);
+		let ret = ret.into_result()?;
+		let ret = unsafe { ret.as_mut() }.ok_or_else(|| Error::new(core::StsNullPtr, "Function returned null pointer"))?;
+		Ok(ret)
 	}
 	
 	#[inline]
-	fn rinv(&mut self) -> &mut [f32; 9] {
-		let ret = unsafe { sys::cv_detail_ProjectorBase_getPropRinv(self.as_raw_mut_Detail_ProjectorBase()) };
-		let ret = unsafe { ret.as_mut() }.expect("Function returned null pointer");
-		ret
+	fn rinv(&mut self) -> Result<&mut [f32; 9]> {
+		crate::core::validity::check("Detail_ProjectorBase", self.as_raw_mut_Detail_ProjectorBase())?;
+		return_send!(via ocvrs_return);
+		unsafe { ffi_call!(cv_detail_ProjectorBase_getPropRinv(self.as_raw_mut_Detail_ProjectorBase(), ocvrs_return.as_mut_ptr())) };
+		return_receive!(unsafe ocvrs_return => ret);
+		let ret = ret.into_result()?;
+		let ret = unsafe { ret.as_mut() }.ok_or_else(|| Error::new(core::StsNullPtr, "Function returned null pointer"))?;
+		Ok(ret)
 	}
 	
 	#[inline]
-	fn r_kinv(&mut self) -> &mut [f32; 9] {
-		let ret = unsafe { sys::cv_detail_ProjectorBase_getPropR_kinv(self.as_raw_mut_Detail_ProjectorBase()) };
-		let ret = unsafe { ret.as_mut() }.expect("Function returned null pointer");
-		ret
+	fn r_kinv(&mut self) -> Result<&mut [f32; 9]> {
+		crate::core::validity::check("Detail_ProjectorBase", self.as_raw_mut_Detail_ProjectorBase())?;
+		return_send!(via ocvrs_return);
+		unsafe { ffi_call!(cv_detail_ProjectorBase_getPropR_kinv(self.as_raw_mut_Detail_ProjectorBase(), ocvrs_return.as_mut_ptr())) };
+		return_receive!(unsafe ocvrs_return => ret);
+		let ret = ret.into_result()?;
+		let ret = unsafe { ret.as_mut() }.ok_or_else(|| Error::new(core::StsNullPtr, "Function returned null pointer"))?;
+		Ok(ret)
 	}
 	
 	#[inline]
-	fn k_rinv(&mut self) -> &mut [f32; 9] {
-		let ret = unsafe { sys::cv_detail_ProjectorBase_getPropK_rinv(self.as_raw_mut_Detail_ProjectorBase()) };
-		let ret = unsafe { ret.as_mut() }.expect("Function returned null pointer");
-		ret
+	fn k_rinv(&mut self) -> Result<&mut [f32; 9]> {
+		crate::core::validity::check("Detail_ProjectorBase", self.as_raw_mut_Detail_ProjectorBase())?;
+		return_send!(via ocvrs_return);
+		unsafe { ffi_call!(cv_detail_ProjectorBase_getPropK_rinv(self.as_raw_mut_Detail_ProjectorBase(), ocvrs_return.as_mut_ptr())) };
+		return_receive!(unsafe ocvrs_return => ret);
+		let ret = ret.into_result()?;
+		let ret = unsafe { ret.as_mut() }.ok_or_else(|| Error::new(core::StsNullPtr, "Function returned null pointer"))?;
+		Ok(ret)
 	}
 	
 	#[inline]
-	fn t(&mut self) -> &mut [f32; 3] {
-		let ret = unsafe { sys::cv_detail_ProjectorBase_getPropT(self.as_raw_mut_Detail_ProjectorBase()) };
-		let ret = unsafe { ret.as_mut() }.expect("Function returned null pointer");
-		ret
+	fn t(&mut self) -> Result<&mut [f32; 3]> {
+		crate::core::validity::check("Detail_ProjectorBase", self.as_raw_mut_Detail_ProjectorBase())?;
+		return_send!(via ocvrs_return);
+		unsafe { ffi_call!(cv_detail_ProjectorBase_getPropT(self.as_raw_mut_Detail_ProjectorBase(), ocvrs_return.as_mut_ptr())) };
+		return_receive!(unsafe ocvrs_return => ret);
+		let ret = ret.into_result()?;
+		let ret = unsafe { ret.as_mut() }.ok_or_else(|| Error::new(core::StsNullPtr, "Function returned null pointer"))?;
+		Ok(ret)
 	}
 	
 	/// ## C++ default parameters
@@ -7154,11 +8290,12 @@ pub trait Detail_ProjectorBaseTrait: crate::stitching::Detail_ProjectorBaseTrait
 	/// * t: Mat::zeros(3,1,CV_32F)
 	#[inline]
 	fn set_camera_params(&mut self, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, t: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("Detail_ProjectorBase", self.as_raw_mut_Detail_ProjectorBase())?;
 		input_array_arg!(k);
 		input_array_arg!(r);
 		input_array_arg!(t);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_ProjectorBase_setCameraParams_const__InputArrayR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_Detail_ProjectorBase(), k.as_raw__InputArray(), r.as_raw__InputArray(), t.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_ProjectorBase_setCameraParams_const__InputArrayR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_Detail_ProjectorBase(), k.as_raw__InputArray(), r.as_raw__InputArray(), t.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7176,7 +8313,10 @@ opencv_type_boxed! { Detail_ProjectorBase }
 impl Drop for Detail_ProjectorBase {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_ProjectorBase_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_ProjectorBase_delete(self.as_raw_mut_Detail_ProjectorBase()) };
+		let ptr = self.as_raw_mut_Detail_ProjectorBase();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_ProjectorBase_delete(ptr) };
 	}
 }
 
@@ -7199,8 +8339,9 @@ pub trait Detail_RotationWarperConst {
 
 	#[inline]
 	fn get_scale(&self) -> Result<f32> {
+		crate::core::validity::check("Detail_RotationWarper", self.as_raw_Detail_RotationWarper())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_RotationWarper_getScale_const(self.as_raw_Detail_RotationWarper(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_RotationWarper_getScale_const(self.as_raw_Detail_RotationWarper(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7221,10 +8362,11 @@ pub trait Detail_RotationWarper: crate::stitching::Detail_RotationWarperConst {
 	/// Projected point
 	#[inline]
 	fn warp_point(&mut self, pt: core::Point2f, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray) -> Result<core::Point2f> {
+		crate::core::validity::check("Detail_RotationWarper", self.as_raw_mut_Detail_RotationWarper())?;
 		input_array_arg!(k);
 		input_array_arg!(r);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_RotationWarper_warpPoint_const_Point2fR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_Detail_RotationWarper(), &pt, k.as_raw__InputArray(), r.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_RotationWarper_warpPoint_const_Point2fR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_Detail_RotationWarper(), &pt, k.as_raw__InputArray(), r.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7232,10 +8374,11 @@ pub trait Detail_RotationWarper: crate::stitching::Detail_RotationWarperConst {
 	
 	#[inline]
 	fn warp_point_backward(&mut self, pt: core::Point2f, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray) -> Result<core::Point2f> {
+		crate::core::validity::check("Detail_RotationWarper", self.as_raw_mut_Detail_RotationWarper())?;
 		input_array_arg!(k);
 		input_array_arg!(r);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_RotationWarper_warpPointBackward_const_Point2fR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_Detail_RotationWarper(), &pt, k.as_raw__InputArray(), r.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_RotationWarper_warpPointBackward_const_Point2fR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_Detail_RotationWarper(), &pt, k.as_raw__InputArray(), r.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7253,12 +8396,13 @@ pub trait Detail_RotationWarper: crate::stitching::Detail_RotationWarperConst {
 	/// Projected image minimum bounding box
 	#[inline]
 	fn build_maps(&mut self, src_size: core::Size, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, xmap: &mut dyn core::ToOutputArray, ymap: &mut dyn core::ToOutputArray) -> Result<core::Rect> {
+		crate::core::validity::check("Detail_RotationWarper", self.as_raw_mut_Detail_RotationWarper())?;
 		input_array_arg!(k);
 		input_array_arg!(r);
 		output_array_arg!(xmap);
 		output_array_arg!(ymap);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_RotationWarper_buildMaps_Size_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_Detail_RotationWarper(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), xmap.as_raw__OutputArray(), ymap.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_RotationWarper_buildMaps_Size_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_Detail_RotationWarper(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), xmap.as_raw__OutputArray(), ymap.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7277,12 +8421,13 @@ pub trait Detail_RotationWarper: crate::stitching::Detail_RotationWarperConst {
 	/// Project image top-left corner
 	#[inline]
 	fn warp(&mut self, src: &dyn core::ToInputArray, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, interp_mode: i32, border_mode: i32, dst: &mut dyn core::ToOutputArray) -> Result<core::Point> {
+		crate::core::validity::check("Detail_RotationWarper", self.as_raw_mut_Detail_RotationWarper())?;
 		input_array_arg!(src);
 		input_array_arg!(k);
 		input_array_arg!(r);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_RotationWarper_warp_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_int_const__OutputArrayR(self.as_raw_mut_Detail_RotationWarper(), src.as_raw__InputArray(), k.as_raw__InputArray(), r.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_RotationWarper_warp_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_int_const__OutputArrayR(self.as_raw_mut_Detail_RotationWarper(), src.as_raw__InputArray(), k.as_raw__InputArray(), r.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7300,12 +8445,13 @@ pub trait Detail_RotationWarper: crate::stitching::Detail_RotationWarperConst {
 	/// * dst: Backward-projected image
 	#[inline]
 	fn warp_backward(&mut self, src: &dyn core::ToInputArray, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, interp_mode: i32, border_mode: i32, dst_size: core::Size, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("Detail_RotationWarper", self.as_raw_mut_Detail_RotationWarper())?;
 		input_array_arg!(src);
 		input_array_arg!(k);
 		input_array_arg!(r);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_RotationWarper_warpBackward_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_int_Size_const__OutputArrayR(self.as_raw_mut_Detail_RotationWarper(), src.as_raw__InputArray(), k.as_raw__InputArray(), r.as_raw__InputArray(), interp_mode, border_mode, dst_size.opencv_as_extern(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_RotationWarper_warpBackward_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_int_Size_const__OutputArrayR(self.as_raw_mut_Detail_RotationWarper(), src.as_raw__InputArray(), k.as_raw__InputArray(), r.as_raw__InputArray(), interp_mode, border_mode, dst_size.opencv_as_extern(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7319,10 +8465,11 @@ pub trait Detail_RotationWarper: crate::stitching::Detail_RotationWarperConst {
 	/// Projected image minimum bounding box
 	#[inline]
 	fn warp_roi(&mut self, src_size: core::Size, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray) -> Result<core::Rect> {
+		crate::core::validity::check("Detail_RotationWarper", self.as_raw_mut_Detail_RotationWarper())?;
 		input_array_arg!(k);
 		input_array_arg!(r);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_RotationWarper_warpRoi_Size_const__InputArrayR_const__InputArrayR(self.as_raw_mut_Detail_RotationWarper(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_RotationWarper_warpRoi_Size_const__InputArrayR_const__InputArrayR(self.as_raw_mut_Detail_RotationWarper(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7330,8 +8477,9 @@ pub trait Detail_RotationWarper: crate::stitching::Detail_RotationWarperConst {
 	
 	#[inline]
 	fn set_scale(&mut self, unnamed: f32) -> Result<()> {
+		crate::core::validity::check("Detail_RotationWarper", self.as_raw_mut_Detail_RotationWarper())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_RotationWarper_setScale_float(self.as_raw_mut_Detail_RotationWarper(), unnamed, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_RotationWarper_setScale_float(self.as_raw_mut_Detail_RotationWarper(), unnamed, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7356,8 +8504,9 @@ pub trait Detail_SeamFinder: crate::stitching::Detail_SeamFinderConst {
 	/// * masks: Source image masks to update
 	#[inline]
 	fn find(&mut self, src: &core::Vector<core::UMat>, corners: &core::Vector<core::Point>, masks: &mut core::Vector<core::UMat>) -> Result<()> {
+		crate::core::validity::check("Detail_SeamFinder", self.as_raw_mut_Detail_SeamFinder())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_SeamFinder_find_const_vector_UMat_R_const_vector_Point_R_vector_UMat_R(self.as_raw_mut_Detail_SeamFinder(), src.as_raw_VectorOfUMat(), corners.as_raw_VectorOfPoint(), masks.as_raw_mut_VectorOfUMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_SeamFinder_find_const_vector_UMat_R_const_vector_Point_R_vector_UMat_R(self.as_raw_mut_Detail_SeamFinder(), src.as_raw_VectorOfUMat(), corners.as_raw_VectorOfPoint(), masks.as_raw_mut_VectorOfUMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7369,7 +8518,7 @@ impl dyn Detail_SeamFinder + '_ {
 	#[inline]
 	pub fn create_default(typ: i32) -> Result<core::Ptr<dyn crate::stitching::Detail_SeamFinder>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_SeamFinder_createDefault_int(typ, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_SeamFinder_createDefault_int(typ, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::stitching::Detail_SeamFinder>::opencv_from_extern(ret) };
@@ -7387,8 +8536,9 @@ pub trait Detail_SphericalPortraitProjectorTrait: crate::stitching::Detail_Proje
 
 	#[inline]
 	fn map_forward(&mut self, x: f32, y: f32, u: &mut f32, v: &mut f32) -> Result<()> {
+		crate::core::validity::check("Detail_SphericalPortraitProjector", self.as_raw_mut_Detail_SphericalPortraitProjector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_SphericalPortraitProjector_mapForward_float_float_floatR_floatR(self.as_raw_mut_Detail_SphericalPortraitProjector(), x, y, u, v, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_SphericalPortraitProjector_mapForward_float_float_floatR_floatR(self.as_raw_mut_Detail_SphericalPortraitProjector(), x, y, u, v, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7396,8 +8546,9 @@ pub trait Detail_SphericalPortraitProjectorTrait: crate::stitching::Detail_Proje
 	
 	#[inline]
 	fn map_backward(&mut self, u: f32, v: f32, x: &mut f32, y: &mut f32) -> Result<()> {
+		crate::core::validity::check("Detail_SphericalPortraitProjector", self.as_raw_mut_Detail_SphericalPortraitProjector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_SphericalPortraitProjector_mapBackward_float_float_floatR_floatR(self.as_raw_mut_Detail_SphericalPortraitProjector(), u, v, x, y, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_SphericalPortraitProjector_mapBackward_float_float_floatR_floatR(self.as_raw_mut_Detail_SphericalPortraitProjector(), u, v, x, y, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7414,7 +8565,10 @@ opencv_type_boxed! { Detail_SphericalPortraitProjector }
 impl Drop for Detail_SphericalPortraitProjector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_SphericalPortraitProjector_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_SphericalPortraitProjector_delete(self.as_raw_mut_Detail_SphericalPortraitProjector()) };
+		let ptr = self.as_raw_mut_Detail_SphericalPortraitProjector();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_SphericalPortraitProjector_delete(ptr) };
 	}
 }
 
@@ -7460,7 +8614,10 @@ opencv_type_boxed! { Detail_SphericalPortraitWarper }
 impl Drop for Detail_SphericalPortraitWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_SphericalPortraitWarper_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_SphericalPortraitWarper_delete(self.as_raw_mut_Detail_SphericalPortraitWarper()) };
+		let ptr = self.as_raw_mut_Detail_SphericalPortraitWarper();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_SphericalPortraitWarper_delete(ptr) };
 	}
 }
 
@@ -7486,7 +8643,7 @@ impl Detail_SphericalPortraitWarper {
 	#[inline]
 	pub fn new(scale: f32) -> Result<crate::stitching::Detail_SphericalPortraitWarper> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_SphericalPortraitWarper_SphericalPortraitWarper_float(scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_SphericalPortraitWarper_SphericalPortraitWarper_float(scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_SphericalPortraitWarper::opencv_from_extern(ret) };
@@ -7507,7 +8664,7 @@ impl Detail_SphericalProjector {
 	#[inline]
 	pub fn map_forward(self, x: f32, y: f32, u: &mut f32, v: &mut f32) -> Result<()> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_SphericalProjector_mapForward_float_float_floatR_floatR(self.opencv_as_extern(), x, y, u, v, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_SphericalProjector_mapForward_float_float_floatR_floatR(self.opencv_as_extern(), x, y, u, v, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7516,7 +8673,7 @@ impl Detail_SphericalProjector {
 	#[inline]
 	pub fn map_backward(self, u: f32, v: f32, x: &mut f32, y: &mut f32) -> Result<()> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_SphericalProjector_mapBackward_float_float_floatR_floatR(self.opencv_as_extern(), u, v, x, y, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_SphericalProjector_mapBackward_float_float_floatR_floatR(self.opencv_as_extern(), u, v, x, y, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7539,12 +8696,13 @@ pub trait Detail_SphericalWarperTrait: crate::stitching::Detail_SphericalWarperT
 
 	#[inline]
 	fn build_maps(&mut self, src_size: core::Size, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, xmap: &mut dyn core::ToOutputArray, ymap: &mut dyn core::ToOutputArray) -> Result<core::Rect> {
+		crate::core::validity::check("Detail_SphericalWarper", self.as_raw_mut_Detail_SphericalWarper())?;
 		input_array_arg!(k);
 		input_array_arg!(r);
 		output_array_arg!(xmap);
 		output_array_arg!(ymap);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_SphericalWarper_buildMaps_Size_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_Detail_SphericalWarper(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), xmap.as_raw__OutputArray(), ymap.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_SphericalWarper_buildMaps_Size_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_Detail_SphericalWarper(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), xmap.as_raw__OutputArray(), ymap.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7552,12 +8710,13 @@ pub trait Detail_SphericalWarperTrait: crate::stitching::Detail_SphericalWarperT
 	
 	#[inline]
 	fn warp(&mut self, src: &dyn core::ToInputArray, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, interp_mode: i32, border_mode: i32, dst: &mut dyn core::ToOutputArray) -> Result<core::Point> {
+		crate::core::validity::check("Detail_SphericalWarper", self.as_raw_mut_Detail_SphericalWarper())?;
 		input_array_arg!(src);
 		input_array_arg!(k);
 		input_array_arg!(r);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_SphericalWarper_warp_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_int_const__OutputArrayR(self.as_raw_mut_Detail_SphericalWarper(), src.as_raw__InputArray(), k.as_raw__InputArray(), r.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_SphericalWarper_warp_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_int_const__OutputArrayR(self.as_raw_mut_Detail_SphericalWarper(), src.as_raw__InputArray(), k.as_raw__InputArray(), r.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7579,7 +8738,10 @@ opencv_type_boxed! { Detail_SphericalWarper }
 impl Drop for Detail_SphericalWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_SphericalWarper_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_SphericalWarper_delete(self.as_raw_mut_Detail_SphericalWarper()) };
+		let ptr = self.as_raw_mut_Detail_SphericalWarper();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_SphericalWarper_delete(ptr) };
 	}
 }
 
@@ -7610,7 +8772,7 @@ impl Detail_SphericalWarper {
 	#[inline]
 	pub fn new(scale: f32) -> Result<crate::stitching::Detail_SphericalWarper> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_SphericalWarper_SphericalWarper_float(scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_SphericalWarper_SphericalWarper_float(scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_SphericalWarper::opencv_from_extern(ret) };
@@ -7631,12 +8793,13 @@ pub trait Detail_SphericalWarperGpuTrait: crate::stitching::Detail_SphericalWarp
 
 	#[inline]
 	fn build_maps(&mut self, src_size: core::Size, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, xmap: &mut dyn core::ToOutputArray, ymap: &mut dyn core::ToOutputArray) -> Result<core::Rect> {
+		crate::core::validity::check("Detail_SphericalWarperGpu", self.as_raw_mut_Detail_SphericalWarperGpu())?;
 		input_array_arg!(k);
 		input_array_arg!(r);
 		output_array_arg!(xmap);
 		output_array_arg!(ymap);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_SphericalWarperGpu_buildMaps_Size_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_Detail_SphericalWarperGpu(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), xmap.as_raw__OutputArray(), ymap.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_SphericalWarperGpu_buildMaps_Size_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_Detail_SphericalWarperGpu(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), xmap.as_raw__OutputArray(), ymap.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7644,12 +8807,13 @@ pub trait Detail_SphericalWarperGpuTrait: crate::stitching::Detail_SphericalWarp
 	
 	#[inline]
 	fn warp(&mut self, src: &dyn core::ToInputArray, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, interp_mode: i32, border_mode: i32, dst: &mut dyn core::ToOutputArray) -> Result<core::Point> {
+		crate::core::validity::check("Detail_SphericalWarperGpu", self.as_raw_mut_Detail_SphericalWarperGpu())?;
 		input_array_arg!(src);
 		input_array_arg!(k);
 		input_array_arg!(r);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_SphericalWarperGpu_warp_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_int_const__OutputArrayR(self.as_raw_mut_Detail_SphericalWarperGpu(), src.as_raw__InputArray(), k.as_raw__InputArray(), r.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_SphericalWarperGpu_warp_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_int_const__OutputArrayR(self.as_raw_mut_Detail_SphericalWarperGpu(), src.as_raw__InputArray(), k.as_raw__InputArray(), r.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7657,10 +8821,11 @@ pub trait Detail_SphericalWarperGpuTrait: crate::stitching::Detail_SphericalWarp
 	
 	#[inline]
 	fn build_maps_1(&mut self, src_size: core::Size, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, xmap: &mut core::GpuMat, ymap: &mut core::GpuMat) -> Result<core::Rect> {
+		crate::core::validity::check("Detail_SphericalWarperGpu", self.as_raw_mut_Detail_SphericalWarperGpu())?;
 		input_array_arg!(k);
 		input_array_arg!(r);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_SphericalWarperGpu_buildMaps_Size_const__InputArrayR_const__InputArrayR_GpuMatR_GpuMatR(self.as_raw_mut_Detail_SphericalWarperGpu(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), xmap.as_raw_mut_GpuMat(), ymap.as_raw_mut_GpuMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_SphericalWarperGpu_buildMaps_Size_const__InputArrayR_const__InputArrayR_GpuMatR_GpuMatR(self.as_raw_mut_Detail_SphericalWarperGpu(), src_size.opencv_as_extern(), k.as_raw__InputArray(), r.as_raw__InputArray(), xmap.as_raw_mut_GpuMat(), ymap.as_raw_mut_GpuMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7668,10 +8833,11 @@ pub trait Detail_SphericalWarperGpuTrait: crate::stitching::Detail_SphericalWarp
 	
 	#[inline]
 	fn warp_1(&mut self, src: &core::GpuMat, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, interp_mode: i32, border_mode: i32, dst: &mut core::GpuMat) -> Result<core::Point> {
+		crate::core::validity::check("Detail_SphericalWarperGpu", self.as_raw_mut_Detail_SphericalWarperGpu())?;
 		input_array_arg!(k);
 		input_array_arg!(r);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_SphericalWarperGpu_warp_const_GpuMatR_const__InputArrayR_const__InputArrayR_int_int_GpuMatR(self.as_raw_mut_Detail_SphericalWarperGpu(), src.as_raw_GpuMat(), k.as_raw__InputArray(), r.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw_mut_GpuMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_SphericalWarperGpu_warp_const_GpuMatR_const__InputArrayR_const__InputArrayR_int_int_GpuMatR(self.as_raw_mut_Detail_SphericalWarperGpu(), src.as_raw_GpuMat(), k.as_raw__InputArray(), r.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw_mut_GpuMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7688,7 +8854,10 @@ opencv_type_boxed! { Detail_SphericalWarperGpu }
 impl Drop for Detail_SphericalWarperGpu {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_SphericalWarperGpu_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_SphericalWarperGpu_delete(self.as_raw_mut_Detail_SphericalWarperGpu()) };
+		let ptr = self.as_raw_mut_Detail_SphericalWarperGpu();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_SphericalWarperGpu_delete(ptr) };
 	}
 }
 
@@ -7722,7 +8891,7 @@ impl Detail_SphericalWarperGpu {
 	#[inline]
 	pub fn new(scale: f32) -> Result<crate::stitching::Detail_SphericalWarperGpu> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_SphericalWarperGpu_SphericalWarperGpu_float(scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_SphericalWarperGpu_SphericalWarperGpu_float(scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_SphericalWarperGpu::opencv_from_extern(ret) };
@@ -7743,8 +8912,9 @@ pub trait Detail_StereographicProjectorTrait: crate::stitching::Detail_Projector
 
 	#[inline]
 	fn map_forward(&mut self, x: f32, y: f32, u: &mut f32, v: &mut f32) -> Result<()> {
+		crate::core::validity::check("Detail_StereographicProjector", self.as_raw_mut_Detail_StereographicProjector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_StereographicProjector_mapForward_float_float_floatR_floatR(self.as_raw_mut_Detail_StereographicProjector(), x, y, u, v, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_StereographicProjector_mapForward_float_float_floatR_floatR(self.as_raw_mut_Detail_StereographicProjector(), x, y, u, v, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7752,8 +8922,9 @@ pub trait Detail_StereographicProjectorTrait: crate::stitching::Detail_Projector
 	
 	#[inline]
 	fn map_backward(&mut self, u: f32, v: f32, x: &mut f32, y: &mut f32) -> Result<()> {
+		crate::core::validity::check("Detail_StereographicProjector", self.as_raw_mut_Detail_StereographicProjector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_StereographicProjector_mapBackward_float_float_floatR_floatR(self.as_raw_mut_Detail_StereographicProjector(), u, v, x, y, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_StereographicProjector_mapBackward_float_float_floatR_floatR(self.as_raw_mut_Detail_StereographicProjector(), u, v, x, y, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7770,7 +8941,10 @@ opencv_type_boxed! { Detail_StereographicProjector }
 impl Drop for Detail_StereographicProjector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_StereographicProjector_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_StereographicProjector_delete(self.as_raw_mut_Detail_StereographicProjector()) };
+		let ptr = self.as_raw_mut_Detail_StereographicProjector();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_StereographicProjector_delete(ptr) };
 	}
 }
 
@@ -7816,7 +8990,10 @@ opencv_type_boxed! { Detail_StereographicWarper }
 impl Drop for Detail_StereographicWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_StereographicWarper_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_StereographicWarper_delete(self.as_raw_mut_Detail_StereographicWarper()) };
+		let ptr = self.as_raw_mut_Detail_StereographicWarper();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_StereographicWarper_delete(ptr) };
 	}
 }
 
@@ -7842,7 +9019,7 @@ impl Detail_StereographicWarper {
 	#[inline]
 	pub fn new(scale: f32) -> Result<crate::stitching::Detail_StereographicWarper> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_StereographicWarper_StereographicWarper_float(scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_StereographicWarper_StereographicWarper_float(scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_StereographicWarper::opencv_from_extern(ret) };
@@ -7861,8 +9038,9 @@ pub trait Detail_TransverseMercatorProjectorTrait: crate::stitching::Detail_Proj
 
 	#[inline]
 	fn map_forward(&mut self, x: f32, y: f32, u: &mut f32, v: &mut f32) -> Result<()> {
+		crate::core::validity::check("Detail_TransverseMercatorProjector", self.as_raw_mut_Detail_TransverseMercatorProjector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_TransverseMercatorProjector_mapForward_float_float_floatR_floatR(self.as_raw_mut_Detail_TransverseMercatorProjector(), x, y, u, v, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_TransverseMercatorProjector_mapForward_float_float_floatR_floatR(self.as_raw_mut_Detail_TransverseMercatorProjector(), x, y, u, v, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7870,8 +9048,9 @@ pub trait Detail_TransverseMercatorProjectorTrait: crate::stitching::Detail_Proj
 	
 	#[inline]
 	fn map_backward(&mut self, u: f32, v: f32, x: &mut f32, y: &mut f32) -> Result<()> {
+		crate::core::validity::check("Detail_TransverseMercatorProjector", self.as_raw_mut_Detail_TransverseMercatorProjector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_TransverseMercatorProjector_mapBackward_float_float_floatR_floatR(self.as_raw_mut_Detail_TransverseMercatorProjector(), u, v, x, y, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_TransverseMercatorProjector_mapBackward_float_float_floatR_floatR(self.as_raw_mut_Detail_TransverseMercatorProjector(), u, v, x, y, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7888,7 +9067,10 @@ opencv_type_boxed! { Detail_TransverseMercatorProjector }
 impl Drop for Detail_TransverseMercatorProjector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_TransverseMercatorProjector_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_TransverseMercatorProjector_delete(self.as_raw_mut_Detail_TransverseMercatorProjector()) };
+		let ptr = self.as_raw_mut_Detail_TransverseMercatorProjector();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_TransverseMercatorProjector_delete(ptr) };
 	}
 }
 
@@ -7934,7 +9116,10 @@ opencv_type_boxed! { Detail_TransverseMercatorWarper }
 impl Drop for Detail_TransverseMercatorWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_TransverseMercatorWarper_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_TransverseMercatorWarper_delete(self.as_raw_mut_Detail_TransverseMercatorWarper()) };
+		let ptr = self.as_raw_mut_Detail_TransverseMercatorWarper();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_TransverseMercatorWarper_delete(ptr) };
 	}
 }
 
@@ -7960,7 +9145,7 @@ impl Detail_TransverseMercatorWarper {
 	#[inline]
 	pub fn new(scale: f32) -> Result<crate::stitching::Detail_TransverseMercatorWarper> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_TransverseMercatorWarper_TransverseMercatorWarper_float(scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_TransverseMercatorWarper_TransverseMercatorWarper_float(scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::stitching::Detail_TransverseMercatorWarper::opencv_from_extern(ret) };
@@ -7980,8 +9165,9 @@ pub trait Detail_VoronoiSeamFinderTrait: crate::stitching::Detail_PairwiseSeamFi
 
 	#[inline]
 	fn find(&mut self, src: &core::Vector<core::UMat>, corners: &core::Vector<core::Point>, masks: &mut core::Vector<core::UMat>) -> Result<()> {
+		crate::core::validity::check("Detail_VoronoiSeamFinder", self.as_raw_mut_Detail_VoronoiSeamFinder())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_VoronoiSeamFinder_find_const_vector_UMat_R_const_vector_Point_R_vector_UMat_R(self.as_raw_mut_Detail_VoronoiSeamFinder(), src.as_raw_VectorOfUMat(), corners.as_raw_VectorOfPoint(), masks.as_raw_mut_VectorOfUMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_VoronoiSeamFinder_find_const_vector_UMat_R_const_vector_Point_R_vector_UMat_R(self.as_raw_mut_Detail_VoronoiSeamFinder(), src.as_raw_VectorOfUMat(), corners.as_raw_VectorOfPoint(), masks.as_raw_mut_VectorOfUMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -7989,8 +9175,9 @@ pub trait Detail_VoronoiSeamFinderTrait: crate::stitching::Detail_PairwiseSeamFi
 	
 	#[inline]
 	fn find_1(&mut self, size: &core::Vector<core::Size>, corners: &core::Vector<core::Point>, masks: &mut core::Vector<core::UMat>) -> Result<()> {
+		crate::core::validity::check("Detail_VoronoiSeamFinder", self.as_raw_mut_Detail_VoronoiSeamFinder())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_detail_VoronoiSeamFinder_find_const_vector_Size_R_const_vector_Point_R_vector_UMat_R(self.as_raw_mut_Detail_VoronoiSeamFinder(), size.as_raw_VectorOfSize(), corners.as_raw_VectorOfPoint(), masks.as_raw_mut_VectorOfUMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_detail_VoronoiSeamFinder_find_const_vector_Size_R_const_vector_Point_R_vector_UMat_R(self.as_raw_mut_Detail_VoronoiSeamFinder(), size.as_raw_VectorOfSize(), corners.as_raw_VectorOfPoint(), masks.as_raw_mut_VectorOfUMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8008,7 +9195,10 @@ opencv_type_boxed! { Detail_VoronoiSeamFinder }
 impl Drop for Detail_VoronoiSeamFinder {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_VoronoiSeamFinder_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_VoronoiSeamFinder_delete(self.as_raw_mut_Detail_VoronoiSeamFinder()) };
+		let ptr = self.as_raw_mut_Detail_VoronoiSeamFinder();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Detail_VoronoiSeamFinder_delete(ptr) };
 	}
 }
 
@@ -8040,3 +9230,4 @@ impl crate::stitching::Detail_VoronoiSeamFinderTrait for Detail_VoronoiSeamFinde
 
 impl Detail_VoronoiSeamFinder {
 }
+pub use crate::manual::stitching::*;
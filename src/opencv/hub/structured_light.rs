@@ -23,7 +23,7 @@
 //! 
 //! In this module a time-multiplexing coding strategy based on Gray encoding is implemented following the
 //! (stereo) approach described in 3DUNDERWORLD algorithm [UNDERWORLD](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_UNDERWORLD) .
-//! For more details, see @ref tutorial_structured_light.
+//! For more details, see tutorial_structured_light.
 use crate::{mod_prelude::*, core, sys, types};
 pub mod prelude {
 	pub use { super::StructuredLightPatternConst, super::StructuredLightPattern, super::GrayCodePattern_ParamsTraitConst, super::GrayCodePattern_ParamsTrait, super::GrayCodePatternConst, super::GrayCodePattern, super::SinusoidalPattern_ParamsTraitConst, super::SinusoidalPattern_ParamsTrait, super::SinusoidalPatternConst, super::SinusoidalPattern };
@@ -56,8 +56,9 @@ pub trait GrayCodePatternConst: crate::structured_light::StructuredLightPatternC
 	/// The number of pattern images needed for the graycode pattern.
 	#[inline]
 	fn get_number_of_pattern_images(&self) -> Result<size_t> {
+		crate::core::validity::check("GrayCodePattern", self.as_raw_GrayCodePattern())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_structured_light_GrayCodePattern_getNumberOfPatternImages_const(self.as_raw_GrayCodePattern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_structured_light_GrayCodePattern_getNumberOfPatternImages_const(self.as_raw_GrayCodePattern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -74,10 +75,11 @@ pub trait GrayCodePatternConst: crate::structured_light::StructuredLightPatternC
 	/// * whiteImage: The generated all-white CV_8U image, at projector's resolution.
 	#[inline]
 	fn get_images_for_shadow_masks(&self, black_image: &mut dyn core::ToInputOutputArray, white_image: &mut dyn core::ToInputOutputArray) -> Result<()> {
+		crate::core::validity::check("GrayCodePattern", self.as_raw_GrayCodePattern())?;
 		input_output_array_arg!(black_image);
 		input_output_array_arg!(white_image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_structured_light_GrayCodePattern_getImagesForShadowMasks_const_const__InputOutputArrayR_const__InputOutputArrayR(self.as_raw_GrayCodePattern(), black_image.as_raw__InputOutputArray(), white_image.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_structured_light_GrayCodePattern_getImagesForShadowMasks_const_const__InputOutputArrayR_const__InputOutputArrayR(self.as_raw_GrayCodePattern(), black_image.as_raw__InputOutputArray(), white_image.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -95,9 +97,10 @@ pub trait GrayCodePatternConst: crate::structured_light::StructuredLightPatternC
 	/// * projPix: Projector's pixel corresponding to the camera's pixel: projPix.x and projPix.y are the image coordinates of the projector's pixel corresponding to the pixel being decoded in a camera.
 	#[inline]
 	fn get_proj_pixel(&self, pattern_images: &dyn core::ToInputArray, x: i32, y: i32, proj_pix: &mut core::Point) -> Result<bool> {
+		crate::core::validity::check("GrayCodePattern", self.as_raw_GrayCodePattern())?;
 		input_array_arg!(pattern_images);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_structured_light_GrayCodePattern_getProjPixel_const_const__InputArrayR_int_int_PointR(self.as_raw_GrayCodePattern(), pattern_images.as_raw__InputArray(), x, y, proj_pix, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_structured_light_GrayCodePattern_getProjPixel_const_const__InputArrayR_int_int_PointR(self.as_raw_GrayCodePattern(), pattern_images.as_raw__InputArray(), x, y, proj_pix, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -116,8 +119,9 @@ pub trait GrayCodePattern: crate::structured_light::GrayCodePatternConst + crate
 	/// * value: The desired white threshold value.
 	#[inline]
 	fn set_white_threshold(&mut self, value: size_t) -> Result<()> {
+		crate::core::validity::check("GrayCodePattern", self.as_raw_mut_GrayCodePattern())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_structured_light_GrayCodePattern_setWhiteThreshold_size_t(self.as_raw_mut_GrayCodePattern(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_structured_light_GrayCodePattern_setWhiteThreshold_size_t(self.as_raw_mut_GrayCodePattern(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -131,8 +135,9 @@ pub trait GrayCodePattern: crate::structured_light::GrayCodePatternConst + crate
 	/// * value: The desired black threshold value.
 	#[inline]
 	fn set_black_threshold(&mut self, value: size_t) -> Result<()> {
+		crate::core::validity::check("GrayCodePattern", self.as_raw_mut_GrayCodePattern())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_structured_light_GrayCodePattern_setBlackThreshold_size_t(self.as_raw_mut_GrayCodePattern(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_structured_light_GrayCodePattern_setBlackThreshold_size_t(self.as_raw_mut_GrayCodePattern(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -150,7 +155,7 @@ impl dyn GrayCodePattern + '_ {
 	#[inline]
 	pub fn create(parameters: &crate::structured_light::GrayCodePattern_Params) -> Result<core::Ptr<dyn crate::structured_light::GrayCodePattern>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_structured_light_GrayCodePattern_create_const_ParamsR(parameters.as_raw_GrayCodePattern_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_structured_light_GrayCodePattern_create_const_ParamsR(parameters.as_raw_GrayCodePattern_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::structured_light::GrayCodePattern>::opencv_from_extern(ret) };
@@ -160,7 +165,7 @@ impl dyn GrayCodePattern + '_ {
 	#[inline]
 	pub fn create_1(width: i32, height: i32) -> Result<core::Ptr<dyn crate::structured_light::GrayCodePattern>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_structured_light_GrayCodePattern_create_int_int(width, height, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_structured_light_GrayCodePattern_create_int_int(width, height, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::structured_light::GrayCodePattern>::opencv_from_extern(ret) };
@@ -177,16 +182,34 @@ pub trait GrayCodePattern_ParamsTraitConst {
 
 	#[inline]
 	fn width(&self) -> i32 {
-		let ret = unsafe { sys::cv_structured_light_GrayCodePattern_Params_getPropWidth_const(self.as_raw_GrayCodePattern_Params()) };
+		crate::core::validity::debug_check("GrayCodePattern_Params", self.as_raw_GrayCodePattern_Params());
+		let ret = unsafe { ffi_call!(cv_structured_light_GrayCodePattern_Params_getPropWidth_const(self.as_raw_GrayCodePattern_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [width](GrayCodePattern_ParamsTraitConst::width), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_width(&self) -> Result<i32> {
+		crate::core::validity::check("GrayCodePattern_Params", self.as_raw_GrayCodePattern_Params())?;
+		Ok(GrayCodePattern_ParamsTraitConst::width(self))
+	}
+	
 	#[inline]
 	fn height(&self) -> i32 {
-		let ret = unsafe { sys::cv_structured_light_GrayCodePattern_Params_getPropHeight_const(self.as_raw_GrayCodePattern_Params()) };
+		crate::core::validity::debug_check("GrayCodePattern_Params", self.as_raw_GrayCodePattern_Params());
+		let ret = unsafe { ffi_call!(cv_structured_light_GrayCodePattern_Params_getPropHeight_const(self.as_raw_GrayCodePattern_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [height](GrayCodePattern_ParamsTraitConst::height), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_height(&self) -> Result<i32> {
+		crate::core::validity::check("GrayCodePattern_Params", self.as_raw_GrayCodePattern_Params())?;
+		Ok(GrayCodePattern_ParamsTraitConst::height(self))
+	}
+	
 }
 
 pub trait GrayCodePattern_ParamsTrait: crate::structured_light::GrayCodePattern_ParamsTraitConst {
@@ -194,13 +217,15 @@ pub trait GrayCodePattern_ParamsTrait: crate::structured_light::GrayCodePattern_
 
 	#[inline]
 	fn set_width(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_structured_light_GrayCodePattern_Params_setPropWidth_int(self.as_raw_mut_GrayCodePattern_Params(), val) };
+		crate::core::validity::debug_check("GrayCodePattern_Params", self.as_raw_mut_GrayCodePattern_Params());
+		let ret = unsafe { ffi_call!(cv_structured_light_GrayCodePattern_Params_setPropWidth_int(self.as_raw_mut_GrayCodePattern_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_height(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_structured_light_GrayCodePattern_Params_setPropHeight_int(self.as_raw_mut_GrayCodePattern_Params(), val) };
+		crate::core::validity::debug_check("GrayCodePattern_Params", self.as_raw_mut_GrayCodePattern_Params());
+		let ret = unsafe { ffi_call!(cv_structured_light_GrayCodePattern_Params_setPropHeight_int(self.as_raw_mut_GrayCodePattern_Params(), val)) };
 		ret
 	}
 	
@@ -219,7 +244,10 @@ opencv_type_boxed! { GrayCodePattern_Params }
 impl Drop for GrayCodePattern_Params {
 	fn drop(&mut self) {
 		extern "C" { fn cv_GrayCodePattern_Params_delete(instance: *mut c_void); }
-		unsafe { cv_GrayCodePattern_Params_delete(self.as_raw_mut_GrayCodePattern_Params()) };
+		let ptr = self.as_raw_mut_GrayCodePattern_Params();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_GrayCodePattern_Params_delete(ptr) };
 	}
 }
 
@@ -237,7 +265,7 @@ impl GrayCodePattern_Params {
 	#[inline]
 	pub fn default() -> Result<crate::structured_light::GrayCodePattern_Params> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_structured_light_GrayCodePattern_Params_Params(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_structured_light_GrayCodePattern_Params_Params(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::structured_light::GrayCodePattern_Params::opencv_from_extern(ret) };
@@ -270,12 +298,13 @@ pub trait SinusoidalPattern: crate::structured_light::SinusoidalPatternConst + c
 	/// * fundamental: noArray()
 	#[inline]
 	fn compute_phase_map(&mut self, pattern_images: &dyn core::ToInputArray, wrapped_phase_map: &mut dyn core::ToOutputArray, shadow_mask: &mut dyn core::ToOutputArray, fundamental: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("SinusoidalPattern", self.as_raw_mut_SinusoidalPattern())?;
 		input_array_arg!(pattern_images);
 		output_array_arg!(wrapped_phase_map);
 		output_array_arg!(shadow_mask);
 		input_array_arg!(fundamental);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_structured_light_SinusoidalPattern_computePhaseMap_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__InputArrayR(self.as_raw_mut_SinusoidalPattern(), pattern_images.as_raw__InputArray(), wrapped_phase_map.as_raw__OutputArray(), shadow_mask.as_raw__OutputArray(), fundamental.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_structured_light_SinusoidalPattern_computePhaseMap_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__InputArrayR(self.as_raw_mut_SinusoidalPattern(), pattern_images.as_raw__InputArray(), wrapped_phase_map.as_raw__OutputArray(), shadow_mask.as_raw__OutputArray(), fundamental.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -292,11 +321,12 @@ pub trait SinusoidalPattern: crate::structured_light::SinusoidalPatternConst + c
 	/// * shadow_mask: noArray()
 	#[inline]
 	fn unwrap_phase_map(&mut self, wrapped_phase_map: &dyn core::ToInputArray, unwrapped_phase_map: &mut dyn core::ToOutputArray, cam_size: core::Size, shadow_mask: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("SinusoidalPattern", self.as_raw_mut_SinusoidalPattern())?;
 		input_array_arg!(wrapped_phase_map);
 		output_array_arg!(unwrapped_phase_map);
 		input_array_arg!(shadow_mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_structured_light_SinusoidalPattern_unwrapPhaseMap_const__InputArrayR_const__OutputArrayR_Size_const__InputArrayR(self.as_raw_mut_SinusoidalPattern(), wrapped_phase_map.as_raw__InputArray(), unwrapped_phase_map.as_raw__OutputArray(), cam_size.opencv_as_extern(), shadow_mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_structured_light_SinusoidalPattern_unwrapPhaseMap_const__InputArrayR_const__OutputArrayR_Size_const__InputArrayR(self.as_raw_mut_SinusoidalPattern(), wrapped_phase_map.as_raw__InputArray(), unwrapped_phase_map.as_raw__OutputArray(), cam_size.opencv_as_extern(), shadow_mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -309,11 +339,12 @@ pub trait SinusoidalPattern: crate::structured_light::SinusoidalPatternConst + c
 	/// * matches: Images used to display correspondences map.
 	#[inline]
 	fn find_pro_cam_matches(&mut self, proj_unwrapped_phase_map: &dyn core::ToInputArray, cam_unwrapped_phase_map: &dyn core::ToInputArray, matches: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("SinusoidalPattern", self.as_raw_mut_SinusoidalPattern())?;
 		input_array_arg!(proj_unwrapped_phase_map);
 		input_array_arg!(cam_unwrapped_phase_map);
 		output_array_arg!(matches);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_structured_light_SinusoidalPattern_findProCamMatches_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_SinusoidalPattern(), proj_unwrapped_phase_map.as_raw__InputArray(), cam_unwrapped_phase_map.as_raw__InputArray(), matches.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_structured_light_SinusoidalPattern_findProCamMatches_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_SinusoidalPattern(), proj_unwrapped_phase_map.as_raw__InputArray(), cam_unwrapped_phase_map.as_raw__InputArray(), matches.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -326,11 +357,12 @@ pub trait SinusoidalPattern: crate::structured_light::SinusoidalPatternConst + c
 	/// * shadowMask: Mask used to discard shadow regions.
 	#[inline]
 	fn compute_data_modulation_term(&mut self, pattern_images: &dyn core::ToInputArray, data_modulation_term: &mut dyn core::ToOutputArray, shadow_mask: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("SinusoidalPattern", self.as_raw_mut_SinusoidalPattern())?;
 		input_array_arg!(pattern_images);
 		output_array_arg!(data_modulation_term);
 		input_array_arg!(shadow_mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_structured_light_SinusoidalPattern_computeDataModulationTerm_const__InputArrayR_const__OutputArrayR_const__InputArrayR(self.as_raw_mut_SinusoidalPattern(), pattern_images.as_raw__InputArray(), data_modulation_term.as_raw__OutputArray(), shadow_mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_structured_light_SinusoidalPattern_computeDataModulationTerm_const__InputArrayR_const__OutputArrayR_const__InputArrayR(self.as_raw_mut_SinusoidalPattern(), pattern_images.as_raw__InputArray(), data_modulation_term.as_raw__OutputArray(), shadow_mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -348,7 +380,7 @@ impl dyn SinusoidalPattern + '_ {
 	#[inline]
 	pub fn create(mut parameters: core::Ptr<crate::structured_light::SinusoidalPattern_Params>) -> Result<core::Ptr<dyn crate::structured_light::SinusoidalPattern>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_structured_light_SinusoidalPattern_create_Ptr_Params_(parameters.as_raw_mut_PtrOfSinusoidalPattern_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_structured_light_SinusoidalPattern_create_Ptr_Params_(parameters.as_raw_mut_PtrOfSinusoidalPattern_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::structured_light::SinusoidalPattern>::opencv_from_extern(ret) };
@@ -371,59 +403,140 @@ pub trait SinusoidalPattern_ParamsTraitConst {
 
 	#[inline]
 	fn width(&self) -> i32 {
-		let ret = unsafe { sys::cv_structured_light_SinusoidalPattern_Params_getPropWidth_const(self.as_raw_SinusoidalPattern_Params()) };
+		crate::core::validity::debug_check("SinusoidalPattern_Params", self.as_raw_SinusoidalPattern_Params());
+		let ret = unsafe { ffi_call!(cv_structured_light_SinusoidalPattern_Params_getPropWidth_const(self.as_raw_SinusoidalPattern_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [width](SinusoidalPattern_ParamsTraitConst::width), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_width(&self) -> Result<i32> {
+		crate::core::validity::check("SinusoidalPattern_Params", self.as_raw_SinusoidalPattern_Params())?;
+		Ok(SinusoidalPattern_ParamsTraitConst::width(self))
+	}
+	
 	#[inline]
 	fn height(&self) -> i32 {
-		let ret = unsafe { sys::cv_structured_light_SinusoidalPattern_Params_getPropHeight_const(self.as_raw_SinusoidalPattern_Params()) };
+		crate::core::validity::debug_check("SinusoidalPattern_Params", self.as_raw_SinusoidalPattern_Params());
+		let ret = unsafe { ffi_call!(cv_structured_light_SinusoidalPattern_Params_getPropHeight_const(self.as_raw_SinusoidalPattern_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [height](SinusoidalPattern_ParamsTraitConst::height), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_height(&self) -> Result<i32> {
+		crate::core::validity::check("SinusoidalPattern_Params", self.as_raw_SinusoidalPattern_Params())?;
+		Ok(SinusoidalPattern_ParamsTraitConst::height(self))
+	}
+	
 	#[inline]
 	fn nbr_of_periods(&self) -> i32 {
-		let ret = unsafe { sys::cv_structured_light_SinusoidalPattern_Params_getPropNbrOfPeriods_const(self.as_raw_SinusoidalPattern_Params()) };
+		crate::core::validity::debug_check("SinusoidalPattern_Params", self.as_raw_SinusoidalPattern_Params());
+		let ret = unsafe { ffi_call!(cv_structured_light_SinusoidalPattern_Params_getPropNbrOfPeriods_const(self.as_raw_SinusoidalPattern_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [nbr_of_periods](SinusoidalPattern_ParamsTraitConst::nbr_of_periods), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_nbr_of_periods(&self) -> Result<i32> {
+		crate::core::validity::check("SinusoidalPattern_Params", self.as_raw_SinusoidalPattern_Params())?;
+		Ok(SinusoidalPattern_ParamsTraitConst::nbr_of_periods(self))
+	}
+	
 	#[inline]
 	fn shift_value(&self) -> f32 {
-		let ret = unsafe { sys::cv_structured_light_SinusoidalPattern_Params_getPropShiftValue_const(self.as_raw_SinusoidalPattern_Params()) };
+		crate::core::validity::debug_check("SinusoidalPattern_Params", self.as_raw_SinusoidalPattern_Params());
+		let ret = unsafe { ffi_call!(cv_structured_light_SinusoidalPattern_Params_getPropShiftValue_const(self.as_raw_SinusoidalPattern_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [shift_value](SinusoidalPattern_ParamsTraitConst::shift_value), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_shift_value(&self) -> Result<f32> {
+		crate::core::validity::check("SinusoidalPattern_Params", self.as_raw_SinusoidalPattern_Params())?;
+		Ok(SinusoidalPattern_ParamsTraitConst::shift_value(self))
+	}
+	
 	#[inline]
 	fn method_id(&self) -> i32 {
-		let ret = unsafe { sys::cv_structured_light_SinusoidalPattern_Params_getPropMethodId_const(self.as_raw_SinusoidalPattern_Params()) };
+		crate::core::validity::debug_check("SinusoidalPattern_Params", self.as_raw_SinusoidalPattern_Params());
+		let ret = unsafe { ffi_call!(cv_structured_light_SinusoidalPattern_Params_getPropMethodId_const(self.as_raw_SinusoidalPattern_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [method_id](SinusoidalPattern_ParamsTraitConst::method_id), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_method_id(&self) -> Result<i32> {
+		crate::core::validity::check("SinusoidalPattern_Params", self.as_raw_SinusoidalPattern_Params())?;
+		Ok(SinusoidalPattern_ParamsTraitConst::method_id(self))
+	}
+	
 	#[inline]
 	fn nbr_of_pixels_between_markers(&self) -> i32 {
-		let ret = unsafe { sys::cv_structured_light_SinusoidalPattern_Params_getPropNbrOfPixelsBetweenMarkers_const(self.as_raw_SinusoidalPattern_Params()) };
+		crate::core::validity::debug_check("SinusoidalPattern_Params", self.as_raw_SinusoidalPattern_Params());
+		let ret = unsafe { ffi_call!(cv_structured_light_SinusoidalPattern_Params_getPropNbrOfPixelsBetweenMarkers_const(self.as_raw_SinusoidalPattern_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [nbr_of_pixels_between_markers](SinusoidalPattern_ParamsTraitConst::nbr_of_pixels_between_markers), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_nbr_of_pixels_between_markers(&self) -> Result<i32> {
+		crate::core::validity::check("SinusoidalPattern_Params", self.as_raw_SinusoidalPattern_Params())?;
+		Ok(SinusoidalPattern_ParamsTraitConst::nbr_of_pixels_between_markers(self))
+	}
+	
 	#[inline]
 	fn horizontal(&self) -> bool {
-		let ret = unsafe { sys::cv_structured_light_SinusoidalPattern_Params_getPropHorizontal_const(self.as_raw_SinusoidalPattern_Params()) };
+		crate::core::validity::debug_check("SinusoidalPattern_Params", self.as_raw_SinusoidalPattern_Params());
+		let ret = unsafe { ffi_call!(cv_structured_light_SinusoidalPattern_Params_getPropHorizontal_const(self.as_raw_SinusoidalPattern_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [horizontal](SinusoidalPattern_ParamsTraitConst::horizontal), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_horizontal(&self) -> Result<bool> {
+		crate::core::validity::check("SinusoidalPattern_Params", self.as_raw_SinusoidalPattern_Params())?;
+		Ok(SinusoidalPattern_ParamsTraitConst::horizontal(self))
+	}
+	
 	#[inline]
 	fn set_markers(&self) -> bool {
-		let ret = unsafe { sys::cv_structured_light_SinusoidalPattern_Params_getPropSetMarkers_const(self.as_raw_SinusoidalPattern_Params()) };
+		crate::core::validity::debug_check("SinusoidalPattern_Params", self.as_raw_SinusoidalPattern_Params());
+		let ret = unsafe { ffi_call!(cv_structured_light_SinusoidalPattern_Params_getPropSetMarkers_const(self.as_raw_SinusoidalPattern_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [set_markers](SinusoidalPattern_ParamsTraitConst::set_markers), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_set_markers(&self) -> Result<bool> {
+		crate::core::validity::check("SinusoidalPattern_Params", self.as_raw_SinusoidalPattern_Params())?;
+		Ok(SinusoidalPattern_ParamsTraitConst::set_markers(self))
+	}
+	
 	#[inline]
 	fn markers_location(&self) -> core::Vector<core::Point2f> {
-		let ret = unsafe { sys::cv_structured_light_SinusoidalPattern_Params_getPropMarkersLocation_const(self.as_raw_SinusoidalPattern_Params()) };
+		crate::core::validity::debug_check("SinusoidalPattern_Params", self.as_raw_SinusoidalPattern_Params());
+		let ret = unsafe { ffi_call!(cv_structured_light_SinusoidalPattern_Params_getPropMarkersLocation_const(self.as_raw_SinusoidalPattern_Params())) };
 		let ret = unsafe { core::Vector::<core::Point2f>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [markers_location](SinusoidalPattern_ParamsTraitConst::markers_location), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_markers_location(&self) -> Result<core::Vector<core::Point2f>> {
+		crate::core::validity::check("SinusoidalPattern_Params", self.as_raw_SinusoidalPattern_Params())?;
+		Ok(SinusoidalPattern_ParamsTraitConst::markers_location(self))
+	}
+	
 }
 
 pub trait SinusoidalPattern_ParamsTrait: crate::structured_light::SinusoidalPattern_ParamsTraitConst {
@@ -431,55 +544,64 @@ pub trait SinusoidalPattern_ParamsTrait: crate::structured_light::SinusoidalPatt
 
 	#[inline]
 	fn set_width(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_structured_light_SinusoidalPattern_Params_setPropWidth_int(self.as_raw_mut_SinusoidalPattern_Params(), val) };
+		crate::core::validity::debug_check("SinusoidalPattern_Params", self.as_raw_mut_SinusoidalPattern_Params());
+		let ret = unsafe { ffi_call!(cv_structured_light_SinusoidalPattern_Params_setPropWidth_int(self.as_raw_mut_SinusoidalPattern_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_height(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_structured_light_SinusoidalPattern_Params_setPropHeight_int(self.as_raw_mut_SinusoidalPattern_Params(), val) };
+		crate::core::validity::debug_check("SinusoidalPattern_Params", self.as_raw_mut_SinusoidalPattern_Params());
+		let ret = unsafe { ffi_call!(cv_structured_light_SinusoidalPattern_Params_setPropHeight_int(self.as_raw_mut_SinusoidalPattern_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_nbr_of_periods(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_structured_light_SinusoidalPattern_Params_setPropNbrOfPeriods_int(self.as_raw_mut_SinusoidalPattern_Params(), val) };
+		crate::core::validity::debug_check("SinusoidalPattern_Params", self.as_raw_mut_SinusoidalPattern_Params());
+		let ret = unsafe { ffi_call!(cv_structured_light_SinusoidalPattern_Params_setPropNbrOfPeriods_int(self.as_raw_mut_SinusoidalPattern_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_shift_value(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_structured_light_SinusoidalPattern_Params_setPropShiftValue_float(self.as_raw_mut_SinusoidalPattern_Params(), val) };
+		crate::core::validity::debug_check("SinusoidalPattern_Params", self.as_raw_mut_SinusoidalPattern_Params());
+		let ret = unsafe { ffi_call!(cv_structured_light_SinusoidalPattern_Params_setPropShiftValue_float(self.as_raw_mut_SinusoidalPattern_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_method_id(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_structured_light_SinusoidalPattern_Params_setPropMethodId_int(self.as_raw_mut_SinusoidalPattern_Params(), val) };
+		crate::core::validity::debug_check("SinusoidalPattern_Params", self.as_raw_mut_SinusoidalPattern_Params());
+		let ret = unsafe { ffi_call!(cv_structured_light_SinusoidalPattern_Params_setPropMethodId_int(self.as_raw_mut_SinusoidalPattern_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_nbr_of_pixels_between_markers(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_structured_light_SinusoidalPattern_Params_setPropNbrOfPixelsBetweenMarkers_int(self.as_raw_mut_SinusoidalPattern_Params(), val) };
+		crate::core::validity::debug_check("SinusoidalPattern_Params", self.as_raw_mut_SinusoidalPattern_Params());
+		let ret = unsafe { ffi_call!(cv_structured_light_SinusoidalPattern_Params_setPropNbrOfPixelsBetweenMarkers_int(self.as_raw_mut_SinusoidalPattern_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_horizontal(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_structured_light_SinusoidalPattern_Params_setPropHorizontal_bool(self.as_raw_mut_SinusoidalPattern_Params(), val) };
+		crate::core::validity::debug_check("SinusoidalPattern_Params", self.as_raw_mut_SinusoidalPattern_Params());
+		let ret = unsafe { ffi_call!(cv_structured_light_SinusoidalPattern_Params_setPropHorizontal_bool(self.as_raw_mut_SinusoidalPattern_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_set_markers(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_structured_light_SinusoidalPattern_Params_setPropSetMarkers_bool(self.as_raw_mut_SinusoidalPattern_Params(), val) };
+		crate::core::validity::debug_check("SinusoidalPattern_Params", self.as_raw_mut_SinusoidalPattern_Params());
+		let ret = unsafe { ffi_call!(cv_structured_light_SinusoidalPattern_Params_setPropSetMarkers_bool(self.as_raw_mut_SinusoidalPattern_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_markers_location(&mut self, mut val: core::Vector<core::Point2f>) {
-		let ret = unsafe { sys::cv_structured_light_SinusoidalPattern_Params_setPropMarkersLocation_vector_Point2f_(self.as_raw_mut_SinusoidalPattern_Params(), val.as_raw_mut_VectorOfPoint2f()) };
+		crate::core::validity::debug_check("SinusoidalPattern_Params", self.as_raw_mut_SinusoidalPattern_Params());
+		let ret = unsafe { ffi_call!(cv_structured_light_SinusoidalPattern_Params_setPropMarkersLocation_vector_Point2f_(self.as_raw_mut_SinusoidalPattern_Params(), val.as_raw_mut_VectorOfPoint2f())) };
 		ret
 	}
 	
@@ -504,7 +626,10 @@ opencv_type_boxed! { SinusoidalPattern_Params }
 impl Drop for SinusoidalPattern_Params {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SinusoidalPattern_Params_delete(instance: *mut c_void); }
-		unsafe { cv_SinusoidalPattern_Params_delete(self.as_raw_mut_SinusoidalPattern_Params()) };
+		let ptr = self.as_raw_mut_SinusoidalPattern_Params();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_SinusoidalPattern_Params_delete(ptr) };
 	}
 }
 
@@ -522,7 +647,7 @@ impl SinusoidalPattern_Params {
 	#[inline]
 	pub fn default() -> Result<crate::structured_light::SinusoidalPattern_Params> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_structured_light_SinusoidalPattern_Params_Params(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_structured_light_SinusoidalPattern_Params_Params(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::structured_light::SinusoidalPattern_Params::opencv_from_extern(ret) };
@@ -552,11 +677,12 @@ pub trait StructuredLightPatternConst: core::AlgorithmTraitConst {
 	/// * flags: DECODE_3D_UNDERWORLD
 	#[inline]
 	fn decode(&self, pattern_images: &core::Vector<core::Vector<core::Mat>>, disparity_map: &mut dyn core::ToOutputArray, black_images: &dyn core::ToInputArray, white_images: &dyn core::ToInputArray, flags: i32) -> Result<bool> {
+		crate::core::validity::check("StructuredLightPattern", self.as_raw_StructuredLightPattern())?;
 		output_array_arg!(disparity_map);
 		input_array_arg!(black_images);
 		input_array_arg!(white_images);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_structured_light_StructuredLightPattern_decode_const_const_vector_vector_Mat__R_const__OutputArrayR_const__InputArrayR_const__InputArrayR_int(self.as_raw_StructuredLightPattern(), pattern_images.as_raw_VectorOfVectorOfMat(), disparity_map.as_raw__OutputArray(), black_images.as_raw__InputArray(), white_images.as_raw__InputArray(), flags, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_structured_light_StructuredLightPattern_decode_const_const_vector_vector_Mat__R_const__OutputArrayR_const__InputArrayR_const__InputArrayR_int(self.as_raw_StructuredLightPattern(), pattern_images.as_raw_VectorOfVectorOfMat(), disparity_map.as_raw__OutputArray(), black_images.as_raw__InputArray(), white_images.as_raw__InputArray(), flags, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -564,6 +690,49 @@ pub trait StructuredLightPatternConst: core::AlgorithmTraitConst {
 	
 }
 
+/// Builder for `StructuredLightPatternConst::decode()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct StructuredLightPattern_DecodeBuilder<'a, T: ?Sized + StructuredLightPatternConst> {
+	instance: &'a T,
+	pattern_images: &'a core::Vector<core::Vector<core::Mat>>,
+	disparity_map: &'a mut dyn core::ToOutputArray,
+	black_images: Option<&'a dyn core::ToInputArray>,
+	white_images: Option<&'a dyn core::ToInputArray>,
+	flags: i32,
+}
+
+impl<'a, T: ?Sized + StructuredLightPatternConst> StructuredLightPattern_DecodeBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a T, pattern_images: &'a core::Vector<core::Vector<core::Mat>>, disparity_map: &'a mut dyn core::ToOutputArray, flags: i32) -> Self {
+		Self { instance, pattern_images, disparity_map, black_images: None, white_images: None, flags }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_black_images(mut self, black_images: &'a dyn core::ToInputArray) -> Self {
+		self.black_images = Some(black_images);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_white_images(mut self, white_images: &'a dyn core::ToInputArray) -> Self {
+		self.white_images = Some(white_images);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<bool> {
+		let black_images_no_array = core::no_array();
+		let black_images: &dyn core::ToInputArray = match self.black_images { Some(black_images) => black_images, None => &black_images_no_array };
+		let white_images_no_array = core::no_array();
+		let white_images: &dyn core::ToInputArray = match self.white_images { Some(white_images) => white_images, None => &white_images_no_array };
+		StructuredLightPatternConst::decode(self.instance, self.pattern_images, self.disparity_map, black_images, white_images, self.flags)
+	}
+}
+
 pub trait StructuredLightPattern: core::AlgorithmTrait + crate::structured_light::StructuredLightPatternConst {
 	fn as_raw_mut_StructuredLightPattern(&mut self) -> *mut c_void;
 
@@ -573,9 +742,10 @@ pub trait StructuredLightPattern: core::AlgorithmTrait + crate::structured_light
 	/// * patternImages: The generated pattern: a vector<Mat>, in which each image is a CV_8U Mat at projector's resolution.
 	#[inline]
 	fn generate(&mut self, pattern_images: &mut dyn core::ToOutputArray) -> Result<bool> {
+		crate::core::validity::check("StructuredLightPattern", self.as_raw_mut_StructuredLightPattern())?;
 		output_array_arg!(pattern_images);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_structured_light_StructuredLightPattern_generate_const__OutputArrayR(self.as_raw_mut_StructuredLightPattern(), pattern_images.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_structured_light_StructuredLightPattern_generate_const__OutputArrayR(self.as_raw_mut_StructuredLightPattern(), pattern_images.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
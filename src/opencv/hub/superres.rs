@@ -22,7 +22,7 @@ pub mod prelude {
 #[inline]
 pub fn create_frame_source_camera(device_id: i32) -> Result<core::Ptr<dyn crate::superres::Superres_FrameSource>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_superres_createFrameSource_Camera_int(device_id, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_superres_createFrameSource_Camera_int(device_id, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::superres::Superres_FrameSource>::opencv_from_extern(ret) };
@@ -32,7 +32,7 @@ pub fn create_frame_source_camera(device_id: i32) -> Result<core::Ptr<dyn crate:
 #[inline]
 pub fn create_frame_source_empty() -> Result<core::Ptr<dyn crate::superres::Superres_FrameSource>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_superres_createFrameSource_Empty(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_superres_createFrameSource_Empty(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::superres::Superres_FrameSource>::opencv_from_extern(ret) };
@@ -43,7 +43,7 @@ pub fn create_frame_source_empty() -> Result<core::Ptr<dyn crate::superres::Supe
 pub fn create_frame_source_video_cuda(file_name: &str) -> Result<core::Ptr<dyn crate::superres::Superres_FrameSource>> {
 	extern_container_arg!(file_name);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_superres_createFrameSource_Video_CUDA_const_StringR(file_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_superres_createFrameSource_Video_CUDA_const_StringR(file_name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::superres::Superres_FrameSource>::opencv_from_extern(ret) };
@@ -54,7 +54,7 @@ pub fn create_frame_source_video_cuda(file_name: &str) -> Result<core::Ptr<dyn c
 pub fn create_frame_source_video(file_name: &str) -> Result<core::Ptr<dyn crate::superres::Superres_FrameSource>> {
 	extern_container_arg!(file_name);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_superres_createFrameSource_Video_const_StringR(file_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_superres_createFrameSource_Video_const_StringR(file_name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::superres::Superres_FrameSource>::opencv_from_extern(ret) };
@@ -64,7 +64,7 @@ pub fn create_frame_source_video(file_name: &str) -> Result<core::Ptr<dyn crate:
 #[inline]
 pub fn create_opt_flow_brox_cuda() -> Result<core::Ptr<dyn crate::superres::Superres_BroxOpticalFlow>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_superres_createOptFlow_Brox_CUDA(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_superres_createOptFlow_Brox_CUDA(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::superres::Superres_BroxOpticalFlow>::opencv_from_extern(ret) };
@@ -74,7 +74,7 @@ pub fn create_opt_flow_brox_cuda() -> Result<core::Ptr<dyn crate::superres::Supe
 #[inline]
 pub fn create_opt_flow_dual_tvl1() -> Result<core::Ptr<dyn crate::superres::Superres_DualTVL1OpticalFlow>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_superres_createOptFlow_DualTVL1(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_superres_createOptFlow_DualTVL1(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::superres::Superres_DualTVL1OpticalFlow>::opencv_from_extern(ret) };
@@ -84,7 +84,7 @@ pub fn create_opt_flow_dual_tvl1() -> Result<core::Ptr<dyn crate::superres::Supe
 #[inline]
 pub fn create_opt_flow_dual_tvl1_cuda() -> Result<core::Ptr<dyn crate::superres::Superres_DualTVL1OpticalFlow>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_superres_createOptFlow_DualTVL1_CUDA(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_superres_createOptFlow_DualTVL1_CUDA(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::superres::Superres_DualTVL1OpticalFlow>::opencv_from_extern(ret) };
@@ -94,7 +94,7 @@ pub fn create_opt_flow_dual_tvl1_cuda() -> Result<core::Ptr<dyn crate::superres:
 #[inline]
 pub fn create_opt_flow_farneback() -> Result<core::Ptr<dyn crate::superres::Superres_FarnebackOpticalFlow>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_superres_createOptFlow_Farneback(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_superres_createOptFlow_Farneback(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::superres::Superres_FarnebackOpticalFlow>::opencv_from_extern(ret) };
@@ -104,7 +104,7 @@ pub fn create_opt_flow_farneback() -> Result<core::Ptr<dyn crate::superres::Supe
 #[inline]
 pub fn create_opt_flow_farneback_cuda() -> Result<core::Ptr<dyn crate::superres::Superres_FarnebackOpticalFlow>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_superres_createOptFlow_Farneback_CUDA(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_superres_createOptFlow_Farneback_CUDA(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::superres::Superres_FarnebackOpticalFlow>::opencv_from_extern(ret) };
@@ -114,7 +114,7 @@ pub fn create_opt_flow_farneback_cuda() -> Result<core::Ptr<dyn crate::superres:
 #[inline]
 pub fn create_opt_flow_pyr_lk_cuda() -> Result<core::Ptr<dyn crate::superres::Superres_PyrLKOpticalFlow>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_superres_createOptFlow_PyrLK_CUDA(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_superres_createOptFlow_PyrLK_CUDA(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::superres::Superres_PyrLKOpticalFlow>::opencv_from_extern(ret) };
@@ -142,7 +142,7 @@ pub fn create_opt_flow_pyr_lk_cuda() -> Result<core::Ptr<dyn crate::superres::Su
 #[inline]
 pub fn create_super_resolution_btvl1() -> Result<core::Ptr<dyn crate::superres::Superres_SuperResolution>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_superres_createSuperResolution_BTVL1(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_superres_createSuperResolution_BTVL1(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::superres::Superres_SuperResolution>::opencv_from_extern(ret) };
@@ -152,7 +152,7 @@ pub fn create_super_resolution_btvl1() -> Result<core::Ptr<dyn crate::superres::
 #[inline]
 pub fn create_super_resolution_btvl1_cuda() -> Result<core::Ptr<dyn crate::superres::Superres_SuperResolution>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_superres_createSuperResolution_BTVL1_CUDA(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_superres_createSuperResolution_BTVL1_CUDA(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::superres::Superres_SuperResolution>::opencv_from_extern(ret) };
@@ -164,11 +164,12 @@ pub trait Superres_BroxOpticalFlowConst: crate::superres::Superres_DenseOpticalF
 
 	/// Flow smoothness
 	/// ## See also
-	/// setAlpha
+	/// `set_alpha`
 	#[inline]
 	fn get_alpha(&self) -> Result<f64> {
+		crate::core::validity::check("Superres_BroxOpticalFlow", self.as_raw_Superres_BroxOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_BroxOpticalFlow_getAlpha_const(self.as_raw_Superres_BroxOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_BroxOpticalFlow_getAlpha_const(self.as_raw_Superres_BroxOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -176,11 +177,12 @@ pub trait Superres_BroxOpticalFlowConst: crate::superres::Superres_DenseOpticalF
 	
 	/// Gradient constancy importance
 	/// ## See also
-	/// setGamma
+	/// `set_gamma`
 	#[inline]
 	fn get_gamma(&self) -> Result<f64> {
+		crate::core::validity::check("Superres_BroxOpticalFlow", self.as_raw_Superres_BroxOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_BroxOpticalFlow_getGamma_const(self.as_raw_Superres_BroxOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_BroxOpticalFlow_getGamma_const(self.as_raw_Superres_BroxOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -188,11 +190,12 @@ pub trait Superres_BroxOpticalFlowConst: crate::superres::Superres_DenseOpticalF
 	
 	/// Pyramid scale factor
 	/// ## See also
-	/// setScaleFactor
+	/// `set_scale_factor`
 	#[inline]
 	fn get_scale_factor(&self) -> Result<f64> {
+		crate::core::validity::check("Superres_BroxOpticalFlow", self.as_raw_Superres_BroxOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_BroxOpticalFlow_getScaleFactor_const(self.as_raw_Superres_BroxOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_BroxOpticalFlow_getScaleFactor_const(self.as_raw_Superres_BroxOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -200,11 +203,12 @@ pub trait Superres_BroxOpticalFlowConst: crate::superres::Superres_DenseOpticalF
 	
 	/// Number of lagged non-linearity iterations (inner loop)
 	/// ## See also
-	/// setInnerIterations
+	/// `set_inner_iterations`
 	#[inline]
 	fn get_inner_iterations(&self) -> Result<i32> {
+		crate::core::validity::check("Superres_BroxOpticalFlow", self.as_raw_Superres_BroxOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_BroxOpticalFlow_getInnerIterations_const(self.as_raw_Superres_BroxOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_BroxOpticalFlow_getInnerIterations_const(self.as_raw_Superres_BroxOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -212,11 +216,12 @@ pub trait Superres_BroxOpticalFlowConst: crate::superres::Superres_DenseOpticalF
 	
 	/// Number of warping iterations (number of pyramid levels)
 	/// ## See also
-	/// setOuterIterations
+	/// `set_outer_iterations`
 	#[inline]
 	fn get_outer_iterations(&self) -> Result<i32> {
+		crate::core::validity::check("Superres_BroxOpticalFlow", self.as_raw_Superres_BroxOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_BroxOpticalFlow_getOuterIterations_const(self.as_raw_Superres_BroxOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_BroxOpticalFlow_getOuterIterations_const(self.as_raw_Superres_BroxOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -224,11 +229,12 @@ pub trait Superres_BroxOpticalFlowConst: crate::superres::Superres_DenseOpticalF
 	
 	/// Number of linear system solver iterations
 	/// ## See also
-	/// setSolverIterations
+	/// `set_solver_iterations`
 	#[inline]
 	fn get_solver_iterations(&self) -> Result<i32> {
+		crate::core::validity::check("Superres_BroxOpticalFlow", self.as_raw_Superres_BroxOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_BroxOpticalFlow_getSolverIterations_const(self.as_raw_Superres_BroxOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_BroxOpticalFlow_getSolverIterations_const(self.as_raw_Superres_BroxOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -244,8 +250,9 @@ pub trait Superres_BroxOpticalFlow: crate::superres::Superres_BroxOpticalFlowCon
 	/// setAlpha getAlpha
 	#[inline]
 	fn set_alpha(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("Superres_BroxOpticalFlow", self.as_raw_mut_Superres_BroxOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_BroxOpticalFlow_setAlpha_double(self.as_raw_mut_Superres_BroxOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_BroxOpticalFlow_setAlpha_double(self.as_raw_mut_Superres_BroxOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -256,8 +263,9 @@ pub trait Superres_BroxOpticalFlow: crate::superres::Superres_BroxOpticalFlowCon
 	/// setGamma getGamma
 	#[inline]
 	fn set_gamma(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("Superres_BroxOpticalFlow", self.as_raw_mut_Superres_BroxOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_BroxOpticalFlow_setGamma_double(self.as_raw_mut_Superres_BroxOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_BroxOpticalFlow_setGamma_double(self.as_raw_mut_Superres_BroxOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -268,8 +276,9 @@ pub trait Superres_BroxOpticalFlow: crate::superres::Superres_BroxOpticalFlowCon
 	/// setScaleFactor getScaleFactor
 	#[inline]
 	fn set_scale_factor(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("Superres_BroxOpticalFlow", self.as_raw_mut_Superres_BroxOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_BroxOpticalFlow_setScaleFactor_double(self.as_raw_mut_Superres_BroxOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_BroxOpticalFlow_setScaleFactor_double(self.as_raw_mut_Superres_BroxOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -280,8 +289,9 @@ pub trait Superres_BroxOpticalFlow: crate::superres::Superres_BroxOpticalFlowCon
 	/// setInnerIterations getInnerIterations
 	#[inline]
 	fn set_inner_iterations(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("Superres_BroxOpticalFlow", self.as_raw_mut_Superres_BroxOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_BroxOpticalFlow_setInnerIterations_int(self.as_raw_mut_Superres_BroxOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_BroxOpticalFlow_setInnerIterations_int(self.as_raw_mut_Superres_BroxOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -292,8 +302,9 @@ pub trait Superres_BroxOpticalFlow: crate::superres::Superres_BroxOpticalFlowCon
 	/// setOuterIterations getOuterIterations
 	#[inline]
 	fn set_outer_iterations(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("Superres_BroxOpticalFlow", self.as_raw_mut_Superres_BroxOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_BroxOpticalFlow_setOuterIterations_int(self.as_raw_mut_Superres_BroxOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_BroxOpticalFlow_setOuterIterations_int(self.as_raw_mut_Superres_BroxOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -304,8 +315,9 @@ pub trait Superres_BroxOpticalFlow: crate::superres::Superres_BroxOpticalFlowCon
 	/// setSolverIterations getSolverIterations
 	#[inline]
 	fn set_solver_iterations(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("Superres_BroxOpticalFlow", self.as_raw_mut_Superres_BroxOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_BroxOpticalFlow_setSolverIterations_int(self.as_raw_mut_Superres_BroxOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_BroxOpticalFlow_setSolverIterations_int(self.as_raw_mut_Superres_BroxOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -325,12 +337,13 @@ pub trait Superres_DenseOpticalFlowExt: core::AlgorithmTrait + crate::superres::
 	/// * flow2: noArray()
 	#[inline]
 	fn calc(&mut self, frame0: &dyn core::ToInputArray, frame1: &dyn core::ToInputArray, flow1: &mut dyn core::ToOutputArray, flow2: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("Superres_DenseOpticalFlowExt", self.as_raw_mut_Superres_DenseOpticalFlowExt())?;
 		input_array_arg!(frame0);
 		input_array_arg!(frame1);
 		output_array_arg!(flow1);
 		output_array_arg!(flow2);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_DenseOpticalFlowExt_calc_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_Superres_DenseOpticalFlowExt(), frame0.as_raw__InputArray(), frame1.as_raw__InputArray(), flow1.as_raw__OutputArray(), flow2.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_DenseOpticalFlowExt_calc_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_Superres_DenseOpticalFlowExt(), frame0.as_raw__InputArray(), frame1.as_raw__InputArray(), flow1.as_raw__OutputArray(), flow2.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -338,8 +351,9 @@ pub trait Superres_DenseOpticalFlowExt: core::AlgorithmTrait + crate::superres::
 	
 	#[inline]
 	fn collect_garbage(&mut self) -> Result<()> {
+		crate::core::validity::check("Superres_DenseOpticalFlowExt", self.as_raw_mut_Superres_DenseOpticalFlowExt())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_DenseOpticalFlowExt_collectGarbage(self.as_raw_mut_Superres_DenseOpticalFlowExt(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_DenseOpticalFlowExt_collectGarbage(self.as_raw_mut_Superres_DenseOpticalFlowExt(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -351,88 +365,96 @@ pub trait Superres_DualTVL1OpticalFlowConst: crate::superres::Superres_DenseOpti
 	fn as_raw_Superres_DualTVL1OpticalFlow(&self) -> *const c_void;
 
 	/// ## See also
-	/// setTau
+	/// `set_tau`
 	#[inline]
 	fn get_tau(&self) -> Result<f64> {
+		crate::core::validity::check("Superres_DualTVL1OpticalFlow", self.as_raw_Superres_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_DualTVL1OpticalFlow_getTau_const(self.as_raw_Superres_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_DualTVL1OpticalFlow_getTau_const(self.as_raw_Superres_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setLambda
+	/// `set_lambda`
 	#[inline]
 	fn get_lambda(&self) -> Result<f64> {
+		crate::core::validity::check("Superres_DualTVL1OpticalFlow", self.as_raw_Superres_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_DualTVL1OpticalFlow_getLambda_const(self.as_raw_Superres_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_DualTVL1OpticalFlow_getLambda_const(self.as_raw_Superres_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setTheta
+	/// `set_theta`
 	#[inline]
 	fn get_theta(&self) -> Result<f64> {
+		crate::core::validity::check("Superres_DualTVL1OpticalFlow", self.as_raw_Superres_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_DualTVL1OpticalFlow_getTheta_const(self.as_raw_Superres_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_DualTVL1OpticalFlow_getTheta_const(self.as_raw_Superres_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setScalesNumber
+	/// `set_scales_number`
 	#[inline]
 	fn get_scales_number(&self) -> Result<i32> {
+		crate::core::validity::check("Superres_DualTVL1OpticalFlow", self.as_raw_Superres_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_DualTVL1OpticalFlow_getScalesNumber_const(self.as_raw_Superres_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_DualTVL1OpticalFlow_getScalesNumber_const(self.as_raw_Superres_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setWarpingsNumber
+	/// `set_warpings_number`
 	#[inline]
 	fn get_warpings_number(&self) -> Result<i32> {
+		crate::core::validity::check("Superres_DualTVL1OpticalFlow", self.as_raw_Superres_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_DualTVL1OpticalFlow_getWarpingsNumber_const(self.as_raw_Superres_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_DualTVL1OpticalFlow_getWarpingsNumber_const(self.as_raw_Superres_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setEpsilon
+	/// `set_epsilon`
 	#[inline]
 	fn get_epsilon(&self) -> Result<f64> {
+		crate::core::validity::check("Superres_DualTVL1OpticalFlow", self.as_raw_Superres_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_DualTVL1OpticalFlow_getEpsilon_const(self.as_raw_Superres_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_DualTVL1OpticalFlow_getEpsilon_const(self.as_raw_Superres_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setIterations
+	/// `set_iterations`
 	#[inline]
 	fn get_iterations(&self) -> Result<i32> {
+		crate::core::validity::check("Superres_DualTVL1OpticalFlow", self.as_raw_Superres_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_DualTVL1OpticalFlow_getIterations_const(self.as_raw_Superres_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_DualTVL1OpticalFlow_getIterations_const(self.as_raw_Superres_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setUseInitialFlow
+	/// `set_use_initial_flow`
 	#[inline]
 	fn get_use_initial_flow(&self) -> Result<bool> {
+		crate::core::validity::check("Superres_DualTVL1OpticalFlow", self.as_raw_Superres_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_DualTVL1OpticalFlow_getUseInitialFlow_const(self.as_raw_Superres_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_DualTVL1OpticalFlow_getUseInitialFlow_const(self.as_raw_Superres_DualTVL1OpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -447,8 +469,9 @@ pub trait Superres_DualTVL1OpticalFlow: crate::superres::Superres_DenseOpticalFl
 	/// setTau getTau
 	#[inline]
 	fn set_tau(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("Superres_DualTVL1OpticalFlow", self.as_raw_mut_Superres_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_DualTVL1OpticalFlow_setTau_double(self.as_raw_mut_Superres_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_DualTVL1OpticalFlow_setTau_double(self.as_raw_mut_Superres_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -458,8 +481,9 @@ pub trait Superres_DualTVL1OpticalFlow: crate::superres::Superres_DenseOpticalFl
 	/// setLambda getLambda
 	#[inline]
 	fn set_lambda(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("Superres_DualTVL1OpticalFlow", self.as_raw_mut_Superres_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_DualTVL1OpticalFlow_setLambda_double(self.as_raw_mut_Superres_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_DualTVL1OpticalFlow_setLambda_double(self.as_raw_mut_Superres_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -469,8 +493,9 @@ pub trait Superres_DualTVL1OpticalFlow: crate::superres::Superres_DenseOpticalFl
 	/// setTheta getTheta
 	#[inline]
 	fn set_theta(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("Superres_DualTVL1OpticalFlow", self.as_raw_mut_Superres_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_DualTVL1OpticalFlow_setTheta_double(self.as_raw_mut_Superres_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_DualTVL1OpticalFlow_setTheta_double(self.as_raw_mut_Superres_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -480,8 +505,9 @@ pub trait Superres_DualTVL1OpticalFlow: crate::superres::Superres_DenseOpticalFl
 	/// setScalesNumber getScalesNumber
 	#[inline]
 	fn set_scales_number(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("Superres_DualTVL1OpticalFlow", self.as_raw_mut_Superres_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_DualTVL1OpticalFlow_setScalesNumber_int(self.as_raw_mut_Superres_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_DualTVL1OpticalFlow_setScalesNumber_int(self.as_raw_mut_Superres_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -491,8 +517,9 @@ pub trait Superres_DualTVL1OpticalFlow: crate::superres::Superres_DenseOpticalFl
 	/// setWarpingsNumber getWarpingsNumber
 	#[inline]
 	fn set_warpings_number(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("Superres_DualTVL1OpticalFlow", self.as_raw_mut_Superres_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_DualTVL1OpticalFlow_setWarpingsNumber_int(self.as_raw_mut_Superres_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_DualTVL1OpticalFlow_setWarpingsNumber_int(self.as_raw_mut_Superres_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -502,8 +529,9 @@ pub trait Superres_DualTVL1OpticalFlow: crate::superres::Superres_DenseOpticalFl
 	/// setEpsilon getEpsilon
 	#[inline]
 	fn set_epsilon(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("Superres_DualTVL1OpticalFlow", self.as_raw_mut_Superres_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_DualTVL1OpticalFlow_setEpsilon_double(self.as_raw_mut_Superres_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_DualTVL1OpticalFlow_setEpsilon_double(self.as_raw_mut_Superres_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -513,8 +541,9 @@ pub trait Superres_DualTVL1OpticalFlow: crate::superres::Superres_DenseOpticalFl
 	/// setIterations getIterations
 	#[inline]
 	fn set_iterations(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("Superres_DualTVL1OpticalFlow", self.as_raw_mut_Superres_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_DualTVL1OpticalFlow_setIterations_int(self.as_raw_mut_Superres_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_DualTVL1OpticalFlow_setIterations_int(self.as_raw_mut_Superres_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -524,8 +553,9 @@ pub trait Superres_DualTVL1OpticalFlow: crate::superres::Superres_DenseOpticalFl
 	/// setUseInitialFlow getUseInitialFlow
 	#[inline]
 	fn set_use_initial_flow(&mut self, val: bool) -> Result<()> {
+		crate::core::validity::check("Superres_DualTVL1OpticalFlow", self.as_raw_mut_Superres_DualTVL1OpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_DualTVL1OpticalFlow_setUseInitialFlow_bool(self.as_raw_mut_Superres_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_DualTVL1OpticalFlow_setUseInitialFlow_bool(self.as_raw_mut_Superres_DualTVL1OpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -537,77 +567,84 @@ pub trait Superres_FarnebackOpticalFlowConst: crate::superres::Superres_DenseOpt
 	fn as_raw_Superres_FarnebackOpticalFlow(&self) -> *const c_void;
 
 	/// ## See also
-	/// setPyrScale
+	/// `set_pyr_scale`
 	#[inline]
 	fn get_pyr_scale(&self) -> Result<f64> {
+		crate::core::validity::check("Superres_FarnebackOpticalFlow", self.as_raw_Superres_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_FarnebackOpticalFlow_getPyrScale_const(self.as_raw_Superres_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_FarnebackOpticalFlow_getPyrScale_const(self.as_raw_Superres_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setLevelsNumber
+	/// `set_levels_number`
 	#[inline]
 	fn get_levels_number(&self) -> Result<i32> {
+		crate::core::validity::check("Superres_FarnebackOpticalFlow", self.as_raw_Superres_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_FarnebackOpticalFlow_getLevelsNumber_const(self.as_raw_Superres_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_FarnebackOpticalFlow_getLevelsNumber_const(self.as_raw_Superres_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setWindowSize
+	/// `set_window_size`
 	#[inline]
 	fn get_window_size(&self) -> Result<i32> {
+		crate::core::validity::check("Superres_FarnebackOpticalFlow", self.as_raw_Superres_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_FarnebackOpticalFlow_getWindowSize_const(self.as_raw_Superres_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_FarnebackOpticalFlow_getWindowSize_const(self.as_raw_Superres_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setIterations
+	/// `set_iterations`
 	#[inline]
 	fn get_iterations(&self) -> Result<i32> {
+		crate::core::validity::check("Superres_FarnebackOpticalFlow", self.as_raw_Superres_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_FarnebackOpticalFlow_getIterations_const(self.as_raw_Superres_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_FarnebackOpticalFlow_getIterations_const(self.as_raw_Superres_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setPolyN
+	/// `set_poly_n`
 	#[inline]
 	fn get_poly_n(&self) -> Result<i32> {
+		crate::core::validity::check("Superres_FarnebackOpticalFlow", self.as_raw_Superres_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_FarnebackOpticalFlow_getPolyN_const(self.as_raw_Superres_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_FarnebackOpticalFlow_getPolyN_const(self.as_raw_Superres_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setPolySigma
+	/// `set_poly_sigma`
 	#[inline]
 	fn get_poly_sigma(&self) -> Result<f64> {
+		crate::core::validity::check("Superres_FarnebackOpticalFlow", self.as_raw_Superres_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_FarnebackOpticalFlow_getPolySigma_const(self.as_raw_Superres_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_FarnebackOpticalFlow_getPolySigma_const(self.as_raw_Superres_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setFlags
+	/// [set_flags](crate::core::set_flags)
 	#[inline]
 	fn get_flags(&self) -> Result<i32> {
+		crate::core::validity::check("Superres_FarnebackOpticalFlow", self.as_raw_Superres_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_FarnebackOpticalFlow_getFlags_const(self.as_raw_Superres_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_FarnebackOpticalFlow_getFlags_const(self.as_raw_Superres_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -622,8 +659,9 @@ pub trait Superres_FarnebackOpticalFlow: crate::superres::Superres_DenseOpticalF
 	/// setPyrScale getPyrScale
 	#[inline]
 	fn set_pyr_scale(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("Superres_FarnebackOpticalFlow", self.as_raw_mut_Superres_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_FarnebackOpticalFlow_setPyrScale_double(self.as_raw_mut_Superres_FarnebackOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_FarnebackOpticalFlow_setPyrScale_double(self.as_raw_mut_Superres_FarnebackOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -633,8 +671,9 @@ pub trait Superres_FarnebackOpticalFlow: crate::superres::Superres_DenseOpticalF
 	/// setLevelsNumber getLevelsNumber
 	#[inline]
 	fn set_levels_number(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("Superres_FarnebackOpticalFlow", self.as_raw_mut_Superres_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_FarnebackOpticalFlow_setLevelsNumber_int(self.as_raw_mut_Superres_FarnebackOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_FarnebackOpticalFlow_setLevelsNumber_int(self.as_raw_mut_Superres_FarnebackOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -644,8 +683,9 @@ pub trait Superres_FarnebackOpticalFlow: crate::superres::Superres_DenseOpticalF
 	/// setWindowSize getWindowSize
 	#[inline]
 	fn set_window_size(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("Superres_FarnebackOpticalFlow", self.as_raw_mut_Superres_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_FarnebackOpticalFlow_setWindowSize_int(self.as_raw_mut_Superres_FarnebackOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_FarnebackOpticalFlow_setWindowSize_int(self.as_raw_mut_Superres_FarnebackOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -655,8 +695,9 @@ pub trait Superres_FarnebackOpticalFlow: crate::superres::Superres_DenseOpticalF
 	/// setIterations getIterations
 	#[inline]
 	fn set_iterations(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("Superres_FarnebackOpticalFlow", self.as_raw_mut_Superres_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_FarnebackOpticalFlow_setIterations_int(self.as_raw_mut_Superres_FarnebackOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_FarnebackOpticalFlow_setIterations_int(self.as_raw_mut_Superres_FarnebackOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -666,8 +707,9 @@ pub trait Superres_FarnebackOpticalFlow: crate::superres::Superres_DenseOpticalF
 	/// setPolyN getPolyN
 	#[inline]
 	fn set_poly_n(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("Superres_FarnebackOpticalFlow", self.as_raw_mut_Superres_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_FarnebackOpticalFlow_setPolyN_int(self.as_raw_mut_Superres_FarnebackOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_FarnebackOpticalFlow_setPolyN_int(self.as_raw_mut_Superres_FarnebackOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -677,8 +719,9 @@ pub trait Superres_FarnebackOpticalFlow: crate::superres::Superres_DenseOpticalF
 	/// setPolySigma getPolySigma
 	#[inline]
 	fn set_poly_sigma(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("Superres_FarnebackOpticalFlow", self.as_raw_mut_Superres_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_FarnebackOpticalFlow_setPolySigma_double(self.as_raw_mut_Superres_FarnebackOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_FarnebackOpticalFlow_setPolySigma_double(self.as_raw_mut_Superres_FarnebackOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -688,8 +731,9 @@ pub trait Superres_FarnebackOpticalFlow: crate::superres::Superres_DenseOpticalF
 	/// setFlags getFlags
 	#[inline]
 	fn set_flags(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("Superres_FarnebackOpticalFlow", self.as_raw_mut_Superres_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_FarnebackOpticalFlow_setFlags_int(self.as_raw_mut_Superres_FarnebackOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_FarnebackOpticalFlow_setFlags_int(self.as_raw_mut_Superres_FarnebackOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -707,9 +751,10 @@ pub trait Superres_FrameSource: crate::superres::Superres_FrameSourceConst {
 
 	#[inline]
 	fn next_frame(&mut self, frame: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("Superres_FrameSource", self.as_raw_mut_Superres_FrameSource())?;
 		output_array_arg!(frame);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_FrameSource_nextFrame_const__OutputArrayR(self.as_raw_mut_Superres_FrameSource(), frame.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_FrameSource_nextFrame_const__OutputArrayR(self.as_raw_mut_Superres_FrameSource(), frame.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -717,8 +762,9 @@ pub trait Superres_FrameSource: crate::superres::Superres_FrameSourceConst {
 	
 	#[inline]
 	fn reset(&mut self) -> Result<()> {
+		crate::core::validity::check("Superres_FrameSource", self.as_raw_mut_Superres_FrameSource())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_FrameSource_reset(self.as_raw_mut_Superres_FrameSource(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_FrameSource_reset(self.as_raw_mut_Superres_FrameSource(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -730,33 +776,36 @@ pub trait Superres_PyrLKOpticalFlowConst: crate::superres::Superres_DenseOptical
 	fn as_raw_Superres_PyrLKOpticalFlow(&self) -> *const c_void;
 
 	/// ## See also
-	/// setWindowSize
+	/// `set_window_size`
 	#[inline]
 	fn get_window_size(&self) -> Result<i32> {
+		crate::core::validity::check("Superres_PyrLKOpticalFlow", self.as_raw_Superres_PyrLKOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_PyrLKOpticalFlow_getWindowSize_const(self.as_raw_Superres_PyrLKOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_PyrLKOpticalFlow_getWindowSize_const(self.as_raw_Superres_PyrLKOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setMaxLevel
+	/// `set_max_level`
 	#[inline]
 	fn get_max_level(&self) -> Result<i32> {
+		crate::core::validity::check("Superres_PyrLKOpticalFlow", self.as_raw_Superres_PyrLKOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_PyrLKOpticalFlow_getMaxLevel_const(self.as_raw_Superres_PyrLKOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_PyrLKOpticalFlow_getMaxLevel_const(self.as_raw_Superres_PyrLKOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setIterations
+	/// `set_iterations`
 	#[inline]
 	fn get_iterations(&self) -> Result<i32> {
+		crate::core::validity::check("Superres_PyrLKOpticalFlow", self.as_raw_Superres_PyrLKOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_PyrLKOpticalFlow_getIterations_const(self.as_raw_Superres_PyrLKOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_PyrLKOpticalFlow_getIterations_const(self.as_raw_Superres_PyrLKOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -771,8 +820,9 @@ pub trait Superres_PyrLKOpticalFlow: crate::superres::Superres_DenseOpticalFlowE
 	/// setWindowSize getWindowSize
 	#[inline]
 	fn set_window_size(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("Superres_PyrLKOpticalFlow", self.as_raw_mut_Superres_PyrLKOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_PyrLKOpticalFlow_setWindowSize_int(self.as_raw_mut_Superres_PyrLKOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_PyrLKOpticalFlow_setWindowSize_int(self.as_raw_mut_Superres_PyrLKOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -782,8 +832,9 @@ pub trait Superres_PyrLKOpticalFlow: crate::superres::Superres_DenseOpticalFlowE
 	/// setMaxLevel getMaxLevel
 	#[inline]
 	fn set_max_level(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("Superres_PyrLKOpticalFlow", self.as_raw_mut_Superres_PyrLKOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_PyrLKOpticalFlow_setMaxLevel_int(self.as_raw_mut_Superres_PyrLKOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_PyrLKOpticalFlow_setMaxLevel_int(self.as_raw_mut_Superres_PyrLKOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -793,8 +844,9 @@ pub trait Superres_PyrLKOpticalFlow: crate::superres::Superres_DenseOpticalFlowE
 	/// setIterations getIterations
 	#[inline]
 	fn set_iterations(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("Superres_PyrLKOpticalFlow", self.as_raw_mut_Superres_PyrLKOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_PyrLKOpticalFlow_setIterations_int(self.as_raw_mut_Superres_PyrLKOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_PyrLKOpticalFlow_setIterations_int(self.as_raw_mut_Superres_PyrLKOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -811,11 +863,12 @@ pub trait Superres_SuperResolutionConst: core::AlgorithmTraitConst + crate::supe
 
 	/// Scale factor
 	/// ## See also
-	/// setScale
+	/// `set_scale`
 	#[inline]
 	fn get_scale(&self) -> Result<i32> {
+		crate::core::validity::check("Superres_SuperResolution", self.as_raw_Superres_SuperResolution())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_SuperResolution_getScale_const(self.as_raw_Superres_SuperResolution(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_SuperResolution_getScale_const(self.as_raw_Superres_SuperResolution(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -823,11 +876,12 @@ pub trait Superres_SuperResolutionConst: core::AlgorithmTraitConst + crate::supe
 	
 	/// Iterations count
 	/// ## See also
-	/// setIterations
+	/// `set_iterations`
 	#[inline]
 	fn get_iterations(&self) -> Result<i32> {
+		crate::core::validity::check("Superres_SuperResolution", self.as_raw_Superres_SuperResolution())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_SuperResolution_getIterations_const(self.as_raw_Superres_SuperResolution(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_SuperResolution_getIterations_const(self.as_raw_Superres_SuperResolution(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -835,11 +889,12 @@ pub trait Superres_SuperResolutionConst: core::AlgorithmTraitConst + crate::supe
 	
 	/// Asymptotic value of steepest descent method
 	/// ## See also
-	/// setTau
+	/// `set_tau`
 	#[inline]
 	fn get_tau(&self) -> Result<f64> {
+		crate::core::validity::check("Superres_SuperResolution", self.as_raw_Superres_SuperResolution())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_SuperResolution_getTau_const(self.as_raw_Superres_SuperResolution(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_SuperResolution_getTau_const(self.as_raw_Superres_SuperResolution(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -847,11 +902,12 @@ pub trait Superres_SuperResolutionConst: core::AlgorithmTraitConst + crate::supe
 	
 	/// Weight parameter to balance data term and smoothness term
 	/// ## See also
-	/// setLambda
+	/// `set_lambda`
 	#[inline]
 	fn get_lambda(&self) -> Result<f64> {
+		crate::core::validity::check("Superres_SuperResolution", self.as_raw_Superres_SuperResolution())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_SuperResolution_getLambda_const(self.as_raw_Superres_SuperResolution(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_SuperResolution_getLambda_const(self.as_raw_Superres_SuperResolution(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -859,11 +915,12 @@ pub trait Superres_SuperResolutionConst: core::AlgorithmTraitConst + crate::supe
 	
 	/// Parameter of spacial distribution in Bilateral-TV
 	/// ## See also
-	/// setAlpha
+	/// `set_alpha`
 	#[inline]
 	fn get_alpha(&self) -> Result<f64> {
+		crate::core::validity::check("Superres_SuperResolution", self.as_raw_Superres_SuperResolution())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_SuperResolution_getAlpha_const(self.as_raw_Superres_SuperResolution(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_SuperResolution_getAlpha_const(self.as_raw_Superres_SuperResolution(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -871,11 +928,12 @@ pub trait Superres_SuperResolutionConst: core::AlgorithmTraitConst + crate::supe
 	
 	/// Kernel size of Bilateral-TV filter
 	/// ## See also
-	/// setKernelSize
+	/// `set_kernel_size`
 	#[inline]
 	fn get_kernel_size(&self) -> Result<i32> {
+		crate::core::validity::check("Superres_SuperResolution", self.as_raw_Superres_SuperResolution())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_SuperResolution_getKernelSize_const(self.as_raw_Superres_SuperResolution(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_SuperResolution_getKernelSize_const(self.as_raw_Superres_SuperResolution(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -883,11 +941,12 @@ pub trait Superres_SuperResolutionConst: core::AlgorithmTraitConst + crate::supe
 	
 	/// Gaussian blur kernel size
 	/// ## See also
-	/// setBlurKernelSize
+	/// `set_blur_kernel_size`
 	#[inline]
 	fn get_blur_kernel_size(&self) -> Result<i32> {
+		crate::core::validity::check("Superres_SuperResolution", self.as_raw_Superres_SuperResolution())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_SuperResolution_getBlurKernelSize_const(self.as_raw_Superres_SuperResolution(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_SuperResolution_getBlurKernelSize_const(self.as_raw_Superres_SuperResolution(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -895,11 +954,12 @@ pub trait Superres_SuperResolutionConst: core::AlgorithmTraitConst + crate::supe
 	
 	/// Gaussian blur sigma
 	/// ## See also
-	/// setBlurSigma
+	/// `set_blur_sigma`
 	#[inline]
 	fn get_blur_sigma(&self) -> Result<f64> {
+		crate::core::validity::check("Superres_SuperResolution", self.as_raw_Superres_SuperResolution())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_SuperResolution_getBlurSigma_const(self.as_raw_Superres_SuperResolution(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_SuperResolution_getBlurSigma_const(self.as_raw_Superres_SuperResolution(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -907,11 +967,12 @@ pub trait Superres_SuperResolutionConst: core::AlgorithmTraitConst + crate::supe
 	
 	/// Radius of the temporal search area
 	/// ## See also
-	/// setTemporalAreaRadius
+	/// `set_temporal_area_radius`
 	#[inline]
 	fn get_temporal_area_radius(&self) -> Result<i32> {
+		crate::core::validity::check("Superres_SuperResolution", self.as_raw_Superres_SuperResolution())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_SuperResolution_getTemporalAreaRadius_const(self.as_raw_Superres_SuperResolution(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_SuperResolution_getTemporalAreaRadius_const(self.as_raw_Superres_SuperResolution(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -919,11 +980,12 @@ pub trait Superres_SuperResolutionConst: core::AlgorithmTraitConst + crate::supe
 	
 	/// Dense optical flow algorithm
 	/// ## See also
-	/// setOpticalFlow
+	/// `set_optical_flow`
 	#[inline]
 	fn get_optical_flow(&self) -> Result<core::Ptr<dyn crate::superres::Superres_DenseOpticalFlowExt>> {
+		crate::core::validity::check("Superres_SuperResolution", self.as_raw_Superres_SuperResolution())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_SuperResolution_getOpticalFlow_const(self.as_raw_Superres_SuperResolution(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_SuperResolution_getOpticalFlow_const(self.as_raw_Superres_SuperResolution(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::superres::Superres_DenseOpticalFlowExt>::opencv_from_extern(ret) };
@@ -941,8 +1003,9 @@ pub trait Superres_SuperResolution: core::AlgorithmTrait + crate::superres::Supe
 	/// * frameSource: Input frame source
 	#[inline]
 	fn set_input(&mut self, frame_source: &core::Ptr<dyn crate::superres::Superres_FrameSource>) -> Result<()> {
+		crate::core::validity::check("Superres_SuperResolution", self.as_raw_mut_Superres_SuperResolution())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_SuperResolution_setInput_const_Ptr_FrameSource_R(self.as_raw_mut_Superres_SuperResolution(), frame_source.as_raw_PtrOfSuperres_FrameSource(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_SuperResolution_setInput_const_Ptr_FrameSource_R(self.as_raw_mut_Superres_SuperResolution(), frame_source.as_raw_PtrOfSuperres_FrameSource(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -954,9 +1017,10 @@ pub trait Superres_SuperResolution: core::AlgorithmTrait + crate::superres::Supe
 	/// * frame: Output result
 	#[inline]
 	fn next_frame(&mut self, frame: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("Superres_SuperResolution", self.as_raw_mut_Superres_SuperResolution())?;
 		output_array_arg!(frame);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_SuperResolution_nextFrame_const__OutputArrayR(self.as_raw_mut_Superres_SuperResolution(), frame.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_SuperResolution_nextFrame_const__OutputArrayR(self.as_raw_mut_Superres_SuperResolution(), frame.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -964,8 +1028,9 @@ pub trait Superres_SuperResolution: core::AlgorithmTrait + crate::superres::Supe
 	
 	#[inline]
 	fn reset(&mut self) -> Result<()> {
+		crate::core::validity::check("Superres_SuperResolution", self.as_raw_mut_Superres_SuperResolution())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_SuperResolution_reset(self.as_raw_mut_Superres_SuperResolution(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_SuperResolution_reset(self.as_raw_mut_Superres_SuperResolution(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -974,8 +1039,9 @@ pub trait Superres_SuperResolution: core::AlgorithmTrait + crate::superres::Supe
 	/// Clear all inner buffers.
 	#[inline]
 	fn collect_garbage(&mut self) -> Result<()> {
+		crate::core::validity::check("Superres_SuperResolution", self.as_raw_mut_Superres_SuperResolution())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_SuperResolution_collectGarbage(self.as_raw_mut_Superres_SuperResolution(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_SuperResolution_collectGarbage(self.as_raw_mut_Superres_SuperResolution(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -986,8 +1052,9 @@ pub trait Superres_SuperResolution: core::AlgorithmTrait + crate::superres::Supe
 	/// setScale getScale
 	#[inline]
 	fn set_scale(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("Superres_SuperResolution", self.as_raw_mut_Superres_SuperResolution())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_SuperResolution_setScale_int(self.as_raw_mut_Superres_SuperResolution(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_SuperResolution_setScale_int(self.as_raw_mut_Superres_SuperResolution(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -998,8 +1065,9 @@ pub trait Superres_SuperResolution: core::AlgorithmTrait + crate::superres::Supe
 	/// setIterations getIterations
 	#[inline]
 	fn set_iterations(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("Superres_SuperResolution", self.as_raw_mut_Superres_SuperResolution())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_SuperResolution_setIterations_int(self.as_raw_mut_Superres_SuperResolution(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_SuperResolution_setIterations_int(self.as_raw_mut_Superres_SuperResolution(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1010,8 +1078,9 @@ pub trait Superres_SuperResolution: core::AlgorithmTrait + crate::superres::Supe
 	/// setTau getTau
 	#[inline]
 	fn set_tau(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("Superres_SuperResolution", self.as_raw_mut_Superres_SuperResolution())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_SuperResolution_setTau_double(self.as_raw_mut_Superres_SuperResolution(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_SuperResolution_setTau_double(self.as_raw_mut_Superres_SuperResolution(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1022,8 +1091,9 @@ pub trait Superres_SuperResolution: core::AlgorithmTrait + crate::superres::Supe
 	/// setLambda getLambda
 	#[inline]
 	fn set_lambda(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("Superres_SuperResolution", self.as_raw_mut_Superres_SuperResolution())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_SuperResolution_setLambda_double(self.as_raw_mut_Superres_SuperResolution(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_SuperResolution_setLambda_double(self.as_raw_mut_Superres_SuperResolution(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1034,8 +1104,9 @@ pub trait Superres_SuperResolution: core::AlgorithmTrait + crate::superres::Supe
 	/// setAlpha getAlpha
 	#[inline]
 	fn set_alpha(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("Superres_SuperResolution", self.as_raw_mut_Superres_SuperResolution())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_SuperResolution_setAlpha_double(self.as_raw_mut_Superres_SuperResolution(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_SuperResolution_setAlpha_double(self.as_raw_mut_Superres_SuperResolution(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1046,8 +1117,9 @@ pub trait Superres_SuperResolution: core::AlgorithmTrait + crate::superres::Supe
 	/// setKernelSize getKernelSize
 	#[inline]
 	fn set_kernel_size(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("Superres_SuperResolution", self.as_raw_mut_Superres_SuperResolution())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_SuperResolution_setKernelSize_int(self.as_raw_mut_Superres_SuperResolution(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_SuperResolution_setKernelSize_int(self.as_raw_mut_Superres_SuperResolution(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1058,8 +1130,9 @@ pub trait Superres_SuperResolution: core::AlgorithmTrait + crate::superres::Supe
 	/// setBlurKernelSize getBlurKernelSize
 	#[inline]
 	fn set_blur_kernel_size(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("Superres_SuperResolution", self.as_raw_mut_Superres_SuperResolution())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_SuperResolution_setBlurKernelSize_int(self.as_raw_mut_Superres_SuperResolution(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_SuperResolution_setBlurKernelSize_int(self.as_raw_mut_Superres_SuperResolution(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1070,8 +1143,9 @@ pub trait Superres_SuperResolution: core::AlgorithmTrait + crate::superres::Supe
 	/// setBlurSigma getBlurSigma
 	#[inline]
 	fn set_blur_sigma(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("Superres_SuperResolution", self.as_raw_mut_Superres_SuperResolution())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_SuperResolution_setBlurSigma_double(self.as_raw_mut_Superres_SuperResolution(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_SuperResolution_setBlurSigma_double(self.as_raw_mut_Superres_SuperResolution(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1082,8 +1156,9 @@ pub trait Superres_SuperResolution: core::AlgorithmTrait + crate::superres::Supe
 	/// setTemporalAreaRadius getTemporalAreaRadius
 	#[inline]
 	fn set_temporal_area_radius(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("Superres_SuperResolution", self.as_raw_mut_Superres_SuperResolution())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_SuperResolution_setTemporalAreaRadius_int(self.as_raw_mut_Superres_SuperResolution(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_SuperResolution_setTemporalAreaRadius_int(self.as_raw_mut_Superres_SuperResolution(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1094,8 +1169,9 @@ pub trait Superres_SuperResolution: core::AlgorithmTrait + crate::superres::Supe
 	/// setOpticalFlow getOpticalFlow
 	#[inline]
 	fn set_optical_flow(&mut self, val: &core::Ptr<dyn crate::superres::Superres_DenseOpticalFlowExt>) -> Result<()> {
+		crate::core::validity::check("Superres_SuperResolution", self.as_raw_mut_Superres_SuperResolution())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_superres_SuperResolution_setOpticalFlow_const_Ptr_DenseOpticalFlowExt_R(self.as_raw_mut_Superres_SuperResolution(), val.as_raw_PtrOfSuperres_DenseOpticalFlowExt(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_superres_SuperResolution_setOpticalFlow_const_Ptr_DenseOpticalFlowExt_R(self.as_raw_mut_Superres_SuperResolution(), val.as_raw_PtrOfSuperres_DenseOpticalFlowExt(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
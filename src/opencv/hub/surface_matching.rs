@@ -179,7 +179,7 @@
 //! // print the poses
 //! for (size_t i=0; i<results.size(); i++)
 //! {
-//!    Pose3DPtr pose = results[i];
+//!    Pose3DPtr pose = results\[i\];
 //!    cout << "Pose Result " << i << endl;
 //!    pose->printPose();
 //! }
@@ -401,15 +401,16 @@ pub trait ICPTrait: crate::surface_matching::ICPTraitConst {
 	/// * srcPC: The input point cloud for the model. Expected to have the normals (Nx6). Currently,
 	/// CV_32F is the only supported data type.
 	/// * dstPC: The input point cloud for the scene. It is assumed that the model is registered on the scene. Scene remains static. Expected to have the normals (Nx6). Currently, CV_32F is the only supported data type.
-	/// * residual:[out] The output registration error.
-	/// * pose:[out] Transformation between srcPC and dstPC.
+	/// * residual:\[out\] The output registration error.
+	/// * pose:\[out\] Transformation between srcPC and dstPC.
 	/// \return On successful termination, the function returns 0.
 	/// 
 	/// \details It is assumed that the model is registered on the scene. Scene remains static, while the model transforms. The output poses transform the models onto the scene. Because of the point to plane minimization, the scene is expected to have the normals available. Expected to have the normals (Nx6).
 	#[inline]
 	fn register_model_to_scene(&mut self, src_pc: &core::Mat, dst_pc: &core::Mat, residual: &mut f64, pose: &mut core::Matx44d) -> Result<i32> {
+		crate::core::validity::check("ICP", self.as_raw_mut_ICP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ppf_match_3d_ICP_registerModelToScene_const_MatR_const_MatR_doubleR_Matx44dR(self.as_raw_mut_ICP(), src_pc.as_raw_Mat(), dst_pc.as_raw_Mat(), residual, pose, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ppf_match_3d_ICP_registerModelToScene_const_MatR_const_MatR_doubleR_Matx44dR(self.as_raw_mut_ICP(), src_pc.as_raw_Mat(), dst_pc.as_raw_Mat(), residual, pose, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -421,14 +422,15 @@ pub trait ICPTrait: crate::surface_matching::ICPTraitConst {
 	/// * srcPC: The input point cloud for the model. Expected to have the normals (Nx6). Currently,
 	/// CV_32F is the only supported data type.
 	/// * dstPC: The input point cloud for the scene. Currently, CV_32F is the only supported data type.
-	/// @param [in,out] poses Input poses to start with but also list output of poses.
+	/// @param \[in,out\] poses Input poses to start with but also list output of poses.
 	/// \return On successful termination, the function returns 0.
 	/// 
 	/// \details It is assumed that the model is registered on the scene. Scene remains static, while the model transforms. The output poses transform the models onto the scene. Because of the point to plane minimization, the scene is expected to have the normals available. Expected to have the normals (Nx6).
 	#[inline]
 	fn register_model_to_scene_vec(&mut self, src_pc: &core::Mat, dst_pc: &core::Mat, poses: &mut core::Vector<crate::surface_matching::Pose3DPtr>) -> Result<i32> {
+		crate::core::validity::check("ICP", self.as_raw_mut_ICP())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ppf_match_3d_ICP_registerModelToScene_const_MatR_const_MatR_vector_Pose3DPtr_R(self.as_raw_mut_ICP(), src_pc.as_raw_Mat(), dst_pc.as_raw_Mat(), poses.as_raw_mut_VectorOfPose3DPtr(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ppf_match_3d_ICP_registerModelToScene_const_MatR_const_MatR_vector_Pose3DPtr_R(self.as_raw_mut_ICP(), src_pc.as_raw_Mat(), dst_pc.as_raw_Mat(), poses.as_raw_mut_VectorOfPose3DPtr(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -461,7 +463,10 @@ opencv_type_boxed! { ICP }
 impl Drop for ICP {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ICP_delete(instance: *mut c_void); }
-		unsafe { cv_ICP_delete(self.as_raw_mut_ICP()) };
+		let ptr = self.as_raw_mut_ICP();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ICP_delete(ptr) };
 	}
 }
 
@@ -479,7 +484,7 @@ impl ICP {
 	#[inline]
 	pub fn default() -> Result<crate::surface_matching::ICP> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ppf_match_3d_ICP_ICP(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ppf_match_3d_ICP_ICP(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::surface_matching::ICP::opencv_from_extern(ret) };
@@ -510,7 +515,7 @@ impl ICP {
 	#[inline]
 	pub fn new(iterations: i32, tolerence: f32, rejection_scale: f32, num_levels: i32, sample_type: i32, num_max_corr: i32) -> Result<crate::surface_matching::ICP> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ppf_match_3d_ICP_ICP_const_int_const_float_const_float_const_int_const_int_const_int(iterations, tolerence, rejection_scale, num_levels, sample_type, num_max_corr, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ppf_match_3d_ICP_ICP_const_int_const_float_const_float_const_int_const_int_const_int(iterations, tolerence, rejection_scale, num_levels, sample_type, num_max_corr, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::surface_matching::ICP::opencv_from_extern(ret) };
@@ -519,6 +524,59 @@ impl ICP {
 	
 }
 
+/// Builder for `ICP::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct ICP_NewBuilder {
+	iterations: i32,
+	tolerence: f32,
+	rejection_scale: f32,
+	num_levels: i32,
+	sample_type: i32,
+	num_max_corr: i32,
+}
+
+impl ICP_NewBuilder {
+	#[inline]
+	pub fn new(iterations: i32, sample_type: i32) -> Self {
+		Self { iterations, tolerence: 0.05, rejection_scale: 2.5, num_levels: 6, sample_type, num_max_corr: 1 }
+	}
+	
+	/// C++ default: `0.05f`
+	#[inline]
+	pub fn with_tolerence(mut self, tolerence: f32) -> Self {
+		self.tolerence = tolerence;
+		self
+	}
+	
+	/// C++ default: `2.5f`
+	#[inline]
+	pub fn with_rejection_scale(mut self, rejection_scale: f32) -> Self {
+		self.rejection_scale = rejection_scale;
+		self
+	}
+	
+	/// C++ default: `6`
+	#[inline]
+	pub fn with_num_levels(mut self, num_levels: i32) -> Self {
+		self.num_levels = num_levels;
+		self
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_num_max_corr(mut self, num_max_corr: i32) -> Self {
+		self.num_max_corr = num_max_corr;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::surface_matching::ICP> {
+		ICP::new(self.iterations, self.tolerence, self.rejection_scale, self.num_levels, self.sample_type, self.num_max_corr)
+	}
+}
+
 /// Class, allowing the load and matching 3D models.
 /// Typical Use:
 /// ```ignore
@@ -550,8 +608,9 @@ pub trait PPF3DDetectorTrait: crate::surface_matching::PPF3DDetectorTraitConst {
 	/// * use_weighted_clustering: false
 	#[inline]
 	fn set_search_params(&mut self, position_threshold: f64, rotation_threshold: f64, use_weighted_clustering: bool) -> Result<()> {
+		crate::core::validity::check("PPF3DDetector", self.as_raw_mut_PPF3DDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ppf_match_3d_PPF3DDetector_setSearchParams_const_double_const_double_const_bool(self.as_raw_mut_PPF3DDetector(), position_threshold, rotation_threshold, use_weighted_clustering, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ppf_match_3d_PPF3DDetector_setSearchParams_const_double_const_double_const_bool(self.as_raw_mut_PPF3DDetector(), position_threshold, rotation_threshold, use_weighted_clustering, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -565,8 +624,9 @@ pub trait PPF3DDetectorTrait: crate::surface_matching::PPF3DDetectorTraitConst {
 	/// \details Uses the parameters set in the constructor to downsample and learn a new model. When the model is learnt, the instance gets ready for calling "match".
 	#[inline]
 	fn train_model(&mut self, model: &core::Mat) -> Result<()> {
+		crate::core::validity::check("PPF3DDetector", self.as_raw_mut_PPF3DDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ppf_match_3d_PPF3DDetector_trainModel_const_MatR(self.as_raw_mut_PPF3DDetector(), model.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ppf_match_3d_PPF3DDetector_trainModel_const_MatR(self.as_raw_mut_PPF3DDetector(), model.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -576,7 +636,7 @@ pub trait PPF3DDetectorTrait: crate::surface_matching::PPF3DDetectorTraitConst {
 	/// 
 	/// ## Parameters
 	/// * scene: Point cloud for the scene
-	/// * results:[out] List of output poses
+	/// * results:\[out\] List of output poses
 	/// * relativeSceneSampleStep: The ratio of scene points to be used for the matching after sampling with relativeSceneDistance. For example, if this value is set to 1.0/5.0, every 5th point from the scene is used for pose estimation. This parameter allows an easy trade-off between speed and accuracy of the matching. Increasing the value leads to less points being used and in turn to a faster but less accurate pose computation. Decreasing the value has the inverse effect.
 	/// * relativeSceneDistance: Set the distance threshold relative to the diameter of the model. This parameter is equivalent to relativeSamplingStep in the training stage. This parameter acts like a prior sampling with the relativeSceneSampleStep parameter.
 	/// 
@@ -585,8 +645,9 @@ pub trait PPF3DDetectorTrait: crate::surface_matching::PPF3DDetectorTraitConst {
 	/// * relative_scene_distance: 0.03
 	#[inline]
 	fn match_(&mut self, scene: &core::Mat, results: &mut core::Vector<crate::surface_matching::Pose3DPtr>, relative_scene_sample_step: f64, relative_scene_distance: f64) -> Result<()> {
+		crate::core::validity::check("PPF3DDetector", self.as_raw_mut_PPF3DDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ppf_match_3d_PPF3DDetector_match_const_MatR_vector_Pose3DPtr_R_const_double_const_double(self.as_raw_mut_PPF3DDetector(), scene.as_raw_Mat(), results.as_raw_mut_VectorOfPose3DPtr(), relative_scene_sample_step, relative_scene_distance, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ppf_match_3d_PPF3DDetector_match_const_MatR_vector_Pose3DPtr_R_const_double_const_double(self.as_raw_mut_PPF3DDetector(), scene.as_raw_Mat(), results.as_raw_mut_VectorOfPose3DPtr(), relative_scene_sample_step, relative_scene_distance, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -594,6 +655,50 @@ pub trait PPF3DDetectorTrait: crate::surface_matching::PPF3DDetectorTraitConst {
 	
 }
 
+/// Builder for `PPF3DDetectorTrait::set_search_params()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct PPF3DDetector_SetSearchParamsBuilder<'a, T: ?Sized + PPF3DDetectorTrait> {
+	instance: &'a mut T,
+	position_threshold: f64,
+	rotation_threshold: f64,
+	use_weighted_clustering: bool,
+}
+
+impl<'a, T: ?Sized + PPF3DDetectorTrait> PPF3DDetector_SetSearchParamsBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T) -> Self {
+		Self { instance, position_threshold: -1., rotation_threshold: -1., use_weighted_clustering: false }
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_position_threshold(mut self, position_threshold: f64) -> Self {
+		self.position_threshold = position_threshold;
+		self
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_rotation_threshold(mut self, rotation_threshold: f64) -> Self {
+		self.rotation_threshold = rotation_threshold;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_use_weighted_clustering(mut self, use_weighted_clustering: bool) -> Self {
+		self.use_weighted_clustering = use_weighted_clustering;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		PPF3DDetectorTrait::set_search_params(self.instance, self.position_threshold, self.rotation_threshold, self.use_weighted_clustering)
+	}
+}
+
 /// Class, allowing the load and matching 3D models.
 /// Typical Use:
 /// ```ignore
@@ -614,7 +719,10 @@ opencv_type_boxed! { PPF3DDetector }
 impl Drop for PPF3DDetector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_PPF3DDetector_delete(instance: *mut c_void); }
-		unsafe { cv_PPF3DDetector_delete(self.as_raw_mut_PPF3DDetector()) };
+		let ptr = self.as_raw_mut_PPF3DDetector();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_PPF3DDetector_delete(ptr) };
 	}
 }
 
@@ -633,7 +741,7 @@ impl PPF3DDetector {
 	#[inline]
 	pub fn default() -> Result<crate::surface_matching::PPF3DDetector> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ppf_match_3d_PPF3DDetector_PPF3DDetector(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ppf_match_3d_PPF3DDetector_PPF3DDetector(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::surface_matching::PPF3DDetector::opencv_from_extern(ret) };
@@ -652,7 +760,7 @@ impl PPF3DDetector {
 	#[inline]
 	pub fn new(relative_sampling_step: f64, relative_distance_step: f64, num_angles: f64) -> Result<crate::surface_matching::PPF3DDetector> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ppf_match_3d_PPF3DDetector_PPF3DDetector_const_double_const_double_const_double(relative_sampling_step, relative_distance_step, num_angles, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ppf_match_3d_PPF3DDetector_PPF3DDetector_const_double_const_double_const_double(relative_sampling_step, relative_distance_step, num_angles, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::surface_matching::PPF3DDetector::opencv_from_extern(ret) };
@@ -669,58 +777,130 @@ pub trait Pose3DTraitConst {
 
 	#[inline]
 	fn alpha(&self) -> f64 {
-		let ret = unsafe { sys::cv_ppf_match_3d_Pose3D_getPropAlpha_const(self.as_raw_Pose3D()) };
+		crate::core::validity::debug_check("Pose3D", self.as_raw_Pose3D());
+		let ret = unsafe { ffi_call!(cv_ppf_match_3d_Pose3D_getPropAlpha_const(self.as_raw_Pose3D())) };
 		ret
 	}
 	
+	/// Non-panicking version of [alpha](Pose3DTraitConst::alpha), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_alpha(&self) -> Result<f64> {
+		crate::core::validity::check("Pose3D", self.as_raw_Pose3D())?;
+		Ok(Pose3DTraitConst::alpha(self))
+	}
+	
 	#[inline]
 	fn residual(&self) -> f64 {
-		let ret = unsafe { sys::cv_ppf_match_3d_Pose3D_getPropResidual_const(self.as_raw_Pose3D()) };
+		crate::core::validity::debug_check("Pose3D", self.as_raw_Pose3D());
+		let ret = unsafe { ffi_call!(cv_ppf_match_3d_Pose3D_getPropResidual_const(self.as_raw_Pose3D())) };
 		ret
 	}
 	
+	/// Non-panicking version of [residual](Pose3DTraitConst::residual), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_residual(&self) -> Result<f64> {
+		crate::core::validity::check("Pose3D", self.as_raw_Pose3D())?;
+		Ok(Pose3DTraitConst::residual(self))
+	}
+	
 	#[inline]
 	fn model_index(&self) -> size_t {
-		let ret = unsafe { sys::cv_ppf_match_3d_Pose3D_getPropModelIndex_const(self.as_raw_Pose3D()) };
+		crate::core::validity::debug_check("Pose3D", self.as_raw_Pose3D());
+		let ret = unsafe { ffi_call!(cv_ppf_match_3d_Pose3D_getPropModelIndex_const(self.as_raw_Pose3D())) };
 		ret
 	}
 	
+	/// Non-panicking version of [model_index](Pose3DTraitConst::model_index), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_model_index(&self) -> Result<size_t> {
+		crate::core::validity::check("Pose3D", self.as_raw_Pose3D())?;
+		Ok(Pose3DTraitConst::model_index(self))
+	}
+	
 	#[inline]
 	fn num_votes(&self) -> size_t {
-		let ret = unsafe { sys::cv_ppf_match_3d_Pose3D_getPropNumVotes_const(self.as_raw_Pose3D()) };
+		crate::core::validity::debug_check("Pose3D", self.as_raw_Pose3D());
+		let ret = unsafe { ffi_call!(cv_ppf_match_3d_Pose3D_getPropNumVotes_const(self.as_raw_Pose3D())) };
 		ret
 	}
 	
+	/// Non-panicking version of [num_votes](Pose3DTraitConst::num_votes), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_num_votes(&self) -> Result<size_t> {
+		crate::core::validity::check("Pose3D", self.as_raw_Pose3D())?;
+		Ok(Pose3DTraitConst::num_votes(self))
+	}
+	
 	#[inline]
 	fn pose(&self) -> core::Matx44d {
+		crate::core::validity::debug_check("Pose3D", self.as_raw_Pose3D());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ppf_match_3d_Pose3D_getPropPose_const(self.as_raw_Pose3D(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ppf_match_3d_Pose3D_getPropPose_const(self.as_raw_Pose3D(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [pose](Pose3DTraitConst::pose), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pose(&self) -> Result<core::Matx44d> {
+		crate::core::validity::check("Pose3D", self.as_raw_Pose3D())?;
+		Ok(Pose3DTraitConst::pose(self))
+	}
+	
 	#[inline]
 	fn angle(&self) -> f64 {
-		let ret = unsafe { sys::cv_ppf_match_3d_Pose3D_getPropAngle_const(self.as_raw_Pose3D()) };
+		crate::core::validity::debug_check("Pose3D", self.as_raw_Pose3D());
+		let ret = unsafe { ffi_call!(cv_ppf_match_3d_Pose3D_getPropAngle_const(self.as_raw_Pose3D())) };
 		ret
 	}
 	
+	/// Non-panicking version of [angle](Pose3DTraitConst::angle), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_angle(&self) -> Result<f64> {
+		crate::core::validity::check("Pose3D", self.as_raw_Pose3D())?;
+		Ok(Pose3DTraitConst::angle(self))
+	}
+	
 	#[inline]
 	fn t(&self) -> core::Vec3d {
+		crate::core::validity::debug_check("Pose3D", self.as_raw_Pose3D());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ppf_match_3d_Pose3D_getPropT_const(self.as_raw_Pose3D(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ppf_match_3d_Pose3D_getPropT_const(self.as_raw_Pose3D(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [t](Pose3DTraitConst::t), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_t(&self) -> Result<core::Vec3d> {
+		crate::core::validity::check("Pose3D", self.as_raw_Pose3D())?;
+		Ok(Pose3DTraitConst::t(self))
+	}
+	
 	#[inline]
 	fn q(&self) -> core::Vec4d {
+		crate::core::validity::debug_check("Pose3D", self.as_raw_Pose3D());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ppf_match_3d_Pose3D_getPropQ_const(self.as_raw_Pose3D(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ppf_match_3d_Pose3D_getPropQ_const(self.as_raw_Pose3D(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [q](Pose3DTraitConst::q), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_q(&self) -> Result<core::Vec4d> {
+		crate::core::validity::check("Pose3D", self.as_raw_Pose3D())?;
+		Ok(Pose3DTraitConst::q(self))
+	}
+	
 }
 
 pub trait Pose3DTrait: crate::surface_matching::Pose3DTraitConst {
@@ -728,58 +908,67 @@ pub trait Pose3DTrait: crate::surface_matching::Pose3DTraitConst {
 
 	#[inline]
 	fn set_alpha(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_ppf_match_3d_Pose3D_setPropAlpha_double(self.as_raw_mut_Pose3D(), val) };
+		crate::core::validity::debug_check("Pose3D", self.as_raw_mut_Pose3D());
+		let ret = unsafe { ffi_call!(cv_ppf_match_3d_Pose3D_setPropAlpha_double(self.as_raw_mut_Pose3D(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_residual(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_ppf_match_3d_Pose3D_setPropResidual_double(self.as_raw_mut_Pose3D(), val) };
+		crate::core::validity::debug_check("Pose3D", self.as_raw_mut_Pose3D());
+		let ret = unsafe { ffi_call!(cv_ppf_match_3d_Pose3D_setPropResidual_double(self.as_raw_mut_Pose3D(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_model_index(&mut self, val: size_t) {
-		let ret = unsafe { sys::cv_ppf_match_3d_Pose3D_setPropModelIndex_size_t(self.as_raw_mut_Pose3D(), val) };
+		crate::core::validity::debug_check("Pose3D", self.as_raw_mut_Pose3D());
+		let ret = unsafe { ffi_call!(cv_ppf_match_3d_Pose3D_setPropModelIndex_size_t(self.as_raw_mut_Pose3D(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_num_votes(&mut self, val: size_t) {
-		let ret = unsafe { sys::cv_ppf_match_3d_Pose3D_setPropNumVotes_size_t(self.as_raw_mut_Pose3D(), val) };
+		crate::core::validity::debug_check("Pose3D", self.as_raw_mut_Pose3D());
+		let ret = unsafe { ffi_call!(cv_ppf_match_3d_Pose3D_setPropNumVotes_size_t(self.as_raw_mut_Pose3D(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_pose(&mut self, val: core::Matx44d) {
-		let ret = unsafe { sys::cv_ppf_match_3d_Pose3D_setPropPose_Matx44d(self.as_raw_mut_Pose3D(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("Pose3D", self.as_raw_mut_Pose3D());
+		let ret = unsafe { ffi_call!(cv_ppf_match_3d_Pose3D_setPropPose_Matx44d(self.as_raw_mut_Pose3D(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_angle(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_ppf_match_3d_Pose3D_setPropAngle_double(self.as_raw_mut_Pose3D(), val) };
+		crate::core::validity::debug_check("Pose3D", self.as_raw_mut_Pose3D());
+		let ret = unsafe { ffi_call!(cv_ppf_match_3d_Pose3D_setPropAngle_double(self.as_raw_mut_Pose3D(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_t(&mut self, val: core::Vec3d) {
-		let ret = unsafe { sys::cv_ppf_match_3d_Pose3D_setPropT_Vec3d(self.as_raw_mut_Pose3D(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("Pose3D", self.as_raw_mut_Pose3D());
+		let ret = unsafe { ffi_call!(cv_ppf_match_3d_Pose3D_setPropT_Vec3d(self.as_raw_mut_Pose3D(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_q(&mut self, val: core::Vec4d) {
-		let ret = unsafe { sys::cv_ppf_match_3d_Pose3D_setPropQ_Vec4d(self.as_raw_mut_Pose3D(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("Pose3D", self.as_raw_mut_Pose3D());
+		let ret = unsafe { ffi_call!(cv_ppf_match_3d_Pose3D_setPropQ_Vec4d(self.as_raw_mut_Pose3D(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	/// \brief Updates the pose with the new one
-	/// \param [in] NewPose New pose to overwrite
+	/// \param \[in\] NewPose New pose to overwrite
 	#[inline]
 	fn update_pose(&mut self, new_pose: &mut core::Matx44d) -> Result<()> {
+		crate::core::validity::check("Pose3D", self.as_raw_mut_Pose3D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ppf_match_3d_Pose3D_updatePose_Matx44dR(self.as_raw_mut_Pose3D(), new_pose, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ppf_match_3d_Pose3D_updatePose_Matx44dR(self.as_raw_mut_Pose3D(), new_pose, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -788,8 +977,9 @@ pub trait Pose3DTrait: crate::surface_matching::Pose3DTraitConst {
 	/// \brief Updates the pose with the new one
 	#[inline]
 	fn update_pose_1(&mut self, new_r: &mut core::Matx33d, new_t: &mut core::Vec3d) -> Result<()> {
+		crate::core::validity::check("Pose3D", self.as_raw_mut_Pose3D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ppf_match_3d_Pose3D_updatePose_Matx33dR_Vec3dR(self.as_raw_mut_Pose3D(), new_r, new_t, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ppf_match_3d_Pose3D_updatePose_Matx33dR_Vec3dR(self.as_raw_mut_Pose3D(), new_r, new_t, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -798,19 +988,21 @@ pub trait Pose3DTrait: crate::surface_matching::Pose3DTraitConst {
 	/// \brief Updates the pose with the new one, but this time using quaternions to represent rotation
 	#[inline]
 	fn update_pose_quat(&mut self, q: &mut core::Vec4d, new_t: &mut core::Vec3d) -> Result<()> {
+		crate::core::validity::check("Pose3D", self.as_raw_mut_Pose3D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ppf_match_3d_Pose3D_updatePoseQuat_Vec4dR_Vec3dR(self.as_raw_mut_Pose3D(), q, new_t, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ppf_match_3d_Pose3D_updatePoseQuat_Vec4dR_Vec3dR(self.as_raw_mut_Pose3D(), q, new_t, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// \brief Left multiplies the existing pose in order to update the transformation
-	/// \param [in] IncrementalPose New pose to apply
+	/// \param \[in\] IncrementalPose New pose to apply
 	#[inline]
 	fn append_pose(&mut self, incremental_pose: &mut core::Matx44d) -> Result<()> {
+		crate::core::validity::check("Pose3D", self.as_raw_mut_Pose3D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ppf_match_3d_Pose3D_appendPose_Matx44dR(self.as_raw_mut_Pose3D(), incremental_pose, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ppf_match_3d_Pose3D_appendPose_Matx44dR(self.as_raw_mut_Pose3D(), incremental_pose, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -818,8 +1010,9 @@ pub trait Pose3DTrait: crate::surface_matching::Pose3DTraitConst {
 	
 	#[inline]
 	fn print_pose(&mut self) -> Result<()> {
+		crate::core::validity::check("Pose3D", self.as_raw_mut_Pose3D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ppf_match_3d_Pose3D_printPose(self.as_raw_mut_Pose3D(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ppf_match_3d_Pose3D_printPose(self.as_raw_mut_Pose3D(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -827,8 +1020,9 @@ pub trait Pose3DTrait: crate::surface_matching::Pose3DTraitConst {
 	
 	#[inline]
 	fn clone(&mut self) -> Result<core::Ptr<crate::surface_matching::Pose3D>> {
+		crate::core::validity::check("Pose3D", self.as_raw_mut_Pose3D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ppf_match_3d_Pose3D_clone(self.as_raw_mut_Pose3D(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ppf_match_3d_Pose3D_clone(self.as_raw_mut_Pose3D(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::surface_matching::Pose3D>::opencv_from_extern(ret) };
@@ -837,9 +1031,10 @@ pub trait Pose3DTrait: crate::surface_matching::Pose3DTraitConst {
 	
 	#[inline]
 	fn write_pose(&mut self, file_name: &str) -> Result<i32> {
+		crate::core::validity::check("Pose3D", self.as_raw_mut_Pose3D())?;
 		extern_container_arg!(file_name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ppf_match_3d_Pose3D_writePose_const_stringR(self.as_raw_mut_Pose3D(), file_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ppf_match_3d_Pose3D_writePose_const_stringR(self.as_raw_mut_Pose3D(), file_name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -847,9 +1042,10 @@ pub trait Pose3DTrait: crate::surface_matching::Pose3DTraitConst {
 	
 	#[inline]
 	fn read_pose(&mut self, file_name: &str) -> Result<i32> {
+		crate::core::validity::check("Pose3D", self.as_raw_mut_Pose3D())?;
 		extern_container_arg!(file_name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ppf_match_3d_Pose3D_readPose_const_stringR(self.as_raw_mut_Pose3D(), file_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ppf_match_3d_Pose3D_readPose_const_stringR(self.as_raw_mut_Pose3D(), file_name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -869,7 +1065,10 @@ opencv_type_boxed! { Pose3D }
 impl Drop for Pose3D {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Pose3D_delete(instance: *mut c_void); }
-		unsafe { cv_Pose3D_delete(self.as_raw_mut_Pose3D()) };
+		let ptr = self.as_raw_mut_Pose3D();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Pose3D_delete(ptr) };
 	}
 }
 
@@ -887,7 +1086,7 @@ impl Pose3D {
 	#[inline]
 	pub fn default() -> Result<crate::surface_matching::Pose3D> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ppf_match_3d_Pose3D_Pose3D(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ppf_match_3d_Pose3D_Pose3D(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::surface_matching::Pose3D::opencv_from_extern(ret) };
@@ -900,7 +1099,7 @@ impl Pose3D {
 	#[inline]
 	pub fn new(alpha: f64, model_index: size_t, num_votes: size_t) -> Result<crate::surface_matching::Pose3D> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ppf_match_3d_Pose3D_Pose3D_double_size_t_size_t(alpha, model_index, num_votes, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ppf_match_3d_Pose3D_Pose3D_double_size_t_size_t(alpha, model_index, num_votes, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::surface_matching::Pose3D::opencv_from_extern(ret) };
@@ -917,23 +1116,50 @@ pub trait PoseCluster3DTraitConst {
 
 	#[inline]
 	fn pose_list(&self) -> core::Vector<crate::surface_matching::Pose3DPtr> {
-		let ret = unsafe { sys::cv_ppf_match_3d_PoseCluster3D_getPropPoseList_const(self.as_raw_PoseCluster3D()) };
+		crate::core::validity::debug_check("PoseCluster3D", self.as_raw_PoseCluster3D());
+		let ret = unsafe { ffi_call!(cv_ppf_match_3d_PoseCluster3D_getPropPoseList_const(self.as_raw_PoseCluster3D())) };
 		let ret = unsafe { core::Vector::<crate::surface_matching::Pose3DPtr>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [pose_list](PoseCluster3DTraitConst::pose_list), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pose_list(&self) -> Result<core::Vector<crate::surface_matching::Pose3DPtr>> {
+		crate::core::validity::check("PoseCluster3D", self.as_raw_PoseCluster3D())?;
+		Ok(PoseCluster3DTraitConst::pose_list(self))
+	}
+	
 	#[inline]
 	fn num_votes(&self) -> size_t {
-		let ret = unsafe { sys::cv_ppf_match_3d_PoseCluster3D_getPropNumVotes_const(self.as_raw_PoseCluster3D()) };
+		crate::core::validity::debug_check("PoseCluster3D", self.as_raw_PoseCluster3D());
+		let ret = unsafe { ffi_call!(cv_ppf_match_3d_PoseCluster3D_getPropNumVotes_const(self.as_raw_PoseCluster3D())) };
 		ret
 	}
 	
+	/// Non-panicking version of [num_votes](PoseCluster3DTraitConst::num_votes), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_num_votes(&self) -> Result<size_t> {
+		crate::core::validity::check("PoseCluster3D", self.as_raw_PoseCluster3D())?;
+		Ok(PoseCluster3DTraitConst::num_votes(self))
+	}
+	
 	#[inline]
 	fn id(&self) -> i32 {
-		let ret = unsafe { sys::cv_ppf_match_3d_PoseCluster3D_getPropId_const(self.as_raw_PoseCluster3D()) };
+		crate::core::validity::debug_check("PoseCluster3D", self.as_raw_PoseCluster3D());
+		let ret = unsafe { ffi_call!(cv_ppf_match_3d_PoseCluster3D_getPropId_const(self.as_raw_PoseCluster3D())) };
 		ret
 	}
 	
+	/// Non-panicking version of [id](PoseCluster3DTraitConst::id), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_id(&self) -> Result<i32> {
+		crate::core::validity::check("PoseCluster3D", self.as_raw_PoseCluster3D())?;
+		Ok(PoseCluster3DTraitConst::id(self))
+	}
+	
 }
 
 pub trait PoseCluster3DTrait: crate::surface_matching::PoseCluster3DTraitConst {
@@ -941,29 +1167,33 @@ pub trait PoseCluster3DTrait: crate::surface_matching::PoseCluster3DTraitConst {
 
 	#[inline]
 	fn set_pose_list(&mut self, mut val: core::Vector<crate::surface_matching::Pose3DPtr>) {
-		let ret = unsafe { sys::cv_ppf_match_3d_PoseCluster3D_setPropPoseList_vector_Pose3DPtr_(self.as_raw_mut_PoseCluster3D(), val.as_raw_mut_VectorOfPose3DPtr()) };
+		crate::core::validity::debug_check("PoseCluster3D", self.as_raw_mut_PoseCluster3D());
+		let ret = unsafe { ffi_call!(cv_ppf_match_3d_PoseCluster3D_setPropPoseList_vector_Pose3DPtr_(self.as_raw_mut_PoseCluster3D(), val.as_raw_mut_VectorOfPose3DPtr())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_num_votes(&mut self, val: size_t) {
-		let ret = unsafe { sys::cv_ppf_match_3d_PoseCluster3D_setPropNumVotes_size_t(self.as_raw_mut_PoseCluster3D(), val) };
+		crate::core::validity::debug_check("PoseCluster3D", self.as_raw_mut_PoseCluster3D());
+		let ret = unsafe { ffi_call!(cv_ppf_match_3d_PoseCluster3D_setPropNumVotes_size_t(self.as_raw_mut_PoseCluster3D(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_id(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_ppf_match_3d_PoseCluster3D_setPropId_int(self.as_raw_mut_PoseCluster3D(), val) };
+		crate::core::validity::debug_check("PoseCluster3D", self.as_raw_mut_PoseCluster3D());
+		let ret = unsafe { ffi_call!(cv_ppf_match_3d_PoseCluster3D_setPropId_int(self.as_raw_mut_PoseCluster3D(), val)) };
 		ret
 	}
 	
 	/// \brief Adds a new pose to the cluster. The pose should be "close" to the mean poses
 	/// in order to preserve the consistency
-	/// \param [in] newPose Pose to add to the cluster
+	/// \param \[in\] newPose Pose to add to the cluster
 	#[inline]
 	fn add_pose(&mut self, mut new_pose: crate::surface_matching::Pose3DPtr) -> Result<()> {
+		crate::core::validity::check("PoseCluster3D", self.as_raw_mut_PoseCluster3D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ppf_match_3d_PoseCluster3D_addPose_Pose3DPtr(self.as_raw_mut_PoseCluster3D(), new_pose.as_raw_mut_PtrOfPose3D(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ppf_match_3d_PoseCluster3D_addPose_Pose3DPtr(self.as_raw_mut_PoseCluster3D(), new_pose.as_raw_mut_PtrOfPose3D(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -971,9 +1201,10 @@ pub trait PoseCluster3DTrait: crate::surface_matching::PoseCluster3DTraitConst {
 	
 	#[inline]
 	fn write_pose_cluster(&mut self, file_name: &str) -> Result<i32> {
+		crate::core::validity::check("PoseCluster3D", self.as_raw_mut_PoseCluster3D())?;
 		extern_container_arg!(file_name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ppf_match_3d_PoseCluster3D_writePoseCluster_const_stringR(self.as_raw_mut_PoseCluster3D(), file_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ppf_match_3d_PoseCluster3D_writePoseCluster_const_stringR(self.as_raw_mut_PoseCluster3D(), file_name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -981,9 +1212,10 @@ pub trait PoseCluster3DTrait: crate::surface_matching::PoseCluster3DTraitConst {
 	
 	#[inline]
 	fn read_pose_cluster(&mut self, file_name: &str) -> Result<i32> {
+		crate::core::validity::check("PoseCluster3D", self.as_raw_mut_PoseCluster3D())?;
 		extern_container_arg!(file_name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ppf_match_3d_PoseCluster3D_readPoseCluster_const_stringR(self.as_raw_mut_PoseCluster3D(), file_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ppf_match_3d_PoseCluster3D_readPoseCluster_const_stringR(self.as_raw_mut_PoseCluster3D(), file_name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1003,7 +1235,10 @@ opencv_type_boxed! { PoseCluster3D }
 impl Drop for PoseCluster3D {
 	fn drop(&mut self) {
 		extern "C" { fn cv_PoseCluster3D_delete(instance: *mut c_void); }
-		unsafe { cv_PoseCluster3D_delete(self.as_raw_mut_PoseCluster3D()) };
+		let ptr = self.as_raw_mut_PoseCluster3D();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_PoseCluster3D_delete(ptr) };
 	}
 }
 
@@ -1021,7 +1256,7 @@ impl PoseCluster3D {
 	#[inline]
 	pub fn default() -> Result<crate::surface_matching::PoseCluster3D> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ppf_match_3d_PoseCluster3D_PoseCluster3D(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ppf_match_3d_PoseCluster3D_PoseCluster3D(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::surface_matching::PoseCluster3D::opencv_from_extern(ret) };
@@ -1031,7 +1266,7 @@ impl PoseCluster3D {
 	#[inline]
 	pub fn new(mut new_pose: crate::surface_matching::Pose3DPtr) -> Result<crate::surface_matching::PoseCluster3D> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ppf_match_3d_PoseCluster3D_PoseCluster3D_Pose3DPtr(new_pose.as_raw_mut_PtrOfPose3D(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ppf_match_3d_PoseCluster3D_PoseCluster3D_Pose3DPtr(new_pose.as_raw_mut_PtrOfPose3D(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::surface_matching::PoseCluster3D::opencv_from_extern(ret) };
@@ -1041,7 +1276,7 @@ impl PoseCluster3D {
 	#[inline]
 	pub fn new_1(mut new_pose: crate::surface_matching::Pose3DPtr, new_id: i32) -> Result<crate::surface_matching::PoseCluster3D> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ppf_match_3d_PoseCluster3D_PoseCluster3D_Pose3DPtr_int(new_pose.as_raw_mut_PtrOfPose3D(), new_id, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ppf_match_3d_PoseCluster3D_PoseCluster3D_Pose3DPtr_int(new_pose.as_raw_mut_PtrOfPose3D(), new_id, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::surface_matching::PoseCluster3D::opencv_from_extern(ret) };
@@ -1192,7 +1192,7 @@ mod core_sys {
 		pub fn cv_MatSize_operatorEQ_const_const_MatSizeR(instance: *const c_void, sz: *const c_void) -> bool;
 		pub fn cv_MatStep_getPropP(instance: *mut c_void) -> *mut size_t;
 		pub fn cv_MatStep_setPropP_size_tX(instance: *mut c_void, val: *mut size_t);
-		pub fn cv_MatStep_getPropBuf(instance: *mut c_void) -> *mut [size_t; 2];
+		pub fn cv_MatStep_getPropBuf(instance: *mut c_void, ocvrs_return: *mut Result<*mut [size_t; 2]>);
 		pub fn cv_MatStep_MatStep() -> *mut c_void;
 		pub fn cv_MatStep_MatStep_size_t(s: size_t) -> *mut c_void;
 		pub fn cv_MatStep_operator___const_int(instance: *const c_void, i: i32) -> size_t;
@@ -1365,14 +1365,14 @@ mod core_sys {
 		pub fn cv_SparseMat_Hdr_setPropPool_vector_unsigned_char_(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_SparseMat_Hdr_getPropHashtab_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_SparseMat_Hdr_setPropHashtab_vector_size_t_(instance: *mut c_void, val: *mut c_void);
-		pub fn cv_SparseMat_Hdr_getPropSize(instance: *mut c_void) -> *mut [i32; 32];
+		pub fn cv_SparseMat_Hdr_getPropSize(instance: *mut c_void, ocvrs_return: *mut Result<*mut [i32; 32]>);
 		pub fn cv_SparseMat_Hdr_Hdr_int_const_intX_int(_dims: i32, _sizes: *const i32, _type: i32, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_SparseMat_Hdr_clear(instance: *mut c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_SparseMat_Node_getPropHashval_const(instance: *const c_void) -> size_t;
 		pub fn cv_SparseMat_Node_setPropHashval_size_t(instance: *mut c_void, val: size_t);
 		pub fn cv_SparseMat_Node_getPropNext_const(instance: *const c_void) -> size_t;
 		pub fn cv_SparseMat_Node_setPropNext_size_t(instance: *mut c_void, val: size_t);
-		pub fn cv_SparseMat_Node_getPropIdx(instance: *mut c_void) -> *mut [i32; 32];
+		pub fn cv_SparseMat_Node_getPropIdx(instance: *mut c_void, ocvrs_return: *mut Result<*mut [i32; 32]>);
 		pub fn cv_SparseMatConstIterator_getPropM_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_SparseMatConstIterator_getPropHashidx_const(instance: *const c_void) -> size_t;
 		pub fn cv_SparseMatConstIterator_setPropHashidx_size_t(instance: *mut c_void, val: size_t);
@@ -6525,11 +6525,11 @@ mod stitching_sys {
 		pub fn cv_detail_PlaneWarperGpu_warp_const_GpuMatR_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_int_GpuMatR(instance: *mut c_void, src: *const c_void, k: *const c_void, r: *const c_void, t: *const c_void, interp_mode: i32, border_mode: i32, dst: *mut c_void, ocvrs_return: *mut Result<core::Point>);
 		pub fn cv_detail_ProjectorBase_getPropScale_const(instance: *const c_void) -> f32;
 		pub fn cv_detail_ProjectorBase_setPropScale_float(instance: *mut c_void, val: f32);
-		pub fn cv_detail_ProjectorBase_getPropK(instance: *mut c_void) -> *mut [f32; 9];
-		pub fn cv_detail_ProjectorBase_getPropRinv(instance: *mut c_void) -> *mut [f32; 9];
-		pub fn cv_detail_ProjectorBase_getPropR_kinv(instance: *mut c_void) -> *mut [f32; 9];
-		pub fn cv_detail_ProjectorBase_getPropK_rinv(instance: *mut c_void) -> *mut [f32; 9];
-		pub fn cv_detail_ProjectorBase_getPropT(instance: *mut c_void) -> *mut [f32; 3];
+		pub fn cv_detail_ProjectorBase_getPropK(instance: *mut c_void, ocvrs_return: *mut Result<*mut [f32; 9]>);
+		pub fn cv_detail_ProjectorBase_getPropRinv(instance: *mut c_void, ocvrs_return: *mut Result<*mut [f32; 9]>);
+		pub fn cv_detail_ProjectorBase_getPropR_kinv(instance: *mut c_void, ocvrs_return: *mut Result<*mut [f32; 9]>);
+		pub fn cv_detail_ProjectorBase_getPropK_rinv(instance: *mut c_void, ocvrs_return: *mut Result<*mut [f32; 9]>);
+		pub fn cv_detail_ProjectorBase_getPropT(instance: *mut c_void, ocvrs_return: *mut Result<*mut [f32; 3]>);
 		pub fn cv_detail_ProjectorBase_setCameraParams_const__InputArrayR_const__InputArrayR_const__InputArrayR(instance: *mut c_void, k: *const c_void, r: *const c_void, t: *const c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_detail_RotationWarper_warpPoint_const_Point2fR_const__InputArrayR_const__InputArrayR(instance: *mut c_void, pt: *const core::Point2f, k: *const c_void, r: *const c_void, ocvrs_return: *mut Result<core::Point2f>);
 		pub fn cv_detail_RotationWarper_warpPointBackward_const_Point2fR_const__InputArrayR_const__InputArrayR(instance: *mut c_void, pt: *const core::Point2f, k: *const c_void, r: *const c_void, ocvrs_return: *mut Result<core::Point2f>);
@@ -6818,8 +6818,8 @@ mod text_sys {
 		pub fn cv_text_ERStat_setPropEuler_int(instance: *mut c_void, val: i32);
 		pub fn cv_text_ERStat_getPropRect_const(instance: *const c_void, ocvrs_return: *mut core::Rect);
 		pub fn cv_text_ERStat_setPropRect_Rect(instance: *mut c_void, val: *const core::Rect);
-		pub fn cv_text_ERStat_getPropRaw_moments(instance: *mut c_void) -> *mut [f64; 2];
-		pub fn cv_text_ERStat_getPropCentral_moments(instance: *mut c_void) -> *mut [f64; 3];
+		pub fn cv_text_ERStat_getPropRaw_moments(instance: *mut c_void, ocvrs_return: *mut Result<*mut [f64; 2]>);
+		pub fn cv_text_ERStat_getPropCentral_moments(instance: *mut c_void, ocvrs_return: *mut Result<*mut [f64; 3]>);
 		pub fn cv_text_ERStat_getPropMed_crossings_const(instance: *const c_void) -> f32;
 		pub fn cv_text_ERStat_setPropMed_crossings_float(instance: *mut c_void, val: f32);
 		pub fn cv_text_ERStat_getPropHole_area_ratio_const(instance: *const c_void) -> f32;
@@ -203,7 +203,7 @@ opencv_type_enum! { crate::text::page_seg_mode }
 pub fn mse_rs_to_er_stats(image: &dyn core::ToInputArray, contours: &mut core::Vector<core::Vector<core::Point>>, regions: &mut core::Vector<core::Vector<crate::text::ERStat>>) -> Result<()> {
 	input_array_arg!(image);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_text_MSERsToERStats_const__InputArrayR_vector_vector_Point__R_vector_vector_ERStat__R(image.as_raw__InputArray(), contours.as_raw_mut_VectorOfVectorOfPoint(), regions.as_raw_mut_VectorOfVectorOfERStat(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_text_MSERsToERStats_const__InputArrayR_vector_vector_Point__R_vector_vector_ERStat__R(image.as_raw__InputArray(), contours.as_raw_mut_VectorOfVectorOfPoint(), regions.as_raw_mut_VectorOfVectorOfERStat(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -231,7 +231,7 @@ pub fn compute_nm_channels(_src: &dyn core::ToInputArray, _channels: &mut dyn co
 	input_array_arg!(_src);
 	output_array_arg!(_channels);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_text_computeNMChannels_const__InputArrayR_const__OutputArrayR_int(_src.as_raw__InputArray(), _channels.as_raw__OutputArray(), _mode, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_text_computeNMChannels_const__InputArrayR_const__OutputArrayR_int(_src.as_raw__InputArray(), _channels.as_raw__OutputArray(), _mode, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -268,13 +268,53 @@ pub fn compute_nm_channels(_src: &dyn core::ToInputArray, _channels: &mut dyn co
 #[inline]
 pub fn create_er_filter_nm1(cb: &core::Ptr<dyn crate::text::ERFilter_Callback>, threshold_delta: i32, min_area: f32, max_area: f32, min_probability: f32, non_max_suppression: bool, min_probability_diff: f32) -> Result<core::Ptr<dyn crate::text::ERFilter>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_text_createERFilterNM1_const_Ptr_Callback_R_int_float_float_float_bool_float(cb.as_raw_PtrOfERFilter_Callback(), threshold_delta, min_area, max_area, min_probability, non_max_suppression, min_probability_diff, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_text_createERFilterNM1_const_Ptr_Callback_R_int_float_float_float_bool_float(cb.as_raw_PtrOfERFilter_Callback(), threshold_delta, min_area, max_area, min_probability, non_max_suppression, min_probability_diff, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::text::ERFilter>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_er_filter_nm1()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateErFilterNm1Builder<'a> {
+	cb: &'a core::Ptr<dyn crate::text::ERFilter_Callback>,
+	threshold_delta: i32,
+	min_area: f32,
+	max_area: f32,
+	min_probability: f32,
+	non_max_suppression: bool,
+	min_probability_diff: f32,
+}
+
+impl<'a> CreateErFilterNm1Builder<'a> {
+	#[inline]
+	pub fn new(cb: &'a core::Ptr<dyn crate::text::ERFilter_Callback>, min_area: f32, max_area: f32, min_probability: f32, min_probability_diff: f32) -> Self {
+		Self { cb, threshold_delta: 1, min_area, max_area, min_probability, non_max_suppression: true, min_probability_diff }
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_threshold_delta(mut self, threshold_delta: i32) -> Self {
+		self.threshold_delta = threshold_delta;
+		self
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_non_max_suppression(mut self, non_max_suppression: bool) -> Self {
+		self.non_max_suppression = non_max_suppression;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::text::ERFilter>> {
+		create_er_filter_nm1(self.cb, self.threshold_delta, self.min_area, self.max_area, self.min_probability, self.non_max_suppression, self.min_probability_diff)
+	}
+}
+
 /// Reads an Extremal Region Filter for the 1st stage classifier of N&M algorithm
 ///    from the provided path e.g. /path/to/cpp/trained_classifierNM1.xml
 /// 
@@ -312,13 +352,53 @@ pub fn create_er_filter_nm1(cb: &core::Ptr<dyn crate::text::ERFilter_Callback>,
 pub fn create_er_filter_nm1_from_file(filename: &str, threshold_delta: i32, min_area: f32, max_area: f32, min_probability: f32, non_max_suppression: bool, min_probability_diff: f32) -> Result<core::Ptr<dyn crate::text::ERFilter>> {
 	extern_container_arg!(filename);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_text_createERFilterNM1_const_StringR_int_float_float_float_bool_float(filename.opencv_as_extern(), threshold_delta, min_area, max_area, min_probability, non_max_suppression, min_probability_diff, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_text_createERFilterNM1_const_StringR_int_float_float_float_bool_float(filename.opencv_as_extern(), threshold_delta, min_area, max_area, min_probability, non_max_suppression, min_probability_diff, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::text::ERFilter>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_er_filter_nm1_from_file()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateErFilterNm1FromFileBuilder<'a> {
+	filename: &'a str,
+	threshold_delta: i32,
+	min_area: f32,
+	max_area: f32,
+	min_probability: f32,
+	non_max_suppression: bool,
+	min_probability_diff: f32,
+}
+
+impl<'a> CreateErFilterNm1FromFileBuilder<'a> {
+	#[inline]
+	pub fn new(filename: &'a str, min_area: f32, max_area: f32, min_probability: f32, min_probability_diff: f32) -> Self {
+		Self { filename, threshold_delta: 1, min_area, max_area, min_probability, non_max_suppression: true, min_probability_diff }
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_threshold_delta(mut self, threshold_delta: i32) -> Self {
+		self.threshold_delta = threshold_delta;
+		self
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_non_max_suppression(mut self, non_max_suppression: bool) -> Self {
+		self.non_max_suppression = non_max_suppression;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::text::ERFilter>> {
+		create_er_filter_nm1_from_file(self.filename, self.threshold_delta, self.min_area, self.max_area, self.min_probability, self.non_max_suppression, self.min_probability_diff)
+	}
+}
+
 /// Create an Extremal Region Filter for the 2nd stage classifier of N&M algorithm [Neumann12](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_Neumann12).
 /// 
 /// ## Parameters
@@ -336,7 +416,7 @@ pub fn create_er_filter_nm1_from_file(filename: &str, threshold_delta: i32, min_
 #[inline]
 pub fn create_er_filter_nm2(cb: &core::Ptr<dyn crate::text::ERFilter_Callback>, min_probability: f32) -> Result<core::Ptr<dyn crate::text::ERFilter>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_text_createERFilterNM2_const_Ptr_Callback_R_float(cb.as_raw_PtrOfERFilter_Callback(), min_probability, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_text_createERFilterNM2_const_Ptr_Callback_R_float(cb.as_raw_PtrOfERFilter_Callback(), min_probability, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::text::ERFilter>::opencv_from_extern(ret) };
@@ -366,7 +446,7 @@ pub fn create_er_filter_nm2(cb: &core::Ptr<dyn crate::text::ERFilter_Callback>,
 pub fn create_er_filter_nm2_from_file(filename: &str, min_probability: f32) -> Result<core::Ptr<dyn crate::text::ERFilter>> {
 	extern_container_arg!(filename);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_text_createERFilterNM2_const_StringR_float(filename.opencv_as_extern(), min_probability, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_text_createERFilterNM2_const_StringR_float(filename.opencv_as_extern(), min_probability, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::text::ERFilter>::opencv_from_extern(ret) };
@@ -377,7 +457,7 @@ pub fn create_er_filter_nm2_from_file(filename: &str, min_probability: f32) -> R
 pub fn create_ocrhmm_transitions_table_1(vocabulary: &str, lexicon: &mut core::Vector<String>) -> Result<core::Mat> {
 	extern_container_arg!(vocabulary);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_text_createOCRHMMTransitionsTable_const_StringR_vector_String_R(vocabulary.opencv_as_extern(), lexicon.as_raw_mut_VectorOfString(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_text_createOCRHMMTransitionsTable_const_StringR_vector_String_R(vocabulary.opencv_as_extern(), lexicon.as_raw_mut_VectorOfString(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -403,7 +483,7 @@ pub fn create_ocrhmm_transitions_table(vocabulary: &mut String, lexicon: &mut co
 	string_arg_output_send!(via vocabulary_via);
 	output_array_arg!(transition_probabilities_table);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_text_createOCRHMMTransitionsTable_stringR_vector_string_R_const__OutputArrayR(&mut vocabulary_via, lexicon.as_raw_mut_VectorOfString(), transition_probabilities_table.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_text_createOCRHMMTransitionsTable_stringR_vector_string_R_const__OutputArrayR(&mut vocabulary_via, lexicon.as_raw_mut_VectorOfString(), transition_probabilities_table.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	string_arg_output_receive!(vocabulary_via => vocabulary);
@@ -430,17 +510,50 @@ pub fn detect_regions_from_file(image: &dyn core::ToInputArray, er_filter1: &cor
 	input_array_arg!(image);
 	extern_container_arg!(filename);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_text_detectRegions_const__InputArrayR_const_Ptr_ERFilter_R_const_Ptr_ERFilter_R_vector_Rect_R_int_const_StringR_float(image.as_raw__InputArray(), er_filter1.as_raw_PtrOfERFilter(), er_filter2.as_raw_PtrOfERFilter(), groups_rects.as_raw_mut_VectorOfRect(), method, filename.opencv_as_extern(), min_probability, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_text_detectRegions_const__InputArrayR_const_Ptr_ERFilter_R_const_Ptr_ERFilter_R_vector_Rect_R_int_const_StringR_float(image.as_raw__InputArray(), er_filter1.as_raw_PtrOfERFilter(), er_filter2.as_raw_PtrOfERFilter(), groups_rects.as_raw_mut_VectorOfRect(), method, filename.opencv_as_extern(), min_probability, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `detect_regions_from_file()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct DetectRegionsFromFileBuilder<'a> {
+	image: &'a dyn core::ToInputArray,
+	er_filter1: &'a core::Ptr<dyn crate::text::ERFilter>,
+	er_filter2: &'a core::Ptr<dyn crate::text::ERFilter>,
+	groups_rects: &'a mut core::Vector<core::Rect>,
+	method: i32,
+	filename: &'a str,
+	min_probability: f32,
+}
+
+impl<'a> DetectRegionsFromFileBuilder<'a> {
+	#[inline]
+	pub fn new(image: &'a dyn core::ToInputArray, er_filter1: &'a core::Ptr<dyn crate::text::ERFilter>, er_filter2: &'a core::Ptr<dyn crate::text::ERFilter>, groups_rects: &'a mut core::Vector<core::Rect>, method: i32, min_probability: f32) -> Self {
+		Self { image, er_filter1, er_filter2, groups_rects, method, filename: "", min_probability }
+	}
+	
+	/// C++ default: `String()`
+	#[inline]
+	pub fn with_filename(mut self, filename: &'a str) -> Self {
+		self.filename = filename;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		detect_regions_from_file(self.image, self.er_filter1, self.er_filter2, self.groups_rects, self.method, self.filename, self.min_probability)
+	}
+}
+
 #[inline]
 pub fn detect_regions(image: &dyn core::ToInputArray, er_filter1: &core::Ptr<dyn crate::text::ERFilter>, er_filter2: &core::Ptr<dyn crate::text::ERFilter>, regions: &mut core::Vector<core::Vector<core::Point>>) -> Result<()> {
 	input_array_arg!(image);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_text_detectRegions_const__InputArrayR_const_Ptr_ERFilter_R_const_Ptr_ERFilter_R_vector_vector_Point__R(image.as_raw__InputArray(), er_filter1.as_raw_PtrOfERFilter(), er_filter2.as_raw_PtrOfERFilter(), regions.as_raw_mut_VectorOfVectorOfPoint(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_text_detectRegions_const__InputArrayR_const_Ptr_ERFilter_R_const_Ptr_ERFilter_R_vector_vector_Point__R(image.as_raw__InputArray(), er_filter1.as_raw_PtrOfERFilter(), er_filter2.as_raw_PtrOfERFilter(), regions.as_raw_mut_VectorOfVectorOfPoint(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -463,7 +576,7 @@ pub fn detect_text_swt(input: &dyn core::ToInputArray, result: &mut core::Vector
 	output_array_arg!(draw);
 	output_array_arg!(chain_b_bs);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_text_detectTextSWT_const__InputArrayR_vector_Rect_R_bool_const__OutputArrayR_const__OutputArrayR(input.as_raw__InputArray(), result.as_raw_mut_VectorOfRect(), dark_on_light, draw.as_raw__OutputArray(), chain_b_bs.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_text_detectTextSWT_const__InputArrayR_vector_Rect_R_bool_const__OutputArrayR_const__OutputArrayR(input.as_raw__InputArray(), result.as_raw_mut_VectorOfRect(), dark_on_light, draw.as_raw__OutputArray(), chain_b_bs.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -503,12 +616,53 @@ pub fn er_grouping(img: &dyn core::ToInputArray, channels: &dyn core::ToInputArr
 	input_array_arg!(channels);
 	extern_container_arg!(filename);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_text_erGrouping_const__InputArrayR_const__InputArrayR_vector_vector_ERStat__R_vector_vector_Vec2i__R_vector_Rect_R_int_const_stringR_float(img.as_raw__InputArray(), channels.as_raw__InputArray(), regions.as_raw_mut_VectorOfVectorOfERStat(), groups.as_raw_mut_VectorOfVectorOfVec2i(), groups_rects.as_raw_mut_VectorOfRect(), method, filename.opencv_as_extern(), min_probablity, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_text_erGrouping_const__InputArrayR_const__InputArrayR_vector_vector_ERStat__R_vector_vector_Vec2i__R_vector_Rect_R_int_const_stringR_float(img.as_raw__InputArray(), channels.as_raw__InputArray(), regions.as_raw_mut_VectorOfVectorOfERStat(), groups.as_raw_mut_VectorOfVectorOfVec2i(), groups_rects.as_raw_mut_VectorOfRect(), method, filename.opencv_as_extern(), min_probablity, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `er_grouping()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct ErGroupingBuilder<'a> {
+	img: &'a dyn core::ToInputArray,
+	channels: &'a dyn core::ToInputArray,
+	regions: &'a mut core::Vector<core::Vector<crate::text::ERStat>>,
+	groups: &'a mut core::Vector<core::Vector<core::Vec2i>>,
+	groups_rects: &'a mut core::Vector<core::Rect>,
+	method: i32,
+	filename: &'a str,
+	min_probablity: f32,
+}
+
+impl<'a> ErGroupingBuilder<'a> {
+	#[inline]
+	pub fn new(img: &'a dyn core::ToInputArray, channels: &'a dyn core::ToInputArray, regions: &'a mut core::Vector<core::Vector<crate::text::ERStat>>, groups: &'a mut core::Vector<core::Vector<core::Vec2i>>, groups_rects: &'a mut core::Vector<core::Rect>, method: i32) -> Self {
+		Self { img, channels, regions, groups, groups_rects, method, filename: "", min_probablity: 0.5 }
+	}
+	
+	/// C++ default: `std::string()`
+	#[inline]
+	pub fn with_filename(mut self, filename: &'a str) -> Self {
+		self.filename = filename;
+		self
+	}
+	
+	/// C++ default: `0.5`
+	#[inline]
+	pub fn with_min_probablity(mut self, min_probablity: f32) -> Self {
+		self.min_probablity = min_probablity;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		er_grouping(self.img, self.channels, self.regions, self.groups, self.groups_rects, self.method, self.filename, self.min_probablity)
+	}
+}
+
 /// ## C++ default parameters
 /// * method: ERGROUPING_ORIENTATION_HORIZ
 /// * filename: String()
@@ -519,12 +673,45 @@ pub fn er_grouping_1(image: &dyn core::ToInputArray, channel: &dyn core::ToInput
 	input_array_arg!(channel);
 	extern_container_arg!(filename);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_text_erGrouping_const__InputArrayR_const__InputArrayR_vector_vector_Point___vector_Rect_R_int_const_StringR_float(image.as_raw__InputArray(), channel.as_raw__InputArray(), regions.as_raw_mut_VectorOfVectorOfPoint(), groups_rects.as_raw_mut_VectorOfRect(), method, filename.opencv_as_extern(), min_probablity, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_text_erGrouping_const__InputArrayR_const__InputArrayR_vector_vector_Point___vector_Rect_R_int_const_StringR_float(image.as_raw__InputArray(), channel.as_raw__InputArray(), regions.as_raw_mut_VectorOfVectorOfPoint(), groups_rects.as_raw_mut_VectorOfRect(), method, filename.opencv_as_extern(), min_probablity, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `er_grouping_1()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct ErGrouping1Builder<'a> {
+	image: &'a dyn core::ToInputArray,
+	channel: &'a dyn core::ToInputArray,
+	regions: core::Vector<core::Vector<core::Point>>,
+	groups_rects: &'a mut core::Vector<core::Rect>,
+	method: i32,
+	filename: &'a str,
+	min_probablity: f32,
+}
+
+impl<'a> ErGrouping1Builder<'a> {
+	#[inline]
+	pub fn new(image: &'a dyn core::ToInputArray, channel: &'a dyn core::ToInputArray, regions: core::Vector<core::Vector<core::Point>>, groups_rects: &'a mut core::Vector<core::Rect>, method: i32, min_probablity: f32) -> Self {
+		Self { image, channel, regions, groups_rects, method, filename: "", min_probablity }
+	}
+	
+	/// C++ default: `String()`
+	#[inline]
+	pub fn with_filename(mut self, filename: &'a str) -> Self {
+		self.filename = filename;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		er_grouping_1(self.image, self.channel, self.regions, self.groups_rects, self.method, self.filename, self.min_probablity)
+	}
+}
+
 /// Allow to implicitly load the default classifier when creating an ERFilter object.
 /// 
 /// ## Parameters
@@ -535,7 +722,7 @@ pub fn er_grouping_1(image: &dyn core::ToInputArray, channel: &dyn core::ToInput
 pub fn load_classifier_nm1(filename: &str) -> Result<core::Ptr<dyn crate::text::ERFilter_Callback>> {
 	extern_container_arg!(filename);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_text_loadClassifierNM1_const_StringR(filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_text_loadClassifierNM1_const_StringR(filename.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::text::ERFilter_Callback>::opencv_from_extern(ret) };
@@ -552,7 +739,7 @@ pub fn load_classifier_nm1(filename: &str) -> Result<core::Ptr<dyn crate::text::
 pub fn load_classifier_nm2(filename: &str) -> Result<core::Ptr<dyn crate::text::ERFilter_Callback>> {
 	extern_container_arg!(filename);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_text_loadClassifierNM2_const_StringR(filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_text_loadClassifierNM2_const_StringR(filename.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::text::ERFilter_Callback>::opencv_from_extern(ret) };
@@ -565,14 +752,14 @@ pub fn load_classifier_nm2(filename: &str) -> Result<core::Ptr<dyn crate::text::
 /// * filename: The XML or YAML file with the classifier model (e.g. OCRBeamSearch_CNN_model_data.xml.gz)
 /// 
 /// The CNN default classifier is based in the scene text recognition method proposed by Adam Coates &
-/// Andrew NG in [Coates11a]. The character classifier consists in a Single Layer Convolutional Neural Network and
+/// Andrew NG in \[Coates11a\]. The character classifier consists in a Single Layer Convolutional Neural Network and
 /// a linear classifier. It is applied to the input image in a sliding window fashion, providing a set of recognitions
 /// at each window location.
 #[inline]
 pub fn load_ocr_beam_search_classifier_cnn(filename: &str) -> Result<core::Ptr<crate::text::OCRBeamSearchDecoder_ClassifierCallback>> {
 	extern_container_arg!(filename);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_text_loadOCRBeamSearchClassifierCNN_const_StringR(filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_text_loadOCRBeamSearchClassifierCNN_const_StringR(filename.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<crate::text::OCRBeamSearchDecoder_ClassifierCallback>::opencv_from_extern(ret) };
@@ -585,7 +772,7 @@ pub fn load_ocr_beam_search_classifier_cnn(filename: &str) -> Result<core::Ptr<c
 /// * filename: The XML or YAML file with the classifier model (e.g. OCRBeamSearch_CNN_model_data.xml.gz)
 /// 
 /// The CNN default classifier is based in the scene text recognition method proposed by Adam Coates &
-/// Andrew NG in [Coates11a]. The character classifier consists in a Single Layer Convolutional Neural Network and
+/// Andrew NG in \[Coates11a\]. The character classifier consists in a Single Layer Convolutional Neural Network and
 /// a linear classifier. It is applied to the input image in a sliding window fashion, providing a set of recognitions
 /// at each window location.
 /// 
@@ -596,7 +783,7 @@ pub fn load_ocr_beam_search_classifier_cnn(filename: &str) -> Result<core::Ptr<c
 pub fn load_ocrhmm_classifier_cnn(filename: &str) -> Result<core::Ptr<crate::text::OCRHMMDecoder_ClassifierCallback>> {
 	extern_container_arg!(filename);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_text_loadOCRHMMClassifierCNN_const_StringR(filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_text_loadOCRHMMClassifierCNN_const_StringR(filename.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<crate::text::OCRHMMDecoder_ClassifierCallback>::opencv_from_extern(ret) };
@@ -609,7 +796,7 @@ pub fn load_ocrhmm_classifier_cnn(filename: &str) -> Result<core::Ptr<crate::tex
 /// * filename: The XML or YAML file with the classifier model (e.g. OCRHMM_knn_model_data.xml)
 /// 
 /// The KNN default classifier is based in the scene text recognition method proposed by Lukás Neumann &
-/// Jiri Matas in [Neumann11b]. Basically, the region (contour) in the input image is normalized to a
+/// Jiri Matas in \[Neumann11b\]. Basically, the region (contour) in the input image is normalized to a
 /// fixed size, while retaining the centroid and aspect ratio, in order to extract a feature vector
 /// based on gradient orientations along the chain-code of its perimeter. Then, the region is classified
 /// using a KNN model trained with synthetic data of rendered characters with different standard font
@@ -622,7 +809,7 @@ pub fn load_ocrhmm_classifier_cnn(filename: &str) -> Result<core::Ptr<crate::tex
 pub fn load_ocrhmm_classifier_nm(filename: &str) -> Result<core::Ptr<crate::text::OCRHMMDecoder_ClassifierCallback>> {
 	extern_container_arg!(filename);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_text_loadOCRHMMClassifierNM_const_StringR(filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_text_loadOCRHMMClassifierNM_const_StringR(filename.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<crate::text::OCRHMMDecoder_ClassifierCallback>::opencv_from_extern(ret) };
@@ -639,7 +826,7 @@ pub fn load_ocrhmm_classifier_nm(filename: &str) -> Result<core::Ptr<crate::text
 pub fn load_ocrhmm_classifier(filename: &str, classifier: i32) -> Result<core::Ptr<crate::text::OCRHMMDecoder_ClassifierCallback>> {
 	extern_container_arg!(filename);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_text_loadOCRHMMClassifier_const_StringR_int(filename.opencv_as_extern(), classifier, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_text_loadOCRHMMClassifier_const_StringR_int(filename.opencv_as_extern(), classifier, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<crate::text::OCRHMMDecoder_ClassifierCallback>::opencv_from_extern(ret) };
@@ -661,9 +848,10 @@ pub trait BaseOCR: crate::text::BaseOCRConst {
 	/// * component_level: 0
 	#[inline]
 	fn run(&mut self, image: &mut core::Mat, output_text: &mut String, component_rects: &mut core::Vector<core::Rect>, component_texts: &mut core::Vector<String>, component_confidences: &mut core::Vector<f32>, component_level: i32) -> Result<()> {
+		crate::core::validity::check("BaseOCR", self.as_raw_mut_BaseOCR())?;
 		string_arg_output_send!(via output_text_via);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_BaseOCR_run_MatR_stringR_vector_Rect_X_vector_string_X_vector_float_X_int(self.as_raw_mut_BaseOCR(), image.as_raw_mut_Mat(), &mut output_text_via, component_rects.as_raw_mut_VectorOfRect(), component_texts.as_raw_mut_VectorOfString(), component_confidences.as_raw_mut_VectorOff32(), component_level, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_BaseOCR_run_MatR_stringR_vector_Rect_X_vector_string_X_vector_float_X_int(self.as_raw_mut_BaseOCR(), image.as_raw_mut_Mat(), &mut output_text_via, component_rects.as_raw_mut_VectorOfRect(), component_texts.as_raw_mut_VectorOfString(), component_confidences.as_raw_mut_VectorOff32(), component_level, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		string_arg_output_receive!(output_text_via => output_text);
@@ -677,9 +865,10 @@ pub trait BaseOCR: crate::text::BaseOCRConst {
 	/// * component_level: 0
 	#[inline]
 	fn run_mask(&mut self, image: &mut core::Mat, mask: &mut core::Mat, output_text: &mut String, component_rects: &mut core::Vector<core::Rect>, component_texts: &mut core::Vector<String>, component_confidences: &mut core::Vector<f32>, component_level: i32) -> Result<()> {
+		crate::core::validity::check("BaseOCR", self.as_raw_mut_BaseOCR())?;
 		string_arg_output_send!(via output_text_via);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_BaseOCR_run_MatR_MatR_stringR_vector_Rect_X_vector_string_X_vector_float_X_int(self.as_raw_mut_BaseOCR(), image.as_raw_mut_Mat(), mask.as_raw_mut_Mat(), &mut output_text_via, component_rects.as_raw_mut_VectorOfRect(), component_texts.as_raw_mut_VectorOfString(), component_confidences.as_raw_mut_VectorOff32(), component_level, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_BaseOCR_run_MatR_MatR_stringR_vector_Rect_X_vector_string_X_vector_float_X_int(self.as_raw_mut_BaseOCR(), image.as_raw_mut_Mat(), mask.as_raw_mut_Mat(), &mut output_text_via, component_rects.as_raw_mut_VectorOfRect(), component_texts.as_raw_mut_VectorOfString(), component_confidences.as_raw_mut_VectorOff32(), component_level, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		string_arg_output_receive!(output_text_via => output_text);
@@ -688,6 +877,73 @@ pub trait BaseOCR: crate::text::BaseOCRConst {
 	
 }
 
+/// Builder for `BaseOCR::run()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct BaseOCR_RunBuilder<'a, T: ?Sized + BaseOCR> {
+	instance: &'a mut T,
+	image: &'a mut core::Mat,
+	output_text: &'a mut String,
+	component_rects: &'a mut core::Vector<core::Rect>,
+	component_texts: &'a mut core::Vector<String>,
+	component_confidences: &'a mut core::Vector<f32>,
+	component_level: i32,
+}
+
+impl<'a, T: ?Sized + BaseOCR> BaseOCR_RunBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, image: &'a mut core::Mat, output_text: &'a mut String, component_rects: &'a mut core::Vector<core::Rect>, component_texts: &'a mut core::Vector<String>, component_confidences: &'a mut core::Vector<f32>) -> Self {
+		Self { instance, image, output_text, component_rects, component_texts, component_confidences, component_level: 0 }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_component_level(mut self, component_level: i32) -> Self {
+		self.component_level = component_level;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		BaseOCR::run(self.instance, self.image, self.output_text, self.component_rects, self.component_texts, self.component_confidences, self.component_level)
+	}
+}
+
+/// Builder for `BaseOCR::run_mask()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct BaseOCR_RunMaskBuilder<'a, T: ?Sized + BaseOCR> {
+	instance: &'a mut T,
+	image: &'a mut core::Mat,
+	mask: &'a mut core::Mat,
+	output_text: &'a mut String,
+	component_rects: &'a mut core::Vector<core::Rect>,
+	component_texts: &'a mut core::Vector<String>,
+	component_confidences: &'a mut core::Vector<f32>,
+	component_level: i32,
+}
+
+impl<'a, T: ?Sized + BaseOCR> BaseOCR_RunMaskBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, image: &'a mut core::Mat, mask: &'a mut core::Mat, output_text: &'a mut String, component_rects: &'a mut core::Vector<core::Rect>, component_texts: &'a mut core::Vector<String>, component_confidences: &'a mut core::Vector<f32>) -> Self {
+		Self { instance, image, mask, output_text, component_rects, component_texts, component_confidences, component_level: 0 }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_component_level(mut self, component_level: i32) -> Self {
+		self.component_level = component_level;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		BaseOCR::run_mask(self.instance, self.image, self.mask, self.output_text, self.component_rects, self.component_texts, self.component_confidences, self.component_level)
+	}
+}
+
 /// Base class for 1st and 2nd stages of Neumann and Matas scene text detection algorithm [Neumann12](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_Neumann12). :
 /// 
 /// Extracts the component tree (if needed) and filter the extremal regions (ER's) by using a given classifier.
@@ -696,8 +952,9 @@ pub trait ERFilterConst: core::AlgorithmTraitConst {
 
 	#[inline]
 	fn get_num_rejected(&self) -> Result<i32> {
+		crate::core::validity::check("ERFilter", self.as_raw_ERFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_ERFilter_getNumRejected_const(self.as_raw_ERFilter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_ERFilter_getNumRejected_const(self.as_raw_ERFilter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -723,9 +980,10 @@ pub trait ERFilter: core::AlgorithmTrait + crate::text::ERFilterConst {
 	/// classifier.
 	#[inline]
 	fn run(&mut self, image: &dyn core::ToInputArray, regions: &mut core::Vector<crate::text::ERStat>) -> Result<()> {
+		crate::core::validity::check("ERFilter", self.as_raw_mut_ERFilter())?;
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_ERFilter_run_const__InputArrayR_vector_ERStat_R(self.as_raw_mut_ERFilter(), image.as_raw__InputArray(), regions.as_raw_mut_VectorOfERStat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_ERFilter_run_const__InputArrayR_vector_ERStat_R(self.as_raw_mut_ERFilter(), image.as_raw__InputArray(), regions.as_raw_mut_VectorOfERStat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -734,8 +992,9 @@ pub trait ERFilter: core::AlgorithmTrait + crate::text::ERFilterConst {
 	/// set/get methods to set the algorithm properties,
 	#[inline]
 	fn set_callback(&mut self, cb: &core::Ptr<dyn crate::text::ERFilter_Callback>) -> Result<()> {
+		crate::core::validity::check("ERFilter", self.as_raw_mut_ERFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_ERFilter_setCallback_const_Ptr_Callback_R(self.as_raw_mut_ERFilter(), cb.as_raw_PtrOfERFilter_Callback(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_ERFilter_setCallback_const_Ptr_Callback_R(self.as_raw_mut_ERFilter(), cb.as_raw_PtrOfERFilter_Callback(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -743,8 +1002,9 @@ pub trait ERFilter: core::AlgorithmTrait + crate::text::ERFilterConst {
 	
 	#[inline]
 	fn set_threshold_delta(&mut self, threshold_delta: i32) -> Result<()> {
+		crate::core::validity::check("ERFilter", self.as_raw_mut_ERFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_ERFilter_setThresholdDelta_int(self.as_raw_mut_ERFilter(), threshold_delta, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_ERFilter_setThresholdDelta_int(self.as_raw_mut_ERFilter(), threshold_delta, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -752,8 +1012,9 @@ pub trait ERFilter: core::AlgorithmTrait + crate::text::ERFilterConst {
 	
 	#[inline]
 	fn set_min_area(&mut self, min_area: f32) -> Result<()> {
+		crate::core::validity::check("ERFilter", self.as_raw_mut_ERFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_ERFilter_setMinArea_float(self.as_raw_mut_ERFilter(), min_area, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_ERFilter_setMinArea_float(self.as_raw_mut_ERFilter(), min_area, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -761,8 +1022,9 @@ pub trait ERFilter: core::AlgorithmTrait + crate::text::ERFilterConst {
 	
 	#[inline]
 	fn set_max_area(&mut self, max_area: f32) -> Result<()> {
+		crate::core::validity::check("ERFilter", self.as_raw_mut_ERFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_ERFilter_setMaxArea_float(self.as_raw_mut_ERFilter(), max_area, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_ERFilter_setMaxArea_float(self.as_raw_mut_ERFilter(), max_area, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -770,8 +1032,9 @@ pub trait ERFilter: core::AlgorithmTrait + crate::text::ERFilterConst {
 	
 	#[inline]
 	fn set_min_probability(&mut self, min_probability: f32) -> Result<()> {
+		crate::core::validity::check("ERFilter", self.as_raw_mut_ERFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_ERFilter_setMinProbability_float(self.as_raw_mut_ERFilter(), min_probability, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_ERFilter_setMinProbability_float(self.as_raw_mut_ERFilter(), min_probability, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -779,8 +1042,9 @@ pub trait ERFilter: core::AlgorithmTrait + crate::text::ERFilterConst {
 	
 	#[inline]
 	fn set_min_probability_diff(&mut self, min_probability_diff: f32) -> Result<()> {
+		crate::core::validity::check("ERFilter", self.as_raw_mut_ERFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_ERFilter_setMinProbabilityDiff_float(self.as_raw_mut_ERFilter(), min_probability_diff, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_ERFilter_setMinProbabilityDiff_float(self.as_raw_mut_ERFilter(), min_probability_diff, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -788,8 +1052,9 @@ pub trait ERFilter: core::AlgorithmTrait + crate::text::ERFilterConst {
 	
 	#[inline]
 	fn set_non_max_suppression(&mut self, non_max_suppression: bool) -> Result<()> {
+		crate::core::validity::check("ERFilter", self.as_raw_mut_ERFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_ERFilter_setNonMaxSuppression_bool(self.as_raw_mut_ERFilter(), non_max_suppression, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_ERFilter_setNonMaxSuppression_bool(self.as_raw_mut_ERFilter(), non_max_suppression, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -815,8 +1080,9 @@ pub trait ERFilter_Callback: crate::text::ERFilter_CallbackConst {
 	/// * stat: :   The region to be classified
 	#[inline]
 	fn eval(&mut self, stat: &crate::text::ERStat) -> Result<f64> {
+		crate::core::validity::check("ERFilter_Callback", self.as_raw_mut_ERFilter_Callback())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_ERFilter_Callback_eval_const_ERStatR(self.as_raw_mut_ERFilter_Callback(), stat.as_raw_ERStat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_ERFilter_Callback_eval_const_ERStatR(self.as_raw_mut_ERFilter_Callback(), stat.as_raw_ERStat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -835,84 +1101,192 @@ pub trait ERStatTraitConst {
 	/// seed point and the threshold (max grey-level value)
 	#[inline]
 	fn pixel(&self) -> i32 {
-		let ret = unsafe { sys::cv_text_ERStat_getPropPixel_const(self.as_raw_ERStat()) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_getPropPixel_const(self.as_raw_ERStat())) };
 		ret
 	}
 	
+	/// Non-panicking version of [pixel](ERStatTraitConst::pixel), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pixel(&self) -> Result<i32> {
+		crate::core::validity::check("ERStat", self.as_raw_ERStat())?;
+		Ok(ERStatTraitConst::pixel(self))
+	}
+	
 	#[inline]
 	fn level(&self) -> i32 {
-		let ret = unsafe { sys::cv_text_ERStat_getPropLevel_const(self.as_raw_ERStat()) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_getPropLevel_const(self.as_raw_ERStat())) };
 		ret
 	}
 	
+	/// Non-panicking version of [level](ERStatTraitConst::level), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_level(&self) -> Result<i32> {
+		crate::core::validity::check("ERStat", self.as_raw_ERStat())?;
+		Ok(ERStatTraitConst::level(self))
+	}
+	
 	/// incrementally computable features
 	#[inline]
 	fn area(&self) -> i32 {
-		let ret = unsafe { sys::cv_text_ERStat_getPropArea_const(self.as_raw_ERStat()) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_getPropArea_const(self.as_raw_ERStat())) };
 		ret
 	}
 	
+	/// Non-panicking version of [area](ERStatTraitConst::area), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_area(&self) -> Result<i32> {
+		crate::core::validity::check("ERStat", self.as_raw_ERStat())?;
+		Ok(ERStatTraitConst::area(self))
+	}
+	
 	#[inline]
 	fn perimeter(&self) -> i32 {
-		let ret = unsafe { sys::cv_text_ERStat_getPropPerimeter_const(self.as_raw_ERStat()) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_getPropPerimeter_const(self.as_raw_ERStat())) };
 		ret
 	}
 	
+	/// Non-panicking version of [perimeter](ERStatTraitConst::perimeter), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_perimeter(&self) -> Result<i32> {
+		crate::core::validity::check("ERStat", self.as_raw_ERStat())?;
+		Ok(ERStatTraitConst::perimeter(self))
+	}
+	
 	/// Euler's number
 	#[inline]
 	fn euler(&self) -> i32 {
-		let ret = unsafe { sys::cv_text_ERStat_getPropEuler_const(self.as_raw_ERStat()) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_getPropEuler_const(self.as_raw_ERStat())) };
 		ret
 	}
 	
+	/// Non-panicking version of [euler](ERStatTraitConst::euler), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_euler(&self) -> Result<i32> {
+		crate::core::validity::check("ERStat", self.as_raw_ERStat())?;
+		Ok(ERStatTraitConst::euler(self))
+	}
+	
 	#[inline]
 	fn rect(&self) -> core::Rect {
+		crate::core::validity::debug_check("ERStat", self.as_raw_ERStat());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_ERStat_getPropRect_const(self.as_raw_ERStat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_ERStat_getPropRect_const(self.as_raw_ERStat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [rect](ERStatTraitConst::rect), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_rect(&self) -> Result<core::Rect> {
+		crate::core::validity::check("ERStat", self.as_raw_ERStat())?;
+		Ok(ERStatTraitConst::rect(self))
+	}
+	
 	/// median of the crossings at three different height levels
 	#[inline]
 	fn med_crossings(&self) -> f32 {
-		let ret = unsafe { sys::cv_text_ERStat_getPropMed_crossings_const(self.as_raw_ERStat()) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_getPropMed_crossings_const(self.as_raw_ERStat())) };
 		ret
 	}
 	
+	/// Non-panicking version of [med_crossings](ERStatTraitConst::med_crossings), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_med_crossings(&self) -> Result<f32> {
+		crate::core::validity::check("ERStat", self.as_raw_ERStat())?;
+		Ok(ERStatTraitConst::med_crossings(self))
+	}
+	
 	/// 2nd stage features
 	#[inline]
 	fn hole_area_ratio(&self) -> f32 {
-		let ret = unsafe { sys::cv_text_ERStat_getPropHole_area_ratio_const(self.as_raw_ERStat()) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_getPropHole_area_ratio_const(self.as_raw_ERStat())) };
 		ret
 	}
 	
+	/// Non-panicking version of [hole_area_ratio](ERStatTraitConst::hole_area_ratio), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_hole_area_ratio(&self) -> Result<f32> {
+		crate::core::validity::check("ERStat", self.as_raw_ERStat())?;
+		Ok(ERStatTraitConst::hole_area_ratio(self))
+	}
+	
 	#[inline]
 	fn convex_hull_ratio(&self) -> f32 {
-		let ret = unsafe { sys::cv_text_ERStat_getPropConvex_hull_ratio_const(self.as_raw_ERStat()) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_getPropConvex_hull_ratio_const(self.as_raw_ERStat())) };
 		ret
 	}
 	
+	/// Non-panicking version of [convex_hull_ratio](ERStatTraitConst::convex_hull_ratio), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_convex_hull_ratio(&self) -> Result<f32> {
+		crate::core::validity::check("ERStat", self.as_raw_ERStat())?;
+		Ok(ERStatTraitConst::convex_hull_ratio(self))
+	}
+	
 	#[inline]
 	fn num_inflexion_points(&self) -> f32 {
-		let ret = unsafe { sys::cv_text_ERStat_getPropNum_inflexion_points_const(self.as_raw_ERStat()) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_getPropNum_inflexion_points_const(self.as_raw_ERStat())) };
 		ret
 	}
 	
+	/// Non-panicking version of [num_inflexion_points](ERStatTraitConst::num_inflexion_points), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_num_inflexion_points(&self) -> Result<f32> {
+		crate::core::validity::check("ERStat", self.as_raw_ERStat())?;
+		Ok(ERStatTraitConst::num_inflexion_points(self))
+	}
+	
 	/// probability that the ER belongs to the class we are looking for
 	#[inline]
 	fn probability(&self) -> f64 {
-		let ret = unsafe { sys::cv_text_ERStat_getPropProbability_const(self.as_raw_ERStat()) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_getPropProbability_const(self.as_raw_ERStat())) };
 		ret
 	}
 	
+	/// Non-panicking version of [probability](ERStatTraitConst::probability), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_probability(&self) -> Result<f64> {
+		crate::core::validity::check("ERStat", self.as_raw_ERStat())?;
+		Ok(ERStatTraitConst::probability(self))
+	}
+	
 	/// whenever the regions is a local maxima of the probability
 	#[inline]
 	fn local_maxima(&self) -> bool {
-		let ret = unsafe { sys::cv_text_ERStat_getPropLocal_maxima_const(self.as_raw_ERStat()) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_getPropLocal_maxima_const(self.as_raw_ERStat())) };
 		ret
 	}
 	
+	/// Non-panicking version of [local_maxima](ERStatTraitConst::local_maxima), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_local_maxima(&self) -> Result<bool> {
+		crate::core::validity::check("ERStat", self.as_raw_ERStat())?;
+		Ok(ERStatTraitConst::local_maxima(self))
+	}
+	
 }
 
 pub trait ERStatTrait: crate::text::ERStatTraitConst {
@@ -921,175 +1295,255 @@ pub trait ERStatTrait: crate::text::ERStatTraitConst {
 	/// seed point and the threshold (max grey-level value)
 	#[inline]
 	fn set_pixel(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_text_ERStat_setPropPixel_int(self.as_raw_mut_ERStat(), val) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_mut_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_setPropPixel_int(self.as_raw_mut_ERStat(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_level(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_text_ERStat_setPropLevel_int(self.as_raw_mut_ERStat(), val) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_mut_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_setPropLevel_int(self.as_raw_mut_ERStat(), val)) };
 		ret
 	}
 	
 	/// incrementally computable features
 	#[inline]
 	fn set_area(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_text_ERStat_setPropArea_int(self.as_raw_mut_ERStat(), val) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_mut_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_setPropArea_int(self.as_raw_mut_ERStat(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_perimeter(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_text_ERStat_setPropPerimeter_int(self.as_raw_mut_ERStat(), val) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_mut_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_setPropPerimeter_int(self.as_raw_mut_ERStat(), val)) };
 		ret
 	}
 	
 	/// Euler's number
 	#[inline]
 	fn set_euler(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_text_ERStat_setPropEuler_int(self.as_raw_mut_ERStat(), val) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_mut_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_setPropEuler_int(self.as_raw_mut_ERStat(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_rect(&mut self, val: core::Rect) {
-		let ret = unsafe { sys::cv_text_ERStat_setPropRect_Rect(self.as_raw_mut_ERStat(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_mut_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_setPropRect_Rect(self.as_raw_mut_ERStat(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	/// order 1 raw moments to derive the centroid
 	#[inline]
-	fn raw_moments(&mut self) -> &mut [f64; 2] {
-		let ret = unsafe { sys::cv_text_ERStat_getPropRaw_moments(self.as_raw_mut_ERStat()) };
-		let ret = unsafe { ret.as_mut() }.expect("Function returned null pointer");
-		ret
+	fn raw_moments(&mut self) -> Result<&mut [f64; 2]> {
+		crate::core::validity::check("ERStat", self.as_raw_mut_ERStat())?;
+		return_send!(via ocvrs_return);
+		unsafe { ffi_call!(cv_text_ERStat_getPropRaw_moments(self.as_raw_mut_ERStat(), ocvrs_return.as_mut_ptr())) };
+		return_receive!(unsafe ocvrs_return => ret);
+		let ret = ret.into_result()?;
+		let ret = unsafe { ret.as_mut() }.ok_or_else(|| Error::new(core::StsNullPtr, "Function returned null pointer"))?;
+		Ok(ret)
 	}
 	
 	/// order 2 central moments to construct the covariance matrix
 	#[inline]
-	fn central_moments(&mut self) -> &mut [f64; 3] {
-		let ret = unsafe { sys::cv_text_ERStat_getPropCentral_moments(self.as_raw_mut_ERStat()) };
-		let ret = unsafe { ret.as_mut() }.expect("Function returned null pointer");
-		ret
+	fn central_moments(&mut self) -> Result<&mut [f64; 3]> {
+		crate::core::validity::check("ERStat", self.as_raw_mut_ERStat())?;
+		return_send!(via ocvrs_return);
+		unsafe { ffi_call!(cv_text_ERStat_getPropCentral_moments(self.as_raw_mut_ERStat(), ocvrs_return.as_mut_ptr())) };
+		return_receive!(unsafe ocvrs_return => ret);
+		let ret = ret.into_result()?;
+		let ret = unsafe { ret.as_mut() }.ok_or_else(|| Error::new(core::StsNullPtr, "Function returned null pointer"))?;
+		Ok(ret)
 	}
 	
 	/// median of the crossings at three different height levels
 	#[inline]
 	fn set_med_crossings(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_text_ERStat_setPropMed_crossings_float(self.as_raw_mut_ERStat(), val) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_mut_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_setPropMed_crossings_float(self.as_raw_mut_ERStat(), val)) };
 		ret
 	}
 	
 	/// 2nd stage features
 	#[inline]
 	fn set_hole_area_ratio(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_text_ERStat_setPropHole_area_ratio_float(self.as_raw_mut_ERStat(), val) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_mut_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_setPropHole_area_ratio_float(self.as_raw_mut_ERStat(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_convex_hull_ratio(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_text_ERStat_setPropConvex_hull_ratio_float(self.as_raw_mut_ERStat(), val) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_mut_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_setPropConvex_hull_ratio_float(self.as_raw_mut_ERStat(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_num_inflexion_points(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_text_ERStat_setPropNum_inflexion_points_float(self.as_raw_mut_ERStat(), val) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_mut_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_setPropNum_inflexion_points_float(self.as_raw_mut_ERStat(), val)) };
 		ret
 	}
 	
 	/// probability that the ER belongs to the class we are looking for
 	#[inline]
 	fn set_probability(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_text_ERStat_setPropProbability_double(self.as_raw_mut_ERStat(), val) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_mut_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_setPropProbability_double(self.as_raw_mut_ERStat(), val)) };
 		ret
 	}
 	
 	/// pointers preserving the tree structure of the component tree
 	#[inline]
 	fn parent(&mut self) -> crate::text::ERStat {
-		let ret = unsafe { sys::cv_text_ERStat_getPropParent(self.as_raw_mut_ERStat()) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_mut_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_getPropParent(self.as_raw_mut_ERStat())) };
 		let ret = unsafe { crate::text::ERStat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [parent](ERStatTrait::parent), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_parent(&mut self) -> Result<crate::text::ERStat> {
+		crate::core::validity::check("ERStat", self.as_raw_mut_ERStat())?;
+		Ok(ERStatTrait::parent(self))
+	}
+	
 	/// pointers preserving the tree structure of the component tree
 	#[inline]
 	fn set_parent(&mut self, val: &mut crate::text::ERStat) {
-		let ret = unsafe { sys::cv_text_ERStat_setPropParent_ERStatX(self.as_raw_mut_ERStat(), val.as_raw_mut_ERStat()) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_mut_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_setPropParent_ERStatX(self.as_raw_mut_ERStat(), val.as_raw_mut_ERStat())) };
 		ret
 	}
 	
 	#[inline]
 	fn child(&mut self) -> crate::text::ERStat {
-		let ret = unsafe { sys::cv_text_ERStat_getPropChild(self.as_raw_mut_ERStat()) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_mut_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_getPropChild(self.as_raw_mut_ERStat())) };
 		let ret = unsafe { crate::text::ERStat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [child](ERStatTrait::child), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_child(&mut self) -> Result<crate::text::ERStat> {
+		crate::core::validity::check("ERStat", self.as_raw_mut_ERStat())?;
+		Ok(ERStatTrait::child(self))
+	}
+	
 	#[inline]
 	fn set_child(&mut self, val: &mut crate::text::ERStat) {
-		let ret = unsafe { sys::cv_text_ERStat_setPropChild_ERStatX(self.as_raw_mut_ERStat(), val.as_raw_mut_ERStat()) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_mut_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_setPropChild_ERStatX(self.as_raw_mut_ERStat(), val.as_raw_mut_ERStat())) };
 		ret
 	}
 	
 	#[inline]
 	fn next(&mut self) -> crate::text::ERStat {
-		let ret = unsafe { sys::cv_text_ERStat_getPropNext(self.as_raw_mut_ERStat()) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_mut_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_getPropNext(self.as_raw_mut_ERStat())) };
 		let ret = unsafe { crate::text::ERStat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [next](ERStatTrait::next), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_next(&mut self) -> Result<crate::text::ERStat> {
+		crate::core::validity::check("ERStat", self.as_raw_mut_ERStat())?;
+		Ok(ERStatTrait::next(self))
+	}
+	
 	#[inline]
 	fn set_next(&mut self, val: &mut crate::text::ERStat) {
-		let ret = unsafe { sys::cv_text_ERStat_setPropNext_ERStatX(self.as_raw_mut_ERStat(), val.as_raw_mut_ERStat()) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_mut_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_setPropNext_ERStatX(self.as_raw_mut_ERStat(), val.as_raw_mut_ERStat())) };
 		ret
 	}
 	
 	#[inline]
 	fn prev(&mut self) -> crate::text::ERStat {
-		let ret = unsafe { sys::cv_text_ERStat_getPropPrev(self.as_raw_mut_ERStat()) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_mut_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_getPropPrev(self.as_raw_mut_ERStat())) };
 		let ret = unsafe { crate::text::ERStat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [prev](ERStatTrait::prev), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_prev(&mut self) -> Result<crate::text::ERStat> {
+		crate::core::validity::check("ERStat", self.as_raw_mut_ERStat())?;
+		Ok(ERStatTrait::prev(self))
+	}
+	
 	#[inline]
 	fn set_prev(&mut self, val: &mut crate::text::ERStat) {
-		let ret = unsafe { sys::cv_text_ERStat_setPropPrev_ERStatX(self.as_raw_mut_ERStat(), val.as_raw_mut_ERStat()) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_mut_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_setPropPrev_ERStatX(self.as_raw_mut_ERStat(), val.as_raw_mut_ERStat())) };
 		ret
 	}
 	
 	/// whenever the regions is a local maxima of the probability
 	#[inline]
 	fn set_local_maxima(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_text_ERStat_setPropLocal_maxima_bool(self.as_raw_mut_ERStat(), val) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_mut_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_setPropLocal_maxima_bool(self.as_raw_mut_ERStat(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn max_probability_ancestor(&mut self) -> crate::text::ERStat {
-		let ret = unsafe { sys::cv_text_ERStat_getPropMax_probability_ancestor(self.as_raw_mut_ERStat()) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_mut_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_getPropMax_probability_ancestor(self.as_raw_mut_ERStat())) };
 		let ret = unsafe { crate::text::ERStat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [max_probability_ancestor](ERStatTrait::max_probability_ancestor), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_max_probability_ancestor(&mut self) -> Result<crate::text::ERStat> {
+		crate::core::validity::check("ERStat", self.as_raw_mut_ERStat())?;
+		Ok(ERStatTrait::max_probability_ancestor(self))
+	}
+	
 	#[inline]
 	fn set_max_probability_ancestor(&mut self, val: &mut crate::text::ERStat) {
-		let ret = unsafe { sys::cv_text_ERStat_setPropMax_probability_ancestor_ERStatX(self.as_raw_mut_ERStat(), val.as_raw_mut_ERStat()) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_mut_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_setPropMax_probability_ancestor_ERStatX(self.as_raw_mut_ERStat(), val.as_raw_mut_ERStat())) };
 		ret
 	}
 	
 	#[inline]
 	fn min_probability_ancestor(&mut self) -> crate::text::ERStat {
-		let ret = unsafe { sys::cv_text_ERStat_getPropMin_probability_ancestor(self.as_raw_mut_ERStat()) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_mut_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_getPropMin_probability_ancestor(self.as_raw_mut_ERStat())) };
 		let ret = unsafe { crate::text::ERStat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [min_probability_ancestor](ERStatTrait::min_probability_ancestor), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_min_probability_ancestor(&mut self) -> Result<crate::text::ERStat> {
+		crate::core::validity::check("ERStat", self.as_raw_mut_ERStat())?;
+		Ok(ERStatTrait::min_probability_ancestor(self))
+	}
+	
 	#[inline]
 	fn set_min_probability_ancestor(&mut self, val: &mut crate::text::ERStat) {
-		let ret = unsafe { sys::cv_text_ERStat_setPropMin_probability_ancestor_ERStatX(self.as_raw_mut_ERStat(), val.as_raw_mut_ERStat()) };
+		crate::core::validity::debug_check("ERStat", self.as_raw_mut_ERStat());
+		let ret = unsafe { ffi_call!(cv_text_ERStat_setPropMin_probability_ancestor_ERStatX(self.as_raw_mut_ERStat(), val.as_raw_mut_ERStat())) };
 		ret
 	}
 	
@@ -1109,7 +1563,10 @@ opencv_type_boxed! { ERStat }
 impl Drop for ERStat {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ERStat_delete(instance: *mut c_void); }
-		unsafe { cv_ERStat_delete(self.as_raw_mut_ERStat()) };
+		let ptr = self.as_raw_mut_ERStat();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ERStat_delete(ptr) };
 	}
 }
 
@@ -1134,7 +1591,7 @@ impl ERStat {
 	#[inline]
 	pub fn new(level: i32, pixel: i32, x: i32, y: i32) -> Result<crate::text::ERStat> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_ERStat_ERStat_int_int_int_int(level, pixel, x, y, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_ERStat_ERStat_int_int_int_int(level, pixel, x, y, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::text::ERStat::opencv_from_extern(ret) };
@@ -1143,6 +1600,64 @@ impl ERStat {
 	
 }
 
+/// Builder for `ERStat::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct ERStat_NewBuilder {
+	level: i32,
+	pixel: i32,
+	x: i32,
+	y: i32,
+}
+
+impl ERStat_NewBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { level: 256, pixel: 0, x: 0, y: 0 }
+	}
+	
+	/// C++ default: `256`
+	#[inline]
+	pub fn with_level(mut self, level: i32) -> Self {
+		self.level = level;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_pixel(mut self, pixel: i32) -> Self {
+		self.pixel = pixel;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_x(mut self, x: i32) -> Self {
+		self.x = x;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_y(mut self, y: i32) -> Self {
+		self.y = y;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::text::ERStat> {
+		ERStat::new(self.level, self.pixel, self.x, self.y)
+	}
+}
+
+impl Default for ERStat_NewBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 /// OCRBeamSearchDecoder class provides an interface for OCR using Beam Search algorithm.
 /// 
 /// 
@@ -1187,9 +1702,10 @@ pub trait OCRBeamSearchDecoderTrait: crate::text::BaseOCR + crate::text::OCRBeam
 	/// * component_level: 0
 	#[inline]
 	fn run_multiple(&mut self, image: &mut core::Mat, output_text: &mut String, component_rects: &mut core::Vector<core::Rect>, component_texts: &mut core::Vector<String>, component_confidences: &mut core::Vector<f32>, component_level: i32) -> Result<()> {
+		crate::core::validity::check("OCRBeamSearchDecoder", self.as_raw_mut_OCRBeamSearchDecoder())?;
 		string_arg_output_send!(via output_text_via);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_OCRBeamSearchDecoder_run_MatR_stringR_vector_Rect_X_vector_string_X_vector_float_X_int(self.as_raw_mut_OCRBeamSearchDecoder(), image.as_raw_mut_Mat(), &mut output_text_via, component_rects.as_raw_mut_VectorOfRect(), component_texts.as_raw_mut_VectorOfString(), component_confidences.as_raw_mut_VectorOff32(), component_level, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_OCRBeamSearchDecoder_run_MatR_stringR_vector_Rect_X_vector_string_X_vector_float_X_int(self.as_raw_mut_OCRBeamSearchDecoder(), image.as_raw_mut_Mat(), &mut output_text_via, component_rects.as_raw_mut_VectorOfRect(), component_texts.as_raw_mut_VectorOfString(), component_confidences.as_raw_mut_VectorOff32(), component_level, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		string_arg_output_receive!(output_text_via => output_text);
@@ -1203,9 +1719,10 @@ pub trait OCRBeamSearchDecoderTrait: crate::text::BaseOCR + crate::text::OCRBeam
 	/// * component_level: 0
 	#[inline]
 	fn run_multiple_mask(&mut self, image: &mut core::Mat, mask: &mut core::Mat, output_text: &mut String, component_rects: &mut core::Vector<core::Rect>, component_texts: &mut core::Vector<String>, component_confidences: &mut core::Vector<f32>, component_level: i32) -> Result<()> {
+		crate::core::validity::check("OCRBeamSearchDecoder", self.as_raw_mut_OCRBeamSearchDecoder())?;
 		string_arg_output_send!(via output_text_via);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_OCRBeamSearchDecoder_run_MatR_MatR_stringR_vector_Rect_X_vector_string_X_vector_float_X_int(self.as_raw_mut_OCRBeamSearchDecoder(), image.as_raw_mut_Mat(), mask.as_raw_mut_Mat(), &mut output_text_via, component_rects.as_raw_mut_VectorOfRect(), component_texts.as_raw_mut_VectorOfString(), component_confidences.as_raw_mut_VectorOff32(), component_level, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_OCRBeamSearchDecoder_run_MatR_MatR_stringR_vector_Rect_X_vector_string_X_vector_float_X_int(self.as_raw_mut_OCRBeamSearchDecoder(), image.as_raw_mut_Mat(), mask.as_raw_mut_Mat(), &mut output_text_via, component_rects.as_raw_mut_VectorOfRect(), component_texts.as_raw_mut_VectorOfString(), component_confidences.as_raw_mut_VectorOff32(), component_level, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		string_arg_output_receive!(output_text_via => output_text);
@@ -1216,9 +1733,10 @@ pub trait OCRBeamSearchDecoderTrait: crate::text::BaseOCR + crate::text::OCRBeam
 	/// * component_level: 0
 	#[inline]
 	fn run(&mut self, image: &dyn core::ToInputArray, min_confidence: i32, component_level: i32) -> Result<String> {
+		crate::core::validity::check("OCRBeamSearchDecoder", self.as_raw_mut_OCRBeamSearchDecoder())?;
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_OCRBeamSearchDecoder_run_const__InputArrayR_int_int(self.as_raw_mut_OCRBeamSearchDecoder(), image.as_raw__InputArray(), min_confidence, component_level, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_OCRBeamSearchDecoder_run_const__InputArrayR_int_int(self.as_raw_mut_OCRBeamSearchDecoder(), image.as_raw__InputArray(), min_confidence, component_level, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -1229,10 +1747,11 @@ pub trait OCRBeamSearchDecoderTrait: crate::text::BaseOCR + crate::text::OCRBeam
 	/// * component_level: 0
 	#[inline]
 	fn run_mask(&mut self, image: &dyn core::ToInputArray, mask: &dyn core::ToInputArray, min_confidence: i32, component_level: i32) -> Result<String> {
+		crate::core::validity::check("OCRBeamSearchDecoder", self.as_raw_mut_OCRBeamSearchDecoder())?;
 		input_array_arg!(image);
 		input_array_arg!(mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_OCRBeamSearchDecoder_run_const__InputArrayR_const__InputArrayR_int_int(self.as_raw_mut_OCRBeamSearchDecoder(), image.as_raw__InputArray(), mask.as_raw__InputArray(), min_confidence, component_level, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_OCRBeamSearchDecoder_run_const__InputArrayR_const__InputArrayR_int_int(self.as_raw_mut_OCRBeamSearchDecoder(), image.as_raw__InputArray(), mask.as_raw__InputArray(), min_confidence, component_level, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -1241,6 +1760,73 @@ pub trait OCRBeamSearchDecoderTrait: crate::text::BaseOCR + crate::text::OCRBeam
 	
 }
 
+/// Builder for `OCRBeamSearchDecoderTrait::run_multiple()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct OCRBeamSearchDecoder_RunMultipleBuilder<'a, T: ?Sized + OCRBeamSearchDecoderTrait> {
+	instance: &'a mut T,
+	image: &'a mut core::Mat,
+	output_text: &'a mut String,
+	component_rects: &'a mut core::Vector<core::Rect>,
+	component_texts: &'a mut core::Vector<String>,
+	component_confidences: &'a mut core::Vector<f32>,
+	component_level: i32,
+}
+
+impl<'a, T: ?Sized + OCRBeamSearchDecoderTrait> OCRBeamSearchDecoder_RunMultipleBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, image: &'a mut core::Mat, output_text: &'a mut String, component_rects: &'a mut core::Vector<core::Rect>, component_texts: &'a mut core::Vector<String>, component_confidences: &'a mut core::Vector<f32>) -> Self {
+		Self { instance, image, output_text, component_rects, component_texts, component_confidences, component_level: 0 }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_component_level(mut self, component_level: i32) -> Self {
+		self.component_level = component_level;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		OCRBeamSearchDecoderTrait::run_multiple(self.instance, self.image, self.output_text, self.component_rects, self.component_texts, self.component_confidences, self.component_level)
+	}
+}
+
+/// Builder for `OCRBeamSearchDecoderTrait::run_multiple_mask()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct OCRBeamSearchDecoder_RunMultipleMaskBuilder<'a, T: ?Sized + OCRBeamSearchDecoderTrait> {
+	instance: &'a mut T,
+	image: &'a mut core::Mat,
+	mask: &'a mut core::Mat,
+	output_text: &'a mut String,
+	component_rects: &'a mut core::Vector<core::Rect>,
+	component_texts: &'a mut core::Vector<String>,
+	component_confidences: &'a mut core::Vector<f32>,
+	component_level: i32,
+}
+
+impl<'a, T: ?Sized + OCRBeamSearchDecoderTrait> OCRBeamSearchDecoder_RunMultipleMaskBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, image: &'a mut core::Mat, mask: &'a mut core::Mat, output_text: &'a mut String, component_rects: &'a mut core::Vector<core::Rect>, component_texts: &'a mut core::Vector<String>, component_confidences: &'a mut core::Vector<f32>) -> Self {
+		Self { instance, image, mask, output_text, component_rects, component_texts, component_confidences, component_level: 0 }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_component_level(mut self, component_level: i32) -> Self {
+		self.component_level = component_level;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		OCRBeamSearchDecoderTrait::run_multiple_mask(self.instance, self.image, self.mask, self.output_text, self.component_rects, self.component_texts, self.component_confidences, self.component_level)
+	}
+}
+
 /// OCRBeamSearchDecoder class provides an interface for OCR using Beam Search algorithm.
 /// 
 /// 
@@ -1257,7 +1843,10 @@ opencv_type_boxed! { OCRBeamSearchDecoder }
 impl Drop for OCRBeamSearchDecoder {
 	fn drop(&mut self) {
 		extern "C" { fn cv_OCRBeamSearchDecoder_delete(instance: *mut c_void); }
-		unsafe { cv_OCRBeamSearchDecoder_delete(self.as_raw_mut_OCRBeamSearchDecoder()) };
+		let ptr = self.as_raw_mut_OCRBeamSearchDecoder();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_OCRBeamSearchDecoder_delete(ptr) };
 	}
 }
 
@@ -1308,7 +1897,7 @@ impl OCRBeamSearchDecoder {
 		input_array_arg!(transition_probabilities_table);
 		input_array_arg!(emission_probabilities_table);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_OCRBeamSearchDecoder_create_const_Ptr_ClassifierCallback__const_stringR_const__InputArrayR_const__InputArrayR_decoder_mode_int(classifier.as_raw_PtrOfOCRBeamSearchDecoder_ClassifierCallback(), vocabulary.opencv_as_extern(), transition_probabilities_table.as_raw__InputArray(), emission_probabilities_table.as_raw__InputArray(), mode, beam_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_OCRBeamSearchDecoder_create_const_Ptr_ClassifierCallback__const_stringR_const__InputArrayR_const__InputArrayR_decoder_mode_int(classifier.as_raw_PtrOfOCRBeamSearchDecoder_ClassifierCallback(), vocabulary.opencv_as_extern(), transition_probabilities_table.as_raw__InputArray(), emission_probabilities_table.as_raw__InputArray(), mode, beam_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::text::OCRBeamSearchDecoder>::opencv_from_extern(ret) };
@@ -1348,7 +1937,7 @@ impl OCRBeamSearchDecoder {
 		input_array_arg!(transition_probabilities_table);
 		input_array_arg!(emission_probabilities_table);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_OCRBeamSearchDecoder_create_const_StringR_const_StringR_const__InputArrayR_const__InputArrayR_decoder_mode_int(filename.opencv_as_extern(), vocabulary.opencv_as_extern(), transition_probabilities_table.as_raw__InputArray(), emission_probabilities_table.as_raw__InputArray(), mode, beam_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_OCRBeamSearchDecoder_create_const_StringR_const_StringR_const__InputArrayR_const__InputArrayR_decoder_mode_int(filename.opencv_as_extern(), vocabulary.opencv_as_extern(), transition_probabilities_table.as_raw__InputArray(), emission_probabilities_table.as_raw__InputArray(), mode, beam_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::text::OCRBeamSearchDecoder>::opencv_from_extern(ret) };
@@ -1357,6 +1946,70 @@ impl OCRBeamSearchDecoder {
 	
 }
 
+/// Builder for `OCRBeamSearchDecoder::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct OCRBeamSearchDecoder_CreateBuilder<'a> {
+	classifier: core::Ptr<crate::text::OCRBeamSearchDecoder_ClassifierCallback>,
+	vocabulary: &'a str,
+	transition_probabilities_table: &'a dyn core::ToInputArray,
+	emission_probabilities_table: &'a dyn core::ToInputArray,
+	mode: crate::text::decoder_mode,
+	beam_size: i32,
+}
+
+impl<'a> OCRBeamSearchDecoder_CreateBuilder<'a> {
+	#[inline]
+	pub fn new(classifier: core::Ptr<crate::text::OCRBeamSearchDecoder_ClassifierCallback>, vocabulary: &'a str, transition_probabilities_table: &'a dyn core::ToInputArray, emission_probabilities_table: &'a dyn core::ToInputArray, mode: crate::text::decoder_mode) -> Self {
+		Self { classifier, vocabulary, transition_probabilities_table, emission_probabilities_table, mode, beam_size: 500 }
+	}
+	
+	/// C++ default: `500`
+	#[inline]
+	pub fn with_beam_size(mut self, beam_size: i32) -> Self {
+		self.beam_size = beam_size;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<crate::text::OCRBeamSearchDecoder>> {
+		OCRBeamSearchDecoder::create(self.classifier, self.vocabulary, self.transition_probabilities_table, self.emission_probabilities_table, self.mode, self.beam_size)
+	}
+}
+
+/// Builder for `OCRBeamSearchDecoder::create_from_file()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct OCRBeamSearchDecoder_CreateFromFileBuilder<'a> {
+	filename: &'a str,
+	vocabulary: &'a str,
+	transition_probabilities_table: &'a dyn core::ToInputArray,
+	emission_probabilities_table: &'a dyn core::ToInputArray,
+	mode: crate::text::decoder_mode,
+	beam_size: i32,
+}
+
+impl<'a> OCRBeamSearchDecoder_CreateFromFileBuilder<'a> {
+	#[inline]
+	pub fn new(filename: &'a str, vocabulary: &'a str, transition_probabilities_table: &'a dyn core::ToInputArray, emission_probabilities_table: &'a dyn core::ToInputArray, mode: crate::text::decoder_mode) -> Self {
+		Self { filename, vocabulary, transition_probabilities_table, emission_probabilities_table, mode, beam_size: 500 }
+	}
+	
+	/// C++ default: `500`
+	#[inline]
+	pub fn with_beam_size(mut self, beam_size: i32) -> Self {
+		self.beam_size = beam_size;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<crate::text::OCRBeamSearchDecoder>> {
+		OCRBeamSearchDecoder::create_from_file(self.filename, self.vocabulary, self.transition_probabilities_table, self.emission_probabilities_table, self.mode, self.beam_size)
+	}
+}
+
 /// Callback with the character classifier is made a class.
 /// 
 /// This way it hides the feature extractor and the classifier itself, so developers can write
@@ -1383,9 +2036,10 @@ pub trait OCRBeamSearchDecoder_ClassifierCallbackTrait: crate::text::OCRBeamSear
 	/// including 0 as start-sequence location.
 	#[inline]
 	fn eval(&mut self, image: &dyn core::ToInputArray, recognition_probabilities: &mut core::Vector<core::Vector<f64>>, oversegmentation: &mut core::Vector<i32>) -> Result<()> {
+		crate::core::validity::check("OCRBeamSearchDecoder_ClassifierCallback", self.as_raw_mut_OCRBeamSearchDecoder_ClassifierCallback())?;
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_OCRBeamSearchDecoder_ClassifierCallback_eval_const__InputArrayR_vector_vector_double__R_vector_int_R(self.as_raw_mut_OCRBeamSearchDecoder_ClassifierCallback(), image.as_raw__InputArray(), recognition_probabilities.as_raw_mut_VectorOfVectorOff64(), oversegmentation.as_raw_mut_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_OCRBeamSearchDecoder_ClassifierCallback_eval_const__InputArrayR_vector_vector_double__R_vector_int_R(self.as_raw_mut_OCRBeamSearchDecoder_ClassifierCallback(), image.as_raw__InputArray(), recognition_probabilities.as_raw_mut_VectorOfVectorOff64(), oversegmentation.as_raw_mut_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1393,8 +2047,9 @@ pub trait OCRBeamSearchDecoder_ClassifierCallbackTrait: crate::text::OCRBeamSear
 	
 	#[inline]
 	fn get_window_size(&mut self) -> Result<i32> {
+		crate::core::validity::check("OCRBeamSearchDecoder_ClassifierCallback", self.as_raw_mut_OCRBeamSearchDecoder_ClassifierCallback())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_OCRBeamSearchDecoder_ClassifierCallback_getWindowSize(self.as_raw_mut_OCRBeamSearchDecoder_ClassifierCallback(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_OCRBeamSearchDecoder_ClassifierCallback_getWindowSize(self.as_raw_mut_OCRBeamSearchDecoder_ClassifierCallback(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1402,8 +2057,9 @@ pub trait OCRBeamSearchDecoder_ClassifierCallbackTrait: crate::text::OCRBeamSear
 	
 	#[inline]
 	fn get_step_size(&mut self) -> Result<i32> {
+		crate::core::validity::check("OCRBeamSearchDecoder_ClassifierCallback", self.as_raw_mut_OCRBeamSearchDecoder_ClassifierCallback())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_OCRBeamSearchDecoder_ClassifierCallback_getStepSize(self.as_raw_mut_OCRBeamSearchDecoder_ClassifierCallback(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_OCRBeamSearchDecoder_ClassifierCallback_getStepSize(self.as_raw_mut_OCRBeamSearchDecoder_ClassifierCallback(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1428,7 +2084,10 @@ opencv_type_boxed! { OCRBeamSearchDecoder_ClassifierCallback }
 impl Drop for OCRBeamSearchDecoder_ClassifierCallback {
 	fn drop(&mut self) {
 		extern "C" { fn cv_OCRBeamSearchDecoder_ClassifierCallback_delete(instance: *mut c_void); }
-		unsafe { cv_OCRBeamSearchDecoder_ClassifierCallback_delete(self.as_raw_mut_OCRBeamSearchDecoder_ClassifierCallback()) };
+		let ptr = self.as_raw_mut_OCRBeamSearchDecoder_ClassifierCallback();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_OCRBeamSearchDecoder_ClassifierCallback_delete(ptr) };
 	}
 }
 
@@ -1489,9 +2148,10 @@ pub trait OCRHMMDecoderTrait: crate::text::BaseOCR + crate::text::OCRHMMDecoderT
 	/// * component_level: 0
 	#[inline]
 	fn run_multiple(&mut self, image: &mut core::Mat, output_text: &mut String, component_rects: &mut core::Vector<core::Rect>, component_texts: &mut core::Vector<String>, component_confidences: &mut core::Vector<f32>, component_level: i32) -> Result<()> {
+		crate::core::validity::check("OCRHMMDecoder", self.as_raw_mut_OCRHMMDecoder())?;
 		string_arg_output_send!(via output_text_via);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_OCRHMMDecoder_run_MatR_stringR_vector_Rect_X_vector_string_X_vector_float_X_int(self.as_raw_mut_OCRHMMDecoder(), image.as_raw_mut_Mat(), &mut output_text_via, component_rects.as_raw_mut_VectorOfRect(), component_texts.as_raw_mut_VectorOfString(), component_confidences.as_raw_mut_VectorOff32(), component_level, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_OCRHMMDecoder_run_MatR_stringR_vector_Rect_X_vector_string_X_vector_float_X_int(self.as_raw_mut_OCRHMMDecoder(), image.as_raw_mut_Mat(), &mut output_text_via, component_rects.as_raw_mut_VectorOfRect(), component_texts.as_raw_mut_VectorOfString(), component_confidences.as_raw_mut_VectorOff32(), component_level, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		string_arg_output_receive!(output_text_via => output_text);
@@ -1529,9 +2189,10 @@ pub trait OCRHMMDecoderTrait: crate::text::BaseOCR + crate::text::OCRHMMDecoderT
 	/// * component_level: 0
 	#[inline]
 	fn run_multiple_mask(&mut self, image: &mut core::Mat, mask: &mut core::Mat, output_text: &mut String, component_rects: &mut core::Vector<core::Rect>, component_texts: &mut core::Vector<String>, component_confidences: &mut core::Vector<f32>, component_level: i32) -> Result<()> {
+		crate::core::validity::check("OCRHMMDecoder", self.as_raw_mut_OCRHMMDecoder())?;
 		string_arg_output_send!(via output_text_via);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_OCRHMMDecoder_run_MatR_MatR_stringR_vector_Rect_X_vector_string_X_vector_float_X_int(self.as_raw_mut_OCRHMMDecoder(), image.as_raw_mut_Mat(), mask.as_raw_mut_Mat(), &mut output_text_via, component_rects.as_raw_mut_VectorOfRect(), component_texts.as_raw_mut_VectorOfString(), component_confidences.as_raw_mut_VectorOff32(), component_level, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_OCRHMMDecoder_run_MatR_MatR_stringR_vector_Rect_X_vector_string_X_vector_float_X_int(self.as_raw_mut_OCRHMMDecoder(), image.as_raw_mut_Mat(), mask.as_raw_mut_Mat(), &mut output_text_via, component_rects.as_raw_mut_VectorOfRect(), component_texts.as_raw_mut_VectorOfString(), component_confidences.as_raw_mut_VectorOff32(), component_level, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		string_arg_output_receive!(output_text_via => output_text);
@@ -1542,9 +2203,10 @@ pub trait OCRHMMDecoderTrait: crate::text::BaseOCR + crate::text::OCRHMMDecoderT
 	/// * component_level: 0
 	#[inline]
 	fn run(&mut self, image: &dyn core::ToInputArray, min_confidence: i32, component_level: i32) -> Result<String> {
+		crate::core::validity::check("OCRHMMDecoder", self.as_raw_mut_OCRHMMDecoder())?;
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_OCRHMMDecoder_run_const__InputArrayR_int_int(self.as_raw_mut_OCRHMMDecoder(), image.as_raw__InputArray(), min_confidence, component_level, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_OCRHMMDecoder_run_const__InputArrayR_int_int(self.as_raw_mut_OCRHMMDecoder(), image.as_raw__InputArray(), min_confidence, component_level, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -1555,10 +2217,11 @@ pub trait OCRHMMDecoderTrait: crate::text::BaseOCR + crate::text::OCRHMMDecoderT
 	/// * component_level: 0
 	#[inline]
 	fn run_mask(&mut self, image: &dyn core::ToInputArray, mask: &dyn core::ToInputArray, min_confidence: i32, component_level: i32) -> Result<String> {
+		crate::core::validity::check("OCRHMMDecoder", self.as_raw_mut_OCRHMMDecoder())?;
 		input_array_arg!(image);
 		input_array_arg!(mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_OCRHMMDecoder_run_const__InputArrayR_const__InputArrayR_int_int(self.as_raw_mut_OCRHMMDecoder(), image.as_raw__InputArray(), mask.as_raw__InputArray(), min_confidence, component_level, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_OCRHMMDecoder_run_const__InputArrayR_const__InputArrayR_int_int(self.as_raw_mut_OCRHMMDecoder(), image.as_raw__InputArray(), mask.as_raw__InputArray(), min_confidence, component_level, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -1567,6 +2230,73 @@ pub trait OCRHMMDecoderTrait: crate::text::BaseOCR + crate::text::OCRHMMDecoderT
 	
 }
 
+/// Builder for `OCRHMMDecoderTrait::run_multiple()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct OCRHMMDecoder_RunMultipleBuilder<'a, T: ?Sized + OCRHMMDecoderTrait> {
+	instance: &'a mut T,
+	image: &'a mut core::Mat,
+	output_text: &'a mut String,
+	component_rects: &'a mut core::Vector<core::Rect>,
+	component_texts: &'a mut core::Vector<String>,
+	component_confidences: &'a mut core::Vector<f32>,
+	component_level: i32,
+}
+
+impl<'a, T: ?Sized + OCRHMMDecoderTrait> OCRHMMDecoder_RunMultipleBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, image: &'a mut core::Mat, output_text: &'a mut String, component_rects: &'a mut core::Vector<core::Rect>, component_texts: &'a mut core::Vector<String>, component_confidences: &'a mut core::Vector<f32>) -> Self {
+		Self { instance, image, output_text, component_rects, component_texts, component_confidences, component_level: 0 }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_component_level(mut self, component_level: i32) -> Self {
+		self.component_level = component_level;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		OCRHMMDecoderTrait::run_multiple(self.instance, self.image, self.output_text, self.component_rects, self.component_texts, self.component_confidences, self.component_level)
+	}
+}
+
+/// Builder for `OCRHMMDecoderTrait::run_multiple_mask()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct OCRHMMDecoder_RunMultipleMaskBuilder<'a, T: ?Sized + OCRHMMDecoderTrait> {
+	instance: &'a mut T,
+	image: &'a mut core::Mat,
+	mask: &'a mut core::Mat,
+	output_text: &'a mut String,
+	component_rects: &'a mut core::Vector<core::Rect>,
+	component_texts: &'a mut core::Vector<String>,
+	component_confidences: &'a mut core::Vector<f32>,
+	component_level: i32,
+}
+
+impl<'a, T: ?Sized + OCRHMMDecoderTrait> OCRHMMDecoder_RunMultipleMaskBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, image: &'a mut core::Mat, mask: &'a mut core::Mat, output_text: &'a mut String, component_rects: &'a mut core::Vector<core::Rect>, component_texts: &'a mut core::Vector<String>, component_confidences: &'a mut core::Vector<f32>) -> Self {
+		Self { instance, image, mask, output_text, component_rects, component_texts, component_confidences, component_level: 0 }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_component_level(mut self, component_level: i32) -> Self {
+		self.component_level = component_level;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		OCRHMMDecoderTrait::run_multiple_mask(self.instance, self.image, self.mask, self.output_text, self.component_rects, self.component_texts, self.component_confidences, self.component_level)
+	}
+}
+
 /// OCRHMMDecoder class provides an interface for OCR using Hidden Markov Models.
 /// 
 /// 
@@ -1583,7 +2313,10 @@ opencv_type_boxed! { OCRHMMDecoder }
 impl Drop for OCRHMMDecoder {
 	fn drop(&mut self) {
 		extern "C" { fn cv_OCRHMMDecoder_delete(instance: *mut c_void); }
-		unsafe { cv_OCRHMMDecoder_delete(self.as_raw_mut_OCRHMMDecoder()) };
+		let ptr = self.as_raw_mut_OCRHMMDecoder();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_OCRHMMDecoder_delete(ptr) };
 	}
 }
 
@@ -1631,7 +2364,7 @@ impl OCRHMMDecoder {
 		input_array_arg!(transition_probabilities_table);
 		input_array_arg!(emission_probabilities_table);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_OCRHMMDecoder_create_const_Ptr_ClassifierCallback__const_StringR_const__InputArrayR_const__InputArrayR_int(classifier.as_raw_PtrOfOCRHMMDecoder_ClassifierCallback(), vocabulary.opencv_as_extern(), transition_probabilities_table.as_raw__InputArray(), emission_probabilities_table.as_raw__InputArray(), mode, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_OCRHMMDecoder_create_const_Ptr_ClassifierCallback__const_StringR_const__InputArrayR_const__InputArrayR_int(classifier.as_raw_PtrOfOCRHMMDecoder_ClassifierCallback(), vocabulary.opencv_as_extern(), transition_probabilities_table.as_raw__InputArray(), emission_probabilities_table.as_raw__InputArray(), mode, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::text::OCRHMMDecoder>::opencv_from_extern(ret) };
@@ -1669,7 +2402,7 @@ impl OCRHMMDecoder {
 		input_array_arg!(transition_probabilities_table);
 		input_array_arg!(emission_probabilities_table);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_OCRHMMDecoder_create_const_StringR_const_StringR_const__InputArrayR_const__InputArrayR_int_int(filename.opencv_as_extern(), vocabulary.opencv_as_extern(), transition_probabilities_table.as_raw__InputArray(), emission_probabilities_table.as_raw__InputArray(), mode, classifier, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_OCRHMMDecoder_create_const_StringR_const_StringR_const__InputArrayR_const__InputArrayR_int_int(filename.opencv_as_extern(), vocabulary.opencv_as_extern(), transition_probabilities_table.as_raw__InputArray(), emission_probabilities_table.as_raw__InputArray(), mode, classifier, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::text::OCRHMMDecoder>::opencv_from_extern(ret) };
@@ -1704,9 +2437,10 @@ pub trait OCRHMMDecoder_ClassifierCallbackTrait: crate::text::OCRHMMDecoder_Clas
 	/// corresponding to each classes in out_class.
 	#[inline]
 	fn eval(&mut self, image: &dyn core::ToInputArray, out_class: &mut core::Vector<i32>, out_confidence: &mut core::Vector<f64>) -> Result<()> {
+		crate::core::validity::check("OCRHMMDecoder_ClassifierCallback", self.as_raw_mut_OCRHMMDecoder_ClassifierCallback())?;
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_OCRHMMDecoder_ClassifierCallback_eval_const__InputArrayR_vector_int_R_vector_double_R(self.as_raw_mut_OCRHMMDecoder_ClassifierCallback(), image.as_raw__InputArray(), out_class.as_raw_mut_VectorOfi32(), out_confidence.as_raw_mut_VectorOff64(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_OCRHMMDecoder_ClassifierCallback_eval_const__InputArrayR_vector_int_R_vector_double_R(self.as_raw_mut_OCRHMMDecoder_ClassifierCallback(), image.as_raw__InputArray(), out_class.as_raw_mut_VectorOfi32(), out_confidence.as_raw_mut_VectorOff64(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1731,7 +2465,10 @@ opencv_type_boxed! { OCRHMMDecoder_ClassifierCallback }
 impl Drop for OCRHMMDecoder_ClassifierCallback {
 	fn drop(&mut self) {
 		extern "C" { fn cv_OCRHMMDecoder_ClassifierCallback_delete(instance: *mut c_void); }
-		unsafe { cv_OCRHMMDecoder_ClassifierCallback_delete(self.as_raw_mut_OCRHMMDecoder_ClassifierCallback()) };
+		let ptr = self.as_raw_mut_OCRHMMDecoder_ClassifierCallback();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_OCRHMMDecoder_ClassifierCallback_delete(ptr) };
 	}
 }
 
@@ -1770,9 +2507,10 @@ pub trait OCRHolisticWordRecognizer: crate::text::BaseOCR + crate::text::OCRHoli
 	/// * component_level: OCR_LEVEL_WORD
 	#[inline]
 	fn run(&mut self, image: &mut core::Mat, output_text: &mut String, component_rects: &mut core::Vector<core::Rect>, component_texts: &mut core::Vector<String>, component_confidences: &mut core::Vector<f32>, component_level: i32) -> Result<()> {
+		crate::core::validity::check("OCRHolisticWordRecognizer", self.as_raw_mut_OCRHolisticWordRecognizer())?;
 		string_arg_output_send!(via output_text_via);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_OCRHolisticWordRecognizer_run_MatR_stringR_vector_Rect_X_vector_string_X_vector_float_X_int(self.as_raw_mut_OCRHolisticWordRecognizer(), image.as_raw_mut_Mat(), &mut output_text_via, component_rects.as_raw_mut_VectorOfRect(), component_texts.as_raw_mut_VectorOfString(), component_confidences.as_raw_mut_VectorOff32(), component_level, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_OCRHolisticWordRecognizer_run_MatR_stringR_vector_Rect_X_vector_string_X_vector_float_X_int(self.as_raw_mut_OCRHolisticWordRecognizer(), image.as_raw_mut_Mat(), &mut output_text_via, component_rects.as_raw_mut_VectorOfRect(), component_texts.as_raw_mut_VectorOfString(), component_confidences.as_raw_mut_VectorOff32(), component_level, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		string_arg_output_receive!(output_text_via => output_text);
@@ -1810,9 +2548,10 @@ pub trait OCRHolisticWordRecognizer: crate::text::BaseOCR + crate::text::OCRHoli
 	/// * component_level: OCR_LEVEL_WORD
 	#[inline]
 	fn run_mask(&mut self, image: &mut core::Mat, mask: &mut core::Mat, output_text: &mut String, component_rects: &mut core::Vector<core::Rect>, component_texts: &mut core::Vector<String>, component_confidences: &mut core::Vector<f32>, component_level: i32) -> Result<()> {
+		crate::core::validity::check("OCRHolisticWordRecognizer", self.as_raw_mut_OCRHolisticWordRecognizer())?;
 		string_arg_output_send!(via output_text_via);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_OCRHolisticWordRecognizer_run_MatR_MatR_stringR_vector_Rect_X_vector_string_X_vector_float_X_int(self.as_raw_mut_OCRHolisticWordRecognizer(), image.as_raw_mut_Mat(), mask.as_raw_mut_Mat(), &mut output_text_via, component_rects.as_raw_mut_VectorOfRect(), component_texts.as_raw_mut_VectorOfString(), component_confidences.as_raw_mut_VectorOff32(), component_level, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_OCRHolisticWordRecognizer_run_MatR_MatR_stringR_vector_Rect_X_vector_string_X_vector_float_X_int(self.as_raw_mut_OCRHolisticWordRecognizer(), image.as_raw_mut_Mat(), mask.as_raw_mut_Mat(), &mut output_text_via, component_rects.as_raw_mut_VectorOfRect(), component_texts.as_raw_mut_VectorOfString(), component_confidences.as_raw_mut_VectorOff32(), component_level, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		string_arg_output_receive!(output_text_via => output_text);
@@ -1829,7 +2568,7 @@ impl dyn OCRHolisticWordRecognizer + '_ {
 		extern_container_arg!(weights_filename);
 		extern_container_arg!(words_filename);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_OCRHolisticWordRecognizer_create_const_stringR_const_stringR_const_stringR(arch_filename.opencv_as_extern(), weights_filename.opencv_as_extern(), words_filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_OCRHolisticWordRecognizer_create_const_stringR_const_stringR_const_stringR(arch_filename.opencv_as_extern(), weights_filename.opencv_as_extern(), words_filename.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::text::OCRHolisticWordRecognizer>::opencv_from_extern(ret) };
@@ -1881,9 +2620,10 @@ pub trait OCRTesseract: crate::text::BaseOCR + crate::text::OCRTesseractConst {
 	/// * component_level: 0
 	#[inline]
 	fn run_multiple(&mut self, image: &mut core::Mat, output_text: &mut String, component_rects: &mut core::Vector<core::Rect>, component_texts: &mut core::Vector<String>, component_confidences: &mut core::Vector<f32>, component_level: i32) -> Result<()> {
+		crate::core::validity::check("OCRTesseract", self.as_raw_mut_OCRTesseract())?;
 		string_arg_output_send!(via output_text_via);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_OCRTesseract_run_MatR_stringR_vector_Rect_X_vector_string_X_vector_float_X_int(self.as_raw_mut_OCRTesseract(), image.as_raw_mut_Mat(), &mut output_text_via, component_rects.as_raw_mut_VectorOfRect(), component_texts.as_raw_mut_VectorOfString(), component_confidences.as_raw_mut_VectorOff32(), component_level, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_OCRTesseract_run_MatR_stringR_vector_Rect_X_vector_string_X_vector_float_X_int(self.as_raw_mut_OCRTesseract(), image.as_raw_mut_Mat(), &mut output_text_via, component_rects.as_raw_mut_VectorOfRect(), component_texts.as_raw_mut_VectorOfString(), component_confidences.as_raw_mut_VectorOff32(), component_level, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		string_arg_output_receive!(output_text_via => output_text);
@@ -1897,9 +2637,10 @@ pub trait OCRTesseract: crate::text::BaseOCR + crate::text::OCRTesseractConst {
 	/// * component_level: 0
 	#[inline]
 	fn run_multiple_mask(&mut self, image: &mut core::Mat, mask: &mut core::Mat, output_text: &mut String, component_rects: &mut core::Vector<core::Rect>, component_texts: &mut core::Vector<String>, component_confidences: &mut core::Vector<f32>, component_level: i32) -> Result<()> {
+		crate::core::validity::check("OCRTesseract", self.as_raw_mut_OCRTesseract())?;
 		string_arg_output_send!(via output_text_via);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_OCRTesseract_run_MatR_MatR_stringR_vector_Rect_X_vector_string_X_vector_float_X_int(self.as_raw_mut_OCRTesseract(), image.as_raw_mut_Mat(), mask.as_raw_mut_Mat(), &mut output_text_via, component_rects.as_raw_mut_VectorOfRect(), component_texts.as_raw_mut_VectorOfString(), component_confidences.as_raw_mut_VectorOff32(), component_level, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_OCRTesseract_run_MatR_MatR_stringR_vector_Rect_X_vector_string_X_vector_float_X_int(self.as_raw_mut_OCRTesseract(), image.as_raw_mut_Mat(), mask.as_raw_mut_Mat(), &mut output_text_via, component_rects.as_raw_mut_VectorOfRect(), component_texts.as_raw_mut_VectorOfString(), component_confidences.as_raw_mut_VectorOff32(), component_level, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		string_arg_output_receive!(output_text_via => output_text);
@@ -1910,9 +2651,10 @@ pub trait OCRTesseract: crate::text::BaseOCR + crate::text::OCRTesseractConst {
 	/// * component_level: 0
 	#[inline]
 	fn run(&mut self, image: &dyn core::ToInputArray, min_confidence: i32, component_level: i32) -> Result<String> {
+		crate::core::validity::check("OCRTesseract", self.as_raw_mut_OCRTesseract())?;
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_OCRTesseract_run_const__InputArrayR_int_int(self.as_raw_mut_OCRTesseract(), image.as_raw__InputArray(), min_confidence, component_level, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_OCRTesseract_run_const__InputArrayR_int_int(self.as_raw_mut_OCRTesseract(), image.as_raw__InputArray(), min_confidence, component_level, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -1923,10 +2665,11 @@ pub trait OCRTesseract: crate::text::BaseOCR + crate::text::OCRTesseractConst {
 	/// * component_level: 0
 	#[inline]
 	fn run_mask(&mut self, image: &dyn core::ToInputArray, mask: &dyn core::ToInputArray, min_confidence: i32, component_level: i32) -> Result<String> {
+		crate::core::validity::check("OCRTesseract", self.as_raw_mut_OCRTesseract())?;
 		input_array_arg!(image);
 		input_array_arg!(mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_OCRTesseract_run_const__InputArrayR_const__InputArrayR_int_int(self.as_raw_mut_OCRTesseract(), image.as_raw__InputArray(), mask.as_raw__InputArray(), min_confidence, component_level, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_OCRTesseract_run_const__InputArrayR_const__InputArrayR_int_int(self.as_raw_mut_OCRTesseract(), image.as_raw__InputArray(), mask.as_raw__InputArray(), min_confidence, component_level, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -1935,9 +2678,10 @@ pub trait OCRTesseract: crate::text::BaseOCR + crate::text::OCRTesseractConst {
 	
 	#[inline]
 	fn set_white_list(&mut self, char_whitelist: &str) -> Result<()> {
+		crate::core::validity::check("OCRTesseract", self.as_raw_mut_OCRTesseract())?;
 		extern_container_arg!(char_whitelist);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_OCRTesseract_setWhiteList_const_StringR(self.as_raw_mut_OCRTesseract(), char_whitelist.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_OCRTesseract_setWhiteList_const_StringR(self.as_raw_mut_OCRTesseract(), char_whitelist.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1945,6 +2689,73 @@ pub trait OCRTesseract: crate::text::BaseOCR + crate::text::OCRTesseractConst {
 	
 }
 
+/// Builder for `OCRTesseract::run_multiple()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct OCRTesseract_RunMultipleBuilder<'a, T: ?Sized + OCRTesseract> {
+	instance: &'a mut T,
+	image: &'a mut core::Mat,
+	output_text: &'a mut String,
+	component_rects: &'a mut core::Vector<core::Rect>,
+	component_texts: &'a mut core::Vector<String>,
+	component_confidences: &'a mut core::Vector<f32>,
+	component_level: i32,
+}
+
+impl<'a, T: ?Sized + OCRTesseract> OCRTesseract_RunMultipleBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, image: &'a mut core::Mat, output_text: &'a mut String, component_rects: &'a mut core::Vector<core::Rect>, component_texts: &'a mut core::Vector<String>, component_confidences: &'a mut core::Vector<f32>) -> Self {
+		Self { instance, image, output_text, component_rects, component_texts, component_confidences, component_level: 0 }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_component_level(mut self, component_level: i32) -> Self {
+		self.component_level = component_level;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		OCRTesseract::run_multiple(self.instance, self.image, self.output_text, self.component_rects, self.component_texts, self.component_confidences, self.component_level)
+	}
+}
+
+/// Builder for `OCRTesseract::run_multiple_mask()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct OCRTesseract_RunMultipleMaskBuilder<'a, T: ?Sized + OCRTesseract> {
+	instance: &'a mut T,
+	image: &'a mut core::Mat,
+	mask: &'a mut core::Mat,
+	output_text: &'a mut String,
+	component_rects: &'a mut core::Vector<core::Rect>,
+	component_texts: &'a mut core::Vector<String>,
+	component_confidences: &'a mut core::Vector<f32>,
+	component_level: i32,
+}
+
+impl<'a, T: ?Sized + OCRTesseract> OCRTesseract_RunMultipleMaskBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, image: &'a mut core::Mat, mask: &'a mut core::Mat, output_text: &'a mut String, component_rects: &'a mut core::Vector<core::Rect>, component_texts: &'a mut core::Vector<String>, component_confidences: &'a mut core::Vector<f32>) -> Self {
+		Self { instance, image, mask, output_text, component_rects, component_texts, component_confidences, component_level: 0 }
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_component_level(mut self, component_level: i32) -> Self {
+		self.component_level = component_level;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		OCRTesseract::run_multiple_mask(self.instance, self.image, self.mask, self.output_text, self.component_rects, self.component_texts, self.component_confidences, self.component_level)
+	}
+}
+
 impl dyn OCRTesseract + '_ {
 	/// Creates an instance of the OCRTesseract class. Initializes Tesseract.
 	/// 
@@ -1973,7 +2784,7 @@ impl dyn OCRTesseract + '_ {
 		extern_container_arg!(language);
 		extern_container_arg!(char_whitelist);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_OCRTesseract_create_const_charX_const_charX_const_charX_int_int(datapath.opencv_as_extern(), language.opencv_as_extern(), char_whitelist.opencv_as_extern(), oem, psmode, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_OCRTesseract_create_const_charX_const_charX_const_charX_int_int(datapath.opencv_as_extern(), language.opencv_as_extern(), char_whitelist.opencv_as_extern(), oem, psmode, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::text::OCRTesseract>::opencv_from_extern(ret) };
@@ -1998,9 +2809,10 @@ pub trait TextDetector: crate::text::TextDetectorConst {
 	/// * confidence: a vector of float that will be updated with the confidence the classifier has for the selected bounding box
 	#[inline]
 	fn detect(&mut self, input_image: &dyn core::ToInputArray, bbox: &mut core::Vector<core::Rect>, confidence: &mut core::Vector<f32>) -> Result<()> {
+		crate::core::validity::check("TextDetector", self.as_raw_mut_TextDetector())?;
 		input_array_arg!(input_image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_TextDetector_detect_const__InputArrayR_vector_Rect_R_vector_float_R(self.as_raw_mut_TextDetector(), input_image.as_raw__InputArray(), bbox.as_raw_mut_VectorOfRect(), confidence.as_raw_mut_VectorOff32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_TextDetector_detect_const__InputArrayR_vector_Rect_R_vector_float_R(self.as_raw_mut_TextDetector(), input_image.as_raw__InputArray(), bbox.as_raw_mut_VectorOfRect(), confidence.as_raw_mut_VectorOff32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2028,9 +2840,10 @@ pub trait TextDetectorCNN: crate::text::TextDetector + crate::text::TextDetector
 	/// * confidence: a vector of float that will be updated with the confidence the classifier has for the selected bounding box
 	#[inline]
 	fn detect(&mut self, input_image: &dyn core::ToInputArray, bbox: &mut core::Vector<core::Rect>, confidence: &mut core::Vector<f32>) -> Result<()> {
+		crate::core::validity::check("TextDetectorCNN", self.as_raw_mut_TextDetectorCNN())?;
 		input_array_arg!(input_image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_TextDetectorCNN_detect_const__InputArrayR_vector_Rect_R_vector_float_R(self.as_raw_mut_TextDetectorCNN(), input_image.as_raw__InputArray(), bbox.as_raw_mut_VectorOfRect(), confidence.as_raw_mut_VectorOff32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_TextDetectorCNN_detect_const__InputArrayR_vector_Rect_R_vector_float_R(self.as_raw_mut_TextDetectorCNN(), input_image.as_raw__InputArray(), bbox.as_raw_mut_VectorOfRect(), confidence.as_raw_mut_VectorOff32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2051,7 +2864,7 @@ impl dyn TextDetectorCNN + '_ {
 		extern_container_arg!(model_arch_filename);
 		extern_container_arg!(model_weights_filename);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_TextDetectorCNN_create_const_StringR_const_StringR_vector_Size_(model_arch_filename.opencv_as_extern(), model_weights_filename.opencv_as_extern(), detection_sizes.as_raw_mut_VectorOfSize(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_TextDetectorCNN_create_const_StringR_const_StringR_vector_Size_(model_arch_filename.opencv_as_extern(), model_weights_filename.opencv_as_extern(), detection_sizes.as_raw_mut_VectorOfSize(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::text::TextDetectorCNN>::opencv_from_extern(ret) };
@@ -2072,7 +2885,7 @@ impl dyn TextDetectorCNN + '_ {
 		extern_container_arg!(model_arch_filename);
 		extern_container_arg!(model_weights_filename);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_text_TextDetectorCNN_create_const_StringR_const_StringR(model_arch_filename.opencv_as_extern(), model_weights_filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_text_TextDetectorCNN_create_const_StringR_const_StringR(model_arch_filename.opencv_as_extern(), model_weights_filename.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::text::TextDetectorCNN>::opencv_from_extern(ret) };
@@ -43,9 +43,10 @@ pub trait TrackerCSRT: crate::tracking::TrackerCSRTConst + crate::video::Tracker
 
 	#[inline]
 	fn set_initial_mask(&mut self, mask: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("TrackerCSRT", self.as_raw_mut_TrackerCSRT())?;
 		input_array_arg!(mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_tracking_TrackerCSRT_setInitialMask_const__InputArrayR(self.as_raw_mut_TrackerCSRT(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_tracking_TrackerCSRT_setInitialMask_const__InputArrayR(self.as_raw_mut_TrackerCSRT(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -63,7 +64,7 @@ impl dyn TrackerCSRT + '_ {
 	#[inline]
 	pub fn create(parameters: &crate::tracking::TrackerCSRT_Params) -> Result<core::Ptr<dyn crate::tracking::TrackerCSRT>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_tracking_TrackerCSRT_create_const_ParamsR(parameters.as_raw_TrackerCSRT_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_tracking_TrackerCSRT_create_const_ParamsR(parameters.as_raw_TrackerCSRT_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::tracking::TrackerCSRT>::opencv_from_extern(ret) };
@@ -76,169 +77,412 @@ pub trait TrackerCSRT_ParamsTraitConst {
 
 	#[inline]
 	fn use_hog(&self) -> bool {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_getPropUse_hog_const(self.as_raw_TrackerCSRT_Params()) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_getPropUse_hog_const(self.as_raw_TrackerCSRT_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [use_hog](TrackerCSRT_ParamsTraitConst::use_hog), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_use_hog(&self) -> Result<bool> {
+		crate::core::validity::check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params())?;
+		Ok(TrackerCSRT_ParamsTraitConst::use_hog(self))
+	}
+	
 	#[inline]
 	fn use_color_names(&self) -> bool {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_getPropUse_color_names_const(self.as_raw_TrackerCSRT_Params()) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_getPropUse_color_names_const(self.as_raw_TrackerCSRT_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [use_color_names](TrackerCSRT_ParamsTraitConst::use_color_names), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_use_color_names(&self) -> Result<bool> {
+		crate::core::validity::check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params())?;
+		Ok(TrackerCSRT_ParamsTraitConst::use_color_names(self))
+	}
+	
 	#[inline]
 	fn use_gray(&self) -> bool {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_getPropUse_gray_const(self.as_raw_TrackerCSRT_Params()) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_getPropUse_gray_const(self.as_raw_TrackerCSRT_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [use_gray](TrackerCSRT_ParamsTraitConst::use_gray), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_use_gray(&self) -> Result<bool> {
+		crate::core::validity::check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params())?;
+		Ok(TrackerCSRT_ParamsTraitConst::use_gray(self))
+	}
+	
 	#[inline]
 	fn use_rgb(&self) -> bool {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_getPropUse_rgb_const(self.as_raw_TrackerCSRT_Params()) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_getPropUse_rgb_const(self.as_raw_TrackerCSRT_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [use_rgb](TrackerCSRT_ParamsTraitConst::use_rgb), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_use_rgb(&self) -> Result<bool> {
+		crate::core::validity::check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params())?;
+		Ok(TrackerCSRT_ParamsTraitConst::use_rgb(self))
+	}
+	
 	#[inline]
 	fn use_channel_weights(&self) -> bool {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_getPropUse_channel_weights_const(self.as_raw_TrackerCSRT_Params()) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_getPropUse_channel_weights_const(self.as_raw_TrackerCSRT_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [use_channel_weights](TrackerCSRT_ParamsTraitConst::use_channel_weights), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_use_channel_weights(&self) -> Result<bool> {
+		crate::core::validity::check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params())?;
+		Ok(TrackerCSRT_ParamsTraitConst::use_channel_weights(self))
+	}
+	
 	#[inline]
 	fn use_segmentation(&self) -> bool {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_getPropUse_segmentation_const(self.as_raw_TrackerCSRT_Params()) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_getPropUse_segmentation_const(self.as_raw_TrackerCSRT_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [use_segmentation](TrackerCSRT_ParamsTraitConst::use_segmentation), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_use_segmentation(&self) -> Result<bool> {
+		crate::core::validity::check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params())?;
+		Ok(TrackerCSRT_ParamsTraitConst::use_segmentation(self))
+	}
+	
 	/// Window function: "hann", "cheb", "kaiser"
 	#[inline]
 	fn window_function(&self) -> String {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_getPropWindow_function_const(self.as_raw_TrackerCSRT_Params()) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_getPropWindow_function_const(self.as_raw_TrackerCSRT_Params())) };
 		let ret = unsafe { String::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [window_function](TrackerCSRT_ParamsTraitConst::window_function), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_window_function(&self) -> Result<String> {
+		crate::core::validity::check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params())?;
+		Ok(TrackerCSRT_ParamsTraitConst::window_function(self))
+	}
+	
 	#[inline]
 	fn kaiser_alpha(&self) -> f32 {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_getPropKaiser_alpha_const(self.as_raw_TrackerCSRT_Params()) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_getPropKaiser_alpha_const(self.as_raw_TrackerCSRT_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [kaiser_alpha](TrackerCSRT_ParamsTraitConst::kaiser_alpha), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_kaiser_alpha(&self) -> Result<f32> {
+		crate::core::validity::check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params())?;
+		Ok(TrackerCSRT_ParamsTraitConst::kaiser_alpha(self))
+	}
+	
 	#[inline]
 	fn cheb_attenuation(&self) -> f32 {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_getPropCheb_attenuation_const(self.as_raw_TrackerCSRT_Params()) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_getPropCheb_attenuation_const(self.as_raw_TrackerCSRT_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [cheb_attenuation](TrackerCSRT_ParamsTraitConst::cheb_attenuation), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_cheb_attenuation(&self) -> Result<f32> {
+		crate::core::validity::check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params())?;
+		Ok(TrackerCSRT_ParamsTraitConst::cheb_attenuation(self))
+	}
+	
 	#[inline]
 	fn template_size(&self) -> f32 {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_getPropTemplate_size_const(self.as_raw_TrackerCSRT_Params()) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_getPropTemplate_size_const(self.as_raw_TrackerCSRT_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [template_size](TrackerCSRT_ParamsTraitConst::template_size), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_template_size(&self) -> Result<f32> {
+		crate::core::validity::check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params())?;
+		Ok(TrackerCSRT_ParamsTraitConst::template_size(self))
+	}
+	
 	#[inline]
 	fn gsl_sigma(&self) -> f32 {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_getPropGsl_sigma_const(self.as_raw_TrackerCSRT_Params()) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_getPropGsl_sigma_const(self.as_raw_TrackerCSRT_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [gsl_sigma](TrackerCSRT_ParamsTraitConst::gsl_sigma), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_gsl_sigma(&self) -> Result<f32> {
+		crate::core::validity::check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params())?;
+		Ok(TrackerCSRT_ParamsTraitConst::gsl_sigma(self))
+	}
+	
 	#[inline]
 	fn hog_orientations(&self) -> f32 {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_getPropHog_orientations_const(self.as_raw_TrackerCSRT_Params()) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_getPropHog_orientations_const(self.as_raw_TrackerCSRT_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [hog_orientations](TrackerCSRT_ParamsTraitConst::hog_orientations), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_hog_orientations(&self) -> Result<f32> {
+		crate::core::validity::check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params())?;
+		Ok(TrackerCSRT_ParamsTraitConst::hog_orientations(self))
+	}
+	
 	#[inline]
 	fn hog_clip(&self) -> f32 {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_getPropHog_clip_const(self.as_raw_TrackerCSRT_Params()) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_getPropHog_clip_const(self.as_raw_TrackerCSRT_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [hog_clip](TrackerCSRT_ParamsTraitConst::hog_clip), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_hog_clip(&self) -> Result<f32> {
+		crate::core::validity::check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params())?;
+		Ok(TrackerCSRT_ParamsTraitConst::hog_clip(self))
+	}
+	
 	#[inline]
 	fn padding(&self) -> f32 {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_getPropPadding_const(self.as_raw_TrackerCSRT_Params()) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_getPropPadding_const(self.as_raw_TrackerCSRT_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [padding](TrackerCSRT_ParamsTraitConst::padding), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_padding(&self) -> Result<f32> {
+		crate::core::validity::check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params())?;
+		Ok(TrackerCSRT_ParamsTraitConst::padding(self))
+	}
+	
 	#[inline]
 	fn filter_lr(&self) -> f32 {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_getPropFilter_lr_const(self.as_raw_TrackerCSRT_Params()) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_getPropFilter_lr_const(self.as_raw_TrackerCSRT_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [filter_lr](TrackerCSRT_ParamsTraitConst::filter_lr), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_filter_lr(&self) -> Result<f32> {
+		crate::core::validity::check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params())?;
+		Ok(TrackerCSRT_ParamsTraitConst::filter_lr(self))
+	}
+	
 	#[inline]
 	fn weights_lr(&self) -> f32 {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_getPropWeights_lr_const(self.as_raw_TrackerCSRT_Params()) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_getPropWeights_lr_const(self.as_raw_TrackerCSRT_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [weights_lr](TrackerCSRT_ParamsTraitConst::weights_lr), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_weights_lr(&self) -> Result<f32> {
+		crate::core::validity::check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params())?;
+		Ok(TrackerCSRT_ParamsTraitConst::weights_lr(self))
+	}
+	
 	#[inline]
 	fn num_hog_channels_used(&self) -> i32 {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_getPropNum_hog_channels_used_const(self.as_raw_TrackerCSRT_Params()) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_getPropNum_hog_channels_used_const(self.as_raw_TrackerCSRT_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [num_hog_channels_used](TrackerCSRT_ParamsTraitConst::num_hog_channels_used), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_num_hog_channels_used(&self) -> Result<i32> {
+		crate::core::validity::check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params())?;
+		Ok(TrackerCSRT_ParamsTraitConst::num_hog_channels_used(self))
+	}
+	
 	#[inline]
 	fn admm_iterations(&self) -> i32 {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_getPropAdmm_iterations_const(self.as_raw_TrackerCSRT_Params()) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_getPropAdmm_iterations_const(self.as_raw_TrackerCSRT_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [admm_iterations](TrackerCSRT_ParamsTraitConst::admm_iterations), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_admm_iterations(&self) -> Result<i32> {
+		crate::core::validity::check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params())?;
+		Ok(TrackerCSRT_ParamsTraitConst::admm_iterations(self))
+	}
+	
 	#[inline]
 	fn histogram_bins(&self) -> i32 {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_getPropHistogram_bins_const(self.as_raw_TrackerCSRT_Params()) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_getPropHistogram_bins_const(self.as_raw_TrackerCSRT_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [histogram_bins](TrackerCSRT_ParamsTraitConst::histogram_bins), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_histogram_bins(&self) -> Result<i32> {
+		crate::core::validity::check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params())?;
+		Ok(TrackerCSRT_ParamsTraitConst::histogram_bins(self))
+	}
+	
 	#[inline]
 	fn histogram_lr(&self) -> f32 {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_getPropHistogram_lr_const(self.as_raw_TrackerCSRT_Params()) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_getPropHistogram_lr_const(self.as_raw_TrackerCSRT_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [histogram_lr](TrackerCSRT_ParamsTraitConst::histogram_lr), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_histogram_lr(&self) -> Result<f32> {
+		crate::core::validity::check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params())?;
+		Ok(TrackerCSRT_ParamsTraitConst::histogram_lr(self))
+	}
+	
 	#[inline]
 	fn background_ratio(&self) -> i32 {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_getPropBackground_ratio_const(self.as_raw_TrackerCSRT_Params()) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_getPropBackground_ratio_const(self.as_raw_TrackerCSRT_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [background_ratio](TrackerCSRT_ParamsTraitConst::background_ratio), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_background_ratio(&self) -> Result<i32> {
+		crate::core::validity::check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params())?;
+		Ok(TrackerCSRT_ParamsTraitConst::background_ratio(self))
+	}
+	
 	#[inline]
 	fn number_of_scales(&self) -> i32 {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_getPropNumber_of_scales_const(self.as_raw_TrackerCSRT_Params()) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_getPropNumber_of_scales_const(self.as_raw_TrackerCSRT_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [number_of_scales](TrackerCSRT_ParamsTraitConst::number_of_scales), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_number_of_scales(&self) -> Result<i32> {
+		crate::core::validity::check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params())?;
+		Ok(TrackerCSRT_ParamsTraitConst::number_of_scales(self))
+	}
+	
 	#[inline]
 	fn scale_sigma_factor(&self) -> f32 {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_getPropScale_sigma_factor_const(self.as_raw_TrackerCSRT_Params()) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_getPropScale_sigma_factor_const(self.as_raw_TrackerCSRT_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [scale_sigma_factor](TrackerCSRT_ParamsTraitConst::scale_sigma_factor), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_scale_sigma_factor(&self) -> Result<f32> {
+		crate::core::validity::check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params())?;
+		Ok(TrackerCSRT_ParamsTraitConst::scale_sigma_factor(self))
+	}
+	
 	#[inline]
 	fn scale_model_max_area(&self) -> f32 {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_getPropScale_model_max_area_const(self.as_raw_TrackerCSRT_Params()) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_getPropScale_model_max_area_const(self.as_raw_TrackerCSRT_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [scale_model_max_area](TrackerCSRT_ParamsTraitConst::scale_model_max_area), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_scale_model_max_area(&self) -> Result<f32> {
+		crate::core::validity::check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params())?;
+		Ok(TrackerCSRT_ParamsTraitConst::scale_model_max_area(self))
+	}
+	
 	#[inline]
 	fn scale_lr(&self) -> f32 {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_getPropScale_lr_const(self.as_raw_TrackerCSRT_Params()) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_getPropScale_lr_const(self.as_raw_TrackerCSRT_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [scale_lr](TrackerCSRT_ParamsTraitConst::scale_lr), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_scale_lr(&self) -> Result<f32> {
+		crate::core::validity::check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params())?;
+		Ok(TrackerCSRT_ParamsTraitConst::scale_lr(self))
+	}
+	
 	#[inline]
 	fn scale_step(&self) -> f32 {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_getPropScale_step_const(self.as_raw_TrackerCSRT_Params()) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_getPropScale_step_const(self.as_raw_TrackerCSRT_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [scale_step](TrackerCSRT_ParamsTraitConst::scale_step), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_scale_step(&self) -> Result<f32> {
+		crate::core::validity::check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params())?;
+		Ok(TrackerCSRT_ParamsTraitConst::scale_step(self))
+	}
+	
 	/// we lost the target, if the psr is lower than this.
 	#[inline]
 	fn psr_threshold(&self) -> f32 {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_getPropPsr_threshold_const(self.as_raw_TrackerCSRT_Params()) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_getPropPsr_threshold_const(self.as_raw_TrackerCSRT_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [psr_threshold](TrackerCSRT_ParamsTraitConst::psr_threshold), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_psr_threshold(&self) -> Result<f32> {
+		crate::core::validity::check("TrackerCSRT_Params", self.as_raw_TrackerCSRT_Params())?;
+		Ok(TrackerCSRT_ParamsTraitConst::psr_threshold(self))
+	}
+	
 }
 
 pub trait TrackerCSRT_ParamsTrait: crate::tracking::TrackerCSRT_ParamsTraitConst {
@@ -246,166 +490,193 @@ pub trait TrackerCSRT_ParamsTrait: crate::tracking::TrackerCSRT_ParamsTraitConst
 
 	#[inline]
 	fn set_use_hog(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_setPropUse_hog_bool(self.as_raw_mut_TrackerCSRT_Params(), val) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_mut_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_setPropUse_hog_bool(self.as_raw_mut_TrackerCSRT_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_use_color_names(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_setPropUse_color_names_bool(self.as_raw_mut_TrackerCSRT_Params(), val) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_mut_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_setPropUse_color_names_bool(self.as_raw_mut_TrackerCSRT_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_use_gray(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_setPropUse_gray_bool(self.as_raw_mut_TrackerCSRT_Params(), val) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_mut_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_setPropUse_gray_bool(self.as_raw_mut_TrackerCSRT_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_use_rgb(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_setPropUse_rgb_bool(self.as_raw_mut_TrackerCSRT_Params(), val) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_mut_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_setPropUse_rgb_bool(self.as_raw_mut_TrackerCSRT_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_use_channel_weights(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_setPropUse_channel_weights_bool(self.as_raw_mut_TrackerCSRT_Params(), val) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_mut_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_setPropUse_channel_weights_bool(self.as_raw_mut_TrackerCSRT_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_use_segmentation(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_setPropUse_segmentation_bool(self.as_raw_mut_TrackerCSRT_Params(), val) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_mut_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_setPropUse_segmentation_bool(self.as_raw_mut_TrackerCSRT_Params(), val)) };
 		ret
 	}
 	
 	/// Window function: "hann", "cheb", "kaiser"
 	#[inline]
 	fn set_window_function(&mut self, val: &str) {
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_mut_TrackerCSRT_Params());
 		extern_container_arg!(nofail mut val);
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_setPropWindow_function_string(self.as_raw_mut_TrackerCSRT_Params(), val.opencv_as_extern_mut()) };
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_setPropWindow_function_string(self.as_raw_mut_TrackerCSRT_Params(), val.opencv_as_extern_mut())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_kaiser_alpha(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_setPropKaiser_alpha_float(self.as_raw_mut_TrackerCSRT_Params(), val) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_mut_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_setPropKaiser_alpha_float(self.as_raw_mut_TrackerCSRT_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_cheb_attenuation(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_setPropCheb_attenuation_float(self.as_raw_mut_TrackerCSRT_Params(), val) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_mut_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_setPropCheb_attenuation_float(self.as_raw_mut_TrackerCSRT_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_template_size(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_setPropTemplate_size_float(self.as_raw_mut_TrackerCSRT_Params(), val) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_mut_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_setPropTemplate_size_float(self.as_raw_mut_TrackerCSRT_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_gsl_sigma(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_setPropGsl_sigma_float(self.as_raw_mut_TrackerCSRT_Params(), val) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_mut_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_setPropGsl_sigma_float(self.as_raw_mut_TrackerCSRT_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_hog_orientations(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_setPropHog_orientations_float(self.as_raw_mut_TrackerCSRT_Params(), val) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_mut_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_setPropHog_orientations_float(self.as_raw_mut_TrackerCSRT_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_hog_clip(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_setPropHog_clip_float(self.as_raw_mut_TrackerCSRT_Params(), val) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_mut_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_setPropHog_clip_float(self.as_raw_mut_TrackerCSRT_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_padding(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_setPropPadding_float(self.as_raw_mut_TrackerCSRT_Params(), val) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_mut_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_setPropPadding_float(self.as_raw_mut_TrackerCSRT_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_filter_lr(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_setPropFilter_lr_float(self.as_raw_mut_TrackerCSRT_Params(), val) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_mut_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_setPropFilter_lr_float(self.as_raw_mut_TrackerCSRT_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_weights_lr(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_setPropWeights_lr_float(self.as_raw_mut_TrackerCSRT_Params(), val) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_mut_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_setPropWeights_lr_float(self.as_raw_mut_TrackerCSRT_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_num_hog_channels_used(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_setPropNum_hog_channels_used_int(self.as_raw_mut_TrackerCSRT_Params(), val) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_mut_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_setPropNum_hog_channels_used_int(self.as_raw_mut_TrackerCSRT_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_admm_iterations(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_setPropAdmm_iterations_int(self.as_raw_mut_TrackerCSRT_Params(), val) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_mut_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_setPropAdmm_iterations_int(self.as_raw_mut_TrackerCSRT_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_histogram_bins(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_setPropHistogram_bins_int(self.as_raw_mut_TrackerCSRT_Params(), val) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_mut_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_setPropHistogram_bins_int(self.as_raw_mut_TrackerCSRT_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_histogram_lr(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_setPropHistogram_lr_float(self.as_raw_mut_TrackerCSRT_Params(), val) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_mut_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_setPropHistogram_lr_float(self.as_raw_mut_TrackerCSRT_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_background_ratio(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_setPropBackground_ratio_int(self.as_raw_mut_TrackerCSRT_Params(), val) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_mut_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_setPropBackground_ratio_int(self.as_raw_mut_TrackerCSRT_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_number_of_scales(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_setPropNumber_of_scales_int(self.as_raw_mut_TrackerCSRT_Params(), val) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_mut_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_setPropNumber_of_scales_int(self.as_raw_mut_TrackerCSRT_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_scale_sigma_factor(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_setPropScale_sigma_factor_float(self.as_raw_mut_TrackerCSRT_Params(), val) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_mut_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_setPropScale_sigma_factor_float(self.as_raw_mut_TrackerCSRT_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_scale_model_max_area(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_setPropScale_model_max_area_float(self.as_raw_mut_TrackerCSRT_Params(), val) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_mut_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_setPropScale_model_max_area_float(self.as_raw_mut_TrackerCSRT_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_scale_lr(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_setPropScale_lr_float(self.as_raw_mut_TrackerCSRT_Params(), val) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_mut_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_setPropScale_lr_float(self.as_raw_mut_TrackerCSRT_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_scale_step(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_setPropScale_step_float(self.as_raw_mut_TrackerCSRT_Params(), val) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_mut_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_setPropScale_step_float(self.as_raw_mut_TrackerCSRT_Params(), val)) };
 		ret
 	}
 	
 	/// we lost the target, if the psr is lower than this.
 	#[inline]
 	fn set_psr_threshold(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_setPropPsr_threshold_float(self.as_raw_mut_TrackerCSRT_Params(), val) };
+		crate::core::validity::debug_check("TrackerCSRT_Params", self.as_raw_mut_TrackerCSRT_Params());
+		let ret = unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_setPropPsr_threshold_float(self.as_raw_mut_TrackerCSRT_Params(), val)) };
 		ret
 	}
 	
@@ -420,7 +691,10 @@ opencv_type_boxed! { TrackerCSRT_Params }
 impl Drop for TrackerCSRT_Params {
 	fn drop(&mut self) {
 		extern "C" { fn cv_TrackerCSRT_Params_delete(instance: *mut c_void); }
-		unsafe { cv_TrackerCSRT_Params_delete(self.as_raw_mut_TrackerCSRT_Params()) };
+		let ptr = self.as_raw_mut_TrackerCSRT_Params();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_TrackerCSRT_Params_delete(ptr) };
 	}
 }
 
@@ -438,7 +712,7 @@ impl TrackerCSRT_Params {
 	#[inline]
 	pub fn default() -> Result<crate::tracking::TrackerCSRT_Params> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_tracking_TrackerCSRT_Params_Params(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_tracking_TrackerCSRT_Params_Params(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::tracking::TrackerCSRT_Params::opencv_from_extern(ret) };
@@ -466,8 +740,9 @@ pub trait TrackerKCF: crate::tracking::TrackerKCFConst + crate::video::Tracker {
 	/// * pca_func: false
 	#[inline]
 	fn set_feature_extractor(&mut self, callback: crate::tracking::TrackerKCF_FeatureExtractorCallbackFN, pca_func: bool) -> Result<()> {
+		crate::core::validity::check("TrackerKCF", self.as_raw_mut_TrackerKCF())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_tracking_TrackerKCF_setFeatureExtractor_FeatureExtractorCallbackFN_bool(self.as_raw_mut_TrackerKCF(), callback, pca_func, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_tracking_TrackerKCF_setFeatureExtractor_FeatureExtractorCallbackFN_bool(self.as_raw_mut_TrackerKCF(), callback, pca_func, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -485,7 +760,7 @@ impl dyn TrackerKCF + '_ {
 	#[inline]
 	pub fn create(parameters: crate::tracking::TrackerKCF_Params) -> Result<core::Ptr<dyn crate::tracking::TrackerKCF>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_tracking_TrackerKCF_create_const_ParamsR(&parameters, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_tracking_TrackerKCF_create_const_ParamsR(&parameters, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::tracking::TrackerKCF>::opencv_from_extern(ret) };
@@ -532,7 +807,7 @@ impl TrackerKCF_Params {
 	#[inline]
 	pub fn default() -> Result<crate::tracking::TrackerKCF_Params> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_tracking_TrackerKCF_Params_Params(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_tracking_TrackerKCF_Params_Params(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
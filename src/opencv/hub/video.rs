@@ -49,7 +49,7 @@ pub const OPTFLOW_USE_INITIAL_FLOW: i32 = 4;
 pub fn cam_shift(prob_image: &dyn core::ToInputArray, window: &mut core::Rect, criteria: core::TermCriteria) -> Result<core::RotatedRect> {
 	input_array_arg!(prob_image);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_CamShift_const__InputArrayR_RectR_TermCriteria(prob_image.as_raw__InputArray(), window, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_CamShift_const__InputArrayR_RectR_TermCriteria(prob_image.as_raw__InputArray(), window, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::RotatedRect::opencv_from_extern(ret) };
@@ -83,12 +83,53 @@ pub fn build_optical_flow_pyramid(img: &dyn core::ToInputArray, pyramid: &mut dy
 	input_array_arg!(img);
 	output_array_arg!(pyramid);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_buildOpticalFlowPyramid_const__InputArrayR_const__OutputArrayR_Size_int_bool_int_int_bool(img.as_raw__InputArray(), pyramid.as_raw__OutputArray(), win_size.opencv_as_extern(), max_level, with_derivatives, pyr_border, deriv_border, try_reuse_input_image, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_buildOpticalFlowPyramid_const__InputArrayR_const__OutputArrayR_Size_int_bool_int_int_bool(img.as_raw__InputArray(), pyramid.as_raw__OutputArray(), win_size.opencv_as_extern(), max_level, with_derivatives, pyr_border, deriv_border, try_reuse_input_image, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `build_optical_flow_pyramid()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct BuildOpticalFlowPyramidBuilder<'a> {
+	img: &'a dyn core::ToInputArray,
+	pyramid: &'a mut dyn core::ToOutputArray,
+	win_size: core::Size,
+	max_level: i32,
+	with_derivatives: bool,
+	pyr_border: i32,
+	deriv_border: i32,
+	try_reuse_input_image: bool,
+}
+
+impl<'a> BuildOpticalFlowPyramidBuilder<'a> {
+	#[inline]
+	pub fn new(img: &'a dyn core::ToInputArray, pyramid: &'a mut dyn core::ToOutputArray, win_size: core::Size, max_level: i32, pyr_border: i32, deriv_border: i32) -> Self {
+		Self { img, pyramid, win_size, max_level, with_derivatives: true, pyr_border, deriv_border, try_reuse_input_image: true }
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_with_derivatives(mut self, with_derivatives: bool) -> Self {
+		self.with_derivatives = with_derivatives;
+		self
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_try_reuse_input_image(mut self, try_reuse_input_image: bool) -> Self {
+		self.try_reuse_input_image = try_reuse_input_image;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<i32> {
+		build_optical_flow_pyramid(self.img, self.pyramid, self.win_size, self.max_level, self.with_derivatives, self.pyr_border, self.deriv_border, self.try_reuse_input_image)
+	}
+}
+
 /// Computes a dense optical flow using the Gunnar Farneback's algorithm.
 /// 
 /// ## Parameters
@@ -134,7 +175,7 @@ pub fn calc_optical_flow_farneback(prev: &dyn core::ToInputArray, next: &dyn cor
 	input_array_arg!(next);
 	input_output_array_arg!(flow);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_calcOpticalFlowFarneback_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_double_int_int_int_int_double_int(prev.as_raw__InputArray(), next.as_raw__InputArray(), flow.as_raw__InputOutputArray(), pyr_scale, levels, winsize, iterations, poly_n, poly_sigma, flags, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_calcOpticalFlowFarneback_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_double_int_int_int_int_double_int(prev.as_raw__InputArray(), next.as_raw__InputArray(), flow.as_raw__InputOutputArray(), pyr_scale, levels, winsize, iterations, poly_n, poly_sigma, flags, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -204,12 +245,70 @@ pub fn calc_optical_flow_pyr_lk(prev_img: &dyn core::ToInputArray, next_img: &dy
 	output_array_arg!(status);
 	output_array_arg!(err);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_calcOpticalFlowPyrLK_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_Size_int_TermCriteria_int_double(prev_img.as_raw__InputArray(), next_img.as_raw__InputArray(), prev_pts.as_raw__InputArray(), next_pts.as_raw__InputOutputArray(), status.as_raw__OutputArray(), err.as_raw__OutputArray(), win_size.opencv_as_extern(), max_level, criteria.opencv_as_extern(), flags, min_eig_threshold, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_calcOpticalFlowPyrLK_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_Size_int_TermCriteria_int_double(prev_img.as_raw__InputArray(), next_img.as_raw__InputArray(), prev_pts.as_raw__InputArray(), next_pts.as_raw__InputOutputArray(), status.as_raw__OutputArray(), err.as_raw__OutputArray(), win_size.opencv_as_extern(), max_level, criteria.opencv_as_extern(), flags, min_eig_threshold, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `calc_optical_flow_pyr_lk()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CalcOpticalFlowPyrLkBuilder<'a> {
+	prev_img: &'a dyn core::ToInputArray,
+	next_img: &'a dyn core::ToInputArray,
+	prev_pts: &'a dyn core::ToInputArray,
+	next_pts: &'a mut dyn core::ToInputOutputArray,
+	status: &'a mut dyn core::ToOutputArray,
+	err: &'a mut dyn core::ToOutputArray,
+	win_size: core::Size,
+	max_level: i32,
+	criteria: core::TermCriteria,
+	flags: i32,
+	min_eig_threshold: f64,
+}
+
+impl<'a> CalcOpticalFlowPyrLkBuilder<'a> {
+	#[inline]
+	pub fn new(prev_img: &'a dyn core::ToInputArray, next_img: &'a dyn core::ToInputArray, prev_pts: &'a dyn core::ToInputArray, next_pts: &'a mut dyn core::ToInputOutputArray, status: &'a mut dyn core::ToOutputArray, err: &'a mut dyn core::ToOutputArray, criteria: core::TermCriteria) -> Self {
+		Self { prev_img, next_img, prev_pts, next_pts, status, err, win_size: core::Size::new(21, 21), max_level: 3, criteria, flags: 0, min_eig_threshold: 1e-4 }
+	}
+	
+	/// C++ default: `Size(21,21)`
+	#[inline]
+	pub fn with_win_size(mut self, win_size: core::Size) -> Self {
+		self.win_size = win_size;
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_max_level(mut self, max_level: i32) -> Self {
+		self.max_level = max_level;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_flags(mut self, flags: i32) -> Self {
+		self.flags = flags;
+		self
+	}
+	
+	/// C++ default: `1e-4`
+	#[inline]
+	pub fn with_min_eig_threshold(mut self, min_eig_threshold: f64) -> Self {
+		self.min_eig_threshold = min_eig_threshold;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		calc_optical_flow_pyr_lk(self.prev_img, self.next_img, self.prev_pts, self.next_pts, self.status, self.err, self.win_size, self.max_level, self.criteria, self.flags, self.min_eig_threshold)
+	}
+}
+
 /// Computes the Enhanced Correlation Coefficient value between two images [EP08](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_EP08) .
 /// 
 /// ## Parameters
@@ -218,7 +317,7 @@ pub fn calc_optical_flow_pyr_lk(prev_img: &dyn core::ToInputArray, next_img: &dy
 ///  templateImage, same type as templateImage.
 /// * inputMask: An optional mask to indicate valid values of inputImage.
 /// ## See also
-/// findTransformECC
+/// [find_transform_ecc](crate::video::find_transform_ecc)
 /// 
 /// ## C++ default parameters
 /// * input_mask: noArray()
@@ -228,7 +327,7 @@ pub fn compute_ecc(template_image: &dyn core::ToInputArray, input_image: &dyn co
 	input_array_arg!(input_image);
 	input_array_arg!(input_mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_computeECC_const__InputArrayR_const__InputArrayR_const__InputArrayR(template_image.as_raw__InputArray(), input_image.as_raw__InputArray(), input_mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_computeECC_const__InputArrayR_const__InputArrayR_const__InputArrayR(template_image.as_raw__InputArray(), input_image.as_raw__InputArray(), input_mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -250,13 +349,63 @@ pub fn compute_ecc(template_image: &dyn core::ToInputArray, input_image: &dyn co
 #[inline]
 pub fn create_background_subtractor_knn(history: i32, dist2_threshold: f64, detect_shadows: bool) -> Result<core::Ptr<dyn crate::video::BackgroundSubtractorKNN>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_createBackgroundSubtractorKNN_int_double_bool(history, dist2_threshold, detect_shadows, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_createBackgroundSubtractorKNN_int_double_bool(history, dist2_threshold, detect_shadows, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::video::BackgroundSubtractorKNN>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_background_subtractor_knn()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateBackgroundSubtractorKnnBuilder {
+	history: i32,
+	dist2_threshold: f64,
+	detect_shadows: bool,
+}
+
+impl CreateBackgroundSubtractorKnnBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { history: 500, dist2_threshold: 400.0, detect_shadows: true }
+	}
+	
+	/// C++ default: `500`
+	#[inline]
+	pub fn with_history(mut self, history: i32) -> Self {
+		self.history = history;
+		self
+	}
+	
+	/// C++ default: `400.0`
+	#[inline]
+	pub fn with_dist2_threshold(mut self, dist2_threshold: f64) -> Self {
+		self.dist2_threshold = dist2_threshold;
+		self
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_detect_shadows(mut self, detect_shadows: bool) -> Self {
+		self.detect_shadows = detect_shadows;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::video::BackgroundSubtractorKNN>> {
+		create_background_subtractor_knn(self.history, self.dist2_threshold, self.detect_shadows)
+	}
+}
+
+impl Default for CreateBackgroundSubtractorKnnBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 /// Creates MOG2 Background Subtractor
 /// 
 /// ## Parameters
@@ -274,13 +423,63 @@ pub fn create_background_subtractor_knn(history: i32, dist2_threshold: f64, dete
 #[inline]
 pub fn create_background_subtractor_mog2(history: i32, var_threshold: f64, detect_shadows: bool) -> Result<core::Ptr<dyn crate::video::BackgroundSubtractorMOG2>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_createBackgroundSubtractorMOG2_int_double_bool(history, var_threshold, detect_shadows, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_createBackgroundSubtractorMOG2_int_double_bool(history, var_threshold, detect_shadows, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::video::BackgroundSubtractorMOG2>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_background_subtractor_mog2()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateBackgroundSubtractorMog2Builder {
+	history: i32,
+	var_threshold: f64,
+	detect_shadows: bool,
+}
+
+impl CreateBackgroundSubtractorMog2Builder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { history: 500, var_threshold: 16., detect_shadows: true }
+	}
+	
+	/// C++ default: `500`
+	#[inline]
+	pub fn with_history(mut self, history: i32) -> Self {
+		self.history = history;
+		self
+	}
+	
+	/// C++ default: `16`
+	#[inline]
+	pub fn with_var_threshold(mut self, var_threshold: f64) -> Self {
+		self.var_threshold = var_threshold;
+		self
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_detect_shadows(mut self, detect_shadows: bool) -> Self {
+		self.detect_shadows = detect_shadows;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::video::BackgroundSubtractorMOG2>> {
+		create_background_subtractor_mog2(self.history, self.var_threshold, self.detect_shadows)
+	}
+}
+
+impl Default for CreateBackgroundSubtractorMog2Builder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 /// Computes an optimal affine transformation between two 2D point sets.
 /// 
 /// ## Parameters
@@ -290,7 +489,7 @@ pub fn create_background_subtractor_mog2(history: i32, var_threshold: f64, detec
 /// restrictions (6 degrees of freedom). Otherwise, the class of transformations to choose from is
 /// limited to combinations of translation, rotation, and uniform scaling (4 degrees of freedom).
 /// 
-/// The function finds an optimal affine transform *[A|b]* (a 2 x 3 floating-point matrix) that
+/// The function finds an optimal affine transform *\[A|b\]* (a 2 x 3 floating-point matrix) that
 /// approximates best the affine transformation between:
 /// 
 /// *   Two point sets
@@ -301,7 +500,7 @@ pub fn create_background_subtractor_mog2(history: i32, var_threshold: f64, detec
 /// 2x1 vector *b* so that:
 /// 
 /// ![block formula](https://latex.codecogs.com/png.latex?%5BA%5E%2A%7Cb%5E%2A%5D%20%3D%20arg%20%20%5Cmin%20%5F%7B%5BA%7Cb%5D%7D%20%20%5Csum%20%5Fi%20%20%5C%7C%20%5Ctexttt%7Bdst%7D%5Bi%5D%20%2D%20A%20%7B%20%5Ctexttt%7Bsrc%7D%5Bi%5D%7D%5ET%20%2D%20b%20%20%5C%7C%20%5E2)
-/// where src[i] and dst[i] are the i-th points in src and dst, respectively
+/// where src\[i\] and dst\[i\] are the i-th points in src and dst, respectively
 /// ![inline formula](https://latex.codecogs.com/png.latex?%5BA%7Cb%5D) can be either arbitrary (when fullAffine=true ) or have a form of
 /// ![block formula](https://latex.codecogs.com/png.latex?%5Cbegin%7Bbmatrix%7D%20a%5F%7B11%7D%20%26%20a%5F%7B12%7D%20%26%20b%5F1%20%20%5C%5C%20%2Da%5F%7B12%7D%20%26%20a%5F%7B11%7D%20%26%20b%5F2%20%20%5Cend%7Bbmatrix%7D)
 /// when fullAffine=false.
@@ -310,14 +509,14 @@ pub fn create_background_subtractor_mog2(history: i32, var_threshold: f64, detec
 /// **Deprecated**: Use cv::estimateAffine2D, cv::estimateAffinePartial2D instead. If you are using this function
 /// with images, extract points using cv::calcOpticalFlowPyrLK and then use the estimation functions.
 /// ## See also
-/// estimateAffine2D, estimateAffinePartial2D, getAffineTransform, getPerspectiveTransform, findHomography
+/// `estimate_affine_2d`, `estimate_affine_partial_2d`, `get_affine_transform`, `get_perspective_transform`, `find_homography`
 #[deprecated = "Use cv::estimateAffine2D, cv::estimateAffinePartial2D instead. If you are using this function"]
 #[inline]
 pub fn estimate_rigid_transform(src: &dyn core::ToInputArray, dst: &dyn core::ToInputArray, full_affine: bool) -> Result<core::Mat> {
 	input_array_arg!(src);
 	input_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_estimateRigidTransform_const__InputArrayR_const__InputArrayR_bool(src.as_raw__InputArray(), dst.as_raw__InputArray(), full_affine, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_estimateRigidTransform_const__InputArrayR_const__InputArrayR_bool(src.as_raw__InputArray(), dst.as_raw__InputArray(), full_affine, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -373,7 +572,7 @@ pub fn estimate_rigid_transform(src: &dyn core::ToInputArray, dst: &dyn core::To
 /// sample image_alignment.cpp that demonstrates the use of the function. Note that the function throws
 /// an exception if algorithm does not converges.
 /// ## See also
-/// computeECC, estimateAffine2D, estimateAffinePartial2D, findHomography
+/// [compute_ecc](crate::video::compute_ecc), `estimate_affine_2d`, `estimate_affine_partial_2d`, `find_homography`
 /// 
 /// ## Overloaded parameters
 /// 
@@ -388,12 +587,46 @@ pub fn find_transform_ecc_1(template_image: &dyn core::ToInputArray, input_image
 	input_output_array_arg!(warp_matrix);
 	input_array_arg!(input_mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_findTransformECC_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_int_TermCriteria_const__InputArrayR(template_image.as_raw__InputArray(), input_image.as_raw__InputArray(), warp_matrix.as_raw__InputOutputArray(), motion_type, criteria.opencv_as_extern(), input_mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_findTransformECC_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_int_TermCriteria_const__InputArrayR(template_image.as_raw__InputArray(), input_image.as_raw__InputArray(), warp_matrix.as_raw__InputOutputArray(), motion_type, criteria.opencv_as_extern(), input_mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `find_transform_ecc_1()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FindTransformEcc1Builder<'a> {
+	template_image: &'a dyn core::ToInputArray,
+	input_image: &'a dyn core::ToInputArray,
+	warp_matrix: &'a mut dyn core::ToInputOutputArray,
+	motion_type: i32,
+	criteria: core::TermCriteria,
+	input_mask: Option<&'a dyn core::ToInputArray>,
+}
+
+impl<'a> FindTransformEcc1Builder<'a> {
+	#[inline]
+	pub fn new(template_image: &'a dyn core::ToInputArray, input_image: &'a dyn core::ToInputArray, warp_matrix: &'a mut dyn core::ToInputOutputArray, motion_type: i32, criteria: core::TermCriteria) -> Self {
+		Self { template_image, input_image, warp_matrix, motion_type, criteria, input_mask: None }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_input_mask(mut self, input_mask: &'a dyn core::ToInputArray) -> Self {
+		self.input_mask = Some(input_mask);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<f64> {
+		let input_mask_no_array = core::no_array();
+		let input_mask: &dyn core::ToInputArray = match self.input_mask { Some(input_mask) => input_mask, None => &input_mask_no_array };
+		find_transform_ecc_1(self.template_image, self.input_image, self.warp_matrix, self.motion_type, self.criteria, input_mask)
+	}
+}
+
 /// Finds the geometric transform (warp) between two images in terms of the ECC criterion [EP08](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_EP08) .
 /// 
 /// ## Parameters
@@ -443,7 +676,7 @@ pub fn find_transform_ecc_1(template_image: &dyn core::ToInputArray, input_image
 /// sample image_alignment.cpp that demonstrates the use of the function. Note that the function throws
 /// an exception if algorithm does not converges.
 /// ## See also
-/// computeECC, estimateAffine2D, estimateAffinePartial2D, findHomography
+/// [compute_ecc](crate::video::compute_ecc), `estimate_affine_2d`, `estimate_affine_partial_2d`, `find_homography`
 #[inline]
 pub fn find_transform_ecc(template_image: &dyn core::ToInputArray, input_image: &dyn core::ToInputArray, warp_matrix: &mut dyn core::ToInputOutputArray, motion_type: i32, criteria: core::TermCriteria, input_mask: &dyn core::ToInputArray, gauss_filt_size: i32) -> Result<f64> {
 	input_array_arg!(template_image);
@@ -451,7 +684,7 @@ pub fn find_transform_ecc(template_image: &dyn core::ToInputArray, input_image:
 	input_output_array_arg!(warp_matrix);
 	input_array_arg!(input_mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_findTransformECC_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_int_TermCriteria_const__InputArrayR_int(template_image.as_raw__InputArray(), input_image.as_raw__InputArray(), warp_matrix.as_raw__InputOutputArray(), motion_type, criteria.opencv_as_extern(), input_mask.as_raw__InputArray(), gauss_filt_size, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_findTransformECC_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_int_TermCriteria_const__InputArrayR_int(template_image.as_raw__InputArray(), input_image.as_raw__InputArray(), warp_matrix.as_raw__InputOutputArray(), motion_type, criteria.opencv_as_extern(), input_mask.as_raw__InputArray(), gauss_filt_size, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -479,7 +712,7 @@ pub fn find_transform_ecc(template_image: &dyn core::ToInputArray, input_image:
 pub fn mean_shift(prob_image: &dyn core::ToInputArray, window: &mut core::Rect, criteria: core::TermCriteria) -> Result<i32> {
 	input_array_arg!(prob_image);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_meanShift_const__InputArrayR_RectR_TermCriteria(prob_image.as_raw__InputArray(), window, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_meanShift_const__InputArrayR_RectR_TermCriteria(prob_image.as_raw__InputArray(), window, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -497,7 +730,7 @@ pub fn mean_shift(prob_image: &dyn core::ToInputArray, window: &mut core::Rect,
 pub fn read_optical_flow(path: &str) -> Result<core::Mat> {
 	extern_container_arg!(path);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_readOpticalFlow_const_StringR(path.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_readOpticalFlow_const_StringR(path.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -518,7 +751,7 @@ pub fn write_optical_flow(path: &str, flow: &dyn core::ToInputArray) -> Result<b
 	extern_container_arg!(path);
 	input_array_arg!(flow);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_writeOpticalFlow_const_StringR_const__InputArrayR(path.opencv_as_extern(), flow.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_writeOpticalFlow_const_StringR_const__InputArrayR(path.opencv_as_extern(), flow.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -541,9 +774,10 @@ pub trait BackgroundSubtractorConst: core::AlgorithmTraitConst {
 	/// statistics.
 	#[inline]
 	fn get_background_image(&self, background_image: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractor", self.as_raw_BackgroundSubtractor())?;
 		output_array_arg!(background_image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractor_getBackgroundImage_const_const__OutputArrayR(self.as_raw_BackgroundSubtractor(), background_image.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractor_getBackgroundImage_const_const__OutputArrayR(self.as_raw_BackgroundSubtractor(), background_image.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -568,10 +802,11 @@ pub trait BackgroundSubtractor: core::AlgorithmTrait + crate::video::BackgroundS
 	/// * learning_rate: -1
 	#[inline]
 	fn apply(&mut self, image: &dyn core::ToInputArray, fgmask: &mut dyn core::ToOutputArray, learning_rate: f64) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractor", self.as_raw_mut_BackgroundSubtractor())?;
 		input_array_arg!(image);
 		output_array_arg!(fgmask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractor_apply_const__InputArrayR_const__OutputArrayR_double(self.as_raw_mut_BackgroundSubtractor(), image.as_raw__InputArray(), fgmask.as_raw__OutputArray(), learning_rate, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractor_apply_const__InputArrayR_const__OutputArrayR_double(self.as_raw_mut_BackgroundSubtractor(), image.as_raw__InputArray(), fgmask.as_raw__OutputArray(), learning_rate, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -589,8 +824,9 @@ pub trait BackgroundSubtractorKNNConst: crate::video::BackgroundSubtractorConst
 	/// Returns the number of last frames that affect the background model
 	#[inline]
 	fn get_history(&self) -> Result<i32> {
+		crate::core::validity::check("BackgroundSubtractorKNN", self.as_raw_BackgroundSubtractorKNN())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorKNN_getHistory_const(self.as_raw_BackgroundSubtractorKNN(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorKNN_getHistory_const(self.as_raw_BackgroundSubtractorKNN(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -599,8 +835,9 @@ pub trait BackgroundSubtractorKNNConst: crate::video::BackgroundSubtractorConst
 	/// Returns the number of data samples in the background model
 	#[inline]
 	fn get_n_samples(&self) -> Result<i32> {
+		crate::core::validity::check("BackgroundSubtractorKNN", self.as_raw_BackgroundSubtractorKNN())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorKNN_getNSamples_const(self.as_raw_BackgroundSubtractorKNN(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorKNN_getNSamples_const(self.as_raw_BackgroundSubtractorKNN(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -612,8 +849,9 @@ pub trait BackgroundSubtractorKNNConst: crate::video::BackgroundSubtractorConst
 	/// close to a data sample.
 	#[inline]
 	fn get_dist2_threshold(&self) -> Result<f64> {
+		crate::core::validity::check("BackgroundSubtractorKNN", self.as_raw_BackgroundSubtractorKNN())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorKNN_getDist2Threshold_const(self.as_raw_BackgroundSubtractorKNN(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorKNN_getDist2Threshold_const(self.as_raw_BackgroundSubtractorKNN(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -625,8 +863,9 @@ pub trait BackgroundSubtractorKNNConst: crate::video::BackgroundSubtractorConst
 	/// pixel is matching the kNN background model.
 	#[inline]
 	fn getk_nn_samples(&self) -> Result<i32> {
+		crate::core::validity::check("BackgroundSubtractorKNN", self.as_raw_BackgroundSubtractorKNN())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorKNN_getkNNSamples_const(self.as_raw_BackgroundSubtractorKNN(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorKNN_getkNNSamples_const(self.as_raw_BackgroundSubtractorKNN(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -638,8 +877,9 @@ pub trait BackgroundSubtractorKNNConst: crate::video::BackgroundSubtractorConst
 	/// details.
 	#[inline]
 	fn get_detect_shadows(&self) -> Result<bool> {
+		crate::core::validity::check("BackgroundSubtractorKNN", self.as_raw_BackgroundSubtractorKNN())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorKNN_getDetectShadows_const(self.as_raw_BackgroundSubtractorKNN(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorKNN_getDetectShadows_const(self.as_raw_BackgroundSubtractorKNN(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -651,8 +891,9 @@ pub trait BackgroundSubtractorKNNConst: crate::video::BackgroundSubtractorConst
 	/// in the mask always means background, 255 means foreground.
 	#[inline]
 	fn get_shadow_value(&self) -> Result<i32> {
+		crate::core::validity::check("BackgroundSubtractorKNN", self.as_raw_BackgroundSubtractorKNN())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorKNN_getShadowValue_const(self.as_raw_BackgroundSubtractorKNN(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorKNN_getShadowValue_const(self.as_raw_BackgroundSubtractorKNN(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -666,8 +907,9 @@ pub trait BackgroundSubtractorKNNConst: crate::video::BackgroundSubtractorConst
 	/// *Detecting Moving Shadows...*, IEEE PAMI,2003.
 	#[inline]
 	fn get_shadow_threshold(&self) -> Result<f64> {
+		crate::core::validity::check("BackgroundSubtractorKNN", self.as_raw_BackgroundSubtractorKNN())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorKNN_getShadowThreshold_const(self.as_raw_BackgroundSubtractorKNN(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorKNN_getShadowThreshold_const(self.as_raw_BackgroundSubtractorKNN(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -681,8 +923,9 @@ pub trait BackgroundSubtractorKNN: crate::video::BackgroundSubtractor + crate::v
 	/// Sets the number of last frames that affect the background model
 	#[inline]
 	fn set_history(&mut self, history: i32) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorKNN", self.as_raw_mut_BackgroundSubtractorKNN())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorKNN_setHistory_int(self.as_raw_mut_BackgroundSubtractorKNN(), history, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorKNN_setHistory_int(self.as_raw_mut_BackgroundSubtractorKNN(), history, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -693,8 +936,9 @@ pub trait BackgroundSubtractorKNN: crate::video::BackgroundSubtractor + crate::v
 	/// The model needs to be reinitalized to reserve memory.
 	#[inline]
 	fn set_n_samples(&mut self, _n_n: i32) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorKNN", self.as_raw_mut_BackgroundSubtractorKNN())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorKNN_setNSamples_int(self.as_raw_mut_BackgroundSubtractorKNN(), _n_n, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorKNN_setNSamples_int(self.as_raw_mut_BackgroundSubtractorKNN(), _n_n, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -703,8 +947,9 @@ pub trait BackgroundSubtractorKNN: crate::video::BackgroundSubtractor + crate::v
 	/// Sets the threshold on the squared distance
 	#[inline]
 	fn set_dist2_threshold(&mut self, _dist2_threshold: f64) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorKNN", self.as_raw_mut_BackgroundSubtractorKNN())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorKNN_setDist2Threshold_double(self.as_raw_mut_BackgroundSubtractorKNN(), _dist2_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorKNN_setDist2Threshold_double(self.as_raw_mut_BackgroundSubtractorKNN(), _dist2_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -713,8 +958,9 @@ pub trait BackgroundSubtractorKNN: crate::video::BackgroundSubtractor + crate::v
 	/// Sets the k in the kNN. How many nearest neighbours need to match.
 	#[inline]
 	fn setk_nn_samples(&mut self, _nk_nn: i32) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorKNN", self.as_raw_mut_BackgroundSubtractorKNN())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorKNN_setkNNSamples_int(self.as_raw_mut_BackgroundSubtractorKNN(), _nk_nn, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorKNN_setkNNSamples_int(self.as_raw_mut_BackgroundSubtractorKNN(), _nk_nn, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -723,8 +969,9 @@ pub trait BackgroundSubtractorKNN: crate::video::BackgroundSubtractor + crate::v
 	/// Enables or disables shadow detection
 	#[inline]
 	fn set_detect_shadows(&mut self, detect_shadows: bool) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorKNN", self.as_raw_mut_BackgroundSubtractorKNN())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorKNN_setDetectShadows_bool(self.as_raw_mut_BackgroundSubtractorKNN(), detect_shadows, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorKNN_setDetectShadows_bool(self.as_raw_mut_BackgroundSubtractorKNN(), detect_shadows, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -733,8 +980,9 @@ pub trait BackgroundSubtractorKNN: crate::video::BackgroundSubtractor + crate::v
 	/// Sets the shadow value
 	#[inline]
 	fn set_shadow_value(&mut self, value: i32) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorKNN", self.as_raw_mut_BackgroundSubtractorKNN())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorKNN_setShadowValue_int(self.as_raw_mut_BackgroundSubtractorKNN(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorKNN_setShadowValue_int(self.as_raw_mut_BackgroundSubtractorKNN(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -743,8 +991,9 @@ pub trait BackgroundSubtractorKNN: crate::video::BackgroundSubtractor + crate::v
 	/// Sets the shadow threshold
 	#[inline]
 	fn set_shadow_threshold(&mut self, threshold: f64) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorKNN", self.as_raw_mut_BackgroundSubtractorKNN())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorKNN_setShadowThreshold_double(self.as_raw_mut_BackgroundSubtractorKNN(), threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorKNN_setShadowThreshold_double(self.as_raw_mut_BackgroundSubtractorKNN(), threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -762,8 +1011,9 @@ pub trait BackgroundSubtractorMOG2Const: crate::video::BackgroundSubtractorConst
 	/// Returns the number of last frames that affect the background model
 	#[inline]
 	fn get_history(&self) -> Result<i32> {
+		crate::core::validity::check("BackgroundSubtractorMOG2", self.as_raw_BackgroundSubtractorMOG2())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorMOG2_getHistory_const(self.as_raw_BackgroundSubtractorMOG2(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorMOG2_getHistory_const(self.as_raw_BackgroundSubtractorMOG2(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -772,8 +1022,9 @@ pub trait BackgroundSubtractorMOG2Const: crate::video::BackgroundSubtractorConst
 	/// Returns the number of gaussian components in the background model
 	#[inline]
 	fn get_n_mixtures(&self) -> Result<i32> {
+		crate::core::validity::check("BackgroundSubtractorMOG2", self.as_raw_BackgroundSubtractorMOG2())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorMOG2_getNMixtures_const(self.as_raw_BackgroundSubtractorMOG2(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorMOG2_getNMixtures_const(self.as_raw_BackgroundSubtractorMOG2(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -786,8 +1037,9 @@ pub trait BackgroundSubtractorMOG2Const: crate::video::BackgroundSubtractorConst
 	/// parameter in the paper.
 	#[inline]
 	fn get_background_ratio(&self) -> Result<f64> {
+		crate::core::validity::check("BackgroundSubtractorMOG2", self.as_raw_BackgroundSubtractorMOG2())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorMOG2_getBackgroundRatio_const(self.as_raw_BackgroundSubtractorMOG2(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorMOG2_getBackgroundRatio_const(self.as_raw_BackgroundSubtractorMOG2(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -799,8 +1051,9 @@ pub trait BackgroundSubtractorMOG2Const: crate::video::BackgroundSubtractorConst
 	/// the background model or not. Related to Cthr from the paper.
 	#[inline]
 	fn get_var_threshold(&self) -> Result<f64> {
+		crate::core::validity::check("BackgroundSubtractorMOG2", self.as_raw_BackgroundSubtractorMOG2())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorMOG2_getVarThreshold_const(self.as_raw_BackgroundSubtractorMOG2(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorMOG2_getVarThreshold_const(self.as_raw_BackgroundSubtractorMOG2(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -815,8 +1068,9 @@ pub trait BackgroundSubtractorMOG2Const: crate::video::BackgroundSubtractorConst
 	/// they can grow too large.
 	#[inline]
 	fn get_var_threshold_gen(&self) -> Result<f64> {
+		crate::core::validity::check("BackgroundSubtractorMOG2", self.as_raw_BackgroundSubtractorMOG2())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorMOG2_getVarThresholdGen_const(self.as_raw_BackgroundSubtractorMOG2(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorMOG2_getVarThresholdGen_const(self.as_raw_BackgroundSubtractorMOG2(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -825,8 +1079,9 @@ pub trait BackgroundSubtractorMOG2Const: crate::video::BackgroundSubtractorConst
 	/// Returns the initial variance of each gaussian component
 	#[inline]
 	fn get_var_init(&self) -> Result<f64> {
+		crate::core::validity::check("BackgroundSubtractorMOG2", self.as_raw_BackgroundSubtractorMOG2())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorMOG2_getVarInit_const(self.as_raw_BackgroundSubtractorMOG2(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorMOG2_getVarInit_const(self.as_raw_BackgroundSubtractorMOG2(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -834,8 +1089,9 @@ pub trait BackgroundSubtractorMOG2Const: crate::video::BackgroundSubtractorConst
 	
 	#[inline]
 	fn get_var_min(&self) -> Result<f64> {
+		crate::core::validity::check("BackgroundSubtractorMOG2", self.as_raw_BackgroundSubtractorMOG2())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorMOG2_getVarMin_const(self.as_raw_BackgroundSubtractorMOG2(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorMOG2_getVarMin_const(self.as_raw_BackgroundSubtractorMOG2(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -843,8 +1099,9 @@ pub trait BackgroundSubtractorMOG2Const: crate::video::BackgroundSubtractorConst
 	
 	#[inline]
 	fn get_var_max(&self) -> Result<f64> {
+		crate::core::validity::check("BackgroundSubtractorMOG2", self.as_raw_BackgroundSubtractorMOG2())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorMOG2_getVarMax_const(self.as_raw_BackgroundSubtractorMOG2(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorMOG2_getVarMax_const(self.as_raw_BackgroundSubtractorMOG2(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -857,8 +1114,9 @@ pub trait BackgroundSubtractorMOG2Const: crate::video::BackgroundSubtractorConst
 	/// standard Stauffer&Grimson algorithm.
 	#[inline]
 	fn get_complexity_reduction_threshold(&self) -> Result<f64> {
+		crate::core::validity::check("BackgroundSubtractorMOG2", self.as_raw_BackgroundSubtractorMOG2())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorMOG2_getComplexityReductionThreshold_const(self.as_raw_BackgroundSubtractorMOG2(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorMOG2_getComplexityReductionThreshold_const(self.as_raw_BackgroundSubtractorMOG2(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -870,8 +1128,9 @@ pub trait BackgroundSubtractorMOG2Const: crate::video::BackgroundSubtractorConst
 	/// details.
 	#[inline]
 	fn get_detect_shadows(&self) -> Result<bool> {
+		crate::core::validity::check("BackgroundSubtractorMOG2", self.as_raw_BackgroundSubtractorMOG2())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorMOG2_getDetectShadows_const(self.as_raw_BackgroundSubtractorMOG2(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorMOG2_getDetectShadows_const(self.as_raw_BackgroundSubtractorMOG2(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -883,8 +1142,9 @@ pub trait BackgroundSubtractorMOG2Const: crate::video::BackgroundSubtractorConst
 	/// in the mask always means background, 255 means foreground.
 	#[inline]
 	fn get_shadow_value(&self) -> Result<i32> {
+		crate::core::validity::check("BackgroundSubtractorMOG2", self.as_raw_BackgroundSubtractorMOG2())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorMOG2_getShadowValue_const(self.as_raw_BackgroundSubtractorMOG2(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorMOG2_getShadowValue_const(self.as_raw_BackgroundSubtractorMOG2(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -898,8 +1158,9 @@ pub trait BackgroundSubtractorMOG2Const: crate::video::BackgroundSubtractorConst
 	/// *Detecting Moving Shadows...*, IEEE PAMI,2003.
 	#[inline]
 	fn get_shadow_threshold(&self) -> Result<f64> {
+		crate::core::validity::check("BackgroundSubtractorMOG2", self.as_raw_BackgroundSubtractorMOG2())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorMOG2_getShadowThreshold_const(self.as_raw_BackgroundSubtractorMOG2(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorMOG2_getShadowThreshold_const(self.as_raw_BackgroundSubtractorMOG2(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -913,8 +1174,9 @@ pub trait BackgroundSubtractorMOG2: crate::video::BackgroundSubtractor + crate::
 	/// Sets the number of last frames that affect the background model
 	#[inline]
 	fn set_history(&mut self, history: i32) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorMOG2", self.as_raw_mut_BackgroundSubtractorMOG2())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorMOG2_setHistory_int(self.as_raw_mut_BackgroundSubtractorMOG2(), history, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorMOG2_setHistory_int(self.as_raw_mut_BackgroundSubtractorMOG2(), history, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -925,8 +1187,9 @@ pub trait BackgroundSubtractorMOG2: crate::video::BackgroundSubtractor + crate::
 	/// The model needs to be reinitalized to reserve memory.
 	#[inline]
 	fn set_n_mixtures(&mut self, nmixtures: i32) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorMOG2", self.as_raw_mut_BackgroundSubtractorMOG2())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorMOG2_setNMixtures_int(self.as_raw_mut_BackgroundSubtractorMOG2(), nmixtures, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorMOG2_setNMixtures_int(self.as_raw_mut_BackgroundSubtractorMOG2(), nmixtures, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -935,8 +1198,9 @@ pub trait BackgroundSubtractorMOG2: crate::video::BackgroundSubtractor + crate::
 	/// Sets the "background ratio" parameter of the algorithm
 	#[inline]
 	fn set_background_ratio(&mut self, ratio: f64) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorMOG2", self.as_raw_mut_BackgroundSubtractorMOG2())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorMOG2_setBackgroundRatio_double(self.as_raw_mut_BackgroundSubtractorMOG2(), ratio, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorMOG2_setBackgroundRatio_double(self.as_raw_mut_BackgroundSubtractorMOG2(), ratio, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -945,8 +1209,9 @@ pub trait BackgroundSubtractorMOG2: crate::video::BackgroundSubtractor + crate::
 	/// Sets the variance threshold for the pixel-model match
 	#[inline]
 	fn set_var_threshold(&mut self, var_threshold: f64) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorMOG2", self.as_raw_mut_BackgroundSubtractorMOG2())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorMOG2_setVarThreshold_double(self.as_raw_mut_BackgroundSubtractorMOG2(), var_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorMOG2_setVarThreshold_double(self.as_raw_mut_BackgroundSubtractorMOG2(), var_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -955,8 +1220,9 @@ pub trait BackgroundSubtractorMOG2: crate::video::BackgroundSubtractor + crate::
 	/// Sets the variance threshold for the pixel-model match used for new mixture component generation
 	#[inline]
 	fn set_var_threshold_gen(&mut self, var_threshold_gen: f64) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorMOG2", self.as_raw_mut_BackgroundSubtractorMOG2())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorMOG2_setVarThresholdGen_double(self.as_raw_mut_BackgroundSubtractorMOG2(), var_threshold_gen, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorMOG2_setVarThresholdGen_double(self.as_raw_mut_BackgroundSubtractorMOG2(), var_threshold_gen, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -965,8 +1231,9 @@ pub trait BackgroundSubtractorMOG2: crate::video::BackgroundSubtractor + crate::
 	/// Sets the initial variance of each gaussian component
 	#[inline]
 	fn set_var_init(&mut self, var_init: f64) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorMOG2", self.as_raw_mut_BackgroundSubtractorMOG2())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorMOG2_setVarInit_double(self.as_raw_mut_BackgroundSubtractorMOG2(), var_init, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorMOG2_setVarInit_double(self.as_raw_mut_BackgroundSubtractorMOG2(), var_init, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -974,8 +1241,9 @@ pub trait BackgroundSubtractorMOG2: crate::video::BackgroundSubtractor + crate::
 	
 	#[inline]
 	fn set_var_min(&mut self, var_min: f64) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorMOG2", self.as_raw_mut_BackgroundSubtractorMOG2())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorMOG2_setVarMin_double(self.as_raw_mut_BackgroundSubtractorMOG2(), var_min, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorMOG2_setVarMin_double(self.as_raw_mut_BackgroundSubtractorMOG2(), var_min, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -983,8 +1251,9 @@ pub trait BackgroundSubtractorMOG2: crate::video::BackgroundSubtractor + crate::
 	
 	#[inline]
 	fn set_var_max(&mut self, var_max: f64) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorMOG2", self.as_raw_mut_BackgroundSubtractorMOG2())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorMOG2_setVarMax_double(self.as_raw_mut_BackgroundSubtractorMOG2(), var_max, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorMOG2_setVarMax_double(self.as_raw_mut_BackgroundSubtractorMOG2(), var_max, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -993,8 +1262,9 @@ pub trait BackgroundSubtractorMOG2: crate::video::BackgroundSubtractor + crate::
 	/// Sets the complexity reduction threshold
 	#[inline]
 	fn set_complexity_reduction_threshold(&mut self, ct: f64) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorMOG2", self.as_raw_mut_BackgroundSubtractorMOG2())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorMOG2_setComplexityReductionThreshold_double(self.as_raw_mut_BackgroundSubtractorMOG2(), ct, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorMOG2_setComplexityReductionThreshold_double(self.as_raw_mut_BackgroundSubtractorMOG2(), ct, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1003,8 +1273,9 @@ pub trait BackgroundSubtractorMOG2: crate::video::BackgroundSubtractor + crate::
 	/// Enables or disables shadow detection
 	#[inline]
 	fn set_detect_shadows(&mut self, detect_shadows: bool) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorMOG2", self.as_raw_mut_BackgroundSubtractorMOG2())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorMOG2_setDetectShadows_bool(self.as_raw_mut_BackgroundSubtractorMOG2(), detect_shadows, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorMOG2_setDetectShadows_bool(self.as_raw_mut_BackgroundSubtractorMOG2(), detect_shadows, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1013,8 +1284,9 @@ pub trait BackgroundSubtractorMOG2: crate::video::BackgroundSubtractor + crate::
 	/// Sets the shadow value
 	#[inline]
 	fn set_shadow_value(&mut self, value: i32) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorMOG2", self.as_raw_mut_BackgroundSubtractorMOG2())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorMOG2_setShadowValue_int(self.as_raw_mut_BackgroundSubtractorMOG2(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorMOG2_setShadowValue_int(self.as_raw_mut_BackgroundSubtractorMOG2(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1023,8 +1295,9 @@ pub trait BackgroundSubtractorMOG2: crate::video::BackgroundSubtractor + crate::
 	/// Sets the shadow threshold
 	#[inline]
 	fn set_shadow_threshold(&mut self, threshold: f64) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorMOG2", self.as_raw_mut_BackgroundSubtractorMOG2())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorMOG2_setShadowThreshold_double(self.as_raw_mut_BackgroundSubtractorMOG2(), threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorMOG2_setShadowThreshold_double(self.as_raw_mut_BackgroundSubtractorMOG2(), threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1044,10 +1317,11 @@ pub trait BackgroundSubtractorMOG2: crate::video::BackgroundSubtractor + crate::
 	/// * learning_rate: -1
 	#[inline]
 	fn apply(&mut self, image: &dyn core::ToInputArray, fgmask: &mut dyn core::ToOutputArray, learning_rate: f64) -> Result<()> {
+		crate::core::validity::check("BackgroundSubtractorMOG2", self.as_raw_mut_BackgroundSubtractorMOG2())?;
 		input_array_arg!(image);
 		output_array_arg!(fgmask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_BackgroundSubtractorMOG2_apply_const__InputArrayR_const__OutputArrayR_double(self.as_raw_mut_BackgroundSubtractorMOG2(), image.as_raw__InputArray(), fgmask.as_raw__OutputArray(), learning_rate, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_BackgroundSubtractorMOG2_apply_const__InputArrayR_const__OutputArrayR_double(self.as_raw_mut_BackgroundSubtractorMOG2(), image.as_raw__InputArray(), fgmask.as_raw__OutputArray(), learning_rate, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1063,8 +1337,8 @@ pub trait BackgroundSubtractorMOG2: crate::video::BackgroundSubtractor + crate::
 /// still relatively fast, use DeepFlow if you need better quality and don't care about speed.
 /// 
 /// This implementation includes several additional features compared to the algorithm described in the paper,
-/// including spatial propagation of flow vectors (@ref getUseSpatialPropagation), as well as an option to
-/// utilize an initial flow approximation passed to @ref calc (which is, essentially, temporal propagation,
+/// including spatial propagation of flow vectors (`get_use_spatial_propagation`), as well as an option to
+/// utilize an initial flow approximation passed to `calc` (which is, essentially, temporal propagation,
 /// if the previous frame's flow field is passed).
 pub trait DISOpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	fn as_raw_DISOpticalFlow(&self) -> *const c_void;
@@ -1072,11 +1346,12 @@ pub trait DISOpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	/// Finest level of the Gaussian pyramid on which the flow is computed (zero level
 	/// corresponds to the original image resolution). The final flow is obtained by bilinear upscaling.
 	/// ## See also
-	/// setFinestScale
+	/// `set_finest_scale`
 	#[inline]
 	fn get_finest_scale(&self) -> Result<i32> {
+		crate::core::validity::check("DISOpticalFlow", self.as_raw_DISOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DISOpticalFlow_getFinestScale_const(self.as_raw_DISOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DISOpticalFlow_getFinestScale_const(self.as_raw_DISOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1085,11 +1360,12 @@ pub trait DISOpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	/// Size of an image patch for matching (in pixels). Normally, default 8x8 patches work well
 	/// enough in most cases.
 	/// ## See also
-	/// setPatchSize
+	/// `set_patch_size`
 	#[inline]
 	fn get_patch_size(&self) -> Result<i32> {
+		crate::core::validity::check("DISOpticalFlow", self.as_raw_DISOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DISOpticalFlow_getPatchSize_const(self.as_raw_DISOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DISOpticalFlow_getPatchSize_const(self.as_raw_DISOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1098,11 +1374,12 @@ pub trait DISOpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	/// Stride between neighbor patches. Must be less than patch size. Lower values correspond
 	/// to higher flow quality.
 	/// ## See also
-	/// setPatchStride
+	/// `set_patch_stride`
 	#[inline]
 	fn get_patch_stride(&self) -> Result<i32> {
+		crate::core::validity::check("DISOpticalFlow", self.as_raw_DISOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DISOpticalFlow_getPatchStride_const(self.as_raw_DISOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DISOpticalFlow_getPatchStride_const(self.as_raw_DISOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1111,11 +1388,12 @@ pub trait DISOpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	/// Maximum number of gradient descent iterations in the patch inverse search stage. Higher values
 	/// may improve quality in some cases.
 	/// ## See also
-	/// setGradientDescentIterations
+	/// `set_gradient_descent_iterations`
 	#[inline]
 	fn get_gradient_descent_iterations(&self) -> Result<i32> {
+		crate::core::validity::check("DISOpticalFlow", self.as_raw_DISOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DISOpticalFlow_getGradientDescentIterations_const(self.as_raw_DISOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DISOpticalFlow_getGradientDescentIterations_const(self.as_raw_DISOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1125,11 +1403,12 @@ pub trait DISOpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	///    disable variational refinement completely. Higher values will typically result in more smooth and
 	///    high-quality flow.
 	/// ## See also
-	/// setGradientDescentIterations
+	/// `set_gradient_descent_iterations`
 	#[inline]
 	fn get_variational_refinement_iterations(&self) -> Result<i32> {
+		crate::core::validity::check("DISOpticalFlow", self.as_raw_DISOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DISOpticalFlow_getVariationalRefinementIterations_const(self.as_raw_DISOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DISOpticalFlow_getVariationalRefinementIterations_const(self.as_raw_DISOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1137,11 +1416,12 @@ pub trait DISOpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	
 	/// Weight of the smoothness term
 	/// ## See also
-	/// setVariationalRefinementAlpha
+	/// `set_variational_refinement_alpha`
 	#[inline]
 	fn get_variational_refinement_alpha(&self) -> Result<f32> {
+		crate::core::validity::check("DISOpticalFlow", self.as_raw_DISOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DISOpticalFlow_getVariationalRefinementAlpha_const(self.as_raw_DISOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DISOpticalFlow_getVariationalRefinementAlpha_const(self.as_raw_DISOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1149,11 +1429,12 @@ pub trait DISOpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	
 	/// Weight of the color constancy term
 	/// ## See also
-	/// setVariationalRefinementDelta
+	/// `set_variational_refinement_delta`
 	#[inline]
 	fn get_variational_refinement_delta(&self) -> Result<f32> {
+		crate::core::validity::check("DISOpticalFlow", self.as_raw_DISOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DISOpticalFlow_getVariationalRefinementDelta_const(self.as_raw_DISOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DISOpticalFlow_getVariationalRefinementDelta_const(self.as_raw_DISOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1161,11 +1442,12 @@ pub trait DISOpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	
 	/// Weight of the gradient constancy term
 	/// ## See also
-	/// setVariationalRefinementGamma
+	/// `set_variational_refinement_gamma`
 	#[inline]
 	fn get_variational_refinement_gamma(&self) -> Result<f32> {
+		crate::core::validity::check("DISOpticalFlow", self.as_raw_DISOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DISOpticalFlow_getVariationalRefinementGamma_const(self.as_raw_DISOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DISOpticalFlow_getVariationalRefinementGamma_const(self.as_raw_DISOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1176,11 +1458,12 @@ pub trait DISOpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	///    illumination variations. Turn it off if you are certain that your sequence doesn't contain any changes
 	///    in illumination.
 	/// ## See also
-	/// setUseMeanNormalization
+	/// `set_use_mean_normalization`
 	#[inline]
 	fn get_use_mean_normalization(&self) -> Result<bool> {
+		crate::core::validity::check("DISOpticalFlow", self.as_raw_DISOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DISOpticalFlow_getUseMeanNormalization_const(self.as_raw_DISOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DISOpticalFlow_getUseMeanNormalization_const(self.as_raw_DISOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1191,11 +1474,12 @@ pub trait DISOpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	///    introduced by the coarse-to-fine scheme employed by the DIS optical flow algorithm. Turning this
 	///    option off can make the output flow field a bit smoother, however.
 	/// ## See also
-	/// setUseSpatialPropagation
+	/// `set_use_spatial_propagation`
 	#[inline]
 	fn get_use_spatial_propagation(&self) -> Result<bool> {
+		crate::core::validity::check("DISOpticalFlow", self.as_raw_DISOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DISOpticalFlow_getUseSpatialPropagation_const(self.as_raw_DISOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DISOpticalFlow_getUseSpatialPropagation_const(self.as_raw_DISOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1212,8 +1496,9 @@ pub trait DISOpticalFlow: crate::video::DISOpticalFlowConst + crate::video::Dens
 	/// setFinestScale getFinestScale
 	#[inline]
 	fn set_finest_scale(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("DISOpticalFlow", self.as_raw_mut_DISOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DISOpticalFlow_setFinestScale_int(self.as_raw_mut_DISOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DISOpticalFlow_setFinestScale_int(self.as_raw_mut_DISOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1225,8 +1510,9 @@ pub trait DISOpticalFlow: crate::video::DISOpticalFlowConst + crate::video::Dens
 	/// setPatchSize getPatchSize
 	#[inline]
 	fn set_patch_size(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("DISOpticalFlow", self.as_raw_mut_DISOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DISOpticalFlow_setPatchSize_int(self.as_raw_mut_DISOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DISOpticalFlow_setPatchSize_int(self.as_raw_mut_DISOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1238,8 +1524,9 @@ pub trait DISOpticalFlow: crate::video::DISOpticalFlowConst + crate::video::Dens
 	/// setPatchStride getPatchStride
 	#[inline]
 	fn set_patch_stride(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("DISOpticalFlow", self.as_raw_mut_DISOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DISOpticalFlow_setPatchStride_int(self.as_raw_mut_DISOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DISOpticalFlow_setPatchStride_int(self.as_raw_mut_DISOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1251,8 +1538,9 @@ pub trait DISOpticalFlow: crate::video::DISOpticalFlowConst + crate::video::Dens
 	/// setGradientDescentIterations getGradientDescentIterations
 	#[inline]
 	fn set_gradient_descent_iterations(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("DISOpticalFlow", self.as_raw_mut_DISOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DISOpticalFlow_setGradientDescentIterations_int(self.as_raw_mut_DISOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DISOpticalFlow_setGradientDescentIterations_int(self.as_raw_mut_DISOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1264,8 +1552,9 @@ pub trait DISOpticalFlow: crate::video::DISOpticalFlowConst + crate::video::Dens
 	/// setGradientDescentIterations getGradientDescentIterations
 	#[inline]
 	fn set_variational_refinement_iterations(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("DISOpticalFlow", self.as_raw_mut_DISOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DISOpticalFlow_setVariationalRefinementIterations_int(self.as_raw_mut_DISOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DISOpticalFlow_setVariationalRefinementIterations_int(self.as_raw_mut_DISOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1276,8 +1565,9 @@ pub trait DISOpticalFlow: crate::video::DISOpticalFlowConst + crate::video::Dens
 	/// setVariationalRefinementAlpha getVariationalRefinementAlpha
 	#[inline]
 	fn set_variational_refinement_alpha(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("DISOpticalFlow", self.as_raw_mut_DISOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DISOpticalFlow_setVariationalRefinementAlpha_float(self.as_raw_mut_DISOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DISOpticalFlow_setVariationalRefinementAlpha_float(self.as_raw_mut_DISOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1288,8 +1578,9 @@ pub trait DISOpticalFlow: crate::video::DISOpticalFlowConst + crate::video::Dens
 	/// setVariationalRefinementDelta getVariationalRefinementDelta
 	#[inline]
 	fn set_variational_refinement_delta(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("DISOpticalFlow", self.as_raw_mut_DISOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DISOpticalFlow_setVariationalRefinementDelta_float(self.as_raw_mut_DISOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DISOpticalFlow_setVariationalRefinementDelta_float(self.as_raw_mut_DISOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1300,8 +1591,9 @@ pub trait DISOpticalFlow: crate::video::DISOpticalFlowConst + crate::video::Dens
 	/// setVariationalRefinementGamma getVariationalRefinementGamma
 	#[inline]
 	fn set_variational_refinement_gamma(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("DISOpticalFlow", self.as_raw_mut_DISOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DISOpticalFlow_setVariationalRefinementGamma_float(self.as_raw_mut_DISOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DISOpticalFlow_setVariationalRefinementGamma_float(self.as_raw_mut_DISOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1315,8 +1607,9 @@ pub trait DISOpticalFlow: crate::video::DISOpticalFlowConst + crate::video::Dens
 	/// setUseMeanNormalization getUseMeanNormalization
 	#[inline]
 	fn set_use_mean_normalization(&mut self, val: bool) -> Result<()> {
+		crate::core::validity::check("DISOpticalFlow", self.as_raw_mut_DISOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DISOpticalFlow_setUseMeanNormalization_bool(self.as_raw_mut_DISOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DISOpticalFlow_setUseMeanNormalization_bool(self.as_raw_mut_DISOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1330,8 +1623,9 @@ pub trait DISOpticalFlow: crate::video::DISOpticalFlowConst + crate::video::Dens
 	/// setUseSpatialPropagation getUseSpatialPropagation
 	#[inline]
 	fn set_use_spatial_propagation(&mut self, val: bool) -> Result<()> {
+		crate::core::validity::check("DISOpticalFlow", self.as_raw_mut_DISOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DISOpticalFlow_setUseSpatialPropagation_bool(self.as_raw_mut_DISOpticalFlow(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DISOpticalFlow_setUseSpatialPropagation_bool(self.as_raw_mut_DISOpticalFlow(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1350,7 +1644,7 @@ impl dyn DISOpticalFlow + '_ {
 	#[inline]
 	pub fn create(preset: i32) -> Result<core::Ptr<dyn crate::video::DISOpticalFlow>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DISOpticalFlow_create_int(preset, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DISOpticalFlow_create_int(preset, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::video::DISOpticalFlow>::opencv_from_extern(ret) };
@@ -1375,11 +1669,12 @@ pub trait DenseOpticalFlow: core::AlgorithmTrait + crate::video::DenseOpticalFlo
 	/// * flow: computed flow image that has the same size as prev and type CV_32FC2.
 	#[inline]
 	fn calc(&mut self, i0: &dyn core::ToInputArray, i1: &dyn core::ToInputArray, flow: &mut dyn core::ToInputOutputArray) -> Result<()> {
+		crate::core::validity::check("DenseOpticalFlow", self.as_raw_mut_DenseOpticalFlow())?;
 		input_array_arg!(i0);
 		input_array_arg!(i1);
 		input_output_array_arg!(flow);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DenseOpticalFlow_calc_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR(self.as_raw_mut_DenseOpticalFlow(), i0.as_raw__InputArray(), i1.as_raw__InputArray(), flow.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DenseOpticalFlow_calc_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR(self.as_raw_mut_DenseOpticalFlow(), i0.as_raw__InputArray(), i1.as_raw__InputArray(), flow.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1388,8 +1683,9 @@ pub trait DenseOpticalFlow: core::AlgorithmTrait + crate::video::DenseOpticalFlo
 	/// Releases all inner buffers.
 	#[inline]
 	fn collect_garbage(&mut self) -> Result<()> {
+		crate::core::validity::check("DenseOpticalFlow", self.as_raw_mut_DenseOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_DenseOpticalFlow_collectGarbage(self.as_raw_mut_DenseOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_DenseOpticalFlow_collectGarbage(self.as_raw_mut_DenseOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1403,8 +1699,9 @@ pub trait FarnebackOpticalFlowConst: crate::video::DenseOpticalFlowConst {
 
 	#[inline]
 	fn get_num_levels(&self) -> Result<i32> {
+		crate::core::validity::check("FarnebackOpticalFlow", self.as_raw_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FarnebackOpticalFlow_getNumLevels_const(self.as_raw_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FarnebackOpticalFlow_getNumLevels_const(self.as_raw_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1412,8 +1709,9 @@ pub trait FarnebackOpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	
 	#[inline]
 	fn get_pyr_scale(&self) -> Result<f64> {
+		crate::core::validity::check("FarnebackOpticalFlow", self.as_raw_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FarnebackOpticalFlow_getPyrScale_const(self.as_raw_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FarnebackOpticalFlow_getPyrScale_const(self.as_raw_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1421,8 +1719,9 @@ pub trait FarnebackOpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	
 	#[inline]
 	fn get_fast_pyramids(&self) -> Result<bool> {
+		crate::core::validity::check("FarnebackOpticalFlow", self.as_raw_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FarnebackOpticalFlow_getFastPyramids_const(self.as_raw_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FarnebackOpticalFlow_getFastPyramids_const(self.as_raw_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1430,8 +1729,9 @@ pub trait FarnebackOpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	
 	#[inline]
 	fn get_win_size(&self) -> Result<i32> {
+		crate::core::validity::check("FarnebackOpticalFlow", self.as_raw_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FarnebackOpticalFlow_getWinSize_const(self.as_raw_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FarnebackOpticalFlow_getWinSize_const(self.as_raw_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1439,8 +1739,9 @@ pub trait FarnebackOpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	
 	#[inline]
 	fn get_num_iters(&self) -> Result<i32> {
+		crate::core::validity::check("FarnebackOpticalFlow", self.as_raw_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FarnebackOpticalFlow_getNumIters_const(self.as_raw_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FarnebackOpticalFlow_getNumIters_const(self.as_raw_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1448,8 +1749,9 @@ pub trait FarnebackOpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	
 	#[inline]
 	fn get_poly_n(&self) -> Result<i32> {
+		crate::core::validity::check("FarnebackOpticalFlow", self.as_raw_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FarnebackOpticalFlow_getPolyN_const(self.as_raw_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FarnebackOpticalFlow_getPolyN_const(self.as_raw_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1457,8 +1759,9 @@ pub trait FarnebackOpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	
 	#[inline]
 	fn get_poly_sigma(&self) -> Result<f64> {
+		crate::core::validity::check("FarnebackOpticalFlow", self.as_raw_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FarnebackOpticalFlow_getPolySigma_const(self.as_raw_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FarnebackOpticalFlow_getPolySigma_const(self.as_raw_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1466,8 +1769,9 @@ pub trait FarnebackOpticalFlowConst: crate::video::DenseOpticalFlowConst {
 	
 	#[inline]
 	fn get_flags(&self) -> Result<i32> {
+		crate::core::validity::check("FarnebackOpticalFlow", self.as_raw_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FarnebackOpticalFlow_getFlags_const(self.as_raw_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FarnebackOpticalFlow_getFlags_const(self.as_raw_FarnebackOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1480,8 +1784,9 @@ pub trait FarnebackOpticalFlow: crate::video::DenseOpticalFlow + crate::video::F
 
 	#[inline]
 	fn set_num_levels(&mut self, num_levels: i32) -> Result<()> {
+		crate::core::validity::check("FarnebackOpticalFlow", self.as_raw_mut_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FarnebackOpticalFlow_setNumLevels_int(self.as_raw_mut_FarnebackOpticalFlow(), num_levels, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FarnebackOpticalFlow_setNumLevels_int(self.as_raw_mut_FarnebackOpticalFlow(), num_levels, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1489,8 +1794,9 @@ pub trait FarnebackOpticalFlow: crate::video::DenseOpticalFlow + crate::video::F
 	
 	#[inline]
 	fn set_pyr_scale(&mut self, pyr_scale: f64) -> Result<()> {
+		crate::core::validity::check("FarnebackOpticalFlow", self.as_raw_mut_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FarnebackOpticalFlow_setPyrScale_double(self.as_raw_mut_FarnebackOpticalFlow(), pyr_scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FarnebackOpticalFlow_setPyrScale_double(self.as_raw_mut_FarnebackOpticalFlow(), pyr_scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1498,8 +1804,9 @@ pub trait FarnebackOpticalFlow: crate::video::DenseOpticalFlow + crate::video::F
 	
 	#[inline]
 	fn set_fast_pyramids(&mut self, fast_pyramids: bool) -> Result<()> {
+		crate::core::validity::check("FarnebackOpticalFlow", self.as_raw_mut_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FarnebackOpticalFlow_setFastPyramids_bool(self.as_raw_mut_FarnebackOpticalFlow(), fast_pyramids, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FarnebackOpticalFlow_setFastPyramids_bool(self.as_raw_mut_FarnebackOpticalFlow(), fast_pyramids, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1507,8 +1814,9 @@ pub trait FarnebackOpticalFlow: crate::video::DenseOpticalFlow + crate::video::F
 	
 	#[inline]
 	fn set_win_size(&mut self, win_size: i32) -> Result<()> {
+		crate::core::validity::check("FarnebackOpticalFlow", self.as_raw_mut_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FarnebackOpticalFlow_setWinSize_int(self.as_raw_mut_FarnebackOpticalFlow(), win_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FarnebackOpticalFlow_setWinSize_int(self.as_raw_mut_FarnebackOpticalFlow(), win_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1516,8 +1824,9 @@ pub trait FarnebackOpticalFlow: crate::video::DenseOpticalFlow + crate::video::F
 	
 	#[inline]
 	fn set_num_iters(&mut self, num_iters: i32) -> Result<()> {
+		crate::core::validity::check("FarnebackOpticalFlow", self.as_raw_mut_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FarnebackOpticalFlow_setNumIters_int(self.as_raw_mut_FarnebackOpticalFlow(), num_iters, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FarnebackOpticalFlow_setNumIters_int(self.as_raw_mut_FarnebackOpticalFlow(), num_iters, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1525,8 +1834,9 @@ pub trait FarnebackOpticalFlow: crate::video::DenseOpticalFlow + crate::video::F
 	
 	#[inline]
 	fn set_poly_n(&mut self, poly_n: i32) -> Result<()> {
+		crate::core::validity::check("FarnebackOpticalFlow", self.as_raw_mut_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FarnebackOpticalFlow_setPolyN_int(self.as_raw_mut_FarnebackOpticalFlow(), poly_n, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FarnebackOpticalFlow_setPolyN_int(self.as_raw_mut_FarnebackOpticalFlow(), poly_n, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1534,8 +1844,9 @@ pub trait FarnebackOpticalFlow: crate::video::DenseOpticalFlow + crate::video::F
 	
 	#[inline]
 	fn set_poly_sigma(&mut self, poly_sigma: f64) -> Result<()> {
+		crate::core::validity::check("FarnebackOpticalFlow", self.as_raw_mut_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FarnebackOpticalFlow_setPolySigma_double(self.as_raw_mut_FarnebackOpticalFlow(), poly_sigma, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FarnebackOpticalFlow_setPolySigma_double(self.as_raw_mut_FarnebackOpticalFlow(), poly_sigma, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1543,8 +1854,9 @@ pub trait FarnebackOpticalFlow: crate::video::DenseOpticalFlow + crate::video::F
 	
 	#[inline]
 	fn set_flags(&mut self, flags: i32) -> Result<()> {
+		crate::core::validity::check("FarnebackOpticalFlow", self.as_raw_mut_FarnebackOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FarnebackOpticalFlow_setFlags_int(self.as_raw_mut_FarnebackOpticalFlow(), flags, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FarnebackOpticalFlow_setFlags_int(self.as_raw_mut_FarnebackOpticalFlow(), flags, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1565,7 +1877,7 @@ impl dyn FarnebackOpticalFlow + '_ {
 	#[inline]
 	pub fn create(num_levels: i32, pyr_scale: f64, fast_pyramids: bool, win_size: i32, num_iters: i32, poly_n: i32, poly_sigma: f64, flags: i32) -> Result<core::Ptr<dyn crate::video::FarnebackOpticalFlow>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_FarnebackOpticalFlow_create_int_double_bool_int_int_int_double_int(num_levels, pyr_scale, fast_pyramids, win_size, num_iters, poly_n, poly_sigma, flags, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_FarnebackOpticalFlow_create_int_double_bool_int_int_int_double_int(num_levels, pyr_scale, fast_pyramids, win_size, num_iters, poly_n, poly_sigma, flags, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::video::FarnebackOpticalFlow>::opencv_from_extern(ret) };
@@ -1573,6 +1885,96 @@ impl dyn FarnebackOpticalFlow + '_ {
 	}
 	
 }
+
+/// Builder for `<dyn FarnebackOpticalFlow>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FarnebackOpticalFlow_CreateBuilder {
+	num_levels: i32,
+	pyr_scale: f64,
+	fast_pyramids: bool,
+	win_size: i32,
+	num_iters: i32,
+	poly_n: i32,
+	poly_sigma: f64,
+	flags: i32,
+}
+
+impl FarnebackOpticalFlow_CreateBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { num_levels: 5, pyr_scale: 0.5, fast_pyramids: false, win_size: 13, num_iters: 10, poly_n: 5, poly_sigma: 1.1, flags: 0 }
+	}
+	
+	/// C++ default: `5`
+	#[inline]
+	pub fn with_num_levels(mut self, num_levels: i32) -> Self {
+		self.num_levels = num_levels;
+		self
+	}
+	
+	/// C++ default: `0.5`
+	#[inline]
+	pub fn with_pyr_scale(mut self, pyr_scale: f64) -> Self {
+		self.pyr_scale = pyr_scale;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_fast_pyramids(mut self, fast_pyramids: bool) -> Self {
+		self.fast_pyramids = fast_pyramids;
+		self
+	}
+	
+	/// C++ default: `13`
+	#[inline]
+	pub fn with_win_size(mut self, win_size: i32) -> Self {
+		self.win_size = win_size;
+		self
+	}
+	
+	/// C++ default: `10`
+	#[inline]
+	pub fn with_num_iters(mut self, num_iters: i32) -> Self {
+		self.num_iters = num_iters;
+		self
+	}
+	
+	/// C++ default: `5`
+	#[inline]
+	pub fn with_poly_n(mut self, poly_n: i32) -> Self {
+		self.poly_n = poly_n;
+		self
+	}
+	
+	/// C++ default: `1.1`
+	#[inline]
+	pub fn with_poly_sigma(mut self, poly_sigma: f64) -> Self {
+		self.poly_sigma = poly_sigma;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_flags(mut self, flags: i32) -> Self {
+		self.flags = flags;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::video::FarnebackOpticalFlow>> {
+		<dyn FarnebackOpticalFlow>::create(self.num_levels, self.pyr_scale, self.fast_pyramids, self.win_size, self.num_iters, self.poly_n, self.poly_sigma, self.flags)
+	}
+}
+
+impl Default for FarnebackOpticalFlow_CreateBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
 /// Kalman filter class.
 /// 
 /// The class implements a standard Kalman filter <http://en.wikipedia.org/wiki/Kalman_filter>,
@@ -1587,118 +1989,253 @@ pub trait KalmanFilterTraitConst {
 	/// predicted state (x'(k)): x(k)=A*x(k-1)+B*u(k)
 	#[inline]
 	fn state_pre(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_KalmanFilter_getPropStatePre_const(self.as_raw_KalmanFilter()) };
+		crate::core::validity::debug_check("KalmanFilter", self.as_raw_KalmanFilter());
+		let ret = unsafe { ffi_call!(cv_KalmanFilter_getPropStatePre_const(self.as_raw_KalmanFilter())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [state_pre](KalmanFilterTraitConst::state_pre), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_state_pre(&self) -> Result<core::Mat> {
+		crate::core::validity::check("KalmanFilter", self.as_raw_KalmanFilter())?;
+		Ok(KalmanFilterTraitConst::state_pre(self))
+	}
+	
 	/// corrected state (x(k)): x(k)=x'(k)+K(k)*(z(k)-H*x'(k))
 	#[inline]
 	fn state_post(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_KalmanFilter_getPropStatePost_const(self.as_raw_KalmanFilter()) };
+		crate::core::validity::debug_check("KalmanFilter", self.as_raw_KalmanFilter());
+		let ret = unsafe { ffi_call!(cv_KalmanFilter_getPropStatePost_const(self.as_raw_KalmanFilter())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [state_post](KalmanFilterTraitConst::state_post), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_state_post(&self) -> Result<core::Mat> {
+		crate::core::validity::check("KalmanFilter", self.as_raw_KalmanFilter())?;
+		Ok(KalmanFilterTraitConst::state_post(self))
+	}
+	
 	/// state transition matrix (A)
 	#[inline]
 	fn transition_matrix(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_KalmanFilter_getPropTransitionMatrix_const(self.as_raw_KalmanFilter()) };
+		crate::core::validity::debug_check("KalmanFilter", self.as_raw_KalmanFilter());
+		let ret = unsafe { ffi_call!(cv_KalmanFilter_getPropTransitionMatrix_const(self.as_raw_KalmanFilter())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [transition_matrix](KalmanFilterTraitConst::transition_matrix), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_transition_matrix(&self) -> Result<core::Mat> {
+		crate::core::validity::check("KalmanFilter", self.as_raw_KalmanFilter())?;
+		Ok(KalmanFilterTraitConst::transition_matrix(self))
+	}
+	
 	/// control matrix (B) (not used if there is no control)
 	#[inline]
 	fn control_matrix(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_KalmanFilter_getPropControlMatrix_const(self.as_raw_KalmanFilter()) };
+		crate::core::validity::debug_check("KalmanFilter", self.as_raw_KalmanFilter());
+		let ret = unsafe { ffi_call!(cv_KalmanFilter_getPropControlMatrix_const(self.as_raw_KalmanFilter())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [control_matrix](KalmanFilterTraitConst::control_matrix), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_control_matrix(&self) -> Result<core::Mat> {
+		crate::core::validity::check("KalmanFilter", self.as_raw_KalmanFilter())?;
+		Ok(KalmanFilterTraitConst::control_matrix(self))
+	}
+	
 	/// measurement matrix (H)
 	#[inline]
 	fn measurement_matrix(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_KalmanFilter_getPropMeasurementMatrix_const(self.as_raw_KalmanFilter()) };
+		crate::core::validity::debug_check("KalmanFilter", self.as_raw_KalmanFilter());
+		let ret = unsafe { ffi_call!(cv_KalmanFilter_getPropMeasurementMatrix_const(self.as_raw_KalmanFilter())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [measurement_matrix](KalmanFilterTraitConst::measurement_matrix), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_measurement_matrix(&self) -> Result<core::Mat> {
+		crate::core::validity::check("KalmanFilter", self.as_raw_KalmanFilter())?;
+		Ok(KalmanFilterTraitConst::measurement_matrix(self))
+	}
+	
 	/// process noise covariance matrix (Q)
 	#[inline]
 	fn process_noise_cov(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_KalmanFilter_getPropProcessNoiseCov_const(self.as_raw_KalmanFilter()) };
+		crate::core::validity::debug_check("KalmanFilter", self.as_raw_KalmanFilter());
+		let ret = unsafe { ffi_call!(cv_KalmanFilter_getPropProcessNoiseCov_const(self.as_raw_KalmanFilter())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [process_noise_cov](KalmanFilterTraitConst::process_noise_cov), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_process_noise_cov(&self) -> Result<core::Mat> {
+		crate::core::validity::check("KalmanFilter", self.as_raw_KalmanFilter())?;
+		Ok(KalmanFilterTraitConst::process_noise_cov(self))
+	}
+	
 	/// measurement noise covariance matrix (R)
 	#[inline]
 	fn measurement_noise_cov(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_KalmanFilter_getPropMeasurementNoiseCov_const(self.as_raw_KalmanFilter()) };
+		crate::core::validity::debug_check("KalmanFilter", self.as_raw_KalmanFilter());
+		let ret = unsafe { ffi_call!(cv_KalmanFilter_getPropMeasurementNoiseCov_const(self.as_raw_KalmanFilter())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [measurement_noise_cov](KalmanFilterTraitConst::measurement_noise_cov), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_measurement_noise_cov(&self) -> Result<core::Mat> {
+		crate::core::validity::check("KalmanFilter", self.as_raw_KalmanFilter())?;
+		Ok(KalmanFilterTraitConst::measurement_noise_cov(self))
+	}
+	
 	/// priori error estimate covariance matrix (P'(k)): P'(k)=A*P(k-1)*At + Q)
 	#[inline]
 	fn error_cov_pre(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_KalmanFilter_getPropErrorCovPre_const(self.as_raw_KalmanFilter()) };
+		crate::core::validity::debug_check("KalmanFilter", self.as_raw_KalmanFilter());
+		let ret = unsafe { ffi_call!(cv_KalmanFilter_getPropErrorCovPre_const(self.as_raw_KalmanFilter())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [error_cov_pre](KalmanFilterTraitConst::error_cov_pre), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_error_cov_pre(&self) -> Result<core::Mat> {
+		crate::core::validity::check("KalmanFilter", self.as_raw_KalmanFilter())?;
+		Ok(KalmanFilterTraitConst::error_cov_pre(self))
+	}
+	
 	/// Kalman gain matrix (K(k)): K(k)=P'(k)*Ht*inv(H*P'(k)*Ht+R)
 	#[inline]
 	fn gain(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_KalmanFilter_getPropGain_const(self.as_raw_KalmanFilter()) };
+		crate::core::validity::debug_check("KalmanFilter", self.as_raw_KalmanFilter());
+		let ret = unsafe { ffi_call!(cv_KalmanFilter_getPropGain_const(self.as_raw_KalmanFilter())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [gain](KalmanFilterTraitConst::gain), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_gain(&self) -> Result<core::Mat> {
+		crate::core::validity::check("KalmanFilter", self.as_raw_KalmanFilter())?;
+		Ok(KalmanFilterTraitConst::gain(self))
+	}
+	
 	/// posteriori error estimate covariance matrix (P(k)): P(k)=(I-K(k)*H)*P'(k)
 	#[inline]
 	fn error_cov_post(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_KalmanFilter_getPropErrorCovPost_const(self.as_raw_KalmanFilter()) };
+		crate::core::validity::debug_check("KalmanFilter", self.as_raw_KalmanFilter());
+		let ret = unsafe { ffi_call!(cv_KalmanFilter_getPropErrorCovPost_const(self.as_raw_KalmanFilter())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [error_cov_post](KalmanFilterTraitConst::error_cov_post), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_error_cov_post(&self) -> Result<core::Mat> {
+		crate::core::validity::check("KalmanFilter", self.as_raw_KalmanFilter())?;
+		Ok(KalmanFilterTraitConst::error_cov_post(self))
+	}
+	
 	#[inline]
 	fn temp1(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_KalmanFilter_getPropTemp1_const(self.as_raw_KalmanFilter()) };
+		crate::core::validity::debug_check("KalmanFilter", self.as_raw_KalmanFilter());
+		let ret = unsafe { ffi_call!(cv_KalmanFilter_getPropTemp1_const(self.as_raw_KalmanFilter())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [temp1](KalmanFilterTraitConst::temp1), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_temp1(&self) -> Result<core::Mat> {
+		crate::core::validity::check("KalmanFilter", self.as_raw_KalmanFilter())?;
+		Ok(KalmanFilterTraitConst::temp1(self))
+	}
+	
 	#[inline]
 	fn temp2(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_KalmanFilter_getPropTemp2_const(self.as_raw_KalmanFilter()) };
+		crate::core::validity::debug_check("KalmanFilter", self.as_raw_KalmanFilter());
+		let ret = unsafe { ffi_call!(cv_KalmanFilter_getPropTemp2_const(self.as_raw_KalmanFilter())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [temp2](KalmanFilterTraitConst::temp2), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_temp2(&self) -> Result<core::Mat> {
+		crate::core::validity::check("KalmanFilter", self.as_raw_KalmanFilter())?;
+		Ok(KalmanFilterTraitConst::temp2(self))
+	}
+	
 	#[inline]
 	fn temp3(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_KalmanFilter_getPropTemp3_const(self.as_raw_KalmanFilter()) };
+		crate::core::validity::debug_check("KalmanFilter", self.as_raw_KalmanFilter());
+		let ret = unsafe { ffi_call!(cv_KalmanFilter_getPropTemp3_const(self.as_raw_KalmanFilter())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [temp3](KalmanFilterTraitConst::temp3), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_temp3(&self) -> Result<core::Mat> {
+		crate::core::validity::check("KalmanFilter", self.as_raw_KalmanFilter())?;
+		Ok(KalmanFilterTraitConst::temp3(self))
+	}
+	
 	#[inline]
 	fn temp4(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_KalmanFilter_getPropTemp4_const(self.as_raw_KalmanFilter()) };
+		crate::core::validity::debug_check("KalmanFilter", self.as_raw_KalmanFilter());
+		let ret = unsafe { ffi_call!(cv_KalmanFilter_getPropTemp4_const(self.as_raw_KalmanFilter())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [temp4](KalmanFilterTraitConst::temp4), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_temp4(&self) -> Result<core::Mat> {
+		crate::core::validity::check("KalmanFilter", self.as_raw_KalmanFilter())?;
+		Ok(KalmanFilterTraitConst::temp4(self))
+	}
+	
 	#[inline]
 	fn temp5(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_KalmanFilter_getPropTemp5_const(self.as_raw_KalmanFilter()) };
+		crate::core::validity::debug_check("KalmanFilter", self.as_raw_KalmanFilter());
+		let ret = unsafe { ffi_call!(cv_KalmanFilter_getPropTemp5_const(self.as_raw_KalmanFilter())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [temp5](KalmanFilterTraitConst::temp5), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_temp5(&self) -> Result<core::Mat> {
+		crate::core::validity::check("KalmanFilter", self.as_raw_KalmanFilter())?;
+		Ok(KalmanFilterTraitConst::temp5(self))
+	}
+	
 }
 
 pub trait KalmanFilterTrait: crate::video::KalmanFilterTraitConst {
@@ -1707,100 +2244,115 @@ pub trait KalmanFilterTrait: crate::video::KalmanFilterTraitConst {
 	/// predicted state (x'(k)): x(k)=A*x(k-1)+B*u(k)
 	#[inline]
 	fn set_state_pre(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_KalmanFilter_setPropStatePre_Mat(self.as_raw_mut_KalmanFilter(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("KalmanFilter", self.as_raw_mut_KalmanFilter());
+		let ret = unsafe { ffi_call!(cv_KalmanFilter_setPropStatePre_Mat(self.as_raw_mut_KalmanFilter(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	/// corrected state (x(k)): x(k)=x'(k)+K(k)*(z(k)-H*x'(k))
 	#[inline]
 	fn set_state_post(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_KalmanFilter_setPropStatePost_Mat(self.as_raw_mut_KalmanFilter(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("KalmanFilter", self.as_raw_mut_KalmanFilter());
+		let ret = unsafe { ffi_call!(cv_KalmanFilter_setPropStatePost_Mat(self.as_raw_mut_KalmanFilter(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	/// state transition matrix (A)
 	#[inline]
 	fn set_transition_matrix(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_KalmanFilter_setPropTransitionMatrix_Mat(self.as_raw_mut_KalmanFilter(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("KalmanFilter", self.as_raw_mut_KalmanFilter());
+		let ret = unsafe { ffi_call!(cv_KalmanFilter_setPropTransitionMatrix_Mat(self.as_raw_mut_KalmanFilter(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	/// control matrix (B) (not used if there is no control)
 	#[inline]
 	fn set_control_matrix(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_KalmanFilter_setPropControlMatrix_Mat(self.as_raw_mut_KalmanFilter(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("KalmanFilter", self.as_raw_mut_KalmanFilter());
+		let ret = unsafe { ffi_call!(cv_KalmanFilter_setPropControlMatrix_Mat(self.as_raw_mut_KalmanFilter(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	/// measurement matrix (H)
 	#[inline]
 	fn set_measurement_matrix(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_KalmanFilter_setPropMeasurementMatrix_Mat(self.as_raw_mut_KalmanFilter(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("KalmanFilter", self.as_raw_mut_KalmanFilter());
+		let ret = unsafe { ffi_call!(cv_KalmanFilter_setPropMeasurementMatrix_Mat(self.as_raw_mut_KalmanFilter(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	/// process noise covariance matrix (Q)
 	#[inline]
 	fn set_process_noise_cov(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_KalmanFilter_setPropProcessNoiseCov_Mat(self.as_raw_mut_KalmanFilter(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("KalmanFilter", self.as_raw_mut_KalmanFilter());
+		let ret = unsafe { ffi_call!(cv_KalmanFilter_setPropProcessNoiseCov_Mat(self.as_raw_mut_KalmanFilter(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	/// measurement noise covariance matrix (R)
 	#[inline]
 	fn set_measurement_noise_cov(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_KalmanFilter_setPropMeasurementNoiseCov_Mat(self.as_raw_mut_KalmanFilter(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("KalmanFilter", self.as_raw_mut_KalmanFilter());
+		let ret = unsafe { ffi_call!(cv_KalmanFilter_setPropMeasurementNoiseCov_Mat(self.as_raw_mut_KalmanFilter(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	/// priori error estimate covariance matrix (P'(k)): P'(k)=A*P(k-1)*At + Q)
 	#[inline]
 	fn set_error_cov_pre(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_KalmanFilter_setPropErrorCovPre_Mat(self.as_raw_mut_KalmanFilter(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("KalmanFilter", self.as_raw_mut_KalmanFilter());
+		let ret = unsafe { ffi_call!(cv_KalmanFilter_setPropErrorCovPre_Mat(self.as_raw_mut_KalmanFilter(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	/// Kalman gain matrix (K(k)): K(k)=P'(k)*Ht*inv(H*P'(k)*Ht+R)
 	#[inline]
 	fn set_gain(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_KalmanFilter_setPropGain_Mat(self.as_raw_mut_KalmanFilter(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("KalmanFilter", self.as_raw_mut_KalmanFilter());
+		let ret = unsafe { ffi_call!(cv_KalmanFilter_setPropGain_Mat(self.as_raw_mut_KalmanFilter(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	/// posteriori error estimate covariance matrix (P(k)): P(k)=(I-K(k)*H)*P'(k)
 	#[inline]
 	fn set_error_cov_post(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_KalmanFilter_setPropErrorCovPost_Mat(self.as_raw_mut_KalmanFilter(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("KalmanFilter", self.as_raw_mut_KalmanFilter());
+		let ret = unsafe { ffi_call!(cv_KalmanFilter_setPropErrorCovPost_Mat(self.as_raw_mut_KalmanFilter(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_temp1(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_KalmanFilter_setPropTemp1_Mat(self.as_raw_mut_KalmanFilter(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("KalmanFilter", self.as_raw_mut_KalmanFilter());
+		let ret = unsafe { ffi_call!(cv_KalmanFilter_setPropTemp1_Mat(self.as_raw_mut_KalmanFilter(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_temp2(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_KalmanFilter_setPropTemp2_Mat(self.as_raw_mut_KalmanFilter(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("KalmanFilter", self.as_raw_mut_KalmanFilter());
+		let ret = unsafe { ffi_call!(cv_KalmanFilter_setPropTemp2_Mat(self.as_raw_mut_KalmanFilter(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_temp3(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_KalmanFilter_setPropTemp3_Mat(self.as_raw_mut_KalmanFilter(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("KalmanFilter", self.as_raw_mut_KalmanFilter());
+		let ret = unsafe { ffi_call!(cv_KalmanFilter_setPropTemp3_Mat(self.as_raw_mut_KalmanFilter(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_temp4(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_KalmanFilter_setPropTemp4_Mat(self.as_raw_mut_KalmanFilter(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("KalmanFilter", self.as_raw_mut_KalmanFilter());
+		let ret = unsafe { ffi_call!(cv_KalmanFilter_setPropTemp4_Mat(self.as_raw_mut_KalmanFilter(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_temp5(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_KalmanFilter_setPropTemp5_Mat(self.as_raw_mut_KalmanFilter(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("KalmanFilter", self.as_raw_mut_KalmanFilter());
+		let ret = unsafe { ffi_call!(cv_KalmanFilter_setPropTemp5_Mat(self.as_raw_mut_KalmanFilter(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
@@ -1817,8 +2369,9 @@ pub trait KalmanFilterTrait: crate::video::KalmanFilterTraitConst {
 	/// * typ: CV_32F
 	#[inline]
 	fn init(&mut self, dynam_params: i32, measure_params: i32, control_params: i32, typ: i32) -> Result<()> {
+		crate::core::validity::check("KalmanFilter", self.as_raw_mut_KalmanFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_KalmanFilter_init_int_int_int_int(self.as_raw_mut_KalmanFilter(), dynam_params, measure_params, control_params, typ, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_KalmanFilter_init_int_int_int_int(self.as_raw_mut_KalmanFilter(), dynam_params, measure_params, control_params, typ, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1833,8 +2386,9 @@ pub trait KalmanFilterTrait: crate::video::KalmanFilterTraitConst {
 	/// * control: Mat()
 	#[inline]
 	fn predict(&mut self, control: &core::Mat) -> Result<core::Mat> {
+		crate::core::validity::check("KalmanFilter", self.as_raw_mut_KalmanFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_KalmanFilter_predict_const_MatR(self.as_raw_mut_KalmanFilter(), control.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_KalmanFilter_predict_const_MatR(self.as_raw_mut_KalmanFilter(), control.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -1847,8 +2401,9 @@ pub trait KalmanFilterTrait: crate::video::KalmanFilterTraitConst {
 	/// * measurement: The measured system parameters
 	#[inline]
 	fn correct(&mut self, measurement: &core::Mat) -> Result<core::Mat> {
+		crate::core::validity::check("KalmanFilter", self.as_raw_mut_KalmanFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_KalmanFilter_correct_const_MatR(self.as_raw_mut_KalmanFilter(), measurement.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_KalmanFilter_correct_const_MatR(self.as_raw_mut_KalmanFilter(), measurement.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -1874,7 +2429,10 @@ opencv_type_boxed! { KalmanFilter }
 impl Drop for KalmanFilter {
 	fn drop(&mut self) {
 		extern "C" { fn cv_KalmanFilter_delete(instance: *mut c_void); }
-		unsafe { cv_KalmanFilter_delete(self.as_raw_mut_KalmanFilter()) };
+		let ptr = self.as_raw_mut_KalmanFilter();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_KalmanFilter_delete(ptr) };
 	}
 }
 
@@ -1892,7 +2450,7 @@ impl KalmanFilter {
 	#[inline]
 	pub fn default() -> Result<crate::video::KalmanFilter> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_KalmanFilter_KalmanFilter(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_KalmanFilter_KalmanFilter(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::video::KalmanFilter::opencv_from_extern(ret) };
@@ -1911,7 +2469,7 @@ impl KalmanFilter {
 	#[inline]
 	pub fn new(dynam_params: i32, measure_params: i32, control_params: i32, typ: i32) -> Result<crate::video::KalmanFilter> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_KalmanFilter_KalmanFilter_int_int_int_int(dynam_params, measure_params, control_params, typ, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_KalmanFilter_KalmanFilter_int_int_int_int(dynam_params, measure_params, control_params, typ, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::video::KalmanFilter::opencv_from_extern(ret) };
@@ -1944,6 +2502,7 @@ pub trait SparseOpticalFlow: core::AlgorithmTrait + crate::video::SparseOpticalF
 	/// * err: cv::noArray()
 	#[inline]
 	fn calc(&mut self, prev_img: &dyn core::ToInputArray, next_img: &dyn core::ToInputArray, prev_pts: &dyn core::ToInputArray, next_pts: &mut dyn core::ToInputOutputArray, status: &mut dyn core::ToOutputArray, err: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("SparseOpticalFlow", self.as_raw_mut_SparseOpticalFlow())?;
 		input_array_arg!(prev_img);
 		input_array_arg!(next_img);
 		input_array_arg!(prev_pts);
@@ -1951,7 +2510,7 @@ pub trait SparseOpticalFlow: core::AlgorithmTrait + crate::video::SparseOpticalF
 		output_array_arg!(status);
 		output_array_arg!(err);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_SparseOpticalFlow_calc_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_SparseOpticalFlow(), prev_img.as_raw__InputArray(), next_img.as_raw__InputArray(), prev_pts.as_raw__InputArray(), next_pts.as_raw__InputOutputArray(), status.as_raw__OutputArray(), err.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_SparseOpticalFlow_calc_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_SparseOpticalFlow(), prev_img.as_raw__InputArray(), next_img.as_raw__InputArray(), prev_pts.as_raw__InputArray(), next_pts.as_raw__InputOutputArray(), status.as_raw__OutputArray(), err.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1959,19 +2518,55 @@ pub trait SparseOpticalFlow: core::AlgorithmTrait + crate::video::SparseOpticalF
 	
 }
 
+/// Builder for `SparseOpticalFlow::calc()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct SparseOpticalFlow_CalcBuilder<'a, T: ?Sized + SparseOpticalFlow> {
+	instance: &'a mut T,
+	prev_img: &'a dyn core::ToInputArray,
+	next_img: &'a dyn core::ToInputArray,
+	prev_pts: &'a dyn core::ToInputArray,
+	next_pts: &'a mut dyn core::ToInputOutputArray,
+	status: &'a mut dyn core::ToOutputArray,
+	err: Option<&'a mut dyn core::ToOutputArray>,
+}
+
+impl<'a, T: ?Sized + SparseOpticalFlow> SparseOpticalFlow_CalcBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, prev_img: &'a dyn core::ToInputArray, next_img: &'a dyn core::ToInputArray, prev_pts: &'a dyn core::ToInputArray, next_pts: &'a mut dyn core::ToInputOutputArray, status: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { instance, prev_img, next_img, prev_pts, next_pts, status, err: None }
+	}
+	
+	/// C++ default: `cv::noArray()`
+	#[inline]
+	pub fn with_err(mut self, err: &'a mut dyn core::ToOutputArray) -> Self {
+		self.err = Some(err);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let mut err_no_array = core::no_array();
+		let err: &mut dyn core::ToOutputArray = match self.err { Some(err) => err, None => &mut err_no_array };
+		SparseOpticalFlow::calc(self.instance, self.prev_img, self.next_img, self.prev_pts, self.next_pts, self.status, err)
+	}
+}
+
 /// Class used for calculating a sparse optical flow.
 /// 
 /// The class can calculate an optical flow for a sparse feature set using the
 /// iterative Lucas-Kanade method with pyramids.
 /// ## See also
-/// calcOpticalFlowPyrLK
+/// [calc_optical_flow_pyr_lk](crate::video::calc_optical_flow_pyr_lk)
 pub trait SparsePyrLKOpticalFlowConst: crate::video::SparseOpticalFlowConst {
 	fn as_raw_SparsePyrLKOpticalFlow(&self) -> *const c_void;
 
 	#[inline]
 	fn get_win_size(&self) -> Result<core::Size> {
+		crate::core::validity::check("SparsePyrLKOpticalFlow", self.as_raw_SparsePyrLKOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_SparsePyrLKOpticalFlow_getWinSize_const(self.as_raw_SparsePyrLKOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_SparsePyrLKOpticalFlow_getWinSize_const(self.as_raw_SparsePyrLKOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1979,8 +2574,9 @@ pub trait SparsePyrLKOpticalFlowConst: crate::video::SparseOpticalFlowConst {
 	
 	#[inline]
 	fn get_max_level(&self) -> Result<i32> {
+		crate::core::validity::check("SparsePyrLKOpticalFlow", self.as_raw_SparsePyrLKOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_SparsePyrLKOpticalFlow_getMaxLevel_const(self.as_raw_SparsePyrLKOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_SparsePyrLKOpticalFlow_getMaxLevel_const(self.as_raw_SparsePyrLKOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1988,8 +2584,9 @@ pub trait SparsePyrLKOpticalFlowConst: crate::video::SparseOpticalFlowConst {
 	
 	#[inline]
 	fn get_term_criteria(&self) -> Result<core::TermCriteria> {
+		crate::core::validity::check("SparsePyrLKOpticalFlow", self.as_raw_SparsePyrLKOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_SparsePyrLKOpticalFlow_getTermCriteria_const(self.as_raw_SparsePyrLKOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_SparsePyrLKOpticalFlow_getTermCriteria_const(self.as_raw_SparsePyrLKOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1997,8 +2594,9 @@ pub trait SparsePyrLKOpticalFlowConst: crate::video::SparseOpticalFlowConst {
 	
 	#[inline]
 	fn get_flags(&self) -> Result<i32> {
+		crate::core::validity::check("SparsePyrLKOpticalFlow", self.as_raw_SparsePyrLKOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_SparsePyrLKOpticalFlow_getFlags_const(self.as_raw_SparsePyrLKOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_SparsePyrLKOpticalFlow_getFlags_const(self.as_raw_SparsePyrLKOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2006,8 +2604,9 @@ pub trait SparsePyrLKOpticalFlowConst: crate::video::SparseOpticalFlowConst {
 	
 	#[inline]
 	fn get_min_eig_threshold(&self) -> Result<f64> {
+		crate::core::validity::check("SparsePyrLKOpticalFlow", self.as_raw_SparsePyrLKOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_SparsePyrLKOpticalFlow_getMinEigThreshold_const(self.as_raw_SparsePyrLKOpticalFlow(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_SparsePyrLKOpticalFlow_getMinEigThreshold_const(self.as_raw_SparsePyrLKOpticalFlow(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2020,8 +2619,9 @@ pub trait SparsePyrLKOpticalFlow: crate::video::SparseOpticalFlow + crate::video
 
 	#[inline]
 	fn set_win_size(&mut self, win_size: core::Size) -> Result<()> {
+		crate::core::validity::check("SparsePyrLKOpticalFlow", self.as_raw_mut_SparsePyrLKOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_SparsePyrLKOpticalFlow_setWinSize_Size(self.as_raw_mut_SparsePyrLKOpticalFlow(), win_size.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_SparsePyrLKOpticalFlow_setWinSize_Size(self.as_raw_mut_SparsePyrLKOpticalFlow(), win_size.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2029,8 +2629,9 @@ pub trait SparsePyrLKOpticalFlow: crate::video::SparseOpticalFlow + crate::video
 	
 	#[inline]
 	fn set_max_level(&mut self, max_level: i32) -> Result<()> {
+		crate::core::validity::check("SparsePyrLKOpticalFlow", self.as_raw_mut_SparsePyrLKOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_SparsePyrLKOpticalFlow_setMaxLevel_int(self.as_raw_mut_SparsePyrLKOpticalFlow(), max_level, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_SparsePyrLKOpticalFlow_setMaxLevel_int(self.as_raw_mut_SparsePyrLKOpticalFlow(), max_level, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2038,8 +2639,9 @@ pub trait SparsePyrLKOpticalFlow: crate::video::SparseOpticalFlow + crate::video
 	
 	#[inline]
 	fn set_term_criteria(&mut self, crit: &mut core::TermCriteria) -> Result<()> {
+		crate::core::validity::check("SparsePyrLKOpticalFlow", self.as_raw_mut_SparsePyrLKOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_SparsePyrLKOpticalFlow_setTermCriteria_TermCriteriaR(self.as_raw_mut_SparsePyrLKOpticalFlow(), crit, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_SparsePyrLKOpticalFlow_setTermCriteria_TermCriteriaR(self.as_raw_mut_SparsePyrLKOpticalFlow(), crit, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2047,8 +2649,9 @@ pub trait SparsePyrLKOpticalFlow: crate::video::SparseOpticalFlow + crate::video
 	
 	#[inline]
 	fn set_flags(&mut self, flags: i32) -> Result<()> {
+		crate::core::validity::check("SparsePyrLKOpticalFlow", self.as_raw_mut_SparsePyrLKOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_SparsePyrLKOpticalFlow_setFlags_int(self.as_raw_mut_SparsePyrLKOpticalFlow(), flags, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_SparsePyrLKOpticalFlow_setFlags_int(self.as_raw_mut_SparsePyrLKOpticalFlow(), flags, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2056,8 +2659,9 @@ pub trait SparsePyrLKOpticalFlow: crate::video::SparseOpticalFlow + crate::video
 	
 	#[inline]
 	fn set_min_eig_threshold(&mut self, min_eig_threshold: f64) -> Result<()> {
+		crate::core::validity::check("SparsePyrLKOpticalFlow", self.as_raw_mut_SparsePyrLKOpticalFlow())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_SparsePyrLKOpticalFlow_setMinEigThreshold_double(self.as_raw_mut_SparsePyrLKOpticalFlow(), min_eig_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_SparsePyrLKOpticalFlow_setMinEigThreshold_double(self.as_raw_mut_SparsePyrLKOpticalFlow(), min_eig_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2075,7 +2679,7 @@ impl dyn SparsePyrLKOpticalFlow + '_ {
 	#[inline]
 	pub fn create(win_size: core::Size, max_level: i32, crit: core::TermCriteria, flags: i32, min_eig_threshold: f64) -> Result<core::Ptr<dyn crate::video::SparsePyrLKOpticalFlow>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_SparsePyrLKOpticalFlow_create_Size_int_TermCriteria_int_double(win_size.opencv_as_extern(), max_level, crit.opencv_as_extern(), flags, min_eig_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_SparsePyrLKOpticalFlow_create_Size_int_TermCriteria_int_double(win_size.opencv_as_extern(), max_level, crit.opencv_as_extern(), flags, min_eig_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::video::SparsePyrLKOpticalFlow>::opencv_from_extern(ret) };
@@ -2083,6 +2687,58 @@ impl dyn SparsePyrLKOpticalFlow + '_ {
 	}
 	
 }
+
+/// Builder for `<dyn SparsePyrLKOpticalFlow>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct SparsePyrLKOpticalFlow_CreateBuilder {
+	win_size: core::Size,
+	max_level: i32,
+	crit: core::TermCriteria,
+	flags: i32,
+	min_eig_threshold: f64,
+}
+
+impl SparsePyrLKOpticalFlow_CreateBuilder {
+	#[inline]
+	pub fn new(crit: core::TermCriteria) -> Self {
+		Self { win_size: core::Size::new(21, 21), max_level: 3, crit, flags: 0, min_eig_threshold: 1e-4 }
+	}
+	
+	/// C++ default: `Size(21,21)`
+	#[inline]
+	pub fn with_win_size(mut self, win_size: core::Size) -> Self {
+		self.win_size = win_size;
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_max_level(mut self, max_level: i32) -> Self {
+		self.max_level = max_level;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_flags(mut self, flags: i32) -> Self {
+		self.flags = flags;
+		self
+	}
+	
+	/// C++ default: `1e-4`
+	#[inline]
+	pub fn with_min_eig_threshold(mut self, min_eig_threshold: f64) -> Self {
+		self.min_eig_threshold = min_eig_threshold;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::video::SparsePyrLKOpticalFlow>> {
+		<dyn SparsePyrLKOpticalFlow>::create(self.win_size, self.max_level, self.crit, self.flags, self.min_eig_threshold)
+	}
+}
 /// Base abstract class for the long-term tracker
 pub trait TrackerConst {
 	fn as_raw_Tracker(&self) -> *const c_void;
@@ -2098,9 +2754,10 @@ pub trait Tracker: crate::video::TrackerConst {
 	/// * boundingBox: The initial bounding box
 	#[inline]
 	fn init(&mut self, image: &dyn core::ToInputArray, bounding_box: core::Rect) -> Result<()> {
+		crate::core::validity::check("Tracker", self.as_raw_mut_Tracker())?;
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Tracker_init_const__InputArrayR_const_RectR(self.as_raw_mut_Tracker(), image.as_raw__InputArray(), &bounding_box, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Tracker_init_const__InputArrayR_const_RectR(self.as_raw_mut_Tracker(), image.as_raw__InputArray(), &bounding_box, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2118,9 +2775,10 @@ pub trait Tracker: crate::video::TrackerConst {
 	/// missing from the frame (say, out of sight)
 	#[inline]
 	fn update(&mut self, image: &dyn core::ToInputArray, bounding_box: &mut core::Rect) -> Result<bool> {
+		crate::core::validity::check("Tracker", self.as_raw_mut_Tracker())?;
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_Tracker_update_const__InputArrayR_RectR(self.as_raw_mut_Tracker(), image.as_raw__InputArray(), bounding_box, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_Tracker_update_const__InputArrayR_RectR(self.as_raw_mut_Tracker(), image.as_raw__InputArray(), bounding_box, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2139,8 +2797,9 @@ pub trait TrackerDaSiamRPN: crate::video::Tracker + crate::video::TrackerDaSiamR
 	/// Return tracking score
 	#[inline]
 	fn get_tracking_score(&mut self) -> Result<f32> {
+		crate::core::validity::check("TrackerDaSiamRPN", self.as_raw_mut_TrackerDaSiamRPN())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_TrackerDaSiamRPN_getTrackingScore(self.as_raw_mut_TrackerDaSiamRPN(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_TrackerDaSiamRPN_getTrackingScore(self.as_raw_mut_TrackerDaSiamRPN(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2158,7 +2817,7 @@ impl dyn TrackerDaSiamRPN + '_ {
 	#[inline]
 	pub fn create(parameters: &crate::video::TrackerDaSiamRPN_Params) -> Result<core::Ptr<dyn crate::video::TrackerDaSiamRPN>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_TrackerDaSiamRPN_create_const_ParamsR(parameters.as_raw_TrackerDaSiamRPN_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_TrackerDaSiamRPN_create_const_ParamsR(parameters.as_raw_TrackerDaSiamRPN_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::video::TrackerDaSiamRPN>::opencv_from_extern(ret) };
@@ -2171,37 +2830,82 @@ pub trait TrackerDaSiamRPN_ParamsTraitConst {
 
 	#[inline]
 	fn model(&self) -> String {
-		let ret = unsafe { sys::cv_TrackerDaSiamRPN_Params_getPropModel_const(self.as_raw_TrackerDaSiamRPN_Params()) };
+		crate::core::validity::debug_check("TrackerDaSiamRPN_Params", self.as_raw_TrackerDaSiamRPN_Params());
+		let ret = unsafe { ffi_call!(cv_TrackerDaSiamRPN_Params_getPropModel_const(self.as_raw_TrackerDaSiamRPN_Params())) };
 		let ret = unsafe { String::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [model](TrackerDaSiamRPN_ParamsTraitConst::model), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_model(&self) -> Result<String> {
+		crate::core::validity::check("TrackerDaSiamRPN_Params", self.as_raw_TrackerDaSiamRPN_Params())?;
+		Ok(TrackerDaSiamRPN_ParamsTraitConst::model(self))
+	}
+	
 	#[inline]
 	fn kernel_cls1(&self) -> String {
-		let ret = unsafe { sys::cv_TrackerDaSiamRPN_Params_getPropKernel_cls1_const(self.as_raw_TrackerDaSiamRPN_Params()) };
+		crate::core::validity::debug_check("TrackerDaSiamRPN_Params", self.as_raw_TrackerDaSiamRPN_Params());
+		let ret = unsafe { ffi_call!(cv_TrackerDaSiamRPN_Params_getPropKernel_cls1_const(self.as_raw_TrackerDaSiamRPN_Params())) };
 		let ret = unsafe { String::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [kernel_cls1](TrackerDaSiamRPN_ParamsTraitConst::kernel_cls1), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_kernel_cls1(&self) -> Result<String> {
+		crate::core::validity::check("TrackerDaSiamRPN_Params", self.as_raw_TrackerDaSiamRPN_Params())?;
+		Ok(TrackerDaSiamRPN_ParamsTraitConst::kernel_cls1(self))
+	}
+	
 	#[inline]
 	fn kernel_r1(&self) -> String {
-		let ret = unsafe { sys::cv_TrackerDaSiamRPN_Params_getPropKernel_r1_const(self.as_raw_TrackerDaSiamRPN_Params()) };
+		crate::core::validity::debug_check("TrackerDaSiamRPN_Params", self.as_raw_TrackerDaSiamRPN_Params());
+		let ret = unsafe { ffi_call!(cv_TrackerDaSiamRPN_Params_getPropKernel_r1_const(self.as_raw_TrackerDaSiamRPN_Params())) };
 		let ret = unsafe { String::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [kernel_r1](TrackerDaSiamRPN_ParamsTraitConst::kernel_r1), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_kernel_r1(&self) -> Result<String> {
+		crate::core::validity::check("TrackerDaSiamRPN_Params", self.as_raw_TrackerDaSiamRPN_Params())?;
+		Ok(TrackerDaSiamRPN_ParamsTraitConst::kernel_r1(self))
+	}
+	
 	#[inline]
 	fn backend(&self) -> i32 {
-		let ret = unsafe { sys::cv_TrackerDaSiamRPN_Params_getPropBackend_const(self.as_raw_TrackerDaSiamRPN_Params()) };
+		crate::core::validity::debug_check("TrackerDaSiamRPN_Params", self.as_raw_TrackerDaSiamRPN_Params());
+		let ret = unsafe { ffi_call!(cv_TrackerDaSiamRPN_Params_getPropBackend_const(self.as_raw_TrackerDaSiamRPN_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [backend](TrackerDaSiamRPN_ParamsTraitConst::backend), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_backend(&self) -> Result<i32> {
+		crate::core::validity::check("TrackerDaSiamRPN_Params", self.as_raw_TrackerDaSiamRPN_Params())?;
+		Ok(TrackerDaSiamRPN_ParamsTraitConst::backend(self))
+	}
+	
 	#[inline]
 	fn target(&self) -> i32 {
-		let ret = unsafe { sys::cv_TrackerDaSiamRPN_Params_getPropTarget_const(self.as_raw_TrackerDaSiamRPN_Params()) };
+		crate::core::validity::debug_check("TrackerDaSiamRPN_Params", self.as_raw_TrackerDaSiamRPN_Params());
+		let ret = unsafe { ffi_call!(cv_TrackerDaSiamRPN_Params_getPropTarget_const(self.as_raw_TrackerDaSiamRPN_Params())) };
 		ret
 	}
 	
+	/// Non-panicking version of [target](TrackerDaSiamRPN_ParamsTraitConst::target), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_target(&self) -> Result<i32> {
+		crate::core::validity::check("TrackerDaSiamRPN_Params", self.as_raw_TrackerDaSiamRPN_Params())?;
+		Ok(TrackerDaSiamRPN_ParamsTraitConst::target(self))
+	}
+	
 }
 
 pub trait TrackerDaSiamRPN_ParamsTrait: crate::video::TrackerDaSiamRPN_ParamsTraitConst {
@@ -2209,34 +2913,39 @@ pub trait TrackerDaSiamRPN_ParamsTrait: crate::video::TrackerDaSiamRPN_ParamsTra
 
 	#[inline]
 	fn set_model(&mut self, val: &str) {
+		crate::core::validity::debug_check("TrackerDaSiamRPN_Params", self.as_raw_mut_TrackerDaSiamRPN_Params());
 		extern_container_arg!(nofail mut val);
-		let ret = unsafe { sys::cv_TrackerDaSiamRPN_Params_setPropModel_string(self.as_raw_mut_TrackerDaSiamRPN_Params(), val.opencv_as_extern_mut()) };
+		let ret = unsafe { ffi_call!(cv_TrackerDaSiamRPN_Params_setPropModel_string(self.as_raw_mut_TrackerDaSiamRPN_Params(), val.opencv_as_extern_mut())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_kernel_cls1(&mut self, val: &str) {
+		crate::core::validity::debug_check("TrackerDaSiamRPN_Params", self.as_raw_mut_TrackerDaSiamRPN_Params());
 		extern_container_arg!(nofail mut val);
-		let ret = unsafe { sys::cv_TrackerDaSiamRPN_Params_setPropKernel_cls1_string(self.as_raw_mut_TrackerDaSiamRPN_Params(), val.opencv_as_extern_mut()) };
+		let ret = unsafe { ffi_call!(cv_TrackerDaSiamRPN_Params_setPropKernel_cls1_string(self.as_raw_mut_TrackerDaSiamRPN_Params(), val.opencv_as_extern_mut())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_kernel_r1(&mut self, val: &str) {
+		crate::core::validity::debug_check("TrackerDaSiamRPN_Params", self.as_raw_mut_TrackerDaSiamRPN_Params());
 		extern_container_arg!(nofail mut val);
-		let ret = unsafe { sys::cv_TrackerDaSiamRPN_Params_setPropKernel_r1_string(self.as_raw_mut_TrackerDaSiamRPN_Params(), val.opencv_as_extern_mut()) };
+		let ret = unsafe { ffi_call!(cv_TrackerDaSiamRPN_Params_setPropKernel_r1_string(self.as_raw_mut_TrackerDaSiamRPN_Params(), val.opencv_as_extern_mut())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_backend(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_TrackerDaSiamRPN_Params_setPropBackend_int(self.as_raw_mut_TrackerDaSiamRPN_Params(), val) };
+		crate::core::validity::debug_check("TrackerDaSiamRPN_Params", self.as_raw_mut_TrackerDaSiamRPN_Params());
+		let ret = unsafe { ffi_call!(cv_TrackerDaSiamRPN_Params_setPropBackend_int(self.as_raw_mut_TrackerDaSiamRPN_Params(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_target(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_TrackerDaSiamRPN_Params_setPropTarget_int(self.as_raw_mut_TrackerDaSiamRPN_Params(), val) };
+		crate::core::validity::debug_check("TrackerDaSiamRPN_Params", self.as_raw_mut_TrackerDaSiamRPN_Params());
+		let ret = unsafe { ffi_call!(cv_TrackerDaSiamRPN_Params_setPropTarget_int(self.as_raw_mut_TrackerDaSiamRPN_Params(), val)) };
 		ret
 	}
 	
@@ -2251,7 +2960,10 @@ opencv_type_boxed! { TrackerDaSiamRPN_Params }
 impl Drop for TrackerDaSiamRPN_Params {
 	fn drop(&mut self) {
 		extern "C" { fn cv_TrackerDaSiamRPN_Params_delete(instance: *mut c_void); }
-		unsafe { cv_TrackerDaSiamRPN_Params_delete(self.as_raw_mut_TrackerDaSiamRPN_Params()) };
+		let ptr = self.as_raw_mut_TrackerDaSiamRPN_Params();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_TrackerDaSiamRPN_Params_delete(ptr) };
 	}
 }
 
@@ -2269,7 +2981,7 @@ impl TrackerDaSiamRPN_Params {
 	#[inline]
 	pub fn default() -> Result<crate::video::TrackerDaSiamRPN_Params> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_TrackerDaSiamRPN_Params_Params(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_TrackerDaSiamRPN_Params_Params(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::video::TrackerDaSiamRPN_Params::opencv_from_extern(ret) };
@@ -2312,7 +3024,7 @@ impl dyn TrackerGOTURN + '_ {
 	#[inline]
 	pub fn create(parameters: &crate::video::TrackerGOTURN_Params) -> Result<core::Ptr<dyn crate::video::TrackerGOTURN>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_TrackerGOTURN_create_const_ParamsR(parameters.as_raw_TrackerGOTURN_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_TrackerGOTURN_create_const_ParamsR(parameters.as_raw_TrackerGOTURN_Params(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::video::TrackerGOTURN>::opencv_from_extern(ret) };
@@ -2325,18 +3037,36 @@ pub trait TrackerGOTURN_ParamsTraitConst {
 
 	#[inline]
 	fn model_txt(&self) -> String {
-		let ret = unsafe { sys::cv_TrackerGOTURN_Params_getPropModelTxt_const(self.as_raw_TrackerGOTURN_Params()) };
+		crate::core::validity::debug_check("TrackerGOTURN_Params", self.as_raw_TrackerGOTURN_Params());
+		let ret = unsafe { ffi_call!(cv_TrackerGOTURN_Params_getPropModelTxt_const(self.as_raw_TrackerGOTURN_Params())) };
 		let ret = unsafe { String::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [model_txt](TrackerGOTURN_ParamsTraitConst::model_txt), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_model_txt(&self) -> Result<String> {
+		crate::core::validity::check("TrackerGOTURN_Params", self.as_raw_TrackerGOTURN_Params())?;
+		Ok(TrackerGOTURN_ParamsTraitConst::model_txt(self))
+	}
+	
 	#[inline]
 	fn model_bin(&self) -> String {
-		let ret = unsafe { sys::cv_TrackerGOTURN_Params_getPropModelBin_const(self.as_raw_TrackerGOTURN_Params()) };
+		crate::core::validity::debug_check("TrackerGOTURN_Params", self.as_raw_TrackerGOTURN_Params());
+		let ret = unsafe { ffi_call!(cv_TrackerGOTURN_Params_getPropModelBin_const(self.as_raw_TrackerGOTURN_Params())) };
 		let ret = unsafe { String::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [model_bin](TrackerGOTURN_ParamsTraitConst::model_bin), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_model_bin(&self) -> Result<String> {
+		crate::core::validity::check("TrackerGOTURN_Params", self.as_raw_TrackerGOTURN_Params())?;
+		Ok(TrackerGOTURN_ParamsTraitConst::model_bin(self))
+	}
+	
 }
 
 pub trait TrackerGOTURN_ParamsTrait: crate::video::TrackerGOTURN_ParamsTraitConst {
@@ -2344,15 +3074,17 @@ pub trait TrackerGOTURN_ParamsTrait: crate::video::TrackerGOTURN_ParamsTraitCons
 
 	#[inline]
 	fn set_model_txt(&mut self, val: &str) {
+		crate::core::validity::debug_check("TrackerGOTURN_Params", self.as_raw_mut_TrackerGOTURN_Params());
 		extern_container_arg!(nofail mut val);
-		let ret = unsafe { sys::cv_TrackerGOTURN_Params_setPropModelTxt_string(self.as_raw_mut_TrackerGOTURN_Params(), val.opencv_as_extern_mut()) };
+		let ret = unsafe { ffi_call!(cv_TrackerGOTURN_Params_setPropModelTxt_string(self.as_raw_mut_TrackerGOTURN_Params(), val.opencv_as_extern_mut())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_model_bin(&mut self, val: &str) {
+		crate::core::validity::debug_check("TrackerGOTURN_Params", self.as_raw_mut_TrackerGOTURN_Params());
 		extern_container_arg!(nofail mut val);
-		let ret = unsafe { sys::cv_TrackerGOTURN_Params_setPropModelBin_string(self.as_raw_mut_TrackerGOTURN_Params(), val.opencv_as_extern_mut()) };
+		let ret = unsafe { ffi_call!(cv_TrackerGOTURN_Params_setPropModelBin_string(self.as_raw_mut_TrackerGOTURN_Params(), val.opencv_as_extern_mut())) };
 		ret
 	}
 	
@@ -2367,7 +3099,10 @@ opencv_type_boxed! { TrackerGOTURN_Params }
 impl Drop for TrackerGOTURN_Params {
 	fn drop(&mut self) {
 		extern "C" { fn cv_TrackerGOTURN_Params_delete(instance: *mut c_void); }
-		unsafe { cv_TrackerGOTURN_Params_delete(self.as_raw_mut_TrackerGOTURN_Params()) };
+		let ptr = self.as_raw_mut_TrackerGOTURN_Params();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_TrackerGOTURN_Params_delete(ptr) };
 	}
 }
 
@@ -2385,7 +3120,7 @@ impl TrackerGOTURN_Params {
 	#[inline]
 	pub fn default() -> Result<crate::video::TrackerGOTURN_Params> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_TrackerGOTURN_Params_Params(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_TrackerGOTURN_Params_Params(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::video::TrackerGOTURN_Params::opencv_from_extern(ret) };
@@ -2421,7 +3156,7 @@ impl dyn TrackerMIL + '_ {
 	#[inline]
 	pub fn create(parameters: crate::video::TrackerMIL_Params) -> Result<core::Ptr<dyn crate::video::TrackerMIL>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_TrackerMIL_create_const_ParamsR(&parameters, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_TrackerMIL_create_const_ParamsR(&parameters, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::video::TrackerMIL>::opencv_from_extern(ret) };
@@ -2454,7 +3189,7 @@ impl TrackerMIL_Params {
 	#[inline]
 	pub fn default() -> Result<crate::video::TrackerMIL_Params> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_TrackerMIL_Params_Params(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_TrackerMIL_Params_Params(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2476,11 +3211,12 @@ pub trait VariationalRefinementConst: crate::video::DenseOpticalFlowConst {
 
 	/// Number of outer (fixed-point) iterations in the minimization procedure.
 	/// ## See also
-	/// setFixedPointIterations
+	/// `set_fixed_point_iterations`
 	#[inline]
 	fn get_fixed_point_iterations(&self) -> Result<i32> {
+		crate::core::validity::check("VariationalRefinement", self.as_raw_VariationalRefinement())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VariationalRefinement_getFixedPointIterations_const(self.as_raw_VariationalRefinement(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VariationalRefinement_getFixedPointIterations_const(self.as_raw_VariationalRefinement(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2489,11 +3225,12 @@ pub trait VariationalRefinementConst: crate::video::DenseOpticalFlowConst {
 	/// Number of inner successive over-relaxation (SOR) iterations
 	///    in the minimization procedure to solve the respective linear system.
 	/// ## See also
-	/// setSorIterations
+	/// `set_sor_iterations`
 	#[inline]
 	fn get_sor_iterations(&self) -> Result<i32> {
+		crate::core::validity::check("VariationalRefinement", self.as_raw_VariationalRefinement())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VariationalRefinement_getSorIterations_const(self.as_raw_VariationalRefinement(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VariationalRefinement_getSorIterations_const(self.as_raw_VariationalRefinement(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2501,11 +3238,12 @@ pub trait VariationalRefinementConst: crate::video::DenseOpticalFlowConst {
 	
 	/// Relaxation factor in SOR
 	/// ## See also
-	/// setOmega
+	/// `set_omega`
 	#[inline]
 	fn get_omega(&self) -> Result<f32> {
+		crate::core::validity::check("VariationalRefinement", self.as_raw_VariationalRefinement())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VariationalRefinement_getOmega_const(self.as_raw_VariationalRefinement(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VariationalRefinement_getOmega_const(self.as_raw_VariationalRefinement(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2513,11 +3251,12 @@ pub trait VariationalRefinementConst: crate::video::DenseOpticalFlowConst {
 	
 	/// Weight of the smoothness term
 	/// ## See also
-	/// setAlpha
+	/// `set_alpha`
 	#[inline]
 	fn get_alpha(&self) -> Result<f32> {
+		crate::core::validity::check("VariationalRefinement", self.as_raw_VariationalRefinement())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VariationalRefinement_getAlpha_const(self.as_raw_VariationalRefinement(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VariationalRefinement_getAlpha_const(self.as_raw_VariationalRefinement(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2525,11 +3264,12 @@ pub trait VariationalRefinementConst: crate::video::DenseOpticalFlowConst {
 	
 	/// Weight of the color constancy term
 	/// ## See also
-	/// setDelta
+	/// `set_delta`
 	#[inline]
 	fn get_delta(&self) -> Result<f32> {
+		crate::core::validity::check("VariationalRefinement", self.as_raw_VariationalRefinement())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VariationalRefinement_getDelta_const(self.as_raw_VariationalRefinement(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VariationalRefinement_getDelta_const(self.as_raw_VariationalRefinement(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2537,11 +3277,12 @@ pub trait VariationalRefinementConst: crate::video::DenseOpticalFlowConst {
 	
 	/// Weight of the gradient constancy term
 	/// ## See also
-	/// setGamma
+	/// `set_gamma`
 	#[inline]
 	fn get_gamma(&self) -> Result<f32> {
+		crate::core::validity::check("VariationalRefinement", self.as_raw_VariationalRefinement())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VariationalRefinement_getGamma_const(self.as_raw_VariationalRefinement(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VariationalRefinement_getGamma_const(self.as_raw_VariationalRefinement(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2552,16 +3293,17 @@ pub trait VariationalRefinementConst: crate::video::DenseOpticalFlowConst {
 pub trait VariationalRefinement: crate::video::DenseOpticalFlow + crate::video::VariationalRefinementConst {
 	fn as_raw_mut_VariationalRefinement(&mut self) -> *mut c_void;
 
-	/// @ref calc function overload to handle separate horizontal (u) and vertical (v) flow components
+	/// `calc` function overload to handle separate horizontal (u) and vertical (v) flow components
 	/// (to avoid extra splits/merges)
 	#[inline]
 	fn calc_uv(&mut self, i0: &dyn core::ToInputArray, i1: &dyn core::ToInputArray, flow_u: &mut dyn core::ToInputOutputArray, flow_v: &mut dyn core::ToInputOutputArray) -> Result<()> {
+		crate::core::validity::check("VariationalRefinement", self.as_raw_mut_VariationalRefinement())?;
 		input_array_arg!(i0);
 		input_array_arg!(i1);
 		input_output_array_arg!(flow_u);
 		input_output_array_arg!(flow_v);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VariationalRefinement_calcUV_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR(self.as_raw_mut_VariationalRefinement(), i0.as_raw__InputArray(), i1.as_raw__InputArray(), flow_u.as_raw__InputOutputArray(), flow_v.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VariationalRefinement_calcUV_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR(self.as_raw_mut_VariationalRefinement(), i0.as_raw__InputArray(), i1.as_raw__InputArray(), flow_u.as_raw__InputOutputArray(), flow_v.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2572,8 +3314,9 @@ pub trait VariationalRefinement: crate::video::DenseOpticalFlow + crate::video::
 	/// setFixedPointIterations getFixedPointIterations
 	#[inline]
 	fn set_fixed_point_iterations(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("VariationalRefinement", self.as_raw_mut_VariationalRefinement())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VariationalRefinement_setFixedPointIterations_int(self.as_raw_mut_VariationalRefinement(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VariationalRefinement_setFixedPointIterations_int(self.as_raw_mut_VariationalRefinement(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2585,8 +3328,9 @@ pub trait VariationalRefinement: crate::video::DenseOpticalFlow + crate::video::
 	/// setSorIterations getSorIterations
 	#[inline]
 	fn set_sor_iterations(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("VariationalRefinement", self.as_raw_mut_VariationalRefinement())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VariationalRefinement_setSorIterations_int(self.as_raw_mut_VariationalRefinement(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VariationalRefinement_setSorIterations_int(self.as_raw_mut_VariationalRefinement(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2597,8 +3341,9 @@ pub trait VariationalRefinement: crate::video::DenseOpticalFlow + crate::video::
 	/// setOmega getOmega
 	#[inline]
 	fn set_omega(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("VariationalRefinement", self.as_raw_mut_VariationalRefinement())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VariationalRefinement_setOmega_float(self.as_raw_mut_VariationalRefinement(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VariationalRefinement_setOmega_float(self.as_raw_mut_VariationalRefinement(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2609,8 +3354,9 @@ pub trait VariationalRefinement: crate::video::DenseOpticalFlow + crate::video::
 	/// setAlpha getAlpha
 	#[inline]
 	fn set_alpha(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("VariationalRefinement", self.as_raw_mut_VariationalRefinement())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VariationalRefinement_setAlpha_float(self.as_raw_mut_VariationalRefinement(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VariationalRefinement_setAlpha_float(self.as_raw_mut_VariationalRefinement(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2621,8 +3367,9 @@ pub trait VariationalRefinement: crate::video::DenseOpticalFlow + crate::video::
 	/// setDelta getDelta
 	#[inline]
 	fn set_delta(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("VariationalRefinement", self.as_raw_mut_VariationalRefinement())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VariationalRefinement_setDelta_float(self.as_raw_mut_VariationalRefinement(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VariationalRefinement_setDelta_float(self.as_raw_mut_VariationalRefinement(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2633,8 +3380,9 @@ pub trait VariationalRefinement: crate::video::DenseOpticalFlow + crate::video::
 	/// setGamma getGamma
 	#[inline]
 	fn set_gamma(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("VariationalRefinement", self.as_raw_mut_VariationalRefinement())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VariationalRefinement_setGamma_float(self.as_raw_mut_VariationalRefinement(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VariationalRefinement_setGamma_float(self.as_raw_mut_VariationalRefinement(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2647,7 +3395,7 @@ impl dyn VariationalRefinement + '_ {
 	#[inline]
 	pub fn create() -> Result<core::Ptr<dyn crate::video::VariationalRefinement>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VariationalRefinement_create(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VariationalRefinement_create(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::video::VariationalRefinement>::opencv_from_extern(ret) };
@@ -12,8 +12,8 @@
 //! Read and write video or images sequence with OpenCV
 //! 
 //! ### See also:
-//! - @ref videoio_overview
-//! - Tutorials: @ref tutorial_table_of_content_app
+//! - videoio_overview
+//! - Tutorials: tutorial_table_of_content_app
 //!   # Flags for video I/O
 //!   # Additional flags for video I/O API backends
 //!   # Hardware-accelerated video decoding and encoding
@@ -370,37 +370,37 @@ pub const CAP_PROP_XI_BPC: i32 = 445;
 pub const CAP_PROP_XI_BUFFERS_QUEUE_SIZE: i32 = 551;
 /// Data move policy.
 pub const CAP_PROP_XI_BUFFER_POLICY: i32 = 540;
-/// Color Correction Matrix element [0][0].
+/// Color Correction Matrix element \[0\]\[0\].
 pub const CAP_PROP_XI_CC_MATRIX_00: i32 = 479;
-/// Color Correction Matrix element [0][1].
+/// Color Correction Matrix element \[0\]\[1\].
 pub const CAP_PROP_XI_CC_MATRIX_01: i32 = 480;
-/// Color Correction Matrix element [0][2].
+/// Color Correction Matrix element \[0\]\[2\].
 pub const CAP_PROP_XI_CC_MATRIX_02: i32 = 481;
-/// Color Correction Matrix element [0][3].
+/// Color Correction Matrix element \[0\]\[3\].
 pub const CAP_PROP_XI_CC_MATRIX_03: i32 = 482;
-/// Color Correction Matrix element [1][0].
+/// Color Correction Matrix element \[1\]\[0\].
 pub const CAP_PROP_XI_CC_MATRIX_10: i32 = 483;
-/// Color Correction Matrix element [1][1].
+/// Color Correction Matrix element \[1\]\[1\].
 pub const CAP_PROP_XI_CC_MATRIX_11: i32 = 484;
-/// Color Correction Matrix element [1][2].
+/// Color Correction Matrix element \[1\]\[2\].
 pub const CAP_PROP_XI_CC_MATRIX_12: i32 = 485;
-/// Color Correction Matrix element [1][3].
+/// Color Correction Matrix element \[1\]\[3\].
 pub const CAP_PROP_XI_CC_MATRIX_13: i32 = 486;
-/// Color Correction Matrix element [2][0].
+/// Color Correction Matrix element \[2\]\[0\].
 pub const CAP_PROP_XI_CC_MATRIX_20: i32 = 487;
-/// Color Correction Matrix element [2][1].
+/// Color Correction Matrix element \[2\]\[1\].
 pub const CAP_PROP_XI_CC_MATRIX_21: i32 = 488;
-/// Color Correction Matrix element [2][2].
+/// Color Correction Matrix element \[2\]\[2\].
 pub const CAP_PROP_XI_CC_MATRIX_22: i32 = 489;
-/// Color Correction Matrix element [2][3].
+/// Color Correction Matrix element \[2\]\[3\].
 pub const CAP_PROP_XI_CC_MATRIX_23: i32 = 490;
-/// Color Correction Matrix element [3][0].
+/// Color Correction Matrix element \[3\]\[0\].
 pub const CAP_PROP_XI_CC_MATRIX_30: i32 = 491;
-/// Color Correction Matrix element [3][1].
+/// Color Correction Matrix element \[3\]\[1\].
 pub const CAP_PROP_XI_CC_MATRIX_31: i32 = 492;
-/// Color Correction Matrix element [3][2].
+/// Color Correction Matrix element \[3\]\[2\].
 pub const CAP_PROP_XI_CC_MATRIX_32: i32 = 493;
-/// Color Correction Matrix element [3][3].
+/// Color Correction Matrix element \[3\]\[3\].
 pub const CAP_PROP_XI_CC_MATRIX_33: i32 = 494;
 /// Camera sensor temperature.
 pub const CAP_PROP_XI_CHIP_TEMP: i32 = 468;
@@ -746,7 +746,7 @@ opencv_type_enum! { crate::videoio::VideoAccelerationType }
 /// 
 /// 
 /// Note: Backends are available only if they have been built with your OpenCV binaries.
-/// See @ref videoio_overview for more information.
+/// See videoio_overview for more information.
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum VideoCaptureAPIs {
@@ -829,7 +829,7 @@ opencv_type_enum! { crate::videoio::VideoCaptureAPIs }
 /// Reading / writing properties involves many layers. Some unexpected result might happens along this chain.
 /// Effective behaviour depends from device hardware, driver and API Backend.
 /// ## See also
-/// videoio_flags_others, VideoCapture::get(), VideoCapture::set()
+/// videoio_flags_others, `VideoCapture::get`, `VideoCapture::set`
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum VideoCaptureProperties {
@@ -959,7 +959,7 @@ opencv_type_enum! { crate::videoio::VideoCaptureProperties }
 
 /// cv::VideoWriter generic properties identifier.
 /// ## See also
-/// VideoWriter::get(), VideoWriter::set()
+/// `VideoWriter::get`, `VideoWriter::set`
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum VideoWriterProperties {
@@ -991,7 +991,7 @@ opencv_type_enum! { crate::videoio::VideoWriterProperties }
 #[inline]
 pub fn get_backend_name(api: crate::videoio::VideoCaptureAPIs) -> Result<String> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_videoio_registry_getBackendName_VideoCaptureAPIs(api, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_videoio_registry_getBackendName_VideoCaptureAPIs(api, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { String::opencv_from_extern(ret) };
@@ -1002,7 +1002,7 @@ pub fn get_backend_name(api: crate::videoio::VideoCaptureAPIs) -> Result<String>
 #[inline]
 pub fn get_backends() -> Result<core::Vector<crate::videoio::VideoCaptureAPIs>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_videoio_registry_getBackends(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_videoio_registry_getBackends(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Vector::<crate::videoio::VideoCaptureAPIs>::opencv_from_extern(ret) };
@@ -1013,7 +1013,7 @@ pub fn get_backends() -> Result<core::Vector<crate::videoio::VideoCaptureAPIs>>
 #[inline]
 pub fn get_camera_backend_plugin_version(api: crate::videoio::VideoCaptureAPIs, version_abi: &mut i32, version_api: &mut i32) -> Result<String> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_videoio_registry_getCameraBackendPluginVersion_VideoCaptureAPIs_intR_intR(api, version_abi, version_api, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_videoio_registry_getCameraBackendPluginVersion_VideoCaptureAPIs_intR_intR(api, version_abi, version_api, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { String::opencv_from_extern(ret) };
@@ -1024,7 +1024,7 @@ pub fn get_camera_backend_plugin_version(api: crate::videoio::VideoCaptureAPIs,
 #[inline]
 pub fn get_camera_backends() -> Result<core::Vector<crate::videoio::VideoCaptureAPIs>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_videoio_registry_getCameraBackends(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_videoio_registry_getCameraBackends(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Vector::<crate::videoio::VideoCaptureAPIs>::opencv_from_extern(ret) };
@@ -1035,7 +1035,7 @@ pub fn get_camera_backends() -> Result<core::Vector<crate::videoio::VideoCapture
 #[inline]
 pub fn get_stream_backend_plugin_version(api: crate::videoio::VideoCaptureAPIs, version_abi: &mut i32, version_api: &mut i32) -> Result<String> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_videoio_registry_getStreamBackendPluginVersion_VideoCaptureAPIs_intR_intR(api, version_abi, version_api, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_videoio_registry_getStreamBackendPluginVersion_VideoCaptureAPIs_intR_intR(api, version_abi, version_api, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { String::opencv_from_extern(ret) };
@@ -1046,7 +1046,7 @@ pub fn get_stream_backend_plugin_version(api: crate::videoio::VideoCaptureAPIs,
 #[inline]
 pub fn get_stream_backends() -> Result<core::Vector<crate::videoio::VideoCaptureAPIs>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_videoio_registry_getStreamBackends(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_videoio_registry_getStreamBackends(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Vector::<crate::videoio::VideoCaptureAPIs>::opencv_from_extern(ret) };
@@ -1057,7 +1057,7 @@ pub fn get_stream_backends() -> Result<core::Vector<crate::videoio::VideoCapture
 #[inline]
 pub fn get_writer_backend_plugin_version(api: crate::videoio::VideoCaptureAPIs, version_abi: &mut i32, version_api: &mut i32) -> Result<String> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_videoio_registry_getWriterBackendPluginVersion_VideoCaptureAPIs_intR_intR(api, version_abi, version_api, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_videoio_registry_getWriterBackendPluginVersion_VideoCaptureAPIs_intR_intR(api, version_abi, version_api, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { String::opencv_from_extern(ret) };
@@ -1068,7 +1068,7 @@ pub fn get_writer_backend_plugin_version(api: crate::videoio::VideoCaptureAPIs,
 #[inline]
 pub fn get_writer_backends() -> Result<core::Vector<crate::videoio::VideoCaptureAPIs>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_videoio_registry_getWriterBackends(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_videoio_registry_getWriterBackends(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Vector::<crate::videoio::VideoCaptureAPIs>::opencv_from_extern(ret) };
@@ -1079,7 +1079,7 @@ pub fn get_writer_backends() -> Result<core::Vector<crate::videoio::VideoCapture
 #[inline]
 pub fn has_backend(api: crate::videoio::VideoCaptureAPIs) -> Result<bool> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_videoio_registry_hasBackend_VideoCaptureAPIs(api, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_videoio_registry_hasBackend_VideoCaptureAPIs(api, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1089,7 +1089,7 @@ pub fn has_backend(api: crate::videoio::VideoCaptureAPIs) -> Result<bool> {
 #[inline]
 pub fn is_backend_built_in(api: crate::videoio::VideoCaptureAPIs) -> Result<bool> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_videoio_registry_isBackendBuiltIn_VideoCaptureAPIs(api, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_videoio_registry_isBackendBuiltIn_VideoCaptureAPIs(api, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1103,7 +1103,7 @@ pub fn is_backend_built_in(api: crate::videoio::VideoCaptureAPIs) -> Result<bool
 /// @include samples/cpp/videocapture_basic.cpp
 /// 
 /// 
-/// Note: In @ref videoio_c "C API" the black-box structure `CvCapture` is used instead of %VideoCapture.
+/// Note: In C API the black-box structure `CvCapture` is used instead of %VideoCapture.
 /// 
 /// Note:
 /// *   (C++) A basic sample on using the %VideoCapture interface can be found at
@@ -1123,8 +1123,9 @@ pub trait VideoCaptureTraitConst {
 	/// true.
 	#[inline]
 	fn is_opened(&self) -> Result<bool> {
+		crate::core::validity::check("VideoCapture", self.as_raw_VideoCapture())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoCapture_isOpened_const(self.as_raw_VideoCapture(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoCapture_isOpened_const(self.as_raw_VideoCapture(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1134,7 +1135,7 @@ pub trait VideoCaptureTraitConst {
 	/// 
 	/// ## Parameters
 	/// * propId: Property identifier from cv::VideoCaptureProperties (eg. cv::CAP_PROP_POS_MSEC, cv::CAP_PROP_POS_FRAMES, ...)
-	/// or one from @ref videoio_flags_others
+	/// or one from videoio_flags_others
 	/// ## Returns
 	/// Value for the specified property. Value 0 is returned when querying a property that is
 	/// not supported by the backend used by the VideoCapture instance.
@@ -1151,8 +1152,9 @@ pub trait VideoCaptureTraitConst {
 	/// driver and API Backend
 	#[inline]
 	fn get(&self, prop_id: i32) -> Result<f64> {
+		crate::core::validity::check("VideoCapture", self.as_raw_VideoCapture())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoCapture_get_const_int(self.as_raw_VideoCapture(), prop_id, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoCapture_get_const_int(self.as_raw_VideoCapture(), prop_id, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1164,8 +1166,9 @@ pub trait VideoCaptureTraitConst {
 	/// Note: Stream should be opened.
 	#[inline]
 	fn get_backend_name(&self) -> Result<String> {
+		crate::core::validity::check("VideoCapture", self.as_raw_VideoCapture())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoCapture_getBackendName_const(self.as_raw_VideoCapture(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoCapture_getBackendName_const(self.as_raw_VideoCapture(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -1191,9 +1194,10 @@ pub trait VideoCaptureTrait: crate::videoio::VideoCaptureTraitConst {
 	/// * api_preference: CAP_ANY
 	#[inline]
 	fn open_file(&mut self, filename: &str, api_preference: i32) -> Result<bool> {
+		crate::core::validity::check("VideoCapture", self.as_raw_mut_VideoCapture())?;
 		extern_container_arg!(filename);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoCapture_open_const_StringR_int(self.as_raw_mut_VideoCapture(), filename.opencv_as_extern(), api_preference, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoCapture_open_const_StringR_int(self.as_raw_mut_VideoCapture(), filename.opencv_as_extern(), api_preference, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1212,9 +1216,10 @@ pub trait VideoCaptureTrait: crate::videoio::VideoCaptureTraitConst {
 	/// The method first calls VideoCapture::release to close the already opened file or camera.
 	#[inline]
 	fn open(&mut self, filename: &str, api_preference: i32, params: &core::Vector<i32>) -> Result<bool> {
+		crate::core::validity::check("VideoCapture", self.as_raw_mut_VideoCapture())?;
 		extern_container_arg!(filename);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoCapture_open_const_StringR_int_const_vector_int_R(self.as_raw_mut_VideoCapture(), filename.opencv_as_extern(), api_preference, params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoCapture_open_const_StringR_int_const_vector_int_R(self.as_raw_mut_VideoCapture(), filename.opencv_as_extern(), api_preference, params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1234,8 +1239,9 @@ pub trait VideoCaptureTrait: crate::videoio::VideoCaptureTraitConst {
 	/// * api_preference: CAP_ANY
 	#[inline]
 	fn open_1(&mut self, index: i32, api_preference: i32) -> Result<bool> {
+		crate::core::validity::check("VideoCapture", self.as_raw_mut_VideoCapture())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoCapture_open_int_int(self.as_raw_mut_VideoCapture(), index, api_preference, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoCapture_open_int_int(self.as_raw_mut_VideoCapture(), index, api_preference, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1254,8 +1260,9 @@ pub trait VideoCaptureTrait: crate::videoio::VideoCaptureTraitConst {
 	/// The method first calls VideoCapture::release to close the already opened file or camera.
 	#[inline]
 	fn open_2(&mut self, index: i32, api_preference: i32, params: &core::Vector<i32>) -> Result<bool> {
+		crate::core::validity::check("VideoCapture", self.as_raw_mut_VideoCapture())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoCapture_open_int_int_const_vector_int_R(self.as_raw_mut_VideoCapture(), index, api_preference, params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoCapture_open_int_int_const_vector_int_R(self.as_raw_mut_VideoCapture(), index, api_preference, params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1269,8 +1276,9 @@ pub trait VideoCaptureTrait: crate::videoio::VideoCaptureTraitConst {
 	/// The C function also deallocates memory and clears \*capture pointer.
 	#[inline]
 	fn release(&mut self) -> Result<()> {
+		crate::core::validity::check("VideoCapture", self.as_raw_mut_VideoCapture())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoCapture_release(self.as_raw_mut_VideoCapture(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoCapture_release(self.as_raw_mut_VideoCapture(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1294,11 +1302,12 @@ pub trait VideoCaptureTrait: crate::videoio::VideoCaptureTraitConst {
 	/// correct way of retrieving data from it is to call VideoCapture::grab() first and then call
 	/// VideoCapture::retrieve() one or more times with different values of the channel parameter.
 	/// 
-	/// @ref tutorial_kinect_openni
+	/// tutorial_kinect_openni
 	#[inline]
 	fn grab(&mut self) -> Result<bool> {
+		crate::core::validity::check("VideoCapture", self.as_raw_mut_VideoCapture())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoCapture_grab(self.as_raw_mut_VideoCapture(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoCapture_grab(self.as_raw_mut_VideoCapture(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1307,7 +1316,7 @@ pub trait VideoCaptureTrait: crate::videoio::VideoCaptureTraitConst {
 	/// Decodes and returns the grabbed video frame.
 	/// 
 	/// ## Parameters
-	/// * image:[out] the video frame is returned here. If no frames has been grabbed the image will be empty.
+	/// * image:\[out\] the video frame is returned here. If no frames has been grabbed the image will be empty.
 	/// * flag: it could be a frame index or a driver specific flag
 	/// ## Returns
 	/// `false` if no frames has been grabbed
@@ -1316,10 +1325,10 @@ pub trait VideoCaptureTrait: crate::videoio::VideoCaptureTraitConst {
 	/// (camera has been disconnected, or there are no more frames in video file), the method returns false
 	/// and the function returns an empty image (with %cv::Mat, test it with Mat::empty()).
 	/// ## See also
-	/// read()
+	/// `read`
 	/// 
 	/// 
-	/// Note: In @ref videoio_c "C API", functions cvRetrieveFrame() and cv.RetrieveFrame() return image stored inside the video
+	/// Note: In C API, functions cvRetrieveFrame() and cv.RetrieveFrame() return image stored inside the video
 	/// capturing structure. It is not allowed to modify or release the image! You can copy the frame using
 	/// cvCloneImage and then do whatever you want with the copy.
 	/// 
@@ -1327,9 +1336,10 @@ pub trait VideoCaptureTrait: crate::videoio::VideoCaptureTraitConst {
 	/// * flag: 0
 	#[inline]
 	fn retrieve(&mut self, image: &mut dyn core::ToOutputArray, flag: i32) -> Result<bool> {
+		crate::core::validity::check("VideoCapture", self.as_raw_mut_VideoCapture())?;
 		output_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoCapture_retrieve_const__OutputArrayR_int(self.as_raw_mut_VideoCapture(), image.as_raw__OutputArray(), flag, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoCapture_retrieve_const__OutputArrayR_int(self.as_raw_mut_VideoCapture(), image.as_raw__OutputArray(), flag, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1338,7 +1348,7 @@ pub trait VideoCaptureTrait: crate::videoio::VideoCaptureTraitConst {
 	/// Grabs, decodes and returns the next video frame.
 	/// 
 	/// ## Parameters
-	/// * image:[out] the video frame is returned here. If no frames has been grabbed the image will be empty.
+	/// * image:\[out\] the video frame is returned here. If no frames has been grabbed the image will be empty.
 	/// ## Returns
 	/// `false` if no frames has been grabbed
 	/// 
@@ -1348,14 +1358,15 @@ pub trait VideoCaptureTrait: crate::videoio::VideoCaptureTraitConst {
 	/// frames in video file), the method returns false and the function returns empty image (with %cv::Mat, test it with Mat::empty()).
 	/// 
 	/// 
-	/// Note: In @ref videoio_c "C API", functions cvRetrieveFrame() and cv.RetrieveFrame() return image stored inside the video
+	/// Note: In C API, functions cvRetrieveFrame() and cv.RetrieveFrame() return image stored inside the video
 	/// capturing structure. It is not allowed to modify or release the image! You can copy the frame using
 	/// cvCloneImage and then do whatever you want with the copy.
 	#[inline]
 	fn read(&mut self, image: &mut dyn core::ToOutputArray) -> Result<bool> {
+		crate::core::validity::check("VideoCapture", self.as_raw_mut_VideoCapture())?;
 		output_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoCapture_read_const__OutputArrayR(self.as_raw_mut_VideoCapture(), image.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoCapture_read_const__OutputArrayR(self.as_raw_mut_VideoCapture(), image.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1365,7 +1376,7 @@ pub trait VideoCaptureTrait: crate::videoio::VideoCaptureTraitConst {
 	/// 
 	/// ## Parameters
 	/// * propId: Property identifier from cv::VideoCaptureProperties (eg. cv::CAP_PROP_POS_MSEC, cv::CAP_PROP_POS_FRAMES, ...)
-	/// or one from @ref videoio_flags_others
+	/// or one from videoio_flags_others
 	/// * value: Value of the property.
 	/// ## Returns
 	/// `true` if the property is supported by backend used by the VideoCapture instance.
@@ -1374,8 +1385,9 @@ pub trait VideoCaptureTrait: crate::videoio::VideoCaptureTraitConst {
 	/// value has been accepted by the capture device. See note in VideoCapture::get()
 	#[inline]
 	fn set(&mut self, prop_id: i32, value: f64) -> Result<bool> {
+		crate::core::validity::check("VideoCapture", self.as_raw_mut_VideoCapture())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoCapture_set_int_double(self.as_raw_mut_VideoCapture(), prop_id, value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoCapture_set_int_double(self.as_raw_mut_VideoCapture(), prop_id, value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1386,8 +1398,9 @@ pub trait VideoCaptureTrait: crate::videoio::VideoCaptureTraitConst {
 	/// methods raise exceptions if not successful instead of returning an error code
 	#[inline]
 	fn set_exception_mode(&mut self, enable: bool) -> Result<()> {
+		crate::core::validity::check("VideoCapture", self.as_raw_mut_VideoCapture())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoCapture_setExceptionMode_bool(self.as_raw_mut_VideoCapture(), enable, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoCapture_setExceptionMode_bool(self.as_raw_mut_VideoCapture(), enable, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1396,8 +1409,9 @@ pub trait VideoCaptureTrait: crate::videoio::VideoCaptureTraitConst {
 	/// query if exception mode is active
 	#[inline]
 	fn get_exception_mode(&mut self) -> Result<bool> {
+		crate::core::validity::check("VideoCapture", self.as_raw_mut_VideoCapture())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoCapture_getExceptionMode(self.as_raw_mut_VideoCapture(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoCapture_getExceptionMode(self.as_raw_mut_VideoCapture(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1413,7 +1427,7 @@ pub trait VideoCaptureTrait: crate::videoio::VideoCaptureTraitConst {
 /// @include samples/cpp/videocapture_basic.cpp
 /// 
 /// 
-/// Note: In @ref videoio_c "C API" the black-box structure `CvCapture` is used instead of %VideoCapture.
+/// Note: In C API the black-box structure `CvCapture` is used instead of %VideoCapture.
 /// 
 /// Note:
 /// *   (C++) A basic sample on using the %VideoCapture interface can be found at
@@ -1433,7 +1447,10 @@ opencv_type_boxed! { VideoCapture }
 impl Drop for VideoCapture {
 	fn drop(&mut self) {
 		extern "C" { fn cv_VideoCapture_delete(instance: *mut c_void); }
-		unsafe { cv_VideoCapture_delete(self.as_raw_mut_VideoCapture()) };
+		let ptr = self.as_raw_mut_VideoCapture();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_VideoCapture_delete(ptr) };
 	}
 }
 
@@ -1450,13 +1467,13 @@ impl crate::videoio::VideoCaptureTrait for VideoCapture {
 impl VideoCapture {
 	/// Default constructor
 	/// 
-	/// Note: In @ref videoio_c "C API", when you finished working with video, release CvCapture structure with
+	/// Note: In C API, when you finished working with video, release CvCapture structure with
 	/// cvReleaseCapture(), or use Ptr\<CvCapture\> that calls cvReleaseCapture() automatically in the
 	/// destructor.
 	#[inline]
 	pub fn default() -> Result<crate::videoio::VideoCapture> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoCapture_VideoCapture(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoCapture_VideoCapture(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videoio::VideoCapture::opencv_from_extern(ret) };
@@ -1465,7 +1482,7 @@ impl VideoCapture {
 	
 	/// Default constructor
 	/// 
-	/// Note: In @ref videoio_c "C API", when you finished working with video, release CvCapture structure with
+	/// Note: In C API, when you finished working with video, release CvCapture structure with
 	/// cvReleaseCapture(), or use Ptr\<CvCapture\> that calls cvReleaseCapture() automatically in the
 	/// destructor.
 	/// 
@@ -1484,7 +1501,7 @@ impl VideoCapture {
 	/// * apiPreference: preferred Capture API backends to use. Can be used to enforce a specific reader
 	///    implementation if multiple are available: e.g. cv::CAP_FFMPEG or cv::CAP_IMAGES or cv::CAP_DSHOW.
 	/// ## See also
-	/// cv::VideoCaptureAPIs
+	/// `VideoCaptureAPIs`
 	/// 
 	/// ## C++ default parameters
 	/// * api_preference: CAP_ANY
@@ -1492,7 +1509,7 @@ impl VideoCapture {
 	pub fn from_file(filename: &str, api_preference: i32) -> Result<crate::videoio::VideoCapture> {
 		extern_container_arg!(filename);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoCapture_VideoCapture_const_StringR_int(filename.opencv_as_extern(), api_preference, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoCapture_VideoCapture_const_StringR_int(filename.opencv_as_extern(), api_preference, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videoio::VideoCapture::opencv_from_extern(ret) };
@@ -1501,7 +1518,7 @@ impl VideoCapture {
 	
 	/// Default constructor
 	/// 
-	/// Note: In @ref videoio_c "C API", when you finished working with video, release CvCapture structure with
+	/// Note: In C API, when you finished working with video, release CvCapture structure with
 	/// cvReleaseCapture(), or use Ptr\<CvCapture\> that calls cvReleaseCapture() automatically in the
 	/// destructor.
 	/// 
@@ -1515,7 +1532,7 @@ impl VideoCapture {
 	pub fn from_file_with_params(filename: &str, api_preference: i32, params: &core::Vector<i32>) -> Result<crate::videoio::VideoCapture> {
 		extern_container_arg!(filename);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoCapture_VideoCapture_const_StringR_int_const_vector_int_R(filename.opencv_as_extern(), api_preference, params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoCapture_VideoCapture_const_StringR_int_const_vector_int_R(filename.opencv_as_extern(), api_preference, params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videoio::VideoCapture::opencv_from_extern(ret) };
@@ -1524,7 +1541,7 @@ impl VideoCapture {
 	
 	/// Default constructor
 	/// 
-	/// Note: In @ref videoio_c "C API", when you finished working with video, release CvCapture structure with
+	/// Note: In C API, when you finished working with video, release CvCapture structure with
 	/// cvReleaseCapture(), or use Ptr\<CvCapture\> that calls cvReleaseCapture() automatically in the
 	/// destructor.
 	/// 
@@ -1538,14 +1555,14 @@ impl VideoCapture {
 	/// * apiPreference: preferred Capture API backends to use. Can be used to enforce a specific reader
 	///    implementation if multiple are available: e.g. cv::CAP_DSHOW or cv::CAP_MSMF or cv::CAP_V4L.
 	/// ## See also
-	/// cv::VideoCaptureAPIs
+	/// `VideoCaptureAPIs`
 	/// 
 	/// ## C++ default parameters
 	/// * api_preference: CAP_ANY
 	#[inline]
 	pub fn new(index: i32, api_preference: i32) -> Result<crate::videoio::VideoCapture> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoCapture_VideoCapture_int_int(index, api_preference, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoCapture_VideoCapture_int_int(index, api_preference, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videoio::VideoCapture::opencv_from_extern(ret) };
@@ -1554,7 +1571,7 @@ impl VideoCapture {
 	
 	/// Default constructor
 	/// 
-	/// Note: In @ref videoio_c "C API", when you finished working with video, release CvCapture structure with
+	/// Note: In C API, when you finished working with video, release CvCapture structure with
 	/// cvReleaseCapture(), or use Ptr\<CvCapture\> that calls cvReleaseCapture() automatically in the
 	/// destructor.
 	/// 
@@ -1567,7 +1584,7 @@ impl VideoCapture {
 	#[inline]
 	pub fn new_with_params(index: i32, api_preference: i32, params: &core::Vector<i32>) -> Result<crate::videoio::VideoCapture> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoCapture_VideoCapture_int_int_const_vector_int_R(index, api_preference, params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoCapture_VideoCapture_int_int_const_vector_int_R(index, api_preference, params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videoio::VideoCapture::opencv_from_extern(ret) };
@@ -1595,7 +1612,7 @@ impl VideoCapture {
 	#[inline]
 	pub fn wait_any(streams: &core::Vector<crate::videoio::VideoCapture>, ready_index: &mut core::Vector<i32>, timeout_ns: i64) -> Result<bool> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoCapture_waitAny_const_vector_VideoCapture_R_vector_int_R_int64_t(streams.as_raw_VectorOfVideoCapture(), ready_index.as_raw_mut_VectorOfi32(), timeout_ns, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoCapture_waitAny_const_vector_VideoCapture_R_vector_int_R_int64_t(streams.as_raw_VectorOfVideoCapture(), ready_index.as_raw_mut_VectorOfi32(), timeout_ns, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1612,8 +1629,9 @@ pub trait VideoWriterTraitConst {
 	/// Returns true if video writer has been successfully initialized.
 	#[inline]
 	fn is_opened(&self) -> Result<bool> {
+		crate::core::validity::check("VideoWriter", self.as_raw_VideoWriter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoWriter_isOpened_const(self.as_raw_VideoWriter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoWriter_isOpened_const(self.as_raw_VideoWriter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1623,15 +1641,16 @@ pub trait VideoWriterTraitConst {
 	/// 
 	/// ## Parameters
 	/// * propId: Property identifier from cv::VideoWriterProperties (eg. cv::VIDEOWRITER_PROP_QUALITY)
-	/// or one of @ref videoio_flags_others
+	/// or one of videoio_flags_others
 	/// 
 	/// ## Returns
 	/// Value for the specified property. Value 0 is returned when querying a property that is
 	/// not supported by the backend used by the VideoWriter instance.
 	#[inline]
 	fn get(&self, prop_id: i32) -> Result<f64> {
+		crate::core::validity::check("VideoWriter", self.as_raw_VideoWriter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoWriter_get_const_int(self.as_raw_VideoWriter(), prop_id, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoWriter_get_const_int(self.as_raw_VideoWriter(), prop_id, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1643,8 +1662,9 @@ pub trait VideoWriterTraitConst {
 	/// Note: Stream should be opened.
 	#[inline]
 	fn get_backend_name(&self) -> Result<String> {
+		crate::core::validity::check("VideoWriter", self.as_raw_VideoWriter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoWriter_getBackendName_const(self.as_raw_VideoWriter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoWriter_getBackendName_const(self.as_raw_VideoWriter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -1669,9 +1689,10 @@ pub trait VideoWriterTrait: crate::videoio::VideoWriterTraitConst {
 	/// * is_color: true
 	#[inline]
 	fn open(&mut self, filename: &str, fourcc: i32, fps: f64, frame_size: core::Size, is_color: bool) -> Result<bool> {
+		crate::core::validity::check("VideoWriter", self.as_raw_mut_VideoWriter())?;
 		extern_container_arg!(filename);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoWriter_open_const_StringR_int_double_Size_bool(self.as_raw_mut_VideoWriter(), filename.opencv_as_extern(), fourcc, fps, frame_size.opencv_as_extern(), is_color, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoWriter_open_const_StringR_int_double_Size_bool(self.as_raw_mut_VideoWriter(), filename.opencv_as_extern(), fourcc, fps, frame_size.opencv_as_extern(), is_color, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1692,9 +1713,10 @@ pub trait VideoWriterTrait: crate::videoio::VideoWriterTraitConst {
 	/// * is_color: true
 	#[inline]
 	fn open_with_backend(&mut self, filename: &str, api_preference: i32, fourcc: i32, fps: f64, frame_size: core::Size, is_color: bool) -> Result<bool> {
+		crate::core::validity::check("VideoWriter", self.as_raw_mut_VideoWriter())?;
 		extern_container_arg!(filename);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoWriter_open_const_StringR_int_int_double_Size_bool(self.as_raw_mut_VideoWriter(), filename.opencv_as_extern(), api_preference, fourcc, fps, frame_size.opencv_as_extern(), is_color, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoWriter_open_const_StringR_int_int_double_Size_bool(self.as_raw_mut_VideoWriter(), filename.opencv_as_extern(), api_preference, fourcc, fps, frame_size.opencv_as_extern(), is_color, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1712,9 +1734,10 @@ pub trait VideoWriterTrait: crate::videoio::VideoWriterTraitConst {
 	/// ## Overloaded parameters
 	#[inline]
 	fn open_1(&mut self, filename: &str, fourcc: i32, fps: f64, frame_size: core::Size, params: &core::Vector<i32>) -> Result<bool> {
+		crate::core::validity::check("VideoWriter", self.as_raw_mut_VideoWriter())?;
 		extern_container_arg!(filename);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoWriter_open_const_StringR_int_double_const_SizeR_const_vector_int_R(self.as_raw_mut_VideoWriter(), filename.opencv_as_extern(), fourcc, fps, &frame_size, params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoWriter_open_const_StringR_int_double_const_SizeR_const_vector_int_R(self.as_raw_mut_VideoWriter(), filename.opencv_as_extern(), fourcc, fps, &frame_size, params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1732,9 +1755,10 @@ pub trait VideoWriterTrait: crate::videoio::VideoWriterTraitConst {
 	/// ## Overloaded parameters
 	#[inline]
 	fn open_2(&mut self, filename: &str, api_preference: i32, fourcc: i32, fps: f64, frame_size: core::Size, params: &core::Vector<i32>) -> Result<bool> {
+		crate::core::validity::check("VideoWriter", self.as_raw_mut_VideoWriter())?;
 		extern_container_arg!(filename);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoWriter_open_const_StringR_int_int_double_const_SizeR_const_vector_int_R(self.as_raw_mut_VideoWriter(), filename.opencv_as_extern(), api_preference, fourcc, fps, &frame_size, params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoWriter_open_const_StringR_int_int_double_const_SizeR_const_vector_int_R(self.as_raw_mut_VideoWriter(), filename.opencv_as_extern(), api_preference, fourcc, fps, &frame_size, params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1746,8 +1770,9 @@ pub trait VideoWriterTrait: crate::videoio::VideoWriterTraitConst {
 	/// destructor.
 	#[inline]
 	fn release(&mut self) -> Result<()> {
+		crate::core::validity::check("VideoWriter", self.as_raw_mut_VideoWriter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoWriter_release(self.as_raw_mut_VideoWriter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoWriter_release(self.as_raw_mut_VideoWriter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1762,9 +1787,10 @@ pub trait VideoWriterTrait: crate::videoio::VideoWriterTraitConst {
 	/// been specified when opening the video writer.
 	#[inline]
 	fn write(&mut self, image: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("VideoWriter", self.as_raw_mut_VideoWriter())?;
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoWriter_write_const__InputArrayR(self.as_raw_mut_VideoWriter(), image.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoWriter_write_const__InputArrayR(self.as_raw_mut_VideoWriter(), image.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1774,15 +1800,16 @@ pub trait VideoWriterTrait: crate::videoio::VideoWriterTraitConst {
 	/// 
 	/// ## Parameters
 	/// * propId: Property identifier from cv::VideoWriterProperties (eg. cv::VIDEOWRITER_PROP_QUALITY)
-	/// or one of @ref videoio_flags_others
+	/// or one of videoio_flags_others
 	/// 
 	/// * value: Value of the property.
 	/// ## Returns
 	/// `true` if the property is supported by the backend used by the VideoWriter instance.
 	#[inline]
 	fn set(&mut self, prop_id: i32, value: f64) -> Result<bool> {
+		crate::core::validity::check("VideoWriter", self.as_raw_mut_VideoWriter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoWriter_set_int_double(self.as_raw_mut_VideoWriter(), prop_id, value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoWriter_set_int_double(self.as_raw_mut_VideoWriter(), prop_id, value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1790,6 +1817,39 @@ pub trait VideoWriterTrait: crate::videoio::VideoWriterTraitConst {
 	
 }
 
+/// Builder for `VideoWriterTrait::open_with_backend()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct VideoWriter_OpenWithBackendBuilder<'a, T: ?Sized + VideoWriterTrait> {
+	instance: &'a mut T,
+	filename: &'a str,
+	api_preference: i32,
+	fourcc: i32,
+	fps: f64,
+	frame_size: core::Size,
+	is_color: bool,
+}
+
+impl<'a, T: ?Sized + VideoWriterTrait> VideoWriter_OpenWithBackendBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, filename: &'a str, api_preference: i32, fourcc: i32, fps: f64, frame_size: core::Size) -> Self {
+		Self { instance, filename, api_preference, fourcc, fps, frame_size, is_color: true }
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_is_color(mut self, is_color: bool) -> Self {
+		self.is_color = is_color;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<bool> {
+		VideoWriterTrait::open_with_backend(self.instance, self.filename, self.api_preference, self.fourcc, self.fps, self.frame_size, self.is_color)
+	}
+}
+
 /// Video writer class.
 /// 
 /// The class provides C++ API for writing video files or image sequences.
@@ -1802,7 +1862,10 @@ opencv_type_boxed! { VideoWriter }
 impl Drop for VideoWriter {
 	fn drop(&mut self) {
 		extern "C" { fn cv_VideoWriter_delete(instance: *mut c_void); }
-		unsafe { cv_VideoWriter_delete(self.as_raw_mut_VideoWriter()) };
+		let ptr = self.as_raw_mut_VideoWriter();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_VideoWriter_delete(ptr) };
 	}
 }
 
@@ -1826,7 +1889,7 @@ impl VideoWriter {
 	#[inline]
 	pub fn default() -> Result<crate::videoio::VideoWriter> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoWriter_VideoWriter(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoWriter_VideoWriter(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videoio::VideoWriter::opencv_from_extern(ret) };
@@ -1846,8 +1909,8 @@ impl VideoWriter {
 	/// * filename: Name of the output video file.
 	/// * fourcc: 4-character code of codec used to compress the frames. For example,
 	///    VideoWriter::fourcc('P','I','M','1') is a MPEG-1 codec, VideoWriter::fourcc('M','J','P','G') is a
-	///    motion-jpeg codec etc. List of codes can be obtained at [Video Codecs by
-	///    FOURCC](http://www.fourcc.org/codecs.php) page. FFMPEG backend with MP4 container natively uses
+	///    motion-jpeg codec etc. List of codes can be obtained at \[Video Codecs by
+	///    FOURCC\](http://www.fourcc.org/codecs.php) page. FFMPEG backend with MP4 container natively uses
 	///    other values as fourcc code: see [ObjectType](http://mp4ra.org/#/codecs),
 	///    so you may receive a warning message from OpenCV about fourcc code conversion.
 	/// * fps: Framerate of the created video stream.
@@ -1869,7 +1932,7 @@ impl VideoWriter {
 	pub fn new(filename: &str, fourcc: i32, fps: f64, frame_size: core::Size, is_color: bool) -> Result<crate::videoio::VideoWriter> {
 		extern_container_arg!(filename);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoWriter_VideoWriter_const_StringR_int_double_Size_bool(filename.opencv_as_extern(), fourcc, fps, frame_size.opencv_as_extern(), is_color, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoWriter_VideoWriter_const_StringR_int_double_Size_bool(filename.opencv_as_extern(), fourcc, fps, frame_size.opencv_as_extern(), is_color, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videoio::VideoWriter::opencv_from_extern(ret) };
@@ -1894,7 +1957,7 @@ impl VideoWriter {
 	pub fn new_with_backend(filename: &str, api_preference: i32, fourcc: i32, fps: f64, frame_size: core::Size, is_color: bool) -> Result<crate::videoio::VideoWriter> {
 		extern_container_arg!(filename);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoWriter_VideoWriter_const_StringR_int_int_double_Size_bool(filename.opencv_as_extern(), api_preference, fourcc, fps, frame_size.opencv_as_extern(), is_color, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoWriter_VideoWriter_const_StringR_int_int_double_Size_bool(filename.opencv_as_extern(), api_preference, fourcc, fps, frame_size.opencv_as_extern(), is_color, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videoio::VideoWriter::opencv_from_extern(ret) };
@@ -1916,7 +1979,7 @@ impl VideoWriter {
 	pub fn new_1(filename: &str, fourcc: i32, fps: f64, frame_size: core::Size, params: &core::Vector<i32>) -> Result<crate::videoio::VideoWriter> {
 		extern_container_arg!(filename);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoWriter_VideoWriter_const_StringR_int_double_const_SizeR_const_vector_int_R(filename.opencv_as_extern(), fourcc, fps, &frame_size, params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoWriter_VideoWriter_const_StringR_int_double_const_SizeR_const_vector_int_R(filename.opencv_as_extern(), fourcc, fps, &frame_size, params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videoio::VideoWriter::opencv_from_extern(ret) };
@@ -1935,7 +1998,7 @@ impl VideoWriter {
 	pub fn new_2(filename: &str, api_preference: i32, fourcc: i32, fps: f64, frame_size: core::Size, params: &core::Vector<i32>) -> Result<crate::videoio::VideoWriter> {
 		extern_container_arg!(filename);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoWriter_VideoWriter_const_StringR_int_int_double_const_SizeR_const_vector_int_R(filename.opencv_as_extern(), api_preference, fourcc, fps, &frame_size, params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoWriter_VideoWriter_const_StringR_int_int_double_const_SizeR_const_vector_int_R(filename.opencv_as_extern(), api_preference, fourcc, fps, &frame_size, params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videoio::VideoWriter::opencv_from_extern(ret) };
@@ -1952,10 +2015,43 @@ impl VideoWriter {
 	#[inline]
 	pub fn fourcc(c1: i8, c2: i8, c3: i8, c4: i8) -> Result<i32> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_VideoWriter_fourcc_char_char_char_char(c1, c2, c3, c4, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_VideoWriter_fourcc_char_char_char_char(c1, c2, c3, c4, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 }
+
+/// Builder for `VideoWriter::new_with_backend()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct VideoWriter_NewWithBackendBuilder<'a> {
+	filename: &'a str,
+	api_preference: i32,
+	fourcc: i32,
+	fps: f64,
+	frame_size: core::Size,
+	is_color: bool,
+}
+
+impl<'a> VideoWriter_NewWithBackendBuilder<'a> {
+	#[inline]
+	pub fn new(filename: &'a str, api_preference: i32, fourcc: i32, fps: f64, frame_size: core::Size) -> Self {
+		Self { filename, api_preference, fourcc, fps, frame_size, is_color: true }
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_is_color(mut self, is_color: bool) -> Self {
+		self.is_color = is_color;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::videoio::VideoWriter> {
+		VideoWriter::new_with_backend(self.filename, self.api_preference, self.fourcc, self.fps, self.frame_size, self.is_color)
+	}
+}
+pub use crate::manual::videoio::*;
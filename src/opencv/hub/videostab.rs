@@ -63,7 +63,7 @@ opencv_type_enum! { crate::videostab::MotionModel }
 #[inline]
 pub fn calc_blurriness(frame: &core::Mat) -> Result<f32> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_videostab_calcBlurriness_const_MatR(frame.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_videostab_calcBlurriness_const_MatR(frame.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -72,7 +72,7 @@ pub fn calc_blurriness(frame: &core::Mat) -> Result<f32> {
 #[inline]
 pub fn calc_flow_mask(flow_x: &core::Mat, flow_y: &core::Mat, errors: &core::Mat, max_error: f32, mask0: &core::Mat, mask1: &core::Mat, flow_mask: &mut core::Mat) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_videostab_calcFlowMask_const_MatR_const_MatR_const_MatR_float_const_MatR_const_MatR_MatR(flow_x.as_raw_Mat(), flow_y.as_raw_Mat(), errors.as_raw_Mat(), max_error, mask0.as_raw_Mat(), mask1.as_raw_Mat(), flow_mask.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_videostab_calcFlowMask_const_MatR_const_MatR_const_MatR_float_const_MatR_const_MatR_MatR(flow_x.as_raw_Mat(), flow_y.as_raw_Mat(), errors.as_raw_Mat(), max_error, mask0.as_raw_Mat(), mask1.as_raw_Mat(), flow_mask.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -81,7 +81,7 @@ pub fn calc_flow_mask(flow_x: &core::Mat, flow_y: &core::Mat, errors: &core::Mat
 #[inline]
 pub fn complete_frame_according_to_flow(flow_mask: &core::Mat, flow_x: &core::Mat, flow_y: &core::Mat, frame1: &core::Mat, mask1: &core::Mat, dist_thresh: f32, frame0: &mut core::Mat, mask0: &mut core::Mat) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_videostab_completeFrameAccordingToFlow_const_MatR_const_MatR_const_MatR_const_MatR_const_MatR_float_MatR_MatR(flow_mask.as_raw_Mat(), flow_x.as_raw_Mat(), flow_y.as_raw_Mat(), frame1.as_raw_Mat(), mask1.as_raw_Mat(), dist_thresh, frame0.as_raw_mut_Mat(), mask0.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_videostab_completeFrameAccordingToFlow_const_MatR_const_MatR_const_MatR_const_MatR_const_MatR_float_MatR_MatR(flow_mask.as_raw_Mat(), flow_x.as_raw_Mat(), flow_y.as_raw_Mat(), frame1.as_raw_Mat(), mask1.as_raw_Mat(), dist_thresh, frame0.as_raw_mut_Mat(), mask0.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -90,7 +90,7 @@ pub fn complete_frame_according_to_flow(flow_mask: &core::Mat, flow_x: &core::Ma
 #[inline]
 pub fn ensure_inclusion_constraint(m: &core::Mat, size: core::Size, trim_ratio: f32) -> Result<core::Mat> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_videostab_ensureInclusionConstraint_const_MatR_Size_float(m.as_raw_Mat(), size.opencv_as_extern(), trim_ratio, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_videostab_ensureInclusionConstraint_const_MatR_Size_float(m.as_raw_Mat(), size.opencv_as_extern(), trim_ratio, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -118,7 +118,7 @@ pub fn estimate_global_motion_least_squares(points0: &mut dyn core::ToInputOutpu
 	input_output_array_arg!(points0);
 	input_output_array_arg!(points1);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_videostab_estimateGlobalMotionLeastSquares_const__InputOutputArrayR_const__InputOutputArrayR_int_floatX(points0.as_raw__InputOutputArray(), points1.as_raw__InputOutputArray(), model, rmse, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_videostab_estimateGlobalMotionLeastSquares_const__InputOutputArrayR_const__InputOutputArrayR_int_floatX(points0.as_raw__InputOutputArray(), points1.as_raw__InputOutputArray(), model, rmse, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -145,7 +145,7 @@ pub fn estimate_global_motion_ransac(points0: &dyn core::ToInputArray, points1:
 	input_array_arg!(points0);
 	input_array_arg!(points1);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_videostab_estimateGlobalMotionRansac_const__InputArrayR_const__InputArrayR_int_const_RansacParamsR_floatX_intX(points0.as_raw__InputArray(), points1.as_raw__InputArray(), model, params.as_raw_RansacParams(), rmse, ninliers, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_videostab_estimateGlobalMotionRansac_const__InputArrayR_const__InputArrayR_int_const_RansacParamsR_floatX_intX(points0.as_raw__InputArray(), points1.as_raw__InputArray(), model, params.as_raw_RansacParams(), rmse, ninliers, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -155,7 +155,7 @@ pub fn estimate_global_motion_ransac(points0: &dyn core::ToInputArray, points1:
 #[inline]
 pub fn estimate_optimal_trim_ratio(m: &core::Mat, size: core::Size) -> Result<f32> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_videostab_estimateOptimalTrimRatio_const_MatR_Size(m.as_raw_Mat(), size.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_videostab_estimateOptimalTrimRatio_const_MatR_Size(m.as_raw_Mat(), size.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -166,13 +166,13 @@ pub fn estimate_optimal_trim_ratio(m: &core::Mat, size: core::Size) -> Result<f3
 /// ## Parameters
 /// * from: Source frame index.
 /// * to: Destination frame index.
-/// * motions: Pair-wise motions. motions[i] denotes motion from the frame i to the frame i+1
+/// * motions: Pair-wise motions. motions\[i\] denotes motion from the frame i to the frame i+1
 /// ## Returns
 /// Motion from the Source frame to the Destination frame.
 #[inline]
 pub fn get_motion(from: i32, to: i32, motions: &core::Vector<core::Mat>) -> Result<core::Mat> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_videostab_getMotion_int_int_const_vector_Mat_R(from, to, motions.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_videostab_getMotion_int_int_const_vector_Mat_R(from, to, motions.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -189,8 +189,9 @@ pub trait ColorAverageInpainterTrait: crate::videostab::ColorAverageInpainterTra
 
 	#[inline]
 	fn inpaint(&mut self, idx: i32, frame: &mut core::Mat, mask: &mut core::Mat) -> Result<()> {
+		crate::core::validity::check("ColorAverageInpainter", self.as_raw_mut_ColorAverageInpainter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_ColorAverageInpainter_inpaint_int_MatR_MatR(self.as_raw_mut_ColorAverageInpainter(), idx, frame.as_raw_mut_Mat(), mask.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_ColorAverageInpainter_inpaint_int_MatR_MatR(self.as_raw_mut_ColorAverageInpainter(), idx, frame.as_raw_mut_Mat(), mask.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -207,7 +208,10 @@ opencv_type_boxed! { ColorAverageInpainter }
 impl Drop for ColorAverageInpainter {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ColorAverageInpainter_delete(instance: *mut c_void); }
-		unsafe { cv_ColorAverageInpainter_delete(self.as_raw_mut_ColorAverageInpainter()) };
+		let ptr = self.as_raw_mut_ColorAverageInpainter();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ColorAverageInpainter_delete(ptr) };
 	}
 }
 
@@ -242,8 +246,9 @@ pub trait ColorInpainterTrait: crate::videostab::ColorInpainterTraitConst + crat
 
 	#[inline]
 	fn inpaint(&mut self, idx: i32, frame: &mut core::Mat, mask: &mut core::Mat) -> Result<()> {
+		crate::core::validity::check("ColorInpainter", self.as_raw_mut_ColorInpainter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_ColorInpainter_inpaint_int_MatR_MatR(self.as_raw_mut_ColorInpainter(), idx, frame.as_raw_mut_Mat(), mask.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_ColorInpainter_inpaint_int_MatR_MatR(self.as_raw_mut_ColorInpainter(), idx, frame.as_raw_mut_Mat(), mask.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -260,7 +265,10 @@ opencv_type_boxed! { ColorInpainter }
 impl Drop for ColorInpainter {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ColorInpainter_delete(instance: *mut c_void); }
-		unsafe { cv_ColorInpainter_delete(self.as_raw_mut_ColorInpainter()) };
+		let ptr = self.as_raw_mut_ColorInpainter();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ColorInpainter_delete(ptr) };
 	}
 }
 
@@ -289,7 +297,7 @@ impl ColorInpainter {
 	#[inline]
 	pub fn new(method: i32, radius: f64) -> Result<crate::videostab::ColorInpainter> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_ColorInpainter_ColorInpainter_int_double(method, radius, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_ColorInpainter_ColorInpainter_int_double(method, radius, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videostab::ColorInpainter::opencv_from_extern(ret) };
@@ -303,8 +311,9 @@ pub trait ConsistentMosaicInpainterTraitConst: crate::videostab::InpainterBaseCo
 
 	#[inline]
 	fn stdev_thresh(&self) -> Result<f32> {
+		crate::core::validity::check("ConsistentMosaicInpainter", self.as_raw_ConsistentMosaicInpainter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_ConsistentMosaicInpainter_stdevThresh_const(self.as_raw_ConsistentMosaicInpainter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_ConsistentMosaicInpainter_stdevThresh_const(self.as_raw_ConsistentMosaicInpainter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -317,8 +326,9 @@ pub trait ConsistentMosaicInpainterTrait: crate::videostab::ConsistentMosaicInpa
 
 	#[inline]
 	fn set_stdev_thresh(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("ConsistentMosaicInpainter", self.as_raw_mut_ConsistentMosaicInpainter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_ConsistentMosaicInpainter_setStdevThresh_float(self.as_raw_mut_ConsistentMosaicInpainter(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_ConsistentMosaicInpainter_setStdevThresh_float(self.as_raw_mut_ConsistentMosaicInpainter(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -326,8 +336,9 @@ pub trait ConsistentMosaicInpainterTrait: crate::videostab::ConsistentMosaicInpa
 	
 	#[inline]
 	fn inpaint(&mut self, idx: i32, frame: &mut core::Mat, mask: &mut core::Mat) -> Result<()> {
+		crate::core::validity::check("ConsistentMosaicInpainter", self.as_raw_mut_ConsistentMosaicInpainter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_ConsistentMosaicInpainter_inpaint_int_MatR_MatR(self.as_raw_mut_ConsistentMosaicInpainter(), idx, frame.as_raw_mut_Mat(), mask.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_ConsistentMosaicInpainter_inpaint_int_MatR_MatR(self.as_raw_mut_ConsistentMosaicInpainter(), idx, frame.as_raw_mut_Mat(), mask.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -344,7 +355,10 @@ opencv_type_boxed! { ConsistentMosaicInpainter }
 impl Drop for ConsistentMosaicInpainter {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ConsistentMosaicInpainter_delete(instance: *mut c_void); }
-		unsafe { cv_ConsistentMosaicInpainter_delete(self.as_raw_mut_ConsistentMosaicInpainter()) };
+		let ptr = self.as_raw_mut_ConsistentMosaicInpainter();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ConsistentMosaicInpainter_delete(ptr) };
 	}
 }
 
@@ -370,7 +384,7 @@ impl ConsistentMosaicInpainter {
 	#[inline]
 	pub fn default() -> Result<crate::videostab::ConsistentMosaicInpainter> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_ConsistentMosaicInpainter_ConsistentMosaicInpainter(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_ConsistentMosaicInpainter_ConsistentMosaicInpainter(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videostab::ConsistentMosaicInpainter::opencv_from_extern(ret) };
@@ -384,8 +398,9 @@ pub trait DeblurerBaseConst {
 
 	#[inline]
 	fn radius(&self) -> Result<i32> {
+		crate::core::validity::check("DeblurerBase", self.as_raw_DeblurerBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_DeblurerBase_radius_const(self.as_raw_DeblurerBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_DeblurerBase_radius_const(self.as_raw_DeblurerBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -393,8 +408,9 @@ pub trait DeblurerBaseConst {
 	
 	#[inline]
 	fn frames(&self) -> Result<core::Vector<core::Mat>> {
+		crate::core::validity::check("DeblurerBase", self.as_raw_DeblurerBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_DeblurerBase_frames_const(self.as_raw_DeblurerBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_DeblurerBase_frames_const(self.as_raw_DeblurerBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<core::Mat>::opencv_from_extern(ret) };
@@ -403,8 +419,9 @@ pub trait DeblurerBaseConst {
 	
 	#[inline]
 	fn motions(&self) -> Result<core::Vector<core::Mat>> {
+		crate::core::validity::check("DeblurerBase", self.as_raw_DeblurerBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_DeblurerBase_motions_const(self.as_raw_DeblurerBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_DeblurerBase_motions_const(self.as_raw_DeblurerBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<core::Mat>::opencv_from_extern(ret) };
@@ -413,8 +430,9 @@ pub trait DeblurerBaseConst {
 	
 	#[inline]
 	fn blurriness_rates(&self) -> Result<core::Vector<f32>> {
+		crate::core::validity::check("DeblurerBase", self.as_raw_DeblurerBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_DeblurerBase_blurrinessRates_const(self.as_raw_DeblurerBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_DeblurerBase_blurrinessRates_const(self.as_raw_DeblurerBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<f32>::opencv_from_extern(ret) };
@@ -428,8 +446,9 @@ pub trait DeblurerBase: crate::videostab::DeblurerBaseConst {
 
 	#[inline]
 	fn set_radius(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("DeblurerBase", self.as_raw_mut_DeblurerBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_DeblurerBase_setRadius_int(self.as_raw_mut_DeblurerBase(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_DeblurerBase_setRadius_int(self.as_raw_mut_DeblurerBase(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -437,8 +456,9 @@ pub trait DeblurerBase: crate::videostab::DeblurerBaseConst {
 	
 	#[inline]
 	fn deblur(&mut self, idx: i32, frame: &mut core::Mat, range: &core::Range) -> Result<()> {
+		crate::core::validity::check("DeblurerBase", self.as_raw_mut_DeblurerBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_DeblurerBase_deblur_int_MatR_const_RangeR(self.as_raw_mut_DeblurerBase(), idx, frame.as_raw_mut_Mat(), range.as_raw_Range(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_DeblurerBase_deblur_int_MatR_const_RangeR(self.as_raw_mut_DeblurerBase(), idx, frame.as_raw_mut_Mat(), range.as_raw_Range(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -446,8 +466,9 @@ pub trait DeblurerBase: crate::videostab::DeblurerBaseConst {
 	
 	#[inline]
 	fn set_frames(&mut self, val: &core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("DeblurerBase", self.as_raw_mut_DeblurerBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_DeblurerBase_setFrames_const_vector_Mat_R(self.as_raw_mut_DeblurerBase(), val.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_DeblurerBase_setFrames_const_vector_Mat_R(self.as_raw_mut_DeblurerBase(), val.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -455,8 +476,9 @@ pub trait DeblurerBase: crate::videostab::DeblurerBaseConst {
 	
 	#[inline]
 	fn set_motions(&mut self, val: &core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("DeblurerBase", self.as_raw_mut_DeblurerBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_DeblurerBase_setMotions_const_vector_Mat_R(self.as_raw_mut_DeblurerBase(), val.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_DeblurerBase_setMotions_const_vector_Mat_R(self.as_raw_mut_DeblurerBase(), val.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -464,8 +486,9 @@ pub trait DeblurerBase: crate::videostab::DeblurerBaseConst {
 	
 	#[inline]
 	fn set_blurriness_rates(&mut self, val: &core::Vector<f32>) -> Result<()> {
+		crate::core::validity::check("DeblurerBase", self.as_raw_mut_DeblurerBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_DeblurerBase_setBlurrinessRates_const_vector_float_R(self.as_raw_mut_DeblurerBase(), val.as_raw_VectorOff32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_DeblurerBase_setBlurrinessRates_const_vector_float_R(self.as_raw_mut_DeblurerBase(), val.as_raw_VectorOff32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -483,13 +506,14 @@ pub trait DensePyrLkOptFlowEstimatorGpuTrait: crate::videostab::DensePyrLkOptFlo
 
 	#[inline]
 	fn run(&mut self, frame0: &dyn core::ToInputArray, frame1: &dyn core::ToInputArray, flow_x: &mut dyn core::ToInputOutputArray, flow_y: &mut dyn core::ToInputOutputArray, errors: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("DensePyrLkOptFlowEstimatorGpu", self.as_raw_mut_DensePyrLkOptFlowEstimatorGpu())?;
 		input_array_arg!(frame0);
 		input_array_arg!(frame1);
 		input_output_array_arg!(flow_x);
 		input_output_array_arg!(flow_y);
 		output_array_arg!(errors);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_DensePyrLkOptFlowEstimatorGpu_run_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR(self.as_raw_mut_DensePyrLkOptFlowEstimatorGpu(), frame0.as_raw__InputArray(), frame1.as_raw__InputArray(), flow_x.as_raw__InputOutputArray(), flow_y.as_raw__InputOutputArray(), errors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_DensePyrLkOptFlowEstimatorGpu_run_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR(self.as_raw_mut_DensePyrLkOptFlowEstimatorGpu(), frame0.as_raw__InputArray(), frame1.as_raw__InputArray(), flow_x.as_raw__InputOutputArray(), flow_y.as_raw__InputOutputArray(), errors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -506,7 +530,10 @@ opencv_type_boxed! { DensePyrLkOptFlowEstimatorGpu }
 impl Drop for DensePyrLkOptFlowEstimatorGpu {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DensePyrLkOptFlowEstimatorGpu_delete(instance: *mut c_void); }
-		unsafe { cv_DensePyrLkOptFlowEstimatorGpu_delete(self.as_raw_mut_DensePyrLkOptFlowEstimatorGpu()) };
+		let ptr = self.as_raw_mut_DensePyrLkOptFlowEstimatorGpu();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_DensePyrLkOptFlowEstimatorGpu_delete(ptr) };
 	}
 }
 
@@ -540,7 +567,7 @@ impl DensePyrLkOptFlowEstimatorGpu {
 	#[inline]
 	pub fn default() -> Result<crate::videostab::DensePyrLkOptFlowEstimatorGpu> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_DensePyrLkOptFlowEstimatorGpu_DensePyrLkOptFlowEstimatorGpu(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_DensePyrLkOptFlowEstimatorGpu_DensePyrLkOptFlowEstimatorGpu(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videostab::DensePyrLkOptFlowEstimatorGpu::opencv_from_extern(ret) };
@@ -561,8 +588,9 @@ pub trait FastMarchingMethodTraitConst {
 	/// Distance map that's created during working of the method.
 	#[inline]
 	fn distance_map(&self) -> Result<core::Mat> {
+		crate::core::validity::check("FastMarchingMethod", self.as_raw_FastMarchingMethod())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_FastMarchingMethod_distanceMap_const(self.as_raw_FastMarchingMethod(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_FastMarchingMethod_distanceMap_const(self.as_raw_FastMarchingMethod(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -588,7 +616,10 @@ opencv_type_boxed! { FastMarchingMethod }
 impl Drop for FastMarchingMethod {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FastMarchingMethod_delete(instance: *mut c_void); }
-		unsafe { cv_FastMarchingMethod_delete(self.as_raw_mut_FastMarchingMethod()) };
+		let ptr = self.as_raw_mut_FastMarchingMethod();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_FastMarchingMethod_delete(ptr) };
 	}
 }
 
@@ -606,7 +637,7 @@ impl FastMarchingMethod {
 	#[inline]
 	pub fn default() -> Result<crate::videostab::FastMarchingMethod> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_FastMarchingMethod_FastMarchingMethod(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_FastMarchingMethod_FastMarchingMethod(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videostab::FastMarchingMethod::opencv_from_extern(ret) };
@@ -627,8 +658,9 @@ pub trait FromFileMotionReaderTrait: crate::videostab::FromFileMotionReaderTrait
 	/// * ok: 0
 	#[inline]
 	fn estimate(&mut self, frame0: &core::Mat, frame1: &core::Mat, ok: &mut bool) -> Result<core::Mat> {
+		crate::core::validity::check("FromFileMotionReader", self.as_raw_mut_FromFileMotionReader())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_FromFileMotionReader_estimate_const_MatR_const_MatR_boolX(self.as_raw_mut_FromFileMotionReader(), frame0.as_raw_Mat(), frame1.as_raw_Mat(), ok, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_FromFileMotionReader_estimate_const_MatR_const_MatR_boolX(self.as_raw_mut_FromFileMotionReader(), frame0.as_raw_Mat(), frame1.as_raw_Mat(), ok, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -646,7 +678,10 @@ opencv_type_boxed! { FromFileMotionReader }
 impl Drop for FromFileMotionReader {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FromFileMotionReader_delete(instance: *mut c_void); }
-		unsafe { cv_FromFileMotionReader_delete(self.as_raw_mut_FromFileMotionReader()) };
+		let ptr = self.as_raw_mut_FromFileMotionReader();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_FromFileMotionReader_delete(ptr) };
 	}
 }
 
@@ -673,7 +708,7 @@ impl FromFileMotionReader {
 	pub fn new(path: &str) -> Result<crate::videostab::FromFileMotionReader> {
 		extern_container_arg!(path);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_FromFileMotionReader_FromFileMotionReader_const_StringR(path.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_FromFileMotionReader_FromFileMotionReader_const_StringR(path.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videostab::FromFileMotionReader::opencv_from_extern(ret) };
@@ -687,8 +722,9 @@ pub trait GaussianMotionFilterTraitConst: crate::videostab::MotionFilterBaseCons
 
 	#[inline]
 	fn radius(&self) -> Result<i32> {
+		crate::core::validity::check("GaussianMotionFilter", self.as_raw_GaussianMotionFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_GaussianMotionFilter_radius_const(self.as_raw_GaussianMotionFilter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_GaussianMotionFilter_radius_const(self.as_raw_GaussianMotionFilter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -696,8 +732,9 @@ pub trait GaussianMotionFilterTraitConst: crate::videostab::MotionFilterBaseCons
 	
 	#[inline]
 	fn stdev(&self) -> Result<f32> {
+		crate::core::validity::check("GaussianMotionFilter", self.as_raw_GaussianMotionFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_GaussianMotionFilter_stdev_const(self.as_raw_GaussianMotionFilter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_GaussianMotionFilter_stdev_const(self.as_raw_GaussianMotionFilter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -712,8 +749,9 @@ pub trait GaussianMotionFilterTrait: crate::videostab::GaussianMotionFilterTrait
 	/// * stdev: -1.f
 	#[inline]
 	fn set_params(&mut self, radius: i32, stdev: f32) -> Result<()> {
+		crate::core::validity::check("GaussianMotionFilter", self.as_raw_mut_GaussianMotionFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_GaussianMotionFilter_setParams_int_float(self.as_raw_mut_GaussianMotionFilter(), radius, stdev, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_GaussianMotionFilter_setParams_int_float(self.as_raw_mut_GaussianMotionFilter(), radius, stdev, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -721,8 +759,9 @@ pub trait GaussianMotionFilterTrait: crate::videostab::GaussianMotionFilterTrait
 	
 	#[inline]
 	fn stabilize(&mut self, idx: i32, motions: &core::Vector<core::Mat>, range: &core::Range) -> Result<core::Mat> {
+		crate::core::validity::check("GaussianMotionFilter", self.as_raw_mut_GaussianMotionFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_GaussianMotionFilter_stabilize_int_const_vector_Mat_R_const_RangeR(self.as_raw_mut_GaussianMotionFilter(), idx, motions.as_raw_VectorOfMat(), range.as_raw_Range(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_GaussianMotionFilter_stabilize_int_const_vector_Mat_R_const_RangeR(self.as_raw_mut_GaussianMotionFilter(), idx, motions.as_raw_VectorOfMat(), range.as_raw_Range(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -740,7 +779,10 @@ opencv_type_boxed! { GaussianMotionFilter }
 impl Drop for GaussianMotionFilter {
 	fn drop(&mut self) {
 		extern "C" { fn cv_GaussianMotionFilter_delete(instance: *mut c_void); }
-		unsafe { cv_GaussianMotionFilter_delete(self.as_raw_mut_GaussianMotionFilter()) };
+		let ptr = self.as_raw_mut_GaussianMotionFilter();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_GaussianMotionFilter_delete(ptr) };
 	}
 }
 
@@ -777,7 +819,7 @@ impl GaussianMotionFilter {
 	#[inline]
 	pub fn new(radius: i32, stdev: f32) -> Result<crate::videostab::GaussianMotionFilter> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_GaussianMotionFilter_GaussianMotionFilter_int_float(radius, stdev, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_GaussianMotionFilter_GaussianMotionFilter_int_float(radius, stdev, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videostab::GaussianMotionFilter::opencv_from_extern(ret) };
@@ -796,13 +838,14 @@ pub trait IDenseOptFlowEstimator: crate::videostab::IDenseOptFlowEstimatorConst
 
 	#[inline]
 	fn run(&mut self, frame0: &dyn core::ToInputArray, frame1: &dyn core::ToInputArray, flow_x: &mut dyn core::ToInputOutputArray, flow_y: &mut dyn core::ToInputOutputArray, errors: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("IDenseOptFlowEstimator", self.as_raw_mut_IDenseOptFlowEstimator())?;
 		input_array_arg!(frame0);
 		input_array_arg!(frame1);
 		input_output_array_arg!(flow_x);
 		input_output_array_arg!(flow_y);
 		output_array_arg!(errors);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_IDenseOptFlowEstimator_run_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR(self.as_raw_mut_IDenseOptFlowEstimator(), frame0.as_raw__InputArray(), frame1.as_raw__InputArray(), flow_x.as_raw__InputOutputArray(), flow_y.as_raw__InputOutputArray(), errors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_IDenseOptFlowEstimator_run_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR(self.as_raw_mut_IDenseOptFlowEstimator(), frame0.as_raw__InputArray(), frame1.as_raw__InputArray(), flow_x.as_raw__InputOutputArray(), flow_y.as_raw__InputOutputArray(), errors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -820,8 +863,9 @@ pub trait IFrameSource: crate::videostab::IFrameSourceConst {
 
 	#[inline]
 	fn reset(&mut self) -> Result<()> {
+		crate::core::validity::check("IFrameSource", self.as_raw_mut_IFrameSource())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_IFrameSource_reset(self.as_raw_mut_IFrameSource(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_IFrameSource_reset(self.as_raw_mut_IFrameSource(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -829,8 +873,9 @@ pub trait IFrameSource: crate::videostab::IFrameSourceConst {
 	
 	#[inline]
 	fn next_frame(&mut self) -> Result<core::Mat> {
+		crate::core::validity::check("IFrameSource", self.as_raw_mut_IFrameSource())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_IFrameSource_nextFrame(self.as_raw_mut_IFrameSource(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_IFrameSource_nextFrame(self.as_raw_mut_IFrameSource(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -849,9 +894,10 @@ pub trait ILog: crate::videostab::ILogConst {
 
 	#[inline]
 	fn print(&mut self, format: &str) -> Result<()> {
+		crate::core::validity::check("ILog", self.as_raw_mut_ILog())?;
 		extern_container_arg!(format);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_ILog_print_const_charX(self.as_raw_mut_ILog(), format.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_ILog_print_const_charX(self.as_raw_mut_ILog(), format.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -867,11 +913,12 @@ pub trait IMotionStabilizerConst {
 pub trait IMotionStabilizer: crate::videostab::IMotionStabilizerConst {
 	fn as_raw_mut_IMotionStabilizer(&mut self) -> *mut c_void;
 
-	/// assumes that [0, size-1) is in or equals to [range.first, range.second)
+	/// assumes that \[0, size-1) is in or equals to \[range.first, range.second)
 	#[inline]
 	fn stabilize(&mut self, size: i32, motions: &core::Vector<core::Mat>, range: &core::Range, stabilization_motions: &mut core::Mat) -> Result<()> {
+		crate::core::validity::check("IMotionStabilizer", self.as_raw_mut_IMotionStabilizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_IMotionStabilizer_stabilize_int_const_vector_Mat_R_const_RangeR_MatX(self.as_raw_mut_IMotionStabilizer(), size, motions.as_raw_VectorOfMat(), range.as_raw_Range(), stabilization_motions.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_IMotionStabilizer_stabilize_int_const_vector_Mat_R_const_RangeR_MatX(self.as_raw_mut_IMotionStabilizer(), size, motions.as_raw_VectorOfMat(), range.as_raw_Range(), stabilization_motions.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -889,11 +936,12 @@ pub trait IOutlierRejector: crate::videostab::IOutlierRejectorConst {
 
 	#[inline]
 	fn process(&mut self, frame_size: core::Size, points0: &dyn core::ToInputArray, points1: &dyn core::ToInputArray, mask: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("IOutlierRejector", self.as_raw_mut_IOutlierRejector())?;
 		input_array_arg!(points0);
 		input_array_arg!(points1);
 		output_array_arg!(mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_IOutlierRejector_process_Size_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_IOutlierRejector(), frame_size.opencv_as_extern(), points0.as_raw__InputArray(), points1.as_raw__InputArray(), mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_IOutlierRejector_process_Size_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_IOutlierRejector(), frame_size.opencv_as_extern(), points0.as_raw__InputArray(), points1.as_raw__InputArray(), mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -911,6 +959,7 @@ pub trait ISparseOptFlowEstimator: crate::videostab::ISparseOptFlowEstimatorCons
 
 	#[inline]
 	fn run(&mut self, frame0: &dyn core::ToInputArray, frame1: &dyn core::ToInputArray, points0: &dyn core::ToInputArray, points1: &mut dyn core::ToInputOutputArray, status: &mut dyn core::ToOutputArray, errors: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("ISparseOptFlowEstimator", self.as_raw_mut_ISparseOptFlowEstimator())?;
 		input_array_arg!(frame0);
 		input_array_arg!(frame1);
 		input_array_arg!(points0);
@@ -918,7 +967,7 @@ pub trait ISparseOptFlowEstimator: crate::videostab::ISparseOptFlowEstimatorCons
 		output_array_arg!(status);
 		output_array_arg!(errors);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_ISparseOptFlowEstimator_run_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_ISparseOptFlowEstimator(), frame0.as_raw__InputArray(), frame1.as_raw__InputArray(), points0.as_raw__InputArray(), points1.as_raw__InputOutputArray(), status.as_raw__OutputArray(), errors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_ISparseOptFlowEstimator_run_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_ISparseOptFlowEstimator(), frame0.as_raw__InputArray(), frame1.as_raw__InputArray(), points0.as_raw__InputArray(), points1.as_raw__InputOutputArray(), status.as_raw__OutputArray(), errors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -932,8 +981,9 @@ pub trait ImageMotionEstimatorBaseConst {
 
 	#[inline]
 	fn motion_model(&self) -> Result<crate::videostab::MotionModel> {
+		crate::core::validity::check("ImageMotionEstimatorBase", self.as_raw_ImageMotionEstimatorBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_ImageMotionEstimatorBase_motionModel_const(self.as_raw_ImageMotionEstimatorBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_ImageMotionEstimatorBase_motionModel_const(self.as_raw_ImageMotionEstimatorBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -946,8 +996,9 @@ pub trait ImageMotionEstimatorBase: crate::videostab::ImageMotionEstimatorBaseCo
 
 	#[inline]
 	fn set_motion_model(&mut self, val: crate::videostab::MotionModel) -> Result<()> {
+		crate::core::validity::check("ImageMotionEstimatorBase", self.as_raw_mut_ImageMotionEstimatorBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_ImageMotionEstimatorBase_setMotionModel_MotionModel(self.as_raw_mut_ImageMotionEstimatorBase(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_ImageMotionEstimatorBase_setMotionModel_MotionModel(self.as_raw_mut_ImageMotionEstimatorBase(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -955,9 +1006,10 @@ pub trait ImageMotionEstimatorBase: crate::videostab::ImageMotionEstimatorBaseCo
 	
 	#[inline]
 	fn set_frame_mask(&mut self, mask: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("ImageMotionEstimatorBase", self.as_raw_mut_ImageMotionEstimatorBase())?;
 		input_array_arg!(mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_ImageMotionEstimatorBase_setFrameMask_const__InputArrayR(self.as_raw_mut_ImageMotionEstimatorBase(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_ImageMotionEstimatorBase_setFrameMask_const__InputArrayR(self.as_raw_mut_ImageMotionEstimatorBase(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -967,8 +1019,9 @@ pub trait ImageMotionEstimatorBase: crate::videostab::ImageMotionEstimatorBaseCo
 	/// * ok: 0
 	#[inline]
 	fn estimate(&mut self, frame0: &core::Mat, frame1: &core::Mat, ok: &mut bool) -> Result<core::Mat> {
+		crate::core::validity::check("ImageMotionEstimatorBase", self.as_raw_mut_ImageMotionEstimatorBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_ImageMotionEstimatorBase_estimate_const_MatR_const_MatR_boolX(self.as_raw_mut_ImageMotionEstimatorBase(), frame0.as_raw_Mat(), frame1.as_raw_Mat(), ok, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_ImageMotionEstimatorBase_estimate_const_MatR_const_MatR_boolX(self.as_raw_mut_ImageMotionEstimatorBase(), frame0.as_raw_Mat(), frame1.as_raw_Mat(), ok, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -982,8 +1035,9 @@ pub trait InpainterBaseConst {
 
 	#[inline]
 	fn radius(&self) -> Result<i32> {
+		crate::core::validity::check("InpainterBase", self.as_raw_InpainterBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_InpainterBase_radius_const(self.as_raw_InpainterBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_InpainterBase_radius_const(self.as_raw_InpainterBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -991,8 +1045,9 @@ pub trait InpainterBaseConst {
 	
 	#[inline]
 	fn motion_model(&self) -> Result<crate::videostab::MotionModel> {
+		crate::core::validity::check("InpainterBase", self.as_raw_InpainterBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_InpainterBase_motionModel_const(self.as_raw_InpainterBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_InpainterBase_motionModel_const(self.as_raw_InpainterBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1000,8 +1055,9 @@ pub trait InpainterBaseConst {
 	
 	#[inline]
 	fn frames(&self) -> Result<core::Vector<core::Mat>> {
+		crate::core::validity::check("InpainterBase", self.as_raw_InpainterBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_InpainterBase_frames_const(self.as_raw_InpainterBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_InpainterBase_frames_const(self.as_raw_InpainterBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<core::Mat>::opencv_from_extern(ret) };
@@ -1010,8 +1066,9 @@ pub trait InpainterBaseConst {
 	
 	#[inline]
 	fn motions(&self) -> Result<core::Vector<core::Mat>> {
+		crate::core::validity::check("InpainterBase", self.as_raw_InpainterBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_InpainterBase_motions_const(self.as_raw_InpainterBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_InpainterBase_motions_const(self.as_raw_InpainterBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<core::Mat>::opencv_from_extern(ret) };
@@ -1020,8 +1077,9 @@ pub trait InpainterBaseConst {
 	
 	#[inline]
 	fn stabilized_frames(&self) -> Result<core::Vector<core::Mat>> {
+		crate::core::validity::check("InpainterBase", self.as_raw_InpainterBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_InpainterBase_stabilizedFrames_const(self.as_raw_InpainterBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_InpainterBase_stabilizedFrames_const(self.as_raw_InpainterBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<core::Mat>::opencv_from_extern(ret) };
@@ -1030,8 +1088,9 @@ pub trait InpainterBaseConst {
 	
 	#[inline]
 	fn stabilization_motions(&self) -> Result<core::Vector<core::Mat>> {
+		crate::core::validity::check("InpainterBase", self.as_raw_InpainterBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_InpainterBase_stabilizationMotions_const(self.as_raw_InpainterBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_InpainterBase_stabilizationMotions_const(self.as_raw_InpainterBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<core::Mat>::opencv_from_extern(ret) };
@@ -1045,8 +1104,9 @@ pub trait InpainterBase: crate::videostab::InpainterBaseConst {
 
 	#[inline]
 	fn set_radius(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("InpainterBase", self.as_raw_mut_InpainterBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_InpainterBase_setRadius_int(self.as_raw_mut_InpainterBase(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_InpainterBase_setRadius_int(self.as_raw_mut_InpainterBase(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1054,8 +1114,9 @@ pub trait InpainterBase: crate::videostab::InpainterBaseConst {
 	
 	#[inline]
 	fn set_motion_model(&mut self, val: crate::videostab::MotionModel) -> Result<()> {
+		crate::core::validity::check("InpainterBase", self.as_raw_mut_InpainterBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_InpainterBase_setMotionModel_MotionModel(self.as_raw_mut_InpainterBase(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_InpainterBase_setMotionModel_MotionModel(self.as_raw_mut_InpainterBase(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1063,8 +1124,9 @@ pub trait InpainterBase: crate::videostab::InpainterBaseConst {
 	
 	#[inline]
 	fn inpaint(&mut self, idx: i32, frame: &mut core::Mat, mask: &mut core::Mat) -> Result<()> {
+		crate::core::validity::check("InpainterBase", self.as_raw_mut_InpainterBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_InpainterBase_inpaint_int_MatR_MatR(self.as_raw_mut_InpainterBase(), idx, frame.as_raw_mut_Mat(), mask.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_InpainterBase_inpaint_int_MatR_MatR(self.as_raw_mut_InpainterBase(), idx, frame.as_raw_mut_Mat(), mask.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1072,8 +1134,9 @@ pub trait InpainterBase: crate::videostab::InpainterBaseConst {
 	
 	#[inline]
 	fn set_frames(&mut self, val: &core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("InpainterBase", self.as_raw_mut_InpainterBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_InpainterBase_setFrames_const_vector_Mat_R(self.as_raw_mut_InpainterBase(), val.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_InpainterBase_setFrames_const_vector_Mat_R(self.as_raw_mut_InpainterBase(), val.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1081,8 +1144,9 @@ pub trait InpainterBase: crate::videostab::InpainterBaseConst {
 	
 	#[inline]
 	fn set_motions(&mut self, val: &core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("InpainterBase", self.as_raw_mut_InpainterBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_InpainterBase_setMotions_const_vector_Mat_R(self.as_raw_mut_InpainterBase(), val.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_InpainterBase_setMotions_const_vector_Mat_R(self.as_raw_mut_InpainterBase(), val.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1090,8 +1154,9 @@ pub trait InpainterBase: crate::videostab::InpainterBaseConst {
 	
 	#[inline]
 	fn set_stabilized_frames(&mut self, val: &core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("InpainterBase", self.as_raw_mut_InpainterBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_InpainterBase_setStabilizedFrames_const_vector_Mat_R(self.as_raw_mut_InpainterBase(), val.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_InpainterBase_setStabilizedFrames_const_vector_Mat_R(self.as_raw_mut_InpainterBase(), val.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1099,8 +1164,9 @@ pub trait InpainterBase: crate::videostab::InpainterBaseConst {
 	
 	#[inline]
 	fn set_stabilization_motions(&mut self, val: &core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("InpainterBase", self.as_raw_mut_InpainterBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_InpainterBase_setStabilizationMotions_const_vector_Mat_R(self.as_raw_mut_InpainterBase(), val.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_InpainterBase_setStabilizationMotions_const_vector_Mat_R(self.as_raw_mut_InpainterBase(), val.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1113,8 +1179,9 @@ pub trait InpaintingPipelineTraitConst: crate::videostab::InpainterBaseConst {
 
 	#[inline]
 	fn empty(&self) -> Result<bool> {
+		crate::core::validity::check("InpaintingPipeline", self.as_raw_InpaintingPipeline())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_InpaintingPipeline_empty_const(self.as_raw_InpaintingPipeline(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_InpaintingPipeline_empty_const(self.as_raw_InpaintingPipeline(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1127,8 +1194,9 @@ pub trait InpaintingPipelineTrait: crate::videostab::InpainterBase + crate::vide
 
 	#[inline]
 	fn push_back(&mut self, mut inpainter: core::Ptr<dyn crate::videostab::InpainterBase>) -> Result<()> {
+		crate::core::validity::check("InpaintingPipeline", self.as_raw_mut_InpaintingPipeline())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_InpaintingPipeline_pushBack_Ptr_InpainterBase_(self.as_raw_mut_InpaintingPipeline(), inpainter.as_raw_mut_PtrOfInpainterBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_InpaintingPipeline_pushBack_Ptr_InpainterBase_(self.as_raw_mut_InpaintingPipeline(), inpainter.as_raw_mut_PtrOfInpainterBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1136,8 +1204,9 @@ pub trait InpaintingPipelineTrait: crate::videostab::InpainterBase + crate::vide
 	
 	#[inline]
 	fn set_radius(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("InpaintingPipeline", self.as_raw_mut_InpaintingPipeline())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_InpaintingPipeline_setRadius_int(self.as_raw_mut_InpaintingPipeline(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_InpaintingPipeline_setRadius_int(self.as_raw_mut_InpaintingPipeline(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1145,8 +1214,9 @@ pub trait InpaintingPipelineTrait: crate::videostab::InpainterBase + crate::vide
 	
 	#[inline]
 	fn set_motion_model(&mut self, val: crate::videostab::MotionModel) -> Result<()> {
+		crate::core::validity::check("InpaintingPipeline", self.as_raw_mut_InpaintingPipeline())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_InpaintingPipeline_setMotionModel_MotionModel(self.as_raw_mut_InpaintingPipeline(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_InpaintingPipeline_setMotionModel_MotionModel(self.as_raw_mut_InpaintingPipeline(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1154,8 +1224,9 @@ pub trait InpaintingPipelineTrait: crate::videostab::InpainterBase + crate::vide
 	
 	#[inline]
 	fn set_frames(&mut self, val: &core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("InpaintingPipeline", self.as_raw_mut_InpaintingPipeline())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_InpaintingPipeline_setFrames_const_vector_Mat_R(self.as_raw_mut_InpaintingPipeline(), val.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_InpaintingPipeline_setFrames_const_vector_Mat_R(self.as_raw_mut_InpaintingPipeline(), val.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1163,8 +1234,9 @@ pub trait InpaintingPipelineTrait: crate::videostab::InpainterBase + crate::vide
 	
 	#[inline]
 	fn set_motions(&mut self, val: &core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("InpaintingPipeline", self.as_raw_mut_InpaintingPipeline())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_InpaintingPipeline_setMotions_const_vector_Mat_R(self.as_raw_mut_InpaintingPipeline(), val.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_InpaintingPipeline_setMotions_const_vector_Mat_R(self.as_raw_mut_InpaintingPipeline(), val.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1172,8 +1244,9 @@ pub trait InpaintingPipelineTrait: crate::videostab::InpainterBase + crate::vide
 	
 	#[inline]
 	fn set_stabilized_frames(&mut self, val: &core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("InpaintingPipeline", self.as_raw_mut_InpaintingPipeline())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_InpaintingPipeline_setStabilizedFrames_const_vector_Mat_R(self.as_raw_mut_InpaintingPipeline(), val.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_InpaintingPipeline_setStabilizedFrames_const_vector_Mat_R(self.as_raw_mut_InpaintingPipeline(), val.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1181,8 +1254,9 @@ pub trait InpaintingPipelineTrait: crate::videostab::InpainterBase + crate::vide
 	
 	#[inline]
 	fn set_stabilization_motions(&mut self, val: &core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("InpaintingPipeline", self.as_raw_mut_InpaintingPipeline())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_InpaintingPipeline_setStabilizationMotions_const_vector_Mat_R(self.as_raw_mut_InpaintingPipeline(), val.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_InpaintingPipeline_setStabilizationMotions_const_vector_Mat_R(self.as_raw_mut_InpaintingPipeline(), val.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1190,8 +1264,9 @@ pub trait InpaintingPipelineTrait: crate::videostab::InpainterBase + crate::vide
 	
 	#[inline]
 	fn inpaint(&mut self, idx: i32, frame: &mut core::Mat, mask: &mut core::Mat) -> Result<()> {
+		crate::core::validity::check("InpaintingPipeline", self.as_raw_mut_InpaintingPipeline())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_InpaintingPipeline_inpaint_int_MatR_MatR(self.as_raw_mut_InpaintingPipeline(), idx, frame.as_raw_mut_Mat(), mask.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_InpaintingPipeline_inpaint_int_MatR_MatR(self.as_raw_mut_InpaintingPipeline(), idx, frame.as_raw_mut_Mat(), mask.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1208,7 +1283,10 @@ opencv_type_boxed! { InpaintingPipeline }
 impl Drop for InpaintingPipeline {
 	fn drop(&mut self) {
 		extern "C" { fn cv_InpaintingPipeline_delete(instance: *mut c_void); }
-		unsafe { cv_InpaintingPipeline_delete(self.as_raw_mut_InpaintingPipeline()) };
+		let ptr = self.as_raw_mut_InpaintingPipeline();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_InpaintingPipeline_delete(ptr) };
 	}
 }
 
@@ -1240,8 +1318,9 @@ pub trait KeypointBasedMotionEstimatorTraitConst: crate::videostab::ImageMotionE
 
 	#[inline]
 	fn motion_model(&self) -> Result<crate::videostab::MotionModel> {
+		crate::core::validity::check("KeypointBasedMotionEstimator", self.as_raw_KeypointBasedMotionEstimator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_KeypointBasedMotionEstimator_motionModel_const(self.as_raw_KeypointBasedMotionEstimator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_KeypointBasedMotionEstimator_motionModel_const(self.as_raw_KeypointBasedMotionEstimator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1249,8 +1328,9 @@ pub trait KeypointBasedMotionEstimatorTraitConst: crate::videostab::ImageMotionE
 	
 	#[inline]
 	fn detector(&self) -> Result<core::Ptr<crate::features2d::Feature2D>> {
+		crate::core::validity::check("KeypointBasedMotionEstimator", self.as_raw_KeypointBasedMotionEstimator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_KeypointBasedMotionEstimator_detector_const(self.as_raw_KeypointBasedMotionEstimator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_KeypointBasedMotionEstimator_detector_const(self.as_raw_KeypointBasedMotionEstimator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::features2d::Feature2D>::opencv_from_extern(ret) };
@@ -1259,8 +1339,9 @@ pub trait KeypointBasedMotionEstimatorTraitConst: crate::videostab::ImageMotionE
 	
 	#[inline]
 	fn optical_flow_estimator(&self) -> Result<core::Ptr<dyn crate::videostab::ISparseOptFlowEstimator>> {
+		crate::core::validity::check("KeypointBasedMotionEstimator", self.as_raw_KeypointBasedMotionEstimator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_KeypointBasedMotionEstimator_opticalFlowEstimator_const(self.as_raw_KeypointBasedMotionEstimator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_KeypointBasedMotionEstimator_opticalFlowEstimator_const(self.as_raw_KeypointBasedMotionEstimator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::videostab::ISparseOptFlowEstimator>::opencv_from_extern(ret) };
@@ -1269,8 +1350,9 @@ pub trait KeypointBasedMotionEstimatorTraitConst: crate::videostab::ImageMotionE
 	
 	#[inline]
 	fn outlier_rejector(&self) -> Result<core::Ptr<dyn crate::videostab::IOutlierRejector>> {
+		crate::core::validity::check("KeypointBasedMotionEstimator", self.as_raw_KeypointBasedMotionEstimator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_KeypointBasedMotionEstimator_outlierRejector_const(self.as_raw_KeypointBasedMotionEstimator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_KeypointBasedMotionEstimator_outlierRejector_const(self.as_raw_KeypointBasedMotionEstimator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::videostab::IOutlierRejector>::opencv_from_extern(ret) };
@@ -1284,8 +1366,9 @@ pub trait KeypointBasedMotionEstimatorTrait: crate::videostab::ImageMotionEstima
 
 	#[inline]
 	fn set_motion_model(&mut self, val: crate::videostab::MotionModel) -> Result<()> {
+		crate::core::validity::check("KeypointBasedMotionEstimator", self.as_raw_mut_KeypointBasedMotionEstimator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_KeypointBasedMotionEstimator_setMotionModel_MotionModel(self.as_raw_mut_KeypointBasedMotionEstimator(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_KeypointBasedMotionEstimator_setMotionModel_MotionModel(self.as_raw_mut_KeypointBasedMotionEstimator(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1293,8 +1376,9 @@ pub trait KeypointBasedMotionEstimatorTrait: crate::videostab::ImageMotionEstima
 	
 	#[inline]
 	fn set_detector(&mut self, mut val: core::Ptr<crate::features2d::Feature2D>) -> Result<()> {
+		crate::core::validity::check("KeypointBasedMotionEstimator", self.as_raw_mut_KeypointBasedMotionEstimator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_KeypointBasedMotionEstimator_setDetector_Ptr_Feature2D_(self.as_raw_mut_KeypointBasedMotionEstimator(), val.as_raw_mut_PtrOfFeature2D(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_KeypointBasedMotionEstimator_setDetector_Ptr_Feature2D_(self.as_raw_mut_KeypointBasedMotionEstimator(), val.as_raw_mut_PtrOfFeature2D(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1302,8 +1386,9 @@ pub trait KeypointBasedMotionEstimatorTrait: crate::videostab::ImageMotionEstima
 	
 	#[inline]
 	fn set_optical_flow_estimator(&mut self, mut val: core::Ptr<dyn crate::videostab::ISparseOptFlowEstimator>) -> Result<()> {
+		crate::core::validity::check("KeypointBasedMotionEstimator", self.as_raw_mut_KeypointBasedMotionEstimator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_KeypointBasedMotionEstimator_setOpticalFlowEstimator_Ptr_ISparseOptFlowEstimator_(self.as_raw_mut_KeypointBasedMotionEstimator(), val.as_raw_mut_PtrOfISparseOptFlowEstimator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_KeypointBasedMotionEstimator_setOpticalFlowEstimator_Ptr_ISparseOptFlowEstimator_(self.as_raw_mut_KeypointBasedMotionEstimator(), val.as_raw_mut_PtrOfISparseOptFlowEstimator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1311,8 +1396,9 @@ pub trait KeypointBasedMotionEstimatorTrait: crate::videostab::ImageMotionEstima
 	
 	#[inline]
 	fn set_outlier_rejector(&mut self, mut val: core::Ptr<dyn crate::videostab::IOutlierRejector>) -> Result<()> {
+		crate::core::validity::check("KeypointBasedMotionEstimator", self.as_raw_mut_KeypointBasedMotionEstimator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_KeypointBasedMotionEstimator_setOutlierRejector_Ptr_IOutlierRejector_(self.as_raw_mut_KeypointBasedMotionEstimator(), val.as_raw_mut_PtrOfIOutlierRejector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_KeypointBasedMotionEstimator_setOutlierRejector_Ptr_IOutlierRejector_(self.as_raw_mut_KeypointBasedMotionEstimator(), val.as_raw_mut_PtrOfIOutlierRejector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1320,9 +1406,10 @@ pub trait KeypointBasedMotionEstimatorTrait: crate::videostab::ImageMotionEstima
 	
 	#[inline]
 	fn set_frame_mask(&mut self, mask: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("KeypointBasedMotionEstimator", self.as_raw_mut_KeypointBasedMotionEstimator())?;
 		input_array_arg!(mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_KeypointBasedMotionEstimator_setFrameMask_const__InputArrayR(self.as_raw_mut_KeypointBasedMotionEstimator(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_KeypointBasedMotionEstimator_setFrameMask_const__InputArrayR(self.as_raw_mut_KeypointBasedMotionEstimator(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1332,8 +1419,9 @@ pub trait KeypointBasedMotionEstimatorTrait: crate::videostab::ImageMotionEstima
 	/// * ok: 0
 	#[inline]
 	fn estimate_mat(&mut self, frame0: &core::Mat, frame1: &core::Mat, ok: &mut bool) -> Result<core::Mat> {
+		crate::core::validity::check("KeypointBasedMotionEstimator", self.as_raw_mut_KeypointBasedMotionEstimator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_KeypointBasedMotionEstimator_estimate_const_MatR_const_MatR_boolX(self.as_raw_mut_KeypointBasedMotionEstimator(), frame0.as_raw_Mat(), frame1.as_raw_Mat(), ok, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_KeypointBasedMotionEstimator_estimate_const_MatR_const_MatR_boolX(self.as_raw_mut_KeypointBasedMotionEstimator(), frame0.as_raw_Mat(), frame1.as_raw_Mat(), ok, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -1344,10 +1432,11 @@ pub trait KeypointBasedMotionEstimatorTrait: crate::videostab::ImageMotionEstima
 	/// * ok: 0
 	#[inline]
 	fn estimate(&mut self, frame0: &dyn core::ToInputArray, frame1: &dyn core::ToInputArray, ok: &mut bool) -> Result<core::Mat> {
+		crate::core::validity::check("KeypointBasedMotionEstimator", self.as_raw_mut_KeypointBasedMotionEstimator())?;
 		input_array_arg!(frame0);
 		input_array_arg!(frame1);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_KeypointBasedMotionEstimator_estimate_const__InputArrayR_const__InputArrayR_boolX(self.as_raw_mut_KeypointBasedMotionEstimator(), frame0.as_raw__InputArray(), frame1.as_raw__InputArray(), ok, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_KeypointBasedMotionEstimator_estimate_const__InputArrayR_const__InputArrayR_boolX(self.as_raw_mut_KeypointBasedMotionEstimator(), frame0.as_raw__InputArray(), frame1.as_raw__InputArray(), ok, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -1367,7 +1456,10 @@ opencv_type_boxed! { KeypointBasedMotionEstimator }
 impl Drop for KeypointBasedMotionEstimator {
 	fn drop(&mut self) {
 		extern "C" { fn cv_KeypointBasedMotionEstimator_delete(instance: *mut c_void); }
-		unsafe { cv_KeypointBasedMotionEstimator_delete(self.as_raw_mut_KeypointBasedMotionEstimator()) };
+		let ptr = self.as_raw_mut_KeypointBasedMotionEstimator();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_KeypointBasedMotionEstimator_delete(ptr) };
 	}
 }
 
@@ -1393,7 +1485,7 @@ impl KeypointBasedMotionEstimator {
 	#[inline]
 	pub fn new(mut estimator: core::Ptr<dyn crate::videostab::MotionEstimatorBase>) -> Result<crate::videostab::KeypointBasedMotionEstimator> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_KeypointBasedMotionEstimator_KeypointBasedMotionEstimator_Ptr_MotionEstimatorBase_(estimator.as_raw_mut_PtrOfMotionEstimatorBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_KeypointBasedMotionEstimator_KeypointBasedMotionEstimator_Ptr_MotionEstimatorBase_(estimator.as_raw_mut_PtrOfMotionEstimatorBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videostab::KeypointBasedMotionEstimator::opencv_from_extern(ret) };
@@ -1407,8 +1499,9 @@ pub trait KeypointBasedMotionEstimatorGpuTraitConst: crate::videostab::ImageMoti
 
 	#[inline]
 	fn motion_model(&self) -> Result<crate::videostab::MotionModel> {
+		crate::core::validity::check("KeypointBasedMotionEstimatorGpu", self.as_raw_KeypointBasedMotionEstimatorGpu())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_KeypointBasedMotionEstimatorGpu_motionModel_const(self.as_raw_KeypointBasedMotionEstimatorGpu(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_KeypointBasedMotionEstimatorGpu_motionModel_const(self.as_raw_KeypointBasedMotionEstimatorGpu(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1416,8 +1509,9 @@ pub trait KeypointBasedMotionEstimatorGpuTraitConst: crate::videostab::ImageMoti
 	
 	#[inline]
 	fn outlier_rejector(&self) -> Result<core::Ptr<dyn crate::videostab::IOutlierRejector>> {
+		crate::core::validity::check("KeypointBasedMotionEstimatorGpu", self.as_raw_KeypointBasedMotionEstimatorGpu())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_KeypointBasedMotionEstimatorGpu_outlierRejector_const(self.as_raw_KeypointBasedMotionEstimatorGpu(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_KeypointBasedMotionEstimatorGpu_outlierRejector_const(self.as_raw_KeypointBasedMotionEstimatorGpu(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::videostab::IOutlierRejector>::opencv_from_extern(ret) };
@@ -1431,8 +1525,9 @@ pub trait KeypointBasedMotionEstimatorGpuTrait: crate::videostab::ImageMotionEst
 
 	#[inline]
 	fn set_motion_model(&mut self, val: crate::videostab::MotionModel) -> Result<()> {
+		crate::core::validity::check("KeypointBasedMotionEstimatorGpu", self.as_raw_mut_KeypointBasedMotionEstimatorGpu())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_KeypointBasedMotionEstimatorGpu_setMotionModel_MotionModel(self.as_raw_mut_KeypointBasedMotionEstimatorGpu(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_KeypointBasedMotionEstimatorGpu_setMotionModel_MotionModel(self.as_raw_mut_KeypointBasedMotionEstimatorGpu(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1440,8 +1535,9 @@ pub trait KeypointBasedMotionEstimatorGpuTrait: crate::videostab::ImageMotionEst
 	
 	#[inline]
 	fn set_outlier_rejector(&mut self, mut val: core::Ptr<dyn crate::videostab::IOutlierRejector>) -> Result<()> {
+		crate::core::validity::check("KeypointBasedMotionEstimatorGpu", self.as_raw_mut_KeypointBasedMotionEstimatorGpu())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_KeypointBasedMotionEstimatorGpu_setOutlierRejector_Ptr_IOutlierRejector_(self.as_raw_mut_KeypointBasedMotionEstimatorGpu(), val.as_raw_mut_PtrOfIOutlierRejector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_KeypointBasedMotionEstimatorGpu_setOutlierRejector_Ptr_IOutlierRejector_(self.as_raw_mut_KeypointBasedMotionEstimatorGpu(), val.as_raw_mut_PtrOfIOutlierRejector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1451,8 +1547,9 @@ pub trait KeypointBasedMotionEstimatorGpuTrait: crate::videostab::ImageMotionEst
 	/// * ok: 0
 	#[inline]
 	fn estimate(&mut self, frame0: &core::Mat, frame1: &core::Mat, ok: &mut bool) -> Result<core::Mat> {
+		crate::core::validity::check("KeypointBasedMotionEstimatorGpu", self.as_raw_mut_KeypointBasedMotionEstimatorGpu())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_KeypointBasedMotionEstimatorGpu_estimate_const_MatR_const_MatR_boolX(self.as_raw_mut_KeypointBasedMotionEstimatorGpu(), frame0.as_raw_Mat(), frame1.as_raw_Mat(), ok, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_KeypointBasedMotionEstimatorGpu_estimate_const_MatR_const_MatR_boolX(self.as_raw_mut_KeypointBasedMotionEstimatorGpu(), frame0.as_raw_Mat(), frame1.as_raw_Mat(), ok, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -1463,8 +1560,9 @@ pub trait KeypointBasedMotionEstimatorGpuTrait: crate::videostab::ImageMotionEst
 	/// * ok: 0
 	#[inline]
 	fn estimate_1(&mut self, frame0: &core::GpuMat, frame1: &core::GpuMat, ok: &mut bool) -> Result<core::Mat> {
+		crate::core::validity::check("KeypointBasedMotionEstimatorGpu", self.as_raw_mut_KeypointBasedMotionEstimatorGpu())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_KeypointBasedMotionEstimatorGpu_estimate_const_GpuMatR_const_GpuMatR_boolX(self.as_raw_mut_KeypointBasedMotionEstimatorGpu(), frame0.as_raw_GpuMat(), frame1.as_raw_GpuMat(), ok, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_KeypointBasedMotionEstimatorGpu_estimate_const_GpuMatR_const_GpuMatR_boolX(self.as_raw_mut_KeypointBasedMotionEstimatorGpu(), frame0.as_raw_GpuMat(), frame1.as_raw_GpuMat(), ok, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -1482,7 +1580,10 @@ opencv_type_boxed! { KeypointBasedMotionEstimatorGpu }
 impl Drop for KeypointBasedMotionEstimatorGpu {
 	fn drop(&mut self) {
 		extern "C" { fn cv_KeypointBasedMotionEstimatorGpu_delete(instance: *mut c_void); }
-		unsafe { cv_KeypointBasedMotionEstimatorGpu_delete(self.as_raw_mut_KeypointBasedMotionEstimatorGpu()) };
+		let ptr = self.as_raw_mut_KeypointBasedMotionEstimatorGpu();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_KeypointBasedMotionEstimatorGpu_delete(ptr) };
 	}
 }
 
@@ -1508,7 +1609,7 @@ impl KeypointBasedMotionEstimatorGpu {
 	#[inline]
 	pub fn new(mut estimator: core::Ptr<dyn crate::videostab::MotionEstimatorBase>) -> Result<crate::videostab::KeypointBasedMotionEstimatorGpu> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_KeypointBasedMotionEstimatorGpu_KeypointBasedMotionEstimatorGpu_Ptr_MotionEstimatorBase_(estimator.as_raw_mut_PtrOfMotionEstimatorBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_KeypointBasedMotionEstimatorGpu_KeypointBasedMotionEstimatorGpu_Ptr_MotionEstimatorBase_(estimator.as_raw_mut_PtrOfMotionEstimatorBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videostab::KeypointBasedMotionEstimatorGpu::opencv_from_extern(ret) };
@@ -1527,9 +1628,10 @@ pub trait LogToStdoutTrait: crate::videostab::ILog + crate::videostab::LogToStdo
 
 	#[inline]
 	fn print(&mut self, format: &str) -> Result<()> {
+		crate::core::validity::check("LogToStdout", self.as_raw_mut_LogToStdout())?;
 		extern_container_arg!(format);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_LogToStdout_print_const_charX(self.as_raw_mut_LogToStdout(), format.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_LogToStdout_print_const_charX(self.as_raw_mut_LogToStdout(), format.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1546,7 +1648,10 @@ opencv_type_boxed! { LogToStdout }
 impl Drop for LogToStdout {
 	fn drop(&mut self) {
 		extern "C" { fn cv_LogToStdout_delete(instance: *mut c_void); }
-		unsafe { cv_LogToStdout_delete(self.as_raw_mut_LogToStdout()) };
+		let ptr = self.as_raw_mut_LogToStdout();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_LogToStdout_delete(ptr) };
 	}
 }
 
@@ -1576,8 +1681,9 @@ pub trait LpMotionStabilizerTraitConst: crate::videostab::IMotionStabilizerConst
 
 	#[inline]
 	fn motion_model(&self) -> Result<crate::videostab::MotionModel> {
+		crate::core::validity::check("LpMotionStabilizer", self.as_raw_LpMotionStabilizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_LpMotionStabilizer_motionModel_const(self.as_raw_LpMotionStabilizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_LpMotionStabilizer_motionModel_const(self.as_raw_LpMotionStabilizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1585,8 +1691,9 @@ pub trait LpMotionStabilizerTraitConst: crate::videostab::IMotionStabilizerConst
 	
 	#[inline]
 	fn frame_size(&self) -> Result<core::Size> {
+		crate::core::validity::check("LpMotionStabilizer", self.as_raw_LpMotionStabilizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_LpMotionStabilizer_frameSize_const(self.as_raw_LpMotionStabilizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_LpMotionStabilizer_frameSize_const(self.as_raw_LpMotionStabilizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1594,8 +1701,9 @@ pub trait LpMotionStabilizerTraitConst: crate::videostab::IMotionStabilizerConst
 	
 	#[inline]
 	fn trim_ratio(&self) -> Result<f32> {
+		crate::core::validity::check("LpMotionStabilizer", self.as_raw_LpMotionStabilizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_LpMotionStabilizer_trimRatio_const(self.as_raw_LpMotionStabilizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_LpMotionStabilizer_trimRatio_const(self.as_raw_LpMotionStabilizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1603,8 +1711,9 @@ pub trait LpMotionStabilizerTraitConst: crate::videostab::IMotionStabilizerConst
 	
 	#[inline]
 	fn weight1(&self) -> Result<f32> {
+		crate::core::validity::check("LpMotionStabilizer", self.as_raw_LpMotionStabilizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_LpMotionStabilizer_weight1_const(self.as_raw_LpMotionStabilizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_LpMotionStabilizer_weight1_const(self.as_raw_LpMotionStabilizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1612,8 +1721,9 @@ pub trait LpMotionStabilizerTraitConst: crate::videostab::IMotionStabilizerConst
 	
 	#[inline]
 	fn weight2(&self) -> Result<f32> {
+		crate::core::validity::check("LpMotionStabilizer", self.as_raw_LpMotionStabilizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_LpMotionStabilizer_weight2_const(self.as_raw_LpMotionStabilizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_LpMotionStabilizer_weight2_const(self.as_raw_LpMotionStabilizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1621,8 +1731,9 @@ pub trait LpMotionStabilizerTraitConst: crate::videostab::IMotionStabilizerConst
 	
 	#[inline]
 	fn weight3(&self) -> Result<f32> {
+		crate::core::validity::check("LpMotionStabilizer", self.as_raw_LpMotionStabilizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_LpMotionStabilizer_weight3_const(self.as_raw_LpMotionStabilizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_LpMotionStabilizer_weight3_const(self.as_raw_LpMotionStabilizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1630,8 +1741,9 @@ pub trait LpMotionStabilizerTraitConst: crate::videostab::IMotionStabilizerConst
 	
 	#[inline]
 	fn weight4(&self) -> Result<f32> {
+		crate::core::validity::check("LpMotionStabilizer", self.as_raw_LpMotionStabilizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_LpMotionStabilizer_weight4_const(self.as_raw_LpMotionStabilizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_LpMotionStabilizer_weight4_const(self.as_raw_LpMotionStabilizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1644,8 +1756,9 @@ pub trait LpMotionStabilizerTrait: crate::videostab::IMotionStabilizer + crate::
 
 	#[inline]
 	fn set_motion_model(&mut self, val: crate::videostab::MotionModel) -> Result<()> {
+		crate::core::validity::check("LpMotionStabilizer", self.as_raw_mut_LpMotionStabilizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_LpMotionStabilizer_setMotionModel_MotionModel(self.as_raw_mut_LpMotionStabilizer(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_LpMotionStabilizer_setMotionModel_MotionModel(self.as_raw_mut_LpMotionStabilizer(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1653,8 +1766,9 @@ pub trait LpMotionStabilizerTrait: crate::videostab::IMotionStabilizer + crate::
 	
 	#[inline]
 	fn set_frame_size(&mut self, val: core::Size) -> Result<()> {
+		crate::core::validity::check("LpMotionStabilizer", self.as_raw_mut_LpMotionStabilizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_LpMotionStabilizer_setFrameSize_Size(self.as_raw_mut_LpMotionStabilizer(), val.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_LpMotionStabilizer_setFrameSize_Size(self.as_raw_mut_LpMotionStabilizer(), val.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1662,8 +1776,9 @@ pub trait LpMotionStabilizerTrait: crate::videostab::IMotionStabilizer + crate::
 	
 	#[inline]
 	fn set_trim_ratio(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("LpMotionStabilizer", self.as_raw_mut_LpMotionStabilizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_LpMotionStabilizer_setTrimRatio_float(self.as_raw_mut_LpMotionStabilizer(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_LpMotionStabilizer_setTrimRatio_float(self.as_raw_mut_LpMotionStabilizer(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1671,8 +1786,9 @@ pub trait LpMotionStabilizerTrait: crate::videostab::IMotionStabilizer + crate::
 	
 	#[inline]
 	fn set_weight1(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("LpMotionStabilizer", self.as_raw_mut_LpMotionStabilizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_LpMotionStabilizer_setWeight1_float(self.as_raw_mut_LpMotionStabilizer(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_LpMotionStabilizer_setWeight1_float(self.as_raw_mut_LpMotionStabilizer(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1680,8 +1796,9 @@ pub trait LpMotionStabilizerTrait: crate::videostab::IMotionStabilizer + crate::
 	
 	#[inline]
 	fn set_weight2(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("LpMotionStabilizer", self.as_raw_mut_LpMotionStabilizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_LpMotionStabilizer_setWeight2_float(self.as_raw_mut_LpMotionStabilizer(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_LpMotionStabilizer_setWeight2_float(self.as_raw_mut_LpMotionStabilizer(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1689,8 +1806,9 @@ pub trait LpMotionStabilizerTrait: crate::videostab::IMotionStabilizer + crate::
 	
 	#[inline]
 	fn set_weight3(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("LpMotionStabilizer", self.as_raw_mut_LpMotionStabilizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_LpMotionStabilizer_setWeight3_float(self.as_raw_mut_LpMotionStabilizer(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_LpMotionStabilizer_setWeight3_float(self.as_raw_mut_LpMotionStabilizer(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1698,8 +1816,9 @@ pub trait LpMotionStabilizerTrait: crate::videostab::IMotionStabilizer + crate::
 	
 	#[inline]
 	fn set_weight4(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("LpMotionStabilizer", self.as_raw_mut_LpMotionStabilizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_LpMotionStabilizer_setWeight4_float(self.as_raw_mut_LpMotionStabilizer(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_LpMotionStabilizer_setWeight4_float(self.as_raw_mut_LpMotionStabilizer(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1707,8 +1826,9 @@ pub trait LpMotionStabilizerTrait: crate::videostab::IMotionStabilizer + crate::
 	
 	#[inline]
 	fn stabilize(&mut self, size: i32, motions: &core::Vector<core::Mat>, range: &core::Range, stabilization_motions: &mut core::Mat) -> Result<()> {
+		crate::core::validity::check("LpMotionStabilizer", self.as_raw_mut_LpMotionStabilizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_LpMotionStabilizer_stabilize_int_const_vector_Mat_R_const_RangeR_MatX(self.as_raw_mut_LpMotionStabilizer(), size, motions.as_raw_VectorOfMat(), range.as_raw_Range(), stabilization_motions.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_LpMotionStabilizer_stabilize_int_const_vector_Mat_R_const_RangeR_MatX(self.as_raw_mut_LpMotionStabilizer(), size, motions.as_raw_VectorOfMat(), range.as_raw_Range(), stabilization_motions.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1725,7 +1845,10 @@ opencv_type_boxed! { LpMotionStabilizer }
 impl Drop for LpMotionStabilizer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_LpMotionStabilizer_delete(instance: *mut c_void); }
-		unsafe { cv_LpMotionStabilizer_delete(self.as_raw_mut_LpMotionStabilizer()) };
+		let ptr = self.as_raw_mut_LpMotionStabilizer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_LpMotionStabilizer_delete(ptr) };
 	}
 }
 
@@ -1753,7 +1876,7 @@ impl LpMotionStabilizer {
 	#[inline]
 	pub fn new(model: crate::videostab::MotionModel) -> Result<crate::videostab::LpMotionStabilizer> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_LpMotionStabilizer_LpMotionStabilizer_MotionModel(model, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_LpMotionStabilizer_LpMotionStabilizer_MotionModel(model, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videostab::LpMotionStabilizer::opencv_from_extern(ret) };
@@ -1772,8 +1895,9 @@ pub trait MaskFrameSourceTrait: crate::videostab::IFrameSource + crate::videosta
 
 	#[inline]
 	fn reset(&mut self) -> Result<()> {
+		crate::core::validity::check("MaskFrameSource", self.as_raw_mut_MaskFrameSource())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MaskFrameSource_reset(self.as_raw_mut_MaskFrameSource(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MaskFrameSource_reset(self.as_raw_mut_MaskFrameSource(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1781,8 +1905,9 @@ pub trait MaskFrameSourceTrait: crate::videostab::IFrameSource + crate::videosta
 	
 	#[inline]
 	fn next_frame(&mut self) -> Result<core::Mat> {
+		crate::core::validity::check("MaskFrameSource", self.as_raw_mut_MaskFrameSource())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MaskFrameSource_nextFrame(self.as_raw_mut_MaskFrameSource(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MaskFrameSource_nextFrame(self.as_raw_mut_MaskFrameSource(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -1800,7 +1925,10 @@ opencv_type_boxed! { MaskFrameSource }
 impl Drop for MaskFrameSource {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MaskFrameSource_delete(instance: *mut c_void); }
-		unsafe { cv_MaskFrameSource_delete(self.as_raw_mut_MaskFrameSource()) };
+		let ptr = self.as_raw_mut_MaskFrameSource();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_MaskFrameSource_delete(ptr) };
 	}
 }
 
@@ -1826,7 +1954,7 @@ impl MaskFrameSource {
 	#[inline]
 	pub fn new(source: &core::Ptr<dyn crate::videostab::IFrameSource>) -> Result<crate::videostab::MaskFrameSource> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MaskFrameSource_MaskFrameSource_const_Ptr_IFrameSource_R(source.as_raw_PtrOfIFrameSource(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MaskFrameSource_MaskFrameSource_const_Ptr_IFrameSource_R(source.as_raw_PtrOfIFrameSource(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videostab::MaskFrameSource::opencv_from_extern(ret) };
@@ -1845,8 +1973,9 @@ pub trait MoreAccurateMotionWobbleSuppressorTrait: crate::videostab::MoreAccurat
 
 	#[inline]
 	fn suppress(&mut self, idx: i32, frame: &core::Mat, result: &mut core::Mat) -> Result<()> {
+		crate::core::validity::check("MoreAccurateMotionWobbleSuppressor", self.as_raw_mut_MoreAccurateMotionWobbleSuppressor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MoreAccurateMotionWobbleSuppressor_suppress_int_const_MatR_MatR(self.as_raw_mut_MoreAccurateMotionWobbleSuppressor(), idx, frame.as_raw_Mat(), result.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MoreAccurateMotionWobbleSuppressor_suppress_int_const_MatR_MatR(self.as_raw_mut_MoreAccurateMotionWobbleSuppressor(), idx, frame.as_raw_Mat(), result.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1863,7 +1992,10 @@ opencv_type_boxed! { MoreAccurateMotionWobbleSuppressor }
 impl Drop for MoreAccurateMotionWobbleSuppressor {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MoreAccurateMotionWobbleSuppressor_delete(instance: *mut c_void); }
-		unsafe { cv_MoreAccurateMotionWobbleSuppressor_delete(self.as_raw_mut_MoreAccurateMotionWobbleSuppressor()) };
+		let ptr = self.as_raw_mut_MoreAccurateMotionWobbleSuppressor();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_MoreAccurateMotionWobbleSuppressor_delete(ptr) };
 	}
 }
 
@@ -1901,8 +2033,9 @@ pub trait MoreAccurateMotionWobbleSuppressorBaseConst: crate::videostab::WobbleS
 
 	#[inline]
 	fn period(&self) -> Result<i32> {
+		crate::core::validity::check("MoreAccurateMotionWobbleSuppressorBase", self.as_raw_MoreAccurateMotionWobbleSuppressorBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MoreAccurateMotionWobbleSuppressorBase_period_const(self.as_raw_MoreAccurateMotionWobbleSuppressorBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MoreAccurateMotionWobbleSuppressorBase_period_const(self.as_raw_MoreAccurateMotionWobbleSuppressorBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1915,8 +2048,9 @@ pub trait MoreAccurateMotionWobbleSuppressorBase: crate::videostab::MoreAccurate
 
 	#[inline]
 	fn set_period(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("MoreAccurateMotionWobbleSuppressorBase", self.as_raw_mut_MoreAccurateMotionWobbleSuppressorBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MoreAccurateMotionWobbleSuppressorBase_setPeriod_int(self.as_raw_mut_MoreAccurateMotionWobbleSuppressorBase(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MoreAccurateMotionWobbleSuppressorBase_setPeriod_int(self.as_raw_mut_MoreAccurateMotionWobbleSuppressorBase(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1934,8 +2068,9 @@ pub trait MoreAccurateMotionWobbleSuppressorGpuTrait: crate::videostab::MoreAccu
 
 	#[inline]
 	fn suppress(&mut self, idx: i32, frame: &core::GpuMat, result: &mut core::GpuMat) -> Result<()> {
+		crate::core::validity::check("MoreAccurateMotionWobbleSuppressorGpu", self.as_raw_mut_MoreAccurateMotionWobbleSuppressorGpu())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MoreAccurateMotionWobbleSuppressorGpu_suppress_int_const_GpuMatR_GpuMatR(self.as_raw_mut_MoreAccurateMotionWobbleSuppressorGpu(), idx, frame.as_raw_GpuMat(), result.as_raw_mut_GpuMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MoreAccurateMotionWobbleSuppressorGpu_suppress_int_const_GpuMatR_GpuMatR(self.as_raw_mut_MoreAccurateMotionWobbleSuppressorGpu(), idx, frame.as_raw_GpuMat(), result.as_raw_mut_GpuMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1943,8 +2078,9 @@ pub trait MoreAccurateMotionWobbleSuppressorGpuTrait: crate::videostab::MoreAccu
 	
 	#[inline]
 	fn suppress_1(&mut self, idx: i32, frame: &core::Mat, result: &mut core::Mat) -> Result<()> {
+		crate::core::validity::check("MoreAccurateMotionWobbleSuppressorGpu", self.as_raw_mut_MoreAccurateMotionWobbleSuppressorGpu())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MoreAccurateMotionWobbleSuppressorGpu_suppress_int_const_MatR_MatR(self.as_raw_mut_MoreAccurateMotionWobbleSuppressorGpu(), idx, frame.as_raw_Mat(), result.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MoreAccurateMotionWobbleSuppressorGpu_suppress_int_const_MatR_MatR(self.as_raw_mut_MoreAccurateMotionWobbleSuppressorGpu(), idx, frame.as_raw_Mat(), result.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1961,7 +2097,10 @@ opencv_type_boxed! { MoreAccurateMotionWobbleSuppressorGpu }
 impl Drop for MoreAccurateMotionWobbleSuppressorGpu {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MoreAccurateMotionWobbleSuppressorGpu_delete(instance: *mut c_void); }
-		unsafe { cv_MoreAccurateMotionWobbleSuppressorGpu_delete(self.as_raw_mut_MoreAccurateMotionWobbleSuppressorGpu()) };
+		let ptr = self.as_raw_mut_MoreAccurateMotionWobbleSuppressorGpu();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_MoreAccurateMotionWobbleSuppressorGpu_delete(ptr) };
 	}
 }
 
@@ -2002,8 +2141,9 @@ pub trait MotionEstimatorBaseConst {
 	/// Motion model. See cv::videostab::MotionModel.
 	#[inline]
 	fn motion_model(&self) -> Result<crate::videostab::MotionModel> {
+		crate::core::validity::check("MotionEstimatorBase", self.as_raw_MotionEstimatorBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MotionEstimatorBase_motionModel_const(self.as_raw_MotionEstimatorBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MotionEstimatorBase_motionModel_const(self.as_raw_MotionEstimatorBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2020,8 +2160,9 @@ pub trait MotionEstimatorBase: crate::videostab::MotionEstimatorBaseConst {
 	/// * val: Motion model. See cv::videostab::MotionModel.
 	#[inline]
 	fn set_motion_model(&mut self, val: crate::videostab::MotionModel) -> Result<()> {
+		crate::core::validity::check("MotionEstimatorBase", self.as_raw_mut_MotionEstimatorBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MotionEstimatorBase_setMotionModel_MotionModel(self.as_raw_mut_MotionEstimatorBase(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MotionEstimatorBase_setMotionModel_MotionModel(self.as_raw_mut_MotionEstimatorBase(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2040,10 +2181,11 @@ pub trait MotionEstimatorBase: crate::videostab::MotionEstimatorBaseConst {
 	/// * ok: 0
 	#[inline]
 	fn estimate(&mut self, points0: &dyn core::ToInputArray, points1: &dyn core::ToInputArray, ok: &mut bool) -> Result<core::Mat> {
+		crate::core::validity::check("MotionEstimatorBase", self.as_raw_mut_MotionEstimatorBase())?;
 		input_array_arg!(points0);
 		input_array_arg!(points1);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MotionEstimatorBase_estimate_const__InputArrayR_const__InputArrayR_boolX(self.as_raw_mut_MotionEstimatorBase(), points0.as_raw__InputArray(), points1.as_raw__InputArray(), ok, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MotionEstimatorBase_estimate_const__InputArrayR_const__InputArrayR_boolX(self.as_raw_mut_MotionEstimatorBase(), points0.as_raw__InputArray(), points1.as_raw__InputArray(), ok, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -2068,10 +2210,11 @@ pub trait MotionEstimatorL1Trait: crate::videostab::MotionEstimatorBase + crate:
 	/// * ok: 0
 	#[inline]
 	fn estimate(&mut self, points0: &dyn core::ToInputArray, points1: &dyn core::ToInputArray, ok: &mut bool) -> Result<core::Mat> {
+		crate::core::validity::check("MotionEstimatorL1", self.as_raw_mut_MotionEstimatorL1())?;
 		input_array_arg!(points0);
 		input_array_arg!(points1);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MotionEstimatorL1_estimate_const__InputArrayR_const__InputArrayR_boolX(self.as_raw_mut_MotionEstimatorL1(), points0.as_raw__InputArray(), points1.as_raw__InputArray(), ok, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MotionEstimatorL1_estimate_const__InputArrayR_const__InputArrayR_boolX(self.as_raw_mut_MotionEstimatorL1(), points0.as_raw__InputArray(), points1.as_raw__InputArray(), ok, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -2093,7 +2236,10 @@ opencv_type_boxed! { MotionEstimatorL1 }
 impl Drop for MotionEstimatorL1 {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MotionEstimatorL1_delete(instance: *mut c_void); }
-		unsafe { cv_MotionEstimatorL1_delete(self.as_raw_mut_MotionEstimatorL1()) };
+		let ptr = self.as_raw_mut_MotionEstimatorL1();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_MotionEstimatorL1_delete(ptr) };
 	}
 }
 
@@ -2121,7 +2267,7 @@ impl MotionEstimatorL1 {
 	#[inline]
 	pub fn new(model: crate::videostab::MotionModel) -> Result<crate::videostab::MotionEstimatorL1> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MotionEstimatorL1_MotionEstimatorL1_MotionModel(model, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MotionEstimatorL1_MotionEstimatorL1_MotionModel(model, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videostab::MotionEstimatorL1::opencv_from_extern(ret) };
@@ -2136,8 +2282,9 @@ pub trait MotionEstimatorRansacL2TraitConst: crate::videostab::MotionEstimatorBa
 
 	#[inline]
 	fn ransac_params(&self) -> Result<crate::videostab::RansacParams> {
+		crate::core::validity::check("MotionEstimatorRansacL2", self.as_raw_MotionEstimatorRansacL2())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MotionEstimatorRansacL2_ransacParams_const(self.as_raw_MotionEstimatorRansacL2(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MotionEstimatorRansacL2_ransacParams_const(self.as_raw_MotionEstimatorRansacL2(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videostab::RansacParams::opencv_from_extern(ret) };
@@ -2146,8 +2293,9 @@ pub trait MotionEstimatorRansacL2TraitConst: crate::videostab::MotionEstimatorBa
 	
 	#[inline]
 	fn min_inlier_ratio(&self) -> Result<f32> {
+		crate::core::validity::check("MotionEstimatorRansacL2", self.as_raw_MotionEstimatorRansacL2())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MotionEstimatorRansacL2_minInlierRatio_const(self.as_raw_MotionEstimatorRansacL2(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MotionEstimatorRansacL2_minInlierRatio_const(self.as_raw_MotionEstimatorRansacL2(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2160,8 +2308,9 @@ pub trait MotionEstimatorRansacL2Trait: crate::videostab::MotionEstimatorBase +
 
 	#[inline]
 	fn set_ransac_params(&mut self, val: &crate::videostab::RansacParams) -> Result<()> {
+		crate::core::validity::check("MotionEstimatorRansacL2", self.as_raw_mut_MotionEstimatorRansacL2())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MotionEstimatorRansacL2_setRansacParams_const_RansacParamsR(self.as_raw_mut_MotionEstimatorRansacL2(), val.as_raw_RansacParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MotionEstimatorRansacL2_setRansacParams_const_RansacParamsR(self.as_raw_mut_MotionEstimatorRansacL2(), val.as_raw_RansacParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2169,8 +2318,9 @@ pub trait MotionEstimatorRansacL2Trait: crate::videostab::MotionEstimatorBase +
 	
 	#[inline]
 	fn set_min_inlier_ratio(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("MotionEstimatorRansacL2", self.as_raw_mut_MotionEstimatorRansacL2())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MotionEstimatorRansacL2_setMinInlierRatio_float(self.as_raw_mut_MotionEstimatorRansacL2(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MotionEstimatorRansacL2_setMinInlierRatio_float(self.as_raw_mut_MotionEstimatorRansacL2(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2180,10 +2330,11 @@ pub trait MotionEstimatorRansacL2Trait: crate::videostab::MotionEstimatorBase +
 	/// * ok: 0
 	#[inline]
 	fn estimate(&mut self, points0: &dyn core::ToInputArray, points1: &dyn core::ToInputArray, ok: &mut bool) -> Result<core::Mat> {
+		crate::core::validity::check("MotionEstimatorRansacL2", self.as_raw_mut_MotionEstimatorRansacL2())?;
 		input_array_arg!(points0);
 		input_array_arg!(points1);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MotionEstimatorRansacL2_estimate_const__InputArrayR_const__InputArrayR_boolX(self.as_raw_mut_MotionEstimatorRansacL2(), points0.as_raw__InputArray(), points1.as_raw__InputArray(), ok, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MotionEstimatorRansacL2_estimate_const__InputArrayR_const__InputArrayR_boolX(self.as_raw_mut_MotionEstimatorRansacL2(), points0.as_raw__InputArray(), points1.as_raw__InputArray(), ok, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -2202,7 +2353,10 @@ opencv_type_boxed! { MotionEstimatorRansacL2 }
 impl Drop for MotionEstimatorRansacL2 {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MotionEstimatorRansacL2_delete(instance: *mut c_void); }
-		unsafe { cv_MotionEstimatorRansacL2_delete(self.as_raw_mut_MotionEstimatorRansacL2()) };
+		let ptr = self.as_raw_mut_MotionEstimatorRansacL2();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_MotionEstimatorRansacL2_delete(ptr) };
 	}
 }
 
@@ -2230,7 +2384,7 @@ impl MotionEstimatorRansacL2 {
 	#[inline]
 	pub fn new(model: crate::videostab::MotionModel) -> Result<crate::videostab::MotionEstimatorRansacL2> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MotionEstimatorRansacL2_MotionEstimatorRansacL2_MotionModel(model, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MotionEstimatorRansacL2_MotionEstimatorRansacL2_MotionModel(model, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videostab::MotionEstimatorRansacL2::opencv_from_extern(ret) };
@@ -2249,8 +2403,9 @@ pub trait MotionFilterBase: crate::videostab::IMotionStabilizer + crate::videost
 
 	#[inline]
 	fn stabilize(&mut self, idx: i32, motions: &core::Vector<core::Mat>, range: &core::Range) -> Result<core::Mat> {
+		crate::core::validity::check("MotionFilterBase", self.as_raw_mut_MotionFilterBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MotionFilterBase_stabilize_int_const_vector_Mat_R_const_RangeR(self.as_raw_mut_MotionFilterBase(), idx, motions.as_raw_VectorOfMat(), range.as_raw_Range(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MotionFilterBase_stabilize_int_const_vector_Mat_R_const_RangeR(self.as_raw_mut_MotionFilterBase(), idx, motions.as_raw_VectorOfMat(), range.as_raw_Range(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -2259,8 +2414,9 @@ pub trait MotionFilterBase: crate::videostab::IMotionStabilizer + crate::videost
 	
 	#[inline]
 	fn stabilize_1(&mut self, size: i32, motions: &core::Vector<core::Mat>, range: &core::Range, stabilization_motions: &mut core::Mat) -> Result<()> {
+		crate::core::validity::check("MotionFilterBase", self.as_raw_mut_MotionFilterBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MotionFilterBase_stabilize_int_const_vector_Mat_R_const_RangeR_MatX(self.as_raw_mut_MotionFilterBase(), size, motions.as_raw_VectorOfMat(), range.as_raw_Range(), stabilization_motions.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MotionFilterBase_stabilize_int_const_vector_Mat_R_const_RangeR_MatX(self.as_raw_mut_MotionFilterBase(), size, motions.as_raw_VectorOfMat(), range.as_raw_Range(), stabilization_motions.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2273,8 +2429,9 @@ pub trait MotionInpainterTraitConst: crate::videostab::InpainterBaseConst {
 
 	#[inline]
 	fn opt_flow_estimator(&self) -> Result<core::Ptr<dyn crate::videostab::IDenseOptFlowEstimator>> {
+		crate::core::validity::check("MotionInpainter", self.as_raw_MotionInpainter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MotionInpainter_optFlowEstimator_const(self.as_raw_MotionInpainter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MotionInpainter_optFlowEstimator_const(self.as_raw_MotionInpainter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::videostab::IDenseOptFlowEstimator>::opencv_from_extern(ret) };
@@ -2283,8 +2440,9 @@ pub trait MotionInpainterTraitConst: crate::videostab::InpainterBaseConst {
 	
 	#[inline]
 	fn flow_error_threshold(&self) -> Result<f32> {
+		crate::core::validity::check("MotionInpainter", self.as_raw_MotionInpainter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MotionInpainter_flowErrorThreshold_const(self.as_raw_MotionInpainter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MotionInpainter_flowErrorThreshold_const(self.as_raw_MotionInpainter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2292,8 +2450,9 @@ pub trait MotionInpainterTraitConst: crate::videostab::InpainterBaseConst {
 	
 	#[inline]
 	fn dist_thresh(&self) -> Result<f32> {
+		crate::core::validity::check("MotionInpainter", self.as_raw_MotionInpainter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MotionInpainter_distThresh_const(self.as_raw_MotionInpainter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MotionInpainter_distThresh_const(self.as_raw_MotionInpainter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2301,8 +2460,9 @@ pub trait MotionInpainterTraitConst: crate::videostab::InpainterBaseConst {
 	
 	#[inline]
 	fn border_mode(&self) -> Result<i32> {
+		crate::core::validity::check("MotionInpainter", self.as_raw_MotionInpainter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MotionInpainter_borderMode_const(self.as_raw_MotionInpainter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MotionInpainter_borderMode_const(self.as_raw_MotionInpainter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2315,8 +2475,9 @@ pub trait MotionInpainterTrait: crate::videostab::InpainterBase + crate::videost
 
 	#[inline]
 	fn set_opt_flow_estimator(&mut self, mut val: core::Ptr<dyn crate::videostab::IDenseOptFlowEstimator>) -> Result<()> {
+		crate::core::validity::check("MotionInpainter", self.as_raw_mut_MotionInpainter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MotionInpainter_setOptFlowEstimator_Ptr_IDenseOptFlowEstimator_(self.as_raw_mut_MotionInpainter(), val.as_raw_mut_PtrOfIDenseOptFlowEstimator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MotionInpainter_setOptFlowEstimator_Ptr_IDenseOptFlowEstimator_(self.as_raw_mut_MotionInpainter(), val.as_raw_mut_PtrOfIDenseOptFlowEstimator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2324,8 +2485,9 @@ pub trait MotionInpainterTrait: crate::videostab::InpainterBase + crate::videost
 	
 	#[inline]
 	fn set_flow_error_threshold(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("MotionInpainter", self.as_raw_mut_MotionInpainter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MotionInpainter_setFlowErrorThreshold_float(self.as_raw_mut_MotionInpainter(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MotionInpainter_setFlowErrorThreshold_float(self.as_raw_mut_MotionInpainter(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2333,8 +2495,9 @@ pub trait MotionInpainterTrait: crate::videostab::InpainterBase + crate::videost
 	
 	#[inline]
 	fn set_dist_threshold(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("MotionInpainter", self.as_raw_mut_MotionInpainter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MotionInpainter_setDistThreshold_float(self.as_raw_mut_MotionInpainter(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MotionInpainter_setDistThreshold_float(self.as_raw_mut_MotionInpainter(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2342,8 +2505,9 @@ pub trait MotionInpainterTrait: crate::videostab::InpainterBase + crate::videost
 	
 	#[inline]
 	fn set_border_mode(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("MotionInpainter", self.as_raw_mut_MotionInpainter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MotionInpainter_setBorderMode_int(self.as_raw_mut_MotionInpainter(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MotionInpainter_setBorderMode_int(self.as_raw_mut_MotionInpainter(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2351,8 +2515,9 @@ pub trait MotionInpainterTrait: crate::videostab::InpainterBase + crate::videost
 	
 	#[inline]
 	fn inpaint(&mut self, idx: i32, frame: &mut core::Mat, mask: &mut core::Mat) -> Result<()> {
+		crate::core::validity::check("MotionInpainter", self.as_raw_mut_MotionInpainter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MotionInpainter_inpaint_int_MatR_MatR(self.as_raw_mut_MotionInpainter(), idx, frame.as_raw_mut_Mat(), mask.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MotionInpainter_inpaint_int_MatR_MatR(self.as_raw_mut_MotionInpainter(), idx, frame.as_raw_mut_Mat(), mask.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2369,7 +2534,10 @@ opencv_type_boxed! { MotionInpainter }
 impl Drop for MotionInpainter {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MotionInpainter_delete(instance: *mut c_void); }
-		unsafe { cv_MotionInpainter_delete(self.as_raw_mut_MotionInpainter()) };
+		let ptr = self.as_raw_mut_MotionInpainter();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_MotionInpainter_delete(ptr) };
 	}
 }
 
@@ -2395,7 +2563,7 @@ impl MotionInpainter {
 	#[inline]
 	pub fn default() -> Result<crate::videostab::MotionInpainter> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MotionInpainter_MotionInpainter(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MotionInpainter_MotionInpainter(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videostab::MotionInpainter::opencv_from_extern(ret) };
@@ -2409,8 +2577,9 @@ pub trait MotionStabilizationPipelineTraitConst: crate::videostab::IMotionStabil
 
 	#[inline]
 	fn empty(&self) -> Result<bool> {
+		crate::core::validity::check("MotionStabilizationPipeline", self.as_raw_MotionStabilizationPipeline())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MotionStabilizationPipeline_empty_const(self.as_raw_MotionStabilizationPipeline(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MotionStabilizationPipeline_empty_const(self.as_raw_MotionStabilizationPipeline(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2423,8 +2592,9 @@ pub trait MotionStabilizationPipelineTrait: crate::videostab::IMotionStabilizer
 
 	#[inline]
 	fn push_back(&mut self, mut stabilizer: core::Ptr<dyn crate::videostab::IMotionStabilizer>) -> Result<()> {
+		crate::core::validity::check("MotionStabilizationPipeline", self.as_raw_mut_MotionStabilizationPipeline())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MotionStabilizationPipeline_pushBack_Ptr_IMotionStabilizer_(self.as_raw_mut_MotionStabilizationPipeline(), stabilizer.as_raw_mut_PtrOfIMotionStabilizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MotionStabilizationPipeline_pushBack_Ptr_IMotionStabilizer_(self.as_raw_mut_MotionStabilizationPipeline(), stabilizer.as_raw_mut_PtrOfIMotionStabilizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2432,8 +2602,9 @@ pub trait MotionStabilizationPipelineTrait: crate::videostab::IMotionStabilizer
 	
 	#[inline]
 	fn stabilize(&mut self, size: i32, motions: &core::Vector<core::Mat>, range: &core::Range, stabilization_motions: &mut core::Mat) -> Result<()> {
+		crate::core::validity::check("MotionStabilizationPipeline", self.as_raw_mut_MotionStabilizationPipeline())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_MotionStabilizationPipeline_stabilize_int_const_vector_Mat_R_const_RangeR_MatX(self.as_raw_mut_MotionStabilizationPipeline(), size, motions.as_raw_VectorOfMat(), range.as_raw_Range(), stabilization_motions.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_MotionStabilizationPipeline_stabilize_int_const_vector_Mat_R_const_RangeR_MatX(self.as_raw_mut_MotionStabilizationPipeline(), size, motions.as_raw_VectorOfMat(), range.as_raw_Range(), stabilization_motions.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2450,7 +2621,10 @@ opencv_type_boxed! { MotionStabilizationPipeline }
 impl Drop for MotionStabilizationPipeline {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MotionStabilizationPipeline_delete(instance: *mut c_void); }
-		unsafe { cv_MotionStabilizationPipeline_delete(self.as_raw_mut_MotionStabilizationPipeline()) };
+		let ptr = self.as_raw_mut_MotionStabilizationPipeline();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_MotionStabilizationPipeline_delete(ptr) };
 	}
 }
 
@@ -2485,8 +2659,9 @@ pub trait NullDeblurerTrait: crate::videostab::DeblurerBase + crate::videostab::
 
 	#[inline]
 	fn deblur(&mut self, unnamed: i32, unnamed_1: &mut core::Mat, unnamed_2: &core::Range) -> Result<()> {
+		crate::core::validity::check("NullDeblurer", self.as_raw_mut_NullDeblurer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_NullDeblurer_deblur_int_MatR_const_RangeR(self.as_raw_mut_NullDeblurer(), unnamed, unnamed_1.as_raw_mut_Mat(), unnamed_2.as_raw_Range(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_NullDeblurer_deblur_int_MatR_const_RangeR(self.as_raw_mut_NullDeblurer(), unnamed, unnamed_1.as_raw_mut_Mat(), unnamed_2.as_raw_Range(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2503,7 +2678,10 @@ opencv_type_boxed! { NullDeblurer }
 impl Drop for NullDeblurer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_NullDeblurer_delete(instance: *mut c_void); }
-		unsafe { cv_NullDeblurer_delete(self.as_raw_mut_NullDeblurer()) };
+		let ptr = self.as_raw_mut_NullDeblurer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_NullDeblurer_delete(ptr) };
 	}
 }
 
@@ -2538,8 +2716,9 @@ pub trait NullFrameSourceTrait: crate::videostab::IFrameSource + crate::videosta
 
 	#[inline]
 	fn reset(&mut self) -> Result<()> {
+		crate::core::validity::check("NullFrameSource", self.as_raw_mut_NullFrameSource())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_NullFrameSource_reset(self.as_raw_mut_NullFrameSource(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_NullFrameSource_reset(self.as_raw_mut_NullFrameSource(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2547,8 +2726,9 @@ pub trait NullFrameSourceTrait: crate::videostab::IFrameSource + crate::videosta
 	
 	#[inline]
 	fn next_frame(&mut self) -> Result<core::Mat> {
+		crate::core::validity::check("NullFrameSource", self.as_raw_mut_NullFrameSource())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_NullFrameSource_nextFrame(self.as_raw_mut_NullFrameSource(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_NullFrameSource_nextFrame(self.as_raw_mut_NullFrameSource(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -2566,7 +2746,10 @@ opencv_type_boxed! { NullFrameSource }
 impl Drop for NullFrameSource {
 	fn drop(&mut self) {
 		extern "C" { fn cv_NullFrameSource_delete(instance: *mut c_void); }
-		unsafe { cv_NullFrameSource_delete(self.as_raw_mut_NullFrameSource()) };
+		let ptr = self.as_raw_mut_NullFrameSource();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_NullFrameSource_delete(ptr) };
 	}
 }
 
@@ -2601,8 +2784,9 @@ pub trait NullInpainterTrait: crate::videostab::InpainterBase + crate::videostab
 
 	#[inline]
 	fn inpaint(&mut self, unnamed: i32, unnamed_1: &mut core::Mat, unnamed_2: &mut core::Mat) -> Result<()> {
+		crate::core::validity::check("NullInpainter", self.as_raw_mut_NullInpainter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_NullInpainter_inpaint_int_MatR_MatR(self.as_raw_mut_NullInpainter(), unnamed, unnamed_1.as_raw_mut_Mat(), unnamed_2.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_NullInpainter_inpaint_int_MatR_MatR(self.as_raw_mut_NullInpainter(), unnamed, unnamed_1.as_raw_mut_Mat(), unnamed_2.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2619,7 +2803,10 @@ opencv_type_boxed! { NullInpainter }
 impl Drop for NullInpainter {
 	fn drop(&mut self) {
 		extern "C" { fn cv_NullInpainter_delete(instance: *mut c_void); }
-		unsafe { cv_NullInpainter_delete(self.as_raw_mut_NullInpainter()) };
+		let ptr = self.as_raw_mut_NullInpainter();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_NullInpainter_delete(ptr) };
 	}
 }
 
@@ -2654,9 +2841,10 @@ pub trait NullLogTrait: crate::videostab::ILog + crate::videostab::NullLogTraitC
 
 	#[inline]
 	fn print(&mut self, unnamed: &str) -> Result<()> {
+		crate::core::validity::check("NullLog", self.as_raw_mut_NullLog())?;
 		extern_container_arg!(unnamed);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_NullLog_print_const_charX(self.as_raw_mut_NullLog(), unnamed.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_NullLog_print_const_charX(self.as_raw_mut_NullLog(), unnamed.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2673,7 +2861,10 @@ opencv_type_boxed! { NullLog }
 impl Drop for NullLog {
 	fn drop(&mut self) {
 		extern "C" { fn cv_NullLog_delete(instance: *mut c_void); }
-		unsafe { cv_NullLog_delete(self.as_raw_mut_NullLog()) };
+		let ptr = self.as_raw_mut_NullLog();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_NullLog_delete(ptr) };
 	}
 }
 
@@ -2708,11 +2899,12 @@ pub trait NullOutlierRejectorTrait: crate::videostab::IOutlierRejector + crate::
 
 	#[inline]
 	fn process(&mut self, frame_size: core::Size, points0: &dyn core::ToInputArray, points1: &dyn core::ToInputArray, mask: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("NullOutlierRejector", self.as_raw_mut_NullOutlierRejector())?;
 		input_array_arg!(points0);
 		input_array_arg!(points1);
 		output_array_arg!(mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_NullOutlierRejector_process_Size_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_NullOutlierRejector(), frame_size.opencv_as_extern(), points0.as_raw__InputArray(), points1.as_raw__InputArray(), mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_NullOutlierRejector_process_Size_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_NullOutlierRejector(), frame_size.opencv_as_extern(), points0.as_raw__InputArray(), points1.as_raw__InputArray(), mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2729,7 +2921,10 @@ opencv_type_boxed! { NullOutlierRejector }
 impl Drop for NullOutlierRejector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_NullOutlierRejector_delete(instance: *mut c_void); }
-		unsafe { cv_NullOutlierRejector_delete(self.as_raw_mut_NullOutlierRejector()) };
+		let ptr = self.as_raw_mut_NullOutlierRejector();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_NullOutlierRejector_delete(ptr) };
 	}
 }
 
@@ -2764,8 +2959,9 @@ pub trait NullWobbleSuppressorTrait: crate::videostab::NullWobbleSuppressorTrait
 
 	#[inline]
 	fn suppress(&mut self, idx: i32, frame: &core::Mat, result: &mut core::Mat) -> Result<()> {
+		crate::core::validity::check("NullWobbleSuppressor", self.as_raw_mut_NullWobbleSuppressor())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_NullWobbleSuppressor_suppress_int_const_MatR_MatR(self.as_raw_mut_NullWobbleSuppressor(), idx, frame.as_raw_Mat(), result.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_NullWobbleSuppressor_suppress_int_const_MatR_MatR(self.as_raw_mut_NullWobbleSuppressor(), idx, frame.as_raw_Mat(), result.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2782,7 +2978,10 @@ opencv_type_boxed! { NullWobbleSuppressor }
 impl Drop for NullWobbleSuppressor {
 	fn drop(&mut self) {
 		extern "C" { fn cv_NullWobbleSuppressor_delete(instance: *mut c_void); }
-		unsafe { cv_NullWobbleSuppressor_delete(self.as_raw_mut_NullWobbleSuppressor()) };
+		let ptr = self.as_raw_mut_NullWobbleSuppressor();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_NullWobbleSuppressor_delete(ptr) };
 	}
 }
 
@@ -2812,8 +3011,9 @@ pub trait OnePassStabilizerTraitConst: crate::videostab::IFrameSourceConst + cra
 
 	#[inline]
 	fn motion_filter(&self) -> Result<core::Ptr<dyn crate::videostab::MotionFilterBase>> {
+		crate::core::validity::check("OnePassStabilizer", self.as_raw_OnePassStabilizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_OnePassStabilizer_motionFilter_const(self.as_raw_OnePassStabilizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_OnePassStabilizer_motionFilter_const(self.as_raw_OnePassStabilizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::videostab::MotionFilterBase>::opencv_from_extern(ret) };
@@ -2827,8 +3027,9 @@ pub trait OnePassStabilizerTrait: crate::videostab::IFrameSource + crate::videos
 
 	#[inline]
 	fn set_motion_filter(&mut self, mut val: core::Ptr<dyn crate::videostab::MotionFilterBase>) -> Result<()> {
+		crate::core::validity::check("OnePassStabilizer", self.as_raw_mut_OnePassStabilizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_OnePassStabilizer_setMotionFilter_Ptr_MotionFilterBase_(self.as_raw_mut_OnePassStabilizer(), val.as_raw_mut_PtrOfMotionFilterBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_OnePassStabilizer_setMotionFilter_Ptr_MotionFilterBase_(self.as_raw_mut_OnePassStabilizer(), val.as_raw_mut_PtrOfMotionFilterBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2836,8 +3037,9 @@ pub trait OnePassStabilizerTrait: crate::videostab::IFrameSource + crate::videos
 	
 	#[inline]
 	fn reset(&mut self) -> Result<()> {
+		crate::core::validity::check("OnePassStabilizer", self.as_raw_mut_OnePassStabilizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_OnePassStabilizer_reset(self.as_raw_mut_OnePassStabilizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_OnePassStabilizer_reset(self.as_raw_mut_OnePassStabilizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2845,8 +3047,9 @@ pub trait OnePassStabilizerTrait: crate::videostab::IFrameSource + crate::videos
 	
 	#[inline]
 	fn next_frame(&mut self) -> Result<core::Mat> {
+		crate::core::validity::check("OnePassStabilizer", self.as_raw_mut_OnePassStabilizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_OnePassStabilizer_nextFrame(self.as_raw_mut_OnePassStabilizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_OnePassStabilizer_nextFrame(self.as_raw_mut_OnePassStabilizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -2864,7 +3067,10 @@ opencv_type_boxed! { OnePassStabilizer }
 impl Drop for OnePassStabilizer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_OnePassStabilizer_delete(instance: *mut c_void); }
-		unsafe { cv_OnePassStabilizer_delete(self.as_raw_mut_OnePassStabilizer()) };
+		let ptr = self.as_raw_mut_OnePassStabilizer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_OnePassStabilizer_delete(ptr) };
 	}
 }
 
@@ -2898,7 +3104,7 @@ impl OnePassStabilizer {
 	#[inline]
 	pub fn default() -> Result<crate::videostab::OnePassStabilizer> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_OnePassStabilizer_OnePassStabilizer(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_OnePassStabilizer_OnePassStabilizer(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videostab::OnePassStabilizer::opencv_from_extern(ret) };
@@ -2912,8 +3118,9 @@ pub trait PyrLkOptFlowEstimatorBaseTraitConst {
 
 	#[inline]
 	fn win_size(&self) -> Result<core::Size> {
+		crate::core::validity::check("PyrLkOptFlowEstimatorBase", self.as_raw_PyrLkOptFlowEstimatorBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_PyrLkOptFlowEstimatorBase_winSize_const(self.as_raw_PyrLkOptFlowEstimatorBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_PyrLkOptFlowEstimatorBase_winSize_const(self.as_raw_PyrLkOptFlowEstimatorBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2921,8 +3128,9 @@ pub trait PyrLkOptFlowEstimatorBaseTraitConst {
 	
 	#[inline]
 	fn max_level(&self) -> Result<i32> {
+		crate::core::validity::check("PyrLkOptFlowEstimatorBase", self.as_raw_PyrLkOptFlowEstimatorBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_PyrLkOptFlowEstimatorBase_maxLevel_const(self.as_raw_PyrLkOptFlowEstimatorBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_PyrLkOptFlowEstimatorBase_maxLevel_const(self.as_raw_PyrLkOptFlowEstimatorBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2935,8 +3143,9 @@ pub trait PyrLkOptFlowEstimatorBaseTrait: crate::videostab::PyrLkOptFlowEstimato
 
 	#[inline]
 	fn set_win_size(&mut self, val: core::Size) -> Result<()> {
+		crate::core::validity::check("PyrLkOptFlowEstimatorBase", self.as_raw_mut_PyrLkOptFlowEstimatorBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_PyrLkOptFlowEstimatorBase_setWinSize_Size(self.as_raw_mut_PyrLkOptFlowEstimatorBase(), val.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_PyrLkOptFlowEstimatorBase_setWinSize_Size(self.as_raw_mut_PyrLkOptFlowEstimatorBase(), val.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2944,8 +3153,9 @@ pub trait PyrLkOptFlowEstimatorBaseTrait: crate::videostab::PyrLkOptFlowEstimato
 	
 	#[inline]
 	fn set_max_level(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("PyrLkOptFlowEstimatorBase", self.as_raw_mut_PyrLkOptFlowEstimatorBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_PyrLkOptFlowEstimatorBase_setMaxLevel_int(self.as_raw_mut_PyrLkOptFlowEstimatorBase(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_PyrLkOptFlowEstimatorBase_setMaxLevel_int(self.as_raw_mut_PyrLkOptFlowEstimatorBase(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2962,7 +3172,10 @@ opencv_type_boxed! { PyrLkOptFlowEstimatorBase }
 impl Drop for PyrLkOptFlowEstimatorBase {
 	fn drop(&mut self) {
 		extern "C" { fn cv_PyrLkOptFlowEstimatorBase_delete(instance: *mut c_void); }
-		unsafe { cv_PyrLkOptFlowEstimatorBase_delete(self.as_raw_mut_PyrLkOptFlowEstimatorBase()) };
+		let ptr = self.as_raw_mut_PyrLkOptFlowEstimatorBase();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_PyrLkOptFlowEstimatorBase_delete(ptr) };
 	}
 }
 
@@ -2980,7 +3193,7 @@ impl PyrLkOptFlowEstimatorBase {
 	#[inline]
 	pub fn default() -> Result<crate::videostab::PyrLkOptFlowEstimatorBase> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_PyrLkOptFlowEstimatorBase_PyrLkOptFlowEstimatorBase(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_PyrLkOptFlowEstimatorBase_PyrLkOptFlowEstimatorBase(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videostab::PyrLkOptFlowEstimatorBase::opencv_from_extern(ret) };
@@ -3002,37 +3215,74 @@ pub trait RansacParamsTraitConst {
 	/// subset size
 	#[inline]
 	fn size(&self) -> i32 {
-		let ret = unsafe { sys::cv_videostab_RansacParams_getPropSize_const(self.as_raw_RansacParams()) };
+		crate::core::validity::debug_check("RansacParams", self.as_raw_RansacParams());
+		let ret = unsafe { ffi_call!(cv_videostab_RansacParams_getPropSize_const(self.as_raw_RansacParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [size](RansacParamsTraitConst::size), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_size(&self) -> Result<i32> {
+		crate::core::validity::check("RansacParams", self.as_raw_RansacParams())?;
+		Ok(RansacParamsTraitConst::size(self))
+	}
+	
 	/// max error to classify as inlier
 	#[inline]
 	fn thresh(&self) -> f32 {
-		let ret = unsafe { sys::cv_videostab_RansacParams_getPropThresh_const(self.as_raw_RansacParams()) };
+		crate::core::validity::debug_check("RansacParams", self.as_raw_RansacParams());
+		let ret = unsafe { ffi_call!(cv_videostab_RansacParams_getPropThresh_const(self.as_raw_RansacParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [thresh](RansacParamsTraitConst::thresh), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_thresh(&self) -> Result<f32> {
+		crate::core::validity::check("RansacParams", self.as_raw_RansacParams())?;
+		Ok(RansacParamsTraitConst::thresh(self))
+	}
+	
 	/// max outliers ratio
 	#[inline]
 	fn eps(&self) -> f32 {
-		let ret = unsafe { sys::cv_videostab_RansacParams_getPropEps_const(self.as_raw_RansacParams()) };
+		crate::core::validity::debug_check("RansacParams", self.as_raw_RansacParams());
+		let ret = unsafe { ffi_call!(cv_videostab_RansacParams_getPropEps_const(self.as_raw_RansacParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [eps](RansacParamsTraitConst::eps), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_eps(&self) -> Result<f32> {
+		crate::core::validity::check("RansacParams", self.as_raw_RansacParams())?;
+		Ok(RansacParamsTraitConst::eps(self))
+	}
+	
 	/// probability of success
 	#[inline]
 	fn prob(&self) -> f32 {
-		let ret = unsafe { sys::cv_videostab_RansacParams_getPropProb_const(self.as_raw_RansacParams()) };
+		crate::core::validity::debug_check("RansacParams", self.as_raw_RansacParams());
+		let ret = unsafe { ffi_call!(cv_videostab_RansacParams_getPropProb_const(self.as_raw_RansacParams())) };
 		ret
 	}
 	
+	/// Non-panicking version of [prob](RansacParamsTraitConst::prob), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_prob(&self) -> Result<f32> {
+		crate::core::validity::check("RansacParams", self.as_raw_RansacParams())?;
+		Ok(RansacParamsTraitConst::prob(self))
+	}
+	
 	/// ## Returns
 	/// Number of iterations that'll be performed by RANSAC method.
 	#[inline]
 	fn niters(&self) -> Result<i32> {
+		crate::core::validity::check("RansacParams", self.as_raw_RansacParams())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_RansacParams_niters_const(self.as_raw_RansacParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_RansacParams_niters_const(self.as_raw_RansacParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3046,28 +3296,32 @@ pub trait RansacParamsTrait: crate::videostab::RansacParamsTraitConst {
 	/// subset size
 	#[inline]
 	fn set_size(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_videostab_RansacParams_setPropSize_int(self.as_raw_mut_RansacParams(), val) };
+		crate::core::validity::debug_check("RansacParams", self.as_raw_mut_RansacParams());
+		let ret = unsafe { ffi_call!(cv_videostab_RansacParams_setPropSize_int(self.as_raw_mut_RansacParams(), val)) };
 		ret
 	}
 	
 	/// max error to classify as inlier
 	#[inline]
 	fn set_thresh(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_videostab_RansacParams_setPropThresh_float(self.as_raw_mut_RansacParams(), val) };
+		crate::core::validity::debug_check("RansacParams", self.as_raw_mut_RansacParams());
+		let ret = unsafe { ffi_call!(cv_videostab_RansacParams_setPropThresh_float(self.as_raw_mut_RansacParams(), val)) };
 		ret
 	}
 	
 	/// max outliers ratio
 	#[inline]
 	fn set_eps(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_videostab_RansacParams_setPropEps_float(self.as_raw_mut_RansacParams(), val) };
+		crate::core::validity::debug_check("RansacParams", self.as_raw_mut_RansacParams());
+		let ret = unsafe { ffi_call!(cv_videostab_RansacParams_setPropEps_float(self.as_raw_mut_RansacParams(), val)) };
 		ret
 	}
 	
 	/// probability of success
 	#[inline]
 	fn set_prob(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_videostab_RansacParams_setPropProb_float(self.as_raw_mut_RansacParams(), val) };
+		crate::core::validity::debug_check("RansacParams", self.as_raw_mut_RansacParams());
+		let ret = unsafe { ffi_call!(cv_videostab_RansacParams_setPropProb_float(self.as_raw_mut_RansacParams(), val)) };
 		ret
 	}
 	
@@ -3083,7 +3337,10 @@ opencv_type_boxed! { RansacParams }
 impl Drop for RansacParams {
 	fn drop(&mut self) {
 		extern "C" { fn cv_RansacParams_delete(instance: *mut c_void); }
-		unsafe { cv_RansacParams_delete(self.as_raw_mut_RansacParams()) };
+		let ptr = self.as_raw_mut_RansacParams();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_RansacParams_delete(ptr) };
 	}
 }
 
@@ -3101,7 +3358,7 @@ impl RansacParams {
 	#[inline]
 	pub fn default() -> Result<crate::videostab::RansacParams> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_RansacParams_RansacParams(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_RansacParams_RansacParams(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videostab::RansacParams::opencv_from_extern(ret) };
@@ -3117,7 +3374,7 @@ impl RansacParams {
 	#[inline]
 	pub fn new(size: i32, thresh: f32, eps: f32, prob: f32) -> Result<crate::videostab::RansacParams> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_RansacParams_RansacParams_int_float_float_float(size, thresh, eps, prob, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_RansacParams_RansacParams_int_float_float_float(size, thresh, eps, prob, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videostab::RansacParams::opencv_from_extern(ret) };
@@ -3131,7 +3388,7 @@ impl RansacParams {
 	#[inline]
 	pub fn default2d_motion(model: crate::videostab::MotionModel) -> Result<crate::videostab::RansacParams> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_RansacParams_default2dMotion_MotionModel(model, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_RansacParams_default2dMotion_MotionModel(model, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videostab::RansacParams::opencv_from_extern(ret) };
@@ -3150,6 +3407,7 @@ pub trait SparsePyrLkOptFlowEstimatorTrait: crate::videostab::ISparseOptFlowEsti
 
 	#[inline]
 	fn run(&mut self, frame0: &dyn core::ToInputArray, frame1: &dyn core::ToInputArray, points0: &dyn core::ToInputArray, points1: &mut dyn core::ToInputOutputArray, status: &mut dyn core::ToOutputArray, errors: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("SparsePyrLkOptFlowEstimator", self.as_raw_mut_SparsePyrLkOptFlowEstimator())?;
 		input_array_arg!(frame0);
 		input_array_arg!(frame1);
 		input_array_arg!(points0);
@@ -3157,7 +3415,7 @@ pub trait SparsePyrLkOptFlowEstimatorTrait: crate::videostab::ISparseOptFlowEsti
 		output_array_arg!(status);
 		output_array_arg!(errors);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_SparsePyrLkOptFlowEstimator_run_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_SparsePyrLkOptFlowEstimator(), frame0.as_raw__InputArray(), frame1.as_raw__InputArray(), points0.as_raw__InputArray(), points1.as_raw__InputOutputArray(), status.as_raw__OutputArray(), errors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_SparsePyrLkOptFlowEstimator_run_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_SparsePyrLkOptFlowEstimator(), frame0.as_raw__InputArray(), frame1.as_raw__InputArray(), points0.as_raw__InputArray(), points1.as_raw__InputOutputArray(), status.as_raw__OutputArray(), errors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3174,7 +3432,10 @@ opencv_type_boxed! { SparsePyrLkOptFlowEstimator }
 impl Drop for SparsePyrLkOptFlowEstimator {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SparsePyrLkOptFlowEstimator_delete(instance: *mut c_void); }
-		unsafe { cv_SparsePyrLkOptFlowEstimator_delete(self.as_raw_mut_SparsePyrLkOptFlowEstimator()) };
+		let ptr = self.as_raw_mut_SparsePyrLkOptFlowEstimator();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_SparsePyrLkOptFlowEstimator_delete(ptr) };
 	}
 }
 
@@ -3219,6 +3480,7 @@ pub trait SparsePyrLkOptFlowEstimatorGpuTrait: crate::videostab::ISparseOptFlowE
 
 	#[inline]
 	fn run(&mut self, frame0: &dyn core::ToInputArray, frame1: &dyn core::ToInputArray, points0: &dyn core::ToInputArray, points1: &mut dyn core::ToInputOutputArray, status: &mut dyn core::ToOutputArray, errors: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("SparsePyrLkOptFlowEstimatorGpu", self.as_raw_mut_SparsePyrLkOptFlowEstimatorGpu())?;
 		input_array_arg!(frame0);
 		input_array_arg!(frame1);
 		input_array_arg!(points0);
@@ -3226,7 +3488,7 @@ pub trait SparsePyrLkOptFlowEstimatorGpuTrait: crate::videostab::ISparseOptFlowE
 		output_array_arg!(status);
 		output_array_arg!(errors);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_SparsePyrLkOptFlowEstimatorGpu_run_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_SparsePyrLkOptFlowEstimatorGpu(), frame0.as_raw__InputArray(), frame1.as_raw__InputArray(), points0.as_raw__InputArray(), points1.as_raw__InputOutputArray(), status.as_raw__OutputArray(), errors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_SparsePyrLkOptFlowEstimatorGpu_run_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_SparsePyrLkOptFlowEstimatorGpu(), frame0.as_raw__InputArray(), frame1.as_raw__InputArray(), points0.as_raw__InputArray(), points1.as_raw__InputOutputArray(), status.as_raw__OutputArray(), errors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3234,8 +3496,9 @@ pub trait SparsePyrLkOptFlowEstimatorGpuTrait: crate::videostab::ISparseOptFlowE
 	
 	#[inline]
 	fn run_1(&mut self, frame0: &core::GpuMat, frame1: &core::GpuMat, points0: &core::GpuMat, points1: &mut core::GpuMat, status: &mut core::GpuMat, errors: &mut core::GpuMat) -> Result<()> {
+		crate::core::validity::check("SparsePyrLkOptFlowEstimatorGpu", self.as_raw_mut_SparsePyrLkOptFlowEstimatorGpu())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_SparsePyrLkOptFlowEstimatorGpu_run_const_GpuMatR_const_GpuMatR_const_GpuMatR_GpuMatR_GpuMatR_GpuMatR(self.as_raw_mut_SparsePyrLkOptFlowEstimatorGpu(), frame0.as_raw_GpuMat(), frame1.as_raw_GpuMat(), points0.as_raw_GpuMat(), points1.as_raw_mut_GpuMat(), status.as_raw_mut_GpuMat(), errors.as_raw_mut_GpuMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_SparsePyrLkOptFlowEstimatorGpu_run_const_GpuMatR_const_GpuMatR_const_GpuMatR_GpuMatR_GpuMatR_GpuMatR(self.as_raw_mut_SparsePyrLkOptFlowEstimatorGpu(), frame0.as_raw_GpuMat(), frame1.as_raw_GpuMat(), points0.as_raw_GpuMat(), points1.as_raw_mut_GpuMat(), status.as_raw_mut_GpuMat(), errors.as_raw_mut_GpuMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3243,8 +3506,9 @@ pub trait SparsePyrLkOptFlowEstimatorGpuTrait: crate::videostab::ISparseOptFlowE
 	
 	#[inline]
 	fn run_2(&mut self, frame0: &core::GpuMat, frame1: &core::GpuMat, points0: &core::GpuMat, points1: &mut core::GpuMat, status: &mut core::GpuMat) -> Result<()> {
+		crate::core::validity::check("SparsePyrLkOptFlowEstimatorGpu", self.as_raw_mut_SparsePyrLkOptFlowEstimatorGpu())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_SparsePyrLkOptFlowEstimatorGpu_run_const_GpuMatR_const_GpuMatR_const_GpuMatR_GpuMatR_GpuMatR(self.as_raw_mut_SparsePyrLkOptFlowEstimatorGpu(), frame0.as_raw_GpuMat(), frame1.as_raw_GpuMat(), points0.as_raw_GpuMat(), points1.as_raw_mut_GpuMat(), status.as_raw_mut_GpuMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_SparsePyrLkOptFlowEstimatorGpu_run_const_GpuMatR_const_GpuMatR_const_GpuMatR_GpuMatR_GpuMatR(self.as_raw_mut_SparsePyrLkOptFlowEstimatorGpu(), frame0.as_raw_GpuMat(), frame1.as_raw_GpuMat(), points0.as_raw_GpuMat(), points1.as_raw_mut_GpuMat(), status.as_raw_mut_GpuMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3261,7 +3525,10 @@ opencv_type_boxed! { SparsePyrLkOptFlowEstimatorGpu }
 impl Drop for SparsePyrLkOptFlowEstimatorGpu {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SparsePyrLkOptFlowEstimatorGpu_delete(instance: *mut c_void); }
-		unsafe { cv_SparsePyrLkOptFlowEstimatorGpu_delete(self.as_raw_mut_SparsePyrLkOptFlowEstimatorGpu()) };
+		let ptr = self.as_raw_mut_SparsePyrLkOptFlowEstimatorGpu();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_SparsePyrLkOptFlowEstimatorGpu_delete(ptr) };
 	}
 }
 
@@ -3295,7 +3562,7 @@ impl SparsePyrLkOptFlowEstimatorGpu {
 	#[inline]
 	pub fn default() -> Result<crate::videostab::SparsePyrLkOptFlowEstimatorGpu> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_SparsePyrLkOptFlowEstimatorGpu_SparsePyrLkOptFlowEstimatorGpu(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_SparsePyrLkOptFlowEstimatorGpu_SparsePyrLkOptFlowEstimatorGpu(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videostab::SparsePyrLkOptFlowEstimatorGpu::opencv_from_extern(ret) };
@@ -3311,8 +3578,9 @@ pub trait StabilizerBaseConst {
 
 	#[inline]
 	fn log(&self) -> Result<core::Ptr<dyn crate::videostab::ILog>> {
+		crate::core::validity::check("StabilizerBase", self.as_raw_StabilizerBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_StabilizerBase_log_const(self.as_raw_StabilizerBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_StabilizerBase_log_const(self.as_raw_StabilizerBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::videostab::ILog>::opencv_from_extern(ret) };
@@ -3321,8 +3589,9 @@ pub trait StabilizerBaseConst {
 	
 	#[inline]
 	fn radius(&self) -> Result<i32> {
+		crate::core::validity::check("StabilizerBase", self.as_raw_StabilizerBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_StabilizerBase_radius_const(self.as_raw_StabilizerBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_StabilizerBase_radius_const(self.as_raw_StabilizerBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3330,8 +3599,9 @@ pub trait StabilizerBaseConst {
 	
 	#[inline]
 	fn frame_source(&self) -> Result<core::Ptr<dyn crate::videostab::IFrameSource>> {
+		crate::core::validity::check("StabilizerBase", self.as_raw_StabilizerBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_StabilizerBase_frameSource_const(self.as_raw_StabilizerBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_StabilizerBase_frameSource_const(self.as_raw_StabilizerBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::videostab::IFrameSource>::opencv_from_extern(ret) };
@@ -3340,8 +3610,9 @@ pub trait StabilizerBaseConst {
 	
 	#[inline]
 	fn mask_source(&self) -> Result<core::Ptr<dyn crate::videostab::IFrameSource>> {
+		crate::core::validity::check("StabilizerBase", self.as_raw_StabilizerBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_StabilizerBase_maskSource_const(self.as_raw_StabilizerBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_StabilizerBase_maskSource_const(self.as_raw_StabilizerBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::videostab::IFrameSource>::opencv_from_extern(ret) };
@@ -3350,8 +3621,9 @@ pub trait StabilizerBaseConst {
 	
 	#[inline]
 	fn motion_estimator(&self) -> Result<core::Ptr<dyn crate::videostab::ImageMotionEstimatorBase>> {
+		crate::core::validity::check("StabilizerBase", self.as_raw_StabilizerBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_StabilizerBase_motionEstimator_const(self.as_raw_StabilizerBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_StabilizerBase_motionEstimator_const(self.as_raw_StabilizerBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::videostab::ImageMotionEstimatorBase>::opencv_from_extern(ret) };
@@ -3360,8 +3632,9 @@ pub trait StabilizerBaseConst {
 	
 	#[inline]
 	fn deblurrer(&self) -> Result<core::Ptr<dyn crate::videostab::DeblurerBase>> {
+		crate::core::validity::check("StabilizerBase", self.as_raw_StabilizerBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_StabilizerBase_deblurrer_const(self.as_raw_StabilizerBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_StabilizerBase_deblurrer_const(self.as_raw_StabilizerBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::videostab::DeblurerBase>::opencv_from_extern(ret) };
@@ -3370,8 +3643,9 @@ pub trait StabilizerBaseConst {
 	
 	#[inline]
 	fn trim_ratio(&self) -> Result<f32> {
+		crate::core::validity::check("StabilizerBase", self.as_raw_StabilizerBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_StabilizerBase_trimRatio_const(self.as_raw_StabilizerBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_StabilizerBase_trimRatio_const(self.as_raw_StabilizerBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3379,8 +3653,9 @@ pub trait StabilizerBaseConst {
 	
 	#[inline]
 	fn do_correction_for_inclusion(&self) -> Result<bool> {
+		crate::core::validity::check("StabilizerBase", self.as_raw_StabilizerBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_StabilizerBase_doCorrectionForInclusion_const(self.as_raw_StabilizerBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_StabilizerBase_doCorrectionForInclusion_const(self.as_raw_StabilizerBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3388,8 +3663,9 @@ pub trait StabilizerBaseConst {
 	
 	#[inline]
 	fn border_mode(&self) -> Result<i32> {
+		crate::core::validity::check("StabilizerBase", self.as_raw_StabilizerBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_StabilizerBase_borderMode_const(self.as_raw_StabilizerBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_StabilizerBase_borderMode_const(self.as_raw_StabilizerBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3397,8 +3673,9 @@ pub trait StabilizerBaseConst {
 	
 	#[inline]
 	fn inpainter(&self) -> Result<core::Ptr<dyn crate::videostab::InpainterBase>> {
+		crate::core::validity::check("StabilizerBase", self.as_raw_StabilizerBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_StabilizerBase_inpainter_const(self.as_raw_StabilizerBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_StabilizerBase_inpainter_const(self.as_raw_StabilizerBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::videostab::InpainterBase>::opencv_from_extern(ret) };
@@ -3412,8 +3689,9 @@ pub trait StabilizerBase: crate::videostab::StabilizerBaseConst {
 
 	#[inline]
 	fn set_log(&mut self, mut ilog: core::Ptr<dyn crate::videostab::ILog>) -> Result<()> {
+		crate::core::validity::check("StabilizerBase", self.as_raw_mut_StabilizerBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_StabilizerBase_setLog_Ptr_ILog_(self.as_raw_mut_StabilizerBase(), ilog.as_raw_mut_PtrOfILog(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_StabilizerBase_setLog_Ptr_ILog_(self.as_raw_mut_StabilizerBase(), ilog.as_raw_mut_PtrOfILog(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3421,8 +3699,9 @@ pub trait StabilizerBase: crate::videostab::StabilizerBaseConst {
 	
 	#[inline]
 	fn set_radius(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("StabilizerBase", self.as_raw_mut_StabilizerBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_StabilizerBase_setRadius_int(self.as_raw_mut_StabilizerBase(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_StabilizerBase_setRadius_int(self.as_raw_mut_StabilizerBase(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3430,8 +3709,9 @@ pub trait StabilizerBase: crate::videostab::StabilizerBaseConst {
 	
 	#[inline]
 	fn set_frame_source(&mut self, mut val: core::Ptr<dyn crate::videostab::IFrameSource>) -> Result<()> {
+		crate::core::validity::check("StabilizerBase", self.as_raw_mut_StabilizerBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_StabilizerBase_setFrameSource_Ptr_IFrameSource_(self.as_raw_mut_StabilizerBase(), val.as_raw_mut_PtrOfIFrameSource(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_StabilizerBase_setFrameSource_Ptr_IFrameSource_(self.as_raw_mut_StabilizerBase(), val.as_raw_mut_PtrOfIFrameSource(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3439,8 +3719,9 @@ pub trait StabilizerBase: crate::videostab::StabilizerBaseConst {
 	
 	#[inline]
 	fn set_mask_source(&mut self, val: &core::Ptr<dyn crate::videostab::IFrameSource>) -> Result<()> {
+		crate::core::validity::check("StabilizerBase", self.as_raw_mut_StabilizerBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_StabilizerBase_setMaskSource_const_Ptr_IFrameSource_R(self.as_raw_mut_StabilizerBase(), val.as_raw_PtrOfIFrameSource(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_StabilizerBase_setMaskSource_const_Ptr_IFrameSource_R(self.as_raw_mut_StabilizerBase(), val.as_raw_PtrOfIFrameSource(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3448,8 +3729,9 @@ pub trait StabilizerBase: crate::videostab::StabilizerBaseConst {
 	
 	#[inline]
 	fn set_motion_estimator(&mut self, mut val: core::Ptr<dyn crate::videostab::ImageMotionEstimatorBase>) -> Result<()> {
+		crate::core::validity::check("StabilizerBase", self.as_raw_mut_StabilizerBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_StabilizerBase_setMotionEstimator_Ptr_ImageMotionEstimatorBase_(self.as_raw_mut_StabilizerBase(), val.as_raw_mut_PtrOfImageMotionEstimatorBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_StabilizerBase_setMotionEstimator_Ptr_ImageMotionEstimatorBase_(self.as_raw_mut_StabilizerBase(), val.as_raw_mut_PtrOfImageMotionEstimatorBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3457,8 +3739,9 @@ pub trait StabilizerBase: crate::videostab::StabilizerBaseConst {
 	
 	#[inline]
 	fn set_deblurer(&mut self, mut val: core::Ptr<dyn crate::videostab::DeblurerBase>) -> Result<()> {
+		crate::core::validity::check("StabilizerBase", self.as_raw_mut_StabilizerBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_StabilizerBase_setDeblurer_Ptr_DeblurerBase_(self.as_raw_mut_StabilizerBase(), val.as_raw_mut_PtrOfDeblurerBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_StabilizerBase_setDeblurer_Ptr_DeblurerBase_(self.as_raw_mut_StabilizerBase(), val.as_raw_mut_PtrOfDeblurerBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3466,8 +3749,9 @@ pub trait StabilizerBase: crate::videostab::StabilizerBaseConst {
 	
 	#[inline]
 	fn set_trim_ratio(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("StabilizerBase", self.as_raw_mut_StabilizerBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_StabilizerBase_setTrimRatio_float(self.as_raw_mut_StabilizerBase(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_StabilizerBase_setTrimRatio_float(self.as_raw_mut_StabilizerBase(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3475,8 +3759,9 @@ pub trait StabilizerBase: crate::videostab::StabilizerBaseConst {
 	
 	#[inline]
 	fn set_correction_for_inclusion(&mut self, val: bool) -> Result<()> {
+		crate::core::validity::check("StabilizerBase", self.as_raw_mut_StabilizerBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_StabilizerBase_setCorrectionForInclusion_bool(self.as_raw_mut_StabilizerBase(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_StabilizerBase_setCorrectionForInclusion_bool(self.as_raw_mut_StabilizerBase(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3484,8 +3769,9 @@ pub trait StabilizerBase: crate::videostab::StabilizerBaseConst {
 	
 	#[inline]
 	fn set_border_mode(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("StabilizerBase", self.as_raw_mut_StabilizerBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_StabilizerBase_setBorderMode_int(self.as_raw_mut_StabilizerBase(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_StabilizerBase_setBorderMode_int(self.as_raw_mut_StabilizerBase(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3493,8 +3779,9 @@ pub trait StabilizerBase: crate::videostab::StabilizerBaseConst {
 	
 	#[inline]
 	fn set_inpainter(&mut self, mut val: core::Ptr<dyn crate::videostab::InpainterBase>) -> Result<()> {
+		crate::core::validity::check("StabilizerBase", self.as_raw_mut_StabilizerBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_StabilizerBase_setInpainter_Ptr_InpainterBase_(self.as_raw_mut_StabilizerBase(), val.as_raw_mut_PtrOfInpainterBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_StabilizerBase_setInpainter_Ptr_InpainterBase_(self.as_raw_mut_StabilizerBase(), val.as_raw_mut_PtrOfInpainterBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3507,8 +3794,9 @@ pub trait ToFileMotionWriterTraitConst: crate::videostab::ImageMotionEstimatorBa
 
 	#[inline]
 	fn motion_model(&self) -> Result<crate::videostab::MotionModel> {
+		crate::core::validity::check("ToFileMotionWriter", self.as_raw_ToFileMotionWriter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_ToFileMotionWriter_motionModel_const(self.as_raw_ToFileMotionWriter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_ToFileMotionWriter_motionModel_const(self.as_raw_ToFileMotionWriter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3521,8 +3809,9 @@ pub trait ToFileMotionWriterTrait: crate::videostab::ImageMotionEstimatorBase +
 
 	#[inline]
 	fn set_motion_model(&mut self, val: crate::videostab::MotionModel) -> Result<()> {
+		crate::core::validity::check("ToFileMotionWriter", self.as_raw_mut_ToFileMotionWriter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_ToFileMotionWriter_setMotionModel_MotionModel(self.as_raw_mut_ToFileMotionWriter(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_ToFileMotionWriter_setMotionModel_MotionModel(self.as_raw_mut_ToFileMotionWriter(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3530,9 +3819,10 @@ pub trait ToFileMotionWriterTrait: crate::videostab::ImageMotionEstimatorBase +
 	
 	#[inline]
 	fn set_frame_mask(&mut self, mask: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("ToFileMotionWriter", self.as_raw_mut_ToFileMotionWriter())?;
 		input_array_arg!(mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_ToFileMotionWriter_setFrameMask_const__InputArrayR(self.as_raw_mut_ToFileMotionWriter(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_ToFileMotionWriter_setFrameMask_const__InputArrayR(self.as_raw_mut_ToFileMotionWriter(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3542,8 +3832,9 @@ pub trait ToFileMotionWriterTrait: crate::videostab::ImageMotionEstimatorBase +
 	/// * ok: 0
 	#[inline]
 	fn estimate(&mut self, frame0: &core::Mat, frame1: &core::Mat, ok: &mut bool) -> Result<core::Mat> {
+		crate::core::validity::check("ToFileMotionWriter", self.as_raw_mut_ToFileMotionWriter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_ToFileMotionWriter_estimate_const_MatR_const_MatR_boolX(self.as_raw_mut_ToFileMotionWriter(), frame0.as_raw_Mat(), frame1.as_raw_Mat(), ok, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_ToFileMotionWriter_estimate_const_MatR_const_MatR_boolX(self.as_raw_mut_ToFileMotionWriter(), frame0.as_raw_Mat(), frame1.as_raw_Mat(), ok, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -3561,7 +3852,10 @@ opencv_type_boxed! { ToFileMotionWriter }
 impl Drop for ToFileMotionWriter {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ToFileMotionWriter_delete(instance: *mut c_void); }
-		unsafe { cv_ToFileMotionWriter_delete(self.as_raw_mut_ToFileMotionWriter()) };
+		let ptr = self.as_raw_mut_ToFileMotionWriter();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ToFileMotionWriter_delete(ptr) };
 	}
 }
 
@@ -3588,7 +3882,7 @@ impl ToFileMotionWriter {
 	pub fn new(path: &str, mut estimator: core::Ptr<dyn crate::videostab::ImageMotionEstimatorBase>) -> Result<crate::videostab::ToFileMotionWriter> {
 		extern_container_arg!(path);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_ToFileMotionWriter_ToFileMotionWriter_const_StringR_Ptr_ImageMotionEstimatorBase_(path.opencv_as_extern(), estimator.as_raw_mut_PtrOfImageMotionEstimatorBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_ToFileMotionWriter_ToFileMotionWriter_const_StringR_Ptr_ImageMotionEstimatorBase_(path.opencv_as_extern(), estimator.as_raw_mut_PtrOfImageMotionEstimatorBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videostab::ToFileMotionWriter::opencv_from_extern(ret) };
@@ -3602,8 +3896,9 @@ pub trait TranslationBasedLocalOutlierRejectorTraitConst: crate::videostab::IOut
 
 	#[inline]
 	fn cell_size(&self) -> Result<core::Size> {
+		crate::core::validity::check("TranslationBasedLocalOutlierRejector", self.as_raw_TranslationBasedLocalOutlierRejector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_TranslationBasedLocalOutlierRejector_cellSize_const(self.as_raw_TranslationBasedLocalOutlierRejector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_TranslationBasedLocalOutlierRejector_cellSize_const(self.as_raw_TranslationBasedLocalOutlierRejector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3611,8 +3906,9 @@ pub trait TranslationBasedLocalOutlierRejectorTraitConst: crate::videostab::IOut
 	
 	#[inline]
 	fn ransac_params(&self) -> Result<crate::videostab::RansacParams> {
+		crate::core::validity::check("TranslationBasedLocalOutlierRejector", self.as_raw_TranslationBasedLocalOutlierRejector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_TranslationBasedLocalOutlierRejector_ransacParams_const(self.as_raw_TranslationBasedLocalOutlierRejector(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_TranslationBasedLocalOutlierRejector_ransacParams_const(self.as_raw_TranslationBasedLocalOutlierRejector(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videostab::RansacParams::opencv_from_extern(ret) };
@@ -3626,8 +3922,9 @@ pub trait TranslationBasedLocalOutlierRejectorTrait: crate::videostab::IOutlierR
 
 	#[inline]
 	fn set_cell_size(&mut self, val: core::Size) -> Result<()> {
+		crate::core::validity::check("TranslationBasedLocalOutlierRejector", self.as_raw_mut_TranslationBasedLocalOutlierRejector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_TranslationBasedLocalOutlierRejector_setCellSize_Size(self.as_raw_mut_TranslationBasedLocalOutlierRejector(), val.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_TranslationBasedLocalOutlierRejector_setCellSize_Size(self.as_raw_mut_TranslationBasedLocalOutlierRejector(), val.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3635,8 +3932,9 @@ pub trait TranslationBasedLocalOutlierRejectorTrait: crate::videostab::IOutlierR
 	
 	#[inline]
 	fn set_ransac_params(&mut self, mut val: crate::videostab::RansacParams) -> Result<()> {
+		crate::core::validity::check("TranslationBasedLocalOutlierRejector", self.as_raw_mut_TranslationBasedLocalOutlierRejector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_TranslationBasedLocalOutlierRejector_setRansacParams_RansacParams(self.as_raw_mut_TranslationBasedLocalOutlierRejector(), val.as_raw_mut_RansacParams(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_TranslationBasedLocalOutlierRejector_setRansacParams_RansacParams(self.as_raw_mut_TranslationBasedLocalOutlierRejector(), val.as_raw_mut_RansacParams(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3644,11 +3942,12 @@ pub trait TranslationBasedLocalOutlierRejectorTrait: crate::videostab::IOutlierR
 	
 	#[inline]
 	fn process(&mut self, frame_size: core::Size, points0: &dyn core::ToInputArray, points1: &dyn core::ToInputArray, mask: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("TranslationBasedLocalOutlierRejector", self.as_raw_mut_TranslationBasedLocalOutlierRejector())?;
 		input_array_arg!(points0);
 		input_array_arg!(points1);
 		output_array_arg!(mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_TranslationBasedLocalOutlierRejector_process_Size_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_TranslationBasedLocalOutlierRejector(), frame_size.opencv_as_extern(), points0.as_raw__InputArray(), points1.as_raw__InputArray(), mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_TranslationBasedLocalOutlierRejector_process_Size_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_TranslationBasedLocalOutlierRejector(), frame_size.opencv_as_extern(), points0.as_raw__InputArray(), points1.as_raw__InputArray(), mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3665,7 +3964,10 @@ opencv_type_boxed! { TranslationBasedLocalOutlierRejector }
 impl Drop for TranslationBasedLocalOutlierRejector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_TranslationBasedLocalOutlierRejector_delete(instance: *mut c_void); }
-		unsafe { cv_TranslationBasedLocalOutlierRejector_delete(self.as_raw_mut_TranslationBasedLocalOutlierRejector()) };
+		let ptr = self.as_raw_mut_TranslationBasedLocalOutlierRejector();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_TranslationBasedLocalOutlierRejector_delete(ptr) };
 	}
 }
 
@@ -3691,7 +3993,7 @@ impl TranslationBasedLocalOutlierRejector {
 	#[inline]
 	pub fn default() -> Result<crate::videostab::TranslationBasedLocalOutlierRejector> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_TranslationBasedLocalOutlierRejector_TranslationBasedLocalOutlierRejector(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_TranslationBasedLocalOutlierRejector_TranslationBasedLocalOutlierRejector(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videostab::TranslationBasedLocalOutlierRejector::opencv_from_extern(ret) };
@@ -3705,8 +4007,9 @@ pub trait TwoPassStabilizerTraitConst: crate::videostab::IFrameSourceConst + cra
 
 	#[inline]
 	fn motion_stabilizer(&self) -> Result<core::Ptr<dyn crate::videostab::IMotionStabilizer>> {
+		crate::core::validity::check("TwoPassStabilizer", self.as_raw_TwoPassStabilizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_TwoPassStabilizer_motionStabilizer_const(self.as_raw_TwoPassStabilizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_TwoPassStabilizer_motionStabilizer_const(self.as_raw_TwoPassStabilizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::videostab::IMotionStabilizer>::opencv_from_extern(ret) };
@@ -3715,8 +4018,9 @@ pub trait TwoPassStabilizerTraitConst: crate::videostab::IFrameSourceConst + cra
 	
 	#[inline]
 	fn wobble_suppressor(&self) -> Result<core::Ptr<dyn crate::videostab::WobbleSuppressorBase>> {
+		crate::core::validity::check("TwoPassStabilizer", self.as_raw_TwoPassStabilizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_TwoPassStabilizer_wobbleSuppressor_const(self.as_raw_TwoPassStabilizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_TwoPassStabilizer_wobbleSuppressor_const(self.as_raw_TwoPassStabilizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::videostab::WobbleSuppressorBase>::opencv_from_extern(ret) };
@@ -3725,8 +4029,9 @@ pub trait TwoPassStabilizerTraitConst: crate::videostab::IFrameSourceConst + cra
 	
 	#[inline]
 	fn must_estimate_trima_ratio(&self) -> Result<bool> {
+		crate::core::validity::check("TwoPassStabilizer", self.as_raw_TwoPassStabilizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_TwoPassStabilizer_mustEstimateTrimaRatio_const(self.as_raw_TwoPassStabilizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_TwoPassStabilizer_mustEstimateTrimaRatio_const(self.as_raw_TwoPassStabilizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3739,8 +4044,9 @@ pub trait TwoPassStabilizerTrait: crate::videostab::IFrameSource + crate::videos
 
 	#[inline]
 	fn set_motion_stabilizer(&mut self, mut val: core::Ptr<dyn crate::videostab::IMotionStabilizer>) -> Result<()> {
+		crate::core::validity::check("TwoPassStabilizer", self.as_raw_mut_TwoPassStabilizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_TwoPassStabilizer_setMotionStabilizer_Ptr_IMotionStabilizer_(self.as_raw_mut_TwoPassStabilizer(), val.as_raw_mut_PtrOfIMotionStabilizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_TwoPassStabilizer_setMotionStabilizer_Ptr_IMotionStabilizer_(self.as_raw_mut_TwoPassStabilizer(), val.as_raw_mut_PtrOfIMotionStabilizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3748,8 +4054,9 @@ pub trait TwoPassStabilizerTrait: crate::videostab::IFrameSource + crate::videos
 	
 	#[inline]
 	fn set_wobble_suppressor(&mut self, mut val: core::Ptr<dyn crate::videostab::WobbleSuppressorBase>) -> Result<()> {
+		crate::core::validity::check("TwoPassStabilizer", self.as_raw_mut_TwoPassStabilizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_TwoPassStabilizer_setWobbleSuppressor_Ptr_WobbleSuppressorBase_(self.as_raw_mut_TwoPassStabilizer(), val.as_raw_mut_PtrOfWobbleSuppressorBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_TwoPassStabilizer_setWobbleSuppressor_Ptr_WobbleSuppressorBase_(self.as_raw_mut_TwoPassStabilizer(), val.as_raw_mut_PtrOfWobbleSuppressorBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3757,8 +4064,9 @@ pub trait TwoPassStabilizerTrait: crate::videostab::IFrameSource + crate::videos
 	
 	#[inline]
 	fn set_estimate_trim_ratio(&mut self, val: bool) -> Result<()> {
+		crate::core::validity::check("TwoPassStabilizer", self.as_raw_mut_TwoPassStabilizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_TwoPassStabilizer_setEstimateTrimRatio_bool(self.as_raw_mut_TwoPassStabilizer(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_TwoPassStabilizer_setEstimateTrimRatio_bool(self.as_raw_mut_TwoPassStabilizer(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3766,8 +4074,9 @@ pub trait TwoPassStabilizerTrait: crate::videostab::IFrameSource + crate::videos
 	
 	#[inline]
 	fn reset(&mut self) -> Result<()> {
+		crate::core::validity::check("TwoPassStabilizer", self.as_raw_mut_TwoPassStabilizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_TwoPassStabilizer_reset(self.as_raw_mut_TwoPassStabilizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_TwoPassStabilizer_reset(self.as_raw_mut_TwoPassStabilizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3775,8 +4084,9 @@ pub trait TwoPassStabilizerTrait: crate::videostab::IFrameSource + crate::videos
 	
 	#[inline]
 	fn next_frame(&mut self) -> Result<core::Mat> {
+		crate::core::validity::check("TwoPassStabilizer", self.as_raw_mut_TwoPassStabilizer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_TwoPassStabilizer_nextFrame(self.as_raw_mut_TwoPassStabilizer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_TwoPassStabilizer_nextFrame(self.as_raw_mut_TwoPassStabilizer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -3794,7 +4104,10 @@ opencv_type_boxed! { TwoPassStabilizer }
 impl Drop for TwoPassStabilizer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_TwoPassStabilizer_delete(instance: *mut c_void); }
-		unsafe { cv_TwoPassStabilizer_delete(self.as_raw_mut_TwoPassStabilizer()) };
+		let ptr = self.as_raw_mut_TwoPassStabilizer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_TwoPassStabilizer_delete(ptr) };
 	}
 }
 
@@ -3828,7 +4141,7 @@ impl TwoPassStabilizer {
 	#[inline]
 	pub fn default() -> Result<crate::videostab::TwoPassStabilizer> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_TwoPassStabilizer_TwoPassStabilizer(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_TwoPassStabilizer_TwoPassStabilizer(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videostab::TwoPassStabilizer::opencv_from_extern(ret) };
@@ -3847,8 +4160,9 @@ pub trait VideoFileSourceTrait: crate::videostab::IFrameSource + crate::videosta
 
 	#[inline]
 	fn reset(&mut self) -> Result<()> {
+		crate::core::validity::check("VideoFileSource", self.as_raw_mut_VideoFileSource())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_VideoFileSource_reset(self.as_raw_mut_VideoFileSource(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_VideoFileSource_reset(self.as_raw_mut_VideoFileSource(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3856,8 +4170,9 @@ pub trait VideoFileSourceTrait: crate::videostab::IFrameSource + crate::videosta
 	
 	#[inline]
 	fn next_frame(&mut self) -> Result<core::Mat> {
+		crate::core::validity::check("VideoFileSource", self.as_raw_mut_VideoFileSource())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_VideoFileSource_nextFrame(self.as_raw_mut_VideoFileSource(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_VideoFileSource_nextFrame(self.as_raw_mut_VideoFileSource(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -3866,8 +4181,9 @@ pub trait VideoFileSourceTrait: crate::videostab::IFrameSource + crate::videosta
 	
 	#[inline]
 	fn width(&mut self) -> Result<i32> {
+		crate::core::validity::check("VideoFileSource", self.as_raw_mut_VideoFileSource())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_VideoFileSource_width(self.as_raw_mut_VideoFileSource(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_VideoFileSource_width(self.as_raw_mut_VideoFileSource(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3875,8 +4191,9 @@ pub trait VideoFileSourceTrait: crate::videostab::IFrameSource + crate::videosta
 	
 	#[inline]
 	fn height(&mut self) -> Result<i32> {
+		crate::core::validity::check("VideoFileSource", self.as_raw_mut_VideoFileSource())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_VideoFileSource_height(self.as_raw_mut_VideoFileSource(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_VideoFileSource_height(self.as_raw_mut_VideoFileSource(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3884,8 +4201,9 @@ pub trait VideoFileSourceTrait: crate::videostab::IFrameSource + crate::videosta
 	
 	#[inline]
 	fn count(&mut self) -> Result<i32> {
+		crate::core::validity::check("VideoFileSource", self.as_raw_mut_VideoFileSource())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_VideoFileSource_count(self.as_raw_mut_VideoFileSource(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_VideoFileSource_count(self.as_raw_mut_VideoFileSource(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3893,8 +4211,9 @@ pub trait VideoFileSourceTrait: crate::videostab::IFrameSource + crate::videosta
 	
 	#[inline]
 	fn fps(&mut self) -> Result<f64> {
+		crate::core::validity::check("VideoFileSource", self.as_raw_mut_VideoFileSource())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_VideoFileSource_fps(self.as_raw_mut_VideoFileSource(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_VideoFileSource_fps(self.as_raw_mut_VideoFileSource(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3911,7 +4230,10 @@ opencv_type_boxed! { VideoFileSource }
 impl Drop for VideoFileSource {
 	fn drop(&mut self) {
 		extern "C" { fn cv_VideoFileSource_delete(instance: *mut c_void); }
-		unsafe { cv_VideoFileSource_delete(self.as_raw_mut_VideoFileSource()) };
+		let ptr = self.as_raw_mut_VideoFileSource();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_VideoFileSource_delete(ptr) };
 	}
 }
 
@@ -3940,7 +4262,7 @@ impl VideoFileSource {
 	pub fn new(path: &str, volatile_frame: bool) -> Result<crate::videostab::VideoFileSource> {
 		extern_container_arg!(path);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_VideoFileSource_VideoFileSource_const_StringR_bool(path.opencv_as_extern(), volatile_frame, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_VideoFileSource_VideoFileSource_const_StringR_bool(path.opencv_as_extern(), volatile_frame, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videostab::VideoFileSource::opencv_from_extern(ret) };
@@ -3954,8 +4276,9 @@ pub trait WeightingDeblurerTraitConst: crate::videostab::DeblurerBaseConst {
 
 	#[inline]
 	fn sensitivity(&self) -> Result<f32> {
+		crate::core::validity::check("WeightingDeblurer", self.as_raw_WeightingDeblurer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_WeightingDeblurer_sensitivity_const(self.as_raw_WeightingDeblurer(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_WeightingDeblurer_sensitivity_const(self.as_raw_WeightingDeblurer(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3968,8 +4291,9 @@ pub trait WeightingDeblurerTrait: crate::videostab::DeblurerBase + crate::videos
 
 	#[inline]
 	fn set_sensitivity(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("WeightingDeblurer", self.as_raw_mut_WeightingDeblurer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_WeightingDeblurer_setSensitivity_float(self.as_raw_mut_WeightingDeblurer(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_WeightingDeblurer_setSensitivity_float(self.as_raw_mut_WeightingDeblurer(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3977,8 +4301,9 @@ pub trait WeightingDeblurerTrait: crate::videostab::DeblurerBase + crate::videos
 	
 	#[inline]
 	fn deblur(&mut self, idx: i32, frame: &mut core::Mat, range: &core::Range) -> Result<()> {
+		crate::core::validity::check("WeightingDeblurer", self.as_raw_mut_WeightingDeblurer())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_WeightingDeblurer_deblur_int_MatR_const_RangeR(self.as_raw_mut_WeightingDeblurer(), idx, frame.as_raw_mut_Mat(), range.as_raw_Range(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_WeightingDeblurer_deblur_int_MatR_const_RangeR(self.as_raw_mut_WeightingDeblurer(), idx, frame.as_raw_mut_Mat(), range.as_raw_Range(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3995,7 +4320,10 @@ opencv_type_boxed! { WeightingDeblurer }
 impl Drop for WeightingDeblurer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WeightingDeblurer_delete(instance: *mut c_void); }
-		unsafe { cv_WeightingDeblurer_delete(self.as_raw_mut_WeightingDeblurer()) };
+		let ptr = self.as_raw_mut_WeightingDeblurer();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_WeightingDeblurer_delete(ptr) };
 	}
 }
 
@@ -4021,7 +4349,7 @@ impl WeightingDeblurer {
 	#[inline]
 	pub fn default() -> Result<crate::videostab::WeightingDeblurer> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_WeightingDeblurer_WeightingDeblurer(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_WeightingDeblurer_WeightingDeblurer(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::videostab::WeightingDeblurer::opencv_from_extern(ret) };
@@ -4035,8 +4363,9 @@ pub trait WobbleSuppressorBaseConst {
 
 	#[inline]
 	fn motion_estimator(&self) -> Result<core::Ptr<dyn crate::videostab::ImageMotionEstimatorBase>> {
+		crate::core::validity::check("WobbleSuppressorBase", self.as_raw_WobbleSuppressorBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_WobbleSuppressorBase_motionEstimator_const(self.as_raw_WobbleSuppressorBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_WobbleSuppressorBase_motionEstimator_const(self.as_raw_WobbleSuppressorBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::videostab::ImageMotionEstimatorBase>::opencv_from_extern(ret) };
@@ -4045,8 +4374,9 @@ pub trait WobbleSuppressorBaseConst {
 	
 	#[inline]
 	fn frame_count(&self) -> Result<i32> {
+		crate::core::validity::check("WobbleSuppressorBase", self.as_raw_WobbleSuppressorBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_WobbleSuppressorBase_frameCount_const(self.as_raw_WobbleSuppressorBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_WobbleSuppressorBase_frameCount_const(self.as_raw_WobbleSuppressorBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4054,8 +4384,9 @@ pub trait WobbleSuppressorBaseConst {
 	
 	#[inline]
 	fn motions(&self) -> Result<core::Vector<core::Mat>> {
+		crate::core::validity::check("WobbleSuppressorBase", self.as_raw_WobbleSuppressorBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_WobbleSuppressorBase_motions_const(self.as_raw_WobbleSuppressorBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_WobbleSuppressorBase_motions_const(self.as_raw_WobbleSuppressorBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<core::Mat>::opencv_from_extern(ret) };
@@ -4064,8 +4395,9 @@ pub trait WobbleSuppressorBaseConst {
 	
 	#[inline]
 	fn motions2(&self) -> Result<core::Vector<core::Mat>> {
+		crate::core::validity::check("WobbleSuppressorBase", self.as_raw_WobbleSuppressorBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_WobbleSuppressorBase_motions2_const(self.as_raw_WobbleSuppressorBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_WobbleSuppressorBase_motions2_const(self.as_raw_WobbleSuppressorBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<core::Mat>::opencv_from_extern(ret) };
@@ -4074,8 +4406,9 @@ pub trait WobbleSuppressorBaseConst {
 	
 	#[inline]
 	fn stabilization_motions(&self) -> Result<core::Vector<core::Mat>> {
+		crate::core::validity::check("WobbleSuppressorBase", self.as_raw_WobbleSuppressorBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_WobbleSuppressorBase_stabilizationMotions_const(self.as_raw_WobbleSuppressorBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_WobbleSuppressorBase_stabilizationMotions_const(self.as_raw_WobbleSuppressorBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<core::Mat>::opencv_from_extern(ret) };
@@ -4089,8 +4422,9 @@ pub trait WobbleSuppressorBase: crate::videostab::WobbleSuppressorBaseConst {
 
 	#[inline]
 	fn set_motion_estimator(&mut self, mut val: core::Ptr<dyn crate::videostab::ImageMotionEstimatorBase>) -> Result<()> {
+		crate::core::validity::check("WobbleSuppressorBase", self.as_raw_mut_WobbleSuppressorBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_WobbleSuppressorBase_setMotionEstimator_Ptr_ImageMotionEstimatorBase_(self.as_raw_mut_WobbleSuppressorBase(), val.as_raw_mut_PtrOfImageMotionEstimatorBase(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_WobbleSuppressorBase_setMotionEstimator_Ptr_ImageMotionEstimatorBase_(self.as_raw_mut_WobbleSuppressorBase(), val.as_raw_mut_PtrOfImageMotionEstimatorBase(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4098,8 +4432,9 @@ pub trait WobbleSuppressorBase: crate::videostab::WobbleSuppressorBaseConst {
 	
 	#[inline]
 	fn suppress(&mut self, idx: i32, frame: &core::Mat, result: &mut core::Mat) -> Result<()> {
+		crate::core::validity::check("WobbleSuppressorBase", self.as_raw_mut_WobbleSuppressorBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_WobbleSuppressorBase_suppress_int_const_MatR_MatR(self.as_raw_mut_WobbleSuppressorBase(), idx, frame.as_raw_Mat(), result.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_WobbleSuppressorBase_suppress_int_const_MatR_MatR(self.as_raw_mut_WobbleSuppressorBase(), idx, frame.as_raw_Mat(), result.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4107,8 +4442,9 @@ pub trait WobbleSuppressorBase: crate::videostab::WobbleSuppressorBaseConst {
 	
 	#[inline]
 	fn set_frame_count(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("WobbleSuppressorBase", self.as_raw_mut_WobbleSuppressorBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_WobbleSuppressorBase_setFrameCount_int(self.as_raw_mut_WobbleSuppressorBase(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_WobbleSuppressorBase_setFrameCount_int(self.as_raw_mut_WobbleSuppressorBase(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4116,8 +4452,9 @@ pub trait WobbleSuppressorBase: crate::videostab::WobbleSuppressorBaseConst {
 	
 	#[inline]
 	fn set_motions(&mut self, val: &core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("WobbleSuppressorBase", self.as_raw_mut_WobbleSuppressorBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_WobbleSuppressorBase_setMotions_const_vector_Mat_R(self.as_raw_mut_WobbleSuppressorBase(), val.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_WobbleSuppressorBase_setMotions_const_vector_Mat_R(self.as_raw_mut_WobbleSuppressorBase(), val.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4125,8 +4462,9 @@ pub trait WobbleSuppressorBase: crate::videostab::WobbleSuppressorBaseConst {
 	
 	#[inline]
 	fn set_motions2(&mut self, val: &core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("WobbleSuppressorBase", self.as_raw_mut_WobbleSuppressorBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_WobbleSuppressorBase_setMotions2_const_vector_Mat_R(self.as_raw_mut_WobbleSuppressorBase(), val.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_WobbleSuppressorBase_setMotions2_const_vector_Mat_R(self.as_raw_mut_WobbleSuppressorBase(), val.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4134,11 +4472,13 @@ pub trait WobbleSuppressorBase: crate::videostab::WobbleSuppressorBaseConst {
 	
 	#[inline]
 	fn set_stabilization_motions(&mut self, val: &core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("WobbleSuppressorBase", self.as_raw_mut_WobbleSuppressorBase())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_videostab_WobbleSuppressorBase_setStabilizationMotions_const_vector_Mat_R(self.as_raw_mut_WobbleSuppressorBase(), val.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_videostab_WobbleSuppressorBase_setStabilizationMotions_const_vector_Mat_R(self.as_raw_mut_WobbleSuppressorBase(), val.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 }
+pub use crate::manual::videostab::*;
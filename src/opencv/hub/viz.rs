@@ -145,7 +145,7 @@ pub type Viz3d_MouseCallback = Option<Box<dyn FnMut(*const c_void) -> () + Send
 pub fn compute_normals(mesh: &crate::viz::Mesh, normals: &mut dyn core::ToOutputArray) -> Result<()> {
 	output_array_arg!(normals);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_viz_computeNormals_const_MeshR_const__OutputArrayR(mesh.as_raw_Mesh(), normals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_viz_computeNormals_const_MeshR_const__OutputArrayR(mesh.as_raw_Mesh(), normals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -165,7 +165,7 @@ pub fn compute_normals(mesh: &crate::viz::Mesh, normals: &mut dyn core::ToOutput
 pub fn get_window_by_name(window_name: &str) -> Result<crate::viz::Viz3d> {
 	extern_container_arg!(window_name);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_viz_getWindowByName_const_StringR(window_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_viz_getWindowByName_const_StringR(window_name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { crate::viz::Viz3d::opencv_from_extern(ret) };
@@ -181,7 +181,7 @@ pub fn imshow(window_name: &str, image: &dyn core::ToInputArray, window_size: co
 	extern_container_arg!(window_name);
 	input_array_arg!(image);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_viz_imshow_const_StringR_const__InputArrayR_const_SizeR(window_name.opencv_as_extern(), image.as_raw__InputArray(), &window_size, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_viz_imshow_const_StringR_const__InputArrayR_const_SizeR(window_name.opencv_as_extern(), image.as_raw__InputArray(), &window_size, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { crate::viz::Viz3d::opencv_from_extern(ret) };
@@ -200,7 +200,7 @@ pub fn imshow(window_name: &str, image: &dyn core::ToInputArray, window_size: co
 #[inline]
 pub fn make_camera_pose(position: core::Vec3d, focal_point: core::Vec3d, y_dir: core::Vec3d) -> Result<core::Affine3d> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_viz_makeCameraPose_const_Vec3dR_const_Vec3dR_const_Vec3dR(&position, &focal_point, &y_dir, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_viz_makeCameraPose_const_Vec3dR_const_Vec3dR_const_Vec3dR(&position, &focal_point, &y_dir, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -225,7 +225,7 @@ pub fn make_camera_pose(position: core::Vec3d, focal_point: core::Vec3d, y_dir:
 #[inline]
 pub fn make_transform_to_global(axis_x: core::Vec3d, axis_y: core::Vec3d, axis_z: core::Vec3d, origin: core::Vec3d) -> Result<core::Affine3d> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_viz_makeTransformToGlobal_const_Vec3dR_const_Vec3dR_const_Vec3dR_const_Vec3dR(&axis_x, &axis_y, &axis_z, &origin, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_viz_makeTransformToGlobal_const_Vec3dR_const_Vec3dR_const_Vec3dR_const_Vec3dR(&axis_x, &axis_y, &axis_z, &origin, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -248,7 +248,7 @@ pub fn read_cloud(file: &str, colors: &mut dyn core::ToOutputArray, normals: &mu
 	output_array_arg!(colors);
 	output_array_arg!(normals);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_viz_readCloud_const_StringR_const__OutputArrayR_const__OutputArrayR(file.opencv_as_extern(), colors.as_raw__OutputArray(), normals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_viz_readCloud_const_StringR_const__OutputArrayR_const__OutputArrayR(file.opencv_as_extern(), colors.as_raw__OutputArray(), normals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -261,7 +261,7 @@ pub fn read_cloud(file: &str, colors: &mut dyn core::ToOutputArray, normals: &mu
 pub fn read_mesh(file: &str) -> Result<crate::viz::Mesh> {
 	extern_container_arg!(file);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_viz_readMesh_const_StringR(file.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_viz_readMesh_const_StringR(file.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { crate::viz::Mesh::opencv_from_extern(ret) };
@@ -280,7 +280,7 @@ pub fn read_pose(file: &str, pose: &mut core::Affine3d, tag: &str) -> Result<boo
 	extern_container_arg!(file);
 	extern_container_arg!(tag);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_viz_readPose_const_StringR_Affine3dR_const_StringR(file.opencv_as_extern(), pose, tag.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_viz_readPose_const_StringR_Affine3dR_const_StringR(file.opencv_as_extern(), pose, tag.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -309,17 +309,62 @@ pub fn read_trajectory(traj: &mut dyn core::ToOutputArray, files_format: &str, s
 	extern_container_arg!(files_format);
 	extern_container_arg!(tag);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_viz_readTrajectory_const__OutputArrayR_const_StringR_int_int_const_StringR(traj.as_raw__OutputArray(), files_format.opencv_as_extern(), start, end, tag.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_viz_readTrajectory_const__OutputArrayR_const_StringR_int_int_const_StringR(traj.as_raw__OutputArray(), files_format.opencv_as_extern(), start, end, tag.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `read_trajectory()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct ReadTrajectoryBuilder<'a> {
+	traj: &'a mut dyn core::ToOutputArray,
+	files_format: &'a str,
+	start: i32,
+	end: i32,
+	tag: &'a str,
+}
+
+impl<'a> ReadTrajectoryBuilder<'a> {
+	#[inline]
+	pub fn new(traj: &'a mut dyn core::ToOutputArray, end: i32) -> Self {
+		Self { traj, files_format: "pose%05d.xml", start: 0, end, tag: "pose" }
+	}
+	
+	/// C++ default: `"pose%05d.xml"`
+	#[inline]
+	pub fn with_files_format(mut self, files_format: &'a str) -> Self {
+		self.files_format = files_format;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_start(mut self, start: i32) -> Self {
+		self.start = start;
+		self
+	}
+	
+	/// C++ default: `"pose"`
+	#[inline]
+	pub fn with_tag(mut self, tag: &'a str) -> Self {
+		self.tag = tag;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		read_trajectory(self.traj, self.files_format, self.start, self.end, self.tag)
+	}
+}
+
 /// Unregisters all Viz windows from internal database. After it 'getWindowByName()' will create new windows instead of getting existing from the database.
 #[inline]
 pub fn unregister_all_windows() -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_viz_unregisterAllWindows(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_viz_unregisterAllWindows(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -344,12 +389,61 @@ pub fn write_cloud(file: &str, cloud: &dyn core::ToInputArray, colors: &dyn core
 	input_array_arg!(colors);
 	input_array_arg!(normals);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_viz_writeCloud_const_StringR_const__InputArrayR_const__InputArrayR_const__InputArrayR_bool(file.opencv_as_extern(), cloud.as_raw__InputArray(), colors.as_raw__InputArray(), normals.as_raw__InputArray(), binary, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_viz_writeCloud_const_StringR_const__InputArrayR_const__InputArrayR_const__InputArrayR_bool(file.opencv_as_extern(), cloud.as_raw__InputArray(), colors.as_raw__InputArray(), normals.as_raw__InputArray(), binary, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `write_cloud()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct WriteCloudBuilder<'a> {
+	file: &'a str,
+	cloud: &'a dyn core::ToInputArray,
+	colors: Option<&'a dyn core::ToInputArray>,
+	normals: Option<&'a dyn core::ToInputArray>,
+	binary: bool,
+}
+
+impl<'a> WriteCloudBuilder<'a> {
+	#[inline]
+	pub fn new(file: &'a str, cloud: &'a dyn core::ToInputArray) -> Self {
+		Self { file, cloud, colors: None, normals: None, binary: false }
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_colors(mut self, colors: &'a dyn core::ToInputArray) -> Self {
+		self.colors = Some(colors);
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_normals(mut self, normals: &'a dyn core::ToInputArray) -> Self {
+		self.normals = Some(normals);
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_binary(mut self, binary: bool) -> Self {
+		self.binary = binary;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let colors_no_array = core::no_array();
+		let colors: &dyn core::ToInputArray = match self.colors { Some(colors) => colors, None => &colors_no_array };
+		let normals_no_array = core::no_array();
+		let normals: &dyn core::ToInputArray = match self.normals { Some(normals) => normals, None => &normals_no_array };
+		write_cloud(self.file, self.cloud, colors, normals, self.binary)
+	}
+}
+
 /// ## Parameters
 /// * file: Filename.
 /// * pose: Input matrix.
@@ -362,7 +456,7 @@ pub fn write_pose(file: &str, pose: core::Affine3d, tag: &str) -> Result<()> {
 	extern_container_arg!(file);
 	extern_container_arg!(tag);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_viz_writePose_const_StringR_const_Affine3dR_const_StringR(file.opencv_as_extern(), &pose, tag.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_viz_writePose_const_StringR_const_Affine3dR_const_StringR(file.opencv_as_extern(), &pose, tag.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -389,12 +483,56 @@ pub fn write_trajectory(traj: &dyn core::ToInputArray, files_format: &str, start
 	extern_container_arg!(files_format);
 	extern_container_arg!(tag);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_viz_writeTrajectory_const__InputArrayR_const_StringR_int_const_StringR(traj.as_raw__InputArray(), files_format.opencv_as_extern(), start, tag.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_viz_writeTrajectory_const__InputArrayR_const_StringR_int_const_StringR(traj.as_raw__InputArray(), files_format.opencv_as_extern(), start, tag.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `write_trajectory()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct WriteTrajectoryBuilder<'a> {
+	traj: &'a dyn core::ToInputArray,
+	files_format: &'a str,
+	start: i32,
+	tag: &'a str,
+}
+
+impl<'a> WriteTrajectoryBuilder<'a> {
+	#[inline]
+	pub fn new(traj: &'a dyn core::ToInputArray) -> Self {
+		Self { traj, files_format: "pose%05d.xml", start: 0, tag: "pose" }
+	}
+	
+	/// C++ default: `"pose%05d.xml"`
+	#[inline]
+	pub fn with_files_format(mut self, files_format: &'a str) -> Self {
+		self.files_format = files_format;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_start(mut self, start: i32) -> Self {
+		self.start = start;
+		self
+	}
+	
+	/// C++ default: `"pose"`
+	#[inline]
+	pub fn with_tag(mut self, tag: &'a str) -> Self {
+		self.tag = tag;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		write_trajectory(self.traj, self.files_format, self.start, self.tag)
+	}
+}
+
 /// This class wraps intrinsic parameters of a camera.
 /// 
 /// It provides several constructors that can extract the intrinsic parameters from field of
@@ -404,8 +542,9 @@ pub trait CameraTraitConst {
 
 	#[inline]
 	fn get_clip(&self) -> Result<core::Vec2d> {
+		crate::core::validity::check("Camera", self.as_raw_Camera())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Camera_getClip_const(self.as_raw_Camera(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Camera_getClip_const(self.as_raw_Camera(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -413,8 +552,9 @@ pub trait CameraTraitConst {
 	
 	#[inline]
 	fn get_window_size(&self) -> Result<core::Size> {
+		crate::core::validity::check("Camera", self.as_raw_Camera())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Camera_getWindowSize_const(self.as_raw_Camera(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Camera_getWindowSize_const(self.as_raw_Camera(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -422,8 +562,9 @@ pub trait CameraTraitConst {
 	
 	#[inline]
 	fn get_fov(&self) -> Result<core::Vec2d> {
+		crate::core::validity::check("Camera", self.as_raw_Camera())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Camera_getFov_const(self.as_raw_Camera(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Camera_getFov_const(self.as_raw_Camera(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -431,8 +572,9 @@ pub trait CameraTraitConst {
 	
 	#[inline]
 	fn get_principal_point(&self) -> Result<core::Vec2d> {
+		crate::core::validity::check("Camera", self.as_raw_Camera())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Camera_getPrincipalPoint_const(self.as_raw_Camera(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Camera_getPrincipalPoint_const(self.as_raw_Camera(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -440,8 +582,9 @@ pub trait CameraTraitConst {
 	
 	#[inline]
 	fn get_focal_length(&self) -> Result<core::Vec2d> {
+		crate::core::validity::check("Camera", self.as_raw_Camera())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Camera_getFocalLength_const(self.as_raw_Camera(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Camera_getFocalLength_const(self.as_raw_Camera(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -455,8 +598,9 @@ pub trait CameraTraitConst {
 	/// ![block formula](https://latex.codecogs.com/png.latex?%0A%20%20%5Cbegin%7Bbmatrix%7D%0A%20%20%5Cfrac%7B2n%7D%7Br%2Dl%7D%20%26%20%20%20%20%20%20%20%200%20%20%20%20%20%20%20%26%20%5Cfrac%7Br%2Bl%7D%7Br%2Dl%7D%20%20%26%200%5C%5C%0A%20%20%20%20%20%20%20%200%20%20%20%20%20%20%20%20%26%20%5Cfrac%7B2n%7D%7Bt%2Db%7D%20%26%20%5Cfrac%7Bt%2Bb%7D%7Bt%2Db%7D%20%20%26%200%5C%5C%0A%20%20%20%20%20%20%20%200%20%20%20%20%20%20%20%20%26%20%20%20%20%20%20%20%200%20%20%20%20%20%20%20%26%20%2D%5Cfrac%7Bf%2Bn%7D%7Bf%2Dn%7D%20%26%20%2D%5Cfrac%7B2fn%7D%7Bf%2Dn%7D%5C%5C%0A%20%20%20%20%20%20%20%200%20%20%20%20%20%20%20%20%26%20%20%20%20%20%20%20%200%20%20%20%20%20%20%20%26%20%2D1%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%26%200%5C%5C%0A%20%20%5Cend%7Bbmatrix%7D%0A)
 	#[inline]
 	fn compute_projection_matrix(&self, proj: &mut core::Matx44d) -> Result<()> {
+		crate::core::validity::check("Camera", self.as_raw_Camera())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Camera_computeProjectionMatrix_const_Matx44dR(self.as_raw_Camera(), proj, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Camera_computeProjectionMatrix_const_Matx44dR(self.as_raw_Camera(), proj, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -469,8 +613,9 @@ pub trait CameraTrait: crate::viz::CameraTraitConst {
 
 	#[inline]
 	fn set_clip(&mut self, clip: core::Vec2d) -> Result<()> {
+		crate::core::validity::check("Camera", self.as_raw_mut_Camera())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Camera_setClip_const_Vec2dR(self.as_raw_mut_Camera(), &clip, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Camera_setClip_const_Vec2dR(self.as_raw_mut_Camera(), &clip, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -478,8 +623,9 @@ pub trait CameraTrait: crate::viz::CameraTraitConst {
 	
 	#[inline]
 	fn set_window_size(&mut self, window_size: core::Size) -> Result<()> {
+		crate::core::validity::check("Camera", self.as_raw_mut_Camera())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Camera_setWindowSize_const_SizeR(self.as_raw_mut_Camera(), &window_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Camera_setWindowSize_const_SizeR(self.as_raw_mut_Camera(), &window_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -487,8 +633,9 @@ pub trait CameraTrait: crate::viz::CameraTraitConst {
 	
 	#[inline]
 	fn set_fov(&mut self, fov: core::Vec2d) -> Result<()> {
+		crate::core::validity::check("Camera", self.as_raw_mut_Camera())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Camera_setFov_const_Vec2dR(self.as_raw_mut_Camera(), &fov, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Camera_setFov_const_Vec2dR(self.as_raw_mut_Camera(), &fov, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -509,7 +656,10 @@ opencv_type_boxed! { Camera }
 impl Drop for Camera {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Camera_delete(instance: *mut c_void); }
-		unsafe { cv_Camera_delete(self.as_raw_mut_Camera()) };
+		let ptr = self.as_raw_mut_Camera();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Camera_delete(ptr) };
 	}
 }
 
@@ -536,7 +686,7 @@ impl Camera {
 	#[inline]
 	pub fn new(fx: f64, fy: f64, cx: f64, cy: f64, window_size: core::Size) -> Result<crate::viz::Camera> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Camera_Camera_double_double_double_double_const_SizeR(fx, fy, cx, cy, &window_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Camera_Camera_double_double_double_double_const_SizeR(fx, fy, cx, cy, &window_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Camera::opencv_from_extern(ret) };
@@ -561,7 +711,7 @@ impl Camera {
 	#[inline]
 	pub fn new_1(fov: core::Vec2d, window_size: core::Size) -> Result<crate::viz::Camera> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Camera_Camera_const_Vec2dR_const_SizeR(&fov, &window_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Camera_Camera_const_Vec2dR_const_SizeR(&fov, &window_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Camera::opencv_from_extern(ret) };
@@ -587,7 +737,7 @@ impl Camera {
 	#[inline]
 	pub fn new_2(k: core::Matx33d, window_size: core::Size) -> Result<crate::viz::Camera> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Camera_Camera_const_Matx33dR_const_SizeR(&k, &window_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Camera_Camera_const_Matx33dR_const_SizeR(&k, &window_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Camera::opencv_from_extern(ret) };
@@ -614,7 +764,7 @@ impl Camera {
 	#[inline]
 	pub fn new_3(proj: core::Matx44d, window_size: core::Size) -> Result<crate::viz::Camera> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Camera_Camera_const_Matx44dR_const_SizeR(&proj, &window_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Camera_Camera_const_Matx44dR_const_SizeR(&proj, &window_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Camera::opencv_from_extern(ret) };
@@ -632,7 +782,7 @@ impl Camera {
 	#[inline]
 	pub fn kinect_camera(window_size: core::Size) -> Result<crate::viz::Camera> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Camera_KinectCamera_const_SizeR(&window_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Camera_KinectCamera_const_SizeR(&window_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Camera::opencv_from_extern(ret) };
@@ -647,8 +797,9 @@ pub trait ColorTraitConst {
 
 	#[inline]
 	fn to_vec3b(&self) -> Result<core::Vec3b> {
+		crate::core::validity::check("Color", self.as_raw_Color())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_operator_cv_Vec3b_const(self.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_operator_cv_Vec3b_const(self.as_raw_Color(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -671,7 +822,10 @@ opencv_type_boxed! { Color }
 impl Drop for Color {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Color_delete(instance: *mut c_void); }
-		unsafe { cv_Color_delete(self.as_raw_mut_Color()) };
+		let ptr = self.as_raw_mut_Color();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Color_delete(ptr) };
 	}
 }
 
@@ -691,7 +845,7 @@ impl Color {
 	#[inline]
 	pub fn default() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_Color(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_Color(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -702,7 +856,7 @@ impl Color {
 	#[inline]
 	pub fn new(gray: f64) -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_Color_double(gray, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_Color_double(gray, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -712,7 +866,7 @@ impl Color {
 	#[inline]
 	pub fn new_1(blue: f64, green: f64, red: f64) -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_Color_double_double_double(blue, green, red, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_Color_double_double_double(blue, green, red, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -722,7 +876,7 @@ impl Color {
 	#[inline]
 	pub fn new_2(color: core::Scalar) -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_Color_const_ScalarR(&color, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_Color_const_ScalarR(&color, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -732,7 +886,7 @@ impl Color {
 	#[inline]
 	pub fn black() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_black(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_black(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -742,7 +896,7 @@ impl Color {
 	#[inline]
 	pub fn blue() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_blue(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_blue(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -752,7 +906,7 @@ impl Color {
 	#[inline]
 	pub fn green() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_green(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_green(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -762,7 +916,7 @@ impl Color {
 	#[inline]
 	pub fn red() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_red(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_red(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -772,7 +926,7 @@ impl Color {
 	#[inline]
 	pub fn cyan() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_cyan(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_cyan(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -782,7 +936,7 @@ impl Color {
 	#[inline]
 	pub fn yellow() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_yellow(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_yellow(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -792,7 +946,7 @@ impl Color {
 	#[inline]
 	pub fn magenta() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_magenta(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_magenta(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -802,7 +956,7 @@ impl Color {
 	#[inline]
 	pub fn white() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_white(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_white(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -812,7 +966,7 @@ impl Color {
 	#[inline]
 	pub fn gray() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_gray(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_gray(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -822,7 +976,7 @@ impl Color {
 	#[inline]
 	pub fn silver() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_silver(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_silver(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -832,7 +986,7 @@ impl Color {
 	#[inline]
 	pub fn mlab() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_mlab(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_mlab(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -842,7 +996,7 @@ impl Color {
 	#[inline]
 	pub fn navy() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_navy(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_navy(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -852,7 +1006,7 @@ impl Color {
 	#[inline]
 	pub fn maroon() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_maroon(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_maroon(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -862,7 +1016,7 @@ impl Color {
 	#[inline]
 	pub fn teal() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_teal(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_teal(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -872,7 +1026,7 @@ impl Color {
 	#[inline]
 	pub fn olive() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_olive(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_olive(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -882,7 +1036,7 @@ impl Color {
 	#[inline]
 	pub fn purple() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_purple(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_purple(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -892,7 +1046,7 @@ impl Color {
 	#[inline]
 	pub fn azure() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_azure(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_azure(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -902,7 +1056,7 @@ impl Color {
 	#[inline]
 	pub fn chartreuse() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_chartreuse(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_chartreuse(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -912,7 +1066,7 @@ impl Color {
 	#[inline]
 	pub fn rose() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_rose(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_rose(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -922,7 +1076,7 @@ impl Color {
 	#[inline]
 	pub fn lime() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_lime(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_lime(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -932,7 +1086,7 @@ impl Color {
 	#[inline]
 	pub fn gold() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_gold(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_gold(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -942,7 +1096,7 @@ impl Color {
 	#[inline]
 	pub fn orange() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_orange(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_orange(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -952,7 +1106,7 @@ impl Color {
 	#[inline]
 	pub fn orange_red() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_orange_red(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_orange_red(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -962,7 +1116,7 @@ impl Color {
 	#[inline]
 	pub fn indigo() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_indigo(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_indigo(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -972,7 +1126,7 @@ impl Color {
 	#[inline]
 	pub fn brown() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_brown(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_brown(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -982,7 +1136,7 @@ impl Color {
 	#[inline]
 	pub fn apricot() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_apricot(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_apricot(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -992,7 +1146,7 @@ impl Color {
 	#[inline]
 	pub fn pink() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_pink(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_pink(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -1002,7 +1156,7 @@ impl Color {
 	#[inline]
 	pub fn raspberry() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_raspberry(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_raspberry(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -1012,7 +1166,7 @@ impl Color {
 	#[inline]
 	pub fn cherry() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_cherry(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_cherry(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -1022,7 +1176,7 @@ impl Color {
 	#[inline]
 	pub fn violet() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_violet(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_violet(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -1032,7 +1186,7 @@ impl Color {
 	#[inline]
 	pub fn amethyst() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_amethyst(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_amethyst(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -1042,7 +1196,7 @@ impl Color {
 	#[inline]
 	pub fn bluberry() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_bluberry(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_bluberry(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -1052,7 +1206,7 @@ impl Color {
 	#[inline]
 	pub fn celestial_blue() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_celestial_blue(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_celestial_blue(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -1062,7 +1216,7 @@ impl Color {
 	#[inline]
 	pub fn turquoise() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_turquoise(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_turquoise(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -1072,7 +1226,7 @@ impl Color {
 	#[inline]
 	pub fn not_set() -> Result<crate::viz::Color> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Color_not_set(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Color_not_set(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Color::opencv_from_extern(ret) };
@@ -1087,31 +1241,67 @@ pub trait KeyboardEventTraitConst {
 
 	#[inline]
 	fn action(&self) -> crate::viz::KeyboardEvent_Action {
+		crate::core::validity::debug_check("KeyboardEvent", self.as_raw_KeyboardEvent());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_KeyboardEvent_getPropAction_const(self.as_raw_KeyboardEvent(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_KeyboardEvent_getPropAction_const(self.as_raw_KeyboardEvent(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [action](KeyboardEventTraitConst::action), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_action(&self) -> Result<crate::viz::KeyboardEvent_Action> {
+		crate::core::validity::check("KeyboardEvent", self.as_raw_KeyboardEvent())?;
+		Ok(KeyboardEventTraitConst::action(self))
+	}
+	
 	#[inline]
 	fn symbol(&self) -> String {
-		let ret = unsafe { sys::cv_viz_KeyboardEvent_getPropSymbol_const(self.as_raw_KeyboardEvent()) };
+		crate::core::validity::debug_check("KeyboardEvent", self.as_raw_KeyboardEvent());
+		let ret = unsafe { ffi_call!(cv_viz_KeyboardEvent_getPropSymbol_const(self.as_raw_KeyboardEvent())) };
 		let ret = unsafe { String::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [symbol](KeyboardEventTraitConst::symbol), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_symbol(&self) -> Result<String> {
+		crate::core::validity::check("KeyboardEvent", self.as_raw_KeyboardEvent())?;
+		Ok(KeyboardEventTraitConst::symbol(self))
+	}
+	
 	#[inline]
 	fn code(&self) -> u8 {
-		let ret = unsafe { sys::cv_viz_KeyboardEvent_getPropCode_const(self.as_raw_KeyboardEvent()) };
+		crate::core::validity::debug_check("KeyboardEvent", self.as_raw_KeyboardEvent());
+		let ret = unsafe { ffi_call!(cv_viz_KeyboardEvent_getPropCode_const(self.as_raw_KeyboardEvent())) };
 		ret
 	}
 	
+	/// Non-panicking version of [code](KeyboardEventTraitConst::code), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_code(&self) -> Result<u8> {
+		crate::core::validity::check("KeyboardEvent", self.as_raw_KeyboardEvent())?;
+		Ok(KeyboardEventTraitConst::code(self))
+	}
+	
 	#[inline]
 	fn modifiers(&self) -> i32 {
-		let ret = unsafe { sys::cv_viz_KeyboardEvent_getPropModifiers_const(self.as_raw_KeyboardEvent()) };
+		crate::core::validity::debug_check("KeyboardEvent", self.as_raw_KeyboardEvent());
+		let ret = unsafe { ffi_call!(cv_viz_KeyboardEvent_getPropModifiers_const(self.as_raw_KeyboardEvent())) };
 		ret
 	}
 	
+	/// Non-panicking version of [modifiers](KeyboardEventTraitConst::modifiers), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_modifiers(&self) -> Result<i32> {
+		crate::core::validity::check("KeyboardEvent", self.as_raw_KeyboardEvent())?;
+		Ok(KeyboardEventTraitConst::modifiers(self))
+	}
+	
 }
 
 pub trait KeyboardEventTrait: crate::viz::KeyboardEventTraitConst {
@@ -1119,26 +1309,30 @@ pub trait KeyboardEventTrait: crate::viz::KeyboardEventTraitConst {
 
 	#[inline]
 	fn set_action(&mut self, val: crate::viz::KeyboardEvent_Action) {
-		let ret = unsafe { sys::cv_viz_KeyboardEvent_setPropAction_Action(self.as_raw_mut_KeyboardEvent(), val) };
+		crate::core::validity::debug_check("KeyboardEvent", self.as_raw_mut_KeyboardEvent());
+		let ret = unsafe { ffi_call!(cv_viz_KeyboardEvent_setPropAction_Action(self.as_raw_mut_KeyboardEvent(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_symbol(&mut self, val: &str) {
+		crate::core::validity::debug_check("KeyboardEvent", self.as_raw_mut_KeyboardEvent());
 		extern_container_arg!(nofail mut val);
-		let ret = unsafe { sys::cv_viz_KeyboardEvent_setPropSymbol_String(self.as_raw_mut_KeyboardEvent(), val.opencv_as_extern_mut()) };
+		let ret = unsafe { ffi_call!(cv_viz_KeyboardEvent_setPropSymbol_String(self.as_raw_mut_KeyboardEvent(), val.opencv_as_extern_mut())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_code(&mut self, val: u8) {
-		let ret = unsafe { sys::cv_viz_KeyboardEvent_setPropCode_unsigned_char(self.as_raw_mut_KeyboardEvent(), val) };
+		crate::core::validity::debug_check("KeyboardEvent", self.as_raw_mut_KeyboardEvent());
+		let ret = unsafe { ffi_call!(cv_viz_KeyboardEvent_setPropCode_unsigned_char(self.as_raw_mut_KeyboardEvent(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_modifiers(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_viz_KeyboardEvent_setPropModifiers_int(self.as_raw_mut_KeyboardEvent(), val) };
+		crate::core::validity::debug_check("KeyboardEvent", self.as_raw_mut_KeyboardEvent());
+		let ret = unsafe { ffi_call!(cv_viz_KeyboardEvent_setPropModifiers_int(self.as_raw_mut_KeyboardEvent(), val)) };
 		ret
 	}
 	
@@ -1154,7 +1348,10 @@ opencv_type_boxed! { KeyboardEvent }
 impl Drop for KeyboardEvent {
 	fn drop(&mut self) {
 		extern "C" { fn cv_KeyboardEvent_delete(instance: *mut c_void); }
-		unsafe { cv_KeyboardEvent_delete(self.as_raw_mut_KeyboardEvent()) };
+		let ptr = self.as_raw_mut_KeyboardEvent();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_KeyboardEvent_delete(ptr) };
 	}
 }
 
@@ -1180,7 +1377,7 @@ impl KeyboardEvent {
 	pub fn new(action: crate::viz::KeyboardEvent_Action, symbol: &str, code: u8, modifiers: i32) -> Result<crate::viz::KeyboardEvent> {
 		extern_container_arg!(symbol);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_KeyboardEvent_KeyboardEvent_Action_const_StringR_unsigned_char_int(action, symbol.opencv_as_extern(), code, modifiers, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_KeyboardEvent_KeyboardEvent_Action_const_StringR_unsigned_char_int(action, symbol.opencv_as_extern(), code, modifiers, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::KeyboardEvent::opencv_from_extern(ret) };
@@ -1196,50 +1393,104 @@ pub trait MeshTraitConst {
 	/// point coordinates of type CV_32FC3 or CV_64FC3 with only 1 row
 	#[inline]
 	fn cloud(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_viz_Mesh_getPropCloud_const(self.as_raw_Mesh()) };
+		crate::core::validity::debug_check("Mesh", self.as_raw_Mesh());
+		let ret = unsafe { ffi_call!(cv_viz_Mesh_getPropCloud_const(self.as_raw_Mesh())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [cloud](MeshTraitConst::cloud), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_cloud(&self) -> Result<core::Mat> {
+		crate::core::validity::check("Mesh", self.as_raw_Mesh())?;
+		Ok(MeshTraitConst::cloud(self))
+	}
+	
 	/// point color of type CV_8UC3 or CV_8UC4 with only 1 row
 	#[inline]
 	fn colors(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_viz_Mesh_getPropColors_const(self.as_raw_Mesh()) };
+		crate::core::validity::debug_check("Mesh", self.as_raw_Mesh());
+		let ret = unsafe { ffi_call!(cv_viz_Mesh_getPropColors_const(self.as_raw_Mesh())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [colors](MeshTraitConst::colors), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_colors(&self) -> Result<core::Mat> {
+		crate::core::validity::check("Mesh", self.as_raw_Mesh())?;
+		Ok(MeshTraitConst::colors(self))
+	}
+	
 	/// point normals of type CV_32FC3, CV_32FC4, CV_64FC3 or CV_64FC4 with only 1 row
 	#[inline]
 	fn normals(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_viz_Mesh_getPropNormals_const(self.as_raw_Mesh()) };
+		crate::core::validity::debug_check("Mesh", self.as_raw_Mesh());
+		let ret = unsafe { ffi_call!(cv_viz_Mesh_getPropNormals_const(self.as_raw_Mesh())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [normals](MeshTraitConst::normals), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_normals(&self) -> Result<core::Mat> {
+		crate::core::validity::check("Mesh", self.as_raw_Mesh())?;
+		Ok(MeshTraitConst::normals(self))
+	}
+	
 	/// CV_32SC1 with only 1 row
 	#[inline]
 	fn polygons(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_viz_Mesh_getPropPolygons_const(self.as_raw_Mesh()) };
+		crate::core::validity::debug_check("Mesh", self.as_raw_Mesh());
+		let ret = unsafe { ffi_call!(cv_viz_Mesh_getPropPolygons_const(self.as_raw_Mesh())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [polygons](MeshTraitConst::polygons), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_polygons(&self) -> Result<core::Mat> {
+		crate::core::validity::check("Mesh", self.as_raw_Mesh())?;
+		Ok(MeshTraitConst::polygons(self))
+	}
+	
 	#[inline]
 	fn texture(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_viz_Mesh_getPropTexture_const(self.as_raw_Mesh()) };
+		crate::core::validity::debug_check("Mesh", self.as_raw_Mesh());
+		let ret = unsafe { ffi_call!(cv_viz_Mesh_getPropTexture_const(self.as_raw_Mesh())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [texture](MeshTraitConst::texture), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_texture(&self) -> Result<core::Mat> {
+		crate::core::validity::check("Mesh", self.as_raw_Mesh())?;
+		Ok(MeshTraitConst::texture(self))
+	}
+	
 	/// CV_32FC2 or CV_64FC2 with only 1 row
 	#[inline]
 	fn tcoords(&self) -> core::Mat {
-		let ret = unsafe { sys::cv_viz_Mesh_getPropTcoords_const(self.as_raw_Mesh()) };
+		crate::core::validity::debug_check("Mesh", self.as_raw_Mesh());
+		let ret = unsafe { ffi_call!(cv_viz_Mesh_getPropTcoords_const(self.as_raw_Mesh())) };
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [tcoords](MeshTraitConst::tcoords), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_tcoords(&self) -> Result<core::Mat> {
+		crate::core::validity::check("Mesh", self.as_raw_Mesh())?;
+		Ok(MeshTraitConst::tcoords(self))
+	}
+	
 }
 
 pub trait MeshTrait: crate::viz::MeshTraitConst {
@@ -1248,41 +1499,47 @@ pub trait MeshTrait: crate::viz::MeshTraitConst {
 	/// point coordinates of type CV_32FC3 or CV_64FC3 with only 1 row
 	#[inline]
 	fn set_cloud(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_viz_Mesh_setPropCloud_Mat(self.as_raw_mut_Mesh(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("Mesh", self.as_raw_mut_Mesh());
+		let ret = unsafe { ffi_call!(cv_viz_Mesh_setPropCloud_Mat(self.as_raw_mut_Mesh(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	/// point color of type CV_8UC3 or CV_8UC4 with only 1 row
 	#[inline]
 	fn set_colors(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_viz_Mesh_setPropColors_Mat(self.as_raw_mut_Mesh(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("Mesh", self.as_raw_mut_Mesh());
+		let ret = unsafe { ffi_call!(cv_viz_Mesh_setPropColors_Mat(self.as_raw_mut_Mesh(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	/// point normals of type CV_32FC3, CV_32FC4, CV_64FC3 or CV_64FC4 with only 1 row
 	#[inline]
 	fn set_normals(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_viz_Mesh_setPropNormals_Mat(self.as_raw_mut_Mesh(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("Mesh", self.as_raw_mut_Mesh());
+		let ret = unsafe { ffi_call!(cv_viz_Mesh_setPropNormals_Mat(self.as_raw_mut_Mesh(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	/// CV_32SC1 with only 1 row
 	#[inline]
 	fn set_polygons(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_viz_Mesh_setPropPolygons_Mat(self.as_raw_mut_Mesh(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("Mesh", self.as_raw_mut_Mesh());
+		let ret = unsafe { ffi_call!(cv_viz_Mesh_setPropPolygons_Mat(self.as_raw_mut_Mesh(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_texture(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_viz_Mesh_setPropTexture_Mat(self.as_raw_mut_Mesh(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("Mesh", self.as_raw_mut_Mesh());
+		let ret = unsafe { ffi_call!(cv_viz_Mesh_setPropTexture_Mat(self.as_raw_mut_Mesh(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
 	/// CV_32FC2 or CV_64FC2 with only 1 row
 	#[inline]
 	fn set_tcoords(&mut self, mut val: core::Mat) {
-		let ret = unsafe { sys::cv_viz_Mesh_setPropTcoords_Mat(self.as_raw_mut_Mesh(), val.as_raw_mut_Mat()) };
+		crate::core::validity::debug_check("Mesh", self.as_raw_mut_Mesh());
+		let ret = unsafe { ffi_call!(cv_viz_Mesh_setPropTcoords_Mat(self.as_raw_mut_Mesh(), val.as_raw_mut_Mat())) };
 		ret
 	}
 	
@@ -1298,7 +1555,10 @@ opencv_type_boxed! { Mesh }
 impl Drop for Mesh {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Mesh_delete(instance: *mut c_void); }
-		unsafe { cv_Mesh_delete(self.as_raw_mut_Mesh()) };
+		let ptr = self.as_raw_mut_Mesh();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Mesh_delete(ptr) };
 	}
 }
 
@@ -1316,7 +1576,7 @@ impl Mesh {
 	#[inline]
 	pub fn default() -> Result<crate::viz::Mesh> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Mesh_Mesh(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Mesh_Mesh(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Mesh::opencv_from_extern(ret) };
@@ -1339,7 +1599,7 @@ impl Mesh {
 	pub fn load(file: &str, typ: i32) -> Result<crate::viz::Mesh> {
 		extern_container_arg!(file);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Mesh_load_const_StringR_int(file.opencv_as_extern(), typ, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Mesh_load_const_StringR_int(file.opencv_as_extern(), typ, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Mesh::opencv_from_extern(ret) };
@@ -1354,34 +1614,70 @@ pub trait MouseEventTraitConst {
 
 	#[inline]
 	fn typ(&self) -> crate::viz::MouseEvent_Type {
+		crate::core::validity::debug_check("MouseEvent", self.as_raw_MouseEvent());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_MouseEvent_getPropType_const(self.as_raw_MouseEvent(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_MouseEvent_getPropType_const(self.as_raw_MouseEvent(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [typ](MouseEventTraitConst::typ), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_typ(&self) -> Result<crate::viz::MouseEvent_Type> {
+		crate::core::validity::check("MouseEvent", self.as_raw_MouseEvent())?;
+		Ok(MouseEventTraitConst::typ(self))
+	}
+	
 	#[inline]
 	fn button(&self) -> crate::viz::MouseEvent_MouseButton {
+		crate::core::validity::debug_check("MouseEvent", self.as_raw_MouseEvent());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_MouseEvent_getPropButton_const(self.as_raw_MouseEvent(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_MouseEvent_getPropButton_const(self.as_raw_MouseEvent(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [button](MouseEventTraitConst::button), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_button(&self) -> Result<crate::viz::MouseEvent_MouseButton> {
+		crate::core::validity::check("MouseEvent", self.as_raw_MouseEvent())?;
+		Ok(MouseEventTraitConst::button(self))
+	}
+	
 	#[inline]
 	fn pointer(&self) -> core::Point {
+		crate::core::validity::debug_check("MouseEvent", self.as_raw_MouseEvent());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_MouseEvent_getPropPointer_const(self.as_raw_MouseEvent(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_MouseEvent_getPropPointer_const(self.as_raw_MouseEvent(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [pointer](MouseEventTraitConst::pointer), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_pointer(&self) -> Result<core::Point> {
+		crate::core::validity::check("MouseEvent", self.as_raw_MouseEvent())?;
+		Ok(MouseEventTraitConst::pointer(self))
+	}
+	
 	#[inline]
 	fn modifiers(&self) -> i32 {
-		let ret = unsafe { sys::cv_viz_MouseEvent_getPropModifiers_const(self.as_raw_MouseEvent()) };
+		crate::core::validity::debug_check("MouseEvent", self.as_raw_MouseEvent());
+		let ret = unsafe { ffi_call!(cv_viz_MouseEvent_getPropModifiers_const(self.as_raw_MouseEvent())) };
 		ret
 	}
 	
+	/// Non-panicking version of [modifiers](MouseEventTraitConst::modifiers), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_modifiers(&self) -> Result<i32> {
+		crate::core::validity::check("MouseEvent", self.as_raw_MouseEvent())?;
+		Ok(MouseEventTraitConst::modifiers(self))
+	}
+	
 }
 
 pub trait MouseEventTrait: crate::viz::MouseEventTraitConst {
@@ -1389,25 +1685,29 @@ pub trait MouseEventTrait: crate::viz::MouseEventTraitConst {
 
 	#[inline]
 	fn set_type(&mut self, val: crate::viz::MouseEvent_Type) {
-		let ret = unsafe { sys::cv_viz_MouseEvent_setPropType_Type(self.as_raw_mut_MouseEvent(), val) };
+		crate::core::validity::debug_check("MouseEvent", self.as_raw_mut_MouseEvent());
+		let ret = unsafe { ffi_call!(cv_viz_MouseEvent_setPropType_Type(self.as_raw_mut_MouseEvent(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_button(&mut self, val: crate::viz::MouseEvent_MouseButton) {
-		let ret = unsafe { sys::cv_viz_MouseEvent_setPropButton_MouseButton(self.as_raw_mut_MouseEvent(), val) };
+		crate::core::validity::debug_check("MouseEvent", self.as_raw_mut_MouseEvent());
+		let ret = unsafe { ffi_call!(cv_viz_MouseEvent_setPropButton_MouseButton(self.as_raw_mut_MouseEvent(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_pointer(&mut self, val: core::Point) {
-		let ret = unsafe { sys::cv_viz_MouseEvent_setPropPointer_Point(self.as_raw_mut_MouseEvent(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("MouseEvent", self.as_raw_mut_MouseEvent());
+		let ret = unsafe { ffi_call!(cv_viz_MouseEvent_setPropPointer_Point(self.as_raw_mut_MouseEvent(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_modifiers(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_viz_MouseEvent_setPropModifiers_int(self.as_raw_mut_MouseEvent(), val) };
+		crate::core::validity::debug_check("MouseEvent", self.as_raw_mut_MouseEvent());
+		let ret = unsafe { ffi_call!(cv_viz_MouseEvent_setPropModifiers_int(self.as_raw_mut_MouseEvent(), val)) };
 		ret
 	}
 	
@@ -1423,7 +1723,10 @@ opencv_type_boxed! { MouseEvent }
 impl Drop for MouseEvent {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MouseEvent_delete(instance: *mut c_void); }
-		unsafe { cv_MouseEvent_delete(self.as_raw_mut_MouseEvent()) };
+		let ptr = self.as_raw_mut_MouseEvent();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_MouseEvent_delete(ptr) };
 	}
 }
 
@@ -1450,7 +1753,7 @@ impl MouseEvent {
 	#[inline]
 	pub fn new(typ: crate::viz::MouseEvent_Type, button: crate::viz::MouseEvent_MouseButton, pointer: core::Point, modifiers: i32) -> Result<crate::viz::MouseEvent> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_MouseEvent_MouseEvent_const_TypeR_const_MouseButtonR_const_PointR_int(&typ, &button, &pointer, modifiers, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_MouseEvent_MouseEvent_const_TypeR_const_MouseButtonR_const_PointR_int(&typ, &button, &pointer, modifiers, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::MouseEvent::opencv_from_extern(ret) };
@@ -1472,9 +1775,10 @@ pub trait Viz3dTraitConst {
 	/// * id: The id of the widget that will be returned.
 	#[inline]
 	fn get_widget(&self, id: &str) -> Result<crate::viz::Widget> {
+		crate::core::validity::check("Viz3d", self.as_raw_Viz3d())?;
 		extern_container_arg!(id);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_getWidget_const_const_StringR(self.as_raw_Viz3d(), id.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_getWidget_const_const_StringR(self.as_raw_Viz3d(), id.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Widget::opencv_from_extern(ret) };
@@ -1487,9 +1791,10 @@ pub trait Viz3dTraitConst {
 	/// * id: The id of the widget whose pose will be returned.
 	#[inline]
 	fn get_widget_pose(&self, id: &str) -> Result<core::Affine3d> {
+		crate::core::validity::check("Viz3d", self.as_raw_Viz3d())?;
 		extern_container_arg!(id);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_getWidgetPose_const_const_StringR(self.as_raw_Viz3d(), id.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_getWidgetPose_const_const_StringR(self.as_raw_Viz3d(), id.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1498,8 +1803,9 @@ pub trait Viz3dTraitConst {
 	/// Returns a camera object that contains intrinsic parameters of the current viewer.
 	#[inline]
 	fn get_camera(&self) -> Result<crate::viz::Camera> {
+		crate::core::validity::check("Viz3d", self.as_raw_Viz3d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_getCamera_const(self.as_raw_Viz3d(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_getCamera_const(self.as_raw_Viz3d(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Camera::opencv_from_extern(ret) };
@@ -1509,8 +1815,9 @@ pub trait Viz3dTraitConst {
 	/// Returns the current pose of the viewer.
 	#[inline]
 	fn get_viewer_pose(&self) -> Result<core::Affine3d> {
+		crate::core::validity::check("Viz3d", self.as_raw_Viz3d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_getViewerPose_const(self.as_raw_Viz3d(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_getViewerPose_const(self.as_raw_Viz3d(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1519,8 +1826,9 @@ pub trait Viz3dTraitConst {
 	/// Returns the current size of the window.
 	#[inline]
 	fn get_window_size(&self) -> Result<core::Size> {
+		crate::core::validity::check("Viz3d", self.as_raw_Viz3d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_getWindowSize_const(self.as_raw_Viz3d(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_getWindowSize_const(self.as_raw_Viz3d(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1530,8 +1838,9 @@ pub trait Viz3dTraitConst {
 	/// `Viz - ` is prepended to the name if necessary.
 	#[inline]
 	fn get_window_name(&self) -> Result<String> {
+		crate::core::validity::check("Viz3d", self.as_raw_Viz3d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_getWindowName_const(self.as_raw_Viz3d(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_getWindowName_const(self.as_raw_Viz3d(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -1541,8 +1850,9 @@ pub trait Viz3dTraitConst {
 	/// Returns the Mat screenshot of the current scene.
 	#[inline]
 	fn get_screenshot(&self) -> Result<core::Mat> {
+		crate::core::validity::check("Viz3d", self.as_raw_Viz3d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_getScreenshot_const(self.as_raw_Viz3d(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_getScreenshot_const(self.as_raw_Viz3d(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -1552,8 +1862,9 @@ pub trait Viz3dTraitConst {
 	/// Returns whether the event loop has been stopped.
 	#[inline]
 	fn was_stopped(&self) -> Result<bool> {
+		crate::core::validity::check("Viz3d", self.as_raw_Viz3d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_wasStopped_const(self.as_raw_Viz3d(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_wasStopped_const(self.as_raw_Viz3d(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1574,9 +1885,10 @@ pub trait Viz3dTrait: crate::viz::Viz3dTraitConst {
 	/// * pose: Affine3d::Identity()
 	#[inline]
 	fn show_widget(&mut self, id: &str, widget: &crate::viz::Widget, pose: core::Affine3d) -> Result<()> {
+		crate::core::validity::check("Viz3d", self.as_raw_mut_Viz3d())?;
 		extern_container_arg!(id);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_showWidget_const_StringR_const_WidgetR_const_Affine3dR(self.as_raw_mut_Viz3d(), id.opencv_as_extern(), widget.as_raw_Widget(), &pose, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_showWidget_const_StringR_const_WidgetR_const_Affine3dR(self.as_raw_mut_Viz3d(), id.opencv_as_extern(), widget.as_raw_Widget(), &pose, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1588,9 +1900,10 @@ pub trait Viz3dTrait: crate::viz::Viz3dTraitConst {
 	/// * id: The id of the widget that will be removed.
 	#[inline]
 	fn remove_widget(&mut self, id: &str) -> Result<()> {
+		crate::core::validity::check("Viz3d", self.as_raw_mut_Viz3d())?;
 		extern_container_arg!(id);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_removeWidget_const_StringR(self.as_raw_mut_Viz3d(), id.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_removeWidget_const_StringR(self.as_raw_mut_Viz3d(), id.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1599,8 +1912,9 @@ pub trait Viz3dTrait: crate::viz::Viz3dTraitConst {
 	/// Removes all widgets from the window.
 	#[inline]
 	fn remove_all_widgets(&mut self) -> Result<()> {
+		crate::core::validity::check("Viz3d", self.as_raw_mut_Viz3d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_removeAllWidgets(self.as_raw_mut_Viz3d(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_removeAllWidgets(self.as_raw_mut_Viz3d(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1616,9 +1930,10 @@ pub trait Viz3dTrait: crate::viz::Viz3dTraitConst {
 	/// * window_size: Size(-1,-1)
 	#[inline]
 	fn show_image(&mut self, image: &dyn core::ToInputArray, window_size: core::Size) -> Result<()> {
+		crate::core::validity::check("Viz3d", self.as_raw_mut_Viz3d())?;
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_showImage_const__InputArrayR_const_SizeR(self.as_raw_mut_Viz3d(), image.as_raw__InputArray(), &window_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_showImage_const__InputArrayR_const_SizeR(self.as_raw_mut_Viz3d(), image.as_raw__InputArray(), &window_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1630,9 +1945,10 @@ pub trait Viz3dTrait: crate::viz::Viz3dTraitConst {
 	/// * id: The id of the widget whose pose will be set. @param pose The new pose of the widget.
 	#[inline]
 	fn set_widget_pose(&mut self, id: &str, pose: core::Affine3d) -> Result<()> {
+		crate::core::validity::check("Viz3d", self.as_raw_mut_Viz3d())?;
 		extern_container_arg!(id);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_setWidgetPose_const_StringR_const_Affine3dR(self.as_raw_mut_Viz3d(), id.opencv_as_extern(), &pose, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_setWidgetPose_const_StringR_const_Affine3dR(self.as_raw_mut_Viz3d(), id.opencv_as_extern(), &pose, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1645,9 +1961,10 @@ pub trait Viz3dTrait: crate::viz::Viz3dTraitConst {
 	/// pose of the widget will be pre-multiplied by.
 	#[inline]
 	fn update_widget_pose(&mut self, id: &str, pose: core::Affine3d) -> Result<()> {
+		crate::core::validity::check("Viz3d", self.as_raw_mut_Viz3d())?;
 		extern_container_arg!(id);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_updateWidgetPose_const_StringR_const_Affine3dR(self.as_raw_mut_Viz3d(), id.opencv_as_extern(), &pose, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_updateWidgetPose_const_StringR_const_Affine3dR(self.as_raw_mut_Viz3d(), id.opencv_as_extern(), &pose, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1659,8 +1976,9 @@ pub trait Viz3dTrait: crate::viz::Viz3dTraitConst {
 	/// * camera: Camera object wrapping intrinsic parameters.
 	#[inline]
 	fn set_camera(&mut self, camera: &crate::viz::Camera) -> Result<()> {
+		crate::core::validity::check("Viz3d", self.as_raw_mut_Viz3d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_setCamera_const_CameraR(self.as_raw_mut_Viz3d(), camera.as_raw_Camera(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_setCamera_const_CameraR(self.as_raw_mut_Viz3d(), camera.as_raw_Camera(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1672,8 +1990,9 @@ pub trait Viz3dTrait: crate::viz::Viz3dTraitConst {
 	/// * pose: The new pose of the viewer.
 	#[inline]
 	fn set_viewer_pose(&mut self, pose: core::Affine3d) -> Result<()> {
+		crate::core::validity::check("Viz3d", self.as_raw_mut_Viz3d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_setViewerPose_const_Affine3dR(self.as_raw_mut_Viz3d(), &pose, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_setViewerPose_const_Affine3dR(self.as_raw_mut_Viz3d(), &pose, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1685,9 +2004,10 @@ pub trait Viz3dTrait: crate::viz::Viz3dTraitConst {
 	/// * id: Id of a 3D widget.
 	#[inline]
 	fn reset_camera_viewpoint(&mut self, id: &str) -> Result<()> {
+		crate::core::validity::check("Viz3d", self.as_raw_mut_Viz3d())?;
 		extern_container_arg!(id);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_resetCameraViewpoint_const_StringR(self.as_raw_mut_Viz3d(), id.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_resetCameraViewpoint_const_StringR(self.as_raw_mut_Viz3d(), id.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1696,8 +2016,9 @@ pub trait Viz3dTrait: crate::viz::Viz3dTraitConst {
 	/// Resets camera.
 	#[inline]
 	fn reset_camera(&mut self) -> Result<()> {
+		crate::core::validity::check("Viz3d", self.as_raw_mut_Viz3d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_resetCamera(self.as_raw_mut_Viz3d(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_resetCamera(self.as_raw_mut_Viz3d(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1710,8 +2031,9 @@ pub trait Viz3dTrait: crate::viz::Viz3dTraitConst {
 	/// * window_coord: Output point in window coordinate system.
 	#[inline]
 	fn convert_to_window_coordinates(&mut self, pt: core::Point3d, window_coord: &mut core::Point3d) -> Result<()> {
+		crate::core::validity::check("Viz3d", self.as_raw_mut_Viz3d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_convertToWindowCoordinates_const_Point3dR_Point3dR(self.as_raw_mut_Viz3d(), &pt, window_coord, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_convertToWindowCoordinates_const_Point3dR_Point3dR(self.as_raw_mut_Viz3d(), &pt, window_coord, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1724,8 +2046,9 @@ pub trait Viz3dTrait: crate::viz::Viz3dTraitConst {
 	/// * direction: Output direction of the ray.
 	#[inline]
 	fn conver_to3_d_ray(&mut self, window_coord: core::Point3d, origin: &mut core::Point3d, direction: &mut core::Vec3d) -> Result<()> {
+		crate::core::validity::check("Viz3d", self.as_raw_mut_Viz3d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_converTo3DRay_const_Point3dR_Point3dR_Vec3dR(self.as_raw_mut_Viz3d(), &window_coord, origin, direction, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_converTo3DRay_const_Point3dR_Point3dR_Vec3dR(self.as_raw_mut_Viz3d(), &window_coord, origin, direction, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1737,8 +2060,9 @@ pub trait Viz3dTrait: crate::viz::Viz3dTraitConst {
 	/// * window_size: New size of the window.
 	#[inline]
 	fn set_window_size(&mut self, window_size: core::Size) -> Result<()> {
+		crate::core::validity::check("Viz3d", self.as_raw_mut_Viz3d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_setWindowSize_const_SizeR(self.as_raw_mut_Viz3d(), &window_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_setWindowSize_const_SizeR(self.as_raw_mut_Viz3d(), &window_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1750,9 +2074,10 @@ pub trait Viz3dTrait: crate::viz::Viz3dTraitConst {
 	/// * file: Name of the file.
 	#[inline]
 	fn save_screenshot(&mut self, file: &str) -> Result<()> {
+		crate::core::validity::check("Viz3d", self.as_raw_mut_Viz3d())?;
 		extern_container_arg!(file);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_saveScreenshot_const_StringR(self.as_raw_mut_Viz3d(), file.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_saveScreenshot_const_StringR(self.as_raw_mut_Viz3d(), file.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1764,8 +2089,9 @@ pub trait Viz3dTrait: crate::viz::Viz3dTraitConst {
 	/// * window_position: coordinates of the window
 	#[inline]
 	fn set_window_position(&mut self, window_position: core::Point) -> Result<()> {
+		crate::core::validity::check("Viz3d", self.as_raw_mut_Viz3d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_setWindowPosition_const_PointR(self.as_raw_mut_Viz3d(), &window_position, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_setWindowPosition_const_PointR(self.as_raw_mut_Viz3d(), &window_position, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1780,8 +2106,9 @@ pub trait Viz3dTrait: crate::viz::Viz3dTraitConst {
 	/// * mode: true
 	#[inline]
 	fn set_full_screen(&mut self, mode: bool) -> Result<()> {
+		crate::core::validity::check("Viz3d", self.as_raw_mut_Viz3d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_setFullScreen_bool(self.as_raw_mut_Viz3d(), mode, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_setFullScreen_bool(self.as_raw_mut_Viz3d(), mode, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1794,8 +2121,9 @@ pub trait Viz3dTrait: crate::viz::Viz3dTraitConst {
 	/// * color2: Color::not_set()
 	#[inline]
 	fn set_background_color(&mut self, color: &crate::viz::Viz3d_Color, color2: &crate::viz::Viz3d_Color) -> Result<()> {
+		crate::core::validity::check("Viz3d", self.as_raw_mut_Viz3d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_setBackgroundColor_const_ColorR_const_ColorR(self.as_raw_mut_Viz3d(), color.as_raw_Color(), color2.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_setBackgroundColor_const_ColorR_const_ColorR(self.as_raw_mut_Viz3d(), color.as_raw_Color(), color2.as_raw_Color(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1805,9 +2133,10 @@ pub trait Viz3dTrait: crate::viz::Viz3dTraitConst {
 	/// * image: noArray()
 	#[inline]
 	fn set_background_texture(&mut self, image: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("Viz3d", self.as_raw_mut_Viz3d())?;
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_setBackgroundTexture_const__InputArrayR(self.as_raw_mut_Viz3d(), image.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_setBackgroundTexture_const__InputArrayR(self.as_raw_mut_Viz3d(), image.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1815,8 +2144,9 @@ pub trait Viz3dTrait: crate::viz::Viz3dTraitConst {
 	
 	#[inline]
 	fn set_background_mesh_lab(&mut self) -> Result<()> {
+		crate::core::validity::check("Viz3d", self.as_raw_mut_Viz3d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_setBackgroundMeshLab(self.as_raw_mut_Viz3d(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_setBackgroundMeshLab(self.as_raw_mut_Viz3d(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1825,8 +2155,9 @@ pub trait Viz3dTrait: crate::viz::Viz3dTraitConst {
 	/// The window renders and starts the event loop.
 	#[inline]
 	fn spin(&mut self) -> Result<()> {
+		crate::core::validity::check("Viz3d", self.as_raw_mut_Viz3d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_spin(self.as_raw_mut_Viz3d(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_spin(self.as_raw_mut_Viz3d(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1843,8 +2174,9 @@ pub trait Viz3dTrait: crate::viz::Viz3dTraitConst {
 	/// * force_redraw: false
 	#[inline]
 	fn spin_once(&mut self, time: i32, force_redraw: bool) -> Result<()> {
+		crate::core::validity::check("Viz3d", self.as_raw_mut_Viz3d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_spinOnce_int_bool(self.as_raw_mut_Viz3d(), time, force_redraw, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_spinOnce_int_bool(self.as_raw_mut_Viz3d(), time, force_redraw, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1853,8 +2185,9 @@ pub trait Viz3dTrait: crate::viz::Viz3dTraitConst {
 	/// Create a window in memory instead of on the screen.
 	#[inline]
 	fn set_off_screen_rendering(&mut self) -> Result<()> {
+		crate::core::validity::check("Viz3d", self.as_raw_mut_Viz3d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_setOffScreenRendering(self.as_raw_mut_Viz3d(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_setOffScreenRendering(self.as_raw_mut_Viz3d(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1863,8 +2196,9 @@ pub trait Viz3dTrait: crate::viz::Viz3dTraitConst {
 	/// Remove all lights from the current scene.
 	#[inline]
 	fn remove_all_lights(&mut self) -> Result<()> {
+		crate::core::validity::check("Viz3d", self.as_raw_mut_Viz3d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_removeAllLights(self.as_raw_mut_Viz3d(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_removeAllLights(self.as_raw_mut_Viz3d(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1888,8 +2222,9 @@ pub trait Viz3dTrait: crate::viz::Viz3dTraitConst {
 	/// * specular_color: Color::white()
 	#[inline]
 	fn add_light(&mut self, position: core::Vec3d, focal_point: core::Vec3d, color: &crate::viz::Viz3d_Color, diffuse_color: &crate::viz::Viz3d_Color, ambient_color: &crate::viz::Viz3d_Color, specular_color: &crate::viz::Viz3d_Color) -> Result<()> {
+		crate::core::validity::check("Viz3d", self.as_raw_mut_Viz3d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_addLight_const_Vec3dR_const_Vec3dR_const_ColorR_const_ColorR_const_ColorR_const_ColorR(self.as_raw_mut_Viz3d(), &position, &focal_point, color.as_raw_Color(), diffuse_color.as_raw_Color(), ambient_color.as_raw_Color(), specular_color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_addLight_const_Vec3dR_const_Vec3dR_const_ColorR_const_ColorR_const_ColorR_const_ColorR(self.as_raw_mut_Viz3d(), &position, &focal_point, color.as_raw_Color(), diffuse_color.as_raw_Color(), ambient_color.as_raw_Color(), specular_color.as_raw_Color(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1897,8 +2232,9 @@ pub trait Viz3dTrait: crate::viz::Viz3dTraitConst {
 	
 	#[inline]
 	fn close(&mut self) -> Result<()> {
+		crate::core::validity::check("Viz3d", self.as_raw_mut_Viz3d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_close(self.as_raw_mut_Viz3d(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_close(self.as_raw_mut_Viz3d(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1915,10 +2251,11 @@ pub trait Viz3dTrait: crate::viz::Viz3dTraitConst {
 	/// * cookie: 0
 	#[inline]
 	fn register_keyboard_callback(&mut self, callback: crate::viz::Viz3d_KeyboardCallback) -> Result<()> {
+		crate::core::validity::check("Viz3d", self.as_raw_mut_Viz3d())?;
 		callback_arg!(callback_trampoline(unnamed: *const c_void, unnamed_1: *mut c_void) -> () => unnamed_1 in callbacks => callback(unnamed: *const c_void) -> ());
 		userdata_arg!(cookie in callbacks => callback);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_registerKeyboardCallback_KeyboardCallback_voidX(self.as_raw_mut_Viz3d(), callback_trampoline, cookie, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_registerKeyboardCallback_KeyboardCallback_voidX(self.as_raw_mut_Viz3d(), callback_trampoline, cookie, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1934,10 +2271,11 @@ pub trait Viz3dTrait: crate::viz::Viz3dTraitConst {
 	/// * cookie: 0
 	#[inline]
 	fn register_mouse_callback(&mut self, callback: crate::viz::Viz3d_MouseCallback) -> Result<()> {
+		crate::core::validity::check("Viz3d", self.as_raw_mut_Viz3d())?;
 		callback_arg!(callback_trampoline(unnamed: *const c_void, unnamed_1: *mut c_void) -> () => unnamed_1 in callbacks => callback(unnamed: *const c_void) -> ());
 		userdata_arg!(cookie in callbacks => callback);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_registerMouseCallback_MouseCallback_voidX(self.as_raw_mut_Viz3d(), callback_trampoline, cookie, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_registerMouseCallback_MouseCallback_voidX(self.as_raw_mut_Viz3d(), callback_trampoline, cookie, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1971,9 +2309,10 @@ pub trait Viz3dTrait: crate::viz::Viz3dTraitConst {
 	/// *   **SHADING_PHONG**
 	#[inline]
 	fn set_rendering_property(&mut self, id: &str, property: i32, value: f64) -> Result<()> {
+		crate::core::validity::check("Viz3d", self.as_raw_mut_Viz3d())?;
 		extern_container_arg!(id);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_setRenderingProperty_const_StringR_int_double(self.as_raw_mut_Viz3d(), id.opencv_as_extern(), property, value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_setRenderingProperty_const_StringR_int_double(self.as_raw_mut_Viz3d(), id.opencv_as_extern(), property, value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2006,9 +2345,10 @@ pub trait Viz3dTrait: crate::viz::Viz3dTraitConst {
 	/// *   **SHADING_PHONG**
 	#[inline]
 	fn get_rendering_property(&mut self, id: &str, property: i32) -> Result<f64> {
+		crate::core::validity::check("Viz3d", self.as_raw_mut_Viz3d())?;
 		extern_container_arg!(id);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_getRenderingProperty_const_StringR_int(self.as_raw_mut_Viz3d(), id.opencv_as_extern(), property, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_getRenderingProperty_const_StringR_int(self.as_raw_mut_Viz3d(), id.opencv_as_extern(), property, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2023,8 +2363,9 @@ pub trait Viz3dTrait: crate::viz::Viz3dTraitConst {
 	/// *   **REPRESENTATION_SURFACE**
 	#[inline]
 	fn set_representation(&mut self, representation: i32) -> Result<()> {
+		crate::core::validity::check("Viz3d", self.as_raw_mut_Viz3d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_setRepresentation_int(self.as_raw_mut_Viz3d(), representation, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_setRepresentation_int(self.as_raw_mut_Viz3d(), representation, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2034,8 +2375,9 @@ pub trait Viz3dTrait: crate::viz::Viz3dTraitConst {
 	/// * enabled: false
 	#[inline]
 	fn set_global_warnings(&mut self, enabled: bool) -> Result<()> {
+		crate::core::validity::check("Viz3d", self.as_raw_mut_Viz3d())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_setGlobalWarnings_bool(self.as_raw_mut_Viz3d(), enabled, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_setGlobalWarnings_bool(self.as_raw_mut_Viz3d(), enabled, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2053,7 +2395,10 @@ opencv_type_boxed! { Viz3d }
 impl Drop for Viz3d {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Viz3d_delete(instance: *mut c_void); }
-		unsafe { cv_Viz3d_delete(self.as_raw_mut_Viz3d()) };
+		let ptr = self.as_raw_mut_Viz3d();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Viz3d_delete(ptr) };
 	}
 }
 
@@ -2079,7 +2424,7 @@ impl Viz3d {
 	pub fn new(window_name: &str) -> Result<crate::viz::Viz3d> {
 		extern_container_arg!(window_name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_Viz3d_const_StringR(window_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_Viz3d_const_StringR(window_name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Viz3d::opencv_from_extern(ret) };
@@ -2089,7 +2434,7 @@ impl Viz3d {
 	#[inline]
 	pub fn copy(unnamed: &crate::viz::Viz3d) -> Result<crate::viz::Viz3d> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Viz3d_Viz3d_const_Viz3dR(unnamed.as_raw_Viz3d(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Viz3d_Viz3d_const_Viz3dR(unnamed.as_raw_Viz3d(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Viz3d::opencv_from_extern(ret) };
@@ -2119,7 +2464,10 @@ opencv_type_boxed! { WArrow }
 impl Drop for WArrow {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WArrow_delete(instance: *mut c_void); }
-		unsafe { cv_WArrow_delete(self.as_raw_mut_WArrow()) };
+		let ptr = self.as_raw_mut_WArrow();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_WArrow_delete(ptr) };
 	}
 }
 
@@ -2167,7 +2515,7 @@ impl WArrow {
 	#[inline]
 	pub fn new(pt1: core::Point3d, pt2: core::Point3d, thickness: f64, color: &crate::viz::Color) -> Result<crate::viz::WArrow> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WArrow_WArrow_const_Point3dR_const_Point3dR_double_const_ColorR(&pt1, &pt2, thickness, color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WArrow_WArrow_const_Point3dR_const_Point3dR_double_const_ColorR(&pt1, &pt2, thickness, color.as_raw_Color(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WArrow::opencv_from_extern(ret) };
@@ -2201,7 +2549,10 @@ opencv_type_boxed! { WCameraPosition }
 impl Drop for WCameraPosition {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WCameraPosition_delete(instance: *mut c_void); }
-		unsafe { cv_WCameraPosition_delete(self.as_raw_mut_WCameraPosition()) };
+		let ptr = self.as_raw_mut_WCameraPosition();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_WCameraPosition_delete(ptr) };
 	}
 }
 
@@ -2241,7 +2592,7 @@ impl WCameraPosition {
 	#[inline]
 	pub fn new(scale: f64) -> Result<crate::viz::WCameraPosition> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WCameraPosition_WCameraPosition_double(scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WCameraPosition_WCameraPosition_double(scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WCameraPosition::opencv_from_extern(ret) };
@@ -2264,7 +2615,7 @@ impl WCameraPosition {
 	#[inline]
 	pub fn new_1(k: core::Matx33d, scale: f64, color: &crate::viz::Color) -> Result<crate::viz::WCameraPosition> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WCameraPosition_WCameraPosition_const_Matx33dR_double_const_ColorR(&k, scale, color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WCameraPosition_WCameraPosition_const_Matx33dR_double_const_ColorR(&k, scale, color.as_raw_Color(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WCameraPosition::opencv_from_extern(ret) };
@@ -2287,7 +2638,7 @@ impl WCameraPosition {
 	#[inline]
 	pub fn new_2(fov: core::Vec2d, scale: f64, color: &crate::viz::Color) -> Result<crate::viz::WCameraPosition> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WCameraPosition_WCameraPosition_const_Vec2dR_double_const_ColorR(&fov, scale, color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WCameraPosition_WCameraPosition_const_Vec2dR_double_const_ColorR(&fov, scale, color.as_raw_Color(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WCameraPosition::opencv_from_extern(ret) };
@@ -2314,7 +2665,7 @@ impl WCameraPosition {
 	pub fn new_3(k: core::Matx33d, image: &dyn core::ToInputArray, scale: f64, color: &crate::viz::Color) -> Result<crate::viz::WCameraPosition> {
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WCameraPosition_WCameraPosition_const_Matx33dR_const__InputArrayR_double_const_ColorR(&k, image.as_raw__InputArray(), scale, color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WCameraPosition_WCameraPosition_const_Matx33dR_const__InputArrayR_double_const_ColorR(&k, image.as_raw__InputArray(), scale, color.as_raw_Color(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WCameraPosition::opencv_from_extern(ret) };
@@ -2341,7 +2692,7 @@ impl WCameraPosition {
 	pub fn new_4(fov: core::Vec2d, image: &dyn core::ToInputArray, scale: f64, color: &crate::viz::Color) -> Result<crate::viz::WCameraPosition> {
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WCameraPosition_WCameraPosition_const_Vec2dR_const__InputArrayR_double_const_ColorR(&fov, image.as_raw__InputArray(), scale, color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WCameraPosition_WCameraPosition_const_Vec2dR_const__InputArrayR_double_const_ColorR(&fov, image.as_raw__InputArray(), scale, color.as_raw_Color(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WCameraPosition::opencv_from_extern(ret) };
@@ -2375,7 +2726,10 @@ opencv_type_boxed! { WCircle }
 impl Drop for WCircle {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WCircle_delete(instance: *mut c_void); }
-		unsafe { cv_WCircle_delete(self.as_raw_mut_WCircle()) };
+		let ptr = self.as_raw_mut_WCircle();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_WCircle_delete(ptr) };
 	}
 }
 
@@ -2419,7 +2773,7 @@ impl WCircle {
 	#[inline]
 	pub fn new(radius: f64, thickness: f64, color: &crate::viz::Color) -> Result<crate::viz::WCircle> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WCircle_WCircle_double_double_const_ColorR(radius, thickness, color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WCircle_WCircle_double_double_const_ColorR(radius, thickness, color.as_raw_Color(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WCircle::opencv_from_extern(ret) };
@@ -2441,7 +2795,7 @@ impl WCircle {
 	#[inline]
 	pub fn new_1(radius: f64, center: core::Point3d, normal: core::Vec3d, thickness: f64, color: &crate::viz::Color) -> Result<crate::viz::WCircle> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WCircle_WCircle_double_const_Point3dR_const_Vec3dR_double_const_ColorR(radius, &center, &normal, thickness, color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WCircle_WCircle_double_const_Point3dR_const_Vec3dR_double_const_ColorR(radius, &center, &normal, thickness, color.as_raw_Color(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WCircle::opencv_from_extern(ret) };
@@ -2481,7 +2835,10 @@ opencv_type_boxed! { WCloud }
 impl Drop for WCloud {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WCloud_delete(instance: *mut c_void); }
-		unsafe { cv_WCloud_delete(self.as_raw_mut_WCloud()) };
+		let ptr = self.as_raw_mut_WCloud();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_WCloud_delete(ptr) };
 	}
 }
 
@@ -2524,7 +2881,7 @@ impl WCloud {
 		input_array_arg!(cloud);
 		input_array_arg!(colors);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WCloud_WCloud_const__InputArrayR_const__InputArrayR(cloud.as_raw__InputArray(), colors.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WCloud_WCloud_const__InputArrayR_const__InputArrayR(cloud.as_raw__InputArray(), colors.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WCloud::opencv_from_extern(ret) };
@@ -2544,7 +2901,7 @@ impl WCloud {
 	pub fn new_1(cloud: &dyn core::ToInputArray, color: &crate::viz::Color) -> Result<crate::viz::WCloud> {
 		input_array_arg!(cloud);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WCloud_WCloud_const__InputArrayR_const_ColorR(cloud.as_raw__InputArray(), color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WCloud_WCloud_const__InputArrayR_const_ColorR(cloud.as_raw__InputArray(), color.as_raw_Color(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WCloud::opencv_from_extern(ret) };
@@ -2564,7 +2921,7 @@ impl WCloud {
 		input_array_arg!(colors);
 		input_array_arg!(normals);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WCloud_WCloud_const__InputArrayR_const__InputArrayR_const__InputArrayR(cloud.as_raw__InputArray(), colors.as_raw__InputArray(), normals.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WCloud_WCloud_const__InputArrayR_const__InputArrayR_const__InputArrayR(cloud.as_raw__InputArray(), colors.as_raw__InputArray(), normals.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WCloud::opencv_from_extern(ret) };
@@ -2584,7 +2941,7 @@ impl WCloud {
 		input_array_arg!(cloud);
 		input_array_arg!(normals);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WCloud_WCloud_const__InputArrayR_const_ColorR_const__InputArrayR(cloud.as_raw__InputArray(), color.as_raw_Color(), normals.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WCloud_WCloud_const__InputArrayR_const_ColorR_const__InputArrayR(cloud.as_raw__InputArray(), color.as_raw_Color(), normals.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WCloud::opencv_from_extern(ret) };
@@ -2619,10 +2976,11 @@ pub trait WCloudCollectionTrait: crate::viz::WCloudCollectionTraitConst + crate:
 	/// * pose: Affine3d::Identity()
 	#[inline]
 	fn add_cloud(&mut self, cloud: &dyn core::ToInputArray, colors: &dyn core::ToInputArray, pose: core::Affine3d) -> Result<()> {
+		crate::core::validity::check("WCloudCollection", self.as_raw_mut_WCloudCollection())?;
 		input_array_arg!(cloud);
 		input_array_arg!(colors);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WCloudCollection_addCloud_const__InputArrayR_const__InputArrayR_const_Affine3dR(self.as_raw_mut_WCloudCollection(), cloud.as_raw__InputArray(), colors.as_raw__InputArray(), &pose, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WCloudCollection_addCloud_const__InputArrayR_const__InputArrayR_const_Affine3dR(self.as_raw_mut_WCloudCollection(), cloud.as_raw__InputArray(), colors.as_raw__InputArray(), &pose, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2640,9 +2998,10 @@ pub trait WCloudCollectionTrait: crate::viz::WCloudCollectionTraitConst + crate:
 	/// * pose: Affine3d::Identity()
 	#[inline]
 	fn add_cloud_1(&mut self, cloud: &dyn core::ToInputArray, color: &crate::viz::Color, pose: core::Affine3d) -> Result<()> {
+		crate::core::validity::check("WCloudCollection", self.as_raw_mut_WCloudCollection())?;
 		input_array_arg!(cloud);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WCloudCollection_addCloud_const__InputArrayR_const_ColorR_const_Affine3dR(self.as_raw_mut_WCloudCollection(), cloud.as_raw__InputArray(), color.as_raw_Color(), &pose, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WCloudCollection_addCloud_const__InputArrayR_const_ColorR_const_Affine3dR(self.as_raw_mut_WCloudCollection(), cloud.as_raw__InputArray(), color.as_raw_Color(), &pose, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2653,8 +3012,9 @@ pub trait WCloudCollectionTrait: crate::viz::WCloudCollectionTraitConst + crate:
 	/// Useful for large cloud collections to reduce memory usage
 	#[inline]
 	fn finalize(&mut self) -> Result<()> {
+		crate::core::validity::check("WCloudCollection", self.as_raw_mut_WCloudCollection())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WCloudCollection_finalize(self.as_raw_mut_WCloudCollection(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WCloudCollection_finalize(self.as_raw_mut_WCloudCollection(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2674,7 +3034,10 @@ opencv_type_boxed! { WCloudCollection }
 impl Drop for WCloudCollection {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WCloudCollection_delete(instance: *mut c_void); }
-		unsafe { cv_WCloudCollection_delete(self.as_raw_mut_WCloudCollection()) };
+		let ptr = self.as_raw_mut_WCloudCollection();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_WCloudCollection_delete(ptr) };
 	}
 }
 
@@ -2708,7 +3071,7 @@ impl WCloudCollection {
 	#[inline]
 	pub fn default() -> Result<crate::viz::WCloudCollection> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WCloudCollection_WCloudCollection(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WCloudCollection_WCloudCollection(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WCloudCollection::opencv_from_extern(ret) };
@@ -2742,7 +3105,10 @@ opencv_type_boxed! { WCloudNormals }
 impl Drop for WCloudNormals {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WCloudNormals_delete(instance: *mut c_void); }
-		unsafe { cv_WCloudNormals_delete(self.as_raw_mut_WCloudNormals()) };
+		let ptr = self.as_raw_mut_WCloudNormals();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_WCloudNormals_delete(ptr) };
 	}
 }
 
@@ -2794,7 +3160,7 @@ impl WCloudNormals {
 		input_array_arg!(cloud);
 		input_array_arg!(normals);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WCloudNormals_WCloudNormals_const__InputArrayR_const__InputArrayR_int_double_const_ColorR(cloud.as_raw__InputArray(), normals.as_raw__InputArray(), level, scale, color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WCloudNormals_WCloudNormals_const__InputArrayR_const__InputArrayR_int_double_const_ColorR(cloud.as_raw__InputArray(), normals.as_raw__InputArray(), level, scale, color.as_raw_Color(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WCloudNormals::opencv_from_extern(ret) };
@@ -2803,6 +3169,44 @@ impl WCloudNormals {
 	
 }
 
+/// Builder for `WCloudNormals::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct WCloudNormals_NewBuilder<'a> {
+	cloud: &'a dyn core::ToInputArray,
+	normals: &'a dyn core::ToInputArray,
+	level: i32,
+	scale: f64,
+	color: &'a crate::viz::Color,
+}
+
+impl<'a> WCloudNormals_NewBuilder<'a> {
+	#[inline]
+	pub fn new(cloud: &'a dyn core::ToInputArray, normals: &'a dyn core::ToInputArray, color: &'a crate::viz::Color) -> Self {
+		Self { cloud, normals, level: 64, scale: 0.1, color }
+	}
+	
+	/// C++ default: `64`
+	#[inline]
+	pub fn with_level(mut self, level: i32) -> Self {
+		self.level = level;
+		self
+	}
+	
+	/// C++ default: `0.1`
+	#[inline]
+	pub fn with_scale(mut self, scale: f64) -> Self {
+		self.scale = scale;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::viz::WCloudNormals> {
+		WCloudNormals::new(self.cloud, self.normals, self.level, self.scale, self.color)
+	}
+}
+
 boxed_cast_base! { WCloudNormals, crate::viz::Widget, cv_WCloudNormals_to_Widget }
 
 boxed_cast_base! { WCloudNormals, crate::viz::Widget3D, cv_WCloudNormals_to_Widget3D }
@@ -2828,7 +3232,10 @@ opencv_type_boxed! { WCone }
 impl Drop for WCone {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WCone_delete(instance: *mut c_void); }
-		unsafe { cv_WCone_delete(self.as_raw_mut_WCone()) };
+		let ptr = self.as_raw_mut_WCone();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_WCone_delete(ptr) };
 	}
 }
 
@@ -2873,7 +3280,7 @@ impl WCone {
 	#[inline]
 	pub fn new(length: f64, radius: f64, resolution: i32, color: &crate::viz::Color) -> Result<crate::viz::WCone> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WCone_WCone_double_double_int_const_ColorR(length, radius, resolution, color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WCone_WCone_double_double_int_const_ColorR(length, radius, resolution, color.as_raw_Color(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WCone::opencv_from_extern(ret) };
@@ -2895,7 +3302,7 @@ impl WCone {
 	#[inline]
 	pub fn new_1(radius: f64, center: core::Point3d, tip: core::Point3d, resolution: i32, color: &crate::viz::Color) -> Result<crate::viz::WCone> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WCone_WCone_double_const_Point3dR_const_Point3dR_int_const_ColorR(radius, &center, &tip, resolution, color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WCone_WCone_double_const_Point3dR_const_Point3dR_int_const_ColorR(radius, &center, &tip, resolution, color.as_raw_Color(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WCone::opencv_from_extern(ret) };
@@ -2929,7 +3336,10 @@ opencv_type_boxed! { WCoordinateSystem }
 impl Drop for WCoordinateSystem {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WCoordinateSystem_delete(instance: *mut c_void); }
-		unsafe { cv_WCoordinateSystem_delete(self.as_raw_mut_WCoordinateSystem()) };
+		let ptr = self.as_raw_mut_WCoordinateSystem();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_WCoordinateSystem_delete(ptr) };
 	}
 }
 
@@ -2970,7 +3380,7 @@ impl WCoordinateSystem {
 	#[inline]
 	pub fn new(scale: f64) -> Result<crate::viz::WCoordinateSystem> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WCoordinateSystem_WCoordinateSystem_double(scale, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WCoordinateSystem_WCoordinateSystem_double(scale, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WCoordinateSystem::opencv_from_extern(ret) };
@@ -3004,7 +3414,10 @@ opencv_type_boxed! { WCube }
 impl Drop for WCube {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WCube_delete(instance: *mut c_void); }
-		unsafe { cv_WCube_delete(self.as_raw_mut_WCube()) };
+		let ptr = self.as_raw_mut_WCube();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_WCube_delete(ptr) };
 	}
 }
 
@@ -3053,7 +3466,7 @@ impl WCube {
 	#[inline]
 	pub fn new(min_point: core::Point3d, max_point: core::Point3d, wire_frame: bool, color: &crate::viz::Color) -> Result<crate::viz::WCube> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WCube_WCube_const_Point3dR_const_Point3dR_bool_const_ColorR(&min_point, &max_point, wire_frame, color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WCube_WCube_const_Point3dR_const_Point3dR_bool_const_ColorR(&min_point, &max_point, wire_frame, color.as_raw_Color(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WCube::opencv_from_extern(ret) };
@@ -3062,6 +3475,36 @@ impl WCube {
 	
 }
 
+/// Builder for `WCube::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct WCube_NewBuilder<'a> {
+	min_point: core::Point3d,
+	max_point: core::Point3d,
+	wire_frame: bool,
+	color: &'a crate::viz::Color,
+}
+
+impl<'a> WCube_NewBuilder<'a> {
+	#[inline]
+	pub fn new(min_point: core::Point3d, max_point: core::Point3d, color: &'a crate::viz::Color) -> Self {
+		Self { min_point, max_point, wire_frame: true, color }
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_wire_frame(mut self, wire_frame: bool) -> Self {
+		self.wire_frame = wire_frame;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::viz::WCube> {
+		WCube::new(self.min_point, self.max_point, self.wire_frame, self.color)
+	}
+}
+
 boxed_cast_base! { WCube, crate::viz::Widget, cv_WCube_to_Widget }
 
 boxed_cast_base! { WCube, crate::viz::Widget3D, cv_WCube_to_Widget3D }
@@ -3087,7 +3530,10 @@ opencv_type_boxed! { WCylinder }
 impl Drop for WCylinder {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WCylinder_delete(instance: *mut c_void); }
-		unsafe { cv_WCylinder_delete(self.as_raw_mut_WCylinder()) };
+		let ptr = self.as_raw_mut_WCylinder();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_WCylinder_delete(ptr) };
 	}
 }
 
@@ -3133,7 +3579,7 @@ impl WCylinder {
 	#[inline]
 	pub fn new(axis_point1: core::Point3d, axis_point2: core::Point3d, radius: f64, numsides: i32, color: &crate::viz::Color) -> Result<crate::viz::WCylinder> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WCylinder_WCylinder_const_Point3dR_const_Point3dR_double_int_const_ColorR(&axis_point1, &axis_point2, radius, numsides, color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WCylinder_WCylinder_const_Point3dR_const_Point3dR_double_int_const_ColorR(&axis_point1, &axis_point2, radius, numsides, color.as_raw_Color(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WCylinder::opencv_from_extern(ret) };
@@ -3167,7 +3613,10 @@ opencv_type_boxed! { WGrid }
 impl Drop for WGrid {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WGrid_delete(instance: *mut c_void); }
-		unsafe { cv_WGrid_delete(self.as_raw_mut_WGrid()) };
+		let ptr = self.as_raw_mut_WGrid();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_WGrid_delete(ptr) };
 	}
 }
 
@@ -3212,7 +3661,7 @@ impl WGrid {
 	#[inline]
 	pub fn new(cells: core::Vec2i, cells_spacing: core::Vec2d, color: &crate::viz::Color) -> Result<crate::viz::WGrid> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WGrid_WGrid_const_Vec2iR_const_Vec2dR_const_ColorR(&cells, &cells_spacing, color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WGrid_WGrid_const_Vec2iR_const_Vec2dR_const_ColorR(&cells, &cells_spacing, color.as_raw_Color(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WGrid::opencv_from_extern(ret) };
@@ -3228,7 +3677,7 @@ impl WGrid {
 	#[inline]
 	pub fn new_1(center: core::Point3d, normal: core::Vec3d, new_yaxis: core::Vec3d, cells: core::Vec2i, cells_spacing: core::Vec2d, color: &crate::viz::Color) -> Result<crate::viz::WGrid> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WGrid_WGrid_const_Point3dR_const_Vec3dR_const_Vec3dR_const_Vec2iR_const_Vec2dR_const_ColorR(&center, &normal, &new_yaxis, &cells, &cells_spacing, color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WGrid_WGrid_const_Point3dR_const_Vec3dR_const_Vec3dR_const_Vec2iR_const_Vec2dR_const_ColorR(&center, &normal, &new_yaxis, &cells, &cells_spacing, color.as_raw_Color(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WGrid::opencv_from_extern(ret) };
@@ -3256,9 +3705,10 @@ pub trait WImage3DTrait: crate::viz::WImage3DTraitConst + crate::viz::Widget3DTr
 	/// * image: BGR or Gray-Scale image.
 	#[inline]
 	fn set_image(&mut self, image: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("WImage3D", self.as_raw_mut_WImage3D())?;
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WImage3D_setImage_const__InputArrayR(self.as_raw_mut_WImage3D(), image.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WImage3D_setImage_const__InputArrayR(self.as_raw_mut_WImage3D(), image.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3270,8 +3720,9 @@ pub trait WImage3DTrait: crate::viz::WImage3DTraitConst + crate::viz::Widget3DTr
 	/// * size: the new size of the image.
 	#[inline]
 	fn set_size(&mut self, size: core::Size) -> Result<()> {
+		crate::core::validity::check("WImage3D", self.as_raw_mut_WImage3D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WImage3D_setSize_const_SizeR(self.as_raw_mut_WImage3D(), &size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WImage3D_setSize_const_SizeR(self.as_raw_mut_WImage3D(), &size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3289,7 +3740,10 @@ opencv_type_boxed! { WImage3D }
 impl Drop for WImage3D {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WImage3D_delete(instance: *mut c_void); }
-		unsafe { cv_WImage3D_delete(self.as_raw_mut_WImage3D()) };
+		let ptr = self.as_raw_mut_WImage3D();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_WImage3D_delete(ptr) };
 	}
 }
 
@@ -3329,7 +3783,7 @@ impl WImage3D {
 	pub fn new(image: &dyn core::ToInputArray, size: core::Size2d) -> Result<crate::viz::WImage3D> {
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WImage3D_WImage3D_const__InputArrayR_const_Size2dR(image.as_raw__InputArray(), &size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WImage3D_WImage3D_const__InputArrayR_const_Size2dR(image.as_raw__InputArray(), &size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WImage3D::opencv_from_extern(ret) };
@@ -3348,7 +3802,7 @@ impl WImage3D {
 	pub fn new_1(image: &dyn core::ToInputArray, size: core::Size2d, center: core::Vec3d, normal: core::Vec3d, up_vector: core::Vec3d) -> Result<crate::viz::WImage3D> {
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WImage3D_WImage3D_const__InputArrayR_const_Size2dR_const_Vec3dR_const_Vec3dR_const_Vec3dR(image.as_raw__InputArray(), &size, &center, &normal, &up_vector, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WImage3D_WImage3D_const__InputArrayR_const_Size2dR_const_Vec3dR_const_Vec3dR_const_Vec3dR(image.as_raw__InputArray(), &size, &center, &normal, &up_vector, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WImage3D::opencv_from_extern(ret) };
@@ -3376,9 +3830,10 @@ pub trait WImageOverlayTrait: crate::viz::WImageOverlayTraitConst + crate::viz::
 	/// * image: BGR or Gray-Scale image.
 	#[inline]
 	fn set_image(&mut self, image: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("WImageOverlay", self.as_raw_mut_WImageOverlay())?;
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WImageOverlay_setImage_const__InputArrayR(self.as_raw_mut_WImageOverlay(), image.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WImageOverlay_setImage_const__InputArrayR(self.as_raw_mut_WImageOverlay(), image.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3396,7 +3851,10 @@ opencv_type_boxed! { WImageOverlay }
 impl Drop for WImageOverlay {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WImageOverlay_delete(instance: *mut c_void); }
-		unsafe { cv_WImageOverlay_delete(self.as_raw_mut_WImageOverlay()) };
+		let ptr = self.as_raw_mut_WImageOverlay();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_WImageOverlay_delete(ptr) };
 	}
 }
 
@@ -3436,7 +3894,7 @@ impl WImageOverlay {
 	pub fn new(image: &dyn core::ToInputArray, rect: core::Rect) -> Result<crate::viz::WImageOverlay> {
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WImageOverlay_WImageOverlay_const__InputArrayR_const_RectR(image.as_raw__InputArray(), &rect, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WImageOverlay_WImageOverlay_const__InputArrayR_const_RectR(image.as_raw__InputArray(), &rect, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WImageOverlay::opencv_from_extern(ret) };
@@ -3470,7 +3928,10 @@ opencv_type_boxed! { WLine }
 impl Drop for WLine {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WLine_delete(instance: *mut c_void); }
-		unsafe { cv_WLine_delete(self.as_raw_mut_WLine()) };
+		let ptr = self.as_raw_mut_WLine();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_WLine_delete(ptr) };
 	}
 }
 
@@ -3513,7 +3974,7 @@ impl WLine {
 	#[inline]
 	pub fn new(pt1: core::Point3d, pt2: core::Point3d, color: &crate::viz::Color) -> Result<crate::viz::WLine> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WLine_WLine_const_Point3dR_const_Point3dR_const_ColorR(&pt1, &pt2, color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WLine_WLine_const_Point3dR_const_Point3dR_const_ColorR(&pt1, &pt2, color.as_raw_Color(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WLine::opencv_from_extern(ret) };
@@ -3561,7 +4022,10 @@ opencv_type_boxed! { WMesh }
 impl Drop for WMesh {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WMesh_delete(instance: *mut c_void); }
-		unsafe { cv_WMesh_delete(self.as_raw_mut_WMesh()) };
+		let ptr = self.as_raw_mut_WMesh();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_WMesh_delete(ptr) };
 	}
 }
 
@@ -3595,7 +4059,7 @@ impl WMesh {
 	#[inline]
 	pub fn new(mesh: &crate::viz::Mesh) -> Result<crate::viz::WMesh> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WMesh_WMesh_const_MeshR(mesh.as_raw_Mesh(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WMesh_WMesh_const_MeshR(mesh.as_raw_Mesh(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WMesh::opencv_from_extern(ret) };
@@ -3612,7 +4076,7 @@ impl WMesh {
 		input_array_arg!(colors);
 		input_array_arg!(normals);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WMesh_WMesh_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR(cloud.as_raw__InputArray(), polygons.as_raw__InputArray(), colors.as_raw__InputArray(), normals.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WMesh_WMesh_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR(cloud.as_raw__InputArray(), polygons.as_raw__InputArray(), colors.as_raw__InputArray(), normals.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WMesh::opencv_from_extern(ret) };
@@ -3644,7 +4108,10 @@ opencv_type_boxed! { WPaintedCloud }
 impl Drop for WPaintedCloud {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WPaintedCloud_delete(instance: *mut c_void); }
-		unsafe { cv_WPaintedCloud_delete(self.as_raw_mut_WPaintedCloud()) };
+		let ptr = self.as_raw_mut_WPaintedCloud();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_WPaintedCloud_delete(ptr) };
 	}
 }
 
@@ -3680,7 +4147,7 @@ impl WPaintedCloud {
 	pub fn new(cloud: &dyn core::ToInputArray) -> Result<crate::viz::WPaintedCloud> {
 		input_array_arg!(cloud);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WPaintedCloud_WPaintedCloud_const__InputArrayR(cloud.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WPaintedCloud_WPaintedCloud_const__InputArrayR(cloud.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WPaintedCloud::opencv_from_extern(ret) };
@@ -3692,7 +4159,7 @@ impl WPaintedCloud {
 	pub fn new_1(cloud: &dyn core::ToInputArray, p1: core::Point3d, p2: core::Point3d) -> Result<crate::viz::WPaintedCloud> {
 		input_array_arg!(cloud);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WPaintedCloud_WPaintedCloud_const__InputArrayR_const_Point3dR_const_Point3dR(cloud.as_raw__InputArray(), &p1, &p2, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WPaintedCloud_WPaintedCloud_const__InputArrayR_const_Point3dR_const_Point3dR(cloud.as_raw__InputArray(), &p1, &p2, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WPaintedCloud::opencv_from_extern(ret) };
@@ -3704,7 +4171,7 @@ impl WPaintedCloud {
 	pub fn new_2(cloud: &dyn core::ToInputArray, p1: core::Point3d, p2: core::Point3d, c1: &crate::viz::Color, c2: crate::viz::Color) -> Result<crate::viz::WPaintedCloud> {
 		input_array_arg!(cloud);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WPaintedCloud_WPaintedCloud_const__InputArrayR_const_Point3dR_const_Point3dR_const_ColorR_const_Color(cloud.as_raw__InputArray(), &p1, &p2, c1.as_raw_Color(), c2.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WPaintedCloud_WPaintedCloud_const__InputArrayR_const_Point3dR_const_Point3dR_const_ColorR_const_Color(cloud.as_raw__InputArray(), &p1, &p2, c1.as_raw_Color(), c2.as_raw_Color(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WPaintedCloud::opencv_from_extern(ret) };
@@ -3738,7 +4205,10 @@ opencv_type_boxed! { WPlane }
 impl Drop for WPlane {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WPlane_delete(instance: *mut c_void); }
-		unsafe { cv_WPlane_delete(self.as_raw_mut_WPlane()) };
+		let ptr = self.as_raw_mut_WPlane();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_WPlane_delete(ptr) };
 	}
 }
 
@@ -3781,7 +4251,7 @@ impl WPlane {
 	#[inline]
 	pub fn new(size: core::Size2d, color: &crate::viz::Color) -> Result<crate::viz::WPlane> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WPlane_WPlane_const_Size2dR_const_ColorR(&size, color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WPlane_WPlane_const_Size2dR_const_ColorR(&size, color.as_raw_Color(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WPlane::opencv_from_extern(ret) };
@@ -3803,7 +4273,7 @@ impl WPlane {
 	#[inline]
 	pub fn new_1(center: core::Point3d, normal: core::Vec3d, new_yaxis: core::Vec3d, size: core::Size2d, color: &crate::viz::Color) -> Result<crate::viz::WPlane> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WPlane_WPlane_const_Point3dR_const_Vec3dR_const_Vec3dR_const_Size2dR_const_ColorR(&center, &normal, &new_yaxis, &size, color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WPlane_WPlane_const_Point3dR_const_Vec3dR_const_Vec3dR_const_Size2dR_const_ColorR(&center, &normal, &new_yaxis, &size, color.as_raw_Color(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WPlane::opencv_from_extern(ret) };
@@ -3837,7 +4307,10 @@ opencv_type_boxed! { WPolyLine }
 impl Drop for WPolyLine {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WPolyLine_delete(instance: *mut c_void); }
-		unsafe { cv_WPolyLine_delete(self.as_raw_mut_WPolyLine()) };
+		let ptr = self.as_raw_mut_WPolyLine();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_WPolyLine_delete(ptr) };
 	}
 }
 
@@ -3873,7 +4346,7 @@ impl WPolyLine {
 		input_array_arg!(points);
 		input_array_arg!(colors);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WPolyLine_WPolyLine_const__InputArrayR_const__InputArrayR(points.as_raw__InputArray(), colors.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WPolyLine_WPolyLine_const__InputArrayR_const__InputArrayR(points.as_raw__InputArray(), colors.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WPolyLine::opencv_from_extern(ret) };
@@ -3892,7 +4365,7 @@ impl WPolyLine {
 	pub fn new_1(points: &dyn core::ToInputArray, color: &crate::viz::Color) -> Result<crate::viz::WPolyLine> {
 		input_array_arg!(points);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WPolyLine_WPolyLine_const__InputArrayR_const_ColorR(points.as_raw__InputArray(), color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WPolyLine_WPolyLine_const__InputArrayR_const_ColorR(points.as_raw__InputArray(), color.as_raw_Color(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WPolyLine::opencv_from_extern(ret) };
@@ -3926,7 +4399,10 @@ opencv_type_boxed! { WSphere }
 impl Drop for WSphere {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WSphere_delete(instance: *mut c_void); }
-		unsafe { cv_WSphere_delete(self.as_raw_mut_WSphere()) };
+		let ptr = self.as_raw_mut_WSphere();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_WSphere_delete(ptr) };
 	}
 }
 
@@ -3971,7 +4447,7 @@ impl WSphere {
 	#[inline]
 	pub fn new(center: core::Point3d, radius: f64, sphere_resolution: i32, color: &crate::viz::Color) -> Result<crate::viz::WSphere> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WSphere_WSphere_const_Point3dR_double_int_const_ColorR(&center, radius, sphere_resolution, color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WSphere_WSphere_const_Point3dR_double_int_const_ColorR(&center, radius, sphere_resolution, color.as_raw_Color(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WSphere::opencv_from_extern(ret) };
@@ -3991,8 +4467,9 @@ pub trait WTextTraitConst: crate::viz::Widget2DTraitConst {
 	/// Returns the current text content of the widget.
 	#[inline]
 	fn get_text(&self) -> Result<String> {
+		crate::core::validity::check("WText", self.as_raw_WText())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WText_getText_const(self.as_raw_WText(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WText_getText_const(self.as_raw_WText(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -4010,9 +4487,10 @@ pub trait WTextTrait: crate::viz::WTextTraitConst + crate::viz::Widget2DTrait {
 	/// * text: Text content of the widget.
 	#[inline]
 	fn set_text(&mut self, text: &str) -> Result<()> {
+		crate::core::validity::check("WText", self.as_raw_mut_WText())?;
 		extern_container_arg!(text);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WText_setText_const_StringR(self.as_raw_mut_WText(), text.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WText_setText_const_StringR(self.as_raw_mut_WText(), text.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4030,7 +4508,10 @@ opencv_type_boxed! { WText }
 impl Drop for WText {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WText_delete(instance: *mut c_void); }
-		unsafe { cv_WText_delete(self.as_raw_mut_WText()) };
+		let ptr = self.as_raw_mut_WText();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_WText_delete(ptr) };
 	}
 }
 
@@ -4076,7 +4557,7 @@ impl WText {
 	pub fn new(text: &str, pos: core::Point, font_size: i32, color: &crate::viz::Color) -> Result<crate::viz::WText> {
 		extern_container_arg!(text);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WText_WText_const_StringR_const_PointR_int_const_ColorR(text.opencv_as_extern(), &pos, font_size, color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WText_WText_const_StringR_const_PointR_int_const_ColorR(text.opencv_as_extern(), &pos, font_size, color.as_raw_Color(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WText::opencv_from_extern(ret) };
@@ -4096,8 +4577,9 @@ pub trait WText3DTraitConst: crate::viz::Widget3DTraitConst {
 	/// Returns the current text content of the widget.
 	#[inline]
 	fn get_text(&self) -> Result<String> {
+		crate::core::validity::check("WText3D", self.as_raw_WText3D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WText3D_getText_const(self.as_raw_WText3D(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WText3D_getText_const(self.as_raw_WText3D(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { String::opencv_from_extern(ret) };
@@ -4115,9 +4597,10 @@ pub trait WText3DTrait: crate::viz::WText3DTraitConst + crate::viz::Widget3DTrai
 	/// * text: Text content of the widget.
 	#[inline]
 	fn set_text(&mut self, text: &str) -> Result<()> {
+		crate::core::validity::check("WText3D", self.as_raw_mut_WText3D())?;
 		extern_container_arg!(text);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WText3D_setText_const_StringR(self.as_raw_mut_WText3D(), text.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WText3D_setText_const_StringR(self.as_raw_mut_WText3D(), text.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4135,7 +4618,10 @@ opencv_type_boxed! { WText3D }
 impl Drop for WText3D {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WText3D_delete(instance: *mut c_void); }
-		unsafe { cv_WText3D_delete(self.as_raw_mut_WText3D()) };
+		let ptr = self.as_raw_mut_WText3D();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_WText3D_delete(ptr) };
 	}
 }
 
@@ -4183,7 +4669,7 @@ impl WText3D {
 	pub fn new(text: &str, position: core::Point3d, text_scale: f64, face_camera: bool, color: &crate::viz::Color) -> Result<crate::viz::WText3D> {
 		extern_container_arg!(text);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WText3D_WText3D_const_StringR_const_Point3dR_double_bool_const_ColorR(text.opencv_as_extern(), &position, text_scale, face_camera, color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WText3D_WText3D_const_StringR_const_Point3dR_double_bool_const_ColorR(text.opencv_as_extern(), &position, text_scale, face_camera, color.as_raw_Color(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WText3D::opencv_from_extern(ret) };
@@ -4192,6 +4678,44 @@ impl WText3D {
 	
 }
 
+/// Builder for `WText3D::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct WText3D_NewBuilder<'a> {
+	text: &'a str,
+	position: core::Point3d,
+	text_scale: f64,
+	face_camera: bool,
+	color: &'a crate::viz::Color,
+}
+
+impl<'a> WText3D_NewBuilder<'a> {
+	#[inline]
+	pub fn new(text: &'a str, position: core::Point3d, color: &'a crate::viz::Color) -> Self {
+		Self { text, position, text_scale: 1., face_camera: true, color }
+	}
+	
+	/// C++ default: `1.`
+	#[inline]
+	pub fn with_text_scale(mut self, text_scale: f64) -> Self {
+		self.text_scale = text_scale;
+		self
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_face_camera(mut self, face_camera: bool) -> Self {
+		self.face_camera = face_camera;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::viz::WText3D> {
+		WText3D::new(self.text, self.position, self.text_scale, self.face_camera, self.color)
+	}
+}
+
 boxed_cast_base! { WText3D, crate::viz::Widget, cv_WText3D_to_Widget }
 
 boxed_cast_base! { WText3D, crate::viz::Widget3D, cv_WText3D_to_Widget3D }
@@ -4217,7 +4741,10 @@ opencv_type_boxed! { WTrajectory }
 impl Drop for WTrajectory {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WTrajectory_delete(instance: *mut c_void); }
-		unsafe { cv_WTrajectory_delete(self.as_raw_mut_WTrajectory()) };
+		let ptr = self.as_raw_mut_WTrajectory();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_WTrajectory_delete(ptr) };
 	}
 }
 
@@ -4251,7 +4778,7 @@ impl WTrajectory {
 	/// Constructs a WTrajectory.
 	/// 
 	/// ## Parameters
-	/// * path: List of poses on a trajectory. Takes std::vector\<Affine\<T\>\> with T == [float | double]
+	/// * path: List of poses on a trajectory. Takes std::vector\<Affine\<T\>\> with T == \[float | double\]
 	/// * display_mode: Display mode. This can be PATH, FRAMES, and BOTH.
 	/// * scale: Scale of the frames. Polyline is not affected.
 	/// * color: Color of the polyline that represents path.
@@ -4270,7 +4797,7 @@ impl WTrajectory {
 	pub fn new(path: &dyn core::ToInputArray, display_mode: i32, scale: f64, color: &crate::viz::Color) -> Result<crate::viz::WTrajectory> {
 		input_array_arg!(path);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WTrajectory_WTrajectory_const__InputArrayR_int_double_const_ColorR(path.as_raw__InputArray(), display_mode, scale, color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WTrajectory_WTrajectory_const__InputArrayR_int_double_const_ColorR(path.as_raw__InputArray(), display_mode, scale, color.as_raw_Color(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WTrajectory::opencv_from_extern(ret) };
@@ -4279,6 +4806,36 @@ impl WTrajectory {
 	
 }
 
+/// Builder for `WTrajectory::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct WTrajectory_NewBuilder<'a> {
+	path: &'a dyn core::ToInputArray,
+	display_mode: i32,
+	scale: f64,
+	color: &'a crate::viz::Color,
+}
+
+impl<'a> WTrajectory_NewBuilder<'a> {
+	#[inline]
+	pub fn new(path: &'a dyn core::ToInputArray, display_mode: i32, color: &'a crate::viz::Color) -> Self {
+		Self { path, display_mode, scale: 1.0, color }
+	}
+	
+	/// C++ default: `1.0`
+	#[inline]
+	pub fn with_scale(mut self, scale: f64) -> Self {
+		self.scale = scale;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::viz::WTrajectory> {
+		WTrajectory::new(self.path, self.display_mode, self.scale, self.color)
+	}
+}
+
 boxed_cast_base! { WTrajectory, crate::viz::Widget, cv_WTrajectory_to_Widget }
 
 boxed_cast_base! { WTrajectory, crate::viz::Widget3D, cv_WTrajectory_to_Widget3D }
@@ -4304,7 +4861,10 @@ opencv_type_boxed! { WTrajectoryFrustums }
 impl Drop for WTrajectoryFrustums {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WTrajectoryFrustums_delete(instance: *mut c_void); }
-		unsafe { cv_WTrajectoryFrustums_delete(self.as_raw_mut_WTrajectoryFrustums()) };
+		let ptr = self.as_raw_mut_WTrajectoryFrustums();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_WTrajectoryFrustums_delete(ptr) };
 	}
 }
 
@@ -4338,7 +4898,7 @@ impl WTrajectoryFrustums {
 	/// Constructs a WTrajectoryFrustums.
 	/// 
 	/// ## Parameters
-	/// * path: List of poses on a trajectory. Takes std::vector\<Affine\<T\>\> with T == [float | double]
+	/// * path: List of poses on a trajectory. Takes std::vector\<Affine\<T\>\> with T == \[float | double\]
 	/// * K: Intrinsic matrix of the camera.
 	/// * scale: Scale of the frustums.
 	/// * color: Color of the frustums.
@@ -4352,7 +4912,7 @@ impl WTrajectoryFrustums {
 	pub fn new(path: &dyn core::ToInputArray, k: core::Matx33d, scale: f64, color: &crate::viz::Color) -> Result<crate::viz::WTrajectoryFrustums> {
 		input_array_arg!(path);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WTrajectoryFrustums_WTrajectoryFrustums_const__InputArrayR_const_Matx33dR_double_const_ColorR(path.as_raw__InputArray(), &k, scale, color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WTrajectoryFrustums_WTrajectoryFrustums_const__InputArrayR_const_Matx33dR_double_const_ColorR(path.as_raw__InputArray(), &k, scale, color.as_raw_Color(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WTrajectoryFrustums::opencv_from_extern(ret) };
@@ -4362,7 +4922,7 @@ impl WTrajectoryFrustums {
 	/// Constructs a WTrajectoryFrustums.
 	/// 
 	/// ## Parameters
-	/// * path: List of poses on a trajectory. Takes std::vector\<Affine\<T\>\> with T == [float | double]
+	/// * path: List of poses on a trajectory. Takes std::vector\<Affine\<T\>\> with T == \[float | double\]
 	/// * fov: Field of view of the camera (horizontal, vertical).
 	/// * scale: Scale of the frustums.
 	/// * color: Color of the frustums.
@@ -4376,7 +4936,7 @@ impl WTrajectoryFrustums {
 	pub fn new_1(path: &dyn core::ToInputArray, fov: core::Vec2d, scale: f64, color: &crate::viz::Color) -> Result<crate::viz::WTrajectoryFrustums> {
 		input_array_arg!(path);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WTrajectoryFrustums_WTrajectoryFrustums_const__InputArrayR_const_Vec2dR_double_const_ColorR(path.as_raw__InputArray(), &fov, scale, color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WTrajectoryFrustums_WTrajectoryFrustums_const__InputArrayR_const_Vec2dR_double_const_ColorR(path.as_raw__InputArray(), &fov, scale, color.as_raw_Color(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WTrajectoryFrustums::opencv_from_extern(ret) };
@@ -4416,7 +4976,10 @@ opencv_type_boxed! { WTrajectorySpheres }
 impl Drop for WTrajectorySpheres {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WTrajectorySpheres_delete(instance: *mut c_void); }
-		unsafe { cv_WTrajectorySpheres_delete(self.as_raw_mut_WTrajectorySpheres()) };
+		let ptr = self.as_raw_mut_WTrajectorySpheres();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_WTrajectorySpheres_delete(ptr) };
 	}
 }
 
@@ -4450,7 +5013,7 @@ impl WTrajectorySpheres {
 	/// Constructs a WTrajectorySpheres.
 	/// 
 	/// ## Parameters
-	/// * path: List of poses on a trajectory. Takes std::vector\<Affine\<T\>\> with T == [float | double]
+	/// * path: List of poses on a trajectory. Takes std::vector\<Affine\<T\>\> with T == \[float | double\]
 	/// * line_length: Max length of the lines which point to previous position
 	/// * radius: Radius of the spheres.
 	/// * from: Color for first sphere.
@@ -4465,7 +5028,7 @@ impl WTrajectorySpheres {
 	pub fn new(path: &dyn core::ToInputArray, line_length: f64, radius: f64, from: &crate::viz::Color, to: &crate::viz::Color) -> Result<crate::viz::WTrajectorySpheres> {
 		input_array_arg!(path);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WTrajectorySpheres_WTrajectorySpheres_const__InputArrayR_double_double_const_ColorR_const_ColorR(path.as_raw__InputArray(), line_length, radius, from.as_raw_Color(), to.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WTrajectorySpheres_WTrajectorySpheres_const__InputArrayR_double_double_const_ColorR_const_ColorR(path.as_raw__InputArray(), line_length, radius, from.as_raw_Color(), to.as_raw_Color(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WTrajectorySpheres::opencv_from_extern(ret) };
@@ -4474,6 +5037,44 @@ impl WTrajectorySpheres {
 	
 }
 
+/// Builder for `WTrajectorySpheres::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct WTrajectorySpheres_NewBuilder<'a> {
+	path: &'a dyn core::ToInputArray,
+	line_length: f64,
+	radius: f64,
+	from: &'a crate::viz::Color,
+	to: &'a crate::viz::Color,
+}
+
+impl<'a> WTrajectorySpheres_NewBuilder<'a> {
+	#[inline]
+	pub fn new(path: &'a dyn core::ToInputArray, from: &'a crate::viz::Color, to: &'a crate::viz::Color) -> Self {
+		Self { path, line_length: 0.05, radius: 0.007, from, to }
+	}
+	
+	/// C++ default: `0.05`
+	#[inline]
+	pub fn with_line_length(mut self, line_length: f64) -> Self {
+		self.line_length = line_length;
+		self
+	}
+	
+	/// C++ default: `0.007`
+	#[inline]
+	pub fn with_radius(mut self, radius: f64) -> Self {
+		self.radius = radius;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::viz::WTrajectorySpheres> {
+		WTrajectorySpheres::new(self.path, self.line_length, self.radius, self.from, self.to)
+	}
+}
+
 boxed_cast_base! { WTrajectorySpheres, crate::viz::Widget, cv_WTrajectorySpheres_to_Widget }
 
 boxed_cast_base! { WTrajectorySpheres, crate::viz::Widget3D, cv_WTrajectorySpheres_to_Widget3D }
@@ -4498,8 +5099,9 @@ pub trait WWidgetMergerTrait: crate::viz::WWidgetMergerTraitConst + crate::viz::
 	/// * pose: Affine3d::Identity()
 	#[inline]
 	fn add_widget(&mut self, widget: &crate::viz::Widget3D, pose: core::Affine3d) -> Result<()> {
+		crate::core::validity::check("WWidgetMerger", self.as_raw_mut_WWidgetMerger())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WWidgetMerger_addWidget_const_Widget3DR_const_Affine3dR(self.as_raw_mut_WWidgetMerger(), widget.as_raw_Widget3D(), &pose, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WWidgetMerger_addWidget_const_Widget3DR_const_Affine3dR(self.as_raw_mut_WWidgetMerger(), widget.as_raw_Widget3D(), &pose, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4508,8 +5110,9 @@ pub trait WWidgetMergerTrait: crate::viz::WWidgetMergerTraitConst + crate::viz::
 	/// Repacks internal structure to single widget
 	#[inline]
 	fn finalize(&mut self) -> Result<()> {
+		crate::core::validity::check("WWidgetMerger", self.as_raw_mut_WWidgetMerger())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WWidgetMerger_finalize(self.as_raw_mut_WWidgetMerger(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WWidgetMerger_finalize(self.as_raw_mut_WWidgetMerger(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4532,7 +5135,10 @@ opencv_type_boxed! { WWidgetMerger }
 impl Drop for WWidgetMerger {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WWidgetMerger_delete(instance: *mut c_void); }
-		unsafe { cv_WWidgetMerger_delete(self.as_raw_mut_WWidgetMerger()) };
+		let ptr = self.as_raw_mut_WWidgetMerger();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_WWidgetMerger_delete(ptr) };
 	}
 }
 
@@ -4566,7 +5172,7 @@ impl WWidgetMerger {
 	#[inline]
 	pub fn default() -> Result<crate::viz::WWidgetMerger> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_WWidgetMerger_WWidgetMerger(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_WWidgetMerger_WWidgetMerger(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::WWidgetMerger::opencv_from_extern(ret) };
@@ -4610,8 +5216,9 @@ pub trait WidgetTraitConst {
 	/// *   **SHADING_PHONG**
 	#[inline]
 	fn get_rendering_property(&self, property: i32) -> Result<f64> {
+		crate::core::validity::check("Widget", self.as_raw_Widget())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Widget_getRenderingProperty_const_int(self.as_raw_Widget(), property, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Widget_getRenderingProperty_const_int(self.as_raw_Widget(), property, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4649,8 +5256,9 @@ pub trait WidgetTrait: crate::viz::WidgetTraitConst {
 	/// *   **SHADING_PHONG**
 	#[inline]
 	fn set_rendering_property(&mut self, property: i32, value: f64) -> Result<()> {
+		crate::core::validity::check("Widget", self.as_raw_mut_Widget())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Widget_setRenderingProperty_int_double(self.as_raw_mut_Widget(), property, value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Widget_setRenderingProperty_int_double(self.as_raw_mut_Widget(), property, value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4668,7 +5276,10 @@ opencv_type_boxed! { Widget }
 impl Drop for Widget {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Widget_delete(instance: *mut c_void); }
-		unsafe { cv_Widget_delete(self.as_raw_mut_Widget()) };
+		let ptr = self.as_raw_mut_Widget();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Widget_delete(ptr) };
 	}
 }
 
@@ -4686,7 +5297,7 @@ impl Widget {
 	#[inline]
 	pub fn default() -> Result<crate::viz::Widget> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Widget_Widget(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Widget_Widget(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Widget::opencv_from_extern(ret) };
@@ -4696,7 +5307,7 @@ impl Widget {
 	#[inline]
 	pub fn copy(other: &crate::viz::Widget) -> Result<crate::viz::Widget> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Widget_Widget_const_WidgetR(other.as_raw_Widget(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Widget_Widget_const_WidgetR(other.as_raw_Widget(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Widget::opencv_from_extern(ret) };
@@ -4711,7 +5322,7 @@ impl Widget {
 	pub fn from_ply_file(file_name: &str) -> Result<crate::viz::Widget> {
 		extern_container_arg!(file_name);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Widget_fromPlyFile_const_StringR(file_name.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Widget_fromPlyFile_const_StringR(file_name.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Widget::opencv_from_extern(ret) };
@@ -4735,8 +5346,9 @@ pub trait Widget2DTrait: crate::viz::Widget2DTraitConst + crate::viz::WidgetTrai
 	/// * color: color of type Color
 	#[inline]
 	fn set_color(&mut self, color: &crate::viz::Color) -> Result<()> {
+		crate::core::validity::check("Widget2D", self.as_raw_mut_Widget2D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Widget2D_setColor_const_ColorR(self.as_raw_mut_Widget2D(), color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Widget2D_setColor_const_ColorR(self.as_raw_mut_Widget2D(), color.as_raw_Color(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4754,7 +5366,10 @@ opencv_type_boxed! { Widget2D }
 impl Drop for Widget2D {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Widget2D_delete(instance: *mut c_void); }
-		unsafe { cv_Widget2D_delete(self.as_raw_mut_Widget2D()) };
+		let ptr = self.as_raw_mut_Widget2D();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Widget2D_delete(ptr) };
 	}
 }
 
@@ -4780,7 +5395,7 @@ impl Widget2D {
 	#[inline]
 	pub fn default() -> Result<crate::viz::Widget2D> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Widget2D_Widget2D(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Widget2D_Widget2D(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Widget2D::opencv_from_extern(ret) };
@@ -4798,8 +5413,9 @@ pub trait Widget3DTraitConst: crate::viz::WidgetTraitConst {
 	/// Returns the current pose of the widget.
 	#[inline]
 	fn get_pose(&self) -> Result<core::Affine3d> {
+		crate::core::validity::check("Widget3D", self.as_raw_Widget3D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Widget3D_getPose_const(self.as_raw_Widget3D(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Widget3D_getPose_const(self.as_raw_Widget3D(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4816,8 +5432,9 @@ pub trait Widget3DTrait: crate::viz::Widget3DTraitConst + crate::viz::WidgetTrai
 	/// * pose: The new pose of the widget.
 	#[inline]
 	fn set_pose(&mut self, pose: core::Affine3d) -> Result<()> {
+		crate::core::validity::check("Widget3D", self.as_raw_mut_Widget3D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Widget3D_setPose_const_Affine3dR(self.as_raw_mut_Widget3D(), &pose, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Widget3D_setPose_const_Affine3dR(self.as_raw_mut_Widget3D(), &pose, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4829,8 +5446,9 @@ pub trait Widget3DTrait: crate::viz::Widget3DTraitConst + crate::viz::WidgetTrai
 	/// * pose: The pose that the current pose of the widget will be pre-multiplied by.
 	#[inline]
 	fn update_pose(&mut self, pose: core::Affine3d) -> Result<()> {
+		crate::core::validity::check("Widget3D", self.as_raw_mut_Widget3D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Widget3D_updatePose_const_Affine3dR(self.as_raw_mut_Widget3D(), &pose, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Widget3D_updatePose_const_Affine3dR(self.as_raw_mut_Widget3D(), &pose, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4842,8 +5460,9 @@ pub trait Widget3DTrait: crate::viz::Widget3DTraitConst + crate::viz::WidgetTrai
 	/// * transform: Specified transformation to apply.
 	#[inline]
 	fn apply_transform(&mut self, transform: core::Affine3d) -> Result<()> {
+		crate::core::validity::check("Widget3D", self.as_raw_mut_Widget3D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Widget3D_applyTransform_const_Affine3dR(self.as_raw_mut_Widget3D(), &transform, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Widget3D_applyTransform_const_Affine3dR(self.as_raw_mut_Widget3D(), &transform, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4855,8 +5474,9 @@ pub trait Widget3DTrait: crate::viz::Widget3DTraitConst + crate::viz::WidgetTrai
 	/// * color: color of type Color
 	#[inline]
 	fn set_color(&mut self, color: &crate::viz::Color) -> Result<()> {
+		crate::core::validity::check("Widget3D", self.as_raw_mut_Widget3D())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Widget3D_setColor_const_ColorR(self.as_raw_mut_Widget3D(), color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Widget3D_setColor_const_ColorR(self.as_raw_mut_Widget3D(), color.as_raw_Color(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4874,7 +5494,10 @@ opencv_type_boxed! { Widget3D }
 impl Drop for Widget3D {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Widget3D_delete(instance: *mut c_void); }
-		unsafe { cv_Widget3D_delete(self.as_raw_mut_Widget3D()) };
+		let ptr = self.as_raw_mut_Widget3D();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Widget3D_delete(ptr) };
 	}
 }
 
@@ -4900,7 +5523,7 @@ impl Widget3D {
 	#[inline]
 	pub fn default() -> Result<crate::viz::Widget3D> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_viz_Widget3D_Widget3D(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_viz_Widget3D_Widget3D(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::viz::Widget3D::opencv_from_extern(ret) };
@@ -4910,3 +5533,4 @@ impl Widget3D {
 }
 
 boxed_cast_base! { Widget3D, crate::viz::Widget, cv_Widget3D_to_Widget }
+pub use crate::manual::viz::*;
@@ -40,10 +40,11 @@ pub trait WeChatQRCodeTrait: crate::wechat_qrcode::WeChatQRCodeTraitConst {
 	/// * points: noArray()
 	#[inline]
 	fn detect_and_decode(&mut self, img: &dyn core::ToInputArray, points: &mut dyn core::ToOutputArray) -> Result<core::Vector<String>> {
+		crate::core::validity::check("WeChatQRCode", self.as_raw_mut_WeChatQRCode())?;
 		input_array_arg!(img);
 		output_array_arg!(points);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_wechat_qrcode_WeChatQRCode_detectAndDecode_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_WeChatQRCode(), img.as_raw__InputArray(), points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_wechat_qrcode_WeChatQRCode_detectAndDecode_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_WeChatQRCode(), img.as_raw__InputArray(), points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<String>::opencv_from_extern(ret) };
@@ -66,7 +67,10 @@ opencv_type_boxed! { WeChatQRCode }
 impl Drop for WeChatQRCode {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WeChatQRCode_delete(instance: *mut c_void); }
-		unsafe { cv_WeChatQRCode_delete(self.as_raw_mut_WeChatQRCode()) };
+		let ptr = self.as_raw_mut_WeChatQRCode();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_WeChatQRCode_delete(ptr) };
 	}
 }
 
@@ -103,7 +107,7 @@ impl WeChatQRCode {
 		extern_container_arg!(super_resolution_prototxt_path);
 		extern_container_arg!(super_resolution_caffe_model_path);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_wechat_qrcode_WeChatQRCode_WeChatQRCode_const_stringR_const_stringR_const_stringR_const_stringR(detector_prototxt_path.opencv_as_extern(), detector_caffe_model_path.opencv_as_extern(), super_resolution_prototxt_path.opencv_as_extern(), super_resolution_caffe_model_path.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_wechat_qrcode_WeChatQRCode_WeChatQRCode_const_stringR_const_stringR_const_stringR_const_stringR(detector_prototxt_path.opencv_as_extern(), detector_caffe_model_path.opencv_as_extern(), super_resolution_prototxt_path.opencv_as_extern(), super_resolution_caffe_model_path.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::wechat_qrcode::WeChatQRCode::opencv_from_extern(ret) };
@@ -111,3 +115,61 @@ impl WeChatQRCode {
 	}
 	
 }
+
+/// Builder for `WeChatQRCode::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct WeChatQRCode_NewBuilder<'a> {
+	detector_prototxt_path: &'a str,
+	detector_caffe_model_path: &'a str,
+	super_resolution_prototxt_path: &'a str,
+	super_resolution_caffe_model_path: &'a str,
+}
+
+impl<'a> WeChatQRCode_NewBuilder<'a> {
+	#[inline]
+	pub fn new() -> Self {
+		Self { detector_prototxt_path: "", detector_caffe_model_path: "", super_resolution_prototxt_path: "", super_resolution_caffe_model_path: "" }
+	}
+	
+	/// C++ default: `""`
+	#[inline]
+	pub fn with_detector_prototxt_path(mut self, detector_prototxt_path: &'a str) -> Self {
+		self.detector_prototxt_path = detector_prototxt_path;
+		self
+	}
+	
+	/// C++ default: `""`
+	#[inline]
+	pub fn with_detector_caffe_model_path(mut self, detector_caffe_model_path: &'a str) -> Self {
+		self.detector_caffe_model_path = detector_caffe_model_path;
+		self
+	}
+	
+	/// C++ default: `""`
+	#[inline]
+	pub fn with_super_resolution_prototxt_path(mut self, super_resolution_prototxt_path: &'a str) -> Self {
+		self.super_resolution_prototxt_path = super_resolution_prototxt_path;
+		self
+	}
+	
+	/// C++ default: `""`
+	#[inline]
+	pub fn with_super_resolution_caffe_model_path(mut self, super_resolution_caffe_model_path: &'a str) -> Self {
+		self.super_resolution_caffe_model_path = super_resolution_caffe_model_path;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::wechat_qrcode::WeChatQRCode> {
+		WeChatQRCode::new(self.detector_prototxt_path, self.detector_caffe_model_path, self.super_resolution_prototxt_path, self.super_resolution_caffe_model_path)
+	}
+}
+
+impl<'a> Default for WeChatQRCode_NewBuilder<'a> {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
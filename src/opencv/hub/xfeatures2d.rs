@@ -94,7 +94,7 @@ opencv_type_enum! { crate::xfeatures2d::PCTSignatures_PointDistribution }
 /// Christian Beecks, Merih Seran Uysal, Thomas Seidl.
 ///       Signature quadratic form distance.
 ///       In Proceedings of the ACM International Conference on Image and Video Retrieval, pages 438-445.
-///       ACM, 2010.
+///       `ACM`, 2010.
 /// [BeecksUS10](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_BeecksUS10)
 /// 
 /// Note: For selected distance function: ![block formula](https://latex.codecogs.com/png.latex?%20d%28c%5Fi%2C%20c%5Fj%29%20)  and parameter: ![block formula](https://latex.codecogs.com/png.latex?%20%5Calpha%20)
@@ -151,7 +151,7 @@ pub type SurfFeatureDetector = dyn crate::xfeatures2d::SURF;
 pub fn fast_for_point_set(image: &dyn core::ToInputArray, keypoints: &mut core::Vector<core::KeyPoint>, threshold: i32, nonmax_suppression: bool, typ: crate::features2d::FastFeatureDetector_DetectorType) -> Result<()> {
 	input_array_arg!(image);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_xfeatures2d_FASTForPointSet_const__InputArrayR_vector_KeyPoint_R_int_bool_DetectorType(image.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfKeyPoint(), threshold, nonmax_suppression, typ, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_xfeatures2d_FASTForPointSet_const__InputArrayR_vector_KeyPoint_R_int_bool_DetectorType(image.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfKeyPoint(), threshold, nonmax_suppression, typ, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -181,12 +181,61 @@ pub fn fast_for_point_set(image: &dyn core::ToInputArray, keypoints: &mut core::
 #[inline]
 pub fn match_gms(size1: core::Size, size2: core::Size, keypoints1: &core::Vector<core::KeyPoint>, keypoints2: &core::Vector<core::KeyPoint>, matches1to2: &core::Vector<core::DMatch>, matches_gms: &mut core::Vector<core::DMatch>, with_rotation: bool, with_scale: bool, threshold_factor: f64) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_xfeatures2d_matchGMS_const_SizeR_const_SizeR_const_vector_KeyPoint_R_const_vector_KeyPoint_R_const_vector_DMatch_R_vector_DMatch_R_const_bool_const_bool_const_double(&size1, &size2, keypoints1.as_raw_VectorOfKeyPoint(), keypoints2.as_raw_VectorOfKeyPoint(), matches1to2.as_raw_VectorOfDMatch(), matches_gms.as_raw_mut_VectorOfDMatch(), with_rotation, with_scale, threshold_factor, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_xfeatures2d_matchGMS_const_SizeR_const_SizeR_const_vector_KeyPoint_R_const_vector_KeyPoint_R_const_vector_DMatch_R_vector_DMatch_R_const_bool_const_bool_const_double(&size1, &size2, keypoints1.as_raw_VectorOfKeyPoint(), keypoints2.as_raw_VectorOfKeyPoint(), matches1to2.as_raw_VectorOfDMatch(), matches_gms.as_raw_mut_VectorOfDMatch(), with_rotation, with_scale, threshold_factor, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `match_gms()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct MatchGmsBuilder<'a> {
+	size1: core::Size,
+	size2: core::Size,
+	keypoints1: &'a core::Vector<core::KeyPoint>,
+	keypoints2: &'a core::Vector<core::KeyPoint>,
+	matches1to2: &'a core::Vector<core::DMatch>,
+	matches_gms: &'a mut core::Vector<core::DMatch>,
+	with_rotation: bool,
+	with_scale: bool,
+	threshold_factor: f64,
+}
+
+impl<'a> MatchGmsBuilder<'a> {
+	#[inline]
+	pub fn new(size1: core::Size, size2: core::Size, keypoints1: &'a core::Vector<core::KeyPoint>, keypoints2: &'a core::Vector<core::KeyPoint>, matches1to2: &'a core::Vector<core::DMatch>, matches_gms: &'a mut core::Vector<core::DMatch>) -> Self {
+		Self { size1, size2, keypoints1, keypoints2, matches1to2, matches_gms, with_rotation: false, with_scale: false, threshold_factor: 6.0 }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_with_rotation(mut self, with_rotation: bool) -> Self {
+		self.with_rotation = with_rotation;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_with_scale(mut self, with_scale: bool) -> Self {
+		self.with_scale = with_scale;
+		self
+	}
+	
+	/// C++ default: `6.0`
+	#[inline]
+	pub fn with_threshold_factor(mut self, threshold_factor: f64) -> Self {
+		self.threshold_factor = threshold_factor;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		match_gms(self.size1, self.size2, self.keypoints1, self.keypoints2, self.matches1to2, self.matches_gms, self.with_rotation, self.with_scale, self.threshold_factor)
+	}
+}
+
 /// LOGOS (Local geometric support for high-outlier spatial verification) feature matching strategy described in [Lowry2018LOGOSLG](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_Lowry2018LOGOSLG) .
 /// ## Parameters
 /// * keypoints1: Input keypoints of image1.
@@ -202,7 +251,7 @@ pub fn match_gms(size1: core::Size, size2: core::Size, keypoints1: &core::Vector
 #[inline]
 pub fn match_logos(keypoints1: &core::Vector<core::KeyPoint>, keypoints2: &core::Vector<core::KeyPoint>, nn1: &core::Vector<i32>, nn2: &core::Vector<i32>, matches1to2: &mut core::Vector<core::DMatch>) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_xfeatures2d_matchLOGOS_const_vector_KeyPoint_R_const_vector_KeyPoint_R_const_vector_int_R_const_vector_int_R_vector_DMatch_R(keypoints1.as_raw_VectorOfKeyPoint(), keypoints2.as_raw_VectorOfKeyPoint(), nn1.as_raw_VectorOfi32(), nn2.as_raw_VectorOfi32(), matches1to2.as_raw_mut_VectorOfDMatch(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_xfeatures2d_matchLOGOS_const_vector_KeyPoint_R_const_vector_KeyPoint_R_const_vector_int_R_const_vector_int_R_vector_DMatch_R(keypoints1.as_raw_VectorOfKeyPoint(), keypoints2.as_raw_VectorOfKeyPoint(), nn1.as_raw_VectorOfi32(), nn2.as_raw_VectorOfi32(), matches1to2.as_raw_mut_VectorOfDMatch(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -220,13 +269,13 @@ pub fn match_logos(keypoints1: &core::Vector<core::KeyPoint>, keypoints2: &core:
 /// format of CPU results is the same as SURF results. GPU results are stored in GpuMat. The keypoints
 /// matrix is ![inline formula](https://latex.codecogs.com/png.latex?%5Ctexttt%7BnFeatures%7D%20%5Ctimes%207) matrix with the CV_32FC1 type.
 /// 
-/// *   keypoints.ptr\<float\>(X_ROW)[i] contains x coordinate of the i-th feature.
-/// *   keypoints.ptr\<float\>(Y_ROW)[i] contains y coordinate of the i-th feature.
-/// *   keypoints.ptr\<float\>(LAPLACIAN_ROW)[i] contains the laplacian sign of the i-th feature.
-/// *   keypoints.ptr\<float\>(OCTAVE_ROW)[i] contains the octave of the i-th feature.
-/// *   keypoints.ptr\<float\>(SIZE_ROW)[i] contains the size of the i-th feature.
-/// *   keypoints.ptr\<float\>(ANGLE_ROW)[i] contain orientation of the i-th feature.
-/// *   keypoints.ptr\<float\>(HESSIAN_ROW)[i] contains the response of the i-th feature.
+/// *   keypoints.ptr\<float\>(X_ROW)\[i\] contains x coordinate of the i-th feature.
+/// *   keypoints.ptr\<float\>(Y_ROW)\[i\] contains y coordinate of the i-th feature.
+/// *   keypoints.ptr\<float\>(LAPLACIAN_ROW)\[i\] contains the laplacian sign of the i-th feature.
+/// *   keypoints.ptr\<float\>(OCTAVE_ROW)\[i\] contains the octave of the i-th feature.
+/// *   keypoints.ptr\<float\>(SIZE_ROW)\[i\] contains the size of the i-th feature.
+/// *   keypoints.ptr\<float\>(ANGLE_ROW)\[i\] contain orientation of the i-th feature.
+/// *   keypoints.ptr\<float\>(HESSIAN_ROW)\[i\] contains the response of the i-th feature.
 /// 
 /// The descriptors matrix is ![inline formula](https://latex.codecogs.com/png.latex?%5Ctexttt%7BnFeatures%7D%20%5Ctimes%20%5Ctexttt%7BdescriptorSize%7D) matrix with the
 /// CV_32FC1 type.
@@ -234,7 +283,7 @@ pub fn match_logos(keypoints1: &core::Vector<core::KeyPoint>, keypoints2: &core:
 /// The class SURF_CUDA uses some buffers and provides access to it. All buffers can be safely released
 /// between function calls.
 /// ## See also
-/// SURF
+/// [SURF](crate::xfeatures2d::SURF)
 /// 
 /// 
 /// Note:
@@ -245,88 +294,197 @@ pub trait SURF_CUDATraitConst {
 
 	#[inline]
 	fn hessian_threshold(&self) -> f64 {
-		let ret = unsafe { sys::cv_cuda_SURF_CUDA_getPropHessianThreshold_const(self.as_raw_SURF_CUDA()) };
+		crate::core::validity::debug_check("SURF_CUDA", self.as_raw_SURF_CUDA());
+		let ret = unsafe { ffi_call!(cv_cuda_SURF_CUDA_getPropHessianThreshold_const(self.as_raw_SURF_CUDA())) };
 		ret
 	}
 	
+	/// Non-panicking version of [hessian_threshold](SURF_CUDATraitConst::hessian_threshold), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_hessian_threshold(&self) -> Result<f64> {
+		crate::core::validity::check("SURF_CUDA", self.as_raw_SURF_CUDA())?;
+		Ok(SURF_CUDATraitConst::hessian_threshold(self))
+	}
+	
 	#[inline]
 	fn n_octaves(&self) -> i32 {
-		let ret = unsafe { sys::cv_cuda_SURF_CUDA_getPropNOctaves_const(self.as_raw_SURF_CUDA()) };
+		crate::core::validity::debug_check("SURF_CUDA", self.as_raw_SURF_CUDA());
+		let ret = unsafe { ffi_call!(cv_cuda_SURF_CUDA_getPropNOctaves_const(self.as_raw_SURF_CUDA())) };
 		ret
 	}
 	
+	/// Non-panicking version of [n_octaves](SURF_CUDATraitConst::n_octaves), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_n_octaves(&self) -> Result<i32> {
+		crate::core::validity::check("SURF_CUDA", self.as_raw_SURF_CUDA())?;
+		Ok(SURF_CUDATraitConst::n_octaves(self))
+	}
+	
 	#[inline]
 	fn n_octave_layers(&self) -> i32 {
-		let ret = unsafe { sys::cv_cuda_SURF_CUDA_getPropNOctaveLayers_const(self.as_raw_SURF_CUDA()) };
+		crate::core::validity::debug_check("SURF_CUDA", self.as_raw_SURF_CUDA());
+		let ret = unsafe { ffi_call!(cv_cuda_SURF_CUDA_getPropNOctaveLayers_const(self.as_raw_SURF_CUDA())) };
 		ret
 	}
 	
+	/// Non-panicking version of [n_octave_layers](SURF_CUDATraitConst::n_octave_layers), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_n_octave_layers(&self) -> Result<i32> {
+		crate::core::validity::check("SURF_CUDA", self.as_raw_SURF_CUDA())?;
+		Ok(SURF_CUDATraitConst::n_octave_layers(self))
+	}
+	
 	#[inline]
 	fn extended(&self) -> bool {
-		let ret = unsafe { sys::cv_cuda_SURF_CUDA_getPropExtended_const(self.as_raw_SURF_CUDA()) };
+		crate::core::validity::debug_check("SURF_CUDA", self.as_raw_SURF_CUDA());
+		let ret = unsafe { ffi_call!(cv_cuda_SURF_CUDA_getPropExtended_const(self.as_raw_SURF_CUDA())) };
 		ret
 	}
 	
+	/// Non-panicking version of [extended](SURF_CUDATraitConst::extended), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_extended(&self) -> Result<bool> {
+		crate::core::validity::check("SURF_CUDA", self.as_raw_SURF_CUDA())?;
+		Ok(SURF_CUDATraitConst::extended(self))
+	}
+	
 	#[inline]
 	fn upright(&self) -> bool {
-		let ret = unsafe { sys::cv_cuda_SURF_CUDA_getPropUpright_const(self.as_raw_SURF_CUDA()) };
+		crate::core::validity::debug_check("SURF_CUDA", self.as_raw_SURF_CUDA());
+		let ret = unsafe { ffi_call!(cv_cuda_SURF_CUDA_getPropUpright_const(self.as_raw_SURF_CUDA())) };
 		ret
 	}
 	
+	/// Non-panicking version of [upright](SURF_CUDATraitConst::upright), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_upright(&self) -> Result<bool> {
+		crate::core::validity::check("SURF_CUDA", self.as_raw_SURF_CUDA())?;
+		Ok(SURF_CUDATraitConst::upright(self))
+	}
+	
 	/// max keypoints = min(keypointsRatio * img.size().area(), 65535)
 	#[inline]
 	fn keypoints_ratio(&self) -> f32 {
-		let ret = unsafe { sys::cv_cuda_SURF_CUDA_getPropKeypointsRatio_const(self.as_raw_SURF_CUDA()) };
+		crate::core::validity::debug_check("SURF_CUDA", self.as_raw_SURF_CUDA());
+		let ret = unsafe { ffi_call!(cv_cuda_SURF_CUDA_getPropKeypointsRatio_const(self.as_raw_SURF_CUDA())) };
 		ret
 	}
 	
+	/// Non-panicking version of [keypoints_ratio](SURF_CUDATraitConst::keypoints_ratio), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_keypoints_ratio(&self) -> Result<f32> {
+		crate::core::validity::check("SURF_CUDA", self.as_raw_SURF_CUDA())?;
+		Ok(SURF_CUDATraitConst::keypoints_ratio(self))
+	}
+	
 	#[inline]
 	fn sum(&self) -> core::GpuMat {
-		let ret = unsafe { sys::cv_cuda_SURF_CUDA_getPropSum_const(self.as_raw_SURF_CUDA()) };
+		crate::core::validity::debug_check("SURF_CUDA", self.as_raw_SURF_CUDA());
+		let ret = unsafe { ffi_call!(cv_cuda_SURF_CUDA_getPropSum_const(self.as_raw_SURF_CUDA())) };
 		let ret = unsafe { core::GpuMat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [sum](SURF_CUDATraitConst::sum), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_sum(&self) -> Result<core::GpuMat> {
+		crate::core::validity::check("SURF_CUDA", self.as_raw_SURF_CUDA())?;
+		Ok(SURF_CUDATraitConst::sum(self))
+	}
+	
 	#[inline]
 	fn mask1(&self) -> core::GpuMat {
-		let ret = unsafe { sys::cv_cuda_SURF_CUDA_getPropMask1_const(self.as_raw_SURF_CUDA()) };
+		crate::core::validity::debug_check("SURF_CUDA", self.as_raw_SURF_CUDA());
+		let ret = unsafe { ffi_call!(cv_cuda_SURF_CUDA_getPropMask1_const(self.as_raw_SURF_CUDA())) };
 		let ret = unsafe { core::GpuMat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [mask1](SURF_CUDATraitConst::mask1), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_mask1(&self) -> Result<core::GpuMat> {
+		crate::core::validity::check("SURF_CUDA", self.as_raw_SURF_CUDA())?;
+		Ok(SURF_CUDATraitConst::mask1(self))
+	}
+	
 	#[inline]
 	fn mask_sum(&self) -> core::GpuMat {
-		let ret = unsafe { sys::cv_cuda_SURF_CUDA_getPropMaskSum_const(self.as_raw_SURF_CUDA()) };
+		crate::core::validity::debug_check("SURF_CUDA", self.as_raw_SURF_CUDA());
+		let ret = unsafe { ffi_call!(cv_cuda_SURF_CUDA_getPropMaskSum_const(self.as_raw_SURF_CUDA())) };
 		let ret = unsafe { core::GpuMat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [mask_sum](SURF_CUDATraitConst::mask_sum), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_mask_sum(&self) -> Result<core::GpuMat> {
+		crate::core::validity::check("SURF_CUDA", self.as_raw_SURF_CUDA())?;
+		Ok(SURF_CUDATraitConst::mask_sum(self))
+	}
+	
 	#[inline]
 	fn det(&self) -> core::GpuMat {
-		let ret = unsafe { sys::cv_cuda_SURF_CUDA_getPropDet_const(self.as_raw_SURF_CUDA()) };
+		crate::core::validity::debug_check("SURF_CUDA", self.as_raw_SURF_CUDA());
+		let ret = unsafe { ffi_call!(cv_cuda_SURF_CUDA_getPropDet_const(self.as_raw_SURF_CUDA())) };
 		let ret = unsafe { core::GpuMat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [det](SURF_CUDATraitConst::det), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_det(&self) -> Result<core::GpuMat> {
+		crate::core::validity::check("SURF_CUDA", self.as_raw_SURF_CUDA())?;
+		Ok(SURF_CUDATraitConst::det(self))
+	}
+	
 	#[inline]
 	fn trace(&self) -> core::GpuMat {
-		let ret = unsafe { sys::cv_cuda_SURF_CUDA_getPropTrace_const(self.as_raw_SURF_CUDA()) };
+		crate::core::validity::debug_check("SURF_CUDA", self.as_raw_SURF_CUDA());
+		let ret = unsafe { ffi_call!(cv_cuda_SURF_CUDA_getPropTrace_const(self.as_raw_SURF_CUDA())) };
 		let ret = unsafe { core::GpuMat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [trace](SURF_CUDATraitConst::trace), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_trace(&self) -> Result<core::GpuMat> {
+		crate::core::validity::check("SURF_CUDA", self.as_raw_SURF_CUDA())?;
+		Ok(SURF_CUDATraitConst::trace(self))
+	}
+	
 	#[inline]
 	fn max_pos_buffer(&self) -> core::GpuMat {
-		let ret = unsafe { sys::cv_cuda_SURF_CUDA_getPropMaxPosBuffer_const(self.as_raw_SURF_CUDA()) };
+		crate::core::validity::debug_check("SURF_CUDA", self.as_raw_SURF_CUDA());
+		let ret = unsafe { ffi_call!(cv_cuda_SURF_CUDA_getPropMaxPosBuffer_const(self.as_raw_SURF_CUDA())) };
 		let ret = unsafe { core::GpuMat::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Non-panicking version of [max_pos_buffer](SURF_CUDATraitConst::max_pos_buffer), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_max_pos_buffer(&self) -> Result<core::GpuMat> {
+		crate::core::validity::check("SURF_CUDA", self.as_raw_SURF_CUDA())?;
+		Ok(SURF_CUDATraitConst::max_pos_buffer(self))
+	}
+	
 	/// returns the descriptor size in float's (64 or 128)
 	#[inline]
 	fn descriptor_size(&self) -> Result<i32> {
+		crate::core::validity::check("SURF_CUDA", self.as_raw_SURF_CUDA())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_SURF_CUDA_descriptorSize_const(self.as_raw_SURF_CUDA(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_SURF_CUDA_descriptorSize_const(self.as_raw_SURF_CUDA(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -335,8 +493,9 @@ pub trait SURF_CUDATraitConst {
 	/// returns the default norm type
 	#[inline]
 	fn default_norm(&self) -> Result<i32> {
+		crate::core::validity::check("SURF_CUDA", self.as_raw_SURF_CUDA())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_SURF_CUDA_defaultNorm_const(self.as_raw_SURF_CUDA(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_SURF_CUDA_defaultNorm_const(self.as_raw_SURF_CUDA(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -349,82 +508,95 @@ pub trait SURF_CUDATrait: crate::xfeatures2d::SURF_CUDATraitConst {
 
 	#[inline]
 	fn set_hessian_threshold(&mut self, val: f64) {
-		let ret = unsafe { sys::cv_cuda_SURF_CUDA_setPropHessianThreshold_double(self.as_raw_mut_SURF_CUDA(), val) };
+		crate::core::validity::debug_check("SURF_CUDA", self.as_raw_mut_SURF_CUDA());
+		let ret = unsafe { ffi_call!(cv_cuda_SURF_CUDA_setPropHessianThreshold_double(self.as_raw_mut_SURF_CUDA(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_n_octaves(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_cuda_SURF_CUDA_setPropNOctaves_int(self.as_raw_mut_SURF_CUDA(), val) };
+		crate::core::validity::debug_check("SURF_CUDA", self.as_raw_mut_SURF_CUDA());
+		let ret = unsafe { ffi_call!(cv_cuda_SURF_CUDA_setPropNOctaves_int(self.as_raw_mut_SURF_CUDA(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_n_octave_layers(&mut self, val: i32) {
-		let ret = unsafe { sys::cv_cuda_SURF_CUDA_setPropNOctaveLayers_int(self.as_raw_mut_SURF_CUDA(), val) };
+		crate::core::validity::debug_check("SURF_CUDA", self.as_raw_mut_SURF_CUDA());
+		let ret = unsafe { ffi_call!(cv_cuda_SURF_CUDA_setPropNOctaveLayers_int(self.as_raw_mut_SURF_CUDA(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_extended(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_cuda_SURF_CUDA_setPropExtended_bool(self.as_raw_mut_SURF_CUDA(), val) };
+		crate::core::validity::debug_check("SURF_CUDA", self.as_raw_mut_SURF_CUDA());
+		let ret = unsafe { ffi_call!(cv_cuda_SURF_CUDA_setPropExtended_bool(self.as_raw_mut_SURF_CUDA(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_upright(&mut self, val: bool) {
-		let ret = unsafe { sys::cv_cuda_SURF_CUDA_setPropUpright_bool(self.as_raw_mut_SURF_CUDA(), val) };
+		crate::core::validity::debug_check("SURF_CUDA", self.as_raw_mut_SURF_CUDA());
+		let ret = unsafe { ffi_call!(cv_cuda_SURF_CUDA_setPropUpright_bool(self.as_raw_mut_SURF_CUDA(), val)) };
 		ret
 	}
 	
 	/// max keypoints = min(keypointsRatio * img.size().area(), 65535)
 	#[inline]
 	fn set_keypoints_ratio(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_cuda_SURF_CUDA_setPropKeypointsRatio_float(self.as_raw_mut_SURF_CUDA(), val) };
+		crate::core::validity::debug_check("SURF_CUDA", self.as_raw_mut_SURF_CUDA());
+		let ret = unsafe { ffi_call!(cv_cuda_SURF_CUDA_setPropKeypointsRatio_float(self.as_raw_mut_SURF_CUDA(), val)) };
 		ret
 	}
 	
 	#[inline]
 	fn set_sum(&mut self, mut val: core::GpuMat) {
-		let ret = unsafe { sys::cv_cuda_SURF_CUDA_setPropSum_GpuMat(self.as_raw_mut_SURF_CUDA(), val.as_raw_mut_GpuMat()) };
+		crate::core::validity::debug_check("SURF_CUDA", self.as_raw_mut_SURF_CUDA());
+		let ret = unsafe { ffi_call!(cv_cuda_SURF_CUDA_setPropSum_GpuMat(self.as_raw_mut_SURF_CUDA(), val.as_raw_mut_GpuMat())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_mask1(&mut self, mut val: core::GpuMat) {
-		let ret = unsafe { sys::cv_cuda_SURF_CUDA_setPropMask1_GpuMat(self.as_raw_mut_SURF_CUDA(), val.as_raw_mut_GpuMat()) };
+		crate::core::validity::debug_check("SURF_CUDA", self.as_raw_mut_SURF_CUDA());
+		let ret = unsafe { ffi_call!(cv_cuda_SURF_CUDA_setPropMask1_GpuMat(self.as_raw_mut_SURF_CUDA(), val.as_raw_mut_GpuMat())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_mask_sum(&mut self, mut val: core::GpuMat) {
-		let ret = unsafe { sys::cv_cuda_SURF_CUDA_setPropMaskSum_GpuMat(self.as_raw_mut_SURF_CUDA(), val.as_raw_mut_GpuMat()) };
+		crate::core::validity::debug_check("SURF_CUDA", self.as_raw_mut_SURF_CUDA());
+		let ret = unsafe { ffi_call!(cv_cuda_SURF_CUDA_setPropMaskSum_GpuMat(self.as_raw_mut_SURF_CUDA(), val.as_raw_mut_GpuMat())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_det(&mut self, mut val: core::GpuMat) {
-		let ret = unsafe { sys::cv_cuda_SURF_CUDA_setPropDet_GpuMat(self.as_raw_mut_SURF_CUDA(), val.as_raw_mut_GpuMat()) };
+		crate::core::validity::debug_check("SURF_CUDA", self.as_raw_mut_SURF_CUDA());
+		let ret = unsafe { ffi_call!(cv_cuda_SURF_CUDA_setPropDet_GpuMat(self.as_raw_mut_SURF_CUDA(), val.as_raw_mut_GpuMat())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_trace(&mut self, mut val: core::GpuMat) {
-		let ret = unsafe { sys::cv_cuda_SURF_CUDA_setPropTrace_GpuMat(self.as_raw_mut_SURF_CUDA(), val.as_raw_mut_GpuMat()) };
+		crate::core::validity::debug_check("SURF_CUDA", self.as_raw_mut_SURF_CUDA());
+		let ret = unsafe { ffi_call!(cv_cuda_SURF_CUDA_setPropTrace_GpuMat(self.as_raw_mut_SURF_CUDA(), val.as_raw_mut_GpuMat())) };
 		ret
 	}
 	
 	#[inline]
 	fn set_max_pos_buffer(&mut self, mut val: core::GpuMat) {
-		let ret = unsafe { sys::cv_cuda_SURF_CUDA_setPropMaxPosBuffer_GpuMat(self.as_raw_mut_SURF_CUDA(), val.as_raw_mut_GpuMat()) };
+		crate::core::validity::debug_check("SURF_CUDA", self.as_raw_mut_SURF_CUDA());
+		let ret = unsafe { ffi_call!(cv_cuda_SURF_CUDA_setPropMaxPosBuffer_GpuMat(self.as_raw_mut_SURF_CUDA(), val.as_raw_mut_GpuMat())) };
 		ret
 	}
 	
 	/// upload host keypoints to device memory
 	#[inline]
 	fn upload_keypoints(&mut self, keypoints: &core::Vector<core::KeyPoint>, keypoints_gpu: &mut core::GpuMat) -> Result<()> {
+		crate::core::validity::check("SURF_CUDA", self.as_raw_mut_SURF_CUDA())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_SURF_CUDA_uploadKeypoints_const_vector_KeyPoint_R_GpuMatR(self.as_raw_mut_SURF_CUDA(), keypoints.as_raw_VectorOfKeyPoint(), keypoints_gpu.as_raw_mut_GpuMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_SURF_CUDA_uploadKeypoints_const_vector_KeyPoint_R_GpuMatR(self.as_raw_mut_SURF_CUDA(), keypoints.as_raw_VectorOfKeyPoint(), keypoints_gpu.as_raw_mut_GpuMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -433,8 +605,9 @@ pub trait SURF_CUDATrait: crate::xfeatures2d::SURF_CUDATraitConst {
 	/// download keypoints from device to host memory
 	#[inline]
 	fn download_keypoints(&mut self, keypoints_gpu: &core::GpuMat, keypoints: &mut core::Vector<core::KeyPoint>) -> Result<()> {
+		crate::core::validity::check("SURF_CUDA", self.as_raw_mut_SURF_CUDA())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_SURF_CUDA_downloadKeypoints_const_GpuMatR_vector_KeyPoint_R(self.as_raw_mut_SURF_CUDA(), keypoints_gpu.as_raw_GpuMat(), keypoints.as_raw_mut_VectorOfKeyPoint(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_SURF_CUDA_downloadKeypoints_const_GpuMatR_vector_KeyPoint_R(self.as_raw_mut_SURF_CUDA(), keypoints_gpu.as_raw_GpuMat(), keypoints.as_raw_mut_VectorOfKeyPoint(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -443,8 +616,9 @@ pub trait SURF_CUDATrait: crate::xfeatures2d::SURF_CUDATraitConst {
 	/// download descriptors from device to host memory
 	#[inline]
 	fn download_descriptors(&mut self, descriptors_gpu: &core::GpuMat, descriptors: &mut core::Vector<f32>) -> Result<()> {
+		crate::core::validity::check("SURF_CUDA", self.as_raw_mut_SURF_CUDA())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_SURF_CUDA_downloadDescriptors_const_GpuMatR_vector_float_R(self.as_raw_mut_SURF_CUDA(), descriptors_gpu.as_raw_GpuMat(), descriptors.as_raw_mut_VectorOff32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_SURF_CUDA_downloadDescriptors_const_GpuMatR_vector_float_R(self.as_raw_mut_SURF_CUDA(), descriptors_gpu.as_raw_GpuMat(), descriptors.as_raw_mut_VectorOff32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -458,8 +632,9 @@ pub trait SURF_CUDATrait: crate::xfeatures2d::SURF_CUDATraitConst {
 	/// * keypoints: Detected keypoints.
 	#[inline]
 	fn detect(&mut self, img: &core::GpuMat, mask: &core::GpuMat, keypoints: &mut core::GpuMat) -> Result<()> {
+		crate::core::validity::check("SURF_CUDA", self.as_raw_mut_SURF_CUDA())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_SURF_CUDA_detect_const_GpuMatR_const_GpuMatR_GpuMatR(self.as_raw_mut_SURF_CUDA(), img.as_raw_GpuMat(), mask.as_raw_GpuMat(), keypoints.as_raw_mut_GpuMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_SURF_CUDA_detect_const_GpuMatR_const_GpuMatR_GpuMatR(self.as_raw_mut_SURF_CUDA(), img.as_raw_GpuMat(), mask.as_raw_GpuMat(), keypoints.as_raw_mut_GpuMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -478,8 +653,9 @@ pub trait SURF_CUDATrait: crate::xfeatures2d::SURF_CUDATraitConst {
 	/// * use_provided_keypoints: false
 	#[inline]
 	fn detect_with_descriptors(&mut self, img: &core::GpuMat, mask: &core::GpuMat, keypoints: &mut core::GpuMat, descriptors: &mut core::GpuMat, use_provided_keypoints: bool) -> Result<()> {
+		crate::core::validity::check("SURF_CUDA", self.as_raw_mut_SURF_CUDA())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_SURF_CUDA_detectWithDescriptors_const_GpuMatR_const_GpuMatR_GpuMatR_GpuMatR_bool(self.as_raw_mut_SURF_CUDA(), img.as_raw_GpuMat(), mask.as_raw_GpuMat(), keypoints.as_raw_mut_GpuMat(), descriptors.as_raw_mut_GpuMat(), use_provided_keypoints, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_SURF_CUDA_detectWithDescriptors_const_GpuMatR_const_GpuMatR_GpuMatR_GpuMatR_bool(self.as_raw_mut_SURF_CUDA(), img.as_raw_GpuMat(), mask.as_raw_GpuMat(), keypoints.as_raw_mut_GpuMat(), descriptors.as_raw_mut_GpuMat(), use_provided_keypoints, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -487,8 +663,9 @@ pub trait SURF_CUDATrait: crate::xfeatures2d::SURF_CUDATraitConst {
 	
 	#[inline]
 	fn release_memory(&mut self) -> Result<()> {
+		crate::core::validity::check("SURF_CUDA", self.as_raw_mut_SURF_CUDA())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_SURF_CUDA_releaseMemory(self.as_raw_mut_SURF_CUDA(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_SURF_CUDA_releaseMemory(self.as_raw_mut_SURF_CUDA(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -508,13 +685,13 @@ pub trait SURF_CUDATrait: crate::xfeatures2d::SURF_CUDATraitConst {
 /// format of CPU results is the same as SURF results. GPU results are stored in GpuMat. The keypoints
 /// matrix is ![inline formula](https://latex.codecogs.com/png.latex?%5Ctexttt%7BnFeatures%7D%20%5Ctimes%207) matrix with the CV_32FC1 type.
 /// 
-/// *   keypoints.ptr\<float\>(X_ROW)[i] contains x coordinate of the i-th feature.
-/// *   keypoints.ptr\<float\>(Y_ROW)[i] contains y coordinate of the i-th feature.
-/// *   keypoints.ptr\<float\>(LAPLACIAN_ROW)[i] contains the laplacian sign of the i-th feature.
-/// *   keypoints.ptr\<float\>(OCTAVE_ROW)[i] contains the octave of the i-th feature.
-/// *   keypoints.ptr\<float\>(SIZE_ROW)[i] contains the size of the i-th feature.
-/// *   keypoints.ptr\<float\>(ANGLE_ROW)[i] contain orientation of the i-th feature.
-/// *   keypoints.ptr\<float\>(HESSIAN_ROW)[i] contains the response of the i-th feature.
+/// *   keypoints.ptr\<float\>(X_ROW)\[i\] contains x coordinate of the i-th feature.
+/// *   keypoints.ptr\<float\>(Y_ROW)\[i\] contains y coordinate of the i-th feature.
+/// *   keypoints.ptr\<float\>(LAPLACIAN_ROW)\[i\] contains the laplacian sign of the i-th feature.
+/// *   keypoints.ptr\<float\>(OCTAVE_ROW)\[i\] contains the octave of the i-th feature.
+/// *   keypoints.ptr\<float\>(SIZE_ROW)\[i\] contains the size of the i-th feature.
+/// *   keypoints.ptr\<float\>(ANGLE_ROW)\[i\] contain orientation of the i-th feature.
+/// *   keypoints.ptr\<float\>(HESSIAN_ROW)\[i\] contains the response of the i-th feature.
 /// 
 /// The descriptors matrix is ![inline formula](https://latex.codecogs.com/png.latex?%5Ctexttt%7BnFeatures%7D%20%5Ctimes%20%5Ctexttt%7BdescriptorSize%7D) matrix with the
 /// CV_32FC1 type.
@@ -522,7 +699,7 @@ pub trait SURF_CUDATrait: crate::xfeatures2d::SURF_CUDATraitConst {
 /// The class SURF_CUDA uses some buffers and provides access to it. All buffers can be safely released
 /// between function calls.
 /// ## See also
-/// SURF
+/// [SURF](crate::xfeatures2d::SURF)
 /// 
 /// 
 /// Note:
@@ -537,7 +714,10 @@ opencv_type_boxed! { SURF_CUDA }
 impl Drop for SURF_CUDA {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SURF_CUDA_delete(instance: *mut c_void); }
-		unsafe { cv_SURF_CUDA_delete(self.as_raw_mut_SURF_CUDA()) };
+		let ptr = self.as_raw_mut_SURF_CUDA();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_SURF_CUDA_delete(ptr) };
 	}
 }
 
@@ -556,7 +736,7 @@ impl SURF_CUDA {
 	#[inline]
 	pub fn default() -> Result<crate::xfeatures2d::SURF_CUDA> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_SURF_CUDA_SURF_CUDA(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_SURF_CUDA_SURF_CUDA(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::xfeatures2d::SURF_CUDA::opencv_from_extern(ret) };
@@ -574,7 +754,7 @@ impl SURF_CUDA {
 	#[inline]
 	pub fn new(_hessian_threshold: f64, _n_octaves: i32, _n_octave_layers: i32, _extended: bool, _keypoints_ratio: f32, _upright: bool) -> Result<crate::xfeatures2d::SURF_CUDA> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_SURF_CUDA_SURF_CUDA_double_int_int_bool_float_bool(_hessian_threshold, _n_octaves, _n_octave_layers, _extended, _keypoints_ratio, _upright, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_SURF_CUDA_SURF_CUDA_double_int_int_bool_float_bool(_hessian_threshold, _n_octaves, _n_octave_layers, _extended, _keypoints_ratio, _upright, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::xfeatures2d::SURF_CUDA::opencv_from_extern(ret) };
@@ -600,7 +780,7 @@ impl SURF_CUDA {
 	#[inline]
 	pub fn create(_hessian_threshold: f64, _n_octaves: i32, _n_octave_layers: i32, _extended: bool, _keypoints_ratio: f32, _upright: bool) -> Result<core::Ptr<crate::xfeatures2d::SURF_CUDA>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_cuda_SURF_CUDA_create_double_int_int_bool_float_bool(_hessian_threshold, _n_octaves, _n_octave_layers, _extended, _keypoints_ratio, _upright, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_cuda_SURF_CUDA_create_double_int_int_bool_float_bool(_hessian_threshold, _n_octaves, _n_octave_layers, _extended, _keypoints_ratio, _upright, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::xfeatures2d::SURF_CUDA>::opencv_from_extern(ret) };
@@ -609,14 +789,134 @@ impl SURF_CUDA {
 	
 }
 
+/// Builder for `SURF_CUDA::new()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct SURF_CUDA_NewBuilder {
+	_hessian_threshold: f64,
+	_n_octaves: i32,
+	_n_octave_layers: i32,
+	_extended: bool,
+	_keypoints_ratio: f32,
+	_upright: bool,
+}
+
+impl SURF_CUDA_NewBuilder {
+	#[inline]
+	pub fn new(_hessian_threshold: f64) -> Self {
+		Self { _hessian_threshold, _n_octaves: 4, _n_octave_layers: 2, _extended: false, _keypoints_ratio: 0.01, _upright: false }
+	}
+	
+	/// C++ default: `4`
+	#[inline]
+	pub fn with__n_octaves(mut self, _n_octaves: i32) -> Self {
+		self._n_octaves = _n_octaves;
+		self
+	}
+	
+	/// C++ default: `2`
+	#[inline]
+	pub fn with__n_octave_layers(mut self, _n_octave_layers: i32) -> Self {
+		self._n_octave_layers = _n_octave_layers;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with__extended(mut self, _extended: bool) -> Self {
+		self._extended = _extended;
+		self
+	}
+	
+	/// C++ default: `0.01f`
+	#[inline]
+	pub fn with__keypoints_ratio(mut self, _keypoints_ratio: f32) -> Self {
+		self._keypoints_ratio = _keypoints_ratio;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with__upright(mut self, _upright: bool) -> Self {
+		self._upright = _upright;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<crate::xfeatures2d::SURF_CUDA> {
+		SURF_CUDA::new(self._hessian_threshold, self._n_octaves, self._n_octave_layers, self._extended, self._keypoints_ratio, self._upright)
+	}
+}
+
+/// Builder for `SURF_CUDA::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct SURF_CUDA_CreateBuilder {
+	_hessian_threshold: f64,
+	_n_octaves: i32,
+	_n_octave_layers: i32,
+	_extended: bool,
+	_keypoints_ratio: f32,
+	_upright: bool,
+}
+
+impl SURF_CUDA_CreateBuilder {
+	#[inline]
+	pub fn new(_hessian_threshold: f64) -> Self {
+		Self { _hessian_threshold, _n_octaves: 4, _n_octave_layers: 2, _extended: false, _keypoints_ratio: 0.01, _upright: false }
+	}
+	
+	/// C++ default: `4`
+	#[inline]
+	pub fn with__n_octaves(mut self, _n_octaves: i32) -> Self {
+		self._n_octaves = _n_octaves;
+		self
+	}
+	
+	/// C++ default: `2`
+	#[inline]
+	pub fn with__n_octave_layers(mut self, _n_octave_layers: i32) -> Self {
+		self._n_octave_layers = _n_octave_layers;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with__extended(mut self, _extended: bool) -> Self {
+		self._extended = _extended;
+		self
+	}
+	
+	/// C++ default: `0.01f`
+	#[inline]
+	pub fn with__keypoints_ratio(mut self, _keypoints_ratio: f32) -> Self {
+		self._keypoints_ratio = _keypoints_ratio;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with__upright(mut self, _upright: bool) -> Self {
+		self._upright = _upright;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<crate::xfeatures2d::SURF_CUDA>> {
+		SURF_CUDA::create(self._hessian_threshold, self._n_octaves, self._n_octave_layers, self._extended, self._keypoints_ratio, self._upright)
+	}
+}
+
 /// Class implementing affine adaptation for key points.
 /// 
-/// A @ref FeatureDetector and a @ref DescriptorExtractor are wrapped to augment the
+/// A `FeatureDetector` and a `DescriptorExtractor` are wrapped to augment the
 /// detected points with their affine invariant elliptic region and to compute
 /// the feature descriptors on the regions after warping them into circles.
 /// 
-/// The interface is equivalent to @ref Feature2D, adding operations for
-/// @ref Elliptic_KeyPoint "Elliptic_KeyPoints" instead of @ref KeyPoint "KeyPoints".
+/// The interface is equivalent to `Feature2D`, adding operations for
+/// Elliptic_KeyPoints instead of KeyPoints.
 pub trait AffineFeature2DConst: crate::features2d::Feature2DTraitConst {
 	fn as_raw_AffineFeature2D(&self) -> *const c_void;
 
@@ -632,10 +932,11 @@ pub trait AffineFeature2D: crate::features2d::Feature2DTrait + crate::xfeatures2
 	/// * mask: noArray()
 	#[inline]
 	fn detect(&mut self, image: &dyn core::ToInputArray, keypoints: &mut core::Vector<crate::xfeatures2d::Elliptic_KeyPoint>, mask: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("AffineFeature2D", self.as_raw_mut_AffineFeature2D())?;
 		input_array_arg!(image);
 		input_array_arg!(mask);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_AffineFeature2D_detect_const__InputArrayR_vector_Elliptic_KeyPoint_R_const__InputArrayR(self.as_raw_mut_AffineFeature2D(), image.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfElliptic_KeyPoint(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_AffineFeature2D_detect_const__InputArrayR_vector_Elliptic_KeyPoint_R_const__InputArrayR(self.as_raw_mut_AffineFeature2D(), image.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfElliptic_KeyPoint(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -648,11 +949,12 @@ pub trait AffineFeature2D: crate::features2d::Feature2DTrait + crate::xfeatures2
 	/// * use_provided_keypoints: false
 	#[inline]
 	fn detect_and_compute(&mut self, image: &dyn core::ToInputArray, mask: &dyn core::ToInputArray, keypoints: &mut core::Vector<crate::xfeatures2d::Elliptic_KeyPoint>, descriptors: &mut dyn core::ToOutputArray, use_provided_keypoints: bool) -> Result<()> {
+		crate::core::validity::check("AffineFeature2D", self.as_raw_mut_AffineFeature2D())?;
 		input_array_arg!(image);
 		input_array_arg!(mask);
 		output_array_arg!(descriptors);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_AffineFeature2D_detectAndCompute_const__InputArrayR_const__InputArrayR_vector_Elliptic_KeyPoint_R_const__OutputArrayR_bool(self.as_raw_mut_AffineFeature2D(), image.as_raw__InputArray(), mask.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfElliptic_KeyPoint(), descriptors.as_raw__OutputArray(), use_provided_keypoints, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_AffineFeature2D_detectAndCompute_const__InputArrayR_const__InputArrayR_vector_Elliptic_KeyPoint_R_const__OutputArrayR_bool(self.as_raw_mut_AffineFeature2D(), image.as_raw__InputArray(), mask.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfElliptic_KeyPoint(), descriptors.as_raw__OutputArray(), use_provided_keypoints, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -666,7 +968,7 @@ impl dyn AffineFeature2D + '_ {
 	#[inline]
 	pub fn create(mut keypoint_detector: core::Ptr<crate::features2d::Feature2D>, mut descriptor_extractor: core::Ptr<crate::features2d::Feature2D>) -> Result<core::Ptr<dyn crate::xfeatures2d::AffineFeature2D>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_AffineFeature2D_create_Ptr_Feature2D__Ptr_Feature2D_(keypoint_detector.as_raw_mut_PtrOfFeature2D(), descriptor_extractor.as_raw_mut_PtrOfFeature2D(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_AffineFeature2D_create_Ptr_Feature2D__Ptr_Feature2D_(keypoint_detector.as_raw_mut_PtrOfFeature2D(), descriptor_extractor.as_raw_mut_PtrOfFeature2D(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::xfeatures2d::AffineFeature2D>::opencv_from_extern(ret) };
@@ -678,7 +980,7 @@ impl dyn AffineFeature2D + '_ {
 	#[inline]
 	pub fn create_1(mut keypoint_detector: core::Ptr<crate::features2d::Feature2D>) -> Result<core::Ptr<dyn crate::xfeatures2d::AffineFeature2D>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_AffineFeature2D_create_Ptr_Feature2D_(keypoint_detector.as_raw_mut_PtrOfFeature2D(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_AffineFeature2D_create_Ptr_Feature2D_(keypoint_detector.as_raw_mut_PtrOfFeature2D(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::xfeatures2d::AffineFeature2D>::opencv_from_extern(ret) };
@@ -747,7 +1049,10 @@ opencv_type_boxed! { BEBLID }
 impl Drop for BEBLID {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BEBLID_delete(instance: *mut c_void); }
-		unsafe { cv_BEBLID_delete(self.as_raw_mut_BEBLID()) };
+		let ptr = self.as_raw_mut_BEBLID();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_BEBLID_delete(ptr) };
 	}
 }
 
@@ -793,7 +1098,7 @@ impl BEBLID {
 	#[inline]
 	pub fn create(scale_factor: f32, n_bits: i32) -> Result<core::Ptr<crate::xfeatures2d::BEBLID>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_BEBLID_create_float_int(scale_factor, n_bits, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_BEBLID_create_float_int(scale_factor, n_bits, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::xfeatures2d::BEBLID>::opencv_from_extern(ret) };
@@ -837,8 +1142,9 @@ pub trait BoostDescConst: crate::features2d::Feature2DTraitConst {
 
 	#[inline]
 	fn get_use_scale_orientation(&self) -> Result<bool> {
+		crate::core::validity::check("BoostDesc", self.as_raw_BoostDesc())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_BoostDesc_getUseScaleOrientation_const(self.as_raw_BoostDesc(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_BoostDesc_getUseScaleOrientation_const(self.as_raw_BoostDesc(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -846,8 +1152,9 @@ pub trait BoostDescConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_scale_factor(&self) -> Result<f32> {
+		crate::core::validity::check("BoostDesc", self.as_raw_BoostDesc())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_BoostDesc_getScaleFactor_const(self.as_raw_BoostDesc(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_BoostDesc_getScaleFactor_const(self.as_raw_BoostDesc(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -860,8 +1167,9 @@ pub trait BoostDesc: crate::features2d::Feature2DTrait + crate::xfeatures2d::Boo
 
 	#[inline]
 	fn set_use_scale_orientation(&mut self, use_scale_orientation: bool) -> Result<()> {
+		crate::core::validity::check("BoostDesc", self.as_raw_mut_BoostDesc())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_BoostDesc_setUseScaleOrientation_const_bool(self.as_raw_mut_BoostDesc(), use_scale_orientation, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_BoostDesc_setUseScaleOrientation_const_bool(self.as_raw_mut_BoostDesc(), use_scale_orientation, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -869,8 +1177,9 @@ pub trait BoostDesc: crate::features2d::Feature2DTrait + crate::xfeatures2d::Boo
 	
 	#[inline]
 	fn set_scale_factor(&mut self, scale_factor: f32) -> Result<()> {
+		crate::core::validity::check("BoostDesc", self.as_raw_mut_BoostDesc())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_BoostDesc_setScaleFactor_const_float(self.as_raw_mut_BoostDesc(), scale_factor, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_BoostDesc_setScaleFactor_const_float(self.as_raw_mut_BoostDesc(), scale_factor, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -886,7 +1195,7 @@ impl dyn BoostDesc + '_ {
 	#[inline]
 	pub fn create(desc: i32, use_scale_orientation: bool, scale_factor: f32) -> Result<core::Ptr<dyn crate::xfeatures2d::BoostDesc>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_BoostDesc_create_int_bool_float(desc, use_scale_orientation, scale_factor, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_BoostDesc_create_int_bool_float(desc, use_scale_orientation, scale_factor, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::xfeatures2d::BoostDesc>::opencv_from_extern(ret) };
@@ -894,6 +1203,42 @@ impl dyn BoostDesc + '_ {
 	}
 	
 }
+
+/// Builder for `<dyn BoostDesc>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct BoostDesc_CreateBuilder {
+	desc: i32,
+	use_scale_orientation: bool,
+	scale_factor: f32,
+}
+
+impl BoostDesc_CreateBuilder {
+	#[inline]
+	pub fn new(desc: i32) -> Self {
+		Self { desc, use_scale_orientation: true, scale_factor: 6.25 }
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_use_scale_orientation(mut self, use_scale_orientation: bool) -> Self {
+		self.use_scale_orientation = use_scale_orientation;
+		self
+	}
+	
+	/// C++ default: `6.25f`
+	#[inline]
+	pub fn with_scale_factor(mut self, scale_factor: f32) -> Self {
+		self.scale_factor = scale_factor;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::xfeatures2d::BoostDesc>> {
+		<dyn BoostDesc>::create(self.desc, self.use_scale_orientation, self.scale_factor)
+	}
+}
 /// Class for computing BRIEF descriptors described in [calon2010](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_calon2010) .
 /// 
 /// ## Parameters
@@ -923,7 +1268,10 @@ opencv_type_boxed! { BriefDescriptorExtractor }
 impl Drop for BriefDescriptorExtractor {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BriefDescriptorExtractor_delete(instance: *mut c_void); }
-		unsafe { cv_BriefDescriptorExtractor_delete(self.as_raw_mut_BriefDescriptorExtractor()) };
+		let ptr = self.as_raw_mut_BriefDescriptorExtractor();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_BriefDescriptorExtractor_delete(ptr) };
 	}
 }
 
@@ -960,7 +1308,7 @@ impl BriefDescriptorExtractor {
 	#[inline]
 	pub fn create(bytes: i32, use_orientation: bool) -> Result<core::Ptr<crate::xfeatures2d::BriefDescriptorExtractor>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_BriefDescriptorExtractor_create_int_bool(bytes, use_orientation, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_BriefDescriptorExtractor_create_int_bool(bytes, use_orientation, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::xfeatures2d::BriefDescriptorExtractor>::opencv_from_extern(ret) };
@@ -998,8 +1346,9 @@ pub trait DAISYConst: crate::features2d::Feature2DTraitConst {
 	/// * descriptor: supplied array for descriptor storage
 	#[inline]
 	fn get_descriptor(&self, y: f64, x: f64, orientation: i32, descriptor: &mut f32) -> Result<()> {
+		crate::core::validity::check("DAISY", self.as_raw_DAISY())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_DAISY_GetDescriptor_const_double_double_int_floatX(self.as_raw_DAISY(), y, x, orientation, descriptor, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_DAISY_GetDescriptor_const_double_double_int_floatX(self.as_raw_DAISY(), y, x, orientation, descriptor, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1013,8 +1362,9 @@ pub trait DAISYConst: crate::features2d::Feature2DTraitConst {
 	/// * H: homography matrix for warped grid
 	#[inline]
 	fn get_descriptor_1(&self, y: f64, x: f64, orientation: i32, descriptor: &mut f32, h: &mut f64) -> Result<bool> {
+		crate::core::validity::check("DAISY", self.as_raw_DAISY())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_DAISY_GetDescriptor_const_double_double_int_floatX_doubleX(self.as_raw_DAISY(), y, x, orientation, descriptor, h, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_DAISY_GetDescriptor_const_double_double_int_floatX_doubleX(self.as_raw_DAISY(), y, x, orientation, descriptor, h, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1027,8 +1377,9 @@ pub trait DAISYConst: crate::features2d::Feature2DTraitConst {
 	/// * descriptor: supplied array for descriptor storage
 	#[inline]
 	fn get_unnormalized_descriptor(&self, y: f64, x: f64, orientation: i32, descriptor: &mut f32) -> Result<()> {
+		crate::core::validity::check("DAISY", self.as_raw_DAISY())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_DAISY_GetUnnormalizedDescriptor_const_double_double_int_floatX(self.as_raw_DAISY(), y, x, orientation, descriptor, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_DAISY_GetUnnormalizedDescriptor_const_double_double_int_floatX(self.as_raw_DAISY(), y, x, orientation, descriptor, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1042,8 +1393,9 @@ pub trait DAISYConst: crate::features2d::Feature2DTraitConst {
 	/// * H: homography matrix for warped grid
 	#[inline]
 	fn get_unnormalized_descriptor_1(&self, y: f64, x: f64, orientation: i32, descriptor: &mut f32, h: &mut f64) -> Result<bool> {
+		crate::core::validity::check("DAISY", self.as_raw_DAISY())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_DAISY_GetUnnormalizedDescriptor_const_double_double_int_floatX_doubleX(self.as_raw_DAISY(), y, x, orientation, descriptor, h, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_DAISY_GetUnnormalizedDescriptor_const_double_double_int_floatX_doubleX(self.as_raw_DAISY(), y, x, orientation, descriptor, h, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1060,10 +1412,11 @@ pub trait DAISY: crate::features2d::Feature2DTrait + crate::xfeatures2d::DAISYCo
 	/// * descriptors: resulted descriptors array
 	#[inline]
 	fn compute(&mut self, image: &dyn core::ToInputArray, keypoints: &mut core::Vector<core::KeyPoint>, descriptors: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("DAISY", self.as_raw_mut_DAISY())?;
 		input_array_arg!(image);
 		output_array_arg!(descriptors);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_DAISY_compute_const__InputArrayR_vector_KeyPoint_R_const__OutputArrayR(self.as_raw_mut_DAISY(), image.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfKeyPoint(), descriptors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_DAISY_compute_const__InputArrayR_vector_KeyPoint_R_const__OutputArrayR(self.as_raw_mut_DAISY(), image.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfKeyPoint(), descriptors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1071,10 +1424,11 @@ pub trait DAISY: crate::features2d::Feature2DTrait + crate::xfeatures2d::DAISYCo
 	
 	#[inline]
 	fn compute_1(&mut self, images: &dyn core::ToInputArray, keypoints: &mut core::Vector<core::Vector<core::KeyPoint>>, descriptors: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("DAISY", self.as_raw_mut_DAISY())?;
 		input_array_arg!(images);
 		output_array_arg!(descriptors);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_DAISY_compute_const__InputArrayR_vector_vector_KeyPoint__R_const__OutputArrayR(self.as_raw_mut_DAISY(), images.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfVectorOfKeyPoint(), descriptors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_DAISY_compute_const__InputArrayR_vector_vector_KeyPoint__R_const__OutputArrayR(self.as_raw_mut_DAISY(), images.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfVectorOfKeyPoint(), descriptors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1086,10 +1440,11 @@ pub trait DAISY: crate::features2d::Feature2DTrait + crate::xfeatures2d::DAISYCo
 	/// * descriptors: resulted descriptors array for roi image pixels
 	#[inline]
 	fn compute_2(&mut self, image: &dyn core::ToInputArray, roi: core::Rect, descriptors: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("DAISY", self.as_raw_mut_DAISY())?;
 		input_array_arg!(image);
 		output_array_arg!(descriptors);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_DAISY_compute_const__InputArrayR_Rect_const__OutputArrayR(self.as_raw_mut_DAISY(), image.as_raw__InputArray(), roi.opencv_as_extern(), descriptors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_DAISY_compute_const__InputArrayR_Rect_const__OutputArrayR(self.as_raw_mut_DAISY(), image.as_raw__InputArray(), roi.opencv_as_extern(), descriptors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1100,10 +1455,11 @@ pub trait DAISY: crate::features2d::Feature2DTrait + crate::xfeatures2d::DAISYCo
 	/// * descriptors: resulted descriptors array for all image pixels
 	#[inline]
 	fn compute_3(&mut self, image: &dyn core::ToInputArray, descriptors: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("DAISY", self.as_raw_mut_DAISY())?;
 		input_array_arg!(image);
 		output_array_arg!(descriptors);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_DAISY_compute_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_DAISY(), image.as_raw__InputArray(), descriptors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_DAISY_compute_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_DAISY(), image.as_raw__InputArray(), descriptors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1125,7 +1481,7 @@ impl dyn DAISY + '_ {
 	pub fn create(radius: f32, q_radius: i32, q_theta: i32, q_hist: i32, norm: crate::xfeatures2d::DAISY_NormalizationType, h: &dyn core::ToInputArray, interpolation: bool, use_orientation: bool) -> Result<core::Ptr<dyn crate::xfeatures2d::DAISY>> {
 		input_array_arg!(h);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_DAISY_create_float_int_int_int_NormalizationType_const__InputArrayR_bool_bool(radius, q_radius, q_theta, q_hist, norm, h.as_raw__InputArray(), interpolation, use_orientation, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_DAISY_create_float_int_int_int_NormalizationType_const__InputArrayR_bool_bool(radius, q_radius, q_theta, q_hist, norm, h.as_raw__InputArray(), interpolation, use_orientation, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::xfeatures2d::DAISY>::opencv_from_extern(ret) };
@@ -1133,6 +1489,84 @@ impl dyn DAISY + '_ {
 	}
 	
 }
+
+/// Builder for `<dyn DAISY>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct DAISY_CreateBuilder<'a> {
+	radius: f32,
+	q_radius: i32,
+	q_theta: i32,
+	q_hist: i32,
+	norm: crate::xfeatures2d::DAISY_NormalizationType,
+	h: Option<&'a dyn core::ToInputArray>,
+	interpolation: bool,
+	use_orientation: bool,
+}
+
+impl<'a> DAISY_CreateBuilder<'a> {
+	#[inline]
+	pub fn new(norm: crate::xfeatures2d::DAISY_NormalizationType) -> Self {
+		Self { radius: 15., q_radius: 3, q_theta: 8, q_hist: 8, norm, h: None, interpolation: true, use_orientation: false }
+	}
+	
+	/// C++ default: `15`
+	#[inline]
+	pub fn with_radius(mut self, radius: f32) -> Self {
+		self.radius = radius;
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_q_radius(mut self, q_radius: i32) -> Self {
+		self.q_radius = q_radius;
+		self
+	}
+	
+	/// C++ default: `8`
+	#[inline]
+	pub fn with_q_theta(mut self, q_theta: i32) -> Self {
+		self.q_theta = q_theta;
+		self
+	}
+	
+	/// C++ default: `8`
+	#[inline]
+	pub fn with_q_hist(mut self, q_hist: i32) -> Self {
+		self.q_hist = q_hist;
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_h(mut self, h: &'a dyn core::ToInputArray) -> Self {
+		self.h = Some(h);
+		self
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_interpolation(mut self, interpolation: bool) -> Self {
+		self.interpolation = interpolation;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_use_orientation(mut self, use_orientation: bool) -> Self {
+		self.use_orientation = use_orientation;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::xfeatures2d::DAISY>> {
+		let h_no_array = core::no_array();
+		let h: &dyn core::ToInputArray = match self.h { Some(h) => h, None => &h_no_array };
+		<dyn DAISY>::create(self.radius, self.q_radius, self.q_theta, self.q_hist, self.norm, h, self.interpolation, self.use_orientation)
+	}
+}
 /// Elliptic region around an interest point.
 pub trait Elliptic_KeyPointTraitConst {
 	fn as_raw_Elliptic_KeyPoint(&self) -> *const c_void;
@@ -1140,28 +1574,55 @@ pub trait Elliptic_KeyPointTraitConst {
 	/// the lengths of the major and minor ellipse axes
 	#[inline]
 	fn axes(&self) -> core::Size_<f32> {
+		crate::core::validity::debug_check("Elliptic_KeyPoint", self.as_raw_Elliptic_KeyPoint());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_Elliptic_KeyPoint_getPropAxes_const(self.as_raw_Elliptic_KeyPoint(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_Elliptic_KeyPoint_getPropAxes_const(self.as_raw_Elliptic_KeyPoint(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [axes](Elliptic_KeyPointTraitConst::axes), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_axes(&self) -> Result<core::Size_<f32>> {
+		crate::core::validity::check("Elliptic_KeyPoint", self.as_raw_Elliptic_KeyPoint())?;
+		Ok(Elliptic_KeyPointTraitConst::axes(self))
+	}
+	
 	/// the integration scale at which the parameters were estimated
 	#[inline]
 	fn si(&self) -> f32 {
-		let ret = unsafe { sys::cv_xfeatures2d_Elliptic_KeyPoint_getPropSi_const(self.as_raw_Elliptic_KeyPoint()) };
+		crate::core::validity::debug_check("Elliptic_KeyPoint", self.as_raw_Elliptic_KeyPoint());
+		let ret = unsafe { ffi_call!(cv_xfeatures2d_Elliptic_KeyPoint_getPropSi_const(self.as_raw_Elliptic_KeyPoint())) };
 		ret
 	}
 	
+	/// Non-panicking version of [si](Elliptic_KeyPointTraitConst::si), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_si(&self) -> Result<f32> {
+		crate::core::validity::check("Elliptic_KeyPoint", self.as_raw_Elliptic_KeyPoint())?;
+		Ok(Elliptic_KeyPointTraitConst::si(self))
+	}
+	
 	/// the transformation between image space and local patch space
 	#[inline]
 	fn transf(&self) -> core::Matx23f {
+		crate::core::validity::debug_check("Elliptic_KeyPoint", self.as_raw_Elliptic_KeyPoint());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_Elliptic_KeyPoint_getPropTransf_const(self.as_raw_Elliptic_KeyPoint(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_Elliptic_KeyPoint_getPropTransf_const(self.as_raw_Elliptic_KeyPoint(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [transf](Elliptic_KeyPointTraitConst::transf), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_transf(&self) -> Result<core::Matx23f> {
+		crate::core::validity::check("Elliptic_KeyPoint", self.as_raw_Elliptic_KeyPoint())?;
+		Ok(Elliptic_KeyPointTraitConst::transf(self))
+	}
+	
 }
 
 pub trait Elliptic_KeyPointTrait: crate::xfeatures2d::Elliptic_KeyPointTraitConst {
@@ -1170,21 +1631,24 @@ pub trait Elliptic_KeyPointTrait: crate::xfeatures2d::Elliptic_KeyPointTraitCons
 	/// the lengths of the major and minor ellipse axes
 	#[inline]
 	fn set_axes(&mut self, val: core::Size_<f32>) {
-		let ret = unsafe { sys::cv_xfeatures2d_Elliptic_KeyPoint_setPropAxes_Size__float_(self.as_raw_mut_Elliptic_KeyPoint(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("Elliptic_KeyPoint", self.as_raw_mut_Elliptic_KeyPoint());
+		let ret = unsafe { ffi_call!(cv_xfeatures2d_Elliptic_KeyPoint_setPropAxes_Size__float_(self.as_raw_mut_Elliptic_KeyPoint(), val.opencv_as_extern())) };
 		ret
 	}
 	
 	/// the integration scale at which the parameters were estimated
 	#[inline]
 	fn set_si(&mut self, val: f32) {
-		let ret = unsafe { sys::cv_xfeatures2d_Elliptic_KeyPoint_setPropSi_float(self.as_raw_mut_Elliptic_KeyPoint(), val) };
+		crate::core::validity::debug_check("Elliptic_KeyPoint", self.as_raw_mut_Elliptic_KeyPoint());
+		let ret = unsafe { ffi_call!(cv_xfeatures2d_Elliptic_KeyPoint_setPropSi_float(self.as_raw_mut_Elliptic_KeyPoint(), val)) };
 		ret
 	}
 	
 	/// the transformation between image space and local patch space
 	#[inline]
 	fn set_transf(&mut self, val: core::Matx23f) {
-		let ret = unsafe { sys::cv_xfeatures2d_Elliptic_KeyPoint_setPropTransf_Matx23f(self.as_raw_mut_Elliptic_KeyPoint(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("Elliptic_KeyPoint", self.as_raw_mut_Elliptic_KeyPoint());
+		let ret = unsafe { ffi_call!(cv_xfeatures2d_Elliptic_KeyPoint_setPropTransf_Matx23f(self.as_raw_mut_Elliptic_KeyPoint(), val.opencv_as_extern())) };
 		ret
 	}
 	
@@ -1200,7 +1664,10 @@ opencv_type_boxed! { Elliptic_KeyPoint }
 impl Drop for Elliptic_KeyPoint {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Elliptic_KeyPoint_delete(instance: *mut c_void); }
-		unsafe { cv_Elliptic_KeyPoint_delete(self.as_raw_mut_Elliptic_KeyPoint()) };
+		let ptr = self.as_raw_mut_Elliptic_KeyPoint();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_Elliptic_KeyPoint_delete(ptr) };
 	}
 }
 
@@ -1218,7 +1685,7 @@ impl Elliptic_KeyPoint {
 	#[inline]
 	pub fn default() -> Result<crate::xfeatures2d::Elliptic_KeyPoint> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_Elliptic_KeyPoint_Elliptic_KeyPoint(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_Elliptic_KeyPoint_Elliptic_KeyPoint(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::xfeatures2d::Elliptic_KeyPoint::opencv_from_extern(ret) };
@@ -1228,7 +1695,7 @@ impl Elliptic_KeyPoint {
 	#[inline]
 	pub fn new(pt: core::Point2f, angle: f32, axes: core::Size, size: f32, si: f32) -> Result<crate::xfeatures2d::Elliptic_KeyPoint> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_Elliptic_KeyPoint_Elliptic_KeyPoint_Point2f_float_Size_float_float(pt.opencv_as_extern(), angle, axes.opencv_as_extern(), size, si, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_Elliptic_KeyPoint_Elliptic_KeyPoint_Point2f_float_Size_float_float(pt.opencv_as_extern(), angle, axes.opencv_as_extern(), size, si, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::xfeatures2d::Elliptic_KeyPoint::opencv_from_extern(ret) };
@@ -1280,7 +1747,10 @@ opencv_type_boxed! { FREAK }
 impl Drop for FREAK {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FREAK_delete(instance: *mut c_void); }
-		unsafe { cv_FREAK_delete(self.as_raw_mut_FREAK()) };
+		let ptr = self.as_raw_mut_FREAK();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_FREAK_delete(ptr) };
 	}
 }
 
@@ -1330,7 +1800,7 @@ impl FREAK {
 	#[inline]
 	pub fn create(orientation_normalized: bool, scale_normalized: bool, pattern_scale: f32, n_octaves: i32, selected_pairs: &core::Vector<i32>) -> Result<core::Ptr<crate::xfeatures2d::FREAK>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_FREAK_create_bool_bool_float_int_const_vector_int_R(orientation_normalized, scale_normalized, pattern_scale, n_octaves, selected_pairs.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_FREAK_create_bool_bool_float_int_const_vector_int_R(orientation_normalized, scale_normalized, pattern_scale, n_octaves, selected_pairs.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::xfeatures2d::FREAK>::opencv_from_extern(ret) };
@@ -1339,6 +1809,58 @@ impl FREAK {
 	
 }
 
+/// Builder for `FREAK::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FREAK_CreateBuilder<'a> {
+	orientation_normalized: bool,
+	scale_normalized: bool,
+	pattern_scale: f32,
+	n_octaves: i32,
+	selected_pairs: &'a core::Vector<i32>,
+}
+
+impl<'a> FREAK_CreateBuilder<'a> {
+	#[inline]
+	pub fn new(selected_pairs: &'a core::Vector<i32>) -> Self {
+		Self { orientation_normalized: true, scale_normalized: true, pattern_scale: 22.0, n_octaves: 4, selected_pairs }
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_orientation_normalized(mut self, orientation_normalized: bool) -> Self {
+		self.orientation_normalized = orientation_normalized;
+		self
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_scale_normalized(mut self, scale_normalized: bool) -> Self {
+		self.scale_normalized = scale_normalized;
+		self
+	}
+	
+	/// C++ default: `22.0f`
+	#[inline]
+	pub fn with_pattern_scale(mut self, pattern_scale: f32) -> Self {
+		self.pattern_scale = pattern_scale;
+		self
+	}
+	
+	/// C++ default: `4`
+	#[inline]
+	pub fn with_n_octaves(mut self, n_octaves: i32) -> Self {
+		self.n_octaves = n_octaves;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<crate::xfeatures2d::FREAK>> {
+		FREAK::create(self.orientation_normalized, self.scale_normalized, self.pattern_scale, self.n_octaves, self.selected_pairs)
+	}
+}
+
 boxed_cast_base! { FREAK, core::Algorithm, cv_FREAK_to_Algorithm }
 
 boxed_cast_base! { FREAK, crate::features2d::Feature2D, cv_FREAK_to_Feature2D }
@@ -1364,7 +1886,10 @@ opencv_type_boxed! { HarrisLaplaceFeatureDetector }
 impl Drop for HarrisLaplaceFeatureDetector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_HarrisLaplaceFeatureDetector_delete(instance: *mut c_void); }
-		unsafe { cv_HarrisLaplaceFeatureDetector_delete(self.as_raw_mut_HarrisLaplaceFeatureDetector()) };
+		let ptr = self.as_raw_mut_HarrisLaplaceFeatureDetector();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_HarrisLaplaceFeatureDetector_delete(ptr) };
 	}
 }
 
@@ -1413,7 +1938,7 @@ impl HarrisLaplaceFeatureDetector {
 	#[inline]
 	pub fn create(num_octaves: i32, corn_thresh: f32, dog_thresh: f32, max_corners: i32, num_layers: i32) -> Result<core::Ptr<crate::xfeatures2d::HarrisLaplaceFeatureDetector>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_HarrisLaplaceFeatureDetector_create_int_float_float_int_int(num_octaves, corn_thresh, dog_thresh, max_corners, num_layers, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_HarrisLaplaceFeatureDetector_create_int_float_float_int_int(num_octaves, corn_thresh, dog_thresh, max_corners, num_layers, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::xfeatures2d::HarrisLaplaceFeatureDetector>::opencv_from_extern(ret) };
@@ -1422,6 +1947,72 @@ impl HarrisLaplaceFeatureDetector {
 	
 }
 
+/// Builder for `HarrisLaplaceFeatureDetector::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct HarrisLaplaceFeatureDetector_CreateBuilder {
+	num_octaves: i32,
+	corn_thresh: f32,
+	dog_thresh: f32,
+	max_corners: i32,
+	num_layers: i32,
+}
+
+impl HarrisLaplaceFeatureDetector_CreateBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { num_octaves: 6, corn_thresh: 0.01, dog_thresh: 0.01, max_corners: 5000, num_layers: 4 }
+	}
+	
+	/// C++ default: `6`
+	#[inline]
+	pub fn with_num_octaves(mut self, num_octaves: i32) -> Self {
+		self.num_octaves = num_octaves;
+		self
+	}
+	
+	/// C++ default: `0.01f`
+	#[inline]
+	pub fn with_corn_thresh(mut self, corn_thresh: f32) -> Self {
+		self.corn_thresh = corn_thresh;
+		self
+	}
+	
+	/// C++ default: `0.01f`
+	#[inline]
+	pub fn with_dog_thresh(mut self, dog_thresh: f32) -> Self {
+		self.dog_thresh = dog_thresh;
+		self
+	}
+	
+	/// C++ default: `5000`
+	#[inline]
+	pub fn with_max_corners(mut self, max_corners: i32) -> Self {
+		self.max_corners = max_corners;
+		self
+	}
+	
+	/// C++ default: `4`
+	#[inline]
+	pub fn with_num_layers(mut self, num_layers: i32) -> Self {
+		self.num_layers = num_layers;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<crate::xfeatures2d::HarrisLaplaceFeatureDetector>> {
+		HarrisLaplaceFeatureDetector::create(self.num_octaves, self.corn_thresh, self.dog_thresh, self.max_corners, self.num_layers)
+	}
+}
+
+impl Default for HarrisLaplaceFeatureDetector_CreateBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 boxed_cast_base! { HarrisLaplaceFeatureDetector, core::Algorithm, cv_HarrisLaplaceFeatureDetector_to_Algorithm }
 
 boxed_cast_base! { HarrisLaplaceFeatureDetector, crate::features2d::Feature2D, cv_HarrisLaplaceFeatureDetector_to_Feature2D }
@@ -1477,7 +2068,10 @@ opencv_type_boxed! { LATCH }
 impl Drop for LATCH {
 	fn drop(&mut self) {
 		extern "C" { fn cv_LATCH_delete(instance: *mut c_void); }
-		unsafe { cv_LATCH_delete(self.as_raw_mut_LATCH()) };
+		let ptr = self.as_raw_mut_LATCH();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_LATCH_delete(ptr) };
 	}
 }
 
@@ -1516,7 +2110,7 @@ impl LATCH {
 	#[inline]
 	pub fn create(bytes: i32, rotation_invariance: bool, half_ssd_size: i32, sigma: f64) -> Result<core::Ptr<crate::xfeatures2d::LATCH>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_LATCH_create_int_bool_int_double(bytes, rotation_invariance, half_ssd_size, sigma, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_LATCH_create_int_bool_int_double(bytes, rotation_invariance, half_ssd_size, sigma, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::xfeatures2d::LATCH>::opencv_from_extern(ret) };
@@ -1525,6 +2119,64 @@ impl LATCH {
 	
 }
 
+/// Builder for `LATCH::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct LATCH_CreateBuilder {
+	bytes: i32,
+	rotation_invariance: bool,
+	half_ssd_size: i32,
+	sigma: f64,
+}
+
+impl LATCH_CreateBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { bytes: 32, rotation_invariance: true, half_ssd_size: 3, sigma: 2.0 }
+	}
+	
+	/// C++ default: `32`
+	#[inline]
+	pub fn with_bytes(mut self, bytes: i32) -> Self {
+		self.bytes = bytes;
+		self
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_rotation_invariance(mut self, rotation_invariance: bool) -> Self {
+		self.rotation_invariance = rotation_invariance;
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_half_ssd_size(mut self, half_ssd_size: i32) -> Self {
+		self.half_ssd_size = half_ssd_size;
+		self
+	}
+	
+	/// C++ default: `2.0`
+	#[inline]
+	pub fn with_sigma(mut self, sigma: f64) -> Self {
+		self.sigma = sigma;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<crate::xfeatures2d::LATCH>> {
+		LATCH::create(self.bytes, self.rotation_invariance, self.half_ssd_size, self.sigma)
+	}
+}
+
+impl Default for LATCH_CreateBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 boxed_cast_base! { LATCH, core::Algorithm, cv_LATCH_to_Algorithm }
 
 boxed_cast_base! { LATCH, crate::features2d::Feature2D, cv_LATCH_to_Feature2D }
@@ -1562,7 +2214,10 @@ opencv_type_boxed! { LUCID }
 impl Drop for LUCID {
 	fn drop(&mut self) {
 		extern "C" { fn cv_LUCID_delete(instance: *mut c_void); }
-		unsafe { cv_LUCID_delete(self.as_raw_mut_LUCID()) };
+		let ptr = self.as_raw_mut_LUCID();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_LUCID_delete(ptr) };
 	}
 }
 
@@ -1603,7 +2258,7 @@ impl LUCID {
 	#[inline]
 	pub fn create(lucid_kernel: i32, blur_kernel: i32) -> Result<core::Ptr<crate::xfeatures2d::LUCID>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_LUCID_create_const_int_const_int(lucid_kernel, blur_kernel, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_LUCID_create_const_int_const_int(lucid_kernel, blur_kernel, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::xfeatures2d::LUCID>::opencv_from_extern(ret) };
@@ -1655,7 +2310,10 @@ opencv_type_boxed! { MSDDetector }
 impl Drop for MSDDetector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MSDDetector_delete(instance: *mut c_void); }
-		unsafe { cv_MSDDetector_delete(self.as_raw_mut_MSDDetector()) };
+		let ptr = self.as_raw_mut_MSDDetector();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_MSDDetector_delete(ptr) };
 	}
 }
 
@@ -1699,7 +2357,7 @@ impl MSDDetector {
 	#[inline]
 	pub fn create(m_patch_radius: i32, m_search_area_radius: i32, m_nms_radius: i32, m_nms_scale_radius: i32, m_th_saliency: f32, m_k_nn: i32, m_scale_factor: f32, m_n_scales: i32, m_compute_orientation: bool) -> Result<core::Ptr<crate::xfeatures2d::MSDDetector>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_MSDDetector_create_int_int_int_int_float_int_float_int_bool(m_patch_radius, m_search_area_radius, m_nms_radius, m_nms_scale_radius, m_th_saliency, m_k_nn, m_scale_factor, m_n_scales, m_compute_orientation, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_MSDDetector_create_int_int_int_int_float_int_float_int_bool(m_patch_radius, m_search_area_radius, m_nms_radius, m_nms_scale_radius, m_th_saliency, m_k_nn, m_scale_factor, m_n_scales, m_compute_orientation, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::xfeatures2d::MSDDetector>::opencv_from_extern(ret) };
@@ -1708,6 +2366,104 @@ impl MSDDetector {
 	
 }
 
+/// Builder for `MSDDetector::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct MSDDetector_CreateBuilder {
+	m_patch_radius: i32,
+	m_search_area_radius: i32,
+	m_nms_radius: i32,
+	m_nms_scale_radius: i32,
+	m_th_saliency: f32,
+	m_k_nn: i32,
+	m_scale_factor: f32,
+	m_n_scales: i32,
+	m_compute_orientation: bool,
+}
+
+impl MSDDetector_CreateBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { m_patch_radius: 3, m_search_area_radius: 5, m_nms_radius: 5, m_nms_scale_radius: 0, m_th_saliency: 250.0, m_k_nn: 4, m_scale_factor: 1.25, m_n_scales: -1, m_compute_orientation: false }
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_m_patch_radius(mut self, m_patch_radius: i32) -> Self {
+		self.m_patch_radius = m_patch_radius;
+		self
+	}
+	
+	/// C++ default: `5`
+	#[inline]
+	pub fn with_m_search_area_radius(mut self, m_search_area_radius: i32) -> Self {
+		self.m_search_area_radius = m_search_area_radius;
+		self
+	}
+	
+	/// C++ default: `5`
+	#[inline]
+	pub fn with_m_nms_radius(mut self, m_nms_radius: i32) -> Self {
+		self.m_nms_radius = m_nms_radius;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_m_nms_scale_radius(mut self, m_nms_scale_radius: i32) -> Self {
+		self.m_nms_scale_radius = m_nms_scale_radius;
+		self
+	}
+	
+	/// C++ default: `250.0f`
+	#[inline]
+	pub fn with_m_th_saliency(mut self, m_th_saliency: f32) -> Self {
+		self.m_th_saliency = m_th_saliency;
+		self
+	}
+	
+	/// C++ default: `4`
+	#[inline]
+	pub fn with_m_k_nn(mut self, m_k_nn: i32) -> Self {
+		self.m_k_nn = m_k_nn;
+		self
+	}
+	
+	/// C++ default: `1.25f`
+	#[inline]
+	pub fn with_m_scale_factor(mut self, m_scale_factor: f32) -> Self {
+		self.m_scale_factor = m_scale_factor;
+		self
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_m_n_scales(mut self, m_n_scales: i32) -> Self {
+		self.m_n_scales = m_n_scales;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_m_compute_orientation(mut self, m_compute_orientation: bool) -> Self {
+		self.m_compute_orientation = m_compute_orientation;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<crate::xfeatures2d::MSDDetector>> {
+		MSDDetector::create(self.m_patch_radius, self.m_search_area_radius, self.m_nms_radius, self.m_nms_scale_radius, self.m_th_saliency, self.m_k_nn, self.m_scale_factor, self.m_n_scales, self.m_compute_orientation)
+	}
+}
+
+impl Default for MSDDetector_CreateBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 boxed_cast_base! { MSDDetector, core::Algorithm, cv_MSDDetector_to_Algorithm }
 
 boxed_cast_base! { MSDDetector, crate::features2d::Feature2D, cv_MSDDetector_to_Feature2D }
@@ -1733,10 +2489,11 @@ pub trait PCTSignaturesConst: core::AlgorithmTraitConst {
 	/// * signature: Output computed signature.
 	#[inline]
 	fn compute_signature(&self, image: &dyn core::ToInputArray, signature: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_PCTSignatures())?;
 		input_array_arg!(image);
 		output_array_arg!(signature);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_computeSignature_const_const__InputArrayR_const__OutputArrayR(self.as_raw_PCTSignatures(), image.as_raw__InputArray(), signature.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_computeSignature_const_const__InputArrayR_const__OutputArrayR(self.as_raw_PCTSignatures(), image.as_raw__InputArray(), signature.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1748,8 +2505,9 @@ pub trait PCTSignaturesConst: core::AlgorithmTraitConst {
 	/// * signatures: Vector of computed signatures.
 	#[inline]
 	fn compute_signatures(&self, images: &core::Vector<core::Mat>, signatures: &mut core::Vector<core::Mat>) -> Result<()> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_computeSignatures_const_const_vector_Mat_R_vector_Mat_R(self.as_raw_PCTSignatures(), images.as_raw_VectorOfMat(), signatures.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_computeSignatures_const_const_vector_Mat_R_vector_Mat_R(self.as_raw_PCTSignatures(), images.as_raw_VectorOfMat(), signatures.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1758,8 +2516,9 @@ pub trait PCTSignaturesConst: core::AlgorithmTraitConst {
 	/// Number of initial samples taken from the image.
 	#[inline]
 	fn get_sample_count(&self) -> Result<i32> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_getSampleCount_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_getSampleCount_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1770,8 +2529,9 @@ pub trait PCTSignaturesConst: core::AlgorithmTraitConst {
 	///       The greyscale bitmap is used for computing contrast and entropy values.
 	#[inline]
 	fn get_grayscale_bits(&self) -> Result<i32> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_getGrayscaleBits_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_getGrayscaleBits_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1782,8 +2542,9 @@ pub trait PCTSignaturesConst: core::AlgorithmTraitConst {
 	///       of the corresponding feature sample).
 	#[inline]
 	fn get_window_radius(&self) -> Result<i32> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_getWindowRadius_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_getWindowRadius_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1793,8 +2554,9 @@ pub trait PCTSignaturesConst: core::AlgorithmTraitConst {
 	///       (x,y = position; L,a,b = color in CIE Lab space; c = contrast. e = entropy)
 	#[inline]
 	fn get_weight_x(&self) -> Result<f32> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_getWeightX_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_getWeightX_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1804,8 +2566,9 @@ pub trait PCTSignaturesConst: core::AlgorithmTraitConst {
 	///       (x,y = position; L,a,b = color in CIE Lab space; c = contrast. e = entropy)
 	#[inline]
 	fn get_weight_y(&self) -> Result<f32> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_getWeightY_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_getWeightY_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1815,8 +2578,9 @@ pub trait PCTSignaturesConst: core::AlgorithmTraitConst {
 	///       (x,y = position; L,a,b = color in CIE Lab space; c = contrast. e = entropy)
 	#[inline]
 	fn get_weight_l(&self) -> Result<f32> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_getWeightL_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_getWeightL_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1826,8 +2590,9 @@ pub trait PCTSignaturesConst: core::AlgorithmTraitConst {
 	///       (x,y = position; L,a,b = color in CIE Lab space; c = contrast. e = entropy)
 	#[inline]
 	fn get_weight_a(&self) -> Result<f32> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_getWeightA_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_getWeightA_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1837,8 +2602,9 @@ pub trait PCTSignaturesConst: core::AlgorithmTraitConst {
 	///       (x,y = position; L,a,b = color in CIE Lab space; c = contrast. e = entropy)
 	#[inline]
 	fn get_weight_b(&self) -> Result<f32> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_getWeightB_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_getWeightB_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1848,8 +2614,9 @@ pub trait PCTSignaturesConst: core::AlgorithmTraitConst {
 	///       (x,y = position; L,a,b = color in CIE Lab space; c = contrast. e = entropy)
 	#[inline]
 	fn get_weight_contrast(&self) -> Result<f32> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_getWeightContrast_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_getWeightContrast_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1859,8 +2626,9 @@ pub trait PCTSignaturesConst: core::AlgorithmTraitConst {
 	///       (x,y = position; L,a,b = color in CIE Lab space; c = contrast. e = entropy)
 	#[inline]
 	fn get_weight_entropy(&self) -> Result<f32> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_getWeightEntropy_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_getWeightEntropy_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1870,8 +2638,9 @@ pub trait PCTSignaturesConst: core::AlgorithmTraitConst {
 	///       These sampled features become the input for clustering.
 	#[inline]
 	fn get_sampling_points(&self) -> Result<core::Vector<core::Point2f>> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_getSamplingPoints_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_getSamplingPoints_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<core::Point2f>::opencv_from_extern(ret) };
@@ -1883,8 +2652,9 @@ pub trait PCTSignaturesConst: core::AlgorithmTraitConst {
 	/// * Initial seeds (initial number of clusters) for the k-means algorithm.
 	#[inline]
 	fn get_init_seed_indexes(&self) -> Result<core::Vector<i32>> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_getInitSeedIndexes_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_getInitSeedIndexes_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<i32>::opencv_from_extern(ret) };
@@ -1894,8 +2664,9 @@ pub trait PCTSignaturesConst: core::AlgorithmTraitConst {
 	/// Number of initial seeds (initial number of clusters) for the k-means algorithm.
 	#[inline]
 	fn get_init_seed_count(&self) -> Result<i32> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_getInitSeedCount_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_getInitSeedCount_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1906,8 +2677,9 @@ pub trait PCTSignaturesConst: core::AlgorithmTraitConst {
 	///       (not iteratively refining k clusters).
 	#[inline]
 	fn get_iteration_count(&self) -> Result<i32> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_getIterationCount_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_getIterationCount_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1917,8 +2689,9 @@ pub trait PCTSignaturesConst: core::AlgorithmTraitConst {
 	///       the clusters are sorted by their weights and the smallest clusters are cropped.
 	#[inline]
 	fn get_max_clusters_count(&self) -> Result<i32> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_getMaxClustersCount_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_getMaxClustersCount_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1929,8 +2702,9 @@ pub trait PCTSignaturesConst: core::AlgorithmTraitConst {
 	///       and points are reassigned.
 	#[inline]
 	fn get_cluster_min_size(&self) -> Result<i32> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_getClusterMinSize_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_getClusterMinSize_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1941,8 +2715,9 @@ pub trait PCTSignaturesConst: core::AlgorithmTraitConst {
 	///       one of the centroid is dismissed and points are reassigned.
 	#[inline]
 	fn get_joining_distance(&self) -> Result<f32> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_getJoiningDistance_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_getJoiningDistance_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1951,8 +2726,9 @@ pub trait PCTSignaturesConst: core::AlgorithmTraitConst {
 	/// Remove centroids in k-means whose weight is lesser or equal to given threshold.
 	#[inline]
 	fn get_drop_threshold(&self) -> Result<f32> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_getDropThreshold_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_getDropThreshold_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1961,8 +2737,9 @@ pub trait PCTSignaturesConst: core::AlgorithmTraitConst {
 	/// Distance function selector used for measuring distance between two points in k-means.
 	#[inline]
 	fn get_distance_function(&self) -> Result<i32> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_getDistanceFunction_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_getDistanceFunction_const(self.as_raw_PCTSignatures(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1978,8 +2755,9 @@ pub trait PCTSignatures: core::AlgorithmTrait + crate::xfeatures2d::PCTSignature
 	///       The greyscale bitmap is used for computing contrast and entropy values.
 	#[inline]
 	fn set_grayscale_bits(&mut self, grayscale_bits: i32) -> Result<()> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_mut_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_setGrayscaleBits_int(self.as_raw_mut_PCTSignatures(), grayscale_bits, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_setGrayscaleBits_int(self.as_raw_mut_PCTSignatures(), grayscale_bits, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1990,8 +2768,9 @@ pub trait PCTSignatures: core::AlgorithmTrait + crate::xfeatures2d::PCTSignature
 	///       of the corresponding feature sample).
 	#[inline]
 	fn set_window_radius(&mut self, radius: i32) -> Result<()> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_mut_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_setWindowRadius_int(self.as_raw_mut_PCTSignatures(), radius, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_setWindowRadius_int(self.as_raw_mut_PCTSignatures(), radius, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2001,8 +2780,9 @@ pub trait PCTSignatures: core::AlgorithmTrait + crate::xfeatures2d::PCTSignature
 	///       (x,y = position; L,a,b = color in CIE Lab space; c = contrast. e = entropy)
 	#[inline]
 	fn set_weight_x(&mut self, weight: f32) -> Result<()> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_mut_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_setWeightX_float(self.as_raw_mut_PCTSignatures(), weight, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_setWeightX_float(self.as_raw_mut_PCTSignatures(), weight, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2012,8 +2792,9 @@ pub trait PCTSignatures: core::AlgorithmTrait + crate::xfeatures2d::PCTSignature
 	///       (x,y = position; L,a,b = color in CIE Lab space; c = contrast. e = entropy)
 	#[inline]
 	fn set_weight_y(&mut self, weight: f32) -> Result<()> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_mut_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_setWeightY_float(self.as_raw_mut_PCTSignatures(), weight, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_setWeightY_float(self.as_raw_mut_PCTSignatures(), weight, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2023,8 +2804,9 @@ pub trait PCTSignatures: core::AlgorithmTrait + crate::xfeatures2d::PCTSignature
 	///       (x,y = position; L,a,b = color in CIE Lab space; c = contrast. e = entropy)
 	#[inline]
 	fn set_weight_l(&mut self, weight: f32) -> Result<()> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_mut_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_setWeightL_float(self.as_raw_mut_PCTSignatures(), weight, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_setWeightL_float(self.as_raw_mut_PCTSignatures(), weight, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2034,8 +2816,9 @@ pub trait PCTSignatures: core::AlgorithmTrait + crate::xfeatures2d::PCTSignature
 	///       (x,y = position; L,a,b = color in CIE Lab space; c = contrast. e = entropy)
 	#[inline]
 	fn set_weight_a(&mut self, weight: f32) -> Result<()> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_mut_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_setWeightA_float(self.as_raw_mut_PCTSignatures(), weight, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_setWeightA_float(self.as_raw_mut_PCTSignatures(), weight, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2045,8 +2828,9 @@ pub trait PCTSignatures: core::AlgorithmTrait + crate::xfeatures2d::PCTSignature
 	///       (x,y = position; L,a,b = color in CIE Lab space; c = contrast. e = entropy)
 	#[inline]
 	fn set_weight_b(&mut self, weight: f32) -> Result<()> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_mut_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_setWeightB_float(self.as_raw_mut_PCTSignatures(), weight, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_setWeightB_float(self.as_raw_mut_PCTSignatures(), weight, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2056,8 +2840,9 @@ pub trait PCTSignatures: core::AlgorithmTrait + crate::xfeatures2d::PCTSignature
 	///       (x,y = position; L,a,b = color in CIE Lab space; c = contrast. e = entropy)
 	#[inline]
 	fn set_weight_contrast(&mut self, weight: f32) -> Result<()> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_mut_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_setWeightContrast_float(self.as_raw_mut_PCTSignatures(), weight, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_setWeightContrast_float(self.as_raw_mut_PCTSignatures(), weight, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2067,8 +2852,9 @@ pub trait PCTSignatures: core::AlgorithmTrait + crate::xfeatures2d::PCTSignature
 	///       (x,y = position; L,a,b = color in CIE Lab space; c = contrast. e = entropy)
 	#[inline]
 	fn set_weight_entropy(&mut self, weight: f32) -> Result<()> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_mut_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_setWeightEntropy_float(self.as_raw_mut_PCTSignatures(), weight, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_setWeightEntropy_float(self.as_raw_mut_PCTSignatures(), weight, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2090,8 +2876,9 @@ pub trait PCTSignatures: core::AlgorithmTrait + crate::xfeatures2d::PCTSignature
 	///       ENTROPY_IDX = 7;
 	#[inline]
 	fn set_weight(&mut self, idx: i32, value: f32) -> Result<()> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_mut_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_setWeight_int_float(self.as_raw_mut_PCTSignatures(), idx, value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_setWeight_int_float(self.as_raw_mut_PCTSignatures(), idx, value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2112,8 +2899,9 @@ pub trait PCTSignatures: core::AlgorithmTrait + crate::xfeatures2d::PCTSignature
 	///       ENTROPY_IDX = 7;
 	#[inline]
 	fn set_weights(&mut self, weights: &core::Vector<f32>) -> Result<()> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_mut_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_setWeights_const_vector_float_R(self.as_raw_mut_PCTSignatures(), weights.as_raw_VectorOff32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_setWeights_const_vector_float_R(self.as_raw_mut_PCTSignatures(), weights.as_raw_VectorOff32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2135,8 +2923,9 @@ pub trait PCTSignatures: core::AlgorithmTrait + crate::xfeatures2d::PCTSignature
 	///       ENTROPY_IDX = 7;
 	#[inline]
 	fn set_translation(&mut self, idx: i32, value: f32) -> Result<()> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_mut_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_setTranslation_int_float(self.as_raw_mut_PCTSignatures(), idx, value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_setTranslation_int_float(self.as_raw_mut_PCTSignatures(), idx, value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2157,8 +2946,9 @@ pub trait PCTSignatures: core::AlgorithmTrait + crate::xfeatures2d::PCTSignature
 	///       ENTROPY_IDX = 7;
 	#[inline]
 	fn set_translations(&mut self, translations: &core::Vector<f32>) -> Result<()> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_mut_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_setTranslations_const_vector_float_R(self.as_raw_mut_PCTSignatures(), translations.as_raw_VectorOff32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_setTranslations_const_vector_float_R(self.as_raw_mut_PCTSignatures(), translations.as_raw_VectorOff32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2166,13 +2956,14 @@ pub trait PCTSignatures: core::AlgorithmTrait + crate::xfeatures2d::PCTSignature
 	
 	/// Sets sampling points used to sample the input image.
 	/// ## Parameters
-	/// * samplingPoints: Vector of sampling points in range [0..1)
+	/// * samplingPoints: Vector of sampling points in range \[0..1)
 	/// 
 	/// Note: Number of sampling points must be greater or equal to clusterization seed count.
 	#[inline]
 	fn set_sampling_points(&mut self, mut sampling_points: core::Vector<core::Point2f>) -> Result<()> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_mut_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_setSamplingPoints_vector_Point2f_(self.as_raw_mut_PCTSignatures(), sampling_points.as_raw_mut_VectorOfPoint2f(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_setSamplingPoints_vector_Point2f_(self.as_raw_mut_PCTSignatures(), sampling_points.as_raw_mut_VectorOfPoint2f(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2181,8 +2972,9 @@ pub trait PCTSignatures: core::AlgorithmTrait + crate::xfeatures2d::PCTSignature
 	/// Initial seed indexes for the k-means algorithm.
 	#[inline]
 	fn set_init_seed_indexes(&mut self, mut init_seed_indexes: core::Vector<i32>) -> Result<()> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_mut_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_setInitSeedIndexes_vector_int_(self.as_raw_mut_PCTSignatures(), init_seed_indexes.as_raw_mut_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_setInitSeedIndexes_vector_int_(self.as_raw_mut_PCTSignatures(), init_seed_indexes.as_raw_mut_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2193,8 +2985,9 @@ pub trait PCTSignatures: core::AlgorithmTrait + crate::xfeatures2d::PCTSignature
 	///       (not iteratively refining k clusters).
 	#[inline]
 	fn set_iteration_count(&mut self, iteration_count: i32) -> Result<()> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_mut_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_setIterationCount_int(self.as_raw_mut_PCTSignatures(), iteration_count, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_setIterationCount_int(self.as_raw_mut_PCTSignatures(), iteration_count, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2204,8 +2997,9 @@ pub trait PCTSignatures: core::AlgorithmTrait + crate::xfeatures2d::PCTSignature
 	///       the clusters are sorted by their weights and the smallest clusters are cropped.
 	#[inline]
 	fn set_max_clusters_count(&mut self, max_clusters_count: i32) -> Result<()> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_mut_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_setMaxClustersCount_int(self.as_raw_mut_PCTSignatures(), max_clusters_count, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_setMaxClustersCount_int(self.as_raw_mut_PCTSignatures(), max_clusters_count, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2216,8 +3010,9 @@ pub trait PCTSignatures: core::AlgorithmTrait + crate::xfeatures2d::PCTSignature
 	///       and points are reassigned.
 	#[inline]
 	fn set_cluster_min_size(&mut self, cluster_min_size: i32) -> Result<()> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_mut_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_setClusterMinSize_int(self.as_raw_mut_PCTSignatures(), cluster_min_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_setClusterMinSize_int(self.as_raw_mut_PCTSignatures(), cluster_min_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2228,8 +3023,9 @@ pub trait PCTSignatures: core::AlgorithmTrait + crate::xfeatures2d::PCTSignature
 	///       one of the centroid is dismissed and points are reassigned.
 	#[inline]
 	fn set_joining_distance(&mut self, joining_distance: f32) -> Result<()> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_mut_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_setJoiningDistance_float(self.as_raw_mut_PCTSignatures(), joining_distance, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_setJoiningDistance_float(self.as_raw_mut_PCTSignatures(), joining_distance, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2238,8 +3034,9 @@ pub trait PCTSignatures: core::AlgorithmTrait + crate::xfeatures2d::PCTSignature
 	/// Remove centroids in k-means whose weight is lesser or equal to given threshold.
 	#[inline]
 	fn set_drop_threshold(&mut self, drop_threshold: f32) -> Result<()> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_mut_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_setDropThreshold_float(self.as_raw_mut_PCTSignatures(), drop_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_setDropThreshold_float(self.as_raw_mut_PCTSignatures(), drop_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2249,8 +3046,9 @@ pub trait PCTSignatures: core::AlgorithmTrait + crate::xfeatures2d::PCTSignature
 	///       Available: L0_25, L0_5, L1, L2, L2SQUARED, L5, L_INFINITY.
 	#[inline]
 	fn set_distance_function(&mut self, distance_function: i32) -> Result<()> {
+		crate::core::validity::check("PCTSignatures", self.as_raw_mut_PCTSignatures())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_setDistanceFunction_int(self.as_raw_mut_PCTSignatures(), distance_function, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_setDistanceFunction_int(self.as_raw_mut_PCTSignatures(), distance_function, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2277,7 +3075,7 @@ impl dyn PCTSignatures + '_ {
 	#[inline]
 	pub fn create(init_sample_count: i32, init_seed_count: i32, point_distribution: i32) -> Result<core::Ptr<dyn crate::xfeatures2d::PCTSignatures>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_create_const_int_const_int_const_int(init_sample_count, init_seed_count, point_distribution, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_create_const_int_const_int_const_int(init_sample_count, init_seed_count, point_distribution, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::xfeatures2d::PCTSignatures>::opencv_from_extern(ret) };
@@ -2296,7 +3094,7 @@ impl dyn PCTSignatures + '_ {
 	#[inline]
 	pub fn create_1(init_sampling_points: &core::Vector<core::Point2f>, init_seed_count: i32) -> Result<core::Ptr<dyn crate::xfeatures2d::PCTSignatures>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_create_const_vector_Point2f_R_const_int(init_sampling_points.as_raw_VectorOfPoint2f(), init_seed_count, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_create_const_vector_Point2f_R_const_int(init_sampling_points.as_raw_VectorOfPoint2f(), init_seed_count, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::xfeatures2d::PCTSignatures>::opencv_from_extern(ret) };
@@ -2314,7 +3112,7 @@ impl dyn PCTSignatures + '_ {
 	#[inline]
 	pub fn create_2(init_sampling_points: &core::Vector<core::Point2f>, init_cluster_seed_indexes: &core::Vector<i32>) -> Result<core::Ptr<dyn crate::xfeatures2d::PCTSignatures>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_create_const_vector_Point2f_R_const_vector_int_R(init_sampling_points.as_raw_VectorOfPoint2f(), init_cluster_seed_indexes.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_create_const_vector_Point2f_R_const_vector_int_R(init_sampling_points.as_raw_VectorOfPoint2f(), init_cluster_seed_indexes.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::xfeatures2d::PCTSignatures>::opencv_from_extern(ret) };
@@ -2342,7 +3140,7 @@ impl dyn PCTSignatures + '_ {
 		input_array_arg!(signature);
 		output_array_arg!(result);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_drawSignature_const__InputArrayR_const__InputArrayR_const__OutputArrayR_float_int(source.as_raw__InputArray(), signature.as_raw__InputArray(), result.as_raw__OutputArray(), radius_to_shorter_side_ratio, border_thickness, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_drawSignature_const__InputArrayR_const__InputArrayR_const__OutputArrayR_float_int(source.as_raw__InputArray(), signature.as_raw__InputArray(), result.as_raw__OutputArray(), radius_to_shorter_side_ratio, border_thickness, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2355,23 +3153,73 @@ impl dyn PCTSignatures + '_ {
 	/// * pointDistribution: Point distribution selector.
 	///       Available: UNIFORM, REGULAR, NORMAL.
 	/// 
-	/// Note: Generated coordinates are in range [0..1)
+	/// Note: Generated coordinates are in range \[0..1)
 	#[inline]
 	pub fn generate_init_points(init_points: &mut core::Vector<core::Point2f>, count: i32, point_distribution: i32) -> Result<()> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignatures_generateInitPoints_vector_Point2f_R_const_int_int(init_points.as_raw_mut_VectorOfPoint2f(), count, point_distribution, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignatures_generateInitPoints_vector_Point2f_R_const_int_int(init_points.as_raw_mut_VectorOfPoint2f(), count, point_distribution, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 }
+
+/// Builder for `<dyn PCTSignatures>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct PCTSignatures_CreateBuilder {
+	init_sample_count: i32,
+	init_seed_count: i32,
+	point_distribution: i32,
+}
+
+impl PCTSignatures_CreateBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { init_sample_count: 2000, init_seed_count: 400, point_distribution: 0 }
+	}
+	
+	/// C++ default: `2000`
+	#[inline]
+	pub fn with_init_sample_count(mut self, init_sample_count: i32) -> Self {
+		self.init_sample_count = init_sample_count;
+		self
+	}
+	
+	/// C++ default: `400`
+	#[inline]
+	pub fn with_init_seed_count(mut self, init_seed_count: i32) -> Self {
+		self.init_seed_count = init_seed_count;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_point_distribution(mut self, point_distribution: i32) -> Self {
+		self.point_distribution = point_distribution;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::xfeatures2d::PCTSignatures>> {
+		<dyn PCTSignatures>::create(self.init_sample_count, self.init_seed_count, self.point_distribution)
+	}
+}
+
+impl Default for PCTSignatures_CreateBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
 /// Class implementing Signature Quadratic Form Distance (SQFD).
 /// ## See also
 /// Christian Beecks, Merih Seran Uysal, Thomas Seidl.
 ///   Signature quadratic form distance.
 ///   In Proceedings of the ACM International Conference on Image and Video Retrieval, pages 438-445.
-///   ACM, 2010.
+///   `ACM`, 2010.
 /// [BeecksUS10](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_BeecksUS10)
 pub trait PCTSignaturesSQFDConst: core::AlgorithmTraitConst {
 	fn as_raw_PCTSignaturesSQFD(&self) -> *const c_void;
@@ -2382,10 +3230,11 @@ pub trait PCTSignaturesSQFDConst: core::AlgorithmTraitConst {
 	/// * _signature1: The second signature.
 	#[inline]
 	fn compute_quadratic_form_distance(&self, _signature0: &dyn core::ToInputArray, _signature1: &dyn core::ToInputArray) -> Result<f32> {
+		crate::core::validity::check("PCTSignaturesSQFD", self.as_raw_PCTSignaturesSQFD())?;
 		input_array_arg!(_signature0);
 		input_array_arg!(_signature1);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignaturesSQFD_computeQuadraticFormDistance_const_const__InputArrayR_const__InputArrayR(self.as_raw_PCTSignaturesSQFD(), _signature0.as_raw__InputArray(), _signature1.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignaturesSQFD_computeQuadraticFormDistance_const_const__InputArrayR_const__InputArrayR(self.as_raw_PCTSignaturesSQFD(), _signature0.as_raw__InputArray(), _signature1.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2399,8 +3248,9 @@ pub trait PCTSignaturesSQFDConst: core::AlgorithmTraitConst {
 	/// * distances: Output vector of measured distances.
 	#[inline]
 	fn compute_quadratic_form_distances(&self, source_signature: &core::Mat, image_signatures: &core::Vector<core::Mat>, distances: &mut core::Vector<f32>) -> Result<()> {
+		crate::core::validity::check("PCTSignaturesSQFD", self.as_raw_PCTSignaturesSQFD())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignaturesSQFD_computeQuadraticFormDistances_const_const_MatR_const_vector_Mat_R_vector_float_R(self.as_raw_PCTSignaturesSQFD(), source_signature.as_raw_Mat(), image_signatures.as_raw_VectorOfMat(), distances.as_raw_mut_VectorOff32(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignaturesSQFD_computeQuadraticFormDistances_const_const_MatR_const_vector_Mat_R_vector_float_R(self.as_raw_PCTSignaturesSQFD(), source_signature.as_raw_Mat(), image_signatures.as_raw_VectorOfMat(), distances.as_raw_mut_VectorOff32(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2430,7 +3280,7 @@ impl dyn PCTSignaturesSQFD + '_ {
 	#[inline]
 	pub fn create(distance_function: i32, similarity_function: i32, similarity_parameter: f32) -> Result<core::Ptr<dyn crate::xfeatures2d::PCTSignaturesSQFD>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_PCTSignaturesSQFD_create_const_int_const_int_const_float(distance_function, similarity_function, similarity_parameter, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_PCTSignaturesSQFD_create_const_int_const_int_const_float(distance_function, similarity_function, similarity_parameter, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::xfeatures2d::PCTSignaturesSQFD>::opencv_from_extern(ret) };
@@ -2438,6 +3288,56 @@ impl dyn PCTSignaturesSQFD + '_ {
 	}
 	
 }
+
+/// Builder for `<dyn PCTSignaturesSQFD>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct PCTSignaturesSQFD_CreateBuilder {
+	distance_function: i32,
+	similarity_function: i32,
+	similarity_parameter: f32,
+}
+
+impl PCTSignaturesSQFD_CreateBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { distance_function: 3, similarity_function: 2, similarity_parameter: 1.0 }
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_distance_function(mut self, distance_function: i32) -> Self {
+		self.distance_function = distance_function;
+		self
+	}
+	
+	/// C++ default: `2`
+	#[inline]
+	pub fn with_similarity_function(mut self, similarity_function: i32) -> Self {
+		self.similarity_function = similarity_function;
+		self
+	}
+	
+	/// C++ default: `1.0f`
+	#[inline]
+	pub fn with_similarity_parameter(mut self, similarity_parameter: f32) -> Self {
+		self.similarity_parameter = similarity_parameter;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::xfeatures2d::PCTSignaturesSQFD>> {
+		<dyn PCTSignaturesSQFD>::create(self.distance_function, self.similarity_function, self.similarity_parameter)
+	}
+}
+
+impl Default for PCTSignaturesSQFD_CreateBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
 /// Class for extracting Speeded Up Robust Features from an image [Bay06](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_Bay06) .
 /// 
 /// The algorithm parameters:
@@ -2472,8 +3372,9 @@ pub trait SURFConst: crate::features2d::Feature2DTraitConst {
 
 	#[inline]
 	fn get_hessian_threshold(&self) -> Result<f64> {
+		crate::core::validity::check("SURF", self.as_raw_SURF())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_SURF_getHessianThreshold_const(self.as_raw_SURF(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_SURF_getHessianThreshold_const(self.as_raw_SURF(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2481,8 +3382,9 @@ pub trait SURFConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_n_octaves(&self) -> Result<i32> {
+		crate::core::validity::check("SURF", self.as_raw_SURF())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_SURF_getNOctaves_const(self.as_raw_SURF(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_SURF_getNOctaves_const(self.as_raw_SURF(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2490,8 +3392,9 @@ pub trait SURFConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_n_octave_layers(&self) -> Result<i32> {
+		crate::core::validity::check("SURF", self.as_raw_SURF())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_SURF_getNOctaveLayers_const(self.as_raw_SURF(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_SURF_getNOctaveLayers_const(self.as_raw_SURF(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2499,8 +3402,9 @@ pub trait SURFConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_extended(&self) -> Result<bool> {
+		crate::core::validity::check("SURF", self.as_raw_SURF())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_SURF_getExtended_const(self.as_raw_SURF(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_SURF_getExtended_const(self.as_raw_SURF(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2508,8 +3412,9 @@ pub trait SURFConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_upright(&self) -> Result<bool> {
+		crate::core::validity::check("SURF", self.as_raw_SURF())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_SURF_getUpright_const(self.as_raw_SURF(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_SURF_getUpright_const(self.as_raw_SURF(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2522,8 +3427,9 @@ pub trait SURF: crate::features2d::Feature2DTrait + crate::xfeatures2d::SURFCons
 
 	#[inline]
 	fn set_hessian_threshold(&mut self, hessian_threshold: f64) -> Result<()> {
+		crate::core::validity::check("SURF", self.as_raw_mut_SURF())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_SURF_setHessianThreshold_double(self.as_raw_mut_SURF(), hessian_threshold, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_SURF_setHessianThreshold_double(self.as_raw_mut_SURF(), hessian_threshold, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2531,8 +3437,9 @@ pub trait SURF: crate::features2d::Feature2DTrait + crate::xfeatures2d::SURFCons
 	
 	#[inline]
 	fn set_n_octaves(&mut self, n_octaves: i32) -> Result<()> {
+		crate::core::validity::check("SURF", self.as_raw_mut_SURF())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_SURF_setNOctaves_int(self.as_raw_mut_SURF(), n_octaves, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_SURF_setNOctaves_int(self.as_raw_mut_SURF(), n_octaves, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2540,8 +3447,9 @@ pub trait SURF: crate::features2d::Feature2DTrait + crate::xfeatures2d::SURFCons
 	
 	#[inline]
 	fn set_n_octave_layers(&mut self, n_octave_layers: i32) -> Result<()> {
+		crate::core::validity::check("SURF", self.as_raw_mut_SURF())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_SURF_setNOctaveLayers_int(self.as_raw_mut_SURF(), n_octave_layers, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_SURF_setNOctaveLayers_int(self.as_raw_mut_SURF(), n_octave_layers, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2549,8 +3457,9 @@ pub trait SURF: crate::features2d::Feature2DTrait + crate::xfeatures2d::SURFCons
 	
 	#[inline]
 	fn set_extended(&mut self, extended: bool) -> Result<()> {
+		crate::core::validity::check("SURF", self.as_raw_mut_SURF())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_SURF_setExtended_bool(self.as_raw_mut_SURF(), extended, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_SURF_setExtended_bool(self.as_raw_mut_SURF(), extended, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2558,8 +3467,9 @@ pub trait SURF: crate::features2d::Feature2DTrait + crate::xfeatures2d::SURFCons
 	
 	#[inline]
 	fn set_upright(&mut self, upright: bool) -> Result<()> {
+		crate::core::validity::check("SURF", self.as_raw_mut_SURF())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_SURF_setUpright_bool(self.as_raw_mut_SURF(), upright, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_SURF_setUpright_bool(self.as_raw_mut_SURF(), upright, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2586,7 +3496,7 @@ impl dyn SURF + '_ {
 	#[inline]
 	pub fn create(hessian_threshold: f64, n_octaves: i32, n_octave_layers: i32, extended: bool, upright: bool) -> Result<core::Ptr<dyn crate::xfeatures2d::SURF>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_SURF_create_double_int_int_bool_bool(hessian_threshold, n_octaves, n_octave_layers, extended, upright, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_SURF_create_double_int_int_bool_bool(hessian_threshold, n_octaves, n_octave_layers, extended, upright, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::xfeatures2d::SURF>::opencv_from_extern(ret) };
@@ -2594,6 +3504,72 @@ impl dyn SURF + '_ {
 	}
 	
 }
+
+/// Builder for `<dyn SURF>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct SURF_CreateBuilder {
+	hessian_threshold: f64,
+	n_octaves: i32,
+	n_octave_layers: i32,
+	extended: bool,
+	upright: bool,
+}
+
+impl SURF_CreateBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { hessian_threshold: 100., n_octaves: 4, n_octave_layers: 3, extended: false, upright: false }
+	}
+	
+	/// C++ default: `100`
+	#[inline]
+	pub fn with_hessian_threshold(mut self, hessian_threshold: f64) -> Self {
+		self.hessian_threshold = hessian_threshold;
+		self
+	}
+	
+	/// C++ default: `4`
+	#[inline]
+	pub fn with_n_octaves(mut self, n_octaves: i32) -> Self {
+		self.n_octaves = n_octaves;
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_n_octave_layers(mut self, n_octave_layers: i32) -> Self {
+		self.n_octave_layers = n_octave_layers;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_extended(mut self, extended: bool) -> Self {
+		self.extended = extended;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_upright(mut self, upright: bool) -> Self {
+		self.upright = upright;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::xfeatures2d::SURF>> {
+		<dyn SURF>::create(self.hessian_threshold, self.n_octaves, self.n_octave_layers, self.extended, self.upright)
+	}
+}
+
+impl Default for SURF_CreateBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
 /// The class implements the keypoint detector introduced by [Agrawal08](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_Agrawal08), synonym of StarDetector. :
 pub trait StarDetectorTraitConst: crate::features2d::Feature2DTraitConst {
 	fn as_raw_StarDetector(&self) -> *const c_void;
@@ -2615,7 +3591,10 @@ opencv_type_boxed! { StarDetector }
 impl Drop for StarDetector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_StarDetector_delete(instance: *mut c_void); }
-		unsafe { cv_StarDetector_delete(self.as_raw_mut_StarDetector()) };
+		let ptr = self.as_raw_mut_StarDetector();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_StarDetector_delete(ptr) };
 	}
 }
 
@@ -2657,7 +3636,7 @@ impl StarDetector {
 	#[inline]
 	pub fn create(max_size: i32, response_threshold: i32, line_threshold_projected: i32, line_threshold_binarized: i32, suppress_nonmax_size: i32) -> Result<core::Ptr<crate::xfeatures2d::StarDetector>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_StarDetector_create_int_int_int_int_int(max_size, response_threshold, line_threshold_projected, line_threshold_binarized, suppress_nonmax_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_StarDetector_create_int_int_int_int_int(max_size, response_threshold, line_threshold_projected, line_threshold_binarized, suppress_nonmax_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::xfeatures2d::StarDetector>::opencv_from_extern(ret) };
@@ -2666,6 +3645,72 @@ impl StarDetector {
 	
 }
 
+/// Builder for `StarDetector::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct StarDetector_CreateBuilder {
+	max_size: i32,
+	response_threshold: i32,
+	line_threshold_projected: i32,
+	line_threshold_binarized: i32,
+	suppress_nonmax_size: i32,
+}
+
+impl StarDetector_CreateBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { max_size: 45, response_threshold: 30, line_threshold_projected: 10, line_threshold_binarized: 8, suppress_nonmax_size: 5 }
+	}
+	
+	/// C++ default: `45`
+	#[inline]
+	pub fn with_max_size(mut self, max_size: i32) -> Self {
+		self.max_size = max_size;
+		self
+	}
+	
+	/// C++ default: `30`
+	#[inline]
+	pub fn with_response_threshold(mut self, response_threshold: i32) -> Self {
+		self.response_threshold = response_threshold;
+		self
+	}
+	
+	/// C++ default: `10`
+	#[inline]
+	pub fn with_line_threshold_projected(mut self, line_threshold_projected: i32) -> Self {
+		self.line_threshold_projected = line_threshold_projected;
+		self
+	}
+	
+	/// C++ default: `8`
+	#[inline]
+	pub fn with_line_threshold_binarized(mut self, line_threshold_binarized: i32) -> Self {
+		self.line_threshold_binarized = line_threshold_binarized;
+		self
+	}
+	
+	/// C++ default: `5`
+	#[inline]
+	pub fn with_suppress_nonmax_size(mut self, suppress_nonmax_size: i32) -> Self {
+		self.suppress_nonmax_size = suppress_nonmax_size;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<crate::xfeatures2d::StarDetector>> {
+		StarDetector::create(self.max_size, self.response_threshold, self.line_threshold_projected, self.line_threshold_binarized, self.suppress_nonmax_size)
+	}
+}
+
+impl Default for StarDetector_CreateBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 boxed_cast_base! { StarDetector, core::Algorithm, cv_StarDetector_to_Algorithm }
 
 boxed_cast_base! { StarDetector, crate::features2d::Feature2D, cv_StarDetector_to_Feature2D }
@@ -2691,8 +3736,9 @@ pub trait TBMRConst: crate::xfeatures2d::AffineFeature2DConst {
 
 	#[inline]
 	fn get_min_area(&self) -> Result<i32> {
+		crate::core::validity::check("TBMR", self.as_raw_TBMR())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_TBMR_getMinArea_const(self.as_raw_TBMR(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_TBMR_getMinArea_const(self.as_raw_TBMR(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2700,8 +3746,9 @@ pub trait TBMRConst: crate::xfeatures2d::AffineFeature2DConst {
 	
 	#[inline]
 	fn get_max_area_relative(&self) -> Result<f32> {
+		crate::core::validity::check("TBMR", self.as_raw_TBMR())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_TBMR_getMaxAreaRelative_const(self.as_raw_TBMR(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_TBMR_getMaxAreaRelative_const(self.as_raw_TBMR(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2709,8 +3756,9 @@ pub trait TBMRConst: crate::xfeatures2d::AffineFeature2DConst {
 	
 	#[inline]
 	fn get_scale_factor(&self) -> Result<f32> {
+		crate::core::validity::check("TBMR", self.as_raw_TBMR())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_TBMR_getScaleFactor_const(self.as_raw_TBMR(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_TBMR_getScaleFactor_const(self.as_raw_TBMR(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2718,8 +3766,9 @@ pub trait TBMRConst: crate::xfeatures2d::AffineFeature2DConst {
 	
 	#[inline]
 	fn get_n_scales(&self) -> Result<i32> {
+		crate::core::validity::check("TBMR", self.as_raw_TBMR())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_TBMR_getNScales_const(self.as_raw_TBMR(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_TBMR_getNScales_const(self.as_raw_TBMR(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2732,8 +3781,9 @@ pub trait TBMR: crate::xfeatures2d::AffineFeature2D + crate::xfeatures2d::TBMRCo
 
 	#[inline]
 	fn set_min_area(&mut self, min_area: i32) -> Result<()> {
+		crate::core::validity::check("TBMR", self.as_raw_mut_TBMR())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_TBMR_setMinArea_int(self.as_raw_mut_TBMR(), min_area, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_TBMR_setMinArea_int(self.as_raw_mut_TBMR(), min_area, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2741,8 +3791,9 @@ pub trait TBMR: crate::xfeatures2d::AffineFeature2D + crate::xfeatures2d::TBMRCo
 	
 	#[inline]
 	fn set_max_area_relative(&mut self, max_area: f32) -> Result<()> {
+		crate::core::validity::check("TBMR", self.as_raw_mut_TBMR())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_TBMR_setMaxAreaRelative_float(self.as_raw_mut_TBMR(), max_area, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_TBMR_setMaxAreaRelative_float(self.as_raw_mut_TBMR(), max_area, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2750,8 +3801,9 @@ pub trait TBMR: crate::xfeatures2d::AffineFeature2D + crate::xfeatures2d::TBMRCo
 	
 	#[inline]
 	fn set_scale_factor(&mut self, scale_factor: f32) -> Result<()> {
+		crate::core::validity::check("TBMR", self.as_raw_mut_TBMR())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_TBMR_setScaleFactor_float(self.as_raw_mut_TBMR(), scale_factor, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_TBMR_setScaleFactor_float(self.as_raw_mut_TBMR(), scale_factor, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2759,8 +3811,9 @@ pub trait TBMR: crate::xfeatures2d::AffineFeature2D + crate::xfeatures2d::TBMRCo
 	
 	#[inline]
 	fn set_n_scales(&mut self, n_scales: i32) -> Result<()> {
+		crate::core::validity::check("TBMR", self.as_raw_mut_TBMR())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_TBMR_setNScales_int(self.as_raw_mut_TBMR(), n_scales, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_TBMR_setNScales_int(self.as_raw_mut_TBMR(), n_scales, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2777,7 +3830,7 @@ impl dyn TBMR + '_ {
 	#[inline]
 	pub fn create(min_area: i32, max_area_relative: f32, scale_factor: f32, n_scales: i32) -> Result<core::Ptr<dyn crate::xfeatures2d::TBMR>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_TBMR_create_int_float_float_int(min_area, max_area_relative, scale_factor, n_scales, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_TBMR_create_int_float_float_int(min_area, max_area_relative, scale_factor, n_scales, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::xfeatures2d::TBMR>::opencv_from_extern(ret) };
@@ -2785,6 +3838,64 @@ impl dyn TBMR + '_ {
 	}
 	
 }
+
+/// Builder for `<dyn TBMR>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct TBMR_CreateBuilder {
+	min_area: i32,
+	max_area_relative: f32,
+	scale_factor: f32,
+	n_scales: i32,
+}
+
+impl TBMR_CreateBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { min_area: 60, max_area_relative: 0.01, scale_factor: 1.25, n_scales: -1 }
+	}
+	
+	/// C++ default: `60`
+	#[inline]
+	pub fn with_min_area(mut self, min_area: i32) -> Self {
+		self.min_area = min_area;
+		self
+	}
+	
+	/// C++ default: `0.01f`
+	#[inline]
+	pub fn with_max_area_relative(mut self, max_area_relative: f32) -> Self {
+		self.max_area_relative = max_area_relative;
+		self
+	}
+	
+	/// C++ default: `1.25f`
+	#[inline]
+	pub fn with_scale_factor(mut self, scale_factor: f32) -> Self {
+		self.scale_factor = scale_factor;
+		self
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_n_scales(mut self, n_scales: i32) -> Self {
+		self.n_scales = n_scales;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::xfeatures2d::TBMR>> {
+		<dyn TBMR>::create(self.min_area, self.max_area_relative, self.scale_factor, self.n_scales)
+	}
+}
+
+impl Default for TBMR_CreateBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
 /// Class implementing VGG (Oxford Visual Geometry Group) descriptor trained end to end
 /// using "Descriptor Learning Using Convex Optimisation" (DLCO) aparatus described in [Simonyan14](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_Simonyan14).
 /// 
@@ -2806,8 +3917,9 @@ pub trait VGGConst: crate::features2d::Feature2DTraitConst {
 
 	#[inline]
 	fn get_sigma(&self) -> Result<f32> {
+		crate::core::validity::check("VGG", self.as_raw_VGG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_VGG_getSigma_const(self.as_raw_VGG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_VGG_getSigma_const(self.as_raw_VGG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2815,8 +3927,9 @@ pub trait VGGConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_use_normalize_image(&self) -> Result<bool> {
+		crate::core::validity::check("VGG", self.as_raw_VGG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_VGG_getUseNormalizeImage_const(self.as_raw_VGG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_VGG_getUseNormalizeImage_const(self.as_raw_VGG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2824,8 +3937,9 @@ pub trait VGGConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_use_scale_orientation(&self) -> Result<bool> {
+		crate::core::validity::check("VGG", self.as_raw_VGG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_VGG_getUseScaleOrientation_const(self.as_raw_VGG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_VGG_getUseScaleOrientation_const(self.as_raw_VGG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2833,8 +3947,9 @@ pub trait VGGConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_scale_factor(&self) -> Result<f32> {
+		crate::core::validity::check("VGG", self.as_raw_VGG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_VGG_getScaleFactor_const(self.as_raw_VGG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_VGG_getScaleFactor_const(self.as_raw_VGG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2842,8 +3957,9 @@ pub trait VGGConst: crate::features2d::Feature2DTraitConst {
 	
 	#[inline]
 	fn get_use_normalize_descriptor(&self) -> Result<bool> {
+		crate::core::validity::check("VGG", self.as_raw_VGG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_VGG_getUseNormalizeDescriptor_const(self.as_raw_VGG(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_VGG_getUseNormalizeDescriptor_const(self.as_raw_VGG(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2856,8 +3972,9 @@ pub trait VGG: crate::features2d::Feature2DTrait + crate::xfeatures2d::VGGConst
 
 	#[inline]
 	fn set_sigma(&mut self, isigma: f32) -> Result<()> {
+		crate::core::validity::check("VGG", self.as_raw_mut_VGG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_VGG_setSigma_const_float(self.as_raw_mut_VGG(), isigma, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_VGG_setSigma_const_float(self.as_raw_mut_VGG(), isigma, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2865,8 +3982,9 @@ pub trait VGG: crate::features2d::Feature2DTrait + crate::xfeatures2d::VGGConst
 	
 	#[inline]
 	fn set_use_normalize_image(&mut self, img_normalize: bool) -> Result<()> {
+		crate::core::validity::check("VGG", self.as_raw_mut_VGG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_VGG_setUseNormalizeImage_const_bool(self.as_raw_mut_VGG(), img_normalize, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_VGG_setUseNormalizeImage_const_bool(self.as_raw_mut_VGG(), img_normalize, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2874,8 +3992,9 @@ pub trait VGG: crate::features2d::Feature2DTrait + crate::xfeatures2d::VGGConst
 	
 	#[inline]
 	fn set_use_scale_orientation(&mut self, use_scale_orientation: bool) -> Result<()> {
+		crate::core::validity::check("VGG", self.as_raw_mut_VGG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_VGG_setUseScaleOrientation_const_bool(self.as_raw_mut_VGG(), use_scale_orientation, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_VGG_setUseScaleOrientation_const_bool(self.as_raw_mut_VGG(), use_scale_orientation, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2883,8 +4002,9 @@ pub trait VGG: crate::features2d::Feature2DTrait + crate::xfeatures2d::VGGConst
 	
 	#[inline]
 	fn set_scale_factor(&mut self, scale_factor: f32) -> Result<()> {
+		crate::core::validity::check("VGG", self.as_raw_mut_VGG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_VGG_setScaleFactor_const_float(self.as_raw_mut_VGG(), scale_factor, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_VGG_setScaleFactor_const_float(self.as_raw_mut_VGG(), scale_factor, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2892,8 +4012,9 @@ pub trait VGG: crate::features2d::Feature2DTrait + crate::xfeatures2d::VGGConst
 	
 	#[inline]
 	fn set_use_normalize_descriptor(&mut self, dsc_normalize: bool) -> Result<()> {
+		crate::core::validity::check("VGG", self.as_raw_mut_VGG())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_VGG_setUseNormalizeDescriptor_const_bool(self.as_raw_mut_VGG(), dsc_normalize, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_VGG_setUseNormalizeDescriptor_const_bool(self.as_raw_mut_VGG(), dsc_normalize, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2912,11 +4033,71 @@ impl dyn VGG + '_ {
 	#[inline]
 	pub fn create(desc: i32, isigma: f32, img_normalize: bool, use_scale_orientation: bool, scale_factor: f32, dsc_normalize: bool) -> Result<core::Ptr<dyn crate::xfeatures2d::VGG>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xfeatures2d_VGG_create_int_float_bool_bool_float_bool(desc, isigma, img_normalize, use_scale_orientation, scale_factor, dsc_normalize, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xfeatures2d_VGG_create_int_float_bool_bool_float_bool(desc, isigma, img_normalize, use_scale_orientation, scale_factor, dsc_normalize, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::xfeatures2d::VGG>::opencv_from_extern(ret) };
 		Ok(ret)
 	}
 	
+}
+
+/// Builder for `<dyn VGG>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct VGG_CreateBuilder {
+	desc: i32,
+	isigma: f32,
+	img_normalize: bool,
+	use_scale_orientation: bool,
+	scale_factor: f32,
+	dsc_normalize: bool,
+}
+
+impl VGG_CreateBuilder {
+	#[inline]
+	pub fn new(desc: i32) -> Self {
+		Self { desc, isigma: 1.4, img_normalize: true, use_scale_orientation: true, scale_factor: 6.25, dsc_normalize: false }
+	}
+	
+	/// C++ default: `1.4f`
+	#[inline]
+	pub fn with_isigma(mut self, isigma: f32) -> Self {
+		self.isigma = isigma;
+		self
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_img_normalize(mut self, img_normalize: bool) -> Self {
+		self.img_normalize = img_normalize;
+		self
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_use_scale_orientation(mut self, use_scale_orientation: bool) -> Self {
+		self.use_scale_orientation = use_scale_orientation;
+		self
+	}
+	
+	/// C++ default: `6.25f`
+	#[inline]
+	pub fn with_scale_factor(mut self, scale_factor: f32) -> Self {
+		self.scale_factor = scale_factor;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_dsc_normalize(mut self, dsc_normalize: bool) -> Self {
+		self.dsc_normalize = dsc_normalize;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::xfeatures2d::VGG>> {
+		<dyn VGG>::create(self.desc, self.isigma, self.img_normalize, self.use_scale_orientation, self.scale_factor, self.dsc_normalize)
+	}
 }
\ No newline at end of file
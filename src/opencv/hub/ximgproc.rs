@@ -175,7 +175,7 @@ opencv_type_enum! { crate::ximgproc::HoughDeskewOption }
 ///          two operands. Formally, a binary operation @f$ f @f$ on a set @f$ S @f$
 ///          is a binary relation that maps elements of the Cartesian product
 ///          @f$ S \times S @f$ to @f$ S @f$:
-///           @f[ f: S \times S \to S @f]
+///           @f\[ f: S \times S \to S @f\]
 /// @ingroup MinUtils_MathOper
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -264,12 +264,57 @@ opencv_type_enum! { crate::ximgproc::WMFWeightType }
 #[inline]
 pub fn bright_edges(_original: &mut core::Mat, _edgeview: &mut core::Mat, contrast: i32, shortrange: i32, longrange: i32) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_BrightEdges_MatR_MatR_int_int_int(_original.as_raw_mut_Mat(), _edgeview.as_raw_mut_Mat(), contrast, shortrange, longrange, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_BrightEdges_MatR_MatR_int_int_int(_original.as_raw_mut_Mat(), _edgeview.as_raw_mut_Mat(), contrast, shortrange, longrange, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `bright_edges()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct BrightEdgesBuilder<'a> {
+	_original: &'a mut core::Mat,
+	_edgeview: &'a mut core::Mat,
+	contrast: i32,
+	shortrange: i32,
+	longrange: i32,
+}
+
+impl<'a> BrightEdgesBuilder<'a> {
+	#[inline]
+	pub fn new(_original: &'a mut core::Mat, _edgeview: &'a mut core::Mat) -> Self {
+		Self { _original, _edgeview, contrast: 1, shortrange: 3, longrange: 9 }
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_contrast(mut self, contrast: i32) -> Self {
+		self.contrast = contrast;
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_shortrange(mut self, shortrange: i32) -> Self {
+		self.shortrange = shortrange;
+		self
+	}
+	
+	/// C++ default: `9`
+	#[inline]
+	pub fn with_longrange(mut self, longrange: i32) -> Self {
+		self.longrange = longrange;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		bright_edges(self._original, self._edgeview, self.contrast, self.shortrange, self.longrange)
+	}
+}
+
 ///   Calculates 2D Fast Hough transform of an image.
 /// ## Parameters
 /// * dst: The destination image, result of transformation.
@@ -291,7 +336,7 @@ pub fn fast_hough_transform(src: &dyn core::ToInputArray, dst: &mut dyn core::To
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_FastHoughTransform_const__InputArrayR_const__OutputArrayR_int_int_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), dst_mat_depth, angle_range, op, make_skew, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_FastHoughTransform_const__InputArrayR_const__OutputArrayR_int_int_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), dst_mat_depth, angle_range, op, make_skew, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -311,7 +356,7 @@ pub fn gradient_deriche_x(op: &dyn core::ToInputArray, dst: &mut dyn core::ToOut
 	input_array_arg!(op);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_GradientDericheX_const__InputArrayR_const__OutputArrayR_double_double(op.as_raw__InputArray(), dst.as_raw__OutputArray(), alpha, omega, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_GradientDericheX_const__InputArrayR_const__OutputArrayR_double_double(op.as_raw__InputArray(), dst.as_raw__OutputArray(), alpha, omega, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -331,7 +376,7 @@ pub fn gradient_deriche_y(op: &dyn core::ToInputArray, dst: &mut dyn core::ToOut
 	input_array_arg!(op);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_GradientDericheY_const__InputArrayR_const__OutputArrayR_double_double(op.as_raw__InputArray(), dst.as_raw__OutputArray(), alpha, omega, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_GradientDericheY_const__InputArrayR_const__OutputArrayR_double_double(op.as_raw__InputArray(), dst.as_raw__OutputArray(), alpha, omega, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -342,7 +387,7 @@ pub fn gradient_paillou_x(op: &dyn core::ToInputArray, _dst: &mut dyn core::ToOu
 	input_array_arg!(op);
 	output_array_arg!(_dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_GradientPaillouX_const__InputArrayR_const__OutputArrayR_double_double(op.as_raw__InputArray(), _dst.as_raw__OutputArray(), alpha, omega, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_GradientPaillouX_const__InputArrayR_const__OutputArrayR_double_double(op.as_raw__InputArray(), _dst.as_raw__OutputArray(), alpha, omega, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -358,13 +403,13 @@ pub fn gradient_paillou_x(op: &dyn core::ToInputArray, _dst: &mut dyn core::ToOu
 /// * omega: double see paper
 /// * alpha: double see paper
 /// ## See also
-/// GradientPaillouX, GradientPaillouY
+/// [gradient_paillou_x](crate::ximgproc::gradient_paillou_x), [gradient_paillou_y](crate::ximgproc::gradient_paillou_y)
 #[inline]
 pub fn gradient_paillou_y(op: &dyn core::ToInputArray, _dst: &mut dyn core::ToOutputArray, alpha: f64, omega: f64) -> Result<()> {
 	input_array_arg!(op);
 	output_array_arg!(_dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_GradientPaillouY_const__InputArrayR_const__OutputArrayR_double_double(op.as_raw__InputArray(), _dst.as_raw__OutputArray(), alpha, omega, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_GradientPaillouY_const__InputArrayR_const__OutputArrayR_double_double(op.as_raw__InputArray(), _dst.as_raw__OutputArray(), alpha, omega, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -377,7 +422,7 @@ pub fn gradient_paillou_y(op: &dyn core::ToInputArray, _dst: &mut dyn core::ToOu
 /// * angleRange: The part of Hough space where point is situated, see cv::AngleRangeOption
 /// * makeSkew: Specifies to do or not to do image skewing, see cv::HoughDeskewOption
 /// * rules: Specifies strictness of line segment calculating, see cv::RulesOption
-/// @retval  [Vec4i]     Coordinates of line segment corresponded by point in Hough space.
+/// @retval  \[Vec4i\]     Coordinates of line segment corresponded by point in Hough space.
 /// @remarks If rules parameter set to RO_STRICT
 ///        then returned line cut along the border of source image.
 /// @remarks If rules parameter set to RO_WEAK then in case of point, which belongs
@@ -393,7 +438,7 @@ pub fn gradient_paillou_y(op: &dyn core::ToInputArray, _dst: &mut dyn core::ToOu
 pub fn hough_point2_line(hough_point: core::Point, src_img_info: &dyn core::ToInputArray, angle_range: i32, make_skew: i32, rules: i32) -> Result<core::Vec4i> {
 	input_array_arg!(src_img_info);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_HoughPoint2Line_const_PointR_const__InputArrayR_int_int_int(&hough_point, src_img_info.as_raw__InputArray(), angle_range, make_skew, rules, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_HoughPoint2Line_const_PointR_const__InputArrayR_int_int_int(&hough_point, src_img_info.as_raw__InputArray(), angle_range, make_skew, rules, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -402,9 +447,9 @@ pub fn hough_point2_line(hough_point: core::Point, src_img_info: &dyn core::ToIn
 ///   Calculates an affine transformation that normalize given image using Pei&Lin Normalization.
 /// 
 /// Assume given image ![inline formula](https://latex.codecogs.com/png.latex?I%3DT%28%5Cbar%7BI%7D%29) where ![inline formula](https://latex.codecogs.com/png.latex?%5Cbar%7BI%7D) is a normalized image and ![inline formula](https://latex.codecogs.com/png.latex?T) is an affine transformation distorting this image by translation, rotation, scaling and skew.
-/// The function returns an affine transformation matrix corresponding to the transformation ![inline formula](https://latex.codecogs.com/png.latex?T%5E%7B%2D1%7D) described in [PeiLin95].
+/// The function returns an affine transformation matrix corresponding to the transformation ![inline formula](https://latex.codecogs.com/png.latex?T%5E%7B%2D1%7D) described in \[PeiLin95\].
 /// For more details about this implementation, please see
-/// [PeiLin95] Soo-Chang Pei and Chao-Nan Lin. Image normalization for pattern recognition. Image and Vision Computing, Vol. 13, N.10, pp. 711-723, 1995.
+/// \[PeiLin95\] Soo-Chang Pei and Chao-Nan Lin. Image normalization for pattern recognition. Image and Vision Computing, Vol. 13, N.10, pp. 711-723, 1995.
 /// 
 /// ## Parameters
 /// * I: Given transformed image.
@@ -414,7 +459,7 @@ pub fn hough_point2_line(hough_point: core::Point, src_img_info: &dyn core::ToIn
 pub fn pei_lin_normalization(i: &dyn core::ToInputArray) -> Result<core::Matx23d> {
 	input_array_arg!(i);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_PeiLinNormalization_const__InputArrayR(i.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_PeiLinNormalization_const__InputArrayR(i.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -423,9 +468,9 @@ pub fn pei_lin_normalization(i: &dyn core::ToInputArray) -> Result<core::Matx23d
 ///   Calculates an affine transformation that normalize given image using Pei&Lin Normalization.
 /// 
 /// Assume given image ![inline formula](https://latex.codecogs.com/png.latex?I%3DT%28%5Cbar%7BI%7D%29) where ![inline formula](https://latex.codecogs.com/png.latex?%5Cbar%7BI%7D) is a normalized image and ![inline formula](https://latex.codecogs.com/png.latex?T) is an affine transformation distorting this image by translation, rotation, scaling and skew.
-/// The function returns an affine transformation matrix corresponding to the transformation ![inline formula](https://latex.codecogs.com/png.latex?T%5E%7B%2D1%7D) described in [PeiLin95].
+/// The function returns an affine transformation matrix corresponding to the transformation ![inline formula](https://latex.codecogs.com/png.latex?T%5E%7B%2D1%7D) described in \[PeiLin95\].
 /// For more details about this implementation, please see
-/// [PeiLin95] Soo-Chang Pei and Chao-Nan Lin. Image normalization for pattern recognition. Image and Vision Computing, Vol. 13, N.10, pp. 711-723, 1995.
+/// \[PeiLin95\] Soo-Chang Pei and Chao-Nan Lin. Image normalization for pattern recognition. Image and Vision Computing, Vol. 13, N.10, pp. 711-723, 1995.
 /// 
 /// ## Parameters
 /// * I: Given transformed image.
@@ -438,7 +483,7 @@ pub fn pei_lin_normalization_1(i: &dyn core::ToInputArray, t: &mut dyn core::ToO
 	input_array_arg!(i);
 	output_array_arg!(t);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_PeiLinNormalization_const__InputArrayR_const__OutputArrayR(i.as_raw__InputArray(), t.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_PeiLinNormalization_const__InputArrayR_const__OutputArrayR(i.as_raw__InputArray(), t.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -473,12 +518,73 @@ pub fn radon_transform(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutpu
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_RadonTransform_const__InputArrayR_const__OutputArrayR_double_double_double_bool_bool(src.as_raw__InputArray(), dst.as_raw__OutputArray(), theta, start_angle, end_angle, crop, norm, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_RadonTransform_const__InputArrayR_const__OutputArrayR_double_double_double_bool_bool(src.as_raw__InputArray(), dst.as_raw__OutputArray(), theta, start_angle, end_angle, crop, norm, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `radon_transform()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct RadonTransformBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	theta: f64,
+	start_angle: f64,
+	end_angle: f64,
+	crop: bool,
+	norm: bool,
+}
+
+impl<'a> RadonTransformBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { src, dst, theta: 1., start_angle: 0., end_angle: 180., crop: false, norm: false }
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_theta(mut self, theta: f64) -> Self {
+		self.theta = theta;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_start_angle(mut self, start_angle: f64) -> Self {
+		self.start_angle = start_angle;
+		self
+	}
+	
+	/// C++ default: `180`
+	#[inline]
+	pub fn with_end_angle(mut self, end_angle: f64) -> Self {
+		self.end_angle = end_angle;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_crop(mut self, crop: bool) -> Self {
+		self.crop = crop;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_norm(mut self, norm: bool) -> Self {
+		self.norm = norm;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		radon_transform(self.src, self.dst, self.theta, self.start_angle, self.end_angle, self.crop, self.norm)
+	}
+}
+
 /// Simple one-line Adaptive Manifold Filter call.
 /// 
 /// ## Parameters
@@ -497,8 +603,8 @@ pub fn radon_transform(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutpu
 /// original paper.
 /// 
 /// 
-/// Note: Joint images with CV_8U and CV_16U depth converted to images with CV_32F depth and [0; 1]
-/// color range before processing. Hence color space sigma sigma_r must be in [0; 1] range, unlike same
+/// Note: Joint images with CV_8U and CV_16U depth converted to images with CV_32F depth and \[0; 1\]
+/// color range before processing. Hence color space sigma sigma_r must be in \[0; 1\] range, unlike same
 /// sigmas in bilateralFilter and dtFilter functions. see also: bilateralFilter, dtFilter, guidedFilter
 /// 
 /// ## C++ default parameters
@@ -509,12 +615,44 @@ pub fn am_filter(joint: &dyn core::ToInputArray, src: &dyn core::ToInputArray, d
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_amFilter_const__InputArrayR_const__InputArrayR_const__OutputArrayR_double_double_bool(joint.as_raw__InputArray(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), sigma_s, sigma_r, adjust_outliers, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_amFilter_const__InputArrayR_const__InputArrayR_const__OutputArrayR_double_double_bool(joint.as_raw__InputArray(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), sigma_s, sigma_r, adjust_outliers, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `am_filter()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct AmFilterBuilder<'a> {
+	joint: &'a dyn core::ToInputArray,
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	sigma_s: f64,
+	sigma_r: f64,
+	adjust_outliers: bool,
+}
+
+impl<'a> AmFilterBuilder<'a> {
+	#[inline]
+	pub fn new(joint: &'a dyn core::ToInputArray, src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, sigma_s: f64, sigma_r: f64) -> Self {
+		Self { joint, src, dst, sigma_s, sigma_r, adjust_outliers: false }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_adjust_outliers(mut self, adjust_outliers: bool) -> Self {
+		self.adjust_outliers = adjust_outliers;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		am_filter(self.joint, self.src, self.dst, self.sigma_s, self.sigma_r, self.adjust_outliers)
+	}
+}
+
 /// Performs anisotropic diffusion on an image.
 /// 
 /// The function applies Perona-Malik anisotropic diffusion to an image. This is the solution to the partial differential equation:
@@ -540,7 +678,7 @@ pub fn anisotropic_diffusion(src: &dyn core::ToInputArray, dst: &mut dyn core::T
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_anisotropicDiffusion_const__InputArrayR_const__OutputArrayR_float_float_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), alpha, k, niters, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_anisotropicDiffusion_const__InputArrayR_const__OutputArrayR_float_float_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), alpha, k, niters, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -564,7 +702,7 @@ pub fn anisotropic_diffusion(src: &dyn core::ToInputArray, dst: &mut dyn core::T
 /// * sigmaAvg: Range blur parameter for texture blurring. Larger value makes result to be more blurred. When the
 /// value is negative, it is automatically calculated as described in the paper.
 /// ## See also
-/// rollingGuidanceFilter, bilateralFilter
+/// [rolling_guidance_filter](crate::ximgproc::rolling_guidance_filter), `bilateral_filter`
 /// 
 /// ## C++ default parameters
 /// * fr: 3
@@ -576,12 +714,65 @@ pub fn bilateral_texture_filter(src: &dyn core::ToInputArray, dst: &mut dyn core
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_bilateralTextureFilter_const__InputArrayR_const__OutputArrayR_int_int_double_double(src.as_raw__InputArray(), dst.as_raw__OutputArray(), fr, num_iter, sigma_alpha, sigma_avg, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_bilateralTextureFilter_const__InputArrayR_const__OutputArrayR_int_int_double_double(src.as_raw__InputArray(), dst.as_raw__OutputArray(), fr, num_iter, sigma_alpha, sigma_avg, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `bilateral_texture_filter()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct BilateralTextureFilterBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	fr: i32,
+	num_iter: i32,
+	sigma_alpha: f64,
+	sigma_avg: f64,
+}
+
+impl<'a> BilateralTextureFilterBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { src, dst, fr: 3, num_iter: 1, sigma_alpha: -1., sigma_avg: -1. }
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_fr(mut self, fr: i32) -> Self {
+		self.fr = fr;
+		self
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_num_iter(mut self, num_iter: i32) -> Self {
+		self.num_iter = num_iter;
+		self
+	}
+	
+	/// C++ default: `-1.`
+	#[inline]
+	pub fn with_sigma_alpha(mut self, sigma_alpha: f64) -> Self {
+		self.sigma_alpha = sigma_alpha;
+		self
+	}
+	
+	/// C++ default: `-1.`
+	#[inline]
+	pub fn with_sigma_avg(mut self, sigma_avg: f64) -> Self {
+		self.sigma_avg = sigma_avg;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		bilateral_texture_filter(self.src, self.dst, self.fr, self.num_iter, self.sigma_alpha, self.sigma_avg)
+	}
+}
+
 ///    Compares a color template against overlapped color image regions.
 /// 
 /// ## Parameters
@@ -594,7 +785,7 @@ pub fn color_match_template(img: &dyn core::ToInputArray, templ: &dyn core::ToIn
 	input_array_arg!(templ);
 	output_array_arg!(result);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_colorMatchTemplate_const__InputArrayR_const__InputArrayR_const__OutputArrayR(img.as_raw__InputArray(), templ.as_raw__InputArray(), result.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_colorMatchTemplate_const__InputArrayR_const__InputArrayR_const__OutputArrayR(img.as_raw__InputArray(), templ.as_raw__InputArray(), result.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -621,7 +812,7 @@ pub fn compute_bad_pixel_percent(gt: &dyn core::ToInputArray, src: &dyn core::To
 	input_array_arg!(gt);
 	input_array_arg!(src);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_computeBadPixelPercent_const__InputArrayR_const__InputArrayR_Rect_int(gt.as_raw__InputArray(), src.as_raw__InputArray(), roi.opencv_as_extern(), thresh, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_computeBadPixelPercent_const__InputArrayR_const__InputArrayR_Rect_int(gt.as_raw__InputArray(), src.as_raw__InputArray(), roi.opencv_as_extern(), thresh, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -642,7 +833,7 @@ pub fn compute_mse(gt: &dyn core::ToInputArray, src: &dyn core::ToInputArray, ro
 	input_array_arg!(gt);
 	input_array_arg!(src);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_computeMSE_const__InputArrayR_const__InputArrayR_Rect(gt.as_raw__InputArray(), src.as_raw__InputArray(), roi.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_computeMSE_const__InputArrayR_const__InputArrayR_Rect(gt.as_raw__InputArray(), src.as_raw__InputArray(), roi.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -659,7 +850,7 @@ pub fn contour_sampling(src: &dyn core::ToInputArray, out: &mut dyn core::ToOutp
 	input_array_arg!(src);
 	output_array_arg!(out);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_contourSampling_const__InputArrayR_const__OutputArrayR_int(src.as_raw__InputArray(), out.as_raw__OutputArray(), nb_elt, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_contourSampling_const__InputArrayR_const__OutputArrayR_int(src.as_raw__InputArray(), out.as_raw__OutputArray(), nb_elt, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -684,7 +875,7 @@ pub fn covariance_estimation(src: &dyn core::ToInputArray, dst: &mut dyn core::T
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_covarianceEstimation_const__InputArrayR_const__OutputArrayR_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), window_rows, window_cols, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_covarianceEstimation_const__InputArrayR_const__OutputArrayR_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), window_rows, window_cols, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -704,8 +895,8 @@ pub fn covariance_estimation(src: &dyn core::ToInputArray, dst: &mut dyn core::T
 /// For more details about Adaptive Manifold Filter parameters, see the original article [Gastal12](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_Gastal12) .
 /// 
 /// 
-/// Note: Joint images with CV_8U and CV_16U depth converted to images with CV_32F depth and [0; 1]
-/// color range before processing. Hence color space sigma sigma_r must be in [0; 1] range, unlike same
+/// Note: Joint images with CV_8U and CV_16U depth converted to images with CV_32F depth and \[0; 1\]
+/// color range before processing. Hence color space sigma sigma_r must be in \[0; 1\] range, unlike same
 /// sigmas in bilateralFilter and dtFilter functions.
 /// 
 /// ## C++ default parameters
@@ -713,7 +904,7 @@ pub fn covariance_estimation(src: &dyn core::ToInputArray, dst: &mut dyn core::T
 #[inline]
 pub fn create_am_filter(sigma_s: f64, sigma_r: f64, adjust_outliers: bool) -> Result<core::Ptr<dyn crate::ximgproc::AdaptiveManifoldFilter>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_createAMFilter_double_double_bool(sigma_s, sigma_r, adjust_outliers, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_createAMFilter_double_double_bool(sigma_s, sigma_r, adjust_outliers, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::ximgproc::AdaptiveManifoldFilter>::opencv_from_extern(ret) };
@@ -732,7 +923,7 @@ pub fn create_am_filter(sigma_s: f64, sigma_r: f64, adjust_outliers: bool) -> Re
 #[inline]
 pub fn create_contour_fitting(ctr: i32, fd: i32) -> Result<core::Ptr<crate::ximgproc::ContourFitting>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_createContourFitting_int_int(ctr, fd, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_createContourFitting_int_int(ctr, fd, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<crate::ximgproc::ContourFitting>::opencv_from_extern(ret) };
@@ -766,7 +957,7 @@ pub fn create_contour_fitting(ctr: i32, fd: i32) -> Result<core::Ptr<crate::ximg
 pub fn create_dt_filter(guide: &dyn core::ToInputArray, sigma_spatial: f64, sigma_color: f64, mode: i32, num_iters: i32) -> Result<core::Ptr<dyn crate::ximgproc::DTFilter>> {
 	input_array_arg!(guide);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_createDTFilter_const__InputArrayR_double_double_int_int(guide.as_raw__InputArray(), sigma_spatial, sigma_color, mode, num_iters, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_createDTFilter_const__InputArrayR_double_double_int_int(guide.as_raw__InputArray(), sigma_spatial, sigma_color, mode, num_iters, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::ximgproc::DTFilter>::opencv_from_extern(ret) };
@@ -783,7 +974,7 @@ pub fn create_dt_filter(guide: &dyn core::ToInputArray, sigma_spatial: f64, sigm
 #[inline]
 pub fn create_disparity_wls_filter_generic(use_confidence: bool) -> Result<core::Ptr<dyn crate::ximgproc::DisparityWLSFilter>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_createDisparityWLSFilterGeneric_bool(use_confidence, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_createDisparityWLSFilterGeneric_bool(use_confidence, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::ximgproc::DisparityWLSFilter>::opencv_from_extern(ret) };
@@ -798,7 +989,7 @@ pub fn create_disparity_wls_filter_generic(use_confidence: bool) -> Result<core:
 #[inline]
 pub fn create_disparity_wls_filter(mut matcher_left: core::Ptr<dyn crate::calib3d::StereoMatcher>) -> Result<core::Ptr<dyn crate::ximgproc::DisparityWLSFilter>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_createDisparityWLSFilter_Ptr_StereoMatcher_(matcher_left.as_raw_mut_PtrOfStereoMatcher(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_createDisparityWLSFilter_Ptr_StereoMatcher_(matcher_left.as_raw_mut_PtrOfStereoMatcher(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::ximgproc::DisparityWLSFilter>::opencv_from_extern(ret) };
@@ -810,7 +1001,7 @@ pub fn create_disparity_wls_filter(mut matcher_left: core::Ptr<dyn crate::calib3
 #[inline]
 pub fn create_edge_aware_interpolator() -> Result<core::Ptr<dyn crate::ximgproc::EdgeAwareInterpolator>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_createEdgeAwareInterpolator(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_createEdgeAwareInterpolator(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::ximgproc::EdgeAwareInterpolator>::opencv_from_extern(ret) };
@@ -849,18 +1040,140 @@ pub fn create_edge_aware_interpolator() -> Result<core::Ptr<dyn crate::ximgproc:
 #[inline]
 pub fn create_edge_boxes(alpha: f32, beta: f32, eta: f32, min_score: f32, max_boxes: i32, edge_min_mag: f32, edge_merge_thr: f32, cluster_min_mag: f32, max_aspect_ratio: f32, min_box_area: f32, gamma: f32, kappa: f32) -> Result<core::Ptr<dyn crate::ximgproc::EdgeBoxes>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_createEdgeBoxes_float_float_float_float_int_float_float_float_float_float_float_float(alpha, beta, eta, min_score, max_boxes, edge_min_mag, edge_merge_thr, cluster_min_mag, max_aspect_ratio, min_box_area, gamma, kappa, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_createEdgeBoxes_float_float_float_float_int_float_float_float_float_float_float_float(alpha, beta, eta, min_score, max_boxes, edge_min_mag, edge_merge_thr, cluster_min_mag, max_aspect_ratio, min_box_area, gamma, kappa, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::ximgproc::EdgeBoxes>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_edge_boxes()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateEdgeBoxesBuilder {
+	alpha: f32,
+	beta: f32,
+	eta: f32,
+	min_score: f32,
+	max_boxes: i32,
+	edge_min_mag: f32,
+	edge_merge_thr: f32,
+	cluster_min_mag: f32,
+	max_aspect_ratio: f32,
+	min_box_area: f32,
+	gamma: f32,
+	kappa: f32,
+}
+
+impl CreateEdgeBoxesBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { alpha: 0.65, beta: 0.75, eta: 1., min_score: 0.01, max_boxes: 10000, edge_min_mag: 0.1, edge_merge_thr: 0.5, cluster_min_mag: 0.5, max_aspect_ratio: 3., min_box_area: 1000., gamma: 2., kappa: 1.5 }
+	}
+	
+	/// C++ default: `0.65f`
+	#[inline]
+	pub fn with_alpha(mut self, alpha: f32) -> Self {
+		self.alpha = alpha;
+		self
+	}
+	
+	/// C++ default: `0.75f`
+	#[inline]
+	pub fn with_beta(mut self, beta: f32) -> Self {
+		self.beta = beta;
+		self
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_eta(mut self, eta: f32) -> Self {
+		self.eta = eta;
+		self
+	}
+	
+	/// C++ default: `0.01f`
+	#[inline]
+	pub fn with_min_score(mut self, min_score: f32) -> Self {
+		self.min_score = min_score;
+		self
+	}
+	
+	/// C++ default: `10000`
+	#[inline]
+	pub fn with_max_boxes(mut self, max_boxes: i32) -> Self {
+		self.max_boxes = max_boxes;
+		self
+	}
+	
+	/// C++ default: `0.1f`
+	#[inline]
+	pub fn with_edge_min_mag(mut self, edge_min_mag: f32) -> Self {
+		self.edge_min_mag = edge_min_mag;
+		self
+	}
+	
+	/// C++ default: `0.5f`
+	#[inline]
+	pub fn with_edge_merge_thr(mut self, edge_merge_thr: f32) -> Self {
+		self.edge_merge_thr = edge_merge_thr;
+		self
+	}
+	
+	/// C++ default: `0.5f`
+	#[inline]
+	pub fn with_cluster_min_mag(mut self, cluster_min_mag: f32) -> Self {
+		self.cluster_min_mag = cluster_min_mag;
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_max_aspect_ratio(mut self, max_aspect_ratio: f32) -> Self {
+		self.max_aspect_ratio = max_aspect_ratio;
+		self
+	}
+	
+	/// C++ default: `1000`
+	#[inline]
+	pub fn with_min_box_area(mut self, min_box_area: f32) -> Self {
+		self.min_box_area = min_box_area;
+		self
+	}
+	
+	/// C++ default: `2`
+	#[inline]
+	pub fn with_gamma(mut self, gamma: f32) -> Self {
+		self.gamma = gamma;
+		self
+	}
+	
+	/// C++ default: `1.5f`
+	#[inline]
+	pub fn with_kappa(mut self, kappa: f32) -> Self {
+		self.kappa = kappa;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::ximgproc::EdgeBoxes>> {
+		create_edge_boxes(self.alpha, self.beta, self.eta, self.min_score, self.max_boxes, self.edge_min_mag, self.edge_merge_thr, self.cluster_min_mag, self.max_aspect_ratio, self.min_box_area, self.gamma, self.kappa)
+	}
+}
+
+impl Default for CreateEdgeBoxesBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 /// Creates a smart pointer to a EdgeDrawing object and initializes it
 #[inline]
 pub fn create_edge_drawing() -> Result<core::Ptr<dyn crate::ximgproc::EdgeDrawing>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_createEdgeDrawing(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_createEdgeDrawing(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::ximgproc::EdgeDrawing>::opencv_from_extern(ret) };
@@ -894,13 +1207,60 @@ pub fn create_edge_drawing() -> Result<core::Ptr<dyn crate::ximgproc::EdgeDrawin
 pub fn create_fast_bilateral_solver_filter(guide: &dyn core::ToInputArray, sigma_spatial: f64, sigma_luma: f64, sigma_chroma: f64, lambda: f64, num_iter: i32, max_tol: f64) -> Result<core::Ptr<dyn crate::ximgproc::FastBilateralSolverFilter>> {
 	input_array_arg!(guide);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_createFastBilateralSolverFilter_const__InputArrayR_double_double_double_double_int_double(guide.as_raw__InputArray(), sigma_spatial, sigma_luma, sigma_chroma, lambda, num_iter, max_tol, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_createFastBilateralSolverFilter_const__InputArrayR_double_double_double_double_int_double(guide.as_raw__InputArray(), sigma_spatial, sigma_luma, sigma_chroma, lambda, num_iter, max_tol, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::ximgproc::FastBilateralSolverFilter>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_fast_bilateral_solver_filter()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateFastBilateralSolverFilterBuilder<'a> {
+	guide: &'a dyn core::ToInputArray,
+	sigma_spatial: f64,
+	sigma_luma: f64,
+	sigma_chroma: f64,
+	lambda: f64,
+	num_iter: i32,
+	max_tol: f64,
+}
+
+impl<'a> CreateFastBilateralSolverFilterBuilder<'a> {
+	#[inline]
+	pub fn new(guide: &'a dyn core::ToInputArray, sigma_spatial: f64, sigma_luma: f64, sigma_chroma: f64) -> Self {
+		Self { guide, sigma_spatial, sigma_luma, sigma_chroma, lambda: 128.0, num_iter: 25, max_tol: 1e-5 }
+	}
+	
+	/// C++ default: `128.0`
+	#[inline]
+	pub fn with_lambda(mut self, lambda: f64) -> Self {
+		self.lambda = lambda;
+		self
+	}
+	
+	/// C++ default: `25`
+	#[inline]
+	pub fn with_num_iter(mut self, num_iter: i32) -> Self {
+		self.num_iter = num_iter;
+		self
+	}
+	
+	/// C++ default: `1e-5`
+	#[inline]
+	pub fn with_max_tol(mut self, max_tol: f64) -> Self {
+		self.max_tol = max_tol;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::ximgproc::FastBilateralSolverFilter>> {
+		create_fast_bilateral_solver_filter(self.guide, self.sigma_spatial, self.sigma_luma, self.sigma_chroma, self.lambda, self.num_iter, self.max_tol)
+	}
+}
+
 /// Factory method, create instance of FastGlobalSmootherFilter and execute the initialization routines.
 /// 
 /// ## Parameters
@@ -929,7 +1289,7 @@ pub fn create_fast_bilateral_solver_filter(guide: &dyn core::ToInputArray, sigma
 pub fn create_fast_global_smoother_filter(guide: &dyn core::ToInputArray, lambda: f64, sigma_color: f64, lambda_attenuation: f64, num_iter: i32) -> Result<core::Ptr<dyn crate::ximgproc::FastGlobalSmootherFilter>> {
 	input_array_arg!(guide);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_createFastGlobalSmootherFilter_const__InputArrayR_double_double_double_int(guide.as_raw__InputArray(), lambda, sigma_color, lambda_attenuation, num_iter, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_createFastGlobalSmootherFilter_const__InputArrayR_double_double_double_int(guide.as_raw__InputArray(), lambda, sigma_color, lambda_attenuation, num_iter, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::ximgproc::FastGlobalSmootherFilter>::opencv_from_extern(ret) };
@@ -958,13 +1318,87 @@ pub fn create_fast_global_smoother_filter(guide: &dyn core::ToInputArray, lambda
 #[inline]
 pub fn create_fast_line_detector(length_threshold: i32, distance_threshold: f32, canny_th1: f64, canny_th2: f64, canny_aperture_size: i32, do_merge: bool) -> Result<core::Ptr<dyn crate::ximgproc::FastLineDetector>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_createFastLineDetector_int_float_double_double_int_bool(length_threshold, distance_threshold, canny_th1, canny_th2, canny_aperture_size, do_merge, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_createFastLineDetector_int_float_double_double_int_bool(length_threshold, distance_threshold, canny_th1, canny_th2, canny_aperture_size, do_merge, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::ximgproc::FastLineDetector>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_fast_line_detector()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateFastLineDetectorBuilder {
+	length_threshold: i32,
+	distance_threshold: f32,
+	canny_th1: f64,
+	canny_th2: f64,
+	canny_aperture_size: i32,
+	do_merge: bool,
+}
+
+impl CreateFastLineDetectorBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { length_threshold: 10, distance_threshold: 1.414213562, canny_th1: 50.0, canny_th2: 50.0, canny_aperture_size: 3, do_merge: false }
+	}
+	
+	/// C++ default: `10`
+	#[inline]
+	pub fn with_length_threshold(mut self, length_threshold: i32) -> Self {
+		self.length_threshold = length_threshold;
+		self
+	}
+	
+	/// C++ default: `1.414213562f`
+	#[inline]
+	pub fn with_distance_threshold(mut self, distance_threshold: f32) -> Self {
+		self.distance_threshold = distance_threshold;
+		self
+	}
+	
+	/// C++ default: `50.0`
+	#[inline]
+	pub fn with_canny_th1(mut self, canny_th1: f64) -> Self {
+		self.canny_th1 = canny_th1;
+		self
+	}
+	
+	/// C++ default: `50.0`
+	#[inline]
+	pub fn with_canny_th2(mut self, canny_th2: f64) -> Self {
+		self.canny_th2 = canny_th2;
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_canny_aperture_size(mut self, canny_aperture_size: i32) -> Self {
+		self.canny_aperture_size = canny_aperture_size;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_do_merge(mut self, do_merge: bool) -> Self {
+		self.do_merge = do_merge;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::ximgproc::FastLineDetector>> {
+		create_fast_line_detector(self.length_threshold, self.distance_threshold, self.canny_th1, self.canny_th2, self.canny_aperture_size, self.do_merge)
+	}
+}
+
+impl Default for CreateFastLineDetectorBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 /// Factory method, create instance of GuidedFilter and produce initialization routines.
 /// 
 /// ## Parameters
@@ -981,7 +1415,7 @@ pub fn create_fast_line_detector(length_threshold: i32, distance_threshold: f32,
 pub fn create_guided_filter(guide: &dyn core::ToInputArray, radius: i32, eps: f64) -> Result<core::Ptr<dyn crate::ximgproc::GuidedFilter>> {
 	input_array_arg!(guide);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_createGuidedFilter_const__InputArrayR_int_double(guide.as_raw__InputArray(), radius, eps, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_createGuidedFilter_const__InputArrayR_int_double(guide.as_raw__InputArray(), radius, eps, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::ximgproc::GuidedFilter>::opencv_from_extern(ret) };
@@ -998,7 +1432,7 @@ pub fn create_quaternion_image(img: &dyn core::ToInputArray, qimg: &mut dyn core
 	input_array_arg!(img);
 	output_array_arg!(qimg);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_createQuaternionImage_const__InputArrayR_const__OutputArrayR(img.as_raw__InputArray(), qimg.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_createQuaternionImage_const__InputArrayR_const__OutputArrayR(img.as_raw__InputArray(), qimg.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1007,7 +1441,7 @@ pub fn create_quaternion_image(img: &dyn core::ToInputArray, qimg: &mut dyn core
 #[inline]
 pub fn create_rf_feature_getter() -> Result<core::Ptr<dyn crate::ximgproc::RFFeatureGetter>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_createRFFeatureGetter(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_createRFFeatureGetter(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::ximgproc::RFFeatureGetter>::opencv_from_extern(ret) };
@@ -1019,7 +1453,7 @@ pub fn create_rf_feature_getter() -> Result<core::Ptr<dyn crate::ximgproc::RFFea
 #[inline]
 pub fn create_ric_interpolator() -> Result<core::Ptr<dyn crate::ximgproc::RICInterpolator>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_createRICInterpolator(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_createRICInterpolator(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::ximgproc::RICInterpolator>::opencv_from_extern(ret) };
@@ -1034,7 +1468,7 @@ pub fn create_ric_interpolator() -> Result<core::Ptr<dyn crate::ximgproc::RICInt
 #[inline]
 pub fn create_right_matcher(mut matcher_left: core::Ptr<dyn crate::calib3d::StereoMatcher>) -> Result<core::Ptr<dyn crate::calib3d::StereoMatcher>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_createRightMatcher_Ptr_StereoMatcher_(matcher_left.as_raw_mut_PtrOfStereoMatcher(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_createRightMatcher_Ptr_StereoMatcher_(matcher_left.as_raw_mut_PtrOfStereoMatcher(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::calib3d::StereoMatcher>::opencv_from_extern(ret) };
@@ -1064,7 +1498,7 @@ pub fn create_right_matcher(mut matcher_left: core::Ptr<dyn crate::calib3d::Ster
 #[inline]
 pub fn create_scan_segment(image_width: i32, image_height: i32, num_superpixels: i32, slices: i32, merge_small: bool) -> Result<core::Ptr<dyn crate::ximgproc::ScanSegment>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_createScanSegment_int_int_int_int_bool(image_width, image_height, num_superpixels, slices, merge_small, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_createScanSegment_int_int_int_int_bool(image_width, image_height, num_superpixels, slices, merge_small, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::ximgproc::ScanSegment>::opencv_from_extern(ret) };
@@ -1085,7 +1519,7 @@ pub fn create_scan_segment(image_width: i32, image_height: i32, num_superpixels:
 pub fn create_structured_edge_detection(model: &str, how_to_get_features: core::Ptr<dyn crate::ximgproc::RFFeatureGetter>) -> Result<core::Ptr<dyn crate::ximgproc::StructuredEdgeDetection>> {
 	extern_container_arg!(model);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_createStructuredEdgeDetection_const_StringR_Ptr_const_RFFeatureGetter_(model.opencv_as_extern(), how_to_get_features.as_raw_PtrOfRFFeatureGetter(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_createStructuredEdgeDetection_const_StringR_Ptr_const_RFFeatureGetter_(model.opencv_as_extern(), how_to_get_features.as_raw_PtrOfRFFeatureGetter(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::ximgproc::StructuredEdgeDetection>::opencv_from_extern(ret) };
@@ -1114,7 +1548,7 @@ pub fn create_structured_edge_detection(model: &str, how_to_get_features: core::
 pub fn create_superpixel_lsc(image: &dyn core::ToInputArray, region_size: i32, ratio: f32) -> Result<core::Ptr<dyn crate::ximgproc::SuperpixelLSC>> {
 	input_array_arg!(image);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_createSuperpixelLSC_const__InputArrayR_int_float(image.as_raw__InputArray(), region_size, ratio, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_createSuperpixelLSC_const__InputArrayR_int_float(image.as_raw__InputArray(), region_size, ratio, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::ximgproc::SuperpixelLSC>::opencv_from_extern(ret) };
@@ -1133,7 +1567,7 @@ pub fn create_superpixel_lsc(image: &dyn core::ToInputArray, region_size: i32, r
 /// * num_levels: Number of block levels. The more levels, the more accurate is the segmentation,
 /// but needs more memory and CPU time.
 /// * prior: enable 3x3 shape smoothing term if \>0. A larger value leads to smoother shapes. prior
-/// must be in the range [0, 5].
+/// must be in the range \[0, 5\].
 /// * histogram_bins: Number of histogram bins.
 /// * double_step: If true, iterate each block level twice for higher accuracy.
 /// 
@@ -1158,13 +1592,61 @@ pub fn create_superpixel_lsc(image: &dyn core::ToInputArray, region_size: i32, r
 #[inline]
 pub fn create_superpixel_seeds(image_width: i32, image_height: i32, image_channels: i32, num_superpixels: i32, num_levels: i32, prior: i32, histogram_bins: i32, double_step: bool) -> Result<core::Ptr<dyn crate::ximgproc::SuperpixelSEEDS>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_createSuperpixelSEEDS_int_int_int_int_int_int_int_bool(image_width, image_height, image_channels, num_superpixels, num_levels, prior, histogram_bins, double_step, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_createSuperpixelSEEDS_int_int_int_int_int_int_int_bool(image_width, image_height, image_channels, num_superpixels, num_levels, prior, histogram_bins, double_step, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::ximgproc::SuperpixelSEEDS>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_superpixel_seeds()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateSuperpixelSeedsBuilder {
+	image_width: i32,
+	image_height: i32,
+	image_channels: i32,
+	num_superpixels: i32,
+	num_levels: i32,
+	prior: i32,
+	histogram_bins: i32,
+	double_step: bool,
+}
+
+impl CreateSuperpixelSeedsBuilder {
+	#[inline]
+	pub fn new(image_width: i32, image_height: i32, image_channels: i32, num_superpixels: i32, num_levels: i32) -> Self {
+		Self { image_width, image_height, image_channels, num_superpixels, num_levels, prior: 2, histogram_bins: 5, double_step: false }
+	}
+	
+	/// C++ default: `2`
+	#[inline]
+	pub fn with_prior(mut self, prior: i32) -> Self {
+		self.prior = prior;
+		self
+	}
+	
+	/// C++ default: `5`
+	#[inline]
+	pub fn with_histogram_bins(mut self, histogram_bins: i32) -> Self {
+		self.histogram_bins = histogram_bins;
+		self
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_double_step(mut self, double_step: bool) -> Self {
+		self.double_step = double_step;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::ximgproc::SuperpixelSEEDS>> {
+		create_superpixel_seeds(self.image_width, self.image_height, self.image_channels, self.num_superpixels, self.num_levels, self.prior, self.histogram_bins, self.double_step)
+	}
+}
+
 /// Initialize a SuperpixelSLIC object
 /// 
 /// ## Parameters
@@ -1191,13 +1673,50 @@ pub fn create_superpixel_seeds(image_width: i32, image_height: i32, image_channe
 pub fn create_superpixel_slic(image: &dyn core::ToInputArray, algorithm: i32, region_size: i32, ruler: f32) -> Result<core::Ptr<dyn crate::ximgproc::SuperpixelSLIC>> {
 	input_array_arg!(image);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_createSuperpixelSLIC_const__InputArrayR_int_int_float(image.as_raw__InputArray(), algorithm, region_size, ruler, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_createSuperpixelSLIC_const__InputArrayR_int_int_float(image.as_raw__InputArray(), algorithm, region_size, ruler, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::ximgproc::SuperpixelSLIC>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_superpixel_slic()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateSuperpixelSlicBuilder<'a> {
+	image: &'a dyn core::ToInputArray,
+	algorithm: i32,
+	region_size: i32,
+	ruler: f32,
+}
+
+impl<'a> CreateSuperpixelSlicBuilder<'a> {
+	#[inline]
+	pub fn new(image: &'a dyn core::ToInputArray, algorithm: i32) -> Self {
+		Self { image, algorithm, region_size: 10, ruler: 10.0 }
+	}
+	
+	/// C++ default: `10`
+	#[inline]
+	pub fn with_region_size(mut self, region_size: i32) -> Self {
+		self.region_size = region_size;
+		self
+	}
+	
+	/// C++ default: `10.0f`
+	#[inline]
+	pub fn with_ruler(mut self, ruler: f32) -> Self {
+		self.ruler = ruler;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::ximgproc::SuperpixelSLIC>> {
+		create_superpixel_slic(self.image, self.algorithm, self.region_size, self.ruler)
+	}
+}
+
 /// Simple one-line Domain Transform filter call. If you have multiple images to filter with the same
 /// guided image then use DTFilter interface to avoid extra computations on initialization stage.
 /// 
@@ -1214,7 +1733,7 @@ pub fn create_superpixel_slic(image: &dyn core::ToInputArray, algorithm: i32, re
 /// filtering 2D signals in the article.
 /// * numIters: optional number of iterations used for filtering, 3 is quite enough.
 /// ## See also
-/// bilateralFilter, guidedFilter, amFilter
+/// `bilateral_filter`, [guided_filter](crate::ximgproc::guided_filter), [am_filter](crate::ximgproc::am_filter)
 /// 
 /// ## C++ default parameters
 /// * mode: DTF_NC
@@ -1225,17 +1744,50 @@ pub fn dt_filter(guide: &dyn core::ToInputArray, src: &dyn core::ToInputArray, d
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_dtFilter_const__InputArrayR_const__InputArrayR_const__OutputArrayR_double_double_int_int(guide.as_raw__InputArray(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), sigma_spatial, sigma_color, mode, num_iters, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_dtFilter_const__InputArrayR_const__InputArrayR_const__OutputArrayR_double_double_int_int(guide.as_raw__InputArray(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), sigma_spatial, sigma_color, mode, num_iters, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `dt_filter()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct DtFilterBuilder<'a> {
+	guide: &'a dyn core::ToInputArray,
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	sigma_spatial: f64,
+	sigma_color: f64,
+	mode: i32,
+	num_iters: i32,
+}
+
+impl<'a> DtFilterBuilder<'a> {
+	#[inline]
+	pub fn new(guide: &'a dyn core::ToInputArray, src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, sigma_spatial: f64, sigma_color: f64, mode: i32) -> Self {
+		Self { guide, src, dst, sigma_spatial, sigma_color, mode, num_iters: 3 }
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_num_iters(mut self, num_iters: i32) -> Self {
+		self.num_iters = num_iters;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		dt_filter(self.guide, self.src, self.dst, self.sigma_spatial, self.sigma_color, self.mode, self.num_iters)
+	}
+}
+
 /// Smoothes an image using the Edge-Preserving filter.
 /// 
 /// The function smoothes Gaussian noise as well as salt & pepper noise.
 /// For more details about this implementation, please see
-/// [ReiWoe18]  Reich, S. and Wörgötter, F. and Dellen, B. (2018). A Real-Time Edge-Preserving Denoising Filter. Proceedings of the 13th International Joint Conference on Computer Vision, Imaging and Computer Graphics Theory and Applications (VISIGRAPP): Visapp, 85-94, 4. DOI: 10.5220/0006509000850094.
+/// \[ReiWoe18\]  Reich, S. and Wörgötter, F. and Dellen, B. (2018). A Real-Time Edge-Preserving Denoising Filter. Proceedings of the 13th International Joint Conference on Computer Vision, Imaging and Computer Graphics Theory and Applications (VISIGRAPP): Visapp, 85-94, 4. DOI: 10.5220/0006509000850094.
 /// 
 /// ## Parameters
 /// * src: Source 8-bit 3-channel image.
@@ -1247,7 +1799,7 @@ pub fn edge_preserving_filter(src: &dyn core::ToInputArray, dst: &mut dyn core::
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_edgePreservingFilter_const__InputArrayR_const__OutputArrayR_int_double(src.as_raw__InputArray(), dst.as_raw__OutputArray(), d, threshold, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_edgePreservingFilter_const__InputArrayR_const__OutputArrayR_int_double(src.as_raw__InputArray(), dst.as_raw__OutputArray(), d, threshold, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1280,7 +1832,7 @@ pub fn edge_preserving_filter(src: &dyn core::ToInputArray, dst: &mut dyn core::
 /// For more details about the Fast Bilateral Solver parameters, see the original paper [BarronPoole2016](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_BarronPoole2016).
 /// 
 /// 
-/// Note: Confidence images with CV_8U depth are expected to in [0, 255] and CV_32F in [0, 1] range.
+/// Note: Confidence images with CV_8U depth are expected to in \[0, 255\] and CV_32F in \[0, 1\] range.
 /// 
 /// ## C++ default parameters
 /// * sigma_spatial: 8
@@ -1296,12 +1848,83 @@ pub fn fast_bilateral_solver_filter(guide: &dyn core::ToInputArray, src: &dyn co
 	input_array_arg!(confidence);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_fastBilateralSolverFilter_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_double_double_double_double_int_double(guide.as_raw__InputArray(), src.as_raw__InputArray(), confidence.as_raw__InputArray(), dst.as_raw__OutputArray(), sigma_spatial, sigma_luma, sigma_chroma, lambda, num_iter, max_tol, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_fastBilateralSolverFilter_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_double_double_double_double_int_double(guide.as_raw__InputArray(), src.as_raw__InputArray(), confidence.as_raw__InputArray(), dst.as_raw__OutputArray(), sigma_spatial, sigma_luma, sigma_chroma, lambda, num_iter, max_tol, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `fast_bilateral_solver_filter()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FastBilateralSolverFilterBuilder<'a> {
+	guide: &'a dyn core::ToInputArray,
+	src: &'a dyn core::ToInputArray,
+	confidence: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	sigma_spatial: f64,
+	sigma_luma: f64,
+	sigma_chroma: f64,
+	lambda: f64,
+	num_iter: i32,
+	max_tol: f64,
+}
+
+impl<'a> FastBilateralSolverFilterBuilder<'a> {
+	#[inline]
+	pub fn new(guide: &'a dyn core::ToInputArray, src: &'a dyn core::ToInputArray, confidence: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { guide, src, confidence, dst, sigma_spatial: 8., sigma_luma: 8., sigma_chroma: 8., lambda: 128.0, num_iter: 25, max_tol: 1e-5 }
+	}
+	
+	/// C++ default: `8`
+	#[inline]
+	pub fn with_sigma_spatial(mut self, sigma_spatial: f64) -> Self {
+		self.sigma_spatial = sigma_spatial;
+		self
+	}
+	
+	/// C++ default: `8`
+	#[inline]
+	pub fn with_sigma_luma(mut self, sigma_luma: f64) -> Self {
+		self.sigma_luma = sigma_luma;
+		self
+	}
+	
+	/// C++ default: `8`
+	#[inline]
+	pub fn with_sigma_chroma(mut self, sigma_chroma: f64) -> Self {
+		self.sigma_chroma = sigma_chroma;
+		self
+	}
+	
+	/// C++ default: `128.0`
+	#[inline]
+	pub fn with_lambda(mut self, lambda: f64) -> Self {
+		self.lambda = lambda;
+		self
+	}
+	
+	/// C++ default: `25`
+	#[inline]
+	pub fn with_num_iter(mut self, num_iter: i32) -> Self {
+		self.num_iter = num_iter;
+		self
+	}
+	
+	/// C++ default: `1e-5`
+	#[inline]
+	pub fn with_max_tol(mut self, max_tol: f64) -> Self {
+		self.max_tol = max_tol;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		fast_bilateral_solver_filter(self.guide, self.src, self.confidence, self.dst, self.sigma_spatial, self.sigma_luma, self.sigma_chroma, self.lambda, self.num_iter, self.max_tol)
+	}
+}
+
 /// Simple one-line Fast Global Smoother filter call. If you have multiple images to filter with the same
 /// guide then use FastGlobalSmootherFilter interface to avoid extra computations.
 /// 
@@ -1330,12 +1953,52 @@ pub fn fast_global_smoother_filter(guide: &dyn core::ToInputArray, src: &dyn cor
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_fastGlobalSmootherFilter_const__InputArrayR_const__InputArrayR_const__OutputArrayR_double_double_double_int(guide.as_raw__InputArray(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), lambda, sigma_color, lambda_attenuation, num_iter, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_fastGlobalSmootherFilter_const__InputArrayR_const__InputArrayR_const__OutputArrayR_double_double_double_int(guide.as_raw__InputArray(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), lambda, sigma_color, lambda_attenuation, num_iter, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `fast_global_smoother_filter()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FastGlobalSmootherFilterBuilder<'a> {
+	guide: &'a dyn core::ToInputArray,
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	lambda: f64,
+	sigma_color: f64,
+	lambda_attenuation: f64,
+	num_iter: i32,
+}
+
+impl<'a> FastGlobalSmootherFilterBuilder<'a> {
+	#[inline]
+	pub fn new(guide: &'a dyn core::ToInputArray, src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, lambda: f64, sigma_color: f64) -> Self {
+		Self { guide, src, dst, lambda, sigma_color, lambda_attenuation: 0.25, num_iter: 3 }
+	}
+	
+	/// C++ default: `0.25`
+	#[inline]
+	pub fn with_lambda_attenuation(mut self, lambda_attenuation: f64) -> Self {
+		self.lambda_attenuation = lambda_attenuation;
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_num_iter(mut self, num_iter: i32) -> Self {
+		self.num_iter = num_iter;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		fast_global_smoother_filter(self.guide, self.src, self.dst, self.lambda, self.sigma_color, self.lambda_attenuation, self.num_iter)
+	}
+}
+
 ///   Fourier descriptors for planed closed curves
 /// 
 /// For more details about this implementation, please see [PersoonFu1977](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_PersoonFu1977)
@@ -1344,7 +2007,7 @@ pub fn fast_global_smoother_filter(guide: &dyn core::ToInputArray, src: &dyn cor
 /// * src: contour type vector<Point> , vector<Point2f>  or vector<Point2d>
 /// * dst: Mat of type CV_64FC2 and nbElt rows A VERIFIER
 /// * nbElt: number of rows in dst or getOptimalDFTSize rows if nbElt=-1
-/// * nbFD: number of FD return in dst dst = [FD(1...nbFD/2) FD(nbFD/2-nbElt+1...:nbElt)]
+/// * nbFD: number of FD return in dst dst = \[FD(1...nbFD/2) FD(nbFD/2-nbElt+1...:nbElt)\]
 /// 
 /// ## C++ default parameters
 /// * nb_elt: -1
@@ -1354,7 +2017,7 @@ pub fn fourier_descriptor(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOu
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_fourierDescriptor_const__InputArrayR_const__OutputArrayR_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), nb_elt, nb_fd, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_fourierDescriptor_const__InputArrayR_const__OutputArrayR_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), nb_elt, nb_fd, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1376,7 +2039,7 @@ pub fn get_disparity_vis(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOut
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_getDisparityVis_const__InputArrayR_const__OutputArrayR_double(src.as_raw__InputArray(), dst.as_raw__OutputArray(), scale, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_getDisparityVis_const__InputArrayR_const__OutputArrayR_double(src.as_raw__InputArray(), dst.as_raw__OutputArray(), scale, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1402,7 +2065,7 @@ pub fn get_disparity_vis(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOut
 /// 
 /// * dDepth: optional depth of the output image.
 /// ## See also
-/// bilateralFilter, dtFilter, amFilter
+/// `bilateral_filter`, [dt_filter](crate::ximgproc::dt_filter), [am_filter](crate::ximgproc::am_filter)
 /// 
 /// ## C++ default parameters
 /// * d_depth: -1
@@ -1412,12 +2075,44 @@ pub fn guided_filter(guide: &dyn core::ToInputArray, src: &dyn core::ToInputArra
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_guidedFilter_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_double_int(guide.as_raw__InputArray(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), radius, eps, d_depth, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_guidedFilter_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_double_int(guide.as_raw__InputArray(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), radius, eps, d_depth, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `guided_filter()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct GuidedFilterBuilder<'a> {
+	guide: &'a dyn core::ToInputArray,
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	radius: i32,
+	eps: f64,
+	d_depth: i32,
+}
+
+impl<'a> GuidedFilterBuilder<'a> {
+	#[inline]
+	pub fn new(guide: &'a dyn core::ToInputArray, src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, radius: i32, eps: f64) -> Self {
+		Self { guide, src, dst, radius, eps, d_depth: -1 }
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_d_depth(mut self, d_depth: i32) -> Self {
+		self.d_depth = d_depth;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		guided_filter(self.guide, self.src, self.dst, self.radius, self.eps, self.d_depth)
+	}
+}
+
 /// Applies the joint bilateral filter to an image.
 /// 
 /// ## Parameters
@@ -1445,7 +2140,7 @@ pub fn guided_filter(guide: &dyn core::ToInputArray, src: &dyn core::ToInputArra
 /// 
 /// Note: bilateralFilter and jointBilateralFilter use L1 norm to compute difference between colors.
 /// ## See also
-/// bilateralFilter, amFilter
+/// `bilateral_filter`, [am_filter](crate::ximgproc::am_filter)
 /// 
 /// ## C++ default parameters
 /// * border_type: BORDER_DEFAULT
@@ -1455,7 +2150,7 @@ pub fn joint_bilateral_filter(joint: &dyn core::ToInputArray, src: &dyn core::To
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_jointBilateralFilter_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_double_double_int(joint.as_raw__InputArray(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), d, sigma_color, sigma_space, border_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_jointBilateralFilter_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_double_double_int(joint.as_raw__InputArray(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), d, sigma_color, sigma_space, border_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1482,7 +2177,7 @@ pub fn l0_smooth(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_l0Smooth_const__InputArrayR_const__OutputArrayR_double_double(src.as_raw__InputArray(), dst.as_raw__OutputArray(), lambda, kappa, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_l0Smooth_const__InputArrayR_const__OutputArrayR_double_double(src.as_raw__InputArray(), dst.as_raw__OutputArray(), lambda, kappa, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1520,7 +2215,7 @@ pub fn l0_smooth(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 /// * r: The user-adjustable parameter used by Sauvola's technique. This is the dynamic range
 /// of standard deviation.
 /// ## See also
-/// threshold, adaptiveThreshold
+/// [threshold](crate::ximgproc::threshold), `adaptive_threshold`
 /// 
 /// ## C++ default parameters
 /// * binarization_method: BINARIZATION_NIBLACK
@@ -1530,12 +2225,46 @@ pub fn ni_black_threshold(_src: &dyn core::ToInputArray, _dst: &mut dyn core::To
 	input_array_arg!(_src);
 	output_array_arg!(_dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_niBlackThreshold_const__InputArrayR_const__OutputArrayR_double_int_int_double_int_double(_src.as_raw__InputArray(), _dst.as_raw__OutputArray(), max_value, typ, block_size, k, binarization_method, r, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_niBlackThreshold_const__InputArrayR_const__OutputArrayR_double_int_int_double_int_double(_src.as_raw__InputArray(), _dst.as_raw__OutputArray(), max_value, typ, block_size, k, binarization_method, r, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `ni_black_threshold()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct NiBlackThresholdBuilder<'a> {
+	_src: &'a dyn core::ToInputArray,
+	_dst: &'a mut dyn core::ToOutputArray,
+	max_value: f64,
+	typ: i32,
+	block_size: i32,
+	k: f64,
+	binarization_method: i32,
+	r: f64,
+}
+
+impl<'a> NiBlackThresholdBuilder<'a> {
+	#[inline]
+	pub fn new(_src: &'a dyn core::ToInputArray, _dst: &'a mut dyn core::ToOutputArray, max_value: f64, typ: i32, block_size: i32, k: f64, binarization_method: i32) -> Self {
+		Self { _src, _dst, max_value, typ, block_size, k, binarization_method, r: 128. }
+	}
+	
+	/// C++ default: `128`
+	#[inline]
+	pub fn with_r(mut self, r: f64) -> Self {
+		self.r = r;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		ni_black_threshold(self._src, self._dst, self.max_value, self.typ, self.block_size, self.k, self.binarization_method, self.r)
+	}
+}
+
 ///   calculates conjugate of a quaternion image.
 /// 
 /// ## Parameters
@@ -1546,7 +2275,7 @@ pub fn qconj(qimg: &dyn core::ToInputArray, qcimg: &mut dyn core::ToOutputArray)
 	input_array_arg!(qimg);
 	output_array_arg!(qcimg);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_qconj_const__InputArrayR_const__OutputArrayR(qimg.as_raw__InputArray(), qcimg.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_qconj_const__InputArrayR_const__OutputArrayR(qimg.as_raw__InputArray(), qcimg.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1564,7 +2293,7 @@ pub fn qdft(img: &dyn core::ToInputArray, qimg: &mut dyn core::ToOutputArray, fl
 	input_array_arg!(img);
 	output_array_arg!(qimg);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_qdft_const__InputArrayR_const__OutputArrayR_int_bool(img.as_raw__InputArray(), qimg.as_raw__OutputArray(), flags, side_left, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_qdft_const__InputArrayR_const__OutputArrayR_int_bool(img.as_raw__InputArray(), qimg.as_raw__OutputArray(), flags, side_left, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1582,7 +2311,7 @@ pub fn qmultiply(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, d
 	input_array_arg!(src2);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_qmultiply_const__InputArrayR_const__InputArrayR_const__OutputArrayR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_qmultiply_const__InputArrayR_const__InputArrayR_const__OutputArrayR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1598,7 +2327,7 @@ pub fn qunitary(qimg: &dyn core::ToInputArray, qnimg: &mut dyn core::ToOutputArr
 	input_array_arg!(qimg);
 	output_array_arg!(qnimg);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_qunitary_const__InputArrayR_const__OutputArrayR(qimg.as_raw__InputArray(), qnimg.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_qunitary_const__InputArrayR_const__OutputArrayR(qimg.as_raw__InputArray(), qnimg.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1618,7 +2347,7 @@ pub fn read_gt(src_path: &str, dst: &mut dyn core::ToOutputArray) -> Result<i32>
 	extern_container_arg!(mut src_path);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_readGT_String_const__OutputArrayR(src_path.opencv_as_extern_mut(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_readGT_String_const__OutputArrayR(src_path.opencv_as_extern_mut(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1638,7 +2367,7 @@ pub fn read_gt(src_path: &str, dst: &mut dyn core::ToOutputArray) -> Result<i32>
 pub fn create_rle_image(runs: &core::Vector<core::Point3i>, res: &mut dyn core::ToOutputArray, size: core::Size) -> Result<()> {
 	output_array_arg!(res);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_rl_createRLEImage_const_vector_Point3i_R_const__OutputArrayR_Size(runs.as_raw_VectorOfPoint3i(), res.as_raw__OutputArray(), size.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_rl_createRLEImage_const_vector_Point3i_R_const__OutputArrayR_Size(runs.as_raw_VectorOfPoint3i(), res.as_raw__OutputArray(), size.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1662,7 +2391,7 @@ pub fn dilate(rl_src: &dyn core::ToInputArray, rl_dest: &mut dyn core::ToOutputA
 	output_array_arg!(rl_dest);
 	input_array_arg!(rl_kernel);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_rl_dilate_const__InputArrayR_const__OutputArrayR_const__InputArrayR_Point(rl_src.as_raw__InputArray(), rl_dest.as_raw__OutputArray(), rl_kernel.as_raw__InputArray(), anchor.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_rl_dilate_const__InputArrayR_const__OutputArrayR_const__InputArrayR_Point(rl_src.as_raw__InputArray(), rl_dest.as_raw__OutputArray(), rl_kernel.as_raw__InputArray(), anchor.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1689,7 +2418,7 @@ pub fn erode(rl_src: &dyn core::ToInputArray, rl_dest: &mut dyn core::ToOutputAr
 	output_array_arg!(rl_dest);
 	input_array_arg!(rl_kernel);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_rl_erode_const__InputArrayR_const__OutputArrayR_const__InputArrayR_bool_Point(rl_src.as_raw__InputArray(), rl_dest.as_raw__OutputArray(), rl_kernel.as_raw__InputArray(), b_boundary_on, anchor.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_rl_erode_const__InputArrayR_const__OutputArrayR_const__InputArrayR_bool_Point(rl_src.as_raw__InputArray(), rl_dest.as_raw__OutputArray(), rl_kernel.as_raw__InputArray(), b_boundary_on, anchor.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1704,7 +2433,7 @@ pub fn erode(rl_src: &dyn core::ToInputArray, rl_dest: &mut dyn core::ToOutputAr
 #[inline]
 pub fn get_structuring_element(shape: i32, ksize: core::Size) -> Result<core::Mat> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_rl_getStructuringElement_int_Size(shape, ksize.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_rl_getStructuringElement_int_Size(shape, ksize.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -1720,7 +2449,7 @@ pub fn get_structuring_element(shape: i32, ksize: core::Size) -> Result<core::Ma
 pub fn is_rl_morphology_possible(rl_structuring_element: &dyn core::ToInputArray) -> Result<bool> {
 	input_array_arg!(rl_structuring_element);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_rl_isRLMorphologyPossible_const__InputArrayR(rl_structuring_element.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_rl_isRLMorphologyPossible_const__InputArrayR(rl_structuring_element.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1748,12 +2477,51 @@ pub fn morphology_ex(rl_src: &dyn core::ToInputArray, rl_dest: &mut dyn core::To
 	output_array_arg!(rl_dest);
 	input_array_arg!(rl_kernel);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_rl_morphologyEx_const__InputArrayR_const__OutputArrayR_int_const__InputArrayR_bool_Point(rl_src.as_raw__InputArray(), rl_dest.as_raw__OutputArray(), op, rl_kernel.as_raw__InputArray(), b_boundary_on_for_erosion, anchor.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_rl_morphologyEx_const__InputArrayR_const__OutputArrayR_int_const__InputArrayR_bool_Point(rl_src.as_raw__InputArray(), rl_dest.as_raw__OutputArray(), op, rl_kernel.as_raw__InputArray(), b_boundary_on_for_erosion, anchor.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `morphology_ex()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct MorphologyExBuilder<'a> {
+	rl_src: &'a dyn core::ToInputArray,
+	rl_dest: &'a mut dyn core::ToOutputArray,
+	op: i32,
+	rl_kernel: &'a dyn core::ToInputArray,
+	b_boundary_on_for_erosion: bool,
+	anchor: core::Point,
+}
+
+impl<'a> MorphologyExBuilder<'a> {
+	#[inline]
+	pub fn new(rl_src: &'a dyn core::ToInputArray, rl_dest: &'a mut dyn core::ToOutputArray, op: i32, rl_kernel: &'a dyn core::ToInputArray) -> Self {
+		Self { rl_src, rl_dest, op, rl_kernel, b_boundary_on_for_erosion: true, anchor: core::Point::new(0, 0) }
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_b_boundary_on_for_erosion(mut self, b_boundary_on_for_erosion: bool) -> Self {
+		self.b_boundary_on_for_erosion = b_boundary_on_for_erosion;
+		self
+	}
+	
+	/// C++ default: `Point(0,0)`
+	#[inline]
+	pub fn with_anchor(mut self, anchor: core::Point) -> Self {
+		self.anchor = anchor;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		morphology_ex(self.rl_src, self.rl_dest, self.op, self.rl_kernel, self.b_boundary_on_for_erosion, self.anchor)
+	}
+}
+
 ///   Paint run length encoded binary image into an image.
 /// 
 /// 
@@ -1766,7 +2534,7 @@ pub fn paint(image: &mut dyn core::ToInputOutputArray, rl_src: &dyn core::ToInpu
 	input_output_array_arg!(image);
 	input_array_arg!(rl_src);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_rl_paint_const__InputOutputArrayR_const__InputArrayR_const_ScalarR(image.as_raw__InputOutputArray(), rl_src.as_raw__InputArray(), &value, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_rl_paint_const__InputOutputArrayR_const__InputArrayR_const_ScalarR(image.as_raw__InputOutputArray(), rl_src.as_raw__InputArray(), &value, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1785,7 +2553,7 @@ pub fn threshold(src: &dyn core::ToInputArray, rl_dest: &mut dyn core::ToOutputA
 	input_array_arg!(src);
 	output_array_arg!(rl_dest);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_rl_threshold_const__InputArrayR_const__OutputArrayR_double_int(src.as_raw__InputArray(), rl_dest.as_raw__OutputArray(), thresh, typ, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_rl_threshold_const__InputArrayR_const__OutputArrayR_double_int(src.as_raw__InputArray(), rl_dest.as_raw__OutputArray(), thresh, typ, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -1819,7 +2587,7 @@ pub fn threshold(src: &dyn core::ToInputArray, rl_dest: &mut dyn core::ToOutputA
 /// 
 /// Note:  rollingGuidanceFilter uses jointBilateralFilter as the edge-preserving filter.
 /// ## See also
-/// jointBilateralFilter, bilateralFilter, amFilter
+/// [joint_bilateral_filter](crate::ximgproc::joint_bilateral_filter), `bilateral_filter`, [am_filter](crate::ximgproc::am_filter)
 /// 
 /// ## C++ default parameters
 /// * d: -1
@@ -1832,12 +2600,66 @@ pub fn rolling_guidance_filter(src: &dyn core::ToInputArray, dst: &mut dyn core:
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_rollingGuidanceFilter_const__InputArrayR_const__OutputArrayR_int_double_double_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), d, sigma_color, sigma_space, num_of_iter, border_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_rollingGuidanceFilter_const__InputArrayR_const__OutputArrayR_int_double_double_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), d, sigma_color, sigma_space, num_of_iter, border_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `rolling_guidance_filter()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct RollingGuidanceFilterBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	d: i32,
+	sigma_color: f64,
+	sigma_space: f64,
+	num_of_iter: i32,
+	border_type: i32,
+}
+
+impl<'a> RollingGuidanceFilterBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, border_type: i32) -> Self {
+		Self { src, dst, d: -1, sigma_color: 25., sigma_space: 3., num_of_iter: 4, border_type }
+	}
+	
+	/// C++ default: `-1`
+	#[inline]
+	pub fn with_d(mut self, d: i32) -> Self {
+		self.d = d;
+		self
+	}
+	
+	/// C++ default: `25`
+	#[inline]
+	pub fn with_sigma_color(mut self, sigma_color: f64) -> Self {
+		self.sigma_color = sigma_color;
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_sigma_space(mut self, sigma_space: f64) -> Self {
+		self.sigma_space = sigma_space;
+		self
+	}
+	
+	/// C++ default: `4`
+	#[inline]
+	pub fn with_num_of_iter(mut self, num_of_iter: i32) -> Self {
+		self.num_of_iter = num_of_iter;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		rolling_guidance_filter(self.src, self.dst, self.d, self.sigma_color, self.sigma_space, self.num_of_iter, self.border_type)
+	}
+}
+
 /// Creates a graph based segmentor
 /// ## Parameters
 /// * sigma: The sigma parameter, used to smooth image
@@ -1851,18 +2673,68 @@ pub fn rolling_guidance_filter(src: &dyn core::ToInputArray, dst: &mut dyn core:
 #[inline]
 pub fn create_graph_segmentation(sigma: f64, k: f32, min_size: i32) -> Result<core::Ptr<dyn crate::ximgproc::GraphSegmentation>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_segmentation_createGraphSegmentation_double_float_int(sigma, k, min_size, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_segmentation_createGraphSegmentation_double_float_int(sigma, k, min_size, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::ximgproc::GraphSegmentation>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_graph_segmentation()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateGraphSegmentationBuilder {
+	sigma: f64,
+	k: f32,
+	min_size: i32,
+}
+
+impl CreateGraphSegmentationBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { sigma: 0.5, k: 300., min_size: 100 }
+	}
+	
+	/// C++ default: `0.5`
+	#[inline]
+	pub fn with_sigma(mut self, sigma: f64) -> Self {
+		self.sigma = sigma;
+		self
+	}
+	
+	/// C++ default: `300`
+	#[inline]
+	pub fn with_k(mut self, k: f32) -> Self {
+		self.k = k;
+		self
+	}
+	
+	/// C++ default: `100`
+	#[inline]
+	pub fn with_min_size(mut self, min_size: i32) -> Self {
+		self.min_size = min_size;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::ximgproc::GraphSegmentation>> {
+		create_graph_segmentation(self.sigma, self.k, self.min_size)
+	}
+}
+
+impl Default for CreateGraphSegmentationBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 /// Create a new SelectiveSearchSegmentation class.
 #[inline]
 pub fn create_selective_search_segmentation() -> Result<core::Ptr<dyn crate::ximgproc::SelectiveSearchSegmentation>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_segmentation_createSelectiveSearchSegmentation(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_segmentation_createSelectiveSearchSegmentation(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::ximgproc::SelectiveSearchSegmentation>::opencv_from_extern(ret) };
@@ -1873,7 +2745,7 @@ pub fn create_selective_search_segmentation() -> Result<core::Ptr<dyn crate::xim
 #[inline]
 pub fn create_selective_search_segmentation_strategy_color() -> Result<core::Ptr<dyn crate::ximgproc::SelectiveSearchSegmentationStrategyColor>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_segmentation_createSelectiveSearchSegmentationStrategyColor(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_segmentation_createSelectiveSearchSegmentationStrategyColor(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::ximgproc::SelectiveSearchSegmentationStrategyColor>::opencv_from_extern(ret) };
@@ -1884,7 +2756,7 @@ pub fn create_selective_search_segmentation_strategy_color() -> Result<core::Ptr
 #[inline]
 pub fn create_selective_search_segmentation_strategy_fill() -> Result<core::Ptr<dyn crate::ximgproc::SelectiveSearchSegmentationStrategyFill>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_segmentation_createSelectiveSearchSegmentationStrategyFill(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_segmentation_createSelectiveSearchSegmentationStrategyFill(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::ximgproc::SelectiveSearchSegmentationStrategyFill>::opencv_from_extern(ret) };
@@ -1895,7 +2767,7 @@ pub fn create_selective_search_segmentation_strategy_fill() -> Result<core::Ptr<
 #[inline]
 pub fn create_selective_search_segmentation_strategy_multiple() -> Result<core::Ptr<dyn crate::ximgproc::SelectiveSearchSegmentationStrategyMultiple>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_segmentation_createSelectiveSearchSegmentationStrategyMultiple(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_segmentation_createSelectiveSearchSegmentationStrategyMultiple(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::ximgproc::SelectiveSearchSegmentationStrategyMultiple>::opencv_from_extern(ret) };
@@ -1908,7 +2780,7 @@ pub fn create_selective_search_segmentation_strategy_multiple() -> Result<core::
 #[inline]
 pub fn create_selective_search_segmentation_strategy_multiple_1(mut s1: core::Ptr<dyn crate::ximgproc::SelectiveSearchSegmentationStrategy>) -> Result<core::Ptr<dyn crate::ximgproc::SelectiveSearchSegmentationStrategyMultiple>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_segmentation_createSelectiveSearchSegmentationStrategyMultiple_Ptr_SelectiveSearchSegmentationStrategy_(s1.as_raw_mut_PtrOfSelectiveSearchSegmentationStrategy(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_segmentation_createSelectiveSearchSegmentationStrategyMultiple_Ptr_SelectiveSearchSegmentationStrategy_(s1.as_raw_mut_PtrOfSelectiveSearchSegmentationStrategy(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::ximgproc::SelectiveSearchSegmentationStrategyMultiple>::opencv_from_extern(ret) };
@@ -1922,7 +2794,7 @@ pub fn create_selective_search_segmentation_strategy_multiple_1(mut s1: core::Pt
 #[inline]
 pub fn create_selective_search_segmentation_strategy_multiple_2(mut s1: core::Ptr<dyn crate::ximgproc::SelectiveSearchSegmentationStrategy>, mut s2: core::Ptr<dyn crate::ximgproc::SelectiveSearchSegmentationStrategy>) -> Result<core::Ptr<dyn crate::ximgproc::SelectiveSearchSegmentationStrategyMultiple>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_segmentation_createSelectiveSearchSegmentationStrategyMultiple_Ptr_SelectiveSearchSegmentationStrategy__Ptr_SelectiveSearchSegmentationStrategy_(s1.as_raw_mut_PtrOfSelectiveSearchSegmentationStrategy(), s2.as_raw_mut_PtrOfSelectiveSearchSegmentationStrategy(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_segmentation_createSelectiveSearchSegmentationStrategyMultiple_Ptr_SelectiveSearchSegmentationStrategy__Ptr_SelectiveSearchSegmentationStrategy_(s1.as_raw_mut_PtrOfSelectiveSearchSegmentationStrategy(), s2.as_raw_mut_PtrOfSelectiveSearchSegmentationStrategy(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::ximgproc::SelectiveSearchSegmentationStrategyMultiple>::opencv_from_extern(ret) };
@@ -1937,7 +2809,7 @@ pub fn create_selective_search_segmentation_strategy_multiple_2(mut s1: core::Pt
 #[inline]
 pub fn create_selective_search_segmentation_strategy_multiple_3(mut s1: core::Ptr<dyn crate::ximgproc::SelectiveSearchSegmentationStrategy>, mut s2: core::Ptr<dyn crate::ximgproc::SelectiveSearchSegmentationStrategy>, mut s3: core::Ptr<dyn crate::ximgproc::SelectiveSearchSegmentationStrategy>) -> Result<core::Ptr<dyn crate::ximgproc::SelectiveSearchSegmentationStrategyMultiple>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_segmentation_createSelectiveSearchSegmentationStrategyMultiple_Ptr_SelectiveSearchSegmentationStrategy__Ptr_SelectiveSearchSegmentationStrategy__Ptr_SelectiveSearchSegmentationStrategy_(s1.as_raw_mut_PtrOfSelectiveSearchSegmentationStrategy(), s2.as_raw_mut_PtrOfSelectiveSearchSegmentationStrategy(), s3.as_raw_mut_PtrOfSelectiveSearchSegmentationStrategy(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_segmentation_createSelectiveSearchSegmentationStrategyMultiple_Ptr_SelectiveSearchSegmentationStrategy__Ptr_SelectiveSearchSegmentationStrategy__Ptr_SelectiveSearchSegmentationStrategy_(s1.as_raw_mut_PtrOfSelectiveSearchSegmentationStrategy(), s2.as_raw_mut_PtrOfSelectiveSearchSegmentationStrategy(), s3.as_raw_mut_PtrOfSelectiveSearchSegmentationStrategy(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::ximgproc::SelectiveSearchSegmentationStrategyMultiple>::opencv_from_extern(ret) };
@@ -1953,7 +2825,7 @@ pub fn create_selective_search_segmentation_strategy_multiple_3(mut s1: core::Pt
 #[inline]
 pub fn create_selective_search_segmentation_strategy_multiple_4(mut s1: core::Ptr<dyn crate::ximgproc::SelectiveSearchSegmentationStrategy>, mut s2: core::Ptr<dyn crate::ximgproc::SelectiveSearchSegmentationStrategy>, mut s3: core::Ptr<dyn crate::ximgproc::SelectiveSearchSegmentationStrategy>, mut s4: core::Ptr<dyn crate::ximgproc::SelectiveSearchSegmentationStrategy>) -> Result<core::Ptr<dyn crate::ximgproc::SelectiveSearchSegmentationStrategyMultiple>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_segmentation_createSelectiveSearchSegmentationStrategyMultiple_Ptr_SelectiveSearchSegmentationStrategy__Ptr_SelectiveSearchSegmentationStrategy__Ptr_SelectiveSearchSegmentationStrategy__Ptr_SelectiveSearchSegmentationStrategy_(s1.as_raw_mut_PtrOfSelectiveSearchSegmentationStrategy(), s2.as_raw_mut_PtrOfSelectiveSearchSegmentationStrategy(), s3.as_raw_mut_PtrOfSelectiveSearchSegmentationStrategy(), s4.as_raw_mut_PtrOfSelectiveSearchSegmentationStrategy(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_segmentation_createSelectiveSearchSegmentationStrategyMultiple_Ptr_SelectiveSearchSegmentationStrategy__Ptr_SelectiveSearchSegmentationStrategy__Ptr_SelectiveSearchSegmentationStrategy__Ptr_SelectiveSearchSegmentationStrategy_(s1.as_raw_mut_PtrOfSelectiveSearchSegmentationStrategy(), s2.as_raw_mut_PtrOfSelectiveSearchSegmentationStrategy(), s3.as_raw_mut_PtrOfSelectiveSearchSegmentationStrategy(), s4.as_raw_mut_PtrOfSelectiveSearchSegmentationStrategy(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::ximgproc::SelectiveSearchSegmentationStrategyMultiple>::opencv_from_extern(ret) };
@@ -1964,7 +2836,7 @@ pub fn create_selective_search_segmentation_strategy_multiple_4(mut s1: core::Pt
 #[inline]
 pub fn create_selective_search_segmentation_strategy_size() -> Result<core::Ptr<dyn crate::ximgproc::SelectiveSearchSegmentationStrategySize>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_segmentation_createSelectiveSearchSegmentationStrategySize(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_segmentation_createSelectiveSearchSegmentationStrategySize(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::ximgproc::SelectiveSearchSegmentationStrategySize>::opencv_from_extern(ret) };
@@ -1975,7 +2847,7 @@ pub fn create_selective_search_segmentation_strategy_size() -> Result<core::Ptr<
 #[inline]
 pub fn create_selective_search_segmentation_strategy_texture() -> Result<core::Ptr<dyn crate::ximgproc::SelectiveSearchSegmentationStrategyTexture>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_segmentation_createSelectiveSearchSegmentationStrategyTexture(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_segmentation_createSelectiveSearchSegmentationStrategyTexture(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::ximgproc::SelectiveSearchSegmentationStrategyTexture>::opencv_from_extern(ret) };
@@ -1998,7 +2870,7 @@ pub fn thinning(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray,
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_thinning_const__InputArrayR_const__OutputArrayR_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), thinning_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_thinning_const__InputArrayR_const__OutputArrayR_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), thinning_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -2020,7 +2892,7 @@ pub fn transform_fd(src: &dyn core::ToInputArray, t: &dyn core::ToInputArray, ds
 	input_array_arg!(t);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_transformFD_const__InputArrayR_const__InputArrayR_const__OutputArrayR_bool(src.as_raw__InputArray(), t.as_raw__InputArray(), dst.as_raw__OutputArray(), fd_contour, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_transformFD_const__InputArrayR_const__InputArrayR_const__OutputArrayR_bool(src.as_raw__InputArray(), t.as_raw__InputArray(), dst.as_raw__OutputArray(), fd_contour, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -2040,7 +2912,7 @@ pub fn transform_fd(src: &dyn core::ToInputArray, t: &dyn core::ToInputArray, ds
 /// * mask: A 0-1 mask that has the same size with I. This mask is used to ignore the effect of some pixels. If the pixel value on mask is 0,
 ///                           the pixel will be ignored when maintaining the joint-histogram. This is useful for applications like optical flow occlusion handling.
 /// ## See also
-/// medianBlur, jointBilateralFilter
+/// `median_blur`, [joint_bilateral_filter](crate::ximgproc::joint_bilateral_filter)
 /// 
 /// ## C++ default parameters
 /// * sigma: 25.5
@@ -2053,12 +2925,54 @@ pub fn weighted_median_filter(joint: &dyn core::ToInputArray, src: &dyn core::To
 	output_array_arg!(dst);
 	input_array_arg!(mask);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_ximgproc_weightedMedianFilter_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_double_int_const__InputArrayR(joint.as_raw__InputArray(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), r, sigma, weight_type, mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_ximgproc_weightedMedianFilter_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_double_int_const__InputArrayR(joint.as_raw__InputArray(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), r, sigma, weight_type, mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `weighted_median_filter()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct WeightedMedianFilterBuilder<'a> {
+	joint: &'a dyn core::ToInputArray,
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	r: i32,
+	sigma: f64,
+	weight_type: i32,
+	mask: Option<&'a dyn core::ToInputArray>,
+}
+
+impl<'a> WeightedMedianFilterBuilder<'a> {
+	#[inline]
+	pub fn new(joint: &'a dyn core::ToInputArray, src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, r: i32, weight_type: i32) -> Self {
+		Self { joint, src, dst, r, sigma: 25.5, weight_type, mask: None }
+	}
+	
+	/// C++ default: `25.5`
+	#[inline]
+	pub fn with_sigma(mut self, sigma: f64) -> Self {
+		self.sigma = sigma;
+		self
+	}
+	
+	/// C++ default: `noArray()`
+	#[inline]
+	pub fn with_mask(mut self, mask: &'a dyn core::ToInputArray) -> Self {
+		self.mask = Some(mask);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let mask_no_array = core::no_array();
+		let mask: &dyn core::ToInputArray = match self.mask { Some(mask) => mask, None => &mask_no_array };
+		weighted_median_filter(self.joint, self.src, self.dst, self.r, self.sigma, self.weight_type, mask)
+	}
+}
+
 /// Interface for Adaptive Manifold Filter realizations.
 /// 
 /// For more details about this filter see [Gastal12](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_Gastal12) and References_.
@@ -2080,66 +2994,72 @@ pub trait AdaptiveManifoldFilterConst: core::AlgorithmTraitConst {
 	fn as_raw_AdaptiveManifoldFilter(&self) -> *const c_void;
 
 	/// ## See also
-	/// setSigmaS
+	/// `set_sigma_s`
 	#[inline]
 	fn get_sigma_s(&self) -> Result<f64> {
+		crate::core::validity::check("AdaptiveManifoldFilter", self.as_raw_AdaptiveManifoldFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_AdaptiveManifoldFilter_getSigmaS_const(self.as_raw_AdaptiveManifoldFilter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_AdaptiveManifoldFilter_getSigmaS_const(self.as_raw_AdaptiveManifoldFilter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setSigmaR
+	/// `set_sigma_r`
 	#[inline]
 	fn get_sigma_r(&self) -> Result<f64> {
+		crate::core::validity::check("AdaptiveManifoldFilter", self.as_raw_AdaptiveManifoldFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_AdaptiveManifoldFilter_getSigmaR_const(self.as_raw_AdaptiveManifoldFilter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_AdaptiveManifoldFilter_getSigmaR_const(self.as_raw_AdaptiveManifoldFilter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setTreeHeight
+	/// `set_tree_height`
 	#[inline]
 	fn get_tree_height(&self) -> Result<i32> {
+		crate::core::validity::check("AdaptiveManifoldFilter", self.as_raw_AdaptiveManifoldFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_AdaptiveManifoldFilter_getTreeHeight_const(self.as_raw_AdaptiveManifoldFilter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_AdaptiveManifoldFilter_getTreeHeight_const(self.as_raw_AdaptiveManifoldFilter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setPCAIterations
+	/// `set_pca_iterations`
 	#[inline]
 	fn get_pca_iterations(&self) -> Result<i32> {
+		crate::core::validity::check("AdaptiveManifoldFilter", self.as_raw_AdaptiveManifoldFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_AdaptiveManifoldFilter_getPCAIterations_const(self.as_raw_AdaptiveManifoldFilter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_AdaptiveManifoldFilter_getPCAIterations_const(self.as_raw_AdaptiveManifoldFilter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setAdjustOutliers
+	/// `set_adjust_outliers`
 	#[inline]
 	fn get_adjust_outliers(&self) -> Result<bool> {
+		crate::core::validity::check("AdaptiveManifoldFilter", self.as_raw_AdaptiveManifoldFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_AdaptiveManifoldFilter_getAdjustOutliers_const(self.as_raw_AdaptiveManifoldFilter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_AdaptiveManifoldFilter_getAdjustOutliers_const(self.as_raw_AdaptiveManifoldFilter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setUseRNG
+	/// `set_use_rng`
 	#[inline]
 	fn get_use_rng(&self) -> Result<bool> {
+		crate::core::validity::check("AdaptiveManifoldFilter", self.as_raw_AdaptiveManifoldFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_AdaptiveManifoldFilter_getUseRNG_const(self.as_raw_AdaptiveManifoldFilter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_AdaptiveManifoldFilter_getUseRNG_const(self.as_raw_AdaptiveManifoldFilter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2163,11 +3083,12 @@ pub trait AdaptiveManifoldFilter: core::AlgorithmTrait + crate::ximgproc::Adapti
 	/// * joint: noArray()
 	#[inline]
 	fn filter(&mut self, src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, joint: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("AdaptiveManifoldFilter", self.as_raw_mut_AdaptiveManifoldFilter())?;
 		input_array_arg!(src);
 		output_array_arg!(dst);
 		input_array_arg!(joint);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_AdaptiveManifoldFilter_filter_const__InputArrayR_const__OutputArrayR_const__InputArrayR(self.as_raw_mut_AdaptiveManifoldFilter(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), joint.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_AdaptiveManifoldFilter_filter_const__InputArrayR_const__OutputArrayR_const__InputArrayR(self.as_raw_mut_AdaptiveManifoldFilter(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), joint.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2175,8 +3096,9 @@ pub trait AdaptiveManifoldFilter: core::AlgorithmTrait + crate::ximgproc::Adapti
 	
 	#[inline]
 	fn collect_garbage(&mut self) -> Result<()> {
+		crate::core::validity::check("AdaptiveManifoldFilter", self.as_raw_mut_AdaptiveManifoldFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_AdaptiveManifoldFilter_collectGarbage(self.as_raw_mut_AdaptiveManifoldFilter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_AdaptiveManifoldFilter_collectGarbage(self.as_raw_mut_AdaptiveManifoldFilter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2186,8 +3108,9 @@ pub trait AdaptiveManifoldFilter: core::AlgorithmTrait + crate::ximgproc::Adapti
 	/// setSigmaS getSigmaS
 	#[inline]
 	fn set_sigma_s(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("AdaptiveManifoldFilter", self.as_raw_mut_AdaptiveManifoldFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_AdaptiveManifoldFilter_setSigmaS_double(self.as_raw_mut_AdaptiveManifoldFilter(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_AdaptiveManifoldFilter_setSigmaS_double(self.as_raw_mut_AdaptiveManifoldFilter(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2197,8 +3120,9 @@ pub trait AdaptiveManifoldFilter: core::AlgorithmTrait + crate::ximgproc::Adapti
 	/// setSigmaR getSigmaR
 	#[inline]
 	fn set_sigma_r(&mut self, val: f64) -> Result<()> {
+		crate::core::validity::check("AdaptiveManifoldFilter", self.as_raw_mut_AdaptiveManifoldFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_AdaptiveManifoldFilter_setSigmaR_double(self.as_raw_mut_AdaptiveManifoldFilter(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_AdaptiveManifoldFilter_setSigmaR_double(self.as_raw_mut_AdaptiveManifoldFilter(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2208,8 +3132,9 @@ pub trait AdaptiveManifoldFilter: core::AlgorithmTrait + crate::ximgproc::Adapti
 	/// setTreeHeight getTreeHeight
 	#[inline]
 	fn set_tree_height(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("AdaptiveManifoldFilter", self.as_raw_mut_AdaptiveManifoldFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_AdaptiveManifoldFilter_setTreeHeight_int(self.as_raw_mut_AdaptiveManifoldFilter(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_AdaptiveManifoldFilter_setTreeHeight_int(self.as_raw_mut_AdaptiveManifoldFilter(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2219,8 +3144,9 @@ pub trait AdaptiveManifoldFilter: core::AlgorithmTrait + crate::ximgproc::Adapti
 	/// setPCAIterations getPCAIterations
 	#[inline]
 	fn set_pca_iterations(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("AdaptiveManifoldFilter", self.as_raw_mut_AdaptiveManifoldFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_AdaptiveManifoldFilter_setPCAIterations_int(self.as_raw_mut_AdaptiveManifoldFilter(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_AdaptiveManifoldFilter_setPCAIterations_int(self.as_raw_mut_AdaptiveManifoldFilter(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2230,8 +3156,9 @@ pub trait AdaptiveManifoldFilter: core::AlgorithmTrait + crate::ximgproc::Adapti
 	/// setAdjustOutliers getAdjustOutliers
 	#[inline]
 	fn set_adjust_outliers(&mut self, val: bool) -> Result<()> {
+		crate::core::validity::check("AdaptiveManifoldFilter", self.as_raw_mut_AdaptiveManifoldFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_AdaptiveManifoldFilter_setAdjustOutliers_bool(self.as_raw_mut_AdaptiveManifoldFilter(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_AdaptiveManifoldFilter_setAdjustOutliers_bool(self.as_raw_mut_AdaptiveManifoldFilter(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2241,8 +3168,9 @@ pub trait AdaptiveManifoldFilter: core::AlgorithmTrait + crate::ximgproc::Adapti
 	/// setUseRNG getUseRNG
 	#[inline]
 	fn set_use_rng(&mut self, val: bool) -> Result<()> {
+		crate::core::validity::check("AdaptiveManifoldFilter", self.as_raw_mut_AdaptiveManifoldFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_AdaptiveManifoldFilter_setUseRNG_bool(self.as_raw_mut_AdaptiveManifoldFilter(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_AdaptiveManifoldFilter_setUseRNG_bool(self.as_raw_mut_AdaptiveManifoldFilter(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2254,7 +3182,7 @@ impl dyn AdaptiveManifoldFilter + '_ {
 	#[inline]
 	pub fn create() -> Result<core::Ptr<dyn crate::ximgproc::AdaptiveManifoldFilter>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_AdaptiveManifoldFilter_create(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_AdaptiveManifoldFilter_create(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::ximgproc::AdaptiveManifoldFilter>::opencv_from_extern(ret) };
@@ -2287,11 +3215,12 @@ pub trait ContourFittingTrait: core::AlgorithmTrait + crate::ximgproc::ContourFi
 	/// * fd_contour: false
 	#[inline]
 	fn estimate_transformation(&mut self, src: &dyn core::ToInputArray, dst: &dyn core::ToInputArray, alpha_phi_st: &mut dyn core::ToOutputArray, dist: &mut f64, fd_contour: bool) -> Result<()> {
+		crate::core::validity::check("ContourFitting", self.as_raw_mut_ContourFitting())?;
 		input_array_arg!(src);
 		input_array_arg!(dst);
 		output_array_arg!(alpha_phi_st);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_ContourFitting_estimateTransformation_const__InputArrayR_const__InputArrayR_const__OutputArrayR_doubleX_bool(self.as_raw_mut_ContourFitting(), src.as_raw__InputArray(), dst.as_raw__InputArray(), alpha_phi_st.as_raw__OutputArray(), dist, fd_contour, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_ContourFitting_estimateTransformation_const__InputArrayR_const__InputArrayR_const__OutputArrayR_doubleX_bool(self.as_raw_mut_ContourFitting(), src.as_raw__InputArray(), dst.as_raw__InputArray(), alpha_phi_st.as_raw__OutputArray(), dist, fd_contour, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2310,11 +3239,12 @@ pub trait ContourFittingTrait: core::AlgorithmTrait + crate::ximgproc::ContourFi
 	/// * fd_contour: false
 	#[inline]
 	fn estimate_transformation_1(&mut self, src: &dyn core::ToInputArray, dst: &dyn core::ToInputArray, alpha_phi_st: &mut dyn core::ToOutputArray, dist: &mut f64, fd_contour: bool) -> Result<()> {
+		crate::core::validity::check("ContourFitting", self.as_raw_mut_ContourFitting())?;
 		input_array_arg!(src);
 		input_array_arg!(dst);
 		output_array_arg!(alpha_phi_st);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_ContourFitting_estimateTransformation_const__InputArrayR_const__InputArrayR_const__OutputArrayR_doubleR_bool(self.as_raw_mut_ContourFitting(), src.as_raw__InputArray(), dst.as_raw__InputArray(), alpha_phi_st.as_raw__OutputArray(), dist, fd_contour, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_ContourFitting_estimateTransformation_const__InputArrayR_const__InputArrayR_const__OutputArrayR_doubleR_bool(self.as_raw_mut_ContourFitting(), src.as_raw__InputArray(), dst.as_raw__InputArray(), alpha_phi_st.as_raw__OutputArray(), dist, fd_contour, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2326,8 +3256,9 @@ pub trait ContourFittingTrait: core::AlgorithmTrait + crate::ximgproc::ContourFi
 	/// * n: number of Fourier descriptors equal to number of contour points after resampling.
 	#[inline]
 	fn set_ctr_size(&mut self, n: i32) -> Result<()> {
+		crate::core::validity::check("ContourFitting", self.as_raw_mut_ContourFitting())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_ContourFitting_setCtrSize_int(self.as_raw_mut_ContourFitting(), n, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_ContourFitting_setCtrSize_int(self.as_raw_mut_ContourFitting(), n, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2339,8 +3270,9 @@ pub trait ContourFittingTrait: core::AlgorithmTrait + crate::ximgproc::ContourFi
 	/// * n: number of fourier descriptors used for optimal curve matching.
 	#[inline]
 	fn set_fd_size(&mut self, n: i32) -> Result<()> {
+		crate::core::validity::check("ContourFitting", self.as_raw_mut_ContourFitting())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_ContourFitting_setFDSize_int(self.as_raw_mut_ContourFitting(), n, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_ContourFitting_setFDSize_int(self.as_raw_mut_ContourFitting(), n, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2350,8 +3282,9 @@ pub trait ContourFittingTrait: core::AlgorithmTrait + crate::ximgproc::ContourFi
 	/// number of fourier descriptors
 	#[inline]
 	fn get_ctr_size(&mut self) -> Result<i32> {
+		crate::core::validity::check("ContourFitting", self.as_raw_mut_ContourFitting())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_ContourFitting_getCtrSize(self.as_raw_mut_ContourFitting(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_ContourFitting_getCtrSize(self.as_raw_mut_ContourFitting(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2361,8 +3294,9 @@ pub trait ContourFittingTrait: core::AlgorithmTrait + crate::ximgproc::ContourFi
 	/// number of fourier descriptors used for optimal curve matching
 	#[inline]
 	fn get_fd_size(&mut self) -> Result<i32> {
+		crate::core::validity::check("ContourFitting", self.as_raw_mut_ContourFitting())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_ContourFitting_getFDSize(self.as_raw_mut_ContourFitting(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_ContourFitting_getFDSize(self.as_raw_mut_ContourFitting(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2382,7 +3316,10 @@ opencv_type_boxed! { ContourFitting }
 impl Drop for ContourFitting {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ContourFitting_delete(instance: *mut c_void); }
-		unsafe { cv_ContourFitting_delete(self.as_raw_mut_ContourFitting()) };
+		let ptr = self.as_raw_mut_ContourFitting();
+		crate::core::alloc_tracking::track_release(ptr);
+		crate::core::validity::track_drop(ptr);
+		unsafe { cv_ContourFitting_delete(ptr) };
 	}
 }
 
@@ -2417,7 +3354,7 @@ impl ContourFitting {
 	#[inline]
 	pub fn new(ctr: i32, fd: i32) -> Result<crate::ximgproc::ContourFitting> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_ContourFitting_ContourFitting_int_int(ctr, fd, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_ContourFitting_ContourFitting_int_int(ctr, fd, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::ximgproc::ContourFitting::opencv_from_extern(ret) };
@@ -2453,10 +3390,11 @@ pub trait DTFilter: core::AlgorithmTrait + crate::ximgproc::DTFilterConst {
 	/// * d_depth: -1
 	#[inline]
 	fn filter(&mut self, src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, d_depth: i32) -> Result<()> {
+		crate::core::validity::check("DTFilter", self.as_raw_mut_DTFilter())?;
 		input_array_arg!(src);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_DTFilter_filter_const__InputArrayR_const__OutputArrayR_int(self.as_raw_mut_DTFilter(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), d_depth, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_DTFilter_filter_const__InputArrayR_const__OutputArrayR_int(self.as_raw_mut_DTFilter(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), d_depth, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2499,13 +3437,14 @@ pub trait DisparityFilter: core::AlgorithmTrait + crate::ximgproc::DisparityFilt
 	/// * right_view: Mat()
 	#[inline]
 	fn filter(&mut self, disparity_map_left: &dyn core::ToInputArray, left_view: &dyn core::ToInputArray, filtered_disparity_map: &mut dyn core::ToOutputArray, disparity_map_right: &dyn core::ToInputArray, roi: core::Rect, right_view: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("DisparityFilter", self.as_raw_mut_DisparityFilter())?;
 		input_array_arg!(disparity_map_left);
 		input_array_arg!(left_view);
 		output_array_arg!(filtered_disparity_map);
 		input_array_arg!(disparity_map_right);
 		input_array_arg!(right_view);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_DisparityFilter_filter_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR_Rect_const__InputArrayR(self.as_raw_mut_DisparityFilter(), disparity_map_left.as_raw__InputArray(), left_view.as_raw__InputArray(), filtered_disparity_map.as_raw__OutputArray(), disparity_map_right.as_raw__InputArray(), roi.opencv_as_extern(), right_view.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_DisparityFilter_filter_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR_Rect_const__InputArrayR(self.as_raw_mut_DisparityFilter(), disparity_map_left.as_raw__InputArray(), left_view.as_raw__InputArray(), filtered_disparity_map.as_raw__OutputArray(), disparity_map_right.as_raw__InputArray(), roi.opencv_as_extern(), right_view.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2513,6 +3452,57 @@ pub trait DisparityFilter: core::AlgorithmTrait + crate::ximgproc::DisparityFilt
 	
 }
 
+/// Builder for `DisparityFilter::filter()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct DisparityFilter_FilterBuilder<'a, T: ?Sized + DisparityFilter> {
+	instance: &'a mut T,
+	disparity_map_left: &'a dyn core::ToInputArray,
+	left_view: &'a dyn core::ToInputArray,
+	filtered_disparity_map: &'a mut dyn core::ToOutputArray,
+	disparity_map_right: Option<&'a dyn core::ToInputArray>,
+	roi: core::Rect,
+	right_view: Option<&'a dyn core::ToInputArray>,
+}
+
+impl<'a, T: ?Sized + DisparityFilter> DisparityFilter_FilterBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, disparity_map_left: &'a dyn core::ToInputArray, left_view: &'a dyn core::ToInputArray, filtered_disparity_map: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { instance, disparity_map_left, left_view, filtered_disparity_map, disparity_map_right: None, roi: core::Rect::default(), right_view: None }
+	}
+	
+	/// C++ default: `Mat()`
+	#[inline]
+	pub fn with_disparity_map_right(mut self, disparity_map_right: &'a dyn core::ToInputArray) -> Self {
+		self.disparity_map_right = Some(disparity_map_right);
+		self
+	}
+	
+	/// C++ default: `Rect()`
+	#[inline]
+	pub fn with_roi(mut self, roi: core::Rect) -> Self {
+		self.roi = roi;
+		self
+	}
+	
+	/// C++ default: `Mat()`
+	#[inline]
+	pub fn with_right_view(mut self, right_view: &'a dyn core::ToInputArray) -> Self {
+		self.right_view = Some(right_view);
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		let disparity_map_right_no_array = core::no_array();
+		let disparity_map_right: &dyn core::ToInputArray = match self.disparity_map_right { Some(disparity_map_right) => disparity_map_right, None => &disparity_map_right_no_array };
+		let right_view_no_array = core::no_array();
+		let right_view: &dyn core::ToInputArray = match self.right_view { Some(right_view) => right_view, None => &right_view_no_array };
+		DisparityFilter::filter(self.instance, self.disparity_map_left, self.left_view, self.filtered_disparity_map, disparity_map_right, self.roi, right_view)
+	}
+}
+
 /// Disparity map filter based on Weighted Least Squares filter (in form of Fast Global Smoother that
 /// is a lot faster than traditional Weighted Least Squares filter implementations) and optional use of
 /// left-right-consistency-based confidence to refine the results in half-occlusions and uniform areas.
@@ -2528,19 +3518,21 @@ pub trait DisparityWLSFilter: crate::ximgproc::DisparityFilter + crate::ximgproc
 	/// filtered disparity map edges to adhere more to source image edges. Typical value is 8000.
 	#[inline]
 	fn get_lambda(&mut self) -> Result<f64> {
+		crate::core::validity::check("DisparityWLSFilter", self.as_raw_mut_DisparityWLSFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_DisparityWLSFilter_getLambda(self.as_raw_mut_DisparityWLSFilter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_DisparityWLSFilter_getLambda(self.as_raw_mut_DisparityWLSFilter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// getLambda
+	/// `get_lambda`
 	#[inline]
 	fn set_lambda(&mut self, _lambda: f64) -> Result<()> {
+		crate::core::validity::check("DisparityWLSFilter", self.as_raw_mut_DisparityWLSFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_DisparityWLSFilter_setLambda_double(self.as_raw_mut_DisparityWLSFilter(), _lambda, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_DisparityWLSFilter_setLambda_double(self.as_raw_mut_DisparityWLSFilter(), _lambda, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2551,19 +3543,21 @@ pub trait DisparityWLSFilter: crate::ximgproc::DisparityFilter + crate::ximgproc
 	/// sensitive to noise and textures in the source image. Typical values range from 0.8 to 2.0.
 	#[inline]
 	fn get_sigma_color(&mut self) -> Result<f64> {
+		crate::core::validity::check("DisparityWLSFilter", self.as_raw_mut_DisparityWLSFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_DisparityWLSFilter_getSigmaColor(self.as_raw_mut_DisparityWLSFilter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_DisparityWLSFilter_getSigmaColor(self.as_raw_mut_DisparityWLSFilter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// getSigmaColor
+	/// `get_sigma_color`
 	#[inline]
 	fn set_sigma_color(&mut self, _sigma_color: f64) -> Result<()> {
+		crate::core::validity::check("DisparityWLSFilter", self.as_raw_mut_DisparityWLSFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_DisparityWLSFilter_setSigmaColor_double(self.as_raw_mut_DisparityWLSFilter(), _sigma_color, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_DisparityWLSFilter_setSigmaColor_double(self.as_raw_mut_DisparityWLSFilter(), _sigma_color, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2573,19 +3567,21 @@ pub trait DisparityWLSFilter: crate::ximgproc::DisparityFilter + crate::ximgproc
 	/// confidence map computation. The default value of 24 (1.5 pixels) is virtually always good enough.
 	#[inline]
 	fn get_lr_cthresh(&mut self) -> Result<i32> {
+		crate::core::validity::check("DisparityWLSFilter", self.as_raw_mut_DisparityWLSFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_DisparityWLSFilter_getLRCthresh(self.as_raw_mut_DisparityWLSFilter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_DisparityWLSFilter_getLRCthresh(self.as_raw_mut_DisparityWLSFilter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// getLRCthresh
+	/// `get_lr_cthresh`
 	#[inline]
 	fn set_lr_cthresh(&mut self, _lrc_thresh: i32) -> Result<()> {
+		crate::core::validity::check("DisparityWLSFilter", self.as_raw_mut_DisparityWLSFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_DisparityWLSFilter_setLRCthresh_int(self.as_raw_mut_DisparityWLSFilter(), _lrc_thresh, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_DisparityWLSFilter_setLRCthresh_int(self.as_raw_mut_DisparityWLSFilter(), _lrc_thresh, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2595,19 +3591,21 @@ pub trait DisparityWLSFilter: crate::ximgproc::DisparityFilter + crate::ximgproc
 	/// low-confidence regions around depth discontinuities.
 	#[inline]
 	fn get_depth_discontinuity_radius(&mut self) -> Result<i32> {
+		crate::core::validity::check("DisparityWLSFilter", self.as_raw_mut_DisparityWLSFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_DisparityWLSFilter_getDepthDiscontinuityRadius(self.as_raw_mut_DisparityWLSFilter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_DisparityWLSFilter_getDepthDiscontinuityRadius(self.as_raw_mut_DisparityWLSFilter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// getDepthDiscontinuityRadius
+	/// `get_depth_discontinuity_radius`
 	#[inline]
 	fn set_depth_discontinuity_radius(&mut self, _disc_radius: i32) -> Result<()> {
+		crate::core::validity::check("DisparityWLSFilter", self.as_raw_mut_DisparityWLSFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_DisparityWLSFilter_setDepthDiscontinuityRadius_int(self.as_raw_mut_DisparityWLSFilter(), _disc_radius, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_DisparityWLSFilter_setDepthDiscontinuityRadius_int(self.as_raw_mut_DisparityWLSFilter(), _disc_radius, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2618,8 +3616,9 @@ pub trait DisparityWLSFilter: crate::ximgproc::DisparityFilter + crate::ximgproc
 	/// correct disparity values with a high degree of confidence).
 	#[inline]
 	fn get_confidence_map(&mut self) -> Result<core::Mat> {
+		crate::core::validity::check("DisparityWLSFilter", self.as_raw_mut_DisparityWLSFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_DisparityWLSFilter_getConfidenceMap(self.as_raw_mut_DisparityWLSFilter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_DisparityWLSFilter_getConfidenceMap(self.as_raw_mut_DisparityWLSFilter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -2629,8 +3628,9 @@ pub trait DisparityWLSFilter: crate::ximgproc::DisparityFilter + crate::ximgproc
 	/// Get the ROI used in the last filter call
 	#[inline]
 	fn get_roi(&mut self) -> Result<core::Rect> {
+		crate::core::validity::check("DisparityWLSFilter", self.as_raw_mut_DisparityWLSFilter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_DisparityWLSFilter_getROI(self.as_raw_mut_DisparityWLSFilter(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_DisparityWLSFilter_getROI(self.as_raw_mut_DisparityWLSFilter(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2659,8 +3659,9 @@ pub trait EdgeAwareInterpolator: crate::ximgproc::EdgeAwareInterpolatorConst + c
 	/// cv::ximgproc::createSuperpixelSLIC
 	#[inline]
 	fn set_cost_map(&mut self, _cost_map: &core::Mat) -> Result<()> {
+		crate::core::validity::check("EdgeAwareInterpolator", self.as_raw_mut_EdgeAwareInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeAwareInterpolator_setCostMap_const_MatR(self.as_raw_mut_EdgeAwareInterpolator(), _cost_map.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeAwareInterpolator_setCostMap_const_MatR(self.as_raw_mut_EdgeAwareInterpolator(), _cost_map.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2675,19 +3676,21 @@ pub trait EdgeAwareInterpolator: crate::ximgproc::EdgeAwareInterpolatorConst + c
 	///   noticeably faster.
 	#[inline]
 	fn set_k(&mut self, _k: i32) -> Result<()> {
+		crate::core::validity::check("EdgeAwareInterpolator", self.as_raw_mut_EdgeAwareInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeAwareInterpolator_setK_int(self.as_raw_mut_EdgeAwareInterpolator(), _k, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeAwareInterpolator_setK_int(self.as_raw_mut_EdgeAwareInterpolator(), _k, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setK
+	/// `set_k`
 	#[inline]
 	fn get_k(&mut self) -> Result<i32> {
+		crate::core::validity::check("EdgeAwareInterpolator", self.as_raw_mut_EdgeAwareInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeAwareInterpolator_getK(self.as_raw_mut_EdgeAwareInterpolator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeAwareInterpolator_getK(self.as_raw_mut_EdgeAwareInterpolator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2698,19 +3701,21 @@ pub trait EdgeAwareInterpolator: crate::ximgproc::EdgeAwareInterpolatorConst + c
 	/// output flow.
 	#[inline]
 	fn set_sigma(&mut self, _sigma: f32) -> Result<()> {
+		crate::core::validity::check("EdgeAwareInterpolator", self.as_raw_mut_EdgeAwareInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeAwareInterpolator_setSigma_float(self.as_raw_mut_EdgeAwareInterpolator(), _sigma, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeAwareInterpolator_setSigma_float(self.as_raw_mut_EdgeAwareInterpolator(), _sigma, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setSigma
+	/// `set_sigma`
 	#[inline]
 	fn get_sigma(&mut self) -> Result<f32> {
+		crate::core::validity::check("EdgeAwareInterpolator", self.as_raw_mut_EdgeAwareInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeAwareInterpolator_getSigma(self.as_raw_mut_EdgeAwareInterpolator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeAwareInterpolator_getSigma(self.as_raw_mut_EdgeAwareInterpolator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2720,19 +3725,21 @@ pub trait EdgeAwareInterpolator: crate::ximgproc::EdgeAwareInterpolatorConst + c
 	/// should be in the range of 0 to 1000.
 	#[inline]
 	fn set_lambda(&mut self, _lambda: f32) -> Result<()> {
+		crate::core::validity::check("EdgeAwareInterpolator", self.as_raw_mut_EdgeAwareInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeAwareInterpolator_setLambda_float(self.as_raw_mut_EdgeAwareInterpolator(), _lambda, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeAwareInterpolator_setLambda_float(self.as_raw_mut_EdgeAwareInterpolator(), _lambda, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setLambda
+	/// `set_lambda`
 	#[inline]
 	fn get_lambda(&mut self) -> Result<f32> {
+		crate::core::validity::check("EdgeAwareInterpolator", self.as_raw_mut_EdgeAwareInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeAwareInterpolator_getLambda(self.as_raw_mut_EdgeAwareInterpolator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeAwareInterpolator_getLambda(self.as_raw_mut_EdgeAwareInterpolator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2742,19 +3749,21 @@ pub trait EdgeAwareInterpolator: crate::ximgproc::EdgeAwareInterpolatorConst + c
 	/// default.
 	#[inline]
 	fn set_use_post_processing(&mut self, _use_post_proc: bool) -> Result<()> {
+		crate::core::validity::check("EdgeAwareInterpolator", self.as_raw_mut_EdgeAwareInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeAwareInterpolator_setUsePostProcessing_bool(self.as_raw_mut_EdgeAwareInterpolator(), _use_post_proc, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeAwareInterpolator_setUsePostProcessing_bool(self.as_raw_mut_EdgeAwareInterpolator(), _use_post_proc, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setUsePostProcessing
+	/// `set_use_post_processing`
 	#[inline]
 	fn get_use_post_processing(&mut self) -> Result<bool> {
+		crate::core::validity::check("EdgeAwareInterpolator", self.as_raw_mut_EdgeAwareInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeAwareInterpolator_getUsePostProcessing(self.as_raw_mut_EdgeAwareInterpolator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeAwareInterpolator_getUsePostProcessing(self.as_raw_mut_EdgeAwareInterpolator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2763,41 +3772,45 @@ pub trait EdgeAwareInterpolator: crate::ximgproc::EdgeAwareInterpolatorConst + c
 	/// Sets the respective fastGlobalSmootherFilter() parameter.
 	#[inline]
 	fn set_fgs_lambda(&mut self, _lambda: f32) -> Result<()> {
+		crate::core::validity::check("EdgeAwareInterpolator", self.as_raw_mut_EdgeAwareInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeAwareInterpolator_setFGSLambda_float(self.as_raw_mut_EdgeAwareInterpolator(), _lambda, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeAwareInterpolator_setFGSLambda_float(self.as_raw_mut_EdgeAwareInterpolator(), _lambda, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setFGSLambda
+	/// `set_fgs_lambda`
 	#[inline]
 	fn get_fgs_lambda(&mut self) -> Result<f32> {
+		crate::core::validity::check("EdgeAwareInterpolator", self.as_raw_mut_EdgeAwareInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeAwareInterpolator_getFGSLambda(self.as_raw_mut_EdgeAwareInterpolator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeAwareInterpolator_getFGSLambda(self.as_raw_mut_EdgeAwareInterpolator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setFGSLambda
+	/// `set_fgs_lambda`
 	#[inline]
 	fn set_fgs_sigma(&mut self, _sigma: f32) -> Result<()> {
+		crate::core::validity::check("EdgeAwareInterpolator", self.as_raw_mut_EdgeAwareInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeAwareInterpolator_setFGSSigma_float(self.as_raw_mut_EdgeAwareInterpolator(), _sigma, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeAwareInterpolator_setFGSSigma_float(self.as_raw_mut_EdgeAwareInterpolator(), _sigma, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 	/// ## See also
-	/// setFGSLambda
+	/// `set_fgs_lambda`
 	#[inline]
 	fn get_fgs_sigma(&mut self) -> Result<f32> {
+		crate::core::validity::check("EdgeAwareInterpolator", self.as_raw_mut_EdgeAwareInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeAwareInterpolator_getFGSSigma(self.as_raw_mut_EdgeAwareInterpolator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeAwareInterpolator_getFGSSigma(self.as_raw_mut_EdgeAwareInterpolator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2812,8 +3825,9 @@ pub trait EdgeBoxesConst: core::AlgorithmTraitConst {
 	/// Returns the step size of sliding window search.
 	#[inline]
 	fn get_alpha(&self) -> Result<f32> {
+		crate::core::validity::check("EdgeBoxes", self.as_raw_EdgeBoxes())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeBoxes_getAlpha_const(self.as_raw_EdgeBoxes(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeBoxes_getAlpha_const(self.as_raw_EdgeBoxes(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2822,8 +3836,9 @@ pub trait EdgeBoxesConst: core::AlgorithmTraitConst {
 	/// Returns the nms threshold for object proposals.
 	#[inline]
 	fn get_beta(&self) -> Result<f32> {
+		crate::core::validity::check("EdgeBoxes", self.as_raw_EdgeBoxes())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeBoxes_getBeta_const(self.as_raw_EdgeBoxes(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeBoxes_getBeta_const(self.as_raw_EdgeBoxes(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2832,8 +3847,9 @@ pub trait EdgeBoxesConst: core::AlgorithmTraitConst {
 	/// Returns adaptation rate for nms threshold.
 	#[inline]
 	fn get_eta(&self) -> Result<f32> {
+		crate::core::validity::check("EdgeBoxes", self.as_raw_EdgeBoxes())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeBoxes_getEta_const(self.as_raw_EdgeBoxes(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeBoxes_getEta_const(self.as_raw_EdgeBoxes(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2842,8 +3858,9 @@ pub trait EdgeBoxesConst: core::AlgorithmTraitConst {
 	/// Returns the min score of boxes to detect.
 	#[inline]
 	fn get_min_score(&self) -> Result<f32> {
+		crate::core::validity::check("EdgeBoxes", self.as_raw_EdgeBoxes())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeBoxes_getMinScore_const(self.as_raw_EdgeBoxes(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeBoxes_getMinScore_const(self.as_raw_EdgeBoxes(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2852,8 +3869,9 @@ pub trait EdgeBoxesConst: core::AlgorithmTraitConst {
 	/// Returns the max number of boxes to detect.
 	#[inline]
 	fn get_max_boxes(&self) -> Result<i32> {
+		crate::core::validity::check("EdgeBoxes", self.as_raw_EdgeBoxes())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeBoxes_getMaxBoxes_const(self.as_raw_EdgeBoxes(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeBoxes_getMaxBoxes_const(self.as_raw_EdgeBoxes(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2862,8 +3880,9 @@ pub trait EdgeBoxesConst: core::AlgorithmTraitConst {
 	/// Returns the edge min magnitude.
 	#[inline]
 	fn get_edge_min_mag(&self) -> Result<f32> {
+		crate::core::validity::check("EdgeBoxes", self.as_raw_EdgeBoxes())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeBoxes_getEdgeMinMag_const(self.as_raw_EdgeBoxes(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeBoxes_getEdgeMinMag_const(self.as_raw_EdgeBoxes(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2872,8 +3891,9 @@ pub trait EdgeBoxesConst: core::AlgorithmTraitConst {
 	/// Returns the edge merge threshold.
 	#[inline]
 	fn get_edge_merge_thr(&self) -> Result<f32> {
+		crate::core::validity::check("EdgeBoxes", self.as_raw_EdgeBoxes())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeBoxes_getEdgeMergeThr_const(self.as_raw_EdgeBoxes(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeBoxes_getEdgeMergeThr_const(self.as_raw_EdgeBoxes(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2882,8 +3902,9 @@ pub trait EdgeBoxesConst: core::AlgorithmTraitConst {
 	/// Returns the cluster min magnitude.
 	#[inline]
 	fn get_cluster_min_mag(&self) -> Result<f32> {
+		crate::core::validity::check("EdgeBoxes", self.as_raw_EdgeBoxes())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeBoxes_getClusterMinMag_const(self.as_raw_EdgeBoxes(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeBoxes_getClusterMinMag_const(self.as_raw_EdgeBoxes(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2892,8 +3913,9 @@ pub trait EdgeBoxesConst: core::AlgorithmTraitConst {
 	/// Returns the max aspect ratio of boxes.
 	#[inline]
 	fn get_max_aspect_ratio(&self) -> Result<f32> {
+		crate::core::validity::check("EdgeBoxes", self.as_raw_EdgeBoxes())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeBoxes_getMaxAspectRatio_const(self.as_raw_EdgeBoxes(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeBoxes_getMaxAspectRatio_const(self.as_raw_EdgeBoxes(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2902,8 +3924,9 @@ pub trait EdgeBoxesConst: core::AlgorithmTraitConst {
 	/// Returns the minimum area of boxes.
 	#[inline]
 	fn get_min_box_area(&self) -> Result<f32> {
+		crate::core::validity::check("EdgeBoxes", self.as_raw_EdgeBoxes())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeBoxes_getMinBoxArea_const(self.as_raw_EdgeBoxes(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeBoxes_getMinBoxArea_const(self.as_raw_EdgeBoxes(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2912,8 +3935,9 @@ pub trait EdgeBoxesConst: core::AlgorithmTraitConst {
 	/// Returns the affinity sensitivity.
 	#[inline]
 	fn get_gamma(&self) -> Result<f32> {
+		crate::core::validity::check("EdgeBoxes", self.as_raw_EdgeBoxes())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeBoxes_getGamma_const(self.as_raw_EdgeBoxes(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeBoxes_getGamma_const(self.as_raw_EdgeBoxes(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2922,8 +3946,9 @@ pub trait EdgeBoxesConst: core::AlgorithmTraitConst {
 	/// Returns the scale sensitivity.
 	#[inline]
 	fn get_kappa(&self) -> Result<f32> {
+		crate::core::validity::check("EdgeBoxes", self.as_raw_EdgeBoxes())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeBoxes_getKappa_const(self.as_raw_EdgeBoxes(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeBoxes_getKappa_const(self.as_raw_EdgeBoxes(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2946,11 +3971,12 @@ pub trait EdgeBoxes: core::AlgorithmTrait + crate::ximgproc::EdgeBoxesConst {
 	/// * scores: noArray()
 	#[inline]
 	fn get_bounding_boxes(&mut self, edge_map: &dyn core::ToInputArray, orientation_map: &dyn core::ToInputArray, boxes: &mut core::Vector<core::Rect>, scores: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("EdgeBoxes", self.as_raw_mut_EdgeBoxes())?;
 		input_array_arg!(edge_map);
 		input_array_arg!(orientation_map);
 		output_array_arg!(scores);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeBoxes_getBoundingBoxes_const__InputArrayR_const__InputArrayR_vector_Rect_R_const__OutputArrayR(self.as_raw_mut_EdgeBoxes(), edge_map.as_raw__InputArray(), orientation_map.as_raw__InputArray(), boxes.as_raw_mut_VectorOfRect(), scores.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeBoxes_getBoundingBoxes_const__InputArrayR_const__InputArrayR_vector_Rect_R_const__OutputArrayR(self.as_raw_mut_EdgeBoxes(), edge_map.as_raw__InputArray(), orientation_map.as_raw__InputArray(), boxes.as_raw_mut_VectorOfRect(), scores.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2959,8 +3985,9 @@ pub trait EdgeBoxes: core::AlgorithmTrait + crate::ximgproc::EdgeBoxesConst {
 	/// Sets the step size of sliding window search.
 	#[inline]
 	fn set_alpha(&mut self, value: f32) -> Result<()> {
+		crate::core::validity::check("EdgeBoxes", self.as_raw_mut_EdgeBoxes())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeBoxes_setAlpha_float(self.as_raw_mut_EdgeBoxes(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeBoxes_setAlpha_float(self.as_raw_mut_EdgeBoxes(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2969,8 +3996,9 @@ pub trait EdgeBoxes: core::AlgorithmTrait + crate::ximgproc::EdgeBoxesConst {
 	/// Sets the nms threshold for object proposals.
 	#[inline]
 	fn set_beta(&mut self, value: f32) -> Result<()> {
+		crate::core::validity::check("EdgeBoxes", self.as_raw_mut_EdgeBoxes())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeBoxes_setBeta_float(self.as_raw_mut_EdgeBoxes(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeBoxes_setBeta_float(self.as_raw_mut_EdgeBoxes(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2979,8 +4007,9 @@ pub trait EdgeBoxes: core::AlgorithmTrait + crate::ximgproc::EdgeBoxesConst {
 	/// Sets the adaptation rate for nms threshold.
 	#[inline]
 	fn set_eta(&mut self, value: f32) -> Result<()> {
+		crate::core::validity::check("EdgeBoxes", self.as_raw_mut_EdgeBoxes())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeBoxes_setEta_float(self.as_raw_mut_EdgeBoxes(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeBoxes_setEta_float(self.as_raw_mut_EdgeBoxes(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2989,8 +4018,9 @@ pub trait EdgeBoxes: core::AlgorithmTrait + crate::ximgproc::EdgeBoxesConst {
 	/// Sets the min score of boxes to detect.
 	#[inline]
 	fn set_min_score(&mut self, value: f32) -> Result<()> {
+		crate::core::validity::check("EdgeBoxes", self.as_raw_mut_EdgeBoxes())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeBoxes_setMinScore_float(self.as_raw_mut_EdgeBoxes(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeBoxes_setMinScore_float(self.as_raw_mut_EdgeBoxes(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -2999,8 +4029,9 @@ pub trait EdgeBoxes: core::AlgorithmTrait + crate::ximgproc::EdgeBoxesConst {
 	/// Sets max number of boxes to detect.
 	#[inline]
 	fn set_max_boxes(&mut self, value: i32) -> Result<()> {
+		crate::core::validity::check("EdgeBoxes", self.as_raw_mut_EdgeBoxes())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeBoxes_setMaxBoxes_int(self.as_raw_mut_EdgeBoxes(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeBoxes_setMaxBoxes_int(self.as_raw_mut_EdgeBoxes(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3009,8 +4040,9 @@ pub trait EdgeBoxes: core::AlgorithmTrait + crate::ximgproc::EdgeBoxesConst {
 	/// Sets the edge min magnitude.
 	#[inline]
 	fn set_edge_min_mag(&mut self, value: f32) -> Result<()> {
+		crate::core::validity::check("EdgeBoxes", self.as_raw_mut_EdgeBoxes())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeBoxes_setEdgeMinMag_float(self.as_raw_mut_EdgeBoxes(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeBoxes_setEdgeMinMag_float(self.as_raw_mut_EdgeBoxes(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3019,8 +4051,9 @@ pub trait EdgeBoxes: core::AlgorithmTrait + crate::ximgproc::EdgeBoxesConst {
 	/// Sets the edge merge threshold.
 	#[inline]
 	fn set_edge_merge_thr(&mut self, value: f32) -> Result<()> {
+		crate::core::validity::check("EdgeBoxes", self.as_raw_mut_EdgeBoxes())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeBoxes_setEdgeMergeThr_float(self.as_raw_mut_EdgeBoxes(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeBoxes_setEdgeMergeThr_float(self.as_raw_mut_EdgeBoxes(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3029,8 +4062,9 @@ pub trait EdgeBoxes: core::AlgorithmTrait + crate::ximgproc::EdgeBoxesConst {
 	/// Sets the cluster min magnitude.
 	#[inline]
 	fn set_cluster_min_mag(&mut self, value: f32) -> Result<()> {
+		crate::core::validity::check("EdgeBoxes", self.as_raw_mut_EdgeBoxes())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeBoxes_setClusterMinMag_float(self.as_raw_mut_EdgeBoxes(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeBoxes_setClusterMinMag_float(self.as_raw_mut_EdgeBoxes(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3039,8 +4073,9 @@ pub trait EdgeBoxes: core::AlgorithmTrait + crate::ximgproc::EdgeBoxesConst {
 	/// Sets the max aspect ratio of boxes.
 	#[inline]
 	fn set_max_aspect_ratio(&mut self, value: f32) -> Result<()> {
+		crate::core::validity::check("EdgeBoxes", self.as_raw_mut_EdgeBoxes())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeBoxes_setMaxAspectRatio_float(self.as_raw_mut_EdgeBoxes(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeBoxes_setMaxAspectRatio_float(self.as_raw_mut_EdgeBoxes(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3049,8 +4084,9 @@ pub trait EdgeBoxes: core::AlgorithmTrait + crate::ximgproc::EdgeBoxesConst {
 	/// Sets the minimum area of boxes.
 	#[inline]
 	fn set_min_box_area(&mut self, value: f32) -> Result<()> {
+		crate::core::validity::check("EdgeBoxes", self.as_raw_mut_EdgeBoxes())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeBoxes_setMinBoxArea_float(self.as_raw_mut_EdgeBoxes(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeBoxes_setMinBoxArea_float(self.as_raw_mut_EdgeBoxes(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3059,8 +4095,9 @@ pub trait EdgeBoxes: core::AlgorithmTrait + crate::ximgproc::EdgeBoxesConst {
 	/// Sets the affinity sensitivity
 	#[inline]
 	fn set_gamma(&mut self, value: f32) -> Result<()> {
+		crate::core::validity::check("EdgeBoxes", self.as_raw_mut_EdgeBoxes())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeBoxes_setGamma_float(self.as_raw_mut_EdgeBoxes(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeBoxes_setGamma_float(self.as_raw_mut_EdgeBoxes(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3069,8 +4106,9 @@ pub trait EdgeBoxes: core::AlgorithmTrait + crate::ximgproc::EdgeBoxesConst {
 	/// Sets the scale sensitivity.
 	#[inline]
 	fn set_kappa(&mut self, value: f32) -> Result<()> {
+		crate::core::validity::check("EdgeBoxes", self.as_raw_mut_EdgeBoxes())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeBoxes_setKappa_float(self.as_raw_mut_EdgeBoxes(), value, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeBoxes_setKappa_float(self.as_raw_mut_EdgeBoxes(), value, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3084,17 +4122,27 @@ pub trait EdgeDrawingConst: core::AlgorithmTraitConst {
 
 	#[inline]
 	fn params(&self) -> crate::ximgproc::EdgeDrawing_Params {
+		crate::core::validity::debug_check("EdgeDrawing", self.as_raw_EdgeDrawing());
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeDrawing_getPropParams_const(self.as_raw_EdgeDrawing(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeDrawing_getPropParams_const(self.as_raw_EdgeDrawing(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		ret
 	}
 	
+	/// Non-panicking version of [params](EdgeDrawingConst::params), fails with `StsNullPtr` if the object pointer is null or
+	/// with `StsObjectNotFound` if the object was already released (see `core::validity`)
+	#[inline]
+	fn try_params(&self) -> Result<crate::ximgproc::EdgeDrawing_Params> {
+		crate::core::validity::check("EdgeDrawing", self.as_raw_EdgeDrawing())?;
+		Ok(EdgeDrawingConst::params(self))
+	}
+	
 	/// Returns for each line found in detectLines() its edge segment index in getSegments()
 	#[inline]
 	fn get_segment_indices_of_lines(&self) -> Result<core::Vector<i32>> {
+		crate::core::validity::check("EdgeDrawing", self.as_raw_EdgeDrawing())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeDrawing_getSegmentIndicesOfLines_const(self.as_raw_EdgeDrawing(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeDrawing_getSegmentIndicesOfLines_const(self.as_raw_EdgeDrawing(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<i32>::opencv_from_extern(ret) };
@@ -3108,7 +4156,8 @@ pub trait EdgeDrawing: core::AlgorithmTrait + crate::ximgproc::EdgeDrawingConst
 
 	#[inline]
 	fn set_params(&mut self, val: crate::ximgproc::EdgeDrawing_Params) {
-		let ret = unsafe { sys::cv_ximgproc_EdgeDrawing_setPropParams_Params(self.as_raw_mut_EdgeDrawing(), val.opencv_as_extern()) };
+		crate::core::validity::debug_check("EdgeDrawing", self.as_raw_mut_EdgeDrawing());
+		let ret = unsafe { ffi_call!(cv_ximgproc_EdgeDrawing_setPropParams_Params(self.as_raw_mut_EdgeDrawing(), val.opencv_as_extern())) };
 		ret
 	}
 	
@@ -3118,9 +4167,10 @@ pub trait EdgeDrawing: core::AlgorithmTrait + crate::ximgproc::EdgeDrawingConst
 	/// * src: input image
 	#[inline]
 	fn detect_edges(&mut self, src: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("EdgeDrawing", self.as_raw_mut_EdgeDrawing())?;
 		input_array_arg!(src);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeDrawing_detectEdges_const__InputArrayR(self.as_raw_mut_EdgeDrawing(), src.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeDrawing_detectEdges_const__InputArrayR(self.as_raw_mut_EdgeDrawing(), src.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3128,9 +4178,10 @@ pub trait EdgeDrawing: core::AlgorithmTrait + crate::ximgproc::EdgeDrawingConst
 	
 	#[inline]
 	fn get_edge_image(&mut self, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("EdgeDrawing", self.as_raw_mut_EdgeDrawing())?;
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeDrawing_getEdgeImage_const__OutputArrayR(self.as_raw_mut_EdgeDrawing(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeDrawing_getEdgeImage_const__OutputArrayR(self.as_raw_mut_EdgeDrawing(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3138,9 +4189,10 @@ pub trait EdgeDrawing: core::AlgorithmTrait + crate::ximgproc::EdgeDrawingConst
 	
 	#[inline]
 	fn get_gradient_image(&mut self, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("EdgeDrawing", self.as_raw_mut_EdgeDrawing())?;
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeDrawing_getGradientImage_const__OutputArrayR(self.as_raw_mut_EdgeDrawing(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeDrawing_getGradientImage_const__OutputArrayR(self.as_raw_mut_EdgeDrawing(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3149,8 +4201,9 @@ pub trait EdgeDrawing: core::AlgorithmTrait + crate::ximgproc::EdgeDrawingConst
 	/// Returns std::vector<std::vector<Point>> of detected edge segments, see detectEdges()
 	#[inline]
 	fn get_segments(&mut self) -> Result<core::Vector<core::Vector<core::Point>>> {
+		crate::core::validity::check("EdgeDrawing", self.as_raw_mut_EdgeDrawing())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeDrawing_getSegments(self.as_raw_mut_EdgeDrawing(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeDrawing_getSegments(self.as_raw_mut_EdgeDrawing(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Vector::<core::Vector<core::Point>>::opencv_from_extern(ret) };
@@ -3165,9 +4218,10 @@ pub trait EdgeDrawing: core::AlgorithmTrait + crate::ximgproc::EdgeDrawingConst
 	/// Note: you should call detectEdges() method before call this.
 	#[inline]
 	fn detect_lines(&mut self, lines: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("EdgeDrawing", self.as_raw_mut_EdgeDrawing())?;
 		output_array_arg!(lines);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeDrawing_detectLines_const__OutputArrayR(self.as_raw_mut_EdgeDrawing(), lines.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeDrawing_detectLines_const__OutputArrayR(self.as_raw_mut_EdgeDrawing(), lines.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3181,9 +4235,10 @@ pub trait EdgeDrawing: core::AlgorithmTrait + crate::ximgproc::EdgeDrawingConst
 	/// Note: you should call detectEdges() method before call this.
 	#[inline]
 	fn detect_ellipses(&mut self, ellipses: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("EdgeDrawing", self.as_raw_mut_EdgeDrawing())?;
 		output_array_arg!(ellipses);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeDrawing_detectEllipses_const__OutputArrayR(self.as_raw_mut_EdgeDrawing(), ellipses.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeDrawing_detectEllipses_const__OutputArrayR(self.as_raw_mut_EdgeDrawing(), ellipses.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3194,8 +4249,9 @@ pub trait EdgeDrawing: core::AlgorithmTrait + crate::ximgproc::EdgeDrawingConst
 	/// this function is meant to be used for parameter setting in other languages than c++.
 	#[inline]
 	fn set_params_1(&mut self, parameters: crate::ximgproc::EdgeDrawing_Params) -> Result<()> {
+		crate::core::validity::check("EdgeDrawing", self.as_raw_mut_EdgeDrawing())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeDrawing_setParams_const_ParamsR(self.as_raw_mut_EdgeDrawing(), &parameters, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeDrawing_setParams_const_ParamsR(self.as_raw_mut_EdgeDrawing(), &parameters, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3235,7 +4291,7 @@ impl EdgeDrawing_Params {
 	#[inline]
 	pub fn write(self, fs: &mut core::FileStorage) -> Result<()> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeDrawing_Params_write_const_FileStorageR(self.opencv_as_extern(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeDrawing_Params_write_const_FileStorageR(self.opencv_as_extern(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3244,7 +4300,7 @@ impl EdgeDrawing_Params {
 	#[inline]
 	pub fn default() -> Result<crate::ximgproc::EdgeDrawing_Params> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeDrawing_Params_Params(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeDrawing_Params_Params(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3253,7 +4309,7 @@ impl EdgeDrawing_Params {
 	#[inline]
 	pub fn read(self, fn_: &core::FileNode) -> Result<()> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_EdgeDrawing_Params_read_const_FileNodeR(self.opencv_as_extern(), fn_.as_raw_FileNode(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_EdgeDrawing_Params_read_const_FileNodeR(self.opencv_as_extern(), fn_.as_raw_FileNode(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3282,14 +4338,15 @@ pub trait FastBilateralSolverFilter: core::AlgorithmTrait + crate::ximgproc::Fas
 	/// * dst: destination image.
 	/// 
 	/// 
-	/// Note: Confidence images with CV_8U depth are expected to in [0, 255] and CV_32F in [0, 1] range.
+	/// Note: Confidence images with CV_8U depth are expected to in \[0, 255\] and CV_32F in \[0, 1\] range.
 	#[inline]
 	fn filter(&mut self, src: &dyn core::ToInputArray, confidence: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("FastBilateralSolverFilter", self.as_raw_mut_FastBilateralSolverFilter())?;
 		input_array_arg!(src);
 		input_array_arg!(confidence);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_FastBilateralSolverFilter_filter_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_FastBilateralSolverFilter(), src.as_raw__InputArray(), confidence.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_FastBilateralSolverFilter_filter_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_FastBilateralSolverFilter(), src.as_raw__InputArray(), confidence.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3316,10 +4373,11 @@ pub trait FastGlobalSmootherFilter: core::AlgorithmTrait + crate::ximgproc::Fast
 	/// * dst: destination image.
 	#[inline]
 	fn filter(&mut self, src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("FastGlobalSmootherFilter", self.as_raw_mut_FastGlobalSmootherFilter())?;
 		input_array_arg!(src);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_FastGlobalSmootherFilter_filter_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_FastGlobalSmootherFilter(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_FastGlobalSmootherFilter_filter_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_FastGlobalSmootherFilter(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3355,10 +4413,11 @@ pub trait FastLineDetector: core::AlgorithmTrait + crate::ximgproc::FastLineDete
 	///       brighter side is on their left.
 	#[inline]
 	fn detect(&mut self, image: &dyn core::ToInputArray, lines: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("FastLineDetector", self.as_raw_mut_FastLineDetector())?;
 		input_array_arg!(image);
 		output_array_arg!(lines);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_FastLineDetector_detect_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_FastLineDetector(), image.as_raw__InputArray(), lines.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_FastLineDetector_detect_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_FastLineDetector(), image.as_raw__InputArray(), lines.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3379,10 +4438,11 @@ pub trait FastLineDetector: core::AlgorithmTrait + crate::ximgproc::FastLineDete
 	/// * linethickness: 1
 	#[inline]
 	fn draw_segments(&mut self, image: &mut dyn core::ToInputOutputArray, lines: &dyn core::ToInputArray, draw_arrow: bool, linecolor: core::Scalar, linethickness: i32) -> Result<()> {
+		crate::core::validity::check("FastLineDetector", self.as_raw_mut_FastLineDetector())?;
 		input_output_array_arg!(image);
 		input_array_arg!(lines);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_FastLineDetector_drawSegments_const__InputOutputArrayR_const__InputArrayR_bool_Scalar_int(self.as_raw_mut_FastLineDetector(), image.as_raw__InputOutputArray(), lines.as_raw__InputArray(), draw_arrow, linecolor.opencv_as_extern(), linethickness, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_FastLineDetector_drawSegments_const__InputOutputArrayR_const__InputArrayR_bool_Scalar_int(self.as_raw_mut_FastLineDetector(), image.as_raw__InputOutputArray(), lines.as_raw__InputArray(), draw_arrow, linecolor.opencv_as_extern(), linethickness, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3390,6 +4450,52 @@ pub trait FastLineDetector: core::AlgorithmTrait + crate::ximgproc::FastLineDete
 	
 }
 
+/// Builder for `FastLineDetector::draw_segments()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct FastLineDetector_DrawSegmentsBuilder<'a, T: ?Sized + FastLineDetector> {
+	instance: &'a mut T,
+	image: &'a mut dyn core::ToInputOutputArray,
+	lines: &'a dyn core::ToInputArray,
+	draw_arrow: bool,
+	linecolor: core::Scalar,
+	linethickness: i32,
+}
+
+impl<'a, T: ?Sized + FastLineDetector> FastLineDetector_DrawSegmentsBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T, image: &'a mut dyn core::ToInputOutputArray, lines: &'a dyn core::ToInputArray) -> Self {
+		Self { instance, image, lines, draw_arrow: false, linecolor: core::Scalar::new(0., 0., 255., 0.), linethickness: 1 }
+	}
+	
+	/// C++ default: `false`
+	#[inline]
+	pub fn with_draw_arrow(mut self, draw_arrow: bool) -> Self {
+		self.draw_arrow = draw_arrow;
+		self
+	}
+	
+	/// C++ default: `Scalar(0,0,255)`
+	#[inline]
+	pub fn with_linecolor(mut self, linecolor: core::Scalar) -> Self {
+		self.linecolor = linecolor;
+		self
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_linethickness(mut self, linethickness: i32) -> Self {
+		self.linethickness = linethickness;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		FastLineDetector::draw_segments(self.instance, self.image, self.lines, self.draw_arrow, self.linecolor, self.linethickness)
+	}
+}
+
 /// Interface for realizations of Guided Filter.
 /// 
 /// For more details about this filter see [Kaiming10](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_Kaiming10) .
@@ -3415,10 +4521,11 @@ pub trait GuidedFilter: core::AlgorithmTrait + crate::ximgproc::GuidedFilterCons
 	/// * d_depth: -1
 	#[inline]
 	fn filter(&mut self, src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, d_depth: i32) -> Result<()> {
+		crate::core::validity::check("GuidedFilter", self.as_raw_mut_GuidedFilter())?;
 		input_array_arg!(src);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_GuidedFilter_filter_const__InputArrayR_const__OutputArrayR_int(self.as_raw_mut_GuidedFilter(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), d_depth, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_GuidedFilter_filter_const__InputArrayR_const__OutputArrayR_int(self.as_raw_mut_GuidedFilter(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), d_depth, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3427,7 +4534,7 @@ pub trait GuidedFilter: core::AlgorithmTrait + crate::ximgproc::GuidedFilterCons
 }
 
 /// !
-/// Helper class for training part of [P. Dollar and C. L. Zitnick. Structured Forests for Fast Edge Detection, 2013].
+/// Helper class for training part of \[P. Dollar and C. L. Zitnick. Structured Forests for Fast Edge Detection, 2013\].
 pub trait RFFeatureGetterConst: core::AlgorithmTraitConst {
 	fn as_raw_RFFeatureGetter(&self) -> *const c_void;
 
@@ -3446,8 +4553,9 @@ pub trait RFFeatureGetterConst: core::AlgorithmTraitConst {
 	/// * \param gradNum : __rf.options.numberOfGradientOrientations
 	#[inline]
 	fn get_features(&self, src: &core::Mat, features: &mut core::Mat, gnrm_rad: i32, gsmth_rad: i32, shrink: i32, out_num: i32, grad_num: i32) -> Result<()> {
+		crate::core::validity::check("RFFeatureGetter", self.as_raw_RFFeatureGetter())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_RFFeatureGetter_getFeatures_const_const_MatR_MatR_const_int_const_int_const_int_const_int_const_int(self.as_raw_RFFeatureGetter(), src.as_raw_Mat(), features.as_raw_mut_Mat(), gnrm_rad, gsmth_rad, shrink, out_num, grad_num, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_RFFeatureGetter_getFeatures_const_const_MatR_MatR_const_int_const_int_const_int_const_int_const_int(self.as_raw_RFFeatureGetter(), src.as_raw_Mat(), features.as_raw_mut_Mat(), gnrm_rad, gsmth_rad, shrink, out_num, grad_num, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3474,8 +4582,9 @@ pub trait RICInterpolatorConst: crate::ximgproc::SparseMatchInterpolatorConst {
 	///      *  see also: setK
 	#[inline]
 	fn get_k(&self) -> Result<i32> {
+		crate::core::validity::check("RICInterpolator", self.as_raw_RICInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_RICInterpolator_getK_const(self.as_raw_RICInterpolator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_RICInterpolator_getK_const(self.as_raw_RICInterpolator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3483,12 +4592,13 @@ pub trait RICInterpolatorConst: crate::ximgproc::SparseMatchInterpolatorConst {
 	
 	/// Get the internal cost, i.e. edge map, used for estimating the edge-aware term.
 	/// ## See also
-	/// setCostMap
+	/// `set_cost_map`
 	///      *  setSuperpixelSize
 	#[inline]
 	fn get_superpixel_size(&self) -> Result<i32> {
+		crate::core::validity::check("RICInterpolator", self.as_raw_RICInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_RICInterpolator_getSuperpixelSize_const(self.as_raw_RICInterpolator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_RICInterpolator_getSuperpixelSize_const(self.as_raw_RICInterpolator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3499,8 +4609,9 @@ pub trait RICInterpolatorConst: crate::ximgproc::SparseMatchInterpolatorConst {
 	///      *  see also: setSuperpixelNNCnt
 	#[inline]
 	fn get_superpixel_nn_cnt(&self) -> Result<i32> {
+		crate::core::validity::check("RICInterpolator", self.as_raw_RICInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_RICInterpolator_getSuperpixelNNCnt_const(self.as_raw_RICInterpolator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_RICInterpolator_getSuperpixelNNCnt_const(self.as_raw_RICInterpolator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3512,8 +4623,9 @@ pub trait RICInterpolatorConst: crate::ximgproc::SparseMatchInterpolatorConst {
 	///      *  setSuperpixelRuler
 	#[inline]
 	fn get_superpixel_ruler(&self) -> Result<f32> {
+		crate::core::validity::check("RICInterpolator", self.as_raw_RICInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_RICInterpolator_getSuperpixelRuler_const(self.as_raw_RICInterpolator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_RICInterpolator_getSuperpixelRuler_const(self.as_raw_RICInterpolator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3528,8 +4640,9 @@ pub trait RICInterpolatorConst: crate::ximgproc::SparseMatchInterpolatorConst {
 	///      *  setSuperpixelMode
 	#[inline]
 	fn get_superpixel_mode(&self) -> Result<i32> {
+		crate::core::validity::check("RICInterpolator", self.as_raw_RICInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_RICInterpolator_getSuperpixelMode_const(self.as_raw_RICInterpolator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_RICInterpolator_getSuperpixelMode_const(self.as_raw_RICInterpolator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3537,11 +4650,12 @@ pub trait RICInterpolatorConst: crate::ximgproc::SparseMatchInterpolatorConst {
 	
 	/// Alpha is a parameter defining a global weight for transforming geodesic distance into weight.
 	/// ## See also
-	/// setAlpha
+	/// `set_alpha`
 	#[inline]
 	fn get_alpha(&self) -> Result<f32> {
+		crate::core::validity::check("RICInterpolator", self.as_raw_RICInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_RICInterpolator_getAlpha_const(self.as_raw_RICInterpolator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_RICInterpolator_getAlpha_const(self.as_raw_RICInterpolator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3549,11 +4663,12 @@ pub trait RICInterpolatorConst: crate::ximgproc::SparseMatchInterpolatorConst {
 	
 	/// Parameter defining the number of iterations for piece-wise affine model estimation.
 	/// ## See also
-	/// setModelIter
+	/// `set_model_iter`
 	#[inline]
 	fn get_model_iter(&self) -> Result<i32> {
+		crate::core::validity::check("RICInterpolator", self.as_raw_RICInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_RICInterpolator_getModelIter_const(self.as_raw_RICInterpolator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_RICInterpolator_getModelIter_const(self.as_raw_RICInterpolator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3561,11 +4676,12 @@ pub trait RICInterpolatorConst: crate::ximgproc::SparseMatchInterpolatorConst {
 	
 	/// Parameter to choose wether additional refinement of the piece-wise affine models is employed.
 	/// ## See also
-	/// setRefineModels
+	/// `set_refine_models`
 	#[inline]
 	fn get_refine_models(&self) -> Result<bool> {
+		crate::core::validity::check("RICInterpolator", self.as_raw_RICInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_RICInterpolator_getRefineModels_const(self.as_raw_RICInterpolator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_RICInterpolator_getRefineModels_const(self.as_raw_RICInterpolator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3576,8 +4692,9 @@ pub trait RICInterpolatorConst: crate::ximgproc::SparseMatchInterpolatorConst {
 	///      *  see also: setMaxFlow
 	#[inline]
 	fn get_max_flow(&self) -> Result<f32> {
+		crate::core::validity::check("RICInterpolator", self.as_raw_RICInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_RICInterpolator_getMaxFlow_const(self.as_raw_RICInterpolator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_RICInterpolator_getMaxFlow_const(self.as_raw_RICInterpolator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3585,11 +4702,12 @@ pub trait RICInterpolatorConst: crate::ximgproc::SparseMatchInterpolatorConst {
 	
 	/// Parameter to choose wether the VariationalRefinement post-processing  is employed.
 	/// ## See also
-	/// setUseVariationalRefinement
+	/// `set_use_variational_refinement`
 	#[inline]
 	fn get_use_variational_refinement(&self) -> Result<bool> {
+		crate::core::validity::check("RICInterpolator", self.as_raw_RICInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_RICInterpolator_getUseVariationalRefinement_const(self.as_raw_RICInterpolator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_RICInterpolator_getUseVariationalRefinement_const(self.as_raw_RICInterpolator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3597,11 +4715,12 @@ pub trait RICInterpolatorConst: crate::ximgproc::SparseMatchInterpolatorConst {
 	
 	/// Sets whether the fastGlobalSmootherFilter() post-processing is employed.
 	/// ## See also
-	/// setUseGlobalSmootherFilter
+	/// `set_use_global_smoother_filter`
 	#[inline]
 	fn get_use_global_smoother_filter(&self) -> Result<bool> {
+		crate::core::validity::check("RICInterpolator", self.as_raw_RICInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_RICInterpolator_getUseGlobalSmootherFilter_const(self.as_raw_RICInterpolator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_RICInterpolator_getUseGlobalSmootherFilter_const(self.as_raw_RICInterpolator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3609,11 +4728,12 @@ pub trait RICInterpolatorConst: crate::ximgproc::SparseMatchInterpolatorConst {
 	
 	/// Sets the respective fastGlobalSmootherFilter() parameter.
 	/// ## See also
-	/// setFGSLambda
+	/// `set_fgs_lambda`
 	#[inline]
 	fn get_fgs_lambda(&self) -> Result<f32> {
+		crate::core::validity::check("RICInterpolator", self.as_raw_RICInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_RICInterpolator_getFGSLambda_const(self.as_raw_RICInterpolator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_RICInterpolator_getFGSLambda_const(self.as_raw_RICInterpolator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3621,11 +4741,12 @@ pub trait RICInterpolatorConst: crate::ximgproc::SparseMatchInterpolatorConst {
 	
 	/// Sets the respective fastGlobalSmootherFilter() parameter.
 	/// ## See also
-	/// setFGSSigma
+	/// `set_fgs_sigma`
 	#[inline]
 	fn get_fgs_sigma(&self) -> Result<f32> {
+		crate::core::validity::check("RICInterpolator", self.as_raw_RICInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_RICInterpolator_getFGSSigma_const(self.as_raw_RICInterpolator(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_RICInterpolator_getFGSSigma_const(self.as_raw_RICInterpolator(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3644,8 +4765,9 @@ pub trait RICInterpolator: crate::ximgproc::RICInterpolatorConst + crate::ximgpr
 	/// * k: 32
 	#[inline]
 	fn set_k(&mut self, k: i32) -> Result<()> {
+		crate::core::validity::check("RICInterpolator", self.as_raw_mut_RICInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_RICInterpolator_setK_int(self.as_raw_mut_RICInterpolator(), k, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_RICInterpolator_setK_int(self.as_raw_mut_RICInterpolator(), k, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3662,8 +4784,9 @@ pub trait RICInterpolator: crate::ximgproc::RICInterpolatorConst + crate::ximgpr
 	/// cv::ximgproc::createSuperpixelSLIC
 	#[inline]
 	fn set_cost_map(&mut self, cost_map: &core::Mat) -> Result<()> {
+		crate::core::validity::check("RICInterpolator", self.as_raw_mut_RICInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_RICInterpolator_setCostMap_const_MatR(self.as_raw_mut_RICInterpolator(), cost_map.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_RICInterpolator_setCostMap_const_MatR(self.as_raw_mut_RICInterpolator(), cost_map.as_raw_Mat(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3671,14 +4794,15 @@ pub trait RICInterpolator: crate::ximgproc::RICInterpolatorConst + crate::ximgpr
 	
 	/// Get the internal cost, i.e. edge map, used for estimating the edge-aware term.
 	/// ## See also
-	/// setCostMap
+	/// `set_cost_map`
 	/// 
 	/// ## C++ default parameters
 	/// * sp_size: 15
 	#[inline]
 	fn set_superpixel_size(&mut self, sp_size: i32) -> Result<()> {
+		crate::core::validity::check("RICInterpolator", self.as_raw_mut_RICInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_RICInterpolator_setSuperpixelSize_int(self.as_raw_mut_RICInterpolator(), sp_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_RICInterpolator_setSuperpixelSize_int(self.as_raw_mut_RICInterpolator(), sp_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3691,8 +4815,9 @@ pub trait RICInterpolator: crate::ximgproc::RICInterpolatorConst + crate::ximgpr
 	/// * sp_nn: 150
 	#[inline]
 	fn set_superpixel_nn_cnt(&mut self, sp_nn: i32) -> Result<()> {
+		crate::core::validity::check("RICInterpolator", self.as_raw_mut_RICInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_RICInterpolator_setSuperpixelNNCnt_int(self.as_raw_mut_RICInterpolator(), sp_nn, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_RICInterpolator_setSuperpixelNNCnt_int(self.as_raw_mut_RICInterpolator(), sp_nn, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3706,8 +4831,9 @@ pub trait RICInterpolator: crate::ximgproc::RICInterpolatorConst + crate::ximgpr
 	/// * ruler: 15.f
 	#[inline]
 	fn set_superpixel_ruler(&mut self, ruler: f32) -> Result<()> {
+		crate::core::validity::check("RICInterpolator", self.as_raw_mut_RICInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_RICInterpolator_setSuperpixelRuler_float(self.as_raw_mut_RICInterpolator(), ruler, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_RICInterpolator_setSuperpixelRuler_float(self.as_raw_mut_RICInterpolator(), ruler, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3724,8 +4850,9 @@ pub trait RICInterpolator: crate::ximgproc::RICInterpolatorConst + crate::ximgpr
 	/// * mode: 100
 	#[inline]
 	fn set_superpixel_mode(&mut self, mode: i32) -> Result<()> {
+		crate::core::validity::check("RICInterpolator", self.as_raw_mut_RICInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_RICInterpolator_setSuperpixelMode_int(self.as_raw_mut_RICInterpolator(), mode, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_RICInterpolator_setSuperpixelMode_int(self.as_raw_mut_RICInterpolator(), mode, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3737,8 +4864,9 @@ pub trait RICInterpolator: crate::ximgproc::RICInterpolatorConst + crate::ximgpr
 	/// * alpha: 0.7f
 	#[inline]
 	fn set_alpha(&mut self, alpha: f32) -> Result<()> {
+		crate::core::validity::check("RICInterpolator", self.as_raw_mut_RICInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_RICInterpolator_setAlpha_float(self.as_raw_mut_RICInterpolator(), alpha, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_RICInterpolator_setAlpha_float(self.as_raw_mut_RICInterpolator(), alpha, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3750,8 +4878,9 @@ pub trait RICInterpolator: crate::ximgproc::RICInterpolatorConst + crate::ximgpr
 	/// * model_iter: 4
 	#[inline]
 	fn set_model_iter(&mut self, model_iter: i32) -> Result<()> {
+		crate::core::validity::check("RICInterpolator", self.as_raw_mut_RICInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_RICInterpolator_setModelIter_int(self.as_raw_mut_RICInterpolator(), model_iter, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_RICInterpolator_setModelIter_int(self.as_raw_mut_RICInterpolator(), model_iter, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3763,8 +4892,9 @@ pub trait RICInterpolator: crate::ximgproc::RICInterpolatorConst + crate::ximgpr
 	/// * refine_modles: true
 	#[inline]
 	fn set_refine_models(&mut self, refine_modles: bool) -> Result<()> {
+		crate::core::validity::check("RICInterpolator", self.as_raw_mut_RICInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_RICInterpolator_setRefineModels_bool(self.as_raw_mut_RICInterpolator(), refine_modles, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_RICInterpolator_setRefineModels_bool(self.as_raw_mut_RICInterpolator(), refine_modles, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3777,8 +4907,9 @@ pub trait RICInterpolator: crate::ximgproc::RICInterpolatorConst + crate::ximgpr
 	/// * max_flow: 250.f
 	#[inline]
 	fn set_max_flow(&mut self, max_flow: f32) -> Result<()> {
+		crate::core::validity::check("RICInterpolator", self.as_raw_mut_RICInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_RICInterpolator_setMaxFlow_float(self.as_raw_mut_RICInterpolator(), max_flow, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_RICInterpolator_setMaxFlow_float(self.as_raw_mut_RICInterpolator(), max_flow, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3790,8 +4921,9 @@ pub trait RICInterpolator: crate::ximgproc::RICInterpolatorConst + crate::ximgpr
 	/// * use_variational_refinement: false
 	#[inline]
 	fn set_use_variational_refinement(&mut self, use_variational_refinement: bool) -> Result<()> {
+		crate::core::validity::check("RICInterpolator", self.as_raw_mut_RICInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_RICInterpolator_setUseVariationalRefinement_bool(self.as_raw_mut_RICInterpolator(), use_variational_refinement, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_RICInterpolator_setUseVariationalRefinement_bool(self.as_raw_mut_RICInterpolator(), use_variational_refinement, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3803,8 +4935,9 @@ pub trait RICInterpolator: crate::ximgproc::RICInterpolatorConst + crate::ximgpr
 	/// * use_fgs: true
 	#[inline]
 	fn set_use_global_smoother_filter(&mut self, use_fgs: bool) -> Result<()> {
+		crate::core::validity::check("RICInterpolator", self.as_raw_mut_RICInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_RICInterpolator_setUseGlobalSmootherFilter_bool(self.as_raw_mut_RICInterpolator(), use_fgs, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_RICInterpolator_setUseGlobalSmootherFilter_bool(self.as_raw_mut_RICInterpolator(), use_fgs, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3816,8 +4949,9 @@ pub trait RICInterpolator: crate::ximgproc::RICInterpolatorConst + crate::ximgpr
 	/// * lambda: 500.f
 	#[inline]
 	fn set_fgs_lambda(&mut self, lambda: f32) -> Result<()> {
+		crate::core::validity::check("RICInterpolator", self.as_raw_mut_RICInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_RICInterpolator_setFGSLambda_float(self.as_raw_mut_RICInterpolator(), lambda, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_RICInterpolator_setFGSLambda_float(self.as_raw_mut_RICInterpolator(), lambda, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3829,8 +4963,9 @@ pub trait RICInterpolator: crate::ximgproc::RICInterpolatorConst + crate::ximgpr
 	/// * sigma: 1.5f
 	#[inline]
 	fn set_fgs_sigma(&mut self, sigma: f32) -> Result<()> {
+		crate::core::validity::check("RICInterpolator", self.as_raw_mut_RICInterpolator())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_RICInterpolator_setFGSSigma_float(self.as_raw_mut_RICInterpolator(), sigma, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_RICInterpolator_setFGSSigma_float(self.as_raw_mut_RICInterpolator(), sigma, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3856,10 +4991,11 @@ pub trait RidgeDetectionFilter: core::AlgorithmTrait + crate::ximgproc::RidgeDet
 	/// * out: OutputAray of structure as RidgeDetectionFilter::ddepth. Output image with ridges.
 	#[inline]
 	fn get_ridge_filtered_image(&mut self, _img: &dyn core::ToInputArray, out: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("RidgeDetectionFilter", self.as_raw_mut_RidgeDetectionFilter())?;
 		input_array_arg!(_img);
 		output_array_arg!(out);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_RidgeDetectionFilter_getRidgeFilteredImage_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_RidgeDetectionFilter(), _img.as_raw__InputArray(), out.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_RidgeDetectionFilter_getRidgeFilteredImage_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_RidgeDetectionFilter(), _img.as_raw__InputArray(), out.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3893,7 +5029,7 @@ impl dyn RidgeDetectionFilter + '_ {
 	#[inline]
 	pub fn create(ddepth: i32, dx: i32, dy: i32, ksize: i32, out_dtype: i32, scale: f64, delta: f64, border_type: i32) -> Result<core::Ptr<dyn crate::ximgproc::RidgeDetectionFilter>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_RidgeDetectionFilter_create_int_int_int_int_int_double_double_int(ddepth, dx, dy, ksize, out_dtype, scale, delta, border_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_RidgeDetectionFilter_create_int_int_int_int_int_double_double_int(ddepth, dx, dy, ksize, out_dtype, scale, delta, border_type, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::ximgproc::RidgeDetectionFilter>::opencv_from_extern(ret) };
@@ -3901,6 +5037,68 @@ impl dyn RidgeDetectionFilter + '_ {
 	}
 	
 }
+
+/// Builder for `<dyn RidgeDetectionFilter>::create()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct RidgeDetectionFilter_CreateBuilder {
+	ddepth: i32,
+	dx: i32,
+	dy: i32,
+	ksize: i32,
+	out_dtype: i32,
+	scale: f64,
+	delta: f64,
+	border_type: i32,
+}
+
+impl RidgeDetectionFilter_CreateBuilder {
+	#[inline]
+	pub fn new(ddepth: i32, out_dtype: i32, border_type: i32) -> Self {
+		Self { ddepth, dx: 1, dy: 1, ksize: 3, out_dtype, scale: 1., delta: 0., border_type }
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_dx(mut self, dx: i32) -> Self {
+		self.dx = dx;
+		self
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_dy(mut self, dy: i32) -> Self {
+		self.dy = dy;
+		self
+	}
+	
+	/// C++ default: `3`
+	#[inline]
+	pub fn with_ksize(mut self, ksize: i32) -> Self {
+		self.ksize = ksize;
+		self
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_scale(mut self, scale: f64) -> Self {
+		self.scale = scale;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_delta(mut self, delta: f64) -> Self {
+		self.delta = delta;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::ximgproc::RidgeDetectionFilter>> {
+		<dyn RidgeDetectionFilter>::create(self.ddepth, self.dx, self.dy, self.ksize, self.out_dtype, self.scale, self.delta, self.border_type)
+	}
+}
 /// Class implementing the F-DBSCAN (Accelerated superpixel image segmentation with a parallelized DBSCAN algorithm) superpixels
 /// algorithm by Loke SC, et al. [loke2021accelerated](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_loke2021accelerated) for original paper.
 /// 
@@ -3922,8 +5120,9 @@ pub trait ScanSegment: core::AlgorithmTrait + crate::ximgproc::ScanSegmentConst
 	/// Returns zero if no image has been processed.
 	#[inline]
 	fn get_number_of_superpixels(&mut self) -> Result<i32> {
+		crate::core::validity::check("ScanSegment", self.as_raw_mut_ScanSegment())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_ScanSegment_getNumberOfSuperpixels(self.as_raw_mut_ScanSegment(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_ScanSegment_getNumberOfSuperpixels(self.as_raw_mut_ScanSegment(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3940,9 +5139,10 @@ pub trait ScanSegment: core::AlgorithmTrait + crate::ximgproc::ScanSegmentConst
 	/// image size with the function createScanSegment(). It MUST be in Lab color space.
 	#[inline]
 	fn iterate(&mut self, img: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("ScanSegment", self.as_raw_mut_ScanSegment())?;
 		input_array_arg!(img);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_ScanSegment_iterate_const__InputArrayR(self.as_raw_mut_ScanSegment(), img.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_ScanSegment_iterate_const__InputArrayR(self.as_raw_mut_ScanSegment(), img.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3954,12 +5154,13 @@ pub trait ScanSegment: core::AlgorithmTrait + crate::ximgproc::ScanSegmentConst
 	/// 
 	/// ## Parameters
 	/// * labels_out: Return: A CV_32UC1 integer array containing the labels of the superpixel
-	/// segmentation. The labels are in the range [0, getNumberOfSuperpixels()].
+	/// segmentation. The labels are in the range \[0, getNumberOfSuperpixels()\].
 	#[inline]
 	fn get_labels(&mut self, labels_out: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("ScanSegment", self.as_raw_mut_ScanSegment())?;
 		output_array_arg!(labels_out);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_ScanSegment_getLabels_const__OutputArrayR(self.as_raw_mut_ScanSegment(), labels_out.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_ScanSegment_getLabels_const__OutputArrayR(self.as_raw_mut_ScanSegment(), labels_out.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -3977,9 +5178,10 @@ pub trait ScanSegment: core::AlgorithmTrait + crate::ximgproc::ScanSegmentConst
 	/// * thick_line: false
 	#[inline]
 	fn get_label_contour_mask(&mut self, image: &mut dyn core::ToOutputArray, thick_line: bool) -> Result<()> {
+		crate::core::validity::check("ScanSegment", self.as_raw_mut_ScanSegment())?;
 		output_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_ScanSegment_getLabelContourMask_const__OutputArrayR_bool(self.as_raw_mut_ScanSegment(), image.as_raw__OutputArray(), thick_line, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_ScanSegment_getLabelContourMask_const__OutputArrayR_bool(self.as_raw_mut_ScanSegment(), image.as_raw__OutputArray(), thick_line, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4013,13 +5215,14 @@ pub trait SparseMatchInterpolator: core::AlgorithmTrait + crate::ximgproc::Spars
 	/// * dense_flow: output dense matching (two-channel CV_32F image)
 	#[inline]
 	fn interpolate(&mut self, from_image: &dyn core::ToInputArray, from_points: &dyn core::ToInputArray, to_image: &dyn core::ToInputArray, to_points: &dyn core::ToInputArray, dense_flow: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("SparseMatchInterpolator", self.as_raw_mut_SparseMatchInterpolator())?;
 		input_array_arg!(from_image);
 		input_array_arg!(from_points);
 		input_array_arg!(to_image);
 		input_array_arg!(to_points);
 		output_array_arg!(dense_flow);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_SparseMatchInterpolator_interpolate_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_SparseMatchInterpolator(), from_image.as_raw__InputArray(), from_points.as_raw__InputArray(), to_image.as_raw__InputArray(), to_points.as_raw__InputArray(), dense_flow.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_SparseMatchInterpolator_interpolate_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_SparseMatchInterpolator(), from_image.as_raw__InputArray(), from_points.as_raw__InputArray(), to_image.as_raw__InputArray(), to_points.as_raw__InputArray(), dense_flow.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4036,16 +5239,17 @@ pub trait StructuredEdgeDetectionConst: core::AlgorithmTraitConst {
 	/// The algorithm underlies this function is much more robust to texture presence, than common
 	/// approaches, e.g. Sobel
 	/// ## Parameters
-	/// * src: source image (RGB, float, in [0;1]) to detect edges
-	/// * dst: destination image (grayscale, float, in [0;1]) where edges are drawn
+	/// * src: source image (RGB, float, in \[0;1\]) to detect edges
+	/// * dst: destination image (grayscale, float, in \[0;1\]) where edges are drawn
 	/// ## See also
-	/// Sobel, Canny
+	/// `Sobel`, `Canny`
 	#[inline]
 	fn detect_edges(&self, src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("StructuredEdgeDetection", self.as_raw_StructuredEdgeDetection())?;
 		input_array_arg!(src);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_StructuredEdgeDetection_detectEdges_const_const__InputArrayR_const__OutputArrayR(self.as_raw_StructuredEdgeDetection(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_StructuredEdgeDetection_detectEdges_const_const__InputArrayR_const__OutputArrayR(self.as_raw_StructuredEdgeDetection(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4058,10 +5262,11 @@ pub trait StructuredEdgeDetectionConst: core::AlgorithmTraitConst {
 	/// * dst: orientation image.
 	#[inline]
 	fn compute_orientation(&self, src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("StructuredEdgeDetection", self.as_raw_StructuredEdgeDetection())?;
 		input_array_arg!(src);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_StructuredEdgeDetection_computeOrientation_const_const__InputArrayR_const__OutputArrayR(self.as_raw_StructuredEdgeDetection(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_StructuredEdgeDetection_computeOrientation_const_const__InputArrayR_const__OutputArrayR(self.as_raw_StructuredEdgeDetection(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4072,7 +5277,7 @@ pub trait StructuredEdgeDetectionConst: core::AlgorithmTraitConst {
 	/// ## Parameters
 	/// * edge_image: edge image from detectEdges function.
 	/// * orientation_image: orientation image from computeOrientation function.
-	/// * dst: suppressed image (grayscale, float, in [0;1])
+	/// * dst: suppressed image (grayscale, float, in \[0;1\])
 	/// * r: radius for NMS suppression.
 	/// * s: radius for boundary suppression.
 	/// * m: multiplier for conservative suppression.
@@ -4085,11 +5290,12 @@ pub trait StructuredEdgeDetectionConst: core::AlgorithmTraitConst {
 	/// * is_parallel: true
 	#[inline]
 	fn edges_nms(&self, edge_image: &dyn core::ToInputArray, orientation_image: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, r: i32, s: i32, m: f32, is_parallel: bool) -> Result<()> {
+		crate::core::validity::check("StructuredEdgeDetection", self.as_raw_StructuredEdgeDetection())?;
 		input_array_arg!(edge_image);
 		input_array_arg!(orientation_image);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_StructuredEdgeDetection_edgesNms_const_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_int_float_bool(self.as_raw_StructuredEdgeDetection(), edge_image.as_raw__InputArray(), orientation_image.as_raw__InputArray(), dst.as_raw__OutputArray(), r, s, m, is_parallel, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_StructuredEdgeDetection_edgesNms_const_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_int_float_bool(self.as_raw_StructuredEdgeDetection(), edge_image.as_raw__InputArray(), orientation_image.as_raw__InputArray(), dst.as_raw__OutputArray(), r, s, m, is_parallel, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4097,6 +5303,61 @@ pub trait StructuredEdgeDetectionConst: core::AlgorithmTraitConst {
 	
 }
 
+/// Builder for `StructuredEdgeDetectionConst::edges_nms()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct StructuredEdgeDetection_EdgesNmsBuilder<'a, T: ?Sized + StructuredEdgeDetectionConst> {
+	instance: &'a T,
+	edge_image: &'a dyn core::ToInputArray,
+	orientation_image: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	r: i32,
+	s: i32,
+	m: f32,
+	is_parallel: bool,
+}
+
+impl<'a, T: ?Sized + StructuredEdgeDetectionConst> StructuredEdgeDetection_EdgesNmsBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a T, edge_image: &'a dyn core::ToInputArray, orientation_image: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray) -> Self {
+		Self { instance, edge_image, orientation_image, dst, r: 2, s: 0, m: 1., is_parallel: true }
+	}
+	
+	/// C++ default: `2`
+	#[inline]
+	pub fn with_r(mut self, r: i32) -> Self {
+		self.r = r;
+		self
+	}
+	
+	/// C++ default: `0`
+	#[inline]
+	pub fn with_s(mut self, s: i32) -> Self {
+		self.s = s;
+		self
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_m(mut self, m: f32) -> Self {
+		self.m = m;
+		self
+	}
+	
+	/// C++ default: `true`
+	#[inline]
+	pub fn with_is_parallel(mut self, is_parallel: bool) -> Self {
+		self.is_parallel = is_parallel;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		StructuredEdgeDetectionConst::edges_nms(self.instance, self.edge_image, self.orientation_image, self.dst, self.r, self.s, self.m, self.is_parallel)
+	}
+}
+
 pub trait StructuredEdgeDetection: core::AlgorithmTrait + crate::ximgproc::StructuredEdgeDetectionConst {
 	fn as_raw_mut_StructuredEdgeDetection(&mut self) -> *mut c_void;
 
@@ -4117,8 +5378,9 @@ pub trait SuperpixelLSCConst: core::AlgorithmTraitConst {
 	/// and stored in SuperpixelLSC object.
 	#[inline]
 	fn get_number_of_superpixels(&self) -> Result<i32> {
+		crate::core::validity::check("SuperpixelLSC", self.as_raw_SuperpixelLSC())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_SuperpixelLSC_getNumberOfSuperpixels_const(self.as_raw_SuperpixelLSC(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_SuperpixelLSC_getNumberOfSuperpixels_const(self.as_raw_SuperpixelLSC(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4130,15 +5392,16 @@ pub trait SuperpixelLSCConst: core::AlgorithmTraitConst {
 	/// 
 	/// ## Parameters
 	/// * labels_out: Return: A CV_32SC1 integer array containing the labels of the superpixel
-	/// segmentation. The labels are in the range [0, getNumberOfSuperpixels()].
+	/// segmentation. The labels are in the range \[0, getNumberOfSuperpixels()\].
 	/// 
 	/// The function returns an image with the labels of the superpixel segmentation. The labels are in
-	/// the range [0, getNumberOfSuperpixels()].
+	/// the range \[0, getNumberOfSuperpixels()\].
 	#[inline]
 	fn get_labels(&self, labels_out: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("SuperpixelLSC", self.as_raw_SuperpixelLSC())?;
 		output_array_arg!(labels_out);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_SuperpixelLSC_getLabels_const_const__OutputArrayR(self.as_raw_SuperpixelLSC(), labels_out.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_SuperpixelLSC_getLabels_const_const__OutputArrayR(self.as_raw_SuperpixelLSC(), labels_out.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4159,9 +5422,10 @@ pub trait SuperpixelLSCConst: core::AlgorithmTraitConst {
 	/// * thick_line: true
 	#[inline]
 	fn get_label_contour_mask(&self, image: &mut dyn core::ToOutputArray, thick_line: bool) -> Result<()> {
+		crate::core::validity::check("SuperpixelLSC", self.as_raw_SuperpixelLSC())?;
 		output_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_SuperpixelLSC_getLabelContourMask_const_const__OutputArrayR_bool(self.as_raw_SuperpixelLSC(), image.as_raw__OutputArray(), thick_line, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_SuperpixelLSC_getLabelContourMask_const_const__OutputArrayR_bool(self.as_raw_SuperpixelLSC(), image.as_raw__OutputArray(), thick_line, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4190,8 +5454,9 @@ pub trait SuperpixelLSC: core::AlgorithmTrait + crate::ximgproc::SuperpixelLSCCo
 	/// * num_iterations: 10
 	#[inline]
 	fn iterate(&mut self, num_iterations: i32) -> Result<()> {
+		crate::core::validity::check("SuperpixelLSC", self.as_raw_mut_SuperpixelLSC())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_SuperpixelLSC_iterate_int(self.as_raw_mut_SuperpixelLSC(), num_iterations, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_SuperpixelLSC_iterate_int(self.as_raw_mut_SuperpixelLSC(), num_iterations, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4211,8 +5476,9 @@ pub trait SuperpixelLSC: core::AlgorithmTrait + crate::ximgproc::SuperpixelLSCCo
 	/// * min_element_size: 25
 	#[inline]
 	fn enforce_label_connectivity(&mut self, min_element_size: i32) -> Result<()> {
+		crate::core::validity::check("SuperpixelLSC", self.as_raw_mut_SuperpixelLSC())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_SuperpixelLSC_enforceLabelConnectivity_int(self.as_raw_mut_SuperpixelLSC(), min_element_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_SuperpixelLSC_enforceLabelConnectivity_int(self.as_raw_mut_SuperpixelLSC(), min_element_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4243,8 +5509,9 @@ pub trait SuperpixelSEEDS: core::AlgorithmTrait + crate::ximgproc::SuperpixelSEE
 	/// with the function createSuperpixelSEEDS().
 	#[inline]
 	fn get_number_of_superpixels(&mut self) -> Result<i32> {
+		crate::core::validity::check("SuperpixelSEEDS", self.as_raw_mut_SuperpixelSEEDS())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_SuperpixelSEEDS_getNumberOfSuperpixels(self.as_raw_mut_SuperpixelSEEDS(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_SuperpixelSEEDS_getNumberOfSuperpixels(self.as_raw_mut_SuperpixelSEEDS(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4277,9 +5544,10 @@ pub trait SuperpixelSEEDS: core::AlgorithmTrait + crate::ximgproc::SuperpixelSEE
 	/// * num_iterations: 4
 	#[inline]
 	fn iterate(&mut self, img: &dyn core::ToInputArray, num_iterations: i32) -> Result<()> {
+		crate::core::validity::check("SuperpixelSEEDS", self.as_raw_mut_SuperpixelSEEDS())?;
 		input_array_arg!(img);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_SuperpixelSEEDS_iterate_const__InputArrayR_int(self.as_raw_mut_SuperpixelSEEDS(), img.as_raw__InputArray(), num_iterations, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_SuperpixelSEEDS_iterate_const__InputArrayR_int(self.as_raw_mut_SuperpixelSEEDS(), img.as_raw__InputArray(), num_iterations, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4291,15 +5559,16 @@ pub trait SuperpixelSEEDS: core::AlgorithmTrait + crate::ximgproc::SuperpixelSEE
 	/// 
 	/// ## Parameters
 	/// * labels_out: Return: A CV_32UC1 integer array containing the labels of the superpixel
-	/// segmentation. The labels are in the range [0, getNumberOfSuperpixels()].
+	/// segmentation. The labels are in the range \[0, getNumberOfSuperpixels()\].
 	/// 
 	/// The function returns an image with ssthe labels of the superpixel segmentation. The labels are in
-	/// the range [0, getNumberOfSuperpixels()].
+	/// the range \[0, getNumberOfSuperpixels()\].
 	#[inline]
 	fn get_labels(&mut self, labels_out: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("SuperpixelSEEDS", self.as_raw_mut_SuperpixelSEEDS())?;
 		output_array_arg!(labels_out);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_SuperpixelSEEDS_getLabels_const__OutputArrayR(self.as_raw_mut_SuperpixelSEEDS(), labels_out.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_SuperpixelSEEDS_getLabels_const__OutputArrayR(self.as_raw_mut_SuperpixelSEEDS(), labels_out.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4337,9 +5606,10 @@ pub trait SuperpixelSEEDS: core::AlgorithmTrait + crate::ximgproc::SuperpixelSEE
 	/// * thick_line: false
 	#[inline]
 	fn get_label_contour_mask(&mut self, image: &mut dyn core::ToOutputArray, thick_line: bool) -> Result<()> {
+		crate::core::validity::check("SuperpixelSEEDS", self.as_raw_mut_SuperpixelSEEDS())?;
 		output_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_SuperpixelSEEDS_getLabelContourMask_const__OutputArrayR_bool(self.as_raw_mut_SuperpixelSEEDS(), image.as_raw__OutputArray(), thick_line, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_SuperpixelSEEDS_getLabelContourMask_const__OutputArrayR_bool(self.as_raw_mut_SuperpixelSEEDS(), image.as_raw__OutputArray(), thick_line, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4364,8 +5634,9 @@ pub trait SuperpixelSLICConst: core::AlgorithmTraitConst {
 	/// and stored in SuperpixelSLIC object.
 	#[inline]
 	fn get_number_of_superpixels(&self) -> Result<i32> {
+		crate::core::validity::check("SuperpixelSLIC", self.as_raw_SuperpixelSLIC())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_SuperpixelSLIC_getNumberOfSuperpixels_const(self.as_raw_SuperpixelSLIC(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_SuperpixelSLIC_getNumberOfSuperpixels_const(self.as_raw_SuperpixelSLIC(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4377,15 +5648,16 @@ pub trait SuperpixelSLICConst: core::AlgorithmTraitConst {
 	/// 
 	/// ## Parameters
 	/// * labels_out: Return: A CV_32SC1 integer array containing the labels of the superpixel
-	/// segmentation. The labels are in the range [0, getNumberOfSuperpixels()].
+	/// segmentation. The labels are in the range \[0, getNumberOfSuperpixels()\].
 	/// 
 	/// The function returns an image with the labels of the superpixel segmentation. The labels are in
-	/// the range [0, getNumberOfSuperpixels()].
+	/// the range \[0, getNumberOfSuperpixels()\].
 	#[inline]
 	fn get_labels(&self, labels_out: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("SuperpixelSLIC", self.as_raw_SuperpixelSLIC())?;
 		output_array_arg!(labels_out);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_SuperpixelSLIC_getLabels_const_const__OutputArrayR(self.as_raw_SuperpixelSLIC(), labels_out.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_SuperpixelSLIC_getLabels_const_const__OutputArrayR(self.as_raw_SuperpixelSLIC(), labels_out.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4406,9 +5678,10 @@ pub trait SuperpixelSLICConst: core::AlgorithmTraitConst {
 	/// * thick_line: true
 	#[inline]
 	fn get_label_contour_mask(&self, image: &mut dyn core::ToOutputArray, thick_line: bool) -> Result<()> {
+		crate::core::validity::check("SuperpixelSLIC", self.as_raw_SuperpixelSLIC())?;
 		output_array_arg!(image);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_SuperpixelSLIC_getLabelContourMask_const_const__OutputArrayR_bool(self.as_raw_SuperpixelSLIC(), image.as_raw__OutputArray(), thick_line, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_SuperpixelSLIC_getLabelContourMask_const_const__OutputArrayR_bool(self.as_raw_SuperpixelSLIC(), image.as_raw__OutputArray(), thick_line, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4437,8 +5710,9 @@ pub trait SuperpixelSLIC: core::AlgorithmTrait + crate::ximgproc::SuperpixelSLIC
 	/// * num_iterations: 10
 	#[inline]
 	fn iterate(&mut self, num_iterations: i32) -> Result<()> {
+		crate::core::validity::check("SuperpixelSLIC", self.as_raw_mut_SuperpixelSLIC())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_SuperpixelSLIC_iterate_int(self.as_raw_mut_SuperpixelSLIC(), num_iterations, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_SuperpixelSLIC_iterate_int(self.as_raw_mut_SuperpixelSLIC(), num_iterations, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4458,8 +5732,9 @@ pub trait SuperpixelSLIC: core::AlgorithmTrait + crate::ximgproc::SuperpixelSLIC
 	/// * min_element_size: 25
 	#[inline]
 	fn enforce_label_connectivity(&mut self, min_element_size: i32) -> Result<()> {
+		crate::core::validity::check("SuperpixelSLIC", self.as_raw_mut_SuperpixelSLIC())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_SuperpixelSLIC_enforceLabelConnectivity_int(self.as_raw_mut_SuperpixelSLIC(), min_element_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_SuperpixelSLIC_enforceLabelConnectivity_int(self.as_raw_mut_SuperpixelSLIC(), min_element_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4483,10 +5758,11 @@ pub trait GraphSegmentation: core::AlgorithmTrait + crate::ximgproc::GraphSegmen
 	/// * dst: The output segmentation. It's a CV_32SC1 Mat with the same number of cols and rows as input image, with an unique, sequential, id for each pixel.
 	#[inline]
 	fn process_image(&mut self, src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("GraphSegmentation", self.as_raw_mut_GraphSegmentation())?;
 		input_array_arg!(src);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_segmentation_GraphSegmentation_processImage_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_GraphSegmentation(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_segmentation_GraphSegmentation_processImage_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_GraphSegmentation(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4494,8 +5770,9 @@ pub trait GraphSegmentation: core::AlgorithmTrait + crate::ximgproc::GraphSegmen
 	
 	#[inline]
 	fn set_sigma(&mut self, sigma: f64) -> Result<()> {
+		crate::core::validity::check("GraphSegmentation", self.as_raw_mut_GraphSegmentation())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_segmentation_GraphSegmentation_setSigma_double(self.as_raw_mut_GraphSegmentation(), sigma, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_segmentation_GraphSegmentation_setSigma_double(self.as_raw_mut_GraphSegmentation(), sigma, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4503,8 +5780,9 @@ pub trait GraphSegmentation: core::AlgorithmTrait + crate::ximgproc::GraphSegmen
 	
 	#[inline]
 	fn get_sigma(&mut self) -> Result<f64> {
+		crate::core::validity::check("GraphSegmentation", self.as_raw_mut_GraphSegmentation())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_segmentation_GraphSegmentation_getSigma(self.as_raw_mut_GraphSegmentation(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_segmentation_GraphSegmentation_getSigma(self.as_raw_mut_GraphSegmentation(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4512,8 +5790,9 @@ pub trait GraphSegmentation: core::AlgorithmTrait + crate::ximgproc::GraphSegmen
 	
 	#[inline]
 	fn set_k(&mut self, k: f32) -> Result<()> {
+		crate::core::validity::check("GraphSegmentation", self.as_raw_mut_GraphSegmentation())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_segmentation_GraphSegmentation_setK_float(self.as_raw_mut_GraphSegmentation(), k, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_segmentation_GraphSegmentation_setK_float(self.as_raw_mut_GraphSegmentation(), k, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4521,8 +5800,9 @@ pub trait GraphSegmentation: core::AlgorithmTrait + crate::ximgproc::GraphSegmen
 	
 	#[inline]
 	fn get_k(&mut self) -> Result<f32> {
+		crate::core::validity::check("GraphSegmentation", self.as_raw_mut_GraphSegmentation())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_segmentation_GraphSegmentation_getK(self.as_raw_mut_GraphSegmentation(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_segmentation_GraphSegmentation_getK(self.as_raw_mut_GraphSegmentation(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4530,8 +5810,9 @@ pub trait GraphSegmentation: core::AlgorithmTrait + crate::ximgproc::GraphSegmen
 	
 	#[inline]
 	fn set_min_size(&mut self, min_size: i32) -> Result<()> {
+		crate::core::validity::check("GraphSegmentation", self.as_raw_mut_GraphSegmentation())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_segmentation_GraphSegmentation_setMinSize_int(self.as_raw_mut_GraphSegmentation(), min_size, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_segmentation_GraphSegmentation_setMinSize_int(self.as_raw_mut_GraphSegmentation(), min_size, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4539,8 +5820,9 @@ pub trait GraphSegmentation: core::AlgorithmTrait + crate::ximgproc::GraphSegmen
 	
 	#[inline]
 	fn get_min_size(&mut self) -> Result<i32> {
+		crate::core::validity::check("GraphSegmentation", self.as_raw_mut_GraphSegmentation())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_segmentation_GraphSegmentation_getMinSize(self.as_raw_mut_GraphSegmentation(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_segmentation_GraphSegmentation_getMinSize(self.as_raw_mut_GraphSegmentation(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4563,9 +5845,10 @@ pub trait SelectiveSearchSegmentation: core::AlgorithmTrait + crate::ximgproc::S
 	/// * img: The image
 	#[inline]
 	fn set_base_image(&mut self, img: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("SelectiveSearchSegmentation", self.as_raw_mut_SelectiveSearchSegmentation())?;
 		input_array_arg!(img);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_segmentation_SelectiveSearchSegmentation_setBaseImage_const__InputArrayR(self.as_raw_mut_SelectiveSearchSegmentation(), img.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_segmentation_SelectiveSearchSegmentation_setBaseImage_const__InputArrayR(self.as_raw_mut_SelectiveSearchSegmentation(), img.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4581,8 +5864,9 @@ pub trait SelectiveSearchSegmentation: core::AlgorithmTrait + crate::ximgproc::S
 	/// * sigma: 0.8f
 	#[inline]
 	fn switch_to_single_strategy(&mut self, k: i32, sigma: f32) -> Result<()> {
+		crate::core::validity::check("SelectiveSearchSegmentation", self.as_raw_mut_SelectiveSearchSegmentation())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_segmentation_SelectiveSearchSegmentation_switchToSingleStrategy_int_float(self.as_raw_mut_SelectiveSearchSegmentation(), k, sigma, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_segmentation_SelectiveSearchSegmentation_switchToSingleStrategy_int_float(self.as_raw_mut_SelectiveSearchSegmentation(), k, sigma, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4600,8 +5884,9 @@ pub trait SelectiveSearchSegmentation: core::AlgorithmTrait + crate::ximgproc::S
 	/// * sigma: 0.8f
 	#[inline]
 	fn switch_to_selective_search_fast(&mut self, base_k: i32, inc_k: i32, sigma: f32) -> Result<()> {
+		crate::core::validity::check("SelectiveSearchSegmentation", self.as_raw_mut_SelectiveSearchSegmentation())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_segmentation_SelectiveSearchSegmentation_switchToSelectiveSearchFast_int_int_float(self.as_raw_mut_SelectiveSearchSegmentation(), base_k, inc_k, sigma, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_segmentation_SelectiveSearchSegmentation_switchToSelectiveSearchFast_int_int_float(self.as_raw_mut_SelectiveSearchSegmentation(), base_k, inc_k, sigma, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4619,8 +5904,9 @@ pub trait SelectiveSearchSegmentation: core::AlgorithmTrait + crate::ximgproc::S
 	/// * sigma: 0.8f
 	#[inline]
 	fn switch_to_selective_search_quality(&mut self, base_k: i32, inc_k: i32, sigma: f32) -> Result<()> {
+		crate::core::validity::check("SelectiveSearchSegmentation", self.as_raw_mut_SelectiveSearchSegmentation())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_segmentation_SelectiveSearchSegmentation_switchToSelectiveSearchQuality_int_int_float(self.as_raw_mut_SelectiveSearchSegmentation(), base_k, inc_k, sigma, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_segmentation_SelectiveSearchSegmentation_switchToSelectiveSearchQuality_int_int_float(self.as_raw_mut_SelectiveSearchSegmentation(), base_k, inc_k, sigma, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4631,9 +5917,10 @@ pub trait SelectiveSearchSegmentation: core::AlgorithmTrait + crate::ximgproc::S
 	/// * img: The image
 	#[inline]
 	fn add_image(&mut self, img: &dyn core::ToInputArray) -> Result<()> {
+		crate::core::validity::check("SelectiveSearchSegmentation", self.as_raw_mut_SelectiveSearchSegmentation())?;
 		input_array_arg!(img);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_segmentation_SelectiveSearchSegmentation_addImage_const__InputArrayR(self.as_raw_mut_SelectiveSearchSegmentation(), img.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_segmentation_SelectiveSearchSegmentation_addImage_const__InputArrayR(self.as_raw_mut_SelectiveSearchSegmentation(), img.as_raw__InputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4642,8 +5929,9 @@ pub trait SelectiveSearchSegmentation: core::AlgorithmTrait + crate::ximgproc::S
 	/// Clear the list of images to process
 	#[inline]
 	fn clear_images(&mut self) -> Result<()> {
+		crate::core::validity::check("SelectiveSearchSegmentation", self.as_raw_mut_SelectiveSearchSegmentation())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_segmentation_SelectiveSearchSegmentation_clearImages(self.as_raw_mut_SelectiveSearchSegmentation(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_segmentation_SelectiveSearchSegmentation_clearImages(self.as_raw_mut_SelectiveSearchSegmentation(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4654,8 +5942,9 @@ pub trait SelectiveSearchSegmentation: core::AlgorithmTrait + crate::ximgproc::S
 	/// * g: The graph segmentation
 	#[inline]
 	fn add_graph_segmentation(&mut self, mut g: core::Ptr<dyn crate::ximgproc::GraphSegmentation>) -> Result<()> {
+		crate::core::validity::check("SelectiveSearchSegmentation", self.as_raw_mut_SelectiveSearchSegmentation())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_segmentation_SelectiveSearchSegmentation_addGraphSegmentation_Ptr_GraphSegmentation_(self.as_raw_mut_SelectiveSearchSegmentation(), g.as_raw_mut_PtrOfGraphSegmentation(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_segmentation_SelectiveSearchSegmentation_addGraphSegmentation_Ptr_GraphSegmentation_(self.as_raw_mut_SelectiveSearchSegmentation(), g.as_raw_mut_PtrOfGraphSegmentation(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4664,8 +5953,9 @@ pub trait SelectiveSearchSegmentation: core::AlgorithmTrait + crate::ximgproc::S
 	/// Clear the list of graph segmentations to process;
 	#[inline]
 	fn clear_graph_segmentations(&mut self) -> Result<()> {
+		crate::core::validity::check("SelectiveSearchSegmentation", self.as_raw_mut_SelectiveSearchSegmentation())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_segmentation_SelectiveSearchSegmentation_clearGraphSegmentations(self.as_raw_mut_SelectiveSearchSegmentation(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_segmentation_SelectiveSearchSegmentation_clearGraphSegmentations(self.as_raw_mut_SelectiveSearchSegmentation(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4676,8 +5966,9 @@ pub trait SelectiveSearchSegmentation: core::AlgorithmTrait + crate::ximgproc::S
 	/// * s: The strategy
 	#[inline]
 	fn add_strategy(&mut self, mut s: core::Ptr<dyn crate::ximgproc::SelectiveSearchSegmentationStrategy>) -> Result<()> {
+		crate::core::validity::check("SelectiveSearchSegmentation", self.as_raw_mut_SelectiveSearchSegmentation())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_segmentation_SelectiveSearchSegmentation_addStrategy_Ptr_SelectiveSearchSegmentationStrategy_(self.as_raw_mut_SelectiveSearchSegmentation(), s.as_raw_mut_PtrOfSelectiveSearchSegmentationStrategy(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_segmentation_SelectiveSearchSegmentation_addStrategy_Ptr_SelectiveSearchSegmentationStrategy_(self.as_raw_mut_SelectiveSearchSegmentation(), s.as_raw_mut_PtrOfSelectiveSearchSegmentationStrategy(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4686,8 +5977,9 @@ pub trait SelectiveSearchSegmentation: core::AlgorithmTrait + crate::ximgproc::S
 	/// Clear the list of strategy to process;
 	#[inline]
 	fn clear_strategies(&mut self) -> Result<()> {
+		crate::core::validity::check("SelectiveSearchSegmentation", self.as_raw_mut_SelectiveSearchSegmentation())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_segmentation_SelectiveSearchSegmentation_clearStrategies(self.as_raw_mut_SelectiveSearchSegmentation(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_segmentation_SelectiveSearchSegmentation_clearStrategies(self.as_raw_mut_SelectiveSearchSegmentation(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4698,8 +5990,9 @@ pub trait SelectiveSearchSegmentation: core::AlgorithmTrait + crate::ximgproc::S
 	/// * rects: The list of rects. The first ones are more relevents than the lasts ones.
 	#[inline]
 	fn process(&mut self, rects: &mut core::Vector<core::Rect>) -> Result<()> {
+		crate::core::validity::check("SelectiveSearchSegmentation", self.as_raw_mut_SelectiveSearchSegmentation())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_segmentation_SelectiveSearchSegmentation_process_vector_Rect_R(self.as_raw_mut_SelectiveSearchSegmentation(), rects.as_raw_mut_VectorOfRect(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_segmentation_SelectiveSearchSegmentation_process_vector_Rect_R(self.as_raw_mut_SelectiveSearchSegmentation(), rects.as_raw_mut_VectorOfRect(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4707,6 +6000,94 @@ pub trait SelectiveSearchSegmentation: core::AlgorithmTrait + crate::ximgproc::S
 	
 }
 
+/// Builder for `SelectiveSearchSegmentation::switch_to_selective_search_fast()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct SelectiveSearchSegmentation_SwitchToSelectiveSearchFastBuilder<'a, T: ?Sized + SelectiveSearchSegmentation> {
+	instance: &'a mut T,
+	base_k: i32,
+	inc_k: i32,
+	sigma: f32,
+}
+
+impl<'a, T: ?Sized + SelectiveSearchSegmentation> SelectiveSearchSegmentation_SwitchToSelectiveSearchFastBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T) -> Self {
+		Self { instance, base_k: 150, inc_k: 150, sigma: 0.8 }
+	}
+	
+	/// C++ default: `150`
+	#[inline]
+	pub fn with_base_k(mut self, base_k: i32) -> Self {
+		self.base_k = base_k;
+		self
+	}
+	
+	/// C++ default: `150`
+	#[inline]
+	pub fn with_inc_k(mut self, inc_k: i32) -> Self {
+		self.inc_k = inc_k;
+		self
+	}
+	
+	/// C++ default: `0.8f`
+	#[inline]
+	pub fn with_sigma(mut self, sigma: f32) -> Self {
+		self.sigma = sigma;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		SelectiveSearchSegmentation::switch_to_selective_search_fast(self.instance, self.base_k, self.inc_k, self.sigma)
+	}
+}
+
+/// Builder for `SelectiveSearchSegmentation::switch_to_selective_search_quality()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct SelectiveSearchSegmentation_SwitchToSelectiveSearchQualityBuilder<'a, T: ?Sized + SelectiveSearchSegmentation> {
+	instance: &'a mut T,
+	base_k: i32,
+	inc_k: i32,
+	sigma: f32,
+}
+
+impl<'a, T: ?Sized + SelectiveSearchSegmentation> SelectiveSearchSegmentation_SwitchToSelectiveSearchQualityBuilder<'a, T> {
+	#[inline]
+	pub fn new(instance: &'a mut T) -> Self {
+		Self { instance, base_k: 150, inc_k: 150, sigma: 0.8 }
+	}
+	
+	/// C++ default: `150`
+	#[inline]
+	pub fn with_base_k(mut self, base_k: i32) -> Self {
+		self.base_k = base_k;
+		self
+	}
+	
+	/// C++ default: `150`
+	#[inline]
+	pub fn with_inc_k(mut self, inc_k: i32) -> Self {
+		self.inc_k = inc_k;
+		self
+	}
+	
+	/// C++ default: `0.8f`
+	#[inline]
+	pub fn with_sigma(mut self, sigma: f32) -> Self {
+		self.sigma = sigma;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		SelectiveSearchSegmentation::switch_to_selective_search_quality(self.instance, self.base_k, self.inc_k, self.sigma)
+	}
+}
+
 /// Strategie for the selective search segmentation algorithm
 /// The class implements a generic stragery for the algorithm described in [uijlings2013selective](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_uijlings2013selective).
 pub trait SelectiveSearchSegmentationStrategyConst: core::AlgorithmTraitConst {
@@ -4728,11 +6109,12 @@ pub trait SelectiveSearchSegmentationStrategy: core::AlgorithmTrait + crate::xim
 	/// * image_id: -1
 	#[inline]
 	fn set_image(&mut self, img: &dyn core::ToInputArray, regions: &dyn core::ToInputArray, sizes: &dyn core::ToInputArray, image_id: i32) -> Result<()> {
+		crate::core::validity::check("SelectiveSearchSegmentationStrategy", self.as_raw_mut_SelectiveSearchSegmentationStrategy())?;
 		input_array_arg!(img);
 		input_array_arg!(regions);
 		input_array_arg!(sizes);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_segmentation_SelectiveSearchSegmentationStrategy_setImage_const__InputArrayR_const__InputArrayR_const__InputArrayR_int(self.as_raw_mut_SelectiveSearchSegmentationStrategy(), img.as_raw__InputArray(), regions.as_raw__InputArray(), sizes.as_raw__InputArray(), image_id, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_segmentation_SelectiveSearchSegmentationStrategy_setImage_const__InputArrayR_const__InputArrayR_const__InputArrayR_int(self.as_raw_mut_SelectiveSearchSegmentationStrategy(), img.as_raw__InputArray(), regions.as_raw__InputArray(), sizes.as_raw__InputArray(), image_id, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4744,8 +6126,9 @@ pub trait SelectiveSearchSegmentationStrategy: core::AlgorithmTrait + crate::xim
 	/// * r2: The second region
 	#[inline]
 	fn get(&mut self, r1: i32, r2: i32) -> Result<f32> {
+		crate::core::validity::check("SelectiveSearchSegmentationStrategy", self.as_raw_mut_SelectiveSearchSegmentationStrategy())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_segmentation_SelectiveSearchSegmentationStrategy_get_int_int(self.as_raw_mut_SelectiveSearchSegmentationStrategy(), r1, r2, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_segmentation_SelectiveSearchSegmentationStrategy_get_int_int(self.as_raw_mut_SelectiveSearchSegmentationStrategy(), r1, r2, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4757,8 +6140,9 @@ pub trait SelectiveSearchSegmentationStrategy: core::AlgorithmTrait + crate::xim
 	/// * r2: The second region
 	#[inline]
 	fn merge(&mut self, r1: i32, r2: i32) -> Result<()> {
+		crate::core::validity::check("SelectiveSearchSegmentationStrategy", self.as_raw_mut_SelectiveSearchSegmentationStrategy())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_segmentation_SelectiveSearchSegmentationStrategy_merge_int_int(self.as_raw_mut_SelectiveSearchSegmentationStrategy(), r1, r2, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_segmentation_SelectiveSearchSegmentationStrategy_merge_int_int(self.as_raw_mut_SelectiveSearchSegmentationStrategy(), r1, r2, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4805,8 +6189,9 @@ pub trait SelectiveSearchSegmentationStrategyMultiple: crate::ximgproc::Selectiv
 	/// * weight: The weight of the strategy
 	#[inline]
 	fn add_strategy(&mut self, mut g: core::Ptr<dyn crate::ximgproc::SelectiveSearchSegmentationStrategy>, weight: f32) -> Result<()> {
+		crate::core::validity::check("SelectiveSearchSegmentationStrategyMultiple", self.as_raw_mut_SelectiveSearchSegmentationStrategyMultiple())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_segmentation_SelectiveSearchSegmentationStrategyMultiple_addStrategy_Ptr_SelectiveSearchSegmentationStrategy__float(self.as_raw_mut_SelectiveSearchSegmentationStrategyMultiple(), g.as_raw_mut_PtrOfSelectiveSearchSegmentationStrategy(), weight, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_segmentation_SelectiveSearchSegmentationStrategyMultiple_addStrategy_Ptr_SelectiveSearchSegmentationStrategy__float(self.as_raw_mut_SelectiveSearchSegmentationStrategyMultiple(), g.as_raw_mut_PtrOfSelectiveSearchSegmentationStrategy(), weight, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4815,8 +6200,9 @@ pub trait SelectiveSearchSegmentationStrategyMultiple: crate::ximgproc::Selectiv
 	/// Remove all sub-strategies
 	#[inline]
 	fn clear_strategies(&mut self) -> Result<()> {
+		crate::core::validity::check("SelectiveSearchSegmentationStrategyMultiple", self.as_raw_mut_SelectiveSearchSegmentationStrategyMultiple())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ximgproc_segmentation_SelectiveSearchSegmentationStrategyMultiple_clearStrategies(self.as_raw_mut_SelectiveSearchSegmentationStrategyMultiple(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_ximgproc_segmentation_SelectiveSearchSegmentationStrategyMultiple_clearStrategies(self.as_raw_mut_SelectiveSearchSegmentationStrategyMultiple(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4847,3 +6233,4 @@ pub trait SelectiveSearchSegmentationStrategyTexture: crate::ximgproc::Selective
 	fn as_raw_mut_SelectiveSearchSegmentationStrategyTexture(&mut self) -> *mut c_void;
 
 }
+pub use crate::manual::ximgproc::*;
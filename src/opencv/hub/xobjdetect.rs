@@ -22,8 +22,9 @@ pub trait WBDetectorConst {
 	/// * fs: FileStorage for output
 	#[inline]
 	fn write(&self, fs: &mut core::FileStorage) -> Result<()> {
+		crate::core::validity::check("WBDetector", self.as_raw_WBDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xobjdetect_WBDetector_write_const_FileStorageR(self.as_raw_WBDetector(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xobjdetect_WBDetector_write_const_FileStorageR(self.as_raw_WBDetector(), fs.as_raw_mut_FileStorage(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -39,8 +40,9 @@ pub trait WBDetector: crate::xobjdetect::WBDetectorConst {
 	/// * node: FileNode for input
 	#[inline]
 	fn read(&mut self, node: &core::FileNode) -> Result<()> {
+		crate::core::validity::check("WBDetector", self.as_raw_mut_WBDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xobjdetect_WBDetector_read_const_FileNodeR(self.as_raw_mut_WBDetector(), node.as_raw_FileNode(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xobjdetect_WBDetector_read_const_FileNodeR(self.as_raw_mut_WBDetector(), node.as_raw_FileNode(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -52,10 +54,11 @@ pub trait WBDetector: crate::xobjdetect::WBDetectorConst {
 	/// * neg_imgs: Path to directory with negative (background) images
 	#[inline]
 	fn train(&mut self, pos_samples: &str, neg_imgs: &str) -> Result<()> {
+		crate::core::validity::check("WBDetector", self.as_raw_mut_WBDetector())?;
 		extern_container_arg!(pos_samples);
 		extern_container_arg!(neg_imgs);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xobjdetect_WBDetector_train_const_stringR_const_stringR(self.as_raw_mut_WBDetector(), pos_samples.opencv_as_extern(), neg_imgs.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xobjdetect_WBDetector_train_const_stringR_const_stringR(self.as_raw_mut_WBDetector(), pos_samples.opencv_as_extern(), neg_imgs.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -68,8 +71,9 @@ pub trait WBDetector: crate::xobjdetect::WBDetectorConst {
 	/// * confidences: Confidence values for bounding boxes output vector
 	#[inline]
 	fn detect(&mut self, img: &core::Mat, bboxes: &mut core::Vector<core::Rect>, confidences: &mut core::Vector<f64>) -> Result<()> {
+		crate::core::validity::check("WBDetector", self.as_raw_mut_WBDetector())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xobjdetect_WBDetector_detect_const_MatR_vector_Rect_R_vector_double_R(self.as_raw_mut_WBDetector(), img.as_raw_Mat(), bboxes.as_raw_mut_VectorOfRect(), confidences.as_raw_mut_VectorOff64(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xobjdetect_WBDetector_detect_const_MatR_vector_Rect_R_vector_double_R(self.as_raw_mut_WBDetector(), img.as_raw_Mat(), bboxes.as_raw_mut_VectorOfRect(), confidences.as_raw_mut_VectorOff64(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -82,7 +86,7 @@ impl dyn WBDetector + '_ {
 	#[inline]
 	pub fn create() -> Result<core::Ptr<dyn crate::xobjdetect::WBDetector>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xobjdetect_WBDetector_create(ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xobjdetect_WBDetector_create(ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::xobjdetect::WBDetector>::opencv_from_extern(ret) };
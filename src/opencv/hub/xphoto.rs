@@ -62,7 +62,7 @@ opencv_type_enum! { crate::xphoto::Bm3dSteps }
 
 /// Various inpainting algorithms
 /// ## See also
-/// inpaint
+/// `inpaint`
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum InpaintTypes {
@@ -119,7 +119,7 @@ pub fn apply_channel_gains(src: &dyn core::ToInputArray, dst: &mut dyn core::ToO
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_xphoto_applyChannelGains_const__InputArrayR_const__OutputArrayR_float_float_float(src.as_raw__InputArray(), dst.as_raw__OutputArray(), gain_b, gain_g, gain_r, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_xphoto_applyChannelGains_const__InputArrayR_const__OutputArrayR_float_float_float(src.as_raw__InputArray(), dst.as_raw__OutputArray(), gain_b, gain_g, gain_r, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -160,7 +160,7 @@ pub fn apply_channel_gains(src: &dyn core::ToInputArray, dst: &mut dyn core::ToO
 /// This function expected to be applied to grayscale images. Advanced usage of this function
 /// can be manual denoising of colored image in different colorspaces.
 /// ## See also
-/// fastNlMeansDenoising
+/// `fast_nl_means_denoising`
 /// 
 /// ## C++ default parameters
 /// * h: 1
@@ -180,12 +180,101 @@ pub fn bm3d_denoising(src: &dyn core::ToInputArray, dst_step1: &mut dyn core::To
 	input_output_array_arg!(dst_step1);
 	output_array_arg!(dst_step2);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_xphoto_bm3dDenoising_const__InputArrayR_const__InputOutputArrayR_const__OutputArrayR_float_int_int_int_int_int_int_float_int_int_int(src.as_raw__InputArray(), dst_step1.as_raw__InputOutputArray(), dst_step2.as_raw__OutputArray(), h, template_window_size, search_window_size, block_matching_step1, block_matching_step2, group_size, sliding_step, beta, norm_type, step, transform_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_xphoto_bm3dDenoising_const__InputArrayR_const__InputOutputArrayR_const__OutputArrayR_float_int_int_int_int_int_int_float_int_int_int(src.as_raw__InputArray(), dst_step1.as_raw__InputOutputArray(), dst_step2.as_raw__OutputArray(), h, template_window_size, search_window_size, block_matching_step1, block_matching_step2, group_size, sliding_step, beta, norm_type, step, transform_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `bm3d_denoising()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct Bm3dDenoisingBuilder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst_step1: &'a mut dyn core::ToInputOutputArray,
+	dst_step2: &'a mut dyn core::ToOutputArray,
+	h: f32,
+	template_window_size: i32,
+	search_window_size: i32,
+	block_matching_step1: i32,
+	block_matching_step2: i32,
+	group_size: i32,
+	sliding_step: i32,
+	beta: f32,
+	norm_type: i32,
+	step: i32,
+	transform_type: i32,
+}
+
+impl<'a> Bm3dDenoisingBuilder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst_step1: &'a mut dyn core::ToInputOutputArray, dst_step2: &'a mut dyn core::ToOutputArray, norm_type: i32, step: i32, transform_type: i32) -> Self {
+		Self { src, dst_step1, dst_step2, h: 1., template_window_size: 4, search_window_size: 16, block_matching_step1: 2500, block_matching_step2: 400, group_size: 8, sliding_step: 1, beta: 2.0, norm_type, step, transform_type }
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_h(mut self, h: f32) -> Self {
+		self.h = h;
+		self
+	}
+	
+	/// C++ default: `4`
+	#[inline]
+	pub fn with_template_window_size(mut self, template_window_size: i32) -> Self {
+		self.template_window_size = template_window_size;
+		self
+	}
+	
+	/// C++ default: `16`
+	#[inline]
+	pub fn with_search_window_size(mut self, search_window_size: i32) -> Self {
+		self.search_window_size = search_window_size;
+		self
+	}
+	
+	/// C++ default: `2500`
+	#[inline]
+	pub fn with_block_matching_step1(mut self, block_matching_step1: i32) -> Self {
+		self.block_matching_step1 = block_matching_step1;
+		self
+	}
+	
+	/// C++ default: `400`
+	#[inline]
+	pub fn with_block_matching_step2(mut self, block_matching_step2: i32) -> Self {
+		self.block_matching_step2 = block_matching_step2;
+		self
+	}
+	
+	/// C++ default: `8`
+	#[inline]
+	pub fn with_group_size(mut self, group_size: i32) -> Self {
+		self.group_size = group_size;
+		self
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_sliding_step(mut self, sliding_step: i32) -> Self {
+		self.sliding_step = sliding_step;
+		self
+	}
+	
+	/// C++ default: `2.0f`
+	#[inline]
+	pub fn with_beta(mut self, beta: f32) -> Self {
+		self.beta = beta;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		bm3d_denoising(self.src, self.dst_step1, self.dst_step2, self.h, self.template_window_size, self.search_window_size, self.block_matching_step1, self.block_matching_step2, self.group_size, self.sliding_step, self.beta, self.norm_type, self.step, self.transform_type)
+	}
+}
+
 /// Performs image denoising using the Block-Matching and 3D-filtering algorithm
 /// <http://www.cs.tut.fi/~foi/GCF-BM3D/BM3D_TIP_2007.pdf> with several computational
 /// optimizations. Noise expected to be a gaussian white noise.
@@ -221,7 +310,7 @@ pub fn bm3d_denoising(src: &dyn core::ToInputArray, dst_step1: &mut dyn core::To
 /// This function expected to be applied to grayscale images. Advanced usage of this function
 /// can be manual denoising of colored image in different colorspaces.
 /// ## See also
-/// fastNlMeansDenoising
+/// `fast_nl_means_denoising`
 /// 
 /// ## C++ default parameters
 /// * h: 1
@@ -240,17 +329,105 @@ pub fn bm3d_denoising_1(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutp
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_xphoto_bm3dDenoising_const__InputArrayR_const__OutputArrayR_float_int_int_int_int_int_int_float_int_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), h, template_window_size, search_window_size, block_matching_step1, block_matching_step2, group_size, sliding_step, beta, norm_type, step, transform_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_xphoto_bm3dDenoising_const__InputArrayR_const__OutputArrayR_float_int_int_int_int_int_int_float_int_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), h, template_window_size, search_window_size, block_matching_step1, block_matching_step2, group_size, sliding_step, beta, norm_type, step, transform_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
 
+/// Builder for `bm3d_denoising_1()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct Bm3dDenoising1Builder<'a> {
+	src: &'a dyn core::ToInputArray,
+	dst: &'a mut dyn core::ToOutputArray,
+	h: f32,
+	template_window_size: i32,
+	search_window_size: i32,
+	block_matching_step1: i32,
+	block_matching_step2: i32,
+	group_size: i32,
+	sliding_step: i32,
+	beta: f32,
+	norm_type: i32,
+	step: i32,
+	transform_type: i32,
+}
+
+impl<'a> Bm3dDenoising1Builder<'a> {
+	#[inline]
+	pub fn new(src: &'a dyn core::ToInputArray, dst: &'a mut dyn core::ToOutputArray, norm_type: i32, step: i32, transform_type: i32) -> Self {
+		Self { src, dst, h: 1., template_window_size: 4, search_window_size: 16, block_matching_step1: 2500, block_matching_step2: 400, group_size: 8, sliding_step: 1, beta: 2.0, norm_type, step, transform_type }
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_h(mut self, h: f32) -> Self {
+		self.h = h;
+		self
+	}
+	
+	/// C++ default: `4`
+	#[inline]
+	pub fn with_template_window_size(mut self, template_window_size: i32) -> Self {
+		self.template_window_size = template_window_size;
+		self
+	}
+	
+	/// C++ default: `16`
+	#[inline]
+	pub fn with_search_window_size(mut self, search_window_size: i32) -> Self {
+		self.search_window_size = search_window_size;
+		self
+	}
+	
+	/// C++ default: `2500`
+	#[inline]
+	pub fn with_block_matching_step1(mut self, block_matching_step1: i32) -> Self {
+		self.block_matching_step1 = block_matching_step1;
+		self
+	}
+	
+	/// C++ default: `400`
+	#[inline]
+	pub fn with_block_matching_step2(mut self, block_matching_step2: i32) -> Self {
+		self.block_matching_step2 = block_matching_step2;
+		self
+	}
+	
+	/// C++ default: `8`
+	#[inline]
+	pub fn with_group_size(mut self, group_size: i32) -> Self {
+		self.group_size = group_size;
+		self
+	}
+	
+	/// C++ default: `1`
+	#[inline]
+	pub fn with_sliding_step(mut self, sliding_step: i32) -> Self {
+		self.sliding_step = sliding_step;
+		self
+	}
+	
+	/// C++ default: `2.0f`
+	#[inline]
+	pub fn with_beta(mut self, beta: f32) -> Self {
+		self.beta = beta;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<()> {
+		bm3d_denoising_1(self.src, self.dst, self.h, self.template_window_size, self.search_window_size, self.block_matching_step1, self.block_matching_step2, self.group_size, self.sliding_step, self.beta, self.norm_type, self.step, self.transform_type)
+	}
+}
+
 /// Creates an instance of GrayworldWB
 #[inline]
 pub fn create_grayworld_wb() -> Result<core::Ptr<dyn crate::xphoto::GrayworldWB>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_xphoto_createGrayworldWB(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_xphoto_createGrayworldWB(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::xphoto::GrayworldWB>::opencv_from_extern(ret) };
@@ -268,7 +445,7 @@ pub fn create_grayworld_wb() -> Result<core::Ptr<dyn crate::xphoto::GrayworldWB>
 pub fn create_learning_based_wb(path_to_model: &str) -> Result<core::Ptr<dyn crate::xphoto::LearningBasedWB>> {
 	extern_container_arg!(path_to_model);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_xphoto_createLearningBasedWB_const_StringR(path_to_model.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_xphoto_createLearningBasedWB_const_StringR(path_to_model.opencv_as_extern(), ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::xphoto::LearningBasedWB>::opencv_from_extern(ret) };
@@ -279,7 +456,7 @@ pub fn create_learning_based_wb(path_to_model: &str) -> Result<core::Ptr<dyn cra
 #[inline]
 pub fn create_simple_wb() -> Result<core::Ptr<dyn crate::xphoto::SimpleWB>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_xphoto_createSimpleWB(ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_xphoto_createSimpleWB(ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::xphoto::SimpleWB>::opencv_from_extern(ret) };
@@ -307,13 +484,79 @@ pub fn create_simple_wb() -> Result<core::Ptr<dyn crate::xphoto::SimpleWB>> {
 #[inline]
 pub fn create_tonemap_durand(gamma: f32, contrast: f32, saturation: f32, sigma_color: f32, sigma_space: f32) -> Result<core::Ptr<dyn crate::xphoto::TonemapDurand>> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_xphoto_createTonemapDurand_float_float_float_float_float(gamma, contrast, saturation, sigma_color, sigma_space, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_xphoto_createTonemapDurand_float_float_float_float_float(gamma, contrast, saturation, sigma_color, sigma_space, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	let ret = unsafe { core::Ptr::<dyn crate::xphoto::TonemapDurand>::opencv_from_extern(ret) };
 	Ok(ret)
 }
 
+/// Builder for `create_tonemap_durand()` with the C++ default parameters
+///
+/// The parameters without a translatable default value are passed to `new()`, the rest can be overridden with the
+/// `with_*()` methods before running the function with `call()`.
+pub struct CreateTonemapDurandBuilder {
+	gamma: f32,
+	contrast: f32,
+	saturation: f32,
+	sigma_color: f32,
+	sigma_space: f32,
+}
+
+impl CreateTonemapDurandBuilder {
+	#[inline]
+	pub fn new() -> Self {
+		Self { gamma: 1.0, contrast: 4.0, saturation: 1.0, sigma_color: 2.0, sigma_space: 2.0 }
+	}
+	
+	/// C++ default: `1.0f`
+	#[inline]
+	pub fn with_gamma(mut self, gamma: f32) -> Self {
+		self.gamma = gamma;
+		self
+	}
+	
+	/// C++ default: `4.0f`
+	#[inline]
+	pub fn with_contrast(mut self, contrast: f32) -> Self {
+		self.contrast = contrast;
+		self
+	}
+	
+	/// C++ default: `1.0f`
+	#[inline]
+	pub fn with_saturation(mut self, saturation: f32) -> Self {
+		self.saturation = saturation;
+		self
+	}
+	
+	/// C++ default: `2.0f`
+	#[inline]
+	pub fn with_sigma_color(mut self, sigma_color: f32) -> Self {
+		self.sigma_color = sigma_color;
+		self
+	}
+	
+	/// C++ default: `2.0f`
+	#[inline]
+	pub fn with_sigma_space(mut self, sigma_space: f32) -> Self {
+		self.sigma_space = sigma_space;
+		self
+	}
+	
+	#[inline]
+	pub fn call(self) -> Result<core::Ptr<dyn crate::xphoto::TonemapDurand>> {
+		create_tonemap_durand(self.gamma, self.contrast, self.saturation, self.sigma_color, self.sigma_space)
+	}
+}
+
+impl Default for CreateTonemapDurandBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 /// The function implements simple dct-based denoising
 /// 
 /// <http://www.ipol.im/pub/art/2011/ys-dct/>.
@@ -323,14 +566,14 @@ pub fn create_tonemap_durand(gamma: f32, contrast: f32, saturation: f32, sigma_c
 /// * sigma: expected noise standard deviation
 /// * psize: size of block side where dct is computed
 /// ## See also
-/// fastNlMeansDenoising
+/// `fast_nl_means_denoising`
 /// 
 /// ## C++ default parameters
 /// * psize: 16
 #[inline]
 pub fn dct_denoising(src: &core::Mat, dst: &mut core::Mat, sigma: f64, psize: i32) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_xphoto_dctDenoising_const_MatR_MatR_const_double_const_int(src.as_raw_Mat(), dst.as_raw_mut_Mat(), sigma, psize, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_xphoto_dctDenoising_const_MatR_MatR_const_double_const_int(src.as_raw_Mat(), dst.as_raw_mut_Mat(), sigma, psize, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -354,7 +597,7 @@ pub fn dct_denoising(src: &core::Mat, dst: &mut core::Mat, sigma: f64, psize: i3
 #[inline]
 pub fn inpaint(src: &core::Mat, mask: &core::Mat, dst: &mut core::Mat, algorithm_type: i32) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_xphoto_inpaint_const_MatR_const_MatR_MatR_const_int(src.as_raw_Mat(), mask.as_raw_Mat(), dst.as_raw_mut_Mat(), algorithm_type, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_xphoto_inpaint_const_MatR_const_MatR_MatR_const_int(src.as_raw_Mat(), mask.as_raw_Mat(), dst.as_raw_mut_Mat(), algorithm_type, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -372,7 +615,7 @@ pub fn oil_painting_1(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutput
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_xphoto_oilPainting_const__InputArrayR_const__OutputArrayR_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), size, dyn_ratio, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_xphoto_oilPainting_const__InputArrayR_const__OutputArrayR_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), size, dyn_ratio, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -391,7 +634,7 @@ pub fn oil_painting(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputAr
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_xphoto_oilPainting_const__InputArrayR_const__OutputArrayR_int_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), size, dyn_ratio, code, ocvrs_return.as_mut_ptr()) };
+	unsafe { ffi_call!(cv_xphoto_oilPainting_const__InputArrayR_const__OutputArrayR_int_int_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), size, dyn_ratio, code, ocvrs_return.as_mut_ptr())) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -408,7 +651,7 @@ pub fn oil_painting(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputAr
 /// finding average pixel values.
 /// 
 /// Saturation is calculated using the following for a 3-channel RGB image per
-/// pixel I and is in the range [0, 1]:
+/// pixel I and is in the range \[0, 1\]:
 /// 
 /// ![block formula](https://latex.codecogs.com/png.latex?%20%5Ctexttt%7BSaturation%7D%20%5BI%5D%20%3D%20%5Cfrac%7B%5Ctextrm%7Bmax%7D%28R%2CG%2CB%29%20%2D%20%5Ctextrm%7Bmin%7D%28R%2CG%2CB%29%0A%7D%7B%5Ctextrm%7Bmax%7D%28R%2CG%2CB%29%7D%20)
 /// 
@@ -416,18 +659,19 @@ pub fn oil_painting(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputAr
 /// threshold of 0 means no pixels are used. Lower thresholds are useful in
 /// white-balancing saturated images.
 /// 
-/// Currently supports images of type @ref CV_8UC3 and @ref CV_16UC3.
+/// Currently supports images of type `CV_8UC3` and `CV_16UC3`.
 pub trait GrayworldWBConst: crate::xphoto::WhiteBalancerConst {
 	fn as_raw_GrayworldWB(&self) -> *const c_void;
 
 	/// Maximum saturation for a pixel to be included in the
 	///    gray-world assumption
 	/// ## See also
-	/// setSaturationThreshold
+	/// `set_saturation_threshold`
 	#[inline]
 	fn get_saturation_threshold(&self) -> Result<f32> {
+		crate::core::validity::check("GrayworldWB", self.as_raw_GrayworldWB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xphoto_GrayworldWB_getSaturationThreshold_const(self.as_raw_GrayworldWB(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xphoto_GrayworldWB_getSaturationThreshold_const(self.as_raw_GrayworldWB(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -444,8 +688,9 @@ pub trait GrayworldWB: crate::xphoto::GrayworldWBConst + crate::xphoto::WhiteBal
 	/// setSaturationThreshold getSaturationThreshold
 	#[inline]
 	fn set_saturation_threshold(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("GrayworldWB", self.as_raw_mut_GrayworldWB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xphoto_GrayworldWB_setSaturationThreshold_float(self.as_raw_mut_GrayworldWB(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xphoto_GrayworldWB_setSaturationThreshold_float(self.as_raw_mut_GrayworldWB(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -455,7 +700,7 @@ pub trait GrayworldWB: crate::xphoto::GrayworldWBConst + crate::xphoto::WhiteBal
 
 /// More sophisticated learning-based automatic white balance algorithm.
 /// 
-/// As @ref GrayworldWB, this algorithm works by applying different gains to the input
+/// As [GrayworldWB](crate::xphoto::GrayworldWB), this algorithm works by applying different gains to the input
 /// image channels, but their computation is a bit more involved compared to the
 /// simple gray-world assumption. More details about the algorithm can be found in
 /// [Cheng2015](https://docs.opencv.org/4.5.5/d0/de3/citelist.html#CITEREF_Cheng2015) .
@@ -465,18 +710,19 @@ pub trait GrayworldWB: crate::xphoto::GrayworldWBConst + crate::xphoto::WhiteBal
 /// 
 /// ![block formula](https://latex.codecogs.com/png.latex?%20%5Cfrac%7B%5Ctextrm%7Bmax%7D%28R%2CG%2CB%29%7D%7B%5Ctexttt%7Brange%5Fmax%5Fval%7D%7D%20%3C%20%5Ctexttt%7Bsaturation%5Fthresh%7D%20)
 /// 
-/// Currently supports images of type @ref CV_8UC3 and @ref CV_16UC3.
+/// Currently supports images of type `CV_8UC3` and `CV_16UC3`.
 pub trait LearningBasedWBConst: crate::xphoto::WhiteBalancerConst {
 	fn as_raw_LearningBasedWB(&self) -> *const c_void;
 
 	/// Maximum possible value of the input image (e.g. 255 for 8 bit images,
 	///            4095 for 12 bit images)
 	/// ## See also
-	/// setRangeMaxVal
+	/// `set_range_max_val`
 	#[inline]
 	fn get_range_max_val(&self) -> Result<i32> {
+		crate::core::validity::check("LearningBasedWB", self.as_raw_LearningBasedWB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xphoto_LearningBasedWB_getRangeMaxVal_const(self.as_raw_LearningBasedWB(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xphoto_LearningBasedWB_getRangeMaxVal_const(self.as_raw_LearningBasedWB(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -485,11 +731,12 @@ pub trait LearningBasedWBConst: crate::xphoto::WhiteBalancerConst {
 	/// Threshold that is used to determine saturated pixels, i.e. pixels where at least one of the
 	///    channels exceeds ![inline formula](https://latex.codecogs.com/png.latex?%5Ctexttt%7Bsaturation%5Fthreshold%7D%5Ctimes%5Ctexttt%7Brange%5Fmax%5Fval%7D) are ignored.
 	/// ## See also
-	/// setSaturationThreshold
+	/// `set_saturation_threshold`
 	#[inline]
 	fn get_saturation_threshold(&self) -> Result<f32> {
+		crate::core::validity::check("LearningBasedWB", self.as_raw_LearningBasedWB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xphoto_LearningBasedWB_getSaturationThreshold_const(self.as_raw_LearningBasedWB(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xphoto_LearningBasedWB_getSaturationThreshold_const(self.as_raw_LearningBasedWB(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -499,11 +746,12 @@ pub trait LearningBasedWBConst: crate::xphoto::WhiteBalancerConst {
 	///    by the algorithm. It often makes sense to increase the number of bins for images with higher bit depth
 	///    (e.g. 256 bins for a 12 bit image).
 	/// ## See also
-	/// setHistBinNum
+	/// `set_hist_bin_num`
 	#[inline]
 	fn get_hist_bin_num(&self) -> Result<i32> {
+		crate::core::validity::check("LearningBasedWB", self.as_raw_LearningBasedWB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xphoto_LearningBasedWB_getHistBinNum_const(self.as_raw_LearningBasedWB(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xphoto_LearningBasedWB_getHistBinNum_const(self.as_raw_LearningBasedWB(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -530,10 +778,11 @@ pub trait LearningBasedWB: crate::xphoto::LearningBasedWBConst + crate::xphoto::
 	/// * dst: An array of four (r,g) chromaticity tuples corresponding to the features listed above.
 	#[inline]
 	fn extract_simple_features(&mut self, src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("LearningBasedWB", self.as_raw_mut_LearningBasedWB())?;
 		input_array_arg!(src);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xphoto_LearningBasedWB_extractSimpleFeatures_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_LearningBasedWB(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xphoto_LearningBasedWB_extractSimpleFeatures_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_LearningBasedWB(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -545,8 +794,9 @@ pub trait LearningBasedWB: crate::xphoto::LearningBasedWBConst + crate::xphoto::
 	/// setRangeMaxVal getRangeMaxVal
 	#[inline]
 	fn set_range_max_val(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("LearningBasedWB", self.as_raw_mut_LearningBasedWB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xphoto_LearningBasedWB_setRangeMaxVal_int(self.as_raw_mut_LearningBasedWB(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xphoto_LearningBasedWB_setRangeMaxVal_int(self.as_raw_mut_LearningBasedWB(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -558,8 +808,9 @@ pub trait LearningBasedWB: crate::xphoto::LearningBasedWBConst + crate::xphoto::
 	/// setSaturationThreshold getSaturationThreshold
 	#[inline]
 	fn set_saturation_threshold(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("LearningBasedWB", self.as_raw_mut_LearningBasedWB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xphoto_LearningBasedWB_setSaturationThreshold_float(self.as_raw_mut_LearningBasedWB(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xphoto_LearningBasedWB_setSaturationThreshold_float(self.as_raw_mut_LearningBasedWB(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -572,8 +823,9 @@ pub trait LearningBasedWB: crate::xphoto::LearningBasedWBConst + crate::xphoto::
 	/// setHistBinNum getHistBinNum
 	#[inline]
 	fn set_hist_bin_num(&mut self, val: i32) -> Result<()> {
+		crate::core::validity::check("LearningBasedWB", self.as_raw_mut_LearningBasedWB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xphoto_LearningBasedWB_setHistBinNum_int(self.as_raw_mut_LearningBasedWB(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xphoto_LearningBasedWB_setHistBinNum_int(self.as_raw_mut_LearningBasedWB(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -589,11 +841,12 @@ pub trait SimpleWBConst: crate::xphoto::WhiteBalancerConst {
 
 	/// Input image range minimum value
 	/// ## See also
-	/// setInputMin
+	/// `set_input_min`
 	#[inline]
 	fn get_input_min(&self) -> Result<f32> {
+		crate::core::validity::check("SimpleWB", self.as_raw_SimpleWB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xphoto_SimpleWB_getInputMin_const(self.as_raw_SimpleWB(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xphoto_SimpleWB_getInputMin_const(self.as_raw_SimpleWB(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -601,11 +854,12 @@ pub trait SimpleWBConst: crate::xphoto::WhiteBalancerConst {
 	
 	/// Input image range maximum value
 	/// ## See also
-	/// setInputMax
+	/// `set_input_max`
 	#[inline]
 	fn get_input_max(&self) -> Result<f32> {
+		crate::core::validity::check("SimpleWB", self.as_raw_SimpleWB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xphoto_SimpleWB_getInputMax_const(self.as_raw_SimpleWB(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xphoto_SimpleWB_getInputMax_const(self.as_raw_SimpleWB(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -613,11 +867,12 @@ pub trait SimpleWBConst: crate::xphoto::WhiteBalancerConst {
 	
 	/// Output image range minimum value
 	/// ## See also
-	/// setOutputMin
+	/// `set_output_min`
 	#[inline]
 	fn get_output_min(&self) -> Result<f32> {
+		crate::core::validity::check("SimpleWB", self.as_raw_SimpleWB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xphoto_SimpleWB_getOutputMin_const(self.as_raw_SimpleWB(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xphoto_SimpleWB_getOutputMin_const(self.as_raw_SimpleWB(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -625,11 +880,12 @@ pub trait SimpleWBConst: crate::xphoto::WhiteBalancerConst {
 	
 	/// Output image range maximum value
 	/// ## See also
-	/// setOutputMax
+	/// `set_output_max`
 	#[inline]
 	fn get_output_max(&self) -> Result<f32> {
+		crate::core::validity::check("SimpleWB", self.as_raw_SimpleWB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xphoto_SimpleWB_getOutputMax_const(self.as_raw_SimpleWB(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xphoto_SimpleWB_getOutputMax_const(self.as_raw_SimpleWB(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -637,11 +893,12 @@ pub trait SimpleWBConst: crate::xphoto::WhiteBalancerConst {
 	
 	/// Percent of top/bottom values to ignore
 	/// ## See also
-	/// setP
+	/// `set_p`
 	#[inline]
 	fn get_p(&self) -> Result<f32> {
+		crate::core::validity::check("SimpleWB", self.as_raw_SimpleWB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xphoto_SimpleWB_getP_const(self.as_raw_SimpleWB(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xphoto_SimpleWB_getP_const(self.as_raw_SimpleWB(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -657,8 +914,9 @@ pub trait SimpleWB: crate::xphoto::SimpleWBConst + crate::xphoto::WhiteBalancer
 	/// setInputMin getInputMin
 	#[inline]
 	fn set_input_min(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("SimpleWB", self.as_raw_mut_SimpleWB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xphoto_SimpleWB_setInputMin_float(self.as_raw_mut_SimpleWB(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xphoto_SimpleWB_setInputMin_float(self.as_raw_mut_SimpleWB(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -669,8 +927,9 @@ pub trait SimpleWB: crate::xphoto::SimpleWBConst + crate::xphoto::WhiteBalancer
 	/// setInputMax getInputMax
 	#[inline]
 	fn set_input_max(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("SimpleWB", self.as_raw_mut_SimpleWB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xphoto_SimpleWB_setInputMax_float(self.as_raw_mut_SimpleWB(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xphoto_SimpleWB_setInputMax_float(self.as_raw_mut_SimpleWB(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -681,8 +940,9 @@ pub trait SimpleWB: crate::xphoto::SimpleWBConst + crate::xphoto::WhiteBalancer
 	/// setOutputMin getOutputMin
 	#[inline]
 	fn set_output_min(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("SimpleWB", self.as_raw_mut_SimpleWB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xphoto_SimpleWB_setOutputMin_float(self.as_raw_mut_SimpleWB(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xphoto_SimpleWB_setOutputMin_float(self.as_raw_mut_SimpleWB(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -693,8 +953,9 @@ pub trait SimpleWB: crate::xphoto::SimpleWBConst + crate::xphoto::WhiteBalancer
 	/// setOutputMax getOutputMax
 	#[inline]
 	fn set_output_max(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("SimpleWB", self.as_raw_mut_SimpleWB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xphoto_SimpleWB_setOutputMax_float(self.as_raw_mut_SimpleWB(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xphoto_SimpleWB_setOutputMax_float(self.as_raw_mut_SimpleWB(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -705,8 +966,9 @@ pub trait SimpleWB: crate::xphoto::SimpleWBConst + crate::xphoto::WhiteBalancer
 	/// setP getP
 	#[inline]
 	fn set_p(&mut self, val: f32) -> Result<()> {
+		crate::core::validity::check("SimpleWB", self.as_raw_mut_SimpleWB())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xphoto_SimpleWB_setP_float(self.as_raw_mut_SimpleWB(), val, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xphoto_SimpleWB_setP_float(self.as_raw_mut_SimpleWB(), val, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -727,8 +989,9 @@ pub trait TonemapDurandConst: crate::photo::TonemapConst {
 
 	#[inline]
 	fn get_saturation(&self) -> Result<f32> {
+		crate::core::validity::check("TonemapDurand", self.as_raw_TonemapDurand())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xphoto_TonemapDurand_getSaturation_const(self.as_raw_TonemapDurand(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xphoto_TonemapDurand_getSaturation_const(self.as_raw_TonemapDurand(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -736,8 +999,9 @@ pub trait TonemapDurandConst: crate::photo::TonemapConst {
 	
 	#[inline]
 	fn get_contrast(&self) -> Result<f32> {
+		crate::core::validity::check("TonemapDurand", self.as_raw_TonemapDurand())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xphoto_TonemapDurand_getContrast_const(self.as_raw_TonemapDurand(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xphoto_TonemapDurand_getContrast_const(self.as_raw_TonemapDurand(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -745,8 +1009,9 @@ pub trait TonemapDurandConst: crate::photo::TonemapConst {
 	
 	#[inline]
 	fn get_sigma_space(&self) -> Result<f32> {
+		crate::core::validity::check("TonemapDurand", self.as_raw_TonemapDurand())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xphoto_TonemapDurand_getSigmaSpace_const(self.as_raw_TonemapDurand(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xphoto_TonemapDurand_getSigmaSpace_const(self.as_raw_TonemapDurand(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -754,8 +1019,9 @@ pub trait TonemapDurandConst: crate::photo::TonemapConst {
 	
 	#[inline]
 	fn get_sigma_color(&self) -> Result<f32> {
+		crate::core::validity::check("TonemapDurand", self.as_raw_TonemapDurand())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xphoto_TonemapDurand_getSigmaColor_const(self.as_raw_TonemapDurand(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xphoto_TonemapDurand_getSigmaColor_const(self.as_raw_TonemapDurand(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -768,8 +1034,9 @@ pub trait TonemapDurand: crate::photo::Tonemap + crate::xphoto::TonemapDurandCon
 
 	#[inline]
 	fn set_saturation(&mut self, saturation: f32) -> Result<()> {
+		crate::core::validity::check("TonemapDurand", self.as_raw_mut_TonemapDurand())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xphoto_TonemapDurand_setSaturation_float(self.as_raw_mut_TonemapDurand(), saturation, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xphoto_TonemapDurand_setSaturation_float(self.as_raw_mut_TonemapDurand(), saturation, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -777,8 +1044,9 @@ pub trait TonemapDurand: crate::photo::Tonemap + crate::xphoto::TonemapDurandCon
 	
 	#[inline]
 	fn set_contrast(&mut self, contrast: f32) -> Result<()> {
+		crate::core::validity::check("TonemapDurand", self.as_raw_mut_TonemapDurand())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xphoto_TonemapDurand_setContrast_float(self.as_raw_mut_TonemapDurand(), contrast, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xphoto_TonemapDurand_setContrast_float(self.as_raw_mut_TonemapDurand(), contrast, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -786,8 +1054,9 @@ pub trait TonemapDurand: crate::photo::Tonemap + crate::xphoto::TonemapDurandCon
 	
 	#[inline]
 	fn set_sigma_space(&mut self, sigma_space: f32) -> Result<()> {
+		crate::core::validity::check("TonemapDurand", self.as_raw_mut_TonemapDurand())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xphoto_TonemapDurand_setSigmaSpace_float(self.as_raw_mut_TonemapDurand(), sigma_space, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xphoto_TonemapDurand_setSigmaSpace_float(self.as_raw_mut_TonemapDurand(), sigma_space, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -795,8 +1064,9 @@ pub trait TonemapDurand: crate::photo::Tonemap + crate::xphoto::TonemapDurandCon
 	
 	#[inline]
 	fn set_sigma_color(&mut self, sigma_color: f32) -> Result<()> {
+		crate::core::validity::check("TonemapDurand", self.as_raw_mut_TonemapDurand())?;
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xphoto_TonemapDurand_setSigmaColor_float(self.as_raw_mut_TonemapDurand(), sigma_color, ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xphoto_TonemapDurand_setSigmaColor_float(self.as_raw_mut_TonemapDurand(), sigma_color, ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -819,16 +1089,18 @@ pub trait WhiteBalancer: core::AlgorithmTrait + crate::xphoto::WhiteBalancerCons
 	/// * src: Input image
 	/// * dst: White balancing result
 	/// ## See also
-	/// cvtColor, equalizeHist
+	/// `cvt_color`, `equalize_hist`
 	#[inline]
 	fn balance_white(&mut self, src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+		crate::core::validity::check("WhiteBalancer", self.as_raw_mut_WhiteBalancer())?;
 		input_array_arg!(src);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_xphoto_WhiteBalancer_balanceWhite_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_WhiteBalancer(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
+		unsafe { ffi_call!(cv_xphoto_WhiteBalancer_balanceWhite_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_WhiteBalancer(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr())) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
 	}
 	
 }
+pub use crate::manual::xphoto::*;
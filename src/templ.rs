@@ -104,6 +104,47 @@ macro_rules! return_send {
 	};
 }
 
+/// Calls the extern function from `sys`, with the `trace-ffi` feature the call is logged together with its arguments and
+/// duration (see `sys::FfiCallTrace`)
+///
+/// The `local` calls go to the extern function declared in the calling scope, they are used by the manual bindings that
+/// declare their own externs.
+#[cfg(not(feature = "trace-ffi"))]
+macro_rules! ffi_call {
+	(local $func: ident($($arg: expr),*)) => {
+		$func($($arg),*)
+	};
+	($func: ident($($arg: expr),*)) => {
+		$crate::sys::$func($($arg),*)
+	};
+}
+
+#[cfg(feature = "trace-ffi")]
+macro_rules! ffi_call {
+	(local $func: ident($($arg: expr),*)) => {{
+		#[allow(unused_mut)]
+		let mut trace = $crate::sys::FfiCallTrace::new(stringify!($func), <[&str]>::len(&[$(stringify!($arg)),*]));
+		let ret = $func($({
+			let arg = $arg;
+			trace.arg(&arg);
+			arg
+		}),*);
+		trace.finish();
+		ret
+	}};
+	($func: ident($($arg: expr),*)) => {{
+		#[allow(unused_mut)]
+		let mut trace = $crate::sys::FfiCallTrace::new(stringify!($func), <[&str]>::len(&[$(stringify!($arg)),*]));
+		let ret = $crate::sys::$func($({
+			let arg = $arg;
+			trace.arg(&arg);
+			arg
+		}),*);
+		trace.finish();
+		ret
+	}};
+}
+
 macro_rules! return_receive {
 	(unsafe $name_via: ident => $name: ident) => {
 		let $name = unsafe { $name_via.assume_init() };
@@ -23,6 +23,12 @@ pub trait Boxed: Sized {
 	/// that ownership is still retained in the original object. Use `into_raw()` if you want to transfer
 	/// ownership to another wrapper.
 	fn as_raw_mut(&mut self) -> *mut c_void;
+
+	/// Checks that the wrapper points to a live object, fails with `StsNullPtr` if the pointer is null or with
+	/// `StsObjectNotFound` if the object was already released (see `core::validity`)
+	fn validate(&self) -> crate::Result<()> {
+		crate::core::validity::check(std::any::type_name::<Self>(), self.as_raw())
+	}
 }
 
 #[macro_export]
@@ -31,12 +37,16 @@ macro_rules! opencv_type_boxed {
 		impl $crate::traits::Boxed for $type {
 			#[inline]
 			unsafe fn from_raw(ptr: *mut ::std::ffi::c_void) -> Self {
+				$crate::core::alloc_tracking::track_alloc(::std::any::type_name::<Self>(), ptr);
+				$crate::core::validity::track_wrap(ptr);
 				Self { ptr }
 			}
 
 			#[inline]
 			fn into_raw(self) -> *mut ::std::ffi::c_void {
-				::std::mem::ManuallyDrop::new(self).ptr
+				let ptr = ::std::mem::ManuallyDrop::new(self).ptr;
+				$crate::core::alloc_tracking::track_release(ptr);
+				ptr
 			}
 
 			#[inline]
@@ -48,6 +58,11 @@ macro_rules! opencv_type_boxed {
 			fn as_raw_mut(&mut self) -> *mut ::std::ffi::c_void {
 				self.ptr
 			}
+
+			#[inline]
+			fn validate(&self) -> $crate::Result<()> {
+				$crate::core::validity::check(stringify!($type), self.ptr)
+			}
 		}
 
 		impl $crate::traits::OpenCVType<'_> for $type {
@@ -115,7 +130,7 @@ macro_rules! boxed_cast_base {
 			fn from(s: $type) -> Self {
 				extern "C" { fn $extern_convert(val: *mut ::std::ffi::c_void) -> *mut ::std::ffi::c_void; }
 
-				unsafe { Self::from_raw($extern_convert(s.into_raw())) }
+				unsafe { Self::from_raw(ffi_call!(local $extern_convert(s.into_raw()))) }
 			}
 		}
 	};
@@ -131,7 +146,7 @@ macro_rules! boxed_cast_descendant {
 			fn try_from(s: $type) -> $crate::Result<Self> {
 				extern "C" { fn $extern_convert(val: *mut ::std::ffi::c_void) -> *mut ::std::ffi::c_void; }
 
-				let ret = unsafe { $extern_convert(s.into_raw()) };
+				let ret = unsafe { ffi_call!(local $extern_convert(s.into_raw())) };
 				if ret.is_null() {
 					Err($crate::Error::new($crate::core::StsBadArg, format!("Unable to cast base class: {} to: {}", stringify!($type), stringify!($descendant))))
 				} else {
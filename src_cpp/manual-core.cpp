@@ -1,4 +1,13 @@
 #include "core.hpp"
+#include <opencv2/opencv_modules.hpp>
+#ifdef HAVE_OPENCV_IMGPROC
+	#include <opencv2/imgproc.hpp>
+#endif
+#if (CV_VERSION_MAJOR == 3 && CV_VERSION_MINOR == 4 && CV_VERSION_REVISION >= 4) /* 3.4.4+ */ \
+	|| (CV_VERSION_MAJOR == 4) /* 4.0+ */
+	#define OCVRS_HAVE_ALLOCATOR_STATS
+	#include <opencv2/core/utils/allocator_stats.hpp>
+#endif
 
 template struct Result<void*>;
 template struct Result<cv::Size>;
@@ -22,6 +31,57 @@ template<typename T> inline void ocvrs_input_output_array(T* instance, Result<vo
 	} OCVRS_CATCH(Result<void*>)
 }
 
+// must match AllocatorStats in src/manual/core/alloc_tracking.rs
+struct ocvrs_AllocatorStats {
+	unsigned long long current_bytes;
+	unsigned long long peak_bytes;
+	unsigned long long total_bytes;
+	unsigned long long allocations;
+};
+
+// must match BatchOpKind in src/manual/core/batch.rs
+enum ocvrs_BatchOpKind {
+	OCVRS_BATCH_CVT_COLOR = 0,
+	OCVRS_BATCH_RESIZE = 1,
+	OCVRS_BATCH_NORMALIZE = 2,
+	OCVRS_BATCH_CONVERT_TO = 3,
+};
+
+// must match BatchOp in src/manual/core/batch.rs
+struct ocvrs_BatchOp {
+	ocvrs_BatchOpKind kind;
+	int flag;
+	int type;
+	cv::Size size;
+	double a;
+	double b;
+};
+
+inline void ocvrs_batch_op(const ocvrs_BatchOp& op, const cv::Mat& src, cv::Mat& dst) {
+	switch (op.kind) {
+		case OCVRS_BATCH_CVT_COLOR:
+		case OCVRS_BATCH_RESIZE:
+			#ifdef HAVE_OPENCV_IMGPROC
+				if (op.kind == OCVRS_BATCH_CVT_COLOR) {
+					cv::cvtColor(src, dst, op.flag, op.type);
+				} else {
+					cv::resize(src, dst, op.size, op.a, op.b, op.flag);
+				}
+				break;
+			#else
+				CV_Error(cv::Error::StsNotImplemented, "Batch operation requires imgproc module");
+			#endif
+		case OCVRS_BATCH_NORMALIZE:
+			cv::normalize(src, dst, op.a, op.b, op.flag, op.type);
+			break;
+		case OCVRS_BATCH_CONVERT_TO:
+			src.convertTo(dst, op.type, op.a, op.b);
+			break;
+		default:
+			CV_Error(cv::Error::StsBadArg, "Unknown batch operation");
+	}
+}
+
 #define ocvrs_ioa(base) \
 	void cv_##base##_input_array(const cv::base* instance, Result<void*>* ocvrs_return) { return ocvrs_input_array(instance, ocvrs_return); } \
 	void cv_##base##_output_array(cv::base* instance, Result<void*>* ocvrs_return) { return ocvrs_output_array(instance, ocvrs_return); } \
@@ -53,6 +113,42 @@ extern "C" {
 		} OCVRS_CATCH(Result_void)
 	}
 
+	void cv_manual_Batch_run(const cv::Mat* src, cv::Mat* dst, const ocvrs_BatchOp* ops, size_t ops_count, Result_void* ocvrs_return) {
+		try {
+			if (ops_count == 0) {
+				src->copyTo(*dst);
+			} else {
+				// intermediate results alternate between the two buffers, the last operation writes directly into dst
+				cv::Mat buf[2];
+				const cv::Mat* cur = src;
+				for (size_t i = 0; i < ops_count; ++i) {
+					cv::Mat& out = i + 1 == ops_count ? *dst : buf[i % 2];
+					ocvrs_batch_op(ops[i], *cur, out);
+					cur = &out;
+				}
+			}
+			Ok(ocvrs_return);
+		} OCVRS_CATCH(Result_void)
+	}
+
+	void cv_manual_getAllocatorStatistics(ocvrs_AllocatorStats* ocvrs_return) {
+		#ifdef OCVRS_HAVE_ALLOCATOR_STATS
+			const cv::utils::AllocatorStatisticsInterface& stats = cv::getAllocatorStatistics();
+			ocvrs_return->current_bytes = stats.getCurrentUsage();
+			ocvrs_return->peak_bytes = stats.getPeakUsage();
+			ocvrs_return->total_bytes = stats.getTotalUsage();
+			ocvrs_return->allocations = stats.getNumberOfAllocations();
+		#else
+			*ocvrs_return = ocvrs_AllocatorStats();
+		#endif
+	}
+
+	void cv_manual_resetAllocatorPeakUsage() {
+		#ifdef OCVRS_HAVE_ALLOCATOR_STATS
+			cv::getAllocatorStatistics().resetPeakUsage();
+		#endif
+	}
+
 	const unsigned char* cv_manual_Mat_data(const cv::Mat* instance) {
 		return instance->data;
 	}
@@ -79,6 +175,18 @@ extern "C" {
 		return instance->ptr != instance->sliceEnd;
 	}
 
+	void cv_manual_OutputMat_output_array(cv::Mat* instance, Result<void*>* ocvrs_return) {
+		try {
+			Ok<void*>(new cv::_OutputArray(cv::_InputArray::MAT + cv::_InputArray::FIXED_SIZE + cv::_InputArray::FIXED_TYPE + cv::ACCESS_WRITE, instance), ocvrs_return);
+		} OCVRS_CATCH(Result<void*>)
+	}
+
+	void cv_manual_OutputMat_input_output_array(cv::Mat* instance, Result<void*>* ocvrs_return) {
+		try {
+			Ok<void*>(new cv::_InputOutputArray(cv::_InputArray::MAT + cv::_InputArray::FIXED_SIZE + cv::_InputArray::FIXED_TYPE + cv::ACCESS_RW, instance), ocvrs_return);
+		} OCVRS_CATCH(Result<void*>)
+	}
+
 	void cv_InputArray_input_array(cv::_InputArray* instance, Result<void*>* ocvrs_return) { return ocvrs_input_array(instance, ocvrs_return); }
 	void cv_OutputArray_output_array(cv::_OutputArray* instance, Result<void*>* ocvrs_return) { return ocvrs_output_array(instance, ocvrs_return); }
 	void cv_InputOutputArray_input_output_array(cv::_InputOutputArray* instance, Result<void*>* ocvrs_return) { return ocvrs_input_output_array(instance, ocvrs_return); }
@@ -1,11 +1,152 @@
 #include "dnn.hpp"
+#include <map>
+#include <memory>
+#include <mutex>
 
 template struct Result<void*>;
 
+#if !(CV_VERSION_MAJOR == 3 && CV_VERSION_MINOR == 2)
+	#define OCVRS_HAVE_CUSTOM_LAYER
+#endif
+
+#ifdef OCVRS_HAVE_CUSTOM_LAYER
+// must match the trampolines in src/manual/dnn/custom_layer.rs
+typedef bool (*ocvrs_CustomLayerShapes)(void* user, const cv::dnn::LayerParams* params, const std::vector<cv::dnn::MatShape>* inputs, std::vector<cv::dnn::MatShape>* outputs, void* error);
+typedef bool (*ocvrs_CustomLayerForward)(void* user, const cv::dnn::LayerParams* params, const std::vector<cv::Mat>* inputs, std::vector<cv::Mat>* outputs, void* error);
+typedef void (*ocvrs_CustomLayerDrop)(void* user);
+
+struct ocvrs_CustomLayerError {
+	int code;
+	std::string message;
+};
+
+// Rust callbacks of a registered layer type, released once the type is unregistered and all its layers are destroyed
+struct ocvrs_CustomLayerCallbacks {
+	void* user;
+	ocvrs_CustomLayerShapes shapes;
+	ocvrs_CustomLayerForward forward;
+	ocvrs_CustomLayerDrop drop;
+
+	~ocvrs_CustomLayerCallbacks() {
+		drop(user);
+	}
+};
+
+inline std::mutex& ocvrs_custom_layers_mutex() {
+	static std::mutex mutex;
+	return mutex;
+}
+
+inline std::map<std::string, std::shared_ptr<ocvrs_CustomLayerCallbacks>>& ocvrs_custom_layers() {
+	static std::map<std::string, std::shared_ptr<ocvrs_CustomLayerCallbacks>> layers;
+	return layers;
+}
+
+class ocvrs_CustomLayer : public cv::dnn::Layer {
+public:
+	ocvrs_CustomLayer(const cv::dnn::LayerParams& params, std::shared_ptr<ocvrs_CustomLayerCallbacks> callbacks) : cv::dnn::Layer(params), params(params), callbacks(std::move(callbacks)) {}
+
+	bool getMemoryShapes(const std::vector<cv::dnn::MatShape>& inputs, const int requiredOutputs, std::vector<cv::dnn::MatShape>& outputs, std::vector<cv::dnn::MatShape>& internals) const CV_OVERRIDE {
+		if (!callbacks->shapes) {
+			return cv::dnn::Layer::getMemoryShapes(inputs, requiredOutputs, outputs, internals);
+		}
+		ocvrs_CustomLayerError error = { 0, std::string() };
+		if (!callbacks->shapes(callbacks->user, &params, &inputs, &outputs, &error)) {
+			CV_Error(error.code, error.message);
+		}
+		return false;
+	}
+
+	void forward(cv::InputArrayOfArrays inputs_arr, cv::OutputArrayOfArrays outputs_arr, cv::OutputArrayOfArrays internals_arr) CV_OVERRIDE {
+		// FP16 and OpenCL blobs are converted to CV_32F Mats and passed back into this function
+		if (inputs_arr.depth() == CV_16S || inputs_arr.isUMatVector()) {
+			forward_fallback(inputs_arr, outputs_arr, internals_arr);
+			return;
+		}
+		std::vector<cv::Mat> inputs, outputs;
+		inputs_arr.getMatVector(inputs);
+		outputs_arr.getMatVector(outputs);
+		// the callback can either write into the preallocated outputs or replace them
+		std::vector<cv::Mat> results(outputs);
+		ocvrs_CustomLayerError error = { 0, std::string() };
+		if (!callbacks->forward(callbacks->user, &params, &inputs, &results, &error)) {
+			CV_Error(error.code, error.message);
+		}
+		CV_Assert(results.size() == outputs.size() && "Custom layer forward must not change the number of outputs");
+		for (size_t i = 0; i < outputs.size(); ++i) {
+			if (results[i].data != outputs[i].data) {
+				CV_Assert(results[i].type() == outputs[i].type() && results[i].size == outputs[i].size && "Custom layer output must match the output shapes");
+				results[i].copyTo(outputs[i]);
+			}
+		}
+	}
+
+	static cv::Ptr<cv::dnn::Layer> create(cv::dnn::LayerParams& params) {
+		std::shared_ptr<ocvrs_CustomLayerCallbacks> callbacks;
+		{
+			std::lock_guard<std::mutex> lock(ocvrs_custom_layers_mutex());
+			auto it = ocvrs_custom_layers().find(params.type);
+			if (it == ocvrs_custom_layers().end()) {
+				CV_Error(cv::Error::StsObjectNotFound, "Custom layer type is not registered: " + params.type);
+			}
+			callbacks = it->second;
+		}
+		return cv::makePtr<ocvrs_CustomLayer>(params, callbacks);
+	}
+
+private:
+	cv::dnn::LayerParams params;
+	std::shared_ptr<ocvrs_CustomLayerCallbacks> callbacks;
+};
+#endif
+
 extern "C" {
 	void cv_dnn_LayerParams_LayerParams(Result<void*>* ocvrs_return) {
 		try {
 			return Ok<void*>(new cv::dnn::LayerParams(), ocvrs_return);
 		} OCVRS_CATCH(Result<void*>)
 	}
+
+	#ifdef OCVRS_HAVE_CUSTOM_LAYER
+		void cv_manual_dnn_CustomLayer_register(const char* type, void* user, ocvrs_CustomLayerShapes shapes, ocvrs_CustomLayerForward forward, ocvrs_CustomLayerDrop drop, Result_void* ocvrs_return) {
+			try {
+				// the callbacks take ownership of the user data right away, so it's released on any error below
+				std::shared_ptr<ocvrs_CustomLayerCallbacks> callbacks(new ocvrs_CustomLayerCallbacks { user, shapes, forward, drop });
+				bool is_new;
+				{
+					std::lock_guard<std::mutex> lock(ocvrs_custom_layers_mutex());
+					auto& slot = ocvrs_custom_layers()[type];
+					is_new = !slot;
+					slot = callbacks;
+				}
+				// the factory keeps a stack of constructors per type, so it's only pushed once to be popped by a single
+				// unregister; it's called outside of the lock because the factory calls `create` under its own lock
+				if (is_new) {
+					cv::dnn::LayerFactory::registerLayer(type, ocvrs_CustomLayer::create);
+				}
+				Ok(ocvrs_return);
+			} OCVRS_CATCH(Result_void)
+		}
+
+		void cv_manual_dnn_CustomLayer_unregister(const char* type, Result_void* ocvrs_return) {
+			try {
+				bool was_registered;
+				{
+					std::lock_guard<std::mutex> lock(ocvrs_custom_layers_mutex());
+					was_registered = ocvrs_custom_layers().erase(type) > 0;
+				}
+				// only the own constructor is popped, the types registered by OpenCV itself stay intact
+				if (was_registered) {
+					cv::dnn::LayerFactory::unregisterLayer(type);
+				}
+				Ok(ocvrs_return);
+			} OCVRS_CATCH(Result_void)
+		}
+
+		void cv_manual_dnn_CustomLayer_setError(void* error, int code, const char* message) {
+			ocvrs_CustomLayerError* dst = static_cast<ocvrs_CustomLayerError*>(error);
+			dst->code = code;
+			dst->message = message;
+		}
+	#endif
 }
@@ -12,6 +12,9 @@
 	#define CV_DNN_DONT_ADD_EXPERIMENTAL_NS
 	#define CV_DNN_DONT_ADD_INLINE_NS
 #endif
+#include <initializer_list>
+#include <string>
+
 #include <opencv2/core.hpp>
 
 #define OCVRS_ONLY_DEPENDENT_TYPES
@@ -27,6 +30,12 @@ catch (exc_type) { \
 CODE_CATCH(OCVRS_TYPE(return_type), cv::Exception& e, e.code, e.what()) \
 CODE_CATCH(OCVRS_TYPE(return_type), ..., -99999, "unspecified error in OpenCV guts")
 
+// like OCVRS_CATCH, but appends the called function name and the summary of the passed arrays to the error message,
+// the trailing arguments are the ocvrs_arg_desc initializers, e.g. {"src", ocvrs_describe_array(*src)}
+#define OCVRS_CATCH_CTX(return_type, func, ...) \
+CODE_CATCH(OCVRS_TYPE(return_type), cv::Exception& e, e.code, ocvrs_error_context(e.what(), func, {__VA_ARGS__}).c_str()) \
+CODE_CATCH(OCVRS_TYPE(return_type), ..., -99999, "unspecified error in OpenCV guts")
+
 #define VEC_CATCH(return_type) \
 CODE_CATCH(OCVRS_TYPE(return_type), std::out_of_range, cv::Error::Code::StsOutOfRange, "index out of bounds")
 
@@ -62,4 +71,50 @@ template<typename T> inline void Err(int code, const char* msg, T* ocvrs_return)
 	// it's ok to leave result uninitialized because the Rust implementation only assumes it as init if error_msg is NULL
 }
 
+struct ocvrs_arg_desc {
+	const char* name;
+	std::string desc;
+};
+
+// e.g. "480x640 CV_8UC3", never throws because it's called while handling an exception
+inline std::string ocvrs_describe_array(const cv::_InputArray& arr) {
+	static const char* const depths[] = {"CV_8U", "CV_8S", "CV_16U", "CV_16S", "CV_32S", "CV_32F", "CV_64F", "CV_16F"};
+	try {
+		if (arr.empty()) {
+			return "empty";
+		}
+		const cv::Size size = arr.size();
+		const int type = arr.type();
+		std::string out = std::to_string(size.height) + "x" + std::to_string(size.width) + " " + depths[CV_MAT_DEPTH(type)] + "C" + std::to_string(CV_MAT_CN(type));
+		if (arr.dims() > 2) {
+			out += ", dims: " + std::to_string(arr.dims());
+		}
+		return out;
+	} catch (...) {
+		return "?";
+	}
+}
+
+inline std::string ocvrs_error_context(const char* what, const char* func, std::initializer_list<ocvrs_arg_desc> args) {
+	std::string out = what;
+	while (!out.empty() && out.back() == '\n') {
+		out.pop_back();
+	}
+	out += "\n  in call: ";
+	out += func;
+	out += "(";
+	bool first = true;
+	for (const ocvrs_arg_desc& arg : args) {
+		if (!first) {
+			out += ", ";
+		}
+		first = false;
+		out += arg.name;
+		out += ": ";
+		out += arg.desc;
+	}
+	out += ")";
+	return out;
+}
+
 #endif
@@ -0,0 +1,146 @@
+use std::sync::{Mutex, MutexGuard};
+
+use once_cell::sync::Lazy;
+use opencv::{
+	core::{alloc_tracking, Scalar},
+	prelude::*,
+	Result,
+};
+#[cfg(feature = "alloc-tracking")]
+use opencv::core::{FileNode, FileStorage, MatExpr, PCA, Ptr, Range, RNG, SparseMat, SVD, TickMeter, UMat, UMatUsageFlags, Vector};
+
+/// The live object counts and the allocator statistics are global, the tests that check them must not run in parallel
+static SERIAL: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
+
+fn serial() -> MutexGuard<'static, ()> {
+	SERIAL.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+#[cfg(feature = "alloc-tracking")]
+fn live_count<T>() -> usize {
+	let type_name = std::any::type_name::<T>();
+	alloc_tracking::live_objects().into_iter()
+		.find(|o| o.type_name == type_name)
+		.map_or(0, |o| o.count)
+}
+
+/// The objects of the generated boxed types are unregistered on drop
+#[test]
+#[cfg(feature = "alloc-tracking")]
+fn generated_drop_is_tracked() -> Result<()> {
+	let _serial = serial();
+	let start = live_count::<UMat>();
+	let umat = UMat::new(UMatUsageFlags::USAGE_DEFAULT);
+	let umat2 = UMat::new(UMatUsageFlags::USAGE_DEFAULT);
+	assert_eq!(start + 2, live_count::<UMat>());
+	drop(umat);
+	assert_eq!(start + 1, live_count::<UMat>());
+	drop(umat2);
+	assert_eq!(start, live_count::<UMat>());
+
+	macro_rules! assert_default_tracked {
+		($($typ: ty),+ $(,)?) => {
+			$(
+				let start = live_count::<$typ>();
+				let obj = <$typ>::default()?;
+				assert_eq!(start + 1, live_count::<$typ>(), "{} is not registered", stringify!($typ));
+				drop(obj);
+				assert_eq!(start, live_count::<$typ>(), "{} is not unregistered on drop", stringify!($typ));
+			)+
+		};
+	}
+	assert_default_tracked!(FileNode, FileStorage, MatExpr, PCA, Range, RNG, SparseMat, SVD, TickMeter);
+	Ok(())
+}
+
+/// Every `Drop` impl in the generated bindings unregisters the object and poisons its address
+#[test]
+fn generated_drop_impls_track_release() {
+	let hub_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("src/opencv/hub");
+	let mut drop_impls = 0;
+	for entry in hub_dir.read_dir().expect("Can't read hub dir") {
+		let path = entry.expect("Can't read hub dir entry").path();
+		let src = std::fs::read_to_string(&path).expect("Can't read generated module");
+		for drop_impl in src.split("\nimpl Drop for ").skip(1) {
+			let drop_impl = drop_impl.split("\n}\n").next().unwrap_or(drop_impl);
+			let type_name = drop_impl.split_whitespace().next().unwrap_or_default();
+			assert!(drop_impl.contains("alloc_tracking::track_release("), "Drop for {} in {} isn't tracked", type_name, path.display());
+			assert!(drop_impl.contains("validity::track_drop("), "Drop for {} in {} doesn't poison", type_name, path.display());
+			drop_impls += 1;
+		}
+	}
+	assert!(drop_impls > 0);
+}
+
+#[test]
+#[cfg(feature = "alloc-tracking")]
+fn ptr_is_tracked() {
+	let _serial = serial();
+	let start = live_count::<Ptr<f32>>();
+	let ptr = Ptr::new(1f32);
+	assert_eq!(start + 1, live_count::<Ptr<f32>>());
+
+	let raw = ptr.into_raw();
+	assert_eq!(start, live_count::<Ptr<f32>>());
+	let ptr = unsafe { Ptr::<f32>::from_raw(raw) };
+	assert_eq!(start + 1, live_count::<Ptr<f32>>());
+
+	drop(ptr);
+	assert_eq!(start, live_count::<Ptr<f32>>());
+}
+
+#[test]
+#[cfg(feature = "alloc-tracking")]
+fn vector_is_tracked() {
+	let _serial = serial();
+	let start = live_count::<Vector<i32>>();
+	let vec = Vector::<i32>::from_slice(&[1, 2, 3]);
+	let cloned = vec.clone();
+	assert_eq!(start + 2, live_count::<Vector<i32>>());
+
+	let raw = cloned.into_raw();
+	assert_eq!(start + 1, live_count::<Vector<i32>>());
+	let cloned = unsafe { Vector::<i32>::from_raw(raw) };
+	assert_eq!(start + 2, live_count::<Vector<i32>>());
+
+	drop(cloned);
+	drop(vec);
+	assert_eq!(start, live_count::<Vector<i32>>());
+}
+
+#[test]
+#[cfg(feature = "alloc-tracking")]
+fn live_objects() -> Result<()> {
+	let _serial = serial();
+	let start = live_count::<Mat>();
+	let mat = Mat::new_rows_cols_with_default(100, 100, f32::typ(), Scalar::all(1.))?;
+	let cloned = mat.clone();
+	assert_eq!(start + 2, live_count::<Mat>());
+	assert!(alloc_tracking::dump_live_objects().contains("opencv::core::Mat: "));
+
+	let raw = cloned.into_raw();
+	assert_eq!(start + 1, live_count::<Mat>());
+	let cloned = unsafe { Mat::from_raw(raw) };
+	assert_eq!(start + 2, live_count::<Mat>());
+
+	drop(cloned);
+	drop(mat);
+	assert_eq!(start, live_count::<Mat>());
+	Ok(())
+}
+
+#[test]
+fn allocator_stats() -> Result<()> {
+	let _serial = serial();
+	alloc_tracking::reset_peak_bytes();
+	let before = alloc_tracking::allocator_stats();
+	let mat = Mat::new_rows_cols_with_default(1000, 1000, u8::typ(), Scalar::all(1.))?;
+	let after = alloc_tracking::allocator_stats();
+	// statistics can be disabled in the OpenCV build
+	if after.allocations > 0 {
+		assert!(after.total_bytes >= before.total_bytes + 1_000_000);
+		assert!(after.peak_bytes >= 1_000_000);
+	}
+	drop(mat);
+	Ok(())
+}
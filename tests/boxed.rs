@@ -1,10 +1,10 @@
 use std::{
 	ffi::c_void,
-	mem::transmute,
+	mem::{ManuallyDrop, transmute},
 };
 
 use opencv::{
-	core::{Algorithm, Scalar, Vec4f},
+	core::{self, Algorithm, Scalar, validity, Vec4f},
 	prelude::*,
 	Result,
 	types::{PtrOfFeature2D, VectorOfVec4f},
@@ -168,3 +168,64 @@ fn cast_descendant_fail() -> Result<()> {
 	}
 	Ok(())
 }
+
+/// Released Mat data and stale wrappers of the dropped objects produce errors
+#[test]
+fn released_object_validity() -> Result<()> {
+	let mut mat = Mat::new_rows_cols_with_default(2, 2, u8::typ(), Scalar::all(1.))?;
+	mat.validate()?;
+	mat.release()?;
+	mat.validate()?;
+	assert!(mat.data_bytes_mut().is_err());
+	assert_eq!(core::StsNullPtr, unsafe { mat.data_typed_unchecked_mut::<u8>() }.unwrap_err().code);
+
+	// the released objects are only poisoned in the debug builds, in release the stale wrapper would reach OpenCV
+	if cfg!(debug_assertions) {
+		let ptr = Mat::new_rows_cols_with_default(2, 2, u8::typ(), Scalar::all(1.))?.into_raw();
+		assert!(!validity::is_poisoned(ptr));
+		let stale = ManuallyDrop::new(unsafe { Mat::from_raw(ptr) });
+		drop(unsafe { Mat::from_raw(ptr) });
+		assert!(validity::is_poisoned(ptr));
+		assert_eq!(core::StsObjectNotFound, stale.validate().unwrap_err().code);
+		assert_eq!(core::StsObjectNotFound, stale.try_clone().unwrap_err().code);
+	}
+	Ok(())
+}
+
+/// The inner object of a `Ptr` can reuse the address of a released wrapper, its methods must keep working
+#[test]
+fn validity_ptr_inner_address_reuse() -> Result<()> {
+	#![cfg(ocvrs_has_module_features2d)]
+	#[cfg(ocvrs_opencv_branch_4)]
+	use opencv::features2d::{AKAZE_DescriptorType::DESCRIPTOR_MLDB, KAZE_DiffusivityType::DIFF_PM_G2};
+	#[cfg(not(ocvrs_opencv_branch_4))]
+	use opencv::features2d::{AKAZE_DESCRIPTOR_MLDB as DESCRIPTOR_MLDB, KAZE_DIFF_PM_G2 as DIFF_PM_G2};
+
+	let d = <dyn AKAZE>::create(DESCRIPTOR_MLDB, 0, 3, 0.001, 4, 4, DIFF_PM_G2)?;
+	// simulate a wrapper released at the address that the inner object got afterwards
+	validity::track_drop(d.inner_as_raw());
+	let d: core::Ptr<dyn AKAZE> = unsafe { Boxed::from_raw(d.into_raw()) };
+	assert!(!validity::is_poisoned(d.inner_as_raw()));
+	assert_eq!(true, Feature2DTraitConst::empty(&d)?);
+	assert_eq!(3, d.get_descriptor_channels()?);
+	Ok(())
+}
+
+/// Addresses reused by the other threads right after the release must not be reported as released
+#[test]
+fn validity_address_reuse_across_threads() -> Result<()> {
+	let threads = (0..4)
+		.map(|_| std::thread::spawn(|| -> Result<()> {
+			for _ in 0..2000 {
+				let mat = Mat::new_rows_cols_with_default(1, 1, u8::typ(), Scalar::all(1.))?;
+				mat.validate()?;
+				assert_eq!(1, mat.rows());
+			}
+			Ok(())
+		}))
+		.collect::<Vec<_>>();
+	for thread in threads {
+		thread.join().expect("Thread panicked")?;
+	}
+	Ok(())
+}
@@ -0,0 +1,125 @@
+#![cfg(ocvrs_has_module_calib3d)]
+
+use opencv::{
+	calib3d::{
+		self,
+		hand_eye::{self, Pose},
+		stereo::{Disparity, Matcher, Preset},
+		targets::{self, TargetSpec},
+	},
+	core::{self, Point2f, Rect, Scalar, Size, Vec3d, Vector},
+	prelude::*,
+	Result,
+};
+
+#[test]
+fn stereo_matcher() -> Result<()> {
+	let mut scene = Mat::new_rows_cols_with_default(64, 136, core::CV_8UC1, Scalar::all(0.))?;
+	core::randu(&mut scene, &Scalar::all(0.), &Scalar::all(255.))?;
+	// the right view sees the scene shifted by 8 pixels
+	let left = Mat::roi(&scene, Rect::new(0, 0, 128, 64))?.try_clone()?;
+	let right = Mat::roi(&scene, Rect::new(8, 0, 128, 64))?.try_clone()?;
+
+	let mut matcher = Matcher::new(Preset::Balanced, 10)?;
+	let disparity = matcher.compute(&left, &right)?;
+	assert_eq!(core::CV_32F, disparity.as_mat().typ());
+	assert!((disparity.at(32, 64)?.unwrap() - 8.).abs() < 0.5);
+	// no match for the left border shorter than the disparity range
+	assert_eq!(None, disparity.at(32, 2)?);
+
+	let narrow = Mat::roi(&left, Rect::new(0, 0, 64, 64))?;
+	assert!(matcher.compute(&narrow, &right).is_err());
+	Ok(())
+}
+
+#[test]
+fn stereo_depth() -> Result<()> {
+	let mut raw = Mat::new_rows_cols_with_default(4, 4, core::CV_16SC1, Scalar::all(8. * 16.))?;
+	*raw.at_2d_mut::<i16>(1, 1)? = -16;
+	*raw.at_2d_mut::<i16>(2, 2)? = 0;
+	let disparity = Disparity::from_fixed_point(&raw, 0)?;
+	assert_eq!(Some(8.), disparity.at(0, 0)?);
+	assert_eq!(None, disparity.at(1, 1)?);
+
+	// f = 500, baseline = 0.1
+	let q = Mat::from_slice_2d(&[
+		[1., 0., 0., -2.],
+		[0., 1., 0., -2.],
+		[0., 0., 0., 500.],
+		[0., 0., 10., 0.],
+	])?;
+	let depth = disparity.to_depth(&q)?;
+	assert!((depth.at(0, 0)?.unwrap().z - 6.25).abs() < 1e-5);
+	assert_eq!(None, depth.at(1, 1)?);
+	assert_eq!(None, depth.at(2, 2)?);
+	let z = depth.depth()?;
+	assert_eq!(core::CV_32FC1, z.typ());
+	assert!(z.at_2d::<f32>(1, 1)?.is_nan());
+
+	assert!(disparity.to_depth(&Mat::eye(3, 3, core::CV_64F)?.to_mat()?).is_err());
+	assert!(Disparity::from_fixed_point(&Mat::default(), 0).is_err());
+	Ok(())
+}
+
+#[test]
+fn chessboard_target() -> Result<()> {
+	// 20 pixels per square
+	let target = targets::render(TargetSpec::Chessboard { inner_corners: Size::new(9, 6), square_size: 20. }, 25.4)?;
+	assert_eq!(Size::new(12 * 20, 9 * 20), target.image.size()?);
+	assert_eq!(54, target.object_points.len());
+	assert_eq!(20., target.object_points.get(1)?.x);
+	assert_eq!(20., target.object_points.get(9)?.y);
+
+	let mut corners = Vector::<Point2f>::new();
+	assert!(calib3d::find_chessboard_corners(&target.image, Size::new(9, 6), &mut corners, calib3d::CALIB_CB_ADAPTIVE_THRESH)?);
+	assert_eq!(54, corners.len());
+
+	let pdf = target.to_pdf()?;
+	assert!(pdf.starts_with(b"%PDF-1.4\n"));
+	assert!(pdf.ends_with(b"%%EOF\n"));
+
+	assert!(targets::render(TargetSpec::Chessboard { inner_corners: Size::new(9, 6), square_size: 1. }, 25.4).is_err());
+	Ok(())
+}
+
+#[test]
+#[cfg(ocvrs_has_module_aruco)]
+fn charuco_target() -> Result<()> {
+	use opencv::aruco::PREDEFINED_DICTIONARY_NAME;
+
+	let spec = TargetSpec::Charuco { squares: Size::new(5, 7), square_size: 30., marker_size: 22., dictionary: PREDEFINED_DICTIONARY_NAME::DICT_4X4_50 };
+	let target = targets::render(spec, 25.4)?;
+	assert_eq!(Size::new(7 * 30, 9 * 30), target.image.size()?);
+	assert_eq!(core::CV_8UC1, target.image.typ());
+	assert_eq!(4 * 6, target.object_points.len());
+	Ok(())
+}
+
+#[test]
+fn hand_eye() -> Result<()> {
+	let cam2gripper = Pose::from_rvec_tvec(Vec3d::from([0.1, -0.2, 0.05]), Vec3d::from([0.03, 0.01, 0.12]))?;
+	let target2base = Pose::from_rvec_tvec(Vec3d::from([0., 0., 0.3]), Vec3d::from([0.6, -0.1, 0.]))?;
+	let stations = vec![
+		([0.3, 0., 0.], [0.4, 0., 0.5]),
+		([0., 0.4, 0.1], [0.3, 0.2, 0.5]),
+		([-0.2, 0.1, 0.5], [0.5, -0.1, 0.4]),
+		([0.1, -0.3, -0.4], [0.4, 0.1, 0.6]),
+	]
+		.into_iter()
+		.map(|(rvec, tvec)| {
+			let gripper2base = Pose::from_rvec_tvec(Vec3d::from(rvec), Vec3d::from(tvec))?;
+			let target2cam = (gripper2base * cam2gripper).inverse() * target2base;
+			Ok((gripper2base, target2cam))
+		})
+		.collect::<Result<Vec<_>>>()?;
+
+	let out = hand_eye::calibrate(&stations, calib3d::HandEyeCalibrationMethod::CALIB_HAND_EYE_PARK)?;
+	let error = out.cam2gripper.inverse() * cam2gripper;
+	assert!(error.rotation_angle() < 1e-6);
+	assert!(error.translation_norm() < 1e-6);
+	assert!(out.residuals.rotation_max < 1e-4);
+	assert!(out.residuals.translation_max < 1e-6);
+
+	assert!(hand_eye::calibrate(&stations[..2], calib3d::HandEyeCalibrationMethod::CALIB_HAND_EYE_PARK).is_err());
+	Ok(())
+}
@@ -0,0 +1,83 @@
+#![cfg(ocvrs_has_module_ccalib)]
+
+use std::fs;
+
+use opencv::{
+	ccalib::{self, RigCalibration},
+	core::{self, Size},
+	prelude::*,
+	Result,
+};
+
+const RIG: &str = r#"%YAML:1.0
+---
+nCameras: 2
+camera_matrix_0: !!opencv-matrix
+   rows: 3
+   cols: 3
+   dt: d
+   data: [ 500., 0., 320., 0., 500., 240., 0., 0., 1. ]
+camera_distortion_0: !!opencv-matrix
+   rows: 1
+   cols: 4
+   dt: d
+   data: [ 0.1, -0.05, 0., 0. ]
+camera_pose_0: !!opencv-matrix
+   rows: 4
+   cols: 4
+   dt: d
+   data: [ 1., 0., 0., 0., 0., 1., 0., 0., 0., 0., 1., 0., 0., 0., 0., 1. ]
+camera_matrix_1: !!opencv-matrix
+   rows: 3
+   cols: 3
+   dt: d
+   data: [ 510., 0., 330., 0., 510., 250., 0., 0., 1. ]
+camera_distortion_1: !!opencv-matrix
+   rows: 1
+   cols: 4
+   dt: d
+   data: [ 0., 0., 0., 0. ]
+camera_pose_1: !!opencv-matrix
+   rows: 4
+   cols: 4
+   dt: d
+   data: [ 0., -1., 0., 0.5, 1., 0., 0., 0., 0., 0., 1., 0., 0., 0., 0., 1. ]
+meanReprojectError: 0.25
+"#;
+
+#[test]
+fn rig_calibration() -> Result<()> {
+	let path = std::env::temp_dir().join("opencv-rust-test-rig.yml");
+	fs::write(&path, RIG).unwrap();
+	let rig = RigCalibration::load(path.to_str().unwrap());
+	fs::remove_file(&path).unwrap();
+	let rig = rig?;
+
+	assert_eq!(2, rig.cameras.len());
+	assert_eq!(0.25, rig.reprojection_error);
+	assert_eq!(None, rig.cameras[1].xi);
+	assert_eq!(510., *rig.cameras[1].camera_matrix.at_2d::<f64>(0, 0)?);
+	assert_eq!(&[0.5, 0., 0.], rig.cameras[1].t.data_typed::<f64>()?);
+
+	// camera 0 seen from camera 1 is the inverse of the camera 1 pose
+	let (r, t) = rig.relative_pose(1, 0)?;
+	assert_eq!(&[0., 1., 0., -1., 0., 0., 0., 0., 1.], r.data_typed::<f64>()?);
+	assert_eq!(&[0., 0.5, 0.], t.data_typed::<f64>()?);
+	assert!(rig.relative_pose(0, 2).is_err());
+
+	#[cfg(ocvrs_has_module_sfm)]
+	{
+		let intrinsics = rig.cameras[0].intrinsics(Size::new(640, 480))?;
+		assert_eq!((500., 240.), (intrinsics.focal_length_x, intrinsics.principal_point_y));
+		assert_eq!(-0.05, intrinsics.polynomial_k2);
+	}
+	Ok(())
+}
+
+#[test]
+fn random_pattern() -> Result<()> {
+	let pattern = ccalib::generate_random_pattern(Size::new(200, 100))?;
+	assert_eq!(Size::new(200, 100), pattern.size()?);
+	assert_eq!(core::CV_8UC1, pattern.typ());
+	Ok(())
+}
@@ -1,5 +1,7 @@
+use std::convert::TryFrom;
+
 use opencv::{
-	core::{self, CV_32S, CV_64F, CV_8U, CV_MAKETYPE, Moments, Point2f, RotatedRect, Scalar, Size2f, Vec3b},
+	core::{self, CV_32S, CV_64F, CV_8U, CV_MAKETYPE, Moments, Point, Point2f, RotatedRect, Scalar, Size2f, Vec3b},
 	prelude::*,
 	Result,
 	types::VectorOfMat,
@@ -106,3 +108,213 @@ fn min_max_loc() -> Result<()> {
 	assert_eq!(30., max_val);
 	Ok(())
 }
+
+#[test]
+fn typed_results() -> Result<()> {
+	let mut m = Mat::new_rows_cols_with_default(3, 4, CV_64F, Scalar::all(2.))?;
+	*m.at_2d_mut::<f64>(1, 2)? = 10.;
+	*m.at_2d_mut::<f64>(2, 0)? = -4.;
+	let min_max = core::min_max(&m, &core::no_array())?;
+	assert_eq!(core::MinMax { min: -4., max: 10., min_loc: Point::new(0, 2), max_loc: Point::new(2, 1) }, min_max);
+
+	let mean_std = core::mean_std(&m, &core::no_array())?;
+	assert!((mean_std.mean[0] - 26. / 12.).abs() < 1e-9);
+	assert_eq!(0., mean_std.mean[1]);
+	assert!(mean_std.std_dev[0] > 0.);
+
+	assert_eq!(vec![8., 16., 2.], core::reduce_to_vec::<f64>(&m, 1, core::REDUCE_SUM)?);
+	assert_eq!(vec![2., 2., 10., 2.], core::reduce_to_vec::<f64>(&m, 0, core::REDUCE_MAX)?);
+	assert!(core::reduce_to_vec::<Vec3b>(&m, 0, core::REDUCE_MAX).is_err());
+	Ok(())
+}
+
+#[test]
+fn range_from() -> Result<()> {
+	let range = core::Range::try_from(2..5)?;
+	assert_eq!(2, range.start());
+	assert_eq!(5, range.end());
+	assert_eq!(3, range.size()?);
+	assert_eq!(2..5, std::ops::Range::from(&range));
+
+	let all = core::Range::try_from(..)?;
+	assert_eq!(core::Range::all()?.start(), all.start());
+	assert_eq!(core::Range::all()?.end(), all.end());
+	Ok(())
+}
+
+#[test]
+fn term_criteria() -> Result<()> {
+	let count = core::TermCriteria::max_iter(10);
+	assert_eq!(core::TermCriteria::new(core::TermCriteria_Type::COUNT as i32, 10, 0.)?, count);
+	assert!(count.has_max_iter());
+	assert!(!count.has_eps());
+	assert!(count.is_valid()?);
+	let eps = core::TermCriteria::eps(0.1);
+	assert!(!eps.has_max_iter());
+	assert!(eps.has_eps());
+	let both = core::TermCriteria::both(10, 0.1);
+	assert_eq!(3, both.typ);
+	assert!(both.has_max_iter() && both.has_eps());
+	Ok(())
+}
+
+#[test]
+fn stats_describe() -> Result<()> {
+	let mut m = Mat::new_rows_cols_with_default(2, 3, core::CV_32FC2, Scalar::new(1., 5., 0., 0.))?;
+	*m.at_2d_mut::<core::Vec2f>(1, 2)? = core::Vec2f::from([9., f32::NAN]);
+	let summary = core::stats::describe(&m)?;
+	assert_eq!(2, summary.len());
+	assert_eq!(6, summary[0].count);
+	assert_eq!((1., 9., 1.), (summary[0].min, summary[0].max, summary[0].median));
+	// NaN is skipped
+	assert_eq!(5, summary[1].count);
+	assert_eq!(5., summary[1].std_dev + summary[1].mean);
+
+	let columns = core::stats::describe_columns(&Mat::from_slice_2d(&[[1f64, 4.], [2., 4.], [3., 4.]])?)?;
+	assert_eq!(2, columns.len());
+	assert_eq!((1.5, 2., 2.5), (columns[0].q25, columns[0].median, columns[0].q75));
+	assert_eq!(0., columns[1].std_dev);
+	assert!(core::stats::describe_columns(&m).is_err());
+	Ok(())
+}
+
+#[test]
+fn coord_map() -> Result<()> {
+	use core::{CoordMap, Rect, Rect2f};
+
+	let map = CoordMap::crop(Rect::new(10, 20, 100, 100)).then(&CoordMap::scale(2., 4.));
+	assert_eq!(CoordMap::new(2., 4., -20., -80.), map);
+	assert_eq!(Point2f::new(0., 0.), map.apply(Point2f::new(10., 20.)));
+	assert_eq!(Point2f::new(15., 25.), map.invert(Point2f::new(10., 20.)));
+	assert_eq!(Rect2f::new(10., 20., 5., 2.), map.invert_rect(Rect2f::new(0., 0., 10., 8.)));
+	assert_eq!(Rect2f::new(0., 0., 10., 8.), map.apply_rect(Rect2f::new(10., 20., 5., 2.)));
+	assert_eq!(Rect::new(10, 20, 2, 1), map.invert_rect_i(Rect::new(1, 1, 2, 2)));
+	assert_eq!(CoordMap::identity(), map.then(&map.inverse()));
+	assert_eq!(CoordMap::default(), CoordMap::identity());
+	Ok(())
+}
+
+#[test]
+fn decomposition() -> Result<()> {
+	use core::decomposition::{Lda, Pca, Svd};
+
+	// points on the line y = 2x + 1 with a small offset
+	let samples = Mat::from_slice_2d(&[[0f32, 1., 0.1], [1., 3., -0.1], [2., 5., 0.1], [3., 7., -0.1], [4., 9., 0.]])?;
+	let pca = Pca::fit(&samples, 0)?;
+	let ratio = pca.explained_variance_ratio()?;
+	assert!((ratio.iter().sum::<f64>() - 1.).abs() < 1e-6);
+	assert!(ratio[0] > 0.99);
+	let restored = pca.back_project(&pca.project(&samples)?)?;
+	assert!(core::norm2(&samples, &restored, core::NORM_INF, &core::no_array())? < 1e-4);
+
+	let pca = Pca::fit(&samples, 1)?;
+	assert_eq!(1, pca.component_count());
+	assert!(pca.explained_variance_ratio()?[0] < 1.);
+	let loaded = Pca::load_from_str(&pca.save_to_string()?)?;
+	assert_eq!(pca.explained_variance_ratio()?, loaded.explained_variance_ratio()?);
+	assert_eq!(1, loaded.project(&samples)?.cols());
+	assert!(Lda::load_from_str(&pca.save_to_string()?).is_err());
+
+	let svd = Svd::fit(&samples)?;
+	let restored = svd.back_project(&svd.project(&samples)?)?;
+	let mut samples_f64 = Mat::default();
+	samples.convert_to(&mut samples_f64, CV_64F, 1., 0.)?;
+	let mut restored_f64 = Mat::default();
+	restored.convert_to(&mut restored_f64, CV_64F, 1., 0.)?;
+	assert!(core::norm2(&samples_f64, &restored_f64, core::NORM_INF, &core::no_array())? < 1e-4);
+	let svd = Svd::load_from_str(&svd.truncated(2)?.save_to_string()?)?;
+	assert_eq!(2, svd.component_count());
+	assert_eq!(2, svd.explained_variance_ratio()?.len());
+
+	let samples = Mat::from_slice_2d(&[[0f64, 0.], [0.1, 1.], [-0.1, 2.], [5., 0.], [5.1, 1.], [4.9, 2.]])?;
+	let labels = Mat::from_slice(&[0i32, 0, 0, 1, 1, 1])?;
+	let lda = Lda::fit(&samples, &labels, 0)?;
+	assert_eq!(1, lda.component_count());
+	let projected = lda.project(&samples)?;
+	let (a, b) = (*projected.at_2d::<f64>(0, 0)?, *projected.at_2d::<f64>(3, 0)?);
+	assert!((a - b).abs() > (a - *projected.at_2d::<f64>(1, 0)?).abs());
+	let loaded = Lda::load_from_str(&lda.save_to_string()?)?;
+	assert_eq!(lda.explained_variance()?, loaded.explained_variance()?);
+	assert!(Lda::fit(&samples, &Mat::from_slice(&[0i32, 1])?, 0).is_err());
+	Ok(())
+}
+
+#[test]
+fn linalg() -> Result<()> {
+	use core::linalg::{self, DecompMethod};
+
+	let a = Mat::from_slice_2d(&[[2f64, 1.], [1., 3.]])?;
+	let b = Mat::from_slice_2d(&[[3f64], [5.]])?;
+	for &method in &[DecompMethod::Lu, DecompMethod::Cholesky, DecompMethod::Svd, DecompMethod::Eig, DecompMethod::Qr] {
+		let solution = linalg::solve(&a, &b, method)?;
+		assert!((*solution.x.at_2d::<f64>(0, 0)? - 0.8).abs() < 1e-9);
+		assert!((*solution.x.at_2d::<f64>(1, 0)? - 1.4).abs() < 1e-9);
+		assert_eq!(2, solution.info.rank);
+	}
+	assert!((linalg::determinant(&a)? - 5.).abs() < 1e-9);
+	let inverse = linalg::invert(&a, DecompMethod::Lu)?;
+	assert!((*inverse.inverse.at_2d::<f64>(0, 0)? - 0.6).abs() < 1e-9);
+	let eigen = linalg::eigen(&a)?;
+	assert_eq!(2, eigen.values.rows());
+	assert!(*eigen.values.at_2d::<f64>(0, 0)? > *eigen.values.at_2d::<f64>(1, 0)?);
+
+	let singular = Mat::from_slice_2d(&[[1f64, 2.], [2., 4.]])?;
+	assert!(linalg::solve(&singular, &b, DecompMethod::Lu).is_err());
+	let solution = linalg::solve(&singular, &b, DecompMethod::Svd)?;
+	assert_eq!(1, solution.info.rank);
+	assert!(solution.info.condition_number > 1e10);
+	assert!(linalg::invert(&singular, DecompMethod::Lu).is_err());
+	assert!(linalg::invert(&singular, DecompMethod::Svd).is_ok());
+
+	let non_square = Mat::from_slice_2d(&[[1f64, 0.], [0., 1.], [1., 1.]])?;
+	assert!(linalg::solve(&non_square, &Mat::from_slice_2d(&[[1f64], [1.], [2.]])?, DecompMethod::Qr).is_ok());
+	assert!(linalg::solve(&non_square, &Mat::from_slice_2d(&[[1f64], [1.], [2.]])?, DecompMethod::Lu).is_err());
+	assert!(linalg::determinant(&non_square).is_err());
+	assert!(linalg::eigen(&Mat::from_slice_2d(&[[1f64, 2.], [0., 1.]])?).is_err());
+	Ok(())
+}
+
+#[test]
+fn estimate_similarity() -> Result<()> {
+	use core::{Matx33d, Point3d, Similarity, Vec3d};
+
+	// 90° around Z, scale 2
+	let expected = Similarity { r: Matx33d::from([0., -1., 0., 1., 0., 0., 0., 0., 1.]), t: Vec3d::from([1., 2., 3.]), s: 2. };
+	let src = vec![
+		Point3d::new(0., 0., 0.),
+		Point3d::new(1., 0., 0.),
+		Point3d::new(0., 1., 0.),
+		Point3d::new(0., 0., 1.),
+		Point3d::new(1., 2., 3.),
+	];
+	let dst = src.iter().map(|&p| expected.apply(p)).collect::<Vec<_>>();
+	assert_eq!(Point3d::new(1., 4., 3.), dst[1]);
+
+	let sim = core::estimate_similarity(&src, &dst, true)?;
+	assert!((sim.s - 2.).abs() < 1e-9);
+	for (&s, &d) in src.iter().zip(&dst) {
+		assert!((sim.apply(s) - d).norm() < 1e-9);
+		assert!((sim.inverse().apply(d) - s).norm() < 1e-9);
+	}
+	let mat = sim.to_mat()?;
+	assert!((*mat.at_2d::<f64>(0, 1)? + 2.).abs() < 1e-9);
+	assert!((*mat.at_2d::<f64>(2, 3)? - 3.).abs() < 1e-9);
+
+	let rigid = core::estimate_similarity(&src, &dst, false)?;
+	assert_eq!(1., rigid.s);
+	assert!((rigid.r[(1, 0)] - 1.).abs() < 1e-9);
+
+	// mirrored points still give a proper rotation
+	let mirrored = src.iter().map(|p| Point3d::new(p.x, p.y, -p.z)).collect::<Vec<_>>();
+	let sim = core::estimate_similarity(&src, &mirrored, true)?;
+	let r = sim.r;
+	let det = r[(0, 0)] * (r[(1, 1)] * r[(2, 2)] - r[(1, 2)] * r[(2, 1)])
+		- r[(0, 1)] * (r[(1, 0)] * r[(2, 2)] - r[(1, 2)] * r[(2, 0)])
+		+ r[(0, 2)] * (r[(1, 0)] * r[(2, 1)] - r[(1, 1)] * r[(2, 0)]);
+	assert!((det - 1.).abs() < 1e-9);
+
+	let collinear = vec![Point3d::new(0., 0., 0.), Point3d::new(1., 1., 1.), Point3d::new(2., 2., 2.)];
+	assert!(core::estimate_similarity(&collinear, &collinear, true).is_err());
+	assert!(core::estimate_similarity(&src, &dst[1..], true).is_err());
+	Ok(())
+}
@@ -93,3 +93,477 @@ fn dict_value() -> Result<()> {
 	}
 	Ok(())
 }
+
+#[test]
+fn masks_polygons_and_rle() -> Result<()> {
+	use opencv::{
+		core::{Point, Rect, Scalar, Size},
+		dnn::masks::{self, Rle},
+		imgproc,
+	};
+
+	let mask = Mat::from_slice_2d(&[[0u8, 1], [1, 1], [0, 0]])?;
+	let rle = masks::encode(&mask)?;
+	assert_eq!(Rle { size: Size::new(2, 3), counts: vec![1, 1, 1, 2, 1] }, rle);
+	assert_eq!(3, rle.area());
+	assert_eq!(Rect::new(0, 0, 2, 2), rle.bbox());
+	assert_eq!("11110", rle.to_compressed_string());
+	assert_eq!(rle, Rle::from_compressed_string(rle.size, "11110")?);
+	let decoded = masks::decode(&rle)?;
+	assert_eq!(&[0u8, 255, 255, 255, 0, 0][..], decoded.data_bytes()?);
+
+	let mut mask = Mat::new_rows_cols_with_default(50, 80, core::CV_8UC1, Scalar::all(0.))?;
+	imgproc::rectangle(&mut mask, Rect::new(20, 10, 41, 31), Scalar::all(255.), imgproc::FILLED, imgproc::LINE_8, 0)?;
+	imgproc::circle(&mut mask, Point::new(70, 40), 6, Scalar::all(255.), imgproc::FILLED, imgproc::LINE_8, 0)?;
+	let rle = masks::encode(&mask)?;
+	let restored = Rle::from_compressed_string(rle.size, &rle.to_compressed_string())?;
+	assert_eq!(rle, restored);
+	assert_eq!(core::count_non_zero(&mask)? as u64, rle.area());
+	assert_eq!(Rect::new(20, 10, 57, 37), rle.bbox());
+	let mut diff = Mat::default();
+	core::absdiff(&mask, &masks::decode(&restored)?, &mut diff)?;
+	assert_eq!(0, core::count_non_zero(&diff)?);
+
+	let mut polygons = masks::to_polygons(&mask, 1.)?;
+	assert_eq!(2, polygons.len());
+	polygons.sort_by_key(|polygon| polygon.len());
+	let mut rectangle = polygons[0].clone();
+	rectangle.sort_by_key(|p| (p.x, p.y));
+	assert_eq!(vec![Point::new(20, 10), Point::new(20, 40), Point::new(60, 10), Point::new(60, 40)], rectangle);
+
+	assert!(masks::decode(&Rle { size: Size::new(2, 2), counts: vec![1, 2] }).is_err());
+	assert!(Rle::from_compressed_string(Size::new(2, 2), "1a").is_err());
+	Ok(())
+}
+
+#[test]
+fn pose_decoding() -> Result<()> {
+	use opencv::{
+		core::{Point2f, Scalar, Size},
+		dnn::pose::{self, KEYPOINT_COUNT},
+	};
+
+	fn blob(shape: &[i32]) -> Result<Mat> {
+		Mat::new_nd_with_default(shape, core::CV_32F, Scalar::all(0.))
+	}
+
+	// top-down heatmaps: 16x12 output for 64x48 input, the nose and the left ankle are found
+	let mut heatmaps = blob(&[1, KEYPOINT_COUNT as i32, 16, 12])?;
+	{
+		let data = heatmaps.data_typed_mut::<f32>()?;
+		data[3 * 12 + 5] = 0.9;
+		data[15 * 16 * 12 + 10 * 12 + 2] = 0.7;
+		data[16 * 16 * 12 + 4 * 12 + 4] = 0.05;
+	}
+	let people = pose::decode_heatmaps(&heatmaps, Size::new(48, 64), 0.3)?;
+	assert_eq!(1, people.len());
+	let person = &people[0];
+	assert_eq!(2, person.keypoint_count());
+	assert_eq!(Some(Point2f::new(21.5, 13.5)), person.keypoints[0]);
+	assert_eq!(Some(Point2f::new(9.5, 41.5)), person.keypoints[15]);
+	assert_eq!(None, person.keypoints[16]);
+	assert!((person.score - 0.8).abs() < 1e-6);
+
+	// single pose regression with normalized [y, x, score]
+	let mut regression = blob(&[1, 1, KEYPOINT_COUNT as i32, 3])?;
+	regression.data_typed_mut::<f32>()?[..6].copy_from_slice(&[0.25, 0.5, 0.9, 0.5, 0.75, 0.1]);
+	let people = pose::decode_regression(&regression, Size::new(200, 100), 0.3)?;
+	assert_eq!(1, people.len());
+	assert_eq!(Some(Point2f::new(100., 25.)), people[0].keypoints[0]);
+	assert_eq!(None, people[0].keypoints[1]);
+
+	// two OpenPose skeletons of the nose, the neck and the shoulders
+	let (height, width) = (20, 40);
+	let mut output = blob(&[1, 57, height, width])?;
+	{
+		let data = output.data_typed_mut::<f32>()?;
+		let plane = (height * width) as usize;
+		for &offset in &[8, 28] {
+			let parts = [(0, offset, 3), (1, offset, 8), (2, offset - 4, 8), (5, offset + 4, 8)];
+			for &(part, x, y) in &parts {
+				data[part * plane + y * width as usize + x] = 0.8;
+			}
+			// neck -> right shoulder, neck -> left shoulder and neck -> nose affinity fields
+			let limbs = [((offset, 8), (offset - 4, 8), 31), ((offset, 8), (offset + 4, 8), 39), ((offset, 8), (offset, 3), 47)];
+			for &((xa, ya), (xb, yb), paf) in &limbs {
+				let (dx, dy) = (xb as f32 - xa as f32, yb as f32 - ya as f32);
+				let norm = dx.hypot(dy);
+				for i in 0..=20 {
+					let t = i as f32 / 20.;
+					let x = (xa as f32 + dx * t).round() as usize;
+					let y = (ya as f32 + dy * t).round() as usize;
+					data[paf * plane + y * width as usize + x] = dx / norm;
+					data[(paf + 1) * plane + y * width as usize + x] = dy / norm;
+				}
+			}
+		}
+	}
+	let mut people = pose::decode_openpose(&output, Size::new(width, height), 0.3)?;
+	assert_eq!(2, people.len());
+	people.sort_by(|a, b| a.keypoints[0].map(|p| p.x).partial_cmp(&b.keypoints[0].map(|p| p.x)).unwrap());
+	for (person, &offset) in people.iter().zip(&[8., 28.]) {
+		assert_eq!(3, person.keypoint_count());
+		assert_eq!(Some(Point2f::new(offset, 3.)), person.keypoints[0]);
+		assert_eq!(Some(Point2f::new(offset + 4., 8.)), person.keypoints[5]);
+		assert_eq!(Some(Point2f::new(offset - 4., 8.)), person.keypoints[6]);
+	}
+	assert!(pose::decode_openpose(&heatmaps, Size::new(48, 64), 0.3).is_err());
+
+	let mut canvas = Mat::new_rows_cols_with_default(height, width, core::CV_8UC3, Scalar::all(0.))?;
+	pose::draw(&mut canvas, &people, Scalar::new(0., 255., 0., 0.), 1)?;
+	assert!(core::count_non_zero(&canvas.reshape(1, 0)?)? > 0);
+	Ok(())
+}
+
+#[test]
+fn yolo_decode() -> Result<()> {
+	use opencv::{
+		core::{Rect2f, Scalar, Size},
+		dnn::decode::{YoloDecoder, YoloVersion},
+	};
+
+	fn output(shape: &[i32], values: &[f32]) -> Result<Mat> {
+		let mut out = Mat::new_nd_with_default(shape, core::CV_32F, Scalar::all(0.))?;
+		out.data_typed_mut::<f32>()?.copy_from_slice(values);
+		Ok(out)
+	}
+
+	let config = r#"{
+		"version": "yolov8",
+		"input_width": 640,
+		"input_height": 480,
+		"class_names": ["person", "car"],
+		"score_threshold": 0.5
+	}"#;
+	let decoder = YoloDecoder::load_from_str(config)?;
+	assert_eq!(YoloDecoder::new(YoloVersion::V8, Size::new(640, 480), 2).with_class_names(vec!["person", "car"]).with_score_threshold(0.5), decoder);
+
+	// 1x(4+C)xN: two overlapping persons and a car
+	#[rustfmt::skip]
+	let v8 = output(&[1, 6, 3], &[
+		100., 105., 300.,
+		100., 100., 200.,
+		50., 50., 40.,
+		50., 50., 20.,
+		0.9, 0.6, 0.1,
+		0.05, 0.1, 0.8,
+	])?;
+	let detections = decoder.decode(&[v8])?;
+	assert_eq!(2, detections.len());
+	assert_eq!(Rect2f::new(75., 75., 50., 50.), detections[0].rect);
+	assert_eq!(("person", 0), (detections[0].label.as_str(), detections[0].class_id));
+	assert!((detections[0].score - 0.9).abs() < 1e-6);
+	assert_eq!(Rect2f::new(280., 190., 40., 20.), detections[1].rect);
+	assert_eq!("car", detections[1].label);
+
+	// decoded YOLOv5 rows with the objectness
+	let decoder = YoloDecoder::new(YoloVersion::V5, Size::new(640, 640), 2);
+	let v5 = output(&[1, 2, 7], &[
+		320., 320., 64., 32., 0.9, 0.2, 0.8,
+		10., 10., 4., 4., 0.2, 0.9, 0.1,
+	])?;
+	let detections = decoder.decode(&[v5])?;
+	assert_eq!(1, detections.len());
+	assert_eq!((Rect2f::new(288., 304., 64., 32.), 1), (detections[0].rect, detections[0].class_id));
+	assert!((detections[0].score - 0.72).abs() < 1e-6);
+	assert_eq!("", detections[0].label);
+
+	// raw YOLOv5 logits of the 2x2 grid with a single anchor
+	let decoder = decoder.with_strides(vec![32]).with_anchors(vec![vec![(32., 32.)]]);
+	let mut raw = vec![0.; 2 * 2 * 7];
+	raw.chunks_exact_mut(7).for_each(|cell| cell[4] = -10.);
+	raw[3 * 7 + 4] = 10.;
+	raw[3 * 7 + 5] = 10.;
+	let detections = decoder.decode(&[output(&[1, 1, 2, 2, 7], &raw)?])?;
+	assert_eq!(1, detections.len());
+	assert_eq!((Rect2f::new(32., 32., 32., 32.), 0), (detections[0].rect, detections[0].class_id));
+	assert!(decoder.decode(&[output(&[1, 7], &[0.; 7])?]).is_err());
+
+	// YOLOX offsets in the 2x2 grid of stride 32
+	let decoder = YoloDecoder::new(YoloVersion::X, Size::new(64, 64), 1).with_strides(vec![32]);
+	let mut yolox = vec![0.; 4 * 6];
+	yolox[6..12].copy_from_slice(&[0.5, 0.5, 0., 0., 0.9, 0.9]);
+	let detections = decoder.decode(&[output(&[1, 4, 6], &yolox)?])?;
+	assert_eq!(1, detections.len());
+	assert_eq!(Rect2f::new(32., 0., 32., 32.), detections[0].rect);
+
+	let path = std::env::temp_dir().join("opencv-rust-yolo-decoder.json");
+	let path = path.to_str().unwrap();
+	let decoder = decoder.with_anchors(vec![vec![(10., 13.), (16., 30.)]]);
+	decoder.save(path)?;
+	assert_eq!(decoder, YoloDecoder::load(path)?);
+	std::fs::remove_file(path).ok();
+	Ok(())
+}
+
+#[test]
+fn preprocess_batch_letterbox() -> Result<()> {
+	use opencv::{
+		core::{CoordMap, Scalar, Size},
+		dnn::{self, PadMode, PreprocessTarget},
+	};
+
+	let wide = Mat::new_rows_cols_with_default(100, 200, core::CV_8UC3, Scalar::new(0., 0., 255., 0.))?;
+	let tall = Mat::new_rows_cols_with_default(100, 50, core::CV_8UC3, Scalar::new(255., 0., 0., 0.))?;
+	let frames = [wide, tall];
+	let batch = dnn::preprocess_batch(&frames, Size::new(64, 64), PadMode::Letterbox, PreprocessTarget::Cpu)?;
+	assert_eq!([2, 3, 64, 64], *batch.blob.mat_size());
+	assert_eq!(vec![CoordMap::new(0.32, 0.32, 0., 16.), CoordMap::new(0.64, 0.64, 16., 0.)], batch.transforms);
+	let at = |blob: &Mat, n: usize, c: usize, y: usize, x: usize| -> Result<f32> {
+		Ok(blob.data_typed::<f32>()?[((n * 3 + c) * 64 + y) * 64 + x])
+	};
+	let pad = 114. / 255.;
+	// red wide frame in RGB order, padded above and below
+	assert!((at(&batch.blob, 0, 0, 32, 32)? - 1.).abs() < 1e-6);
+	assert!(at(&batch.blob, 0, 2, 32, 32)?.abs() < 1e-6);
+	assert!((at(&batch.blob, 0, 1, 2, 32)? - pad).abs() < 1e-6);
+	// blue tall frame, padded on the left and right
+	assert!((at(&batch.blob, 1, 2, 32, 32)? - 1.).abs() < 1e-6);
+	assert!((at(&batch.blob, 1, 0, 32, 2)? - pad).abs() < 1e-6);
+
+	for &target in &[PreprocessTarget::OpenCl, PreprocessTarget::Cuda] {
+		let device_batch = dnn::preprocess_batch(&frames, Size::new(64, 64), PadMode::Letterbox, target)?;
+		assert_eq!(batch.transforms, device_batch.transforms);
+		assert!(core::norm2(&batch.blob, &device_batch.blob, core::NORM_INF, &core::no_array())? < 1e-3);
+	}
+
+	let stretched = dnn::preprocess_batch(&frames[..1], Size::new(32, 16), PadMode::Stretch, PreprocessTarget::Cpu)?;
+	assert_eq!([1, 3, 16, 32], *stretched.blob.mat_size());
+	assert_eq!(vec![CoordMap::scale(0.16, 0.16)], stretched.transforms);
+	assert!(dnn::preprocess_batch(&[], Size::new(64, 64), PadMode::Letterbox, PreprocessTarget::Cpu).is_err());
+	Ok(())
+}
+
+#[test]
+fn zoo_fetch_verified() -> Result<()> {
+	use std::{cell::Cell, fs, path::Path, rc::Rc};
+
+	use opencv::{
+		dnn::zoo::{self, Downloader, ModelFile, ModelSpec, Zoo},
+		Error,
+	};
+
+	struct FakeDownloader {
+		downloads: Rc<Cell<usize>>,
+	}
+
+	impl Downloader for FakeDownloader {
+		fn download(&self, url: &str, dest: &Path) -> Result<()> {
+			self.downloads.set(self.downloads.get() + 1);
+			let content = url.rsplit('/').next().unwrap().split('.').next().unwrap();
+			fs::write(dest, content).map_err(|e| Error::new(core::StsError, e.to_string()))
+		}
+	}
+
+	const SHA256_ABC: &str = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+	const SHA256_NET: &str = "a08a0fcbdeafd3c1b3a4b495b9a9c9d96850f08946b52bc0622347d3b6e73b78";
+	let dir = std::env::temp_dir().join(format!("opencv-rust-zoo-{}", std::process::id()));
+	fs::remove_dir_all(&dir).ok();
+	let downloads = Rc::new(Cell::new(0));
+	let zoo = Zoo::new(&dir)
+		.with_downloader(FakeDownloader { downloads: Rc::clone(&downloads) })
+		.with_model(ModelSpec::new("test", ModelFile::new("https://example.com/models/abc.onnx", SHA256_ABC.to_uppercase())))
+		.with_model(
+			ModelSpec::new("test_config", ModelFile::new("https://example.com/abc.bin", SHA256_ABC))
+				.with_config(ModelFile::new("https://example.com/net.txt", SHA256_NET)),
+		)
+		.with_model(ModelSpec::new("test_broken", ModelFile::new("https://example.com/xyz.onnx", SHA256_ABC)))
+		.with_model(ModelSpec::new("test_unpinned", ModelFile::new("https://example.com/abc.onnx", "")));
+	assert_eq!(4, zoo.models().len());
+
+	let fetched = zoo.fetch("test")?;
+	assert_eq!(dir.join("test").join("abc.onnx"), fetched.model);
+	assert_eq!(None, fetched.config);
+	assert_eq!(SHA256_ABC, zoo::sha256_file(&fetched.model)?);
+	assert_eq!(1, downloads.get());
+	// cached
+	zoo.fetch("test")?;
+	assert_eq!(1, downloads.get());
+
+	let fetched = zoo.fetch("test_config")?;
+	assert_eq!(3, downloads.get());
+	let config = fetched.config.unwrap();
+	assert_eq!("net", fs::read_to_string(&config).unwrap());
+	// the cached file not matching the pinned checksum is downloaded again
+	fs::write(&config, "corrupted").unwrap();
+	zoo.fetch("test_config")?;
+	assert_eq!(4, downloads.get());
+	assert_eq!("net", fs::read_to_string(&config).unwrap());
+
+	assert!(zoo.fetch("test_unpinned").is_err());
+	assert_eq!(4, downloads.get());
+	assert!(zoo.fetch("test_broken").is_err());
+	assert!(!dir.join("test_broken").join("xyz.onnx").exists());
+	assert!(zoo.fetch("unknown").is_err());
+
+	let empty = dir.join("empty");
+	fs::write(&empty, "").unwrap();
+	assert_eq!("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855", zoo::sha256_file(&empty)?);
+	let long = dir.join("long");
+	fs::write(&long, "abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq".repeat(3000)).unwrap();
+	assert_eq!(64, zoo::sha256_file(&long)?.len());
+	fs::write(&long, "abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq").unwrap();
+	assert_eq!("248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1", zoo::sha256_file(&long)?);
+	fs::remove_dir_all(&dir).ok();
+	Ok(())
+}
+
+#[test]
+#[cfg(ocvrs_opencv_branch_4)]
+fn quantize_calibration() -> Result<()> {
+	use opencv::{core::Scalar, dnn::quantization};
+
+	let mut net = Net::default()?;
+	net.set_inputs_names(&core::Vector::from_iter(vec!["data"]))?;
+	net.add_layer_to_prev("relu", "ReLU", &mut LayerParams::default()?)?;
+	let calibration = (0..4).map(|i| {
+		let mut blob = Mat::new_nd_with_default(&[1, 2, 4, 4], core::CV_32F, Scalar::all(0.)).unwrap();
+		for (j, v) in blob.data_typed_mut::<f32>().unwrap().iter_mut().enumerate() {
+			*v = ((i * 32 + j) % 17) as f32 / 8. - 1.;
+		}
+		blob
+	});
+	let quantized = quantization::quantize(&mut net, calibration)?;
+	assert_eq!(1, quantized.layers.len());
+	let relu = &quantized.layers[0];
+	assert_eq!("relu", relu.name);
+	assert!(relu.max_abs < 0.05);
+	assert!(relu.sqnr_db > 20.);
+	assert_eq!(Some(relu), quantized.worst_layer());
+
+	assert!(quantization::quantize(&mut net, Vec::<Mat>::new().into_iter()).is_err());
+	let params = quantization::QuantizeParams { inputs_dtype: core::CV_8U, ..Default::default() };
+	let blob = Mat::new_nd_with_default(&[1, 2, 4, 4], core::CV_32F, Scalar::all(1.))?;
+	assert!(quantization::quantize_with_params(&mut net, std::iter::once(blob), &params).is_err());
+	Ok(())
+}
+
+#[test]
+fn profile_report() -> Result<()> {
+	use opencv::{
+		core::Scalar,
+		dnn::profile::{self, LayerTiming, Profile},
+	};
+
+	let mut net = Net::default()?;
+	net.add_layer_to_prev("relu", "ReLU", &mut LayerParams::default()?)?;
+	let blob = Mat::new_nd_with_default(&[1, 2, 4, 4], core::CV_32F, Scalar::all(-1.))?;
+	let measured = profile::run(&mut net, &blob)?;
+	assert_eq!(1, measured.layers.len());
+	assert_eq!("relu", measured.layers[0].name);
+	assert_eq!("ReLU", measured.layers[0].typ);
+	assert!(measured.layers[0].flops.is_some());
+	assert!(measured.total_ms >= measured.layers[0].time_ms);
+
+	let report = Profile {
+		total_ms: 2.5,
+		layers: vec![
+			LayerTiming { id: 1, name: "conv\"1\"".to_string(), typ: "Convolution".to_string(), time_ms: 2., flops: Some(3_000_000) },
+			LayerTiming { id: 2, name: "relu;1".to_string(), typ: "ReLU".to_string(), time_ms: 0., flops: None },
+		],
+	};
+	assert_eq!(2., report.layers_ms());
+	assert_eq!(None, report.flops());
+	assert_eq!("conv\"1\"", report.slowest(1)[0].name);
+	assert_eq!("net;Convolution;conv\"1\" 2000\n", report.to_folded());
+	assert_eq!(
+		r#"{"total_ms": 2.5, "layers": [{"id": 1, "name": "conv\"1\"", "type": "Convolution", "time_ms": 2, "flops": 3000000}, {"id": 2, "name": "relu;1", "type": "ReLU", "time_ms": 0, "flops": null}]}"#,
+		report.to_json()
+	);
+	assert!(report.to_string().lines().nth(1).unwrap().contains("80.0"));
+	Ok(())
+}
+
+#[test]
+#[cfg(not(ocvrs_opencv_branch_32))]
+fn custom_layer_forward() -> Result<()> {
+	use opencv::{
+		core::{Scalar, Vector},
+		dnn::{custom_layer, MatShape},
+	};
+
+	custom_layer::register("RustScale", |params, inputs, outputs| {
+		let factor = params.get("factor")?.get_f64(-1)?;
+		let mut out = outputs.get(0)?;
+		inputs.get(0)?.convert_to(&mut out, -1, factor, 0.)
+	})?;
+	custom_layer::register_with_shapes(
+		"RustSum",
+		|_, inputs| {
+			let mut shape = inputs.get(0)?.to_vec();
+			shape[1] = 1;
+			Ok(Vector::from_iter(vec![MatShape::from_iter(shape)]))
+		},
+		|_, inputs, outputs| {
+			let input = inputs.get(0)?;
+			let shape = input.mat_size().to_vec();
+			let plane = (shape[2] * shape[3]) as usize;
+			let data = input.data_typed::<f32>()?;
+			let mut sum = Mat::new_nd_with_default(&[shape[0], 1, shape[2], shape[3]], core::CV_32F, Scalar::all(0.))?;
+			for (i, v) in sum.data_typed_mut::<f32>()?.iter_mut().enumerate() {
+				*v = (0..shape[1] as usize).map(|c| data[c * plane + i]).sum();
+			}
+			outputs.set(0, sum)
+		},
+	)?;
+	custom_layer::register("RustFail", |_, _, _| Err(Error::new(core::StsNotImplemented, "not yet")))?;
+
+	let mut net = Net::default()?;
+	let mut params = LayerParams::default()?;
+	params.set_f64("factor", &2.)?;
+	net.add_layer_to_prev("scale", "RustScale", &mut params)?;
+	net.add_layer_to_prev("sum", "RustSum", &mut LayerParams::default()?)?;
+	let blob = Mat::new_nd_with_default(&[1, 3, 2, 2], core::CV_32F, Scalar::all(1.5))?;
+	net.set_input(&blob, "", 1., Scalar::default())?;
+	let out = net.forward_single("")?;
+	assert_eq!([1, 1, 2, 2], *out.mat_size());
+	assert_eq!(&[9f32; 4][..], out.data_typed::<f32>()?);
+
+	let mut failing = Net::default()?;
+	failing.add_layer_to_prev("fail", "RustFail", &mut LayerParams::default()?)?;
+	failing.set_input(&blob, "", 1., Scalar::default())?;
+	let err = failing.forward_single("").unwrap_err();
+	assert_eq!(core::StsNotImplemented, err.code);
+	assert!(err.message.contains("not yet"));
+
+	for typ in &["RustScale", "RustSum", "RustFail"] {
+		custom_layer::unregister(typ)?;
+	}
+	assert!(custom_layer::register("", |_, _, _| Ok(())).is_err());
+	Ok(())
+}
+
+#[test]
+#[cfg(not(ocvrs_opencv_branch_32))]
+fn custom_layer_override() -> Result<()> {
+	use opencv::{core::Scalar, dnn::custom_layer};
+
+	fn run_abs() -> Result<f32> {
+		let mut net = Net::default()?;
+		net.add_layer_to_prev("abs", "AbsVal", &mut LayerParams::default()?)?;
+		let blob = Mat::new_nd_with_default(&[1, 1, 2, 2], core::CV_32F, Scalar::all(-1.5))?;
+		net.set_input(&blob, "", 1., Scalar::default())?;
+		let out = net.forward_single("")?;
+		Ok(out.data_typed::<f32>()?[0])
+	}
+
+	fn fill(value: f64) -> impl Fn(&LayerParams, &core::Vector<Mat>, &mut core::Vector<Mat>) -> Result<()> {
+		move |_, _, outputs| {
+			outputs.get(0)?.set_to(&Scalar::all(value), &core::no_array())?;
+			Ok(())
+		}
+	}
+
+	assert_eq!(1.5, run_abs()?);
+	custom_layer::register("AbsVal", fill(7.))?;
+	assert_eq!(7., run_abs()?);
+	// registering again only replaces the closure
+	custom_layer::register("AbsVal", fill(8.))?;
+	assert_eq!(8., run_abs()?);
+	custom_layer::unregister("AbsVal")?;
+	assert_eq!(1.5, run_abs()?);
+	// the OpenCV implementation is not removed by the extra unregister
+	custom_layer::unregister("AbsVal")?;
+	assert_eq!(1.5, run_abs()?);
+	Ok(())
+}
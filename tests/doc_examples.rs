@@ -0,0 +1,32 @@
+//! Examples that the binding generator appends to the generated docs, they are not run as doctests
+
+#[cfg(ocvrs_has_module_ml)]
+mod ml_svm_create {
+	include!("../binding-generator/doc_examples/ml_svm_create.rs");
+
+	#[test]
+	fn run() -> opencv::Result<()> {
+		example()
+	}
+}
+
+#[cfg(ocvrs_has_module_ml)]
+mod ml_traindata_create {
+	include!("../binding-generator/doc_examples/ml_traindata_create.rs");
+
+	#[test]
+	fn run() -> opencv::Result<()> {
+		example()
+	}
+}
+
+/// `no_run` example, it's only compiled
+#[cfg(ocvrs_has_module_sfm)]
+mod sfm_reconstruct {
+	include!("../binding-generator/doc_examples/sfm_reconstruct.rs");
+
+	#[test]
+	fn compiles() {
+		let _: fn() -> opencv::Result<()> = example;
+	}
+}
@@ -0,0 +1,31 @@
+use opencv::{
+	core,
+	fuzz,
+	prelude::*,
+	Result,
+};
+
+#[test]
+fn fuzz_entry_points() -> Result<()> {
+	assert_eq!(core::StsError, fuzz::catch_panic::<()>(|| panic!("boom")).unwrap_err().code);
+
+	let yaml = b"%YAML:1.0\n---\nwidth: 640\nsizes: [ 1, 2, 3 ]\nname: \"test\"\n";
+	assert_eq!(7, fuzz::file_storage(yaml)?);
+	assert!(fuzz::file_storage(b"\xff\xfe").is_err());
+	assert!(fuzz::file_storage(b"<?xml version=\"1.0\"?><opencv_storage><a>").is_err());
+
+	#[cfg(ocvrs_has_module_imgcodecs)]
+	{
+		assert!(fuzz::imdecode(b"")?.empty());
+		assert!(fuzz::imdecode(b"\x89PNG\r\n\x1a\n\x00\x00garbage").map(|m| m.empty()).unwrap_or(true));
+		let png = std::fs::read(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/pixel.png")).expect("Can't read test image");
+		assert!(!fuzz::imdecode(&png)?.empty());
+	}
+
+	#[cfg(ocvrs_has_module_ml)]
+	{
+		assert_eq!(3, fuzz::train_data_csv(b"1,2,0\n3,4,1\n5,6,0\n")?);
+		assert!(fuzz::train_data_csv(b"").is_err());
+	}
+	Ok(())
+}
@@ -0,0 +1,39 @@
+#![cfg(ocvrs_has_module_img_hash)]
+
+use opencv::{
+	core,
+	img_hash::{self, Hash},
+	prelude::*,
+	Result,
+};
+
+#[test]
+fn hash() -> Result<()> {
+	// left half is white
+	let image = Mat::from_exact_iter((0..64 * 64).map(|i| if i % 64 < 32 { 255u8 } else { 0 }))?.reshape(1, 64)?.try_clone()?;
+	let mut similar = image.try_clone()?;
+	*similar.at_2d_mut::<u8>(40, 40)? = 30;
+	let mut inverted = Mat::default();
+	core::bitwise_not(&image, &mut inverted, &core::no_array())?;
+
+	let a = Hash::average(&image)?;
+	assert_eq!(64, a.bit_count());
+	assert_eq!(0, a.hamming_distance(&Hash::average(&similar)?)?);
+	assert_eq!(64, a.hamming_distance(&Hash::average(&inverted)?)?);
+
+	let mut hasher = img_hash::AverageHash::create()?;
+	assert_eq!(a, Hash::compute(&mut hasher, &image)?);
+	assert_eq!(64., hasher.compare(&a.to_mat()?, &Hash::average(&inverted)?.to_mat()?)?);
+
+	let p = Hash::phash(&image)?;
+	assert!(p.hamming_distance(&Hash::radial_variance(&image)?).is_err());
+	assert_eq!(p, p.to_string().parse()?);
+
+	assert_eq!("00ff10", Hash::from(vec![0, 255, 16]).to_string());
+	assert!("0g".parse::<Hash>().is_err());
+	assert!("abc".parse::<Hash>().is_err());
+
+	let hashes = vec![a.clone(), Hash::average(&inverted)?, Hash::average(&similar)?];
+	assert_eq!(vec![(0, 2)], img_hash::near_duplicates(&hashes, 5)?);
+	Ok(())
+}
@@ -33,3 +33,29 @@ fn decode() -> Result<()> {
 
 	Ok(())
 }
+
+#[test]
+#[cfg(feature = "fast-jpeg")]
+fn decode_jpeg() -> Result<()> {
+	let blox = std::fs::read(std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/blox.jpg")).unwrap();
+	let reference = imgcodecs::imdecode(&Mat::from_slice(&blox)?, imgcodecs::IMREAD_COLOR)?;
+
+	let mut dest = imgcodecs::imdecode_jpeg(&blox, imgcodecs::IMREAD_COLOR)?;
+	assert_eq!(reference.size()?, dest.size()?);
+	assert_eq!(core::CV_8UC3, dest.typ());
+	assert!(core::norm2(&reference, &dest, core::NORM_INF, &core::no_array())? <= 2.);
+
+	// the buffer is reused for the same size and type
+	let data = dest.data();
+	imgcodecs::imdecode_jpeg_to(&blox, imgcodecs::IMREAD_COLOR | imgcodecs::IMREAD_IGNORE_ORIENTATION, &mut dest)?;
+	assert_eq!(data, dest.data());
+
+	let reduced = imgcodecs::imdecode_jpeg(&blox, imgcodecs::IMREAD_REDUCED_GRAYSCALE_2)?;
+	assert_eq!(core::CV_8UC1, reduced.typ());
+	assert_eq!(imgcodecs::imdecode(&Mat::from_slice(&blox)?, imgcodecs::IMREAD_REDUCED_GRAYSCALE_2)?.size()?, reduced.size()?);
+
+	// non-JPEG data goes through imdecode()
+	assert_eq!(Size::new(1, 1), imgcodecs::imdecode_jpeg(PIXEL, imgcodecs::IMREAD_COLOR)?.size()?);
+	assert!(imgcodecs::imdecode_jpeg(&[0xFF, 0xD8, 0xFF, 0], imgcodecs::IMREAD_COLOR).is_err());
+	Ok(())
+}
@@ -1,7 +1,7 @@
 #![cfg(ocvrs_has_module_imgproc)]
 
 use opencv::{
-	core::{Point, Point2f, Scalar, Size, Vec2f, Mat_AUTO_STEP},
+	core::{self, Point, Point2f, Scalar, Size, Vec2f, Vec3b, Mat_AUTO_STEP},
 	imgproc,
 	prelude::*,
 	Result,
@@ -63,3 +63,459 @@ fn line_iterator() -> Result<()> {
 	assert_eq!(9, unsafe { *line_iter.try_deref_mut()?.as_ref().unwrap() });
 	Ok(())
 }
+
+#[test]
+fn batch() -> Result<()> {
+	let src = Mat::new_rows_cols_with_default(40, 30, Vec3b::typ(), Scalar::new(10., 100., 200., 0.))?;
+	let mut batch = core::Batch::new();
+	batch.cvt_color(imgproc::COLOR_BGR2RGB, 0)
+		.resize(Size::new(15, 20), 0., 0., imgproc::INTER_LINEAR)
+		.convert_to(f32::typ(), 1. / 255., 0.);
+	assert_eq!(3, batch.len());
+	let mut dst = Mat::default();
+	batch.run(&src, &mut dst)?;
+	assert_eq!(Size::new(15, 20), dst.size()?);
+	assert_eq!(f32::typ(), dst.depth());
+	let px = dst.at_2d::<core::Vec3f>(5, 5)?;
+	assert!((px[0] - 200. / 255.).abs() < 1e-6);
+	assert!((px[2] - 10. / 255.).abs() < 1e-6);
+
+	batch.clear();
+	batch.run(&src, &mut dst)?;
+	assert_eq!(src.size()?, dst.size()?);
+	assert_eq!(Vec3b::typ(), dst.typ());
+	Ok(())
+}
+
+/// OpenCV errors name the failing call and describe the passed arrays
+#[test]
+fn error_context() -> Result<()> {
+	let src = Mat::new_rows_cols_with_default(4, 3, u8::typ(), Scalar::all(0.))?;
+	let mut dst = Mat::default();
+	let err = imgproc::cvt_color(&src, &mut dst, imgproc::COLOR_BGR2GRAY, 0).unwrap_err();
+	assert!(err.message.ends_with("\n  in call: cv::cvtColor(src: 4x3 CV_8UC1)"), "{}", err.message);
+	Ok(())
+}
+
+#[test]
+fn output_mat() -> Result<()> {
+	let src = Mat::new_rows_cols_with_default(40, 30, Vec3b::typ(), Scalar::new(10., 100., 200., 0.))?;
+	let mut buf = vec![Vec3b::default(); 20 * 15];
+	{
+		let mut dst = core::OutputMat::from_slice(20, 15, &mut buf)?;
+		imgproc::resize(&src, &mut dst, Size::new(15, 20), 0., 0., imgproc::INTER_LINEAR)?;
+		dst.check()?;
+		imgproc::cvt_color(&src, &mut dst, imgproc::COLOR_BGR2RGB, 0).unwrap_err();
+	}
+	assert!(buf.iter().all(|px| *px == Vec3b::from([10, 100, 200])));
+
+	let mut gray = Mat::new_rows_cols_with_default(20, 15, u8::typ(), Scalar::all(0.))?;
+	let data = gray.data();
+	let mut dst = core::OutputMat::new(&mut gray)?;
+	imgproc::resize(&src, &mut dst, Size::new(15, 20), 0., 0., imgproc::INTER_LINEAR).unwrap_err();
+	drop(dst);
+	assert_eq!(data, gray.data());
+	Ok(())
+}
+
+#[test]
+#[cfg(all(ocvrs_has_module_calib3d, ocvrs_has_module_features2d, ocvrs_has_module_video))]
+fn align_estimate_and_warp() -> Result<()> {
+	use imgproc::align::{self, AlignMethod};
+
+	let blox_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/blox.jpg");
+	let template = opencv::imgcodecs::imread(blox_path.to_str().unwrap(), opencv::imgcodecs::IMREAD_COLOR)?;
+	// shift by (6, 4) pixels
+	let shift = Mat::from_slice_2d(&[[1f64, 0., 6.], [0., 1., 4.], [0., 0., 1.]])?;
+	let mut src = Mat::default();
+	imgproc::warp_perspective(&template, &mut src, &shift, template.size()?, imgproc::INTER_LINEAR, core::BORDER_REPLICATE, Scalar::default())?;
+
+	for method in [AlignMethod::orb(), AlignMethod::ecc()] {
+		let aligned = align::estimate_and_warp(&src, &template, method)?;
+		assert_eq!(template.size()?, aligned.warped.size()?);
+		assert_eq!(template.typ(), aligned.warped.typ());
+		assert!(aligned.inliers > 10);
+		assert!((*aligned.homography.at_2d::<f64>(0, 2)? + 6.).abs() < 0.5);
+		assert!((*aligned.homography.at_2d::<f64>(1, 2)? + 4.).abs() < 0.5);
+	}
+	Ok(())
+}
+
+#[test]
+fn match_template_multi() -> Result<()> {
+	let mut templ = Mat::new_rows_cols_with_default(20, 20, u8::typ(), Scalar::all(0.))?;
+	imgproc::rectangle(&mut templ, core::Rect::new(2, 2, 10, 6), Scalar::all(255.), -1, imgproc::LINE_8, 0)?;
+	imgproc::circle(&mut templ, Point::new(13, 13), 4, Scalar::all(128.), -1, imgproc::LINE_8, 0)?;
+	let mut big_templ = Mat::default();
+	imgproc::resize(&templ, &mut big_templ, Size::new(30, 30), 0., 0., imgproc::INTER_LINEAR)?;
+
+	let image = Mat::new_rows_cols_with_default(200, 200, u8::typ(), Scalar::all(0.))?;
+	templ.copy_to(&mut Mat::roi(&image, core::Rect::new(30, 40, 20, 20))?)?;
+	big_templ.copy_to(&mut Mat::roi(&image, core::Rect::new(120, 100, 30, 30))?)?;
+
+	let search = imgproc::TemplateSearch::new()
+		.with_scale_range(1., 1.5, 0.5)
+		.with_threshold(0.95);
+	assert_eq!(vec![1., 1.5], search.scales);
+	let matches = imgproc::match_template_multi(&image, &templ, &search)?;
+	assert_eq!(2, matches.len());
+	let mut rects = matches.iter().map(|m| m.rect).collect::<Vec<_>>();
+	rects.sort_by_key(|r| r.x);
+	assert_eq!(vec![core::Rect::new(30, 40, 20, 20), core::Rect::new(120, 100, 30, 30)], rects);
+	assert!(matches.iter().all(|m| m.score >= 0.95));
+
+	let wrong_mask = Mat::new_rows_cols_with_default(5, 5, u8::typ(), Scalar::all(255.))?;
+	assert!(imgproc::match_template_multi(&image, &templ, &imgproc::TemplateSearch::new().with_mask(wrong_mask)).is_err());
+	Ok(())
+}
+
+#[test]
+fn detect_lines_and_circles() -> Result<()> {
+	use imgproc::detect::{self, HoughCirclesParams, HoughLinesParams};
+
+	let mut image = Mat::new_rows_cols_with_default(200, 200, u8::typ(), Scalar::all(0.))?;
+	imgproc::line(&mut image, Point::new(20, 30), Point::new(180, 30), Scalar::all(255.), 3, imgproc::LINE_8, 0)?;
+	imgproc::circle(&mut image, Point::new(100, 120), 40, Scalar::all(255.), 3, imgproc::LINE_8, 0)?;
+
+	let lines = detect::lines(&image, HoughLinesParams::default().with_min_line_length(100.))?;
+	assert!(!lines.is_empty());
+	for line in lines {
+		assert!(line.length() >= 100.);
+		assert!(line.angle().abs() < 1. || (line.angle().abs() - 180.).abs() < 1.);
+		assert!((line.start.y - 30.).abs() <= 2.);
+	}
+
+	let circles = detect::circles(&image, &HoughCirclesParams::default().with_min_dist(100.).with_radius_range(20, 60))?;
+	assert_eq!(1, circles.len());
+	assert!((circles[0].center.x - 100.).abs() <= 2.);
+	assert!((circles[0].center.y - 120.).abs() <= 2.);
+	assert!((circles[0].radius - 40.).abs() <= 3.);
+
+	let float_image = Mat::new_rows_cols_with_default(10, 10, f32::typ(), Scalar::all(0.))?;
+	assert!(detect::lines(&float_image, HoughLinesParams::default()).is_err());
+	Ok(())
+}
+
+#[test]
+fn rotated_rect_geometry() -> Result<()> {
+	use imgproc::geometry;
+
+	let a = core::RotatedRect::new(Point2f::new(1., 1.), core::Size2f::new(2., 2.), 0.)?;
+	let b = core::RotatedRect::new(Point2f::new(2., 1.), core::Size2f::new(2., 2.), 0.)?;
+	let far = core::RotatedRect::new(Point2f::new(20., 20.), core::Size2f::new(2., 2.), 45.)?;
+	assert_eq!(4., a.area());
+	assert!((geometry::rotated_rect_intersection_area(&a, &b)? - 2.).abs() < 1e-4);
+	assert!((geometry::rotated_rect_iou(&a, &b)? - 1. / 3.).abs() < 1e-4);
+	assert!((geometry::rotated_rect_iou(&a, &a)? - 1.).abs() < 1e-4);
+	assert!(geometry::rotated_rect_intersection(&a, &far)?.is_empty());
+	assert_eq!(0., geometry::rotated_rect_iou(&a, &far)?);
+
+	let corners = a.corners()?;
+	assert!((geometry::polygon_area(&corners) - 4.).abs() < 1e-4);
+	let square = [Point2f::new(0., 0.), Point2f::new(2., 0.), Point2f::new(2., 2.), Point2f::new(0., 2.)];
+	assert_eq!(4., geometry::polygon_signed_area(&square));
+	let mut reversed = square;
+	reversed.reverse();
+	assert_eq!(-4., geometry::polygon_signed_area(&reversed));
+	let shifted = [Point2f::new(1., 1.), Point2f::new(3., 1.), Point2f::new(3., 3.), Point2f::new(1., 3.)];
+	assert_eq!(1., geometry::polygon_area(&geometry::clip_polygon(&square, &shifted)));
+	assert_eq!(1., geometry::polygon_area(&geometry::clip_polygon(&reversed, &shifted)));
+	let far = [Point2f::new(6., 1.), Point2f::new(8., 1.), Point2f::new(8., 3.), Point2f::new(6., 3.)];
+	assert!(geometry::clip_polygon(&square, &far).is_empty());
+	Ok(())
+}
+
+#[test]
+fn morphology_pipeline() -> Result<()> {
+	use imgproc::{Kernel, Morphology};
+
+	let mut mask = Mat::new_rows_cols_with_default(50, 50, u8::typ(), Scalar::all(0.))?;
+	imgproc::rectangle(&mut mask, core::Rect::new(10, 10, 20, 20), Scalar::all(255.), -1, imgproc::LINE_8, 0)?;
+	// speck outside and a hole inside of the rectangle
+	*mask.at_2d_mut::<u8>(40, 40)? = 255;
+	*mask.at_2d_mut::<u8>(20, 20)? = 0;
+
+	let pipeline = Morphology::new()
+		.open(Kernel::rect(3))
+		.close(Kernel::ellipse(3));
+	assert_eq!(2, pipeline.len());
+	let cleaned = pipeline.apply(&mask)?;
+	assert_eq!(0, *cleaned.at_2d::<u8>(40, 40)?);
+	assert_eq!(255, *cleaned.at_2d::<u8>(20, 20)?);
+	assert_eq!(400, core::count_non_zero(&cleaned)?);
+
+	let mut expected = Mat::default();
+	imgproc::dilate(&mask, &mut expected, &imgproc::get_structuring_element(imgproc::MORPH_CROSS, Size::new(5, 5), Point::new(-1, -1))?, Point::new(-1, -1), 1, core::BORDER_CONSTANT, imgproc::morphology_default_border_value()?)?;
+	let mut dilated = Mat::default();
+	Morphology::new().dilate(Kernel::cross(5)).apply_to(&mask, &mut dilated)?;
+	assert_eq!(expected.data_bytes()?, dilated.data_bytes()?);
+
+	let copy = Morphology::new().apply(&mask)?;
+	assert_eq!(mask.data_bytes()?, copy.data_bytes()?);
+
+	// sizes and border values accept tuples
+	assert_eq!(Size::new(3, 5), Kernel::rect_size((3, 5)).to_mat()?.size()?);
+	let bordered = Morphology::new().dilate(Kernel::cross_size((5, 5))).with_border(core::BORDER_CONSTANT, (0., 0., 0., 0.)).apply(&mask)?;
+	assert_eq!(expected.data_bytes()?, bordered.data_bytes()?);
+	Ok(())
+}
+
+#[test]
+fn builders() -> Result<()> {
+	let mut img = Mat::new_rows_cols_with_default(20, 20, u8::typ(), Scalar::all(0.))?;
+	imgproc::RectangleBuilder::new(&mut img, core::Rect::new(5, 5, 4, 4), Scalar::all(100.), imgproc::LINE_8)
+		.with_thickness(-1)
+		.call()?;
+	assert_eq!(16, core::count_non_zero(&img)?);
+
+	let kernel = imgproc::get_structuring_element(imgproc::MORPH_RECT, Size::new(3, 3), Point::new(-1, -1))?;
+	let mut expected = Mat::default();
+	imgproc::dilate(&img, &mut expected, &kernel, Point::new(-1, -1), 1, core::BORDER_CONSTANT, imgproc::morphology_default_border_value()?)?;
+	let mut dilated = Mat::default();
+	imgproc::DilateBuilder::new(&img, &mut dilated, &kernel, core::BORDER_CONSTANT, imgproc::morphology_default_border_value()?).call()?;
+	assert_eq!(expected.data_bytes()?, dilated.data_bytes()?);
+	assert_eq!(36, core::count_non_zero(&dilated)?);
+
+	let mut normalized = Mat::default();
+	core::NormalizeBuilder::new(&img, &mut normalized, core::NORM_MINMAX)
+		.with_alpha(0.)
+		.with_beta(255.)
+		.call()?;
+	assert_eq!(255, *normalized.at_2d::<u8>(6, 6)?);
+	let mut mask = Mat::new_rows_cols_with_default(20, 20, u8::typ(), Scalar::all(0.))?;
+	*mask.at_2d_mut::<u8>(0, 0)? = 255;
+	core::NormalizeBuilder::new(&img, &mut normalized, core::NORM_MINMAX)
+		.with_alpha(0.)
+		.with_beta(255.)
+		.with_mask(&mask)
+		.call()?;
+	assert_eq!(0, *normalized.at_2d::<u8>(0, 0)?);
+	Ok(())
+}
+
+#[test]
+fn segment_watershed_markers() -> Result<()> {
+	let mut mask = Mat::new_rows_cols_with_default(100, 120, u8::typ(), Scalar::all(0.))?;
+	imgproc::circle(&mut mask, Point::new(40, 50), 20, Scalar::all(255.), -1, imgproc::LINE_8, 0)?;
+	imgproc::circle(&mut mask, Point::new(72, 50), 20, Scalar::all(255.), -1, imgproc::LINE_8, 0)?;
+	// single connected component before the segmentation
+	let mut labels = Mat::default();
+	assert_eq!(2, imgproc::connected_components(&mask, &mut labels, 8, core::CV_32S)?);
+
+	let segmentation = imgproc::segment::watershed_markers(&mask)?;
+	assert_eq!(core::CV_32S, segmentation.labels.typ());
+	assert_eq!(mask.size()?, segmentation.labels.size()?);
+	assert_eq!(2, segmentation.regions.len());
+	let (left, right) = (segmentation.regions[0], segmentation.regions[1]);
+	let (left, right) = if left.centroid.x < right.centroid.x { (left, right) } else { (right, left) };
+	assert!((left.centroid.x - 39.).abs() < 3. && (left.centroid.y - 50.).abs() < 1.);
+	assert!((right.centroid.x - 73.).abs() < 3. && (right.centroid.y - 50.).abs() < 1.);
+	assert!(left.area > 1000 && right.area > 1000);
+	assert_eq!(0, *segmentation.labels.at_2d::<i32>(5, 5)?);
+	assert!(segmentation.labels.at_row::<i32>(50)?[50..62].contains(&-1));
+
+	assert!(imgproc::segment::watershed_markers(&Mat::new_rows_cols_with_default(10, 10, f32::typ(), Scalar::all(0.))?).is_err());
+	Ok(())
+}
+
+#[test]
+fn mat_resized() -> Result<()> {
+	use imgproc::{Fit, Interp};
+
+	let src = Mat::new_rows_cols_with_default(100, 200, core::CV_8UC3, Scalar::all(255.))?;
+
+	let exact = src.resized(Size::new(50, 50), Interp::Area, Fit::Exact)?;
+	assert_eq!(Size::new(50, 50), exact.mat.size()?);
+	assert_eq!((0.25, 0.5), (exact.transform.scale_x, exact.transform.scale_y));
+
+	let preserved = src.resized(Size::new(100, 100), Interp::Linear, Fit::Preserve)?;
+	assert_eq!(Size::new(100, 50), preserved.mat.size()?);
+	assert_eq!(Point2f::new(50., 25.), preserved.transform.apply(Point2f::new(100., 50.)));
+
+	let scaled = src.resized(0.5, Interp::Nearest, Fit::Exact)?;
+	assert_eq!(Size::new(100, 50), scaled.mat.size()?);
+
+	let letterbox = src.resized(Size::new(100, 100), Interp::Linear, Fit::Letterbox(Scalar::all(114.)))?;
+	assert_eq!(Size::new(100, 100), letterbox.mat.size()?);
+	assert_eq!((0., 25.), (letterbox.transform.offset_x, letterbox.transform.offset_y));
+	assert_eq!(Vec3b::from([114, 114, 114]), *letterbox.mat.at_2d::<Vec3b>(10, 50)?);
+	assert_eq!(Vec3b::from([255, 255, 255]), *letterbox.mat.at_2d::<Vec3b>(50, 50)?);
+	assert_eq!(Point2f::new(100., 50.), letterbox.transform.invert(Point2f::new(50., 50.)));
+	let rect = letterbox.transform.invert_rect(core::Rect2f::new(0., 25., 100., 50.));
+	assert_eq!(core::Rect2f::new(0., 0., 200., 100.), rect);
+
+	let crop = src.cropped(core::Rect::new(50, 0, 100, 100))?;
+	assert_eq!(Size::new(100, 100), crop.mat.size()?);
+	let resized_crop = crop.mat.resized(0.5, Interp::Area, Fit::Exact)?;
+	let to_input = crop.transform.then(&resized_crop.transform);
+	assert_eq!(core::Rect::new(60, 20, 20, 40), to_input.invert_rect_i(core::Rect::new(5, 10, 10, 20)));
+
+	assert!(src.resized(Size::new(0, 10), Interp::Linear, Fit::Exact).is_err());
+	Ok(())
+}
+
+#[test]
+fn plot_render() -> Result<()> {
+	use imgproc::plot::{Plot, Series};
+
+	let loss = [1.0f32, 0.6, 0.4, 0.3, 0.25, 0.22];
+	let chart = Plot::new(Size::new(320, 240))
+		.with_title("training")
+		.with_labels("epoch", "loss")
+		.series(Series::line("train", loss.iter().copied()))
+		.series(Series::histogram("hist", vec![0.1, 0.2, 0.2, 0.9], 4));
+	let mat = chart.render()?;
+	assert_eq!(Size::new(320, 240), mat.size()?);
+	assert_eq!(core::CV_8UC3, mat.typ());
+	assert_eq!(Vec3b::from([255, 255, 255]), *mat.at_2d::<Vec3b>(0, 0)?);
+	let mut non_white = 0;
+	for row in 0..mat.rows() {
+		non_white += mat.at_row::<Vec3b>(row)?.iter().filter(|p| **p != Vec3b::from([255, 255, 255])).count();
+	}
+	assert!(non_white > 1000);
+
+	let svg = chart.to_svg()?;
+	assert!(svg.starts_with("<svg"));
+	assert!(svg.contains(">training</text>"));
+	assert!(svg.trim_end().ends_with("</svg>"));
+
+	let col = Mat::from_slice(&[0.5f64, 0.8, 0.9])?.reshape(1, 3)?;
+	let series = Series::from_mat("mat", &col)?;
+	assert_eq!(vec![(0., 0.5), (1., 0.8), (2., 0.9)], series.points);
+	assert!(Series::from_mat("bad", &Mat::new_rows_cols_with_default(2, 2, core::CV_8UC1, Scalar::all(0.))?).is_err());
+
+	let roc = Plot::roc(Size::new(200, 200), "model", vec![(0., 0.), (0.1, 0.7), (1., 1.)]);
+	assert_eq!(Size::new(200, 200), roc.render()?.size()?);
+	assert!(Plot::new(Size::new(50, 50)).render().is_err());
+	Ok(())
+}
+
+#[test]
+#[cfg(ocvrs_has_module_video)]
+fn rolling_shutter() -> Result<()> {
+	use imgproc::rolling_shutter::{self, Estimator};
+
+	let blox_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/blox.jpg");
+	let scene = opencv::imgcodecs::imread(blox_path.to_str().unwrap(), opencv::imgcodecs::IMREAD_GRAYSCALE)?;
+	let rows = scene.rows() as usize;
+	// the camera pans by 8 pixels per frame and the readout takes the whole frame period
+	let skew = (0..rows)
+		.map(|y| Point2f::new(8. * (y as f32 - (rows / 2) as f32) / rows as f32, 0.))
+		.collect::<Vec<_>>();
+	let distort = |shift: f32| -> Result<Mat> {
+		let motion = skew.iter().map(|&m| Point2f::new(-m.x - shift, 0.)).collect::<Vec<_>>();
+		rolling_shutter::correct(&scene, &motion)
+	};
+	let previous = distort(0.)?;
+	let frame = distort(8.)?;
+
+	let motion = Estimator::new(1.).with_bands(4).estimate(&previous, &frame)?;
+	assert_eq!(rows, motion.len());
+	assert_eq!(Point2f::default(), motion[rows / 2]);
+	assert!((motion[0].x - skew[0].x).abs() < 1., "{:?}", motion[0]);
+	assert!((motion[rows - 1].x - skew[rows - 1].x).abs() < 1., "{:?}", motion[rows - 1]);
+	assert!(motion.iter().all(|m| m.y.abs() < 0.5));
+
+	let corrected = rolling_shutter::correct(&frame, &motion)?;
+	assert_eq!(frame.size()?, corrected.size()?);
+	assert!(rolling_shutter::correct(&frame, &motion[1..]).is_err());
+	Ok(())
+}
+
+#[test]
+fn photometric_match_exposure() -> Result<()> {
+	use imgproc::photometric::{self, ExposureModel, ExposureOptions};
+
+	let mut frame = Mat::new_rows_cols_with_default(64, 64, core::CV_8UC3, Scalar::default())?;
+	core::randu(&mut frame, &Scalar::all(50.), &Scalar::all(200.))?;
+	let mut darker = Mat::default();
+	frame.convert_to(&mut darker, core::CV_8UC3, 0.5, 0.)?;
+	let mut dimmer = Mat::default();
+	frame.convert_to(&mut dimmer, core::CV_8UC3, 0.5, 20.)?;
+	let mean_abs_diff = |a: &Mat, b: &Mat| -> Result<f64> {
+		let mut diff = Mat::default();
+		core::absdiff(a, b, &mut diff)?;
+		Ok(core::mean(&diff, &core::no_array())?[0])
+	};
+
+	let options = ExposureOptions::new(ExposureModel::Gain).with_reference(Some(0));
+	let compensations = photometric::estimate_exposure(&[frame.try_clone()?, darker.try_clone()?], &options)?;
+	assert_eq!(photometric::Compensation::identity(3), compensations[0]);
+	assert!((compensations[1].gain[0] - 2.).abs() < 0.02);
+	let matched = photometric::match_exposure(&[frame.try_clone()?, darker], &options)?;
+	assert!(mean_abs_diff(&frame, &matched[1])? < 1.5);
+
+	let options = ExposureOptions::new(ExposureModel::GainOffset).with_reference(Some(0));
+	let matched = photometric::match_exposure(&[frame.try_clone()?, dimmer.try_clone()?], &options)?;
+	assert!(mean_abs_diff(&frame, &matched[1])? < 1.5);
+
+	// without the reference both frames meet in the middle
+	let matched = photometric::match_exposure(&[frame.try_clone()?, dimmer], &ExposureOptions::new(ExposureModel::ColorTransfer))?;
+	assert!(mean_abs_diff(&matched[0], &matched[1])? < 3.);
+
+	let gray = Mat::new_rows_cols_with_default(8, 8, core::CV_8UC1, Scalar::all(1.))?;
+	assert!(photometric::match_exposure(&[frame, gray], &ExposureOptions::default()).is_err());
+	Ok(())
+}
+
+#[test]
+fn document_scan() -> Result<()> {
+	use imgproc::document::{self, ScanParams};
+
+	// light page photographed at an angle on the dark desk
+	let mut photo = Mat::new_rows_cols_with_default(400, 400, core::CV_8UC3, Scalar::all(40.))?;
+	let page = VectorOfPoint::from_iter([Point::new(80, 60), Point::new(320, 90), Point::new(300, 360), Point::new(60, 330)]);
+	imgproc::fill_convex_poly(&mut photo, &page, Scalar::all(230.), imgproc::LINE_8, 0)?;
+
+	let scanned = document::scan(&photo)?;
+	let expected = [Point2f::new(80., 60.), Point2f::new(320., 90.), Point2f::new(300., 360.), Point2f::new(60., 330.)];
+	for (corner, expected) in scanned.corners.iter().zip(&expected) {
+		assert!((corner.x - expected.x).abs() < 4. && (corner.y - expected.y).abs() < 4., "{:?} != {:?}", corner, expected);
+	}
+	assert_eq!(core::CV_8UC1, scanned.image.typ());
+	assert!((scanned.image.cols() - 242).abs() < 6 && (scanned.image.rows() - 272).abs() < 6, "{:?}", scanned.image.size()?);
+
+	let params = ScanParams { dpi: Some(100.), binarization: None, ..ScanParams::default() };
+	let scanned = document::scan_with_params(&photo, &params)?;
+	assert_eq!(Size::new(827, 1169), scanned.image.size()?);
+	assert_eq!(photo.typ(), scanned.image.typ());
+	assert!(core::mean(&scanned.image, &core::no_array())?[0] > 200.);
+
+	let empty = Mat::new_rows_cols_with_default(100, 100, core::CV_8UC1, Scalar::all(40.))?;
+	assert!(document::scan(&empty).is_err());
+	Ok(())
+}
+
+#[test]
+fn grab_cut_strokes() -> Result<()> {
+	use imgproc::grab_cut::Segmentation;
+
+	// red disc on the textured green background
+	let mut image = Mat::new_rows_cols_with_default(120, 160, core::CV_8UC3, Scalar::new(30., 160., 40., 0.))?;
+	for x in (0..160).step_by(8) {
+		imgproc::line(&mut image, Point::new(x, 0), Point::new(x, 119), Scalar::new(40., 120., 50., 0.), 1, imgproc::LINE_8, 0)?;
+	}
+	imgproc::circle(&mut image, Point::new(80, 60), 30, Scalar::new(30., 40., 200., 0.), imgproc::FILLED, imgproc::LINE_8, 0)?;
+
+	let mut segmentation = Segmentation::new(&image)?.with_brush_radius(2);
+	assert!(!segmentation.is_initialized());
+	segmentation.add_foreground_stroke(&[Point::new(70, 60), Point::new(90, 60)])?;
+	segmentation.add_background_stroke(&[Point::new(5, 5), Point::new(155, 5), Point::new(155, 115)])?;
+	segmentation.add_background_stroke(&[Point::new(5, 115)])?;
+	assert_eq!(imgproc::GC_FGD, i32::from(*segmentation.mask().at_2d::<u8>(60, 80)?));
+	segmentation.refine()?;
+	assert!(segmentation.is_initialized());
+	segmentation.refine()?;
+
+	let foreground = segmentation.foreground_mask()?;
+	assert_eq!(255, *foreground.at_2d::<u8>(60, 80)?);
+	assert_eq!(255, *foreground.at_2d::<u8>(60, 105)?);
+	assert_eq!(0, *foreground.at_2d::<u8>(10, 10)?);
+	assert_eq!(0, *foreground.at_2d::<u8>(60, 140)?);
+	let area = f64::from(core::count_non_zero(&foreground)?);
+	assert!((area - std::f64::consts::PI * 30. * 30.).abs() < 400., "{}", area);
+
+	let gray = Mat::new_rows_cols_with_default(10, 10, core::CV_8UC1, Scalar::all(0.))?;
+	assert!(Segmentation::new(&gray).is_err());
+	Ok(())
+}
@@ -0,0 +1,142 @@
+use opencv::{
+	core::{self, KeyPoint, Mat, Point2f, Point3d, Rect2f, Size},
+	interop::{messages::{self, Detection, Detections, KeyPoints, Message, PointCloud, Pose}, ros},
+	prelude::*,
+	Result,
+};
+
+#[test]
+fn messages_encode() -> Result<()> {
+	let detections = Detections {
+		detections: vec![Detection { rect: Rect2f::new(1., 0., 3., 4.), score: 0.5, class_id: -1, label: "cat".to_string() }],
+		image_size: Size::new(640, 480),
+		timestamp_us: 0,
+	};
+	assert_eq!(
+		vec![
+			10, 38, 10, 15, 13, 0, 0, 128, 63, 29, 0, 0, 64, 64, 37, 0, 0, 128, 64, 21, 0, 0, 0, 63,
+			24, 255, 255, 255, 255, 255, 255, 255, 255, 255, 1, 34, 3, 99, 97, 116, 16, 128, 5, 24, 224, 3,
+		],
+		detections.encode_to_vec(),
+	);
+	assert!(Detections::default().encode_to_vec().is_empty());
+
+	let pose = Pose::from_mats(&Mat::from_slice_2d(&[[1f32, 0., 0.], [0., 1., 0.], [0., 0., 1.]])?, &Mat::from_slice(&[1f64, 2., 3.])?)?;
+	assert_eq!([1., 2., 3.], pose.translation);
+	let encoded = pose.encode_to_vec();
+	assert_eq!(100, encoded.len());
+	assert_eq!(&[10, 72, 0], &encoded[..3]);
+	assert_eq!(&[18, 24, 0], &encoded[74..77]);
+	assert!(Pose::from_mats(&Mat::from_slice(&[1f64, 2.])?, &Mat::from_slice(&[1f64, 2., 3.])?).is_err());
+
+	let cloud = PointCloud::from(vec![Point3d::new(1., 2., 3.)]).with_colors(vec![0xff0000])?;
+	assert_eq!(
+		vec![10, 24, 0, 0, 0, 0, 0, 0, 240, 63, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 8, 64, 18, 4, 128, 128, 252, 7],
+		cloud.encode_to_vec(),
+	);
+	assert!(PointCloud::from(vec![Point3d::new(1., 2., 3.)]).with_colors(vec![]).is_err());
+
+	let keypoints = KeyPoints { keypoints: vec![KeyPoint::new_point(Point2f::new(2., 0.), 1., -1., 0., 0, -1)?] };
+	let encoded = keypoints.encode_to_vec();
+	assert_eq!(&[10], &encoded[..1]);
+	assert_eq!(encoded.len() - 2, usize::from(encoded[1]));
+	assert!(messages::PROTO_SCHEMA.contains("message PointCloud"));
+	Ok(())
+}
+
+mod msg {
+	#[derive(Default)]
+	pub struct Image {
+		pub height: u32,
+		pub width: u32,
+		pub encoding: String,
+		pub is_bigendian: u8,
+		pub step: u32,
+		pub data: Vec<u8>,
+	}
+
+	#[derive(Default)]
+	pub struct CameraInfo {
+		pub height: u32,
+		pub width: u32,
+		pub distortion_model: String,
+		pub d: Vec<f64>,
+		pub k: [f64; 9],
+		pub r: [f64; 9],
+		pub p: Vec<f64>,
+		pub binning_x: u32,
+		pub binning_y: u32,
+	}
+}
+
+opencv::ros_conversions! {
+	mod ros_cv {
+		image: super::msg::Image,
+		camera_info: super::msg::CameraInfo,
+	}
+}
+
+#[test]
+fn ros_image() -> Result<()> {
+	let mat = Mat::from_slice_2d(&[[1u16, 2, 3], [4, 5, 6]])?;
+	let image = ros::RosImage::from_mat(&mat)?;
+	assert_eq!("mono16", image.encoding);
+	assert_eq!((2, 3, 6), (image.height, image.width, image.step));
+	assert_eq!(mat.data_typed::<u16>()?, image.to_mat()?.data_typed::<u16>()?);
+
+	// big endian with padded rows
+	let padded = ros::image_to_mat(2, 2, "16UC1", true, 6, &[0, 1, 0, 2, 9, 9, 1, 0, 2, 0])?;
+	assert_eq!(&[1, 2, 256, 512], padded.data_typed::<u16>()?);
+	assert!(ros::image_to_mat(2, 2, "16UC1", true, 6, &[0, 1, 0, 2, 9, 9, 1, 0]).is_err());
+
+	assert_eq!(core::CV_8UC3, ros::encoding_to_mat_type("rgb8")?);
+	assert_eq!(core::CV_32FC2, ros::encoding_to_mat_type("32FC2")?);
+	assert_eq!(core::CV_8UC1, ros::encoding_to_mat_type("bayer_rggb8")?);
+	assert!(ros::encoding_to_mat_type("32FC5").is_err());
+	assert!(ros::encoding_to_mat_type("jpeg").is_err());
+	assert_eq!("64FC3", ros::mat_type_to_encoding(core::CV_64FC3)?);
+	assert!(ros::RosImage::from_mat_with_encoding(&mat, "mono8").is_err());
+
+	let color = Mat::new_rows_cols_with_default(2, 2, core::CV_8UC3, core::Scalar::new(1., 2., 3., 0.))?;
+	let msg = ros_cv::mat_to_image_with_encoding(&color, "rgb8")?;
+	assert_eq!(("rgb8", 6, 12), (msg.encoding.as_str(), msg.step, msg.data.len()));
+	assert_eq!(color.data_bytes()?, ros_cv::image_to_mat(&msg)?.data_bytes()?);
+	Ok(())
+}
+
+#[test]
+fn ros_camera_info() -> Result<()> {
+	let camera_matrix = Mat::from_slice_2d(&[[500f64, 0., 320.], [0., 510., 240.], [0., 0., 1.]])?;
+	let dist_coeffs = Mat::from_slice(&[0.1f64, -0.2, 0.001, 0.002, 0.05])?;
+	let info = ros::RosCameraInfo::from_calibration(Size::new(640, 480), &camera_matrix, &dist_coeffs)?;
+	assert_eq!("plumb_bob", info.distortion_model);
+	assert_eq!([500., 0., 320., 0., 0., 510., 240., 0., 0., 0., 1., 0.], info.p);
+	assert_eq!(camera_matrix.data_typed::<f64>()?, info.camera_matrix()?.data_typed::<f64>()?);
+	assert_eq!(Size::new(640, 480), info.image_size());
+
+	let msg = ros_cv::camera_info_to_msg(&info);
+	assert_eq!(12, msg.p.len());
+	assert_eq!(info, ros_cv::camera_info_from_msg(&msg)?);
+	assert!(ros_cv::camera_info_from_msg(&msg::CameraInfo::default()).is_err());
+	assert!(ros::RosCameraInfo::from_calibration(Size::new(1, 1), &camera_matrix, &Mat::from_slice(&[0f64; 6])?).is_err());
+	Ok(())
+}
+
+#[test]
+#[cfg(feature = "wgpu-interop")]
+fn texture_formats() -> Result<()> {
+	use opencv::interop::texture;
+
+	assert_eq!(wgpu::TextureFormat::Bgra8Unorm, texture::texture_format(core::CV_8UC4)?);
+	assert_eq!(wgpu::TextureFormat::Rg32Float, texture::texture_format(core::CV_32FC2)?);
+	assert!(texture::texture_format(core::CV_8UC3).is_err());
+	assert!(texture::texture_format(core::CV_64FC1).is_err());
+	for &typ in &[core::CV_8UC1, core::CV_8SC4, core::CV_16UC2, core::CV_32SC4, core::CV_32FC1] {
+		assert_eq!(typ, texture::mat_type(texture::texture_format(typ)?)?);
+	}
+	#[cfg(not(ocvrs_opencv_branch_32))]
+	assert_eq!(core::CV_16FC1, texture::mat_type(texture::texture_format(core::CV_16FC1)?)?);
+	assert_eq!(core::CV_8UC4, texture::mat_type(wgpu::TextureFormat::Rgba8UnormSrgb)?);
+	assert!(texture::mat_type(wgpu::TextureFormat::Depth32Float).is_err());
+	Ok(())
+}
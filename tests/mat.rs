@@ -643,3 +643,99 @@ fn mat_rgb() -> Result<()> {
 	assert_matches!(m.at_2d::<rgb::RGB8>(1, 1), Err(Error { code: core::StsUnmatchedFormats, .. }));
 	Ok(())
 }
+
+#[test]
+#[cfg(feature = "mmap")]
+fn mat_from_memmap() -> Result<()> {
+	let path = std::env::temp_dir().join(format!("opencv-rust-mmap-{}.bin", std::process::id()));
+	let header = [0u8; 8];
+	let data = (0..12).flat_map(|x| (x as f32).to_ne_bytes().to_vec()).collect::<Vec<_>>();
+	std::fs::write(&path, [&header[..], &data].concat()).unwrap();
+
+	let mat = Mat::from_memmap(&path, 3, 4, f32::typ())?;
+	assert_eq!(Size::new(4, 3), mat.size()?);
+	assert_eq!(0., *mat.at_2d::<f32>(0, 0)?);
+	let mat = core::MmapMat::open(&path, 8, 3, 4, f32::typ())?;
+	assert_eq!(6., *mat.at_2d::<f32>(1, 2)?);
+	assert_eq!(11., *mat.at_2d::<f32>(2, 3)?);
+	assert!(Mat::from_memmap(&path, 4, 4, f32::typ()).is_err());
+	assert!(core::MmapMat::open(&path, 2, 3, 4, f32::typ()).is_err());
+	drop(mat);
+	std::fs::remove_file(&path).unwrap();
+	Ok(())
+}
+
+#[test]
+fn mat_npy() -> Result<()> {
+	use opencv::core::npy;
+
+	let mat = Mat::from_slice_2d(&[[1f32, 2., 3.], [4., 5., 6.]])?;
+	let data = mat.to_npy()?;
+	assert!(data.starts_with(b"\x93NUMPY\x01\x00"));
+	assert_eq!(0, (data.len() - 6 * 4) % 64);
+	let loaded = Mat::from_npy(&data)?;
+	assert_eq!(mat.typ(), loaded.typ());
+	assert_eq!(mat.data_typed::<f32>()?, loaded.data_typed::<f32>()?);
+
+	let mat = Mat::new_rows_cols_with_default(2, 3, core::Vec3b::typ(), Scalar::new(1., 2., 3., 0.))?;
+	let loaded = Mat::from_npy(&mat.col(1)?.to_npy()?)?;
+	assert_eq!(Size::new(1, 2), loaded.size()?);
+	assert_eq!(core::Vec3b::from([1, 2, 3]), *loaded.at_2d::<core::Vec3b>(1, 0)?);
+
+	// hand-made npy: int64 1D array in fortran order and big-endian int16 matrix in fortran order
+	let npy_data = |header: &str, body: &[u8]| {
+		let mut out = b"\x93NUMPY\x01\x00".to_vec();
+		out.extend_from_slice(&(header.len() as u16).to_le_bytes());
+		out.extend_from_slice(header.as_bytes());
+		out.extend_from_slice(body);
+		out
+	};
+	let body = [5i64, -3, 7].iter().flat_map(|v| v.to_le_bytes().to_vec()).collect::<Vec<_>>();
+	let labels = Mat::from_npy(&npy_data("{'descr': '<i8', 'fortran_order': True, 'shape': (3,), }\n", &body))?;
+	assert_eq!(core::CV_32S, labels.typ());
+	assert_eq!(Size::new(1, 3), labels.size()?);
+	assert_eq!(&[5, -3, 7], labels.data_typed::<i32>()?);
+	let body = [1i16, 2, 3, 4, 5, 6].iter().flat_map(|v| v.to_be_bytes().to_vec()).collect::<Vec<_>>();
+	let matrix = Mat::from_npy(&npy_data("{'descr': '>i2', 'fortran_order': True, 'shape': (2, 3), }\n", &body))?;
+	assert_eq!(Size::new(3, 2), matrix.size()?);
+	assert_eq!(&[1, 3, 5, 2, 4, 6], matrix.data_typed::<i16>()?);
+	let body = (i64::from(i32::MAX) + 1).to_le_bytes();
+	assert!(Mat::from_npy(&npy_data("{'descr': '<i8', 'fortran_order': False, 'shape': (1,), }\n", &body)).is_err());
+	assert!(Mat::from_npy(&npy_data("{'descr': '<c8', 'fortran_order': False, 'shape': (1,), }\n", &body)).is_err());
+	assert!(Mat::from_npy(&data[..data.len() - 1]).is_err());
+	assert!(Mat::from_npy(&npy_data("{'descr': '<\u{e9}4', 'fortran_order': False, 'shape': (1,), }\n", &body)).is_err());
+	let huge = Mat::from_npy(&npy_data("{'descr': '<f4', 'fortran_order': False, 'shape': (4294967296, 4294967296, 4), }\n", &body));
+	assert_eq!(core::StsOutOfRange, huge.unwrap_err().code);
+
+	// zip64 end record claiming u64::MAX entries, followed by the zip64 locator and the end record
+	let mut zip64 = vec![];
+	zip64.extend_from_slice(&0x0606_4b50u32.to_le_bytes());
+	zip64.extend_from_slice(&[0; 28]);
+	zip64.extend_from_slice(&u64::MAX.to_le_bytes());
+	zip64.extend_from_slice(&[0; 16]);
+	zip64.extend_from_slice(&0x0706_4b50u32.to_le_bytes());
+	zip64.extend_from_slice(&[0; 4]);
+	zip64.extend_from_slice(&0u64.to_le_bytes());
+	zip64.extend_from_slice(&[0; 4]);
+	zip64.extend_from_slice(&0x0605_4b50u32.to_le_bytes());
+	zip64.extend_from_slice(&[0; 6]);
+	zip64.extend_from_slice(&0xffffu16.to_le_bytes());
+	zip64.extend_from_slice(&[0; 4]);
+	zip64.extend_from_slice(&u32::MAX.to_le_bytes());
+	zip64.extend_from_slice(&[0; 2]);
+	assert!(npy::read_npz(&zip64).is_err());
+
+	// archive written by zipfile with zip64 local headers like numpy.savez()
+	let archive = npy::read_npz(include_bytes!("dataset.npz"))?;
+	assert_eq!(vec!["x", "y"], archive.iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>());
+	assert_eq!(Size::new(2, 3), archive[0].1.size()?);
+	assert_eq!(0.5, *archive[0].1.at_2d::<f32>(0, 0)?);
+	assert_eq!(&[0, 1, 2], archive[1].1.data_typed::<i32>()?);
+
+	let written = npy::write_npz(&[("samples", &archive[0].1), ("labels", &archive[1].1)])?;
+	let archive = npy::read_npz(&written)?;
+	assert_eq!("labels", archive[1].0);
+	assert_eq!(&[0, 1, 2], archive[1].1.data_typed::<i32>()?);
+	assert!(npy::read_npz(&data).is_err());
+	Ok(())
+}
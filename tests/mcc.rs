@@ -0,0 +1,37 @@
+#![cfg(ocvrs_has_module_mcc)]
+
+use opencv::{
+	core::{self, Mat, Scalar, Vec3d},
+	mcc::{self, ColorCorrectionModel, COLOR_SPACE},
+	prelude::*,
+	Result,
+};
+
+#[test]
+fn apply_ccm() -> Result<()> {
+	let rgb = Mat::from_slice_2d(&[[Vec3d::from([0.2, 0.4, 0.6]), Vec3d::from([1., 0., 0.5])]])?;
+	// swaps red and blue and adds 0.1 to green
+	let ccm = Mat::from_slice_2d(&[
+		[0., 0., 1.],
+		[0., 1., 0.],
+		[1., 0., 0.],
+		[0., 0.1, 0.],
+	])?;
+	let out = mcc::apply_ccm(&rgb, &ccm)?;
+	let pixel = *out.at_2d::<Vec3d>(0, 0)?;
+	assert!((pixel[0] - 0.6).abs() < 1e-9 && (pixel[1] - 0.5).abs() < 1e-9 && (pixel[2] - 0.2).abs() < 1e-9, "{:?}", pixel);
+	assert!(mcc::apply_ccm(&rgb, &Mat::from_slice_2d(&[[1., 0.], [0., 1.]])?).is_err());
+
+	// the model fitted on the chart already having the reference colors doesn't change the image much
+	let mut colors = Mat::new_rows_cols_with_default(24, 1, core::CV_64FC3, Scalar::default())?;
+	core::randu(&mut colors, &Scalar::all(0.1), &Scalar::all(0.9))?;
+	let mut model = ColorCorrectionModel::new_1(&colors, colors.try_clone()?, COLOR_SPACE::COLOR_SPACE_sRGB)?;
+	model.run()?;
+	let mut image = Mat::new_rows_cols_with_default(16, 16, core::CV_8UC3, Scalar::default())?;
+	core::randu(&mut image, &Scalar::all(40.), &Scalar::all(220.))?;
+	let calibrated = model.apply_ccm(&image)?;
+	assert_eq!(image.typ(), calibrated.typ());
+	assert!(core::norm2(&image, &calibrated, core::NORM_L1, &core::no_array())? / (16. * 16. * 3.) < 8.);
+	assert!(model.apply_ccm(&Mat::new_rows_cols_with_default(4, 4, core::CV_8UC1, Scalar::default())?).is_err());
+	Ok(())
+}
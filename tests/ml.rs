@@ -1,13 +1,19 @@
 #![cfg(ocvrs_has_module_ml)]
 
+use std::{
+	collections::HashMap,
+	time::Duration,
+};
+
 use opencv::{
-	core::{Scalar, Size},
-	ml,
+	core::{self, no_array, Scalar, Size, UMat},
+	ml::{self, SampleTypes},
 	prelude::*,
 	Result,
 };
 
 #[test]
+#[allow(deprecated)]
 fn knn() -> Result<()> {
 	let mut knn = <dyn KNearest>::create()?;
 	assert!(StatModelConst::empty(&knn)?);
@@ -27,3 +33,488 @@ fn knn() -> Result<()> {
 	assert_eq!(Size::new(width, 1), dist.size()?);
 	Ok(())
 }
+
+#[test]
+fn knn_train_with_layout() -> Result<()> {
+	let mut knn = <dyn KNearest>::create()?;
+	let samp = Mat::from_slice_2d(&[[1f32, 1.], [9., 9.]])?;
+	let resp = Mat::from_slice(&[1f32, 2.])?;
+	assert!(knn.train_with_layout(&samp, SampleTypes::ROW_SAMPLE, &resp)?);
+	let mut res = Mat::default();
+	knn.find_nearest(&Mat::from_slice_2d(&[[8f32, 8.]])?, 1, &mut res, &mut no_array(), &mut no_array())?;
+	assert_eq!(2., *res.at_2d::<f32>(0, 0)?);
+
+	let cols = Mat::from_slice_2d(&[[1f32, 9.], [1., 9.]])?;
+	assert!(knn.train_with_layout(&cols, SampleTypes::COL_SAMPLE, &resp)?);
+	assert_eq!(2, knn.get_var_count()?);
+	Ok(())
+}
+
+#[test]
+fn samples_layout() -> Result<()> {
+	use ml::{Samples, SamplesLayout};
+
+	let rows = Mat::from_slice_2d(&[[1f32, 1.], [2., 2.], [8., 8.], [9., 9.], [1., 2.]])?;
+	let mut cols = Mat::default();
+	core::transpose(&rows, &mut cols)?;
+	let resp = Mat::from_slice(&[0i32, 0, 1, 1, 0])?;
+	let samples = Samples::col_major(&cols);
+	assert_eq!((5, 2), (samples.sample_count(), samples.feature_count()));
+	assert_eq!(SampleTypes::COL_SAMPLE, SampleTypes::from(samples.layout()));
+	assert_eq!(SamplesLayout::RowMajor, SamplesLayout::from(SampleTypes::ROW_SAMPLE));
+
+	let mut knn = <dyn ml::KNearest>::create()?;
+	knn.set_default_k(1)?;
+	assert!(knn.train_samples(&Samples::row_major(&cols), &resp).is_err());
+	knn.train_samples(&samples, &resp)?;
+	let query = Mat::from_slice_2d(&[[8.5f32], [8.5]])?;
+	let mut results = Mat::default();
+	knn.predict_samples(&Samples::col_major(&query), &mut results, 0)?;
+	assert_eq!(1., *results.at_2d::<f32>(0, 0)?);
+
+	let data = <dyn ml::TrainData>::create_from_samples(&samples, &resp)?;
+	assert_eq!(5, data.get_n_samples()?);
+	assert_eq!(2, data.get_n_vars()?);
+	Ok(())
+}
+
+#[test]
+fn model_bundle() -> Result<()> {
+	let samp = Mat::from_slice_2d(&[[1f32, 1.], [2., 2.], [8., 8.], [9., 9.]])?;
+	let resp = Mat::from_slice(&[0i32, 0, 1, 1])?;
+	let data = <dyn ml::TrainData>::create_with_layout(&samp, SampleTypes::ROW_SAMPLE, &resp)?;
+	let mut svm = <dyn ml::SVM>::create()?;
+	svm.train_with_data(&data, 0)?;
+	let metadata = ml::ModelMetadata::new()?
+		.with_train_data(&data)?
+		.with_feature_names(vec!["x", "y"])
+		.with_metric("train_error", 0.);
+	let bundle = ml::ModelBundle::new(svm, metadata.clone());
+	let serialized = bundle.save_to_string()?;
+
+	let loaded = ml::ModelBundle::load_from_str(&serialized, <dyn ml::SVM>::create()?)?;
+	assert_eq!(metadata, loaded.metadata);
+	assert_eq!(Some(0.), loaded.metadata.metric("train_error"));
+	assert_eq!(16, loaded.metadata.train_data_hash.as_ref().unwrap().len());
+	assert_eq!(1., loaded.model.predict(&Mat::from_slice_2d(&[[8.5f32, 8.5]])?, &mut no_array(), 0)?);
+
+	let mismatched = ml::ModelBundle::new(loaded.model, metadata.with_feature_names(vec!["x"]));
+	assert!(ml::ModelBundle::load_from_str(&mismatched.save_to_string()?, <dyn ml::SVM>::create()?).is_err());
+
+	// unsupervised data has no responses
+	let unlabeled = <dyn ml::TrainData>::create_with_layout(&samp, SampleTypes::ROW_SAMPLE, &Mat::default())?;
+	let hash = ml::ModelMetadata::hash_train_data(&unlabeled)?;
+	assert_eq!(hash, ml::ModelMetadata::hash_train_data(&unlabeled)?);
+	assert_ne!(hash, ml::ModelMetadata::hash_train_data(&data)?);
+	Ok(())
+}
+
+#[test]
+fn labels() -> Result<()> {
+	let samp = Mat::from_slice_2d(&[[1f32, 1.], [2., 2.], [8., 8.], [9., 9.]])?;
+	let mut labels = ml::Labels::new();
+	let resp = labels.encode_all(vec!["small", "small", "big", "big"])?;
+	assert_eq!((4, 1, core::CV_32SC1), (resp.rows(), resp.cols(), resp.typ()));
+	assert_eq!(Some(1), labels.code("big"));
+	assert_eq!(Some("small"), labels.name(0));
+	assert_eq!(None, labels.name(2));
+	assert!(ml::Labels::from_names(vec!["a", "a"]).is_err());
+
+	let data = <dyn ml::TrainData>::create_with_layout(&samp, SampleTypes::ROW_SAMPLE, &resp)?;
+	labels.check_train_data(&data)?;
+	assert!(ml::Labels::from_names(vec!["small"])?.check_train_data(&data).is_err());
+	let mut svm = <dyn ml::SVM>::create()?;
+	svm.train_with_data(&data, 0)?;
+	let metadata = ml::ModelMetadata::new()?.with_labels(&labels);
+	let loaded = ml::ModelBundle::load_from_str(&ml::ModelBundle::new(svm, metadata).save_to_string()?, <dyn ml::SVM>::create()?)?;
+	let labels = loaded.metadata.labels()?;
+	let mut results = Mat::default();
+	loaded.model.predict(&Mat::from_slice_2d(&[[8.5f32, 8.5], [1.5, 1.5]])?, &mut results, 0)?;
+	assert_eq!(vec!["big", "small"], labels.decode_all(&results)?);
+	assert!(labels.decode(5.).is_err());
+	Ok(())
+}
+
+#[test]
+fn named_model() -> Result<()> {
+	let samp = Mat::from_slice_2d(&[[1f32, 10.], [2., 20.], [8., 1.], [9., 2.]])?;
+	let resp = Mat::from_slice(&[0i32, 0, 1, 1])?;
+	let mut knn = <dyn ml::KNearest>::create()?;
+	knn.train_with_layout(&samp, SampleTypes::ROW_SAMPLE, &resp)?;
+	let model = ml::NamedModel::new(knn, vec!["a", "b"])?;
+
+	let mut input = HashMap::new();
+	input.insert("b", 1.5);
+	input.insert("a", 8.5);
+	assert_eq!(1., model.predict(&input)?);
+	assert_eq!(0., model.predict(&[("a", 1.5), ("b", 15.)][..])?);
+
+	input.insert("c", 0.);
+	assert!(model.predict(&input).is_err());
+	input.remove("c");
+	input.remove("a");
+	assert!(model.predict(&input).is_err());
+
+	assert!(ml::NamedModel::new(model.into_inner(), vec!["a"]).is_err());
+	Ok(())
+}
+
+#[test]
+fn named_model_csv_header() -> Result<()> {
+	let path = std::env::temp_dir().join(format!("opencv-rust-named-{}.csv", std::process::id()));
+	std::fs::write(&path, "color,width,label\nred,1,0\nblue,2,0\nred,8,1\ngreen,9,1\n").unwrap();
+	let filename = path.to_str().unwrap();
+	let data = <dyn ml::TrainData>::load_from_csv(filename, 1, -1, -1, "", b',' as i8, b'?' as i8)?;
+	// the categorical column values are reported as the names by OpenCV
+	let mut values = core::Vector::<String>::new();
+	data.get_names(&mut values)?;
+	assert!(values.iter().any(|v| v == "red"));
+
+	let model = ml::NamedModel::from_csv_header(<dyn ml::KNearest>::create()?, filename, -1, -1, ',')?;
+	assert_eq!(["color", "width"], model.feature_names());
+	assert_eq!(data.get_n_vars()? as usize, model.feature_names().len());
+	assert_eq!(vec!["width", "label"], ml::csv_feature_names(filename, 0, 1, ',')?);
+	assert_eq!(core::StsOutOfRange, ml::csv_feature_names(filename, 2, 4, ',').unwrap_err().code);
+	std::fs::remove_file(&path).unwrap();
+	Ok(())
+}
+
+#[test]
+fn drift_monitor() -> Result<()> {
+	let reference = Mat::from_exact_iter((0..1000).map(|i| i as f32 / 1000.))?;
+	let monitor = ml::monitor::DriftMonitor::from_samples(&reference)?;
+	assert_eq!(1, monitor.feature_count());
+
+	let same = Mat::from_exact_iter((0..500).map(|i| i as f32 / 500.))?;
+	let drift = monitor.drift(&same)?;
+	assert!(drift[0].psi < 0.01);
+	assert!(drift[0].ks_p_value > 0.5);
+
+	let shifted = Mat::from_exact_iter((0..500).map(|i| 0.5 + i as f32 / 500.))?;
+	let drift = monitor.drift(&shifted)?;
+	assert!(drift[0].psi > 0.25);
+	assert!(drift[0].ks_statistic > 0.4);
+	assert!(drift[0].ks_p_value < 0.01);
+
+	assert!(monitor.drift(&Mat::from_slice_2d(&[[1f32, 2.]])?).is_err());
+	Ok(())
+}
+
+#[test]
+fn svm_gpu_predictor() -> Result<()> {
+	let samp = Mat::from_slice_2d(&[[1f32, 1.], [2., 1.], [1., 2.], [8., 8.], [9., 8.], [8., 9.], [1., 8.], [2., 9.], [1., 9.]])?;
+	let resp = Mat::from_slice(&[3i32, 3, 3, 5, 5, 5, 7, 7, 7])?;
+	let data = <dyn ml::TrainData>::create_with_layout(&samp, SampleTypes::ROW_SAMPLE, &resp)?;
+	let test = Mat::from_slice_2d(&[[1.5f32, 1.5], [8.5, 8.5], [1.5, 8.5], [5., 5.]])?;
+	for kernel in [ml::SVM_KernelTypes::LINEAR, ml::SVM_KernelTypes::RBF] {
+		let mut svm = <dyn ml::SVM>::create()?;
+		svm.set_kernel(kernel as i32)?;
+		svm.set_gamma(0.1)?;
+		svm.train_with_data(&data, 0)?;
+		let mut expected = Mat::default();
+		svm.predict(&test, &mut expected, 0)?;
+
+		let predictor = ml::SvmGpuPredictor::<UMat>::new(&svm)?
+			.with_class_labels(&data.get_class_labels()?)?;
+		let actual = predictor.predict(&ml::SvmDeviceMat::upload(&test)?)?;
+		assert_eq!(expected.data_typed::<f32>()?, actual.data_typed::<f32>()?);
+	}
+	Ok(())
+}
+
+#[test]
+fn train_control() -> Result<()> {
+	assert!(ml::TrainControl::new(core::TermCriteria::max_iter(0)).is_err());
+	assert!(ml::TrainControl::new(core::TermCriteria::eps(0.)).is_err());
+
+	let samp = Mat::from_slice_2d(&[[0f32, 0.], [0., 1.], [1., 0.], [1., 1.]])?;
+	let resp = Mat::from_slice_2d(&[[0f32], [1.], [1.], [0.]])?;
+	let data = <dyn ml::TrainData>::create_with_layout(&samp, SampleTypes::ROW_SAMPLE, &resp)?;
+	let mut ann = <dyn ml::ANN_MLP>::create()?;
+	ann.set_layer_sizes(&Mat::from_slice(&[2i32, 4, 1])?)?;
+	ann.set_activation_function(ml::ANN_MLP_ActivationFunctions::SIGMOID_SYM as i32, 0., 0.)?;
+
+	let summary = ml::TrainControl::new(core::TermCriteria::max_iter(25))?
+		.with_chunk_iterations(10)
+		.train(&mut ann, &data)?;
+	assert_eq!(ml::TrainStopReason::MaxIterations, summary.stop_reason);
+	assert_eq!(25, summary.iterations);
+	assert!(ann.is_trained()?);
+
+	let summary = ml::TrainControl::new(core::TermCriteria::eps(1e-3))?
+		.with_timeout(Duration::from_millis(1))
+		.train(&mut ann, &data)?;
+	assert_ne!(ml::TrainStopReason::MaxIterations, summary.stop_reason);
+
+	assert!(ml::TrainControl::new(core::TermCriteria::eps(1e-3))?.train(&mut ann, &data).is_err());
+	let summary = ml::TrainControl::new(core::TermCriteria::max_iter(1000))?
+		.with_chunk_iterations(10)
+		.with_error_tolerance(100.)
+		.train(&mut ann, &data)?;
+	assert_eq!(ml::TrainStopReason::Converged, summary.stop_reason);
+	assert_eq!(20, summary.iterations);
+	Ok(())
+}
+
+#[test]
+fn train_row_chunks() -> Result<()> {
+	let samp = Mat::from_slice_2d(&[[0f32, 0.], [0., 1.], [1., 0.], [1., 1.], [0.1, 0.1], [0.9, 0.1], [0.1, 0.9]])?;
+	let resp = Mat::from_slice_2d(&[[0f32], [1.], [1.], [0.], [0.], [1.], [1.]])?;
+	let mut ann = <dyn ml::ANN_MLP>::create()?;
+	ann.set_layer_sizes(&Mat::from_slice(&[2i32, 4, 1])?)?;
+	ann.set_activation_function(ml::ANN_MLP_ActivationFunctions::SIGMOID_SYM as i32, 0., 0.)?;
+	ann.set_term_criteria(core::TermCriteria::max_iter(5))?;
+	assert!(ml::train_row_chunks(&mut ann, &samp, &resp, 3, 2, 0)?);
+	assert!(ann.is_trained()?);
+	assert!(ml::train_row_chunks(&mut ann, &samp, &resp.row_range(&core::Range::new(0, 2)?)?, 3, 1, 0).is_err());
+	assert!(ml::train_row_chunks(&mut ann, &samp, &resp, 0, 1, 0).is_err());
+	Ok(())
+}
+
+#[test]
+fn fit_stream() -> Result<()> {
+	use std::{sync::mpsc, thread};
+	use ml::BatchSource;
+
+	let csv = "x,y,out\n0,0,0\n0,1,1\n\n1,0,1\n1,1,0\n0.1,0.1,0\n";
+	let mut batches = ml::CsvBatches::new(csv.as_bytes(), 2)?.with_header(true);
+	let (samples, responses) = batches.next_batch()?.unwrap();
+	assert_eq!(core::Size::new(2, 2), samples.size()?);
+	assert_eq!(1., *responses.at_2d::<f32>(1, 0)?);
+	assert_eq!(1., *samples.at_2d::<f32>(1, 1)?);
+
+	let mut ann = <dyn ml::ANN_MLP>::create()?;
+	ann.set_layer_sizes(&Mat::from_slice(&[2i32, 4, 1])?)?;
+	ann.set_activation_function(ml::ANN_MLP_ActivationFunctions::SIGMOID_SYM as i32, 0., 0.)?;
+	ann.set_term_criteria(core::TermCriteria::max_iter(5))?;
+	let summary = ml::fit_stream(&mut ann, ml::CsvBatches::new(csv.as_bytes(), 2)?.with_header(true), 0)?;
+	assert_eq!(ml::StreamSummary { batches: 3, samples: 5 }, summary);
+	assert!(ann.is_trained()?);
+
+	let first_col = ml::CsvBatches::new("1;0;0\n".as_bytes(), 2)?.with_delimiter(';').with_response_col(0).next_batch()?.unwrap();
+	assert_eq!(1., *first_col.1.at_2d::<f32>(0, 0)?);
+	assert!(ml::CsvBatches::new("0,1\n0,1,2\n".as_bytes(), 2)?.next_batch().is_err());
+	assert!(ml::CsvBatches::new("0,x\n".as_bytes(), 2)?.next_batch().is_err());
+
+	let (sender, receiver) = mpsc::channel();
+	let producer = thread::spawn(move || -> Result<()> {
+		for _ in 0..3 {
+			let samples = Mat::from_slice_2d(&[[0f32, 0.], [0., 1.], [1., 0.], [1., 1.]])?;
+			let responses = Mat::from_slice_2d(&[[0f32], [1.], [1.], [0.]])?;
+			sender.send((samples, responses)).unwrap();
+		}
+		Ok(())
+	});
+	let summary = ml::fit_stream(&mut ann, receiver, 0)?;
+	producer.join().unwrap()?;
+	assert_eq!(ml::StreamSummary { batches: 3, samples: 12 }, summary);
+	Ok(())
+}
+
+#[test]
+fn tracking() -> Result<()> {
+	use ml::logging::{self, JsonlTracker};
+
+	let samp = Mat::from_slice_2d(&[[1f32, 1.], [2., 2.], [8., 8.], [9., 9.]])?;
+	let resp = Mat::from_slice(&[0i32, 0, 1, 1])?;
+	let data = <dyn ml::TrainData>::create_with_layout(&samp, SampleTypes::ROW_SAMPLE, &resp)?;
+	let mut tracker = JsonlTracker::new(Vec::new(), "svm \"linear\"");
+	let mut svm = <dyn ml::SVM>::create()?;
+	svm.set_c(2.)?;
+	assert!(logging::train_tracked(&mut svm, &data, &mut tracker, |svm| svm.train_with_data(&data, 0))?);
+	let out = String::from_utf8(tracker.into_inner()).unwrap();
+	let lines = out.lines().collect::<Vec<_>>();
+	assert!(lines.iter().all(|l| l.starts_with(r#"{"run":"svm \"linear\"","timestamp":"#) && l.ends_with('}')));
+	assert!(lines[0].ends_with(r#""kind":"param","key":"model","value":"opencv_ml_svm"}"#));
+	assert!(lines.iter().any(|l| l.ends_with(r#""kind":"param","key":"c","value":"2"}"#)));
+	assert!(lines.iter().any(|l| l.ends_with(r#""kind":"metric","key":"train_error","value":0}"#)));
+
+	let samp = Mat::from_slice_2d(&[[0f32, 0.], [0., 1.], [1., 0.], [1., 1.]])?;
+	let resp = Mat::from_slice_2d(&[[0f32], [1.], [1.], [0.]])?;
+	let data = <dyn ml::TrainData>::create_with_layout(&samp, SampleTypes::ROW_SAMPLE, &resp)?;
+	let mut ann = <dyn ml::ANN_MLP>::create()?;
+	ann.set_layer_sizes(&Mat::from_slice(&[2i32, 4, 1])?)?;
+	let mut tracker = JsonlTracker::new(Vec::new(), "ann");
+	ml::TrainControl::new(core::TermCriteria::max_iter(6))?
+		.with_chunk_iterations(3)
+		.train_tracked(&mut ann, &data, &mut tracker)?;
+	let out = String::from_utf8(tracker.into_inner()).unwrap();
+	assert_eq!(2, out.lines().filter(|l| l.contains(r#""key":"train_error""#) && l.contains(r#""step":"#)).count());
+	assert!(out.contains(r#""key":"stop_reason","value":"MaxIterations"}"#));
+	Ok(())
+}
+
+#[test]
+fn train_with_cancel() -> Result<()> {
+	let samp = Mat::from_slice_2d(&[[0f32, 0.], [0., 1.], [1., 0.], [1., 1.]])?;
+	let resp = Mat::from_slice_2d(&[[0f32], [1.], [1.], [0.]])?;
+	let data = <dyn ml::TrainData>::create_with_layout(&samp, SampleTypes::ROW_SAMPLE, &resp)?;
+	let mut ann = <dyn ml::ANN_MLP>::create()?;
+	ann.set_layer_sizes(&Mat::from_slice(&[2i32, 4, 1])?)?;
+	ann.set_activation_function(ml::ANN_MLP_ActivationFunctions::SIGMOID_SYM as i32, 0., 0.)?;
+	ann.set_term_criteria(core::TermCriteria::max_iter(30))?;
+
+	let token = ml::CancellationToken::new();
+	assert!(ann.train_with_cancel(&data, 0, &token)?);
+	assert!(ann.is_trained()?);
+	assert_eq!(core::TermCriteria::max_iter(30), ann.get_term_criteria()?);
+
+	let clone = token.clone();
+	clone.cancel();
+	assert!(token.is_cancelled());
+	let mut ann = <dyn ml::ANN_MLP>::create()?;
+	ann.set_layer_sizes(&Mat::from_slice(&[2i32, 4, 1])?)?;
+	assert!(!ann.train_with_cancel(&data, 0, &token)?);
+	assert!(!ann.is_trained()?);
+
+	let samp = Mat::from_slice_2d(&[[0f32, 0.], [0.1, 0.2], [0.2, 0.1], [5., 5.], [5.1, 5.2], [5.2, 5.1]])?;
+	let data = <dyn ml::TrainData>::create_with_layout(&samp, SampleTypes::ROW_SAMPLE, &no_array())?;
+	let mut em = <dyn ml::EM>::create()?;
+	em.set_clusters_number(2)?;
+	let summary = ml::TrainControl::new(core::TermCriteria::max_iter(6))?
+		.with_chunk_iterations(3)
+		.train(&mut em, &data)?;
+	assert_eq!(6, summary.iterations);
+	assert!(em.is_trained()?);
+	Ok(())
+}
+
+#[test]
+fn train_data_describe() -> Result<()> {
+	let samp = Mat::from_slice_2d(&[[1f32, 10.], [2., 10.], [3., 10.], [4., 10.], [5., 30.]])?;
+	let resp = Mat::from_slice(&[3i32, 3, 5, 5, 5])?;
+	let data = <dyn ml::TrainData>::create_with_layout(&samp, SampleTypes::ROW_SAMPLE, &resp)?;
+	let summary = data.describe()?;
+	assert_eq!(5, summary.samples);
+	assert_eq!(2, summary.features.len());
+	let first = summary.features[0];
+	assert_eq!((1., 5., 3., 3.), (first.min, first.max, first.mean, first.median));
+	assert_eq!((2., 4.), (first.q25, first.q75));
+	assert_eq!(14., summary.features[1].mean);
+	assert_eq!(1, summary.responses.len());
+	assert_eq!(Some(vec![(3, 2), (5, 3)]), summary.class_counts);
+	Ok(())
+}
+
+#[test]
+fn null_ptr_guard() -> Result<()> {
+	let mut data = <dyn ml::TrainData>::load_from_csv("non-existent.csv", 0, -1, -1, "", b',' as i8, b'?' as i8)?;
+	assert!(data.is_null());
+	assert_eq!(core::StsNullPtr, data.try_non_null().map(|_| ()).unwrap_err().code);
+	assert!(data.try_non_null_mut().is_err());
+	let samp = Mat::from_slice_2d(&[[1f32], [2.]])?;
+	let resp = Mat::from_slice(&[1i32, 2])?;
+	let data = <dyn ml::TrainData>::create_with_layout(&samp, SampleTypes::ROW_SAMPLE, &resp)?;
+	assert!(!data.is_null());
+	assert_eq!(2, data.try_non_null()?.get_n_samples()?);
+	Ok(())
+}
+
+#[test]
+fn train_predict_validation() -> Result<()> {
+	let samp = Mat::from_slice_2d(&[[1f32, 1.], [1., 2.], [5., 5.], [5., 6.]])?;
+	let labels_f32 = Mat::from_slice(&[1f32, 1., 2., 2.])?;
+	let labels = Mat::from_slice(&[1i32, 1, 2, 2])?;
+	let mut svm = <dyn ml::SVM>::create()?;
+	let err = svm.validate_train(&samp, SampleTypes::ROW_SAMPLE as i32, &labels_f32).unwrap_err();
+	assert_eq!(core::StsUnsupportedFormat, err.code);
+	let err = svm.validate_train(&samp, SampleTypes::ROW_SAMPLE as i32, &Mat::from_slice(&[1i32, 2])?).unwrap_err();
+	assert_eq!(core::StsUnmatchedSizes, err.code);
+	let samp_i32 = Mat::from_slice_2d(&[[1i32, 1], [1, 2], [5, 5], [5, 6]])?;
+	assert_eq!(core::StsUnsupportedFormat, svm.validate_train(&samp_i32, SampleTypes::ROW_SAMPLE as i32, &labels).unwrap_err().code);
+	assert_eq!(core::StsError, svm.validate_predict(&samp).unwrap_err().code);
+
+	assert!(svm.train_validated(&samp, SampleTypes::ROW_SAMPLE as i32, &labels)?);
+	let mut res = Mat::default();
+	svm.predict_validated(&samp, &mut res, 0)?;
+	assert_eq!(4, res.rows());
+	let err = svm.predict_validated(&Mat::from_slice(&[1f32, 1., 1.])?, &mut res, 0).unwrap_err();
+	assert_eq!(core::StsBadSize, err.code);
+	Ok(())
+}
+
+#[test]
+fn train_data_layout() -> Result<()> {
+	let samp = Mat::from_slice_2d(&[[1f32, 1.], [2., 2.], [8., 8.], [9., 9.]])?;
+	let resp = Mat::from_slice(&[0i32, 0, 1, 1])?;
+	let data = <dyn ml::TrainData>::create_with_layout(&samp, SampleTypes::ROW_SAMPLE, &resp)?;
+	assert_eq!(SampleTypes::ROW_SAMPLE, data.sample_layout()?);
+	assert_eq!(ml::VariableTypes::VAR_CATEGORICAL, data.response_variable_type()?);
+	let cols = data.train_samples_with_layout(SampleTypes::COL_SAMPLE, true, true)?;
+	assert_eq!(Size::new(4, 2), cols.size()?);
+	Ok(())
+}
+
+#[test]
+#[cfg(all(ocvrs_has_module_features2d, ocvrs_has_module_imgcodecs, ocvrs_has_module_imgproc, ocvrs_has_module_objdetect))]
+fn quick_image_classifier() -> Result<()> {
+	use opencv::{
+		core::{Point, Rect},
+		imgcodecs,
+		imgproc,
+		ml::quick::{self, FeatureExtractor, ModelKind},
+	};
+
+	let dir = std::env::temp_dir().join(format!("opencv-rust-quick-{}", std::process::id()));
+	let shape = |class: &str, i: i32| -> Result<Mat> {
+		let mut img = Mat::new_rows_cols_with_default(128, 64, u8::typ(), Scalar::all(0.))?;
+		if class == "circle" {
+			imgproc::circle(&mut img, Point::new(32, 64), 15 + i, Scalar::all(255.), -1, imgproc::LINE_8, 0)?;
+		} else {
+			imgproc::rectangle(&mut img, Rect::new(8 + i, 16 + i, 40, 90), Scalar::all(255.), 3, imgproc::LINE_8, 0)?;
+		}
+		Ok(img)
+	};
+	for class in &["circle", "frame"] {
+		std::fs::create_dir_all(dir.join(class)).unwrap();
+		for i in 0..5 {
+			let path = dir.join(class).join(format!("{}.png", i));
+			imgcodecs::imwrite(&path.to_string_lossy(), &shape(class, i)?, &core::Vector::new())?;
+		}
+	}
+	std::fs::write(dir.join("circle").join("notes.txt"), "not an image").unwrap();
+
+	let classifier = quick::train_image_classifier(&dir, FeatureExtractor::Hog, ModelKind::Svm)?;
+	assert_eq!(&["circle".to_string(), "frame".to_string()][..], classifier.labels().names());
+	assert_eq!(1., classifier.train_accuracy());
+	assert!(classifier.test_accuracy() >= 0.5);
+	let model_path = dir.join("classifier.json").to_string_lossy().into_owned();
+	classifier.save(&model_path)?;
+	let loaded = quick::ImageClassifier::load(&model_path)?;
+	assert_eq!(FeatureExtractor::Hog, loaded.extractor());
+	assert_eq!("circle", loaded.classify(&shape("circle", 2)?)?);
+	assert_eq!("frame", loaded.classify(&shape("frame", 2)?)?);
+
+	assert!(quick::train_image_classifier(dir.join("circle"), FeatureExtractor::Hog, ModelKind::Svm).is_err());
+	std::fs::remove_dir_all(&dir).unwrap();
+	Ok(())
+}
+
+#[test]
+fn dtrees_node_try_accessors() -> Result<()> {
+	let samp = Mat::from_slice_2d(&[[1f32], [2.], [10.], [11.]])?;
+	let resp = Mat::from_slice(&[1i32, 1, 2, 2])?;
+	let data = <dyn ml::TrainData>::create_with_layout(&samp, SampleTypes::ROW_SAMPLE, &resp)?;
+	let mut tree = <dyn ml::DTrees>::create()?;
+	tree.set_max_depth(2)?;
+	tree.set_cv_folds(0)?;
+	assert!(tree.train_with_data(&data, 0)?);
+	let nodes = tree.get_nodes()?;
+	assert!(!nodes.is_empty());
+	for node in nodes {
+		assert_eq!(node.value(), node.try_value()?);
+		assert_eq!(node.parent(), node.try_parent()?);
+	}
+	let null_node = unsafe { ml::DTrees_Node::from_raw(std::ptr::null_mut()) };
+	assert_eq!(core::StsNullPtr, null_node.try_value().unwrap_err().code);
+	assert_eq!(core::StsNullPtr, null_node.try_class_idx().unwrap_err().code);
+	null_node.into_raw();
+	// the released objects are only poisoned in the debug builds
+	if cfg!(debug_assertions) {
+		let ptr = tree.get_nodes()?.get(0)?.into_raw();
+		let stale = std::mem::ManuallyDrop::new(unsafe { ml::DTrees_Node::from_raw(ptr) });
+		drop(unsafe { ml::DTrees_Node::from_raw(ptr) });
+		assert_eq!(core::StsObjectNotFound, stale.try_value().unwrap_err().code);
+	}
+	Ok(())
+}
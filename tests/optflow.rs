@@ -0,0 +1,46 @@
+#![cfg(all(ocvrs_has_module_optflow, ocvrs_has_module_calib3d))]
+
+use opencv::{
+	calib3d::stereo::{Disparity, Matcher, Preset},
+	core::{self, Mat, Scalar},
+	optflow::scene_flow::{SceneFlow, SceneFlowEstimator},
+	prelude::*,
+	Result,
+};
+
+#[test]
+fn scene_flow() -> Result<()> {
+	// f = 500, baseline = 0.1
+	let q = Mat::from_slice_2d(&[
+		[1., 0., 0., -2.],
+		[0., 1., 0., -2.],
+		[0., 0., 0., 500.],
+		[0., 0., 10., 0.],
+	])?;
+	let mut raw0 = Mat::new_rows_cols_with_default(4, 4, core::CV_16SC1, Scalar::all(8. * 16.))?;
+	*raw0.at_2d_mut::<i16>(1, 1)? = -16;
+	let mut raw1 = Mat::new_rows_cols_with_default(4, 4, core::CV_16SC1, Scalar::all(10. * 16.))?;
+	*raw1.at_2d_mut::<i16>(2, 2)? = -16;
+	let depth0 = Disparity::from_fixed_point(&raw0, 0)?.to_depth(&q)?;
+	let depth1 = Disparity::from_fixed_point(&raw1, 0)?.to_depth(&q)?;
+	// everything moves one pixel to the right
+	let flow = Mat::new_rows_cols_with_default(4, 4, core::CV_32FC2, Scalar::new(1., 0., 0., 0.))?;
+
+	let scene_flow = SceneFlow::from_parts(depth0, &depth1, flow)?;
+	let motion = scene_flow.at(0, 0)?.expect("Motion is known");
+	assert!((motion[2] + 1.25).abs() < 1e-5);
+	assert!((motion[0] - 0.015).abs() < 1e-5);
+	// lands outside of the image
+	assert_eq!(None, scene_flow.at(0, 3)?);
+	// no depth in the first frame
+	assert_eq!(None, scene_flow.at(1, 1)?);
+	// lands on the pixel without depth in the second frame
+	assert_eq!(None, scene_flow.at(2, 1)?);
+	assert_eq!(10, core::count_non_zero(&scene_flow.valid_mask()?)?);
+	assert_eq!(core::CV_32FC3, scene_flow.points().typ());
+
+	let depth0 = Disparity::from_fixed_point(&raw0, 0)?.to_depth(&q)?;
+	assert!(SceneFlow::from_parts(depth0, &depth1, Mat::new_rows_cols_with_default(2, 2, core::CV_32FC2, Scalar::all(0.))?).is_err());
+	assert!(SceneFlowEstimator::new(Matcher::new(Preset::Fast, 16)?, Mat::eye(3, 3, core::CV_64F)?.to_mat()?).is_err());
+	Ok(())
+}
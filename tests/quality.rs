@@ -0,0 +1,25 @@
+#![cfg(ocvrs_has_module_quality)]
+
+use opencv::{
+	core::{self, Scalar},
+	prelude::*,
+	quality,
+	Result,
+};
+
+#[test]
+fn full_reference() -> Result<()> {
+	let a = Mat::new_rows_cols_with_default(32, 32, core::CV_8UC3, Scalar::all(100.))?;
+	let b = Mat::new_rows_cols_with_default(32, 32, core::CV_8UC3, Scalar::all(110.))?;
+
+	assert!((quality::ssim(&a, &a)? - 1.).abs() < 1e-6);
+	assert!(quality::psnr(&a, &a)?.is_infinite());
+	assert!((quality::psnr(&a, &b)? - 28.1308).abs() < 1e-3);
+	assert!((quality::mse(&a, &b)? - 100.).abs() < 1e-6);
+	assert!(quality::gmsd(&a, &a)?.abs() < 1e-6);
+
+	let gray = Mat::new_rows_cols_with_default(32, 32, core::CV_8UC1, Scalar::all(100.))?;
+	assert!(quality::ssim(&a, &gray).is_err());
+	assert!(quality::psnr(&Mat::default(), &Mat::default()).is_err());
+	Ok(())
+}
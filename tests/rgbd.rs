@@ -0,0 +1,36 @@
+#![cfg(ocvrs_has_module_rgbd)]
+
+use opencv::{
+	core::{self, Mat, Scalar, Size},
+	prelude::*,
+	Result,
+	rgbd::{self, OdometryTracker},
+};
+
+#[test]
+fn check_depth_frame() -> Result<()> {
+	let size = Size::new(64, 48);
+	let depth = Mat::new_size_with_default(size, core::CV_16UC1, Scalar::all(1000.))?;
+	rgbd::check_depth_frame(&depth, size)?;
+	assert!(rgbd::check_depth_frame(&depth, Size::new(640, 480)).is_err());
+	let depth_f64 = Mat::new_size_with_default(size, core::CV_64FC1, Scalar::all(1.))?;
+	assert!(rgbd::check_depth_frame(&depth_f64, size).is_err());
+	assert!(rgbd::check_depth_frame(&Mat::default(), size).is_err());
+	Ok(())
+}
+
+#[test]
+fn odometry_tracker() -> Result<()> {
+	let size = Size::new(64, 48);
+	let mut tracker = OdometryTracker::new(<dyn rgbd::Odometry>::create("RgbdOdometry")?)?.with_depth_scale(0.001);
+	let image = Mat::new_size_with_default(size, core::CV_8UC3, Scalar::all(128.))?;
+	let depth = Mat::new_size_with_default(size, core::CV_16UC1, Scalar::all(1000.))?;
+	// first frame has nothing to track against
+	assert!(tracker.feed(&image, &depth)?.is_none());
+	assert_eq!(1., *tracker.pose().at_2d::<f64>(3, 3)?);
+	let small_depth = Mat::new_size_with_default(Size::new(32, 24), core::CV_16UC1, Scalar::all(1000.))?;
+	assert!(tracker.feed(&image, &small_depth).is_err());
+	tracker.reset()?;
+	assert_eq!(4, tracker.pose().rows());
+	Ok(())
+}
@@ -0,0 +1,41 @@
+#![cfg(unix)]
+
+use opencv::{
+	core::{self, Scalar},
+	Error,
+	prelude::*,
+	Result,
+	risky,
+};
+
+#[test]
+fn risky_result() -> Result<()> {
+	let mat = risky::run(|| Mat::new_rows_cols_with_default(3, 4, f32::typ(), Scalar::all(2.5)))?;
+	assert_eq!(3, mat.rows());
+	assert_eq!(4, mat.cols());
+	assert_eq!(2.5, *mat.at_2d::<f32>(2, 3)?);
+
+	let empty = risky::run(|| Ok(Mat::default()))?;
+	assert!(empty.empty());
+
+	let s = risky::run(|| Ok(Some("test".to_string())))?;
+	assert_eq!(Some("test".to_string()), s);
+	Ok(())
+}
+
+#[test]
+fn risky_error() {
+	let err = risky::run(|| -> Result<i32> { Err(Error::new(core::StsBadArg, "bad input")) }).unwrap_err();
+	assert_eq!(core::StsBadArg, err.code);
+	assert_eq!("bad input", err.message);
+
+	let err = risky::run(|| -> Result<()> { panic!("boom") }).unwrap_err();
+	assert_eq!(core::StsError, err.code);
+}
+
+#[test]
+fn risky_crash() {
+	let err = risky::run(|| -> Result<()> { std::process::abort() }).unwrap_err();
+	assert_eq!(core::StsInternal, err.code);
+	assert!(err.message.contains("SIGABRT"), "{}", err.message);
+}
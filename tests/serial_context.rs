@@ -0,0 +1,73 @@
+use std::{
+	sync::Arc,
+	thread,
+};
+
+use opencv::{
+	core::{self, Scalar, Serial, SerialContext},
+	prelude::*,
+	Result,
+};
+
+#[test]
+fn serial_context_run() -> Result<()> {
+	let context = Arc::new(SerialContext::new("test-serial")?);
+	assert!(!context.is_current());
+	let context_thread = context.run(|| thread::current().id());
+	let handles = (0..4)
+		.map(|i| {
+			let context = Arc::clone(&context);
+			thread::spawn(move || context.run(|| (i * 2, thread::current().id(), context.is_current())))
+		})
+		.collect::<Vec<_>>();
+	for (i, handle) in handles.into_iter().enumerate() {
+		let (res, thread_id, is_current) = handle.join().unwrap();
+		assert_eq!(i * 2, res);
+		assert_eq!(context_thread, thread_id);
+		assert!(is_current);
+	}
+	// nested calls and borrows from the calling thread
+	let mut sum = 0;
+	context.run(|| context.run(|| sum += 10));
+	assert_eq!(10, sum);
+	Ok(())
+}
+
+#[test]
+fn serial_context_panic() -> Result<()> {
+	let context = SerialContext::new("test-serial-panic")?;
+	let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| context.run(|| panic!("boom"))));
+	assert!(res.is_err());
+	assert_eq!(5, context.run(|| 5));
+	Ok(())
+}
+
+#[test]
+fn serial_object() -> Result<()> {
+	let context = Arc::new(SerialContext::new("test-serial-object")?);
+	let mat = Arc::new(Serial::new(Arc::clone(&context), || Mat::new_rows_cols_with_default(2, 2, u8::typ(), Scalar::all(1.)))?);
+	let handles = (0..4)
+		.map(|_| {
+			let mat = Arc::clone(&mat);
+			thread::spawn(move || mat.with(|m| -> Result<()> {
+				*m.at_2d_mut::<u8>(0, 0)? += 1;
+				Ok(())
+			}))
+		})
+		.collect::<Vec<_>>();
+	for handle in handles {
+		handle.join().unwrap()?;
+	}
+	assert_eq!(5, mat.with(|m| -> Result<u8> { Ok(*m.at_2d::<u8>(0, 0)?) })?);
+	assert_eq!(core::CV_8UC1, mat.with(|m| Ok(m.typ()))?);
+	Ok(())
+}
+
+#[test]
+fn serial_object_reentrant() -> Result<()> {
+	let mat = Serial::new(Arc::new(SerialContext::new("test-serial-reentrant")?), || Mat::new_rows_cols_with_default(2, 2, u8::typ(), Scalar::all(1.)))?;
+	let inner = mat.with(|_| Ok(mat.with(|m| Ok(m.rows()))))?;
+	assert_eq!(core::StsError, inner.unwrap_err().code);
+	assert_eq!(2, mat.with(|m| Ok(m.rows()))?);
+	Ok(())
+}
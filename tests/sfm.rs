@@ -0,0 +1,724 @@
+#![cfg(ocvrs_has_module_sfm)]
+
+use std::{thread, time::Duration};
+
+use opencv::{
+	calib3d,
+	core::{self, Mat, Matx33d, Matx66d, no_array, Point2d, Point3d, Scalar, Vec3d},
+	prelude::*,
+	Result,
+	sfm::{self, BaCamera, BaLoss, BaObservation, BaOptions, PnpMethod, PnpSolver},
+};
+
+#[test]
+fn spawn_reconstruction() -> Result<()> {
+	let k = Mat::from_slice_2d(&[[500f64, 0., 320.], [0., 500., 240.], [0., 0., 1.]])?;
+	let handle = sfm::spawn_reconstruction(sfm::ReconstructionInput::from_tracks(vec![], k.clone()));
+	while handle.poll_status() == sfm::ReconstructionStatus::Running {
+		thread::sleep(Duration::from_millis(1));
+	}
+	assert_eq!(sfm::ReconstructionStatus::Finished, handle.poll_status());
+	handle.cancel();
+	assert_eq!(sfm::ReconstructionStatus::Finished, handle.poll_status());
+	// not enough frames
+	assert!(handle.join().is_err());
+	Ok(())
+}
+
+#[test]
+fn bundle_adjust() -> Result<()> {
+	let k = Matx33d::from([500., 0., 320., 0., 500., 240., 0., 0., 1.]);
+	let poses = [([0., 0., 0.], [0., 0., 0.]), ([0.05, -0.1, 0.02], [-1., 0.1, 0.05]), ([-0.03, 0.1, 0.], [1., -0.05, 0.1])];
+	let points_gt = (0..30)
+		.map(|i| {
+			let i = f64::from(i);
+			Point3d::new((i * 0.37).sin() * 2., (i * 0.71).cos() * 1.5, 6. + (i * 0.13).sin())
+		})
+		.collect::<Vec<_>>();
+	let mut observations = vec![];
+	for (camera, (rvec, tvec)) in poses.iter().enumerate() {
+		let mut cam_points = Mat::default();
+		calib3d::project_points(&Mat::from_slice(&points_gt)?, &Mat::from_slice(rvec)?, &Mat::from_slice(tvec)?, &k, &Mat::default(), &mut cam_points, &mut no_array(), 0.)?;
+		for (point, &pixel) in cam_points.data_typed::<Point2d>()?.iter().enumerate() {
+			observations.push(BaObservation { camera, point, pixel });
+		}
+	}
+	// single gross outlier
+	observations[5].pixel.x += 80.;
+
+	let mut fixed_points = vec![false; points_gt.len()];
+	fixed_points[3] = true;
+	for loss in [BaLoss::Squared, BaLoss::Huber(2.), BaLoss::Cauchy(2.)] {
+		let mut cameras = poses.iter()
+			.enumerate()
+			.map(|(i, (rvec, tvec))| {
+				let d = if i == 0 { 0. } else { 0.02 };
+				BaCamera { k, rvec: Vec3d::from([rvec[0] + d, rvec[1] - d, rvec[2]]), tvec: Vec3d::from([tvec[0] + 5. * d, tvec[1], tvec[2] - 5. * d]) }
+			})
+			.collect::<Vec<_>>();
+		let mut points = points_gt.iter().map(|p| Point3d::new(p.x + 0.03, p.y + 0.02, p.z - 0.05)).collect::<Vec<_>>();
+		let first_camera = cameras[0];
+		let fixed_point = points[3];
+		let options = BaOptions::default()
+			.with_loss(loss)
+			.with_fixed_cameras(vec![true])
+			.with_fixed_points(fixed_points.clone())
+			.with_covariance(loss == BaLoss::Squared);
+		let mut reported = 0;
+		let report = sfm::bundle_adjust_with_callback(&mut cameras, &mut points, &observations, &options, |_| reported += 1)?;
+		assert!(report.final_cost < report.initial_cost);
+		assert_eq!(reported, report.iterations.len());
+		assert!(report.converged);
+		assert_eq!(first_camera, cameras[0]);
+		assert_eq!(fixed_point, points[3]);
+		if loss != BaLoss::Squared {
+			// robust loss is not dragged away by the outlier
+			assert!((cameras[1].tvec[0] + 1.).abs() < 0.05);
+			assert!(report.camera_covariances.is_none());
+		} else {
+			let covariances = report.camera_covariances.expect("Covariance was requested");
+			assert_eq!(3, covariances.len());
+			assert_eq!(Matx66d::default(), covariances[0]);
+			for cov in &covariances[1..] {
+				assert!((0..6).all(|i| cov[(i, i)] > 0.));
+			}
+		}
+	}
+
+	let invalid = [BaObservation { camera: 5, point: 0, pixel: Point2d::default() }];
+	assert!(sfm::bundle_adjust(&mut [], &mut [], &invalid, &BaOptions::default()).is_err());
+	Ok(())
+}
+
+#[test]
+fn register_camera() -> Result<()> {
+	let k = Matx33d::from([500., 0., 320., 0., 500., 240., 0., 0., 1.]);
+	let points3d = (0..20)
+		.map(|i| {
+			let i = f64::from(i);
+			Point3d::new((i * 0.37).sin() * 2., (i * 0.71).cos() * 1.5, 6. + (i * 0.13).sin())
+		})
+		.collect::<Vec<_>>();
+	let points3d = Mat::from_slice(&points3d)?;
+	let tvec = [0.3, -0.2, 0.5];
+	let mut points2d = Mat::default();
+	calib3d::project_points(&points3d, &Mat::from_slice(&[0.05, -0.1, 0.02])?, &Mat::from_slice(&tvec)?, &k, &Mat::default(), &mut points2d, &mut no_array(), 0.)?;
+	// one outlier correspondence
+	points2d.at_mut::<Point2d>(7)?.x += 100.;
+
+	for method in [PnpMethod::default(), PnpMethod::ransac(PnpSolver::Epnp)] {
+		let pose = sfm::register_camera(&points3d, &points2d, &k, method)?.expect("Pose not found");
+		assert_eq!(3, pose.r.rows());
+		assert_eq!(3, pose.r.cols());
+		assert_eq!(19, pose.inliers.len());
+		assert!(!pose.inliers.contains(&7));
+		for (i, &t) in tvec.iter().enumerate() {
+			assert!((*pose.t.at::<f64>(i as i32)? - t).abs() < 1e-3);
+		}
+	}
+
+	let pose = sfm::register_camera(&points3d, &points2d, &k, PnpMethod::default())?.expect("Pose not found");
+	let mut noisy = points2d.try_clone()?;
+	for i in 0..20 {
+		noisy.at_mut::<Point2d>(i)?.y += if i % 2 == 0 { 0.5 } else { -0.5 };
+	}
+	let cov = pose.covariance(&points3d, &noisy, &k)?.expect("Pose is constrained");
+	assert_eq!((6, 6), (cov.rows(), cov.cols()));
+	for r in 0..6 {
+		assert!(*cov.at_2d::<f64>(r, r)? > 0.);
+		for c in 0..6 {
+			assert!((*cov.at_2d::<f64>(r, c)? - *cov.at_2d::<f64>(c, r)?).abs() < 1e-12);
+		}
+	}
+	assert!(pose.covariance(&points3d, &noisy.row_range(&core::Range::new(0, 10)?)?, &k).is_err());
+	Ok(())
+}
+
+#[test]
+fn cloud() -> Result<()> {
+	use sfm::cloud;
+
+	// 10x10 grid in z = 5 plane with 0.1 spacing and a single far away point
+	let mut points = (0..100)
+		.map(|i| Point3d::new(f64::from(i % 10) * 0.1, f64::from(i / 10) * 0.1, 5.))
+		.collect::<Vec<_>>();
+	points.push(Point3d::new(10., 10., 10.));
+
+	let mat = cloud::points_to_mat(&points)?;
+	assert_eq!(3, mat.rows());
+	assert_eq!(101, mat.cols());
+	assert_eq!(points, cloud::points_from_mat(&mat)?);
+
+	let mask = cloud::statistical_outlier_mask(&points, 8, 1.)?;
+	assert!(!mask[100]);
+	assert!(mask[..100].iter().all(|&keep| keep));
+	let inliers = cloud::remove_statistical_outliers(&points, 8, 1.)?;
+	assert_eq!(100, inliers.len());
+
+	let downsampled = cloud::voxel_downsample(&inliers, 0.2)?;
+	assert_eq!(25, downsampled.len());
+	assert!(cloud::voxel_downsample(&inliers, 0.).is_err());
+
+	let normals = cloud::estimate_normals(&inliers, 8, Point3d::new(0., 0., 0.))?;
+	assert_eq!(100, normals.len());
+	for n in normals {
+		assert!(n[0].abs() < 1e-6 && n[1].abs() < 1e-6);
+		assert!((n[2] + 1.).abs() < 1e-6);
+	}
+	Ok(())
+}
+
+#[test]
+fn depth_to_cloud() -> Result<()> {
+	let k = Matx33d::from([2., 0., 1., 0., 2., 1., 0., 0., 1.]);
+	let depth = Mat::from_slice_2d(&[
+		[2f32, 2., 2.],
+		[2., 2., 2.],
+		[0., 2., 9.],
+	])?;
+	let cloud = sfm::depth_to_cloud(&depth, k)?;
+	assert_eq!(8, cloud.len());
+	assert_eq!(Point3d::new(-1., -1., 2.), cloud[0]);
+	assert_eq!(Point3d::new(0., 0., 2.), cloud[4]);
+
+	let mesh = sfm::depth_to_mesh(&depth, k, 1.5)?;
+	// bottom left pixel is missing and bottom right one is far away
+	assert_eq!(vec![[0, 1, 2], [2, 1, 3], [2, 3, 4], [4, 3, 5], [3, 6, 5]], mesh.faces);
+	assert_eq!(7, mesh.vertices.len());
+	let all = sfm::depth_to_mesh(&depth, k, f64::INFINITY)?;
+	assert_eq!(6, all.faces.len());
+	assert_eq!(8, all.vertices.len());
+
+	assert!(sfm::depth_to_cloud(&Mat::from_slice(&[1u8, 2, 3])?.reshape(3, 1)?, k).is_err());
+	Ok(())
+}
+
+#[test]
+fn viz() -> Result<()> {
+	let img1 = Mat::new_rows_cols_with_default(100, 120, core::CV_8UC1, Scalar::all(0.))?;
+	let img2 = Mat::new_rows_cols_with_default(80, 100, core::CV_8UC3, Scalar::all(0.))?;
+	// pure horizontal translation, the epipolar lines are the image rows
+	let f = Mat::from_slice_2d(&[[0f64, 0., 0.], [0., 0., -1.], [0., 1., 0.]])?;
+	let out = sfm::viz::draw_epipolar_lines(&img1, &img2, &f, &[(Point2d::new(10., 20.), Point2d::new(5., 20.))])?;
+	assert_eq!(core::Size::new(220, 100), out.size()?);
+	assert_eq!(core::CV_8UC3, out.typ());
+	// line across the left image at the row of the match
+	assert_ne!(core::Vec3b::all(0), *out.at_2d::<core::Vec3b>(20, 100)?);
+	assert_eq!(core::Vec3b::all(0), *out.at_2d::<core::Vec3b>(50, 100)?);
+
+	let k = Mat::from_slice_2d(&[[500f64, 0., 320.], [0., 500., 240.], [0., 0., 1.]])?;
+	let r = Mat::from_slice_2d(&[[1f64, 0., 0.], [0., 1., 0.], [0., 0., 1.]])?;
+	let ts = vec![Mat::from_slice_2d(&[[0f64], [0.], [0.]])?, Mat::from_slice_2d(&[[-1f64], [0.], [0.]])?];
+	let mut canvas = Mat::new_rows_cols_with_default(200, 200, core::CV_8UC3, Scalar::all(0.))?;
+	sfm::viz::draw_camera_frustums(&mut canvas, &[k], &[r.clone(), r], &ts)?;
+	assert_ne!(Scalar::all(0.), core::sum_elems(&canvas)?);
+	Ok(())
+}
+
+#[test]
+fn error_report() -> Result<()> {
+	let k = Mat::from_slice_2d(&[[500f64, 0., 320.], [0., 500., 240.], [0., 0., 1.]])?;
+	let r = Mat::from_slice_2d(&[[1f64, 0., 0.], [0., 1., 0.], [0., 0., 1.]])?;
+	let ts = vec![Mat::from_slice_2d(&[[0f64], [0.], [0.]])?, Mat::from_slice_2d(&[[-1f64], [0.], [0.]])?];
+	let points3d = vec![Mat::from_slice_2d(&[[0f64], [0.], [5.]])?, Mat::from_slice_2d(&[[1f64], [0.5], [10.]])?];
+	let tracks = vec![
+		// second observation is 3 pixels off
+		Mat::from_slice_2d(&[[320f64, 373.], [240., 265.]])?,
+		// first point is not observed
+		Mat::from_slice_2d(&[[-1f64, 320.], [-1., 265.]])?,
+	];
+	let report = sfm::reprojection_error_report(&[r.clone(), r], &ts, &k, &points3d, &tracks)?;
+	assert_eq!(3, report.overall.count);
+	assert!((report.overall.max - 3.).abs() < 1e-9);
+	assert_eq!(2, report.per_view[0].count);
+	assert_eq!(1, report.per_view[1].count);
+	assert!(report.per_view[1].max < 1e-9);
+	assert_eq!(1, report.per_point[0].count);
+	assert_eq!(vec![1], report.points_above(1.));
+	assert_eq!(vec![0], report.views_above(2.));
+
+	assert!(sfm::reprojection_error_report(&[], &ts, &k, &points3d, &tracks).is_err());
+	Ok(())
+}
+
+#[test]
+fn scale() -> Result<()> {
+	use sfm::scale::{self, GeoPoint};
+
+	let identity = Mat::from_slice_2d(&[[1f64, 0., 0.], [0., 1., 0.], [0., 0., 1.]])?;
+	let vec3 = |v: [f64; 3]| Mat::from_slice_2d(&[[v[0]], [v[1]], [v[2]]]);
+	let geo = vec![
+		GeoPoint::new(48., 11., 500.),
+		GeoPoint::new(48.001, 11., 500.),
+		GeoPoint::new(48., 11.002, 510.),
+		GeoPoint::new(48.0005, 11.001, 520.),
+	];
+	// reconstruction is the ENU frame scaled by 0.5 and rotated by 90° around Z
+	let centers = geo.iter()
+		.map(|g| {
+			let enu = g.to_enu(&geo[0]);
+			[enu[1] * 0.5, -enu[0] * 0.5, enu[2] * 0.5]
+		})
+		.collect::<Vec<_>>();
+	let mut recon = sfm::Reconstruction {
+		rs: vec![identity; 4],
+		ts: centers.iter().map(|c| vec3([-c[0], -c[1], -c[2]])).collect::<Result<_>>()?,
+		k: Mat::default(),
+		points3d: vec![vec3([0., 0., 10.])?, vec3([2., 0., 10.])?],
+	};
+
+	assert_eq!(1.5, scale::apply_known_distance(&mut recon, 0, 1, 3.)?);
+	assert!((*recon.points3d[1].at::<f64>(0)? - 3.).abs() < 1e-12);
+	assert!(scale::apply_known_distance(&mut recon, 0, 5, 3.).is_err());
+
+	let priors = geo.iter().copied().enumerate().collect::<Vec<_>>();
+	let alignment = scale::align_to_gps(&mut recon, &priors)?;
+	assert!(alignment.rms_error < 1e-6);
+	assert!((alignment.similarity.s - 2. / 1.5).abs() < 1e-6);
+	for (center, g) in scale::camera_centers(&recon)?.iter().zip(&geo) {
+		let enu = g.to_enu(&geo[0]);
+		assert!((0..3).all(|i| (center[i] - enu[i]).abs() < 1e-6));
+	}
+
+	assert!(scale::align_to_gps(&mut recon, &priors[..2]).is_err());
+	Ok(())
+}
+
+#[test]
+fn loop_closure() -> Result<()> {
+	use sfm::loop_closure::{LoopDetector, Vocabulary};
+
+	// word w is the unit vector along the dimension w
+	let words = (0..24).map(|w| (0..32).map(|d| if d == w { 100f32 } else { 0. }).collect::<Vec<_>>()).collect::<Vec<_>>();
+	let vocabulary = Vocabulary::from_mat(Mat::from_slice_2d(&words)?)?;
+	assert_eq!(24, vocabulary.len());
+	// views 0 and 3 see the same scene, views 1 and 2 different ones
+	let descriptors = |first_word: usize| -> Result<Mat> {
+		let rows = (0..24)
+			.map(|j| (0..32).map(|d| if d == first_word + j % 8 { 100f32 } else if d == 31 { j as f32 * 3. } else { 0. }).collect::<Vec<_>>())
+			.collect::<Vec<_>>();
+		Mat::from_slice_2d(&rows)
+	};
+	let keypoints = |shift: f64| (0..24)
+		.map(|j| {
+			let depth = 4. + f64::from(j % 5);
+			Point2d::new(f64::from(37 * j % 300 + 10) + shift / depth, f64::from(53 * j % 200 + 10))
+		})
+		.collect::<Vec<_>>();
+
+	let mut detector = LoopDetector::new(vocabulary)
+		.with_min_gap(2)
+		.with_min_inliers(20);
+	assert_eq!(None, detector.add_view(&keypoints(0.), &descriptors(0)?)?);
+	assert_eq!(None, detector.add_view(&keypoints(0.), &descriptors(8)?)?);
+	assert_eq!(None, detector.add_view(&keypoints(0.), &descriptors(16)?)?);
+	let closure = detector.add_view(&keypoints(60.), &descriptors(0)?)?.expect("loop must be detected");
+	assert_eq!((3, 0), (closure.view, closure.matched_view));
+	assert!(closure.matches.len() >= 20);
+	assert!(closure.matches.iter().all(|&(a, b)| a == b));
+
+	let mut observations = vec![
+		sfm::BaObservation { camera: 3, point: 100, pixel: Point2d::default() },
+		sfm::BaObservation { camera: 0, point: 0, pixel: Point2d::default() },
+	];
+	// keypoint j of the view 3 observes the point 100 + j, of the view 0 the point j
+	let merged = closure.link_observations(&mut observations, |view, keypoint| Some(if view == 3 { 100 + keypoint } else { keypoint }));
+	assert_eq!(closure.matches.len(), merged);
+	assert_eq!(0, observations[0].point);
+
+	assert!(detector.add_view(&keypoints(0.), &Mat::default()).is_err());
+	Ok(())
+}
+
+#[test]
+fn keyframe_selection() -> Result<()> {
+	use sfm::{KeyframeSelection, ReconstructionBuilder};
+
+	// camera moving along X over the points at the depths from 4 to 6, frame i loses the first 2 * i points
+	let tracks = (0..6)
+		.map(|i| {
+			let (xs, ys): (Vec<_>, Vec<_>) = (0..40)
+				.map(|j| {
+					if j < 2 * i {
+						return (-1., -1.);
+					}
+					let (x, y, z) = (f64::from(j % 8 - 4) * 0.5, f64::from(j / 8 - 2) * 0.5, 4. + f64::from(j % 5) * 0.5);
+					(500. * (x - 0.2 * f64::from(i)) / z + 320., 500. * y / z + 240.)
+				})
+				.unzip();
+			Mat::from_slice_2d(&[xs, ys])
+		})
+		.collect::<Result<Vec<_>>>()?;
+	let k = Mat::from_slice_2d(&[[500f64, 0., 320.], [0., 500., 240.], [0., 0., 1.]])?;
+	let builder = |selection| ReconstructionBuilder::new(tracks.clone(), k.clone()).keyframe_selection(selection);
+
+	assert_eq!(None, builder(KeyframeSelection::Libmv).select_keyframes()?);
+	let manual = builder(KeyframeSelection::Manual(0, 3)).select_keyframes()?.expect("manual pair");
+	assert_eq!((0, 3, 34, None), (manual.first, manual.second, manual.common_tracks, manual.gric_score));
+	assert!(builder(KeyframeSelection::Manual(3, 3)).select_keyframes().is_err());
+
+	let parallax = builder(KeyframeSelection::Parallax { min_px: 30. }).select_keyframes()?.expect("parallax pair");
+	assert_eq!((0, 2), (parallax.first, parallax.second));
+	assert!(parallax.median_parallax >= 30.);
+	assert!(builder(KeyframeSelection::Parallax { min_px: 1000. }).select_keyframes().is_err());
+
+	let overlap = builder(KeyframeSelection::FeatureOverlap { min_ratio: 0.9 }).select_keyframes()?.expect("overlap pair");
+	assert!(overlap.overlap >= 0.9);
+	let narrow = builder(KeyframeSelection::FeatureOverlap { min_ratio: 0.9 }).keyframe_window(1).select_keyframes()?.expect("overlap pair");
+	assert_eq!(1, narrow.second - narrow.first);
+	assert!(overlap.median_parallax > narrow.median_parallax);
+
+	let gric = builder(KeyframeSelection::Gric).select_keyframes()?.expect("gric pair");
+	assert!(gric.gric_score.expect("gric score") > 0.);
+	Ok(())
+}
+
+#[test]
+fn filter_tracks() -> Result<()> {
+	use sfm::{OutlierPolicy, Reconstruction};
+
+	let k = Mat::from_slice_2d(&[[500f64, 0., 320.], [0., 500., 240.], [0., 0., 1.]])?;
+	let points = [[0f64, 0., 5.], [1., 0., 5.], [0., 1., 6.], [-1., -1., 4.]];
+	let centers = [0f64, 0.5, 1.];
+	let reconstruction = Reconstruction {
+		rs: centers.iter().map(|_| Mat::from_slice_2d(&[[1f64, 0., 0.], [0., 1., 0.], [0., 0., 1.]])).collect::<Result<_>>()?,
+		ts: centers.iter().map(|&c| Mat::from_slice_2d(&[[-c], [0.], [0.]])).collect::<Result<_>>()?,
+		k: k.clone(),
+		points3d: points.iter().map(|p| Mat::from_slice_2d(&[[p[0]], [p[1]], [p[2]]])).collect::<Result<_>>()?,
+	};
+	let tracks = centers.iter()
+		.enumerate()
+		.map(|(frame, &c)| {
+			let (xs, ys): (Vec<_>, Vec<_>) = points.iter()
+				.enumerate()
+				.map(|(i, p)| match (frame, i) {
+					// track 3 is seen only in the first frame
+					(1, 3) | (2, 3) => (-1., -1.),
+					// outlier
+					(1, 0) => (500. * (p[0] - c) / p[2] + 330., 500. * p[1] / p[2] + 240.),
+					_ => (500. * (p[0] - c) / p[2] + 320., 500. * p[1] / p[2] + 240.),
+				})
+				.unzip();
+			Mat::from_slice_2d(&[xs, ys])
+		})
+		.collect::<Result<Vec<_>>>()?;
+
+	let filtered = sfm::filter_tracks(&reconstruction, &tracks, OutlierPolicy { max_reproj_px: 2., min_track_len: 2 })?;
+	assert_eq!((1, 1), (filtered.removed_observations, filtered.removed_tracks));
+	assert_eq!(vec![0, 1, 2], filtered.kept);
+	assert_eq!(3, filtered.tracks.len());
+	assert_eq!(3, filtered.tracks[1].cols());
+	assert_eq!(-1., *filtered.tracks[1].at_2d::<f64>(0, 0)?);
+	assert_eq!(*tracks[2].at_2d::<f64>(1, 2)?, *filtered.tracks[2].at_2d::<f64>(1, 2)?);
+
+	assert!(sfm::filter_tracks(&reconstruction, &tracks, OutlierPolicy { max_reproj_px: 2., min_track_len: 4 }).is_err());
+	assert!(sfm::filter_tracks(&reconstruction, &tracks[..2], OutlierPolicy { max_reproj_px: 2., min_track_len: 2 }).is_err());
+	Ok(())
+}
+
+#[test]
+fn mask_tracks() -> Result<()> {
+	let mut tracks = vec![
+		Mat::from_slice_2d(&[[1f32, 8., -1.], [1., 8., -1.]])?,
+		Mat::from_slice_2d(&[[2f32, 3., 20.], [2., 3., 2.]])?,
+	];
+	// the lower right quarter is dynamic
+	let rows = (0..10).map(|r| (0..10).map(|c| if r >= 5 && c >= 5 { 0u8 } else { 255 }).collect::<Vec<_>>()).collect::<Vec<_>>();
+	let mask = Mat::from_slice_2d(&rows)?;
+	assert_eq!(2, sfm::mask_tracks(&mut tracks, &[mask.clone(), mask.clone()])?);
+	assert_eq!(core::CV_64F, tracks[0].typ());
+	assert_eq!(-1., *tracks[0].at_2d::<f64>(0, 1)?);
+	assert_eq!(1., *tracks[0].at_2d::<f64>(0, 0)?);
+	// outside of the mask
+	assert_eq!(-1., *tracks[1].at_2d::<f64>(0, 2)?);
+	assert_eq!(0, sfm::mask_tracks(&mut tracks, &[Mat::default(), mask.clone()])?);
+	assert!(sfm::mask_tracks(&mut tracks, &[mask]).is_err());
+	Ok(())
+}
+
+#[test]
+#[cfg(all(ocvrs_has_module_calib3d, ocvrs_has_module_features2d))]
+fn track_builder() -> Result<()> {
+	let mut scene = Mat::new_rows_cols_with_default(240, 340, core::CV_8UC1, Scalar::all(0.))?;
+	core::randu(&mut scene, &Scalar::all(0.), &Scalar::all(255.))?;
+	// the camera moves 10 pixels to the right
+	let images = vec![
+		Mat::roi(&scene, core::Rect::new(0, 0, 320, 240))?.try_clone()?,
+		Mat::roi(&scene, core::Rect::new(10, 0, 320, 240))?.try_clone()?,
+	];
+	// only the left half is static
+	let rows = (0..240).map(|_| (0..320).map(|c| if c < 160 { 255u8 } else { 0 }).collect::<Vec<_>>()).collect::<Vec<_>>();
+	let mask = Mat::from_slice_2d(&rows)?;
+
+	let tracks = sfm::TrackBuilder::new()?.with_masks(vec![mask.clone(), mask.clone()]).build(&images)?;
+	assert_eq!(2, tracks.len());
+	assert!(tracks[0].cols() > 0);
+	for i in 0..tracks[0].cols() {
+		let (x0, x1) = (*tracks[0].at_2d::<f64>(0, i)?, *tracks[1].at_2d::<f64>(0, i)?);
+		assert!(x0 < 160. && x1 < 160.);
+		assert!((x1 - (x0 - 10.)).abs() < 1.);
+	}
+
+	assert!(sfm::TrackBuilder::new()?.with_masks(vec![mask]).build(&images).is_err());
+	Ok(())
+}
+
+#[test]
+fn exif() {
+	// little endian TIFF with the make in IFD0 and the focal lengths in the EXIF IFD
+	let mut tiff = b"II\x2a\x00\x08\x00\x00\x00".to_vec();
+	let entry = |tiff: &mut Vec<u8>, tag: u16, typ: u16, count: u32, value: u32| {
+		tiff.extend_from_slice(&tag.to_le_bytes());
+		tiff.extend_from_slice(&typ.to_le_bytes());
+		tiff.extend_from_slice(&count.to_le_bytes());
+		tiff.extend_from_slice(&value.to_le_bytes());
+	};
+	tiff.extend_from_slice(&2u16.to_le_bytes());
+	entry(&mut tiff, 0x010F, 2, 6, 38);
+	entry(&mut tiff, 0x8769, 4, 1, 44);
+	tiff.extend_from_slice(&[0; 4]);
+	tiff.extend_from_slice(b"Canon\0");
+	tiff.extend_from_slice(&2u16.to_le_bytes());
+	entry(&mut tiff, 0x920A, 5, 1, 74);
+	entry(&mut tiff, 0xA405, 3, 1, 50);
+	tiff.extend_from_slice(&[0; 4]);
+	tiff.extend_from_slice(&35u32.to_le_bytes());
+	tiff.extend_from_slice(&1u32.to_le_bytes());
+
+	let mut jpeg = vec![0xFF, 0xD8, 0xFF, 0xE1];
+	jpeg.extend_from_slice(&(tiff.len() as u16 + 8).to_be_bytes());
+	jpeg.extend_from_slice(b"Exif\0\0");
+	jpeg.extend_from_slice(&tiff);
+	jpeg.extend_from_slice(&[0xFF, 0xDA, 0x00, 0x02]);
+
+	let exif = sfm::Exif::from_jpeg(&jpeg).expect("EXIF must be found");
+	assert_eq!(Some("Canon"), exif.make.as_deref());
+	assert_eq!(Some(35.), exif.focal_length_mm);
+	assert_eq!(Some(50.), exif.focal_length_35mm);
+	// no sensor resolution, 35 mm equivalent over the diagonal
+	let focal = exif.focal_length_px(3000, 4000).unwrap();
+	assert!((focal - 50. * 5000. / 43.266_615).abs() < 1e-6);
+
+	assert_eq!(None, sfm::Exif::from_jpeg(&[0xFF, 0xD8, 0xFF, 0xDA, 0x00, 0x02]));
+	assert_eq!(None, sfm::Exif::from_jpeg(b"\x89PNG"));
+}
+
+#[test]
+#[cfg(ocvrs_has_module_imgcodecs)]
+fn image_set() -> Result<()> {
+	use opencv::imgcodecs;
+
+	let dir = std::env::temp_dir().join(format!("opencv-rust-image-set-{}", std::process::id()));
+	std::fs::create_dir_all(&dir).unwrap();
+	for (name, width) in &[("b.png", 20), ("a.png", 10), ("c.png", 30)] {
+		let image = Mat::new_rows_cols_with_default(8, *width, core::CV_8UC3, Scalar::all(128.))?;
+		imgcodecs::imwrite(&dir.join(name).to_string_lossy(), &image, &core::Vector::new())?;
+	}
+	std::fs::write(dir.join("notes.txt"), "not an image").unwrap();
+
+	let mut images = sfm::ImageSet::from_glob(&dir.join("*.png").to_string_lossy())?.with_cache_size(1);
+	assert_eq!(3, images.len());
+	assert_eq!(vec!["a.png", "b.png", "c.png"], images.paths().iter().map(|p| p.file_name().unwrap().to_str().unwrap()).collect::<Vec<_>>());
+	assert_eq!(20, images.get(1)?.cols());
+	assert_eq!(10, images.get(0)?.cols());
+	assert_eq!(20, images.get(1)?.cols());
+	assert!(images.get(3).is_err());
+	// PNG has no EXIF
+	assert!(images.k_from_exif(2)?.is_none());
+	assert_eq!(3, images.to_vector().len());
+	assert!(sfm::ImageSet::from_glob(&dir.join("?.jpg").to_string_lossy()).is_err());
+
+	std::fs::remove_dir_all(&dir).unwrap();
+	Ok(())
+}
+
+#[test]
+#[cfg(all(ocvrs_has_module_imgcodecs, ocvrs_has_module_imgproc))]
+fn intrinsics() -> Result<()> {
+	use opencv::imgproc;
+	use sfm::intrinsics::{self, SensorDatabase};
+
+	let mut sensors = SensorDatabase::default();
+	assert_eq!(Some(35.9), sensors.sensor_width("NIKON CORPORATION", "NIKON D800"));
+	assert_eq!(None, sensors.sensor_width("Acme", "Cam 1"));
+	sensors.insert("Acme", "Cam 1", 6.4);
+	assert_eq!(Some(6.4), sensors.sensor_width(" acme ", "CAM 1"));
+	assert_eq!(None, SensorDatabase::empty().sensor_width("NIKON CORPORATION", "NIKON D800"));
+
+	// two sets of the parallel 3D lines along the orthogonal horizontal directions, camera yawed by 45°
+	let (f, cx, cy) = (500., 320., 240.);
+	let project = |p: [f64; 3]| core::Point::new((f * p[0] / p[2] + cx).round() as i32, (f * p[1] / p[2] + cy).round() as i32);
+	let mut image = Mat::new_rows_cols_with_default(480, 640, core::CV_8UC1, Scalar::all(0.))?;
+	let s = std::f64::consts::FRAC_1_SQRT_2;
+	for &direction in &[[s, 0., s], [-s, 0., s]] {
+		for &y in &[-1.5, -0.75, 0.75, 1.5] {
+			for &offset in &[-1., 1.] {
+				let start = [offset * direction[2], y, 6. - offset * direction[0]];
+				let along = |t: f64| [start[0] + t * direction[0], start[1], start[2] + t * direction[2]];
+				imgproc::line(&mut image, project(along(-2.)), project(along(2.)), Scalar::all(255.), 2, imgproc::LINE_8, 0)?;
+			}
+		}
+	}
+	let focal = intrinsics::focal_from_vanishing_points(&image)?.expect("focal must be found");
+	assert!((focal - f).abs() / f < 0.1, "{}", focal);
+
+	let blank = Mat::new_rows_cols_with_default(480, 640, core::CV_8UC1, Scalar::all(0.))?;
+	assert_eq!(None, intrinsics::focal_from_vanishing_points(&blank)?);
+	Ok(())
+}
+
+#[test]
+fn refine_intrinsics() -> Result<()> {
+	use sfm::RefineIntrinsics;
+
+	let flags = RefineIntrinsics::FOCAL_LENGTH | RefineIntrinsics::RADIAL_K1 | RefineIntrinsics::RADIAL_K2;
+	assert_eq!(sfm::SFM_REFINE_FOCAL_LENGTH | sfm::SFM_REFINE_RADIAL_DISTORTION_K1 | sfm::SFM_REFINE_RADIAL_DISTORTION_K2, flags.bits());
+	assert!(flags.contains(RefineIntrinsics::RADIAL_K1));
+	assert!(!flags.contains(RefineIntrinsics::PRINCIPAL_POINT));
+	assert_eq!("FOCAL_LENGTH | RADIAL_K1 | RADIAL_K2", format!("{:?}", flags));
+	assert!(flags.validate().is_ok());
+	assert!(RefineIntrinsics::NONE.validate().is_ok());
+	assert!(RefineIntrinsics::PRINCIPAL_POINT.validate().is_err());
+	assert!((RefineIntrinsics::FOCAL_LENGTH | RefineIntrinsics::RADIAL_K2).validate().is_err());
+	assert_eq!(RefineIntrinsics::default(), RefineIntrinsics::from_bits(sfm::SFM_REFINE_FOCAL_LENGTH)?);
+	assert!(RefineIntrinsics::from_bits(0x100).is_err());
+
+	let mut options = sfm::libmv_ReconstructionOptions::new(1, 2, sfm::SFM_REFINE_FOCAL_LENGTH, 1, -1)?;
+	options.set_refine_intrinsics_flags(flags)?;
+	assert_eq!(flags, options.refine_intrinsics_flags()?);
+	assert!(options.set_refine_intrinsics_flags(RefineIntrinsics::RADIAL_K1).is_err());
+	assert_eq!(flags.bits(), options.refine_intrinsics);
+	Ok(())
+}
+
+#[test]
+fn two_view() -> Result<()> {
+	let k = Mat::from_slice_2d(&[[500f64, 0., 320.], [0., 500., 240.], [0., 0., 1.]])?;
+	// the second camera is 1 unit to the right, t = -C
+	let points = (0..30)
+		.map(|i| Point3d::new(f64::from(i % 6) - 2.5, f64::from(i / 6) - 2., 6. + f64::from(i % 4)))
+		.collect::<Vec<_>>();
+	let project = |p: &Point3d, cx: f64| Point2d::new(500. * (p.x - cx) / p.z + 320., 500. * p.y / p.z + 240.);
+	let x1 = points.iter().map(|p| project(p, 0.)).collect::<Vec<_>>();
+	let mut x2 = points.iter().map(|p| project(p, 1.)).collect::<Vec<_>>();
+	// outlier
+	x2[0].y += 40.;
+
+	let out = sfm::two_view::reconstruct(&x1, &x2, &k)?;
+	assert!(!out.inliers[0]);
+	assert!(out.inliers[1..].iter().all(|&inlier| inlier));
+	assert!((*out.t.at::<f64>(0)? + 1.).abs() < 1e-3);
+	assert!((*out.r.at_2d::<f64>(0, 0)? - 1.).abs() < 1e-3);
+	for (expected, actual) in points.iter().zip(&out.points3d).skip(1) {
+		assert!((*expected - *actual).norm() < 1e-2);
+	}
+
+	assert!(sfm::two_view::reconstruct(&x1[..4], &x2[..4], &k).is_err());
+	assert!(sfm::two_view::reconstruct(&x1, &x2[1..], &k).is_err());
+	Ok(())
+}
+
+#[test]
+fn fit() -> Result<()> {
+	use sfm::fit::{self, Line3, Plane, RansacParams, Sphere};
+
+	// ground plane z = 0.5 * x + 1 with the points above it
+	let mut points = (0..100)
+		.map(|i| {
+			let (x, y) = (f64::from(i % 10), f64::from(i / 10));
+			Point3d::new(x, y, 0.5 * x + 1. + if i % 2 == 0 { 0.01 } else { -0.01 })
+		})
+		.collect::<Vec<_>>();
+	points.extend((0..20).map(|i| Point3d::new(f64::from(i % 5), f64::from(i / 5), 10. + f64::from(i))));
+	let plane = fit::fit_plane(&points, 0.05)?;
+	assert_eq!(100, plane.inlier_count());
+	assert!(plane.inliers[..100].iter().all(|&inlier| inlier));
+	assert!((plane.rms - 0.01 * 2. / 5f64.sqrt()).abs() < 1e-3);
+	let n = plane.model.normal;
+	assert!((n[0] / n[2] + 0.5).abs() < 1e-3 && (n[1] / n[2]).abs() < 1e-3);
+	assert!(plane.model.signed_distance(plane.model.project(Point3d::new(3., 4., 20.))).abs() < 1e-9);
+	assert_eq!(120, plane.inlier_mask()?.rows());
+
+	let sphere_points = (0..50)
+		.map(|i| {
+			let (theta, phi) = (f64::from(i) * 0.7, f64::from(i) * 0.3);
+			Point3d::new(1. + 2. * theta.sin() * phi.cos(), -1. + 2. * theta.sin() * phi.sin(), 3. + 2. * theta.cos())
+		})
+		.chain(vec![Point3d::new(10., 10., 10.), Point3d::new(-5., 0., 0.)])
+		.collect::<Vec<_>>();
+	let sphere = fit::ransac::<Sphere>(&sphere_points, RansacParams::new(0.01).with_max_iterations(500))?;
+	assert_eq!(50, sphere.inlier_count());
+	assert!((sphere.model.center - Point3d::new(1., -1., 3.)).norm() < 1e-6);
+	assert!((sphere.model.radius - 2.).abs() < 1e-6);
+
+	let line = fit::least_squares::<Line3>(&[Point3d::new(0., 0., 0.), Point3d::new(1., 1., 1.), Point3d::new(2., 2., 2.)])?;
+	assert!((line.model.direction[0].abs() - 1. / 3f64.sqrt()).abs() < 1e-9);
+	assert!(line.rms < 1e-9);
+
+	assert!(fit::least_squares::<Plane>(&[Point3d::new(0., 0., 0.), Point3d::new(1., 1., 1.), Point3d::new(2., 2., 2.)]).is_err());
+	assert!(fit::fit_plane(&points[..2], 0.1).is_err());
+	assert!(fit::fit_plane(&points, 0.).is_err());
+	Ok(())
+}
+
+#[test]
+#[cfg(ocvrs_has_module_calib3d)]
+fn merge() -> Result<()> {
+	use sfm::{MergeOptions, Reconstruction};
+
+	let k = Mat::from_slice_2d(&[[500f64, 0., 320.], [0., 500., 240.], [0., 0., 1.]])?;
+	let world = (0..10)
+		.map(|i| Point3d::new(f64::from(i % 4) - 1.5, f64::from(i / 4) - 1., 5. + f64::from(i % 3)))
+		.collect::<Vec<_>>();
+	let centers = [0f64, 0.5, 1., 1.5];
+	let tracks = |centers: &[f64], points: &[Point3d]| -> Result<Vec<Mat>> {
+		centers.iter()
+			.map(|&c| {
+				let (xs, ys): (Vec<_>, Vec<_>) = points.iter().map(|p| (500. * (p.x - c) / p.z + 320., 500. * p.y / p.z + 240.)).unzip();
+				Mat::from_slice_2d(&[xs, ys])
+			})
+			.collect()
+	};
+	let column = |v: [f64; 3]| Mat::from_slice_2d(&[[v[0]], [v[1]], [v[2]]]);
+	let identity = || Mat::from_slice_2d(&[[1f64, 0., 0.], [0., 1., 0.], [0., 0., 1.]]);
+
+	// the first part sees the points 0..8 from the first two cameras
+	let a = Reconstruction {
+		rs: vec![identity()?, identity()?],
+		ts: centers[..2].iter().map(|&c| column([-c, 0., 0.])).collect::<Result<_>>()?,
+		k: k.clone(),
+		points3d: world[..8].iter().map(|p| column([p.x, p.y, p.z])).collect::<Result<_>>()?,
+	};
+	// the second part sees the points 3..10 from the last two cameras in its own coordinates: X_b = R^T * (X - o) / 2
+	// with R rotating by 90° around Z and o = (1, 2, 3)
+	let to_b = |p: Point3d| Point3d::new(p.y - 2., -(p.x - 1.), p.z - 3.) / 2.;
+	let b = Reconstruction {
+		rs: vec![Mat::from_slice_2d(&[[0f64, -1., 0.], [1., 0., 0.], [0., 0., 1.]])?; 2],
+		ts: centers[2..].iter().map(|&c| column([(1. - c) / 2., 1., 1.5])).collect::<Result<_>>()?,
+		k: k.clone(),
+		points3d: world[3..].iter().map(|&p| to_b(p)).map(|p| column([p.x, p.y, p.z])).collect::<Result<_>>()?,
+	};
+	let correspondences = (3..8).map(|i| (i, i - 3)).collect::<Vec<_>>();
+
+	let options = MergeOptions::default().with_tracks(tracks(&centers[..2], &world[..8])?, tracks(&centers[2..], &world[3..])?);
+	let merged = sfm::merge(&a, &b, &correspondences, &options)?;
+	assert!((merged.alignment.s - 2.).abs() < 1e-9);
+	assert!(merged.alignment_rms < 1e-9);
+	assert_eq!(vec![3, 4, 5, 6, 7, 8, 9], merged.point_map_b);
+	let recon = &merged.reconstruction;
+	assert_eq!(4, recon.rs.len());
+	assert_eq!(10, recon.points3d.len());
+	for (p, expected) in recon.points3d.iter().zip(&world) {
+		assert!((*p.at::<f64>(0)? - expected.x).abs() < 1e-6);
+		assert!((*p.at::<f64>(2)? - expected.z).abs() < 1e-6);
+	}
+	assert!((*recon.ts[3].at::<f64>(0)? + 1.5).abs() < 1e-6);
+	assert!((*recon.rs[3].at_2d::<f64>(0, 0)? - 1.).abs() < 1e-6);
+	assert!(merged.bundle_adjustment.expect("Bundle adjustment was run").final_cost < 1e-9);
+
+	let without_ba = sfm::merge(&a, &b, &correspondences, &MergeOptions::default())?;
+	assert!(without_ba.bundle_adjustment.is_none());
+	assert!(sfm::merge(&a, &b, &correspondences[..2], &options).is_err());
+	assert!(sfm::merge(&a, &b, &[(8, 0), (3, 0), (4, 1)], &options).is_err());
+	Ok(())
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "simd")]
+
+use opencv::{
+	core::simd,
+	Result,
+};
+
+#[test]
+fn bgr_to_rgb() -> Result<()> {
+	let src = (0..3 * 37).map(|i| i as u8).collect::<Vec<_>>();
+	let expected = src.chunks_exact(3).flat_map(|px| [px[2], px[1], px[0]]).collect::<Vec<_>>();
+	let mut dst = vec![0; src.len()];
+	simd::bgr_to_rgb(&src, &mut dst)?;
+	assert_eq!(expected, dst);
+
+	let mut inplace = src.clone();
+	simd::bgr_to_rgb_inplace(&mut inplace)?;
+	assert_eq!(expected, inplace);
+
+	assert!(simd::bgr_to_rgb(&src[1..], &mut dst[1..]).is_err());
+	assert!(simd::bgr_to_rgb(&src, &mut dst[3..]).is_err());
+	Ok(())
+}
+
+#[test]
+fn normalize_u8_to_f32() -> Result<()> {
+	let src = (0..=255).collect::<Vec<u8>>();
+	let mut dst = vec![0.; src.len()];
+	simd::normalize_u8_to_f32(&src, &mut dst, 1. / 255., -0.5)?;
+	for (&s, &d) in src.iter().zip(&dst) {
+		assert!((f32::from(s) / 255. - 0.5 - d).abs() < 1e-6);
+	}
+	Ok(())
+}
+
+#[test]
+fn nhwc_to_nchw() -> Result<()> {
+	for &(height, width, channels) in &[(5, 7, 3), (4, 3, 1), (2, 9, 4)] {
+		let src = (0..height * width * channels).map(|i| i as f32).collect::<Vec<_>>();
+		let mut dst = vec![0.; src.len()];
+		simd::nhwc_to_nchw(&src, &mut dst, height, width, channels)?;
+		for y in 0..height {
+			for x in 0..width {
+				for c in 0..channels {
+					assert_eq!(src[(y * width + x) * channels + c], dst[(c * height + y) * width + x]);
+				}
+			}
+		}
+	}
+	let mut dst = vec![0.; 10];
+	assert!(simd::nhwc_to_nchw(&[0.; 10], &mut dst, 2, 2, 3).is_err());
+	Ok(())
+}
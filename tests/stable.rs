@@ -0,0 +1,72 @@
+use opencv::{
+	core::{Scalar, UMat, UMatUsageFlags},
+	imgproc,
+	prelude::*,
+	Result,
+	stable::{self, core::AccessFlag, imgproc::{HersheyFont, LineType}},
+};
+
+#[test]
+fn stable_line_type() -> Result<()> {
+	assert_eq!(8, i32::from(LineType::Line8));
+	assert_eq!(-1, i32::from(LineType::Filled));
+	let mut m = Mat::new_rows_cols_with_default(10, 10, u8::typ(), Scalar::all(0.))?;
+	imgproc::line(&mut m, (0, 0).into(), (9, 9).into(), Scalar::all(255.), 1, LineType::Line4.into(), 0)?;
+	assert_eq!(255, *m.at_2d::<u8>(5, 5)?);
+	Ok(())
+}
+
+#[test]
+fn stable_font() -> Result<()> {
+	assert_eq!(imgproc::FONT_HERSHEY_SIMPLEX, i32::from(HersheyFont::Simplex));
+	assert_eq!(imgproc::FONT_HERSHEY_COMPLEX | imgproc::FONT_ITALIC, HersheyFont::Complex.italic());
+	let mut base_line = 0;
+	let size = imgproc::get_text_size("text", HersheyFont::Plain.into(), 1., 1, &mut base_line)?;
+	assert!(size.width > 0);
+	Ok(())
+}
+
+#[test]
+fn stable_umat_access() -> Result<()> {
+	let mat = Mat::new_rows_cols_with_default(3, 3, u8::typ(), Scalar::all(7.))?;
+	let mut umat = UMat::new(UMatUsageFlags::USAGE_DEFAULT);
+	mat.copy_to(&mut umat)?;
+	let mat = stable::core::umat_get_mat(&umat, AccessFlag::Read)?;
+	assert_eq!(7, *mat.at_2d::<u8>(1, 1)?);
+	let umat = stable::core::mat_get_umat(&mat, AccessFlag::Read, UMatUsageFlags::USAGE_DEFAULT)?;
+	assert_eq!(mat.size()?, umat.size()?);
+	Ok(())
+}
+
+#[test]
+fn stable_fast_detector() -> Result<()> {
+	#![cfg(ocvrs_has_module_features2d)]
+	use opencv::stable::features2d::FastType;
+
+	let detector = stable::features2d::fast_feature_detector(10, true, FastType::Type9_16)?;
+	assert_eq!(10, detector.get_threshold()?);
+	Ok(())
+}
+
+#[test]
+fn stable_feature_detectors() -> Result<()> {
+	#![cfg(ocvrs_has_module_features2d)]
+	use opencv::stable::features2d::{AgastType, AkazeDescriptorType, KazeDiffusivity, OrbScoreType};
+
+	let agast = stable::features2d::agast_feature_detector(10, true, AgastType::Oast9_16)?;
+	assert_eq!(10, agast.get_threshold()?);
+	let orb = stable::features2d::orb(100, 1.2, 8, 31, 0, 2, OrbScoreType::Fast, 31, 20)?;
+	assert_eq!(100, orb.get_max_features()?);
+	let akaze = stable::features2d::akaze(AkazeDescriptorType::Mldb, 0, 3, 0.001, 4, 4, KazeDiffusivity::PmG2)?;
+	assert_eq!(4, akaze.get_n_octaves()?);
+	let kaze = stable::features2d::kaze(false, true, 0.001, 4, 4, KazeDiffusivity::Charbonnier)?;
+	assert!(kaze.get_upright()?);
+	Ok(())
+}
+
+#[test]
+fn stable_fourcc() -> Result<()> {
+	#![cfg(ocvrs_has_module_videoio)]
+	assert_eq!(i32::from_le_bytes(*b"MJPG"), stable::videoio::fourcc(b"MJPG")?);
+	Ok(())
+}
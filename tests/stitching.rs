@@ -0,0 +1,36 @@
+#![cfg(ocvrs_has_module_stitching)]
+
+use opencv::{
+	core::{self, Mat, Matx33d, Scalar, Size, Vec4d},
+	prelude::*,
+	Result,
+	stitching::dual_fisheye::{DualFisheye, FisheyeLens},
+};
+
+#[test]
+fn dual_fisheye() -> Result<()> {
+	// equidistant lenses with 200° field of view filling a 400x400 image
+	let f = 195. / 100f64.to_radians();
+	let k = Matx33d::from([f, 0., 199.5, 0., f, 199.5, 0., 0., 1.]);
+	let front_lens = FisheyeLens::new(k, Vec4d::default()).with_fov(200f64.to_radians());
+	let back_lens = front_lens.facing_back();
+	let stitcher = DualFisheye::new(&front_lens, &back_lens, Size::new(400, 400), 360)?;
+	assert_eq!(Size::new(360, 180), stitcher.panorama_size()?);
+
+	let front = Mat::new_rows_cols_with_default(400, 400, core::CV_8UC3, Scalar::all(100.))?;
+	let back = Mat::new_rows_cols_with_default(400, 400, core::CV_8UC3, Scalar::all(200.))?;
+	let panorama = stitcher.stitch(&front, &back)?;
+	assert_eq!(Size::new(360, 180), panorama.size()?);
+	assert_eq!(core::CV_8UC3, panorama.typ());
+	// the center of the panorama is seen by the front lens only, its left and right edges by the back lens only
+	assert_eq!(100, panorama.at_2d::<core::Vec3b>(90, 180)?[0]);
+	assert_eq!(200, panorama.at_2d::<core::Vec3b>(90, 0)?[0]);
+	assert_eq!(200, panorama.at_2d::<core::Vec3b>(90, 359)?[0]);
+	// both lenses contribute at the seam
+	let seam = panorama.at_2d::<core::Vec3b>(90, 90)?[0];
+	assert!(seam > 100 && seam < 200, "{}", seam);
+
+	assert!(stitcher.stitch(&front, &Mat::new_rows_cols_with_default(300, 300, core::CV_8UC3, Scalar::all(0.))?).is_err());
+	assert!(FisheyeLens::from_mats(&Mat::from_slice(&[1., 2., 3.])?, &Mat::from_slice(&[0., 0., 0., 0.])?).is_err());
+	Ok(())
+}
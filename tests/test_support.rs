@@ -0,0 +1,42 @@
+#![cfg(feature = "test-util")]
+
+use opencv::{
+	core::{self, Scalar},
+	prelude::*,
+	Result,
+	test_support::{self, proptest::prelude::*, Tolerance},
+};
+
+proptest! {
+	#[test]
+	fn generated_mats(mat in test_support::mats(1..8, 1..8, test_support::mat_types_of(&[core::CV_8U, core::CV_32F], 1..4))) {
+		prop_assert!((1..8).contains(&mat.rows()));
+		prop_assert!((1..8).contains(&mat.cols()));
+		prop_assert!(mat.depth() == core::CV_8U || mat.depth() == core::CV_32F);
+		let comparison = test_support::compare(&mat, &mat.try_clone()?, &Tolerance::exact())?;
+		prop_assert!(comparison.is_within());
+		prop_assert_eq!(0., comparison.max_abs_diff);
+	}
+}
+
+#[test]
+fn compare_with_tolerance() -> Result<()> {
+	let expected = Mat::new_rows_cols_with_default(10, 10, core::CV_8UC1, Scalar::all(100.))?;
+	let mut actual = expected.try_clone()?;
+	*actual.at_2d_mut::<u8>(0, 0)? = 103;
+	*actual.at_2d_mut::<u8>(5, 5)? = 101;
+
+	let exact = test_support::compare(&actual, &expected, &Tolerance::exact())?;
+	assert!(!exact.is_within());
+	assert_eq!(2, exact.mismatched);
+	assert_eq!(3., exact.max_abs_diff);
+	assert_eq!(255, *exact.diff.at_2d::<u8>(0, 0)?);
+
+	assert!(!test_support::compare(&actual, &expected, &Tolerance::new(2.))?.is_within());
+	assert!(test_support::compare(&actual, &expected, &Tolerance::new(2.).with_mismatched_ratio(0.01))?.is_within());
+	assert!(test_support::compare(&actual, &expected, &Tolerance::new(3.))?.is_within());
+
+	let other_size = Mat::new_rows_cols_with_default(5, 10, core::CV_8UC1, Scalar::all(100.))?;
+	assert_eq!(core::StsUnmatchedSizes, test_support::compare(&actual, &other_size, &Tolerance::exact()).unwrap_err().code);
+	Ok(())
+}
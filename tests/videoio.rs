@@ -0,0 +1,187 @@
+#![cfg(ocvrs_has_module_videoio)]
+
+use std::{
+	path::PathBuf,
+	sync::{Arc, Mutex},
+	thread,
+	time::{Duration, Instant},
+};
+
+use opencv::{
+	core::{self, Scalar, Size, Vec3b},
+	Error,
+	prelude::*,
+	Result,
+	videoio::{self, CaptureEvent, CaptureProperty, pipeline::{Frame, Pipeline}, sources::{ImageSequence, Pattern, SyntheticPattern}, Timestamp},
+};
+
+fn counting_source(count: u64) -> impl FnMut() -> Result<Option<Mat>> + Send {
+	let mut produced = 0;
+	move || {
+		if produced == count {
+			return Ok(None);
+		}
+		produced += 1;
+		Mat::new_rows_cols_with_default(4, 4, core::CV_32FC1, Scalar::all(produced as f64)).map(Some)
+	}
+}
+
+#[test]
+fn pipeline() -> Result<()> {
+	let mut seen = vec![];
+	let mut last_index = None;
+	let report = Pipeline::new(counting_source(20))
+		.with_capacity(2)
+		.parallel_stage("double", 3, |mut frame: Frame| {
+			// make the later frames finish earlier
+			thread::sleep(Duration::from_millis(20 - frame.index));
+			let mut doubled = Mat::default();
+			frame.mat.convert_to(&mut doubled, -1, 2., 0.)?;
+			frame.mat = doubled;
+			Ok(frame)
+		})
+		.stage("check order", move |frame: Frame| {
+			assert_eq!(last_index.map_or(0, |i| i + 1), frame.index);
+			last_index = Some(frame.index);
+			Ok(frame)
+		})
+		.run(|frame: Frame| {
+			seen.push((frame.index, *frame.mat.at_2d::<f32>(0, 0)?));
+			Ok(())
+		})?;
+	assert_eq!(20, report.frames);
+	assert_eq!(2, report.stages.len());
+	assert_eq!("double", report.stages[0].name);
+	assert_eq!(20, report.stages[0].frames);
+	assert!(report.stages[0].max >= Duration::from_millis(20));
+	assert!(report.stages[0].mean() > Duration::default());
+	assert_eq!((0..20).map(|i| (i, (i + 1) as f32 * 2.)).collect::<Vec<_>>(), seen);
+
+	let err = Pipeline::new(counting_source(100))
+		.stage("fail", |frame: Frame| if frame.index == 5 {
+			Err(Error::new(core::StsError, "stage failed"))
+		} else {
+			Ok(frame)
+		})
+		.run(|_| Ok(()))
+		.unwrap_err();
+	assert_eq!("stage failed", err.message);
+	Ok(())
+}
+
+#[test]
+fn resilient_capture_gives_up() -> Result<()> {
+	let events = Arc::new(Mutex::new(vec![]));
+	let mut cap = videoio::ResilientCapture::new("/nonexistent/stream.avi", videoio::CAP_ANY)
+		.with_backoff(Duration::from_millis(1), Duration::from_millis(2))
+		.with_max_attempts(Some(2))
+		.on_event({
+			let events = Arc::clone(&events);
+			move |e| events.lock().unwrap().push(e)
+		});
+	let mut frame = Mat::default();
+	assert!(cap.read(&mut frame).is_err());
+	assert!(!cap.is_connected());
+	assert_eq!(
+		vec![
+			CaptureEvent::ConnectFailed { attempt: 0 },
+			CaptureEvent::Reconnecting { attempt: 1, delay: Duration::from_millis(1) },
+			CaptureEvent::ConnectFailed { attempt: 1 },
+			CaptureEvent::Reconnecting { attempt: 2, delay: Duration::from_millis(2) },
+			CaptureEvent::ConnectFailed { attempt: 2 },
+			CaptureEvent::GaveUp { attempts: 2 },
+		],
+		*events.lock().unwrap(),
+	);
+	// no more attempts after giving up
+	assert!(cap.read(&mut frame).is_err());
+	assert_eq!(6, events.lock().unwrap().len());
+	Ok(())
+}
+
+#[test]
+fn capture_properties() -> Result<()> {
+	assert_eq!(videoio::CAP_PROP_FRAME_WIDTH, CaptureProperty::FrameWidth.id());
+	assert_eq!(videoio::CAP_PROP_XI_GAIN, CaptureProperty::Other(videoio::CAP_PROP_XI_GAIN).id());
+
+	let mut cap = videoio::VideoCapture::default()?;
+	assert_eq!(0., cap.property(CaptureProperty::FrameWidth)?);
+	assert!(cap.set_property(CaptureProperty::Exposure, 10.).is_err());
+	assert!(cap.read_timestamped()?.is_none());
+
+	let origin = Instant::now();
+	let captured_at = origin + Duration::from_millis(40);
+	let monotonic = Timestamp { captured_at, stream_time: None };
+	assert_eq!(Duration::from_millis(40), monotonic.time_since(origin));
+	let stream = Timestamp { captured_at, stream_time: Some(Duration::from_millis(1000)) };
+	assert_eq!(Duration::from_millis(1000), stream.time_since(origin));
+	Ok(())
+}
+
+#[test]
+fn frame_sources() -> Result<()> {
+	let mut pattern = SyntheticPattern::new(Size::new(64, 48), Pattern::MovingBox(8)).with_limit(3);
+	let mut frame = Mat::default();
+	for _ in 0..3 {
+		assert!(pattern.read(&mut frame)?);
+		assert_eq!(Size::new(64, 48), frame.size()?);
+		assert_eq!(core::CV_8UC3, frame.typ());
+	}
+	assert!(!pattern.read(&mut frame)?);
+
+	let mut bars = SyntheticPattern::new(Size::new(80, 10), Pattern::ColorBars);
+	assert!(bars.read(&mut frame)?);
+	assert_eq!(Vec3b::from([255, 255, 255]), *frame.at_2d::<Vec3b>(5, 0)?);
+	assert_eq!(Vec3b::from([0, 0, 0]), *frame.at_2d::<Vec3b>(5, 79)?);
+
+	let mut noise_a = SyntheticPattern::new(Size::new(16, 16), Pattern::Noise(42));
+	let mut noise_b = noise_a.clone();
+	let (mut a, mut b) = (Mat::default(), Mat::default());
+	noise_a.read(&mut a)?;
+	noise_b.read(&mut b)?;
+	assert_eq!(a.data_typed::<Vec3b>()?, b.data_typed::<Vec3b>()?);
+
+	let tests_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests");
+	let report = Pipeline::new(ImageSequence::new(vec![tests_dir.join("blox.jpg"), tests_dir.join("pixel.png")]))
+		.run(|frame: Frame| {
+			assert!(!frame.mat.empty());
+			Ok(())
+		})?;
+	assert_eq!(2, report.frames);
+
+	let mut looped = ImageSequence::new(vec![tests_dir.join("pixel.png")]).looped(true);
+	for _ in 0..3 {
+		assert!(looped.read(&mut frame)?);
+	}
+	let mut missing = ImageSequence::new(vec![tests_dir.join("missing.png")]);
+	assert!(missing.read(&mut frame).is_err());
+	Ok(())
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn v4l2_controls() -> Result<()> {
+	use videoio::v4l2::{Control, ControlKind, ControlRange, V4l2Device};
+
+	assert!(V4l2Device::open("/dev/nonexistent-video-device").is_err());
+	assert_eq!(0x009a0902, Control::ExposureAbsolute.id());
+
+	let range = ControlRange {
+		control: Control::WhiteBalanceTemperature,
+		name: "White Balance Temperature".to_string(),
+		kind: ControlKind::Integer,
+		min: 2800,
+		max: 6500,
+		step: 10,
+		default: 4600,
+		read_only: false,
+		inactive: false,
+	};
+	range.validate(4000)?;
+	assert_eq!(core::StsOutOfRange, range.validate(7000).unwrap_err().code);
+	assert_eq!(core::StsOutOfRange, range.validate(4005).unwrap_err().code);
+	assert_eq!(6500, range.clamp(9000));
+	assert_eq!(4010, range.clamp(4006));
+	assert_eq!(2800, range.clamp(0));
+	Ok(())
+}
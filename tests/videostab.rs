@@ -0,0 +1,63 @@
+#![cfg(ocvrs_has_module_videostab)]
+
+use opencv::{
+	core::{self, Mat, Rect, Scalar, Size},
+	imgproc,
+	prelude::*,
+	Result,
+	videostab::{stabilize, StabMode, StabOptions},
+};
+
+/// Mean absolute difference of the consecutive frames in their central part
+fn shakiness(frames: &[Mat]) -> Result<f64> {
+	let mut sum = 0.;
+	for pair in frames.windows(2) {
+		let size = pair[0].size()?;
+		let center = Rect::new(size.width / 4, size.height / 4, size.width / 2, size.height / 2);
+		let mut diff = Mat::default();
+		core::absdiff(&Mat::roi(&pair[0], center)?, &Mat::roi(&pair[1], center)?, &mut diff)?;
+		sum += core::mean(&diff, &core::no_array())?[0];
+	}
+	Ok(sum / (frames.len() - 1) as f64)
+}
+
+#[test]
+fn stabilize_frames() -> Result<()> {
+	core::set_rng_seed(7)?;
+	let mut noise = Mat::new_rows_cols_with_default(240, 320, core::CV_8UC1, Scalar::all(0.))?;
+	core::randu(&mut noise, &Scalar::all(0.), &Scalar::all(255.))?;
+	let mut texture = Mat::default();
+	imgproc::gaussian_blur(&noise, &mut texture, Size::new(5, 5), 0., 0., core::BORDER_DEFAULT)?;
+	// camera shake of a few pixels around the static scene
+	let shake = [(0., 0.), (4., -3.), (-3., 2.), (5., 4.), (-4., -4.), (2., 3.), (-5., 1.), (3., -2.), (-2., 5.), (4., 0.), (-3., -3.), (1., 4.)];
+	let frames = shake.iter()
+		.map(|&(dx, dy)| {
+			let m = Mat::from_slice_2d(&[[1., 0., dx], [0., 1., dy]])?;
+			let mut frame = Mat::default();
+			imgproc::warp_affine(&texture, &mut frame, &m, texture.size()?, imgproc::INTER_LINEAR, core::BORDER_REFLECT, Scalar::default())?;
+			Ok(frame)
+		})
+		.collect::<Result<Vec<_>>>()?;
+	let input_shakiness = shakiness(&frames)?;
+
+	for &mode in &[StabMode::OnePass, StabMode::TwoPass] {
+		let options = StabOptions::default()
+			.with_mode(mode)
+			.with_radius(5)
+			.with_motion_model(opencv::videostab::MotionModel::MM_TRANSLATION)
+			.with_trim_ratio(0.05);
+		let out = stabilize(frames.clone(), options)?.collect::<Result<Vec<_>>>()?;
+		assert_eq!(frames.len(), out.len());
+		assert_eq!(Size::new(288, 216), out[0].size()?);
+		assert!(shakiness(&out)? < input_shakiness / 2., "{:?}: {} is not less than half of {}", mode, shakiness(&out)?, input_shakiness);
+	}
+
+	let options = StabOptions::default().with_mode(StabMode::TwoPass).with_estimate_trim_ratio(true);
+	let stabilized = stabilize(frames.clone(), options)?;
+	assert!(stabilized.trim_ratio() > 0.);
+	assert_eq!(frames.len(), stabilized.count());
+
+	assert!(stabilize(frames.clone(), StabOptions::default().with_radius(0)).is_err());
+	assert_eq!(0, stabilize(vec![], StabOptions::default())?.count());
+	Ok(())
+}
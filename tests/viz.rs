@@ -0,0 +1,27 @@
+#![cfg(ocvrs_has_module_viz)]
+
+use opencv::{
+	core::Mat,
+	prelude::*,
+	Result,
+	viz,
+};
+
+#[test]
+fn trajectory_from_motions() -> Result<()> {
+	let rs = vec![
+		Mat::from_slice_2d(&[[1f64, 0., 0.], [0., 1., 0.], [0., 0., 1.]])?,
+		Mat::from_slice_2d(&[[0f64, -1., 0.], [1., 0., 0.], [0., 0., 1.]])?,
+	];
+	let ts = vec![Mat::from_slice(&[0f64, 0., 0.])?, Mat::from_slice(&[1f64, 2., 3.])?];
+	let path = viz::trajectory_from_motions(&rs, &ts)?;
+	assert_eq!(2, path.rows());
+	assert_eq!(16, path.channels());
+	let poses = unsafe { std::slice::from_raw_parts(path.data().cast::<f64>(), 32) };
+	assert_eq!([1., 0., 0., 0., 0., 1., 0., 0., 0., 0., 1., 0., 0., 0., 0., 1.], poses[..16]);
+	// R^T and -R^T t
+	assert_eq!([0., 1., 0., -2., -1., 0., 0., 1., 0., 0., 1., -3., 0., 0., 0., 1.], poses[16..]);
+
+	assert!(viz::trajectory_from_motions(&rs, &ts[..1]).is_err());
+	Ok(())
+}
@@ -0,0 +1,33 @@
+#![cfg(ocvrs_has_module_ximgproc)]
+
+use opencv::{
+	core::{self, Point, Scalar},
+	imgproc,
+	prelude::*,
+	Result,
+	ximgproc,
+};
+
+#[test]
+fn slic_superpixels() -> Result<()> {
+	let mut image = Mat::new_rows_cols_with_default(60, 80, core::CV_8UC3, Scalar::new(200., 30., 30., 0.))?;
+	imgproc::rectangle(&mut image, core::Rect::new(0, 0, 40, 60), Scalar::new(30., 200., 30., 0.), -1, imgproc::LINE_8, 0)?;
+	imgproc::circle(&mut image, Point::new(60, 30), 10, Scalar::new(30., 30., 200., 0.), -1, imgproc::LINE_8, 0)?;
+
+	let superpixels = ximgproc::slic_superpixels(&image, &ximgproc::SlicParams::default())?;
+	assert_eq!(core::CV_32S, superpixels.labels.typ());
+	assert_eq!(image.size()?, superpixels.labels.size()?);
+	assert!(superpixels.count > 10);
+	let labels = superpixels.labels.data_typed::<i32>()?;
+	assert!(labels.iter().all(|&l| (0..superpixels.count).contains(&l)));
+	assert!(core::count_non_zero(&superpixels.contours)? > 0);
+
+	let color = Scalar::new(0., 255., 255., 0.);
+	let overlay = superpixels.overlay(&image, color)?;
+	assert_eq!(image.size()?, overlay.size()?);
+	let mut contours = Mat::default();
+	core::in_range(&overlay, &color, &color, &mut contours)?;
+	assert_eq!(core::count_non_zero(&superpixels.contours)?, core::count_non_zero(&contours)?);
+	assert!(superpixels.overlay(&Mat::new_rows_cols_with_default(10, 10, core::CV_8UC3, Scalar::default())?, color).is_err());
+	Ok(())
+}
@@ -0,0 +1,38 @@
+#![cfg(ocvrs_has_module_xphoto)]
+
+use opencv::{
+	core::{self, Mat, Scalar},
+	prelude::*,
+	Result,
+	xphoto::ColorCorrection,
+};
+
+#[test]
+fn color_correction() -> Result<()> {
+	// gray scene under the bluish light
+	let mut src = Mat::new_rows_cols_with_default(64, 64, core::CV_8UC3, Scalar::default())?;
+	core::randu(&mut src, &Scalar::all(60.), &Scalar::all(140.))?;
+	let mut tinted = Mat::default();
+	core::multiply(&src, &Scalar::new(1.4, 1., 0.7, 0.), &mut tinted, 1., -1)?;
+
+	let mut correction = ColorCorrection::new().grayworld_wb(0.99)?;
+	assert_eq!(1, correction.len());
+	let balanced = correction.apply(&tinted)?;
+	let mean = core::mean(&balanced, &core::no_array())?;
+	assert!((mean[0] - mean[2]).abs() < 5., "{:?}", mean);
+	assert!((mean[1] - mean[2]).abs() < 5., "{:?}", mean);
+
+	let mut correction = ColorCorrection::new().channel_gains(1. / 1.4, 1., 1. / 0.7).nl_means_denoise(3., 3.);
+	let corrected = correction.apply(&tinted)?;
+	assert_eq!(tinted.size()?, corrected.size()?);
+	let mean = core::mean(&corrected, &core::no_array())?;
+	assert!((mean[0] - mean[2]).abs() < 3., "{:?}", mean);
+
+	let mut empty = ColorCorrection::new();
+	assert!(empty.is_empty());
+	assert_eq!(0, core::norm2(&src, &empty.apply(&src)?, core::NORM_INF, &core::no_array())? as i32);
+
+	let gray = Mat::new_rows_cols_with_default(8, 8, core::CV_8UC1, Scalar::all(100.))?;
+	assert!(ColorCorrection::new().channel_gains(1., 1., 1.).apply(&gray).is_err());
+	Ok(())
+}